@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents the input for <code>AddTagsToStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToStreamInput {
     /// <p>The name of the stream.</p>
     #[serde(rename = "StreamName")]
@@ -34,9 +36,35 @@ pub struct AddTagsToStreamInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for AddTagsToStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(200),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 /// <p>An object that represents the details of the consumer you registered.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Consumer {
     /// <p>When you register a consumer, Kinesis Data Streams generates an ARN for it. You need this ARN to be able to call <a>SubscribeToShard</a>.</p> <p>If you delete a consumer and then create a new one with the same name, it won't have the same ARN. That's because consumer ARNs contain the creation timestamp. This is important to keep in mind if you have IAM policies that reference consumer ARNs.</p>
     #[serde(rename = "ConsumerARN")]
@@ -54,7 +82,7 @@ pub struct Consumer {
 
 /// <p>An object that represents the details of a registered consumer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConsumerDescription {
     /// <p>When you register a consumer, Kinesis Data Streams generates an ARN for it. You need this ARN to be able to call <a>SubscribeToShard</a>.</p> <p>If you delete a consumer and then create a new one with the same name, it won't have the same ARN. That's because consumer ARNs contain the creation timestamp. This is important to keep in mind if you have IAM policies that reference consumer ARNs.</p>
     #[serde(rename = "ConsumerARN")]
@@ -75,6 +103,7 @@ pub struct ConsumerDescription {
 
 /// <p>Represents the input for <code>CreateStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamInput {
     /// <p>The number of shards that the stream will use. The throughput of the stream is a function of the number of shards; more shards are required for greater provisioned throughput.</p> <p>DefaultShardLimit;</p>
     #[serde(rename = "ShardCount")]
@@ -84,8 +113,31 @@ pub struct CreateStreamInput {
     pub stream_name: String,
 }
 
+impl Validate for CreateStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "shard_count"),
+            self.shard_count,
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input for <a>DecreaseStreamRetentionPeriod</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecreaseStreamRetentionPeriodInput {
     /// <p>The new retention period of the stream, in hours. Must be less than the current retention period.</p>
     #[serde(rename = "RetentionPeriodHours")]
@@ -95,8 +147,25 @@ pub struct DecreaseStreamRetentionPeriodInput {
     pub stream_name: String,
 }
 
+impl Validate for DecreaseStreamRetentionPeriodInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input for <a>DeleteStream</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStreamInput {
     /// <p>If this parameter is unset (<code>null</code>) or if you set it to <code>false</code>, and the stream has registered consumers, the call to <code>DeleteStream</code> fails with a <code>ResourceInUseException</code>. </p>
     #[serde(rename = "EnforceConsumerDeletion")]
@@ -107,7 +176,24 @@ pub struct DeleteStreamInput {
     pub stream_name: String,
 }
 
+impl Validate for DeleteStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterStreamConsumerInput {
     /// <p>The ARN returned by Kinesis Data Streams when you registered the consumer. If you don't know the ARN of the consumer that you want to deregister, you can use the ListStreamConsumers operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its ARN.</p>
     #[serde(rename = "ConsumerARN")]
@@ -123,11 +209,52 @@ pub struct DeregisterStreamConsumerInput {
     pub stream_arn: Option<String>,
 }
 
+impl Validate for DeregisterStreamConsumerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.consumer_arn {
+            errors.check_length(
+                &field_path(path, "consumer_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "consumer_arn"), &value, "^(arn):aws.*:kinesis:.*:\\d{12}:.*stream\\/[a-zA-Z0-9_.-]+\\/consumer\\/[a-zA-Z0-9_.-]+:[0-9]+");
+        }
+        if let Some(ref value) = self.consumer_name {
+            errors.check_length(
+                &field_path(path, "consumer_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "consumer_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:aws.*:kinesis:.*:\\d{12}:stream/\\S+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLimitsInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLimitsOutput {
     /// <p>The number of open shards.</p>
     #[serde(rename = "OpenShardCount")]
@@ -138,6 +265,7 @@ pub struct DescribeLimitsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStreamConsumerInput {
     /// <p>The ARN returned by Kinesis Data Streams when you registered the consumer.</p>
     #[serde(rename = "ConsumerARN")]
@@ -153,8 +281,48 @@ pub struct DescribeStreamConsumerInput {
     pub stream_arn: Option<String>,
 }
 
+impl Validate for DescribeStreamConsumerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.consumer_arn {
+            errors.check_length(
+                &field_path(path, "consumer_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "consumer_arn"), &value, "^(arn):aws.*:kinesis:.*:\\d{12}:.*stream\\/[a-zA-Z0-9_.-]+\\/consumer\\/[a-zA-Z0-9_.-]+:[0-9]+");
+        }
+        if let Some(ref value) = self.consumer_name {
+            errors.check_length(
+                &field_path(path, "consumer_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "consumer_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:aws.*:kinesis:.*:\\d{12}:stream/\\S+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStreamConsumerOutput {
     /// <p>An object that represents the details of the consumer.</p>
     #[serde(rename = "ConsumerDescription")]
@@ -163,6 +331,7 @@ pub struct DescribeStreamConsumerOutput {
 
 /// <p>Represents the input for <code>DescribeStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStreamInput {
     /// <p>The shard ID of the shard to start with.</p>
     #[serde(rename = "ExclusiveStartShardId")]
@@ -177,9 +346,41 @@ pub struct DescribeStreamInput {
     pub stream_name: String,
 }
 
+impl Validate for DescribeStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_shard_id {
+            errors.check_length(
+                &field_path(path, "exclusive_start_shard_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_shard_id"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output for <code>DescribeStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStreamOutput {
     /// <p>The current status of the stream, the stream Amazon Resource Name (ARN), an array of shard objects that comprise the stream, and whether there are more shards available.</p>
     #[serde(rename = "StreamDescription")]
@@ -187,14 +388,31 @@ pub struct DescribeStreamOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStreamSummaryInput {
     /// <p>The name of the stream to describe.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
 }
 
+impl Validate for DescribeStreamSummaryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStreamSummaryOutput {
     /// <p>A <a>StreamDescriptionSummary</a> containing information about the stream.</p>
     #[serde(rename = "StreamDescriptionSummary")]
@@ -203,6 +421,7 @@ pub struct DescribeStreamSummaryOutput {
 
 /// <p>Represents the input for <a>DisableEnhancedMonitoring</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableEnhancedMonitoringInput {
     /// <p>List of shard-level metrics to disable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" disables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
@@ -212,8 +431,31 @@ pub struct DisableEnhancedMonitoringInput {
     pub stream_name: String,
 }
 
+impl Validate for DisableEnhancedMonitoringInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "shard_level_metrics"),
+            self.shard_level_metrics.len(),
+            Some(1),
+            Some(7),
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the input for <a>EnableEnhancedMonitoring</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableEnhancedMonitoringInput {
     /// <p>List of shard-level metrics to enable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" enables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
@@ -223,9 +465,31 @@ pub struct EnableEnhancedMonitoringInput {
     pub stream_name: String,
 }
 
+impl Validate for EnableEnhancedMonitoringInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "shard_level_metrics"),
+            self.shard_level_metrics.len(),
+            Some(1),
+            Some(7),
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents enhanced metrics types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnhancedMetrics {
     /// <p>List of shard-level metrics.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" enhances every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
@@ -235,7 +499,7 @@ pub struct EnhancedMetrics {
 
 /// <p>Represents the output for <a>EnableEnhancedMonitoring</a> and <a>DisableEnhancedMonitoring</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnhancedMonitoringOutput {
     /// <p>Represents the current state of the metrics that are in the enhanced state before the operation.</p>
     #[serde(rename = "CurrentShardLevelMetrics")]
@@ -266,6 +530,7 @@ pub struct ExpiredNextTokenException {
 
 /// <p>Represents the input for <a>GetRecords</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRecordsInput {
     /// <p>The maximum number of records to return. Specify a value of up to 10,000. If you specify a value that is greater than 10,000, <a>GetRecords</a> throws <code>InvalidArgumentException</code>.</p>
     #[serde(rename = "Limit")]
@@ -276,9 +541,23 @@ pub struct GetRecordsInput {
     pub shard_iterator: String,
 }
 
+impl Validate for GetRecordsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+        errors.check_length(
+            &field_path(path, "shard_iterator"),
+            self.shard_iterator.chars().count(),
+            Some(1),
+            Some(512),
+        );
+    }
+}
+
 /// <p>Represents the output for <a>GetRecords</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRecordsOutput {
     /// <p>The number of milliseconds the <a>GetRecords</a> response is from the tip of the stream, indicating how far behind current time the consumer is. A value of zero indicates that record processing is caught up, and there are no new records to process at this moment.</p>
     #[serde(rename = "MillisBehindLatest")]
@@ -295,6 +574,7 @@ pub struct GetRecordsOutput {
 
 /// <p>Represents the input for <code>GetShardIterator</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetShardIteratorInput {
     /// <p>The shard ID of the Kinesis Data Streams shard to get the iterator for.</p>
     #[serde(rename = "ShardId")]
@@ -315,9 +595,43 @@ pub struct GetShardIteratorInput {
     pub timestamp: Option<f64>,
 }
 
+impl Validate for GetShardIteratorInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "shard_id"),
+            self.shard_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "shard_id"),
+            &self.shard_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.starting_sequence_number {
+            errors.check_pattern(
+                &field_path(path, "starting_sequence_number"),
+                &value,
+                "0|([1-9]\\d{0,128})",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output for <code>GetShardIterator</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetShardIteratorOutput {
     /// <p>The position in the shard from which to start reading data records sequentially. A shard iterator specifies this position using the sequence number of a data record in a shard.</p>
     #[serde(rename = "ShardIterator")]
@@ -327,7 +641,7 @@ pub struct GetShardIteratorOutput {
 
 /// <p>The range of possible hash key values for the shard, which is a set of ordered contiguous positive integers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HashKeyRange {
     /// <p>The ending hash key of the hash key range.</p>
     #[serde(rename = "EndingHashKey")]
@@ -339,6 +653,7 @@ pub struct HashKeyRange {
 
 /// <p>Represents the input for <a>IncreaseStreamRetentionPeriod</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IncreaseStreamRetentionPeriodInput {
     /// <p>The new retention period of the stream, in hours. Must be more than the current retention period.</p>
     #[serde(rename = "RetentionPeriodHours")]
@@ -348,8 +663,24 @@ pub struct IncreaseStreamRetentionPeriodInput {
     pub stream_name: String,
 }
 
+impl Validate for IncreaseStreamRetentionPeriodInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InternalFailureException {
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -365,7 +696,7 @@ pub struct InvalidArgumentException {
 
 /// <p>The ciphertext references a key that doesn't exist or that you don't have access to.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KMSAccessDeniedException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -375,7 +706,7 @@ pub struct KMSAccessDeniedException {
 
 /// <p>The request was rejected because the specified customer master key (CMK) isn't enabled.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KMSDisabledException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -385,7 +716,7 @@ pub struct KMSDisabledException {
 
 /// <p>The request was rejected because the state of the specified resource isn't valid for this request. For more information, see <a href="http://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KMSInvalidStateException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -395,7 +726,7 @@ pub struct KMSInvalidStateException {
 
 /// <p>The request was rejected because the specified entity or resource can't be found.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KMSNotFoundException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -405,7 +736,7 @@ pub struct KMSNotFoundException {
 
 /// <p>The AWS access key ID needs a subscription for the service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KMSOptInRequired {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -415,7 +746,7 @@ pub struct KMSOptInRequired {
 
 /// <p>The request was denied due to request throttling. For more information about throttling, see <a href="http://docs.aws.amazon.com/kms/latest/developerguide/limits.html#requests-per-second">Limits</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KMSThrottlingException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -431,6 +762,7 @@ pub struct LimitExceededException {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListShardsInput {
     /// <p>Specify this parameter to indicate that you want to list the shards starting with the shard whose ID immediately follows <code>ExclusiveStartShardId</code>.</p> <p>If you don't specify this parameter, the default behavior is for <code>ListShards</code> to list the shards starting with the first one in the stream.</p> <p>You cannot specify this parameter if you specify <code>NextToken</code>.</p>
     #[serde(rename = "ExclusiveStartShardId")]
@@ -454,8 +786,51 @@ pub struct ListShardsInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for ListShardsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_shard_id {
+            errors.check_length(
+                &field_path(path, "exclusive_start_shard_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_shard_id"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(10000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1048576),
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListShardsOutput {
     /// <p><p>When the number of shards in the data stream is greater than the default value for the <code>MaxResults</code> parameter, or if you explicitly specify a value for <code>MaxResults</code> that is less than the number of shards in the data stream, the response includes a pagination token named <code>NextToken</code>. You can specify this <code>NextToken</code> value in a subsequent call to <code>ListShards</code> to list the next set of shards. For more information about the use of this pagination token when calling the <code>ListShards</code> operation, see <a>ListShardsInput$NextToken</a>.</p> <important> <p>Tokens expire after 300 seconds. When you obtain a value for <code>NextToken</code> in the response to a call to <code>ListShards</code>, you have 300 seconds to use that value. If you specify an expired token in a call to <code>ListShards</code>, you get <code>ExpiredNextTokenException</code>.</p> </important></p>
     #[serde(rename = "NextToken")]
@@ -468,6 +843,7 @@ pub struct ListShardsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamConsumersInput {
     /// <p>The maximum number of consumers that you want a single call of <code>ListStreamConsumers</code> to return.</p>
     #[serde(rename = "MaxResults")]
@@ -486,8 +862,40 @@ pub struct ListStreamConsumersInput {
     pub stream_creation_timestamp: Option<f64>,
 }
 
+impl Validate for ListStreamConsumersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(10000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1048576),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stream_arn"),
+            self.stream_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_arn"),
+            &self.stream_arn,
+            "arn:aws.*:kinesis:.*:\\d{12}:stream/\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStreamConsumersOutput {
     /// <p>An array of JSON objects. Each object represents one registered consumer.</p>
     #[serde(rename = "Consumers")]
@@ -501,6 +909,7 @@ pub struct ListStreamConsumersOutput {
 
 /// <p>Represents the input for <code>ListStreams</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamsInput {
     /// <p>The name of the stream to start the list with.</p>
     #[serde(rename = "ExclusiveStartStreamName")]
@@ -512,9 +921,30 @@ pub struct ListStreamsInput {
     pub limit: Option<i64>,
 }
 
+impl Validate for ListStreamsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_stream_name {
+            errors.check_length(
+                &field_path(path, "exclusive_start_stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_stream_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 /// <p>Represents the output for <code>ListStreams</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStreamsOutput {
     /// <p>If set to <code>true</code>, there are more streams available to list.</p>
     #[serde(rename = "HasMoreStreams")]
@@ -526,6 +956,7 @@ pub struct ListStreamsOutput {
 
 /// <p>Represents the input for <code>ListTagsForStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForStreamInput {
     /// <p>The key to use as the starting point for the list of tags. If this parameter is set, <code>ListTagsForStream</code> gets all tags that occur after <code>ExclusiveStartTagKey</code>. </p>
     #[serde(rename = "ExclusiveStartTagKey")]
@@ -540,9 +971,36 @@ pub struct ListTagsForStreamInput {
     pub stream_name: String,
 }
 
+impl Validate for ListTagsForStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_tag_key {
+            errors.check_length(
+                &field_path(path, "exclusive_start_tag_key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output for <code>ListTagsForStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForStreamOutput {
     /// <p>If set to <code>true</code>, more tags are available. To request additional tags, set <code>ExclusiveStartTagKey</code> to the key of the last tag returned.</p>
     #[serde(rename = "HasMoreTags")]
@@ -554,6 +1012,7 @@ pub struct ListTagsForStreamOutput {
 
 /// <p>Represents the input for <code>MergeShards</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MergeShardsInput {
     /// <p>The shard ID of the adjacent shard for the merge.</p>
     #[serde(rename = "AdjacentShardToMerge")]
@@ -566,6 +1025,44 @@ pub struct MergeShardsInput {
     pub stream_name: String,
 }
 
+impl Validate for MergeShardsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "adjacent_shard_to_merge"),
+            self.adjacent_shard_to_merge.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "adjacent_shard_to_merge"),
+            &self.adjacent_shard_to_merge,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "shard_to_merge"),
+            self.shard_to_merge.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "shard_to_merge"),
+            &self.shard_to_merge,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>The request rate for the stream is too high, or the requested data is too large for the available throughput. Reduce the frequency or size of your requests. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>, and <a href="http://docs.aws.amazon.com/general/latest/gr/api-retries.html">Error Retries and Exponential Backoff in AWS</a> in the <i>AWS General Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ProvisionedThroughputExceededException {
@@ -575,6 +1072,7 @@ pub struct ProvisionedThroughputExceededException {
 
 /// <p>Represents the input for <code>PutRecord</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRecordInput {
     /// <p>The data blob to put into the record, which is base64-encoded when the blob is serialized. When the data blob (the payload before base64-encoding) is added to the partition key size, the total size must not exceed the maximum record size (1 MB).</p>
     #[serde(rename = "Data")]
@@ -600,9 +1098,51 @@ pub struct PutRecordInput {
     pub stream_name: String,
 }
 
+impl Validate for PutRecordInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data"),
+            self.data.len(),
+            None,
+            Some(1048576),
+        );
+        if let Some(ref value) = self.explicit_hash_key {
+            errors.check_pattern(
+                &field_path(path, "explicit_hash_key"),
+                &value,
+                "0|([1-9]\\d{0,38})",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "partition_key"),
+            self.partition_key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.sequence_number_for_ordering {
+            errors.check_pattern(
+                &field_path(path, "sequence_number_for_ordering"),
+                &value,
+                "0|([1-9]\\d{0,128})",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output for <code>PutRecord</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRecordOutput {
     /// <p><p>The encryption type to use on the record. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
@@ -618,6 +1158,7 @@ pub struct PutRecordOutput {
 
 /// <p>A <code>PutRecords</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRecordsInput {
     /// <p>The records associated with the request.</p>
     #[serde(rename = "Records")]
@@ -627,9 +1168,35 @@ pub struct PutRecordsInput {
     pub stream_name: String,
 }
 
+impl Validate for PutRecordsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "records"),
+            self.records.len(),
+            Some(1),
+            Some(500),
+        );
+        for (i, item) in self.records.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "records"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p> <code>PutRecords</code> results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRecordsOutput {
     /// <p><p>The encryption type used on the records. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
@@ -646,6 +1213,7 @@ pub struct PutRecordsOutput {
 
 /// <p>Represents the output for <code>PutRecords</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRecordsRequestEntry {
     /// <p>The data blob to put into the record, which is base64-encoded when the blob is serialized. When the data blob (the payload before base64-encoding) is added to the partition key size, the total size must not exceed the maximum record size (1 MB).</p>
     #[serde(rename = "Data")]
@@ -664,9 +1232,33 @@ pub struct PutRecordsRequestEntry {
     pub partition_key: String,
 }
 
+impl Validate for PutRecordsRequestEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data"),
+            self.data.len(),
+            None,
+            Some(1048576),
+        );
+        if let Some(ref value) = self.explicit_hash_key {
+            errors.check_pattern(
+                &field_path(path, "explicit_hash_key"),
+                &value,
+                "0|([1-9]\\d{0,38})",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "partition_key"),
+            self.partition_key.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Represents the result of an individual record from a <code>PutRecords</code> request. A record that is successfully added to a stream includes <code>SequenceNumber</code> and <code>ShardId</code> in the result. A record that fails to be added to the stream includes <code>ErrorCode</code> and <code>ErrorMessage</code> in the result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutRecordsResultEntry {
     /// <p>The error code for an individual record result. <code>ErrorCodes</code> can be either <code>ProvisionedThroughputExceededException</code> or <code>InternalFailure</code>.</p>
     #[serde(rename = "ErrorCode")]
@@ -688,7 +1280,7 @@ pub struct PutRecordsResultEntry {
 
 /// <p>The unit of data of the Kinesis data stream, which is composed of a sequence number, a partition key, and a data blob.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Record {
     /// <p>The approximate time that the record was inserted into the stream.</p>
     #[serde(rename = "ApproximateArrivalTimestamp")]
@@ -715,6 +1307,7 @@ pub struct Record {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterStreamConsumerInput {
     /// <p>For a given Kinesis data stream, each consumer must have a unique name. However, consumer names don't have to be unique across data streams.</p>
     #[serde(rename = "ConsumerName")]
@@ -724,8 +1317,35 @@ pub struct RegisterStreamConsumerInput {
     pub stream_arn: String,
 }
 
+impl Validate for RegisterStreamConsumerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "consumer_name"),
+            self.consumer_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "consumer_name"),
+            &self.consumer_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "stream_arn"),
+            self.stream_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_arn"),
+            &self.stream_arn,
+            "arn:aws.*:kinesis:.*:\\d{12}:stream/\\S+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterStreamConsumerOutput {
     /// <p>An object that represents the details of the consumer you registered. When you register a consumer, it gets an ARN that is generated by Kinesis Data Streams.</p>
     #[serde(rename = "Consumer")]
@@ -734,6 +1354,7 @@ pub struct RegisterStreamConsumerOutput {
 
 /// <p>Represents the input for <code>RemoveTagsFromStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromStreamInput {
     /// <p>The name of the stream.</p>
     #[serde(rename = "StreamName")]
@@ -743,9 +1364,35 @@ pub struct RemoveTagsFromStreamInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for RemoveTagsFromStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// <p>The resource is not available for this operation. For successful operation, the resource must be in the <code>ACTIVE</code> state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceInUseException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -755,7 +1402,7 @@ pub struct ResourceInUseException {
 
 /// <p>The requested resource could not be found. The stream might not be specified correctly.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceNotFoundException {
     /// <p>A message that provides information about the error.</p>
     #[serde(rename = "message")]
@@ -765,7 +1412,7 @@ pub struct ResourceNotFoundException {
 
 /// <p>The range of possible sequence numbers for the shard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SequenceNumberRange {
     /// <p>The ending sequence number for the range. Shards that are in the OPEN state have an ending sequence number of <code>null</code>.</p>
     #[serde(rename = "EndingSequenceNumber")]
@@ -778,7 +1425,7 @@ pub struct SequenceNumberRange {
 
 /// <p>A uniquely identified group of data records in a Kinesis data stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Shard {
     /// <p>The shard ID of the shard adjacent to the shard's parent.</p>
     #[serde(rename = "AdjacentParentShardId")]
@@ -801,6 +1448,7 @@ pub struct Shard {
 
 /// <p>Represents the input for <code>SplitShard</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SplitShardInput {
     /// <p>A hash key value for the starting hash key of one of the child shards created by the split. The hash key range for a given shard constitutes a set of ordered contiguous positive integers. The value for <code>NewStartingHashKey</code> must be in the range of hash keys being mapped into the shard. The <code>NewStartingHashKey</code> hash key value and all higher hash key values in hash key range are distributed to one of the child shards. All the lower hash key values in the range are distributed to the other child shard.</p>
     #[serde(rename = "NewStartingHashKey")]
@@ -813,7 +1461,40 @@ pub struct SplitShardInput {
     pub stream_name: String,
 }
 
+impl Validate for SplitShardInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "new_starting_hash_key"),
+            &self.new_starting_hash_key,
+            "0|([1-9]\\d{0,38})",
+        );
+        errors.check_length(
+            &field_path(path, "shard_to_split"),
+            self.shard_to_split.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "shard_to_split"),
+            &self.shard_to_split,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartStreamEncryptionInput {
     /// <p>The encryption type to use. The only valid value is <code>KMS</code>.</p>
     #[serde(rename = "EncryptionType")]
@@ -826,7 +1507,30 @@ pub struct StartStreamEncryptionInput {
     pub stream_name: String,
 }
 
+impl Validate for StartStreamEncryptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartingPosition {
     #[serde(rename = "SequenceNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -838,7 +1542,20 @@ pub struct StartingPosition {
     pub type_: String,
 }
 
+impl Validate for StartingPosition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sequence_number {
+            errors.check_pattern(
+                &field_path(path, "sequence_number"),
+                &value,
+                "0|([1-9]\\d{0,128})",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopStreamEncryptionInput {
     /// <p>The encryption type. The only valid value is <code>KMS</code>.</p>
     #[serde(rename = "EncryptionType")]
@@ -851,9 +1568,31 @@ pub struct StopStreamEncryptionInput {
     pub stream_name: String,
 }
 
+impl Validate for StopStreamEncryptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output for <a>DescribeStream</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamDescription {
     /// <p><p>The server-side encryption type used on the stream. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
@@ -891,7 +1630,7 @@ pub struct StreamDescription {
 
 /// <p>Represents the output for <a>DescribeStreamSummary</a> </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamDescriptionSummary {
     /// <p>The number of enhanced fan-out consumers registered with the stream.</p>
     #[serde(rename = "ConsumerCount")]
@@ -930,7 +1669,7 @@ pub struct StreamDescriptionSummary {
 
 /// <p>After you call <a>SubscribeToShard</a>, Kinesis Data Streams sends events of this type to your consumer. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscribeToShardEvent {
     /// <p>Use this as <code>StartingSequenceNumber</code> in the next call to <a>SubscribeToShard</a>.</p>
     #[serde(rename = "ContinuationSequenceNumber")]
@@ -944,7 +1683,7 @@ pub struct SubscribeToShardEvent {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscribeToShardEventStream {
     #[serde(rename = "InternalFailureException")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -978,6 +1717,7 @@ pub struct SubscribeToShardEventStream {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubscribeToShardInput {
     /// <p>For this parameter, use the value you obtained when you called <a>RegisterStreamConsumer</a>.</p>
     #[serde(rename = "ConsumerARN")]
@@ -989,8 +1729,33 @@ pub struct SubscribeToShardInput {
     pub starting_position: StartingPosition,
 }
 
+impl Validate for SubscribeToShardInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "consumer_arn"),
+            self.consumer_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "consumer_arn"), &self.consumer_arn, "^(arn):aws.*:kinesis:.*:\\d{12}:.*stream\\/[a-zA-Z0-9_.-]+\\/consumer\\/[a-zA-Z0-9_.-]+:[0-9]+");
+        errors.check_length(
+            &field_path(path, "shard_id"),
+            self.shard_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "shard_id"),
+            &self.shard_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.starting_position
+            .validate_at(&field_path(path, "starting_position"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscribeToShardOutput {
     /// <p>The event stream that your consumer can use to read records from the shard.</p>
     #[serde(rename = "EventStream")]
@@ -999,7 +1764,7 @@ pub struct SubscribeToShardOutput {
 
 /// <p>Metadata assigned to the stream, consisting of a key-value pair.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Tag {
     /// <p>A unique identifier for the tag. Maximum length: 128 characters. Valid characters: Unicode letters, digits, white space, _ . / = + - % @</p>
     #[serde(rename = "Key")]
@@ -1011,6 +1776,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateShardCountInput {
     /// <p>The scaling type. Uniform scaling creates shards of equal size.</p>
     #[serde(rename = "ScalingType")]
@@ -1023,8 +1789,30 @@ pub struct UpdateShardCountInput {
     pub target_shard_count: i64,
 }
 
+impl Validate for UpdateShardCountInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "target_shard_count"),
+            self.target_shard_count,
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateShardCountOutput {
     /// <p>The current number of shards.</p>
     #[serde(rename = "CurrentShardCount")]
@@ -1069,7 +1857,12 @@ impl AddTagsToStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AddTagsToStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1115,7 +1908,12 @@ impl CreateStreamError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1175,7 +1973,12 @@ impl DecreaseStreamRetentionPeriodError {
                         DecreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1221,7 +2024,12 @@ impl DeleteStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1272,7 +2080,12 @@ impl DeregisterStreamConsumerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1307,7 +2120,12 @@ impl DescribeLimitsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(DescribeLimitsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1345,7 +2163,12 @@ impl DescribeStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1395,7 +2218,12 @@ impl DescribeStreamConsumerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1437,7 +2265,12 @@ impl DescribeStreamSummaryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1494,7 +2327,12 @@ impl DisableEnhancedMonitoringError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1553,7 +2391,12 @@ impl EnableEnhancedMonitoringError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1636,7 +2479,12 @@ impl GetRecordsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1690,7 +2538,12 @@ impl GetShardIteratorError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1750,7 +2603,12 @@ impl IncreaseStreamRetentionPeriodError {
                         IncreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1806,7 +2664,12 @@ impl ListShardsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListShardsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1867,7 +2730,12 @@ impl ListStreamConsumersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1904,7 +2772,12 @@ impl ListStreamsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(ListStreamsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1947,7 +2820,12 @@ impl ListTagsForStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1997,7 +2875,12 @@ impl MergeShardsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(MergeShardsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2075,7 +2958,12 @@ impl PutRecordError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2158,7 +3046,12 @@ impl PutRecordsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutRecordsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2222,7 +3115,12 @@ impl RegisterStreamConsumerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2277,7 +3175,12 @@ impl RemoveTagsFromStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2328,7 +3231,12 @@ impl SplitShardError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(SplitShardError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2419,7 +3327,12 @@ impl StartStreamEncryptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2480,7 +3393,12 @@ impl StopStreamEncryptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2531,7 +3449,12 @@ impl SubscribeToShardError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(SubscribeToShardError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2582,7 +3505,12 @@ impl UpdateShardCountError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateShardCountError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2756,697 +3684,1357 @@ pub trait Kinesis {
         input: UpdateShardCountInput,
     ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError>;
 }
-/// A client for the Kinesis API.
-#[derive(Clone)]
-pub struct KinesisClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl KinesisClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> KinesisClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> KinesisClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> KinesisClient {
-        KinesisClient { client, region }
-    }
-}
-
-impl Kinesis for KinesisClient {
-    /// <p>Adds or updates tags for the specified Kinesis data stream. Each time you invoke this operation, you can specify up to 10 tags. If you want to add more than 10 tags to your stream, you can invoke this operation multiple times. In total, each stream can have up to 50 tags.</p> <p>If tags have already been assigned to the stream, <code>AddTagsToStream</code> overwrites any existing tags that correspond to the specified tag keys.</p> <p> <a>AddTagsToStream</a> has a limit of five transactions per second per account.</p>
+const _: Option<&dyn Kinesis> = None;
+impl<'a, T: Kinesis + ?Sized> Kinesis for &'a T {
     fn add_tags_to_stream(
         &self,
         input: AddTagsToStreamInput,
     ) -> RusotoFuture<(), AddTagsToStreamError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.AddTagsToStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags_to_stream(input)
     }
-
-    /// <p>Creates a Kinesis data stream. A stream captures and transports data records that are continuously emitted from different data sources or <i>producers</i>. Scale-out within a stream is explicitly supported by means of shards, which are uniquely identified groups of data records in a stream.</p> <p>You specify and control the number of shards that a stream is composed of. Each shard can support reads up to five transactions per second, up to a maximum data read total of 2 MB per second. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second. If the amount of data input increases or decreases, you can add or remove shards.</p> <p>The stream name identifies the stream. The name is scoped to the AWS account used by the application. It is also scoped by AWS Region. That is, two streams in two different accounts can have the same name, and two streams in the same account, but in two different Regions, can have the same name.</p> <p> <code>CreateStream</code> is an asynchronous operation. Upon receiving a <code>CreateStream</code> request, Kinesis Data Streams immediately returns and sets the stream status to <code>CREATING</code>. After the stream is created, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. You should perform read and write operations only on an <code>ACTIVE</code> stream. </p> <p>You receive a <code>LimitExceededException</code> when making a <code>CreateStream</code> request when you try to do one of the following:</p> <ul> <li> <p>Have more than five streams in the <code>CREATING</code> state at any point in time.</p> </li> <li> <p>Create more shards than are authorized for your account.</p> </li> </ul> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>You can use <code>DescribeStream</code> to check the stream status, which is returned in <code>StreamStatus</code>.</p> <p> <a>CreateStream</a> has a limit of five transactions per second per account.</p>
     fn create_stream(&self, input: CreateStreamInput) -> RusotoFuture<(), CreateStreamError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.CreateStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_stream(input)
     }
-
-    /// <p>Decreases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The minimum value of a stream's retention period is 24 hours.</p> <p>This operation may result in lost data. For example, if the stream's retention period is 48 hours and is decreased to 24 hours, any data already in the stream that is older than 24 hours is inaccessible.</p>
     fn decrease_stream_retention_period(
         &self,
         input: DecreaseStreamRetentionPeriodInput,
     ) -> RusotoFuture<(), DecreaseStreamRetentionPeriodError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Kinesis_20131202.DecreaseStreamRetentionPeriod",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DecreaseStreamRetentionPeriodError::from_response(response))
-                }))
-            }
-        })
+        (**self).decrease_stream_retention_period(input)
     }
-
-    /// <p>Deletes a Kinesis data stream and all its shards and data. You must shut down any applications that are operating on the stream before you delete the stream. If an application attempts to operate on a deleted stream, it receives the exception <code>ResourceNotFoundException</code>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can delete it. After a <code>DeleteStream</code> request, the specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> <p> <b>Note:</b> Kinesis Data Streams might continue to accept data read and write operations, such as <a>PutRecord</a>, <a>PutRecords</a>, and <a>GetRecords</a>, on a stream in the <code>DELETING</code> state until the stream deletion is complete.</p> <p>When you delete a stream, any shards in that stream are also deleted, and any tags are dissociated from the stream.</p> <p>You can use the <a>DescribeStream</a> operation to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <a>DeleteStream</a> has a limit of five transactions per second per account.</p>
     fn delete_stream(&self, input: DeleteStreamInput) -> RusotoFuture<(), DeleteStreamError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DeleteStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_stream(input)
     }
-
-    /// <p>To deregister a consumer, provide its ARN. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to deregister, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its name and ARN.</p> <p>This operation has a limit of five transactions per second per account.</p>
     fn deregister_stream_consumer(
         &self,
         input: DeregisterStreamConsumerInput,
     ) -> RusotoFuture<(), DeregisterStreamConsumerError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DeregisterStreamConsumer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterStreamConsumerError::from_response(response))
-                }))
-            }
-        })
+        (**self).deregister_stream_consumer(input)
     }
-
-    /// <p>Describes the shard limits and usage for the account.</p> <p>If you update your account limits, the old limits might be returned for a few minutes.</p> <p>This operation has a limit of one transaction per second per account.</p>
     fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DescribeLimits");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLimitsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLimitsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_limits()
     }
-
-    /// <p>Describes the specified Kinesis data stream.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), creation time, enhanced metric configuration, and shard map. The shard map is an array of shard objects. For each shard object, there is the hash key and sequence number ranges that the shard spans, and the IDs of any earlier shards that played in a role in creating the shard. Every record ingested in the stream is identified by a sequence number, which is assigned when the record is put into the stream.</p> <p>You can limit the number of shards returned by each call. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-retrieve-shards.html">Retrieving Shards from a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>There are no guarantees about the chronological order shards returned. To process shards in chronological order, use the ID of the parent shard to track the lineage to the oldest shard.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
     fn describe_stream(
         &self,
         input: DescribeStreamInput,
     ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DescribeStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStreamOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_stream(input)
     }
-
-    /// <p>To get the description of a registered consumer, provide the ARN of the consumer. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to describe, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream.</p> <p>This operation has a limit of 20 transactions per second per account.</p>
     fn describe_stream_consumer(
         &self,
         input: DescribeStreamConsumerInput,
     ) -> RusotoFuture<DescribeStreamConsumerOutput, DescribeStreamConsumerError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamConsumer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStreamConsumerOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeStreamConsumerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_stream_consumer(input)
     }
-
-    /// <p>Provides a summarized description of the specified Kinesis data stream without the shard list.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), status, record retention period, approximate creation time, monitoring, encryption details, and open shard count. </p>
     fn describe_stream_summary(
         &self,
         input: DescribeStreamSummaryInput,
     ) -> RusotoFuture<DescribeStreamSummaryOutput, DescribeStreamSummaryError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamSummary");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStreamSummaryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeStreamSummaryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_stream_summary(input)
     }
-
-    /// <p>Disables enhanced monitoring.</p>
     fn disable_enhanced_monitoring(
         &self,
         input: DisableEnhancedMonitoringInput,
     ) -> RusotoFuture<EnhancedMonitoringOutput, DisableEnhancedMonitoringError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.DisableEnhancedMonitoring");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnhancedMonitoringOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisableEnhancedMonitoringError::from_response(response))
-                }))
-            }
-        })
+        (**self).disable_enhanced_monitoring(input)
     }
-
-    /// <p>Enables enhanced Kinesis data stream monitoring for shard-level metrics.</p>
     fn enable_enhanced_monitoring(
         &self,
         input: EnableEnhancedMonitoringInput,
     ) -> RusotoFuture<EnhancedMonitoringOutput, EnableEnhancedMonitoringError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.EnableEnhancedMonitoring");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnhancedMonitoringOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(EnableEnhancedMonitoringError::from_response(response))
-                }))
-            }
-        })
+        (**self).enable_enhanced_monitoring(input)
     }
-
-    /// <p>Gets data records from a Kinesis data stream's shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading data records sequentially. If there are no records available in the portion of the shard that the iterator points to, <a>GetRecords</a> returns an empty list. It might take multiple calls to get to a portion of the shard that contains records.</p> <p>You can scale by provisioning multiple shards per stream while considering service limits (for more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>). Your application should have one thread per shard, each reading continuously from its stream. To read from a stream continually, call <a>GetRecords</a> in a loop. Use <a>GetShardIterator</a> to get the shard iterator to specify in the first <a>GetRecords</a> call. <a>GetRecords</a> returns a new shard iterator in <code>NextShardIterator</code>. Specify the shard iterator returned in <code>NextShardIterator</code> in subsequent calls to <a>GetRecords</a>. If the shard has been closed, the shard iterator can't return more data and <a>GetRecords</a> returns <code>null</code> in <code>NextShardIterator</code>. You can terminate the loop when the shard is closed, or when the shard iterator reaches the record with the sequence number or other attribute that marks it as the last record to process.</p> <p>Each data record can be up to 1 MiB in size, and each shard can read up to 2 MiB per second. You can ensure that your calls don't exceed the maximum supported size or throughput by using the <code>Limit</code> parameter to specify the maximum number of records that <a>GetRecords</a> can return. Consider your average record size when determining this limit. The maximum number of records that can be returned per call is 10,000.</p> <p>The size of the data returned by <a>GetRecords</a> varies depending on the utilization of the shard. The maximum size of data that <a>GetRecords</a> can return is 10 MiB. If a call returns this amount of data, subsequent calls made within the next 5 seconds throw <code>ProvisionedThroughputExceededException</code>. If there is insufficient provisioned throughput on the stream, subsequent calls made within the next 1 second throw <code>ProvisionedThroughputExceededException</code>. <a>GetRecords</a> doesn't return any data when it throws an exception. For this reason, we recommend that you wait 1 second between calls to <a>GetRecords</a>. However, it's possible that the application will get exceptions for longer than 1 second.</p> <p>To detect whether the application is falling behind in processing, you can use the <code>MillisBehindLatest</code> response attribute. You can also monitor the stream using CloudWatch metrics and other mechanisms (see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring.html">Monitoring</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>).</p> <p>Each Amazon Kinesis record includes a value, <code>ApproximateArrivalTimestamp</code>, that is set when a stream successfully receives and stores a record. This is commonly referred to as a server-side time stamp, whereas a client-side time stamp is set when a data producer creates or sends the record to a stream (a data producer is any data source putting data records into a stream, for example with <a>PutRecords</a>). The time stamp has millisecond precision. There are no guarantees about the time stamp accuracy, or that the time stamp is always increasing. For example, records in a shard or across a stream might have time stamps that are out of order.</p> <p>This operation has a limit of five transactions per second per account.</p>
     fn get_records(
         &self,
         input: GetRecordsInput,
     ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.GetRecords");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRecordsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRecordsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_records(input)
     }
-
-    /// <p>Gets an Amazon Kinesis shard iterator. A shard iterator expires 5 minutes after it is returned to the requester.</p> <p>A shard iterator specifies the shard position from which to start reading data records sequentially. The position is specified using the sequence number of a data record in a shard. A sequence number is the identifier associated with every record ingested in the stream, and is assigned when a record is put into the stream. Each stream has one or more shards.</p> <p>You must specify the shard iterator type. For example, you can set the <code>ShardIteratorType</code> parameter to read exactly from the position denoted by a specific sequence number by using the <code>AT_SEQUENCE_NUMBER</code> shard iterator type. Alternatively, the parameter can read right after the sequence number by using the <code>AFTER_SEQUENCE_NUMBER</code> shard iterator type, using sequence numbers returned by earlier calls to <a>PutRecord</a>, <a>PutRecords</a>, <a>GetRecords</a>, or <a>DescribeStream</a>. In the request, you can specify the shard iterator type <code>AT_TIMESTAMP</code> to read records from an arbitrary point in time, <code>TRIM_HORIZON</code> to cause <code>ShardIterator</code> to point to the last untrimmed record in the shard in the system (the oldest data record in the shard), or <code>LATEST</code> so that you always read the most recent data in the shard. </p> <p>When you read repeatedly from a stream, use a <a>GetShardIterator</a> request to get the first shard iterator for use in your first <a>GetRecords</a> request and for subsequent reads use the shard iterator returned by the <a>GetRecords</a> request in <code>NextShardIterator</code>. A new shard iterator is returned by every <a>GetRecords</a> request in <code>NextShardIterator</code>, which you use in the <code>ShardIterator</code> parameter of the next <a>GetRecords</a> request. </p> <p>If a <a>GetShardIterator</a> request is made too often, you receive a <code>ProvisionedThroughputExceededException</code>. For more information about throughput limits, see <a>GetRecords</a>, and <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the shard is closed, <a>GetShardIterator</a> returns a valid iterator for the last sequence number of the shard. A shard can be closed as a result of using <a>SplitShard</a> or <a>MergeShards</a>.</p> <p> <a>GetShardIterator</a> has a limit of five transactions per second per account per open shard.</p>
     fn get_shard_iterator(
         &self,
         input: GetShardIteratorInput,
     ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.GetShardIterator");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetShardIteratorOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetShardIteratorError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_shard_iterator(input)
     }
-
-    /// <p>Increases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The maximum value of a stream's retention period is 168 hours (7 days).</p> <p>If you choose a longer stream retention period, this operation increases the time period during which records that have not yet expired are accessible. However, it does not make previous, expired data (older than the stream's previous retention period) accessible after the operation has been called. For example, if a stream's retention period is set to 24 hours and is increased to 168 hours, any data that is older than 24 hours remains inaccessible to consumer applications.</p>
     fn increase_stream_retention_period(
         &self,
         input: IncreaseStreamRetentionPeriodInput,
     ) -> RusotoFuture<(), IncreaseStreamRetentionPeriodError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Kinesis_20131202.IncreaseStreamRetentionPeriod",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(IncreaseStreamRetentionPeriodError::from_response(response))
-                }))
-            }
-        })
+        (**self).increase_stream_retention_period(input)
     }
-
-    /// <p><p>Lists the shards in a stream and provides information about each shard. This operation has a limit of 100 transactions per second per data stream.</p> <important> <p>This API is a new operation that is used by the Amazon Kinesis Client Library (KCL). If you have a fine-grained IAM policy that only allows specific operations, you must update your policy to allow calls to this API. For more information, see <a href="https://docs.aws.amazon.com/streams/latest/dev/controlling-access.html">Controlling Access to Amazon Kinesis Data Streams Resources Using IAM</a>.</p> </important></p>
     fn list_shards(
         &self,
         input: ListShardsInput,
     ) -> RusotoFuture<ListShardsOutput, ListShardsError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.ListShards");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListShardsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListShardsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_shards(input)
     }
-
-    /// <p>Lists the consumers registered to receive data from a stream using enhanced fan-out, and provides information about each consumer.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
     fn list_stream_consumers(
         &self,
         input: ListStreamConsumersInput,
     ) -> RusotoFuture<ListStreamConsumersOutput, ListStreamConsumersError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.ListStreamConsumers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListStreamConsumersOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListStreamConsumersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_stream_consumers(input)
     }
-
-    /// <p>Lists your Kinesis data streams.</p> <p>The number of streams may be too large to return from a single call to <code>ListStreams</code>. You can limit the number of returned streams using the <code>Limit</code> parameter. If you do not specify a value for the <code>Limit</code> parameter, Kinesis Data Streams uses the default limit, which is currently 10.</p> <p>You can detect if there are more streams available to list by using the <code>HasMoreStreams</code> flag from the returned output. If there are more streams available, you can request more streams by using the name of the last stream returned by the <code>ListStreams</code> request in the <code>ExclusiveStartStreamName</code> parameter in a subsequent request to <code>ListStreams</code>. The group of stream names returned by the subsequent request is then added to the list. You can continue this process until all the stream names have been collected in the list. </p> <p> <a>ListStreams</a> has a limit of five transactions per second per account.</p>
     fn list_streams(
         &self,
         input: ListStreamsInput,
     ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.ListStreams");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListStreamsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListStreamsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_streams(input)
     }
-
-    /// <p>Lists the tags for the specified Kinesis data stream. This operation has a limit of five transactions per second per account.</p>
     fn list_tags_for_stream(
         &self,
         input: ListTagsForStreamInput,
     ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.ListTagsForStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForStreamOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsForStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags_for_stream(input)
     }
-
-    /// <p>Merges two adjacent shards in a Kinesis data stream and combines them into a single shard to reduce the stream's capacity to ingest and transport data. Two shards are considered adjacent if the union of the hash key ranges for the two shards form a contiguous set with no gaps. For example, if you have two shards, one with a hash key range of 276...381 and the other with a hash key range of 382...454, then you could merge these two shards into a single shard that would have a hash key range of 276...454. After the merge, the single child shard receives data for all hash key values covered by the two parent shards.</p> <p> <code>MergeShards</code> is called when there is a need to reduce the overall capacity of a stream because of excess capacity that is not being used. You must specify the shard to be merged and the adjacent shard for a stream. For more information about merging shards, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-merge.html">Merge Two Shards</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can call <code>MergeShards</code>. If a stream is in the <code>CREATING</code>, <code>UPDATING</code>, or <code>DELETING</code> state, <code>MergeShards</code> returns a <code>ResourceInUseException</code>. If the specified stream does not exist, <code>MergeShards</code> returns a <code>ResourceNotFoundException</code>. </p> <p>You can use <a>DescribeStream</a> to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <code>MergeShards</code> is an asynchronous operation. Upon receiving a <code>MergeShards</code> request, Amazon Kinesis Data Streams immediately returns a response and sets the <code>StreamStatus</code> to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the <code>StreamStatus</code> to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You use <a>DescribeStream</a> to determine the shard IDs that are specified in the <code>MergeShards</code> request. </p> <p>If you try to operate on too many streams in parallel using <a>CreateStream</a>, <a>DeleteStream</a>, <code>MergeShards</code>, or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>MergeShards</code> has a limit of five transactions per second per account.</p>
     fn merge_shards(&self, input: MergeShardsInput) -> RusotoFuture<(), MergeShardsError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.MergeShards");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(MergeShardsError::from_response(response))),
-                )
-            }
-        })
+        (**self).merge_shards(input)
     }
-
-    /// <p>Writes a single data record into an Amazon Kinesis data stream. Call <code>PutRecord</code> to send data into the stream for real-time ingestion and subsequent processing, one record at a time. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; a partition key; and the data blob itself.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams to distribute data across shards. Kinesis Data Streams segregates the data records that belong to a stream into multiple shards, using the partition key associated with each data record to determine the shard to which a given data record belongs.</p> <p>Partition keys are Unicode strings, with a maximum length limit of 256 characters for each key. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards using the hash key ranges of the shards. You can override hashing the partition key to determine the shard by explicitly specifying a hash value using the <code>ExplicitHashKey</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p> <code>PutRecord</code> returns the shard ID of where the data record was placed and the sequence number that was assigned to the data record.</p> <p>Sequence numbers increase over time and are specific to a shard within a stream, not across all shards within a stream. To guarantee strictly increasing ordering, write serially to a shard and use the <code>SequenceNumberForOrdering</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If a <code>PutRecord</code> request cannot be processed because of insufficient provisioned throughput on the shard involved in the request, <code>PutRecord</code> throws <code>ProvisionedThroughputExceededException</code>. </p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
     fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.PutRecord");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutRecordError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_record(input)
     }
-
-    /// <p>Writes multiple data records into a Kinesis data stream in a single call (also referred to as a <code>PutRecords</code> request). Use this operation to send data into the stream for data ingestion and processing. </p> <p>Each <code>PutRecords</code> request can support up to 500 records. Each record in the request can be as large as 1 MB, up to a limit of 5 MB for the entire request, including partition keys. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; and an array of request <code>Records</code>, with each record in the array requiring a partition key and data blob. The record size limit applies to the total size of the partition key and data blob.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams as input to a hash function that maps the partition key and associated data to a specific shard. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards. As a result of this hashing mechanism, all data records with the same partition key map to the same shard within the stream. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>Each record in the <code>Records</code> array may include an optional parameter, <code>ExplicitHashKey</code>, which overrides the partition key to shard mapping. This parameter allows a data producer to determine explicitly the shard where the record is stored. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>The <code>PutRecords</code> response includes an array of response <code>Records</code>. Each record in the response array directly correlates with a record in the request array using natural ordering, from the top to the bottom of the request and response. The response <code>Records</code> array always includes the same number of records as the request array.</p> <p>The response <code>Records</code> array includes both successfully and unsuccessfully processed records. Kinesis Data Streams attempts to process all records in each <code>PutRecords</code> request. A single record failure does not stop the processing of subsequent records.</p> <p>A successfully processed record includes <code>ShardId</code> and <code>SequenceNumber</code> values. The <code>ShardId</code> parameter identifies the shard in the stream where the record is stored. The <code>SequenceNumber</code> parameter is an identifier assigned to the put record, unique to all records in the stream.</p> <p>An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error and can be one of the following values: <code>ProvisionedThroughputExceededException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the <code>ProvisionedThroughputExceededException</code> exception including the account ID, stream name, and shard ID of the record that was throttled. For more information about partially successful responses, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-add-data-to-stream.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
     fn put_records(
         &self,
         input: PutRecordsInput,
     ) -> RusotoFuture<PutRecordsOutput, PutRecordsError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.PutRecords");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutRecordsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutRecordsError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_records(input)
     }
-
-    /// <p>Registers a consumer with a Kinesis data stream. When you use this operation, the consumer you register can read data from the stream at a rate of up to 2 MiB per second. This rate is unaffected by the total number of consumers that read from the same stream.</p> <p>You can register up to 5 consumers per stream. A given consumer can only be registered with one stream.</p> <p>This operation has a limit of five transactions per second per account.</p>
     fn register_stream_consumer(
         &self,
         input: RegisterStreamConsumerInput,
     ) -> RusotoFuture<RegisterStreamConsumerOutput, RegisterStreamConsumerError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.RegisterStreamConsumer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterStreamConsumerOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RegisterStreamConsumerError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).register_stream_consumer(input)
     }
-
-    /// <p>Removes tags from the specified Kinesis data stream. Removed tags are deleted and cannot be recovered after this operation successfully completes.</p> <p>If you specify a tag that does not exist, it is ignored.</p> <p> <a>RemoveTagsFromStream</a> has a limit of five transactions per second per account.</p>
     fn remove_tags_from_stream(
         &self,
         input: RemoveTagsFromStreamInput,
     ) -> RusotoFuture<(), RemoveTagsFromStreamError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.RemoveTagsFromStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveTagsFromStreamError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).remove_tags_from_stream(input)
     }
-
-    /// <p>Splits a shard into two new shards in the Kinesis data stream, to increase the stream's capacity to ingest and transport data. <code>SplitShard</code> is called when there is a need to increase the overall capacity of a stream because of an expected increase in the volume of data records being ingested. </p> <p>You can also use <code>SplitShard</code> when a shard appears to be approaching its maximum utilization; for example, the producers sending data into the specific shard are suddenly sending more than previously anticipated. You can also call <code>SplitShard</code> to increase stream capacity, so that more Kinesis Data Streams applications can simultaneously read data from the stream for real-time processing. </p> <p>You must specify the shard to be split and the new hash key, which is the position in the shard where the shard gets split in two. In many cases, the new hash key might be the average of the beginning and ending hash key, but it can be any hash key value in the range being mapped into the shard. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-split.html">Split a Shard</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>You can use <a>DescribeStream</a> to determine the shard ID and hash key values for the <code>ShardToSplit</code> and <code>NewStartingHashKey</code> parameters that are specified in the <code>SplitShard</code> request.</p> <p> <code>SplitShard</code> is an asynchronous operation. Upon receiving a <code>SplitShard</code> request, Kinesis Data Streams immediately returns a response and sets the stream status to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You can use <code>DescribeStream</code> to check the status of the stream, which is returned in <code>StreamStatus</code>. If the stream is in the <code>ACTIVE</code> state, you can call <code>SplitShard</code>. If a stream is in <code>CREATING</code> or <code>UPDATING</code> or <code>DELETING</code> states, <code>DescribeStream</code> returns a <code>ResourceInUseException</code>.</p> <p>If the specified stream does not exist, <code>DescribeStream</code> returns a <code>ResourceNotFoundException</code>. If you try to create more shards than are authorized for your account, you receive a <code>LimitExceededException</code>. </p> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>If you try to operate on too many streams simultaneously using <a>CreateStream</a>, <a>DeleteStream</a>, <a>MergeShards</a>, and/or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>SplitShard</code> has a limit of five transactions per second per account.</p>
     fn split_shard(&self, input: SplitShardInput) -> RusotoFuture<(), SplitShardError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.SplitShard");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SplitShardError::from_response(response))),
-                )
-            }
-        })
+        (**self).split_shard(input)
     }
-
-    /// <p>Enables or updates server-side encryption using an AWS KMS key for a specified stream. </p> <p>Starting encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Updating or applying encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, encryption begins for records written to the stream. </p> <p>API Limits: You can successfully apply a new AWS KMS key for server-side encryption 25 times in a rolling 24-hour period.</p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are encrypted. After you enable encryption, you can verify that encryption is applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
     fn start_stream_encryption(
         &self,
         input: StartStreamEncryptionInput,
     ) -> RusotoFuture<(), StartStreamEncryptionError> {
-        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Kinesis_20131202.StartStreamEncryption");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
+        (**self).start_stream_encryption(input)
+    }
+    fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> RusotoFuture<(), StopStreamEncryptionError> {
+        (**self).stop_stream_encryption(input)
+    }
+    fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> RusotoFuture<SubscribeToShardOutput, SubscribeToShardError> {
+        (**self).subscribe_to_shard(input)
+    }
+    fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError> {
+        (**self).update_shard_count(input)
+    }
+}
+impl<T: Kinesis + ?Sized> Kinesis for Box<T> {
+    fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> RusotoFuture<(), AddTagsToStreamError> {
+        (**self).add_tags_to_stream(input)
+    }
+    fn create_stream(&self, input: CreateStreamInput) -> RusotoFuture<(), CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), DecreaseStreamRetentionPeriodError> {
+        (**self).decrease_stream_retention_period(input)
+    }
+    fn delete_stream(&self, input: DeleteStreamInput) -> RusotoFuture<(), DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> RusotoFuture<(), DeregisterStreamConsumerError> {
+        (**self).deregister_stream_consumer(input)
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        (**self).describe_limits()
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> RusotoFuture<DescribeStreamConsumerOutput, DescribeStreamConsumerError> {
+        (**self).describe_stream_consumer(input)
+    }
+    fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> RusotoFuture<DescribeStreamSummaryOutput, DescribeStreamSummaryError> {
+        (**self).describe_stream_summary(input)
+    }
+    fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, DisableEnhancedMonitoringError> {
+        (**self).disable_enhanced_monitoring(input)
+    }
+    fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, EnableEnhancedMonitoringError> {
+        (**self).enable_enhanced_monitoring(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), IncreaseStreamRetentionPeriodError> {
+        (**self).increase_stream_retention_period(input)
+    }
+    fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> RusotoFuture<ListShardsOutput, ListShardsError> {
+        (**self).list_shards(input)
+    }
+    fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> RusotoFuture<ListStreamConsumersOutput, ListStreamConsumersError> {
+        (**self).list_stream_consumers(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn merge_shards(&self, input: MergeShardsInput) -> RusotoFuture<(), MergeShardsError> {
+        (**self).merge_shards(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> RusotoFuture<PutRecordsOutput, PutRecordsError> {
+        (**self).put_records(input)
+    }
+    fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> RusotoFuture<RegisterStreamConsumerOutput, RegisterStreamConsumerError> {
+        (**self).register_stream_consumer(input)
+    }
+    fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> RusotoFuture<(), RemoveTagsFromStreamError> {
+        (**self).remove_tags_from_stream(input)
+    }
+    fn split_shard(&self, input: SplitShardInput) -> RusotoFuture<(), SplitShardError> {
+        (**self).split_shard(input)
+    }
+    fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> RusotoFuture<(), StartStreamEncryptionError> {
+        (**self).start_stream_encryption(input)
+    }
+    fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> RusotoFuture<(), StopStreamEncryptionError> {
+        (**self).stop_stream_encryption(input)
+    }
+    fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> RusotoFuture<SubscribeToShardOutput, SubscribeToShardError> {
+        (**self).subscribe_to_shard(input)
+    }
+    fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError> {
+        (**self).update_shard_count(input)
+    }
+}
+impl<T: Kinesis + ?Sized> Kinesis for ::std::sync::Arc<T> {
+    fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> RusotoFuture<(), AddTagsToStreamError> {
+        (**self).add_tags_to_stream(input)
+    }
+    fn create_stream(&self, input: CreateStreamInput) -> RusotoFuture<(), CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), DecreaseStreamRetentionPeriodError> {
+        (**self).decrease_stream_retention_period(input)
+    }
+    fn delete_stream(&self, input: DeleteStreamInput) -> RusotoFuture<(), DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> RusotoFuture<(), DeregisterStreamConsumerError> {
+        (**self).deregister_stream_consumer(input)
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        (**self).describe_limits()
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> RusotoFuture<DescribeStreamConsumerOutput, DescribeStreamConsumerError> {
+        (**self).describe_stream_consumer(input)
+    }
+    fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> RusotoFuture<DescribeStreamSummaryOutput, DescribeStreamSummaryError> {
+        (**self).describe_stream_summary(input)
+    }
+    fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, DisableEnhancedMonitoringError> {
+        (**self).disable_enhanced_monitoring(input)
+    }
+    fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, EnableEnhancedMonitoringError> {
+        (**self).enable_enhanced_monitoring(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), IncreaseStreamRetentionPeriodError> {
+        (**self).increase_stream_retention_period(input)
+    }
+    fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> RusotoFuture<ListShardsOutput, ListShardsError> {
+        (**self).list_shards(input)
+    }
+    fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> RusotoFuture<ListStreamConsumersOutput, ListStreamConsumersError> {
+        (**self).list_stream_consumers(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn merge_shards(&self, input: MergeShardsInput) -> RusotoFuture<(), MergeShardsError> {
+        (**self).merge_shards(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> RusotoFuture<PutRecordsOutput, PutRecordsError> {
+        (**self).put_records(input)
+    }
+    fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> RusotoFuture<RegisterStreamConsumerOutput, RegisterStreamConsumerError> {
+        (**self).register_stream_consumer(input)
+    }
+    fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> RusotoFuture<(), RemoveTagsFromStreamError> {
+        (**self).remove_tags_from_stream(input)
+    }
+    fn split_shard(&self, input: SplitShardInput) -> RusotoFuture<(), SplitShardError> {
+        (**self).split_shard(input)
+    }
+    fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> RusotoFuture<(), StartStreamEncryptionError> {
+        (**self).start_stream_encryption(input)
+    }
+    fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> RusotoFuture<(), StopStreamEncryptionError> {
+        (**self).stop_stream_encryption(input)
+    }
+    fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> RusotoFuture<SubscribeToShardOutput, SubscribeToShardError> {
+        (**self).subscribe_to_shard(input)
+    }
+    fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError> {
+        (**self).update_shard_count(input)
+    }
+}
+/// A client for the Kinesis API.
+#[derive(Clone)]
+pub struct KinesisClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl KinesisClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> KinesisClient {
+        KinesisClient { client, region }
+    }
+}
+
+impl Kinesis for KinesisClient {
+    /// <p>Adds or updates tags for the specified Kinesis data stream. Each time you invoke this operation, you can specify up to 10 tags. If you want to add more than 10 tags to your stream, you can invoke this operation multiple times. In total, each stream can have up to 50 tags.</p> <p>If tags have already been assigned to the stream, <code>AddTagsToStream</code> overwrites any existing tags that correspond to the specified tag keys.</p> <p> <a>AddTagsToStream</a> has a limit of five transactions per second per account.</p>
+    fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> RusotoFuture<(), AddTagsToStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("AddTagsToStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.AddTagsToStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Kinesis data stream. A stream captures and transports data records that are continuously emitted from different data sources or <i>producers</i>. Scale-out within a stream is explicitly supported by means of shards, which are uniquely identified groups of data records in a stream.</p> <p>You specify and control the number of shards that a stream is composed of. Each shard can support reads up to five transactions per second, up to a maximum data read total of 2 MB per second. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second. If the amount of data input increases or decreases, you can add or remove shards.</p> <p>The stream name identifies the stream. The name is scoped to the AWS account used by the application. It is also scoped by AWS Region. That is, two streams in two different accounts can have the same name, and two streams in the same account, but in two different Regions, can have the same name.</p> <p> <code>CreateStream</code> is an asynchronous operation. Upon receiving a <code>CreateStream</code> request, Kinesis Data Streams immediately returns and sets the stream status to <code>CREATING</code>. After the stream is created, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. You should perform read and write operations only on an <code>ACTIVE</code> stream. </p> <p>You receive a <code>LimitExceededException</code> when making a <code>CreateStream</code> request when you try to do one of the following:</p> <ul> <li> <p>Have more than five streams in the <code>CREATING</code> state at any point in time.</p> </li> <li> <p>Create more shards than are authorized for your account.</p> </li> </ul> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>You can use <code>DescribeStream</code> to check the stream status, which is returned in <code>StreamStatus</code>.</p> <p> <a>CreateStream</a> has a limit of five transactions per second per account.</p>
+    fn create_stream(&self, input: CreateStreamInput) -> RusotoFuture<(), CreateStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("CreateStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.CreateStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Decreases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The minimum value of a stream's retention period is 24 hours.</p> <p>This operation may result in lost data. For example, if the stream's retention period is 48 hours and is decreased to 24 hours, any data already in the stream that is older than 24 hours is inaccessible.</p>
+    fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), DecreaseStreamRetentionPeriodError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DecreaseStreamRetentionPeriod");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Kinesis_20131202.DecreaseStreamRetentionPeriod",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DecreaseStreamRetentionPeriodError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a Kinesis data stream and all its shards and data. You must shut down any applications that are operating on the stream before you delete the stream. If an application attempts to operate on a deleted stream, it receives the exception <code>ResourceNotFoundException</code>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can delete it. After a <code>DeleteStream</code> request, the specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> <p> <b>Note:</b> Kinesis Data Streams might continue to accept data read and write operations, such as <a>PutRecord</a>, <a>PutRecords</a>, and <a>GetRecords</a>, on a stream in the <code>DELETING</code> state until the stream deletion is complete.</p> <p>When you delete a stream, any shards in that stream are also deleted, and any tags are dissociated from the stream.</p> <p>You can use the <a>DescribeStream</a> operation to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <a>DeleteStream</a> has a limit of five transactions per second per account.</p>
+    fn delete_stream(&self, input: DeleteStreamInput) -> RusotoFuture<(), DeleteStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DeleteStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DeleteStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>To deregister a consumer, provide its ARN. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to deregister, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its name and ARN.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> RusotoFuture<(), DeregisterStreamConsumerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DeregisterStreamConsumer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DeregisterStreamConsumer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterStreamConsumerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the shard limits and usage for the account.</p> <p>If you update your account limits, the old limits might be returned for a few minutes.</p> <p>This operation has a limit of one transaction per second per account.</p>
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeLimits");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DescribeLimits");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLimitsOutput, _>(
+                    response,
+                    "DescribeLimits",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLimitsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the specified Kinesis data stream.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), creation time, enhanced metric configuration, and shard map. The shard map is an array of shard objects. For each shard object, there is the hash key and sequence number ranges that the shard spans, and the IDs of any earlier shards that played in a role in creating the shard. Every record ingested in the stream is identified by a sequence number, which is assigned when the record is put into the stream.</p> <p>You can limit the number of shards returned by each call. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-retrieve-shards.html">Retrieving Shards from a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>There are no guarantees about the chronological order shards returned. To process shards in chronological order, use the ID of the parent shard to track the lineage to the oldest shard.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DescribeStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStreamOutput, _>(
+                    response,
+                    "DescribeStream",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>To get the description of a registered consumer, provide the ARN of the consumer. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to describe, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream.</p> <p>This operation has a limit of 20 transactions per second per account.</p>
+    fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> RusotoFuture<DescribeStreamConsumerOutput, DescribeStreamConsumerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStreamConsumer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamConsumer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStreamConsumerOutput, _>(
+                    response,
+                    "DescribeStreamConsumer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeStreamConsumerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides a summarized description of the specified Kinesis data stream without the shard list.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), status, record retention period, approximate creation time, monitoring, encryption details, and open shard count. </p>
+    fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> RusotoFuture<DescribeStreamSummaryOutput, DescribeStreamSummaryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DescribeStreamSummary");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamSummary");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStreamSummaryOutput, _>(
+                    response,
+                    "DescribeStreamSummary",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeStreamSummaryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables enhanced monitoring.</p>
+    fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, DisableEnhancedMonitoringError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("DisableEnhancedMonitoring");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.DisableEnhancedMonitoring");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnhancedMonitoringOutput, _>(
+                    response,
+                    "DisableEnhancedMonitoring",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisableEnhancedMonitoringError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Enables enhanced Kinesis data stream monitoring for shard-level metrics.</p>
+    fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, EnableEnhancedMonitoringError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("EnableEnhancedMonitoring");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.EnableEnhancedMonitoring");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnhancedMonitoringOutput, _>(
+                    response,
+                    "EnableEnhancedMonitoring",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(EnableEnhancedMonitoringError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets data records from a Kinesis data stream's shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading data records sequentially. If there are no records available in the portion of the shard that the iterator points to, <a>GetRecords</a> returns an empty list. It might take multiple calls to get to a portion of the shard that contains records.</p> <p>You can scale by provisioning multiple shards per stream while considering service limits (for more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>). Your application should have one thread per shard, each reading continuously from its stream. To read from a stream continually, call <a>GetRecords</a> in a loop. Use <a>GetShardIterator</a> to get the shard iterator to specify in the first <a>GetRecords</a> call. <a>GetRecords</a> returns a new shard iterator in <code>NextShardIterator</code>. Specify the shard iterator returned in <code>NextShardIterator</code> in subsequent calls to <a>GetRecords</a>. If the shard has been closed, the shard iterator can't return more data and <a>GetRecords</a> returns <code>null</code> in <code>NextShardIterator</code>. You can terminate the loop when the shard is closed, or when the shard iterator reaches the record with the sequence number or other attribute that marks it as the last record to process.</p> <p>Each data record can be up to 1 MiB in size, and each shard can read up to 2 MiB per second. You can ensure that your calls don't exceed the maximum supported size or throughput by using the <code>Limit</code> parameter to specify the maximum number of records that <a>GetRecords</a> can return. Consider your average record size when determining this limit. The maximum number of records that can be returned per call is 10,000.</p> <p>The size of the data returned by <a>GetRecords</a> varies depending on the utilization of the shard. The maximum size of data that <a>GetRecords</a> can return is 10 MiB. If a call returns this amount of data, subsequent calls made within the next 5 seconds throw <code>ProvisionedThroughputExceededException</code>. If there is insufficient provisioned throughput on the stream, subsequent calls made within the next 1 second throw <code>ProvisionedThroughputExceededException</code>. <a>GetRecords</a> doesn't return any data when it throws an exception. For this reason, we recommend that you wait 1 second between calls to <a>GetRecords</a>. However, it's possible that the application will get exceptions for longer than 1 second.</p> <p>To detect whether the application is falling behind in processing, you can use the <code>MillisBehindLatest</code> response attribute. You can also monitor the stream using CloudWatch metrics and other mechanisms (see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring.html">Monitoring</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>).</p> <p>Each Amazon Kinesis record includes a value, <code>ApproximateArrivalTimestamp</code>, that is set when a stream successfully receives and stores a record. This is commonly referred to as a server-side time stamp, whereas a client-side time stamp is set when a data producer creates or sends the record to a stream (a data producer is any data source putting data records into a stream, for example with <a>PutRecords</a>). The time stamp has millisecond precision. There are no guarantees about the time stamp accuracy, or that the time stamp is always increasing. For example, records in a shard or across a stream might have time stamps that are out of order.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("GetRecords");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.GetRecords");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRecordsOutput, _>(response, "GetRecords")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRecordsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets an Amazon Kinesis shard iterator. A shard iterator expires 5 minutes after it is returned to the requester.</p> <p>A shard iterator specifies the shard position from which to start reading data records sequentially. The position is specified using the sequence number of a data record in a shard. A sequence number is the identifier associated with every record ingested in the stream, and is assigned when a record is put into the stream. Each stream has one or more shards.</p> <p>You must specify the shard iterator type. For example, you can set the <code>ShardIteratorType</code> parameter to read exactly from the position denoted by a specific sequence number by using the <code>AT_SEQUENCE_NUMBER</code> shard iterator type. Alternatively, the parameter can read right after the sequence number by using the <code>AFTER_SEQUENCE_NUMBER</code> shard iterator type, using sequence numbers returned by earlier calls to <a>PutRecord</a>, <a>PutRecords</a>, <a>GetRecords</a>, or <a>DescribeStream</a>. In the request, you can specify the shard iterator type <code>AT_TIMESTAMP</code> to read records from an arbitrary point in time, <code>TRIM_HORIZON</code> to cause <code>ShardIterator</code> to point to the last untrimmed record in the shard in the system (the oldest data record in the shard), or <code>LATEST</code> so that you always read the most recent data in the shard. </p> <p>When you read repeatedly from a stream, use a <a>GetShardIterator</a> request to get the first shard iterator for use in your first <a>GetRecords</a> request and for subsequent reads use the shard iterator returned by the <a>GetRecords</a> request in <code>NextShardIterator</code>. A new shard iterator is returned by every <a>GetRecords</a> request in <code>NextShardIterator</code>, which you use in the <code>ShardIterator</code> parameter of the next <a>GetRecords</a> request. </p> <p>If a <a>GetShardIterator</a> request is made too often, you receive a <code>ProvisionedThroughputExceededException</code>. For more information about throughput limits, see <a>GetRecords</a>, and <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the shard is closed, <a>GetShardIterator</a> returns a valid iterator for the last sequence number of the shard. A shard can be closed as a result of using <a>SplitShard</a> or <a>MergeShards</a>.</p> <p> <a>GetShardIterator</a> has a limit of five transactions per second per account per open shard.</p>
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("GetShardIterator");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.GetShardIterator");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetShardIteratorOutput, _>(
+                    response,
+                    "GetShardIterator",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetShardIteratorError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Increases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The maximum value of a stream's retention period is 168 hours (7 days).</p> <p>If you choose a longer stream retention period, this operation increases the time period during which records that have not yet expired are accessible. However, it does not make previous, expired data (older than the stream's previous retention period) accessible after the operation has been called. For example, if a stream's retention period is set to 24 hours and is increased to 168 hours, any data that is older than 24 hours remains inaccessible to consumer applications.</p>
+    fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), IncreaseStreamRetentionPeriodError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("IncreaseStreamRetentionPeriod");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Kinesis_20131202.IncreaseStreamRetentionPeriod",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(IncreaseStreamRetentionPeriodError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the shards in a stream and provides information about each shard. This operation has a limit of 100 transactions per second per data stream.</p> <important> <p>This API is a new operation that is used by the Amazon Kinesis Client Library (KCL). If you have a fine-grained IAM policy that only allows specific operations, you must update your policy to allow calls to this API. For more information, see <a href="https://docs.aws.amazon.com/streams/latest/dev/controlling-access.html">Controlling Access to Amazon Kinesis Data Streams Resources Using IAM</a>.</p> </important></p>
+    fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> RusotoFuture<ListShardsOutput, ListShardsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListShards");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.ListShards");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListShardsOutput, _>(response, "ListShards")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListShardsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the consumers registered to receive data from a stream using enhanced fan-out, and provides information about each consumer.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
+    fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> RusotoFuture<ListStreamConsumersOutput, ListStreamConsumersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListStreamConsumers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.ListStreamConsumers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListStreamConsumersOutput, _>(
+                    response,
+                    "ListStreamConsumers",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListStreamConsumersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists your Kinesis data streams.</p> <p>The number of streams may be too large to return from a single call to <code>ListStreams</code>. You can limit the number of returned streams using the <code>Limit</code> parameter. If you do not specify a value for the <code>Limit</code> parameter, Kinesis Data Streams uses the default limit, which is currently 10.</p> <p>You can detect if there are more streams available to list by using the <code>HasMoreStreams</code> flag from the returned output. If there are more streams available, you can request more streams by using the name of the last stream returned by the <code>ListStreams</code> request in the <code>ExclusiveStartStreamName</code> parameter in a subsequent request to <code>ListStreams</code>. The group of stream names returned by the subsequent request is then added to the list. You can continue this process until all the stream names have been collected in the list. </p> <p> <a>ListStreams</a> has a limit of five transactions per second per account.</p>
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListStreams");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.ListStreams");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListStreamsOutput, _>(response, "ListStreams")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListStreamsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the tags for the specified Kinesis data stream. This operation has a limit of five transactions per second per account.</p>
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("ListTagsForStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.ListTagsForStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForStreamOutput, _>(
+                    response,
+                    "ListTagsForStream",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsForStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Merges two adjacent shards in a Kinesis data stream and combines them into a single shard to reduce the stream's capacity to ingest and transport data. Two shards are considered adjacent if the union of the hash key ranges for the two shards form a contiguous set with no gaps. For example, if you have two shards, one with a hash key range of 276...381 and the other with a hash key range of 382...454, then you could merge these two shards into a single shard that would have a hash key range of 276...454. After the merge, the single child shard receives data for all hash key values covered by the two parent shards.</p> <p> <code>MergeShards</code> is called when there is a need to reduce the overall capacity of a stream because of excess capacity that is not being used. You must specify the shard to be merged and the adjacent shard for a stream. For more information about merging shards, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-merge.html">Merge Two Shards</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can call <code>MergeShards</code>. If a stream is in the <code>CREATING</code>, <code>UPDATING</code>, or <code>DELETING</code> state, <code>MergeShards</code> returns a <code>ResourceInUseException</code>. If the specified stream does not exist, <code>MergeShards</code> returns a <code>ResourceNotFoundException</code>. </p> <p>You can use <a>DescribeStream</a> to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <code>MergeShards</code> is an asynchronous operation. Upon receiving a <code>MergeShards</code> request, Amazon Kinesis Data Streams immediately returns a response and sets the <code>StreamStatus</code> to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the <code>StreamStatus</code> to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You use <a>DescribeStream</a> to determine the shard IDs that are specified in the <code>MergeShards</code> request. </p> <p>If you try to operate on too many streams in parallel using <a>CreateStream</a>, <a>DeleteStream</a>, <code>MergeShards</code>, or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>MergeShards</code> has a limit of five transactions per second per account.</p>
+    fn merge_shards(&self, input: MergeShardsInput) -> RusotoFuture<(), MergeShardsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("MergeShards");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.MergeShards");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(MergeShardsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Writes a single data record into an Amazon Kinesis data stream. Call <code>PutRecord</code> to send data into the stream for real-time ingestion and subsequent processing, one record at a time. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; a partition key; and the data blob itself.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams to distribute data across shards. Kinesis Data Streams segregates the data records that belong to a stream into multiple shards, using the partition key associated with each data record to determine the shard to which a given data record belongs.</p> <p>Partition keys are Unicode strings, with a maximum length limit of 256 characters for each key. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards using the hash key ranges of the shards. You can override hashing the partition key to determine the shard by explicitly specifying a hash value using the <code>ExplicitHashKey</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p> <code>PutRecord</code> returns the shard ID of where the data record was placed and the sequence number that was assigned to the data record.</p> <p>Sequence numbers increase over time and are specific to a shard within a stream, not across all shards within a stream. To guarantee strictly increasing ordering, write serially to a shard and use the <code>SequenceNumberForOrdering</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If a <code>PutRecord</code> request cannot be processed because of insufficient provisioned throughput on the shard involved in the request, <code>PutRecord</code> throws <code>ProvisionedThroughputExceededException</code>. </p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("PutRecord");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.PutRecord");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRecordOutput, _>(response, "PutRecord")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutRecordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Writes multiple data records into a Kinesis data stream in a single call (also referred to as a <code>PutRecords</code> request). Use this operation to send data into the stream for data ingestion and processing. </p> <p>Each <code>PutRecords</code> request can support up to 500 records. Each record in the request can be as large as 1 MB, up to a limit of 5 MB for the entire request, including partition keys. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; and an array of request <code>Records</code>, with each record in the array requiring a partition key and data blob. The record size limit applies to the total size of the partition key and data blob.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams as input to a hash function that maps the partition key and associated data to a specific shard. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards. As a result of this hashing mechanism, all data records with the same partition key map to the same shard within the stream. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>Each record in the <code>Records</code> array may include an optional parameter, <code>ExplicitHashKey</code>, which overrides the partition key to shard mapping. This parameter allows a data producer to determine explicitly the shard where the record is stored. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>The <code>PutRecords</code> response includes an array of response <code>Records</code>. Each record in the response array directly correlates with a record in the request array using natural ordering, from the top to the bottom of the request and response. The response <code>Records</code> array always includes the same number of records as the request array.</p> <p>The response <code>Records</code> array includes both successfully and unsuccessfully processed records. Kinesis Data Streams attempts to process all records in each <code>PutRecords</code> request. A single record failure does not stop the processing of subsequent records.</p> <p>A successfully processed record includes <code>ShardId</code> and <code>SequenceNumber</code> values. The <code>ShardId</code> parameter identifies the shard in the stream where the record is stored. The <code>SequenceNumber</code> parameter is an identifier assigned to the put record, unique to all records in the stream.</p> <p>An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error and can be one of the following values: <code>ProvisionedThroughputExceededException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the <code>ProvisionedThroughputExceededException</code> exception including the account ID, stream name, and shard ID of the record that was throttled. For more information about partially successful responses, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-add-data-to-stream.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
+    fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> RusotoFuture<PutRecordsOutput, PutRecordsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("PutRecords");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.PutRecords");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutRecordsOutput, _>(response, "PutRecords")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutRecordsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers a consumer with a Kinesis data stream. When you use this operation, the consumer you register can read data from the stream at a rate of up to 2 MiB per second. This rate is unaffected by the total number of consumers that read from the same stream.</p> <p>You can register up to 5 consumers per stream. A given consumer can only be registered with one stream.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> RusotoFuture<RegisterStreamConsumerOutput, RegisterStreamConsumerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("RegisterStreamConsumer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.RegisterStreamConsumer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterStreamConsumerOutput, _>(
+                    response,
+                    "RegisterStreamConsumer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RegisterStreamConsumerError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags from the specified Kinesis data stream. Removed tags are deleted and cannot be recovered after this operation successfully completes.</p> <p>If you specify a tag that does not exist, it is ignored.</p> <p> <a>RemoveTagsFromStream</a> has a limit of five transactions per second per account.</p>
+    fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> RusotoFuture<(), RemoveTagsFromStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("RemoveTagsFromStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.RemoveTagsFromStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveTagsFromStreamError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Splits a shard into two new shards in the Kinesis data stream, to increase the stream's capacity to ingest and transport data. <code>SplitShard</code> is called when there is a need to increase the overall capacity of a stream because of an expected increase in the volume of data records being ingested. </p> <p>You can also use <code>SplitShard</code> when a shard appears to be approaching its maximum utilization; for example, the producers sending data into the specific shard are suddenly sending more than previously anticipated. You can also call <code>SplitShard</code> to increase stream capacity, so that more Kinesis Data Streams applications can simultaneously read data from the stream for real-time processing. </p> <p>You must specify the shard to be split and the new hash key, which is the position in the shard where the shard gets split in two. In many cases, the new hash key might be the average of the beginning and ending hash key, but it can be any hash key value in the range being mapped into the shard. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-split.html">Split a Shard</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>You can use <a>DescribeStream</a> to determine the shard ID and hash key values for the <code>ShardToSplit</code> and <code>NewStartingHashKey</code> parameters that are specified in the <code>SplitShard</code> request.</p> <p> <code>SplitShard</code> is an asynchronous operation. Upon receiving a <code>SplitShard</code> request, Kinesis Data Streams immediately returns a response and sets the stream status to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You can use <code>DescribeStream</code> to check the status of the stream, which is returned in <code>StreamStatus</code>. If the stream is in the <code>ACTIVE</code> state, you can call <code>SplitShard</code>. If a stream is in <code>CREATING</code> or <code>UPDATING</code> or <code>DELETING</code> states, <code>DescribeStream</code> returns a <code>ResourceInUseException</code>.</p> <p>If the specified stream does not exist, <code>DescribeStream</code> returns a <code>ResourceNotFoundException</code>. If you try to create more shards than are authorized for your account, you receive a <code>LimitExceededException</code>. </p> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>If you try to operate on too many streams simultaneously using <a>CreateStream</a>, <a>DeleteStream</a>, <a>MergeShards</a>, and/or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>SplitShard</code> has a limit of five transactions per second per account.</p>
+    fn split_shard(&self, input: SplitShardInput) -> RusotoFuture<(), SplitShardError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("SplitShard");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.SplitShard");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SplitShardError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables or updates server-side encryption using an AWS KMS key for a specified stream. </p> <p>Starting encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Updating or applying encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, encryption begins for records written to the stream. </p> <p>API Limits: You can successfully apply a new AWS KMS key for server-side encryption 25 times in a rolling 24-hour period.</p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are encrypted. After you enable encryption, you can verify that encryption is applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
+    fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> RusotoFuture<(), StartStreamEncryptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("StartStreamEncryption");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Kinesis_20131202.StartStreamEncryption");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
                         Err(StartStreamEncryptionError::from_response(response))
                     }),
                 )
@@ -3459,7 +5047,16 @@ impl Kinesis for KinesisClient {
         &self,
         input: StopStreamEncryptionInput,
     ) -> RusotoFuture<(), StopStreamEncryptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("StopStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.StopStreamEncryption");
@@ -3484,7 +5081,16 @@ impl Kinesis for KinesisClient {
         &self,
         input: SubscribeToShardInput,
     ) -> RusotoFuture<SubscribeToShardOutput, SubscribeToShardError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("SubscribeToShard");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.SubscribeToShard");
@@ -3493,10 +5099,10 @@ impl Kinesis for KinesisClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SubscribeToShardOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<SubscribeToShardOutput, _>(
+                    response,
+                    "SubscribeToShard",
+                )
             } else {
                 Box::new(
                     response
@@ -3513,7 +5119,16 @@ impl Kinesis for KinesisClient {
         &self,
         input: UpdateShardCountInput,
     ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesis", &self.region, "/");
+        request.set_operation("UpdateShardCount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Kinesis_20131202.UpdateShardCount");
@@ -3522,10 +5137,10 @@ impl Kinesis for KinesisClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateShardCountOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateShardCountOutput, _>(
+                    response,
+                    "UpdateShardCount",
+                )
             } else {
                 Box::new(
                     response
@@ -3537,3 +5152,907 @@ impl Kinesis for KinesisClient {
         })
     }
 }
+
+/// A mock of the Kinesis API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKinesis {
+    add_tags_to_stream: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToStreamInput,
+                ) -> ::std::result::Result<(), RusotoError<AddTagsToStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_stream: Option<
+        Box<
+            dyn Fn(CreateStreamInput) -> ::std::result::Result<(), RusotoError<CreateStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    decrease_stream_retention_period: Option<
+        Box<
+            dyn Fn(
+                    DecreaseStreamRetentionPeriodInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DecreaseStreamRetentionPeriodError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stream: Option<
+        Box<
+            dyn Fn(DeleteStreamInput) -> ::std::result::Result<(), RusotoError<DeleteStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_stream_consumer: Option<
+        Box<
+            dyn Fn(
+                    DeregisterStreamConsumerInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeregisterStreamConsumerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_limits: Option<
+        Box<
+            dyn Fn()
+                    -> ::std::result::Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_stream: Option<
+        Box<
+            dyn Fn(
+                    DescribeStreamInput,
+                )
+                    -> ::std::result::Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_stream_consumer: Option<
+        Box<
+            dyn Fn(
+                    DescribeStreamConsumerInput,
+                ) -> ::std::result::Result<
+                    DescribeStreamConsumerOutput,
+                    RusotoError<DescribeStreamConsumerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stream_summary: Option<
+        Box<
+            dyn Fn(
+                    DescribeStreamSummaryInput,
+                ) -> ::std::result::Result<
+                    DescribeStreamSummaryOutput,
+                    RusotoError<DescribeStreamSummaryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_enhanced_monitoring: Option<
+        Box<
+            dyn Fn(
+                    DisableEnhancedMonitoringInput,
+                ) -> ::std::result::Result<
+                    EnhancedMonitoringOutput,
+                    RusotoError<DisableEnhancedMonitoringError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_enhanced_monitoring: Option<
+        Box<
+            dyn Fn(
+                    EnableEnhancedMonitoringInput,
+                ) -> ::std::result::Result<
+                    EnhancedMonitoringOutput,
+                    RusotoError<EnableEnhancedMonitoringError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_records: Option<
+        Box<
+            dyn Fn(
+                    GetRecordsInput,
+                )
+                    -> ::std::result::Result<GetRecordsOutput, RusotoError<GetRecordsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_shard_iterator: Option<
+        Box<
+            dyn Fn(
+                    GetShardIteratorInput,
+                ) -> ::std::result::Result<
+                    GetShardIteratorOutput,
+                    RusotoError<GetShardIteratorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    increase_stream_retention_period: Option<
+        Box<
+            dyn Fn(
+                    IncreaseStreamRetentionPeriodInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<IncreaseStreamRetentionPeriodError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_shards: Option<
+        Box<
+            dyn Fn(
+                    ListShardsInput,
+                )
+                    -> ::std::result::Result<ListShardsOutput, RusotoError<ListShardsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_stream_consumers: Option<
+        Box<
+            dyn Fn(
+                    ListStreamConsumersInput,
+                ) -> ::std::result::Result<
+                    ListStreamConsumersOutput,
+                    RusotoError<ListStreamConsumersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_streams: Option<
+        Box<
+            dyn Fn(
+                    ListStreamsInput,
+                )
+                    -> ::std::result::Result<ListStreamsOutput, RusotoError<ListStreamsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_stream: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForStreamInput,
+                ) -> ::std::result::Result<
+                    ListTagsForStreamOutput,
+                    RusotoError<ListTagsForStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    merge_shards: Option<
+        Box<
+            dyn Fn(MergeShardsInput) -> ::std::result::Result<(), RusotoError<MergeShardsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_record: Option<
+        Box<
+            dyn Fn(
+                    PutRecordInput,
+                )
+                    -> ::std::result::Result<PutRecordOutput, RusotoError<PutRecordError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_records: Option<
+        Box<
+            dyn Fn(
+                    PutRecordsInput,
+                )
+                    -> ::std::result::Result<PutRecordsOutput, RusotoError<PutRecordsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_stream_consumer: Option<
+        Box<
+            dyn Fn(
+                    RegisterStreamConsumerInput,
+                ) -> ::std::result::Result<
+                    RegisterStreamConsumerOutput,
+                    RusotoError<RegisterStreamConsumerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_stream: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromStreamInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveTagsFromStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    split_shard: Option<
+        Box<
+            dyn Fn(SplitShardInput) -> ::std::result::Result<(), RusotoError<SplitShardError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_stream_encryption: Option<
+        Box<
+            dyn Fn(
+                    StartStreamEncryptionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<StartStreamEncryptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_stream_encryption: Option<
+        Box<
+            dyn Fn(
+                    StopStreamEncryptionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<StopStreamEncryptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    subscribe_to_shard: Option<
+        Box<
+            dyn Fn(
+                    SubscribeToShardInput,
+                ) -> ::std::result::Result<
+                    SubscribeToShardOutput,
+                    RusotoError<SubscribeToShardError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_shard_count: Option<
+        Box<
+            dyn Fn(
+                    UpdateShardCountInput,
+                ) -> ::std::result::Result<
+                    UpdateShardCountOutput,
+                    RusotoError<UpdateShardCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKinesis {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKinesis {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_stream` with what `handler` returns for its input.
+    pub fn with_add_tags_to_stream<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(AddTagsToStreamInput) -> ::std::result::Result<(), RusotoError<AddTagsToStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stream` with what `handler` returns for its input.
+    pub fn with_create_stream<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(CreateStreamInput) -> ::std::result::Result<(), RusotoError<CreateStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decrease_stream_retention_period` with what `handler` returns for its input.
+    pub fn with_decrease_stream_retention_period<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                DecreaseStreamRetentionPeriodInput,
+            )
+                -> ::std::result::Result<(), RusotoError<DecreaseStreamRetentionPeriodError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decrease_stream_retention_period = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stream` with what `handler` returns for its input.
+    pub fn with_delete_stream<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(DeleteStreamInput) -> ::std::result::Result<(), RusotoError<DeleteStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_stream_consumer` with what `handler` returns for its input.
+    pub fn with_deregister_stream_consumer<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                DeregisterStreamConsumerInput,
+            ) -> ::std::result::Result<(), RusotoError<DeregisterStreamConsumerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_stream_consumer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_limits` with what `handler` returns.
+    pub fn with_describe_limits<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn() -> ::std::result::Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_limits = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stream` with what `handler` returns for its input.
+    pub fn with_describe_stream<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                DescribeStreamInput,
+            )
+                -> ::std::result::Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stream_consumer` with what `handler` returns for its input.
+    pub fn with_describe_stream_consumer<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                DescribeStreamConsumerInput,
+            ) -> ::std::result::Result<
+                DescribeStreamConsumerOutput,
+                RusotoError<DescribeStreamConsumerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stream_consumer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stream_summary` with what `handler` returns for its input.
+    pub fn with_describe_stream_summary<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                DescribeStreamSummaryInput,
+            ) -> ::std::result::Result<
+                DescribeStreamSummaryOutput,
+                RusotoError<DescribeStreamSummaryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stream_summary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_enhanced_monitoring` with what `handler` returns for its input.
+    pub fn with_disable_enhanced_monitoring<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                DisableEnhancedMonitoringInput,
+            ) -> ::std::result::Result<
+                EnhancedMonitoringOutput,
+                RusotoError<DisableEnhancedMonitoringError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_enhanced_monitoring = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_enhanced_monitoring` with what `handler` returns for its input.
+    pub fn with_enable_enhanced_monitoring<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                EnableEnhancedMonitoringInput,
+            ) -> ::std::result::Result<
+                EnhancedMonitoringOutput,
+                RusotoError<EnableEnhancedMonitoringError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_enhanced_monitoring = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_records` with what `handler` returns for its input.
+    pub fn with_get_records<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                GetRecordsInput,
+            ) -> ::std::result::Result<GetRecordsOutput, RusotoError<GetRecordsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_shard_iterator` with what `handler` returns for its input.
+    pub fn with_get_shard_iterator<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                GetShardIteratorInput,
+            )
+                -> ::std::result::Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_shard_iterator = Some(Box::new(handler));
+        self
+    }
+    /// Answers `increase_stream_retention_period` with what `handler` returns for its input.
+    pub fn with_increase_stream_retention_period<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                IncreaseStreamRetentionPeriodInput,
+            )
+                -> ::std::result::Result<(), RusotoError<IncreaseStreamRetentionPeriodError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.increase_stream_retention_period = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_shards` with what `handler` returns for its input.
+    pub fn with_list_shards<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                ListShardsInput,
+            ) -> ::std::result::Result<ListShardsOutput, RusotoError<ListShardsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_shards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stream_consumers` with what `handler` returns for its input.
+    pub fn with_list_stream_consumers<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                ListStreamConsumersInput,
+            ) -> ::std::result::Result<
+                ListStreamConsumersOutput,
+                RusotoError<ListStreamConsumersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stream_consumers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_streams` with what `handler` returns for its input.
+    pub fn with_list_streams<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                ListStreamsInput,
+            )
+                -> ::std::result::Result<ListStreamsOutput, RusotoError<ListStreamsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_streams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_stream` with what `handler` returns for its input.
+    pub fn with_list_tags_for_stream<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                ListTagsForStreamInput,
+            ) -> ::std::result::Result<
+                ListTagsForStreamOutput,
+                RusotoError<ListTagsForStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `merge_shards` with what `handler` returns for its input.
+    pub fn with_merge_shards<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(MergeShardsInput) -> ::std::result::Result<(), RusotoError<MergeShardsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.merge_shards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_record` with what `handler` returns for its input.
+    pub fn with_put_record<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                PutRecordInput,
+            ) -> ::std::result::Result<PutRecordOutput, RusotoError<PutRecordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_record = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_records` with what `handler` returns for its input.
+    pub fn with_put_records<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                PutRecordsInput,
+            ) -> ::std::result::Result<PutRecordsOutput, RusotoError<PutRecordsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_stream_consumer` with what `handler` returns for its input.
+    pub fn with_register_stream_consumer<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                RegisterStreamConsumerInput,
+            ) -> ::std::result::Result<
+                RegisterStreamConsumerOutput,
+                RusotoError<RegisterStreamConsumerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_stream_consumer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_stream` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_stream<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                RemoveTagsFromStreamInput,
+            ) -> ::std::result::Result<(), RusotoError<RemoveTagsFromStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `split_shard` with what `handler` returns for its input.
+    pub fn with_split_shard<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(SplitShardInput) -> ::std::result::Result<(), RusotoError<SplitShardError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.split_shard = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_stream_encryption` with what `handler` returns for its input.
+    pub fn with_start_stream_encryption<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                StartStreamEncryptionInput,
+            ) -> ::std::result::Result<(), RusotoError<StartStreamEncryptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_stream_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_stream_encryption` with what `handler` returns for its input.
+    pub fn with_stop_stream_encryption<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                StopStreamEncryptionInput,
+            ) -> ::std::result::Result<(), RusotoError<StopStreamEncryptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_stream_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `subscribe_to_shard` with what `handler` returns for its input.
+    pub fn with_subscribe_to_shard<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                SubscribeToShardInput,
+            )
+                -> ::std::result::Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.subscribe_to_shard = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_shard_count` with what `handler` returns for its input.
+    pub fn with_update_shard_count<F>(mut self, handler: F) -> MockKinesis
+    where
+        F: Fn(
+                UpdateShardCountInput,
+            )
+                -> ::std::result::Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_shard_count = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Kinesis for MockKinesis {
+    fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> RusotoFuture<(), AddTagsToStreamError> {
+        match self.add_tags_to_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for add_tags_to_stream"),
+        }
+    }
+    fn create_stream(&self, input: CreateStreamInput) -> RusotoFuture<(), CreateStreamError> {
+        match self.create_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for create_stream"),
+        }
+    }
+    fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), DecreaseStreamRetentionPeriodError> {
+        match self.decrease_stream_retention_period {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for decrease_stream_retention_period"),
+        }
+    }
+    fn delete_stream(&self, input: DeleteStreamInput) -> RusotoFuture<(), DeleteStreamError> {
+        match self.delete_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for delete_stream"),
+        }
+    }
+    fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> RusotoFuture<(), DeregisterStreamConsumerError> {
+        match self.deregister_stream_consumer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for deregister_stream_consumer"),
+        }
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        match self.describe_limits {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockKinesis has no handler for describe_limits"),
+        }
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        match self.describe_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for describe_stream"),
+        }
+    }
+    fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> RusotoFuture<DescribeStreamConsumerOutput, DescribeStreamConsumerError> {
+        match self.describe_stream_consumer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for describe_stream_consumer"),
+        }
+    }
+    fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> RusotoFuture<DescribeStreamSummaryOutput, DescribeStreamSummaryError> {
+        match self.describe_stream_summary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for describe_stream_summary"),
+        }
+    }
+    fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, DisableEnhancedMonitoringError> {
+        match self.disable_enhanced_monitoring {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for disable_enhanced_monitoring"),
+        }
+    }
+    fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, EnableEnhancedMonitoringError> {
+        match self.enable_enhanced_monitoring {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for enable_enhanced_monitoring"),
+        }
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        match self.get_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for get_records"),
+        }
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        match self.get_shard_iterator {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for get_shard_iterator"),
+        }
+    }
+    fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), IncreaseStreamRetentionPeriodError> {
+        match self.increase_stream_retention_period {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for increase_stream_retention_period"),
+        }
+    }
+    fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> RusotoFuture<ListShardsOutput, ListShardsError> {
+        match self.list_shards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for list_shards"),
+        }
+    }
+    fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> RusotoFuture<ListStreamConsumersOutput, ListStreamConsumersError> {
+        match self.list_stream_consumers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for list_stream_consumers"),
+        }
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        match self.list_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for list_streams"),
+        }
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        match self.list_tags_for_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for list_tags_for_stream"),
+        }
+    }
+    fn merge_shards(&self, input: MergeShardsInput) -> RusotoFuture<(), MergeShardsError> {
+        match self.merge_shards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for merge_shards"),
+        }
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        match self.put_record {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for put_record"),
+        }
+    }
+    fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> RusotoFuture<PutRecordsOutput, PutRecordsError> {
+        match self.put_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for put_records"),
+        }
+    }
+    fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> RusotoFuture<RegisterStreamConsumerOutput, RegisterStreamConsumerError> {
+        match self.register_stream_consumer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for register_stream_consumer"),
+        }
+    }
+    fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> RusotoFuture<(), RemoveTagsFromStreamError> {
+        match self.remove_tags_from_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for remove_tags_from_stream"),
+        }
+    }
+    fn split_shard(&self, input: SplitShardInput) -> RusotoFuture<(), SplitShardError> {
+        match self.split_shard {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for split_shard"),
+        }
+    }
+    fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> RusotoFuture<(), StartStreamEncryptionError> {
+        match self.start_stream_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for start_stream_encryption"),
+        }
+    }
+    fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> RusotoFuture<(), StopStreamEncryptionError> {
+        match self.stop_stream_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for stop_stream_encryption"),
+        }
+    }
+    fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> RusotoFuture<SubscribeToShardOutput, SubscribeToShardError> {
+        match self.subscribe_to_shard {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for subscribe_to_shard"),
+        }
+    }
+    fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError> {
+        match self.update_shard_count {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesis has no handler for update_shard_count"),
+        }
+    }
+}
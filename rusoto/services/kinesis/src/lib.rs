@@ -18,6 +18,7 @@
 
 extern crate bytes;
 extern crate futures;
+extern crate md5;
 extern crate rusoto_core;
 extern crate serde;
 #[macro_use]
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddApplicationCloudWatchLoggingOptionRequest {
     /// <p>The Kinesis Analytics application name.</p>
     #[serde(rename = "ApplicationName")]
@@ -36,11 +38,36 @@ pub struct AddApplicationCloudWatchLoggingOptionRequest {
     pub current_application_version_id: i64,
 }
 
+impl Validate for AddApplicationCloudWatchLoggingOptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.cloud_watch_logging_option
+            .validate_at(&field_path(path, "cloud_watch_logging_option"), errors);
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddApplicationCloudWatchLoggingOptionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddApplicationInputProcessingConfigurationRequest {
     /// <p>Name of the application to which you want to add the input processing configuration.</p>
     #[serde(rename = "ApplicationName")]
@@ -56,12 +83,48 @@ pub struct AddApplicationInputProcessingConfigurationRequest {
     pub input_processing_configuration: InputProcessingConfiguration,
 }
 
+impl Validate for AddApplicationInputProcessingConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        errors.check_length(
+            &field_path(path, "input_id"),
+            self.input_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "input_id"),
+            &self.input_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.input_processing_configuration
+            .validate_at(&field_path(path, "input_processing_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddApplicationInputProcessingConfigurationResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddApplicationInputRequest {
     /// <p>Name of your existing Amazon Kinesis Analytics application to which you want to add the streaming source.</p>
     #[serde(rename = "ApplicationName")]
@@ -74,13 +137,37 @@ pub struct AddApplicationInputRequest {
     pub input: Input,
 }
 
+impl Validate for AddApplicationInputRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        self.input.validate_at(&field_path(path, "input"), errors);
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddApplicationInputResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddApplicationOutputRequest {
     /// <p>Name of the application to which you want to add the output configuration.</p>
     #[serde(rename = "ApplicationName")]
@@ -93,13 +180,37 @@ pub struct AddApplicationOutputRequest {
     pub output: Output,
 }
 
+impl Validate for AddApplicationOutputRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        self.output.validate_at(&field_path(path, "output"), errors);
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddApplicationOutputResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddApplicationReferenceDataSourceRequest {
     /// <p>Name of an existing application.</p>
     #[serde(rename = "ApplicationName")]
@@ -112,14 +223,38 @@ pub struct AddApplicationReferenceDataSourceRequest {
     pub reference_data_source: ReferenceDataSource,
 }
 
+impl Validate for AddApplicationReferenceDataSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        self.reference_data_source
+            .validate_at(&field_path(path, "reference_data_source"), errors);
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddApplicationReferenceDataSourceResponse {}
 
 /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Provides a description of the application, including the application Amazon Resource Name (ARN), status, latest version, and input and output configuration.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplicationDetail {
     /// <p>ARN of the application.</p>
     #[serde(rename = "ApplicationARN")]
@@ -169,7 +304,7 @@ pub struct ApplicationDetail {
 
 /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Provides application summary information, including the application Amazon Resource Name (ARN), name, and status.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplicationSummary {
     /// <p>ARN of the application.</p>
     #[serde(rename = "ApplicationARN")]
@@ -184,6 +319,7 @@ pub struct ApplicationSummary {
 
 /// <p>Describes updates to apply to an existing Amazon Kinesis Analytics application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApplicationUpdate {
     /// <p>Describes application code updates.</p>
     #[serde(rename = "ApplicationCodeUpdate")]
@@ -207,6 +343,51 @@ pub struct ApplicationUpdate {
     pub reference_data_source_updates: Option<Vec<ReferenceDataSourceUpdate>>,
 }
 
+impl Validate for ApplicationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_code_update {
+            errors.check_length(
+                &field_path(path, "application_code_update"),
+                value.chars().count(),
+                None,
+                Some(102400),
+            );
+        }
+        if let Some(ref value) = self.cloud_watch_logging_option_updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "cloud_watch_logging_option_updates"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.input_updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_updates"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.output_updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "output_updates"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.reference_data_source_updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "reference_data_source_updates"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Provides additional mapping information when the record format uses delimiters, such as CSV. For example, the following sample records use CSV format, where the records use the <i>'\n'</i> as the row delimiter and a comma (",") as the column delimiter: </p> <p> <code>"name1", "address1"</code> </p> <p> <code>"name2", "address2"</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CSVMappingParameters {
@@ -218,8 +399,26 @@ pub struct CSVMappingParameters {
     pub record_row_delimiter: String,
 }
 
+impl Validate for CSVMappingParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "record_column_delimiter"),
+            self.record_column_delimiter.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "record_row_delimiter"),
+            self.record_row_delimiter.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Provides a description of CloudWatch logging options, including the log stream Amazon Resource Name (ARN) and the role ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudWatchLoggingOption {
     /// <p>ARN of the CloudWatch log to receive application messages.</p>
     #[serde(rename = "LogStreamARN")]
@@ -229,9 +428,36 @@ pub struct CloudWatchLoggingOption {
     pub role_arn: String,
 }
 
+impl Validate for CloudWatchLoggingOption {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_stream_arn"),
+            self.log_stream_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_stream_arn"),
+            &self.log_stream_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p>Description of the CloudWatch logging option.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CloudWatchLoggingOptionDescription {
     /// <p>ID of the CloudWatch logging option description.</p>
     #[serde(rename = "CloudWatchLoggingOptionId")]
@@ -247,6 +473,7 @@ pub struct CloudWatchLoggingOptionDescription {
 
 /// <p>Describes CloudWatch logging option updates.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudWatchLoggingOptionUpdate {
     /// <p>ID of the CloudWatch logging option to update</p>
     #[serde(rename = "CloudWatchLoggingOptionId")]
@@ -261,8 +488,47 @@ pub struct CloudWatchLoggingOptionUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for CloudWatchLoggingOptionUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cloud_watch_logging_option_id"),
+            self.cloud_watch_logging_option_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "cloud_watch_logging_option_id"),
+            &self.cloud_watch_logging_option_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.log_stream_arn_update {
+            errors.check_length(
+                &field_path(path, "log_stream_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "log_stream_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p>TBD</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationRequest {
     /// <p>One or more SQL statements that read input data, transform it, and generate output. For example, you can write a SQL statement that reads data from one in-application stream, generates a running average of the number of advertisement clicks by vendor, and insert resulting rows in another in-application stream using pumps. For more information about the typical pattern, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-app-code.html">Application Code</a>. </p> <p>You can provide such series of SQL statements, where output of one statement can be used as the input for the next statement. You store intermediate results by creating in-application streams and pumps.</p> <p>Note that the application code must create the streams with names specified in the <code>Outputs</code>. For example, if your <code>Outputs</code> defines output streams named <code>ExampleOutputStream1</code> and <code>ExampleOutputStream2</code>, then your application code must create these streams. </p>
     #[serde(rename = "ApplicationCode")]
@@ -293,9 +559,67 @@ pub struct CreateApplicationRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.application_code {
+            errors.check_length(
+                &field_path(path, "application_code"),
+                value.chars().count(),
+                None,
+                Some(102400),
+            );
+        }
+        if let Some(ref value) = self.application_description {
+            errors.check_length(
+                &field_path(path, "application_description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.cloud_watch_logging_options {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "cloud_watch_logging_options"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.inputs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "inputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.outputs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "outputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(200));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>TBD</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApplicationResponse {
     /// <p>In response to your <code>CreateApplication</code> request, Amazon Kinesis Analytics returns a response with a summary of the application it created, including the application Amazon Resource Name (ARN), name, and status.</p>
     #[serde(rename = "ApplicationSummary")]
@@ -303,6 +627,7 @@ pub struct CreateApplicationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationCloudWatchLoggingOptionRequest {
     /// <p>The Kinesis Analytics application name.</p>
     #[serde(rename = "ApplicationName")]
@@ -315,11 +640,45 @@ pub struct DeleteApplicationCloudWatchLoggingOptionRequest {
     pub current_application_version_id: i64,
 }
 
+impl Validate for DeleteApplicationCloudWatchLoggingOptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "cloud_watch_logging_option_id"),
+            self.cloud_watch_logging_option_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "cloud_watch_logging_option_id"),
+            &self.cloud_watch_logging_option_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApplicationCloudWatchLoggingOptionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationInputProcessingConfigurationRequest {
     /// <p>The Kinesis Analytics application name.</p>
     #[serde(rename = "ApplicationName")]
@@ -332,12 +691,46 @@ pub struct DeleteApplicationInputProcessingConfigurationRequest {
     pub input_id: String,
 }
 
+impl Validate for DeleteApplicationInputProcessingConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        errors.check_length(
+            &field_path(path, "input_id"),
+            self.input_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "input_id"),
+            &self.input_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApplicationInputProcessingConfigurationResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationOutputRequest {
     /// <p>Amazon Kinesis Analytics application name.</p>
     #[serde(rename = "ApplicationName")]
@@ -350,12 +743,46 @@ pub struct DeleteApplicationOutputRequest {
     pub output_id: String,
 }
 
+impl Validate for DeleteApplicationOutputRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        errors.check_length(
+            &field_path(path, "output_id"),
+            self.output_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "output_id"),
+            &self.output_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApplicationOutputResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationReferenceDataSourceRequest {
     /// <p>Name of an existing application.</p>
     #[serde(rename = "ApplicationName")]
@@ -368,12 +795,46 @@ pub struct DeleteApplicationReferenceDataSourceRequest {
     pub reference_id: String,
 }
 
+impl Validate for DeleteApplicationReferenceDataSourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+        errors.check_length(
+            &field_path(path, "reference_id"),
+            self.reference_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "reference_id"),
+            &self.reference_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApplicationReferenceDataSourceResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationRequest {
     /// <p>Name of the Amazon Kinesis Analytics application to delete.</p>
     #[serde(rename = "ApplicationName")]
@@ -383,22 +844,55 @@ pub struct DeleteApplicationRequest {
     pub create_timestamp: f64,
 }
 
+impl Validate for DeleteApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteApplicationResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeApplicationRequest {
     /// <p>Name of the application.</p>
     #[serde(rename = "ApplicationName")]
     pub application_name: String,
 }
 
+impl Validate for DescribeApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeApplicationResponse {
     /// <p>Provides a description of the application, such as the application Amazon Resource Name (ARN), status, latest version, and input and output configuration details.</p>
     #[serde(rename = "ApplicationDetail")]
@@ -414,6 +908,7 @@ pub struct DestinationSchema {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DiscoverInputSchemaRequest {
     /// <p>The <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> to use to preprocess the records before discovering the schema of the records.</p>
     #[serde(rename = "InputProcessingConfiguration")]
@@ -437,9 +932,42 @@ pub struct DiscoverInputSchemaRequest {
     pub s3_configuration: Option<S3Configuration>,
 }
 
+impl Validate for DiscoverInputSchemaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_processing_configuration {
+            value.validate_at(&field_path(path, "input_processing_configuration"), errors);
+        }
+        if let Some(ref value) = self.resource_arn {
+            errors.check_length(
+                &field_path(path, "resource_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+        if let Some(ref value) = self.s3_configuration {
+            value.validate_at(&field_path(path, "s3_configuration"), errors);
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiscoverInputSchemaResponse {
     /// <p>Schema inferred from the streaming source. It identifies the format of the data in the streaming source and how each data element maps to corresponding columns in the in-application stream that you can create.</p>
     #[serde(rename = "InputSchema")]
@@ -461,6 +989,7 @@ pub struct DiscoverInputSchemaResponse {
 
 /// <p>When you configure the application input, you specify the streaming source, the in-application stream name that is created, and the mapping between the two. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Input {
     /// <p>Describes the number of in-application streams to create. </p> <p>Data from your source is routed to these in-application input streams.</p> <p> (see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>.</p>
     #[serde(rename = "InputParallelism")]
@@ -486,8 +1015,34 @@ pub struct Input {
     pub name_prefix: String,
 }
 
+impl Validate for Input {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_parallelism {
+            value.validate_at(&field_path(path, "input_parallelism"), errors);
+        }
+        if let Some(ref value) = self.input_processing_configuration {
+            value.validate_at(&field_path(path, "input_processing_configuration"), errors);
+        }
+        self.input_schema
+            .validate_at(&field_path(path, "input_schema"), errors);
+        if let Some(ref value) = self.kinesis_firehose_input {
+            value.validate_at(&field_path(path, "kinesis_firehose_input"), errors);
+        }
+        if let Some(ref value) = self.kinesis_streams_input {
+            value.validate_at(&field_path(path, "kinesis_streams_input"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name_prefix"),
+            self.name_prefix.chars().count(),
+            Some(1),
+            Some(32),
+        );
+    }
+}
+
 /// <p>When you start your application, you provide this configuration, which identifies the input source and the point in the input source at which you want the application to start processing records.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputConfiguration {
     /// <p>Input source ID. You can get this ID by calling the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation.</p>
     #[serde(rename = "Id")]
@@ -497,9 +1052,21 @@ pub struct InputConfiguration {
     pub input_starting_position_configuration: InputStartingPositionConfiguration,
 }
 
+impl Validate for InputConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "[a-zA-Z0-9_.-]+");
+    }
+}
+
 /// <p>Describes the application input configuration. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputDescription {
     /// <p>Returns the in-application stream names that are mapped to the stream source.</p>
     #[serde(rename = "InAppStreamNames")]
@@ -541,6 +1108,7 @@ pub struct InputDescription {
 
 /// <p>An object that contains the Amazon Resource Name (ARN) of the <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a> function that is used to preprocess records in the stream, and the ARN of the IAM role that is used to access the AWS Lambda function. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputLambdaProcessor {
     /// <p><p>The ARN of the <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a> function that operates on records in the stream.</p> <note> <p>To specify an earlier version of the Lambda function than the latest, include the Lambda function version in the Lambda function ARN. For more information about Lambda ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-lambda">Example ARNs: AWS Lambda</a> </p> </note></p>
     #[serde(rename = "ResourceARN")]
@@ -550,9 +1118,36 @@ pub struct InputLambdaProcessor {
     pub role_arn: String,
 }
 
+impl Validate for InputLambdaProcessor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p>An object that contains the Amazon Resource Name (ARN) of the <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a> function that is used to preprocess records in the stream, and the ARN of the IAM role that is used to access the AWS Lambda expression.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputLambdaProcessorDescription {
     /// <p>The ARN of the <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a> function that is used to preprocess the records in the stream.</p>
     #[serde(rename = "ResourceARN")]
@@ -566,6 +1161,7 @@ pub struct InputLambdaProcessorDescription {
 
 /// <p>Represents an update to the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputLambdaProcessor.html">InputLambdaProcessor</a> that is used to preprocess the records in the stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputLambdaProcessorUpdate {
     /// <p><p>The Amazon Resource Name (ARN) of the new <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a> function that is used to preprocess the records in the stream.</p> <note> <p>To specify an earlier version of the Lambda function than the latest, include the Lambda function version in the Lambda function ARN. For more information about Lambda ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-lambda">Example ARNs: AWS Lambda</a> </p> </note></p>
     #[serde(rename = "ResourceARNUpdate")]
@@ -577,6 +1173,33 @@ pub struct InputLambdaProcessorUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for InputLambdaProcessorUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_arn_update {
+            errors.check_length(
+                &field_path(path, "resource_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p>Describes the number of in-application streams to create for a given streaming source. For information about parallelism, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputParallelism {
@@ -586,8 +1209,17 @@ pub struct InputParallelism {
     pub count: Option<i64>,
 }
 
+impl Validate for InputParallelism {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.count {
+            errors.check_range(&field_path(path, "count"), *value, Some(1), Some(64));
+        }
+    }
+}
+
 /// <p>Provides updates to the parallelism count.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputParallelismUpdate {
     /// <p>Number of in-application streams to create for the specified streaming source.</p>
     #[serde(rename = "CountUpdate")]
@@ -595,17 +1227,33 @@ pub struct InputParallelismUpdate {
     pub count_update: Option<i64>,
 }
 
+impl Validate for InputParallelismUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.count_update {
+            errors.check_range(&field_path(path, "count_update"), *value, Some(1), Some(64));
+        }
+    }
+}
+
 /// <p>Provides a description of a processor that is used to preprocess the records in the stream before being processed by your application code. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputProcessingConfiguration {
     /// <p>The <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputLambdaProcessor.html">InputLambdaProcessor</a> that is used to preprocess the records in the stream before being processed by your application code.</p>
     #[serde(rename = "InputLambdaProcessor")]
     pub input_lambda_processor: InputLambdaProcessor,
 }
 
+impl Validate for InputProcessingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.input_lambda_processor
+            .validate_at(&field_path(path, "input_lambda_processor"), errors);
+    }
+}
+
 /// <p>Provides configuration information about an input processor. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputProcessingConfigurationDescription {
     /// <p>Provides configuration information about the associated <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputLambdaProcessorDescription.html">InputLambdaProcessorDescription</a>.</p>
     #[serde(rename = "InputLambdaProcessorDescription")]
@@ -615,14 +1263,23 @@ pub struct InputProcessingConfigurationDescription {
 
 /// <p>Describes updates to an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputProcessingConfigurationUpdate {
     /// <p>Provides update information for an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputLambdaProcessor.html">InputLambdaProcessor</a>.</p>
     #[serde(rename = "InputLambdaProcessorUpdate")]
     pub input_lambda_processor_update: InputLambdaProcessorUpdate,
 }
 
+impl Validate for InputProcessingConfigurationUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.input_lambda_processor_update
+            .validate_at(&field_path(path, "input_lambda_processor_update"), errors);
+    }
+}
+
 /// <p>Describes updates for the application's input schema.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputSchemaUpdate {
     /// <p>A list of <code>RecordColumn</code> objects. Each object describes the mapping of the streaming source element to the corresponding column in the in-application stream. </p>
     #[serde(rename = "RecordColumnUpdates")]
@@ -638,6 +1295,29 @@ pub struct InputSchemaUpdate {
     pub record_format_update: Option<RecordFormat>,
 }
 
+impl Validate for InputSchemaUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.record_column_updates {
+            errors.check_length(
+                &field_path(path, "record_column_updates"),
+                value.len(),
+                Some(1),
+                Some(1000),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "record_column_updates"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.record_encoding_update {
+            errors.check_pattern(&field_path(path, "record_encoding_update"), &value, "UTF-8");
+        }
+        if let Some(ref value) = self.record_format_update {
+            value.validate_at(&field_path(path, "record_format_update"), errors);
+        }
+    }
+}
+
 /// <p>Describes the point at which the application reads from the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputStartingPositionConfiguration {
@@ -649,6 +1329,7 @@ pub struct InputStartingPositionConfiguration {
 
 /// <p>Describes updates to a specific input configuration (identified by the <code>InputId</code> of an application). </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputUpdate {
     /// <p>Input ID of the application input to be updated.</p>
     #[serde(rename = "InputId")]
@@ -679,6 +1360,48 @@ pub struct InputUpdate {
     pub name_prefix_update: Option<String>,
 }
 
+impl Validate for InputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "input_id"),
+            self.input_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "input_id"),
+            &self.input_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.input_parallelism_update {
+            value.validate_at(&field_path(path, "input_parallelism_update"), errors);
+        }
+        if let Some(ref value) = self.input_processing_configuration_update {
+            value.validate_at(
+                &field_path(path, "input_processing_configuration_update"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.input_schema_update {
+            value.validate_at(&field_path(path, "input_schema_update"), errors);
+        }
+        if let Some(ref value) = self.kinesis_firehose_input_update {
+            value.validate_at(&field_path(path, "kinesis_firehose_input_update"), errors);
+        }
+        if let Some(ref value) = self.kinesis_streams_input_update {
+            value.validate_at(&field_path(path, "kinesis_streams_input_update"), errors);
+        }
+        if let Some(ref value) = self.name_prefix_update {
+            errors.check_length(
+                &field_path(path, "name_prefix_update"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 /// <p>Provides additional mapping information when JSON is the record format on the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JSONMappingParameters {
@@ -687,8 +1410,20 @@ pub struct JSONMappingParameters {
     pub record_row_path: String,
 }
 
+impl Validate for JSONMappingParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "record_row_path"),
+            self.record_row_path.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p> Identifies an Amazon Kinesis Firehose delivery stream as the streaming source. You provide the delivery stream's Amazon Resource Name (ARN) and an IAM role ARN that enables Amazon Kinesis Analytics to access the stream on your behalf.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisFirehoseInput {
     /// <p>ARN of the input delivery stream.</p>
     #[serde(rename = "ResourceARN")]
@@ -698,9 +1433,36 @@ pub struct KinesisFirehoseInput {
     pub role_arn: String,
 }
 
+impl Validate for KinesisFirehoseInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p> Describes the Amazon Kinesis Firehose delivery stream that is configured as the streaming source in the application input configuration. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KinesisFirehoseInputDescription {
     /// <p>Amazon Resource Name (ARN) of the Amazon Kinesis Firehose delivery stream.</p>
     #[serde(rename = "ResourceARN")]
@@ -714,6 +1476,7 @@ pub struct KinesisFirehoseInputDescription {
 
 /// <p>When updating application input configuration, provides information about an Amazon Kinesis Firehose delivery stream as the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisFirehoseInputUpdate {
     /// <p>Amazon Resource Name (ARN) of the input Amazon Kinesis Firehose delivery stream to read.</p>
     #[serde(rename = "ResourceARNUpdate")]
@@ -725,8 +1488,36 @@ pub struct KinesisFirehoseInputUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for KinesisFirehoseInputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_arn_update {
+            errors.check_length(
+                &field_path(path, "resource_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p>When configuring application output, identifies an Amazon Kinesis Firehose delivery stream as the destination. You provide the stream Amazon Resource Name (ARN) and an IAM role that enables Amazon Kinesis Analytics to write to the stream on your behalf.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisFirehoseOutput {
     /// <p>ARN of the destination Amazon Kinesis Firehose delivery stream to write to.</p>
     #[serde(rename = "ResourceARN")]
@@ -736,9 +1527,36 @@ pub struct KinesisFirehoseOutput {
     pub role_arn: String,
 }
 
+impl Validate for KinesisFirehoseOutput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p> For an application output, describes the Amazon Kinesis Firehose delivery stream configured as its destination. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KinesisFirehoseOutputDescription {
     /// <p>Amazon Resource Name (ARN) of the Amazon Kinesis Firehose delivery stream.</p>
     #[serde(rename = "ResourceARN")]
@@ -752,6 +1570,7 @@ pub struct KinesisFirehoseOutputDescription {
 
 /// <p> When updating an output configuration using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_UpdateApplication.html">UpdateApplication</a> operation, provides information about an Amazon Kinesis Firehose delivery stream configured as the destination. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisFirehoseOutputUpdate {
     /// <p>Amazon Resource Name (ARN) of the Amazon Kinesis Firehose delivery stream to write to.</p>
     #[serde(rename = "ResourceARNUpdate")]
@@ -763,8 +1582,36 @@ pub struct KinesisFirehoseOutputUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for KinesisFirehoseOutputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_arn_update {
+            errors.check_length(
+                &field_path(path, "resource_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p> Identifies an Amazon Kinesis stream as the streaming source. You provide the stream's Amazon Resource Name (ARN) and an IAM role ARN that enables Amazon Kinesis Analytics to access the stream on your behalf.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisStreamsInput {
     /// <p>ARN of the input Amazon Kinesis stream to read.</p>
     #[serde(rename = "ResourceARN")]
@@ -774,9 +1621,36 @@ pub struct KinesisStreamsInput {
     pub role_arn: String,
 }
 
+impl Validate for KinesisStreamsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p> Describes the Amazon Kinesis stream that is configured as the streaming source in the application input configuration. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KinesisStreamsInputDescription {
     /// <p>Amazon Resource Name (ARN) of the Amazon Kinesis stream.</p>
     #[serde(rename = "ResourceARN")]
@@ -790,6 +1664,7 @@ pub struct KinesisStreamsInputDescription {
 
 /// <p>When updating application input configuration, provides information about an Amazon Kinesis stream as the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisStreamsInputUpdate {
     /// <p>Amazon Resource Name (ARN) of the input Amazon Kinesis stream to read.</p>
     #[serde(rename = "ResourceARNUpdate")]
@@ -801,8 +1676,36 @@ pub struct KinesisStreamsInputUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for KinesisStreamsInputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_arn_update {
+            errors.check_length(
+                &field_path(path, "resource_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p>When configuring application output, identifies an Amazon Kinesis stream as the destination. You provide the stream Amazon Resource Name (ARN) and also an IAM role ARN that Amazon Kinesis Analytics can use to write to the stream on your behalf.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisStreamsOutput {
     /// <p>ARN of the destination Amazon Kinesis stream to write to.</p>
     #[serde(rename = "ResourceARN")]
@@ -812,9 +1715,36 @@ pub struct KinesisStreamsOutput {
     pub role_arn: String,
 }
 
+impl Validate for KinesisStreamsOutput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p> For an application output, describes the Amazon Kinesis stream configured as its destination. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KinesisStreamsOutputDescription {
     /// <p>Amazon Resource Name (ARN) of the Amazon Kinesis stream.</p>
     #[serde(rename = "ResourceARN")]
@@ -828,6 +1758,7 @@ pub struct KinesisStreamsOutputDescription {
 
 /// <p> When updating an output configuration using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_UpdateApplication.html">UpdateApplication</a> operation, provides information about an Amazon Kinesis stream configured as the destination. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisStreamsOutputUpdate {
     /// <p>Amazon Resource Name (ARN) of the Amazon Kinesis stream where you want to write the output.</p>
     #[serde(rename = "ResourceARNUpdate")]
@@ -839,8 +1770,36 @@ pub struct KinesisStreamsOutputUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for KinesisStreamsOutputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_arn_update {
+            errors.check_length(
+                &field_path(path, "resource_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p>When configuring application output, identifies an AWS Lambda function as the destination. You provide the function Amazon Resource Name (ARN) and also an IAM role ARN that Amazon Kinesis Analytics can use to write to the function on your behalf. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LambdaOutput {
     /// <p><p>Amazon Resource Name (ARN) of the destination Lambda function to write to.</p> <note> <p>To specify an earlier version of the Lambda function than the latest, include the Lambda function version in the Lambda function ARN. For more information about Lambda ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-lambda">Example ARNs: AWS Lambda</a> </p> </note></p>
     #[serde(rename = "ResourceARN")]
@@ -850,9 +1809,36 @@ pub struct LambdaOutput {
     pub role_arn: String,
 }
 
+impl Validate for LambdaOutput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:.*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p>For an application output, describes the AWS Lambda function configured as its destination. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaOutputDescription {
     /// <p>Amazon Resource Name (ARN) of the destination Lambda function.</p>
     #[serde(rename = "ResourceARN")]
@@ -866,6 +1852,7 @@ pub struct LambdaOutputDescription {
 
 /// <p>When updating an output configuration using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_UpdateApplication.html">UpdateApplication</a> operation, provides information about an AWS Lambda function configured as the destination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LambdaOutputUpdate {
     /// <p><p>Amazon Resource Name (ARN) of the destination Lambda function.</p> <note> <p>To specify an earlier version of the Lambda function than the latest, include the Lambda function version in the Lambda function ARN. For more information about Lambda ARNs, see <a href="/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-lambda">Example ARNs: AWS Lambda</a> </p> </note></p>
     #[serde(rename = "ResourceARNUpdate")]
@@ -877,8 +1864,36 @@ pub struct LambdaOutputUpdate {
     pub role_arn_update: Option<String>,
 }
 
+impl Validate for LambdaOutputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.resource_arn_update {
+            errors.check_length(
+                &field_path(path, "resource_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.role_arn_update {
+            errors.check_length(
+                &field_path(path, "role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApplicationsRequest {
     /// <p>Name of the application to start the list with. When using pagination to retrieve the list, you don't need to specify this parameter in the first request. However, in subsequent requests, you add the last application name from the previous response to get the next page of applications.</p>
     #[serde(rename = "ExclusiveStartApplicationName")]
@@ -890,9 +1905,30 @@ pub struct ListApplicationsRequest {
     pub limit: Option<i64>,
 }
 
+impl Validate for ListApplicationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclusive_start_application_name {
+            errors.check_length(
+                &field_path(path, "exclusive_start_application_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "exclusive_start_application_name"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApplicationsResponse {
     /// <p>List of <code>ApplicationSummary</code> objects. </p>
     #[serde(rename = "ApplicationSummaries")]
@@ -903,14 +1939,27 @@ pub struct ListApplicationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the application for which to retrieve tags.</p>
     #[serde(rename = "ResourceARN")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:aws:kinesisanalytics:[a-z]{2}-[a-z]+-\\d{1}+:\\d{12}+:application/[a-zA-Z0-9_.-]{1,128}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The key-value tags assigned to the application.</p>
     #[serde(rename = "Tags")]
@@ -931,8 +1980,20 @@ pub struct MappingParameters {
     pub json_mapping_parameters: Option<JSONMappingParameters>,
 }
 
+impl Validate for MappingParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.csv_mapping_parameters {
+            value.validate_at(&field_path(path, "csv_mapping_parameters"), errors);
+        }
+        if let Some(ref value) = self.json_mapping_parameters {
+            value.validate_at(&field_path(path, "json_mapping_parameters"), errors);
+        }
+    }
+}
+
 /// <p> Describes application output configuration in which you identify an in-application stream and a destination where you want the in-application stream data to be written. The destination can be an Amazon Kinesis stream or an Amazon Kinesis Firehose delivery stream. </p> <p/> <p>For limits on how many destinations an application can write and other limitations, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Output {
     /// <p>Describes the data format when records are written to the destination. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Configuring Application Output</a>.</p>
     #[serde(rename = "DestinationSchema")]
@@ -954,9 +2015,29 @@ pub struct Output {
     pub name: String,
 }
 
+impl Validate for Output {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kinesis_firehose_output {
+            value.validate_at(&field_path(path, "kinesis_firehose_output"), errors);
+        }
+        if let Some(ref value) = self.kinesis_streams_output {
+            value.validate_at(&field_path(path, "kinesis_streams_output"), errors);
+        }
+        if let Some(ref value) = self.lambda_output {
+            value.validate_at(&field_path(path, "lambda_output"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+    }
+}
+
 /// <p>Describes the application output configuration, which includes the in-application stream name and the destination where the stream data is written. The destination can be an Amazon Kinesis stream or an Amazon Kinesis Firehose delivery stream. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OutputDescription {
     /// <p>Data format used for writing data to the destination.</p>
     #[serde(rename = "DestinationSchema")]
@@ -986,6 +2067,7 @@ pub struct OutputDescription {
 
 /// <p> Describes updates to the output configuration identified by the <code>OutputId</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OutputUpdate {
     /// <p>Describes the data format when records are written to the destination. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Configuring Application Output</a>.</p>
     #[serde(rename = "DestinationSchemaUpdate")]
@@ -1012,6 +2094,39 @@ pub struct OutputUpdate {
     pub output_id: String,
 }
 
+impl Validate for OutputUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kinesis_firehose_output_update {
+            value.validate_at(&field_path(path, "kinesis_firehose_output_update"), errors);
+        }
+        if let Some(ref value) = self.kinesis_streams_output_update {
+            value.validate_at(&field_path(path, "kinesis_streams_output_update"), errors);
+        }
+        if let Some(ref value) = self.lambda_output_update {
+            value.validate_at(&field_path(path, "lambda_output_update"), errors);
+        }
+        if let Some(ref value) = self.name_update {
+            errors.check_length(
+                &field_path(path, "name_update"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "output_id"),
+            self.output_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "output_id"),
+            &self.output_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Describes the mapping of each data element in the streaming source to the corresponding column in the in-application stream.</p> <p>Also used to describe the format of the reference data source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecordColumn {
@@ -1027,6 +2142,17 @@ pub struct RecordColumn {
     pub sql_type: String,
 }
 
+impl Validate for RecordColumn {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "sql_type"),
+            self.sql_type.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p> Describes the record format and relevant mapping information that should be applied to schematize the records on the stream. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecordFormat {
@@ -1039,8 +2165,17 @@ pub struct RecordFormat {
     pub record_format_type: String,
 }
 
+impl Validate for RecordFormat {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.mapping_parameters {
+            value.validate_at(&field_path(path, "mapping_parameters"), errors);
+        }
+    }
+}
+
 /// <p>Describes the reference data source by providing the source information (S3 bucket name and object key name), the resulting in-application table name that is created, and the necessary schema to map the data elements in the Amazon S3 object to the in-application table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReferenceDataSource {
     /// <p>Describes the format of the data in the streaming source, and how each data element maps to corresponding columns created in the in-application stream.</p>
     #[serde(rename = "ReferenceSchema")]
@@ -1054,9 +2189,25 @@ pub struct ReferenceDataSource {
     pub table_name: String,
 }
 
+impl Validate for ReferenceDataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.reference_schema
+            .validate_at(&field_path(path, "reference_schema"), errors);
+        if let Some(ref value) = self.s3_reference_data_source {
+            value.validate_at(&field_path(path, "s3_reference_data_source"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "table_name"),
+            self.table_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+    }
+}
+
 /// <p>Describes the reference data source configured for an application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReferenceDataSourceDescription {
     /// <p>ID of the reference data source. This is the ID that Amazon Kinesis Analytics assigns when you add the reference data source to your application using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_AddApplicationReferenceDataSource.html">AddApplicationReferenceDataSource</a> operation.</p>
     #[serde(rename = "ReferenceId")]
@@ -1075,6 +2226,7 @@ pub struct ReferenceDataSourceDescription {
 
 /// <p>When you update a reference data source configuration for an application, this object provides all the updated values (such as the source bucket name and object key name), the in-application table name that is created, and updated mapping information that maps the data in the Amazon S3 object to the in-application reference table that is created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReferenceDataSourceUpdate {
     /// <p>ID of the reference data source being updated. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to get this value.</p>
     #[serde(rename = "ReferenceId")]
@@ -1093,8 +2245,39 @@ pub struct ReferenceDataSourceUpdate {
     pub table_name_update: Option<String>,
 }
 
+impl Validate for ReferenceDataSourceUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "reference_id"),
+            self.reference_id.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "reference_id"),
+            &self.reference_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.reference_schema_update {
+            value.validate_at(&field_path(path, "reference_schema_update"), errors);
+        }
+        if let Some(ref value) = self.s3_reference_data_source_update {
+            value.validate_at(&field_path(path, "s3_reference_data_source_update"), errors);
+        }
+        if let Some(ref value) = self.table_name_update {
+            errors.check_length(
+                &field_path(path, "table_name_update"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 /// <p>Provides a description of an Amazon S3 data source, including the Amazon Resource Name (ARN) of the S3 bucket, the ARN of the IAM role that is used to access the bucket, and the name of the Amazon S3 object that contains the data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Configuration {
     /// <p>ARN of the S3 bucket that contains the data.</p>
     #[serde(rename = "BucketARN")]
@@ -1107,8 +2290,38 @@ pub struct S3Configuration {
     pub role_arn: String,
 }
 
+impl Validate for S3Configuration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket_arn"),
+            self.bucket_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "bucket_arn"), &self.bucket_arn, "arn:.*");
+        errors.check_length(
+            &field_path(path, "file_key"),
+            self.file_key.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p>Identifies the S3 bucket and object that contains the reference data. Also identifies the IAM role Amazon Kinesis Analytics can assume to read this object on your behalf.</p> <p>An Amazon Kinesis Analytics application loads reference data only once. If the data changes, you call the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_UpdateApplication.html">UpdateApplication</a> operation to trigger reloading of data into your application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3ReferenceDataSource {
     /// <p>Amazon Resource Name (ARN) of the S3 bucket.</p>
     #[serde(rename = "BucketARN")]
@@ -1121,9 +2334,38 @@ pub struct S3ReferenceDataSource {
     pub reference_role_arn: String,
 }
 
+impl Validate for S3ReferenceDataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket_arn"),
+            self.bucket_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "bucket_arn"), &self.bucket_arn, "arn:.*");
+        errors.check_length(
+            &field_path(path, "file_key"),
+            self.file_key.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "reference_role_arn"),
+            self.reference_role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "reference_role_arn"),
+            &self.reference_role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 /// <p>Provides the bucket name and object key name that stores the reference data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct S3ReferenceDataSourceDescription {
     /// <p>Amazon Resource Name (ARN) of the S3 bucket.</p>
     #[serde(rename = "BucketARN")]
@@ -1138,6 +2380,7 @@ pub struct S3ReferenceDataSourceDescription {
 
 /// <p>Describes the S3 bucket name, object key name, and IAM role that Amazon Kinesis Analytics can assume to read the Amazon S3 object on your behalf and populate the in-application reference table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3ReferenceDataSourceUpdate {
     /// <p>Amazon Resource Name (ARN) of the S3 bucket.</p>
     #[serde(rename = "BucketARNUpdate")]
@@ -1153,6 +2396,41 @@ pub struct S3ReferenceDataSourceUpdate {
     pub reference_role_arn_update: Option<String>,
 }
 
+impl Validate for S3ReferenceDataSourceUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket_arn_update {
+            errors.check_length(
+                &field_path(path, "bucket_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "bucket_arn_update"), &value, "arn:.*");
+        }
+        if let Some(ref value) = self.file_key_update {
+            errors.check_length(
+                &field_path(path, "file_key_update"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.reference_role_arn_update {
+            errors.check_length(
+                &field_path(path, "reference_role_arn_update"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "reference_role_arn_update"),
+                &value,
+                "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+    }
+}
+
 /// <p>Describes the format of the data in the streaming source, and how each data element maps to corresponding columns created in the in-application stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceSchema {
@@ -1168,8 +2446,29 @@ pub struct SourceSchema {
     pub record_format: RecordFormat,
 }
 
+impl Validate for SourceSchema {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "record_columns"),
+            self.record_columns.len(),
+            Some(1),
+            Some(1000),
+        );
+        for (i, item) in self.record_columns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "record_columns"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.record_encoding {
+            errors.check_pattern(&field_path(path, "record_encoding"), &value, "UTF-8");
+        }
+        self.record_format
+            .validate_at(&field_path(path, "record_format"), errors);
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartApplicationRequest {
     /// <p>Name of the application.</p>
     #[serde(rename = "ApplicationName")]
@@ -1179,22 +2478,59 @@ pub struct StartApplicationRequest {
     pub input_configurations: Vec<InputConfiguration>,
 }
 
+impl Validate for StartApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        for (i, item) in self.input_configurations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "input_configurations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartApplicationResponse {}
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopApplicationRequest {
     /// <p>Name of the running application to stop.</p>
     #[serde(rename = "ApplicationName")]
     pub application_name: String,
 }
 
+impl Validate for StopApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopApplicationResponse {}
 
 /// <p>A key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
@@ -1209,7 +2545,27 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the application to assign the tags.</p>
     #[serde(rename = "ResourceARN")]
@@ -1219,11 +2575,34 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:aws:kinesisanalytics:[a-z]{2}-[a-z]+-\\d{1}+:\\d{12}+:application/[a-zA-Z0-9_.-]{1,128}");
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(200),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the Kinesis Analytics application from which to remove the tags.</p>
     #[serde(rename = "ResourceARN")]
@@ -1233,11 +2612,34 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "resource_arn"), &self.resource_arn, "arn:aws:kinesisanalytics:[a-z]{2}-[a-z]+-\\d{1}+:\\d{12}+:application/[a-zA-Z0-9_.-]{1,128}");
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(200),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationRequest {
     /// <p>Name of the Amazon Kinesis Analytics application to update.</p>
     #[serde(rename = "ApplicationName")]
@@ -1250,8 +2652,32 @@ pub struct UpdateApplicationRequest {
     pub current_application_version_id: i64,
 }
 
+impl Validate for UpdateApplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_name"),
+            self.application_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_name"),
+            &self.application_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        self.application_update
+            .validate_at(&field_path(path, "application_update"), errors);
+        errors.check_range(
+            &field_path(path, "current_application_version_id"),
+            self.current_application_version_id,
+            Some(1),
+            Some(999999999),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateApplicationResponse {}
 
 /// Errors returned by AddApplicationCloudWatchLoggingOption
@@ -1300,7 +2726,12 @@ impl AddApplicationCloudWatchLoggingOptionError {
                         AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1368,7 +2799,12 @@ impl AddApplicationInputError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1442,7 +2878,12 @@ impl AddApplicationInputProcessingConfigurationError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1511,7 +2952,12 @@ impl AddApplicationOutputError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1580,7 +3026,12 @@ impl AddApplicationReferenceDataSourceError {
                         AddApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1644,7 +3095,12 @@ impl CreateApplicationError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1701,7 +3157,12 @@ impl DeleteApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1773,7 +3234,12 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1852,7 +3318,12 @@ impl DeleteApplicationInputProcessingConfigurationError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1925,7 +3396,12 @@ impl DeleteApplicationOutputError {
                         DeleteApplicationOutputError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1994,7 +3470,12 @@ impl DeleteApplicationReferenceDataSourceError {
                         DeleteApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2040,7 +3521,12 @@ impl DescribeApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2095,7 +3581,12 @@ impl DiscoverInputSchemaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2125,7 +3616,12 @@ impl ListApplicationsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListApplicationsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2170,7 +3666,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2229,7 +3730,12 @@ impl StartApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2278,7 +3784,12 @@ impl StopApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2333,7 +3844,12 @@ impl TagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2392,7 +3908,12 @@ impl UntagResourceError {
                 "TooManyTagsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2458,7 +3979,12 @@ impl UpdateApplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2622,45 +4148,8 @@ pub trait KinesisAnalytics {
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
 }
-/// A client for the Kinesis Analytics API.
-#[derive(Clone)]
-pub struct KinesisAnalyticsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl KinesisAnalyticsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> KinesisAnalyticsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> KinesisAnalyticsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
-        KinesisAnalyticsClient { client, region }
-    }
-}
-
-impl KinesisAnalytics for KinesisAnalyticsClient {
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a CloudWatch log stream to monitor application configuration errors. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
+const _: Option<&dyn KinesisAnalytics> = None;
+impl<'a, T: KinesisAnalytics + ?Sized> KinesisAnalytics for &'a T {
     fn add_application_cloud_watch_logging_option(
         &self,
         input: AddApplicationCloudWatchLoggingOptionRequest,
@@ -2668,64 +4157,14 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         AddApplicationCloudWatchLoggingOptionResponse,
         AddApplicationCloudWatchLoggingOptionError,
     > {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "KinesisAnalytics_20150814.AddApplicationCloudWatchLoggingOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddApplicationCloudWatchLoggingOptionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AddApplicationCloudWatchLoggingOptionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).add_application_cloud_watch_logging_option(input)
     }
-
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Adds a streaming source to your Amazon Kinesis application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p> <p>You can add a streaming source either when you create an application or you can use this operation to add a streaming source after you create an application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_CreateApplication.html">CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationInput</code> action.</p></p>
     fn add_application_input(
         &self,
         input: AddApplicationInputRequest,
     ) -> RusotoFuture<AddApplicationInputResponse, AddApplicationInputError> {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "KinesisAnalytics_20150814.AddApplicationInput",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddApplicationInputResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AddApplicationInputError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).add_application_input(input)
     }
-
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> to an application. An input processor preprocesses records on the input stream before the application&#39;s SQL code executes. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p></p>
     fn add_application_input_processing_configuration(
         &self,
         input: AddApplicationInputProcessingConfigurationRequest,
@@ -2733,62 +4172,14 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         AddApplicationInputProcessingConfigurationResponse,
         AddApplicationInputProcessingConfigurationError,
     > {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "KinesisAnalytics_20150814.AddApplicationInputProcessingConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddApplicationInputProcessingConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AddApplicationInputProcessingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).add_application_input_processing_configuration(input)
     }
-
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an external destination to your Amazon Kinesis Analytics application.</p> <p>If you want Amazon Kinesis Analytics to deliver data from an in-application stream within your application to an external destination (such as an Amazon Kinesis stream, an Amazon Kinesis Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Understanding Application Output (Destination)</a>. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version.</p> <p>For the limits on the number of application inputs and outputs you can configure, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action.</p></p>
     fn add_application_output(
         &self,
         input: AddApplicationOutputRequest,
     ) -> RusotoFuture<AddApplicationOutputResponse, AddApplicationOutputError> {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "KinesisAnalytics_20150814.AddApplicationOutput",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddApplicationOutputResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AddApplicationOutputError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).add_application_output(input)
     }
-
-    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a reference data source to an existing application.</p> <p>Amazon Kinesis Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in Amazon S3 object maps to columns in the resulting in-application table.</p> <p> For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. For the limits on data sources you can add to your application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action. </p></p>
     fn add_application_reference_data_source(
         &self,
         input: AddApplicationReferenceDataSourceRequest,
@@ -2796,22 +4187,620 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         AddApplicationReferenceDataSourceResponse,
         AddApplicationReferenceDataSourceError,
     > {
-        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        (**self).add_application_reference_data_source(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        DeleteApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).delete_application_cloud_watch_logging_option(input)
+    }
+    fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        DeleteApplicationInputProcessingConfigurationError,
+    > {
+        (**self).delete_application_input_processing_configuration(input)
+    }
+    fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> RusotoFuture<DeleteApplicationOutputResponse, DeleteApplicationOutputError> {
+        (**self).delete_application_output(input)
+    }
+    fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationReferenceDataSourceResponse,
+        DeleteApplicationReferenceDataSourceError,
+    > {
+        (**self).delete_application_reference_data_source(input)
+    }
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        (**self).describe_application(input)
+    }
+    fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> RusotoFuture<DiscoverInputSchemaResponse, DiscoverInputSchemaError> {
+        (**self).discover_input_schema(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> RusotoFuture<StartApplicationResponse, StartApplicationError> {
+        (**self).start_application(input)
+    }
+    fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> RusotoFuture<StopApplicationResponse, StopApplicationError> {
+        (**self).stop_application(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+impl<T: KinesisAnalytics + ?Sized> KinesisAnalytics for Box<T> {
+    fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        AddApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).add_application_cloud_watch_logging_option(input)
+    }
+    fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> RusotoFuture<AddApplicationInputResponse, AddApplicationInputError> {
+        (**self).add_application_input(input)
+    }
+    fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        AddApplicationInputProcessingConfigurationResponse,
+        AddApplicationInputProcessingConfigurationError,
+    > {
+        (**self).add_application_input_processing_configuration(input)
+    }
+    fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> RusotoFuture<AddApplicationOutputResponse, AddApplicationOutputError> {
+        (**self).add_application_output(input)
+    }
+    fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        AddApplicationReferenceDataSourceResponse,
+        AddApplicationReferenceDataSourceError,
+    > {
+        (**self).add_application_reference_data_source(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        DeleteApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).delete_application_cloud_watch_logging_option(input)
+    }
+    fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        DeleteApplicationInputProcessingConfigurationError,
+    > {
+        (**self).delete_application_input_processing_configuration(input)
+    }
+    fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> RusotoFuture<DeleteApplicationOutputResponse, DeleteApplicationOutputError> {
+        (**self).delete_application_output(input)
+    }
+    fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationReferenceDataSourceResponse,
+        DeleteApplicationReferenceDataSourceError,
+    > {
+        (**self).delete_application_reference_data_source(input)
+    }
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        (**self).describe_application(input)
+    }
+    fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> RusotoFuture<DiscoverInputSchemaResponse, DiscoverInputSchemaError> {
+        (**self).discover_input_schema(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> RusotoFuture<StartApplicationResponse, StartApplicationError> {
+        (**self).start_application(input)
+    }
+    fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> RusotoFuture<StopApplicationResponse, StopApplicationError> {
+        (**self).stop_application(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+impl<T: KinesisAnalytics + ?Sized> KinesisAnalytics for ::std::sync::Arc<T> {
+    fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        AddApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).add_application_cloud_watch_logging_option(input)
+    }
+    fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> RusotoFuture<AddApplicationInputResponse, AddApplicationInputError> {
+        (**self).add_application_input(input)
+    }
+    fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        AddApplicationInputProcessingConfigurationResponse,
+        AddApplicationInputProcessingConfigurationError,
+    > {
+        (**self).add_application_input_processing_configuration(input)
+    }
+    fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> RusotoFuture<AddApplicationOutputResponse, AddApplicationOutputError> {
+        (**self).add_application_output(input)
+    }
+    fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        AddApplicationReferenceDataSourceResponse,
+        AddApplicationReferenceDataSourceError,
+    > {
+        (**self).add_application_reference_data_source(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        DeleteApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).delete_application_cloud_watch_logging_option(input)
+    }
+    fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        DeleteApplicationInputProcessingConfigurationError,
+    > {
+        (**self).delete_application_input_processing_configuration(input)
+    }
+    fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> RusotoFuture<DeleteApplicationOutputResponse, DeleteApplicationOutputError> {
+        (**self).delete_application_output(input)
+    }
+    fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationReferenceDataSourceResponse,
+        DeleteApplicationReferenceDataSourceError,
+    > {
+        (**self).delete_application_reference_data_source(input)
+    }
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        (**self).describe_application(input)
+    }
+    fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> RusotoFuture<DiscoverInputSchemaResponse, DiscoverInputSchemaError> {
+        (**self).discover_input_schema(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> RusotoFuture<StartApplicationResponse, StartApplicationError> {
+        (**self).start_application(input)
+    }
+    fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> RusotoFuture<StopApplicationResponse, StopApplicationError> {
+        (**self).stop_application(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+/// A client for the Kinesis Analytics API.
+#[derive(Clone)]
+pub struct KinesisAnalyticsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl KinesisAnalyticsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisAnalyticsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisAnalyticsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
+        KinesisAnalyticsClient { client, region }
+    }
+}
+
+impl KinesisAnalytics for KinesisAnalyticsClient {
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a CloudWatch log stream to monitor application configuration errors. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
+    fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        AddApplicationCloudWatchLoggingOptionError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "KinesisAnalytics_20150814.AddApplicationReferenceDataSource",
+            "KinesisAnalytics_20150814.AddApplicationCloudWatchLoggingOption",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddApplicationCloudWatchLoggingOptionResponse, _>(response, "AddApplicationCloudWatchLoggingOption")
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddApplicationReferenceDataSourceResponse, _>()
+                    Err(AddApplicationCloudWatchLoggingOptionError::from_response(
+                        response,
+                    ))
                 }))
+            }
+        })
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Adds a streaming source to your Amazon Kinesis application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p> <p>You can add a streaming source either when you create an application or you can use this operation to add a streaming source after you create an application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_CreateApplication.html">CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationInput</code> action.</p></p>
+    fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> RusotoFuture<AddApplicationInputResponse, AddApplicationInputError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInput");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "KinesisAnalytics_20150814.AddApplicationInput",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddApplicationInputResponse, _>(
+                    response,
+                    "AddApplicationInput",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AddApplicationInputError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> to an application. An input processor preprocesses records on the input stream before the application&#39;s SQL code executes. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p></p>
+    fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        AddApplicationInputProcessingConfigurationResponse,
+        AddApplicationInputProcessingConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "KinesisAnalytics_20150814.AddApplicationInputProcessingConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    AddApplicationInputProcessingConfigurationResponse,
+                    _,
+                >(response, "AddApplicationInputProcessingConfiguration")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AddApplicationInputProcessingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an external destination to your Amazon Kinesis Analytics application.</p> <p>If you want Amazon Kinesis Analytics to deliver data from an in-application stream within your application to an external destination (such as an Amazon Kinesis stream, an Amazon Kinesis Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Understanding Application Output (Destination)</a>. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version.</p> <p>For the limits on the number of application inputs and outputs you can configure, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action.</p></p>
+    fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> RusotoFuture<AddApplicationOutputResponse, AddApplicationOutputError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationOutput");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "KinesisAnalytics_20150814.AddApplicationOutput",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddApplicationOutputResponse, _>(
+                    response,
+                    "AddApplicationOutput",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AddApplicationOutputError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a reference data source to an existing application.</p> <p>Amazon Kinesis Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in Amazon S3 object maps to columns in the resulting in-application table.</p> <p> For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. For the limits on data sources you can add to your application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action. </p></p>
+    fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        AddApplicationReferenceDataSourceResponse,
+        AddApplicationReferenceDataSourceError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("AddApplicationReferenceDataSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "KinesisAnalytics_20150814.AddApplicationReferenceDataSource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddApplicationReferenceDataSourceResponse, _>(
+                    response,
+                    "AddApplicationReferenceDataSource",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(AddApplicationReferenceDataSourceError::from_response(
@@ -2827,7 +4816,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: CreateApplicationRequest,
     ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2839,10 +4837,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateApplicationResponse, _>(
+                    response,
+                    "CreateApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -2859,7 +4857,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: DeleteApplicationRequest,
     ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2871,10 +4878,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteApplicationResponse, _>(
+                    response,
+                    "DeleteApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -2894,7 +4901,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         DeleteApplicationCloudWatchLoggingOptionResponse,
         DeleteApplicationCloudWatchLoggingOptionError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2905,11 +4921,11 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteApplicationCloudWatchLoggingOptionResponse, _>()
-                }))
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DeleteApplicationCloudWatchLoggingOptionResponse,
+                    _,
+                >(response, "DeleteApplicationCloudWatchLoggingOption")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteApplicationCloudWatchLoggingOptionError::from_response(response))
@@ -2926,7 +4942,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         DeleteApplicationInputProcessingConfigurationResponse,
         DeleteApplicationInputProcessingConfigurationError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2937,16 +4962,17 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DeleteApplicationInputProcessingConfigurationResponse, _>()
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DeleteApplicationInputProcessingConfigurationResponse,
+                    _,
+                >(response, "DeleteApplicationInputProcessingConfiguration")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteApplicationInputProcessingConfigurationError::from_response(response))
                 }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(DeleteApplicationInputProcessingConfigurationError::from_response(response))
-                            }))
-                        }
-                    })
+            }
+        })
     }
 
     /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes output destination configuration from your application configuration. Amazon Kinesis Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplicationOutput</code> action.</p></p>
@@ -2954,7 +4980,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: DeleteApplicationOutputRequest,
     ) -> RusotoFuture<DeleteApplicationOutputResponse, DeleteApplicationOutputError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationOutput");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2966,10 +5001,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteApplicationOutputResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteApplicationOutputResponse, _>(
+                    response,
+                    "DeleteApplicationOutput",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteApplicationOutputError::from_response(response))
@@ -2986,7 +5021,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         DeleteApplicationReferenceDataSourceResponse,
         DeleteApplicationReferenceDataSourceError,
     > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2998,10 +5042,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteApplicationReferenceDataSourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteApplicationReferenceDataSourceResponse, _>(response, "DeleteApplicationReferenceDataSource")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteApplicationReferenceDataSourceError::from_response(
@@ -3017,7 +5058,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: DescribeApplicationRequest,
     ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3029,10 +5079,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeApplicationResponse, _>(
+                    response,
+                    "DescribeApplication",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3048,7 +5098,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: DiscoverInputSchemaRequest,
     ) -> RusotoFuture<DiscoverInputSchemaResponse, DiscoverInputSchemaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("DiscoverInputSchema");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3060,10 +5119,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DiscoverInputSchemaResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DiscoverInputSchemaResponse, _>(
+                    response,
+                    "DiscoverInputSchema",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3079,7 +5138,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: ListApplicationsRequest,
     ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.ListApplications");
@@ -3088,10 +5156,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListApplicationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListApplicationsResponse, _>(
+                    response,
+                    "ListApplications",
+                )
             } else {
                 Box::new(
                     response
@@ -3108,7 +5176,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3120,10 +5197,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -3139,7 +5216,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: StartApplicationRequest,
     ) -> RusotoFuture<StartApplicationResponse, StartApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StartApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StartApplication");
@@ -3148,10 +5234,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartApplicationResponse, _>(
+                    response,
+                    "StartApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -3168,7 +5254,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: StopApplicationRequest,
     ) -> RusotoFuture<StopApplicationResponse, StopApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("StopApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StopApplication");
@@ -3177,10 +5272,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StopApplicationResponse, _>(
+                    response,
+                    "StopApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -3197,7 +5292,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.TagResource");
@@ -3206,10 +5310,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -3226,7 +5330,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.UntagResource");
@@ -3235,10 +5348,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -3255,7 +5368,16 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         &self,
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "kinesisanalytics", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3267,10 +5389,10 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateApplicationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateApplicationResponse, _>(
+                    response,
+                    "UpdateApplication",
+                )
             } else {
                 Box::new(
                     response
@@ -3282,3 +5404,760 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         })
     }
 }
+
+/// A mock of the Kinesis Analytics API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKinesisAnalytics {
+    add_application_cloud_watch_logging_option: Option<
+        Box<
+            dyn Fn(
+                    AddApplicationCloudWatchLoggingOptionRequest,
+                ) -> ::std::result::Result<
+                    AddApplicationCloudWatchLoggingOptionResponse,
+                    RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_application_input: Option<
+        Box<
+            dyn Fn(
+                    AddApplicationInputRequest,
+                ) -> ::std::result::Result<
+                    AddApplicationInputResponse,
+                    RusotoError<AddApplicationInputError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_application_input_processing_configuration: Option<
+        Box<
+            dyn Fn(
+                    AddApplicationInputProcessingConfigurationRequest,
+                ) -> ::std::result::Result<
+                    AddApplicationInputProcessingConfigurationResponse,
+                    RusotoError<AddApplicationInputProcessingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_application_output: Option<
+        Box<
+            dyn Fn(
+                    AddApplicationOutputRequest,
+                ) -> ::std::result::Result<
+                    AddApplicationOutputResponse,
+                    RusotoError<AddApplicationOutputError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_application_reference_data_source: Option<
+        Box<
+            dyn Fn(
+                    AddApplicationReferenceDataSourceRequest,
+                ) -> ::std::result::Result<
+                    AddApplicationReferenceDataSourceResponse,
+                    RusotoError<AddApplicationReferenceDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_application: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationRequest,
+                ) -> ::std::result::Result<
+                    CreateApplicationResponse,
+                    RusotoError<CreateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationRequest,
+                ) -> ::std::result::Result<
+                    DeleteApplicationResponse,
+                    RusotoError<DeleteApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application_cloud_watch_logging_option: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationCloudWatchLoggingOptionRequest,
+                ) -> ::std::result::Result<
+                    DeleteApplicationCloudWatchLoggingOptionResponse,
+                    RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application_input_processing_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationInputProcessingConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteApplicationInputProcessingConfigurationResponse,
+                    RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application_output: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationOutputRequest,
+                ) -> ::std::result::Result<
+                    DeleteApplicationOutputResponse,
+                    RusotoError<DeleteApplicationOutputError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application_reference_data_source: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationReferenceDataSourceRequest,
+                ) -> ::std::result::Result<
+                    DeleteApplicationReferenceDataSourceResponse,
+                    RusotoError<DeleteApplicationReferenceDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_application: Option<
+        Box<
+            dyn Fn(
+                    DescribeApplicationRequest,
+                ) -> ::std::result::Result<
+                    DescribeApplicationResponse,
+                    RusotoError<DescribeApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    discover_input_schema: Option<
+        Box<
+            dyn Fn(
+                    DiscoverInputSchemaRequest,
+                ) -> ::std::result::Result<
+                    DiscoverInputSchemaResponse,
+                    RusotoError<DiscoverInputSchemaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_applications: Option<
+        Box<
+            dyn Fn(
+                    ListApplicationsRequest,
+                ) -> ::std::result::Result<
+                    ListApplicationsResponse,
+                    RusotoError<ListApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_application: Option<
+        Box<
+            dyn Fn(
+                    StartApplicationRequest,
+                ) -> ::std::result::Result<
+                    StartApplicationResponse,
+                    RusotoError<StartApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_application: Option<
+        Box<
+            dyn Fn(
+                    StopApplicationRequest,
+                ) -> ::std::result::Result<
+                    StopApplicationResponse,
+                    RusotoError<StopApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_application: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationRequest,
+                ) -> ::std::result::Result<
+                    UpdateApplicationResponse,
+                    RusotoError<UpdateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKinesisAnalytics {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKinesisAnalytics {
+        Default::default()
+    }
+
+    /// Answers `add_application_cloud_watch_logging_option` with what `handler` returns for its input.
+    pub fn with_add_application_cloud_watch_logging_option<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                AddApplicationCloudWatchLoggingOptionRequest,
+            ) -> ::std::result::Result<
+                AddApplicationCloudWatchLoggingOptionResponse,
+                RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_application_cloud_watch_logging_option = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_application_input` with what `handler` returns for its input.
+    pub fn with_add_application_input<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                AddApplicationInputRequest,
+            ) -> ::std::result::Result<
+                AddApplicationInputResponse,
+                RusotoError<AddApplicationInputError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_application_input = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_application_input_processing_configuration` with what `handler` returns for its input.
+    pub fn with_add_application_input_processing_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                AddApplicationInputProcessingConfigurationRequest,
+            ) -> ::std::result::Result<
+                AddApplicationInputProcessingConfigurationResponse,
+                RusotoError<AddApplicationInputProcessingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_application_input_processing_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_application_output` with what `handler` returns for its input.
+    pub fn with_add_application_output<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                AddApplicationOutputRequest,
+            ) -> ::std::result::Result<
+                AddApplicationOutputResponse,
+                RusotoError<AddApplicationOutputError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_application_output = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_application_reference_data_source` with what `handler` returns for its input.
+    pub fn with_add_application_reference_data_source<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                AddApplicationReferenceDataSourceRequest,
+            ) -> ::std::result::Result<
+                AddApplicationReferenceDataSourceResponse,
+                RusotoError<AddApplicationReferenceDataSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_application_reference_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_application` with what `handler` returns for its input.
+    pub fn with_create_application<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                CreateApplicationRequest,
+            ) -> ::std::result::Result<
+                CreateApplicationResponse,
+                RusotoError<CreateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application` with what `handler` returns for its input.
+    pub fn with_delete_application<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DeleteApplicationRequest,
+            ) -> ::std::result::Result<
+                DeleteApplicationResponse,
+                RusotoError<DeleteApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application_cloud_watch_logging_option` with what `handler` returns for its input.
+    pub fn with_delete_application_cloud_watch_logging_option<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DeleteApplicationCloudWatchLoggingOptionRequest,
+            ) -> ::std::result::Result<
+                DeleteApplicationCloudWatchLoggingOptionResponse,
+                RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application_cloud_watch_logging_option = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application_input_processing_configuration` with what `handler` returns for its input.
+    pub fn with_delete_application_input_processing_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DeleteApplicationInputProcessingConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteApplicationInputProcessingConfigurationResponse,
+                RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application_input_processing_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application_output` with what `handler` returns for its input.
+    pub fn with_delete_application_output<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DeleteApplicationOutputRequest,
+            ) -> ::std::result::Result<
+                DeleteApplicationOutputResponse,
+                RusotoError<DeleteApplicationOutputError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application_output = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application_reference_data_source` with what `handler` returns for its input.
+    pub fn with_delete_application_reference_data_source<F>(
+        mut self,
+        handler: F,
+    ) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DeleteApplicationReferenceDataSourceRequest,
+            ) -> ::std::result::Result<
+                DeleteApplicationReferenceDataSourceResponse,
+                RusotoError<DeleteApplicationReferenceDataSourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application_reference_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_application` with what `handler` returns for its input.
+    pub fn with_describe_application<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DescribeApplicationRequest,
+            ) -> ::std::result::Result<
+                DescribeApplicationResponse,
+                RusotoError<DescribeApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `discover_input_schema` with what `handler` returns for its input.
+    pub fn with_discover_input_schema<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                DiscoverInputSchemaRequest,
+            ) -> ::std::result::Result<
+                DiscoverInputSchemaResponse,
+                RusotoError<DiscoverInputSchemaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.discover_input_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_applications` with what `handler` returns for its input.
+    pub fn with_list_applications<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                ListApplicationsRequest,
+            ) -> ::std::result::Result<
+                ListApplicationsResponse,
+                RusotoError<ListApplicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_application` with what `handler` returns for its input.
+    pub fn with_start_application<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                StartApplicationRequest,
+            ) -> ::std::result::Result<
+                StartApplicationResponse,
+                RusotoError<StartApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_application` with what `handler` returns for its input.
+    pub fn with_stop_application<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                StopApplicationRequest,
+            )
+                -> ::std::result::Result<StopApplicationResponse, RusotoError<StopApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application` with what `handler` returns for its input.
+    pub fn with_update_application<F>(mut self, handler: F) -> MockKinesisAnalytics
+    where
+        F: Fn(
+                UpdateApplicationRequest,
+            ) -> ::std::result::Result<
+                UpdateApplicationResponse,
+                RusotoError<UpdateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl KinesisAnalytics for MockKinesisAnalytics {
+    fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        AddApplicationCloudWatchLoggingOptionError,
+    > {
+        match self.add_application_cloud_watch_logging_option {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockKinesisAnalytics has no handler for add_application_cloud_watch_logging_option"),
+}
+    }
+    fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> RusotoFuture<AddApplicationInputResponse, AddApplicationInputError> {
+        match self.add_application_input {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for add_application_input"),
+        }
+    }
+    fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        AddApplicationInputProcessingConfigurationResponse,
+        AddApplicationInputProcessingConfigurationError,
+    > {
+        match self.add_application_input_processing_configuration {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockKinesisAnalytics has no handler for add_application_input_processing_configuration"),
+}
+    }
+    fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> RusotoFuture<AddApplicationOutputResponse, AddApplicationOutputError> {
+        match self.add_application_output {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for add_application_output"),
+        }
+    }
+    fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        AddApplicationReferenceDataSourceResponse,
+        AddApplicationReferenceDataSourceError,
+    > {
+        match self.add_application_reference_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockKinesisAnalytics has no handler for add_application_reference_data_source"
+            ),
+        }
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        match self.create_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for create_application"),
+        }
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        match self.delete_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for delete_application"),
+        }
+    }
+    fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        DeleteApplicationCloudWatchLoggingOptionError,
+    > {
+        match self.delete_application_cloud_watch_logging_option {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockKinesisAnalytics has no handler for delete_application_cloud_watch_logging_option"),
+}
+    }
+    fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        DeleteApplicationInputProcessingConfigurationError,
+    > {
+        match self.delete_application_input_processing_configuration {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockKinesisAnalytics has no handler for delete_application_input_processing_configuration"),
+}
+    }
+    fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> RusotoFuture<DeleteApplicationOutputResponse, DeleteApplicationOutputError> {
+        match self.delete_application_output {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for delete_application_output"),
+        }
+    }
+    fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationReferenceDataSourceResponse,
+        DeleteApplicationReferenceDataSourceError,
+    > {
+        match self.delete_application_reference_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockKinesisAnalytics has no handler for delete_application_reference_data_source"
+            ),
+        }
+    }
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        match self.describe_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for describe_application"),
+        }
+    }
+    fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> RusotoFuture<DiscoverInputSchemaResponse, DiscoverInputSchemaError> {
+        match self.discover_input_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for discover_input_schema"),
+        }
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        match self.list_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for list_applications"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for list_tags_for_resource"),
+        }
+    }
+    fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> RusotoFuture<StartApplicationResponse, StartApplicationError> {
+        match self.start_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for start_application"),
+        }
+    }
+    fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> RusotoFuture<StopApplicationResponse, StopApplicationError> {
+        match self.stop_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for stop_application"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for untag_resource"),
+        }
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        match self.update_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisAnalytics has no handler for update_application"),
+        }
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamInput {
     /// <p>The number of hours that you want to retain the data in the stream. Kinesis Video Streams retains the data in a data store that is associated with the stream.</p> <p>The default value is 0, indicating that the stream does not persist data.</p> <p>When the <code>DataRetentionInHours</code> value is 0, consumers can still consume the fragments that remain in the service host buffer, which has a retention time limit of 5 minutes and a retention memory limit of 200 MB. Fragments are removed from the buffer when either limit is reached.</p>
     #[serde(rename = "DataRetentionInHours")]
@@ -50,8 +52,63 @@ pub struct CreateStreamInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_name {
+            errors.check_length(
+                &field_path(path, "device_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "device_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, ".+");
+        }
+        if let Some(ref value) = self.media_type {
+            errors.check_length(
+                &field_path(path, "media_type"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "media_type"),
+                &value,
+                "[\\w\\-\\.\\+]+/[\\w\\-\\.\\+]+(,[\\w\\-\\.\\+]+/[\\w\\-\\.\\+]+)*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "stream_name"),
+            self.stream_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_name"),
+            &self.stream_name,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStreamOutput {
     /// <p>The Amazon Resource Name (ARN) of the stream.</p>
     #[serde(rename = "StreamARN")]
@@ -60,6 +117,7 @@ pub struct CreateStreamOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStreamInput {
     /// <p>Optional: The version of the stream that you want to delete. </p> <p>Specify the version as a safeguard to ensure that your are deleting the correct stream. To get the stream version, use the <code>DescribeStream</code> API.</p> <p>If not specified, only the <code>CreationTime</code> is checked before deleting the stream.</p>
     #[serde(rename = "CurrentVersion")]
@@ -70,11 +128,37 @@ pub struct DeleteStreamInput {
     pub stream_arn: String,
 }
 
+impl Validate for DeleteStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.current_version {
+            errors.check_length(
+                &field_path(path, "current_version"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "current_version"), &value, "[a-zA-Z0-9]+");
+        }
+        errors.check_length(
+            &field_path(path, "stream_arn"),
+            self.stream_arn.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "stream_arn"),
+            &self.stream_arn,
+            "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteStreamOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStreamInput {
     /// <p>The Amazon Resource Name (ARN) of the stream.</p>
     #[serde(rename = "StreamARN")]
@@ -86,8 +170,35 @@ pub struct DescribeStreamInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for DescribeStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStreamOutput {
     /// <p>An object that describes the stream.</p>
     #[serde(rename = "StreamInfo")]
@@ -96,6 +207,7 @@ pub struct DescribeStreamOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDataEndpointInput {
     /// <p>The name of the API action for which to get an endpoint.</p>
     #[serde(rename = "APIName")]
@@ -110,8 +222,35 @@ pub struct GetDataEndpointInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for GetDataEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDataEndpointOutput {
     /// <p>The endpoint value. To read data from the stream or to write data to it, specify this endpoint in your application.</p>
     #[serde(rename = "DataEndpoint")]
@@ -120,6 +259,7 @@ pub struct GetDataEndpointOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamsInput {
     /// <p>The maximum number of streams to return in the response. The default is 10,000.</p>
     #[serde(rename = "MaxResults")]
@@ -135,8 +275,33 @@ pub struct ListStreamsInput {
     pub stream_name_condition: Option<StreamNameCondition>,
 }
 
+impl Validate for ListStreamsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(10000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[a-zA-Z0-9+/=]*");
+        }
+        if let Some(ref value) = self.stream_name_condition {
+            value.validate_at(&field_path(path, "stream_name_condition"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStreamsOutput {
     /// <p>If the response is truncated, the call returns this element with a token. To get the next batch of streams, use this token in your next request. </p>
     #[serde(rename = "NextToken")]
@@ -149,6 +314,7 @@ pub struct ListStreamsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForStreamInput {
     /// <p>If you specify this parameter and the result of a <code>ListTagsForStream</code> call is truncated, the response includes a token that you can use in the next request to fetch the next batch of tags.</p>
     #[serde(rename = "NextToken")]
@@ -164,8 +330,44 @@ pub struct ListTagsForStreamInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for ListTagsForStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[a-zA-Z0-9+/=]*");
+        }
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForStreamOutput {
     /// <p>If you specify this parameter and the result of a <code>ListTags</code> call is truncated, the response includes a token that you can use in the next request to fetch the next set of tags.</p>
     #[serde(rename = "NextToken")]
@@ -179,7 +381,7 @@ pub struct ListTagsForStreamOutput {
 
 /// <p>An object describing a Kinesis video stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamInfo {
     /// <p>A time stamp that indicates when the stream was created.</p>
     #[serde(rename = "CreationTime")]
@@ -221,6 +423,7 @@ pub struct StreamInfo {
 
 /// <p>Specifies the condition that streams must satisfy to be returned when you list streams (see the <code>ListStreams</code> API). A condition has a comparison operation and a value. Currently, you can specify only the <code>BEGINS_WITH</code> operator, which finds streams whose names start with a given prefix. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StreamNameCondition {
     /// <p>A comparison operator. Currently, you can specify only the <code>BEGINS_WITH</code> operator, which finds streams whose names start with a given prefix.</p>
     #[serde(rename = "ComparisonOperator")]
@@ -232,7 +435,26 @@ pub struct StreamNameCondition {
     pub comparison_value: Option<String>,
 }
 
+impl Validate for StreamNameCondition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comparison_value {
+            errors.check_length(
+                &field_path(path, "comparison_value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "comparison_value"),
+                &value,
+                "[a-zA-Z0-9_.-]+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagStreamInput {
     /// <p>The Amazon Resource Name (ARN) of the resource that you want to add the tag or tags to.</p>
     #[serde(rename = "StreamARN")]
@@ -247,11 +469,50 @@ pub struct TagStreamInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagStreamOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagStreamInput {
     /// <p>The Amazon Resource Name (ARN) of the stream that you want to remove tags from.</p>
     #[serde(rename = "StreamARN")]
@@ -266,11 +527,50 @@ pub struct UntagStreamInput {
     pub tag_key_list: Vec<String>,
 }
 
+impl Validate for UntagStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+        errors.check_length(
+            &field_path(path, "tag_key_list"),
+            self.tag_key_list.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_key_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_key_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagStreamOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDataRetentionInput {
     /// <p>The version of the stream whose retention period you want to change. To get the version, call either the <code>DescribeStream</code> or the <code>ListStreams</code> API.</p>
     #[serde(rename = "CurrentVersion")]
@@ -291,11 +591,56 @@ pub struct UpdateDataRetentionInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for UpdateDataRetentionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "current_version"),
+            self.current_version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "current_version"),
+            &self.current_version,
+            "[a-zA-Z0-9]+",
+        );
+        errors.check_range(
+            &field_path(path, "data_retention_change_in_hours"),
+            self.data_retention_change_in_hours,
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDataRetentionOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStreamInput {
     /// <p>The version of the stream whose metadata you want to update.</p>
     #[serde(rename = "CurrentVersion")]
@@ -318,8 +663,68 @@ pub struct UpdateStreamInput {
     pub stream_name: Option<String>,
 }
 
+impl Validate for UpdateStreamInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "current_version"),
+            self.current_version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "current_version"),
+            &self.current_version,
+            "[a-zA-Z0-9]+",
+        );
+        if let Some(ref value) = self.device_name {
+            errors.check_length(
+                &field_path(path, "device_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "device_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+        if let Some(ref value) = self.media_type {
+            errors.check_length(
+                &field_path(path, "media_type"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "media_type"),
+                &value,
+                "[\\w\\-\\.\\+]+/[\\w\\-\\.\\+]+(,[\\w\\-\\.\\+]+/[\\w\\-\\.\\+]+)*",
+            );
+        }
+        if let Some(ref value) = self.stream_arn {
+            errors.check_length(
+                &field_path(path, "stream_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "stream_arn"),
+                &value,
+                "arn:[a-z\\d-]+:kinesisvideo:[a-z0-9-]+:[0-9]+:[a-z]+/[a-zA-Z0-9_.-]+/[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_length(
+                &field_path(path, "stream_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "[a-zA-Z0-9_.-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateStreamOutput {}
 
 /// Errors returned by CreateStream
@@ -372,7 +777,12 @@ impl CreateStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -431,7 +841,12 @@ impl DeleteStreamError {
                 "VersionMismatchException" => {
                     return RusotoError::Service(DeleteStreamError::VersionMismatch(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -483,7 +898,12 @@ impl DescribeStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -534,7 +954,12 @@ impl GetDataEndpointError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetDataEndpointError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -575,7 +1000,12 @@ impl ListStreamsError {
                 "InvalidArgumentException" => {
                     return RusotoError::Service(ListStreamsError::InvalidArgument(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -633,7 +1063,12 @@ impl ListTagsForStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -697,7 +1132,12 @@ impl TagStreamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -755,7 +1195,12 @@ impl UntagStreamError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagStreamError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -821,7 +1266,12 @@ impl UpdateDataRetentionError {
                 "VersionMismatchException" => {
                     return RusotoError::Service(UpdateDataRetentionError::VersionMismatch(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -884,7 +1334,12 @@ impl UpdateStreamError {
                 "VersionMismatchException" => {
                     return RusotoError::Service(UpdateStreamError::VersionMismatch(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -967,6 +1422,184 @@ pub trait KinesisVideo {
         input: UpdateStreamInput,
     ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError>;
 }
+const _: Option<&dyn KinesisVideo> = None;
+impl<'a, T: KinesisVideo + ?Sized> KinesisVideo for &'a T {
+    fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> RusotoFuture<CreateStreamOutput, CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> RusotoFuture<DeleteStreamOutput, DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> RusotoFuture<GetDataEndpointOutput, GetDataEndpointError> {
+        (**self).get_data_endpoint(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn tag_stream(&self, input: TagStreamInput) -> RusotoFuture<TagStreamOutput, TagStreamError> {
+        (**self).tag_stream(input)
+    }
+    fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> RusotoFuture<UntagStreamOutput, UntagStreamError> {
+        (**self).untag_stream(input)
+    }
+    fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> RusotoFuture<UpdateDataRetentionOutput, UpdateDataRetentionError> {
+        (**self).update_data_retention(input)
+    }
+    fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError> {
+        (**self).update_stream(input)
+    }
+}
+impl<T: KinesisVideo + ?Sized> KinesisVideo for Box<T> {
+    fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> RusotoFuture<CreateStreamOutput, CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> RusotoFuture<DeleteStreamOutput, DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> RusotoFuture<GetDataEndpointOutput, GetDataEndpointError> {
+        (**self).get_data_endpoint(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn tag_stream(&self, input: TagStreamInput) -> RusotoFuture<TagStreamOutput, TagStreamError> {
+        (**self).tag_stream(input)
+    }
+    fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> RusotoFuture<UntagStreamOutput, UntagStreamError> {
+        (**self).untag_stream(input)
+    }
+    fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> RusotoFuture<UpdateDataRetentionOutput, UpdateDataRetentionError> {
+        (**self).update_data_retention(input)
+    }
+    fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError> {
+        (**self).update_stream(input)
+    }
+}
+impl<T: KinesisVideo + ?Sized> KinesisVideo for ::std::sync::Arc<T> {
+    fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> RusotoFuture<CreateStreamOutput, CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> RusotoFuture<DeleteStreamOutput, DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> RusotoFuture<GetDataEndpointOutput, GetDataEndpointError> {
+        (**self).get_data_endpoint(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn tag_stream(&self, input: TagStreamInput) -> RusotoFuture<TagStreamOutput, TagStreamError> {
+        (**self).tag_stream(input)
+    }
+    fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> RusotoFuture<UntagStreamOutput, UntagStreamError> {
+        (**self).untag_stream(input)
+    }
+    fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> RusotoFuture<UpdateDataRetentionOutput, UpdateDataRetentionError> {
+        (**self).update_data_retention(input)
+    }
+    fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError> {
+        (**self).update_stream(input)
+    }
+}
 /// A client for the Kinesis Video API.
 #[derive(Clone)]
 pub struct KinesisVideoClient {
@@ -1010,9 +1643,19 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: CreateStreamInput,
     ) -> RusotoFuture<CreateStreamOutput, CreateStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/createStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateStream");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1022,6 +1665,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateStream")
                         .deserialize::<CreateStreamOutput, _>()?;
 
                     Ok(result)
@@ -1042,9 +1686,18 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: DeleteStreamInput,
     ) -> RusotoFuture<DeleteStreamOutput, DeleteStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/deleteStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1054,6 +1707,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteStream")
                         .deserialize::<DeleteStreamOutput, _>()?;
 
                     Ok(result)
@@ -1074,9 +1728,19 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: DescribeStreamInput,
     ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/describeStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeStream");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1086,6 +1750,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeStream")
                         .deserialize::<DescribeStreamOutput, _>()?;
 
                     Ok(result)
@@ -1106,9 +1771,19 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: GetDataEndpointInput,
     ) -> RusotoFuture<GetDataEndpointOutput, GetDataEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getDataEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDataEndpoint");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1118,6 +1793,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDataEndpoint")
                         .deserialize::<GetDataEndpointOutput, _>()?;
 
                     Ok(result)
@@ -1138,9 +1814,19 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: ListStreamsInput,
     ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/listStreams";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListStreams");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1150,6 +1836,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListStreams")
                         .deserialize::<ListStreamsOutput, _>()?;
 
                     Ok(result)
@@ -1170,9 +1857,19 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: ListTagsForStreamInput,
     ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/listTagsForStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForStream");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1182,6 +1879,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForStream")
                         .deserialize::<ListTagsForStreamOutput, _>()?;
 
                     Ok(result)
@@ -1199,9 +1897,18 @@ impl KinesisVideo for KinesisVideoClient {
 
     /// <p>Adds one or more tags to a stream. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>You must provide either the <code>StreamName</code> or the <code>StreamARN</code>.</p> <p>This operation requires permission for the <code>KinesisVideo:TagStream</code> action.</p> <p>Kinesis video streams support up to 50 tags.</p>
     fn tag_stream(&self, input: TagStreamInput) -> RusotoFuture<TagStreamOutput, TagStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/tagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1211,6 +1918,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagStream")
                         .deserialize::<TagStreamOutput, _>()?;
 
                     Ok(result)
@@ -1231,9 +1939,18 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: UntagStreamInput,
     ) -> RusotoFuture<UntagStreamOutput, UntagStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/untagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1243,6 +1960,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagStream")
                         .deserialize::<UntagStreamOutput, _>()?;
 
                     Ok(result)
@@ -1263,9 +1981,18 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: UpdateDataRetentionInput,
     ) -> RusotoFuture<UpdateDataRetentionOutput, UpdateDataRetentionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/updateDataRetention";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateDataRetention");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1275,6 +2002,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDataRetention")
                         .deserialize::<UpdateDataRetentionOutput, _>()?;
 
                     Ok(result)
@@ -1294,9 +2022,18 @@ impl KinesisVideo for KinesisVideoClient {
         &self,
         input: UpdateStreamInput,
     ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/updateStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1306,6 +2043,7 @@ impl KinesisVideo for KinesisVideoClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateStream")
                         .deserialize::<UpdateStreamOutput, _>()?;
 
                     Ok(result)
@@ -1321,3 +2059,356 @@ impl KinesisVideo for KinesisVideoClient {
         })
     }
 }
+
+/// A mock of the Kinesis Video API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKinesisVideo {
+    create_stream: Option<
+        Box<
+            dyn Fn(
+                    CreateStreamInput,
+                )
+                    -> ::std::result::Result<CreateStreamOutput, RusotoError<CreateStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stream: Option<
+        Box<
+            dyn Fn(
+                    DeleteStreamInput,
+                )
+                    -> ::std::result::Result<DeleteStreamOutput, RusotoError<DeleteStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_stream: Option<
+        Box<
+            dyn Fn(
+                    DescribeStreamInput,
+                )
+                    -> ::std::result::Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_data_endpoint: Option<
+        Box<
+            dyn Fn(
+                    GetDataEndpointInput,
+                ) -> ::std::result::Result<
+                    GetDataEndpointOutput,
+                    RusotoError<GetDataEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_streams: Option<
+        Box<
+            dyn Fn(
+                    ListStreamsInput,
+                )
+                    -> ::std::result::Result<ListStreamsOutput, RusotoError<ListStreamsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_stream: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForStreamInput,
+                ) -> ::std::result::Result<
+                    ListTagsForStreamOutput,
+                    RusotoError<ListTagsForStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_stream: Option<
+        Box<
+            dyn Fn(
+                    TagStreamInput,
+                )
+                    -> ::std::result::Result<TagStreamOutput, RusotoError<TagStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_stream: Option<
+        Box<
+            dyn Fn(
+                    UntagStreamInput,
+                )
+                    -> ::std::result::Result<UntagStreamOutput, RusotoError<UntagStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_data_retention: Option<
+        Box<
+            dyn Fn(
+                    UpdateDataRetentionInput,
+                ) -> ::std::result::Result<
+                    UpdateDataRetentionOutput,
+                    RusotoError<UpdateDataRetentionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_stream: Option<
+        Box<
+            dyn Fn(
+                    UpdateStreamInput,
+                )
+                    -> ::std::result::Result<UpdateStreamOutput, RusotoError<UpdateStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKinesisVideo {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKinesisVideo {
+        Default::default()
+    }
+
+    /// Answers `create_stream` with what `handler` returns for its input.
+    pub fn with_create_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                CreateStreamInput,
+            )
+                -> ::std::result::Result<CreateStreamOutput, RusotoError<CreateStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stream` with what `handler` returns for its input.
+    pub fn with_delete_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                DeleteStreamInput,
+            )
+                -> ::std::result::Result<DeleteStreamOutput, RusotoError<DeleteStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stream` with what `handler` returns for its input.
+    pub fn with_describe_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                DescribeStreamInput,
+            )
+                -> ::std::result::Result<DescribeStreamOutput, RusotoError<DescribeStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_data_endpoint` with what `handler` returns for its input.
+    pub fn with_get_data_endpoint<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                GetDataEndpointInput,
+            )
+                -> ::std::result::Result<GetDataEndpointOutput, RusotoError<GetDataEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_data_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_streams` with what `handler` returns for its input.
+    pub fn with_list_streams<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                ListStreamsInput,
+            )
+                -> ::std::result::Result<ListStreamsOutput, RusotoError<ListStreamsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_streams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_stream` with what `handler` returns for its input.
+    pub fn with_list_tags_for_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                ListTagsForStreamInput,
+            ) -> ::std::result::Result<
+                ListTagsForStreamOutput,
+                RusotoError<ListTagsForStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_stream` with what `handler` returns for its input.
+    pub fn with_tag_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                TagStreamInput,
+            ) -> ::std::result::Result<TagStreamOutput, RusotoError<TagStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_stream` with what `handler` returns for its input.
+    pub fn with_untag_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                UntagStreamInput,
+            )
+                -> ::std::result::Result<UntagStreamOutput, RusotoError<UntagStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_data_retention` with what `handler` returns for its input.
+    pub fn with_update_data_retention<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                UpdateDataRetentionInput,
+            ) -> ::std::result::Result<
+                UpdateDataRetentionOutput,
+                RusotoError<UpdateDataRetentionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_data_retention = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stream` with what `handler` returns for its input.
+    pub fn with_update_stream<F>(mut self, handler: F) -> MockKinesisVideo
+    where
+        F: Fn(
+                UpdateStreamInput,
+            )
+                -> ::std::result::Result<UpdateStreamOutput, RusotoError<UpdateStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stream = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl KinesisVideo for MockKinesisVideo {
+    fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> RusotoFuture<CreateStreamOutput, CreateStreamError> {
+        match self.create_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for create_stream"),
+        }
+    }
+    fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> RusotoFuture<DeleteStreamOutput, DeleteStreamError> {
+        match self.delete_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for delete_stream"),
+        }
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        match self.describe_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for describe_stream"),
+        }
+    }
+    fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> RusotoFuture<GetDataEndpointOutput, GetDataEndpointError> {
+        match self.get_data_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for get_data_endpoint"),
+        }
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        match self.list_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for list_streams"),
+        }
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        match self.list_tags_for_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for list_tags_for_stream"),
+        }
+    }
+    fn tag_stream(&self, input: TagStreamInput) -> RusotoFuture<TagStreamOutput, TagStreamError> {
+        match self.tag_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for tag_stream"),
+        }
+    }
+    fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> RusotoFuture<UntagStreamOutput, UntagStreamError> {
+        match self.untag_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for untag_stream"),
+        }
+    }
+    fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> RusotoFuture<UpdateDataRetentionOutput, UpdateDataRetentionError> {
+        match self.update_data_retention {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for update_data_retention"),
+        }
+    }
+    fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError> {
+        match self.update_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKinesisVideo has no handler for update_stream"),
+        }
+    }
+}
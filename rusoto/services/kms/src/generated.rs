@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains information about an alias.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AliasListEntry {
     /// <p>String that contains the key ARN.</p>
     #[serde(rename = "AliasArn")]
@@ -42,14 +43,26 @@ pub struct AliasListEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelKeyDeletionRequest {
     /// <p>The unique identifier for the customer master key (CMK) for which to cancel deletion.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for CancelKeyDeletionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelKeyDeletionResponse {
     /// <p>The unique identifier of the master key for which deletion is canceled.</p>
     #[serde(rename = "KeyId")]
@@ -58,17 +71,30 @@ pub struct CancelKeyDeletionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConnectCustomKeyStoreRequest {
     /// <p>Enter the key store ID of the custom key store that you want to connect. To find the ID of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p>
     #[serde(rename = "CustomKeyStoreId")]
     pub custom_key_store_id: String,
 }
 
+impl Validate for ConnectCustomKeyStoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "custom_key_store_id"),
+            self.custom_key_store_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConnectCustomKeyStoreResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAliasRequest {
     /// <p>Specifies the alias name. This value must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for AWS managed CMKs.</p>
     #[serde(rename = "AliasName")]
@@ -78,7 +104,30 @@ pub struct CreateAliasRequest {
     pub target_key_id: String,
 }
 
+impl Validate for CreateAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alias_name"),
+            self.alias_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "alias_name"),
+            &self.alias_name,
+            "^[a-zA-Z0-9:/_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "target_key_id"),
+            self.target_key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCustomKeyStoreRequest {
     /// <p>Identifies the AWS CloudHSM cluster for the custom key store. Enter the cluster ID of any active AWS CloudHSM cluster that is not already associated with a custom key store. To find the cluster ID, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation.</p>
     #[serde(rename = "CloudHsmClusterId")]
@@ -94,8 +143,42 @@ pub struct CreateCustomKeyStoreRequest {
     pub trust_anchor_certificate: String,
 }
 
+impl Validate for CreateCustomKeyStoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cloud_hsm_cluster_id"),
+            self.cloud_hsm_cluster_id.chars().count(),
+            Some(19),
+            Some(24),
+        );
+        errors.check_pattern(
+            &field_path(path, "cloud_hsm_cluster_id"),
+            &self.cloud_hsm_cluster_id,
+            "cluster-[2-7a-zA-Z]{11,16}",
+        );
+        errors.check_length(
+            &field_path(path, "custom_key_store_name"),
+            self.custom_key_store_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "key_store_password"),
+            self.key_store_password.chars().count(),
+            Some(7),
+            Some(32),
+        );
+        errors.check_length(
+            &field_path(path, "trust_anchor_certificate"),
+            self.trust_anchor_certificate.chars().count(),
+            Some(1),
+            Some(5000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCustomKeyStoreResponse {
     /// <p>A unique identifier for the new custom key store.</p>
     #[serde(rename = "CustomKeyStoreId")]
@@ -104,6 +187,7 @@ pub struct CreateCustomKeyStoreResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGrantRequest {
     /// <p>Allows a cryptographic operation only when the encryption context matches or includes the encryption context specified in this structure. For more information about encryption context, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p>
     #[serde(rename = "Constraints")]
@@ -132,8 +216,64 @@ pub struct CreateGrantRequest {
     pub retiring_principal: Option<String>,
 }
 
+impl Validate for CreateGrantRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "grantee_principal"),
+            self.grantee_principal.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "grantee_principal"),
+            &self.grantee_principal,
+            "^[\\w+=,.@:/-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "^[a-zA-Z0-9:/_-]+$");
+        }
+        if let Some(ref value) = self.retiring_principal {
+            errors.check_length(
+                &field_path(path, "retiring_principal"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "retiring_principal"),
+                &value,
+                "^[\\w+=,.@:/-]+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGrantResponse {
     /// <p>The unique identifier for the grant.</p> <p>You can use the <code>GrantId</code> in a subsequent <a>RetireGrant</a> or <a>RevokeGrant</a> operation.</p>
     #[serde(rename = "GrantId")]
@@ -146,6 +286,7 @@ pub struct CreateGrantResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateKeyRequest {
     /// <p>A flag to indicate whether to bypass the key policy lockout safety check.</p> <important> <p>Setting this value to true increases the risk that the CMK becomes unmanageable. Do not set this value to true indiscriminately.</p> <p>For more information, refer to the scenario in the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default-allow-root-enable-iam">Default Key Policy</a> section in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </important> <p>Use this parameter only when you include a policy in the request and you intend to prevent the principal that is making the request from making a subsequent <a>PutKeyPolicy</a> request on the CMK.</p> <p>The default value is false.</p>
     #[serde(rename = "BypassPolicyLockoutSafetyCheck")]
@@ -177,8 +318,48 @@ pub struct CreateKeyRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_key_store_id {
+            errors.check_length(
+                &field_path(path, "custom_key_store_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                Some(1),
+                Some(131072),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateKeyResponse {
     /// <p>Metadata associated with the CMK.</p>
     #[serde(rename = "KeyMetadata")]
@@ -188,7 +369,7 @@ pub struct CreateKeyResponse {
 
 /// <p>Contains information about each custom key store in the custom key store list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CustomKeyStoresListEntry {
     /// <p>A unique identifier for the AWS CloudHSM cluster that is associated with the custom key store.</p>
     #[serde(rename = "CloudHsmClusterId")]
@@ -221,6 +402,7 @@ pub struct CustomKeyStoresListEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecryptRequest {
     /// <p>Ciphertext to be decrypted. The blob includes metadata.</p>
     #[serde(rename = "CiphertextBlob")]
@@ -240,8 +422,31 @@ pub struct DecryptRequest {
     pub grant_tokens: Option<Vec<String>>,
 }
 
+impl Validate for DecryptRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ciphertext_blob"),
+            self.ciphertext_blob.len(),
+            Some(1),
+            Some(6144),
+        );
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecryptResponse {
     /// <p>ARN of the key used to perform the decryption. This value is returned if no errors are encountered during the operation.</p>
     #[serde(rename = "KeyId")]
@@ -259,31 +464,73 @@ pub struct DecryptResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAliasRequest {
     /// <p>The alias to be deleted. The alias name must begin with <code>alias/</code> followed by the alias name, such as <code>alias/ExampleAlias</code>.</p>
     #[serde(rename = "AliasName")]
     pub alias_name: String,
 }
 
+impl Validate for DeleteAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alias_name"),
+            self.alias_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "alias_name"),
+            &self.alias_name,
+            "^[a-zA-Z0-9:/_-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCustomKeyStoreRequest {
     /// <p>Enter the ID of the custom key store you want to delete. To find the ID of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p>
     #[serde(rename = "CustomKeyStoreId")]
     pub custom_key_store_id: String,
 }
 
+impl Validate for DeleteCustomKeyStoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "custom_key_store_id"),
+            self.custom_key_store_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCustomKeyStoreResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteImportedKeyMaterialRequest {
     /// <p>Identifies the CMK from which you are deleting imported key material. The <code>Origin</code> of the CMK must be <code>EXTERNAL</code>.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for DeleteImportedKeyMaterialRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCustomKeyStoresRequest {
     /// <p>Gets only information about the specified custom key store. Enter the key store ID.</p> <p>By default, this operation gets information about all custom key stores in the account and region. To limit the output to a particular custom key store, you can use either the <code>CustomKeyStoreId</code> or <code>CustomKeyStoreName</code> parameter, but not both.</p>
     #[serde(rename = "CustomKeyStoreId")]
@@ -303,8 +550,41 @@ pub struct DescribeCustomKeyStoresRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for DescribeCustomKeyStoresRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_key_store_id {
+            errors.check_length(
+                &field_path(path, "custom_key_store_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.custom_key_store_name {
+            errors.check_length(
+                &field_path(path, "custom_key_store_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCustomKeyStoresResponse {
     /// <p>Contains metadata about each custom key store.</p>
     #[serde(rename = "CustomKeyStores")]
@@ -321,6 +601,7 @@ pub struct DescribeCustomKeyStoresResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeKeyRequest {
     /// <p>A list of grant tokens.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#grant_token">Grant Tokens</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "GrantTokens")]
@@ -331,8 +612,31 @@ pub struct DescribeKeyRequest {
     pub key_id: String,
 }
 
+impl Validate for DescribeKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeKeyResponse {
     /// <p>Metadata associated with the key.</p>
     #[serde(rename = "KeyMetadata")]
@@ -341,45 +645,106 @@ pub struct DescribeKeyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableKeyRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for DisableKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableKeyRotationRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for DisableKeyRotationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisconnectCustomKeyStoreRequest {
     /// <p>Enter the ID of the custom key store you want to disconnect. To find the ID of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p>
     #[serde(rename = "CustomKeyStoreId")]
     pub custom_key_store_id: String,
 }
 
+impl Validate for DisconnectCustomKeyStoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "custom_key_store_id"),
+            self.custom_key_store_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisconnectCustomKeyStoreResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableKeyRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for EnableKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableKeyRotationRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for EnableKeyRotationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EncryptRequest {
     /// <p>Name-value pair that specifies the encryption context to be used for authenticated encryption. If used here, the same value must be supplied to the <code>Decrypt</code> API or decryption will fail. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a>.</p>
     #[serde(rename = "EncryptionContext")]
@@ -402,8 +767,37 @@ pub struct EncryptRequest {
     pub plaintext: bytes::Bytes,
 }
 
+impl Validate for EncryptRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "plaintext"),
+            self.plaintext.len(),
+            Some(1),
+            Some(4096),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EncryptResponse {
     /// <p>The encrypted plaintext. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not encoded.</p>
     #[serde(rename = "CiphertextBlob")]
@@ -421,6 +815,7 @@ pub struct EncryptResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateDataKeyRequest {
     /// <p>A set of key-value pairs that represents additional authenticated data.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "EncryptionContext")]
@@ -443,8 +838,39 @@ pub struct GenerateDataKeyRequest {
     pub number_of_bytes: Option<i64>,
 }
 
+impl Validate for GenerateDataKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.number_of_bytes {
+            errors.check_range(
+                &field_path(path, "number_of_bytes"),
+                *value,
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenerateDataKeyResponse {
     /// <p>The encrypted copy of the data key. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not encoded.</p>
     #[serde(rename = "CiphertextBlob")]
@@ -471,6 +897,7 @@ pub struct GenerateDataKeyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateDataKeyWithoutPlaintextRequest {
     /// <p>A set of key-value pairs that represents additional authenticated data.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "EncryptionContext")]
@@ -493,8 +920,39 @@ pub struct GenerateDataKeyWithoutPlaintextRequest {
     pub number_of_bytes: Option<i64>,
 }
 
+impl Validate for GenerateDataKeyWithoutPlaintextRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.number_of_bytes {
+            errors.check_range(
+                &field_path(path, "number_of_bytes"),
+                *value,
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenerateDataKeyWithoutPlaintextResponse {
     /// <p>The encrypted data key. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not encoded.</p>
     #[serde(rename = "CiphertextBlob")]
@@ -512,6 +970,7 @@ pub struct GenerateDataKeyWithoutPlaintextResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateRandomRequest {
     /// <p>Generates the random byte string in the AWS CloudHSM cluster that is associated with the specified <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. To find the ID of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p>
     #[serde(rename = "CustomKeyStoreId")]
@@ -523,8 +982,29 @@ pub struct GenerateRandomRequest {
     pub number_of_bytes: Option<i64>,
 }
 
+impl Validate for GenerateRandomRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_key_store_id {
+            errors.check_length(
+                &field_path(path, "custom_key_store_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.number_of_bytes {
+            errors.check_range(
+                &field_path(path, "number_of_bytes"),
+                *value,
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenerateRandomResponse {
     /// <p>The random byte string. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not encoded.</p>
     #[serde(rename = "Plaintext")]
@@ -538,6 +1018,7 @@ pub struct GenerateRandomResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetKeyPolicyRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -547,8 +1028,30 @@ pub struct GetKeyPolicyRequest {
     pub policy_name: String,
 }
 
+impl Validate for GetKeyPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "[\\w]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetKeyPolicyResponse {
     /// <p>A key policy document in JSON format.</p>
     #[serde(rename = "Policy")]
@@ -557,14 +1060,26 @@ pub struct GetKeyPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetKeyRotationStatusRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK. To specify a CMK in a different AWS account, you must use the key ARN.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
 }
 
+impl Validate for GetKeyRotationStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetKeyRotationStatusResponse {
     /// <p>A Boolean value that specifies whether key rotation is enabled.</p>
     #[serde(rename = "KeyRotationEnabled")]
@@ -573,6 +1088,7 @@ pub struct GetKeyRotationStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetParametersForImportRequest {
     /// <p>The identifier of the CMK into which you will import key material. The CMK's <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -585,8 +1101,19 @@ pub struct GetParametersForImportRequest {
     pub wrapping_key_spec: String,
 }
 
+impl Validate for GetParametersForImportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetParametersForImportResponse {
     /// <p>The import token to send in a subsequent <a>ImportKeyMaterial</a> request.</p>
     #[serde(rename = "ImportToken")]
@@ -631,7 +1158,7 @@ pub struct GrantConstraints {
 
 /// <p>Contains information about an entry in a list of grants.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GrantListEntry {
     /// <p>A list of key-value pairs that must be present in the encryption context of certain subsequent operations that the grant allows.</p>
     #[serde(rename = "Constraints")]
@@ -672,6 +1199,7 @@ pub struct GrantListEntry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportKeyMaterialRequest {
     /// <p>The encrypted key material to import. It must be encrypted with the public key that you received in the response to a previous <a>GetParametersForImport</a> request, using the wrapping algorithm that you specified in that request.</p>
     #[serde(rename = "EncryptedKeyMaterial")]
@@ -702,13 +1230,36 @@ pub struct ImportKeyMaterialRequest {
     pub valid_to: Option<f64>,
 }
 
+impl Validate for ImportKeyMaterialRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "encrypted_key_material"),
+            self.encrypted_key_material.len(),
+            Some(1),
+            Some(6144),
+        );
+        errors.check_length(
+            &field_path(path, "import_token"),
+            self.import_token.len(),
+            Some(1),
+            Some(6144),
+        );
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportKeyMaterialResponse {}
 
 /// <p>Contains information about each entry in the key list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyListEntry {
     /// <p>ARN of the key.</p>
     #[serde(rename = "KeyArn")]
@@ -722,7 +1273,7 @@ pub struct KeyListEntry {
 
 /// <p>Contains metadata about a customer master key (CMK).</p> <p>This data type is used as a response element for the <a>CreateKey</a> and <a>DescribeKey</a> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyMetadata {
     /// <p>The twelve-digit account ID of the AWS account that owns the CMK.</p>
     #[serde(rename = "AWSAccountId")]
@@ -786,6 +1337,7 @@ pub struct KeyMetadata {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAliasesRequest {
     /// <p>Lists only aliases that refer to the specified CMK. The value of this parameter can be the ID or Amazon Resource Name (ARN) of a CMK in the caller's account and region. You cannot use an alias name or alias ARN in this value.</p> <p>This parameter is optional. If you omit it, <code>ListAliases</code> returns all aliases in the account and region.</p>
     #[serde(rename = "KeyId")]
@@ -801,8 +1353,33 @@ pub struct ListAliasesRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for ListAliasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key_id {
+            errors.check_length(
+                &field_path(path, "key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAliasesResponse {
     /// <p>A list of aliases.</p>
     #[serde(rename = "Aliases")]
@@ -819,6 +1396,7 @@ pub struct ListAliasesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGrantsRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK. To specify a CMK in a different AWS account, you must use the key ARN.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -833,8 +1411,31 @@ pub struct ListGrantsRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for ListGrantsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGrantsResponse {
     /// <p>A list of grants.</p>
     #[serde(rename = "Grants")]
@@ -851,6 +1452,7 @@ pub struct ListGrantsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListKeyPoliciesRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -865,8 +1467,31 @@ pub struct ListKeyPoliciesRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for ListKeyPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListKeyPoliciesResponse {
     /// <p>When <code>Truncated</code> is true, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent request.</p>
     #[serde(rename = "NextMarker")]
@@ -883,6 +1508,7 @@ pub struct ListKeyPoliciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListKeysRequest {
     /// <p>Use this parameter to specify the maximum number of items to return. When this value is present, AWS KMS does not return more than the specified number of items, but it might return fewer.</p> <p>This value is optional. If you include a value, it must be between 1 and 1000, inclusive. If you do not include a value, it defaults to 100.</p>
     #[serde(rename = "Limit")]
@@ -894,8 +1520,25 @@ pub struct ListKeysRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for ListKeysRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListKeysResponse {
     /// <p>A list of customer master keys (CMKs).</p>
     #[serde(rename = "Keys")]
@@ -912,6 +1555,7 @@ pub struct ListKeysResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceTagsRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -926,8 +1570,31 @@ pub struct ListResourceTagsRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for ListResourceTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceTagsResponse {
     /// <p>When <code>Truncated</code> is true, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent request.</p> <p>Do not assume or infer any information from this value.</p>
     #[serde(rename = "NextMarker")]
@@ -944,6 +1611,7 @@ pub struct ListResourceTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRetirableGrantsRequest {
     /// <p>Use this parameter to specify the maximum number of items to return. When this value is present, AWS KMS does not return more than the specified number of items, but it might return fewer.</p> <p>This value is optional. If you include a value, it must be between 1 and 100, inclusive. If you do not include a value, it defaults to 50.</p>
     #[serde(rename = "Limit")]
@@ -958,7 +1626,36 @@ pub struct ListRetirableGrantsRequest {
     pub retiring_principal: String,
 }
 
+impl Validate for ListRetirableGrantsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(1000));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0020-\\u00FF]*");
+        }
+        errors.check_length(
+            &field_path(path, "retiring_principal"),
+            self.retiring_principal.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "retiring_principal"),
+            &self.retiring_principal,
+            "^[\\w+=,.@:/-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutKeyPolicyRequest {
     /// <p>A flag to indicate whether to bypass the key policy lockout safety check.</p> <important> <p>Setting this value to true increases the risk that the CMK becomes unmanageable. Do not set this value to true indiscriminately.</p> <p>For more information, refer to the scenario in the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default-allow-root-enable-iam">Default Key Policy</a> section in the <i>AWS Key Management Service Developer Guide</i>.</p> </important> <p>Use this parameter only when you intend to prevent the principal that is making the request from making a subsequent <code>PutKeyPolicy</code> request on the CMK.</p> <p>The default value is false.</p>
     #[serde(rename = "BypassPolicyLockoutSafetyCheck")]
@@ -975,7 +1672,41 @@ pub struct PutKeyPolicyRequest {
     pub policy_name: String,
 }
 
+impl Validate for PutKeyPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "policy"),
+            self.policy.chars().count(),
+            Some(1),
+            Some(131072),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy"),
+            &self.policy,
+            "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+        );
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_name"),
+            &self.policy_name,
+            "[\\w]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReEncryptRequest {
     /// <p>Ciphertext of the data to reencrypt.</p>
     #[serde(rename = "CiphertextBlob")]
@@ -1002,8 +1733,37 @@ pub struct ReEncryptRequest {
     pub source_encryption_context: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for ReEncryptRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ciphertext_blob"),
+            self.ciphertext_blob.len(),
+            Some(1),
+            Some(6144),
+        );
+        errors.check_length(
+            &field_path(path, "destination_key_id"),
+            self.destination_key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.grant_tokens {
+            errors.check_length(
+                &field_path(path, "grant_tokens"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "grant_tokens"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReEncryptResponse {
     /// <p>The reencrypted data. When you use the HTTP API or the AWS CLI, the value is Base64-encoded. Otherwise, it is not encoded.</p>
     #[serde(rename = "CiphertextBlob")]
@@ -1025,6 +1785,7 @@ pub struct ReEncryptResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetireGrantRequest {
     /// <p><p>Unique identifier of the grant to retire. The grant ID is returned in the response to a <code>CreateGrant</code> operation.</p> <ul> <li> <p>Grant ID Example - 0123456789012345678901234567890123456789012345678901234567890123</p> </li> </ul></p>
     #[serde(rename = "GrantId")]
@@ -1040,7 +1801,37 @@ pub struct RetireGrantRequest {
     pub key_id: Option<String>,
 }
 
+impl Validate for RetireGrantRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.grant_id {
+            errors.check_length(
+                &field_path(path, "grant_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.grant_token {
+            errors.check_length(
+                &field_path(path, "grant_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8192),
+            );
+        }
+        if let Some(ref value) = self.key_id {
+            errors.check_length(
+                &field_path(path, "key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeGrantRequest {
     /// <p>Identifier of the grant to be revoked.</p>
     #[serde(rename = "GrantId")]
@@ -1050,7 +1841,25 @@ pub struct RevokeGrantRequest {
     pub key_id: String,
 }
 
+impl Validate for RevokeGrantRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "grant_id"),
+            self.grant_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduleKeyDeletionRequest {
     /// <p>The unique identifier of the customer master key (CMK) to delete.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -1061,8 +1870,27 @@ pub struct ScheduleKeyDeletionRequest {
     pub pending_window_in_days: Option<i64>,
 }
 
+impl Validate for ScheduleKeyDeletionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.pending_window_in_days {
+            errors.check_range(
+                &field_path(path, "pending_window_in_days"),
+                *value,
+                Some(1),
+                Some(365),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduleKeyDeletionResponse {
     /// <p>The date and time after which AWS KMS deletes the customer master key (CMK).</p>
     #[serde(rename = "DeletionDate")]
@@ -1085,7 +1913,25 @@ pub struct Tag {
     pub tag_value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tag_key"),
+            self.tag_key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "tag_value"),
+            self.tag_value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>A unique identifier for the CMK you are tagging.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -1095,7 +1941,23 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>A unique identifier for the CMK from which you are removing tags.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
@@ -1105,7 +1967,23 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAliasRequest {
     /// <p>Specifies the name of the alias to change. This value must begin with <code>alias/</code> followed by the alias name, such as <code>alias/ExampleAlias</code>.</p>
     #[serde(rename = "AliasName")]
@@ -1115,7 +1993,30 @@ pub struct UpdateAliasRequest {
     pub target_key_id: String,
 }
 
+impl Validate for UpdateAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alias_name"),
+            self.alias_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "alias_name"),
+            &self.alias_name,
+            "^[a-zA-Z0-9:/_-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "target_key_id"),
+            self.target_key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCustomKeyStoreRequest {
     /// <p>Associates the custom key store with a related AWS CloudHSM cluster. </p> <p>Enter the cluster ID of the cluster that you used to create the custom key store or a cluster that shares a backup history and has the same cluster certificate as the original cluster. You cannot use this parameter to associate a custom key store with an unrelated cluster. In addition, the replacement cluster must <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">fulfill the requirements</a> for a cluster associated with a custom key store. To view the cluster certificate of a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation.</p>
     #[serde(rename = "CloudHsmClusterId")]
@@ -1134,11 +2035,52 @@ pub struct UpdateCustomKeyStoreRequest {
     pub new_custom_key_store_name: Option<String>,
 }
 
+impl Validate for UpdateCustomKeyStoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_hsm_cluster_id {
+            errors.check_length(
+                &field_path(path, "cloud_hsm_cluster_id"),
+                value.chars().count(),
+                Some(19),
+                Some(24),
+            );
+            errors.check_pattern(
+                &field_path(path, "cloud_hsm_cluster_id"),
+                &value,
+                "cluster-[2-7a-zA-Z]{11,16}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "custom_key_store_id"),
+            self.custom_key_store_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        if let Some(ref value) = self.key_store_password {
+            errors.check_length(
+                &field_path(path, "key_store_password"),
+                value.chars().count(),
+                Some(7),
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.new_custom_key_store_name {
+            errors.check_length(
+                &field_path(path, "new_custom_key_store_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCustomKeyStoreResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateKeyDescriptionRequest {
     /// <p>New description for the CMK.</p>
     #[serde(rename = "Description")]
@@ -1148,6 +2090,23 @@ pub struct UpdateKeyDescriptionRequest {
     pub key_id: String,
 }
 
+impl Validate for UpdateKeyDescriptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(8192),
+        );
+        errors.check_length(
+            &field_path(path, "key_id"),
+            self.key_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 /// Errors returned by CancelKeyDeletion
 #[derive(Debug, PartialEq)]
 pub enum CancelKeyDeletionError {
@@ -1182,7 +2141,12 @@ impl CancelKeyDeletionError {
                 "NotFoundException" => {
                     return RusotoError::Service(CancelKeyDeletionError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1247,7 +2211,12 @@ impl ConnectCustomKeyStoreError {
                 "KMSInternalException" => {
                     return RusotoError::Service(ConnectCustomKeyStoreError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1314,7 +2283,12 @@ impl CreateAliasError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateAliasError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1395,7 +2369,12 @@ impl CreateCustomKeyStoreError {
                 "KMSInternalException" => {
                     return RusotoError::Service(CreateCustomKeyStoreError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1469,7 +2448,12 @@ impl CreateGrantError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateGrantError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1556,7 +2540,12 @@ impl CreateKeyError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(CreateKeyError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1633,7 +2622,12 @@ impl DecryptError {
                 "NotFoundException" => {
                     return RusotoError::Service(DecryptError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1688,7 +2682,12 @@ impl DeleteAliasError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1745,7 +2744,12 @@ impl DeleteCustomKeyStoreError {
                 "KMSInternalException" => {
                     return RusotoError::Service(DeleteCustomKeyStoreError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1816,7 +2820,12 @@ impl DeleteImportedKeyMaterialError {
                         DeleteImportedKeyMaterialError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1861,7 +2870,12 @@ impl DescribeCustomKeyStoresError {
                 "KMSInternalException" => {
                     return RusotoError::Service(DescribeCustomKeyStoresError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1910,7 +2924,12 @@ impl DescribeKeyError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeKeyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1966,7 +2985,12 @@ impl DisableKeyError {
                 "NotFoundException" => {
                     return RusotoError::Service(DisableKeyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2037,7 +3061,12 @@ impl DisableKeyRotationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2092,7 +3121,12 @@ impl DisconnectCustomKeyStoreError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2152,7 +3186,12 @@ impl EnableKeyError {
                 "NotFoundException" => {
                     return RusotoError::Service(EnableKeyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2222,7 +3261,12 @@ impl EnableKeyRotationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2296,7 +3340,12 @@ impl EncryptError {
                 "NotFoundException" => {
                     return RusotoError::Service(EncryptError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2371,7 +3420,12 @@ impl GenerateDataKeyError {
                 "NotFoundException" => {
                     return RusotoError::Service(GenerateDataKeyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2464,7 +3518,12 @@ impl GenerateDataKeyWithoutPlaintextError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2523,7 +3582,12 @@ impl GenerateRandomError {
                 "KMSInternalException" => {
                     return RusotoError::Service(GenerateRandomError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2579,7 +3643,12 @@ impl GetKeyPolicyError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetKeyPolicyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2647,7 +3716,12 @@ impl GetKeyRotationStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2716,7 +3790,12 @@ impl GetParametersForImportError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2807,7 +3886,12 @@ impl ImportKeyMaterialError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2869,7 +3953,12 @@ impl ListAliasesError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListAliasesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2931,7 +4020,12 @@ impl ListGrantsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListGrantsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2989,7 +4083,12 @@ impl ListKeyPoliciesError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListKeyPoliciesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3036,7 +4135,12 @@ impl ListKeysError {
                 "KMSInternalException" => {
                     return RusotoError::Service(ListKeysError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3086,7 +4190,12 @@ impl ListResourceTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListResourceTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3144,7 +4253,12 @@ impl ListRetirableGrantsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListRetirableGrantsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3218,7 +4332,12 @@ impl PutKeyPolicyError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(PutKeyPolicyError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3298,7 +4417,12 @@ impl ReEncryptError {
                 "NotFoundException" => {
                     return RusotoError::Service(ReEncryptError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3369,7 +4493,12 @@ impl RetireGrantError {
                 "NotFoundException" => {
                     return RusotoError::Service(RetireGrantError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3433,7 +4562,12 @@ impl RevokeGrantError {
                 "NotFoundException" => {
                     return RusotoError::Service(RevokeGrantError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3493,7 +4627,12 @@ impl ScheduleKeyDeletionError {
                 "NotFoundException" => {
                     return RusotoError::Service(ScheduleKeyDeletionError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3553,7 +4692,12 @@ impl TagResourceError {
                     return RusotoError::Service(TagResourceError::NotFound(err.msg))
                 }
                 "TagException" => return RusotoError::Service(TagResourceError::Tag(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3609,7 +4753,12 @@ impl UntagResourceError {
                     return RusotoError::Service(UntagResourceError::NotFound(err.msg))
                 }
                 "TagException" => return RusotoError::Service(UntagResourceError::Tag(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3661,7 +4810,12 @@ impl UpdateAliasError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3739,7 +4893,12 @@ impl UpdateCustomKeyStoreError {
                 "KMSInternalException" => {
                     return RusotoError::Service(UpdateCustomKeyStoreError::KMSInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3802,7 +4961,12 @@ impl UpdateKeyDescriptionError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateKeyDescriptionError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4034,945 +5198,2103 @@ pub trait Kms {
         input: UpdateKeyDescriptionRequest,
     ) -> RusotoFuture<(), UpdateKeyDescriptionError>;
 }
-/// A client for the KMS API.
-#[derive(Clone)]
-pub struct KmsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl KmsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> KmsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> KmsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> KmsClient {
-        KmsClient { client, region }
-    }
-}
-
-impl Kms for KmsClient {
-    /// <p>Cancels the deletion of a customer master key (CMK). When this operation is successful, the CMK is set to the <code>Disabled</code> state. To enable a CMK, use <a>EnableKey</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling and canceling deletion of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+const _: Option<&dyn Kms> = None;
+impl<'a, T: Kms + ?Sized> Kms for &'a T {
     fn cancel_key_deletion(
         &self,
         input: CancelKeyDeletionRequest,
     ) -> RusotoFuture<CancelKeyDeletionResponse, CancelKeyDeletionError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelKeyDeletionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelKeyDeletionError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_key_deletion(input)
     }
-
-    /// <p>Connects or reconnects a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> to its associated AWS CloudHSM cluster.</p> <p>The custom key store must be connected before you can create customer master keys (CMKs) in the key store or use the CMKs it contains. You can disconnect and reconnect a custom key store at any time.</p> <p>To connect a custom key store, its associated AWS CloudHSM cluster must have at least one active HSM. To get the number of active HSMs in a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation. To add HSMs to the cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation.</p> <p>The connection process can take an extended amount of time to complete; up to 20 minutes. This operation starts the connection process, but it does not wait for it to complete. When it succeeds, this operation quickly returns an HTTP 200 response and a JSON object with no properties. However, this response does not indicate that the custom key store is connected. To get the connection state of the custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>During the connection process, AWS KMS finds the AWS CloudHSM cluster that is associated with the custom key store, creates the connection infrastructure, connects to the cluster, logs into the AWS CloudHSM client as the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user</a> (CU), and rotates its password.</p> <p>The <code>ConnectCustomKeyStore</code> operation might fail for various reasons. To find the reason, use the <a>DescribeCustomKeyStores</a> operation and see the <code>ConnectionErrorCode</code> in the response. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>To fix the failure, use the <a>DisconnectCustomKeyStore</a> operation to disconnect the custom key store, correct the error, use the <a>UpdateCustomKeyStore</a> operation if necessary, and then use <code>ConnectCustomKeyStore</code> again.</p> <p>If you are having trouble connecting or disconnecting a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn connect_custom_key_store(
         &self,
         input: ConnectCustomKeyStoreRequest,
     ) -> RusotoFuture<ConnectCustomKeyStoreResponse, ConnectCustomKeyStoreError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ConnectCustomKeyStore");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ConnectCustomKeyStoreResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ConnectCustomKeyStoreError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).connect_custom_key_store(input)
     }
-
-    /// <p>Creates a display name for a customer managed customer master key (CMK). You can use an alias to identify a CMK in selected operations, such as <a>Encrypt</a> and <a>GenerateDataKey</a>. </p> <p>Each CMK can have multiple aliases, but each alias points to only one CMK. The alias name must be unique in the AWS account and region. To simplify code that runs in multiple regions, use the same alias name, but point it to a different CMK in each region. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation.</p> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> <p>The alias and the CMK it is mapped to must be in the same AWS account and the same region. You cannot perform this operation on an alias in a different AWS account.</p> <p>To map an existing alias to a different CMK, call <a>UpdateAlias</a>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn create_alias(&self, input: CreateAliasRequest) -> RusotoFuture<(), CreateAliasError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.CreateAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_alias(input)
     }
-
-    /// <p>Creates a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that is associated with an <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/clusters.html">AWS CloudHSM cluster</a> that you own and manage.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>Before you create the custom key store, you must assemble the required elements, including an AWS CloudHSM cluster that fulfills the requirements for a custom key store. For details about the required elements, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the operation completes successfully, it returns the ID of the new custom key store. Before you can use your new custom key store, you need to use the <a>ConnectCustomKeyStore</a> operation to connect the new key store to its AWS CloudHSM cluster. Even if you are not going to use your custom key store immediately, you might want to connect it to verify that all settings are correct and then disconnect it until you are ready to use it.</p> <p>For help with failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn create_custom_key_store(
         &self,
         input: CreateCustomKeyStoreRequest,
     ) -> RusotoFuture<CreateCustomKeyStoreResponse, CreateCustomKeyStoreError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.CreateCustomKeyStore");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCustomKeyStoreResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateCustomKeyStoreError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_custom_key_store(input)
     }
-
-    /// <p>Adds a grant to a customer master key (CMK). The grant allows the grantee principal to use the CMK when the conditions specified in the grant are met. When setting permissions, grants are an alternative to key policies. </p> <p>To create a grant that allows a cryptographic operation only when the encryption context in the operation request matches or includes a specified encryption context, use the <code>Constraints</code> parameter. For details, see <a>GrantConstraints</a>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter. For more information about grants, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/grants.html">Grants</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn create_grant(
         &self,
         input: CreateGrantRequest,
     ) -> RusotoFuture<CreateGrantResponse, CreateGrantError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.CreateGrant");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGrantResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGrantError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_grant(input)
     }
-
-    /// <p>Creates a customer managed <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master key</a> (CMK) in your AWS account.</p> <p>You can use a CMK to encrypt small amounts of data (up to 4096 bytes) directly. But CMKs are more commonly used to encrypt the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data keys</a> that are used to encrypt data.</p> <p>To create a CMK for imported key material, use the <code>Origin</code> parameter with a value of <code>EXTERNAL</code>.</p> <p>To create a CMK in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, use the <code>CustomKeyStoreId</code> parameter to specify the custom key store. You must also use the <code>Origin</code> parameter with a value of <code>AWS_CLOUDHSM</code>. The AWS CloudHSM cluster that is associated with the custom key store must have at least two active HSMs in different Availability Zones in the AWS Region.</p> <p>You cannot use this operation to create a CMK in a different AWS account.</p>
     fn create_key(
         &self,
         input: CreateKeyRequest,
     ) -> RusotoFuture<CreateKeyResponse, CreateKeyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.CreateKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateKeyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_key(input)
     }
-
-    /// <p>Decrypts ciphertext. Ciphertext is plaintext that has been previously encrypted by using any of the following operations:</p> <ul> <li> <p> <a>GenerateDataKey</a> </p> </li> <li> <p> <a>GenerateDataKeyWithoutPlaintext</a> </p> </li> <li> <p> <a>Encrypt</a> </p> </li> </ul> <p>Whenever possible, use key policies to give users permission to call the Decrypt operation on the CMK, instead of IAM policies. Otherwise, you might create an IAM user policy that gives the user Decrypt permission on all CMKs. This user could decrypt ciphertext that was encrypted by CMKs in other accounts if the key policy for the cross-account CMK permits it. If you must use an IAM policy for <code>Decrypt</code> permissions, limit the user to particular CMKs or particular trusted accounts.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn decrypt(&self, input: DecryptRequest) -> RusotoFuture<DecryptResponse, DecryptError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.Decrypt");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DecryptResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DecryptError::from_response(response))),
-                )
-            }
-        })
+        (**self).decrypt(input)
     }
-
-    /// <p>Deletes the specified alias. You cannot perform this operation on an alias in a different AWS account. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation. </p> <p>Each CMK can have multiple aliases. To change the alias of a CMK, use <a>DeleteAlias</a> to delete the current alias and <a>CreateAlias</a> to create a new alias. To associate an existing alias with a different customer master key (CMK), call <a>UpdateAlias</a>.</p>
     fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DeleteAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_alias(input)
     }
-
-    /// <p>Deletes a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. This operation does not delete the AWS CloudHSM cluster that is associated with the custom key store, or affect any users or keys in the cluster.</p> <p>The custom key store that you delete cannot contain any AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master keys (CMKs)</a>. Before deleting the key store, verify that you will never need to use any of the CMKs in the key store for any cryptographic operations. Then, use <a>ScheduleKeyDeletion</a> to delete the AWS KMS customer master keys (CMKs) from the key store. When the scheduled waiting period expires, the <code>ScheduleKeyDeletion</code> operation deletes the CMKs. Then it makes a best effort to delete the key material from the associated cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>After all CMKs are deleted from AWS KMS, use <a>DisconnectCustomKeyStore</a> to disconnect the key store from AWS KMS. Then, you can delete the custom key store.</p> <p>Instead of deleting the custom key store, consider using <a>DisconnectCustomKeyStore</a> to disconnect it from AWS KMS. While the key store is disconnected, you cannot create or use the CMKs in the key store. But, you do not need to delete CMKs and you can reconnect a disconnected custom key store at any time.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
     fn delete_custom_key_store(
         &self,
         input: DeleteCustomKeyStoreRequest,
     ) -> RusotoFuture<DeleteCustomKeyStoreResponse, DeleteCustomKeyStoreError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DeleteCustomKeyStore");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCustomKeyStoreResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteCustomKeyStoreError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_custom_key_store(input)
     }
-
-    /// <p>Deletes key material that you previously imported. This operation makes the specified customer master key (CMK) unusable. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>When the specified CMK is in the <code>PendingDeletion</code> state, this operation does not change the CMK's state. Otherwise, it changes the CMK's state to <code>PendingImport</code>.</p> <p>After you delete key material, you can use <a>ImportKeyMaterial</a> to reimport the same key material into the CMK.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn delete_imported_key_material(
         &self,
         input: DeleteImportedKeyMaterialRequest,
     ) -> RusotoFuture<(), DeleteImportedKeyMaterialError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DeleteImportedKeyMaterial");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteImportedKeyMaterialError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_imported_key_material(input)
     }
-
-    /// <p>Gets information about <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a> in the account and region.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>By default, this operation returns information about all custom key stores in the account and region. To get only information about a particular custom key store, use either the <code>CustomKeyStoreName</code> or <code>CustomKeyStoreId</code> parameter (but not both).</p> <p>To determine whether the custom key store is connected to its AWS CloudHSM cluster, use the <code>ConnectionState</code> element in the response. If an attempt to connect the custom key store failed, the <code>ConnectionState</code> value is <code>FAILED</code> and the <code>ConnectionErrorCode</code> element in the response indicates the cause of the failure. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>Custom key stores have a <code>DISCONNECTED</code> connection state if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If your custom key store state is <code>CONNECTED</code> but you are having trouble using it, make sure that its associated AWS CloudHSM cluster is active and contains the minimum number of HSMs required for the operation, if any.</p> <p> For help repairing your custom key store, see the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> topic in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn describe_custom_key_stores(
         &self,
         input: DescribeCustomKeyStoresRequest,
     ) -> RusotoFuture<DescribeCustomKeyStoresResponse, DescribeCustomKeyStoresError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DescribeCustomKeyStores");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCustomKeyStoresResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCustomKeyStoresError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_custom_key_stores(input)
     }
-
-    /// <p>Provides detailed information about the specified customer master key (CMK).</p> <p>You can use <code>DescribeKey</code> on a predefined AWS alias, that is, an AWS alias with no key ID. When you do, AWS KMS associates the alias with an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">AWS managed CMK</a> and returns its <code>KeyId</code> and <code>Arn</code> in the response.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
     fn describe_key(
         &self,
         input: DescribeKeyRequest,
     ) -> RusotoFuture<DescribeKeyResponse, DescribeKeyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DescribeKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeKeyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_key(input)
     }
-
-    /// <p>Sets the state of a customer master key (CMK) to disabled, thereby preventing its use for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn disable_key(&self, input: DisableKeyRequest) -> RusotoFuture<(), DisableKeyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DisableKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_key(input)
     }
-
-    /// <p>Disables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn disable_key_rotation(
         &self,
         input: DisableKeyRotationRequest,
     ) -> RusotoFuture<(), DisableKeyRotationError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableKeyRotationError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_key_rotation(input)
     }
-
-    /// <p>Disconnects the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> from its associated AWS CloudHSM cluster. While a custom key store is disconnected, you can manage the custom key store and its customer master keys (CMKs), but you cannot create or use CMKs in the custom key store. You can reconnect the custom key store at any time.</p> <note> <p>While a custom key store is disconnected, all attempts to create customer master keys (CMKs) in the custom key store or to use existing CMKs in cryptographic operations will fail. This action can prevent users from storing and accessing sensitive data.</p> </note> <p/> <p>To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation. To reconnect a custom key store, use the <a>ConnectCustomKeyStore</a> operation.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
     fn disconnect_custom_key_store(
         &self,
         input: DisconnectCustomKeyStoreRequest,
     ) -> RusotoFuture<DisconnectCustomKeyStoreResponse, DisconnectCustomKeyStoreError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.DisconnectCustomKeyStore");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisconnectCustomKeyStoreResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisconnectCustomKeyStoreError::from_response(response))
-                }))
-            }
-        })
+        (**self).disconnect_custom_key_store(input)
     }
-
-    /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn enable_key(&self, input: EnableKeyRequest) -> RusotoFuture<(), EnableKeyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.EnableKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_key(input)
     }
-
-    /// <p>Enables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>You cannot enable automatic rotation of CMKs with imported key material or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn enable_key_rotation(
         &self,
         input: EnableKeyRotationRequest,
     ) -> RusotoFuture<(), EnableKeyRotationError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableKeyRotationError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_key_rotation(input)
     }
-
-    /// <p>Encrypts plaintext into ciphertext by using a customer master key (CMK). The <code>Encrypt</code> operation has two primary use cases:</p> <ul> <li> <p>You can encrypt up to 4 kilobytes (4096 bytes) of arbitrary data such as an RSA key, a database password, or other sensitive information.</p> </li> <li> <p>You can use the <code>Encrypt</code> operation to move encrypted data from one AWS region to another. In the first region, generate a data key and use the plaintext key to encrypt the data. Then, in the new region, call the <code>Encrypt</code> method on same plaintext data key. Now, you can safely move the encrypted data and encrypted data key to the new region, and decrypt in the new region when necessary.</p> </li> </ul> <p>You don't need use this operation to encrypt a data key within a region. The <a>GenerateDataKey</a> and <a>GenerateDataKeyWithoutPlaintext</a> operations return an encrypted data key.</p> <p>Also, you don't need to use this operation to encrypt data in your application. You can use the plaintext and encrypted data keys that the <code>GenerateDataKey</code> operation returns.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
     fn encrypt(&self, input: EncryptRequest) -> RusotoFuture<EncryptResponse, EncryptError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.Encrypt");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<EncryptResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EncryptError::from_response(response))),
-                )
-            }
-        })
+        (**self).encrypt(input)
     }
-
-    /// <p>Generates a unique data key. This operation returns a plaintext copy of the data key and a copy that is encrypted under a customer master key (CMK) that you specify. You can use the plaintext key to encrypt your data outside of KMS and store the encrypted data key with the encrypted data.</p> <p> <code>GenerateDataKey</code> returns a unique data key for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the data key.</p> <p>To generate a data key, you need to specify the customer master key (CMK) that will be used to encrypt the data key. You must also specify the length of the data key using either the <code>KeySpec</code> or <code>NumberOfBytes</code> field (but not both). For common key lengths (128-bit and 256-bit symmetric keys), we recommend that you use <code>KeySpec</code>. To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p> <p>You will find the plaintext copy of the data key in the <code>Plaintext</code> field of the response, and the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>We recommend that you use the following pattern to encrypt data locally in your application:</p> <ol> <li> <p>Use the <code>GenerateDataKey</code> operation to get a data encryption key.</p> </li> <li> <p>Use the plaintext data key (returned in the <code>Plaintext</code> field of the response) to encrypt data locally, then erase the plaintext data key from memory.</p> </li> <li> <p>Store the encrypted data key (returned in the <code>CiphertextBlob</code> field of the response) alongside the locally encrypted data.</p> </li> </ol> <p>To decrypt data locally:</p> <ol> <li> <p>Use the <a>Decrypt</a> operation to decrypt the encrypted data key. The operation returns a plaintext copy of the data key.</p> </li> <li> <p>Use the plaintext data key to decrypt data locally, then erase the plaintext data key from memory.</p> </li> </ol> <p>To get only an encrypted copy of the data key, use <a>GenerateDataKeyWithoutPlaintext</a>. To get a cryptographically secure random byte string, use <a>GenerateRandom</a>.</p> <p>You can use the optional encryption context to add additional security to your encryption operation. When you specify an <code>EncryptionContext</code> in the <code>GenerateDataKey</code> operation, you must specify the same encryption context (a case-sensitive exact match) in your request to <a>Decrypt</a> the data key. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn generate_data_key(
         &self,
         input: GenerateDataKeyRequest,
     ) -> RusotoFuture<GenerateDataKeyResponse, GenerateDataKeyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.GenerateDataKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GenerateDataKeyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GenerateDataKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).generate_data_key(input)
     }
-
-    /// <p>Generates a unique data key. This operation returns a data key that is encrypted under a customer master key (CMK) that you specify. <code>GenerateDataKeyWithoutPlaintext</code> is identical to <a>GenerateDataKey</a> except that returns only the encrypted copy of the data key.</p> <p>Like <code>GenerateDataKey</code>, <code>GenerateDataKeyWithoutPlaintext</code> returns a unique data key for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the data key.</p> <p>This operation is useful for systems that need to encrypt data at some point, but not immediately. When you need to encrypt the data, you call the <a>Decrypt</a> operation on the encrypted copy of the key.</p> <p>It's also useful in distributed systems with different levels of trust. For example, you might store encrypted data in containers. One component of your system creates new containers and stores an encrypted data key with each container. Then, a different component puts the data into the containers. That component first decrypts the data key, uses the plaintext data key to encrypt data, puts the encrypted data into the container, and then destroys the plaintext data key. In this system, the component that creates the containers never sees the plaintext data key.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn generate_data_key_without_plaintext(
         &self,
         input: GenerateDataKeyWithoutPlaintextRequest,
     ) -> RusotoFuture<GenerateDataKeyWithoutPlaintextResponse, GenerateDataKeyWithoutPlaintextError>
     {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "TrentService.GenerateDataKeyWithoutPlaintext",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GenerateDataKeyWithoutPlaintextResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GenerateDataKeyWithoutPlaintextError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).generate_data_key_without_plaintext(input)
     }
-
-    /// <p>Returns a random byte string that is cryptographically secure.</p> <p>By default, the random byte string is generated in AWS KMS. To generate the byte string in the AWS CloudHSM cluster that is associated with a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, specify the custom key store ID.</p> <p>For more information about entropy and random number generation, see the <a href="https://d0.awsstatic.com/whitepapers/KMS-Cryptographic-Details.pdf">AWS Key Management Service Cryptographic Details</a> whitepaper.</p>
     fn generate_random(
         &self,
         input: GenerateRandomRequest,
     ) -> RusotoFuture<GenerateRandomResponse, GenerateRandomError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.GenerateRandom");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GenerateRandomResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GenerateRandomError::from_response(response))),
-                )
-            }
-        })
+        (**self).generate_random(input)
     }
-
-    /// <p>Gets a key policy attached to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p>
     fn get_key_policy(
         &self,
         input: GetKeyPolicyRequest,
     ) -> RusotoFuture<GetKeyPolicyResponse, GetKeyPolicyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetKeyPolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetKeyPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_key_policy(input)
     }
-
-    /// <p>Gets a Boolean value that indicates whether <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> is enabled for the specified customer master key (CMK).</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <ul> <li> <p>Disabled: The key rotation status does not change when you disable a CMK. However, while the CMK is disabled, AWS KMS does not rotate the backing key.</p> </li> <li> <p>Pending deletion: While a CMK is pending deletion, its key rotation status is <code>false</code> and AWS KMS does not rotate the backing key. If you cancel the deletion, the original key rotation status is restored.</p> </li> </ul> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
     fn get_key_rotation_status(
         &self,
         input: GetKeyRotationStatusRequest,
     ) -> RusotoFuture<GetKeyRotationStatusResponse, GetKeyRotationStatusError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.GetKeyRotationStatus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetKeyRotationStatusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetKeyRotationStatusError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_key_rotation_status(input)
     }
-
-    /// <p>Returns the items you need in order to import key material into AWS KMS from your existing key management infrastructure. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>You must specify the key ID of the customer master key (CMK) into which you will import key material. This CMK's <code>Origin</code> must be <code>EXTERNAL</code>. You must also specify the wrapping algorithm and type of wrapping key (public key) that you will use to encrypt the key material. You cannot perform this operation on a CMK in a different AWS account.</p> <p>This operation returns a public key and an import token. Use the public key to encrypt the key material. Store the import token to send with a subsequent <a>ImportKeyMaterial</a> request. The public key and import token from the same response must be used together. These items are valid for 24 hours. When they expire, they cannot be used for a subsequent <a>ImportKeyMaterial</a> request. To get new ones, send another <code>GetParametersForImport</code> request.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn get_parameters_for_import(
         &self,
         input: GetParametersForImportRequest,
     ) -> RusotoFuture<GetParametersForImportResponse, GetParametersForImportError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.GetParametersForImport");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetParametersForImportResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetParametersForImportError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_parameters_for_import(input)
     }
-
-    /// <p>Imports key material into an existing AWS KMS customer master key (CMK) that was created without key material. You cannot perform this operation on a CMK in a different AWS account. For more information about creating CMKs with no key material and then importing key material, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Before using this operation, call <a>GetParametersForImport</a>. Its response includes a public key and an import token. Use the public key to encrypt the key material. Then, submit the import token from the same <code>GetParametersForImport</code> response.</p> <p>When calling this operation, you must specify the following values:</p> <ul> <li> <p>The key ID or key ARN of a CMK with no key material. Its <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>To create a CMK with no key material, call <a>CreateKey</a> and set the value of its <code>Origin</code> parameter to <code>EXTERNAL</code>. To get the <code>Origin</code> of a CMK, call <a>DescribeKey</a>.)</p> </li> <li> <p>The encrypted key material. To get the public key to encrypt the key material, call <a>GetParametersForImport</a>.</p> </li> <li> <p>The import token that <a>GetParametersForImport</a> returned. This token and the public key used to encrypt the key material must have come from the same response.</p> </li> <li> <p>Whether the key material expires and if so, when. If you set an expiration date, you can change it only by reimporting the same key material and specifying a new expiration date. If the key material expires, AWS KMS deletes the key material and the CMK becomes unusable. To use the CMK again, you must reimport the same key material.</p> </li> </ul> <p>When this operation is successful, the key state of the CMK changes from <code>PendingImport</code> to <code>Enabled</code>, and you can use the CMK. After you successfully import key material into a CMK, you can reimport the same key material into that CMK, but you cannot import different key material.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn import_key_material(
         &self,
         input: ImportKeyMaterialRequest,
     ) -> RusotoFuture<ImportKeyMaterialResponse, ImportKeyMaterialError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ImportKeyMaterial");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportKeyMaterialResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ImportKeyMaterialError::from_response(response))),
-                )
-            }
-        })
+        (**self).import_key_material(input)
     }
-
-    /// <p>Gets a list of aliases in the caller's AWS account and region. You cannot list aliases in other accounts. For more information about aliases, see <a>CreateAlias</a>.</p> <p>By default, the ListAliases command returns all aliases in the account and region. To get only the aliases that point to a particular customer master key (CMK), use the <code>KeyId</code> parameter.</p> <p>The <code>ListAliases</code> response can include aliases that you created and associated with your customer managed CMKs, and aliases that AWS created and associated with AWS managed CMKs in your account. You can recognize AWS aliases because their names have the format <code>aws/&lt;service-name&gt;</code>, such as <code>aws/dynamodb</code>.</p> <p>The response might also include aliases that have no <code>TargetKeyId</code> field. These are predefined aliases that AWS has created but has not yet associated with a CMK. Aliases that AWS creates in your account, including predefined aliases, do not count against your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/limits.html#aliases-limit">AWS KMS aliases limit</a>.</p>
     fn list_aliases(
         &self,
         input: ListAliasesRequest,
     ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ListAliases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAliasesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAliasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_aliases(input)
     }
-
-    /// <p>Gets a list of all grants for the specified customer master key (CMK).</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
     fn list_grants(
         &self,
         input: ListGrantsRequest,
     ) -> RusotoFuture<ListGrantsResponse, ListGrantsError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ListGrants");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGrantsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGrantsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_grants(input)
     }
-
-    /// <p>Gets the names of the key policies that are attached to a customer master key (CMK). This operation is designed to get policy names that you can use in a <a>GetKeyPolicy</a> operation. However, the only valid policy name is <code>default</code>. You cannot perform this operation on a CMK in a different AWS account.</p>
     fn list_key_policies(
         &self,
         input: ListKeyPoliciesRequest,
     ) -> RusotoFuture<ListKeyPoliciesResponse, ListKeyPoliciesError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListKeyPoliciesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListKeyPoliciesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_key_policies(input)
     }
-
-    /// <p>Gets a list of all customer master keys (CMKs) in the caller's AWS account and region.</p>
     fn list_keys(&self, input: ListKeysRequest) -> RusotoFuture<ListKeysResponse, ListKeysError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ListKeys");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListKeysResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListKeysError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_keys(input)
     }
-
-    /// <p>Returns a list of all tags for the specified customer master key (CMK).</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p>
     fn list_resource_tags(
         &self,
         input: ListResourceTagsRequest,
     ) -> RusotoFuture<ListResourceTagsResponse, ListResourceTagsError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ListResourceTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceTagsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListResourceTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_resource_tags(input)
     }
-
-    /// <p>Returns a list of all grants for which the grant's <code>RetiringPrincipal</code> matches the one specified.</p> <p>A typical use is to list all grants that you are able to retire. To retire a grant, use <a>RetireGrant</a>.</p>
     fn list_retirable_grants(
         &self,
         input: ListRetirableGrantsRequest,
     ) -> RusotoFuture<ListGrantsResponse, ListRetirableGrantsError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ListRetirableGrants");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGrantsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListRetirableGrantsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_retirable_grants(input)
     }
-
-    /// <p>Attaches a key policy to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about key policies, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">Key Policies</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn put_key_policy(&self, input: PutKeyPolicyRequest) -> RusotoFuture<(), PutKeyPolicyError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutKeyPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_key_policy(input)
     }
-
-    /// <p>Encrypts data on the server side with a new customer master key (CMK) without exposing the plaintext of the data on the client side. The data is first decrypted and then reencrypted. You can also use this operation to change the encryption context of a ciphertext. </p> <p>You can reencrypt data using CMKs in different AWS accounts.</p> <p>Unlike other operations, <code>ReEncrypt</code> is authorized twice, once as <code>ReEncryptFrom</code> on the source CMK and once as <code>ReEncryptTo</code> on the destination CMK. We recommend that you include the <code>"kms:ReEncrypt*"</code> permission in your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">key policies</a> to permit reencryption from or to the CMK. This permission is automatically included in the key policy when you create a CMK through the console. But you must include it manually when you create a CMK programmatically or when you set a key policy with the <a>PutKeyPolicy</a> operation.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn re_encrypt(
         &self,
         input: ReEncryptRequest,
     ) -> RusotoFuture<ReEncryptResponse, ReEncryptError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ReEncrypt");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ReEncryptResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ReEncryptError::from_response(response))),
-                )
-            }
-        })
+        (**self).re_encrypt(input)
     }
-
-    /// <p>Retires a grant. To clean up, you can retire a grant when you're done using it. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:</p> <ul> <li> <p>The AWS account (root user) under which the grant was created</p> </li> <li> <p>The <code>RetiringPrincipal</code>, if present in the grant</p> </li> <li> <p>The <code>GranteePrincipal</code>, if <code>RetireGrant</code> is an operation specified in the grant</p> </li> </ul> <p>You must identify the grant to retire by its grant token or by a combination of the grant ID and the Amazon Resource Name (ARN) of the customer master key (CMK). A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. The <a>CreateGrant</a> operation returns both.</p>
     fn retire_grant(&self, input: RetireGrantRequest) -> RusotoFuture<(), RetireGrantError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        (**self).retire_grant(input)
+    }
+    fn revoke_grant(&self, input: RevokeGrantRequest) -> RusotoFuture<(), RevokeGrantError> {
+        (**self).revoke_grant(input)
+    }
+    fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> RusotoFuture<ScheduleKeyDeletionResponse, ScheduleKeyDeletionError> {
+        (**self).schedule_key_deletion(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(&self, input: UpdateAliasRequest) -> RusotoFuture<(), UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> RusotoFuture<UpdateCustomKeyStoreResponse, UpdateCustomKeyStoreError> {
+        (**self).update_custom_key_store(input)
+    }
+    fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> RusotoFuture<(), UpdateKeyDescriptionError> {
+        (**self).update_key_description(input)
+    }
+}
+impl<T: Kms + ?Sized> Kms for Box<T> {
+    fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> RusotoFuture<CancelKeyDeletionResponse, CancelKeyDeletionError> {
+        (**self).cancel_key_deletion(input)
+    }
+    fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<ConnectCustomKeyStoreResponse, ConnectCustomKeyStoreError> {
+        (**self).connect_custom_key_store(input)
+    }
+    fn create_alias(&self, input: CreateAliasRequest) -> RusotoFuture<(), CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> RusotoFuture<CreateCustomKeyStoreResponse, CreateCustomKeyStoreError> {
+        (**self).create_custom_key_store(input)
+    }
+    fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> RusotoFuture<CreateGrantResponse, CreateGrantError> {
+        (**self).create_grant(input)
+    }
+    fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> RusotoFuture<CreateKeyResponse, CreateKeyError> {
+        (**self).create_key(input)
+    }
+    fn decrypt(&self, input: DecryptRequest) -> RusotoFuture<DecryptResponse, DecryptError> {
+        (**self).decrypt(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> RusotoFuture<DeleteCustomKeyStoreResponse, DeleteCustomKeyStoreError> {
+        (**self).delete_custom_key_store(input)
+    }
+    fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> RusotoFuture<(), DeleteImportedKeyMaterialError> {
+        (**self).delete_imported_key_material(input)
+    }
+    fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> RusotoFuture<DescribeCustomKeyStoresResponse, DescribeCustomKeyStoresError> {
+        (**self).describe_custom_key_stores(input)
+    }
+    fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> RusotoFuture<DescribeKeyResponse, DescribeKeyError> {
+        (**self).describe_key(input)
+    }
+    fn disable_key(&self, input: DisableKeyRequest) -> RusotoFuture<(), DisableKeyError> {
+        (**self).disable_key(input)
+    }
+    fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> RusotoFuture<(), DisableKeyRotationError> {
+        (**self).disable_key_rotation(input)
+    }
+    fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<DisconnectCustomKeyStoreResponse, DisconnectCustomKeyStoreError> {
+        (**self).disconnect_custom_key_store(input)
+    }
+    fn enable_key(&self, input: EnableKeyRequest) -> RusotoFuture<(), EnableKeyError> {
+        (**self).enable_key(input)
+    }
+    fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> RusotoFuture<(), EnableKeyRotationError> {
+        (**self).enable_key_rotation(input)
+    }
+    fn encrypt(&self, input: EncryptRequest) -> RusotoFuture<EncryptResponse, EncryptError> {
+        (**self).encrypt(input)
+    }
+    fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> RusotoFuture<GenerateDataKeyResponse, GenerateDataKeyError> {
+        (**self).generate_data_key(input)
+    }
+    fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> RusotoFuture<GenerateDataKeyWithoutPlaintextResponse, GenerateDataKeyWithoutPlaintextError>
+    {
+        (**self).generate_data_key_without_plaintext(input)
+    }
+    fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> RusotoFuture<GenerateRandomResponse, GenerateRandomError> {
+        (**self).generate_random(input)
+    }
+    fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> RusotoFuture<GetKeyPolicyResponse, GetKeyPolicyError> {
+        (**self).get_key_policy(input)
+    }
+    fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> RusotoFuture<GetKeyRotationStatusResponse, GetKeyRotationStatusError> {
+        (**self).get_key_rotation_status(input)
+    }
+    fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> RusotoFuture<GetParametersForImportResponse, GetParametersForImportError> {
+        (**self).get_parameters_for_import(input)
+    }
+    fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> RusotoFuture<ImportKeyMaterialResponse, ImportKeyMaterialError> {
+        (**self).import_key_material(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListGrantsError> {
+        (**self).list_grants(input)
+    }
+    fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> RusotoFuture<ListKeyPoliciesResponse, ListKeyPoliciesError> {
+        (**self).list_key_policies(input)
+    }
+    fn list_keys(&self, input: ListKeysRequest) -> RusotoFuture<ListKeysResponse, ListKeysError> {
+        (**self).list_keys(input)
+    }
+    fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> RusotoFuture<ListResourceTagsResponse, ListResourceTagsError> {
+        (**self).list_resource_tags(input)
+    }
+    fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListRetirableGrantsError> {
+        (**self).list_retirable_grants(input)
+    }
+    fn put_key_policy(&self, input: PutKeyPolicyRequest) -> RusotoFuture<(), PutKeyPolicyError> {
+        (**self).put_key_policy(input)
+    }
+    fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> RusotoFuture<ReEncryptResponse, ReEncryptError> {
+        (**self).re_encrypt(input)
+    }
+    fn retire_grant(&self, input: RetireGrantRequest) -> RusotoFuture<(), RetireGrantError> {
+        (**self).retire_grant(input)
+    }
+    fn revoke_grant(&self, input: RevokeGrantRequest) -> RusotoFuture<(), RevokeGrantError> {
+        (**self).revoke_grant(input)
+    }
+    fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> RusotoFuture<ScheduleKeyDeletionResponse, ScheduleKeyDeletionError> {
+        (**self).schedule_key_deletion(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(&self, input: UpdateAliasRequest) -> RusotoFuture<(), UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> RusotoFuture<UpdateCustomKeyStoreResponse, UpdateCustomKeyStoreError> {
+        (**self).update_custom_key_store(input)
+    }
+    fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> RusotoFuture<(), UpdateKeyDescriptionError> {
+        (**self).update_key_description(input)
+    }
+}
+impl<T: Kms + ?Sized> Kms for ::std::sync::Arc<T> {
+    fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> RusotoFuture<CancelKeyDeletionResponse, CancelKeyDeletionError> {
+        (**self).cancel_key_deletion(input)
+    }
+    fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<ConnectCustomKeyStoreResponse, ConnectCustomKeyStoreError> {
+        (**self).connect_custom_key_store(input)
+    }
+    fn create_alias(&self, input: CreateAliasRequest) -> RusotoFuture<(), CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> RusotoFuture<CreateCustomKeyStoreResponse, CreateCustomKeyStoreError> {
+        (**self).create_custom_key_store(input)
+    }
+    fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> RusotoFuture<CreateGrantResponse, CreateGrantError> {
+        (**self).create_grant(input)
+    }
+    fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> RusotoFuture<CreateKeyResponse, CreateKeyError> {
+        (**self).create_key(input)
+    }
+    fn decrypt(&self, input: DecryptRequest) -> RusotoFuture<DecryptResponse, DecryptError> {
+        (**self).decrypt(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> RusotoFuture<DeleteCustomKeyStoreResponse, DeleteCustomKeyStoreError> {
+        (**self).delete_custom_key_store(input)
+    }
+    fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> RusotoFuture<(), DeleteImportedKeyMaterialError> {
+        (**self).delete_imported_key_material(input)
+    }
+    fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> RusotoFuture<DescribeCustomKeyStoresResponse, DescribeCustomKeyStoresError> {
+        (**self).describe_custom_key_stores(input)
+    }
+    fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> RusotoFuture<DescribeKeyResponse, DescribeKeyError> {
+        (**self).describe_key(input)
+    }
+    fn disable_key(&self, input: DisableKeyRequest) -> RusotoFuture<(), DisableKeyError> {
+        (**self).disable_key(input)
+    }
+    fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> RusotoFuture<(), DisableKeyRotationError> {
+        (**self).disable_key_rotation(input)
+    }
+    fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<DisconnectCustomKeyStoreResponse, DisconnectCustomKeyStoreError> {
+        (**self).disconnect_custom_key_store(input)
+    }
+    fn enable_key(&self, input: EnableKeyRequest) -> RusotoFuture<(), EnableKeyError> {
+        (**self).enable_key(input)
+    }
+    fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> RusotoFuture<(), EnableKeyRotationError> {
+        (**self).enable_key_rotation(input)
+    }
+    fn encrypt(&self, input: EncryptRequest) -> RusotoFuture<EncryptResponse, EncryptError> {
+        (**self).encrypt(input)
+    }
+    fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> RusotoFuture<GenerateDataKeyResponse, GenerateDataKeyError> {
+        (**self).generate_data_key(input)
+    }
+    fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> RusotoFuture<GenerateDataKeyWithoutPlaintextResponse, GenerateDataKeyWithoutPlaintextError>
+    {
+        (**self).generate_data_key_without_plaintext(input)
+    }
+    fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> RusotoFuture<GenerateRandomResponse, GenerateRandomError> {
+        (**self).generate_random(input)
+    }
+    fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> RusotoFuture<GetKeyPolicyResponse, GetKeyPolicyError> {
+        (**self).get_key_policy(input)
+    }
+    fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> RusotoFuture<GetKeyRotationStatusResponse, GetKeyRotationStatusError> {
+        (**self).get_key_rotation_status(input)
+    }
+    fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> RusotoFuture<GetParametersForImportResponse, GetParametersForImportError> {
+        (**self).get_parameters_for_import(input)
+    }
+    fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> RusotoFuture<ImportKeyMaterialResponse, ImportKeyMaterialError> {
+        (**self).import_key_material(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListGrantsError> {
+        (**self).list_grants(input)
+    }
+    fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> RusotoFuture<ListKeyPoliciesResponse, ListKeyPoliciesError> {
+        (**self).list_key_policies(input)
+    }
+    fn list_keys(&self, input: ListKeysRequest) -> RusotoFuture<ListKeysResponse, ListKeysError> {
+        (**self).list_keys(input)
+    }
+    fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> RusotoFuture<ListResourceTagsResponse, ListResourceTagsError> {
+        (**self).list_resource_tags(input)
+    }
+    fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListRetirableGrantsError> {
+        (**self).list_retirable_grants(input)
+    }
+    fn put_key_policy(&self, input: PutKeyPolicyRequest) -> RusotoFuture<(), PutKeyPolicyError> {
+        (**self).put_key_policy(input)
+    }
+    fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> RusotoFuture<ReEncryptResponse, ReEncryptError> {
+        (**self).re_encrypt(input)
+    }
+    fn retire_grant(&self, input: RetireGrantRequest) -> RusotoFuture<(), RetireGrantError> {
+        (**self).retire_grant(input)
+    }
+    fn revoke_grant(&self, input: RevokeGrantRequest) -> RusotoFuture<(), RevokeGrantError> {
+        (**self).revoke_grant(input)
+    }
+    fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> RusotoFuture<ScheduleKeyDeletionResponse, ScheduleKeyDeletionError> {
+        (**self).schedule_key_deletion(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(&self, input: UpdateAliasRequest) -> RusotoFuture<(), UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> RusotoFuture<UpdateCustomKeyStoreResponse, UpdateCustomKeyStoreError> {
+        (**self).update_custom_key_store(input)
+    }
+    fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> RusotoFuture<(), UpdateKeyDescriptionError> {
+        (**self).update_key_description(input)
+    }
+}
+/// A client for the KMS API.
+#[derive(Clone)]
+pub struct KmsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl KmsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KmsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KmsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> KmsClient {
+        KmsClient { client, region }
+    }
+}
+
+impl Kms for KmsClient {
+    /// <p>Cancels the deletion of a customer master key (CMK). When this operation is successful, the CMK is set to the <code>Disabled</code> state. To enable a CMK, use <a>EnableKey</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling and canceling deletion of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> RusotoFuture<CancelKeyDeletionResponse, CancelKeyDeletionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CancelKeyDeletion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelKeyDeletionResponse, _>(
+                    response,
+                    "CancelKeyDeletion",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelKeyDeletionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Connects or reconnects a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> to its associated AWS CloudHSM cluster.</p> <p>The custom key store must be connected before you can create customer master keys (CMKs) in the key store or use the CMKs it contains. You can disconnect and reconnect a custom key store at any time.</p> <p>To connect a custom key store, its associated AWS CloudHSM cluster must have at least one active HSM. To get the number of active HSMs in a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation. To add HSMs to the cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation.</p> <p>The connection process can take an extended amount of time to complete; up to 20 minutes. This operation starts the connection process, but it does not wait for it to complete. When it succeeds, this operation quickly returns an HTTP 200 response and a JSON object with no properties. However, this response does not indicate that the custom key store is connected. To get the connection state of the custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>During the connection process, AWS KMS finds the AWS CloudHSM cluster that is associated with the custom key store, creates the connection infrastructure, connects to the cluster, logs into the AWS CloudHSM client as the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user</a> (CU), and rotates its password.</p> <p>The <code>ConnectCustomKeyStore</code> operation might fail for various reasons. To find the reason, use the <a>DescribeCustomKeyStores</a> operation and see the <code>ConnectionErrorCode</code> in the response. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>To fix the failure, use the <a>DisconnectCustomKeyStore</a> operation to disconnect the custom key store, correct the error, use the <a>UpdateCustomKeyStore</a> operation if necessary, and then use <code>ConnectCustomKeyStore</code> again.</p> <p>If you are having trouble connecting or disconnecting a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<ConnectCustomKeyStoreResponse, ConnectCustomKeyStoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ConnectCustomKeyStore");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ConnectCustomKeyStore");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ConnectCustomKeyStoreResponse, _>(
+                    response,
+                    "ConnectCustomKeyStore",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ConnectCustomKeyStoreError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a display name for a customer managed customer master key (CMK). You can use an alias to identify a CMK in selected operations, such as <a>Encrypt</a> and <a>GenerateDataKey</a>. </p> <p>Each CMK can have multiple aliases, but each alias points to only one CMK. The alias name must be unique in the AWS account and region. To simplify code that runs in multiple regions, use the same alias name, but point it to a different CMK in each region. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation.</p> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> <p>The alias and the CMK it is mapped to must be in the same AWS account and the same region. You cannot perform this operation on an alias in a different AWS account.</p> <p>To map an existing alias to a different CMK, call <a>UpdateAlias</a>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn create_alias(&self, input: CreateAliasRequest) -> RusotoFuture<(), CreateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that is associated with an <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/clusters.html">AWS CloudHSM cluster</a> that you own and manage.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>Before you create the custom key store, you must assemble the required elements, including an AWS CloudHSM cluster that fulfills the requirements for a custom key store. For details about the required elements, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the operation completes successfully, it returns the ID of the new custom key store. Before you can use your new custom key store, you need to use the <a>ConnectCustomKeyStore</a> operation to connect the new key store to its AWS CloudHSM cluster. Even if you are not going to use your custom key store immediately, you might want to connect it to verify that all settings are correct and then disconnect it until you are ready to use it.</p> <p>For help with failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> RusotoFuture<CreateCustomKeyStoreResponse, CreateCustomKeyStoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateCustomKeyStore");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateCustomKeyStore");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCustomKeyStoreResponse, _>(
+                    response,
+                    "CreateCustomKeyStore",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateCustomKeyStoreError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds a grant to a customer master key (CMK). The grant allows the grantee principal to use the CMK when the conditions specified in the grant are met. When setting permissions, grants are an alternative to key policies. </p> <p>To create a grant that allows a cryptographic operation only when the encryption context in the operation request matches or includes a specified encryption context, use the <code>Constraints</code> parameter. For details, see <a>GrantConstraints</a>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter. For more information about grants, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/grants.html">Grants</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> RusotoFuture<CreateGrantResponse, CreateGrantError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateGrant");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateGrant");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGrantResponse, _>(
+                    response,
+                    "CreateGrant",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGrantError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a customer managed <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master key</a> (CMK) in your AWS account.</p> <p>You can use a CMK to encrypt small amounts of data (up to 4096 bytes) directly. But CMKs are more commonly used to encrypt the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data keys</a> that are used to encrypt data.</p> <p>To create a CMK for imported key material, use the <code>Origin</code> parameter with a value of <code>EXTERNAL</code>.</p> <p>To create a CMK in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, use the <code>CustomKeyStoreId</code> parameter to specify the custom key store. You must also use the <code>Origin</code> parameter with a value of <code>AWS_CLOUDHSM</code>. The AWS CloudHSM cluster that is associated with the custom key store must have at least two active HSMs in different Availability Zones in the AWS Region.</p> <p>You cannot use this operation to create a CMK in a different AWS account.</p>
+    fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> RusotoFuture<CreateKeyResponse, CreateKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("CreateKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.CreateKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateKeyResponse, _>(response, "CreateKey")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Decrypts ciphertext. Ciphertext is plaintext that has been previously encrypted by using any of the following operations:</p> <ul> <li> <p> <a>GenerateDataKey</a> </p> </li> <li> <p> <a>GenerateDataKeyWithoutPlaintext</a> </p> </li> <li> <p> <a>Encrypt</a> </p> </li> </ul> <p>Whenever possible, use key policies to give users permission to call the Decrypt operation on the CMK, instead of IAM policies. Otherwise, you might create an IAM user policy that gives the user Decrypt permission on all CMKs. This user could decrypt ciphertext that was encrypted by CMKs in other accounts if the key policy for the cross-account CMK permits it. If you must use an IAM policy for <code>Decrypt</code> permissions, limit the user to particular CMKs or particular trusted accounts.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn decrypt(&self, input: DecryptRequest) -> RusotoFuture<DecryptResponse, DecryptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Decrypt");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.Decrypt");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DecryptResponse, _>(response, "Decrypt")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DecryptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified alias. You cannot perform this operation on an alias in a different AWS account. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation. </p> <p>Each CMK can have multiple aliases. To change the alias of a CMK, use <a>DeleteAlias</a> to delete the current alias and <a>CreateAlias</a> to create a new alias. To associate an existing alias with a different customer master key (CMK), call <a>UpdateAlias</a>.</p>
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DeleteAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. This operation does not delete the AWS CloudHSM cluster that is associated with the custom key store, or affect any users or keys in the cluster.</p> <p>The custom key store that you delete cannot contain any AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master keys (CMKs)</a>. Before deleting the key store, verify that you will never need to use any of the CMKs in the key store for any cryptographic operations. Then, use <a>ScheduleKeyDeletion</a> to delete the AWS KMS customer master keys (CMKs) from the key store. When the scheduled waiting period expires, the <code>ScheduleKeyDeletion</code> operation deletes the CMKs. Then it makes a best effort to delete the key material from the associated cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>After all CMKs are deleted from AWS KMS, use <a>DisconnectCustomKeyStore</a> to disconnect the key store from AWS KMS. Then, you can delete the custom key store.</p> <p>Instead of deleting the custom key store, consider using <a>DisconnectCustomKeyStore</a> to disconnect it from AWS KMS. While the key store is disconnected, you cannot create or use the CMKs in the key store. But, you do not need to delete CMKs and you can reconnect a disconnected custom key store at any time.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> RusotoFuture<DeleteCustomKeyStoreResponse, DeleteCustomKeyStoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteCustomKeyStore");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DeleteCustomKeyStore");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteCustomKeyStoreResponse, _>(
+                    response,
+                    "DeleteCustomKeyStore",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteCustomKeyStoreError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes key material that you previously imported. This operation makes the specified customer master key (CMK) unusable. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>When the specified CMK is in the <code>PendingDeletion</code> state, this operation does not change the CMK's state. Otherwise, it changes the CMK's state to <code>PendingImport</code>.</p> <p>After you delete key material, you can use <a>ImportKeyMaterial</a> to reimport the same key material into the CMK.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> RusotoFuture<(), DeleteImportedKeyMaterialError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DeleteImportedKeyMaterial");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DeleteImportedKeyMaterial");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteImportedKeyMaterialError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a> in the account and region.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>By default, this operation returns information about all custom key stores in the account and region. To get only information about a particular custom key store, use either the <code>CustomKeyStoreName</code> or <code>CustomKeyStoreId</code> parameter (but not both).</p> <p>To determine whether the custom key store is connected to its AWS CloudHSM cluster, use the <code>ConnectionState</code> element in the response. If an attempt to connect the custom key store failed, the <code>ConnectionState</code> value is <code>FAILED</code> and the <code>ConnectionErrorCode</code> element in the response indicates the cause of the failure. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>Custom key stores have a <code>DISCONNECTED</code> connection state if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If your custom key store state is <code>CONNECTED</code> but you are having trouble using it, make sure that its associated AWS CloudHSM cluster is active and contains the minimum number of HSMs required for the operation, if any.</p> <p> For help repairing your custom key store, see the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> topic in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> RusotoFuture<DescribeCustomKeyStoresResponse, DescribeCustomKeyStoresError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DescribeCustomKeyStores");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DescribeCustomKeyStores");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCustomKeyStoresResponse, _>(
+                    response,
+                    "DescribeCustomKeyStores",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCustomKeyStoresError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides detailed information about the specified customer master key (CMK).</p> <p>You can use <code>DescribeKey</code> on a predefined AWS alias, that is, an AWS alias with no key ID. When you do, AWS KMS associates the alias with an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">AWS managed CMK</a> and returns its <code>KeyId</code> and <code>Arn</code> in the response.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
+    fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> RusotoFuture<DescribeKeyResponse, DescribeKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DescribeKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DescribeKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeKeyResponse, _>(
+                    response,
+                    "DescribeKey",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the state of a customer master key (CMK) to disabled, thereby preventing its use for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn disable_key(&self, input: DisableKeyRequest) -> RusotoFuture<(), DisableKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisableKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DisableKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> RusotoFuture<(), DisableKeyRotationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisableKeyRotation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableKeyRotationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disconnects the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> from its associated AWS CloudHSM cluster. While a custom key store is disconnected, you can manage the custom key store and its customer master keys (CMKs), but you cannot create or use CMKs in the custom key store. You can reconnect the custom key store at any time.</p> <note> <p>While a custom key store is disconnected, all attempts to create customer master keys (CMKs) in the custom key store or to use existing CMKs in cryptographic operations will fail. This action can prevent users from storing and accessing sensitive data.</p> </note> <p/> <p>To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation. To reconnect a custom key store, use the <a>ConnectCustomKeyStore</a> operation.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<DisconnectCustomKeyStoreResponse, DisconnectCustomKeyStoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("DisconnectCustomKeyStore");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.DisconnectCustomKeyStore");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisconnectCustomKeyStoreResponse, _>(
+                    response,
+                    "DisconnectCustomKeyStore",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisconnectCustomKeyStoreError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for cryptographic operations. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn enable_key(&self, input: EnableKeyRequest) -> RusotoFuture<(), EnableKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("EnableKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.EnableKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>You cannot enable automatic rotation of CMKs with imported key material or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> RusotoFuture<(), EnableKeyRotationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("EnableKeyRotation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableKeyRotationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Encrypts plaintext into ciphertext by using a customer master key (CMK). The <code>Encrypt</code> operation has two primary use cases:</p> <ul> <li> <p>You can encrypt up to 4 kilobytes (4096 bytes) of arbitrary data such as an RSA key, a database password, or other sensitive information.</p> </li> <li> <p>You can use the <code>Encrypt</code> operation to move encrypted data from one AWS region to another. In the first region, generate a data key and use the plaintext key to encrypt the data. Then, in the new region, call the <code>Encrypt</code> method on same plaintext data key. Now, you can safely move the encrypted data and encrypted data key to the new region, and decrypt in the new region when necessary.</p> </li> </ul> <p>You don't need use this operation to encrypt a data key within a region. The <a>GenerateDataKey</a> and <a>GenerateDataKeyWithoutPlaintext</a> operations return an encrypted data key.</p> <p>Also, you don't need to use this operation to encrypt data in your application. You can use the plaintext and encrypted data keys that the <code>GenerateDataKey</code> operation returns.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
+    fn encrypt(&self, input: EncryptRequest) -> RusotoFuture<EncryptResponse, EncryptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("Encrypt");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.Encrypt");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EncryptResponse, _>(response, "Encrypt")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EncryptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates a unique data key. This operation returns a plaintext copy of the data key and a copy that is encrypted under a customer master key (CMK) that you specify. You can use the plaintext key to encrypt your data outside of KMS and store the encrypted data key with the encrypted data.</p> <p> <code>GenerateDataKey</code> returns a unique data key for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the data key.</p> <p>To generate a data key, you need to specify the customer master key (CMK) that will be used to encrypt the data key. You must also specify the length of the data key using either the <code>KeySpec</code> or <code>NumberOfBytes</code> field (but not both). For common key lengths (128-bit and 256-bit symmetric keys), we recommend that you use <code>KeySpec</code>. To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p> <p>You will find the plaintext copy of the data key in the <code>Plaintext</code> field of the response, and the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>We recommend that you use the following pattern to encrypt data locally in your application:</p> <ol> <li> <p>Use the <code>GenerateDataKey</code> operation to get a data encryption key.</p> </li> <li> <p>Use the plaintext data key (returned in the <code>Plaintext</code> field of the response) to encrypt data locally, then erase the plaintext data key from memory.</p> </li> <li> <p>Store the encrypted data key (returned in the <code>CiphertextBlob</code> field of the response) alongside the locally encrypted data.</p> </li> </ol> <p>To decrypt data locally:</p> <ol> <li> <p>Use the <a>Decrypt</a> operation to decrypt the encrypted data key. The operation returns a plaintext copy of the data key.</p> </li> <li> <p>Use the plaintext data key to decrypt data locally, then erase the plaintext data key from memory.</p> </li> </ol> <p>To get only an encrypted copy of the data key, use <a>GenerateDataKeyWithoutPlaintext</a>. To get a cryptographically secure random byte string, use <a>GenerateRandom</a>.</p> <p>You can use the optional encryption context to add additional security to your encryption operation. When you specify an <code>EncryptionContext</code> in the <code>GenerateDataKey</code> operation, you must specify the same encryption context (a case-sensitive exact match) in your request to <a>Decrypt</a> the data key. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> RusotoFuture<GenerateDataKeyResponse, GenerateDataKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GenerateDataKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GenerateDataKeyResponse, _>(
+                    response,
+                    "GenerateDataKey",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GenerateDataKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates a unique data key. This operation returns a data key that is encrypted under a customer master key (CMK) that you specify. <code>GenerateDataKeyWithoutPlaintext</code> is identical to <a>GenerateDataKey</a> except that returns only the encrypted copy of the data key.</p> <p>Like <code>GenerateDataKey</code>, <code>GenerateDataKeyWithoutPlaintext</code> returns a unique data key for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the data key.</p> <p>This operation is useful for systems that need to encrypt data at some point, but not immediately. When you need to encrypt the data, you call the <a>Decrypt</a> operation on the encrypted copy of the key.</p> <p>It's also useful in distributed systems with different levels of trust. For example, you might store encrypted data in containers. One component of your system creates new containers and stores an encrypted data key with each container. Then, a different component puts the data into the containers. That component first decrypts the data key, uses the plaintext data key to encrypt data, puts the encrypted data into the container, and then destroys the plaintext data key. In this system, the component that creates the containers never sees the plaintext data key.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> RusotoFuture<GenerateDataKeyWithoutPlaintextResponse, GenerateDataKeyWithoutPlaintextError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateDataKeyWithoutPlaintext");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "TrentService.GenerateDataKeyWithoutPlaintext",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GenerateDataKeyWithoutPlaintextResponse, _>(
+                    response,
+                    "GenerateDataKeyWithoutPlaintext",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GenerateDataKeyWithoutPlaintextError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a random byte string that is cryptographically secure.</p> <p>By default, the random byte string is generated in AWS KMS. To generate the byte string in the AWS CloudHSM cluster that is associated with a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, specify the custom key store ID.</p> <p>For more information about entropy and random number generation, see the <a href="https://d0.awsstatic.com/whitepapers/KMS-Cryptographic-Details.pdf">AWS Key Management Service Cryptographic Details</a> whitepaper.</p>
+    fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> RusotoFuture<GenerateRandomResponse, GenerateRandomError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GenerateRandom");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GenerateRandom");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GenerateRandomResponse, _>(
+                    response,
+                    "GenerateRandom",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GenerateRandomError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a key policy attached to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p>
+    fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> RusotoFuture<GetKeyPolicyResponse, GetKeyPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetKeyPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetKeyPolicyResponse, _>(
+                    response,
+                    "GetKeyPolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetKeyPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a Boolean value that indicates whether <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> is enabled for the specified customer master key (CMK).</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <ul> <li> <p>Disabled: The key rotation status does not change when you disable a CMK. However, while the CMK is disabled, AWS KMS does not rotate the backing key.</p> </li> <li> <p>Pending deletion: While a CMK is pending deletion, its key rotation status is <code>false</code> and AWS KMS does not rotate the backing key. If you cancel the deletion, the original key rotation status is restored.</p> </li> </ul> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> RusotoFuture<GetKeyRotationStatusResponse, GetKeyRotationStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetKeyRotationStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GetKeyRotationStatus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetKeyRotationStatusResponse, _>(
+                    response,
+                    "GetKeyRotationStatus",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetKeyRotationStatusError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the items you need in order to import key material into AWS KMS from your existing key management infrastructure. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>You must specify the key ID of the customer master key (CMK) into which you will import key material. This CMK's <code>Origin</code> must be <code>EXTERNAL</code>. You must also specify the wrapping algorithm and type of wrapping key (public key) that you will use to encrypt the key material. You cannot perform this operation on a CMK in a different AWS account.</p> <p>This operation returns a public key and an import token. Use the public key to encrypt the key material. Store the import token to send with a subsequent <a>ImportKeyMaterial</a> request. The public key and import token from the same response must be used together. These items are valid for 24 hours. When they expire, they cannot be used for a subsequent <a>ImportKeyMaterial</a> request. To get new ones, send another <code>GetParametersForImport</code> request.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> RusotoFuture<GetParametersForImportResponse, GetParametersForImportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("GetParametersForImport");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.GetParametersForImport");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetParametersForImportResponse, _>(
+                    response,
+                    "GetParametersForImport",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetParametersForImportError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Imports key material into an existing AWS KMS customer master key (CMK) that was created without key material. You cannot perform this operation on a CMK in a different AWS account. For more information about creating CMKs with no key material and then importing key material, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Before using this operation, call <a>GetParametersForImport</a>. Its response includes a public key and an import token. Use the public key to encrypt the key material. Then, submit the import token from the same <code>GetParametersForImport</code> response.</p> <p>When calling this operation, you must specify the following values:</p> <ul> <li> <p>The key ID or key ARN of a CMK with no key material. Its <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>To create a CMK with no key material, call <a>CreateKey</a> and set the value of its <code>Origin</code> parameter to <code>EXTERNAL</code>. To get the <code>Origin</code> of a CMK, call <a>DescribeKey</a>.)</p> </li> <li> <p>The encrypted key material. To get the public key to encrypt the key material, call <a>GetParametersForImport</a>.</p> </li> <li> <p>The import token that <a>GetParametersForImport</a> returned. This token and the public key used to encrypt the key material must have come from the same response.</p> </li> <li> <p>Whether the key material expires and if so, when. If you set an expiration date, you can change it only by reimporting the same key material and specifying a new expiration date. If the key material expires, AWS KMS deletes the key material and the CMK becomes unusable. To use the CMK again, you must reimport the same key material.</p> </li> </ul> <p>When this operation is successful, the key state of the CMK changes from <code>PendingImport</code> to <code>Enabled</code>, and you can use the CMK. After you successfully import key material into a CMK, you can reimport the same key material into that CMK, but you cannot import different key material.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> RusotoFuture<ImportKeyMaterialResponse, ImportKeyMaterialError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ImportKeyMaterial");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ImportKeyMaterial");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ImportKeyMaterialResponse, _>(
+                    response,
+                    "ImportKeyMaterial",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ImportKeyMaterialError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of aliases in the caller's AWS account and region. You cannot list aliases in other accounts. For more information about aliases, see <a>CreateAlias</a>.</p> <p>By default, the ListAliases command returns all aliases in the account and region. To get only the aliases that point to a particular customer master key (CMK), use the <code>KeyId</code> parameter.</p> <p>The <code>ListAliases</code> response can include aliases that you created and associated with your customer managed CMKs, and aliases that AWS created and associated with AWS managed CMKs in your account. You can recognize AWS aliases because their names have the format <code>aws/&lt;service-name&gt;</code>, such as <code>aws/dynamodb</code>.</p> <p>The response might also include aliases that have no <code>TargetKeyId</code> field. These are predefined aliases that AWS has created but has not yet associated with a CMK. Aliases that AWS creates in your account, including predefined aliases, do not count against your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/limits.html#aliases-limit">AWS KMS aliases limit</a>.</p>
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListAliases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListAliases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAliasesResponse, _>(
+                    response,
+                    "ListAliases",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAliasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of all grants for the specified customer master key (CMK).</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListGrantsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListGrants");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListGrants");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGrantsResponse, _>(response, "ListGrants")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGrantsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the names of the key policies that are attached to a customer master key (CMK). This operation is designed to get policy names that you can use in a <a>GetKeyPolicy</a> operation. However, the only valid policy name is <code>default</code>. You cannot perform this operation on a CMK in a different AWS account.</p>
+    fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> RusotoFuture<ListKeyPoliciesResponse, ListKeyPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListKeyPolicies");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListKeyPoliciesResponse, _>(
+                    response,
+                    "ListKeyPolicies",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListKeyPoliciesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of all customer master keys (CMKs) in the caller's AWS account and region.</p>
+    fn list_keys(&self, input: ListKeysRequest) -> RusotoFuture<ListKeysResponse, ListKeysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListKeys");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListKeys");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListKeysResponse, _>(response, "ListKeys")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListKeysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of all tags for the specified customer master key (CMK).</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p>
+    fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> RusotoFuture<ListResourceTagsResponse, ListResourceTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListResourceTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListResourceTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourceTagsResponse, _>(
+                    response,
+                    "ListResourceTags",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListResourceTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of all grants for which the grant's <code>RetiringPrincipal</code> matches the one specified.</p> <p>A typical use is to list all grants that you are able to retire. To retire a grant, use <a>RetireGrant</a>.</p>
+    fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListRetirableGrantsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ListRetirableGrants");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ListRetirableGrants");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGrantsResponse, _>(
+                    response,
+                    "ListRetirableGrants",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListRetirableGrantsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches a key policy to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about key policies, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">Key Policies</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn put_key_policy(&self, input: PutKeyPolicyRequest) -> RusotoFuture<(), PutKeyPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("PutKeyPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutKeyPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Encrypts data on the server side with a new customer master key (CMK) without exposing the plaintext of the data on the client side. The data is first decrypted and then reencrypted. You can also use this operation to change the encryption context of a ciphertext. </p> <p>You can reencrypt data using CMKs in different AWS accounts.</p> <p>Unlike other operations, <code>ReEncrypt</code> is authorized twice, once as <code>ReEncryptFrom</code> on the source CMK and once as <code>ReEncryptTo</code> on the destination CMK. We recommend that you include the <code>"kms:ReEncrypt*"</code> permission in your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">key policies</a> to permit reencryption from or to the CMK. This permission is automatically included in the key policy when you create a CMK through the console. But you must include it manually when you create a CMK programmatically or when you set a key policy with the <a>PutKeyPolicy</a> operation.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> RusotoFuture<ReEncryptResponse, ReEncryptError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ReEncrypt");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ReEncrypt");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ReEncryptResponse, _>(response, "ReEncrypt")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ReEncryptError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retires a grant. To clean up, you can retire a grant when you're done using it. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:</p> <ul> <li> <p>The AWS account (root user) under which the grant was created</p> </li> <li> <p>The <code>RetiringPrincipal</code>, if present in the grant</p> </li> <li> <p>The <code>GranteePrincipal</code>, if <code>RetireGrant</code> is an operation specified in the grant</p> </li> </ul> <p>You must identify the grant to retire by its grant token or by a combination of the grant ID and the Amazon Resource Name (ARN) of the customer master key (CMK). A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. The <a>CreateGrant</a> operation returns both.</p>
+    fn retire_grant(&self, input: RetireGrantRequest) -> RusotoFuture<(), RetireGrantError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("RetireGrant");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.RetireGrant");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RetireGrantError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Revokes the specified grant for the specified customer master key (CMK). You can revoke a grant to actively deny operations that depend on it.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    fn revoke_grant(&self, input: RevokeGrantRequest) -> RusotoFuture<(), RevokeGrantError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("RevokeGrant");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.RevokeGrant");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RevokeGrantError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the key state of the CMK changes to <code>PendingDeletion</code>. Before the waiting period ends, you can use <a>CancelKeyDeletion</a> to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that refer to it.</p> <important> <p>Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is unrecoverable. To prevent the use of a CMK without deleting it, use <a>DisableKey</a>.</p> </important> <p>If you schedule deletion of a CMK from a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, when the waiting period expires, <code>ScheduleKeyDeletion</code> deletes the CMK from AWS KMS. Then AWS KMS makes a best effort to delete the key material from the associated AWS CloudHSM cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling a CMK for deletion, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> RusotoFuture<ScheduleKeyDeletionResponse, ScheduleKeyDeletionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("ScheduleKeyDeletion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.ScheduleKeyDeletion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ScheduleKeyDeletionResponse, _>(
+                    response,
+                    "ScheduleKeyDeletion",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ScheduleKeyDeletionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or edits tags for a customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty (null) strings.</p> <p>You can only use a tag key once for each CMK. If you use the tag key again, AWS KMS replaces the current tag value with the specified value.</p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified tags from the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a>TagResource</a>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates an existing alias with a different customer master key (CMK). Each CMK can have multiple aliases, but the aliases must be unique within the account and region. You cannot perform this operation on an alias in a different AWS account.</p> <p>This operation works only on existing aliases. To change the alias of a CMK to a new value, use <a>CreateAlias</a> to create a new alias and <a>DeleteAlias</a> to delete the old alias.</p> <p>Because an alias is not a property of a CMK, you can create, update, and delete the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs in the account, use the <a>ListAliases</a> operation. </p> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn update_alias(&self, input: UpdateAliasRequest) -> RusotoFuture<(), UpdateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.UpdateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes the properties of a custom key store. Use the <code>CustomKeyStoreId</code> parameter to identify the custom key store you want to edit. Use the remaining parameters to change the properties of the custom key store.</p> <p>You can only update a custom key store that is disconnected. To disconnect the custom key store, use <a>DisconnectCustomKeyStore</a>. To reconnect the custom key store after the update completes, use <a>ConnectCustomKeyStore</a>. To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>Use the parameters of <code>UpdateCustomKeyStore</code> to edit your keystore settings.</p> <ul> <li> <p>Use the <b>NewCustomKeyStoreName</b> parameter to change the friendly name of the custom key store to the value that you specify.</p> <p> </p> </li> <li> <p>Use the <b>KeyStorePassword</b> parameter tell AWS KMS the current password of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user (CU)</a> in the associated AWS CloudHSM cluster. You can use this parameter to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-password">fix connection failures</a> that occur when AWS KMS cannot log into the associated cluster because the <code>kmsuser</code> password has changed. This value does not change the password in the AWS CloudHSM cluster.</p> <p> </p> </li> <li> <p>Use the <b>CloudHsmClusterId</b> parameter to associate the custom key store with a different, but related, AWS CloudHSM cluster. You can use this parameter to repair a custom key store if its AWS CloudHSM cluster becomes corrupted or is deleted, or when you need to create or restore a cluster from a backup. </p> </li> </ul> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> RusotoFuture<UpdateCustomKeyStoreResponse, UpdateCustomKeyStoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateCustomKeyStore");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.RetireGrant");
+        request.add_header("x-amz-target", "TrentService.UpdateCustomKeyStore");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateCustomKeyStoreResponse, _>(
+                    response,
+                    "UpdateCustomKeyStore",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateCustomKeyStoreError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the description of a customer master key (CMK). To see the description of a CMK, use <a>DescribeKey</a>. </p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> RusotoFuture<(), UpdateKeyDescriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
+        request.set_operation("UpdateKeyDescription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TrentService.UpdateKeyDescription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
@@ -4981,185 +7303,1308 @@ impl Kms for KmsClient {
                 Box::new(future::ok(::std::mem::drop(response)))
             } else {
                 Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RetireGrantError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateKeyDescriptionError::from_response(response))
+                    }),
                 )
             }
         })
     }
-
-    /// <p>Revokes the specified grant for the specified customer master key (CMK). You can revoke a grant to actively deny operations that depend on it.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+}
+
+/// A mock of the KMS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockKms {
+    cancel_key_deletion: Option<
+        Box<
+            dyn Fn(
+                    CancelKeyDeletionRequest,
+                ) -> ::std::result::Result<
+                    CancelKeyDeletionResponse,
+                    RusotoError<CancelKeyDeletionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    connect_custom_key_store: Option<
+        Box<
+            dyn Fn(
+                    ConnectCustomKeyStoreRequest,
+                ) -> ::std::result::Result<
+                    ConnectCustomKeyStoreResponse,
+                    RusotoError<ConnectCustomKeyStoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_alias: Option<
+        Box<
+            dyn Fn(CreateAliasRequest) -> ::std::result::Result<(), RusotoError<CreateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_custom_key_store: Option<
+        Box<
+            dyn Fn(
+                    CreateCustomKeyStoreRequest,
+                ) -> ::std::result::Result<
+                    CreateCustomKeyStoreResponse,
+                    RusotoError<CreateCustomKeyStoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_grant: Option<
+        Box<
+            dyn Fn(
+                    CreateGrantRequest,
+                )
+                    -> ::std::result::Result<CreateGrantResponse, RusotoError<CreateGrantError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_key: Option<
+        Box<
+            dyn Fn(
+                    CreateKeyRequest,
+                )
+                    -> ::std::result::Result<CreateKeyResponse, RusotoError<CreateKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    decrypt: Option<
+        Box<
+            dyn Fn(
+                    DecryptRequest,
+                )
+                    -> ::std::result::Result<DecryptResponse, RusotoError<DecryptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_alias: Option<
+        Box<
+            dyn Fn(DeleteAliasRequest) -> ::std::result::Result<(), RusotoError<DeleteAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_custom_key_store: Option<
+        Box<
+            dyn Fn(
+                    DeleteCustomKeyStoreRequest,
+                ) -> ::std::result::Result<
+                    DeleteCustomKeyStoreResponse,
+                    RusotoError<DeleteCustomKeyStoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_imported_key_material: Option<
+        Box<
+            dyn Fn(
+                    DeleteImportedKeyMaterialRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteImportedKeyMaterialError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_custom_key_stores: Option<
+        Box<
+            dyn Fn(
+                    DescribeCustomKeyStoresRequest,
+                ) -> ::std::result::Result<
+                    DescribeCustomKeyStoresResponse,
+                    RusotoError<DescribeCustomKeyStoresError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_key: Option<
+        Box<
+            dyn Fn(
+                    DescribeKeyRequest,
+                )
+                    -> ::std::result::Result<DescribeKeyResponse, RusotoError<DescribeKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_key: Option<
+        Box<
+            dyn Fn(DisableKeyRequest) -> ::std::result::Result<(), RusotoError<DisableKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_key_rotation: Option<
+        Box<
+            dyn Fn(
+                    DisableKeyRotationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisableKeyRotationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disconnect_custom_key_store: Option<
+        Box<
+            dyn Fn(
+                    DisconnectCustomKeyStoreRequest,
+                ) -> ::std::result::Result<
+                    DisconnectCustomKeyStoreResponse,
+                    RusotoError<DisconnectCustomKeyStoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_key: Option<
+        Box<
+            dyn Fn(EnableKeyRequest) -> ::std::result::Result<(), RusotoError<EnableKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_key_rotation: Option<
+        Box<
+            dyn Fn(
+                    EnableKeyRotationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<EnableKeyRotationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    encrypt: Option<
+        Box<
+            dyn Fn(
+                    EncryptRequest,
+                )
+                    -> ::std::result::Result<EncryptResponse, RusotoError<EncryptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    generate_data_key: Option<
+        Box<
+            dyn Fn(
+                    GenerateDataKeyRequest,
+                ) -> ::std::result::Result<
+                    GenerateDataKeyResponse,
+                    RusotoError<GenerateDataKeyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    generate_data_key_without_plaintext: Option<
+        Box<
+            dyn Fn(
+                    GenerateDataKeyWithoutPlaintextRequest,
+                ) -> ::std::result::Result<
+                    GenerateDataKeyWithoutPlaintextResponse,
+                    RusotoError<GenerateDataKeyWithoutPlaintextError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    generate_random: Option<
+        Box<
+            dyn Fn(
+                    GenerateRandomRequest,
+                ) -> ::std::result::Result<
+                    GenerateRandomResponse,
+                    RusotoError<GenerateRandomError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_key_policy: Option<
+        Box<
+            dyn Fn(
+                    GetKeyPolicyRequest,
+                )
+                    -> ::std::result::Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_key_rotation_status: Option<
+        Box<
+            dyn Fn(
+                    GetKeyRotationStatusRequest,
+                ) -> ::std::result::Result<
+                    GetKeyRotationStatusResponse,
+                    RusotoError<GetKeyRotationStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_parameters_for_import: Option<
+        Box<
+            dyn Fn(
+                    GetParametersForImportRequest,
+                ) -> ::std::result::Result<
+                    GetParametersForImportResponse,
+                    RusotoError<GetParametersForImportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_key_material: Option<
+        Box<
+            dyn Fn(
+                    ImportKeyMaterialRequest,
+                ) -> ::std::result::Result<
+                    ImportKeyMaterialResponse,
+                    RusotoError<ImportKeyMaterialError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_aliases: Option<
+        Box<
+            dyn Fn(
+                    ListAliasesRequest,
+                )
+                    -> ::std::result::Result<ListAliasesResponse, RusotoError<ListAliasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_grants: Option<
+        Box<
+            dyn Fn(
+                    ListGrantsRequest,
+                )
+                    -> ::std::result::Result<ListGrantsResponse, RusotoError<ListGrantsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_key_policies: Option<
+        Box<
+            dyn Fn(
+                    ListKeyPoliciesRequest,
+                ) -> ::std::result::Result<
+                    ListKeyPoliciesResponse,
+                    RusotoError<ListKeyPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_keys: Option<
+        Box<
+            dyn Fn(
+                    ListKeysRequest,
+                )
+                    -> ::std::result::Result<ListKeysResponse, RusotoError<ListKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_resource_tags: Option<
+        Box<
+            dyn Fn(
+                    ListResourceTagsRequest,
+                ) -> ::std::result::Result<
+                    ListResourceTagsResponse,
+                    RusotoError<ListResourceTagsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_retirable_grants: Option<
+        Box<
+            dyn Fn(
+                    ListRetirableGrantsRequest,
+                ) -> ::std::result::Result<
+                    ListGrantsResponse,
+                    RusotoError<ListRetirableGrantsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_key_policy: Option<
+        Box<
+            dyn Fn(PutKeyPolicyRequest) -> ::std::result::Result<(), RusotoError<PutKeyPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    re_encrypt: Option<
+        Box<
+            dyn Fn(
+                    ReEncryptRequest,
+                )
+                    -> ::std::result::Result<ReEncryptResponse, RusotoError<ReEncryptError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    retire_grant: Option<
+        Box<
+            dyn Fn(RetireGrantRequest) -> ::std::result::Result<(), RusotoError<RetireGrantError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    revoke_grant: Option<
+        Box<
+            dyn Fn(RevokeGrantRequest) -> ::std::result::Result<(), RusotoError<RevokeGrantError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    schedule_key_deletion: Option<
+        Box<
+            dyn Fn(
+                    ScheduleKeyDeletionRequest,
+                ) -> ::std::result::Result<
+                    ScheduleKeyDeletionResponse,
+                    RusotoError<ScheduleKeyDeletionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_alias: Option<
+        Box<
+            dyn Fn(UpdateAliasRequest) -> ::std::result::Result<(), RusotoError<UpdateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_custom_key_store: Option<
+        Box<
+            dyn Fn(
+                    UpdateCustomKeyStoreRequest,
+                ) -> ::std::result::Result<
+                    UpdateCustomKeyStoreResponse,
+                    RusotoError<UpdateCustomKeyStoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_key_description: Option<
+        Box<
+            dyn Fn(
+                    UpdateKeyDescriptionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateKeyDescriptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockKms {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockKms {
+        Default::default()
+    }
+
+    /// Answers `cancel_key_deletion` with what `handler` returns for its input.
+    pub fn with_cancel_key_deletion<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                CancelKeyDeletionRequest,
+            ) -> ::std::result::Result<
+                CancelKeyDeletionResponse,
+                RusotoError<CancelKeyDeletionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_key_deletion = Some(Box::new(handler));
+        self
+    }
+    /// Answers `connect_custom_key_store` with what `handler` returns for its input.
+    pub fn with_connect_custom_key_store<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ConnectCustomKeyStoreRequest,
+            ) -> ::std::result::Result<
+                ConnectCustomKeyStoreResponse,
+                RusotoError<ConnectCustomKeyStoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.connect_custom_key_store = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_alias` with what `handler` returns for its input.
+    pub fn with_create_alias<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(CreateAliasRequest) -> ::std::result::Result<(), RusotoError<CreateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_custom_key_store` with what `handler` returns for its input.
+    pub fn with_create_custom_key_store<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                CreateCustomKeyStoreRequest,
+            ) -> ::std::result::Result<
+                CreateCustomKeyStoreResponse,
+                RusotoError<CreateCustomKeyStoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_custom_key_store = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_grant` with what `handler` returns for its input.
+    pub fn with_create_grant<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                CreateGrantRequest,
+            )
+                -> ::std::result::Result<CreateGrantResponse, RusotoError<CreateGrantError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_grant = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_key` with what `handler` returns for its input.
+    pub fn with_create_key<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                CreateKeyRequest,
+            ) -> ::std::result::Result<CreateKeyResponse, RusotoError<CreateKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decrypt` with what `handler` returns for its input.
+    pub fn with_decrypt<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(DecryptRequest) -> ::std::result::Result<DecryptResponse, RusotoError<DecryptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.decrypt = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_alias` with what `handler` returns for its input.
+    pub fn with_delete_alias<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(DeleteAliasRequest) -> ::std::result::Result<(), RusotoError<DeleteAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_custom_key_store` with what `handler` returns for its input.
+    pub fn with_delete_custom_key_store<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                DeleteCustomKeyStoreRequest,
+            ) -> ::std::result::Result<
+                DeleteCustomKeyStoreResponse,
+                RusotoError<DeleteCustomKeyStoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_custom_key_store = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_imported_key_material` with what `handler` returns for its input.
+    pub fn with_delete_imported_key_material<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                DeleteImportedKeyMaterialRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteImportedKeyMaterialError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_imported_key_material = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_custom_key_stores` with what `handler` returns for its input.
+    pub fn with_describe_custom_key_stores<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                DescribeCustomKeyStoresRequest,
+            ) -> ::std::result::Result<
+                DescribeCustomKeyStoresResponse,
+                RusotoError<DescribeCustomKeyStoresError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_custom_key_stores = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_key` with what `handler` returns for its input.
+    pub fn with_describe_key<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                DescribeKeyRequest,
+            )
+                -> ::std::result::Result<DescribeKeyResponse, RusotoError<DescribeKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_key` with what `handler` returns for its input.
+    pub fn with_disable_key<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(DisableKeyRequest) -> ::std::result::Result<(), RusotoError<DisableKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_key_rotation` with what `handler` returns for its input.
+    pub fn with_disable_key_rotation<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                DisableKeyRotationRequest,
+            ) -> ::std::result::Result<(), RusotoError<DisableKeyRotationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_key_rotation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disconnect_custom_key_store` with what `handler` returns for its input.
+    pub fn with_disconnect_custom_key_store<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                DisconnectCustomKeyStoreRequest,
+            ) -> ::std::result::Result<
+                DisconnectCustomKeyStoreResponse,
+                RusotoError<DisconnectCustomKeyStoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disconnect_custom_key_store = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_key` with what `handler` returns for its input.
+    pub fn with_enable_key<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(EnableKeyRequest) -> ::std::result::Result<(), RusotoError<EnableKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_key_rotation` with what `handler` returns for its input.
+    pub fn with_enable_key_rotation<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                EnableKeyRotationRequest,
+            ) -> ::std::result::Result<(), RusotoError<EnableKeyRotationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_key_rotation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `encrypt` with what `handler` returns for its input.
+    pub fn with_encrypt<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(EncryptRequest) -> ::std::result::Result<EncryptResponse, RusotoError<EncryptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.encrypt = Some(Box::new(handler));
+        self
+    }
+    /// Answers `generate_data_key` with what `handler` returns for its input.
+    pub fn with_generate_data_key<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                GenerateDataKeyRequest,
+            )
+                -> ::std::result::Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_data_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `generate_data_key_without_plaintext` with what `handler` returns for its input.
+    pub fn with_generate_data_key_without_plaintext<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                GenerateDataKeyWithoutPlaintextRequest,
+            ) -> ::std::result::Result<
+                GenerateDataKeyWithoutPlaintextResponse,
+                RusotoError<GenerateDataKeyWithoutPlaintextError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_data_key_without_plaintext = Some(Box::new(handler));
+        self
+    }
+    /// Answers `generate_random` with what `handler` returns for its input.
+    pub fn with_generate_random<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                GenerateRandomRequest,
+            )
+                -> ::std::result::Result<GenerateRandomResponse, RusotoError<GenerateRandomError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_random = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_key_policy` with what `handler` returns for its input.
+    pub fn with_get_key_policy<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                GetKeyPolicyRequest,
+            )
+                -> ::std::result::Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_key_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_key_rotation_status` with what `handler` returns for its input.
+    pub fn with_get_key_rotation_status<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                GetKeyRotationStatusRequest,
+            ) -> ::std::result::Result<
+                GetKeyRotationStatusResponse,
+                RusotoError<GetKeyRotationStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_key_rotation_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_parameters_for_import` with what `handler` returns for its input.
+    pub fn with_get_parameters_for_import<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                GetParametersForImportRequest,
+            ) -> ::std::result::Result<
+                GetParametersForImportResponse,
+                RusotoError<GetParametersForImportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_parameters_for_import = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_key_material` with what `handler` returns for its input.
+    pub fn with_import_key_material<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ImportKeyMaterialRequest,
+            ) -> ::std::result::Result<
+                ImportKeyMaterialResponse,
+                RusotoError<ImportKeyMaterialError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_key_material = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_aliases` with what `handler` returns for its input.
+    pub fn with_list_aliases<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ListAliasesRequest,
+            )
+                -> ::std::result::Result<ListAliasesResponse, RusotoError<ListAliasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_aliases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_grants` with what `handler` returns for its input.
+    pub fn with_list_grants<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ListGrantsRequest,
+            )
+                -> ::std::result::Result<ListGrantsResponse, RusotoError<ListGrantsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_grants = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_key_policies` with what `handler` returns for its input.
+    pub fn with_list_key_policies<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ListKeyPoliciesRequest,
+            )
+                -> ::std::result::Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_key_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_keys` with what `handler` returns for its input.
+    pub fn with_list_keys<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ListKeysRequest,
+            ) -> ::std::result::Result<ListKeysResponse, RusotoError<ListKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_tags` with what `handler` returns for its input.
+    pub fn with_list_resource_tags<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ListResourceTagsRequest,
+            ) -> ::std::result::Result<
+                ListResourceTagsResponse,
+                RusotoError<ListResourceTagsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_retirable_grants` with what `handler` returns for its input.
+    pub fn with_list_retirable_grants<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ListRetirableGrantsRequest,
+            )
+                -> ::std::result::Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_retirable_grants = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_key_policy` with what `handler` returns for its input.
+    pub fn with_put_key_policy<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(PutKeyPolicyRequest) -> ::std::result::Result<(), RusotoError<PutKeyPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_key_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `re_encrypt` with what `handler` returns for its input.
+    pub fn with_re_encrypt<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ReEncryptRequest,
+            ) -> ::std::result::Result<ReEncryptResponse, RusotoError<ReEncryptError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.re_encrypt = Some(Box::new(handler));
+        self
+    }
+    /// Answers `retire_grant` with what `handler` returns for its input.
+    pub fn with_retire_grant<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(RetireGrantRequest) -> ::std::result::Result<(), RusotoError<RetireGrantError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.retire_grant = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_grant` with what `handler` returns for its input.
+    pub fn with_revoke_grant<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(RevokeGrantRequest) -> ::std::result::Result<(), RusotoError<RevokeGrantError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_grant = Some(Box::new(handler));
+        self
+    }
+    /// Answers `schedule_key_deletion` with what `handler` returns for its input.
+    pub fn with_schedule_key_deletion<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                ScheduleKeyDeletionRequest,
+            ) -> ::std::result::Result<
+                ScheduleKeyDeletionResponse,
+                RusotoError<ScheduleKeyDeletionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.schedule_key_deletion = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_alias` with what `handler` returns for its input.
+    pub fn with_update_alias<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(UpdateAliasRequest) -> ::std::result::Result<(), RusotoError<UpdateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_custom_key_store` with what `handler` returns for its input.
+    pub fn with_update_custom_key_store<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                UpdateCustomKeyStoreRequest,
+            ) -> ::std::result::Result<
+                UpdateCustomKeyStoreResponse,
+                RusotoError<UpdateCustomKeyStoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_custom_key_store = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_key_description` with what `handler` returns for its input.
+    pub fn with_update_key_description<F>(mut self, handler: F) -> MockKms
+    where
+        F: Fn(
+                UpdateKeyDescriptionRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateKeyDescriptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_key_description = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Kms for MockKms {
+    fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> RusotoFuture<CancelKeyDeletionResponse, CancelKeyDeletionError> {
+        match self.cancel_key_deletion {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for cancel_key_deletion"),
+        }
+    }
+    fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<ConnectCustomKeyStoreResponse, ConnectCustomKeyStoreError> {
+        match self.connect_custom_key_store {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for connect_custom_key_store"),
+        }
+    }
+    fn create_alias(&self, input: CreateAliasRequest) -> RusotoFuture<(), CreateAliasError> {
+        match self.create_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for create_alias"),
+        }
+    }
+    fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> RusotoFuture<CreateCustomKeyStoreResponse, CreateCustomKeyStoreError> {
+        match self.create_custom_key_store {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for create_custom_key_store"),
+        }
+    }
+    fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> RusotoFuture<CreateGrantResponse, CreateGrantError> {
+        match self.create_grant {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for create_grant"),
+        }
+    }
+    fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> RusotoFuture<CreateKeyResponse, CreateKeyError> {
+        match self.create_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for create_key"),
+        }
+    }
+    fn decrypt(&self, input: DecryptRequest) -> RusotoFuture<DecryptResponse, DecryptError> {
+        match self.decrypt {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for decrypt"),
+        }
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        match self.delete_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for delete_alias"),
+        }
+    }
+    fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> RusotoFuture<DeleteCustomKeyStoreResponse, DeleteCustomKeyStoreError> {
+        match self.delete_custom_key_store {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for delete_custom_key_store"),
+        }
+    }
+    fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> RusotoFuture<(), DeleteImportedKeyMaterialError> {
+        match self.delete_imported_key_material {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for delete_imported_key_material"),
+        }
+    }
+    fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> RusotoFuture<DescribeCustomKeyStoresResponse, DescribeCustomKeyStoresError> {
+        match self.describe_custom_key_stores {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for describe_custom_key_stores"),
+        }
+    }
+    fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> RusotoFuture<DescribeKeyResponse, DescribeKeyError> {
+        match self.describe_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for describe_key"),
+        }
+    }
+    fn disable_key(&self, input: DisableKeyRequest) -> RusotoFuture<(), DisableKeyError> {
+        match self.disable_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for disable_key"),
+        }
+    }
+    fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> RusotoFuture<(), DisableKeyRotationError> {
+        match self.disable_key_rotation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for disable_key_rotation"),
+        }
+    }
+    fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<DisconnectCustomKeyStoreResponse, DisconnectCustomKeyStoreError> {
+        match self.disconnect_custom_key_store {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for disconnect_custom_key_store"),
+        }
+    }
+    fn enable_key(&self, input: EnableKeyRequest) -> RusotoFuture<(), EnableKeyError> {
+        match self.enable_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for enable_key"),
+        }
+    }
+    fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> RusotoFuture<(), EnableKeyRotationError> {
+        match self.enable_key_rotation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for enable_key_rotation"),
+        }
+    }
+    fn encrypt(&self, input: EncryptRequest) -> RusotoFuture<EncryptResponse, EncryptError> {
+        match self.encrypt {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for encrypt"),
+        }
+    }
+    fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> RusotoFuture<GenerateDataKeyResponse, GenerateDataKeyError> {
+        match self.generate_data_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for generate_data_key"),
+        }
+    }
+    fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> RusotoFuture<GenerateDataKeyWithoutPlaintextResponse, GenerateDataKeyWithoutPlaintextError>
+    {
+        match self.generate_data_key_without_plaintext {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for generate_data_key_without_plaintext"),
+        }
+    }
+    fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> RusotoFuture<GenerateRandomResponse, GenerateRandomError> {
+        match self.generate_random {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for generate_random"),
+        }
+    }
+    fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> RusotoFuture<GetKeyPolicyResponse, GetKeyPolicyError> {
+        match self.get_key_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for get_key_policy"),
+        }
+    }
+    fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> RusotoFuture<GetKeyRotationStatusResponse, GetKeyRotationStatusError> {
+        match self.get_key_rotation_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for get_key_rotation_status"),
+        }
+    }
+    fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> RusotoFuture<GetParametersForImportResponse, GetParametersForImportError> {
+        match self.get_parameters_for_import {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for get_parameters_for_import"),
+        }
+    }
+    fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> RusotoFuture<ImportKeyMaterialResponse, ImportKeyMaterialError> {
+        match self.import_key_material {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for import_key_material"),
+        }
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        match self.list_aliases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for list_aliases"),
+        }
+    }
+    fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListGrantsError> {
+        match self.list_grants {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for list_grants"),
+        }
+    }
+    fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> RusotoFuture<ListKeyPoliciesResponse, ListKeyPoliciesError> {
+        match self.list_key_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for list_key_policies"),
+        }
+    }
+    fn list_keys(&self, input: ListKeysRequest) -> RusotoFuture<ListKeysResponse, ListKeysError> {
+        match self.list_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for list_keys"),
+        }
+    }
+    fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> RusotoFuture<ListResourceTagsResponse, ListResourceTagsError> {
+        match self.list_resource_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for list_resource_tags"),
+        }
+    }
+    fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListRetirableGrantsError> {
+        match self.list_retirable_grants {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for list_retirable_grants"),
+        }
+    }
+    fn put_key_policy(&self, input: PutKeyPolicyRequest) -> RusotoFuture<(), PutKeyPolicyError> {
+        match self.put_key_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for put_key_policy"),
+        }
+    }
+    fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> RusotoFuture<ReEncryptResponse, ReEncryptError> {
+        match self.re_encrypt {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for re_encrypt"),
+        }
+    }
+    fn retire_grant(&self, input: RetireGrantRequest) -> RusotoFuture<(), RetireGrantError> {
+        match self.retire_grant {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for retire_grant"),
+        }
+    }
     fn revoke_grant(&self, input: RevokeGrantRequest) -> RusotoFuture<(), RevokeGrantError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.RevokeGrant");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RevokeGrantError::from_response(response))),
-                )
-            }
-        })
+        match self.revoke_grant {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for revoke_grant"),
+        }
     }
-
-    /// <p>Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the key state of the CMK changes to <code>PendingDeletion</code>. Before the waiting period ends, you can use <a>CancelKeyDeletion</a> to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that refer to it.</p> <important> <p>Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is unrecoverable. To prevent the use of a CMK without deleting it, use <a>DisableKey</a>.</p> </important> <p>If you schedule deletion of a CMK from a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, when the waiting period expires, <code>ScheduleKeyDeletion</code> deletes the CMK from AWS KMS. Then AWS KMS makes a best effort to delete the key material from the associated AWS CloudHSM cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling a CMK for deletion, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn schedule_key_deletion(
         &self,
         input: ScheduleKeyDeletionRequest,
     ) -> RusotoFuture<ScheduleKeyDeletionResponse, ScheduleKeyDeletionError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.ScheduleKeyDeletion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ScheduleKeyDeletionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ScheduleKeyDeletionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.schedule_key_deletion {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for schedule_key_deletion"),
+        }
     }
-
-    /// <p>Adds or edits tags for a customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty (null) strings.</p> <p>You can only use a tag key once for each CMK. If you use the tag key again, AWS KMS replaces the current tag value with the specified value.</p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes the specified tags from the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a>TagResource</a>.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Associates an existing alias with a different customer master key (CMK). Each CMK can have multiple aliases, but the aliases must be unique within the account and region. You cannot perform this operation on an alias in a different AWS account.</p> <p>This operation works only on existing aliases. To change the alias of a CMK to a new value, use <a>CreateAlias</a> to create a new alias and <a>DeleteAlias</a> to delete the old alias.</p> <p>Because an alias is not a property of a CMK, you can create, update, and delete the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs in the account, use the <a>ListAliases</a> operation. </p> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn update_alias(&self, input: UpdateAliasRequest) -> RusotoFuture<(), UpdateAliasError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.UpdateAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAliasError::from_response(response))),
-                )
-            }
-        })
+        match self.update_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for update_alias"),
+        }
     }
-
-    /// <p>Changes the properties of a custom key store. Use the <code>CustomKeyStoreId</code> parameter to identify the custom key store you want to edit. Use the remaining parameters to change the properties of the custom key store.</p> <p>You can only update a custom key store that is disconnected. To disconnect the custom key store, use <a>DisconnectCustomKeyStore</a>. To reconnect the custom key store after the update completes, use <a>ConnectCustomKeyStore</a>. To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>Use the parameters of <code>UpdateCustomKeyStore</code> to edit your keystore settings.</p> <ul> <li> <p>Use the <b>NewCustomKeyStoreName</b> parameter to change the friendly name of the custom key store to the value that you specify.</p> <p> </p> </li> <li> <p>Use the <b>KeyStorePassword</b> parameter tell AWS KMS the current password of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user (CU)</a> in the associated AWS CloudHSM cluster. You can use this parameter to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-password">fix connection failures</a> that occur when AWS KMS cannot log into the associated cluster because the <code>kmsuser</code> password has changed. This value does not change the password in the AWS CloudHSM cluster.</p> <p> </p> </li> <li> <p>Use the <b>CloudHsmClusterId</b> parameter to associate the custom key store with a different, but related, AWS CloudHSM cluster. You can use this parameter to repair a custom key store if its AWS CloudHSM cluster becomes corrupted or is deleted, or when you need to create or restore a cluster from a backup. </p> </li> </ul> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
     fn update_custom_key_store(
         &self,
         input: UpdateCustomKeyStoreRequest,
     ) -> RusotoFuture<UpdateCustomKeyStoreResponse, UpdateCustomKeyStoreError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.UpdateCustomKeyStore");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCustomKeyStoreResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateCustomKeyStoreError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_custom_key_store {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for update_custom_key_store"),
+        }
     }
-
-    /// <p>Updates the description of a customer master key (CMK). To see the description of a CMK, use <a>DescribeKey</a>. </p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>The result of this operation varies with the key state of the CMK. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     fn update_key_description(
         &self,
         input: UpdateKeyDescriptionRequest,
     ) -> RusotoFuture<(), UpdateKeyDescriptionError> {
-        let mut request = SignedRequest::new("POST", "kms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TrentService.UpdateKeyDescription");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateKeyDescriptionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_key_description {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockKms has no handler for update_key_description"),
+        }
     }
 }
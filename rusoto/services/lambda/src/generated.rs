@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Limits that are related to concurrency and code storage. All file and storage sizes are in bytes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccountLimit {
     /// <p>The maximum size of your function's code and layers when they're extracted.</p>
     #[serde(rename = "CodeSizeUnzipped")]
@@ -52,7 +53,7 @@ pub struct AccountLimit {
 
 /// <p>The number of functions and amount of storage in use.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccountUsage {
     /// <p>The number of Lambda functions.</p>
     #[serde(rename = "FunctionCount")]
@@ -65,6 +66,7 @@ pub struct AccountUsage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddLayerVersionPermissionRequest {
     /// <p>The API action that grants access to the layer. For example, <code>lambda:GetLayerVersion</code>.</p>
     #[serde(rename = "Action")]
@@ -91,8 +93,64 @@ pub struct AddLayerVersionPermissionRequest {
     pub version_number: i64,
 }
 
+impl Validate for AddLayerVersionPermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "action"),
+            self.action.chars().count(),
+            None,
+            Some(22),
+        );
+        errors.check_pattern(
+            &field_path(path, "action"),
+            &self.action,
+            "lambda:GetLayerVersion",
+        );
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+        if let Some(ref value) = self.organization_id {
+            errors.check_length(
+                &field_path(path, "organization_id"),
+                value.chars().count(),
+                None,
+                Some(34),
+            );
+            errors.check_pattern(
+                &field_path(path, "organization_id"),
+                &value,
+                "o-[a-z0-9]{10,32}",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "principal"),
+            &self.principal,
+            "\\d{12}|\\*|arn:(aws[a-zA-Z-]*):iam::\\d{12}:root",
+        );
+        errors.check_length(
+            &field_path(path, "statement_id"),
+            self.statement_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "statement_id"),
+            &self.statement_id,
+            "([a-zA-Z0-9-_]+)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddLayerVersionPermissionResponse {
     /// <p>A unique identifier for the current revision of the policy.</p>
     #[serde(rename = "RevisionId")]
@@ -105,6 +163,7 @@ pub struct AddLayerVersionPermissionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddPermissionRequest {
     /// <p>The action that the principal can use on the function. For example, <code>lambda:InvokeFunction</code> or <code>lambda:GetFunction</code>.</p>
     #[serde(rename = "Action")]
@@ -140,8 +199,71 @@ pub struct AddPermissionRequest {
     pub statement_id: String,
 }
 
+impl Validate for AddPermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "action"),
+            &self.action,
+            "(lambda:[*]|lambda:[a-zA-Z]+|[*])",
+        );
+        if let Some(ref value) = self.event_source_token {
+            errors.check_length(
+                &field_path(path, "event_source_token"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "event_source_token"),
+                &value,
+                "[a-zA-Z0-9._\\-]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        errors.check_pattern(&field_path(path, "principal"), &self.principal, "[^\\s]+");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+        if let Some(ref value) = self.source_account {
+            errors.check_length(
+                &field_path(path, "source_account"),
+                value.chars().count(),
+                None,
+                Some(12),
+            );
+            errors.check_pattern(&field_path(path, "source_account"), &value, "\\d{12}");
+        }
+        if let Some(ref value) = self.source_arn {
+            errors.check_pattern(&field_path(path, "source_arn"), &value, "arn:(aws[a-zA-Z0-9-]*):([a-zA-Z0-9\\-])+:([a-z]{2}(-gov)?-[a-z]+-\\d{1})?:(\\d{12})?:(.*)");
+        }
+        errors.check_length(
+            &field_path(path, "statement_id"),
+            self.statement_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "statement_id"),
+            &self.statement_id,
+            "([a-zA-Z0-9-_]+)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddPermissionResponse {
     /// <p>The permission statement that's added to the function policy.</p>
     #[serde(rename = "Statement")]
@@ -151,7 +273,7 @@ pub struct AddPermissionResponse {
 
 /// <p>Provides configuration information about a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AliasConfiguration {
     /// <p>The Amazon Resource Name (ARN) of the alias.</p>
     #[serde(rename = "AliasArn")]
@@ -188,8 +310,23 @@ pub struct AliasRoutingConfiguration {
     pub additional_version_weights: Option<::std::collections::HashMap<String, f64>>,
 }
 
+impl Validate for AliasRoutingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_version_weights {
+            for (key, item) in value.iter() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "additional_version_weights"),
+                    key
+                );
+                errors.check_range(&item_path, *item, None, Some(1.0));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Concurrency {
     /// <p>The number of concurrent executions that are reserved for this function. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/concurrent-executions.html">Managing Concurrency</a>.</p>
     #[serde(rename = "ReservedConcurrentExecutions")]
@@ -198,6 +335,7 @@ pub struct Concurrency {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAliasRequest {
     /// <p>A description of the alias.</p>
     #[serde(rename = "Description")]
@@ -218,7 +356,53 @@ pub struct CreateAliasRequest {
     pub routing_config: Option<AliasRoutingConfiguration>,
 }
 
+impl Validate for CreateAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        errors.check_length(
+            &field_path(path, "function_version"),
+            self.function_version.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "function_version"),
+            &self.function_version,
+            "(\\$LATEST|[0-9]+)",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^[0-9]+$)([a-zA-Z0-9-_]+)",
+        );
+        if let Some(ref value) = self.routing_config {
+            value.validate_at(&field_path(path, "routing_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEventSourceMappingRequest {
     /// <p><p>The maximum number of items to retrieve in a single batch.</p> <ul> <li> <p> <b>Amazon Kinesis</b> - Default 100. Max 10,000.</p> </li> <li> <p> <b>Amazon DynamoDB Streams</b> - Default 100. Max 1,000.</p> </li> <li> <p> <b>Amazon Simple Queue Service</b> - Default 10. Max 10.</p> </li> </ul></p>
     #[serde(rename = "BatchSize")]
@@ -247,7 +431,37 @@ pub struct CreateEventSourceMappingRequest {
     pub starting_position_timestamp: Option<f64>,
 }
 
+impl Validate for CreateEventSourceMappingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.batch_size {
+            errors.check_range(
+                &field_path(path, "batch_size"),
+                *value,
+                Some(1),
+                Some(10000),
+            );
+        }
+        errors.check_pattern(&field_path(path, "event_source_arn"), &self.event_source_arn, "arn:(aws[a-zA-Z0-9-]*):([a-zA-Z0-9\\-])+:([a-z]{2}(-gov)?-[a-z]+-\\d{1})?:(\\d{12})?:(.*)");
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.maximum_batching_window_in_seconds {
+            errors.check_range(
+                &field_path(path, "maximum_batching_window_in_seconds"),
+                *value,
+                None,
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFunctionRequest {
     /// <p>The code for the function.</p>
     #[serde(rename = "Code")]
@@ -310,6 +524,74 @@ pub struct CreateFunctionRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.code.validate_at(&field_path(path, "code"), errors);
+        if let Some(ref value) = self.dead_letter_config {
+            value.validate_at(&field_path(path, "dead_letter_config"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        errors.check_length(
+            &field_path(path, "handler"),
+            self.handler.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "handler"), &self.handler, "[^\\s]+");
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_pattern(
+                &field_path(path, "kms_key_arn"),
+                &value,
+                "(arn:(aws[a-zA-Z-]*)?:[a-z0-9-.]+:.*)|()",
+            );
+        }
+        if let Some(ref value) = self.layers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "layers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(140));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+:[0-9]+",
+                );
+            }
+        }
+        if let Some(ref value) = self.memory_size {
+            errors.check_range(
+                &field_path(path, "memory_size"),
+                *value,
+                Some(128),
+                Some(10240),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "role"),
+            &self.role,
+            "arn:(aws[a-zA-Z-]*)?:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 /// <p>The <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">dead letter queue</a> for failed asynchronous invocations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeadLetterConfig {
@@ -319,7 +601,20 @@ pub struct DeadLetterConfig {
     pub target_arn: Option<String>,
 }
 
+impl Validate for DeadLetterConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.target_arn {
+            errors.check_pattern(
+                &field_path(path, "target_arn"),
+                &value,
+                "(arn:(aws[a-zA-Z-]*)?:[a-z0-9-.]+:.*)|()",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAliasRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>MyFunction</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:MyFunction</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:MyFunction</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -329,7 +624,31 @@ pub struct DeleteAliasRequest {
     pub name: String,
 }
 
+impl Validate for DeleteAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^[0-9]+$)([a-zA-Z0-9-_]+)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEventSourceMappingRequest {
     /// <p>The identifier of the event source mapping.</p>
     #[serde(rename = "UUID")]
@@ -337,13 +656,27 @@ pub struct DeleteEventSourceMappingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFunctionConcurrencyRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
     pub function_name: String,
 }
 
+impl Validate for DeleteFunctionConcurrencyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFunctionRequest {
     /// <p>The name of the Lambda function or version.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:1</code> (with version).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -354,7 +687,29 @@ pub struct DeleteFunctionRequest {
     pub qualifier: Option<String>,
 }
 
+impl Validate for DeleteFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLayerVersionRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
     #[serde(rename = "LayerName")]
@@ -364,8 +719,25 @@ pub struct DeleteLayerVersionRequest {
     pub version_number: i64,
 }
 
+impl Validate for DeleteLayerVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+    }
+}
+
 /// <p>A function's environment variable settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Environment {
     /// <p>Environment variable key-value pairs.</p>
     #[serde(rename = "Variables")]
@@ -375,7 +747,7 @@ pub struct Environment {
 
 /// <p>Error messages for environment variables that couldn't be applied.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -389,7 +761,7 @@ pub struct EnvironmentError {
 
 /// <p>The results of a configuration update that applied environment variables.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnvironmentResponse {
     /// <p>Error messages for environment variables that couldn't be applied.</p>
     #[serde(rename = "Error")]
@@ -403,7 +775,7 @@ pub struct EnvironmentResponse {
 
 /// <p>A mapping between an AWS resource and an AWS Lambda function. See <a>CreateEventSourceMapping</a> for details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EventSourceMappingConfiguration {
     /// <p>The maximum number of items to retrieve in a single batch.</p>
     #[serde(rename = "BatchSize")]
@@ -444,6 +816,7 @@ pub struct EventSourceMappingConfiguration {
 
 /// <p>The code for the Lambda function. You can specify either an object in Amazon S3, or upload a deployment package directly.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FunctionCode {
     /// <p>An Amazon S3 bucket in the same AWS Region as your function. The bucket can be in a different AWS account.</p>
     #[serde(rename = "S3Bucket")]
@@ -468,9 +841,43 @@ pub struct FunctionCode {
     pub zip_file: Option<bytes::Bytes>,
 }
 
+impl Validate for FunctionCode {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_bucket {
+            errors.check_length(
+                &field_path(path, "s3_bucket"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_bucket"),
+                &value,
+                "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$",
+            );
+        }
+        if let Some(ref value) = self.s3_key {
+            errors.check_length(
+                &field_path(path, "s3_key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.s3_object_version {
+            errors.check_length(
+                &field_path(path, "s3_object_version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Details about a function's deployment package.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FunctionCodeLocation {
     /// <p>A presigned URL that you can use to download the deployment package.</p>
     #[serde(rename = "Location")]
@@ -484,7 +891,7 @@ pub struct FunctionCodeLocation {
 
 /// <p>Details about a function's configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FunctionConfiguration {
     /// <p>The SHA256 hash of the function's deployment package.</p>
     #[serde(rename = "CodeSha256")]
@@ -569,10 +976,11 @@ pub struct FunctionConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountSettingsRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAccountSettingsResponse {
     /// <p>Limits that are related to concurrency and code storage.</p>
     #[serde(rename = "AccountLimit")]
@@ -585,6 +993,7 @@ pub struct GetAccountSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAliasRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>MyFunction</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:MyFunction</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:MyFunction</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -594,7 +1003,31 @@ pub struct GetAliasRequest {
     pub name: String,
 }
 
+impl Validate for GetAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^[0-9]+$)([a-zA-Z0-9-_]+)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEventSourceMappingRequest {
     /// <p>The identifier of the event source mapping.</p>
     #[serde(rename = "UUID")]
@@ -602,6 +1035,7 @@ pub struct GetEventSourceMappingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFunctionConfigurationRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -612,7 +1046,29 @@ pub struct GetFunctionConfigurationRequest {
     pub qualifier: Option<String>,
 }
 
+impl Validate for GetFunctionConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_\\.]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFunctionRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -623,8 +1079,29 @@ pub struct GetFunctionRequest {
     pub qualifier: Option<String>,
 }
 
+impl Validate for GetFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_\\.]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFunctionResponse {
     /// <p>The deployment package of the function or version.</p>
     #[serde(rename = "Code")]
@@ -645,13 +1122,31 @@ pub struct GetFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLayerVersionByArnRequest {
     /// <p>The ARN of the layer version.</p>
     #[serde(rename = "Arn")]
     pub arn: String,
 }
 
+impl Validate for GetLayerVersionByArnRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "arn"),
+            &self.arn,
+            "arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+:[0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLayerVersionPolicyRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
     #[serde(rename = "LayerName")]
@@ -661,8 +1156,24 @@ pub struct GetLayerVersionPolicyRequest {
     pub version_number: i64,
 }
 
+impl Validate for GetLayerVersionPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLayerVersionPolicyResponse {
     /// <p>The policy document.</p>
     #[serde(rename = "Policy")]
@@ -675,6 +1186,7 @@ pub struct GetLayerVersionPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLayerVersionRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
     #[serde(rename = "LayerName")]
@@ -684,8 +1196,24 @@ pub struct GetLayerVersionRequest {
     pub version_number: i64,
 }
 
+impl Validate for GetLayerVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLayerVersionResponse {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
@@ -722,6 +1250,7 @@ pub struct GetLayerVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPolicyRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -732,8 +1261,29 @@ pub struct GetPolicyRequest {
     pub qualifier: Option<String>,
 }
 
+impl Validate for GetPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_\\.]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPolicyResponse {
     /// <p>The resource-based policy.</p>
     #[serde(rename = "Policy")]
@@ -746,6 +1296,7 @@ pub struct GetPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvocationRequest {
     /// <p>Up to 3583 bytes of base64-encoded data about the invoking client to pass to the function in the context object.</p>
     #[serde(rename = "ClientContext")]
@@ -777,6 +1328,27 @@ pub struct InvocationRequest {
     pub qualifier: Option<String>,
 }
 
+impl Validate for InvocationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_\\.]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct InvocationResponse {
     /// <p>The version of the function that executed. When you invoke a function with an alias, this indicates which version the alias resolved to.</p>
@@ -792,6 +1364,7 @@ pub struct InvocationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvokeAsyncRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -806,9 +1379,21 @@ pub struct InvokeAsyncRequest {
     pub invoke_args: bytes::Bytes,
 }
 
+impl Validate for InvokeAsyncRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_\\.]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 /// <p>A success response (<code>202 Accepted</code>) indicates that the request is queued for invocation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InvokeAsyncResponse {
     /// <p>The status code.</p>
     #[serde(rename = "Status")]
@@ -818,7 +1403,7 @@ pub struct InvokeAsyncResponse {
 
 /// <p>An <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Layer {
     /// <p>The Amazon Resource Name (ARN) of the function layer.</p>
     #[serde(rename = "Arn")]
@@ -832,6 +1417,7 @@ pub struct Layer {
 
 /// <p>A ZIP archive that contains the contents of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. You can specify either an Amazon S3 location, or upload a layer archive directly.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LayerVersionContentInput {
     /// <p>The Amazon S3 bucket of the layer archive.</p>
     #[serde(rename = "S3Bucket")]
@@ -856,9 +1442,43 @@ pub struct LayerVersionContentInput {
     pub zip_file: Option<bytes::Bytes>,
 }
 
+impl Validate for LayerVersionContentInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_bucket {
+            errors.check_length(
+                &field_path(path, "s3_bucket"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_bucket"),
+                &value,
+                "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$",
+            );
+        }
+        if let Some(ref value) = self.s3_key {
+            errors.check_length(
+                &field_path(path, "s3_key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.s3_object_version {
+            errors.check_length(
+                &field_path(path, "s3_object_version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Details about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LayerVersionContentOutput {
     /// <p>The SHA-256 hash of the layer archive.</p>
     #[serde(rename = "CodeSha256")]
@@ -876,7 +1496,7 @@ pub struct LayerVersionContentOutput {
 
 /// <p>Details about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LayerVersionsListItem {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
@@ -906,7 +1526,7 @@ pub struct LayerVersionsListItem {
 
 /// <p>Details about an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LayersListItem {
     /// <p>The newest version of the layer.</p>
     #[serde(rename = "LatestMatchingVersion")]
@@ -923,6 +1543,7 @@ pub struct LayersListItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAliasesRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>MyFunction</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:MyFunction</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:MyFunction</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -941,8 +1562,36 @@ pub struct ListAliasesRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListAliasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.function_version {
+            errors.check_length(
+                &field_path(path, "function_version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "function_version"),
+                &value,
+                "(\\$LATEST|[0-9]+)",
+            );
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAliasesResponse {
     /// <p>A list of aliases.</p>
     #[serde(rename = "Aliases")]
@@ -955,6 +1604,7 @@ pub struct ListAliasesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEventSourceMappingsRequest {
     /// <p><p>The Amazon Resource Name (ARN) of the event source.</p> <ul> <li> <p> <b>Amazon Kinesis</b> - The ARN of the data stream or a stream consumer.</p> </li> <li> <p> <b>Amazon DynamoDB Streams</b> - The ARN of the stream.</p> </li> <li> <p> <b>Amazon Simple Queue Service</b> - The ARN of the queue.</p> </li> </ul></p>
     #[serde(rename = "EventSourceArn")]
@@ -974,8 +1624,28 @@ pub struct ListEventSourceMappingsRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListEventSourceMappingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_source_arn {
+            errors.check_pattern(&field_path(path, "event_source_arn"), &value, "arn:(aws[a-zA-Z0-9-]*):([a-zA-Z0-9\\-])+:([a-z]{2}(-gov)?-[a-z]+-\\d{1})?:(\\d{12})?:(.*)");
+        }
+        if let Some(ref value) = self.function_name {
+            errors.check_length(
+                &field_path(path, "function_name"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(&field_path(path, "function_name"), &value, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEventSourceMappingsResponse {
     /// <p>A list of event source mappings.</p>
     #[serde(rename = "EventSourceMappings")]
@@ -988,6 +1658,7 @@ pub struct ListEventSourceMappingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFunctionsRequest {
     /// <p>Set to <code>ALL</code> to include entries for all published versions of each function.</p>
     #[serde(rename = "FunctionVersion")]
@@ -1007,9 +1678,24 @@ pub struct ListFunctionsRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListFunctionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.master_region {
+            errors.check_pattern(
+                &field_path(path, "master_region"),
+                &value,
+                "ALL|[a-z]{2}(-gov)?-[a-z]+-\\d{1}",
+            );
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 /// <p>A list of Lambda functions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFunctionsResponse {
     /// <p>A list of Lambda functions.</p>
     #[serde(rename = "Functions")]
@@ -1022,6 +1708,7 @@ pub struct ListFunctionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLayerVersionsRequest {
     /// <p>A runtime identifier. For example, <code>go1.x</code>.</p>
     #[serde(rename = "CompatibleRuntime")]
@@ -1040,8 +1727,27 @@ pub struct ListLayerVersionsRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListLayerVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLayerVersionsResponse {
     /// <p>A list of versions.</p>
     #[serde(rename = "LayerVersions")]
@@ -1054,6 +1760,7 @@ pub struct ListLayerVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLayersRequest {
     /// <p>A runtime identifier. For example, <code>go1.x</code>.</p>
     #[serde(rename = "CompatibleRuntime")]
@@ -1069,8 +1776,16 @@ pub struct ListLayersRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListLayersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLayersResponse {
     /// <p>A list of function layers.</p>
     #[serde(rename = "Layers")]
@@ -1083,14 +1798,21 @@ pub struct ListLayersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>The function's Amazon Resource Name (ARN).</p>
     #[serde(rename = "Resource")]
     pub resource: String,
 }
 
+impl Validate for ListTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource"), &self.resource, "arn:(aws[a-zA-Z-]*)?:lambda:[a-z]{2}(-gov)?-[a-z]+-\\d{1}:\\d{12}:function:[a-zA-Z0-9-_]+(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>The function's tags.</p>
     #[serde(rename = "Tags")]
@@ -1099,6 +1821,7 @@ pub struct ListTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVersionsByFunctionRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>MyFunction</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:MyFunction</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:MyFunction</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -1113,8 +1836,23 @@ pub struct ListVersionsByFunctionRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListVersionsByFunctionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_\\.]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(10000));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVersionsByFunctionResponse {
     /// <p>The pagination token that's included if more results are available.</p>
     #[serde(rename = "NextMarker")]
@@ -1127,6 +1865,7 @@ pub struct ListVersionsByFunctionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublishLayerVersionRequest {
     /// <p>A list of compatible <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">function runtimes</a>. Used for filtering with <a>ListLayers</a> and <a>ListLayerVersions</a>.</p>
     #[serde(rename = "CompatibleRuntimes")]
@@ -1148,8 +1887,50 @@ pub struct PublishLayerVersionRequest {
     pub license_info: Option<String>,
 }
 
+impl Validate for PublishLayerVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.compatible_runtimes {
+            errors.check_length(
+                &field_path(path, "compatible_runtimes"),
+                value.len(),
+                None,
+                Some(15),
+            );
+        }
+        self.content
+            .validate_at(&field_path(path, "content"), errors);
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+        if let Some(ref value) = self.license_info {
+            errors.check_length(
+                &field_path(path, "license_info"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PublishLayerVersionResponse {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
@@ -1186,6 +1967,7 @@ pub struct PublishLayerVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublishVersionRequest {
     /// <p>Only publish a version if the hash value matches the value that's specified. Use this option to avoid publishing a version if the function code has changed since you last updated it. You can get the hash for the version that you uploaded from the output of <a>UpdateFunctionCode</a>.</p>
     #[serde(rename = "CodeSha256")]
@@ -1204,7 +1986,28 @@ pub struct PublishVersionRequest {
     pub revision_id: Option<String>,
 }
 
+impl Validate for PublishVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutFunctionConcurrencyRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -1214,7 +2017,20 @@ pub struct PutFunctionConcurrencyRequest {
     pub reserved_concurrent_executions: i64,
 }
 
+impl Validate for PutFunctionConcurrencyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveLayerVersionPermissionRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
     #[serde(rename = "LayerName")]
@@ -1231,7 +2047,35 @@ pub struct RemoveLayerVersionPermissionRequest {
     pub version_number: i64,
 }
 
+impl Validate for RemoveLayerVersionPermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "layer_name"),
+            self.layer_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(
+            &field_path(path, "layer_name"),
+            &self.layer_name,
+            "(arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+)|[a-zA-Z0-9-_]+",
+        );
+        errors.check_length(
+            &field_path(path, "statement_id"),
+            self.statement_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "statement_id"),
+            &self.statement_id,
+            "([a-zA-Z0-9-_]+)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemovePermissionRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
     #[serde(rename = "FunctionName")]
@@ -1249,7 +2093,40 @@ pub struct RemovePermissionRequest {
     pub statement_id: String,
 }
 
+impl Validate for RemovePermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "qualifier"), &value, "(|[a-zA-Z0-9$_-]+)");
+        }
+        errors.check_length(
+            &field_path(path, "statement_id"),
+            self.statement_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "statement_id"),
+            &self.statement_id,
+            "([a-zA-Z0-9-_.]+)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The function's Amazon Resource Name (ARN).</p>
     #[serde(rename = "Resource")]
@@ -1259,8 +2136,15 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource"), &self.resource, "arn:(aws[a-zA-Z-]*)?:lambda:[a-z]{2}(-gov)?-[a-z]+-\\d{1}:\\d{12}:function:[a-zA-Z0-9-_]+(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 /// <p>The function's AWS X-Ray tracing configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TracingConfig {
     /// <p>The tracing mode.</p>
     #[serde(rename = "Mode")]
@@ -1270,7 +2154,7 @@ pub struct TracingConfig {
 
 /// <p>The function's AWS X-Ray tracing configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TracingConfigResponse {
     /// <p>The tracing mode.</p>
     #[serde(rename = "Mode")]
@@ -1279,6 +2163,7 @@ pub struct TracingConfigResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The function's Amazon Resource Name (ARN).</p>
     #[serde(rename = "Resource")]
@@ -1288,7 +2173,14 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "resource"), &self.resource, "arn:(aws[a-zA-Z-]*)?:lambda:[a-z]{2}(-gov)?-[a-z]+-\\d{1}:\\d{12}:function:[a-zA-Z0-9-_]+(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAliasRequest {
     /// <p>A description of the alias.</p>
     #[serde(rename = "Description")]
@@ -1314,7 +2206,55 @@ pub struct UpdateAliasRequest {
     pub routing_config: Option<AliasRoutingConfiguration>,
 }
 
+impl Validate for UpdateAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.function_version {
+            errors.check_length(
+                &field_path(path, "function_version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "function_version"),
+                &value,
+                "(\\$LATEST|[0-9]+)",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^[0-9]+$)([a-zA-Z0-9-_]+)",
+        );
+        if let Some(ref value) = self.routing_config {
+            value.validate_at(&field_path(path, "routing_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEventSourceMappingRequest {
     /// <p><p>The maximum number of items to retrieve in a single batch.</p> <ul> <li> <p> <b>Amazon Kinesis</b> - Default 100. Max 10,000.</p> </li> <li> <p> <b>Amazon DynamoDB Streams</b> - Default 100. Max 1,000.</p> </li> <li> <p> <b>Amazon Simple Queue Service</b> - Default 10. Max 10.</p> </li> </ul></p>
     #[serde(rename = "BatchSize")]
@@ -1336,7 +2276,38 @@ pub struct UpdateEventSourceMappingRequest {
     pub uuid: String,
 }
 
+impl Validate for UpdateEventSourceMappingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.batch_size {
+            errors.check_range(
+                &field_path(path, "batch_size"),
+                *value,
+                Some(1),
+                Some(10000),
+            );
+        }
+        if let Some(ref value) = self.function_name {
+            errors.check_length(
+                &field_path(path, "function_name"),
+                value.chars().count(),
+                Some(1),
+                Some(140),
+            );
+            errors.check_pattern(&field_path(path, "function_name"), &value, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        }
+        if let Some(ref value) = self.maximum_batching_window_in_seconds {
+            errors.check_range(
+                &field_path(path, "maximum_batching_window_in_seconds"),
+                *value,
+                None,
+                Some(300),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFunctionCodeRequest {
     /// <p>Set to true to validate the request parameters and access permissions without modifying the function code.</p>
     #[serde(rename = "DryRun")]
@@ -1376,7 +2347,49 @@ pub struct UpdateFunctionCodeRequest {
     pub zip_file: Option<bytes::Bytes>,
 }
 
+impl Validate for UpdateFunctionCodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.s3_bucket {
+            errors.check_length(
+                &field_path(path, "s3_bucket"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "s3_bucket"),
+                &value,
+                "^[0-9A-Za-z\\.\\-_]*(?<!\\.)$",
+            );
+        }
+        if let Some(ref value) = self.s3_key {
+            errors.check_length(
+                &field_path(path, "s3_key"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.s3_object_version {
+            errors.check_length(
+                &field_path(path, "s3_object_version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFunctionConfigurationRequest {
     /// <p>A dead letter queue configuration that specifies the queue or topic where Lambda sends asynchronous events when they fail processing. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">Dead Letter Queues</a>.</p>
     #[serde(rename = "DeadLetterConfig")]
@@ -1435,8 +2448,80 @@ pub struct UpdateFunctionConfigurationRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for UpdateFunctionConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dead_letter_config {
+            value.validate_at(&field_path(path, "dead_letter_config"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "function_name"),
+            self.function_name.chars().count(),
+            Some(1),
+            Some(140),
+        );
+        errors.check_pattern(&field_path(path, "function_name"), &self.function_name, "(arn:(aws[a-zA-Z-]*)?:lambda:)?([a-z]{2}(-gov)?-[a-z]+-\\d{1}:)?(\\d{12}:)?(function:)?([a-zA-Z0-9-_]+)(:(\\$LATEST|[a-zA-Z0-9-_]+))?");
+        if let Some(ref value) = self.handler {
+            errors.check_length(
+                &field_path(path, "handler"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "handler"), &value, "[^\\s]+");
+        }
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_pattern(
+                &field_path(path, "kms_key_arn"),
+                &value,
+                "(arn:(aws[a-zA-Z-]*)?:[a-z0-9-.]+:.*)|()",
+            );
+        }
+        if let Some(ref value) = self.layers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "layers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(140));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "arn:[a-zA-Z0-9-]+:lambda:[a-zA-Z0-9-]+:\\d{12}:layer:[a-zA-Z0-9-_]+:[0-9]+",
+                );
+            }
+        }
+        if let Some(ref value) = self.memory_size {
+            errors.check_range(
+                &field_path(path, "memory_size"),
+                *value,
+                Some(128),
+                Some(10240),
+            );
+        }
+        if let Some(ref value) = self.role {
+            errors.check_pattern(
+                &field_path(path, "role"),
+                &value,
+                "arn:(aws[a-zA-Z-]*)?:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+            );
+        }
+        if let Some(ref value) = self.timeout {
+            errors.check_range(&field_path(path, "timeout"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 /// <p>The VPC security groups and subnets that are attached to a Lambda function.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VpcConfig {
     /// <p>A list of VPC security groups IDs.</p>
     #[serde(rename = "SecurityGroupIds")]
@@ -1448,9 +2533,25 @@ pub struct VpcConfig {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+impl Validate for VpcConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.security_group_ids {
+            errors.check_length(
+                &field_path(path, "security_group_ids"),
+                value.len(),
+                None,
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.subnet_ids {
+            errors.check_length(&field_path(path, "subnet_ids"), value.len(), None, Some(16));
+        }
+    }
+}
+
 /// <p>The VPC security groups and subnets that are attached to a Lambda function.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VpcConfigResponse {
     /// <p>A list of VPC security groups IDs.</p>
     #[serde(rename = "SecurityGroupIds")]
@@ -1522,7 +2623,12 @@ impl AddLayerVersionPermissionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1591,7 +2697,12 @@ impl AddPermissionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(AddPermissionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1650,7 +2761,12 @@ impl CreateAliasError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateAliasError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1715,7 +2831,12 @@ impl CreateEventSourceMappingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1779,7 +2900,12 @@ impl CreateFunctionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateFunctionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1827,7 +2953,12 @@ impl DeleteAliasError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteAliasError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1890,7 +3021,12 @@ impl DeleteEventSourceMappingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1949,7 +3085,12 @@ impl DeleteFunctionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteFunctionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2007,7 +3148,12 @@ impl DeleteFunctionConcurrencyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2048,7 +3194,12 @@ impl DeleteLayerVersionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteLayerVersionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2087,7 +3238,12 @@ impl GetAccountSettingsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetAccountSettingsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2134,7 +3290,12 @@ impl GetAliasError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetAliasError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2191,7 +3352,12 @@ impl GetEventSourceMappingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2242,7 +3408,12 @@ impl GetFunctionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetFunctionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2299,7 +3470,12 @@ impl GetFunctionConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2352,7 +3528,12 @@ impl GetLayerVersionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetLayerVersionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2409,7 +3590,12 @@ impl GetLayerVersionByArnError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2466,7 +3652,12 @@ impl GetLayerVersionPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2517,7 +3708,12 @@ impl GetPolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetPolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2646,7 +3842,12 @@ impl InvokeError {
                 "UnsupportedMediaTypeException" => {
                     return RusotoError::Service(InvokeError::UnsupportedMediaType(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2713,7 +3914,12 @@ impl InvokeAsyncError {
                 "ServiceException" => {
                     return RusotoError::Service(InvokeAsyncError::Service(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2764,7 +3970,12 @@ impl ListAliasesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListAliasesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2821,7 +4032,12 @@ impl ListEventSourceMappingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2867,7 +4083,12 @@ impl ListFunctionsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListFunctionsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2919,7 +4140,12 @@ impl ListLayerVersionsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListLayerVersionsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2965,7 +4191,12 @@ impl ListLayersError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListLayersError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3013,7 +4244,12 @@ impl ListTagsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTagsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3070,7 +4306,12 @@ impl ListVersionsByFunctionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3132,7 +4373,12 @@ impl PublishLayerVersionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(PublishLayerVersionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3196,7 +4442,12 @@ impl PublishVersionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(PublishVersionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3255,7 +4506,12 @@ impl PutFunctionConcurrencyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3323,7 +4579,12 @@ impl RemoveLayerVersionPermissionError {
                         RemoveLayerVersionPermissionError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3382,7 +4643,12 @@ impl RemovePermissionError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(RemovePermissionError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3434,7 +4700,12 @@ impl TagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3485,7 +4756,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3541,7 +4817,12 @@ impl UpdateAliasError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateAliasError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3613,7 +4894,12 @@ impl UpdateEventSourceMappingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3682,7 +4968,12 @@ impl UpdateFunctionCodeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateFunctionCodeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3757,7 +5048,12 @@ impl UpdateFunctionConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3998,1137 +5294,2351 @@ pub trait Lambda {
         input: UpdateFunctionConfigurationRequest,
     ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError>;
 }
-/// A client for the AWS Lambda API.
-#[derive(Clone)]
-pub struct LambdaClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl LambdaClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> LambdaClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> LambdaClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> LambdaClient {
-        LambdaClient { client, region }
-    }
-}
-
-impl Lambda for LambdaClient {
-    /// <p>Adds permissions to the resource-based policy of a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Use this action to grant layer usage permission to other accounts. You can grant permission to a single account, all AWS accounts, or all accounts in an organization.</p> <p>To revoke permission, call <a>RemoveLayerVersionPermission</a> with the statement ID that you specified when you added it.</p>
+const _: Option<&dyn Lambda> = None;
+impl<'a, T: Lambda + ?Sized> Lambda for &'a T {
     fn add_layer_version_permission(
         &self,
         input: AddLayerVersionPermissionRequest,
     ) -> RusotoFuture<AddLayerVersionPermissionResponse, AddLayerVersionPermissionError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy",
-            layer_name = input.layer_name,
-            version_number = input.version_number
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.revision_id {
-            params.put("RevisionId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddLayerVersionPermissionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AddLayerVersionPermissionError::from_response(response))
-                }))
-            }
-        })
+        (**self).add_layer_version_permission(input)
     }
-
-    /// <p>Grants an AWS service or another account permission to use a function. You can apply the policy at the function level, or specify a qualifier to restrict access to a single version or alias. If you use a qualifier, the invoker must use the full Amazon Resource Name (ARN) of that version or alias to invoke the function.</p> <p>To grant permission to another account, specify the account ID as the <code>Principal</code>. For AWS services, the principal is a domain-style identifier defined by the service, like <code>s3.amazonaws.com</code> or <code>sns.amazonaws.com</code>. For AWS services, you can also specify the ARN or owning account of the associated resource as the <code>SourceArn</code> or <code>SourceAccount</code>. If you grant permission to a service principal without specifying the source, other accounts could potentially configure resources in their account to invoke your Lambda function.</p> <p>This action adds a statement to a resource-based permission policy for the function. For more information about function policies, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">Lambda Function Policies</a>. </p>
     fn add_permission(
         &self,
         input: AddPermissionRequest,
     ) -> RusotoFuture<AddPermissionResponse, AddPermissionError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/policy",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddPermissionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddPermissionError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_permission(input)
     }
-
-    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a> for a Lambda function version. Use aliases to provide clients with a function identifier that you can update to invoke a different version.</p> <p>You can also map an alias to split invocation requests between two versions. Use the <code>RoutingConfig</code> parameter to specify a second version and the percentage of invocation requests that it receives.</p>
     fn create_alias(
         &self,
         input: CreateAliasRequest,
     ) -> RusotoFuture<AliasConfiguration, CreateAliasError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/aliases",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AliasConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_alias(input)
     }
-
-    /// <p><p>Creates a mapping between an event source and an AWS Lambda function. Lambda reads items from the event source and triggers the function.</p> <p>For details about each event source type, see the following topics.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-kinesis.html">Using AWS Lambda with Amazon Kinesis</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-sqs.html">Using AWS Lambda with Amazon SQS</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-ddb.html">Using AWS Lambda with Amazon DynamoDB</a> </p> </li> </ul></p>
     fn create_event_source_mapping(
         &self,
         input: CreateEventSourceMappingRequest,
     ) -> RusotoFuture<EventSourceMappingConfiguration, CreateEventSourceMappingError> {
-        let request_uri = "/2015-03-31/event-source-mappings/";
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EventSourceMappingConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateEventSourceMappingError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_event_source_mapping(input)
     }
-
-    /// <p>Creates a Lambda function. To create a function, you need a <a href="https://docs.aws.amazon.com/lambda/latest/dg/deployment-package-v2.html">deployment package</a> and an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-permission-model.html#lambda-intro-execution-role">execution role</a>. The deployment package contains your function code. The execution role grants the function permission to use AWS services, such as Amazon CloudWatch Logs for log streaming and AWS X-Ray for request tracing.</p> <p>A function has an unpublished version, and can have published versions and aliases. The unpublished version changes when you update your function's code and configuration. A published version is a snapshot of your function code and configuration that can't be changed. An alias is a named resource that maps to a version, and can be changed to map to a different version. Use the <code>Publish</code> parameter to create version <code>1</code> of your function from its initial configuration.</p> <p>The other parameters let you configure version-specific and function-level settings. You can modify version-specific settings later with <a>UpdateFunctionConfiguration</a>. Function-level settings apply to both the unpublished and published versions of the function, and include tags (<a>TagResource</a>) and per-function concurrency limits (<a>PutFunctionConcurrency</a>).</p> <p>If another account or an AWS service invokes your function, use <a>AddPermission</a> to grant permission by creating a resource-based IAM policy. You can grant permissions at the function level, on a version, or on an alias.</p> <p>To invoke your function directly, use <a>Invoke</a>. To invoke your function in response to events in other AWS services, create an event source mapping (<a>CreateEventSourceMapping</a>), or configure a function trigger in the other service. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/invoking-lambda-functions.html">Invoking Functions</a>.</p>
     fn create_function(
         &self,
         input: CreateFunctionRequest,
     ) -> RusotoFuture<FunctionConfiguration, CreateFunctionError> {
-        let request_uri = "/2015-03-31/functions";
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<FunctionConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFunctionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_function(input)
     }
-
-    /// <p>Deletes a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
     fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/aliases/{name}",
-            function_name = input.function_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_alias(input)
     }
-
-    /// <p>Deletes an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-invocation-modes.html">event source mapping</a>. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
     fn delete_event_source_mapping(
         &self,
         input: DeleteEventSourceMappingRequest,
     ) -> RusotoFuture<EventSourceMappingConfiguration, DeleteEventSourceMappingError> {
-        let request_uri = format!(
-            "/2015-03-31/event-source-mappings/{uuid}",
-            uuid = input.uuid
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EventSourceMappingConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteEventSourceMappingError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_event_source_mapping(input)
     }
-
-    /// <p>Deletes a Lambda function. To delete a specific function version, use the <code>Qualifier</code> parameter. Otherwise, all versions and aliases are deleted.</p> <p>To delete Lambda event source mappings that invoke a function, use <a>DeleteEventSourceMapping</a>. For AWS services and resources that invoke your function directly, delete the trigger in the service where you originally configured it.</p>
     fn delete_function(
         &self,
         input: DeleteFunctionRequest,
     ) -> RusotoFuture<(), DeleteFunctionError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFunctionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_function(input)
     }
-
-    /// <p>Removes a concurrent execution limit from a function.</p>
     fn delete_function_concurrency(
         &self,
         input: DeleteFunctionConcurrencyRequest,
     ) -> RusotoFuture<(), DeleteFunctionConcurrencyError> {
-        let request_uri = format!(
-            "/2017-10-31/functions/{function_name}/concurrency",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteFunctionConcurrencyError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_function_concurrency(input)
     }
-
-    /// <p>Deletes a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Deleted versions can no longer be viewed or added to functions. To avoid breaking functions, a copy of the version remains in Lambda until no functions refer to it.</p>
     fn delete_layer_version(
         &self,
         input: DeleteLayerVersionRequest,
     ) -> RusotoFuture<(), DeleteLayerVersionError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions/{version_number}",
-            layer_name = input.layer_name,
-            version_number = input.version_number
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLayerVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_layer_version(input)
     }
-
-    /// <p>Retrieves details about your account's <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limits</a> and usage in an AWS Region.</p>
     fn get_account_settings(
         &self,
     ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
-        let request_uri = "/2016-08-19/account-settings/";
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAccountSettingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountSettingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_account_settings()
     }
-
-    /// <p>Returns details about a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
     fn get_alias(&self, input: GetAliasRequest) -> RusotoFuture<AliasConfiguration, GetAliasError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/aliases/{name}",
-            function_name = input.function_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AliasConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_alias(input)
     }
-
-    /// <p>Returns details about an event source mapping. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
     fn get_event_source_mapping(
         &self,
         input: GetEventSourceMappingRequest,
     ) -> RusotoFuture<EventSourceMappingConfiguration, GetEventSourceMappingError> {
-        let request_uri = format!(
-            "/2015-03-31/event-source-mappings/{uuid}",
-            uuid = input.uuid
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EventSourceMappingConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetEventSourceMappingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_event_source_mapping(input)
     }
-
-    /// <p>Returns information about the function or function version, with a link to download the deployment package that's valid for 10 minutes. If you specify a function version, only details that are specific to that version are returned.</p>
     fn get_function(
         &self,
         input: GetFunctionRequest,
     ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFunctionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_function(input)
     }
-
-    /// <p>Returns the version-specific settings of a Lambda function or version. The output includes only options that can vary between versions of a function. To modify these settings, use <a>UpdateFunctionConfiguration</a>.</p> <p>To get all of a function's details, including function-level settings, use <a>GetFunction</a>.</p>
     fn get_function_configuration(
         &self,
         input: GetFunctionConfigurationRequest,
     ) -> RusotoFuture<FunctionConfiguration, GetFunctionConfigurationError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/configuration",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<FunctionConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetFunctionConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_function_configuration(input)
     }
-
-    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
     fn get_layer_version(
         &self,
         input: GetLayerVersionRequest,
     ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions/{version_number}",
-            layer_name = input.layer_name,
-            version_number = input.version_number
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLayerVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLayerVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_layer_version(input)
     }
-
-    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
     fn get_layer_version_by_arn(
         &self,
         input: GetLayerVersionByArnRequest,
     ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionByArnError> {
-        let request_uri = "/2018-10-31/layers";
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("Arn", &input.arn);
-        params.put("find", "LayerVersion");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLayerVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetLayerVersionByArnError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_layer_version_by_arn(input)
     }
-
-    /// <p>Returns the permission policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
     fn get_layer_version_policy(
         &self,
         input: GetLayerVersionPolicyRequest,
     ) -> RusotoFuture<GetLayerVersionPolicyResponse, GetLayerVersionPolicyError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy",
-            layer_name = input.layer_name,
-            version_number = input.version_number
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLayerVersionPolicyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetLayerVersionPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_layer_version_policy(input)
     }
-
-    /// <p>Returns the <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">resource-based IAM policy</a> for a function, version, or alias.</p>
     fn get_policy(
         &self,
         input: GetPolicyRequest,
     ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/policy",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPolicyResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_policy(input)
     }
-
-    /// <p>Invokes a Lambda function. You can invoke a function synchronously (and wait for the response), or asynchronously. To invoke a function asynchronously, set <code>InvocationType</code> to <code>Event</code>.</p> <p>For synchronous invocation, details about the function response, including errors, are included in the response body and headers. For either invocation type, you can find more information in the <a href="https://docs.aws.amazon.com/lambda/latest/dg/monitoring-functions.html">execution log</a> and <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">trace</a>. To record function errors for asynchronous invocations, configure your function with a <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">dead letter queue</a>.</p> <p>When an error occurs, your function may be invoked multiple times. Retry behavior varies by error type, client, event source, and invocation type. For example, if you invoke a function asynchronously and it returns an error, Lambda executes the function up to two more times. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/retries-on-errors.html">Retry Behavior</a>.</p> <p>The status code in the API response doesn't reflect function errors. Error codes are reserved for errors that prevent your function from executing, such as permissions errors, <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limit errors</a>, or issues with your function's code and configuration. For example, Lambda returns <code>TooManyRequestsException</code> if executing the function would cause you to exceed a concurrency limit at either the account level (<code>ConcurrentInvocationLimitExceeded</code>) or function level (<code>ReservedFunctionConcurrentInvocationLimitExceeded</code>).</p> <p>For functions with a long timeout, your client might be disconnected during synchronous invocation while it waits for a response. Configure your HTTP client, SDK, firewall, proxy, or operating system to allow for long connections with timeout or keep-alive settings.</p> <p>This operation requires permission for the <code>lambda:InvokeFunction</code> action.</p>
     fn invoke(&self, input: InvocationRequest) -> RusotoFuture<InvocationResponse, InvokeError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/invocations",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = if let Some(ref payload) = input.payload {
-            Some(payload.to_owned())
-        } else {
-            None
-        };
-        request.set_payload(encoded);
-
-        if let Some(ref client_context) = input.client_context {
-            request.add_header("X-Amz-Client-Context", &client_context.to_string());
-        }
-
-        if let Some(ref invocation_type) = input.invocation_type {
-            request.add_header("X-Amz-Invocation-Type", &invocation_type.to_string());
-        }
-
-        if let Some(ref log_type) = input.log_type {
-            request.add_header("X-Amz-Log-Type", &log_type.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = InvocationResponse::default();
-                    result.payload = Some(response.body);
-
-                    if let Some(executed_version) = response.headers.get("X-Amz-Executed-Version") {
-                        let value = executed_version.to_owned();
-                        result.executed_version = Some(value)
-                    };
-                    if let Some(function_error) = response.headers.get("X-Amz-Function-Error") {
-                        let value = function_error.to_owned();
-                        result.function_error = Some(value)
-                    };
-                    if let Some(log_result) = response.headers.get("X-Amz-Log-Result") {
-                        let value = log_result.to_owned();
-                        result.log_result = Some(value)
-                    };
-                    result.status_code = Some(response.status.as_u16() as i64);
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InvokeError::from_response(response))),
-                )
-            }
-        })
+        (**self).invoke(input)
     }
-
-    /// <p><important> <p>For asynchronous function invocation, use <a>Invoke</a>.</p> </important> <p>Invokes a function asynchronously.</p></p>
     fn invoke_async(
         &self,
         input: InvokeAsyncRequest,
     ) -> RusotoFuture<InvokeAsyncResponse, InvokeAsyncError> {
-        let request_uri = format!(
-            "/2014-11-13/functions/{function_name}/invoke-async/",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(input.invoke_args.to_owned());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let mut result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InvokeAsyncResponse, _>()?;
-
-                    result.status = Some(response.status.as_u16() as i64);
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InvokeAsyncError::from_response(response))),
-                )
-            }
-        })
+        (**self).invoke_async(input)
     }
-
-    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">aliases</a> for a Lambda function.</p>
     fn list_aliases(
         &self,
         input: ListAliasesRequest,
     ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/aliases",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.function_version {
-            params.put("FunctionVersion", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAliasesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAliasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_aliases(input)
     }
-
-    /// <p>Lists event source mappings. Specify an <code>EventSourceArn</code> to only show event source mappings for a single event source.</p>
     fn list_event_source_mappings(
         &self,
         input: ListEventSourceMappingsRequest,
     ) -> RusotoFuture<ListEventSourceMappingsResponse, ListEventSourceMappingsError> {
-        let request_uri = "/2015-03-31/event-source-mappings/";
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.event_source_arn {
-            params.put("EventSourceArn", x);
-        }
-        if let Some(ref x) = input.function_name {
-            params.put("FunctionName", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEventSourceMappingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListEventSourceMappingsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_event_source_mappings(input)
     }
-
-    /// <p>Returns a list of Lambda functions, with the version-specific configuration of each.</p> <p>Set <code>FunctionVersion</code> to <code>ALL</code> to include all published versions of each function in addition to the unpublished version. To get more information about a function or version, use <a>GetFunction</a>.</p>
     fn list_functions(
         &self,
         input: ListFunctionsRequest,
     ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
-        let request_uri = "/2015-03-31/functions/";
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.function_version {
-            params.put("FunctionVersion", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.master_region {
-            params.put("MasterRegion", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFunctionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFunctionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_functions(input)
     }
-
-    /// <p>Lists the versions of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Versions that have been deleted aren't listed. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only versions that indicate that they're compatible with that runtime.</p>
     fn list_layer_versions(
         &self,
         input: ListLayerVersionsRequest,
     ) -> RusotoFuture<ListLayerVersionsResponse, ListLayerVersionsError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions",
-            layer_name = input.layer_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.compatible_runtime {
-            params.put("CompatibleRuntime", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLayerVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListLayerVersionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_layer_versions(input)
     }
-
-    /// <p>Lists <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layers</a> and shows information about the latest version of each. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only layers that indicate that they're compatible with that runtime.</p>
     fn list_layers(
         &self,
         input: ListLayersRequest,
     ) -> RusotoFuture<ListLayersResponse, ListLayersError> {
-        let request_uri = "/2018-10-31/layers";
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.compatible_runtime {
-            params.put("CompatibleRuntime", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLayersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListLayersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_layers(input)
     }
-
-    /// <p>Returns a function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a>. You can also view tags with <a>GetFunction</a>.</p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
-        let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags(input)
     }
-
-    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">versions</a>, with the version-specific configuration of each. </p>
     fn list_versions_by_function(
         &self,
         input: ListVersionsByFunctionRequest,
     ) -> RusotoFuture<ListVersionsByFunctionResponse, ListVersionsByFunctionError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/versions",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVersionsByFunctionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListVersionsByFunctionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_versions_by_function(input)
     }
-
-    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a> from a ZIP archive. Each time you call <code>PublishLayerVersion</code> with the same version name, a new version is created.</p> <p>Add layers to your function with <a>CreateFunction</a> or <a>UpdateFunctionConfiguration</a>.</p>
     fn publish_layer_version(
         &self,
         input: PublishLayerVersionRequest,
     ) -> RusotoFuture<PublishLayerVersionResponse, PublishLayerVersionError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions",
-            layer_name = input.layer_name
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PublishLayerVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PublishLayerVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).publish_layer_version(input)
     }
-
-    /// <p>Creates a <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">version</a> from the current code and configuration of a function. Use versions to create a snapshot of your function code and configuration that doesn't change.</p> <p>AWS Lambda doesn't publish a version if the function's configuration and code haven't changed since the last version. Use <a>UpdateFunctionCode</a> or <a>UpdateFunctionConfiguration</a> to update the function before publishing a version.</p> <p>Clients can invoke versions directly or with an alias. To create an alias, use <a>CreateAlias</a>.</p>
     fn publish_version(
         &self,
         input: PublishVersionRequest,
     ) -> RusotoFuture<FunctionConfiguration, PublishVersionError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/versions",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<FunctionConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PublishVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).publish_version(input)
     }
-
-    /// <p>Sets the maximum number of simultaneous executions for a function, and reserves capacity for that concurrency level.</p> <p>Concurrency settings apply to the function as a whole, including all published versions and the unpublished version. Reserving concurrency both ensures that your function has capacity to process the specified number of events simultaneously, and prevents it from scaling beyond that level. Use <a>GetFunction</a> to see the current setting for a function.</p> <p>Use <a>GetAccountSettings</a> to see your regional concurrency limit. You can reserve concurrency for as many functions as you like, as long as you leave at least 100 simultaneous executions unreserved for functions that aren't configured with a per-function limit. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/concurrent-executions.html">Managing Concurrency</a>.</p>
     fn put_function_concurrency(
         &self,
         input: PutFunctionConcurrencyRequest,
     ) -> RusotoFuture<Concurrency, PutFunctionConcurrencyError> {
-        let request_uri = format!(
-            "/2017-10-31/functions/{function_name}/concurrency",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
+        (**self).put_function_concurrency(input)
+    }
+    fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> RusotoFuture<(), RemoveLayerVersionPermissionError> {
+        (**self).remove_layer_version_permission(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, UpdateEventSourceMappingError> {
+        (**self).update_event_source_mapping(input)
+    }
+    fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionCodeError> {
+        (**self).update_function_code(input)
+    }
+    fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError> {
+        (**self).update_function_configuration(input)
+    }
+}
+impl<T: Lambda + ?Sized> Lambda for Box<T> {
+    fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> RusotoFuture<AddLayerVersionPermissionResponse, AddLayerVersionPermissionError> {
+        (**self).add_layer_version_permission(input)
+    }
+    fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> RusotoFuture<AddPermissionResponse, AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, CreateEventSourceMappingError> {
+        (**self).create_event_source_mapping(input)
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, CreateFunctionError> {
+        (**self).create_function(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, DeleteEventSourceMappingError> {
+        (**self).delete_event_source_mapping(input)
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<(), DeleteFunctionError> {
+        (**self).delete_function(input)
+    }
+    fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> RusotoFuture<(), DeleteFunctionConcurrencyError> {
+        (**self).delete_function_concurrency(input)
+    }
+    fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> RusotoFuture<(), DeleteLayerVersionError> {
+        (**self).delete_layer_version(input)
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        (**self).get_account_settings()
+    }
+    fn get_alias(&self, input: GetAliasRequest) -> RusotoFuture<AliasConfiguration, GetAliasError> {
+        (**self).get_alias(input)
+    }
+    fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, GetEventSourceMappingError> {
+        (**self).get_event_source_mapping(input)
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        (**self).get_function(input)
+    }
+    fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, GetFunctionConfigurationError> {
+        (**self).get_function_configuration(input)
+    }
+    fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionError> {
+        (**self).get_layer_version(input)
+    }
+    fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionByArnError> {
+        (**self).get_layer_version_by_arn(input)
+    }
+    fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> RusotoFuture<GetLayerVersionPolicyResponse, GetLayerVersionPolicyError> {
+        (**self).get_layer_version_policy(input)
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn invoke(&self, input: InvocationRequest) -> RusotoFuture<InvocationResponse, InvokeError> {
+        (**self).invoke(input)
+    }
+    fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> RusotoFuture<InvokeAsyncResponse, InvokeAsyncError> {
+        (**self).invoke_async(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> RusotoFuture<ListEventSourceMappingsResponse, ListEventSourceMappingsError> {
+        (**self).list_event_source_mappings(input)
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        (**self).list_functions(input)
+    }
+    fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> RusotoFuture<ListLayerVersionsResponse, ListLayerVersionsError> {
+        (**self).list_layer_versions(input)
+    }
+    fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> RusotoFuture<ListLayersResponse, ListLayersError> {
+        (**self).list_layers(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> RusotoFuture<ListVersionsByFunctionResponse, ListVersionsByFunctionError> {
+        (**self).list_versions_by_function(input)
+    }
+    fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> RusotoFuture<PublishLayerVersionResponse, PublishLayerVersionError> {
+        (**self).publish_layer_version(input)
+    }
+    fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, PublishVersionError> {
+        (**self).publish_version(input)
+    }
+    fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> RusotoFuture<Concurrency, PutFunctionConcurrencyError> {
+        (**self).put_function_concurrency(input)
+    }
+    fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> RusotoFuture<(), RemoveLayerVersionPermissionError> {
+        (**self).remove_layer_version_permission(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, UpdateEventSourceMappingError> {
+        (**self).update_event_source_mapping(input)
+    }
+    fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionCodeError> {
+        (**self).update_function_code(input)
+    }
+    fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError> {
+        (**self).update_function_configuration(input)
+    }
+}
+impl<T: Lambda + ?Sized> Lambda for ::std::sync::Arc<T> {
+    fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> RusotoFuture<AddLayerVersionPermissionResponse, AddLayerVersionPermissionError> {
+        (**self).add_layer_version_permission(input)
+    }
+    fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> RusotoFuture<AddPermissionResponse, AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, CreateEventSourceMappingError> {
+        (**self).create_event_source_mapping(input)
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, CreateFunctionError> {
+        (**self).create_function(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, DeleteEventSourceMappingError> {
+        (**self).delete_event_source_mapping(input)
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<(), DeleteFunctionError> {
+        (**self).delete_function(input)
+    }
+    fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> RusotoFuture<(), DeleteFunctionConcurrencyError> {
+        (**self).delete_function_concurrency(input)
+    }
+    fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> RusotoFuture<(), DeleteLayerVersionError> {
+        (**self).delete_layer_version(input)
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        (**self).get_account_settings()
+    }
+    fn get_alias(&self, input: GetAliasRequest) -> RusotoFuture<AliasConfiguration, GetAliasError> {
+        (**self).get_alias(input)
+    }
+    fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, GetEventSourceMappingError> {
+        (**self).get_event_source_mapping(input)
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        (**self).get_function(input)
+    }
+    fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, GetFunctionConfigurationError> {
+        (**self).get_function_configuration(input)
+    }
+    fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionError> {
+        (**self).get_layer_version(input)
+    }
+    fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionByArnError> {
+        (**self).get_layer_version_by_arn(input)
+    }
+    fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> RusotoFuture<GetLayerVersionPolicyResponse, GetLayerVersionPolicyError> {
+        (**self).get_layer_version_policy(input)
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn invoke(&self, input: InvocationRequest) -> RusotoFuture<InvocationResponse, InvokeError> {
+        (**self).invoke(input)
+    }
+    fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> RusotoFuture<InvokeAsyncResponse, InvokeAsyncError> {
+        (**self).invoke_async(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> RusotoFuture<ListEventSourceMappingsResponse, ListEventSourceMappingsError> {
+        (**self).list_event_source_mappings(input)
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        (**self).list_functions(input)
+    }
+    fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> RusotoFuture<ListLayerVersionsResponse, ListLayerVersionsError> {
+        (**self).list_layer_versions(input)
+    }
+    fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> RusotoFuture<ListLayersResponse, ListLayersError> {
+        (**self).list_layers(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> RusotoFuture<ListVersionsByFunctionResponse, ListVersionsByFunctionError> {
+        (**self).list_versions_by_function(input)
+    }
+    fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> RusotoFuture<PublishLayerVersionResponse, PublishLayerVersionError> {
+        (**self).publish_layer_version(input)
+    }
+    fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, PublishVersionError> {
+        (**self).publish_version(input)
+    }
+    fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> RusotoFuture<Concurrency, PutFunctionConcurrencyError> {
+        (**self).put_function_concurrency(input)
+    }
+    fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> RusotoFuture<(), RemoveLayerVersionPermissionError> {
+        (**self).remove_layer_version_permission(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, UpdateEventSourceMappingError> {
+        (**self).update_event_source_mapping(input)
+    }
+    fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionCodeError> {
+        (**self).update_function_code(input)
+    }
+    fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError> {
+        (**self).update_function_configuration(input)
+    }
+}
+/// A client for the AWS Lambda API.
+#[derive(Clone)]
+pub struct LambdaClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl LambdaClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LambdaClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LambdaClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> LambdaClient {
+        LambdaClient { client, region }
+    }
+}
+
+impl Lambda for LambdaClient {
+    /// <p>Adds permissions to the resource-based policy of a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Use this action to grant layer usage permission to other accounts. You can grant permission to a single account, all AWS accounts, or all accounts in an organization.</p> <p>To revoke permission, call <a>RemoveLayerVersionPermission</a> with the statement ID that you specified when you added it.</p>
+    fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> RusotoFuture<AddLayerVersionPermissionResponse, AddLayerVersionPermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy",
+            layer_name = input.layer_name,
+            version_number = input.version_number
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddLayerVersionPermission");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.add_resource_id("VersionNumber", &input.version_number.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.revision_id {
+            params.put("RevisionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AddLayerVersionPermission")
+                        .deserialize::<AddLayerVersionPermissionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AddLayerVersionPermissionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Grants an AWS service or another account permission to use a function. You can apply the policy at the function level, or specify a qualifier to restrict access to a single version or alias. If you use a qualifier, the invoker must use the full Amazon Resource Name (ARN) of that version or alias to invoke the function.</p> <p>To grant permission to another account, specify the account ID as the <code>Principal</code>. For AWS services, the principal is a domain-style identifier defined by the service, like <code>s3.amazonaws.com</code> or <code>sns.amazonaws.com</code>. For AWS services, you can also specify the ARN or owning account of the associated resource as the <code>SourceArn</code> or <code>SourceAccount</code>. If you grant permission to a service principal without specifying the source, other accounts could potentially configure resources in their account to invoke your Lambda function.</p> <p>This action adds a statement to a resource-based permission policy for the function. For more information about function policies, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">Lambda Function Policies</a>. </p>
+    fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> RusotoFuture<AddPermissionResponse, AddPermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/policy",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddPermission");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AddPermission")
+                        .deserialize::<AddPermissionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddPermissionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a> for a Lambda function version. Use aliases to provide clients with a function identifier that you can update to invoke a different version.</p> <p>You can also map an alias to split invocation requests between two versions. Use the <code>RoutingConfig</code> parameter to specify a second version and the percentage of invocation requests that it receives.</p>
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, CreateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/aliases",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateAlias");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateAlias")
+                        .deserialize::<AliasConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a mapping between an event source and an AWS Lambda function. Lambda reads items from the event source and triggers the function.</p> <p>For details about each event source type, see the following topics.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-kinesis.html">Using AWS Lambda with Amazon Kinesis</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-sqs.html">Using AWS Lambda with Amazon SQS</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-ddb.html">Using AWS Lambda with Amazon DynamoDB</a> </p> </li> </ul></p>
+    fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, CreateEventSourceMappingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2015-03-31/event-source-mappings/";
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateEventSourceMapping");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateEventSourceMapping")
+                        .deserialize::<EventSourceMappingConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateEventSourceMappingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a Lambda function. To create a function, you need a <a href="https://docs.aws.amazon.com/lambda/latest/dg/deployment-package-v2.html">deployment package</a> and an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-permission-model.html#lambda-intro-execution-role">execution role</a>. The deployment package contains your function code. The execution role grants the function permission to use AWS services, such as Amazon CloudWatch Logs for log streaming and AWS X-Ray for request tracing.</p> <p>A function has an unpublished version, and can have published versions and aliases. The unpublished version changes when you update your function's code and configuration. A published version is a snapshot of your function code and configuration that can't be changed. An alias is a named resource that maps to a version, and can be changed to map to a different version. Use the <code>Publish</code> parameter to create version <code>1</code> of your function from its initial configuration.</p> <p>The other parameters let you configure version-specific and function-level settings. You can modify version-specific settings later with <a>UpdateFunctionConfiguration</a>. Function-level settings apply to both the unpublished and published versions of the function, and include tags (<a>TagResource</a>) and per-function concurrency limits (<a>PutFunctionConcurrency</a>).</p> <p>If another account or an AWS service invokes your function, use <a>AddPermission</a> to grant permission by creating a resource-based IAM policy. You can grant permissions at the function level, on a version, or on an alias.</p> <p>To invoke your function directly, use <a>Invoke</a>. To invoke your function in response to events in other AWS services, create an event source mapping (<a>CreateEventSourceMapping</a>), or configure a function trigger in the other service. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/invoking-lambda-functions.html">Invoking Functions</a>.</p>
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, CreateFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2015-03-31/functions";
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFunction")
+                        .deserialize::<FunctionConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/aliases/{name}",
+            function_name = input.function_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteAlias");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-invocation-modes.html">event source mapping</a>. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
+    fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, DeleteEventSourceMappingError> {
+        let request_uri = format!(
+            "/2015-03-31/event-source-mappings/{uuid}",
+            uuid = input.uuid
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteEventSourceMapping");
+        request.add_resource_id("UUID", &input.uuid.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteEventSourceMapping")
+                        .deserialize::<EventSourceMappingConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteEventSourceMappingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a Lambda function. To delete a specific function version, use the <code>Qualifier</code> parameter. Otherwise, all versions and aliases are deleted.</p> <p>To delete Lambda event source mappings that invoke a function, use <a>DeleteEventSourceMapping</a>. For AWS services and resources that invoke your function directly, delete the trigger in the service where you originally configured it.</p>
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<(), DeleteFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a concurrent execution limit from a function.</p>
+    fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> RusotoFuture<(), DeleteFunctionConcurrencyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2017-10-31/functions/{function_name}/concurrency",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionConcurrency");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteFunctionConcurrencyError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Deleted versions can no longer be viewed or added to functions. To avoid breaking functions, a copy of the version remains in Lambda until no functions refer to it.</p>
+    fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> RusotoFuture<(), DeleteLayerVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions/{version_number}",
+            layer_name = input.layer_name,
+            version_number = input.version_number
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteLayerVersion");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.add_resource_id("VersionNumber", &input.version_number.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLayerVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details about your account's <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limits</a> and usage in an AWS Region.</p>
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        let request_uri = "/2016-08-19/account-settings/";
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAccountSettings")
+                        .deserialize::<GetAccountSettingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountSettingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns details about a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    fn get_alias(&self, input: GetAliasRequest) -> RusotoFuture<AliasConfiguration, GetAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/aliases/{name}",
+            function_name = input.function_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAlias");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetAlias")
+                        .deserialize::<AliasConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns details about an event source mapping. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
+    fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, GetEventSourceMappingError> {
+        let request_uri = format!(
+            "/2015-03-31/event-source-mappings/{uuid}",
+            uuid = input.uuid
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetEventSourceMapping");
+        request.add_resource_id("UUID", &input.uuid.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetEventSourceMapping")
+                        .deserialize::<EventSourceMappingConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetEventSourceMappingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the function or function version, with a link to download the deployment package that's valid for 10 minutes. If you specify a function version, only details that are specific to that version are returned.</p>
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunction");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFunction")
+                        .deserialize::<GetFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFunctionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the version-specific settings of a Lambda function or version. The output includes only options that can vary between versions of a function. To modify these settings, use <a>UpdateFunctionConfiguration</a>.</p> <p>To get all of a function's details, including function-level settings, use <a>GetFunction</a>.</p>
+    fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, GetFunctionConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/configuration",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConfiguration");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFunctionConfiguration")
+                        .deserialize::<FunctionConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetFunctionConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
+    fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions/{version_number}",
+            layer_name = input.layer_name,
+            version_number = input.version_number
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersion");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.add_resource_id("VersionNumber", &input.version_number.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLayerVersion")
+                        .deserialize::<GetLayerVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLayerVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
+    fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionByArnError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-10-31/layers";
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionByArn");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("Arn", &input.arn);
+        params.put("find", "LayerVersion");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLayerVersionByArn")
+                        .deserialize::<GetLayerVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetLayerVersionByArnError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the permission policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
+    fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> RusotoFuture<GetLayerVersionPolicyResponse, GetLayerVersionPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy",
+            layer_name = input.layer_name,
+            version_number = input.version_number
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionPolicy");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.add_resource_id("VersionNumber", &input.version_number.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLayerVersionPolicy")
+                        .deserialize::<GetLayerVersionPolicyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetLayerVersionPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">resource-based IAM policy</a> for a function, version, or alias.</p>
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/policy",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetPolicy")
+                        .deserialize::<GetPolicyResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Invokes a Lambda function. You can invoke a function synchronously (and wait for the response), or asynchronously. To invoke a function asynchronously, set <code>InvocationType</code> to <code>Event</code>.</p> <p>For synchronous invocation, details about the function response, including errors, are included in the response body and headers. For either invocation type, you can find more information in the <a href="https://docs.aws.amazon.com/lambda/latest/dg/monitoring-functions.html">execution log</a> and <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">trace</a>. To record function errors for asynchronous invocations, configure your function with a <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">dead letter queue</a>.</p> <p>When an error occurs, your function may be invoked multiple times. Retry behavior varies by error type, client, event source, and invocation type. For example, if you invoke a function asynchronously and it returns an error, Lambda executes the function up to two more times. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/retries-on-errors.html">Retry Behavior</a>.</p> <p>The status code in the API response doesn't reflect function errors. Error codes are reserved for errors that prevent your function from executing, such as permissions errors, <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limit errors</a>, or issues with your function's code and configuration. For example, Lambda returns <code>TooManyRequestsException</code> if executing the function would cause you to exceed a concurrency limit at either the account level (<code>ConcurrentInvocationLimitExceeded</code>) or function level (<code>ReservedFunctionConcurrentInvocationLimitExceeded</code>).</p> <p>For functions with a long timeout, your client might be disconnected during synchronous invocation while it waits for a response. Configure your HTTP client, SDK, firewall, proxy, or operating system to allow for long connections with timeout or keep-alive settings.</p> <p>This operation requires permission for the <code>lambda:InvokeFunction</code> action.</p>
+    fn invoke(&self, input: InvocationRequest) -> RusotoFuture<InvocationResponse, InvokeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/invocations",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("Invoke");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = if let Some(ref payload) = input.payload {
+            Some(payload.to_owned())
+        } else {
+            None
+        };
+        request.set_payload(encoded);
+
+        if let Some(ref client_context) = input.client_context {
+            request.add_header("X-Amz-Client-Context", &client_context.to_string());
+        }
+
+        if let Some(ref invocation_type) = input.invocation_type {
+            request.add_header("X-Amz-Invocation-Type", &invocation_type.to_string());
+        }
+
+        if let Some(ref log_type) = input.log_type {
+            request.add_header("X-Amz-Log-Type", &log_type.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = InvocationResponse::default();
+                    result.payload = Some(response.body);
+
+                    if let Some(executed_version) = response.headers.get("X-Amz-Executed-Version") {
+                        let value = executed_version.to_owned();
+                        result.executed_version = Some(value)
+                    };
+                    if let Some(function_error) = response.headers.get("X-Amz-Function-Error") {
+                        let value = function_error.to_owned();
+                        result.function_error = Some(value)
+                    };
+                    if let Some(log_result) = response.headers.get("X-Amz-Log-Result") {
+                        let value = log_result.to_owned();
+                        result.log_result = Some(value)
+                    };
+                    result.status_code = Some(response.status.as_u16() as i64);
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InvokeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><important> <p>For asynchronous function invocation, use <a>Invoke</a>.</p> </important> <p>Invokes a function asynchronously.</p></p>
+    fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> RusotoFuture<InvokeAsyncResponse, InvokeAsyncError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2014-11-13/functions/{function_name}/invoke-async/",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("InvokeAsync");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(input.invoke_args.to_owned());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let mut result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InvokeAsync")
+                        .deserialize::<InvokeAsyncResponse, _>()?;
+
+                    result.status = Some(response.status.as_u16() as i64);
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InvokeAsyncError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">aliases</a> for a Lambda function.</p>
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/aliases",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListAliases");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.function_version {
+            params.put("FunctionVersion", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListAliases")
+                        .deserialize::<ListAliasesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAliasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists event source mappings. Specify an <code>EventSourceArn</code> to only show event source mappings for a single event source.</p>
+    fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> RusotoFuture<ListEventSourceMappingsResponse, ListEventSourceMappingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2015-03-31/event-source-mappings/";
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListEventSourceMappings");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.event_source_arn {
+            params.put("EventSourceArn", x);
+        }
+        if let Some(ref x) = input.function_name {
+            params.put("FunctionName", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListEventSourceMappings")
+                        .deserialize::<ListEventSourceMappingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListEventSourceMappingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of Lambda functions, with the version-specific configuration of each.</p> <p>Set <code>FunctionVersion</code> to <code>ALL</code> to include all published versions of each function in addition to the unpublished version. To get more information about a function or version, use <a>GetFunction</a>.</p>
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2015-03-31/functions/";
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.function_version {
+            params.put("FunctionVersion", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.master_region {
+            params.put("MasterRegion", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFunctions")
+                        .deserialize::<ListFunctionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFunctionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the versions of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Versions that have been deleted aren't listed. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only versions that indicate that they're compatible with that runtime.</p>
+    fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> RusotoFuture<ListLayerVersionsResponse, ListLayerVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions",
+            layer_name = input.layer_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayerVersions");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.compatible_runtime {
+            params.put("CompatibleRuntime", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListLayerVersions")
+                        .deserialize::<ListLayerVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListLayerVersionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layers</a> and shows information about the latest version of each. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only layers that indicate that they're compatible with that runtime.</p>
+    fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> RusotoFuture<ListLayersResponse, ListLayersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2018-10-31/layers";
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.compatible_runtime {
+            params.put("CompatibleRuntime", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListLayers")
+                        .deserialize::<ListLayersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListLayersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a>. You can also view tags with <a>GetFunction</a>.</p>
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListTags");
+        request.add_resource_id("Resource", &input.resource.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTags")
+                        .deserialize::<ListTagsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">versions</a>, with the version-specific configuration of each. </p>
+    fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> RusotoFuture<ListVersionsByFunctionResponse, ListVersionsByFunctionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/versions",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListVersionsByFunction");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListVersionsByFunction")
+                        .deserialize::<ListVersionsByFunctionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListVersionsByFunctionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a> from a ZIP archive. Each time you call <code>PublishLayerVersion</code> with the same version name, a new version is created.</p> <p>Add layers to your function with <a>CreateFunction</a> or <a>UpdateFunctionConfiguration</a>.</p>
+    fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> RusotoFuture<PublishLayerVersionResponse, PublishLayerVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions",
+            layer_name = input.layer_name
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishLayerVersion");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PublishLayerVersion")
+                        .deserialize::<PublishLayerVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PublishLayerVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">version</a> from the current code and configuration of a function. Use versions to create a snapshot of your function code and configuration that doesn't change.</p> <p>AWS Lambda doesn't publish a version if the function's configuration and code haven't changed since the last version. Use <a>UpdateFunctionCode</a> or <a>UpdateFunctionConfiguration</a> to update the function before publishing a version.</p> <p>Clients can invoke versions directly or with an alias. To create an alias, use <a>CreateAlias</a>.</p>
+    fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, PublishVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/versions",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishVersion");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PublishVersion")
+                        .deserialize::<FunctionConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PublishVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the maximum number of simultaneous executions for a function, and reserves capacity for that concurrency level.</p> <p>Concurrency settings apply to the function as a whole, including all published versions and the unpublished version. Reserving concurrency both ensures that your function has capacity to process the specified number of events simultaneously, and prevents it from scaling beyond that level. Use <a>GetFunction</a> to see the current setting for a function.</p> <p>Use <a>GetAccountSettings</a> to see your regional concurrency limit. You can reserve concurrency for as many functions as you like, as long as you leave at least 100 simultaneous executions unreserved for functions that aren't configured with a per-function limit. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/concurrent-executions.html">Managing Concurrency</a>.</p>
+    fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> RusotoFuture<Concurrency, PutFunctionConcurrencyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2017-10-31/functions/{function_name}/concurrency",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionConcurrency");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutFunctionConcurrency")
+                        .deserialize::<Concurrency, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutFunctionConcurrencyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a statement from the permissions policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
+    fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> RusotoFuture<(), RemoveLayerVersionPermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy/{statement_id}",
+            layer_name = input.layer_name,
+            statement_id = input.statement_id,
+            version_number = input.version_number
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemoveLayerVersionPermission");
+        request.add_resource_id("LayerName", &input.layer_name.to_string());
+        request.add_resource_id("StatementId", &input.statement_id.to_string());
+        request.add_resource_id("VersionNumber", &input.version_number.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.revision_id {
+            params.put("RevisionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveLayerVersionPermissionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Revokes function-use permission from an AWS service or another account. You can get the ID of the statement from the output of <a>GetPolicy</a>.</p>
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/policy/{statement_id}",
+            function_name = input.function_name,
+            statement_id = input.statement_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.add_resource_id("StatementId", &input.statement_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.qualifier {
+            params.put("Qualifier", x);
+        }
+        if let Some(ref x) = input.revision_id {
+            params.put("RevisionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RemovePermissionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to a function.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
+
+        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("Resource", &input.resource.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> from a function.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
+
+        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("Resource", &input.resource.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the configuration of a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, UpdateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/aliases/{name}",
+            function_name = input.function_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateAlias");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
@@ -5136,288 +7646,1443 @@ impl Lambda for LambdaClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<Concurrency, _>()?;
+                        .with_operation("UpdateAlias")
+                        .deserialize::<AliasConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an event source mapping. You can change the function that AWS Lambda invokes, or pause invocation and resume later from the same location.</p>
+    fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, UpdateEventSourceMappingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/event-source-mappings/{uuid}",
+            uuid = input.uuid
+        );
+
+        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateEventSourceMapping");
+        request.add_resource_id("UUID", &input.uuid.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateEventSourceMapping")
+                        .deserialize::<EventSourceMappingConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateEventSourceMappingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a Lambda function's code.</p> <p>The function's code is locked when you publish a version. You can't modify the code of a published version, only the unpublished version.</p>
+    fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionCodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/code",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionCode");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFunctionCode")
+                        .deserialize::<FunctionConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFunctionCodeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Modify the version-specific settings of a Lambda function.</p> <p>These settings can vary between versions of a function and are locked when you publish a version. You can't modify the configuration of a published version, only the unpublished version.</p> <p>To configure function concurrency, use <a>PutFunctionConcurrency</a>. To grant invoke permissions to an account or AWS service, use <a>AddPermission</a>.</p>
+    fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2015-03-31/functions/{function_name}/configuration",
+            function_name = input.function_name
+        );
+
+        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionConfiguration");
+        request.add_resource_id("FunctionName", &input.function_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFunctionConfiguration")
+                        .deserialize::<FunctionConfiguration, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateFunctionConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Lambda API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockLambda {
+    add_layer_version_permission: Option<
+        Box<
+            dyn Fn(
+                    AddLayerVersionPermissionRequest,
+                ) -> ::std::result::Result<
+                    AddLayerVersionPermissionResponse,
+                    RusotoError<AddLayerVersionPermissionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_permission: Option<
+        Box<
+            dyn Fn(
+                    AddPermissionRequest,
+                )
+                    -> ::std::result::Result<AddPermissionResponse, RusotoError<AddPermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_alias: Option<
+        Box<
+            dyn Fn(
+                    CreateAliasRequest,
+                )
+                    -> ::std::result::Result<AliasConfiguration, RusotoError<CreateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_event_source_mapping: Option<
+        Box<
+            dyn Fn(
+                    CreateEventSourceMappingRequest,
+                ) -> ::std::result::Result<
+                    EventSourceMappingConfiguration,
+                    RusotoError<CreateEventSourceMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_function: Option<
+        Box<
+            dyn Fn(
+                    CreateFunctionRequest,
+                )
+                    -> ::std::result::Result<FunctionConfiguration, RusotoError<CreateFunctionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_alias: Option<
+        Box<
+            dyn Fn(DeleteAliasRequest) -> ::std::result::Result<(), RusotoError<DeleteAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_event_source_mapping: Option<
+        Box<
+            dyn Fn(
+                    DeleteEventSourceMappingRequest,
+                ) -> ::std::result::Result<
+                    EventSourceMappingConfiguration,
+                    RusotoError<DeleteEventSourceMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_function: Option<
+        Box<
+            dyn Fn(
+                    DeleteFunctionRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteFunctionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_function_concurrency: Option<
+        Box<
+            dyn Fn(
+                    DeleteFunctionConcurrencyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteFunctionConcurrencyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_layer_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteLayerVersionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteLayerVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_account_settings: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetAccountSettingsResponse,
+                    RusotoError<GetAccountSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_alias: Option<
+        Box<
+            dyn Fn(
+                    GetAliasRequest,
+                )
+                    -> ::std::result::Result<AliasConfiguration, RusotoError<GetAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_event_source_mapping: Option<
+        Box<
+            dyn Fn(
+                    GetEventSourceMappingRequest,
+                ) -> ::std::result::Result<
+                    EventSourceMappingConfiguration,
+                    RusotoError<GetEventSourceMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_function: Option<
+        Box<
+            dyn Fn(
+                    GetFunctionRequest,
+                )
+                    -> ::std::result::Result<GetFunctionResponse, RusotoError<GetFunctionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_function_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetFunctionConfigurationRequest,
+                ) -> ::std::result::Result<
+                    FunctionConfiguration,
+                    RusotoError<GetFunctionConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_layer_version: Option<
+        Box<
+            dyn Fn(
+                    GetLayerVersionRequest,
+                ) -> ::std::result::Result<
+                    GetLayerVersionResponse,
+                    RusotoError<GetLayerVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_layer_version_by_arn: Option<
+        Box<
+            dyn Fn(
+                    GetLayerVersionByArnRequest,
+                ) -> ::std::result::Result<
+                    GetLayerVersionResponse,
+                    RusotoError<GetLayerVersionByArnError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_layer_version_policy: Option<
+        Box<
+            dyn Fn(
+                    GetLayerVersionPolicyRequest,
+                ) -> ::std::result::Result<
+                    GetLayerVersionPolicyResponse,
+                    RusotoError<GetLayerVersionPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_policy: Option<
+        Box<
+            dyn Fn(
+                    GetPolicyRequest,
+                )
+                    -> ::std::result::Result<GetPolicyResponse, RusotoError<GetPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    invoke: Option<
+        Box<
+            dyn Fn(
+                    InvocationRequest,
+                )
+                    -> ::std::result::Result<InvocationResponse, RusotoError<InvokeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    invoke_async: Option<
+        Box<
+            dyn Fn(
+                    InvokeAsyncRequest,
+                )
+                    -> ::std::result::Result<InvokeAsyncResponse, RusotoError<InvokeAsyncError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_aliases: Option<
+        Box<
+            dyn Fn(
+                    ListAliasesRequest,
+                )
+                    -> ::std::result::Result<ListAliasesResponse, RusotoError<ListAliasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_event_source_mappings: Option<
+        Box<
+            dyn Fn(
+                    ListEventSourceMappingsRequest,
+                ) -> ::std::result::Result<
+                    ListEventSourceMappingsResponse,
+                    RusotoError<ListEventSourceMappingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_functions: Option<
+        Box<
+            dyn Fn(
+                    ListFunctionsRequest,
+                )
+                    -> ::std::result::Result<ListFunctionsResponse, RusotoError<ListFunctionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_layer_versions: Option<
+        Box<
+            dyn Fn(
+                    ListLayerVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListLayerVersionsResponse,
+                    RusotoError<ListLayerVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_layers: Option<
+        Box<
+            dyn Fn(
+                    ListLayersRequest,
+                )
+                    -> ::std::result::Result<ListLayersResponse, RusotoError<ListLayersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_versions_by_function: Option<
+        Box<
+            dyn Fn(
+                    ListVersionsByFunctionRequest,
+                ) -> ::std::result::Result<
+                    ListVersionsByFunctionResponse,
+                    RusotoError<ListVersionsByFunctionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    publish_layer_version: Option<
+        Box<
+            dyn Fn(
+                    PublishLayerVersionRequest,
+                ) -> ::std::result::Result<
+                    PublishLayerVersionResponse,
+                    RusotoError<PublishLayerVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    publish_version: Option<
+        Box<
+            dyn Fn(
+                    PublishVersionRequest,
+                )
+                    -> ::std::result::Result<FunctionConfiguration, RusotoError<PublishVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_function_concurrency: Option<
+        Box<
+            dyn Fn(
+                    PutFunctionConcurrencyRequest,
+                )
+                    -> ::std::result::Result<Concurrency, RusotoError<PutFunctionConcurrencyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_layer_version_permission: Option<
+        Box<
+            dyn Fn(
+                    RemoveLayerVersionPermissionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveLayerVersionPermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_permission: Option<
+        Box<
+            dyn Fn(
+                    RemovePermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_alias: Option<
+        Box<
+            dyn Fn(
+                    UpdateAliasRequest,
+                )
+                    -> ::std::result::Result<AliasConfiguration, RusotoError<UpdateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_event_source_mapping: Option<
+        Box<
+            dyn Fn(
+                    UpdateEventSourceMappingRequest,
+                ) -> ::std::result::Result<
+                    EventSourceMappingConfiguration,
+                    RusotoError<UpdateEventSourceMappingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_function_code: Option<
+        Box<
+            dyn Fn(
+                    UpdateFunctionCodeRequest,
+                ) -> ::std::result::Result<
+                    FunctionConfiguration,
+                    RusotoError<UpdateFunctionCodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_function_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateFunctionConfigurationRequest,
+                ) -> ::std::result::Result<
+                    FunctionConfiguration,
+                    RusotoError<UpdateFunctionConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockLambda {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockLambda {
+        Default::default()
+    }
+
+    /// Answers `add_layer_version_permission` with what `handler` returns for its input.
+    pub fn with_add_layer_version_permission<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                AddLayerVersionPermissionRequest,
+            ) -> ::std::result::Result<
+                AddLayerVersionPermissionResponse,
+                RusotoError<AddLayerVersionPermissionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_layer_version_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_permission` with what `handler` returns for its input.
+    pub fn with_add_permission<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                AddPermissionRequest,
+            )
+                -> ::std::result::Result<AddPermissionResponse, RusotoError<AddPermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_alias` with what `handler` returns for its input.
+    pub fn with_create_alias<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                CreateAliasRequest,
+            )
+                -> ::std::result::Result<AliasConfiguration, RusotoError<CreateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_event_source_mapping` with what `handler` returns for its input.
+    pub fn with_create_event_source_mapping<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                CreateEventSourceMappingRequest,
+            ) -> ::std::result::Result<
+                EventSourceMappingConfiguration,
+                RusotoError<CreateEventSourceMappingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_event_source_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_function` with what `handler` returns for its input.
+    pub fn with_create_function<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                CreateFunctionRequest,
+            )
+                -> ::std::result::Result<FunctionConfiguration, RusotoError<CreateFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_alias` with what `handler` returns for its input.
+    pub fn with_delete_alias<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(DeleteAliasRequest) -> ::std::result::Result<(), RusotoError<DeleteAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_event_source_mapping` with what `handler` returns for its input.
+    pub fn with_delete_event_source_mapping<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                DeleteEventSourceMappingRequest,
+            ) -> ::std::result::Result<
+                EventSourceMappingConfiguration,
+                RusotoError<DeleteEventSourceMappingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_event_source_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_function` with what `handler` returns for its input.
+    pub fn with_delete_function<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(DeleteFunctionRequest) -> ::std::result::Result<(), RusotoError<DeleteFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_function_concurrency` with what `handler` returns for its input.
+    pub fn with_delete_function_concurrency<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                DeleteFunctionConcurrencyRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteFunctionConcurrencyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_function_concurrency = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_layer_version` with what `handler` returns for its input.
+    pub fn with_delete_layer_version<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                DeleteLayerVersionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteLayerVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_layer_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account_settings` with what `handler` returns.
+    pub fn with_get_account_settings<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn() -> ::std::result::Result<
+                GetAccountSettingsResponse,
+                RusotoError<GetAccountSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_alias` with what `handler` returns for its input.
+    pub fn with_get_alias<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetAliasRequest,
+            ) -> ::std::result::Result<AliasConfiguration, RusotoError<GetAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_event_source_mapping` with what `handler` returns for its input.
+    pub fn with_get_event_source_mapping<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetEventSourceMappingRequest,
+            ) -> ::std::result::Result<
+                EventSourceMappingConfiguration,
+                RusotoError<GetEventSourceMappingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_event_source_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_function` with what `handler` returns for its input.
+    pub fn with_get_function<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetFunctionRequest,
+            )
+                -> ::std::result::Result<GetFunctionResponse, RusotoError<GetFunctionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_function_configuration` with what `handler` returns for its input.
+    pub fn with_get_function_configuration<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetFunctionConfigurationRequest,
+            ) -> ::std::result::Result<
+                FunctionConfiguration,
+                RusotoError<GetFunctionConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_function_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_layer_version` with what `handler` returns for its input.
+    pub fn with_get_layer_version<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetLayerVersionRequest,
+            )
+                -> ::std::result::Result<GetLayerVersionResponse, RusotoError<GetLayerVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_layer_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_layer_version_by_arn` with what `handler` returns for its input.
+    pub fn with_get_layer_version_by_arn<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetLayerVersionByArnRequest,
+            ) -> ::std::result::Result<
+                GetLayerVersionResponse,
+                RusotoError<GetLayerVersionByArnError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_layer_version_by_arn = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_layer_version_policy` with what `handler` returns for its input.
+    pub fn with_get_layer_version_policy<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetLayerVersionPolicyRequest,
+            ) -> ::std::result::Result<
+                GetLayerVersionPolicyResponse,
+                RusotoError<GetLayerVersionPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_layer_version_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_policy` with what `handler` returns for its input.
+    pub fn with_get_policy<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                GetPolicyRequest,
+            ) -> ::std::result::Result<GetPolicyResponse, RusotoError<GetPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invoke` with what `handler` returns for its input.
+    pub fn with_invoke<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                InvocationRequest,
+            ) -> ::std::result::Result<InvocationResponse, RusotoError<InvokeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.invoke = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invoke_async` with what `handler` returns for its input.
+    pub fn with_invoke_async<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                InvokeAsyncRequest,
+            )
+                -> ::std::result::Result<InvokeAsyncResponse, RusotoError<InvokeAsyncError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.invoke_async = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_aliases` with what `handler` returns for its input.
+    pub fn with_list_aliases<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListAliasesRequest,
+            )
+                -> ::std::result::Result<ListAliasesResponse, RusotoError<ListAliasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_aliases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_event_source_mappings` with what `handler` returns for its input.
+    pub fn with_list_event_source_mappings<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListEventSourceMappingsRequest,
+            ) -> ::std::result::Result<
+                ListEventSourceMappingsResponse,
+                RusotoError<ListEventSourceMappingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_event_source_mappings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_functions` with what `handler` returns for its input.
+    pub fn with_list_functions<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListFunctionsRequest,
+            )
+                -> ::std::result::Result<ListFunctionsResponse, RusotoError<ListFunctionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_functions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_layer_versions` with what `handler` returns for its input.
+    pub fn with_list_layer_versions<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListLayerVersionsRequest,
+            ) -> ::std::result::Result<
+                ListLayerVersionsResponse,
+                RusotoError<ListLayerVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_layer_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_layers` with what `handler` returns for its input.
+    pub fn with_list_layers<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListLayersRequest,
+            )
+                -> ::std::result::Result<ListLayersResponse, RusotoError<ListLayersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_layers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_versions_by_function` with what `handler` returns for its input.
+    pub fn with_list_versions_by_function<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                ListVersionsByFunctionRequest,
+            ) -> ::std::result::Result<
+                ListVersionsByFunctionResponse,
+                RusotoError<ListVersionsByFunctionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_versions_by_function = Some(Box::new(handler));
+        self
+    }
+    /// Answers `publish_layer_version` with what `handler` returns for its input.
+    pub fn with_publish_layer_version<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                PublishLayerVersionRequest,
+            ) -> ::std::result::Result<
+                PublishLayerVersionResponse,
+                RusotoError<PublishLayerVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.publish_layer_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `publish_version` with what `handler` returns for its input.
+    pub fn with_publish_version<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                PublishVersionRequest,
+            )
+                -> ::std::result::Result<FunctionConfiguration, RusotoError<PublishVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.publish_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_function_concurrency` with what `handler` returns for its input.
+    pub fn with_put_function_concurrency<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                PutFunctionConcurrencyRequest,
+            )
+                -> ::std::result::Result<Concurrency, RusotoError<PutFunctionConcurrencyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_function_concurrency = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_layer_version_permission` with what `handler` returns for its input.
+    pub fn with_remove_layer_version_permission<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                RemoveLayerVersionPermissionRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<RemoveLayerVersionPermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_layer_version_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_permission` with what `handler` returns for its input.
+    pub fn with_remove_permission<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                RemovePermissionRequest,
+            ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_alias` with what `handler` returns for its input.
+    pub fn with_update_alias<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                UpdateAliasRequest,
+            )
+                -> ::std::result::Result<AliasConfiguration, RusotoError<UpdateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_event_source_mapping` with what `handler` returns for its input.
+    pub fn with_update_event_source_mapping<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                UpdateEventSourceMappingRequest,
+            ) -> ::std::result::Result<
+                EventSourceMappingConfiguration,
+                RusotoError<UpdateEventSourceMappingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_event_source_mapping = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_function_code` with what `handler` returns for its input.
+    pub fn with_update_function_code<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                UpdateFunctionCodeRequest,
+            )
+                -> ::std::result::Result<FunctionConfiguration, RusotoError<UpdateFunctionCodeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_function_code = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_function_configuration` with what `handler` returns for its input.
+    pub fn with_update_function_configuration<F>(mut self, handler: F) -> MockLambda
+    where
+        F: Fn(
+                UpdateFunctionConfigurationRequest,
+            ) -> ::std::result::Result<
+                FunctionConfiguration,
+                RusotoError<UpdateFunctionConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_function_configuration = Some(Box::new(handler));
+        self
+    }
+}
 
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutFunctionConcurrencyError::from_response(response))
-                    }),
-                )
-            }
-        })
+#[cfg(any(test, feature = "mocks"))]
+impl Lambda for MockLambda {
+    fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> RusotoFuture<AddLayerVersionPermissionResponse, AddLayerVersionPermissionError> {
+        match self.add_layer_version_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for add_layer_version_permission"),
+        }
+    }
+    fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> RusotoFuture<AddPermissionResponse, AddPermissionError> {
+        match self.add_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for add_permission"),
+        }
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, CreateAliasError> {
+        match self.create_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for create_alias"),
+        }
+    }
+    fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, CreateEventSourceMappingError> {
+        match self.create_event_source_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for create_event_source_mapping"),
+        }
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, CreateFunctionError> {
+        match self.create_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for create_function"),
+        }
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        match self.delete_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for delete_alias"),
+        }
+    }
+    fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, DeleteEventSourceMappingError> {
+        match self.delete_event_source_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for delete_event_source_mapping"),
+        }
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<(), DeleteFunctionError> {
+        match self.delete_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for delete_function"),
+        }
+    }
+    fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> RusotoFuture<(), DeleteFunctionConcurrencyError> {
+        match self.delete_function_concurrency {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for delete_function_concurrency"),
+        }
+    }
+    fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> RusotoFuture<(), DeleteLayerVersionError> {
+        match self.delete_layer_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for delete_layer_version"),
+        }
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        match self.get_account_settings {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockLambda has no handler for get_account_settings"),
+        }
+    }
+    fn get_alias(&self, input: GetAliasRequest) -> RusotoFuture<AliasConfiguration, GetAliasError> {
+        match self.get_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_alias"),
+        }
+    }
+    fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, GetEventSourceMappingError> {
+        match self.get_event_source_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_event_source_mapping"),
+        }
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        match self.get_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_function"),
+        }
+    }
+    fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, GetFunctionConfigurationError> {
+        match self.get_function_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_function_configuration"),
+        }
+    }
+    fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionError> {
+        match self.get_layer_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_layer_version"),
+        }
+    }
+    fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionByArnError> {
+        match self.get_layer_version_by_arn {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_layer_version_by_arn"),
+        }
+    }
+    fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> RusotoFuture<GetLayerVersionPolicyResponse, GetLayerVersionPolicyError> {
+        match self.get_layer_version_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_layer_version_policy"),
+        }
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        match self.get_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for get_policy"),
+        }
+    }
+    fn invoke(&self, input: InvocationRequest) -> RusotoFuture<InvocationResponse, InvokeError> {
+        match self.invoke {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for invoke"),
+        }
+    }
+    fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> RusotoFuture<InvokeAsyncResponse, InvokeAsyncError> {
+        match self.invoke_async {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for invoke_async"),
+        }
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        match self.list_aliases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_aliases"),
+        }
+    }
+    fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> RusotoFuture<ListEventSourceMappingsResponse, ListEventSourceMappingsError> {
+        match self.list_event_source_mappings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_event_source_mappings"),
+        }
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        match self.list_functions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_functions"),
+        }
+    }
+    fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> RusotoFuture<ListLayerVersionsResponse, ListLayerVersionsError> {
+        match self.list_layer_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_layer_versions"),
+        }
+    }
+    fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> RusotoFuture<ListLayersResponse, ListLayersError> {
+        match self.list_layers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_layers"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_tags"),
+        }
+    }
+    fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> RusotoFuture<ListVersionsByFunctionResponse, ListVersionsByFunctionError> {
+        match self.list_versions_by_function {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for list_versions_by_function"),
+        }
+    }
+    fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> RusotoFuture<PublishLayerVersionResponse, PublishLayerVersionError> {
+        match self.publish_layer_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for publish_layer_version"),
+        }
+    }
+    fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, PublishVersionError> {
+        match self.publish_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for publish_version"),
+        }
+    }
+    fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> RusotoFuture<Concurrency, PutFunctionConcurrencyError> {
+        match self.put_function_concurrency {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for put_function_concurrency"),
+        }
     }
-
-    /// <p>Removes a statement from the permissions policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
     fn remove_layer_version_permission(
         &self,
         input: RemoveLayerVersionPermissionRequest,
     ) -> RusotoFuture<(), RemoveLayerVersionPermissionError> {
-        let request_uri = format!(
-            "/2018-10-31/layers/{layer_name}/versions/{version_number}/policy/{statement_id}",
-            layer_name = input.layer_name,
-            statement_id = input.statement_id,
-            version_number = input.version_number
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.revision_id {
-            params.put("RevisionId", x);
+        match self.remove_layer_version_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for remove_layer_version_permission"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveLayerVersionPermissionError::from_response(response))
-                }))
-            }
-        })
     }
-
-    /// <p>Revokes function-use permission from an AWS service or another account. You can get the ID of the statement from the output of <a>GetPolicy</a>.</p>
     fn remove_permission(
         &self,
         input: RemovePermissionRequest,
     ) -> RusotoFuture<(), RemovePermissionError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/policy/{statement_id}",
-            function_name = input.function_name,
-            statement_id = input.statement_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.qualifier {
-            params.put("Qualifier", x);
-        }
-        if let Some(ref x) = input.revision_id {
-            params.put("RevisionId", x);
+        match self.remove_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for remove_permission"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemovePermissionError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Adds <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to a function.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
-
-        let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> from a function.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
-
-        let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for untag_resource"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Updates the configuration of a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
     fn update_alias(
         &self,
         input: UpdateAliasRequest,
     ) -> RusotoFuture<AliasConfiguration, UpdateAliasError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/aliases/{name}",
-            function_name = input.function_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AliasConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAliasError::from_response(response))),
-                )
-            }
-        })
+        match self.update_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for update_alias"),
+        }
     }
-
-    /// <p>Updates an event source mapping. You can change the function that AWS Lambda invokes, or pause invocation and resume later from the same location.</p>
     fn update_event_source_mapping(
         &self,
         input: UpdateEventSourceMappingRequest,
     ) -> RusotoFuture<EventSourceMappingConfiguration, UpdateEventSourceMappingError> {
-        let request_uri = format!(
-            "/2015-03-31/event-source-mappings/{uuid}",
-            uuid = input.uuid
-        );
-
-        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EventSourceMappingConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateEventSourceMappingError::from_response(response))
-                }))
-            }
-        })
+        match self.update_event_source_mapping {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for update_event_source_mapping"),
+        }
     }
-
-    /// <p>Updates a Lambda function's code.</p> <p>The function's code is locked when you publish a version. You can't modify the code of a published version, only the unpublished version.</p>
     fn update_function_code(
         &self,
         input: UpdateFunctionCodeRequest,
     ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionCodeError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/code",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<FunctionConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFunctionCodeError::from_response(response))),
-                )
-            }
-        })
+        match self.update_function_code {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for update_function_code"),
+        }
     }
-
-    /// <p>Modify the version-specific settings of a Lambda function.</p> <p>These settings can vary between versions of a function and are locked when you publish a version. You can't modify the configuration of a published version, only the unpublished version.</p> <p>To configure function concurrency, use <a>PutFunctionConcurrency</a>. To grant invoke permissions to an account or AWS service, use <a>AddPermission</a>.</p>
     fn update_function_configuration(
         &self,
         input: UpdateFunctionConfigurationRequest,
     ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError> {
-        let request_uri = format!(
-            "/2015-03-31/functions/{function_name}/configuration",
-            function_name = input.function_name
-        );
-
-        let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<FunctionConfiguration, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateFunctionConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.update_function_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLambda has no handler for update_function_configuration"),
+        }
     }
 }
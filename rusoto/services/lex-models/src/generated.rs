@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Provides information about a bot alias.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BotAliasMetadata {
     /// <p>The name of the bot to which the alias points.</p>
     #[serde(rename = "botName")]
@@ -60,7 +61,7 @@ pub struct BotAliasMetadata {
 
 /// <p>Represents an association between an Amazon Lex bot and an external messaging platform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BotChannelAssociation {
     /// <p>An alias pointing to the specific version of the Amazon Lex bot to which this association is being made. </p>
     #[serde(rename = "botAlias")]
@@ -102,7 +103,7 @@ pub struct BotChannelAssociation {
 
 /// <p>Provides information about a bot. .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BotMetadata {
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
@@ -132,7 +133,7 @@ pub struct BotMetadata {
 
 /// <p>Provides metadata for a built-in intent.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BuiltinIntentMetadata {
     /// <p>A unique identifier for the built-in intent. To find the signature for an intent, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/standard-intents">Standard Built-in Intents</a> in the <i>Alexa Skills Kit</i>.</p>
     #[serde(rename = "signature")]
@@ -146,7 +147,7 @@ pub struct BuiltinIntentMetadata {
 
 /// <p>Provides information about a slot used in a built-in intent.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BuiltinIntentSlot {
     /// <p>A list of the slots defined for the intent.</p>
     #[serde(rename = "name")]
@@ -156,7 +157,7 @@ pub struct BuiltinIntentSlot {
 
 /// <p>Provides information about a built in slot type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BuiltinSlotTypeMetadata {
     /// <p>A unique identifier for the built-in slot type. To find the signature for a slot type, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/slot-type-reference">Slot Type Reference</a> in the <i>Alexa Skills Kit</i>.</p>
     #[serde(rename = "signature")]
@@ -179,7 +180,26 @@ pub struct CodeHook {
     pub uri: String,
 }
 
+impl Validate for CodeHook {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "message_version"),
+            self.message_version.chars().count(),
+            Some(1),
+            Some(5),
+        );
+        errors.check_length(
+            &field_path(path, "uri"),
+            self.uri.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "uri"), &self.uri, "arn:aws[a-zA-Z-]*:lambda:[a-z]+-[a-z]+(-[a-z]+)*-[0-9]:[0-9]{12}:function:[a-zA-Z0-9-_]+(\\/[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})?(:[a-zA-Z0-9-_]+)?");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBotVersionRequest {
     /// <p>Identifies a specific revision of the <code>$LATEST</code> version of the bot. If you specify a checksum and the <code>$LATEST</code> version of the bot has a different checksum, a <code>PreconditionFailedException</code> exception is returned and Amazon Lex doesn't publish a new version. If you don't specify a checksum, Amazon Lex publishes the <code>$LATEST</code> version.</p>
     #[serde(rename = "checksum")]
@@ -190,8 +210,20 @@ pub struct CreateBotVersionRequest {
     pub name: String,
 }
 
+impl Validate for CreateBotVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBotVersionResponse {
     /// <p>The message that Amazon Lex uses to abort a conversation. For more information, see <a>PutBot</a>.</p>
     #[serde(rename = "abortStatement")]
@@ -256,6 +288,7 @@ pub struct CreateBotVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIntentVersionRequest {
     /// <p>Checksum of the <code>$LATEST</code> version of the intent that should be used to create the new version. If you specify a checksum and the <code>$LATEST</code> version of the intent has a different checksum, Amazon Lex returns a <code>PreconditionFailedException</code> exception and doesn't publish a new version. If you don't specify a checksum, Amazon Lex publishes the <code>$LATEST</code> version.</p>
     #[serde(rename = "checksum")]
@@ -266,8 +299,20 @@ pub struct CreateIntentVersionRequest {
     pub name: String,
 }
 
+impl Validate for CreateIntentVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIntentVersionResponse {
     /// <p>Checksum of the intent version created.</p>
     #[serde(rename = "checksum")]
@@ -332,6 +377,7 @@ pub struct CreateIntentVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSlotTypeVersionRequest {
     /// <p>Checksum for the <code>$LATEST</code> version of the slot type that you want to publish. If you specify a checksum and the <code>$LATEST</code> version of the slot type has a different checksum, Amazon Lex returns a <code>PreconditionFailedException</code> exception and doesn't publish the new version. If you don't specify a checksum, Amazon Lex publishes the <code>$LATEST</code> version.</p>
     #[serde(rename = "checksum")]
@@ -342,8 +388,20 @@ pub struct CreateSlotTypeVersionRequest {
     pub name: String,
 }
 
+impl Validate for CreateSlotTypeVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSlotTypeVersionResponse {
     /// <p>Checksum of the <code>$LATEST</code> version of the slot type.</p>
     #[serde(rename = "checksum")]
@@ -380,6 +438,7 @@ pub struct CreateSlotTypeVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBotAliasRequest {
     /// <p>The name of the bot that the alias points to.</p>
     #[serde(rename = "botName")]
@@ -389,7 +448,31 @@ pub struct DeleteBotAliasRequest {
     pub name: String,
 }
 
+impl Validate for DeleteBotAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBotChannelAssociationRequest {
     /// <p>An alias that points to the specific version of the Amazon Lex bot to which this association is being made.</p>
     #[serde(rename = "botAlias")]
@@ -402,14 +485,62 @@ pub struct DeleteBotChannelAssociationRequest {
     pub name: String,
 }
 
+impl Validate for DeleteBotChannelAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_alias"),
+            self.bot_alias.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_alias"),
+            &self.bot_alias,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBotRequest {
     /// <p>The name of the bot. The name is case sensitive. </p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeleteBotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBotVersionRequest {
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
@@ -419,14 +550,47 @@ pub struct DeleteBotVersionRequest {
     pub version: String,
 }
 
+impl Validate for DeleteBotVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "version"), &self.version, "[0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIntentRequest {
     /// <p>The name of the intent. The name is case sensitive. </p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeleteIntentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIntentVersionRequest {
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
@@ -436,14 +600,47 @@ pub struct DeleteIntentVersionRequest {
     pub version: String,
 }
 
+impl Validate for DeleteIntentVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "version"), &self.version, "[0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSlotTypeRequest {
     /// <p>The name of the slot type. The name is case sensitive. </p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeleteSlotTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSlotTypeVersionRequest {
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
@@ -453,7 +650,27 @@ pub struct DeleteSlotTypeVersionRequest {
     pub version: String,
 }
 
+impl Validate for DeleteSlotTypeVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "version"), &self.version, "[0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUtterancesRequest {
     /// <p>The name of the bot that stored the utterances.</p>
     #[serde(rename = "botName")]
@@ -463,6 +680,28 @@ pub struct DeleteUtterancesRequest {
     pub user_id: String,
 }
 
+impl Validate for DeleteUtterancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(100),
+        );
+    }
+}
+
 /// <p><p>Each slot type can have a set of values. Each enumeration value represents a value the slot type can take. </p> <p>For example, a pizza ordering bot could have a slot type that specifies the type of crust that the pizza should have. The slot type could include the values </p> <ul> <li> <p>thick</p> </li> <li> <p>thin</p> </li> <li> <p>stuffed</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnumerationValue {
@@ -475,6 +714,23 @@ pub struct EnumerationValue {
     pub value: String,
 }
 
+impl Validate for EnumerationValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.synonyms {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "synonyms"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(140));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(140),
+        );
+    }
+}
+
 /// <p>A prompt for additional activity after an intent is fulfilled. For example, after the <code>OrderPizza</code> intent is fulfilled, you might prompt the user to find out whether the user wants to order drinks.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FollowUpPrompt {
@@ -486,6 +742,14 @@ pub struct FollowUpPrompt {
     pub rejection_statement: Statement,
 }
 
+impl Validate for FollowUpPrompt {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.prompt.validate_at(&field_path(path, "prompt"), errors);
+        self.rejection_statement
+            .validate_at(&field_path(path, "rejection_statement"), errors);
+    }
+}
+
 /// <p><p> Describes how the intent is fulfilled after the user provides all of the information required for the intent. You can provide a Lambda function to process the intent, or you can return the intent information to the client application. We recommend that you use a Lambda function so that the relevant logic lives in the Cloud and limit the client-side code primarily to presentation. If you need to update the logic, you only update the Lambda function; you don&#39;t need to upgrade your client application. </p> <p>Consider the following examples:</p> <ul> <li> <p>In a pizza ordering application, after the user provides all of the information for placing an order, you use a Lambda function to place an order with a pizzeria. </p> </li> <li> <p>In a gaming application, when a user says &quot;pick up a rock,&quot; this information must go back to the client application so that it can perform the operation and update the graphics. In this case, you want Amazon Lex to return the intent data to the client. </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FulfillmentActivity {
@@ -498,7 +762,16 @@ pub struct FulfillmentActivity {
     pub type_: String,
 }
 
+impl Validate for FulfillmentActivity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.code_hook {
+            value.validate_at(&field_path(path, "code_hook"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotAliasRequest {
     /// <p>The name of the bot.</p>
     #[serde(rename = "botName")]
@@ -508,8 +781,31 @@ pub struct GetBotAliasRequest {
     pub name: String,
 }
 
+impl Validate for GetBotAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotAliasResponse {
     /// <p>The name of the bot that the alias points to.</p>
     #[serde(rename = "botName")]
@@ -542,6 +838,7 @@ pub struct GetBotAliasResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotAliasesRequest {
     /// <p>The name of the bot.</p>
     #[serde(rename = "botName")]
@@ -560,8 +857,40 @@ pub struct GetBotAliasesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetBotAliasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^([A-Za-z]_?)+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotAliasesResponse {
     /// <p>An array of <code>BotAliasMetadata</code> objects, each describing a bot alias.</p>
     #[serde(rename = "BotAliases")]
@@ -574,6 +903,7 @@ pub struct GetBotAliasesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotChannelAssociationRequest {
     /// <p>An alias pointing to the specific version of the Amazon Lex bot to which this association is being made.</p>
     #[serde(rename = "botAlias")]
@@ -586,8 +916,42 @@ pub struct GetBotChannelAssociationRequest {
     pub name: String,
 }
 
+impl Validate for GetBotChannelAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_alias"),
+            self.bot_alias.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_alias"),
+            &self.bot_alias,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotChannelAssociationResponse {
     /// <p>An alias pointing to the specific version of the Amazon Lex bot to which this association is being made.</p>
     #[serde(rename = "botAlias")]
@@ -628,6 +992,7 @@ pub struct GetBotChannelAssociationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotChannelAssociationsRequest {
     /// <p>An alias pointing to the specific version of the Amazon Lex bot to which this association is being made.</p>
     #[serde(rename = "botAlias")]
@@ -649,8 +1014,51 @@ pub struct GetBotChannelAssociationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetBotChannelAssociationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_alias"),
+            self.bot_alias.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_alias"),
+            &self.bot_alias,
+            "^(-|^([A-Za-z]_?)+$)$",
+        );
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^([A-Za-z]_?)+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotChannelAssociationsResponse {
     /// <p>An array of objects, one for each association, that provides information about the Amazon Lex bot and its association with the channel. </p>
     #[serde(rename = "botChannelAssociations")]
@@ -663,6 +1071,7 @@ pub struct GetBotChannelAssociationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotRequest {
     /// <p>The name of the bot. The name is case sensitive. </p>
     #[serde(rename = "name")]
@@ -672,8 +1081,20 @@ pub struct GetBotRequest {
     pub version_or_alias: String,
 }
 
+impl Validate for GetBotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotResponse {
     /// <p>The message that Amazon Lex returns when the user elects to end the conversation without completing it. For more information, see <a>PutBot</a>.</p>
     #[serde(rename = "abortStatement")]
@@ -738,6 +1159,7 @@ pub struct GetBotResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotVersionsRequest {
     /// <p>The maximum number of bot versions to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
@@ -752,8 +1174,23 @@ pub struct GetBotVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetBotVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotVersionsResponse {
     /// <p>An array of <code>BotMetadata</code> objects, one for each numbered version of the bot plus one for the <code>$LATEST</code> version.</p>
     #[serde(rename = "bots")]
@@ -766,6 +1203,7 @@ pub struct GetBotVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBotsRequest {
     /// <p>The maximum number of bots to return in the response that the request will return. The default is 10.</p>
     #[serde(rename = "maxResults")]
@@ -781,8 +1219,29 @@ pub struct GetBotsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetBotsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(2),
+                Some(50),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^([A-Za-z]_?)+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBotsResponse {
     /// <p>An array of <code>botMetadata</code> objects, with one entry for each bot. </p>
     #[serde(rename = "bots")]
@@ -795,6 +1254,7 @@ pub struct GetBotsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBuiltinIntentRequest {
     /// <p>The unique identifier for a built-in intent. To find the signature for an intent, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/standard-intents">Standard Built-in Intents</a> in the <i>Alexa Skills Kit</i>.</p>
     #[serde(rename = "signature")]
@@ -802,7 +1262,7 @@ pub struct GetBuiltinIntentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBuiltinIntentResponse {
     /// <p>The unique identifier for a built-in intent.</p>
     #[serde(rename = "signature")]
@@ -819,6 +1279,7 @@ pub struct GetBuiltinIntentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBuiltinIntentsRequest {
     /// <p>A list of locales that the intent supports.</p>
     #[serde(rename = "locale")]
@@ -838,8 +1299,16 @@ pub struct GetBuiltinIntentsRequest {
     pub signature_contains: Option<String>,
 }
 
+impl Validate for GetBuiltinIntentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBuiltinIntentsResponse {
     /// <p>An array of <code>builtinIntentMetadata</code> objects, one for each intent in the response.</p>
     #[serde(rename = "intents")]
@@ -852,6 +1321,7 @@ pub struct GetBuiltinIntentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBuiltinSlotTypesRequest {
     /// <p>A list of locales that the slot type supports.</p>
     #[serde(rename = "locale")]
@@ -871,8 +1341,16 @@ pub struct GetBuiltinSlotTypesRequest {
     pub signature_contains: Option<String>,
 }
 
+impl Validate for GetBuiltinSlotTypesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBuiltinSlotTypesResponse {
     /// <p>If the response is truncated, the response includes a pagination token that you can use in your next request to fetch the next page of slot types.</p>
     #[serde(rename = "nextToken")]
@@ -885,6 +1363,7 @@ pub struct GetBuiltinSlotTypesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetExportRequest {
     /// <p>The format of the exported data.</p>
     #[serde(rename = "exportType")]
@@ -900,8 +1379,27 @@ pub struct GetExportRequest {
     pub version: String,
 }
 
+impl Validate for GetExportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z_]+");
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "version"), &self.version, "[0-9]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetExportResponse {
     /// <p><p>The status of the export. </p> <ul> <li> <p> <code>IN_PROGRESS</code> - The export is in progress.</p> </li> <li> <p> <code>READY</code> - The export is complete.</p> </li> <li> <p> <code>FAILED</code> - The export could not be completed.</p> </li> </ul></p>
     #[serde(rename = "exportStatus")]
@@ -934,6 +1432,7 @@ pub struct GetExportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetImportRequest {
     /// <p>The identifier of the import job information to return.</p>
     #[serde(rename = "importId")]
@@ -941,7 +1440,7 @@ pub struct GetImportRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetImportResponse {
     /// <p>A timestamp for the date and time that the import job was created.</p>
     #[serde(rename = "createdDate")]
@@ -974,6 +1473,7 @@ pub struct GetImportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntentRequest {
     /// <p>The name of the intent. The name is case sensitive. </p>
     #[serde(rename = "name")]
@@ -983,8 +1483,31 @@ pub struct GetIntentRequest {
     pub version: String,
 }
 
+impl Validate for GetIntentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "version"),
+            &self.version,
+            "\\$LATEST|[0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntentResponse {
     /// <p>Checksum of the intent.</p>
     #[serde(rename = "checksum")]
@@ -1049,6 +1572,7 @@ pub struct GetIntentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntentVersionsRequest {
     /// <p>The maximum number of intent versions to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
@@ -1063,8 +1587,23 @@ pub struct GetIntentVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetIntentVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntentVersionsResponse {
     /// <p>An array of <code>IntentMetadata</code> objects, one for each numbered version of the intent plus one for the <code>$LATEST</code> version.</p>
     #[serde(rename = "intents")]
@@ -1077,6 +1616,7 @@ pub struct GetIntentVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIntentsRequest {
     /// <p>The maximum number of intents to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
@@ -1092,8 +1632,29 @@ pub struct GetIntentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetIntentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^([A-Za-z]_?)+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIntentsResponse {
     /// <p>An array of <code>Intent</code> objects. For more information, see <a>PutBot</a>.</p>
     #[serde(rename = "intents")]
@@ -1106,6 +1667,7 @@ pub struct GetIntentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSlotTypeRequest {
     /// <p>The name of the slot type. The name is case sensitive. </p>
     #[serde(rename = "name")]
@@ -1115,8 +1677,31 @@ pub struct GetSlotTypeRequest {
     pub version: String,
 }
 
+impl Validate for GetSlotTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "version"),
+            &self.version,
+            "\\$LATEST|[0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSlotTypeResponse {
     /// <p>Checksum of the <code>$LATEST</code> version of the slot type.</p>
     #[serde(rename = "checksum")]
@@ -1153,6 +1738,7 @@ pub struct GetSlotTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSlotTypeVersionsRequest {
     /// <p>The maximum number of slot type versions to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
@@ -1167,8 +1753,23 @@ pub struct GetSlotTypeVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetSlotTypeVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSlotTypeVersionsResponse {
     /// <p>A pagination token for fetching the next page of slot type versions. If the response to this call is truncated, Amazon Lex returns a pagination token in the response. To fetch the next page of versions, specify the pagination token in the next request. </p>
     #[serde(rename = "nextToken")]
@@ -1181,6 +1782,7 @@ pub struct GetSlotTypeVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSlotTypesRequest {
     /// <p>The maximum number of slot types to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
@@ -1196,8 +1798,29 @@ pub struct GetSlotTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetSlotTypesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^([A-Za-z]_?)+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSlotTypesResponse {
     /// <p>If the response is truncated, it includes a pagination token that you can specify in your next request to fetch the next page of slot types.</p>
     #[serde(rename = "nextToken")]
@@ -1210,6 +1833,7 @@ pub struct GetSlotTypesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetUtterancesViewRequest {
     /// <p>The name of the bot for which utterance information should be returned.</p>
     #[serde(rename = "botName")]
@@ -1222,8 +1846,35 @@ pub struct GetUtterancesViewRequest {
     pub status_type: String,
 }
 
+impl Validate for GetUtterancesViewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "bot_versions"),
+            self.bot_versions.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.bot_versions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "bot_versions"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            errors.check_pattern(&item_path, &item, "\\$LATEST|[0-9]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetUtterancesViewResponse {
     /// <p>The name of the bot for which utterance information was returned.</p>
     #[serde(rename = "botName")]
@@ -1246,9 +1897,36 @@ pub struct Intent {
     pub intent_version: String,
 }
 
+impl Validate for Intent {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "intent_name"),
+            self.intent_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "intent_name"),
+            &self.intent_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "intent_version"),
+            self.intent_version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "intent_version"),
+            &self.intent_version,
+            "\\$LATEST|[0-9]+",
+        );
+    }
+}
+
 /// <p>Provides information about an intent.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IntentMetadata {
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
@@ -1287,6 +1965,20 @@ pub struct Message {
     pub group_number: Option<i64>,
 }
 
+impl Validate for Message {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "content"),
+            self.content.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+        if let Some(ref value) = self.group_number {
+            errors.check_range(&field_path(path, "group_number"), *value, Some(1), Some(5));
+        }
+    }
+}
+
 /// <p>Obtains information from the user. To define a prompt, provide one or more messages and specify the number of attempts to get information from the user. If you provide more than one message, Amazon Lex chooses one of the messages to use to prompt the user. For more information, see <a>how-it-works</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Prompt {
@@ -1302,7 +1994,37 @@ pub struct Prompt {
     pub response_card: Option<String>,
 }
 
+impl Validate for Prompt {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "max_attempts"),
+            self.max_attempts,
+            Some(1),
+            Some(5),
+        );
+        errors.check_length(
+            &field_path(path, "messages"),
+            self.messages.len(),
+            Some(1),
+            Some(15),
+        );
+        for (i, item) in self.messages.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "messages"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.response_card {
+            errors.check_length(
+                &field_path(path, "response_card"),
+                value.chars().count(),
+                Some(1),
+                Some(50000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBotAliasRequest {
     /// <p>The name of the bot.</p>
     #[serde(rename = "botName")]
@@ -1323,8 +2045,50 @@ pub struct PutBotAliasRequest {
     pub name: String,
 }
 
+impl Validate for PutBotAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bot_name"),
+            self.bot_name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_name"),
+            &self.bot_name,
+            "^([A-Za-z]_?)+$",
+        );
+        errors.check_length(
+            &field_path(path, "bot_version"),
+            self.bot_version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "bot_version"),
+            &self.bot_version,
+            "\\$LATEST|[0-9]+",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutBotAliasResponse {
     /// <p>The name of the bot that the alias points to.</p>
     #[serde(rename = "botName")]
@@ -1357,6 +2121,7 @@ pub struct PutBotAliasResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBotRequest {
     /// <p>When Amazon Lex can't understand the user's input in context, it tries to elicit the information a few times. After that, Amazon Lex sends the message defined in <code>abortStatement</code> to the user, and then aborts the conversation. To set the number of retries, use the <code>valueElicitationPrompt</code> field for the slot type. </p> <p>For example, in a pizza ordering bot, Amazon Lex might ask a user "What type of crust would you like?" If the user's response is not one of the expected responses (for example, "thin crust, "deep dish," etc.), Amazon Lex tries to elicit a correct response a few more times. </p> <p>For example, in a pizza ordering application, <code>OrderPizza</code> might be one of the intents. This intent might require the <code>CrustType</code> slot. You specify the <code>valueElicitationPrompt</code> field when you create the <code>CrustType</code> slot.</p>
     #[serde(rename = "abortStatement")]
@@ -1404,8 +2169,48 @@ pub struct PutBotRequest {
     pub voice_id: Option<String>,
 }
 
+impl Validate for PutBotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.abort_statement {
+            value.validate_at(&field_path(path, "abort_statement"), errors);
+        }
+        if let Some(ref value) = self.clarification_prompt {
+            value.validate_at(&field_path(path, "clarification_prompt"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.idle_session_ttl_in_seconds {
+            errors.check_range(
+                &field_path(path, "idle_session_ttl_in_seconds"),
+                *value,
+                Some(60),
+                Some(86400),
+            );
+        }
+        if let Some(ref value) = self.intents {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "intents"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutBotResponse {
     /// <p>The message that Amazon Lex uses to abort a conversation. For more information, see <a>PutBot</a>.</p>
     #[serde(rename = "abortStatement")]
@@ -1473,6 +2278,7 @@ pub struct PutBotResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutIntentRequest {
     /// <p>Identifies a specific revision of the <code>$LATEST</code> version.</p> <p>When you create a new intent, leave the <code>checksum</code> field blank. If you specify a checksum you get a <code>BadRequestException</code> exception.</p> <p>When you want to update a intent, set the <code>checksum</code> field to the checksum of the most recent revision of the <code>$LATEST</code> version. If you don't specify the <code> checksum</code> field, or if the checksum does not match the <code>$LATEST</code> version, you get a <code>PreconditionFailedException</code> exception.</p>
     #[serde(rename = "checksum")]
@@ -1526,8 +2332,65 @@ pub struct PutIntentRequest {
     pub slots: Option<Vec<Slot>>,
 }
 
+impl Validate for PutIntentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.conclusion_statement {
+            value.validate_at(&field_path(path, "conclusion_statement"), errors);
+        }
+        if let Some(ref value) = self.confirmation_prompt {
+            value.validate_at(&field_path(path, "confirmation_prompt"), errors);
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.dialog_code_hook {
+            value.validate_at(&field_path(path, "dialog_code_hook"), errors);
+        }
+        if let Some(ref value) = self.follow_up_prompt {
+            value.validate_at(&field_path(path, "follow_up_prompt"), errors);
+        }
+        if let Some(ref value) = self.fulfillment_activity {
+            value.validate_at(&field_path(path, "fulfillment_activity"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+        if let Some(ref value) = self.rejection_statement {
+            value.validate_at(&field_path(path, "rejection_statement"), errors);
+        }
+        if let Some(ref value) = self.sample_utterances {
+            errors.check_length(
+                &field_path(path, "sample_utterances"),
+                value.len(),
+                None,
+                Some(1500),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sample_utterances"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(200));
+            }
+        }
+        if let Some(ref value) = self.slots {
+            errors.check_length(&field_path(path, "slots"), value.len(), None, Some(100));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "slots"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutIntentResponse {
     /// <p>Checksum of the <code>$LATEST</code>version of the intent created or updated.</p>
     #[serde(rename = "checksum")]
@@ -1595,6 +2458,7 @@ pub struct PutIntentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutSlotTypeRequest {
     /// <p>Identifies a specific revision of the <code>$LATEST</code> version.</p> <p>When you create a new slot type, leave the <code>checksum</code> field blank. If you specify a checksum you get a <code>BadRequestException</code> exception.</p> <p>When you want to update a slot type, set the <code>checksum</code> field to the checksum of the most recent revision of the <code>$LATEST</code> version. If you don't specify the <code> checksum</code> field, or if the checksum does not match the <code>$LATEST</code> version, you get a <code>PreconditionFailedException</code> exception.</p>
     #[serde(rename = "checksum")]
@@ -1620,8 +2484,40 @@ pub struct PutSlotTypeRequest {
     pub value_selection_strategy: Option<String>,
 }
 
+impl Validate for PutSlotTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.enumeration_values {
+            errors.check_length(
+                &field_path(path, "enumeration_values"),
+                value.len(),
+                None,
+                Some(10000),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "enumeration_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutSlotTypeResponse {
     /// <p>Checksum of the <code>$LATEST</code> version of the slot type.</p>
     #[serde(rename = "checksum")]
@@ -1708,9 +2604,85 @@ pub struct Slot {
     pub value_elicitation_prompt: Option<Prompt>,
 }
 
+impl Validate for Slot {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^([A-Za-z](-|_|.)?)+$",
+        );
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.response_card {
+            errors.check_length(
+                &field_path(path, "response_card"),
+                value.chars().count(),
+                Some(1),
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.sample_utterances {
+            errors.check_length(
+                &field_path(path, "sample_utterances"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sample_utterances"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(200));
+            }
+        }
+        if let Some(ref value) = self.slot_type {
+            errors.check_length(
+                &field_path(path, "slot_type"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "slot_type"),
+                &value,
+                "^((AMAZON\\.)_?|[A-Za-z]_?)+",
+            );
+        }
+        if let Some(ref value) = self.slot_type_version {
+            errors.check_length(
+                &field_path(path, "slot_type_version"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "slot_type_version"),
+                &value,
+                "\\$LATEST|[0-9]+",
+            );
+        }
+        if let Some(ref value) = self.value_elicitation_prompt {
+            value.validate_at(&field_path(path, "value_elicitation_prompt"), errors);
+        }
+    }
+}
+
 /// <p>Provides information about a slot type..</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SlotTypeMetadata {
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
@@ -1735,6 +2707,7 @@ pub struct SlotTypeMetadata {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartImportRequest {
     /// <p><p>Specifies the action that the <code>StartImport</code> operation should take when there is an existing resource with the same name.</p> <ul> <li> <p>FAIL<em>ON</em>CONFLICT - The import operation is stopped on the first conflict between a resource in the import file and an existing resource. The name of the resource causing the conflict is in the <code>failureReason</code> field of the response to the <code>GetImport</code> operation.</p> <p>OVERWRITE_LATEST - The import operation proceeds even if there is a conflict with an existing resource. The $LASTEST version of the existing resource is overwritten with the data from the import file.</p> </li> </ul></p>
     #[serde(rename = "mergeStrategy")]
@@ -1753,7 +2726,7 @@ pub struct StartImportRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartImportResponse {
     /// <p>A timestamp for the date and time that the import job was requested.</p>
     #[serde(rename = "createdDate")]
@@ -1793,9 +2766,32 @@ pub struct Statement {
     pub response_card: Option<String>,
 }
 
+impl Validate for Statement {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "messages"),
+            self.messages.len(),
+            Some(1),
+            Some(15),
+        );
+        for (i, item) in self.messages.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "messages"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.response_card {
+            errors.check_length(
+                &field_path(path, "response_card"),
+                value.chars().count(),
+                Some(1),
+                Some(50000),
+            );
+        }
+    }
+}
+
 /// <p>Provides information about a single utterance that was made to your bot. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UtteranceData {
     /// <p>The number of times that the utterance was processed.</p>
     #[serde(rename = "count")]
@@ -1821,7 +2817,7 @@ pub struct UtteranceData {
 
 /// <p>Provides a list of utterances that have been made to a specific version of your bot. The list contains a maximum of 100 utterances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UtteranceList {
     /// <p>The version of the bot that processed the list.</p>
     #[serde(rename = "botVersion")]
@@ -1872,7 +2868,12 @@ impl CreateBotVersionError {
                 "PreconditionFailedException" => {
                     return RusotoError::Service(CreateBotVersionError::PreconditionFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1937,7 +2938,12 @@ impl CreateIntentVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2004,7 +3010,12 @@ impl CreateSlotTypeVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2067,7 +3078,12 @@ impl DeleteBotError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteBotError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2130,7 +3146,12 @@ impl DeleteBotAliasError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteBotAliasError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2200,7 +3221,12 @@ impl DeleteBotChannelAssociationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2262,7 +3288,12 @@ impl DeleteBotVersionError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteBotVersionError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2325,7 +3356,12 @@ impl DeleteIntentError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteIntentError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2388,7 +3424,12 @@ impl DeleteIntentVersionError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteIntentVersionError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2451,7 +3492,12 @@ impl DeleteSlotTypeError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteSlotTypeError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2516,7 +3562,12 @@ impl DeleteSlotTypeVersionError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeleteSlotTypeVersionError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2569,7 +3620,12 @@ impl DeleteUtterancesError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteUtterancesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2618,7 +3674,12 @@ impl GetBotError {
                     return RusotoError::Service(GetBotError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2669,7 +3730,12 @@ impl GetBotAliasError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetBotAliasError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2715,7 +3781,12 @@ impl GetBotAliasesError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetBotAliasesError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2769,7 +3840,12 @@ impl GetBotChannelAssociationError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetBotChannelAssociationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2821,7 +3897,12 @@ impl GetBotChannelAssociationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2871,7 +3952,12 @@ impl GetBotVersionsError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetBotVersionsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2922,7 +4008,12 @@ impl GetBotsError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetBotsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2973,7 +4064,12 @@ impl GetBuiltinIntentError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetBuiltinIntentError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3019,7 +4115,12 @@ impl GetBuiltinIntentsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetBuiltinIntentsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3064,7 +4165,12 @@ impl GetBuiltinSlotTypesError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetBuiltinSlotTypesError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3114,7 +4220,12 @@ impl GetExportError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetExportError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3165,7 +4276,12 @@ impl GetImportError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetImportError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3216,7 +4332,12 @@ impl GetIntentError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetIntentError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3267,7 +4388,12 @@ impl GetIntentVersionsError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetIntentVersionsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3318,7 +4444,12 @@ impl GetIntentsError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetIntentsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3369,7 +4500,12 @@ impl GetSlotTypeError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetSlotTypeError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3420,7 +4556,12 @@ impl GetSlotTypeVersionsError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetSlotTypeVersionsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3471,7 +4612,12 @@ impl GetSlotTypesError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetSlotTypesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3517,7 +4663,12 @@ impl GetUtterancesViewError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetUtterancesViewError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3570,7 +4721,12 @@ impl PutBotError {
                 "PreconditionFailedException" => {
                     return RusotoError::Service(PutBotError::PreconditionFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3627,7 +4783,12 @@ impl PutBotAliasError {
                 "PreconditionFailedException" => {
                     return RusotoError::Service(PutBotAliasError::PreconditionFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3684,7 +4845,12 @@ impl PutIntentError {
                 "PreconditionFailedException" => {
                     return RusotoError::Service(PutIntentError::PreconditionFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3741,7 +4907,12 @@ impl PutSlotTypeError {
                 "PreconditionFailedException" => {
                     return RusotoError::Service(PutSlotTypeError::PreconditionFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3788,7 +4959,12 @@ impl StartImportError {
                 "LimitExceededException" => {
                     return RusotoError::Service(StartImportError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4012,1320 +5188,3501 @@ pub trait LexModels {
         input: StartImportRequest,
     ) -> RusotoFuture<StartImportResponse, StartImportError>;
 }
-/// A client for the Amazon Lex Model Building Service API.
-#[derive(Clone)]
-pub struct LexModelsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl LexModelsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> LexModelsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> LexModelsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> LexModelsClient {
-        LexModelsClient { client, region }
-    }
-}
-
-impl LexModels for LexModelsClient {
-    /// <p>Creates a new version of the bot based on the <code>$LATEST</code> version. If the <code>$LATEST</code> version of this resource hasn't changed since you created the last version, Amazon Lex doesn't create a new version. It returns the last created version.</p> <note> <p>You can update only the <code>$LATEST</code> version of the bot. You can't update the numbered versions that you create with the <code>CreateBotVersion</code> operation.</p> </note> <p> When you create the first version of a bot, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p> This operation requires permission for the <code>lex:CreateBotVersion</code> action. </p>
+const _: Option<&dyn LexModels> = None;
+impl<'a, T: LexModels + ?Sized> LexModels for &'a T {
     fn create_bot_version(
         &self,
         input: CreateBotVersionRequest,
     ) -> RusotoFuture<CreateBotVersionResponse, CreateBotVersionError> {
-        let request_uri = format!("/bots/{name}/versions", name = input.name);
-
-        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBotVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBotVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_bot_version(input)
     }
-
-    /// <p>Creates a new version of an intent based on the <code>$LATEST</code> version of the intent. If the <code>$LATEST</code> version of this intent hasn't changed since you last updated it, Amazon Lex doesn't create a new version. It returns the last version you created.</p> <note> <p>You can update only the <code>$LATEST</code> version of the intent. You can't update the numbered versions that you create with the <code>CreateIntentVersion</code> operation.</p> </note> <p> When you create a version of an intent, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions to perform the <code>lex:CreateIntentVersion</code> action. </p>
     fn create_intent_version(
         &self,
         input: CreateIntentVersionRequest,
     ) -> RusotoFuture<CreateIntentVersionResponse, CreateIntentVersionError> {
-        let request_uri = format!("/intents/{name}/versions", name = input.name);
-
-        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIntentVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateIntentVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_intent_version(input)
     }
-
-    /// <p>Creates a new version of a slot type based on the <code>$LATEST</code> version of the specified slot type. If the <code>$LATEST</code> version of this resource has not changed since the last version that you created, Amazon Lex doesn't create a new version. It returns the last version that you created. </p> <note> <p>You can update only the <code>$LATEST</code> version of a slot type. You can't update the numbered versions that you create with the <code>CreateSlotTypeVersion</code> operation.</p> </note> <p>When you create a version of a slot type, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions for the <code>lex:CreateSlotTypeVersion</code> action.</p>
     fn create_slot_type_version(
         &self,
         input: CreateSlotTypeVersionRequest,
     ) -> RusotoFuture<CreateSlotTypeVersionResponse, CreateSlotTypeVersionError> {
-        let request_uri = format!("/slottypes/{name}/versions", name = input.name);
-
-        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSlotTypeVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateSlotTypeVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_slot_type_version(input)
     }
-
-    /// <p>Deletes all versions of the bot, including the <code>$LATEST</code> version. To delete a specific version of the bot, use the <a>DeleteBotVersion</a> operation.</p> <p>If a bot has an alias, you can't delete it. Instead, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the alias that refers to the bot. To remove the reference to the bot, delete the alias. If you get the same exception again, delete the referring alias until the <code>DeleteBot</code> operation is successful.</p> <p>This operation requires permissions for the <code>lex:DeleteBot</code> action.</p>
     fn delete_bot(&self, input: DeleteBotRequest) -> RusotoFuture<(), DeleteBotError> {
-        let request_uri = format!("/bots/{name}", name = input.name);
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBotError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_bot(input)
     }
-
-    /// <p>Deletes an alias for the specified bot. </p> <p>You can't delete an alias that is used in the association between a bot and a messaging channel. If an alias is used in a channel association, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the channel association that refers to the bot. You can remove the reference to the alias by deleting the channel association. If you get the same exception again, delete the referring association until the <code>DeleteBotAlias</code> operation is successful.</p>
     fn delete_bot_alias(
         &self,
         input: DeleteBotAliasRequest,
     ) -> RusotoFuture<(), DeleteBotAliasError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/aliases/{name}",
-            bot_name = input.bot_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBotAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_bot_alias(input)
     }
-
-    /// <p>Deletes the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permission for the <code>lex:DeleteBotChannelAssociation</code> action.</p>
     fn delete_bot_channel_association(
         &self,
         input: DeleteBotChannelAssociationRequest,
     ) -> RusotoFuture<(), DeleteBotChannelAssociationError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/aliases/{alias_name}/channels/{name}",
-            alias_name = input.bot_alias,
-            bot_name = input.bot_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBotChannelAssociationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_bot_channel_association(input)
     }
-
-    /// <p>Deletes a specific version of a bot. To delete all versions of a bot, use the <a>DeleteBot</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteBotVersion</code> action.</p>
     fn delete_bot_version(
         &self,
         input: DeleteBotVersionRequest,
     ) -> RusotoFuture<(), DeleteBotVersionError> {
-        let request_uri = format!(
-            "/bots/{name}/versions/{version}",
-            name = input.name,
-            version = input.version
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBotVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_bot_version(input)
     }
-
-    /// <p>Deletes all versions of the intent, including the <code>$LATEST</code> version. To delete a specific version of the intent, use the <a>DeleteIntentVersion</a> operation.</p> <p> You can delete a version of an intent only if it is not referenced. To delete an intent that is referred to in one or more bots (see <a>how-it-works</a>), you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, it provides an example reference that shows where the intent is referenced. To remove the reference to the intent, either update the bot or delete it. If you get the same exception when you attempt to delete the intent again, repeat until the intent has no references and the call to <code>DeleteIntent</code> is successful. </p> </note> <p> This operation requires permission for the <code>lex:DeleteIntent</code> action. </p>
     fn delete_intent(&self, input: DeleteIntentRequest) -> RusotoFuture<(), DeleteIntentError> {
-        let request_uri = format!("/intents/{name}", name = input.name);
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteIntentError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_intent(input)
     }
-
-    /// <p>Deletes a specific version of an intent. To delete all versions of a intent, use the <a>DeleteIntent</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteIntentVersion</code> action.</p>
     fn delete_intent_version(
         &self,
         input: DeleteIntentVersionRequest,
     ) -> RusotoFuture<(), DeleteIntentVersionError> {
-        let request_uri = format!(
-            "/intents/{name}/versions/{version}",
-            name = input.name,
-            version = input.version
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteIntentVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_intent_version(input)
     }
-
-    /// <p>Deletes all versions of the slot type, including the <code>$LATEST</code> version. To delete a specific version of the slot type, use the <a>DeleteSlotTypeVersion</a> operation.</p> <p> You can delete a version of a slot type only if it is not referenced. To delete a slot type that is referred to in one or more intents, you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, the exception provides an example reference that shows the intent where the slot type is referenced. To remove the reference to the slot type, either update the intent or delete it. If you get the same exception when you attempt to delete the slot type again, repeat until the slot type has no references and the <code>DeleteSlotType</code> call is successful. </p> </note> <p>This operation requires permission for the <code>lex:DeleteSlotType</code> action.</p>
     fn delete_slot_type(
         &self,
         input: DeleteSlotTypeRequest,
     ) -> RusotoFuture<(), DeleteSlotTypeError> {
-        let request_uri = format!("/slottypes/{name}", name = input.name);
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSlotTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_slot_type(input)
     }
-
-    /// <p>Deletes a specific version of a slot type. To delete all versions of a slot type, use the <a>DeleteSlotType</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteSlotTypeVersion</code> action.</p>
     fn delete_slot_type_version(
         &self,
         input: DeleteSlotTypeVersionRequest,
     ) -> RusotoFuture<(), DeleteSlotTypeVersionError> {
-        let request_uri = format!(
-            "/slottypes/{name}/version/{version}",
-            name = input.name,
-            version = input.version
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteSlotTypeVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_slot_type_version(input)
     }
-
-    /// <p>Deletes stored utterances.</p> <p>Amazon Lex stores the utterances that users send to your bot. Utterances are stored for 15 days for use with the <a>GetUtterancesView</a> operation, and then stored indefinitely for use in improving the ability of your bot to respond to user input.</p> <p>Use the <code>DeleteStoredUtterances</code> operation to manually delete stored utterances for a specific user.</p> <p>This operation requires permissions for the <code>lex:DeleteUtterances</code> action.</p>
     fn delete_utterances(
         &self,
         input: DeleteUtterancesRequest,
     ) -> RusotoFuture<(), DeleteUtterancesError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/utterances/{user_id}",
-            bot_name = input.bot_name,
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUtterancesError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_utterances(input)
     }
-
-    /// <p>Returns metadata information for a specific bot. You must provide the bot name and the bot version or alias. </p> <p> This operation requires permissions for the <code>lex:GetBot</code> action. </p>
     fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
-        let request_uri = format!(
-            "/bots/{name}/versions/{versionoralias}",
-            name = input.name,
-            versionoralias = input.version_or_alias
-        );
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBotError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_bot(input)
     }
-
-    /// <p>Returns information about an Amazon Lex bot alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:GetBotAlias</code> action.</p>
     fn get_bot_alias(
         &self,
         input: GetBotAliasRequest,
     ) -> RusotoFuture<GetBotAliasResponse, GetBotAliasError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/aliases/{name}",
-            bot_name = input.bot_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotAliasResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBotAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_bot_alias(input)
     }
-
-    /// <p>Returns a list of aliases for a specified Amazon Lex bot.</p> <p>This operation requires permissions for the <code>lex:GetBotAliases</code> action.</p>
     fn get_bot_aliases(
         &self,
         input: GetBotAliasesRequest,
     ) -> RusotoFuture<GetBotAliasesResponse, GetBotAliasesError> {
-        let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.name_contains {
-            params.put("nameContains", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotAliasesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBotAliasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_bot_aliases(input)
     }
-
-    /// <p>Returns information about the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permissions for the <code>lex:GetBotChannelAssociation</code> action.</p>
     fn get_bot_channel_association(
         &self,
         input: GetBotChannelAssociationRequest,
     ) -> RusotoFuture<GetBotChannelAssociationResponse, GetBotChannelAssociationError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/aliases/{alias_name}/channels/{name}",
-            alias_name = input.bot_alias,
-            bot_name = input.bot_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
+        (**self).get_bot_channel_association(input)
+    }
+    fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationsResponse, GetBotChannelAssociationsError> {
+        (**self).get_bot_channel_associations(input)
+    }
+    fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> RusotoFuture<GetBotVersionsResponse, GetBotVersionsError> {
+        (**self).get_bot_versions(input)
+    }
+    fn get_bots(&self, input: GetBotsRequest) -> RusotoFuture<GetBotsResponse, GetBotsError> {
+        (**self).get_bots(input)
+    }
+    fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> RusotoFuture<GetBuiltinIntentResponse, GetBuiltinIntentError> {
+        (**self).get_builtin_intent(input)
+    }
+    fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
+        (**self).get_builtin_intents(input)
+    }
+    fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> RusotoFuture<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError> {
+        (**self).get_builtin_slot_types(input)
+    }
+    fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> RusotoFuture<GetExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> RusotoFuture<GetImportResponse, GetImportError> {
+        (**self).get_import(input)
+    }
+    fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> RusotoFuture<GetIntentResponse, GetIntentError> {
+        (**self).get_intent(input)
+    }
+    fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> RusotoFuture<GetIntentVersionsResponse, GetIntentVersionsError> {
+        (**self).get_intent_versions(input)
+    }
+    fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> RusotoFuture<GetIntentsResponse, GetIntentsError> {
+        (**self).get_intents(input)
+    }
+    fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
+        (**self).get_slot_type(input)
+    }
+    fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> RusotoFuture<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError> {
+        (**self).get_slot_type_versions(input)
+    }
+    fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> RusotoFuture<GetSlotTypesResponse, GetSlotTypesError> {
+        (**self).get_slot_types(input)
+    }
+    fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> RusotoFuture<GetUtterancesViewResponse, GetUtterancesViewError> {
+        (**self).get_utterances_view(input)
+    }
+    fn put_bot(&self, input: PutBotRequest) -> RusotoFuture<PutBotResponse, PutBotError> {
+        (**self).put_bot(input)
+    }
+    fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> RusotoFuture<PutBotAliasResponse, PutBotAliasError> {
+        (**self).put_bot_alias(input)
+    }
+    fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> RusotoFuture<PutIntentResponse, PutIntentError> {
+        (**self).put_intent(input)
+    }
+    fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> RusotoFuture<PutSlotTypeResponse, PutSlotTypeError> {
+        (**self).put_slot_type(input)
+    }
+    fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> RusotoFuture<StartImportResponse, StartImportError> {
+        (**self).start_import(input)
+    }
+}
+impl<T: LexModels + ?Sized> LexModels for Box<T> {
+    fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> RusotoFuture<CreateBotVersionResponse, CreateBotVersionError> {
+        (**self).create_bot_version(input)
+    }
+    fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> RusotoFuture<CreateIntentVersionResponse, CreateIntentVersionError> {
+        (**self).create_intent_version(input)
+    }
+    fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> RusotoFuture<CreateSlotTypeVersionResponse, CreateSlotTypeVersionError> {
+        (**self).create_slot_type_version(input)
+    }
+    fn delete_bot(&self, input: DeleteBotRequest) -> RusotoFuture<(), DeleteBotError> {
+        (**self).delete_bot(input)
+    }
+    fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> RusotoFuture<(), DeleteBotAliasError> {
+        (**self).delete_bot_alias(input)
+    }
+    fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> RusotoFuture<(), DeleteBotChannelAssociationError> {
+        (**self).delete_bot_channel_association(input)
+    }
+    fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> RusotoFuture<(), DeleteBotVersionError> {
+        (**self).delete_bot_version(input)
+    }
+    fn delete_intent(&self, input: DeleteIntentRequest) -> RusotoFuture<(), DeleteIntentError> {
+        (**self).delete_intent(input)
+    }
+    fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> RusotoFuture<(), DeleteIntentVersionError> {
+        (**self).delete_intent_version(input)
+    }
+    fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeError> {
+        (**self).delete_slot_type(input)
+    }
+    fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeVersionError> {
+        (**self).delete_slot_type_version(input)
+    }
+    fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> RusotoFuture<(), DeleteUtterancesError> {
+        (**self).delete_utterances(input)
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        (**self).get_bot(input)
+    }
+    fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> RusotoFuture<GetBotAliasResponse, GetBotAliasError> {
+        (**self).get_bot_alias(input)
+    }
+    fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> RusotoFuture<GetBotAliasesResponse, GetBotAliasesError> {
+        (**self).get_bot_aliases(input)
+    }
+    fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationResponse, GetBotChannelAssociationError> {
+        (**self).get_bot_channel_association(input)
+    }
+    fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationsResponse, GetBotChannelAssociationsError> {
+        (**self).get_bot_channel_associations(input)
+    }
+    fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> RusotoFuture<GetBotVersionsResponse, GetBotVersionsError> {
+        (**self).get_bot_versions(input)
+    }
+    fn get_bots(&self, input: GetBotsRequest) -> RusotoFuture<GetBotsResponse, GetBotsError> {
+        (**self).get_bots(input)
+    }
+    fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> RusotoFuture<GetBuiltinIntentResponse, GetBuiltinIntentError> {
+        (**self).get_builtin_intent(input)
+    }
+    fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
+        (**self).get_builtin_intents(input)
+    }
+    fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> RusotoFuture<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError> {
+        (**self).get_builtin_slot_types(input)
+    }
+    fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> RusotoFuture<GetExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> RusotoFuture<GetImportResponse, GetImportError> {
+        (**self).get_import(input)
+    }
+    fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> RusotoFuture<GetIntentResponse, GetIntentError> {
+        (**self).get_intent(input)
+    }
+    fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> RusotoFuture<GetIntentVersionsResponse, GetIntentVersionsError> {
+        (**self).get_intent_versions(input)
+    }
+    fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> RusotoFuture<GetIntentsResponse, GetIntentsError> {
+        (**self).get_intents(input)
+    }
+    fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
+        (**self).get_slot_type(input)
+    }
+    fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> RusotoFuture<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError> {
+        (**self).get_slot_type_versions(input)
+    }
+    fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> RusotoFuture<GetSlotTypesResponse, GetSlotTypesError> {
+        (**self).get_slot_types(input)
+    }
+    fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> RusotoFuture<GetUtterancesViewResponse, GetUtterancesViewError> {
+        (**self).get_utterances_view(input)
+    }
+    fn put_bot(&self, input: PutBotRequest) -> RusotoFuture<PutBotResponse, PutBotError> {
+        (**self).put_bot(input)
+    }
+    fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> RusotoFuture<PutBotAliasResponse, PutBotAliasError> {
+        (**self).put_bot_alias(input)
+    }
+    fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> RusotoFuture<PutIntentResponse, PutIntentError> {
+        (**self).put_intent(input)
+    }
+    fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> RusotoFuture<PutSlotTypeResponse, PutSlotTypeError> {
+        (**self).put_slot_type(input)
+    }
+    fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> RusotoFuture<StartImportResponse, StartImportError> {
+        (**self).start_import(input)
+    }
+}
+impl<T: LexModels + ?Sized> LexModels for ::std::sync::Arc<T> {
+    fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> RusotoFuture<CreateBotVersionResponse, CreateBotVersionError> {
+        (**self).create_bot_version(input)
+    }
+    fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> RusotoFuture<CreateIntentVersionResponse, CreateIntentVersionError> {
+        (**self).create_intent_version(input)
+    }
+    fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> RusotoFuture<CreateSlotTypeVersionResponse, CreateSlotTypeVersionError> {
+        (**self).create_slot_type_version(input)
+    }
+    fn delete_bot(&self, input: DeleteBotRequest) -> RusotoFuture<(), DeleteBotError> {
+        (**self).delete_bot(input)
+    }
+    fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> RusotoFuture<(), DeleteBotAliasError> {
+        (**self).delete_bot_alias(input)
+    }
+    fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> RusotoFuture<(), DeleteBotChannelAssociationError> {
+        (**self).delete_bot_channel_association(input)
+    }
+    fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> RusotoFuture<(), DeleteBotVersionError> {
+        (**self).delete_bot_version(input)
+    }
+    fn delete_intent(&self, input: DeleteIntentRequest) -> RusotoFuture<(), DeleteIntentError> {
+        (**self).delete_intent(input)
+    }
+    fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> RusotoFuture<(), DeleteIntentVersionError> {
+        (**self).delete_intent_version(input)
+    }
+    fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeError> {
+        (**self).delete_slot_type(input)
+    }
+    fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeVersionError> {
+        (**self).delete_slot_type_version(input)
+    }
+    fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> RusotoFuture<(), DeleteUtterancesError> {
+        (**self).delete_utterances(input)
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        (**self).get_bot(input)
+    }
+    fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> RusotoFuture<GetBotAliasResponse, GetBotAliasError> {
+        (**self).get_bot_alias(input)
+    }
+    fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> RusotoFuture<GetBotAliasesResponse, GetBotAliasesError> {
+        (**self).get_bot_aliases(input)
+    }
+    fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationResponse, GetBotChannelAssociationError> {
+        (**self).get_bot_channel_association(input)
+    }
+    fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationsResponse, GetBotChannelAssociationsError> {
+        (**self).get_bot_channel_associations(input)
+    }
+    fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> RusotoFuture<GetBotVersionsResponse, GetBotVersionsError> {
+        (**self).get_bot_versions(input)
+    }
+    fn get_bots(&self, input: GetBotsRequest) -> RusotoFuture<GetBotsResponse, GetBotsError> {
+        (**self).get_bots(input)
+    }
+    fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> RusotoFuture<GetBuiltinIntentResponse, GetBuiltinIntentError> {
+        (**self).get_builtin_intent(input)
+    }
+    fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
+        (**self).get_builtin_intents(input)
+    }
+    fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> RusotoFuture<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError> {
+        (**self).get_builtin_slot_types(input)
+    }
+    fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> RusotoFuture<GetExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> RusotoFuture<GetImportResponse, GetImportError> {
+        (**self).get_import(input)
+    }
+    fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> RusotoFuture<GetIntentResponse, GetIntentError> {
+        (**self).get_intent(input)
+    }
+    fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> RusotoFuture<GetIntentVersionsResponse, GetIntentVersionsError> {
+        (**self).get_intent_versions(input)
+    }
+    fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> RusotoFuture<GetIntentsResponse, GetIntentsError> {
+        (**self).get_intents(input)
+    }
+    fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
+        (**self).get_slot_type(input)
+    }
+    fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> RusotoFuture<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError> {
+        (**self).get_slot_type_versions(input)
+    }
+    fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> RusotoFuture<GetSlotTypesResponse, GetSlotTypesError> {
+        (**self).get_slot_types(input)
+    }
+    fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> RusotoFuture<GetUtterancesViewResponse, GetUtterancesViewError> {
+        (**self).get_utterances_view(input)
+    }
+    fn put_bot(&self, input: PutBotRequest) -> RusotoFuture<PutBotResponse, PutBotError> {
+        (**self).put_bot(input)
+    }
+    fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> RusotoFuture<PutBotAliasResponse, PutBotAliasError> {
+        (**self).put_bot_alias(input)
+    }
+    fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> RusotoFuture<PutIntentResponse, PutIntentError> {
+        (**self).put_intent(input)
+    }
+    fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> RusotoFuture<PutSlotTypeResponse, PutSlotTypeError> {
+        (**self).put_slot_type(input)
+    }
+    fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> RusotoFuture<StartImportResponse, StartImportError> {
+        (**self).start_import(input)
+    }
+}
+/// A client for the Amazon Lex Model Building Service API.
+#[derive(Clone)]
+pub struct LexModelsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl LexModelsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LexModelsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LexModelsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> LexModelsClient {
+        LexModelsClient { client, region }
+    }
+}
+
+impl LexModels for LexModelsClient {
+    /// <p>Creates a new version of the bot based on the <code>$LATEST</code> version. If the <code>$LATEST</code> version of this resource hasn't changed since you created the last version, Amazon Lex doesn't create a new version. It returns the last created version.</p> <note> <p>You can update only the <code>$LATEST</code> version of the bot. You can't update the numbered versions that you create with the <code>CreateBotVersion</code> operation.</p> </note> <p> When you create the first version of a bot, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p> This operation requires permission for the <code>lex:CreateBotVersion</code> action. </p>
+    fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> RusotoFuture<CreateBotVersionResponse, CreateBotVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/bots/{name}/versions", name = input.name);
+
+        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateBotVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateBotVersion")
+                        .deserialize::<CreateBotVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBotVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new version of an intent based on the <code>$LATEST</code> version of the intent. If the <code>$LATEST</code> version of this intent hasn't changed since you last updated it, Amazon Lex doesn't create a new version. It returns the last version you created.</p> <note> <p>You can update only the <code>$LATEST</code> version of the intent. You can't update the numbered versions that you create with the <code>CreateIntentVersion</code> operation.</p> </note> <p> When you create a version of an intent, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions to perform the <code>lex:CreateIntentVersion</code> action. </p>
+    fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> RusotoFuture<CreateIntentVersionResponse, CreateIntentVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/intents/{name}/versions", name = input.name);
+
+        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateIntentVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateIntentVersion")
+                        .deserialize::<CreateIntentVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateIntentVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new version of a slot type based on the <code>$LATEST</code> version of the specified slot type. If the <code>$LATEST</code> version of this resource has not changed since the last version that you created, Amazon Lex doesn't create a new version. It returns the last version that you created. </p> <note> <p>You can update only the <code>$LATEST</code> version of a slot type. You can't update the numbered versions that you create with the <code>CreateSlotTypeVersion</code> operation.</p> </note> <p>When you create a version of a slot type, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions for the <code>lex:CreateSlotTypeVersion</code> action.</p>
+    fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> RusotoFuture<CreateSlotTypeVersionResponse, CreateSlotTypeVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/slottypes/{name}/versions", name = input.name);
+
+        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateSlotTypeVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSlotTypeVersion")
+                        .deserialize::<CreateSlotTypeVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateSlotTypeVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes all versions of the bot, including the <code>$LATEST</code> version. To delete a specific version of the bot, use the <a>DeleteBotVersion</a> operation.</p> <p>If a bot has an alias, you can't delete it. Instead, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the alias that refers to the bot. To remove the reference to the bot, delete the alias. If you get the same exception again, delete the referring alias until the <code>DeleteBot</code> operation is successful.</p> <p>This operation requires permissions for the <code>lex:DeleteBot</code> action.</p>
+    fn delete_bot(&self, input: DeleteBotRequest) -> RusotoFuture<(), DeleteBotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/bots/{name}", name = input.name);
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBot");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an alias for the specified bot. </p> <p>You can't delete an alias that is used in the association between a bot and a messaging channel. If an alias is used in a channel association, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the channel association that refers to the bot. You can remove the reference to the alias by deleting the channel association. If you get the same exception again, delete the referring association until the <code>DeleteBotAlias</code> operation is successful.</p>
+    fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> RusotoFuture<(), DeleteBotAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/aliases/{name}",
+            bot_name = input.bot_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotAlias");
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBotAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permission for the <code>lex:DeleteBotChannelAssociation</code> action.</p>
+    fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> RusotoFuture<(), DeleteBotChannelAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/aliases/{alias_name}/channels/{name}",
+            alias_name = input.bot_alias,
+            bot_name = input.bot_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotChannelAssociation");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBotChannelAssociationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a specific version of a bot. To delete all versions of a bot, use the <a>DeleteBot</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteBotVersion</code> action.</p>
+    fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> RusotoFuture<(), DeleteBotVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{name}/versions/{version}",
+            name = input.name,
+            version = input.version
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("version", &input.version.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBotVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes all versions of the intent, including the <code>$LATEST</code> version. To delete a specific version of the intent, use the <a>DeleteIntentVersion</a> operation.</p> <p> You can delete a version of an intent only if it is not referenced. To delete an intent that is referred to in one or more bots (see <a>how-it-works</a>), you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, it provides an example reference that shows where the intent is referenced. To remove the reference to the intent, either update the bot or delete it. If you get the same exception when you attempt to delete the intent again, repeat until the intent has no references and the call to <code>DeleteIntent</code> is successful. </p> </note> <p> This operation requires permission for the <code>lex:DeleteIntent</code> action. </p>
+    fn delete_intent(&self, input: DeleteIntentRequest) -> RusotoFuture<(), DeleteIntentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/intents/{name}", name = input.name);
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntent");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIntentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specific version of an intent. To delete all versions of a intent, use the <a>DeleteIntent</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteIntentVersion</code> action.</p>
+    fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> RusotoFuture<(), DeleteIntentVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/intents/{name}/versions/{version}",
+            name = input.name,
+            version = input.version
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntentVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("version", &input.version.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteIntentVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes all versions of the slot type, including the <code>$LATEST</code> version. To delete a specific version of the slot type, use the <a>DeleteSlotTypeVersion</a> operation.</p> <p> You can delete a version of a slot type only if it is not referenced. To delete a slot type that is referred to in one or more intents, you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, the exception provides an example reference that shows the intent where the slot type is referenced. To remove the reference to the slot type, either update the intent or delete it. If you get the same exception when you attempt to delete the slot type again, repeat until the slot type has no references and the <code>DeleteSlotType</code> call is successful. </p> </note> <p>This operation requires permission for the <code>lex:DeleteSlotType</code> action.</p>
+    fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/slottypes/{name}", name = input.name);
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotType");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSlotTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specific version of a slot type. To delete all versions of a slot type, use the <a>DeleteSlotType</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteSlotTypeVersion</code> action.</p>
+    fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/slottypes/{name}/version/{version}",
+            name = input.name,
+            version = input.version
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotTypeVersion");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("version", &input.version.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteSlotTypeVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes stored utterances.</p> <p>Amazon Lex stores the utterances that users send to your bot. Utterances are stored for 15 days for use with the <a>GetUtterancesView</a> operation, and then stored indefinitely for use in improving the ability of your bot to respond to user input.</p> <p>Use the <code>DeleteStoredUtterances</code> operation to manually delete stored utterances for a specific user.</p> <p>This operation requires permissions for the <code>lex:DeleteUtterances</code> action.</p>
+    fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> RusotoFuture<(), DeleteUtterancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/utterances/{user_id}",
+            bot_name = input.bot_name,
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteUtterances");
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("userId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUtterancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns metadata information for a specific bot. You must provide the bot name and the bot version or alias. </p> <p> This operation requires permissions for the <code>lex:GetBot</code> action. </p>
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{name}/versions/{versionoralias}",
+            name = input.name,
+            versionoralias = input.version_or_alias
+        );
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBot");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("versionOrAlias", &input.version_or_alias.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBot")
+                        .deserialize::<GetBotResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about an Amazon Lex bot alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:GetBotAlias</code> action.</p>
+    fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> RusotoFuture<GetBotAliasResponse, GetBotAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/aliases/{name}",
+            bot_name = input.bot_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAlias");
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBotAlias")
+                        .deserialize::<GetBotAliasResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBotAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of aliases for a specified Amazon Lex bot.</p> <p>This operation requires permissions for the <code>lex:GetBotAliases</code> action.</p>
+    fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> RusotoFuture<GetBotAliasesResponse, GetBotAliasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAliases");
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.name_contains {
+            params.put("nameContains", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBotAliases")
+                        .deserialize::<GetBotAliasesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBotAliasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permissions for the <code>lex:GetBotChannelAssociation</code> action.</p>
+    fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationResponse, GetBotChannelAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/aliases/{alias_name}/channels/{name}",
+            alias_name = input.bot_alias,
+            bot_name = input.bot_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociation");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBotChannelAssociation")
                         .deserialize::<GetBotChannelAssociationResponse, _>()?;
 
                     Ok(result)
                 }))
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBotChannelAssociationError::from_response(response))
+                    Err(GetBotChannelAssociationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> Returns a list of all of the channels associated with the specified bot. </p> <p>The <code>GetBotChannelAssociations</code> operation requires permissions for the <code>lex:GetBotChannelAssociations</code> action.</p>
+    fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationsResponse, GetBotChannelAssociationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/aliases/{alias_name}/channels/",
+            alias_name = input.bot_alias,
+            bot_name = input.bot_name
+        );
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociations");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.name_contains {
+            params.put("nameContains", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBotChannelAssociations")
+                        .deserialize::<GetBotChannelAssociationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBotChannelAssociationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about all of the versions of a bot.</p> <p>The <code>GetBotVersions</code> operation returns a <code>BotMetadata</code> object for each version of a bot. For example, if a bot has three numbered versions, the <code>GetBotVersions</code> operation returns four <code>BotMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetBotVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetBotVersions</code> action.</p>
+    fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> RusotoFuture<GetBotVersionsResponse, GetBotVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/bots/{name}/versions/", name = input.name);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotVersions");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBotVersions")
+                        .deserialize::<GetBotVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBotVersionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns bot information as follows: </p> <ul> <li> <p>If you provide the <code>nameContains</code> field, the response includes information for the <code>$LATEST</code> version of all bots whose name contains the specified string.</p> </li> <li> <p>If you don't specify the <code>nameContains</code> field, the operation returns information about the <code>$LATEST</code> version of all of your bots.</p> </li> </ul> <p>This operation requires permission for the <code>lex:GetBots</code> action.</p>
+    fn get_bots(&self, input: GetBotsRequest) -> RusotoFuture<GetBotsResponse, GetBotsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/bots/";
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBots");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.name_contains {
+            params.put("nameContains", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBots")
+                        .deserialize::<GetBotsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBotsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a built-in intent.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntent</code> action.</p>
+    fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> RusotoFuture<GetBuiltinIntentResponse, GetBuiltinIntentError> {
+        let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntent");
+        request.add_resource_id("signature", &input.signature.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBuiltinIntent")
+                        .deserialize::<GetBuiltinIntentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBuiltinIntentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of built-in intents that meet the specified criteria.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntents</code> action.</p>
+    fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/builtins/intents/";
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntents");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.locale {
+            params.put("locale", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        if let Some(ref x) = input.signature_contains {
+            params.put("signatureContains", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBuiltinIntents")
+                        .deserialize::<GetBuiltinIntentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBuiltinIntentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of built-in slot types that meet the specified criteria.</p> <p>For a list of built-in slot types, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/slot-type-reference">Slot Type Reference</a> in the <i>Alexa Skills Kit</i>.</p> <p>This operation requires permission for the <code>lex:GetBuiltInSlotTypes</code> action.</p>
+    fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> RusotoFuture<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/builtins/slottypes/";
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinSlotTypes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.locale {
+            params.put("locale", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        if let Some(ref x) = input.signature_contains {
+            params.put("signatureContains", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBuiltinSlotTypes")
+                        .deserialize::<GetBuiltinSlotTypesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetBuiltinSlotTypesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Exports the contents of a Amazon Lex resource in a specified format. </p>
+    fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> RusotoFuture<GetExportResponse, GetExportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/exports/";
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetExport");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        params.put("exportType", &input.export_type);
+        params.put("name", &input.name);
+        params.put("resourceType", &input.resource_type);
+        params.put("version", &input.version);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetExport")
+                        .deserialize::<GetExportResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetExportError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about an import job started with the <code>StartImport</code> operation.</p>
+    fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> RusotoFuture<GetImportResponse, GetImportError> {
+        let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetImport");
+        request.add_resource_id("importId", &input.import_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetImport")
+                        .deserialize::<GetImportResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetImportError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Returns information about an intent. In addition to the intent name, you must specify the intent version. </p> <p> This operation requires permissions to perform the <code>lex:GetIntent</code> action. </p>
+    fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> RusotoFuture<GetIntentResponse, GetIntentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/intents/{name}/versions/{version}",
+            name = input.name,
+            version = input.version
+        );
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntent");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("version", &input.version.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntent")
+                        .deserialize::<GetIntentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIntentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about all of the versions of an intent.</p> <p>The <code>GetIntentVersions</code> operation returns an <code>IntentMetadata</code> object for each version of an intent. For example, if an intent has three numbered versions, the <code>GetIntentVersions</code> operation returns four <code>IntentMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetIntentVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetIntentVersions</code> action.</p>
+    fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> RusotoFuture<GetIntentVersionsResponse, GetIntentVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/intents/{name}/versions/", name = input.name);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntentVersions");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntentVersions")
+                        .deserialize::<GetIntentVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIntentVersionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns intent information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all intents that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all intents. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetIntents</code> action. </p>
+    fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> RusotoFuture<GetIntentsResponse, GetIntentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/intents/";
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntents");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.name_contains {
+            params.put("nameContains", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetIntents")
+                        .deserialize::<GetIntentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIntentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific version of a slot type. In addition to specifying the slot type name, you must specify the slot type version.</p> <p>This operation requires permissions for the <code>lex:GetSlotType</code> action.</p>
+    fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/slottypes/{name}/versions/{version}",
+            name = input.name,
+            version = input.version
+        );
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotType");
+        request.add_resource_id("name", &input.name.to_string());
+        request.add_resource_id("version", &input.version.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSlotType")
+                        .deserialize::<GetSlotTypeResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSlotTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about all versions of a slot type.</p> <p>The <code>GetSlotTypeVersions</code> operation returns a <code>SlotTypeMetadata</code> object for each version of a slot type. For example, if a slot type has three numbered versions, the <code>GetSlotTypeVersions</code> operation returns four <code>SlotTypeMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetSlotTypeVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetSlotTypeVersions</code> action.</p>
+    fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> RusotoFuture<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypeVersions");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSlotTypeVersions")
+                        .deserialize::<GetSlotTypeVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetSlotTypeVersionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns slot type information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all slot types that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all slot types. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetSlotTypes</code> action. </p>
+    fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> RusotoFuture<GetSlotTypesResponse, GetSlotTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/slottypes/";
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypes");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.name_contains {
+            params.put("nameContains", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSlotTypes")
+                        .deserialize::<GetSlotTypesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSlotTypesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Use the <code>GetUtterancesView</code> operation to get information about the utterances that your users have made to your bot. You can use this list to tune the utterances that your bot responds to.</p> <p>For example, say that you have created a bot to order flowers. After your users have used your bot for a while, use the <code>GetUtterancesView</code> operation to see the requests that they have made and whether they have been successful. You might find that the utterance "I want flowers" is not being recognized. You could add this utterance to the <code>OrderFlowers</code> intent so that your bot recognizes that utterance.</p> <p>After you publish a new version of a bot, you can get information about the old version and the new so that you can compare the performance across the two versions. </p> <note> <p>Utterance statistics are generated once a day. Data is available for the last 15 days. You can request information for up to 5 versions in each request. The response contains information about a maximum of 100 utterances for each version.</p> </note> <p>This operation requires permissions for the <code>lex:GetUtterancesView</code> action.</p>
+    fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> RusotoFuture<GetUtterancesViewResponse, GetUtterancesViewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
+
+        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetUtterancesView");
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+
+        let mut params = Params::new();
+        for item in input.bot_versions.iter() {
+            params.put("bot_versions", item);
+        }
+        params.put("status_type", &input.status_type);
+        params.put("view", "aggregation");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetUtterancesView")
+                        .deserialize::<GetUtterancesViewResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetUtterancesViewError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Amazon Lex conversational bot or replaces an existing bot. When you create or update a bot you are only required to specify a name, a locale, and whether the bot is directed toward children under age 13. You can use this to add intents later, or to remove intents from an existing bot. When you create a bot with the minimum information, the bot is created or updated but Amazon Lex returns the <code/> response <code>FAILED</code>. You can build the bot after you add one or more intents. For more information about Amazon Lex bots, see <a>how-it-works</a>. </p> <p>If you specify the name of an existing bot, the fields in the request replace the existing values in the <code>$LATEST</code> version of the bot. Amazon Lex removes any fields that you don't provide values for in the request, except for the <code>idleTTLInSeconds</code> and <code>privacySettings</code> fields, which are set to their default values. If you don't specify values for required fields, Amazon Lex throws an exception.</p> <p>This operation requires permissions for the <code>lex:PutBot</code> action. For more information, see <a>auth-and-access-control</a>.</p>
+    fn put_bot(&self, input: PutBotRequest) -> RusotoFuture<PutBotResponse, PutBotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
+
+        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBot");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutBot")
+                        .deserialize::<PutBotResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutBotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an alias for the specified version of the bot or replaces an alias for the specified bot. To change the version of the bot that the alias points to, replace the alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:PutBotAlias</code> action. </p>
+    fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> RusotoFuture<PutBotAliasResponse, PutBotAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/bots/{bot_name}/aliases/{name}",
+            bot_name = input.bot_name,
+            name = input.name
+        );
+
+        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBotAlias");
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutBotAlias")
+                        .deserialize::<PutBotAliasResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutBotAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an intent or replaces an existing intent.</p> <p>To define the interaction between the user and your bot, you use one or more intents. For a pizza ordering bot, for example, you would create an <code>OrderPizza</code> intent. </p> <p>To create an intent or replace an existing intent, you must provide the following:</p> <ul> <li> <p>Intent name. For example, <code>OrderPizza</code>.</p> </li> <li> <p>Sample utterances. For example, "Can I order a pizza, please." and "I want to order a pizza."</p> </li> <li> <p>Information to be gathered. You specify slot types for the information that your bot will request from the user. You can specify standard slot types, such as a date or a time, or custom slot types such as the size and crust of a pizza.</p> </li> <li> <p>How the intent will be fulfilled. You can provide a Lambda function or configure the intent to return the intent information to the client application. If you use a Lambda function, when all of the intent information is available, Amazon Lex invokes your Lambda function. If you configure your intent to return the intent information to the client application. </p> </li> </ul> <p>You can specify other optional information in the request, such as:</p> <ul> <li> <p>A confirmation prompt to ask the user to confirm an intent. For example, "Shall I order your pizza?"</p> </li> <li> <p>A conclusion statement to send to the user after the intent has been fulfilled. For example, "I placed your pizza order."</p> </li> <li> <p>A follow-up prompt that asks the user for additional activity. For example, asking "Do you want to order a drink with your pizza?"</p> </li> </ul> <p>If you specify an existing intent name to update the intent, Amazon Lex replaces the values in the <code>$LATEST</code> version of the intent with the values in the request. Amazon Lex removes fields that you don't provide in the request. If you don't specify the required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of an intent, the <code>status</code> field of any bot that uses the <code>$LATEST</code> version of the intent is set to <code>NOT_BUILT</code>.</p> <p>For more information, see <a>how-it-works</a>.</p> <p>This operation requires permissions for the <code>lex:PutIntent</code> action.</p>
+    fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> RusotoFuture<PutIntentResponse, PutIntentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
+
+        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutIntent");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutIntent")
+                        .deserialize::<PutIntentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutIntentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a custom slot type or replaces an existing custom slot type.</p> <p>To create a custom slot type, specify a name for the slot type and a set of enumeration values, which are the values that a slot of this type can assume. For more information, see <a>how-it-works</a>.</p> <p>If you specify the name of an existing slot type, the fields in the request replace the existing values in the <code>$LATEST</code> version of the slot type. Amazon Lex removes the fields that you don't provide in the request. If you don't specify required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of a slot type, if a bot uses the <code>$LATEST</code> version of an intent that contains the slot type, the bot's <code>status</code> field is set to <code>NOT_BUILT</code>.</p> <p>This operation requires permissions for the <code>lex:PutSlotType</code> action.</p>
+    fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> RusotoFuture<PutSlotTypeResponse, PutSlotTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
+
+        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutSlotType");
+        request.add_resource_id("name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutSlotType")
+                        .deserialize::<PutSlotTypeResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutSlotTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a job to import a resource to Amazon Lex.</p>
+    fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> RusotoFuture<StartImportResponse, StartImportError> {
+        let request_uri = "/imports/";
+
+        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("StartImport");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("models.lex".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartImport")
+                        .deserialize::<StartImportResponse, _>()?;
+
+                    Ok(result)
                 }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartImportError::from_response(response))),
+                )
             }
         })
     }
+}
+
+/// A mock of the Amazon Lex Model Building Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockLexModels {
+    create_bot_version: Option<
+        Box<
+            dyn Fn(
+                    CreateBotVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateBotVersionResponse,
+                    RusotoError<CreateBotVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_intent_version: Option<
+        Box<
+            dyn Fn(
+                    CreateIntentVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateIntentVersionResponse,
+                    RusotoError<CreateIntentVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_slot_type_version: Option<
+        Box<
+            dyn Fn(
+                    CreateSlotTypeVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateSlotTypeVersionResponse,
+                    RusotoError<CreateSlotTypeVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_bot: Option<
+        Box<
+            dyn Fn(DeleteBotRequest) -> ::std::result::Result<(), RusotoError<DeleteBotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bot_alias: Option<
+        Box<
+            dyn Fn(
+                    DeleteBotAliasRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteBotAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bot_channel_association: Option<
+        Box<
+            dyn Fn(
+                    DeleteBotChannelAssociationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBotChannelAssociationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bot_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteBotVersionRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteBotVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_intent: Option<
+        Box<
+            dyn Fn(DeleteIntentRequest) -> ::std::result::Result<(), RusotoError<DeleteIntentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_intent_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteIntentVersionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteIntentVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_slot_type: Option<
+        Box<
+            dyn Fn(
+                    DeleteSlotTypeRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteSlotTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_slot_type_version: Option<
+        Box<
+            dyn Fn(
+                    DeleteSlotTypeVersionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteSlotTypeVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_utterances: Option<
+        Box<
+            dyn Fn(
+                    DeleteUtterancesRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteUtterancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bot: Option<
+        Box<
+            dyn Fn(GetBotRequest) -> ::std::result::Result<GetBotResponse, RusotoError<GetBotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bot_alias: Option<
+        Box<
+            dyn Fn(
+                    GetBotAliasRequest,
+                )
+                    -> ::std::result::Result<GetBotAliasResponse, RusotoError<GetBotAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bot_aliases: Option<
+        Box<
+            dyn Fn(
+                    GetBotAliasesRequest,
+                )
+                    -> ::std::result::Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bot_channel_association: Option<
+        Box<
+            dyn Fn(
+                    GetBotChannelAssociationRequest,
+                ) -> ::std::result::Result<
+                    GetBotChannelAssociationResponse,
+                    RusotoError<GetBotChannelAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bot_channel_associations: Option<
+        Box<
+            dyn Fn(
+                    GetBotChannelAssociationsRequest,
+                ) -> ::std::result::Result<
+                    GetBotChannelAssociationsResponse,
+                    RusotoError<GetBotChannelAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bot_versions: Option<
+        Box<
+            dyn Fn(
+                    GetBotVersionsRequest,
+                ) -> ::std::result::Result<
+                    GetBotVersionsResponse,
+                    RusotoError<GetBotVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bots: Option<
+        Box<
+            dyn Fn(
+                    GetBotsRequest,
+                )
+                    -> ::std::result::Result<GetBotsResponse, RusotoError<GetBotsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_builtin_intent: Option<
+        Box<
+            dyn Fn(
+                    GetBuiltinIntentRequest,
+                ) -> ::std::result::Result<
+                    GetBuiltinIntentResponse,
+                    RusotoError<GetBuiltinIntentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_builtin_intents: Option<
+        Box<
+            dyn Fn(
+                    GetBuiltinIntentsRequest,
+                ) -> ::std::result::Result<
+                    GetBuiltinIntentsResponse,
+                    RusotoError<GetBuiltinIntentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_builtin_slot_types: Option<
+        Box<
+            dyn Fn(
+                    GetBuiltinSlotTypesRequest,
+                ) -> ::std::result::Result<
+                    GetBuiltinSlotTypesResponse,
+                    RusotoError<GetBuiltinSlotTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_export: Option<
+        Box<
+            dyn Fn(
+                    GetExportRequest,
+                )
+                    -> ::std::result::Result<GetExportResponse, RusotoError<GetExportError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_import: Option<
+        Box<
+            dyn Fn(
+                    GetImportRequest,
+                )
+                    -> ::std::result::Result<GetImportResponse, RusotoError<GetImportError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_intent: Option<
+        Box<
+            dyn Fn(
+                    GetIntentRequest,
+                )
+                    -> ::std::result::Result<GetIntentResponse, RusotoError<GetIntentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_intent_versions: Option<
+        Box<
+            dyn Fn(
+                    GetIntentVersionsRequest,
+                ) -> ::std::result::Result<
+                    GetIntentVersionsResponse,
+                    RusotoError<GetIntentVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_intents: Option<
+        Box<
+            dyn Fn(
+                    GetIntentsRequest,
+                )
+                    -> ::std::result::Result<GetIntentsResponse, RusotoError<GetIntentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_slot_type: Option<
+        Box<
+            dyn Fn(
+                    GetSlotTypeRequest,
+                )
+                    -> ::std::result::Result<GetSlotTypeResponse, RusotoError<GetSlotTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_slot_type_versions: Option<
+        Box<
+            dyn Fn(
+                    GetSlotTypeVersionsRequest,
+                ) -> ::std::result::Result<
+                    GetSlotTypeVersionsResponse,
+                    RusotoError<GetSlotTypeVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_slot_types: Option<
+        Box<
+            dyn Fn(
+                    GetSlotTypesRequest,
+                )
+                    -> ::std::result::Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_utterances_view: Option<
+        Box<
+            dyn Fn(
+                    GetUtterancesViewRequest,
+                ) -> ::std::result::Result<
+                    GetUtterancesViewResponse,
+                    RusotoError<GetUtterancesViewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_bot: Option<
+        Box<
+            dyn Fn(PutBotRequest) -> ::std::result::Result<PutBotResponse, RusotoError<PutBotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bot_alias: Option<
+        Box<
+            dyn Fn(
+                    PutBotAliasRequest,
+                )
+                    -> ::std::result::Result<PutBotAliasResponse, RusotoError<PutBotAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_intent: Option<
+        Box<
+            dyn Fn(
+                    PutIntentRequest,
+                )
+                    -> ::std::result::Result<PutIntentResponse, RusotoError<PutIntentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_slot_type: Option<
+        Box<
+            dyn Fn(
+                    PutSlotTypeRequest,
+                )
+                    -> ::std::result::Result<PutSlotTypeResponse, RusotoError<PutSlotTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_import: Option<
+        Box<
+            dyn Fn(
+                    StartImportRequest,
+                )
+                    -> ::std::result::Result<StartImportResponse, RusotoError<StartImportError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockLexModels {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockLexModels {
+        Default::default()
+    }
+
+    /// Answers `create_bot_version` with what `handler` returns for its input.
+    pub fn with_create_bot_version<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                CreateBotVersionRequest,
+            ) -> ::std::result::Result<
+                CreateBotVersionResponse,
+                RusotoError<CreateBotVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_bot_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_intent_version` with what `handler` returns for its input.
+    pub fn with_create_intent_version<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                CreateIntentVersionRequest,
+            ) -> ::std::result::Result<
+                CreateIntentVersionResponse,
+                RusotoError<CreateIntentVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_intent_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_slot_type_version` with what `handler` returns for its input.
+    pub fn with_create_slot_type_version<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                CreateSlotTypeVersionRequest,
+            ) -> ::std::result::Result<
+                CreateSlotTypeVersionResponse,
+                RusotoError<CreateSlotTypeVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_slot_type_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bot` with what `handler` returns for its input.
+    pub fn with_delete_bot<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(DeleteBotRequest) -> ::std::result::Result<(), RusotoError<DeleteBotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bot_alias` with what `handler` returns for its input.
+    pub fn with_delete_bot_alias<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(DeleteBotAliasRequest) -> ::std::result::Result<(), RusotoError<DeleteBotAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bot_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bot_channel_association` with what `handler` returns for its input.
+    pub fn with_delete_bot_channel_association<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                DeleteBotChannelAssociationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteBotChannelAssociationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bot_channel_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bot_version` with what `handler` returns for its input.
+    pub fn with_delete_bot_version<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                DeleteBotVersionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBotVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bot_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_intent` with what `handler` returns for its input.
+    pub fn with_delete_intent<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(DeleteIntentRequest) -> ::std::result::Result<(), RusotoError<DeleteIntentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_intent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_intent_version` with what `handler` returns for its input.
+    pub fn with_delete_intent_version<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                DeleteIntentVersionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteIntentVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_intent_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_slot_type` with what `handler` returns for its input.
+    pub fn with_delete_slot_type<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(DeleteSlotTypeRequest) -> ::std::result::Result<(), RusotoError<DeleteSlotTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_slot_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_slot_type_version` with what `handler` returns for its input.
+    pub fn with_delete_slot_type_version<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                DeleteSlotTypeVersionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteSlotTypeVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_slot_type_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_utterances` with what `handler` returns for its input.
+    pub fn with_delete_utterances<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                DeleteUtterancesRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteUtterancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_utterances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot` with what `handler` returns for its input.
+    pub fn with_get_bot<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(GetBotRequest) -> ::std::result::Result<GetBotResponse, RusotoError<GetBotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot_alias` with what `handler` returns for its input.
+    pub fn with_get_bot_alias<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBotAliasRequest,
+            )
+                -> ::std::result::Result<GetBotAliasResponse, RusotoError<GetBotAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot_aliases` with what `handler` returns for its input.
+    pub fn with_get_bot_aliases<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBotAliasesRequest,
+            )
+                -> ::std::result::Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot_aliases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot_channel_association` with what `handler` returns for its input.
+    pub fn with_get_bot_channel_association<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBotChannelAssociationRequest,
+            ) -> ::std::result::Result<
+                GetBotChannelAssociationResponse,
+                RusotoError<GetBotChannelAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot_channel_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot_channel_associations` with what `handler` returns for its input.
+    pub fn with_get_bot_channel_associations<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBotChannelAssociationsRequest,
+            ) -> ::std::result::Result<
+                GetBotChannelAssociationsResponse,
+                RusotoError<GetBotChannelAssociationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot_channel_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bot_versions` with what `handler` returns for its input.
+    pub fn with_get_bot_versions<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBotVersionsRequest,
+            )
+                -> ::std::result::Result<GetBotVersionsResponse, RusotoError<GetBotVersionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bot_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bots` with what `handler` returns for its input.
+    pub fn with_get_bots<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(GetBotsRequest) -> ::std::result::Result<GetBotsResponse, RusotoError<GetBotsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_builtin_intent` with what `handler` returns for its input.
+    pub fn with_get_builtin_intent<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBuiltinIntentRequest,
+            ) -> ::std::result::Result<
+                GetBuiltinIntentResponse,
+                RusotoError<GetBuiltinIntentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_builtin_intent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_builtin_intents` with what `handler` returns for its input.
+    pub fn with_get_builtin_intents<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBuiltinIntentsRequest,
+            ) -> ::std::result::Result<
+                GetBuiltinIntentsResponse,
+                RusotoError<GetBuiltinIntentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_builtin_intents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_builtin_slot_types` with what `handler` returns for its input.
+    pub fn with_get_builtin_slot_types<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetBuiltinSlotTypesRequest,
+            ) -> ::std::result::Result<
+                GetBuiltinSlotTypesResponse,
+                RusotoError<GetBuiltinSlotTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_builtin_slot_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_export` with what `handler` returns for its input.
+    pub fn with_get_export<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetExportRequest,
+            ) -> ::std::result::Result<GetExportResponse, RusotoError<GetExportError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_export = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_import` with what `handler` returns for its input.
+    pub fn with_get_import<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetImportRequest,
+            ) -> ::std::result::Result<GetImportResponse, RusotoError<GetImportError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_import = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_intent` with what `handler` returns for its input.
+    pub fn with_get_intent<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetIntentRequest,
+            ) -> ::std::result::Result<GetIntentResponse, RusotoError<GetIntentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_intent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_intent_versions` with what `handler` returns for its input.
+    pub fn with_get_intent_versions<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetIntentVersionsRequest,
+            ) -> ::std::result::Result<
+                GetIntentVersionsResponse,
+                RusotoError<GetIntentVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_intent_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_intents` with what `handler` returns for its input.
+    pub fn with_get_intents<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetIntentsRequest,
+            )
+                -> ::std::result::Result<GetIntentsResponse, RusotoError<GetIntentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_intents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_slot_type` with what `handler` returns for its input.
+    pub fn with_get_slot_type<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetSlotTypeRequest,
+            )
+                -> ::std::result::Result<GetSlotTypeResponse, RusotoError<GetSlotTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_slot_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_slot_type_versions` with what `handler` returns for its input.
+    pub fn with_get_slot_type_versions<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetSlotTypeVersionsRequest,
+            ) -> ::std::result::Result<
+                GetSlotTypeVersionsResponse,
+                RusotoError<GetSlotTypeVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_slot_type_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_slot_types` with what `handler` returns for its input.
+    pub fn with_get_slot_types<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetSlotTypesRequest,
+            )
+                -> ::std::result::Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_slot_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_utterances_view` with what `handler` returns for its input.
+    pub fn with_get_utterances_view<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                GetUtterancesViewRequest,
+            ) -> ::std::result::Result<
+                GetUtterancesViewResponse,
+                RusotoError<GetUtterancesViewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_utterances_view = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bot` with what `handler` returns for its input.
+    pub fn with_put_bot<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(PutBotRequest) -> ::std::result::Result<PutBotResponse, RusotoError<PutBotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bot_alias` with what `handler` returns for its input.
+    pub fn with_put_bot_alias<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                PutBotAliasRequest,
+            )
+                -> ::std::result::Result<PutBotAliasResponse, RusotoError<PutBotAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bot_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_intent` with what `handler` returns for its input.
+    pub fn with_put_intent<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                PutIntentRequest,
+            ) -> ::std::result::Result<PutIntentResponse, RusotoError<PutIntentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_intent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_slot_type` with what `handler` returns for its input.
+    pub fn with_put_slot_type<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                PutSlotTypeRequest,
+            )
+                -> ::std::result::Result<PutSlotTypeResponse, RusotoError<PutSlotTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_slot_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_import` with what `handler` returns for its input.
+    pub fn with_start_import<F>(mut self, handler: F) -> MockLexModels
+    where
+        F: Fn(
+                StartImportRequest,
+            )
+                -> ::std::result::Result<StartImportResponse, RusotoError<StartImportError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_import = Some(Box::new(handler));
+        self
+    }
+}
 
-    /// <p> Returns a list of all of the channels associated with the specified bot. </p> <p>The <code>GetBotChannelAssociations</code> operation requires permissions for the <code>lex:GetBotChannelAssociations</code> action.</p>
+#[cfg(any(test, feature = "mocks"))]
+impl LexModels for MockLexModels {
+    fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> RusotoFuture<CreateBotVersionResponse, CreateBotVersionError> {
+        match self.create_bot_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for create_bot_version"),
+        }
+    }
+    fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> RusotoFuture<CreateIntentVersionResponse, CreateIntentVersionError> {
+        match self.create_intent_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for create_intent_version"),
+        }
+    }
+    fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> RusotoFuture<CreateSlotTypeVersionResponse, CreateSlotTypeVersionError> {
+        match self.create_slot_type_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for create_slot_type_version"),
+        }
+    }
+    fn delete_bot(&self, input: DeleteBotRequest) -> RusotoFuture<(), DeleteBotError> {
+        match self.delete_bot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_bot"),
+        }
+    }
+    fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> RusotoFuture<(), DeleteBotAliasError> {
+        match self.delete_bot_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_bot_alias"),
+        }
+    }
+    fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> RusotoFuture<(), DeleteBotChannelAssociationError> {
+        match self.delete_bot_channel_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_bot_channel_association"),
+        }
+    }
+    fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> RusotoFuture<(), DeleteBotVersionError> {
+        match self.delete_bot_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_bot_version"),
+        }
+    }
+    fn delete_intent(&self, input: DeleteIntentRequest) -> RusotoFuture<(), DeleteIntentError> {
+        match self.delete_intent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_intent"),
+        }
+    }
+    fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> RusotoFuture<(), DeleteIntentVersionError> {
+        match self.delete_intent_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_intent_version"),
+        }
+    }
+    fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeError> {
+        match self.delete_slot_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_slot_type"),
+        }
+    }
+    fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeVersionError> {
+        match self.delete_slot_type_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_slot_type_version"),
+        }
+    }
+    fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> RusotoFuture<(), DeleteUtterancesError> {
+        match self.delete_utterances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for delete_utterances"),
+        }
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        match self.get_bot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bot"),
+        }
+    }
+    fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> RusotoFuture<GetBotAliasResponse, GetBotAliasError> {
+        match self.get_bot_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bot_alias"),
+        }
+    }
+    fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> RusotoFuture<GetBotAliasesResponse, GetBotAliasesError> {
+        match self.get_bot_aliases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bot_aliases"),
+        }
+    }
+    fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationResponse, GetBotChannelAssociationError> {
+        match self.get_bot_channel_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bot_channel_association"),
+        }
+    }
     fn get_bot_channel_associations(
         &self,
         input: GetBotChannelAssociationsRequest,
     ) -> RusotoFuture<GetBotChannelAssociationsResponse, GetBotChannelAssociationsError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/aliases/{alias_name}/channels/",
-            alias_name = input.bot_alias,
-            bot_name = input.bot_name
-        );
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.name_contains {
-            params.put("nameContains", x);
+        match self.get_bot_channel_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bot_channel_associations"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotChannelAssociationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBotChannelAssociationsError::from_response(response))
-                }))
-            }
-        })
     }
-
-    /// <p>Gets information about all of the versions of a bot.</p> <p>The <code>GetBotVersions</code> operation returns a <code>BotMetadata</code> object for each version of a bot. For example, if a bot has three numbered versions, the <code>GetBotVersions</code> operation returns four <code>BotMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetBotVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetBotVersions</code> action.</p>
     fn get_bot_versions(
         &self,
         input: GetBotVersionsRequest,
     ) -> RusotoFuture<GetBotVersionsResponse, GetBotVersionsError> {
-        let request_uri = format!("/bots/{name}/versions/", name = input.name);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
+        match self.get_bot_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bot_versions"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBotVersionsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns bot information as follows: </p> <ul> <li> <p>If you provide the <code>nameContains</code> field, the response includes information for the <code>$LATEST</code> version of all bots whose name contains the specified string.</p> </li> <li> <p>If you don't specify the <code>nameContains</code> field, the operation returns information about the <code>$LATEST</code> version of all of your bots.</p> </li> </ul> <p>This operation requires permission for the <code>lex:GetBots</code> action.</p>
     fn get_bots(&self, input: GetBotsRequest) -> RusotoFuture<GetBotsResponse, GetBotsError> {
-        let request_uri = "/bots/";
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.name_contains {
-            params.put("nameContains", x);
+        match self.get_bots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_bots"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBotsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBotsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns information about a built-in intent.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntent</code> action.</p>
     fn get_builtin_intent(
         &self,
         input: GetBuiltinIntentRequest,
     ) -> RusotoFuture<GetBuiltinIntentResponse, GetBuiltinIntentError> {
-        let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBuiltinIntentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBuiltinIntentError::from_response(response))),
-                )
-            }
-        })
+        match self.get_builtin_intent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_builtin_intent"),
+        }
     }
-
-    /// <p>Gets a list of built-in intents that meet the specified criteria.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntents</code> action.</p>
     fn get_builtin_intents(
         &self,
-        input: GetBuiltinIntentsRequest,
-    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
-        let request_uri = "/builtins/intents/";
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.locale {
-            params.put("locale", x);
-        }
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        if let Some(ref x) = input.signature_contains {
-            params.put("signatureContains", x);
+        input: GetBuiltinIntentsRequest,
+    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
+        match self.get_builtin_intents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_builtin_intents"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBuiltinIntentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBuiltinIntentsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Gets a list of built-in slot types that meet the specified criteria.</p> <p>For a list of built-in slot types, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/slot-type-reference">Slot Type Reference</a> in the <i>Alexa Skills Kit</i>.</p> <p>This operation requires permission for the <code>lex:GetBuiltInSlotTypes</code> action.</p>
     fn get_builtin_slot_types(
         &self,
         input: GetBuiltinSlotTypesRequest,
     ) -> RusotoFuture<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError> {
-        let request_uri = "/builtins/slottypes/";
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.locale {
-            params.put("locale", x);
-        }
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        if let Some(ref x) = input.signature_contains {
-            params.put("signatureContains", x);
+        match self.get_builtin_slot_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_builtin_slot_types"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBuiltinSlotTypesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetBuiltinSlotTypesError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Exports the contents of a Amazon Lex resource in a specified format. </p>
     fn get_export(
         &self,
         input: GetExportRequest,
     ) -> RusotoFuture<GetExportResponse, GetExportError> {
-        let request_uri = "/exports/";
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        params.put("exportType", &input.export_type);
-        params.put("name", &input.name);
-        params.put("resourceType", &input.resource_type);
-        params.put("version", &input.version);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetExportResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetExportError::from_response(response))),
-                )
-            }
-        })
+        match self.get_export {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_export"),
+        }
     }
-
-    /// <p>Gets information about an import job started with the <code>StartImport</code> operation.</p>
     fn get_import(
         &self,
         input: GetImportRequest,
     ) -> RusotoFuture<GetImportResponse, GetImportError> {
-        let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetImportResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetImportError::from_response(response))),
-                )
-            }
-        })
+        match self.get_import {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_import"),
+        }
     }
-
-    /// <p> Returns information about an intent. In addition to the intent name, you must specify the intent version. </p> <p> This operation requires permissions to perform the <code>lex:GetIntent</code> action. </p>
     fn get_intent(
         &self,
         input: GetIntentRequest,
     ) -> RusotoFuture<GetIntentResponse, GetIntentError> {
-        let request_uri = format!(
-            "/intents/{name}/versions/{version}",
-            name = input.name,
-            version = input.version
-        );
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIntentError::from_response(response))),
-                )
-            }
-        })
+        match self.get_intent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_intent"),
+        }
     }
-
-    /// <p>Gets information about all of the versions of an intent.</p> <p>The <code>GetIntentVersions</code> operation returns an <code>IntentMetadata</code> object for each version of an intent. For example, if an intent has three numbered versions, the <code>GetIntentVersions</code> operation returns four <code>IntentMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetIntentVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetIntentVersions</code> action.</p>
     fn get_intent_versions(
         &self,
         input: GetIntentVersionsRequest,
     ) -> RusotoFuture<GetIntentVersionsResponse, GetIntentVersionsError> {
-        let request_uri = format!("/intents/{name}/versions/", name = input.name);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
+        match self.get_intent_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_intent_versions"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntentVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIntentVersionsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns intent information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all intents that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all intents. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetIntents</code> action. </p>
     fn get_intents(
         &self,
         input: GetIntentsRequest,
     ) -> RusotoFuture<GetIntentsResponse, GetIntentsError> {
-        let request_uri = "/intents/";
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.name_contains {
-            params.put("nameContains", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
+        match self.get_intents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_intents"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIntentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIntentsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns information about a specific version of a slot type. In addition to specifying the slot type name, you must specify the slot type version.</p> <p>This operation requires permissions for the <code>lex:GetSlotType</code> action.</p>
     fn get_slot_type(
-        &self,
-        input: GetSlotTypeRequest,
-    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
-        let request_uri = format!(
-            "/slottypes/{name}/versions/{version}",
-            name = input.name,
-            version = input.version
-        );
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSlotTypeResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSlotTypeError::from_response(response))),
-                )
-            }
-        })
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
+        match self.get_slot_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_slot_type"),
+        }
     }
-
-    /// <p>Gets information about all versions of a slot type.</p> <p>The <code>GetSlotTypeVersions</code> operation returns a <code>SlotTypeMetadata</code> object for each version of a slot type. For example, if a slot type has three numbered versions, the <code>GetSlotTypeVersions</code> operation returns four <code>SlotTypeMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetSlotTypeVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetSlotTypeVersions</code> action.</p>
     fn get_slot_type_versions(
         &self,
         input: GetSlotTypeVersionsRequest,
     ) -> RusotoFuture<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError> {
-        let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
+        match self.get_slot_type_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_slot_type_versions"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSlotTypeVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetSlotTypeVersionsError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Returns slot type information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all slot types that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all slot types. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetSlotTypes</code> action. </p>
     fn get_slot_types(
         &self,
         input: GetSlotTypesRequest,
     ) -> RusotoFuture<GetSlotTypesResponse, GetSlotTypesError> {
-        let request_uri = "/slottypes/";
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.name_contains {
-            params.put("nameContains", x);
+        match self.get_slot_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_slot_types"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSlotTypesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSlotTypesError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Use the <code>GetUtterancesView</code> operation to get information about the utterances that your users have made to your bot. You can use this list to tune the utterances that your bot responds to.</p> <p>For example, say that you have created a bot to order flowers. After your users have used your bot for a while, use the <code>GetUtterancesView</code> operation to see the requests that they have made and whether they have been successful. You might find that the utterance "I want flowers" is not being recognized. You could add this utterance to the <code>OrderFlowers</code> intent so that your bot recognizes that utterance.</p> <p>After you publish a new version of a bot, you can get information about the old version and the new so that you can compare the performance across the two versions. </p> <note> <p>Utterance statistics are generated once a day. Data is available for the last 15 days. You can request information for up to 5 versions in each request. The response contains information about a maximum of 100 utterances for each version.</p> </note> <p>This operation requires permissions for the <code>lex:GetUtterancesView</code> action.</p>
     fn get_utterances_view(
         &self,
         input: GetUtterancesViewRequest,
     ) -> RusotoFuture<GetUtterancesViewResponse, GetUtterancesViewError> {
-        let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
-
-        let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-
-        let mut params = Params::new();
-        for item in input.bot_versions.iter() {
-            params.put("bot_versions", item);
+        match self.get_utterances_view {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for get_utterances_view"),
         }
-        params.put("status_type", &input.status_type);
-        params.put("view", "aggregation");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetUtterancesViewResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetUtterancesViewError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Creates an Amazon Lex conversational bot or replaces an existing bot. When you create or update a bot you are only required to specify a name, a locale, and whether the bot is directed toward children under age 13. You can use this to add intents later, or to remove intents from an existing bot. When you create a bot with the minimum information, the bot is created or updated but Amazon Lex returns the <code/> response <code>FAILED</code>. You can build the bot after you add one or more intents. For more information about Amazon Lex bots, see <a>how-it-works</a>. </p> <p>If you specify the name of an existing bot, the fields in the request replace the existing values in the <code>$LATEST</code> version of the bot. Amazon Lex removes any fields that you don't provide values for in the request, except for the <code>idleTTLInSeconds</code> and <code>privacySettings</code> fields, which are set to their default values. If you don't specify values for required fields, Amazon Lex throws an exception.</p> <p>This operation requires permissions for the <code>lex:PutBot</code> action. For more information, see <a>auth-and-access-control</a>.</p>
     fn put_bot(&self, input: PutBotRequest) -> RusotoFuture<PutBotResponse, PutBotError> {
-        let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
-
-        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutBotResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutBotError::from_response(response))),
-                )
-            }
-        })
+        match self.put_bot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for put_bot"),
+        }
     }
-
-    /// <p>Creates an alias for the specified version of the bot or replaces an alias for the specified bot. To change the version of the bot that the alias points to, replace the alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:PutBotAlias</code> action. </p>
     fn put_bot_alias(
         &self,
         input: PutBotAliasRequest,
     ) -> RusotoFuture<PutBotAliasResponse, PutBotAliasError> {
-        let request_uri = format!(
-            "/bots/{bot_name}/aliases/{name}",
-            bot_name = input.bot_name,
-            name = input.name
-        );
-
-        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutBotAliasResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutBotAliasError::from_response(response))),
-                )
-            }
-        })
+        match self.put_bot_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for put_bot_alias"),
+        }
     }
-
-    /// <p>Creates an intent or replaces an existing intent.</p> <p>To define the interaction between the user and your bot, you use one or more intents. For a pizza ordering bot, for example, you would create an <code>OrderPizza</code> intent. </p> <p>To create an intent or replace an existing intent, you must provide the following:</p> <ul> <li> <p>Intent name. For example, <code>OrderPizza</code>.</p> </li> <li> <p>Sample utterances. For example, "Can I order a pizza, please." and "I want to order a pizza."</p> </li> <li> <p>Information to be gathered. You specify slot types for the information that your bot will request from the user. You can specify standard slot types, such as a date or a time, or custom slot types such as the size and crust of a pizza.</p> </li> <li> <p>How the intent will be fulfilled. You can provide a Lambda function or configure the intent to return the intent information to the client application. If you use a Lambda function, when all of the intent information is available, Amazon Lex invokes your Lambda function. If you configure your intent to return the intent information to the client application. </p> </li> </ul> <p>You can specify other optional information in the request, such as:</p> <ul> <li> <p>A confirmation prompt to ask the user to confirm an intent. For example, "Shall I order your pizza?"</p> </li> <li> <p>A conclusion statement to send to the user after the intent has been fulfilled. For example, "I placed your pizza order."</p> </li> <li> <p>A follow-up prompt that asks the user for additional activity. For example, asking "Do you want to order a drink with your pizza?"</p> </li> </ul> <p>If you specify an existing intent name to update the intent, Amazon Lex replaces the values in the <code>$LATEST</code> version of the intent with the values in the request. Amazon Lex removes fields that you don't provide in the request. If you don't specify the required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of an intent, the <code>status</code> field of any bot that uses the <code>$LATEST</code> version of the intent is set to <code>NOT_BUILT</code>.</p> <p>For more information, see <a>how-it-works</a>.</p> <p>This operation requires permissions for the <code>lex:PutIntent</code> action.</p>
     fn put_intent(
         &self,
         input: PutIntentRequest,
     ) -> RusotoFuture<PutIntentResponse, PutIntentError> {
-        let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
-
-        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutIntentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutIntentError::from_response(response))),
-                )
-            }
-        })
+        match self.put_intent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for put_intent"),
+        }
     }
-
-    /// <p>Creates a custom slot type or replaces an existing custom slot type.</p> <p>To create a custom slot type, specify a name for the slot type and a set of enumeration values, which are the values that a slot of this type can assume. For more information, see <a>how-it-works</a>.</p> <p>If you specify the name of an existing slot type, the fields in the request replace the existing values in the <code>$LATEST</code> version of the slot type. Amazon Lex removes the fields that you don't provide in the request. If you don't specify required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of a slot type, if a bot uses the <code>$LATEST</code> version of an intent that contains the slot type, the bot's <code>status</code> field is set to <code>NOT_BUILT</code>.</p> <p>This operation requires permissions for the <code>lex:PutSlotType</code> action.</p>
     fn put_slot_type(
         &self,
         input: PutSlotTypeRequest,
     ) -> RusotoFuture<PutSlotTypeResponse, PutSlotTypeError> {
-        let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
-
-        let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutSlotTypeResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutSlotTypeError::from_response(response))),
-                )
-            }
-        })
+        match self.put_slot_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for put_slot_type"),
+        }
     }
-
-    /// <p>Starts a job to import a resource to Amazon Lex.</p>
     fn start_import(
         &self,
         input: StartImportRequest,
     ) -> RusotoFuture<StartImportResponse, StartImportError> {
-        let request_uri = "/imports/";
-
-        let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("models.lex".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartImportResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartImportError::from_response(response))),
-                )
-            }
-        })
+        match self.start_import {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexModels has no handler for start_import"),
+        }
     }
 }
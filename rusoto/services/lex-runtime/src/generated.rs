@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Represents an option to be shown on the client platform (Facebook, Slack, etc.)</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Button {
     /// <p>Text that is visible to the user on the button.</p>
     #[serde(rename = "text")]
@@ -36,6 +37,7 @@ pub struct Button {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSessionRequest {
     /// <p>The alias in use for the bot that contains the session data.</p>
     #[serde(rename = "botAlias")]
@@ -48,8 +50,24 @@ pub struct DeleteSessionRequest {
     pub user_id: String,
 }
 
+impl Validate for DeleteSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[0-9a-zA-Z._:-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSessionResponse {
     /// <p>The alias in use for the bot associated with the session data.</p>
     #[serde(rename = "botAlias")]
@@ -101,9 +119,22 @@ pub struct DialogAction {
     pub type_: String,
 }
 
+impl Validate for DialogAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.message {
+            errors.check_length(
+                &field_path(path, "message"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents an option rendered to the user when a prompt is shown. It could be an image, a button, a link, or text. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenericAttachment {
     /// <p>The URL of an attachment to the response card.</p>
     #[serde(rename = "attachmentLinkUrl")]
@@ -128,6 +159,7 @@ pub struct GenericAttachment {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSessionRequest {
     /// <p>The alias in use for the bot that contains the session data.</p>
     #[serde(rename = "botAlias")]
@@ -140,8 +172,24 @@ pub struct GetSessionRequest {
     pub user_id: String,
 }
 
+impl Validate for GetSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[0-9a-zA-Z._:-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSessionResponse {
     /// <p>Describes the current state of the bot.</p>
     #[serde(rename = "dialogAction")]
@@ -163,7 +211,7 @@ pub struct GetSessionResponse {
 
 /// <p>Provides information about the state of an intent. You can use this information to get the current state of an intent so that you can process the intent, or so that you can return the intent to its previous state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IntentSummary {
     /// <p><p>The status of the intent after the user responds to the confirmation prompt. If the user confirms the intent, Amazon Lex sets this field to <code>Confirmed</code>. If the user denies the intent, Amazon Lex sets this value to <code>Denied</code>. The possible values are:</p> <ul> <li> <p> <code>Confirmed</code> - The user has responded &quot;Yes&quot; to the confirmation prompt, confirming that the intent is complete and that it is ready to be fulfilled.</p> </li> <li> <p> <code>Denied</code> - The user has responded &quot;No&quot; to the confirmation prompt.</p> </li> <li> <p> <code>None</code> - The user has never been prompted for confirmation; or, the user was prompted but did not confirm or deny the prompt.</p> </li> </ul></p>
     #[serde(rename = "confirmationStatus")]
@@ -191,6 +239,7 @@ pub struct IntentSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PostContentRequest {
     /// <p><p> You pass this value as the <code>Accept</code> HTTP header. </p> <p> The message Amazon Lex returns in the response can be either text or speech based on the <code>Accept</code> HTTP header value in the request. </p> <ul> <li> <p> If the value is <code>text/plain; charset=utf-8</code>, Amazon Lex returns text in the response. </p> </li> <li> <p> If the value begins with <code>audio/</code>, Amazon Lex returns speech in the response. Amazon Lex uses Amazon Polly to generate the speech (using the configuration you specified in the <code>Accept</code> header). For example, if you specify <code>audio/mpeg</code> as the value, Amazon Lex returns speech in the MPEG format.</p> </li> <li> <p>If the value is <code>audio/pcm</code>, the speech returned is <code>audio/pcm</code> in 16-bit, little endian format. </p> </li> <li> <p>The following are the accepted values:</p> <ul> <li> <p>audio/mpeg</p> </li> <li> <p>audio/ogg</p> </li> <li> <p>audio/pcm</p> </li> <li> <p>text/plain; charset=utf-8</p> </li> <li> <p>audio/* (defaults to mpeg)</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "accept")]
@@ -226,6 +275,22 @@ pub struct PostContentRequest {
     pub user_id: String,
 }
 
+impl Validate for PostContentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[0-9a-zA-Z._:-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PostContentResponse {
     /// <p>The prompt (or statement) to convey to the user. This is based on the bot configuration and context. For example, if Amazon Lex did not understand the user intent, it sends the <code>clarificationPrompt</code> configured for the bot. If the intent requires confirmation before taking the fulfillment action, it sends the <code>confirmationPrompt</code>. Another example: Suppose that the Lambda function successfully fulfilled the intent, and sent a message to convey to the user. Then Amazon Lex sends that message in the response. </p>
@@ -251,6 +316,7 @@ pub struct PostContentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PostTextRequest {
     /// <p>The alias of the Amazon Lex bot.</p>
     #[serde(rename = "botAlias")]
@@ -274,8 +340,30 @@ pub struct PostTextRequest {
     pub user_id: String,
 }
 
+impl Validate for PostTextRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "input_text"),
+            self.input_text.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[0-9a-zA-Z._:-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PostTextResponse {
     /// <p><p> Identifies the current state of the user interaction. Amazon Lex returns one of the following values as <code>dialogState</code>. The client can optionally use this information to customize the user interface. </p> <ul> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit user intent. </p> <p>For example, a user might utter an intent (&quot;I want to order a pizza&quot;). If Amazon Lex cannot infer the user intent from this utterance, it will return this dialogState.</p> </li> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response. </p> <p> For example, Amazon Lex wants user confirmation before fulfilling an intent. </p> <p>Instead of a simple &quot;yes&quot; or &quot;no,&quot; a user might respond with additional information. For example, &quot;yes, but make it thick crust pizza&quot; or &quot;no, I want to order a drink&quot;. Amazon Lex can process such additional information (in these examples, update the crust type slot value, or change intent from OrderPizza to OrderDrink).</p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting a slot value for the current intent. </p> <p>For example, suppose that in the response Amazon Lex sends this message: &quot;What size pizza would you like?&quot;. A user might reply with the slot value (e.g., &quot;medium&quot;). The user might also provide additional information in the response (e.g., &quot;medium thick crust pizza&quot;). Amazon Lex can process such additional information appropriately. </p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function configured for the intent has successfully fulfilled the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the intent. </p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user failed. </p> <p> This can happen for various reasons including that the user did not provide an appropriate response to prompts from the service (you can configure how many times Amazon Lex can prompt a user for specific information), or the Lambda function failed to fulfill the intent. </p> </li> </ul></p>
     #[serde(rename = "dialogState")]
@@ -312,6 +400,7 @@ pub struct PostTextResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutSessionRequest {
     /// <p><p>The message that Amazon Lex returns in the response can be either text or speech based depending on the value of this field.</p> <ul> <li> <p>If the value is <code>text/plain; charset=utf-8</code>, Amazon Lex returns text in the response.</p> </li> <li> <p>If the value begins with <code>audio/</code>, Amazon Lex returns speech in the response. Amazon Lex uses Amazon Polly to generate the speech in the configuration that you specify. For example, if you specify <code>audio/mpeg</code> as the value, Amazon Lex returns speech in the MPEG format.</p> </li> <li> <p>If the value is <code>audio/pcm</code>, the speech is returned as <code>audio/pcm</code> in 16-bit, little endian format.</p> </li> <li> <p>The following are the accepted values:</p> <ul> <li> <p> <code>audio/mpeg</code> </p> </li> <li> <p> <code>audio/ogg</code> </p> </li> <li> <p> <code>audio/pcm</code> </p> </li> <li> <p> <code>audio/*</code> (defaults to mpeg)</p> </li> <li> <p> <code>text/plain; charset=utf-8</code> </p> </li> </ul> </li> </ul></p>
     #[serde(rename = "accept")]
@@ -336,6 +425,25 @@ pub struct PutSessionRequest {
     pub user_id: String,
 }
 
+impl Validate for PutSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dialog_action {
+            value.validate_at(&field_path(path, "dialog_action"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(2),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[0-9a-zA-Z._:-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PutSessionResponse {
     /// <p>The audio version of the message to convey to the user.</p>
@@ -362,7 +470,7 @@ pub struct PutSessionResponse {
 
 /// <p>If you configure a response card when creating your bots, Amazon Lex substitutes the session attributes and slot values that are available, and then returns it. The response card can also come from a Lambda function ( <code>dialogCodeHook</code> and <code>fulfillmentActivity</code> on an intent).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResponseCard {
     /// <p>The content type of the response.</p>
     #[serde(rename = "contentType")]
@@ -412,7 +520,12 @@ impl DeleteSessionError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteSessionError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -464,7 +577,12 @@ impl GetSessionError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetSessionError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -550,7 +668,12 @@ impl PostContentError {
                 "UnsupportedMediaTypeException" => {
                     return RusotoError::Service(PostContentError::UnsupportedMediaType(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -628,7 +751,12 @@ impl PostTextError {
                 "NotFoundException" => {
                     return RusotoError::Service(PostTextError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -703,7 +831,12 @@ impl PutSessionError {
                 "NotFoundException" => {
                     return RusotoError::Service(PutSessionError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -758,6 +891,94 @@ pub trait LexRuntime {
         input: PutSessionRequest,
     ) -> RusotoFuture<PutSessionResponse, PutSessionError>;
 }
+const _: Option<&dyn LexRuntime> = None;
+impl<'a, T: LexRuntime + ?Sized> LexRuntime for &'a T {
+    fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> RusotoFuture<DeleteSessionResponse, DeleteSessionError> {
+        (**self).delete_session(input)
+    }
+    fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> RusotoFuture<GetSessionResponse, GetSessionError> {
+        (**self).get_session(input)
+    }
+    fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> RusotoFuture<PostContentResponse, PostContentError> {
+        (**self).post_content(input)
+    }
+    fn post_text(&self, input: PostTextRequest) -> RusotoFuture<PostTextResponse, PostTextError> {
+        (**self).post_text(input)
+    }
+    fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> RusotoFuture<PutSessionResponse, PutSessionError> {
+        (**self).put_session(input)
+    }
+}
+impl<T: LexRuntime + ?Sized> LexRuntime for Box<T> {
+    fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> RusotoFuture<DeleteSessionResponse, DeleteSessionError> {
+        (**self).delete_session(input)
+    }
+    fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> RusotoFuture<GetSessionResponse, GetSessionError> {
+        (**self).get_session(input)
+    }
+    fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> RusotoFuture<PostContentResponse, PostContentError> {
+        (**self).post_content(input)
+    }
+    fn post_text(&self, input: PostTextRequest) -> RusotoFuture<PostTextResponse, PostTextError> {
+        (**self).post_text(input)
+    }
+    fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> RusotoFuture<PutSessionResponse, PutSessionError> {
+        (**self).put_session(input)
+    }
+}
+impl<T: LexRuntime + ?Sized> LexRuntime for ::std::sync::Arc<T> {
+    fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> RusotoFuture<DeleteSessionResponse, DeleteSessionError> {
+        (**self).delete_session(input)
+    }
+    fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> RusotoFuture<GetSessionResponse, GetSessionError> {
+        (**self).get_session(input)
+    }
+    fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> RusotoFuture<PostContentResponse, PostContentError> {
+        (**self).post_content(input)
+    }
+    fn post_text(&self, input: PostTextRequest) -> RusotoFuture<PostTextResponse, PostTextError> {
+        (**self).post_text(input)
+    }
+    fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> RusotoFuture<PutSessionResponse, PutSessionError> {
+        (**self).put_session(input)
+    }
+}
 /// A client for the Amazon Lex Runtime Service API.
 #[derive(Clone)]
 pub struct LexRuntimeClient {
@@ -801,6 +1022,14 @@ impl LexRuntime for LexRuntimeClient {
         &self,
         input: DeleteSessionRequest,
     ) -> RusotoFuture<DeleteSessionResponse, DeleteSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
             bot_alias = input.bot_alias,
@@ -809,6 +1038,11 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSession");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("userId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -817,6 +1051,7 @@ impl LexRuntime for LexRuntimeClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteSession")
                         .deserialize::<DeleteSessionResponse, _>()?;
 
                     Ok(result)
@@ -837,6 +1072,14 @@ impl LexRuntime for LexRuntimeClient {
         &self,
         input: GetSessionRequest,
     ) -> RusotoFuture<GetSessionResponse, GetSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
             bot_alias = input.bot_alias,
@@ -845,6 +1088,11 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSession");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("userId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -853,6 +1101,7 @@ impl LexRuntime for LexRuntimeClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSession")
                         .deserialize::<GetSessionResponse, _>()?;
 
                     Ok(result)
@@ -873,6 +1122,14 @@ impl LexRuntime for LexRuntimeClient {
         &self,
         input: PostContentRequest,
     ) -> RusotoFuture<PostContentResponse, PostContentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/content",
             bot_alias = input.bot_alias,
@@ -881,6 +1138,10 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostContent");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("userId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -968,6 +1229,14 @@ impl LexRuntime for LexRuntimeClient {
 
     /// <p>Sends user input (text or SSML) to Amazon Lex. Client applications can use this API to send requests to Amazon Lex at runtime. Amazon Lex then interprets the user input using the machine learning model it built for the bot. </p> <p> In response, Amazon Lex returns the next <code>message</code> to convey to the user an optional <code>responseCard</code> to display. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza", Amazon Lex might return a response with a message eliciting slot data (for example, PizzaSize): "What size pizza would you like?" </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to obtain user confirmation "Proceed with the pizza order?". </p> </li> <li> <p> After the user replies to a confirmation prompt with a "yes", Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a user response. For example, a conclusion statement does not require a response. Some messages require only a "yes" or "no" user response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you might use to enhance client behavior, for example, to display the appropriate client user interface. These are the <code>slotToElicit</code>, <code>dialogState</code>, <code>intentName</code>, and <code>slots</code> fields in the response. Consider the following examples: </p> <ul> <li> <p>If the message is to elicit slot data, Amazon Lex returns the following context information:</p> <ul> <li> <p> <code>dialogState</code> set to ElicitSlot </p> </li> <li> <p> <code>intentName</code> set to the intent name in the current context </p> </li> <li> <p> <code>slotToElicit</code> set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>slots</code> set to a map of slots, configured for the intent, with currently known values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>dialogState</code> is set to ConfirmIntent and <code>SlotToElicit</code> is set to null. </p> </li> <li> <p>If the message is a clarification prompt (configured for the intent) that indicates that user intent is not understood, the <code>dialogState</code> is set to ElicitIntent and <code>slotToElicit</code> is set to null. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
     fn post_text(&self, input: PostTextRequest) -> RusotoFuture<PostTextResponse, PostTextError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/text",
             bot_alias = input.bot_alias,
@@ -976,6 +1245,11 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostText");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("userId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -986,6 +1260,7 @@ impl LexRuntime for LexRuntimeClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PostText")
                         .deserialize::<PostTextResponse, _>()?;
 
                     Ok(result)
@@ -1006,6 +1281,14 @@ impl LexRuntime for LexRuntimeClient {
         &self,
         input: PutSessionRequest,
     ) -> RusotoFuture<PutSessionResponse, PutSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/bot/{bot_name}/alias/{bot_alias}/user/{user_id}/session",
             bot_alias = input.bot_alias,
@@ -1014,6 +1297,10 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PutSession");
+        request.add_resource_id("botAlias", &input.bot_alias.to_string());
+        request.add_resource_id("botName", &input.bot_name.to_string());
+        request.add_resource_id("userId", &input.user_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1082,3 +1369,186 @@ impl LexRuntime for LexRuntimeClient {
         })
     }
 }
+
+/// A mock of the Amazon Lex Runtime Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockLexRuntime {
+    delete_session: Option<
+        Box<
+            dyn Fn(
+                    DeleteSessionRequest,
+                )
+                    -> ::std::result::Result<DeleteSessionResponse, RusotoError<DeleteSessionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_session: Option<
+        Box<
+            dyn Fn(
+                    GetSessionRequest,
+                )
+                    -> ::std::result::Result<GetSessionResponse, RusotoError<GetSessionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    post_content: Option<
+        Box<
+            dyn Fn(
+                    PostContentRequest,
+                )
+                    -> ::std::result::Result<PostContentResponse, RusotoError<PostContentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    post_text: Option<
+        Box<
+            dyn Fn(
+                    PostTextRequest,
+                )
+                    -> ::std::result::Result<PostTextResponse, RusotoError<PostTextError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_session: Option<
+        Box<
+            dyn Fn(
+                    PutSessionRequest,
+                )
+                    -> ::std::result::Result<PutSessionResponse, RusotoError<PutSessionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockLexRuntime {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockLexRuntime {
+        Default::default()
+    }
+
+    /// Answers `delete_session` with what `handler` returns for its input.
+    pub fn with_delete_session<F>(mut self, handler: F) -> MockLexRuntime
+    where
+        F: Fn(
+                DeleteSessionRequest,
+            )
+                -> ::std::result::Result<DeleteSessionResponse, RusotoError<DeleteSessionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_session` with what `handler` returns for its input.
+    pub fn with_get_session<F>(mut self, handler: F) -> MockLexRuntime
+    where
+        F: Fn(
+                GetSessionRequest,
+            )
+                -> ::std::result::Result<GetSessionResponse, RusotoError<GetSessionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `post_content` with what `handler` returns for its input.
+    pub fn with_post_content<F>(mut self, handler: F) -> MockLexRuntime
+    where
+        F: Fn(
+                PostContentRequest,
+            )
+                -> ::std::result::Result<PostContentResponse, RusotoError<PostContentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.post_content = Some(Box::new(handler));
+        self
+    }
+    /// Answers `post_text` with what `handler` returns for its input.
+    pub fn with_post_text<F>(mut self, handler: F) -> MockLexRuntime
+    where
+        F: Fn(
+                PostTextRequest,
+            ) -> ::std::result::Result<PostTextResponse, RusotoError<PostTextError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.post_text = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_session` with what `handler` returns for its input.
+    pub fn with_put_session<F>(mut self, handler: F) -> MockLexRuntime
+    where
+        F: Fn(
+                PutSessionRequest,
+            )
+                -> ::std::result::Result<PutSessionResponse, RusotoError<PutSessionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_session = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl LexRuntime for MockLexRuntime {
+    fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> RusotoFuture<DeleteSessionResponse, DeleteSessionError> {
+        match self.delete_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexRuntime has no handler for delete_session"),
+        }
+    }
+    fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> RusotoFuture<GetSessionResponse, GetSessionError> {
+        match self.get_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexRuntime has no handler for get_session"),
+        }
+    }
+    fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> RusotoFuture<PostContentResponse, PostContentError> {
+        match self.post_content {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexRuntime has no handler for post_content"),
+        }
+    }
+    fn post_text(&self, input: PostTextRequest) -> RusotoFuture<PostTextResponse, PostTextError> {
+        match self.post_text {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexRuntime has no handler for post_text"),
+        }
+    }
+    fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> RusotoFuture<PutSessionResponse, PutSessionError> {
+        match self.put_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLexRuntime has no handler for put_session"),
+        }
+    }
+}
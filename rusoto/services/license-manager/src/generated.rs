@@ -25,7 +25,7 @@ use rusoto_core::signature::SignedRequest;
 use serde_json;
 /// <p>Details about license consumption.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConsumedLicenseSummary {
     /// <p>Number of licenses consumed by a resource.</p>
     #[serde(rename = "ConsumedLicenses")]
@@ -38,6 +38,7 @@ pub struct ConsumedLicenseSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLicenseConfigurationRequest {
     /// <p>Human-friendly description of the license configuration.</p>
     #[serde(rename = "Description")]
@@ -68,7 +69,7 @@ pub struct CreateLicenseConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLicenseConfigurationResponse {
     /// <p>ARN of the license configuration object after its creation.</p>
     #[serde(rename = "LicenseConfigurationArn")]
@@ -77,6 +78,7 @@ pub struct CreateLicenseConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLicenseConfigurationRequest {
     /// <p>Unique ID of the configuration object to delete.</p>
     #[serde(rename = "LicenseConfigurationArn")]
@@ -84,11 +86,12 @@ pub struct DeleteLicenseConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLicenseConfigurationResponse {}
 
 /// <p>A filter name and value pair that is used to return a more specific list of results from a describe operation. Filters can be used to match a set of resources by specific criteria, such as tags, attributes, or IDs. The filters supported by a <code>Describe</code> operation are documented with the <code>Describe</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>Name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
@@ -101,6 +104,7 @@ pub struct Filter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLicenseConfigurationRequest {
     /// <p>ARN of the license configuration being requested.</p>
     #[serde(rename = "LicenseConfigurationArn")]
@@ -108,7 +112,7 @@ pub struct GetLicenseConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLicenseConfigurationResponse {
     /// <p>List of summaries for consumed licenses used by various resources.</p>
     #[serde(rename = "ConsumedLicenseSummaryList")]
@@ -169,10 +173,11 @@ pub struct GetLicenseConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetServiceSettingsRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetServiceSettingsResponse {
     /// <p>Indicates whether cross-account discovery has been enabled.</p>
     #[serde(rename = "EnableCrossAccountsDiscovery")]
@@ -194,6 +199,7 @@ pub struct GetServiceSettingsResponse {
 
 /// <p>An inventory filter object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryFilter {
     /// <p>The condition of the filter.</p>
     #[serde(rename = "Condition")]
@@ -209,7 +215,7 @@ pub struct InventoryFilter {
 
 /// <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or VCPU), tenancy (shared tenancy, Amazon EC2 Dedicated Instance, Amazon EC2 Dedicated Host, or any of these), host affinity (how long a VM must be associated with a host), the number of licenses purchased and used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LicenseConfiguration {
     /// <p>List of summaries for licenses consumed by various resources.</p>
     #[serde(rename = "ConsumedLicenseSummaryList")]
@@ -267,7 +273,7 @@ pub struct LicenseConfiguration {
 
 /// <p>Describes a server resource that is associated with a license configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LicenseConfigurationAssociation {
     /// <p>Time when the license configuration was associated with the resource.</p>
     #[serde(rename = "AssociationTime")]
@@ -289,7 +295,7 @@ pub struct LicenseConfigurationAssociation {
 
 /// <p>Contains details of the usage of each resource from the license pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LicenseConfigurationUsage {
     /// <p>Time when the license configuration was initially associated with a resource.</p>
     #[serde(rename = "AssociationTime")]
@@ -326,6 +332,7 @@ pub struct LicenseSpecification {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssociationsForLicenseConfigurationRequest {
     /// <p>ARN of a <code>LicenseConfiguration</code> object.</p>
     #[serde(rename = "LicenseConfigurationArn")]
@@ -341,7 +348,7 @@ pub struct ListAssociationsForLicenseConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssociationsForLicenseConfigurationResponse {
     /// <p>Lists association objects for the license configuration, each containing the association time, number of consumed licenses, resource ARN, resource ID, account ID that owns the resource, resource size, and resource type.</p>
     #[serde(rename = "LicenseConfigurationAssociations")]
@@ -354,6 +361,7 @@ pub struct ListAssociationsForLicenseConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLicenseConfigurationsRequest {
     /// <p>One or more filters.</p>
     #[serde(rename = "Filters")]
@@ -374,7 +382,7 @@ pub struct ListLicenseConfigurationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLicenseConfigurationsResponse {
     /// <p>Array of license configuration objects.</p>
     #[serde(rename = "LicenseConfigurations")]
@@ -387,6 +395,7 @@ pub struct ListLicenseConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLicenseSpecificationsForResourceRequest {
     /// <p>Maximum number of results to return in a single call. To retrieve the remaining results, make another call with the returned <code>NextToken</code> value.</p>
     #[serde(rename = "MaxResults")]
@@ -402,7 +411,7 @@ pub struct ListLicenseSpecificationsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLicenseSpecificationsForResourceResponse {
     /// <p>License configurations associated with a resource.</p>
     #[serde(rename = "LicenseSpecifications")]
@@ -415,6 +424,7 @@ pub struct ListLicenseSpecificationsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceInventoryRequest {
     /// <p>One or more filters.</p>
     #[serde(rename = "Filters")]
@@ -431,7 +441,7 @@ pub struct ListResourceInventoryRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceInventoryResponse {
     /// <p>Token for the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -444,6 +454,7 @@ pub struct ListResourceInventoryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>ARN for the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -451,7 +462,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>List of tags attached to the resource.</p>
     #[serde(rename = "Tags")]
@@ -460,6 +471,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsageForLicenseConfigurationRequest {
     /// <p>List of filters to apply.</p>
     #[serde(rename = "Filters")]
@@ -479,7 +491,7 @@ pub struct ListUsageForLicenseConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsageForLicenseConfigurationResponse {
     /// <p>An array of <code>LicenseConfigurationUsage</code> objects.</p>
     #[serde(rename = "LicenseConfigurationUsageList")]
@@ -493,7 +505,7 @@ pub struct ListUsageForLicenseConfigurationResponse {
 
 /// <p>Summary for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ManagedResourceSummary {
     /// <p>Number of resources associated with licenses.</p>
     #[serde(rename = "AssociationCount")]
@@ -515,7 +527,7 @@ pub struct OrganizationConfiguration {
 
 /// <p>A set of attributes that describe a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceInventory {
     /// <p>The platform of the resource.</p>
     #[serde(rename = "Platform")]
@@ -557,6 +569,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>Resource of the ARN to be tagged.</p>
     #[serde(rename = "ResourceArn")]
@@ -567,10 +580,11 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>ARN of the resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -581,10 +595,11 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLicenseConfigurationRequest {
     /// <p>New human-friendly description of the license configuration.</p>
     #[serde(rename = "Description")]
@@ -616,10 +631,11 @@ pub struct UpdateLicenseConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateLicenseConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLicenseSpecificationsForResourceRequest {
     /// <p>License configuration ARNs to be added to a resource.</p>
     #[serde(rename = "AddLicenseSpecifications")]
@@ -635,10 +651,11 @@ pub struct UpdateLicenseSpecificationsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateLicenseSpecificationsForResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceSettingsRequest {
     /// <p>Activates cross-account discovery.</p>
     #[serde(rename = "EnableCrossAccountsDiscovery")]
@@ -659,7 +676,7 @@ pub struct UpdateServiceSettingsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServiceSettingsResponse {}
 
 /// Errors returned by CreateLicenseConfiguration
@@ -715,7 +732,12 @@ impl CreateLicenseConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -785,7 +807,12 @@ impl DeleteLicenseConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -852,7 +879,12 @@ impl GetLicenseConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -906,7 +938,12 @@ impl GetServiceSettingsError {
                 "ServerInternalException" => {
                     return RusotoError::Service(GetServiceSettingsError::ServerInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -983,7 +1020,12 @@ impl ListAssociationsForLicenseConfigurationError {
                         ListAssociationsForLicenseConfigurationError::ServerInternal(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1058,7 +1100,12 @@ impl ListLicenseConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1128,7 +1175,12 @@ impl ListLicenseSpecificationsForResourceError {
                         ListLicenseSpecificationsForResourceError::ServerInternal(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1205,7 +1257,12 @@ impl ListResourceInventoryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1268,7 +1325,12 @@ impl ListTagsForResourceError {
                 "ServerInternalException" => {
                     return RusotoError::Service(ListTagsForResourceError::ServerInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1344,7 +1406,12 @@ impl ListUsageForLicenseConfigurationError {
                         ListUsageForLicenseConfigurationError::ServerInternal(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1402,7 +1469,12 @@ impl TagResourceError {
                 "ServerInternalException" => {
                     return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1459,7 +1531,12 @@ impl UntagResourceError {
                 "ServerInternalException" => {
                     return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1528,7 +1605,12 @@ impl UpdateLicenseConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1611,7 +1693,12 @@ impl UpdateLicenseSpecificationsForResourceError {
                         UpdateLicenseSpecificationsForResourceError::ServerInternal(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1676,7 +1763,12 @@ impl UpdateServiceSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1799,6 +1891,310 @@ pub trait LicenseManager {
         input: UpdateServiceSettingsRequest,
     ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError>;
 }
+const _: Option<&dyn LicenseManager> = None;
+impl<'a, T: LicenseManager + ?Sized> LicenseManager for &'a T {
+    fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> RusotoFuture<CreateLicenseConfigurationResponse, CreateLicenseConfigurationError> {
+        (**self).create_license_configuration(input)
+    }
+    fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> RusotoFuture<DeleteLicenseConfigurationResponse, DeleteLicenseConfigurationError> {
+        (**self).delete_license_configuration(input)
+    }
+    fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> RusotoFuture<GetLicenseConfigurationResponse, GetLicenseConfigurationError> {
+        (**self).get_license_configuration(input)
+    }
+    fn get_service_settings(
+        &self,
+    ) -> RusotoFuture<GetServiceSettingsResponse, GetServiceSettingsError> {
+        (**self).get_service_settings()
+    }
+    fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> RusotoFuture<
+        ListAssociationsForLicenseConfigurationResponse,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        (**self).list_associations_for_license_configuration(input)
+    }
+    fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> RusotoFuture<ListLicenseConfigurationsResponse, ListLicenseConfigurationsError> {
+        (**self).list_license_configurations(input)
+    }
+    fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        ListLicenseSpecificationsForResourceResponse,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        (**self).list_license_specifications_for_resource(input)
+    }
+    fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> RusotoFuture<ListResourceInventoryResponse, ListResourceInventoryError> {
+        (**self).list_resource_inventory(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> RusotoFuture<ListUsageForLicenseConfigurationResponse, ListUsageForLicenseConfigurationError>
+    {
+        (**self).list_usage_for_license_configuration(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> RusotoFuture<UpdateLicenseConfigurationResponse, UpdateLicenseConfigurationError> {
+        (**self).update_license_configuration(input)
+    }
+    fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        UpdateLicenseSpecificationsForResourceResponse,
+        UpdateLicenseSpecificationsForResourceError,
+    > {
+        (**self).update_license_specifications_for_resource(input)
+    }
+    fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError> {
+        (**self).update_service_settings(input)
+    }
+}
+impl<T: LicenseManager + ?Sized> LicenseManager for Box<T> {
+    fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> RusotoFuture<CreateLicenseConfigurationResponse, CreateLicenseConfigurationError> {
+        (**self).create_license_configuration(input)
+    }
+    fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> RusotoFuture<DeleteLicenseConfigurationResponse, DeleteLicenseConfigurationError> {
+        (**self).delete_license_configuration(input)
+    }
+    fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> RusotoFuture<GetLicenseConfigurationResponse, GetLicenseConfigurationError> {
+        (**self).get_license_configuration(input)
+    }
+    fn get_service_settings(
+        &self,
+    ) -> RusotoFuture<GetServiceSettingsResponse, GetServiceSettingsError> {
+        (**self).get_service_settings()
+    }
+    fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> RusotoFuture<
+        ListAssociationsForLicenseConfigurationResponse,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        (**self).list_associations_for_license_configuration(input)
+    }
+    fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> RusotoFuture<ListLicenseConfigurationsResponse, ListLicenseConfigurationsError> {
+        (**self).list_license_configurations(input)
+    }
+    fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        ListLicenseSpecificationsForResourceResponse,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        (**self).list_license_specifications_for_resource(input)
+    }
+    fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> RusotoFuture<ListResourceInventoryResponse, ListResourceInventoryError> {
+        (**self).list_resource_inventory(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> RusotoFuture<ListUsageForLicenseConfigurationResponse, ListUsageForLicenseConfigurationError>
+    {
+        (**self).list_usage_for_license_configuration(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> RusotoFuture<UpdateLicenseConfigurationResponse, UpdateLicenseConfigurationError> {
+        (**self).update_license_configuration(input)
+    }
+    fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        UpdateLicenseSpecificationsForResourceResponse,
+        UpdateLicenseSpecificationsForResourceError,
+    > {
+        (**self).update_license_specifications_for_resource(input)
+    }
+    fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError> {
+        (**self).update_service_settings(input)
+    }
+}
+impl<T: LicenseManager + ?Sized> LicenseManager for ::std::sync::Arc<T> {
+    fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> RusotoFuture<CreateLicenseConfigurationResponse, CreateLicenseConfigurationError> {
+        (**self).create_license_configuration(input)
+    }
+    fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> RusotoFuture<DeleteLicenseConfigurationResponse, DeleteLicenseConfigurationError> {
+        (**self).delete_license_configuration(input)
+    }
+    fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> RusotoFuture<GetLicenseConfigurationResponse, GetLicenseConfigurationError> {
+        (**self).get_license_configuration(input)
+    }
+    fn get_service_settings(
+        &self,
+    ) -> RusotoFuture<GetServiceSettingsResponse, GetServiceSettingsError> {
+        (**self).get_service_settings()
+    }
+    fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> RusotoFuture<
+        ListAssociationsForLicenseConfigurationResponse,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        (**self).list_associations_for_license_configuration(input)
+    }
+    fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> RusotoFuture<ListLicenseConfigurationsResponse, ListLicenseConfigurationsError> {
+        (**self).list_license_configurations(input)
+    }
+    fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        ListLicenseSpecificationsForResourceResponse,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        (**self).list_license_specifications_for_resource(input)
+    }
+    fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> RusotoFuture<ListResourceInventoryResponse, ListResourceInventoryError> {
+        (**self).list_resource_inventory(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> RusotoFuture<ListUsageForLicenseConfigurationResponse, ListUsageForLicenseConfigurationError>
+    {
+        (**self).list_usage_for_license_configuration(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> RusotoFuture<UpdateLicenseConfigurationResponse, UpdateLicenseConfigurationError> {
+        (**self).update_license_configuration(input)
+    }
+    fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        UpdateLicenseSpecificationsForResourceResponse,
+        UpdateLicenseSpecificationsForResourceError,
+    > {
+        (**self).update_license_specifications_for_resource(input)
+    }
+    fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError> {
+        (**self).update_service_settings(input)
+    }
+}
 /// A client for the AWS License Manager API.
 #[derive(Clone)]
 pub struct LicenseManagerClient {
@@ -1843,6 +2239,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CreateLicenseConfigurationRequest,
     ) -> RusotoFuture<CreateLicenseConfigurationResponse, CreateLicenseConfigurationError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("CreateLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1854,10 +2251,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLicenseConfigurationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateLicenseConfigurationResponse, _>(
+                    response,
+                    "CreateLicenseConfiguration",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(CreateLicenseConfigurationError::from_response(response))
@@ -1872,6 +2269,7 @@ impl LicenseManager for LicenseManagerClient {
         input: DeleteLicenseConfigurationRequest,
     ) -> RusotoFuture<DeleteLicenseConfigurationResponse, DeleteLicenseConfigurationError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("DeleteLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1883,10 +2281,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLicenseConfigurationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteLicenseConfigurationResponse, _>(
+                    response,
+                    "DeleteLicenseConfiguration",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteLicenseConfigurationError::from_response(response))
@@ -1901,6 +2299,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetLicenseConfigurationRequest,
     ) -> RusotoFuture<GetLicenseConfigurationResponse, GetLicenseConfigurationError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("GetLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicenseConfiguration");
@@ -1909,10 +2308,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLicenseConfigurationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetLicenseConfigurationResponse, _>(
+                    response,
+                    "GetLicenseConfiguration",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetLicenseConfigurationError::from_response(response))
@@ -1926,6 +2325,7 @@ impl LicenseManager for LicenseManagerClient {
         &self,
     ) -> RusotoFuture<GetServiceSettingsResponse, GetServiceSettingsError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("GetServiceSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.GetServiceSettings");
@@ -1933,10 +2333,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetServiceSettingsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetServiceSettingsResponse, _>(
+                    response,
+                    "GetServiceSettings",
+                )
             } else {
                 Box::new(
                     response
@@ -1957,6 +2357,7 @@ impl LicenseManager for LicenseManagerClient {
         ListAssociationsForLicenseConfigurationError,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListAssociationsForLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1968,10 +2369,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssociationsForLicenseConfigurationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<
+                    ListAssociationsForLicenseConfigurationResponse,
+                    _,
+                >(response, "ListAssociationsForLicenseConfiguration")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListAssociationsForLicenseConfigurationError::from_response(
@@ -1988,6 +2389,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListLicenseConfigurationsRequest,
     ) -> RusotoFuture<ListLicenseConfigurationsResponse, ListLicenseConfigurationsError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListLicenseConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1999,10 +2401,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLicenseConfigurationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListLicenseConfigurationsResponse, _>(
+                    response,
+                    "ListLicenseConfigurations",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListLicenseConfigurationsError::from_response(response))
@@ -2020,6 +2422,7 @@ impl LicenseManager for LicenseManagerClient {
         ListLicenseSpecificationsForResourceError,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListLicenseSpecificationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2031,10 +2434,7 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLicenseSpecificationsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListLicenseSpecificationsForResourceResponse, _>(response, "ListLicenseSpecificationsForResource")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListLicenseSpecificationsForResourceError::from_response(
@@ -2051,6 +2451,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListResourceInventoryRequest,
     ) -> RusotoFuture<ListResourceInventoryResponse, ListResourceInventoryError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListResourceInventory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.ListResourceInventory");
@@ -2059,10 +2460,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceInventoryResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListResourceInventoryResponse, _>(
+                    response,
+                    "ListResourceInventory",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2079,6 +2480,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.ListTagsForResource");
@@ -2087,10 +2489,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2108,6 +2510,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> RusotoFuture<ListUsageForLicenseConfigurationResponse, ListUsageForLicenseConfigurationError>
     {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("ListUsageForLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2119,10 +2522,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUsageForLicenseConfigurationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListUsageForLicenseConfigurationResponse, _>(
+                    response,
+                    "ListUsageForLicenseConfiguration",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListUsageForLicenseConfigurationError::from_response(
@@ -2139,6 +2542,7 @@ impl LicenseManager for LicenseManagerClient {
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.TagResource");
@@ -2147,10 +2551,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -2168,6 +2572,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.UntagResource");
@@ -2176,10 +2581,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -2197,6 +2602,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UpdateLicenseConfigurationRequest,
     ) -> RusotoFuture<UpdateLicenseConfigurationResponse, UpdateLicenseConfigurationError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateLicenseConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2208,10 +2614,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateLicenseConfigurationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateLicenseConfigurationResponse, _>(
+                    response,
+                    "UpdateLicenseConfiguration",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateLicenseConfigurationError::from_response(response))
@@ -2229,6 +2635,7 @@ impl LicenseManager for LicenseManagerClient {
         UpdateLicenseSpecificationsForResourceError,
     > {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateLicenseSpecificationsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2240,10 +2647,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateLicenseSpecificationsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<
+                    UpdateLicenseSpecificationsForResourceResponse,
+                    _,
+                >(response, "UpdateLicenseSpecificationsForResource")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateLicenseSpecificationsForResourceError::from_response(
@@ -2260,6 +2667,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UpdateServiceSettingsRequest,
     ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError> {
         let mut request = SignedRequest::new("POST", "license-manager", &self.region, "/");
+        request.set_operation("UpdateServiceSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSLicenseManager.UpdateServiceSettings");
@@ -2268,10 +2676,10 @@ impl LicenseManager for LicenseManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServiceSettingsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateServiceSettingsResponse, _>(
+                    response,
+                    "UpdateServiceSettings",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2282,3 +2690,568 @@ impl LicenseManager for LicenseManagerClient {
         })
     }
 }
+
+/// A mock of the AWS License Manager API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockLicenseManager {
+    create_license_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateLicenseConfigurationRequest,
+                ) -> ::std::result::Result<
+                    CreateLicenseConfigurationResponse,
+                    RusotoError<CreateLicenseConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_license_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteLicenseConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteLicenseConfigurationResponse,
+                    RusotoError<DeleteLicenseConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_license_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetLicenseConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetLicenseConfigurationResponse,
+                    RusotoError<GetLicenseConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_service_settings: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetServiceSettingsResponse,
+                    RusotoError<GetServiceSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_associations_for_license_configuration: Option<
+        Box<
+            dyn Fn(
+                    ListAssociationsForLicenseConfigurationRequest,
+                ) -> ::std::result::Result<
+                    ListAssociationsForLicenseConfigurationResponse,
+                    RusotoError<ListAssociationsForLicenseConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_license_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListLicenseConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListLicenseConfigurationsResponse,
+                    RusotoError<ListLicenseConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_license_specifications_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListLicenseSpecificationsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListLicenseSpecificationsForResourceResponse,
+                    RusotoError<ListLicenseSpecificationsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_inventory: Option<
+        Box<
+            dyn Fn(
+                    ListResourceInventoryRequest,
+                ) -> ::std::result::Result<
+                    ListResourceInventoryResponse,
+                    RusotoError<ListResourceInventoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_usage_for_license_configuration: Option<
+        Box<
+            dyn Fn(
+                    ListUsageForLicenseConfigurationRequest,
+                ) -> ::std::result::Result<
+                    ListUsageForLicenseConfigurationResponse,
+                    RusotoError<ListUsageForLicenseConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_license_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateLicenseConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateLicenseConfigurationResponse,
+                    RusotoError<UpdateLicenseConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_license_specifications_for_resource: Option<
+        Box<
+            dyn Fn(
+                    UpdateLicenseSpecificationsForResourceRequest,
+                ) -> ::std::result::Result<
+                    UpdateLicenseSpecificationsForResourceResponse,
+                    RusotoError<UpdateLicenseSpecificationsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_service_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateServiceSettingsRequest,
+                ) -> ::std::result::Result<
+                    UpdateServiceSettingsResponse,
+                    RusotoError<UpdateServiceSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockLicenseManager {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockLicenseManager {
+        Default::default()
+    }
+
+    /// Answers `create_license_configuration` with what `handler` returns for its input.
+    pub fn with_create_license_configuration<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                CreateLicenseConfigurationRequest,
+            ) -> ::std::result::Result<
+                CreateLicenseConfigurationResponse,
+                RusotoError<CreateLicenseConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_license_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_license_configuration` with what `handler` returns for its input.
+    pub fn with_delete_license_configuration<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                DeleteLicenseConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteLicenseConfigurationResponse,
+                RusotoError<DeleteLicenseConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_license_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_license_configuration` with what `handler` returns for its input.
+    pub fn with_get_license_configuration<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                GetLicenseConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetLicenseConfigurationResponse,
+                RusotoError<GetLicenseConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_license_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_service_settings` with what `handler` returns.
+    pub fn with_get_service_settings<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn() -> ::std::result::Result<
+                GetServiceSettingsResponse,
+                RusotoError<GetServiceSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_service_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_associations_for_license_configuration` with what `handler` returns for its input.
+    pub fn with_list_associations_for_license_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockLicenseManager
+    where
+        F: Fn(
+                ListAssociationsForLicenseConfigurationRequest,
+            ) -> ::std::result::Result<
+                ListAssociationsForLicenseConfigurationResponse,
+                RusotoError<ListAssociationsForLicenseConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_associations_for_license_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_license_configurations` with what `handler` returns for its input.
+    pub fn with_list_license_configurations<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                ListLicenseConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListLicenseConfigurationsResponse,
+                RusotoError<ListLicenseConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_license_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_license_specifications_for_resource` with what `handler` returns for its input.
+    pub fn with_list_license_specifications_for_resource<F>(
+        mut self,
+        handler: F,
+    ) -> MockLicenseManager
+    where
+        F: Fn(
+                ListLicenseSpecificationsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListLicenseSpecificationsForResourceResponse,
+                RusotoError<ListLicenseSpecificationsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_license_specifications_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_inventory` with what `handler` returns for its input.
+    pub fn with_list_resource_inventory<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                ListResourceInventoryRequest,
+            ) -> ::std::result::Result<
+                ListResourceInventoryResponse,
+                RusotoError<ListResourceInventoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_inventory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_usage_for_license_configuration` with what `handler` returns for its input.
+    pub fn with_list_usage_for_license_configuration<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                ListUsageForLicenseConfigurationRequest,
+            ) -> ::std::result::Result<
+                ListUsageForLicenseConfigurationResponse,
+                RusotoError<ListUsageForLicenseConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_usage_for_license_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_license_configuration` with what `handler` returns for its input.
+    pub fn with_update_license_configuration<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                UpdateLicenseConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateLicenseConfigurationResponse,
+                RusotoError<UpdateLicenseConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_license_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_license_specifications_for_resource` with what `handler` returns for its input.
+    pub fn with_update_license_specifications_for_resource<F>(
+        mut self,
+        handler: F,
+    ) -> MockLicenseManager
+    where
+        F: Fn(
+                UpdateLicenseSpecificationsForResourceRequest,
+            ) -> ::std::result::Result<
+                UpdateLicenseSpecificationsForResourceResponse,
+                RusotoError<UpdateLicenseSpecificationsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_license_specifications_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service_settings` with what `handler` returns for its input.
+    pub fn with_update_service_settings<F>(mut self, handler: F) -> MockLicenseManager
+    where
+        F: Fn(
+                UpdateServiceSettingsRequest,
+            ) -> ::std::result::Result<
+                UpdateServiceSettingsResponse,
+                RusotoError<UpdateServiceSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service_settings = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl LicenseManager for MockLicenseManager {
+    fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> RusotoFuture<CreateLicenseConfigurationResponse, CreateLicenseConfigurationError> {
+        match self.create_license_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for create_license_configuration"),
+        }
+    }
+    fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> RusotoFuture<DeleteLicenseConfigurationResponse, DeleteLicenseConfigurationError> {
+        match self.delete_license_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for delete_license_configuration"),
+        }
+    }
+    fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> RusotoFuture<GetLicenseConfigurationResponse, GetLicenseConfigurationError> {
+        match self.get_license_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for get_license_configuration"),
+        }
+    }
+    fn get_service_settings(
+        &self,
+    ) -> RusotoFuture<GetServiceSettingsResponse, GetServiceSettingsError> {
+        match self.get_service_settings {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockLicenseManager has no handler for get_service_settings"),
+        }
+    }
+    fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> RusotoFuture<
+        ListAssociationsForLicenseConfigurationResponse,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        match self.list_associations_for_license_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockLicenseManager has no handler for list_associations_for_license_configuration"
+            ),
+        }
+    }
+    fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> RusotoFuture<ListLicenseConfigurationsResponse, ListLicenseConfigurationsError> {
+        match self.list_license_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for list_license_configurations"),
+        }
+    }
+    fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        ListLicenseSpecificationsForResourceResponse,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        match self.list_license_specifications_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockLicenseManager has no handler for list_license_specifications_for_resource"
+            ),
+        }
+    }
+    fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> RusotoFuture<ListResourceInventoryResponse, ListResourceInventoryError> {
+        match self.list_resource_inventory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for list_resource_inventory"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> RusotoFuture<ListUsageForLicenseConfigurationResponse, ListUsageForLicenseConfigurationError>
+    {
+        match self.list_usage_for_license_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockLicenseManager has no handler for list_usage_for_license_configuration")
+            }
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for untag_resource"),
+        }
+    }
+    fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> RusotoFuture<UpdateLicenseConfigurationResponse, UpdateLicenseConfigurationError> {
+        match self.update_license_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for update_license_configuration"),
+        }
+    }
+    fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        UpdateLicenseSpecificationsForResourceResponse,
+        UpdateLicenseSpecificationsForResourceError,
+    > {
+        match self.update_license_specifications_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockLicenseManager has no handler for update_license_specifications_for_resource"
+            ),
+        }
+    }
+    fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError> {
+        match self.update_service_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLicenseManager has no handler for update_service_settings"),
+        }
+    }
+}
@@ -22,16 +22,28 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AllocateStaticIpRequest {
     /// <p>The name of the static IP address.</p>
     #[serde(rename = "staticIpName")]
     pub static_ip_name: String,
 }
 
+impl Validate for AllocateStaticIpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "static_ip_name"),
+            &self.static_ip_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AllocateStaticIpResult {
     /// <p>An array of key-value pairs containing information about the static IP address you allocated.</p>
     #[serde(rename = "operations")]
@@ -40,6 +52,7 @@ pub struct AllocateStaticIpResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachDiskRequest {
     /// <p>The unique Lightsail disk name (e.g., <code>my-disk</code>).</p>
     #[serde(rename = "diskName")]
@@ -52,8 +65,24 @@ pub struct AttachDiskRequest {
     pub instance_name: String,
 }
 
+impl Validate for AttachDiskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "disk_name"),
+            &self.disk_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(&field_path(path, "disk_path"), &self.disk_path, ".*\\S.*");
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachDiskResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -62,6 +91,7 @@ pub struct AttachDiskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachInstancesToLoadBalancerRequest {
     /// <p>An array of strings representing the instance name(s) you want to attach to your load balancer.</p> <p>An instance must be <code>running</code> before you can attach it to your load balancer.</p> <p>There are no additional limits on the number of instances you can attach to your load balancer, aside from the limit of Lightsail instances you can create in your account (20).</p>
     #[serde(rename = "instanceNames")]
@@ -71,8 +101,22 @@ pub struct AttachInstancesToLoadBalancerRequest {
     pub load_balancer_name: String,
 }
 
+impl Validate for AttachInstancesToLoadBalancerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.instance_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "instance_names"), i);
+            errors.check_pattern(&item_path, &item, "\\w[\\w\\-]*\\w");
+        }
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachInstancesToLoadBalancerResult {
     /// <p>An object representing the API operations.</p>
     #[serde(rename = "operations")]
@@ -81,6 +125,7 @@ pub struct AttachInstancesToLoadBalancerResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachLoadBalancerTlsCertificateRequest {
     /// <p>The name of your SSL/TLS certificate.</p>
     #[serde(rename = "certificateName")]
@@ -90,8 +135,23 @@ pub struct AttachLoadBalancerTlsCertificateRequest {
     pub load_balancer_name: String,
 }
 
+impl Validate for AttachLoadBalancerTlsCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "certificate_name"),
+            &self.certificate_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachLoadBalancerTlsCertificateResult {
     /// <p>An object representing the API operations.</p> <p>These SSL/TLS certificates are only usable by Lightsail load balancers. You can't get the certificate and use it for another purpose.</p>
     #[serde(rename = "operations")]
@@ -100,6 +160,7 @@ pub struct AttachLoadBalancerTlsCertificateResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachStaticIpRequest {
     /// <p>The instance name to which you want to attach the static IP address.</p>
     #[serde(rename = "instanceName")]
@@ -109,8 +170,23 @@ pub struct AttachStaticIpRequest {
     pub static_ip_name: String,
 }
 
+impl Validate for AttachStaticIpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "static_ip_name"),
+            &self.static_ip_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachStaticIpResult {
     /// <p>An array of key-value pairs containing information about your API operations.</p>
     #[serde(rename = "operations")]
@@ -120,7 +196,7 @@ pub struct AttachStaticIpResult {
 
 /// <p>Describes an Availability Zone.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AvailabilityZone {
     /// <p>The state of the Availability Zone.</p>
     #[serde(rename = "state")]
@@ -134,7 +210,7 @@ pub struct AvailabilityZone {
 
 /// <p>Describes a blueprint (a virtual private server image).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Blueprint {
     /// <p>The ID for the virtual private server image (e.g., <code>app_wordpress_4_4</code> or <code>app_lamp_7_0</code>).</p>
     #[serde(rename = "blueprintId")]
@@ -188,7 +264,7 @@ pub struct Blueprint {
 
 /// <p>Describes a bundle, which is a set of specs describing your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Bundle {
     /// <p>The bundle ID (e.g., <code>micro_1_0</code>).</p>
     #[serde(rename = "bundleId")]
@@ -237,6 +313,7 @@ pub struct Bundle {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloseInstancePublicPortsRequest {
     /// <p>The name of the instance on which you're attempting to close the public ports.</p>
     #[serde(rename = "instanceName")]
@@ -246,8 +323,20 @@ pub struct CloseInstancePublicPortsRequest {
     pub port_info: PortInfo,
 }
 
+impl Validate for CloseInstancePublicPortsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        self.port_info
+            .validate_at(&field_path(path, "port_info"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CloseInstancePublicPortsResult {
     /// <p>An array of key-value pairs that contains information about the operation.</p>
     #[serde(rename = "operation")]
@@ -257,7 +346,7 @@ pub struct CloseInstancePublicPortsResult {
 
 /// <p>Describes a CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>A CloudFormation stack record provides information about the AWS CloudFormation stack used to create a new Amazon Elastic Compute Cloud instance from an exported Lightsail instance snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CloudFormationStackRecord {
     /// <p>The Amazon Resource Name (ARN) of the CloudFormation stack record.</p>
     #[serde(rename = "arn")]
@@ -295,7 +384,7 @@ pub struct CloudFormationStackRecord {
 
 /// <p>Describes the source of a CloudFormation stack record (i.e., the export snapshot record).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CloudFormationStackRecordSourceInfo {
     /// <p>The Amazon Resource Name (ARN) of the export snapshot record.</p>
     #[serde(rename = "arn")]
@@ -312,6 +401,7 @@ pub struct CloudFormationStackRecordSourceInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopySnapshotRequest {
     /// <p>The AWS Region where the source snapshot is located.</p>
     #[serde(rename = "sourceRegion")]
@@ -324,8 +414,23 @@ pub struct CopySnapshotRequest {
     pub target_snapshot_name: String,
 }
 
+impl Validate for CopySnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "source_snapshot_name"),
+            &self.source_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "target_snapshot_name"),
+            &self.target_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CopySnapshotResult {
     /// <p>A list of objects describing the API operation.</p>
     #[serde(rename = "operations")]
@@ -334,14 +439,24 @@ pub struct CopySnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCloudFormationStackRequest {
     /// <p>An array of parameters that will be used to create the new Amazon EC2 instance. You can only pass one instance entry at a time in this array. You will get an invalid parameter error if you pass more than one instance entry in this array.</p>
     #[serde(rename = "instances")]
     pub instances: Vec<InstanceEntry>,
 }
 
+impl Validate for CreateCloudFormationStackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.instances.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "instances"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCloudFormationStackResult {
     /// <p>A list of objects describing the API operation.</p>
     #[serde(rename = "operations")]
@@ -350,6 +465,7 @@ pub struct CreateCloudFormationStackResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDiskFromSnapshotRequest {
     /// <p>The Availability Zone where you want to create the disk (e.g., <code>us-east-2a</code>). Choose the same Availability Zone as the Lightsail instance where you want to create the disk.</p> <p>Use the GetRegions operation to list the Availability Zones where Lightsail is currently available.</p>
     #[serde(rename = "availabilityZone")]
@@ -369,8 +485,28 @@ pub struct CreateDiskFromSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateDiskFromSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "availability_zone"),
+            &self.availability_zone,
+            ".*\\S.*",
+        );
+        errors.check_pattern(
+            &field_path(path, "disk_name"),
+            &self.disk_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "disk_snapshot_name"),
+            &self.disk_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDiskFromSnapshotResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -379,6 +515,7 @@ pub struct CreateDiskFromSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDiskRequest {
     /// <p>The Availability Zone where you want to create the disk (e.g., <code>us-east-2a</code>). Choose the same Availability Zone as the Lightsail instance where you want to create the disk.</p> <p>Use the GetRegions operation to list the Availability Zones where Lightsail is currently available.</p>
     #[serde(rename = "availabilityZone")]
@@ -395,8 +532,23 @@ pub struct CreateDiskRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateDiskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "availability_zone"),
+            &self.availability_zone,
+            ".*\\S.*",
+        );
+        errors.check_pattern(
+            &field_path(path, "disk_name"),
+            &self.disk_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDiskResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -405,6 +557,7 @@ pub struct CreateDiskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDiskSnapshotRequest {
     /// <p><p>The unique name of the source disk (e.g., <code>Disk-Virginia-1</code>).</p> <note> <p>This parameter cannot be defined together with the <code>instance name</code> parameter. The <code>disk name</code> and <code>instance name</code> parameters are mutually exclusive.</p> </note></p>
     #[serde(rename = "diskName")]
@@ -423,8 +576,28 @@ pub struct CreateDiskSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateDiskSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.disk_name {
+            errors.check_pattern(&field_path(path, "disk_name"), &value, "\\w[\\w\\-]*\\w");
+        }
+        errors.check_pattern(
+            &field_path(path, "disk_snapshot_name"),
+            &self.disk_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        if let Some(ref value) = self.instance_name {
+            errors.check_pattern(
+                &field_path(path, "instance_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDiskSnapshotResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -433,6 +606,7 @@ pub struct CreateDiskSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainEntryRequest {
     /// <p>An array of key-value pairs containing information about the domain entry request.</p>
     #[serde(rename = "domainEntry")]
@@ -442,8 +616,15 @@ pub struct CreateDomainEntryRequest {
     pub domain_name: String,
 }
 
+impl Validate for CreateDomainEntryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.domain_entry
+            .validate_at(&field_path(path, "domain_entry"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDomainEntryResult {
     /// <p>An array of key-value pairs containing information about the operation.</p>
     #[serde(rename = "operation")]
@@ -452,6 +633,7 @@ pub struct CreateDomainEntryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainRequest {
     /// <p><p>The domain name to manage (e.g., <code>example.com</code>).</p> <note> <p>You cannot register a new domain name using Lightsail. You must register a domain name using Amazon Route 53 or another domain name registrar. If you have already registered your domain, you can enter its name in this parameter to manage the DNS records for that domain.</p> </note></p>
     #[serde(rename = "domainName")]
@@ -463,7 +645,7 @@ pub struct CreateDomainRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDomainResult {
     /// <p>An array of key-value pairs containing information about the domain resource you created.</p>
     #[serde(rename = "operation")]
@@ -472,6 +654,7 @@ pub struct CreateDomainResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInstanceSnapshotRequest {
     /// <p>The Lightsail instance on which to base your snapshot.</p>
     #[serde(rename = "instanceName")]
@@ -485,8 +668,23 @@ pub struct CreateInstanceSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateInstanceSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_snapshot_name"),
+            &self.instance_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInstanceSnapshotResult {
     /// <p>An array of key-value pairs containing information about the results of your create instances snapshot request.</p>
     #[serde(rename = "operations")]
@@ -495,6 +693,7 @@ pub struct CreateInstanceSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInstancesFromSnapshotRequest {
     /// <p>An object containing information about one or more disk mappings.</p>
     #[serde(rename = "attachedDiskMapping")]
@@ -526,8 +725,35 @@ pub struct CreateInstancesFromSnapshotRequest {
     pub user_data: Option<String>,
 }
 
+impl Validate for CreateInstancesFromSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attached_disk_mapping {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attached_disk_mapping"), key);
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    item2.validate_at(&item_path2, errors);
+                }
+            }
+        }
+        errors.check_pattern(&field_path(path, "bundle_id"), &self.bundle_id, ".*\\S.*");
+        errors.check_pattern(
+            &field_path(path, "instance_snapshot_name"),
+            &self.instance_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        if let Some(ref value) = self.key_pair_name {
+            errors.check_pattern(
+                &field_path(path, "key_pair_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInstancesFromSnapshotResult {
     /// <p>An array of key-value pairs containing information about the results of your create instances from snapshot request.</p>
     #[serde(rename = "operations")]
@@ -536,6 +762,7 @@ pub struct CreateInstancesFromSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInstancesRequest {
     /// <p>The Availability Zone in which to create your instance. Use the following format: <code>us-east-2a</code> (case sensitive). You can get a list of Availability Zones by using the <a href="http://docs.aws.amazon.com/lightsail/2016-11-28/api-reference/API_GetRegions.html">get regions</a> operation. Be sure to add the <code>include Availability Zones</code> parameter to your request.</p>
     #[serde(rename = "availabilityZone")]
@@ -563,8 +790,26 @@ pub struct CreateInstancesRequest {
     pub user_data: Option<String>,
 }
 
+impl Validate for CreateInstancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "blueprint_id"),
+            &self.blueprint_id,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "bundle_id"), &self.bundle_id, ".*\\S.*");
+        if let Some(ref value) = self.key_pair_name {
+            errors.check_pattern(
+                &field_path(path, "key_pair_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInstancesResult {
     /// <p>An array of key-value pairs containing information about the results of your create instances request.</p>
     #[serde(rename = "operations")]
@@ -573,6 +818,7 @@ pub struct CreateInstancesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateKeyPairRequest {
     /// <p>The name for your new key pair.</p>
     #[serde(rename = "keyPairName")]
@@ -583,8 +829,18 @@ pub struct CreateKeyPairRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateKeyPairRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "key_pair_name"),
+            &self.key_pair_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateKeyPairResult {
     /// <p>An array of key-value pairs containing information about the new key pair you just created.</p>
     #[serde(rename = "keyPair")]
@@ -605,6 +861,7 @@ pub struct CreateKeyPairResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerRequest {
     /// <p>The optional alternative domains and subdomains to use with your SSL/TLS certificate (e.g., <code>www.example.com</code>, <code>example.com</code>, <code>m.example.com</code>, <code>blog.example.com</code>).</p>
     #[serde(rename = "certificateAlternativeNames")]
@@ -634,8 +891,31 @@ pub struct CreateLoadBalancerRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateLoadBalancerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_name {
+            errors.check_pattern(
+                &field_path(path, "certificate_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+        errors.check_range(
+            &field_path(path, "instance_port"),
+            self.instance_port,
+            None,
+            Some(65535),
+        );
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLoadBalancerResult {
     /// <p>An object containing information about the API operations.</p>
     #[serde(rename = "operations")]
@@ -644,6 +924,7 @@ pub struct CreateLoadBalancerResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLoadBalancerTlsCertificateRequest {
     /// <p>An array of strings listing alternative domains and subdomains for your SSL/TLS certificate. Lightsail will de-dupe the names for you. You can have a maximum of 9 alternative names (in addition to the 1 primary domain). We do not support wildcards (e.g., <code>*.example.com</code>).</p>
     #[serde(rename = "certificateAlternativeNames")]
@@ -664,8 +945,23 @@ pub struct CreateLoadBalancerTlsCertificateRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateLoadBalancerTlsCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "certificate_name"),
+            &self.certificate_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLoadBalancerTlsCertificateResult {
     /// <p>An object containing information about the API operations.</p>
     #[serde(rename = "operations")]
@@ -674,6 +970,7 @@ pub struct CreateLoadBalancerTlsCertificateResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRelationalDatabaseFromSnapshotRequest {
     /// <p>The Availability Zone in which to create your new database. Use the <code>us-east-2a</code> case-sensitive format.</p> <p>You can get a list of Availability Zones by using the <code>get regions</code> operation. Be sure to add the <code>include relational database Availability Zones</code> parameter to your request.</p>
     #[serde(rename = "availabilityZone")]
@@ -712,8 +1009,32 @@ pub struct CreateRelationalDatabaseFromSnapshotRequest {
     pub use_latest_restorable_time: Option<bool>,
 }
 
+impl Validate for CreateRelationalDatabaseFromSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        if let Some(ref value) = self.relational_database_snapshot_name {
+            errors.check_pattern(
+                &field_path(path, "relational_database_snapshot_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+        if let Some(ref value) = self.source_relational_database_name {
+            errors.check_pattern(
+                &field_path(path, "source_relational_database_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRelationalDatabaseFromSnapshotResult {
     /// <p>An object describing the result of your create relational database from snapshot request.</p>
     #[serde(rename = "operations")]
@@ -722,6 +1043,7 @@ pub struct CreateRelationalDatabaseFromSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRelationalDatabaseRequest {
     /// <p>The Availability Zone in which to create your new database. Use the <code>us-east-2a</code> case-sensitive format.</p> <p>You can get a list of Availability Zones by using the <code>get regions</code> operation. Be sure to add the <code>include relational database Availability Zones</code> parameter to your request.</p>
     #[serde(rename = "availabilityZone")]
@@ -764,8 +1086,18 @@ pub struct CreateRelationalDatabaseRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRelationalDatabaseResult {
     /// <p>An object describing the result of your create relational database request.</p>
     #[serde(rename = "operations")]
@@ -774,6 +1106,7 @@ pub struct CreateRelationalDatabaseResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRelationalDatabaseSnapshotRequest {
     /// <p>The name of the database on which to base your new snapshot.</p>
     #[serde(rename = "relationalDatabaseName")]
@@ -787,8 +1120,23 @@ pub struct CreateRelationalDatabaseSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRelationalDatabaseSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "relational_database_snapshot_name"),
+            &self.relational_database_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRelationalDatabaseSnapshotResult {
     /// <p>An object describing the result of your create relational database snapshot request.</p>
     #[serde(rename = "operations")]
@@ -797,14 +1145,25 @@ pub struct CreateRelationalDatabaseSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDiskRequest {
     /// <p>The unique name of the disk you want to delete (e.g., <code>my-disk</code>).</p>
     #[serde(rename = "diskName")]
     pub disk_name: String,
 }
 
+impl Validate for DeleteDiskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "disk_name"),
+            &self.disk_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDiskResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -813,14 +1172,25 @@ pub struct DeleteDiskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDiskSnapshotRequest {
     /// <p>The name of the disk snapshot you want to delete (e.g., <code>my-disk-snapshot</code>).</p>
     #[serde(rename = "diskSnapshotName")]
     pub disk_snapshot_name: String,
 }
 
+impl Validate for DeleteDiskSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "disk_snapshot_name"),
+            &self.disk_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDiskSnapshotResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -829,6 +1199,7 @@ pub struct DeleteDiskSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainEntryRequest {
     /// <p>An array of key-value pairs containing information about your domain entries.</p>
     #[serde(rename = "domainEntry")]
@@ -838,8 +1209,15 @@ pub struct DeleteDomainEntryRequest {
     pub domain_name: String,
 }
 
+impl Validate for DeleteDomainEntryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.domain_entry
+            .validate_at(&field_path(path, "domain_entry"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDomainEntryResult {
     /// <p>An array of key-value pairs containing information about the results of your delete domain entry request.</p>
     #[serde(rename = "operation")]
@@ -848,6 +1226,7 @@ pub struct DeleteDomainEntryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainRequest {
     /// <p>The specific domain name to delete.</p>
     #[serde(rename = "domainName")]
@@ -855,7 +1234,7 @@ pub struct DeleteDomainRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDomainResult {
     /// <p>An array of key-value pairs containing information about the results of your delete domain request.</p>
     #[serde(rename = "operation")]
@@ -864,14 +1243,25 @@ pub struct DeleteDomainResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInstanceRequest {
     /// <p>The name of the instance to delete.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for DeleteInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInstanceResult {
     /// <p>An array of key-value pairs containing information about the results of your delete instance request.</p>
     #[serde(rename = "operations")]
@@ -880,14 +1270,25 @@ pub struct DeleteInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInstanceSnapshotRequest {
     /// <p>The name of the snapshot to delete.</p>
     #[serde(rename = "instanceSnapshotName")]
     pub instance_snapshot_name: String,
 }
 
+impl Validate for DeleteInstanceSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_snapshot_name"),
+            &self.instance_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInstanceSnapshotResult {
     /// <p>An array of key-value pairs containing information about the results of your delete instance snapshot request.</p>
     #[serde(rename = "operations")]
@@ -896,14 +1297,25 @@ pub struct DeleteInstanceSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteKeyPairRequest {
     /// <p>The name of the key pair to delete.</p>
     #[serde(rename = "keyPairName")]
     pub key_pair_name: String,
 }
 
+impl Validate for DeleteKeyPairRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "key_pair_name"),
+            &self.key_pair_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteKeyPairResult {
     /// <p>An array of key-value pairs containing information about the results of your delete key pair request.</p>
     #[serde(rename = "operation")]
@@ -912,14 +1324,25 @@ pub struct DeleteKeyPairResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteKnownHostKeysRequest {
     /// <p>The name of the instance for which you want to reset the host key or certificate.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for DeleteKnownHostKeysRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteKnownHostKeysResult {
     /// <p>A list of objects describing the API operation.</p>
     #[serde(rename = "operations")]
@@ -928,14 +1351,25 @@ pub struct DeleteKnownHostKeysResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerRequest {
     /// <p>The name of the load balancer you want to delete.</p>
     #[serde(rename = "loadBalancerName")]
     pub load_balancer_name: String,
 }
 
+impl Validate for DeleteLoadBalancerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLoadBalancerResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -944,6 +1378,7 @@ pub struct DeleteLoadBalancerResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoadBalancerTlsCertificateRequest {
     /// <p>The SSL/TLS certificate name.</p>
     #[serde(rename = "certificateName")]
@@ -957,8 +1392,23 @@ pub struct DeleteLoadBalancerTlsCertificateRequest {
     pub load_balancer_name: String,
 }
 
+impl Validate for DeleteLoadBalancerTlsCertificateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "certificate_name"),
+            &self.certificate_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLoadBalancerTlsCertificateResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -967,6 +1417,7 @@ pub struct DeleteLoadBalancerTlsCertificateResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRelationalDatabaseRequest {
     /// <p><p>The name of the database snapshot created if <code>skip final snapshot</code> is <code>false</code>, which is the default value for that parameter.</p> <note> <p>Specifying this parameter and also specifying the <code>skip final snapshot</code> parameter to <code>true</code> results in an error.</p> </note> <p>Constraints:</p> <ul> <li> <p>Must contain from 2 to 255 alphanumeric characters, or hyphens.</p> </li> <li> <p>The first and last character must be a letter or number.</p> </li> </ul></p>
     #[serde(rename = "finalRelationalDatabaseSnapshotName")]
@@ -981,8 +1432,25 @@ pub struct DeleteRelationalDatabaseRequest {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl Validate for DeleteRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.final_relational_database_snapshot_name {
+            errors.check_pattern(
+                &field_path(path, "final_relational_database_snapshot_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRelationalDatabaseResult {
     /// <p>An object describing the result of your delete relational database request.</p>
     #[serde(rename = "operations")]
@@ -991,14 +1459,25 @@ pub struct DeleteRelationalDatabaseResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRelationalDatabaseSnapshotRequest {
     /// <p>The name of the database snapshot that you are deleting.</p>
     #[serde(rename = "relationalDatabaseSnapshotName")]
     pub relational_database_snapshot_name: String,
 }
 
+impl Validate for DeleteRelationalDatabaseSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_snapshot_name"),
+            &self.relational_database_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRelationalDatabaseSnapshotResult {
     /// <p>An object describing the result of your delete relational database snapshot request.</p>
     #[serde(rename = "operations")]
@@ -1008,7 +1487,7 @@ pub struct DeleteRelationalDatabaseSnapshotResult {
 
 /// <p>Describes the destination of a record.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DestinationInfo {
     /// <p>The ID of the resource created at the destination.</p>
     #[serde(rename = "id")]
@@ -1021,14 +1500,25 @@ pub struct DestinationInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachDiskRequest {
     /// <p>The unique name of the disk you want to detach from your instance (e.g., <code>my-disk</code>).</p>
     #[serde(rename = "diskName")]
     pub disk_name: String,
 }
 
+impl Validate for DetachDiskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "disk_name"),
+            &self.disk_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachDiskResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -1037,6 +1527,7 @@ pub struct DetachDiskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachInstancesFromLoadBalancerRequest {
     /// <p>An array of strings containing the names of the instances you want to detach from the load balancer.</p>
     #[serde(rename = "instanceNames")]
@@ -1046,8 +1537,22 @@ pub struct DetachInstancesFromLoadBalancerRequest {
     pub load_balancer_name: String,
 }
 
+impl Validate for DetachInstancesFromLoadBalancerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.instance_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "instance_names"), i);
+            errors.check_pattern(&item_path, &item, "\\w[\\w\\-]*\\w");
+        }
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachInstancesFromLoadBalancerResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -1056,14 +1561,25 @@ pub struct DetachInstancesFromLoadBalancerResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachStaticIpRequest {
     /// <p>The name of the static IP to detach from the instance.</p>
     #[serde(rename = "staticIpName")]
     pub static_ip_name: String,
 }
 
+impl Validate for DetachStaticIpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "static_ip_name"),
+            &self.static_ip_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachStaticIpResult {
     /// <p>An array of key-value pairs containing information about the results of your detach static IP request.</p>
     #[serde(rename = "operations")]
@@ -1073,7 +1589,7 @@ pub struct DetachStaticIpResult {
 
 /// <p>Describes a system disk or an block storage disk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Disk {
     /// <p>The Amazon Resource Name (ARN) of the disk.</p>
     #[serde(rename = "arn")]
@@ -1135,7 +1651,7 @@ pub struct Disk {
 
 /// <p>Describes a disk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiskInfo {
     /// <p>A Boolean value indicating whether this disk is a system disk (has an operating system loaded on it).</p>
     #[serde(rename = "isSystemDisk")]
@@ -1157,6 +1673,7 @@ pub struct DiskInfo {
 
 /// <p>Describes a block storage disk mapping.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DiskMap {
     /// <p>The new disk name (e.g., <code>my-new-disk</code>).</p>
     #[serde(rename = "newDiskName")]
@@ -1168,9 +1685,24 @@ pub struct DiskMap {
     pub original_disk_path: Option<String>,
 }
 
+impl Validate for DiskMap {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.new_disk_name {
+            errors.check_pattern(
+                &field_path(path, "new_disk_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+        if let Some(ref value) = self.original_disk_path {
+            errors.check_pattern(&field_path(path, "original_disk_path"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>Describes a block storage disk snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiskSnapshot {
     /// <p>The Amazon Resource Name (ARN) of the disk snapshot.</p>
     #[serde(rename = "arn")]
@@ -1232,7 +1764,7 @@ pub struct DiskSnapshot {
 
 /// <p>Describes a disk snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiskSnapshotInfo {
     /// <p>The size of the disk in GB (e.g., <code>32</code>).</p>
     #[serde(rename = "sizeInGb")]
@@ -1242,7 +1774,7 @@ pub struct DiskSnapshotInfo {
 
 /// <p>Describes a domain where you are storing recordsets in Lightsail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Domain {
     /// <p>The Amazon Resource Name (ARN) of the domain recordset (e.g., <code>arn:aws:lightsail:global:123456789101:Domain/824cede0-abc7-4f84-8dbc-12345EXAMPLE</code>).</p>
     #[serde(rename = "arn")]
@@ -1303,11 +1835,20 @@ pub struct DomainEntry {
     pub type_: Option<String>,
 }
 
+impl Validate for DomainEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.id {
+            errors.check_pattern(&field_path(path, "id"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DownloadDefaultKeyPairRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DownloadDefaultKeyPairResult {
     /// <p>A base64-encoded RSA private key.</p>
     #[serde(rename = "privateKeyBase64")]
@@ -1321,7 +1862,7 @@ pub struct DownloadDefaultKeyPairResult {
 
 /// <p>Describes an export snapshot record.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportSnapshotRecord {
     /// <p>The Amazon Resource Name (ARN) of the export snapshot record.</p>
     #[serde(rename = "arn")]
@@ -1359,7 +1900,7 @@ pub struct ExportSnapshotRecord {
 
 /// <p>Describes the source of an export snapshot record.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportSnapshotRecordSourceInfo {
     /// <p>The Amazon Resource Name (ARN) of the source instance or disk snapshot.</p>
     #[serde(rename = "arn")]
@@ -1396,14 +1937,25 @@ pub struct ExportSnapshotRecordSourceInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportSnapshotRequest {
     /// <p>The name of the instance or disk snapshot to be exported to Amazon EC2.</p>
     #[serde(rename = "sourceSnapshotName")]
     pub source_snapshot_name: String,
 }
 
+impl Validate for ExportSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "source_snapshot_name"),
+            &self.source_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportSnapshotResult {
     /// <p>A list of objects describing the API operation.</p>
     #[serde(rename = "operations")]
@@ -1412,6 +1964,7 @@ pub struct ExportSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetActiveNamesRequest {
     /// <p>A token used for paginating results from your get active names request.</p>
     #[serde(rename = "pageToken")]
@@ -1420,7 +1973,7 @@ pub struct GetActiveNamesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetActiveNamesResult {
     /// <p>The list of active names returned by the get active names request.</p>
     #[serde(rename = "activeNames")]
@@ -1433,6 +1986,7 @@ pub struct GetActiveNamesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBlueprintsRequest {
     /// <p>A Boolean value indicating whether to include inactive results in your request.</p>
     #[serde(rename = "includeInactive")]
@@ -1445,7 +1999,7 @@ pub struct GetBlueprintsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBlueprintsResult {
     /// <p>An array of key-value pairs that contains information about the available blueprints.</p>
     #[serde(rename = "blueprints")]
@@ -1458,6 +2012,7 @@ pub struct GetBlueprintsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBundlesRequest {
     /// <p>A Boolean value that indicates whether to include inactive bundle results in your request.</p>
     #[serde(rename = "includeInactive")]
@@ -1470,7 +2025,7 @@ pub struct GetBundlesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBundlesResult {
     /// <p>An array of key-value pairs that contains information about the available bundles.</p>
     #[serde(rename = "bundles")]
@@ -1483,6 +2038,7 @@ pub struct GetBundlesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCloudFormationStackRecordsRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get cloud formation stack records</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -1491,7 +2047,7 @@ pub struct GetCloudFormationStackRecordsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCloudFormationStackRecordsResult {
     /// <p>A list of objects describing the CloudFormation stack records.</p>
     #[serde(rename = "cloudFormationStackRecords")]
@@ -1504,14 +2060,25 @@ pub struct GetCloudFormationStackRecordsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDiskRequest {
     /// <p>The name of the disk (e.g., <code>my-disk</code>).</p>
     #[serde(rename = "diskName")]
     pub disk_name: String,
 }
 
+impl Validate for GetDiskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "disk_name"),
+            &self.disk_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDiskResult {
     /// <p>An object containing information about the disk.</p>
     #[serde(rename = "disk")]
@@ -1520,14 +2087,25 @@ pub struct GetDiskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDiskSnapshotRequest {
     /// <p>The name of the disk snapshot (e.g., <code>my-disk-snapshot</code>).</p>
     #[serde(rename = "diskSnapshotName")]
     pub disk_snapshot_name: String,
 }
 
+impl Validate for GetDiskSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "disk_snapshot_name"),
+            &self.disk_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDiskSnapshotResult {
     /// <p>An object containing information about the disk snapshot.</p>
     #[serde(rename = "diskSnapshot")]
@@ -1536,6 +2114,7 @@ pub struct GetDiskSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDiskSnapshotsRequest {
     /// <p>A token used for advancing to the next page of results from your GetDiskSnapshots request.</p>
     #[serde(rename = "pageToken")]
@@ -1544,7 +2123,7 @@ pub struct GetDiskSnapshotsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDiskSnapshotsResult {
     /// <p>An array of objects containing information about all block storage disk snapshots.</p>
     #[serde(rename = "diskSnapshots")]
@@ -1557,6 +2136,7 @@ pub struct GetDiskSnapshotsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDisksRequest {
     /// <p>A token used for advancing to the next page of results from your GetDisks request.</p>
     #[serde(rename = "pageToken")]
@@ -1565,7 +2145,7 @@ pub struct GetDisksRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDisksResult {
     /// <p>An array of objects containing information about all block storage disks.</p>
     #[serde(rename = "disks")]
@@ -1578,6 +2158,7 @@ pub struct GetDisksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainRequest {
     /// <p>The domain name for which your want to return information about.</p>
     #[serde(rename = "domainName")]
@@ -1585,7 +2166,7 @@ pub struct GetDomainRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainResult {
     /// <p>An array of key-value pairs containing information about your get domain request.</p>
     #[serde(rename = "domain")]
@@ -1594,6 +2175,7 @@ pub struct GetDomainResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainsRequest {
     /// <p>A token used for advancing to the next page of results from your get domains request.</p>
     #[serde(rename = "pageToken")]
@@ -1602,7 +2184,7 @@ pub struct GetDomainsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainsResult {
     /// <p>An array of key-value pairs containing information about each of the domain entries in the user's account.</p>
     #[serde(rename = "domains")]
@@ -1615,6 +2197,7 @@ pub struct GetDomainsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetExportSnapshotRecordsRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get export snapshot records</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -1623,7 +2206,7 @@ pub struct GetExportSnapshotRecordsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetExportSnapshotRecordsResult {
     /// <p>A list of objects describing the export snapshot records.</p>
     #[serde(rename = "exportSnapshotRecords")]
@@ -1636,6 +2219,7 @@ pub struct GetExportSnapshotRecordsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceAccessDetailsRequest {
     /// <p>The name of the instance to access.</p>
     #[serde(rename = "instanceName")]
@@ -1646,8 +2230,18 @@ pub struct GetInstanceAccessDetailsRequest {
     pub protocol: Option<String>,
 }
 
+impl Validate for GetInstanceAccessDetailsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceAccessDetailsResult {
     /// <p>An array of key-value pairs containing information about a get instance access request.</p>
     #[serde(rename = "accessDetails")]
@@ -1656,6 +2250,7 @@ pub struct GetInstanceAccessDetailsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceMetricDataRequest {
     /// <p>The end time of the time period.</p>
     #[serde(rename = "endTime")]
@@ -1680,8 +2275,24 @@ pub struct GetInstanceMetricDataRequest {
     pub unit: String,
 }
 
+impl Validate for GetInstanceMetricDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_range(
+            &field_path(path, "period"),
+            self.period,
+            Some(60),
+            Some(86400),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceMetricDataResult {
     /// <p>An array of key-value pairs containing information about the results of your get instance metric data request.</p>
     #[serde(rename = "metricData")]
@@ -1694,14 +2305,25 @@ pub struct GetInstanceMetricDataResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstancePortStatesRequest {
     /// <p>The name of the instance.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for GetInstancePortStatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstancePortStatesResult {
     /// <p>Information about the port states resulting from your request.</p>
     #[serde(rename = "portStates")]
@@ -1710,14 +2332,25 @@ pub struct GetInstancePortStatesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceRequest {
     /// <p>The name of the instance.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for GetInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceResult {
     /// <p>An array of key-value pairs containing information about the specified instance.</p>
     #[serde(rename = "instance")]
@@ -1726,14 +2359,25 @@ pub struct GetInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceSnapshotRequest {
     /// <p>The name of the snapshot for which you are requesting information.</p>
     #[serde(rename = "instanceSnapshotName")]
     pub instance_snapshot_name: String,
 }
 
+impl Validate for GetInstanceSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_snapshot_name"),
+            &self.instance_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceSnapshotResult {
     /// <p>An array of key-value pairs containing information about the results of your get instance snapshot request.</p>
     #[serde(rename = "instanceSnapshot")]
@@ -1742,6 +2386,7 @@ pub struct GetInstanceSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceSnapshotsRequest {
     /// <p>A token used for advancing to the next page of results from your get instance snapshots request.</p>
     #[serde(rename = "pageToken")]
@@ -1750,7 +2395,7 @@ pub struct GetInstanceSnapshotsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceSnapshotsResult {
     /// <p>An array of key-value pairs containing information about the results of your get instance snapshots request.</p>
     #[serde(rename = "instanceSnapshots")]
@@ -1763,14 +2408,25 @@ pub struct GetInstanceSnapshotsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceStateRequest {
     /// <p>The name of the instance to get state information about.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for GetInstanceStateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceStateResult {
     /// <p>The state of the instance.</p>
     #[serde(rename = "state")]
@@ -1779,6 +2435,7 @@ pub struct GetInstanceStateResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstancesRequest {
     /// <p>A token used for advancing to the next page of results from your get instances request.</p>
     #[serde(rename = "pageToken")]
@@ -1787,7 +2444,7 @@ pub struct GetInstancesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstancesResult {
     /// <p>An array of key-value pairs containing information about your instances.</p>
     #[serde(rename = "instances")]
@@ -1800,14 +2457,25 @@ pub struct GetInstancesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetKeyPairRequest {
     /// <p>The name of the key pair for which you are requesting information.</p>
     #[serde(rename = "keyPairName")]
     pub key_pair_name: String,
 }
 
+impl Validate for GetKeyPairRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "key_pair_name"),
+            &self.key_pair_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetKeyPairResult {
     /// <p>An array of key-value pairs containing information about the key pair.</p>
     #[serde(rename = "keyPair")]
@@ -1816,6 +2484,7 @@ pub struct GetKeyPairResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetKeyPairsRequest {
     /// <p>A token used for advancing to the next page of results from your get key pairs request.</p>
     #[serde(rename = "pageToken")]
@@ -1824,7 +2493,7 @@ pub struct GetKeyPairsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetKeyPairsResult {
     /// <p>An array of key-value pairs containing information about the key pairs.</p>
     #[serde(rename = "keyPairs")]
@@ -1837,6 +2506,7 @@ pub struct GetKeyPairsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoadBalancerMetricDataRequest {
     /// <p>The end time of the period.</p>
     #[serde(rename = "endTime")]
@@ -1861,8 +2531,24 @@ pub struct GetLoadBalancerMetricDataRequest {
     pub unit: String,
 }
 
+impl Validate for GetLoadBalancerMetricDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        errors.check_range(
+            &field_path(path, "period"),
+            self.period,
+            Some(60),
+            Some(86400),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoadBalancerMetricDataResult {
     /// <p>An array of metric datapoint objects.</p>
     #[serde(rename = "metricData")]
@@ -1875,14 +2561,25 @@ pub struct GetLoadBalancerMetricDataResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoadBalancerRequest {
     /// <p>The name of the load balancer.</p>
     #[serde(rename = "loadBalancerName")]
     pub load_balancer_name: String,
 }
 
+impl Validate for GetLoadBalancerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoadBalancerResult {
     /// <p>An object containing information about your load balancer.</p>
     #[serde(rename = "loadBalancer")]
@@ -1891,14 +2588,25 @@ pub struct GetLoadBalancerResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoadBalancerTlsCertificatesRequest {
     /// <p>The name of the load balancer you associated with your SSL/TLS certificate.</p>
     #[serde(rename = "loadBalancerName")]
     pub load_balancer_name: String,
 }
 
+impl Validate for GetLoadBalancerTlsCertificatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoadBalancerTlsCertificatesResult {
     /// <p>An array of LoadBalancerTlsCertificate objects describing your SSL/TLS certificates.</p>
     #[serde(rename = "tlsCertificates")]
@@ -1907,6 +2615,7 @@ pub struct GetLoadBalancerTlsCertificatesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoadBalancersRequest {
     /// <p>A token used for paginating the results from your GetLoadBalancers request.</p>
     #[serde(rename = "pageToken")]
@@ -1915,7 +2624,7 @@ pub struct GetLoadBalancersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoadBalancersResult {
     /// <p>An array of LoadBalancer objects describing your load balancers.</p>
     #[serde(rename = "loadBalancers")]
@@ -1928,14 +2637,25 @@ pub struct GetLoadBalancersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOperationRequest {
     /// <p>A GUID used to identify the operation.</p>
     #[serde(rename = "operationId")]
     pub operation_id: String,
 }
 
+impl Validate for GetOperationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "operation_id"),
+            &self.operation_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOperationResult {
     /// <p>An array of key-value pairs containing information about the results of your get operation request.</p>
     #[serde(rename = "operation")]
@@ -1944,6 +2664,7 @@ pub struct GetOperationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOperationsForResourceRequest {
     /// <p>A token used for advancing to the next page of results from your get operations for resource request.</p>
     #[serde(rename = "pageToken")]
@@ -1954,8 +2675,18 @@ pub struct GetOperationsForResourceRequest {
     pub resource_name: String,
 }
 
+impl Validate for GetOperationsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_name"),
+            &self.resource_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOperationsForResourceResult {
     /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
     #[serde(rename = "nextPageToken")]
@@ -1968,6 +2699,7 @@ pub struct GetOperationsForResourceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOperationsRequest {
     /// <p>A token used for advancing to the next page of results from your get operations request.</p>
     #[serde(rename = "pageToken")]
@@ -1976,7 +2708,7 @@ pub struct GetOperationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOperationsResult {
     /// <p>A token used for advancing to the next page of results from your get operations request.</p>
     #[serde(rename = "nextPageToken")]
@@ -1989,6 +2721,7 @@ pub struct GetOperationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRegionsRequest {
     /// <p>A Boolean value indicating whether to also include Availability Zones in your get regions request. Availability Zones are indicated with a letter: e.g., <code>us-east-2a</code>.</p>
     #[serde(rename = "includeAvailabilityZones")]
@@ -2001,7 +2734,7 @@ pub struct GetRegionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRegionsResult {
     /// <p>An array of key-value pairs containing information about your get regions request.</p>
     #[serde(rename = "regions")]
@@ -2010,6 +2743,7 @@ pub struct GetRegionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseBlueprintsRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get relational database blueprints</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -2018,7 +2752,7 @@ pub struct GetRelationalDatabaseBlueprintsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseBlueprintsResult {
     /// <p>An object describing the result of your get relational database blueprints request.</p>
     #[serde(rename = "blueprints")]
@@ -2031,6 +2765,7 @@ pub struct GetRelationalDatabaseBlueprintsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseBundlesRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get relational database bundles</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -2039,7 +2774,7 @@ pub struct GetRelationalDatabaseBundlesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseBundlesResult {
     /// <p>An object describing the result of your get relational database bundles request.</p>
     #[serde(rename = "bundles")]
@@ -2052,6 +2787,7 @@ pub struct GetRelationalDatabaseBundlesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseEventsRequest {
     /// <p>The number of minutes in the past from which to retrieve events. For example, to get all events from the past 2 hours, enter 120.</p> <p>Default: <code>60</code> </p> <p>The minimum is 1 and the maximum is 14 days (20160 minutes).</p>
     #[serde(rename = "durationInMinutes")]
@@ -2066,8 +2802,18 @@ pub struct GetRelationalDatabaseEventsRequest {
     pub relational_database_name: String,
 }
 
+impl Validate for GetRelationalDatabaseEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseEventsResult {
     /// <p>A token used for advancing to the next page of results from your get relational database events request.</p>
     #[serde(rename = "nextPageToken")]
@@ -2080,6 +2826,7 @@ pub struct GetRelationalDatabaseEventsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseLogEventsRequest {
     /// <p><p>The end of the time interval from which to get log events.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use an end time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the end time.</p> </li> </ul></p>
     #[serde(rename = "endTime")]
@@ -2105,8 +2852,18 @@ pub struct GetRelationalDatabaseLogEventsRequest {
     pub start_time: Option<f64>,
 }
 
+impl Validate for GetRelationalDatabaseLogEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseLogEventsResult {
     /// <p>A token used for advancing to the previous page of results from your get relational database log events request.</p>
     #[serde(rename = "nextBackwardToken")]
@@ -2123,14 +2880,25 @@ pub struct GetRelationalDatabaseLogEventsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseLogStreamsRequest {
     /// <p>The name of your database for which to get log streams.</p>
     #[serde(rename = "relationalDatabaseName")]
     pub relational_database_name: String,
 }
 
+impl Validate for GetRelationalDatabaseLogStreamsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseLogStreamsResult {
     /// <p>An object describing the result of your get relational database log streams request.</p>
     #[serde(rename = "logStreams")]
@@ -2139,6 +2907,7 @@ pub struct GetRelationalDatabaseLogStreamsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseMasterUserPasswordRequest {
     /// <p>The password version to return.</p> <p>Specifying <code>CURRENT</code> or <code>PREVIOUS</code> returns the current or previous passwords respectively. Specifying <code>PENDING</code> returns the newest version of the password that will rotate to <code>CURRENT</code>. After the <code>PENDING</code> password rotates to <code>CURRENT</code>, the <code>PENDING</code> password is no longer available.</p> <p>Default: <code>CURRENT</code> </p>
     #[serde(rename = "passwordVersion")]
@@ -2149,8 +2918,18 @@ pub struct GetRelationalDatabaseMasterUserPasswordRequest {
     pub relational_database_name: String,
 }
 
+impl Validate for GetRelationalDatabaseMasterUserPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseMasterUserPasswordResult {
     /// <p>The timestamp when the specified version of the master user password was created.</p>
     #[serde(rename = "createdAt")]
@@ -2163,6 +2942,7 @@ pub struct GetRelationalDatabaseMasterUserPasswordResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseMetricDataRequest {
     /// <p><p>The end of the time interval from which to get metric data.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use an end time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the end time.</p> </li> </ul></p>
     #[serde(rename = "endTime")]
@@ -2187,8 +2967,24 @@ pub struct GetRelationalDatabaseMetricDataRequest {
     pub unit: String,
 }
 
+impl Validate for GetRelationalDatabaseMetricDataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "period"),
+            self.period,
+            Some(60),
+            Some(86400),
+        );
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseMetricDataResult {
     /// <p>An object describing the result of your get relational database metric data request.</p>
     #[serde(rename = "metricData")]
@@ -2201,6 +2997,7 @@ pub struct GetRelationalDatabaseMetricDataResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseParametersRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get relational database parameters</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -2211,8 +3008,18 @@ pub struct GetRelationalDatabaseParametersRequest {
     pub relational_database_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl Validate for GetRelationalDatabaseParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseParametersResult {
     /// <p>A token used for advancing to the next page of results from your get static IPs request.</p>
     #[serde(rename = "nextPageToken")]
@@ -2225,14 +3032,25 @@ pub struct GetRelationalDatabaseParametersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseRequest {
     /// <p>The name of the database that you are looking up.</p>
     #[serde(rename = "relationalDatabaseName")]
     pub relational_database_name: String,
 }
 
+impl Validate for GetRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseResult {
     /// <p>An object describing the specified database.</p>
     #[serde(rename = "relationalDatabase")]
@@ -2241,14 +3059,25 @@ pub struct GetRelationalDatabaseResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseSnapshotRequest {
     /// <p>The name of the database snapshot for which to get information.</p>
     #[serde(rename = "relationalDatabaseSnapshotName")]
     pub relational_database_snapshot_name: String,
 }
 
+impl Validate for GetRelationalDatabaseSnapshotRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_snapshot_name"),
+            &self.relational_database_snapshot_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseSnapshotResult {
     /// <p>An object describing the specified database snapshot.</p>
     #[serde(rename = "relationalDatabaseSnapshot")]
@@ -2257,6 +3086,7 @@ pub struct GetRelationalDatabaseSnapshotResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabaseSnapshotsRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get relational database snapshots</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -2265,7 +3095,7 @@ pub struct GetRelationalDatabaseSnapshotsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabaseSnapshotsResult {
     /// <p>A token used for advancing to the next page of results from your get relational database snapshots request.</p>
     #[serde(rename = "nextPageToken")]
@@ -2278,6 +3108,7 @@ pub struct GetRelationalDatabaseSnapshotsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRelationalDatabasesRequest {
     /// <p>A token used for advancing to a specific page of results for your <code>get relational database</code> request.</p>
     #[serde(rename = "pageToken")]
@@ -2286,7 +3117,7 @@ pub struct GetRelationalDatabasesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRelationalDatabasesResult {
     /// <p>A token used for advancing to the next page of results from your get relational databases request.</p>
     #[serde(rename = "nextPageToken")]
@@ -2299,14 +3130,25 @@ pub struct GetRelationalDatabasesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStaticIpRequest {
     /// <p>The name of the static IP in Lightsail.</p>
     #[serde(rename = "staticIpName")]
     pub static_ip_name: String,
 }
 
+impl Validate for GetStaticIpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "static_ip_name"),
+            &self.static_ip_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetStaticIpResult {
     /// <p>An array of key-value pairs containing information about the requested static IP.</p>
     #[serde(rename = "staticIp")]
@@ -2315,6 +3157,7 @@ pub struct GetStaticIpResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetStaticIpsRequest {
     /// <p>A token used for advancing to the next page of results from your get static IPs request.</p>
     #[serde(rename = "pageToken")]
@@ -2323,7 +3166,7 @@ pub struct GetStaticIpsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetStaticIpsResult {
     /// <p>A token used for advancing to the next page of results from your get static IPs request.</p>
     #[serde(rename = "nextPageToken")]
@@ -2337,7 +3180,7 @@ pub struct GetStaticIpsResult {
 
 /// <p>Describes the public SSH host keys or the RDP certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HostKeyAttributes {
     /// <p>The SSH host key algorithm or the RDP certificate format.</p> <p>For SSH host keys, the algorithm may be <code>ssh-rsa</code>, <code>ecdsa-sha2-nistp256</code>, <code>ssh-ed25519</code>, etc. For RDP certificates, the algorithm is always <code>x509-cert</code>.</p>
     #[serde(rename = "algorithm")]
@@ -2370,6 +3213,7 @@ pub struct HostKeyAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportKeyPairRequest {
     /// <p>The name of the key pair for which you want to import the public key.</p>
     #[serde(rename = "keyPairName")]
@@ -2379,8 +3223,18 @@ pub struct ImportKeyPairRequest {
     pub public_key_base_64: String,
 }
 
+impl Validate for ImportKeyPairRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "key_pair_name"),
+            &self.key_pair_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportKeyPairResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operation")]
@@ -2390,7 +3244,7 @@ pub struct ImportKeyPairResult {
 
 /// <p>Describes an instance (a virtual private server).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Instance {
     /// <p>The Amazon Resource Name (ARN) of the instance (e.g., <code>arn:aws:lightsail:us-east-2:123456789101:Instance/244ad76f-8aad-4741-809f-12345EXAMPLE</code>).</p>
     #[serde(rename = "arn")]
@@ -2472,7 +3326,7 @@ pub struct Instance {
 
 /// <p>The parameters for gaining temporary access to one of your Amazon Lightsail instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceAccessDetails {
     /// <p>For SSH access, the public key to use when accessing your instance For OpenSSH clients (e.g., command line SSH), you should save this value to <code>tempkey-cert.pub</code>.</p>
     #[serde(rename = "certKey")]
@@ -2518,6 +3372,7 @@ pub struct InstanceAccessDetails {
 
 /// <p>Describes the Amazon Elastic Compute Cloud instance and related resources to be created using the <code>create cloud formation stack</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceEntry {
     /// <p>The Availability Zone for the new Amazon EC2 instance.</p>
     #[serde(rename = "availabilityZone")]
@@ -2537,9 +3392,24 @@ pub struct InstanceEntry {
     pub user_data: Option<String>,
 }
 
+impl Validate for InstanceEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_type"),
+            &self.instance_type,
+            ".*\\S.*",
+        );
+        errors.check_pattern(
+            &field_path(path, "source_name"),
+            &self.source_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 /// <p>Describes the hardware for the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceHardware {
     /// <p>The number of vCPUs the instance has.</p>
     #[serde(rename = "cpuCount")]
@@ -2557,7 +3427,7 @@ pub struct InstanceHardware {
 
 /// <p>Describes information about the health of the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceHealthSummary {
     /// <p>Describes the overall instance health. Valid values are below.</p>
     #[serde(rename = "instanceHealth")]
@@ -2575,7 +3445,7 @@ pub struct InstanceHealthSummary {
 
 /// <p>Describes monthly data transfer rates and port information for an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceNetworking {
     /// <p>The amount of data in GB allocated for monthly data transfers.</p>
     #[serde(rename = "monthlyTransfer")]
@@ -2589,7 +3459,7 @@ pub struct InstanceNetworking {
 
 /// <p>Describes information about the instance ports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstancePortInfo {
     /// <p>The access direction (<code>inbound</code> or <code>outbound</code>).</p>
     #[serde(rename = "accessDirection")]
@@ -2623,7 +3493,7 @@ pub struct InstancePortInfo {
 
 /// <p>Describes the port state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstancePortState {
     /// <p>The first port in the range.</p>
     #[serde(rename = "fromPort")]
@@ -2645,7 +3515,7 @@ pub struct InstancePortState {
 
 /// <p>Describes the snapshot of the virtual private server, or <i>instance</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceSnapshot {
     /// <p>The Amazon Resource Name (ARN) of the snapshot (e.g., <code>arn:aws:lightsail:us-east-2:123456789101:InstanceSnapshot/d23b5706-3322-4d83-81e5-12345EXAMPLE</code>).</p>
     #[serde(rename = "arn")]
@@ -2711,7 +3581,7 @@ pub struct InstanceSnapshot {
 
 /// <p>Describes an instance snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceSnapshotInfo {
     /// <p>The blueprint ID from which the source instance (e.g., <code>os_debian_8_3</code>).</p>
     #[serde(rename = "fromBlueprintId")]
@@ -2729,7 +3599,7 @@ pub struct InstanceSnapshotInfo {
 
 /// <p>Describes the virtual private server (or <i>instance</i>) status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceState {
     /// <p>The status code for the instance.</p>
     #[serde(rename = "code")]
@@ -2742,10 +3612,11 @@ pub struct InstanceState {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IsVpcPeeredRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IsVpcPeeredResult {
     /// <p>Returns <code>true</code> if the Lightsail VPC is peered; otherwise, <code>false</code>.</p>
     #[serde(rename = "isPeered")]
@@ -2755,7 +3626,7 @@ pub struct IsVpcPeeredResult {
 
 /// <p>Describes the SSH key pair.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct KeyPair {
     /// <p>The Amazon Resource Name (ARN) of the key pair (e.g., <code>arn:aws:lightsail:us-east-2:123456789101:KeyPair/05859e3d-331d-48ba-9034-12345EXAMPLE</code>).</p>
     #[serde(rename = "arn")]
@@ -2793,7 +3664,7 @@ pub struct KeyPair {
 
 /// <p>Describes the Lightsail load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBalancer {
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     #[serde(rename = "arn")]
@@ -2863,7 +3734,7 @@ pub struct LoadBalancer {
 
 /// <p>Describes a load balancer SSL/TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBalancerTlsCertificate {
     /// <p>The Amazon Resource Name (ARN) of the SSL/TLS certificate.</p>
     #[serde(rename = "arn")]
@@ -2969,7 +3840,7 @@ pub struct LoadBalancerTlsCertificate {
 
 /// <p>Contains information about the domain names on an SSL/TLS certificate that you will use to validate domain ownership.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBalancerTlsCertificateDomainValidationOption {
     /// <p>The fully qualified domain name in the certificate request.</p>
     #[serde(rename = "domainName")]
@@ -2983,7 +3854,7 @@ pub struct LoadBalancerTlsCertificateDomainValidationOption {
 
 /// <p>Describes the validation record of each domain name in the SSL/TLS certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBalancerTlsCertificateDomainValidationRecord {
     /// <p>The domain name against which your SSL/TLS certificate was validated.</p>
     #[serde(rename = "domainName")]
@@ -3009,7 +3880,7 @@ pub struct LoadBalancerTlsCertificateDomainValidationRecord {
 
 /// <p>Contains information about the status of Lightsail's managed renewal for the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBalancerTlsCertificateRenewalSummary {
     /// <p>Contains information about the validation of each domain name in the certificate, as it pertains to Lightsail's managed renewal. This is different from the initial validation that occurs as a result of the RequestCertificate request.</p>
     #[serde(rename = "domainValidationOptions")]
@@ -3023,7 +3894,7 @@ pub struct LoadBalancerTlsCertificateRenewalSummary {
 
 /// <p>Provides a summary of SSL/TLS certificate metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBalancerTlsCertificateSummary {
     /// <p>When <code>true</code>, the SSL/TLS certificate is attached to the Lightsail load balancer.</p>
     #[serde(rename = "isAttached")]
@@ -3037,7 +3908,7 @@ pub struct LoadBalancerTlsCertificateSummary {
 
 /// <p>Describes a database log event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogEvent {
     /// <p>The timestamp when the database log event was created.</p>
     #[serde(rename = "createdAt")]
@@ -3051,7 +3922,7 @@ pub struct LogEvent {
 
 /// <p>Describes the metric data point.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MetricDatapoint {
     /// <p>The average.</p>
     #[serde(rename = "average")]
@@ -3085,7 +3956,7 @@ pub struct MetricDatapoint {
 
 /// <p>Describes the monthly data transfer in and out of your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MonthlyTransfer {
     /// <p>The amount allocated per month (in GB).</p>
     #[serde(rename = "gbPerMonthAllocated")]
@@ -3094,6 +3965,7 @@ pub struct MonthlyTransfer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OpenInstancePublicPortsRequest {
     /// <p>The name of the instance for which you want to open the public ports.</p>
     #[serde(rename = "instanceName")]
@@ -3103,8 +3975,20 @@ pub struct OpenInstancePublicPortsRequest {
     pub port_info: PortInfo,
 }
 
+impl Validate for OpenInstancePublicPortsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        self.port_info
+            .validate_at(&field_path(path, "port_info"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OpenInstancePublicPortsResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operation")]
@@ -3114,7 +3998,7 @@ pub struct OpenInstancePublicPortsResult {
 
 /// <p>Describes the API operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Operation {
     /// <p>The timestamp when the operation was initialized (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "createdAt")]
@@ -3168,7 +4052,7 @@ pub struct Operation {
 
 /// <p>The password data for the Windows Server-based instance, including the ciphertext and the key pair name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PasswordData {
     /// <p><p>The encrypted password. Ciphertext will be an empty string if access to your new instance is not ready yet. When you create an instance, it can take up to 15 minutes for the instance to be ready.</p> <note> <p>If you use the default key pair (<code>LightsailDefaultKeyPair</code>), the decrypted password will be available in the password field.</p> <p>If you are using a custom key pair, you need to use your own means of decryption.</p> <p>If you change the Administrator password on the instance, Lightsail will continue to return the original ciphertext value. When accessing the instance using RDP, you need to manually enter the Administrator password after changing it from the default.</p> </note></p>
     #[serde(rename = "ciphertext")]
@@ -3181,10 +4065,11 @@ pub struct PasswordData {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PeerVpcRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PeerVpcResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operation")]
@@ -3194,7 +4079,7 @@ pub struct PeerVpcResult {
 
 /// <p>Describes a pending database maintenance action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PendingMaintenanceAction {
     /// <p>The type of pending database maintenance action.</p>
     #[serde(rename = "action")]
@@ -3212,7 +4097,7 @@ pub struct PendingMaintenanceAction {
 
 /// <p>Describes a pending database value modification.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PendingModifiedRelationalDatabaseValues {
     /// <p>A Boolean value indicating whether automated backup retention is enabled.</p>
     #[serde(rename = "backupRetentionEnabled")]
@@ -3230,6 +4115,7 @@ pub struct PendingModifiedRelationalDatabaseValues {
 
 /// <p>Describes information about the ports on your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PortInfo {
     /// <p>The first port in the range.</p>
     #[serde(rename = "fromPort")]
@@ -3245,7 +4131,19 @@ pub struct PortInfo {
     pub to_port: Option<i64>,
 }
 
+impl Validate for PortInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.from_port {
+            errors.check_range(&field_path(path, "from_port"), *value, None, Some(65535));
+        }
+        if let Some(ref value) = self.to_port {
+            errors.check_range(&field_path(path, "to_port"), *value, None, Some(65535));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutInstancePublicPortsRequest {
     /// <p>The Lightsail instance name of the public port(s) you are setting.</p>
     #[serde(rename = "instanceName")]
@@ -3255,8 +4153,22 @@ pub struct PutInstancePublicPortsRequest {
     pub port_infos: Vec<PortInfo>,
 }
 
+impl Validate for PutInstancePublicPortsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        for (i, item) in self.port_infos.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "port_infos"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutInstancePublicPortsResult {
     /// <p>Describes metadata about the operation you just executed.</p>
     #[serde(rename = "operation")]
@@ -3265,14 +4177,25 @@ pub struct PutInstancePublicPortsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootInstanceRequest {
     /// <p>The name of the instance to reboot.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for RebootInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebootInstanceResult {
     /// <p>An array of key-value pairs containing information about the request operations.</p>
     #[serde(rename = "operations")]
@@ -3281,14 +4204,25 @@ pub struct RebootInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootRelationalDatabaseRequest {
     /// <p>The name of your database to reboot.</p>
     #[serde(rename = "relationalDatabaseName")]
     pub relational_database_name: String,
 }
 
+impl Validate for RebootRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebootRelationalDatabaseResult {
     /// <p>An object describing the result of your reboot relational database request.</p>
     #[serde(rename = "operations")]
@@ -3298,7 +4232,7 @@ pub struct RebootRelationalDatabaseResult {
 
 /// <p>Describes the AWS Region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Region {
     /// <p>The Availability Zones. Follows the format <code>us-east-2a</code> (case-sensitive).</p>
     #[serde(rename = "availabilityZones")]
@@ -3328,7 +4262,7 @@ pub struct Region {
 
 /// <p>Describes a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabase {
     /// <p>The Amazon Resource Name (ARN) of the database.</p>
     #[serde(rename = "arn")]
@@ -3434,7 +4368,7 @@ pub struct RelationalDatabase {
 
 /// <p>Describes a database image, or blueprint. A blueprint describes the major engine version of a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseBlueprint {
     /// <p>The ID for the database blueprint.</p>
     #[serde(rename = "blueprintId")]
@@ -3464,7 +4398,7 @@ pub struct RelationalDatabaseBlueprint {
 
 /// <p>Describes a database bundle. A bundle describes the performance specifications of the database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseBundle {
     /// <p>The ID for the database bundle.</p>
     #[serde(rename = "bundleId")]
@@ -3506,7 +4440,7 @@ pub struct RelationalDatabaseBundle {
 
 /// <p>Describes an endpoint for a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseEndpoint {
     /// <p>Specifies the DNS address of the database.</p>
     #[serde(rename = "address")]
@@ -3520,7 +4454,7 @@ pub struct RelationalDatabaseEndpoint {
 
 /// <p>Describes an event for a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseEvent {
     /// <p>The timestamp when the database event was created.</p>
     #[serde(rename = "createdAt")]
@@ -3542,7 +4476,7 @@ pub struct RelationalDatabaseEvent {
 
 /// <p>Describes the hardware of a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseHardware {
     /// <p>The number of vCPUs for the database.</p>
     #[serde(rename = "cpuCount")]
@@ -3597,7 +4531,7 @@ pub struct RelationalDatabaseParameter {
 
 /// <p>Describes a database snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RelationalDatabaseSnapshot {
     /// <p>The Amazon Resource Name (ARN) of the database snapshot.</p>
     #[serde(rename = "arn")]
@@ -3662,14 +4596,25 @@ pub struct RelationalDatabaseSnapshot {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReleaseStaticIpRequest {
     /// <p>The name of the static IP to delete.</p>
     #[serde(rename = "staticIpName")]
     pub static_ip_name: String,
 }
 
+impl Validate for ReleaseStaticIpRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "static_ip_name"),
+            &self.static_ip_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReleaseStaticIpResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operations")]
@@ -3679,7 +4624,7 @@ pub struct ReleaseStaticIpResult {
 
 /// <p>Describes the resource location.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceLocation {
     /// <p>The Availability Zone. Follows the format <code>us-east-2a</code> (case-sensitive).</p>
     #[serde(rename = "availabilityZone")]
@@ -3692,14 +4637,25 @@ pub struct ResourceLocation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartInstanceRequest {
     /// <p>The name of the instance (a virtual private server) to start.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl Validate for StartInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartInstanceResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operations")]
@@ -3708,14 +4664,25 @@ pub struct StartInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartRelationalDatabaseRequest {
     /// <p>The name of your database to start.</p>
     #[serde(rename = "relationalDatabaseName")]
     pub relational_database_name: String,
 }
 
+impl Validate for StartRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartRelationalDatabaseResult {
     /// <p>An object describing the result of your start relational database request.</p>
     #[serde(rename = "operations")]
@@ -3725,7 +4692,7 @@ pub struct StartRelationalDatabaseResult {
 
 /// <p>Describes the static IP.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StaticIp {
     /// <p>The Amazon Resource Name (ARN) of the static IP (e.g., <code>arn:aws:lightsail:us-east-2:123456789101:StaticIp/9cbb4a9e-f8e3-4dfe-b57e-12345EXAMPLE</code>).</p>
     #[serde(rename = "arn")]
@@ -3766,6 +4733,7 @@ pub struct StaticIp {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopInstanceRequest {
     /// <p><p>When set to <code>True</code>, forces a Lightsail instance that is stuck in a <code>stopping</code> state to stop.</p> <important> <p>Only use the <code>force</code> parameter if your instance is stuck in the <code>stopping</code> state. In any other state, your instance should stop normally without adding this parameter to your API request.</p> </important></p>
     #[serde(rename = "force")]
@@ -3776,8 +4744,18 @@ pub struct StopInstanceRequest {
     pub instance_name: String,
 }
 
+impl Validate for StopInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_name"),
+            &self.instance_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopInstanceResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operations")]
@@ -3786,6 +4764,7 @@ pub struct StopInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopRelationalDatabaseRequest {
     /// <p>The name of your database to stop.</p>
     #[serde(rename = "relationalDatabaseName")]
@@ -3796,8 +4775,25 @@ pub struct StopRelationalDatabaseRequest {
     pub relational_database_snapshot_name: Option<String>,
 }
 
+impl Validate for StopRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+        if let Some(ref value) = self.relational_database_snapshot_name {
+            errors.check_pattern(
+                &field_path(path, "relational_database_snapshot_name"),
+                &value,
+                "\\w[\\w\\-]*\\w",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopRelationalDatabaseResult {
     /// <p>An object describing the result of your stop relational database request.</p>
     #[serde(rename = "operations")]
@@ -3819,6 +4815,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The name of the resource to which you are adding tags.</p>
     #[serde(rename = "resourceName")]
@@ -3828,8 +4825,18 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_name"),
+            &self.resource_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResult {
     /// <p>A list of objects describing the API operation.</p>
     #[serde(rename = "operations")]
@@ -3838,10 +4845,11 @@ pub struct TagResourceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnpeerVpcRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnpeerVpcResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operation")]
@@ -3850,6 +4858,7 @@ pub struct UnpeerVpcResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The name of the resource from which you are removing a tag.</p>
     #[serde(rename = "resourceName")]
@@ -3859,8 +4868,18 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_name"),
+            &self.resource_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResult {
     /// <p>A list of objects describing the API operation.</p>
     #[serde(rename = "operations")]
@@ -3869,6 +4888,7 @@ pub struct UntagResourceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainEntryRequest {
     /// <p>An array of key-value pairs containing information about the domain entry.</p>
     #[serde(rename = "domainEntry")]
@@ -3878,8 +4898,15 @@ pub struct UpdateDomainEntryRequest {
     pub domain_name: String,
 }
 
+impl Validate for UpdateDomainEntryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.domain_entry
+            .validate_at(&field_path(path, "domain_entry"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainEntryResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
     #[serde(rename = "operations")]
@@ -3888,6 +4915,7 @@ pub struct UpdateDomainEntryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLoadBalancerAttributeRequest {
     /// <p>The name of the attribute you want to update. Valid values are below.</p>
     #[serde(rename = "attributeName")]
@@ -3900,8 +4928,24 @@ pub struct UpdateLoadBalancerAttributeRequest {
     pub load_balancer_name: String,
 }
 
+impl Validate for UpdateLoadBalancerAttributeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_value"),
+            self.attribute_value.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "load_balancer_name"),
+            &self.load_balancer_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateLoadBalancerAttributeResult {
     /// <p>An object describing the API operations.</p>
     #[serde(rename = "operations")]
@@ -3910,6 +4954,7 @@ pub struct UpdateLoadBalancerAttributeResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRelationalDatabaseParametersRequest {
     /// <p>The database parameters to update.</p>
     #[serde(rename = "parameters")]
@@ -3919,8 +4964,18 @@ pub struct UpdateRelationalDatabaseParametersRequest {
     pub relational_database_name: String,
 }
 
+impl Validate for UpdateRelationalDatabaseParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRelationalDatabaseParametersResult {
     /// <p>An object describing the result of your update relational database parameters request.</p>
     #[serde(rename = "operations")]
@@ -3929,6 +4984,7 @@ pub struct UpdateRelationalDatabaseParametersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRelationalDatabaseRequest {
     /// <p>When <code>true</code>, applies changes immediately. When <code>false</code>, applies changes during the preferred maintenance window. Some changes may cause an outage.</p> <p>Default: <code>false</code> </p>
     #[serde(rename = "applyImmediately")]
@@ -3967,8 +5023,18 @@ pub struct UpdateRelationalDatabaseRequest {
     pub rotate_master_user_password: Option<bool>,
 }
 
+impl Validate for UpdateRelationalDatabaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "relational_database_name"),
+            &self.relational_database_name,
+            "\\w[\\w\\-]*\\w",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRelationalDatabaseResult {
     /// <p>An object describing the result of your update relational database request.</p>
     #[serde(rename = "operations")]
@@ -4022,7 +5088,12 @@ impl AllocateStaticIpError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(AllocateStaticIpError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4091,7 +5162,12 @@ impl AttachDiskError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(AttachDiskError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4176,7 +5252,12 @@ impl AttachInstancesToLoadBalancerError {
                         AttachInstancesToLoadBalancerError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4261,7 +5342,12 @@ impl AttachLoadBalancerTlsCertificateError {
                         AttachLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4332,7 +5418,12 @@ impl AttachStaticIpError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(AttachStaticIpError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4411,7 +5502,12 @@ impl CloseInstancePublicPortsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4480,7 +5576,12 @@ impl CopySnapshotError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CopySnapshotError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4559,7 +5660,12 @@ impl CreateCloudFormationStackError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4628,7 +5734,12 @@ impl CreateDiskError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateDiskError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4703,7 +5814,12 @@ impl CreateDiskFromSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4774,7 +5890,12 @@ impl CreateDiskSnapshotError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateDiskSnapshotError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4843,7 +5964,12 @@ impl CreateDomainError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateDomainError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4914,7 +6040,12 @@ impl CreateDomainEntryError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateDomainEntryError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4989,7 +6120,12 @@ impl CreateInstanceSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5060,7 +6196,12 @@ impl CreateInstancesError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateInstancesError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5143,7 +6284,12 @@ impl CreateInstancesFromSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5214,7 +6360,12 @@ impl CreateKeyPairError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateKeyPairError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5285,7 +6436,12 @@ impl CreateLoadBalancerError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(CreateLoadBalancerError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5370,7 +6526,12 @@ impl CreateLoadBalancerTlsCertificateError {
                         CreateLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5449,7 +6610,12 @@ impl CreateRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5534,7 +6700,12 @@ impl CreateRelationalDatabaseFromSnapshotError {
                         CreateRelationalDatabaseFromSnapshotError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5619,7 +6790,12 @@ impl CreateRelationalDatabaseSnapshotError {
                         CreateRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5688,7 +6864,12 @@ impl DeleteDiskError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteDiskError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5759,7 +6940,12 @@ impl DeleteDiskSnapshotError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteDiskSnapshotError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5828,7 +7014,12 @@ impl DeleteDomainError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteDomainError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5899,7 +7090,12 @@ impl DeleteDomainEntryError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteDomainEntryError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5970,7 +7166,12 @@ impl DeleteInstanceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteInstanceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6045,7 +7246,12 @@ impl DeleteInstanceSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6116,7 +7322,12 @@ impl DeleteKeyPairError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteKeyPairError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6189,7 +7400,12 @@ impl DeleteKnownHostKeysError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteKnownHostKeysError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6260,7 +7476,12 @@ impl DeleteLoadBalancerError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DeleteLoadBalancerError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6345,7 +7566,12 @@ impl DeleteLoadBalancerTlsCertificateError {
                         DeleteLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6424,7 +7650,12 @@ impl DeleteRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6509,7 +7740,12 @@ impl DeleteRelationalDatabaseSnapshotError {
                         DeleteRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6578,7 +7814,12 @@ impl DetachDiskError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DetachDiskError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6663,7 +7904,12 @@ impl DetachInstancesFromLoadBalancerError {
                         DetachInstancesFromLoadBalancerError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6734,7 +7980,12 @@ impl DetachStaticIpError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(DetachStaticIpError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6809,7 +8060,12 @@ impl DownloadDefaultKeyPairError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6880,7 +8136,12 @@ impl ExportSnapshotError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(ExportSnapshotError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6951,7 +8212,12 @@ impl GetActiveNamesError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetActiveNamesError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7022,7 +8288,12 @@ impl GetBlueprintsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetBlueprintsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7091,7 +8362,12 @@ impl GetBundlesError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetBundlesError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7176,7 +8452,12 @@ impl GetCloudFormationStackRecordsError {
                         GetCloudFormationStackRecordsError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7243,7 +8524,12 @@ impl GetDiskError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetDiskError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7314,7 +8600,12 @@ impl GetDiskSnapshotError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetDiskSnapshotError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7385,7 +8676,12 @@ impl GetDiskSnapshotsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetDiskSnapshotsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7452,7 +8748,12 @@ impl GetDisksError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetDisksError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7521,7 +8822,12 @@ impl GetDomainError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetDomainError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7590,7 +8896,12 @@ impl GetDomainsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetDomainsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7669,7 +8980,12 @@ impl GetExportSnapshotRecordsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7738,7 +9054,12 @@ impl GetInstanceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetInstanceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7817,7 +9138,12 @@ impl GetInstanceAccessDetailsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7892,7 +9218,12 @@ impl GetInstanceMetricDataError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7967,7 +9298,12 @@ impl GetInstancePortStatesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8040,7 +9376,12 @@ impl GetInstanceSnapshotError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetInstanceSnapshotError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8115,7 +9456,12 @@ impl GetInstanceSnapshotsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8186,7 +9532,12 @@ impl GetInstanceStateError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetInstanceStateError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8255,7 +9606,12 @@ impl GetInstancesError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetInstancesError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8324,7 +9680,12 @@ impl GetKeyPairError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetKeyPairError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8393,7 +9754,12 @@ impl GetKeyPairsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetKeyPairsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8464,7 +9830,12 @@ impl GetLoadBalancerError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetLoadBalancerError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8543,7 +9914,12 @@ impl GetLoadBalancerMetricDataError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8628,7 +10004,12 @@ impl GetLoadBalancerTlsCertificatesError {
                         GetLoadBalancerTlsCertificatesError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8699,7 +10080,12 @@ impl GetLoadBalancersError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetLoadBalancersError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8768,7 +10154,12 @@ impl GetOperationError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetOperationError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8839,7 +10230,12 @@ impl GetOperationsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetOperationsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8918,7 +10314,12 @@ impl GetOperationsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8987,7 +10388,12 @@ impl GetRegionsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetRegionsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9062,7 +10468,12 @@ impl GetRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9147,7 +10558,12 @@ impl GetRelationalDatabaseBlueprintsError {
                         GetRelationalDatabaseBlueprintsError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9232,7 +10648,12 @@ impl GetRelationalDatabaseBundlesError {
                         GetRelationalDatabaseBundlesError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9315,7 +10736,12 @@ impl GetRelationalDatabaseEventsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9400,7 +10826,12 @@ impl GetRelationalDatabaseLogEventsError {
                         GetRelationalDatabaseLogEventsError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9485,7 +10916,12 @@ impl GetRelationalDatabaseLogStreamsError {
                         GetRelationalDatabaseLogStreamsError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9572,7 +11008,12 @@ impl GetRelationalDatabaseMasterUserPasswordError {
                         GetRelationalDatabaseMasterUserPasswordError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9659,7 +11100,12 @@ impl GetRelationalDatabaseMetricDataError {
                         GetRelationalDatabaseMetricDataError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9744,7 +11190,12 @@ impl GetRelationalDatabaseParametersError {
                         GetRelationalDatabaseParametersError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9829,7 +11280,12 @@ impl GetRelationalDatabaseSnapshotError {
                         GetRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9914,7 +11370,12 @@ impl GetRelationalDatabaseSnapshotsError {
                         GetRelationalDatabaseSnapshotsError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9989,7 +11450,12 @@ impl GetRelationalDatabasesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10058,7 +11524,12 @@ impl GetStaticIpError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetStaticIpError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10127,7 +11598,12 @@ impl GetStaticIpsError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(GetStaticIpsError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10198,7 +11674,12 @@ impl ImportKeyPairError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(ImportKeyPairError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10267,7 +11748,12 @@ impl IsVpcPeeredError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(IsVpcPeeredError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10346,7 +11832,12 @@ impl OpenInstancePublicPortsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10413,7 +11904,12 @@ impl PeerVpcError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(PeerVpcError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10488,7 +11984,12 @@ impl PutInstancePublicPortsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10559,7 +12060,12 @@ impl RebootInstanceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(RebootInstanceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10638,7 +12144,12 @@ impl RebootRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10709,7 +12220,12 @@ impl ReleaseStaticIpError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(ReleaseStaticIpError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10780,7 +12296,12 @@ impl StartInstanceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(StartInstanceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10859,7 +12380,12 @@ impl StartRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10928,7 +12454,12 @@ impl StopInstanceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(StopInstanceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11003,7 +12534,12 @@ impl StopRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11072,7 +12608,12 @@ impl TagResourceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(TagResourceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11141,7 +12682,12 @@ impl UnpeerVpcError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(UnpeerVpcError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11212,7 +12758,12 @@ impl UntagResourceError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(UntagResourceError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11283,7 +12834,12 @@ impl UpdateDomainEntryError {
                 "UnauthenticatedException" => {
                     return RusotoError::Service(UpdateDomainEntryError::Unauthenticated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11366,7 +12922,12 @@ impl UpdateLoadBalancerAttributeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11445,7 +13006,12 @@ impl UpdateRelationalDatabaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11530,7 +13096,12 @@ impl UpdateRelationalDatabaseParametersError {
                         UpdateRelationalDatabaseParametersError::Unauthenticated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12153,629 +13724,130 @@ pub trait Lightsail {
         UpdateRelationalDatabaseParametersError,
     >;
 }
-/// A client for the Amazon Lightsail API.
-#[derive(Clone)]
-pub struct LightsailClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl LightsailClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> LightsailClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> LightsailClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> LightsailClient {
-        LightsailClient { client, region }
-    }
-}
-
-impl Lightsail for LightsailClient {
-    /// <p>Allocates a static IP address.</p>
+const _: Option<&dyn Lightsail> = None;
+impl<'a, T: Lightsail + ?Sized> Lightsail for &'a T {
     fn allocate_static_ip(
         &self,
         input: AllocateStaticIpRequest,
     ) -> RusotoFuture<AllocateStaticIpResult, AllocateStaticIpError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.AllocateStaticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AllocateStaticIpResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AllocateStaticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).allocate_static_ip(input)
     }
-
-    /// <p>Attaches a block storage disk to a running or stopped Lightsail instance and exposes it to the instance with the specified disk name.</p> <p>The <code>attach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn attach_disk(
         &self,
         input: AttachDiskRequest,
     ) -> RusotoFuture<AttachDiskResult, AttachDiskError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.AttachDisk");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachDiskResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachDiskError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_disk(input)
     }
-
-    /// <p>Attaches one or more Lightsail instances to a load balancer.</p> <p>After some time, the instances are attached to the load balancer and the health check status is available.</p> <p>The <code>attach instances to load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn attach_instances_to_load_balancer(
         &self,
         input: AttachInstancesToLoadBalancerRequest,
     ) -> RusotoFuture<AttachInstancesToLoadBalancerResult, AttachInstancesToLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.AttachInstancesToLoadBalancer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachInstancesToLoadBalancerResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AttachInstancesToLoadBalancerError::from_response(response))
-                }))
-            }
-        })
+        (**self).attach_instances_to_load_balancer(input)
     }
-
-    /// <p>Attaches a Transport Layer Security (TLS) certificate to your load balancer. TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>Once you create and validate your certificate, you can attach it to your load balancer. You can also use this API to rotate the certificates on your account. Use the <code>AttachLoadBalancerTlsCertificate</code> operation with the non-attached certificate, and it will replace the existing one and become the attached certificate.</p> <p>The <code>attach load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn attach_load_balancer_tls_certificate(
         &self,
         input: AttachLoadBalancerTlsCertificateRequest,
     ) -> RusotoFuture<AttachLoadBalancerTlsCertificateResult, AttachLoadBalancerTlsCertificateError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.AttachLoadBalancerTlsCertificate",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachLoadBalancerTlsCertificateResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AttachLoadBalancerTlsCertificateError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).attach_load_balancer_tls_certificate(input)
     }
-
-    /// <p>Attaches a static IP address to a specific Amazon Lightsail instance.</p>
     fn attach_static_ip(
         &self,
         input: AttachStaticIpRequest,
     ) -> RusotoFuture<AttachStaticIpResult, AttachStaticIpError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.AttachStaticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachStaticIpResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachStaticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_static_ip(input)
     }
-
-    /// <p>Closes the public ports on a specific Amazon Lightsail instance.</p> <p>The <code>close instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn close_instance_public_ports(
         &self,
         input: CloseInstancePublicPortsRequest,
     ) -> RusotoFuture<CloseInstancePublicPortsResult, CloseInstancePublicPortsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CloseInstancePublicPorts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CloseInstancePublicPortsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CloseInstancePublicPortsError::from_response(response))
-                }))
-            }
-        })
+        (**self).close_instance_public_ports(input)
     }
-
-    /// <p>Copies an instance or disk snapshot from one AWS Region to another in Amazon Lightsail.</p>
     fn copy_snapshot(
         &self,
         input: CopySnapshotRequest,
     ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CopySnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CopySnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CopySnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).copy_snapshot(input)
     }
-
-    /// <p><p>Creates an AWS CloudFormation stack, which creates a new Amazon EC2 instance from an exported Amazon Lightsail snapshot. This operation results in a CloudFormation stack record that can be used to track the AWS CloudFormation stack created. Use the <code>get cloud formation stack records</code> operation to get a list of the CloudFormation stacks created.</p> <important> <p>Wait until after your new Amazon EC2 instance is created before running the <code>create cloud formation stack</code> operation again with the same export snapshot record.</p> </important></p>
     fn create_cloud_formation_stack(
         &self,
         input: CreateCloudFormationStackRequest,
     ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CreateCloudFormationStack",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCloudFormationStackResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCloudFormationStackError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_cloud_formation_stack(input)
     }
-
-    /// <p>Creates a block storage disk that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_disk(
         &self,
         input: CreateDiskRequest,
     ) -> RusotoFuture<CreateDiskResult, CreateDiskError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateDisk");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDiskResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDiskError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_disk(input)
     }
-
-    /// <p>Creates a block storage disk from a disk snapshot that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_disk_from_snapshot(
         &self,
         input: CreateDiskFromSnapshotRequest,
     ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskFromSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDiskFromSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDiskFromSnapshotError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_disk_from_snapshot(input)
     }
-
-    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_disk_snapshot(
         &self,
         input: CreateDiskSnapshotRequest,
     ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDiskSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDiskSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_disk_snapshot(input)
     }
-
-    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_domain(
         &self,
         input: CreateDomainRequest,
     ) -> RusotoFuture<CreateDomainResult, CreateDomainError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDomainResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_domain(input)
     }
-
-    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_domain_entry(
         &self,
         input: CreateDomainEntryRequest,
     ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateDomainEntry");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDomainEntryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDomainEntryError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_domain_entry(input)
     }
-
-    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_instance_snapshot(
         &self,
         input: CreateInstanceSnapshotRequest,
     ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateInstanceSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInstanceSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateInstanceSnapshotError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_instance_snapshot(input)
     }
-
-    /// <p>Creates one or more Amazon Lightsail virtual private servers, or <i>instances</i>. Create instances using active blueprints. Inactive blueprints are listed to support customers with existing instances but are not necessarily available for launch of new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases. Use the get blueprints operation to return a list of available blueprints.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_instances(
         &self,
         input: CreateInstancesRequest,
     ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInstancesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateInstancesError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_instances(input)
     }
-
-    /// <p>Uses a specific snapshot as a blueprint for creating one or more new instances that are based on that identical configuration.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_instances_from_snapshot(
         &self,
         input: CreateInstancesFromSnapshotRequest,
     ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CreateInstancesFromSnapshot",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInstancesFromSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateInstancesFromSnapshotError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_instances_from_snapshot(input)
     }
-
-    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_key_pair(
         &self,
         input: CreateKeyPairRequest,
     ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateKeyPair");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateKeyPairResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateKeyPairError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_key_pair(input)
     }
-
-    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_load_balancer(
         &self,
         input: CreateLoadBalancerRequest,
     ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.CreateLoadBalancer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLoadBalancerResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLoadBalancerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_load_balancer(input)
     }
-
-    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>create load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_load_balancer_tls_certificate(
         &self,
         input: CreateLoadBalancerTlsCertificateRequest,
     ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CreateLoadBalancerTlsCertificate",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLoadBalancerTlsCertificateResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateLoadBalancerTlsCertificateError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_load_balancer_tls_certificate(input)
     }
-
-    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_relational_database(
         &self,
         input: CreateRelationalDatabaseRequest,
     ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CreateRelationalDatabase",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateRelationalDatabaseError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_relational_database(input)
     }
-
-    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_relational_database_from_snapshot(
         &self,
         input: CreateRelationalDatabaseFromSnapshotRequest,
@@ -12783,1568 +13855,324 @@ impl Lightsail for LightsailClient {
         CreateRelationalDatabaseFromSnapshotResult,
         CreateRelationalDatabaseFromSnapshotError,
     > {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CreateRelationalDatabaseFromSnapshot",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRelationalDatabaseFromSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateRelationalDatabaseFromSnapshotError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_relational_database_from_snapshot(input)
     }
-
-    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn create_relational_database_snapshot(
         &self,
         input: CreateRelationalDatabaseSnapshotRequest,
     ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.CreateRelationalDatabaseSnapshot",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRelationalDatabaseSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateRelationalDatabaseSnapshotError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_relational_database_snapshot(input)
     }
-
-    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_disk(
         &self,
         input: DeleteDiskRequest,
     ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDisk");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDiskResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDiskError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_disk(input)
     }
-
-    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_disk_snapshot(
         &self,
         input: DeleteDiskSnapshotRequest,
     ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDiskSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDiskSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDiskSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_disk_snapshot(input)
     }
-
-    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_domain(
         &self,
         input: DeleteDomainRequest,
     ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDomainResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_domain(input)
     }
-
-    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_domain_entry(
         &self,
         input: DeleteDomainEntryRequest,
     ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomainEntry");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDomainEntryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDomainEntryError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_domain_entry(input)
     }
-
-    /// <p>Deletes a specific Amazon Lightsail virtual private server, or <i>instance</i>.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_instance(
         &self,
         input: DeleteInstanceRequest,
     ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_instance(input)
     }
-
-    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_instance_snapshot(
         &self,
         input: DeleteInstanceSnapshotRequest,
     ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstanceSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInstanceSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteInstanceSnapshotError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_instance_snapshot(input)
     }
-
-    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by keyPairName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_key_pair(
         &self,
         input: DeleteKeyPairRequest,
     ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteKeyPair");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteKeyPairResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteKeyPairError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_key_pair(input)
     }
-
-    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
     fn delete_known_host_keys(
         &self,
         input: DeleteKnownHostKeysRequest,
     ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteKnownHostKeys");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteKnownHostKeysResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteKnownHostKeysError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_known_host_keys(input)
     }
-
-    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_load_balancer(
         &self,
         input: DeleteLoadBalancerRequest,
     ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DeleteLoadBalancer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLoadBalancerResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLoadBalancerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_load_balancer(input)
     }
-
-    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>delete load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_load_balancer_tls_certificate(
         &self,
         input: DeleteLoadBalancerTlsCertificateRequest,
     ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.DeleteLoadBalancerTlsCertificate",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLoadBalancerTlsCertificateResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteLoadBalancerTlsCertificateError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_load_balancer_tls_certificate(input)
     }
-
-    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_relational_database(
         &self,
         input: DeleteRelationalDatabaseRequest,
     ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.DeleteRelationalDatabase",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRelationalDatabaseError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_relational_database(input)
     }
-
-    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn delete_relational_database_snapshot(
         &self,
         input: DeleteRelationalDatabaseSnapshotRequest,
     ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.DeleteRelationalDatabaseSnapshot",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRelationalDatabaseSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteRelationalDatabaseSnapshotError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_relational_database_snapshot(input)
     }
-
-    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn detach_disk(
         &self,
         input: DetachDiskRequest,
     ) -> RusotoFuture<DetachDiskResult, DetachDiskError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DetachDisk");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachDiskResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachDiskError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_disk(input)
     }
-
-    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn detach_instances_from_load_balancer(
         &self,
         input: DetachInstancesFromLoadBalancerRequest,
     ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.DetachInstancesFromLoadBalancer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachInstancesFromLoadBalancerResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DetachInstancesFromLoadBalancerError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).detach_instances_from_load_balancer(input)
     }
-
-    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
     fn detach_static_ip(
         &self,
         input: DetachStaticIpRequest,
     ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DetachStaticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachStaticIpResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachStaticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_static_ip(input)
     }
-
-    /// <p>Downloads the default SSH key pair from the user's account.</p>
     fn download_default_key_pair(
         &self,
     ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.DownloadDefaultKeyPair");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DownloadDefaultKeyPairResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DownloadDefaultKeyPairError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).download_default_key_pair()
     }
-
-    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by sourceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
     fn export_snapshot(
         &self,
         input: ExportSnapshotRequest,
     ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.ExportSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExportSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ExportSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).export_snapshot(input)
     }
-
-    /// <p>Returns the names of all active (not deleted) resources.</p>
     fn get_active_names(
         &self,
         input: GetActiveNamesRequest,
     ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetActiveNames");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetActiveNamesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetActiveNamesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_active_names(input)
     }
-
-    /// <p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new virtual private server already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p>
     fn get_blueprints(
         &self,
         input: GetBlueprintsRequest,
     ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetBlueprints");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBlueprintsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBlueprintsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_blueprints(input)
     }
-
-    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
     fn get_bundles(
         &self,
         input: GetBundlesRequest,
     ) -> RusotoFuture<GetBundlesResult, GetBundlesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetBundles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBundlesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBundlesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_bundles(input)
     }
-
-    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
     fn get_cloud_formation_stack_records(
         &self,
         input: GetCloudFormationStackRecordsRequest,
     ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetCloudFormationStackRecords",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCloudFormationStackRecordsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCloudFormationStackRecordsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_cloud_formation_stack_records(input)
     }
-
-    /// <p>Returns information about a specific block storage disk.</p>
     fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetDisk");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetDiskResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDiskError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_disk(input)
     }
-
-    /// <p>Returns information about a specific block storage disk snapshot.</p>
     fn get_disk_snapshot(
         &self,
         input: GetDiskSnapshotRequest,
     ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDiskSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDiskSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_disk_snapshot(input)
     }
-
-    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p> <p>If you are describing a long list of disk snapshots, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
     fn get_disk_snapshots(
         &self,
         input: GetDiskSnapshotsRequest,
     ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshots");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDiskSnapshotsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDiskSnapshotsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_disk_snapshots(input)
     }
-
-    /// <p>Returns information about all block storage disks in your AWS account and region.</p> <p>If you are describing a long list of disks, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
     fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetDisks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetDisksResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDisksError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_disks(input)
     }
-
-    /// <p>Returns information about a specific domain recordset.</p>
     fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetDomainResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_domain(input)
     }
-
-    /// <p>Returns a list of all domains in the user's account.</p>
     fn get_domains(
         &self,
         input: GetDomainsRequest,
     ) -> RusotoFuture<GetDomainsResult, GetDomainsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetDomains");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDomainsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDomainsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_domains(input)
     }
-
-    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
     fn get_export_snapshot_records(
         &self,
         input: GetExportSnapshotRecordsRequest,
     ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetExportSnapshotRecords",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetExportSnapshotRecordsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetExportSnapshotRecordsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_export_snapshot_records(input)
     }
-
-    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
     fn get_instance(
         &self,
         input: GetInstanceRequest,
     ) -> RusotoFuture<GetInstanceResult, GetInstanceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_instance(input)
     }
-
-    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn get_instance_access_details(
         &self,
         input: GetInstanceAccessDetailsRequest,
     ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetInstanceAccessDetails",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceAccessDetailsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetInstanceAccessDetailsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_instance_access_details(input)
     }
-
-    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p>
     fn get_instance_metric_data(
         &self,
         input: GetInstanceMetricDataRequest,
     ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceMetricData");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceMetricDataResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetInstanceMetricDataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_instance_metric_data(input)
     }
-
-    /// <p>Returns the port states for a specific virtual private server, or <i>instance</i>.</p>
     fn get_instance_port_states(
         &self,
         input: GetInstancePortStatesRequest,
     ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstancePortStates");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstancePortStatesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetInstancePortStatesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_instance_port_states(input)
     }
-
-    /// <p>Returns information about a specific instance snapshot.</p>
     fn get_instance_snapshot(
         &self,
         input: GetInstanceSnapshotRequest,
     ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetInstanceSnapshotError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_instance_snapshot(input)
     }
-
-    /// <p>Returns all instance snapshots for the user's account.</p>
     fn get_instance_snapshots(
         &self,
         input: GetInstanceSnapshotsRequest,
     ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshots");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceSnapshotsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetInstanceSnapshotsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_instance_snapshots(input)
     }
-
-    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
     fn get_instance_state(
         &self,
         input: GetInstanceStateRequest,
     ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceState");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceStateResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInstanceStateError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_instance_state(input)
     }
-
-    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
     fn get_instances(
         &self,
         input: GetInstancesRequest,
     ) -> RusotoFuture<GetInstancesResult, GetInstancesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstancesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInstancesError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_instances(input)
     }
-
-    /// <p>Returns information about a specific key pair.</p>
     fn get_key_pair(
         &self,
         input: GetKeyPairRequest,
     ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPair");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetKeyPairResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetKeyPairError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_key_pair(input)
     }
-
-    /// <p>Returns information about all key pairs in the user's account.</p>
     fn get_key_pairs(
         &self,
         input: GetKeyPairsRequest,
     ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPairs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetKeyPairsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetKeyPairsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_key_pairs(input)
     }
-
-    /// <p>Returns information about the specified Lightsail load balancer.</p>
     fn get_load_balancer(
         &self,
         input: GetLoadBalancerRequest,
     ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoadBalancerResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLoadBalancerError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_load_balancer(input)
     }
-
-    /// <p>Returns information about health metrics for your Lightsail load balancer.</p>
     fn get_load_balancer_metric_data(
         &self,
         input: GetLoadBalancerMetricDataRequest,
     ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetLoadBalancerMetricData",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoadBalancerMetricDataResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetLoadBalancerMetricDataError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_load_balancer_metric_data(input)
     }
-
-    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
     fn get_load_balancer_tls_certificates(
         &self,
         input: GetLoadBalancerTlsCertificatesRequest,
     ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetLoadBalancerTlsCertificates",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoadBalancerTlsCertificatesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetLoadBalancerTlsCertificatesError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_load_balancer_tls_certificates(input)
     }
-
-    /// <p>Returns information about all load balancers in an account.</p> <p>If you are describing a long list of load balancers, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
     fn get_load_balancers(
         &self,
         input: GetLoadBalancersRequest,
     ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoadBalancersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLoadBalancersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_load_balancers(input)
     }
-
-    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
     fn get_operation(
         &self,
         input: GetOperationRequest,
     ) -> RusotoFuture<GetOperationResult, GetOperationError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetOperation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOperationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetOperationError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_operation(input)
     }
-
-    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
     fn get_operations(
         &self,
         input: GetOperationsRequest,
     ) -> RusotoFuture<GetOperationsResult, GetOperationsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetOperations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOperationsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetOperationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_operations(input)
     }
-
-    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
     fn get_operations_for_resource(
         &self,
         input: GetOperationsForResourceRequest,
     ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetOperationsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOperationsForResourceResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetOperationsForResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_operations_for_resource(input)
     }
-
-    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
     fn get_regions(
         &self,
         input: GetRegionsRequest,
     ) -> RusotoFuture<GetRegionsResult, GetRegionsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetRegions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRegionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRegionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_regions(input)
     }
-
-    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
     fn get_relational_database(
         &self,
         input: GetRelationalDatabaseRequest,
     ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRelationalDatabaseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_relational_database(input)
     }
-
-    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
     fn get_relational_database_blueprints(
         &self,
         input: GetRelationalDatabaseBlueprintsRequest,
     ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseBlueprints",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseBlueprintsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseBlueprintsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_relational_database_blueprints(input)
     }
-
-    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
     fn get_relational_database_bundles(
         &self,
         input: GetRelationalDatabaseBundlesRequest,
     ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseBundles",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseBundlesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseBundlesError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_relational_database_bundles(input)
     }
-
-    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
     fn get_relational_database_events(
         &self,
         input: GetRelationalDatabaseEventsRequest,
     ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseEvents",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseEventsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseEventsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_relational_database_events(input)
     }
-
-    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
     fn get_relational_database_log_events(
         &self,
         input: GetRelationalDatabaseLogEventsRequest,
     ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseLogEvents",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseLogEventsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseLogEventsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_relational_database_log_events(input)
     }
-
-    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
     fn get_relational_database_log_streams(
         &self,
         input: GetRelationalDatabaseLogStreamsRequest,
     ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseLogStreams",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseLogStreamsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseLogStreamsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_relational_database_log_streams(input)
     }
-
-    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>asdf</code> operation GetRelationalDatabaseMasterUserPassword supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
     fn get_relational_database_master_user_password(
         &self,
         input: GetRelationalDatabaseMasterUserPasswordRequest,
@@ -14352,742 +14180,8434 @@ impl Lightsail for LightsailClient {
         GetRelationalDatabaseMasterUserPasswordResult,
         GetRelationalDatabaseMasterUserPasswordError,
     > {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseMasterUserPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseMasterUserPasswordResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseMasterUserPasswordError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_relational_database_master_user_password(input)
     }
-
-    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p>
     fn get_relational_database_metric_data(
         &self,
         input: GetRelationalDatabaseMetricDataRequest,
     ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseMetricData",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseMetricDataResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseMetricDataError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_relational_database_metric_data(input)
     }
-
-    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
     fn get_relational_database_parameters(
         &self,
         input: GetRelationalDatabaseParametersRequest,
     ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseParameters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseParametersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseParametersError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_relational_database_parameters(input)
     }
-
-    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
     fn get_relational_database_snapshot(
         &self,
         input: GetRelationalDatabaseSnapshotRequest,
     ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseSnapshot",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseSnapshotResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseSnapshotError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_relational_database_snapshot(input)
     }
-
-    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
     fn get_relational_database_snapshots(
         &self,
         input: GetRelationalDatabaseSnapshotsRequest,
     ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>
     {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.GetRelationalDatabaseSnapshots",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabaseSnapshotsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRelationalDatabaseSnapshotsError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_relational_database_snapshots(input)
     }
-
-    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
     fn get_relational_databases(
         &self,
         input: GetRelationalDatabasesRequest,
     ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRelationalDatabasesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetRelationalDatabasesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_relational_databases(input)
     }
-
-    /// <p>Returns information about a specific static IP.</p>
     fn get_static_ip(
         &self,
         input: GetStaticIpRequest,
     ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetStaticIpResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStaticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_static_ip(input)
     }
-
-    /// <p>Returns information about all static IPs in the user's account.</p>
     fn get_static_ips(
         &self,
         input: GetStaticIpsRequest,
     ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIps");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetStaticIpsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetStaticIpsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_static_ips(input)
     }
-
-    /// <p>Imports a public SSH key from a specific key pair.</p>
     fn import_key_pair(
         &self,
         input: ImportKeyPairRequest,
     ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.ImportKeyPair");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportKeyPairResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ImportKeyPairError::from_response(response))),
-                )
-            }
-        })
+        (**self).import_key_pair(input)
     }
-
-    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
     fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.IsVpcPeered");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IsVpcPeeredResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(IsVpcPeeredError::from_response(response))),
-                )
-            }
-        })
+        (**self).is_vpc_peered()
     }
-
-    /// <p>Adds public ports to an Amazon Lightsail instance.</p> <p>The <code>open instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn open_instance_public_ports(
         &self,
         input: OpenInstancePublicPortsRequest,
     ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.OpenInstancePublicPorts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<OpenInstancePublicPortsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(OpenInstancePublicPortsError::from_response(response))
-                }))
-            }
-        })
+        (**self).open_instance_public_ports(input)
     }
-
-    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
     fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.PeerVpc");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PeerVpcResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PeerVpcError::from_response(response))),
-                )
-            }
-        })
+        (**self).peer_vpc()
     }
-
-    /// <p>Sets the specified open ports for an Amazon Lightsail instance, and closes all ports for every protocol not included in the current request.</p> <p>The <code>put instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn put_instance_public_ports(
         &self,
         input: PutInstancePublicPortsRequest,
     ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.PutInstancePublicPorts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutInstancePublicPortsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutInstancePublicPortsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_instance_public_ports(input)
     }
-
-    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn reboot_instance(
         &self,
         input: RebootInstanceRequest,
     ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.RebootInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RebootInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebootInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).reboot_instance(input)
     }
-
-    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn reboot_relational_database(
         &self,
         input: RebootRelationalDatabaseRequest,
     ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.RebootRelationalDatabase",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RebootRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RebootRelationalDatabaseError::from_response(response))
-                }))
-            }
-        })
+        (**self).reboot_relational_database(input)
     }
-
-    /// <p>Deletes a specific static IP from your account.</p>
     fn release_static_ip(
         &self,
         input: ReleaseStaticIpRequest,
     ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.ReleaseStaticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ReleaseStaticIpResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ReleaseStaticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).release_static_ip(input)
+    }
+    fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> RusotoFuture<StartInstanceResult, StartInstanceError> {
+        (**self).start_instance(input)
+    }
+    fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError> {
+        (**self).start_relational_database(input)
+    }
+    fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> RusotoFuture<StopInstanceResult, StopInstanceError> {
+        (**self).stop_instance(input)
+    }
+    fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError> {
+        (**self).stop_relational_database(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResult, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError> {
+        (**self).unpeer_vpc()
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResult, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError> {
+        (**self).update_domain_entry(input)
+    }
+    fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError> {
+        (**self).update_load_balancer_attribute(input)
+    }
+    fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError> {
+        (**self).update_relational_database(input)
+    }
+    fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<
+        UpdateRelationalDatabaseParametersResult,
+        UpdateRelationalDatabaseParametersError,
+    > {
+        (**self).update_relational_database_parameters(input)
+    }
+}
+impl<T: Lightsail + ?Sized> Lightsail for Box<T> {
+    fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> RusotoFuture<AllocateStaticIpResult, AllocateStaticIpError> {
+        (**self).allocate_static_ip(input)
+    }
+    fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> RusotoFuture<AttachDiskResult, AttachDiskError> {
+        (**self).attach_disk(input)
+    }
+    fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> RusotoFuture<AttachInstancesToLoadBalancerResult, AttachInstancesToLoadBalancerError> {
+        (**self).attach_instances_to_load_balancer(input)
+    }
+    fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<AttachLoadBalancerTlsCertificateResult, AttachLoadBalancerTlsCertificateError>
+    {
+        (**self).attach_load_balancer_tls_certificate(input)
+    }
+    fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> RusotoFuture<AttachStaticIpResult, AttachStaticIpError> {
+        (**self).attach_static_ip(input)
+    }
+    fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> RusotoFuture<CloseInstancePublicPortsResult, CloseInstancePublicPortsError> {
+        (**self).close_instance_public_ports(input)
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        (**self).copy_snapshot(input)
+    }
+    fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError> {
+        (**self).create_cloud_formation_stack(input)
+    }
+    fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> RusotoFuture<CreateDiskResult, CreateDiskError> {
+        (**self).create_disk(input)
+    }
+    fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError> {
+        (**self).create_disk_from_snapshot(input)
+    }
+    fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError> {
+        (**self).create_disk_snapshot(input)
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResult, CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError> {
+        (**self).create_domain_entry(input)
+    }
+    fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError> {
+        (**self).create_instance_snapshot(input)
+    }
+    fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError> {
+        (**self).create_instances(input)
+    }
+    fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError> {
+        (**self).create_instances_from_snapshot(input)
+    }
+    fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError> {
+        (**self).create_key_pair(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>
+    {
+        (**self).create_load_balancer_tls_certificate(input)
+    }
+    fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError> {
+        (**self).create_relational_database(input)
+    }
+    fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> RusotoFuture<
+        CreateRelationalDatabaseFromSnapshotResult,
+        CreateRelationalDatabaseFromSnapshotError,
+    > {
+        (**self).create_relational_database_from_snapshot(input)
+    }
+    fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>
+    {
+        (**self).create_relational_database_snapshot(input)
+    }
+    fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError> {
+        (**self).delete_disk(input)
+    }
+    fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError> {
+        (**self).delete_disk_snapshot(input)
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError> {
+        (**self).delete_domain_entry(input)
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
+    fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError> {
+        (**self).delete_instance_snapshot(input)
+    }
+    fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError> {
+        (**self).delete_key_pair(input)
+    }
+    fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError> {
+        (**self).delete_known_host_keys(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>
+    {
+        (**self).delete_load_balancer_tls_certificate(input)
+    }
+    fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError> {
+        (**self).delete_relational_database(input)
+    }
+    fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>
+    {
+        (**self).delete_relational_database_snapshot(input)
+    }
+    fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> RusotoFuture<DetachDiskResult, DetachDiskError> {
+        (**self).detach_disk(input)
+    }
+    fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>
+    {
+        (**self).detach_instances_from_load_balancer(input)
+    }
+    fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError> {
+        (**self).detach_static_ip(input)
+    }
+    fn download_default_key_pair(
+        &self,
+    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError> {
+        (**self).download_default_key_pair()
+    }
+    fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError> {
+        (**self).export_snapshot(input)
+    }
+    fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError> {
+        (**self).get_active_names(input)
+    }
+    fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError> {
+        (**self).get_blueprints(input)
+    }
+    fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> RusotoFuture<GetBundlesResult, GetBundlesError> {
+        (**self).get_bundles(input)
+    }
+    fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError> {
+        (**self).get_cloud_formation_stack_records(input)
+    }
+    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError> {
+        (**self).get_disk(input)
+    }
+    fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError> {
+        (**self).get_disk_snapshot(input)
+    }
+    fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
+        (**self).get_disk_snapshots(input)
+    }
+    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError> {
+        (**self).get_disks(input)
+    }
+    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError> {
+        (**self).get_domain(input)
+    }
+    fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> RusotoFuture<GetDomainsResult, GetDomainsError> {
+        (**self).get_domains(input)
+    }
+    fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError> {
+        (**self).get_export_snapshot_records(input)
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResult, GetInstanceError> {
+        (**self).get_instance(input)
+    }
+    fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError> {
+        (**self).get_instance_access_details(input)
+    }
+    fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError> {
+        (**self).get_instance_metric_data(input)
+    }
+    fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError> {
+        (**self).get_instance_port_states(input)
+    }
+    fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError> {
+        (**self).get_instance_snapshot(input)
+    }
+    fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError> {
+        (**self).get_instance_snapshots(input)
+    }
+    fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError> {
+        (**self).get_instance_state(input)
+    }
+    fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> RusotoFuture<GetInstancesResult, GetInstancesError> {
+        (**self).get_instances(input)
+    }
+    fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError> {
+        (**self).get_key_pair(input)
+    }
+    fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError> {
+        (**self).get_key_pairs(input)
+    }
+    fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError> {
+        (**self).get_load_balancer(input)
+    }
+    fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError> {
+        (**self).get_load_balancer_metric_data(input)
+    }
+    fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>
+    {
+        (**self).get_load_balancer_tls_certificates(input)
+    }
+    fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError> {
+        (**self).get_load_balancers(input)
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResult, GetOperationError> {
+        (**self).get_operation(input)
+    }
+    fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> RusotoFuture<GetOperationsResult, GetOperationsError> {
+        (**self).get_operations(input)
+    }
+    fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError> {
+        (**self).get_operations_for_resource(input)
+    }
+    fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> RusotoFuture<GetRegionsResult, GetRegionsError> {
+        (**self).get_regions(input)
+    }
+    fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError> {
+        (**self).get_relational_database(input)
+    }
+    fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>
+    {
+        (**self).get_relational_database_blueprints(input)
+    }
+    fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError> {
+        (**self).get_relational_database_bundles(input)
+    }
+    fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError> {
+        (**self).get_relational_database_events(input)
+    }
+    fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>
+    {
+        (**self).get_relational_database_log_events(input)
+    }
+    fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>
+    {
+        (**self).get_relational_database_log_streams(input)
+    }
+    fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> RusotoFuture<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        GetRelationalDatabaseMasterUserPasswordError,
+    > {
+        (**self).get_relational_database_master_user_password(input)
+    }
+    fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>
+    {
+        (**self).get_relational_database_metric_data(input)
+    }
+    fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>
+    {
+        (**self).get_relational_database_parameters(input)
+    }
+    fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError> {
+        (**self).get_relational_database_snapshot(input)
+    }
+    fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>
+    {
+        (**self).get_relational_database_snapshots(input)
+    }
+    fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError> {
+        (**self).get_relational_databases(input)
+    }
+    fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError> {
+        (**self).get_static_ip(input)
+    }
+    fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError> {
+        (**self).get_static_ips(input)
+    }
+    fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError> {
+        (**self).import_key_pair(input)
+    }
+    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError> {
+        (**self).is_vpc_peered()
+    }
+    fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError> {
+        (**self).open_instance_public_ports(input)
+    }
+    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError> {
+        (**self).peer_vpc()
+    }
+    fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError> {
+        (**self).put_instance_public_ports(input)
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError> {
+        (**self).reboot_instance(input)
+    }
+    fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError> {
+        (**self).reboot_relational_database(input)
+    }
+    fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError> {
+        (**self).release_static_ip(input)
+    }
+    fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> RusotoFuture<StartInstanceResult, StartInstanceError> {
+        (**self).start_instance(input)
+    }
+    fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError> {
+        (**self).start_relational_database(input)
+    }
+    fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> RusotoFuture<StopInstanceResult, StopInstanceError> {
+        (**self).stop_instance(input)
+    }
+    fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError> {
+        (**self).stop_relational_database(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResult, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError> {
+        (**self).unpeer_vpc()
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResult, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError> {
+        (**self).update_domain_entry(input)
+    }
+    fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError> {
+        (**self).update_load_balancer_attribute(input)
+    }
+    fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError> {
+        (**self).update_relational_database(input)
+    }
+    fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<
+        UpdateRelationalDatabaseParametersResult,
+        UpdateRelationalDatabaseParametersError,
+    > {
+        (**self).update_relational_database_parameters(input)
+    }
+}
+impl<T: Lightsail + ?Sized> Lightsail for ::std::sync::Arc<T> {
+    fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> RusotoFuture<AllocateStaticIpResult, AllocateStaticIpError> {
+        (**self).allocate_static_ip(input)
+    }
+    fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> RusotoFuture<AttachDiskResult, AttachDiskError> {
+        (**self).attach_disk(input)
+    }
+    fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> RusotoFuture<AttachInstancesToLoadBalancerResult, AttachInstancesToLoadBalancerError> {
+        (**self).attach_instances_to_load_balancer(input)
+    }
+    fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<AttachLoadBalancerTlsCertificateResult, AttachLoadBalancerTlsCertificateError>
+    {
+        (**self).attach_load_balancer_tls_certificate(input)
+    }
+    fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> RusotoFuture<AttachStaticIpResult, AttachStaticIpError> {
+        (**self).attach_static_ip(input)
+    }
+    fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> RusotoFuture<CloseInstancePublicPortsResult, CloseInstancePublicPortsError> {
+        (**self).close_instance_public_ports(input)
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        (**self).copy_snapshot(input)
+    }
+    fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError> {
+        (**self).create_cloud_formation_stack(input)
+    }
+    fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> RusotoFuture<CreateDiskResult, CreateDiskError> {
+        (**self).create_disk(input)
+    }
+    fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError> {
+        (**self).create_disk_from_snapshot(input)
+    }
+    fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError> {
+        (**self).create_disk_snapshot(input)
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResult, CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError> {
+        (**self).create_domain_entry(input)
+    }
+    fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError> {
+        (**self).create_instance_snapshot(input)
+    }
+    fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError> {
+        (**self).create_instances(input)
+    }
+    fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError> {
+        (**self).create_instances_from_snapshot(input)
+    }
+    fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError> {
+        (**self).create_key_pair(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>
+    {
+        (**self).create_load_balancer_tls_certificate(input)
+    }
+    fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError> {
+        (**self).create_relational_database(input)
+    }
+    fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> RusotoFuture<
+        CreateRelationalDatabaseFromSnapshotResult,
+        CreateRelationalDatabaseFromSnapshotError,
+    > {
+        (**self).create_relational_database_from_snapshot(input)
+    }
+    fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>
+    {
+        (**self).create_relational_database_snapshot(input)
+    }
+    fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError> {
+        (**self).delete_disk(input)
+    }
+    fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError> {
+        (**self).delete_disk_snapshot(input)
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError> {
+        (**self).delete_domain_entry(input)
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
+    fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError> {
+        (**self).delete_instance_snapshot(input)
+    }
+    fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError> {
+        (**self).delete_key_pair(input)
+    }
+    fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError> {
+        (**self).delete_known_host_keys(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>
+    {
+        (**self).delete_load_balancer_tls_certificate(input)
+    }
+    fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError> {
+        (**self).delete_relational_database(input)
+    }
+    fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>
+    {
+        (**self).delete_relational_database_snapshot(input)
+    }
+    fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> RusotoFuture<DetachDiskResult, DetachDiskError> {
+        (**self).detach_disk(input)
+    }
+    fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>
+    {
+        (**self).detach_instances_from_load_balancer(input)
+    }
+    fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError> {
+        (**self).detach_static_ip(input)
+    }
+    fn download_default_key_pair(
+        &self,
+    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError> {
+        (**self).download_default_key_pair()
+    }
+    fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError> {
+        (**self).export_snapshot(input)
+    }
+    fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError> {
+        (**self).get_active_names(input)
+    }
+    fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError> {
+        (**self).get_blueprints(input)
+    }
+    fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> RusotoFuture<GetBundlesResult, GetBundlesError> {
+        (**self).get_bundles(input)
+    }
+    fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError> {
+        (**self).get_cloud_formation_stack_records(input)
+    }
+    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError> {
+        (**self).get_disk(input)
+    }
+    fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError> {
+        (**self).get_disk_snapshot(input)
+    }
+    fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
+        (**self).get_disk_snapshots(input)
+    }
+    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError> {
+        (**self).get_disks(input)
+    }
+    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError> {
+        (**self).get_domain(input)
+    }
+    fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> RusotoFuture<GetDomainsResult, GetDomainsError> {
+        (**self).get_domains(input)
+    }
+    fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError> {
+        (**self).get_export_snapshot_records(input)
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResult, GetInstanceError> {
+        (**self).get_instance(input)
+    }
+    fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError> {
+        (**self).get_instance_access_details(input)
+    }
+    fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError> {
+        (**self).get_instance_metric_data(input)
+    }
+    fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError> {
+        (**self).get_instance_port_states(input)
+    }
+    fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError> {
+        (**self).get_instance_snapshot(input)
+    }
+    fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError> {
+        (**self).get_instance_snapshots(input)
+    }
+    fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError> {
+        (**self).get_instance_state(input)
+    }
+    fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> RusotoFuture<GetInstancesResult, GetInstancesError> {
+        (**self).get_instances(input)
+    }
+    fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError> {
+        (**self).get_key_pair(input)
+    }
+    fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError> {
+        (**self).get_key_pairs(input)
+    }
+    fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError> {
+        (**self).get_load_balancer(input)
+    }
+    fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError> {
+        (**self).get_load_balancer_metric_data(input)
+    }
+    fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>
+    {
+        (**self).get_load_balancer_tls_certificates(input)
+    }
+    fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError> {
+        (**self).get_load_balancers(input)
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResult, GetOperationError> {
+        (**self).get_operation(input)
+    }
+    fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> RusotoFuture<GetOperationsResult, GetOperationsError> {
+        (**self).get_operations(input)
+    }
+    fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError> {
+        (**self).get_operations_for_resource(input)
+    }
+    fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> RusotoFuture<GetRegionsResult, GetRegionsError> {
+        (**self).get_regions(input)
+    }
+    fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError> {
+        (**self).get_relational_database(input)
+    }
+    fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>
+    {
+        (**self).get_relational_database_blueprints(input)
+    }
+    fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError> {
+        (**self).get_relational_database_bundles(input)
+    }
+    fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError> {
+        (**self).get_relational_database_events(input)
+    }
+    fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>
+    {
+        (**self).get_relational_database_log_events(input)
+    }
+    fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>
+    {
+        (**self).get_relational_database_log_streams(input)
+    }
+    fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> RusotoFuture<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        GetRelationalDatabaseMasterUserPasswordError,
+    > {
+        (**self).get_relational_database_master_user_password(input)
+    }
+    fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>
+    {
+        (**self).get_relational_database_metric_data(input)
+    }
+    fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>
+    {
+        (**self).get_relational_database_parameters(input)
+    }
+    fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError> {
+        (**self).get_relational_database_snapshot(input)
+    }
+    fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>
+    {
+        (**self).get_relational_database_snapshots(input)
+    }
+    fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError> {
+        (**self).get_relational_databases(input)
+    }
+    fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError> {
+        (**self).get_static_ip(input)
+    }
+    fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError> {
+        (**self).get_static_ips(input)
+    }
+    fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError> {
+        (**self).import_key_pair(input)
+    }
+    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError> {
+        (**self).is_vpc_peered()
+    }
+    fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError> {
+        (**self).open_instance_public_ports(input)
+    }
+    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError> {
+        (**self).peer_vpc()
+    }
+    fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError> {
+        (**self).put_instance_public_ports(input)
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError> {
+        (**self).reboot_instance(input)
+    }
+    fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError> {
+        (**self).reboot_relational_database(input)
+    }
+    fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError> {
+        (**self).release_static_ip(input)
+    }
+    fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> RusotoFuture<StartInstanceResult, StartInstanceError> {
+        (**self).start_instance(input)
+    }
+    fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError> {
+        (**self).start_relational_database(input)
+    }
+    fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> RusotoFuture<StopInstanceResult, StopInstanceError> {
+        (**self).stop_instance(input)
+    }
+    fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError> {
+        (**self).stop_relational_database(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResult, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError> {
+        (**self).unpeer_vpc()
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResult, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError> {
+        (**self).update_domain_entry(input)
+    }
+    fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError> {
+        (**self).update_load_balancer_attribute(input)
+    }
+    fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError> {
+        (**self).update_relational_database(input)
+    }
+    fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<
+        UpdateRelationalDatabaseParametersResult,
+        UpdateRelationalDatabaseParametersError,
+    > {
+        (**self).update_relational_database_parameters(input)
+    }
+}
+/// A client for the Amazon Lightsail API.
+#[derive(Clone)]
+pub struct LightsailClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl LightsailClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LightsailClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LightsailClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> LightsailClient {
+        LightsailClient { client, region }
+    }
+}
+
+impl Lightsail for LightsailClient {
+    /// <p>Allocates a static IP address.</p>
+    fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> RusotoFuture<AllocateStaticIpResult, AllocateStaticIpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AllocateStaticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.AllocateStaticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AllocateStaticIpResult, _>(
+                    response,
+                    "AllocateStaticIp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AllocateStaticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches a block storage disk to a running or stopped Lightsail instance and exposes it to the instance with the specified disk name.</p> <p>The <code>attach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> RusotoFuture<AttachDiskResult, AttachDiskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachDisk");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.AttachDisk");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AttachDiskResult, _>(response, "AttachDisk")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachDiskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches one or more Lightsail instances to a load balancer.</p> <p>After some time, the instances are attached to the load balancer and the health check status is available.</p> <p>The <code>attach instances to load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> RusotoFuture<AttachInstancesToLoadBalancerResult, AttachInstancesToLoadBalancerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachInstancesToLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.AttachInstancesToLoadBalancer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AttachInstancesToLoadBalancerResult, _>(
+                    response,
+                    "AttachInstancesToLoadBalancer",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AttachInstancesToLoadBalancerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attaches a Transport Layer Security (TLS) certificate to your load balancer. TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>Once you create and validate your certificate, you can attach it to your load balancer. You can also use this API to rotate the certificates on your account. Use the <code>AttachLoadBalancerTlsCertificate</code> operation with the non-attached certificate, and it will replace the existing one and become the attached certificate.</p> <p>The <code>attach load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<AttachLoadBalancerTlsCertificateResult, AttachLoadBalancerTlsCertificateError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTlsCertificate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.AttachLoadBalancerTlsCertificate",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AttachLoadBalancerTlsCertificateResult, _>(
+                    response,
+                    "AttachLoadBalancerTlsCertificate",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AttachLoadBalancerTlsCertificateError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attaches a static IP address to a specific Amazon Lightsail instance.</p>
+    fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> RusotoFuture<AttachStaticIpResult, AttachStaticIpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("AttachStaticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.AttachStaticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AttachStaticIpResult, _>(
+                    response,
+                    "AttachStaticIp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachStaticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Closes the public ports on a specific Amazon Lightsail instance.</p> <p>The <code>close instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> RusotoFuture<CloseInstancePublicPortsResult, CloseInstancePublicPortsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CloseInstancePublicPorts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CloseInstancePublicPorts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CloseInstancePublicPortsResult, _>(
+                    response,
+                    "CloseInstancePublicPorts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CloseInstancePublicPortsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Copies an instance or disk snapshot from one AWS Region to another in Amazon Lightsail.</p>
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CopySnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CopySnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CopySnapshotResult, _>(
+                    response,
+                    "CopySnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CopySnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates an AWS CloudFormation stack, which creates a new Amazon EC2 instance from an exported Amazon Lightsail snapshot. This operation results in a CloudFormation stack record that can be used to track the AWS CloudFormation stack created. Use the <code>get cloud formation stack records</code> operation to get a list of the CloudFormation stacks created.</p> <important> <p>Wait until after your new Amazon EC2 instance is created before running the <code>create cloud formation stack</code> operation again with the same export snapshot record.</p> </important></p>
+    fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateCloudFormationStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CreateCloudFormationStack",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCloudFormationStackResult, _>(
+                    response,
+                    "CreateCloudFormationStack",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCloudFormationStackError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a block storage disk that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> RusotoFuture<CreateDiskResult, CreateDiskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDisk");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateDisk");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDiskResult, _>(response, "CreateDisk")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDiskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a block storage disk from a disk snapshot that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDiskFromSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskFromSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDiskFromSnapshotResult, _>(
+                    response,
+                    "CreateDiskFromSnapshot",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDiskFromSnapshotError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDiskSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDiskSnapshotResult, _>(
+                    response,
+                    "CreateDiskSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDiskSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResult, CreateDomainError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDomainResult, _>(
+                    response,
+                    "CreateDomain",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateDomainEntry");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateDomainEntry");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDomainEntryResult, _>(
+                    response,
+                    "CreateDomainEntry",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDomainEntryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstanceSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateInstanceSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateInstanceSnapshotResult, _>(
+                    response,
+                    "CreateInstanceSnapshot",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateInstanceSnapshotError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates one or more Amazon Lightsail virtual private servers, or <i>instances</i>. Create instances using active blueprints. Inactive blueprints are listed to support customers with existing instances but are not necessarily available for launch of new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases. Use the get blueprints operation to return a list of available blueprints.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateInstancesResult, _>(
+                    response,
+                    "CreateInstances",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInstancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Uses a specific snapshot as a blueprint for creating one or more new instances that are based on that identical configuration.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateInstancesFromSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CreateInstancesFromSnapshot",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateInstancesFromSnapshotResult, _>(
+                    response,
+                    "CreateInstancesFromSnapshot",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateInstancesFromSnapshotError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateKeyPair");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateKeyPair");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateKeyPairResult, _>(
+                    response,
+                    "CreateKeyPair",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateKeyPairError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.CreateLoadBalancer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateLoadBalancerResult, _>(
+                    response,
+                    "CreateLoadBalancer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLoadBalancerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>create load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateLoadBalancerTlsCertificate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CreateLoadBalancerTlsCertificate",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateLoadBalancerTlsCertificateResult, _>(
+                    response,
+                    "CreateLoadBalancerTlsCertificate",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateLoadBalancerTlsCertificateError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CreateRelationalDatabase",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRelationalDatabaseResult, _>(
+                    response,
+                    "CreateRelationalDatabase",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateRelationalDatabaseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> RusotoFuture<
+        CreateRelationalDatabaseFromSnapshotResult,
+        CreateRelationalDatabaseFromSnapshotError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabaseFromSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CreateRelationalDatabaseFromSnapshot",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRelationalDatabaseFromSnapshotResult, _>(
+                    response,
+                    "CreateRelationalDatabaseFromSnapshot",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateRelationalDatabaseFromSnapshotError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("CreateRelationalDatabaseSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.CreateRelationalDatabaseSnapshot",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRelationalDatabaseSnapshotResult, _>(
+                    response,
+                    "CreateRelationalDatabaseSnapshot",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateRelationalDatabaseSnapshotError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDisk");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDisk");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDiskResult, _>(response, "DeleteDisk")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDiskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDiskSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDiskSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDiskSnapshotResult, _>(
+                    response,
+                    "DeleteDiskSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDiskSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDomainResult, _>(
+                    response,
+                    "DeleteDomain",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteDomainEntry");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomainEntry");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDomainEntryResult, _>(
+                    response,
+                    "DeleteDomainEntry",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDomainEntryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specific Amazon Lightsail virtual private server, or <i>instance</i>.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteInstanceResult, _>(
+                    response,
+                    "DeleteInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteInstanceSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstanceSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteInstanceSnapshotResult, _>(
+                    response,
+                    "DeleteInstanceSnapshot",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteInstanceSnapshotError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by keyPairName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteKeyPair");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteKeyPair");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteKeyPairResult, _>(
+                    response,
+                    "DeleteKeyPair",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteKeyPairError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
+    fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteKnownHostKeys");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteKnownHostKeys");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteKnownHostKeysResult, _>(
+                    response,
+                    "DeleteKnownHostKeys",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteKnownHostKeysError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DeleteLoadBalancer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteLoadBalancerResult, _>(
+                    response,
+                    "DeleteLoadBalancer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLoadBalancerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>delete load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerTlsCertificate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.DeleteLoadBalancerTlsCertificate",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteLoadBalancerTlsCertificateResult, _>(
+                    response,
+                    "DeleteLoadBalancerTlsCertificate",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteLoadBalancerTlsCertificateError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.DeleteRelationalDatabase",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRelationalDatabaseResult, _>(
+                    response,
+                    "DeleteRelationalDatabase",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRelationalDatabaseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DeleteRelationalDatabaseSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.DeleteRelationalDatabaseSnapshot",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRelationalDatabaseSnapshotResult, _>(
+                    response,
+                    "DeleteRelationalDatabaseSnapshot",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteRelationalDatabaseSnapshotError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> RusotoFuture<DetachDiskResult, DetachDiskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachDisk");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DetachDisk");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetachDiskResult, _>(response, "DetachDisk")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachDiskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachInstancesFromLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.DetachInstancesFromLoadBalancer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetachInstancesFromLoadBalancerResult, _>(
+                    response,
+                    "DetachInstancesFromLoadBalancer",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DetachInstancesFromLoadBalancerError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
+    fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DetachStaticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DetachStaticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetachStaticIpResult, _>(
+                    response,
+                    "DetachStaticIp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachStaticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Downloads the default SSH key pair from the user's account.</p>
+    fn download_default_key_pair(
+        &self,
+    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("DownloadDefaultKeyPair");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.DownloadDefaultKeyPair");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DownloadDefaultKeyPairResult, _>(
+                    response,
+                    "DownloadDefaultKeyPair",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DownloadDefaultKeyPairError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by sourceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
+    fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ExportSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.ExportSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ExportSnapshotResult, _>(
+                    response,
+                    "ExportSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ExportSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the names of all active (not deleted) resources.</p>
+    fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetActiveNames");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetActiveNames");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetActiveNamesResult, _>(
+                    response,
+                    "GetActiveNames",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetActiveNamesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new virtual private server already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p>
+    fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetBlueprints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetBlueprints");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetBlueprintsResult, _>(
+                    response,
+                    "GetBlueprints",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBlueprintsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
+    fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> RusotoFuture<GetBundlesResult, GetBundlesError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetBundles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetBundles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetBundlesResult, _>(response, "GetBundles")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBundlesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
+    fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetCloudFormationStackRecords");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetCloudFormationStackRecords",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCloudFormationStackRecordsResult, _>(
+                    response,
+                    "GetCloudFormationStackRecords",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCloudFormationStackRecordsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific block storage disk.</p>
+    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDisk");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetDisk");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDiskResult, _>(response, "GetDisk")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDiskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific block storage disk snapshot.</p>
+    fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDiskSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDiskSnapshotResult, _>(
+                    response,
+                    "GetDiskSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDiskSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p> <p>If you are describing a long list of disk snapshots, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDiskSnapshots");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshots");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDiskSnapshotsResult, _>(
+                    response,
+                    "GetDiskSnapshots",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDiskSnapshotsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all block storage disks in your AWS account and region.</p> <p>If you are describing a long list of disks, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDisks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetDisks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDisksResult, _>(response, "GetDisks")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDisksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific domain recordset.</p>
+    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDomainResult, _>(response, "GetDomain")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of all domains in the user's account.</p>
+    fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> RusotoFuture<GetDomainsResult, GetDomainsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetDomains");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetDomains");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDomainsResult, _>(response, "GetDomains")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDomainsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
+    fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetExportSnapshotRecords");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetExportSnapshotRecords",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetExportSnapshotRecordsResult, _>(
+                    response,
+                    "GetExportSnapshotRecords",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetExportSnapshotRecordsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResult, GetInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceResult, _>(response, "GetInstance")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceAccessDetails");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetInstanceAccessDetails",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceAccessDetailsResult, _>(
+                    response,
+                    "GetInstanceAccessDetails",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetInstanceAccessDetailsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p>
+    fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceMetricData");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceMetricData");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceMetricDataResult, _>(
+                    response,
+                    "GetInstanceMetricData",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetInstanceMetricDataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the port states for a specific virtual private server, or <i>instance</i>.</p>
+    fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstancePortStates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstancePortStates");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstancePortStatesResult, _>(
+                    response,
+                    "GetInstancePortStates",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetInstancePortStatesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific instance snapshot.</p>
+    fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceSnapshotResult, _>(
+                    response,
+                    "GetInstanceSnapshot",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetInstanceSnapshotError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns all instance snapshots for the user's account.</p>
+    fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceSnapshots");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshots");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceSnapshotsResult, _>(
+                    response,
+                    "GetInstanceSnapshots",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetInstanceSnapshotsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
+    fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstanceState");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceState");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstanceStateResult, _>(
+                    response,
+                    "GetInstanceState",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInstanceStateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
+    fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> RusotoFuture<GetInstancesResult, GetInstancesError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInstancesResult, _>(
+                    response,
+                    "GetInstances",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInstancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific key pair.</p>
+    fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetKeyPair");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPair");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetKeyPairResult, _>(response, "GetKeyPair")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetKeyPairError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all key pairs in the user's account.</p>
+    fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetKeyPairs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPairs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetKeyPairsResult, _>(response, "GetKeyPairs")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetKeyPairsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified Lightsail load balancer.</p>
+    fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLoadBalancerResult, _>(
+                    response,
+                    "GetLoadBalancer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLoadBalancerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about health metrics for your Lightsail load balancer.</p>
+    fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancerMetricData");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetLoadBalancerMetricData",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLoadBalancerMetricDataResult, _>(
+                    response,
+                    "GetLoadBalancerMetricData",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetLoadBalancerMetricDataError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
+    fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancerTlsCertificates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetLoadBalancerTlsCertificates",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLoadBalancerTlsCertificatesResult, _>(
+                    response,
+                    "GetLoadBalancerTlsCertificates",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetLoadBalancerTlsCertificatesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about all load balancers in an account.</p> <p>If you are describing a long list of load balancers, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetLoadBalancers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLoadBalancersResult, _>(
+                    response,
+                    "GetLoadBalancers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLoadBalancersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResult, GetOperationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetOperation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOperationResult, _>(
+                    response,
+                    "GetOperation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetOperationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
+    fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> RusotoFuture<GetOperationsResult, GetOperationsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetOperations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOperationsResult, _>(
+                    response,
+                    "GetOperations",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetOperationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
+    fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetOperationsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetOperationsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOperationsForResourceResult, _>(
+                    response,
+                    "GetOperationsForResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetOperationsForResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
+    fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> RusotoFuture<GetRegionsResult, GetRegionsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRegions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetRegions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRegionsResult, _>(response, "GetRegions")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRegionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
+    fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseResult, _>(
+                    response,
+                    "GetRelationalDatabase",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRelationalDatabaseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
+    fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>
+    {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseBlueprints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseBlueprints",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseBlueprintsResult, _>(
+                    response,
+                    "GetRelationalDatabaseBlueprints",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseBlueprintsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
+    fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseBundles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseBundles",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseBundlesResult, _>(
+                    response,
+                    "GetRelationalDatabaseBundles",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseBundlesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
+    fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseEvents",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseEventsResult, _>(
+                    response,
+                    "GetRelationalDatabaseEvents",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseEventsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
+    fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseLogEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseLogEvents",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseLogEventsResult, _>(
+                    response,
+                    "GetRelationalDatabaseLogEvents",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseLogEventsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
+    fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseLogStreams");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseLogStreams",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseLogStreamsResult, _>(
+                    response,
+                    "GetRelationalDatabaseLogStreams",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseLogStreamsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>asdf</code> operation GetRelationalDatabaseMasterUserPassword supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
+    fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> RusotoFuture<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        GetRelationalDatabaseMasterUserPasswordError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseMasterUserPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseMasterUserPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseMasterUserPasswordResult, _>(response, "GetRelationalDatabaseMasterUserPassword")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseMasterUserPasswordError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p>
+    fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseMetricData");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseMetricData",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseMetricDataResult, _>(
+                    response,
+                    "GetRelationalDatabaseMetricData",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseMetricDataError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
+    fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseParameters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseParametersResult, _>(
+                    response,
+                    "GetRelationalDatabaseParameters",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseParametersError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
+    fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseSnapshot",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseSnapshotResult, _>(
+                    response,
+                    "GetRelationalDatabaseSnapshot",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseSnapshotError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
+    fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>
+    {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabaseSnapshots");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.GetRelationalDatabaseSnapshots",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabaseSnapshotsResult, _>(
+                    response,
+                    "GetRelationalDatabaseSnapshots",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRelationalDatabaseSnapshotsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
+    fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetRelationalDatabases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRelationalDatabasesResult, _>(
+                    response,
+                    "GetRelationalDatabases",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetRelationalDatabasesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a specific static IP.</p>
+    fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetStaticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetStaticIpResult, _>(response, "GetStaticIp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStaticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about all static IPs in the user's account.</p>
+    fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("GetStaticIps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIps");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetStaticIpsResult, _>(
+                    response,
+                    "GetStaticIps",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetStaticIpsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Imports a public SSH key from a specific key pair.</p>
+    fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ImportKeyPair");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.ImportKeyPair");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ImportKeyPairResult, _>(
+                    response,
+                    "ImportKeyPair",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ImportKeyPairError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
+    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("IsVpcPeered");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.IsVpcPeered");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<IsVpcPeeredResult, _>(response, "IsVpcPeered")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(IsVpcPeeredError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds public ports to an Amazon Lightsail instance.</p> <p>The <code>open instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("OpenInstancePublicPorts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.OpenInstancePublicPorts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<OpenInstancePublicPortsResult, _>(
+                    response,
+                    "OpenInstancePublicPorts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(OpenInstancePublicPortsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
+    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("PeerVpc");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.PeerVpc");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PeerVpcResult, _>(response, "PeerVpc")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PeerVpcError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the specified open ports for an Amazon Lightsail instance, and closes all ports for every protocol not included in the current request.</p> <p>The <code>put instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("PutInstancePublicPorts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.PutInstancePublicPorts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutInstancePublicPortsResult, _>(
+                    response,
+                    "PutInstancePublicPorts",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutInstancePublicPortsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("RebootInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.RebootInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RebootInstanceResult, _>(
+                    response,
+                    "RebootInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RebootInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("RebootRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.RebootRelationalDatabase",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RebootRelationalDatabaseResult, _>(
+                    response,
+                    "RebootRelationalDatabase",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RebootRelationalDatabaseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a specific static IP from your account.</p>
+    fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("ReleaseStaticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.ReleaseStaticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ReleaseStaticIpResult, _>(
+                    response,
+                    "ReleaseStaticIp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ReleaseStaticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> RusotoFuture<StartInstanceResult, StartInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StartInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.StartInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartInstanceResult, _>(
+                    response,
+                    "StartInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StartRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.StartRelationalDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartRelationalDatabaseResult, _>(
+                    response,
+                    "StartRelationalDatabase",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartRelationalDatabaseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> RusotoFuture<StopInstanceResult, StopInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StopInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.StopInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopInstanceResult, _>(
+                    response,
+                    "StopInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("StopRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.StopRelationalDatabase");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopRelationalDatabaseResult, _>(
+                    response,
+                    "StopRelationalDatabase",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StopRelationalDatabaseError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResult, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResult, _>(response, "TagResource")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
+    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError> {
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UnpeerVpc");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.UnpeerVpc");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UnpeerVpcResult, _>(response, "UnpeerVpc")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UnpeerVpcError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResult, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResult, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateDomainEntry");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Lightsail_20161128.UpdateDomainEntry");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDomainEntryResult, _>(
+                    response,
+                    "UpdateDomainEntry",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDomainEntryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateLoadBalancerAttribute");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.UpdateLoadBalancerAttribute",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateLoadBalancerAttributeResult, _>(
+                    response,
+                    "UpdateLoadBalancerAttribute",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateLoadBalancerAttributeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateRelationalDatabase");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.UpdateRelationalDatabase",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRelationalDatabaseResult, _>(
+                    response,
+                    "UpdateRelationalDatabase",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateRelationalDatabaseError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which paramater updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<
+        UpdateRelationalDatabaseParametersResult,
+        UpdateRelationalDatabaseParametersError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
+        request.set_operation("UpdateRelationalDatabaseParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Lightsail_20161128.UpdateRelationalDatabaseParameters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRelationalDatabaseParametersResult, _>(
+                    response,
+                    "UpdateRelationalDatabaseParameters",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateRelationalDatabaseParametersError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Lightsail API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockLightsail {
+    allocate_static_ip: Option<
+        Box<
+            dyn Fn(
+                    AllocateStaticIpRequest,
+                ) -> ::std::result::Result<
+                    AllocateStaticIpResult,
+                    RusotoError<AllocateStaticIpError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    attach_disk: Option<
+        Box<
+            dyn Fn(
+                    AttachDiskRequest,
+                )
+                    -> ::std::result::Result<AttachDiskResult, RusotoError<AttachDiskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_instances_to_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    AttachInstancesToLoadBalancerRequest,
+                ) -> ::std::result::Result<
+                    AttachInstancesToLoadBalancerResult,
+                    RusotoError<AttachInstancesToLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    attach_load_balancer_tls_certificate: Option<
+        Box<
+            dyn Fn(
+                    AttachLoadBalancerTlsCertificateRequest,
+                ) -> ::std::result::Result<
+                    AttachLoadBalancerTlsCertificateResult,
+                    RusotoError<AttachLoadBalancerTlsCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    attach_static_ip: Option<
+        Box<
+            dyn Fn(
+                    AttachStaticIpRequest,
+                )
+                    -> ::std::result::Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    close_instance_public_ports: Option<
+        Box<
+            dyn Fn(
+                    CloseInstancePublicPortsRequest,
+                ) -> ::std::result::Result<
+                    CloseInstancePublicPortsResult,
+                    RusotoError<CloseInstancePublicPortsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CopySnapshotRequest,
+                )
+                    -> ::std::result::Result<CopySnapshotResult, RusotoError<CopySnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_cloud_formation_stack: Option<
+        Box<
+            dyn Fn(
+                    CreateCloudFormationStackRequest,
+                ) -> ::std::result::Result<
+                    CreateCloudFormationStackResult,
+                    RusotoError<CreateCloudFormationStackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_disk: Option<
+        Box<
+            dyn Fn(
+                    CreateDiskRequest,
+                )
+                    -> ::std::result::Result<CreateDiskResult, RusotoError<CreateDiskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_disk_from_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateDiskFromSnapshotRequest,
+                ) -> ::std::result::Result<
+                    CreateDiskFromSnapshotResult,
+                    RusotoError<CreateDiskFromSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_disk_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateDiskSnapshotRequest,
+                ) -> ::std::result::Result<
+                    CreateDiskSnapshotResult,
+                    RusotoError<CreateDiskSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_domain: Option<
+        Box<
+            dyn Fn(
+                    CreateDomainRequest,
+                )
+                    -> ::std::result::Result<CreateDomainResult, RusotoError<CreateDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_domain_entry: Option<
+        Box<
+            dyn Fn(
+                    CreateDomainEntryRequest,
+                ) -> ::std::result::Result<
+                    CreateDomainEntryResult,
+                    RusotoError<CreateDomainEntryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_instance_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateInstanceSnapshotRequest,
+                ) -> ::std::result::Result<
+                    CreateInstanceSnapshotResult,
+                    RusotoError<CreateInstanceSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_instances: Option<
+        Box<
+            dyn Fn(
+                    CreateInstancesRequest,
+                ) -> ::std::result::Result<
+                    CreateInstancesResult,
+                    RusotoError<CreateInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_instances_from_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateInstancesFromSnapshotRequest,
+                ) -> ::std::result::Result<
+                    CreateInstancesFromSnapshotResult,
+                    RusotoError<CreateInstancesFromSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_key_pair: Option<
+        Box<
+            dyn Fn(
+                    CreateKeyPairRequest,
+                )
+                    -> ::std::result::Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    CreateLoadBalancerRequest,
+                ) -> ::std::result::Result<
+                    CreateLoadBalancerResult,
+                    RusotoError<CreateLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_load_balancer_tls_certificate: Option<
+        Box<
+            dyn Fn(
+                    CreateLoadBalancerTlsCertificateRequest,
+                ) -> ::std::result::Result<
+                    CreateLoadBalancerTlsCertificateResult,
+                    RusotoError<CreateLoadBalancerTlsCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_relational_database: Option<
+        Box<
+            dyn Fn(
+                    CreateRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    CreateRelationalDatabaseResult,
+                    RusotoError<CreateRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_relational_database_from_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateRelationalDatabaseFromSnapshotRequest,
+                ) -> ::std::result::Result<
+                    CreateRelationalDatabaseFromSnapshotResult,
+                    RusotoError<CreateRelationalDatabaseFromSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_relational_database_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateRelationalDatabaseSnapshotRequest,
+                ) -> ::std::result::Result<
+                    CreateRelationalDatabaseSnapshotResult,
+                    RusotoError<CreateRelationalDatabaseSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_disk: Option<
+        Box<
+            dyn Fn(
+                    DeleteDiskRequest,
+                )
+                    -> ::std::result::Result<DeleteDiskResult, RusotoError<DeleteDiskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_disk_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteDiskSnapshotRequest,
+                ) -> ::std::result::Result<
+                    DeleteDiskSnapshotResult,
+                    RusotoError<DeleteDiskSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_domain: Option<
+        Box<
+            dyn Fn(
+                    DeleteDomainRequest,
+                )
+                    -> ::std::result::Result<DeleteDomainResult, RusotoError<DeleteDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_domain_entry: Option<
+        Box<
+            dyn Fn(
+                    DeleteDomainEntryRequest,
+                ) -> ::std::result::Result<
+                    DeleteDomainEntryResult,
+                    RusotoError<DeleteDomainEntryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_instance: Option<
+        Box<
+            dyn Fn(
+                    DeleteInstanceRequest,
+                )
+                    -> ::std::result::Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_instance_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteInstanceSnapshotRequest,
+                ) -> ::std::result::Result<
+                    DeleteInstanceSnapshotResult,
+                    RusotoError<DeleteInstanceSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_key_pair: Option<
+        Box<
+            dyn Fn(
+                    DeleteKeyPairRequest,
+                )
+                    -> ::std::result::Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_known_host_keys: Option<
+        Box<
+            dyn Fn(
+                    DeleteKnownHostKeysRequest,
+                ) -> ::std::result::Result<
+                    DeleteKnownHostKeysResult,
+                    RusotoError<DeleteKnownHostKeysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoadBalancerRequest,
+                ) -> ::std::result::Result<
+                    DeleteLoadBalancerResult,
+                    RusotoError<DeleteLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_load_balancer_tls_certificate: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoadBalancerTlsCertificateRequest,
+                ) -> ::std::result::Result<
+                    DeleteLoadBalancerTlsCertificateResult,
+                    RusotoError<DeleteLoadBalancerTlsCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_relational_database: Option<
+        Box<
+            dyn Fn(
+                    DeleteRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    DeleteRelationalDatabaseResult,
+                    RusotoError<DeleteRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_relational_database_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteRelationalDatabaseSnapshotRequest,
+                ) -> ::std::result::Result<
+                    DeleteRelationalDatabaseSnapshotResult,
+                    RusotoError<DeleteRelationalDatabaseSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_disk: Option<
+        Box<
+            dyn Fn(
+                    DetachDiskRequest,
+                )
+                    -> ::std::result::Result<DetachDiskResult, RusotoError<DetachDiskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detach_instances_from_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    DetachInstancesFromLoadBalancerRequest,
+                ) -> ::std::result::Result<
+                    DetachInstancesFromLoadBalancerResult,
+                    RusotoError<DetachInstancesFromLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_static_ip: Option<
+        Box<
+            dyn Fn(
+                    DetachStaticIpRequest,
+                )
+                    -> ::std::result::Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    download_default_key_pair: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DownloadDefaultKeyPairResult,
+                    RusotoError<DownloadDefaultKeyPairError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    export_snapshot: Option<
+        Box<
+            dyn Fn(
+                    ExportSnapshotRequest,
+                )
+                    -> ::std::result::Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_active_names: Option<
+        Box<
+            dyn Fn(
+                    GetActiveNamesRequest,
+                )
+                    -> ::std::result::Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_blueprints: Option<
+        Box<
+            dyn Fn(
+                    GetBlueprintsRequest,
+                )
+                    -> ::std::result::Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bundles: Option<
+        Box<
+            dyn Fn(
+                    GetBundlesRequest,
+                )
+                    -> ::std::result::Result<GetBundlesResult, RusotoError<GetBundlesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_cloud_formation_stack_records: Option<
+        Box<
+            dyn Fn(
+                    GetCloudFormationStackRecordsRequest,
+                ) -> ::std::result::Result<
+                    GetCloudFormationStackRecordsResult,
+                    RusotoError<GetCloudFormationStackRecordsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_disk: Option<
+        Box<
+            dyn Fn(
+                    GetDiskRequest,
+                )
+                    -> ::std::result::Result<GetDiskResult, RusotoError<GetDiskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_disk_snapshot: Option<
+        Box<
+            dyn Fn(
+                    GetDiskSnapshotRequest,
+                ) -> ::std::result::Result<
+                    GetDiskSnapshotResult,
+                    RusotoError<GetDiskSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_disk_snapshots: Option<
+        Box<
+            dyn Fn(
+                    GetDiskSnapshotsRequest,
+                ) -> ::std::result::Result<
+                    GetDiskSnapshotsResult,
+                    RusotoError<GetDiskSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_disks: Option<
+        Box<
+            dyn Fn(
+                    GetDisksRequest,
+                )
+                    -> ::std::result::Result<GetDisksResult, RusotoError<GetDisksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_domain: Option<
+        Box<
+            dyn Fn(
+                    GetDomainRequest,
+                )
+                    -> ::std::result::Result<GetDomainResult, RusotoError<GetDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_domains: Option<
+        Box<
+            dyn Fn(
+                    GetDomainsRequest,
+                )
+                    -> ::std::result::Result<GetDomainsResult, RusotoError<GetDomainsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_export_snapshot_records: Option<
+        Box<
+            dyn Fn(
+                    GetExportSnapshotRecordsRequest,
+                ) -> ::std::result::Result<
+                    GetExportSnapshotRecordsResult,
+                    RusotoError<GetExportSnapshotRecordsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceRequest,
+                )
+                    -> ::std::result::Result<GetInstanceResult, RusotoError<GetInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_instance_access_details: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceAccessDetailsRequest,
+                ) -> ::std::result::Result<
+                    GetInstanceAccessDetailsResult,
+                    RusotoError<GetInstanceAccessDetailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_metric_data: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceMetricDataRequest,
+                ) -> ::std::result::Result<
+                    GetInstanceMetricDataResult,
+                    RusotoError<GetInstanceMetricDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_port_states: Option<
+        Box<
+            dyn Fn(
+                    GetInstancePortStatesRequest,
+                ) -> ::std::result::Result<
+                    GetInstancePortStatesResult,
+                    RusotoError<GetInstancePortStatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_snapshot: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceSnapshotRequest,
+                ) -> ::std::result::Result<
+                    GetInstanceSnapshotResult,
+                    RusotoError<GetInstanceSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_snapshots: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceSnapshotsRequest,
+                ) -> ::std::result::Result<
+                    GetInstanceSnapshotsResult,
+                    RusotoError<GetInstanceSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance_state: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceStateRequest,
+                ) -> ::std::result::Result<
+                    GetInstanceStateResult,
+                    RusotoError<GetInstanceStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instances: Option<
+        Box<
+            dyn Fn(
+                    GetInstancesRequest,
+                )
+                    -> ::std::result::Result<GetInstancesResult, RusotoError<GetInstancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_key_pair: Option<
+        Box<
+            dyn Fn(
+                    GetKeyPairRequest,
+                )
+                    -> ::std::result::Result<GetKeyPairResult, RusotoError<GetKeyPairError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_key_pairs: Option<
+        Box<
+            dyn Fn(
+                    GetKeyPairsRequest,
+                )
+                    -> ::std::result::Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    GetLoadBalancerRequest,
+                ) -> ::std::result::Result<
+                    GetLoadBalancerResult,
+                    RusotoError<GetLoadBalancerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_load_balancer_metric_data: Option<
+        Box<
+            dyn Fn(
+                    GetLoadBalancerMetricDataRequest,
+                ) -> ::std::result::Result<
+                    GetLoadBalancerMetricDataResult,
+                    RusotoError<GetLoadBalancerMetricDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_load_balancer_tls_certificates: Option<
+        Box<
+            dyn Fn(
+                    GetLoadBalancerTlsCertificatesRequest,
+                ) -> ::std::result::Result<
+                    GetLoadBalancerTlsCertificatesResult,
+                    RusotoError<GetLoadBalancerTlsCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    GetLoadBalancersRequest,
+                ) -> ::std::result::Result<
+                    GetLoadBalancersResult,
+                    RusotoError<GetLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_operation: Option<
+        Box<
+            dyn Fn(
+                    GetOperationRequest,
+                )
+                    -> ::std::result::Result<GetOperationResult, RusotoError<GetOperationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_operations: Option<
+        Box<
+            dyn Fn(
+                    GetOperationsRequest,
+                )
+                    -> ::std::result::Result<GetOperationsResult, RusotoError<GetOperationsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_operations_for_resource: Option<
+        Box<
+            dyn Fn(
+                    GetOperationsForResourceRequest,
+                ) -> ::std::result::Result<
+                    GetOperationsForResourceResult,
+                    RusotoError<GetOperationsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_regions: Option<
+        Box<
+            dyn Fn(
+                    GetRegionsRequest,
+                )
+                    -> ::std::result::Result<GetRegionsResult, RusotoError<GetRegionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseResult,
+                    RusotoError<GetRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_blueprints: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseBlueprintsRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseBlueprintsResult,
+                    RusotoError<GetRelationalDatabaseBlueprintsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_bundles: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseBundlesRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseBundlesResult,
+                    RusotoError<GetRelationalDatabaseBundlesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_events: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseEventsRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseEventsResult,
+                    RusotoError<GetRelationalDatabaseEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_log_events: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseLogEventsRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseLogEventsResult,
+                    RusotoError<GetRelationalDatabaseLogEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_log_streams: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseLogStreamsRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseLogStreamsResult,
+                    RusotoError<GetRelationalDatabaseLogStreamsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_master_user_password: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseMasterUserPasswordRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseMasterUserPasswordResult,
+                    RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_metric_data: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseMetricDataRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseMetricDataResult,
+                    RusotoError<GetRelationalDatabaseMetricDataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_parameters: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseParametersRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseParametersResult,
+                    RusotoError<GetRelationalDatabaseParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_snapshot: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseSnapshotRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseSnapshotResult,
+                    RusotoError<GetRelationalDatabaseSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_database_snapshots: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabaseSnapshotsRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabaseSnapshotsResult,
+                    RusotoError<GetRelationalDatabaseSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_relational_databases: Option<
+        Box<
+            dyn Fn(
+                    GetRelationalDatabasesRequest,
+                ) -> ::std::result::Result<
+                    GetRelationalDatabasesResult,
+                    RusotoError<GetRelationalDatabasesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_static_ip: Option<
+        Box<
+            dyn Fn(
+                    GetStaticIpRequest,
+                )
+                    -> ::std::result::Result<GetStaticIpResult, RusotoError<GetStaticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_static_ips: Option<
+        Box<
+            dyn Fn(
+                    GetStaticIpsRequest,
+                )
+                    -> ::std::result::Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    import_key_pair: Option<
+        Box<
+            dyn Fn(
+                    ImportKeyPairRequest,
+                )
+                    -> ::std::result::Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    is_vpc_peered: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    open_instance_public_ports: Option<
+        Box<
+            dyn Fn(
+                    OpenInstancePublicPortsRequest,
+                ) -> ::std::result::Result<
+                    OpenInstancePublicPortsResult,
+                    RusotoError<OpenInstancePublicPortsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    peer_vpc: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<PeerVpcResult, RusotoError<PeerVpcError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_instance_public_ports: Option<
+        Box<
+            dyn Fn(
+                    PutInstancePublicPortsRequest,
+                ) -> ::std::result::Result<
+                    PutInstancePublicPortsResult,
+                    RusotoError<PutInstancePublicPortsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reboot_instance: Option<
+        Box<
+            dyn Fn(
+                    RebootInstanceRequest,
+                )
+                    -> ::std::result::Result<RebootInstanceResult, RusotoError<RebootInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reboot_relational_database: Option<
+        Box<
+            dyn Fn(
+                    RebootRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    RebootRelationalDatabaseResult,
+                    RusotoError<RebootRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    release_static_ip: Option<
+        Box<
+            dyn Fn(
+                    ReleaseStaticIpRequest,
+                ) -> ::std::result::Result<
+                    ReleaseStaticIpResult,
+                    RusotoError<ReleaseStaticIpError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_instance: Option<
+        Box<
+            dyn Fn(
+                    StartInstanceRequest,
+                )
+                    -> ::std::result::Result<StartInstanceResult, RusotoError<StartInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_relational_database: Option<
+        Box<
+            dyn Fn(
+                    StartRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    StartRelationalDatabaseResult,
+                    RusotoError<StartRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_instance: Option<
+        Box<
+            dyn Fn(
+                    StopInstanceRequest,
+                )
+                    -> ::std::result::Result<StopInstanceResult, RusotoError<StopInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_relational_database: Option<
+        Box<
+            dyn Fn(
+                    StopRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    StopRelationalDatabaseResult,
+                    RusotoError<StopRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResult, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unpeer_vpc: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResult, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_domain_entry: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainEntryRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainEntryResult,
+                    RusotoError<UpdateDomainEntryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_load_balancer_attribute: Option<
+        Box<
+            dyn Fn(
+                    UpdateLoadBalancerAttributeRequest,
+                ) -> ::std::result::Result<
+                    UpdateLoadBalancerAttributeResult,
+                    RusotoError<UpdateLoadBalancerAttributeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_relational_database: Option<
+        Box<
+            dyn Fn(
+                    UpdateRelationalDatabaseRequest,
+                ) -> ::std::result::Result<
+                    UpdateRelationalDatabaseResult,
+                    RusotoError<UpdateRelationalDatabaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_relational_database_parameters: Option<
+        Box<
+            dyn Fn(
+                    UpdateRelationalDatabaseParametersRequest,
+                ) -> ::std::result::Result<
+                    UpdateRelationalDatabaseParametersResult,
+                    RusotoError<UpdateRelationalDatabaseParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockLightsail {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockLightsail {
+        Default::default()
+    }
+
+    /// Answers `allocate_static_ip` with what `handler` returns for its input.
+    pub fn with_allocate_static_ip<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                AllocateStaticIpRequest,
+            )
+                -> ::std::result::Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.allocate_static_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_disk` with what `handler` returns for its input.
+    pub fn with_attach_disk<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                AttachDiskRequest,
+            ) -> ::std::result::Result<AttachDiskResult, RusotoError<AttachDiskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_disk = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_instances_to_load_balancer` with what `handler` returns for its input.
+    pub fn with_attach_instances_to_load_balancer<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                AttachInstancesToLoadBalancerRequest,
+            ) -> ::std::result::Result<
+                AttachInstancesToLoadBalancerResult,
+                RusotoError<AttachInstancesToLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_instances_to_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_load_balancer_tls_certificate` with what `handler` returns for its input.
+    pub fn with_attach_load_balancer_tls_certificate<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                AttachLoadBalancerTlsCertificateRequest,
+            ) -> ::std::result::Result<
+                AttachLoadBalancerTlsCertificateResult,
+                RusotoError<AttachLoadBalancerTlsCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_load_balancer_tls_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_static_ip` with what `handler` returns for its input.
+    pub fn with_attach_static_ip<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                AttachStaticIpRequest,
+            )
+                -> ::std::result::Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_static_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `close_instance_public_ports` with what `handler` returns for its input.
+    pub fn with_close_instance_public_ports<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CloseInstancePublicPortsRequest,
+            ) -> ::std::result::Result<
+                CloseInstancePublicPortsResult,
+                RusotoError<CloseInstancePublicPortsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.close_instance_public_ports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_snapshot` with what `handler` returns for its input.
+    pub fn with_copy_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CopySnapshotRequest,
+            )
+                -> ::std::result::Result<CopySnapshotResult, RusotoError<CopySnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cloud_formation_stack` with what `handler` returns for its input.
+    pub fn with_create_cloud_formation_stack<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateCloudFormationStackRequest,
+            ) -> ::std::result::Result<
+                CreateCloudFormationStackResult,
+                RusotoError<CreateCloudFormationStackError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cloud_formation_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_disk` with what `handler` returns for its input.
+    pub fn with_create_disk<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateDiskRequest,
+            ) -> ::std::result::Result<CreateDiskResult, RusotoError<CreateDiskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_disk = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_disk_from_snapshot` with what `handler` returns for its input.
+    pub fn with_create_disk_from_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateDiskFromSnapshotRequest,
+            ) -> ::std::result::Result<
+                CreateDiskFromSnapshotResult,
+                RusotoError<CreateDiskFromSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_disk_from_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_disk_snapshot` with what `handler` returns for its input.
+    pub fn with_create_disk_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateDiskSnapshotRequest,
+            ) -> ::std::result::Result<
+                CreateDiskSnapshotResult,
+                RusotoError<CreateDiskSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_disk_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain` with what `handler` returns for its input.
+    pub fn with_create_domain<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateDomainRequest,
+            )
+                -> ::std::result::Result<CreateDomainResult, RusotoError<CreateDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain_entry` with what `handler` returns for its input.
+    pub fn with_create_domain_entry<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateDomainEntryRequest,
+            ) -> ::std::result::Result<
+                CreateDomainEntryResult,
+                RusotoError<CreateDomainEntryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain_entry = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_instance_snapshot` with what `handler` returns for its input.
+    pub fn with_create_instance_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateInstanceSnapshotRequest,
+            ) -> ::std::result::Result<
+                CreateInstanceSnapshotResult,
+                RusotoError<CreateInstanceSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_instance_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_instances` with what `handler` returns for its input.
+    pub fn with_create_instances<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateInstancesRequest,
+            )
+                -> ::std::result::Result<CreateInstancesResult, RusotoError<CreateInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_instances_from_snapshot` with what `handler` returns for its input.
+    pub fn with_create_instances_from_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateInstancesFromSnapshotRequest,
+            ) -> ::std::result::Result<
+                CreateInstancesFromSnapshotResult,
+                RusotoError<CreateInstancesFromSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_instances_from_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_key_pair` with what `handler` returns for its input.
+    pub fn with_create_key_pair<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateKeyPairRequest,
+            )
+                -> ::std::result::Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_key_pair = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_load_balancer` with what `handler` returns for its input.
+    pub fn with_create_load_balancer<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateLoadBalancerRequest,
+            ) -> ::std::result::Result<
+                CreateLoadBalancerResult,
+                RusotoError<CreateLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_load_balancer_tls_certificate` with what `handler` returns for its input.
+    pub fn with_create_load_balancer_tls_certificate<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateLoadBalancerTlsCertificateRequest,
+            ) -> ::std::result::Result<
+                CreateLoadBalancerTlsCertificateResult,
+                RusotoError<CreateLoadBalancerTlsCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_load_balancer_tls_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_relational_database` with what `handler` returns for its input.
+    pub fn with_create_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                CreateRelationalDatabaseResult,
+                RusotoError<CreateRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_relational_database_from_snapshot` with what `handler` returns for its input.
+    pub fn with_create_relational_database_from_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateRelationalDatabaseFromSnapshotRequest,
+            ) -> ::std::result::Result<
+                CreateRelationalDatabaseFromSnapshotResult,
+                RusotoError<CreateRelationalDatabaseFromSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_relational_database_from_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_relational_database_snapshot` with what `handler` returns for its input.
+    pub fn with_create_relational_database_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                CreateRelationalDatabaseSnapshotRequest,
+            ) -> ::std::result::Result<
+                CreateRelationalDatabaseSnapshotResult,
+                RusotoError<CreateRelationalDatabaseSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_relational_database_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_disk` with what `handler` returns for its input.
+    pub fn with_delete_disk<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteDiskRequest,
+            ) -> ::std::result::Result<DeleteDiskResult, RusotoError<DeleteDiskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_disk = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_disk_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_disk_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteDiskSnapshotRequest,
+            ) -> ::std::result::Result<
+                DeleteDiskSnapshotResult,
+                RusotoError<DeleteDiskSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_disk_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain` with what `handler` returns for its input.
+    pub fn with_delete_domain<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteDomainRequest,
+            )
+                -> ::std::result::Result<DeleteDomainResult, RusotoError<DeleteDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain_entry` with what `handler` returns for its input.
+    pub fn with_delete_domain_entry<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteDomainEntryRequest,
+            ) -> ::std::result::Result<
+                DeleteDomainEntryResult,
+                RusotoError<DeleteDomainEntryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain_entry = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_instance` with what `handler` returns for its input.
+    pub fn with_delete_instance<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteInstanceRequest,
+            )
+                -> ::std::result::Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_instance_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_instance_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteInstanceSnapshotRequest,
+            ) -> ::std::result::Result<
+                DeleteInstanceSnapshotResult,
+                RusotoError<DeleteInstanceSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_instance_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_key_pair` with what `handler` returns for its input.
+    pub fn with_delete_key_pair<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteKeyPairRequest,
+            )
+                -> ::std::result::Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_key_pair = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_known_host_keys` with what `handler` returns for its input.
+    pub fn with_delete_known_host_keys<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteKnownHostKeysRequest,
+            ) -> ::std::result::Result<
+                DeleteKnownHostKeysResult,
+                RusotoError<DeleteKnownHostKeysError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_known_host_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_load_balancer` with what `handler` returns for its input.
+    pub fn with_delete_load_balancer<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteLoadBalancerRequest,
+            ) -> ::std::result::Result<
+                DeleteLoadBalancerResult,
+                RusotoError<DeleteLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_load_balancer_tls_certificate` with what `handler` returns for its input.
+    pub fn with_delete_load_balancer_tls_certificate<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteLoadBalancerTlsCertificateRequest,
+            ) -> ::std::result::Result<
+                DeleteLoadBalancerTlsCertificateResult,
+                RusotoError<DeleteLoadBalancerTlsCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_load_balancer_tls_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_relational_database` with what `handler` returns for its input.
+    pub fn with_delete_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                DeleteRelationalDatabaseResult,
+                RusotoError<DeleteRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_relational_database_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_relational_database_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DeleteRelationalDatabaseSnapshotRequest,
+            ) -> ::std::result::Result<
+                DeleteRelationalDatabaseSnapshotResult,
+                RusotoError<DeleteRelationalDatabaseSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_relational_database_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_disk` with what `handler` returns for its input.
+    pub fn with_detach_disk<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DetachDiskRequest,
+            ) -> ::std::result::Result<DetachDiskResult, RusotoError<DetachDiskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_disk = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_instances_from_load_balancer` with what `handler` returns for its input.
+    pub fn with_detach_instances_from_load_balancer<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DetachInstancesFromLoadBalancerRequest,
+            ) -> ::std::result::Result<
+                DetachInstancesFromLoadBalancerResult,
+                RusotoError<DetachInstancesFromLoadBalancerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_instances_from_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_static_ip` with what `handler` returns for its input.
+    pub fn with_detach_static_ip<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                DetachStaticIpRequest,
+            )
+                -> ::std::result::Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_static_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `download_default_key_pair` with what `handler` returns.
+    pub fn with_download_default_key_pair<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn() -> ::std::result::Result<
+                DownloadDefaultKeyPairResult,
+                RusotoError<DownloadDefaultKeyPairError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.download_default_key_pair = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_snapshot` with what `handler` returns for its input.
+    pub fn with_export_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                ExportSnapshotRequest,
+            )
+                -> ::std::result::Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.export_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_active_names` with what `handler` returns for its input.
+    pub fn with_get_active_names<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetActiveNamesRequest,
+            )
+                -> ::std::result::Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_active_names = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_blueprints` with what `handler` returns for its input.
+    pub fn with_get_blueprints<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetBlueprintsRequest,
+            )
+                -> ::std::result::Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_blueprints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bundles` with what `handler` returns for its input.
+    pub fn with_get_bundles<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetBundlesRequest,
+            ) -> ::std::result::Result<GetBundlesResult, RusotoError<GetBundlesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bundles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cloud_formation_stack_records` with what `handler` returns for its input.
+    pub fn with_get_cloud_formation_stack_records<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetCloudFormationStackRecordsRequest,
+            ) -> ::std::result::Result<
+                GetCloudFormationStackRecordsResult,
+                RusotoError<GetCloudFormationStackRecordsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cloud_formation_stack_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_disk` with what `handler` returns for its input.
+    pub fn with_get_disk<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(GetDiskRequest) -> ::std::result::Result<GetDiskResult, RusotoError<GetDiskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_disk = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_disk_snapshot` with what `handler` returns for its input.
+    pub fn with_get_disk_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetDiskSnapshotRequest,
+            )
+                -> ::std::result::Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_disk_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_disk_snapshots` with what `handler` returns for its input.
+    pub fn with_get_disk_snapshots<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetDiskSnapshotsRequest,
+            )
+                -> ::std::result::Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_disk_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_disks` with what `handler` returns for its input.
+    pub fn with_get_disks<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(GetDisksRequest) -> ::std::result::Result<GetDisksResult, RusotoError<GetDisksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_disks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain` with what `handler` returns for its input.
+    pub fn with_get_domain<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetDomainRequest,
+            ) -> ::std::result::Result<GetDomainResult, RusotoError<GetDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domains` with what `handler` returns for its input.
+    pub fn with_get_domains<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetDomainsRequest,
+            ) -> ::std::result::Result<GetDomainsResult, RusotoError<GetDomainsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domains = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_export_snapshot_records` with what `handler` returns for its input.
+    pub fn with_get_export_snapshot_records<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetExportSnapshotRecordsRequest,
+            ) -> ::std::result::Result<
+                GetExportSnapshotRecordsResult,
+                RusotoError<GetExportSnapshotRecordsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_export_snapshot_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance` with what `handler` returns for its input.
+    pub fn with_get_instance<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstanceRequest,
+            )
+                -> ::std::result::Result<GetInstanceResult, RusotoError<GetInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_access_details` with what `handler` returns for its input.
+    pub fn with_get_instance_access_details<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstanceAccessDetailsRequest,
+            ) -> ::std::result::Result<
+                GetInstanceAccessDetailsResult,
+                RusotoError<GetInstanceAccessDetailsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_access_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_metric_data` with what `handler` returns for its input.
+    pub fn with_get_instance_metric_data<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstanceMetricDataRequest,
+            ) -> ::std::result::Result<
+                GetInstanceMetricDataResult,
+                RusotoError<GetInstanceMetricDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_port_states` with what `handler` returns for its input.
+    pub fn with_get_instance_port_states<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstancePortStatesRequest,
+            ) -> ::std::result::Result<
+                GetInstancePortStatesResult,
+                RusotoError<GetInstancePortStatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_port_states = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_snapshot` with what `handler` returns for its input.
+    pub fn with_get_instance_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstanceSnapshotRequest,
+            ) -> ::std::result::Result<
+                GetInstanceSnapshotResult,
+                RusotoError<GetInstanceSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_snapshots` with what `handler` returns for its input.
+    pub fn with_get_instance_snapshots<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstanceSnapshotsRequest,
+            ) -> ::std::result::Result<
+                GetInstanceSnapshotsResult,
+                RusotoError<GetInstanceSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance_state` with what `handler` returns for its input.
+    pub fn with_get_instance_state<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstanceStateRequest,
+            )
+                -> ::std::result::Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instances` with what `handler` returns for its input.
+    pub fn with_get_instances<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetInstancesRequest,
+            )
+                -> ::std::result::Result<GetInstancesResult, RusotoError<GetInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_key_pair` with what `handler` returns for its input.
+    pub fn with_get_key_pair<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetKeyPairRequest,
+            ) -> ::std::result::Result<GetKeyPairResult, RusotoError<GetKeyPairError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_key_pair = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_key_pairs` with what `handler` returns for its input.
+    pub fn with_get_key_pairs<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetKeyPairsRequest,
+            )
+                -> ::std::result::Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_key_pairs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_load_balancer` with what `handler` returns for its input.
+    pub fn with_get_load_balancer<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetLoadBalancerRequest,
+            )
+                -> ::std::result::Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_load_balancer_metric_data` with what `handler` returns for its input.
+    pub fn with_get_load_balancer_metric_data<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetLoadBalancerMetricDataRequest,
+            ) -> ::std::result::Result<
+                GetLoadBalancerMetricDataResult,
+                RusotoError<GetLoadBalancerMetricDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_load_balancer_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_load_balancer_tls_certificates` with what `handler` returns for its input.
+    pub fn with_get_load_balancer_tls_certificates<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetLoadBalancerTlsCertificatesRequest,
+            ) -> ::std::result::Result<
+                GetLoadBalancerTlsCertificatesResult,
+                RusotoError<GetLoadBalancerTlsCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_load_balancer_tls_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_load_balancers` with what `handler` returns for its input.
+    pub fn with_get_load_balancers<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetLoadBalancersRequest,
+            )
+                -> ::std::result::Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_operation` with what `handler` returns for its input.
+    pub fn with_get_operation<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetOperationRequest,
+            )
+                -> ::std::result::Result<GetOperationResult, RusotoError<GetOperationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_operation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_operations` with what `handler` returns for its input.
+    pub fn with_get_operations<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetOperationsRequest,
+            )
+                -> ::std::result::Result<GetOperationsResult, RusotoError<GetOperationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_operations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_operations_for_resource` with what `handler` returns for its input.
+    pub fn with_get_operations_for_resource<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetOperationsForResourceRequest,
+            ) -> ::std::result::Result<
+                GetOperationsForResourceResult,
+                RusotoError<GetOperationsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_operations_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_regions` with what `handler` returns for its input.
+    pub fn with_get_regions<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRegionsRequest,
+            ) -> ::std::result::Result<GetRegionsResult, RusotoError<GetRegionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_regions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database` with what `handler` returns for its input.
+    pub fn with_get_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseResult,
+                RusotoError<GetRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_blueprints` with what `handler` returns for its input.
+    pub fn with_get_relational_database_blueprints<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseBlueprintsRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseBlueprintsResult,
+                RusotoError<GetRelationalDatabaseBlueprintsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_blueprints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_bundles` with what `handler` returns for its input.
+    pub fn with_get_relational_database_bundles<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseBundlesRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseBundlesResult,
+                RusotoError<GetRelationalDatabaseBundlesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_bundles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_events` with what `handler` returns for its input.
+    pub fn with_get_relational_database_events<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseEventsRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseEventsResult,
+                RusotoError<GetRelationalDatabaseEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_log_events` with what `handler` returns for its input.
+    pub fn with_get_relational_database_log_events<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseLogEventsRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseLogEventsResult,
+                RusotoError<GetRelationalDatabaseLogEventsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_log_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_log_streams` with what `handler` returns for its input.
+    pub fn with_get_relational_database_log_streams<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseLogStreamsRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseLogStreamsResult,
+                RusotoError<GetRelationalDatabaseLogStreamsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_log_streams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_master_user_password` with what `handler` returns for its input.
+    pub fn with_get_relational_database_master_user_password<F>(
+        mut self,
+        handler: F,
+    ) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseMasterUserPasswordRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseMasterUserPasswordResult,
+                RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_master_user_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_metric_data` with what `handler` returns for its input.
+    pub fn with_get_relational_database_metric_data<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseMetricDataRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseMetricDataResult,
+                RusotoError<GetRelationalDatabaseMetricDataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_metric_data = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_parameters` with what `handler` returns for its input.
+    pub fn with_get_relational_database_parameters<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseParametersRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseParametersResult,
+                RusotoError<GetRelationalDatabaseParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_snapshot` with what `handler` returns for its input.
+    pub fn with_get_relational_database_snapshot<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseSnapshotRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseSnapshotResult,
+                RusotoError<GetRelationalDatabaseSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_database_snapshots` with what `handler` returns for its input.
+    pub fn with_get_relational_database_snapshots<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabaseSnapshotsRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabaseSnapshotsResult,
+                RusotoError<GetRelationalDatabaseSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_database_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_relational_databases` with what `handler` returns for its input.
+    pub fn with_get_relational_databases<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetRelationalDatabasesRequest,
+            ) -> ::std::result::Result<
+                GetRelationalDatabasesResult,
+                RusotoError<GetRelationalDatabasesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_relational_databases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_static_ip` with what `handler` returns for its input.
+    pub fn with_get_static_ip<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetStaticIpRequest,
+            )
+                -> ::std::result::Result<GetStaticIpResult, RusotoError<GetStaticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_static_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_static_ips` with what `handler` returns for its input.
+    pub fn with_get_static_ips<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                GetStaticIpsRequest,
+            )
+                -> ::std::result::Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_static_ips = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_key_pair` with what `handler` returns for its input.
+    pub fn with_import_key_pair<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                ImportKeyPairRequest,
+            )
+                -> ::std::result::Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.import_key_pair = Some(Box::new(handler));
+        self
+    }
+    /// Answers `is_vpc_peered` with what `handler` returns.
+    pub fn with_is_vpc_peered<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn() -> ::std::result::Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.is_vpc_peered = Some(Box::new(handler));
+        self
+    }
+    /// Answers `open_instance_public_ports` with what `handler` returns for its input.
+    pub fn with_open_instance_public_ports<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                OpenInstancePublicPortsRequest,
+            ) -> ::std::result::Result<
+                OpenInstancePublicPortsResult,
+                RusotoError<OpenInstancePublicPortsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.open_instance_public_ports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `peer_vpc` with what `handler` returns.
+    pub fn with_peer_vpc<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn() -> ::std::result::Result<PeerVpcResult, RusotoError<PeerVpcError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.peer_vpc = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_instance_public_ports` with what `handler` returns for its input.
+    pub fn with_put_instance_public_ports<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                PutInstancePublicPortsRequest,
+            ) -> ::std::result::Result<
+                PutInstancePublicPortsResult,
+                RusotoError<PutInstancePublicPortsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_instance_public_ports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_instance` with what `handler` returns for its input.
+    pub fn with_reboot_instance<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                RebootInstanceRequest,
+            )
+                -> ::std::result::Result<RebootInstanceResult, RusotoError<RebootInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_relational_database` with what `handler` returns for its input.
+    pub fn with_reboot_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                RebootRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                RebootRelationalDatabaseResult,
+                RusotoError<RebootRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `release_static_ip` with what `handler` returns for its input.
+    pub fn with_release_static_ip<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                ReleaseStaticIpRequest,
+            )
+                -> ::std::result::Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.release_static_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_instance` with what `handler` returns for its input.
+    pub fn with_start_instance<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                StartInstanceRequest,
+            )
+                -> ::std::result::Result<StartInstanceResult, RusotoError<StartInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_relational_database` with what `handler` returns for its input.
+    pub fn with_start_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                StartRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                StartRelationalDatabaseResult,
+                RusotoError<StartRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_instance` with what `handler` returns for its input.
+    pub fn with_stop_instance<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                StopInstanceRequest,
+            )
+                -> ::std::result::Result<StopInstanceResult, RusotoError<StopInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_relational_database` with what `handler` returns for its input.
+    pub fn with_stop_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                StopRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                StopRelationalDatabaseResult,
+                RusotoError<StopRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResult, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unpeer_vpc` with what `handler` returns.
+    pub fn with_unpeer_vpc<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn() -> ::std::result::Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unpeer_vpc = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResult, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_entry` with what `handler` returns for its input.
+    pub fn with_update_domain_entry<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                UpdateDomainEntryRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainEntryResult,
+                RusotoError<UpdateDomainEntryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_entry = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_load_balancer_attribute` with what `handler` returns for its input.
+    pub fn with_update_load_balancer_attribute<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                UpdateLoadBalancerAttributeRequest,
+            ) -> ::std::result::Result<
+                UpdateLoadBalancerAttributeResult,
+                RusotoError<UpdateLoadBalancerAttributeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_load_balancer_attribute = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_relational_database` with what `handler` returns for its input.
+    pub fn with_update_relational_database<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                UpdateRelationalDatabaseRequest,
+            ) -> ::std::result::Result<
+                UpdateRelationalDatabaseResult,
+                RusotoError<UpdateRelationalDatabaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_relational_database = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_relational_database_parameters` with what `handler` returns for its input.
+    pub fn with_update_relational_database_parameters<F>(mut self, handler: F) -> MockLightsail
+    where
+        F: Fn(
+                UpdateRelationalDatabaseParametersRequest,
+            ) -> ::std::result::Result<
+                UpdateRelationalDatabaseParametersResult,
+                RusotoError<UpdateRelationalDatabaseParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_relational_database_parameters = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Lightsail for MockLightsail {
+    fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> RusotoFuture<AllocateStaticIpResult, AllocateStaticIpError> {
+        match self.allocate_static_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for allocate_static_ip"),
+        }
+    }
+    fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> RusotoFuture<AttachDiskResult, AttachDiskError> {
+        match self.attach_disk {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for attach_disk"),
+        }
+    }
+    fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> RusotoFuture<AttachInstancesToLoadBalancerResult, AttachInstancesToLoadBalancerError> {
+        match self.attach_instances_to_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for attach_instances_to_load_balancer"),
+        }
+    }
+    fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<AttachLoadBalancerTlsCertificateResult, AttachLoadBalancerTlsCertificateError>
+    {
+        match self.attach_load_balancer_tls_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for attach_load_balancer_tls_certificate"),
+        }
+    }
+    fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> RusotoFuture<AttachStaticIpResult, AttachStaticIpError> {
+        match self.attach_static_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for attach_static_ip"),
+        }
+    }
+    fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> RusotoFuture<CloseInstancePublicPortsResult, CloseInstancePublicPortsError> {
+        match self.close_instance_public_ports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for close_instance_public_ports"),
+        }
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        match self.copy_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for copy_snapshot"),
+        }
+    }
+    fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError> {
+        match self.create_cloud_formation_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_cloud_formation_stack"),
+        }
+    }
+    fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> RusotoFuture<CreateDiskResult, CreateDiskError> {
+        match self.create_disk {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_disk"),
+        }
+    }
+    fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError> {
+        match self.create_disk_from_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_disk_from_snapshot"),
+        }
+    }
+    fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError> {
+        match self.create_disk_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_disk_snapshot"),
+        }
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResult, CreateDomainError> {
+        match self.create_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_domain"),
+        }
+    }
+    fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError> {
+        match self.create_domain_entry {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_domain_entry"),
+        }
+    }
+    fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError> {
+        match self.create_instance_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_instance_snapshot"),
+        }
+    }
+    fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError> {
+        match self.create_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_instances"),
+        }
+    }
+    fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError> {
+        match self.create_instances_from_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_instances_from_snapshot"),
+        }
+    }
+    fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError> {
+        match self.create_key_pair {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_key_pair"),
+        }
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError> {
+        match self.create_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_load_balancer"),
+        }
+    }
+    fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>
+    {
+        match self.create_load_balancer_tls_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_load_balancer_tls_certificate"),
+        }
+    }
+    fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError> {
+        match self.create_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_relational_database"),
+        }
+    }
+    fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> RusotoFuture<
+        CreateRelationalDatabaseFromSnapshotResult,
+        CreateRelationalDatabaseFromSnapshotError,
+    > {
+        match self.create_relational_database_from_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockLightsail has no handler for create_relational_database_from_snapshot")
+            }
+        }
+    }
+    fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>
+    {
+        match self.create_relational_database_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for create_relational_database_snapshot"),
+        }
+    }
+    fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError> {
+        match self.delete_disk {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_disk"),
+        }
+    }
+    fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError> {
+        match self.delete_disk_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_disk_snapshot"),
+        }
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError> {
+        match self.delete_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_domain"),
+        }
+    }
+    fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError> {
+        match self.delete_domain_entry {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_domain_entry"),
+        }
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError> {
+        match self.delete_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_instance"),
+        }
+    }
+    fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError> {
+        match self.delete_instance_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_instance_snapshot"),
+        }
+    }
+    fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError> {
+        match self.delete_key_pair {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_key_pair"),
+        }
+    }
+    fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError> {
+        match self.delete_known_host_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_known_host_keys"),
+        }
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError> {
+        match self.delete_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_load_balancer"),
+        }
+    }
+    fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>
+    {
+        match self.delete_load_balancer_tls_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_load_balancer_tls_certificate"),
+        }
+    }
+    fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError> {
+        match self.delete_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_relational_database"),
+        }
+    }
+    fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>
+    {
+        match self.delete_relational_database_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for delete_relational_database_snapshot"),
+        }
+    }
+    fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> RusotoFuture<DetachDiskResult, DetachDiskError> {
+        match self.detach_disk {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for detach_disk"),
+        }
+    }
+    fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>
+    {
+        match self.detach_instances_from_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for detach_instances_from_load_balancer"),
+        }
+    }
+    fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError> {
+        match self.detach_static_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for detach_static_ip"),
+        }
+    }
+    fn download_default_key_pair(
+        &self,
+    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError> {
+        match self.download_default_key_pair {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockLightsail has no handler for download_default_key_pair"),
+        }
+    }
+    fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError> {
+        match self.export_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for export_snapshot"),
+        }
+    }
+    fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError> {
+        match self.get_active_names {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_active_names"),
+        }
+    }
+    fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError> {
+        match self.get_blueprints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_blueprints"),
+        }
+    }
+    fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> RusotoFuture<GetBundlesResult, GetBundlesError> {
+        match self.get_bundles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_bundles"),
+        }
+    }
+    fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError> {
+        match self.get_cloud_formation_stack_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_cloud_formation_stack_records"),
+        }
+    }
+    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError> {
+        match self.get_disk {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_disk"),
+        }
+    }
+    fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError> {
+        match self.get_disk_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_disk_snapshot"),
+        }
+    }
+    fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
+        match self.get_disk_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_disk_snapshots"),
+        }
+    }
+    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError> {
+        match self.get_disks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_disks"),
+        }
+    }
+    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError> {
+        match self.get_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_domain"),
+        }
+    }
+    fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> RusotoFuture<GetDomainsResult, GetDomainsError> {
+        match self.get_domains {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_domains"),
+        }
+    }
+    fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError> {
+        match self.get_export_snapshot_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_export_snapshot_records"),
+        }
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResult, GetInstanceError> {
+        match self.get_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance"),
+        }
+    }
+    fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError> {
+        match self.get_instance_access_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance_access_details"),
+        }
+    }
+    fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError> {
+        match self.get_instance_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance_metric_data"),
+        }
+    }
+    fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError> {
+        match self.get_instance_port_states {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance_port_states"),
+        }
+    }
+    fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError> {
+        match self.get_instance_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance_snapshot"),
+        }
+    }
+    fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError> {
+        match self.get_instance_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance_snapshots"),
+        }
+    }
+    fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError> {
+        match self.get_instance_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instance_state"),
+        }
+    }
+    fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> RusotoFuture<GetInstancesResult, GetInstancesError> {
+        match self.get_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_instances"),
+        }
+    }
+    fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError> {
+        match self.get_key_pair {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_key_pair"),
+        }
+    }
+    fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError> {
+        match self.get_key_pairs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_key_pairs"),
+        }
+    }
+    fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError> {
+        match self.get_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_load_balancer"),
+        }
+    }
+    fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError> {
+        match self.get_load_balancer_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_load_balancer_metric_data"),
+        }
+    }
+    fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>
+    {
+        match self.get_load_balancer_tls_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_load_balancer_tls_certificates"),
+        }
+    }
+    fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError> {
+        match self.get_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_load_balancers"),
+        }
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResult, GetOperationError> {
+        match self.get_operation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_operation"),
+        }
+    }
+    fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> RusotoFuture<GetOperationsResult, GetOperationsError> {
+        match self.get_operations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_operations"),
+        }
+    }
+    fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError> {
+        match self.get_operations_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_operations_for_resource"),
+        }
+    }
+    fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> RusotoFuture<GetRegionsResult, GetRegionsError> {
+        match self.get_regions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_regions"),
+        }
+    }
+    fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError> {
+        match self.get_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database"),
+        }
+    }
+    fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>
+    {
+        match self.get_relational_database_blueprints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_blueprints"),
+        }
+    }
+    fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError> {
+        match self.get_relational_database_bundles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_bundles"),
+        }
+    }
+    fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError> {
+        match self.get_relational_database_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_events"),
+        }
+    }
+    fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>
+    {
+        match self.get_relational_database_log_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_log_events"),
+        }
+    }
+    fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>
+    {
+        match self.get_relational_database_log_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_log_streams"),
+        }
+    }
+    fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> RusotoFuture<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        GetRelationalDatabaseMasterUserPasswordError,
+    > {
+        match self.get_relational_database_master_user_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockLightsail has no handler for get_relational_database_master_user_password"
+            ),
+        }
+    }
+    fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>
+    {
+        match self.get_relational_database_metric_data {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_metric_data"),
+        }
+    }
+    fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>
+    {
+        match self.get_relational_database_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_parameters"),
+        }
+    }
+    fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError> {
+        match self.get_relational_database_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_snapshot"),
+        }
+    }
+    fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>
+    {
+        match self.get_relational_database_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_database_snapshots"),
+        }
+    }
+    fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError> {
+        match self.get_relational_databases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_relational_databases"),
+        }
+    }
+    fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError> {
+        match self.get_static_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_static_ip"),
+        }
+    }
+    fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError> {
+        match self.get_static_ips {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for get_static_ips"),
+        }
+    }
+    fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError> {
+        match self.import_key_pair {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for import_key_pair"),
+        }
+    }
+    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError> {
+        match self.is_vpc_peered {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockLightsail has no handler for is_vpc_peered"),
+        }
+    }
+    fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError> {
+        match self.open_instance_public_ports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for open_instance_public_ports"),
+        }
+    }
+    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError> {
+        match self.peer_vpc {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockLightsail has no handler for peer_vpc"),
+        }
+    }
+    fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError> {
+        match self.put_instance_public_ports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for put_instance_public_ports"),
+        }
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError> {
+        match self.reboot_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for reboot_instance"),
+        }
+    }
+    fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError> {
+        match self.reboot_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for reboot_relational_database"),
+        }
+    }
+    fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError> {
+        match self.release_static_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for release_static_ip"),
+        }
     }
-
-    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn start_instance(
         &self,
         input: StartInstanceRequest,
     ) -> RusotoFuture<StartInstanceResult, StartInstanceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.StartInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartInstanceError::from_response(response))),
-                )
-            }
-        })
+        match self.start_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for start_instance"),
+        }
     }
-
-    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn start_relational_database(
         &self,
         input: StartRelationalDatabaseRequest,
     ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.StartRelationalDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartRelationalDatabaseError::from_response(response))
-                }))
-            }
-        })
+        match self.start_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for start_relational_database"),
+        }
     }
-
-    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn stop_instance(
         &self,
         input: StopInstanceRequest,
     ) -> RusotoFuture<StopInstanceResult, StopInstanceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.StopInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopInstanceError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for stop_instance"),
+        }
     }
-
-    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn stop_relational_database(
         &self,
         input: StopRelationalDatabaseRequest,
     ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.StopRelationalDatabase");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StopRelationalDatabaseError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.stop_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for stop_relational_database"),
+        }
     }
-
-    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResult, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
     fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.UnpeerVpc");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<UnpeerVpcResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UnpeerVpcError::from_response(response))),
-                )
-            }
-        })
+        match self.unpeer_vpc {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockLightsail has no handler for unpeer_vpc"),
+        }
     }
-
-    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResult, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn update_domain_entry(
         &self,
         input: UpdateDomainEntryRequest,
     ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Lightsail_20161128.UpdateDomainEntry");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainEntryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDomainEntryError::from_response(response))),
-                )
-            }
-        })
+        match self.update_domain_entry {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for update_domain_entry"),
+        }
     }
-
-    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn update_load_balancer_attribute(
         &self,
         input: UpdateLoadBalancerAttributeRequest,
     ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.UpdateLoadBalancerAttribute",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateLoadBalancerAttributeResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateLoadBalancerAttributeError::from_response(response))
-                }))
-            }
-        })
+        match self.update_load_balancer_attribute {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for update_load_balancer_attribute"),
+        }
     }
-
-    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn update_relational_database(
         &self,
         input: UpdateRelationalDatabaseRequest,
     ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError> {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.UpdateRelationalDatabase",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRelationalDatabaseResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateRelationalDatabaseError::from_response(response))
-                }))
-            }
-        })
+        match self.update_relational_database {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockLightsail has no handler for update_relational_database"),
+        }
     }
-
-    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which paramater updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
     fn update_relational_database_parameters(
         &self,
         input: UpdateRelationalDatabaseParametersRequest,
@@ -15095,29 +22615,11 @@ impl Lightsail for LightsailClient {
         UpdateRelationalDatabaseParametersResult,
         UpdateRelationalDatabaseParametersError,
     > {
-        let mut request = SignedRequest::new("POST", "lightsail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Lightsail_20161128.UpdateRelationalDatabaseParameters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRelationalDatabaseParametersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateRelationalDatabaseParametersError::from_response(
-                        response,
-                    ))
-                }))
+        match self.update_relational_database_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockLightsail has no handler for update_relational_database_parameters")
             }
-        })
+        }
     }
 }
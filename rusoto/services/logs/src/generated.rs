@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateKmsKeyRequest {
     /// <p>The Amazon Resource Name (ARN) of the CMK to use when encrypting log data. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-kms">Amazon Resource Names - AWS Key Management Service (AWS KMS)</a>.</p>
     #[serde(rename = "kmsKeyId")]
@@ -33,14 +35,49 @@ pub struct AssociateKmsKeyRequest {
     pub log_group_name: String,
 }
 
+impl Validate for AssociateKmsKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "kms_key_id"),
+            self.kms_key_id.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelExportTaskRequest {
     /// <p>The ID of the export task.</p>
     #[serde(rename = "taskId")]
     pub task_id: String,
 }
 
+impl Validate for CancelExportTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "task_id"),
+            self.task_id.chars().count(),
+            Some(1),
+            Some(512),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateExportTaskRequest {
     /// <p>The name of S3 bucket for the exported log data. The bucket must be in the same AWS region.</p>
     #[serde(rename = "destination")]
@@ -68,8 +105,51 @@ pub struct CreateExportTaskRequest {
     pub to: i64,
 }
 
+impl Validate for CreateExportTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "destination"),
+            self.destination.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        if let Some(ref value) = self.log_stream_name_prefix {
+            errors.check_length(
+                &field_path(path, "log_stream_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_stream_name_prefix"),
+                &value,
+                "[^:*]*",
+            );
+        }
+        if let Some(ref value) = self.task_name {
+            errors.check_length(
+                &field_path(path, "task_name"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateExportTaskResponse {
     /// <p>The ID of the export task.</p>
     #[serde(rename = "taskId")]
@@ -78,6 +158,7 @@ pub struct CreateExportTaskResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLogGroupRequest {
     /// <p>The Amazon Resource Name (ARN) of the CMK to use when encrypting log data. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-kms">Amazon Resource Names - AWS Key Management Service (AWS KMS)</a>.</p>
     #[serde(rename = "kmsKeyId")]
@@ -92,7 +173,40 @@ pub struct CreateLogGroupRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateLogGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLogStreamRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
@@ -102,21 +216,83 @@ pub struct CreateLogStreamRequest {
     pub log_stream_name: String,
 }
 
+impl Validate for CreateLogStreamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "log_stream_name"),
+            self.log_stream_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_stream_name"),
+            &self.log_stream_name,
+            "[^:*]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDestinationRequest {
     /// <p>The name of the destination.</p>
     #[serde(rename = "destinationName")]
     pub destination_name: String,
 }
 
+impl Validate for DeleteDestinationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "destination_name"),
+            self.destination_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_name"),
+            &self.destination_name,
+            "[^:*]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLogGroupRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
     pub log_group_name: String,
 }
 
+impl Validate for DeleteLogGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLogStreamRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
@@ -126,7 +302,35 @@ pub struct DeleteLogStreamRequest {
     pub log_stream_name: String,
 }
 
+impl Validate for DeleteLogStreamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "log_stream_name"),
+            self.log_stream_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_stream_name"),
+            &self.log_stream_name,
+            "[^:*]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMetricFilterRequest {
     /// <p>The name of the metric filter.</p>
     #[serde(rename = "filterName")]
@@ -136,7 +340,35 @@ pub struct DeleteMetricFilterRequest {
     pub log_group_name: String,
 }
 
+impl Validate for DeleteMetricFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filter_name"),
+            self.filter_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "filter_name"),
+            &self.filter_name,
+            "[^:*]*",
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourcePolicyRequest {
     /// <p>The name of the policy to be revoked. This parameter is required.</p>
     #[serde(rename = "policyName")]
@@ -145,13 +377,31 @@ pub struct DeleteResourcePolicyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRetentionPolicyRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
     pub log_group_name: String,
 }
 
+impl Validate for DeleteRetentionPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSubscriptionFilterRequest {
     /// <p>The name of the subscription filter.</p>
     #[serde(rename = "filterName")]
@@ -161,7 +411,35 @@ pub struct DeleteSubscriptionFilterRequest {
     pub log_group_name: String,
 }
 
+impl Validate for DeleteSubscriptionFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filter_name"),
+            self.filter_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "filter_name"),
+            &self.filter_name,
+            "[^:*]*",
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDestinationsRequest {
     /// <p>The prefix to match. If you don't specify a value, no prefix filter is applied.</p>
     #[serde(rename = "DestinationNamePrefix")]
@@ -177,8 +455,37 @@ pub struct DescribeDestinationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeDestinationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_name_prefix {
+            errors.check_length(
+                &field_path(path, "destination_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "destination_name_prefix"),
+                &value,
+                "[^:*]*",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDestinationsResponse {
     /// <p>The destinations.</p>
     #[serde(rename = "destinations")]
@@ -190,6 +497,7 @@ pub struct DescribeDestinationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExportTasksRequest {
     /// <p>The maximum number of items returned. If you don't specify a value, the default is up to 50 items.</p>
     #[serde(rename = "limit")]
@@ -209,8 +517,32 @@ pub struct DescribeExportTasksRequest {
     pub task_id: Option<String>,
 }
 
+impl Validate for DescribeExportTasksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.task_id {
+            errors.check_length(
+                &field_path(path, "task_id"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeExportTasksResponse {
     /// <p>The export tasks.</p>
     #[serde(rename = "exportTasks")]
@@ -222,6 +554,7 @@ pub struct DescribeExportTasksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLogGroupsRequest {
     /// <p>The maximum number of items returned. If you don't specify a value, the default is up to 50 items.</p>
     #[serde(rename = "limit")]
@@ -237,8 +570,37 @@ pub struct DescribeLogGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeLogGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.log_group_name_prefix {
+            errors.check_length(
+                &field_path(path, "log_group_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_group_name_prefix"),
+                &value,
+                "[\\.\\-_/#A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLogGroupsResponse {
     /// <p>The log groups.</p>
     #[serde(rename = "logGroups")]
@@ -250,6 +612,7 @@ pub struct DescribeLogGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLogStreamsRequest {
     /// <p>If the value is true, results are returned in descending order. If the value is to false, results are returned in ascending order. The default value is false.</p>
     #[serde(rename = "descending")]
@@ -276,8 +639,48 @@ pub struct DescribeLogStreamsRequest {
     pub order_by: Option<String>,
 }
 
+impl Validate for DescribeLogStreamsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        if let Some(ref value) = self.log_stream_name_prefix {
+            errors.check_length(
+                &field_path(path, "log_stream_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_stream_name_prefix"),
+                &value,
+                "[^:*]*",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLogStreamsResponse {
     /// <p>The log streams.</p>
     #[serde(rename = "logStreams")]
@@ -289,6 +692,7 @@ pub struct DescribeLogStreamsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMetricFiltersRequest {
     /// <p>The prefix to match.</p>
     #[serde(rename = "filterNamePrefix")]
@@ -316,8 +720,64 @@ pub struct DescribeMetricFiltersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeMetricFiltersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_name_prefix {
+            errors.check_length(
+                &field_path(path, "filter_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "filter_name_prefix"), &value, "[^:*]*");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.log_group_name {
+            errors.check_length(
+                &field_path(path, "log_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_group_name"),
+                &value,
+                "[\\.\\-_/#A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.metric_name {
+            errors.check_length(
+                &field_path(path, "metric_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "metric_name"), &value, "[^:*$]*");
+        }
+        if let Some(ref value) = self.metric_namespace {
+            errors.check_length(
+                &field_path(path, "metric_namespace"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "metric_namespace"), &value, "[^:*$]*");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMetricFiltersResponse {
     /// <p>The metric filters.</p>
     #[serde(rename = "metricFilters")]
@@ -329,6 +789,7 @@ pub struct DescribeMetricFiltersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeQueriesRequest {
     /// <p>Limits the returned queries to only those for the specified log group.</p>
     #[serde(rename = "logGroupName")]
@@ -347,8 +808,42 @@ pub struct DescribeQueriesRequest {
     pub status: Option<String>,
 }
 
+impl Validate for DescribeQueriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.log_group_name {
+            errors.check_length(
+                &field_path(path, "log_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_group_name"),
+                &value,
+                "[\\.\\-_/#A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeQueriesResponse {
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -360,6 +855,7 @@ pub struct DescribeQueriesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeResourcePoliciesRequest {
     /// <p>The maximum number of resource policies to be displayed with one call of this API.</p>
     #[serde(rename = "limit")]
@@ -370,8 +866,24 @@ pub struct DescribeResourcePoliciesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeResourcePoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeResourcePoliciesResponse {
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -383,6 +895,7 @@ pub struct DescribeResourcePoliciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSubscriptionFiltersRequest {
     /// <p>The prefix to match. If you don't specify a value, no prefix filter is applied.</p>
     #[serde(rename = "filterNamePrefix")]
@@ -401,8 +914,44 @@ pub struct DescribeSubscriptionFiltersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeSubscriptionFiltersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_name_prefix {
+            errors.check_length(
+                &field_path(path, "filter_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "filter_name_prefix"), &value, "[^:*]*");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSubscriptionFiltersResponse {
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -415,7 +964,7 @@ pub struct DescribeSubscriptionFiltersResponse {
 
 /// <p>Represents a cross-account destination that receives subscription log events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Destination {
     /// <p>An IAM policy document that governs which AWS accounts can create subscription filters against this destination.</p>
     #[serde(rename = "accessPolicy")]
@@ -444,15 +993,32 @@ pub struct Destination {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateKmsKeyRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
     pub log_group_name: String,
 }
 
+impl Validate for DisassociateKmsKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 /// <p>Represents an export task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportTask {
     /// <p>The name of Amazon S3 bucket to which the log data was exported.</p>
     #[serde(rename = "destination")]
@@ -494,7 +1060,7 @@ pub struct ExportTask {
 
 /// <p>Represents the status of an export task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportTaskExecutionInfo {
     /// <p>The completion time of the export task, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC.</p>
     #[serde(rename = "completionTime")]
@@ -508,7 +1074,7 @@ pub struct ExportTaskExecutionInfo {
 
 /// <p>Represents the status of an export task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportTaskStatus {
     /// <p>The status code of the export task.</p>
     #[serde(rename = "code")]
@@ -521,6 +1087,7 @@ pub struct ExportTaskStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FilterLogEventsRequest {
     /// <p>The end of the time range, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. Events with a timestamp later than this time are not returned.</p>
     #[serde(rename = "endTime")]
@@ -555,8 +1122,69 @@ pub struct FilterLogEventsRequest {
     pub start_time: Option<i64>,
 }
 
+impl Validate for FilterLogEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_pattern {
+            errors.check_length(
+                &field_path(path, "filter_pattern"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        if let Some(ref value) = self.log_stream_name_prefix {
+            errors.check_length(
+                &field_path(path, "log_stream_name_prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_stream_name_prefix"),
+                &value,
+                "[^:*]*",
+            );
+        }
+        if let Some(ref value) = self.log_stream_names {
+            errors.check_length(
+                &field_path(path, "log_stream_names"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "log_stream_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(512));
+                errors.check_pattern(&item_path, &item, "[^:*]*");
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FilterLogEventsResponse {
     /// <p>The matched events.</p>
     #[serde(rename = "events")]
@@ -574,7 +1202,7 @@ pub struct FilterLogEventsResponse {
 
 /// <p>Represents a matched event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FilteredLogEvent {
     /// <p>The ID of the event.</p>
     #[serde(rename = "eventId")]
@@ -599,6 +1227,7 @@ pub struct FilteredLogEvent {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLogEventsRequest {
     /// <p>The end of the time range, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. Events with a timestamp equal to or later than this time are not included.</p>
     #[serde(rename = "endTime")]
@@ -628,8 +1257,46 @@ pub struct GetLogEventsRequest {
     pub start_time: Option<i64>,
 }
 
+impl Validate for GetLogEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "log_stream_name"),
+            self.log_stream_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_stream_name"),
+            &self.log_stream_name,
+            "[^:*]*",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLogEventsResponse {
     /// <p>The events.</p>
     #[serde(rename = "events")]
@@ -646,6 +1313,7 @@ pub struct GetLogEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLogGroupFieldsRequest {
     /// <p>The name of the log group to search.</p>
     #[serde(rename = "logGroupName")]
@@ -656,8 +1324,24 @@ pub struct GetLogGroupFieldsRequest {
     pub time: Option<i64>,
 }
 
+impl Validate for GetLogGroupFieldsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLogGroupFieldsResponse {
     /// <p>The array of fields found in the query. Each object in the array contains the name of the field, along with the percentage of time it appeared in the log events that were queried.</p>
     #[serde(rename = "logGroupFields")]
@@ -666,6 +1350,7 @@ pub struct GetLogGroupFieldsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLogRecordRequest {
     /// <p>The pointer corresponding to the log event record you want to retrieve. You get this from the response of a <code>GetQueryResults</code> operation. In that response, the value of the <code>@ptr</code> field for a log event is the value to use as <code>logRecordPointer</code> to retrieve that complete log event record.</p>
     #[serde(rename = "logRecordPointer")]
@@ -673,7 +1358,7 @@ pub struct GetLogRecordRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLogRecordResponse {
     /// <p>The requested log event, as a JSON string.</p>
     #[serde(rename = "logRecord")]
@@ -682,14 +1367,26 @@ pub struct GetLogRecordResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueryResultsRequest {
     /// <p>The ID number of the query.</p>
     #[serde(rename = "queryId")]
     pub query_id: String,
 }
 
+impl Validate for GetQueryResultsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "query_id"),
+            self.query_id.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetQueryResultsResponse {
     /// <p>The log events that matched the query criteria during the most recent time it ran.</p> <p>The <code>results</code> value is an array of arrays. Each log event is one object in the top-level array. Each of these log event objects is an array of <code>field</code>/<code>value</code> pairs.</p>
     #[serde(rename = "results")]
@@ -707,6 +1404,7 @@ pub struct GetQueryResultsResponse {
 
 /// <p>Represents a log event, which is a record of activity that was recorded by the application or resource being monitored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputLogEvent {
     /// <p>The raw event message.</p>
     #[serde(rename = "message")]
@@ -716,15 +1414,43 @@ pub struct InputLogEvent {
     pub timestamp: i64,
 }
 
+impl Validate for InputLogEvent {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "message"),
+            self.message.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsLogGroupRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
     pub log_group_name: String,
 }
 
+impl Validate for ListTagsLogGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsLogGroupResponse {
     /// <p>The tags for the log group.</p>
     #[serde(rename = "tags")]
@@ -734,7 +1460,7 @@ pub struct ListTagsLogGroupResponse {
 
 /// <p>Represents a log group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogGroup {
     /// <p>The Amazon Resource Name (ARN) of the log group.</p>
     #[serde(rename = "arn")]
@@ -767,7 +1493,7 @@ pub struct LogGroup {
 
 /// <p>The fields contained in log events found by a <code>GetLogGroupFields</code> operation, along with the percentage of queried log events in which each field appears.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogGroupField {
     /// <p>The name of a log field.</p>
     #[serde(rename = "name")]
@@ -781,7 +1507,7 @@ pub struct LogGroupField {
 
 /// <p>Represents a log stream, which is a sequence of log events from a single emitter of logs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogStream {
     /// <p>The Amazon Resource Name (ARN) of the log stream.</p>
     #[serde(rename = "arn")]
@@ -815,7 +1541,7 @@ pub struct LogStream {
 
 /// <p>Metric filters express how CloudWatch Logs would extract metric observations from ingested log events and transform them into metric data in a CloudWatch metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MetricFilter {
     /// <p>The creation time of the metric filter, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC.</p>
     #[serde(rename = "creationTime")]
@@ -840,7 +1566,7 @@ pub struct MetricFilter {
 
 /// <p>Represents a matched event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MetricFilterMatchRecord {
     /// <p>The raw event data.</p>
     #[serde(rename = "eventMessage")]
@@ -874,9 +1600,42 @@ pub struct MetricTransformation {
     pub metric_value: String,
 }
 
+impl Validate for MetricTransformation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "metric_name"),
+            &self.metric_name,
+            "[^:*$]*",
+        );
+        errors.check_length(
+            &field_path(path, "metric_namespace"),
+            self.metric_namespace.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "metric_namespace"),
+            &self.metric_namespace,
+            "[^:*$]*",
+        );
+        errors.check_length(
+            &field_path(path, "metric_value"),
+            self.metric_value.chars().count(),
+            None,
+            Some(100),
+        );
+    }
+}
+
 /// <p>Represents a log event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OutputLogEvent {
     /// <p>The time the event was ingested, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC.</p>
     #[serde(rename = "ingestionTime")]
@@ -893,6 +1652,7 @@ pub struct OutputLogEvent {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutDestinationPolicyRequest {
     /// <p>An IAM policy document that authorizes cross-account users to deliver their log events to the associated destination.</p>
     #[serde(rename = "accessPolicy")]
@@ -902,7 +1662,30 @@ pub struct PutDestinationPolicyRequest {
     pub destination_name: String,
 }
 
+impl Validate for PutDestinationPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "access_policy"),
+            self.access_policy.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "destination_name"),
+            self.destination_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_name"),
+            &self.destination_name,
+            "[^:*]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutDestinationRequest {
     /// <p>A name for the destination.</p>
     #[serde(rename = "destinationName")]
@@ -915,8 +1698,36 @@ pub struct PutDestinationRequest {
     pub target_arn: String,
 }
 
+impl Validate for PutDestinationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "destination_name"),
+            self.destination_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_name"),
+            &self.destination_name,
+            "[^:*]*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "target_arn"),
+            self.target_arn.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutDestinationResponse {
     /// <p>The destination.</p>
     #[serde(rename = "destination")]
@@ -925,6 +1736,7 @@ pub struct PutDestinationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLogEventsRequest {
     /// <p>The log events.</p>
     #[serde(rename = "logEvents")]
@@ -941,8 +1753,53 @@ pub struct PutLogEventsRequest {
     pub sequence_token: Option<String>,
 }
 
+impl Validate for PutLogEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_events"),
+            self.log_events.len(),
+            Some(1),
+            Some(10000),
+        );
+        for (i, item) in self.log_events.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "log_events"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "log_stream_name"),
+            self.log_stream_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_stream_name"),
+            &self.log_stream_name,
+            "[^:*]*",
+        );
+        if let Some(ref value) = self.sequence_token {
+            errors.check_length(
+                &field_path(path, "sequence_token"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLogEventsResponse {
     /// <p>The next sequence token.</p>
     #[serde(rename = "nextSequenceToken")]
@@ -955,6 +1812,7 @@ pub struct PutLogEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutMetricFilterRequest {
     /// <p>A name for the metric filter.</p>
     #[serde(rename = "filterName")]
@@ -970,7 +1828,51 @@ pub struct PutMetricFilterRequest {
     pub metric_transformations: Vec<MetricTransformation>,
 }
 
+impl Validate for PutMetricFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filter_name"),
+            self.filter_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "filter_name"),
+            &self.filter_name,
+            "[^:*]*",
+        );
+        errors.check_length(
+            &field_path(path, "filter_pattern"),
+            self.filter_pattern.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "metric_transformations"),
+            self.metric_transformations.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.metric_transformations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "metric_transformations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutResourcePolicyRequest {
     /// <p>Details of the new policy, including the identity of the principal that is enabled to put logs to this account. This is formatted as a JSON string. This parameter is required.</p> <p>The following example creates a resource policy enabling the Route 53 service to put DNS query logs in to the specified log group. Replace "logArn" with the ARN of your CloudWatch Logs resource, such as a log group or log stream.</p> <p> <code>{ "Version": "2012-10-17", "Statement": [ { "Sid": "Route53LogsToCloudWatchLogs", "Effect": "Allow", "Principal": { "Service": [ "route53.amazonaws.com" ] }, "Action":"logs:PutLogEvents", "Resource": "logArn" } ] } </code> </p>
     #[serde(rename = "policyDocument")]
@@ -982,8 +1884,21 @@ pub struct PutResourcePolicyRequest {
     pub policy_name: Option<String>,
 }
 
+impl Validate for PutResourcePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.policy_document {
+            errors.check_length(
+                &field_path(path, "policy_document"),
+                value.chars().count(),
+                Some(1),
+                Some(5120),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutResourcePolicyResponse {
     /// <p>The new policy.</p>
     #[serde(rename = "resourcePolicy")]
@@ -992,6 +1907,7 @@ pub struct PutResourcePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutRetentionPolicyRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
@@ -1000,7 +1916,24 @@ pub struct PutRetentionPolicyRequest {
     pub retention_in_days: i64,
 }
 
+impl Validate for PutRetentionPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutSubscriptionFilterRequest {
     /// <p><p>The ARN of the destination to deliver matching log events to. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination (specified using an ARN) belonging to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function belonging to the same account as the subscription filter, for same-account delivery.</p> </li> </ul></p>
     #[serde(rename = "destinationArn")]
@@ -1024,6 +1957,53 @@ pub struct PutSubscriptionFilterRequest {
     pub role_arn: Option<String>,
 }
 
+impl Validate for PutSubscriptionFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "destination_arn"),
+            self.destination_arn.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "filter_name"),
+            self.filter_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "filter_name"),
+            &self.filter_name,
+            "[^:*]*",
+        );
+        errors.check_length(
+            &field_path(path, "filter_pattern"),
+            self.filter_pattern.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Reserved.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct QueryCompileError {
@@ -1044,7 +2024,7 @@ pub struct QueryCompileErrorLocation {
 
 /// <p>Information about one CloudWatch Logs Insights query that matches the request in a <code>DescribeQueries</code> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryInfo {
     /// <p>The date and time that this query was created.</p>
     #[serde(rename = "createTime")]
@@ -1070,7 +2050,7 @@ pub struct QueryInfo {
 
 /// <p>Contains the number of log events scanned by the query, the number of log events that matched the query criteria, and the total number of bytes in the log events that were scanned.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryStatistics {
     /// <p>The total number of bytes in the log events scanned during the query.</p>
     #[serde(rename = "bytesScanned")]
@@ -1088,7 +2068,7 @@ pub struct QueryStatistics {
 
 /// <p>Represents the rejected events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectedLogEventsInfo {
     /// <p>The expired log events.</p>
     #[serde(rename = "expiredLogEventEndIndex")]
@@ -1106,7 +2086,7 @@ pub struct RejectedLogEventsInfo {
 
 /// <p>A policy enabling one or more entities to put logs to a log group in this account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourcePolicy {
     /// <p>Timestamp showing when this policy was last updated, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC.</p>
     #[serde(rename = "lastUpdatedTime")]
@@ -1124,7 +2104,7 @@ pub struct ResourcePolicy {
 
 /// <p>Contains one field from one log event returned by a CloudWatch Logs Insights query, along with the value of that field.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResultField {
     /// <p>The log event field.</p>
     #[serde(rename = "field")]
@@ -1138,7 +2118,7 @@ pub struct ResultField {
 
 /// <p>Represents the search status of a log stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchedLogStream {
     /// <p>The name of the log stream.</p>
     #[serde(rename = "logStreamName")]
@@ -1151,6 +2131,7 @@ pub struct SearchedLogStream {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartQueryRequest {
     /// <p>The end of the time range to query. The range is inclusive, so the specified end time is included in the query. Specified as epoch time, the number of seconds since January 1, 1970, 00:00:00 UTC.</p>
     #[serde(rename = "endTime")]
@@ -1175,8 +2156,42 @@ pub struct StartQueryRequest {
     pub start_time: i64,
 }
 
+impl Validate for StartQueryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(10000));
+        }
+        if let Some(ref value) = self.log_group_name {
+            errors.check_length(
+                &field_path(path, "log_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "log_group_name"),
+                &value,
+                "[\\.\\-_/#A-Za-z0-9]+",
+            );
+        }
+        if let Some(ref value) = self.log_group_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "log_group_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(512));
+                errors.check_pattern(&item_path, &item, "[\\.\\-_/#A-Za-z0-9]+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "query_string"),
+            self.query_string.chars().count(),
+            None,
+            Some(10000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartQueryResponse {
     /// <p>The unique ID of the query. </p>
     #[serde(rename = "queryId")]
@@ -1185,14 +2200,26 @@ pub struct StartQueryResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopQueryRequest {
     /// <p>The ID number of the query to stop. If necessary, you can use <code>DescribeQueries</code> to find this ID number.</p>
     #[serde(rename = "queryId")]
     pub query_id: String,
 }
 
+impl Validate for StopQueryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "query_id"),
+            self.query_id.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopQueryResponse {
     /// <p>This is true if the query was stopped by the <code>StopQuery</code> operation.</p>
     #[serde(rename = "success")]
@@ -1202,7 +2229,7 @@ pub struct StopQueryResponse {
 
 /// <p>Represents a subscription filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscriptionFilter {
     /// <p>The creation time of the subscription filter, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC.</p>
     #[serde(rename = "creationTime")]
@@ -1233,6 +2260,7 @@ pub struct SubscriptionFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagLogGroupRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
@@ -1242,7 +2270,35 @@ pub struct TagLogGroupRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagLogGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestMetricFilterRequest {
     #[serde(rename = "filterPattern")]
     pub filter_pattern: String,
@@ -1251,8 +2307,29 @@ pub struct TestMetricFilterRequest {
     pub log_event_messages: Vec<String>,
 }
 
+impl Validate for TestMetricFilterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filter_pattern"),
+            self.filter_pattern.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "log_event_messages"),
+            self.log_event_messages.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.log_event_messages.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "log_event_messages"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestMetricFilterResponse {
     /// <p>The matched events.</p>
     #[serde(rename = "matches")]
@@ -1261,6 +2338,7 @@ pub struct TestMetricFilterResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagLogGroupRequest {
     /// <p>The name of the log group.</p>
     #[serde(rename = "logGroupName")]
@@ -1270,6 +2348,28 @@ pub struct UntagLogGroupRequest {
     pub tags: Vec<String>,
 }
 
+impl Validate for UntagLogGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_group_name"),
+            self.log_group_name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "log_group_name"),
+            &self.log_group_name,
+            "[\\.\\-_/#A-Za-z0-9]+",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), Some(1), None);
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]+)$");
+        }
+    }
+}
+
 /// Errors returned by AssociateKmsKey
 #[derive(Debug, PartialEq)]
 pub enum AssociateKmsKeyError {
@@ -1299,7 +2399,12 @@ impl AssociateKmsKeyError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(AssociateKmsKeyError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1350,7 +2455,12 @@ impl CancelExportTaskError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CancelExportTaskError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1413,7 +2523,12 @@ impl CreateExportTaskError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CreateExportTaskError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1473,7 +2588,12 @@ impl CreateLogGroupError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CreateLogGroupError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1527,7 +2647,12 @@ impl CreateLogStreamError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CreateLogStreamError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1580,7 +2705,12 @@ impl DeleteDestinationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1631,7 +2761,12 @@ impl DeleteLogGroupError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteLogGroupError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1682,7 +2817,12 @@ impl DeleteLogStreamError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteLogStreamError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1735,7 +2875,12 @@ impl DeleteMetricFilterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1787,7 +2932,12 @@ impl DeleteResourcePolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1845,7 +2995,12 @@ impl DeleteRetentionPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1904,7 +3059,12 @@ impl DeleteSubscriptionFilterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1949,7 +3109,12 @@ impl DescribeDestinationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1992,7 +3157,12 @@ impl DescribeExportTasksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2033,7 +3203,12 @@ impl DescribeLogGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2079,7 +3254,12 @@ impl DescribeLogStreamsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2130,7 +3310,12 @@ impl DescribeMetricFiltersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2175,7 +3360,12 @@ impl DescribeQueriesError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DescribeQueriesError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2219,7 +3409,12 @@ impl DescribeResourcePoliciesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2271,7 +3466,12 @@ impl DescribeSubscriptionFiltersError {
                         DescribeSubscriptionFiltersError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2323,7 +3523,12 @@ impl DisassociateKmsKeyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2369,7 +3574,12 @@ impl FilterLogEventsError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(FilterLogEventsError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2414,7 +3624,12 @@ impl GetLogEventsError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(GetLogEventsError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2466,7 +3681,12 @@ impl GetLogGroupFieldsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2517,7 +3737,12 @@ impl GetLogRecordError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(GetLogRecordError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2563,7 +3788,12 @@ impl GetQueryResultsError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(GetQueryResultsError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2603,7 +3833,12 @@ impl ListTagsLogGroupError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListTagsLogGroupError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2647,7 +3882,12 @@ impl PutDestinationError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(PutDestinationError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2698,7 +3938,12 @@ impl PutDestinationPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2758,7 +4003,12 @@ impl PutLogEventsError {
                 "UnrecognizedClientException" => {
                     return RusotoError::Service(PutLogEventsError::UnrecognizedClient(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2816,7 +4066,12 @@ impl PutMetricFilterError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(PutMetricFilterError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2865,7 +4120,12 @@ impl PutResourcePolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2917,7 +4177,12 @@ impl PutRetentionPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2981,7 +4246,12 @@ impl PutSubscriptionFilterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3038,7 +4308,12 @@ impl StartQueryError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(StartQueryError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3085,7 +4360,12 @@ impl StopQueryError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(StopQueryError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3125,7 +4405,12 @@ impl TagLogGroupError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagLogGroupError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3164,7 +4449,12 @@ impl TestMetricFilterError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(TestMetricFilterError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3198,7 +4488,12 @@ impl UntagLogGroupError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagLogGroupError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3447,1096 +4742,3409 @@ pub trait CloudWatchLogs {
     /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To add tags, use <a>UntagLogGroup</a>.</p>
     fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError>;
 }
-/// A client for the Amazon CloudWatch Logs API.
-#[derive(Clone)]
-pub struct CloudWatchLogsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl CloudWatchLogsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> CloudWatchLogsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> CloudWatchLogsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
-        CloudWatchLogsClient { client, region }
-    }
-}
-
-impl CloudWatchLogs for CloudWatchLogsClient {
-    /// <p>Associates the specified AWS Key Management Service (AWS KMS) customer master key (CMK) with the specified log group.</p> <p>Associating an AWS KMS CMK with a log group overrides any existing associations between the log group and a CMK. After a CMK is associated with a log group, all newly ingested data for the log group is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p> <p>If you attempt to associate a CMK with a log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
+const _: Option<&dyn CloudWatchLogs> = None;
+impl<'a, T: CloudWatchLogs + ?Sized> CloudWatchLogs for &'a T {
     fn associate_kms_key(
         &self,
         input: AssociateKmsKeyRequest,
     ) -> RusotoFuture<(), AssociateKmsKeyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.AssociateKmsKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssociateKmsKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).associate_kms_key(input)
     }
-
-    /// <p>Cancels the specified export task.</p> <p>The task must be in the <code>PENDING</code> or <code>RUNNING</code> state.</p>
     fn cancel_export_task(
         &self,
         input: CancelExportTaskRequest,
     ) -> RusotoFuture<(), CancelExportTaskError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.CancelExportTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelExportTaskError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_export_task(input)
     }
-
-    /// <p>Creates an export task, which allows you to efficiently export data from a log group to an Amazon S3 bucket.</p> <p>This is an asynchronous call. If all the required information is provided, this operation initiates an export task and responds with the ID of the task. After the task has started, you can use <a>DescribeExportTasks</a> to get the status of the export task. Each account can only have one active (<code>RUNNING</code> or <code>PENDING</code>) export task at a time. To cancel an export task, use <a>CancelExportTask</a>.</p> <p>You can export logs from multiple log groups or multiple time ranges to the same S3 bucket. To separate out log data for each export task, you can specify a prefix to be used as the Amazon S3 key prefix for all exported objects.</p> <p>Exporting to S3 buckets that are encrypted with AES-256 is supported. Exporting to S3 buckets encrypted with SSE-KMS is not supported. </p>
     fn create_export_task(
         &self,
         input: CreateExportTaskRequest,
     ) -> RusotoFuture<CreateExportTaskResponse, CreateExportTaskError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.CreateExportTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateExportTaskResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateExportTaskError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_export_task(input)
     }
-
-    /// <p>Creates a log group with the specified name.</p> <p>You can create up to 5000 log groups per account.</p> <p>You must use the following guidelines when naming a log group:</p> <ul> <li> <p>Log group names must be unique within a region for an AWS account.</p> </li> <li> <p>Log group names can be between 1 and 512 characters long.</p> </li> <li> <p>Log group names consist of the following characters: a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), '/' (forward slash), and '.' (period).</p> </li> </ul> <p>If you associate a AWS Key Management Service (AWS KMS) customer master key (CMK) with the log group, ingested data is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>If you attempt to associate a CMK with the log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
     fn create_log_group(
         &self,
         input: CreateLogGroupRequest,
     ) -> RusotoFuture<(), CreateLogGroupError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.CreateLogGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLogGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_log_group(input)
     }
-
-    /// <p><p>Creates a log stream for the specified log group.</p> <p>There is no limit on the number of log streams that you can create for a log group.</p> <p>You must use the following guidelines when naming a log stream:</p> <ul> <li> <p>Log stream names must be unique within the log group.</p> </li> <li> <p>Log stream names can be between 1 and 512 characters long.</p> </li> <li> <p>The &#39;:&#39; (colon) and &#39;*&#39; (asterisk) characters are not allowed.</p> </li> </ul></p>
     fn create_log_stream(
         &self,
         input: CreateLogStreamRequest,
     ) -> RusotoFuture<(), CreateLogStreamError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.CreateLogStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLogStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_log_stream(input)
     }
-
-    /// <p>Deletes the specified destination, and eventually disables all the subscription filters that publish to it. This operation does not delete the physical resource encapsulated by the destination.</p>
     fn delete_destination(
         &self,
         input: DeleteDestinationRequest,
     ) -> RusotoFuture<(), DeleteDestinationError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteDestination");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDestinationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_destination(input)
     }
-
-    /// <p>Deletes the specified log group and permanently deletes all the archived log events associated with the log group.</p>
     fn delete_log_group(
         &self,
         input: DeleteLogGroupRequest,
     ) -> RusotoFuture<(), DeleteLogGroupError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteLogGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLogGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_log_group(input)
     }
-
-    /// <p>Deletes the specified log stream and permanently deletes all the archived log events associated with the log stream.</p>
     fn delete_log_stream(
         &self,
         input: DeleteLogStreamRequest,
     ) -> RusotoFuture<(), DeleteLogStreamError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteLogStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLogStreamError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_log_stream(input)
     }
-
-    /// <p>Deletes the specified metric filter.</p>
     fn delete_metric_filter(
         &self,
         input: DeleteMetricFilterRequest,
     ) -> RusotoFuture<(), DeleteMetricFilterError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteMetricFilter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMetricFilterError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_metric_filter(input)
     }
-
-    /// <p>Deletes a resource policy from this account. This revokes the access of the identities in that policy to put log events to this account.</p>
     fn delete_resource_policy(
         &self,
         input: DeleteResourcePolicyRequest,
     ) -> RusotoFuture<(), DeleteResourcePolicyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteResourcePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteResourcePolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_resource_policy(input)
     }
-
-    /// <p>Deletes the specified retention policy.</p> <p>Log events do not expire if they belong to log groups without a retention policy.</p>
     fn delete_retention_policy(
         &self,
         input: DeleteRetentionPolicyRequest,
     ) -> RusotoFuture<(), DeleteRetentionPolicyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteRetentionPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRetentionPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_retention_policy(input)
     }
-
-    /// <p>Deletes the specified subscription filter.</p>
     fn delete_subscription_filter(
         &self,
         input: DeleteSubscriptionFilterRequest,
     ) -> RusotoFuture<(), DeleteSubscriptionFilterError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DeleteSubscriptionFilter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSubscriptionFilterError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_subscription_filter(input)
     }
-
-    /// <p>Lists all your destinations. The results are ASCII-sorted by destination name.</p>
     fn describe_destinations(
         &self,
         input: DescribeDestinationsRequest,
     ) -> RusotoFuture<DescribeDestinationsResponse, DescribeDestinationsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeDestinations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDestinationsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeDestinationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_destinations(input)
     }
-
-    /// <p>Lists the specified export tasks. You can list all your export tasks or filter the results based on task ID or task status.</p>
     fn describe_export_tasks(
         &self,
         input: DescribeExportTasksRequest,
     ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeExportTasks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeExportTasksResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeExportTasksError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_export_tasks(input)
     }
-
-    /// <p>Lists the specified log groups. You can list all your log groups or filter the results by prefix. The results are ASCII-sorted by log group name.</p>
     fn describe_log_groups(
         &self,
         input: DescribeLogGroupsRequest,
     ) -> RusotoFuture<DescribeLogGroupsResponse, DescribeLogGroupsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeLogGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLogGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLogGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_log_groups(input)
     }
-
-    /// <p>Lists the log streams for the specified log group. You can list all the log streams or filter the results by prefix. You can also control how the results are ordered.</p> <p>This operation has a limit of five transactions per second, after which transactions are throttled.</p>
     fn describe_log_streams(
         &self,
         input: DescribeLogStreamsRequest,
     ) -> RusotoFuture<DescribeLogStreamsResponse, DescribeLogStreamsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeLogStreams");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLogStreamsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLogStreamsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_log_streams(input)
     }
-
-    /// <p>Lists the specified metric filters. You can list all the metric filters or filter the results by log name, prefix, metric name, or metric namespace. The results are ASCII-sorted by filter name.</p>
     fn describe_metric_filters(
         &self,
         input: DescribeMetricFiltersRequest,
     ) -> RusotoFuture<DescribeMetricFiltersResponse, DescribeMetricFiltersError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeMetricFilters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMetricFiltersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeMetricFiltersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_metric_filters(input)
     }
-
-    /// <p>Returns a list of CloudWatch Logs Insights queries that are scheduled, executing, or have been executed recently in this account. You can request all queries, or limit it to queries of a specific log group or queries with a certain status.</p>
     fn describe_queries(
         &self,
         input: DescribeQueriesRequest,
     ) -> RusotoFuture<DescribeQueriesResponse, DescribeQueriesError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeQueries");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeQueriesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeQueriesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_queries(input)
     }
-
-    /// <p>Lists the resource policies in this account.</p>
     fn describe_resource_policies(
         &self,
         input: DescribeResourcePoliciesRequest,
     ) -> RusotoFuture<DescribeResourcePoliciesResponse, DescribeResourcePoliciesError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeResourcePolicies");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeResourcePoliciesResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeResourcePoliciesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_resource_policies(input)
     }
-
-    /// <p>Lists the subscription filters for the specified log group. You can list all the subscription filters or filter the results by prefix. The results are ASCII-sorted by filter name.</p>
     fn describe_subscription_filters(
         &self,
         input: DescribeSubscriptionFiltersRequest,
     ) -> RusotoFuture<DescribeSubscriptionFiltersResponse, DescribeSubscriptionFiltersError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DescribeSubscriptionFilters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSubscriptionFiltersResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSubscriptionFiltersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_subscription_filters(input)
     }
-
-    /// <p>Disassociates the associated AWS Key Management Service (AWS KMS) customer master key (CMK) from the specified log group.</p> <p>After the AWS KMS CMK is disassociated from the log group, AWS CloudWatch Logs stops encrypting newly ingested data for the log group. All previously ingested data remains encrypted, and AWS CloudWatch Logs requires permissions for the CMK whenever the encrypted data is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p>
     fn disassociate_kms_key(
         &self,
         input: DisassociateKmsKeyRequest,
     ) -> RusotoFuture<(), DisassociateKmsKeyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.DisassociateKmsKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisassociateKmsKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).disassociate_kms_key(input)
     }
-
-    /// <p>Lists log events from the specified log group. You can list all the log events or filter the results using a filter pattern, a time range, and the name of the log stream.</p> <p>By default, this operation returns as many log events as can fit in 1 MB (up to 10,000 log events), or all the events found within the time range that you specify. If the results include a token, then there are more log events available, and you can get additional results by specifying the token in a subsequent call.</p>
     fn filter_log_events(
         &self,
         input: FilterLogEventsRequest,
     ) -> RusotoFuture<FilterLogEventsResponse, FilterLogEventsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.FilterLogEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<FilterLogEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(FilterLogEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).filter_log_events(input)
     }
-
-    /// <p>Lists log events from the specified log stream. You can list all the log events or filter using a time range.</p> <p>By default, this operation returns as many log events as can fit in a response size of 1MB (up to 10,000 log events). You can get additional log events by specifying one of the tokens in a subsequent call.</p>
     fn get_log_events(
         &self,
         input: GetLogEventsRequest,
     ) -> RusotoFuture<GetLogEventsResponse, GetLogEventsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.GetLogEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLogEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLogEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_log_events(input)
     }
-
-    /// <p>Returns a list of the fields that are included in log events in the specified log group, along with the percentage of log events that contain each field. The search is limited to a time period that you specify.</p> <p>In the results, fields that start with @ are fields generated by CloudWatch Logs. For example, <code>@timestamp</code> is the timestamp of each log event.</p> <p>The response results are sorted by the frequency percentage, starting with the highest percentage.</p>
     fn get_log_group_fields(
         &self,
         input: GetLogGroupFieldsRequest,
     ) -> RusotoFuture<GetLogGroupFieldsResponse, GetLogGroupFieldsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.GetLogGroupFields");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLogGroupFieldsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLogGroupFieldsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_log_group_fields(input)
     }
-
-    /// <p>Retrieves all the fields and values of a single log event. All fields are retrieved, even if the original query that produced the <code>logRecordPointer</code> retrieved only a subset of fields. Fields are returned as field name/field value pairs.</p> <p>Additionally, the entire unparsed log event is returned within <code>@message</code>.</p>
     fn get_log_record(
         &self,
         input: GetLogRecordRequest,
     ) -> RusotoFuture<GetLogRecordResponse, GetLogRecordError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.GetLogRecord");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLogRecordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLogRecordError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_log_record(input)
     }
-
-    /// <p>Returns the results from the specified query.</p> <p>Only the fields requested in the query are returned, along with a <code>@ptr</code> field which is the identifier for the log record. You can use the value of <code>@ptr</code> in a operation to get the full log record.</p> <p> <code>GetQueryResults</code> does not start a query execution. To run a query, use .</p> <p>If the value of the <code>Status</code> field in the output is <code>Running</code>, this operation returns only partial results. If you see a value of <code>Scheduled</code> or <code>Running</code> for the status, you can retry the operation later to see the final results. </p>
     fn get_query_results(
         &self,
         input: GetQueryResultsRequest,
     ) -> RusotoFuture<GetQueryResultsResponse, GetQueryResultsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.GetQueryResults");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetQueryResultsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetQueryResultsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_query_results(input)
     }
-
-    /// <p>Lists the tags for the specified log group.</p>
     fn list_tags_log_group(
         &self,
         input: ListTagsLogGroupRequest,
     ) -> RusotoFuture<ListTagsLogGroupResponse, ListTagsLogGroupError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.ListTagsLogGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsLogGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsLogGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags_log_group(input)
     }
-
-    /// <p>Creates or updates a destination. A destination encapsulates a physical resource (such as an Amazon Kinesis stream) and enables you to subscribe to a real-time stream of log events for a different account, ingested using <a>PutLogEvents</a>. A destination can be an Amazon Kinesis stream, Amazon Kinesis Data Firehose strea, or an AWS Lambda function.</p> <p>Through an access policy, a destination controls what is written to it. By default, <code>PutDestination</code> does not set any access policy with the destination, which means a cross-account user cannot call <a>PutSubscriptionFilter</a> against this destination. To enable this, the destination owner must call <a>PutDestinationPolicy</a> after <code>PutDestination</code>.</p>
     fn put_destination(
         &self,
         input: PutDestinationRequest,
     ) -> RusotoFuture<PutDestinationResponse, PutDestinationError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutDestination");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutDestinationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutDestinationError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_destination(input)
     }
-
-    /// <p>Creates or updates an access policy associated with an existing destination. An access policy is an <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies_overview.html">IAM policy document</a> that is used to authorize claims to register a subscription filter against a given destination.</p>
     fn put_destination_policy(
         &self,
         input: PutDestinationPolicyRequest,
     ) -> RusotoFuture<(), PutDestinationPolicyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutDestinationPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutDestinationPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_destination_policy(input)
     }
-
-    /// <p>Uploads a batch of log events to the specified log stream.</p> <p>You must include the sequence token obtained from the response of the previous call. An upload in a newly created log stream does not require a sequence token. You can also get the sequence token using <a>DescribeLogStreams</a>. If you call <code>PutLogEvents</code> twice within a narrow time period using the same value for <code>sequenceToken</code>, both calls may be successful, or one may be rejected.</p> <p>The batch of events must satisfy the following constraints:</p> <ul> <li> <p>The maximum batch size is 1,048,576 bytes, and this size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p> </li> <li> <p>None of the log events in the batch can be more than 2 hours in the future.</p> </li> <li> <p>None of the log events in the batch can be older than 14 days or older than the retention period of the log group.</p> </li> <li> <p>The log events in the batch must be in chronological ordered by their timestamp. The timestamp is the time the event occurred, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. (In AWS Tools for PowerShell and the AWS SDK for .NET, the timestamp is specified in .NET format: yyyy-mm-ddThh:mm:ss. For example, 2017-09-15T13:45:30.) </p> </li> <li> <p>The maximum number of log events in a batch is 10,000.</p> </li> <li> <p>A batch of log events in a single request cannot span more than 24 hours. Otherwise, the operation fails.</p> </li> </ul> <p>If a call to PutLogEvents returns "UnrecognizedClientException" the most likely cause is an invalid AWS access key ID or secret key. </p>
     fn put_log_events(
         &self,
         input: PutLogEventsRequest,
     ) -> RusotoFuture<PutLogEventsResponse, PutLogEventsError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutLogEvents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutLogEventsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutLogEventsError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_log_events(input)
     }
-
-    /// <p>Creates or updates a metric filter and associates it with the specified log group. Metric filters allow you to configure rules to extract metric data from log events ingested through <a>PutLogEvents</a>.</p> <p>The maximum number of metric filters that can be associated with a log group is 100.</p>
     fn put_metric_filter(
         &self,
         input: PutMetricFilterRequest,
     ) -> RusotoFuture<(), PutMetricFilterError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutMetricFilter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutMetricFilterError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_metric_filter(input)
     }
-
-    /// <p>Creates or updates a resource policy allowing other AWS services to put log events to this account, such as Amazon Route 53. An account can have up to 10 resource policies per region.</p>
     fn put_resource_policy(
         &self,
         input: PutResourcePolicyRequest,
     ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutResourcePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutResourcePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutResourcePolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_resource_policy(input)
     }
-
-    /// <p>Sets the retention of the specified log group. A retention policy allows you to configure the number of days for which to retain log events in the specified log group.</p>
     fn put_retention_policy(
         &self,
         input: PutRetentionPolicyRequest,
     ) -> RusotoFuture<(), PutRetentionPolicyError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutRetentionPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutRetentionPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).put_retention_policy(input)
     }
-
-    /// <p>Creates or updates a subscription filter and associates it with the specified log group. Subscription filters allow you to subscribe to a real-time stream of log events ingested through <a>PutLogEvents</a> and have them delivered to a specific destination. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination that belongs to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> </ul> <p>There can only be one subscription filter associated with a log group. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group.</p>
     fn put_subscription_filter(
         &self,
         input: PutSubscriptionFilterRequest,
     ) -> RusotoFuture<(), PutSubscriptionFilterError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.PutSubscriptionFilter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutSubscriptionFilterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_subscription_filter(input)
     }
-
-    /// <p>Schedules a query of a log group using CloudWatch Logs Insights. You specify the log group and time range to query, and the query string to use.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/CWL_QuerySyntax.html">CloudWatch Logs Insights Query Syntax</a>.</p> <p>Queries time out after 15 minutes of execution. If your queries are timing out, reduce the time range being searched, or partition your query into a number of queries.</p>
     fn start_query(
         &self,
         input: StartQueryRequest,
     ) -> RusotoFuture<StartQueryResponse, StartQueryError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.StartQuery");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartQueryResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartQueryError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_query(input)
     }
-
-    /// <p>Stops a CloudWatch Logs Insights query that is in progress. If the query has already ended, the operation returns an error indicating that the specified query is not running.</p>
     fn stop_query(
         &self,
         input: StopQueryRequest,
     ) -> RusotoFuture<StopQueryResponse, StopQueryError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.StopQuery");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopQueryResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopQueryError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_query(input)
     }
-
-    /// <p>Adds or updates the specified tags for the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To remove tags, use <a>UntagLogGroup</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/log-group-tagging.html">Tag Log Groups in Amazon CloudWatch Logs</a> in the <i>Amazon CloudWatch Logs User Guide</i>.</p>
     fn tag_log_group(&self, input: TagLogGroupRequest) -> RusotoFuture<(), TagLogGroupError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.TagLogGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagLogGroupError::from_response(response))),
-                )
-            }
+        (**self).tag_log_group(input)
+    }
+    fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> RusotoFuture<TestMetricFilterResponse, TestMetricFilterError> {
+        (**self).test_metric_filter(input)
+    }
+    fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError> {
+        (**self).untag_log_group(input)
+    }
+}
+impl<T: CloudWatchLogs + ?Sized> CloudWatchLogs for Box<T> {
+    fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> RusotoFuture<(), AssociateKmsKeyError> {
+        (**self).associate_kms_key(input)
+    }
+    fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> RusotoFuture<(), CancelExportTaskError> {
+        (**self).cancel_export_task(input)
+    }
+    fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> RusotoFuture<CreateExportTaskResponse, CreateExportTaskError> {
+        (**self).create_export_task(input)
+    }
+    fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> RusotoFuture<(), CreateLogGroupError> {
+        (**self).create_log_group(input)
+    }
+    fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> RusotoFuture<(), CreateLogStreamError> {
+        (**self).create_log_stream(input)
+    }
+    fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> RusotoFuture<(), DeleteDestinationError> {
+        (**self).delete_destination(input)
+    }
+    fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> RusotoFuture<(), DeleteLogGroupError> {
+        (**self).delete_log_group(input)
+    }
+    fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> RusotoFuture<(), DeleteLogStreamError> {
+        (**self).delete_log_stream(input)
+    }
+    fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> RusotoFuture<(), DeleteMetricFilterError> {
+        (**self).delete_metric_filter(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<(), DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> RusotoFuture<(), DeleteRetentionPolicyError> {
+        (**self).delete_retention_policy(input)
+    }
+    fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), DeleteSubscriptionFilterError> {
+        (**self).delete_subscription_filter(input)
+    }
+    fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> RusotoFuture<DescribeDestinationsResponse, DescribeDestinationsError> {
+        (**self).describe_destinations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> RusotoFuture<DescribeLogGroupsResponse, DescribeLogGroupsError> {
+        (**self).describe_log_groups(input)
+    }
+    fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> RusotoFuture<DescribeLogStreamsResponse, DescribeLogStreamsError> {
+        (**self).describe_log_streams(input)
+    }
+    fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> RusotoFuture<DescribeMetricFiltersResponse, DescribeMetricFiltersError> {
+        (**self).describe_metric_filters(input)
+    }
+    fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> RusotoFuture<DescribeQueriesResponse, DescribeQueriesError> {
+        (**self).describe_queries(input)
+    }
+    fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> RusotoFuture<DescribeResourcePoliciesResponse, DescribeResourcePoliciesError> {
+        (**self).describe_resource_policies(input)
+    }
+    fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> RusotoFuture<DescribeSubscriptionFiltersResponse, DescribeSubscriptionFiltersError> {
+        (**self).describe_subscription_filters(input)
+    }
+    fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> RusotoFuture<(), DisassociateKmsKeyError> {
+        (**self).disassociate_kms_key(input)
+    }
+    fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> RusotoFuture<FilterLogEventsResponse, FilterLogEventsError> {
+        (**self).filter_log_events(input)
+    }
+    fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> RusotoFuture<GetLogEventsResponse, GetLogEventsError> {
+        (**self).get_log_events(input)
+    }
+    fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> RusotoFuture<GetLogGroupFieldsResponse, GetLogGroupFieldsError> {
+        (**self).get_log_group_fields(input)
+    }
+    fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> RusotoFuture<GetLogRecordResponse, GetLogRecordError> {
+        (**self).get_log_record(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> RusotoFuture<GetQueryResultsResponse, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> RusotoFuture<ListTagsLogGroupResponse, ListTagsLogGroupError> {
+        (**self).list_tags_log_group(input)
+    }
+    fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> RusotoFuture<PutDestinationResponse, PutDestinationError> {
+        (**self).put_destination(input)
+    }
+    fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> RusotoFuture<(), PutDestinationPolicyError> {
+        (**self).put_destination_policy(input)
+    }
+    fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> RusotoFuture<PutLogEventsResponse, PutLogEventsError> {
+        (**self).put_log_events(input)
+    }
+    fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> RusotoFuture<(), PutMetricFilterError> {
+        (**self).put_metric_filter(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> RusotoFuture<(), PutRetentionPolicyError> {
+        (**self).put_retention_policy(input)
+    }
+    fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), PutSubscriptionFilterError> {
+        (**self).put_subscription_filter(input)
+    }
+    fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> RusotoFuture<StartQueryResponse, StartQueryError> {
+        (**self).start_query(input)
+    }
+    fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> RusotoFuture<StopQueryResponse, StopQueryError> {
+        (**self).stop_query(input)
+    }
+    fn tag_log_group(&self, input: TagLogGroupRequest) -> RusotoFuture<(), TagLogGroupError> {
+        (**self).tag_log_group(input)
+    }
+    fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> RusotoFuture<TestMetricFilterResponse, TestMetricFilterError> {
+        (**self).test_metric_filter(input)
+    }
+    fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError> {
+        (**self).untag_log_group(input)
+    }
+}
+impl<T: CloudWatchLogs + ?Sized> CloudWatchLogs for ::std::sync::Arc<T> {
+    fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> RusotoFuture<(), AssociateKmsKeyError> {
+        (**self).associate_kms_key(input)
+    }
+    fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> RusotoFuture<(), CancelExportTaskError> {
+        (**self).cancel_export_task(input)
+    }
+    fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> RusotoFuture<CreateExportTaskResponse, CreateExportTaskError> {
+        (**self).create_export_task(input)
+    }
+    fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> RusotoFuture<(), CreateLogGroupError> {
+        (**self).create_log_group(input)
+    }
+    fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> RusotoFuture<(), CreateLogStreamError> {
+        (**self).create_log_stream(input)
+    }
+    fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> RusotoFuture<(), DeleteDestinationError> {
+        (**self).delete_destination(input)
+    }
+    fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> RusotoFuture<(), DeleteLogGroupError> {
+        (**self).delete_log_group(input)
+    }
+    fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> RusotoFuture<(), DeleteLogStreamError> {
+        (**self).delete_log_stream(input)
+    }
+    fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> RusotoFuture<(), DeleteMetricFilterError> {
+        (**self).delete_metric_filter(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<(), DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> RusotoFuture<(), DeleteRetentionPolicyError> {
+        (**self).delete_retention_policy(input)
+    }
+    fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), DeleteSubscriptionFilterError> {
+        (**self).delete_subscription_filter(input)
+    }
+    fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> RusotoFuture<DescribeDestinationsResponse, DescribeDestinationsError> {
+        (**self).describe_destinations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> RusotoFuture<DescribeLogGroupsResponse, DescribeLogGroupsError> {
+        (**self).describe_log_groups(input)
+    }
+    fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> RusotoFuture<DescribeLogStreamsResponse, DescribeLogStreamsError> {
+        (**self).describe_log_streams(input)
+    }
+    fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> RusotoFuture<DescribeMetricFiltersResponse, DescribeMetricFiltersError> {
+        (**self).describe_metric_filters(input)
+    }
+    fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> RusotoFuture<DescribeQueriesResponse, DescribeQueriesError> {
+        (**self).describe_queries(input)
+    }
+    fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> RusotoFuture<DescribeResourcePoliciesResponse, DescribeResourcePoliciesError> {
+        (**self).describe_resource_policies(input)
+    }
+    fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> RusotoFuture<DescribeSubscriptionFiltersResponse, DescribeSubscriptionFiltersError> {
+        (**self).describe_subscription_filters(input)
+    }
+    fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> RusotoFuture<(), DisassociateKmsKeyError> {
+        (**self).disassociate_kms_key(input)
+    }
+    fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> RusotoFuture<FilterLogEventsResponse, FilterLogEventsError> {
+        (**self).filter_log_events(input)
+    }
+    fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> RusotoFuture<GetLogEventsResponse, GetLogEventsError> {
+        (**self).get_log_events(input)
+    }
+    fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> RusotoFuture<GetLogGroupFieldsResponse, GetLogGroupFieldsError> {
+        (**self).get_log_group_fields(input)
+    }
+    fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> RusotoFuture<GetLogRecordResponse, GetLogRecordError> {
+        (**self).get_log_record(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> RusotoFuture<GetQueryResultsResponse, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> RusotoFuture<ListTagsLogGroupResponse, ListTagsLogGroupError> {
+        (**self).list_tags_log_group(input)
+    }
+    fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> RusotoFuture<PutDestinationResponse, PutDestinationError> {
+        (**self).put_destination(input)
+    }
+    fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> RusotoFuture<(), PutDestinationPolicyError> {
+        (**self).put_destination_policy(input)
+    }
+    fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> RusotoFuture<PutLogEventsResponse, PutLogEventsError> {
+        (**self).put_log_events(input)
+    }
+    fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> RusotoFuture<(), PutMetricFilterError> {
+        (**self).put_metric_filter(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> RusotoFuture<(), PutRetentionPolicyError> {
+        (**self).put_retention_policy(input)
+    }
+    fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), PutSubscriptionFilterError> {
+        (**self).put_subscription_filter(input)
+    }
+    fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> RusotoFuture<StartQueryResponse, StartQueryError> {
+        (**self).start_query(input)
+    }
+    fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> RusotoFuture<StopQueryResponse, StopQueryError> {
+        (**self).stop_query(input)
+    }
+    fn tag_log_group(&self, input: TagLogGroupRequest) -> RusotoFuture<(), TagLogGroupError> {
+        (**self).tag_log_group(input)
+    }
+    fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> RusotoFuture<TestMetricFilterResponse, TestMetricFilterError> {
+        (**self).test_metric_filter(input)
+    }
+    fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError> {
+        (**self).untag_log_group(input)
+    }
+}
+/// A client for the Amazon CloudWatch Logs API.
+#[derive(Clone)]
+pub struct CloudWatchLogsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl CloudWatchLogsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudWatchLogsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudWatchLogsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
+        CloudWatchLogsClient { client, region }
+    }
+}
+
+impl CloudWatchLogs for CloudWatchLogsClient {
+    /// <p>Associates the specified AWS Key Management Service (AWS KMS) customer master key (CMK) with the specified log group.</p> <p>Associating an AWS KMS CMK with a log group overrides any existing associations between the log group and a CMK. After a CMK is associated with a log group, all newly ingested data for the log group is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p> <p>If you attempt to associate a CMK with a log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
+    fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> RusotoFuture<(), AssociateKmsKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("AssociateKmsKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.AssociateKmsKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateKmsKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels the specified export task.</p> <p>The task must be in the <code>PENDING</code> or <code>RUNNING</code> state.</p>
+    fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> RusotoFuture<(), CancelExportTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CancelExportTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.CancelExportTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelExportTaskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an export task, which allows you to efficiently export data from a log group to an Amazon S3 bucket.</p> <p>This is an asynchronous call. If all the required information is provided, this operation initiates an export task and responds with the ID of the task. After the task has started, you can use <a>DescribeExportTasks</a> to get the status of the export task. Each account can only have one active (<code>RUNNING</code> or <code>PENDING</code>) export task at a time. To cancel an export task, use <a>CancelExportTask</a>.</p> <p>You can export logs from multiple log groups or multiple time ranges to the same S3 bucket. To separate out log data for each export task, you can specify a prefix to be used as the Amazon S3 key prefix for all exported objects.</p> <p>Exporting to S3 buckets that are encrypted with AES-256 is supported. Exporting to S3 buckets encrypted with SSE-KMS is not supported. </p>
+    fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> RusotoFuture<CreateExportTaskResponse, CreateExportTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateExportTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.CreateExportTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateExportTaskResponse, _>(
+                    response,
+                    "CreateExportTask",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateExportTaskError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a log group with the specified name.</p> <p>You can create up to 5000 log groups per account.</p> <p>You must use the following guidelines when naming a log group:</p> <ul> <li> <p>Log group names must be unique within a region for an AWS account.</p> </li> <li> <p>Log group names can be between 1 and 512 characters long.</p> </li> <li> <p>Log group names consist of the following characters: a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), '/' (forward slash), and '.' (period).</p> </li> </ul> <p>If you associate a AWS Key Management Service (AWS KMS) customer master key (CMK) with the log group, ingested data is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>If you attempt to associate a CMK with the log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
+    fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> RusotoFuture<(), CreateLogGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateLogGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.CreateLogGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLogGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a log stream for the specified log group.</p> <p>There is no limit on the number of log streams that you can create for a log group.</p> <p>You must use the following guidelines when naming a log stream:</p> <ul> <li> <p>Log stream names must be unique within the log group.</p> </li> <li> <p>Log stream names can be between 1 and 512 characters long.</p> </li> <li> <p>The &#39;:&#39; (colon) and &#39;*&#39; (asterisk) characters are not allowed.</p> </li> </ul></p>
+    fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> RusotoFuture<(), CreateLogStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("CreateLogStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.CreateLogStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLogStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified destination, and eventually disables all the subscription filters that publish to it. This operation does not delete the physical resource encapsulated by the destination.</p>
+    fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> RusotoFuture<(), DeleteDestinationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteDestination");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteDestination");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDestinationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified log group and permanently deletes all the archived log events associated with the log group.</p>
+    fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> RusotoFuture<(), DeleteLogGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteLogGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteLogGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLogGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified log stream and permanently deletes all the archived log events associated with the log stream.</p>
+    fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> RusotoFuture<(), DeleteLogStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteLogStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteLogStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLogStreamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified metric filter.</p>
+    fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> RusotoFuture<(), DeleteMetricFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteMetricFilter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteMetricFilter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMetricFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a resource policy from this account. This revokes the access of the identities in that policy to put log events to this account.</p>
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<(), DeleteResourcePolicyError> {
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteResourcePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteResourcePolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified retention policy.</p> <p>Log events do not expire if they belong to log groups without a retention policy.</p>
+    fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> RusotoFuture<(), DeleteRetentionPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteRetentionPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteRetentionPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRetentionPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified subscription filter.</p>
+    fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), DeleteSubscriptionFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DeleteSubscriptionFilter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DeleteSubscriptionFilter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSubscriptionFilterError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all your destinations. The results are ASCII-sorted by destination name.</p>
+    fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> RusotoFuture<DescribeDestinationsResponse, DescribeDestinationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeDestinations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeDestinations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDestinationsResponse, _>(
+                    response,
+                    "DescribeDestinations",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeDestinationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the specified export tasks. You can list all your export tasks or filter the results based on task ID or task status.</p>
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeExportTasks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeExportTasksResponse, _>(
+                    response,
+                    "DescribeExportTasks",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeExportTasksError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the specified log groups. You can list all your log groups or filter the results by prefix. The results are ASCII-sorted by log group name.</p>
+    fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> RusotoFuture<DescribeLogGroupsResponse, DescribeLogGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeLogGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeLogGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLogGroupsResponse, _>(
+                    response,
+                    "DescribeLogGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLogGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the log streams for the specified log group. You can list all the log streams or filter the results by prefix. You can also control how the results are ordered.</p> <p>This operation has a limit of five transactions per second, after which transactions are throttled.</p>
+    fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> RusotoFuture<DescribeLogStreamsResponse, DescribeLogStreamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeLogStreams");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeLogStreams");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLogStreamsResponse, _>(
+                    response,
+                    "DescribeLogStreams",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLogStreamsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the specified metric filters. You can list all the metric filters or filter the results by log name, prefix, metric name, or metric namespace. The results are ASCII-sorted by filter name.</p>
+    fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> RusotoFuture<DescribeMetricFiltersResponse, DescribeMetricFiltersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeMetricFilters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeMetricFilters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMetricFiltersResponse, _>(
+                    response,
+                    "DescribeMetricFilters",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeMetricFiltersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of CloudWatch Logs Insights queries that are scheduled, executing, or have been executed recently in this account. You can request all queries, or limit it to queries of a specific log group or queries with a certain status.</p>
+    fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> RusotoFuture<DescribeQueriesResponse, DescribeQueriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeQueries");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeQueries");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeQueriesResponse, _>(
+                    response,
+                    "DescribeQueries",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeQueriesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the resource policies in this account.</p>
+    fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> RusotoFuture<DescribeResourcePoliciesResponse, DescribeResourcePoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeResourcePolicies");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeResourcePolicies");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeResourcePoliciesResponse, _>(
+                    response,
+                    "DescribeResourcePolicies",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeResourcePoliciesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the subscription filters for the specified log group. You can list all the subscription filters or filter the results by prefix. The results are ASCII-sorted by filter name.</p>
+    fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> RusotoFuture<DescribeSubscriptionFiltersResponse, DescribeSubscriptionFiltersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DescribeSubscriptionFilters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DescribeSubscriptionFilters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSubscriptionFiltersResponse, _>(
+                    response,
+                    "DescribeSubscriptionFilters",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSubscriptionFiltersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the associated AWS Key Management Service (AWS KMS) customer master key (CMK) from the specified log group.</p> <p>After the AWS KMS CMK is disassociated from the log group, AWS CloudWatch Logs stops encrypting newly ingested data for the log group. All previously ingested data remains encrypted, and AWS CloudWatch Logs requires permissions for the CMK whenever the encrypted data is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p>
+    fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> RusotoFuture<(), DisassociateKmsKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("DisassociateKmsKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.DisassociateKmsKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisassociateKmsKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists log events from the specified log group. You can list all the log events or filter the results using a filter pattern, a time range, and the name of the log stream.</p> <p>By default, this operation returns as many log events as can fit in 1 MB (up to 10,000 log events), or all the events found within the time range that you specify. If the results include a token, then there are more log events available, and you can get additional results by specifying the token in a subsequent call.</p>
+    fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> RusotoFuture<FilterLogEventsResponse, FilterLogEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("FilterLogEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.FilterLogEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<FilterLogEventsResponse, _>(
+                    response,
+                    "FilterLogEvents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(FilterLogEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists log events from the specified log stream. You can list all the log events or filter using a time range.</p> <p>By default, this operation returns as many log events as can fit in a response size of 1MB (up to 10,000 log events). You can get additional log events by specifying one of the tokens in a subsequent call.</p>
+    fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> RusotoFuture<GetLogEventsResponse, GetLogEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.GetLogEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLogEventsResponse, _>(
+                    response,
+                    "GetLogEvents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLogEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of the fields that are included in log events in the specified log group, along with the percentage of log events that contain each field. The search is limited to a time period that you specify.</p> <p>In the results, fields that start with @ are fields generated by CloudWatch Logs. For example, <code>@timestamp</code> is the timestamp of each log event.</p> <p>The response results are sorted by the frequency percentage, starting with the highest percentage.</p>
+    fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> RusotoFuture<GetLogGroupFieldsResponse, GetLogGroupFieldsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogGroupFields");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.GetLogGroupFields");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLogGroupFieldsResponse, _>(
+                    response,
+                    "GetLogGroupFields",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLogGroupFieldsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves all the fields and values of a single log event. All fields are retrieved, even if the original query that produced the <code>logRecordPointer</code> retrieved only a subset of fields. Fields are returned as field name/field value pairs.</p> <p>Additionally, the entire unparsed log event is returned within <code>@message</code>.</p>
+    fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> RusotoFuture<GetLogRecordResponse, GetLogRecordError> {
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetLogRecord");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.GetLogRecord");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLogRecordResponse, _>(
+                    response,
+                    "GetLogRecord",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLogRecordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the results from the specified query.</p> <p>Only the fields requested in the query are returned, along with a <code>@ptr</code> field which is the identifier for the log record. You can use the value of <code>@ptr</code> in a operation to get the full log record.</p> <p> <code>GetQueryResults</code> does not start a query execution. To run a query, use .</p> <p>If the value of the <code>Status</code> field in the output is <code>Running</code>, this operation returns only partial results. If you see a value of <code>Scheduled</code> or <code>Running</code> for the status, you can retry the operation later to see the final results. </p>
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> RusotoFuture<GetQueryResultsResponse, GetQueryResultsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("GetQueryResults");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.GetQueryResults");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetQueryResultsResponse, _>(
+                    response,
+                    "GetQueryResults",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetQueryResultsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the tags for the specified log group.</p>
+    fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> RusotoFuture<ListTagsLogGroupResponse, ListTagsLogGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("ListTagsLogGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.ListTagsLogGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsLogGroupResponse, _>(
+                    response,
+                    "ListTagsLogGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsLogGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates a destination. A destination encapsulates a physical resource (such as an Amazon Kinesis stream) and enables you to subscribe to a real-time stream of log events for a different account, ingested using <a>PutLogEvents</a>. A destination can be an Amazon Kinesis stream, Amazon Kinesis Data Firehose strea, or an AWS Lambda function.</p> <p>Through an access policy, a destination controls what is written to it. By default, <code>PutDestination</code> does not set any access policy with the destination, which means a cross-account user cannot call <a>PutSubscriptionFilter</a> against this destination. To enable this, the destination owner must call <a>PutDestinationPolicy</a> after <code>PutDestination</code>.</p>
+    fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> RusotoFuture<PutDestinationResponse, PutDestinationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutDestination");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutDestination");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutDestinationResponse, _>(
+                    response,
+                    "PutDestination",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutDestinationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates an access policy associated with an existing destination. An access policy is an <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies_overview.html">IAM policy document</a> that is used to authorize claims to register a subscription filter against a given destination.</p>
+    fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> RusotoFuture<(), PutDestinationPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutDestinationPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutDestinationPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutDestinationPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Uploads a batch of log events to the specified log stream.</p> <p>You must include the sequence token obtained from the response of the previous call. An upload in a newly created log stream does not require a sequence token. You can also get the sequence token using <a>DescribeLogStreams</a>. If you call <code>PutLogEvents</code> twice within a narrow time period using the same value for <code>sequenceToken</code>, both calls may be successful, or one may be rejected.</p> <p>The batch of events must satisfy the following constraints:</p> <ul> <li> <p>The maximum batch size is 1,048,576 bytes, and this size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p> </li> <li> <p>None of the log events in the batch can be more than 2 hours in the future.</p> </li> <li> <p>None of the log events in the batch can be older than 14 days or older than the retention period of the log group.</p> </li> <li> <p>The log events in the batch must be in chronological ordered by their timestamp. The timestamp is the time the event occurred, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. (In AWS Tools for PowerShell and the AWS SDK for .NET, the timestamp is specified in .NET format: yyyy-mm-ddThh:mm:ss. For example, 2017-09-15T13:45:30.) </p> </li> <li> <p>The maximum number of log events in a batch is 10,000.</p> </li> <li> <p>A batch of log events in a single request cannot span more than 24 hours. Otherwise, the operation fails.</p> </li> </ul> <p>If a call to PutLogEvents returns "UnrecognizedClientException" the most likely cause is an invalid AWS access key ID or secret key. </p>
+    fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> RusotoFuture<PutLogEventsResponse, PutLogEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutLogEvents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutLogEvents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutLogEventsResponse, _>(
+                    response,
+                    "PutLogEvents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutLogEventsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates a metric filter and associates it with the specified log group. Metric filters allow you to configure rules to extract metric data from log events ingested through <a>PutLogEvents</a>.</p> <p>The maximum number of metric filters that can be associated with a log group is 100.</p>
+    fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> RusotoFuture<(), PutMetricFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutMetricFilter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutMetricFilter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutMetricFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates a resource policy allowing other AWS services to put log events to this account, such as Amazon Route 53. An account can have up to 10 resource policies per region.</p>
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutResourcePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutResourcePolicyResponse, _>(
+                    response,
+                    "PutResourcePolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutResourcePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets the retention of the specified log group. A retention policy allows you to configure the number of days for which to retain log events in the specified log group.</p>
+    fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> RusotoFuture<(), PutRetentionPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutRetentionPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutRetentionPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutRetentionPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates or updates a subscription filter and associates it with the specified log group. Subscription filters allow you to subscribe to a real-time stream of log events ingested through <a>PutLogEvents</a> and have them delivered to a specific destination. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination that belongs to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> </ul> <p>There can only be one subscription filter associated with a log group. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group.</p>
+    fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), PutSubscriptionFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("PutSubscriptionFilter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.PutSubscriptionFilter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutSubscriptionFilterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Schedules a query of a log group using CloudWatch Logs Insights. You specify the log group and time range to query, and the query string to use.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/CWL_QuerySyntax.html">CloudWatch Logs Insights Query Syntax</a>.</p> <p>Queries time out after 15 minutes of execution. If your queries are timing out, reduce the time range being searched, or partition your query into a number of queries.</p>
+    fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> RusotoFuture<StartQueryResponse, StartQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("StartQuery");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.StartQuery");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartQueryResponse, _>(response, "StartQuery")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartQueryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a CloudWatch Logs Insights query that is in progress. If the query has already ended, the operation returns an error indicating that the specified query is not running.</p>
+    fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> RusotoFuture<StopQueryResponse, StopQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("StopQuery");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.StopQuery");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopQueryResponse, _>(response, "StopQuery")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopQueryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds or updates the specified tags for the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To remove tags, use <a>UntagLogGroup</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/log-group-tagging.html">Tag Log Groups in Amazon CloudWatch Logs</a> in the <i>Amazon CloudWatch Logs User Guide</i>.</p>
+    fn tag_log_group(&self, input: TagLogGroupRequest) -> RusotoFuture<(), TagLogGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("TagLogGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.TagLogGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagLogGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Tests the filter pattern of a metric filter against a sample of log event messages. You can use this operation to validate the correctness of a metric filter pattern.</p>
+    fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> RusotoFuture<TestMetricFilterResponse, TestMetricFilterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("TestMetricFilter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.TestMetricFilter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TestMetricFilterResponse, _>(
+                    response,
+                    "TestMetricFilter",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestMetricFilterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To add tags, use <a>UntagLogGroup</a>.</p>
+    fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
+        request.set_operation("UntagLogGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Logs_20140328.UntagLogGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagLogGroupError::from_response(response))),
+                )
+            }
         })
     }
-
-    /// <p>Tests the filter pattern of a metric filter against a sample of log event messages. You can use this operation to validate the correctness of a metric filter pattern.</p>
+}
+
+/// A mock of the Amazon CloudWatch Logs API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockCloudWatchLogs {
+    associate_kms_key: Option<
+        Box<
+            dyn Fn(
+                    AssociateKmsKeyRequest,
+                ) -> ::std::result::Result<(), RusotoError<AssociateKmsKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_export_task: Option<
+        Box<
+            dyn Fn(
+                    CancelExportTaskRequest,
+                ) -> ::std::result::Result<(), RusotoError<CancelExportTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_export_task: Option<
+        Box<
+            dyn Fn(
+                    CreateExportTaskRequest,
+                ) -> ::std::result::Result<
+                    CreateExportTaskResponse,
+                    RusotoError<CreateExportTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_log_group: Option<
+        Box<
+            dyn Fn(
+                    CreateLogGroupRequest,
+                ) -> ::std::result::Result<(), RusotoError<CreateLogGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_log_stream: Option<
+        Box<
+            dyn Fn(
+                    CreateLogStreamRequest,
+                ) -> ::std::result::Result<(), RusotoError<CreateLogStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_destination: Option<
+        Box<
+            dyn Fn(
+                    DeleteDestinationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteDestinationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_log_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteLogGroupRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteLogGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_log_stream: Option<
+        Box<
+            dyn Fn(
+                    DeleteLogStreamRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteLogStreamError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_metric_filter: Option<
+        Box<
+            dyn Fn(
+                    DeleteMetricFilterRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteMetricFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourcePolicyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteResourcePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_retention_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteRetentionPolicyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteRetentionPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_subscription_filter: Option<
+        Box<
+            dyn Fn(
+                    DeleteSubscriptionFilterRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteSubscriptionFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_destinations: Option<
+        Box<
+            dyn Fn(
+                    DescribeDestinationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDestinationsResponse,
+                    RusotoError<DescribeDestinationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_export_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeExportTasksRequest,
+                ) -> ::std::result::Result<
+                    DescribeExportTasksResponse,
+                    RusotoError<DescribeExportTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_log_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeLogGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeLogGroupsResponse,
+                    RusotoError<DescribeLogGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_log_streams: Option<
+        Box<
+            dyn Fn(
+                    DescribeLogStreamsRequest,
+                ) -> ::std::result::Result<
+                    DescribeLogStreamsResponse,
+                    RusotoError<DescribeLogStreamsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_metric_filters: Option<
+        Box<
+            dyn Fn(
+                    DescribeMetricFiltersRequest,
+                ) -> ::std::result::Result<
+                    DescribeMetricFiltersResponse,
+                    RusotoError<DescribeMetricFiltersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_queries: Option<
+        Box<
+            dyn Fn(
+                    DescribeQueriesRequest,
+                ) -> ::std::result::Result<
+                    DescribeQueriesResponse,
+                    RusotoError<DescribeQueriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_resource_policies: Option<
+        Box<
+            dyn Fn(
+                    DescribeResourcePoliciesRequest,
+                ) -> ::std::result::Result<
+                    DescribeResourcePoliciesResponse,
+                    RusotoError<DescribeResourcePoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_subscription_filters: Option<
+        Box<
+            dyn Fn(
+                    DescribeSubscriptionFiltersRequest,
+                ) -> ::std::result::Result<
+                    DescribeSubscriptionFiltersResponse,
+                    RusotoError<DescribeSubscriptionFiltersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_kms_key: Option<
+        Box<
+            dyn Fn(
+                    DisassociateKmsKeyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisassociateKmsKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    filter_log_events: Option<
+        Box<
+            dyn Fn(
+                    FilterLogEventsRequest,
+                ) -> ::std::result::Result<
+                    FilterLogEventsResponse,
+                    RusotoError<FilterLogEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_log_events: Option<
+        Box<
+            dyn Fn(
+                    GetLogEventsRequest,
+                )
+                    -> ::std::result::Result<GetLogEventsResponse, RusotoError<GetLogEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_log_group_fields: Option<
+        Box<
+            dyn Fn(
+                    GetLogGroupFieldsRequest,
+                ) -> ::std::result::Result<
+                    GetLogGroupFieldsResponse,
+                    RusotoError<GetLogGroupFieldsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_log_record: Option<
+        Box<
+            dyn Fn(
+                    GetLogRecordRequest,
+                )
+                    -> ::std::result::Result<GetLogRecordResponse, RusotoError<GetLogRecordError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_query_results: Option<
+        Box<
+            dyn Fn(
+                    GetQueryResultsRequest,
+                ) -> ::std::result::Result<
+                    GetQueryResultsResponse,
+                    RusotoError<GetQueryResultsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_log_group: Option<
+        Box<
+            dyn Fn(
+                    ListTagsLogGroupRequest,
+                ) -> ::std::result::Result<
+                    ListTagsLogGroupResponse,
+                    RusotoError<ListTagsLogGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_destination: Option<
+        Box<
+            dyn Fn(
+                    PutDestinationRequest,
+                ) -> ::std::result::Result<
+                    PutDestinationResponse,
+                    RusotoError<PutDestinationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_destination_policy: Option<
+        Box<
+            dyn Fn(
+                    PutDestinationPolicyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutDestinationPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_log_events: Option<
+        Box<
+            dyn Fn(
+                    PutLogEventsRequest,
+                )
+                    -> ::std::result::Result<PutLogEventsResponse, RusotoError<PutLogEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_metric_filter: Option<
+        Box<
+            dyn Fn(
+                    PutMetricFilterRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutMetricFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    PutResourcePolicyRequest,
+                ) -> ::std::result::Result<
+                    PutResourcePolicyResponse,
+                    RusotoError<PutResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_retention_policy: Option<
+        Box<
+            dyn Fn(
+                    PutRetentionPolicyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutRetentionPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_subscription_filter: Option<
+        Box<
+            dyn Fn(
+                    PutSubscriptionFilterRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutSubscriptionFilterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_query: Option<
+        Box<
+            dyn Fn(
+                    StartQueryRequest,
+                )
+                    -> ::std::result::Result<StartQueryResponse, RusotoError<StartQueryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_query: Option<
+        Box<
+            dyn Fn(
+                    StopQueryRequest,
+                )
+                    -> ::std::result::Result<StopQueryResponse, RusotoError<StopQueryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_log_group: Option<
+        Box<
+            dyn Fn(TagLogGroupRequest) -> ::std::result::Result<(), RusotoError<TagLogGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    test_metric_filter: Option<
+        Box<
+            dyn Fn(
+                    TestMetricFilterRequest,
+                ) -> ::std::result::Result<
+                    TestMetricFilterResponse,
+                    RusotoError<TestMetricFilterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_log_group: Option<
+        Box<
+            dyn Fn(
+                    UntagLogGroupRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagLogGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockCloudWatchLogs {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockCloudWatchLogs {
+        Default::default()
+    }
+
+    /// Answers `associate_kms_key` with what `handler` returns for its input.
+    pub fn with_associate_kms_key<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                AssociateKmsKeyRequest,
+            ) -> ::std::result::Result<(), RusotoError<AssociateKmsKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_kms_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_export_task` with what `handler` returns for its input.
+    pub fn with_cancel_export_task<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                CancelExportTaskRequest,
+            ) -> ::std::result::Result<(), RusotoError<CancelExportTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_export_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_export_task` with what `handler` returns for its input.
+    pub fn with_create_export_task<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                CreateExportTaskRequest,
+            ) -> ::std::result::Result<
+                CreateExportTaskResponse,
+                RusotoError<CreateExportTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_export_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_log_group` with what `handler` returns for its input.
+    pub fn with_create_log_group<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(CreateLogGroupRequest) -> ::std::result::Result<(), RusotoError<CreateLogGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_log_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_log_stream` with what `handler` returns for its input.
+    pub fn with_create_log_stream<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                CreateLogStreamRequest,
+            ) -> ::std::result::Result<(), RusotoError<CreateLogStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_log_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_destination` with what `handler` returns for its input.
+    pub fn with_delete_destination<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DeleteDestinationRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteDestinationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_destination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_log_group` with what `handler` returns for its input.
+    pub fn with_delete_log_group<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(DeleteLogGroupRequest) -> ::std::result::Result<(), RusotoError<DeleteLogGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_log_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_log_stream` with what `handler` returns for its input.
+    pub fn with_delete_log_stream<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DeleteLogStreamRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteLogStreamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_log_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_metric_filter` with what `handler` returns for its input.
+    pub fn with_delete_metric_filter<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DeleteMetricFilterRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteMetricFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_metric_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_policy` with what `handler` returns for its input.
+    pub fn with_delete_resource_policy<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DeleteResourcePolicyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteResourcePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_retention_policy` with what `handler` returns for its input.
+    pub fn with_delete_retention_policy<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DeleteRetentionPolicyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteRetentionPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_retention_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_subscription_filter` with what `handler` returns for its input.
+    pub fn with_delete_subscription_filter<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DeleteSubscriptionFilterRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteSubscriptionFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_subscription_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_destinations` with what `handler` returns for its input.
+    pub fn with_describe_destinations<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeDestinationsRequest,
+            ) -> ::std::result::Result<
+                DescribeDestinationsResponse,
+                RusotoError<DescribeDestinationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_destinations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_export_tasks` with what `handler` returns for its input.
+    pub fn with_describe_export_tasks<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeExportTasksRequest,
+            ) -> ::std::result::Result<
+                DescribeExportTasksResponse,
+                RusotoError<DescribeExportTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_export_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_log_groups` with what `handler` returns for its input.
+    pub fn with_describe_log_groups<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeLogGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribeLogGroupsResponse,
+                RusotoError<DescribeLogGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_log_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_log_streams` with what `handler` returns for its input.
+    pub fn with_describe_log_streams<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeLogStreamsRequest,
+            ) -> ::std::result::Result<
+                DescribeLogStreamsResponse,
+                RusotoError<DescribeLogStreamsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_log_streams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_metric_filters` with what `handler` returns for its input.
+    pub fn with_describe_metric_filters<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeMetricFiltersRequest,
+            ) -> ::std::result::Result<
+                DescribeMetricFiltersResponse,
+                RusotoError<DescribeMetricFiltersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_metric_filters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_queries` with what `handler` returns for its input.
+    pub fn with_describe_queries<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeQueriesRequest,
+            )
+                -> ::std::result::Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_queries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_resource_policies` with what `handler` returns for its input.
+    pub fn with_describe_resource_policies<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeResourcePoliciesRequest,
+            ) -> ::std::result::Result<
+                DescribeResourcePoliciesResponse,
+                RusotoError<DescribeResourcePoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_resource_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_subscription_filters` with what `handler` returns for its input.
+    pub fn with_describe_subscription_filters<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DescribeSubscriptionFiltersRequest,
+            ) -> ::std::result::Result<
+                DescribeSubscriptionFiltersResponse,
+                RusotoError<DescribeSubscriptionFiltersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_subscription_filters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_kms_key` with what `handler` returns for its input.
+    pub fn with_disassociate_kms_key<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                DisassociateKmsKeyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DisassociateKmsKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_kms_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `filter_log_events` with what `handler` returns for its input.
+    pub fn with_filter_log_events<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                FilterLogEventsRequest,
+            )
+                -> ::std::result::Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.filter_log_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_log_events` with what `handler` returns for its input.
+    pub fn with_get_log_events<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                GetLogEventsRequest,
+            )
+                -> ::std::result::Result<GetLogEventsResponse, RusotoError<GetLogEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_log_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_log_group_fields` with what `handler` returns for its input.
+    pub fn with_get_log_group_fields<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                GetLogGroupFieldsRequest,
+            ) -> ::std::result::Result<
+                GetLogGroupFieldsResponse,
+                RusotoError<GetLogGroupFieldsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_log_group_fields = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_log_record` with what `handler` returns for its input.
+    pub fn with_get_log_record<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                GetLogRecordRequest,
+            )
+                -> ::std::result::Result<GetLogRecordResponse, RusotoError<GetLogRecordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_log_record = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_query_results` with what `handler` returns for its input.
+    pub fn with_get_query_results<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                GetQueryResultsRequest,
+            )
+                -> ::std::result::Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_query_results = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_log_group` with what `handler` returns for its input.
+    pub fn with_list_tags_log_group<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                ListTagsLogGroupRequest,
+            ) -> ::std::result::Result<
+                ListTagsLogGroupResponse,
+                RusotoError<ListTagsLogGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_log_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_destination` with what `handler` returns for its input.
+    pub fn with_put_destination<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutDestinationRequest,
+            )
+                -> ::std::result::Result<PutDestinationResponse, RusotoError<PutDestinationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_destination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_destination_policy` with what `handler` returns for its input.
+    pub fn with_put_destination_policy<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutDestinationPolicyRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutDestinationPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_destination_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_log_events` with what `handler` returns for its input.
+    pub fn with_put_log_events<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutLogEventsRequest,
+            )
+                -> ::std::result::Result<PutLogEventsResponse, RusotoError<PutLogEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_log_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_metric_filter` with what `handler` returns for its input.
+    pub fn with_put_metric_filter<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutMetricFilterRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutMetricFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_metric_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_resource_policy` with what `handler` returns for its input.
+    pub fn with_put_resource_policy<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutResourcePolicyRequest,
+            ) -> ::std::result::Result<
+                PutResourcePolicyResponse,
+                RusotoError<PutResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_retention_policy` with what `handler` returns for its input.
+    pub fn with_put_retention_policy<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutRetentionPolicyRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutRetentionPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_retention_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_subscription_filter` with what `handler` returns for its input.
+    pub fn with_put_subscription_filter<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                PutSubscriptionFilterRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutSubscriptionFilterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_subscription_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_query` with what `handler` returns for its input.
+    pub fn with_start_query<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                StartQueryRequest,
+            )
+                -> ::std::result::Result<StartQueryResponse, RusotoError<StartQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_query` with what `handler` returns for its input.
+    pub fn with_stop_query<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                StopQueryRequest,
+            ) -> ::std::result::Result<StopQueryResponse, RusotoError<StopQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_log_group` with what `handler` returns for its input.
+    pub fn with_tag_log_group<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(TagLogGroupRequest) -> ::std::result::Result<(), RusotoError<TagLogGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_log_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_metric_filter` with what `handler` returns for its input.
+    pub fn with_test_metric_filter<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(
+                TestMetricFilterRequest,
+            ) -> ::std::result::Result<
+                TestMetricFilterResponse,
+                RusotoError<TestMetricFilterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_metric_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_log_group` with what `handler` returns for its input.
+    pub fn with_untag_log_group<F>(mut self, handler: F) -> MockCloudWatchLogs
+    where
+        F: Fn(UntagLogGroupRequest) -> ::std::result::Result<(), RusotoError<UntagLogGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_log_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl CloudWatchLogs for MockCloudWatchLogs {
+    fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> RusotoFuture<(), AssociateKmsKeyError> {
+        match self.associate_kms_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for associate_kms_key"),
+        }
+    }
+    fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> RusotoFuture<(), CancelExportTaskError> {
+        match self.cancel_export_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for cancel_export_task"),
+        }
+    }
+    fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> RusotoFuture<CreateExportTaskResponse, CreateExportTaskError> {
+        match self.create_export_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for create_export_task"),
+        }
+    }
+    fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> RusotoFuture<(), CreateLogGroupError> {
+        match self.create_log_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for create_log_group"),
+        }
+    }
+    fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> RusotoFuture<(), CreateLogStreamError> {
+        match self.create_log_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for create_log_stream"),
+        }
+    }
+    fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> RusotoFuture<(), DeleteDestinationError> {
+        match self.delete_destination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_destination"),
+        }
+    }
+    fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> RusotoFuture<(), DeleteLogGroupError> {
+        match self.delete_log_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_log_group"),
+        }
+    }
+    fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> RusotoFuture<(), DeleteLogStreamError> {
+        match self.delete_log_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_log_stream"),
+        }
+    }
+    fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> RusotoFuture<(), DeleteMetricFilterError> {
+        match self.delete_metric_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_metric_filter"),
+        }
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<(), DeleteResourcePolicyError> {
+        match self.delete_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_resource_policy"),
+        }
+    }
+    fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> RusotoFuture<(), DeleteRetentionPolicyError> {
+        match self.delete_retention_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_retention_policy"),
+        }
+    }
+    fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), DeleteSubscriptionFilterError> {
+        match self.delete_subscription_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for delete_subscription_filter"),
+        }
+    }
+    fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> RusotoFuture<DescribeDestinationsResponse, DescribeDestinationsError> {
+        match self.describe_destinations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_destinations"),
+        }
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        match self.describe_export_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_export_tasks"),
+        }
+    }
+    fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> RusotoFuture<DescribeLogGroupsResponse, DescribeLogGroupsError> {
+        match self.describe_log_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_log_groups"),
+        }
+    }
+    fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> RusotoFuture<DescribeLogStreamsResponse, DescribeLogStreamsError> {
+        match self.describe_log_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_log_streams"),
+        }
+    }
+    fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> RusotoFuture<DescribeMetricFiltersResponse, DescribeMetricFiltersError> {
+        match self.describe_metric_filters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_metric_filters"),
+        }
+    }
+    fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> RusotoFuture<DescribeQueriesResponse, DescribeQueriesError> {
+        match self.describe_queries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_queries"),
+        }
+    }
+    fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> RusotoFuture<DescribeResourcePoliciesResponse, DescribeResourcePoliciesError> {
+        match self.describe_resource_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_resource_policies"),
+        }
+    }
+    fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> RusotoFuture<DescribeSubscriptionFiltersResponse, DescribeSubscriptionFiltersError> {
+        match self.describe_subscription_filters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for describe_subscription_filters"),
+        }
+    }
+    fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> RusotoFuture<(), DisassociateKmsKeyError> {
+        match self.disassociate_kms_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for disassociate_kms_key"),
+        }
+    }
+    fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> RusotoFuture<FilterLogEventsResponse, FilterLogEventsError> {
+        match self.filter_log_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for filter_log_events"),
+        }
+    }
+    fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> RusotoFuture<GetLogEventsResponse, GetLogEventsError> {
+        match self.get_log_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for get_log_events"),
+        }
+    }
+    fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> RusotoFuture<GetLogGroupFieldsResponse, GetLogGroupFieldsError> {
+        match self.get_log_group_fields {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for get_log_group_fields"),
+        }
+    }
+    fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> RusotoFuture<GetLogRecordResponse, GetLogRecordError> {
+        match self.get_log_record {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for get_log_record"),
+        }
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> RusotoFuture<GetQueryResultsResponse, GetQueryResultsError> {
+        match self.get_query_results {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for get_query_results"),
+        }
+    }
+    fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> RusotoFuture<ListTagsLogGroupResponse, ListTagsLogGroupError> {
+        match self.list_tags_log_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for list_tags_log_group"),
+        }
+    }
+    fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> RusotoFuture<PutDestinationResponse, PutDestinationError> {
+        match self.put_destination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_destination"),
+        }
+    }
+    fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> RusotoFuture<(), PutDestinationPolicyError> {
+        match self.put_destination_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_destination_policy"),
+        }
+    }
+    fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> RusotoFuture<PutLogEventsResponse, PutLogEventsError> {
+        match self.put_log_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_log_events"),
+        }
+    }
+    fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> RusotoFuture<(), PutMetricFilterError> {
+        match self.put_metric_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_metric_filter"),
+        }
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        match self.put_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_resource_policy"),
+        }
+    }
+    fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> RusotoFuture<(), PutRetentionPolicyError> {
+        match self.put_retention_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_retention_policy"),
+        }
+    }
+    fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), PutSubscriptionFilterError> {
+        match self.put_subscription_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for put_subscription_filter"),
+        }
+    }
+    fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> RusotoFuture<StartQueryResponse, StartQueryError> {
+        match self.start_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for start_query"),
+        }
+    }
+    fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> RusotoFuture<StopQueryResponse, StopQueryError> {
+        match self.stop_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for stop_query"),
+        }
+    }
+    fn tag_log_group(&self, input: TagLogGroupRequest) -> RusotoFuture<(), TagLogGroupError> {
+        match self.tag_log_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for tag_log_group"),
+        }
+    }
     fn test_metric_filter(
         &self,
         input: TestMetricFilterRequest,
     ) -> RusotoFuture<TestMetricFilterResponse, TestMetricFilterError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.TestMetricFilter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestMetricFilterResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestMetricFilterError::from_response(response))),
-                )
-            }
-        })
+        match self.test_metric_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for test_metric_filter"),
+        }
     }
-
-    /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To add tags, use <a>UntagLogGroup</a>.</p>
     fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError> {
-        let mut request = SignedRequest::new("POST", "logs", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Logs_20140328.UntagLogGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagLogGroupError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_log_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockCloudWatchLogs has no handler for untag_log_group"),
+        }
     }
 }
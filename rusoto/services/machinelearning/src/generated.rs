@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsInput {
     /// <p>The ID of the ML object to tag. For example, <code>exampleModelId</code>.</p>
     #[serde(rename = "ResourceId")]
@@ -36,9 +38,30 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(100));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Amazon ML returns the following elements. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsOutput {
     /// <p>The ID of the ML object that was tagged.</p>
     #[serde(rename = "ResourceId")]
@@ -52,7 +75,7 @@ pub struct AddTagsOutput {
 
 /// <p> Represents the output of a <code>GetBatchPrediction</code> operation.</p> <p> The content consists of the detailed metadata, the status, and the data file information of a <code>Batch Prediction</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchPrediction {
     /// <p>The ID of the <code>DataSource</code> that points to the group of observations to predict.</p>
     #[serde(rename = "BatchPredictionDataSourceId")]
@@ -116,6 +139,7 @@ pub struct BatchPrediction {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBatchPredictionInput {
     /// <p>The ID of the <code>DataSource</code> that points to the group of observations to predict.</p>
     #[serde(rename = "BatchPredictionDataSourceId")]
@@ -135,9 +159,71 @@ pub struct CreateBatchPredictionInput {
     pub output_uri: String,
 }
 
+impl Validate for CreateBatchPredictionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "batch_prediction_data_source_id"),
+            self.batch_prediction_data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "batch_prediction_data_source_id"),
+            &self.batch_prediction_data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "batch_prediction_id"),
+            self.batch_prediction_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "batch_prediction_id"),
+            &self.batch_prediction_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.batch_prediction_name {
+            errors.check_length(
+                &field_path(path, "batch_prediction_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "batch_prediction_name"),
+                &value,
+                ".*\\S.*|^$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "output_uri"),
+            self.output_uri.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "output_uri"),
+            &self.output_uri,
+            "s3://([^/]+)(/.*)?",
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>CreateBatchPrediction</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateBatchPrediction</code> operation is asynchronous. You can poll for status updates by using the <code>&gt;GetBatchPrediction</code> operation and checking the <code>Status</code> parameter of the result. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBatchPredictionOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>BatchPrediction</code>. This value is identical to the value of the <code>BatchPredictionId</code> in the request.</p>
     #[serde(rename = "BatchPredictionId")]
@@ -146,6 +232,7 @@ pub struct CreateBatchPredictionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDataSourceFromRDSInput {
     /// <p>The compute statistics for a <code>DataSource</code>. The statistics are generated from the observation data referenced by a <code>DataSource</code>. Amazon ML uses the statistics internally during <code>MLModel</code> training. This parameter must be set to <code>true</code> if the <code></code>DataSource<code></code> needs to be used for <code>MLModel</code> training. </p>
     #[serde(rename = "ComputeStatistics")]
@@ -166,9 +253,42 @@ pub struct CreateDataSourceFromRDSInput {
     pub role_arn: String,
 }
 
+impl Validate for CreateDataSourceFromRDSInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_id"),
+            self.data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_id"),
+            &self.data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.data_source_name {
+            errors.check_length(
+                &field_path(path, "data_source_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "data_source_name"), &value, ".*\\S.*|^$");
+        }
+        self.rds_data
+            .validate_at(&field_path(path, "rds_data"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(110),
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>CreateDataSourceFromRDS</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateDataSourceFromRDS</code>&gt; operation is asynchronous. You can poll for updates by using the <code>GetBatchPrediction</code> operation and checking the <code>Status</code> parameter. You can inspect the <code>Message</code> when <code>Status</code> shows up as <code>FAILED</code>. You can also check the progress of the copy operation by going to the <code>DataPipeline</code> console and looking up the pipeline using the <code>pipelineId </code> from the describe call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDataSourceFromRDSOutput {
     /// <p>A user-supplied ID that uniquely identifies the datasource. This value should be identical to the value of the <code>DataSourceID</code> in the request. </p>
     #[serde(rename = "DataSourceId")]
@@ -177,6 +297,7 @@ pub struct CreateDataSourceFromRDSOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDataSourceFromRedshiftInput {
     /// <p>The compute statistics for a <code>DataSource</code>. The statistics are generated from the observation data referenced by a <code>DataSource</code>. Amazon ML uses the statistics internally during <code>MLModel</code> training. This parameter must be set to <code>true</code> if the <code>DataSource</code> needs to be used for <code>MLModel</code> training.</p>
     #[serde(rename = "ComputeStatistics")]
@@ -197,9 +318,42 @@ pub struct CreateDataSourceFromRedshiftInput {
     pub role_arn: String,
 }
 
+impl Validate for CreateDataSourceFromRedshiftInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_id"),
+            self.data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_id"),
+            &self.data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.data_source_name {
+            errors.check_length(
+                &field_path(path, "data_source_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "data_source_name"), &value, ".*\\S.*|^$");
+        }
+        self.data_spec
+            .validate_at(&field_path(path, "data_spec"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(110),
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>CreateDataSourceFromRedshift</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateDataSourceFromRedshift</code> operation is asynchronous. You can poll for updates by using the <code>GetBatchPrediction</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDataSourceFromRedshiftOutput {
     /// <p>A user-supplied ID that uniquely identifies the datasource. This value should be identical to the value of the <code>DataSourceID</code> in the request. </p>
     #[serde(rename = "DataSourceId")]
@@ -208,6 +362,7 @@ pub struct CreateDataSourceFromRedshiftOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDataSourceFromS3Input {
     /// <p>The compute statistics for a <code>DataSource</code>. The statistics are generated from the observation data referenced by a <code>DataSource</code>. Amazon ML uses the statistics internally during <code>MLModel</code> training. This parameter must be set to <code>true</code> if the <code></code>DataSource<code></code> needs to be used for <code>MLModel</code> training.</p>
     #[serde(rename = "ComputeStatistics")]
@@ -225,9 +380,36 @@ pub struct CreateDataSourceFromS3Input {
     pub data_spec: S3DataSpec,
 }
 
+impl Validate for CreateDataSourceFromS3Input {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_id"),
+            self.data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_id"),
+            &self.data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.data_source_name {
+            errors.check_length(
+                &field_path(path, "data_source_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "data_source_name"), &value, ".*\\S.*|^$");
+        }
+        self.data_spec
+            .validate_at(&field_path(path, "data_spec"), errors);
+    }
+}
+
 /// <p> Represents the output of a <code>CreateDataSourceFromS3</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateDataSourceFromS3</code> operation is asynchronous. You can poll for updates by using the <code>GetBatchPrediction</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDataSourceFromS3Output {
     /// <p>A user-supplied ID that uniquely identifies the <code>DataSource</code>. This value should be identical to the value of the <code>DataSourceID</code> in the request. </p>
     #[serde(rename = "DataSourceId")]
@@ -236,6 +418,7 @@ pub struct CreateDataSourceFromS3Output {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEvaluationInput {
     /// <p>The ID of the <code>DataSource</code> for the evaluation. The schema of the <code>DataSource</code> must match the schema used to create the <code>MLModel</code>.</p>
     #[serde(rename = "EvaluationDataSourceId")]
@@ -252,9 +435,56 @@ pub struct CreateEvaluationInput {
     pub ml_model_id: String,
 }
 
+impl Validate for CreateEvaluationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "evaluation_data_source_id"),
+            self.evaluation_data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "evaluation_data_source_id"),
+            &self.evaluation_data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "evaluation_id"),
+            self.evaluation_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "evaluation_id"),
+            &self.evaluation_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.evaluation_name {
+            errors.check_length(
+                &field_path(path, "evaluation_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "evaluation_name"), &value, ".*\\S.*|^$");
+        }
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>CreateEvaluation</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p><code>CreateEvaluation</code> operation is asynchronous. You can poll for status updates by using the <code>GetEvcaluation</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEvaluationOutput {
     /// <p>The user-supplied ID that uniquely identifies the <code>Evaluation</code>. This value should be identical to the value of the <code>EvaluationId</code> in the request.</p>
     #[serde(rename = "EvaluationId")]
@@ -263,6 +493,7 @@ pub struct CreateEvaluationOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMLModelInput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>.</p>
     #[serde(rename = "MLModelId")]
@@ -291,9 +522,66 @@ pub struct CreateMLModelInput {
     pub training_data_source_id: String,
 }
 
+impl Validate for CreateMLModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.ml_model_name {
+            errors.check_length(
+                &field_path(path, "ml_model_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ml_model_name"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.recipe {
+            errors.check_length(
+                &field_path(path, "recipe"),
+                value.chars().count(),
+                None,
+                Some(131071),
+            );
+        }
+        if let Some(ref value) = self.recipe_uri {
+            errors.check_length(
+                &field_path(path, "recipe_uri"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "recipe_uri"),
+                &value,
+                "s3://([^/]+)(/.*)?",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "training_data_source_id"),
+            self.training_data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "training_data_source_id"),
+            &self.training_data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>CreateMLModel</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateMLModel</code> operation is asynchronous. You can poll for status updates by using the <code>GetMLModel</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMLModelOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>. This value should be identical to the value of the <code>MLModelId</code> in the request. </p>
     #[serde(rename = "MLModelId")]
@@ -302,15 +590,32 @@ pub struct CreateMLModelOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRealtimeEndpointInput {
     /// <p>The ID assigned to the <code>MLModel</code> during creation.</p>
     #[serde(rename = "MLModelId")]
     pub ml_model_id: String,
 }
 
+impl Validate for CreateRealtimeEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p><p>Represents the output of an <code>CreateRealtimeEndpoint</code> operation.</p> <p>The result contains the <code>MLModelId</code> and the endpoint information for the <code>MLModel</code>.</p> <note> <p>The endpoint information includes the URI of the <code>MLModel</code>; that is, the location to send online prediction requests for the specified <code>MLModel</code>.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRealtimeEndpointOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>. This value should be identical to the value of the <code>MLModelId</code> in the request.</p>
     #[serde(rename = "MLModelId")]
@@ -324,7 +629,7 @@ pub struct CreateRealtimeEndpointOutput {
 
 /// <p> Represents the output of the <code>GetDataSource</code> operation. </p> <p> The content consists of the detailed metadata and data file information and the current status of the <code>DataSource</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataSource {
     /// <p> The parameter is <code>true</code> if statistics need to be generated from the observation data. </p>
     #[serde(rename = "ComputeStatistics")]
@@ -395,15 +700,32 @@ pub struct DataSource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBatchPredictionInput {
     /// <p>A user-supplied ID that uniquely identifies the <code>BatchPrediction</code>.</p>
     #[serde(rename = "BatchPredictionId")]
     pub batch_prediction_id: String,
 }
 
+impl Validate for DeleteBatchPredictionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "batch_prediction_id"),
+            self.batch_prediction_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "batch_prediction_id"),
+            &self.batch_prediction_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>DeleteBatchPrediction</code> operation.</p> <p>You can use the <code>GetBatchPrediction</code> operation and check the value of the <code>Status</code> parameter to see whether a <code>BatchPrediction</code> is marked as <code>DELETED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBatchPredictionOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>BatchPrediction</code>. This value should be identical to the value of the <code>BatchPredictionID</code> in the request.</p>
     #[serde(rename = "BatchPredictionId")]
@@ -412,15 +734,32 @@ pub struct DeleteBatchPredictionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDataSourceInput {
     /// <p>A user-supplied ID that uniquely identifies the <code>DataSource</code>.</p>
     #[serde(rename = "DataSourceId")]
     pub data_source_id: String,
 }
 
+impl Validate for DeleteDataSourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_id"),
+            self.data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_id"),
+            &self.data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>DeleteDataSource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDataSourceOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>DataSource</code>. This value should be identical to the value of the <code>DataSourceID</code> in the request.</p>
     #[serde(rename = "DataSourceId")]
@@ -429,15 +768,32 @@ pub struct DeleteDataSourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEvaluationInput {
     /// <p>A user-supplied ID that uniquely identifies the <code>Evaluation</code> to delete.</p>
     #[serde(rename = "EvaluationId")]
     pub evaluation_id: String,
 }
 
+impl Validate for DeleteEvaluationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "evaluation_id"),
+            self.evaluation_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "evaluation_id"),
+            &self.evaluation_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p> Represents the output of a <code>DeleteEvaluation</code> operation. The output indicates that Amazon Machine Learning (Amazon ML) received the request.</p> <p>You can use the <code>GetEvaluation</code> operation and check the value of the <code>Status</code> parameter to see whether an <code>Evaluation</code> is marked as <code>DELETED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteEvaluationOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>Evaluation</code>. This value should be identical to the value of the <code>EvaluationId</code> in the request.</p>
     #[serde(rename = "EvaluationId")]
@@ -446,15 +802,32 @@ pub struct DeleteEvaluationOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMLModelInput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>.</p>
     #[serde(rename = "MLModelId")]
     pub ml_model_id: String,
 }
 
+impl Validate for DeleteMLModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>DeleteMLModel</code> operation.</p> <p>You can use the <code>GetMLModel</code> operation and check the value of the <code>Status</code> parameter to see whether an <code>MLModel</code> is marked as <code>DELETED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMLModelOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>. This value should be identical to the value of the <code>MLModelID</code> in the request.</p>
     #[serde(rename = "MLModelId")]
@@ -463,15 +836,32 @@ pub struct DeleteMLModelOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRealtimeEndpointInput {
     /// <p>The ID assigned to the <code>MLModel</code> during creation.</p>
     #[serde(rename = "MLModelId")]
     pub ml_model_id: String,
 }
 
+impl Validate for DeleteRealtimeEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of an <code>DeleteRealtimeEndpoint</code> operation.</p> <p>The result contains the <code>MLModelId</code> and the endpoint information for the <code>MLModel</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRealtimeEndpointOutput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>. This value should be identical to the value of the <code>MLModelId</code> in the request.</p>
     #[serde(rename = "MLModelId")]
@@ -484,6 +874,7 @@ pub struct DeleteRealtimeEndpointOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsInput {
     /// <p>The ID of the tagged ML object. For example, <code>exampleModelId</code>.</p>
     #[serde(rename = "ResourceId")]
@@ -496,9 +887,36 @@ pub struct DeleteTagsInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for DeleteTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 /// <p>Amazon ML returns the following elements. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTagsOutput {
     /// <p>The ID of the ML object from which tags were deleted.</p>
     #[serde(rename = "ResourceId")]
@@ -511,6 +929,7 @@ pub struct DeleteTagsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBatchPredictionsInput {
     /// <p>The equal to operator. The <code>BatchPrediction</code> results will have <code>FilterVariable</code> values that exactly match the value specified with <code>EQ</code>.</p>
     #[serde(rename = "EQ")]
@@ -558,9 +977,80 @@ pub struct DescribeBatchPredictionsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for DescribeBatchPredictionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.eq {
+            errors.check_length(
+                &field_path(path, "eq"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "eq"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.ge {
+            errors.check_length(
+                &field_path(path, "ge"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ge"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.gt {
+            errors.check_length(
+                &field_path(path, "gt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "gt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.le {
+            errors.check_length(
+                &field_path(path, "le"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "le"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.lt {
+            errors.check_length(
+                &field_path(path, "lt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "lt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.ne {
+            errors.check_length(
+                &field_path(path, "ne"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ne"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*\\S.*|^$");
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>DescribeBatchPredictions</code> operation. The content is essentially a list of <code>BatchPrediction</code>s.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBatchPredictionsOutput {
     /// <p>The ID of the next page in the paginated results that indicates at least one more page follows.</p>
     #[serde(rename = "NextToken")]
@@ -573,6 +1063,7 @@ pub struct DescribeBatchPredictionsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDataSourcesInput {
     /// <p>The equal to operator. The <code>DataSource</code> results will have <code>FilterVariable</code> values that exactly match the value specified with <code>EQ</code>.</p>
     #[serde(rename = "EQ")]
@@ -620,9 +1111,80 @@ pub struct DescribeDataSourcesInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for DescribeDataSourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.eq {
+            errors.check_length(
+                &field_path(path, "eq"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "eq"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.ge {
+            errors.check_length(
+                &field_path(path, "ge"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ge"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.gt {
+            errors.check_length(
+                &field_path(path, "gt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "gt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.le {
+            errors.check_length(
+                &field_path(path, "le"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "le"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.lt {
+            errors.check_length(
+                &field_path(path, "lt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "lt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.ne {
+            errors.check_length(
+                &field_path(path, "ne"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ne"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*\\S.*|^$");
+        }
+    }
+}
+
 /// <p>Represents the query results from a <a>DescribeDataSources</a> operation. The content is essentially a list of <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDataSourcesOutput {
     /// <p>An ID of the next page in the paginated results that indicates at least one more page follows.</p>
     #[serde(rename = "NextToken")]
@@ -635,6 +1197,7 @@ pub struct DescribeDataSourcesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEvaluationsInput {
     /// <p>The equal to operator. The <code>Evaluation</code> results will have <code>FilterVariable</code> values that exactly match the value specified with <code>EQ</code>.</p>
     #[serde(rename = "EQ")]
@@ -682,9 +1245,80 @@ pub struct DescribeEvaluationsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for DescribeEvaluationsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.eq {
+            errors.check_length(
+                &field_path(path, "eq"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "eq"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.ge {
+            errors.check_length(
+                &field_path(path, "ge"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ge"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.gt {
+            errors.check_length(
+                &field_path(path, "gt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "gt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.le {
+            errors.check_length(
+                &field_path(path, "le"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "le"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.lt {
+            errors.check_length(
+                &field_path(path, "lt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "lt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.ne {
+            errors.check_length(
+                &field_path(path, "ne"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ne"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*\\S.*|^$");
+        }
+    }
+}
+
 /// <p>Represents the query results from a <code>DescribeEvaluations</code> operation. The content is essentially a list of <code>Evaluation</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEvaluationsOutput {
     /// <p>The ID of the next page in the paginated results that indicates at least one more page follows.</p>
     #[serde(rename = "NextToken")]
@@ -697,6 +1331,7 @@ pub struct DescribeEvaluationsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMLModelsInput {
     /// <p>The equal to operator. The <code>MLModel</code> results will have <code>FilterVariable</code> values that exactly match the value specified with <code>EQ</code>.</p>
     #[serde(rename = "EQ")]
@@ -744,9 +1379,80 @@ pub struct DescribeMLModelsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for DescribeMLModelsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.eq {
+            errors.check_length(
+                &field_path(path, "eq"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "eq"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.ge {
+            errors.check_length(
+                &field_path(path, "ge"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ge"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.gt {
+            errors.check_length(
+                &field_path(path, "gt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "gt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.le {
+            errors.check_length(
+                &field_path(path, "le"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "le"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.lt {
+            errors.check_length(
+                &field_path(path, "lt"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "lt"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.ne {
+            errors.check_length(
+                &field_path(path, "ne"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ne"), &value, ".*\\S.*|^$");
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefix"), &value, ".*\\S.*|^$");
+        }
+    }
+}
+
 /// <p>Represents the output of a <code>DescribeMLModels</code> operation. The content is essentially a list of <code>MLModel</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMLModelsOutput {
     /// <p>The ID of the next page in the paginated results that indicates at least one more page follows.</p>
     #[serde(rename = "NextToken")]
@@ -759,6 +1465,7 @@ pub struct DescribeMLModelsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsInput {
     /// <p>The ID of the ML object. For example, <code>exampleModelId</code>. </p>
     #[serde(rename = "ResourceId")]
@@ -768,9 +1475,25 @@ pub struct DescribeTagsInput {
     pub resource_type: String,
 }
 
+impl Validate for DescribeTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Amazon ML returns the following elements. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTagsOutput {
     /// <p>The ID of the tagged ML object.</p>
     #[serde(rename = "ResourceId")]
@@ -788,7 +1511,7 @@ pub struct DescribeTagsOutput {
 
 /// <p> Represents the output of <code>GetEvaluation</code> operation. </p> <p>The content consists of the detailed metadata and data file information and the current status of the <code>Evaluation</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Evaluation {
     #[serde(rename = "ComputeTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -846,15 +1569,32 @@ pub struct Evaluation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBatchPredictionInput {
     /// <p>An ID assigned to the <code>BatchPrediction</code> at creation.</p>
     #[serde(rename = "BatchPredictionId")]
     pub batch_prediction_id: String,
 }
 
+impl Validate for GetBatchPredictionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "batch_prediction_id"),
+            self.batch_prediction_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "batch_prediction_id"),
+            &self.batch_prediction_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetBatchPrediction</code> operation and describes a <code>BatchPrediction</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBatchPredictionOutput {
     /// <p>The ID of the <code>DataSource</code> that was used to create the <code>BatchPrediction</code>. </p>
     #[serde(rename = "BatchPredictionDataSourceId")]
@@ -927,6 +1667,7 @@ pub struct GetBatchPredictionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDataSourceInput {
     /// <p>The ID assigned to the <code>DataSource</code> at creation.</p>
     #[serde(rename = "DataSourceId")]
@@ -937,9 +1678,25 @@ pub struct GetDataSourceInput {
     pub verbose: Option<bool>,
 }
 
+impl Validate for GetDataSourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_id"),
+            self.data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_id"),
+            &self.data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetDataSource</code> operation and describes a <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDataSourceOutput {
     /// <p> The parameter is <code>true</code> if statistics need to be generated from the observation data. </p>
     #[serde(rename = "ComputeStatistics")]
@@ -1021,15 +1778,32 @@ pub struct GetDataSourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEvaluationInput {
     /// <p>The ID of the <code>Evaluation</code> to retrieve. The evaluation of each <code>MLModel</code> is recorded and cataloged. The ID provides the means to access the information. </p>
     #[serde(rename = "EvaluationId")]
     pub evaluation_id: String,
 }
 
+impl Validate for GetEvaluationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "evaluation_id"),
+            self.evaluation_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "evaluation_id"),
+            &self.evaluation_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetEvaluation</code> operation and describes an <code>Evaluation</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetEvaluationOutput {
     /// <p>The approximate CPU time in milliseconds that Amazon Machine Learning spent processing the <code>Evaluation</code>, normalized and scaled on computation resources. <code>ComputeTime</code> is only available if the <code>Evaluation</code> is in the <code>COMPLETED</code> state.</p>
     #[serde(rename = "ComputeTime")]
@@ -1094,6 +1868,7 @@ pub struct GetEvaluationOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMLModelInput {
     /// <p>The ID assigned to the <code>MLModel</code> at creation.</p>
     #[serde(rename = "MLModelId")]
@@ -1104,9 +1879,25 @@ pub struct GetMLModelInput {
     pub verbose: Option<bool>,
 }
 
+impl Validate for GetMLModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+    }
+}
+
 /// <p>Represents the output of a <code>GetMLModel</code> operation, and provides detailed information about a <code>MLModel</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMLModelOutput {
     /// <p>The approximate CPU time in milliseconds that Amazon Machine Learning spent processing the <code>MLModel</code>, normalized and scaled on computation resources. <code>ComputeTime</code> is only available if the <code>MLModel</code> is in the <code>COMPLETED</code> state.</p>
     #[serde(rename = "ComputeTime")]
@@ -1195,7 +1986,7 @@ pub struct GetMLModelOutput {
 
 /// <p> Represents the output of a <code>GetMLModel</code> operation. </p> <p>The content consists of the detailed metadata and the current status of the <code>MLModel</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MLModel {
     /// <p><p>The algorithm used to train the <code>MLModel</code>. The following algorithm is supported:</p> <ul> <li> <code>SGD</code> -- Stochastic gradient descent. The goal of <code>SGD</code> is to minimize the gradient of the loss function. </li> </ul></p>
     #[serde(rename = "Algorithm")]
@@ -1272,7 +2063,7 @@ pub struct MLModel {
 
 /// <p>Measurements of how well the <code>MLModel</code> performed on known observations. One of the following metrics is returned, based on the type of the <code>MLModel</code>: </p> <ul> <li> <p>BinaryAUC: The binary <code>MLModel</code> uses the Area Under the Curve (AUC) technique to measure performance. </p> </li> <li> <p>RegressionRMSE: The regression <code>MLModel</code> uses the Root Mean Square Error (RMSE) technique to measure performance. RMSE measures the difference between predicted and actual values for a single variable.</p> </li> <li> <p>MulticlassAvgFScore: The multiclass <code>MLModel</code> uses the F1 score technique to measure performance. </p> </li> </ul> <p> For more information about performance metrics, please see the <a href="http://docs.aws.amazon.com/machine-learning/latest/dg">Amazon Machine Learning Developer Guide</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PerformanceMetrics {
     #[serde(rename = "Properties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1280,6 +2071,7 @@ pub struct PerformanceMetrics {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PredictInput {
     /// <p>A unique identifier of the <code>MLModel</code>.</p>
     #[serde(rename = "MLModelId")]
@@ -1290,8 +2082,35 @@ pub struct PredictInput {
     pub record: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for PredictInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "predict_endpoint"),
+            self.predict_endpoint.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "predict_endpoint"),
+            &self.predict_endpoint,
+            "https://[a-zA-Z0-9-.]*\\.amazon(aws)?\\.com[/]?",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PredictOutput {
     #[serde(rename = "Prediction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1300,7 +2119,7 @@ pub struct PredictOutput {
 
 /// <p><p>The output from a <code>Predict</code> operation: </p> <ul> <li> <p> <code>Details</code> - Contains the following attributes: <code>DetailsAttributes.PREDICTIVE<em>MODEL</em>TYPE - REGRESSION | BINARY | MULTICLASS</code> <code>DetailsAttributes.ALGORITHM - SGD</code> </p> </li> <li> <p> <code>PredictedLabel</code> - Present for either a <code>BINARY</code> or <code>MULTICLASS</code> <code>MLModel</code> request. </p> </li> <li> <p> <code>PredictedScores</code> - Contains the raw classification score corresponding to each label. </p> </li> <li> <p> <code>PredictedValue</code> - Present for a <code>REGRESSION</code> <code>MLModel</code> request. </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Prediction {
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1320,6 +2139,7 @@ pub struct Prediction {
 
 /// <p>The data specification of an Amazon Relational Database Service (Amazon RDS) <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RDSDataSpec {
     /// <p><p>A JSON string that represents the splitting and rearrangement processing to be applied to a <code>DataSource</code>. If the <code>DataRearrangement</code> parameter is not provided, all of the input data is used to create the <code>Datasource</code>.</p> <p>There are multiple parameters that control what data is used to create a datasource:</p> <ul> <li><p><b><code>percentBegin</code></b></p> <p>Use <code>percentBegin</code> to indicate the beginning of the range of the data used to create the Datasource. If you do not include <code>percentBegin</code> and <code>percentEnd</code>, Amazon ML includes all of the data when creating the datasource.</p></li> <li><p><b><code>percentEnd</code></b></p> <p>Use <code>percentEnd</code> to indicate the end of the range of the data used to create the Datasource. If you do not include <code>percentBegin</code> and <code>percentEnd</code>, Amazon ML includes all of the data when creating the datasource.</p></li> <li><p><b><code>complement</code></b></p> <p>The <code>complement</code> parameter instructs Amazon ML to use the data that is not included in the range of <code>percentBegin</code> to <code>percentEnd</code> to create a datasource. The <code>complement</code> parameter is useful if you need to create complementary datasources for training and evaluation. To create a complementary datasource, use the same values for <code>percentBegin</code> and <code>percentEnd</code>, along with the <code>complement</code> parameter.</p> <p>For example, the following two datasources do not share any data, and can be used to train and evaluate a model. The first datasource has 25 percent of the data, and the second one has 75 percent of the data.</p> <p>Datasource for evaluation: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:0, &quot;percentEnd&quot;:25}}</code></p> <p>Datasource for training: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:0, &quot;percentEnd&quot;:25, &quot;complement&quot;:&quot;true&quot;}}</code></p> </li> <li><p><b><code>strategy</code></b></p> <p>To change how Amazon ML splits the data for a datasource, use the <code>strategy</code> parameter.</p> <p>The default value for the <code>strategy</code> parameter is <code>sequential</code>, meaning that Amazon ML takes all of the data records between the <code>percentBegin</code> and <code>percentEnd</code> parameters for the datasource, in the order that the records appear in the input data.</p> <p>The following two <code>DataRearrangement</code> lines are examples of sequentially ordered training and evaluation datasources:</p> <p>Datasource for evaluation: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;sequential&quot;}}</code></p> <p>Datasource for training: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;sequential&quot;, &quot;complement&quot;:&quot;true&quot;}}</code></p> <p>To randomly split the input data into the proportions indicated by the percentBegin and percentEnd parameters, set the <code>strategy</code> parameter to <code>random</code> and provide a string that is used as the seed value for the random data splitting (for example, you can use the S3 path to your data as the random seed string). If you choose the random split strategy, Amazon ML assigns each row of data a pseudo-random number between 0 and 100, and then selects the rows that have an assigned number between <code>percentBegin</code> and <code>percentEnd</code>. Pseudo-random numbers are assigned using both the input seed string value and the byte offset as a seed, so changing the data results in a different split. Any existing ordering is preserved. The random splitting strategy ensures that variables in the training and evaluation data are distributed similarly. It is useful in the cases where the input data may have an implicit sort order, which would otherwise result in training and evaluation datasources containing non-similar data records.</p> <p>The following two <code>DataRearrangement</code> lines are examples of non-sequentially ordered training and evaluation datasources:</p> <p>Datasource for evaluation: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;random&quot;, &quot;randomSeed&quot;=&quot;s3://my<em>s3</em>path/bucket/file.csv&quot;}}</code></p> <p>Datasource for training: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;random&quot;, &quot;randomSeed&quot;=&quot;s3://my<em>s3</em>path/bucket/file.csv&quot;, &quot;complement&quot;:&quot;true&quot;}}</code></p> </li> </ul></p>
     #[serde(rename = "DataRearrangement")]
@@ -1359,6 +2179,75 @@ pub struct RDSDataSpec {
     pub subnet_id: String,
 }
 
+impl Validate for RDSDataSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_schema {
+            errors.check_length(
+                &field_path(path, "data_schema"),
+                value.chars().count(),
+                None,
+                Some(131071),
+            );
+        }
+        if let Some(ref value) = self.data_schema_uri {
+            errors.check_length(
+                &field_path(path, "data_schema_uri"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "data_schema_uri"),
+                &value,
+                "s3://([^/]+)(/.*)?",
+            );
+        }
+        self.database_credentials
+            .validate_at(&field_path(path, "database_credentials"), errors);
+        self.database_information
+            .validate_at(&field_path(path, "database_information"), errors);
+        errors.check_length(
+            &field_path(path, "resource_role"),
+            self.resource_role.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "s3_staging_location"),
+            self.s3_staging_location.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_staging_location"),
+            &self.s3_staging_location,
+            "s3://([^/]+)(/.*)?",
+        );
+        for (i, item) in self.security_group_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+        errors.check_length(
+            &field_path(path, "select_sql_query"),
+            self.select_sql_query.chars().count(),
+            Some(1),
+            Some(16777216),
+        );
+        errors.check_length(
+            &field_path(path, "service_role"),
+            self.service_role.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "subnet_id"),
+            self.subnet_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>The database details of an Amazon RDS database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RDSDatabase {
@@ -1369,8 +2258,31 @@ pub struct RDSDatabase {
     pub instance_identifier: String,
 }
 
+impl Validate for RDSDatabase {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "instance_identifier"),
+            self.instance_identifier.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_identifier"),
+            &self.instance_identifier,
+            "[a-z0-9-]+",
+        );
+    }
+}
+
 /// <p>The database credentials to connect to a database on an RDS DB instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RDSDatabaseCredentials {
     #[serde(rename = "Password")]
     pub password: String,
@@ -1378,9 +2290,26 @@ pub struct RDSDatabaseCredentials {
     pub username: String,
 }
 
+impl Validate for RDSDatabaseCredentials {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(8),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+    }
+}
+
 /// <p>The datasource details that are specific to Amazon RDS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RDSMetadata {
     /// <p>The ID of the Data Pipeline instance that is used to carry to copy data from Amazon RDS to Amazon S3. You can use the ID to find details about the instance in the Data Pipeline console.</p>
     #[serde(rename = "DataPipelineId")]
@@ -1409,7 +2338,7 @@ pub struct RDSMetadata {
 
 /// <p> Describes the real-time endpoint information for an <code>MLModel</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RealtimeEndpointInfo {
     /// <p>The time that the request to create the real-time endpoint for the <code>MLModel</code> was received. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
@@ -1431,6 +2360,7 @@ pub struct RealtimeEndpointInfo {
 
 /// <p>Describes the data specification of an Amazon Redshift <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RedshiftDataSpec {
     /// <p><p>A JSON string that represents the splitting and rearrangement processing to be applied to a <code>DataSource</code>. If the <code>DataRearrangement</code> parameter is not provided, all of the input data is used to create the <code>Datasource</code>.</p> <p>There are multiple parameters that control what data is used to create a datasource:</p> <ul> <li><p><b><code>percentBegin</code></b></p> <p>Use <code>percentBegin</code> to indicate the beginning of the range of the data used to create the Datasource. If you do not include <code>percentBegin</code> and <code>percentEnd</code>, Amazon ML includes all of the data when creating the datasource.</p></li> <li><p><b><code>percentEnd</code></b></p> <p>Use <code>percentEnd</code> to indicate the end of the range of the data used to create the Datasource. If you do not include <code>percentBegin</code> and <code>percentEnd</code>, Amazon ML includes all of the data when creating the datasource.</p></li> <li><p><b><code>complement</code></b></p> <p>The <code>complement</code> parameter instructs Amazon ML to use the data that is not included in the range of <code>percentBegin</code> to <code>percentEnd</code> to create a datasource. The <code>complement</code> parameter is useful if you need to create complementary datasources for training and evaluation. To create a complementary datasource, use the same values for <code>percentBegin</code> and <code>percentEnd</code>, along with the <code>complement</code> parameter.</p> <p>For example, the following two datasources do not share any data, and can be used to train and evaluate a model. The first datasource has 25 percent of the data, and the second one has 75 percent of the data.</p> <p>Datasource for evaluation: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:0, &quot;percentEnd&quot;:25}}</code></p> <p>Datasource for training: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:0, &quot;percentEnd&quot;:25, &quot;complement&quot;:&quot;true&quot;}}</code></p> </li> <li><p><b><code>strategy</code></b></p> <p>To change how Amazon ML splits the data for a datasource, use the <code>strategy</code> parameter.</p> <p>The default value for the <code>strategy</code> parameter is <code>sequential</code>, meaning that Amazon ML takes all of the data records between the <code>percentBegin</code> and <code>percentEnd</code> parameters for the datasource, in the order that the records appear in the input data.</p> <p>The following two <code>DataRearrangement</code> lines are examples of sequentially ordered training and evaluation datasources:</p> <p>Datasource for evaluation: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;sequential&quot;}}</code></p> <p>Datasource for training: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;sequential&quot;, &quot;complement&quot;:&quot;true&quot;}}</code></p> <p>To randomly split the input data into the proportions indicated by the percentBegin and percentEnd parameters, set the <code>strategy</code> parameter to <code>random</code> and provide a string that is used as the seed value for the random data splitting (for example, you can use the S3 path to your data as the random seed string). If you choose the random split strategy, Amazon ML assigns each row of data a pseudo-random number between 0 and 100, and then selects the rows that have an assigned number between <code>percentBegin</code> and <code>percentEnd</code>. Pseudo-random numbers are assigned using both the input seed string value and the byte offset as a seed, so changing the data results in a different split. Any existing ordering is preserved. The random splitting strategy ensures that variables in the training and evaluation data are distributed similarly. It is useful in the cases where the input data may have an implicit sort order, which would otherwise result in training and evaluation datasources containing non-similar data records.</p> <p>The following two <code>DataRearrangement</code> lines are examples of non-sequentially ordered training and evaluation datasources:</p> <p>Datasource for evaluation: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;random&quot;, &quot;randomSeed&quot;=&quot;s3://my<em>s3</em>path/bucket/file.csv&quot;}}</code></p> <p>Datasource for training: <code>{&quot;splitting&quot;:{&quot;percentBegin&quot;:70, &quot;percentEnd&quot;:100, &quot;strategy&quot;:&quot;random&quot;, &quot;randomSeed&quot;=&quot;s3://my<em>s3</em>path/bucket/file.csv&quot;, &quot;complement&quot;:&quot;true&quot;}}</code></p> </li> </ul></p>
     #[serde(rename = "DataRearrangement")]
@@ -1458,6 +2388,53 @@ pub struct RedshiftDataSpec {
     pub select_sql_query: String,
 }
 
+impl Validate for RedshiftDataSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_schema {
+            errors.check_length(
+                &field_path(path, "data_schema"),
+                value.chars().count(),
+                None,
+                Some(131071),
+            );
+        }
+        if let Some(ref value) = self.data_schema_uri {
+            errors.check_length(
+                &field_path(path, "data_schema_uri"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "data_schema_uri"),
+                &value,
+                "s3://([^/]+)(/.*)?",
+            );
+        }
+        self.database_credentials
+            .validate_at(&field_path(path, "database_credentials"), errors);
+        self.database_information
+            .validate_at(&field_path(path, "database_information"), errors);
+        errors.check_length(
+            &field_path(path, "s3_staging_location"),
+            self.s3_staging_location.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_staging_location"),
+            &self.s3_staging_location,
+            "s3://([^/]+)(/.*)?",
+        );
+        errors.check_length(
+            &field_path(path, "select_sql_query"),
+            self.select_sql_query.chars().count(),
+            Some(1),
+            Some(16777216),
+        );
+    }
+}
+
 /// <p>Describes the database details required to connect to an Amazon Redshift database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RedshiftDatabase {
@@ -1467,8 +2444,36 @@ pub struct RedshiftDatabase {
     pub database_name: String,
 }
 
+impl Validate for RedshiftDatabase {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_identifier"),
+            &self.cluster_identifier,
+            "[a-z0-9-]+",
+        );
+        errors.check_length(
+            &field_path(path, "database_name"),
+            self.database_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "database_name"),
+            &self.database_name,
+            "[a-z0-9]+",
+        );
+    }
+}
+
 /// <p> Describes the database credentials for connecting to a database on an Amazon Redshift cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RedshiftDatabaseCredentials {
     #[serde(rename = "Password")]
     pub password: String,
@@ -1476,9 +2481,26 @@ pub struct RedshiftDatabaseCredentials {
     pub username: String,
 }
 
+impl Validate for RedshiftDatabaseCredentials {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(8),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(128),
+        );
+    }
+}
+
 /// <p>Describes the <code>DataSource</code> details specific to Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RedshiftMetadata {
     #[serde(rename = "DatabaseUserName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1494,6 +2516,7 @@ pub struct RedshiftMetadata {
 
 /// <p> Describes the data specification of a <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3DataSpec {
     /// <p>The location of the data file(s) used by a <code>DataSource</code>. The URI specifies a data file or an Amazon Simple Storage Service (Amazon S3) directory or bucket containing data files.</p>
     #[serde(rename = "DataLocationS3")]
@@ -1512,6 +2535,43 @@ pub struct S3DataSpec {
     pub data_schema_location_s3: Option<String>,
 }
 
+impl Validate for S3DataSpec {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_location_s3"),
+            self.data_location_s3.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_location_s3"),
+            &self.data_location_s3,
+            "s3://([^/]+)(/.*)?",
+        );
+        if let Some(ref value) = self.data_schema {
+            errors.check_length(
+                &field_path(path, "data_schema"),
+                value.chars().count(),
+                None,
+                Some(131071),
+            );
+        }
+        if let Some(ref value) = self.data_schema_location_s3 {
+            errors.check_length(
+                &field_path(path, "data_schema_location_s3"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "data_schema_location_s3"),
+                &value,
+                "s3://([^/]+)(/.*)?",
+            );
+        }
+    }
+}
+
 /// <p>A custom key-value pair associated with an ML object, such as an ML model.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1525,7 +2585,39 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBatchPredictionInput {
     /// <p>The ID assigned to the <code>BatchPrediction</code> during creation.</p>
     #[serde(rename = "BatchPredictionId")]
@@ -1535,9 +2627,36 @@ pub struct UpdateBatchPredictionInput {
     pub batch_prediction_name: String,
 }
 
+impl Validate for UpdateBatchPredictionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "batch_prediction_id"),
+            self.batch_prediction_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "batch_prediction_id"),
+            &self.batch_prediction_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "batch_prediction_name"),
+            self.batch_prediction_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "batch_prediction_name"),
+            &self.batch_prediction_name,
+            ".*\\S.*|^$",
+        );
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateBatchPrediction</code> operation.</p> <p>You can see the updated content by using the <code>GetBatchPrediction</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBatchPredictionOutput {
     /// <p>The ID assigned to the <code>BatchPrediction</code> during creation. This value should be identical to the value of the <code>BatchPredictionId</code> in the request.</p>
     #[serde(rename = "BatchPredictionId")]
@@ -1546,6 +2665,7 @@ pub struct UpdateBatchPredictionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDataSourceInput {
     /// <p>The ID assigned to the <code>DataSource</code> during creation.</p>
     #[serde(rename = "DataSourceId")]
@@ -1555,9 +2675,36 @@ pub struct UpdateDataSourceInput {
     pub data_source_name: String,
 }
 
+impl Validate for UpdateDataSourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_source_id"),
+            self.data_source_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_id"),
+            &self.data_source_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "data_source_name"),
+            self.data_source_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_source_name"),
+            &self.data_source_name,
+            ".*\\S.*|^$",
+        );
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateDataSource</code> operation.</p> <p>You can see the updated content by using the <code>GetBatchPrediction</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDataSourceOutput {
     /// <p>The ID assigned to the <code>DataSource</code> during creation. This value should be identical to the value of the <code>DataSourceID</code> in the request.</p>
     #[serde(rename = "DataSourceId")]
@@ -1566,6 +2713,7 @@ pub struct UpdateDataSourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEvaluationInput {
     /// <p>The ID assigned to the <code>Evaluation</code> during creation.</p>
     #[serde(rename = "EvaluationId")]
@@ -1575,9 +2723,36 @@ pub struct UpdateEvaluationInput {
     pub evaluation_name: String,
 }
 
+impl Validate for UpdateEvaluationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "evaluation_id"),
+            self.evaluation_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "evaluation_id"),
+            &self.evaluation_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "evaluation_name"),
+            self.evaluation_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "evaluation_name"),
+            &self.evaluation_name,
+            ".*\\S.*|^$",
+        );
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateEvaluation</code> operation.</p> <p>You can see the updated content by using the <code>GetEvaluation</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateEvaluationOutput {
     /// <p>The ID assigned to the <code>Evaluation</code> during creation. This value should be identical to the value of the <code>Evaluation</code> in the request.</p>
     #[serde(rename = "EvaluationId")]
@@ -1586,6 +2761,7 @@ pub struct UpdateEvaluationOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMLModelInput {
     /// <p>The ID assigned to the <code>MLModel</code> during creation.</p>
     #[serde(rename = "MLModelId")]
@@ -1600,9 +2776,34 @@ pub struct UpdateMLModelInput {
     pub score_threshold: Option<f32>,
 }
 
+impl Validate for UpdateMLModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ml_model_id"),
+            self.ml_model_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "ml_model_id"),
+            &self.ml_model_id,
+            "[a-zA-Z0-9_.-]+",
+        );
+        if let Some(ref value) = self.ml_model_name {
+            errors.check_length(
+                &field_path(path, "ml_model_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "ml_model_name"), &value, ".*\\S.*|^$");
+        }
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateMLModel</code> operation.</p> <p>You can see the updated content by using the <code>GetMLModel</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMLModelOutput {
     /// <p>The ID assigned to the <code>MLModel</code> during creation. This value should be identical to the value of the <code>MLModelID</code> in the request.</p>
     #[serde(rename = "MLModelId")]
@@ -1644,7 +2845,12 @@ impl AddTagsError {
                 "TagLimitExceededException" => {
                     return RusotoError::Service(AddTagsError::TagLimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1695,7 +2901,12 @@ impl CreateBatchPredictionError {
                 "InvalidInputException" => {
                     return RusotoError::Service(CreateBatchPredictionError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1746,7 +2957,12 @@ impl CreateDataSourceFromRDSError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1799,7 +3015,12 @@ impl CreateDataSourceFromRedshiftError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1848,7 +3069,12 @@ impl CreateDataSourceFromS3Error {
                 "InvalidInputException" => {
                     return RusotoError::Service(CreateDataSourceFromS3Error::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1895,7 +3121,12 @@ impl CreateEvaluationError {
                 "InvalidInputException" => {
                     return RusotoError::Service(CreateEvaluationError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1942,7 +3173,12 @@ impl CreateMLModelError {
                 "InvalidInputException" => {
                     return RusotoError::Service(CreateMLModelError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1991,7 +3227,12 @@ impl CreateRealtimeEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2040,7 +3281,12 @@ impl DeleteBatchPredictionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2085,7 +3331,12 @@ impl DeleteDataSourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2130,7 +3381,12 @@ impl DeleteEvaluationError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteEvaluationError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2175,7 +3431,12 @@ impl DeleteMLModelError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteMLModelError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2224,7 +3485,12 @@ impl DeleteRealtimeEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2274,7 +3540,12 @@ impl DeleteTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2319,7 +3590,12 @@ impl DescribeBatchPredictionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2358,7 +3634,12 @@ impl DescribeDataSourcesError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeDataSourcesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2397,7 +3678,12 @@ impl DescribeEvaluationsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeEvaluationsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2436,7 +3722,12 @@ impl DescribeMLModelsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(DescribeMLModelsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2480,7 +3771,12 @@ impl DescribeTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2525,7 +3821,12 @@ impl GetBatchPredictionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetBatchPredictionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2570,7 +3871,12 @@ impl GetDataSourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetDataSourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2615,7 +3921,12 @@ impl GetEvaluationError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetEvaluationError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2660,7 +3971,12 @@ impl GetMLModelError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetMLModelError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2715,7 +4031,12 @@ impl PredictError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PredictError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2766,7 +4087,12 @@ impl UpdateBatchPredictionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2811,7 +4137,12 @@ impl UpdateDataSourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2856,7 +4187,12 @@ impl UpdateEvaluationError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateEvaluationError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2901,7 +4237,12 @@ impl UpdateMLModelError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateMLModelError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3086,831 +4427,2524 @@ pub trait MachineLearning {
         input: UpdateMLModelInput,
     ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError>;
 }
-/// A client for the Amazon Machine Learning API.
-#[derive(Clone)]
-pub struct MachineLearningClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MachineLearningClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MachineLearningClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MachineLearningClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MachineLearningClient {
-        MachineLearningClient { client, region }
-    }
-}
-
-impl MachineLearning for MachineLearningClient {
-    /// <p>Adds one or more tags to an object, up to a limit of 10. Each tag consists of a key and an optional value. If you add a tag using a key that is already associated with the ML object, <code>AddTags</code> updates the tag's value.</p>
+const _: Option<&dyn MachineLearning> = None;
+impl<'a, T: MachineLearning + ?Sized> MachineLearning for &'a T {
     fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.AddTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags(input)
     }
-
-    /// <p>Generates predictions for a group of observations. The observations to process exist in one or more data files referenced by a <code>DataSource</code>. This operation creates a new <code>BatchPrediction</code>, and uses an <code>MLModel</code> and the data files referenced by the <code>DataSource</code> as information sources. </p> <p><code>CreateBatchPrediction</code> is an asynchronous operation. In response to <code>CreateBatchPrediction</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>BatchPrediction</code> status to <code>PENDING</code>. After the <code>BatchPrediction</code> completes, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can poll for status updates by using the <a>GetBatchPrediction</a> operation and checking the <code>Status</code> parameter of the result. After the <code>COMPLETED</code> status appears, the results are available in the location specified by the <code>OutputUri</code> parameter.</p>
     fn create_batch_prediction(
         &self,
         input: CreateBatchPredictionInput,
     ) -> RusotoFuture<CreateBatchPredictionOutput, CreateBatchPredictionError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.CreateBatchPrediction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBatchPredictionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateBatchPredictionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_batch_prediction(input)
     }
-
-    /// <p>Creates a <code>DataSource</code> object from an <a href="http://aws.amazon.com/rds/"> Amazon Relational Database Service</a> (Amazon RDS). A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRDS</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRDS</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used only to perform <code>&gt;CreateMLModel</code>&gt;, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML cannot accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p>
     fn create_data_source_from_rds(
         &self,
         input: CreateDataSourceFromRDSInput,
     ) -> RusotoFuture<CreateDataSourceFromRDSOutput, CreateDataSourceFromRDSError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromRDS");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDataSourceFromRDSOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDataSourceFromRDSError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_data_source_from_rds(input)
     }
-
-    /// <p><p>Creates a <code>DataSource</code> from a database hosted on an Amazon Redshift cluster. A <code>DataSource</code> references data that can be used to perform either <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRedshift</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRedshift</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in <code>COMPLETED</code> or <code>PENDING</code> states can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can&#39;t accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observations should be contained in the database hosted on an Amazon Redshift cluster and should be specified by a <code>SelectSqlQuery</code> query. Amazon ML executes an <code>Unload</code> command in Amazon Redshift to transfer the result set of the <code>SelectSqlQuery</code> query to <code>S3StagingLocation</code>.</p> <p>After the <code>DataSource</code> has been created, it&#39;s ready for use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also requires a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p> &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160406T153842-0700&quot;&gt;<p>You can&#39;t change an existing datasource, but you can copy and modify the settings from an existing Amazon Redshift datasource to create a new datasource. To do so, call <code>GetDataSource</code> for an existing datasource and copy the values to a <code>CreateDataSource</code> call. Change the settings that you want to change and make sure that all required fields have the appropriate values.</p> &lt;?oxy<em>insert</em>end&gt;</p>
     fn create_data_source_from_redshift(
         &self,
         input: CreateDataSourceFromRedshiftInput,
     ) -> RusotoFuture<CreateDataSourceFromRedshiftOutput, CreateDataSourceFromRedshiftError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonML_20141212.CreateDataSourceFromRedshift",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDataSourceFromRedshiftOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateDataSourceFromRedshiftError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_data_source_from_redshift(input)
     }
-
-    /// <p>Creates a <code>DataSource</code> object. A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromS3</code> is an asynchronous operation. In response to <code>CreateDataSourceFromS3</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> has been created and is ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code> or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can't accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observation data used in a <code>DataSource</code> should be ready to use; that is, it should have a consistent structure, and missing data values should be kept to a minimum. The observation data must reside in one or more .csv files in an Amazon Simple Storage Service (Amazon S3) location, along with a schema that describes the data items by name and type. The same schema must be used for all of the data files referenced by the <code>DataSource</code>. </p> <p>After the <code>DataSource</code> has been created, it's ready to use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also needs a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p>
     fn create_data_source_from_s3(
         &self,
         input: CreateDataSourceFromS3Input,
     ) -> RusotoFuture<CreateDataSourceFromS3Output, CreateDataSourceFromS3Error> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromS3");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDataSourceFromS3Output, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateDataSourceFromS3Error::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_data_source_from_s3(input)
     }
-
-    /// <p>Creates a new <code>Evaluation</code> of an <code>MLModel</code>. An <code>MLModel</code> is evaluated on a set of observations associated to a <code>DataSource</code>. Like a <code>DataSource</code> for an <code>MLModel</code>, the <code>DataSource</code> for an <code>Evaluation</code> contains values for the <code>Target Variable</code>. The <code>Evaluation</code> compares the predicted result for each observation to the actual outcome and provides a summary so that you know how effective the <code>MLModel</code> functions on the test data. Evaluation generates a relevant performance metric, such as BinaryAUC, RegressionRMSE or MulticlassAvgFScore based on the corresponding <code>MLModelType</code>: <code>BINARY</code>, <code>REGRESSION</code> or <code>MULTICLASS</code>. </p> <p><code>CreateEvaluation</code> is an asynchronous operation. In response to <code>CreateEvaluation</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the evaluation status to <code>PENDING</code>. After the <code>Evaluation</code> is created and ready for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetEvaluation</code> operation to check progress of the evaluation during the creation operation.</p>
     fn create_evaluation(
         &self,
         input: CreateEvaluationInput,
     ) -> RusotoFuture<CreateEvaluationOutput, CreateEvaluationError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.CreateEvaluation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEvaluationOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateEvaluationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_evaluation(input)
     }
-
-    /// <p>Creates a new <code>MLModel</code> using the <code>DataSource</code> and the recipe as information sources. </p> <p>An <code>MLModel</code> is nearly immutable. Users can update only the <code>MLModelName</code> and the <code>ScoreThreshold</code> in an <code>MLModel</code> without creating a new <code>MLModel</code>. </p> <p><code>CreateMLModel</code> is an asynchronous operation. In response to <code>CreateMLModel</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>MLModel</code> status to <code>PENDING</code>. After the <code>MLModel</code> has been created and ready is for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetMLModel</code> operation to check the progress of the <code>MLModel</code> during the creation operation.</p> <p> <code>CreateMLModel</code> requires a <code>DataSource</code> with computed statistics, which can be created by setting <code>ComputeStatistics</code> to <code>true</code> in <code>CreateDataSourceFromRDS</code>, <code>CreateDataSourceFromS3</code>, or <code>CreateDataSourceFromRedshift</code> operations. </p>
     fn create_ml_model(
         &self,
         input: CreateMLModelInput,
     ) -> RusotoFuture<CreateMLModelOutput, CreateMLModelError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.CreateMLModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMLModelOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateMLModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_ml_model(input)
     }
-
-    /// <p>Creates a real-time endpoint for the <code>MLModel</code>. The endpoint contains the URI of the <code>MLModel</code>; that is, the location to send real-time prediction requests for the specified <code>MLModel</code>.</p>
     fn create_realtime_endpoint(
         &self,
         input: CreateRealtimeEndpointInput,
     ) -> RusotoFuture<CreateRealtimeEndpointOutput, CreateRealtimeEndpointError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.CreateRealtimeEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRealtimeEndpointOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateRealtimeEndpointError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_realtime_endpoint(input)
     }
-
-    /// <p>Assigns the DELETED status to a <code>BatchPrediction</code>, rendering it unusable.</p> <p>After using the <code>DeleteBatchPrediction</code> operation, you can use the <a>GetBatchPrediction</a> operation to verify that the status of the <code>BatchPrediction</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteBatchPrediction</code> operation is irreversible.</p>
     fn delete_batch_prediction(
         &self,
         input: DeleteBatchPredictionInput,
     ) -> RusotoFuture<DeleteBatchPredictionOutput, DeleteBatchPredictionError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DeleteBatchPrediction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBatchPredictionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteBatchPredictionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_batch_prediction(input)
     }
-
-    /// <p>Assigns the DELETED status to a <code>DataSource</code>, rendering it unusable.</p> <p>After using the <code>DeleteDataSource</code> operation, you can use the <a>GetDataSource</a> operation to verify that the status of the <code>DataSource</code> changed to DELETED.</p> <p><b>Caution:</b> The results of the <code>DeleteDataSource</code> operation are irreversible.</p>
     fn delete_data_source(
         &self,
         input: DeleteDataSourceInput,
     ) -> RusotoFuture<DeleteDataSourceOutput, DeleteDataSourceError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DeleteDataSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDataSourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDataSourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_data_source(input)
     }
-
-    /// <p><p>Assigns the <code>DELETED</code> status to an <code>Evaluation</code>, rendering it unusable.</p> <p>After invoking the <code>DeleteEvaluation</code> operation, you can use the <code>GetEvaluation</code> operation to verify that the status of the <code>Evaluation</code> changed to <code>DELETED</code>.</p> <caution><title>Caution</title> <p>The results of the <code>DeleteEvaluation</code> operation are irreversible.</p></caution></p>
     fn delete_evaluation(
         &self,
         input: DeleteEvaluationInput,
     ) -> RusotoFuture<DeleteEvaluationOutput, DeleteEvaluationError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DeleteEvaluation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteEvaluationOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteEvaluationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_evaluation(input)
     }
-
-    /// <p>Assigns the <code>DELETED</code> status to an <code>MLModel</code>, rendering it unusable.</p> <p>After using the <code>DeleteMLModel</code> operation, you can use the <code>GetMLModel</code> operation to verify that the status of the <code>MLModel</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteMLModel</code> operation is irreversible.</p>
     fn delete_ml_model(
         &self,
         input: DeleteMLModelInput,
     ) -> RusotoFuture<DeleteMLModelOutput, DeleteMLModelError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DeleteMLModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMLModelOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMLModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_ml_model(input)
     }
-
-    /// <p>Deletes a real time endpoint of an <code>MLModel</code>.</p>
     fn delete_realtime_endpoint(
         &self,
         input: DeleteRealtimeEndpointInput,
     ) -> RusotoFuture<DeleteRealtimeEndpointOutput, DeleteRealtimeEndpointError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DeleteRealtimeEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRealtimeEndpointOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRealtimeEndpointError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_realtime_endpoint(input)
     }
-
-    /// <p>Deletes the specified tags associated with an ML object. After this operation is complete, you can't recover deleted tags.</p> <p>If you specify a tag that doesn't exist, Amazon ML ignores it.</p>
     fn delete_tags(
         &self,
         input: DeleteTagsInput,
     ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DeleteTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tags(input)
     }
-
-    /// <p>Returns a list of <code>BatchPrediction</code> operations that match the search criteria in the request.</p>
     fn describe_batch_predictions(
         &self,
         input: DescribeBatchPredictionsInput,
     ) -> RusotoFuture<DescribeBatchPredictionsOutput, DescribeBatchPredictionsError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DescribeBatchPredictions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBatchPredictionsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeBatchPredictionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_batch_predictions(input)
     }
-
-    /// <p>Returns a list of <code>DataSource</code> that match the search criteria in the request.</p>
     fn describe_data_sources(
         &self,
         input: DescribeDataSourcesInput,
     ) -> RusotoFuture<DescribeDataSourcesOutput, DescribeDataSourcesError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DescribeDataSources");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDataSourcesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeDataSourcesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_data_sources(input)
     }
-
-    /// <p>Returns a list of <code>DescribeEvaluations</code> that match the search criteria in the request.</p>
     fn describe_evaluations(
         &self,
         input: DescribeEvaluationsInput,
     ) -> RusotoFuture<DescribeEvaluationsOutput, DescribeEvaluationsError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DescribeEvaluations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEvaluationsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEvaluationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_evaluations(input)
     }
-
-    /// <p>Returns a list of <code>MLModel</code> that match the search criteria in the request.</p>
     fn describe_ml_models(
         &self,
         input: DescribeMLModelsInput,
     ) -> RusotoFuture<DescribeMLModelsOutput, DescribeMLModelsError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DescribeMLModels");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMLModelsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeMLModelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_ml_models(input)
     }
-
-    /// <p>Describes one or more of the tags for your Amazon ML object.</p>
     fn describe_tags(
         &self,
         input: DescribeTagsInput,
     ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.DescribeTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_tags(input)
     }
-
-    /// <p>Returns a <code>BatchPrediction</code> that includes detailed metadata, status, and data file information for a <code>Batch Prediction</code> request.</p>
     fn get_batch_prediction(
         &self,
         input: GetBatchPredictionInput,
     ) -> RusotoFuture<GetBatchPredictionOutput, GetBatchPredictionError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.GetBatchPrediction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetBatchPredictionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBatchPredictionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_batch_prediction(input)
     }
-
-    /// <p>Returns a <code>DataSource</code> that includes metadata and data file information, as well as the current status of the <code>DataSource</code>.</p> <p><code>GetDataSource</code> provides results in normal or verbose format. The verbose format adds the schema description and the list of files pointed to by the DataSource to the normal format.</p>
     fn get_data_source(
         &self,
         input: GetDataSourceInput,
     ) -> RusotoFuture<GetDataSourceOutput, GetDataSourceError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.GetDataSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDataSourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDataSourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_data_source(input)
     }
-
+    fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> RusotoFuture<GetEvaluationOutput, GetEvaluationError> {
+        (**self).get_evaluation(input)
+    }
+    fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> RusotoFuture<GetMLModelOutput, GetMLModelError> {
+        (**self).get_ml_model(input)
+    }
+    fn predict(&self, input: PredictInput) -> RusotoFuture<PredictOutput, PredictError> {
+        (**self).predict(input)
+    }
+    fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> RusotoFuture<UpdateBatchPredictionOutput, UpdateBatchPredictionError> {
+        (**self).update_batch_prediction(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> RusotoFuture<UpdateDataSourceOutput, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> RusotoFuture<UpdateEvaluationOutput, UpdateEvaluationError> {
+        (**self).update_evaluation(input)
+    }
+    fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError> {
+        (**self).update_ml_model(input)
+    }
+}
+impl<T: MachineLearning + ?Sized> MachineLearning for Box<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> RusotoFuture<CreateBatchPredictionOutput, CreateBatchPredictionError> {
+        (**self).create_batch_prediction(input)
+    }
+    fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> RusotoFuture<CreateDataSourceFromRDSOutput, CreateDataSourceFromRDSError> {
+        (**self).create_data_source_from_rds(input)
+    }
+    fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> RusotoFuture<CreateDataSourceFromRedshiftOutput, CreateDataSourceFromRedshiftError> {
+        (**self).create_data_source_from_redshift(input)
+    }
+    fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> RusotoFuture<CreateDataSourceFromS3Output, CreateDataSourceFromS3Error> {
+        (**self).create_data_source_from_s3(input)
+    }
+    fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> RusotoFuture<CreateEvaluationOutput, CreateEvaluationError> {
+        (**self).create_evaluation(input)
+    }
+    fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> RusotoFuture<CreateMLModelOutput, CreateMLModelError> {
+        (**self).create_ml_model(input)
+    }
+    fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> RusotoFuture<CreateRealtimeEndpointOutput, CreateRealtimeEndpointError> {
+        (**self).create_realtime_endpoint(input)
+    }
+    fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> RusotoFuture<DeleteBatchPredictionOutput, DeleteBatchPredictionError> {
+        (**self).delete_batch_prediction(input)
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> RusotoFuture<DeleteDataSourceOutput, DeleteDataSourceError> {
+        (**self).delete_data_source(input)
+    }
+    fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> RusotoFuture<DeleteEvaluationOutput, DeleteEvaluationError> {
+        (**self).delete_evaluation(input)
+    }
+    fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> RusotoFuture<DeleteMLModelOutput, DeleteMLModelError> {
+        (**self).delete_ml_model(input)
+    }
+    fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> RusotoFuture<DeleteRealtimeEndpointOutput, DeleteRealtimeEndpointError> {
+        (**self).delete_realtime_endpoint(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> RusotoFuture<DescribeBatchPredictionsOutput, DescribeBatchPredictionsError> {
+        (**self).describe_batch_predictions(input)
+    }
+    fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> RusotoFuture<DescribeDataSourcesOutput, DescribeDataSourcesError> {
+        (**self).describe_data_sources(input)
+    }
+    fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> RusotoFuture<DescribeEvaluationsOutput, DescribeEvaluationsError> {
+        (**self).describe_evaluations(input)
+    }
+    fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> RusotoFuture<DescribeMLModelsOutput, DescribeMLModelsError> {
+        (**self).describe_ml_models(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> RusotoFuture<GetBatchPredictionOutput, GetBatchPredictionError> {
+        (**self).get_batch_prediction(input)
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> RusotoFuture<GetDataSourceOutput, GetDataSourceError> {
+        (**self).get_data_source(input)
+    }
+    fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> RusotoFuture<GetEvaluationOutput, GetEvaluationError> {
+        (**self).get_evaluation(input)
+    }
+    fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> RusotoFuture<GetMLModelOutput, GetMLModelError> {
+        (**self).get_ml_model(input)
+    }
+    fn predict(&self, input: PredictInput) -> RusotoFuture<PredictOutput, PredictError> {
+        (**self).predict(input)
+    }
+    fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> RusotoFuture<UpdateBatchPredictionOutput, UpdateBatchPredictionError> {
+        (**self).update_batch_prediction(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> RusotoFuture<UpdateDataSourceOutput, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> RusotoFuture<UpdateEvaluationOutput, UpdateEvaluationError> {
+        (**self).update_evaluation(input)
+    }
+    fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError> {
+        (**self).update_ml_model(input)
+    }
+}
+impl<T: MachineLearning + ?Sized> MachineLearning for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> RusotoFuture<CreateBatchPredictionOutput, CreateBatchPredictionError> {
+        (**self).create_batch_prediction(input)
+    }
+    fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> RusotoFuture<CreateDataSourceFromRDSOutput, CreateDataSourceFromRDSError> {
+        (**self).create_data_source_from_rds(input)
+    }
+    fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> RusotoFuture<CreateDataSourceFromRedshiftOutput, CreateDataSourceFromRedshiftError> {
+        (**self).create_data_source_from_redshift(input)
+    }
+    fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> RusotoFuture<CreateDataSourceFromS3Output, CreateDataSourceFromS3Error> {
+        (**self).create_data_source_from_s3(input)
+    }
+    fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> RusotoFuture<CreateEvaluationOutput, CreateEvaluationError> {
+        (**self).create_evaluation(input)
+    }
+    fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> RusotoFuture<CreateMLModelOutput, CreateMLModelError> {
+        (**self).create_ml_model(input)
+    }
+    fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> RusotoFuture<CreateRealtimeEndpointOutput, CreateRealtimeEndpointError> {
+        (**self).create_realtime_endpoint(input)
+    }
+    fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> RusotoFuture<DeleteBatchPredictionOutput, DeleteBatchPredictionError> {
+        (**self).delete_batch_prediction(input)
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> RusotoFuture<DeleteDataSourceOutput, DeleteDataSourceError> {
+        (**self).delete_data_source(input)
+    }
+    fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> RusotoFuture<DeleteEvaluationOutput, DeleteEvaluationError> {
+        (**self).delete_evaluation(input)
+    }
+    fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> RusotoFuture<DeleteMLModelOutput, DeleteMLModelError> {
+        (**self).delete_ml_model(input)
+    }
+    fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> RusotoFuture<DeleteRealtimeEndpointOutput, DeleteRealtimeEndpointError> {
+        (**self).delete_realtime_endpoint(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> RusotoFuture<DescribeBatchPredictionsOutput, DescribeBatchPredictionsError> {
+        (**self).describe_batch_predictions(input)
+    }
+    fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> RusotoFuture<DescribeDataSourcesOutput, DescribeDataSourcesError> {
+        (**self).describe_data_sources(input)
+    }
+    fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> RusotoFuture<DescribeEvaluationsOutput, DescribeEvaluationsError> {
+        (**self).describe_evaluations(input)
+    }
+    fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> RusotoFuture<DescribeMLModelsOutput, DescribeMLModelsError> {
+        (**self).describe_ml_models(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> RusotoFuture<GetBatchPredictionOutput, GetBatchPredictionError> {
+        (**self).get_batch_prediction(input)
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> RusotoFuture<GetDataSourceOutput, GetDataSourceError> {
+        (**self).get_data_source(input)
+    }
+    fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> RusotoFuture<GetEvaluationOutput, GetEvaluationError> {
+        (**self).get_evaluation(input)
+    }
+    fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> RusotoFuture<GetMLModelOutput, GetMLModelError> {
+        (**self).get_ml_model(input)
+    }
+    fn predict(&self, input: PredictInput) -> RusotoFuture<PredictOutput, PredictError> {
+        (**self).predict(input)
+    }
+    fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> RusotoFuture<UpdateBatchPredictionOutput, UpdateBatchPredictionError> {
+        (**self).update_batch_prediction(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> RusotoFuture<UpdateDataSourceOutput, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> RusotoFuture<UpdateEvaluationOutput, UpdateEvaluationError> {
+        (**self).update_evaluation(input)
+    }
+    fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError> {
+        (**self).update_ml_model(input)
+    }
+}
+/// A client for the Amazon Machine Learning API.
+#[derive(Clone)]
+pub struct MachineLearningClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MachineLearningClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MachineLearningClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MachineLearningClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MachineLearningClient {
+        MachineLearningClient { client, region }
+    }
+}
+
+impl MachineLearning for MachineLearningClient {
+    /// <p>Adds one or more tags to an object, up to a limit of 10. Each tag consists of a key and an optional value. If you add a tag using a key that is already associated with the ML object, <code>AddTags</code> updates the tag's value.</p>
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("AddTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.AddTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsOutput, _>(response, "AddTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates predictions for a group of observations. The observations to process exist in one or more data files referenced by a <code>DataSource</code>. This operation creates a new <code>BatchPrediction</code>, and uses an <code>MLModel</code> and the data files referenced by the <code>DataSource</code> as information sources. </p> <p><code>CreateBatchPrediction</code> is an asynchronous operation. In response to <code>CreateBatchPrediction</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>BatchPrediction</code> status to <code>PENDING</code>. After the <code>BatchPrediction</code> completes, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can poll for status updates by using the <a>GetBatchPrediction</a> operation and checking the <code>Status</code> parameter of the result. After the <code>COMPLETED</code> status appears, the results are available in the location specified by the <code>OutputUri</code> parameter.</p>
+    fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> RusotoFuture<CreateBatchPredictionOutput, CreateBatchPredictionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateBatchPrediction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.CreateBatchPrediction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBatchPredictionOutput, _>(
+                    response,
+                    "CreateBatchPrediction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateBatchPredictionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>DataSource</code> object from an <a href="http://aws.amazon.com/rds/"> Amazon Relational Database Service</a> (Amazon RDS). A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRDS</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRDS</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used only to perform <code>&gt;CreateMLModel</code>&gt;, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML cannot accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p>
+    fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> RusotoFuture<CreateDataSourceFromRDSOutput, CreateDataSourceFromRDSError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromRDS");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromRDS");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDataSourceFromRDSOutput, _>(
+                    response,
+                    "CreateDataSourceFromRDS",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDataSourceFromRDSError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a <code>DataSource</code> from a database hosted on an Amazon Redshift cluster. A <code>DataSource</code> references data that can be used to perform either <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRedshift</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRedshift</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in <code>COMPLETED</code> or <code>PENDING</code> states can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can&#39;t accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observations should be contained in the database hosted on an Amazon Redshift cluster and should be specified by a <code>SelectSqlQuery</code> query. Amazon ML executes an <code>Unload</code> command in Amazon Redshift to transfer the result set of the <code>SelectSqlQuery</code> query to <code>S3StagingLocation</code>.</p> <p>After the <code>DataSource</code> has been created, it&#39;s ready for use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also requires a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p> &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160406T153842-0700&quot;&gt;<p>You can&#39;t change an existing datasource, but you can copy and modify the settings from an existing Amazon Redshift datasource to create a new datasource. To do so, call <code>GetDataSource</code> for an existing datasource and copy the values to a <code>CreateDataSource</code> call. Change the settings that you want to change and make sure that all required fields have the appropriate values.</p> &lt;?oxy<em>insert</em>end&gt;</p>
+    fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> RusotoFuture<CreateDataSourceFromRedshiftOutput, CreateDataSourceFromRedshiftError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromRedshift");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonML_20141212.CreateDataSourceFromRedshift",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDataSourceFromRedshiftOutput, _>(
+                    response,
+                    "CreateDataSourceFromRedshift",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateDataSourceFromRedshiftError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a <code>DataSource</code> object. A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromS3</code> is an asynchronous operation. In response to <code>CreateDataSourceFromS3</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> has been created and is ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code> or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can't accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observation data used in a <code>DataSource</code> should be ready to use; that is, it should have a consistent structure, and missing data values should be kept to a minimum. The observation data must reside in one or more .csv files in an Amazon Simple Storage Service (Amazon S3) location, along with a schema that describes the data items by name and type. The same schema must be used for all of the data files referenced by the <code>DataSource</code>. </p> <p>After the <code>DataSource</code> has been created, it's ready to use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also needs a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p>
+    fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> RusotoFuture<CreateDataSourceFromS3Output, CreateDataSourceFromS3Error> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateDataSourceFromS3");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromS3");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDataSourceFromS3Output, _>(
+                    response,
+                    "CreateDataSourceFromS3",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateDataSourceFromS3Error::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new <code>Evaluation</code> of an <code>MLModel</code>. An <code>MLModel</code> is evaluated on a set of observations associated to a <code>DataSource</code>. Like a <code>DataSource</code> for an <code>MLModel</code>, the <code>DataSource</code> for an <code>Evaluation</code> contains values for the <code>Target Variable</code>. The <code>Evaluation</code> compares the predicted result for each observation to the actual outcome and provides a summary so that you know how effective the <code>MLModel</code> functions on the test data. Evaluation generates a relevant performance metric, such as BinaryAUC, RegressionRMSE or MulticlassAvgFScore based on the corresponding <code>MLModelType</code>: <code>BINARY</code>, <code>REGRESSION</code> or <code>MULTICLASS</code>. </p> <p><code>CreateEvaluation</code> is an asynchronous operation. In response to <code>CreateEvaluation</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the evaluation status to <code>PENDING</code>. After the <code>Evaluation</code> is created and ready for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetEvaluation</code> operation to check progress of the evaluation during the creation operation.</p>
+    fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> RusotoFuture<CreateEvaluationOutput, CreateEvaluationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateEvaluation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.CreateEvaluation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEvaluationOutput, _>(
+                    response,
+                    "CreateEvaluation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateEvaluationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new <code>MLModel</code> using the <code>DataSource</code> and the recipe as information sources. </p> <p>An <code>MLModel</code> is nearly immutable. Users can update only the <code>MLModelName</code> and the <code>ScoreThreshold</code> in an <code>MLModel</code> without creating a new <code>MLModel</code>. </p> <p><code>CreateMLModel</code> is an asynchronous operation. In response to <code>CreateMLModel</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>MLModel</code> status to <code>PENDING</code>. After the <code>MLModel</code> has been created and ready is for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetMLModel</code> operation to check the progress of the <code>MLModel</code> during the creation operation.</p> <p> <code>CreateMLModel</code> requires a <code>DataSource</code> with computed statistics, which can be created by setting <code>ComputeStatistics</code> to <code>true</code> in <code>CreateDataSourceFromRDS</code>, <code>CreateDataSourceFromS3</code>, or <code>CreateDataSourceFromRedshift</code> operations. </p>
+    fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> RusotoFuture<CreateMLModelOutput, CreateMLModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateMLModel");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.CreateMLModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateMLModelOutput, _>(
+                    response,
+                    "CreateMLModel",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateMLModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a real-time endpoint for the <code>MLModel</code>. The endpoint contains the URI of the <code>MLModel</code>; that is, the location to send real-time prediction requests for the specified <code>MLModel</code>.</p>
+    fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> RusotoFuture<CreateRealtimeEndpointOutput, CreateRealtimeEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("CreateRealtimeEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.CreateRealtimeEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRealtimeEndpointOutput, _>(
+                    response,
+                    "CreateRealtimeEndpoint",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateRealtimeEndpointError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns the DELETED status to a <code>BatchPrediction</code>, rendering it unusable.</p> <p>After using the <code>DeleteBatchPrediction</code> operation, you can use the <a>GetBatchPrediction</a> operation to verify that the status of the <code>BatchPrediction</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteBatchPrediction</code> operation is irreversible.</p>
+    fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> RusotoFuture<DeleteBatchPredictionOutput, DeleteBatchPredictionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteBatchPrediction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DeleteBatchPrediction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBatchPredictionOutput, _>(
+                    response,
+                    "DeleteBatchPrediction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteBatchPredictionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns the DELETED status to a <code>DataSource</code>, rendering it unusable.</p> <p>After using the <code>DeleteDataSource</code> operation, you can use the <a>GetDataSource</a> operation to verify that the status of the <code>DataSource</code> changed to DELETED.</p> <p><b>Caution:</b> The results of the <code>DeleteDataSource</code> operation are irreversible.</p>
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> RusotoFuture<DeleteDataSourceOutput, DeleteDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteDataSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DeleteDataSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDataSourceOutput, _>(
+                    response,
+                    "DeleteDataSource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Assigns the <code>DELETED</code> status to an <code>Evaluation</code>, rendering it unusable.</p> <p>After invoking the <code>DeleteEvaluation</code> operation, you can use the <code>GetEvaluation</code> operation to verify that the status of the <code>Evaluation</code> changed to <code>DELETED</code>.</p> <caution><title>Caution</title> <p>The results of the <code>DeleteEvaluation</code> operation are irreversible.</p></caution></p>
+    fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> RusotoFuture<DeleteEvaluationOutput, DeleteEvaluationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteEvaluation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DeleteEvaluation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteEvaluationOutput, _>(
+                    response,
+                    "DeleteEvaluation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteEvaluationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns the <code>DELETED</code> status to an <code>MLModel</code>, rendering it unusable.</p> <p>After using the <code>DeleteMLModel</code> operation, you can use the <code>GetMLModel</code> operation to verify that the status of the <code>MLModel</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteMLModel</code> operation is irreversible.</p>
+    fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> RusotoFuture<DeleteMLModelOutput, DeleteMLModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteMLModel");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DeleteMLModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteMLModelOutput, _>(
+                    response,
+                    "DeleteMLModel",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMLModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a real time endpoint of an <code>MLModel</code>.</p>
+    fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> RusotoFuture<DeleteRealtimeEndpointOutput, DeleteRealtimeEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteRealtimeEndpoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DeleteRealtimeEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRealtimeEndpointOutput, _>(
+                    response,
+                    "DeleteRealtimeEndpoint",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRealtimeEndpointError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified tags associated with an ML object. After this operation is complete, you can't recover deleted tags.</p> <p>If you specify a tag that doesn't exist, Amazon ML ignores it.</p>
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DeleteTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DeleteTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTagsOutput, _>(response, "DeleteTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of <code>BatchPrediction</code> operations that match the search criteria in the request.</p>
+    fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> RusotoFuture<DescribeBatchPredictionsOutput, DescribeBatchPredictionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeBatchPredictions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DescribeBatchPredictions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeBatchPredictionsOutput, _>(
+                    response,
+                    "DescribeBatchPredictions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeBatchPredictionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of <code>DataSource</code> that match the search criteria in the request.</p>
+    fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> RusotoFuture<DescribeDataSourcesOutput, DescribeDataSourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeDataSources");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DescribeDataSources");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDataSourcesOutput, _>(
+                    response,
+                    "DescribeDataSources",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeDataSourcesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of <code>DescribeEvaluations</code> that match the search criteria in the request.</p>
+    fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> RusotoFuture<DescribeEvaluationsOutput, DescribeEvaluationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeEvaluations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DescribeEvaluations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEvaluationsOutput, _>(
+                    response,
+                    "DescribeEvaluations",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEvaluationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of <code>MLModel</code> that match the search criteria in the request.</p>
+    fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> RusotoFuture<DescribeMLModelsOutput, DescribeMLModelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeMLModels");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DescribeMLModels");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMLModelsOutput, _>(
+                    response,
+                    "DescribeMLModels",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeMLModelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes one or more of the tags for your Amazon ML object.</p>
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("DescribeTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.DescribeTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTagsOutput, _>(
+                    response,
+                    "DescribeTags",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a <code>BatchPrediction</code> that includes detailed metadata, status, and data file information for a <code>Batch Prediction</code> request.</p>
+    fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> RusotoFuture<GetBatchPredictionOutput, GetBatchPredictionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetBatchPrediction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.GetBatchPrediction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetBatchPredictionOutput, _>(
+                    response,
+                    "GetBatchPrediction",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBatchPredictionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a <code>DataSource</code> that includes metadata and data file information, as well as the current status of the <code>DataSource</code>.</p> <p><code>GetDataSource</code> provides results in normal or verbose format. The verbose format adds the schema description and the list of files pointed to by the DataSource to the normal format.</p>
+    fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> RusotoFuture<GetDataSourceOutput, GetDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetDataSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.GetDataSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDataSourceOutput, _>(
+                    response,
+                    "GetDataSource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
     /// <p>Returns an <code>Evaluation</code> that includes metadata as well as the current status of the <code>Evaluation</code>.</p>
     fn get_evaluation(
         &self,
         input: GetEvaluationInput,
     ) -> RusotoFuture<GetEvaluationOutput, GetEvaluationError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.GetEvaluation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetEvaluationOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetEvaluationError::from_response(response))),
-                )
-            }
-        })
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetEvaluation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.GetEvaluation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetEvaluationOutput, _>(
+                    response,
+                    "GetEvaluation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetEvaluationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an <code>MLModel</code> that includes detailed metadata, data source information, and the current status of the <code>MLModel</code>.</p> <p><code>GetMLModel</code> provides results in normal or verbose format. </p>
+    fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> RusotoFuture<GetMLModelOutput, GetMLModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("GetMLModel");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.GetMLModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMLModelOutput, _>(response, "GetMLModel")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMLModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Generates a prediction for the observation using the specified <code>ML Model</code>.</p> <note><title>Note</title> <p>Not all response parameters will be populated. Whether a response parameter is populated depends on the type of model requested.</p></note></p>
+    fn predict(&self, input: PredictInput) -> RusotoFuture<PredictOutput, PredictError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("Predict");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.Predict");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PredictOutput, _>(response, "Predict")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PredictError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the <code>BatchPredictionName</code> of a <code>BatchPrediction</code>.</p> <p>You can use the <code>GetBatchPrediction</code> operation to view the contents of the updated data element.</p>
+    fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> RusotoFuture<UpdateBatchPredictionOutput, UpdateBatchPredictionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateBatchPrediction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.UpdateBatchPrediction");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateBatchPredictionOutput, _>(
+                    response,
+                    "UpdateBatchPrediction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateBatchPredictionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the <code>DataSourceName</code> of a <code>DataSource</code>.</p> <p>You can use the <code>GetDataSource</code> operation to view the contents of the updated data element.</p>
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> RusotoFuture<UpdateDataSourceOutput, UpdateDataSourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateDataSource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.UpdateDataSource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDataSourceOutput, _>(
+                    response,
+                    "UpdateDataSource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDataSourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the <code>EvaluationName</code> of an <code>Evaluation</code>.</p> <p>You can use the <code>GetEvaluation</code> operation to view the contents of the updated data element.</p>
+    fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> RusotoFuture<UpdateEvaluationOutput, UpdateEvaluationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateEvaluation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.UpdateEvaluation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateEvaluationOutput, _>(
+                    response,
+                    "UpdateEvaluation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateEvaluationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the <code>MLModelName</code> and the <code>ScoreThreshold</code> of an <code>MLModel</code>.</p> <p>You can use the <code>GetMLModel</code> operation to view the contents of the updated data element.</p>
+    fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
+        request.set_operation("UpdateMLModel");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonML_20141212.UpdateMLModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMLModelOutput, _>(
+                    response,
+                    "UpdateMLModel",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateMLModelError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon Machine Learning API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMachineLearning {
+    add_tags: Option<
+        Box<
+            dyn Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_batch_prediction: Option<
+        Box<
+            dyn Fn(
+                    CreateBatchPredictionInput,
+                ) -> ::std::result::Result<
+                    CreateBatchPredictionOutput,
+                    RusotoError<CreateBatchPredictionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_data_source_from_rds: Option<
+        Box<
+            dyn Fn(
+                    CreateDataSourceFromRDSInput,
+                ) -> ::std::result::Result<
+                    CreateDataSourceFromRDSOutput,
+                    RusotoError<CreateDataSourceFromRDSError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_data_source_from_redshift: Option<
+        Box<
+            dyn Fn(
+                    CreateDataSourceFromRedshiftInput,
+                ) -> ::std::result::Result<
+                    CreateDataSourceFromRedshiftOutput,
+                    RusotoError<CreateDataSourceFromRedshiftError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_data_source_from_s3: Option<
+        Box<
+            dyn Fn(
+                    CreateDataSourceFromS3Input,
+                ) -> ::std::result::Result<
+                    CreateDataSourceFromS3Output,
+                    RusotoError<CreateDataSourceFromS3Error>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_evaluation: Option<
+        Box<
+            dyn Fn(
+                    CreateEvaluationInput,
+                ) -> ::std::result::Result<
+                    CreateEvaluationOutput,
+                    RusotoError<CreateEvaluationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_ml_model: Option<
+        Box<
+            dyn Fn(
+                    CreateMLModelInput,
+                )
+                    -> ::std::result::Result<CreateMLModelOutput, RusotoError<CreateMLModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_realtime_endpoint: Option<
+        Box<
+            dyn Fn(
+                    CreateRealtimeEndpointInput,
+                ) -> ::std::result::Result<
+                    CreateRealtimeEndpointOutput,
+                    RusotoError<CreateRealtimeEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_batch_prediction: Option<
+        Box<
+            dyn Fn(
+                    DeleteBatchPredictionInput,
+                ) -> ::std::result::Result<
+                    DeleteBatchPredictionOutput,
+                    RusotoError<DeleteBatchPredictionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_data_source: Option<
+        Box<
+            dyn Fn(
+                    DeleteDataSourceInput,
+                ) -> ::std::result::Result<
+                    DeleteDataSourceOutput,
+                    RusotoError<DeleteDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_evaluation: Option<
+        Box<
+            dyn Fn(
+                    DeleteEvaluationInput,
+                ) -> ::std::result::Result<
+                    DeleteEvaluationOutput,
+                    RusotoError<DeleteEvaluationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_ml_model: Option<
+        Box<
+            dyn Fn(
+                    DeleteMLModelInput,
+                )
+                    -> ::std::result::Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_realtime_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DeleteRealtimeEndpointInput,
+                ) -> ::std::result::Result<
+                    DeleteRealtimeEndpointOutput,
+                    RusotoError<DeleteRealtimeEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(
+                    DeleteTagsInput,
+                )
+                    -> ::std::result::Result<DeleteTagsOutput, RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_batch_predictions: Option<
+        Box<
+            dyn Fn(
+                    DescribeBatchPredictionsInput,
+                ) -> ::std::result::Result<
+                    DescribeBatchPredictionsOutput,
+                    RusotoError<DescribeBatchPredictionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_data_sources: Option<
+        Box<
+            dyn Fn(
+                    DescribeDataSourcesInput,
+                ) -> ::std::result::Result<
+                    DescribeDataSourcesOutput,
+                    RusotoError<DescribeDataSourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_evaluations: Option<
+        Box<
+            dyn Fn(
+                    DescribeEvaluationsInput,
+                ) -> ::std::result::Result<
+                    DescribeEvaluationsOutput,
+                    RusotoError<DescribeEvaluationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_ml_models: Option<
+        Box<
+            dyn Fn(
+                    DescribeMLModelsInput,
+                ) -> ::std::result::Result<
+                    DescribeMLModelsOutput,
+                    RusotoError<DescribeMLModelsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsInput,
+                )
+                    -> ::std::result::Result<DescribeTagsOutput, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_batch_prediction: Option<
+        Box<
+            dyn Fn(
+                    GetBatchPredictionInput,
+                ) -> ::std::result::Result<
+                    GetBatchPredictionOutput,
+                    RusotoError<GetBatchPredictionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_data_source: Option<
+        Box<
+            dyn Fn(
+                    GetDataSourceInput,
+                )
+                    -> ::std::result::Result<GetDataSourceOutput, RusotoError<GetDataSourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_evaluation: Option<
+        Box<
+            dyn Fn(
+                    GetEvaluationInput,
+                )
+                    -> ::std::result::Result<GetEvaluationOutput, RusotoError<GetEvaluationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_ml_model: Option<
+        Box<
+            dyn Fn(
+                    GetMLModelInput,
+                )
+                    -> ::std::result::Result<GetMLModelOutput, RusotoError<GetMLModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    predict: Option<
+        Box<
+            dyn Fn(PredictInput) -> ::std::result::Result<PredictOutput, RusotoError<PredictError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_batch_prediction: Option<
+        Box<
+            dyn Fn(
+                    UpdateBatchPredictionInput,
+                ) -> ::std::result::Result<
+                    UpdateBatchPredictionOutput,
+                    RusotoError<UpdateBatchPredictionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_data_source: Option<
+        Box<
+            dyn Fn(
+                    UpdateDataSourceInput,
+                ) -> ::std::result::Result<
+                    UpdateDataSourceOutput,
+                    RusotoError<UpdateDataSourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_evaluation: Option<
+        Box<
+            dyn Fn(
+                    UpdateEvaluationInput,
+                ) -> ::std::result::Result<
+                    UpdateEvaluationOutput,
+                    RusotoError<UpdateEvaluationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_ml_model: Option<
+        Box<
+            dyn Fn(
+                    UpdateMLModelInput,
+                )
+                    -> ::std::result::Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMachineLearning {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMachineLearning {
+        Default::default()
+    }
+
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_batch_prediction` with what `handler` returns for its input.
+    pub fn with_create_batch_prediction<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateBatchPredictionInput,
+            ) -> ::std::result::Result<
+                CreateBatchPredictionOutput,
+                RusotoError<CreateBatchPredictionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_batch_prediction = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_data_source_from_rds` with what `handler` returns for its input.
+    pub fn with_create_data_source_from_rds<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateDataSourceFromRDSInput,
+            ) -> ::std::result::Result<
+                CreateDataSourceFromRDSOutput,
+                RusotoError<CreateDataSourceFromRDSError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_data_source_from_rds = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_data_source_from_redshift` with what `handler` returns for its input.
+    pub fn with_create_data_source_from_redshift<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateDataSourceFromRedshiftInput,
+            ) -> ::std::result::Result<
+                CreateDataSourceFromRedshiftOutput,
+                RusotoError<CreateDataSourceFromRedshiftError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_data_source_from_redshift = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_data_source_from_s3` with what `handler` returns for its input.
+    pub fn with_create_data_source_from_s3<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateDataSourceFromS3Input,
+            ) -> ::std::result::Result<
+                CreateDataSourceFromS3Output,
+                RusotoError<CreateDataSourceFromS3Error>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_data_source_from_s3 = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_evaluation` with what `handler` returns for its input.
+    pub fn with_create_evaluation<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateEvaluationInput,
+            )
+                -> ::std::result::Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_evaluation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_ml_model` with what `handler` returns for its input.
+    pub fn with_create_ml_model<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateMLModelInput,
+            )
+                -> ::std::result::Result<CreateMLModelOutput, RusotoError<CreateMLModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ml_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_realtime_endpoint` with what `handler` returns for its input.
+    pub fn with_create_realtime_endpoint<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                CreateRealtimeEndpointInput,
+            ) -> ::std::result::Result<
+                CreateRealtimeEndpointOutput,
+                RusotoError<CreateRealtimeEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_realtime_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_batch_prediction` with what `handler` returns for its input.
+    pub fn with_delete_batch_prediction<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DeleteBatchPredictionInput,
+            ) -> ::std::result::Result<
+                DeleteBatchPredictionOutput,
+                RusotoError<DeleteBatchPredictionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_batch_prediction = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_data_source` with what `handler` returns for its input.
+    pub fn with_delete_data_source<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DeleteDataSourceInput,
+            )
+                -> ::std::result::Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_evaluation` with what `handler` returns for its input.
+    pub fn with_delete_evaluation<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DeleteEvaluationInput,
+            )
+                -> ::std::result::Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_evaluation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ml_model` with what `handler` returns for its input.
+    pub fn with_delete_ml_model<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DeleteMLModelInput,
+            )
+                -> ::std::result::Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ml_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_realtime_endpoint` with what `handler` returns for its input.
+    pub fn with_delete_realtime_endpoint<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DeleteRealtimeEndpointInput,
+            ) -> ::std::result::Result<
+                DeleteRealtimeEndpointOutput,
+                RusotoError<DeleteRealtimeEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_realtime_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DeleteTagsInput,
+            ) -> ::std::result::Result<DeleteTagsOutput, RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_batch_predictions` with what `handler` returns for its input.
+    pub fn with_describe_batch_predictions<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DescribeBatchPredictionsInput,
+            ) -> ::std::result::Result<
+                DescribeBatchPredictionsOutput,
+                RusotoError<DescribeBatchPredictionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_batch_predictions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_data_sources` with what `handler` returns for its input.
+    pub fn with_describe_data_sources<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DescribeDataSourcesInput,
+            ) -> ::std::result::Result<
+                DescribeDataSourcesOutput,
+                RusotoError<DescribeDataSourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_data_sources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_evaluations` with what `handler` returns for its input.
+    pub fn with_describe_evaluations<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DescribeEvaluationsInput,
+            ) -> ::std::result::Result<
+                DescribeEvaluationsOutput,
+                RusotoError<DescribeEvaluationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_evaluations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ml_models` with what `handler` returns for its input.
+    pub fn with_describe_ml_models<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DescribeMLModelsInput,
+            )
+                -> ::std::result::Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ml_models = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                DescribeTagsInput,
+            )
+                -> ::std::result::Result<DescribeTagsOutput, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_batch_prediction` with what `handler` returns for its input.
+    pub fn with_get_batch_prediction<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                GetBatchPredictionInput,
+            ) -> ::std::result::Result<
+                GetBatchPredictionOutput,
+                RusotoError<GetBatchPredictionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_batch_prediction = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_data_source` with what `handler` returns for its input.
+    pub fn with_get_data_source<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                GetDataSourceInput,
+            )
+                -> ::std::result::Result<GetDataSourceOutput, RusotoError<GetDataSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_evaluation` with what `handler` returns for its input.
+    pub fn with_get_evaluation<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                GetEvaluationInput,
+            )
+                -> ::std::result::Result<GetEvaluationOutput, RusotoError<GetEvaluationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_evaluation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ml_model` with what `handler` returns for its input.
+    pub fn with_get_ml_model<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                GetMLModelInput,
+            ) -> ::std::result::Result<GetMLModelOutput, RusotoError<GetMLModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ml_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `predict` with what `handler` returns for its input.
+    pub fn with_predict<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(PredictInput) -> ::std::result::Result<PredictOutput, RusotoError<PredictError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.predict = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_batch_prediction` with what `handler` returns for its input.
+    pub fn with_update_batch_prediction<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                UpdateBatchPredictionInput,
+            ) -> ::std::result::Result<
+                UpdateBatchPredictionOutput,
+                RusotoError<UpdateBatchPredictionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_batch_prediction = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_data_source` with what `handler` returns for its input.
+    pub fn with_update_data_source<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                UpdateDataSourceInput,
+            )
+                -> ::std::result::Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_data_source = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_evaluation` with what `handler` returns for its input.
+    pub fn with_update_evaluation<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                UpdateEvaluationInput,
+            )
+                -> ::std::result::Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_evaluation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_ml_model` with what `handler` returns for its input.
+    pub fn with_update_ml_model<F>(mut self, handler: F) -> MockMachineLearning
+    where
+        F: Fn(
+                UpdateMLModelInput,
+            )
+                -> ::std::result::Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_ml_model = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MachineLearning for MockMachineLearning {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for add_tags"),
+        }
+    }
+    fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> RusotoFuture<CreateBatchPredictionOutput, CreateBatchPredictionError> {
+        match self.create_batch_prediction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for create_batch_prediction"),
+        }
+    }
+    fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> RusotoFuture<CreateDataSourceFromRDSOutput, CreateDataSourceFromRDSError> {
+        match self.create_data_source_from_rds {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for create_data_source_from_rds"),
+        }
+    }
+    fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> RusotoFuture<CreateDataSourceFromRedshiftOutput, CreateDataSourceFromRedshiftError> {
+        match self.create_data_source_from_redshift {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockMachineLearning has no handler for create_data_source_from_redshift")
+            }
+        }
+    }
+    fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> RusotoFuture<CreateDataSourceFromS3Output, CreateDataSourceFromS3Error> {
+        match self.create_data_source_from_s3 {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for create_data_source_from_s3"),
+        }
+    }
+    fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> RusotoFuture<CreateEvaluationOutput, CreateEvaluationError> {
+        match self.create_evaluation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for create_evaluation"),
+        }
+    }
+    fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> RusotoFuture<CreateMLModelOutput, CreateMLModelError> {
+        match self.create_ml_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for create_ml_model"),
+        }
+    }
+    fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> RusotoFuture<CreateRealtimeEndpointOutput, CreateRealtimeEndpointError> {
+        match self.create_realtime_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for create_realtime_endpoint"),
+        }
+    }
+    fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> RusotoFuture<DeleteBatchPredictionOutput, DeleteBatchPredictionError> {
+        match self.delete_batch_prediction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for delete_batch_prediction"),
+        }
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> RusotoFuture<DeleteDataSourceOutput, DeleteDataSourceError> {
+        match self.delete_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for delete_data_source"),
+        }
+    }
+    fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> RusotoFuture<DeleteEvaluationOutput, DeleteEvaluationError> {
+        match self.delete_evaluation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for delete_evaluation"),
+        }
+    }
+    fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> RusotoFuture<DeleteMLModelOutput, DeleteMLModelError> {
+        match self.delete_ml_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for delete_ml_model"),
+        }
+    }
+    fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> RusotoFuture<DeleteRealtimeEndpointOutput, DeleteRealtimeEndpointError> {
+        match self.delete_realtime_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for delete_realtime_endpoint"),
+        }
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for delete_tags"),
+        }
+    }
+    fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> RusotoFuture<DescribeBatchPredictionsOutput, DescribeBatchPredictionsError> {
+        match self.describe_batch_predictions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for describe_batch_predictions"),
+        }
+    }
+    fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> RusotoFuture<DescribeDataSourcesOutput, DescribeDataSourcesError> {
+        match self.describe_data_sources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for describe_data_sources"),
+        }
+    }
+    fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> RusotoFuture<DescribeEvaluationsOutput, DescribeEvaluationsError> {
+        match self.describe_evaluations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for describe_evaluations"),
+        }
+    }
+    fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> RusotoFuture<DescribeMLModelsOutput, DescribeMLModelsError> {
+        match self.describe_ml_models {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for describe_ml_models"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for describe_tags"),
+        }
+    }
+    fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> RusotoFuture<GetBatchPredictionOutput, GetBatchPredictionError> {
+        match self.get_batch_prediction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for get_batch_prediction"),
+        }
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> RusotoFuture<GetDataSourceOutput, GetDataSourceError> {
+        match self.get_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for get_data_source"),
+        }
+    }
+    fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> RusotoFuture<GetEvaluationOutput, GetEvaluationError> {
+        match self.get_evaluation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for get_evaluation"),
+        }
     }
-
-    /// <p>Returns an <code>MLModel</code> that includes detailed metadata, data source information, and the current status of the <code>MLModel</code>.</p> <p><code>GetMLModel</code> provides results in normal or verbose format. </p>
     fn get_ml_model(
         &self,
         input: GetMLModelInput,
     ) -> RusotoFuture<GetMLModelOutput, GetMLModelError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.GetMLModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMLModelOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMLModelError::from_response(response))),
-                )
-            }
-        })
+        match self.get_ml_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for get_ml_model"),
+        }
     }
-
-    /// <p><p>Generates a prediction for the observation using the specified <code>ML Model</code>.</p> <note><title>Note</title> <p>Not all response parameters will be populated. Whether a response parameter is populated depends on the type of model requested.</p></note></p>
     fn predict(&self, input: PredictInput) -> RusotoFuture<PredictOutput, PredictError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.Predict");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PredictOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PredictError::from_response(response))),
-                )
-            }
-        })
+        match self.predict {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for predict"),
+        }
     }
-
-    /// <p>Updates the <code>BatchPredictionName</code> of a <code>BatchPrediction</code>.</p> <p>You can use the <code>GetBatchPrediction</code> operation to view the contents of the updated data element.</p>
     fn update_batch_prediction(
         &self,
         input: UpdateBatchPredictionInput,
     ) -> RusotoFuture<UpdateBatchPredictionOutput, UpdateBatchPredictionError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.UpdateBatchPrediction");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBatchPredictionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateBatchPredictionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_batch_prediction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for update_batch_prediction"),
+        }
     }
-
-    /// <p>Updates the <code>DataSourceName</code> of a <code>DataSource</code>.</p> <p>You can use the <code>GetDataSource</code> operation to view the contents of the updated data element.</p>
     fn update_data_source(
         &self,
         input: UpdateDataSourceInput,
     ) -> RusotoFuture<UpdateDataSourceOutput, UpdateDataSourceError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.UpdateDataSource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDataSourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDataSourceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_data_source {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for update_data_source"),
+        }
     }
-
-    /// <p>Updates the <code>EvaluationName</code> of an <code>Evaluation</code>.</p> <p>You can use the <code>GetEvaluation</code> operation to view the contents of the updated data element.</p>
     fn update_evaluation(
         &self,
         input: UpdateEvaluationInput,
     ) -> RusotoFuture<UpdateEvaluationOutput, UpdateEvaluationError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.UpdateEvaluation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateEvaluationOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateEvaluationError::from_response(response))),
-                )
-            }
-        })
+        match self.update_evaluation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for update_evaluation"),
+        }
     }
-
-    /// <p>Updates the <code>MLModelName</code> and the <code>ScoreThreshold</code> of an <code>MLModel</code>.</p> <p>You can use the <code>GetMLModel</code> operation to view the contents of the updated data element.</p>
     fn update_ml_model(
         &self,
         input: UpdateMLModelInput,
     ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError> {
-        let mut request = SignedRequest::new("POST", "machinelearning", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonML_20141212.UpdateMLModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMLModelOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateMLModelError::from_response(response))),
-                )
-            }
-        })
+        match self.update_ml_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMachineLearning has no handler for update_ml_model"),
+        }
     }
 }
@@ -24,6 +24,7 @@ use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateMemberAccountRequest {
     /// <p>The ID of the AWS account that you want to associate with Amazon Macie as a member account.</p>
     #[serde(rename = "memberAccountId")]
@@ -31,6 +32,7 @@ pub struct AssociateMemberAccountRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateS3ResourcesRequest {
     /// <p>The ID of the Amazon Macie member account whose resources you want to associate with Macie. </p>
     #[serde(rename = "memberAccountId")]
@@ -42,7 +44,7 @@ pub struct AssociateS3ResourcesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateS3ResourcesResult {
     /// <p>S3 resources that couldn't be associated with Amazon Macie. An error code and an error message are provided for each failed item. </p>
     #[serde(rename = "failedS3Resources")]
@@ -63,6 +65,7 @@ pub struct ClassificationType {
 
 /// <p>The classification type that Amazon Macie applies to the associated S3 resources. At least one of the classification types (oneTime or continuous) must be specified. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClassificationTypeUpdate {
     /// <p>A continuous classification of the objects that are added to a specified S3 bucket. Amazon Macie begins performing continuous classification after a bucket is successfully associated with Amazon Macie. </p>
     #[serde(rename = "continuous")]
@@ -75,6 +78,7 @@ pub struct ClassificationTypeUpdate {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateMemberAccountRequest {
     /// <p>The ID of the member account that you want to remove from Amazon Macie.</p>
     #[serde(rename = "memberAccountId")]
@@ -82,6 +86,7 @@ pub struct DisassociateMemberAccountRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateS3ResourcesRequest {
     /// <p>The S3 resources (buckets or prefixes) that you want to remove from being monitored and classified by Amazon Macie. </p>
     #[serde(rename = "associatedS3Resources")]
@@ -93,7 +98,7 @@ pub struct DisassociateS3ResourcesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateS3ResourcesResult {
     /// <p>S3 resources that couldn't be removed from being monitored and classified by Amazon Macie. An error code and an error message are provided for each failed item. </p>
     #[serde(rename = "failedS3Resources")]
@@ -103,7 +108,7 @@ pub struct DisassociateS3ResourcesResult {
 
 /// <p>Includes details about the failed S3 resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedS3Resource {
     /// <p>The status code of a failed item.</p>
     #[serde(rename = "errorCode")]
@@ -120,6 +125,7 @@ pub struct FailedS3Resource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMemberAccountsRequest {
     /// <p>Use this parameter to indicate the maximum number of items that you want in the response. The default value is 250. </p>
     #[serde(rename = "maxResults")]
@@ -132,7 +138,7 @@ pub struct ListMemberAccountsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMemberAccountsResult {
     /// <p>A list of the Amazon Macie member accounts returned by the action. The current master account is also included in this list. </p>
     #[serde(rename = "memberAccounts")]
@@ -145,6 +151,7 @@ pub struct ListMemberAccountsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListS3ResourcesRequest {
     /// <p>Use this parameter to indicate the maximum number of items that you want in the response. The default value is 250. </p>
     #[serde(rename = "maxResults")]
@@ -161,7 +168,7 @@ pub struct ListS3ResourcesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListS3ResourcesResult {
     /// <p>When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the nextToken parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null. </p>
     #[serde(rename = "nextToken")]
@@ -175,7 +182,7 @@ pub struct ListS3ResourcesResult {
 
 /// <p>Contains information about the Amazon Macie member account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MemberAccount {
     /// <p>The AWS account ID of the Amazon Macie member account.</p>
     #[serde(rename = "accountId")]
@@ -212,6 +219,7 @@ pub struct S3ResourceClassification {
 
 /// <p>The S3 resources whose classification types you want to update. This data type is used as a request parameter in the UpdateS3Resources action. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3ResourceClassificationUpdate {
     /// <p>The name of the S3 bucket whose classification types you want to update.</p>
     #[serde(rename = "bucketName")]
@@ -226,6 +234,7 @@ pub struct S3ResourceClassificationUpdate {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateS3ResourcesRequest {
     /// <p>The AWS ID of the Amazon Macie member account whose S3 resources' classification types you want to update. </p>
     #[serde(rename = "memberAccountId")]
@@ -237,7 +246,7 @@ pub struct UpdateS3ResourcesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateS3ResourcesResult {
     /// <p>The S3 resources whose classification types can't be updated. An error code and an error message are provided for each failed item. </p>
     #[serde(rename = "failedS3Resources")]
@@ -271,7 +280,12 @@ impl AssociateMemberAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -321,7 +335,12 @@ impl AssociateS3ResourcesError {
                 "LimitExceededException" => {
                     return RusotoError::Service(AssociateS3ResourcesError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -364,7 +383,12 @@ impl DisassociateMemberAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -412,7 +436,12 @@ impl DisassociateS3ResourcesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -452,7 +481,12 @@ impl ListMemberAccountsError {
                 "InvalidInputException" => {
                     return RusotoError::Service(ListMemberAccountsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -496,7 +530,12 @@ impl ListS3ResourcesError {
                 "InvalidInputException" => {
                     return RusotoError::Service(ListS3ResourcesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -541,7 +580,12 @@ impl UpdateS3ResourcesError {
                 "InvalidInputException" => {
                     return RusotoError::Service(UpdateS3ResourcesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -606,6 +650,139 @@ pub trait Macie {
         input: UpdateS3ResourcesRequest,
     ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError>;
 }
+const _: Option<&dyn Macie> = None;
+impl<'a, T: Macie + ?Sized> Macie for &'a T {
+    fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> RusotoFuture<(), AssociateMemberAccountError> {
+        (**self).associate_member_account(input)
+    }
+    fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> RusotoFuture<AssociateS3ResourcesResult, AssociateS3ResourcesError> {
+        (**self).associate_s3_resources(input)
+    }
+    fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> RusotoFuture<(), DisassociateMemberAccountError> {
+        (**self).disassociate_member_account(input)
+    }
+    fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> RusotoFuture<DisassociateS3ResourcesResult, DisassociateS3ResourcesError> {
+        (**self).disassociate_s3_resources(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResult, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> RusotoFuture<ListS3ResourcesResult, ListS3ResourcesError> {
+        (**self).list_s3_resources(input)
+    }
+    fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError> {
+        (**self).update_s3_resources(input)
+    }
+}
+impl<T: Macie + ?Sized> Macie for Box<T> {
+    fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> RusotoFuture<(), AssociateMemberAccountError> {
+        (**self).associate_member_account(input)
+    }
+    fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> RusotoFuture<AssociateS3ResourcesResult, AssociateS3ResourcesError> {
+        (**self).associate_s3_resources(input)
+    }
+    fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> RusotoFuture<(), DisassociateMemberAccountError> {
+        (**self).disassociate_member_account(input)
+    }
+    fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> RusotoFuture<DisassociateS3ResourcesResult, DisassociateS3ResourcesError> {
+        (**self).disassociate_s3_resources(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResult, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> RusotoFuture<ListS3ResourcesResult, ListS3ResourcesError> {
+        (**self).list_s3_resources(input)
+    }
+    fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError> {
+        (**self).update_s3_resources(input)
+    }
+}
+impl<T: Macie + ?Sized> Macie for ::std::sync::Arc<T> {
+    fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> RusotoFuture<(), AssociateMemberAccountError> {
+        (**self).associate_member_account(input)
+    }
+    fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> RusotoFuture<AssociateS3ResourcesResult, AssociateS3ResourcesError> {
+        (**self).associate_s3_resources(input)
+    }
+    fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> RusotoFuture<(), DisassociateMemberAccountError> {
+        (**self).disassociate_member_account(input)
+    }
+    fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> RusotoFuture<DisassociateS3ResourcesResult, DisassociateS3ResourcesError> {
+        (**self).disassociate_s3_resources(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResult, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> RusotoFuture<ListS3ResourcesResult, ListS3ResourcesError> {
+        (**self).list_s3_resources(input)
+    }
+    fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError> {
+        (**self).update_s3_resources(input)
+    }
+}
 /// A client for the Amazon Macie API.
 #[derive(Clone)]
 pub struct MacieClient {
@@ -650,6 +827,7 @@ impl Macie for MacieClient {
         input: AssociateMemberAccountRequest,
     ) -> RusotoFuture<(), AssociateMemberAccountError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("AssociateMemberAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.AssociateMemberAccount");
@@ -675,6 +853,7 @@ impl Macie for MacieClient {
         input: AssociateS3ResourcesRequest,
     ) -> RusotoFuture<AssociateS3ResourcesResult, AssociateS3ResourcesError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("AssociateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.AssociateS3Resources");
@@ -683,10 +862,10 @@ impl Macie for MacieClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateS3ResourcesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<AssociateS3ResourcesResult, _>(
+                    response,
+                    "AssociateS3Resources",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -703,6 +882,7 @@ impl Macie for MacieClient {
         input: DisassociateMemberAccountRequest,
     ) -> RusotoFuture<(), DisassociateMemberAccountError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("DisassociateMemberAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.DisassociateMemberAccount");
@@ -726,6 +906,7 @@ impl Macie for MacieClient {
         input: DisassociateS3ResourcesRequest,
     ) -> RusotoFuture<DisassociateS3ResourcesResult, DisassociateS3ResourcesError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("DisassociateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.DisassociateS3Resources");
@@ -734,10 +915,10 @@ impl Macie for MacieClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateS3ResourcesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateS3ResourcesResult, _>(
+                    response,
+                    "DisassociateS3Resources",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DisassociateS3ResourcesError::from_response(response))
@@ -752,6 +933,7 @@ impl Macie for MacieClient {
         input: ListMemberAccountsRequest,
     ) -> RusotoFuture<ListMemberAccountsResult, ListMemberAccountsError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("ListMemberAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.ListMemberAccounts");
@@ -760,10 +942,10 @@ impl Macie for MacieClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMemberAccountsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListMemberAccountsResult, _>(
+                    response,
+                    "ListMemberAccounts",
+                )
             } else {
                 Box::new(
                     response
@@ -781,6 +963,7 @@ impl Macie for MacieClient {
         input: ListS3ResourcesRequest,
     ) -> RusotoFuture<ListS3ResourcesResult, ListS3ResourcesError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("ListS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.ListS3Resources");
@@ -789,10 +972,10 @@ impl Macie for MacieClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListS3ResourcesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListS3ResourcesResult, _>(
+                    response,
+                    "ListS3Resources",
+                )
             } else {
                 Box::new(
                     response
@@ -810,6 +993,7 @@ impl Macie for MacieClient {
         input: UpdateS3ResourcesRequest,
     ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError> {
         let mut request = SignedRequest::new("POST", "macie", &self.region, "/");
+        request.set_operation("UpdateS3Resources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MacieService.UpdateS3Resources");
@@ -818,10 +1002,10 @@ impl Macie for MacieClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateS3ResourcesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateS3ResourcesResult, _>(
+                    response,
+                    "UpdateS3Resources",
+                )
             } else {
                 Box::new(
                     response
@@ -833,3 +1017,264 @@ impl Macie for MacieClient {
         })
     }
 }
+
+/// A mock of the Amazon Macie API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMacie {
+    associate_member_account: Option<
+        Box<
+            dyn Fn(
+                    AssociateMemberAccountRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AssociateMemberAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    associate_s3_resources: Option<
+        Box<
+            dyn Fn(
+                    AssociateS3ResourcesRequest,
+                ) -> ::std::result::Result<
+                    AssociateS3ResourcesResult,
+                    RusotoError<AssociateS3ResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_member_account: Option<
+        Box<
+            dyn Fn(
+                    DisassociateMemberAccountRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisassociateMemberAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_s3_resources: Option<
+        Box<
+            dyn Fn(
+                    DisassociateS3ResourcesRequest,
+                ) -> ::std::result::Result<
+                    DisassociateS3ResourcesResult,
+                    RusotoError<DisassociateS3ResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_member_accounts: Option<
+        Box<
+            dyn Fn(
+                    ListMemberAccountsRequest,
+                ) -> ::std::result::Result<
+                    ListMemberAccountsResult,
+                    RusotoError<ListMemberAccountsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_s3_resources: Option<
+        Box<
+            dyn Fn(
+                    ListS3ResourcesRequest,
+                ) -> ::std::result::Result<
+                    ListS3ResourcesResult,
+                    RusotoError<ListS3ResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_s3_resources: Option<
+        Box<
+            dyn Fn(
+                    UpdateS3ResourcesRequest,
+                ) -> ::std::result::Result<
+                    UpdateS3ResourcesResult,
+                    RusotoError<UpdateS3ResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMacie {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMacie {
+        Default::default()
+    }
+
+    /// Answers `associate_member_account` with what `handler` returns for its input.
+    pub fn with_associate_member_account<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                AssociateMemberAccountRequest,
+            ) -> ::std::result::Result<(), RusotoError<AssociateMemberAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_member_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_s3_resources` with what `handler` returns for its input.
+    pub fn with_associate_s3_resources<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                AssociateS3ResourcesRequest,
+            ) -> ::std::result::Result<
+                AssociateS3ResourcesResult,
+                RusotoError<AssociateS3ResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_s3_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_member_account` with what `handler` returns for its input.
+    pub fn with_disassociate_member_account<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                DisassociateMemberAccountRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DisassociateMemberAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_member_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_s3_resources` with what `handler` returns for its input.
+    pub fn with_disassociate_s3_resources<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                DisassociateS3ResourcesRequest,
+            ) -> ::std::result::Result<
+                DisassociateS3ResourcesResult,
+                RusotoError<DisassociateS3ResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_s3_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_member_accounts` with what `handler` returns for its input.
+    pub fn with_list_member_accounts<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                ListMemberAccountsRequest,
+            ) -> ::std::result::Result<
+                ListMemberAccountsResult,
+                RusotoError<ListMemberAccountsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_member_accounts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_s3_resources` with what `handler` returns for its input.
+    pub fn with_list_s3_resources<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                ListS3ResourcesRequest,
+            )
+                -> ::std::result::Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_s3_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_s3_resources` with what `handler` returns for its input.
+    pub fn with_update_s3_resources<F>(mut self, handler: F) -> MockMacie
+    where
+        F: Fn(
+                UpdateS3ResourcesRequest,
+            ) -> ::std::result::Result<
+                UpdateS3ResourcesResult,
+                RusotoError<UpdateS3ResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_s3_resources = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Macie for MockMacie {
+    fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> RusotoFuture<(), AssociateMemberAccountError> {
+        match self.associate_member_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for associate_member_account"),
+        }
+    }
+    fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> RusotoFuture<AssociateS3ResourcesResult, AssociateS3ResourcesError> {
+        match self.associate_s3_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for associate_s3_resources"),
+        }
+    }
+    fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> RusotoFuture<(), DisassociateMemberAccountError> {
+        match self.disassociate_member_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for disassociate_member_account"),
+        }
+    }
+    fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> RusotoFuture<DisassociateS3ResourcesResult, DisassociateS3ResourcesError> {
+        match self.disassociate_s3_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for disassociate_s3_resources"),
+        }
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResult, ListMemberAccountsError> {
+        match self.list_member_accounts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for list_member_accounts"),
+        }
+    }
+    fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> RusotoFuture<ListS3ResourcesResult, ListS3ResourcesError> {
+        match self.list_s3_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for list_s3_resources"),
+        }
+    }
+    fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError> {
+        match self.update_s3_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMacie has no handler for update_s3_resources"),
+        }
+    }
+}
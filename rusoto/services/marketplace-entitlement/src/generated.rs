@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An entitlement represents capacity in a product owned by the customer. For example, a customer might own some number of users or seats in an SaaS application or some amount of data capacity in a multi-tenant database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Entitlement {
     /// <p>The customer identifier is a handle to each unique customer in an application. Customer identifiers are obtained through the ResolveCustomer operation in AWS Marketplace Metering Service.</p>
     #[serde(rename = "CustomerIdentifier")]
@@ -51,7 +52,7 @@ pub struct Entitlement {
 
 /// <p>The EntitlementValue represents the amount of capacity that the customer is entitled to for the product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EntitlementValue {
     /// <p>The BooleanValue field will be populated with a boolean value when the entitlement is a boolean type. Otherwise, the field will not be set.</p>
     #[serde(rename = "BooleanValue")]
@@ -73,6 +74,7 @@ pub struct EntitlementValue {
 
 /// <p>The GetEntitlementsRequest contains parameters for the GetEntitlements operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEntitlementsRequest {
     /// <p>Filter is used to return entitlements for a specific customer or for a specific dimension. Filters are described as keys mapped to a lists of values. Filtered requests are <i>unioned</i> for each value in the value list, and then <i>intersected</i> for each filter key.</p>
     #[serde(rename = "Filter")]
@@ -91,9 +93,32 @@ pub struct GetEntitlementsRequest {
     pub product_code: String,
 }
 
+impl Validate for GetEntitlementsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "filter"), key);
+                errors.check_length(&item_path, item.len(), Some(1), None);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_pattern(&field_path(path, "next_token"), &value, "\\S+");
+        }
+        errors.check_length(
+            &field_path(path, "product_code"),
+            self.product_code.chars().count(),
+            Some(1),
+            Some(255),
+        );
+    }
+}
+
 /// <p>The GetEntitlementsRequest contains results from the GetEntitlements operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetEntitlementsResult {
     /// <p>The set of entitlements found through the GetEntitlements operation. If the result contains an empty set of entitlements, NextToken might still be present and should be used.</p>
     #[serde(rename = "Entitlements")]
@@ -131,7 +156,12 @@ impl GetEntitlementsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetEntitlementsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -160,6 +190,31 @@ pub trait MarketplaceEntitlement {
         input: GetEntitlementsRequest,
     ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError>;
 }
+const _: Option<&dyn MarketplaceEntitlement> = None;
+impl<'a, T: MarketplaceEntitlement + ?Sized> MarketplaceEntitlement for &'a T {
+    fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError> {
+        (**self).get_entitlements(input)
+    }
+}
+impl<T: MarketplaceEntitlement + ?Sized> MarketplaceEntitlement for Box<T> {
+    fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError> {
+        (**self).get_entitlements(input)
+    }
+}
+impl<T: MarketplaceEntitlement + ?Sized> MarketplaceEntitlement for ::std::sync::Arc<T> {
+    fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError> {
+        (**self).get_entitlements(input)
+    }
+}
 /// A client for the AWS Marketplace Entitlement Service API.
 #[derive(Clone)]
 pub struct MarketplaceEntitlementClient {
@@ -203,7 +258,16 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         &self,
         input: GetEntitlementsRequest,
     ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("GetEntitlements");
         request.set_endpoint_prefix("entitlement.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPEntitlementService.GetEntitlements");
@@ -212,10 +276,10 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetEntitlementsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<GetEntitlementsResult, _>(
+                    response,
+                    "GetEntitlements",
+                )
             } else {
                 Box::new(
                     response
@@ -227,3 +291,59 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         })
     }
 }
+
+/// A mock of the AWS Marketplace Entitlement Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMarketplaceEntitlement {
+    get_entitlements: Option<
+        Box<
+            dyn Fn(
+                    GetEntitlementsRequest,
+                ) -> ::std::result::Result<
+                    GetEntitlementsResult,
+                    RusotoError<GetEntitlementsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMarketplaceEntitlement {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMarketplaceEntitlement {
+        Default::default()
+    }
+
+    /// Answers `get_entitlements` with what `handler` returns for its input.
+    pub fn with_get_entitlements<F>(mut self, handler: F) -> MockMarketplaceEntitlement
+    where
+        F: Fn(
+                GetEntitlementsRequest,
+            )
+                -> ::std::result::Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_entitlements = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MarketplaceEntitlement for MockMarketplaceEntitlement {
+    fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError> {
+        match self.get_entitlements {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMarketplaceEntitlement has no handler for get_entitlements"),
+        }
+    }
+}
@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Container for the parameters to the GenerateDataSet operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateDataSetRequest {
     /// <p>(Optional) Key-value pairs which will be returned, unmodified, in the Amazon SNS notification message and the data set metadata file. These key-value pairs can be used to correlated responses with tracking information from other systems.</p>
     #[serde(rename = "customerDefinedValues")]
@@ -51,9 +53,50 @@ pub struct GenerateDataSetRequest {
     pub sns_topic_arn: String,
 }
 
+impl Validate for GenerateDataSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.customer_defined_values {
+            errors.check_length(
+                &field_path(path, "customer_defined_values"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "customer_defined_values"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "data_set_type"),
+            self.data_set_type.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "destination_s3_bucket_name"),
+            self.destination_s3_bucket_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_length(
+            &field_path(path, "role_name_arn"),
+            self.role_name_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "sns_topic_arn"),
+            self.sns_topic_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Container for the result of the GenerateDataSet operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenerateDataSetResult {
     /// <p>A unique identifier representing a specific request to the GenerateDataSet operation. This identifier can be used to correlate a request with notifications from the SNS topic.</p>
     #[serde(rename = "dataSetRequestId")]
@@ -63,6 +106,7 @@ pub struct GenerateDataSetResult {
 
 /// <p>Container for the parameters to the StartSupportDataExport operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSupportDataExportRequest {
     /// <p>(Optional) Key-value pairs which will be returned, unmodified, in the Amazon SNS notification message and the data set metadata file.</p>
     #[serde(rename = "customerDefinedValues")]
@@ -89,9 +133,50 @@ pub struct StartSupportDataExportRequest {
     pub sns_topic_arn: String,
 }
 
+impl Validate for StartSupportDataExportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.customer_defined_values {
+            errors.check_length(
+                &field_path(path, "customer_defined_values"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "customer_defined_values"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "data_set_type"),
+            self.data_set_type.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_length(
+            &field_path(path, "destination_s3_bucket_name"),
+            self.destination_s3_bucket_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_length(
+            &field_path(path, "role_name_arn"),
+            self.role_name_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "sns_topic_arn"),
+            self.sns_topic_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Container for the result of the StartSupportDataExport operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSupportDataExportResult {
     /// <p>A unique identifier representing a specific request to the StartSupportDataExport operation. This identifier can be used to correlate a request with notifications from the SNS topic.</p>
     #[serde(rename = "dataSetRequestId")]
@@ -115,7 +200,12 @@ impl GenerateDataSetError {
                         GenerateDataSetError::MarketplaceCommerceAnalytics(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -150,7 +240,12 @@ impl StartSupportDataExportError {
                         StartSupportDataExportError::MarketplaceCommerceAnalytics(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -183,6 +278,51 @@ pub trait MarketplaceCommerceAnalytics {
         input: StartSupportDataExportRequest,
     ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError>;
 }
+const _: Option<&dyn MarketplaceCommerceAnalytics> = None;
+impl<'a, T: MarketplaceCommerceAnalytics + ?Sized> MarketplaceCommerceAnalytics for &'a T {
+    fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> RusotoFuture<GenerateDataSetResult, GenerateDataSetError> {
+        (**self).generate_data_set(input)
+    }
+    fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError> {
+        (**self).start_support_data_export(input)
+    }
+}
+impl<T: MarketplaceCommerceAnalytics + ?Sized> MarketplaceCommerceAnalytics for Box<T> {
+    fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> RusotoFuture<GenerateDataSetResult, GenerateDataSetError> {
+        (**self).generate_data_set(input)
+    }
+    fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError> {
+        (**self).start_support_data_export(input)
+    }
+}
+impl<T: MarketplaceCommerceAnalytics + ?Sized> MarketplaceCommerceAnalytics
+    for ::std::sync::Arc<T>
+{
+    fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> RusotoFuture<GenerateDataSetResult, GenerateDataSetError> {
+        (**self).generate_data_set(input)
+    }
+    fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError> {
+        (**self).start_support_data_export(input)
+    }
+}
 /// A client for the AWS Marketplace Commerce Analytics API.
 #[derive(Clone)]
 pub struct MarketplaceCommerceAnalyticsClient {
@@ -229,8 +369,17 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         &self,
         input: GenerateDataSetRequest,
     ) -> RusotoFuture<GenerateDataSetResult, GenerateDataSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request =
             SignedRequest::new("POST", "marketplacecommerceanalytics", &self.region, "/");
+        request.set_operation("GenerateDataSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -242,10 +391,10 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GenerateDataSetResult, _>()
-                }))
+                proto::json::deserialize_streaming::<GenerateDataSetResult, _>(
+                    response,
+                    "GenerateDataSet",
+                )
             } else {
                 Box::new(
                     response
@@ -262,8 +411,17 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         &self,
         input: StartSupportDataExportRequest,
     ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request =
             SignedRequest::new("POST", "marketplacecommerceanalytics", &self.region, "/");
+        request.set_operation("StartSupportDataExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -275,10 +433,10 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartSupportDataExportResult, _>()
-                }))
+                proto::json::deserialize_streaming::<StartSupportDataExportResult, _>(
+                    response,
+                    "StartSupportDataExport",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -289,3 +447,99 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         })
     }
 }
+
+/// A mock of the AWS Marketplace Commerce Analytics API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMarketplaceCommerceAnalytics {
+    generate_data_set: Option<
+        Box<
+            dyn Fn(
+                    GenerateDataSetRequest,
+                ) -> ::std::result::Result<
+                    GenerateDataSetResult,
+                    RusotoError<GenerateDataSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_support_data_export: Option<
+        Box<
+            dyn Fn(
+                    StartSupportDataExportRequest,
+                ) -> ::std::result::Result<
+                    StartSupportDataExportResult,
+                    RusotoError<StartSupportDataExportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMarketplaceCommerceAnalytics {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMarketplaceCommerceAnalytics {
+        Default::default()
+    }
+
+    /// Answers `generate_data_set` with what `handler` returns for its input.
+    pub fn with_generate_data_set<F>(mut self, handler: F) -> MockMarketplaceCommerceAnalytics
+    where
+        F: Fn(
+                GenerateDataSetRequest,
+            )
+                -> ::std::result::Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_data_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_support_data_export` with what `handler` returns for its input.
+    pub fn with_start_support_data_export<F>(
+        mut self,
+        handler: F,
+    ) -> MockMarketplaceCommerceAnalytics
+    where
+        F: Fn(
+                StartSupportDataExportRequest,
+            ) -> ::std::result::Result<
+                StartSupportDataExportResult,
+                RusotoError<StartSupportDataExportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_support_data_export = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MarketplaceCommerceAnalytics for MockMarketplaceCommerceAnalytics {
+    fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> RusotoFuture<GenerateDataSetResult, GenerateDataSetError> {
+        match self.generate_data_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMarketplaceCommerceAnalytics has no handler for generate_data_set"),
+        }
+    }
+    fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError> {
+        match self.start_support_data_export {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockMarketplaceCommerceAnalytics has no handler for start_support_data_export"
+            ),
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Required when you set (Codec) under (AudioDescriptions)&gt;(CodecSettings) to the value AAC. The service accepts one of two mutually exclusive groups of AAC settings--VBR and CBR. To select one of these modes, set the value of Bitrate control mode (rateControlMode) to &quot;VBR&quot; or &quot;CBR&quot;.  In VBR mode, you control the audio quality with the setting VBR quality (vbrQuality). In CBR mode, you use the setting Bitrate (bitrate). Defaults and valid values depend on the rate control mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -65,6 +66,27 @@ pub struct AacSettings {
     pub vbr_quality: Option<String>,
 }
 
+impl Validate for AacSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(6000),
+                Some(1024000),
+            );
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(8000),
+                Some(96000),
+            );
+        }
+    }
+}
+
 /// <p>Required when you set (Codec) under (AudioDescriptions)&gt;(CodecSettings) to the value AC3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Ac3Settings {
@@ -102,6 +124,30 @@ pub struct Ac3Settings {
     pub sample_rate: Option<i64>,
 }
 
+impl Validate for Ac3Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(64000),
+                Some(640000),
+            );
+        }
+        if let Some(ref value) = self.dialnorm {
+            errors.check_range(&field_path(path, "dialnorm"), *value, Some(1), Some(31));
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(48000),
+                Some(48000),
+            );
+        }
+    }
+}
+
 /// <p>Accelerated transcoding can significantly speed up jobs with long, visually complex content. Outputs that use this feature incur pro-tier pricing. For information about feature limitations, see the AWS Elemental MediaConvert User Guide.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccelerationSettings {
@@ -127,6 +173,25 @@ pub struct AiffSettings {
     pub sample_rate: Option<i64>,
 }
 
+impl Validate for AiffSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bit_depth {
+            errors.check_range(&field_path(path, "bit_depth"), *value, Some(16), Some(24));
+        }
+        if let Some(ref value) = self.channels {
+            errors.check_range(&field_path(path, "channels"), *value, Some(1), Some(64));
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(8000),
+                Some(192000),
+            );
+        }
+    }
+}
+
 /// <p>Settings for ancillary captions source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AncillarySourceSettings {
@@ -144,7 +209,21 @@ pub struct AncillarySourceSettings {
     pub terminate_captions: Option<String>,
 }
 
+impl Validate for AncillarySourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_ancillary_channel_number {
+            errors.check_range(
+                &field_path(path, "source_ancillary_channel_number"),
+                *value,
+                Some(1),
+                Some(4),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateCertificateRequest {
     /// <p>The ARN of the ACM certificate that you want to associate with your MediaConvert resource.</p>
     #[serde(rename = "Arn")]
@@ -152,7 +231,7 @@ pub struct AssociateCertificateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateCertificateResponse {}
 
 /// <p>Audio codec settings (CodecSettings) under (AudioDescriptions) contains the group of settings related to audio encoding. The settings in this group vary depending on the value that you choose for Audio codec (Codec). For each codec enum that you choose, define the corresponding settings object. The following lists the codec enum, settings object pairs. * AAC, AacSettings * MP2, Mp2Settings * WAV, WavSettings * AIFF, AiffSettings * AC3, Ac3Settings * EAC3, Eac3Settings * EAC3_ATMOS, Eac3AtmosSettings</p>
@@ -192,6 +271,32 @@ pub struct AudioCodecSettings {
     pub wav_settings: Option<WavSettings>,
 }
 
+impl Validate for AudioCodecSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aac_settings {
+            value.validate_at(&field_path(path, "aac_settings"), errors);
+        }
+        if let Some(ref value) = self.ac_3_settings {
+            value.validate_at(&field_path(path, "ac_3_settings"), errors);
+        }
+        if let Some(ref value) = self.aiff_settings {
+            value.validate_at(&field_path(path, "aiff_settings"), errors);
+        }
+        if let Some(ref value) = self.eac_3_atmos_settings {
+            value.validate_at(&field_path(path, "eac_3_atmos_settings"), errors);
+        }
+        if let Some(ref value) = self.eac_3_settings {
+            value.validate_at(&field_path(path, "eac_3_settings"), errors);
+        }
+        if let Some(ref value) = self.mp_2_settings {
+            value.validate_at(&field_path(path, "mp_2_settings"), errors);
+        }
+        if let Some(ref value) = self.wav_settings {
+            value.validate_at(&field_path(path, "wav_settings"), errors);
+        }
+    }
+}
+
 /// <p>Description of audio output</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioDescription {
@@ -237,6 +342,33 @@ pub struct AudioDescription {
     pub stream_name: Option<String>,
 }
 
+impl Validate for AudioDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_normalization_settings {
+            value.validate_at(&field_path(path, "audio_normalization_settings"), errors);
+        }
+        if let Some(ref value) = self.audio_type {
+            errors.check_range(&field_path(path, "audio_type"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.codec_settings {
+            value.validate_at(&field_path(path, "codec_settings"), errors);
+        }
+        if let Some(ref value) = self.custom_language_code {
+            errors.check_pattern(
+                &field_path(path, "custom_language_code"),
+                &value,
+                "^[A-Za-z]{2,3}(-[A-Za-z-]+)?$",
+            );
+        }
+        if let Some(ref value) = self.remix_settings {
+            value.validate_at(&field_path(path, "remix_settings"), errors);
+        }
+        if let Some(ref value) = self.stream_name {
+            errors.check_pattern(&field_path(path, "stream_name"), &value, "^[\\w\\s]*$");
+        }
+    }
+}
+
 /// <p>Advanced audio normalization settings. Ignore these settings unless you need to comply with a loudness standard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioNormalizationSettings {
@@ -266,6 +398,19 @@ pub struct AudioNormalizationSettings {
     pub target_lkfs: Option<f64>,
 }
 
+impl Validate for AudioNormalizationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.correction_gate_level {
+            errors.check_range(
+                &field_path(path, "correction_gate_level"),
+                *value,
+                None,
+                Some(0),
+            );
+        }
+    }
+}
+
 /// <p>Selector for Audio</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioSelector {
@@ -311,6 +456,57 @@ pub struct AudioSelector {
     pub tracks: Option<Vec<i64>>,
 }
 
+impl Validate for AudioSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_language_code {
+            errors.check_length(
+                &field_path(path, "custom_language_code"),
+                value.chars().count(),
+                Some(3),
+                Some(3),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_language_code"),
+                &value,
+                "^[A-Za-z]{3}$",
+            );
+        }
+        if let Some(ref value) = self.external_audio_file_input {
+            errors.check_pattern(
+                &field_path(path, "external_audio_file_input"),
+                &value,
+                "^s3://([^\\/]+\\/+)+((([^\\/]*)))|^https?://[^\\/].*[^&]$",
+            );
+        }
+        if let Some(ref value) = self.offset {
+            errors.check_range(&field_path(path, "offset"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.pids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "pids"), i);
+                errors.check_range(&item_path, *item, Some(1), Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.program_selection {
+            errors.check_range(
+                &field_path(path, "program_selection"),
+                *value,
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.remix_settings {
+            value.validate_at(&field_path(path, "remix_settings"), errors);
+        }
+        if let Some(ref value) = self.tracks {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tracks"), i);
+                errors.check_range(&item_path, *item, Some(1), Some(2147483647));
+            }
+        }
+    }
+}
+
 /// <p>Group of Audio Selectors</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioSelectorGroup {
@@ -320,6 +516,17 @@ pub struct AudioSelectorGroup {
     pub audio_selector_names: Option<Vec<String>>,
 }
 
+impl Validate for AudioSelectorGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_selector_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_selector_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), None);
+            }
+        }
+    }
+}
+
 /// <p>Settings for Avail Blanking</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AvailBlanking {
@@ -329,6 +536,20 @@ pub struct AvailBlanking {
     pub avail_blanking_image: Option<String>,
 }
 
+impl Validate for AvailBlanking {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.avail_blanking_image {
+            errors.check_length(
+                &field_path(path, "avail_blanking_image"),
+                value.chars().count(),
+                Some(14),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "avail_blanking_image"), &value, "^((s3://(.*?)\\.(bmp|BMP|png|PNG))|(https?://(.*?)\\.(bmp|BMP|png|PNG)(\\?([^&=]+=[^&]+&)*[^&=]+=[^&]+)?))$");
+        }
+    }
+}
+
 /// <p>Burn-In Destination Settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BurninDestinationSettings {
@@ -406,7 +627,73 @@ pub struct BurninDestinationSettings {
     pub y_position: Option<i64>,
 }
 
+impl Validate for BurninDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.background_opacity {
+            errors.check_range(
+                &field_path(path, "background_opacity"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.font_opacity {
+            errors.check_range(&field_path(path, "font_opacity"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.font_resolution {
+            errors.check_range(
+                &field_path(path, "font_resolution"),
+                *value,
+                Some(96),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.font_size {
+            errors.check_range(&field_path(path, "font_size"), *value, None, Some(96));
+        }
+        if let Some(ref value) = self.outline_size {
+            errors.check_range(&field_path(path, "outline_size"), *value, None, Some(10));
+        }
+        if let Some(ref value) = self.shadow_opacity {
+            errors.check_range(&field_path(path, "shadow_opacity"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.shadow_x_offset {
+            errors.check_range(
+                &field_path(path, "shadow_x_offset"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.shadow_y_offset {
+            errors.check_range(
+                &field_path(path, "shadow_y_offset"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.x_position {
+            errors.check_range(
+                &field_path(path, "x_position"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.y_position {
+            errors.check_range(
+                &field_path(path, "y_position"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelJobRequest {
     /// <p>The Job ID of the job to be cancelled.</p>
     #[serde(rename = "Id")]
@@ -414,7 +701,7 @@ pub struct CancelJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelJobResponse {}
 
 /// <p>Description of Caption output</p>
@@ -442,6 +729,29 @@ pub struct CaptionDescription {
     pub language_description: Option<String>,
 }
 
+impl Validate for CaptionDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.caption_selector_name {
+            errors.check_length(
+                &field_path(path, "caption_selector_name"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.custom_language_code {
+            errors.check_pattern(
+                &field_path(path, "custom_language_code"),
+                &value,
+                "^[A-Za-z]{2,3}(-[A-Za-z-]+)?$",
+            );
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+    }
+}
+
 /// <p>Caption Description for preset</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionDescriptionPreset {
@@ -463,6 +773,21 @@ pub struct CaptionDescriptionPreset {
     pub language_description: Option<String>,
 }
 
+impl Validate for CaptionDescriptionPreset {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_language_code {
+            errors.check_pattern(
+                &field_path(path, "custom_language_code"),
+                &value,
+                "^[A-Za-z]{2,3}(-[A-Za-z-]+)?$",
+            );
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+    }
+}
+
 /// <p>Specific settings required by destination type. Note that burnin<em>destination</em>settings are not available if the source of the caption data is Embedded or Teletext.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionDestinationSettings {
@@ -500,6 +825,23 @@ pub struct CaptionDestinationSettings {
     pub ttml_destination_settings: Option<TtmlDestinationSettings>,
 }
 
+impl Validate for CaptionDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.burnin_destination_settings {
+            value.validate_at(&field_path(path, "burnin_destination_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_sub_destination_settings {
+            value.validate_at(&field_path(path, "dvb_sub_destination_settings"), errors);
+        }
+        if let Some(ref value) = self.embedded_destination_settings {
+            value.validate_at(&field_path(path, "embedded_destination_settings"), errors);
+        }
+        if let Some(ref value) = self.teletext_destination_settings {
+            value.validate_at(&field_path(path, "teletext_destination_settings"), errors);
+        }
+    }
+}
+
 /// <p>Set up captions in your outputs by first selecting them from your input here.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionSelector {
@@ -517,6 +859,27 @@ pub struct CaptionSelector {
     pub source_settings: Option<CaptionSourceSettings>,
 }
 
+impl Validate for CaptionSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.custom_language_code {
+            errors.check_length(
+                &field_path(path, "custom_language_code"),
+                value.chars().count(),
+                Some(3),
+                Some(3),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_language_code"),
+                &value,
+                "^[A-Za-z]{3}$",
+            );
+        }
+        if let Some(ref value) = self.source_settings {
+            value.validate_at(&field_path(path, "source_settings"), errors);
+        }
+    }
+}
+
 /// <p>If your input captions are SCC, TTML, STL, SMI, SRT, or IMSC in an xml file, specify the URI of the input captions source file. If your input captions are IMSC in an IMF package, use TrackSourceSettings instead of FileSoureSettings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionSourceSettings {
@@ -550,6 +913,29 @@ pub struct CaptionSourceSettings {
     pub track_source_settings: Option<TrackSourceSettings>,
 }
 
+impl Validate for CaptionSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ancillary_source_settings {
+            value.validate_at(&field_path(path, "ancillary_source_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_sub_source_settings {
+            value.validate_at(&field_path(path, "dvb_sub_source_settings"), errors);
+        }
+        if let Some(ref value) = self.embedded_source_settings {
+            value.validate_at(&field_path(path, "embedded_source_settings"), errors);
+        }
+        if let Some(ref value) = self.file_source_settings {
+            value.validate_at(&field_path(path, "file_source_settings"), errors);
+        }
+        if let Some(ref value) = self.teletext_source_settings {
+            value.validate_at(&field_path(path, "teletext_source_settings"), errors);
+        }
+        if let Some(ref value) = self.track_source_settings {
+            value.validate_at(&field_path(path, "track_source_settings"), errors);
+        }
+    }
+}
+
 /// <p>Channel mapping (ChannelMapping) contains the group of fields that hold the remixing value for each channel. Units are in dB. Acceptable values are within the range from -60 (mute) through 6. A setting of 0 passes the input channel unchanged to the output channel (no attenuation or amplification).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChannelMapping {
@@ -559,6 +945,17 @@ pub struct ChannelMapping {
     pub output_channels: Option<Vec<OutputChannelMapping>>,
 }
 
+impl Validate for ChannelMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.output_channels {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "output_channels"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Settings for CMAF encryption</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CmafEncryptionSettings {
@@ -588,6 +985,30 @@ pub struct CmafEncryptionSettings {
     pub type_: Option<String>,
 }
 
+impl Validate for CmafEncryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.constant_initialization_vector {
+            errors.check_length(
+                &field_path(path, "constant_initialization_vector"),
+                value.chars().count(),
+                Some(32),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "constant_initialization_vector"),
+                &value,
+                "^[0-9a-fA-F]{32}$",
+            );
+        }
+        if let Some(ref value) = self.speke_key_provider {
+            value.validate_at(&field_path(path, "speke_key_provider"), errors);
+        }
+        if let Some(ref value) = self.static_key_provider {
+            value.validate_at(&field_path(path, "static_key_provider"), errors);
+        }
+    }
+}
+
 /// <p>Required when you set (Type) under (OutputGroups)&gt;(OutputGroupSettings) to CMAF<em>GROUP</em>SETTINGS. Each output in a CMAF Output Group may only contain a single video, audio, or caption output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CmafGroupSettings {
@@ -657,6 +1078,44 @@ pub struct CmafGroupSettings {
     pub write_hls_manifest: Option<String>,
 }
 
+impl Validate for CmafGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination {
+            errors.check_pattern(&field_path(path, "destination"), &value, "^s3:\\/\\/");
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.fragment_length {
+            errors.check_range(
+                &field_path(path, "fragment_length"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.min_buffer_time {
+            errors.check_range(
+                &field_path(path, "min_buffer_time"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.segment_length {
+            errors.check_range(
+                &field_path(path, "segment_length"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>Settings for color correction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColorCorrector {
@@ -686,6 +1145,26 @@ pub struct ColorCorrector {
     pub saturation: Option<i64>,
 }
 
+impl Validate for ColorCorrector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.brightness {
+            errors.check_range(&field_path(path, "brightness"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.contrast {
+            errors.check_range(&field_path(path, "contrast"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.hdr_10_metadata {
+            value.validate_at(&field_path(path, "hdr_10_metadata"), errors);
+        }
+        if let Some(ref value) = self.hue {
+            errors.check_range(&field_path(path, "hue"), *value, None, Some(180));
+        }
+        if let Some(ref value) = self.saturation {
+            errors.check_range(&field_path(path, "saturation"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 /// <p>Container specific settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerSettings {
@@ -715,7 +1194,19 @@ pub struct ContainerSettings {
     pub mp_4_settings: Option<Mp4Settings>,
 }
 
+impl Validate for ContainerSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.m_2ts_settings {
+            value.validate_at(&field_path(path, "m_2ts_settings"), errors);
+        }
+        if let Some(ref value) = self.m_3u_8_settings {
+            value.validate_at(&field_path(path, "m_3u_8_settings"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobRequest {
     /// <p>Accelerated transcoding can significantly speed up jobs with long, visually complex content. Outputs that use this feature incur pro-tier pricing. For information about feature limitations, see the AWS Elemental MediaConvert User Guide.</p>
     #[serde(rename = "AccelerationSettings")]
@@ -761,8 +1252,18 @@ pub struct CreateJobRequest {
     pub user_metadata: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, None, Some(50));
+        }
+        self.settings
+            .validate_at(&field_path(path, "settings"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateJobResponse {
     /// <p>Each job converts an input file into an output file or files. For more information, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
     #[serde(rename = "Job")]
@@ -771,6 +1272,7 @@ pub struct CreateJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobTemplateRequest {
     /// <p>Accelerated transcoding can significantly speed up jobs with long, visually complex content. Outputs that use this feature incur pro-tier pricing. For information about feature limitations, see the AWS Elemental MediaConvert User Guide.</p>
     #[serde(rename = "AccelerationSettings")]
@@ -808,8 +1310,18 @@ pub struct CreateJobTemplateRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateJobTemplateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, None, Some(50));
+        }
+        self.settings
+            .validate_at(&field_path(path, "settings"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateJobTemplateResponse {
     /// <p>A job template is a pre-made set of encoding instructions that you can use to quickly create a job.</p>
     #[serde(rename = "JobTemplate")]
@@ -818,6 +1330,7 @@ pub struct CreateJobTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePresetRequest {
     /// <p>Optional. A category for the preset you are creating.</p>
     #[serde(rename = "Category")]
@@ -839,8 +1352,15 @@ pub struct CreatePresetRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreatePresetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.settings
+            .validate_at(&field_path(path, "settings"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePresetResponse {
     /// <p>A preset is a collection of preconfigured media conversion settings that you want MediaConvert to apply to the output during the conversion process.</p>
     #[serde(rename = "Preset")]
@@ -849,6 +1369,7 @@ pub struct CreatePresetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateQueueRequest {
     /// <p>Optional. A description of the queue that you are creating.</p>
     #[serde(rename = "Description")]
@@ -876,7 +1397,7 @@ pub struct CreateQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateQueueResponse {
     /// <p>You can use queues to manage the resources that are available to your AWS account for running multiple transcoding jobs at the same time. If you don&#39;t specify a queue, the service sends all jobs through the default queue. For more information, see https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html.</p>
     #[serde(rename = "Queue")]
@@ -897,6 +1418,14 @@ pub struct DashIsoEncryptionSettings {
     pub speke_key_provider: Option<SpekeKeyProvider>,
 }
 
+impl Validate for DashIsoEncryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.speke_key_provider {
+            value.validate_at(&field_path(path, "speke_key_provider"), errors);
+        }
+    }
+}
+
 /// <p>Required when you set (Type) under (OutputGroups)&gt;(OutputGroupSettings) to DASH<em>ISO</em>GROUP_SETTINGS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DashIsoGroupSettings {
@@ -942,6 +1471,44 @@ pub struct DashIsoGroupSettings {
     pub write_segment_timeline_in_representation: Option<String>,
 }
 
+impl Validate for DashIsoGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination {
+            errors.check_pattern(&field_path(path, "destination"), &value, "^s3:\\/\\/");
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.fragment_length {
+            errors.check_range(
+                &field_path(path, "fragment_length"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.min_buffer_time {
+            errors.check_range(
+                &field_path(path, "min_buffer_time"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.segment_length {
+            errors.check_range(
+                &field_path(path, "segment_length"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>Settings for deinterlacer</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Deinterlacer {
@@ -962,6 +1529,7 @@ pub struct Deinterlacer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteJobTemplateRequest {
     /// <p>The name of the job template to be deleted.</p>
     #[serde(rename = "Name")]
@@ -969,10 +1537,11 @@ pub struct DeleteJobTemplateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteJobTemplateResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePresetRequest {
     /// <p>The name of the preset to be deleted.</p>
     #[serde(rename = "Name")]
@@ -980,10 +1549,11 @@ pub struct DeletePresetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePresetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteQueueRequest {
     /// <p>The name of the queue that you want to delete.</p>
     #[serde(rename = "Name")]
@@ -991,11 +1561,12 @@ pub struct DeleteQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteQueueResponse {}
 
 /// <p>DescribeEndpointsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndpointsRequest {
     /// <p>Optional. Max number of endpoints, up to twenty, that will be returned at one time.</p>
     #[serde(rename = "MaxResults")]
@@ -1012,7 +1583,7 @@ pub struct DescribeEndpointsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEndpointsResponse {
     /// <p>List of endpoints</p>
     #[serde(rename = "Endpoints")]
@@ -1033,7 +1604,16 @@ pub struct DestinationSettings {
     pub s3_settings: Option<S3DestinationSettings>,
 }
 
+impl Validate for DestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_settings {
+            value.validate_at(&field_path(path, "s3_settings"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateCertificateRequest {
     /// <p>The ARN of the ACM certificate that you want to disassociate from your MediaConvert resource.</p>
     #[serde(rename = "Arn")]
@@ -1041,7 +1621,7 @@ pub struct DisassociateCertificateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateCertificateResponse {}
 
 /// <p>Inserts DVB Network Information Table (NIT) at the specified table repetition interval.</p>
@@ -1061,6 +1641,30 @@ pub struct DvbNitSettings {
     pub nit_interval: Option<i64>,
 }
 
+impl Validate for DvbNitSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.network_id {
+            errors.check_range(&field_path(path, "network_id"), *value, None, Some(65535));
+        }
+        if let Some(ref value) = self.network_name {
+            errors.check_length(
+                &field_path(path, "network_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.nit_interval {
+            errors.check_range(
+                &field_path(path, "nit_interval"),
+                *value,
+                Some(25),
+                Some(10000),
+            );
+        }
+    }
+}
+
 /// <p>Inserts DVB Service Description Table (NIT) at the specified table repetition interval.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbSdtSettings {
@@ -1082,6 +1686,35 @@ pub struct DvbSdtSettings {
     pub service_provider_name: Option<String>,
 }
 
+impl Validate for DvbSdtSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sdt_interval {
+            errors.check_range(
+                &field_path(path, "sdt_interval"),
+                *value,
+                Some(25),
+                Some(2000),
+            );
+        }
+        if let Some(ref value) = self.service_name {
+            errors.check_length(
+                &field_path(path, "service_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.service_provider_name {
+            errors.check_length(
+                &field_path(path, "service_provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>DVB-Sub Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbSubDestinationSettings {
@@ -1159,6 +1792,71 @@ pub struct DvbSubDestinationSettings {
     pub y_position: Option<i64>,
 }
 
+impl Validate for DvbSubDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.background_opacity {
+            errors.check_range(
+                &field_path(path, "background_opacity"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.font_opacity {
+            errors.check_range(&field_path(path, "font_opacity"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.font_resolution {
+            errors.check_range(
+                &field_path(path, "font_resolution"),
+                *value,
+                Some(96),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.font_size {
+            errors.check_range(&field_path(path, "font_size"), *value, None, Some(96));
+        }
+        if let Some(ref value) = self.outline_size {
+            errors.check_range(&field_path(path, "outline_size"), *value, None, Some(10));
+        }
+        if let Some(ref value) = self.shadow_opacity {
+            errors.check_range(&field_path(path, "shadow_opacity"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.shadow_x_offset {
+            errors.check_range(
+                &field_path(path, "shadow_x_offset"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.shadow_y_offset {
+            errors.check_range(
+                &field_path(path, "shadow_y_offset"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.x_position {
+            errors.check_range(
+                &field_path(path, "x_position"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.y_position {
+            errors.check_range(
+                &field_path(path, "y_position"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>DVB Sub Source Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbSubSourceSettings {
@@ -1168,6 +1866,14 @@ pub struct DvbSubSourceSettings {
     pub pid: Option<i64>,
 }
 
+impl Validate for DvbSubSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pid {
+            errors.check_range(&field_path(path, "pid"), *value, Some(1), Some(2147483647));
+        }
+    }
+}
+
 /// <p>Inserts DVB Time and Date Table (TDT) at the specified table repetition interval.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbTdtSettings {
@@ -1177,6 +1883,19 @@ pub struct DvbTdtSettings {
     pub tdt_interval: Option<i64>,
 }
 
+impl Validate for DvbTdtSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tdt_interval {
+            errors.check_range(
+                &field_path(path, "tdt_interval"),
+                *value,
+                Some(1000),
+                Some(30000),
+            );
+        }
+    }
+}
+
 /// <p>Required when you set (Codec) under (AudioDescriptions)&gt;(CodecSettings) to the value EAC3_ATMOS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Eac3AtmosSettings {
@@ -1246,6 +1965,35 @@ pub struct Eac3AtmosSettings {
     pub surround_ex_mode: Option<String>,
 }
 
+impl Validate for Eac3AtmosSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(384000),
+                Some(1024000),
+            );
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(48000),
+                Some(48000),
+            );
+        }
+        if let Some(ref value) = self.speech_threshold {
+            errors.check_range(
+                &field_path(path, "speech_threshold"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Required when you set (Codec) under (AudioDescriptions)&gt;(CodecSettings) to the value EAC3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Eac3Settings {
@@ -1335,6 +2083,30 @@ pub struct Eac3Settings {
     pub surround_mode: Option<String>,
 }
 
+impl Validate for Eac3Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(32000),
+                Some(3024000),
+            );
+        }
+        if let Some(ref value) = self.dialnorm {
+            errors.check_range(&field_path(path, "dialnorm"), *value, Some(1), Some(31));
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(48000),
+                Some(48000),
+            );
+        }
+    }
+}
+
 /// <p>Settings specific to embedded/ancillary caption outputs, including 608/708 Channel destination number.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmbeddedDestinationSettings {
@@ -1348,6 +2120,27 @@ pub struct EmbeddedDestinationSettings {
     pub destination_708_service_number: Option<i64>,
 }
 
+impl Validate for EmbeddedDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_608_channel_number {
+            errors.check_range(
+                &field_path(path, "destination_608_channel_number"),
+                *value,
+                Some(1),
+                Some(4),
+            );
+        }
+        if let Some(ref value) = self.destination_708_service_number {
+            errors.check_range(
+                &field_path(path, "destination_708_service_number"),
+                *value,
+                Some(1),
+                Some(6),
+            );
+        }
+    }
+}
+
 /// <p>Settings for embedded captions Source</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmbeddedSourceSettings {
@@ -1369,9 +2162,30 @@ pub struct EmbeddedSourceSettings {
     pub terminate_captions: Option<String>,
 }
 
+impl Validate for EmbeddedSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_608_channel_number {
+            errors.check_range(
+                &field_path(path, "source_608_channel_number"),
+                *value,
+                Some(1),
+                Some(4),
+            );
+        }
+        if let Some(ref value) = self.source_608_track_number {
+            errors.check_range(
+                &field_path(path, "source_608_track_number"),
+                *value,
+                Some(1),
+                Some(1),
+            );
+        }
+    }
+}
+
 /// <p>Describes an account-specific API endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Endpoint {
     /// <p>URL of endpoint</p>
     #[serde(rename = "Url")]
@@ -1388,6 +2202,18 @@ pub struct EsamManifestConfirmConditionNotification {
     pub mcc_xml: Option<String>,
 }
 
+impl Validate for EsamManifestConfirmConditionNotification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.mcc_xml {
+            errors.check_pattern(
+                &field_path(path, "mcc_xml"),
+                &value,
+                "^\\s*<(.|\\n)*ManifestConfirmConditionNotification(.|\\n)*>\\s*$",
+            );
+        }
+    }
+}
+
 /// <p>Settings for Event Signaling And Messaging (ESAM). If you don&#39;t do ad insertion, you can ignore these settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EsamSettings {
@@ -1405,6 +2231,28 @@ pub struct EsamSettings {
     pub signal_processing_notification: Option<EsamSignalProcessingNotification>,
 }
 
+impl Validate for EsamSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.manifest_confirm_condition_notification {
+            value.validate_at(
+                &field_path(path, "manifest_confirm_condition_notification"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.response_signal_preroll {
+            errors.check_range(
+                &field_path(path, "response_signal_preroll"),
+                *value,
+                None,
+                Some(30000),
+            );
+        }
+        if let Some(ref value) = self.signal_processing_notification {
+            value.validate_at(&field_path(path, "signal_processing_notification"), errors);
+        }
+    }
+}
+
 /// <p>ESAM SignalProcessingNotification data defined by OC-SP-ESAM-API-I03-131025.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EsamSignalProcessingNotification {
@@ -1414,6 +2262,18 @@ pub struct EsamSignalProcessingNotification {
     pub scc_xml: Option<String>,
 }
 
+impl Validate for EsamSignalProcessingNotification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.scc_xml {
+            errors.check_pattern(
+                &field_path(path, "scc_xml"),
+                &value,
+                "^\\s*<(.|\\n)*SignalProcessingNotification(.|\\n)*>\\s*$",
+            );
+        }
+    }
+}
+
 /// <p>Settings for F4v container</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct F4vSettings {
@@ -1436,6 +2296,17 @@ pub struct FileGroupSettings {
     pub destination_settings: Option<DestinationSettings>,
 }
 
+impl Validate for FileGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination {
+            errors.check_pattern(&field_path(path, "destination"), &value, "^s3:\\/\\/");
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+    }
+}
+
 /// <p>If your input captions are SCC, SMI, SRT, STL, TTML, or IMSC 1.1 in an xml file, specify the URI of the input caption source file. If your caption source is IMSC in an IMF package, use TrackSourceSettings instead of FileSoureSettings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileSourceSettings {
@@ -1453,6 +2324,28 @@ pub struct FileSourceSettings {
     pub time_delta: Option<i64>,
 }
 
+impl Validate for FileSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_file {
+            errors.check_length(
+                &field_path(path, "source_file"),
+                value.chars().count(),
+                Some(14),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "source_file"), &value, "^((s3://(.*?)\\.(scc|SCC|ttml|TTML|dfxp|DFXP|stl|STL|srt|SRT|xml|XML|smi|SMI|vtt|VTT|webvtt|WEBVTT))|(https?://(.*?)\\.(scc|SCC|ttml|TTML|dfxp|DFXP|stl|STL|srt|SRT|xml|XML|smi|SMI|vtt|VTT|webvtt|WEBVTT)(\\?([^&=]+=[^&]+&)*[^&=]+=[^&]+)?))$");
+        }
+        if let Some(ref value) = self.time_delta {
+            errors.check_range(
+                &field_path(path, "time_delta"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>Required when you set (Codec) under (VideoDescription)&gt;(CodecSettings) to the value FRAME_CAPTURE.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FrameCaptureSettings {
@@ -1474,15 +2367,48 @@ pub struct FrameCaptureSettings {
     pub quality: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetJobRequest {
-    /// <p>the job ID of the job.</p>
-    #[serde(rename = "Id")]
-    pub id: String,
-}
+impl Validate for FrameCaptureSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.max_captures {
+            errors.check_range(
+                &field_path(path, "max_captures"),
+                *value,
+                Some(1),
+                Some(10000000),
+            );
+        }
+        if let Some(ref value) = self.quality {
+            errors.check_range(&field_path(path, "quality"), *value, Some(1), Some(100));
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
+pub struct GetJobRequest {
+    /// <p>the job ID of the job.</p>
+    #[serde(rename = "Id")]
+    pub id: String,
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobResponse {
     /// <p>Each job converts an input file into an output file or files. For more information, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
     #[serde(rename = "Job")]
@@ -1491,6 +2417,7 @@ pub struct GetJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobTemplateRequest {
     /// <p>The name of the job template.</p>
     #[serde(rename = "Name")]
@@ -1498,7 +2425,7 @@ pub struct GetJobTemplateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobTemplateResponse {
     /// <p>A job template is a pre-made set of encoding instructions that you can use to quickly create a job.</p>
     #[serde(rename = "JobTemplate")]
@@ -1507,6 +2434,7 @@ pub struct GetJobTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPresetRequest {
     /// <p>The name of the preset.</p>
     #[serde(rename = "Name")]
@@ -1514,7 +2442,7 @@ pub struct GetPresetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPresetResponse {
     /// <p>A preset is a collection of preconfigured media conversion settings that you want MediaConvert to apply to the output during the conversion process.</p>
     #[serde(rename = "Preset")]
@@ -1523,6 +2451,7 @@ pub struct GetPresetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueueRequest {
     /// <p>The name of the queue that you want information about.</p>
     #[serde(rename = "Name")]
@@ -1530,7 +2459,7 @@ pub struct GetQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetQueueResponse {
     /// <p>You can use queues to manage the resources that are available to your AWS account for running multiple transcoding jobs at the same time. If you don&#39;t specify a queue, the service sends all jobs through the default queue. For more information, see https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html.</p>
     #[serde(rename = "Queue")]
@@ -1551,6 +2480,27 @@ pub struct H264QvbrSettings {
     pub qvbr_quality_level: Option<i64>,
 }
 
+impl Validate for H264QvbrSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_average_bitrate {
+            errors.check_range(
+                &field_path(path, "max_average_bitrate"),
+                *value,
+                Some(1000),
+                Some(1152000000),
+            );
+        }
+        if let Some(ref value) = self.qvbr_quality_level {
+            errors.check_range(
+                &field_path(path, "qvbr_quality_level"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+    }
+}
+
 /// <p>Required when you set (Codec) under (VideoDescription)&gt;(CodecSettings) to the value H_264.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct H264Settings {
@@ -1714,6 +2664,111 @@ pub struct H264Settings {
     pub unregistered_sei_timecode: Option<String>,
 }
 
+impl Validate for H264Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(1000),
+                Some(1152000000),
+            );
+        }
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.gop_closed_cadence {
+            errors.check_range(
+                &field_path(path, "gop_closed_cadence"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hrd_buffer_initial_fill_percentage {
+            errors.check_range(
+                &field_path(path, "hrd_buffer_initial_fill_percentage"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.hrd_buffer_size {
+            errors.check_range(
+                &field_path(path, "hrd_buffer_size"),
+                *value,
+                None,
+                Some(1152000000),
+            );
+        }
+        if let Some(ref value) = self.max_bitrate {
+            errors.check_range(
+                &field_path(path, "max_bitrate"),
+                *value,
+                Some(1000),
+                Some(1152000000),
+            );
+        }
+        if let Some(ref value) = self.min_i_interval {
+            errors.check_range(&field_path(path, "min_i_interval"), *value, None, Some(30));
+        }
+        if let Some(ref value) = self.number_b_frames_between_reference_frames {
+            errors.check_range(
+                &field_path(path, "number_b_frames_between_reference_frames"),
+                *value,
+                None,
+                Some(7),
+            );
+        }
+        if let Some(ref value) = self.number_reference_frames {
+            errors.check_range(
+                &field_path(path, "number_reference_frames"),
+                *value,
+                Some(1),
+                Some(6),
+            );
+        }
+        if let Some(ref value) = self.par_denominator {
+            errors.check_range(
+                &field_path(path, "par_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.par_numerator {
+            errors.check_range(
+                &field_path(path, "par_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.qvbr_settings {
+            value.validate_at(&field_path(path, "qvbr_settings"), errors);
+        }
+        if let Some(ref value) = self.slices {
+            errors.check_range(&field_path(path, "slices"), *value, Some(1), Some(32));
+        }
+        if let Some(ref value) = self.softness {
+            errors.check_range(&field_path(path, "softness"), *value, None, Some(128));
+        }
+    }
+}
+
 /// <p>Settings for quality-defined variable bitrate encoding with the H.265 codec. Required when you set Rate control mode to QVBR. Not valid when you set Rate control mode to a value other than QVBR, or when you don&#39;t define Rate control mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct H265QvbrSettings {
@@ -1727,6 +2782,27 @@ pub struct H265QvbrSettings {
     pub qvbr_quality_level: Option<i64>,
 }
 
+impl Validate for H265QvbrSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_average_bitrate {
+            errors.check_range(
+                &field_path(path, "max_average_bitrate"),
+                *value,
+                Some(1000),
+                Some(1466400000),
+            );
+        }
+        if let Some(ref value) = self.qvbr_quality_level {
+            errors.check_range(
+                &field_path(path, "qvbr_quality_level"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+    }
+}
+
 /// <p>Settings for H265 codec</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct H265Settings {
@@ -1888,6 +2964,108 @@ pub struct H265Settings {
     pub write_mp_4_packaging_type: Option<String>,
 }
 
+impl Validate for H265Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(1000),
+                Some(1466400000),
+            );
+        }
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.gop_closed_cadence {
+            errors.check_range(
+                &field_path(path, "gop_closed_cadence"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hrd_buffer_initial_fill_percentage {
+            errors.check_range(
+                &field_path(path, "hrd_buffer_initial_fill_percentage"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.hrd_buffer_size {
+            errors.check_range(
+                &field_path(path, "hrd_buffer_size"),
+                *value,
+                None,
+                Some(1466400000),
+            );
+        }
+        if let Some(ref value) = self.max_bitrate {
+            errors.check_range(
+                &field_path(path, "max_bitrate"),
+                *value,
+                Some(1000),
+                Some(1466400000),
+            );
+        }
+        if let Some(ref value) = self.min_i_interval {
+            errors.check_range(&field_path(path, "min_i_interval"), *value, None, Some(30));
+        }
+        if let Some(ref value) = self.number_b_frames_between_reference_frames {
+            errors.check_range(
+                &field_path(path, "number_b_frames_between_reference_frames"),
+                *value,
+                None,
+                Some(7),
+            );
+        }
+        if let Some(ref value) = self.number_reference_frames {
+            errors.check_range(
+                &field_path(path, "number_reference_frames"),
+                *value,
+                Some(1),
+                Some(6),
+            );
+        }
+        if let Some(ref value) = self.par_denominator {
+            errors.check_range(
+                &field_path(path, "par_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.par_numerator {
+            errors.check_range(
+                &field_path(path, "par_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.qvbr_settings {
+            value.validate_at(&field_path(path, "qvbr_settings"), errors);
+        }
+        if let Some(ref value) = self.slices {
+            errors.check_range(&field_path(path, "slices"), *value, Some(1), Some(32));
+        }
+    }
+}
+
 /// <p>Use these settings to specify static color calibration metadata, as defined by SMPTE ST 2086. These values don&#39;t affect the pixel values that are encoded in the video stream. They are intended to help the downstream video player display content in a way that reflects the intentions of the the content creator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Hdr10Metadata {
@@ -1941,6 +3119,107 @@ pub struct Hdr10Metadata {
     pub white_point_y: Option<i64>,
 }
 
+impl Validate for Hdr10Metadata {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.blue_primary_x {
+            errors.check_range(
+                &field_path(path, "blue_primary_x"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.blue_primary_y {
+            errors.check_range(
+                &field_path(path, "blue_primary_y"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.green_primary_x {
+            errors.check_range(
+                &field_path(path, "green_primary_x"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.green_primary_y {
+            errors.check_range(
+                &field_path(path, "green_primary_y"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.max_content_light_level {
+            errors.check_range(
+                &field_path(path, "max_content_light_level"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.max_frame_average_light_level {
+            errors.check_range(
+                &field_path(path, "max_frame_average_light_level"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.max_luminance {
+            errors.check_range(
+                &field_path(path, "max_luminance"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.min_luminance {
+            errors.check_range(
+                &field_path(path, "min_luminance"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.red_primary_x {
+            errors.check_range(
+                &field_path(path, "red_primary_x"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.red_primary_y {
+            errors.check_range(
+                &field_path(path, "red_primary_y"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.white_point_x {
+            errors.check_range(
+                &field_path(path, "white_point_x"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+        if let Some(ref value) = self.white_point_y {
+            errors.check_range(
+                &field_path(path, "white_point_y"),
+                *value,
+                None,
+                Some(50000),
+            );
+        }
+    }
+}
+
 /// <p>Caption Language Mapping</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsCaptionLanguageMapping {
@@ -1962,6 +3241,32 @@ pub struct HlsCaptionLanguageMapping {
     pub language_description: Option<String>,
 }
 
+impl Validate for HlsCaptionLanguageMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.caption_channel {
+            errors.check_range(
+                &field_path(path, "caption_channel"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.custom_language_code {
+            errors.check_length(
+                &field_path(path, "custom_language_code"),
+                value.chars().count(),
+                Some(3),
+                Some(3),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_language_code"),
+                &value,
+                "^[A-Za-z]{3}$",
+            );
+        }
+    }
+}
+
 /// <p>Settings for HLS encryption</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsEncryptionSettings {
@@ -1995,6 +3300,30 @@ pub struct HlsEncryptionSettings {
     pub type_: Option<String>,
 }
 
+impl Validate for HlsEncryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.constant_initialization_vector {
+            errors.check_length(
+                &field_path(path, "constant_initialization_vector"),
+                value.chars().count(),
+                Some(32),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "constant_initialization_vector"),
+                &value,
+                "^[0-9a-fA-F]{32}$",
+            );
+        }
+        if let Some(ref value) = self.speke_key_provider {
+            value.validate_at(&field_path(path, "speke_key_provider"), errors);
+        }
+        if let Some(ref value) = self.static_key_provider {
+            value.validate_at(&field_path(path, "static_key_provider"), errors);
+        }
+    }
+}
+
 /// <p>Required when you set (Type) under (OutputGroups)&gt;(OutputGroupSettings) to HLS<em>GROUP</em>SETTINGS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsGroupSettings {
@@ -2096,6 +3425,74 @@ pub struct HlsGroupSettings {
     pub timestamp_delta_milliseconds: Option<i64>,
 }
 
+impl Validate for HlsGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.caption_language_mappings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_language_mappings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.destination {
+            errors.check_pattern(&field_path(path, "destination"), &value, "^s3:\\/\\/");
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.min_segment_length {
+            errors.check_range(
+                &field_path(path, "min_segment_length"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.program_date_time_period {
+            errors.check_range(
+                &field_path(path, "program_date_time_period"),
+                *value,
+                None,
+                Some(3600),
+            );
+        }
+        if let Some(ref value) = self.segment_length {
+            errors.check_range(
+                &field_path(path, "segment_length"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.segments_per_subdirectory {
+            errors.check_range(
+                &field_path(path, "segments_per_subdirectory"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.timed_metadata_id_3_period {
+            errors.check_range(
+                &field_path(path, "timed_metadata_id_3_period"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.timestamp_delta_milliseconds {
+            errors.check_range(
+                &field_path(path, "timestamp_delta_milliseconds"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>Settings for HLS output groups</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsSettings {
@@ -2138,6 +3535,25 @@ pub struct Id3Insertion {
     pub timecode: Option<String>,
 }
 
+impl Validate for Id3Insertion {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.id_3 {
+            errors.check_pattern(
+                &field_path(path, "id_3"),
+                &value,
+                "^[A-Za-z0-9+\\/]+={0,2}$",
+            );
+        }
+        if let Some(ref value) = self.timecode {
+            errors.check_pattern(
+                &field_path(path, "timecode"),
+                &value,
+                "^([01][0-9]|2[0-4]):[0-5][0-9]:[0-5][0-9][:;][0-9]{2}$",
+            );
+        }
+    }
+}
+
 /// <p>Enable the image inserter feature to include a graphic overlay on your video. Enable or disable this feature for each input or output individually. This setting is disabled by default.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageInserter {
@@ -2147,6 +3563,17 @@ pub struct ImageInserter {
     pub insertable_images: Option<Vec<InsertableImage>>,
 }
 
+impl Validate for ImageInserter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.insertable_images {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "insertable_images"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Settings specific to IMSC caption outputs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImscDestinationSettings {
@@ -2237,6 +3664,87 @@ pub struct Input {
     pub video_selector: Option<VideoSelector>,
 }
 
+impl Validate for Input {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_selector_groups {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_selector_groups"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.audio_selectors {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_selectors"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.caption_selectors {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_selectors"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.crop {
+            value.validate_at(&field_path(path, "crop"), errors);
+        }
+        if let Some(ref value) = self.decryption_settings {
+            value.validate_at(&field_path(path, "decryption_settings"), errors);
+        }
+        if let Some(ref value) = self.file_input {
+            errors.check_pattern(
+                &field_path(path, "file_input"),
+                &value,
+                "^s3://([^\\/]+\\/+)+((([^\\/]*)))|^https?://[^\\/].*[^&]$",
+            );
+        }
+        if let Some(ref value) = self.filter_strength {
+            errors.check_range(&field_path(path, "filter_strength"), *value, None, Some(5));
+        }
+        if let Some(ref value) = self.image_inserter {
+            value.validate_at(&field_path(path, "image_inserter"), errors);
+        }
+        if let Some(ref value) = self.input_clippings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_clippings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.position {
+            value.validate_at(&field_path(path, "position"), errors);
+        }
+        if let Some(ref value) = self.program_number {
+            errors.check_range(
+                &field_path(path, "program_number"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.supplemental_imps {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "supplemental_imps"), i);
+                errors.check_pattern(&item_path, &item, "^s3:\\/\\/.*\\/(ASSETMAP.xml)?$");
+            }
+        }
+        if let Some(ref value) = self.timecode_start {
+            errors.check_length(
+                &field_path(path, "timecode_start"),
+                value.chars().count(),
+                Some(11),
+                Some(11),
+            );
+            errors.check_pattern(
+                &field_path(path, "timecode_start"),
+                &value,
+                "^((([0-1]\\d)|(2[0-3]))(:[0-5]\\d){2}([:;][0-5]\\d))$",
+            );
+        }
+        if let Some(ref value) = self.video_selector {
+            value.validate_at(&field_path(path, "video_selector"), errors);
+        }
+    }
+}
+
 /// <p>To transcode only portions of your input (clips), include one Input clipping (one instance of InputClipping in the JSON job file) for each input clip. All input clips you specify will be included in every output of the job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputClipping {
@@ -2250,6 +3758,25 @@ pub struct InputClipping {
     pub start_timecode: Option<String>,
 }
 
+impl Validate for InputClipping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.end_timecode {
+            errors.check_pattern(
+                &field_path(path, "end_timecode"),
+                &value,
+                "^([01][0-9]|2[0-4]):[0-5][0-9]:[0-5][0-9][:;][0-9]{2}$",
+            );
+        }
+        if let Some(ref value) = self.start_timecode {
+            errors.check_pattern(
+                &field_path(path, "start_timecode"),
+                &value,
+                "^([01][0-9]|2[0-4]):[0-5][0-9]:[0-5][0-9][:;][0-9]{2}$",
+            );
+        }
+    }
+}
+
 /// <p>Settings for decrypting any input files that you encrypt before you upload them to Amazon S3. MediaConvert can decrypt files only when you use AWS Key Management Service (KMS) to encrypt the data key that you use to encrypt your content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputDecryptionSettings {
@@ -2271,6 +3798,50 @@ pub struct InputDecryptionSettings {
     pub kms_key_region: Option<String>,
 }
 
+impl Validate for InputDecryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encrypted_decryption_key {
+            errors.check_length(
+                &field_path(path, "encrypted_decryption_key"),
+                value.chars().count(),
+                Some(24),
+                Some(512),
+            );
+            errors.check_pattern(
+                &field_path(path, "encrypted_decryption_key"),
+                &value,
+                "^[A-Za-z0-9+\\/]+={0,2}$",
+            );
+        }
+        if let Some(ref value) = self.initialization_vector {
+            errors.check_length(
+                &field_path(path, "initialization_vector"),
+                value.chars().count(),
+                Some(16),
+                Some(24),
+            );
+            errors.check_pattern(
+                &field_path(path, "initialization_vector"),
+                &value,
+                "^[A-Za-z0-9+\\/]{22}==$|^[A-Za-z0-9+\\/]{16}$",
+            );
+        }
+        if let Some(ref value) = self.kms_key_region {
+            errors.check_length(
+                &field_path(path, "kms_key_region"),
+                value.chars().count(),
+                Some(9),
+                Some(19),
+            );
+            errors.check_pattern(
+                &field_path(path, "kms_key_region"),
+                &value,
+                "^[a-z-]{2,6}-(east|west|central|((north|south)(east|west)?))-[1-9]{1,2}$",
+            );
+        }
+    }
+}
+
 /// <p>Specified video input in a template.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputTemplate {
@@ -2340,6 +3911,71 @@ pub struct InputTemplate {
     pub video_selector: Option<VideoSelector>,
 }
 
+impl Validate for InputTemplate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_selector_groups {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_selector_groups"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.audio_selectors {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_selectors"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.caption_selectors {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_selectors"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.crop {
+            value.validate_at(&field_path(path, "crop"), errors);
+        }
+        if let Some(ref value) = self.filter_strength {
+            errors.check_range(&field_path(path, "filter_strength"), *value, None, Some(5));
+        }
+        if let Some(ref value) = self.image_inserter {
+            value.validate_at(&field_path(path, "image_inserter"), errors);
+        }
+        if let Some(ref value) = self.input_clippings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_clippings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.position {
+            value.validate_at(&field_path(path, "position"), errors);
+        }
+        if let Some(ref value) = self.program_number {
+            errors.check_range(
+                &field_path(path, "program_number"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.timecode_start {
+            errors.check_length(
+                &field_path(path, "timecode_start"),
+                value.chars().count(),
+                Some(11),
+                Some(11),
+            );
+            errors.check_pattern(
+                &field_path(path, "timecode_start"),
+                &value,
+                "^((([0-1]\\d)|(2[0-3]))(:[0-5]\\d){2}([:;][0-5]\\d))$",
+            );
+        }
+        if let Some(ref value) = self.video_selector {
+            value.validate_at(&field_path(path, "video_selector"), errors);
+        }
+    }
+}
+
 /// <p>Settings that specify how your still graphic overlay appears.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InsertableImage {
@@ -2389,9 +4025,67 @@ pub struct InsertableImage {
     pub width: Option<i64>,
 }
 
+impl Validate for InsertableImage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration {
+            errors.check_range(
+                &field_path(path, "duration"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.fade_in {
+            errors.check_range(&field_path(path, "fade_in"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.fade_out {
+            errors.check_range(
+                &field_path(path, "fade_out"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.height {
+            errors.check_range(&field_path(path, "height"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.image_inserter_input {
+            errors.check_length(
+                &field_path(path, "image_inserter_input"),
+                value.chars().count(),
+                Some(14),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "image_inserter_input"), &value, "^((s3://(.*?)\\.(bmp|BMP|png|PNG|tga|TGA))|(https?://(.*?)\\.(bmp|BMP|png|PNG|tga|TGA)(\\?([^&=]+=[^&]+&)*[^&=]+=[^&]+)?))$");
+        }
+        if let Some(ref value) = self.image_x {
+            errors.check_range(&field_path(path, "image_x"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.image_y {
+            errors.check_range(&field_path(path, "image_y"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.layer {
+            errors.check_range(&field_path(path, "layer"), *value, None, Some(99));
+        }
+        if let Some(ref value) = self.opacity {
+            errors.check_range(&field_path(path, "opacity"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.start_time {
+            errors.check_pattern(
+                &field_path(path, "start_time"),
+                &value,
+                "^((([0-1]\\d)|(2[0-3]))(:[0-5]\\d){2}([:;][0-5]\\d))$",
+            );
+        }
+        if let Some(ref value) = self.width {
+            errors.check_range(&field_path(path, "width"), *value, None, Some(2147483647));
+        }
+    }
+}
+
 /// <p>Each job converts an input file into an output file or files. For more information, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Job {
     /// <p>Accelerated transcoding can significantly speed up jobs with long, visually complex content.</p>
     #[serde(rename = "AccelerationSettings")]
@@ -2518,9 +4212,52 @@ pub struct JobSettings {
     pub timed_metadata_insertion: Option<TimedMetadataInsertion>,
 }
 
+impl Validate for JobSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ad_avail_offset {
+            errors.check_range(
+                &field_path(path, "ad_avail_offset"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.avail_blanking {
+            value.validate_at(&field_path(path, "avail_blanking"), errors);
+        }
+        if let Some(ref value) = self.esam {
+            value.validate_at(&field_path(path, "esam"), errors);
+        }
+        if let Some(ref value) = self.inputs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "inputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.motion_image_inserter {
+            value.validate_at(&field_path(path, "motion_image_inserter"), errors);
+        }
+        if let Some(ref value) = self.nielsen_configuration {
+            value.validate_at(&field_path(path, "nielsen_configuration"), errors);
+        }
+        if let Some(ref value) = self.output_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "output_groups"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.timecode_config {
+            value.validate_at(&field_path(path, "timecode_config"), errors);
+        }
+        if let Some(ref value) = self.timed_metadata_insertion {
+            value.validate_at(&field_path(path, "timed_metadata_insertion"), errors);
+        }
+    }
+}
+
 /// <p>A job template is a pre-made set of encoding instructions that you can use to quickly create a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobTemplate {
     /// <p>Accelerated transcoding can significantly speed up jobs with long, visually complex content.</p>
     #[serde(rename = "AccelerationSettings")]
@@ -2611,7 +4348,51 @@ pub struct JobTemplateSettings {
     pub timed_metadata_insertion: Option<TimedMetadataInsertion>,
 }
 
+impl Validate for JobTemplateSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ad_avail_offset {
+            errors.check_range(
+                &field_path(path, "ad_avail_offset"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.avail_blanking {
+            value.validate_at(&field_path(path, "avail_blanking"), errors);
+        }
+        if let Some(ref value) = self.esam {
+            value.validate_at(&field_path(path, "esam"), errors);
+        }
+        if let Some(ref value) = self.inputs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "inputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.motion_image_inserter {
+            value.validate_at(&field_path(path, "motion_image_inserter"), errors);
+        }
+        if let Some(ref value) = self.nielsen_configuration {
+            value.validate_at(&field_path(path, "nielsen_configuration"), errors);
+        }
+        if let Some(ref value) = self.output_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "output_groups"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.timecode_config {
+            value.validate_at(&field_path(path, "timecode_config"), errors);
+        }
+        if let Some(ref value) = self.timed_metadata_insertion {
+            value.validate_at(&field_path(path, "timed_metadata_insertion"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobTemplatesRequest {
     /// <p>Optionally, specify a job template category to limit responses to only job templates from that category.</p>
     #[serde(rename = "Category")]
@@ -2635,8 +4416,16 @@ pub struct ListJobTemplatesRequest {
     pub order: Option<String>,
 }
 
+impl Validate for ListJobTemplatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobTemplatesResponse {
     /// <p>List of Job templates.</p>
     #[serde(rename = "JobTemplates")]
@@ -2649,6 +4438,7 @@ pub struct ListJobTemplatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsRequest {
     /// <p>Optional. Number of jobs, up to twenty, that will be returned at one time.</p>
     #[serde(rename = "MaxResults")]
@@ -2672,8 +4462,16 @@ pub struct ListJobsRequest {
     pub status: Option<String>,
 }
 
+impl Validate for ListJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsResponse {
     /// <p>List of jobs</p>
     #[serde(rename = "Jobs")]
@@ -2686,6 +4484,7 @@ pub struct ListJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPresetsRequest {
     /// <p>Optionally, specify a preset category to limit responses to only presets from that category.</p>
     #[serde(rename = "Category")]
@@ -2709,8 +4508,16 @@ pub struct ListPresetsRequest {
     pub order: Option<String>,
 }
 
+impl Validate for ListPresetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPresetsResponse {
     /// <p>Use this string to request the next batch of presets.</p>
     #[serde(rename = "NextToken")]
@@ -2723,6 +4530,7 @@ pub struct ListPresetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueuesRequest {
     /// <p>Optional. When you request a list of queues, you can choose to list them alphabetically by NAME or chronologically by CREATION_DATE. If you don&#39;t specify, the service will list them by creation date.</p>
     #[serde(rename = "ListBy")]
@@ -2742,8 +4550,16 @@ pub struct ListQueuesRequest {
     pub order: Option<String>,
 }
 
+impl Validate for ListQueuesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListQueuesResponse {
     /// <p>Use this string to request the next batch of queues.</p>
     #[serde(rename = "NextToken")]
@@ -2756,6 +4572,7 @@ pub struct ListQueuesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource that you want to list tags for. To get the ARN, send a GET request with the resource name.</p>
     #[serde(rename = "Arn")]
@@ -2763,7 +4580,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The Amazon Resource Name (ARN) and tags for an AWS Elemental MediaConvert resource.</p>
     #[serde(rename = "ResourceTags")]
@@ -2780,6 +4597,19 @@ pub struct M2tsScte35Esam {
     pub scte_35_esam_pid: Option<i64>,
 }
 
+impl Validate for M2tsScte35Esam {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.scte_35_esam_pid {
+            errors.check_range(
+                &field_path(path, "scte_35_esam_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+    }
+}
+
 /// <p>MPEG-2 TS container settings. These apply to outputs in a File output group when the output&#39;s container (ContainerType) is MPEG-2 Transport Stream (M2TS). In these assets, data is organized by the program map table (PMT). Each transport stream program contains subsets of data, including audio, video, and metadata. Each of these subsets of data has a numerical label called a packet identifier (PID). Each transport stream program corresponds to one MediaConvert output. The PMT lists the types of data in a program along with their PID. Downstream systems and players use the program map table to look up the PID for each type of data it accesses and then uses the PIDs to locate specific data within the asset.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct M2tsSettings {
@@ -2929,6 +4759,125 @@ pub struct M2tsSettings {
     pub video_pid: Option<i64>,
 }
 
+impl Validate for M2tsSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_frames_per_pes {
+            errors.check_range(
+                &field_path(path, "audio_frames_per_pes"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.audio_pids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_pids"), i);
+                errors.check_range(&item_path, *item, Some(32), Some(8182));
+            }
+        }
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(&field_path(path, "bitrate"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.dvb_nit_settings {
+            value.validate_at(&field_path(path, "dvb_nit_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_sdt_settings {
+            value.validate_at(&field_path(path, "dvb_sdt_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_sub_pids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dvb_sub_pids"), i);
+                errors.check_range(&item_path, *item, Some(32), Some(8182));
+            }
+        }
+        if let Some(ref value) = self.dvb_tdt_settings {
+            value.validate_at(&field_path(path, "dvb_tdt_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_teletext_pid {
+            errors.check_range(
+                &field_path(path, "dvb_teletext_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.max_pcr_interval {
+            errors.check_range(
+                &field_path(path, "max_pcr_interval"),
+                *value,
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.min_ebp_interval {
+            errors.check_range(
+                &field_path(path, "min_ebp_interval"),
+                *value,
+                None,
+                Some(10000),
+            );
+        }
+        if let Some(ref value) = self.pat_interval {
+            errors.check_range(&field_path(path, "pat_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.pcr_pid {
+            errors.check_range(&field_path(path, "pcr_pid"), *value, Some(32), Some(8182));
+        }
+        if let Some(ref value) = self.pmt_interval {
+            errors.check_range(&field_path(path, "pmt_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.pmt_pid {
+            errors.check_range(&field_path(path, "pmt_pid"), *value, Some(32), Some(8182));
+        }
+        if let Some(ref value) = self.private_metadata_pid {
+            errors.check_range(
+                &field_path(path, "private_metadata_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.program_number {
+            errors.check_range(
+                &field_path(path, "program_number"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.scte_35_esam {
+            value.validate_at(&field_path(path, "scte_35_esam"), errors);
+        }
+        if let Some(ref value) = self.scte_35_pid {
+            errors.check_range(
+                &field_path(path, "scte_35_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.timed_metadata_pid {
+            errors.check_range(
+                &field_path(path, "timed_metadata_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.transport_stream_id {
+            errors.check_range(
+                &field_path(path, "transport_stream_id"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.video_pid {
+            errors.check_range(&field_path(path, "video_pid"), *value, Some(32), Some(8182));
+        }
+    }
+}
+
 /// <p>Settings for TS segments in HLS</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct M3u8Settings {
@@ -2998,15 +4947,89 @@ pub struct M3u8Settings {
     pub video_pid: Option<i64>,
 }
 
-/// <p>Overlay motion graphics on top of your video at the time that you specify.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct MotionImageInserter {
-    /// <p>If your motion graphic asset is a .mov file, keep this setting unspecified. If your motion graphic asset is a series of .png files, specify the frame rate of the overlay in frames per second, as a fraction. For example, specify 24 fps as 24/1. Make sure that the number of images in your series matches the frame rate and your intended overlay duration. For example, if you want a 30-second overlay at 30 fps, you should have 900 .png images. This overlay frame rate doesn&#39;t need to match the frame rate of the underlying video.</p>
-    #[serde(rename = "Framerate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub framerate: Option<MotionImageInsertionFramerate>,
-    /// <p>Specify the .mov file or series of .png files that you want to overlay on your video. For .png files, provide the file name of the first file in the series. Make sure that the names of the .png files end with sequential numbers that specify the order that they are played in. For example, overlay<em>000.png, overlay</em>001.png, overlay<em>002.png, and so on. The sequence must start at zero, and each image file name must have the same number of digits. Pad your initial file names with enough zeros to complete the sequence. For example, if the first image is overlay</em>0.png, there can be only 10 images in the sequence, with the last image being overlay<em>9.png. But if the first image is overlay</em>00.png, there can be 100 images in the sequence.</p>
-    #[serde(rename = "Input")]
+impl Validate for M3u8Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_frames_per_pes {
+            errors.check_range(
+                &field_path(path, "audio_frames_per_pes"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.audio_pids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_pids"), i);
+                errors.check_range(&item_path, *item, Some(32), Some(8182));
+            }
+        }
+        if let Some(ref value) = self.pat_interval {
+            errors.check_range(&field_path(path, "pat_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.pcr_pid {
+            errors.check_range(&field_path(path, "pcr_pid"), *value, Some(32), Some(8182));
+        }
+        if let Some(ref value) = self.pmt_interval {
+            errors.check_range(&field_path(path, "pmt_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.pmt_pid {
+            errors.check_range(&field_path(path, "pmt_pid"), *value, Some(32), Some(8182));
+        }
+        if let Some(ref value) = self.private_metadata_pid {
+            errors.check_range(
+                &field_path(path, "private_metadata_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.program_number {
+            errors.check_range(
+                &field_path(path, "program_number"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.scte_35_pid {
+            errors.check_range(
+                &field_path(path, "scte_35_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.timed_metadata_pid {
+            errors.check_range(
+                &field_path(path, "timed_metadata_pid"),
+                *value,
+                Some(32),
+                Some(8182),
+            );
+        }
+        if let Some(ref value) = self.transport_stream_id {
+            errors.check_range(
+                &field_path(path, "transport_stream_id"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+        if let Some(ref value) = self.video_pid {
+            errors.check_range(&field_path(path, "video_pid"), *value, Some(32), Some(8182));
+        }
+    }
+}
+
+/// <p>Overlay motion graphics on top of your video at the time that you specify.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MotionImageInserter {
+    /// <p>If your motion graphic asset is a .mov file, keep this setting unspecified. If your motion graphic asset is a series of .png files, specify the frame rate of the overlay in frames per second, as a fraction. For example, specify 24 fps as 24/1. Make sure that the number of images in your series matches the frame rate and your intended overlay duration. For example, if you want a 30-second overlay at 30 fps, you should have 900 .png images. This overlay frame rate doesn&#39;t need to match the frame rate of the underlying video.</p>
+    #[serde(rename = "Framerate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framerate: Option<MotionImageInsertionFramerate>,
+    /// <p>Specify the .mov file or series of .png files that you want to overlay on your video. For .png files, provide the file name of the first file in the series. Make sure that the names of the .png files end with sequential numbers that specify the order that they are played in. For example, overlay<em>000.png, overlay</em>001.png, overlay<em>002.png, and so on. The sequence must start at zero, and each image file name must have the same number of digits. Pad your initial file names with enough zeros to complete the sequence. For example, if the first image is overlay</em>0.png, there can be only 10 images in the sequence, with the last image being overlay<em>9.png. But if the first image is overlay</em>00.png, there can be 100 images in the sequence.</p>
+    #[serde(rename = "Input")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input: Option<String>,
     /// <p>Choose the type of motion graphic asset that you are providing for your overlay. You can choose either a .mov file or a series of .png files.</p>
@@ -3027,6 +5050,39 @@ pub struct MotionImageInserter {
     pub start_time: Option<String>,
 }
 
+impl Validate for MotionImageInserter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.framerate {
+            value.validate_at(&field_path(path, "framerate"), errors);
+        }
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                Some(14),
+                None,
+            );
+            errors.check_pattern(&field_path(path, "input"), &value, "^((s3://(.*)(\\.mov|[0-9]+\\.png))|(https?://(.*)(\\.mov|[0-9]+\\.png)(\\?([^&=]+=[^&]+&)*[^&=]+=[^&]+)?))$");
+        }
+        if let Some(ref value) = self.offset {
+            value.validate_at(&field_path(path, "offset"), errors);
+        }
+        if let Some(ref value) = self.start_time {
+            errors.check_length(
+                &field_path(path, "start_time"),
+                value.chars().count(),
+                Some(11),
+                Some(11),
+            );
+            errors.check_pattern(
+                &field_path(path, "start_time"),
+                &value,
+                "^((([0-1]\\d)|(2[0-3]))(:[0-5]\\d){2}([:;][0-5]\\d))$",
+            );
+        }
+    }
+}
+
 /// <p>For motion overlays that don&#39;t have a built-in frame rate, specify the frame rate of the overlay in frames per second, as a fraction. For example, specify 24 fps as 24/1. The overlay frame rate doesn&#39;t need to match the frame rate of the underlying video.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MotionImageInsertionFramerate {
@@ -3040,6 +5096,27 @@ pub struct MotionImageInsertionFramerate {
     pub framerate_numerator: Option<i64>,
 }
 
+impl Validate for MotionImageInsertionFramerate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                Some(17895697),
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(1),
+                Some(2147483640),
+            );
+        }
+    }
+}
+
 /// <p>Specify the offset between the upper-left corner of the video frame and the top left corner of the overlay.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MotionImageInsertionOffset {
@@ -3053,6 +5130,17 @@ pub struct MotionImageInsertionOffset {
     pub image_y: Option<i64>,
 }
 
+impl Validate for MotionImageInsertionOffset {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.image_x {
+            errors.check_range(&field_path(path, "image_x"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.image_y {
+            errors.check_range(&field_path(path, "image_y"), *value, None, Some(2147483647));
+        }
+    }
+}
+
 /// <p>Settings for MOV Container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MovSettings {
@@ -3095,6 +5183,30 @@ pub struct Mp2Settings {
     pub sample_rate: Option<i64>,
 }
 
+impl Validate for Mp2Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(32000),
+                Some(384000),
+            );
+        }
+        if let Some(ref value) = self.channels {
+            errors.check_range(&field_path(path, "channels"), *value, Some(1), Some(2));
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(32000),
+                Some(48000),
+            );
+        }
+    }
+}
+
 /// <p>Settings for MP4 container. You can create audio-only AAC outputs with this container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Mp4Settings {
@@ -3247,6 +5359,97 @@ pub struct Mpeg2Settings {
     pub temporal_adaptive_quantization: Option<String>,
 }
 
+impl Validate for Mpeg2Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(1000),
+                Some(288000000),
+            );
+        }
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                Some(1001),
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(24),
+                Some(60000),
+            );
+        }
+        if let Some(ref value) = self.gop_closed_cadence {
+            errors.check_range(
+                &field_path(path, "gop_closed_cadence"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hrd_buffer_initial_fill_percentage {
+            errors.check_range(
+                &field_path(path, "hrd_buffer_initial_fill_percentage"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.hrd_buffer_size {
+            errors.check_range(
+                &field_path(path, "hrd_buffer_size"),
+                *value,
+                None,
+                Some(47185920),
+            );
+        }
+        if let Some(ref value) = self.max_bitrate {
+            errors.check_range(
+                &field_path(path, "max_bitrate"),
+                *value,
+                Some(1000),
+                Some(300000000),
+            );
+        }
+        if let Some(ref value) = self.min_i_interval {
+            errors.check_range(&field_path(path, "min_i_interval"), *value, None, Some(30));
+        }
+        if let Some(ref value) = self.number_b_frames_between_reference_frames {
+            errors.check_range(
+                &field_path(path, "number_b_frames_between_reference_frames"),
+                *value,
+                None,
+                Some(7),
+            );
+        }
+        if let Some(ref value) = self.par_denominator {
+            errors.check_range(
+                &field_path(path, "par_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.par_numerator {
+            errors.check_range(
+                &field_path(path, "par_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.softness {
+            errors.check_range(&field_path(path, "softness"), *value, None, Some(128));
+        }
+    }
+}
+
 /// <p>If you are using DRM, set DRM System (MsSmoothEncryptionSettings) to specify the value SpekeKeyProvider.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MsSmoothEncryptionSettings {
@@ -3256,6 +5459,14 @@ pub struct MsSmoothEncryptionSettings {
     pub speke_key_provider: Option<SpekeKeyProvider>,
 }
 
+impl Validate for MsSmoothEncryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.speke_key_provider {
+            value.validate_at(&field_path(path, "speke_key_provider"), errors);
+        }
+    }
+}
+
 /// <p>Required when you set (Type) under (OutputGroups)&gt;(OutputGroupSettings) to MS<em>SMOOTH</em>GROUP_SETTINGS.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MsSmoothGroupSettings {
@@ -3285,6 +5496,28 @@ pub struct MsSmoothGroupSettings {
     pub manifest_encoding: Option<String>,
 }
 
+impl Validate for MsSmoothGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination {
+            errors.check_pattern(&field_path(path, "destination"), &value, "^s3:\\/\\/");
+        }
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+        if let Some(ref value) = self.fragment_length {
+            errors.check_range(
+                &field_path(path, "fragment_length"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>Settings for your Nielsen configuration. If you don&#39;t do Nielsen measurement and analytics, ignore these settings. When you enable Nielsen configuration (nielsenConfiguration), MediaConvert enables PCM to ID3 tagging for all outputs in the job. To enable Nielsen configuration programmatically, include an instance of nielsenConfiguration in your JSON job specification. Even if you don&#39;t include any children of nielsenConfiguration, you still enable the setting.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NielsenConfiguration {
@@ -3298,6 +5531,14 @@ pub struct NielsenConfiguration {
     pub distributor_id: Option<String>,
 }
 
+impl Validate for NielsenConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.breakout_code {
+            errors.check_range(&field_path(path, "breakout_code"), *value, None, Some(0));
+        }
+    }
+}
+
 /// <p>Enable the Noise reducer (NoiseReducer) feature to remove noise from your video output if necessary. Enable or disable this feature for each output individually. This setting is disabled by default. When you enable Noise reducer (NoiseReducer), you must also select a value for Noise reducer filter (NoiseReducerFilter).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NoiseReducer {
@@ -3319,6 +5560,20 @@ pub struct NoiseReducer {
     pub temporal_filter_settings: Option<NoiseReducerTemporalFilterSettings>,
 }
 
+impl Validate for NoiseReducer {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter_settings {
+            value.validate_at(&field_path(path, "filter_settings"), errors);
+        }
+        if let Some(ref value) = self.spatial_filter_settings {
+            value.validate_at(&field_path(path, "spatial_filter_settings"), errors);
+        }
+        if let Some(ref value) = self.temporal_filter_settings {
+            value.validate_at(&field_path(path, "temporal_filter_settings"), errors);
+        }
+    }
+}
+
 /// <p>Settings for a noise reducer filter</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NoiseReducerFilterSettings {
@@ -3328,6 +5583,14 @@ pub struct NoiseReducerFilterSettings {
     pub strength: Option<i64>,
 }
 
+impl Validate for NoiseReducerFilterSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.strength {
+            errors.check_range(&field_path(path, "strength"), *value, None, Some(3));
+        }
+    }
+}
+
 /// <p>Noise reducer filter settings for spatial filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NoiseReducerSpatialFilterSettings {
@@ -3345,6 +5608,25 @@ pub struct NoiseReducerSpatialFilterSettings {
     pub strength: Option<i64>,
 }
 
+impl Validate for NoiseReducerSpatialFilterSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.post_filter_sharpen_strength {
+            errors.check_range(
+                &field_path(path, "post_filter_sharpen_strength"),
+                *value,
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.speed {
+            errors.check_range(&field_path(path, "speed"), *value, None, Some(3));
+        }
+        if let Some(ref value) = self.strength {
+            errors.check_range(&field_path(path, "strength"), *value, None, Some(16));
+        }
+    }
+}
+
 /// <p>Noise reducer filter settings for temporal filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NoiseReducerTemporalFilterSettings {
@@ -3362,6 +5644,20 @@ pub struct NoiseReducerTemporalFilterSettings {
     pub strength: Option<i64>,
 }
 
+impl Validate for NoiseReducerTemporalFilterSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aggressive_mode {
+            errors.check_range(&field_path(path, "aggressive_mode"), *value, None, Some(4));
+        }
+        if let Some(ref value) = self.speed {
+            errors.check_range(&field_path(path, "speed"), *value, None, Some(3));
+        }
+        if let Some(ref value) = self.strength {
+            errors.check_range(&field_path(path, "strength"), *value, None, Some(16));
+        }
+    }
+}
+
 /// <p>An output object describes the settings for a single output file or stream in an output group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Output {
@@ -3399,6 +5695,37 @@ pub struct Output {
     pub video_description: Option<VideoDescription>,
 }
 
+impl Validate for Output {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_descriptions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_descriptions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.caption_descriptions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_descriptions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.container_settings {
+            value.validate_at(&field_path(path, "container_settings"), errors);
+        }
+        if let Some(ref value) = self.name_modifier {
+            errors.check_length(
+                &field_path(path, "name_modifier"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.video_description {
+            value.validate_at(&field_path(path, "video_description"), errors);
+        }
+    }
+}
+
 /// <p>OutputChannel mapping settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputChannelMapping {
@@ -3408,9 +5735,20 @@ pub struct OutputChannelMapping {
     pub input_channels: Option<Vec<i64>>,
 }
 
+impl Validate for OutputChannelMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_channels {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_channels"), i);
+                errors.check_range(&item_path, *item, None, Some(6));
+            }
+        }
+    }
+}
+
 /// <p>Details regarding output</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OutputDetail {
     /// <p>Duration in milliseconds</p>
     #[serde(rename = "DurationInMs")]
@@ -3443,9 +5781,23 @@ pub struct OutputGroup {
     pub outputs: Option<Vec<Output>>,
 }
 
+impl Validate for OutputGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.output_group_settings {
+            value.validate_at(&field_path(path, "output_group_settings"), errors);
+        }
+        if let Some(ref value) = self.outputs {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "outputs"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Contains details about the output groups specified in the job settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OutputGroupDetail {
     /// <p>Details about the output</p>
     #[serde(rename = "OutputDetails")]
@@ -3482,6 +5834,26 @@ pub struct OutputGroupSettings {
     pub type_: Option<String>,
 }
 
+impl Validate for OutputGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cmaf_group_settings {
+            value.validate_at(&field_path(path, "cmaf_group_settings"), errors);
+        }
+        if let Some(ref value) = self.dash_iso_group_settings {
+            value.validate_at(&field_path(path, "dash_iso_group_settings"), errors);
+        }
+        if let Some(ref value) = self.file_group_settings {
+            value.validate_at(&field_path(path, "file_group_settings"), errors);
+        }
+        if let Some(ref value) = self.hls_group_settings {
+            value.validate_at(&field_path(path, "hls_group_settings"), errors);
+        }
+        if let Some(ref value) = self.ms_smooth_group_settings {
+            value.validate_at(&field_path(path, "ms_smooth_group_settings"), errors);
+        }
+    }
+}
+
 /// <p>Specific settings for this type of output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputSettings {
@@ -3493,7 +5865,7 @@ pub struct OutputSettings {
 
 /// <p>A preset is a collection of preconfigured media conversion settings that you want MediaConvert to apply to the output during the conversion process.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Preset {
     /// <p>An identifier for this resource that is unique within all of AWS.</p>
     #[serde(rename = "Arn")]
@@ -3548,6 +5920,29 @@ pub struct PresetSettings {
     pub video_description: Option<VideoDescription>,
 }
 
+impl Validate for PresetSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_descriptions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_descriptions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.caption_descriptions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_descriptions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.container_settings {
+            value.validate_at(&field_path(path, "container_settings"), errors);
+        }
+        if let Some(ref value) = self.video_description {
+            value.validate_at(&field_path(path, "video_description"), errors);
+        }
+    }
+}
+
 /// <p>Required when you set (Codec) under (VideoDescription)&gt;(CodecSettings) to the value PRORES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProresSettings {
@@ -3599,9 +5994,46 @@ pub struct ProresSettings {
     pub telecine: Option<String>,
 }
 
+impl Validate for ProresSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.par_denominator {
+            errors.check_range(
+                &field_path(path, "par_denominator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.par_numerator {
+            errors.check_range(
+                &field_path(path, "par_numerator"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>You can use queues to manage the resources that are available to your AWS account for running multiple transcoding jobs at the same time. If you don&#39;t specify a queue, the service sends all jobs through the default queue. For more information, see https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Queue {
     /// <p>An identifier for this resource that is unique within all of AWS.</p>
     #[serde(rename = "Arn")]
@@ -3669,6 +6101,33 @@ pub struct Rectangle {
     pub y: Option<i64>,
 }
 
+impl Validate for Rectangle {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.height {
+            errors.check_range(
+                &field_path(path, "height"),
+                *value,
+                Some(2),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.width {
+            errors.check_range(
+                &field_path(path, "width"),
+                *value,
+                Some(2),
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.x {
+            errors.check_range(&field_path(path, "x"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.y {
+            errors.check_range(&field_path(path, "y"), *value, None, Some(2147483647));
+        }
+    }
+}
+
 /// <p>Use Manual audio remixing (RemixSettings) to adjust audio levels for each audio channel in each output of your job. With audio remixing, you can output more or fewer audio channels than your input audio source provides.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RemixSettings {
@@ -3686,9 +6145,23 @@ pub struct RemixSettings {
     pub channels_out: Option<i64>,
 }
 
+impl Validate for RemixSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.channel_mapping {
+            value.validate_at(&field_path(path, "channel_mapping"), errors);
+        }
+        if let Some(ref value) = self.channels_in {
+            errors.check_range(&field_path(path, "channels_in"), *value, Some(1), Some(64));
+        }
+        if let Some(ref value) = self.channels_out {
+            errors.check_range(&field_path(path, "channels_out"), *value, Some(1), Some(64));
+        }
+    }
+}
+
 /// <p>Details about the pricing plan for your reserved queue. Required for reserved queues and not applicable to on-demand queues.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationPlan {
     /// <p>The length of the term of your reserved queue pricing plan commitment.</p>
     #[serde(rename = "Commitment")]
@@ -3718,6 +6191,7 @@ pub struct ReservationPlan {
 
 /// <p>Details about the pricing plan for your reserved queue. Required for reserved queues and not applicable to on-demand queues.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservationPlanSettings {
     /// <p>The length of the term of your reserved queue pricing plan commitment.</p>
     #[serde(rename = "Commitment")]
@@ -3732,7 +6206,7 @@ pub struct ReservationPlanSettings {
 
 /// <p>The Amazon Resource Name (ARN) and tags for an AWS Elemental MediaConvert resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceTags {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "Arn")]
@@ -3753,6 +6227,14 @@ pub struct S3DestinationSettings {
     pub encryption: Option<S3EncryptionSettings>,
 }
 
+impl Validate for S3DestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.encryption {
+            value.validate_at(&field_path(path, "encryption"), errors);
+        }
+    }
+}
+
 /// <p>Settings for how your job outputs are encrypted as they are uploaded to Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3EncryptionSettings {
@@ -3766,6 +6248,14 @@ pub struct S3EncryptionSettings {
     pub kms_key_arn: Option<String>,
 }
 
+impl Validate for S3EncryptionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_pattern(&field_path(path, "kms_key_arn"), &value, "^arn:aws(-us-gov|-cn)?:kms:[a-z-]{2,6}-(east|west|central|((north|south)(east|west)?))-[1-9]{1,2}:\\d{12}:key/([a-fA-F0-9]{8}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{12}|mrk-[a-fA-F0-9]{32})$");
+        }
+    }
+}
+
 /// <p>Settings for SCC caption output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SccDestinationSettings {
@@ -3797,6 +6287,35 @@ pub struct SpekeKeyProvider {
     pub url: Option<String>,
 }
 
+impl Validate for SpekeKeyProvider {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_arn {
+            errors.check_pattern(
+                &field_path(path, "certificate_arn"),
+                &value,
+                "^arn:aws(-us-gov)?:acm:",
+            );
+        }
+        if let Some(ref value) = self.system_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "system_ids"), i);
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+                );
+            }
+        }
+        if let Some(ref value) = self.url {
+            errors.check_pattern(
+                &field_path(path, "url"),
+                &value,
+                "^https:\\/\\/[^:@\\/]*(:\\d*)?(\\/.*)?$",
+            );
+        }
+    }
+}
+
 /// <p>If your output group type is CMAF, use these settings when doing DRM encryption with a SPEKE-compliant key provider. If your output group type is HLS, DASH, or Microsoft Smooth, use the SpekeKeyProvider settings instead.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpekeKeyProviderCmaf {
@@ -3822,6 +6341,50 @@ pub struct SpekeKeyProviderCmaf {
     pub url: Option<String>,
 }
 
+impl Validate for SpekeKeyProviderCmaf {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.certificate_arn {
+            errors.check_pattern(
+                &field_path(path, "certificate_arn"),
+                &value,
+                "^arn:aws(-us-gov)?:acm:",
+            );
+        }
+        if let Some(ref value) = self.dash_signaled_system_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dash_signaled_system_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(36), Some(36));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+                );
+            }
+        }
+        if let Some(ref value) = self.hls_signaled_system_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "hls_signaled_system_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(36), Some(36));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+                );
+            }
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_pattern(&field_path(path, "resource_id"), &value, "^[\\w-]+$");
+        }
+        if let Some(ref value) = self.url {
+            errors.check_pattern(
+                &field_path(path, "url"),
+                &value,
+                "^https:\\/\\/[^:@\\/]*(:\\d*)?(\\/.*)?$",
+            );
+        }
+    }
+}
+
 /// <p>Use these settings to set up encryption with a static key provider.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StaticKeyProvider {
@@ -3843,7 +6406,34 @@ pub struct StaticKeyProvider {
     pub url: Option<String>,
 }
 
+impl Validate for StaticKeyProvider {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key_format {
+            errors.check_pattern(
+                &field_path(path, "key_format"),
+                &value,
+                "^(identity|[A-Za-z]{2,6}(\\.[A-Za-z0-9-]{1,63})+)$",
+            );
+        }
+        if let Some(ref value) = self.key_format_versions {
+            errors.check_pattern(
+                &field_path(path, "key_format_versions"),
+                &value,
+                "^(\\d+(\\/\\d+)*)$",
+            );
+        }
+        if let Some(ref value) = self.static_key_value {
+            errors.check_pattern(
+                &field_path(path, "static_key_value"),
+                &value,
+                "^[A-Za-z0-9]{32}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource that you want to tag. To get the ARN, send a GET request with the resource name.</p>
     #[serde(rename = "Arn")]
@@ -3854,7 +6444,7 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Settings for Teletext caption output</p>
@@ -3870,6 +6460,24 @@ pub struct TeletextDestinationSettings {
     pub page_types: Option<Vec<String>>,
 }
 
+impl Validate for TeletextDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_number {
+            errors.check_length(
+                &field_path(path, "page_number"),
+                value.chars().count(),
+                Some(3),
+                Some(3),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_number"),
+                &value,
+                "^[1-8][0-9a-fA-F][0-9a-eA-E]$",
+            );
+        }
+    }
+}
+
 /// <p>Settings specific to Teletext caption sources, including Page number.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TeletextSourceSettings {
@@ -3879,6 +6487,24 @@ pub struct TeletextSourceSettings {
     pub page_number: Option<String>,
 }
 
+impl Validate for TeletextSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_number {
+            errors.check_length(
+                &field_path(path, "page_number"),
+                value.chars().count(),
+                Some(3),
+                Some(3),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_number"),
+                &value,
+                "^[1-8][0-9a-fA-F][0-9a-eA-E]$",
+            );
+        }
+    }
+}
+
 /// <p>Timecode burn-in (TimecodeBurnIn)--Burns the output timecode and specified prefix into the output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimecodeBurnin {
@@ -3896,6 +6522,17 @@ pub struct TimecodeBurnin {
     pub prefix: Option<String>,
 }
 
+impl Validate for TimecodeBurnin {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.font_size {
+            errors.check_range(&field_path(path, "font_size"), *value, Some(10), Some(48));
+        }
+        if let Some(ref value) = self.prefix {
+            errors.check_pattern(&field_path(path, "prefix"), &value, "^[ -~]+$");
+        }
+    }
+}
+
 /// <p>These settings control how the service handles timecodes throughout the job. These settings don&#39;t affect input clipping.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimecodeConfig {
@@ -3917,6 +6554,32 @@ pub struct TimecodeConfig {
     pub timestamp_offset: Option<String>,
 }
 
+impl Validate for TimecodeConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.anchor {
+            errors.check_pattern(
+                &field_path(path, "anchor"),
+                &value,
+                "^([01][0-9]|2[0-4]):[0-5][0-9]:[0-5][0-9][:;][0-9]{2}$",
+            );
+        }
+        if let Some(ref value) = self.start {
+            errors.check_pattern(
+                &field_path(path, "start"),
+                &value,
+                "^([01][0-9]|2[0-4]):[0-5][0-9]:[0-5][0-9][:;][0-9]{2}$",
+            );
+        }
+        if let Some(ref value) = self.timestamp_offset {
+            errors.check_pattern(
+                &field_path(path, "timestamp_offset"),
+                &value,
+                "^([0-9]{4})-(0[1-9]|1[0-2])-(0[1-9]|[12][0-9]|3[01])$",
+            );
+        }
+    }
+}
+
 /// <p>Enable Timed metadata insertion (TimedMetadataInsertion) to include ID3 tags in your job. To include timed metadata, you must enable it here, enable it in each output container, and specify tags and timecodes in ID3 insertion (Id3Insertion) objects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimedMetadataInsertion {
@@ -3926,9 +6589,20 @@ pub struct TimedMetadataInsertion {
     pub id_3_insertions: Option<Vec<Id3Insertion>>,
 }
 
+impl Validate for TimedMetadataInsertion {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.id_3_insertions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "id_3_insertions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Information about when jobs are submitted, started, and finished is specified in Unix epoch format in seconds.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Timing {
     /// <p>The time, in Unix epoch format, that the transcoding job finished</p>
     #[serde(rename = "FinishTime")]
@@ -3953,6 +6627,19 @@ pub struct TrackSourceSettings {
     pub track_number: Option<i64>,
 }
 
+impl Validate for TrackSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.track_number {
+            errors.check_range(
+                &field_path(path, "track_number"),
+                *value,
+                Some(1),
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>Settings specific to TTML caption outputs, including Pass style information (TtmlStylePassthrough).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TtmlDestinationSettings {
@@ -3963,6 +6650,7 @@ pub struct TtmlDestinationSettings {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource that you want to remove tags from. To get the ARN, send a GET request with the resource name.</p>
     #[serde(rename = "Arn")]
@@ -3974,10 +6662,11 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJobTemplateRequest {
     /// <p>Accelerated transcoding can significantly speed up jobs with long, visually complex content. Outputs that use this feature incur pro-tier pricing. For information about feature limitations, see the AWS Elemental MediaConvert User Guide.</p>
     #[serde(rename = "AccelerationSettings")]
@@ -4012,8 +6701,19 @@ pub struct UpdateJobTemplateRequest {
     pub status_update_interval: Option<String>,
 }
 
+impl Validate for UpdateJobTemplateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, None, Some(50));
+        }
+        if let Some(ref value) = self.settings {
+            value.validate_at(&field_path(path, "settings"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateJobTemplateResponse {
     /// <p>A job template is a pre-made set of encoding instructions that you can use to quickly create a job.</p>
     #[serde(rename = "JobTemplate")]
@@ -4022,6 +6722,7 @@ pub struct UpdateJobTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePresetRequest {
     /// <p>The new category for the preset, if you are changing it.</p>
     #[serde(rename = "Category")]
@@ -4040,8 +6741,16 @@ pub struct UpdatePresetRequest {
     pub settings: Option<PresetSettings>,
 }
 
+impl Validate for UpdatePresetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.settings {
+            value.validate_at(&field_path(path, "settings"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePresetResponse {
     /// <p>A preset is a collection of preconfigured media conversion settings that you want MediaConvert to apply to the output during the conversion process.</p>
     #[serde(rename = "Preset")]
@@ -4050,6 +6759,7 @@ pub struct UpdatePresetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateQueueRequest {
     /// <p>The new description for the queue, if you are changing it.</p>
     #[serde(rename = "Description")]
@@ -4069,7 +6779,7 @@ pub struct UpdateQueueRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateQueueResponse {
     /// <p>You can use queues to manage the resources that are available to your AWS account for running multiple transcoding jobs at the same time. If you don&#39;t specify a queue, the service sends all jobs through the default queue. For more information, see https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html.</p>
     #[serde(rename = "Queue")]
@@ -4106,6 +6816,26 @@ pub struct VideoCodecSettings {
     pub prores_settings: Option<ProresSettings>,
 }
 
+impl Validate for VideoCodecSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.frame_capture_settings {
+            value.validate_at(&field_path(path, "frame_capture_settings"), errors);
+        }
+        if let Some(ref value) = self.h264_settings {
+            value.validate_at(&field_path(path, "h264_settings"), errors);
+        }
+        if let Some(ref value) = self.h265_settings {
+            value.validate_at(&field_path(path, "h265_settings"), errors);
+        }
+        if let Some(ref value) = self.mpeg_2_settings {
+            value.validate_at(&field_path(path, "mpeg_2_settings"), errors);
+        }
+        if let Some(ref value) = self.prores_settings {
+            value.validate_at(&field_path(path, "prores_settings"), errors);
+        }
+    }
+}
+
 /// <p>Settings for video outputs</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoDescription {
@@ -4171,9 +6901,38 @@ pub struct VideoDescription {
     pub width: Option<i64>,
 }
 
+impl Validate for VideoDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.codec_settings {
+            value.validate_at(&field_path(path, "codec_settings"), errors);
+        }
+        if let Some(ref value) = self.crop {
+            value.validate_at(&field_path(path, "crop"), errors);
+        }
+        if let Some(ref value) = self.fixed_afd {
+            errors.check_range(&field_path(path, "fixed_afd"), *value, None, Some(15));
+        }
+        if let Some(ref value) = self.height {
+            errors.check_range(&field_path(path, "height"), *value, Some(32), Some(8192));
+        }
+        if let Some(ref value) = self.position {
+            value.validate_at(&field_path(path, "position"), errors);
+        }
+        if let Some(ref value) = self.sharpness {
+            errors.check_range(&field_path(path, "sharpness"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.video_preprocessors {
+            value.validate_at(&field_path(path, "video_preprocessors"), errors);
+        }
+        if let Some(ref value) = self.width {
+            errors.check_range(&field_path(path, "width"), *value, Some(32), Some(8192));
+        }
+    }
+}
+
 /// <p>Contains details about the output&#39;s video stream</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VideoDetail {
     /// <p>Height in pixels for the output</p>
     #[serde(rename = "HeightInPx")]
@@ -4210,6 +6969,23 @@ pub struct VideoPreprocessor {
     pub timecode_burnin: Option<TimecodeBurnin>,
 }
 
+impl Validate for VideoPreprocessor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.color_corrector {
+            value.validate_at(&field_path(path, "color_corrector"), errors);
+        }
+        if let Some(ref value) = self.image_inserter {
+            value.validate_at(&field_path(path, "image_inserter"), errors);
+        }
+        if let Some(ref value) = self.noise_reducer {
+            value.validate_at(&field_path(path, "noise_reducer"), errors);
+        }
+        if let Some(ref value) = self.timecode_burnin {
+            value.validate_at(&field_path(path, "timecode_burnin"), errors);
+        }
+    }
+}
+
 /// <p>Selector for video.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoSelector {
@@ -4239,10 +7015,29 @@ pub struct VideoSelector {
     pub rotate: Option<String>,
 }
 
-/// <p>Required when you set (Codec) under (AudioDescriptions)&gt;(CodecSettings) to the value WAV.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct WavSettings {
-    /// <p>Specify Bit depth (BitDepth), in bits per sample, to choose the encoding quality for this audio track.</p>
+impl Validate for VideoSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hdr_10_metadata {
+            value.validate_at(&field_path(path, "hdr_10_metadata"), errors);
+        }
+        if let Some(ref value) = self.pid {
+            errors.check_range(&field_path(path, "pid"), *value, Some(1), Some(2147483647));
+        }
+        if let Some(ref value) = self.program_number {
+            errors.check_range(
+                &field_path(path, "program_number"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
+/// <p>Required when you set (Codec) under (AudioDescriptions)&gt;(CodecSettings) to the value WAV.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WavSettings {
+    /// <p>Specify Bit depth (BitDepth), in bits per sample, to choose the encoding quality for this audio track.</p>
     #[serde(rename = "BitDepth")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bit_depth: Option<i64>,
@@ -4260,6 +7055,25 @@ pub struct WavSettings {
     pub sample_rate: Option<i64>,
 }
 
+impl Validate for WavSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bit_depth {
+            errors.check_range(&field_path(path, "bit_depth"), *value, Some(16), Some(24));
+        }
+        if let Some(ref value) = self.channels {
+            errors.check_range(&field_path(path, "channels"), *value, Some(1), Some(64));
+        }
+        if let Some(ref value) = self.sample_rate {
+            errors.check_range(
+                &field_path(path, "sample_rate"),
+                *value,
+                Some(8000),
+                Some(192000),
+            );
+        }
+    }
+}
+
 /// Errors returned by AssociateCertificate
 #[derive(Debug, PartialEq)]
 pub enum AssociateCertificateError {
@@ -4303,7 +7117,12 @@ impl AssociateCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4366,7 +7185,12 @@ impl CancelJobError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CancelJobError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4429,7 +7253,12 @@ impl CreateJobError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateJobError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4494,7 +7323,12 @@ impl CreateJobTemplateError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateJobTemplateError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4557,7 +7391,12 @@ impl CreatePresetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreatePresetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4620,7 +7459,12 @@ impl CreateQueueError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateQueueError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4685,7 +7529,12 @@ impl DeleteJobTemplateError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteJobTemplateError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4748,7 +7597,12 @@ impl DeletePresetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeletePresetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4811,7 +7665,12 @@ impl DeleteQueueError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteQueueError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4876,7 +7735,12 @@ impl DescribeEndpointsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeEndpointsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4943,7 +7807,12 @@ impl DisassociateCertificateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5002,7 +7871,12 @@ impl GetJobError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetJobError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5065,7 +7939,12 @@ impl GetJobTemplateError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetJobTemplateError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5128,7 +8007,12 @@ impl GetPresetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetPresetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5191,7 +8075,12 @@ impl GetQueueError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetQueueError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5256,7 +8145,12 @@ impl ListJobTemplatesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListJobTemplatesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5319,7 +8213,12 @@ impl ListJobsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListJobsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5382,7 +8281,12 @@ impl ListPresetsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListPresetsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5445,7 +8349,12 @@ impl ListQueuesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListQueuesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5510,7 +8419,12 @@ impl ListTagsForResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5573,7 +8487,12 @@ impl TagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5636,7 +8555,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5701,7 +8625,12 @@ impl UpdateJobTemplateError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateJobTemplateError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5764,7 +8693,12 @@ impl UpdatePresetError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdatePresetError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5827,7 +8761,12 @@ impl UpdateQueueError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateQueueError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5994,255 +8933,729 @@ pub trait MediaConvert {
         input: UpdateQueueRequest,
     ) -> RusotoFuture<UpdateQueueResponse, UpdateQueueError>;
 }
-/// A client for the MediaConvert API.
-#[derive(Clone)]
-pub struct MediaConvertClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MediaConvertClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MediaConvertClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MediaConvertClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MediaConvertClient {
-        MediaConvertClient { client, region }
-    }
-}
-
-impl MediaConvert for MediaConvertClient {
-    /// <p>Associates an AWS Certificate Manager (ACM) Amazon Resource Name (ARN) with AWS Elemental MediaConvert.</p>
+const _: Option<&dyn MediaConvert> = None;
+impl<'a, T: MediaConvert + ?Sized> MediaConvert for &'a T {
     fn associate_certificate(
         &self,
         input: AssociateCertificateRequest,
     ) -> RusotoFuture<AssociateCertificateResponse, AssociateCertificateError> {
-        let request_uri = "/2017-08-29/certificates";
-
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateCertificateResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AssociateCertificateError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).associate_certificate(input)
     }
-
-    /// <p>Permanently cancel a job. Once you have canceled a job, you can&#39;t start it again.</p>
     fn cancel_job(
         &self,
         input: CancelJobRequest,
     ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
-        let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
-
-        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelJobResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_job(input)
     }
-
-    /// <p>Create a new transcoding job. For information about jobs and job settings, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
     fn create_job(
         &self,
         input: CreateJobRequest,
     ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
-        let request_uri = "/2017-08-29/jobs";
-
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateJobResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_job(input)
     }
-
-    /// <p>Create a new job template. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
     fn create_job_template(
         &self,
         input: CreateJobTemplateRequest,
     ) -> RusotoFuture<CreateJobTemplateResponse, CreateJobTemplateError> {
-        let request_uri = "/2017-08-29/jobTemplates";
-
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateJobTemplateResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateJobTemplateError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_job_template(input)
     }
-
-    /// <p>Create a new preset. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
     fn create_preset(
         &self,
         input: CreatePresetRequest,
     ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
-        let request_uri = "/2017-08-29/presets";
-
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePresetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePresetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_preset(input)
     }
-
-    /// <p>Create a new transcoding queue. For information about queues, see Working With Queues in the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html</p>
     fn create_queue(
         &self,
         input: CreateQueueRequest,
     ) -> RusotoFuture<CreateQueueResponse, CreateQueueError> {
-        let request_uri = "/2017-08-29/queues";
-
-        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateQueueResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateQueueError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_queue(input)
     }
-
-    /// <p>Permanently delete a job template you have created.</p>
     fn delete_job_template(
         &self,
         input: DeleteJobTemplateRequest,
     ) -> RusotoFuture<DeleteJobTemplateResponse, DeleteJobTemplateError> {
-        let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
-
-        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 202 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteJobTemplateResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
+        (**self).delete_job_template(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> RusotoFuture<DeleteQueueResponse, DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints(input)
+    }
+    fn disassociate_certificate(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> RusotoFuture<DisassociateCertificateResponse, DisassociateCertificateError> {
+        (**self).disassociate_certificate(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_job_template(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> RusotoFuture<GetJobTemplateResponse, GetJobTemplateError> {
+        (**self).get_job_template(input)
+    }
+    fn get_preset(
+        &self,
+        input: GetPresetRequest,
+    ) -> RusotoFuture<GetPresetResponse, GetPresetError> {
+        (**self).get_preset(input)
+    }
+    fn get_queue(&self, input: GetQueueRequest) -> RusotoFuture<GetQueueResponse, GetQueueError> {
+        (**self).get_queue(input)
+    }
+    fn list_job_templates(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> RusotoFuture<ListJobTemplatesResponse, ListJobTemplatesError> {
+        (**self).list_job_templates(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResponse, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_job_template(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> RusotoFuture<UpdateJobTemplateResponse, UpdateJobTemplateError> {
+        (**self).update_job_template(input)
+    }
+    fn update_preset(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> RusotoFuture<UpdatePresetResponse, UpdatePresetError> {
+        (**self).update_preset(input)
+    }
+    fn update_queue(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> RusotoFuture<UpdateQueueResponse, UpdateQueueError> {
+        (**self).update_queue(input)
+    }
+}
+impl<T: MediaConvert + ?Sized> MediaConvert for Box<T> {
+    fn associate_certificate(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> RusotoFuture<AssociateCertificateResponse, AssociateCertificateError> {
+        (**self).associate_certificate(input)
+    }
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_job_template(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> RusotoFuture<CreateJobTemplateResponse, CreateJobTemplateError> {
+        (**self).create_job_template(input)
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        (**self).create_preset(input)
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResponse, CreateQueueError> {
+        (**self).create_queue(input)
+    }
+    fn delete_job_template(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> RusotoFuture<DeleteJobTemplateResponse, DeleteJobTemplateError> {
+        (**self).delete_job_template(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> RusotoFuture<DeleteQueueResponse, DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints(input)
+    }
+    fn disassociate_certificate(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> RusotoFuture<DisassociateCertificateResponse, DisassociateCertificateError> {
+        (**self).disassociate_certificate(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_job_template(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> RusotoFuture<GetJobTemplateResponse, GetJobTemplateError> {
+        (**self).get_job_template(input)
+    }
+    fn get_preset(
+        &self,
+        input: GetPresetRequest,
+    ) -> RusotoFuture<GetPresetResponse, GetPresetError> {
+        (**self).get_preset(input)
+    }
+    fn get_queue(&self, input: GetQueueRequest) -> RusotoFuture<GetQueueResponse, GetQueueError> {
+        (**self).get_queue(input)
+    }
+    fn list_job_templates(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> RusotoFuture<ListJobTemplatesResponse, ListJobTemplatesError> {
+        (**self).list_job_templates(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResponse, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_job_template(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> RusotoFuture<UpdateJobTemplateResponse, UpdateJobTemplateError> {
+        (**self).update_job_template(input)
+    }
+    fn update_preset(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> RusotoFuture<UpdatePresetResponse, UpdatePresetError> {
+        (**self).update_preset(input)
+    }
+    fn update_queue(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> RusotoFuture<UpdateQueueResponse, UpdateQueueError> {
+        (**self).update_queue(input)
+    }
+}
+impl<T: MediaConvert + ?Sized> MediaConvert for ::std::sync::Arc<T> {
+    fn associate_certificate(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> RusotoFuture<AssociateCertificateResponse, AssociateCertificateError> {
+        (**self).associate_certificate(input)
+    }
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_job_template(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> RusotoFuture<CreateJobTemplateResponse, CreateJobTemplateError> {
+        (**self).create_job_template(input)
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        (**self).create_preset(input)
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResponse, CreateQueueError> {
+        (**self).create_queue(input)
+    }
+    fn delete_job_template(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> RusotoFuture<DeleteJobTemplateResponse, DeleteJobTemplateError> {
+        (**self).delete_job_template(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> RusotoFuture<DeleteQueueResponse, DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints(input)
+    }
+    fn disassociate_certificate(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> RusotoFuture<DisassociateCertificateResponse, DisassociateCertificateError> {
+        (**self).disassociate_certificate(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_job_template(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> RusotoFuture<GetJobTemplateResponse, GetJobTemplateError> {
+        (**self).get_job_template(input)
+    }
+    fn get_preset(
+        &self,
+        input: GetPresetRequest,
+    ) -> RusotoFuture<GetPresetResponse, GetPresetError> {
+        (**self).get_preset(input)
+    }
+    fn get_queue(&self, input: GetQueueRequest) -> RusotoFuture<GetQueueResponse, GetQueueError> {
+        (**self).get_queue(input)
+    }
+    fn list_job_templates(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> RusotoFuture<ListJobTemplatesResponse, ListJobTemplatesError> {
+        (**self).list_job_templates(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResponse, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_job_template(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> RusotoFuture<UpdateJobTemplateResponse, UpdateJobTemplateError> {
+        (**self).update_job_template(input)
+    }
+    fn update_preset(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> RusotoFuture<UpdatePresetResponse, UpdatePresetError> {
+        (**self).update_preset(input)
+    }
+    fn update_queue(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> RusotoFuture<UpdateQueueResponse, UpdateQueueError> {
+        (**self).update_queue(input)
+    }
+}
+/// A client for the MediaConvert API.
+#[derive(Clone)]
+pub struct MediaConvertClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MediaConvertClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaConvertClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaConvertClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaConvertClient {
+        MediaConvertClient { client, region }
+    }
+}
+
+impl MediaConvert for MediaConvertClient {
+    /// <p>Associates an AWS Certificate Manager (ACM) Amazon Resource Name (ARN) with AWS Elemental MediaConvert.</p>
+    fn associate_certificate(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> RusotoFuture<AssociateCertificateResponse, AssociateCertificateError> {
+        let request_uri = "/2017-08-29/certificates";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("AssociateCertificate");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateCertificate")
+                        .deserialize::<AssociateCertificateResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AssociateCertificateError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Permanently cancel a job. Once you have canceled a job, you can&#39;t start it again.</p>
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CancelJob");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CancelJob")
+                        .deserialize::<CancelJobResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a new transcoding job. For information about jobs and job settings, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2017-08-29/jobs";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreateJob");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateJob")
+                        .deserialize::<CreateJobResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a new job template. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    fn create_job_template(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> RusotoFuture<CreateJobTemplateResponse, CreateJobTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2017-08-29/jobTemplates";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreateJobTemplate");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateJobTemplate")
+                        .deserialize::<CreateJobTemplateResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateJobTemplateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a new preset. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2017-08-29/presets";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreatePreset")
+                        .deserialize::<CreatePresetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePresetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a new transcoding queue. For information about queues, see Working With Queues in the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html</p>
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResponse, CreateQueueError> {
+        let request_uri = "/2017-08-29/queues";
+
+        let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("CreateQueue");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateQueue")
+                        .deserialize::<CreateQueueResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateQueueError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Permanently delete a job template you have created.</p>
+    fn delete_job_template(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> RusotoFuture<DeleteJobTemplateResponse, DeleteJobTemplateError> {
+        let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
+
+        let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DeleteJobTemplate");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 202 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteJobTemplate")
+                        .deserialize::<DeleteJobTemplateResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
                         .and_then(|response| Err(DeleteJobTemplateError::from_response(response))),
                 )
             }
@@ -6257,12 +9670,15 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
+        request.add_resource_id("Name", &input.name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeletePreset")
                         .deserialize::<DeletePresetResponse, _>()?;
 
                     Ok(result)
@@ -6286,12 +9702,15 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DeleteQueue");
+        request.add_resource_id("Name", &input.name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteQueue")
                         .deserialize::<DeleteQueueResponse, _>()?;
 
                     Ok(result)
@@ -6315,6 +9734,8 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/endpoints";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DescribeEndpoints");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6324,6 +9745,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeEndpoints")
                         .deserialize::<DescribeEndpointsResponse, _>()?;
 
                     Ok(result)
@@ -6347,12 +9769,15 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/certificates/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("DisassociateCertificate");
+        request.add_resource_id("Arn", &input.arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateCertificate")
                         .deserialize::<DisassociateCertificateResponse, _>()?;
 
                     Ok(result)
@@ -6370,12 +9795,16 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetJob");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetJob")
                         .deserialize::<GetJobResponse, _>()?;
 
                     Ok(result)
@@ -6399,12 +9828,16 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetJobTemplate");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetJobTemplate")
                         .deserialize::<GetJobTemplateResponse, _>()?;
 
                     Ok(result)
@@ -6428,12 +9861,16 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetPreset");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetPreset")
                         .deserialize::<GetPresetResponse, _>()?;
 
                     Ok(result)
@@ -6454,12 +9891,16 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("GetQueue");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetQueue")
                         .deserialize::<GetQueueResponse, _>()?;
 
                     Ok(result)
@@ -6480,9 +9921,19 @@ impl MediaConvert for MediaConvertClient {
         &self,
         input: ListJobTemplatesRequest,
     ) -> RusotoFuture<ListJobTemplatesResponse, ListJobTemplatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2017-08-29/jobTemplates";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListJobTemplates");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6507,6 +9958,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobTemplates")
                         .deserialize::<ListJobTemplatesResponse, _>()?;
 
                     Ok(result)
@@ -6524,9 +9976,19 @@ impl MediaConvert for MediaConvertClient {
 
     /// <p>Retrieve a JSON array of up to twenty of your most recently created jobs. This array includes in-process, completed, and errored jobs. This will return the jobs themselves, not just a list of the jobs. To retrieve the twenty next most recent jobs, use the nextToken string returned with the array.</p>
     fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2017-08-29/jobs";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListJobs");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6551,6 +10013,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJobs")
                         .deserialize::<ListJobsResponse, _>()?;
 
                     Ok(result)
@@ -6571,9 +10034,19 @@ impl MediaConvert for MediaConvertClient {
         &self,
         input: ListPresetsRequest,
     ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2017-08-29/presets";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListPresets");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6598,6 +10071,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPresets")
                         .deserialize::<ListPresetsResponse, _>()?;
 
                     Ok(result)
@@ -6618,9 +10092,19 @@ impl MediaConvert for MediaConvertClient {
         &self,
         input: ListQueuesRequest,
     ) -> RusotoFuture<ListQueuesResponse, ListQueuesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/2017-08-29/queues";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListQueues");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6642,6 +10126,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListQueues")
                         .deserialize::<ListQueuesResponse, _>()?;
 
                     Ok(result)
@@ -6665,12 +10150,16 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/tags/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -6693,6 +10182,7 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/tags";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6702,6 +10192,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
                         .deserialize::<TagResourceResponse, _>()?;
 
                     Ok(result)
@@ -6725,6 +10216,8 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/tags/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("Arn", &input.arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6734,6 +10227,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
                         .deserialize::<UntagResourceResponse, _>()?;
 
                     Ok(result)
@@ -6754,9 +10248,20 @@ impl MediaConvert for MediaConvertClient {
         &self,
         input: UpdateJobTemplateRequest,
     ) -> RusotoFuture<UpdateJobTemplateResponse, UpdateJobTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UpdateJobTemplate");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6766,6 +10271,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateJobTemplate")
                         .deserialize::<UpdateJobTemplateResponse, _>()?;
 
                     Ok(result)
@@ -6786,9 +10292,20 @@ impl MediaConvert for MediaConvertClient {
         &self,
         input: UpdatePresetRequest,
     ) -> RusotoFuture<UpdatePresetResponse, UpdatePresetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UpdatePreset");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6798,6 +10315,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdatePreset")
                         .deserialize::<UpdatePresetResponse, _>()?;
 
                     Ok(result)
@@ -6821,6 +10339,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation("UpdateQueue");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6830,6 +10351,7 @@ impl MediaConvert for MediaConvertClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateQueue")
                         .deserialize::<UpdateQueueResponse, _>()?;
 
                     Ok(result)
@@ -6845,3 +10367,847 @@ impl MediaConvert for MediaConvertClient {
         })
     }
 }
+
+/// A mock of the MediaConvert API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMediaConvert {
+    associate_certificate: Option<
+        Box<
+            dyn Fn(
+                    AssociateCertificateRequest,
+                ) -> ::std::result::Result<
+                    AssociateCertificateResponse,
+                    RusotoError<AssociateCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    cancel_job: Option<
+        Box<
+            dyn Fn(
+                    CancelJobRequest,
+                )
+                    -> ::std::result::Result<CancelJobResponse, RusotoError<CancelJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_job: Option<
+        Box<
+            dyn Fn(
+                    CreateJobRequest,
+                )
+                    -> ::std::result::Result<CreateJobResponse, RusotoError<CreateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_job_template: Option<
+        Box<
+            dyn Fn(
+                    CreateJobTemplateRequest,
+                ) -> ::std::result::Result<
+                    CreateJobTemplateResponse,
+                    RusotoError<CreateJobTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_preset: Option<
+        Box<
+            dyn Fn(
+                    CreatePresetRequest,
+                )
+                    -> ::std::result::Result<CreatePresetResponse, RusotoError<CreatePresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_queue: Option<
+        Box<
+            dyn Fn(
+                    CreateQueueRequest,
+                )
+                    -> ::std::result::Result<CreateQueueResponse, RusotoError<CreateQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_job_template: Option<
+        Box<
+            dyn Fn(
+                    DeleteJobTemplateRequest,
+                ) -> ::std::result::Result<
+                    DeleteJobTemplateResponse,
+                    RusotoError<DeleteJobTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_preset: Option<
+        Box<
+            dyn Fn(
+                    DeletePresetRequest,
+                )
+                    -> ::std::result::Result<DeletePresetResponse, RusotoError<DeletePresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_queue: Option<
+        Box<
+            dyn Fn(
+                    DeleteQueueRequest,
+                )
+                    -> ::std::result::Result<DeleteQueueResponse, RusotoError<DeleteQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_endpoints: Option<
+        Box<
+            dyn Fn(
+                    DescribeEndpointsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEndpointsResponse,
+                    RusotoError<DescribeEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_certificate: Option<
+        Box<
+            dyn Fn(
+                    DisassociateCertificateRequest,
+                ) -> ::std::result::Result<
+                    DisassociateCertificateResponse,
+                    RusotoError<DisassociateCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_job: Option<
+        Box<
+            dyn Fn(GetJobRequest) -> ::std::result::Result<GetJobResponse, RusotoError<GetJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_job_template: Option<
+        Box<
+            dyn Fn(
+                    GetJobTemplateRequest,
+                ) -> ::std::result::Result<
+                    GetJobTemplateResponse,
+                    RusotoError<GetJobTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_preset: Option<
+        Box<
+            dyn Fn(
+                    GetPresetRequest,
+                )
+                    -> ::std::result::Result<GetPresetResponse, RusotoError<GetPresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_queue: Option<
+        Box<
+            dyn Fn(
+                    GetQueueRequest,
+                )
+                    -> ::std::result::Result<GetQueueResponse, RusotoError<GetQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_job_templates: Option<
+        Box<
+            dyn Fn(
+                    ListJobTemplatesRequest,
+                ) -> ::std::result::Result<
+                    ListJobTemplatesResponse,
+                    RusotoError<ListJobTemplatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsRequest,
+                )
+                    -> ::std::result::Result<ListJobsResponse, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_presets: Option<
+        Box<
+            dyn Fn(
+                    ListPresetsRequest,
+                )
+                    -> ::std::result::Result<ListPresetsResponse, RusotoError<ListPresetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_queues: Option<
+        Box<
+            dyn Fn(
+                    ListQueuesRequest,
+                )
+                    -> ::std::result::Result<ListQueuesResponse, RusotoError<ListQueuesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_job_template: Option<
+        Box<
+            dyn Fn(
+                    UpdateJobTemplateRequest,
+                ) -> ::std::result::Result<
+                    UpdateJobTemplateResponse,
+                    RusotoError<UpdateJobTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_preset: Option<
+        Box<
+            dyn Fn(
+                    UpdatePresetRequest,
+                )
+                    -> ::std::result::Result<UpdatePresetResponse, RusotoError<UpdatePresetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_queue: Option<
+        Box<
+            dyn Fn(
+                    UpdateQueueRequest,
+                )
+                    -> ::std::result::Result<UpdateQueueResponse, RusotoError<UpdateQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMediaConvert {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMediaConvert {
+        Default::default()
+    }
+
+    /// Answers `associate_certificate` with what `handler` returns for its input.
+    pub fn with_associate_certificate<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                AssociateCertificateRequest,
+            ) -> ::std::result::Result<
+                AssociateCertificateResponse,
+                RusotoError<AssociateCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_job` with what `handler` returns for its input.
+    pub fn with_cancel_job<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                CancelJobRequest,
+            ) -> ::std::result::Result<CancelJobResponse, RusotoError<CancelJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job` with what `handler` returns for its input.
+    pub fn with_create_job<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                CreateJobRequest,
+            ) -> ::std::result::Result<CreateJobResponse, RusotoError<CreateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job_template` with what `handler` returns for its input.
+    pub fn with_create_job_template<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                CreateJobTemplateRequest,
+            ) -> ::std::result::Result<
+                CreateJobTemplateResponse,
+                RusotoError<CreateJobTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_preset` with what `handler` returns for its input.
+    pub fn with_create_preset<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                CreatePresetRequest,
+            )
+                -> ::std::result::Result<CreatePresetResponse, RusotoError<CreatePresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_queue` with what `handler` returns for its input.
+    pub fn with_create_queue<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                CreateQueueRequest,
+            )
+                -> ::std::result::Result<CreateQueueResponse, RusotoError<CreateQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_job_template` with what `handler` returns for its input.
+    pub fn with_delete_job_template<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                DeleteJobTemplateRequest,
+            ) -> ::std::result::Result<
+                DeleteJobTemplateResponse,
+                RusotoError<DeleteJobTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_job_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_preset` with what `handler` returns for its input.
+    pub fn with_delete_preset<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                DeletePresetRequest,
+            )
+                -> ::std::result::Result<DeletePresetResponse, RusotoError<DeletePresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_queue` with what `handler` returns for its input.
+    pub fn with_delete_queue<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                DeleteQueueRequest,
+            )
+                -> ::std::result::Result<DeleteQueueResponse, RusotoError<DeleteQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_endpoints` with what `handler` returns for its input.
+    pub fn with_describe_endpoints<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                DescribeEndpointsRequest,
+            ) -> ::std::result::Result<
+                DescribeEndpointsResponse,
+                RusotoError<DescribeEndpointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_certificate` with what `handler` returns for its input.
+    pub fn with_disassociate_certificate<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                DisassociateCertificateRequest,
+            ) -> ::std::result::Result<
+                DisassociateCertificateResponse,
+                RusotoError<DisassociateCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job` with what `handler` returns for its input.
+    pub fn with_get_job<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(GetJobRequest) -> ::std::result::Result<GetJobResponse, RusotoError<GetJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_template` with what `handler` returns for its input.
+    pub fn with_get_job_template<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                GetJobTemplateRequest,
+            )
+                -> ::std::result::Result<GetJobTemplateResponse, RusotoError<GetJobTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_preset` with what `handler` returns for its input.
+    pub fn with_get_preset<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                GetPresetRequest,
+            ) -> ::std::result::Result<GetPresetResponse, RusotoError<GetPresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_queue` with what `handler` returns for its input.
+    pub fn with_get_queue<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                GetQueueRequest,
+            ) -> ::std::result::Result<GetQueueResponse, RusotoError<GetQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_job_templates` with what `handler` returns for its input.
+    pub fn with_list_job_templates<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                ListJobTemplatesRequest,
+            ) -> ::std::result::Result<
+                ListJobTemplatesResponse,
+                RusotoError<ListJobTemplatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_job_templates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                ListJobsRequest,
+            ) -> ::std::result::Result<ListJobsResponse, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_presets` with what `handler` returns for its input.
+    pub fn with_list_presets<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                ListPresetsRequest,
+            )
+                -> ::std::result::Result<ListPresetsResponse, RusotoError<ListPresetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_presets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_queues` with what `handler` returns for its input.
+    pub fn with_list_queues<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                ListQueuesRequest,
+            )
+                -> ::std::result::Result<ListQueuesResponse, RusotoError<ListQueuesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_queues = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_job_template` with what `handler` returns for its input.
+    pub fn with_update_job_template<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                UpdateJobTemplateRequest,
+            ) -> ::std::result::Result<
+                UpdateJobTemplateResponse,
+                RusotoError<UpdateJobTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_job_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_preset` with what `handler` returns for its input.
+    pub fn with_update_preset<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                UpdatePresetRequest,
+            )
+                -> ::std::result::Result<UpdatePresetResponse, RusotoError<UpdatePresetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_preset = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_queue` with what `handler` returns for its input.
+    pub fn with_update_queue<F>(mut self, handler: F) -> MockMediaConvert
+    where
+        F: Fn(
+                UpdateQueueRequest,
+            )
+                -> ::std::result::Result<UpdateQueueResponse, RusotoError<UpdateQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_queue = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MediaConvert for MockMediaConvert {
+    fn associate_certificate(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> RusotoFuture<AssociateCertificateResponse, AssociateCertificateError> {
+        match self.associate_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for associate_certificate"),
+        }
+    }
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        match self.cancel_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for cancel_job"),
+        }
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        match self.create_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for create_job"),
+        }
+    }
+    fn create_job_template(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> RusotoFuture<CreateJobTemplateResponse, CreateJobTemplateError> {
+        match self.create_job_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for create_job_template"),
+        }
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        match self.create_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for create_preset"),
+        }
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResponse, CreateQueueError> {
+        match self.create_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for create_queue"),
+        }
+    }
+    fn delete_job_template(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> RusotoFuture<DeleteJobTemplateResponse, DeleteJobTemplateError> {
+        match self.delete_job_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for delete_job_template"),
+        }
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        match self.delete_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for delete_preset"),
+        }
+    }
+    fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> RusotoFuture<DeleteQueueResponse, DeleteQueueError> {
+        match self.delete_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for delete_queue"),
+        }
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        match self.describe_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for describe_endpoints"),
+        }
+    }
+    fn disassociate_certificate(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> RusotoFuture<DisassociateCertificateResponse, DisassociateCertificateError> {
+        match self.disassociate_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for disassociate_certificate"),
+        }
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        match self.get_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for get_job"),
+        }
+    }
+    fn get_job_template(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> RusotoFuture<GetJobTemplateResponse, GetJobTemplateError> {
+        match self.get_job_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for get_job_template"),
+        }
+    }
+    fn get_preset(
+        &self,
+        input: GetPresetRequest,
+    ) -> RusotoFuture<GetPresetResponse, GetPresetError> {
+        match self.get_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for get_preset"),
+        }
+    }
+    fn get_queue(&self, input: GetQueueRequest) -> RusotoFuture<GetQueueResponse, GetQueueError> {
+        match self.get_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for get_queue"),
+        }
+    }
+    fn list_job_templates(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> RusotoFuture<ListJobTemplatesResponse, ListJobTemplatesError> {
+        match self.list_job_templates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for list_job_templates"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for list_jobs"),
+        }
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        match self.list_presets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for list_presets"),
+        }
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResponse, ListQueuesError> {
+        match self.list_queues {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for list_queues"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for untag_resource"),
+        }
+    }
+    fn update_job_template(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> RusotoFuture<UpdateJobTemplateResponse, UpdateJobTemplateError> {
+        match self.update_job_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for update_job_template"),
+        }
+    }
+    fn update_preset(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> RusotoFuture<UpdatePresetResponse, UpdatePresetError> {
+        match self.update_preset {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for update_preset"),
+        }
+    }
+    fn update_queue(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> RusotoFuture<UpdateQueueResponse, UpdateQueueError> {
+        match self.update_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaConvert has no handler for update_queue"),
+        }
+    }
+}
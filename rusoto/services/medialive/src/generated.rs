@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Aac Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -100,6 +101,14 @@ pub struct Ac3Settings {
     pub metadata_control: Option<String>,
 }
 
+impl Validate for Ac3Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dialnorm {
+            errors.check_range(&field_path(path, "dialnorm"), *value, Some(1), Some(31));
+        }
+    }
+}
+
 /// <p>Archive Container Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArchiveContainerSettings {
@@ -108,6 +117,14 @@ pub struct ArchiveContainerSettings {
     pub m_2ts_settings: Option<M2tsSettings>,
 }
 
+impl Validate for ArchiveContainerSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.m_2ts_settings {
+            value.validate_at(&field_path(path, "m_2ts_settings"), errors);
+        }
+    }
+}
+
 /// <p>Archive Group Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArchiveGroupSettings {
@@ -120,6 +137,19 @@ pub struct ArchiveGroupSettings {
     pub rollover_interval: Option<i64>,
 }
 
+impl Validate for ArchiveGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.rollover_interval {
+            errors.check_range(
+                &field_path(path, "rollover_interval"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Archive Output Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArchiveOutputSettings {
@@ -136,6 +166,13 @@ pub struct ArchiveOutputSettings {
     pub name_modifier: Option<String>,
 }
 
+impl Validate for ArchiveOutputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.container_settings
+            .validate_at(&field_path(path, "container_settings"), errors);
+    }
+}
+
 /// <p>Arib Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AribDestinationSettings {}
@@ -155,6 +192,21 @@ pub struct AudioChannelMapping {
     pub output_channel: i64,
 }
 
+impl Validate for AudioChannelMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.input_channel_levels.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "input_channel_levels"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_range(
+            &field_path(path, "output_channel"),
+            self.output_channel,
+            None,
+            Some(7),
+        );
+    }
+}
+
 /// <p>Audio Codec Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioCodecSettings {
@@ -175,6 +227,17 @@ pub struct AudioCodecSettings {
     pub pass_through_settings: Option<PassThroughSettings>,
 }
 
+impl Validate for AudioCodecSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ac_3_settings {
+            value.validate_at(&field_path(path, "ac_3_settings"), errors);
+        }
+        if let Some(ref value) = self.eac_3_settings {
+            value.validate_at(&field_path(path, "eac_3_settings"), errors);
+        }
+    }
+}
+
 /// <p>Audio Description</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioDescription {
@@ -221,6 +284,31 @@ pub struct AudioDescription {
     pub stream_name: Option<String>,
 }
 
+impl Validate for AudioDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.codec_settings {
+            value.validate_at(&field_path(path, "codec_settings"), errors);
+        }
+        if let Some(ref value) = self.language_code {
+            errors.check_length(
+                &field_path(path, "language_code"),
+                value.chars().count(),
+                Some(1),
+                Some(35),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(255),
+        );
+        if let Some(ref value) = self.remix_settings {
+            value.validate_at(&field_path(path, "remix_settings"), errors);
+        }
+    }
+}
+
 /// <p>Audio Language Selection</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioLanguageSelection {
@@ -281,6 +369,14 @@ pub struct AudioOnlyHlsSettings {
     pub audio_track_type: Option<String>,
 }
 
+impl Validate for AudioOnlyHlsSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_only_image {
+            value.validate_at(&field_path(path, "audio_only_image"), errors);
+        }
+    }
+}
+
 /// <p>Audio Pid Selection</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioPidSelection {
@@ -289,6 +385,12 @@ pub struct AudioPidSelection {
     pub pid: i64,
 }
 
+impl Validate for AudioPidSelection {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(&field_path(path, "pid"), self.pid, None, Some(8191));
+    }
+}
+
 /// <p>Audio Selector</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioSelector {
@@ -301,6 +403,20 @@ pub struct AudioSelector {
     pub selector_settings: Option<AudioSelectorSettings>,
 }
 
+impl Validate for AudioSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.selector_settings {
+            value.validate_at(&field_path(path, "selector_settings"), errors);
+        }
+    }
+}
+
 /// <p>Audio Selector Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioSelectorSettings {
@@ -312,6 +428,14 @@ pub struct AudioSelectorSettings {
     pub audio_pid_selection: Option<AudioPidSelection>,
 }
 
+impl Validate for AudioSelectorSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_pid_selection {
+            value.validate_at(&field_path(path, "audio_pid_selection"), errors);
+        }
+    }
+}
+
 /// <p>Avail Blanking</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AvailBlanking {
@@ -325,6 +449,14 @@ pub struct AvailBlanking {
     pub state: Option<String>,
 }
 
+impl Validate for AvailBlanking {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.avail_blanking_image {
+            value.validate_at(&field_path(path, "avail_blanking_image"), errors);
+        }
+    }
+}
+
 /// <p>Avail Configuration</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AvailConfiguration {
@@ -334,6 +466,14 @@ pub struct AvailConfiguration {
     pub avail_settings: Option<AvailSettings>,
 }
 
+impl Validate for AvailConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.avail_settings {
+            value.validate_at(&field_path(path, "avail_settings"), errors);
+        }
+    }
+}
+
 /// <p>Avail Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AvailSettings {
@@ -345,17 +485,38 @@ pub struct AvailSettings {
     pub scte_35_time_signal_apos: Option<Scte35TimeSignalApos>,
 }
 
+impl Validate for AvailSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.scte_35_splice_insert {
+            value.validate_at(&field_path(path, "scte_35_splice_insert"), errors);
+        }
+        if let Some(ref value) = self.scte_35_time_signal_apos {
+            value.validate_at(&field_path(path, "scte_35_time_signal_apos"), errors);
+        }
+    }
+}
+
 /// <p>A list of schedule actions to create (in a request) or that have been created (in a response).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchScheduleActionCreateRequest {
     /// <p>A list of schedule actions to create.</p>
     #[serde(rename = "ScheduleActions")]
     pub schedule_actions: Vec<ScheduleAction>,
 }
 
+impl Validate for BatchScheduleActionCreateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.schedule_actions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "schedule_actions"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>List of actions that have been created in the schedule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchScheduleActionCreateResult {
     /// <p>List of actions that have been created in the schedule.</p>
     #[serde(rename = "ScheduleActions")]
@@ -364,6 +525,7 @@ pub struct BatchScheduleActionCreateResult {
 
 /// <p>A list of schedule actions to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchScheduleActionDeleteRequest {
     /// <p>A list of schedule actions to delete.</p>
     #[serde(rename = "ActionNames")]
@@ -372,7 +534,7 @@ pub struct BatchScheduleActionDeleteRequest {
 
 /// <p>List of actions that have been deleted from the schedule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchScheduleActionDeleteResult {
     /// <p>List of actions that have been deleted from the schedule.</p>
     #[serde(rename = "ScheduleActions")]
@@ -381,6 +543,7 @@ pub struct BatchScheduleActionDeleteResult {
 
 /// <p>List of actions to create and list of actions to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchUpdateScheduleRequest {
     /// <p>Id of the channel whose schedule is being updated.</p>
     #[serde(rename = "ChannelId")]
@@ -395,9 +558,17 @@ pub struct BatchUpdateScheduleRequest {
     pub deletes: Option<BatchScheduleActionDeleteRequest>,
 }
 
+impl Validate for BatchUpdateScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.creates {
+            value.validate_at(&field_path(path, "creates"), errors);
+        }
+    }
+}
+
 /// <p>Placeholder documentation for BatchUpdateScheduleResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchUpdateScheduleResponse {
     /// <p>Schedule actions created in the schedule.</p>
     #[serde(rename = "Creates")]
@@ -434,6 +605,25 @@ pub struct BlackoutSlate {
     pub state: Option<String>,
 }
 
+impl Validate for BlackoutSlate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.blackout_slate_image {
+            value.validate_at(&field_path(path, "blackout_slate_image"), errors);
+        }
+        if let Some(ref value) = self.network_end_blackout_image {
+            value.validate_at(&field_path(path, "network_end_blackout_image"), errors);
+        }
+        if let Some(ref value) = self.network_id {
+            errors.check_length(
+                &field_path(path, "network_id"),
+                value.chars().count(),
+                Some(34),
+                Some(34),
+            );
+        }
+    }
+}
+
 /// <p>Burn In Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BurnInDestinationSettings {
@@ -507,6 +697,39 @@ pub struct BurnInDestinationSettings {
     pub y_position: Option<i64>,
 }
 
+impl Validate for BurnInDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.background_opacity {
+            errors.check_range(
+                &field_path(path, "background_opacity"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.font {
+            value.validate_at(&field_path(path, "font"), errors);
+        }
+        if let Some(ref value) = self.font_opacity {
+            errors.check_range(&field_path(path, "font_opacity"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.font_resolution {
+            errors.check_range(
+                &field_path(path, "font_resolution"),
+                *value,
+                Some(96),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.outline_size {
+            errors.check_range(&field_path(path, "outline_size"), *value, None, Some(10));
+        }
+        if let Some(ref value) = self.shadow_opacity {
+            errors.check_range(&field_path(path, "shadow_opacity"), *value, None, Some(255));
+        }
+    }
+}
+
 /// <p>Output groups for this Live Event. Output groups contain information about where streams should be distributed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionDescription {
@@ -530,6 +753,14 @@ pub struct CaptionDescription {
     pub name: String,
 }
 
+impl Validate for CaptionDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_settings {
+            value.validate_at(&field_path(path, "destination_settings"), errors);
+        }
+    }
+}
+
 /// <p>Caption Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionDestinationSettings {
@@ -571,6 +802,17 @@ pub struct CaptionDestinationSettings {
     pub webvtt_destination_settings: Option<WebvttDestinationSettings>,
 }
 
+impl Validate for CaptionDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.burn_in_destination_settings {
+            value.validate_at(&field_path(path, "burn_in_destination_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_sub_destination_settings {
+            value.validate_at(&field_path(path, "dvb_sub_destination_settings"), errors);
+        }
+    }
+}
+
 /// <p>Maps a caption channel to an ISO 693-2 language code (http://www.loc.gov/standards/iso639-2), with an optional description.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionLanguageMapping {
@@ -585,6 +827,29 @@ pub struct CaptionLanguageMapping {
     pub language_description: String,
 }
 
+impl Validate for CaptionLanguageMapping {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "caption_channel"),
+            self.caption_channel,
+            Some(1),
+            Some(4),
+        );
+        errors.check_length(
+            &field_path(path, "language_code"),
+            self.language_code.chars().count(),
+            Some(3),
+            Some(3),
+        );
+        errors.check_length(
+            &field_path(path, "language_description"),
+            self.language_description.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Output groups for this Live Event. Output groups contain information about where streams should be distributed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionSelector {
@@ -601,6 +866,20 @@ pub struct CaptionSelector {
     pub selector_settings: Option<CaptionSelectorSettings>,
 }
 
+impl Validate for CaptionSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.selector_settings {
+            value.validate_at(&field_path(path, "selector_settings"), errors);
+        }
+    }
+}
+
 /// <p>Caption Selector Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaptionSelectorSettings {
@@ -624,9 +903,26 @@ pub struct CaptionSelectorSettings {
     pub teletext_source_settings: Option<TeletextSourceSettings>,
 }
 
+impl Validate for CaptionSelectorSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dvb_sub_source_settings {
+            value.validate_at(&field_path(path, "dvb_sub_source_settings"), errors);
+        }
+        if let Some(ref value) = self.embedded_source_settings {
+            value.validate_at(&field_path(path, "embedded_source_settings"), errors);
+        }
+        if let Some(ref value) = self.scte_20_source_settings {
+            value.validate_at(&field_path(path, "scte_20_source_settings"), errors);
+        }
+        if let Some(ref value) = self.scte_27_source_settings {
+            value.validate_at(&field_path(path, "scte_27_source_settings"), errors);
+        }
+    }
+}
+
 /// <p>Placeholder documentation for Channel</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Channel {
     /// <p>The unique arn of the channel.</p>
     #[serde(rename = "Arn")]
@@ -691,7 +987,7 @@ pub struct Channel {
 
 /// <p>Placeholder documentation for ChannelEgressEndpoint</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChannelEgressEndpoint {
     /// <p>Public IP of where a channel&#39;s output comes from</p>
     #[serde(rename = "SourceIp")]
@@ -701,7 +997,7 @@ pub struct ChannelEgressEndpoint {
 
 /// <p>Placeholder documentation for ChannelSummary</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChannelSummary {
     /// <p>The unique arn of the channel.</p>
     #[serde(rename = "Arn")]
@@ -763,6 +1059,7 @@ pub struct ColorSpacePassthroughSettings {}
 
 /// <p>A request to create a channel</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateChannelRequest {
     /// <p>The class for this channel. STANDARD for a channel with two pipelines or SINGLE_PIPELINE for a channel with one pipeline.</p>
     #[serde(rename = "ChannelClass")]
@@ -805,9 +1102,29 @@ pub struct CreateChannelRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destinations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "destinations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.encoder_settings {
+            value.validate_at(&field_path(path, "encoder_settings"), errors);
+        }
+        if let Some(ref value) = self.input_attachments {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_attachments"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Placeholder documentation for CreateChannelResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateChannelResponse {
     #[serde(rename = "Channel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -816,6 +1133,7 @@ pub struct CreateChannelResponse {
 
 /// <p>The name of the input</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInputRequest {
     /// <p>Destination settings for PUSH type inputs.</p>
     #[serde(rename = "Destinations")]
@@ -864,7 +1182,7 @@ pub struct CreateInputRequest {
 
 /// <p>Placeholder documentation for CreateInputResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInputResponse {
     #[serde(rename = "Input")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -873,6 +1191,7 @@ pub struct CreateInputResponse {
 
 /// <p>The IPv4 CIDRs to whitelist for this Input Security Group</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInputSecurityGroupRequest {
     /// <p>A collection of key-value pairs.</p>
     #[serde(rename = "Tags")]
@@ -886,7 +1205,7 @@ pub struct CreateInputSecurityGroupRequest {
 
 /// <p>Placeholder documentation for CreateInputSecurityGroupResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInputSecurityGroupResponse {
     #[serde(rename = "SecurityGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -895,6 +1214,7 @@ pub struct CreateInputSecurityGroupResponse {
 
 /// <p>Placeholder documentation for CreateTagsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagsRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
@@ -905,6 +1225,7 @@ pub struct CreateTagsRequest {
 
 /// <p>Placeholder documentation for DeleteChannelRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteChannelRequest {
     /// <p>Unique ID of the channel.</p>
     #[serde(rename = "ChannelId")]
@@ -913,7 +1234,7 @@ pub struct DeleteChannelRequest {
 
 /// <p>Placeholder documentation for DeleteChannelResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteChannelResponse {
     /// <p>The unique arn of the channel.</p>
     #[serde(rename = "Arn")]
@@ -978,6 +1299,7 @@ pub struct DeleteChannelResponse {
 
 /// <p>Placeholder documentation for DeleteInputRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInputRequest {
     /// <p>Unique ID of the input</p>
     #[serde(rename = "InputId")]
@@ -986,11 +1308,12 @@ pub struct DeleteInputRequest {
 
 /// <p>Placeholder documentation for DeleteInputResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInputResponse {}
 
 /// <p>Placeholder documentation for DeleteInputSecurityGroupRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInputSecurityGroupRequest {
     /// <p>The Input Security Group to delete</p>
     #[serde(rename = "InputSecurityGroupId")]
@@ -999,11 +1322,12 @@ pub struct DeleteInputSecurityGroupRequest {
 
 /// <p>Placeholder documentation for DeleteInputSecurityGroupResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInputSecurityGroupResponse {}
 
 /// <p>Placeholder documentation for DeleteReservationRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReservationRequest {
     /// <p>Unique reservation ID, e.g. &#39;1234567&#39;</p>
     #[serde(rename = "ReservationId")]
@@ -1012,7 +1336,7 @@ pub struct DeleteReservationRequest {
 
 /// <p>Placeholder documentation for DeleteReservationResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteReservationResponse {
     /// <p>Unique reservation ARN, e.g. &#39;arn:aws:medialive:us-west-2:123456789012:reservation:1234567&#39;</p>
     #[serde(rename = "Arn")]
@@ -1090,6 +1414,7 @@ pub struct DeleteReservationResponse {
 
 /// <p>Placeholder documentation for DeleteScheduleRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteScheduleRequest {
     /// <p>Id of the channel whose schedule is being deleted.</p>
     #[serde(rename = "ChannelId")]
@@ -1098,11 +1423,12 @@ pub struct DeleteScheduleRequest {
 
 /// <p>Placeholder documentation for DeleteScheduleResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteScheduleResponse {}
 
 /// <p>Placeholder documentation for DeleteTagsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
@@ -1113,6 +1439,7 @@ pub struct DeleteTagsRequest {
 
 /// <p>Placeholder documentation for DescribeChannelRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeChannelRequest {
     /// <p>channel ID</p>
     #[serde(rename = "ChannelId")]
@@ -1121,7 +1448,7 @@ pub struct DescribeChannelRequest {
 
 /// <p>Placeholder documentation for DescribeChannelResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeChannelResponse {
     /// <p>The unique arn of the channel.</p>
     #[serde(rename = "Arn")]
@@ -1186,6 +1513,7 @@ pub struct DescribeChannelResponse {
 
 /// <p>Placeholder documentation for DescribeInputRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInputRequest {
     /// <p>Unique ID of the input</p>
     #[serde(rename = "InputId")]
@@ -1194,7 +1522,7 @@ pub struct DescribeInputRequest {
 
 /// <p>Placeholder documentation for DescribeInputResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInputResponse {
     /// <p>The Unique ARN of the input (generated, immutable).</p>
     #[serde(rename = "Arn")]
@@ -1256,6 +1584,7 @@ pub struct DescribeInputResponse {
 
 /// <p>Placeholder documentation for DescribeInputSecurityGroupRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInputSecurityGroupRequest {
     /// <p>The id of the Input Security Group to describe</p>
     #[serde(rename = "InputSecurityGroupId")]
@@ -1264,7 +1593,7 @@ pub struct DescribeInputSecurityGroupRequest {
 
 /// <p>Placeholder documentation for DescribeInputSecurityGroupResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInputSecurityGroupResponse {
     /// <p>Unique ARN of Input Security Group</p>
     #[serde(rename = "Arn")]
@@ -1294,6 +1623,7 @@ pub struct DescribeInputSecurityGroupResponse {
 
 /// <p>Placeholder documentation for DescribeOfferingRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOfferingRequest {
     /// <p>Unique offering ID, e.g. &#39;87654321&#39;</p>
     #[serde(rename = "OfferingId")]
@@ -1302,7 +1632,7 @@ pub struct DescribeOfferingRequest {
 
 /// <p>Placeholder documentation for DescribeOfferingResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOfferingResponse {
     /// <p>Unique offering ARN, e.g. &#39;arn:aws:medialive:us-west-2:123456789012:offering:87654321&#39;</p>
     #[serde(rename = "Arn")]
@@ -1352,6 +1682,7 @@ pub struct DescribeOfferingResponse {
 
 /// <p>Placeholder documentation for DescribeReservationRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReservationRequest {
     /// <p>Unique reservation ID, e.g. &#39;1234567&#39;</p>
     #[serde(rename = "ReservationId")]
@@ -1360,7 +1691,7 @@ pub struct DescribeReservationRequest {
 
 /// <p>Placeholder documentation for DescribeReservationResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeReservationResponse {
     /// <p>Unique reservation ARN, e.g. &#39;arn:aws:medialive:us-west-2:123456789012:reservation:1234567&#39;</p>
     #[serde(rename = "Arn")]
@@ -1438,6 +1769,7 @@ pub struct DescribeReservationResponse {
 
 /// <p>Placeholder documentation for DescribeScheduleRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeScheduleRequest {
     /// <p>Id of the channel whose schedule is being updated.</p>
     #[serde(rename = "ChannelId")]
@@ -1450,9 +1782,22 @@ pub struct DescribeScheduleRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Placeholder documentation for DescribeScheduleResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeScheduleResponse {
     /// <p>The next token; for use in pagination.</p>
     #[serde(rename = "NextToken")]
@@ -1479,6 +1824,31 @@ pub struct DvbNitSettings {
     pub rep_interval: Option<i64>,
 }
 
+impl Validate for DvbNitSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "network_id"),
+            self.network_id,
+            None,
+            Some(65536),
+        );
+        errors.check_length(
+            &field_path(path, "network_name"),
+            self.network_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.rep_interval {
+            errors.check_range(
+                &field_path(path, "rep_interval"),
+                *value,
+                Some(25),
+                Some(10000),
+            );
+        }
+    }
+}
+
 /// <p>DVB Service Description Table (SDT)</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbSdtSettings {
@@ -1500,6 +1870,35 @@ pub struct DvbSdtSettings {
     pub service_provider_name: Option<String>,
 }
 
+impl Validate for DvbSdtSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.rep_interval {
+            errors.check_range(
+                &field_path(path, "rep_interval"),
+                *value,
+                Some(25),
+                Some(2000),
+            );
+        }
+        if let Some(ref value) = self.service_name {
+            errors.check_length(
+                &field_path(path, "service_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.service_provider_name {
+            errors.check_length(
+                &field_path(path, "service_provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Dvb Sub Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbSubDestinationSettings {
@@ -1573,6 +1972,39 @@ pub struct DvbSubDestinationSettings {
     pub y_position: Option<i64>,
 }
 
+impl Validate for DvbSubDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.background_opacity {
+            errors.check_range(
+                &field_path(path, "background_opacity"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.font {
+            value.validate_at(&field_path(path, "font"), errors);
+        }
+        if let Some(ref value) = self.font_opacity {
+            errors.check_range(&field_path(path, "font_opacity"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.font_resolution {
+            errors.check_range(
+                &field_path(path, "font_resolution"),
+                *value,
+                Some(96),
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.outline_size {
+            errors.check_range(&field_path(path, "outline_size"), *value, None, Some(10));
+        }
+        if let Some(ref value) = self.shadow_opacity {
+            errors.check_range(&field_path(path, "shadow_opacity"), *value, None, Some(255));
+        }
+    }
+}
+
 /// <p>Dvb Sub Source Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbSubSourceSettings {
@@ -1582,6 +2014,14 @@ pub struct DvbSubSourceSettings {
     pub pid: Option<i64>,
 }
 
+impl Validate for DvbSubSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pid {
+            errors.check_range(&field_path(path, "pid"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>DVB Time and Date Table (SDT)</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DvbTdtSettings {
@@ -1591,6 +2031,19 @@ pub struct DvbTdtSettings {
     pub rep_interval: Option<i64>,
 }
 
+impl Validate for DvbTdtSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.rep_interval {
+            errors.check_range(
+                &field_path(path, "rep_interval"),
+                *value,
+                Some(1000),
+                Some(30000),
+            );
+        }
+    }
+}
+
 /// <p>Eac3 Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Eac3Settings {
@@ -1676,6 +2129,14 @@ pub struct Eac3Settings {
     pub surround_mode: Option<String>,
 }
 
+impl Validate for Eac3Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dialnorm {
+            errors.check_range(&field_path(path, "dialnorm"), *value, Some(1), Some(31));
+        }
+    }
+}
+
 /// <p>Embedded Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmbeddedDestinationSettings {}
@@ -1705,6 +2166,27 @@ pub struct EmbeddedSourceSettings {
     pub source_608_track_number: Option<i64>,
 }
 
+impl Validate for EmbeddedSourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_608_channel_number {
+            errors.check_range(
+                &field_path(path, "source_608_channel_number"),
+                *value,
+                Some(1),
+                Some(4),
+            );
+        }
+        if let Some(ref value) = self.source_608_track_number {
+            errors.check_range(
+                &field_path(path, "source_608_track_number"),
+                *value,
+                Some(1),
+                Some(5),
+            );
+        }
+    }
+}
+
 /// <p>Encoder Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncoderSettings {
@@ -1739,6 +2221,43 @@ pub struct EncoderSettings {
     pub video_descriptions: Vec<VideoDescription>,
 }
 
+impl Validate for EncoderSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.audio_descriptions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "audio_descriptions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.avail_blanking {
+            value.validate_at(&field_path(path, "avail_blanking"), errors);
+        }
+        if let Some(ref value) = self.avail_configuration {
+            value.validate_at(&field_path(path, "avail_configuration"), errors);
+        }
+        if let Some(ref value) = self.blackout_slate {
+            value.validate_at(&field_path(path, "blackout_slate"), errors);
+        }
+        if let Some(ref value) = self.caption_descriptions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_descriptions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.global_configuration {
+            value.validate_at(&field_path(path, "global_configuration"), errors);
+        }
+        for (i, item) in self.output_groups.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "output_groups"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.timecode_config
+            .validate_at(&field_path(path, "timecode_config"), errors);
+        for (i, item) in self.video_descriptions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "video_descriptions"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Fec Output Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FecOutputSettings {
@@ -1756,6 +2275,17 @@ pub struct FecOutputSettings {
     pub row_length: Option<i64>,
 }
 
+impl Validate for FecOutputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.column_depth {
+            errors.check_range(&field_path(path, "column_depth"), *value, Some(4), Some(20));
+        }
+        if let Some(ref value) = self.row_length {
+            errors.check_range(&field_path(path, "row_length"), *value, Some(1), Some(20));
+        }
+    }
+}
+
 /// <p>Start time for the action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FixedModeScheduleActionStartSettings {
@@ -1800,6 +2330,17 @@ pub struct FrameCaptureSettings {
     pub capture_interval: i64,
 }
 
+impl Validate for FrameCaptureSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "capture_interval"),
+            self.capture_interval,
+            Some(1),
+            Some(3600000),
+        );
+    }
+}
+
 /// <p>Global Configuration</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GlobalConfiguration {
@@ -1832,6 +2373,22 @@ pub struct GlobalConfiguration {
     pub support_low_framerate_inputs: Option<String>,
 }
 
+impl Validate for GlobalConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.initial_audio_gain {
+            errors.check_range(
+                &field_path(path, "initial_audio_gain"),
+                *value,
+                None,
+                Some(60),
+            );
+        }
+        if let Some(ref value) = self.input_loss_behavior {
+            value.validate_at(&field_path(path, "input_loss_behavior"), errors);
+        }
+    }
+}
+
 /// <p>H264 Color Space Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct H264ColorSpaceSettings {
@@ -2025,6 +2582,70 @@ pub struct H264Settings {
     pub timecode_insertion: Option<String>,
 }
 
+impl Validate for H264Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(&field_path(path, "bitrate"), *value, Some(1000), None);
+        }
+        if let Some(ref value) = self.buf_fill_pct {
+            errors.check_range(&field_path(path, "buf_fill_pct"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.framerate_denominator {
+            errors.check_range(
+                &field_path(path, "framerate_denominator"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.framerate_numerator {
+            errors.check_range(
+                &field_path(path, "framerate_numerator"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.gop_num_b_frames {
+            errors.check_range(&field_path(path, "gop_num_b_frames"), *value, None, Some(7));
+        }
+        if let Some(ref value) = self.max_bitrate {
+            errors.check_range(&field_path(path, "max_bitrate"), *value, Some(1000), None);
+        }
+        if let Some(ref value) = self.min_i_interval {
+            errors.check_range(&field_path(path, "min_i_interval"), *value, None, Some(30));
+        }
+        if let Some(ref value) = self.num_ref_frames {
+            errors.check_range(
+                &field_path(path, "num_ref_frames"),
+                *value,
+                Some(1),
+                Some(6),
+            );
+        }
+        if let Some(ref value) = self.par_denominator {
+            errors.check_range(&field_path(path, "par_denominator"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.par_numerator {
+            errors.check_range(&field_path(path, "par_numerator"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.qvbr_quality_level {
+            errors.check_range(
+                &field_path(path, "qvbr_quality_level"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.slices {
+            errors.check_range(&field_path(path, "slices"), *value, Some(1), Some(32));
+        }
+        if let Some(ref value) = self.softness {
+            errors.check_range(&field_path(path, "softness"), *value, None, Some(128));
+        }
+    }
+}
+
 /// <p>H265 Color Space Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct H265ColorSpaceSettings {
@@ -2042,6 +2663,14 @@ pub struct H265ColorSpaceSettings {
     pub rec_709_settings: Option<Rec709Settings>,
 }
 
+impl Validate for H265ColorSpaceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hdr_10_settings {
+            value.validate_at(&field_path(path, "hdr_10_settings"), errors);
+        }
+    }
+}
+
 /// <p>H265 Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct H265Settings {
@@ -2169,6 +2798,70 @@ pub struct H265Settings {
     pub timecode_insertion: Option<String>,
 }
 
+impl Validate for H265Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bitrate {
+            errors.check_range(
+                &field_path(path, "bitrate"),
+                *value,
+                Some(100000),
+                Some(40000000),
+            );
+        }
+        if let Some(ref value) = self.buf_size {
+            errors.check_range(
+                &field_path(path, "buf_size"),
+                *value,
+                Some(100000),
+                Some(80000000),
+            );
+        }
+        if let Some(ref value) = self.color_space_settings {
+            value.validate_at(&field_path(path, "color_space_settings"), errors);
+        }
+        errors.check_range(
+            &field_path(path, "framerate_denominator"),
+            self.framerate_denominator,
+            Some(1),
+            Some(3003),
+        );
+        errors.check_range(
+            &field_path(path, "framerate_numerator"),
+            self.framerate_numerator,
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.max_bitrate {
+            errors.check_range(
+                &field_path(path, "max_bitrate"),
+                *value,
+                Some(100000),
+                Some(40000000),
+            );
+        }
+        if let Some(ref value) = self.min_i_interval {
+            errors.check_range(&field_path(path, "min_i_interval"), *value, None, Some(30));
+        }
+        if let Some(ref value) = self.par_denominator {
+            errors.check_range(&field_path(path, "par_denominator"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.par_numerator {
+            errors.check_range(&field_path(path, "par_numerator"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.qvbr_quality_level {
+            errors.check_range(
+                &field_path(path, "qvbr_quality_level"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.slices {
+            errors.check_range(&field_path(path, "slices"), *value, Some(1), Some(16));
+        }
+    }
+}
+
 /// <p>Hdr10 Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Hdr10Settings {
@@ -2186,6 +2879,17 @@ pub struct Hdr10Settings {
     pub max_fall: Option<i64>,
 }
 
+impl Validate for Hdr10Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_cll {
+            errors.check_range(&field_path(path, "max_cll"), *value, None, Some(32768));
+        }
+        if let Some(ref value) = self.max_fall {
+            errors.check_range(&field_path(path, "max_fall"), *value, None, Some(32768));
+        }
+    }
+}
+
 /// <p>Hls Akamai Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsAkamaiSettings {
@@ -2219,6 +2923,22 @@ pub struct HlsAkamaiSettings {
     pub token: Option<String>,
 }
 
+impl Validate for HlsAkamaiSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filecache_duration {
+            errors.check_range(
+                &field_path(path, "filecache_duration"),
+                *value,
+                None,
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.restart_delay {
+            errors.check_range(&field_path(path, "restart_delay"), *value, None, Some(15));
+        }
+    }
+}
+
 /// <p>Hls Basic Put Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsBasicPutSettings {
@@ -2240,6 +2960,22 @@ pub struct HlsBasicPutSettings {
     pub restart_delay: Option<i64>,
 }
 
+impl Validate for HlsBasicPutSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filecache_duration {
+            errors.check_range(
+                &field_path(path, "filecache_duration"),
+                *value,
+                None,
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.restart_delay {
+            errors.check_range(&field_path(path, "restart_delay"), *value, None, Some(15));
+        }
+    }
+}
+
 /// <p>Hls Cdn Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsCdnSettings {
@@ -2257,6 +2993,23 @@ pub struct HlsCdnSettings {
     pub hls_webdav_settings: Option<HlsWebdavSettings>,
 }
 
+impl Validate for HlsCdnSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hls_akamai_settings {
+            value.validate_at(&field_path(path, "hls_akamai_settings"), errors);
+        }
+        if let Some(ref value) = self.hls_basic_put_settings {
+            value.validate_at(&field_path(path, "hls_basic_put_settings"), errors);
+        }
+        if let Some(ref value) = self.hls_media_store_settings {
+            value.validate_at(&field_path(path, "hls_media_store_settings"), errors);
+        }
+        if let Some(ref value) = self.hls_webdav_settings {
+            value.validate_at(&field_path(path, "hls_webdav_settings"), errors);
+        }
+    }
+}
+
 /// <p>Hls Group Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsGroupSettings {
@@ -2424,6 +3177,56 @@ pub struct HlsGroupSettings {
     pub ts_file_mode: Option<String>,
 }
 
+impl Validate for HlsGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.caption_language_mappings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_language_mappings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.constant_iv {
+            errors.check_length(
+                &field_path(path, "constant_iv"),
+                value.chars().count(),
+                Some(32),
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.hls_cdn_settings {
+            value.validate_at(&field_path(path, "hls_cdn_settings"), errors);
+        }
+        if let Some(ref value) = self.index_n_segments {
+            errors.check_range(&field_path(path, "index_n_segments"), *value, Some(3), None);
+        }
+        if let Some(ref value) = self.keep_segments {
+            errors.check_range(&field_path(path, "keep_segments"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.key_provider_settings {
+            value.validate_at(&field_path(path, "key_provider_settings"), errors);
+        }
+        if let Some(ref value) = self.program_date_time_period {
+            errors.check_range(
+                &field_path(path, "program_date_time_period"),
+                *value,
+                None,
+                Some(3600),
+            );
+        }
+        if let Some(ref value) = self.segment_length {
+            errors.check_range(&field_path(path, "segment_length"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.segments_per_subdirectory {
+            errors.check_range(
+                &field_path(path, "segments_per_subdirectory"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Hls Input Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsInputSettings {
@@ -2470,6 +3273,22 @@ pub struct HlsMediaStoreSettings {
     pub restart_delay: Option<i64>,
 }
 
+impl Validate for HlsMediaStoreSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filecache_duration {
+            errors.check_range(
+                &field_path(path, "filecache_duration"),
+                *value,
+                None,
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.restart_delay {
+            errors.check_range(&field_path(path, "restart_delay"), *value, None, Some(15));
+        }
+    }
+}
+
 /// <p>Hls Output Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsOutputSettings {
@@ -2486,6 +3305,21 @@ pub struct HlsOutputSettings {
     pub segment_modifier: Option<String>,
 }
 
+impl Validate for HlsOutputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.hls_settings
+            .validate_at(&field_path(path, "hls_settings"), errors);
+        if let Some(ref value) = self.name_modifier {
+            errors.check_length(
+                &field_path(path, "name_modifier"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Hls Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsSettings {
@@ -2497,6 +3331,17 @@ pub struct HlsSettings {
     pub standard_hls_settings: Option<StandardHlsSettings>,
 }
 
+impl Validate for HlsSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_only_hls_settings {
+            value.validate_at(&field_path(path, "audio_only_hls_settings"), errors);
+        }
+        if let Some(ref value) = self.standard_hls_settings {
+            value.validate_at(&field_path(path, "standard_hls_settings"), errors);
+        }
+    }
+}
+
 /// <p>Settings for the action to emit HLS metadata</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsTimedMetadataScheduleActionSettings {
@@ -2530,13 +3375,29 @@ pub struct HlsWebdavSettings {
     pub restart_delay: Option<i64>,
 }
 
+impl Validate for HlsWebdavSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filecache_duration {
+            errors.check_range(
+                &field_path(path, "filecache_duration"),
+                *value,
+                None,
+                Some(600),
+            );
+        }
+        if let Some(ref value) = self.restart_delay {
+            errors.check_range(&field_path(path, "restart_delay"), *value, None, Some(15));
+        }
+    }
+}
+
 /// <p>Settings to configure an action so that it occurs immediately. This is only supported for input switch actions currently.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImmediateModeScheduleActionStartSettings {}
 
 /// <p>Placeholder documentation for Input</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Input {
     /// <p>The Unique ARN of the input (generated, immutable).</p>
     #[serde(rename = "Arn")]
@@ -2613,6 +3474,14 @@ pub struct InputAttachment {
     pub input_settings: Option<InputSettings>,
 }
 
+impl Validate for InputAttachment {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_settings {
+            value.validate_at(&field_path(path, "input_settings"), errors);
+        }
+    }
+}
+
 /// <p>Input Channel Level</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputChannelLevel {
@@ -2624,6 +3493,18 @@ pub struct InputChannelLevel {
     pub input_channel: i64,
 }
 
+impl Validate for InputChannelLevel {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(&field_path(path, "gain"), self.gain, None, Some(6));
+        errors.check_range(
+            &field_path(path, "input_channel"),
+            self.input_channel,
+            None,
+            Some(15),
+        );
+    }
+}
+
 /// <p>Settings to let you create a clip of the file input, in order to set up the input to ingest only a portion of the file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputClippingSettings {
@@ -2642,7 +3523,7 @@ pub struct InputClippingSettings {
 
 /// <p>The settings for a PUSH type input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputDestination {
     /// <p>The system-generated static IP address of endpoint.
     /// It remains fixed for the lifetime of the input.</p>
@@ -2665,6 +3546,7 @@ pub struct InputDestination {
 
 /// <p>Endpoint settings for a PUSH type input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputDestinationRequest {
     /// <p>A unique name for the location the RTMP stream is being pushed
     /// to.</p>
@@ -2675,7 +3557,7 @@ pub struct InputDestinationRequest {
 
 /// <p>The properties for a VPC type input destination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputDestinationVpc {
     /// <p>The availability zone of the Input destination.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -2703,6 +3585,17 @@ pub struct InputLocation {
     pub username: Option<String>,
 }
 
+impl Validate for InputLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "uri"),
+            self.uri.chars().count(),
+            None,
+            Some(2048),
+        );
+    }
+}
+
 /// <p>Input Loss Behavior</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputLossBehavior {
@@ -2728,9 +3621,41 @@ pub struct InputLossBehavior {
     pub repeat_frame_msec: Option<i64>,
 }
 
+impl Validate for InputLossBehavior {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.black_frame_msec {
+            errors.check_range(
+                &field_path(path, "black_frame_msec"),
+                *value,
+                None,
+                Some(1000000),
+            );
+        }
+        if let Some(ref value) = self.input_loss_image_color {
+            errors.check_length(
+                &field_path(path, "input_loss_image_color"),
+                value.chars().count(),
+                Some(6),
+                Some(6),
+            );
+        }
+        if let Some(ref value) = self.input_loss_image_slate {
+            value.validate_at(&field_path(path, "input_loss_image_slate"), errors);
+        }
+        if let Some(ref value) = self.repeat_frame_msec {
+            errors.check_range(
+                &field_path(path, "repeat_frame_msec"),
+                *value,
+                None,
+                Some(1000000),
+            );
+        }
+    }
+}
+
 /// <p>An Input Security Group</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputSecurityGroup {
     /// <p>Unique ARN of Input Security Group</p>
     #[serde(rename = "Arn")]
@@ -2802,9 +3727,37 @@ pub struct InputSettings {
     pub video_selector: Option<VideoSelector>,
 }
 
+impl Validate for InputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audio_selectors {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "audio_selectors"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.caption_selectors {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "caption_selectors"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filter_strength {
+            errors.check_range(
+                &field_path(path, "filter_strength"),
+                *value,
+                Some(1),
+                Some(5),
+            );
+        }
+        if let Some(ref value) = self.video_selector {
+            value.validate_at(&field_path(path, "video_selector"), errors);
+        }
+    }
+}
+
 /// <p>The settings for a PULL type input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputSource {
     /// <p>The key used to extract the password from EC2 Parameter store.</p>
     #[serde(rename = "PasswordParam")]
@@ -2823,6 +3776,7 @@ pub struct InputSource {
 
 /// <p>Settings for for a PULL type input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputSourceRequest {
     /// <p>The key used to extract the password from EC2 Parameter store.</p>
     #[serde(rename = "PasswordParam")]
@@ -2877,6 +3831,7 @@ pub struct InputSwitchScheduleActionSettings {
 /// This property requires setting the roleArn property on Input creation.
 /// Not compatible with the inputSecurityGroups property.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputVpcRequest {
     /// <p>A list of up to 5 EC2 VPC security group IDs to attach to the Input VPC network interfaces.
     /// Requires subnetIds. If none are specified then the VPC default security group will be used.</p>
@@ -2891,7 +3846,7 @@ pub struct InputVpcRequest {
 
 /// <p>Whitelist rule</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InputWhitelistRule {
     /// <p>The IPv4 CIDR that&#39;s whitelisted.</p>
     #[serde(rename = "Cidr")]
@@ -2901,6 +3856,7 @@ pub struct InputWhitelistRule {
 
 /// <p>An IPv4 CIDR to whitelist.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputWhitelistRuleCidr {
     /// <p>The IPv4 CIDR to whitelist.</p>
     #[serde(rename = "Cidr")]
@@ -2916,8 +3872,17 @@ pub struct KeyProviderSettings {
     pub static_key_settings: Option<StaticKeySettings>,
 }
 
+impl Validate for KeyProviderSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.static_key_settings {
+            value.validate_at(&field_path(path, "static_key_settings"), errors);
+        }
+    }
+}
+
 /// <p>Placeholder documentation for ListChannelsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListChannelsRequest {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2927,9 +3892,22 @@ pub struct ListChannelsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListChannelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Placeholder documentation for ListChannelsResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListChannelsResponse {
     #[serde(rename = "Channels")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2941,6 +3919,7 @@ pub struct ListChannelsResponse {
 
 /// <p>Placeholder documentation for ListInputSecurityGroupsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInputSecurityGroupsRequest {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2950,9 +3929,22 @@ pub struct ListInputSecurityGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListInputSecurityGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Placeholder documentation for ListInputSecurityGroupsResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInputSecurityGroupsResponse {
     /// <p>List of input security groups</p>
     #[serde(rename = "InputSecurityGroups")]
@@ -2965,6 +3957,7 @@ pub struct ListInputSecurityGroupsResponse {
 
 /// <p>Placeholder documentation for ListInputsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInputsRequest {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2974,9 +3967,22 @@ pub struct ListInputsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListInputsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Placeholder documentation for ListInputsResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInputsResponse {
     #[serde(rename = "Inputs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2988,6 +3994,7 @@ pub struct ListInputsResponse {
 
 /// <p>Placeholder documentation for ListOfferingsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOfferingsRequest {
     /// <p>Filter by channel class, &#39;STANDARD&#39; or &#39;SINGLE_PIPELINE&#39;</p>
     #[serde(rename = "ChannelClass")]
@@ -3033,9 +4040,22 @@ pub struct ListOfferingsRequest {
     pub video_quality: Option<String>,
 }
 
+impl Validate for ListOfferingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Placeholder documentation for ListOfferingsResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOfferingsResponse {
     /// <p>Token to retrieve the next page of results</p>
     #[serde(rename = "NextToken")]
@@ -3049,6 +4069,7 @@ pub struct ListOfferingsResponse {
 
 /// <p>Placeholder documentation for ListReservationsRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReservationsRequest {
     /// <p>Filter by channel class, &#39;STANDARD&#39; or &#39;SINGLE_PIPELINE&#39;</p>
     #[serde(rename = "ChannelClass")]
@@ -3090,9 +4111,22 @@ pub struct ListReservationsRequest {
     pub video_quality: Option<String>,
 }
 
+impl Validate for ListReservationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Placeholder documentation for ListReservationsResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListReservationsResponse {
     /// <p>Token to retrieve the next page of results</p>
     #[serde(rename = "NextToken")]
@@ -3106,6 +4140,7 @@ pub struct ListReservationsResponse {
 
 /// <p>Placeholder documentation for ListTagsForResourceRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
@@ -3113,7 +4148,7 @@ pub struct ListTagsForResourceRequest {
 
 /// <p>Placeholder documentation for ListTagsForResourceResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3313,6 +4348,48 @@ pub struct M2tsSettings {
     pub video_pid: Option<String>,
 }
 
+impl Validate for M2tsSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dvb_nit_settings {
+            value.validate_at(&field_path(path, "dvb_nit_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_sdt_settings {
+            value.validate_at(&field_path(path, "dvb_sdt_settings"), errors);
+        }
+        if let Some(ref value) = self.dvb_tdt_settings {
+            value.validate_at(&field_path(path, "dvb_tdt_settings"), errors);
+        }
+        if let Some(ref value) = self.ebp_lookahead_ms {
+            errors.check_range(
+                &field_path(path, "ebp_lookahead_ms"),
+                *value,
+                None,
+                Some(10000),
+            );
+        }
+        if let Some(ref value) = self.pat_interval {
+            errors.check_range(&field_path(path, "pat_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.pcr_period {
+            errors.check_range(&field_path(path, "pcr_period"), *value, None, Some(500));
+        }
+        if let Some(ref value) = self.pmt_interval {
+            errors.check_range(&field_path(path, "pmt_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.program_num {
+            errors.check_range(&field_path(path, "program_num"), *value, None, Some(65535));
+        }
+        if let Some(ref value) = self.transport_stream_id {
+            errors.check_range(
+                &field_path(path, "transport_stream_id"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+    }
+}
+
 /// <p>Settings information for the .m3u8 container</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct M3u8Settings {
@@ -3382,9 +4459,34 @@ pub struct M3u8Settings {
     pub video_pid: Option<String>,
 }
 
+impl Validate for M3u8Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pat_interval {
+            errors.check_range(&field_path(path, "pat_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.pcr_period {
+            errors.check_range(&field_path(path, "pcr_period"), *value, None, Some(500));
+        }
+        if let Some(ref value) = self.pmt_interval {
+            errors.check_range(&field_path(path, "pmt_interval"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.program_num {
+            errors.check_range(&field_path(path, "program_num"), *value, None, Some(65535));
+        }
+        if let Some(ref value) = self.transport_stream_id {
+            errors.check_range(
+                &field_path(path, "transport_stream_id"),
+                *value,
+                None,
+                Some(65535),
+            );
+        }
+    }
+}
+
 /// <p>The settings for a MediaConnect Flow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MediaConnectFlow {
     /// <p>The unique ARN of the MediaConnect Flow being used as a source.</p>
     #[serde(rename = "FlowArn")]
@@ -3394,6 +4496,7 @@ pub struct MediaConnectFlow {
 
 /// <p>The settings for a MediaConnect Flow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MediaConnectFlowRequest {
     /// <p>The ARN of the MediaConnect Flow that you want to use as a source.</p>
     #[serde(rename = "FlowArn")]
@@ -3418,6 +4521,19 @@ pub struct MediaPackageOutputDestinationSettings {
     pub channel_id: Option<String>,
 }
 
+impl Validate for MediaPackageOutputDestinationSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.channel_id {
+            errors.check_length(
+                &field_path(path, "channel_id"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Media Package Output Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MediaPackageOutputSettings {}
@@ -3528,6 +4644,22 @@ pub struct MsSmoothGroupSettings {
     pub timestamp_offset_mode: Option<String>,
 }
 
+impl Validate for MsSmoothGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.fragment_length {
+            errors.check_range(&field_path(path, "fragment_length"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.send_delay_ms {
+            errors.check_range(
+                &field_path(path, "send_delay_ms"),
+                *value,
+                None,
+                Some(10000),
+            );
+        }
+    }
+}
+
 /// <p>Ms Smooth Output Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MsSmoothOutputSettings {
@@ -3557,7 +4689,7 @@ pub struct NetworkInputSettings {
 
 /// <p>Reserved resources available for purchase</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Offering {
     /// <p>Unique offering ARN, e.g. &#39;arn:aws:medialive:us-west-2:123456789012:offering:87654321&#39;</p>
     #[serde(rename = "Arn")]
@@ -3629,6 +4761,21 @@ pub struct Output {
     pub video_description_name: Option<String>,
 }
 
+impl Validate for Output {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.output_name {
+            errors.check_length(
+                &field_path(path, "output_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        self.output_settings
+            .validate_at(&field_path(path, "output_settings"), errors);
+    }
+}
+
 /// <p>Placeholder documentation for OutputDestination</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputDestination {
@@ -3646,6 +4793,17 @@ pub struct OutputDestination {
     pub settings: Option<Vec<OutputDestinationSettings>>,
 }
 
+impl Validate for OutputDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.media_package_settings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "media_package_settings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Placeholder documentation for OutputDestinationSettings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputDestinationSettings {
@@ -3681,6 +4839,25 @@ pub struct OutputGroup {
     pub outputs: Vec<Output>,
 }
 
+impl Validate for OutputGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        self.output_group_settings
+            .validate_at(&field_path(path, "output_group_settings"), errors);
+        for (i, item) in self.outputs.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "outputs"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Output Group Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputGroupSettings {
@@ -3707,6 +4884,23 @@ pub struct OutputGroupSettings {
     pub udp_group_settings: Option<UdpGroupSettings>,
 }
 
+impl Validate for OutputGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.archive_group_settings {
+            value.validate_at(&field_path(path, "archive_group_settings"), errors);
+        }
+        if let Some(ref value) = self.hls_group_settings {
+            value.validate_at(&field_path(path, "hls_group_settings"), errors);
+        }
+        if let Some(ref value) = self.ms_smooth_group_settings {
+            value.validate_at(&field_path(path, "ms_smooth_group_settings"), errors);
+        }
+        if let Some(ref value) = self.rtmp_group_settings {
+            value.validate_at(&field_path(path, "rtmp_group_settings"), errors);
+        }
+    }
+}
+
 /// <p>Reference to an OutputDestination ID defined in the channel</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputLocationRef {
@@ -3741,6 +4935,23 @@ pub struct OutputSettings {
     pub udp_output_settings: Option<UdpOutputSettings>,
 }
 
+impl Validate for OutputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.archive_output_settings {
+            value.validate_at(&field_path(path, "archive_output_settings"), errors);
+        }
+        if let Some(ref value) = self.hls_output_settings {
+            value.validate_at(&field_path(path, "hls_output_settings"), errors);
+        }
+        if let Some(ref value) = self.rtmp_output_settings {
+            value.validate_at(&field_path(path, "rtmp_output_settings"), errors);
+        }
+        if let Some(ref value) = self.udp_output_settings {
+            value.validate_at(&field_path(path, "udp_output_settings"), errors);
+        }
+    }
+}
+
 /// <p>Pass Through Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PassThroughSettings {}
@@ -3755,7 +4966,7 @@ pub struct PauseStateScheduleActionSettings {
 
 /// <p>Runtime details of a pipeline when a channel is running.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PipelineDetail {
     /// <p>The name of the active input attachment currently being ingested by this pipeline.</p>
     #[serde(rename = "ActiveInputAttachmentName")]
@@ -3781,6 +4992,7 @@ pub struct PipelinePauseStateSettings {
 
 /// <p>Placeholder documentation for PurchaseOfferingRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseOfferingRequest {
     /// <p>Number of resources</p>
     #[serde(rename = "Count")]
@@ -3806,9 +5018,15 @@ pub struct PurchaseOfferingRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for PurchaseOfferingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(&field_path(path, "count"), self.count, Some(1), None);
+    }
+}
+
 /// <p>Placeholder documentation for PurchaseOfferingResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PurchaseOfferingResponse {
     #[serde(rename = "Reservation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3840,9 +5058,24 @@ pub struct RemixSettings {
     pub channels_out: Option<i64>,
 }
 
+impl Validate for RemixSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.channel_mappings.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "channel_mappings"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.channels_in {
+            errors.check_range(&field_path(path, "channels_in"), *value, Some(1), Some(16));
+        }
+        if let Some(ref value) = self.channels_out {
+            errors.check_range(&field_path(path, "channels_out"), *value, Some(1), Some(8));
+        }
+    }
+}
+
 /// <p>Reserved resources available to use</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Reservation {
     /// <p>Unique reservation ARN, e.g. &#39;arn:aws:medialive:us-west-2:123456789012:reservation:1234567&#39;</p>
     #[serde(rename = "Arn")]
@@ -3920,7 +5153,7 @@ pub struct Reservation {
 
 /// <p>Resource configuration (codec, resolution, bitrate, ...)</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReservationResourceSpecification {
     /// <p>Channel class, e.g. &#39;STANDARD&#39;</p>
     #[serde(rename = "ChannelClass")]
@@ -3994,6 +5227,14 @@ pub struct RtmpGroupSettings {
     pub restart_delay: Option<i64>,
 }
 
+impl Validate for RtmpGroupSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cache_length {
+            errors.check_range(&field_path(path, "cache_length"), *value, Some(30), None);
+        }
+    }
+}
+
 /// <p>Rtmp Output Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RtmpOutputSettings {
@@ -4014,6 +5255,19 @@ pub struct RtmpOutputSettings {
     pub num_retries: Option<i64>,
 }
 
+impl Validate for RtmpOutputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.connection_retry_interval {
+            errors.check_range(
+                &field_path(path, "connection_retry_interval"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Contains information on a single schedule action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScheduleAction {
@@ -4028,6 +5282,13 @@ pub struct ScheduleAction {
     pub schedule_action_start_settings: ScheduleActionStartSettings,
 }
 
+impl Validate for ScheduleAction {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.schedule_action_settings
+            .validate_at(&field_path(path, "schedule_action_settings"), errors);
+    }
+}
+
 /// <p>Holds the settings for a single schedule action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScheduleActionSettings {
@@ -4065,6 +5326,32 @@ pub struct ScheduleActionSettings {
     pub static_image_deactivate_settings: Option<StaticImageDeactivateScheduleActionSettings>,
 }
 
+impl Validate for ScheduleActionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.scte_35_return_to_network_settings {
+            value.validate_at(
+                &field_path(path, "scte_35_return_to_network_settings"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.scte_35_splice_insert_settings {
+            value.validate_at(&field_path(path, "scte_35_splice_insert_settings"), errors);
+        }
+        if let Some(ref value) = self.scte_35_time_signal_settings {
+            value.validate_at(&field_path(path, "scte_35_time_signal_settings"), errors);
+        }
+        if let Some(ref value) = self.static_image_activate_settings {
+            value.validate_at(&field_path(path, "static_image_activate_settings"), errors);
+        }
+        if let Some(ref value) = self.static_image_deactivate_settings {
+            value.validate_at(
+                &field_path(path, "static_image_deactivate_settings"),
+                errors,
+            );
+        }
+    }
+}
+
 /// <p>Settings to specify when an action should occur. Only one of the options must be selected.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScheduleActionStartSettings {
@@ -4100,6 +5387,19 @@ pub struct Scte20SourceSettings {
     pub source_608_channel_number: Option<i64>,
 }
 
+impl Validate for Scte20SourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_608_channel_number {
+            errors.check_range(
+                &field_path(path, "source_608_channel_number"),
+                *value,
+                Some(1),
+                Some(4),
+            );
+        }
+    }
+}
+
 /// <p>Scte27 Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte27DestinationSettings {}
@@ -4117,6 +5417,14 @@ pub struct Scte27SourceSettings {
     pub pid: Option<i64>,
 }
 
+impl Validate for Scte27SourceSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pid {
+            errors.check_range(&field_path(path, "pid"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Corresponds to SCTE-35 delivery<em>not</em>restricted_flag parameter. To declare delivery restrictions, include this element and its four &quot;restriction&quot; flags. To declare that there are no restrictions, omit this element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35DeliveryRestrictions {
@@ -4142,6 +5450,13 @@ pub struct Scte35Descriptor {
     pub scte_35_descriptor_settings: Scte35DescriptorSettings,
 }
 
+impl Validate for Scte35Descriptor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.scte_35_descriptor_settings
+            .validate_at(&field_path(path, "scte_35_descriptor_settings"), errors);
+    }
+}
+
 /// <p>SCTE-35 Descriptor settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35DescriptorSettings {
@@ -4150,6 +5465,16 @@ pub struct Scte35DescriptorSettings {
     pub segmentation_descriptor_scte_35_descriptor_settings: Scte35SegmentationDescriptor,
 }
 
+impl Validate for Scte35DescriptorSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.segmentation_descriptor_scte_35_descriptor_settings
+            .validate_at(
+                &field_path(path, "segmentation_descriptor_scte_35_descriptor_settings"),
+                errors,
+            );
+    }
+}
+
 /// <p>Settings for a SCTE-35 return<em>to</em>network message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35ReturnToNetworkScheduleActionSettings {
@@ -4158,6 +5483,17 @@ pub struct Scte35ReturnToNetworkScheduleActionSettings {
     pub splice_event_id: i64,
 }
 
+impl Validate for Scte35ReturnToNetworkScheduleActionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "splice_event_id"),
+            self.splice_event_id,
+            None,
+            Some(4294967295),
+        );
+    }
+}
+
 /// <p>Corresponds to SCTE-35 segmentation_descriptor.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35SegmentationDescriptor {
@@ -4205,6 +5541,68 @@ pub struct Scte35SegmentationDescriptor {
     pub sub_segments_expected: Option<i64>,
 }
 
+impl Validate for Scte35SegmentationDescriptor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.segment_num {
+            errors.check_range(&field_path(path, "segment_num"), *value, None, Some(255));
+        }
+        if let Some(ref value) = self.segmentation_duration {
+            errors.check_range(
+                &field_path(path, "segmentation_duration"),
+                *value,
+                None,
+                Some(1099511627775),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "segmentation_event_id"),
+            self.segmentation_event_id,
+            None,
+            Some(4294967295),
+        );
+        if let Some(ref value) = self.segmentation_type_id {
+            errors.check_range(
+                &field_path(path, "segmentation_type_id"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.segmentation_upid_type {
+            errors.check_range(
+                &field_path(path, "segmentation_upid_type"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.segments_expected {
+            errors.check_range(
+                &field_path(path, "segments_expected"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.sub_segment_num {
+            errors.check_range(
+                &field_path(path, "sub_segment_num"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.sub_segments_expected {
+            errors.check_range(
+                &field_path(path, "sub_segments_expected"),
+                *value,
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>Scte35 Splice Insert</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35SpliceInsert {
@@ -4222,6 +5620,19 @@ pub struct Scte35SpliceInsert {
     pub web_delivery_allowed_flag: Option<String>,
 }
 
+impl Validate for Scte35SpliceInsert {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ad_avail_offset {
+            errors.check_range(
+                &field_path(path, "ad_avail_offset"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Settings for a SCTE-35 splice_insert message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35SpliceInsertScheduleActionSettings {
@@ -4234,6 +5645,25 @@ pub struct Scte35SpliceInsertScheduleActionSettings {
     pub splice_event_id: i64,
 }
 
+impl Validate for Scte35SpliceInsertScheduleActionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration {
+            errors.check_range(
+                &field_path(path, "duration"),
+                *value,
+                None,
+                Some(8589934591),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "splice_event_id"),
+            self.splice_event_id,
+            None,
+            Some(4294967295),
+        );
+    }
+}
+
 /// <p>Scte35 Time Signal Apos</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35TimeSignalApos {
@@ -4251,6 +5681,19 @@ pub struct Scte35TimeSignalApos {
     pub web_delivery_allowed_flag: Option<String>,
 }
 
+impl Validate for Scte35TimeSignalApos {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ad_avail_offset {
+            errors.check_range(
+                &field_path(path, "ad_avail_offset"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>Settings for a SCTE-35 time_signal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scte35TimeSignalScheduleActionSettings {
@@ -4259,6 +5702,15 @@ pub struct Scte35TimeSignalScheduleActionSettings {
     pub scte_35_descriptors: Vec<Scte35Descriptor>,
 }
 
+impl Validate for Scte35TimeSignalScheduleActionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.scte_35_descriptors.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "scte_35_descriptors"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Smpte Tt Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SmpteTtDestinationSettings {}
@@ -4274,8 +5726,16 @@ pub struct StandardHlsSettings {
     pub m_3u_8_settings: M3u8Settings,
 }
 
+impl Validate for StandardHlsSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.m_3u_8_settings
+            .validate_at(&field_path(path, "m_3u_8_settings"), errors);
+    }
+}
+
 /// <p>Placeholder documentation for StartChannelRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartChannelRequest {
     /// <p>A request to start a channel</p>
     #[serde(rename = "ChannelId")]
@@ -4284,7 +5744,7 @@ pub struct StartChannelRequest {
 
 /// <p>Placeholder documentation for StartChannelResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartChannelResponse {
     /// <p>The unique arn of the channel.</p>
     #[serde(rename = "Arn")]
@@ -4400,6 +5860,24 @@ pub struct StaticImageActivateScheduleActionSettings {
     pub width: Option<i64>,
 }
 
+impl Validate for StaticImageActivateScheduleActionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.height {
+            errors.check_range(&field_path(path, "height"), *value, Some(1), None);
+        }
+        self.image.validate_at(&field_path(path, "image"), errors);
+        if let Some(ref value) = self.layer {
+            errors.check_range(&field_path(path, "layer"), *value, None, Some(7));
+        }
+        if let Some(ref value) = self.opacity {
+            errors.check_range(&field_path(path, "opacity"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.width {
+            errors.check_range(&field_path(path, "width"), *value, Some(1), None);
+        }
+    }
+}
+
 /// <p>Settings for the action to deactivate the image in a specific layer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StaticImageDeactivateScheduleActionSettings {
@@ -4413,6 +5891,14 @@ pub struct StaticImageDeactivateScheduleActionSettings {
     pub layer: Option<i64>,
 }
 
+impl Validate for StaticImageDeactivateScheduleActionSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.layer {
+            errors.check_range(&field_path(path, "layer"), *value, None, Some(7));
+        }
+    }
+}
+
 /// <p>Static Key Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StaticKeySettings {
@@ -4425,8 +5911,23 @@ pub struct StaticKeySettings {
     pub static_key_value: String,
 }
 
+impl Validate for StaticKeySettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key_provider_server {
+            value.validate_at(&field_path(path, "key_provider_server"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "static_key_value"),
+            self.static_key_value.chars().count(),
+            Some(32),
+            Some(32),
+        );
+    }
+}
+
 /// <p>Placeholder documentation for StopChannelRequest</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopChannelRequest {
     /// <p>A request to stop a running channel</p>
     #[serde(rename = "ChannelId")]
@@ -4435,7 +5936,7 @@ pub struct StopChannelRequest {
 
 /// <p>Placeholder documentation for StopChannelResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopChannelResponse {
     /// <p>The unique arn of the channel.</p>
     #[serde(rename = "Arn")]
@@ -4539,6 +6040,19 @@ pub struct TimecodeConfig {
     pub sync_threshold: Option<i64>,
 }
 
+impl Validate for TimecodeConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sync_threshold {
+            errors.check_range(
+                &field_path(path, "sync_threshold"),
+                *value,
+                Some(1),
+                Some(1000000),
+            );
+        }
+    }
+}
+
 /// <p>Ttml Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TtmlDestinationSettings {
@@ -4556,6 +6070,14 @@ pub struct UdpContainerSettings {
     pub m_2ts_settings: Option<M2tsSettings>,
 }
 
+impl Validate for UdpContainerSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.m_2ts_settings {
+            value.validate_at(&field_path(path, "m_2ts_settings"), errors);
+        }
+    }
+}
+
 /// <p>Udp Group Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UdpGroupSettings {
@@ -4591,8 +6113,22 @@ pub struct UdpOutputSettings {
     pub fec_output_settings: Option<FecOutputSettings>,
 }
 
+impl Validate for UdpOutputSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.buffer_msec {
+            errors.check_range(&field_path(path, "buffer_msec"), *value, None, Some(10000));
+        }
+        self.container_settings
+            .validate_at(&field_path(path, "container_settings"), errors);
+        if let Some(ref value) = self.fec_output_settings {
+            value.validate_at(&field_path(path, "fec_output_settings"), errors);
+        }
+    }
+}
+
 /// <p>Channel class that the channel should be updated to.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateChannelClassRequest {
     /// <p>The channel class that you wish to update this channel to use.</p>
     #[serde(rename = "ChannelClass")]
@@ -4606,9 +6142,20 @@ pub struct UpdateChannelClassRequest {
     pub destinations: Option<Vec<OutputDestination>>,
 }
 
+impl Validate for UpdateChannelClassRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destinations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "destinations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Placeholder documentation for UpdateChannelClassResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateChannelClassResponse {
     #[serde(rename = "Channel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4617,6 +6164,7 @@ pub struct UpdateChannelClassResponse {
 
 /// <p>A request to update a channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateChannelRequest {
     /// <p>channel ID</p>
     #[serde(rename = "ChannelId")]
@@ -4650,9 +6198,29 @@ pub struct UpdateChannelRequest {
     pub role_arn: Option<String>,
 }
 
+impl Validate for UpdateChannelRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destinations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "destinations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.encoder_settings {
+            value.validate_at(&field_path(path, "encoder_settings"), errors);
+        }
+        if let Some(ref value) = self.input_attachments {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_attachments"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Placeholder documentation for UpdateChannelResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateChannelResponse {
     #[serde(rename = "Channel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4661,6 +6229,7 @@ pub struct UpdateChannelResponse {
 
 /// <p>A request to update an input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateInputRequest {
     /// <p>Destination settings for PUSH type inputs.</p>
     #[serde(rename = "Destinations")]
@@ -4697,7 +6266,7 @@ pub struct UpdateInputRequest {
 
 /// <p>Placeholder documentation for UpdateInputResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateInputResponse {
     #[serde(rename = "Input")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4706,6 +6275,7 @@ pub struct UpdateInputResponse {
 
 /// <p>The request to update some combination of the Input Security Group name and the IPv4 CIDRs the Input Security Group should allow.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateInputSecurityGroupRequest {
     /// <p>The id of the Input Security Group to update.</p>
     #[serde(rename = "InputSecurityGroupId")]
@@ -4722,7 +6292,7 @@ pub struct UpdateInputSecurityGroupRequest {
 
 /// <p>Placeholder documentation for UpdateInputSecurityGroupResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateInputSecurityGroupResponse {
     #[serde(rename = "SecurityGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4731,6 +6301,7 @@ pub struct UpdateInputSecurityGroupResponse {
 
 /// <p>Request to update a reservation</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateReservationRequest {
     /// <p>Name of the reservation</p>
     #[serde(rename = "Name")]
@@ -4743,7 +6314,7 @@ pub struct UpdateReservationRequest {
 
 /// <p>Placeholder documentation for UpdateReservationResponse</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateReservationResponse {
     #[serde(rename = "Reservation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4771,6 +6342,20 @@ pub struct VideoCodecSettings {
     pub h265_settings: Option<H265Settings>,
 }
 
+impl Validate for VideoCodecSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.frame_capture_settings {
+            value.validate_at(&field_path(path, "frame_capture_settings"), errors);
+        }
+        if let Some(ref value) = self.h264_settings {
+            value.validate_at(&field_path(path, "h264_settings"), errors);
+        }
+        if let Some(ref value) = self.h265_settings {
+            value.validate_at(&field_path(path, "h265_settings"), errors);
+        }
+    }
+}
+
 /// <p>Video settings for this stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoDescription {
@@ -4803,9 +6388,20 @@ pub struct VideoDescription {
     pub width: Option<i64>,
 }
 
-/// <p>Specifies a particular video stream within an input source. An input may have only a single video selector.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct VideoSelector {
+impl Validate for VideoDescription {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.codec_settings {
+            value.validate_at(&field_path(path, "codec_settings"), errors);
+        }
+        if let Some(ref value) = self.sharpness {
+            errors.check_range(&field_path(path, "sharpness"), *value, None, Some(100));
+        }
+    }
+}
+
+/// <p>Specifies a particular video stream within an input source. An input may have only a single video selector.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoSelector {
     /// <p>Specifies the color space of an input. This setting works in tandem with colorSpaceUsage and a video description&#39;s colorSpaceSettingsChoice to determine if any conversion will be performed.</p>
     #[serde(rename = "ColorSpace")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4820,6 +6416,14 @@ pub struct VideoSelector {
     pub selector_settings: Option<VideoSelectorSettings>,
 }
 
+impl Validate for VideoSelector {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.selector_settings {
+            value.validate_at(&field_path(path, "selector_settings"), errors);
+        }
+    }
+}
+
 /// <p>Video Selector Pid</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoSelectorPid {
@@ -4829,6 +6433,14 @@ pub struct VideoSelectorPid {
     pub pid: Option<i64>,
 }
 
+impl Validate for VideoSelectorPid {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pid {
+            errors.check_range(&field_path(path, "pid"), *value, None, Some(8191));
+        }
+    }
+}
+
 /// <p>Video Selector Program Id</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoSelectorProgramId {
@@ -4838,6 +6450,14 @@ pub struct VideoSelectorProgramId {
     pub program_id: Option<i64>,
 }
 
+impl Validate for VideoSelectorProgramId {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.program_id {
+            errors.check_range(&field_path(path, "program_id"), *value, None, Some(65536));
+        }
+    }
+}
+
 /// <p>Video Selector Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VideoSelectorSettings {
@@ -4849,6 +6469,17 @@ pub struct VideoSelectorSettings {
     pub video_selector_program_id: Option<VideoSelectorProgramId>,
 }
 
+impl Validate for VideoSelectorSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.video_selector_pid {
+            value.validate_at(&field_path(path, "video_selector_pid"), errors);
+        }
+        if let Some(ref value) = self.video_selector_program_id {
+            value.validate_at(&field_path(path, "video_selector_program_id"), errors);
+        }
+    }
+}
+
 /// <p>Webvtt Destination Settings</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebvttDestinationSettings {}
@@ -4906,7 +6537,12 @@ impl BatchUpdateScheduleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4981,7 +6617,12 @@ impl CreateChannelError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5046,7 +6687,12 @@ impl CreateInputError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateInputError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5115,7 +6761,12 @@ impl CreateInputSecurityGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5168,7 +6819,12 @@ impl CreateTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5239,7 +6895,12 @@ impl DeleteChannelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteChannelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5314,7 +6975,12 @@ impl DeleteInputError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteInputError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5390,7 +7056,12 @@ impl DeleteInputSecurityGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5466,7 +7137,12 @@ impl DeleteReservationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteReservationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5536,7 +7212,12 @@ impl DeleteScheduleError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteScheduleError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5590,7 +7271,12 @@ impl DeleteTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5656,7 +7342,12 @@ impl DescribeChannelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeChannelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5725,7 +7416,12 @@ impl DescribeInputError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeInputError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5808,7 +7504,12 @@ impl DescribeInputSecurityGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5879,7 +7580,12 @@ impl DescribeOfferingError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeOfferingError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5950,7 +7656,12 @@ impl DescribeReservationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeReservationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6021,7 +7732,12 @@ impl DescribeScheduleError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeScheduleError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6085,7 +7801,12 @@ impl ListChannelsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListChannelsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6154,7 +7875,12 @@ impl ListInputSecurityGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6217,7 +7943,12 @@ impl ListInputsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListInputsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6280,7 +8011,12 @@ impl ListOfferingsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListOfferingsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6345,7 +8081,12 @@ impl ListReservationsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListReservationsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6400,7 +8141,12 @@ impl ListTagsForResourceError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6473,7 +8219,12 @@ impl PurchaseOfferingError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(PurchaseOfferingError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6548,7 +8299,12 @@ impl StartChannelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(StartChannelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6623,7 +8379,12 @@ impl StopChannelError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(StopChannelError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6693,7 +8454,12 @@ impl UpdateChannelError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6776,7 +8542,12 @@ impl UpdateChannelClassError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6847,7 +8618,12 @@ impl UpdateInputError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateInputError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6920,7 +8696,12 @@ impl UpdateInputSecurityGroupError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateInputSecurityGroupError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6996,7 +8777,12 @@ impl UpdateReservationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateReservationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7204,1109 +8990,2921 @@ pub trait MediaLive {
         input: UpdateReservationRequest,
     ) -> RusotoFuture<UpdateReservationResponse, UpdateReservationError>;
 }
-/// A client for the MediaLive API.
-#[derive(Clone)]
-pub struct MediaLiveClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MediaLiveClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MediaLiveClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MediaLiveClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveClient {
-        MediaLiveClient { client, region }
-    }
-}
-
-impl MediaLive for MediaLiveClient {
-    /// <p>Update a channel schedule</p>
+const _: Option<&dyn MediaLive> = None;
+impl<'a, T: MediaLive + ?Sized> MediaLive for &'a T {
     fn batch_update_schedule(
         &self,
         input: BatchUpdateScheduleRequest,
     ) -> RusotoFuture<BatchUpdateScheduleResponse, BatchUpdateScheduleError> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/schedule",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchUpdateScheduleResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchUpdateScheduleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_update_schedule(input)
     }
-
-    /// <p>Creates a new channel</p>
     fn create_channel(
         &self,
         input: CreateChannelRequest,
     ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
-        let request_uri = "/prod/channels";
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_channel(input)
     }
-
-    /// <p>Create an input</p>
     fn create_input(
         &self,
         input: CreateInputRequest,
     ) -> RusotoFuture<CreateInputResponse, CreateInputError> {
-        let request_uri = "/prod/inputs";
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInputResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateInputError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_input(input)
     }
-
-    /// <p>Creates a Input Security Group</p>
     fn create_input_security_group(
         &self,
         input: CreateInputSecurityGroupRequest,
     ) -> RusotoFuture<CreateInputSecurityGroupResponse, CreateInputSecurityGroupError> {
-        let request_uri = "/prod/inputSecurityGroups";
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInputSecurityGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateInputSecurityGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_input_security_group(input)
     }
-
-    /// <p>Create tags for a resource</p>
     fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
-        let request_uri = format!(
-            "/prod/tags/{resource_arn}",
-            resource_arn = input.resource_arn
-        );
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_tags(input)
     }
-
-    /// <p>Starts deletion of channel. The associated outputs are also deleted.</p>
     fn delete_channel(
         &self,
         input: DeleteChannelRequest,
     ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
-        let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
-
-        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_channel(input)
     }
-
-    /// <p>Deletes the input end point</p>
     fn delete_input(
         &self,
         input: DeleteInputRequest,
     ) -> RusotoFuture<DeleteInputResponse, DeleteInputError> {
-        let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
-
-        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInputResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInputError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_input(input)
     }
-
-    /// <p>Deletes an Input Security Group</p>
     fn delete_input_security_group(
         &self,
         input: DeleteInputSecurityGroupRequest,
     ) -> RusotoFuture<DeleteInputSecurityGroupResponse, DeleteInputSecurityGroupError> {
-        let request_uri = format!(
-            "/prod/inputSecurityGroups/{input_security_group_id}",
-            input_security_group_id = input.input_security_group_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInputSecurityGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteInputSecurityGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_input_security_group(input)
     }
-
-    /// <p>Delete an expired reservation.</p>
     fn delete_reservation(
         &self,
         input: DeleteReservationRequest,
     ) -> RusotoFuture<DeleteReservationResponse, DeleteReservationError> {
-        let request_uri = format!(
-            "/prod/reservations/{reservation_id}",
-            reservation_id = input.reservation_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteReservationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteReservationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_reservation(input)
     }
-
-    /// <p>Delete all schedule actions on a channel.</p>
     fn delete_schedule(
         &self,
         input: DeleteScheduleRequest,
     ) -> RusotoFuture<DeleteScheduleResponse, DeleteScheduleError> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/schedule",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteScheduleResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteScheduleError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_schedule(input)
     }
-
-    /// <p>Removes tags for a resource</p>
     fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
-        let request_uri = format!(
-            "/prod/tags/{resource_arn}",
-            resource_arn = input.resource_arn
-        );
-
-        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tags(input)
     }
-
-    /// <p>Gets details about a channel</p>
     fn describe_channel(
         &self,
         input: DescribeChannelRequest,
     ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
-        let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeChannelError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_channel(input)
     }
-
-    /// <p>Produces details about an input</p>
     fn describe_input(
         &self,
         input: DescribeInputRequest,
     ) -> RusotoFuture<DescribeInputResponse, DescribeInputError> {
-        let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInputResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeInputError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_input(input)
     }
-
-    /// <p>Produces a summary of an Input Security Group</p>
     fn describe_input_security_group(
         &self,
         input: DescribeInputSecurityGroupRequest,
     ) -> RusotoFuture<DescribeInputSecurityGroupResponse, DescribeInputSecurityGroupError> {
-        let request_uri = format!(
-            "/prod/inputSecurityGroups/{input_security_group_id}",
-            input_security_group_id = input.input_security_group_id
-        );
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInputSecurityGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInputSecurityGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_input_security_group(input)
     }
-
-    /// <p>Get details for an offering.</p>
     fn describe_offering(
         &self,
         input: DescribeOfferingRequest,
     ) -> RusotoFuture<DescribeOfferingResponse, DescribeOfferingError> {
-        let request_uri = format!(
-            "/prod/offerings/{offering_id}",
-            offering_id = input.offering_id
-        );
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOfferingResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeOfferingError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_offering(input)
     }
-
-    /// <p>Get details for a reservation.</p>
     fn describe_reservation(
         &self,
         input: DescribeReservationRequest,
     ) -> RusotoFuture<DescribeReservationResponse, DescribeReservationError> {
-        let request_uri = format!(
-            "/prod/reservations/{reservation_id}",
-            reservation_id = input.reservation_id
-        );
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeReservationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeReservationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_reservation(input)
     }
-
-    /// <p>Get a channel schedule</p>
     fn describe_schedule(
         &self,
         input: DescribeScheduleRequest,
     ) -> RusotoFuture<DescribeScheduleResponse, DescribeScheduleError> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/schedule",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeScheduleResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeScheduleError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_schedule(input)
     }
-
-    /// <p>Produces list of channels that have been created</p>
     fn list_channels(
         &self,
         input: ListChannelsRequest,
     ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
-        let request_uri = "/prod/channels";
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListChannelsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListChannelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_channels(input)
     }
-
-    /// <p>Produces a list of Input Security Groups for an account</p>
     fn list_input_security_groups(
         &self,
         input: ListInputSecurityGroupsRequest,
     ) -> RusotoFuture<ListInputSecurityGroupsResponse, ListInputSecurityGroupsError> {
-        let request_uri = "/prod/inputSecurityGroups";
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInputSecurityGroupsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListInputSecurityGroupsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_input_security_groups(input)
     }
-
-    /// <p>Produces list of inputs that have been created</p>
     fn list_inputs(
         &self,
         input: ListInputsRequest,
     ) -> RusotoFuture<ListInputsResponse, ListInputsError> {
-        let request_uri = "/prod/inputs";
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInputsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListInputsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_inputs(input)
     }
-
-    /// <p>List offerings available for purchase.</p>
     fn list_offerings(
         &self,
         input: ListOfferingsRequest,
     ) -> RusotoFuture<ListOfferingsResponse, ListOfferingsError> {
-        let request_uri = "/prod/offerings";
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.channel_class {
-            params.put("channelClass", x);
-        }
-        if let Some(ref x) = input.channel_configuration {
-            params.put("channelConfiguration", x);
-        }
-        if let Some(ref x) = input.codec {
-            params.put("codec", x);
-        }
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.maximum_bitrate {
-            params.put("maximumBitrate", x);
-        }
-        if let Some(ref x) = input.maximum_framerate {
-            params.put("maximumFramerate", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        if let Some(ref x) = input.resolution {
-            params.put("resolution", x);
-        }
-        if let Some(ref x) = input.resource_type {
-            params.put("resourceType", x);
-        }
-        if let Some(ref x) = input.special_feature {
-            params.put("specialFeature", x);
-        }
-        if let Some(ref x) = input.video_quality {
-            params.put("videoQuality", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOfferingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListOfferingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_offerings(input)
     }
-
-    /// <p>List purchased reservations.</p>
     fn list_reservations(
         &self,
         input: ListReservationsRequest,
     ) -> RusotoFuture<ListReservationsResponse, ListReservationsError> {
-        let request_uri = "/prod/reservations";
-
-        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.channel_class {
-            params.put("channelClass", x);
-        }
-        if let Some(ref x) = input.codec {
-            params.put("codec", x);
-        }
-        if let Some(ref x) = input.max_results {
-            params.put("maxResults", x);
-        }
-        if let Some(ref x) = input.maximum_bitrate {
-            params.put("maximumBitrate", x);
-        }
-        if let Some(ref x) = input.maximum_framerate {
-            params.put("maximumFramerate", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nextToken", x);
-        }
-        if let Some(ref x) = input.resolution {
-            params.put("resolution", x);
-        }
-        if let Some(ref x) = input.resource_type {
-            params.put("resourceType", x);
-        }
-        if let Some(ref x) = input.special_feature {
-            params.put("specialFeature", x);
-        }
-        if let Some(ref x) = input.video_quality {
-            params.put("videoQuality", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListReservationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListReservationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_reservations(input)
     }
-
-    /// <p>Produces list of tags that have been created for a resource</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = format!(
-            "/prod/tags/{resource_arn}",
-            resource_arn = input.resource_arn
-        );
-
+        (**self).list_tags_for_resource(input)
+    }
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResponse, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
+    fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> RusotoFuture<StartChannelResponse, StartChannelError> {
+        (**self).start_channel(input)
+    }
+    fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> RusotoFuture<StopChannelResponse, StopChannelError> {
+        (**self).stop_channel(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> RusotoFuture<UpdateChannelClassResponse, UpdateChannelClassError> {
+        (**self).update_channel_class(input)
+    }
+    fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> RusotoFuture<UpdateInputResponse, UpdateInputError> {
+        (**self).update_input(input)
+    }
+    fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> RusotoFuture<UpdateInputSecurityGroupResponse, UpdateInputSecurityGroupError> {
+        (**self).update_input_security_group(input)
+    }
+    fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> RusotoFuture<UpdateReservationResponse, UpdateReservationError> {
+        (**self).update_reservation(input)
+    }
+}
+impl<T: MediaLive + ?Sized> MediaLive for Box<T> {
+    fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> RusotoFuture<BatchUpdateScheduleResponse, BatchUpdateScheduleError> {
+        (**self).batch_update_schedule(input)
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> RusotoFuture<CreateInputResponse, CreateInputError> {
+        (**self).create_input(input)
+    }
+    fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> RusotoFuture<CreateInputSecurityGroupResponse, CreateInputSecurityGroupError> {
+        (**self).create_input_security_group(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> RusotoFuture<DeleteInputResponse, DeleteInputError> {
+        (**self).delete_input(input)
+    }
+    fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> RusotoFuture<DeleteInputSecurityGroupResponse, DeleteInputSecurityGroupError> {
+        (**self).delete_input_security_group(input)
+    }
+    fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> RusotoFuture<DeleteReservationResponse, DeleteReservationError> {
+        (**self).delete_reservation(input)
+    }
+    fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> RusotoFuture<DeleteScheduleResponse, DeleteScheduleError> {
+        (**self).delete_schedule(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> RusotoFuture<DescribeInputResponse, DescribeInputError> {
+        (**self).describe_input(input)
+    }
+    fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> RusotoFuture<DescribeInputSecurityGroupResponse, DescribeInputSecurityGroupError> {
+        (**self).describe_input_security_group(input)
+    }
+    fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> RusotoFuture<DescribeOfferingResponse, DescribeOfferingError> {
+        (**self).describe_offering(input)
+    }
+    fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> RusotoFuture<DescribeReservationResponse, DescribeReservationError> {
+        (**self).describe_reservation(input)
+    }
+    fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> RusotoFuture<DescribeScheduleResponse, DescribeScheduleError> {
+        (**self).describe_schedule(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> RusotoFuture<ListInputSecurityGroupsResponse, ListInputSecurityGroupsError> {
+        (**self).list_input_security_groups(input)
+    }
+    fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> RusotoFuture<ListInputsResponse, ListInputsError> {
+        (**self).list_inputs(input)
+    }
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResponse, ListOfferingsError> {
+        (**self).list_offerings(input)
+    }
+    fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> RusotoFuture<ListReservationsResponse, ListReservationsError> {
+        (**self).list_reservations(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResponse, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
+    fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> RusotoFuture<StartChannelResponse, StartChannelError> {
+        (**self).start_channel(input)
+    }
+    fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> RusotoFuture<StopChannelResponse, StopChannelError> {
+        (**self).stop_channel(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> RusotoFuture<UpdateChannelClassResponse, UpdateChannelClassError> {
+        (**self).update_channel_class(input)
+    }
+    fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> RusotoFuture<UpdateInputResponse, UpdateInputError> {
+        (**self).update_input(input)
+    }
+    fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> RusotoFuture<UpdateInputSecurityGroupResponse, UpdateInputSecurityGroupError> {
+        (**self).update_input_security_group(input)
+    }
+    fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> RusotoFuture<UpdateReservationResponse, UpdateReservationError> {
+        (**self).update_reservation(input)
+    }
+}
+impl<T: MediaLive + ?Sized> MediaLive for ::std::sync::Arc<T> {
+    fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> RusotoFuture<BatchUpdateScheduleResponse, BatchUpdateScheduleError> {
+        (**self).batch_update_schedule(input)
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> RusotoFuture<CreateInputResponse, CreateInputError> {
+        (**self).create_input(input)
+    }
+    fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> RusotoFuture<CreateInputSecurityGroupResponse, CreateInputSecurityGroupError> {
+        (**self).create_input_security_group(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> RusotoFuture<DeleteInputResponse, DeleteInputError> {
+        (**self).delete_input(input)
+    }
+    fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> RusotoFuture<DeleteInputSecurityGroupResponse, DeleteInputSecurityGroupError> {
+        (**self).delete_input_security_group(input)
+    }
+    fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> RusotoFuture<DeleteReservationResponse, DeleteReservationError> {
+        (**self).delete_reservation(input)
+    }
+    fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> RusotoFuture<DeleteScheduleResponse, DeleteScheduleError> {
+        (**self).delete_schedule(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> RusotoFuture<DescribeInputResponse, DescribeInputError> {
+        (**self).describe_input(input)
+    }
+    fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> RusotoFuture<DescribeInputSecurityGroupResponse, DescribeInputSecurityGroupError> {
+        (**self).describe_input_security_group(input)
+    }
+    fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> RusotoFuture<DescribeOfferingResponse, DescribeOfferingError> {
+        (**self).describe_offering(input)
+    }
+    fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> RusotoFuture<DescribeReservationResponse, DescribeReservationError> {
+        (**self).describe_reservation(input)
+    }
+    fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> RusotoFuture<DescribeScheduleResponse, DescribeScheduleError> {
+        (**self).describe_schedule(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> RusotoFuture<ListInputSecurityGroupsResponse, ListInputSecurityGroupsError> {
+        (**self).list_input_security_groups(input)
+    }
+    fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> RusotoFuture<ListInputsResponse, ListInputsError> {
+        (**self).list_inputs(input)
+    }
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResponse, ListOfferingsError> {
+        (**self).list_offerings(input)
+    }
+    fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> RusotoFuture<ListReservationsResponse, ListReservationsError> {
+        (**self).list_reservations(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResponse, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
+    fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> RusotoFuture<StartChannelResponse, StartChannelError> {
+        (**self).start_channel(input)
+    }
+    fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> RusotoFuture<StopChannelResponse, StopChannelError> {
+        (**self).stop_channel(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> RusotoFuture<UpdateChannelClassResponse, UpdateChannelClassError> {
+        (**self).update_channel_class(input)
+    }
+    fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> RusotoFuture<UpdateInputResponse, UpdateInputError> {
+        (**self).update_input(input)
+    }
+    fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> RusotoFuture<UpdateInputSecurityGroupResponse, UpdateInputSecurityGroupError> {
+        (**self).update_input_security_group(input)
+    }
+    fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> RusotoFuture<UpdateReservationResponse, UpdateReservationError> {
+        (**self).update_reservation(input)
+    }
+}
+/// A client for the MediaLive API.
+#[derive(Clone)]
+pub struct MediaLiveClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MediaLiveClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaLiveClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaLiveClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveClient {
+        MediaLiveClient { client, region }
+    }
+}
+
+impl MediaLive for MediaLiveClient {
+    /// <p>Update a channel schedule</p>
+    fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> RusotoFuture<BatchUpdateScheduleResponse, BatchUpdateScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/schedule",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchUpdateSchedule");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchUpdateSchedule")
+                        .deserialize::<BatchUpdateScheduleResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchUpdateScheduleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new channel</p>
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/prod/channels";
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateChannel")
+                        .deserialize::<CreateChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create an input</p>
+    fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> RusotoFuture<CreateInputResponse, CreateInputError> {
+        let request_uri = "/prod/inputs";
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInput");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateInput")
+                        .deserialize::<CreateInputResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInputError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Input Security Group</p>
+    fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> RusotoFuture<CreateInputSecurityGroupResponse, CreateInputSecurityGroupError> {
+        let request_uri = "/prod/inputSecurityGroups";
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInputSecurityGroup");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateInputSecurityGroup")
+                        .deserialize::<CreateInputSecurityGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateInputSecurityGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Create tags for a resource</p>
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        let request_uri = format!(
+            "/prod/tags/{resource_arn}",
+            resource_arn = input.resource_arn
+        );
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateTags");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts deletion of channel. The associated outputs are also deleted.</p>
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
+
+        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteChannel")
+                        .deserialize::<DeleteChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the input end point</p>
+    fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> RusotoFuture<DeleteInputResponse, DeleteInputError> {
+        let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
+
+        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
+        request.add_resource_id("InputId", &input.input_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteInput")
+                        .deserialize::<DeleteInputResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInputError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an Input Security Group</p>
+    fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> RusotoFuture<DeleteInputSecurityGroupResponse, DeleteInputSecurityGroupError> {
+        let request_uri = format!(
+            "/prod/inputSecurityGroups/{input_security_group_id}",
+            input_security_group_id = input.input_security_group_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInputSecurityGroup");
+        request.add_resource_id(
+            "InputSecurityGroupId",
+            &input.input_security_group_id.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteInputSecurityGroup")
+                        .deserialize::<DeleteInputSecurityGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteInputSecurityGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Delete an expired reservation.</p>
+    fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> RusotoFuture<DeleteReservationResponse, DeleteReservationError> {
+        let request_uri = format!(
+            "/prod/reservations/{reservation_id}",
+            reservation_id = input.reservation_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteReservation");
+        request.add_resource_id("ReservationId", &input.reservation_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteReservation")
+                        .deserialize::<DeleteReservationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteReservationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Delete all schedule actions on a channel.</p>
+    fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> RusotoFuture<DeleteScheduleResponse, DeleteScheduleError> {
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/schedule",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteSchedule");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteSchedule")
+                        .deserialize::<DeleteScheduleResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteScheduleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags for a resource</p>
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        let request_uri = format!(
+            "/prod/tags/{resource_arn}",
+            resource_arn = input.resource_arn
+        );
+
+        let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets details about a channel</p>
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeChannel")
+                        .deserialize::<DescribeChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Produces details about an input</p>
+    fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> RusotoFuture<DescribeInputResponse, DescribeInputError> {
+        let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
+        request.add_resource_id("InputId", &input.input_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeInput")
+                        .deserialize::<DescribeInputResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeInputError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Produces a summary of an Input Security Group</p>
+    fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> RusotoFuture<DescribeInputSecurityGroupResponse, DescribeInputSecurityGroupError> {
+        let request_uri = format!(
+            "/prod/inputSecurityGroups/{input_security_group_id}",
+            input_security_group_id = input.input_security_group_id
+        );
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputSecurityGroup");
+        request.add_resource_id(
+            "InputSecurityGroupId",
+            &input.input_security_group_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeInputSecurityGroup")
+                        .deserialize::<DescribeInputSecurityGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInputSecurityGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Get details for an offering.</p>
+    fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> RusotoFuture<DescribeOfferingResponse, DescribeOfferingError> {
+        let request_uri = format!(
+            "/prod/offerings/{offering_id}",
+            offering_id = input.offering_id
+        );
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeOffering");
+        request.add_resource_id("OfferingId", &input.offering_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeOffering")
+                        .deserialize::<DescribeOfferingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeOfferingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Get details for a reservation.</p>
+    fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> RusotoFuture<DescribeReservationResponse, DescribeReservationError> {
+        let request_uri = format!(
+            "/prod/reservations/{reservation_id}",
+            reservation_id = input.reservation_id
+        );
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeReservation");
+        request.add_resource_id("ReservationId", &input.reservation_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeReservation")
+                        .deserialize::<DescribeReservationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeReservationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Get a channel schedule</p>
+    fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> RusotoFuture<DescribeScheduleResponse, DescribeScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/schedule",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeSchedule");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeSchedule")
+                        .deserialize::<DescribeScheduleResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeScheduleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Produces list of channels that have been created</p>
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/prod/channels";
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListChannels");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListChannels")
+                        .deserialize::<ListChannelsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListChannelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Produces a list of Input Security Groups for an account</p>
+    fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> RusotoFuture<ListInputSecurityGroupsResponse, ListInputSecurityGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/prod/inputSecurityGroups";
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputSecurityGroups");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListInputSecurityGroups")
+                        .deserialize::<ListInputSecurityGroupsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListInputSecurityGroupsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Produces list of inputs that have been created</p>
+    fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> RusotoFuture<ListInputsResponse, ListInputsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/prod/inputs";
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputs");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListInputs")
+                        .deserialize::<ListInputsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInputsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List offerings available for purchase.</p>
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResponse, ListOfferingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/prod/offerings";
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListOfferings");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.channel_class {
+            params.put("channelClass", x);
+        }
+        if let Some(ref x) = input.channel_configuration {
+            params.put("channelConfiguration", x);
+        }
+        if let Some(ref x) = input.codec {
+            params.put("codec", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.maximum_bitrate {
+            params.put("maximumBitrate", x);
+        }
+        if let Some(ref x) = input.maximum_framerate {
+            params.put("maximumFramerate", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        if let Some(ref x) = input.resolution {
+            params.put("resolution", x);
+        }
+        if let Some(ref x) = input.resource_type {
+            params.put("resourceType", x);
+        }
+        if let Some(ref x) = input.special_feature {
+            params.put("specialFeature", x);
+        }
+        if let Some(ref x) = input.video_quality {
+            params.put("videoQuality", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListOfferings")
+                        .deserialize::<ListOfferingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListOfferingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List purchased reservations.</p>
+    fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> RusotoFuture<ListReservationsResponse, ListReservationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/prod/reservations";
+
+        let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListReservations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.channel_class {
+            params.put("channelClass", x);
+        }
+        if let Some(ref x) = input.codec {
+            params.put("codec", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("maxResults", x);
+        }
+        if let Some(ref x) = input.maximum_bitrate {
+            params.put("maximumBitrate", x);
+        }
+        if let Some(ref x) = input.maximum_framerate {
+            params.put("maximumFramerate", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nextToken", x);
+        }
+        if let Some(ref x) = input.resolution {
+            params.put("resolution", x);
+        }
+        if let Some(ref x) = input.resource_type {
+            params.put("resourceType", x);
+        }
+        if let Some(ref x) = input.special_feature {
+            params.put("specialFeature", x);
+        }
+        if let Some(ref x) = input.video_quality {
+            params.put("videoQuality", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListReservations")
+                        .deserialize::<ListReservationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListReservationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Produces list of tags that have been created for a resource</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let request_uri = format!(
+            "/prod/tags/{resource_arn}",
+            resource_arn = input.resource_arn
+        );
+
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Purchase an offering and create a reservation.</p>
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResponse, PurchaseOfferingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/prod/offerings/{offering_id}/purchase",
+            offering_id = input.offering_id
+        );
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("PurchaseOffering");
+        request.add_resource_id("OfferingId", &input.offering_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PurchaseOffering")
+                        .deserialize::<PurchaseOfferingResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PurchaseOfferingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts an existing channel</p>
+    fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> RusotoFuture<StartChannelResponse, StartChannelError> {
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/start",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartChannel");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartChannel")
+                        .deserialize::<StartChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a running channel</p>
+    fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> RusotoFuture<StopChannelResponse, StopChannelError> {
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/stop",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopChannel");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StopChannel")
+                        .deserialize::<StopChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a channel.</p>
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateChannel")
+                        .deserialize::<UpdateChannelResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateChannelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes the class of the channel.</p>
+    fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> RusotoFuture<UpdateChannelClassResponse, UpdateChannelClassError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/prod/channels/{channel_id}/channelClass",
+            channel_id = input.channel_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannelClass");
+        request.add_resource_id("ChannelId", &input.channel_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateChannelClass")
+                        .deserialize::<UpdateChannelClassResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateChannelClassError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an input.</p>
+    fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> RusotoFuture<UpdateInputResponse, UpdateInputError> {
+        let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
+        request.add_resource_id("InputId", &input.input_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateInput")
+                        .deserialize::<UpdateInputResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateInputError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Update an Input Security Group&#39;s Whilelists.</p>
+    fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> RusotoFuture<UpdateInputSecurityGroupResponse, UpdateInputSecurityGroupError> {
+        let request_uri = format!(
+            "/prod/inputSecurityGroups/{input_security_group_id}",
+            input_security_group_id = input.input_security_group_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInputSecurityGroup");
+        request.add_resource_id(
+            "InputSecurityGroupId",
+            &input.input_security_group_id.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateInputSecurityGroup")
+                        .deserialize::<UpdateInputSecurityGroupResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateInputSecurityGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Update reservation.</p>
+    fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> RusotoFuture<UpdateReservationResponse, UpdateReservationError> {
+        let request_uri = format!(
+            "/prod/reservations/{reservation_id}",
+            reservation_id = input.reservation_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateReservation");
+        request.add_resource_id("ReservationId", &input.reservation_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
+                        .with_operation("UpdateReservation")
+                        .deserialize::<UpdateReservationResponse, _>()?;
 
                     Ok(result)
                 }))
             } else {
                 Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateReservationError::from_response(response))),
                 )
             }
         })
     }
-
-    /// <p>Purchase an offering and create a reservation.</p>
+}
+
+/// A mock of the MediaLive API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMediaLive {
+    batch_update_schedule: Option<
+        Box<
+            dyn Fn(
+                    BatchUpdateScheduleRequest,
+                ) -> ::std::result::Result<
+                    BatchUpdateScheduleResponse,
+                    RusotoError<BatchUpdateScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_channel: Option<
+        Box<
+            dyn Fn(
+                    CreateChannelRequest,
+                )
+                    -> ::std::result::Result<CreateChannelResponse, RusotoError<CreateChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_input: Option<
+        Box<
+            dyn Fn(
+                    CreateInputRequest,
+                )
+                    -> ::std::result::Result<CreateInputResponse, RusotoError<CreateInputError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_input_security_group: Option<
+        Box<
+            dyn Fn(
+                    CreateInputSecurityGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateInputSecurityGroupResponse,
+                    RusotoError<CreateInputSecurityGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tags: Option<
+        Box<
+            dyn Fn(CreateTagsRequest) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_channel: Option<
+        Box<
+            dyn Fn(
+                    DeleteChannelRequest,
+                )
+                    -> ::std::result::Result<DeleteChannelResponse, RusotoError<DeleteChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_input: Option<
+        Box<
+            dyn Fn(
+                    DeleteInputRequest,
+                )
+                    -> ::std::result::Result<DeleteInputResponse, RusotoError<DeleteInputError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_input_security_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteInputSecurityGroupRequest,
+                ) -> ::std::result::Result<
+                    DeleteInputSecurityGroupResponse,
+                    RusotoError<DeleteInputSecurityGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_reservation: Option<
+        Box<
+            dyn Fn(
+                    DeleteReservationRequest,
+                ) -> ::std::result::Result<
+                    DeleteReservationResponse,
+                    RusotoError<DeleteReservationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_schedule: Option<
+        Box<
+            dyn Fn(
+                    DeleteScheduleRequest,
+                ) -> ::std::result::Result<
+                    DeleteScheduleResponse,
+                    RusotoError<DeleteScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(DeleteTagsRequest) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_channel: Option<
+        Box<
+            dyn Fn(
+                    DescribeChannelRequest,
+                ) -> ::std::result::Result<
+                    DescribeChannelResponse,
+                    RusotoError<DescribeChannelError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_input: Option<
+        Box<
+            dyn Fn(
+                    DescribeInputRequest,
+                )
+                    -> ::std::result::Result<DescribeInputResponse, RusotoError<DescribeInputError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_input_security_group: Option<
+        Box<
+            dyn Fn(
+                    DescribeInputSecurityGroupRequest,
+                ) -> ::std::result::Result<
+                    DescribeInputSecurityGroupResponse,
+                    RusotoError<DescribeInputSecurityGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_offering: Option<
+        Box<
+            dyn Fn(
+                    DescribeOfferingRequest,
+                ) -> ::std::result::Result<
+                    DescribeOfferingResponse,
+                    RusotoError<DescribeOfferingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_reservation: Option<
+        Box<
+            dyn Fn(
+                    DescribeReservationRequest,
+                ) -> ::std::result::Result<
+                    DescribeReservationResponse,
+                    RusotoError<DescribeReservationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_schedule: Option<
+        Box<
+            dyn Fn(
+                    DescribeScheduleRequest,
+                ) -> ::std::result::Result<
+                    DescribeScheduleResponse,
+                    RusotoError<DescribeScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_channels: Option<
+        Box<
+            dyn Fn(
+                    ListChannelsRequest,
+                )
+                    -> ::std::result::Result<ListChannelsResponse, RusotoError<ListChannelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_input_security_groups: Option<
+        Box<
+            dyn Fn(
+                    ListInputSecurityGroupsRequest,
+                ) -> ::std::result::Result<
+                    ListInputSecurityGroupsResponse,
+                    RusotoError<ListInputSecurityGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_inputs: Option<
+        Box<
+            dyn Fn(
+                    ListInputsRequest,
+                )
+                    -> ::std::result::Result<ListInputsResponse, RusotoError<ListInputsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_offerings: Option<
+        Box<
+            dyn Fn(
+                    ListOfferingsRequest,
+                )
+                    -> ::std::result::Result<ListOfferingsResponse, RusotoError<ListOfferingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_reservations: Option<
+        Box<
+            dyn Fn(
+                    ListReservationsRequest,
+                ) -> ::std::result::Result<
+                    ListReservationsResponse,
+                    RusotoError<ListReservationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    purchase_offering: Option<
+        Box<
+            dyn Fn(
+                    PurchaseOfferingRequest,
+                ) -> ::std::result::Result<
+                    PurchaseOfferingResponse,
+                    RusotoError<PurchaseOfferingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_channel: Option<
+        Box<
+            dyn Fn(
+                    StartChannelRequest,
+                )
+                    -> ::std::result::Result<StartChannelResponse, RusotoError<StartChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_channel: Option<
+        Box<
+            dyn Fn(
+                    StopChannelRequest,
+                )
+                    -> ::std::result::Result<StopChannelResponse, RusotoError<StopChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_channel: Option<
+        Box<
+            dyn Fn(
+                    UpdateChannelRequest,
+                )
+                    -> ::std::result::Result<UpdateChannelResponse, RusotoError<UpdateChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_channel_class: Option<
+        Box<
+            dyn Fn(
+                    UpdateChannelClassRequest,
+                ) -> ::std::result::Result<
+                    UpdateChannelClassResponse,
+                    RusotoError<UpdateChannelClassError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_input: Option<
+        Box<
+            dyn Fn(
+                    UpdateInputRequest,
+                )
+                    -> ::std::result::Result<UpdateInputResponse, RusotoError<UpdateInputError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_input_security_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateInputSecurityGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateInputSecurityGroupResponse,
+                    RusotoError<UpdateInputSecurityGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_reservation: Option<
+        Box<
+            dyn Fn(
+                    UpdateReservationRequest,
+                ) -> ::std::result::Result<
+                    UpdateReservationResponse,
+                    RusotoError<UpdateReservationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMediaLive {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMediaLive {
+        Default::default()
+    }
+
+    /// Answers `batch_update_schedule` with what `handler` returns for its input.
+    pub fn with_batch_update_schedule<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                BatchUpdateScheduleRequest,
+            ) -> ::std::result::Result<
+                BatchUpdateScheduleResponse,
+                RusotoError<BatchUpdateScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_update_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_channel` with what `handler` returns for its input.
+    pub fn with_create_channel<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                CreateChannelRequest,
+            )
+                -> ::std::result::Result<CreateChannelResponse, RusotoError<CreateChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_input` with what `handler` returns for its input.
+    pub fn with_create_input<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                CreateInputRequest,
+            )
+                -> ::std::result::Result<CreateInputResponse, RusotoError<CreateInputError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_input = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_input_security_group` with what `handler` returns for its input.
+    pub fn with_create_input_security_group<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                CreateInputSecurityGroupRequest,
+            ) -> ::std::result::Result<
+                CreateInputSecurityGroupResponse,
+                RusotoError<CreateInputSecurityGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_input_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tags` with what `handler` returns for its input.
+    pub fn with_create_tags<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(CreateTagsRequest) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_channel` with what `handler` returns for its input.
+    pub fn with_delete_channel<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DeleteChannelRequest,
+            )
+                -> ::std::result::Result<DeleteChannelResponse, RusotoError<DeleteChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_input` with what `handler` returns for its input.
+    pub fn with_delete_input<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DeleteInputRequest,
+            )
+                -> ::std::result::Result<DeleteInputResponse, RusotoError<DeleteInputError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_input = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_input_security_group` with what `handler` returns for its input.
+    pub fn with_delete_input_security_group<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DeleteInputSecurityGroupRequest,
+            ) -> ::std::result::Result<
+                DeleteInputSecurityGroupResponse,
+                RusotoError<DeleteInputSecurityGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_input_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_reservation` with what `handler` returns for its input.
+    pub fn with_delete_reservation<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DeleteReservationRequest,
+            ) -> ::std::result::Result<
+                DeleteReservationResponse,
+                RusotoError<DeleteReservationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_reservation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_schedule` with what `handler` returns for its input.
+    pub fn with_delete_schedule<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DeleteScheduleRequest,
+            )
+                -> ::std::result::Result<DeleteScheduleResponse, RusotoError<DeleteScheduleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(DeleteTagsRequest) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_channel` with what `handler` returns for its input.
+    pub fn with_describe_channel<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DescribeChannelRequest,
+            )
+                -> ::std::result::Result<DescribeChannelResponse, RusotoError<DescribeChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_input` with what `handler` returns for its input.
+    pub fn with_describe_input<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DescribeInputRequest,
+            )
+                -> ::std::result::Result<DescribeInputResponse, RusotoError<DescribeInputError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_input = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_input_security_group` with what `handler` returns for its input.
+    pub fn with_describe_input_security_group<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DescribeInputSecurityGroupRequest,
+            ) -> ::std::result::Result<
+                DescribeInputSecurityGroupResponse,
+                RusotoError<DescribeInputSecurityGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_input_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_offering` with what `handler` returns for its input.
+    pub fn with_describe_offering<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DescribeOfferingRequest,
+            ) -> ::std::result::Result<
+                DescribeOfferingResponse,
+                RusotoError<DescribeOfferingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_offering = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_reservation` with what `handler` returns for its input.
+    pub fn with_describe_reservation<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DescribeReservationRequest,
+            ) -> ::std::result::Result<
+                DescribeReservationResponse,
+                RusotoError<DescribeReservationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_reservation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_schedule` with what `handler` returns for its input.
+    pub fn with_describe_schedule<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                DescribeScheduleRequest,
+            ) -> ::std::result::Result<
+                DescribeScheduleResponse,
+                RusotoError<DescribeScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_channels` with what `handler` returns for its input.
+    pub fn with_list_channels<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                ListChannelsRequest,
+            )
+                -> ::std::result::Result<ListChannelsResponse, RusotoError<ListChannelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_channels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_input_security_groups` with what `handler` returns for its input.
+    pub fn with_list_input_security_groups<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                ListInputSecurityGroupsRequest,
+            ) -> ::std::result::Result<
+                ListInputSecurityGroupsResponse,
+                RusotoError<ListInputSecurityGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_input_security_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_inputs` with what `handler` returns for its input.
+    pub fn with_list_inputs<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                ListInputsRequest,
+            )
+                -> ::std::result::Result<ListInputsResponse, RusotoError<ListInputsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_inputs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_offerings` with what `handler` returns for its input.
+    pub fn with_list_offerings<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                ListOfferingsRequest,
+            )
+                -> ::std::result::Result<ListOfferingsResponse, RusotoError<ListOfferingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_offerings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_reservations` with what `handler` returns for its input.
+    pub fn with_list_reservations<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                ListReservationsRequest,
+            ) -> ::std::result::Result<
+                ListReservationsResponse,
+                RusotoError<ListReservationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_reservations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `purchase_offering` with what `handler` returns for its input.
+    pub fn with_purchase_offering<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                PurchaseOfferingRequest,
+            ) -> ::std::result::Result<
+                PurchaseOfferingResponse,
+                RusotoError<PurchaseOfferingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.purchase_offering = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_channel` with what `handler` returns for its input.
+    pub fn with_start_channel<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                StartChannelRequest,
+            )
+                -> ::std::result::Result<StartChannelResponse, RusotoError<StartChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_channel` with what `handler` returns for its input.
+    pub fn with_stop_channel<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                StopChannelRequest,
+            )
+                -> ::std::result::Result<StopChannelResponse, RusotoError<StopChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_channel` with what `handler` returns for its input.
+    pub fn with_update_channel<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                UpdateChannelRequest,
+            )
+                -> ::std::result::Result<UpdateChannelResponse, RusotoError<UpdateChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_channel_class` with what `handler` returns for its input.
+    pub fn with_update_channel_class<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                UpdateChannelClassRequest,
+            ) -> ::std::result::Result<
+                UpdateChannelClassResponse,
+                RusotoError<UpdateChannelClassError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_channel_class = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_input` with what `handler` returns for its input.
+    pub fn with_update_input<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                UpdateInputRequest,
+            )
+                -> ::std::result::Result<UpdateInputResponse, RusotoError<UpdateInputError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_input = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_input_security_group` with what `handler` returns for its input.
+    pub fn with_update_input_security_group<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                UpdateInputSecurityGroupRequest,
+            ) -> ::std::result::Result<
+                UpdateInputSecurityGroupResponse,
+                RusotoError<UpdateInputSecurityGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_input_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_reservation` with what `handler` returns for its input.
+    pub fn with_update_reservation<F>(mut self, handler: F) -> MockMediaLive
+    where
+        F: Fn(
+                UpdateReservationRequest,
+            ) -> ::std::result::Result<
+                UpdateReservationResponse,
+                RusotoError<UpdateReservationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_reservation = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MediaLive for MockMediaLive {
+    fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> RusotoFuture<BatchUpdateScheduleResponse, BatchUpdateScheduleError> {
+        match self.batch_update_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for batch_update_schedule"),
+        }
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        match self.create_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for create_channel"),
+        }
+    }
+    fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> RusotoFuture<CreateInputResponse, CreateInputError> {
+        match self.create_input {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for create_input"),
+        }
+    }
+    fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> RusotoFuture<CreateInputSecurityGroupResponse, CreateInputSecurityGroupError> {
+        match self.create_input_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for create_input_security_group"),
+        }
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        match self.create_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for create_tags"),
+        }
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        match self.delete_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for delete_channel"),
+        }
+    }
+    fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> RusotoFuture<DeleteInputResponse, DeleteInputError> {
+        match self.delete_input {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for delete_input"),
+        }
+    }
+    fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> RusotoFuture<DeleteInputSecurityGroupResponse, DeleteInputSecurityGroupError> {
+        match self.delete_input_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for delete_input_security_group"),
+        }
+    }
+    fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> RusotoFuture<DeleteReservationResponse, DeleteReservationError> {
+        match self.delete_reservation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for delete_reservation"),
+        }
+    }
+    fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> RusotoFuture<DeleteScheduleResponse, DeleteScheduleError> {
+        match self.delete_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for delete_schedule"),
+        }
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for delete_tags"),
+        }
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        match self.describe_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for describe_channel"),
+        }
+    }
+    fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> RusotoFuture<DescribeInputResponse, DescribeInputError> {
+        match self.describe_input {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for describe_input"),
+        }
+    }
+    fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> RusotoFuture<DescribeInputSecurityGroupResponse, DescribeInputSecurityGroupError> {
+        match self.describe_input_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for describe_input_security_group"),
+        }
+    }
+    fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> RusotoFuture<DescribeOfferingResponse, DescribeOfferingError> {
+        match self.describe_offering {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for describe_offering"),
+        }
+    }
+    fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> RusotoFuture<DescribeReservationResponse, DescribeReservationError> {
+        match self.describe_reservation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for describe_reservation"),
+        }
+    }
+    fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> RusotoFuture<DescribeScheduleResponse, DescribeScheduleError> {
+        match self.describe_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for describe_schedule"),
+        }
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        match self.list_channels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for list_channels"),
+        }
+    }
+    fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> RusotoFuture<ListInputSecurityGroupsResponse, ListInputSecurityGroupsError> {
+        match self.list_input_security_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for list_input_security_groups"),
+        }
+    }
+    fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> RusotoFuture<ListInputsResponse, ListInputsError> {
+        match self.list_inputs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for list_inputs"),
+        }
+    }
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResponse, ListOfferingsError> {
+        match self.list_offerings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for list_offerings"),
+        }
+    }
+    fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> RusotoFuture<ListReservationsResponse, ListReservationsError> {
+        match self.list_reservations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for list_reservations"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for list_tags_for_resource"),
+        }
+    }
     fn purchase_offering(
         &self,
         input: PurchaseOfferingRequest,
     ) -> RusotoFuture<PurchaseOfferingResponse, PurchaseOfferingError> {
-        let request_uri = format!(
-            "/prod/offerings/{offering_id}/purchase",
-            offering_id = input.offering_id
-        );
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PurchaseOfferingResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PurchaseOfferingError::from_response(response))),
-                )
-            }
-        })
+        match self.purchase_offering {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for purchase_offering"),
+        }
     }
-
-    /// <p>Starts an existing channel</p>
     fn start_channel(
         &self,
         input: StartChannelRequest,
     ) -> RusotoFuture<StartChannelResponse, StartChannelError> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/start",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartChannelError::from_response(response))),
-                )
-            }
-        })
+        match self.start_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for start_channel"),
+        }
     }
-
-    /// <p>Stops a running channel</p>
     fn stop_channel(
         &self,
         input: StopChannelRequest,
     ) -> RusotoFuture<StopChannelResponse, StopChannelError> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/stop",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopChannelError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for stop_channel"),
+        }
     }
-
-    /// <p>Updates a channel.</p>
     fn update_channel(
         &self,
         input: UpdateChannelRequest,
     ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
-        let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateChannelResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateChannelError::from_response(response))),
-                )
-            }
-        })
+        match self.update_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for update_channel"),
+        }
     }
-
-    /// <p>Changes the class of the channel.</p>
     fn update_channel_class(
         &self,
         input: UpdateChannelClassRequest,
     ) -> RusotoFuture<UpdateChannelClassResponse, UpdateChannelClassError> {
-        let request_uri = format!(
-            "/prod/channels/{channel_id}/channelClass",
-            channel_id = input.channel_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateChannelClassResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateChannelClassError::from_response(response))),
-                )
-            }
-        })
+        match self.update_channel_class {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for update_channel_class"),
+        }
     }
-
-    /// <p>Updates an input.</p>
     fn update_input(
         &self,
         input: UpdateInputRequest,
     ) -> RusotoFuture<UpdateInputResponse, UpdateInputError> {
-        let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateInputResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateInputError::from_response(response))),
-                )
-            }
-        })
+        match self.update_input {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for update_input"),
+        }
     }
-
-    /// <p>Update an Input Security Group&#39;s Whilelists.</p>
     fn update_input_security_group(
         &self,
         input: UpdateInputSecurityGroupRequest,
     ) -> RusotoFuture<UpdateInputSecurityGroupResponse, UpdateInputSecurityGroupError> {
-        let request_uri = format!(
-            "/prod/inputSecurityGroups/{input_security_group_id}",
-            input_security_group_id = input.input_security_group_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateInputSecurityGroupResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateInputSecurityGroupError::from_response(response))
-                }))
-            }
-        })
+        match self.update_input_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for update_input_security_group"),
+        }
     }
-
-    /// <p>Update reservation.</p>
     fn update_reservation(
         &self,
         input: UpdateReservationRequest,
     ) -> RusotoFuture<UpdateReservationResponse, UpdateReservationError> {
-        let request_uri = format!(
-            "/prod/reservations/{reservation_id}",
-            reservation_id = input.reservation_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateReservationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateReservationError::from_response(response))),
-                )
-            }
-        })
+        match self.update_reservation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaLive has no handler for update_reservation"),
+        }
     }
 }
@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A Channel resource configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Channel {
     /// <p>The Amazon Resource Name (ARN) assigned to the Channel.</p>
     #[serde(rename = "Arn")]
@@ -61,7 +62,7 @@ pub struct CmafEncryption {
 
 /// <p>A Common Media Application Format (CMAF) packaging configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CmafPackage {
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,6 +87,7 @@ pub struct CmafPackage {
 
 /// <p>A Common Media Application Format (CMAF) packaging configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CmafPackageCreateOrUpdateParameters {
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -110,6 +112,7 @@ pub struct CmafPackageCreateOrUpdateParameters {
 
 /// <p>A new Channel configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateChannelRequest {
     /// <p>A short text description of the Channel.</p>
     #[serde(rename = "Description")]
@@ -125,7 +128,7 @@ pub struct CreateChannelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateChannelResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the Channel.</p>
     #[serde(rename = "Arn")]
@@ -149,6 +152,7 @@ pub struct CreateChannelResponse {
 
 /// <p>Configuration parameters used to create a new OriginEndpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateOriginEndpointRequest {
     /// <p>The ID of the Channel that the OriginEndpoint will be associated with.
     /// This cannot be changed after the OriginEndpoint is created.</p>
@@ -198,7 +202,7 @@ pub struct CreateOriginEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateOriginEndpointResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the OriginEndpoint.</p>
     #[serde(rename = "Arn")]
@@ -324,6 +328,7 @@ pub struct DashPackage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteChannelRequest {
     /// <p>The ID of the Channel to delete.</p>
     #[serde(rename = "Id")]
@@ -331,10 +336,11 @@ pub struct DeleteChannelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteChannelResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteOriginEndpointRequest {
     /// <p>The ID of the OriginEndpoint to delete.</p>
     #[serde(rename = "Id")]
@@ -342,10 +348,11 @@ pub struct DeleteOriginEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteOriginEndpointResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeChannelRequest {
     /// <p>The ID of a Channel.</p>
     #[serde(rename = "Id")]
@@ -353,7 +360,7 @@ pub struct DescribeChannelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeChannelResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the Channel.</p>
     #[serde(rename = "Arn")]
@@ -376,6 +383,7 @@ pub struct DescribeChannelResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOriginEndpointRequest {
     /// <p>The ID of the OriginEndpoint.</p>
     #[serde(rename = "Id")]
@@ -383,7 +391,7 @@ pub struct DescribeOriginEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOriginEndpointResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the OriginEndpoint.</p>
     #[serde(rename = "Arn")]
@@ -466,7 +474,7 @@ pub struct HlsEncryption {
 
 /// <p>An HTTP Live Streaming (HLS) ingest resource configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HlsIngest {
     /// <p>A list of endpoints to which the source stream should be sent.</p>
     #[serde(rename = "IngestEndpoints")]
@@ -476,7 +484,7 @@ pub struct HlsIngest {
 
 /// <p>A HTTP Live Streaming (HLS) manifest configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HlsManifest {
     /// <p>This setting controls how ad markers are included in the packaged OriginEndpoint.
     /// &quot;NONE&quot; will omit all SCTE-35 ad markers from the output.
@@ -528,6 +536,7 @@ pub struct HlsManifest {
 
 /// <p>A HTTP Live Streaming (HLS) manifest configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HlsManifestCreateOrUpdateParameters {
     /// <p>This setting controls how ad markers are included in the packaged OriginEndpoint.
     /// &quot;NONE&quot; will omit all SCTE-35 ad markers from the output.
@@ -642,7 +651,7 @@ pub struct HlsPackage {
 
 /// <p>An endpoint for ingesting source content for a Channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IngestEndpoint {
     /// <p>The system generated unique identifier for the IngestEndpoint</p>
     #[serde(rename = "Id")]
@@ -663,6 +672,7 @@ pub struct IngestEndpoint {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListChannelsRequest {
     /// <p>Upper bound on number of records to return.</p>
     #[serde(rename = "MaxResults")]
@@ -674,8 +684,21 @@ pub struct ListChannelsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListChannelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListChannelsResponse {
     /// <p>A list of Channel records.</p>
     #[serde(rename = "Channels")]
@@ -688,6 +711,7 @@ pub struct ListChannelsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOriginEndpointsRequest {
     /// <p>When specified, the request will return only OriginEndpoints associated with the given Channel ID.</p>
     #[serde(rename = "ChannelId")]
@@ -703,8 +727,21 @@ pub struct ListOriginEndpointsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListOriginEndpointsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOriginEndpointsResponse {
     /// <p>A token that can be used to resume pagination from the end of the collection.</p>
     #[serde(rename = "NextToken")]
@@ -717,13 +754,14 @@ pub struct ListOriginEndpointsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -758,7 +796,7 @@ pub struct MssPackage {
 
 /// <p>An OriginEndpoint resource configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OriginEndpoint {
     /// <p>The Amazon Resource Name (ARN) assigned to the OriginEndpoint.</p>
     #[serde(rename = "Arn")]
@@ -816,6 +854,7 @@ pub struct OriginEndpoint {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RotateChannelCredentialsRequest {
     /// <p>The ID of the channel to update.</p>
     #[serde(rename = "Id")]
@@ -823,7 +862,7 @@ pub struct RotateChannelCredentialsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RotateChannelCredentialsResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the Channel.</p>
     #[serde(rename = "Arn")]
@@ -846,6 +885,7 @@ pub struct RotateChannelCredentialsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RotateIngestEndpointCredentialsRequest {
     /// <p>The ID of the channel the IngestEndpoint is on.</p>
     #[serde(rename = "Id")]
@@ -856,7 +896,7 @@ pub struct RotateIngestEndpointCredentialsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RotateIngestEndpointCredentialsResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the Channel.</p>
     #[serde(rename = "Arn")]
@@ -920,6 +960,7 @@ pub struct StreamSelection {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
@@ -928,6 +969,7 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
@@ -938,6 +980,7 @@ pub struct UntagResourceRequest {
 
 /// <p>Configuration parameters used to update the Channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateChannelRequest {
     /// <p>A short text description of the Channel.</p>
     #[serde(rename = "Description")]
@@ -949,7 +992,7 @@ pub struct UpdateChannelRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateChannelResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the Channel.</p>
     #[serde(rename = "Arn")]
@@ -973,6 +1016,7 @@ pub struct UpdateChannelResponse {
 
 /// <p>Configuration parameters used to update an existing OriginEndpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateOriginEndpointRequest {
     #[serde(rename = "CmafPackage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1014,7 +1058,7 @@ pub struct UpdateOriginEndpointRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateOriginEndpointResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the OriginEndpoint.</p>
     #[serde(rename = "Arn")]
@@ -1110,7 +1154,12 @@ impl CreateChannelError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1181,7 +1230,12 @@ impl CreateOriginEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1244,7 +1298,12 @@ impl DeleteChannelError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(DeleteChannelError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1315,7 +1374,12 @@ impl DeleteOriginEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1378,7 +1442,12 @@ impl DescribeChannelError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(DescribeChannelError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1449,7 +1518,12 @@ impl DescribeOriginEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1512,7 +1586,12 @@ impl ListChannelsError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(ListChannelsError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1581,7 +1660,12 @@ impl ListOriginEndpointsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1613,7 +1697,12 @@ impl ListTagsForResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsForResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1677,7 +1766,12 @@ impl RotateChannelCredentialsError {
                         RotateChannelCredentialsError::UnprocessableEntity(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1754,7 +1848,12 @@ impl RotateIngestEndpointCredentialsError {
                         RotateIngestEndpointCredentialsError::UnprocessableEntity(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1786,7 +1885,12 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1811,7 +1915,12 @@ impl UntagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1867,7 +1976,12 @@ impl UpdateChannelError {
                 "UnprocessableEntityException" => {
                     return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1938,7 +2052,12 @@ impl UpdateOriginEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2045,6 +2164,268 @@ pub trait MediaPackage {
         input: UpdateOriginEndpointRequest,
     ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError>;
 }
+const _: Option<&dyn MediaPackage> = None;
+impl<'a, T: MediaPackage + ?Sized> MediaPackage for &'a T {
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> RusotoFuture<CreateOriginEndpointResponse, CreateOriginEndpointError> {
+        (**self).create_origin_endpoint(input)
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> RusotoFuture<DeleteOriginEndpointResponse, DeleteOriginEndpointError> {
+        (**self).delete_origin_endpoint(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> RusotoFuture<DescribeOriginEndpointResponse, DescribeOriginEndpointError> {
+        (**self).describe_origin_endpoint(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> RusotoFuture<ListOriginEndpointsResponse, ListOriginEndpointsError> {
+        (**self).list_origin_endpoints(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> RusotoFuture<RotateChannelCredentialsResponse, RotateChannelCredentialsError> {
+        (**self).rotate_channel_credentials(input)
+    }
+    fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> RusotoFuture<RotateIngestEndpointCredentialsResponse, RotateIngestEndpointCredentialsError>
+    {
+        (**self).rotate_ingest_endpoint_credentials(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError> {
+        (**self).update_origin_endpoint(input)
+    }
+}
+impl<T: MediaPackage + ?Sized> MediaPackage for Box<T> {
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> RusotoFuture<CreateOriginEndpointResponse, CreateOriginEndpointError> {
+        (**self).create_origin_endpoint(input)
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> RusotoFuture<DeleteOriginEndpointResponse, DeleteOriginEndpointError> {
+        (**self).delete_origin_endpoint(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> RusotoFuture<DescribeOriginEndpointResponse, DescribeOriginEndpointError> {
+        (**self).describe_origin_endpoint(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> RusotoFuture<ListOriginEndpointsResponse, ListOriginEndpointsError> {
+        (**self).list_origin_endpoints(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> RusotoFuture<RotateChannelCredentialsResponse, RotateChannelCredentialsError> {
+        (**self).rotate_channel_credentials(input)
+    }
+    fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> RusotoFuture<RotateIngestEndpointCredentialsResponse, RotateIngestEndpointCredentialsError>
+    {
+        (**self).rotate_ingest_endpoint_credentials(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError> {
+        (**self).update_origin_endpoint(input)
+    }
+}
+impl<T: MediaPackage + ?Sized> MediaPackage for ::std::sync::Arc<T> {
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> RusotoFuture<CreateOriginEndpointResponse, CreateOriginEndpointError> {
+        (**self).create_origin_endpoint(input)
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> RusotoFuture<DeleteOriginEndpointResponse, DeleteOriginEndpointError> {
+        (**self).delete_origin_endpoint(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> RusotoFuture<DescribeOriginEndpointResponse, DescribeOriginEndpointError> {
+        (**self).describe_origin_endpoint(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> RusotoFuture<ListOriginEndpointsResponse, ListOriginEndpointsError> {
+        (**self).list_origin_endpoints(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> RusotoFuture<RotateChannelCredentialsResponse, RotateChannelCredentialsError> {
+        (**self).rotate_channel_credentials(input)
+    }
+    fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> RusotoFuture<RotateIngestEndpointCredentialsResponse, RotateIngestEndpointCredentialsError>
+    {
+        (**self).rotate_ingest_endpoint_credentials(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError> {
+        (**self).update_origin_endpoint(input)
+    }
+}
 /// A client for the MediaPackage API.
 #[derive(Clone)]
 pub struct MediaPackageClient {
@@ -2091,6 +2472,8 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2100,6 +2483,7 @@ impl MediaPackage for MediaPackageClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateChannel")
                         .deserialize::<CreateChannelResponse, _>()?;
 
                     Ok(result)
@@ -2123,6 +2507,8 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("CreateOriginEndpoint");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2132,6 +2518,7 @@ impl MediaPackage for MediaPackageClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateOriginEndpoint")
                         .deserialize::<CreateOriginEndpointResponse, _>()?;
 
                     Ok(result)
@@ -2154,12 +2541,15 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
+        request.add_resource_id("Id", &input.id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteChannel")
                         .deserialize::<DeleteChannelResponse, _>()?;
 
                     Ok(result)
@@ -2183,12 +2573,15 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DeleteOriginEndpoint");
+        request.add_resource_id("Id", &input.id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 202 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteOriginEndpoint")
                         .deserialize::<DeleteOriginEndpointResponse, _>()?;
 
                     Ok(result)
@@ -2211,12 +2604,16 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeChannel")
                         .deserialize::<DescribeChannelResponse, _>()?;
 
                     Ok(result)
@@ -2240,12 +2637,16 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("DescribeOriginEndpoint");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeOriginEndpoint")
                         .deserialize::<DescribeOriginEndpointResponse, _>()?;
 
                     Ok(result)
@@ -2265,9 +2666,19 @@ impl MediaPackage for MediaPackageClient {
         &self,
         input: ListChannelsRequest,
     ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListChannels");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2283,6 +2694,7 @@ impl MediaPackage for MediaPackageClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListChannels")
                         .deserialize::<ListChannelsResponse, _>()?;
 
                     Ok(result)
@@ -2303,9 +2715,19 @@ impl MediaPackage for MediaPackageClient {
         &self,
         input: ListOriginEndpointsRequest,
     ) -> RusotoFuture<ListOriginEndpointsResponse, ListOriginEndpointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListOriginEndpoints");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2324,6 +2746,7 @@ impl MediaPackage for MediaPackageClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListOriginEndpoints")
                         .deserialize::<ListOriginEndpointsResponse, _>()?;
 
                     Ok(result)
@@ -2345,12 +2768,16 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -2373,12 +2800,16 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}/credentials", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("RotateChannelCredentials");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RotateChannelCredentials")
                         .deserialize::<RotateChannelCredentialsResponse, _>()?;
 
                     Ok(result)
@@ -2404,12 +2835,17 @@ impl MediaPackage for MediaPackageClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("RotateIngestEndpointCredentials");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.add_resource_id("IngestEndpointId", &input.ingest_endpoint_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RotateIngestEndpointCredentials")
                         .deserialize::<RotateIngestEndpointCredentialsResponse, _>()?;
 
                     Ok(result)
@@ -2428,6 +2864,8 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2455,6 +2893,8 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2489,6 +2929,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2498,6 +2941,7 @@ impl MediaPackage for MediaPackageClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateChannel")
                         .deserialize::<UpdateChannelResponse, _>()?;
 
                     Ok(result)
@@ -2521,6 +2965,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation("UpdateOriginEndpoint");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2530,6 +2977,7 @@ impl MediaPackage for MediaPackageClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateOriginEndpoint")
                         .deserialize::<UpdateOriginEndpointResponse, _>()?;
 
                     Ok(result)
@@ -2544,3 +2992,524 @@ impl MediaPackage for MediaPackageClient {
         })
     }
 }
+
+/// A mock of the MediaPackage API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMediaPackage {
+    create_channel: Option<
+        Box<
+            dyn Fn(
+                    CreateChannelRequest,
+                )
+                    -> ::std::result::Result<CreateChannelResponse, RusotoError<CreateChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_origin_endpoint: Option<
+        Box<
+            dyn Fn(
+                    CreateOriginEndpointRequest,
+                ) -> ::std::result::Result<
+                    CreateOriginEndpointResponse,
+                    RusotoError<CreateOriginEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_channel: Option<
+        Box<
+            dyn Fn(
+                    DeleteChannelRequest,
+                )
+                    -> ::std::result::Result<DeleteChannelResponse, RusotoError<DeleteChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_origin_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DeleteOriginEndpointRequest,
+                ) -> ::std::result::Result<
+                    DeleteOriginEndpointResponse,
+                    RusotoError<DeleteOriginEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_channel: Option<
+        Box<
+            dyn Fn(
+                    DescribeChannelRequest,
+                ) -> ::std::result::Result<
+                    DescribeChannelResponse,
+                    RusotoError<DescribeChannelError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_origin_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DescribeOriginEndpointRequest,
+                ) -> ::std::result::Result<
+                    DescribeOriginEndpointResponse,
+                    RusotoError<DescribeOriginEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_channels: Option<
+        Box<
+            dyn Fn(
+                    ListChannelsRequest,
+                )
+                    -> ::std::result::Result<ListChannelsResponse, RusotoError<ListChannelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_origin_endpoints: Option<
+        Box<
+            dyn Fn(
+                    ListOriginEndpointsRequest,
+                ) -> ::std::result::Result<
+                    ListOriginEndpointsResponse,
+                    RusotoError<ListOriginEndpointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    rotate_channel_credentials: Option<
+        Box<
+            dyn Fn(
+                    RotateChannelCredentialsRequest,
+                ) -> ::std::result::Result<
+                    RotateChannelCredentialsResponse,
+                    RusotoError<RotateChannelCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    rotate_ingest_endpoint_credentials: Option<
+        Box<
+            dyn Fn(
+                    RotateIngestEndpointCredentialsRequest,
+                ) -> ::std::result::Result<
+                    RotateIngestEndpointCredentialsResponse,
+                    RusotoError<RotateIngestEndpointCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_channel: Option<
+        Box<
+            dyn Fn(
+                    UpdateChannelRequest,
+                )
+                    -> ::std::result::Result<UpdateChannelResponse, RusotoError<UpdateChannelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_origin_endpoint: Option<
+        Box<
+            dyn Fn(
+                    UpdateOriginEndpointRequest,
+                ) -> ::std::result::Result<
+                    UpdateOriginEndpointResponse,
+                    RusotoError<UpdateOriginEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMediaPackage {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMediaPackage {
+        Default::default()
+    }
+
+    /// Answers `create_channel` with what `handler` returns for its input.
+    pub fn with_create_channel<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                CreateChannelRequest,
+            )
+                -> ::std::result::Result<CreateChannelResponse, RusotoError<CreateChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_origin_endpoint` with what `handler` returns for its input.
+    pub fn with_create_origin_endpoint<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                CreateOriginEndpointRequest,
+            ) -> ::std::result::Result<
+                CreateOriginEndpointResponse,
+                RusotoError<CreateOriginEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_origin_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_channel` with what `handler` returns for its input.
+    pub fn with_delete_channel<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                DeleteChannelRequest,
+            )
+                -> ::std::result::Result<DeleteChannelResponse, RusotoError<DeleteChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_origin_endpoint` with what `handler` returns for its input.
+    pub fn with_delete_origin_endpoint<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                DeleteOriginEndpointRequest,
+            ) -> ::std::result::Result<
+                DeleteOriginEndpointResponse,
+                RusotoError<DeleteOriginEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_origin_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_channel` with what `handler` returns for its input.
+    pub fn with_describe_channel<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                DescribeChannelRequest,
+            )
+                -> ::std::result::Result<DescribeChannelResponse, RusotoError<DescribeChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_origin_endpoint` with what `handler` returns for its input.
+    pub fn with_describe_origin_endpoint<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                DescribeOriginEndpointRequest,
+            ) -> ::std::result::Result<
+                DescribeOriginEndpointResponse,
+                RusotoError<DescribeOriginEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_origin_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_channels` with what `handler` returns for its input.
+    pub fn with_list_channels<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                ListChannelsRequest,
+            )
+                -> ::std::result::Result<ListChannelsResponse, RusotoError<ListChannelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_channels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_origin_endpoints` with what `handler` returns for its input.
+    pub fn with_list_origin_endpoints<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                ListOriginEndpointsRequest,
+            ) -> ::std::result::Result<
+                ListOriginEndpointsResponse,
+                RusotoError<ListOriginEndpointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_origin_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rotate_channel_credentials` with what `handler` returns for its input.
+    pub fn with_rotate_channel_credentials<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                RotateChannelCredentialsRequest,
+            ) -> ::std::result::Result<
+                RotateChannelCredentialsResponse,
+                RusotoError<RotateChannelCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.rotate_channel_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rotate_ingest_endpoint_credentials` with what `handler` returns for its input.
+    pub fn with_rotate_ingest_endpoint_credentials<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                RotateIngestEndpointCredentialsRequest,
+            ) -> ::std::result::Result<
+                RotateIngestEndpointCredentialsResponse,
+                RusotoError<RotateIngestEndpointCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.rotate_ingest_endpoint_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_channel` with what `handler` returns for its input.
+    pub fn with_update_channel<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                UpdateChannelRequest,
+            )
+                -> ::std::result::Result<UpdateChannelResponse, RusotoError<UpdateChannelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_channel = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_origin_endpoint` with what `handler` returns for its input.
+    pub fn with_update_origin_endpoint<F>(mut self, handler: F) -> MockMediaPackage
+    where
+        F: Fn(
+                UpdateOriginEndpointRequest,
+            ) -> ::std::result::Result<
+                UpdateOriginEndpointResponse,
+                RusotoError<UpdateOriginEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_origin_endpoint = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MediaPackage for MockMediaPackage {
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        match self.create_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for create_channel"),
+        }
+    }
+    fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> RusotoFuture<CreateOriginEndpointResponse, CreateOriginEndpointError> {
+        match self.create_origin_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for create_origin_endpoint"),
+        }
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        match self.delete_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for delete_channel"),
+        }
+    }
+    fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> RusotoFuture<DeleteOriginEndpointResponse, DeleteOriginEndpointError> {
+        match self.delete_origin_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for delete_origin_endpoint"),
+        }
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        match self.describe_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for describe_channel"),
+        }
+    }
+    fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> RusotoFuture<DescribeOriginEndpointResponse, DescribeOriginEndpointError> {
+        match self.describe_origin_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for describe_origin_endpoint"),
+        }
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        match self.list_channels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for list_channels"),
+        }
+    }
+    fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> RusotoFuture<ListOriginEndpointsResponse, ListOriginEndpointsError> {
+        match self.list_origin_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for list_origin_endpoints"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for list_tags_for_resource"),
+        }
+    }
+    fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> RusotoFuture<RotateChannelCredentialsResponse, RotateChannelCredentialsError> {
+        match self.rotate_channel_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for rotate_channel_credentials"),
+        }
+    }
+    fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> RusotoFuture<RotateIngestEndpointCredentialsResponse, RotateIngestEndpointCredentialsError>
+    {
+        match self.rotate_ingest_endpoint_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockMediaPackage has no handler for rotate_ingest_endpoint_credentials")
+            }
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for untag_resource"),
+        }
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        match self.update_channel {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for update_channel"),
+        }
+    }
+    fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError> {
+        match self.update_origin_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaPackage has no handler for update_origin_endpoint"),
+        }
+    }
+}
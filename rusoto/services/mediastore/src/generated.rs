@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>This section describes operations that you can perform on an AWS Elemental MediaStore container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Container {
     /// <p>The Amazon Resource Name (ARN) of the container. The ARN has the following format:</p> <p>arn:aws:&lt;region&gt;:&lt;account that owns this container&gt;:container/&lt;name of container&gt; </p> <p>For example: arn:aws:mediastore:us-west-2:111122223333:container/movies </p>
     #[serde(rename = "ARN")]
@@ -76,7 +77,64 @@ pub struct CorsRule {
     pub max_age_seconds: Option<i64>,
 }
 
+impl Validate for CorsRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "allowed_headers"),
+            self.allowed_headers.len(),
+            None,
+            Some(100),
+        );
+        for (i, item) in self.allowed_headers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "allowed_headers"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+            errors.check_pattern(&item_path, &item, "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+");
+        }
+        if let Some(ref value) = self.allowed_methods {
+            errors.check_length(
+                &field_path(path, "allowed_methods"),
+                value.len(),
+                Some(1),
+                Some(4),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "allowed_origins"),
+            self.allowed_origins.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.allowed_origins.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "allowed_origins"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+            errors.check_pattern(&item_path, &item, "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+");
+        }
+        if let Some(ref value) = self.expose_headers {
+            errors.check_length(
+                &field_path(path, "expose_headers"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "expose_headers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(8192));
+                errors.check_pattern(&item_path, &item, "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+");
+            }
+        }
+        if let Some(ref value) = self.max_age_seconds {
+            errors.check_range(
+                &field_path(path, "max_age_seconds"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateContainerInput {
     /// <p>The name for the container. The name must be from 1 to 255 characters. Container names must be unique to your AWS account within a specific region. As an example, you could create a container named <code>movies</code> in every region, as long as you don’t have an existing container with that name.</p>
     #[serde(rename = "ContainerName")]
@@ -87,8 +145,31 @@ pub struct CreateContainerInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateContainerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(200));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateContainerOutput {
     /// <p>ContainerARN: The Amazon Resource Name (ARN) of the newly created container. The ARN has the following format: arn:aws:&lt;region&gt;:&lt;account that owns this container&gt;:container/&lt;name of container&gt;. For example: arn:aws:mediastore:us-west-2:111122223333:container/movies </p> <p>ContainerName: The container name as specified in the request.</p> <p>CreationTime: Unix time stamp.</p> <p>Status: The status of container creation or deletion. The status is one of the following: <code>CREATING</code>, <code>ACTIVE</code>, or <code>DELETING</code>. While the service is creating the container, the status is <code>CREATING</code>. When an endpoint is available, the status changes to <code>ACTIVE</code>.</p> <p>The return value does not include the container's endpoint. To make downstream requests, you must obtain this value by using <a>DescribeContainer</a> or <a>ListContainers</a>.</p>
     #[serde(rename = "Container")]
@@ -96,50 +177,119 @@ pub struct CreateContainerOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteContainerInput {
     /// <p>The name of the container to delete. </p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for DeleteContainerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteContainerOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteContainerPolicyInput {
     /// <p>The name of the container that holds the policy.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for DeleteContainerPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteContainerPolicyOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCorsPolicyInput {
     /// <p>The name of the container to remove the policy from.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for DeleteCorsPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCorsPolicyOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLifecyclePolicyInput {
     /// <p>The name of the container that holds the object lifecycle policy.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for DeleteLifecyclePolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLifecyclePolicyOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeContainerInput {
     /// <p>The name of the container to query.</p>
     #[serde(rename = "ContainerName")]
@@ -147,8 +297,22 @@ pub struct DescribeContainerInput {
     pub container_name: Option<String>,
 }
 
+impl Validate for DescribeContainerInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.container_name {
+            errors.check_length(
+                &field_path(path, "container_name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "container_name"), &value, "[\\w-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeContainerOutput {
     /// <p>The name of the queried container.</p>
     #[serde(rename = "Container")]
@@ -157,14 +321,31 @@ pub struct DescribeContainerOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetContainerPolicyInput {
     /// <p>The name of the container. </p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for GetContainerPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetContainerPolicyOutput {
     /// <p>The contents of the access policy.</p>
     #[serde(rename = "Policy")]
@@ -172,14 +353,31 @@ pub struct GetContainerPolicyOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCorsPolicyInput {
     /// <p>The name of the container that the policy is assigned to.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for GetCorsPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCorsPolicyOutput {
     /// <p>The CORS policy assigned to the container.</p>
     #[serde(rename = "CorsPolicy")]
@@ -187,14 +385,31 @@ pub struct GetCorsPolicyOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLifecyclePolicyInput {
     /// <p>The name of the container that the object lifecycle policy is assigned to.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for GetLifecyclePolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLifecyclePolicyOutput {
     /// <p>The object lifecycle policy that is assigned to the container.</p>
     #[serde(rename = "LifecyclePolicy")]
@@ -202,6 +417,7 @@ pub struct GetLifecyclePolicyOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListContainersInput {
     /// <p>Enter the maximum number of containers in the response. Use from 1 to 255 characters. </p>
     #[serde(rename = "MaxResults")]
@@ -213,8 +429,25 @@ pub struct ListContainersInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListContainersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[0-9A-Za-z=/+]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListContainersOutput {
     /// <p>The names of the containers.</p>
     #[serde(rename = "Containers")]
@@ -226,14 +459,31 @@ pub struct ListContainersOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the container.</p>
     #[serde(rename = "Resource")]
     pub resource: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource"),
+            self.resource.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource"),
+            &self.resource,
+            "arn:aws:mediastore:[a-z]+-[a-z]+-\\d:\\d{12}:container/[\\w-]{1,255}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>An array of key:value pairs that are assigned to the container.</p>
     #[serde(rename = "Tags")]
@@ -242,6 +492,7 @@ pub struct ListTagsForResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutContainerPolicyInput {
     /// <p>The name of the container.</p>
     #[serde(rename = "ContainerName")]
@@ -251,11 +502,35 @@ pub struct PutContainerPolicyInput {
     pub policy: String,
 }
 
+impl Validate for PutContainerPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+        errors.check_length(
+            &field_path(path, "policy"),
+            self.policy.chars().count(),
+            Some(1),
+            Some(8192),
+        );
+        errors.check_pattern(&field_path(path, "policy"), &self.policy, "[\\x00-\\x7F]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutContainerPolicyOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutCorsPolicyInput {
     /// <p>The name of the container that you want to assign the CORS policy to.</p>
     #[serde(rename = "ContainerName")]
@@ -265,11 +540,38 @@ pub struct PutCorsPolicyInput {
     pub cors_policy: Vec<CorsRule>,
 }
 
+impl Validate for PutCorsPolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+        errors.check_length(
+            &field_path(path, "cors_policy"),
+            self.cors_policy.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.cors_policy.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "cors_policy"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutCorsPolicyOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLifecyclePolicyInput {
     /// <p>The name of the container that you want to assign the object lifecycle policy to.</p>
     #[serde(rename = "ContainerName")]
@@ -279,30 +581,91 @@ pub struct PutLifecyclePolicyInput {
     pub lifecycle_policy: String,
 }
 
+impl Validate for PutLifecyclePolicyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+        errors.check_length(
+            &field_path(path, "lifecycle_policy"),
+            self.lifecycle_policy.chars().count(),
+            None,
+            Some(8192),
+        );
+        errors.check_pattern(
+            &field_path(path, "lifecycle_policy"),
+            &self.lifecycle_policy,
+            "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLifecyclePolicyOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartAccessLoggingInput {
     /// <p>The name of the container that you want to start access logging on.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for StartAccessLoggingInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartAccessLoggingOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopAccessLoggingInput {
     /// <p>The name of the container that you want to stop access logging on.</p>
     #[serde(rename = "ContainerName")]
     pub container_name: String,
 }
 
+impl Validate for StopAccessLoggingInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "container_name"),
+            self.container_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "container_name"),
+            &self.container_name,
+            "[\\w-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopAccessLoggingOutput {}
 
 /// <p>A collection of tags associated with a container. Each tag consists of a key:value pair, which can be anything you define. Typically, the tag key represents a category (such as "environment") and the tag value represents a specific value within that category (such as "test," "development," or "production"). You can add up to 50 tags to each container. For more information about tagging, including naming and usage conventions, see <a href="https://aws.amazon.com/documentation/mediastore/tagging">Tagging Resources in MediaStore</a>.</p>
@@ -318,7 +681,39 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the container. </p>
     #[serde(rename = "Resource")]
@@ -328,11 +723,38 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource"),
+            self.resource.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource"),
+            &self.resource,
+            "arn:aws:mediastore:[a-z]+-[a-z]+-\\d:\\d{12}:container/[\\w-]{1,255}",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(200),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the container.</p>
     #[serde(rename = "Resource")]
@@ -342,8 +764,29 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource"),
+            self.resource.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource"),
+            &self.resource,
+            "arn:aws:mediastore:[a-z]+-[a-z]+-\\d:\\d{12}:container/[\\w-]{1,255}",
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceOutput {}
 
 /// Errors returned by CreateContainer
@@ -370,7 +813,12 @@ impl CreateContainerError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateContainerError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -415,7 +863,12 @@ impl DeleteContainerError {
                 "InternalServerError" => {
                     return RusotoError::Service(DeleteContainerError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -473,7 +926,12 @@ impl DeleteContainerPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -526,7 +984,12 @@ impl DeleteCorsPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -585,7 +1048,12 @@ impl DeleteLifecyclePolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -628,7 +1096,12 @@ impl DescribeContainerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -681,7 +1154,12 @@ impl GetContainerPolicyError {
                 "PolicyNotFoundException" => {
                     return RusotoError::Service(GetContainerPolicyError::PolicyNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -732,7 +1210,12 @@ impl GetCorsPolicyError {
                 "InternalServerError" => {
                     return RusotoError::Service(GetCorsPolicyError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -787,7 +1270,12 @@ impl GetLifecyclePolicyError {
                 "PolicyNotFoundException" => {
                     return RusotoError::Service(GetLifecyclePolicyError::PolicyNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -823,7 +1311,12 @@ impl ListContainersError {
                 "InternalServerError" => {
                     return RusotoError::Service(ListContainersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -870,7 +1363,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -919,7 +1417,12 @@ impl PutContainerPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -964,7 +1467,12 @@ impl PutCorsPolicyError {
                 "InternalServerError" => {
                     return RusotoError::Service(PutCorsPolicyError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1013,7 +1521,12 @@ impl PutLifecyclePolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1062,7 +1575,12 @@ impl StartAccessLoggingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1109,7 +1627,12 @@ impl StopAccessLoggingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1154,7 +1677,12 @@ impl TagResourceError {
                 "InternalServerError" => {
                     return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1199,7 +1727,12 @@ impl UntagResourceError {
                 "InternalServerError" => {
                     return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1330,234 +1863,628 @@ pub trait MediaStore {
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError>;
 }
-/// A client for the MediaStore API.
-#[derive(Clone)]
-pub struct MediaStoreClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MediaStoreClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MediaStoreClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MediaStoreClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreClient {
-        MediaStoreClient { client, region }
-    }
-}
-
-impl MediaStore for MediaStoreClient {
-    /// <p>Creates a storage container to hold objects. A container is similar to a bucket in the Amazon S3 service.</p>
+const _: Option<&dyn MediaStore> = None;
+impl<'a, T: MediaStore + ?Sized> MediaStore for &'a T {
     fn create_container(
         &self,
         input: CreateContainerInput,
     ) -> RusotoFuture<CreateContainerOutput, CreateContainerError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.CreateContainer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateContainerOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateContainerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_container(input)
     }
-
-    /// <p>Deletes the specified container. Before you make a <code>DeleteContainer</code> request, delete any objects in the container or in any folders in the container. You can delete only empty containers. </p>
     fn delete_container(
         &self,
         input: DeleteContainerInput,
     ) -> RusotoFuture<DeleteContainerOutput, DeleteContainerError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteContainerOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteContainerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_container(input)
     }
-
-    /// <p>Deletes the access policy that is associated with the specified container.</p>
     fn delete_container_policy(
         &self,
         input: DeleteContainerPolicyInput,
     ) -> RusotoFuture<DeleteContainerPolicyOutput, DeleteContainerPolicyError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainerPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteContainerPolicyOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteContainerPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_container_policy(input)
     }
-
-    /// <p>Deletes the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:DeleteCorsPolicy</code> action. The container owner has this permission by default and can grant this permission to others.</p>
     fn delete_cors_policy(
         &self,
         input: DeleteCorsPolicyInput,
     ) -> RusotoFuture<DeleteCorsPolicyOutput, DeleteCorsPolicyError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.DeleteCorsPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCorsPolicyOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteCorsPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_cors_policy(input)
     }
-
-    /// <p>Removes an object lifecycle policy from a container. It takes up to 20 minutes for the change to take effect.</p>
     fn delete_lifecycle_policy(
         &self,
         input: DeleteLifecyclePolicyInput,
     ) -> RusotoFuture<DeleteLifecyclePolicyOutput, DeleteLifecyclePolicyError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.DeleteLifecyclePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLifecyclePolicyOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteLifecyclePolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_lifecycle_policy(input)
     }
-
-    /// <p>Retrieves the properties of the requested container. This request is commonly used to retrieve the endpoint of a container. An endpoint is a value assigned by the service when a new container is created. A container's endpoint does not change after it has been assigned. The <code>DescribeContainer</code> request returns a single <code>Container</code> object based on <code>ContainerName</code>. To return all <code>Container</code> objects that are associated with a specified AWS account, use <a>ListContainers</a>.</p>
     fn describe_container(
         &self,
         input: DescribeContainerInput,
     ) -> RusotoFuture<DescribeContainerOutput, DescribeContainerError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.DescribeContainer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeContainerOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeContainerError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_container(input)
     }
-
-    /// <p>Retrieves the access policy for the specified container. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p>
     fn get_container_policy(
         &self,
         input: GetContainerPolicyInput,
     ) -> RusotoFuture<GetContainerPolicyOutput, GetContainerPolicyError> {
-        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MediaStore_20170901.GetContainerPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
+        (**self).get_container_policy(input)
+    }
+    fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> RusotoFuture<GetCorsPolicyOutput, GetCorsPolicyError> {
+        (**self).get_cors_policy(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> RusotoFuture<GetLifecyclePolicyOutput, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> RusotoFuture<ListContainersOutput, ListContainersError> {
+        (**self).list_containers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> RusotoFuture<PutContainerPolicyOutput, PutContainerPolicyError> {
+        (**self).put_container_policy(input)
+    }
+    fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> RusotoFuture<PutCorsPolicyOutput, PutCorsPolicyError> {
+        (**self).put_cors_policy(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> RusotoFuture<PutLifecyclePolicyOutput, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> RusotoFuture<StartAccessLoggingOutput, StartAccessLoggingError> {
+        (**self).start_access_logging(input)
+    }
+    fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> RusotoFuture<StopAccessLoggingOutput, StopAccessLoggingError> {
+        (**self).stop_access_logging(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: MediaStore + ?Sized> MediaStore for Box<T> {
+    fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> RusotoFuture<CreateContainerOutput, CreateContainerError> {
+        (**self).create_container(input)
+    }
+    fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> RusotoFuture<DeleteContainerOutput, DeleteContainerError> {
+        (**self).delete_container(input)
+    }
+    fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> RusotoFuture<DeleteContainerPolicyOutput, DeleteContainerPolicyError> {
+        (**self).delete_container_policy(input)
+    }
+    fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> RusotoFuture<DeleteCorsPolicyOutput, DeleteCorsPolicyError> {
+        (**self).delete_cors_policy(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> RusotoFuture<DeleteLifecyclePolicyOutput, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> RusotoFuture<DescribeContainerOutput, DescribeContainerError> {
+        (**self).describe_container(input)
+    }
+    fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> RusotoFuture<GetContainerPolicyOutput, GetContainerPolicyError> {
+        (**self).get_container_policy(input)
+    }
+    fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> RusotoFuture<GetCorsPolicyOutput, GetCorsPolicyError> {
+        (**self).get_cors_policy(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> RusotoFuture<GetLifecyclePolicyOutput, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> RusotoFuture<ListContainersOutput, ListContainersError> {
+        (**self).list_containers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> RusotoFuture<PutContainerPolicyOutput, PutContainerPolicyError> {
+        (**self).put_container_policy(input)
+    }
+    fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> RusotoFuture<PutCorsPolicyOutput, PutCorsPolicyError> {
+        (**self).put_cors_policy(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> RusotoFuture<PutLifecyclePolicyOutput, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> RusotoFuture<StartAccessLoggingOutput, StartAccessLoggingError> {
+        (**self).start_access_logging(input)
+    }
+    fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> RusotoFuture<StopAccessLoggingOutput, StopAccessLoggingError> {
+        (**self).stop_access_logging(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: MediaStore + ?Sized> MediaStore for ::std::sync::Arc<T> {
+    fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> RusotoFuture<CreateContainerOutput, CreateContainerError> {
+        (**self).create_container(input)
+    }
+    fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> RusotoFuture<DeleteContainerOutput, DeleteContainerError> {
+        (**self).delete_container(input)
+    }
+    fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> RusotoFuture<DeleteContainerPolicyOutput, DeleteContainerPolicyError> {
+        (**self).delete_container_policy(input)
+    }
+    fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> RusotoFuture<DeleteCorsPolicyOutput, DeleteCorsPolicyError> {
+        (**self).delete_cors_policy(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> RusotoFuture<DeleteLifecyclePolicyOutput, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> RusotoFuture<DescribeContainerOutput, DescribeContainerError> {
+        (**self).describe_container(input)
+    }
+    fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> RusotoFuture<GetContainerPolicyOutput, GetContainerPolicyError> {
+        (**self).get_container_policy(input)
+    }
+    fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> RusotoFuture<GetCorsPolicyOutput, GetCorsPolicyError> {
+        (**self).get_cors_policy(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> RusotoFuture<GetLifecyclePolicyOutput, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> RusotoFuture<ListContainersOutput, ListContainersError> {
+        (**self).list_containers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> RusotoFuture<PutContainerPolicyOutput, PutContainerPolicyError> {
+        (**self).put_container_policy(input)
+    }
+    fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> RusotoFuture<PutCorsPolicyOutput, PutCorsPolicyError> {
+        (**self).put_cors_policy(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> RusotoFuture<PutLifecyclePolicyOutput, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> RusotoFuture<StartAccessLoggingOutput, StartAccessLoggingError> {
+        (**self).start_access_logging(input)
+    }
+    fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> RusotoFuture<StopAccessLoggingOutput, StopAccessLoggingError> {
+        (**self).stop_access_logging(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the MediaStore API.
+#[derive(Clone)]
+pub struct MediaStoreClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MediaStoreClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaStoreClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaStoreClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreClient {
+        MediaStoreClient { client, region }
+    }
+}
+
+impl MediaStore for MediaStoreClient {
+    /// <p>Creates a storage container to hold objects. A container is similar to a bucket in the Amazon S3 service.</p>
+    fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> RusotoFuture<CreateContainerOutput, CreateContainerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("CreateContainer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.CreateContainer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateContainerOutput, _>(
+                    response,
+                    "CreateContainer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateContainerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified container. Before you make a <code>DeleteContainer</code> request, delete any objects in the container or in any folders in the container. You can delete only empty containers. </p>
+    fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> RusotoFuture<DeleteContainerOutput, DeleteContainerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteContainer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteContainerOutput, _>(
+                    response,
+                    "DeleteContainer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteContainerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the access policy that is associated with the specified container.</p>
+    fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> RusotoFuture<DeleteContainerPolicyOutput, DeleteContainerPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteContainerPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainerPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteContainerPolicyOutput, _>(
+                    response,
+                    "DeleteContainerPolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteContainerPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:DeleteCorsPolicy</code> action. The container owner has this permission by default and can grant this permission to others.</p>
+    fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> RusotoFuture<DeleteCorsPolicyOutput, DeleteCorsPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteCorsPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.DeleteCorsPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteCorsPolicyOutput, _>(
+                    response,
+                    "DeleteCorsPolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteCorsPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes an object lifecycle policy from a container. It takes up to 20 minutes for the change to take effect.</p>
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> RusotoFuture<DeleteLifecyclePolicyOutput, DeleteLifecyclePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DeleteLifecyclePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.DeleteLifecyclePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteLifecyclePolicyOutput, _>(
+                    response,
+                    "DeleteLifecyclePolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteLifecyclePolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the properties of the requested container. This request is commonly used to retrieve the endpoint of a container. An endpoint is a value assigned by the service when a new container is created. A container's endpoint does not change after it has been assigned. The <code>DescribeContainer</code> request returns a single <code>Container</code> object based on <code>ContainerName</code>. To return all <code>Container</code> objects that are associated with a specified AWS account, use <a>ListContainers</a>.</p>
+    fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> RusotoFuture<DescribeContainerOutput, DescribeContainerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("DescribeContainer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.DescribeContainer");
+        let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetContainerPolicyOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeContainerOutput, _>(
+                    response,
+                    "DescribeContainer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeContainerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the access policy for the specified container. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p>
+    fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> RusotoFuture<GetContainerPolicyOutput, GetContainerPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetContainerPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MediaStore_20170901.GetContainerPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetContainerPolicyOutput, _>(
+                    response,
+                    "GetContainerPolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1574,7 +2501,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: GetCorsPolicyInput,
     ) -> RusotoFuture<GetCorsPolicyOutput, GetCorsPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetCorsPolicy");
@@ -1583,10 +2519,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCorsPolicyOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetCorsPolicyOutput, _>(
+                    response,
+                    "GetCorsPolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1603,7 +2539,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: GetLifecyclePolicyInput,
     ) -> RusotoFuture<GetLifecyclePolicyOutput, GetLifecyclePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("GetLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.GetLifecyclePolicy");
@@ -1612,10 +2557,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLifecyclePolicyOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetLifecyclePolicyOutput, _>(
+                    response,
+                    "GetLifecyclePolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1632,7 +2577,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: ListContainersInput,
     ) -> RusotoFuture<ListContainersOutput, ListContainersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("ListContainers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.ListContainers");
@@ -1641,10 +2595,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListContainersOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListContainersOutput, _>(
+                    response,
+                    "ListContainers",
+                )
             } else {
                 Box::new(
                     response
@@ -1661,7 +2615,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.ListTagsForResource");
@@ -1670,10 +2633,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1689,7 +2652,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: PutContainerPolicyInput,
     ) -> RusotoFuture<PutContainerPolicyOutput, PutContainerPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutContainerPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutContainerPolicy");
@@ -1698,10 +2670,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutContainerPolicyOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PutContainerPolicyOutput, _>(
+                    response,
+                    "PutContainerPolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1718,7 +2690,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: PutCorsPolicyInput,
     ) -> RusotoFuture<PutCorsPolicyOutput, PutCorsPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutCorsPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutCorsPolicy");
@@ -1727,10 +2708,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutCorsPolicyOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PutCorsPolicyOutput, _>(
+                    response,
+                    "PutCorsPolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1747,7 +2728,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: PutLifecyclePolicyInput,
     ) -> RusotoFuture<PutLifecyclePolicyOutput, PutLifecyclePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("PutLifecyclePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.PutLifecyclePolicy");
@@ -1756,10 +2746,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutLifecyclePolicyOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<PutLifecyclePolicyOutput, _>(
+                    response,
+                    "PutLifecyclePolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -1776,7 +2766,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: StartAccessLoggingInput,
     ) -> RusotoFuture<StartAccessLoggingOutput, StartAccessLoggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("StartAccessLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.StartAccessLogging");
@@ -1785,10 +2784,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartAccessLoggingOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StartAccessLoggingOutput, _>(
+                    response,
+                    "StartAccessLogging",
+                )
             } else {
                 Box::new(
                     response
@@ -1805,7 +2804,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: StopAccessLoggingInput,
     ) -> RusotoFuture<StopAccessLoggingOutput, StopAccessLoggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("StopAccessLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.StopAccessLogging");
@@ -1814,10 +2822,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopAccessLoggingOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StopAccessLoggingOutput, _>(
+                    response,
+                    "StopAccessLogging",
+                )
             } else {
                 Box::new(
                     response
@@ -1834,7 +2842,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.TagResource");
@@ -1843,10 +2860,7 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceOutput, _>(response, "TagResource")
             } else {
                 Box::new(
                     response
@@ -1863,7 +2877,16 @@ impl MediaStore for MediaStoreClient {
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mediastore", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "MediaStore_20170901.UntagResource");
@@ -1872,10 +2895,10 @@ impl MediaStore for MediaStoreClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceOutput, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -1887,3 +2910,642 @@ impl MediaStore for MediaStoreClient {
         })
     }
 }
+
+/// A mock of the MediaStore API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMediaStore {
+    create_container: Option<
+        Box<
+            dyn Fn(
+                    CreateContainerInput,
+                ) -> ::std::result::Result<
+                    CreateContainerOutput,
+                    RusotoError<CreateContainerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_container: Option<
+        Box<
+            dyn Fn(
+                    DeleteContainerInput,
+                ) -> ::std::result::Result<
+                    DeleteContainerOutput,
+                    RusotoError<DeleteContainerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_container_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteContainerPolicyInput,
+                ) -> ::std::result::Result<
+                    DeleteContainerPolicyOutput,
+                    RusotoError<DeleteContainerPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cors_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteCorsPolicyInput,
+                ) -> ::std::result::Result<
+                    DeleteCorsPolicyOutput,
+                    RusotoError<DeleteCorsPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_lifecycle_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteLifecyclePolicyInput,
+                ) -> ::std::result::Result<
+                    DeleteLifecyclePolicyOutput,
+                    RusotoError<DeleteLifecyclePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_container: Option<
+        Box<
+            dyn Fn(
+                    DescribeContainerInput,
+                ) -> ::std::result::Result<
+                    DescribeContainerOutput,
+                    RusotoError<DescribeContainerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_container_policy: Option<
+        Box<
+            dyn Fn(
+                    GetContainerPolicyInput,
+                ) -> ::std::result::Result<
+                    GetContainerPolicyOutput,
+                    RusotoError<GetContainerPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_cors_policy: Option<
+        Box<
+            dyn Fn(
+                    GetCorsPolicyInput,
+                )
+                    -> ::std::result::Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_lifecycle_policy: Option<
+        Box<
+            dyn Fn(
+                    GetLifecyclePolicyInput,
+                ) -> ::std::result::Result<
+                    GetLifecyclePolicyOutput,
+                    RusotoError<GetLifecyclePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_containers: Option<
+        Box<
+            dyn Fn(
+                    ListContainersInput,
+                )
+                    -> ::std::result::Result<ListContainersOutput, RusotoError<ListContainersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_container_policy: Option<
+        Box<
+            dyn Fn(
+                    PutContainerPolicyInput,
+                ) -> ::std::result::Result<
+                    PutContainerPolicyOutput,
+                    RusotoError<PutContainerPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_cors_policy: Option<
+        Box<
+            dyn Fn(
+                    PutCorsPolicyInput,
+                )
+                    -> ::std::result::Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_lifecycle_policy: Option<
+        Box<
+            dyn Fn(
+                    PutLifecyclePolicyInput,
+                ) -> ::std::result::Result<
+                    PutLifecyclePolicyOutput,
+                    RusotoError<PutLifecyclePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_access_logging: Option<
+        Box<
+            dyn Fn(
+                    StartAccessLoggingInput,
+                ) -> ::std::result::Result<
+                    StartAccessLoggingOutput,
+                    RusotoError<StartAccessLoggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_access_logging: Option<
+        Box<
+            dyn Fn(
+                    StopAccessLoggingInput,
+                ) -> ::std::result::Result<
+                    StopAccessLoggingOutput,
+                    RusotoError<StopAccessLoggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMediaStore {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMediaStore {
+        Default::default()
+    }
+
+    /// Answers `create_container` with what `handler` returns for its input.
+    pub fn with_create_container<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                CreateContainerInput,
+            )
+                -> ::std::result::Result<CreateContainerOutput, RusotoError<CreateContainerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_container = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_container` with what `handler` returns for its input.
+    pub fn with_delete_container<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                DeleteContainerInput,
+            )
+                -> ::std::result::Result<DeleteContainerOutput, RusotoError<DeleteContainerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_container = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_container_policy` with what `handler` returns for its input.
+    pub fn with_delete_container_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                DeleteContainerPolicyInput,
+            ) -> ::std::result::Result<
+                DeleteContainerPolicyOutput,
+                RusotoError<DeleteContainerPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_container_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cors_policy` with what `handler` returns for its input.
+    pub fn with_delete_cors_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                DeleteCorsPolicyInput,
+            )
+                -> ::std::result::Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cors_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_lifecycle_policy` with what `handler` returns for its input.
+    pub fn with_delete_lifecycle_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                DeleteLifecyclePolicyInput,
+            ) -> ::std::result::Result<
+                DeleteLifecyclePolicyOutput,
+                RusotoError<DeleteLifecyclePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_lifecycle_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_container` with what `handler` returns for its input.
+    pub fn with_describe_container<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                DescribeContainerInput,
+            ) -> ::std::result::Result<
+                DescribeContainerOutput,
+                RusotoError<DescribeContainerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_container = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_container_policy` with what `handler` returns for its input.
+    pub fn with_get_container_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                GetContainerPolicyInput,
+            ) -> ::std::result::Result<
+                GetContainerPolicyOutput,
+                RusotoError<GetContainerPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_container_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cors_policy` with what `handler` returns for its input.
+    pub fn with_get_cors_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                GetCorsPolicyInput,
+            )
+                -> ::std::result::Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cors_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_lifecycle_policy` with what `handler` returns for its input.
+    pub fn with_get_lifecycle_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                GetLifecyclePolicyInput,
+            ) -> ::std::result::Result<
+                GetLifecyclePolicyOutput,
+                RusotoError<GetLifecyclePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_lifecycle_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_containers` with what `handler` returns for its input.
+    pub fn with_list_containers<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                ListContainersInput,
+            )
+                -> ::std::result::Result<ListContainersOutput, RusotoError<ListContainersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_containers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_container_policy` with what `handler` returns for its input.
+    pub fn with_put_container_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                PutContainerPolicyInput,
+            ) -> ::std::result::Result<
+                PutContainerPolicyOutput,
+                RusotoError<PutContainerPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_container_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_cors_policy` with what `handler` returns for its input.
+    pub fn with_put_cors_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                PutCorsPolicyInput,
+            )
+                -> ::std::result::Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_cors_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_lifecycle_policy` with what `handler` returns for its input.
+    pub fn with_put_lifecycle_policy<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                PutLifecyclePolicyInput,
+            ) -> ::std::result::Result<
+                PutLifecyclePolicyOutput,
+                RusotoError<PutLifecyclePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_lifecycle_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_access_logging` with what `handler` returns for its input.
+    pub fn with_start_access_logging<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                StartAccessLoggingInput,
+            ) -> ::std::result::Result<
+                StartAccessLoggingOutput,
+                RusotoError<StartAccessLoggingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_access_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_access_logging` with what `handler` returns for its input.
+    pub fn with_stop_access_logging<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                StopAccessLoggingInput,
+            ) -> ::std::result::Result<
+                StopAccessLoggingOutput,
+                RusotoError<StopAccessLoggingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_access_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockMediaStore
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MediaStore for MockMediaStore {
+    fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> RusotoFuture<CreateContainerOutput, CreateContainerError> {
+        match self.create_container {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for create_container"),
+        }
+    }
+    fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> RusotoFuture<DeleteContainerOutput, DeleteContainerError> {
+        match self.delete_container {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for delete_container"),
+        }
+    }
+    fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> RusotoFuture<DeleteContainerPolicyOutput, DeleteContainerPolicyError> {
+        match self.delete_container_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for delete_container_policy"),
+        }
+    }
+    fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> RusotoFuture<DeleteCorsPolicyOutput, DeleteCorsPolicyError> {
+        match self.delete_cors_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for delete_cors_policy"),
+        }
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> RusotoFuture<DeleteLifecyclePolicyOutput, DeleteLifecyclePolicyError> {
+        match self.delete_lifecycle_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for delete_lifecycle_policy"),
+        }
+    }
+    fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> RusotoFuture<DescribeContainerOutput, DescribeContainerError> {
+        match self.describe_container {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for describe_container"),
+        }
+    }
+    fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> RusotoFuture<GetContainerPolicyOutput, GetContainerPolicyError> {
+        match self.get_container_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for get_container_policy"),
+        }
+    }
+    fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> RusotoFuture<GetCorsPolicyOutput, GetCorsPolicyError> {
+        match self.get_cors_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for get_cors_policy"),
+        }
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> RusotoFuture<GetLifecyclePolicyOutput, GetLifecyclePolicyError> {
+        match self.get_lifecycle_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for get_lifecycle_policy"),
+        }
+    }
+    fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> RusotoFuture<ListContainersOutput, ListContainersError> {
+        match self.list_containers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for list_containers"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for list_tags_for_resource"),
+        }
+    }
+    fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> RusotoFuture<PutContainerPolicyOutput, PutContainerPolicyError> {
+        match self.put_container_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for put_container_policy"),
+        }
+    }
+    fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> RusotoFuture<PutCorsPolicyOutput, PutCorsPolicyError> {
+        match self.put_cors_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for put_cors_policy"),
+        }
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> RusotoFuture<PutLifecyclePolicyOutput, PutLifecyclePolicyError> {
+        match self.put_lifecycle_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for put_lifecycle_policy"),
+        }
+    }
+    fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> RusotoFuture<StartAccessLoggingOutput, StartAccessLoggingError> {
+        match self.start_access_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for start_access_logging"),
+        }
+    }
+    fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> RusotoFuture<StopAccessLoggingOutput, StopAccessLoggingError> {
+        match self.stop_access_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for stop_access_logging"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMediaStore has no handler for untag_resource"),
+        }
+    }
+}
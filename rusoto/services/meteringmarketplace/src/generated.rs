@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A BatchMeterUsageRequest contains UsageRecords, which indicate quantities of usage within your application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchMeterUsageRequest {
     /// <p>Product code is used to uniquely identify a product in AWS Marketplace. The product code should be the same as the one used during the publishing of a new product.</p>
     #[serde(rename = "ProductCode")]
@@ -34,9 +36,35 @@ pub struct BatchMeterUsageRequest {
     pub usage_records: Vec<UsageRecord>,
 }
 
+impl Validate for BatchMeterUsageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "product_code"),
+            self.product_code.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_code"),
+            &self.product_code,
+            "^[-a-zA-Z0-9/=:_.@]*$",
+        );
+        errors.check_length(
+            &field_path(path, "usage_records"),
+            self.usage_records.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.usage_records.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "usage_records"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Contains the UsageRecords processed by BatchMeterUsage and any records that have failed due to transient error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchMeterUsageResult {
     /// <p>Contains all UsageRecords processed by BatchMeterUsage. These records were either honored by AWS Marketplace Metering Service or were invalid.</p>
     #[serde(rename = "Results")]
@@ -49,6 +77,7 @@ pub struct BatchMeterUsageResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MeterUsageRequest {
     /// <p>Checks whether you have the permissions required for the action, but does not make the request. If you have the permissions, the request returns DryRunOperation; otherwise, it returns UnauthorizedException. Defaults to <code>false</code> if not specified.</p>
     #[serde(rename = "DryRun")]
@@ -69,8 +98,43 @@ pub struct MeterUsageRequest {
     pub usage_quantity: Option<i64>,
 }
 
+impl Validate for MeterUsageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "product_code"),
+            self.product_code.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_code"),
+            &self.product_code,
+            "^[-a-zA-Z0-9/=:_.@]*$",
+        );
+        errors.check_length(
+            &field_path(path, "usage_dimension"),
+            self.usage_dimension.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "usage_dimension"),
+            &self.usage_dimension,
+            "[\\s\\S]+",
+        );
+        if let Some(ref value) = self.usage_quantity {
+            errors.check_range(
+                &field_path(path, "usage_quantity"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MeterUsageResult {
     /// <p>Metering record id.</p>
     #[serde(rename = "MeteringRecordId")]
@@ -79,6 +143,7 @@ pub struct MeterUsageResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterUsageRequest {
     /// <p>(Optional) To scope down the registration to a specific running software instance and guard against replay attacks.</p>
     #[serde(rename = "Nonce")]
@@ -92,8 +157,39 @@ pub struct RegisterUsageRequest {
     pub public_key_version: i64,
 }
 
+impl Validate for RegisterUsageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.nonce {
+            errors.check_length(
+                &field_path(path, "nonce"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "nonce"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "product_code"),
+            self.product_code.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_code"),
+            &self.product_code,
+            "^[-a-zA-Z0-9/=:_.@]*$",
+        );
+        errors.check_range(
+            &field_path(path, "public_key_version"),
+            self.public_key_version,
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterUsageResult {
     /// <p>(Optional) Only included when public key version has expired</p>
     #[serde(rename = "PublicKeyRotationTimestamp")]
@@ -107,15 +203,26 @@ pub struct RegisterUsageResult {
 
 /// <p>Contains input to the ResolveCustomer operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResolveCustomerRequest {
     /// <p>When a buyer visits your website during the registration process, the buyer submits a registration token through the browser. The registration token is resolved to obtain a CustomerIdentifier and product code.</p>
     #[serde(rename = "RegistrationToken")]
     pub registration_token: String,
 }
 
+impl Validate for ResolveCustomerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "registration_token"),
+            &self.registration_token,
+            "[\\s\\S]+",
+        );
+    }
+}
+
 /// <p>The result of the ResolveCustomer operation. Contains the CustomerIdentifier and product code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResolveCustomerResult {
     /// <p>The CustomerIdentifier is used to identify an individual customer in your application. Calls to BatchMeterUsage require CustomerIdentifiers for each UsageRecord.</p>
     #[serde(rename = "CustomerIdentifier")]
@@ -145,9 +252,40 @@ pub struct UsageRecord {
     pub timestamp: f64,
 }
 
+impl Validate for UsageRecord {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "customer_identifier"),
+            self.customer_identifier.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "customer_identifier"),
+            &self.customer_identifier,
+            "[\\s\\S]+",
+        );
+        errors.check_length(
+            &field_path(path, "dimension"),
+            self.dimension.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "dimension"), &self.dimension, "[\\s\\S]+");
+        if let Some(ref value) = self.quantity {
+            errors.check_range(
+                &field_path(path, "quantity"),
+                *value,
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// <p>A UsageRecordResult indicates the status of a given UsageRecord processed by BatchMeterUsage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsageRecordResult {
     /// <p>The MeteringRecordId is a unique identifier for this metering event.</p>
     #[serde(rename = "MeteringRecordId")]
@@ -215,7 +353,12 @@ impl BatchMeterUsageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -284,7 +427,12 @@ impl MeterUsageError {
                 "TimestampOutOfBoundsException" => {
                     return RusotoError::Service(MeterUsageError::TimestampOutOfBounds(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -360,7 +508,12 @@ impl RegisterUsageError {
                 "ThrottlingException" => {
                     return RusotoError::Service(RegisterUsageError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -422,7 +575,12 @@ impl ResolveCustomerError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ResolveCustomerError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -471,6 +629,85 @@ pub trait MarketplaceMetering {
         input: ResolveCustomerRequest,
     ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError>;
 }
+const _: Option<&dyn MarketplaceMetering> = None;
+impl<'a, T: MarketplaceMetering + ?Sized> MarketplaceMetering for &'a T {
+    fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> RusotoFuture<BatchMeterUsageResult, BatchMeterUsageError> {
+        (**self).batch_meter_usage(input)
+    }
+    fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> RusotoFuture<MeterUsageResult, MeterUsageError> {
+        (**self).meter_usage(input)
+    }
+    fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> RusotoFuture<RegisterUsageResult, RegisterUsageError> {
+        (**self).register_usage(input)
+    }
+    fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError> {
+        (**self).resolve_customer(input)
+    }
+}
+impl<T: MarketplaceMetering + ?Sized> MarketplaceMetering for Box<T> {
+    fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> RusotoFuture<BatchMeterUsageResult, BatchMeterUsageError> {
+        (**self).batch_meter_usage(input)
+    }
+    fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> RusotoFuture<MeterUsageResult, MeterUsageError> {
+        (**self).meter_usage(input)
+    }
+    fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> RusotoFuture<RegisterUsageResult, RegisterUsageError> {
+        (**self).register_usage(input)
+    }
+    fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError> {
+        (**self).resolve_customer(input)
+    }
+}
+impl<T: MarketplaceMetering + ?Sized> MarketplaceMetering for ::std::sync::Arc<T> {
+    fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> RusotoFuture<BatchMeterUsageResult, BatchMeterUsageError> {
+        (**self).batch_meter_usage(input)
+    }
+    fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> RusotoFuture<MeterUsageResult, MeterUsageError> {
+        (**self).meter_usage(input)
+    }
+    fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> RusotoFuture<RegisterUsageResult, RegisterUsageError> {
+        (**self).register_usage(input)
+    }
+    fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError> {
+        (**self).resolve_customer(input)
+    }
+}
 /// A client for the AWSMarketplace Metering API.
 #[derive(Clone)]
 pub struct MarketplaceMeteringClient {
@@ -514,7 +751,16 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         &self,
         input: BatchMeterUsageRequest,
     ) -> RusotoFuture<BatchMeterUsageResult, BatchMeterUsageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("BatchMeterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.BatchMeterUsage");
@@ -523,10 +769,10 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchMeterUsageResult, _>()
-                }))
+                proto::json::deserialize_streaming::<BatchMeterUsageResult, _>(
+                    response,
+                    "BatchMeterUsage",
+                )
             } else {
                 Box::new(
                     response
@@ -543,7 +789,16 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         &self,
         input: MeterUsageRequest,
     ) -> RusotoFuture<MeterUsageResult, MeterUsageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("MeterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.MeterUsage");
@@ -552,10 +807,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<MeterUsageResult, _>()
-                }))
+                proto::json::deserialize_streaming::<MeterUsageResult, _>(response, "MeterUsage")
             } else {
                 Box::new(
                     response
@@ -572,7 +824,16 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         &self,
         input: RegisterUsageRequest,
     ) -> RusotoFuture<RegisterUsageResult, RegisterUsageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("RegisterUsage");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.RegisterUsage");
@@ -581,10 +842,10 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterUsageResult, _>()
-                }))
+                proto::json::deserialize_streaming::<RegisterUsageResult, _>(
+                    response,
+                    "RegisterUsage",
+                )
             } else {
                 Box::new(
                     response
@@ -601,7 +862,16 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         &self,
         input: ResolveCustomerRequest,
     ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, "/");
+        request.set_operation("ResolveCustomer");
         request.set_endpoint_prefix("metering.marketplace".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMPMeteringService.ResolveCustomer");
@@ -610,10 +880,10 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResolveCustomerResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ResolveCustomerResult, _>(
+                    response,
+                    "ResolveCustomer",
+                )
             } else {
                 Box::new(
                     response
@@ -625,3 +895,158 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         })
     }
 }
+
+/// A mock of the AWSMarketplace Metering API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMarketplaceMetering {
+    batch_meter_usage: Option<
+        Box<
+            dyn Fn(
+                    BatchMeterUsageRequest,
+                ) -> ::std::result::Result<
+                    BatchMeterUsageResult,
+                    RusotoError<BatchMeterUsageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    meter_usage: Option<
+        Box<
+            dyn Fn(
+                    MeterUsageRequest,
+                )
+                    -> ::std::result::Result<MeterUsageResult, RusotoError<MeterUsageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_usage: Option<
+        Box<
+            dyn Fn(
+                    RegisterUsageRequest,
+                )
+                    -> ::std::result::Result<RegisterUsageResult, RusotoError<RegisterUsageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    resolve_customer: Option<
+        Box<
+            dyn Fn(
+                    ResolveCustomerRequest,
+                ) -> ::std::result::Result<
+                    ResolveCustomerResult,
+                    RusotoError<ResolveCustomerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMarketplaceMetering {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMarketplaceMetering {
+        Default::default()
+    }
+
+    /// Answers `batch_meter_usage` with what `handler` returns for its input.
+    pub fn with_batch_meter_usage<F>(mut self, handler: F) -> MockMarketplaceMetering
+    where
+        F: Fn(
+                BatchMeterUsageRequest,
+            )
+                -> ::std::result::Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_meter_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `meter_usage` with what `handler` returns for its input.
+    pub fn with_meter_usage<F>(mut self, handler: F) -> MockMarketplaceMetering
+    where
+        F: Fn(
+                MeterUsageRequest,
+            ) -> ::std::result::Result<MeterUsageResult, RusotoError<MeterUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.meter_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_usage` with what `handler` returns for its input.
+    pub fn with_register_usage<F>(mut self, handler: F) -> MockMarketplaceMetering
+    where
+        F: Fn(
+                RegisterUsageRequest,
+            )
+                -> ::std::result::Result<RegisterUsageResult, RusotoError<RegisterUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resolve_customer` with what `handler` returns for its input.
+    pub fn with_resolve_customer<F>(mut self, handler: F) -> MockMarketplaceMetering
+    where
+        F: Fn(
+                ResolveCustomerRequest,
+            )
+                -> ::std::result::Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolve_customer = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MarketplaceMetering for MockMarketplaceMetering {
+    fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> RusotoFuture<BatchMeterUsageResult, BatchMeterUsageError> {
+        match self.batch_meter_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMarketplaceMetering has no handler for batch_meter_usage"),
+        }
+    }
+    fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> RusotoFuture<MeterUsageResult, MeterUsageError> {
+        match self.meter_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMarketplaceMetering has no handler for meter_usage"),
+        }
+    }
+    fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> RusotoFuture<RegisterUsageResult, RegisterUsageError> {
+        match self.register_usage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMarketplaceMetering has no handler for register_usage"),
+        }
+    }
+    fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError> {
+        match self.resolve_customer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMarketplaceMetering has no handler for resolve_customer"),
+        }
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateCreatedArtifactRequest {
     /// <p>An ARN of the AWS resource related to the migration (e.g., AMI, EC2 instance, RDS instance, etc.) </p>
     #[serde(rename = "CreatedArtifact")]
@@ -40,11 +42,41 @@ pub struct AssociateCreatedArtifactRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for AssociateCreatedArtifactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.created_artifact
+            .validate_at(&field_path(path, "created_artifact"), errors);
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateCreatedArtifactResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDiscoveredResourceRequest {
     /// <p>Object representing a Resource.</p>
     #[serde(rename = "DiscoveredResource")]
@@ -61,11 +93,41 @@ pub struct AssociateDiscoveredResourceRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for AssociateDiscoveredResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.discovered_resource
+            .validate_at(&field_path(path, "discovered_resource"), errors);
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDiscoveredResourceResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProgressUpdateStreamRequest {
     /// <p>Optional boolean flag to indicate whether any effect should take place. Used to test if the caller has permission to make the call.</p>
     #[serde(rename = "DryRun")]
@@ -76,8 +138,24 @@ pub struct CreateProgressUpdateStreamRequest {
     pub progress_update_stream_name: String,
 }
 
+impl Validate for CreateProgressUpdateStreamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "progress_update_stream_name"),
+            self.progress_update_stream_name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream_name"),
+            &self.progress_update_stream_name,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProgressUpdateStreamResult {}
 
 /// <p>An ARN of the AWS cloud resource target receiving the migration (e.g., AMI, EC2 instance, RDS instance, etc.).</p>
@@ -92,7 +170,33 @@ pub struct CreatedArtifact {
     pub name: String,
 }
 
+impl Validate for CreatedArtifact {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "^.{0,500}$");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "arn:[a-z-]+:[a-z0-9-]+:(?:[a-z0-9-]+|):(?:[0-9]{12}|):.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProgressUpdateStreamRequest {
     /// <p>Optional boolean flag to indicate whether any effect should take place. Used to test if the caller has permission to make the call.</p>
     #[serde(rename = "DryRun")]
@@ -103,19 +207,52 @@ pub struct DeleteProgressUpdateStreamRequest {
     pub progress_update_stream_name: String,
 }
 
+impl Validate for DeleteProgressUpdateStreamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "progress_update_stream_name"),
+            self.progress_update_stream_name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream_name"),
+            &self.progress_update_stream_name,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProgressUpdateStreamResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeApplicationStateRequest {
     /// <p>The configurationId in ADS that uniquely identifies the grouped application.</p>
     #[serde(rename = "ApplicationId")]
     pub application_id: String,
 }
 
+impl Validate for DescribeApplicationStateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_id"),
+            self.application_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_id"),
+            &self.application_id,
+            "^.{1,1600}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeApplicationStateResult {
     /// <p>Status of the application - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "ApplicationStatus")]
@@ -128,6 +265,7 @@ pub struct DescribeApplicationStateResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMigrationTaskRequest {
     /// <p>The identifier given to the MigrationTask.</p>
     #[serde(rename = "MigrationTaskName")]
@@ -137,8 +275,35 @@ pub struct DescribeMigrationTaskRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for DescribeMigrationTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMigrationTaskResult {
     /// <p>Object encapsulating information about the migration task.</p>
     #[serde(rename = "MigrationTask")]
@@ -147,6 +312,7 @@ pub struct DescribeMigrationTaskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateCreatedArtifactRequest {
     /// <p>An ARN of the AWS resource related to the migration (e.g., AMI, EC2 instance, RDS instance, etc.)</p>
     #[serde(rename = "CreatedArtifactName")]
@@ -163,11 +329,50 @@ pub struct DisassociateCreatedArtifactRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for DisassociateCreatedArtifactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "created_artifact_name"),
+            self.created_artifact_name.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "created_artifact_name"),
+            &self.created_artifact_name,
+            "arn:[a-z-]+:[a-z0-9-]+:(?:[a-z0-9-]+|):(?:[0-9]{12}|):.*",
+        );
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateCreatedArtifactResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDiscoveredResourceRequest {
     /// <p>ConfigurationId of the ADS resource to be disassociated.</p>
     #[serde(rename = "ConfigurationId")]
@@ -184,8 +389,46 @@ pub struct DisassociateDiscoveredResourceRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for DisassociateDiscoveredResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_id"),
+            self.configuration_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_id"),
+            &self.configuration_id,
+            "^.{1,1600}$",
+        );
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDiscoveredResourceResult {}
 
 /// <p>Object representing the on-premises resource being migrated.</p>
@@ -200,7 +443,33 @@ pub struct DiscoveredResource {
     pub description: Option<String>,
 }
 
+impl Validate for DiscoveredResource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration_id"),
+            self.configuration_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "configuration_id"),
+            &self.configuration_id,
+            "^.{1,1600}$",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "^.{0,500}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportMigrationTaskRequest {
     /// <p>Optional boolean flag to indicate whether any effect should take place. Used to test if the caller has permission to make the call.</p>
     #[serde(rename = "DryRun")]
@@ -214,11 +483,39 @@ pub struct ImportMigrationTaskRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for ImportMigrationTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportMigrationTaskResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCreatedArtifactsRequest {
     /// <p>Maximum number of results to be returned per page.</p>
     #[serde(rename = "MaxResults")]
@@ -236,8 +533,51 @@ pub struct ListCreatedArtifactsRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for ListCreatedArtifactsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(10));
+        }
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9\\/\\+\\=]{0,2048}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCreatedArtifactsResult {
     /// <p>List of created artifacts up to the maximum number of results specified in the request.</p>
     #[serde(rename = "CreatedArtifactList")]
@@ -250,6 +590,7 @@ pub struct ListCreatedArtifactsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDiscoveredResourcesRequest {
     /// <p>The maximum number of results returned per page.</p>
     #[serde(rename = "MaxResults")]
@@ -267,8 +608,51 @@ pub struct ListDiscoveredResourcesRequest {
     pub progress_update_stream: String,
 }
 
+impl Validate for ListDiscoveredResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(10));
+        }
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9\\/\\+\\=]{0,2048}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDiscoveredResourcesResult {
     /// <p>Returned list of discovered resources associated with the given MigrationTask.</p>
     #[serde(rename = "DiscoveredResourceList")]
@@ -281,6 +665,7 @@ pub struct ListDiscoveredResourcesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMigrationTasksRequest {
     /// <p>Value to specify how many results are returned per page.</p>
     #[serde(rename = "MaxResults")]
@@ -296,8 +681,38 @@ pub struct ListMigrationTasksRequest {
     pub resource_name: Option<String>,
 }
 
+impl Validate for ListMigrationTasksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9\\/\\+\\=]{0,2048}$",
+            );
+        }
+        if let Some(ref value) = self.resource_name {
+            errors.check_length(
+                &field_path(path, "resource_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+            errors.check_pattern(&field_path(path, "resource_name"), &value, "^.{1,1600}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMigrationTasksResult {
     /// <p>Lists the migration task's summary which includes: <code>MigrationTaskName</code>, <code>ProgressPercent</code>, <code>ProgressUpdateStream</code>, <code>Status</code>, and the <code>UpdateDateTime</code> for each task.</p>
     #[serde(rename = "MigrationTaskSummaryList")]
@@ -310,6 +725,7 @@ pub struct ListMigrationTasksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProgressUpdateStreamsRequest {
     /// <p>Filter to limit the maximum number of results to list per page.</p>
     #[serde(rename = "MaxResults")]
@@ -321,8 +737,29 @@ pub struct ListProgressUpdateStreamsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListProgressUpdateStreamsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9\\/\\+\\=]{0,2048}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProgressUpdateStreamsResult {
     /// <p>If there are more streams created than the max result, return the next token to be passed to the next call as a bookmark of where to start from.</p>
     #[serde(rename = "NextToken")]
@@ -336,7 +773,7 @@ pub struct ListProgressUpdateStreamsResult {
 
 /// <p>Represents a migration task in a migration tool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MigrationTask {
     /// <p>Unique identifier that references the migration task.</p>
     #[serde(rename = "MigrationTaskName")]
@@ -362,7 +799,7 @@ pub struct MigrationTask {
 
 /// <p>MigrationTaskSummary includes <code>MigrationTaskName</code>, <code>ProgressPercent</code>, <code>ProgressUpdateStream</code>, <code>Status</code>, and <code>UpdateDateTime</code> for each task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MigrationTaskSummary {
     /// <p>Unique identifier that references the migration task.</p>
     #[serde(rename = "MigrationTaskName")]
@@ -391,6 +828,7 @@ pub struct MigrationTaskSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotifyApplicationStateRequest {
     /// <p>The configurationId in ADS that uniquely identifies the grouped application.</p>
     #[serde(rename = "ApplicationId")]
@@ -404,11 +842,28 @@ pub struct NotifyApplicationStateRequest {
     pub status: String,
 }
 
+impl Validate for NotifyApplicationStateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "application_id"),
+            self.application_id.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        errors.check_pattern(
+            &field_path(path, "application_id"),
+            &self.application_id,
+            "^.{1,1600}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotifyApplicationStateResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotifyMigrationTaskStateRequest {
     /// <p>Optional boolean flag to indicate whether any effect should take place. Used to test if the caller has permission to make the call.</p>
     #[serde(rename = "DryRun")]
@@ -431,13 +886,41 @@ pub struct NotifyMigrationTaskStateRequest {
     pub update_date_time: f64,
 }
 
+impl Validate for NotifyMigrationTaskStateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+        self.task.validate_at(&field_path(path, "task"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotifyMigrationTaskStateResult {}
 
 /// <p>Summary of the AWS resource used for access control that is implicitly linked to your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProgressUpdateStreamSummary {
     /// <p>The name of the ProgressUpdateStream. </p>
     #[serde(rename = "ProgressUpdateStreamName")]
@@ -446,6 +929,7 @@ pub struct ProgressUpdateStreamSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutResourceAttributesRequest {
     /// <p>Optional boolean flag to indicate whether any effect should take place. Used to test if the caller has permission to make the call.</p>
     #[serde(rename = "DryRun")]
@@ -462,8 +946,45 @@ pub struct PutResourceAttributesRequest {
     pub resource_attribute_list: Vec<ResourceAttribute>,
 }
 
+impl Validate for PutResourceAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "migration_task_name"),
+            self.migration_task_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "migration_task_name"),
+            &self.migration_task_name,
+            "[^:|]+",
+        );
+        errors.check_length(
+            &field_path(path, "progress_update_stream"),
+            self.progress_update_stream.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "progress_update_stream"),
+            &self.progress_update_stream,
+            "[^/:|\\000-\\037]+",
+        );
+        errors.check_length(
+            &field_path(path, "resource_attribute_list"),
+            self.resource_attribute_list.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.resource_attribute_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_attribute_list"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutResourceAttributesResult {}
 
 /// <p><p>Attribute associated with a resource.</p> <p>Note the corresponding format required per type listed below:</p> <dl> <dt>IPV4</dt> <dd> <p> <code>x.x.x.x</code> </p> <p> <i>where x is an integer in the range [0,255]</i> </p> </dd> <dt>IPV6</dt> <dd> <p> <code>y : y : y : y : y : y : y : y</code> </p> <p> <i>where y is a hexadecimal between 0 and FFFF. [0, FFFF]</i> </p> </dd> <dt>MAC_ADDRESS</dt> <dd> <p> <code>^([0-9A-Fa-f]{2}[:-]){5}([0-9A-Fa-f]{2})$</code> </p> </dd> <dt>FQDN</dt> <dd> <p> <code>^[^&lt;&gt;{}\\/?,=\p{Cntrl}]{1,256}$</code> </p> </dd> </dl></p>
@@ -477,6 +998,18 @@ pub struct ResourceAttribute {
     pub value: String,
 }
 
+impl Validate for ResourceAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "value"), &self.value, "^.{1,256}$");
+    }
+}
+
 /// <p>Task object encapsulating task information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
@@ -493,6 +1026,28 @@ pub struct Task {
     pub status_detail: Option<String>,
 }
 
+impl Validate for Task {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.progress_percent {
+            errors.check_range(
+                &field_path(path, "progress_percent"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.status_detail {
+            errors.check_length(
+                &field_path(path, "status_detail"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+            errors.check_pattern(&field_path(path, "status_detail"), &value, "^.{0,500}$");
+        }
+    }
+}
+
 /// Errors returned by AssociateCreatedArtifact
 #[derive(Debug, PartialEq)]
 pub enum AssociateCreatedArtifactError {
@@ -551,7 +1106,12 @@ impl AssociateCreatedArtifactError {
                         AssociateCreatedArtifactError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -643,7 +1203,12 @@ impl AssociateDiscoveredResourceError {
                         AssociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -722,7 +1287,12 @@ impl CreateProgressUpdateStreamError {
                         CreateProgressUpdateStreamError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -806,7 +1376,12 @@ impl DeleteProgressUpdateStreamError {
                         DeleteProgressUpdateStreamError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -882,7 +1457,12 @@ impl DescribeApplicationStateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -946,7 +1526,12 @@ impl DescribeMigrationTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1029,7 +1614,12 @@ impl DisassociateCreatedArtifactError {
                         DisassociateCreatedArtifactError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1114,7 +1704,12 @@ impl DisassociateDiscoveredResourceError {
                         DisassociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1191,7 +1786,12 @@ impl ImportMigrationTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1256,7 +1856,12 @@ impl ListCreatedArtifactsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1323,7 +1928,12 @@ impl ListDiscoveredResourcesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1389,7 +1999,12 @@ impl ListMigrationTasksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1450,7 +2065,12 @@ impl ListProgressUpdateStreamsError {
                         ListProgressUpdateStreamsError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1531,7 +2151,12 @@ impl NotifyApplicationStateError {
                         NotifyApplicationStateError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1615,7 +2240,12 @@ impl NotifyMigrationTaskStateError {
                         NotifyMigrationTaskStateError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1694,7 +2324,12 @@ impl PutResourceAttributesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1817,143 +2452,477 @@ pub trait MigrationHub {
         input: PutResourceAttributesRequest,
     ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError>;
 }
-/// A client for the AWS Migration Hub API.
-#[derive(Clone)]
-pub struct MigrationHubClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MigrationHubClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MigrationHubClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MigrationHubClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubClient {
-        MigrationHubClient { client, region }
-    }
-}
-
-impl MigrationHub for MigrationHubClient {
-    /// <p><p>Associates a created artifact of an AWS cloud resource, the target receiving the migration, with the migration task performed by a migration tool. This API has the following traits:</p> <ul> <li> <p>Migration tools can call the <code>AssociateCreatedArtifact</code> operation to indicate which AWS artifact is associated with a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or DMS endpoint, etc.</p> </li> </ul></p>
+const _: Option<&dyn MigrationHub> = None;
+impl<'a, T: MigrationHub + ?Sized> MigrationHub for &'a T {
     fn associate_created_artifact(
         &self,
         input: AssociateCreatedArtifactRequest,
     ) -> RusotoFuture<AssociateCreatedArtifactResult, AssociateCreatedArtifactError> {
-        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSMigrationHub.AssociateCreatedArtifact");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateCreatedArtifactResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateCreatedArtifactError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_created_artifact(input)
     }
-
-    /// <p>Associates a discovered resource ID from Application Discovery Service (ADS) with a migration task.</p>
     fn associate_discovered_resource(
         &self,
         input: AssociateDiscoveredResourceRequest,
     ) -> RusotoFuture<AssociateDiscoveredResourceResult, AssociateDiscoveredResourceError> {
-        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSMigrationHub.AssociateDiscoveredResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDiscoveredResourceResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateDiscoveredResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_discovered_resource(input)
     }
-
-    /// <p>Creates a progress update stream which is an AWS resource used for access control as well as a namespace for migration task names that is implicitly linked to your AWS account. It must uniquely identify the migration tool as it is used for all updates made by the tool; however, it does not need to be unique for each AWS account because it is scoped to the AWS account.</p>
     fn create_progress_update_stream(
         &self,
         input: CreateProgressUpdateStreamRequest,
     ) -> RusotoFuture<CreateProgressUpdateStreamResult, CreateProgressUpdateStreamError> {
-        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSMigrationHub.CreateProgressUpdateStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProgressUpdateStreamResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateProgressUpdateStreamError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_progress_update_stream(input)
     }
-
-    /// <p><p>Deletes a progress update stream, including all of its tasks, which was previously created as an AWS resource used for access control. This API has the following traits:</p> <ul> <li> <p>The only parameter needed for <code>DeleteProgressUpdateStream</code> is the stream name (same as a <code>CreateProgressUpdateStream</code> call).</p> </li> <li> <p>The call will return, and a background process will asynchronously delete the stream and all of its resources (tasks, associated resources, resource attributes, created artifacts).</p> </li> <li> <p>If the stream takes time to be deleted, it might still show up on a <code>ListProgressUpdateStreams</code> call.</p> </li> <li> <p> <code>CreateProgressUpdateStream</code>, <code>ImportMigrationTask</code>, <code>NotifyMigrationTaskState</code>, and all Associate[*] APIs realted to the tasks belonging to the stream will throw &quot;InvalidInputException&quot; if the stream of the same name is in the process of being deleted.</p> </li> <li> <p>Once the stream and all of its resources are deleted, <code>CreateProgressUpdateStream</code> for a stream of the same name will succeed, and that stream will be an entirely new logical resource (without any resources associated with the old stream).</p> </li> </ul></p>
     fn delete_progress_update_stream(
         &self,
         input: DeleteProgressUpdateStreamRequest,
     ) -> RusotoFuture<DeleteProgressUpdateStreamResult, DeleteProgressUpdateStreamError> {
-        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSMigrationHub.DeleteProgressUpdateStream");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
+        (**self).delete_progress_update_stream(input)
+    }
+    fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> RusotoFuture<DescribeApplicationStateResult, DescribeApplicationStateError> {
+        (**self).describe_application_state(input)
+    }
+    fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> RusotoFuture<DescribeMigrationTaskResult, DescribeMigrationTaskError> {
+        (**self).describe_migration_task(input)
+    }
+    fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> RusotoFuture<DisassociateCreatedArtifactResult, DisassociateCreatedArtifactError> {
+        (**self).disassociate_created_artifact(input)
+    }
+    fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<DisassociateDiscoveredResourceResult, DisassociateDiscoveredResourceError>
+    {
+        (**self).disassociate_discovered_resource(input)
+    }
+    fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> RusotoFuture<ImportMigrationTaskResult, ImportMigrationTaskError> {
+        (**self).import_migration_task(input)
+    }
+    fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> RusotoFuture<ListCreatedArtifactsResult, ListCreatedArtifactsError> {
+        (**self).list_created_artifacts(input)
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResult, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
+    fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> RusotoFuture<ListMigrationTasksResult, ListMigrationTasksError> {
+        (**self).list_migration_tasks(input)
+    }
+    fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> RusotoFuture<ListProgressUpdateStreamsResult, ListProgressUpdateStreamsError> {
+        (**self).list_progress_update_streams(input)
+    }
+    fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> RusotoFuture<NotifyApplicationStateResult, NotifyApplicationStateError> {
+        (**self).notify_application_state(input)
+    }
+    fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> RusotoFuture<NotifyMigrationTaskStateResult, NotifyMigrationTaskStateError> {
+        (**self).notify_migration_task_state(input)
+    }
+    fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError> {
+        (**self).put_resource_attributes(input)
+    }
+}
+impl<T: MigrationHub + ?Sized> MigrationHub for Box<T> {
+    fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> RusotoFuture<AssociateCreatedArtifactResult, AssociateCreatedArtifactError> {
+        (**self).associate_created_artifact(input)
+    }
+    fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<AssociateDiscoveredResourceResult, AssociateDiscoveredResourceError> {
+        (**self).associate_discovered_resource(input)
+    }
+    fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> RusotoFuture<CreateProgressUpdateStreamResult, CreateProgressUpdateStreamError> {
+        (**self).create_progress_update_stream(input)
+    }
+    fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> RusotoFuture<DeleteProgressUpdateStreamResult, DeleteProgressUpdateStreamError> {
+        (**self).delete_progress_update_stream(input)
+    }
+    fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> RusotoFuture<DescribeApplicationStateResult, DescribeApplicationStateError> {
+        (**self).describe_application_state(input)
+    }
+    fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> RusotoFuture<DescribeMigrationTaskResult, DescribeMigrationTaskError> {
+        (**self).describe_migration_task(input)
+    }
+    fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> RusotoFuture<DisassociateCreatedArtifactResult, DisassociateCreatedArtifactError> {
+        (**self).disassociate_created_artifact(input)
+    }
+    fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<DisassociateDiscoveredResourceResult, DisassociateDiscoveredResourceError>
+    {
+        (**self).disassociate_discovered_resource(input)
+    }
+    fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> RusotoFuture<ImportMigrationTaskResult, ImportMigrationTaskError> {
+        (**self).import_migration_task(input)
+    }
+    fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> RusotoFuture<ListCreatedArtifactsResult, ListCreatedArtifactsError> {
+        (**self).list_created_artifacts(input)
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResult, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
+    fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> RusotoFuture<ListMigrationTasksResult, ListMigrationTasksError> {
+        (**self).list_migration_tasks(input)
+    }
+    fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> RusotoFuture<ListProgressUpdateStreamsResult, ListProgressUpdateStreamsError> {
+        (**self).list_progress_update_streams(input)
+    }
+    fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> RusotoFuture<NotifyApplicationStateResult, NotifyApplicationStateError> {
+        (**self).notify_application_state(input)
+    }
+    fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> RusotoFuture<NotifyMigrationTaskStateResult, NotifyMigrationTaskStateError> {
+        (**self).notify_migration_task_state(input)
+    }
+    fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError> {
+        (**self).put_resource_attributes(input)
+    }
+}
+impl<T: MigrationHub + ?Sized> MigrationHub for ::std::sync::Arc<T> {
+    fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> RusotoFuture<AssociateCreatedArtifactResult, AssociateCreatedArtifactError> {
+        (**self).associate_created_artifact(input)
+    }
+    fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<AssociateDiscoveredResourceResult, AssociateDiscoveredResourceError> {
+        (**self).associate_discovered_resource(input)
+    }
+    fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> RusotoFuture<CreateProgressUpdateStreamResult, CreateProgressUpdateStreamError> {
+        (**self).create_progress_update_stream(input)
+    }
+    fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> RusotoFuture<DeleteProgressUpdateStreamResult, DeleteProgressUpdateStreamError> {
+        (**self).delete_progress_update_stream(input)
+    }
+    fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> RusotoFuture<DescribeApplicationStateResult, DescribeApplicationStateError> {
+        (**self).describe_application_state(input)
+    }
+    fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> RusotoFuture<DescribeMigrationTaskResult, DescribeMigrationTaskError> {
+        (**self).describe_migration_task(input)
+    }
+    fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> RusotoFuture<DisassociateCreatedArtifactResult, DisassociateCreatedArtifactError> {
+        (**self).disassociate_created_artifact(input)
+    }
+    fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<DisassociateDiscoveredResourceResult, DisassociateDiscoveredResourceError>
+    {
+        (**self).disassociate_discovered_resource(input)
+    }
+    fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> RusotoFuture<ImportMigrationTaskResult, ImportMigrationTaskError> {
+        (**self).import_migration_task(input)
+    }
+    fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> RusotoFuture<ListCreatedArtifactsResult, ListCreatedArtifactsError> {
+        (**self).list_created_artifacts(input)
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResult, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
+    fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> RusotoFuture<ListMigrationTasksResult, ListMigrationTasksError> {
+        (**self).list_migration_tasks(input)
+    }
+    fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> RusotoFuture<ListProgressUpdateStreamsResult, ListProgressUpdateStreamsError> {
+        (**self).list_progress_update_streams(input)
+    }
+    fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> RusotoFuture<NotifyApplicationStateResult, NotifyApplicationStateError> {
+        (**self).notify_application_state(input)
+    }
+    fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> RusotoFuture<NotifyMigrationTaskStateResult, NotifyMigrationTaskStateError> {
+        (**self).notify_migration_task_state(input)
+    }
+    fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError> {
+        (**self).put_resource_attributes(input)
+    }
+}
+/// A client for the AWS Migration Hub API.
+#[derive(Clone)]
+pub struct MigrationHubClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MigrationHubClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MigrationHubClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MigrationHubClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubClient {
+        MigrationHubClient { client, region }
+    }
+}
+
+impl MigrationHub for MigrationHubClient {
+    /// <p><p>Associates a created artifact of an AWS cloud resource, the target receiving the migration, with the migration task performed by a migration tool. This API has the following traits:</p> <ul> <li> <p>Migration tools can call the <code>AssociateCreatedArtifact</code> operation to indicate which AWS artifact is associated with a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or DMS endpoint, etc.</p> </li> </ul></p>
+    fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> RusotoFuture<AssociateCreatedArtifactResult, AssociateCreatedArtifactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("AssociateCreatedArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSMigrationHub.AssociateCreatedArtifact");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateCreatedArtifactResult, _>(
+                    response,
+                    "AssociateCreatedArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateCreatedArtifactError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a discovered resource ID from Application Discovery Service (ADS) with a migration task.</p>
+    fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<AssociateDiscoveredResourceResult, AssociateDiscoveredResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("AssociateDiscoveredResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSMigrationHub.AssociateDiscoveredResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateDiscoveredResourceResult, _>(
+                    response,
+                    "AssociateDiscoveredResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateDiscoveredResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a progress update stream which is an AWS resource used for access control as well as a namespace for migration task names that is implicitly linked to your AWS account. It must uniquely identify the migration tool as it is used for all updates made by the tool; however, it does not need to be unique for each AWS account because it is scoped to the AWS account.</p>
+    fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> RusotoFuture<CreateProgressUpdateStreamResult, CreateProgressUpdateStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("CreateProgressUpdateStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSMigrationHub.CreateProgressUpdateStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProgressUpdateStreamResult, _>(
+                    response,
+                    "CreateProgressUpdateStream",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProgressUpdateStreamResult, _>()
+                    Err(CreateProgressUpdateStreamError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes a progress update stream, including all of its tasks, which was previously created as an AWS resource used for access control. This API has the following traits:</p> <ul> <li> <p>The only parameter needed for <code>DeleteProgressUpdateStream</code> is the stream name (same as a <code>CreateProgressUpdateStream</code> call).</p> </li> <li> <p>The call will return, and a background process will asynchronously delete the stream and all of its resources (tasks, associated resources, resource attributes, created artifacts).</p> </li> <li> <p>If the stream takes time to be deleted, it might still show up on a <code>ListProgressUpdateStreams</code> call.</p> </li> <li> <p> <code>CreateProgressUpdateStream</code>, <code>ImportMigrationTask</code>, <code>NotifyMigrationTaskState</code>, and all Associate[*] APIs realted to the tasks belonging to the stream will throw &quot;InvalidInputException&quot; if the stream of the same name is in the process of being deleted.</p> </li> <li> <p>Once the stream and all of its resources are deleted, <code>CreateProgressUpdateStream</code> for a stream of the same name will succeed, and that stream will be an entirely new logical resource (without any resources associated with the old stream).</p> </li> </ul></p>
+    fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> RusotoFuture<DeleteProgressUpdateStreamResult, DeleteProgressUpdateStreamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DeleteProgressUpdateStream");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSMigrationHub.DeleteProgressUpdateStream");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProgressUpdateStreamResult, _>(
+                    response,
+                    "DeleteProgressUpdateStream",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DeleteProgressUpdateStreamError::from_response(response))
@@ -1967,7 +2936,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: DescribeApplicationStateRequest,
     ) -> RusotoFuture<DescribeApplicationStateResult, DescribeApplicationStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeApplicationState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeApplicationState");
@@ -1976,10 +2954,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeApplicationStateResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeApplicationStateResult, _>(
+                    response,
+                    "DescribeApplicationState",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeApplicationStateError::from_response(response))
@@ -1993,7 +2971,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: DescribeMigrationTaskRequest,
     ) -> RusotoFuture<DescribeMigrationTaskResult, DescribeMigrationTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DescribeMigrationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeMigrationTask");
@@ -2002,10 +2989,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMigrationTaskResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeMigrationTaskResult, _>(
+                    response,
+                    "DescribeMigrationTask",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2021,7 +3008,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: DisassociateCreatedArtifactRequest,
     ) -> RusotoFuture<DisassociateCreatedArtifactResult, DisassociateCreatedArtifactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DisassociateCreatedArtifact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2033,10 +3029,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateCreatedArtifactResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateCreatedArtifactResult, _>(
+                    response,
+                    "DisassociateCreatedArtifact",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DisassociateCreatedArtifactError::from_response(response))
@@ -2051,7 +3047,16 @@ impl MigrationHub for MigrationHubClient {
         input: DisassociateDiscoveredResourceRequest,
     ) -> RusotoFuture<DisassociateDiscoveredResourceResult, DisassociateDiscoveredResourceError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("DisassociateDiscoveredResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2063,10 +3068,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateDiscoveredResourceResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateDiscoveredResourceResult, _>(
+                    response,
+                    "DisassociateDiscoveredResource",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DisassociateDiscoveredResourceError::from_response(response))
@@ -2080,7 +3085,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: ImportMigrationTaskRequest,
     ) -> RusotoFuture<ImportMigrationTaskResult, ImportMigrationTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ImportMigrationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ImportMigrationTask");
@@ -2089,10 +3103,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportMigrationTaskResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ImportMigrationTaskResult, _>(
+                    response,
+                    "ImportMigrationTask",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2108,7 +3122,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: ListCreatedArtifactsRequest,
     ) -> RusotoFuture<ListCreatedArtifactsResult, ListCreatedArtifactsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListCreatedArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListCreatedArtifacts");
@@ -2117,10 +3140,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCreatedArtifactsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListCreatedArtifactsResult, _>(
+                    response,
+                    "ListCreatedArtifacts",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2136,7 +3159,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: ListDiscoveredResourcesRequest,
     ) -> RusotoFuture<ListDiscoveredResourcesResult, ListDiscoveredResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListDiscoveredResources");
@@ -2145,10 +3177,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDiscoveredResourcesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListDiscoveredResourcesResult, _>(
+                    response,
+                    "ListDiscoveredResources",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListDiscoveredResourcesError::from_response(response))
@@ -2162,7 +3194,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: ListMigrationTasksRequest,
     ) -> RusotoFuture<ListMigrationTasksResult, ListMigrationTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListMigrationTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListMigrationTasks");
@@ -2171,10 +3212,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMigrationTasksResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListMigrationTasksResult, _>(
+                    response,
+                    "ListMigrationTasks",
+                )
             } else {
                 Box::new(
                     response
@@ -2191,7 +3232,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: ListProgressUpdateStreamsRequest,
     ) -> RusotoFuture<ListProgressUpdateStreamsResult, ListProgressUpdateStreamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("ListProgressUpdateStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.ListProgressUpdateStreams");
@@ -2200,10 +3250,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProgressUpdateStreamsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListProgressUpdateStreamsResult, _>(
+                    response,
+                    "ListProgressUpdateStreams",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ListProgressUpdateStreamsError::from_response(response))
@@ -2217,7 +3267,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: NotifyApplicationStateRequest,
     ) -> RusotoFuture<NotifyApplicationStateResult, NotifyApplicationStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("NotifyApplicationState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyApplicationState");
@@ -2226,10 +3285,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<NotifyApplicationStateResult, _>()
-                }))
+                proto::json::deserialize_streaming::<NotifyApplicationStateResult, _>(
+                    response,
+                    "NotifyApplicationState",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2245,7 +3304,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: NotifyMigrationTaskStateRequest,
     ) -> RusotoFuture<NotifyMigrationTaskStateResult, NotifyMigrationTaskStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("NotifyMigrationTaskState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyMigrationTaskState");
@@ -2254,10 +3322,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<NotifyMigrationTaskStateResult, _>()
-                }))
+                proto::json::deserialize_streaming::<NotifyMigrationTaskStateResult, _>(
+                    response,
+                    "NotifyMigrationTaskState",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(NotifyMigrationTaskStateError::from_response(response))
@@ -2271,7 +3339,16 @@ impl MigrationHub for MigrationHubClient {
         &self,
         input: PutResourceAttributesRequest,
     ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "mgh", &self.region, "/");
+        request.set_operation("PutResourceAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSMigrationHub.PutResourceAttributes");
@@ -2280,10 +3357,10 @@ impl MigrationHub for MigrationHubClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutResourceAttributesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<PutResourceAttributesResult, _>(
+                    response,
+                    "PutResourceAttributes",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2294,3 +3371,586 @@ impl MigrationHub for MigrationHubClient {
         })
     }
 }
+
+/// A mock of the AWS Migration Hub API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMigrationHub {
+    associate_created_artifact: Option<
+        Box<
+            dyn Fn(
+                    AssociateCreatedArtifactRequest,
+                ) -> ::std::result::Result<
+                    AssociateCreatedArtifactResult,
+                    RusotoError<AssociateCreatedArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_discovered_resource: Option<
+        Box<
+            dyn Fn(
+                    AssociateDiscoveredResourceRequest,
+                ) -> ::std::result::Result<
+                    AssociateDiscoveredResourceResult,
+                    RusotoError<AssociateDiscoveredResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_progress_update_stream: Option<
+        Box<
+            dyn Fn(
+                    CreateProgressUpdateStreamRequest,
+                ) -> ::std::result::Result<
+                    CreateProgressUpdateStreamResult,
+                    RusotoError<CreateProgressUpdateStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_progress_update_stream: Option<
+        Box<
+            dyn Fn(
+                    DeleteProgressUpdateStreamRequest,
+                ) -> ::std::result::Result<
+                    DeleteProgressUpdateStreamResult,
+                    RusotoError<DeleteProgressUpdateStreamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_application_state: Option<
+        Box<
+            dyn Fn(
+                    DescribeApplicationStateRequest,
+                ) -> ::std::result::Result<
+                    DescribeApplicationStateResult,
+                    RusotoError<DescribeApplicationStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_migration_task: Option<
+        Box<
+            dyn Fn(
+                    DescribeMigrationTaskRequest,
+                ) -> ::std::result::Result<
+                    DescribeMigrationTaskResult,
+                    RusotoError<DescribeMigrationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_created_artifact: Option<
+        Box<
+            dyn Fn(
+                    DisassociateCreatedArtifactRequest,
+                ) -> ::std::result::Result<
+                    DisassociateCreatedArtifactResult,
+                    RusotoError<DisassociateCreatedArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_discovered_resource: Option<
+        Box<
+            dyn Fn(
+                    DisassociateDiscoveredResourceRequest,
+                ) -> ::std::result::Result<
+                    DisassociateDiscoveredResourceResult,
+                    RusotoError<DisassociateDiscoveredResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_migration_task: Option<
+        Box<
+            dyn Fn(
+                    ImportMigrationTaskRequest,
+                ) -> ::std::result::Result<
+                    ImportMigrationTaskResult,
+                    RusotoError<ImportMigrationTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_created_artifacts: Option<
+        Box<
+            dyn Fn(
+                    ListCreatedArtifactsRequest,
+                ) -> ::std::result::Result<
+                    ListCreatedArtifactsResult,
+                    RusotoError<ListCreatedArtifactsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_discovered_resources: Option<
+        Box<
+            dyn Fn(
+                    ListDiscoveredResourcesRequest,
+                ) -> ::std::result::Result<
+                    ListDiscoveredResourcesResult,
+                    RusotoError<ListDiscoveredResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_migration_tasks: Option<
+        Box<
+            dyn Fn(
+                    ListMigrationTasksRequest,
+                ) -> ::std::result::Result<
+                    ListMigrationTasksResult,
+                    RusotoError<ListMigrationTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_progress_update_streams: Option<
+        Box<
+            dyn Fn(
+                    ListProgressUpdateStreamsRequest,
+                ) -> ::std::result::Result<
+                    ListProgressUpdateStreamsResult,
+                    RusotoError<ListProgressUpdateStreamsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    notify_application_state: Option<
+        Box<
+            dyn Fn(
+                    NotifyApplicationStateRequest,
+                ) -> ::std::result::Result<
+                    NotifyApplicationStateResult,
+                    RusotoError<NotifyApplicationStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    notify_migration_task_state: Option<
+        Box<
+            dyn Fn(
+                    NotifyMigrationTaskStateRequest,
+                ) -> ::std::result::Result<
+                    NotifyMigrationTaskStateResult,
+                    RusotoError<NotifyMigrationTaskStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_resource_attributes: Option<
+        Box<
+            dyn Fn(
+                    PutResourceAttributesRequest,
+                ) -> ::std::result::Result<
+                    PutResourceAttributesResult,
+                    RusotoError<PutResourceAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMigrationHub {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMigrationHub {
+        Default::default()
+    }
+
+    /// Answers `associate_created_artifact` with what `handler` returns for its input.
+    pub fn with_associate_created_artifact<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                AssociateCreatedArtifactRequest,
+            ) -> ::std::result::Result<
+                AssociateCreatedArtifactResult,
+                RusotoError<AssociateCreatedArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_created_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_discovered_resource` with what `handler` returns for its input.
+    pub fn with_associate_discovered_resource<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                AssociateDiscoveredResourceRequest,
+            ) -> ::std::result::Result<
+                AssociateDiscoveredResourceResult,
+                RusotoError<AssociateDiscoveredResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_discovered_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_progress_update_stream` with what `handler` returns for its input.
+    pub fn with_create_progress_update_stream<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                CreateProgressUpdateStreamRequest,
+            ) -> ::std::result::Result<
+                CreateProgressUpdateStreamResult,
+                RusotoError<CreateProgressUpdateStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_progress_update_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_progress_update_stream` with what `handler` returns for its input.
+    pub fn with_delete_progress_update_stream<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                DeleteProgressUpdateStreamRequest,
+            ) -> ::std::result::Result<
+                DeleteProgressUpdateStreamResult,
+                RusotoError<DeleteProgressUpdateStreamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_progress_update_stream = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_application_state` with what `handler` returns for its input.
+    pub fn with_describe_application_state<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                DescribeApplicationStateRequest,
+            ) -> ::std::result::Result<
+                DescribeApplicationStateResult,
+                RusotoError<DescribeApplicationStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_application_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_migration_task` with what `handler` returns for its input.
+    pub fn with_describe_migration_task<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                DescribeMigrationTaskRequest,
+            ) -> ::std::result::Result<
+                DescribeMigrationTaskResult,
+                RusotoError<DescribeMigrationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_migration_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_created_artifact` with what `handler` returns for its input.
+    pub fn with_disassociate_created_artifact<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                DisassociateCreatedArtifactRequest,
+            ) -> ::std::result::Result<
+                DisassociateCreatedArtifactResult,
+                RusotoError<DisassociateCreatedArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_created_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_discovered_resource` with what `handler` returns for its input.
+    pub fn with_disassociate_discovered_resource<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                DisassociateDiscoveredResourceRequest,
+            ) -> ::std::result::Result<
+                DisassociateDiscoveredResourceResult,
+                RusotoError<DisassociateDiscoveredResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_discovered_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_migration_task` with what `handler` returns for its input.
+    pub fn with_import_migration_task<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                ImportMigrationTaskRequest,
+            ) -> ::std::result::Result<
+                ImportMigrationTaskResult,
+                RusotoError<ImportMigrationTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_migration_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_created_artifacts` with what `handler` returns for its input.
+    pub fn with_list_created_artifacts<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                ListCreatedArtifactsRequest,
+            ) -> ::std::result::Result<
+                ListCreatedArtifactsResult,
+                RusotoError<ListCreatedArtifactsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_created_artifacts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_discovered_resources` with what `handler` returns for its input.
+    pub fn with_list_discovered_resources<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                ListDiscoveredResourcesRequest,
+            ) -> ::std::result::Result<
+                ListDiscoveredResourcesResult,
+                RusotoError<ListDiscoveredResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_discovered_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_migration_tasks` with what `handler` returns for its input.
+    pub fn with_list_migration_tasks<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                ListMigrationTasksRequest,
+            ) -> ::std::result::Result<
+                ListMigrationTasksResult,
+                RusotoError<ListMigrationTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_migration_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_progress_update_streams` with what `handler` returns for its input.
+    pub fn with_list_progress_update_streams<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                ListProgressUpdateStreamsRequest,
+            ) -> ::std::result::Result<
+                ListProgressUpdateStreamsResult,
+                RusotoError<ListProgressUpdateStreamsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_progress_update_streams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `notify_application_state` with what `handler` returns for its input.
+    pub fn with_notify_application_state<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                NotifyApplicationStateRequest,
+            ) -> ::std::result::Result<
+                NotifyApplicationStateResult,
+                RusotoError<NotifyApplicationStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.notify_application_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `notify_migration_task_state` with what `handler` returns for its input.
+    pub fn with_notify_migration_task_state<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                NotifyMigrationTaskStateRequest,
+            ) -> ::std::result::Result<
+                NotifyMigrationTaskStateResult,
+                RusotoError<NotifyMigrationTaskStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.notify_migration_task_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_resource_attributes` with what `handler` returns for its input.
+    pub fn with_put_resource_attributes<F>(mut self, handler: F) -> MockMigrationHub
+    where
+        F: Fn(
+                PutResourceAttributesRequest,
+            ) -> ::std::result::Result<
+                PutResourceAttributesResult,
+                RusotoError<PutResourceAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_resource_attributes = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MigrationHub for MockMigrationHub {
+    fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> RusotoFuture<AssociateCreatedArtifactResult, AssociateCreatedArtifactError> {
+        match self.associate_created_artifact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for associate_created_artifact"),
+        }
+    }
+    fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<AssociateDiscoveredResourceResult, AssociateDiscoveredResourceError> {
+        match self.associate_discovered_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for associate_discovered_resource"),
+        }
+    }
+    fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> RusotoFuture<CreateProgressUpdateStreamResult, CreateProgressUpdateStreamError> {
+        match self.create_progress_update_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for create_progress_update_stream"),
+        }
+    }
+    fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> RusotoFuture<DeleteProgressUpdateStreamResult, DeleteProgressUpdateStreamError> {
+        match self.delete_progress_update_stream {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for delete_progress_update_stream"),
+        }
+    }
+    fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> RusotoFuture<DescribeApplicationStateResult, DescribeApplicationStateError> {
+        match self.describe_application_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for describe_application_state"),
+        }
+    }
+    fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> RusotoFuture<DescribeMigrationTaskResult, DescribeMigrationTaskError> {
+        match self.describe_migration_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for describe_migration_task"),
+        }
+    }
+    fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> RusotoFuture<DisassociateCreatedArtifactResult, DisassociateCreatedArtifactError> {
+        match self.disassociate_created_artifact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for disassociate_created_artifact"),
+        }
+    }
+    fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<DisassociateDiscoveredResourceResult, DisassociateDiscoveredResourceError>
+    {
+        match self.disassociate_discovered_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for disassociate_discovered_resource"),
+        }
+    }
+    fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> RusotoFuture<ImportMigrationTaskResult, ImportMigrationTaskError> {
+        match self.import_migration_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for import_migration_task"),
+        }
+    }
+    fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> RusotoFuture<ListCreatedArtifactsResult, ListCreatedArtifactsError> {
+        match self.list_created_artifacts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for list_created_artifacts"),
+        }
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResult, ListDiscoveredResourcesError> {
+        match self.list_discovered_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for list_discovered_resources"),
+        }
+    }
+    fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> RusotoFuture<ListMigrationTasksResult, ListMigrationTasksError> {
+        match self.list_migration_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for list_migration_tasks"),
+        }
+    }
+    fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> RusotoFuture<ListProgressUpdateStreamsResult, ListProgressUpdateStreamsError> {
+        match self.list_progress_update_streams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for list_progress_update_streams"),
+        }
+    }
+    fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> RusotoFuture<NotifyApplicationStateResult, NotifyApplicationStateError> {
+        match self.notify_application_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for notify_application_state"),
+        }
+    }
+    fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> RusotoFuture<NotifyMigrationTaskStateResult, NotifyMigrationTaskStateError> {
+        match self.notify_migration_task_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for notify_migration_task_state"),
+        }
+    }
+    fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError> {
+        match self.put_resource_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMigrationHub has no handler for put_resource_attributes"),
+        }
+    }
+}
@@ -25,7 +25,7 @@ use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
 /// <p> The details of the bundle. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BundleDetails {
     #[serde(rename = "availablePlatforms")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,6 +49,7 @@ pub struct BundleDetails {
 
 /// <p> Request structure used to request a project be created. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProjectRequest {
     /// <p> ZIP or YAML file which contains configuration settings to be used when creating the project. This may be the contents of the file downloaded from the URL provided in an export project operation. </p>
     #[serde(rename = "contents")]
@@ -75,7 +76,7 @@ pub struct CreateProjectRequest {
 
 /// <p> Result structure used in response to a request to create a project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProjectResult {
     /// <p> Detailed information about the created AWS Mobile Hub project. </p>
     #[serde(rename = "details")]
@@ -85,6 +86,7 @@ pub struct CreateProjectResult {
 
 /// <p> Request structure used to request a project be deleted. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProjectRequest {
     /// <p> Unique project identifier. </p>
     #[serde(rename = "projectId")]
@@ -93,7 +95,7 @@ pub struct DeleteProjectRequest {
 
 /// <p> Result structure used in response to request to delete a project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProjectResult {
     /// <p> Resources which were deleted. </p>
     #[serde(rename = "deletedResources")]
@@ -107,6 +109,7 @@ pub struct DeleteProjectResult {
 
 /// <p> Request structure to request the details of a specific bundle. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBundleRequest {
     /// <p> Unique bundle identifier. </p>
     #[serde(rename = "bundleId")]
@@ -115,7 +118,7 @@ pub struct DescribeBundleRequest {
 
 /// <p> Result structure contains the details of the bundle. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBundleResult {
     /// <p> The details of the bundle. </p>
     #[serde(rename = "details")]
@@ -125,6 +128,7 @@ pub struct DescribeBundleResult {
 
 /// <p> Request structure used to request details about a project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProjectRequest {
     /// <p> Unique project identifier. </p>
     #[serde(rename = "projectId")]
@@ -137,7 +141,7 @@ pub struct DescribeProjectRequest {
 
 /// <p> Result structure used for requests of project details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProjectResult {
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -146,6 +150,7 @@ pub struct DescribeProjectResult {
 
 /// <p> Request structure used to request generation of custom SDK and tool packages required to integrate mobile web or app clients with backed AWS resources. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportBundleRequest {
     /// <p> Unique bundle identifier. </p>
     #[serde(rename = "bundleId")]
@@ -162,7 +167,7 @@ pub struct ExportBundleRequest {
 
 /// <p> Result structure which contains link to download custom-generated SDK and tool packages used to integrate mobile web or app clients with backed AWS resources. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportBundleResult {
     /// <p> URL which contains the custom-generated SDK and tool packages used to integrate the client mobile app or web app with the AWS resources created by the AWS Mobile Hub project. </p>
     #[serde(rename = "downloadUrl")]
@@ -172,6 +177,7 @@ pub struct ExportBundleResult {
 
 /// <p> Request structure used in requests to export project configuration details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportProjectRequest {
     /// <p> Unique project identifier. </p>
     #[serde(rename = "projectId")]
@@ -180,7 +186,7 @@ pub struct ExportProjectRequest {
 
 /// <p> Result structure used for requests to export project configuration details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportProjectResult {
     /// <p> URL which can be used to download the exported project configuation file(s). </p>
     #[serde(rename = "downloadUrl")]
@@ -198,6 +204,7 @@ pub struct ExportProjectResult {
 
 /// <p> Request structure to request all available bundles. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBundlesRequest {
     /// <p> Maximum number of records to list in a single response. </p>
     #[serde(rename = "maxResults")]
@@ -211,7 +218,7 @@ pub struct ListBundlesRequest {
 
 /// <p> Result structure contains a list of all available bundles with details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBundlesResult {
     /// <p> A list of bundles. </p>
     #[serde(rename = "bundleList")]
@@ -225,6 +232,7 @@ pub struct ListBundlesResult {
 
 /// <p> Request structure used to request projects list in AWS Mobile Hub. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProjectsRequest {
     /// <p> Maximum number of records to list in a single response. </p>
     #[serde(rename = "maxResults")]
@@ -238,7 +246,7 @@ pub struct ListProjectsRequest {
 
 /// <p> Result structure used for requests to list projects in AWS Mobile Hub. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProjectsResult {
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -250,7 +258,7 @@ pub struct ListProjectsResult {
 
 /// <p> Detailed information about an AWS Mobile Hub project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectDetails {
     /// <p> Website URL for this project in the AWS Mobile Hub console. </p>
     #[serde(rename = "consoleUrl")]
@@ -283,7 +291,7 @@ pub struct ProjectDetails {
 
 /// <p> Summary information about an AWS Mobile Hub project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProjectSummary {
     /// <p> Name of the project. </p>
     #[serde(rename = "name")]
@@ -297,7 +305,7 @@ pub struct ProjectSummary {
 
 /// <p> Information about an instance of an AWS resource associated with a project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     #[serde(rename = "arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -318,6 +326,7 @@ pub struct Resource {
 
 /// <p> Request structure used for requests to update project configuration. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProjectRequest {
     /// <p> ZIP or YAML file which contains project configuration to be updated. This should be the contents of the file downloaded from the URL provided in an export project operation. </p>
     #[serde(rename = "contents")]
@@ -335,7 +344,7 @@ pub struct UpdateProjectRequest {
 
 /// <p> Result structure used for requests to updated project configuration. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProjectResult {
     /// <p> Detailed information about the updated AWS Mobile Hub project. </p>
     #[serde(rename = "details")]
@@ -387,7 +396,12 @@ impl CreateProjectError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateProjectError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -446,7 +460,12 @@ impl DeleteProjectError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteProjectError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -508,7 +527,12 @@ impl DescribeBundleError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeBundleError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -571,7 +595,12 @@ impl DescribeProjectError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeProjectError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -634,7 +663,12 @@ impl ExportBundleError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ExportBundleError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -697,7 +731,12 @@ impl ExportProjectError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ExportProjectError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -755,7 +794,12 @@ impl ListBundlesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListBundlesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -812,7 +856,12 @@ impl ListProjectsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListProjectsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -884,7 +933,12 @@ impl UpdateProjectError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateProjectError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -966,6 +1020,175 @@ pub trait Mobile {
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError>;
 }
+const _: Option<&dyn Mobile> = None;
+impl<'a, T: Mobile + ?Sized> Mobile for &'a T {
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> RusotoFuture<DescribeBundleResult, DescribeBundleError> {
+        (**self).describe_bundle(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> RusotoFuture<ExportBundleResult, ExportBundleError> {
+        (**self).export_bundle(input)
+    }
+    fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> RusotoFuture<ExportProjectResult, ExportProjectError> {
+        (**self).export_project(input)
+    }
+    fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> RusotoFuture<ListBundlesResult, ListBundlesError> {
+        (**self).list_bundles(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+impl<T: Mobile + ?Sized> Mobile for Box<T> {
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> RusotoFuture<DescribeBundleResult, DescribeBundleError> {
+        (**self).describe_bundle(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> RusotoFuture<ExportBundleResult, ExportBundleError> {
+        (**self).export_bundle(input)
+    }
+    fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> RusotoFuture<ExportProjectResult, ExportProjectError> {
+        (**self).export_project(input)
+    }
+    fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> RusotoFuture<ListBundlesResult, ListBundlesError> {
+        (**self).list_bundles(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+impl<T: Mobile + ?Sized> Mobile for ::std::sync::Arc<T> {
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> RusotoFuture<DescribeBundleResult, DescribeBundleError> {
+        (**self).describe_bundle(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> RusotoFuture<ExportBundleResult, ExportBundleError> {
+        (**self).export_bundle(input)
+    }
+    fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> RusotoFuture<ExportProjectResult, ExportProjectError> {
+        (**self).export_project(input)
+    }
+    fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> RusotoFuture<ListBundlesResult, ListBundlesError> {
+        (**self).list_bundles(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
 /// A client for the AWS Mobile API.
 #[derive(Clone)]
 pub struct MobileClient {
@@ -1013,6 +1236,8 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("CreateProject");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1039,6 +1264,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateProject")
                         .deserialize::<CreateProjectResult, _>()?;
 
                     Ok(result)
@@ -1063,6 +1289,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("DELETE", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DeleteProject");
+        request.add_resource_id("projectId", &input.project_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1071,6 +1300,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteProject")
                         .deserialize::<DeleteProjectResult, _>()?;
 
                     Ok(result)
@@ -1095,6 +1325,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeBundle");
+        request.add_resource_id("bundleId", &input.bundle_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1103,6 +1336,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeBundle")
                         .deserialize::<DescribeBundleResult, _>()?;
 
                     Ok(result)
@@ -1127,6 +1361,8 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("DescribeProject");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1142,6 +1378,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeProject")
                         .deserialize::<DescribeProjectResult, _>()?;
 
                     Ok(result)
@@ -1166,6 +1403,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportBundle");
+        request.add_resource_id("bundleId", &input.bundle_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1183,6 +1423,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ExportBundle")
                         .deserialize::<ExportBundleResult, _>()?;
 
                     Ok(result)
@@ -1207,6 +1448,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ExportProject");
+        request.add_resource_id("projectId", &input.project_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1215,6 +1459,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ExportProject")
                         .deserialize::<ExportProjectResult, _>()?;
 
                     Ok(result)
@@ -1239,6 +1484,8 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListBundles");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1256,6 +1503,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListBundles")
                         .deserialize::<ListBundlesResult, _>()?;
 
                     Ok(result)
@@ -1280,6 +1528,8 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("ListProjects");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1297,6 +1547,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListProjects")
                         .deserialize::<ListProjectsResult, _>()?;
 
                     Ok(result)
@@ -1321,6 +1572,8 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation("UpdateProject");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1339,6 +1592,7 @@ impl Mobile for MobileClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateProject")
                         .deserialize::<UpdateProjectResult, _>()?;
 
                     Ok(result)
@@ -1354,3 +1608,323 @@ impl Mobile for MobileClient {
         })
     }
 }
+
+/// A mock of the AWS Mobile API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMobile {
+    create_project: Option<
+        Box<
+            dyn Fn(
+                    CreateProjectRequest,
+                )
+                    -> ::std::result::Result<CreateProjectResult, RusotoError<CreateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_project: Option<
+        Box<
+            dyn Fn(
+                    DeleteProjectRequest,
+                )
+                    -> ::std::result::Result<DeleteProjectResult, RusotoError<DeleteProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_bundle: Option<
+        Box<
+            dyn Fn(
+                    DescribeBundleRequest,
+                )
+                    -> ::std::result::Result<DescribeBundleResult, RusotoError<DescribeBundleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_project: Option<
+        Box<
+            dyn Fn(
+                    DescribeProjectRequest,
+                ) -> ::std::result::Result<
+                    DescribeProjectResult,
+                    RusotoError<DescribeProjectError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    export_bundle: Option<
+        Box<
+            dyn Fn(
+                    ExportBundleRequest,
+                )
+                    -> ::std::result::Result<ExportBundleResult, RusotoError<ExportBundleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    export_project: Option<
+        Box<
+            dyn Fn(
+                    ExportProjectRequest,
+                )
+                    -> ::std::result::Result<ExportProjectResult, RusotoError<ExportProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_bundles: Option<
+        Box<
+            dyn Fn(
+                    ListBundlesRequest,
+                )
+                    -> ::std::result::Result<ListBundlesResult, RusotoError<ListBundlesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_projects: Option<
+        Box<
+            dyn Fn(
+                    ListProjectsRequest,
+                )
+                    -> ::std::result::Result<ListProjectsResult, RusotoError<ListProjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_project: Option<
+        Box<
+            dyn Fn(
+                    UpdateProjectRequest,
+                )
+                    -> ::std::result::Result<UpdateProjectResult, RusotoError<UpdateProjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMobile {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMobile {
+        Default::default()
+    }
+
+    /// Answers `create_project` with what `handler` returns for its input.
+    pub fn with_create_project<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                CreateProjectRequest,
+            )
+                -> ::std::result::Result<CreateProjectResult, RusotoError<CreateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_project` with what `handler` returns for its input.
+    pub fn with_delete_project<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                DeleteProjectRequest,
+            )
+                -> ::std::result::Result<DeleteProjectResult, RusotoError<DeleteProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_bundle` with what `handler` returns for its input.
+    pub fn with_describe_bundle<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                DescribeBundleRequest,
+            )
+                -> ::std::result::Result<DescribeBundleResult, RusotoError<DescribeBundleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_bundle = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_project` with what `handler` returns for its input.
+    pub fn with_describe_project<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                DescribeProjectRequest,
+            )
+                -> ::std::result::Result<DescribeProjectResult, RusotoError<DescribeProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_bundle` with what `handler` returns for its input.
+    pub fn with_export_bundle<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                ExportBundleRequest,
+            )
+                -> ::std::result::Result<ExportBundleResult, RusotoError<ExportBundleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.export_bundle = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_project` with what `handler` returns for its input.
+    pub fn with_export_project<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                ExportProjectRequest,
+            )
+                -> ::std::result::Result<ExportProjectResult, RusotoError<ExportProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.export_project = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bundles` with what `handler` returns for its input.
+    pub fn with_list_bundles<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                ListBundlesRequest,
+            )
+                -> ::std::result::Result<ListBundlesResult, RusotoError<ListBundlesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bundles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_projects` with what `handler` returns for its input.
+    pub fn with_list_projects<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                ListProjectsRequest,
+            )
+                -> ::std::result::Result<ListProjectsResult, RusotoError<ListProjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_projects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_project` with what `handler` returns for its input.
+    pub fn with_update_project<F>(mut self, handler: F) -> MockMobile
+    where
+        F: Fn(
+                UpdateProjectRequest,
+            )
+                -> ::std::result::Result<UpdateProjectResult, RusotoError<UpdateProjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_project = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Mobile for MockMobile {
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        match self.create_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for create_project"),
+        }
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        match self.delete_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for delete_project"),
+        }
+    }
+    fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> RusotoFuture<DescribeBundleResult, DescribeBundleError> {
+        match self.describe_bundle {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for describe_bundle"),
+        }
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        match self.describe_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for describe_project"),
+        }
+    }
+    fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> RusotoFuture<ExportBundleResult, ExportBundleError> {
+        match self.export_bundle {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for export_bundle"),
+        }
+    }
+    fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> RusotoFuture<ExportProjectResult, ExportProjectError> {
+        match self.export_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for export_project"),
+        }
+    }
+    fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> RusotoFuture<ListBundlesResult, ListBundlesError> {
+        match self.list_bundles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for list_bundles"),
+        }
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        match self.list_projects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for list_projects"),
+        }
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        match self.update_project {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMobile has no handler for update_project"),
+        }
+    }
+}
@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Name of the availability zone.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AvailabilityZone {
     /// <p>Id for the availability zone.</p>
     #[serde(rename = "Name")]
@@ -36,7 +37,7 @@ pub struct AvailabilityZone {
 
 /// <p>Types of broker engines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BrokerEngineType {
     /// <p>The type of broker engine.</p>
     #[serde(rename = "EngineType")]
@@ -50,7 +51,7 @@ pub struct BrokerEngineType {
 
 /// <p>Returns information about all brokers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BrokerInstance {
     /// <p>The URL of the broker&#39;s ActiveMQ Web Console.</p>
     #[serde(rename = "ConsoleURL")]
@@ -68,7 +69,7 @@ pub struct BrokerInstance {
 
 /// <p>Option for host instance type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BrokerInstanceOption {
     /// <p>The list of available az.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -90,7 +91,7 @@ pub struct BrokerInstanceOption {
 
 /// <p>The Amazon Resource Name (ARN) of the broker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BrokerSummary {
     /// <p>The Amazon Resource Name (ARN) of the broker.</p>
     #[serde(rename = "BrokerArn")]
@@ -124,7 +125,7 @@ pub struct BrokerSummary {
 
 /// <p>Returns information about all configurations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Configuration {
     /// <p>Required. The ARN of the configuration.</p>
     #[serde(rename = "Arn")]
@@ -179,7 +180,7 @@ pub struct ConfigurationId {
 
 /// <p>Returns information about the specified configuration revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConfigurationRevision {
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
@@ -197,7 +198,7 @@ pub struct ConfigurationRevision {
 
 /// <p>Broker configuration information</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Configurations {
     /// <p>The current configuration of the broker.</p>
     #[serde(rename = "Current")]
@@ -215,6 +216,7 @@ pub struct Configurations {
 
 /// <p>Creates a broker using the specified properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBrokerRequest {
     /// <p>Required. Enables automatic upgrades to new minor versions for brokers, as Apache releases the versions. The automatic upgrades occur during the maintenance window of the broker or after a manual broker reboot.</p>
     #[serde(rename = "AutoMinorVersionUpgrade")]
@@ -283,7 +285,7 @@ pub struct CreateBrokerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBrokerResponse {
     /// <p>The Amazon Resource Name (ARN) of the broker.</p>
     #[serde(rename = "BrokerArn")]
@@ -297,6 +299,7 @@ pub struct CreateBrokerResponse {
 
 /// <p>Creates a new configuration for the specified configuration name. Amazon MQ uses the default configuration (the engine type and version).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationRequest {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
@@ -317,7 +320,7 @@ pub struct CreateConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConfigurationResponse {
     /// <p>Required. The Amazon Resource Name (ARN) of the configuration.</p>
     #[serde(rename = "Arn")]
@@ -343,6 +346,7 @@ pub struct CreateConfigurationResponse {
 
 /// <p>A map of the key-value pairs for the resource tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagsRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource tag.</p>
     #[serde(rename = "ResourceArn")]
@@ -355,6 +359,7 @@ pub struct CreateTagsRequest {
 
 /// <p>Creates a new ActiveMQ user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -377,10 +382,11 @@ pub struct CreateUserRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBrokerRequest {
     /// <p>The name of the broker. This value must be unique in your AWS account, 1-50 characters long, must contain only letters, numbers, dashes, and underscores, and must not contain whitespaces, brackets, wildcard characters, or special characters.</p>
     #[serde(rename = "BrokerId")]
@@ -388,7 +394,7 @@ pub struct DeleteBrokerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBrokerResponse {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -397,6 +403,7 @@ pub struct DeleteBrokerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource tag.</p>
     #[serde(rename = "ResourceArn")]
@@ -407,6 +414,7 @@ pub struct DeleteTagsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -417,10 +425,11 @@ pub struct DeleteUserRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBrokerEngineTypesRequest {
     /// <p>Filter response by engine type.</p>
     #[serde(rename = "EngineType")]
@@ -436,8 +445,16 @@ pub struct DescribeBrokerEngineTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeBrokerEngineTypesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBrokerEngineTypesResponse {
     /// <p>List of available engine types and versions.</p>
     #[serde(rename = "BrokerEngineTypes")]
@@ -454,6 +471,7 @@ pub struct DescribeBrokerEngineTypesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBrokerInstanceOptionsRequest {
     /// <p>Filter response by engine type.</p>
     #[serde(rename = "EngineType")]
@@ -473,8 +491,16 @@ pub struct DescribeBrokerInstanceOptionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeBrokerInstanceOptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBrokerInstanceOptionsResponse {
     /// <p>List of available broker instance options.</p>
     #[serde(rename = "BrokerInstanceOptions")]
@@ -491,6 +517,7 @@ pub struct DescribeBrokerInstanceOptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBrokerRequest {
     /// <p>The name of the broker. This value must be unique in your AWS account, 1-50 characters long, must contain only letters, numbers, dashes, and underscores, and must not contain whitespaces, brackets, wildcard characters, or special characters.</p>
     #[serde(rename = "BrokerId")]
@@ -498,7 +525,7 @@ pub struct DescribeBrokerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBrokerResponse {
     /// <p>Required. Enables automatic upgrades to new minor versions for brokers, as Apache releases the versions. The automatic upgrades occur during the maintenance window of the broker or after a manual broker reboot.</p>
     #[serde(rename = "AutoMinorVersionUpgrade")]
@@ -591,6 +618,7 @@ pub struct DescribeBrokerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationRequest {
     /// <p>The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "ConfigurationId")]
@@ -598,7 +626,7 @@ pub struct DescribeConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationResponse {
     /// <p>Required. The ARN of the configuration.</p>
     #[serde(rename = "Arn")]
@@ -639,6 +667,7 @@ pub struct DescribeConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationRevisionRequest {
     /// <p>The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "ConfigurationId")]
@@ -649,7 +678,7 @@ pub struct DescribeConfigurationRevisionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConfigurationRevisionResponse {
     /// <p>Required. The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "ConfigurationId")]
@@ -670,6 +699,7 @@ pub struct DescribeConfigurationRevisionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -680,7 +710,7 @@ pub struct DescribeUserRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserResponse {
     /// <p>Required. The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -718,7 +748,7 @@ pub struct EncryptionOptions {
 
 /// <p>Id of the engine version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EngineVersion {
     /// <p>Id for the version.</p>
     #[serde(rename = "Name")]
@@ -727,6 +757,7 @@ pub struct EngineVersion {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBrokersRequest {
     /// <p>The maximum number of brokers that Amazon MQ can return per page (20 by default). This value must be an integer from 5 to 100.</p>
     #[serde(rename = "MaxResults")]
@@ -738,8 +769,16 @@ pub struct ListBrokersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListBrokersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBrokersResponse {
     /// <p>A list of information about all brokers.</p>
     #[serde(rename = "BrokerSummaries")]
@@ -752,6 +791,7 @@ pub struct ListBrokersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationRevisionsRequest {
     /// <p>The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "ConfigurationId")]
@@ -766,8 +806,16 @@ pub struct ListConfigurationRevisionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListConfigurationRevisionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConfigurationRevisionsResponse {
     /// <p>The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "ConfigurationId")]
@@ -788,6 +836,7 @@ pub struct ListConfigurationRevisionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationsRequest {
     /// <p>The maximum number of configurations that Amazon MQ can return per page (20 by default). This value must be an integer from 5 to 100.</p>
     #[serde(rename = "MaxResults")]
@@ -799,8 +848,16 @@ pub struct ListConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConfigurationsResponse {
     /// <p>The list of all revisions for the specified configuration.</p>
     #[serde(rename = "Configurations")]
@@ -817,6 +874,7 @@ pub struct ListConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource tag.</p>
     #[serde(rename = "ResourceArn")]
@@ -824,7 +882,7 @@ pub struct ListTagsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResponse {
     /// <p>The key-value pair for the resource tag.</p>
     #[serde(rename = "Tags")]
@@ -833,6 +891,7 @@ pub struct ListTagsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -847,8 +906,16 @@ pub struct ListUsersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersResponse {
     /// <p>Required. The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -883,7 +950,7 @@ pub struct Logs {
 
 /// <p>The list of information about logs currently enabled and pending to be deployed for the specified broker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LogsSummary {
     /// <p>Enables audit logging. Every user management action made using JMX or the ActiveMQ Web Console is logged.</p>
     #[serde(rename = "Audit")]
@@ -909,7 +976,7 @@ pub struct LogsSummary {
 
 /// <p>The list of information about logs to be enabled for the specified broker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PendingLogs {
     /// <p>Enables audit logging. Every user management action made using JMX or the ActiveMQ Web Console is logged.</p>
     #[serde(rename = "Audit")]
@@ -922,6 +989,7 @@ pub struct PendingLogs {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootBrokerRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -929,12 +997,12 @@ pub struct RebootBrokerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebootBrokerResponse {}
 
 /// <p>Returns information about the XML element or attribute that was sanitized in the configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SanitizationWarning {
     /// <p>The name of the XML attribute that has been sanitized.</p>
     #[serde(rename = "AttributeName")]
@@ -952,6 +1020,7 @@ pub struct SanitizationWarning {
 
 /// <p>Updates the broker using the specified properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBrokerRequest {
     /// <p>Enables automatic upgrades to new minor versions for brokers, as Apache releases the versions. The automatic upgrades occur during the maintenance window of the broker or after a manual broker reboot.</p>
     #[serde(rename = "AutoMinorVersionUpgrade")]
@@ -979,7 +1048,7 @@ pub struct UpdateBrokerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBrokerResponse {
     /// <p>The new value of automatic upgrades to new minor version for brokers.</p>
     #[serde(rename = "AutoMinorVersionUpgrade")]
@@ -1009,6 +1078,7 @@ pub struct UpdateBrokerResponse {
 
 /// <p>Updates the specified configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationRequest {
     /// <p>The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "ConfigurationId")]
@@ -1024,7 +1094,7 @@ pub struct UpdateConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConfigurationResponse {
     /// <p>Required. The Amazon Resource Name (ARN) of the configuration.</p>
     #[serde(rename = "Arn")]
@@ -1054,6 +1124,7 @@ pub struct UpdateConfigurationResponse {
 
 /// <p>Updates the information for an ActiveMQ user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
     #[serde(rename = "BrokerId")]
@@ -1076,11 +1147,12 @@ pub struct UpdateUserRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserResponse {}
 
 /// <p>An ActiveMQ user associated with the broker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct User {
     /// <p>Enables access to the the ActiveMQ Web Console for the ActiveMQ user.</p>
     #[serde(rename = "ConsoleAccess")]
@@ -1102,7 +1174,7 @@ pub struct User {
 
 /// <p>Returns information about the status of the changes pending for the ActiveMQ user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserPendingChanges {
     /// <p>Enables access to the the ActiveMQ Web Console for the ActiveMQ user.</p>
     #[serde(rename = "ConsoleAccess")]
@@ -1120,7 +1192,7 @@ pub struct UserPendingChanges {
 
 /// <p>Returns a list of all ActiveMQ users.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserSummary {
     /// <p>The type of change pending for the ActiveMQ user.</p>
     #[serde(rename = "PendingChange")]
@@ -1183,7 +1255,12 @@ impl CreateBrokerError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateBrokerError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1237,7 +1314,12 @@ impl CreateConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1288,7 +1370,12 @@ impl CreateTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1344,7 +1431,12 @@ impl CreateUserError {
                 "NotFoundException" => {
                     return RusotoError::Service(CreateUserError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1396,7 +1488,12 @@ impl DeleteBrokerError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteBrokerError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1447,7 +1544,12 @@ impl DeleteTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1498,7 +1600,12 @@ impl DeleteUserError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteUserError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1549,7 +1656,12 @@ impl DescribeBrokerError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeBrokerError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1599,7 +1711,12 @@ impl DescribeBrokerEngineTypesError {
                         DescribeBrokerEngineTypesError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1652,7 +1769,12 @@ impl DescribeBrokerInstanceOptionsError {
                         DescribeBrokerInstanceOptionsError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1704,7 +1826,12 @@ impl DescribeConfigurationError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeConfigurationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1765,7 +1892,12 @@ impl DescribeConfigurationRevisionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1816,7 +1948,12 @@ impl DescribeUserError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeUserError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1862,7 +1999,12 @@ impl ListBrokersError {
                 "InternalServerErrorException" => {
                     return RusotoError::Service(ListBrokersError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1920,7 +2062,12 @@ impl ListConfigurationRevisionsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListConfigurationRevisionsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1968,7 +2115,12 @@ impl ListConfigurationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2018,7 +2170,12 @@ impl ListTagsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListTagsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2069,7 +2226,12 @@ impl ListUsersError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListUsersError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2120,7 +2282,12 @@ impl RebootBrokerError {
                 "NotFoundException" => {
                     return RusotoError::Service(RebootBrokerError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2176,7 +2343,12 @@ impl UpdateBrokerError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateBrokerError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2235,7 +2407,12 @@ impl UpdateConfigurationError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateConfigurationError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2292,7 +2469,12 @@ impl UpdateUserError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateUserError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2440,249 +2622,653 @@ pub trait MQ {
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
 }
-/// A client for the AmazonMQ API.
-#[derive(Clone)]
-pub struct MQClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MQClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MQClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MQClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MQClient {
-        MQClient { client, region }
-    }
-}
-
-impl MQ for MQClient {
-    /// <p>Creates a broker. Note: This API is asynchronous.</p>
+const _: Option<&dyn MQ> = None;
+impl<'a, T: MQ + ?Sized> MQ for &'a T {
     fn create_broker(
         &self,
         input: CreateBrokerRequest,
     ) -> RusotoFuture<CreateBrokerResponse, CreateBrokerError> {
-        let request_uri = "/v1/brokers";
-
-        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBrokerResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBrokerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_broker(input)
     }
-
-    /// <p>Creates a new configuration for the specified configuration name. Amazon MQ uses the default configuration (the engine type and version).</p>
     fn create_configuration(
         &self,
         input: CreateConfigurationRequest,
     ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
-        let request_uri = "/v1/configurations";
-
-        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateConfigurationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_configuration(input)
     }
-
-    /// <p>Add a tag to a resource.</p>
     fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
-        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_tags(input)
     }
-
-    /// <p>Creates an ActiveMQ user.</p>
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
-        let request_uri = format!(
-            "/v1/brokers/{broker_id}/users/{username}",
-            broker_id = input.broker_id,
-            username = input.username
-        );
-
-        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Deletes a broker. Note: This API is asynchronous.</p>
     fn delete_broker(
         &self,
         input: DeleteBrokerRequest,
     ) -> RusotoFuture<DeleteBrokerResponse, DeleteBrokerError> {
-        let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
-
-        let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBrokerResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBrokerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_broker(input)
     }
-
-    /// <p>Removes a tag from a resource.</p>
     fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
-        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tags(input)
     }
-
-    /// <p>Deletes an ActiveMQ user.</p>
     fn delete_user(
         &self,
         input: DeleteUserRequest,
     ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
-        let request_uri = format!(
-            "/v1/brokers/{broker_id}/users/{username}",
-            broker_id = input.broker_id,
-            username = input.username
-        );
-
-        let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        (**self).delete_user(input)
+    }
+    fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> RusotoFuture<DescribeBrokerResponse, DescribeBrokerError> {
+        (**self).describe_broker(input)
+    }
+    fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> RusotoFuture<DescribeBrokerEngineTypesResponse, DescribeBrokerEngineTypesError> {
+        (**self).describe_broker_engine_types(input)
+    }
+    fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> RusotoFuture<DescribeBrokerInstanceOptionsResponse, DescribeBrokerInstanceOptionsError>
+    {
+        (**self).describe_broker_instance_options(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> RusotoFuture<ListBrokersResponse, ListBrokersError> {
+        (**self).list_brokers(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> RusotoFuture<RebootBrokerResponse, RebootBrokerError> {
+        (**self).reboot_broker(input)
+    }
+    fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> RusotoFuture<UpdateBrokerResponse, UpdateBrokerError> {
+        (**self).update_broker(input)
+    }
+    fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> RusotoFuture<UpdateConfigurationResponse, UpdateConfigurationError> {
+        (**self).update_configuration(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+impl<T: MQ + ?Sized> MQ for Box<T> {
+    fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> RusotoFuture<CreateBrokerResponse, CreateBrokerError> {
+        (**self).create_broker(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> RusotoFuture<DeleteBrokerResponse, DeleteBrokerError> {
+        (**self).delete_broker(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> RusotoFuture<DescribeBrokerResponse, DescribeBrokerError> {
+        (**self).describe_broker(input)
+    }
+    fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> RusotoFuture<DescribeBrokerEngineTypesResponse, DescribeBrokerEngineTypesError> {
+        (**self).describe_broker_engine_types(input)
+    }
+    fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> RusotoFuture<DescribeBrokerInstanceOptionsResponse, DescribeBrokerInstanceOptionsError>
+    {
+        (**self).describe_broker_instance_options(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> RusotoFuture<ListBrokersResponse, ListBrokersError> {
+        (**self).list_brokers(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> RusotoFuture<RebootBrokerResponse, RebootBrokerError> {
+        (**self).reboot_broker(input)
+    }
+    fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> RusotoFuture<UpdateBrokerResponse, UpdateBrokerError> {
+        (**self).update_broker(input)
+    }
+    fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> RusotoFuture<UpdateConfigurationResponse, UpdateConfigurationError> {
+        (**self).update_configuration(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+impl<T: MQ + ?Sized> MQ for ::std::sync::Arc<T> {
+    fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> RusotoFuture<CreateBrokerResponse, CreateBrokerError> {
+        (**self).create_broker(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> RusotoFuture<DeleteBrokerResponse, DeleteBrokerError> {
+        (**self).delete_broker(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> RusotoFuture<DescribeBrokerResponse, DescribeBrokerError> {
+        (**self).describe_broker(input)
+    }
+    fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> RusotoFuture<DescribeBrokerEngineTypesResponse, DescribeBrokerEngineTypesError> {
+        (**self).describe_broker_engine_types(input)
+    }
+    fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> RusotoFuture<DescribeBrokerInstanceOptionsResponse, DescribeBrokerInstanceOptionsError>
+    {
+        (**self).describe_broker_instance_options(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> RusotoFuture<ListBrokersResponse, ListBrokersError> {
+        (**self).list_brokers(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> RusotoFuture<RebootBrokerResponse, RebootBrokerError> {
+        (**self).reboot_broker(input)
+    }
+    fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> RusotoFuture<UpdateBrokerResponse, UpdateBrokerError> {
+        (**self).update_broker(input)
+    }
+    fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> RusotoFuture<UpdateConfigurationResponse, UpdateConfigurationError> {
+        (**self).update_configuration(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+/// A client for the AmazonMQ API.
+#[derive(Clone)]
+pub struct MQClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MQClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MQClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MQClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MQClient {
+        MQClient { client, region }
+    }
+}
+
+impl MQ for MQClient {
+    /// <p>Creates a broker. Note: This API is asynchronous.</p>
+    fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> RusotoFuture<CreateBrokerResponse, CreateBrokerError> {
+        let request_uri = "/v1/brokers";
+
+        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateBroker");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateBroker")
+                        .deserialize::<CreateBrokerResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBrokerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new configuration for the specified configuration name. Amazon MQ uses the default configuration (the engine type and version).</p>
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        let request_uri = "/v1/configurations";
+
+        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateConfiguration")
+                        .deserialize::<CreateConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateConfigurationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Add a tag to a resource.</p>
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateTags");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an ActiveMQ user.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        let request_uri = format!(
+            "/v1/brokers/{broker_id}/users/{username}",
+            broker_id = input.broker_id,
+            username = input.username
+        );
+
+        let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("CreateUser");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.add_resource_id("Username", &input.username.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateUser")
+                        .deserialize::<CreateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a broker. Note: This API is asynchronous.</p>
+    fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> RusotoFuture<DeleteBrokerResponse, DeleteBrokerError> {
+        let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
+
+        let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteBroker");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteBroker")
+                        .deserialize::<DeleteBrokerResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBrokerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a tag from a resource.</p>
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an ActiveMQ user.</p>
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        let request_uri = format!(
+            "/v1/brokers/{broker_id}/users/{username}",
+            broker_id = input.broker_id,
+            username = input.username
+        );
+
+        let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.add_resource_id("Username", &input.username.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteUser")
                         .deserialize::<DeleteUserResponse, _>()?;
 
                     Ok(result)
@@ -2706,12 +3292,16 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBroker");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeBroker")
                         .deserialize::<DescribeBrokerResponse, _>()?;
 
                     Ok(result)
@@ -2732,9 +3322,19 @@ impl MQ for MQClient {
         &self,
         input: DescribeBrokerEngineTypesRequest,
     ) -> RusotoFuture<DescribeBrokerEngineTypesResponse, DescribeBrokerEngineTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/broker-engine-types";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBrokerEngineTypes");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2753,6 +3353,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeBrokerEngineTypes")
                         .deserialize::<DescribeBrokerEngineTypesResponse, _>()?;
 
                     Ok(result)
@@ -2771,9 +3372,19 @@ impl MQ for MQClient {
         input: DescribeBrokerInstanceOptionsRequest,
     ) -> RusotoFuture<DescribeBrokerInstanceOptionsResponse, DescribeBrokerInstanceOptionsError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/broker-instance-options";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeBrokerInstanceOptions");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2795,6 +3406,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeBrokerInstanceOptions")
                         .deserialize::<DescribeBrokerInstanceOptionsResponse, _>()?;
 
                     Ok(result)
@@ -2818,12 +3430,16 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
+        request.add_resource_id("ConfigurationId", &input.configuration_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeConfiguration")
                         .deserialize::<DescribeConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -2851,12 +3467,20 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
+        request.add_resource_id("ConfigurationId", &input.configuration_id.to_string());
+        request.add_resource_id(
+            "ConfigurationRevision",
+            &input.configuration_revision.to_string(),
+        );
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeConfigurationRevision")
                         .deserialize::<DescribeConfigurationRevisionResponse, _>()?;
 
                     Ok(result)
@@ -2881,12 +3505,17 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.add_resource_id("Username", &input.username.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeUser")
                         .deserialize::<DescribeUserResponse, _>()?;
 
                     Ok(result)
@@ -2907,9 +3536,19 @@ impl MQ for MQClient {
         &self,
         input: ListBrokersRequest,
     ) -> RusotoFuture<ListBrokersResponse, ListBrokersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListBrokers");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2925,6 +3564,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListBrokers")
                         .deserialize::<ListBrokersResponse, _>()?;
 
                     Ok(result)
@@ -2945,12 +3585,23 @@ impl MQ for MQClient {
         &self,
         input: ListConfigurationRevisionsRequest,
     ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/v1/configurations/{configuration_id}/revisions",
             configuration_id = input.configuration_id
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
+        request.add_resource_id("ConfigurationId", &input.configuration_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2966,6 +3617,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListConfigurationRevisions")
                         .deserialize::<ListConfigurationRevisionsResponse, _>()?;
 
                     Ok(result)
@@ -2983,9 +3635,19 @@ impl MQ for MQClient {
         &self,
         input: ListConfigurationsRequest,
     ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3001,6 +3663,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListConfigurations")
                         .deserialize::<ListConfigurationsResponse, _>()?;
 
                     Ok(result)
@@ -3021,12 +3684,16 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListTags");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTags")
                         .deserialize::<ListTagsResponse, _>()?;
 
                     Ok(result)
@@ -3047,9 +3714,20 @@ impl MQ for MQClient {
         &self,
         input: ListUsersRequest,
     ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/v1/brokers/{broker_id}/users", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation("ListUsers");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3065,6 +3743,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListUsers")
                         .deserialize::<ListUsersResponse, _>()?;
 
                     Ok(result)
@@ -3091,12 +3770,15 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation("RebootBroker");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RebootBroker")
                         .deserialize::<RebootBrokerResponse, _>()?;
 
                     Ok(result)
@@ -3120,6 +3802,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateBroker");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3129,6 +3814,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateBroker")
                         .deserialize::<UpdateBrokerResponse, _>()?;
 
                     Ok(result)
@@ -3155,6 +3841,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateConfiguration");
+        request.add_resource_id("ConfigurationId", &input.configuration_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3164,6 +3853,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateConfiguration")
                         .deserialize::<UpdateConfigurationResponse, _>()?;
 
                     Ok(result)
@@ -3190,6 +3880,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
+        request.add_resource_id("BrokerId", &input.broker_id.to_string());
+        request.add_resource_id("Username", &input.username.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3199,6 +3892,7 @@ impl MQ for MQClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateUser")
                         .deserialize::<UpdateUserResponse, _>()?;
 
                     Ok(result)
@@ -3214,3 +3908,747 @@ impl MQ for MQClient {
         })
     }
 }
+
+/// A mock of the AmazonMQ API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMQ {
+    create_broker: Option<
+        Box<
+            dyn Fn(
+                    CreateBrokerRequest,
+                )
+                    -> ::std::result::Result<CreateBrokerResponse, RusotoError<CreateBrokerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateConfigurationRequest,
+                ) -> ::std::result::Result<
+                    CreateConfigurationResponse,
+                    RusotoError<CreateConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tags: Option<
+        Box<
+            dyn Fn(CreateTagsRequest) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_broker: Option<
+        Box<
+            dyn Fn(
+                    DeleteBrokerRequest,
+                )
+                    -> ::std::result::Result<DeleteBrokerResponse, RusotoError<DeleteBrokerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(DeleteTagsRequest) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserRequest,
+                )
+                    -> ::std::result::Result<DeleteUserResponse, RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_broker: Option<
+        Box<
+            dyn Fn(
+                    DescribeBrokerRequest,
+                ) -> ::std::result::Result<
+                    DescribeBrokerResponse,
+                    RusotoError<DescribeBrokerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_broker_engine_types: Option<
+        Box<
+            dyn Fn(
+                    DescribeBrokerEngineTypesRequest,
+                ) -> ::std::result::Result<
+                    DescribeBrokerEngineTypesResponse,
+                    RusotoError<DescribeBrokerEngineTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_broker_instance_options: Option<
+        Box<
+            dyn Fn(
+                    DescribeBrokerInstanceOptionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeBrokerInstanceOptionsResponse,
+                    RusotoError<DescribeBrokerInstanceOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationResponse,
+                    RusotoError<DescribeConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_revision: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationRevisionRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationRevisionResponse,
+                    RusotoError<DescribeConfigurationRevisionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserRequest,
+                )
+                    -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_brokers: Option<
+        Box<
+            dyn Fn(
+                    ListBrokersRequest,
+                )
+                    -> ::std::result::Result<ListBrokersResponse, RusotoError<ListBrokersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_configuration_revisions: Option<
+        Box<
+            dyn Fn(
+                    ListConfigurationRevisionsRequest,
+                ) -> ::std::result::Result<
+                    ListConfigurationRevisionsResponse,
+                    RusotoError<ListConfigurationRevisionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListConfigurationsResponse,
+                    RusotoError<ListConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_users: Option<
+        Box<
+            dyn Fn(
+                    ListUsersRequest,
+                )
+                    -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reboot_broker: Option<
+        Box<
+            dyn Fn(
+                    RebootBrokerRequest,
+                )
+                    -> ::std::result::Result<RebootBrokerResponse, RusotoError<RebootBrokerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_broker: Option<
+        Box<
+            dyn Fn(
+                    UpdateBrokerRequest,
+                )
+                    -> ::std::result::Result<UpdateBrokerResponse, RusotoError<UpdateBrokerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateConfigurationResponse,
+                    RusotoError<UpdateConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_user: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserRequest,
+                )
+                    -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMQ {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMQ {
+        Default::default()
+    }
+
+    /// Answers `create_broker` with what `handler` returns for its input.
+    pub fn with_create_broker<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                CreateBrokerRequest,
+            )
+                -> ::std::result::Result<CreateBrokerResponse, RusotoError<CreateBrokerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_broker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_configuration` with what `handler` returns for its input.
+    pub fn with_create_configuration<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                CreateConfigurationRequest,
+            ) -> ::std::result::Result<
+                CreateConfigurationResponse,
+                RusotoError<CreateConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tags` with what `handler` returns for its input.
+    pub fn with_create_tags<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(CreateTagsRequest) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                CreateUserRequest,
+            )
+                -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_broker` with what `handler` returns for its input.
+    pub fn with_delete_broker<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DeleteBrokerRequest,
+            )
+                -> ::std::result::Result<DeleteBrokerResponse, RusotoError<DeleteBrokerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_broker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(DeleteTagsRequest) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DeleteUserRequest,
+            )
+                -> ::std::result::Result<DeleteUserResponse, RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_broker` with what `handler` returns for its input.
+    pub fn with_describe_broker<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DescribeBrokerRequest,
+            )
+                -> ::std::result::Result<DescribeBrokerResponse, RusotoError<DescribeBrokerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_broker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_broker_engine_types` with what `handler` returns for its input.
+    pub fn with_describe_broker_engine_types<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DescribeBrokerEngineTypesRequest,
+            ) -> ::std::result::Result<
+                DescribeBrokerEngineTypesResponse,
+                RusotoError<DescribeBrokerEngineTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_broker_engine_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_broker_instance_options` with what `handler` returns for its input.
+    pub fn with_describe_broker_instance_options<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DescribeBrokerInstanceOptionsRequest,
+            ) -> ::std::result::Result<
+                DescribeBrokerInstanceOptionsResponse,
+                RusotoError<DescribeBrokerInstanceOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_broker_instance_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration` with what `handler` returns for its input.
+    pub fn with_describe_configuration<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DescribeConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationResponse,
+                RusotoError<DescribeConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_revision` with what `handler` returns for its input.
+    pub fn with_describe_configuration_revision<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DescribeConfigurationRevisionRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationRevisionResponse,
+                RusotoError<DescribeConfigurationRevisionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user` with what `handler` returns for its input.
+    pub fn with_describe_user<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                DescribeUserRequest,
+            )
+                -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_brokers` with what `handler` returns for its input.
+    pub fn with_list_brokers<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                ListBrokersRequest,
+            )
+                -> ::std::result::Result<ListBrokersResponse, RusotoError<ListBrokersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_brokers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_configuration_revisions` with what `handler` returns for its input.
+    pub fn with_list_configuration_revisions<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                ListConfigurationRevisionsRequest,
+            ) -> ::std::result::Result<
+                ListConfigurationRevisionsResponse,
+                RusotoError<ListConfigurationRevisionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_configuration_revisions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_configurations` with what `handler` returns for its input.
+    pub fn with_list_configurations<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                ListConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListConfigurationsResponse,
+                RusotoError<ListConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                ListTagsRequest,
+            ) -> ::std::result::Result<ListTagsResponse, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users` with what `handler` returns for its input.
+    pub fn with_list_users<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                ListUsersRequest,
+            ) -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_broker` with what `handler` returns for its input.
+    pub fn with_reboot_broker<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                RebootBrokerRequest,
+            )
+                -> ::std::result::Result<RebootBrokerResponse, RusotoError<RebootBrokerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_broker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_broker` with what `handler` returns for its input.
+    pub fn with_update_broker<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                UpdateBrokerRequest,
+            )
+                -> ::std::result::Result<UpdateBrokerResponse, RusotoError<UpdateBrokerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_broker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_configuration` with what `handler` returns for its input.
+    pub fn with_update_configuration<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                UpdateConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateConfigurationResponse,
+                RusotoError<UpdateConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user` with what `handler` returns for its input.
+    pub fn with_update_user<F>(mut self, handler: F) -> MockMQ
+    where
+        F: Fn(
+                UpdateUserRequest,
+            )
+                -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MQ for MockMQ {
+    fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> RusotoFuture<CreateBrokerResponse, CreateBrokerError> {
+        match self.create_broker {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for create_broker"),
+        }
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        match self.create_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for create_configuration"),
+        }
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        match self.create_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for create_tags"),
+        }
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for create_user"),
+        }
+    }
+    fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> RusotoFuture<DeleteBrokerResponse, DeleteBrokerError> {
+        match self.delete_broker {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for delete_broker"),
+        }
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for delete_tags"),
+        }
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for delete_user"),
+        }
+    }
+    fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> RusotoFuture<DescribeBrokerResponse, DescribeBrokerError> {
+        match self.describe_broker {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for describe_broker"),
+        }
+    }
+    fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> RusotoFuture<DescribeBrokerEngineTypesResponse, DescribeBrokerEngineTypesError> {
+        match self.describe_broker_engine_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for describe_broker_engine_types"),
+        }
+    }
+    fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> RusotoFuture<DescribeBrokerInstanceOptionsResponse, DescribeBrokerInstanceOptionsError>
+    {
+        match self.describe_broker_instance_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for describe_broker_instance_options"),
+        }
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        match self.describe_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for describe_configuration"),
+        }
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        match self.describe_configuration_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for describe_configuration_revision"),
+        }
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        match self.describe_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for describe_user"),
+        }
+    }
+    fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> RusotoFuture<ListBrokersResponse, ListBrokersError> {
+        match self.list_brokers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for list_brokers"),
+        }
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        match self.list_configuration_revisions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for list_configuration_revisions"),
+        }
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        match self.list_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for list_configurations"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for list_tags"),
+        }
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        match self.list_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for list_users"),
+        }
+    }
+    fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> RusotoFuture<RebootBrokerResponse, RebootBrokerError> {
+        match self.reboot_broker {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for reboot_broker"),
+        }
+    }
+    fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> RusotoFuture<UpdateBrokerResponse, UpdateBrokerError> {
+        match self.update_broker {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for update_broker"),
+        }
+    }
+    fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> RusotoFuture<UpdateConfigurationResponse, UpdateConfigurationError> {
+        match self.update_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for update_configuration"),
+        }
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        match self.update_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMQ has no handler for update_user"),
+        }
+    }
+}
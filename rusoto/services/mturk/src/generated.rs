@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptQualificationRequestRequest {
     /// <p> The value of the Qualification. You can omit this value if you are using the presence or absence of the Qualification as the basis for a HIT requirement. </p>
     #[serde(rename = "IntegerValue")]
@@ -35,10 +37,11 @@ pub struct AcceptQualificationRequestRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptQualificationRequestResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ApproveAssignmentRequest {
     /// <p> The ID of the assignment. The assignment must correspond to a HIT created by the Requester. </p>
     #[serde(rename = "AssignmentId")]
@@ -53,13 +56,29 @@ pub struct ApproveAssignmentRequest {
     pub requester_feedback: Option<String>,
 }
 
+impl Validate for ApproveAssignmentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assignment_id"),
+            self.assignment_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "assignment_id"),
+            &self.assignment_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApproveAssignmentResponse {}
 
 /// <p> The Assignment data structure represents a single assignment of a HIT to a Worker. The assignment tracks the Worker's efforts to complete the HIT, and contains the results for later retrieval. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Assignment {
     /// <p> The date and time the Worker accepted the assignment.</p>
     #[serde(rename = "AcceptTime")]
@@ -112,6 +131,7 @@ pub struct Assignment {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateQualificationWithWorkerRequest {
     /// <p>The value of the Qualification to assign.</p>
     #[serde(rename = "IntegerValue")]
@@ -129,13 +149,40 @@ pub struct AssociateQualificationWithWorkerRequest {
     pub worker_id: String,
 }
 
+impl Validate for AssociateQualificationWithWorkerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "worker_id"),
+            self.worker_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_id"),
+            &self.worker_id,
+            "^A[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateQualificationWithWorkerResponse {}
 
 /// <p>An object representing a Bonus payment paid to a Worker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BonusPayment {
     /// <p>The ID of the assignment associated with this bonus payment.</p>
     #[serde(rename = "AssignmentId")]
@@ -159,6 +206,7 @@ pub struct BonusPayment {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAdditionalAssignmentsForHITRequest {
     /// <p>The ID of the HIT to extend.</p>
     #[serde(rename = "HITId")]
@@ -172,11 +220,32 @@ pub struct CreateAdditionalAssignmentsForHITRequest {
     pub unique_request_token: Option<String>,
 }
 
+impl Validate for CreateAdditionalAssignmentsForHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+        if let Some(ref value) = self.unique_request_token {
+            errors.check_length(
+                &field_path(path, "unique_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAdditionalAssignmentsForHITResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHITRequest {
     /// <p> The amount of time, in seconds, that a Worker has to complete the HIT after accepting it. If a Worker does not complete the assignment within the specified duration, the assignment is considered abandoned. If the HIT is still active (that is, its lifetime has not elapsed), the assignment becomes available for other users to find and accept. </p>
     #[serde(rename = "AssignmentDurationInSeconds")]
@@ -239,8 +308,42 @@ pub struct CreateHITRequest {
     pub unique_request_token: Option<String>,
 }
 
+impl Validate for CreateHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hit_layout_id {
+            errors.check_length(
+                &field_path(path, "hit_layout_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "hit_layout_id"), &value, "^[A-Z0-9]+$");
+        }
+        if let Some(ref value) = self.qualification_requirements {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "qualification_requirements"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "reward"),
+            &self.reward,
+            "^[0-9]+(\\.)?[0-9]{0,2}$",
+        );
+        if let Some(ref value) = self.unique_request_token {
+            errors.check_length(
+                &field_path(path, "unique_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHITResponse {
     /// <p> Contains the newly created HIT data. For a description of the HIT data structure as it appears in responses, see the HIT Data Structure documentation. </p>
     #[serde(rename = "HIT")]
@@ -249,6 +352,7 @@ pub struct CreateHITResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHITTypeRequest {
     /// <p> The amount of time, in seconds, that a Worker has to complete the HIT after accepting it. If a Worker does not complete the assignment within the specified duration, the assignment is considered abandoned. If the HIT is still active (that is, its lifetime has not elapsed), the assignment becomes available for other users to find and accept. </p>
     #[serde(rename = "AssignmentDurationInSeconds")]
@@ -276,8 +380,25 @@ pub struct CreateHITTypeRequest {
     pub title: String,
 }
 
+impl Validate for CreateHITTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.qualification_requirements {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "qualification_requirements"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "reward"),
+            &self.reward,
+            "^[0-9]+(\\.)?[0-9]{0,2}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHITTypeResponse {
     /// <p> The ID of the newly registered HIT type.</p>
     #[serde(rename = "HITTypeId")]
@@ -286,6 +407,7 @@ pub struct CreateHITTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHITWithHITTypeRequest {
     /// <p> The Assignment-level Review Policy applies to the assignments under the HIT. You can specify for Mechanical Turk to take various actions based on the policy. </p>
     #[serde(rename = "AssignmentReviewPolicy")]
@@ -327,8 +449,41 @@ pub struct CreateHITWithHITTypeRequest {
     pub unique_request_token: Option<String>,
 }
 
+impl Validate for CreateHITWithHITTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hit_layout_id {
+            errors.check_length(
+                &field_path(path, "hit_layout_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "hit_layout_id"), &value, "^[A-Z0-9]+$");
+        }
+        errors.check_length(
+            &field_path(path, "hit_type_id"),
+            self.hit_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "hit_type_id"),
+            &self.hit_type_id,
+            "^[A-Z0-9]+$",
+        );
+        if let Some(ref value) = self.unique_request_token {
+            errors.check_length(
+                &field_path(path, "unique_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHITWithHITTypeResponse {
     /// <p> Contains the newly created HIT data. For a description of the HIT data structure as it appears in responses, see the HIT Data Structure documentation. </p>
     #[serde(rename = "HIT")]
@@ -337,6 +492,7 @@ pub struct CreateHITWithHITTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateQualificationTypeRequest {
     /// <p>The answers to the Qualification test specified in the Test parameter, in the form of an AnswerKey data structure.</p> <p>Constraints: Must not be longer than 65535 bytes.</p> <p>Constraints: None. If not specified, you must process Qualification requests manually.</p>
     #[serde(rename = "AnswerKey")]
@@ -378,7 +534,7 @@ pub struct CreateQualificationTypeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateQualificationTypeResponse {
     /// <p>The created Qualification type, returned as a QualificationType data structure.</p>
     #[serde(rename = "QualificationType")]
@@ -387,6 +543,7 @@ pub struct CreateQualificationTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWorkerBlockRequest {
     /// <p>A message explaining the reason for blocking the Worker. This parameter enables you to keep track of your Workers. The Worker does not see this message.</p>
     #[serde(rename = "Reason")]
@@ -396,33 +553,80 @@ pub struct CreateWorkerBlockRequest {
     pub worker_id: String,
 }
 
+impl Validate for CreateWorkerBlockRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "worker_id"),
+            self.worker_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_id"),
+            &self.worker_id,
+            "^A[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWorkerBlockResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHITRequest {
     /// <p>The ID of the HIT to be deleted.</p>
     #[serde(rename = "HITId")]
     pub hit_id: String,
 }
 
+impl Validate for DeleteHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteHITResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteQualificationTypeRequest {
     /// <p>The ID of the QualificationType to dispose.</p>
     #[serde(rename = "QualificationTypeId")]
     pub qualification_type_id: String,
 }
 
+impl Validate for DeleteQualificationTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteQualificationTypeResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWorkerBlockRequest {
     /// <p>A message that explains the reason for unblocking the Worker. The Worker does not see this message.</p>
     #[serde(rename = "Reason")]
@@ -433,11 +637,28 @@ pub struct DeleteWorkerBlockRequest {
     pub worker_id: String,
 }
 
+impl Validate for DeleteWorkerBlockRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "worker_id"),
+            self.worker_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_id"),
+            &self.worker_id,
+            "^A[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWorkerBlockResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateQualificationFromWorkerRequest {
     /// <p>The ID of the Qualification type of the Qualification to be revoked.</p>
     #[serde(rename = "QualificationTypeId")]
@@ -451,15 +672,43 @@ pub struct DisassociateQualificationFromWorkerRequest {
     pub worker_id: String,
 }
 
+impl Validate for DisassociateQualificationFromWorkerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "worker_id"),
+            self.worker_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_id"),
+            &self.worker_id,
+            "^A[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateQualificationFromWorkerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountBalanceRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAccountBalanceResponse {
     #[serde(rename = "AvailableBalance")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -470,14 +719,31 @@ pub struct GetAccountBalanceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAssignmentRequest {
     /// <p>The ID of the Assignment to be retrieved.</p>
     #[serde(rename = "AssignmentId")]
     pub assignment_id: String,
 }
 
+impl Validate for GetAssignmentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assignment_id"),
+            self.assignment_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "assignment_id"),
+            &self.assignment_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAssignmentResponse {
     /// <p> The assignment. The response includes one Assignment element. </p>
     #[serde(rename = "Assignment")]
@@ -490,6 +756,7 @@ pub struct GetAssignmentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFileUploadURLRequest {
     /// <p>The ID of the assignment that contains the question with a FileUploadAnswer.</p>
     #[serde(rename = "AssignmentId")]
@@ -499,8 +766,24 @@ pub struct GetFileUploadURLRequest {
     pub question_identifier: String,
 }
 
+impl Validate for GetFileUploadURLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assignment_id"),
+            self.assignment_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "assignment_id"),
+            &self.assignment_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFileUploadURLResponse {
     /// <p> A temporary URL for the file that the Worker uploaded for the answer. </p>
     #[serde(rename = "FileUploadURL")]
@@ -509,14 +792,27 @@ pub struct GetFileUploadURLResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHITRequest {
     /// <p>The ID of the HIT to be retrieved.</p>
     #[serde(rename = "HITId")]
     pub hit_id: String,
 }
 
+impl Validate for GetHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetHITResponse {
     /// <p> Contains the requested HIT data.</p>
     #[serde(rename = "HIT")]
@@ -525,6 +821,7 @@ pub struct GetHITResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQualificationScoreRequest {
     /// <p>The ID of the QualificationType.</p>
     #[serde(rename = "QualificationTypeId")]
@@ -534,8 +831,35 @@ pub struct GetQualificationScoreRequest {
     pub worker_id: String,
 }
 
+impl Validate for GetQualificationScoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "worker_id"),
+            self.worker_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_id"),
+            &self.worker_id,
+            "^A[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetQualificationScoreResponse {
     /// <p> The Qualification data structure of the Qualification assigned to a user, including the Qualification type and the value (score). </p>
     #[serde(rename = "Qualification")]
@@ -544,14 +868,31 @@ pub struct GetQualificationScoreResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQualificationTypeRequest {
     /// <p>The ID of the QualificationType.</p>
     #[serde(rename = "QualificationTypeId")]
     pub qualification_type_id: String,
 }
 
+impl Validate for GetQualificationTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetQualificationTypeResponse {
     /// <p> The returned Qualification Type</p>
     #[serde(rename = "QualificationType")]
@@ -561,7 +902,7 @@ pub struct GetQualificationTypeResponse {
 
 /// <p> The HIT data structure represents a single HIT, including all the information necessary for a Worker to accept and complete the HIT.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HIT {
     /// <p> The length of time, in seconds, that a Worker has to complete the HIT after accepting it.</p>
     #[serde(rename = "AssignmentDurationInSeconds")]
@@ -650,6 +991,7 @@ pub struct HIT {
 
 /// <p> The HITLayoutParameter data structure defines parameter values used with a HITLayout. A HITLayout is a reusable Amazon Mechanical Turk project template used to provide Human Intelligence Task (HIT) question data for CreateHIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HITLayoutParameter {
     /// <p> The name of the parameter in the HITLayout. </p>
     #[serde(rename = "Name")]
@@ -660,6 +1002,7 @@ pub struct HITLayoutParameter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssignmentsForHITRequest {
     /// <p>The status of the assignments to return: Submitted | Approved | Rejected</p>
     #[serde(rename = "AssignmentStatuses")]
@@ -677,8 +1020,31 @@ pub struct ListAssignmentsForHITRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssignmentsForHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssignmentsForHITResponse {
     /// <p> The collection of Assignment data structures returned by this call.</p>
     #[serde(rename = "Assignments")]
@@ -694,6 +1060,7 @@ pub struct ListAssignmentsForHITResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBonusPaymentsRequest {
     /// <p>The ID of the assignment associated with the bonus payments to retrieve. If specified, only bonus payments for the given assignment are returned. Either the HITId parameter or the AssignmentId parameter must be specified</p>
     #[serde(rename = "AssignmentId")]
@@ -712,8 +1079,42 @@ pub struct ListBonusPaymentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListBonusPaymentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.assignment_id {
+            errors.check_length(
+                &field_path(path, "assignment_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "assignment_id"), &value, "^[A-Z0-9]+$");
+        }
+        if let Some(ref value) = self.hit_id {
+            errors.check_length(
+                &field_path(path, "hit_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "hit_id"), &value, "^[A-Z0-9]+$");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBonusPaymentsResponse {
     /// <p>A successful request to the ListBonusPayments operation returns a list of BonusPayment objects. </p>
     #[serde(rename = "BonusPayments")]
@@ -729,6 +1130,7 @@ pub struct ListBonusPaymentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHITsForQualificationTypeRequest {
     /// <p> Limit the number of results returned. </p>
     #[serde(rename = "MaxResults")]
@@ -743,8 +1145,35 @@ pub struct ListHITsForQualificationTypeRequest {
     pub qualification_type_id: String,
 }
 
+impl Validate for ListHITsForQualificationTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHITsForQualificationTypeResponse {
     /// <p> The list of HIT elements returned by the query.</p>
     #[serde(rename = "HITs")]
@@ -760,6 +1189,7 @@ pub struct ListHITsForQualificationTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHITsRequest {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -770,8 +1200,24 @@ pub struct ListHITsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListHITsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHITsResponse {
     /// <p> The list of HIT elements returned by the query.</p>
     #[serde(rename = "HITs")]
@@ -787,6 +1233,7 @@ pub struct ListHITsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQualificationRequestsRequest {
     /// <p> The maximum number of results to return in a single call. </p>
     #[serde(rename = "MaxResults")]
@@ -801,8 +1248,37 @@ pub struct ListQualificationRequestsRequest {
     pub qualification_type_id: Option<String>,
 }
 
+impl Validate for ListQualificationRequestsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.qualification_type_id {
+            errors.check_length(
+                &field_path(path, "qualification_type_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "qualification_type_id"),
+                &value,
+                "^[A-Z0-9]+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListQualificationRequestsResponse {
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -818,6 +1294,7 @@ pub struct ListQualificationRequestsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQualificationTypesRequest {
     /// <p> The maximum number of results to return in a single call. </p>
     #[serde(rename = "MaxResults")]
@@ -839,8 +1316,24 @@ pub struct ListQualificationTypesRequest {
     pub query: Option<String>,
 }
 
+impl Validate for ListQualificationTypesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListQualificationTypesResponse {
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -856,6 +1349,7 @@ pub struct ListQualificationTypesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReviewPolicyResultsForHITRequest {
     /// <p>The unique identifier of the HIT to retrieve review results for.</p>
     #[serde(rename = "HITId")]
@@ -882,8 +1376,31 @@ pub struct ListReviewPolicyResultsForHITRequest {
     pub retrieve_results: Option<bool>,
 }
 
+impl Validate for ListReviewPolicyResultsForHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListReviewPolicyResultsForHITResponse {
     /// <p> The name of the Assignment-level Review Policy. This contains only the PolicyName element. </p>
     #[serde(rename = "AssignmentReviewPolicy")]
@@ -911,6 +1428,7 @@ pub struct ListReviewPolicyResultsForHITResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReviewableHITsRequest {
     /// <p> The ID of the HIT type of the HITs to consider for the query. If not specified, all HITs for the Reviewer are considered </p>
     #[serde(rename = "HITTypeId")]
@@ -930,8 +1448,33 @@ pub struct ListReviewableHITsRequest {
     pub status: Option<String>,
 }
 
+impl Validate for ListReviewableHITsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hit_type_id {
+            errors.check_length(
+                &field_path(path, "hit_type_id"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "hit_type_id"), &value, "^[A-Z0-9]+$");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListReviewableHITsResponse {
     /// <p> The list of HIT elements returned by the query.</p>
     #[serde(rename = "HITs")]
@@ -947,6 +1490,7 @@ pub struct ListReviewableHITsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWorkerBlocksRequest {
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -957,8 +1501,24 @@ pub struct ListWorkerBlocksRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWorkerBlocksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWorkerBlocksResponse {
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -974,6 +1534,7 @@ pub struct ListWorkerBlocksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWorkersWithQualificationTypeRequest {
     /// <p> Limit the number of results returned. </p>
     #[serde(rename = "MaxResults")]
@@ -992,8 +1553,35 @@ pub struct ListWorkersWithQualificationTypeRequest {
     pub status: Option<String>,
 }
 
+impl Validate for ListWorkersWithQualificationTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWorkersWithQualificationTypeResponse {
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1020,8 +1608,28 @@ pub struct Locale {
     pub subdivision: Option<String>,
 }
 
+impl Validate for Locale {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "country"),
+            self.country.chars().count(),
+            Some(2),
+            Some(2),
+        );
+        if let Some(ref value) = self.subdivision {
+            errors.check_length(
+                &field_path(path, "subdivision"),
+                value.chars().count(),
+                Some(2),
+                Some(2),
+            );
+        }
+    }
+}
+
 /// <p>The NotificationSpecification data structure describes a HIT event notification for a HIT type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationSpecification {
     /// <p><p> The target for notification messages. The Destination’s format is determined by the specified Transport: </p> <ul> <li> <p>When Transport is Email, the Destination is your email address.</p> </li> <li> <p>When Transport is SQS, the Destination is your queue URL.</p> </li> <li> <p>When Transport is SNS, the Destination is the ARN of your topic.</p> </li> </ul></p>
     #[serde(rename = "Destination")]
@@ -1039,7 +1647,7 @@ pub struct NotificationSpecification {
 
 /// <p> When MTurk encounters an issue with notifying the Workers you specified, it returns back this object with failure details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotifyWorkersFailureStatus {
     /// <p> Encoded value for the failure type. </p>
     #[serde(rename = "NotifyWorkersFailureCode")]
@@ -1056,6 +1664,7 @@ pub struct NotifyWorkersFailureStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotifyWorkersRequest {
     /// <p>The text of the email message to send. Can include up to 4,096 characters</p>
     #[serde(rename = "MessageText")]
@@ -1068,8 +1677,18 @@ pub struct NotifyWorkersRequest {
     pub worker_ids: Vec<String>,
 }
 
+impl Validate for NotifyWorkersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.worker_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "worker_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            errors.check_pattern(&item_path, &item, "^A[A-Z0-9]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotifyWorkersResponse {
     /// <p> When MTurk sends notifications to the list of Workers, it returns back any failures it encounters in this list of NotifyWorkersFailureStatus objects. </p>
     #[serde(rename = "NotifyWorkersFailureStatuses")]
@@ -1109,7 +1728,7 @@ pub struct PolicyParameter {
 
 /// <p>The Qualification data structure represents a Qualification assigned to a user, including the Qualification type and the value (score).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Qualification {
     /// <p> The date and time the Qualification was granted to the Worker. If the Worker's Qualification was revoked, and then re-granted based on a new Qualification request, GrantTime is the date and time of the last call to the AcceptQualificationRequest operation.</p>
     #[serde(rename = "GrantTime")]
@@ -1138,7 +1757,7 @@ pub struct Qualification {
 
 /// <p> The QualificationRequest data structure represents a request a Worker has made for a Qualification. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QualificationRequest {
     /// <p> The Worker's answers for the Qualification type's test contained in a QuestionFormAnswers document, if the type has a test and the Worker has submitted answers. If the Worker does not provide any answers, Answer may be empty. </p>
     #[serde(rename = "Answer")]
@@ -1189,9 +1808,20 @@ pub struct QualificationRequirement {
     pub qualification_type_id: String,
 }
 
+impl Validate for QualificationRequirement {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.locale_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "locale_values"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p> The QualificationType data structure represents a Qualification type, a description of a property of a Worker that must match the requirements of a HIT for the Worker to be able to accept the HIT. The type also describes how a Worker can obtain a Qualification of that type, such as through a Qualification test. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QualificationType {
     /// <p>The answers to the Qualification test specified in the Test parameter.</p>
     #[serde(rename = "AnswerKey")]
@@ -1248,6 +1878,7 @@ pub struct QualificationType {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RejectAssignmentRequest {
     /// <p> The ID of the assignment. The assignment must correspond to a HIT created by the Requester. </p>
     #[serde(rename = "AssignmentId")]
@@ -1257,11 +1888,28 @@ pub struct RejectAssignmentRequest {
     pub requester_feedback: String,
 }
 
+impl Validate for RejectAssignmentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assignment_id"),
+            self.assignment_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "assignment_id"),
+            &self.assignment_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectAssignmentResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RejectQualificationRequestRequest {
     /// <p> The ID of the Qualification request, as returned by the <code>ListQualificationRequests</code> operation. </p>
     #[serde(rename = "QualificationRequestId")]
@@ -1273,12 +1921,12 @@ pub struct RejectQualificationRequestRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectQualificationRequestResponse {}
 
 /// <p> Both the AssignmentReviewReport and the HITReviewReport elements contains the ReviewActionDetail data structure. This structure is returned multiple times for each action specified in the Review Policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReviewActionDetail {
     /// <p>The unique identifier for the action.</p>
     #[serde(rename = "ActionId")]
@@ -1328,7 +1976,7 @@ pub struct ReviewPolicy {
 
 /// <p> Contains both ReviewResult and ReviewAction elements for a particular HIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReviewReport {
     /// <p> A list of ReviewAction objects for each action specified in the Review Policy. </p>
     #[serde(rename = "ReviewActions")]
@@ -1342,7 +1990,7 @@ pub struct ReviewReport {
 
 /// <p> This data structure is returned multiple times for each result specified in the Review Policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReviewResultDetail {
     /// <p> A unique identifier of the Review action result. </p>
     #[serde(rename = "ActionId")]
@@ -1371,6 +2019,7 @@ pub struct ReviewResultDetail {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendBonusRequest {
     /// <p>The ID of the assignment for which this bonus is paid.</p>
     #[serde(rename = "AssignmentId")]
@@ -1390,11 +2039,52 @@ pub struct SendBonusRequest {
     pub worker_id: String,
 }
 
+impl Validate for SendBonusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "assignment_id"),
+            self.assignment_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "assignment_id"),
+            &self.assignment_id,
+            "^[A-Z0-9]+$",
+        );
+        errors.check_pattern(
+            &field_path(path, "bonus_amount"),
+            &self.bonus_amount,
+            "^[0-9]+(\\.)?[0-9]{0,2}$",
+        );
+        if let Some(ref value) = self.unique_request_token {
+            errors.check_length(
+                &field_path(path, "unique_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "worker_id"),
+            self.worker_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "worker_id"),
+            &self.worker_id,
+            "^A[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendBonusResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendTestEventNotificationRequest {
     /// <p> The notification specification to test. This value is identical to the value you would provide to the UpdateNotificationSettings operation when you establish the notification specification for a HIT type. </p>
     #[serde(rename = "Notification")]
@@ -1405,10 +2095,11 @@ pub struct SendTestEventNotificationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendTestEventNotificationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateExpirationForHITRequest {
     /// <p> The date and time at which you want the HIT to expire </p>
     #[serde(rename = "ExpireAt")]
@@ -1418,11 +2109,24 @@ pub struct UpdateExpirationForHITRequest {
     pub hit_id: String,
 }
 
+impl Validate for UpdateExpirationForHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateExpirationForHITResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateHITReviewStatusRequest {
     /// <p> The ID of the HIT to update. </p>
     #[serde(rename = "HITId")]
@@ -1433,11 +2137,24 @@ pub struct UpdateHITReviewStatusRequest {
     pub revert: Option<bool>,
 }
 
+impl Validate for UpdateHITReviewStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateHITReviewStatusResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateHITTypeOfHITRequest {
     /// <p>The HIT to update.</p>
     #[serde(rename = "HITId")]
@@ -1447,11 +2164,35 @@ pub struct UpdateHITTypeOfHITRequest {
     pub hit_type_id: String,
 }
 
+impl Validate for UpdateHITTypeOfHITRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_id"),
+            self.hit_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "hit_id"), &self.hit_id, "^[A-Z0-9]+$");
+        errors.check_length(
+            &field_path(path, "hit_type_id"),
+            self.hit_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "hit_type_id"),
+            &self.hit_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateHITTypeOfHITResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNotificationSettingsRequest {
     /// <p> Specifies whether notifications are sent for HITs of this HIT type, according to the notification specification. You must specify either the Notification parameter or the Active parameter for the call to UpdateNotificationSettings to succeed. </p>
     #[serde(rename = "Active")]
@@ -1466,11 +2207,28 @@ pub struct UpdateNotificationSettingsRequest {
     pub notification: Option<NotificationSpecification>,
 }
 
+impl Validate for UpdateNotificationSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hit_type_id"),
+            self.hit_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "hit_type_id"),
+            &self.hit_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNotificationSettingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateQualificationTypeRequest {
     /// <p>The answers to the Qualification test specified in the Test parameter, in the form of an AnswerKey data structure.</p>
     #[serde(rename = "AnswerKey")]
@@ -1509,8 +2267,24 @@ pub struct UpdateQualificationTypeRequest {
     pub test_duration_in_seconds: Option<i64>,
 }
 
+impl Validate for UpdateQualificationTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "qualification_type_id"),
+            self.qualification_type_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "qualification_type_id"),
+            &self.qualification_type_id,
+            "^[A-Z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateQualificationTypeResponse {
     /// <p> Contains a QualificationType data structure.</p>
     #[serde(rename = "QualificationType")]
@@ -1520,7 +2294,7 @@ pub struct UpdateQualificationTypeResponse {
 
 /// <p> The WorkerBlock data structure represents a Worker who has been blocked. It has two elements: the WorkerId and the Reason for the block. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkerBlock {
     /// <p> A message explaining the reason the Worker was blocked. </p>
     #[serde(rename = "Reason")]
@@ -1557,7 +2331,12 @@ impl AcceptQualificationRequestError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1596,7 +2375,12 @@ impl ApproveAssignmentError {
                 "ServiceFault" => {
                     return RusotoError::Service(ApproveAssignmentError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1641,7 +2425,12 @@ impl AssociateQualificationWithWorkerError {
                         AssociateQualificationWithWorkerError::ServiceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1686,7 +2475,12 @@ impl CreateAdditionalAssignmentsForHITError {
                         CreateAdditionalAssignmentsForHITError::ServiceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1725,7 +2519,12 @@ impl CreateHITError {
                 "ServiceFault" => {
                     return RusotoError::Service(CreateHITError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1764,7 +2563,12 @@ impl CreateHITTypeError {
                 "ServiceFault" => {
                     return RusotoError::Service(CreateHITTypeError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1803,7 +2607,12 @@ impl CreateHITWithHITTypeError {
                 "ServiceFault" => {
                     return RusotoError::Service(CreateHITWithHITTypeError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1846,7 +2655,12 @@ impl CreateQualificationTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1885,7 +2699,12 @@ impl CreateWorkerBlockError {
                 "ServiceFault" => {
                     return RusotoError::Service(CreateWorkerBlockError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1924,7 +2743,12 @@ impl DeleteHITError {
                 "ServiceFault" => {
                     return RusotoError::Service(DeleteHITError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1967,7 +2791,12 @@ impl DeleteQualificationTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2006,7 +2835,12 @@ impl DeleteWorkerBlockError {
                 "ServiceFault" => {
                     return RusotoError::Service(DeleteWorkerBlockError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2051,7 +2885,12 @@ impl DisassociateQualificationFromWorkerError {
                         DisassociateQualificationFromWorkerError::ServiceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2090,7 +2929,12 @@ impl GetAccountBalanceError {
                 "ServiceFault" => {
                     return RusotoError::Service(GetAccountBalanceError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2129,7 +2973,12 @@ impl GetAssignmentError {
                 "ServiceFault" => {
                     return RusotoError::Service(GetAssignmentError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2168,7 +3017,12 @@ impl GetFileUploadURLError {
                 "ServiceFault" => {
                     return RusotoError::Service(GetFileUploadURLError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2203,7 +3057,12 @@ impl GetHITError {
             match err.typ.as_str() {
                 "RequestError" => return RusotoError::Service(GetHITError::RequestError(err.msg)),
                 "ServiceFault" => return RusotoError::Service(GetHITError::ServiceFault(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2242,7 +3101,12 @@ impl GetQualificationScoreError {
                 "ServiceFault" => {
                     return RusotoError::Service(GetQualificationScoreError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2281,7 +3145,12 @@ impl GetQualificationTypeError {
                 "ServiceFault" => {
                     return RusotoError::Service(GetQualificationTypeError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2320,7 +3189,12 @@ impl ListAssignmentsForHITError {
                 "ServiceFault" => {
                     return RusotoError::Service(ListAssignmentsForHITError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2359,7 +3233,12 @@ impl ListBonusPaymentsError {
                 "ServiceFault" => {
                     return RusotoError::Service(ListBonusPaymentsError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2398,7 +3277,12 @@ impl ListHITsError {
                 "ServiceFault" => {
                     return RusotoError::Service(ListHITsError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2443,7 +3327,12 @@ impl ListHITsForQualificationTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2486,7 +3375,12 @@ impl ListQualificationRequestsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2525,7 +3419,12 @@ impl ListQualificationTypesError {
                 "ServiceFault" => {
                     return RusotoError::Service(ListQualificationTypesError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2570,7 +3469,12 @@ impl ListReviewPolicyResultsForHITError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2609,7 +3513,12 @@ impl ListReviewableHITsError {
                 "ServiceFault" => {
                     return RusotoError::Service(ListReviewableHITsError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2648,7 +3557,12 @@ impl ListWorkerBlocksError {
                 "ServiceFault" => {
                     return RusotoError::Service(ListWorkerBlocksError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2693,7 +3607,12 @@ impl ListWorkersWithQualificationTypeError {
                         ListWorkersWithQualificationTypeError::ServiceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2732,7 +3651,12 @@ impl NotifyWorkersError {
                 "ServiceFault" => {
                     return RusotoError::Service(NotifyWorkersError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2771,7 +3695,12 @@ impl RejectAssignmentError {
                 "ServiceFault" => {
                     return RusotoError::Service(RejectAssignmentError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2816,7 +3745,12 @@ impl RejectQualificationRequestError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2855,7 +3789,12 @@ impl SendBonusError {
                 "ServiceFault" => {
                     return RusotoError::Service(SendBonusError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2898,7 +3837,12 @@ impl SendTestEventNotificationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2937,7 +3881,12 @@ impl UpdateExpirationForHITError {
                 "ServiceFault" => {
                     return RusotoError::Service(UpdateExpirationForHITError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2976,7 +3925,12 @@ impl UpdateHITReviewStatusError {
                 "ServiceFault" => {
                     return RusotoError::Service(UpdateHITReviewStatusError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3015,7 +3969,12 @@ impl UpdateHITTypeOfHITError {
                 "ServiceFault" => {
                     return RusotoError::Service(UpdateHITTypeOfHITError::ServiceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3060,7 +4019,12 @@ impl UpdateNotificationSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3103,7 +4067,12 @@ impl UpdateQualificationTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3358,138 +4327,27 @@ pub trait MechanicalTurk {
         input: UpdateQualificationTypeRequest,
     ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError>;
 }
-/// A client for the Amazon MTurk API.
-#[derive(Clone)]
-pub struct MechanicalTurkClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl MechanicalTurkClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> MechanicalTurkClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> MechanicalTurkClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkClient {
-        MechanicalTurkClient { client, region }
-    }
-}
-
-impl MechanicalTurk for MechanicalTurkClient {
-    /// <p> The <code>AcceptQualificationRequest</code> operation approves a Worker's request for a Qualification. </p> <p> Only the owner of the Qualification type can grant a Qualification request for that type. </p> <p> A successful request for the <code>AcceptQualificationRequest</code> operation returns with no errors and an empty body. </p>
+const _: Option<&dyn MechanicalTurk> = None;
+impl<'a, T: MechanicalTurk + ?Sized> MechanicalTurk for &'a T {
     fn accept_qualification_request(
         &self,
         input: AcceptQualificationRequestRequest,
     ) -> RusotoFuture<AcceptQualificationRequestResponse, AcceptQualificationRequestError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.AcceptQualificationRequest",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptQualificationRequestResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AcceptQualificationRequestError::from_response(response))
-                }))
-            }
-        })
+        (**self).accept_qualification_request(input)
     }
-
-    /// <p> The <code>ApproveAssignment</code> operation approves the results of a completed assignment. </p> <p> Approving an assignment initiates two payments from the Requester's Amazon.com account </p> <ul> <li> <p> The Worker who submitted the results is paid the reward specified in the HIT. </p> </li> <li> <p> Amazon Mechanical Turk fees are debited. </p> </li> </ul> <p> If the Requester's account does not have adequate funds for these payments, the call to ApproveAssignment returns an exception, and the approval is not processed. You can include an optional feedback message with the approval, which the Worker can see in the Status section of the web site. </p> <p> You can also call this operation for assignments that were previous rejected and approve them by explicitly overriding the previous rejection. This only works on rejected assignments that were submitted within the previous 30 days and only if the assignment's related HIT has not been deleted. </p>
     fn approve_assignment(
         &self,
         input: ApproveAssignmentRequest,
     ) -> RusotoFuture<ApproveAssignmentResponse, ApproveAssignmentError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ApproveAssignment",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ApproveAssignmentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ApproveAssignmentError::from_response(response))),
-                )
-            }
-        })
+        (**self).approve_assignment(input)
     }
-
-    /// <p><p> The <code>AssociateQualificationWithWorker</code> operation gives a Worker a Qualification. <code>AssociateQualificationWithWorker</code> does not require that the Worker submit a Qualification request. It gives the Qualification directly to the Worker. </p> <p> You can only assign a Qualification of a Qualification type that you created (using the <code>CreateQualificationType</code> operation). </p> <note> <p> Note: <code>AssociateQualificationWithWorker</code> does not affect any pending Qualification requests for the Qualification by the Worker. If you assign a Qualification to a Worker, then later grant a Qualification request made by the Worker, the granting of the request may modify the Qualification score. To resolve a pending Qualification request without affecting the Qualification the Worker already has, reject the request with the <code>RejectQualificationRequest</code> operation. </p> </note></p>
     fn associate_qualification_with_worker(
         &self,
         input: AssociateQualificationWithWorkerRequest,
     ) -> RusotoFuture<AssociateQualificationWithWorkerResponse, AssociateQualificationWithWorkerError>
     {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.AssociateQualificationWithWorker",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateQualificationWithWorkerResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateQualificationWithWorkerError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_qualification_with_worker(input)
     }
-
-    /// <p><p> The <code>CreateAdditionalAssignmentsForHIT</code> operation increases the maximum number of assignments of an existing HIT. </p> <p> To extend the maximum number of assignments, specify the number of additional assignments.</p> <note> <ul> <li> <p>HITs created with fewer than 10 assignments cannot be extended to have 10 or more assignments. Attempting to add assignments in a way that brings the total number of assignments for a HIT from fewer than 10 assignments to 10 or more assignments will result in an <code>AWS.MechanicalTurk.InvalidMaximumAssignmentsIncrease</code> exception.</p> </li> <li> <p>HITs that were created before July 22, 2015 cannot be extended. Attempting to extend HITs that were created before July 22, 2015 will result in an <code>AWS.MechanicalTurk.HITTooOldForExtension</code> exception. </p> </li> </ul> </note></p>
     fn create_additional_assignments_for_hit(
         &self,
         input: CreateAdditionalAssignmentsForHITRequest,
@@ -3497,276 +4355,56 @@ impl MechanicalTurk for MechanicalTurkClient {
         CreateAdditionalAssignmentsForHITResponse,
         CreateAdditionalAssignmentsForHITError,
     > {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.CreateAdditionalAssignmentsForHIT",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAdditionalAssignmentsForHITResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateAdditionalAssignmentsForHITError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_additional_assignments_for_hit(input)
     }
-
-    /// <p><p>The <code>CreateHIT</code> operation creates a new Human Intelligence Task (HIT). The new HIT is made available for Workers to find and accept on the Amazon Mechanical Turk website. </p> <p> This operation allows you to specify a new HIT by passing in values for the properties of the HIT, such as its title, reward amount and number of assignments. When you pass these values to <code>CreateHIT</code>, a new HIT is created for you, with a new <code>HITTypeID</code>. The HITTypeID can be used to create additional HITs in the future without needing to specify common parameters such as the title, description and reward amount each time.</p> <p> An alternative way to create HITs is to first generate a HITTypeID using the <code>CreateHITType</code> operation and then call the <code>CreateHITWithHITType</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHIT also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>.</p> </note></p>
     fn create_hit(
         &self,
         input: CreateHITRequest,
     ) -> RusotoFuture<CreateHITResponse, CreateHITError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.CreateHIT");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHITResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateHITError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_hit(input)
     }
-
-    /// <p> The <code>CreateHITType</code> operation creates a new HIT type. This operation allows you to define a standard set of HIT properties to use when creating HITs. If you register a HIT type with values that match an existing HIT type, the HIT type ID of the existing type will be returned. </p>
     fn create_hit_type(
         &self,
         input: CreateHITTypeRequest,
     ) -> RusotoFuture<CreateHITTypeResponse, CreateHITTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.CreateHITType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHITTypeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateHITTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_hit_type(input)
     }
-
-    /// <p><p> The <code>CreateHITWithHITType</code> operation creates a new Human Intelligence Task (HIT) using an existing HITTypeID generated by the <code>CreateHITType</code> operation. </p> <p> This is an alternative way to create HITs from the <code>CreateHIT</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHITWithHITType also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>. </p> </note></p>
     fn create_hit_with_hit_type(
         &self,
         input: CreateHITWithHITTypeRequest,
     ) -> RusotoFuture<CreateHITWithHITTypeResponse, CreateHITWithHITTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.CreateHITWithHITType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHITWithHITTypeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateHITWithHITTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_hit_with_hit_type(input)
     }
-
-    /// <p> The <code>CreateQualificationType</code> operation creates a new Qualification type, which is represented by a <code>QualificationType</code> data structure. </p>
     fn create_qualification_type(
         &self,
         input: CreateQualificationTypeRequest,
     ) -> RusotoFuture<CreateQualificationTypeResponse, CreateQualificationTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.CreateQualificationType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateQualificationTypeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateQualificationTypeError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_qualification_type(input)
     }
-
-    /// <p>The <code>CreateWorkerBlock</code> operation allows you to prevent a Worker from working on your HITs. For example, you can block a Worker who is producing poor quality work. You can block up to 100,000 Workers.</p>
     fn create_worker_block(
         &self,
         input: CreateWorkerBlockRequest,
     ) -> RusotoFuture<CreateWorkerBlockResponse, CreateWorkerBlockError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.CreateWorkerBlock",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWorkerBlockResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateWorkerBlockError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_worker_block(input)
     }
-
-    /// <p><p> The <code>DeleteHIT</code> operation is used to delete HIT that is no longer needed. Only the Requester who created the HIT can delete it. </p> <p> You can only dispose of HITs that are in the <code>Reviewable</code> state, with all of their submitted assignments already either approved or rejected. If you call the DeleteHIT operation on a HIT that is not in the <code>Reviewable</code> state (for example, that has not expired, or still has active assignments), or on a HIT that is Reviewable but without all of its submitted assignments already approved or rejected, the service will return an error. </p> <note> <ul> <li> <p> HITs are automatically disposed of after 120 days. </p> </li> <li> <p> After you dispose of a HIT, you can no longer approve the HIT&#39;s rejected assignments. </p> </li> <li> <p> Disposed HITs are not returned in results for the ListHITs operation. </p> </li> <li> <p> Disposing HITs can improve the performance of operations such as ListReviewableHITs and ListHITs. </p> </li> </ul> </note></p>
     fn delete_hit(
         &self,
         input: DeleteHITRequest,
     ) -> RusotoFuture<DeleteHITResponse, DeleteHITError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.DeleteHIT");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteHITResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteHITError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_hit(input)
     }
-
-    /// <p><p> The <code>DeleteQualificationType</code> deletes a Qualification type and deletes any HIT types that are associated with the Qualification type. </p> <p>This operation does not revoke Qualifications already assigned to Workers because the Qualifications might be needed for active HITs. If there are any pending requests for the Qualification type, Amazon Mechanical Turk rejects those requests. After you delete a Qualification type, you can no longer use it to create HITs or HIT types.</p> <note> <p>DeleteQualificationType must wait for all the HITs that use the deleted Qualification type to be deleted before completing. It may take up to 48 hours before DeleteQualificationType completes and the unique name of the Qualification type is available for reuse with CreateQualificationType.</p> </note></p>
     fn delete_qualification_type(
         &self,
         input: DeleteQualificationTypeRequest,
     ) -> RusotoFuture<DeleteQualificationTypeResponse, DeleteQualificationTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.DeleteQualificationType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteQualificationTypeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteQualificationTypeError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_qualification_type(input)
     }
-
-    /// <p>The <code>DeleteWorkerBlock</code> operation allows you to reinstate a blocked Worker to work on your HITs. This operation reverses the effects of the CreateWorkerBlock operation. You need the Worker ID to use this operation. If the Worker ID is missing or invalid, this operation fails and returns the message “WorkerId is invalid.” If the specified Worker is not blocked, this operation returns successfully.</p>
     fn delete_worker_block(
         &self,
         input: DeleteWorkerBlockRequest,
     ) -> RusotoFuture<DeleteWorkerBlockResponse, DeleteWorkerBlockError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.DeleteWorkerBlock",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWorkerBlockResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteWorkerBlockError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_worker_block(input)
     }
-
-    /// <p> The <code>DisassociateQualificationFromWorker</code> revokes a previously granted Qualification from a user. </p> <p> You can provide a text message explaining why the Qualification was revoked. The user who had the Qualification can see this message. </p>
     fn disassociate_qualification_from_worker(
         &self,
         input: DisassociateQualificationFromWorkerRequest,
@@ -3774,817 +4412,3531 @@ impl MechanicalTurk for MechanicalTurkClient {
         DisassociateQualificationFromWorkerResponse,
         DisassociateQualificationFromWorkerError,
     > {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.DisassociateQualificationFromWorker",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateQualificationFromWorkerResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateQualificationFromWorkerError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_qualification_from_worker(input)
     }
-
-    /// <p>The <code>GetAccountBalance</code> operation retrieves the amount of money in your Amazon Mechanical Turk account.</p>
     fn get_account_balance(
         &self,
     ) -> RusotoFuture<GetAccountBalanceResponse, GetAccountBalanceError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.GetAccountBalance",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAccountBalanceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountBalanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_account_balance()
     }
-
-    /// <p> The <code>GetAssignment</code> operation retrieves the details of the specified Assignment. </p>
     fn get_assignment(
         &self,
         input: GetAssignmentRequest,
     ) -> RusotoFuture<GetAssignmentResponse, GetAssignmentError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.GetAssignment",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAssignmentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAssignmentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_assignment(input)
     }
-
-    /// <p> The <code>GetFileUploadURL</code> operation generates and returns a temporary URL. You use the temporary URL to retrieve a file uploaded by a Worker as an answer to a FileUploadAnswer question for a HIT. The temporary URL is generated the instant the GetFileUploadURL operation is called, and is valid for 60 seconds. You can get a temporary file upload URL any time until the HIT is disposed. After the HIT is disposed, any uploaded files are deleted, and cannot be retrieved. Pending Deprecation on December 12, 2017. The Answer Specification structure will no longer support the <code>FileUploadAnswer</code> element to be used for the QuestionForm data structure. Instead, we recommend that Requesters who want to create HITs asking Workers to upload files to use Amazon S3. </p>
     fn get_file_upload_url(
         &self,
         input: GetFileUploadURLRequest,
     ) -> RusotoFuture<GetFileUploadURLResponse, GetFileUploadURLError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.GetFileUploadURL",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFileUploadURLResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFileUploadURLError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_file_upload_url(input)
     }
-
-    /// <p> The <code>GetHIT</code> operation retrieves the details of the specified HIT. </p>
     fn get_hit(&self, input: GetHITRequest) -> RusotoFuture<GetHITResponse, GetHITError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.GetHIT");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetHITResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetHITError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_hit(input)
     }
-
-    /// <p> The <code>GetQualificationScore</code> operation returns the value of a Worker's Qualification for a given Qualification type. </p> <p> To get a Worker's Qualification, you must know the Worker's ID. The Worker's ID is included in the assignment data returned by the <code>ListAssignmentsForHIT</code> operation. </p> <p>Only the owner of a Qualification type can query the value of a Worker's Qualification of that type.</p>
     fn get_qualification_score(
         &self,
         input: GetQualificationScoreRequest,
     ) -> RusotoFuture<GetQualificationScoreResponse, GetQualificationScoreError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.GetQualificationScore",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetQualificationScoreResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetQualificationScoreError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_qualification_score(input)
     }
-
-    /// <p> The <code>GetQualificationType</code>operation retrieves information about a Qualification type using its ID. </p>
     fn get_qualification_type(
         &self,
         input: GetQualificationTypeRequest,
     ) -> RusotoFuture<GetQualificationTypeResponse, GetQualificationTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.GetQualificationType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetQualificationTypeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetQualificationTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_qualification_type(input)
     }
-
-    /// <p> The <code>ListAssignmentsForHIT</code> operation retrieves completed assignments for a HIT. You can use this operation to retrieve the results for a HIT. </p> <p> You can get assignments for a HIT at any time, even if the HIT is not yet Reviewable. If a HIT requested multiple assignments, and has received some results but has not yet become Reviewable, you can still retrieve the partial results with this operation. </p> <p> Use the AssignmentStatus parameter to control which set of assignments for a HIT are returned. The ListAssignmentsForHIT operation can return submitted assignments awaiting approval, or it can return assignments that have already been approved or rejected. You can set AssignmentStatus=Approved,Rejected to get assignments that have already been approved and rejected together in one result set. </p> <p> Only the Requester who created the HIT can retrieve the assignments for that HIT. </p> <p> Results are sorted and divided into numbered pages and the operation returns a single page of results. You can use the parameters of the operation to control sorting and pagination. </p>
     fn list_assignments_for_hit(
         &self,
         input: ListAssignmentsForHITRequest,
     ) -> RusotoFuture<ListAssignmentsForHITResponse, ListAssignmentsForHITError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListAssignmentsForHIT",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssignmentsForHITResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAssignmentsForHITError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_assignments_for_hit(input)
     }
-
-    /// <p> The <code>ListBonusPayments</code> operation retrieves the amounts of bonuses you have paid to Workers for a given HIT or assignment. </p>
     fn list_bonus_payments(
         &self,
         input: ListBonusPaymentsRequest,
     ) -> RusotoFuture<ListBonusPaymentsResponse, ListBonusPaymentsError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListBonusPayments",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBonusPaymentsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBonusPaymentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_bonus_payments(input)
     }
-
-    /// <p> The <code>ListHITs</code> operation returns all of a Requester's HITs. The operation returns HITs of any status, except for HITs that have been deleted of with the DeleteHIT operation or that have been auto-deleted. </p>
     fn list_hi_ts(&self, input: ListHITsRequest) -> RusotoFuture<ListHITsResponse, ListHITsError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.ListHITs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHITsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListHITsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_hi_ts(input)
     }
-
-    /// <p> The <code>ListHITsForQualificationType</code> operation returns the HITs that use the given Qualification type for a Qualification requirement. The operation returns HITs of any status, except for HITs that have been deleted with the <code>DeleteHIT</code> operation or that have been auto-deleted. </p>
     fn list_hi_ts_for_qualification_type(
         &self,
         input: ListHITsForQualificationTypeRequest,
     ) -> RusotoFuture<ListHITsForQualificationTypeResponse, ListHITsForQualificationTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListHITsForQualificationType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHITsForQualificationTypeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListHITsForQualificationTypeError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_hi_ts_for_qualification_type(input)
     }
-
-    /// <p> The <code>ListQualificationRequests</code> operation retrieves requests for Qualifications of a particular Qualification type. The owner of the Qualification type calls this operation to poll for pending requests, and accepts them using the AcceptQualification operation. </p>
     fn list_qualification_requests(
         &self,
         input: ListQualificationRequestsRequest,
     ) -> RusotoFuture<ListQualificationRequestsResponse, ListQualificationRequestsError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListQualificationRequests",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListQualificationRequestsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListQualificationRequestsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_qualification_requests(input)
     }
-
-    /// <p> The <code>ListQualificationTypes</code> operation returns a list of Qualification types, filtered by an optional search term. </p>
     fn list_qualification_types(
         &self,
         input: ListQualificationTypesRequest,
     ) -> RusotoFuture<ListQualificationTypesResponse, ListQualificationTypesError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListQualificationTypes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListQualificationTypesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListQualificationTypesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_qualification_types(input)
     }
-
-    /// <p> The <code>ListReviewPolicyResultsForHIT</code> operation retrieves the computed results and the actions taken in the course of executing your Review Policies for a given HIT. For information about how to specify Review Policies when you call CreateHIT, see Review Policies. The ListReviewPolicyResultsForHIT operation can return results for both Assignment-level and HIT-level review results. </p>
     fn list_review_policy_results_for_hit(
         &self,
         input: ListReviewPolicyResultsForHITRequest,
     ) -> RusotoFuture<ListReviewPolicyResultsForHITResponse, ListReviewPolicyResultsForHITError>
     {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListReviewPolicyResultsForHIT",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListReviewPolicyResultsForHITResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListReviewPolicyResultsForHITError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_review_policy_results_for_hit(input)
     }
-
-    /// <p> The <code>ListReviewableHITs</code> operation retrieves the HITs with Status equal to Reviewable or Status equal to Reviewing that belong to the Requester calling the operation. </p>
     fn list_reviewable_hi_ts(
         &self,
         input: ListReviewableHITsRequest,
     ) -> RusotoFuture<ListReviewableHITsResponse, ListReviewableHITsError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListReviewableHITs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListReviewableHITsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListReviewableHITsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_reviewable_hi_ts(input)
     }
-
-    /// <p>The <code>ListWorkersBlocks</code> operation retrieves a list of Workers who are blocked from working on your HITs.</p>
     fn list_worker_blocks(
         &self,
         input: ListWorkerBlocksRequest,
     ) -> RusotoFuture<ListWorkerBlocksResponse, ListWorkerBlocksError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListWorkerBlocks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWorkerBlocksResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListWorkerBlocksError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_worker_blocks(input)
     }
-
-    /// <p> The <code>ListWorkersWithQualificationType</code> operation returns all of the Workers that have been associated with a given Qualification type. </p>
     fn list_workers_with_qualification_type(
         &self,
         input: ListWorkersWithQualificationTypeRequest,
     ) -> RusotoFuture<ListWorkersWithQualificationTypeResponse, ListWorkersWithQualificationTypeError>
     {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.ListWorkersWithQualificationType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWorkersWithQualificationTypeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListWorkersWithQualificationTypeError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_workers_with_qualification_type(input)
     }
-
-    /// <p> The <code>NotifyWorkers</code> operation sends an email to one or more Workers that you specify with the Worker ID. You can specify up to 100 Worker IDs to send the same message with a single call to the NotifyWorkers operation. The NotifyWorkers operation will send a notification email to a Worker only if you have previously approved or rejected work from the Worker. </p>
     fn notify_workers(
         &self,
         input: NotifyWorkersRequest,
     ) -> RusotoFuture<NotifyWorkersResponse, NotifyWorkersError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.NotifyWorkers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<NotifyWorkersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(NotifyWorkersError::from_response(response))),
-                )
-            }
-        })
+        (**self).notify_workers(input)
     }
-
-    /// <p> The <code>RejectAssignment</code> operation rejects the results of a completed assignment. </p> <p> You can include an optional feedback message with the rejection, which the Worker can see in the Status section of the web site. When you include a feedback message with the rejection, it helps the Worker understand why the assignment was rejected, and can improve the quality of the results the Worker submits in the future. </p> <p> Only the Requester who created the HIT can reject an assignment for the HIT. </p>
     fn reject_assignment(
         &self,
         input: RejectAssignmentRequest,
     ) -> RusotoFuture<RejectAssignmentResponse, RejectAssignmentError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.RejectAssignment",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RejectAssignmentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RejectAssignmentError::from_response(response))),
-                )
-            }
-        })
+        (**self).reject_assignment(input)
     }
-
-    /// <p> The <code>RejectQualificationRequest</code> operation rejects a user's request for a Qualification. </p> <p> You can provide a text message explaining why the request was rejected. The Worker who made the request can see this message.</p>
     fn reject_qualification_request(
         &self,
         input: RejectQualificationRequestRequest,
     ) -> RusotoFuture<RejectQualificationRequestResponse, RejectQualificationRequestError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.RejectQualificationRequest",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        (**self).reject_qualification_request(input)
+    }
+    fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> RusotoFuture<SendBonusResponse, SendBonusError> {
+        (**self).send_bonus(input)
+    }
+    fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> RusotoFuture<SendTestEventNotificationResponse, SendTestEventNotificationError> {
+        (**self).send_test_event_notification(input)
+    }
+    fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> RusotoFuture<UpdateExpirationForHITResponse, UpdateExpirationForHITError> {
+        (**self).update_expiration_for_hit(input)
+    }
+    fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> RusotoFuture<UpdateHITReviewStatusResponse, UpdateHITReviewStatusError> {
+        (**self).update_hit_review_status(input)
+    }
+    fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> RusotoFuture<UpdateHITTypeOfHITResponse, UpdateHITTypeOfHITError> {
+        (**self).update_hit_type_of_hit(input)
+    }
+    fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> RusotoFuture<UpdateNotificationSettingsResponse, UpdateNotificationSettingsError> {
+        (**self).update_notification_settings(input)
+    }
+    fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError> {
+        (**self).update_qualification_type(input)
+    }
+}
+impl<T: MechanicalTurk + ?Sized> MechanicalTurk for Box<T> {
+    fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> RusotoFuture<AcceptQualificationRequestResponse, AcceptQualificationRequestError> {
+        (**self).accept_qualification_request(input)
+    }
+    fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> RusotoFuture<ApproveAssignmentResponse, ApproveAssignmentError> {
+        (**self).approve_assignment(input)
+    }
+    fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> RusotoFuture<AssociateQualificationWithWorkerResponse, AssociateQualificationWithWorkerError>
+    {
+        (**self).associate_qualification_with_worker(input)
+    }
+    fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> RusotoFuture<
+        CreateAdditionalAssignmentsForHITResponse,
+        CreateAdditionalAssignmentsForHITError,
+    > {
+        (**self).create_additional_assignments_for_hit(input)
+    }
+    fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> RusotoFuture<CreateHITResponse, CreateHITError> {
+        (**self).create_hit(input)
+    }
+    fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> RusotoFuture<CreateHITTypeResponse, CreateHITTypeError> {
+        (**self).create_hit_type(input)
+    }
+    fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> RusotoFuture<CreateHITWithHITTypeResponse, CreateHITWithHITTypeError> {
+        (**self).create_hit_with_hit_type(input)
+    }
+    fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> RusotoFuture<CreateQualificationTypeResponse, CreateQualificationTypeError> {
+        (**self).create_qualification_type(input)
+    }
+    fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> RusotoFuture<CreateWorkerBlockResponse, CreateWorkerBlockError> {
+        (**self).create_worker_block(input)
+    }
+    fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> RusotoFuture<DeleteHITResponse, DeleteHITError> {
+        (**self).delete_hit(input)
+    }
+    fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> RusotoFuture<DeleteQualificationTypeResponse, DeleteQualificationTypeError> {
+        (**self).delete_qualification_type(input)
+    }
+    fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> RusotoFuture<DeleteWorkerBlockResponse, DeleteWorkerBlockError> {
+        (**self).delete_worker_block(input)
+    }
+    fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> RusotoFuture<
+        DisassociateQualificationFromWorkerResponse,
+        DisassociateQualificationFromWorkerError,
+    > {
+        (**self).disassociate_qualification_from_worker(input)
+    }
+    fn get_account_balance(
+        &self,
+    ) -> RusotoFuture<GetAccountBalanceResponse, GetAccountBalanceError> {
+        (**self).get_account_balance()
+    }
+    fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> RusotoFuture<GetAssignmentResponse, GetAssignmentError> {
+        (**self).get_assignment(input)
+    }
+    fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> RusotoFuture<GetFileUploadURLResponse, GetFileUploadURLError> {
+        (**self).get_file_upload_url(input)
+    }
+    fn get_hit(&self, input: GetHITRequest) -> RusotoFuture<GetHITResponse, GetHITError> {
+        (**self).get_hit(input)
+    }
+    fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> RusotoFuture<GetQualificationScoreResponse, GetQualificationScoreError> {
+        (**self).get_qualification_score(input)
+    }
+    fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> RusotoFuture<GetQualificationTypeResponse, GetQualificationTypeError> {
+        (**self).get_qualification_type(input)
+    }
+    fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> RusotoFuture<ListAssignmentsForHITResponse, ListAssignmentsForHITError> {
+        (**self).list_assignments_for_hit(input)
+    }
+    fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> RusotoFuture<ListBonusPaymentsResponse, ListBonusPaymentsError> {
+        (**self).list_bonus_payments(input)
+    }
+    fn list_hi_ts(&self, input: ListHITsRequest) -> RusotoFuture<ListHITsResponse, ListHITsError> {
+        (**self).list_hi_ts(input)
+    }
+    fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> RusotoFuture<ListHITsForQualificationTypeResponse, ListHITsForQualificationTypeError> {
+        (**self).list_hi_ts_for_qualification_type(input)
+    }
+    fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> RusotoFuture<ListQualificationRequestsResponse, ListQualificationRequestsError> {
+        (**self).list_qualification_requests(input)
+    }
+    fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> RusotoFuture<ListQualificationTypesResponse, ListQualificationTypesError> {
+        (**self).list_qualification_types(input)
+    }
+    fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> RusotoFuture<ListReviewPolicyResultsForHITResponse, ListReviewPolicyResultsForHITError>
+    {
+        (**self).list_review_policy_results_for_hit(input)
+    }
+    fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> RusotoFuture<ListReviewableHITsResponse, ListReviewableHITsError> {
+        (**self).list_reviewable_hi_ts(input)
+    }
+    fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> RusotoFuture<ListWorkerBlocksResponse, ListWorkerBlocksError> {
+        (**self).list_worker_blocks(input)
+    }
+    fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> RusotoFuture<ListWorkersWithQualificationTypeResponse, ListWorkersWithQualificationTypeError>
+    {
+        (**self).list_workers_with_qualification_type(input)
+    }
+    fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> RusotoFuture<NotifyWorkersResponse, NotifyWorkersError> {
+        (**self).notify_workers(input)
+    }
+    fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> RusotoFuture<RejectAssignmentResponse, RejectAssignmentError> {
+        (**self).reject_assignment(input)
+    }
+    fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> RusotoFuture<RejectQualificationRequestResponse, RejectQualificationRequestError> {
+        (**self).reject_qualification_request(input)
+    }
+    fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> RusotoFuture<SendBonusResponse, SendBonusError> {
+        (**self).send_bonus(input)
+    }
+    fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> RusotoFuture<SendTestEventNotificationResponse, SendTestEventNotificationError> {
+        (**self).send_test_event_notification(input)
+    }
+    fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> RusotoFuture<UpdateExpirationForHITResponse, UpdateExpirationForHITError> {
+        (**self).update_expiration_for_hit(input)
+    }
+    fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> RusotoFuture<UpdateHITReviewStatusResponse, UpdateHITReviewStatusError> {
+        (**self).update_hit_review_status(input)
+    }
+    fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> RusotoFuture<UpdateHITTypeOfHITResponse, UpdateHITTypeOfHITError> {
+        (**self).update_hit_type_of_hit(input)
+    }
+    fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> RusotoFuture<UpdateNotificationSettingsResponse, UpdateNotificationSettingsError> {
+        (**self).update_notification_settings(input)
+    }
+    fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError> {
+        (**self).update_qualification_type(input)
+    }
+}
+impl<T: MechanicalTurk + ?Sized> MechanicalTurk for ::std::sync::Arc<T> {
+    fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> RusotoFuture<AcceptQualificationRequestResponse, AcceptQualificationRequestError> {
+        (**self).accept_qualification_request(input)
+    }
+    fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> RusotoFuture<ApproveAssignmentResponse, ApproveAssignmentError> {
+        (**self).approve_assignment(input)
+    }
+    fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> RusotoFuture<AssociateQualificationWithWorkerResponse, AssociateQualificationWithWorkerError>
+    {
+        (**self).associate_qualification_with_worker(input)
+    }
+    fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> RusotoFuture<
+        CreateAdditionalAssignmentsForHITResponse,
+        CreateAdditionalAssignmentsForHITError,
+    > {
+        (**self).create_additional_assignments_for_hit(input)
+    }
+    fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> RusotoFuture<CreateHITResponse, CreateHITError> {
+        (**self).create_hit(input)
+    }
+    fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> RusotoFuture<CreateHITTypeResponse, CreateHITTypeError> {
+        (**self).create_hit_type(input)
+    }
+    fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> RusotoFuture<CreateHITWithHITTypeResponse, CreateHITWithHITTypeError> {
+        (**self).create_hit_with_hit_type(input)
+    }
+    fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> RusotoFuture<CreateQualificationTypeResponse, CreateQualificationTypeError> {
+        (**self).create_qualification_type(input)
+    }
+    fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> RusotoFuture<CreateWorkerBlockResponse, CreateWorkerBlockError> {
+        (**self).create_worker_block(input)
+    }
+    fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> RusotoFuture<DeleteHITResponse, DeleteHITError> {
+        (**self).delete_hit(input)
+    }
+    fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> RusotoFuture<DeleteQualificationTypeResponse, DeleteQualificationTypeError> {
+        (**self).delete_qualification_type(input)
+    }
+    fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> RusotoFuture<DeleteWorkerBlockResponse, DeleteWorkerBlockError> {
+        (**self).delete_worker_block(input)
+    }
+    fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> RusotoFuture<
+        DisassociateQualificationFromWorkerResponse,
+        DisassociateQualificationFromWorkerError,
+    > {
+        (**self).disassociate_qualification_from_worker(input)
+    }
+    fn get_account_balance(
+        &self,
+    ) -> RusotoFuture<GetAccountBalanceResponse, GetAccountBalanceError> {
+        (**self).get_account_balance()
+    }
+    fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> RusotoFuture<GetAssignmentResponse, GetAssignmentError> {
+        (**self).get_assignment(input)
+    }
+    fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> RusotoFuture<GetFileUploadURLResponse, GetFileUploadURLError> {
+        (**self).get_file_upload_url(input)
+    }
+    fn get_hit(&self, input: GetHITRequest) -> RusotoFuture<GetHITResponse, GetHITError> {
+        (**self).get_hit(input)
+    }
+    fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> RusotoFuture<GetQualificationScoreResponse, GetQualificationScoreError> {
+        (**self).get_qualification_score(input)
+    }
+    fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> RusotoFuture<GetQualificationTypeResponse, GetQualificationTypeError> {
+        (**self).get_qualification_type(input)
+    }
+    fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> RusotoFuture<ListAssignmentsForHITResponse, ListAssignmentsForHITError> {
+        (**self).list_assignments_for_hit(input)
+    }
+    fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> RusotoFuture<ListBonusPaymentsResponse, ListBonusPaymentsError> {
+        (**self).list_bonus_payments(input)
+    }
+    fn list_hi_ts(&self, input: ListHITsRequest) -> RusotoFuture<ListHITsResponse, ListHITsError> {
+        (**self).list_hi_ts(input)
+    }
+    fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> RusotoFuture<ListHITsForQualificationTypeResponse, ListHITsForQualificationTypeError> {
+        (**self).list_hi_ts_for_qualification_type(input)
+    }
+    fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> RusotoFuture<ListQualificationRequestsResponse, ListQualificationRequestsError> {
+        (**self).list_qualification_requests(input)
+    }
+    fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> RusotoFuture<ListQualificationTypesResponse, ListQualificationTypesError> {
+        (**self).list_qualification_types(input)
+    }
+    fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> RusotoFuture<ListReviewPolicyResultsForHITResponse, ListReviewPolicyResultsForHITError>
+    {
+        (**self).list_review_policy_results_for_hit(input)
+    }
+    fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> RusotoFuture<ListReviewableHITsResponse, ListReviewableHITsError> {
+        (**self).list_reviewable_hi_ts(input)
+    }
+    fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> RusotoFuture<ListWorkerBlocksResponse, ListWorkerBlocksError> {
+        (**self).list_worker_blocks(input)
+    }
+    fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> RusotoFuture<ListWorkersWithQualificationTypeResponse, ListWorkersWithQualificationTypeError>
+    {
+        (**self).list_workers_with_qualification_type(input)
+    }
+    fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> RusotoFuture<NotifyWorkersResponse, NotifyWorkersError> {
+        (**self).notify_workers(input)
+    }
+    fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> RusotoFuture<RejectAssignmentResponse, RejectAssignmentError> {
+        (**self).reject_assignment(input)
+    }
+    fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> RusotoFuture<RejectQualificationRequestResponse, RejectQualificationRequestError> {
+        (**self).reject_qualification_request(input)
+    }
+    fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> RusotoFuture<SendBonusResponse, SendBonusError> {
+        (**self).send_bonus(input)
+    }
+    fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> RusotoFuture<SendTestEventNotificationResponse, SendTestEventNotificationError> {
+        (**self).send_test_event_notification(input)
+    }
+    fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> RusotoFuture<UpdateExpirationForHITResponse, UpdateExpirationForHITError> {
+        (**self).update_expiration_for_hit(input)
+    }
+    fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> RusotoFuture<UpdateHITReviewStatusResponse, UpdateHITReviewStatusError> {
+        (**self).update_hit_review_status(input)
+    }
+    fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> RusotoFuture<UpdateHITTypeOfHITResponse, UpdateHITTypeOfHITError> {
+        (**self).update_hit_type_of_hit(input)
+    }
+    fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> RusotoFuture<UpdateNotificationSettingsResponse, UpdateNotificationSettingsError> {
+        (**self).update_notification_settings(input)
+    }
+    fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError> {
+        (**self).update_qualification_type(input)
+    }
+}
+/// A client for the Amazon MTurk API.
+#[derive(Clone)]
+pub struct MechanicalTurkClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl MechanicalTurkClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MechanicalTurkClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MechanicalTurkClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkClient {
+        MechanicalTurkClient { client, region }
+    }
+}
+
+impl MechanicalTurk for MechanicalTurkClient {
+    /// <p> The <code>AcceptQualificationRequest</code> operation approves a Worker's request for a Qualification. </p> <p> Only the owner of the Qualification type can grant a Qualification request for that type. </p> <p> A successful request for the <code>AcceptQualificationRequest</code> operation returns with no errors and an empty body. </p>
+    fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> RusotoFuture<AcceptQualificationRequestResponse, AcceptQualificationRequestError> {
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("AcceptQualificationRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.AcceptQualificationRequest",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcceptQualificationRequestResponse, _>(
+                    response,
+                    "AcceptQualificationRequest",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AcceptQualificationRequestError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>ApproveAssignment</code> operation approves the results of a completed assignment. </p> <p> Approving an assignment initiates two payments from the Requester's Amazon.com account </p> <ul> <li> <p> The Worker who submitted the results is paid the reward specified in the HIT. </p> </li> <li> <p> Amazon Mechanical Turk fees are debited. </p> </li> </ul> <p> If the Requester's account does not have adequate funds for these payments, the call to ApproveAssignment returns an exception, and the approval is not processed. You can include an optional feedback message with the approval, which the Worker can see in the Status section of the web site. </p> <p> You can also call this operation for assignments that were previous rejected and approve them by explicitly overriding the previous rejection. This only works on rejected assignments that were submitted within the previous 30 days and only if the assignment's related HIT has not been deleted. </p>
+    fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> RusotoFuture<ApproveAssignmentResponse, ApproveAssignmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ApproveAssignment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ApproveAssignment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ApproveAssignmentResponse, _>(
+                    response,
+                    "ApproveAssignment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ApproveAssignmentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> The <code>AssociateQualificationWithWorker</code> operation gives a Worker a Qualification. <code>AssociateQualificationWithWorker</code> does not require that the Worker submit a Qualification request. It gives the Qualification directly to the Worker. </p> <p> You can only assign a Qualification of a Qualification type that you created (using the <code>CreateQualificationType</code> operation). </p> <note> <p> Note: <code>AssociateQualificationWithWorker</code> does not affect any pending Qualification requests for the Qualification by the Worker. If you assign a Qualification to a Worker, then later grant a Qualification request made by the Worker, the granting of the request may modify the Qualification score. To resolve a pending Qualification request without affecting the Qualification the Worker already has, reject the request with the <code>RejectQualificationRequest</code> operation. </p> </note></p>
+    fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> RusotoFuture<AssociateQualificationWithWorkerResponse, AssociateQualificationWithWorkerError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("AssociateQualificationWithWorker");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.AssociateQualificationWithWorker",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateQualificationWithWorkerResponse, _>(
+                    response,
+                    "AssociateQualificationWithWorker",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateQualificationWithWorkerError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p> The <code>CreateAdditionalAssignmentsForHIT</code> operation increases the maximum number of assignments of an existing HIT. </p> <p> To extend the maximum number of assignments, specify the number of additional assignments.</p> <note> <ul> <li> <p>HITs created with fewer than 10 assignments cannot be extended to have 10 or more assignments. Attempting to add assignments in a way that brings the total number of assignments for a HIT from fewer than 10 assignments to 10 or more assignments will result in an <code>AWS.MechanicalTurk.InvalidMaximumAssignmentsIncrease</code> exception.</p> </li> <li> <p>HITs that were created before July 22, 2015 cannot be extended. Attempting to extend HITs that were created before July 22, 2015 will result in an <code>AWS.MechanicalTurk.HITTooOldForExtension</code> exception. </p> </li> </ul> </note></p>
+    fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> RusotoFuture<
+        CreateAdditionalAssignmentsForHITResponse,
+        CreateAdditionalAssignmentsForHITError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateAdditionalAssignmentsForHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.CreateAdditionalAssignmentsForHIT",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAdditionalAssignmentsForHITResponse, _>(
+                    response,
+                    "CreateAdditionalAssignmentsForHIT",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateAdditionalAssignmentsForHITError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>The <code>CreateHIT</code> operation creates a new Human Intelligence Task (HIT). The new HIT is made available for Workers to find and accept on the Amazon Mechanical Turk website. </p> <p> This operation allows you to specify a new HIT by passing in values for the properties of the HIT, such as its title, reward amount and number of assignments. When you pass these values to <code>CreateHIT</code>, a new HIT is created for you, with a new <code>HITTypeID</code>. The HITTypeID can be used to create additional HITs in the future without needing to specify common parameters such as the title, description and reward amount each time.</p> <p> An alternative way to create HITs is to first generate a HITTypeID using the <code>CreateHITType</code> operation and then call the <code>CreateHITWithHITType</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHIT also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>.</p> </note></p>
+    fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> RusotoFuture<CreateHITResponse, CreateHITError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.CreateHIT");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHITResponse, _>(response, "CreateHIT")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateHITError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>CreateHITType</code> operation creates a new HIT type. This operation allows you to define a standard set of HIT properties to use when creating HITs. If you register a HIT type with values that match an existing HIT type, the HIT type ID of the existing type will be returned. </p>
+    fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> RusotoFuture<CreateHITTypeResponse, CreateHITTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHITType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.CreateHITType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHITTypeResponse, _>(
+                    response,
+                    "CreateHITType",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateHITTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> The <code>CreateHITWithHITType</code> operation creates a new Human Intelligence Task (HIT) using an existing HITTypeID generated by the <code>CreateHITType</code> operation. </p> <p> This is an alternative way to create HITs from the <code>CreateHIT</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHITWithHITType also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>. </p> </note></p>
+    fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> RusotoFuture<CreateHITWithHITTypeResponse, CreateHITWithHITTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateHITWithHITType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.CreateHITWithHITType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHITWithHITTypeResponse, _>(
+                    response,
+                    "CreateHITWithHITType",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateHITWithHITTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>CreateQualificationType</code> operation creates a new Qualification type, which is represented by a <code>QualificationType</code> data structure. </p>
+    fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> RusotoFuture<CreateQualificationTypeResponse, CreateQualificationTypeError> {
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateQualificationType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.CreateQualificationType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateQualificationTypeResponse, _>(
+                    response,
+                    "CreateQualificationType",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateQualificationTypeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>The <code>CreateWorkerBlock</code> operation allows you to prevent a Worker from working on your HITs. For example, you can block a Worker who is producing poor quality work. You can block up to 100,000 Workers.</p>
+    fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> RusotoFuture<CreateWorkerBlockResponse, CreateWorkerBlockError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("CreateWorkerBlock");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.CreateWorkerBlock",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateWorkerBlockResponse, _>(
+                    response,
+                    "CreateWorkerBlock",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWorkerBlockError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> The <code>DeleteHIT</code> operation is used to delete HIT that is no longer needed. Only the Requester who created the HIT can delete it. </p> <p> You can only dispose of HITs that are in the <code>Reviewable</code> state, with all of their submitted assignments already either approved or rejected. If you call the DeleteHIT operation on a HIT that is not in the <code>Reviewable</code> state (for example, that has not expired, or still has active assignments), or on a HIT that is Reviewable but without all of its submitted assignments already approved or rejected, the service will return an error. </p> <note> <ul> <li> <p> HITs are automatically disposed of after 120 days. </p> </li> <li> <p> After you dispose of a HIT, you can no longer approve the HIT&#39;s rejected assignments. </p> </li> <li> <p> Disposed HITs are not returned in results for the ListHITs operation. </p> </li> <li> <p> Disposing HITs can improve the performance of operations such as ListReviewableHITs and ListHITs. </p> </li> </ul> </note></p>
+    fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> RusotoFuture<DeleteHITResponse, DeleteHITError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.DeleteHIT");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteHITResponse, _>(response, "DeleteHIT")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteHITError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> The <code>DeleteQualificationType</code> deletes a Qualification type and deletes any HIT types that are associated with the Qualification type. </p> <p>This operation does not revoke Qualifications already assigned to Workers because the Qualifications might be needed for active HITs. If there are any pending requests for the Qualification type, Amazon Mechanical Turk rejects those requests. After you delete a Qualification type, you can no longer use it to create HITs or HIT types.</p> <note> <p>DeleteQualificationType must wait for all the HITs that use the deleted Qualification type to be deleted before completing. It may take up to 48 hours before DeleteQualificationType completes and the unique name of the Qualification type is available for reuse with CreateQualificationType.</p> </note></p>
+    fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> RusotoFuture<DeleteQualificationTypeResponse, DeleteQualificationTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteQualificationType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.DeleteQualificationType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteQualificationTypeResponse, _>(
+                    response,
+                    "DeleteQualificationType",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteQualificationTypeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>The <code>DeleteWorkerBlock</code> operation allows you to reinstate a blocked Worker to work on your HITs. This operation reverses the effects of the CreateWorkerBlock operation. You need the Worker ID to use this operation. If the Worker ID is missing or invalid, this operation fails and returns the message “WorkerId is invalid.” If the specified Worker is not blocked, this operation returns successfully.</p>
+    fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> RusotoFuture<DeleteWorkerBlockResponse, DeleteWorkerBlockError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DeleteWorkerBlock");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.DeleteWorkerBlock",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteWorkerBlockResponse, _>(
+                    response,
+                    "DeleteWorkerBlock",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteWorkerBlockError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>DisassociateQualificationFromWorker</code> revokes a previously granted Qualification from a user. </p> <p> You can provide a text message explaining why the Qualification was revoked. The user who had the Qualification can see this message. </p>
+    fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> RusotoFuture<
+        DisassociateQualificationFromWorkerResponse,
+        DisassociateQualificationFromWorkerError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("DisassociateQualificationFromWorker");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.DisassociateQualificationFromWorker",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateQualificationFromWorkerResponse, _>(response, "DisassociateQualificationFromWorker")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateQualificationFromWorkerError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>The <code>GetAccountBalance</code> operation retrieves the amount of money in your Amazon Mechanical Turk account.</p>
+    fn get_account_balance(
+        &self,
+    ) -> RusotoFuture<GetAccountBalanceResponse, GetAccountBalanceError> {
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetAccountBalance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.GetAccountBalance",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAccountBalanceResponse, _>(
+                    response,
+                    "GetAccountBalance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountBalanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>GetAssignment</code> operation retrieves the details of the specified Assignment. </p>
+    fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> RusotoFuture<GetAssignmentResponse, GetAssignmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetAssignment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.GetAssignment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAssignmentResponse, _>(
+                    response,
+                    "GetAssignment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAssignmentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>GetFileUploadURL</code> operation generates and returns a temporary URL. You use the temporary URL to retrieve a file uploaded by a Worker as an answer to a FileUploadAnswer question for a HIT. The temporary URL is generated the instant the GetFileUploadURL operation is called, and is valid for 60 seconds. You can get a temporary file upload URL any time until the HIT is disposed. After the HIT is disposed, any uploaded files are deleted, and cannot be retrieved. Pending Deprecation on December 12, 2017. The Answer Specification structure will no longer support the <code>FileUploadAnswer</code> element to be used for the QuestionForm data structure. Instead, we recommend that Requesters who want to create HITs asking Workers to upload files to use Amazon S3. </p>
+    fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> RusotoFuture<GetFileUploadURLResponse, GetFileUploadURLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetFileUploadURL");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.GetFileUploadURL",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetFileUploadURLResponse, _>(
+                    response,
+                    "GetFileUploadURL",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFileUploadURLError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>GetHIT</code> operation retrieves the details of the specified HIT. </p>
+    fn get_hit(&self, input: GetHITRequest) -> RusotoFuture<GetHITResponse, GetHITError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.GetHIT");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetHITResponse, _>(response, "GetHIT")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetHITError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>GetQualificationScore</code> operation returns the value of a Worker's Qualification for a given Qualification type. </p> <p> To get a Worker's Qualification, you must know the Worker's ID. The Worker's ID is included in the assignment data returned by the <code>ListAssignmentsForHIT</code> operation. </p> <p>Only the owner of a Qualification type can query the value of a Worker's Qualification of that type.</p>
+    fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> RusotoFuture<GetQualificationScoreResponse, GetQualificationScoreError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetQualificationScore");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.GetQualificationScore",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetQualificationScoreResponse, _>(
+                    response,
+                    "GetQualificationScore",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetQualificationScoreError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>GetQualificationType</code>operation retrieves information about a Qualification type using its ID. </p>
+    fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> RusotoFuture<GetQualificationTypeResponse, GetQualificationTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("GetQualificationType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.GetQualificationType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetQualificationTypeResponse, _>(
+                    response,
+                    "GetQualificationType",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetQualificationTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>ListAssignmentsForHIT</code> operation retrieves completed assignments for a HIT. You can use this operation to retrieve the results for a HIT. </p> <p> You can get assignments for a HIT at any time, even if the HIT is not yet Reviewable. If a HIT requested multiple assignments, and has received some results but has not yet become Reviewable, you can still retrieve the partial results with this operation. </p> <p> Use the AssignmentStatus parameter to control which set of assignments for a HIT are returned. The ListAssignmentsForHIT operation can return submitted assignments awaiting approval, or it can return assignments that have already been approved or rejected. You can set AssignmentStatus=Approved,Rejected to get assignments that have already been approved and rejected together in one result set. </p> <p> Only the Requester who created the HIT can retrieve the assignments for that HIT. </p> <p> Results are sorted and divided into numbered pages and the operation returns a single page of results. You can use the parameters of the operation to control sorting and pagination. </p>
+    fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> RusotoFuture<ListAssignmentsForHITResponse, ListAssignmentsForHITError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListAssignmentsForHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListAssignmentsForHIT",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssignmentsForHITResponse, _>(
+                    response,
+                    "ListAssignmentsForHIT",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAssignmentsForHITError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>ListBonusPayments</code> operation retrieves the amounts of bonuses you have paid to Workers for a given HIT or assignment. </p>
+    fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> RusotoFuture<ListBonusPaymentsResponse, ListBonusPaymentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListBonusPayments");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListBonusPayments",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBonusPaymentsResponse, _>(
+                    response,
+                    "ListBonusPayments",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListBonusPaymentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>ListHITs</code> operation returns all of a Requester's HITs. The operation returns HITs of any status, except for HITs that have been deleted of with the DeleteHIT operation or that have been auto-deleted. </p>
+    fn list_hi_ts(&self, input: ListHITsRequest) -> RusotoFuture<ListHITsResponse, ListHITsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListHITs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.ListHITs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListHITsResponse, _>(response, "ListHITs")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListHITsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>ListHITsForQualificationType</code> operation returns the HITs that use the given Qualification type for a Qualification requirement. The operation returns HITs of any status, except for HITs that have been deleted with the <code>DeleteHIT</code> operation or that have been auto-deleted. </p>
+    fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> RusotoFuture<ListHITsForQualificationTypeResponse, ListHITsForQualificationTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListHITsForQualificationType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListHITsForQualificationType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListHITsForQualificationTypeResponse, _>(
+                    response,
+                    "ListHITsForQualificationType",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListHITsForQualificationTypeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>ListQualificationRequests</code> operation retrieves requests for Qualifications of a particular Qualification type. The owner of the Qualification type calls this operation to poll for pending requests, and accepts them using the AcceptQualification operation. </p>
+    fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> RusotoFuture<ListQualificationRequestsResponse, ListQualificationRequestsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListQualificationRequests");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListQualificationRequests",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListQualificationRequestsResponse, _>(
+                    response,
+                    "ListQualificationRequests",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListQualificationRequestsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>ListQualificationTypes</code> operation returns a list of Qualification types, filtered by an optional search term. </p>
+    fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> RusotoFuture<ListQualificationTypesResponse, ListQualificationTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListQualificationTypes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListQualificationTypes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListQualificationTypesResponse, _>(
+                    response,
+                    "ListQualificationTypes",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListQualificationTypesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>ListReviewPolicyResultsForHIT</code> operation retrieves the computed results and the actions taken in the course of executing your Review Policies for a given HIT. For information about how to specify Review Policies when you call CreateHIT, see Review Policies. The ListReviewPolicyResultsForHIT operation can return results for both Assignment-level and HIT-level review results. </p>
+    fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> RusotoFuture<ListReviewPolicyResultsForHITResponse, ListReviewPolicyResultsForHITError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListReviewPolicyResultsForHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListReviewPolicyResultsForHIT",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListReviewPolicyResultsForHITResponse, _>(
+                    response,
+                    "ListReviewPolicyResultsForHIT",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListReviewPolicyResultsForHITError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>ListReviewableHITs</code> operation retrieves the HITs with Status equal to Reviewable or Status equal to Reviewing that belong to the Requester calling the operation. </p>
+    fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> RusotoFuture<ListReviewableHITsResponse, ListReviewableHITsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListReviewableHITs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListReviewableHITs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListReviewableHITsResponse, _>(
+                    response,
+                    "ListReviewableHITs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListReviewableHITsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>The <code>ListWorkersBlocks</code> operation retrieves a list of Workers who are blocked from working on your HITs.</p>
+    fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> RusotoFuture<ListWorkerBlocksResponse, ListWorkerBlocksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListWorkerBlocks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListWorkerBlocks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListWorkerBlocksResponse, _>(
+                    response,
+                    "ListWorkerBlocks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWorkerBlocksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>ListWorkersWithQualificationType</code> operation returns all of the Workers that have been associated with a given Qualification type. </p>
+    fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> RusotoFuture<ListWorkersWithQualificationTypeResponse, ListWorkersWithQualificationTypeError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("ListWorkersWithQualificationType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.ListWorkersWithQualificationType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListWorkersWithQualificationTypeResponse, _>(
+                    response,
+                    "ListWorkersWithQualificationType",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListWorkersWithQualificationTypeError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>NotifyWorkers</code> operation sends an email to one or more Workers that you specify with the Worker ID. You can specify up to 100 Worker IDs to send the same message with a single call to the NotifyWorkers operation. The NotifyWorkers operation will send a notification email to a Worker only if you have previously approved or rejected work from the Worker. </p>
+    fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> RusotoFuture<NotifyWorkersResponse, NotifyWorkersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("NotifyWorkers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.NotifyWorkers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<NotifyWorkersResponse, _>(
+                    response,
+                    "NotifyWorkers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(NotifyWorkersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>RejectAssignment</code> operation rejects the results of a completed assignment. </p> <p> You can include an optional feedback message with the rejection, which the Worker can see in the Status section of the web site. When you include a feedback message with the rejection, it helps the Worker understand why the assignment was rejected, and can improve the quality of the results the Worker submits in the future. </p> <p> Only the Requester who created the HIT can reject an assignment for the HIT. </p>
+    fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> RusotoFuture<RejectAssignmentResponse, RejectAssignmentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("RejectAssignment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.RejectAssignment",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RejectAssignmentResponse, _>(
+                    response,
+                    "RejectAssignment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RejectAssignmentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>RejectQualificationRequest</code> operation rejects a user's request for a Qualification. </p> <p> You can provide a text message explaining why the request was rejected. The Worker who made the request can see this message.</p>
+    fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> RusotoFuture<RejectQualificationRequestResponse, RejectQualificationRequestError> {
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("RejectQualificationRequest");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.RejectQualificationRequest",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RejectQualificationRequestResponse, _>(
+                    response,
+                    "RejectQualificationRequest",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RejectQualificationRequestError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>SendBonus</code> operation issues a payment of money from your account to a Worker. This payment happens separately from the reward you pay to the Worker when you approve the Worker's assignment. The SendBonus operation requires the Worker's ID and the assignment ID as parameters to initiate payment of the bonus. You must include a message that explains the reason for the bonus payment, as the Worker may not be expecting the payment. Amazon Mechanical Turk collects a fee for bonus payments, similar to the HIT listing fee. This operation fails if your account does not have enough funds to pay for both the bonus and the fees. </p>
+    fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> RusotoFuture<SendBonusResponse, SendBonusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("SendBonus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.SendBonus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SendBonusResponse, _>(response, "SendBonus")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendBonusError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>SendTestEventNotification</code> operation causes Amazon Mechanical Turk to send a notification message as if a HIT event occurred, according to the provided notification specification. This allows you to test notifications without setting up notifications for a real HIT type and trying to trigger them using the website. When you call this operation, the service attempts to send the test notification immediately. </p>
+    fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> RusotoFuture<SendTestEventNotificationResponse, SendTestEventNotificationError> {
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("SendTestEventNotification");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.SendTestEventNotification",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SendTestEventNotificationResponse, _>(
+                    response,
+                    "SendTestEventNotification",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SendTestEventNotificationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>UpdateExpirationForHIT</code> operation allows you update the expiration time of a HIT. If you update it to a time in the past, the HIT will be immediately expired. </p>
+    fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> RusotoFuture<UpdateExpirationForHITResponse, UpdateExpirationForHITError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateExpirationForHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.UpdateExpirationForHIT",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateExpirationForHITResponse, _>(
+                    response,
+                    "UpdateExpirationForHIT",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateExpirationForHITError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>UpdateHITReviewStatus</code> operation updates the status of a HIT. If the status is Reviewable, this operation can update the status to Reviewing, or it can revert a Reviewing HIT back to the Reviewable status. </p>
+    fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> RusotoFuture<UpdateHITReviewStatusResponse, UpdateHITReviewStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateHITReviewStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.UpdateHITReviewStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateHITReviewStatusResponse, _>(
+                    response,
+                    "UpdateHITReviewStatus",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateHITReviewStatusError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>UpdateHITTypeOfHIT</code> operation allows you to change the HITType properties of a HIT. This operation disassociates the HIT from its old HITType properties and associates it with the new HITType properties. The HIT takes on the properties of the new HITType in place of the old ones. </p>
+    fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> RusotoFuture<UpdateHITTypeOfHITResponse, UpdateHITTypeOfHITError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateHITTypeOfHIT");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.UpdateHITTypeOfHIT",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateHITTypeOfHITResponse, _>(
+                    response,
+                    "UpdateHITTypeOfHIT",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateHITTypeOfHITError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> The <code>UpdateNotificationSettings</code> operation creates, updates, disables or re-enables notifications for a HIT type. If you call the UpdateNotificationSettings operation for a HIT type that already has a notification specification, the operation replaces the old specification with a new one. You can call the UpdateNotificationSettings operation to enable or disable notifications for the HIT type, without having to modify the notification specification itself by providing updates to the Active status without specifying a new notification specification. To change the Active status of a HIT type's notifications, the HIT type must already have a notification specification, or one must be provided in the same call to <code>UpdateNotificationSettings</code>. </p>
+    fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> RusotoFuture<UpdateNotificationSettingsResponse, UpdateNotificationSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateNotificationSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.UpdateNotificationSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNotificationSettingsResponse, _>(
+                    response,
+                    "UpdateNotificationSettings",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateNotificationSettingsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> The <code>UpdateQualificationType</code> operation modifies the attributes of an existing Qualification type, which is represented by a QualificationType data structure. Only the owner of a Qualification type can modify its attributes. </p> <p> Most attributes of a Qualification type can be changed after the type has been created. However, the Name and Keywords fields cannot be modified. The RetryDelayInSeconds parameter can be modified or added to change the delay or to enable retries, but RetryDelayInSeconds cannot be used to disable retries. </p> <p> You can use this operation to update the test for a Qualification type. The test is updated based on the values specified for the Test, TestDurationInSeconds and AnswerKey parameters. All three parameters specify the updated test. If you are updating the test for a type, you must specify the Test and TestDurationInSeconds parameters. The AnswerKey parameter is optional; omitting it specifies that the updated test does not have an answer key. </p> <p> If you omit the Test parameter, the test for the Qualification type is unchanged. There is no way to remove a test from a Qualification type that has one. If the type already has a test, you cannot update it to be AutoGranted. If the Qualification type does not have a test and one is provided by an update, the type will henceforth have a test. </p> <p> If you want to update the test duration or answer key for an existing test without changing the questions, you must specify a Test parameter with the original questions, along with the updated values. </p> <p> If you provide an updated Test but no AnswerKey, the new test will not have an answer key. Requests for such Qualifications must be granted manually. </p> <p> You can also update the AutoGranted and AutoGrantedValue attributes of the Qualification type.</p>
+    fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
+        request.set_operation("UpdateQualificationType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "MTurkRequesterServiceV20170117.UpdateQualificationType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RejectQualificationRequestResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateQualificationTypeResponse, _>(
+                    response,
+                    "UpdateQualificationType",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RejectQualificationRequestError::from_response(response))
+                    Err(UpdateQualificationTypeError::from_response(response))
                 }))
             }
-        })
+        })
+    }
+}
+
+/// A mock of the Amazon MTurk API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockMechanicalTurk {
+    accept_qualification_request: Option<
+        Box<
+            dyn Fn(
+                    AcceptQualificationRequestRequest,
+                ) -> ::std::result::Result<
+                    AcceptQualificationRequestResponse,
+                    RusotoError<AcceptQualificationRequestError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    approve_assignment: Option<
+        Box<
+            dyn Fn(
+                    ApproveAssignmentRequest,
+                ) -> ::std::result::Result<
+                    ApproveAssignmentResponse,
+                    RusotoError<ApproveAssignmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_qualification_with_worker: Option<
+        Box<
+            dyn Fn(
+                    AssociateQualificationWithWorkerRequest,
+                ) -> ::std::result::Result<
+                    AssociateQualificationWithWorkerResponse,
+                    RusotoError<AssociateQualificationWithWorkerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_additional_assignments_for_hit: Option<
+        Box<
+            dyn Fn(
+                    CreateAdditionalAssignmentsForHITRequest,
+                ) -> ::std::result::Result<
+                    CreateAdditionalAssignmentsForHITResponse,
+                    RusotoError<CreateAdditionalAssignmentsForHITError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_hit: Option<
+        Box<
+            dyn Fn(
+                    CreateHITRequest,
+                )
+                    -> ::std::result::Result<CreateHITResponse, RusotoError<CreateHITError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_hit_type: Option<
+        Box<
+            dyn Fn(
+                    CreateHITTypeRequest,
+                )
+                    -> ::std::result::Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_hit_with_hit_type: Option<
+        Box<
+            dyn Fn(
+                    CreateHITWithHITTypeRequest,
+                ) -> ::std::result::Result<
+                    CreateHITWithHITTypeResponse,
+                    RusotoError<CreateHITWithHITTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_qualification_type: Option<
+        Box<
+            dyn Fn(
+                    CreateQualificationTypeRequest,
+                ) -> ::std::result::Result<
+                    CreateQualificationTypeResponse,
+                    RusotoError<CreateQualificationTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_worker_block: Option<
+        Box<
+            dyn Fn(
+                    CreateWorkerBlockRequest,
+                ) -> ::std::result::Result<
+                    CreateWorkerBlockResponse,
+                    RusotoError<CreateWorkerBlockError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_hit: Option<
+        Box<
+            dyn Fn(
+                    DeleteHITRequest,
+                )
+                    -> ::std::result::Result<DeleteHITResponse, RusotoError<DeleteHITError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_qualification_type: Option<
+        Box<
+            dyn Fn(
+                    DeleteQualificationTypeRequest,
+                ) -> ::std::result::Result<
+                    DeleteQualificationTypeResponse,
+                    RusotoError<DeleteQualificationTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_worker_block: Option<
+        Box<
+            dyn Fn(
+                    DeleteWorkerBlockRequest,
+                ) -> ::std::result::Result<
+                    DeleteWorkerBlockResponse,
+                    RusotoError<DeleteWorkerBlockError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_qualification_from_worker: Option<
+        Box<
+            dyn Fn(
+                    DisassociateQualificationFromWorkerRequest,
+                ) -> ::std::result::Result<
+                    DisassociateQualificationFromWorkerResponse,
+                    RusotoError<DisassociateQualificationFromWorkerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_account_balance: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetAccountBalanceResponse,
+                    RusotoError<GetAccountBalanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_assignment: Option<
+        Box<
+            dyn Fn(
+                    GetAssignmentRequest,
+                )
+                    -> ::std::result::Result<GetAssignmentResponse, RusotoError<GetAssignmentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_file_upload_url: Option<
+        Box<
+            dyn Fn(
+                    GetFileUploadURLRequest,
+                ) -> ::std::result::Result<
+                    GetFileUploadURLResponse,
+                    RusotoError<GetFileUploadURLError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_hit: Option<
+        Box<
+            dyn Fn(GetHITRequest) -> ::std::result::Result<GetHITResponse, RusotoError<GetHITError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_qualification_score: Option<
+        Box<
+            dyn Fn(
+                    GetQualificationScoreRequest,
+                ) -> ::std::result::Result<
+                    GetQualificationScoreResponse,
+                    RusotoError<GetQualificationScoreError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_qualification_type: Option<
+        Box<
+            dyn Fn(
+                    GetQualificationTypeRequest,
+                ) -> ::std::result::Result<
+                    GetQualificationTypeResponse,
+                    RusotoError<GetQualificationTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_assignments_for_hit: Option<
+        Box<
+            dyn Fn(
+                    ListAssignmentsForHITRequest,
+                ) -> ::std::result::Result<
+                    ListAssignmentsForHITResponse,
+                    RusotoError<ListAssignmentsForHITError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_bonus_payments: Option<
+        Box<
+            dyn Fn(
+                    ListBonusPaymentsRequest,
+                ) -> ::std::result::Result<
+                    ListBonusPaymentsResponse,
+                    RusotoError<ListBonusPaymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_hi_ts: Option<
+        Box<
+            dyn Fn(
+                    ListHITsRequest,
+                )
+                    -> ::std::result::Result<ListHITsResponse, RusotoError<ListHITsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_hi_ts_for_qualification_type: Option<
+        Box<
+            dyn Fn(
+                    ListHITsForQualificationTypeRequest,
+                ) -> ::std::result::Result<
+                    ListHITsForQualificationTypeResponse,
+                    RusotoError<ListHITsForQualificationTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_qualification_requests: Option<
+        Box<
+            dyn Fn(
+                    ListQualificationRequestsRequest,
+                ) -> ::std::result::Result<
+                    ListQualificationRequestsResponse,
+                    RusotoError<ListQualificationRequestsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_qualification_types: Option<
+        Box<
+            dyn Fn(
+                    ListQualificationTypesRequest,
+                ) -> ::std::result::Result<
+                    ListQualificationTypesResponse,
+                    RusotoError<ListQualificationTypesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_review_policy_results_for_hit: Option<
+        Box<
+            dyn Fn(
+                    ListReviewPolicyResultsForHITRequest,
+                ) -> ::std::result::Result<
+                    ListReviewPolicyResultsForHITResponse,
+                    RusotoError<ListReviewPolicyResultsForHITError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_reviewable_hi_ts: Option<
+        Box<
+            dyn Fn(
+                    ListReviewableHITsRequest,
+                ) -> ::std::result::Result<
+                    ListReviewableHITsResponse,
+                    RusotoError<ListReviewableHITsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_worker_blocks: Option<
+        Box<
+            dyn Fn(
+                    ListWorkerBlocksRequest,
+                ) -> ::std::result::Result<
+                    ListWorkerBlocksResponse,
+                    RusotoError<ListWorkerBlocksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_workers_with_qualification_type: Option<
+        Box<
+            dyn Fn(
+                    ListWorkersWithQualificationTypeRequest,
+                ) -> ::std::result::Result<
+                    ListWorkersWithQualificationTypeResponse,
+                    RusotoError<ListWorkersWithQualificationTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    notify_workers: Option<
+        Box<
+            dyn Fn(
+                    NotifyWorkersRequest,
+                )
+                    -> ::std::result::Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reject_assignment: Option<
+        Box<
+            dyn Fn(
+                    RejectAssignmentRequest,
+                ) -> ::std::result::Result<
+                    RejectAssignmentResponse,
+                    RusotoError<RejectAssignmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reject_qualification_request: Option<
+        Box<
+            dyn Fn(
+                    RejectQualificationRequestRequest,
+                ) -> ::std::result::Result<
+                    RejectQualificationRequestResponse,
+                    RusotoError<RejectQualificationRequestError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_bonus: Option<
+        Box<
+            dyn Fn(
+                    SendBonusRequest,
+                )
+                    -> ::std::result::Result<SendBonusResponse, RusotoError<SendBonusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_test_event_notification: Option<
+        Box<
+            dyn Fn(
+                    SendTestEventNotificationRequest,
+                ) -> ::std::result::Result<
+                    SendTestEventNotificationResponse,
+                    RusotoError<SendTestEventNotificationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_expiration_for_hit: Option<
+        Box<
+            dyn Fn(
+                    UpdateExpirationForHITRequest,
+                ) -> ::std::result::Result<
+                    UpdateExpirationForHITResponse,
+                    RusotoError<UpdateExpirationForHITError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_hit_review_status: Option<
+        Box<
+            dyn Fn(
+                    UpdateHITReviewStatusRequest,
+                ) -> ::std::result::Result<
+                    UpdateHITReviewStatusResponse,
+                    RusotoError<UpdateHITReviewStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_hit_type_of_hit: Option<
+        Box<
+            dyn Fn(
+                    UpdateHITTypeOfHITRequest,
+                ) -> ::std::result::Result<
+                    UpdateHITTypeOfHITResponse,
+                    RusotoError<UpdateHITTypeOfHITError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_notification_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateNotificationSettingsRequest,
+                ) -> ::std::result::Result<
+                    UpdateNotificationSettingsResponse,
+                    RusotoError<UpdateNotificationSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_qualification_type: Option<
+        Box<
+            dyn Fn(
+                    UpdateQualificationTypeRequest,
+                ) -> ::std::result::Result<
+                    UpdateQualificationTypeResponse,
+                    RusotoError<UpdateQualificationTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockMechanicalTurk {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockMechanicalTurk {
+        Default::default()
+    }
+
+    /// Answers `accept_qualification_request` with what `handler` returns for its input.
+    pub fn with_accept_qualification_request<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                AcceptQualificationRequestRequest,
+            ) -> ::std::result::Result<
+                AcceptQualificationRequestResponse,
+                RusotoError<AcceptQualificationRequestError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_qualification_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `approve_assignment` with what `handler` returns for its input.
+    pub fn with_approve_assignment<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ApproveAssignmentRequest,
+            ) -> ::std::result::Result<
+                ApproveAssignmentResponse,
+                RusotoError<ApproveAssignmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.approve_assignment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_qualification_with_worker` with what `handler` returns for its input.
+    pub fn with_associate_qualification_with_worker<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                AssociateQualificationWithWorkerRequest,
+            ) -> ::std::result::Result<
+                AssociateQualificationWithWorkerResponse,
+                RusotoError<AssociateQualificationWithWorkerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_qualification_with_worker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_additional_assignments_for_hit` with what `handler` returns for its input.
+    pub fn with_create_additional_assignments_for_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                CreateAdditionalAssignmentsForHITRequest,
+            ) -> ::std::result::Result<
+                CreateAdditionalAssignmentsForHITResponse,
+                RusotoError<CreateAdditionalAssignmentsForHITError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_additional_assignments_for_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hit` with what `handler` returns for its input.
+    pub fn with_create_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                CreateHITRequest,
+            ) -> ::std::result::Result<CreateHITResponse, RusotoError<CreateHITError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hit_type` with what `handler` returns for its input.
+    pub fn with_create_hit_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                CreateHITTypeRequest,
+            )
+                -> ::std::result::Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hit_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hit_with_hit_type` with what `handler` returns for its input.
+    pub fn with_create_hit_with_hit_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                CreateHITWithHITTypeRequest,
+            ) -> ::std::result::Result<
+                CreateHITWithHITTypeResponse,
+                RusotoError<CreateHITWithHITTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hit_with_hit_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_qualification_type` with what `handler` returns for its input.
+    pub fn with_create_qualification_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                CreateQualificationTypeRequest,
+            ) -> ::std::result::Result<
+                CreateQualificationTypeResponse,
+                RusotoError<CreateQualificationTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_qualification_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_worker_block` with what `handler` returns for its input.
+    pub fn with_create_worker_block<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                CreateWorkerBlockRequest,
+            ) -> ::std::result::Result<
+                CreateWorkerBlockResponse,
+                RusotoError<CreateWorkerBlockError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_worker_block = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hit` with what `handler` returns for its input.
+    pub fn with_delete_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                DeleteHITRequest,
+            ) -> ::std::result::Result<DeleteHITResponse, RusotoError<DeleteHITError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_qualification_type` with what `handler` returns for its input.
+    pub fn with_delete_qualification_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                DeleteQualificationTypeRequest,
+            ) -> ::std::result::Result<
+                DeleteQualificationTypeResponse,
+                RusotoError<DeleteQualificationTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_qualification_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_worker_block` with what `handler` returns for its input.
+    pub fn with_delete_worker_block<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                DeleteWorkerBlockRequest,
+            ) -> ::std::result::Result<
+                DeleteWorkerBlockResponse,
+                RusotoError<DeleteWorkerBlockError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_worker_block = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_qualification_from_worker` with what `handler` returns for its input.
+    pub fn with_disassociate_qualification_from_worker<F>(
+        mut self,
+        handler: F,
+    ) -> MockMechanicalTurk
+    where
+        F: Fn(
+                DisassociateQualificationFromWorkerRequest,
+            ) -> ::std::result::Result<
+                DisassociateQualificationFromWorkerResponse,
+                RusotoError<DisassociateQualificationFromWorkerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_qualification_from_worker = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account_balance` with what `handler` returns.
+    pub fn with_get_account_balance<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn() -> ::std::result::Result<
+                GetAccountBalanceResponse,
+                RusotoError<GetAccountBalanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account_balance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_assignment` with what `handler` returns for its input.
+    pub fn with_get_assignment<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                GetAssignmentRequest,
+            )
+                -> ::std::result::Result<GetAssignmentResponse, RusotoError<GetAssignmentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_assignment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_file_upload_url` with what `handler` returns for its input.
+    pub fn with_get_file_upload_url<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                GetFileUploadURLRequest,
+            ) -> ::std::result::Result<
+                GetFileUploadURLResponse,
+                RusotoError<GetFileUploadURLError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_file_upload_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_hit` with what `handler` returns for its input.
+    pub fn with_get_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(GetHITRequest) -> ::std::result::Result<GetHITResponse, RusotoError<GetHITError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_qualification_score` with what `handler` returns for its input.
+    pub fn with_get_qualification_score<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                GetQualificationScoreRequest,
+            ) -> ::std::result::Result<
+                GetQualificationScoreResponse,
+                RusotoError<GetQualificationScoreError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_qualification_score = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_qualification_type` with what `handler` returns for its input.
+    pub fn with_get_qualification_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                GetQualificationTypeRequest,
+            ) -> ::std::result::Result<
+                GetQualificationTypeResponse,
+                RusotoError<GetQualificationTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_qualification_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_assignments_for_hit` with what `handler` returns for its input.
+    pub fn with_list_assignments_for_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListAssignmentsForHITRequest,
+            ) -> ::std::result::Result<
+                ListAssignmentsForHITResponse,
+                RusotoError<ListAssignmentsForHITError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_assignments_for_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bonus_payments` with what `handler` returns for its input.
+    pub fn with_list_bonus_payments<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListBonusPaymentsRequest,
+            ) -> ::std::result::Result<
+                ListBonusPaymentsResponse,
+                RusotoError<ListBonusPaymentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bonus_payments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hi_ts` with what `handler` returns for its input.
+    pub fn with_list_hi_ts<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListHITsRequest,
+            ) -> ::std::result::Result<ListHITsResponse, RusotoError<ListHITsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hi_ts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hi_ts_for_qualification_type` with what `handler` returns for its input.
+    pub fn with_list_hi_ts_for_qualification_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListHITsForQualificationTypeRequest,
+            ) -> ::std::result::Result<
+                ListHITsForQualificationTypeResponse,
+                RusotoError<ListHITsForQualificationTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hi_ts_for_qualification_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_qualification_requests` with what `handler` returns for its input.
+    pub fn with_list_qualification_requests<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListQualificationRequestsRequest,
+            ) -> ::std::result::Result<
+                ListQualificationRequestsResponse,
+                RusotoError<ListQualificationRequestsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_qualification_requests = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_qualification_types` with what `handler` returns for its input.
+    pub fn with_list_qualification_types<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListQualificationTypesRequest,
+            ) -> ::std::result::Result<
+                ListQualificationTypesResponse,
+                RusotoError<ListQualificationTypesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_qualification_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_review_policy_results_for_hit` with what `handler` returns for its input.
+    pub fn with_list_review_policy_results_for_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListReviewPolicyResultsForHITRequest,
+            ) -> ::std::result::Result<
+                ListReviewPolicyResultsForHITResponse,
+                RusotoError<ListReviewPolicyResultsForHITError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_review_policy_results_for_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_reviewable_hi_ts` with what `handler` returns for its input.
+    pub fn with_list_reviewable_hi_ts<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListReviewableHITsRequest,
+            ) -> ::std::result::Result<
+                ListReviewableHITsResponse,
+                RusotoError<ListReviewableHITsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_reviewable_hi_ts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_worker_blocks` with what `handler` returns for its input.
+    pub fn with_list_worker_blocks<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListWorkerBlocksRequest,
+            ) -> ::std::result::Result<
+                ListWorkerBlocksResponse,
+                RusotoError<ListWorkerBlocksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_worker_blocks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_workers_with_qualification_type` with what `handler` returns for its input.
+    pub fn with_list_workers_with_qualification_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                ListWorkersWithQualificationTypeRequest,
+            ) -> ::std::result::Result<
+                ListWorkersWithQualificationTypeResponse,
+                RusotoError<ListWorkersWithQualificationTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_workers_with_qualification_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `notify_workers` with what `handler` returns for its input.
+    pub fn with_notify_workers<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                NotifyWorkersRequest,
+            )
+                -> ::std::result::Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.notify_workers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reject_assignment` with what `handler` returns for its input.
+    pub fn with_reject_assignment<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                RejectAssignmentRequest,
+            ) -> ::std::result::Result<
+                RejectAssignmentResponse,
+                RusotoError<RejectAssignmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reject_assignment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reject_qualification_request` with what `handler` returns for its input.
+    pub fn with_reject_qualification_request<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                RejectQualificationRequestRequest,
+            ) -> ::std::result::Result<
+                RejectQualificationRequestResponse,
+                RusotoError<RejectQualificationRequestError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reject_qualification_request = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_bonus` with what `handler` returns for its input.
+    pub fn with_send_bonus<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                SendBonusRequest,
+            ) -> ::std::result::Result<SendBonusResponse, RusotoError<SendBonusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_bonus = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_test_event_notification` with what `handler` returns for its input.
+    pub fn with_send_test_event_notification<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                SendTestEventNotificationRequest,
+            ) -> ::std::result::Result<
+                SendTestEventNotificationResponse,
+                RusotoError<SendTestEventNotificationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_test_event_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_expiration_for_hit` with what `handler` returns for its input.
+    pub fn with_update_expiration_for_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                UpdateExpirationForHITRequest,
+            ) -> ::std::result::Result<
+                UpdateExpirationForHITResponse,
+                RusotoError<UpdateExpirationForHITError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_expiration_for_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_hit_review_status` with what `handler` returns for its input.
+    pub fn with_update_hit_review_status<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                UpdateHITReviewStatusRequest,
+            ) -> ::std::result::Result<
+                UpdateHITReviewStatusResponse,
+                RusotoError<UpdateHITReviewStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_hit_review_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_hit_type_of_hit` with what `handler` returns for its input.
+    pub fn with_update_hit_type_of_hit<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                UpdateHITTypeOfHITRequest,
+            ) -> ::std::result::Result<
+                UpdateHITTypeOfHITResponse,
+                RusotoError<UpdateHITTypeOfHITError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_hit_type_of_hit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_notification_settings` with what `handler` returns for its input.
+    pub fn with_update_notification_settings<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                UpdateNotificationSettingsRequest,
+            ) -> ::std::result::Result<
+                UpdateNotificationSettingsResponse,
+                RusotoError<UpdateNotificationSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_notification_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_qualification_type` with what `handler` returns for its input.
+    pub fn with_update_qualification_type<F>(mut self, handler: F) -> MockMechanicalTurk
+    where
+        F: Fn(
+                UpdateQualificationTypeRequest,
+            ) -> ::std::result::Result<
+                UpdateQualificationTypeResponse,
+                RusotoError<UpdateQualificationTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_qualification_type = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MechanicalTurk for MockMechanicalTurk {
+    fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> RusotoFuture<AcceptQualificationRequestResponse, AcceptQualificationRequestError> {
+        match self.accept_qualification_request {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for accept_qualification_request"),
+        }
+    }
+    fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> RusotoFuture<ApproveAssignmentResponse, ApproveAssignmentError> {
+        match self.approve_assignment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for approve_assignment"),
+        }
+    }
+    fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> RusotoFuture<AssociateQualificationWithWorkerResponse, AssociateQualificationWithWorkerError>
+    {
+        match self.associate_qualification_with_worker {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockMechanicalTurk has no handler for associate_qualification_with_worker")
+            }
+        }
+    }
+    fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> RusotoFuture<
+        CreateAdditionalAssignmentsForHITResponse,
+        CreateAdditionalAssignmentsForHITError,
+    > {
+        match self.create_additional_assignments_for_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockMechanicalTurk has no handler for create_additional_assignments_for_hit"
+            ),
+        }
+    }
+    fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> RusotoFuture<CreateHITResponse, CreateHITError> {
+        match self.create_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for create_hit"),
+        }
+    }
+    fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> RusotoFuture<CreateHITTypeResponse, CreateHITTypeError> {
+        match self.create_hit_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for create_hit_type"),
+        }
+    }
+    fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> RusotoFuture<CreateHITWithHITTypeResponse, CreateHITWithHITTypeError> {
+        match self.create_hit_with_hit_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for create_hit_with_hit_type"),
+        }
+    }
+    fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> RusotoFuture<CreateQualificationTypeResponse, CreateQualificationTypeError> {
+        match self.create_qualification_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for create_qualification_type"),
+        }
+    }
+    fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> RusotoFuture<CreateWorkerBlockResponse, CreateWorkerBlockError> {
+        match self.create_worker_block {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for create_worker_block"),
+        }
+    }
+    fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> RusotoFuture<DeleteHITResponse, DeleteHITError> {
+        match self.delete_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for delete_hit"),
+        }
+    }
+    fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> RusotoFuture<DeleteQualificationTypeResponse, DeleteQualificationTypeError> {
+        match self.delete_qualification_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for delete_qualification_type"),
+        }
+    }
+    fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> RusotoFuture<DeleteWorkerBlockResponse, DeleteWorkerBlockError> {
+        match self.delete_worker_block {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for delete_worker_block"),
+        }
+    }
+    fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> RusotoFuture<
+        DisassociateQualificationFromWorkerResponse,
+        DisassociateQualificationFromWorkerError,
+    > {
+        match self.disassociate_qualification_from_worker {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockMechanicalTurk has no handler for disassociate_qualification_from_worker"
+            ),
+        }
+    }
+    fn get_account_balance(
+        &self,
+    ) -> RusotoFuture<GetAccountBalanceResponse, GetAccountBalanceError> {
+        match self.get_account_balance {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockMechanicalTurk has no handler for get_account_balance"),
+        }
+    }
+    fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> RusotoFuture<GetAssignmentResponse, GetAssignmentError> {
+        match self.get_assignment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for get_assignment"),
+        }
+    }
+    fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> RusotoFuture<GetFileUploadURLResponse, GetFileUploadURLError> {
+        match self.get_file_upload_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for get_file_upload_url"),
+        }
+    }
+    fn get_hit(&self, input: GetHITRequest) -> RusotoFuture<GetHITResponse, GetHITError> {
+        match self.get_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for get_hit"),
+        }
+    }
+    fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> RusotoFuture<GetQualificationScoreResponse, GetQualificationScoreError> {
+        match self.get_qualification_score {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for get_qualification_score"),
+        }
+    }
+    fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> RusotoFuture<GetQualificationTypeResponse, GetQualificationTypeError> {
+        match self.get_qualification_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for get_qualification_type"),
+        }
+    }
+    fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> RusotoFuture<ListAssignmentsForHITResponse, ListAssignmentsForHITError> {
+        match self.list_assignments_for_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_assignments_for_hit"),
+        }
+    }
+    fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> RusotoFuture<ListBonusPaymentsResponse, ListBonusPaymentsError> {
+        match self.list_bonus_payments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_bonus_payments"),
+        }
+    }
+    fn list_hi_ts(&self, input: ListHITsRequest) -> RusotoFuture<ListHITsResponse, ListHITsError> {
+        match self.list_hi_ts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_hi_ts"),
+        }
+    }
+    fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> RusotoFuture<ListHITsForQualificationTypeResponse, ListHITsForQualificationTypeError> {
+        match self.list_hi_ts_for_qualification_type {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockMechanicalTurk has no handler for list_hi_ts_for_qualification_type")
+            }
+        }
+    }
+    fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> RusotoFuture<ListQualificationRequestsResponse, ListQualificationRequestsError> {
+        match self.list_qualification_requests {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_qualification_requests"),
+        }
+    }
+    fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> RusotoFuture<ListQualificationTypesResponse, ListQualificationTypesError> {
+        match self.list_qualification_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_qualification_types"),
+        }
+    }
+    fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> RusotoFuture<ListReviewPolicyResultsForHITResponse, ListReviewPolicyResultsForHITError>
+    {
+        match self.list_review_policy_results_for_hit {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockMechanicalTurk has no handler for list_review_policy_results_for_hit")
+            }
+        }
+    }
+    fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> RusotoFuture<ListReviewableHITsResponse, ListReviewableHITsError> {
+        match self.list_reviewable_hi_ts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_reviewable_hi_ts"),
+        }
+    }
+    fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> RusotoFuture<ListWorkerBlocksResponse, ListWorkerBlocksError> {
+        match self.list_worker_blocks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for list_worker_blocks"),
+        }
+    }
+    fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> RusotoFuture<ListWorkersWithQualificationTypeResponse, ListWorkersWithQualificationTypeError>
+    {
+        match self.list_workers_with_qualification_type {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockMechanicalTurk has no handler for list_workers_with_qualification_type")
+            }
+        }
+    }
+    fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> RusotoFuture<NotifyWorkersResponse, NotifyWorkersError> {
+        match self.notify_workers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for notify_workers"),
+        }
+    }
+    fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> RusotoFuture<RejectAssignmentResponse, RejectAssignmentError> {
+        match self.reject_assignment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for reject_assignment"),
+        }
+    }
+    fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> RusotoFuture<RejectQualificationRequestResponse, RejectQualificationRequestError> {
+        match self.reject_qualification_request {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for reject_qualification_request"),
+        }
     }
-
-    /// <p> The <code>SendBonus</code> operation issues a payment of money from your account to a Worker. This payment happens separately from the reward you pay to the Worker when you approve the Worker's assignment. The SendBonus operation requires the Worker's ID and the assignment ID as parameters to initiate payment of the bonus. You must include a message that explains the reason for the bonus payment, as the Worker may not be expecting the payment. Amazon Mechanical Turk collects a fee for bonus payments, similar to the HIT listing fee. This operation fails if your account does not have enough funds to pay for both the bonus and the fees. </p>
     fn send_bonus(
         &self,
         input: SendBonusRequest,
     ) -> RusotoFuture<SendBonusResponse, SendBonusError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.SendBonus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendBonusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendBonusError::from_response(response))),
-                )
-            }
-        })
+        match self.send_bonus {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for send_bonus"),
+        }
     }
-
-    /// <p> The <code>SendTestEventNotification</code> operation causes Amazon Mechanical Turk to send a notification message as if a HIT event occurred, according to the provided notification specification. This allows you to test notifications without setting up notifications for a real HIT type and trying to trigger them using the website. When you call this operation, the service attempts to send the test notification immediately. </p>
     fn send_test_event_notification(
         &self,
         input: SendTestEventNotificationRequest,
     ) -> RusotoFuture<SendTestEventNotificationResponse, SendTestEventNotificationError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.SendTestEventNotification",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendTestEventNotificationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SendTestEventNotificationError::from_response(response))
-                }))
-            }
-        })
+        match self.send_test_event_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for send_test_event_notification"),
+        }
     }
-
-    /// <p> The <code>UpdateExpirationForHIT</code> operation allows you update the expiration time of a HIT. If you update it to a time in the past, the HIT will be immediately expired. </p>
     fn update_expiration_for_hit(
         &self,
         input: UpdateExpirationForHITRequest,
     ) -> RusotoFuture<UpdateExpirationForHITResponse, UpdateExpirationForHITError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.UpdateExpirationForHIT",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateExpirationForHITResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateExpirationForHITError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_expiration_for_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for update_expiration_for_hit"),
+        }
     }
-
-    /// <p> The <code>UpdateHITReviewStatus</code> operation updates the status of a HIT. If the status is Reviewable, this operation can update the status to Reviewing, or it can revert a Reviewing HIT back to the Reviewable status. </p>
     fn update_hit_review_status(
         &self,
         input: UpdateHITReviewStatusRequest,
     ) -> RusotoFuture<UpdateHITReviewStatusResponse, UpdateHITReviewStatusError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.UpdateHITReviewStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateHITReviewStatusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateHITReviewStatusError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_hit_review_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for update_hit_review_status"),
+        }
     }
-
-    /// <p> The <code>UpdateHITTypeOfHIT</code> operation allows you to change the HITType properties of a HIT. This operation disassociates the HIT from its old HITType properties and associates it with the new HITType properties. The HIT takes on the properties of the new HITType in place of the old ones. </p>
     fn update_hit_type_of_hit(
         &self,
         input: UpdateHITTypeOfHITRequest,
     ) -> RusotoFuture<UpdateHITTypeOfHITResponse, UpdateHITTypeOfHITError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.UpdateHITTypeOfHIT",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateHITTypeOfHITResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateHITTypeOfHITError::from_response(response))),
-                )
-            }
-        })
+        match self.update_hit_type_of_hit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for update_hit_type_of_hit"),
+        }
     }
-
-    /// <p> The <code>UpdateNotificationSettings</code> operation creates, updates, disables or re-enables notifications for a HIT type. If you call the UpdateNotificationSettings operation for a HIT type that already has a notification specification, the operation replaces the old specification with a new one. You can call the UpdateNotificationSettings operation to enable or disable notifications for the HIT type, without having to modify the notification specification itself by providing updates to the Active status without specifying a new notification specification. To change the Active status of a HIT type's notifications, the HIT type must already have a notification specification, or one must be provided in the same call to <code>UpdateNotificationSettings</code>. </p>
     fn update_notification_settings(
         &self,
         input: UpdateNotificationSettingsRequest,
     ) -> RusotoFuture<UpdateNotificationSettingsResponse, UpdateNotificationSettingsError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.UpdateNotificationSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNotificationSettingsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateNotificationSettingsError::from_response(response))
-                }))
-            }
-        })
+        match self.update_notification_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for update_notification_settings"),
+        }
     }
-
-    /// <p> The <code>UpdateQualificationType</code> operation modifies the attributes of an existing Qualification type, which is represented by a QualificationType data structure. Only the owner of a Qualification type can modify its attributes. </p> <p> Most attributes of a Qualification type can be changed after the type has been created. However, the Name and Keywords fields cannot be modified. The RetryDelayInSeconds parameter can be modified or added to change the delay or to enable retries, but RetryDelayInSeconds cannot be used to disable retries. </p> <p> You can use this operation to update the test for a Qualification type. The test is updated based on the values specified for the Test, TestDurationInSeconds and AnswerKey parameters. All three parameters specify the updated test. If you are updating the test for a type, you must specify the Test and TestDurationInSeconds parameters. The AnswerKey parameter is optional; omitting it specifies that the updated test does not have an answer key. </p> <p> If you omit the Test parameter, the test for the Qualification type is unchanged. There is no way to remove a test from a Qualification type that has one. If the type already has a test, you cannot update it to be AutoGranted. If the Qualification type does not have a test and one is provided by an update, the type will henceforth have a test. </p> <p> If you want to update the test duration or answer key for an existing test without changing the questions, you must specify a Test parameter with the original questions, along with the updated values. </p> <p> If you provide an updated Test but no AnswerKey, the new test will not have an answer key. Requests for such Qualifications must be granted manually. </p> <p> You can also update the AutoGranted and AutoGrantedValue attributes of the Qualification type.</p>
     fn update_qualification_type(
         &self,
         input: UpdateQualificationTypeRequest,
     ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError> {
-        let mut request = SignedRequest::new("POST", "mturk-requester", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "MTurkRequesterServiceV20170117.UpdateQualificationType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateQualificationTypeResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateQualificationTypeError::from_response(response))
-                }))
-            }
-        })
+        match self.update_qualification_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockMechanicalTurk has no handler for update_qualification_type"),
+        }
     }
 }
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Describes an agent version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AgentVersion {
     /// <p>The configuration manager.</p>
     #[serde(rename = "ConfigurationManager")]
@@ -39,7 +40,7 @@ pub struct AgentVersion {
 
 /// <p>A description of the app.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct App {
     /// <p>The app ID.</p>
     #[serde(rename = "AppId")]
@@ -100,6 +101,7 @@ pub struct App {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssignInstanceRequest {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -110,6 +112,7 @@ pub struct AssignInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssignVolumeRequest {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -121,6 +124,7 @@ pub struct AssignVolumeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateElasticIpRequest {
     /// <p>The Elastic IP address.</p>
     #[serde(rename = "ElasticIp")]
@@ -132,6 +136,7 @@ pub struct AssociateElasticIpRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachElasticLoadBalancerRequest {
     /// <p>The Elastic Load Balancing instance's name.</p>
     #[serde(rename = "ElasticLoadBalancerName")]
@@ -174,6 +179,27 @@ pub struct AutoScalingThresholds {
     pub thresholds_wait_time: Option<i64>,
 }
 
+impl Validate for AutoScalingThresholds {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ignore_metrics_time {
+            errors.check_range(
+                &field_path(path, "ignore_metrics_time"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.thresholds_wait_time {
+            errors.check_range(
+                &field_path(path, "thresholds_wait_time"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Describes a block device mapping. This data type maps directly to the Amazon EC2 <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_BlockDeviceMapping.html">BlockDeviceMapping</a> data type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockDeviceMapping {
@@ -209,6 +235,7 @@ pub struct ChefConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloneStackRequest {
     /// <p><p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p>Auto-update - Set this parameter to <code>LATEST</code>. AWS OpsWorks Stacks automatically installs new agent versions on the stack&#39;s instances as soon as they are available.</p> </li> <li> <p>Fixed version - Set this parameter to your preferred agent version. To update the agent version, you must edit the stack configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the stack&#39;s instances.</p> </li> </ul> <p>The default setting is <code>LATEST</code>. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>. AgentVersion cannot be set to Chef 12.2.</p> <note> <p>You can also specify an agent version when you create or update an instance, which overrides the stack&#39;s default setting.</p> </note></p>
     #[serde(rename = "AgentVersion")]
@@ -300,7 +327,7 @@ pub struct CloneStackRequest {
 
 /// <p>Contains the response to a <code>CloneStack</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CloneStackResult {
     /// <p>The cloned stack ID.</p>
     #[serde(rename = "StackId")]
@@ -372,7 +399,7 @@ pub struct CloudWatchLogsLogStream {
 
 /// <p>Describes a command.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Command {
     /// <p>Date and time when the command was acknowledged.</p>
     #[serde(rename = "AcknowledgedAt")]
@@ -417,6 +444,7 @@ pub struct Command {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAppRequest {
     /// <p>A <code>Source</code> object that specifies the app repository.</p>
     #[serde(rename = "AppSource")]
@@ -467,7 +495,7 @@ pub struct CreateAppRequest {
 
 /// <p>Contains the response to a <code>CreateApp</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAppResult {
     /// <p>The app ID.</p>
     #[serde(rename = "AppId")]
@@ -476,6 +504,7 @@ pub struct CreateAppResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDeploymentRequest {
     /// <p>The app ID. This parameter is required for app deployments, but not for other deployment commands.</p>
     #[serde(rename = "AppId")]
@@ -507,7 +536,7 @@ pub struct CreateDeploymentRequest {
 
 /// <p>Contains the response to a <code>CreateDeployment</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDeploymentResult {
     /// <p>The deployment ID, which can be used with other requests to identify the deployment.</p>
     #[serde(rename = "DeploymentId")]
@@ -516,6 +545,7 @@ pub struct CreateDeploymentResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInstanceRequest {
     /// <p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p> <code>INHERIT</code> - Use the stack's default agent version setting.</p> </li> <li> <p> <i>version_number</i> - Use the specified agent version. This value overrides the stack's default setting. To update the agent version, edit the instance configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the instance.</p> </li> </ul> <p>The default setting is <code>INHERIT</code>. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>. AgentVersion cannot be set to Chef 12.2.</p>
     #[serde(rename = "AgentVersion")]
@@ -590,7 +620,7 @@ pub struct CreateInstanceRequest {
 
 /// <p>Contains the response to a <code>CreateInstance</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInstanceResult {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -599,6 +629,7 @@ pub struct CreateInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLayerRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p> <p>To create a cluster layer, set the <code>EcsClusterArn</code> attribute to the cluster's ARN.</p>
     #[serde(rename = "Attributes")]
@@ -672,7 +703,7 @@ pub struct CreateLayerRequest {
 
 /// <p>Contains the response to a <code>CreateLayer</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLayerResult {
     /// <p>The layer ID.</p>
     #[serde(rename = "LayerId")]
@@ -681,6 +712,7 @@ pub struct CreateLayerResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStackRequest {
     /// <p><p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p>Auto-update - Set this parameter to <code>LATEST</code>. AWS OpsWorks Stacks automatically installs new agent versions on the stack&#39;s instances as soon as they are available.</p> </li> <li> <p>Fixed version - Set this parameter to your preferred agent version. To update the agent version, you must edit the stack configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the stack&#39;s instances.</p> </li> </ul> <p>The default setting is the most recent release of the agent. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>. AgentVersion cannot be set to Chef 12.2.</p> <note> <p>You can also specify an agent version when you create or update an instance, which overrides the stack&#39;s default setting.</p> </note></p>
     #[serde(rename = "AgentVersion")]
@@ -758,7 +790,7 @@ pub struct CreateStackRequest {
 
 /// <p>Contains the response to a <code>CreateStack</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStackResult {
     /// <p>The stack ID, which is an opaque string that you use to identify the stack when performing actions such as <code>DescribeStacks</code>.</p>
     #[serde(rename = "StackId")]
@@ -767,6 +799,7 @@ pub struct CreateStackResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserProfileRequest {
     /// <p>Whether users can specify their own SSH public key through the My Settings page. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-settingsshkey.html">Setting an IAM User's Public SSH Key</a>.</p>
     #[serde(rename = "AllowSelfManagement")]
@@ -787,7 +820,7 @@ pub struct CreateUserProfileRequest {
 
 /// <p>Contains the response to a <code>CreateUserProfile</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserProfileResult {
     /// <p>The user's IAM ARN.</p>
     #[serde(rename = "IamUserArn")]
@@ -813,6 +846,7 @@ pub struct DataSource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAppRequest {
     /// <p>The app ID.</p>
     #[serde(rename = "AppId")]
@@ -820,6 +854,7 @@ pub struct DeleteAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInstanceRequest {
     /// <p>Whether to delete the instance Elastic IP address.</p>
     #[serde(rename = "DeleteElasticIp")]
@@ -835,6 +870,7 @@ pub struct DeleteInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLayerRequest {
     /// <p>The layer ID.</p>
     #[serde(rename = "LayerId")]
@@ -842,6 +878,7 @@ pub struct DeleteLayerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStackRequest {
     /// <p>The stack ID.</p>
     #[serde(rename = "StackId")]
@@ -849,6 +886,7 @@ pub struct DeleteStackRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserProfileRequest {
     /// <p>The user's IAM ARN. This can also be a federated user's ARN.</p>
     #[serde(rename = "IamUserArn")]
@@ -857,7 +895,7 @@ pub struct DeleteUserProfileRequest {
 
 /// <p>Describes a deployment of a stack or app.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Deployment {
     /// <p>The app ID.</p>
     #[serde(rename = "AppId")]
@@ -922,6 +960,7 @@ pub struct DeploymentCommand {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterEcsClusterRequest {
     /// <p>The cluster's Amazon Resource Number (ARN).</p>
     #[serde(rename = "EcsClusterArn")]
@@ -929,6 +968,7 @@ pub struct DeregisterEcsClusterRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterElasticIpRequest {
     /// <p>The Elastic IP address.</p>
     #[serde(rename = "ElasticIp")]
@@ -936,6 +976,7 @@ pub struct DeregisterElasticIpRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterInstanceRequest {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -943,6 +984,7 @@ pub struct DeregisterInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterRdsDbInstanceRequest {
     /// <p>The Amazon RDS instance's ARN.</p>
     #[serde(rename = "RdsDbInstanceArn")]
@@ -950,6 +992,7 @@ pub struct DeregisterRdsDbInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterVolumeRequest {
     /// <p>The AWS OpsWorks Stacks volume ID, which is the GUID that AWS OpsWorks Stacks assigned to the instance when you registered the volume with the stack, not the Amazon EC2 volume ID.</p>
     #[serde(rename = "VolumeId")]
@@ -957,6 +1000,7 @@ pub struct DeregisterVolumeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAgentVersionsRequest {
     /// <p>The configuration manager.</p>
     #[serde(rename = "ConfigurationManager")]
@@ -970,7 +1014,7 @@ pub struct DescribeAgentVersionsRequest {
 
 /// <p>Contains the response to a <code>DescribeAgentVersions</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAgentVersionsResult {
     /// <p>The agent versions for the specified stack or configuration manager. Note that this value is the complete version number, not the abbreviated number used by the console.</p>
     #[serde(rename = "AgentVersions")]
@@ -979,6 +1023,7 @@ pub struct DescribeAgentVersionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAppsRequest {
     /// <p>An array of app IDs for the apps to be described. If you use this parameter, <code>DescribeApps</code> returns a description of the specified apps. Otherwise, it returns a description of every app.</p>
     #[serde(rename = "AppIds")]
@@ -992,7 +1037,7 @@ pub struct DescribeAppsRequest {
 
 /// <p>Contains the response to a <code>DescribeApps</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAppsResult {
     /// <p>An array of <code>App</code> objects that describe the specified apps. </p>
     #[serde(rename = "Apps")]
@@ -1001,6 +1046,7 @@ pub struct DescribeAppsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCommandsRequest {
     /// <p>An array of command IDs. If you include this parameter, <code>DescribeCommands</code> returns a description of the specified commands. Otherwise, it returns a description of every command.</p>
     #[serde(rename = "CommandIds")]
@@ -1018,7 +1064,7 @@ pub struct DescribeCommandsRequest {
 
 /// <p>Contains the response to a <code>DescribeCommands</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCommandsResult {
     /// <p>An array of <code>Command</code> objects that describe each of the specified commands.</p>
     #[serde(rename = "Commands")]
@@ -1027,6 +1073,7 @@ pub struct DescribeCommandsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDeploymentsRequest {
     /// <p>The app ID. If you include this parameter, the command returns a description of the commands associated with the specified app.</p>
     #[serde(rename = "AppId")]
@@ -1044,7 +1091,7 @@ pub struct DescribeDeploymentsRequest {
 
 /// <p>Contains the response to a <code>DescribeDeployments</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDeploymentsResult {
     /// <p>An array of <code>Deployment</code> objects that describe the deployments.</p>
     #[serde(rename = "Deployments")]
@@ -1053,6 +1100,7 @@ pub struct DescribeDeploymentsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEcsClustersRequest {
     /// <p>A list of ARNs, one for each cluster to be described.</p>
     #[serde(rename = "EcsClusterArns")]
@@ -1074,7 +1122,7 @@ pub struct DescribeEcsClustersRequest {
 
 /// <p>Contains the response to a <code>DescribeEcsClusters</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEcsClustersResult {
     /// <p>A list of <code>EcsCluster</code> objects containing the cluster descriptions.</p>
     #[serde(rename = "EcsClusters")]
@@ -1087,6 +1135,7 @@ pub struct DescribeEcsClustersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeElasticIpsRequest {
     /// <p>The instance ID. If you include this parameter, <code>DescribeElasticIps</code> returns a description of the Elastic IP addresses associated with the specified instance.</p>
     #[serde(rename = "InstanceId")]
@@ -1104,7 +1153,7 @@ pub struct DescribeElasticIpsRequest {
 
 /// <p>Contains the response to a <code>DescribeElasticIps</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeElasticIpsResult {
     /// <p>An <code>ElasticIps</code> object that describes the specified Elastic IP addresses.</p>
     #[serde(rename = "ElasticIps")]
@@ -1113,6 +1162,7 @@ pub struct DescribeElasticIpsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeElasticLoadBalancersRequest {
     /// <p>A list of layer IDs. The action describes the Elastic Load Balancing instances for the specified layers.</p>
     #[serde(rename = "LayerIds")]
@@ -1126,7 +1176,7 @@ pub struct DescribeElasticLoadBalancersRequest {
 
 /// <p>Contains the response to a <code>DescribeElasticLoadBalancers</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeElasticLoadBalancersResult {
     /// <p>A list of <code>ElasticLoadBalancer</code> objects that describe the specified Elastic Load Balancing instances.</p>
     #[serde(rename = "ElasticLoadBalancers")]
@@ -1135,6 +1185,7 @@ pub struct DescribeElasticLoadBalancersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancesRequest {
     /// <p>An array of instance IDs to be described. If you use this parameter, <code>DescribeInstances</code> returns a description of the specified instances. Otherwise, it returns a description of every instance.</p>
     #[serde(rename = "InstanceIds")]
@@ -1152,7 +1203,7 @@ pub struct DescribeInstancesRequest {
 
 /// <p>Contains the response to a <code>DescribeInstances</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstancesResult {
     /// <p>An array of <code>Instance</code> objects that describe the instances.</p>
     #[serde(rename = "Instances")]
@@ -1161,6 +1212,7 @@ pub struct DescribeInstancesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLayersRequest {
     /// <p>An array of layer IDs that specify the layers to be described. If you omit this parameter, <code>DescribeLayers</code> returns a description of every layer in the specified stack.</p>
     #[serde(rename = "LayerIds")]
@@ -1174,7 +1226,7 @@ pub struct DescribeLayersRequest {
 
 /// <p>Contains the response to a <code>DescribeLayers</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLayersResult {
     /// <p>An array of <code>Layer</code> objects that describe the layers.</p>
     #[serde(rename = "Layers")]
@@ -1183,6 +1235,7 @@ pub struct DescribeLayersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoadBasedAutoScalingRequest {
     /// <p>An array of layer IDs.</p>
     #[serde(rename = "LayerIds")]
@@ -1191,7 +1244,7 @@ pub struct DescribeLoadBasedAutoScalingRequest {
 
 /// <p>Contains the response to a <code>DescribeLoadBasedAutoScaling</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLoadBasedAutoScalingResult {
     /// <p>An array of <code>LoadBasedAutoScalingConfiguration</code> objects that describe each layer's configuration.</p>
     #[serde(rename = "LoadBasedAutoScalingConfigurations")]
@@ -1201,7 +1254,7 @@ pub struct DescribeLoadBasedAutoScalingResult {
 
 /// <p>Contains the response to a <code>DescribeMyUserProfile</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMyUserProfileResult {
     /// <p>A <code>UserProfile</code> object that describes the user's SSH information.</p>
     #[serde(rename = "UserProfile")]
@@ -1211,7 +1264,7 @@ pub struct DescribeMyUserProfileResult {
 
 /// <p>The response to a <code>DescribeOperatingSystems</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOperatingSystemsResponse {
     /// <p>Contains information in response to a <code>DescribeOperatingSystems</code> request.</p>
     #[serde(rename = "OperatingSystems")]
@@ -1220,6 +1273,7 @@ pub struct DescribeOperatingSystemsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePermissionsRequest {
     /// <p>The user's IAM ARN. This can also be a federated user's ARN. For more information about IAM ARNs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">Using Identifiers</a>.</p>
     #[serde(rename = "IamUserArn")]
@@ -1233,7 +1287,7 @@ pub struct DescribePermissionsRequest {
 
 /// <p>Contains the response to a <code>DescribePermissions</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePermissionsResult {
     /// <p><p>An array of <code>Permission</code> objects that describe the stack permissions.</p> <ul> <li> <p>If the request object contains only a stack ID, the array contains a <code>Permission</code> object with permissions for each of the stack IAM ARNs.</p> </li> <li> <p>If the request object contains only an IAM ARN, the array contains a <code>Permission</code> object with permissions for each of the user&#39;s stack IDs.</p> </li> <li> <p>If the request contains a stack ID and an IAM ARN, the array contains a single <code>Permission</code> object with permissions for the specified stack and IAM ARN.</p> </li> </ul></p>
     #[serde(rename = "Permissions")]
@@ -1242,6 +1296,7 @@ pub struct DescribePermissionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRaidArraysRequest {
     /// <p>The instance ID. If you use this parameter, <code>DescribeRaidArrays</code> returns descriptions of the RAID arrays associated with the specified instance. </p>
     #[serde(rename = "InstanceId")]
@@ -1259,7 +1314,7 @@ pub struct DescribeRaidArraysRequest {
 
 /// <p>Contains the response to a <code>DescribeRaidArrays</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRaidArraysResult {
     /// <p>A <code>RaidArrays</code> object that describes the specified RAID arrays.</p>
     #[serde(rename = "RaidArrays")]
@@ -1268,6 +1323,7 @@ pub struct DescribeRaidArraysResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRdsDbInstancesRequest {
     /// <p>An array containing the ARNs of the instances to be described.</p>
     #[serde(rename = "RdsDbInstanceArns")]
@@ -1280,7 +1336,7 @@ pub struct DescribeRdsDbInstancesRequest {
 
 /// <p>Contains the response to a <code>DescribeRdsDbInstances</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRdsDbInstancesResult {
     /// <p>An a array of <code>RdsDbInstance</code> objects that describe the instances.</p>
     #[serde(rename = "RdsDbInstances")]
@@ -1289,6 +1345,7 @@ pub struct DescribeRdsDbInstancesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServiceErrorsRequest {
     /// <p>The instance ID. If you use this parameter, <code>DescribeServiceErrors</code> returns descriptions of the errors associated with the specified instance.</p>
     #[serde(rename = "InstanceId")]
@@ -1306,7 +1363,7 @@ pub struct DescribeServiceErrorsRequest {
 
 /// <p>Contains the response to a <code>DescribeServiceErrors</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServiceErrorsResult {
     /// <p>An array of <code>ServiceError</code> objects that describe the specified service errors.</p>
     #[serde(rename = "ServiceErrors")]
@@ -1315,6 +1372,7 @@ pub struct DescribeServiceErrorsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackProvisioningParametersRequest {
     /// <p>The stack ID.</p>
     #[serde(rename = "StackId")]
@@ -1323,7 +1381,7 @@ pub struct DescribeStackProvisioningParametersRequest {
 
 /// <p>Contains the response to a <code>DescribeStackProvisioningParameters</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStackProvisioningParametersResult {
     /// <p>The AWS OpsWorks Stacks agent installer's URL.</p>
     #[serde(rename = "AgentInstallerUrl")]
@@ -1336,6 +1394,7 @@ pub struct DescribeStackProvisioningParametersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStackSummaryRequest {
     /// <p>The stack ID.</p>
     #[serde(rename = "StackId")]
@@ -1344,7 +1403,7 @@ pub struct DescribeStackSummaryRequest {
 
 /// <p>Contains the response to a <code>DescribeStackSummary</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStackSummaryResult {
     /// <p>A <code>StackSummary</code> object that contains the results.</p>
     #[serde(rename = "StackSummary")]
@@ -1353,6 +1412,7 @@ pub struct DescribeStackSummaryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStacksRequest {
     /// <p>An array of stack IDs that specify the stacks to be described. If you omit this parameter, <code>DescribeStacks</code> returns a description of every stack.</p>
     #[serde(rename = "StackIds")]
@@ -1362,7 +1422,7 @@ pub struct DescribeStacksRequest {
 
 /// <p>Contains the response to a <code>DescribeStacks</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStacksResult {
     /// <p>An array of <code>Stack</code> objects that describe the stacks.</p>
     #[serde(rename = "Stacks")]
@@ -1371,6 +1431,7 @@ pub struct DescribeStacksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTimeBasedAutoScalingRequest {
     /// <p>An array of instance IDs.</p>
     #[serde(rename = "InstanceIds")]
@@ -1379,7 +1440,7 @@ pub struct DescribeTimeBasedAutoScalingRequest {
 
 /// <p>Contains the response to a <code>DescribeTimeBasedAutoScaling</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTimeBasedAutoScalingResult {
     /// <p>An array of <code>TimeBasedAutoScalingConfiguration</code> objects that describe the configuration for the specified instances.</p>
     #[serde(rename = "TimeBasedAutoScalingConfigurations")]
@@ -1388,6 +1449,7 @@ pub struct DescribeTimeBasedAutoScalingResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserProfilesRequest {
     /// <p>An array of IAM or federated user ARNs that identify the users to be described.</p>
     #[serde(rename = "IamUserArns")]
@@ -1397,7 +1459,7 @@ pub struct DescribeUserProfilesRequest {
 
 /// <p>Contains the response to a <code>DescribeUserProfiles</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserProfilesResult {
     /// <p>A <code>Users</code> object that describes the specified users.</p>
     #[serde(rename = "UserProfiles")]
@@ -1406,6 +1468,7 @@ pub struct DescribeUserProfilesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVolumesRequest {
     /// <p>The instance ID. If you use this parameter, <code>DescribeVolumes</code> returns descriptions of the volumes associated with the specified instance.</p>
     #[serde(rename = "InstanceId")]
@@ -1427,7 +1490,7 @@ pub struct DescribeVolumesRequest {
 
 /// <p>Contains the response to a <code>DescribeVolumes</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVolumesResult {
     /// <p>An array of volume IDs.</p>
     #[serde(rename = "Volumes")]
@@ -1436,6 +1499,7 @@ pub struct DescribeVolumesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachElasticLoadBalancerRequest {
     /// <p>The Elastic Load Balancing instance's name.</p>
     #[serde(rename = "ElasticLoadBalancerName")]
@@ -1446,6 +1510,7 @@ pub struct DetachElasticLoadBalancerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateElasticIpRequest {
     /// <p>The Elastic IP address.</p>
     #[serde(rename = "ElasticIp")]
@@ -1479,7 +1544,7 @@ pub struct EbsBlockDevice {
 
 /// <p>Describes a registered Amazon ECS cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EcsCluster {
     /// <p>The cluster's ARN.</p>
     #[serde(rename = "EcsClusterArn")]
@@ -1501,7 +1566,7 @@ pub struct EcsCluster {
 
 /// <p>Describes an Elastic IP address.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ElasticIp {
     /// <p>The domain.</p>
     #[serde(rename = "Domain")]
@@ -1527,7 +1592,7 @@ pub struct ElasticIp {
 
 /// <p>Describes an Elastic Load Balancing instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ElasticLoadBalancer {
     /// <p>A list of Availability Zones.</p>
     #[serde(rename = "AvailabilityZones")]
@@ -1583,6 +1648,7 @@ pub struct EnvironmentVariable {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostnameSuggestionRequest {
     /// <p>The layer ID.</p>
     #[serde(rename = "LayerId")]
@@ -1591,7 +1657,7 @@ pub struct GetHostnameSuggestionRequest {
 
 /// <p>Contains the response to a <code>GetHostnameSuggestion</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetHostnameSuggestionResult {
     /// <p>The generated host name.</p>
     #[serde(rename = "Hostname")]
@@ -1604,6 +1670,7 @@ pub struct GetHostnameSuggestionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GrantAccessRequest {
     /// <p>The instance's AWS OpsWorks Stacks ID.</p>
     #[serde(rename = "InstanceId")]
@@ -1614,9 +1681,22 @@ pub struct GrantAccessRequest {
     pub valid_for_in_minutes: Option<i64>,
 }
 
+impl Validate for GrantAccessRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.valid_for_in_minutes {
+            errors.check_range(
+                &field_path(path, "valid_for_in_minutes"),
+                *value,
+                Some(60),
+                Some(1440),
+            );
+        }
+    }
+}
+
 /// <p>Contains the response to a <code>GrantAccess</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GrantAccessResult {
     /// <p>A <code>TemporaryCredential</code> object that contains the data needed to log in to the instance by RDP clients, such as the Microsoft Remote Desktop Connection.</p>
     #[serde(rename = "TemporaryCredential")]
@@ -1626,7 +1706,7 @@ pub struct GrantAccessResult {
 
 /// <p>Describes an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Instance {
     /// <p>The agent version. This parameter is set to <code>INHERIT</code> if the instance inherits the default stack setting or to a a version number for a fixed agent version.</p>
     #[serde(rename = "AgentVersion")]
@@ -1796,6 +1876,7 @@ pub struct Instance {
 
 /// <p>Contains a description of an Amazon EC2 instance from the Amazon EC2 metadata service. For more information, see <a href="https://docs.aws.amazon.com/sdkfornet/latest/apidocs/Index.html">Instance Metadata and User Data</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceIdentity {
     /// <p>A JSON document that contains the metadata.</p>
     #[serde(rename = "Document")]
@@ -1809,7 +1890,7 @@ pub struct InstanceIdentity {
 
 /// <p>Describes how many instances a stack has for each status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstancesCount {
     /// <p>The number of instances in the Assigning state.</p>
     #[serde(rename = "Assigning")]
@@ -1895,7 +1976,7 @@ pub struct InstancesCount {
 
 /// <p>Describes a layer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Layer {
     /// <p>The Amazon Resource Number (ARN) of a layer.</p>
     #[serde(rename = "Arn")]
@@ -2001,6 +2082,7 @@ pub struct LifecycleEventConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsRequest {
     /// <p>Do not use. A validation exception occurs if you add a <code>MaxResults</code> parameter to a <code>ListTagsRequest</code> call. </p>
     #[serde(rename = "MaxResults")]
@@ -2017,7 +2099,7 @@ pub struct ListTagsRequest {
 
 /// <p>Contains the response to a <code>ListTags</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsResult {
     /// <p>If a paginated request does not return all of the remaining results, this parameter is set to a token that you can assign to the request object's <code>NextToken</code> parameter to get the next set of results. If the previous paginated request returned all of the remaining results, this parameter is set to <code>null</code>. </p>
     #[serde(rename = "NextToken")]
@@ -2031,7 +2113,7 @@ pub struct ListTagsResult {
 
 /// <p>Describes a layer's load-based auto scaling configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LoadBasedAutoScalingConfiguration {
     /// <p>An <code>AutoScalingThresholds</code> object that describes the downscaling configuration, which defines how and when AWS OpsWorks Stacks reduces the number of instances.</p>
     #[serde(rename = "DownScaling")]
@@ -2053,7 +2135,7 @@ pub struct LoadBasedAutoScalingConfiguration {
 
 /// <p>Describes supported operating systems in AWS OpsWorks Stacks.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OperatingSystem {
     /// <p>Supported configuration manager name and versions for an AWS OpsWorks Stacks operating system.</p>
     #[serde(rename = "ConfigurationManagers")]
@@ -2087,7 +2169,7 @@ pub struct OperatingSystem {
 
 /// <p>A block that contains information about the configuration manager (Chef) and the versions of the configuration manager that are supported for an operating system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OperatingSystemConfigurationManager {
     /// <p>The name of the configuration manager, which is Chef.</p>
     #[serde(rename = "Name")]
@@ -2101,7 +2183,7 @@ pub struct OperatingSystemConfigurationManager {
 
 /// <p>Describes stack or user permissions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Permission {
     /// <p>Whether the user can use SSH.</p>
     #[serde(rename = "AllowSsh")]
@@ -2127,7 +2209,7 @@ pub struct Permission {
 
 /// <p>Describes an instance's RAID array.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RaidArray {
     /// <p>The array's Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -2185,7 +2267,7 @@ pub struct RaidArray {
 
 /// <p>Describes an Amazon RDS instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RdsDbInstance {
     /// <p>The instance's address.</p>
     #[serde(rename = "Address")]
@@ -2226,6 +2308,7 @@ pub struct RdsDbInstance {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootInstanceRequest {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -2258,6 +2341,7 @@ pub struct Recipes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterEcsClusterRequest {
     /// <p>The cluster's ARN.</p>
     #[serde(rename = "EcsClusterArn")]
@@ -2269,7 +2353,7 @@ pub struct RegisterEcsClusterRequest {
 
 /// <p>Contains the response to a <code>RegisterEcsCluster</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterEcsClusterResult {
     /// <p>The cluster's ARN.</p>
     #[serde(rename = "EcsClusterArn")]
@@ -2278,6 +2362,7 @@ pub struct RegisterEcsClusterResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterElasticIpRequest {
     /// <p>The Elastic IP address.</p>
     #[serde(rename = "ElasticIp")]
@@ -2289,7 +2374,7 @@ pub struct RegisterElasticIpRequest {
 
 /// <p>Contains the response to a <code>RegisterElasticIp</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterElasticIpResult {
     /// <p>The Elastic IP address.</p>
     #[serde(rename = "ElasticIp")]
@@ -2298,6 +2383,7 @@ pub struct RegisterElasticIpResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterInstanceRequest {
     /// <p>The instance's hostname.</p>
     #[serde(rename = "Hostname")]
@@ -2330,7 +2416,7 @@ pub struct RegisterInstanceRequest {
 
 /// <p>Contains the response to a <code>RegisterInstanceResult</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterInstanceResult {
     /// <p>The registered instance's AWS OpsWorks Stacks ID.</p>
     #[serde(rename = "InstanceId")]
@@ -2339,6 +2425,7 @@ pub struct RegisterInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterRdsDbInstanceRequest {
     /// <p>The database password.</p>
     #[serde(rename = "DbPassword")]
@@ -2355,6 +2442,7 @@ pub struct RegisterRdsDbInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterVolumeRequest {
     /// <p>The Amazon EBS volume ID.</p>
     #[serde(rename = "Ec2VolumeId")]
@@ -2367,7 +2455,7 @@ pub struct RegisterVolumeRequest {
 
 /// <p>Contains the response to a <code>RegisterVolume</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterVolumeResult {
     /// <p>The volume ID.</p>
     #[serde(rename = "VolumeId")]
@@ -2377,7 +2465,7 @@ pub struct RegisterVolumeResult {
 
 /// <p>A registered instance's reported operating system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReportedOs {
     /// <p>The operating system family.</p>
     #[serde(rename = "Family")]
@@ -2395,7 +2483,7 @@ pub struct ReportedOs {
 
 /// <p>Describes a user's SSH information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SelfUserProfile {
     /// <p>The user's IAM ARN.</p>
     #[serde(rename = "IamUserArn")]
@@ -2417,7 +2505,7 @@ pub struct SelfUserProfile {
 
 /// <p>Describes an AWS OpsWorks Stacks service error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceError {
     /// <p>When the error occurred.</p>
     #[serde(rename = "CreatedAt")]
@@ -2446,6 +2534,7 @@ pub struct ServiceError {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLoadBasedAutoScalingRequest {
     /// <p>An <code>AutoScalingThresholds</code> object with the downscaling threshold configuration. If the load falls below these thresholds for a specified amount of time, AWS OpsWorks Stacks stops a specified number of instances.</p>
     #[serde(rename = "DownScaling")]
@@ -2464,7 +2553,19 @@ pub struct SetLoadBasedAutoScalingRequest {
     pub up_scaling: Option<AutoScalingThresholds>,
 }
 
+impl Validate for SetLoadBasedAutoScalingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.down_scaling {
+            value.validate_at(&field_path(path, "down_scaling"), errors);
+        }
+        if let Some(ref value) = self.up_scaling {
+            value.validate_at(&field_path(path, "up_scaling"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetPermissionRequest {
     /// <p>The user is allowed to use SSH to communicate with the instance.</p>
     #[serde(rename = "AllowSsh")]
@@ -2487,6 +2588,7 @@ pub struct SetPermissionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetTimeBasedAutoScalingRequest {
     /// <p>An <code>AutoScalingSchedule</code> with the instance schedule.</p>
     #[serde(rename = "AutoScalingSchedule")]
@@ -2556,7 +2658,7 @@ pub struct SslConfiguration {
 
 /// <p>Describes a stack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Stack {
     /// <p>The agent version. This parameter is set to <code>LATEST</code> for auto-update. or a version number for a fixed agent version.</p>
     #[serde(rename = "AgentVersion")]
@@ -2663,7 +2765,7 @@ pub struct StackConfigurationManager {
 
 /// <p>Summarizes the number of layers, instances, and apps in a stack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StackSummary {
     /// <p>The number of apps.</p>
     #[serde(rename = "AppsCount")]
@@ -2692,6 +2794,7 @@ pub struct StackSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartInstanceRequest {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -2699,6 +2802,7 @@ pub struct StartInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartStackRequest {
     /// <p>The stack ID.</p>
     #[serde(rename = "StackId")]
@@ -2706,6 +2810,7 @@ pub struct StartStackRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopInstanceRequest {
     /// <p>Specifies whether to force an instance to stop. If the instance's root device type is <code>ebs</code>, or EBS-backed, adding the <code>Force</code> parameter to the <code>StopInstances</code> API call disassociates the AWS OpsWorks Stacks instance from EC2, and forces deletion of <i>only</i> the OpsWorks Stacks instance. You must also delete the formerly-associated instance in EC2 after troubleshooting and replacing the AWS OpsWorks Stacks instance with a new one.</p>
     #[serde(rename = "Force")]
@@ -2717,6 +2822,7 @@ pub struct StopInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopStackRequest {
     /// <p>The stack ID.</p>
     #[serde(rename = "StackId")]
@@ -2724,6 +2830,7 @@ pub struct StopStackRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The stack or layer's Amazon Resource Number (ARN).</p>
     #[serde(rename = "ResourceArn")]
@@ -2735,7 +2842,7 @@ pub struct TagResourceRequest {
 
 /// <p>Contains the data needed by RDP clients such as the Microsoft Remote Desktop Connection to log in to the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TemporaryCredential {
     /// <p>The instance's AWS OpsWorks Stacks ID.</p>
     #[serde(rename = "InstanceId")]
@@ -2757,7 +2864,7 @@ pub struct TemporaryCredential {
 
 /// <p>Describes an instance's time-based auto scaling configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TimeBasedAutoScalingConfiguration {
     /// <p>A <code>WeeklyAutoScalingSchedule</code> object with the instance schedule.</p>
     #[serde(rename = "AutoScalingSchedule")]
@@ -2770,6 +2877,7 @@ pub struct TimeBasedAutoScalingConfiguration {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnassignInstanceRequest {
     /// <p>The instance ID.</p>
     #[serde(rename = "InstanceId")]
@@ -2777,6 +2885,7 @@ pub struct UnassignInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnassignVolumeRequest {
     /// <p>The volume ID.</p>
     #[serde(rename = "VolumeId")]
@@ -2784,6 +2893,7 @@ pub struct UnassignVolumeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The stack or layer's Amazon Resource Number (ARN).</p>
     #[serde(rename = "ResourceArn")]
@@ -2794,6 +2904,7 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAppRequest {
     /// <p>The app ID.</p>
     #[serde(rename = "AppId")]
@@ -2841,6 +2952,7 @@ pub struct UpdateAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateElasticIpRequest {
     /// <p>The IP address for which you want to update the name.</p>
     #[serde(rename = "ElasticIp")]
@@ -2852,6 +2964,7 @@ pub struct UpdateElasticIpRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateInstanceRequest {
     /// <p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p> <code>INHERIT</code> - Use the stack's default agent version setting.</p> </li> <li> <p> <i>version_number</i> - Use the specified agent version. This value overrides the stack's default setting. To update the agent version, you must edit the instance configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the instance.</p> </li> </ul> <p>The default setting is <code>INHERIT</code>. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>.</p> <p>AgentVersion cannot be set to Chef 12.2.</p>
     #[serde(rename = "AgentVersion")]
@@ -2903,6 +3016,7 @@ pub struct UpdateInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLayerRequest {
     /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
@@ -2974,6 +3088,7 @@ pub struct UpdateLayerRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMyUserProfileRequest {
     /// <p>The user's SSH public key.</p>
     #[serde(rename = "SshPublicKey")]
@@ -2982,6 +3097,7 @@ pub struct UpdateMyUserProfileRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRdsDbInstanceRequest {
     /// <p>The database password.</p>
     #[serde(rename = "DbPassword")]
@@ -2997,6 +3113,7 @@ pub struct UpdateRdsDbInstanceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStackRequest {
     /// <p><p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p>Auto-update - Set this parameter to <code>LATEST</code>. AWS OpsWorks Stacks automatically installs new agent versions on the stack&#39;s instances as soon as they are available.</p> </li> <li> <p>Fixed version - Set this parameter to your preferred agent version. To update the agent version, you must edit the stack configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the stack&#39;s instances.</p> </li> </ul> <p>The default setting is <code>LATEST</code>. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>. AgentVersion cannot be set to Chef 12.2.</p> <note> <p>You can also specify an agent version when you create or update an instance, which overrides the stack&#39;s default setting.</p> </note></p>
     #[serde(rename = "AgentVersion")]
@@ -3072,6 +3189,7 @@ pub struct UpdateStackRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserProfileRequest {
     /// <p>Whether users can specify their own SSH public key through the My Settings page. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-settingsshkey.html">Managing User Permissions</a>.</p>
     #[serde(rename = "AllowSelfManagement")]
@@ -3091,6 +3209,7 @@ pub struct UpdateUserProfileRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVolumeRequest {
     /// <p>The new mount point.</p>
     #[serde(rename = "MountPoint")]
@@ -3107,7 +3226,7 @@ pub struct UpdateVolumeRequest {
 
 /// <p>Describes a user's SSH information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserProfile {
     /// <p>Whether users can specify their own SSH public key through the My Settings page. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-settingsshkey.html">Managing User Permissions</a>.</p>
     #[serde(rename = "AllowSelfManagement")]
@@ -3133,7 +3252,7 @@ pub struct UserProfile {
 
 /// <p>Describes an instance's Amazon EBS volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Volume {
     /// <p>The volume Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p>
     #[serde(rename = "AvailabilityZone")]
@@ -3270,7 +3389,12 @@ impl AssignInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssignInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3303,7 +3427,12 @@ impl AssignVolumeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssignVolumeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3336,7 +3465,12 @@ impl AssociateElasticIpError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssociateElasticIpError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3371,7 +3505,12 @@ impl AttachElasticLoadBalancerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3404,7 +3543,12 @@ impl CloneStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CloneStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3437,7 +3581,12 @@ impl CreateAppError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateAppError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3470,7 +3619,12 @@ impl CreateDeploymentError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateDeploymentError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3503,7 +3657,12 @@ impl CreateInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3536,7 +3695,12 @@ impl CreateLayerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateLayerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3563,7 +3727,12 @@ impl CreateStackError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateStackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3588,7 +3757,12 @@ impl CreateUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3619,7 +3793,12 @@ impl DeleteAppError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3652,7 +3831,12 @@ impl DeleteInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3685,7 +3869,12 @@ impl DeleteLayerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteLayerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3718,7 +3907,12 @@ impl DeleteStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3751,7 +3945,12 @@ impl DeleteUserProfileError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3786,7 +3985,12 @@ impl DeregisterEcsClusterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3821,7 +4025,12 @@ impl DeregisterElasticIpError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3854,7 +4063,12 @@ impl DeregisterInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeregisterInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3889,7 +4103,12 @@ impl DeregisterRdsDbInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3922,7 +4141,12 @@ impl DeregisterVolumeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeregisterVolumeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3957,7 +4181,12 @@ impl DescribeAgentVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3990,7 +4219,12 @@ impl DescribeAppsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeAppsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4023,7 +4257,12 @@ impl DescribeCommandsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeCommandsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4058,7 +4297,12 @@ impl DescribeDeploymentsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4093,7 +4337,12 @@ impl DescribeEcsClustersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4126,7 +4375,12 @@ impl DescribeElasticIpsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeElasticIpsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4163,7 +4417,12 @@ impl DescribeElasticLoadBalancersError {
                         DescribeElasticLoadBalancersError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4196,7 +4455,12 @@ impl DescribeInstancesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeInstancesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4229,7 +4493,12 @@ impl DescribeLayersError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeLayersError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4266,7 +4535,12 @@ impl DescribeLoadBasedAutoScalingError {
                         DescribeLoadBasedAutoScalingError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4293,7 +4567,12 @@ impl DescribeMyUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeMyUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4318,7 +4597,12 @@ impl DescribeOperatingSystemsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeOperatingSystemsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4351,7 +4635,12 @@ impl DescribePermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4384,7 +4673,12 @@ impl DescribeRaidArraysError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeRaidArraysError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4419,7 +4713,12 @@ impl DescribeRdsDbInstancesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4454,7 +4753,12 @@ impl DescribeServiceErrorsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4491,7 +4795,12 @@ impl DescribeStackProvisioningParametersError {
                         DescribeStackProvisioningParametersError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4526,7 +4835,12 @@ impl DescribeStackSummaryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4559,7 +4873,12 @@ impl DescribeStacksError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4596,7 +4915,12 @@ impl DescribeTimeBasedAutoScalingError {
                         DescribeTimeBasedAutoScalingError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4631,7 +4955,12 @@ impl DescribeUserProfilesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4664,7 +4993,12 @@ impl DescribeVolumesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeVolumesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4699,7 +5033,12 @@ impl DetachElasticLoadBalancerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4734,7 +5073,12 @@ impl DisassociateElasticIpError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4769,7 +5113,12 @@ impl GetHostnameSuggestionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4802,7 +5151,12 @@ impl GrantAccessError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GrantAccessError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4835,7 +5189,12 @@ impl ListTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4868,7 +5227,12 @@ impl RebootInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RebootInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4901,7 +5265,12 @@ impl RegisterEcsClusterError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RegisterEcsClusterError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4934,7 +5303,12 @@ impl RegisterElasticIpError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RegisterElasticIpError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4967,7 +5341,12 @@ impl RegisterInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RegisterInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5002,7 +5381,12 @@ impl RegisterRdsDbInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5035,7 +5419,12 @@ impl RegisterVolumeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RegisterVolumeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5070,7 +5459,12 @@ impl SetLoadBasedAutoScalingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5103,7 +5497,12 @@ impl SetPermissionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(SetPermissionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5138,7 +5537,12 @@ impl SetTimeBasedAutoScalingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5171,7 +5575,12 @@ impl StartInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StartInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5204,7 +5613,12 @@ impl StartStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StartStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5237,7 +5651,12 @@ impl StopInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StopInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5270,7 +5689,12 @@ impl StopStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StopStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5303,7 +5727,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5336,7 +5765,12 @@ impl UnassignInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UnassignInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5369,7 +5803,12 @@ impl UnassignVolumeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UnassignVolumeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5402,7 +5841,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5435,7 +5879,12 @@ impl UpdateAppError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateAppError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5468,7 +5917,12 @@ impl UpdateElasticIpError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateElasticIpError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5501,7 +5955,12 @@ impl UpdateInstanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateInstanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5534,7 +5993,12 @@ impl UpdateLayerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateLayerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5561,7 +6025,12 @@ impl UpdateMyUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateMyUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5594,7 +6063,12 @@ impl UpdateRdsDbInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5627,7 +6101,12 @@ impl UpdateStackError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5660,7 +6139,12 @@ impl UpdateUserProfileError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5693,7 +6177,12 @@ impl UpdateVolumeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateVolumeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6108,1041 +6597,213 @@ pub trait OpsWorks {
     /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError>;
 }
-/// A client for the AWS OpsWorks API.
-#[derive(Clone)]
-pub struct OpsWorksClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl OpsWorksClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> OpsWorksClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> OpsWorksClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksClient {
-        OpsWorksClient { client, region }
-    }
-}
-
-impl OpsWorks for OpsWorksClient {
-    /// <p>Assign a registered instance to a layer.</p> <ul> <li> <p>You can assign registered on-premises instances to any layer type.</p> </li> <li> <p>You can assign registered Amazon EC2 instances only to custom layers.</p> </li> <li> <p>You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> </li> </ul> <p> <b>Required Permissions</b>: To use this action, an AWS Identity and Access Management (IAM) user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+const _: Option<&dyn OpsWorks> = None;
+impl<'a, T: OpsWorks + ?Sized> OpsWorks for &'a T {
     fn assign_instance(
         &self,
         input: AssignInstanceRequest,
     ) -> RusotoFuture<(), AssignInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.AssignInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssignInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).assign_instance(input)
     }
-
-    /// <p>Assigns one of the stack's registered Amazon EBS volumes to a specified instance. The volume must first be registered with the stack by calling <a>RegisterVolume</a>. After you register the volume, you must call <a>UpdateVolume</a> to specify a mount point before calling <code>AssignVolume</code>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn assign_volume(&self, input: AssignVolumeRequest) -> RusotoFuture<(), AssignVolumeError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.AssignVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssignVolumeError::from_response(response))),
-                )
-            }
-        })
+        (**self).assign_volume(input)
     }
-
-    /// <p>Associates one of the stack's registered Elastic IP addresses with a specified instance. The address must first be registered with the stack by calling <a>RegisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn associate_elastic_ip(
         &self,
         input: AssociateElasticIpRequest,
     ) -> RusotoFuture<(), AssociateElasticIpError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.AssociateElasticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssociateElasticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).associate_elastic_ip(input)
     }
-
-    /// <p>Attaches an Elastic Load Balancing load balancer to a specified layer. AWS OpsWorks Stacks does not support Application Load Balancer. You can only use Classic Load Balancer with AWS OpsWorks Stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/layers-elb.html">Elastic Load Balancing</a>.</p> <note> <p>You must create the Elastic Load Balancing instance separately, by using the Elastic Load Balancing console, API, or CLI. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/Welcome.html"> Elastic Load Balancing Developer Guide</a>.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn attach_elastic_load_balancer(
         &self,
         input: AttachElasticLoadBalancerRequest,
     ) -> RusotoFuture<(), AttachElasticLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OpsWorks_20130218.AttachElasticLoadBalancer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AttachElasticLoadBalancerError::from_response(response))
-                }))
-            }
-        })
+        (**self).attach_elastic_load_balancer(input)
     }
-
-    /// <p>Creates a clone of a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-cloning.html">Clone a Stack</a>. By default, all parameters are set to the values used by the parent stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn clone_stack(
         &self,
         input: CloneStackRequest,
     ) -> RusotoFuture<CloneStackResult, CloneStackError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CloneStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CloneStackResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CloneStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).clone_stack(input)
     }
-
-    /// <p>Creates an app for a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-creating.html">Creating Apps</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CreateApp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_app(input)
     }
-
-    /// <p>Runs deployment or stack commands. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-deploying.html">Deploying Apps</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-commands.html">Run Stack Commands</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn create_deployment(
         &self,
         input: CreateDeploymentRequest,
     ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CreateDeployment");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDeploymentResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_deployment(input)
     }
-
-    /// <p>Creates an instance in a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html">Adding an Instance to a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn create_instance(
         &self,
         input: CreateInstanceRequest,
     ) -> RusotoFuture<CreateInstanceResult, CreateInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CreateInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_instance(input)
     }
-
-    /// <p>Creates a layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-create.html">How to Create a Layer</a>.</p> <note> <p>You should use <b>CreateLayer</b> for noncustom layer types such as PHP App Server only if the stack does not have an existing layer of that type. A stack can have at most one instance of each noncustom layer; if you attempt to create a second instance, <b>CreateLayer</b> fails. A stack can have an arbitrary number of custom layers, so you can call <b>CreateLayer</b> as many times as you like for that layer type.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn create_layer(
         &self,
         input: CreateLayerRequest,
     ) -> RusotoFuture<CreateLayerResult, CreateLayerError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CreateLayer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLayerResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLayerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_layer(input)
     }
-
-    /// <p>Creates a new stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-edit.html">Create a New Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn create_stack(
         &self,
         input: CreateStackRequest,
     ) -> RusotoFuture<CreateStackResult, CreateStackError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CreateStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStackResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_stack(input)
     }
-
-    /// <p>Creates a new user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn create_user_profile(
         &self,
         input: CreateUserProfileRequest,
     ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.CreateUserProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user_profile(input)
     }
-
-    /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<(), DeleteAppError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteApp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_app(input)
     }
-
-    /// <p>Deletes a specified instance, which terminates the associated Amazon EC2 instance. You must stop an instance before you can delete it.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-delete.html">Deleting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn delete_instance(
         &self,
         input: DeleteInstanceRequest,
     ) -> RusotoFuture<(), DeleteInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_instance(input)
     }
-
-    /// <p>Deletes a specified layer. You must first stop and then delete all associated instances or unassign registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-delete.html">How to Delete a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn delete_layer(&self, input: DeleteLayerRequest) -> RusotoFuture<(), DeleteLayerError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteLayer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLayerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_layer(input)
     }
-
-    /// <p>Deletes a specified stack. You must first delete all instances, layers, and apps or deregister registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-shutting.html">Shut Down a Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn delete_stack(&self, input: DeleteStackRequest) -> RusotoFuture<(), DeleteStackError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_stack(input)
     }
-
-    /// <p>Deletes a user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn delete_user_profile(
         &self,
         input: DeleteUserProfileRequest,
     ) -> RusotoFuture<(), DeleteUserProfileError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteUserProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserProfileError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user_profile(input)
     }
-
-    /// <p>Deregisters a specified Amazon ECS cluster from a stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html#workinglayers-ecscluster-delete"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html</a>.</p>
     fn deregister_ecs_cluster(
         &self,
         input: DeregisterEcsClusterRequest,
     ) -> RusotoFuture<(), DeregisterEcsClusterError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterEcsCluster");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeregisterEcsClusterError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deregister_ecs_cluster(input)
     }
-
-    /// <p>Deregisters a specified Elastic IP address. The address can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn deregister_elastic_ip(
         &self,
         input: DeregisterElasticIpRequest,
     ) -> RusotoFuture<(), DeregisterElasticIpError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterElasticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeregisterElasticIpError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deregister_elastic_ip(input)
     }
-
-    /// <p>Deregister a registered Amazon EC2 or on-premises instance. This action removes the instance from the stack and returns it to your control. This action cannot be used with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn deregister_instance(
         &self,
         input: DeregisterInstanceRequest,
     ) -> RusotoFuture<(), DeregisterInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeregisterInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).deregister_instance(input)
     }
-
-    /// <p>Deregisters an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn deregister_rds_db_instance(
         &self,
         input: DeregisterRdsDbInstanceRequest,
     ) -> RusotoFuture<(), DeregisterRdsDbInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterRdsDbInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterRdsDbInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).deregister_rds_db_instance(input)
     }
-
-    /// <p>Deregisters an Amazon EBS volume. The volume can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn deregister_volume(
         &self,
         input: DeregisterVolumeRequest,
     ) -> RusotoFuture<(), DeregisterVolumeError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeregisterVolumeError::from_response(response))),
-                )
-            }
-        })
+        (**self).deregister_volume(input)
     }
-
-    /// <p>Describes the available AWS OpsWorks Stacks agent versions. You must specify a stack ID or a configuration manager. <code>DescribeAgentVersions</code> returns a list of available agent versions for the specified stack or configuration manager.</p>
     fn describe_agent_versions(
         &self,
         input: DescribeAgentVersionsRequest,
     ) -> RusotoFuture<DescribeAgentVersionsResult, DescribeAgentVersionsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeAgentVersions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAgentVersionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeAgentVersionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_agent_versions(input)
     }
-
-    /// <p>Requests a description of a specified set of apps.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_apps(
         &self,
         input: DescribeAppsRequest,
     ) -> RusotoFuture<DescribeAppsResult, DescribeAppsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeApps");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAppsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeAppsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_apps(input)
     }
-
-    /// <p>Describes the results of specified commands.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_commands(
         &self,
         input: DescribeCommandsRequest,
     ) -> RusotoFuture<DescribeCommandsResult, DescribeCommandsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeCommands");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCommandsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeCommandsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_commands(input)
     }
-
-    /// <p>Requests a description of a specified set of deployments.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_deployments(
         &self,
         input: DescribeDeploymentsRequest,
     ) -> RusotoFuture<DescribeDeploymentsResult, DescribeDeploymentsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeDeployments");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDeploymentsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeDeploymentsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_deployments(input)
     }
-
-    /// <p>Describes Amazon ECS clusters that are registered with a stack. If you specify only a stack ID, you can use the <code>MaxResults</code> and <code>NextToken</code> parameters to paginate the response. However, AWS OpsWorks Stacks currently supports only one cluster per layer, so the result set has a maximum of one element.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permission. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
     fn describe_ecs_clusters(
         &self,
         input: DescribeEcsClustersRequest,
     ) -> RusotoFuture<DescribeEcsClustersResult, DescribeEcsClustersError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeEcsClusters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEcsClustersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEcsClustersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_ecs_clusters(input)
     }
-
-    /// <p>Describes <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP addresses</a>.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_elastic_ips(
         &self,
         input: DescribeElasticIpsRequest,
     ) -> RusotoFuture<DescribeElasticIpsResult, DescribeElasticIpsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeElasticIps");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeElasticIpsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeElasticIpsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_elastic_ips(input)
     }
-
-    /// <p>Describes a stack's Elastic Load Balancing instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_elastic_load_balancers(
         &self,
         input: DescribeElasticLoadBalancersRequest,
     ) -> RusotoFuture<DescribeElasticLoadBalancersResult, DescribeElasticLoadBalancersError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OpsWorks_20130218.DescribeElasticLoadBalancers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeElasticLoadBalancersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeElasticLoadBalancersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_elastic_load_balancers(input)
     }
-
-    /// <p>Requests a description of a set of instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_instances(
         &self,
         input: DescribeInstancesRequest,
     ) -> RusotoFuture<DescribeInstancesResult, DescribeInstancesError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstancesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeInstancesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_instances(input)
     }
-
-    /// <p>Requests a description of one or more layers in a specified stack.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_layers(
         &self,
         input: DescribeLayersRequest,
     ) -> RusotoFuture<DescribeLayersResult, DescribeLayersError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeLayers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLayersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLayersError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_layers(input)
     }
-
-    /// <p>Describes load-based auto scaling configurations for specified layers.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_load_based_auto_scaling(
         &self,
         input: DescribeLoadBasedAutoScalingRequest,
     ) -> RusotoFuture<DescribeLoadBasedAutoScalingResult, DescribeLoadBasedAutoScalingError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OpsWorks_20130218.DescribeLoadBasedAutoScaling",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLoadBasedAutoScalingResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoadBasedAutoScalingError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_load_based_auto_scaling(input)
     }
-
-    /// <p>Describes a user's SSH information.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_my_user_profile(
         &self,
     ) -> RusotoFuture<DescribeMyUserProfileResult, DescribeMyUserProfileError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeMyUserProfile");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMyUserProfileResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeMyUserProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_my_user_profile()
     }
-
-    /// <p>Describes the operating systems that are supported by AWS OpsWorks Stacks.</p>
     fn describe_operating_systems(
         &self,
     ) -> RusotoFuture<DescribeOperatingSystemsResponse, DescribeOperatingSystemsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeOperatingSystems");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOperatingSystemsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOperatingSystemsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_operating_systems()
     }
-
-    /// <p>Describes the permissions for a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_permissions(
         &self,
         input: DescribePermissionsRequest,
     ) -> RusotoFuture<DescribePermissionsResult, DescribePermissionsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribePermissions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePermissionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribePermissionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_permissions(input)
     }
-
-    /// <p>Describe an instance's RAID arrays.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_raid_arrays(
         &self,
         input: DescribeRaidArraysRequest,
     ) -> RusotoFuture<DescribeRaidArraysResult, DescribeRaidArraysError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRaidArrays");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRaidArraysResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeRaidArraysError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_raid_arrays(input)
     }
-
-    /// <p>Describes Amazon RDS instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
     fn describe_rds_db_instances(
         &self,
         input: DescribeRdsDbInstancesRequest,
     ) -> RusotoFuture<DescribeRdsDbInstancesResult, DescribeRdsDbInstancesError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRdsDbInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRdsDbInstancesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeRdsDbInstancesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_rds_db_instances(input)
     }
-
-    /// <p>Describes AWS OpsWorks Stacks service errors.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
     fn describe_service_errors(
         &self,
         input: DescribeServiceErrorsRequest,
     ) -> RusotoFuture<DescribeServiceErrorsResult, DescribeServiceErrorsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeServiceErrors");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServiceErrorsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeServiceErrorsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_service_errors(input)
     }
-
-    /// <p>Requests a description of a stack's provisioning parameters.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_stack_provisioning_parameters(
         &self,
         input: DescribeStackProvisioningParametersRequest,
@@ -7150,951 +6811,5368 @@ impl OpsWorks for OpsWorksClient {
         DescribeStackProvisioningParametersResult,
         DescribeStackProvisioningParametersError,
     > {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OpsWorks_20130218.DescribeStackProvisioningParameters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStackProvisioningParametersResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStackProvisioningParametersError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_stack_provisioning_parameters(input)
     }
-
-    /// <p>Describes the number of layers and apps in a specified stack, and the number of instances in each state, such as <code>running_setup</code> or <code>online</code>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_stack_summary(
         &self,
         input: DescribeStackSummaryRequest,
     ) -> RusotoFuture<DescribeStackSummaryResult, DescribeStackSummaryError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStackSummary");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStackSummaryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeStackSummaryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_stack_summary(input)
     }
-
-    /// <p>Requests a description of one or more stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_stacks(
         &self,
         input: DescribeStacksRequest,
     ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStacks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStacksResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeStacksError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_stacks(input)
     }
-
-    /// <p>Describes time-based auto scaling configurations for specified instances.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_time_based_auto_scaling(
         &self,
         input: DescribeTimeBasedAutoScalingRequest,
     ) -> RusotoFuture<DescribeTimeBasedAutoScalingResult, DescribeTimeBasedAutoScalingError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OpsWorks_20130218.DescribeTimeBasedAutoScaling",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTimeBasedAutoScalingResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTimeBasedAutoScalingError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_time_based_auto_scaling(input)
     }
-
-    /// <p>Describe specified users.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_user_profiles(
         &self,
         input: DescribeUserProfilesRequest,
     ) -> RusotoFuture<DescribeUserProfilesResult, DescribeUserProfilesError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeUserProfiles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserProfilesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeUserProfilesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_user_profiles(input)
     }
-
-    /// <p>Describes an instance's Amazon EBS volumes.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn describe_volumes(
         &self,
         input: DescribeVolumesRequest,
     ) -> RusotoFuture<DescribeVolumesResult, DescribeVolumesError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeVolumes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVolumesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeVolumesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_volumes(input)
     }
-
-    /// <p>Detaches a specified Elastic Load Balancing instance from its layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn detach_elastic_load_balancer(
         &self,
         input: DetachElasticLoadBalancerRequest,
     ) -> RusotoFuture<(), DetachElasticLoadBalancerError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "OpsWorks_20130218.DetachElasticLoadBalancer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DetachElasticLoadBalancerError::from_response(response))
-                }))
-            }
-        })
+        (**self).detach_elastic_load_balancer(input)
     }
-
-    /// <p>Disassociates an Elastic IP address from its instance. The address remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn disassociate_elastic_ip(
         &self,
         input: DisassociateElasticIpRequest,
     ) -> RusotoFuture<(), DisassociateElasticIpError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.DisassociateElasticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisassociateElasticIpError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).disassociate_elastic_ip(input)
     }
-
-    /// <p>Gets a generated host name for the specified layer, based on the current host name theme.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn get_hostname_suggestion(
         &self,
         input: GetHostnameSuggestionRequest,
     ) -> RusotoFuture<GetHostnameSuggestionResult, GetHostnameSuggestionError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.GetHostnameSuggestion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetHostnameSuggestionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetHostnameSuggestionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_hostname_suggestion(input)
     }
-
-    /// <p><note> <p>This action can be used only with Windows stacks.</p> </note> <p>Grants RDP access to a Windows instance for a specified time period.</p></p>
     fn grant_access(
         &self,
         input: GrantAccessRequest,
     ) -> RusotoFuture<GrantAccessResult, GrantAccessError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.GrantAccess");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GrantAccessResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GrantAccessError::from_response(response))),
-                )
-            }
-        })
+        (**self).grant_access(input)
     }
-
-    /// <p>Returns a list of tags that are applied to the specified stack or layer.</p>
     fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResult, ListTagsError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.ListTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags(input)
     }
-
-    /// <p>Reboots a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn reboot_instance(
         &self,
         input: RebootInstanceRequest,
     ) -> RusotoFuture<(), RebootInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.RebootInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebootInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).reboot_instance(input)
     }
-
-    /// <p>Registers a specified Amazon ECS cluster with a stack. You can register only one cluster with a stack. A cluster can be registered with only one stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html"> Managing User Permissions</a>.</p>
     fn register_ecs_cluster(
         &self,
         input: RegisterEcsClusterRequest,
     ) -> RusotoFuture<RegisterEcsClusterResult, RegisterEcsClusterError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterEcsCluster");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterEcsClusterResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterEcsClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_ecs_cluster(input)
     }
-
-    /// <p>Registers an Elastic IP address with a specified stack. An address can be registered with only one stack at a time. If the address is already registered, you must first deregister it by calling <a>DeregisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn register_elastic_ip(
         &self,
         input: RegisterElasticIpRequest,
     ) -> RusotoFuture<RegisterElasticIpResult, RegisterElasticIpError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterElasticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterElasticIpResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterElasticIpError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_elastic_ip(input)
     }
-
-    /// <p>Registers instances that were created outside of AWS OpsWorks Stacks with a specified stack.</p> <note> <p>We do not recommend using this action to register instances. The complete registration operation includes two tasks: installing the AWS OpsWorks Stacks agent on the instance, and registering the instance with the stack. <code>RegisterInstance</code> handles only the second step. You should instead use the AWS CLI <code>register</code> command, which performs the entire registration operation. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register.html"> Registering an Instance with an AWS OpsWorks Stacks Stack</a>.</p> </note> <p>Registered instances have the same requirements as instances that are created by using the <a>CreateInstance</a> API. For example, registered instances must be running a supported Linux-based operating system, and they must have a supported instance type. For more information about requirements for instances that you want to register, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register-registering-preparer.html"> Preparing the Instance</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn register_instance(
         &self,
         input: RegisterInstanceRequest,
     ) -> RusotoFuture<RegisterInstanceResult, RegisterInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterInstanceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_instance(input)
     }
-
-    /// <p>Registers an Amazon RDS instance with a stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn register_rds_db_instance(
         &self,
         input: RegisterRdsDbInstanceRequest,
     ) -> RusotoFuture<(), RegisterRdsDbInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterRdsDbInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RegisterRdsDbInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).register_rds_db_instance(input)
     }
-
-    /// <p>Registers an Amazon EBS volume with a specified stack. A volume can be registered with only one stack at a time. If the volume is already registered, you must first deregister it by calling <a>DeregisterVolume</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn register_volume(
         &self,
         input: RegisterVolumeRequest,
     ) -> RusotoFuture<RegisterVolumeResult, RegisterVolumeError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterVolumeResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterVolumeError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_volume(input)
     }
-
-    /// <p>Specify the load-based auto scaling configuration for a specified layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <note> <p>To use load-based auto scaling, you must create a set of load-based auto scaling instances. Load-based auto scaling operates only on the instances from that set, so you must ensure that you have created enough instances to handle the maximum anticipated load.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn set_load_based_auto_scaling(
         &self,
         input: SetLoadBasedAutoScalingRequest,
     ) -> RusotoFuture<(), SetLoadBasedAutoScalingError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.SetLoadBasedAutoScaling");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetLoadBasedAutoScalingError::from_response(response))
-                }))
-            }
-        })
+        (**self).set_load_based_auto_scaling(input)
     }
-
-    /// <p>Specifies a user's permissions. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingsecurity.html">Security and Permissions</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn set_permission(&self, input: SetPermissionRequest) -> RusotoFuture<(), SetPermissionError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.SetPermission");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetPermissionError::from_response(response))),
-                )
-            }
-        })
+        (**self).set_permission(input)
     }
-
-    /// <p>Specify the time-based auto scaling configuration for a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn set_time_based_auto_scaling(
         &self,
         input: SetTimeBasedAutoScalingRequest,
     ) -> RusotoFuture<(), SetTimeBasedAutoScalingError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.SetTimeBasedAutoScaling");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetTimeBasedAutoScalingError::from_response(response))
-                }))
-            }
-        })
+        (**self).set_time_based_auto_scaling(input)
     }
-
-    /// <p>Starts a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn start_instance(&self, input: StartInstanceRequest) -> RusotoFuture<(), StartInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.StartInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_instance(input)
     }
-
-    /// <p>Starts a stack's instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn start_stack(&self, input: StartStackRequest) -> RusotoFuture<(), StartStackError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.StartStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_stack(input)
     }
-
-    /// <p>Stops a specified instance. When you stop a standard instance, the data disappears and must be reinstalled when you restart the instance. You can stop an Amazon EBS-backed instance without losing data. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn stop_instance(&self, input: StopInstanceRequest) -> RusotoFuture<(), StopInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.StopInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_instance(input)
     }
-
-    /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn stop_stack(&self, input: StopStackRequest) -> RusotoFuture<(), StopStackError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.StopStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopStackError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_stack(input)
     }
-
-    /// <p>Apply cost-allocation tags to a specified stack or layer in AWS OpsWorks Stacks. For more information about how tagging works, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/tagging.html">Tags</a> in the AWS OpsWorks User Guide.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
-    /// <p>Unassigns a registered instance from all layers that are using the instance. The instance remains in the stack as an unassigned instance, and can be assigned to another layer as needed. You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn unassign_instance(
         &self,
         input: UnassignInstanceRequest,
     ) -> RusotoFuture<(), UnassignInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UnassignInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UnassignInstanceError::from_response(response))),
-                )
-            }
-        })
+        (**self).unassign_instance(input)
+    }
+    fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> RusotoFuture<(), UnassignVolumeError> {
+        (**self).unassign_volume(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<(), UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> RusotoFuture<(), UpdateElasticIpError> {
+        (**self).update_elastic_ip(input)
+    }
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<(), UpdateInstanceError> {
+        (**self).update_instance(input)
+    }
+    fn update_layer(&self, input: UpdateLayerRequest) -> RusotoFuture<(), UpdateLayerError> {
+        (**self).update_layer(input)
+    }
+    fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateMyUserProfileError> {
+        (**self).update_my_user_profile(input)
+    }
+    fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), UpdateRdsDbInstanceError> {
+        (**self).update_rds_db_instance(input)
+    }
+    fn update_stack(&self, input: UpdateStackRequest) -> RusotoFuture<(), UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+    fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError> {
+        (**self).update_volume(input)
+    }
+}
+impl<T: OpsWorks + ?Sized> OpsWorks for Box<T> {
+    fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> RusotoFuture<(), AssignInstanceError> {
+        (**self).assign_instance(input)
+    }
+    fn assign_volume(&self, input: AssignVolumeRequest) -> RusotoFuture<(), AssignVolumeError> {
+        (**self).assign_volume(input)
+    }
+    fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> RusotoFuture<(), AssociateElasticIpError> {
+        (**self).associate_elastic_ip(input)
+    }
+    fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), AttachElasticLoadBalancerError> {
+        (**self).attach_elastic_load_balancer(input)
+    }
+    fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> RusotoFuture<CloneStackResult, CloneStackError> {
+        (**self).clone_stack(input)
+    }
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResult, CreateInstanceError> {
+        (**self).create_instance(input)
+    }
+    fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> RusotoFuture<CreateLayerResult, CreateLayerError> {
+        (**self).create_layer(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        (**self).create_user_profile(input)
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<(), DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<(), DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
+    fn delete_layer(&self, input: DeleteLayerRequest) -> RusotoFuture<(), DeleteLayerError> {
+        (**self).delete_layer(input)
+    }
+    fn delete_stack(&self, input: DeleteStackRequest) -> RusotoFuture<(), DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<(), DeleteUserProfileError> {
+        (**self).delete_user_profile(input)
+    }
+    fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> RusotoFuture<(), DeregisterEcsClusterError> {
+        (**self).deregister_ecs_cluster(input)
+    }
+    fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> RusotoFuture<(), DeregisterElasticIpError> {
+        (**self).deregister_elastic_ip(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterRdsDbInstanceError> {
+        (**self).deregister_rds_db_instance(input)
+    }
+    fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> RusotoFuture<(), DeregisterVolumeError> {
+        (**self).deregister_volume(input)
+    }
+    fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> RusotoFuture<DescribeAgentVersionsResult, DescribeAgentVersionsError> {
+        (**self).describe_agent_versions(input)
+    }
+    fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> RusotoFuture<DescribeAppsResult, DescribeAppsError> {
+        (**self).describe_apps(input)
+    }
+    fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> RusotoFuture<DescribeCommandsResult, DescribeCommandsError> {
+        (**self).describe_commands(input)
+    }
+    fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> RusotoFuture<DescribeDeploymentsResult, DescribeDeploymentsError> {
+        (**self).describe_deployments(input)
+    }
+    fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> RusotoFuture<DescribeEcsClustersResult, DescribeEcsClustersError> {
+        (**self).describe_ecs_clusters(input)
+    }
+    fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> RusotoFuture<DescribeElasticIpsResult, DescribeElasticIpsError> {
+        (**self).describe_elastic_ips(input)
+    }
+    fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeElasticLoadBalancersResult, DescribeElasticLoadBalancersError> {
+        (**self).describe_elastic_load_balancers(input)
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> RusotoFuture<DescribeInstancesResult, DescribeInstancesError> {
+        (**self).describe_instances(input)
+    }
+    fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> RusotoFuture<DescribeLayersResult, DescribeLayersError> {
+        (**self).describe_layers(input)
+    }
+    fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeLoadBasedAutoScalingResult, DescribeLoadBasedAutoScalingError> {
+        (**self).describe_load_based_auto_scaling(input)
+    }
+    fn describe_my_user_profile(
+        &self,
+    ) -> RusotoFuture<DescribeMyUserProfileResult, DescribeMyUserProfileError> {
+        (**self).describe_my_user_profile()
+    }
+    fn describe_operating_systems(
+        &self,
+    ) -> RusotoFuture<DescribeOperatingSystemsResponse, DescribeOperatingSystemsError> {
+        (**self).describe_operating_systems()
+    }
+    fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> RusotoFuture<DescribePermissionsResult, DescribePermissionsError> {
+        (**self).describe_permissions(input)
+    }
+    fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> RusotoFuture<DescribeRaidArraysResult, DescribeRaidArraysError> {
+        (**self).describe_raid_arrays(input)
+    }
+    fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> RusotoFuture<DescribeRdsDbInstancesResult, DescribeRdsDbInstancesError> {
+        (**self).describe_rds_db_instances(input)
+    }
+    fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> RusotoFuture<DescribeServiceErrorsResult, DescribeServiceErrorsError> {
+        (**self).describe_service_errors(input)
+    }
+    fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> RusotoFuture<
+        DescribeStackProvisioningParametersResult,
+        DescribeStackProvisioningParametersError,
+    > {
+        (**self).describe_stack_provisioning_parameters(input)
+    }
+    fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> RusotoFuture<DescribeStackSummaryResult, DescribeStackSummaryError> {
+        (**self).describe_stack_summary(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeTimeBasedAutoScalingResult, DescribeTimeBasedAutoScalingError> {
+        (**self).describe_time_based_auto_scaling(input)
+    }
+    fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> RusotoFuture<DescribeUserProfilesResult, DescribeUserProfilesError> {
+        (**self).describe_user_profiles(input)
+    }
+    fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> RusotoFuture<DescribeVolumesResult, DescribeVolumesError> {
+        (**self).describe_volumes(input)
+    }
+    fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), DetachElasticLoadBalancerError> {
+        (**self).detach_elastic_load_balancer(input)
+    }
+    fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> RusotoFuture<(), DisassociateElasticIpError> {
+        (**self).disassociate_elastic_ip(input)
+    }
+    fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> RusotoFuture<GetHostnameSuggestionResult, GetHostnameSuggestionError> {
+        (**self).get_hostname_suggestion(input)
+    }
+    fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> RusotoFuture<GrantAccessResult, GrantAccessError> {
+        (**self).grant_access(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResult, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<(), RebootInstanceError> {
+        (**self).reboot_instance(input)
+    }
+    fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> RusotoFuture<RegisterEcsClusterResult, RegisterEcsClusterError> {
+        (**self).register_ecs_cluster(input)
+    }
+    fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> RusotoFuture<RegisterElasticIpResult, RegisterElasticIpError> {
+        (**self).register_elastic_ip(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResult, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), RegisterRdsDbInstanceError> {
+        (**self).register_rds_db_instance(input)
+    }
+    fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> RusotoFuture<RegisterVolumeResult, RegisterVolumeError> {
+        (**self).register_volume(input)
+    }
+    fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetLoadBasedAutoScalingError> {
+        (**self).set_load_based_auto_scaling(input)
+    }
+    fn set_permission(&self, input: SetPermissionRequest) -> RusotoFuture<(), SetPermissionError> {
+        (**self).set_permission(input)
+    }
+    fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetTimeBasedAutoScalingError> {
+        (**self).set_time_based_auto_scaling(input)
+    }
+    fn start_instance(&self, input: StartInstanceRequest) -> RusotoFuture<(), StartInstanceError> {
+        (**self).start_instance(input)
+    }
+    fn start_stack(&self, input: StartStackRequest) -> RusotoFuture<(), StartStackError> {
+        (**self).start_stack(input)
+    }
+    fn stop_instance(&self, input: StopInstanceRequest) -> RusotoFuture<(), StopInstanceError> {
+        (**self).stop_instance(input)
+    }
+    fn stop_stack(&self, input: StopStackRequest) -> RusotoFuture<(), StopStackError> {
+        (**self).stop_stack(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> RusotoFuture<(), UnassignInstanceError> {
+        (**self).unassign_instance(input)
+    }
+    fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> RusotoFuture<(), UnassignVolumeError> {
+        (**self).unassign_volume(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<(), UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> RusotoFuture<(), UpdateElasticIpError> {
+        (**self).update_elastic_ip(input)
+    }
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<(), UpdateInstanceError> {
+        (**self).update_instance(input)
+    }
+    fn update_layer(&self, input: UpdateLayerRequest) -> RusotoFuture<(), UpdateLayerError> {
+        (**self).update_layer(input)
+    }
+    fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateMyUserProfileError> {
+        (**self).update_my_user_profile(input)
+    }
+    fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), UpdateRdsDbInstanceError> {
+        (**self).update_rds_db_instance(input)
+    }
+    fn update_stack(&self, input: UpdateStackRequest) -> RusotoFuture<(), UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+    fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError> {
+        (**self).update_volume(input)
+    }
+}
+impl<T: OpsWorks + ?Sized> OpsWorks for ::std::sync::Arc<T> {
+    fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> RusotoFuture<(), AssignInstanceError> {
+        (**self).assign_instance(input)
+    }
+    fn assign_volume(&self, input: AssignVolumeRequest) -> RusotoFuture<(), AssignVolumeError> {
+        (**self).assign_volume(input)
+    }
+    fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> RusotoFuture<(), AssociateElasticIpError> {
+        (**self).associate_elastic_ip(input)
+    }
+    fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), AttachElasticLoadBalancerError> {
+        (**self).attach_elastic_load_balancer(input)
+    }
+    fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> RusotoFuture<CloneStackResult, CloneStackError> {
+        (**self).clone_stack(input)
+    }
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResult, CreateInstanceError> {
+        (**self).create_instance(input)
+    }
+    fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> RusotoFuture<CreateLayerResult, CreateLayerError> {
+        (**self).create_layer(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        (**self).create_user_profile(input)
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<(), DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<(), DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
+    fn delete_layer(&self, input: DeleteLayerRequest) -> RusotoFuture<(), DeleteLayerError> {
+        (**self).delete_layer(input)
+    }
+    fn delete_stack(&self, input: DeleteStackRequest) -> RusotoFuture<(), DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<(), DeleteUserProfileError> {
+        (**self).delete_user_profile(input)
+    }
+    fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> RusotoFuture<(), DeregisterEcsClusterError> {
+        (**self).deregister_ecs_cluster(input)
+    }
+    fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> RusotoFuture<(), DeregisterElasticIpError> {
+        (**self).deregister_elastic_ip(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterRdsDbInstanceError> {
+        (**self).deregister_rds_db_instance(input)
+    }
+    fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> RusotoFuture<(), DeregisterVolumeError> {
+        (**self).deregister_volume(input)
+    }
+    fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> RusotoFuture<DescribeAgentVersionsResult, DescribeAgentVersionsError> {
+        (**self).describe_agent_versions(input)
+    }
+    fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> RusotoFuture<DescribeAppsResult, DescribeAppsError> {
+        (**self).describe_apps(input)
+    }
+    fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> RusotoFuture<DescribeCommandsResult, DescribeCommandsError> {
+        (**self).describe_commands(input)
+    }
+    fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> RusotoFuture<DescribeDeploymentsResult, DescribeDeploymentsError> {
+        (**self).describe_deployments(input)
+    }
+    fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> RusotoFuture<DescribeEcsClustersResult, DescribeEcsClustersError> {
+        (**self).describe_ecs_clusters(input)
+    }
+    fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> RusotoFuture<DescribeElasticIpsResult, DescribeElasticIpsError> {
+        (**self).describe_elastic_ips(input)
+    }
+    fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeElasticLoadBalancersResult, DescribeElasticLoadBalancersError> {
+        (**self).describe_elastic_load_balancers(input)
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> RusotoFuture<DescribeInstancesResult, DescribeInstancesError> {
+        (**self).describe_instances(input)
+    }
+    fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> RusotoFuture<DescribeLayersResult, DescribeLayersError> {
+        (**self).describe_layers(input)
+    }
+    fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeLoadBasedAutoScalingResult, DescribeLoadBasedAutoScalingError> {
+        (**self).describe_load_based_auto_scaling(input)
+    }
+    fn describe_my_user_profile(
+        &self,
+    ) -> RusotoFuture<DescribeMyUserProfileResult, DescribeMyUserProfileError> {
+        (**self).describe_my_user_profile()
+    }
+    fn describe_operating_systems(
+        &self,
+    ) -> RusotoFuture<DescribeOperatingSystemsResponse, DescribeOperatingSystemsError> {
+        (**self).describe_operating_systems()
+    }
+    fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> RusotoFuture<DescribePermissionsResult, DescribePermissionsError> {
+        (**self).describe_permissions(input)
+    }
+    fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> RusotoFuture<DescribeRaidArraysResult, DescribeRaidArraysError> {
+        (**self).describe_raid_arrays(input)
+    }
+    fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> RusotoFuture<DescribeRdsDbInstancesResult, DescribeRdsDbInstancesError> {
+        (**self).describe_rds_db_instances(input)
+    }
+    fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> RusotoFuture<DescribeServiceErrorsResult, DescribeServiceErrorsError> {
+        (**self).describe_service_errors(input)
+    }
+    fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> RusotoFuture<
+        DescribeStackProvisioningParametersResult,
+        DescribeStackProvisioningParametersError,
+    > {
+        (**self).describe_stack_provisioning_parameters(input)
+    }
+    fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> RusotoFuture<DescribeStackSummaryResult, DescribeStackSummaryError> {
+        (**self).describe_stack_summary(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeTimeBasedAutoScalingResult, DescribeTimeBasedAutoScalingError> {
+        (**self).describe_time_based_auto_scaling(input)
+    }
+    fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> RusotoFuture<DescribeUserProfilesResult, DescribeUserProfilesError> {
+        (**self).describe_user_profiles(input)
+    }
+    fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> RusotoFuture<DescribeVolumesResult, DescribeVolumesError> {
+        (**self).describe_volumes(input)
+    }
+    fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), DetachElasticLoadBalancerError> {
+        (**self).detach_elastic_load_balancer(input)
+    }
+    fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> RusotoFuture<(), DisassociateElasticIpError> {
+        (**self).disassociate_elastic_ip(input)
+    }
+    fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> RusotoFuture<GetHostnameSuggestionResult, GetHostnameSuggestionError> {
+        (**self).get_hostname_suggestion(input)
+    }
+    fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> RusotoFuture<GrantAccessResult, GrantAccessError> {
+        (**self).grant_access(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResult, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<(), RebootInstanceError> {
+        (**self).reboot_instance(input)
+    }
+    fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> RusotoFuture<RegisterEcsClusterResult, RegisterEcsClusterError> {
+        (**self).register_ecs_cluster(input)
+    }
+    fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> RusotoFuture<RegisterElasticIpResult, RegisterElasticIpError> {
+        (**self).register_elastic_ip(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResult, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), RegisterRdsDbInstanceError> {
+        (**self).register_rds_db_instance(input)
+    }
+    fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> RusotoFuture<RegisterVolumeResult, RegisterVolumeError> {
+        (**self).register_volume(input)
+    }
+    fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetLoadBasedAutoScalingError> {
+        (**self).set_load_based_auto_scaling(input)
+    }
+    fn set_permission(&self, input: SetPermissionRequest) -> RusotoFuture<(), SetPermissionError> {
+        (**self).set_permission(input)
+    }
+    fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetTimeBasedAutoScalingError> {
+        (**self).set_time_based_auto_scaling(input)
+    }
+    fn start_instance(&self, input: StartInstanceRequest) -> RusotoFuture<(), StartInstanceError> {
+        (**self).start_instance(input)
+    }
+    fn start_stack(&self, input: StartStackRequest) -> RusotoFuture<(), StartStackError> {
+        (**self).start_stack(input)
+    }
+    fn stop_instance(&self, input: StopInstanceRequest) -> RusotoFuture<(), StopInstanceError> {
+        (**self).stop_instance(input)
+    }
+    fn stop_stack(&self, input: StopStackRequest) -> RusotoFuture<(), StopStackError> {
+        (**self).stop_stack(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> RusotoFuture<(), UnassignInstanceError> {
+        (**self).unassign_instance(input)
+    }
+    fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> RusotoFuture<(), UnassignVolumeError> {
+        (**self).unassign_volume(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<(), UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> RusotoFuture<(), UpdateElasticIpError> {
+        (**self).update_elastic_ip(input)
+    }
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<(), UpdateInstanceError> {
+        (**self).update_instance(input)
+    }
+    fn update_layer(&self, input: UpdateLayerRequest) -> RusotoFuture<(), UpdateLayerError> {
+        (**self).update_layer(input)
+    }
+    fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateMyUserProfileError> {
+        (**self).update_my_user_profile(input)
+    }
+    fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), UpdateRdsDbInstanceError> {
+        (**self).update_rds_db_instance(input)
+    }
+    fn update_stack(&self, input: UpdateStackRequest) -> RusotoFuture<(), UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+    fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError> {
+        (**self).update_volume(input)
+    }
+}
+/// A client for the AWS OpsWorks API.
+#[derive(Clone)]
+pub struct OpsWorksClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl OpsWorksClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OpsWorksClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OpsWorksClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksClient {
+        OpsWorksClient { client, region }
+    }
+}
+
+impl OpsWorks for OpsWorksClient {
+    /// <p>Assign a registered instance to a layer.</p> <ul> <li> <p>You can assign registered on-premises instances to any layer type.</p> </li> <li> <p>You can assign registered Amazon EC2 instances only to custom layers.</p> </li> <li> <p>You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> </li> </ul> <p> <b>Required Permissions</b>: To use this action, an AWS Identity and Access Management (IAM) user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> RusotoFuture<(), AssignInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssignInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.AssignInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssignInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns one of the stack's registered Amazon EBS volumes to a specified instance. The volume must first be registered with the stack by calling <a>RegisterVolume</a>. After you register the volume, you must call <a>UpdateVolume</a> to specify a mount point before calling <code>AssignVolume</code>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn assign_volume(&self, input: AssignVolumeRequest) -> RusotoFuture<(), AssignVolumeError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssignVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.AssignVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssignVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates one of the stack's registered Elastic IP addresses with a specified instance. The address must first be registered with the stack by calling <a>RegisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> RusotoFuture<(), AssociateElasticIpError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AssociateElasticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.AssociateElasticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateElasticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches an Elastic Load Balancing load balancer to a specified layer. AWS OpsWorks Stacks does not support Application Load Balancer. You can only use Classic Load Balancer with AWS OpsWorks Stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/layers-elb.html">Elastic Load Balancing</a>.</p> <note> <p>You must create the Elastic Load Balancing instance separately, by using the Elastic Load Balancing console, API, or CLI. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/Welcome.html"> Elastic Load Balancing Developer Guide</a>.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), AttachElasticLoadBalancerError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("AttachElasticLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OpsWorks_20130218.AttachElasticLoadBalancer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AttachElasticLoadBalancerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a clone of a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-cloning.html">Clone a Stack</a>. By default, all parameters are set to the values used by the parent stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> RusotoFuture<CloneStackResult, CloneStackError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CloneStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CloneStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CloneStackResult, _>(response, "CloneStack")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CloneStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an app for a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-creating.html">Creating Apps</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CreateApp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAppResult, _>(response, "CreateApp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Runs deployment or stack commands. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-deploying.html">Deploying Apps</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-commands.html">Run Stack Commands</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateDeployment");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CreateDeployment");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDeploymentResult, _>(
+                    response,
+                    "CreateDeployment",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDeploymentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an instance in a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html">Adding an Instance to a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResult, CreateInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CreateInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateInstanceResult, _>(
+                    response,
+                    "CreateInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-create.html">How to Create a Layer</a>.</p> <note> <p>You should use <b>CreateLayer</b> for noncustom layer types such as PHP App Server only if the stack does not have an existing layer of that type. A stack can have at most one instance of each noncustom layer; if you attempt to create a second instance, <b>CreateLayer</b> fails. A stack can have an arbitrary number of custom layers, so you can call <b>CreateLayer</b> as many times as you like for that layer type.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> RusotoFuture<CreateLayerResult, CreateLayerError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateLayer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CreateLayer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateLayerResult, _>(response, "CreateLayer")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLayerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-edit.html">Create a New Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CreateStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateStackResult, _>(response, "CreateStack")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("CreateUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.CreateUserProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserProfileResult, _>(
+                    response,
+                    "CreateUserProfile",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<(), DeleteAppError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteApp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified instance, which terminates the associated Amazon EC2 instance. You must stop an instance before you can delete it.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-delete.html">Deleting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<(), DeleteInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified layer. You must first stop and then delete all associated instances or unassign registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-delete.html">How to Delete a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn delete_layer(&self, input: DeleteLayerRequest) -> RusotoFuture<(), DeleteLayerError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteLayer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteLayer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLayerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a specified stack. You must first delete all instances, layers, and apps or deregister registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-shutting.html">Shut Down a Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn delete_stack(&self, input: DeleteStackRequest) -> RusotoFuture<(), DeleteStackError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<(), DeleteUserProfileError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeleteUserProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deregisters a specified Amazon ECS cluster from a stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html#workinglayers-ecscluster-delete"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html</a>.</p>
+    fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> RusotoFuture<(), DeregisterEcsClusterError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterEcsCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterEcsCluster");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeregisterEcsClusterError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deregisters a specified Elastic IP address. The address can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> RusotoFuture<(), DeregisterElasticIpError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterElasticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterElasticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeregisterElasticIpError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deregister a registered Amazon EC2 or on-premises instance. This action removes the instance from the stack and returns it to your control. This action cannot be used with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeregisterInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deregisters an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterRdsDbInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterRdsDbInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterRdsDbInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterRdsDbInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deregisters an Amazon EBS volume. The volume can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> RusotoFuture<(), DeregisterVolumeError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DeregisterVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeregisterVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the available AWS OpsWorks Stacks agent versions. You must specify a stack ID or a configuration manager. <code>DescribeAgentVersions</code> returns a list of available agent versions for the specified stack or configuration manager.</p>
+    fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> RusotoFuture<DescribeAgentVersionsResult, DescribeAgentVersionsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeAgentVersions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeAgentVersions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAgentVersionsResult, _>(
+                    response,
+                    "DescribeAgentVersions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeAgentVersionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests a description of a specified set of apps.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> RusotoFuture<DescribeAppsResult, DescribeAppsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeApps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeApps");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAppsResult, _>(
+                    response,
+                    "DescribeApps",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeAppsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the results of specified commands.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> RusotoFuture<DescribeCommandsResult, DescribeCommandsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeCommands");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeCommands");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCommandsResult, _>(
+                    response,
+                    "DescribeCommands",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeCommandsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests a description of a specified set of deployments.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> RusotoFuture<DescribeDeploymentsResult, DescribeDeploymentsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeDeployments");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeDeployments");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDeploymentsResult, _>(
+                    response,
+                    "DescribeDeployments",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeDeploymentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes Amazon ECS clusters that are registered with a stack. If you specify only a stack ID, you can use the <code>MaxResults</code> and <code>NextToken</code> parameters to paginate the response. However, AWS OpsWorks Stacks currently supports only one cluster per layer, so the result set has a maximum of one element.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permission. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> RusotoFuture<DescribeEcsClustersResult, DescribeEcsClustersError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeEcsClusters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeEcsClusters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEcsClustersResult, _>(
+                    response,
+                    "DescribeEcsClusters",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEcsClustersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP addresses</a>.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> RusotoFuture<DescribeElasticIpsResult, DescribeElasticIpsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeElasticIps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeElasticIps");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeElasticIpsResult, _>(
+                    response,
+                    "DescribeElasticIps",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeElasticIpsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes a stack's Elastic Load Balancing instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeElasticLoadBalancersResult, DescribeElasticLoadBalancersError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeElasticLoadBalancers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OpsWorks_20130218.DescribeElasticLoadBalancers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeElasticLoadBalancersResult, _>(
+                    response,
+                    "DescribeElasticLoadBalancers",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeElasticLoadBalancersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Requests a description of a set of instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> RusotoFuture<DescribeInstancesResult, DescribeInstancesError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInstancesResult, _>(
+                    response,
+                    "DescribeInstances",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeInstancesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests a description of one or more layers in a specified stack.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> RusotoFuture<DescribeLayersResult, DescribeLayersError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeLayers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeLayers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLayersResult, _>(
+                    response,
+                    "DescribeLayers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeLayersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes load-based auto scaling configurations for specified layers.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeLoadBasedAutoScalingResult, DescribeLoadBasedAutoScalingError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeLoadBasedAutoScaling");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OpsWorks_20130218.DescribeLoadBasedAutoScaling",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLoadBasedAutoScalingResult, _>(
+                    response,
+                    "DescribeLoadBasedAutoScaling",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoadBasedAutoScalingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes a user's SSH information.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_my_user_profile(
+        &self,
+    ) -> RusotoFuture<DescribeMyUserProfileResult, DescribeMyUserProfileError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeMyUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeMyUserProfile");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMyUserProfileResult, _>(
+                    response,
+                    "DescribeMyUserProfile",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeMyUserProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the operating systems that are supported by AWS OpsWorks Stacks.</p>
+    fn describe_operating_systems(
+        &self,
+    ) -> RusotoFuture<DescribeOperatingSystemsResponse, DescribeOperatingSystemsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeOperatingSystems");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeOperatingSystems");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOperatingSystemsResponse, _>(
+                    response,
+                    "DescribeOperatingSystems",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOperatingSystemsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the permissions for a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> RusotoFuture<DescribePermissionsResult, DescribePermissionsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribePermissions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribePermissions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePermissionsResult, _>(
+                    response,
+                    "DescribePermissions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribePermissionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describe an instance's RAID arrays.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> RusotoFuture<DescribeRaidArraysResult, DescribeRaidArraysError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeRaidArrays");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRaidArrays");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRaidArraysResult, _>(
+                    response,
+                    "DescribeRaidArrays",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeRaidArraysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes Amazon RDS instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> RusotoFuture<DescribeRdsDbInstancesResult, DescribeRdsDbInstancesError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeRdsDbInstances");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRdsDbInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRdsDbInstancesResult, _>(
+                    response,
+                    "DescribeRdsDbInstances",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeRdsDbInstancesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes AWS OpsWorks Stacks service errors.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> RusotoFuture<DescribeServiceErrorsResult, DescribeServiceErrorsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeServiceErrors");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeServiceErrors");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeServiceErrorsResult, _>(
+                    response,
+                    "DescribeServiceErrors",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeServiceErrorsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests a description of a stack's provisioning parameters.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> RusotoFuture<
+        DescribeStackProvisioningParametersResult,
+        DescribeStackProvisioningParametersError,
+    > {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStackProvisioningParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OpsWorks_20130218.DescribeStackProvisioningParameters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStackProvisioningParametersResult, _>(
+                    response,
+                    "DescribeStackProvisioningParameters",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStackProvisioningParametersError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the number of layers and apps in a specified stack, and the number of instances in each state, such as <code>running_setup</code> or <code>online</code>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> RusotoFuture<DescribeStackSummaryResult, DescribeStackSummaryError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStackSummary");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStackSummary");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStackSummaryResult, _>(
+                    response,
+                    "DescribeStackSummary",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeStackSummaryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests a description of one or more stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeStacks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStacks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStacksResult, _>(
+                    response,
+                    "DescribeStacks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStacksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes time-based auto scaling configurations for specified instances.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeTimeBasedAutoScalingResult, DescribeTimeBasedAutoScalingError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeTimeBasedAutoScaling");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OpsWorks_20130218.DescribeTimeBasedAutoScaling",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTimeBasedAutoScalingResult, _>(
+                    response,
+                    "DescribeTimeBasedAutoScaling",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTimeBasedAutoScalingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describe specified users.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> RusotoFuture<DescribeUserProfilesResult, DescribeUserProfilesError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeUserProfiles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeUserProfiles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserProfilesResult, _>(
+                    response,
+                    "DescribeUserProfiles",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeUserProfilesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes an instance's Amazon EBS volumes.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> RusotoFuture<DescribeVolumesResult, DescribeVolumesError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DescribeVolumes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DescribeVolumes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeVolumesResult, _>(
+                    response,
+                    "DescribeVolumes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeVolumesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches a specified Elastic Load Balancing instance from its layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), DetachElasticLoadBalancerError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DetachElasticLoadBalancer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "OpsWorks_20130218.DetachElasticLoadBalancer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DetachElasticLoadBalancerError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates an Elastic IP address from its instance. The address remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> RusotoFuture<(), DisassociateElasticIpError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("DisassociateElasticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.DisassociateElasticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisassociateElasticIpError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a generated host name for the specified layer, based on the current host name theme.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> RusotoFuture<GetHostnameSuggestionResult, GetHostnameSuggestionError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("GetHostnameSuggestion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.GetHostnameSuggestion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetHostnameSuggestionResult, _>(
+                    response,
+                    "GetHostnameSuggestion",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetHostnameSuggestionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><note> <p>This action can be used only with Windows stacks.</p> </note> <p>Grants RDP access to a Windows instance for a specified time period.</p></p>
+    fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> RusotoFuture<GrantAccessResult, GrantAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("GrantAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.GrantAccess");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GrantAccessResult, _>(response, "GrantAccess")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GrantAccessError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of tags that are applied to the specified stack or layer.</p>
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResult, ListTagsError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("ListTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.ListTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsResult, _>(response, "ListTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Reboots a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<(), RebootInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RebootInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.RebootInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RebootInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers a specified Amazon ECS cluster with a stack. You can register only one cluster with a stack. A cluster can be registered with only one stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html"> Managing User Permissions</a>.</p>
+    fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> RusotoFuture<RegisterEcsClusterResult, RegisterEcsClusterError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterEcsCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterEcsCluster");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterEcsClusterResult, _>(
+                    response,
+                    "RegisterEcsCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterEcsClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers an Elastic IP address with a specified stack. An address can be registered with only one stack at a time. If the address is already registered, you must first deregister it by calling <a>DeregisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> RusotoFuture<RegisterElasticIpResult, RegisterElasticIpError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterElasticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterElasticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterElasticIpResult, _>(
+                    response,
+                    "RegisterElasticIp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterElasticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers instances that were created outside of AWS OpsWorks Stacks with a specified stack.</p> <note> <p>We do not recommend using this action to register instances. The complete registration operation includes two tasks: installing the AWS OpsWorks Stacks agent on the instance, and registering the instance with the stack. <code>RegisterInstance</code> handles only the second step. You should instead use the AWS CLI <code>register</code> command, which performs the entire registration operation. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register.html"> Registering an Instance with an AWS OpsWorks Stacks Stack</a>.</p> </note> <p>Registered instances have the same requirements as instances that are created by using the <a>CreateInstance</a> API. For example, registered instances must be running a supported Linux-based operating system, and they must have a supported instance type. For more information about requirements for instances that you want to register, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register-registering-preparer.html"> Preparing the Instance</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResult, RegisterInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterInstanceResult, _>(
+                    response,
+                    "RegisterInstance",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers an Amazon RDS instance with a stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), RegisterRdsDbInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterRdsDbInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterRdsDbInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RegisterRdsDbInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers an Amazon EBS volume with a specified stack. A volume can be registered with only one stack at a time. If the volume is already registered, you must first deregister it by calling <a>DeregisterVolume</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> RusotoFuture<RegisterVolumeResult, RegisterVolumeError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("RegisterVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.RegisterVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterVolumeResult, _>(
+                    response,
+                    "RegisterVolume",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Specify the load-based auto scaling configuration for a specified layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <note> <p>To use load-based auto scaling, you must create a set of load-based auto scaling instances. Load-based auto scaling operates only on the instances from that set, so you must ensure that you have created enough instances to handle the maximum anticipated load.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetLoadBasedAutoScalingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetLoadBasedAutoScaling");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.SetLoadBasedAutoScaling");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetLoadBasedAutoScalingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Specifies a user's permissions. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingsecurity.html">Security and Permissions</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn set_permission(&self, input: SetPermissionRequest) -> RusotoFuture<(), SetPermissionError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetPermission");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.SetPermission");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetPermissionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Specify the time-based auto scaling configuration for a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetTimeBasedAutoScalingError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("SetTimeBasedAutoScaling");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.SetTimeBasedAutoScaling");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetTimeBasedAutoScalingError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn start_instance(&self, input: StartInstanceRequest) -> RusotoFuture<(), StartInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StartInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.StartInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a stack's instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn start_stack(&self, input: StartStackRequest) -> RusotoFuture<(), StartStackError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StartStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.StartStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a specified instance. When you stop a standard instance, the data disappears and must be reinstalled when you restart the instance. You can stop an Amazon EBS-backed instance without losing data. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn stop_instance(&self, input: StopInstanceRequest) -> RusotoFuture<(), StopInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StopInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.StopInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn stop_stack(&self, input: StopStackRequest) -> RusotoFuture<(), StopStackError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("StopStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.StopStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Apply cost-allocation tags to a specified stack or layer in AWS OpsWorks Stacks. For more information about how tagging works, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/tagging.html">Tags</a> in the AWS OpsWorks User Guide.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Unassigns a registered instance from all layers that are using the instance. The instance remains in the stack as an unassigned instance, and can be assigned to another layer as needed. You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> RusotoFuture<(), UnassignInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UnassignInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UnassignInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UnassignInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Unassigns an assigned Amazon EBS volume. The volume remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> RusotoFuture<(), UnassignVolumeError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UnassignVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UnassignVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UnassignVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes tags from a specified stack or layer.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<(), UpdateAppError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateApp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a registered Elastic IP address's name. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> RusotoFuture<(), UpdateElasticIpError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateElasticIp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateElasticIp");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateElasticIpError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<(), UpdateInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateInstanceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_layer(&self, input: UpdateLayerRequest) -> RusotoFuture<(), UpdateLayerError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateLayer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateLayer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateLayerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a user's SSH public key.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateMyUserProfileError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateMyUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateMyUserProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateMyUserProfileError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), UpdateRdsDbInstanceError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateRdsDbInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateRdsDbInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRdsDbInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_stack(&self, input: UpdateStackRequest) -> RusotoFuture<(), UpdateStackError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateStack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateStack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateStackError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a specified user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserProfileError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateUserProfile");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUserProfileError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError> {
+        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
+        request.set_operation("UpdateVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS OpsWorks API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockOpsWorks {
+    assign_instance: Option<
+        Box<
+            dyn Fn(
+                    AssignInstanceRequest,
+                ) -> ::std::result::Result<(), RusotoError<AssignInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    assign_volume: Option<
+        Box<
+            dyn Fn(AssignVolumeRequest) -> ::std::result::Result<(), RusotoError<AssignVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    associate_elastic_ip: Option<
+        Box<
+            dyn Fn(
+                    AssociateElasticIpRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AssociateElasticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_elastic_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    AttachElasticLoadBalancerRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AttachElasticLoadBalancerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    clone_stack: Option<
+        Box<
+            dyn Fn(
+                    CloneStackRequest,
+                )
+                    -> ::std::result::Result<CloneStackResult, RusotoError<CloneStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_app: Option<
+        Box<
+            dyn Fn(
+                    CreateAppRequest,
+                )
+                    -> ::std::result::Result<CreateAppResult, RusotoError<CreateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_deployment: Option<
+        Box<
+            dyn Fn(
+                    CreateDeploymentRequest,
+                ) -> ::std::result::Result<
+                    CreateDeploymentResult,
+                    RusotoError<CreateDeploymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_instance: Option<
+        Box<
+            dyn Fn(
+                    CreateInstanceRequest,
+                )
+                    -> ::std::result::Result<CreateInstanceResult, RusotoError<CreateInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_layer: Option<
+        Box<
+            dyn Fn(
+                    CreateLayerRequest,
+                )
+                    -> ::std::result::Result<CreateLayerResult, RusotoError<CreateLayerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_stack: Option<
+        Box<
+            dyn Fn(
+                    CreateStackRequest,
+                )
+                    -> ::std::result::Result<CreateStackResult, RusotoError<CreateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_user_profile: Option<
+        Box<
+            dyn Fn(
+                    CreateUserProfileRequest,
+                ) -> ::std::result::Result<
+                    CreateUserProfileResult,
+                    RusotoError<CreateUserProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_app: Option<
+        Box<
+            dyn Fn(DeleteAppRequest) -> ::std::result::Result<(), RusotoError<DeleteAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_instance: Option<
+        Box<
+            dyn Fn(
+                    DeleteInstanceRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_layer: Option<
+        Box<
+            dyn Fn(DeleteLayerRequest) -> ::std::result::Result<(), RusotoError<DeleteLayerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stack: Option<
+        Box<
+            dyn Fn(DeleteStackRequest) -> ::std::result::Result<(), RusotoError<DeleteStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user_profile: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserProfileRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteUserProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_ecs_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeregisterEcsClusterRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeregisterEcsClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_elastic_ip: Option<
+        Box<
+            dyn Fn(
+                    DeregisterElasticIpRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeregisterElasticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_instance: Option<
+        Box<
+            dyn Fn(
+                    DeregisterInstanceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeregisterInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_rds_db_instance: Option<
+        Box<
+            dyn Fn(
+                    DeregisterRdsDbInstanceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeregisterRdsDbInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_volume: Option<
+        Box<
+            dyn Fn(
+                    DeregisterVolumeRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeregisterVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_agent_versions: Option<
+        Box<
+            dyn Fn(
+                    DescribeAgentVersionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAgentVersionsResult,
+                    RusotoError<DescribeAgentVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_apps: Option<
+        Box<
+            dyn Fn(
+                    DescribeAppsRequest,
+                )
+                    -> ::std::result::Result<DescribeAppsResult, RusotoError<DescribeAppsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_commands: Option<
+        Box<
+            dyn Fn(
+                    DescribeCommandsRequest,
+                ) -> ::std::result::Result<
+                    DescribeCommandsResult,
+                    RusotoError<DescribeCommandsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_deployments: Option<
+        Box<
+            dyn Fn(
+                    DescribeDeploymentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDeploymentsResult,
+                    RusotoError<DescribeDeploymentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_ecs_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeEcsClustersRequest,
+                ) -> ::std::result::Result<
+                    DescribeEcsClustersResult,
+                    RusotoError<DescribeEcsClustersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_elastic_ips: Option<
+        Box<
+            dyn Fn(
+                    DescribeElasticIpsRequest,
+                ) -> ::std::result::Result<
+                    DescribeElasticIpsResult,
+                    RusotoError<DescribeElasticIpsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_elastic_load_balancers: Option<
+        Box<
+            dyn Fn(
+                    DescribeElasticLoadBalancersRequest,
+                ) -> ::std::result::Result<
+                    DescribeElasticLoadBalancersResult,
+                    RusotoError<DescribeElasticLoadBalancersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstancesRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstancesResult,
+                    RusotoError<DescribeInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_layers: Option<
+        Box<
+            dyn Fn(
+                    DescribeLayersRequest,
+                )
+                    -> ::std::result::Result<DescribeLayersResult, RusotoError<DescribeLayersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_load_based_auto_scaling: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoadBasedAutoScalingRequest,
+                ) -> ::std::result::Result<
+                    DescribeLoadBasedAutoScalingResult,
+                    RusotoError<DescribeLoadBasedAutoScalingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_my_user_profile: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeMyUserProfileResult,
+                    RusotoError<DescribeMyUserProfileError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_operating_systems: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeOperatingSystemsResponse,
+                    RusotoError<DescribeOperatingSystemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_permissions: Option<
+        Box<
+            dyn Fn(
+                    DescribePermissionsRequest,
+                ) -> ::std::result::Result<
+                    DescribePermissionsResult,
+                    RusotoError<DescribePermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_raid_arrays: Option<
+        Box<
+            dyn Fn(
+                    DescribeRaidArraysRequest,
+                ) -> ::std::result::Result<
+                    DescribeRaidArraysResult,
+                    RusotoError<DescribeRaidArraysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_rds_db_instances: Option<
+        Box<
+            dyn Fn(
+                    DescribeRdsDbInstancesRequest,
+                ) -> ::std::result::Result<
+                    DescribeRdsDbInstancesResult,
+                    RusotoError<DescribeRdsDbInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_service_errors: Option<
+        Box<
+            dyn Fn(
+                    DescribeServiceErrorsRequest,
+                ) -> ::std::result::Result<
+                    DescribeServiceErrorsResult,
+                    RusotoError<DescribeServiceErrorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_provisioning_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackProvisioningParametersRequest,
+                ) -> ::std::result::Result<
+                    DescribeStackProvisioningParametersResult,
+                    RusotoError<DescribeStackProvisioningParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stack_summary: Option<
+        Box<
+            dyn Fn(
+                    DescribeStackSummaryRequest,
+                ) -> ::std::result::Result<
+                    DescribeStackSummaryResult,
+                    RusotoError<DescribeStackSummaryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stacks: Option<
+        Box<
+            dyn Fn(
+                    DescribeStacksRequest,
+                )
+                    -> ::std::result::Result<DescribeStacksResult, RusotoError<DescribeStacksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_time_based_auto_scaling: Option<
+        Box<
+            dyn Fn(
+                    DescribeTimeBasedAutoScalingRequest,
+                ) -> ::std::result::Result<
+                    DescribeTimeBasedAutoScalingResult,
+                    RusotoError<DescribeTimeBasedAutoScalingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user_profiles: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserProfilesRequest,
+                ) -> ::std::result::Result<
+                    DescribeUserProfilesResult,
+                    RusotoError<DescribeUserProfilesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_volumes: Option<
+        Box<
+            dyn Fn(
+                    DescribeVolumesRequest,
+                ) -> ::std::result::Result<
+                    DescribeVolumesResult,
+                    RusotoError<DescribeVolumesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_elastic_load_balancer: Option<
+        Box<
+            dyn Fn(
+                    DetachElasticLoadBalancerRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DetachElasticLoadBalancerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_elastic_ip: Option<
+        Box<
+            dyn Fn(
+                    DisassociateElasticIpRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisassociateElasticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_hostname_suggestion: Option<
+        Box<
+            dyn Fn(
+                    GetHostnameSuggestionRequest,
+                ) -> ::std::result::Result<
+                    GetHostnameSuggestionResult,
+                    RusotoError<GetHostnameSuggestionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    grant_access: Option<
+        Box<
+            dyn Fn(
+                    GrantAccessRequest,
+                )
+                    -> ::std::result::Result<GrantAccessResult, RusotoError<GrantAccessError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsRequest,
+                )
+                    -> ::std::result::Result<ListTagsResult, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reboot_instance: Option<
+        Box<
+            dyn Fn(
+                    RebootInstanceRequest,
+                ) -> ::std::result::Result<(), RusotoError<RebootInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_ecs_cluster: Option<
+        Box<
+            dyn Fn(
+                    RegisterEcsClusterRequest,
+                ) -> ::std::result::Result<
+                    RegisterEcsClusterResult,
+                    RusotoError<RegisterEcsClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_elastic_ip: Option<
+        Box<
+            dyn Fn(
+                    RegisterElasticIpRequest,
+                ) -> ::std::result::Result<
+                    RegisterElasticIpResult,
+                    RusotoError<RegisterElasticIpError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_instance: Option<
+        Box<
+            dyn Fn(
+                    RegisterInstanceRequest,
+                ) -> ::std::result::Result<
+                    RegisterInstanceResult,
+                    RusotoError<RegisterInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_rds_db_instance: Option<
+        Box<
+            dyn Fn(
+                    RegisterRdsDbInstanceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RegisterRdsDbInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_volume: Option<
+        Box<
+            dyn Fn(
+                    RegisterVolumeRequest,
+                )
+                    -> ::std::result::Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_load_based_auto_scaling: Option<
+        Box<
+            dyn Fn(
+                    SetLoadBasedAutoScalingRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetLoadBasedAutoScalingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_permission: Option<
+        Box<
+            dyn Fn(
+                    SetPermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<SetPermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_time_based_auto_scaling: Option<
+        Box<
+            dyn Fn(
+                    SetTimeBasedAutoScalingRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetTimeBasedAutoScalingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_instance: Option<
+        Box<
+            dyn Fn(
+                    StartInstanceRequest,
+                ) -> ::std::result::Result<(), RusotoError<StartInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_stack: Option<
+        Box<
+            dyn Fn(StartStackRequest) -> ::std::result::Result<(), RusotoError<StartStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_instance: Option<
+        Box<
+            dyn Fn(StopInstanceRequest) -> ::std::result::Result<(), RusotoError<StopInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_stack: Option<
+        Box<
+            dyn Fn(StopStackRequest) -> ::std::result::Result<(), RusotoError<StopStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unassign_instance: Option<
+        Box<
+            dyn Fn(
+                    UnassignInstanceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UnassignInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unassign_volume: Option<
+        Box<
+            dyn Fn(
+                    UnassignVolumeRequest,
+                ) -> ::std::result::Result<(), RusotoError<UnassignVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_app: Option<
+        Box<
+            dyn Fn(UpdateAppRequest) -> ::std::result::Result<(), RusotoError<UpdateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_elastic_ip: Option<
+        Box<
+            dyn Fn(
+                    UpdateElasticIpRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdateElasticIpError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_instance: Option<
+        Box<
+            dyn Fn(
+                    UpdateInstanceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdateInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_layer: Option<
+        Box<
+            dyn Fn(UpdateLayerRequest) -> ::std::result::Result<(), RusotoError<UpdateLayerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_my_user_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateMyUserProfileRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateMyUserProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_rds_db_instance: Option<
+        Box<
+            dyn Fn(
+                    UpdateRdsDbInstanceRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateRdsDbInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_stack: Option<
+        Box<
+            dyn Fn(UpdateStackRequest) -> ::std::result::Result<(), RusotoError<UpdateStackError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user_profile: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserProfileRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateUserProfileError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_volume: Option<
+        Box<
+            dyn Fn(UpdateVolumeRequest) -> ::std::result::Result<(), RusotoError<UpdateVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockOpsWorks {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockOpsWorks {
+        Default::default()
+    }
+
+    /// Answers `assign_instance` with what `handler` returns for its input.
+    pub fn with_assign_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(AssignInstanceRequest) -> ::std::result::Result<(), RusotoError<AssignInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.assign_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `assign_volume` with what `handler` returns for its input.
+    pub fn with_assign_volume<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(AssignVolumeRequest) -> ::std::result::Result<(), RusotoError<AssignVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.assign_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_elastic_ip` with what `handler` returns for its input.
+    pub fn with_associate_elastic_ip<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                AssociateElasticIpRequest,
+            ) -> ::std::result::Result<(), RusotoError<AssociateElasticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_elastic_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_elastic_load_balancer` with what `handler` returns for its input.
+    pub fn with_attach_elastic_load_balancer<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                AttachElasticLoadBalancerRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<AttachElasticLoadBalancerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_elastic_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `clone_stack` with what `handler` returns for its input.
+    pub fn with_clone_stack<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CloneStackRequest,
+            ) -> ::std::result::Result<CloneStackResult, RusotoError<CloneStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.clone_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_app` with what `handler` returns for its input.
+    pub fn with_create_app<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CreateAppRequest,
+            ) -> ::std::result::Result<CreateAppResult, RusotoError<CreateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_deployment` with what `handler` returns for its input.
+    pub fn with_create_deployment<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CreateDeploymentRequest,
+            )
+                -> ::std::result::Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_deployment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_instance` with what `handler` returns for its input.
+    pub fn with_create_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CreateInstanceRequest,
+            )
+                -> ::std::result::Result<CreateInstanceResult, RusotoError<CreateInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_layer` with what `handler` returns for its input.
+    pub fn with_create_layer<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CreateLayerRequest,
+            )
+                -> ::std::result::Result<CreateLayerResult, RusotoError<CreateLayerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_layer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stack` with what `handler` returns for its input.
+    pub fn with_create_stack<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CreateStackRequest,
+            )
+                -> ::std::result::Result<CreateStackResult, RusotoError<CreateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user_profile` with what `handler` returns for its input.
+    pub fn with_create_user_profile<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                CreateUserProfileRequest,
+            ) -> ::std::result::Result<
+                CreateUserProfileResult,
+                RusotoError<CreateUserProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_app` with what `handler` returns for its input.
+    pub fn with_delete_app<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(DeleteAppRequest) -> ::std::result::Result<(), RusotoError<DeleteAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_instance` with what `handler` returns for its input.
+    pub fn with_delete_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(DeleteInstanceRequest) -> ::std::result::Result<(), RusotoError<DeleteInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_layer` with what `handler` returns for its input.
+    pub fn with_delete_layer<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(DeleteLayerRequest) -> ::std::result::Result<(), RusotoError<DeleteLayerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_layer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stack` with what `handler` returns for its input.
+    pub fn with_delete_stack<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(DeleteStackRequest) -> ::std::result::Result<(), RusotoError<DeleteStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user_profile` with what `handler` returns for its input.
+    pub fn with_delete_user_profile<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DeleteUserProfileRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteUserProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_ecs_cluster` with what `handler` returns for its input.
+    pub fn with_deregister_ecs_cluster<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DeregisterEcsClusterRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeregisterEcsClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_ecs_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_elastic_ip` with what `handler` returns for its input.
+    pub fn with_deregister_elastic_ip<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DeregisterElasticIpRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeregisterElasticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_elastic_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_instance` with what `handler` returns for its input.
+    pub fn with_deregister_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DeregisterInstanceRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeregisterInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_rds_db_instance` with what `handler` returns for its input.
+    pub fn with_deregister_rds_db_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DeregisterRdsDbInstanceRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeregisterRdsDbInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_rds_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_volume` with what `handler` returns for its input.
+    pub fn with_deregister_volume<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DeregisterVolumeRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeregisterVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_agent_versions` with what `handler` returns for its input.
+    pub fn with_describe_agent_versions<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeAgentVersionsRequest,
+            ) -> ::std::result::Result<
+                DescribeAgentVersionsResult,
+                RusotoError<DescribeAgentVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_agent_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_apps` with what `handler` returns for its input.
+    pub fn with_describe_apps<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeAppsRequest,
+            )
+                -> ::std::result::Result<DescribeAppsResult, RusotoError<DescribeAppsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_apps = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_commands` with what `handler` returns for its input.
+    pub fn with_describe_commands<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeCommandsRequest,
+            )
+                -> ::std::result::Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_commands = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_deployments` with what `handler` returns for its input.
+    pub fn with_describe_deployments<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeDeploymentsRequest,
+            ) -> ::std::result::Result<
+                DescribeDeploymentsResult,
+                RusotoError<DescribeDeploymentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_deployments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ecs_clusters` with what `handler` returns for its input.
+    pub fn with_describe_ecs_clusters<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeEcsClustersRequest,
+            ) -> ::std::result::Result<
+                DescribeEcsClustersResult,
+                RusotoError<DescribeEcsClustersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ecs_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_elastic_ips` with what `handler` returns for its input.
+    pub fn with_describe_elastic_ips<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeElasticIpsRequest,
+            ) -> ::std::result::Result<
+                DescribeElasticIpsResult,
+                RusotoError<DescribeElasticIpsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_elastic_ips = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_elastic_load_balancers` with what `handler` returns for its input.
+    pub fn with_describe_elastic_load_balancers<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeElasticLoadBalancersRequest,
+            ) -> ::std::result::Result<
+                DescribeElasticLoadBalancersResult,
+                RusotoError<DescribeElasticLoadBalancersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_elastic_load_balancers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instances` with what `handler` returns for its input.
+    pub fn with_describe_instances<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeInstancesRequest,
+            ) -> ::std::result::Result<
+                DescribeInstancesResult,
+                RusotoError<DescribeInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_layers` with what `handler` returns for its input.
+    pub fn with_describe_layers<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeLayersRequest,
+            )
+                -> ::std::result::Result<DescribeLayersResult, RusotoError<DescribeLayersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_layers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_load_based_auto_scaling` with what `handler` returns for its input.
+    pub fn with_describe_load_based_auto_scaling<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeLoadBasedAutoScalingRequest,
+            ) -> ::std::result::Result<
+                DescribeLoadBasedAutoScalingResult,
+                RusotoError<DescribeLoadBasedAutoScalingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_load_based_auto_scaling = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_my_user_profile` with what `handler` returns.
+    pub fn with_describe_my_user_profile<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeMyUserProfileResult,
+                RusotoError<DescribeMyUserProfileError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_my_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_operating_systems` with what `handler` returns.
+    pub fn with_describe_operating_systems<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeOperatingSystemsResponse,
+                RusotoError<DescribeOperatingSystemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_operating_systems = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_permissions` with what `handler` returns for its input.
+    pub fn with_describe_permissions<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribePermissionsRequest,
+            ) -> ::std::result::Result<
+                DescribePermissionsResult,
+                RusotoError<DescribePermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_raid_arrays` with what `handler` returns for its input.
+    pub fn with_describe_raid_arrays<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeRaidArraysRequest,
+            ) -> ::std::result::Result<
+                DescribeRaidArraysResult,
+                RusotoError<DescribeRaidArraysError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_raid_arrays = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_rds_db_instances` with what `handler` returns for its input.
+    pub fn with_describe_rds_db_instances<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeRdsDbInstancesRequest,
+            ) -> ::std::result::Result<
+                DescribeRdsDbInstancesResult,
+                RusotoError<DescribeRdsDbInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_rds_db_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_service_errors` with what `handler` returns for its input.
+    pub fn with_describe_service_errors<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeServiceErrorsRequest,
+            ) -> ::std::result::Result<
+                DescribeServiceErrorsResult,
+                RusotoError<DescribeServiceErrorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_service_errors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_provisioning_parameters` with what `handler` returns for its input.
+    pub fn with_describe_stack_provisioning_parameters<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeStackProvisioningParametersRequest,
+            ) -> ::std::result::Result<
+                DescribeStackProvisioningParametersResult,
+                RusotoError<DescribeStackProvisioningParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_provisioning_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stack_summary` with what `handler` returns for its input.
+    pub fn with_describe_stack_summary<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeStackSummaryRequest,
+            ) -> ::std::result::Result<
+                DescribeStackSummaryResult,
+                RusotoError<DescribeStackSummaryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stack_summary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stacks` with what `handler` returns for its input.
+    pub fn with_describe_stacks<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeStacksRequest,
+            )
+                -> ::std::result::Result<DescribeStacksResult, RusotoError<DescribeStacksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_time_based_auto_scaling` with what `handler` returns for its input.
+    pub fn with_describe_time_based_auto_scaling<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeTimeBasedAutoScalingRequest,
+            ) -> ::std::result::Result<
+                DescribeTimeBasedAutoScalingResult,
+                RusotoError<DescribeTimeBasedAutoScalingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_time_based_auto_scaling = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user_profiles` with what `handler` returns for its input.
+    pub fn with_describe_user_profiles<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeUserProfilesRequest,
+            ) -> ::std::result::Result<
+                DescribeUserProfilesResult,
+                RusotoError<DescribeUserProfilesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user_profiles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_volumes` with what `handler` returns for its input.
+    pub fn with_describe_volumes<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DescribeVolumesRequest,
+            )
+                -> ::std::result::Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_volumes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_elastic_load_balancer` with what `handler` returns for its input.
+    pub fn with_detach_elastic_load_balancer<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DetachElasticLoadBalancerRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DetachElasticLoadBalancerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_elastic_load_balancer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_elastic_ip` with what `handler` returns for its input.
+    pub fn with_disassociate_elastic_ip<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                DisassociateElasticIpRequest,
+            ) -> ::std::result::Result<(), RusotoError<DisassociateElasticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_elastic_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_hostname_suggestion` with what `handler` returns for its input.
+    pub fn with_get_hostname_suggestion<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                GetHostnameSuggestionRequest,
+            ) -> ::std::result::Result<
+                GetHostnameSuggestionResult,
+                RusotoError<GetHostnameSuggestionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_hostname_suggestion = Some(Box::new(handler));
+        self
+    }
+    /// Answers `grant_access` with what `handler` returns for its input.
+    pub fn with_grant_access<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                GrantAccessRequest,
+            )
+                -> ::std::result::Result<GrantAccessResult, RusotoError<GrantAccessError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.grant_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(ListTagsRequest) -> ::std::result::Result<ListTagsResult, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_instance` with what `handler` returns for its input.
+    pub fn with_reboot_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(RebootInstanceRequest) -> ::std::result::Result<(), RusotoError<RebootInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_ecs_cluster` with what `handler` returns for its input.
+    pub fn with_register_ecs_cluster<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                RegisterEcsClusterRequest,
+            ) -> ::std::result::Result<
+                RegisterEcsClusterResult,
+                RusotoError<RegisterEcsClusterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_ecs_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_elastic_ip` with what `handler` returns for its input.
+    pub fn with_register_elastic_ip<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                RegisterElasticIpRequest,
+            ) -> ::std::result::Result<
+                RegisterElasticIpResult,
+                RusotoError<RegisterElasticIpError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_elastic_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_instance` with what `handler` returns for its input.
+    pub fn with_register_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                RegisterInstanceRequest,
+            )
+                -> ::std::result::Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_rds_db_instance` with what `handler` returns for its input.
+    pub fn with_register_rds_db_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                RegisterRdsDbInstanceRequest,
+            ) -> ::std::result::Result<(), RusotoError<RegisterRdsDbInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_rds_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_volume` with what `handler` returns for its input.
+    pub fn with_register_volume<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                RegisterVolumeRequest,
+            )
+                -> ::std::result::Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_load_based_auto_scaling` with what `handler` returns for its input.
+    pub fn with_set_load_based_auto_scaling<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                SetLoadBasedAutoScalingRequest,
+            ) -> ::std::result::Result<(), RusotoError<SetLoadBasedAutoScalingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_load_based_auto_scaling = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_permission` with what `handler` returns for its input.
+    pub fn with_set_permission<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(SetPermissionRequest) -> ::std::result::Result<(), RusotoError<SetPermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_time_based_auto_scaling` with what `handler` returns for its input.
+    pub fn with_set_time_based_auto_scaling<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                SetTimeBasedAutoScalingRequest,
+            ) -> ::std::result::Result<(), RusotoError<SetTimeBasedAutoScalingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_time_based_auto_scaling = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_instance` with what `handler` returns for its input.
+    pub fn with_start_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(StartInstanceRequest) -> ::std::result::Result<(), RusotoError<StartInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_stack` with what `handler` returns for its input.
+    pub fn with_start_stack<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(StartStackRequest) -> ::std::result::Result<(), RusotoError<StartStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_instance` with what `handler` returns for its input.
+    pub fn with_stop_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(StopInstanceRequest) -> ::std::result::Result<(), RusotoError<StopInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_stack` with what `handler` returns for its input.
+    pub fn with_stop_stack<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(StopStackRequest) -> ::std::result::Result<(), RusotoError<StopStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unassign_instance` with what `handler` returns for its input.
+    pub fn with_unassign_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                UnassignInstanceRequest,
+            ) -> ::std::result::Result<(), RusotoError<UnassignInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unassign_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unassign_volume` with what `handler` returns for its input.
+    pub fn with_unassign_volume<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UnassignVolumeRequest) -> ::std::result::Result<(), RusotoError<UnassignVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unassign_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_app` with what `handler` returns for its input.
+    pub fn with_update_app<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UpdateAppRequest) -> ::std::result::Result<(), RusotoError<UpdateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_elastic_ip` with what `handler` returns for its input.
+    pub fn with_update_elastic_ip<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                UpdateElasticIpRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateElasticIpError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_elastic_ip = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_instance` with what `handler` returns for its input.
+    pub fn with_update_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UpdateInstanceRequest) -> ::std::result::Result<(), RusotoError<UpdateInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_layer` with what `handler` returns for its input.
+    pub fn with_update_layer<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UpdateLayerRequest) -> ::std::result::Result<(), RusotoError<UpdateLayerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_layer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_my_user_profile` with what `handler` returns for its input.
+    pub fn with_update_my_user_profile<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                UpdateMyUserProfileRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateMyUserProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_my_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_rds_db_instance` with what `handler` returns for its input.
+    pub fn with_update_rds_db_instance<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                UpdateRdsDbInstanceRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateRdsDbInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_rds_db_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_stack` with what `handler` returns for its input.
+    pub fn with_update_stack<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UpdateStackRequest) -> ::std::result::Result<(), RusotoError<UpdateStackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_stack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user_profile` with what `handler` returns for its input.
+    pub fn with_update_user_profile<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(
+                UpdateUserProfileRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateUserProfileError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user_profile = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_volume` with what `handler` returns for its input.
+    pub fn with_update_volume<F>(mut self, handler: F) -> MockOpsWorks
+    where
+        F: Fn(UpdateVolumeRequest) -> ::std::result::Result<(), RusotoError<UpdateVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_volume = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl OpsWorks for MockOpsWorks {
+    fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> RusotoFuture<(), AssignInstanceError> {
+        match self.assign_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for assign_instance"),
+        }
+    }
+    fn assign_volume(&self, input: AssignVolumeRequest) -> RusotoFuture<(), AssignVolumeError> {
+        match self.assign_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for assign_volume"),
+        }
+    }
+    fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> RusotoFuture<(), AssociateElasticIpError> {
+        match self.associate_elastic_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for associate_elastic_ip"),
+        }
+    }
+    fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), AttachElasticLoadBalancerError> {
+        match self.attach_elastic_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for attach_elastic_load_balancer"),
+        }
+    }
+    fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> RusotoFuture<CloneStackResult, CloneStackError> {
+        match self.clone_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for clone_stack"),
+        }
+    }
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        match self.create_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for create_app"),
+        }
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        match self.create_deployment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for create_deployment"),
+        }
+    }
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResult, CreateInstanceError> {
+        match self.create_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for create_instance"),
+        }
+    }
+    fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> RusotoFuture<CreateLayerResult, CreateLayerError> {
+        match self.create_layer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for create_layer"),
+        }
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        match self.create_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for create_stack"),
+        }
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        match self.create_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for create_user_profile"),
+        }
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<(), DeleteAppError> {
+        match self.delete_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for delete_app"),
+        }
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<(), DeleteInstanceError> {
+        match self.delete_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for delete_instance"),
+        }
+    }
+    fn delete_layer(&self, input: DeleteLayerRequest) -> RusotoFuture<(), DeleteLayerError> {
+        match self.delete_layer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for delete_layer"),
+        }
+    }
+    fn delete_stack(&self, input: DeleteStackRequest) -> RusotoFuture<(), DeleteStackError> {
+        match self.delete_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for delete_stack"),
+        }
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<(), DeleteUserProfileError> {
+        match self.delete_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for delete_user_profile"),
+        }
+    }
+    fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> RusotoFuture<(), DeregisterEcsClusterError> {
+        match self.deregister_ecs_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for deregister_ecs_cluster"),
+        }
+    }
+    fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> RusotoFuture<(), DeregisterElasticIpError> {
+        match self.deregister_elastic_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for deregister_elastic_ip"),
+        }
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterInstanceError> {
+        match self.deregister_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for deregister_instance"),
+        }
+    }
+    fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterRdsDbInstanceError> {
+        match self.deregister_rds_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for deregister_rds_db_instance"),
+        }
+    }
+    fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> RusotoFuture<(), DeregisterVolumeError> {
+        match self.deregister_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for deregister_volume"),
+        }
+    }
+    fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> RusotoFuture<DescribeAgentVersionsResult, DescribeAgentVersionsError> {
+        match self.describe_agent_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_agent_versions"),
+        }
+    }
+    fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> RusotoFuture<DescribeAppsResult, DescribeAppsError> {
+        match self.describe_apps {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_apps"),
+        }
+    }
+    fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> RusotoFuture<DescribeCommandsResult, DescribeCommandsError> {
+        match self.describe_commands {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_commands"),
+        }
+    }
+    fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> RusotoFuture<DescribeDeploymentsResult, DescribeDeploymentsError> {
+        match self.describe_deployments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_deployments"),
+        }
+    }
+    fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> RusotoFuture<DescribeEcsClustersResult, DescribeEcsClustersError> {
+        match self.describe_ecs_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_ecs_clusters"),
+        }
+    }
+    fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> RusotoFuture<DescribeElasticIpsResult, DescribeElasticIpsError> {
+        match self.describe_elastic_ips {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_elastic_ips"),
+        }
+    }
+    fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeElasticLoadBalancersResult, DescribeElasticLoadBalancersError> {
+        match self.describe_elastic_load_balancers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_elastic_load_balancers"),
+        }
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> RusotoFuture<DescribeInstancesResult, DescribeInstancesError> {
+        match self.describe_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_instances"),
+        }
+    }
+    fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> RusotoFuture<DescribeLayersResult, DescribeLayersError> {
+        match self.describe_layers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_layers"),
+        }
+    }
+    fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeLoadBasedAutoScalingResult, DescribeLoadBasedAutoScalingError> {
+        match self.describe_load_based_auto_scaling {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_load_based_auto_scaling"),
+        }
+    }
+    fn describe_my_user_profile(
+        &self,
+    ) -> RusotoFuture<DescribeMyUserProfileResult, DescribeMyUserProfileError> {
+        match self.describe_my_user_profile {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOpsWorks has no handler for describe_my_user_profile"),
+        }
+    }
+    fn describe_operating_systems(
+        &self,
+    ) -> RusotoFuture<DescribeOperatingSystemsResponse, DescribeOperatingSystemsError> {
+        match self.describe_operating_systems {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOpsWorks has no handler for describe_operating_systems"),
+        }
+    }
+    fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> RusotoFuture<DescribePermissionsResult, DescribePermissionsError> {
+        match self.describe_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_permissions"),
+        }
+    }
+    fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> RusotoFuture<DescribeRaidArraysResult, DescribeRaidArraysError> {
+        match self.describe_raid_arrays {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_raid_arrays"),
+        }
+    }
+    fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> RusotoFuture<DescribeRdsDbInstancesResult, DescribeRdsDbInstancesError> {
+        match self.describe_rds_db_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_rds_db_instances"),
+        }
+    }
+    fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> RusotoFuture<DescribeServiceErrorsResult, DescribeServiceErrorsError> {
+        match self.describe_service_errors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_service_errors"),
+        }
+    }
+    fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> RusotoFuture<
+        DescribeStackProvisioningParametersResult,
+        DescribeStackProvisioningParametersError,
+    > {
+        match self.describe_stack_provisioning_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockOpsWorks has no handler for describe_stack_provisioning_parameters")
+            }
+        }
+    }
+    fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> RusotoFuture<DescribeStackSummaryResult, DescribeStackSummaryError> {
+        match self.describe_stack_summary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_stack_summary"),
+        }
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        match self.describe_stacks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_stacks"),
+        }
+    }
+    fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeTimeBasedAutoScalingResult, DescribeTimeBasedAutoScalingError> {
+        match self.describe_time_based_auto_scaling {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_time_based_auto_scaling"),
+        }
+    }
+    fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> RusotoFuture<DescribeUserProfilesResult, DescribeUserProfilesError> {
+        match self.describe_user_profiles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_user_profiles"),
+        }
+    }
+    fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> RusotoFuture<DescribeVolumesResult, DescribeVolumesError> {
+        match self.describe_volumes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for describe_volumes"),
+        }
+    }
+    fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), DetachElasticLoadBalancerError> {
+        match self.detach_elastic_load_balancer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for detach_elastic_load_balancer"),
+        }
+    }
+    fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> RusotoFuture<(), DisassociateElasticIpError> {
+        match self.disassociate_elastic_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for disassociate_elastic_ip"),
+        }
+    }
+    fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> RusotoFuture<GetHostnameSuggestionResult, GetHostnameSuggestionError> {
+        match self.get_hostname_suggestion {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for get_hostname_suggestion"),
+        }
+    }
+    fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> RusotoFuture<GrantAccessResult, GrantAccessError> {
+        match self.grant_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for grant_access"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResult, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for list_tags"),
+        }
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<(), RebootInstanceError> {
+        match self.reboot_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for reboot_instance"),
+        }
+    }
+    fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> RusotoFuture<RegisterEcsClusterResult, RegisterEcsClusterError> {
+        match self.register_ecs_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for register_ecs_cluster"),
+        }
+    }
+    fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> RusotoFuture<RegisterElasticIpResult, RegisterElasticIpError> {
+        match self.register_elastic_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for register_elastic_ip"),
+        }
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResult, RegisterInstanceError> {
+        match self.register_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for register_instance"),
+        }
+    }
+    fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), RegisterRdsDbInstanceError> {
+        match self.register_rds_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for register_rds_db_instance"),
+        }
+    }
+    fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> RusotoFuture<RegisterVolumeResult, RegisterVolumeError> {
+        match self.register_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for register_volume"),
+        }
+    }
+    fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetLoadBasedAutoScalingError> {
+        match self.set_load_based_auto_scaling {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for set_load_based_auto_scaling"),
+        }
+    }
+    fn set_permission(&self, input: SetPermissionRequest) -> RusotoFuture<(), SetPermissionError> {
+        match self.set_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for set_permission"),
+        }
+    }
+    fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetTimeBasedAutoScalingError> {
+        match self.set_time_based_auto_scaling {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for set_time_based_auto_scaling"),
+        }
+    }
+    fn start_instance(&self, input: StartInstanceRequest) -> RusotoFuture<(), StartInstanceError> {
+        match self.start_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for start_instance"),
+        }
+    }
+    fn start_stack(&self, input: StartStackRequest) -> RusotoFuture<(), StartStackError> {
+        match self.start_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for start_stack"),
+        }
+    }
+    fn stop_instance(&self, input: StopInstanceRequest) -> RusotoFuture<(), StopInstanceError> {
+        match self.stop_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for stop_instance"),
+        }
+    }
+    fn stop_stack(&self, input: StopStackRequest) -> RusotoFuture<(), StopStackError> {
+        match self.stop_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for stop_stack"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for tag_resource"),
+        }
+    }
+    fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> RusotoFuture<(), UnassignInstanceError> {
+        match self.unassign_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for unassign_instance"),
+        }
     }
-
-    /// <p>Unassigns an assigned Amazon EBS volume. The volume remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn unassign_volume(
         &self,
         input: UnassignVolumeRequest,
     ) -> RusotoFuture<(), UnassignVolumeError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UnassignVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UnassignVolumeError::from_response(response))),
-                )
-            }
-        })
+        match self.unassign_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for unassign_volume"),
+        }
     }
-
-    /// <p>Removes tags from a specified stack or layer.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<(), UpdateAppError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateApp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAppError::from_response(response))),
-                )
-            }
-        })
+        match self.update_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_app"),
+        }
     }
-
-    /// <p>Updates a registered Elastic IP address's name. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_elastic_ip(
         &self,
         input: UpdateElasticIpRequest,
     ) -> RusotoFuture<(), UpdateElasticIpError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateElasticIp");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateElasticIpError::from_response(response))),
-                )
-            }
-        })
+        match self.update_elastic_ip {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_elastic_ip"),
+        }
     }
-
-    /// <p>Updates a specified instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_instance(
         &self,
         input: UpdateInstanceRequest,
     ) -> RusotoFuture<(), UpdateInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateInstanceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_instance"),
+        }
     }
-
-    /// <p>Updates a specified layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_layer(&self, input: UpdateLayerRequest) -> RusotoFuture<(), UpdateLayerError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateLayer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateLayerError::from_response(response))),
-                )
-            }
-        })
+        match self.update_layer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_layer"),
+        }
     }
-
-    /// <p>Updates a user's SSH public key.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_my_user_profile(
         &self,
         input: UpdateMyUserProfileRequest,
     ) -> RusotoFuture<(), UpdateMyUserProfileError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateMyUserProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateMyUserProfileError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_my_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_my_user_profile"),
+        }
     }
-
-    /// <p>Updates an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_rds_db_instance(
         &self,
         input: UpdateRdsDbInstanceRequest,
     ) -> RusotoFuture<(), UpdateRdsDbInstanceError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateRdsDbInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRdsDbInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_rds_db_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_rds_db_instance"),
+        }
     }
-
-    /// <p>Updates a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_stack(&self, input: UpdateStackRequest) -> RusotoFuture<(), UpdateStackError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateStack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateStackError::from_response(response))),
-                )
-            }
-        })
+        match self.update_stack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_stack"),
+        }
     }
-
-    /// <p>Updates a specified user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_user_profile(
         &self,
         input: UpdateUserProfileRequest,
     ) -> RusotoFuture<(), UpdateUserProfileError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateUserProfile");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUserProfileError::from_response(response))),
-                )
-            }
-        })
+        match self.update_user_profile {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_user_profile"),
+        }
     }
-
-    /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError> {
-        let mut request = SignedRequest::new("POST", "opsworks", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorks_20130218.UpdateVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateVolumeError::from_response(response))),
-                )
-            }
-        })
+        match self.update_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorks has no handler for update_volume"),
+        }
     }
 }
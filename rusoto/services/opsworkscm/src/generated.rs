@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Stores account attributes. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccountAttribute {
     /// <p> The maximum allowed value. </p>
     #[serde(rename = "Maximum")]
@@ -42,6 +43,7 @@ pub struct AccountAttribute {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateNodeRequest {
     /// <p><p>Engine attributes used for associating the node. </p> <p class="title"> <b>Attributes accepted in a AssociateNode request for Chef</b> </p> <ul> <li> <p> <code>CHEF<em>ORGANIZATION</code>: The Chef organization with which the node is associated. By default only one organization named <code>default</code> can exist. </p> </li> <li> <p> <code>CHEF</em>NODE<em>PUBLIC</em>KEY</code>: A PEM-formatted public key. This key is required for the <code>chef-client</code> agent to access the Chef API. </p> </li> </ul> <p class="title"> <b>Attributes accepted in a AssociateNode request for Puppet</b> </p> <ul> <li> <p> <code>PUPPET<em>NODE</em>CSR</code>: A PEM-formatted certificate-signing request (CSR) that is created by the node. </p> </li> </ul></p>
     #[serde(rename = "EngineAttributes")]
@@ -54,8 +56,39 @@ pub struct AssociateNodeRequest {
     pub server_name: String,
 }
 
+impl Validate for AssociateNodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.engine_attributes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "engine_attributes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "node_name"),
+            self.node_name.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "node_name"),
+            &self.node_name,
+            "^[\\-\\p{Alnum}_:.]+$",
+        );
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateNodeResponse {
     /// <p>Contains a token which can be passed to the <code>DescribeNodeAssociationStatus</code> API call to get the status of the association request. </p>
     #[serde(rename = "NodeAssociationStatusToken")]
@@ -65,7 +98,7 @@ pub struct AssociateNodeResponse {
 
 /// <p>Describes a single backup. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Backup {
     /// <p>The ARN of the backup. </p>
     #[serde(rename = "BackupArn")]
@@ -158,6 +191,7 @@ pub struct Backup {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBackupRequest {
     /// <p> A user-defined description of the backup. </p>
     #[serde(rename = "Description")]
@@ -168,8 +202,33 @@ pub struct CreateBackupRequest {
     pub server_name: String,
 }
 
+impl Validate for CreateBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateBackupResponse {
     /// <p>Backup created by request.</p>
     #[serde(rename = "Backup")]
@@ -178,6 +237,7 @@ pub struct CreateBackupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateServerRequest {
     /// <p> Associate a public IP address with a server that you are launching. Valid values are <code>true</code> or <code>false</code>. The default value is <code>true</code>. </p>
     #[serde(rename = "AssociatePublicIpAddress")]
@@ -245,8 +305,160 @@ pub struct CreateServerRequest {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+impl Validate for CreateServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.backup_id {
+            errors.check_length(
+                &field_path(path, "backup_id"),
+                value.chars().count(),
+                None,
+                Some(79),
+            );
+            errors.check_pattern(
+                &field_path(path, "backup_id"),
+                &value,
+                "[a-zA-Z][a-zA-Z0-9\\-\\.\\:]*",
+            );
+        }
+        if let Some(ref value) = self.backup_retention_count {
+            errors.check_range(
+                &field_path(path, "backup_retention_count"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.engine {
+            errors.check_length(
+                &field_path(path, "engine"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "engine"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.engine_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "engine_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.engine_model {
+            errors.check_length(
+                &field_path(path, "engine_model"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "engine_model"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.engine_version {
+            errors.check_length(
+                &field_path(path, "engine_version"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "engine_version"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "instance_profile_arn"),
+            self.instance_profile_arn.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_profile_arn"),
+            &self.instance_profile_arn,
+            "arn:aws:iam::[0-9]{12}:instance-profile/.*",
+        );
+        errors.check_length(
+            &field_path(path, "instance_type"),
+            self.instance_type.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_type"),
+            &self.instance_type,
+            "(?s).*",
+        );
+        if let Some(ref value) = self.key_pair {
+            errors.check_length(
+                &field_path(path, "key_pair"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "key_pair"), &value, ".*");
+        }
+        if let Some(ref value) = self.preferred_backup_window {
+            errors.check_length(
+                &field_path(path, "preferred_backup_window"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "preferred_backup_window"),
+                &value,
+                "^((Mon|Tue|Wed|Thu|Fri|Sat|Sun):)?([0-1][0-9]|2[0-3]):[0-5][0-9]$",
+            );
+        }
+        if let Some(ref value) = self.preferred_maintenance_window {
+            errors.check_length(
+                &field_path(path, "preferred_maintenance_window"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "preferred_maintenance_window"),
+                &value,
+                "^((Mon|Tue|Wed|Thu|Fri|Sat|Sun):)?([0-1][0-9]|2[0-3]):[0-5][0-9]$",
+            );
+        }
+        if let Some(ref value) = self.security_group_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10000));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "service_role_arn"),
+            self.service_role_arn.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_role_arn"),
+            &self.service_role_arn,
+            "arn:aws:iam::[0-9]{12}:role/.*",
+        );
+        if let Some(ref value) = self.subnet_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10000));
+                errors.check_pattern(&item_path, &item, "(?s).*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateServerResponse {
     /// <p>The server that is created by the request. </p>
     #[serde(rename = "Server")]
@@ -255,32 +467,67 @@ pub struct CreateServerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBackupRequest {
     /// <p>The ID of the backup to delete. Run the DescribeBackups command to get a list of backup IDs. Backup IDs are in the format <code>ServerName-yyyyMMddHHmmssSSS</code>. </p>
     #[serde(rename = "BackupId")]
     pub backup_id: String,
 }
 
+impl Validate for DeleteBackupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_id"),
+            self.backup_id.chars().count(),
+            None,
+            Some(79),
+        );
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "[a-zA-Z][a-zA-Z0-9\\-\\.\\:]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBackupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteServerRequest {
     /// <p>The ID of the server to delete.</p>
     #[serde(rename = "ServerName")]
     pub server_name: String,
 }
 
+impl Validate for DeleteServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteServerResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountAttributesRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAccountAttributesResponse {
     /// <p> The attributes that are currently set for the account. </p>
     #[serde(rename = "Attributes")]
@@ -289,6 +536,7 @@ pub struct DescribeAccountAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBackupsRequest {
     /// <p>Describes a single backup. </p>
     #[serde(rename = "BackupId")]
@@ -308,8 +556,51 @@ pub struct DescribeBackupsRequest {
     pub server_name: Option<String>,
 }
 
+impl Validate for DescribeBackupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.backup_id {
+            errors.check_length(
+                &field_path(path, "backup_id"),
+                value.chars().count(),
+                None,
+                Some(79),
+            );
+            errors.check_pattern(
+                &field_path(path, "backup_id"),
+                &value,
+                "[a-zA-Z][a-zA-Z0-9\\-\\.\\:]*",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.server_name {
+            errors.check_length(
+                &field_path(path, "server_name"),
+                value.chars().count(),
+                Some(1),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "server_name"),
+                &value,
+                "[a-zA-Z][a-zA-Z0-9\\-]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBackupsResponse {
     /// <p>Contains the response to a <code>DescribeBackups</code> request. </p>
     #[serde(rename = "Backups")]
@@ -322,6 +613,7 @@ pub struct DescribeBackupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsRequest {
     /// <p>To receive a paginated response, use this parameter to specify the maximum number of results to be returned with a single call. If the number of available results exceeds this maximum, the response includes a <code>NextToken</code> value that you can assign to the <code>NextToken</code> request parameter to get the next set of results. </p>
     #[serde(rename = "MaxResults")]
@@ -336,8 +628,36 @@ pub struct DescribeEventsRequest {
     pub server_name: String,
 }
 
+impl Validate for DescribeEventsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEventsResponse {
     /// <p>NextToken is a string that is returned in some command responses. It indicates that not all entries have been returned, and that you must run at least one more request to get remaining items. To get remaining results, call <code>DescribeEvents</code> again, and assign the token from the previous results as the value of the <code>nextToken</code> parameter. If there are no more results, the response object's <code>nextToken</code> parameter value is <code>null</code>. Setting a <code>nextToken</code> value that was not returned in your previous results causes an <code>InvalidNextTokenException</code> to occur. </p>
     #[serde(rename = "NextToken")]
@@ -350,6 +670,7 @@ pub struct DescribeEventsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNodeAssociationStatusRequest {
     /// <p>The token returned in either the AssociateNodeResponse or the DisassociateNodeResponse. </p>
     #[serde(rename = "NodeAssociationStatusToken")]
@@ -359,8 +680,35 @@ pub struct DescribeNodeAssociationStatusRequest {
     pub server_name: String,
 }
 
+impl Validate for DescribeNodeAssociationStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "node_association_status_token"),
+            self.node_association_status_token.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "node_association_status_token"),
+            &self.node_association_status_token,
+            "(?s).*",
+        );
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeNodeAssociationStatusResponse {
     /// <p>Attributes specific to the node association. In Puppet, the attibute PUPPET_NODE_CERT contains the signed certificate (the result of the CSR). </p>
     #[serde(rename = "EngineAttributes")]
@@ -373,6 +721,7 @@ pub struct DescribeNodeAssociationStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServersRequest {
     /// <p>This is not currently implemented for <code>DescribeServers</code> requests. </p>
     #[serde(rename = "MaxResults")]
@@ -388,8 +737,38 @@ pub struct DescribeServersRequest {
     pub server_name: Option<String>,
 }
 
+impl Validate for DescribeServersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.server_name {
+            errors.check_length(
+                &field_path(path, "server_name"),
+                value.chars().count(),
+                Some(1),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "server_name"),
+                &value,
+                "[a-zA-Z][a-zA-Z0-9\\-]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServersResponse {
     /// <p>This is not currently implemented for <code>DescribeServers</code> requests. </p>
     #[serde(rename = "NextToken")]
@@ -402,6 +781,7 @@ pub struct DescribeServersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateNodeRequest {
     /// <p><p>Engine attributes that are used for disassociating the node. No attributes are required for Puppet. </p> <p class="title"> <b>Attributes required in a DisassociateNode request for Chef</b> </p> <ul> <li> <p> <code>CHEF_ORGANIZATION</code>: The Chef organization with which the node was associated. By default only one organization named <code>default</code> can exist. </p> </li> </ul></p>
     #[serde(rename = "EngineAttributes")]
@@ -415,8 +795,41 @@ pub struct DisassociateNodeRequest {
     pub server_name: String,
 }
 
+impl Validate for DisassociateNodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.engine_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "engine_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "node_name"),
+            self.node_name.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "node_name"),
+            &self.node_name,
+            "^[\\-\\p{Alnum}_:.]+$",
+        );
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateNodeResponse {
     /// <p>Contains a token which can be passed to the <code>DescribeNodeAssociationStatus</code> API call to get the status of the disassociation request. </p>
     #[serde(rename = "NodeAssociationStatusToken")]
@@ -437,7 +850,31 @@ pub struct EngineAttribute {
     pub value: Option<String>,
 }
 
+impl Validate for EngineAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "value"), &value, "(?s).*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportServerEngineAttributeRequest {
     /// <p>The name of the export attribute. Currently, the supported export attribute is <code>Userdata</code>. This exports a user data script that includes parameters and values provided in the <code>InputAttributes</code> list.</p>
     #[serde(rename = "ExportAttributeName")]
@@ -451,8 +888,41 @@ pub struct ExportServerEngineAttributeRequest {
     pub server_name: String,
 }
 
+impl Validate for ExportServerEngineAttributeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "export_attribute_name"),
+            self.export_attribute_name.chars().count(),
+            None,
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "export_attribute_name"),
+            &self.export_attribute_name,
+            "(?s).*",
+        );
+        if let Some(ref value) = self.input_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportServerEngineAttributeResponse {
     /// <p>The requested engine attribute pair with attribute name and value.</p>
     #[serde(rename = "EngineAttribute")]
@@ -465,6 +935,7 @@ pub struct ExportServerEngineAttributeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreServerRequest {
     /// <p> The ID of the backup that you want to use to restore a server. </p>
     #[serde(rename = "BackupId")]
@@ -482,13 +953,58 @@ pub struct RestoreServerRequest {
     pub server_name: String,
 }
 
+impl Validate for RestoreServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "backup_id"),
+            self.backup_id.chars().count(),
+            None,
+            Some(79),
+        );
+        errors.check_pattern(
+            &field_path(path, "backup_id"),
+            &self.backup_id,
+            "[a-zA-Z][a-zA-Z0-9\\-\\.\\:]*",
+        );
+        if let Some(ref value) = self.instance_type {
+            errors.check_length(
+                &field_path(path, "instance_type"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "instance_type"), &value, "(?s).*");
+        }
+        if let Some(ref value) = self.key_pair {
+            errors.check_length(
+                &field_path(path, "key_pair"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "key_pair"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreServerResponse {}
 
 /// <p>Describes a configuration management server. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Server {
     /// <p>Associate a public IP address with a server that you are launching. </p>
     #[serde(rename = "AssociatePublicIpAddress")]
@@ -586,7 +1102,7 @@ pub struct Server {
 
 /// <p>An event that is related to the server, such as the start of maintenance or backup. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServerEvent {
     /// <p>The time when the event occurred. </p>
     #[serde(rename = "CreatedAt")]
@@ -607,6 +1123,7 @@ pub struct ServerEvent {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartMaintenanceRequest {
     /// <p>Engine attributes that are specific to the server on which you want to run maintenance. </p>
     #[serde(rename = "EngineAttributes")]
@@ -617,8 +1134,30 @@ pub struct StartMaintenanceRequest {
     pub server_name: String,
 }
 
+impl Validate for StartMaintenanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.engine_attributes {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "engine_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartMaintenanceResponse {
     /// <p>Contains the response to a <code>StartMaintenance</code> request. </p>
     #[serde(rename = "Server")]
@@ -627,6 +1166,7 @@ pub struct StartMaintenanceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServerEngineAttributesRequest {
     /// <p>The name of the engine attribute to update. </p>
     #[serde(rename = "AttributeName")]
@@ -640,8 +1180,44 @@ pub struct UpdateServerEngineAttributesRequest {
     pub server_name: String,
 }
 
+impl Validate for UpdateServerEngineAttributesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attribute_name"),
+            self.attribute_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "attribute_name"),
+            &self.attribute_name,
+            "[A-Z][A-Z0-9_]*",
+        );
+        if let Some(ref value) = self.attribute_value {
+            errors.check_length(
+                &field_path(path, "attribute_value"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(&field_path(path, "attribute_value"), &value, "(?s).*");
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServerEngineAttributesResponse {
     /// <p>Contains the response to an <code>UpdateServerEngineAttributes</code> request. </p>
     #[serde(rename = "Server")]
@@ -650,6 +1226,7 @@ pub struct UpdateServerEngineAttributesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServerRequest {
     /// <p>Sets the number of automated backups that you want to keep. </p>
     #[serde(rename = "BackupRetentionCount")]
@@ -670,8 +1247,50 @@ pub struct UpdateServerRequest {
     pub server_name: String,
 }
 
+impl Validate for UpdateServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.preferred_backup_window {
+            errors.check_length(
+                &field_path(path, "preferred_backup_window"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "preferred_backup_window"),
+                &value,
+                "^((Mon|Tue|Wed|Thu|Fri|Sat|Sun):)?([0-1][0-9]|2[0-3]):[0-5][0-9]$",
+            );
+        }
+        if let Some(ref value) = self.preferred_maintenance_window {
+            errors.check_length(
+                &field_path(path, "preferred_maintenance_window"),
+                value.chars().count(),
+                None,
+                Some(10000),
+            );
+            errors.check_pattern(
+                &field_path(path, "preferred_maintenance_window"),
+                &value,
+                "^((Mon|Tue|Wed|Thu|Fri|Sat|Sun):)?([0-1][0-9]|2[0-3]):[0-5][0-9]$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "server_name"),
+            self.server_name.chars().count(),
+            Some(1),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_name"),
+            &self.server_name,
+            "[a-zA-Z][a-zA-Z0-9\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServerResponse {
     /// <p>Contains the response to a <code>UpdateServer</code> request. </p>
     #[serde(rename = "Server")]
@@ -698,7 +1317,12 @@ impl AssociateNodeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssociateNodeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -742,7 +1366,12 @@ impl CreateBackupError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateBackupError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -787,7 +1416,12 @@ impl CreateServerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateServerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -827,7 +1461,12 @@ impl DeleteBackupError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteBackupError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -866,7 +1505,12 @@ impl DeleteServerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteServerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -894,7 +1538,12 @@ impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -930,7 +1579,12 @@ impl DescribeBackupsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeBackupsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -969,7 +1623,12 @@ impl DescribeEventsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeEventsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1007,7 +1666,12 @@ impl DescribeNodeAssociationStatusError {
                         DescribeNodeAssociationStatusError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1045,7 +1709,12 @@ impl DescribeServersError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeServersError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1084,7 +1753,12 @@ impl DisassociateNodeError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DisassociateNodeError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1129,7 +1803,12 @@ impl ExportServerEngineAttributeError {
                         ExportServerEngineAttributeError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1168,7 +1847,12 @@ impl RestoreServerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RestoreServerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1207,7 +1891,12 @@ impl StartMaintenanceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(StartMaintenanceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1246,7 +1935,12 @@ impl UpdateServerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateServerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1291,7 +1985,12 @@ impl UpdateServerEngineAttributesError {
                         UpdateServerEngineAttributesError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1408,149 +2107,480 @@ pub trait OpsWorksCM {
         input: UpdateServerEngineAttributesRequest,
     ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError>;
 }
-/// A client for the OpsWorksCM API.
-#[derive(Clone)]
-pub struct OpsWorksCMClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl OpsWorksCMClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> OpsWorksCMClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> OpsWorksCMClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMClient {
-        OpsWorksCMClient { client, region }
-    }
-}
-
-impl OpsWorksCM for OpsWorksCMClient {
-    /// <p> Associates a new node with the server. For more information about how to disassociate a node, see <a>DisassociateNode</a>.</p> <p> On a Chef server: This command is an alternative to <code>knife bootstrap</code>.</p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>CHEF_ORGANIZATION</i>,Value=default" "Name=<i>CHEF_NODE_PUBLIC_KEY</i>,Value=<i>public-key-pem</i>"</code> </p> <p> On a Puppet server, this command is an alternative to the <code>puppet cert sign</code> command that signs a Puppet node CSR. </p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>PUPPET_NODE_CSR</i>,Value=<i>csr-pem</i>"</code> </p> <p> A node can can only be associated with servers that are in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. The AssociateNode API call can be integrated into Auto Scaling configurations, AWS Cloudformation templates, or the user data of a server's instance. </p>
+const _: Option<&dyn OpsWorksCM> = None;
+impl<'a, T: OpsWorksCM + ?Sized> OpsWorksCM for &'a T {
     fn associate_node(
         &self,
         input: AssociateNodeRequest,
     ) -> RusotoFuture<AssociateNodeResponse, AssociateNodeError> {
-        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.AssociateNode");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateNodeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssociateNodeError::from_response(response))),
-                )
-            }
-        })
+        (**self).associate_node(input)
     }
-
-    /// <p> Creates an application-level backup of a server. While the server is in the <code>BACKING_UP</code> state, the server cannot be changed, and no additional backup can be created. </p> <p> Backups can be created for servers in <code>RUNNING</code>, <code>HEALTHY</code>, and <code>UNHEALTHY</code> states. By default, you can create a maximum of 50 manual backups. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when the maximum number of manual backups is reached. An <code>InvalidStateException</code> is thrown when the server is not in any of the following states: RUNNING, HEALTHY, or UNHEALTHY. A <code>ResourceNotFoundException</code> is thrown when the server is not found. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
     fn create_backup(
         &self,
         input: CreateBackupRequest,
     ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
-        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateBackup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBackupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBackupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_backup(input)
     }
-
-    /// <p> Creates and immedately starts a new server. The server is ready to use when it is in the <code>HEALTHY</code> state. By default, you can create a maximum of 10 servers. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when you have created the maximum number of servers (10). A <code>ResourceAlreadyExistsException</code> is thrown when a server with the same name already exists in the account. A <code>ResourceNotFoundException</code> is thrown when you specify a backup ID that is not valid or is for a backup that does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p> <p> If you do not specify a security group by adding the <code>SecurityGroupIds</code> parameter, AWS OpsWorks creates a new security group. </p> <p> <i>Chef Automate:</i> The default security group opens the Chef server to the world on TCP port 443. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p> <i>Puppet Enterprise:</i> The default security group opens TCP ports 22, 443, 4433, 8140, 8142, 8143, and 8170. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p>By default, your server is accessible from any IP address. We recommend that you update your security group rules to allow access from known IP addresses and address ranges only. To edit security group rules, open Security Groups in the navigation pane of the EC2 management console. </p>
     fn create_server(
         &self,
         input: CreateServerRequest,
     ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
-        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateServer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateServerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateServerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_server(input)
     }
-
-    /// <p> Deletes a backup. You can delete both manual and automated backups. This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a backup deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
     fn delete_backup(
         &self,
         input: DeleteBackupRequest,
     ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
-        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteBackup");
-        let encoded = serde_json::to_string(&input).unwrap();
+        (**self).delete_backup(input)
+    }
+    fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> RusotoFuture<DeleteServerResponse, DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> RusotoFuture<DescribeNodeAssociationStatusResponse, DescribeNodeAssociationStatusError>
+    {
+        (**self).describe_node_association_status(input)
+    }
+    fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> RusotoFuture<DescribeServersResponse, DescribeServersError> {
+        (**self).describe_servers(input)
+    }
+    fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> RusotoFuture<DisassociateNodeResponse, DisassociateNodeError> {
+        (**self).disassociate_node(input)
+    }
+    fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> RusotoFuture<ExportServerEngineAttributeResponse, ExportServerEngineAttributeError> {
+        (**self).export_server_engine_attribute(input)
+    }
+    fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> RusotoFuture<RestoreServerResponse, RestoreServerError> {
+        (**self).restore_server(input)
+    }
+    fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> RusotoFuture<StartMaintenanceResponse, StartMaintenanceError> {
+        (**self).start_maintenance(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError> {
+        (**self).update_server_engine_attributes(input)
+    }
+}
+impl<T: OpsWorksCM + ?Sized> OpsWorksCM for Box<T> {
+    fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> RusotoFuture<AssociateNodeResponse, AssociateNodeError> {
+        (**self).associate_node(input)
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        (**self).create_server(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> RusotoFuture<DeleteServerResponse, DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> RusotoFuture<DescribeNodeAssociationStatusResponse, DescribeNodeAssociationStatusError>
+    {
+        (**self).describe_node_association_status(input)
+    }
+    fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> RusotoFuture<DescribeServersResponse, DescribeServersError> {
+        (**self).describe_servers(input)
+    }
+    fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> RusotoFuture<DisassociateNodeResponse, DisassociateNodeError> {
+        (**self).disassociate_node(input)
+    }
+    fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> RusotoFuture<ExportServerEngineAttributeResponse, ExportServerEngineAttributeError> {
+        (**self).export_server_engine_attribute(input)
+    }
+    fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> RusotoFuture<RestoreServerResponse, RestoreServerError> {
+        (**self).restore_server(input)
+    }
+    fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> RusotoFuture<StartMaintenanceResponse, StartMaintenanceError> {
+        (**self).start_maintenance(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError> {
+        (**self).update_server_engine_attributes(input)
+    }
+}
+impl<T: OpsWorksCM + ?Sized> OpsWorksCM for ::std::sync::Arc<T> {
+    fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> RusotoFuture<AssociateNodeResponse, AssociateNodeError> {
+        (**self).associate_node(input)
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        (**self).create_server(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> RusotoFuture<DeleteServerResponse, DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> RusotoFuture<DescribeNodeAssociationStatusResponse, DescribeNodeAssociationStatusError>
+    {
+        (**self).describe_node_association_status(input)
+    }
+    fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> RusotoFuture<DescribeServersResponse, DescribeServersError> {
+        (**self).describe_servers(input)
+    }
+    fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> RusotoFuture<DisassociateNodeResponse, DisassociateNodeError> {
+        (**self).disassociate_node(input)
+    }
+    fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> RusotoFuture<ExportServerEngineAttributeResponse, ExportServerEngineAttributeError> {
+        (**self).export_server_engine_attribute(input)
+    }
+    fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> RusotoFuture<RestoreServerResponse, RestoreServerError> {
+        (**self).restore_server(input)
+    }
+    fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> RusotoFuture<StartMaintenanceResponse, StartMaintenanceError> {
+        (**self).start_maintenance(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError> {
+        (**self).update_server_engine_attributes(input)
+    }
+}
+/// A client for the OpsWorksCM API.
+#[derive(Clone)]
+pub struct OpsWorksCMClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl OpsWorksCMClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OpsWorksCMClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OpsWorksCMClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMClient {
+        OpsWorksCMClient { client, region }
+    }
+}
+
+impl OpsWorksCM for OpsWorksCMClient {
+    /// <p> Associates a new node with the server. For more information about how to disassociate a node, see <a>DisassociateNode</a>.</p> <p> On a Chef server: This command is an alternative to <code>knife bootstrap</code>.</p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>CHEF_ORGANIZATION</i>,Value=default" "Name=<i>CHEF_NODE_PUBLIC_KEY</i>,Value=<i>public-key-pem</i>"</code> </p> <p> On a Puppet server, this command is an alternative to the <code>puppet cert sign</code> command that signs a Puppet node CSR. </p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>PUPPET_NODE_CSR</i>,Value=<i>csr-pem</i>"</code> </p> <p> A node can can only be associated with servers that are in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. The AssociateNode API call can be integrated into Auto Scaling configurations, AWS Cloudformation templates, or the user data of a server's instance. </p>
+    fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> RusotoFuture<AssociateNodeResponse, AssociateNodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("AssociateNode");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.AssociateNode");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateNodeResponse, _>(
+                    response,
+                    "AssociateNode",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateNodeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Creates an application-level backup of a server. While the server is in the <code>BACKING_UP</code> state, the server cannot be changed, and no additional backup can be created. </p> <p> Backups can be created for servers in <code>RUNNING</code>, <code>HEALTHY</code>, and <code>UNHEALTHY</code> states. By default, you can create a maximum of 50 manual backups. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when the maximum number of manual backups is reached. An <code>InvalidStateException</code> is thrown when the server is not in any of the following states: RUNNING, HEALTHY, or UNHEALTHY. A <code>ResourceNotFoundException</code> is thrown when the server is not found. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("CreateBackup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateBackup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateBackupResponse, _>(
+                    response,
+                    "CreateBackup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBackupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Creates and immedately starts a new server. The server is ready to use when it is in the <code>HEALTHY</code> state. By default, you can create a maximum of 10 servers. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when you have created the maximum number of servers (10). A <code>ResourceAlreadyExistsException</code> is thrown when a server with the same name already exists in the account. A <code>ResourceNotFoundException</code> is thrown when you specify a backup ID that is not valid or is for a backup that does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p> <p> If you do not specify a security group by adding the <code>SecurityGroupIds</code> parameter, AWS OpsWorks creates a new security group. </p> <p> <i>Chef Automate:</i> The default security group opens the Chef server to the world on TCP port 443. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p> <i>Puppet Enterprise:</i> The default security group opens TCP ports 22, 443, 4433, 8140, 8142, 8143, and 8170. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p>By default, your server is accessible from any IP address. We recommend that you update your security group rules to allow access from known IP addresses and address ranges only. To edit security group rules, open Security Groups in the navigation pane of the EC2 management console. </p>
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("CreateServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateServer");
+        let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBackupResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateServerResponse, _>(
+                    response,
+                    "CreateServer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateServerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p> Deletes a backup. You can delete both manual and automated backups. This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a backup deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DeleteBackup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteBackup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBackupResponse, _>(
+                    response,
+                    "DeleteBackup",
+                )
             } else {
                 Box::new(
                     response
@@ -1567,7 +2597,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: DeleteServerRequest,
     ) -> RusotoFuture<DeleteServerResponse, DeleteServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DeleteServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteServer");
@@ -1576,10 +2615,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteServerResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteServerResponse, _>(
+                    response,
+                    "DeleteServer",
+                )
             } else {
                 Box::new(
                     response
@@ -1596,6 +2635,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
     ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1606,10 +2646,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAccountAttributesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeAccountAttributesResponse, _>(
+                    response,
+                    "DescribeAccountAttributes",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeAccountAttributesError::from_response(response))
@@ -1623,7 +2663,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: DescribeBackupsRequest,
     ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeBackups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeBackups");
@@ -1632,10 +2681,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBackupsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeBackupsResponse, _>(
+                    response,
+                    "DescribeBackups",
+                )
             } else {
                 Box::new(
                     response
@@ -1652,7 +2701,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: DescribeEventsRequest,
     ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeEvents");
@@ -1661,10 +2719,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEventsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEventsResponse, _>(
+                    response,
+                    "DescribeEvents",
+                )
             } else {
                 Box::new(
                     response
@@ -1682,7 +2740,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeNodeAssociationStatusRequest,
     ) -> RusotoFuture<DescribeNodeAssociationStatusResponse, DescribeNodeAssociationStatusError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeNodeAssociationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1694,10 +2761,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeNodeAssociationStatusResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeNodeAssociationStatusResponse, _>(
+                    response,
+                    "DescribeNodeAssociationStatus",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeNodeAssociationStatusError::from_response(response))
@@ -1711,7 +2778,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: DescribeServersRequest,
     ) -> RusotoFuture<DescribeServersResponse, DescribeServersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DescribeServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeServers");
@@ -1720,10 +2796,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServersResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeServersResponse, _>(
+                    response,
+                    "DescribeServers",
+                )
             } else {
                 Box::new(
                     response
@@ -1740,7 +2816,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: DisassociateNodeRequest,
     ) -> RusotoFuture<DisassociateNodeResponse, DisassociateNodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("DisassociateNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DisassociateNode");
@@ -1749,10 +2834,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateNodeResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateNodeResponse, _>(
+                    response,
+                    "DisassociateNode",
+                )
             } else {
                 Box::new(
                     response
@@ -1769,7 +2854,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: ExportServerEngineAttributeRequest,
     ) -> RusotoFuture<ExportServerEngineAttributeResponse, ExportServerEngineAttributeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("ExportServerEngineAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1781,10 +2875,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExportServerEngineAttributeResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ExportServerEngineAttributeResponse, _>(
+                    response,
+                    "ExportServerEngineAttribute",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ExportServerEngineAttributeError::from_response(response))
@@ -1798,7 +2892,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: RestoreServerRequest,
     ) -> RusotoFuture<RestoreServerResponse, RestoreServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("RestoreServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.RestoreServer");
@@ -1807,10 +2910,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreServerResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RestoreServerResponse, _>(
+                    response,
+                    "RestoreServer",
+                )
             } else {
                 Box::new(
                     response
@@ -1827,7 +2930,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: StartMaintenanceRequest,
     ) -> RusotoFuture<StartMaintenanceResponse, StartMaintenanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("StartMaintenance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.StartMaintenance");
@@ -1836,10 +2948,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartMaintenanceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartMaintenanceResponse, _>(
+                    response,
+                    "StartMaintenance",
+                )
             } else {
                 Box::new(
                     response
@@ -1856,7 +2968,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: UpdateServerRequest,
     ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UpdateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UpdateServer");
@@ -1865,10 +2986,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServerResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateServerResponse, _>(
+                    response,
+                    "UpdateServer",
+                )
             } else {
                 Box::new(
                     response
@@ -1885,7 +3006,16 @@ impl OpsWorksCM for OpsWorksCMClient {
         &self,
         input: UpdateServerEngineAttributesRequest,
     ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "opsworks-cm", &self.region, "/");
+        request.set_operation("UpdateServerEngineAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1897,10 +3027,10 @@ impl OpsWorksCM for OpsWorksCMClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServerEngineAttributesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateServerEngineAttributesResponse, _>(
+                    response,
+                    "UpdateServerEngineAttributes",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateServerEngineAttributesError::from_response(response))
@@ -1909,3 +3039,564 @@ impl OpsWorksCM for OpsWorksCMClient {
         })
     }
 }
+
+/// A mock of the OpsWorksCM API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockOpsWorksCM {
+    associate_node: Option<
+        Box<
+            dyn Fn(
+                    AssociateNodeRequest,
+                )
+                    -> ::std::result::Result<AssociateNodeResponse, RusotoError<AssociateNodeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_backup: Option<
+        Box<
+            dyn Fn(
+                    CreateBackupRequest,
+                )
+                    -> ::std::result::Result<CreateBackupResponse, RusotoError<CreateBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_server: Option<
+        Box<
+            dyn Fn(
+                    CreateServerRequest,
+                )
+                    -> ::std::result::Result<CreateServerResponse, RusotoError<CreateServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_backup: Option<
+        Box<
+            dyn Fn(
+                    DeleteBackupRequest,
+                )
+                    -> ::std::result::Result<DeleteBackupResponse, RusotoError<DeleteBackupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_server: Option<
+        Box<
+            dyn Fn(
+                    DeleteServerRequest,
+                )
+                    -> ::std::result::Result<DeleteServerResponse, RusotoError<DeleteServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_account_attributes: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAccountAttributesResponse,
+                    RusotoError<DescribeAccountAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_backups: Option<
+        Box<
+            dyn Fn(
+                    DescribeBackupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeBackupsResponse,
+                    RusotoError<DescribeBackupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEventsResponse,
+                    RusotoError<DescribeEventsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_node_association_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeNodeAssociationStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeNodeAssociationStatusResponse,
+                    RusotoError<DescribeNodeAssociationStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_servers: Option<
+        Box<
+            dyn Fn(
+                    DescribeServersRequest,
+                ) -> ::std::result::Result<
+                    DescribeServersResponse,
+                    RusotoError<DescribeServersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_node: Option<
+        Box<
+            dyn Fn(
+                    DisassociateNodeRequest,
+                ) -> ::std::result::Result<
+                    DisassociateNodeResponse,
+                    RusotoError<DisassociateNodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    export_server_engine_attribute: Option<
+        Box<
+            dyn Fn(
+                    ExportServerEngineAttributeRequest,
+                ) -> ::std::result::Result<
+                    ExportServerEngineAttributeResponse,
+                    RusotoError<ExportServerEngineAttributeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_server: Option<
+        Box<
+            dyn Fn(
+                    RestoreServerRequest,
+                )
+                    -> ::std::result::Result<RestoreServerResponse, RusotoError<RestoreServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_maintenance: Option<
+        Box<
+            dyn Fn(
+                    StartMaintenanceRequest,
+                ) -> ::std::result::Result<
+                    StartMaintenanceResponse,
+                    RusotoError<StartMaintenanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_server: Option<
+        Box<
+            dyn Fn(
+                    UpdateServerRequest,
+                )
+                    -> ::std::result::Result<UpdateServerResponse, RusotoError<UpdateServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_server_engine_attributes: Option<
+        Box<
+            dyn Fn(
+                    UpdateServerEngineAttributesRequest,
+                ) -> ::std::result::Result<
+                    UpdateServerEngineAttributesResponse,
+                    RusotoError<UpdateServerEngineAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockOpsWorksCM {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockOpsWorksCM {
+        Default::default()
+    }
+
+    /// Answers `associate_node` with what `handler` returns for its input.
+    pub fn with_associate_node<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                AssociateNodeRequest,
+            )
+                -> ::std::result::Result<AssociateNodeResponse, RusotoError<AssociateNodeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_backup` with what `handler` returns for its input.
+    pub fn with_create_backup<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                CreateBackupRequest,
+            )
+                -> ::std::result::Result<CreateBackupResponse, RusotoError<CreateBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_server` with what `handler` returns for its input.
+    pub fn with_create_server<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                CreateServerRequest,
+            )
+                -> ::std::result::Result<CreateServerResponse, RusotoError<CreateServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_backup` with what `handler` returns for its input.
+    pub fn with_delete_backup<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DeleteBackupRequest,
+            )
+                -> ::std::result::Result<DeleteBackupResponse, RusotoError<DeleteBackupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_backup = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_server` with what `handler` returns for its input.
+    pub fn with_delete_server<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DeleteServerRequest,
+            )
+                -> ::std::result::Result<DeleteServerResponse, RusotoError<DeleteServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_attributes` with what `handler` returns.
+    pub fn with_describe_account_attributes<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeAccountAttributesResponse,
+                RusotoError<DescribeAccountAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_backups` with what `handler` returns for its input.
+    pub fn with_describe_backups<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DescribeBackupsRequest,
+            )
+                -> ::std::result::Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_backups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DescribeEventsRequest,
+            )
+                -> ::std::result::Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_node_association_status` with what `handler` returns for its input.
+    pub fn with_describe_node_association_status<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DescribeNodeAssociationStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeNodeAssociationStatusResponse,
+                RusotoError<DescribeNodeAssociationStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_node_association_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_servers` with what `handler` returns for its input.
+    pub fn with_describe_servers<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DescribeServersRequest,
+            )
+                -> ::std::result::Result<DescribeServersResponse, RusotoError<DescribeServersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_servers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_node` with what `handler` returns for its input.
+    pub fn with_disassociate_node<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                DisassociateNodeRequest,
+            ) -> ::std::result::Result<
+                DisassociateNodeResponse,
+                RusotoError<DisassociateNodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_node = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_server_engine_attribute` with what `handler` returns for its input.
+    pub fn with_export_server_engine_attribute<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                ExportServerEngineAttributeRequest,
+            ) -> ::std::result::Result<
+                ExportServerEngineAttributeResponse,
+                RusotoError<ExportServerEngineAttributeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.export_server_engine_attribute = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_server` with what `handler` returns for its input.
+    pub fn with_restore_server<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                RestoreServerRequest,
+            )
+                -> ::std::result::Result<RestoreServerResponse, RusotoError<RestoreServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_maintenance` with what `handler` returns for its input.
+    pub fn with_start_maintenance<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                StartMaintenanceRequest,
+            ) -> ::std::result::Result<
+                StartMaintenanceResponse,
+                RusotoError<StartMaintenanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_maintenance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_server` with what `handler` returns for its input.
+    pub fn with_update_server<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                UpdateServerRequest,
+            )
+                -> ::std::result::Result<UpdateServerResponse, RusotoError<UpdateServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_server_engine_attributes` with what `handler` returns for its input.
+    pub fn with_update_server_engine_attributes<F>(mut self, handler: F) -> MockOpsWorksCM
+    where
+        F: Fn(
+                UpdateServerEngineAttributesRequest,
+            ) -> ::std::result::Result<
+                UpdateServerEngineAttributesResponse,
+                RusotoError<UpdateServerEngineAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_server_engine_attributes = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl OpsWorksCM for MockOpsWorksCM {
+    fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> RusotoFuture<AssociateNodeResponse, AssociateNodeError> {
+        match self.associate_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for associate_node"),
+        }
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        match self.create_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for create_backup"),
+        }
+    }
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        match self.create_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for create_server"),
+        }
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        match self.delete_backup {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for delete_backup"),
+        }
+    }
+    fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> RusotoFuture<DeleteServerResponse, DeleteServerError> {
+        match self.delete_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for delete_server"),
+        }
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        match self.describe_account_attributes {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOpsWorksCM has no handler for describe_account_attributes"),
+        }
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        match self.describe_backups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for describe_backups"),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for describe_events"),
+        }
+    }
+    fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> RusotoFuture<DescribeNodeAssociationStatusResponse, DescribeNodeAssociationStatusError>
+    {
+        match self.describe_node_association_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for describe_node_association_status"),
+        }
+    }
+    fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> RusotoFuture<DescribeServersResponse, DescribeServersError> {
+        match self.describe_servers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for describe_servers"),
+        }
+    }
+    fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> RusotoFuture<DisassociateNodeResponse, DisassociateNodeError> {
+        match self.disassociate_node {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for disassociate_node"),
+        }
+    }
+    fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> RusotoFuture<ExportServerEngineAttributeResponse, ExportServerEngineAttributeError> {
+        match self.export_server_engine_attribute {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for export_server_engine_attribute"),
+        }
+    }
+    fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> RusotoFuture<RestoreServerResponse, RestoreServerError> {
+        match self.restore_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for restore_server"),
+        }
+    }
+    fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> RusotoFuture<StartMaintenanceResponse, StartMaintenanceError> {
+        match self.start_maintenance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for start_maintenance"),
+        }
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        match self.update_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for update_server"),
+        }
+    }
+    fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError> {
+        match self.update_server_engine_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOpsWorksCM has no handler for update_server_engine_attributes"),
+        }
+    }
+}
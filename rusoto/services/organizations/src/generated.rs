@@ -22,16 +22,34 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptHandshakeRequest {
     /// <p>The unique identifier (ID) of the handshake that you want to accept.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
     #[serde(rename = "HandshakeId")]
     pub handshake_id: String,
 }
 
+impl Validate for AcceptHandshakeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "handshake_id"),
+            self.handshake_id.chars().count(),
+            None,
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "handshake_id"),
+            &self.handshake_id,
+            "^h-[0-9a-z]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptHandshakeResponse {
     /// <p>A structure that contains details about the accepted handshake.</p>
     #[serde(rename = "Handshake")]
@@ -41,7 +59,7 @@ pub struct AcceptHandshakeResponse {
 
 /// <p>Contains information about an AWS account that is a member of an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Account {
     /// <p>The Amazon Resource Name (ARN) of the account.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
@@ -74,6 +92,7 @@ pub struct Account {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachPolicyRequest {
     /// <p>The unique identifier (ID) of the policy that you want to attach to the target. You can get the ID for the policy by calling the <a>ListPolicies</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a policy ID string requires "p-" followed by from 8 to 128 lower-case letters or digits.</p>
     #[serde(rename = "PolicyId")]
@@ -83,15 +102,59 @@ pub struct AttachPolicyRequest {
     pub target_id: String,
 }
 
+impl Validate for AttachPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^p-[0-9a-zA-Z_]{8,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "target_id"),
+            self.target_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_id"),
+            &self.target_id,
+            "^(r-[0-9a-z]{4,32})|(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelHandshakeRequest {
     /// <p>The unique identifier (ID) of the handshake that you want to cancel. You can get the ID from the <a>ListHandshakesForOrganization</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
     #[serde(rename = "HandshakeId")]
     pub handshake_id: String,
 }
 
+impl Validate for CancelHandshakeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "handshake_id"),
+            self.handshake_id.chars().count(),
+            None,
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "handshake_id"),
+            &self.handshake_id,
+            "^h-[0-9a-z]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelHandshakeResponse {
     /// <p>A structure that contains details about the handshake that you canceled.</p>
     #[serde(rename = "Handshake")]
@@ -101,7 +164,7 @@ pub struct CancelHandshakeResponse {
 
 /// <p>Contains a list of child entities, either OUs or accounts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Child {
     /// <p><p>The unique identifier (ID) of this child entity.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a child ID string requires one of the following:</p> <ul> <li> <p>Account: a string that consists of exactly 12 digits.</p> </li> <li> <p>Organizational unit (OU): a string that begins with &quot;ou-&quot; followed by from 4 to 32 lower-case letters or digits (the ID of the root that contains the OU) followed by a second &quot;-&quot; dash and from 8 to 32 additional lower-case letters or digits.</p> </li> </ul></p>
     #[serde(rename = "Id")]
@@ -114,6 +177,7 @@ pub struct Child {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAccountRequest {
     /// <p>The friendly name of the member account.</p>
     #[serde(rename = "AccountName")]
@@ -131,8 +195,44 @@ pub struct CreateAccountRequest {
     pub role_name: Option<String>,
 }
 
+impl Validate for CreateAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_name"),
+            self.account_name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "account_name"),
+            &self.account_name,
+            "[\\u0020-\\u007E]+",
+        );
+        errors.check_length(
+            &field_path(path, "email"),
+            self.email.chars().count(),
+            Some(6),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "email"),
+            &self.email,
+            "[^\\s@]+@[^\\s@]+\\.[^\\s@]+",
+        );
+        if let Some(ref value) = self.role_name {
+            errors.check_length(
+                &field_path(path, "role_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "role_name"), &value, "[\\w+=,.@-]{1,64}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAccountResponse {
     /// <p>A structure that contains details about the request to create an account. This response structure might not be fully populated when you first receive it because account creation is an asynchronous process. You can pass the returned <code>CreateAccountStatus</code> ID as a parameter to <a>DescribeCreateAccountStatus</a> to get status about the progress of the request at later times. You can also check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "CreateAccountStatus")]
@@ -142,7 +242,7 @@ pub struct CreateAccountResponse {
 
 /// <p>Contains the status about a <a>CreateAccount</a> or <a>CreateGovCloudAccount</a> request to create an AWS account or an AWS GovCloud (US) account in an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAccountStatus {
     /// <p>If the account was created successfully, the unique identifier (ID) of the new account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
     #[serde(rename = "AccountId")]
@@ -179,6 +279,7 @@ pub struct CreateAccountStatus {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGovCloudAccountRequest {
     /// <p>The friendly name of the member account.</p>
     #[serde(rename = "AccountName")]
@@ -196,8 +297,44 @@ pub struct CreateGovCloudAccountRequest {
     pub role_name: Option<String>,
 }
 
+impl Validate for CreateGovCloudAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_name"),
+            self.account_name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(
+            &field_path(path, "account_name"),
+            &self.account_name,
+            "[\\u0020-\\u007E]+",
+        );
+        errors.check_length(
+            &field_path(path, "email"),
+            self.email.chars().count(),
+            Some(6),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "email"),
+            &self.email,
+            "[^\\s@]+@[^\\s@]+\\.[^\\s@]+",
+        );
+        if let Some(ref value) = self.role_name {
+            errors.check_length(
+                &field_path(path, "role_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "role_name"), &value, "[\\w+=,.@-]{1,64}");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGovCloudAccountResponse {
     #[serde(rename = "CreateAccountStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -205,6 +342,7 @@ pub struct CreateGovCloudAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateOrganizationRequest {
     /// <p><p>Specifies the feature set supported by the new organization. Each feature set supports different levels of functionality.</p> <ul> <li> <p> <code>CONSOLIDATED<em>BILLING</code>: All member accounts have their bills consolidated to and paid by the master account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started<em>concepts.html#feature-set-cb-only&quot;&gt;Consolidated billing</a> in the <i>AWS Organizations User Guide.</i> </p> <p> The consolidated billing feature subset isn&#39;t available for organizations in the AWS GovCloud (US) Region.</p> </li> <li> <p> <code>ALL</code>: In addition to all the features supported by the consolidated billing feature set, the master account can also apply any policy type to any member account in the organization. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started_concepts.html#feature-set-all&quot;&gt;All features</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul></p>
     #[serde(rename = "FeatureSet")]
@@ -213,7 +351,7 @@ pub struct CreateOrganizationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateOrganizationResponse {
     /// <p>A structure that contains details about the newly created organization.</p>
     #[serde(rename = "Organization")]
@@ -222,6 +360,7 @@ pub struct CreateOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateOrganizationalUnitRequest {
     /// <p>The friendly name to assign to the new OU.</p>
     #[serde(rename = "Name")]
@@ -231,8 +370,31 @@ pub struct CreateOrganizationalUnitRequest {
     pub parent_id: String,
 }
 
+impl Validate for CreateOrganizationalUnitRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\s\\S]*");
+        errors.check_length(
+            &field_path(path, "parent_id"),
+            self.parent_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "parent_id"),
+            &self.parent_id,
+            "^(r-[0-9a-z]{4,32})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateOrganizationalUnitResponse {
     /// <p>A structure that contains details about the newly created OU.</p>
     #[serde(rename = "OrganizationalUnit")]
@@ -241,6 +403,7 @@ pub struct CreateOrganizationalUnitResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePolicyRequest {
     /// <p>The policy content to add to the new policy. For example, if you create a <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_scp.html">service control policy</a> (SCP), this string must be JSON text that specifies the permissions that admins in attached accounts can delegate to their users, groups, and roles. For more information about the SCP syntax, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_scp-syntax.html">Service Control Policy Syntax</a> in the <i>AWS Organizations User Guide.</i> </p>
     #[serde(rename = "Content")]
@@ -256,8 +419,38 @@ pub struct CreatePolicyRequest {
     pub type_: String,
 }
 
+impl Validate for CreatePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "content"),
+            self.content.chars().count(),
+            Some(1),
+            Some(1000000),
+        );
+        errors.check_pattern(&field_path(path, "content"), &self.content, "[\\s\\S]*");
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "description"),
+            &self.description,
+            "[\\s\\S]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\s\\S]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePolicyResponse {
     /// <p>A structure that contains details about the newly created policy.</p>
     #[serde(rename = "Policy")]
@@ -266,14 +459,31 @@ pub struct CreatePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeclineHandshakeRequest {
     /// <p>The unique identifier (ID) of the handshake that you want to decline. You can get the ID from the <a>ListHandshakesForAccount</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
     #[serde(rename = "HandshakeId")]
     pub handshake_id: String,
 }
 
+impl Validate for DeclineHandshakeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "handshake_id"),
+            self.handshake_id.chars().count(),
+            None,
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "handshake_id"),
+            &self.handshake_id,
+            "^h-[0-9a-z]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeclineHandshakeResponse {
     /// <p>A structure that contains details about the declined handshake. The state is updated to show the value <code>DECLINED</code>.</p>
     #[serde(rename = "Handshake")]
@@ -282,28 +492,79 @@ pub struct DeclineHandshakeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteOrganizationalUnitRequest {
     /// <p>The unique identifier (ID) of the organizational unit that you want to delete. You can get the ID from the <a>ListOrganizationalUnitsForParent</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an organizational unit ID string requires "ou-" followed by from 4 to 32 lower-case letters or digits (the ID of the root that contains the OU) followed by a second "-" dash and from 8 to 32 additional lower-case letters or digits.</p>
     #[serde(rename = "OrganizationalUnitId")]
     pub organizational_unit_id: String,
 }
 
+impl Validate for DeleteOrganizationalUnitRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organizational_unit_id"),
+            self.organizational_unit_id.chars().count(),
+            None,
+            Some(68),
+        );
+        errors.check_pattern(
+            &field_path(path, "organizational_unit_id"),
+            &self.organizational_unit_id,
+            "^ou-[0-9a-z]{4,32}-[a-z0-9]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePolicyRequest {
     /// <p>The unique identifier (ID) of the policy that you want to delete. You can get the ID from the <a>ListPolicies</a> or <a>ListPoliciesForTarget</a> operations.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a policy ID string requires "p-" followed by from 8 to 128 lower-case letters or digits.</p>
     #[serde(rename = "PolicyId")]
     pub policy_id: String,
 }
 
+impl Validate for DeletePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^p-[0-9a-zA-Z_]{8,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountRequest {
     /// <p>The unique identifier (ID) of the AWS account that you want information about. You can get the ID from the <a>ListAccounts</a> or <a>ListAccountsForParent</a> operations.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
 }
 
+impl Validate for DescribeAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            None,
+            Some(12),
+        );
+        errors.check_pattern(
+            &field_path(path, "account_id"),
+            &self.account_id,
+            "^\\d{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAccountResponse {
     /// <p>A structure that contains information about the requested account.</p>
     #[serde(rename = "Account")]
@@ -312,14 +573,31 @@ pub struct DescribeAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCreateAccountStatusRequest {
     /// <p>Specifies the <code>operationId</code> that uniquely identifies the request. You can get the ID from the response to an earlier <a>CreateAccount</a> request, or from the <a>ListCreateAccountStatus</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a create account request ID string requires "car-" followed by from 8 to 32 lower-case letters or digits.</p>
     #[serde(rename = "CreateAccountRequestId")]
     pub create_account_request_id: String,
 }
 
+impl Validate for DescribeCreateAccountStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "create_account_request_id"),
+            self.create_account_request_id.chars().count(),
+            None,
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "create_account_request_id"),
+            &self.create_account_request_id,
+            "^car-[a-z0-9]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCreateAccountStatusResponse {
     /// <p>A structure that contains the current status of an account creation request.</p>
     #[serde(rename = "CreateAccountStatus")]
@@ -328,14 +606,31 @@ pub struct DescribeCreateAccountStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHandshakeRequest {
     /// <p>The unique identifier (ID) of the handshake that you want information about. You can get the ID from the original call to <a>InviteAccountToOrganization</a>, or from a call to <a>ListHandshakesForAccount</a> or <a>ListHandshakesForOrganization</a>.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
     #[serde(rename = "HandshakeId")]
     pub handshake_id: String,
 }
 
+impl Validate for DescribeHandshakeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "handshake_id"),
+            self.handshake_id.chars().count(),
+            None,
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "handshake_id"),
+            &self.handshake_id,
+            "^h-[0-9a-z]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeHandshakeResponse {
     /// <p>A structure that contains information about the specified handshake.</p>
     #[serde(rename = "Handshake")]
@@ -344,7 +639,7 @@ pub struct DescribeHandshakeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOrganizationResponse {
     /// <p>A structure that contains information about the organization.</p>
     #[serde(rename = "Organization")]
@@ -353,14 +648,31 @@ pub struct DescribeOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrganizationalUnitRequest {
     /// <p>The unique identifier (ID) of the organizational unit that you want details about. You can get the ID from the <a>ListOrganizationalUnitsForParent</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an organizational unit ID string requires "ou-" followed by from 4 to 32 lower-case letters or digits (the ID of the root that contains the OU) followed by a second "-" dash and from 8 to 32 additional lower-case letters or digits.</p>
     #[serde(rename = "OrganizationalUnitId")]
     pub organizational_unit_id: String,
 }
 
+impl Validate for DescribeOrganizationalUnitRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organizational_unit_id"),
+            self.organizational_unit_id.chars().count(),
+            None,
+            Some(68),
+        );
+        errors.check_pattern(
+            &field_path(path, "organizational_unit_id"),
+            &self.organizational_unit_id,
+            "^ou-[0-9a-z]{4,32}-[a-z0-9]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOrganizationalUnitResponse {
     /// <p>A structure that contains details about the specified OU.</p>
     #[serde(rename = "OrganizationalUnit")]
@@ -369,14 +681,31 @@ pub struct DescribeOrganizationalUnitResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePolicyRequest {
     /// <p>The unique identifier (ID) of the policy that you want details about. You can get the ID from the <a>ListPolicies</a> or <a>ListPoliciesForTarget</a> operations.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a policy ID string requires "p-" followed by from 8 to 128 lower-case letters or digits.</p>
     #[serde(rename = "PolicyId")]
     pub policy_id: String,
 }
 
+impl Validate for DescribePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^p-[0-9a-zA-Z_]{8,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePolicyResponse {
     /// <p>A structure that contains details about the specified policy.</p>
     #[serde(rename = "Policy")]
@@ -385,6 +714,7 @@ pub struct DescribePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachPolicyRequest {
     /// <p>The unique identifier (ID) of the policy you want to detach. You can get the ID from the <a>ListPolicies</a> or <a>ListPoliciesForTarget</a> operations.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a policy ID string requires "p-" followed by from 8 to 128 lower-case letters or digits.</p>
     #[serde(rename = "PolicyId")]
@@ -394,14 +724,59 @@ pub struct DetachPolicyRequest {
     pub target_id: String,
 }
 
+impl Validate for DetachPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^p-[0-9a-zA-Z_]{8,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "target_id"),
+            self.target_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_id"),
+            &self.target_id,
+            "^(r-[0-9a-z]{4,32})|(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableAWSServiceAccessRequest {
     /// <p>The service principal name of the AWS service for which you want to disable integration with your organization. This is typically in the form of a URL, such as <code> <i>service-abbreviation</i>.amazonaws.com</code>.</p>
     #[serde(rename = "ServicePrincipal")]
     pub service_principal: String,
 }
 
+impl Validate for DisableAWSServiceAccessRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "service_principal"),
+            self.service_principal.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_principal"),
+            &self.service_principal,
+            "[\\w+=,.@-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisablePolicyTypeRequest {
     /// <p>The policy type that you want to disable in this root.</p>
     #[serde(rename = "PolicyType")]
@@ -411,8 +786,24 @@ pub struct DisablePolicyTypeRequest {
     pub root_id: String,
 }
 
+impl Validate for DisablePolicyTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "root_id"),
+            self.root_id.chars().count(),
+            None,
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "root_id"),
+            &self.root_id,
+            "^r-[0-9a-z]{4,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisablePolicyTypeResponse {
     /// <p>A structure that shows the root with the updated list of enabled policy types.</p>
     #[serde(rename = "Root")]
@@ -421,17 +812,35 @@ pub struct DisablePolicyTypeResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableAWSServiceAccessRequest {
     /// <p>The service principal name of the AWS service for which you want to enable integration with your organization. This is typically in the form of a URL, such as <code> <i>service-abbreviation</i>.amazonaws.com</code>.</p>
     #[serde(rename = "ServicePrincipal")]
     pub service_principal: String,
 }
 
+impl Validate for EnableAWSServiceAccessRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "service_principal"),
+            self.service_principal.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_principal"),
+            &self.service_principal,
+            "[\\w+=,.@-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableAllFeaturesRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableAllFeaturesResponse {
     /// <p>A structure that contains details about the handshake created to support this request to enable all features in the organization.</p>
     #[serde(rename = "Handshake")]
@@ -440,6 +849,7 @@ pub struct EnableAllFeaturesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnablePolicyTypeRequest {
     /// <p>The policy type that you want to enable.</p>
     #[serde(rename = "PolicyType")]
@@ -449,8 +859,24 @@ pub struct EnablePolicyTypeRequest {
     pub root_id: String,
 }
 
+impl Validate for EnablePolicyTypeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "root_id"),
+            self.root_id.chars().count(),
+            None,
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "root_id"),
+            &self.root_id,
+            "^r-[0-9a-z]{4,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnablePolicyTypeResponse {
     /// <p>A structure that shows the root with the updated list of enabled policy types.</p>
     #[serde(rename = "Root")]
@@ -460,7 +886,7 @@ pub struct EnablePolicyTypeResponse {
 
 /// <p>A structure that contains details of a service principal that is enabled to integrate with AWS Organizations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnabledServicePrincipal {
     /// <p>The date that the service principal was enabled for integration with AWS Organizations.</p>
     #[serde(rename = "DateEnabled")]
@@ -474,7 +900,7 @@ pub struct EnabledServicePrincipal {
 
 /// <p>Contains information that must be exchanged to securely establish a relationship between two accounts (an <i>originator</i> and a <i>recipient</i>). For example, when a master account (the originator) invites another account (the recipient) to join its organization, the two accounts exchange information as a series of handshake requests and responses.</p> <p> <b>Note:</b> Handshakes that are CANCELED, ACCEPTED, or DECLINED show up in lists for only 30 days after entering that state After that they are deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Handshake {
     /// <p><p>The type of handshake, indicating what action occurs when the recipient accepts the handshake. The following handshake types are supported:</p> <ul> <li> <p> <b>INVITE</b>: This type of handshake represents a request to join an organization. It is always sent from the master account to only non-member accounts.</p> </li> <li> <p> <b>ENABLE<em>ALL</em>FEATURES</b>: This type of handshake represents a request to enable all features in an organization. It is always sent from the master account to only <i>invited</i> member accounts. Created accounts do not receive this because those accounts were created by the organization&#39;s master account and approval is inferred.</p> </li> <li> <p> <b>APPROVE<em>ALL</em>FEATURES</b>: This type of handshake is sent from the Organizations service when all member accounts have approved the <code>ENABLE<em>ALL</em>FEATURES</code> invitation. It is sent only to the master account and signals the master that it can finalize the process to enable all features.</p> </li> </ul></p>
     #[serde(rename = "Action")]
@@ -512,6 +938,7 @@ pub struct Handshake {
 
 /// <p>Specifies the criteria that are used to select the handshakes for the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HandshakeFilter {
     /// <p>Specifies the type of handshake action.</p> <p>If you specify <code>ActionType</code>, you cannot also specify <code>ParentHandshakeId</code>.</p>
     #[serde(rename = "ActionType")]
@@ -523,6 +950,24 @@ pub struct HandshakeFilter {
     pub parent_handshake_id: Option<String>,
 }
 
+impl Validate for HandshakeFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parent_handshake_id {
+            errors.check_length(
+                &field_path(path, "parent_handshake_id"),
+                value.chars().count(),
+                None,
+                Some(34),
+            );
+            errors.check_pattern(
+                &field_path(path, "parent_handshake_id"),
+                &value,
+                "^h-[0-9a-z]{8,32}$",
+            );
+        }
+    }
+}
+
 /// <p>Identifies a participant in a handshake.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HandshakeParty {
@@ -534,9 +979,21 @@ pub struct HandshakeParty {
     pub type_: String,
 }
 
+impl Validate for HandshakeParty {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "[\\s\\S]*");
+    }
+}
+
 /// <p>Contains additional data that is needed to process a handshake.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HandshakeResource {
     /// <p>When needed, contains an additional array of <code>HandshakeResource</code> objects.</p>
     #[serde(rename = "Resources")]
@@ -553,6 +1010,7 @@ pub struct HandshakeResource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InviteAccountToOrganizationRequest {
     /// <p>Additional information that you want to include in the generated email to the recipient account owner.</p>
     #[serde(rename = "Notes")]
@@ -563,8 +1021,23 @@ pub struct InviteAccountToOrganizationRequest {
     pub target: HandshakeParty,
 }
 
+impl Validate for InviteAccountToOrganizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.notes {
+            errors.check_length(
+                &field_path(path, "notes"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "notes"), &value, "[\\s\\S]*");
+        }
+        self.target.validate_at(&field_path(path, "target"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InviteAccountToOrganizationResponse {
     /// <p>A structure that contains details about the handshake that is created to support this invitation request.</p>
     #[serde(rename = "Handshake")]
@@ -573,6 +1046,7 @@ pub struct InviteAccountToOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAWSServiceAccessForOrganizationRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -584,8 +1058,25 @@ pub struct ListAWSServiceAccessForOrganizationRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAWSServiceAccessForOrganizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAWSServiceAccessForOrganizationResponse {
     /// <p>A list of the service principals for the services that are enabled to integrate with your organization. Each principal is a structure that includes the name and the date that it was enabled for integration with AWS Organizations.</p>
     #[serde(rename = "EnabledServicePrincipals")]
@@ -598,6 +1089,7 @@ pub struct ListAWSServiceAccessForOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAccountsForParentRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -612,8 +1104,36 @@ pub struct ListAccountsForParentRequest {
     pub parent_id: String,
 }
 
+impl Validate for ListAccountsForParentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "parent_id"),
+            self.parent_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "parent_id"),
+            &self.parent_id,
+            "^(r-[0-9a-z]{4,32})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAccountsForParentResponse {
     /// <p>A list of the accounts in the specified root or OU.</p>
     #[serde(rename = "Accounts")]
@@ -626,6 +1146,7 @@ pub struct ListAccountsForParentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAccountsRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -637,8 +1158,25 @@ pub struct ListAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAccountsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAccountsResponse {
     /// <p>A list of objects in the organization.</p>
     #[serde(rename = "Accounts")]
@@ -651,6 +1189,7 @@ pub struct ListAccountsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListChildrenRequest {
     /// <p>Filters the output to include only the specified child type.</p>
     #[serde(rename = "ChildType")]
@@ -668,8 +1207,36 @@ pub struct ListChildrenRequest {
     pub parent_id: String,
 }
 
+impl Validate for ListChildrenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "parent_id"),
+            self.parent_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "parent_id"),
+            &self.parent_id,
+            "^(r-[0-9a-z]{4,32})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListChildrenResponse {
     /// <p>The list of children of the specified parent container.</p>
     #[serde(rename = "Children")]
@@ -682,6 +1249,7 @@ pub struct ListChildrenResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCreateAccountStatusRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -697,8 +1265,25 @@ pub struct ListCreateAccountStatusRequest {
     pub states: Option<Vec<String>>,
 }
 
+impl Validate for ListCreateAccountStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCreateAccountStatusResponse {
     /// <p>A list of objects with details about the requests. Certain elements, such as the accountId number, are present in the output only after the account has been successfully created.</p>
     #[serde(rename = "CreateAccountStatuses")]
@@ -711,6 +1296,7 @@ pub struct ListCreateAccountStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHandshakesForAccountRequest {
     /// <p>Filters the handshakes that you want included in the response. The default is all types. Use the <code>ActionType</code> element to limit the output to only a specified type, such as <code>INVITE</code>, <code>ENABLE_ALL_FEATURES</code>, or <code>APPROVE_ALL_FEATURES</code>. Alternatively, for the <code>ENABLE_ALL_FEATURES</code> handshake that generates a separate child handshake for each member account, you can specify <code>ParentHandshakeId</code> to see only the handshakes that were generated by that parent request.</p>
     #[serde(rename = "Filter")]
@@ -726,8 +1312,28 @@ pub struct ListHandshakesForAccountRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListHandshakesForAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHandshakesForAccountResponse {
     /// <p>A list of <a>Handshake</a> objects with details about each of the handshakes that is associated with the specified account.</p>
     #[serde(rename = "Handshakes")]
@@ -740,6 +1346,7 @@ pub struct ListHandshakesForAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHandshakesForOrganizationRequest {
     /// <p>A filter of the handshakes that you want included in the response. The default is all types. Use the <code>ActionType</code> element to limit the output to only a specified type, such as <code>INVITE</code>, <code>ENABLE-ALL-FEATURES</code>, or <code>APPROVE-ALL-FEATURES</code>. Alternatively, for the <code>ENABLE-ALL-FEATURES</code> handshake that generates a separate child handshake for each member account, you can specify the <code>ParentHandshakeId</code> to see only the handshakes that were generated by that parent request.</p>
     #[serde(rename = "Filter")]
@@ -755,8 +1362,28 @@ pub struct ListHandshakesForOrganizationRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListHandshakesForOrganizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHandshakesForOrganizationResponse {
     /// <p>A list of <a>Handshake</a> objects with details about each of the handshakes that are associated with an organization.</p>
     #[serde(rename = "Handshakes")]
@@ -769,6 +1396,7 @@ pub struct ListHandshakesForOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOrganizationalUnitsForParentRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -783,8 +1411,36 @@ pub struct ListOrganizationalUnitsForParentRequest {
     pub parent_id: String,
 }
 
+impl Validate for ListOrganizationalUnitsForParentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "parent_id"),
+            self.parent_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "parent_id"),
+            &self.parent_id,
+            "^(r-[0-9a-z]{4,32})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOrganizationalUnitsForParentResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -797,6 +1453,7 @@ pub struct ListOrganizationalUnitsForParentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListParentsRequest {
     /// <p><p>The unique identifier (ID) of the OU or account whose parent containers you want to list. Don&#39;t specify a root.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a child ID string requires one of the following:</p> <ul> <li> <p> <b>Account</b> - A string that consists of exactly 12 digits.</p> </li> <li> <p> <b>Organizational unit (OU)</b> - A string that begins with &quot;ou-&quot; followed by from 4 to 32 lower-case letters or digits (the ID of the root that contains the OU) followed by a second &quot;-&quot; dash and from 8 to 32 additional lower-case letters or digits.</p> </li> </ul></p>
     #[serde(rename = "ChildId")]
@@ -811,8 +1468,36 @@ pub struct ListParentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListParentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "child_id"),
+            self.child_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "child_id"),
+            &self.child_id,
+            "^(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListParentsResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -825,6 +1510,7 @@ pub struct ListParentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPoliciesForTargetRequest {
     /// <p>The type of policy that you want to include in the returned list.</p>
     #[serde(rename = "Filter")]
@@ -842,8 +1528,36 @@ pub struct ListPoliciesForTargetRequest {
     pub target_id: String,
 }
 
+impl Validate for ListPoliciesForTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "target_id"),
+            self.target_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_id"),
+            &self.target_id,
+            "^(r-[0-9a-z]{4,32})|(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPoliciesForTargetResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -856,6 +1570,7 @@ pub struct ListPoliciesForTargetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPoliciesRequest {
     /// <p>Specifies the type of policy that you want to include in the response.</p>
     #[serde(rename = "Filter")]
@@ -870,8 +1585,25 @@ pub struct ListPoliciesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPoliciesResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -884,6 +1616,7 @@ pub struct ListPoliciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRootsRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -895,8 +1628,25 @@ pub struct ListRootsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListRootsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRootsResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -909,6 +1659,7 @@ pub struct ListRootsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>Use this parameter if you receive a <code>NextToken</code> response in a previous request that indicates that there is more output available. Set it to the value of the previous call's <code>NextToken</code> response to indicate where the output should continue from.</p>
     #[serde(rename = "NextToken")]
@@ -919,8 +1670,29 @@ pub struct ListTagsForResourceRequest {
     pub resource_id: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(&field_path(path, "resource_id"), &self.resource_id, "^(r-[0-9a-z]{4,32})|(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})|(^p-[0-9a-zA-Z_]{8,128})|(^rp-[0-9a-zA-Z_]{4,128})$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -933,6 +1705,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTargetsForPolicyRequest {
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -947,8 +1720,36 @@ pub struct ListTargetsForPolicyRequest {
     pub policy_id: String,
 }
 
+impl Validate for ListTargetsForPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(20));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(100000),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^p-[0-9a-zA-Z_]{8,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTargetsForPolicyResponse {
     /// <p>If present, this value indicates that there is more output available than is included in the current response. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back as <code>null</code>.</p>
     #[serde(rename = "NextToken")]
@@ -961,6 +1762,7 @@ pub struct ListTargetsForPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MoveAccountRequest {
     /// <p>The unique identifier (ID) of the account that you want to move.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
     #[serde(rename = "AccountId")]
@@ -973,9 +1775,47 @@ pub struct MoveAccountRequest {
     pub source_parent_id: String,
 }
 
+impl Validate for MoveAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            None,
+            Some(12),
+        );
+        errors.check_pattern(
+            &field_path(path, "account_id"),
+            &self.account_id,
+            "^\\d{12}$",
+        );
+        errors.check_length(
+            &field_path(path, "destination_parent_id"),
+            self.destination_parent_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "destination_parent_id"),
+            &self.destination_parent_id,
+            "^(r-[0-9a-z]{4,32})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+        errors.check_length(
+            &field_path(path, "source_parent_id"),
+            self.source_parent_id.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_parent_id"),
+            &self.source_parent_id,
+            "^(r-[0-9a-z]{4,32})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})$",
+        );
+    }
+}
+
 /// <p>Contains details about an organization. An organization is a collection of accounts that are centrally managed together using consolidated billing, organized hierarchically with organizational units (OUs), and controlled with policies .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Organization {
     /// <p>The Amazon Resource Name (ARN) of an organization.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
@@ -1009,7 +1849,7 @@ pub struct Organization {
 
 /// <p>Contains details about an organizational unit (OU). An OU is a container of AWS accounts within a root of an organization. Policies that are attached to an OU apply to all accounts contained in that OU and in any child OUs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OrganizationalUnit {
     /// <p>The Amazon Resource Name (ARN) of this OU.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
@@ -1027,7 +1867,7 @@ pub struct OrganizationalUnit {
 
 /// <p>Contains information about either a root or an organizational unit (OU) that can contain OUs or accounts in an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Parent {
     /// <p><p>The unique identifier (ID) of the parent entity.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a parent ID string requires one of the following:</p> <ul> <li> <p>Root: a string that begins with &quot;r-&quot; followed by from 4 to 32 lower-case letters or digits.</p> </li> <li> <p>Organizational unit (OU): a string that begins with &quot;ou-&quot; followed by from 4 to 32 lower-case letters or digits (the ID of the root that the OU is in) followed by a second &quot;-&quot; dash and from 8 to 32 additional lower-case letters or digits.</p> </li> </ul></p>
     #[serde(rename = "Id")]
@@ -1041,7 +1881,7 @@ pub struct Parent {
 
 /// <p>Contains rules to be applied to the affected accounts. Policies can be attached directly to accounts, or to roots and OUs to affect all accounts in those hierarchies.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Policy {
     /// <p>The text content of the policy.</p>
     #[serde(rename = "Content")]
@@ -1055,7 +1895,7 @@ pub struct Policy {
 
 /// <p>Contains information about a policy, but does not include the content. To see the content of a policy, see <a>DescribePolicy</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicySummary {
     /// <p>The Amazon Resource Name (ARN) of the policy.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
@@ -1085,7 +1925,7 @@ pub struct PolicySummary {
 
 /// <p>Contains information about a root, OU, or account that a policy is attached to.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyTargetSummary {
     /// <p>The Amazon Resource Name (ARN) of the policy target.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
@@ -1107,7 +1947,7 @@ pub struct PolicyTargetSummary {
 
 /// <p>Contains information about a policy type and its status in the associated root.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PolicyTypeSummary {
     /// <p>The status of the policy type as it relates to the associated root. To attach a policy of the specified type to a root or to an OU or account in that root, it must be available in the organization and enabled for that root.</p>
     #[serde(rename = "Status")]
@@ -1120,15 +1960,32 @@ pub struct PolicyTypeSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveAccountFromOrganizationRequest {
     /// <p>The unique identifier (ID) of the member account that you want to remove from the organization.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
 }
 
+impl Validate for RemoveAccountFromOrganizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_id"),
+            self.account_id.chars().count(),
+            None,
+            Some(12),
+        );
+        errors.check_pattern(
+            &field_path(path, "account_id"),
+            &self.account_id,
+            "^\\d{12}$",
+        );
+    }
+}
+
 /// <p>Contains details about a root. A root is a top-level parent node in the hierarchy of an organization that can contain organizational units (OUs) and accounts. Every root contains every AWS account in the organization. Each root enables the accounts to be organized in a different way and to have different policy types enabled for use in that root.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Root {
     /// <p>The Amazon Resource Name (ARN) of the root.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
@@ -1159,7 +2016,35 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ID of the resource to add a tag to.</p>
     #[serde(rename = "ResourceId")]
@@ -1169,7 +2054,24 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(&field_path(path, "resource_id"), &self.resource_id, "^(r-[0-9a-z]{4,32})|(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})|(^p-[0-9a-zA-Z_]{8,128})|(^rp-[0-9a-zA-Z_]{4,128})$");
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ID of the resource to remove the tag from.</p>
     #[serde(rename = "ResourceId")]
@@ -1179,7 +2081,25 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(&field_path(path, "resource_id"), &self.resource_id, "^(r-[0-9a-z]{4,32})|(\\d{12})|(ou-[0-9a-z]{4,32}-[a-z0-9]{8,32})|(^p-[0-9a-zA-Z_]{8,128})|(^rp-[0-9a-zA-Z_]{4,128})$");
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateOrganizationalUnitRequest {
     /// <p>The new name that you want to assign to the OU.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of any of the characters in the ASCII character range.</p>
     #[serde(rename = "Name")]
@@ -1190,8 +2110,33 @@ pub struct UpdateOrganizationalUnitRequest {
     pub organizational_unit_id: String,
 }
 
+impl Validate for UpdateOrganizationalUnitRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "organizational_unit_id"),
+            self.organizational_unit_id.chars().count(),
+            None,
+            Some(68),
+        );
+        errors.check_pattern(
+            &field_path(path, "organizational_unit_id"),
+            &self.organizational_unit_id,
+            "^ou-[0-9a-z]{4,32}-[a-z0-9]{8,32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateOrganizationalUnitResponse {
     /// <p>A structure that contains the details about the specified OU, including its new name.</p>
     #[serde(rename = "OrganizationalUnit")]
@@ -1200,6 +2145,7 @@ pub struct UpdateOrganizationalUnitResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePolicyRequest {
     /// <p>If provided, the new content for the policy. The text must be correctly formatted JSON that complies with the syntax for the policy's type. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_scp-syntax.html">Service Control Policy Syntax</a> in the <i>AWS Organizations User Guide.</i> </p>
     #[serde(rename = "Content")]
@@ -1218,8 +2164,51 @@ pub struct UpdatePolicyRequest {
     pub policy_id: String,
 }
 
+impl Validate for UpdatePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content {
+            errors.check_length(
+                &field_path(path, "content"),
+                value.chars().count(),
+                Some(1),
+                Some(1000000),
+            );
+            errors.check_pattern(&field_path(path, "content"), &value, "[\\s\\S]*");
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, "[\\s\\S]*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "policy_id"),
+            self.policy_id.chars().count(),
+            None,
+            Some(130),
+        );
+        errors.check_pattern(
+            &field_path(path, "policy_id"),
+            &self.policy_id,
+            "^p-[0-9a-zA-Z_]{8,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePolicyResponse {
     /// <p>A structure that contains details about the updated policy, showing the requested changes.</p>
     #[serde(rename = "Policy")]
@@ -1303,7 +2292,12 @@ impl AcceptHandshakeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(AcceptHandshakeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1400,7 +2394,12 @@ impl AttachPolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(AttachPolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1484,7 +2483,12 @@ impl CancelHandshakeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CancelHandshakeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1572,7 +2576,12 @@ impl CreateAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1665,7 +2674,12 @@ impl CreateGovCloudAccountError {
                         CreateGovCloudAccountError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1749,7 +2763,12 @@ impl CreateOrganizationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateOrganizationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1845,7 +2864,12 @@ impl CreateOrganizationalUnitError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1937,7 +2961,12 @@ impl CreatePolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreatePolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2020,7 +3049,12 @@ impl DeclineHandshakeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeclineHandshakeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2096,7 +3130,12 @@ impl DeleteOrganizationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteOrganizationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2184,7 +3223,12 @@ impl DeleteOrganizationalUnitError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2261,7 +3305,12 @@ impl DeletePolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeletePolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2328,7 +3377,12 @@ impl DescribeAccountError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeAccountError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2410,7 +3464,12 @@ impl DescribeCreateAccountStatusError {
                         DescribeCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2476,7 +3535,12 @@ impl DescribeHandshakeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribeHandshakeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2540,7 +3604,12 @@ impl DescribeOrganizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2614,7 +3683,12 @@ impl DescribeOrganizationalUnitError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2679,7 +3753,12 @@ impl DescribePolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DescribePolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2764,7 +3843,12 @@ impl DetachPolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DetachPolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2848,7 +3932,12 @@ impl DisableAWSServiceAccessError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2935,7 +4024,12 @@ impl DisablePolicyTypeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DisablePolicyTypeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3014,7 +4108,12 @@ impl EnableAWSServiceAccessError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3089,7 +4188,12 @@ impl EnableAllFeaturesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(EnableAllFeaturesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3183,7 +4287,12 @@ impl EnablePolicyTypeError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(EnablePolicyTypeError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3290,7 +4399,12 @@ impl InviteAccountToOrganizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3380,7 +4494,12 @@ impl LeaveOrganizationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(LeaveOrganizationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3460,7 +4579,12 @@ impl ListAWSServiceAccessForOrganizationError {
                         ListAWSServiceAccessForOrganizationError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3520,7 +4644,12 @@ impl ListAccountsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListAccountsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3588,7 +4717,12 @@ impl ListAccountsForParentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3653,7 +4787,12 @@ impl ListChildrenError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListChildrenError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3726,7 +4865,12 @@ impl ListCreateAccountStatusError {
                         ListCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3792,7 +4936,12 @@ impl ListHandshakesForAccountError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3868,7 +5017,12 @@ impl ListHandshakesForOrganizationError {
                         ListHandshakesForOrganizationError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3945,7 +5099,12 @@ impl ListOrganizationalUnitsForParentError {
                         ListOrganizationalUnitsForParentError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4010,7 +5169,12 @@ impl ListParentsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListParentsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4070,7 +5234,12 @@ impl ListPoliciesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListPoliciesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4138,7 +5307,12 @@ impl ListPoliciesForTargetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4196,7 +5370,12 @@ impl ListRootsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListRootsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4260,7 +5439,12 @@ impl ListTagsForResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4327,7 +5511,12 @@ impl ListTargetsForPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4414,7 +5603,12 @@ impl MoveAccountError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(MoveAccountError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4516,7 +5710,12 @@ impl RemoveAccountFromOrganizationError {
                         RemoveAccountFromOrganizationError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4594,7 +5793,12 @@ impl TagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4673,7 +5877,12 @@ impl UntagResourceError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4762,7 +5971,12 @@ impl UpdateOrganizationalUnitError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4851,7 +6065,12 @@ impl UpdatePolicyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdatePolicyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5134,778 +6353,147 @@ pub trait Organizations {
         input: UpdatePolicyRequest,
     ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError>;
 }
-/// A client for the Organizations API.
-#[derive(Clone)]
-pub struct OrganizationsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl OrganizationsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> OrganizationsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> OrganizationsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsClient {
-        OrganizationsClient { client, region }
-    }
-}
-
-impl Organizations for OrganizationsClient {
-    /// <p>Sends a response to the originator of a handshake agreeing to the action proposed by the handshake request. </p> <p>This operation can be called only by the following principals when they also have the relevant IAM permissions:</p> <ul> <li> <p> <b>Invitation to join</b> or <b>Approve all features request</b> handshakes: only a principal from the member account. </p> <p>The user who calls the API for an invitation to join must have the <code>organizations:AcceptHandshake</code> permission. If you enabled all features in the organization, the user must also have the <code>iam:CreateServiceLinkedRole</code> permission so that AWS Organizations can create the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integration_services.html#orgs_integration_service-linked-roles">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p> <b>Enable all features final confirmation</b> handshake: only a principal from the master account.</p> <p>For more information about invitations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_invites.html">Inviting an AWS Account to Join Your Organization</a> in the <i>AWS Organizations User Guide.</i> For more information about requests to enable all features in the organization, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>After you accept a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+const _: Option<&dyn Organizations> = None;
+impl<'a, T: Organizations + ?Sized> Organizations for &'a T {
     fn accept_handshake(
         &self,
         input: AcceptHandshakeRequest,
     ) -> RusotoFuture<AcceptHandshakeResponse, AcceptHandshakeError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptHandshakeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AcceptHandshakeError::from_response(response))),
-                )
-            }
-        })
+        (**self).accept_handshake(input)
     }
-
-    /// <p>Attaches a policy to a root, an organizational unit (OU), or an individual account. How the policy affects accounts depends on the type of policy:</p> <ul> <li> <p> <b>Service control policy (SCP)</b> - An SCP specifies what permissions can be delegated to users in affected member accounts. The scope of influence for a policy depends on what you attach the policy to:</p> <ul> <li> <p>If you attach an SCP to a root, it affects all accounts in the organization.</p> </li> <li> <p>If you attach an SCP to an OU, it affects all accounts in that OU and in any child OUs.</p> </li> <li> <p>If you attach the policy directly to an account, it affects only that account.</p> </li> </ul> <p>SCPs are JSON policies that specify the maximum permissions for an organization or organizational unit (OU). You can attach one SCP to a higher level root or OU, and a different SCP to a child OU or to an account. The child policy can further restrict only the permissions that pass through the parent filter and are available to the child. An SCP that is attached to a child can't grant a permission that the parent hasn't already granted. For example, imagine that the parent SCP allows permissions A, B, C, D, and E. The child SCP allows C, D, E, F, and G. The result is that the accounts affected by the child SCP are allowed to use only C, D, and E. They can't use A or B because the child OU filtered them out. They also can't use F and G because the parent OU filtered them out. They can't be granted back by the child SCP; child SCPs can only filter the permissions they receive from the parent SCP.</p> <p>AWS Organizations attaches a default SCP named <code>"FullAWSAccess</code> to every root, OU, and account. This default SCP allows all services and actions, enabling any new child OU or account to inherit the permissions of the parent root or OU. If you detach the default policy, you must replace it with a policy that specifies the permissions that you want to allow in that OU or account.</p> <p>For more information about how AWS Organizations policies permissions work, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_scp.html">Using Service Control Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>This operation can be called only from the organization's master account.</p>
     fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_policy(input)
     }
-
-    /// <p>Cancels a handshake. Canceling a handshake sets the handshake state to <code>CANCELED</code>. </p> <p>This operation can be called only from the account that originated the handshake. The recipient of the handshake can't cancel it, but can use <a>DeclineHandshake</a> instead. After a handshake is canceled, the recipient can no longer respond to that handshake.</p> <p>After you cancel a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
     fn cancel_handshake(
         &self,
         input: CancelHandshakeRequest,
     ) -> RusotoFuture<CancelHandshakeResponse, CancelHandshakeError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelHandshakeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelHandshakeError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_handshake(input)
     }
-
-    /// <p><p>Creates an AWS account that is automatically a member of the organization whose credentials made the request. This is an asynchronous request that AWS performs in the background. Because <code>CreateAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>The user who calls the API to create an account must have the <code>organizations:CreateAccount</code> permission. If you enabled all features in the organization, AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> <p>AWS Organizations preconfigures the new member account with a role (named <code>OrganizationAccountAccessRole</code> by default) that grants users in the master account administrator permissions in the new member account. Principals in the master account can assume the role. AWS Organizations clones the company name and address information for the new account from the organization&#39;s master account.</p> <p>This operation can be called only from the organization&#39;s master account.</p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required for the account to operate as a standalone account, such as a payment method and signing the end user license agreement (EULA) is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the Billing and Cost Management Console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
     fn create_account(
         &self,
         input: CreateAccountRequest,
     ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreateAccount");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAccountResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_account(input)
     }
-
-    /// <p><p>This action is available if all of the following are true:</p> <ul> <li> <p>You&#39;re authorized to create accounts in the AWS GovCloud (US) Region. For more information on the AWS GovCloud (US) Region, see the <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/welcome.html"> <i>AWS GovCloud User Guide</i>.</a> </p> </li> <li> <p>You already have an account in the AWS GovCloud (US) Region that is associated with your master account in the commercial Region. </p> </li> <li> <p>You call this action from the master account of your organization in the commercial Region.</p> </li> <li> <p>You have the <code>organizations:CreateGovCloudAccount</code> permission. AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>AWS automatically enables AWS CloudTrail for AWS GovCloud (US) accounts, but you should also do the following:</p> <ul> <li> <p>Verify that AWS CloudTrail is enabled to store logs.</p> </li> <li> <p>Create an S3 bucket for AWS CloudTrail log storage.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/verifying-cloudtrail.html">Verifying AWS CloudTrail Is Enabled</a> in the <i>AWS GovCloud User Guide</i>. </p> </li> </ul> <p>You call this action from the master account of your organization in the commercial Region to create a standalone AWS account in the AWS GovCloud (US) Region. After the account is created, the master account of an organization in the AWS GovCloud (US) Region can invite it to that organization. For more information on inviting standalone accounts in the AWS GovCloud (US) to join an organization, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>Calling <code>CreateGovCloudAccount</code> is an asynchronous request that AWS performs in the background. Because <code>CreateGovCloudAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>When you call the <code>CreateGovCloudAccount</code> action, you create two accounts: a standalone account in the AWS GovCloud (US) Region and an associated account in the commercial Region for billing and support purposes. The account in the commercial Region is automatically a member of the organization whose credentials made the request. Both accounts are associated with the same email address.</p> <p>A role is created in the new account in the commercial Region that allows the master account in the organization in the commercial Region to assume it. An AWS GovCloud (US) account is then created and associated with the commercial account that you just created. A role is created in the new AWS GovCloud (US) account that can be assumed by the AWS GovCloud (US) account that is associated with the master account of the commercial organization. For more information and to view a diagram that explains how account access works, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required for the account to operate as a standalone account, such as a payment method and signing the end user license agreement (EULA) is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateGovCloudAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the AWS Billing and Cost Management console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
     fn create_gov_cloud_account(
         &self,
         input: CreateGovCloudAccountRequest,
     ) -> RusotoFuture<CreateGovCloudAccountResponse, CreateGovCloudAccountError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.CreateGovCloudAccount",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGovCloudAccountResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateGovCloudAccountError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_gov_cloud_account(input)
     }
-
-    /// <p>Creates an AWS organization. The account whose user is calling the <code>CreateOrganization</code> operation automatically becomes the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/orgs_getting-started_concepts.html#account">master account</a> of the new organization.</p> <p>This operation must be called using credentials from the account that is to become the new organization's master account. The principal must also have the relevant IAM permissions.</p> <p>By default (or if you set the <code>FeatureSet</code> parameter to <code>ALL</code>), the new organization is created with all features enabled and service control policies automatically enabled in the root. If you instead choose to create the organization supporting only the consolidated billing features by setting the <code>FeatureSet</code> parameter to <code>CONSOLIDATED_BILLING"</code>, no policy types are enabled by default, and you can't use organization policies.</p>
     fn create_organization(
         &self,
         input: CreateOrganizationRequest,
     ) -> RusotoFuture<CreateOrganizationResponse, CreateOrganizationError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.CreateOrganization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateOrganizationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateOrganizationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_organization(input)
     }
-
-    /// <p>Creates an organizational unit (OU) within a root or parent OU. An OU is a container for accounts that enables you to organize your accounts to apply policies according to your business requirements. The number of levels deep that you can nest OUs is dependent upon the policy types enabled for that root. For service control policies, the limit is five. </p> <p>For more information about OUs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_ous.html">Managing Organizational Units</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
     fn create_organizational_unit(
         &self,
         input: CreateOrganizationalUnitRequest,
     ) -> RusotoFuture<CreateOrganizationalUnitResponse, CreateOrganizationalUnitError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.CreateOrganizationalUnit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateOrganizationalUnitResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateOrganizationalUnitError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_organizational_unit(input)
     }
-
-    /// <p>Creates a policy of a specified type that you can attach to a root, an organizational unit (OU), or an individual AWS account.</p> <p>For more information about policies and their use, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies.html">Managing Organization Policies</a>.</p> <p>This operation can be called only from the organization's master account.</p>
     fn create_policy(
         &self,
         input: CreatePolicyRequest,
     ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreatePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_policy(input)
     }
-
-    /// <p>Declines a handshake request. This sets the handshake state to <code>DECLINED</code> and effectively deactivates the request.</p> <p>This operation can be called only from the account that received the handshake. The originator of the handshake can use <a>CancelHandshake</a> instead. The originator can't reactivate a declined request, but can reinitiate the process with a new handshake request.</p> <p>After you decline a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
     fn decline_handshake(
         &self,
         input: DeclineHandshakeRequest,
     ) -> RusotoFuture<DeclineHandshakeResponse, DeclineHandshakeError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeclineHandshake");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeclineHandshakeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeclineHandshakeError::from_response(response))),
-                )
-            }
-        })
+        (**self).decline_handshake(input)
     }
-
-    /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
     fn delete_organization(&self) -> RusotoFuture<(), DeleteOrganizationError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DeleteOrganization",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteOrganizationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_organization()
     }
-
-    /// <p>Deletes an organizational unit (OU) from a root or another OU. You must first remove all accounts and child OUs from the OU that you want to delete.</p> <p>This operation can be called only from the organization's master account.</p>
     fn delete_organizational_unit(
         &self,
         input: DeleteOrganizationalUnitRequest,
     ) -> RusotoFuture<(), DeleteOrganizationalUnitError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DeleteOrganizationalUnit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteOrganizationalUnitError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_organizational_unit(input)
     }
-
-    /// <p>Deletes the specified policy from your organization. Before you perform this operation, you must first detach the policy from all organizational units (OUs), roots, and accounts.</p> <p>This operation can be called only from the organization's master account.</p>
     fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeletePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_policy(input)
     }
-
-    /// <p>Retrieves AWS Organizations-related information about the specified account.</p> <p>This operation can be called only from the organization's master account.</p>
     fn describe_account(
         &self,
         input: DescribeAccountRequest,
     ) -> RusotoFuture<DescribeAccountResponse, DescribeAccountError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribeAccount");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAccountResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_account(input)
     }
-
-    /// <p>Retrieves the current status of an asynchronous request to create an account.</p> <p>This operation can be called only from the organization's master account.</p>
     fn describe_create_account_status(
         &self,
         input: DescribeCreateAccountStatusRequest,
     ) -> RusotoFuture<DescribeCreateAccountStatusResponse, DescribeCreateAccountStatusError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DescribeCreateAccountStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCreateAccountStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCreateAccountStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_create_account_status(input)
     }
-
-    /// <p>Retrieves information about a previously requested handshake. The handshake ID comes from the response to the original <a>InviteAccountToOrganization</a> operation that generated the handshake.</p> <p>You can access handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> for only 30 days after they change to that state. They're then deleted and no longer accessible.</p> <p>This operation can be called from any account in the organization.</p>
     fn describe_handshake(
         &self,
         input: DescribeHandshakeRequest,
     ) -> RusotoFuture<DescribeHandshakeResponse, DescribeHandshakeError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DescribeHandshake",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeHandshakeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeHandshakeError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_handshake(input)
     }
-
-    /// <p><p>Retrieves information about the organization that the user&#39;s account belongs to.</p> <p>This operation can be called from any account in the organization.</p> <note> <p>Even if a policy type is shown as available in the organization, you can disable it separately at the root level with <a>DisablePolicyType</a>. Use <a>ListRoots</a> to see the status of policy types for a specified root.</p> </note></p>
     fn describe_organization(
         &self,
     ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DescribeOrganization",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOrganizationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeOrganizationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_organization()
     }
-
-    /// <p>Retrieves information about an organizational unit (OU).</p> <p>This operation can be called only from the organization's master account.</p>
     fn describe_organizational_unit(
         &self,
         input: DescribeOrganizationalUnitRequest,
     ) -> RusotoFuture<DescribeOrganizationalUnitResponse, DescribeOrganizationalUnitError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DescribeOrganizationalUnit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOrganizationalUnitResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOrganizationalUnitError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_organizational_unit(input)
     }
-
-    /// <p>Retrieves information about a policy.</p> <p>This operation can be called only from the organization's master account.</p>
     fn describe_policy(
         &self,
         input: DescribePolicyRequest,
     ) -> RusotoFuture<DescribePolicyResponse, DescribePolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribePolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_policy(input)
     }
-
-    /// <p>Detaches a policy from a target root, organizational unit (OU), or account. If the policy being detached is a service control policy (SCP), the changes to permissions for IAM users and roles in affected accounts are immediate.</p> <p> <b>Note:</b> Every root, OU, and account must have at least one SCP attached. If you want to replace the default <code>FullAWSAccess</code> policy with one that limits the permissions that can be delegated, you must attach the replacement policy before you can remove the default one. This is the authorization strategy of <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_whitelist">whitelisting</a>. If you instead attach a second SCP and leave the <code>FullAWSAccess</code> SCP still attached, and specify <code>"Effect": "Deny"</code> in the second SCP to override the <code>"Effect": "Allow"</code> in the <code>FullAWSAccess</code> policy (or any other attached SCP), you're using the authorization strategy of <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_blacklist">blacklisting</a> . </p> <p>This operation can be called only from the organization's master account.</p>
     fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DetachPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachPolicyError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_policy(input)
     }
-
-    /// <p>Disables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you disable integration, the specified service no longer can create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in <i>new</i> accounts in your organization. This means the service can't perform operations on your behalf on any new accounts in your organization. The service can still perform operations in older accounts until the service completes its clean-up from AWS Organizations.</p> <p/> <important> <p>We recommend that you disable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the other service is aware that it can clean up any resources that are required only for the integration. How the service cleans up its resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>After you perform the <code>DisableAWSServiceAccess</code> operation, the specified service can no longer perform operations in your organization's accounts unless the operations are explicitly permitted by the IAM policies that are attached to your roles. </p> <p>For more information about integrating other services with AWS Organizations, including the list of services that work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
     fn disable_aws_service_access(
         &self,
         input: DisableAWSServiceAccessRequest,
     ) -> RusotoFuture<(), DisableAWSServiceAccessError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DisableAWSServiceAccess",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisableAWSServiceAccessError::from_response(response))
-                }))
-            }
-        })
+        (**self).disable_aws_service_access(input)
     }
-
-    /// <p>Disables an organizational control policy type in a root. A policy of a certain type can be attached to entities in a root only if that type is enabled in the root. After you perform this operation, you no longer can attach policies of the specified type to that root or to any organizational unit (OU) or account in that root. You can undo this by using the <a>EnablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. If you disable a policy for a root, it still appears enabled for the organization if <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">all features</a> are enabled for the organization. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p> To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
     fn disable_policy_type(
         &self,
         input: DisablePolicyTypeRequest,
     ) -> RusotoFuture<DisablePolicyTypeResponse, DisablePolicyTypeError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.DisablePolicyType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisablePolicyTypeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisablePolicyTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_policy_type(input)
     }
-
-    /// <p>Enables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you enable integration, you allow the specified service to create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in all the accounts in your organization. This allows the service to perform operations on your behalf in your organization and its accounts.</p> <important> <p>We recommend that you enable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the service is aware that it can create the resources that are required for the integration. How the service creates those resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>For more information about enabling services to integrate with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account and only if the organization has <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">enabled all features</a>.</p>
     fn enable_aws_service_access(
         &self,
         input: EnableAWSServiceAccessRequest,
     ) -> RusotoFuture<(), EnableAWSServiceAccessError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.EnableAWSServiceAccess",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(EnableAWSServiceAccessError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).enable_aws_service_access(input)
     }
-
-    /// <p>Enables all features in an organization. This enables the use of organization policies that can restrict the services and actions that can be called in each account. Until you enable all features, you have access only to consolidated billing, and you can't use any of the advanced account administration features that AWS Organizations supports. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <p>This operation is required only for organizations that were created explicitly with only the consolidated billing features enabled. Calling this operation sends a handshake to every invited account in the organization. The feature set change can be finalized and the additional features enabled only after all administrators in the invited accounts approve the change by accepting the handshake.</p> </important> <p>After you enable all features, you can separately enable or disable individual policy types in a root using <a>EnablePolicyType</a> and <a>DisablePolicyType</a>. To see the status of policy types in a root, use <a>ListRoots</a>.</p> <p>After all invited member accounts accept the handshake, you finalize the feature set change by accepting the handshake that contains <code>"Action": "ENABLE_ALL_FEATURES"</code>. This completes the change.</p> <p>After you enable all features in your organization, the master account in the organization can apply policies on all member accounts. These policies can restrict what users and even administrators in those accounts can do. The master account can apply policies that prevent accounts from leaving the organization. Ensure that your account administrators are aware of this.</p> <p>This operation can be called only from the organization's master account. </p>
     fn enable_all_features(
         &self,
     ) -> RusotoFuture<EnableAllFeaturesResponse, EnableAllFeaturesError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.EnableAllFeatures",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableAllFeaturesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableAllFeaturesError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_all_features()
     }
-
-    /// <p>Enables a policy type in a root. After you enable a policy type in a root, you can attach policies of that type to the root, any organizational unit (OU), or account in that root. You can undo this by using the <a>DisablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p>You can enable a policy type in a root only if that policy type is available in the organization. To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
     fn enable_policy_type(
         &self,
         input: EnablePolicyTypeRequest,
     ) -> RusotoFuture<EnablePolicyTypeResponse, EnablePolicyTypeError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.EnablePolicyType");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnablePolicyTypeResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnablePolicyTypeError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_policy_type(input)
     }
-
-    /// <p>Sends an invitation to another account to join your organization as a member account. AWS Organizations sends email on your behalf to the email address that is associated with the other account's owner. The invitation is implemented as a <a>Handshake</a> whose details are in the response.</p> <important> <ul> <li> <p>You can invite AWS accounts only from the same seller as the master account. For example, if your organization's master account was created by Amazon Internet Services Pvt. Ltd (AISPL), an AWS seller in India, you can invite only other AISPL accounts to your organization. You can't combine accounts from AISPL and AWS or from any other AWS seller. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/useconsolidatedbilliing-India.html">Consolidated Billing in India</a>.</p> </li> <li> <p>If you receive an exception that indicates that you exceeded your account limits for the organization or that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists after an hour, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> </ul> </important> <p>This operation can be called only from the organization's master account.</p>
     fn invite_account_to_organization(
         &self,
         input: InviteAccountToOrganizationRequest,
     ) -> RusotoFuture<InviteAccountToOrganizationResponse, InviteAccountToOrganizationError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.InviteAccountToOrganization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InviteAccountToOrganizationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(InviteAccountToOrganizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).invite_account_to_organization(input)
     }
-
-    /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do, including preventing them from successfully calling <code>LeaveOrganization</code> and leaving the organization. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must accept the end user license agreement (EULA), choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
     fn leave_organization(&self) -> RusotoFuture<(), LeaveOrganizationError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.LeaveOrganization",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(LeaveOrganizationError::from_response(response))),
-                )
-            }
-        })
+        (**self).leave_organization()
     }
-
-    /// <p>Returns a list of the AWS services that you enabled to integrate with your organization. After a service on this list creates the resources that it requires for the integration, it can perform operations on your organization and its accounts.</p> <p>For more information about integrating other services with AWS Organizations, including the list of services that currently work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
     fn list_aws_service_access_for_organization(
         &self,
         input: ListAWSServiceAccessForOrganizationRequest,
@@ -5913,571 +6501,3858 @@ impl Organizations for OrganizationsClient {
         ListAWSServiceAccessForOrganizationResponse,
         ListAWSServiceAccessForOrganizationError,
     > {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListAWSServiceAccessForOrganization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAWSServiceAccessForOrganizationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAWSServiceAccessForOrganizationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_aws_service_access_for_organization(input)
     }
-
-    /// <p>Lists all the accounts in the organization. To request only the accounts in a specified root or organizational unit (OU), use the <a>ListAccountsForParent</a> operation instead.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_accounts(
         &self,
         input: ListAccountsRequest,
     ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListAccounts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAccountsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAccountsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_accounts(input)
     }
-
-    /// <p>Lists the accounts in an organization that are contained by the specified target root or organizational unit (OU). If you specify the root, you get a list of all the accounts that aren't in any OU. If you specify an OU, you get a list of all the accounts in only that OU and not in any child OUs. To get a list of all accounts in the organization, use the <a>ListAccounts</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_accounts_for_parent(
         &self,
         input: ListAccountsForParentRequest,
     ) -> RusotoFuture<ListAccountsForParentResponse, ListAccountsForParentError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListAccountsForParent",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAccountsForParentResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListAccountsForParentError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_accounts_for_parent(input)
     }
-
-    /// <p>Lists all of the organizational units (OUs) or accounts that are contained in the specified parent OU or root. This operation, along with <a>ListParents</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_children(
         &self,
         input: ListChildrenRequest,
     ) -> RusotoFuture<ListChildrenResponse, ListChildrenError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListChildren");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListChildrenResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListChildrenError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_children(input)
     }
-
-    /// <p>Lists the account creation requests that match the specified status that is currently being tracked for the organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_create_account_status(
         &self,
         input: ListCreateAccountStatusRequest,
     ) -> RusotoFuture<ListCreateAccountStatusResponse, ListCreateAccountStatusError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListCreateAccountStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCreateAccountStatusResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListCreateAccountStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_create_account_status(input)
     }
-
-    /// <p>Lists the current handshakes that are associated with the account of the requesting user.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called from any account in the organization.</p>
     fn list_handshakes_for_account(
         &self,
         input: ListHandshakesForAccountRequest,
     ) -> RusotoFuture<ListHandshakesForAccountResponse, ListHandshakesForAccountError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListHandshakesForAccount",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHandshakesForAccountResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListHandshakesForAccountError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_handshakes_for_account(input)
     }
-
-    /// <p>Lists the handshakes that are associated with the organization that the requesting user is part of. The <code>ListHandshakesForOrganization</code> operation returns a list of handshake structures. Each structure contains details and status about a handshake.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_handshakes_for_organization(
         &self,
         input: ListHandshakesForOrganizationRequest,
     ) -> RusotoFuture<ListHandshakesForOrganizationResponse, ListHandshakesForOrganizationError>
     {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListHandshakesForOrganization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHandshakesForOrganizationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListHandshakesForOrganizationError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_handshakes_for_organization(input)
     }
-
-    /// <p>Lists the organizational units (OUs) in a parent organizational unit or root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_organizational_units_for_parent(
         &self,
         input: ListOrganizationalUnitsForParentRequest,
     ) -> RusotoFuture<ListOrganizationalUnitsForParentResponse, ListOrganizationalUnitsForParentError>
     {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListOrganizationalUnitsForParent",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOrganizationalUnitsForParentResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListOrganizationalUnitsForParentError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_organizational_units_for_parent(input)
     }
-
-    /// <p><p>Lists the root or organizational units (OUs) that serve as the immediate parent of the specified child OU or account. This operation, along with <a>ListChildren</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>In the current release, a child can have only a single parent. </p> </note></p>
     fn list_parents(
         &self,
         input: ListParentsRequest,
     ) -> RusotoFuture<ListParentsResponse, ListParentsError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListParents");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListParentsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListParentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_parents(input)
     }
-
-    /// <p>Retrieves the list of all policies in an organization of a specified type.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_policies(
         &self,
         input: ListPoliciesRequest,
     ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListPolicies");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPoliciesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPoliciesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_policies(input)
     }
-
-    /// <p>Lists the policies that are directly attached to the specified target root, organizational unit (OU), or account. You must specify the policy type that you want included in the returned list.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_policies_for_target(
         &self,
         input: ListPoliciesForTargetRequest,
     ) -> RusotoFuture<ListPoliciesForTargetResponse, ListPoliciesForTargetError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListPoliciesForTarget",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPoliciesForTargetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListPoliciesForTargetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_policies_for_target(input)
     }
-
-    /// <p><p>Lists the roots that are defined in the current organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>Policy types can be enabled and disabled in roots. This is distinct from whether they&#39;re available in the organization. When you enable all features, you make policy types available for use in that organization. Individual policy types can then be enabled and disabled in a root. To see the availability of a policy type in an organization, use <a>DescribeOrganization</a>.</p> </note></p>
     fn list_roots(
         &self,
         input: ListRootsRequest,
     ) -> RusotoFuture<ListRootsResponse, ListRootsError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListRoots");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRootsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRootsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_roots(input)
     }
-
-    /// <p>Lists tags for the specified resource. </p> <p>Currently, you can list tags on an account in AWS Organizations.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListTagsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Lists all the roots, organizational units (OUs), and accounts that the specified policy is attached to.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
     fn list_targets_for_policy(
         &self,
         input: ListTargetsForPolicyRequest,
     ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.ListTargetsForPolicy",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTargetsForPolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTargetsForPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_targets_for_policy(input)
     }
-
-    /// <p>Moves an account from its current source parent root or organizational unit (OU) to the specified destination parent root or OU.</p> <p>This operation can be called only from the organization's master account.</p>
     fn move_account(&self, input: MoveAccountRequest) -> RusotoFuture<(), MoveAccountError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.MoveAccount");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(MoveAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).move_account(input)
     }
-
-    /// <p><p>Removes the specified account from the organization.</p> <p>The removed account becomes a standalone account that isn&#39;t a member of any organization. It&#39;s no longer subject to any policies and is responsible for its own bill payments. The organization&#39;s master account is no longer charged for any expenses accrued by the member account after it&#39;s removed from the organization.</p> <p>This operation can be called only from the organization&#39;s master account. Member accounts can remove themselves with <a>LeaveOrganization</a> instead.</p> <important> <p>You can remove an account from your organization only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For an account that you want to make standalone, you must accept the end user license agreement (EULA), choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. To remove an account that doesn&#39;t yet have this information, you must sign in as the member account and follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </important></p>
     fn remove_account_from_organization(
         &self,
         input: RemoveAccountFromOrganizationRequest,
     ) -> RusotoFuture<(), RemoveAccountFromOrganizationError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.RemoveAccountFromOrganization",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveAccountFromOrganizationError::from_response(response))
+        (**self).remove_account_from_organization(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> RusotoFuture<UpdateOrganizationalUnitResponse, UpdateOrganizationalUnitError> {
+        (**self).update_organizational_unit(input)
+    }
+    fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError> {
+        (**self).update_policy(input)
+    }
+}
+impl<T: Organizations + ?Sized> Organizations for Box<T> {
+    fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> RusotoFuture<AcceptHandshakeResponse, AcceptHandshakeError> {
+        (**self).accept_handshake(input)
+    }
+    fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> RusotoFuture<CancelHandshakeResponse, CancelHandshakeError> {
+        (**self).cancel_handshake(input)
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        (**self).create_account(input)
+    }
+    fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> RusotoFuture<CreateGovCloudAccountResponse, CreateGovCloudAccountError> {
+        (**self).create_gov_cloud_account(input)
+    }
+    fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> RusotoFuture<CreateOrganizationResponse, CreateOrganizationError> {
+        (**self).create_organization(input)
+    }
+    fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> RusotoFuture<CreateOrganizationalUnitResponse, CreateOrganizationalUnitError> {
+        (**self).create_organizational_unit(input)
+    }
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        (**self).create_policy(input)
+    }
+    fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> RusotoFuture<DeclineHandshakeResponse, DeclineHandshakeError> {
+        (**self).decline_handshake(input)
+    }
+    fn delete_organization(&self) -> RusotoFuture<(), DeleteOrganizationError> {
+        (**self).delete_organization()
+    }
+    fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationalUnitError> {
+        (**self).delete_organizational_unit(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> RusotoFuture<DescribeAccountResponse, DescribeAccountError> {
+        (**self).describe_account(input)
+    }
+    fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> RusotoFuture<DescribeCreateAccountStatusResponse, DescribeCreateAccountStatusError> {
+        (**self).describe_create_account_status(input)
+    }
+    fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> RusotoFuture<DescribeHandshakeResponse, DescribeHandshakeError> {
+        (**self).describe_handshake(input)
+    }
+    fn describe_organization(
+        &self,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        (**self).describe_organization()
+    }
+    fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> RusotoFuture<DescribeOrganizationalUnitResponse, DescribeOrganizationalUnitError> {
+        (**self).describe_organizational_unit(input)
+    }
+    fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> RusotoFuture<DescribePolicyResponse, DescribePolicyError> {
+        (**self).describe_policy(input)
+    }
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
+    fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), DisableAWSServiceAccessError> {
+        (**self).disable_aws_service_access(input)
+    }
+    fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> RusotoFuture<DisablePolicyTypeResponse, DisablePolicyTypeError> {
+        (**self).disable_policy_type(input)
+    }
+    fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), EnableAWSServiceAccessError> {
+        (**self).enable_aws_service_access(input)
+    }
+    fn enable_all_features(
+        &self,
+    ) -> RusotoFuture<EnableAllFeaturesResponse, EnableAllFeaturesError> {
+        (**self).enable_all_features()
+    }
+    fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> RusotoFuture<EnablePolicyTypeResponse, EnablePolicyTypeError> {
+        (**self).enable_policy_type(input)
+    }
+    fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> RusotoFuture<InviteAccountToOrganizationResponse, InviteAccountToOrganizationError> {
+        (**self).invite_account_to_organization(input)
+    }
+    fn leave_organization(&self) -> RusotoFuture<(), LeaveOrganizationError> {
+        (**self).leave_organization()
+    }
+    fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> RusotoFuture<
+        ListAWSServiceAccessForOrganizationResponse,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        (**self).list_aws_service_access_for_organization(input)
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        (**self).list_accounts(input)
+    }
+    fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> RusotoFuture<ListAccountsForParentResponse, ListAccountsForParentError> {
+        (**self).list_accounts_for_parent(input)
+    }
+    fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> RusotoFuture<ListChildrenResponse, ListChildrenError> {
+        (**self).list_children(input)
+    }
+    fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> RusotoFuture<ListCreateAccountStatusResponse, ListCreateAccountStatusError> {
+        (**self).list_create_account_status(input)
+    }
+    fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> RusotoFuture<ListHandshakesForAccountResponse, ListHandshakesForAccountError> {
+        (**self).list_handshakes_for_account(input)
+    }
+    fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> RusotoFuture<ListHandshakesForOrganizationResponse, ListHandshakesForOrganizationError>
+    {
+        (**self).list_handshakes_for_organization(input)
+    }
+    fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> RusotoFuture<ListOrganizationalUnitsForParentResponse, ListOrganizationalUnitsForParentError>
+    {
+        (**self).list_organizational_units_for_parent(input)
+    }
+    fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> RusotoFuture<ListParentsResponse, ListParentsError> {
+        (**self).list_parents(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> RusotoFuture<ListPoliciesForTargetResponse, ListPoliciesForTargetError> {
+        (**self).list_policies_for_target(input)
+    }
+    fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> RusotoFuture<ListRootsResponse, ListRootsError> {
+        (**self).list_roots(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
+        (**self).list_targets_for_policy(input)
+    }
+    fn move_account(&self, input: MoveAccountRequest) -> RusotoFuture<(), MoveAccountError> {
+        (**self).move_account(input)
+    }
+    fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> RusotoFuture<(), RemoveAccountFromOrganizationError> {
+        (**self).remove_account_from_organization(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> RusotoFuture<UpdateOrganizationalUnitResponse, UpdateOrganizationalUnitError> {
+        (**self).update_organizational_unit(input)
+    }
+    fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError> {
+        (**self).update_policy(input)
+    }
+}
+impl<T: Organizations + ?Sized> Organizations for ::std::sync::Arc<T> {
+    fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> RusotoFuture<AcceptHandshakeResponse, AcceptHandshakeError> {
+        (**self).accept_handshake(input)
+    }
+    fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> RusotoFuture<CancelHandshakeResponse, CancelHandshakeError> {
+        (**self).cancel_handshake(input)
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        (**self).create_account(input)
+    }
+    fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> RusotoFuture<CreateGovCloudAccountResponse, CreateGovCloudAccountError> {
+        (**self).create_gov_cloud_account(input)
+    }
+    fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> RusotoFuture<CreateOrganizationResponse, CreateOrganizationError> {
+        (**self).create_organization(input)
+    }
+    fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> RusotoFuture<CreateOrganizationalUnitResponse, CreateOrganizationalUnitError> {
+        (**self).create_organizational_unit(input)
+    }
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        (**self).create_policy(input)
+    }
+    fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> RusotoFuture<DeclineHandshakeResponse, DeclineHandshakeError> {
+        (**self).decline_handshake(input)
+    }
+    fn delete_organization(&self) -> RusotoFuture<(), DeleteOrganizationError> {
+        (**self).delete_organization()
+    }
+    fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationalUnitError> {
+        (**self).delete_organizational_unit(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> RusotoFuture<DescribeAccountResponse, DescribeAccountError> {
+        (**self).describe_account(input)
+    }
+    fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> RusotoFuture<DescribeCreateAccountStatusResponse, DescribeCreateAccountStatusError> {
+        (**self).describe_create_account_status(input)
+    }
+    fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> RusotoFuture<DescribeHandshakeResponse, DescribeHandshakeError> {
+        (**self).describe_handshake(input)
+    }
+    fn describe_organization(
+        &self,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        (**self).describe_organization()
+    }
+    fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> RusotoFuture<DescribeOrganizationalUnitResponse, DescribeOrganizationalUnitError> {
+        (**self).describe_organizational_unit(input)
+    }
+    fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> RusotoFuture<DescribePolicyResponse, DescribePolicyError> {
+        (**self).describe_policy(input)
+    }
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
+    fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), DisableAWSServiceAccessError> {
+        (**self).disable_aws_service_access(input)
+    }
+    fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> RusotoFuture<DisablePolicyTypeResponse, DisablePolicyTypeError> {
+        (**self).disable_policy_type(input)
+    }
+    fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), EnableAWSServiceAccessError> {
+        (**self).enable_aws_service_access(input)
+    }
+    fn enable_all_features(
+        &self,
+    ) -> RusotoFuture<EnableAllFeaturesResponse, EnableAllFeaturesError> {
+        (**self).enable_all_features()
+    }
+    fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> RusotoFuture<EnablePolicyTypeResponse, EnablePolicyTypeError> {
+        (**self).enable_policy_type(input)
+    }
+    fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> RusotoFuture<InviteAccountToOrganizationResponse, InviteAccountToOrganizationError> {
+        (**self).invite_account_to_organization(input)
+    }
+    fn leave_organization(&self) -> RusotoFuture<(), LeaveOrganizationError> {
+        (**self).leave_organization()
+    }
+    fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> RusotoFuture<
+        ListAWSServiceAccessForOrganizationResponse,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        (**self).list_aws_service_access_for_organization(input)
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        (**self).list_accounts(input)
+    }
+    fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> RusotoFuture<ListAccountsForParentResponse, ListAccountsForParentError> {
+        (**self).list_accounts_for_parent(input)
+    }
+    fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> RusotoFuture<ListChildrenResponse, ListChildrenError> {
+        (**self).list_children(input)
+    }
+    fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> RusotoFuture<ListCreateAccountStatusResponse, ListCreateAccountStatusError> {
+        (**self).list_create_account_status(input)
+    }
+    fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> RusotoFuture<ListHandshakesForAccountResponse, ListHandshakesForAccountError> {
+        (**self).list_handshakes_for_account(input)
+    }
+    fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> RusotoFuture<ListHandshakesForOrganizationResponse, ListHandshakesForOrganizationError>
+    {
+        (**self).list_handshakes_for_organization(input)
+    }
+    fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> RusotoFuture<ListOrganizationalUnitsForParentResponse, ListOrganizationalUnitsForParentError>
+    {
+        (**self).list_organizational_units_for_parent(input)
+    }
+    fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> RusotoFuture<ListParentsResponse, ListParentsError> {
+        (**self).list_parents(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> RusotoFuture<ListPoliciesForTargetResponse, ListPoliciesForTargetError> {
+        (**self).list_policies_for_target(input)
+    }
+    fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> RusotoFuture<ListRootsResponse, ListRootsError> {
+        (**self).list_roots(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
+        (**self).list_targets_for_policy(input)
+    }
+    fn move_account(&self, input: MoveAccountRequest) -> RusotoFuture<(), MoveAccountError> {
+        (**self).move_account(input)
+    }
+    fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> RusotoFuture<(), RemoveAccountFromOrganizationError> {
+        (**self).remove_account_from_organization(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> RusotoFuture<UpdateOrganizationalUnitResponse, UpdateOrganizationalUnitError> {
+        (**self).update_organizational_unit(input)
+    }
+    fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError> {
+        (**self).update_policy(input)
+    }
+}
+/// A client for the Organizations API.
+#[derive(Clone)]
+pub struct OrganizationsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl OrganizationsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OrganizationsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OrganizationsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsClient {
+        OrganizationsClient { client, region }
+    }
+}
+
+impl Organizations for OrganizationsClient {
+    /// <p>Sends a response to the originator of a handshake agreeing to the action proposed by the handshake request. </p> <p>This operation can be called only by the following principals when they also have the relevant IAM permissions:</p> <ul> <li> <p> <b>Invitation to join</b> or <b>Approve all features request</b> handshakes: only a principal from the member account. </p> <p>The user who calls the API for an invitation to join must have the <code>organizations:AcceptHandshake</code> permission. If you enabled all features in the organization, the user must also have the <code>iam:CreateServiceLinkedRole</code> permission so that AWS Organizations can create the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integration_services.html#orgs_integration_service-linked-roles">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p> <b>Enable all features final confirmation</b> handshake: only a principal from the master account.</p> <p>For more information about invitations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_invites.html">Inviting an AWS Account to Join Your Organization</a> in the <i>AWS Organizations User Guide.</i> For more information about requests to enable all features in the organization, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>After you accept a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> RusotoFuture<AcceptHandshakeResponse, AcceptHandshakeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("AcceptHandshake");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcceptHandshakeResponse, _>(
+                    response,
+                    "AcceptHandshake",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AcceptHandshakeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attaches a policy to a root, an organizational unit (OU), or an individual account. How the policy affects accounts depends on the type of policy:</p> <ul> <li> <p> <b>Service control policy (SCP)</b> - An SCP specifies what permissions can be delegated to users in affected member accounts. The scope of influence for a policy depends on what you attach the policy to:</p> <ul> <li> <p>If you attach an SCP to a root, it affects all accounts in the organization.</p> </li> <li> <p>If you attach an SCP to an OU, it affects all accounts in that OU and in any child OUs.</p> </li> <li> <p>If you attach the policy directly to an account, it affects only that account.</p> </li> </ul> <p>SCPs are JSON policies that specify the maximum permissions for an organization or organizational unit (OU). You can attach one SCP to a higher level root or OU, and a different SCP to a child OU or to an account. The child policy can further restrict only the permissions that pass through the parent filter and are available to the child. An SCP that is attached to a child can't grant a permission that the parent hasn't already granted. For example, imagine that the parent SCP allows permissions A, B, C, D, and E. The child SCP allows C, D, E, F, and G. The result is that the accounts affected by the child SCP are allowed to use only C, D, and E. They can't use A or B because the child OU filtered them out. They also can't use F and G because the parent OU filtered them out. They can't be granted back by the child SCP; child SCPs can only filter the permissions they receive from the parent SCP.</p> <p>AWS Organizations attaches a default SCP named <code>"FullAWSAccess</code> to every root, OU, and account. This default SCP allows all services and actions, enabling any new child OU or account to inherit the permissions of the parent root or OU. If you detach the default policy, you must replace it with a policy that specifies the permissions that you want to allow in that OU or account.</p> <p>For more information about how AWS Organizations policies permissions work, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_scp.html">Using Service Control Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>This operation can be called only from the organization's master account.</p>
+    fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("AttachPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels a handshake. Canceling a handshake sets the handshake state to <code>CANCELED</code>. </p> <p>This operation can be called only from the account that originated the handshake. The recipient of the handshake can't cancel it, but can use <a>DeclineHandshake</a> instead. After a handshake is canceled, the recipient can no longer respond to that handshake.</p> <p>After you cancel a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> RusotoFuture<CancelHandshakeResponse, CancelHandshakeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CancelHandshake");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelHandshakeResponse, _>(
+                    response,
+                    "CancelHandshake",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelHandshakeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates an AWS account that is automatically a member of the organization whose credentials made the request. This is an asynchronous request that AWS performs in the background. Because <code>CreateAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>The user who calls the API to create an account must have the <code>organizations:CreateAccount</code> permission. If you enabled all features in the organization, AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> <p>AWS Organizations preconfigures the new member account with a role (named <code>OrganizationAccountAccessRole</code> by default) that grants users in the master account administrator permissions in the new member account. Principals in the master account can assume the role. AWS Organizations clones the company name and address information for the new account from the organization&#39;s master account.</p> <p>This operation can be called only from the organization&#39;s master account.</p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required for the account to operate as a standalone account, such as a payment method and signing the end user license agreement (EULA) is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the Billing and Cost Management Console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreateAccount");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAccountResponse, _>(
+                    response,
+                    "CreateAccount",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>This action is available if all of the following are true:</p> <ul> <li> <p>You&#39;re authorized to create accounts in the AWS GovCloud (US) Region. For more information on the AWS GovCloud (US) Region, see the <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/welcome.html"> <i>AWS GovCloud User Guide</i>.</a> </p> </li> <li> <p>You already have an account in the AWS GovCloud (US) Region that is associated with your master account in the commercial Region. </p> </li> <li> <p>You call this action from the master account of your organization in the commercial Region.</p> </li> <li> <p>You have the <code>organizations:CreateGovCloudAccount</code> permission. AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>AWS automatically enables AWS CloudTrail for AWS GovCloud (US) accounts, but you should also do the following:</p> <ul> <li> <p>Verify that AWS CloudTrail is enabled to store logs.</p> </li> <li> <p>Create an S3 bucket for AWS CloudTrail log storage.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/verifying-cloudtrail.html">Verifying AWS CloudTrail Is Enabled</a> in the <i>AWS GovCloud User Guide</i>. </p> </li> </ul> <p>You call this action from the master account of your organization in the commercial Region to create a standalone AWS account in the AWS GovCloud (US) Region. After the account is created, the master account of an organization in the AWS GovCloud (US) Region can invite it to that organization. For more information on inviting standalone accounts in the AWS GovCloud (US) to join an organization, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>Calling <code>CreateGovCloudAccount</code> is an asynchronous request that AWS performs in the background. Because <code>CreateGovCloudAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>When you call the <code>CreateGovCloudAccount</code> action, you create two accounts: a standalone account in the AWS GovCloud (US) Region and an associated account in the commercial Region for billing and support purposes. The account in the commercial Region is automatically a member of the organization whose credentials made the request. Both accounts are associated with the same email address.</p> <p>A role is created in the new account in the commercial Region that allows the master account in the organization in the commercial Region to assume it. An AWS GovCloud (US) account is then created and associated with the commercial account that you just created. A role is created in the new AWS GovCloud (US) account that can be assumed by the AWS GovCloud (US) account that is associated with the master account of the commercial organization. For more information and to view a diagram that explains how account access works, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required for the account to operate as a standalone account, such as a payment method and signing the end user license agreement (EULA) is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateGovCloudAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the AWS Billing and Cost Management console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
+    fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> RusotoFuture<CreateGovCloudAccountResponse, CreateGovCloudAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateGovCloudAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.CreateGovCloudAccount",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGovCloudAccountResponse, _>(
+                    response,
+                    "CreateGovCloudAccount",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateGovCloudAccountError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an AWS organization. The account whose user is calling the <code>CreateOrganization</code> operation automatically becomes the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/orgs_getting-started_concepts.html#account">master account</a> of the new organization.</p> <p>This operation must be called using credentials from the account that is to become the new organization's master account. The principal must also have the relevant IAM permissions.</p> <p>By default (or if you set the <code>FeatureSet</code> parameter to <code>ALL</code>), the new organization is created with all features enabled and service control policies automatically enabled in the root. If you instead choose to create the organization supporting only the consolidated billing features by setting the <code>FeatureSet</code> parameter to <code>CONSOLIDATED_BILLING"</code>, no policy types are enabled by default, and you can't use organization policies.</p>
+    fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> RusotoFuture<CreateOrganizationResponse, CreateOrganizationError> {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.CreateOrganization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateOrganizationResponse, _>(
+                    response,
+                    "CreateOrganization",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateOrganizationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an organizational unit (OU) within a root or parent OU. An OU is a container for accounts that enables you to organize your accounts to apply policies according to your business requirements. The number of levels deep that you can nest OUs is dependent upon the policy types enabled for that root. For service control policies, the limit is five. </p> <p>For more information about OUs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_ous.html">Managing Organizational Units</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> RusotoFuture<CreateOrganizationalUnitResponse, CreateOrganizationalUnitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreateOrganizationalUnit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.CreateOrganizationalUnit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateOrganizationalUnitResponse, _>(
+                    response,
+                    "CreateOrganizationalUnit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateOrganizationalUnitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a policy of a specified type that you can attach to a root, an organizational unit (OU), or an individual AWS account.</p> <p>For more information about policies and their use, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies.html">Managing Organization Policies</a>.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("CreatePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreatePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePolicyResponse, _>(
+                    response,
+                    "CreatePolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Declines a handshake request. This sets the handshake state to <code>DECLINED</code> and effectively deactivates the request.</p> <p>This operation can be called only from the account that received the handshake. The originator of the handshake can use <a>CancelHandshake</a> instead. The originator can't reactivate a declined request, but can reinitiate the process with a new handshake request.</p> <p>After you decline a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> RusotoFuture<DeclineHandshakeResponse, DeclineHandshakeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeclineHandshake");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeclineHandshake");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeclineHandshakeResponse, _>(
+                    response,
+                    "DeclineHandshake",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeclineHandshakeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
+    fn delete_organization(&self) -> RusotoFuture<(), DeleteOrganizationError> {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeleteOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DeleteOrganization",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteOrganizationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an organizational unit (OU) from a root or another OU. You must first remove all accounts and child OUs from the OU that you want to delete.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationalUnitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeleteOrganizationalUnit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DeleteOrganizationalUnit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteOrganizationalUnitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified policy from your organization. Before you perform this operation, you must first detach the policy from all organizational units (OUs), roots, and accounts.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DeletePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeletePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves AWS Organizations-related information about the specified account.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> RusotoFuture<DescribeAccountResponse, DescribeAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribeAccount");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAccountResponse, _>(
+                    response,
+                    "DescribeAccount",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the current status of an asynchronous request to create an account.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> RusotoFuture<DescribeCreateAccountStatusResponse, DescribeCreateAccountStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeCreateAccountStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DescribeCreateAccountStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCreateAccountStatusResponse, _>(
+                    response,
+                    "DescribeCreateAccountStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCreateAccountStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a previously requested handshake. The handshake ID comes from the response to the original <a>InviteAccountToOrganization</a> operation that generated the handshake.</p> <p>You can access handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> for only 30 days after they change to that state. They're then deleted and no longer accessible.</p> <p>This operation can be called from any account in the organization.</p>
+    fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> RusotoFuture<DescribeHandshakeResponse, DescribeHandshakeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeHandshake");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DescribeHandshake",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeHandshakeResponse, _>(
+                    response,
+                    "DescribeHandshake",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeHandshakeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves information about the organization that the user&#39;s account belongs to.</p> <p>This operation can be called from any account in the organization.</p> <note> <p>Even if a policy type is shown as available in the organization, you can disable it separately at the root level with <a>DisablePolicyType</a>. Use <a>ListRoots</a> to see the status of policy types for a specified root.</p> </note></p>
+    fn describe_organization(
+        &self,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DescribeOrganization",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOrganizationResponse, _>(
+                    response,
+                    "DescribeOrganization",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeOrganizationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about an organizational unit (OU).</p> <p>This operation can be called only from the organization's master account.</p>
+    fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> RusotoFuture<DescribeOrganizationalUnitResponse, DescribeOrganizationalUnitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribeOrganizationalUnit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DescribeOrganizationalUnit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOrganizationalUnitResponse, _>(
+                    response,
+                    "DescribeOrganizationalUnit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOrganizationalUnitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a policy.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> RusotoFuture<DescribePolicyResponse, DescribePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DescribePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePolicyResponse, _>(
+                    response,
+                    "DescribePolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detaches a policy from a target root, organizational unit (OU), or account. If the policy being detached is a service control policy (SCP), the changes to permissions for IAM users and roles in affected accounts are immediate.</p> <p> <b>Note:</b> Every root, OU, and account must have at least one SCP attached. If you want to replace the default <code>FullAWSAccess</code> policy with one that limits the permissions that can be delegated, you must attach the replacement policy before you can remove the default one. This is the authorization strategy of <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_whitelist">whitelisting</a>. If you instead attach a second SCP and leave the <code>FullAWSAccess</code> SCP still attached, and specify <code>"Effect": "Deny"</code> in the second SCP to override the <code>"Effect": "Allow"</code> in the <code>FullAWSAccess</code> policy (or any other attached SCP), you're using the authorization strategy of <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_about-scps.html#orgs_policies_blacklist">blacklisting</a> . </p> <p>This operation can be called only from the organization's master account.</p>
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DetachPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.DetachPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachPolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you disable integration, the specified service no longer can create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in <i>new</i> accounts in your organization. This means the service can't perform operations on your behalf on any new accounts in your organization. The service can still perform operations in older accounts until the service completes its clean-up from AWS Organizations.</p> <p/> <important> <p>We recommend that you disable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the other service is aware that it can clean up any resources that are required only for the integration. How the service cleans up its resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>After you perform the <code>DisableAWSServiceAccess</code> operation, the specified service can no longer perform operations in your organization's accounts unless the operations are explicitly permitted by the IAM policies that are attached to your roles. </p> <p>For more information about integrating other services with AWS Organizations, including the list of services that work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), DisableAWSServiceAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DisableAWSServiceAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DisableAWSServiceAccess",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisableAWSServiceAccessError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disables an organizational control policy type in a root. A policy of a certain type can be attached to entities in a root only if that type is enabled in the root. After you perform this operation, you no longer can attach policies of the specified type to that root or to any organizational unit (OU) or account in that root. You can undo this by using the <a>EnablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. If you disable a policy for a root, it still appears enabled for the organization if <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">all features</a> are enabled for the organization. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p> To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
+    fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> RusotoFuture<DisablePolicyTypeResponse, DisablePolicyTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("DisablePolicyType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.DisablePolicyType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisablePolicyTypeResponse, _>(
+                    response,
+                    "DisablePolicyType",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisablePolicyTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you enable integration, you allow the specified service to create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in all the accounts in your organization. This allows the service to perform operations on your behalf in your organization and its accounts.</p> <important> <p>We recommend that you enable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the service is aware that it can create the resources that are required for the integration. How the service creates those resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>For more information about enabling services to integrate with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account and only if the organization has <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">enabled all features</a>.</p>
+    fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), EnableAWSServiceAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnableAWSServiceAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.EnableAWSServiceAccess",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(EnableAWSServiceAccessError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables all features in an organization. This enables the use of organization policies that can restrict the services and actions that can be called in each account. Until you enable all features, you have access only to consolidated billing, and you can't use any of the advanced account administration features that AWS Organizations supports. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <p>This operation is required only for organizations that were created explicitly with only the consolidated billing features enabled. Calling this operation sends a handshake to every invited account in the organization. The feature set change can be finalized and the additional features enabled only after all administrators in the invited accounts approve the change by accepting the handshake.</p> </important> <p>After you enable all features, you can separately enable or disable individual policy types in a root using <a>EnablePolicyType</a> and <a>DisablePolicyType</a>. To see the status of policy types in a root, use <a>ListRoots</a>.</p> <p>After all invited member accounts accept the handshake, you finalize the feature set change by accepting the handshake that contains <code>"Action": "ENABLE_ALL_FEATURES"</code>. This completes the change.</p> <p>After you enable all features in your organization, the master account in the organization can apply policies on all member accounts. These policies can restrict what users and even administrators in those accounts can do. The master account can apply policies that prevent accounts from leaving the organization. Ensure that your account administrators are aware of this.</p> <p>This operation can be called only from the organization's master account. </p>
+    fn enable_all_features(
+        &self,
+    ) -> RusotoFuture<EnableAllFeaturesResponse, EnableAllFeaturesError> {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnableAllFeatures");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.EnableAllFeatures",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnableAllFeaturesResponse, _>(
+                    response,
+                    "EnableAllFeatures",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableAllFeaturesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables a policy type in a root. After you enable a policy type in a root, you can attach policies of that type to the root, any organizational unit (OU), or account in that root. You can undo this by using the <a>DisablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p>You can enable a policy type in a root only if that policy type is available in the organization. To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
+    fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> RusotoFuture<EnablePolicyTypeResponse, EnablePolicyTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("EnablePolicyType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.EnablePolicyType");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnablePolicyTypeResponse, _>(
+                    response,
+                    "EnablePolicyType",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnablePolicyTypeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sends an invitation to another account to join your organization as a member account. AWS Organizations sends email on your behalf to the email address that is associated with the other account's owner. The invitation is implemented as a <a>Handshake</a> whose details are in the response.</p> <important> <ul> <li> <p>You can invite AWS accounts only from the same seller as the master account. For example, if your organization's master account was created by Amazon Internet Services Pvt. Ltd (AISPL), an AWS seller in India, you can invite only other AISPL accounts to your organization. You can't combine accounts from AISPL and AWS or from any other AWS seller. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/useconsolidatedbilliing-India.html">Consolidated Billing in India</a>.</p> </li> <li> <p>If you receive an exception that indicates that you exceeded your account limits for the organization or that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists after an hour, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> </ul> </important> <p>This operation can be called only from the organization's master account.</p>
+    fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> RusotoFuture<InviteAccountToOrganizationResponse, InviteAccountToOrganizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("InviteAccountToOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.InviteAccountToOrganization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<InviteAccountToOrganizationResponse, _>(
+                    response,
+                    "InviteAccountToOrganization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(InviteAccountToOrganizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do, including preventing them from successfully calling <code>LeaveOrganization</code> and leaving the organization. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must accept the end user license agreement (EULA), choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
+    fn leave_organization(&self) -> RusotoFuture<(), LeaveOrganizationError> {
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("LeaveOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.LeaveOrganization",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(LeaveOrganizationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of the AWS services that you enabled to integrate with your organization. After a service on this list creates the resources that it requires for the integration, it can perform operations on your organization and its accounts.</p> <p>For more information about integrating other services with AWS Organizations, including the list of services that currently work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> RusotoFuture<
+        ListAWSServiceAccessForOrganizationResponse,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAWSServiceAccessForOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListAWSServiceAccessForOrganization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAWSServiceAccessForOrganizationResponse, _>(response, "ListAWSServiceAccessForOrganization")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAWSServiceAccessForOrganizationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all the accounts in the organization. To request only the accounts in a specified root or organizational unit (OU), use the <a>ListAccountsForParent</a> operation instead.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAccounts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListAccounts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAccountsResponse, _>(
+                    response,
+                    "ListAccounts",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAccountsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the accounts in an organization that are contained by the specified target root or organizational unit (OU). If you specify the root, you get a list of all the accounts that aren't in any OU. If you specify an OU, you get a list of all the accounts in only that OU and not in any child OUs. To get a list of all accounts in the organization, use the <a>ListAccounts</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> RusotoFuture<ListAccountsForParentResponse, ListAccountsForParentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListAccountsForParent");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListAccountsForParent",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAccountsForParentResponse, _>(
+                    response,
+                    "ListAccountsForParent",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListAccountsForParentError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all of the organizational units (OUs) or accounts that are contained in the specified parent OU or root. This operation, along with <a>ListParents</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> RusotoFuture<ListChildrenResponse, ListChildrenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListChildren");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListChildren");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListChildrenResponse, _>(
+                    response,
+                    "ListChildren",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListChildrenError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the account creation requests that match the specified status that is currently being tracked for the organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> RusotoFuture<ListCreateAccountStatusResponse, ListCreateAccountStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListCreateAccountStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListCreateAccountStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCreateAccountStatusResponse, _>(
+                    response,
+                    "ListCreateAccountStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListCreateAccountStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the current handshakes that are associated with the account of the requesting user.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called from any account in the organization.</p>
+    fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> RusotoFuture<ListHandshakesForAccountResponse, ListHandshakesForAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListHandshakesForAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListHandshakesForAccount",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListHandshakesForAccountResponse, _>(
+                    response,
+                    "ListHandshakesForAccount",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListHandshakesForAccountError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the handshakes that are associated with the organization that the requesting user is part of. The <code>ListHandshakesForOrganization</code> operation returns a list of handshake structures. Each structure contains details and status about a handshake.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> RusotoFuture<ListHandshakesForOrganizationResponse, ListHandshakesForOrganizationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListHandshakesForOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListHandshakesForOrganization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListHandshakesForOrganizationResponse, _>(
+                    response,
+                    "ListHandshakesForOrganization",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListHandshakesForOrganizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the organizational units (OUs) in a parent organizational unit or root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> RusotoFuture<ListOrganizationalUnitsForParentResponse, ListOrganizationalUnitsForParentError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListOrganizationalUnitsForParent");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListOrganizationalUnitsForParent",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOrganizationalUnitsForParentResponse, _>(
+                    response,
+                    "ListOrganizationalUnitsForParent",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListOrganizationalUnitsForParentError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Lists the root or organizational units (OUs) that serve as the immediate parent of the specified child OU or account. This operation, along with <a>ListChildren</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>In the current release, a child can have only a single parent. </p> </note></p>
+    fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> RusotoFuture<ListParentsResponse, ListParentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListParents");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListParents");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListParentsResponse, _>(
+                    response,
+                    "ListParents",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListParentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the list of all policies in an organization of a specified type.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListPolicies");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListPolicies");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPoliciesResponse, _>(
+                    response,
+                    "ListPolicies",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPoliciesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the policies that are directly attached to the specified target root, organizational unit (OU), or account. You must specify the policy type that you want included in the returned list.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> RusotoFuture<ListPoliciesForTargetResponse, ListPoliciesForTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListPoliciesForTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListPoliciesForTarget",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPoliciesForTargetResponse, _>(
+                    response,
+                    "ListPoliciesForTarget",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListPoliciesForTargetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists the roots that are defined in the current organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account.</p> <note> <p>Policy types can be enabled and disabled in roots. This is distinct from whether they&#39;re available in the organization. When you enable all features, you make policy types available for use in that organization. Individual policy types can then be enabled and disabled in a root. To see the availability of a policy type in an organization, use <a>DescribeOrganization</a>.</p> </note></p>
+    fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> RusotoFuture<ListRootsResponse, ListRootsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListRoots");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListRoots");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRootsResponse, _>(response, "ListRoots")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRootsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists tags for the specified resource. </p> <p>Currently, you can list tags on an account in AWS Organizations.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListTagsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all the roots, organizational units (OUs), and accounts that the specified policy is attached to.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account.</p>
+    fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("ListTargetsForPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.ListTargetsForPolicy",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTargetsForPolicyResponse, _>(
+                    response,
+                    "ListTargetsForPolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTargetsForPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Moves an account from its current source parent root or organizational unit (OU) to the specified destination parent root or OU.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn move_account(&self, input: MoveAccountRequest) -> RusotoFuture<(), MoveAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("MoveAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.MoveAccount");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(MoveAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Removes the specified account from the organization.</p> <p>The removed account becomes a standalone account that isn&#39;t a member of any organization. It&#39;s no longer subject to any policies and is responsible for its own bill payments. The organization&#39;s master account is no longer charged for any expenses accrued by the member account after it&#39;s removed from the organization.</p> <p>This operation can be called only from the organization&#39;s master account. Member accounts can remove themselves with <a>LeaveOrganization</a> instead.</p> <important> <p>You can remove an account from your organization only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For an account that you want to make standalone, you must accept the end user license agreement (EULA), choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. To remove an account that doesn&#39;t yet have this information, you must sign in as the member account and follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </important></p>
+    fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> RusotoFuture<(), RemoveAccountFromOrganizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("RemoveAccountFromOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.RemoveAccountFromOrganization",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveAccountFromOrganizationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds one or more tags to the specified resource.</p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a tag from the specified resource. </p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Renames the specified organizational unit (OU). The ID and ARN don't change. The child OUs and accounts remain in place, and any attached policies of the OU remain attached. </p> <p>This operation can be called only from the organization's master account.</p>
+    fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> RusotoFuture<UpdateOrganizationalUnitResponse, UpdateOrganizationalUnitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UpdateOrganizationalUnit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSOrganizationsV20161128.UpdateOrganizationalUnit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateOrganizationalUnitResponse, _>(
+                    response,
+                    "UpdateOrganizationalUnit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateOrganizationalUnitError::from_response(response))
                 }))
             }
-        })
+        })
+    }
+
+    /// <p>Updates an existing policy with a new name, description, or content. If you don't supply any parameter, that value remains unchanged. You can't change a policy's type.</p> <p>This operation can be called only from the organization's master account.</p>
+    fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
+        request.set_operation("UpdatePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSOrganizationsV20161128.UpdatePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePolicyResponse, _>(
+                    response,
+                    "UpdatePolicy",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePolicyError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Organizations API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockOrganizations {
+    accept_handshake: Option<
+        Box<
+            dyn Fn(
+                    AcceptHandshakeRequest,
+                ) -> ::std::result::Result<
+                    AcceptHandshakeResponse,
+                    RusotoError<AcceptHandshakeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    attach_policy: Option<
+        Box<
+            dyn Fn(AttachPolicyRequest) -> ::std::result::Result<(), RusotoError<AttachPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_handshake: Option<
+        Box<
+            dyn Fn(
+                    CancelHandshakeRequest,
+                ) -> ::std::result::Result<
+                    CancelHandshakeResponse,
+                    RusotoError<CancelHandshakeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_account: Option<
+        Box<
+            dyn Fn(
+                    CreateAccountRequest,
+                )
+                    -> ::std::result::Result<CreateAccountResponse, RusotoError<CreateAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_gov_cloud_account: Option<
+        Box<
+            dyn Fn(
+                    CreateGovCloudAccountRequest,
+                ) -> ::std::result::Result<
+                    CreateGovCloudAccountResponse,
+                    RusotoError<CreateGovCloudAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_organization: Option<
+        Box<
+            dyn Fn(
+                    CreateOrganizationRequest,
+                ) -> ::std::result::Result<
+                    CreateOrganizationResponse,
+                    RusotoError<CreateOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_organizational_unit: Option<
+        Box<
+            dyn Fn(
+                    CreateOrganizationalUnitRequest,
+                ) -> ::std::result::Result<
+                    CreateOrganizationalUnitResponse,
+                    RusotoError<CreateOrganizationalUnitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_policy: Option<
+        Box<
+            dyn Fn(
+                    CreatePolicyRequest,
+                )
+                    -> ::std::result::Result<CreatePolicyResponse, RusotoError<CreatePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    decline_handshake: Option<
+        Box<
+            dyn Fn(
+                    DeclineHandshakeRequest,
+                ) -> ::std::result::Result<
+                    DeclineHandshakeResponse,
+                    RusotoError<DeclineHandshakeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_organization: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<(), RusotoError<DeleteOrganizationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_organizational_unit: Option<
+        Box<
+            dyn Fn(
+                    DeleteOrganizationalUnitRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteOrganizationalUnitError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_policy: Option<
+        Box<
+            dyn Fn(DeletePolicyRequest) -> ::std::result::Result<(), RusotoError<DeletePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_account: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccountRequest,
+                ) -> ::std::result::Result<
+                    DescribeAccountResponse,
+                    RusotoError<DescribeAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_create_account_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeCreateAccountStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeCreateAccountStatusResponse,
+                    RusotoError<DescribeCreateAccountStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_handshake: Option<
+        Box<
+            dyn Fn(
+                    DescribeHandshakeRequest,
+                ) -> ::std::result::Result<
+                    DescribeHandshakeResponse,
+                    RusotoError<DescribeHandshakeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_organization: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeOrganizationResponse,
+                    RusotoError<DescribeOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_organizational_unit: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrganizationalUnitRequest,
+                ) -> ::std::result::Result<
+                    DescribeOrganizationalUnitResponse,
+                    RusotoError<DescribeOrganizationalUnitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_policy: Option<
+        Box<
+            dyn Fn(
+                    DescribePolicyRequest,
+                ) -> ::std::result::Result<
+                    DescribePolicyResponse,
+                    RusotoError<DescribePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_policy: Option<
+        Box<
+            dyn Fn(DetachPolicyRequest) -> ::std::result::Result<(), RusotoError<DetachPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_aws_service_access: Option<
+        Box<
+            dyn Fn(
+                    DisableAWSServiceAccessRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DisableAWSServiceAccessError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_policy_type: Option<
+        Box<
+            dyn Fn(
+                    DisablePolicyTypeRequest,
+                ) -> ::std::result::Result<
+                    DisablePolicyTypeResponse,
+                    RusotoError<DisablePolicyTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_aws_service_access: Option<
+        Box<
+            dyn Fn(
+                    EnableAWSServiceAccessRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<EnableAWSServiceAccessError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_all_features: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    EnableAllFeaturesResponse,
+                    RusotoError<EnableAllFeaturesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_policy_type: Option<
+        Box<
+            dyn Fn(
+                    EnablePolicyTypeRequest,
+                ) -> ::std::result::Result<
+                    EnablePolicyTypeResponse,
+                    RusotoError<EnablePolicyTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    invite_account_to_organization: Option<
+        Box<
+            dyn Fn(
+                    InviteAccountToOrganizationRequest,
+                ) -> ::std::result::Result<
+                    InviteAccountToOrganizationResponse,
+                    RusotoError<InviteAccountToOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    leave_organization: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<(), RusotoError<LeaveOrganizationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_aws_service_access_for_organization: Option<
+        Box<
+            dyn Fn(
+                    ListAWSServiceAccessForOrganizationRequest,
+                ) -> ::std::result::Result<
+                    ListAWSServiceAccessForOrganizationResponse,
+                    RusotoError<ListAWSServiceAccessForOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_accounts: Option<
+        Box<
+            dyn Fn(
+                    ListAccountsRequest,
+                )
+                    -> ::std::result::Result<ListAccountsResponse, RusotoError<ListAccountsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_accounts_for_parent: Option<
+        Box<
+            dyn Fn(
+                    ListAccountsForParentRequest,
+                ) -> ::std::result::Result<
+                    ListAccountsForParentResponse,
+                    RusotoError<ListAccountsForParentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_children: Option<
+        Box<
+            dyn Fn(
+                    ListChildrenRequest,
+                )
+                    -> ::std::result::Result<ListChildrenResponse, RusotoError<ListChildrenError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_create_account_status: Option<
+        Box<
+            dyn Fn(
+                    ListCreateAccountStatusRequest,
+                ) -> ::std::result::Result<
+                    ListCreateAccountStatusResponse,
+                    RusotoError<ListCreateAccountStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_handshakes_for_account: Option<
+        Box<
+            dyn Fn(
+                    ListHandshakesForAccountRequest,
+                ) -> ::std::result::Result<
+                    ListHandshakesForAccountResponse,
+                    RusotoError<ListHandshakesForAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_handshakes_for_organization: Option<
+        Box<
+            dyn Fn(
+                    ListHandshakesForOrganizationRequest,
+                ) -> ::std::result::Result<
+                    ListHandshakesForOrganizationResponse,
+                    RusotoError<ListHandshakesForOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_organizational_units_for_parent: Option<
+        Box<
+            dyn Fn(
+                    ListOrganizationalUnitsForParentRequest,
+                ) -> ::std::result::Result<
+                    ListOrganizationalUnitsForParentResponse,
+                    RusotoError<ListOrganizationalUnitsForParentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_parents: Option<
+        Box<
+            dyn Fn(
+                    ListParentsRequest,
+                )
+                    -> ::std::result::Result<ListParentsResponse, RusotoError<ListParentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_policies: Option<
+        Box<
+            dyn Fn(
+                    ListPoliciesRequest,
+                )
+                    -> ::std::result::Result<ListPoliciesResponse, RusotoError<ListPoliciesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_policies_for_target: Option<
+        Box<
+            dyn Fn(
+                    ListPoliciesForTargetRequest,
+                ) -> ::std::result::Result<
+                    ListPoliciesForTargetResponse,
+                    RusotoError<ListPoliciesForTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_roots: Option<
+        Box<
+            dyn Fn(
+                    ListRootsRequest,
+                )
+                    -> ::std::result::Result<ListRootsResponse, RusotoError<ListRootsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_targets_for_policy: Option<
+        Box<
+            dyn Fn(
+                    ListTargetsForPolicyRequest,
+                ) -> ::std::result::Result<
+                    ListTargetsForPolicyResponse,
+                    RusotoError<ListTargetsForPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    move_account: Option<
+        Box<
+            dyn Fn(MoveAccountRequest) -> ::std::result::Result<(), RusotoError<MoveAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_account_from_organization: Option<
+        Box<
+            dyn Fn(
+                    RemoveAccountFromOrganizationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveAccountFromOrganizationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_organizational_unit: Option<
+        Box<
+            dyn Fn(
+                    UpdateOrganizationalUnitRequest,
+                ) -> ::std::result::Result<
+                    UpdateOrganizationalUnitResponse,
+                    RusotoError<UpdateOrganizationalUnitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_policy: Option<
+        Box<
+            dyn Fn(
+                    UpdatePolicyRequest,
+                )
+                    -> ::std::result::Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockOrganizations {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockOrganizations {
+        Default::default()
+    }
+
+    /// Answers `accept_handshake` with what `handler` returns for its input.
+    pub fn with_accept_handshake<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                AcceptHandshakeRequest,
+            )
+                -> ::std::result::Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_handshake = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_policy` with what `handler` returns for its input.
+    pub fn with_attach_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(AttachPolicyRequest) -> ::std::result::Result<(), RusotoError<AttachPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_handshake` with what `handler` returns for its input.
+    pub fn with_cancel_handshake<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                CancelHandshakeRequest,
+            )
+                -> ::std::result::Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_handshake = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_account` with what `handler` returns for its input.
+    pub fn with_create_account<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                CreateAccountRequest,
+            )
+                -> ::std::result::Result<CreateAccountResponse, RusotoError<CreateAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_gov_cloud_account` with what `handler` returns for its input.
+    pub fn with_create_gov_cloud_account<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                CreateGovCloudAccountRequest,
+            ) -> ::std::result::Result<
+                CreateGovCloudAccountResponse,
+                RusotoError<CreateGovCloudAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_gov_cloud_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_organization` with what `handler` returns for its input.
+    pub fn with_create_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                CreateOrganizationRequest,
+            ) -> ::std::result::Result<
+                CreateOrganizationResponse,
+                RusotoError<CreateOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_organizational_unit` with what `handler` returns for its input.
+    pub fn with_create_organizational_unit<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                CreateOrganizationalUnitRequest,
+            ) -> ::std::result::Result<
+                CreateOrganizationalUnitResponse,
+                RusotoError<CreateOrganizationalUnitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_organizational_unit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_policy` with what `handler` returns for its input.
+    pub fn with_create_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                CreatePolicyRequest,
+            )
+                -> ::std::result::Result<CreatePolicyResponse, RusotoError<CreatePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decline_handshake` with what `handler` returns for its input.
+    pub fn with_decline_handshake<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DeclineHandshakeRequest,
+            ) -> ::std::result::Result<
+                DeclineHandshakeResponse,
+                RusotoError<DeclineHandshakeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.decline_handshake = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_organization` with what `handler` returns.
+    pub fn with_delete_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn() -> ::std::result::Result<(), RusotoError<DeleteOrganizationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_organizational_unit` with what `handler` returns for its input.
+    pub fn with_delete_organizational_unit<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DeleteOrganizationalUnitRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteOrganizationalUnitError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_organizational_unit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_policy` with what `handler` returns for its input.
+    pub fn with_delete_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(DeletePolicyRequest) -> ::std::result::Result<(), RusotoError<DeletePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account` with what `handler` returns for its input.
+    pub fn with_describe_account<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DescribeAccountRequest,
+            )
+                -> ::std::result::Result<DescribeAccountResponse, RusotoError<DescribeAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_create_account_status` with what `handler` returns for its input.
+    pub fn with_describe_create_account_status<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DescribeCreateAccountStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeCreateAccountStatusResponse,
+                RusotoError<DescribeCreateAccountStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_create_account_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_handshake` with what `handler` returns for its input.
+    pub fn with_describe_handshake<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DescribeHandshakeRequest,
+            ) -> ::std::result::Result<
+                DescribeHandshakeResponse,
+                RusotoError<DescribeHandshakeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_handshake = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_organization` with what `handler` returns.
+    pub fn with_describe_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeOrganizationResponse,
+                RusotoError<DescribeOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_organizational_unit` with what `handler` returns for its input.
+    pub fn with_describe_organizational_unit<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DescribeOrganizationalUnitRequest,
+            ) -> ::std::result::Result<
+                DescribeOrganizationalUnitResponse,
+                RusotoError<DescribeOrganizationalUnitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_organizational_unit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_policy` with what `handler` returns for its input.
+    pub fn with_describe_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DescribePolicyRequest,
+            )
+                -> ::std::result::Result<DescribePolicyResponse, RusotoError<DescribePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_policy` with what `handler` returns for its input.
+    pub fn with_detach_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(DetachPolicyRequest) -> ::std::result::Result<(), RusotoError<DetachPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_aws_service_access` with what `handler` returns for its input.
+    pub fn with_disable_aws_service_access<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DisableAWSServiceAccessRequest,
+            ) -> ::std::result::Result<(), RusotoError<DisableAWSServiceAccessError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_aws_service_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_policy_type` with what `handler` returns for its input.
+    pub fn with_disable_policy_type<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                DisablePolicyTypeRequest,
+            ) -> ::std::result::Result<
+                DisablePolicyTypeResponse,
+                RusotoError<DisablePolicyTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_policy_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_aws_service_access` with what `handler` returns for its input.
+    pub fn with_enable_aws_service_access<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                EnableAWSServiceAccessRequest,
+            ) -> ::std::result::Result<(), RusotoError<EnableAWSServiceAccessError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_aws_service_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_all_features` with what `handler` returns.
+    pub fn with_enable_all_features<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn() -> ::std::result::Result<
+                EnableAllFeaturesResponse,
+                RusotoError<EnableAllFeaturesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_all_features = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_policy_type` with what `handler` returns for its input.
+    pub fn with_enable_policy_type<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                EnablePolicyTypeRequest,
+            ) -> ::std::result::Result<
+                EnablePolicyTypeResponse,
+                RusotoError<EnablePolicyTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_policy_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invite_account_to_organization` with what `handler` returns for its input.
+    pub fn with_invite_account_to_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                InviteAccountToOrganizationRequest,
+            ) -> ::std::result::Result<
+                InviteAccountToOrganizationResponse,
+                RusotoError<InviteAccountToOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.invite_account_to_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `leave_organization` with what `handler` returns.
+    pub fn with_leave_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn() -> ::std::result::Result<(), RusotoError<LeaveOrganizationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.leave_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_aws_service_access_for_organization` with what `handler` returns for its input.
+    pub fn with_list_aws_service_access_for_organization<F>(
+        mut self,
+        handler: F,
+    ) -> MockOrganizations
+    where
+        F: Fn(
+                ListAWSServiceAccessForOrganizationRequest,
+            ) -> ::std::result::Result<
+                ListAWSServiceAccessForOrganizationResponse,
+                RusotoError<ListAWSServiceAccessForOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_aws_service_access_for_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_accounts` with what `handler` returns for its input.
+    pub fn with_list_accounts<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListAccountsRequest,
+            )
+                -> ::std::result::Result<ListAccountsResponse, RusotoError<ListAccountsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_accounts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_accounts_for_parent` with what `handler` returns for its input.
+    pub fn with_list_accounts_for_parent<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListAccountsForParentRequest,
+            ) -> ::std::result::Result<
+                ListAccountsForParentResponse,
+                RusotoError<ListAccountsForParentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_accounts_for_parent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_children` with what `handler` returns for its input.
+    pub fn with_list_children<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListChildrenRequest,
+            )
+                -> ::std::result::Result<ListChildrenResponse, RusotoError<ListChildrenError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_children = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_create_account_status` with what `handler` returns for its input.
+    pub fn with_list_create_account_status<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListCreateAccountStatusRequest,
+            ) -> ::std::result::Result<
+                ListCreateAccountStatusResponse,
+                RusotoError<ListCreateAccountStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_create_account_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_handshakes_for_account` with what `handler` returns for its input.
+    pub fn with_list_handshakes_for_account<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListHandshakesForAccountRequest,
+            ) -> ::std::result::Result<
+                ListHandshakesForAccountResponse,
+                RusotoError<ListHandshakesForAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_handshakes_for_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_handshakes_for_organization` with what `handler` returns for its input.
+    pub fn with_list_handshakes_for_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListHandshakesForOrganizationRequest,
+            ) -> ::std::result::Result<
+                ListHandshakesForOrganizationResponse,
+                RusotoError<ListHandshakesForOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_handshakes_for_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_organizational_units_for_parent` with what `handler` returns for its input.
+    pub fn with_list_organizational_units_for_parent<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListOrganizationalUnitsForParentRequest,
+            ) -> ::std::result::Result<
+                ListOrganizationalUnitsForParentResponse,
+                RusotoError<ListOrganizationalUnitsForParentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_organizational_units_for_parent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_parents` with what `handler` returns for its input.
+    pub fn with_list_parents<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListParentsRequest,
+            )
+                -> ::std::result::Result<ListParentsResponse, RusotoError<ListParentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_parents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_policies` with what `handler` returns for its input.
+    pub fn with_list_policies<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListPoliciesRequest,
+            )
+                -> ::std::result::Result<ListPoliciesResponse, RusotoError<ListPoliciesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_policies_for_target` with what `handler` returns for its input.
+    pub fn with_list_policies_for_target<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListPoliciesForTargetRequest,
+            ) -> ::std::result::Result<
+                ListPoliciesForTargetResponse,
+                RusotoError<ListPoliciesForTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_policies_for_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_roots` with what `handler` returns for its input.
+    pub fn with_list_roots<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListRootsRequest,
+            ) -> ::std::result::Result<ListRootsResponse, RusotoError<ListRootsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_roots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_targets_for_policy` with what `handler` returns for its input.
+    pub fn with_list_targets_for_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                ListTargetsForPolicyRequest,
+            ) -> ::std::result::Result<
+                ListTargetsForPolicyResponse,
+                RusotoError<ListTargetsForPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_targets_for_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `move_account` with what `handler` returns for its input.
+    pub fn with_move_account<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(MoveAccountRequest) -> ::std::result::Result<(), RusotoError<MoveAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.move_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_account_from_organization` with what `handler` returns for its input.
+    pub fn with_remove_account_from_organization<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                RemoveAccountFromOrganizationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<RemoveAccountFromOrganizationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_account_from_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_organizational_unit` with what `handler` returns for its input.
+    pub fn with_update_organizational_unit<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                UpdateOrganizationalUnitRequest,
+            ) -> ::std::result::Result<
+                UpdateOrganizationalUnitResponse,
+                RusotoError<UpdateOrganizationalUnitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_organizational_unit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_policy` with what `handler` returns for its input.
+    pub fn with_update_policy<F>(mut self, handler: F) -> MockOrganizations
+    where
+        F: Fn(
+                UpdatePolicyRequest,
+            )
+                -> ::std::result::Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_policy = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Organizations for MockOrganizations {
+    fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> RusotoFuture<AcceptHandshakeResponse, AcceptHandshakeError> {
+        match self.accept_handshake {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for accept_handshake"),
+        }
+    }
+    fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
+        match self.attach_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for attach_policy"),
+        }
+    }
+    fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> RusotoFuture<CancelHandshakeResponse, CancelHandshakeError> {
+        match self.cancel_handshake {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for cancel_handshake"),
+        }
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        match self.create_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for create_account"),
+        }
+    }
+    fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> RusotoFuture<CreateGovCloudAccountResponse, CreateGovCloudAccountError> {
+        match self.create_gov_cloud_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for create_gov_cloud_account"),
+        }
+    }
+    fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> RusotoFuture<CreateOrganizationResponse, CreateOrganizationError> {
+        match self.create_organization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for create_organization"),
+        }
+    }
+    fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> RusotoFuture<CreateOrganizationalUnitResponse, CreateOrganizationalUnitError> {
+        match self.create_organizational_unit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for create_organizational_unit"),
+        }
+    }
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        match self.create_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for create_policy"),
+        }
+    }
+    fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> RusotoFuture<DeclineHandshakeResponse, DeclineHandshakeError> {
+        match self.decline_handshake {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for decline_handshake"),
+        }
+    }
+    fn delete_organization(&self) -> RusotoFuture<(), DeleteOrganizationError> {
+        match self.delete_organization {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOrganizations has no handler for delete_organization"),
+        }
+    }
+    fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationalUnitError> {
+        match self.delete_organizational_unit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for delete_organizational_unit"),
+        }
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        match self.delete_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for delete_policy"),
+        }
+    }
+    fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> RusotoFuture<DescribeAccountResponse, DescribeAccountError> {
+        match self.describe_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for describe_account"),
+        }
+    }
+    fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> RusotoFuture<DescribeCreateAccountStatusResponse, DescribeCreateAccountStatusError> {
+        match self.describe_create_account_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for describe_create_account_status"),
+        }
+    }
+    fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> RusotoFuture<DescribeHandshakeResponse, DescribeHandshakeError> {
+        match self.describe_handshake {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for describe_handshake"),
+        }
+    }
+    fn describe_organization(
+        &self,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        match self.describe_organization {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOrganizations has no handler for describe_organization"),
+        }
+    }
+    fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> RusotoFuture<DescribeOrganizationalUnitResponse, DescribeOrganizationalUnitError> {
+        match self.describe_organizational_unit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for describe_organizational_unit"),
+        }
+    }
+    fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> RusotoFuture<DescribePolicyResponse, DescribePolicyError> {
+        match self.describe_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for describe_policy"),
+        }
+    }
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
+        match self.detach_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for detach_policy"),
+        }
+    }
+    fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), DisableAWSServiceAccessError> {
+        match self.disable_aws_service_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for disable_aws_service_access"),
+        }
+    }
+    fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> RusotoFuture<DisablePolicyTypeResponse, DisablePolicyTypeError> {
+        match self.disable_policy_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for disable_policy_type"),
+        }
+    }
+    fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), EnableAWSServiceAccessError> {
+        match self.enable_aws_service_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for enable_aws_service_access"),
+        }
+    }
+    fn enable_all_features(
+        &self,
+    ) -> RusotoFuture<EnableAllFeaturesResponse, EnableAllFeaturesError> {
+        match self.enable_all_features {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOrganizations has no handler for enable_all_features"),
+        }
+    }
+    fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> RusotoFuture<EnablePolicyTypeResponse, EnablePolicyTypeError> {
+        match self.enable_policy_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for enable_policy_type"),
+        }
+    }
+    fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> RusotoFuture<InviteAccountToOrganizationResponse, InviteAccountToOrganizationError> {
+        match self.invite_account_to_organization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for invite_account_to_organization"),
+        }
+    }
+    fn leave_organization(&self) -> RusotoFuture<(), LeaveOrganizationError> {
+        match self.leave_organization {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockOrganizations has no handler for leave_organization"),
+        }
+    }
+    fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> RusotoFuture<
+        ListAWSServiceAccessForOrganizationResponse,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        match self.list_aws_service_access_for_organization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockOrganizations has no handler for list_aws_service_access_for_organization"
+            ),
+        }
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        match self.list_accounts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_accounts"),
+        }
+    }
+    fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> RusotoFuture<ListAccountsForParentResponse, ListAccountsForParentError> {
+        match self.list_accounts_for_parent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_accounts_for_parent"),
+        }
+    }
+    fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> RusotoFuture<ListChildrenResponse, ListChildrenError> {
+        match self.list_children {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_children"),
+        }
+    }
+    fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> RusotoFuture<ListCreateAccountStatusResponse, ListCreateAccountStatusError> {
+        match self.list_create_account_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_create_account_status"),
+        }
+    }
+    fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> RusotoFuture<ListHandshakesForAccountResponse, ListHandshakesForAccountError> {
+        match self.list_handshakes_for_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_handshakes_for_account"),
+        }
+    }
+    fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> RusotoFuture<ListHandshakesForOrganizationResponse, ListHandshakesForOrganizationError>
+    {
+        match self.list_handshakes_for_organization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_handshakes_for_organization"),
+        }
+    }
+    fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> RusotoFuture<ListOrganizationalUnitsForParentResponse, ListOrganizationalUnitsForParentError>
+    {
+        match self.list_organizational_units_for_parent {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockOrganizations has no handler for list_organizational_units_for_parent")
+            }
+        }
+    }
+    fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> RusotoFuture<ListParentsResponse, ListParentsError> {
+        match self.list_parents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_parents"),
+        }
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        match self.list_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_policies"),
+        }
+    }
+    fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> RusotoFuture<ListPoliciesForTargetResponse, ListPoliciesForTargetError> {
+        match self.list_policies_for_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_policies_for_target"),
+        }
+    }
+    fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> RusotoFuture<ListRootsResponse, ListRootsError> {
+        match self.list_roots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_roots"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
+        match self.list_targets_for_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for list_targets_for_policy"),
+        }
+    }
+    fn move_account(&self, input: MoveAccountRequest) -> RusotoFuture<(), MoveAccountError> {
+        match self.move_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for move_account"),
+        }
+    }
+    fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> RusotoFuture<(), RemoveAccountFromOrganizationError> {
+        match self.remove_account_from_organization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for remove_account_from_organization"),
+        }
     }
-
-    /// <p>Adds one or more tags to the specified resource.</p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes a tag from the specified resource. </p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for untag_resource"),
+        }
     }
-
-    /// <p>Renames the specified organizational unit (OU). The ID and ARN don't change. The child OUs and accounts remain in place, and any attached policies of the OU remain attached. </p> <p>This operation can be called only from the organization's master account.</p>
     fn update_organizational_unit(
         &self,
         input: UpdateOrganizationalUnitRequest,
     ) -> RusotoFuture<UpdateOrganizationalUnitResponse, UpdateOrganizationalUnitError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSOrganizationsV20161128.UpdateOrganizationalUnit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateOrganizationalUnitResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateOrganizationalUnitError::from_response(response))
-                }))
-            }
-        })
+        match self.update_organizational_unit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for update_organizational_unit"),
+        }
     }
-
-    /// <p>Updates an existing policy with a new name, description, or content. If you don't supply any parameter, that value remains unchanged. You can't change a policy's type.</p> <p>This operation can be called only from the organization's master account.</p>
     fn update_policy(
         &self,
         input: UpdatePolicyRequest,
     ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError> {
-        let mut request = SignedRequest::new("POST", "organizations", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSOrganizationsV20161128.UpdatePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePolicyError::from_response(response))),
-                )
-            }
-        })
+        match self.update_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockOrganizations has no handler for update_policy"),
+        }
     }
 }
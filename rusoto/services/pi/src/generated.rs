@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A timestamp, and a single numerical value, which together represent a measurement at a particular point in time.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataPoint {
     /// <p>The time, in epoch format, associated with a particular <code>Value</code>.</p>
     #[serde(rename = "Timestamp")]
@@ -36,6 +37,7 @@ pub struct DataPoint {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDimensionKeysRequest {
     /// <p>The date and time specifying the end of the requested time series data. The value specified is <i>exclusive</i> - data points less than (but not equal to) <code>EndTime</code> will be returned.</p> <p>The value for <code>EndTime</code> must be later than the value for <code>StartTime</code>.</p>
     #[serde(rename = "EndTime")]
@@ -77,8 +79,55 @@ pub struct DescribeDimensionKeysRequest {
     pub start_time: f64,
 }
 
+impl Validate for DescribeDimensionKeysRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "filter"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        self.group_by
+            .validate_at(&field_path(path, "group_by"), errors);
+        errors.check_length(
+            &field_path(path, "identifier"),
+            self.identifier.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "identifier"),
+            &self.identifier,
+            "^[a-zA-Z0-9-]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        errors.check_length(
+            &field_path(path, "metric"),
+            self.metric.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "metric"), &self.metric, ".*\\S.*");
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[a-zA-Z0-9_=-]+$");
+        }
+        if let Some(ref value) = self.partition_by {
+            value.validate_at(&field_path(path, "partition_by"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDimensionKeysResponse {
     /// <p>The end time for the returned dimension keys, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedEndTime</code> will be greater than or equal to the value of the user-specified <code>Endtime</code>.</p>
     #[serde(rename = "AlignedEndTime")]
@@ -104,6 +153,7 @@ pub struct DescribeDimensionKeysResponse {
 
 /// <p>A logical grouping of Performance Insights metrics for a related subject area. For example, the <code>db.sql</code> dimension group consists of the following dimensions: <code>db.sql.id</code>, <code>db.sql.db_id</code>, <code>db.sql.statement</code>, and <code>db.sql.tokenized_id</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DimensionGroup {
     /// <p><p>A list of specific dimensions from a dimension group. If this parameter is not present, then it signifies that all of the dimensions in the group were requested, or are present in the response.</p> <p>Valid values for elements in the <code>Dimensions</code> array are:</p> <ul> <li> <p>db.user.id</p> </li> <li> <p>db.user.name</p> </li> <li> <p>db.host.id</p> </li> <li> <p>db.host.name</p> </li> <li> <p>db.sql.id</p> </li> <li> <p>db.sql.db<em>id</p> </li> <li> <p>db.sql.statement</p> </li> <li> <p>db.sql.tokenized</em>id</p> </li> <li> <p>db.sql<em>tokenized.id</p> </li> <li> <p>db.sql</em>tokenized.db<em>id</p> </li> <li> <p>db.sql</em>tokenized.statement</p> </li> <li> <p>db.wait<em>event.name</p> </li> <li> <p>db.wait</em>event.type</p> </li> <li> <p>db.wait<em>event</em>type.name</p> </li> </ul></p>
     #[serde(rename = "Dimensions")]
@@ -118,9 +168,37 @@ pub struct DimensionGroup {
     pub limit: Option<i64>,
 }
 
+impl Validate for DimensionGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dimensions {
+            errors.check_length(
+                &field_path(path, "dimensions"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "dimensions"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "group"),
+            self.group.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "group"), &self.group, ".*\\S.*");
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(25));
+        }
+    }
+}
+
 /// <p>An array of descriptions and aggregated values for each dimension within a dimension group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DimensionKeyDescription {
     /// <p>A map of name-value pairs for the dimensions in the group.</p>
     #[serde(rename = "Dimensions")]
@@ -137,6 +215,7 @@ pub struct DimensionKeyDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceMetricsRequest {
     /// <p>The date and time specifiying the end of the requested time series data. The value specified is <i>exclusive</i> - data points less than (but not equal to) <code>EndTime</code> will be returned.</p> <p>The value for <code>EndTime</code> must be later than the value for <code>StartTime</code>.</p>
     #[serde(rename = "EndTime")]
@@ -167,8 +246,46 @@ pub struct GetResourceMetricsRequest {
     pub start_time: f64,
 }
 
+impl Validate for GetResourceMetricsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "identifier"),
+            self.identifier.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "identifier"),
+            &self.identifier,
+            "^[a-zA-Z0-9-]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(25));
+        }
+        errors.check_length(
+            &field_path(path, "metric_queries"),
+            self.metric_queries.len(),
+            Some(1),
+            Some(15),
+        );
+        for (i, item) in self.metric_queries.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "metric_queries"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^[a-zA-Z0-9_=-]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceMetricsResponse {
     /// <p>The end time for the returned metrics, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedEndTime</code> will be greater than or equal to the value of the user-specified <code>Endtime</code>.</p>
     #[serde(rename = "AlignedEndTime")]
@@ -194,7 +311,7 @@ pub struct GetResourceMetricsResponse {
 
 /// <p>A time-ordered series of data points, correpsonding to a dimension of a Performance Insights metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MetricKeyDataPoints {
     /// <p>An array of timestamp-value pairs, representing measurements over a period of time.</p>
     #[serde(rename = "DataPoints")]
@@ -208,6 +325,7 @@ pub struct MetricKeyDataPoints {
 
 /// <p>A single query to be processed. You must provide the metric to query. If no other parameters are specified, Performance Insights returns all of the data points for that metric. You can optionally request that the data points be aggregated by dimension group ( <code>GroupBy</code>), and return only those data points that match your criteria (<code>Filter</code>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricQuery {
     /// <p><p>One or more filters to apply in the request. Restrictions:</p> <ul> <li> <p>Any number of filters by the same dimension, as specified in the <code>GroupBy</code> parameter.</p> </li> <li> <p>A single filter for any other dimension in this dimension group.</p> </li> </ul></p>
     #[serde(rename = "Filter")]
@@ -222,9 +340,31 @@ pub struct MetricQuery {
     pub metric: String,
 }
 
+impl Validate for MetricQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "filter"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.group_by {
+            value.validate_at(&field_path(path, "group_by"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "metric"),
+            self.metric.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "metric"), &self.metric, ".*\\S.*");
+    }
+}
+
 /// <p>If <code>PartitionBy</code> was specified in a <code>DescribeDimensionKeys</code> request, the dimensions are returned in an array. Each element in the array specifies one dimension. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResponsePartitionKey {
     /// <p>A dimension map that contains the dimension(s) for this partition.</p>
     #[serde(rename = "Dimensions")]
@@ -233,7 +373,7 @@ pub struct ResponsePartitionKey {
 
 /// <p>An object describing a Performance Insights metric and one or more dimensions for that metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResponseResourceMetricKey {
     /// <p>The valid dimensions for the metric.</p>
     #[serde(rename = "Dimensions")]
@@ -272,7 +412,12 @@ impl DescribeDimensionKeysError {
                 "NotAuthorizedException" => {
                     return RusotoError::Service(DescribeDimensionKeysError::NotAuthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -319,7 +464,12 @@ impl GetResourceMetricsError {
                 "NotAuthorizedException" => {
                     return RusotoError::Service(GetResourceMetricsError::NotAuthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -354,6 +504,49 @@ pub trait PerformanceInsights {
         input: GetResourceMetricsRequest,
     ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError>;
 }
+const _: Option<&dyn PerformanceInsights> = None;
+impl<'a, T: PerformanceInsights + ?Sized> PerformanceInsights for &'a T {
+    fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> RusotoFuture<DescribeDimensionKeysResponse, DescribeDimensionKeysError> {
+        (**self).describe_dimension_keys(input)
+    }
+    fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError> {
+        (**self).get_resource_metrics(input)
+    }
+}
+impl<T: PerformanceInsights + ?Sized> PerformanceInsights for Box<T> {
+    fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> RusotoFuture<DescribeDimensionKeysResponse, DescribeDimensionKeysError> {
+        (**self).describe_dimension_keys(input)
+    }
+    fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError> {
+        (**self).get_resource_metrics(input)
+    }
+}
+impl<T: PerformanceInsights + ?Sized> PerformanceInsights for ::std::sync::Arc<T> {
+    fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> RusotoFuture<DescribeDimensionKeysResponse, DescribeDimensionKeysError> {
+        (**self).describe_dimension_keys(input)
+    }
+    fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError> {
+        (**self).get_resource_metrics(input)
+    }
+}
 /// A client for the AWS PI API.
 #[derive(Clone)]
 pub struct PerformanceInsightsClient {
@@ -397,7 +590,16 @@ impl PerformanceInsights for PerformanceInsightsClient {
         &self,
         input: DescribeDimensionKeysRequest,
     ) -> RusotoFuture<DescribeDimensionKeysResponse, DescribeDimensionKeysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "pi", &self.region, "/");
+        request.set_operation("DescribeDimensionKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -409,10 +611,10 @@ impl PerformanceInsights for PerformanceInsightsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDimensionKeysResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeDimensionKeysResponse, _>(
+                    response,
+                    "DescribeDimensionKeys",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -428,7 +630,16 @@ impl PerformanceInsights for PerformanceInsightsClient {
         &self,
         input: GetResourceMetricsRequest,
     ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "pi", &self.region, "/");
+        request.set_operation("GetResourceMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -440,10 +651,10 @@ impl PerformanceInsights for PerformanceInsightsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourceMetricsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetResourceMetricsResponse, _>(
+                    response,
+                    "GetResourceMetrics",
+                )
             } else {
                 Box::new(
                     response
@@ -455,3 +666,95 @@ impl PerformanceInsights for PerformanceInsightsClient {
         })
     }
 }
+
+/// A mock of the AWS PI API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockPerformanceInsights {
+    describe_dimension_keys: Option<
+        Box<
+            dyn Fn(
+                    DescribeDimensionKeysRequest,
+                ) -> ::std::result::Result<
+                    DescribeDimensionKeysResponse,
+                    RusotoError<DescribeDimensionKeysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_metrics: Option<
+        Box<
+            dyn Fn(
+                    GetResourceMetricsRequest,
+                ) -> ::std::result::Result<
+                    GetResourceMetricsResponse,
+                    RusotoError<GetResourceMetricsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockPerformanceInsights {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockPerformanceInsights {
+        Default::default()
+    }
+
+    /// Answers `describe_dimension_keys` with what `handler` returns for its input.
+    pub fn with_describe_dimension_keys<F>(mut self, handler: F) -> MockPerformanceInsights
+    where
+        F: Fn(
+                DescribeDimensionKeysRequest,
+            ) -> ::std::result::Result<
+                DescribeDimensionKeysResponse,
+                RusotoError<DescribeDimensionKeysError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_dimension_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_metrics` with what `handler` returns for its input.
+    pub fn with_get_resource_metrics<F>(mut self, handler: F) -> MockPerformanceInsights
+    where
+        F: Fn(
+                GetResourceMetricsRequest,
+            ) -> ::std::result::Result<
+                GetResourceMetricsResponse,
+                RusotoError<GetResourceMetricsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_metrics = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl PerformanceInsights for MockPerformanceInsights {
+    fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> RusotoFuture<DescribeDimensionKeysResponse, DescribeDimensionKeysError> {
+        match self.describe_dimension_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPerformanceInsights has no handler for describe_dimension_keys"),
+        }
+    }
+    fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError> {
+        match self.get_resource_metrics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPerformanceInsights has no handler for get_resource_metrics"),
+        }
+    }
+}
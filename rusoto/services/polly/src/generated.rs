@@ -23,19 +23,28 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLexiconInput {
     /// <p>The name of the lexicon to delete. Must be an existing lexicon in the region.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteLexiconInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[0-9A-Za-z]{1,20}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLexiconOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVoicesInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) used by Amazon Polly when processing input text for speech synthesis. </p>
     #[serde(rename = "Engine")]
@@ -55,8 +64,21 @@ pub struct DescribeVoicesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeVoicesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVoicesOutput {
     /// <p>The pagination token to use in the next request to continue the listing of voices. <code>NextToken</code> is returned only if the response is truncated.</p>
     #[serde(rename = "NextToken")]
@@ -69,14 +91,21 @@ pub struct DescribeVoicesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLexiconInput {
     /// <p>Name of the lexicon.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for GetLexiconInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[0-9A-Za-z]{1,20}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLexiconOutput {
     /// <p>Lexicon object that provides name and the string content of the lexicon. </p>
     #[serde(rename = "Lexicon")]
@@ -89,14 +118,25 @@ pub struct GetLexiconOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSpeechSynthesisTaskInput {
     /// <p>The Amazon Polly generated identifier for a speech synthesis task.</p>
     #[serde(rename = "TaskId")]
     pub task_id: String,
 }
 
+impl Validate for GetSpeechSynthesisTaskInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "task_id"),
+            &self.task_id,
+            "^[a-zA-Z0-9_-]{1,100}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSpeechSynthesisTaskOutput {
     /// <p>SynthesisTask object that provides information from the requested task, including output format, creation time, task status, and so on.</p>
     #[serde(rename = "SynthesisTask")]
@@ -106,7 +146,7 @@ pub struct GetSpeechSynthesisTaskOutput {
 
 /// <p>Provides lexicon name and lexicon content in string format. For more information, see <a href="https://www.w3.org/TR/pronunciation-lexicon/">Pronunciation Lexicon Specification (PLS) Version 1.0</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Lexicon {
     /// <p>Lexicon content in string format. The content of a lexicon must be in PLS format.</p>
     #[serde(rename = "Content")]
@@ -120,7 +160,7 @@ pub struct Lexicon {
 
 /// <p>Contains metadata describing the lexicon such as the number of lexemes, language code, and so on. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LexiconAttributes {
     /// <p>Phonetic alphabet used in the lexicon. Valid values are <code>ipa</code> and <code>x-sampa</code>.</p>
     #[serde(rename = "Alphabet")]
@@ -150,7 +190,7 @@ pub struct LexiconAttributes {
 
 /// <p>Describes the content of the lexicon.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LexiconDescription {
     /// <p>Provides lexicon metadata.</p>
     #[serde(rename = "Attributes")]
@@ -163,6 +203,7 @@ pub struct LexiconDescription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLexiconsInput {
     /// <p>An opaque pagination token returned from previous <code>ListLexicons</code> operation. If present, indicates where to continue the list of lexicons.</p>
     #[serde(rename = "NextToken")]
@@ -170,8 +211,21 @@ pub struct ListLexiconsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListLexiconsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLexiconsOutput {
     /// <p>A list of lexicon names and attributes.</p>
     #[serde(rename = "Lexicons")]
@@ -184,6 +238,7 @@ pub struct ListLexiconsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSpeechSynthesisTasksInput {
     /// <p>Maximum number of speech synthesis tasks returned in a List operation.</p>
     #[serde(rename = "MaxResults")]
@@ -199,8 +254,24 @@ pub struct ListSpeechSynthesisTasksInput {
     pub status: Option<String>,
 }
 
+impl Validate for ListSpeechSynthesisTasksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSpeechSynthesisTasksOutput {
     /// <p>An opaque pagination token returned from the previous List operation in this request. If present, this indicates where to continue the listing.</p>
     #[serde(rename = "NextToken")]
@@ -213,6 +284,7 @@ pub struct ListSpeechSynthesisTasksOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLexiconInput {
     /// <p>Content of the PLS lexicon as string data.</p>
     #[serde(rename = "Content")]
@@ -222,11 +294,18 @@ pub struct PutLexiconInput {
     pub name: String,
 }
 
+impl Validate for PutLexiconInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[0-9A-Za-z]{1,20}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLexiconOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSpeechSynthesisTaskInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
@@ -274,8 +353,48 @@ pub struct StartSpeechSynthesisTaskInput {
     pub voice_id: String,
 }
 
+impl Validate for StartSpeechSynthesisTaskInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.lexicon_names {
+            errors.check_length(
+                &field_path(path, "lexicon_names"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "lexicon_names"), i);
+                errors.check_pattern(&item_path, &item, "[0-9A-Za-z]{1,20}");
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "output_s3_bucket_name"),
+            &self.output_s3_bucket_name,
+            "^[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9]$",
+        );
+        if let Some(ref value) = self.output_s3_key_prefix {
+            errors.check_pattern(
+                &field_path(path, "output_s3_key_prefix"),
+                &value,
+                "^[0-9a-zA-Z\\/\\!\\-_\\.\\*\\'\\(\\):;\\$@=+\\,\\?&]{0,800}$",
+            );
+        }
+        if let Some(ref value) = self.sns_topic_arn {
+            errors.check_pattern(&field_path(path, "sns_topic_arn"), &value, "^arn:aws(-(cn|iso(-b)?|us-gov))?:sns:[a-z0-9_-]{1,50}:\\d{12}:[a-zA-Z0-9_-]{1,256}$");
+        }
+        if let Some(ref value) = self.speech_mark_types {
+            errors.check_length(
+                &field_path(path, "speech_mark_types"),
+                value.len(),
+                None,
+                Some(4),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSpeechSynthesisTaskOutput {
     /// <p>SynthesisTask object that provides information and attributes about a newly submitted speech synthesis task.</p>
     #[serde(rename = "SynthesisTask")]
@@ -285,7 +404,7 @@ pub struct StartSpeechSynthesisTaskOutput {
 
 /// <p>SynthesisTask object that provides information about a speech synthesis task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SynthesisTask {
     /// <p>Timestamp for the time the synthesis task was started.</p>
     #[serde(rename = "CreationTime")]
@@ -350,6 +469,7 @@ pub struct SynthesisTask {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SynthesizeSpeechInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
@@ -386,6 +506,31 @@ pub struct SynthesizeSpeechInput {
     pub voice_id: String,
 }
 
+impl Validate for SynthesizeSpeechInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.lexicon_names {
+            errors.check_length(
+                &field_path(path, "lexicon_names"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "lexicon_names"), i);
+                errors.check_pattern(&item_path, &item, "[0-9A-Za-z]{1,20}");
+            }
+        }
+        if let Some(ref value) = self.speech_mark_types {
+            errors.check_length(
+                &field_path(path, "speech_mark_types"),
+                value.len(),
+                None,
+                Some(4),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SynthesizeSpeechOutput {
     /// <p> Stream containing the synthesized speech. </p>
@@ -398,7 +543,7 @@ pub struct SynthesizeSpeechOutput {
 
 /// <p>Description of the voice.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Voice {
     /// <p>Additional codes for languages available for the specified voice in addition to its default language. </p> <p>For example, the default language for Aditi is Indian English (en-IN) because it was first used for that language. Since Aditi is bilingual and fluent in both Indian English and Hindi, this parameter would show the code <code>hi-IN</code>.</p>
     #[serde(rename = "AdditionalLanguageCodes")]
@@ -449,7 +594,12 @@ impl DeleteLexiconError {
                 "ServiceFailureException" => {
                     return RusotoError::Service(DeleteLexiconError::ServiceFailure(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -488,7 +638,12 @@ impl DescribeVoicesError {
                 "ServiceFailureException" => {
                     return RusotoError::Service(DescribeVoicesError::ServiceFailure(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -527,7 +682,12 @@ impl GetLexiconError {
                 "ServiceFailureException" => {
                     return RusotoError::Service(GetLexiconError::ServiceFailure(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -577,7 +737,12 @@ impl GetSpeechSynthesisTaskError {
                         GetSpeechSynthesisTaskError::SynthesisTaskNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -617,7 +782,12 @@ impl ListLexiconsError {
                 "ServiceFailureException" => {
                     return RusotoError::Service(ListLexiconsError::ServiceFailure(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -660,7 +830,12 @@ impl ListSpeechSynthesisTasksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -726,7 +901,12 @@ impl PutLexiconError {
                 "UnsupportedPlsLanguageException" => {
                     return RusotoError::Service(PutLexiconError::UnsupportedPlsLanguage(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -844,7 +1024,12 @@ impl StartSpeechSynthesisTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -934,7 +1119,12 @@ impl SynthesizeSpeechError {
                 "TextLengthExceededException" => {
                     return RusotoError::Service(SynthesizeSpeechError::TextLengthExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1017,6 +1207,175 @@ pub trait Polly {
         input: SynthesizeSpeechInput,
     ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError>;
 }
+const _: Option<&dyn Polly> = None;
+impl<'a, T: Polly + ?Sized> Polly for &'a T {
+    fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> RusotoFuture<DeleteLexiconOutput, DeleteLexiconError> {
+        (**self).delete_lexicon(input)
+    }
+    fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> RusotoFuture<DescribeVoicesOutput, DescribeVoicesError> {
+        (**self).describe_voices(input)
+    }
+    fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> RusotoFuture<GetLexiconOutput, GetLexiconError> {
+        (**self).get_lexicon(input)
+    }
+    fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<GetSpeechSynthesisTaskOutput, GetSpeechSynthesisTaskError> {
+        (**self).get_speech_synthesis_task(input)
+    }
+    fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> RusotoFuture<ListLexiconsOutput, ListLexiconsError> {
+        (**self).list_lexicons(input)
+    }
+    fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> RusotoFuture<ListSpeechSynthesisTasksOutput, ListSpeechSynthesisTasksError> {
+        (**self).list_speech_synthesis_tasks(input)
+    }
+    fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> RusotoFuture<PutLexiconOutput, PutLexiconError> {
+        (**self).put_lexicon(input)
+    }
+    fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<StartSpeechSynthesisTaskOutput, StartSpeechSynthesisTaskError> {
+        (**self).start_speech_synthesis_task(input)
+    }
+    fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError> {
+        (**self).synthesize_speech(input)
+    }
+}
+impl<T: Polly + ?Sized> Polly for Box<T> {
+    fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> RusotoFuture<DeleteLexiconOutput, DeleteLexiconError> {
+        (**self).delete_lexicon(input)
+    }
+    fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> RusotoFuture<DescribeVoicesOutput, DescribeVoicesError> {
+        (**self).describe_voices(input)
+    }
+    fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> RusotoFuture<GetLexiconOutput, GetLexiconError> {
+        (**self).get_lexicon(input)
+    }
+    fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<GetSpeechSynthesisTaskOutput, GetSpeechSynthesisTaskError> {
+        (**self).get_speech_synthesis_task(input)
+    }
+    fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> RusotoFuture<ListLexiconsOutput, ListLexiconsError> {
+        (**self).list_lexicons(input)
+    }
+    fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> RusotoFuture<ListSpeechSynthesisTasksOutput, ListSpeechSynthesisTasksError> {
+        (**self).list_speech_synthesis_tasks(input)
+    }
+    fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> RusotoFuture<PutLexiconOutput, PutLexiconError> {
+        (**self).put_lexicon(input)
+    }
+    fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<StartSpeechSynthesisTaskOutput, StartSpeechSynthesisTaskError> {
+        (**self).start_speech_synthesis_task(input)
+    }
+    fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError> {
+        (**self).synthesize_speech(input)
+    }
+}
+impl<T: Polly + ?Sized> Polly for ::std::sync::Arc<T> {
+    fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> RusotoFuture<DeleteLexiconOutput, DeleteLexiconError> {
+        (**self).delete_lexicon(input)
+    }
+    fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> RusotoFuture<DescribeVoicesOutput, DescribeVoicesError> {
+        (**self).describe_voices(input)
+    }
+    fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> RusotoFuture<GetLexiconOutput, GetLexiconError> {
+        (**self).get_lexicon(input)
+    }
+    fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<GetSpeechSynthesisTaskOutput, GetSpeechSynthesisTaskError> {
+        (**self).get_speech_synthesis_task(input)
+    }
+    fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> RusotoFuture<ListLexiconsOutput, ListLexiconsError> {
+        (**self).list_lexicons(input)
+    }
+    fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> RusotoFuture<ListSpeechSynthesisTasksOutput, ListSpeechSynthesisTasksError> {
+        (**self).list_speech_synthesis_tasks(input)
+    }
+    fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> RusotoFuture<PutLexiconOutput, PutLexiconError> {
+        (**self).put_lexicon(input)
+    }
+    fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<StartSpeechSynthesisTaskOutput, StartSpeechSynthesisTaskError> {
+        (**self).start_speech_synthesis_task(input)
+    }
+    fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError> {
+        (**self).synthesize_speech(input)
+    }
+}
 /// A client for the Amazon Polly API.
 #[derive(Clone)]
 pub struct PollyClient {
@@ -1060,15 +1419,26 @@ impl Polly for PollyClient {
         &self,
         input: DeleteLexiconInput,
     ) -> RusotoFuture<DeleteLexiconOutput, DeleteLexiconError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "polly", &self.region, &request_uri);
+        request.set_operation("DeleteLexicon");
+        request.add_resource_id("Name", &input.name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteLexicon")
                         .deserialize::<DeleteLexiconOutput, _>()?;
 
                     Ok(result)
@@ -1089,9 +1459,19 @@ impl Polly for PollyClient {
         &self,
         input: DescribeVoicesInput,
     ) -> RusotoFuture<DescribeVoicesOutput, DescribeVoicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/voices";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("DescribeVoices");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1113,6 +1493,7 @@ impl Polly for PollyClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeVoices")
                         .deserialize::<DescribeVoicesOutput, _>()?;
 
                     Ok(result)
@@ -1133,15 +1514,27 @@ impl Polly for PollyClient {
         &self,
         input: GetLexiconInput,
     ) -> RusotoFuture<GetLexiconOutput, GetLexiconError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetLexicon");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetLexicon")
                         .deserialize::<GetLexiconOutput, _>()?;
 
                     Ok(result)
@@ -1162,15 +1555,27 @@ impl Polly for PollyClient {
         &self,
         input: GetSpeechSynthesisTaskInput,
     ) -> RusotoFuture<GetSpeechSynthesisTaskOutput, GetSpeechSynthesisTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/v1/synthesisTasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetSpeechSynthesisTask");
+        request.add_resource_id("TaskId", &input.task_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSpeechSynthesisTask")
                         .deserialize::<GetSpeechSynthesisTaskOutput, _>()?;
 
                     Ok(result)
@@ -1190,9 +1595,19 @@ impl Polly for PollyClient {
         &self,
         input: ListLexiconsInput,
     ) -> RusotoFuture<ListLexiconsOutput, ListLexiconsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/lexicons";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListLexicons");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1205,6 +1620,7 @@ impl Polly for PollyClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListLexicons")
                         .deserialize::<ListLexiconsOutput, _>()?;
 
                     Ok(result)
@@ -1225,9 +1641,19 @@ impl Polly for PollyClient {
         &self,
         input: ListSpeechSynthesisTasksInput,
     ) -> RusotoFuture<ListSpeechSynthesisTasksOutput, ListSpeechSynthesisTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListSpeechSynthesisTasks");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1246,6 +1672,7 @@ impl Polly for PollyClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListSpeechSynthesisTasks")
                         .deserialize::<ListSpeechSynthesisTasksOutput, _>()?;
 
                     Ok(result)
@@ -1263,9 +1690,19 @@ impl Polly for PollyClient {
         &self,
         input: PutLexiconInput,
     ) -> RusotoFuture<PutLexiconOutput, PutLexiconError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("PUT", "polly", &self.region, &request_uri);
+        request.set_operation("PutLexicon");
+        request.add_resource_id("Name", &input.name.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1275,6 +1712,7 @@ impl Polly for PollyClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutLexicon")
                         .deserialize::<PutLexiconOutput, _>()?;
 
                     Ok(result)
@@ -1295,9 +1733,19 @@ impl Polly for PollyClient {
         &self,
         input: StartSpeechSynthesisTaskInput,
     ) -> RusotoFuture<StartSpeechSynthesisTaskOutput, StartSpeechSynthesisTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("StartSpeechSynthesisTask");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1307,6 +1755,7 @@ impl Polly for PollyClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("StartSpeechSynthesisTask")
                         .deserialize::<StartSpeechSynthesisTaskOutput, _>()?;
 
                     Ok(result)
@@ -1324,9 +1773,18 @@ impl Polly for PollyClient {
         &self,
         input: SynthesizeSpeechInput,
     ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/v1/speech";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("SynthesizeSpeech");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1362,3 +1820,327 @@ impl Polly for PollyClient {
         })
     }
 }
+
+/// A mock of the Amazon Polly API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockPolly {
+    delete_lexicon: Option<
+        Box<
+            dyn Fn(
+                    DeleteLexiconInput,
+                )
+                    -> ::std::result::Result<DeleteLexiconOutput, RusotoError<DeleteLexiconError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_voices: Option<
+        Box<
+            dyn Fn(
+                    DescribeVoicesInput,
+                )
+                    -> ::std::result::Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_lexicon: Option<
+        Box<
+            dyn Fn(
+                    GetLexiconInput,
+                )
+                    -> ::std::result::Result<GetLexiconOutput, RusotoError<GetLexiconError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_speech_synthesis_task: Option<
+        Box<
+            dyn Fn(
+                    GetSpeechSynthesisTaskInput,
+                ) -> ::std::result::Result<
+                    GetSpeechSynthesisTaskOutput,
+                    RusotoError<GetSpeechSynthesisTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_lexicons: Option<
+        Box<
+            dyn Fn(
+                    ListLexiconsInput,
+                )
+                    -> ::std::result::Result<ListLexiconsOutput, RusotoError<ListLexiconsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_speech_synthesis_tasks: Option<
+        Box<
+            dyn Fn(
+                    ListSpeechSynthesisTasksInput,
+                ) -> ::std::result::Result<
+                    ListSpeechSynthesisTasksOutput,
+                    RusotoError<ListSpeechSynthesisTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_lexicon: Option<
+        Box<
+            dyn Fn(
+                    PutLexiconInput,
+                )
+                    -> ::std::result::Result<PutLexiconOutput, RusotoError<PutLexiconError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_speech_synthesis_task: Option<
+        Box<
+            dyn Fn(
+                    StartSpeechSynthesisTaskInput,
+                ) -> ::std::result::Result<
+                    StartSpeechSynthesisTaskOutput,
+                    RusotoError<StartSpeechSynthesisTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    synthesize_speech: Option<
+        Box<
+            dyn Fn(
+                    SynthesizeSpeechInput,
+                ) -> ::std::result::Result<
+                    SynthesizeSpeechOutput,
+                    RusotoError<SynthesizeSpeechError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockPolly {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockPolly {
+        Default::default()
+    }
+
+    /// Answers `delete_lexicon` with what `handler` returns for its input.
+    pub fn with_delete_lexicon<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                DeleteLexiconInput,
+            )
+                -> ::std::result::Result<DeleteLexiconOutput, RusotoError<DeleteLexiconError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_lexicon = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_voices` with what `handler` returns for its input.
+    pub fn with_describe_voices<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                DescribeVoicesInput,
+            )
+                -> ::std::result::Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_voices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_lexicon` with what `handler` returns for its input.
+    pub fn with_get_lexicon<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                GetLexiconInput,
+            ) -> ::std::result::Result<GetLexiconOutput, RusotoError<GetLexiconError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_lexicon = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_speech_synthesis_task` with what `handler` returns for its input.
+    pub fn with_get_speech_synthesis_task<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                GetSpeechSynthesisTaskInput,
+            ) -> ::std::result::Result<
+                GetSpeechSynthesisTaskOutput,
+                RusotoError<GetSpeechSynthesisTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_speech_synthesis_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_lexicons` with what `handler` returns for its input.
+    pub fn with_list_lexicons<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                ListLexiconsInput,
+            )
+                -> ::std::result::Result<ListLexiconsOutput, RusotoError<ListLexiconsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_lexicons = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_speech_synthesis_tasks` with what `handler` returns for its input.
+    pub fn with_list_speech_synthesis_tasks<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                ListSpeechSynthesisTasksInput,
+            ) -> ::std::result::Result<
+                ListSpeechSynthesisTasksOutput,
+                RusotoError<ListSpeechSynthesisTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_speech_synthesis_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_lexicon` with what `handler` returns for its input.
+    pub fn with_put_lexicon<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                PutLexiconInput,
+            ) -> ::std::result::Result<PutLexiconOutput, RusotoError<PutLexiconError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_lexicon = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_speech_synthesis_task` with what `handler` returns for its input.
+    pub fn with_start_speech_synthesis_task<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                StartSpeechSynthesisTaskInput,
+            ) -> ::std::result::Result<
+                StartSpeechSynthesisTaskOutput,
+                RusotoError<StartSpeechSynthesisTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_speech_synthesis_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `synthesize_speech` with what `handler` returns for its input.
+    pub fn with_synthesize_speech<F>(mut self, handler: F) -> MockPolly
+    where
+        F: Fn(
+                SynthesizeSpeechInput,
+            )
+                -> ::std::result::Result<SynthesizeSpeechOutput, RusotoError<SynthesizeSpeechError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.synthesize_speech = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Polly for MockPolly {
+    fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> RusotoFuture<DeleteLexiconOutput, DeleteLexiconError> {
+        match self.delete_lexicon {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for delete_lexicon"),
+        }
+    }
+    fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> RusotoFuture<DescribeVoicesOutput, DescribeVoicesError> {
+        match self.describe_voices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for describe_voices"),
+        }
+    }
+    fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> RusotoFuture<GetLexiconOutput, GetLexiconError> {
+        match self.get_lexicon {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for get_lexicon"),
+        }
+    }
+    fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<GetSpeechSynthesisTaskOutput, GetSpeechSynthesisTaskError> {
+        match self.get_speech_synthesis_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for get_speech_synthesis_task"),
+        }
+    }
+    fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> RusotoFuture<ListLexiconsOutput, ListLexiconsError> {
+        match self.list_lexicons {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for list_lexicons"),
+        }
+    }
+    fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> RusotoFuture<ListSpeechSynthesisTasksOutput, ListSpeechSynthesisTasksError> {
+        match self.list_speech_synthesis_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for list_speech_synthesis_tasks"),
+        }
+    }
+    fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> RusotoFuture<PutLexiconOutput, PutLexiconError> {
+        match self.put_lexicon {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for put_lexicon"),
+        }
+    }
+    fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<StartSpeechSynthesisTaskOutput, StartSpeechSynthesisTaskError> {
+        match self.start_speech_synthesis_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for start_speech_synthesis_task"),
+        }
+    }
+    fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError> {
+        match self.synthesize_speech {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPolly has no handler for synthesize_speech"),
+        }
+    }
+}
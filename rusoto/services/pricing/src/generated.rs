@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The values of a given attribute, such as <code>Throughput Optimized HDD</code> or <code>Provisioned IOPS</code> for the <code>Amazon EC2</code> <code>volumeType</code> attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttributeValue {
     /// <p>The specific value of an <code>attributeName</code>.</p>
     #[serde(rename = "Value")]
@@ -34,6 +35,7 @@ pub struct AttributeValue {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServicesRequest {
     /// <p>The format version that you want the response to be in.</p> <p>Valid values are: <code>aws_v1</code> </p>
     #[serde(rename = "FormatVersion")]
@@ -53,8 +55,16 @@ pub struct DescribeServicesRequest {
     pub service_code: Option<String>,
 }
 
+impl Validate for DescribeServicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServicesResponse {
     /// <p>The format version of the response. For example, <code>aws_v1</code>.</p>
     #[serde(rename = "FormatVersion")]
@@ -72,6 +82,7 @@ pub struct DescribeServicesResponse {
 
 /// <p>The constraints that you want all returned products to match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>The product metadata field that you want to filter on. You can filter by just the service code to see all products for a specific service, filter by just the attribute name to see a specific attribute for multiple services, or use both a service code and an attribute name to retrieve only products that match both fields.</p> <p>Valid values include: <code>ServiceCode</code>, and all attribute names</p> <p>For example, you can filter by the <code>AmazonEC2</code> service code and the <code>volumeType</code> attribute name to get the prices for only Amazon EC2 volumes.</p>
     #[serde(rename = "Field")]
@@ -85,6 +96,7 @@ pub struct Filter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAttributeValuesRequest {
     /// <p>The name of the attribute that you want to retrieve the values for, such as <code>volumeType</code>.</p>
     #[serde(rename = "AttributeName")]
@@ -102,8 +114,16 @@ pub struct GetAttributeValuesRequest {
     pub service_code: String,
 }
 
+impl Validate for GetAttributeValuesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAttributeValuesResponse {
     /// <p>The list of values for an attribute. For example, <code>Throughput Optimized HDD</code> and <code>Provisioned IOPS</code> are two available values for the <code>AmazonEC2</code> <code>volumeType</code>.</p>
     #[serde(rename = "AttributeValues")]
@@ -116,6 +136,7 @@ pub struct GetAttributeValuesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetProductsRequest {
     /// <p>The list of filters that limit the returned products. only products that match all filters are returned.</p>
     #[serde(rename = "Filters")]
@@ -139,8 +160,16 @@ pub struct GetProductsRequest {
     pub service_code: Option<String>,
 }
 
+impl Validate for GetProductsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetProductsResponse {
     /// <p>The format version of the response. For example, aws_v1.</p>
     #[serde(rename = "FormatVersion")]
@@ -158,7 +187,7 @@ pub struct GetProductsResponse {
 
 /// <p>The metadata for a service, such as the service code and available attribute names.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Service {
     /// <p>The attributes that are available for this service.</p>
     #[serde(rename = "AttributeNames")]
@@ -204,7 +233,12 @@ impl DescribeServicesError {
                 "NotFoundException" => {
                     return RusotoError::Service(DescribeServicesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -261,7 +295,12 @@ impl GetAttributeValuesError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetAttributeValuesError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -318,7 +357,12 @@ impl GetProductsError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetProductsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -361,6 +405,67 @@ pub trait Pricing {
         input: GetProductsRequest,
     ) -> RusotoFuture<GetProductsResponse, GetProductsError>;
 }
+const _: Option<&dyn Pricing> = None;
+impl<'a, T: Pricing + ?Sized> Pricing for &'a T {
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> RusotoFuture<GetAttributeValuesResponse, GetAttributeValuesError> {
+        (**self).get_attribute_values(input)
+    }
+    fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> RusotoFuture<GetProductsResponse, GetProductsError> {
+        (**self).get_products(input)
+    }
+}
+impl<T: Pricing + ?Sized> Pricing for Box<T> {
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> RusotoFuture<GetAttributeValuesResponse, GetAttributeValuesError> {
+        (**self).get_attribute_values(input)
+    }
+    fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> RusotoFuture<GetProductsResponse, GetProductsError> {
+        (**self).get_products(input)
+    }
+}
+impl<T: Pricing + ?Sized> Pricing for ::std::sync::Arc<T> {
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> RusotoFuture<GetAttributeValuesResponse, GetAttributeValuesError> {
+        (**self).get_attribute_values(input)
+    }
+    fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> RusotoFuture<GetProductsResponse, GetProductsError> {
+        (**self).get_products(input)
+    }
+}
 /// A client for the AWS Pricing API.
 #[derive(Clone)]
 pub struct PricingClient {
@@ -404,7 +509,16 @@ impl Pricing for PricingClient {
         &self,
         input: DescribeServicesRequest,
     ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("DescribeServices");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPriceListService.DescribeServices");
@@ -413,10 +527,10 @@ impl Pricing for PricingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServicesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeServicesResponse, _>(
+                    response,
+                    "DescribeServices",
+                )
             } else {
                 Box::new(
                     response
@@ -433,7 +547,16 @@ impl Pricing for PricingClient {
         &self,
         input: GetAttributeValuesRequest,
     ) -> RusotoFuture<GetAttributeValuesResponse, GetAttributeValuesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("GetAttributeValues");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPriceListService.GetAttributeValues");
@@ -442,10 +565,10 @@ impl Pricing for PricingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAttributeValuesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetAttributeValuesResponse, _>(
+                    response,
+                    "GetAttributeValues",
+                )
             } else {
                 Box::new(
                     response
@@ -462,7 +585,16 @@ impl Pricing for PricingClient {
         &self,
         input: GetProductsRequest,
     ) -> RusotoFuture<GetProductsResponse, GetProductsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "pricing", &self.region, "/");
+        request.set_operation("GetProducts");
         request.set_endpoint_prefix("api.pricing".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPriceListService.GetProducts");
@@ -471,10 +603,10 @@ impl Pricing for PricingClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetProductsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetProductsResponse, _>(
+                    response,
+                    "GetProducts",
+                )
             } else {
                 Box::new(
                     response
@@ -486,3 +618,128 @@ impl Pricing for PricingClient {
         })
     }
 }
+
+/// A mock of the AWS Pricing API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockPricing {
+    describe_services: Option<
+        Box<
+            dyn Fn(
+                    DescribeServicesRequest,
+                ) -> ::std::result::Result<
+                    DescribeServicesResponse,
+                    RusotoError<DescribeServicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_attribute_values: Option<
+        Box<
+            dyn Fn(
+                    GetAttributeValuesRequest,
+                ) -> ::std::result::Result<
+                    GetAttributeValuesResponse,
+                    RusotoError<GetAttributeValuesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_products: Option<
+        Box<
+            dyn Fn(
+                    GetProductsRequest,
+                )
+                    -> ::std::result::Result<GetProductsResponse, RusotoError<GetProductsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockPricing {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockPricing {
+        Default::default()
+    }
+
+    /// Answers `describe_services` with what `handler` returns for its input.
+    pub fn with_describe_services<F>(mut self, handler: F) -> MockPricing
+    where
+        F: Fn(
+                DescribeServicesRequest,
+            ) -> ::std::result::Result<
+                DescribeServicesResponse,
+                RusotoError<DescribeServicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_services = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_attribute_values` with what `handler` returns for its input.
+    pub fn with_get_attribute_values<F>(mut self, handler: F) -> MockPricing
+    where
+        F: Fn(
+                GetAttributeValuesRequest,
+            ) -> ::std::result::Result<
+                GetAttributeValuesResponse,
+                RusotoError<GetAttributeValuesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_attribute_values = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_products` with what `handler` returns for its input.
+    pub fn with_get_products<F>(mut self, handler: F) -> MockPricing
+    where
+        F: Fn(
+                GetProductsRequest,
+            )
+                -> ::std::result::Result<GetProductsResponse, RusotoError<GetProductsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_products = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Pricing for MockPricing {
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        match self.describe_services {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPricing has no handler for describe_services"),
+        }
+    }
+    fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> RusotoFuture<GetAttributeValuesResponse, GetAttributeValuesError> {
+        match self.get_attribute_values {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPricing has no handler for get_attribute_values"),
+        }
+    }
+    fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> RusotoFuture<GetProductsResponse, GetProductsError> {
+        match self.get_products {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockPricing has no handler for get_products"),
+        }
+    }
+}
@@ -22,18 +22,21 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains the details of the transaction to abort.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortTransactionRequest {}
 
 /// <p>Contains the details of the aborted transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AbortTransactionResult {}
 
 /// <p>Contains the details of the transaction to commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CommitTransactionRequest {
     /// <p>Specifies the commit digest for the transaction to commit. For every active transaction, the commit digest must be passed. QLDB validates <code>CommitDigest</code> and rejects the commit with an error if the digest computed on the client does not match the digest computed by QLDB.</p>
     #[serde(rename = "CommitDigest")]
@@ -48,9 +51,25 @@ pub struct CommitTransactionRequest {
     pub transaction_id: String,
 }
 
+impl Validate for CommitTransactionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transaction_id"),
+            self.transaction_id.chars().count(),
+            Some(22),
+            Some(22),
+        );
+        errors.check_pattern(
+            &field_path(path, "transaction_id"),
+            &self.transaction_id,
+            "^[A-Za-z-0-9]+$",
+        );
+    }
+}
+
 /// <p>Contains the details of the committed transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommitTransactionResult {
     /// <p>The commit digest of the committed transaction.</p>
     #[serde(rename = "CommitDigest")]
@@ -69,15 +88,17 @@ pub struct CommitTransactionResult {
 
 /// <p>Specifies a request to end the session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EndSessionRequest {}
 
 /// <p>Contains the details of the ended session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EndSessionResult {}
 
 /// <p>Specifies a request to execute a statement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteStatementRequest {
     /// <p>Specifies the parameters for the parameterized statement in the request.</p>
     #[serde(rename = "Parameters")]
@@ -91,9 +112,37 @@ pub struct ExecuteStatementRequest {
     pub transaction_id: String,
 }
 
+impl Validate for ExecuteStatementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.parameters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "statement"),
+            self.statement.chars().count(),
+            Some(1),
+            Some(100000),
+        );
+        errors.check_length(
+            &field_path(path, "transaction_id"),
+            self.transaction_id.chars().count(),
+            Some(22),
+            Some(22),
+        );
+        errors.check_pattern(
+            &field_path(path, "transaction_id"),
+            &self.transaction_id,
+            "^[A-Za-z-0-9]+$",
+        );
+    }
+}
+
 /// <p>Contains the details of the executed statement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecuteStatementResult {
     /// <p>Contains the details of the first fetched page.</p>
     #[serde(rename = "FirstPage")]
@@ -103,6 +152,7 @@ pub struct ExecuteStatementResult {
 
 /// <p>Specifies the details of the page to be fetched.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FetchPageRequest {
     /// <p>Specifies the next page token of the page to be fetched.</p>
     #[serde(rename = "NextPageToken")]
@@ -112,9 +162,36 @@ pub struct FetchPageRequest {
     pub transaction_id: String,
 }
 
+impl Validate for FetchPageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "next_page_token"),
+            self.next_page_token.chars().count(),
+            Some(4),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "next_page_token"),
+            &self.next_page_token,
+            "^[A-Za-z-0-9+/=]+$",
+        );
+        errors.check_length(
+            &field_path(path, "transaction_id"),
+            self.transaction_id.chars().count(),
+            Some(22),
+            Some(22),
+        );
+        errors.check_pattern(
+            &field_path(path, "transaction_id"),
+            &self.transaction_id,
+            "^[A-Za-z-0-9]+$",
+        );
+    }
+}
+
 /// <p>Contains the page that was fetched.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FetchPageResult {
     /// <p>Contains details of the fetched page.</p>
     #[serde(rename = "Page")]
@@ -124,7 +201,7 @@ pub struct FetchPageResult {
 
 /// <p>Contains details of the fetched page.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Page {
     /// <p>The token of the next page.</p>
     #[serde(rename = "NextPageToken")]
@@ -137,6 +214,7 @@ pub struct Page {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendCommandRequest {
     /// <p>Command to abort the current transaction.</p>
     #[serde(rename = "AbortTransaction")]
@@ -172,8 +250,38 @@ pub struct SendCommandRequest {
     pub start_transaction: Option<StartTransactionRequest>,
 }
 
+impl Validate for SendCommandRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.commit_transaction {
+            value.validate_at(&field_path(path, "commit_transaction"), errors);
+        }
+        if let Some(ref value) = self.execute_statement {
+            value.validate_at(&field_path(path, "execute_statement"), errors);
+        }
+        if let Some(ref value) = self.fetch_page {
+            value.validate_at(&field_path(path, "fetch_page"), errors);
+        }
+        if let Some(ref value) = self.session_token {
+            errors.check_length(
+                &field_path(path, "session_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "session_token"),
+                &value,
+                "^[A-Za-z-0-9+/=]+$",
+            );
+        }
+        if let Some(ref value) = self.start_session {
+            value.validate_at(&field_path(path, "start_session"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendCommandResult {
     /// <p>Contains the details of the aborted transaction.</p>
     #[serde(rename = "AbortTransaction")]
@@ -207,15 +315,32 @@ pub struct SendCommandResult {
 
 /// <p>Specifies a request to start a a new session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSessionRequest {
     /// <p>The name of the ledger to start a new session against.</p>
     #[serde(rename = "LedgerName")]
     pub ledger_name: String,
 }
 
+impl Validate for StartSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ledger_name"),
+            self.ledger_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "ledger_name"),
+            &self.ledger_name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 /// <p>Contains the details of the started session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSessionResult {
     /// <p>Session token of the started session. This <code>SessionToken</code> is required for every subsequent command that is issued during the current session.</p>
     #[serde(rename = "SessionToken")]
@@ -225,11 +350,12 @@ pub struct StartSessionResult {
 
 /// <p>Specifies a request to start a transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartTransactionRequest {}
 
 /// <p>Contains the details of the started transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTransactionResult {
     /// <p>The transaction id of the started transaction.</p>
     #[serde(rename = "TransactionId")]
@@ -255,6 +381,27 @@ pub struct ValueHolder {
     pub ion_text: Option<String>,
 }
 
+impl Validate for ValueHolder {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ion_binary {
+            errors.check_length(
+                &field_path(path, "ion_binary"),
+                value.len(),
+                Some(1),
+                Some(131072),
+            );
+        }
+        if let Some(ref value) = self.ion_text {
+            errors.check_length(
+                &field_path(path, "ion_text"),
+                value.chars().count(),
+                Some(1),
+                Some(1048576),
+            );
+        }
+    }
+}
+
 /// Errors returned by SendCommand
 #[derive(Debug, PartialEq)]
 pub enum SendCommandError {
@@ -289,7 +436,12 @@ impl SendCommandError {
                 "RateExceededException" => {
                     return RusotoError::Service(SendCommandError::RateExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -320,6 +472,31 @@ pub trait QldbSession {
         input: SendCommandRequest,
     ) -> RusotoFuture<SendCommandResult, SendCommandError>;
 }
+const _: Option<&dyn QldbSession> = None;
+impl<'a, T: QldbSession + ?Sized> QldbSession for &'a T {
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+}
+impl<T: QldbSession + ?Sized> QldbSession for Box<T> {
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+}
+impl<T: QldbSession + ?Sized> QldbSession for ::std::sync::Arc<T> {
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+}
 /// A client for the QLDB Session API.
 #[derive(Clone)]
 pub struct QldbSessionClient {
@@ -363,7 +540,16 @@ impl QldbSession for QldbSessionClient {
         &self,
         input: SendCommandRequest,
     ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "qldb", &self.region, "/");
+        request.set_operation("SendCommand");
         request.set_endpoint_prefix("session.qldb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "QLDBSession.SendCommand");
@@ -372,10 +558,7 @@ impl QldbSession for QldbSessionClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendCommandResult, _>()
-                }))
+                proto::json::deserialize_streaming::<SendCommandResult, _>(response, "SendCommand")
             } else {
                 Box::new(
                     response
@@ -387,3 +570,58 @@ impl QldbSession for QldbSessionClient {
         })
     }
 }
+
+/// A mock of the QLDB Session API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockQldbSession {
+    send_command: Option<
+        Box<
+            dyn Fn(
+                    SendCommandRequest,
+                )
+                    -> ::std::result::Result<SendCommandResult, RusotoError<SendCommandError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockQldbSession {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockQldbSession {
+        Default::default()
+    }
+
+    /// Answers `send_command` with what `handler` returns for its input.
+    pub fn with_send_command<F>(mut self, handler: F) -> MockQldbSession
+    where
+        F: Fn(
+                SendCommandRequest,
+            )
+                -> ::std::result::Result<SendCommandResult, RusotoError<SendCommandError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_command = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl QldbSession for MockQldbSession {
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        match self.send_command {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldbSession has no handler for send_command"),
+        }
+    }
+}
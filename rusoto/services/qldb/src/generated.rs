@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLedgerRequest {
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
@@ -42,8 +44,31 @@ pub struct CreateLedgerRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateLedgerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(200));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLedgerResponse {
     /// <p>The Amazon Resource Name (ARN) for the ledger.</p>
     #[serde(rename = "Arn")]
@@ -68,13 +93,31 @@ pub struct CreateLedgerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLedgerRequest {
     /// <p>The name of the ledger that you want to delete.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteLedgerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJournalS3ExportRequest {
     /// <p>The unique ID of the journal export job that you want to describe.</p>
     #[serde(rename = "ExportId")]
@@ -84,8 +127,35 @@ pub struct DescribeJournalS3ExportRequest {
     pub name: String,
 }
 
+impl Validate for DescribeJournalS3ExportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "export_id"),
+            self.export_id.chars().count(),
+            Some(22),
+            Some(22),
+        );
+        errors.check_pattern(
+            &field_path(path, "export_id"),
+            &self.export_id,
+            "^[A-Za-z-0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJournalS3ExportResponse {
     /// <p>Information about the journal export job returned by a <code>DescribeJournalS3Export</code> request.</p>
     #[serde(rename = "ExportDescription")]
@@ -93,14 +163,31 @@ pub struct DescribeJournalS3ExportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLedgerRequest {
     /// <p>The name of the ledger that you want to describe.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DescribeLedgerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLedgerResponse {
     /// <p>The Amazon Resource Name (ARN) for the ledger.</p>
     #[serde(rename = "Arn")]
@@ -125,6 +212,7 @@ pub struct DescribeLedgerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExportJournalToS3Request {
     /// <p>The exclusive end date and time for the range of journal contents that you want to export.</p> <p>The <code>ExclusiveEndTime</code> must be in <code>ISO 8601</code> date and time format and in Universal Coordinated Time (UTC). For example: <code>2019-06-13T21:36:34Z</code> </p> <p>The <code>ExclusiveEndTime</code> must be less than or equal to the current UTC date and time.</p>
     #[serde(rename = "ExclusiveEndTime")]
@@ -143,8 +231,32 @@ pub struct ExportJournalToS3Request {
     pub s3_export_configuration: S3ExportConfiguration,
 }
 
+impl Validate for ExportJournalToS3Request {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+        self.s3_export_configuration
+            .validate_at(&field_path(path, "s3_export_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExportJournalToS3Response {
     /// <p>The unique ID that QLDB assigns to each journal export job.</p> <p>To describe your export request and check the status of the job, you can use <code>ExportId</code> to call <code>DescribeJournalS3Export</code>.</p>
     #[serde(rename = "ExportId")]
@@ -152,6 +264,7 @@ pub struct ExportJournalToS3Response {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBlockRequest {
     /// <p>The location of the block that you want to request. An address is an Amazon Ion structure that has two fields: <code>strandId</code> and <code>sequenceNo</code>.</p> <p>For example: <code>{strandId:"BlFTjlSXze9BIh1KOszcE3",sequenceNo:14}</code> </p>
     #[serde(rename = "BlockAddress")]
@@ -165,8 +278,29 @@ pub struct GetBlockRequest {
     pub name: String,
 }
 
+impl Validate for GetBlockRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.block_address
+            .validate_at(&field_path(path, "block_address"), errors);
+        if let Some(ref value) = self.digest_tip_address {
+            value.validate_at(&field_path(path, "digest_tip_address"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetBlockResponse {
     /// <p>The block data object in Amazon Ion format.</p>
     #[serde(rename = "Block")]
@@ -178,14 +312,31 @@ pub struct GetBlockResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDigestRequest {
     /// <p>The name of the ledger.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for GetDigestRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDigestResponse {
     /// <p>The 256-bit hash value representing the digest returned by a <code>GetDigest</code> request.</p>
     #[serde(rename = "Digest")]
@@ -201,6 +352,7 @@ pub struct GetDigestResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRevisionRequest {
     /// <p>The block location of the document revision to be verified. An address is an Amazon Ion structure that has two fields: <code>strandId</code> and <code>sequenceNo</code>.</p> <p>For example: <code>{strandId:"BlFTjlSXze9BIh1KOszcE3",sequenceNo:14}</code> </p>
     #[serde(rename = "BlockAddress")]
@@ -217,8 +369,40 @@ pub struct GetRevisionRequest {
     pub name: String,
 }
 
+impl Validate for GetRevisionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.block_address
+            .validate_at(&field_path(path, "block_address"), errors);
+        if let Some(ref value) = self.digest_tip_address {
+            value.validate_at(&field_path(path, "digest_tip_address"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(22),
+            Some(22),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "^[A-Za-z-0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRevisionResponse {
     /// <p>The proof object in Amazon Ion format returned by a <code>GetRevision</code> request. A proof contains the list of hash values that are required to recalculate the specified digest using a Merkle tree, starting with the specified document revision.</p>
     #[serde(rename = "Proof")]
@@ -231,7 +415,7 @@ pub struct GetRevisionResponse {
 
 /// <p>The information about a journal export job, including the ledger name, export ID, when it was created, current status, and its start and end time export parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JournalS3ExportDescription {
     /// <p>The exclusive end date and time for the range of journal contents that are specified in the original export request.</p>
     #[serde(rename = "ExclusiveEndTime")]
@@ -260,7 +444,7 @@ pub struct JournalS3ExportDescription {
 
 /// <p>Information about a ledger, including its name, state, and when it was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LedgerSummary {
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
@@ -277,6 +461,7 @@ pub struct LedgerSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJournalS3ExportsForLedgerRequest {
     /// <p>The maximum number of results to return in a single <code>ListJournalS3ExportsForLedger</code> request. (The actual number of results returned might be fewer.)</p>
     #[serde(rename = "MaxResults")]
@@ -291,8 +476,40 @@ pub struct ListJournalS3ExportsForLedgerRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListJournalS3ExportsForLedgerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[A-Za-z-0-9+/=]+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJournalS3ExportsForLedgerResponse {
     /// <p>The array of journal export job descriptions that are associated with the specified ledger.</p>
     #[serde(rename = "JournalS3Exports")]
@@ -305,6 +522,7 @@ pub struct ListJournalS3ExportsForLedgerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJournalS3ExportsRequest {
     /// <p>The maximum number of results to return in a single <code>ListJournalS3Exports</code> request. (The actual number of results returned might be fewer.)</p>
     #[serde(rename = "MaxResults")]
@@ -316,8 +534,29 @@ pub struct ListJournalS3ExportsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListJournalS3ExportsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[A-Za-z-0-9+/=]+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJournalS3ExportsResponse {
     /// <p>The array of journal export job descriptions for all ledgers that are associated with the current AWS account and Region.</p>
     #[serde(rename = "JournalS3Exports")]
@@ -330,6 +569,7 @@ pub struct ListJournalS3ExportsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLedgersRequest {
     /// <p>The maximum number of results to return in a single <code>ListLedgers</code> request. (The actual number of results returned might be fewer.)</p>
     #[serde(rename = "MaxResults")]
@@ -341,8 +581,29 @@ pub struct ListLedgersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListLedgersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(4),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[A-Za-z-0-9+/=]+$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLedgersResponse {
     /// <p>The array of ledger summaries that are associated with the current AWS account and Region.</p>
     #[serde(rename = "Ledgers")]
@@ -355,14 +616,26 @@ pub struct ListLedgersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The Amazon Resource Name (ARN) for which you want to list the tags. For example:</p> <p> <code>arn:aws:qldb:us-east-1:123456789012:ledger/exampleLedger</code> </p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags that are currently associated with the specified Amazon QLDB resource.</p>
     #[serde(rename = "Tags")]
@@ -382,6 +655,19 @@ pub struct S3EncryptionConfiguration {
     pub object_encryption_type: String,
 }
 
+impl Validate for S3EncryptionConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_length(
+                &field_path(path, "kms_key_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(1600),
+            );
+        }
+    }
+}
+
 /// <p>The Amazon Simple Storage Service (Amazon S3) bucket location in which a journal export job writes the journal contents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3ExportConfiguration {
@@ -396,7 +682,32 @@ pub struct S3ExportConfiguration {
     pub prefix: String,
 }
 
+impl Validate for S3ExportConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket"),
+            self.bucket.chars().count(),
+            Some(3),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "bucket"),
+            &self.bucket,
+            "^[A-Za-z-0-9-_.]+$",
+        );
+        self.encryption_configuration
+            .validate_at(&field_path(path, "encryption_configuration"), errors);
+        errors.check_length(
+            &field_path(path, "prefix"),
+            self.prefix.chars().count(),
+            None,
+            Some(128),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) to which you want to add the tags. For example:</p> <p> <code>arn:aws:qldb:us-east-1:123456789012:ledger/exampleLedger</code> </p>
     #[serde(rename = "ResourceArn")]
@@ -406,11 +717,28 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(200));
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) from which you want to remove the tags. For example:</p> <p> <code>arn:aws:qldb:us-east-1:123456789012:ledger/exampleLedger</code> </p>
     #[serde(rename = "ResourceArn")]
@@ -420,11 +748,33 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            None,
+            Some(200),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateLedgerRequest {
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
@@ -435,8 +785,24 @@ pub struct UpdateLedgerRequest {
     pub name: String,
 }
 
+impl Validate for UpdateLedgerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "(?!^.*--)(?!^[0-9]+$)(?!^-)(?!.*-$)^[A-Za-z0-9-]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateLedgerResponse {
     /// <p>The Amazon Resource Name (ARN) for the ledger.</p>
     #[serde(rename = "Arn")]
@@ -469,6 +835,19 @@ pub struct ValueHolder {
     pub ion_text: Option<String>,
 }
 
+impl Validate for ValueHolder {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ion_text {
+            errors.check_length(
+                &field_path(path, "ion_text"),
+                value.chars().count(),
+                Some(1),
+                Some(1048576),
+            );
+        }
+    }
+}
+
 /// Errors returned by CreateLedger
 #[derive(Debug, PartialEq)]
 pub enum CreateLedgerError {
@@ -498,7 +877,12 @@ impl CreateLedgerError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(CreateLedgerError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -551,7 +935,12 @@ impl DeleteLedgerError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -589,7 +978,12 @@ impl DescribeJournalS3ExportError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -627,7 +1021,12 @@ impl DescribeLedgerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeLedgerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -668,7 +1067,12 @@ impl ExportJournalToS3Error {
                         ExportJournalToS3Error::ResourcePreconditionNotMet(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -712,7 +1116,12 @@ impl GetBlockError {
                 "ResourcePreconditionNotMetException" => {
                     return RusotoError::Service(GetBlockError::ResourcePreconditionNotMet(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -759,7 +1168,12 @@ impl GetDigestError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -806,7 +1220,12 @@ impl GetRevisionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -835,7 +1254,12 @@ impl ListJournalS3ExportsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListJournalS3ExportsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -862,7 +1286,12 @@ impl ListJournalS3ExportsForLedgerError {
     ) -> RusotoError<ListJournalS3ExportsForLedgerError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -887,7 +1316,12 @@ impl ListLedgersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLedgersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -927,7 +1361,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -966,7 +1405,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1005,7 +1449,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1044,7 +1493,12 @@ impl UpdateLedgerError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateLedgerError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1150,6 +1604,268 @@ pub trait Qldb {
         input: UpdateLedgerRequest,
     ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError>;
 }
+const _: Option<&dyn Qldb> = None;
+impl<'a, T: Qldb + ?Sized> Qldb for &'a T {
+    fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> RusotoFuture<CreateLedgerResponse, CreateLedgerError> {
+        (**self).create_ledger(input)
+    }
+    fn delete_ledger(&self, input: DeleteLedgerRequest) -> RusotoFuture<(), DeleteLedgerError> {
+        (**self).delete_ledger(input)
+    }
+    fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> RusotoFuture<DescribeJournalS3ExportResponse, DescribeJournalS3ExportError> {
+        (**self).describe_journal_s3_export(input)
+    }
+    fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> RusotoFuture<DescribeLedgerResponse, DescribeLedgerError> {
+        (**self).describe_ledger(input)
+    }
+    fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> RusotoFuture<ExportJournalToS3Response, ExportJournalToS3Error> {
+        (**self).export_journal_to_s3(input)
+    }
+    fn get_block(&self, input: GetBlockRequest) -> RusotoFuture<GetBlockResponse, GetBlockError> {
+        (**self).get_block(input)
+    }
+    fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> RusotoFuture<GetDigestResponse, GetDigestError> {
+        (**self).get_digest(input)
+    }
+    fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> RusotoFuture<GetRevisionResponse, GetRevisionError> {
+        (**self).get_revision(input)
+    }
+    fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsResponse, ListJournalS3ExportsError> {
+        (**self).list_journal_s3_exports(input)
+    }
+    fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsForLedgerResponse, ListJournalS3ExportsForLedgerError>
+    {
+        (**self).list_journal_s3_exports_for_ledger(input)
+    }
+    fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> RusotoFuture<ListLedgersResponse, ListLedgersError> {
+        (**self).list_ledgers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError> {
+        (**self).update_ledger(input)
+    }
+}
+impl<T: Qldb + ?Sized> Qldb for Box<T> {
+    fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> RusotoFuture<CreateLedgerResponse, CreateLedgerError> {
+        (**self).create_ledger(input)
+    }
+    fn delete_ledger(&self, input: DeleteLedgerRequest) -> RusotoFuture<(), DeleteLedgerError> {
+        (**self).delete_ledger(input)
+    }
+    fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> RusotoFuture<DescribeJournalS3ExportResponse, DescribeJournalS3ExportError> {
+        (**self).describe_journal_s3_export(input)
+    }
+    fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> RusotoFuture<DescribeLedgerResponse, DescribeLedgerError> {
+        (**self).describe_ledger(input)
+    }
+    fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> RusotoFuture<ExportJournalToS3Response, ExportJournalToS3Error> {
+        (**self).export_journal_to_s3(input)
+    }
+    fn get_block(&self, input: GetBlockRequest) -> RusotoFuture<GetBlockResponse, GetBlockError> {
+        (**self).get_block(input)
+    }
+    fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> RusotoFuture<GetDigestResponse, GetDigestError> {
+        (**self).get_digest(input)
+    }
+    fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> RusotoFuture<GetRevisionResponse, GetRevisionError> {
+        (**self).get_revision(input)
+    }
+    fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsResponse, ListJournalS3ExportsError> {
+        (**self).list_journal_s3_exports(input)
+    }
+    fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsForLedgerResponse, ListJournalS3ExportsForLedgerError>
+    {
+        (**self).list_journal_s3_exports_for_ledger(input)
+    }
+    fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> RusotoFuture<ListLedgersResponse, ListLedgersError> {
+        (**self).list_ledgers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError> {
+        (**self).update_ledger(input)
+    }
+}
+impl<T: Qldb + ?Sized> Qldb for ::std::sync::Arc<T> {
+    fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> RusotoFuture<CreateLedgerResponse, CreateLedgerError> {
+        (**self).create_ledger(input)
+    }
+    fn delete_ledger(&self, input: DeleteLedgerRequest) -> RusotoFuture<(), DeleteLedgerError> {
+        (**self).delete_ledger(input)
+    }
+    fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> RusotoFuture<DescribeJournalS3ExportResponse, DescribeJournalS3ExportError> {
+        (**self).describe_journal_s3_export(input)
+    }
+    fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> RusotoFuture<DescribeLedgerResponse, DescribeLedgerError> {
+        (**self).describe_ledger(input)
+    }
+    fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> RusotoFuture<ExportJournalToS3Response, ExportJournalToS3Error> {
+        (**self).export_journal_to_s3(input)
+    }
+    fn get_block(&self, input: GetBlockRequest) -> RusotoFuture<GetBlockResponse, GetBlockError> {
+        (**self).get_block(input)
+    }
+    fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> RusotoFuture<GetDigestResponse, GetDigestError> {
+        (**self).get_digest(input)
+    }
+    fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> RusotoFuture<GetRevisionResponse, GetRevisionError> {
+        (**self).get_revision(input)
+    }
+    fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsResponse, ListJournalS3ExportsError> {
+        (**self).list_journal_s3_exports(input)
+    }
+    fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsForLedgerResponse, ListJournalS3ExportsForLedgerError>
+    {
+        (**self).list_journal_s3_exports_for_ledger(input)
+    }
+    fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> RusotoFuture<ListLedgersResponse, ListLedgersError> {
+        (**self).list_ledgers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError> {
+        (**self).update_ledger(input)
+    }
+}
 /// A client for the QLDB API.
 #[derive(Clone)]
 pub struct QldbClient {
@@ -1193,10 +1909,20 @@ impl Qldb for QldbClient {
         &self,
         input: CreateLedgerRequest,
     ) -> RusotoFuture<CreateLedgerResponse, CreateLedgerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("CreateLedger");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -1205,6 +1931,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateLedger")
                         .deserialize::<CreateLedgerResponse, _>()?;
 
                     Ok(result)
@@ -1222,10 +1949,20 @@ impl Qldb for QldbClient {
 
     /// <p>Deletes a ledger and all of its contents. This action is irreversible.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     fn delete_ledger(&self, input: DeleteLedgerRequest) -> RusotoFuture<(), DeleteLedgerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("DeleteLedger");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
@@ -1250,6 +1987,14 @@ impl Qldb for QldbClient {
         &self,
         input: DescribeJournalS3ExportRequest,
     ) -> RusotoFuture<DescribeJournalS3ExportResponse, DescribeJournalS3ExportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/ledgers/{name}/journal-s3-exports/{export_id}",
             export_id = input.export_id,
@@ -1257,12 +2002,17 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("DescribeJournalS3Export");
+        request.add_resource_id("ExportId", &input.export_id.to_string());
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeJournalS3Export")
                         .deserialize::<DescribeJournalS3ExportResponse, _>()?;
 
                     Ok(result)
@@ -1280,15 +2030,27 @@ impl Qldb for QldbClient {
         &self,
         input: DescribeLedgerRequest,
     ) -> RusotoFuture<DescribeLedgerResponse, DescribeLedgerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("DescribeLedger");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeLedger")
                         .deserialize::<DescribeLedgerResponse, _>()?;
 
                     Ok(result)
@@ -1309,10 +2071,21 @@ impl Qldb for QldbClient {
         &self,
         input: ExportJournalToS3Request,
     ) -> RusotoFuture<ExportJournalToS3Response, ExportJournalToS3Error> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("ExportJournalToS3");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -1321,6 +2094,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ExportJournalToS3")
                         .deserialize::<ExportJournalToS3Response, _>()?;
 
                     Ok(result)
@@ -1338,10 +2112,21 @@ impl Qldb for QldbClient {
 
     /// <p>Returns a journal block object at a specified address in a ledger. Also returns a proof of the specified block for verification if <code>DigestTipAddress</code> is provided.</p> <p>If the specified ledger doesn't exist or is in <code>DELETING</code> status, then throws <code>ResourceNotFoundException</code>.</p> <p>If the specified ledger is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>If no block exists with the specified address, then throws <code>InvalidParameterException</code>.</p>
     fn get_block(&self, input: GetBlockRequest) -> RusotoFuture<GetBlockResponse, GetBlockError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}/block", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("GetBlock");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -1350,6 +2135,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetBlock")
                         .deserialize::<GetBlockResponse, _>()?;
 
                     Ok(result)
@@ -1370,15 +2156,27 @@ impl Qldb for QldbClient {
         &self,
         input: GetDigestRequest,
     ) -> RusotoFuture<GetDigestResponse, GetDigestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}/digest", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("GetDigest");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDigest")
                         .deserialize::<GetDigestResponse, _>()?;
 
                     Ok(result)
@@ -1399,10 +2197,21 @@ impl Qldb for QldbClient {
         &self,
         input: GetRevisionRequest,
     ) -> RusotoFuture<GetRevisionResponse, GetRevisionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}/revision", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("GetRevision");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -1411,6 +2220,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetRevision")
                         .deserialize::<GetRevisionResponse, _>()?;
 
                     Ok(result)
@@ -1431,10 +2241,20 @@ impl Qldb for QldbClient {
         &self,
         input: ListJournalS3ExportsRequest,
     ) -> RusotoFuture<ListJournalS3ExportsResponse, ListJournalS3ExportsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/journal-s3-exports";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("ListJournalS3Exports");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -1449,6 +2269,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJournalS3Exports")
                         .deserialize::<ListJournalS3ExportsResponse, _>()?;
 
                     Ok(result)
@@ -1469,10 +2290,21 @@ impl Qldb for QldbClient {
         input: ListJournalS3ExportsForLedgerRequest,
     ) -> RusotoFuture<ListJournalS3ExportsForLedgerResponse, ListJournalS3ExportsForLedgerError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("ListJournalS3ExportsForLedger");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -1487,6 +2319,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListJournalS3ExportsForLedger")
                         .deserialize::<ListJournalS3ExportsForLedgerResponse, _>()?;
 
                     Ok(result)
@@ -1504,10 +2337,20 @@ impl Qldb for QldbClient {
         &self,
         input: ListLedgersRequest,
     ) -> RusotoFuture<ListLedgersResponse, ListLedgersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("ListLedgers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -1522,6 +2365,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListLedgers")
                         .deserialize::<ListLedgersResponse, _>()?;
 
                     Ok(result)
@@ -1542,15 +2386,27 @@ impl Qldb for QldbClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
                         .deserialize::<ListTagsForResourceResponse, _>()?;
 
                     Ok(result)
@@ -1570,10 +2426,20 @@ impl Qldb for QldbClient {
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -1582,6 +2448,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
                         .deserialize::<TagResourceResponse, _>()?;
 
                     Ok(result)
@@ -1602,10 +2469,20 @@ impl Qldb for QldbClient {
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let mut params = Params::new();
         for item in input.tag_keys.iter() {
@@ -1617,6 +2494,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
                         .deserialize::<UntagResourceResponse, _>()?;
 
                     Ok(result)
@@ -1637,10 +2515,21 @@ impl Qldb for QldbClient {
         &self,
         input: UpdateLedgerRequest,
     ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "qldb", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.set_operation("UpdateLedger");
+        request.add_resource_id("Name", &input.name.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
@@ -1649,6 +2538,7 @@ impl Qldb for QldbClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateLedger")
                         .deserialize::<UpdateLedgerResponse, _>()?;
 
                     Ok(result)
@@ -1664,3 +2554,518 @@ impl Qldb for QldbClient {
         })
     }
 }
+
+/// A mock of the QLDB API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockQldb {
+    create_ledger: Option<
+        Box<
+            dyn Fn(
+                    CreateLedgerRequest,
+                )
+                    -> ::std::result::Result<CreateLedgerResponse, RusotoError<CreateLedgerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_ledger: Option<
+        Box<
+            dyn Fn(DeleteLedgerRequest) -> ::std::result::Result<(), RusotoError<DeleteLedgerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_journal_s3_export: Option<
+        Box<
+            dyn Fn(
+                    DescribeJournalS3ExportRequest,
+                ) -> ::std::result::Result<
+                    DescribeJournalS3ExportResponse,
+                    RusotoError<DescribeJournalS3ExportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_ledger: Option<
+        Box<
+            dyn Fn(
+                    DescribeLedgerRequest,
+                ) -> ::std::result::Result<
+                    DescribeLedgerResponse,
+                    RusotoError<DescribeLedgerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    export_journal_to_s3: Option<
+        Box<
+            dyn Fn(
+                    ExportJournalToS3Request,
+                ) -> ::std::result::Result<
+                    ExportJournalToS3Response,
+                    RusotoError<ExportJournalToS3Error>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_block: Option<
+        Box<
+            dyn Fn(
+                    GetBlockRequest,
+                )
+                    -> ::std::result::Result<GetBlockResponse, RusotoError<GetBlockError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_digest: Option<
+        Box<
+            dyn Fn(
+                    GetDigestRequest,
+                )
+                    -> ::std::result::Result<GetDigestResponse, RusotoError<GetDigestError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_revision: Option<
+        Box<
+            dyn Fn(
+                    GetRevisionRequest,
+                )
+                    -> ::std::result::Result<GetRevisionResponse, RusotoError<GetRevisionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_journal_s3_exports: Option<
+        Box<
+            dyn Fn(
+                    ListJournalS3ExportsRequest,
+                ) -> ::std::result::Result<
+                    ListJournalS3ExportsResponse,
+                    RusotoError<ListJournalS3ExportsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_journal_s3_exports_for_ledger: Option<
+        Box<
+            dyn Fn(
+                    ListJournalS3ExportsForLedgerRequest,
+                ) -> ::std::result::Result<
+                    ListJournalS3ExportsForLedgerResponse,
+                    RusotoError<ListJournalS3ExportsForLedgerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_ledgers: Option<
+        Box<
+            dyn Fn(
+                    ListLedgersRequest,
+                )
+                    -> ::std::result::Result<ListLedgersResponse, RusotoError<ListLedgersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_ledger: Option<
+        Box<
+            dyn Fn(
+                    UpdateLedgerRequest,
+                )
+                    -> ::std::result::Result<UpdateLedgerResponse, RusotoError<UpdateLedgerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockQldb {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockQldb {
+        Default::default()
+    }
+
+    /// Answers `create_ledger` with what `handler` returns for its input.
+    pub fn with_create_ledger<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                CreateLedgerRequest,
+            )
+                -> ::std::result::Result<CreateLedgerResponse, RusotoError<CreateLedgerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ledger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ledger` with what `handler` returns for its input.
+    pub fn with_delete_ledger<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(DeleteLedgerRequest) -> ::std::result::Result<(), RusotoError<DeleteLedgerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ledger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_journal_s3_export` with what `handler` returns for its input.
+    pub fn with_describe_journal_s3_export<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                DescribeJournalS3ExportRequest,
+            ) -> ::std::result::Result<
+                DescribeJournalS3ExportResponse,
+                RusotoError<DescribeJournalS3ExportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_journal_s3_export = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ledger` with what `handler` returns for its input.
+    pub fn with_describe_ledger<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                DescribeLedgerRequest,
+            )
+                -> ::std::result::Result<DescribeLedgerResponse, RusotoError<DescribeLedgerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ledger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `export_journal_to_s3` with what `handler` returns for its input.
+    pub fn with_export_journal_to_s3<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                ExportJournalToS3Request,
+            ) -> ::std::result::Result<
+                ExportJournalToS3Response,
+                RusotoError<ExportJournalToS3Error>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.export_journal_to_s3 = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_block` with what `handler` returns for its input.
+    pub fn with_get_block<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                GetBlockRequest,
+            ) -> ::std::result::Result<GetBlockResponse, RusotoError<GetBlockError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_block = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_digest` with what `handler` returns for its input.
+    pub fn with_get_digest<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                GetDigestRequest,
+            ) -> ::std::result::Result<GetDigestResponse, RusotoError<GetDigestError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_digest = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_revision` with what `handler` returns for its input.
+    pub fn with_get_revision<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                GetRevisionRequest,
+            )
+                -> ::std::result::Result<GetRevisionResponse, RusotoError<GetRevisionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_journal_s3_exports` with what `handler` returns for its input.
+    pub fn with_list_journal_s3_exports<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                ListJournalS3ExportsRequest,
+            ) -> ::std::result::Result<
+                ListJournalS3ExportsResponse,
+                RusotoError<ListJournalS3ExportsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_journal_s3_exports = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_journal_s3_exports_for_ledger` with what `handler` returns for its input.
+    pub fn with_list_journal_s3_exports_for_ledger<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                ListJournalS3ExportsForLedgerRequest,
+            ) -> ::std::result::Result<
+                ListJournalS3ExportsForLedgerResponse,
+                RusotoError<ListJournalS3ExportsForLedgerError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_journal_s3_exports_for_ledger = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_ledgers` with what `handler` returns for its input.
+    pub fn with_list_ledgers<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                ListLedgersRequest,
+            )
+                -> ::std::result::Result<ListLedgersResponse, RusotoError<ListLedgersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_ledgers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_ledger` with what `handler` returns for its input.
+    pub fn with_update_ledger<F>(mut self, handler: F) -> MockQldb
+    where
+        F: Fn(
+                UpdateLedgerRequest,
+            )
+                -> ::std::result::Result<UpdateLedgerResponse, RusotoError<UpdateLedgerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_ledger = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Qldb for MockQldb {
+    fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> RusotoFuture<CreateLedgerResponse, CreateLedgerError> {
+        match self.create_ledger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for create_ledger"),
+        }
+    }
+    fn delete_ledger(&self, input: DeleteLedgerRequest) -> RusotoFuture<(), DeleteLedgerError> {
+        match self.delete_ledger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for delete_ledger"),
+        }
+    }
+    fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> RusotoFuture<DescribeJournalS3ExportResponse, DescribeJournalS3ExportError> {
+        match self.describe_journal_s3_export {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for describe_journal_s3_export"),
+        }
+    }
+    fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> RusotoFuture<DescribeLedgerResponse, DescribeLedgerError> {
+        match self.describe_ledger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for describe_ledger"),
+        }
+    }
+    fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> RusotoFuture<ExportJournalToS3Response, ExportJournalToS3Error> {
+        match self.export_journal_to_s3 {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for export_journal_to_s3"),
+        }
+    }
+    fn get_block(&self, input: GetBlockRequest) -> RusotoFuture<GetBlockResponse, GetBlockError> {
+        match self.get_block {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for get_block"),
+        }
+    }
+    fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> RusotoFuture<GetDigestResponse, GetDigestError> {
+        match self.get_digest {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for get_digest"),
+        }
+    }
+    fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> RusotoFuture<GetRevisionResponse, GetRevisionError> {
+        match self.get_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for get_revision"),
+        }
+    }
+    fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsResponse, ListJournalS3ExportsError> {
+        match self.list_journal_s3_exports {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for list_journal_s3_exports"),
+        }
+    }
+    fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsForLedgerResponse, ListJournalS3ExportsForLedgerError>
+    {
+        match self.list_journal_s3_exports_for_ledger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for list_journal_s3_exports_for_ledger"),
+        }
+    }
+    fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> RusotoFuture<ListLedgersResponse, ListLedgersError> {
+        match self.list_ledgers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for list_ledgers"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for list_tags_for_resource"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for untag_resource"),
+        }
+    }
+    fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError> {
+        match self.update_ledger {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockQldb has no handler for update_ledger"),
+        }
+    }
+}
@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptResourceShareInvitationRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -36,7 +38,7 @@ pub struct AcceptResourceShareInvitationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptResourceShareInvitationResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -49,6 +51,7 @@ pub struct AcceptResourceShareInvitationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateResourceShareRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -68,7 +71,7 @@ pub struct AssociateResourceShareRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateResourceShareResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -81,6 +84,7 @@ pub struct AssociateResourceShareResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceShareRequest {
     /// <p>Indicates whether principals outside your organization can be associated with a resource share.</p>
     #[serde(rename = "allowExternalPrincipals")]
@@ -108,7 +112,7 @@ pub struct CreateResourceShareRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceShareResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -121,6 +125,7 @@ pub struct CreateResourceShareResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourceShareRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -132,7 +137,7 @@ pub struct DeleteResourceShareRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResourceShareResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -145,6 +150,7 @@ pub struct DeleteResourceShareResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateResourceShareRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -164,7 +170,7 @@ pub struct DisassociateResourceShareRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateResourceShareResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -177,10 +183,11 @@ pub struct DisassociateResourceShareResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableSharingWithAwsOrganizationRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableSharingWithAwsOrganizationResponse {
     /// <p>Indicates whether the request succeeded.</p>
     #[serde(rename = "returnValue")]
@@ -189,6 +196,7 @@ pub struct EnableSharingWithAwsOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourcePoliciesRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -207,8 +215,16 @@ pub struct GetResourcePoliciesRequest {
     pub resource_arns: Vec<String>,
 }
 
+impl Validate for GetResourcePoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourcePoliciesResponse {
     /// <p>The token to use to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -221,6 +237,7 @@ pub struct GetResourcePoliciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceShareAssociationsRequest {
     /// <p>The status of the association.</p>
     #[serde(rename = "associationStatus")]
@@ -251,8 +268,16 @@ pub struct GetResourceShareAssociationsRequest {
     pub resource_share_arns: Option<Vec<String>>,
 }
 
+impl Validate for GetResourceShareAssociationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceShareAssociationsResponse {
     /// <p>The token to use to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -265,6 +290,7 @@ pub struct GetResourceShareAssociationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceShareInvitationsRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -284,8 +310,16 @@ pub struct GetResourceShareInvitationsRequest {
     pub resource_share_invitation_arns: Option<Vec<String>>,
 }
 
+impl Validate for GetResourceShareInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceShareInvitationsResponse {
     /// <p>The token to use to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -298,6 +332,7 @@ pub struct GetResourceShareInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourceSharesRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -328,8 +363,16 @@ pub struct GetResourceSharesRequest {
     pub tag_filters: Option<Vec<TagFilter>>,
 }
 
+impl Validate for GetResourceSharesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourceSharesResponse {
     /// <p>The token to use to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -342,6 +385,7 @@ pub struct GetResourceSharesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPrincipalsRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -372,8 +416,16 @@ pub struct ListPrincipalsRequest {
     pub resource_type: Option<String>,
 }
 
+impl Validate for ListPrincipalsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPrincipalsResponse {
     /// <p>The token to use to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -386,6 +438,7 @@ pub struct ListPrincipalsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourcesRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -416,8 +469,16 @@ pub struct ListResourcesRequest {
     pub resource_type: Option<String>,
 }
 
+impl Validate for ListResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourcesResponse {
     /// <p>The token to use to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -431,7 +492,7 @@ pub struct ListResourcesResponse {
 
 /// <p>Describes a principal for use with AWS Resource Access Manager.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Principal {
     /// <p>The time when the principal was associated with the resource share.</p>
     #[serde(rename = "creationTime")]
@@ -456,6 +517,7 @@ pub struct Principal {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RejectResourceShareInvitationRequest {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -467,7 +529,7 @@ pub struct RejectResourceShareInvitationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectResourceShareInvitationResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -481,7 +543,7 @@ pub struct RejectResourceShareInvitationResponse {
 
 /// <p>Describes a resource associated with a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
     #[serde(rename = "arn")]
@@ -515,7 +577,7 @@ pub struct Resource {
 
 /// <p>Describes a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceShare {
     /// <p>Indicates whether principals outside your organization can be associated with a resource share.</p>
     #[serde(rename = "allowExternalPrincipals")]
@@ -557,7 +619,7 @@ pub struct ResourceShare {
 
 /// <p>Describes an association with a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceShareAssociation {
     /// <p>The associated entity. For resource associations, this is the ARN of the resource. For principal associations, this is the ID of an AWS account or the ARN of an OU or organization from AWS Organizations.</p>
     #[serde(rename = "associatedEntity")]
@@ -595,7 +657,7 @@ pub struct ResourceShareAssociation {
 
 /// <p>Describes an invitation to join a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceShareInvitation {
     /// <p>The date and time when the invitation was sent.</p>
     #[serde(rename = "invitationTimestamp")]
@@ -646,6 +708,7 @@ pub struct Tag {
 
 /// <p>Used to filter information based on tags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagFilter {
     /// <p>The tag key.</p>
     #[serde(rename = "tagKey")]
@@ -658,6 +721,7 @@ pub struct TagFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
     #[serde(rename = "resourceShareArn")]
@@ -668,10 +732,11 @@ pub struct TagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
     #[serde(rename = "resourceShareArn")]
@@ -682,10 +747,11 @@ pub struct UntagResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateResourceShareRequest {
     /// <p>Indicates whether principals outside your organization can be associated with a resource share.</p>
     #[serde(rename = "allowExternalPrincipals")]
@@ -705,7 +771,7 @@ pub struct UpdateResourceShareRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateResourceShareResponse {
     /// <p>A unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
     #[serde(rename = "clientToken")]
@@ -790,7 +856,12 @@ impl AcceptResourceShareInvitationError {
                         AcceptResourceShareInvitationError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -899,7 +970,12 @@ impl AssociateResourceShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1000,7 +1076,12 @@ impl CreateResourceShareError {
                 "UnknownResourceException" => {
                     return RusotoError::Service(CreateResourceShareError::UnknownResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1094,7 +1175,12 @@ impl DeleteResourceShareError {
                 "UnknownResourceException" => {
                     return RusotoError::Service(DeleteResourceShareError::UnknownResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1200,7 +1286,12 @@ impl DisassociateResourceShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1260,7 +1351,12 @@ impl EnableSharingWithAwsOrganizationError {
                         EnableSharingWithAwsOrganizationError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1321,7 +1417,12 @@ impl GetResourcePoliciesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1404,7 +1505,12 @@ impl GetResourceShareAssociationsError {
                         GetResourceShareAssociationsError::UnknownResource(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1491,7 +1597,12 @@ impl GetResourceShareInvitationsError {
                         GetResourceShareInvitationsError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1559,7 +1670,12 @@ impl GetResourceSharesError {
                 "UnknownResourceException" => {
                     return RusotoError::Service(GetResourceSharesError::UnknownResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1622,7 +1738,12 @@ impl ListPrincipalsError {
                 "UnknownResourceException" => {
                     return RusotoError::Service(ListPrincipalsError::UnknownResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1690,7 +1811,12 @@ impl ListResourcesError {
                 "UnknownResourceException" => {
                     return RusotoError::Service(ListResourcesError::UnknownResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1788,7 +1914,12 @@ impl RejectResourceShareInvitationError {
                         RejectResourceShareInvitationError::ServiceUnavailable(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1859,7 +1990,12 @@ impl TagResourceError {
                 "TagLimitExceededException" => {
                     return RusotoError::Service(TagResourceError::TagLimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1907,7 +2043,12 @@ impl UntagResourceError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1994,7 +2135,12 @@ impl UpdateResourceShareError {
                 "UnknownResourceException" => {
                     return RusotoError::Service(UpdateResourceShareError::UnknownResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2118,6 +2264,307 @@ pub trait Ram {
         input: UpdateResourceShareRequest,
     ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError>;
 }
+const _: Option<&dyn Ram> = None;
+impl<'a, T: Ram + ?Sized> Ram for &'a T {
+    fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> RusotoFuture<AcceptResourceShareInvitationResponse, AcceptResourceShareInvitationError>
+    {
+        (**self).accept_resource_share_invitation(input)
+    }
+    fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> RusotoFuture<AssociateResourceShareResponse, AssociateResourceShareError> {
+        (**self).associate_resource_share(input)
+    }
+    fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> RusotoFuture<CreateResourceShareResponse, CreateResourceShareError> {
+        (**self).create_resource_share(input)
+    }
+    fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> RusotoFuture<DeleteResourceShareResponse, DeleteResourceShareError> {
+        (**self).delete_resource_share(input)
+    }
+    fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> RusotoFuture<DisassociateResourceShareResponse, DisassociateResourceShareError> {
+        (**self).disassociate_resource_share(input)
+    }
+    fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> RusotoFuture<EnableSharingWithAwsOrganizationResponse, EnableSharingWithAwsOrganizationError>
+    {
+        (**self).enable_sharing_with_aws_organization()
+    }
+    fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> RusotoFuture<GetResourcePoliciesResponse, GetResourcePoliciesError> {
+        (**self).get_resource_policies(input)
+    }
+    fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> RusotoFuture<GetResourceShareAssociationsResponse, GetResourceShareAssociationsError> {
+        (**self).get_resource_share_associations(input)
+    }
+    fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> RusotoFuture<GetResourceShareInvitationsResponse, GetResourceShareInvitationsError> {
+        (**self).get_resource_share_invitations(input)
+    }
+    fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> RusotoFuture<GetResourceSharesResponse, GetResourceSharesError> {
+        (**self).get_resource_shares(input)
+    }
+    fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> RusotoFuture<ListPrincipalsResponse, ListPrincipalsError> {
+        (**self).list_principals(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> RusotoFuture<RejectResourceShareInvitationResponse, RejectResourceShareInvitationError>
+    {
+        (**self).reject_resource_share_invitation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError> {
+        (**self).update_resource_share(input)
+    }
+}
+impl<T: Ram + ?Sized> Ram for Box<T> {
+    fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> RusotoFuture<AcceptResourceShareInvitationResponse, AcceptResourceShareInvitationError>
+    {
+        (**self).accept_resource_share_invitation(input)
+    }
+    fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> RusotoFuture<AssociateResourceShareResponse, AssociateResourceShareError> {
+        (**self).associate_resource_share(input)
+    }
+    fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> RusotoFuture<CreateResourceShareResponse, CreateResourceShareError> {
+        (**self).create_resource_share(input)
+    }
+    fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> RusotoFuture<DeleteResourceShareResponse, DeleteResourceShareError> {
+        (**self).delete_resource_share(input)
+    }
+    fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> RusotoFuture<DisassociateResourceShareResponse, DisassociateResourceShareError> {
+        (**self).disassociate_resource_share(input)
+    }
+    fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> RusotoFuture<EnableSharingWithAwsOrganizationResponse, EnableSharingWithAwsOrganizationError>
+    {
+        (**self).enable_sharing_with_aws_organization()
+    }
+    fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> RusotoFuture<GetResourcePoliciesResponse, GetResourcePoliciesError> {
+        (**self).get_resource_policies(input)
+    }
+    fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> RusotoFuture<GetResourceShareAssociationsResponse, GetResourceShareAssociationsError> {
+        (**self).get_resource_share_associations(input)
+    }
+    fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> RusotoFuture<GetResourceShareInvitationsResponse, GetResourceShareInvitationsError> {
+        (**self).get_resource_share_invitations(input)
+    }
+    fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> RusotoFuture<GetResourceSharesResponse, GetResourceSharesError> {
+        (**self).get_resource_shares(input)
+    }
+    fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> RusotoFuture<ListPrincipalsResponse, ListPrincipalsError> {
+        (**self).list_principals(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> RusotoFuture<RejectResourceShareInvitationResponse, RejectResourceShareInvitationError>
+    {
+        (**self).reject_resource_share_invitation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError> {
+        (**self).update_resource_share(input)
+    }
+}
+impl<T: Ram + ?Sized> Ram for ::std::sync::Arc<T> {
+    fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> RusotoFuture<AcceptResourceShareInvitationResponse, AcceptResourceShareInvitationError>
+    {
+        (**self).accept_resource_share_invitation(input)
+    }
+    fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> RusotoFuture<AssociateResourceShareResponse, AssociateResourceShareError> {
+        (**self).associate_resource_share(input)
+    }
+    fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> RusotoFuture<CreateResourceShareResponse, CreateResourceShareError> {
+        (**self).create_resource_share(input)
+    }
+    fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> RusotoFuture<DeleteResourceShareResponse, DeleteResourceShareError> {
+        (**self).delete_resource_share(input)
+    }
+    fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> RusotoFuture<DisassociateResourceShareResponse, DisassociateResourceShareError> {
+        (**self).disassociate_resource_share(input)
+    }
+    fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> RusotoFuture<EnableSharingWithAwsOrganizationResponse, EnableSharingWithAwsOrganizationError>
+    {
+        (**self).enable_sharing_with_aws_organization()
+    }
+    fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> RusotoFuture<GetResourcePoliciesResponse, GetResourcePoliciesError> {
+        (**self).get_resource_policies(input)
+    }
+    fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> RusotoFuture<GetResourceShareAssociationsResponse, GetResourceShareAssociationsError> {
+        (**self).get_resource_share_associations(input)
+    }
+    fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> RusotoFuture<GetResourceShareInvitationsResponse, GetResourceShareInvitationsError> {
+        (**self).get_resource_share_invitations(input)
+    }
+    fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> RusotoFuture<GetResourceSharesResponse, GetResourceSharesError> {
+        (**self).get_resource_shares(input)
+    }
+    fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> RusotoFuture<ListPrincipalsResponse, ListPrincipalsError> {
+        (**self).list_principals(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> RusotoFuture<RejectResourceShareInvitationResponse, RejectResourceShareInvitationError>
+    {
+        (**self).reject_resource_share_invitation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError> {
+        (**self).update_resource_share(input)
+    }
+}
 /// A client for the RAM API.
 #[derive(Clone)]
 pub struct RamClient {
@@ -2165,6 +2612,8 @@ impl Ram for RamClient {
         let request_uri = "/acceptresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AcceptResourceShareInvitation");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2174,6 +2623,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AcceptResourceShareInvitation")
                         .deserialize::<AcceptResourceShareInvitationResponse, _>()?;
 
                     Ok(result)
@@ -2194,6 +2644,8 @@ impl Ram for RamClient {
         let request_uri = "/associateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceShare");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2203,6 +2655,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateResourceShare")
                         .deserialize::<AssociateResourceShareResponse, _>()?;
 
                     Ok(result)
@@ -2225,6 +2678,8 @@ impl Ram for RamClient {
         let request_uri = "/createresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("CreateResourceShare");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2234,6 +2689,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateResourceShare")
                         .deserialize::<CreateResourceShareResponse, _>()?;
 
                     Ok(result)
@@ -2256,6 +2712,8 @@ impl Ram for RamClient {
         let request_uri = "/deleteresourceshare";
 
         let mut request = SignedRequest::new("DELETE", "ram", &self.region, &request_uri);
+        request.set_operation("DeleteResourceShare");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2269,6 +2727,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteResourceShare")
                         .deserialize::<DeleteResourceShareResponse, _>()?;
 
                     Ok(result)
@@ -2291,6 +2750,8 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceShare");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2300,6 +2761,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateResourceShare")
                         .deserialize::<DisassociateResourceShareResponse, _>()?;
 
                     Ok(result)
@@ -2320,12 +2782,15 @@ impl Ram for RamClient {
         let request_uri = "/enablesharingwithawsorganization";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("EnableSharingWithAwsOrganization");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("EnableSharingWithAwsOrganization")
                         .deserialize::<EnableSharingWithAwsOrganizationResponse, _>()?;
 
                     Ok(result)
@@ -2345,9 +2810,19 @@ impl Ram for RamClient {
         &self,
         input: GetResourcePoliciesRequest,
     ) -> RusotoFuture<GetResourcePoliciesResponse, GetResourcePoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getresourcepolicies";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourcePolicies");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2357,6 +2832,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResourcePolicies")
                         .deserialize::<GetResourcePoliciesResponse, _>()?;
 
                     Ok(result)
@@ -2376,9 +2852,19 @@ impl Ram for RamClient {
         &self,
         input: GetResourceShareAssociationsRequest,
     ) -> RusotoFuture<GetResourceShareAssociationsResponse, GetResourceShareAssociationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getresourceshareassociations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareAssociations");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2388,6 +2874,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResourceShareAssociations")
                         .deserialize::<GetResourceShareAssociationsResponse, _>()?;
 
                     Ok(result)
@@ -2405,9 +2892,19 @@ impl Ram for RamClient {
         &self,
         input: GetResourceShareInvitationsRequest,
     ) -> RusotoFuture<GetResourceShareInvitationsResponse, GetResourceShareInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getresourceshareinvitations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareInvitations");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2417,6 +2914,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResourceShareInvitations")
                         .deserialize::<GetResourceShareInvitationsResponse, _>()?;
 
                     Ok(result)
@@ -2434,9 +2932,19 @@ impl Ram for RamClient {
         &self,
         input: GetResourceSharesRequest,
     ) -> RusotoFuture<GetResourceSharesResponse, GetResourceSharesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/getresourceshares";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShares");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2446,6 +2954,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResourceShares")
                         .deserialize::<GetResourceSharesResponse, _>()?;
 
                     Ok(result)
@@ -2466,9 +2975,19 @@ impl Ram for RamClient {
         &self,
         input: ListPrincipalsRequest,
     ) -> RusotoFuture<ListPrincipalsResponse, ListPrincipalsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/listprincipals";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPrincipals");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2478,6 +2997,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListPrincipals")
                         .deserialize::<ListPrincipalsResponse, _>()?;
 
                     Ok(result)
@@ -2498,9 +3018,19 @@ impl Ram for RamClient {
         &self,
         input: ListResourcesRequest,
     ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/listresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResources");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2510,6 +3040,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListResources")
                         .deserialize::<ListResourcesResponse, _>()?;
 
                     Ok(result)
@@ -2534,6 +3065,8 @@ impl Ram for RamClient {
         let request_uri = "/rejectresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("RejectResourceShareInvitation");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2543,6 +3076,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RejectResourceShareInvitation")
                         .deserialize::<RejectResourceShareInvitationResponse, _>()?;
 
                     Ok(result)
@@ -2563,6 +3097,7 @@ impl Ram for RamClient {
         let request_uri = "/tagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2572,6 +3107,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
                         .deserialize::<TagResourceResponse, _>()?;
 
                     Ok(result)
@@ -2595,6 +3131,7 @@ impl Ram for RamClient {
         let request_uri = "/untagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2604,6 +3141,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
                         .deserialize::<UntagResourceResponse, _>()?;
 
                     Ok(result)
@@ -2627,6 +3165,8 @@ impl Ram for RamClient {
         let request_uri = "/updateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UpdateResourceShare");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2636,6 +3176,7 @@ impl Ram for RamClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateResourceShare")
                         .deserialize::<UpdateResourceShareResponse, _>()?;
 
                     Ok(result)
@@ -2650,3 +3191,576 @@ impl Ram for RamClient {
         })
     }
 }
+
+/// A mock of the RAM API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockRam {
+    accept_resource_share_invitation: Option<
+        Box<
+            dyn Fn(
+                    AcceptResourceShareInvitationRequest,
+                ) -> ::std::result::Result<
+                    AcceptResourceShareInvitationResponse,
+                    RusotoError<AcceptResourceShareInvitationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_resource_share: Option<
+        Box<
+            dyn Fn(
+                    AssociateResourceShareRequest,
+                ) -> ::std::result::Result<
+                    AssociateResourceShareResponse,
+                    RusotoError<AssociateResourceShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource_share: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceShareRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceShareResponse,
+                    RusotoError<CreateResourceShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_share: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourceShareRequest,
+                ) -> ::std::result::Result<
+                    DeleteResourceShareResponse,
+                    RusotoError<DeleteResourceShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_resource_share: Option<
+        Box<
+            dyn Fn(
+                    DisassociateResourceShareRequest,
+                ) -> ::std::result::Result<
+                    DisassociateResourceShareResponse,
+                    RusotoError<DisassociateResourceShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_sharing_with_aws_organization: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    EnableSharingWithAwsOrganizationResponse,
+                    RusotoError<EnableSharingWithAwsOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_policies: Option<
+        Box<
+            dyn Fn(
+                    GetResourcePoliciesRequest,
+                ) -> ::std::result::Result<
+                    GetResourcePoliciesResponse,
+                    RusotoError<GetResourcePoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_share_associations: Option<
+        Box<
+            dyn Fn(
+                    GetResourceShareAssociationsRequest,
+                ) -> ::std::result::Result<
+                    GetResourceShareAssociationsResponse,
+                    RusotoError<GetResourceShareAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_share_invitations: Option<
+        Box<
+            dyn Fn(
+                    GetResourceShareInvitationsRequest,
+                ) -> ::std::result::Result<
+                    GetResourceShareInvitationsResponse,
+                    RusotoError<GetResourceShareInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_shares: Option<
+        Box<
+            dyn Fn(
+                    GetResourceSharesRequest,
+                ) -> ::std::result::Result<
+                    GetResourceSharesResponse,
+                    RusotoError<GetResourceSharesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_principals: Option<
+        Box<
+            dyn Fn(
+                    ListPrincipalsRequest,
+                ) -> ::std::result::Result<
+                    ListPrincipalsResponse,
+                    RusotoError<ListPrincipalsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resources: Option<
+        Box<
+            dyn Fn(
+                    ListResourcesRequest,
+                )
+                    -> ::std::result::Result<ListResourcesResponse, RusotoError<ListResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reject_resource_share_invitation: Option<
+        Box<
+            dyn Fn(
+                    RejectResourceShareInvitationRequest,
+                ) -> ::std::result::Result<
+                    RejectResourceShareInvitationResponse,
+                    RusotoError<RejectResourceShareInvitationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_resource_share: Option<
+        Box<
+            dyn Fn(
+                    UpdateResourceShareRequest,
+                ) -> ::std::result::Result<
+                    UpdateResourceShareResponse,
+                    RusotoError<UpdateResourceShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockRam {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockRam {
+        Default::default()
+    }
+
+    /// Answers `accept_resource_share_invitation` with what `handler` returns for its input.
+    pub fn with_accept_resource_share_invitation<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                AcceptResourceShareInvitationRequest,
+            ) -> ::std::result::Result<
+                AcceptResourceShareInvitationResponse,
+                RusotoError<AcceptResourceShareInvitationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_resource_share_invitation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_resource_share` with what `handler` returns for its input.
+    pub fn with_associate_resource_share<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                AssociateResourceShareRequest,
+            ) -> ::std::result::Result<
+                AssociateResourceShareResponse,
+                RusotoError<AssociateResourceShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_resource_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource_share` with what `handler` returns for its input.
+    pub fn with_create_resource_share<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                CreateResourceShareRequest,
+            ) -> ::std::result::Result<
+                CreateResourceShareResponse,
+                RusotoError<CreateResourceShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_share` with what `handler` returns for its input.
+    pub fn with_delete_resource_share<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                DeleteResourceShareRequest,
+            ) -> ::std::result::Result<
+                DeleteResourceShareResponse,
+                RusotoError<DeleteResourceShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_resource_share` with what `handler` returns for its input.
+    pub fn with_disassociate_resource_share<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                DisassociateResourceShareRequest,
+            ) -> ::std::result::Result<
+                DisassociateResourceShareResponse,
+                RusotoError<DisassociateResourceShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_resource_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_sharing_with_aws_organization` with what `handler` returns.
+    pub fn with_enable_sharing_with_aws_organization<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn() -> ::std::result::Result<
+                EnableSharingWithAwsOrganizationResponse,
+                RusotoError<EnableSharingWithAwsOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_sharing_with_aws_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_policies` with what `handler` returns for its input.
+    pub fn with_get_resource_policies<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                GetResourcePoliciesRequest,
+            ) -> ::std::result::Result<
+                GetResourcePoliciesResponse,
+                RusotoError<GetResourcePoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_share_associations` with what `handler` returns for its input.
+    pub fn with_get_resource_share_associations<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                GetResourceShareAssociationsRequest,
+            ) -> ::std::result::Result<
+                GetResourceShareAssociationsResponse,
+                RusotoError<GetResourceShareAssociationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_share_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_share_invitations` with what `handler` returns for its input.
+    pub fn with_get_resource_share_invitations<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                GetResourceShareInvitationsRequest,
+            ) -> ::std::result::Result<
+                GetResourceShareInvitationsResponse,
+                RusotoError<GetResourceShareInvitationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_share_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_shares` with what `handler` returns for its input.
+    pub fn with_get_resource_shares<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                GetResourceSharesRequest,
+            ) -> ::std::result::Result<
+                GetResourceSharesResponse,
+                RusotoError<GetResourceSharesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_shares = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_principals` with what `handler` returns for its input.
+    pub fn with_list_principals<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                ListPrincipalsRequest,
+            )
+                -> ::std::result::Result<ListPrincipalsResponse, RusotoError<ListPrincipalsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_principals = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resources` with what `handler` returns for its input.
+    pub fn with_list_resources<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                ListResourcesRequest,
+            )
+                -> ::std::result::Result<ListResourcesResponse, RusotoError<ListResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reject_resource_share_invitation` with what `handler` returns for its input.
+    pub fn with_reject_resource_share_invitation<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                RejectResourceShareInvitationRequest,
+            ) -> ::std::result::Result<
+                RejectResourceShareInvitationResponse,
+                RusotoError<RejectResourceShareInvitationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reject_resource_share_invitation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_resource_share` with what `handler` returns for its input.
+    pub fn with_update_resource_share<F>(mut self, handler: F) -> MockRam
+    where
+        F: Fn(
+                UpdateResourceShareRequest,
+            ) -> ::std::result::Result<
+                UpdateResourceShareResponse,
+                RusotoError<UpdateResourceShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_resource_share = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ram for MockRam {
+    fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> RusotoFuture<AcceptResourceShareInvitationResponse, AcceptResourceShareInvitationError>
+    {
+        match self.accept_resource_share_invitation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for accept_resource_share_invitation"),
+        }
+    }
+    fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> RusotoFuture<AssociateResourceShareResponse, AssociateResourceShareError> {
+        match self.associate_resource_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for associate_resource_share"),
+        }
+    }
+    fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> RusotoFuture<CreateResourceShareResponse, CreateResourceShareError> {
+        match self.create_resource_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for create_resource_share"),
+        }
+    }
+    fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> RusotoFuture<DeleteResourceShareResponse, DeleteResourceShareError> {
+        match self.delete_resource_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for delete_resource_share"),
+        }
+    }
+    fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> RusotoFuture<DisassociateResourceShareResponse, DisassociateResourceShareError> {
+        match self.disassociate_resource_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for disassociate_resource_share"),
+        }
+    }
+    fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> RusotoFuture<EnableSharingWithAwsOrganizationResponse, EnableSharingWithAwsOrganizationError>
+    {
+        match self.enable_sharing_with_aws_organization {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockRam has no handler for enable_sharing_with_aws_organization"),
+        }
+    }
+    fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> RusotoFuture<GetResourcePoliciesResponse, GetResourcePoliciesError> {
+        match self.get_resource_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for get_resource_policies"),
+        }
+    }
+    fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> RusotoFuture<GetResourceShareAssociationsResponse, GetResourceShareAssociationsError> {
+        match self.get_resource_share_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for get_resource_share_associations"),
+        }
+    }
+    fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> RusotoFuture<GetResourceShareInvitationsResponse, GetResourceShareInvitationsError> {
+        match self.get_resource_share_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for get_resource_share_invitations"),
+        }
+    }
+    fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> RusotoFuture<GetResourceSharesResponse, GetResourceSharesError> {
+        match self.get_resource_shares {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for get_resource_shares"),
+        }
+    }
+    fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> RusotoFuture<ListPrincipalsResponse, ListPrincipalsError> {
+        match self.list_principals {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for list_principals"),
+        }
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        match self.list_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for list_resources"),
+        }
+    }
+    fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> RusotoFuture<RejectResourceShareInvitationResponse, RejectResourceShareInvitationError>
+    {
+        match self.reject_resource_share_invitation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for reject_resource_share_invitation"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for untag_resource"),
+        }
+    }
+    fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError> {
+        match self.update_resource_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRam has no handler for update_resource_share"),
+        }
+    }
+}
@@ -22,10 +22,12 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The request parameters represent the input of a SQL statement over an array of
 /// data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchExecuteStatementRequest {
     /// <p>The name of the database.</p>
     #[serde(rename = "database")]
@@ -60,10 +62,57 @@ pub struct BatchExecuteStatementRequest {
     pub transaction_id: Option<String>,
 }
 
+impl Validate for BatchExecuteStatementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.database {
+            errors.check_length(
+                &field_path(path, "database"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        if let Some(ref value) = self.schema {
+            errors.check_length(
+                &field_path(path, "schema"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_arn"),
+            self.secret_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "sql"),
+            self.sql.chars().count(),
+            None,
+            Some(65536),
+        );
+        if let Some(ref value) = self.transaction_id {
+            errors.check_length(
+                &field_path(path, "transaction_id"),
+                value.chars().count(),
+                None,
+                Some(192),
+            );
+        }
+    }
+}
+
 /// <p>The response elements represent the output of a SQL statement over an array of
 /// data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchExecuteStatementResponse {
     /// <p>The execution results of each batch entry.</p>
     #[serde(rename = "updateResults")]
@@ -74,6 +123,7 @@ pub struct BatchExecuteStatementResponse {
 /// <p>The request parameters represent the input of a request to start a SQL
 /// transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BeginTransactionRequest {
     /// <p>The name of the database.</p>
     #[serde(rename = "database")]
@@ -91,10 +141,43 @@ pub struct BeginTransactionRequest {
     pub secret_arn: String,
 }
 
+impl Validate for BeginTransactionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.database {
+            errors.check_length(
+                &field_path(path, "database"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        if let Some(ref value) = self.schema {
+            errors.check_length(
+                &field_path(path, "schema"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_arn"),
+            self.secret_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+    }
+}
+
 /// <p>The response elements represent the output of a request to start a SQL
 /// transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BeginTransactionResponse {
     /// <p>The transaction ID of the transaction started by the call.</p>
     #[serde(rename = "transactionId")]
@@ -104,7 +187,7 @@ pub struct BeginTransactionResponse {
 
 /// <p>Contains the metadata for a column.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ColumnMetadata {
     /// <p>The type of the column.</p>
     #[serde(rename = "arrayBaseColumnType")]
@@ -166,6 +249,7 @@ pub struct ColumnMetadata {
 
 /// <p>The request parameters represent the input of a commit transaction request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CommitTransactionRequest {
     /// <p>The Amazon Resource Name (ARN) of the Aurora Serverless DB cluster.</p>
     #[serde(rename = "resourceArn")]
@@ -178,9 +262,32 @@ pub struct CommitTransactionRequest {
     pub transaction_id: String,
 }
 
+impl Validate for CommitTransactionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "secret_arn"),
+            self.secret_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "transaction_id"),
+            self.transaction_id.chars().count(),
+            None,
+            Some(192),
+        );
+    }
+}
+
 /// <p>The response elements represent the output of a commit transaction request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommitTransactionResponse {
     /// <p>The status of the commit operation.</p>
     #[serde(rename = "transactionStatus")]
@@ -191,6 +298,7 @@ pub struct CommitTransactionResponse {
 /// <p>The request parameters represent the input of a request to run one or more SQL
 /// statements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteSqlRequest {
     /// <p>The Amazon Resource Name (ARN) of the secret that enables access to the DB cluster.</p>
     #[serde(rename = "awsSecretStoreArn")]
@@ -216,10 +324,49 @@ pub struct ExecuteSqlRequest {
     pub sql_statements: String,
 }
 
+impl Validate for ExecuteSqlRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "aws_secret_store_arn"),
+            self.aws_secret_store_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        if let Some(ref value) = self.database {
+            errors.check_length(
+                &field_path(path, "database"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "db_cluster_or_instance_arn"),
+            self.db_cluster_or_instance_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        if let Some(ref value) = self.schema {
+            errors.check_length(
+                &field_path(path, "schema"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "sql_statements"),
+            self.sql_statements.chars().count(),
+            None,
+            Some(65536),
+        );
+    }
+}
+
 /// <p>The response elements represent the output of a request to run one or more SQL
 /// statements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecuteSqlResponse {
     /// <p>The results of the SQL statement or statements.</p>
     #[serde(rename = "sqlStatementResults")]
@@ -230,6 +377,7 @@ pub struct ExecuteSqlResponse {
 /// <p>The request parameters represent the input of a request to run a SQL statement against
 /// a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteStatementRequest {
     /// <p>A value that indicates whether to continue running the statement after
     /// the call times out. By default, the statement stops running when the call
@@ -280,10 +428,57 @@ pub struct ExecuteStatementRequest {
     pub transaction_id: Option<String>,
 }
 
+impl Validate for ExecuteStatementRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.database {
+            errors.check_length(
+                &field_path(path, "database"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        if let Some(ref value) = self.schema {
+            errors.check_length(
+                &field_path(path, "schema"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_arn"),
+            self.secret_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "sql"),
+            self.sql.chars().count(),
+            None,
+            Some(65536),
+        );
+        if let Some(ref value) = self.transaction_id {
+            errors.check_length(
+                &field_path(path, "transaction_id"),
+                value.chars().count(),
+                None,
+                Some(192),
+            );
+        }
+    }
+}
+
 /// <p>The response elements represent the output of a request to run a SQL statement against
 /// a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecuteStatementResponse {
     /// <p>Metadata for the columns included in the results.</p>
     #[serde(rename = "columnMetadata")]
@@ -339,7 +534,7 @@ pub struct Field {
 
 /// <p>A record returned by a call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Record {
     /// <p>The values returned in the record.</p>
     #[serde(rename = "values")]
@@ -349,7 +544,7 @@ pub struct Record {
 
 /// <p>The result set returned by a SQL statement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResultFrame {
     /// <p>The records in the result set.</p>
     #[serde(rename = "records")]
@@ -363,7 +558,7 @@ pub struct ResultFrame {
 
 /// <p>The metadata of the result set returned by a SQL statement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResultSetMetadata {
     /// <p>The number of columns in the result set.</p>
     #[serde(rename = "columnCount")]
@@ -378,6 +573,7 @@ pub struct ResultSetMetadata {
 /// <p>The request parameters represent the input of a request to perform a rollback of a
 /// transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RollbackTransactionRequest {
     /// <p>The Amazon Resource Name (ARN) of the Aurora Serverless DB cluster.</p>
     #[serde(rename = "resourceArn")]
@@ -390,10 +586,33 @@ pub struct RollbackTransactionRequest {
     pub transaction_id: String,
 }
 
+impl Validate for RollbackTransactionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "secret_arn"),
+            self.secret_arn.chars().count(),
+            Some(11),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "transaction_id"),
+            self.transaction_id.chars().count(),
+            None,
+            Some(192),
+        );
+    }
+}
+
 /// <p>The response elements represent the output of a request to perform a rollback of a
 /// transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RollbackTransactionResponse {
     /// <p>The status of the rollback operation.</p>
     #[serde(rename = "transactionStatus")]
@@ -403,6 +622,7 @@ pub struct RollbackTransactionResponse {
 
 /// <p>A parameter used in a SQL statement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SqlParameter {
     /// <p>The name of the parameter.</p>
     #[serde(rename = "name")]
@@ -416,7 +636,7 @@ pub struct SqlParameter {
 
 /// <p>The result of a SQL statement.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SqlStatementResult {
     /// <p>The number of records updated by a SQL statement.</p>
     #[serde(rename = "numberOfRecordsUpdated")]
@@ -430,7 +650,7 @@ pub struct SqlStatementResult {
 
 /// <p>A structure value returned by a call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StructValue {
     /// <p>The attributes returned in the record.</p>
     #[serde(rename = "attributes")]
@@ -440,7 +660,7 @@ pub struct StructValue {
 
 /// <p>The response elements represent the results of an update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateResult {
     /// <p>Values for fields generated during the request.</p>
     #[serde(rename = "generatedFields")]
@@ -450,7 +670,7 @@ pub struct UpdateResult {
 
 /// <p>Contains the value of a column.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Value {
     /// <p>An array of column values.</p>
     #[serde(rename = "arrayValues")]
@@ -540,7 +760,12 @@ impl BatchExecuteStatementError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -602,7 +827,12 @@ impl BeginTransactionError {
                 "StatementTimeoutException" => {
                     return RusotoError::Service(BeginTransactionError::StatementTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -664,7 +894,12 @@ impl CommitTransactionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -717,7 +952,12 @@ impl ExecuteSqlError {
                 "ServiceUnavailableError" => {
                     return RusotoError::Service(ExecuteSqlError::ServiceUnavailableError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -778,7 +1018,12 @@ impl ExecuteStatementError {
                 "StatementTimeoutException" => {
                     return RusotoError::Service(ExecuteStatementError::StatementTimeout(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -840,7 +1085,12 @@ impl RollbackTransactionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -938,6 +1188,121 @@ pub trait RdsData {
         input: RollbackTransactionRequest,
     ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError>;
 }
+const _: Option<&dyn RdsData> = None;
+impl<'a, T: RdsData + ?Sized> RdsData for &'a T {
+    fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> RusotoFuture<BatchExecuteStatementResponse, BatchExecuteStatementError> {
+        (**self).batch_execute_statement(input)
+    }
+    fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> RusotoFuture<BeginTransactionResponse, BeginTransactionError> {
+        (**self).begin_transaction(input)
+    }
+    fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> RusotoFuture<CommitTransactionResponse, CommitTransactionError> {
+        (**self).commit_transaction(input)
+    }
+    fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> RusotoFuture<ExecuteSqlResponse, ExecuteSqlError> {
+        (**self).execute_sql(input)
+    }
+    fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> RusotoFuture<ExecuteStatementResponse, ExecuteStatementError> {
+        (**self).execute_statement(input)
+    }
+    fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError> {
+        (**self).rollback_transaction(input)
+    }
+}
+impl<T: RdsData + ?Sized> RdsData for Box<T> {
+    fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> RusotoFuture<BatchExecuteStatementResponse, BatchExecuteStatementError> {
+        (**self).batch_execute_statement(input)
+    }
+    fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> RusotoFuture<BeginTransactionResponse, BeginTransactionError> {
+        (**self).begin_transaction(input)
+    }
+    fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> RusotoFuture<CommitTransactionResponse, CommitTransactionError> {
+        (**self).commit_transaction(input)
+    }
+    fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> RusotoFuture<ExecuteSqlResponse, ExecuteSqlError> {
+        (**self).execute_sql(input)
+    }
+    fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> RusotoFuture<ExecuteStatementResponse, ExecuteStatementError> {
+        (**self).execute_statement(input)
+    }
+    fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError> {
+        (**self).rollback_transaction(input)
+    }
+}
+impl<T: RdsData + ?Sized> RdsData for ::std::sync::Arc<T> {
+    fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> RusotoFuture<BatchExecuteStatementResponse, BatchExecuteStatementError> {
+        (**self).batch_execute_statement(input)
+    }
+    fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> RusotoFuture<BeginTransactionResponse, BeginTransactionError> {
+        (**self).begin_transaction(input)
+    }
+    fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> RusotoFuture<CommitTransactionResponse, CommitTransactionError> {
+        (**self).commit_transaction(input)
+    }
+    fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> RusotoFuture<ExecuteSqlResponse, ExecuteSqlError> {
+        (**self).execute_sql(input)
+    }
+    fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> RusotoFuture<ExecuteStatementResponse, ExecuteStatementError> {
+        (**self).execute_statement(input)
+    }
+    fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError> {
+        (**self).rollback_transaction(input)
+    }
+}
 /// A client for the AWS RDS DataService API.
 #[derive(Clone)]
 pub struct RdsDataClient {
@@ -991,9 +1356,19 @@ impl RdsData for RdsDataClient {
         &self,
         input: BatchExecuteStatementRequest,
     ) -> RusotoFuture<BatchExecuteStatementResponse, BatchExecuteStatementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/BatchExecute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BatchExecuteStatement");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1003,6 +1378,7 @@ impl RdsData for RdsDataClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchExecuteStatement")
                         .deserialize::<BatchExecuteStatementResponse, _>()?;
 
                     Ok(result)
@@ -1034,9 +1410,19 @@ impl RdsData for RdsDataClient {
         &self,
         input: BeginTransactionRequest,
     ) -> RusotoFuture<BeginTransactionResponse, BeginTransactionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/BeginTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BeginTransaction");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1046,6 +1432,7 @@ impl RdsData for RdsDataClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BeginTransaction")
                         .deserialize::<BeginTransactionResponse, _>()?;
 
                     Ok(result)
@@ -1067,9 +1454,19 @@ impl RdsData for RdsDataClient {
         &self,
         input: CommitTransactionRequest,
     ) -> RusotoFuture<CommitTransactionResponse, CommitTransactionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/CommitTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("CommitTransaction");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1079,6 +1476,7 @@ impl RdsData for RdsDataClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CommitTransaction")
                         .deserialize::<CommitTransactionResponse, _>()?;
 
                     Ok(result)
@@ -1105,9 +1503,19 @@ impl RdsData for RdsDataClient {
         &self,
         input: ExecuteSqlRequest,
     ) -> RusotoFuture<ExecuteSqlResponse, ExecuteSqlError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/ExecuteSql";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteSql");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1117,6 +1525,7 @@ impl RdsData for RdsDataClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ExecuteSql")
                         .deserialize::<ExecuteSqlResponse, _>()?;
 
                     Ok(result)
@@ -1145,9 +1554,19 @@ impl RdsData for RdsDataClient {
         &self,
         input: ExecuteStatementRequest,
     ) -> RusotoFuture<ExecuteStatementResponse, ExecuteStatementError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/Execute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteStatement");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1157,6 +1576,7 @@ impl RdsData for RdsDataClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ExecuteStatement")
                         .deserialize::<ExecuteStatementResponse, _>()?;
 
                     Ok(result)
@@ -1177,9 +1597,19 @@ impl RdsData for RdsDataClient {
         &self,
         input: RollbackTransactionRequest,
     ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/RollbackTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("RollbackTransaction");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1189,6 +1619,7 @@ impl RdsData for RdsDataClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RollbackTransaction")
                         .deserialize::<RollbackTransactionResponse, _>()?;
 
                     Ok(result)
@@ -1203,3 +1634,233 @@ impl RdsData for RdsDataClient {
         })
     }
 }
+
+/// A mock of the AWS RDS DataService API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockRdsData {
+    batch_execute_statement: Option<
+        Box<
+            dyn Fn(
+                    BatchExecuteStatementRequest,
+                ) -> ::std::result::Result<
+                    BatchExecuteStatementResponse,
+                    RusotoError<BatchExecuteStatementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    begin_transaction: Option<
+        Box<
+            dyn Fn(
+                    BeginTransactionRequest,
+                ) -> ::std::result::Result<
+                    BeginTransactionResponse,
+                    RusotoError<BeginTransactionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    commit_transaction: Option<
+        Box<
+            dyn Fn(
+                    CommitTransactionRequest,
+                ) -> ::std::result::Result<
+                    CommitTransactionResponse,
+                    RusotoError<CommitTransactionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    execute_sql: Option<
+        Box<
+            dyn Fn(
+                    ExecuteSqlRequest,
+                )
+                    -> ::std::result::Result<ExecuteSqlResponse, RusotoError<ExecuteSqlError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    execute_statement: Option<
+        Box<
+            dyn Fn(
+                    ExecuteStatementRequest,
+                ) -> ::std::result::Result<
+                    ExecuteStatementResponse,
+                    RusotoError<ExecuteStatementError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    rollback_transaction: Option<
+        Box<
+            dyn Fn(
+                    RollbackTransactionRequest,
+                ) -> ::std::result::Result<
+                    RollbackTransactionResponse,
+                    RusotoError<RollbackTransactionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockRdsData {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockRdsData {
+        Default::default()
+    }
+
+    /// Answers `batch_execute_statement` with what `handler` returns for its input.
+    pub fn with_batch_execute_statement<F>(mut self, handler: F) -> MockRdsData
+    where
+        F: Fn(
+                BatchExecuteStatementRequest,
+            ) -> ::std::result::Result<
+                BatchExecuteStatementResponse,
+                RusotoError<BatchExecuteStatementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_execute_statement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `begin_transaction` with what `handler` returns for its input.
+    pub fn with_begin_transaction<F>(mut self, handler: F) -> MockRdsData
+    where
+        F: Fn(
+                BeginTransactionRequest,
+            ) -> ::std::result::Result<
+                BeginTransactionResponse,
+                RusotoError<BeginTransactionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.begin_transaction = Some(Box::new(handler));
+        self
+    }
+    /// Answers `commit_transaction` with what `handler` returns for its input.
+    pub fn with_commit_transaction<F>(mut self, handler: F) -> MockRdsData
+    where
+        F: Fn(
+                CommitTransactionRequest,
+            ) -> ::std::result::Result<
+                CommitTransactionResponse,
+                RusotoError<CommitTransactionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.commit_transaction = Some(Box::new(handler));
+        self
+    }
+    /// Answers `execute_sql` with what `handler` returns for its input.
+    pub fn with_execute_sql<F>(mut self, handler: F) -> MockRdsData
+    where
+        F: Fn(
+                ExecuteSqlRequest,
+            )
+                -> ::std::result::Result<ExecuteSqlResponse, RusotoError<ExecuteSqlError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.execute_sql = Some(Box::new(handler));
+        self
+    }
+    /// Answers `execute_statement` with what `handler` returns for its input.
+    pub fn with_execute_statement<F>(mut self, handler: F) -> MockRdsData
+    where
+        F: Fn(
+                ExecuteStatementRequest,
+            ) -> ::std::result::Result<
+                ExecuteStatementResponse,
+                RusotoError<ExecuteStatementError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.execute_statement = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rollback_transaction` with what `handler` returns for its input.
+    pub fn with_rollback_transaction<F>(mut self, handler: F) -> MockRdsData
+    where
+        F: Fn(
+                RollbackTransactionRequest,
+            ) -> ::std::result::Result<
+                RollbackTransactionResponse,
+                RusotoError<RollbackTransactionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.rollback_transaction = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl RdsData for MockRdsData {
+    fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> RusotoFuture<BatchExecuteStatementResponse, BatchExecuteStatementError> {
+        match self.batch_execute_statement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRdsData has no handler for batch_execute_statement"),
+        }
+    }
+    fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> RusotoFuture<BeginTransactionResponse, BeginTransactionError> {
+        match self.begin_transaction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRdsData has no handler for begin_transaction"),
+        }
+    }
+    fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> RusotoFuture<CommitTransactionResponse, CommitTransactionError> {
+        match self.commit_transaction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRdsData has no handler for commit_transaction"),
+        }
+    }
+    fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> RusotoFuture<ExecuteSqlResponse, ExecuteSqlError> {
+        match self.execute_sql {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRdsData has no handler for execute_sql"),
+        }
+    }
+    fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> RusotoFuture<ExecuteStatementResponse, ExecuteStatementError> {
+        match self.execute_statement {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRdsData has no handler for execute_statement"),
+        }
+    }
+    fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError> {
+        match self.rollback_transaction {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRdsData has no handler for rollback_transaction"),
+        }
+    }
+}
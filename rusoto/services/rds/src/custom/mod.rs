@@ -1 +1,8 @@
+#[cfg(test)]
+mod custom_tests;
 
+/// Presigned URLs for copying encrypted cluster snapshots across regions
+pub mod presign;
+
+/// The topology of Aurora clusters, and watching it for failovers
+pub mod topology;
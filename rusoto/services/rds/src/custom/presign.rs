@@ -0,0 +1,105 @@
+//! Presigned URLs for copying encrypted cluster snapshots across regions.
+//!
+//! Copying an encrypted cluster snapshot from another region takes a `PreSignedUrl`: a
+//! `CopyDBClusterSnapshot` request for the source region, signed with query parameters,
+//! which the destination region sends on the caller's behalf. It names the destination
+//! region with a `DestinationRegion` parameter, which isn't a member of the request.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::Future;
+//! use rusoto_core::credential::{DefaultCredentialsProvider, ProvideAwsCredentials};
+//! use rusoto_core::Region;
+//! use rusoto_rds::presign::presign_copy_db_cluster_snapshot;
+//! use rusoto_rds::{CopyDBClusterSnapshotMessage, Rds, RdsClient};
+//!
+//! let credentials = DefaultCredentialsProvider::new()
+//!     .unwrap()
+//!     .credentials()
+//!     .wait()
+//!     .unwrap();
+//! let mut copy = CopyDBClusterSnapshotMessage {
+//!     source_db_cluster_snapshot_identifier:
+//!         "arn:aws:rds:us-west-2:123456789012:cluster-snapshot:orders".to_owned(),
+//!     target_db_cluster_snapshot_identifier: "orders-copy".to_owned(),
+//!     kms_key_id: Some("alias/orders".to_owned()),
+//!     ..Default::default()
+//! };
+//! copy.pre_signed_url = Some(presign_copy_db_cluster_snapshot(
+//!     &Region::UsWest2,
+//!     &Region::UsEast1,
+//!     &copy,
+//!     &credentials,
+//! ));
+//! let client = RdsClient::new(Region::UsEast1);
+//! client.copy_db_cluster_snapshot(copy).sync().unwrap();
+//! ```
+
+use std::time::Duration;
+
+use rusoto_core::credential::{AwsCredentials, Clock, SystemClock};
+use rusoto_core::param::{Params, ServiceParams};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::Region;
+
+use crate::generated::CopyDBClusterSnapshotMessage;
+
+/// How long presigned URLs are valid for.
+const EXPIRES_IN: Duration = Duration::from_secs(3600);
+
+/// The `PreSignedUrl` of `input`, copying a cluster snapshot from `source_region` to
+/// `destination_region`, valid for an hour.
+///
+/// The URL is signed with `credentials` for the source region, and holds the identifiers
+/// of the snapshots, `KmsKeyId` and `CopyTags` of `input`. Its tags are only applied by the
+/// request sent to the destination region, and its `pre_signed_url` is left out.
+pub fn presign_copy_db_cluster_snapshot(
+    source_region: &Region,
+    destination_region: &Region,
+    input: &CopyDBClusterSnapshotMessage,
+    credentials: &AwsCredentials,
+) -> String {
+    presign_copy_db_cluster_snapshot_with_clock(
+        source_region,
+        destination_region,
+        input,
+        credentials,
+        &SystemClock,
+    )
+}
+
+/// The `PreSignedUrl` of `input` like `presign_copy_db_cluster_snapshot`, dated with the
+/// time of `clock`.
+pub fn presign_copy_db_cluster_snapshot_with_clock(
+    source_region: &Region,
+    destination_region: &Region,
+    input: &CopyDBClusterSnapshotMessage,
+    credentials: &AwsCredentials,
+    clock: &dyn Clock,
+) -> String {
+    let mut params = Params::new();
+    params.put("Action", "CopyDBClusterSnapshot");
+    params.put("Version", "2014-10-31");
+    if let Some(copy_tags) = input.copy_tags {
+        params.put("CopyTags", copy_tags);
+    }
+    if let Some(ref kms_key_id) = input.kms_key_id {
+        params.put("KmsKeyId", kms_key_id);
+    }
+    params.put(
+        "SourceDBClusterSnapshotIdentifier",
+        &input.source_db_cluster_snapshot_identifier,
+    );
+    params.put(
+        "TargetDBClusterSnapshotIdentifier",
+        &input.target_db_cluster_snapshot_identifier,
+    );
+    params.put("DestinationRegion", destination_region.name());
+
+    let mut request = SignedRequest::new("GET", "rds", source_region, "/");
+    request.set_params(params);
+    // the parameters hold values rather than being parsed from a query string, so a `+`
+    // in them, as in session tokens, is a plus sign
+    request.generate_presigned_url_with_plus(credentials, &EXPIRES_IN, true, true, clock)
+}
@@ -0,0 +1,276 @@
+//! The topology of Aurora clusters: which instance is the writer, which are readers, and
+//! where to connect to each of them.
+//!
+//! `RdsClient::cluster_topology` describes a cluster and its instances once, and
+//! `RdsClient::watch_topology` describes them again and again, yielding the topology
+//! whenever it changes, such as when a failover promotes a reader to be the writer or an
+//! instance is added or removed, so that connection pools can rebalance.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use futures::{Future, Stream};
+//! use rusoto_core::Region;
+//! use rusoto_rds::RdsClient;
+//!
+//! let client = RdsClient::new(Region::UsEast1);
+//! let watch = client
+//!     .watch_topology("orders", Duration::from_secs(10))
+//!     .for_each(|topology| {
+//!         if let Some(writer) = topology.writer {
+//!             println!("writing to {:?}", writer.address);
+//!         }
+//!         Ok(())
+//!     });
+//! tokio::run(watch.map_err(|err| eprintln!("watching the cluster failed: {}", err)));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use futures::{Async, Future, Poll, Stream};
+use rusoto_core::RusotoError;
+use tokio_timer::Delay;
+
+use crate::generated::{
+    DBInstance, DescribeDBClustersError, DescribeDBClustersMessage, DescribeDBInstancesError,
+    DescribeDBInstancesMessage, Filter, Rds, RdsClient,
+};
+
+/// The role of an instance in its cluster.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    /// The instance the cluster writes to.
+    Writer,
+    /// A replica serving reads.
+    Reader,
+}
+
+/// Where to connect to an instance of a cluster, and its state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    /// The identifier of the instance.
+    pub instance_id: String,
+    /// The role of the instance in the cluster.
+    pub role: Role,
+    /// The DNS address of the instance, unknown until it is created.
+    pub address: Option<String>,
+    /// The port the instance listens on.
+    pub port: Option<i64>,
+    /// The availability zone the instance is in.
+    pub availability_zone: Option<String>,
+    /// The status of the instance, such as `available` or `rebooting`.
+    pub status: Option<String>,
+}
+
+/// The instances of a cluster, by role.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClusterTopology {
+    /// The identifier of the cluster.
+    pub cluster_id: String,
+    /// The status of the cluster, such as `available` or `failing-over`.
+    pub status: Option<String>,
+    /// The DNS address of the cluster endpoint, which follows the writer.
+    pub cluster_endpoint: Option<String>,
+    /// The DNS address of the reader endpoint, which balances connections across readers.
+    pub reader_endpoint: Option<String>,
+    /// The writer, or `None` while a failover is promoting a reader.
+    pub writer: Option<Endpoint>,
+    /// The readers, ordered by instance identifier.
+    pub readers: Vec<Endpoint>,
+}
+
+/// An error describing the topology of a cluster.
+#[derive(Debug, PartialEq)]
+pub enum TopologyError {
+    /// Describing the cluster failed.
+    DescribeCluster(RusotoError<DescribeDBClustersError>),
+    /// Describing the instances of the cluster failed.
+    DescribeInstances(RusotoError<DescribeDBInstancesError>),
+    /// No cluster has the identifier.
+    ClusterNotFound(String),
+    /// The timer waiting between descriptions of the cluster failed.
+    Timer(String),
+}
+
+impl fmt::Display for TopologyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TopologyError::DescribeCluster(ref err) => {
+                write!(f, "describing the cluster failed: {}", err)
+            }
+            TopologyError::DescribeInstances(ref err) => {
+                write!(f, "describing the instances of the cluster failed: {}", err)
+            }
+            TopologyError::ClusterNotFound(ref cluster_id) => {
+                write!(f, "no cluster is named {}", cluster_id)
+            }
+            TopologyError::Timer(ref message) => write!(f, "timer error: {}", message),
+        }
+    }
+}
+
+impl Error for TopologyError {
+    fn description(&self) -> &str {
+        match *self {
+            TopologyError::DescribeCluster(_) => "describing the cluster failed",
+            TopologyError::DescribeInstances(_) => "describing the instances of the cluster failed",
+            TopologyError::ClusterNotFound(_) => "cluster not found",
+            TopologyError::Timer(_) => "timer error",
+        }
+    }
+}
+
+impl RdsClient {
+    /// Describe the cluster `cluster_id` and its instances.
+    pub fn cluster_topology(
+        &self,
+        cluster_id: &str,
+    ) -> impl Future<Item = ClusterTopology, Error = TopologyError> + Send {
+        describe_topology(self.clone(), cluster_id.to_owned())
+    }
+
+    /// Describe the cluster `cluster_id` every `interval`, yielding its topology when first
+    /// described and then whenever it changes.
+    ///
+    /// Errors are yielded as they happen, and the cluster is described again after them.
+    /// The stream never ends, so drop it to stop watching.
+    pub fn watch_topology(&self, cluster_id: &str, interval: Duration) -> TopologyWatch {
+        TopologyWatch {
+            client: self.clone(),
+            cluster_id: cluster_id.to_owned(),
+            interval,
+            last: None,
+            state: WatchState::Waiting(Delay::new(Instant::now())),
+        }
+    }
+}
+
+/// The stream returned by `RdsClient::watch_topology`.
+pub struct TopologyWatch {
+    client: RdsClient,
+    cluster_id: String,
+    interval: Duration,
+    /// The topology yielded last.
+    last: Option<ClusterTopology>,
+    state: WatchState,
+}
+
+enum WatchState {
+    Describing(Box<dyn Future<Item = ClusterTopology, Error = TopologyError> + Send>),
+    Waiting(Delay),
+}
+
+impl Stream for TopologyWatch {
+    type Item = ClusterTopology;
+    type Error = TopologyError;
+
+    fn poll(&mut self) -> Poll<Option<ClusterTopology>, TopologyError> {
+        loop {
+            let next = match self.state {
+                WatchState::Describing(ref mut future) => {
+                    let described = match future.poll() {
+                        Ok(Async::NotReady) => return Ok(Async::NotReady),
+                        Ok(Async::Ready(topology)) => Ok(topology),
+                        Err(err) => Err(err),
+                    };
+                    self.state = WatchState::Waiting(Delay::new(Instant::now() + self.interval));
+                    let topology = described?;
+                    if self.last.as_ref() == Some(&topology) {
+                        continue;
+                    }
+                    self.last = Some(topology.clone());
+                    return Ok(Async::Ready(Some(topology)));
+                }
+                WatchState::Waiting(ref mut delay) => {
+                    match delay.poll() {
+                        Ok(Async::Ready(())) => {}
+                        Ok(Async::NotReady) => return Ok(Async::NotReady),
+                        Err(err) => return Err(TopologyError::Timer(err.to_string())),
+                    }
+                    Box::new(describe_topology(
+                        self.client.clone(),
+                        self.cluster_id.clone(),
+                    ))
+                }
+            };
+            self.state = WatchState::Describing(next);
+        }
+    }
+}
+
+fn describe_topology<C: Rds + Send + 'static>(
+    client: C,
+    cluster_id: String,
+) -> impl Future<Item = ClusterTopology, Error = TopologyError> + Send {
+    let clusters = DescribeDBClustersMessage {
+        db_cluster_identifier: Some(cluster_id.clone()),
+        ..Default::default()
+    };
+    client
+        .describe_db_clusters(clusters)
+        .map_err(TopologyError::DescribeCluster)
+        .and_then(move |output| {
+            let cluster = output
+                .db_clusters
+                .and_then(|clusters| clusters.into_iter().next())
+                .ok_or_else(|| TopologyError::ClusterNotFound(cluster_id.clone()))?;
+            Ok((client, cluster_id, cluster))
+        })
+        .and_then(|(client, cluster_id, cluster)| {
+            let instances = DescribeDBInstancesMessage {
+                filters: Some(vec![Filter {
+                    name: "db-cluster-id".to_owned(),
+                    values: vec![cluster_id.clone()],
+                }]),
+                ..Default::default()
+            };
+            client
+                .describe_db_instances(instances)
+                .map_err(TopologyError::DescribeInstances)
+                .map(move |output| {
+                    let instances = output.db_instances.unwrap_or_default();
+                    let mut writer = None;
+                    let mut readers = Vec::new();
+                    for member in cluster.db_cluster_members.unwrap_or_default() {
+                        let instance_id = match member.db_instance_identifier {
+                            Some(instance_id) => instance_id,
+                            None => continue,
+                        };
+                        let instance = instances.iter().find(|instance| {
+                            instance.db_instance_identifier.as_ref() == Some(&instance_id)
+                        });
+                        if member.is_cluster_writer == Some(true) {
+                            writer = Some(endpoint(instance_id, Role::Writer, instance));
+                        } else {
+                            readers.push(endpoint(instance_id, Role::Reader, instance));
+                        }
+                    }
+                    readers.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+                    ClusterTopology {
+                        cluster_id,
+                        status: cluster.status,
+                        cluster_endpoint: cluster.endpoint,
+                        reader_endpoint: cluster.reader_endpoint,
+                        writer,
+                        readers,
+                    }
+                })
+        })
+}
+
+/// The endpoint of a member of a cluster, described by `instance` if it was found.
+fn endpoint(instance_id: String, role: Role, instance: Option<&DBInstance>) -> Endpoint {
+    let address = instance.and_then(|instance| instance.endpoint.as_ref());
+    Endpoint {
+        instance_id,
+        role,
+        address: address.and_then(|address| address.address.clone()),
+        port: address.and_then(|address| address.port),
+        availability_zone: instance.and_then(|instance| instance.availability_zone.clone()),
+        status: instance.and_then(|instance| instance.db_instance_status.clone()),
+    }
+}
@@ -23,17 +23,20 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptReservedNodeExchangeInputMessage {
     /// <p>A string representing the node identifier of the DC1 Reserved Node to be exchanged.</p>
     pub reserved_node_id: String,
@@ -41,6 +44,23 @@ pub struct AcceptReservedNodeExchangeInputMessage {
     pub target_reserved_node_offering_id: String,
 }
 
+impl Validate for AcceptReservedNodeExchangeInputMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "reserved_node_id"),
+            self.reserved_node_id.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "target_reserved_node_offering_id"),
+            self.target_reserved_node_offering_id.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `AcceptReservedNodeExchangeInputMessage` contents to a `SignedRequest`.
 struct AcceptReservedNodeExchangeInputMessageSerializer;
 impl AcceptReservedNodeExchangeInputMessageSerializer {
@@ -62,6 +82,8 @@ impl AcceptReservedNodeExchangeInputMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptReservedNodeExchangeOutputMessage {
     /// <p><p/></p>
     pub exchanged_reserved_node: Option<ReservedNode>,
@@ -94,6 +116,8 @@ impl AcceptReservedNodeExchangeOutputMessageDeserializer {
 }
 /// <p>A name value pair that describes an aspect of an account. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountAttribute {
     /// <p>The name of the attribute.</p>
     pub attribute_name: Option<String>,
@@ -126,6 +150,8 @@ impl AccountAttributeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountAttributeList {
     /// <p>A list of attributes assigned to an account.</p>
     pub account_attributes: Option<Vec<AccountAttribute>>,
@@ -153,6 +179,8 @@ impl AccountAttributeListDeserializer {
 }
 /// <p>Describes an AWS customer account authorized to restore a snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountWithRestoreAccess {
     /// <p>The identifier of an AWS support account authorized to restore a snapshot. For AWS support, the identifier is <code>amazon-redshift-support</code>. </p>
     pub account_alias: Option<String>,
@@ -280,6 +308,8 @@ impl AttributeValueListDeserializer {
 }
 /// <p>Describes an attribute value.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttributeValueTarget {
     /// <p>The value of the attribute.</p>
     pub attribute_value: Option<String>,
@@ -306,6 +336,8 @@ impl AttributeValueTargetDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeClusterSecurityGroupIngressMessage {
     /// <p>The IP range to be added the Amazon Redshift security group.</p>
     pub cidrip: Option<String>,
@@ -317,6 +349,41 @@ pub struct AuthorizeClusterSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: Option<String>,
 }
 
+impl Validate for AuthorizeClusterSecurityGroupIngressMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cidrip {
+            errors.check_length(
+                &field_path(path, "cidrip"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "cluster_security_group_name"),
+            self.cluster_security_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.ec2_security_group_name {
+            errors.check_length(
+                &field_path(path, "ec2_security_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.ec2_security_group_owner_id {
+            errors.check_length(
+                &field_path(path, "ec2_security_group_owner_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `AuthorizeClusterSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct AuthorizeClusterSecurityGroupIngressMessageSerializer;
 impl AuthorizeClusterSecurityGroupIngressMessageSerializer {
@@ -353,6 +420,8 @@ impl AuthorizeClusterSecurityGroupIngressMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeClusterSecurityGroupIngressResult {
     pub cluster_security_group: Option<ClusterSecurityGroup>,
 }
@@ -385,6 +454,8 @@ impl AuthorizeClusterSecurityGroupIngressResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeSnapshotAccessMessage {
     /// <p>The identifier of the AWS customer account authorized to restore the specified snapshot.</p> <p>To share a snapshot with AWS support, specify amazon-redshift-support.</p>
     pub account_with_restore_access: String,
@@ -394,6 +465,31 @@ pub struct AuthorizeSnapshotAccessMessage {
     pub snapshot_identifier: String,
 }
 
+impl Validate for AuthorizeSnapshotAccessMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_with_restore_access"),
+            self.account_with_restore_access.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.snapshot_cluster_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `AuthorizeSnapshotAccessMessage` contents to a `SignedRequest`.
 struct AuthorizeSnapshotAccessMessageSerializer;
 impl AuthorizeSnapshotAccessMessageSerializer {
@@ -421,6 +517,8 @@ impl AuthorizeSnapshotAccessMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeSnapshotAccessResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -449,6 +547,8 @@ impl AuthorizeSnapshotAccessResultDeserializer {
 }
 /// <p>Describes an availability zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AvailabilityZone {
     /// <p>The name of the availability zone.</p>
     pub name: Option<String>,
@@ -503,11 +603,22 @@ impl AvailabilityZoneListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteClusterSnapshotsRequest {
     /// <p>A list of identifiers for the snapshots that you want to delete.</p>
     pub identifiers: Vec<DeleteClusterSnapshotMessage>,
 }
 
+impl Validate for BatchDeleteClusterSnapshotsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.identifiers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "identifiers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `BatchDeleteClusterSnapshotsRequest` contents to a `SignedRequest`.
 struct BatchDeleteClusterSnapshotsRequestSerializer;
 impl BatchDeleteClusterSnapshotsRequestSerializer {
@@ -526,6 +637,8 @@ impl BatchDeleteClusterSnapshotsRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteClusterSnapshotsResult {
     /// <p>A list of any errors returned.</p>
     pub errors: Option<Vec<SnapshotErrorMessage>>,
@@ -565,6 +678,8 @@ impl BatchDeleteClusterSnapshotsResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchModifyClusterSnapshotsMessage {
     /// <p>A boolean value indicating whether to override an exception if the retention period has passed. </p>
     pub force: Option<bool>,
@@ -574,6 +689,15 @@ pub struct BatchModifyClusterSnapshotsMessage {
     pub snapshot_identifier_list: Vec<String>,
 }
 
+impl Validate for BatchModifyClusterSnapshotsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.snapshot_identifier_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "snapshot_identifier_list"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+        }
+    }
+}
+
 /// Serialize `BatchModifyClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct BatchModifyClusterSnapshotsMessageSerializer;
 impl BatchModifyClusterSnapshotsMessageSerializer {
@@ -601,6 +725,8 @@ impl BatchModifyClusterSnapshotsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchModifyClusterSnapshotsOutputMessage {
     /// <p>A list of any errors returned.</p>
     pub errors: Option<Vec<SnapshotErrorMessage>>,
@@ -682,7 +808,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -693,18 +819,31 @@ impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelResizeMessage {
     /// <p>The unique identifier for the cluster that you want to cancel a resize operation for.</p>
     pub cluster_identifier: String,
 }
 
+impl Validate for CancelResizeMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `CancelResizeMessage` contents to a `SignedRequest`.
 struct CancelResizeMessageSerializer;
 impl CancelResizeMessageSerializer {
@@ -723,6 +862,8 @@ impl CancelResizeMessageSerializer {
 
 /// <p>Describes a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Cluster {
     /// <p>A boolean value that, if <code>true</code>, indicates that major version upgrades will be applied automatically to the cluster during the maintenance window. </p>
     pub allow_version_upgrade: Option<bool>,
@@ -1069,6 +1210,8 @@ impl ClusterDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterAssociatedToSchedule {
     /// <p><p/></p>
     pub cluster_identifier: Option<String>,
@@ -1108,6 +1251,8 @@ impl ClusterAssociatedToScheduleDeserializer {
 }
 /// <p>Temporary credentials with authorization to log on to an Amazon Redshift database. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterCredentials {
     /// <p>A temporary password that authorizes the user name returned by <code>DbUser</code> to log on to the database <code>DbName</code>. </p>
     pub db_password: Option<String>,
@@ -1146,6 +1291,8 @@ impl ClusterCredentialsDeserializer {
 }
 /// <p>Describes a <code>ClusterDbRevision</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterDbRevision {
     /// <p>The unique identifier of the cluster.</p>
     pub cluster_identifier: Option<String>,
@@ -1214,6 +1361,8 @@ impl ClusterDbRevisionsListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterDbRevisionsMessage {
     /// <p>A list of revisions.</p>
     pub cluster_db_revisions: Option<Vec<ClusterDbRevision>>,
@@ -1253,6 +1402,8 @@ impl ClusterDbRevisionsMessageDeserializer {
 }
 /// <p>An AWS Identity and Access Management (IAM) role that can be used by the associated Amazon Redshift cluster to access other AWS services.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterIamRole {
     /// <p><p>A value that describes the status of the IAM role&#39;s association with an Amazon Redshift cluster.</p> <p>The following are possible statuses and descriptions.</p> <ul> <li> <p> <code>in-sync</code>: The role is available for use by the cluster.</p> </li> <li> <p> <code>adding</code>: The role is in the process of being associated with the cluster.</p> </li> <li> <p> <code>removing</code>: The role is in the process of being disassociated with the cluster.</p> </li> </ul></p>
     pub apply_status: Option<String>,
@@ -1320,6 +1471,8 @@ impl ClusterListDeserializer {
 }
 /// <p>The identifier of a node in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterNode {
     /// <p>Whether the node is a leader node or a compute node.</p>
     pub node_role: Option<String>,
@@ -1374,6 +1527,8 @@ impl ClusterNodesListDeserializer {
 }
 /// <p>Describes a parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterParameterGroup {
     /// <p>The description of the parameter group.</p>
     pub description: Option<String>,
@@ -1422,6 +1577,8 @@ impl ClusterParameterGroupDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeClusterParameters</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterParameterGroupDetails {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -1458,6 +1615,8 @@ impl ClusterParameterGroupDetailsDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterParameterGroupNameMessage {
     /// <p>The name of the cluster parameter group.</p>
     pub parameter_group_name: Option<String>,
@@ -1498,6 +1657,8 @@ impl ClusterParameterGroupNameMessageDeserializer {
 }
 /// <p>Describes the status of a parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterParameterGroupStatus {
     /// <p>The list of parameter statuses.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     pub cluster_parameter_status_list: Option<Vec<ClusterParameterStatus>>,
@@ -1568,6 +1729,8 @@ impl ClusterParameterGroupStatusListDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeClusterParameterGroups</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterParameterGroupsMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -1604,6 +1767,8 @@ impl ClusterParameterGroupsMessageDeserializer {
 }
 /// <p>Describes the status of a parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterParameterStatus {
     /// <p>The error that prevented the parameter from being applied to the database.</p>
     pub parameter_apply_error_description: Option<String>,
@@ -1665,6 +1830,8 @@ impl ClusterParameterStatusListDeserializer {
 }
 /// <p>Describes a security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterSecurityGroup {
     /// <p>The name of the cluster security group to which the operation was applied.</p>
     pub cluster_security_group_name: Option<String>,
@@ -1719,6 +1886,8 @@ impl ClusterSecurityGroupDeserializer {
 }
 /// <p>Describes a cluster security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterSecurityGroupMembership {
     /// <p>The name of the cluster security group.</p>
     pub cluster_security_group_name: Option<String>,
@@ -1776,6 +1945,8 @@ impl ClusterSecurityGroupMembershipListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterSecurityGroupMessage {
     /// <p>A list of <a>ClusterSecurityGroup</a> instances. </p>
     pub cluster_security_groups: Option<Vec<ClusterSecurityGroup>>,
@@ -1847,6 +2018,8 @@ impl ClusterSecurityGroupsDeserializer {
 }
 /// <p>Returns the destination region and retention period that are configured for cross-region snapshot copy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterSnapshotCopyStatus {
     /// <p>The destination region that snapshots are automatically copied to when cross-region snapshot copy is enabled.</p>
     pub destination_region: Option<String>,
@@ -1900,6 +2073,8 @@ impl ClusterSnapshotCopyStatusDeserializer {
 }
 /// <p>Describes a subnet group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterSubnetGroup {
     /// <p>The name of the cluster subnet group.</p>
     pub cluster_subnet_group_name: Option<String>,
@@ -1958,6 +2133,8 @@ impl ClusterSubnetGroupDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeClusterSubnetGroups</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterSubnetGroupMessage {
     /// <p>A list of <a>ClusterSubnetGroup</a> instances. </p>
     pub cluster_subnet_groups: Option<Vec<ClusterSubnetGroup>>,
@@ -2017,6 +2194,8 @@ impl ClusterSubnetGroupsDeserializer {
 }
 /// <p>Describes a cluster version, including the parameter group family and description of the version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterVersion {
     /// <p>The name of the cluster parameter group family for the cluster.</p>
     pub cluster_parameter_group_family: Option<String>,
@@ -2076,6 +2255,8 @@ impl ClusterVersionListDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeClusterVersions</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClusterVersionsMessage {
     /// <p>A list of <code>Version</code> elements. </p>
     pub cluster_versions: Option<Vec<ClusterVersion>>,
@@ -2108,6 +2289,8 @@ impl ClusterVersionsMessageDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeClusters</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ClustersMessage {
     /// <p>A list of <code>Cluster</code> objects, where each object describes one cluster. </p>
     pub clusters: Option<Vec<Cluster>>,
@@ -2140,6 +2323,8 @@ impl ClustersMessageDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyClusterSnapshotMessage {
     /// <p>The number of days that a manual snapshot is retained. If the value is -1, the manual snapshot is retained indefinitely. </p> <p>The value must be either -1 or an integer between 1 and 3,653.</p> <p>The default value is -1.</p>
     pub manual_snapshot_retention_period: Option<i64>,
@@ -2151,6 +2336,31 @@ pub struct CopyClusterSnapshotMessage {
     pub target_snapshot_identifier: String,
 }
 
+impl Validate for CopyClusterSnapshotMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_snapshot_cluster_identifier {
+            errors.check_length(
+                &field_path(path, "source_snapshot_cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "source_snapshot_identifier"),
+            self.source_snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "target_snapshot_identifier"),
+            self.target_snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `CopyClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CopyClusterSnapshotMessageSerializer;
 impl CopyClusterSnapshotMessageSerializer {
@@ -2184,6 +2394,8 @@ impl CopyClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -2212,6 +2424,8 @@ impl CopyClusterSnapshotResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterMessage {
     /// <p>Reserved.</p>
     pub additional_info: Option<String>,
@@ -2275,6 +2489,165 @@ pub struct CreateClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl Validate for CreateClusterMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_info {
+            errors.check_length(
+                &field_path(path, "additional_info"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.availability_zone {
+            errors.check_length(
+                &field_path(path, "availability_zone"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.cluster_parameter_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_parameter_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "cluster_security_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.cluster_subnet_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_subnet_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_type {
+            errors.check_length(
+                &field_path(path, "cluster_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_version {
+            errors.check_length(
+                &field_path(path, "cluster_version"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.db_name {
+            errors.check_length(
+                &field_path(path, "db_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.elastic_ip {
+            errors.check_length(
+                &field_path(path, "elastic_ip"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hsm_client_certificate_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_client_certificate_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hsm_configuration_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_configuration_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.iam_roles {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "iam_roles"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.maintenance_track_name {
+            errors.check_length(
+                &field_path(path, "maintenance_track_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "master_username"),
+            self.master_username.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "node_type"),
+            self.node_type.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.preferred_maintenance_window {
+            errors.check_length(
+                &field_path(path, "preferred_maintenance_window"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.snapshot_schedule_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_schedule_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.vpc_security_group_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "vpc_security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `CreateClusterMessage` contents to a `SignedRequest`.
 struct CreateClusterMessageSerializer;
 impl CreateClusterMessageSerializer {
@@ -2422,6 +2795,8 @@ impl CreateClusterMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterParameterGroupMessage {
     /// <p>A description of the parameter group.</p>
     pub description: String,
@@ -2433,6 +2808,35 @@ pub struct CreateClusterParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateClusterParameterGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "parameter_group_family"),
+            self.parameter_group_family.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "parameter_group_name"),
+            self.parameter_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateClusterParameterGroupMessageSerializer;
 impl CreateClusterParameterGroupMessageSerializer {
@@ -2458,6 +2862,8 @@ impl CreateClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterParameterGroupResult {
     pub cluster_parameter_group: Option<ClusterParameterGroup>,
 }
@@ -2489,6 +2895,8 @@ impl CreateClusterParameterGroupResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterResult {
     pub cluster: Option<Cluster>,
 }
@@ -2513,6 +2921,8 @@ impl CreateClusterResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterSecurityGroupMessage {
     /// <p>The name for the security group. Amazon Redshift stores the value as a lowercase string.</p> <p>Constraints:</p> <ul> <li> <p>Must contain no more than 255 alphanumeric characters or hyphens.</p> </li> <li> <p>Must not be "Default".</p> </li> <li> <p>Must be unique for all security groups that are created by your AWS account.</p> </li> </ul> <p>Example: <code>examplesecuritygroup</code> </p>
     pub cluster_security_group_name: String,
@@ -2522,6 +2932,29 @@ pub struct CreateClusterSecurityGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateClusterSecurityGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_security_group_name"),
+            self.cluster_security_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateClusterSecurityGroupMessage` contents to a `SignedRequest`.
 struct CreateClusterSecurityGroupMessageSerializer;
 impl CreateClusterSecurityGroupMessageSerializer {
@@ -2543,6 +2976,8 @@ impl CreateClusterSecurityGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterSecurityGroupResult {
     pub cluster_security_group: Option<ClusterSecurityGroup>,
 }
@@ -2575,6 +3010,8 @@ impl CreateClusterSecurityGroupResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterSnapshotMessage {
     /// <p>The cluster identifier for which you want a snapshot.</p>
     pub cluster_identifier: String,
@@ -2586,6 +3023,29 @@ pub struct CreateClusterSnapshotMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateClusterSnapshotMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CreateClusterSnapshotMessageSerializer;
 impl CreateClusterSnapshotMessageSerializer {
@@ -2616,6 +3076,8 @@ impl CreateClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -2644,6 +3106,8 @@ impl CreateClusterSnapshotResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterSubnetGroupMessage {
     /// <p>The name for the subnet group. Amazon Redshift stores the value as a lowercase string.</p> <p>Constraints:</p> <ul> <li> <p>Must contain no more than 255 alphanumeric characters or hyphens.</p> </li> <li> <p>Must not be "Default".</p> </li> <li> <p>Must be unique for all subnet groups that are created by your AWS account.</p> </li> </ul> <p>Example: <code>examplesubnetgroup</code> </p>
     pub cluster_subnet_group_name: String,
@@ -2655,6 +3119,33 @@ pub struct CreateClusterSubnetGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateClusterSubnetGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_subnet_group_name"),
+            self.cluster_subnet_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateClusterSubnetGroupMessage` contents to a `SignedRequest`.
 struct CreateClusterSubnetGroupMessageSerializer;
 impl CreateClusterSubnetGroupMessageSerializer {
@@ -2681,6 +3172,8 @@ impl CreateClusterSubnetGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterSubnetGroupResult {
     pub cluster_subnet_group: Option<ClusterSubnetGroup>,
 }
@@ -2713,6 +3206,8 @@ impl CreateClusterSubnetGroupResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEventSubscriptionMessage {
     /// <p>A boolean value; set to <code>true</code> to activate the subscription, and set to <code>false</code> to create the subscription but not activate it. </p>
     pub enabled: Option<bool>,
@@ -2732,6 +3227,57 @@ pub struct CreateEventSubscriptionMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateEventSubscriptionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_categories {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_categories"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.severity {
+            errors.check_length(
+                &field_path(path, "severity"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "sns_topic_arn"),
+            self.sns_topic_arn.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.source_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "source_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.source_type {
+            errors.check_length(
+                &field_path(path, "source_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "subscription_name"),
+            self.subscription_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateEventSubscriptionMessage` contents to a `SignedRequest`.
 struct CreateEventSubscriptionMessageSerializer;
 impl CreateEventSubscriptionMessageSerializer {
@@ -2776,6 +3322,8 @@ impl CreateEventSubscriptionMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
@@ -2807,6 +3355,8 @@ impl CreateEventSubscriptionResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHsmClientCertificateMessage {
     /// <p>The identifier to be assigned to the new HSM client certificate that the cluster will use to connect to the HSM to use the database encryption keys.</p>
     pub hsm_client_certificate_identifier: String,
@@ -2814,6 +3364,23 @@ pub struct CreateHsmClientCertificateMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateHsmClientCertificateMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hsm_client_certificate_identifier"),
+            self.hsm_client_certificate_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateHsmClientCertificateMessage` contents to a `SignedRequest`.
 struct CreateHsmClientCertificateMessageSerializer;
 impl CreateHsmClientCertificateMessageSerializer {
@@ -2834,6 +3401,8 @@ impl CreateHsmClientCertificateMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHsmClientCertificateResult {
     pub hsm_client_certificate: Option<HsmClientCertificate>,
 }
@@ -2866,6 +3435,8 @@ impl CreateHsmClientCertificateResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHsmConfigurationMessage {
     /// <p>A text description of the HSM configuration to be created.</p>
     pub description: String,
@@ -2883,6 +3454,53 @@ pub struct CreateHsmConfigurationMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateHsmConfigurationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "hsm_configuration_identifier"),
+            self.hsm_configuration_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "hsm_ip_address"),
+            self.hsm_ip_address.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "hsm_partition_name"),
+            self.hsm_partition_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "hsm_partition_password"),
+            self.hsm_partition_password.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "hsm_server_public_certificate"),
+            self.hsm_server_public_certificate.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateHsmConfigurationMessage` contents to a `SignedRequest`.
 struct CreateHsmConfigurationMessageSerializer;
 impl CreateHsmConfigurationMessageSerializer {
@@ -2920,6 +3538,8 @@ impl CreateHsmConfigurationMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHsmConfigurationResult {
     pub hsm_configuration: Option<HsmConfiguration>,
 }
@@ -2951,6 +3571,8 @@ impl CreateHsmConfigurationResultDeserializer {
 }
 /// <p>The result of the <code>CreateSnapshotCopyGrant</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotCopyGrantMessage {
     /// <p>The unique identifier of the customer master key (CMK) to which to grant Amazon Redshift permission. If no key is specified, the default key is used.</p>
     pub kms_key_id: Option<String>,
@@ -2960,6 +3582,31 @@ pub struct CreateSnapshotCopyGrantMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateSnapshotCopyGrantMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "snapshot_copy_grant_name"),
+            self.snapshot_copy_grant_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateSnapshotCopyGrantMessage` contents to a `SignedRequest`.
 struct CreateSnapshotCopyGrantMessageSerializer;
 impl CreateSnapshotCopyGrantMessageSerializer {
@@ -2983,6 +3630,8 @@ impl CreateSnapshotCopyGrantMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotCopyGrantResult {
     pub snapshot_copy_grant: Option<SnapshotCopyGrant>,
 }
@@ -3013,6 +3662,8 @@ impl CreateSnapshotCopyGrantResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotScheduleMessage {
     /// <p><p/></p>
     pub dry_run: Option<bool>,
@@ -3028,6 +3679,39 @@ pub struct CreateSnapshotScheduleMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateSnapshotScheduleMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.schedule_definitions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "schedule_definitions"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.schedule_description {
+            errors.check_length(
+                &field_path(path, "schedule_description"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.schedule_identifier {
+            errors.check_length(
+                &field_path(path, "schedule_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateSnapshotScheduleMessage` contents to a `SignedRequest`.
 struct CreateSnapshotScheduleMessageSerializer;
 impl CreateSnapshotScheduleMessageSerializer {
@@ -3067,6 +3751,8 @@ impl CreateSnapshotScheduleMessageSerializer {
 
 /// <p>Contains the output from the <code>CreateTags</code> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagsMessage {
     /// <p>The Amazon Resource Name (ARN) to which you want to add the tag or tags. For example, <code>arn:aws:redshift:us-east-1:123456789:cluster:t1</code>. </p>
     pub resource_name: String,
@@ -3074,6 +3760,21 @@ pub struct CreateTagsMessage {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for CreateTagsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_name"),
+            self.resource_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `CreateTagsMessage` contents to a `SignedRequest`.
 struct CreateTagsMessageSerializer;
 impl CreateTagsMessageSerializer {
@@ -3089,6 +3790,8 @@ impl CreateTagsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomerStorageMessage {
     /// <p>The total amount of storage currently used for snapshots.</p>
     pub total_backup_size_in_mega_bytes: Option<f64>,
@@ -3126,6 +3829,8 @@ impl CustomerStorageMessageDeserializer {
 }
 /// <p>Describes the status of a cluster while it is in the process of resizing with an incremental resize.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DataTransferProgress {
     /// <p>Describes the data transfer rate in MB's per second.</p>
     pub current_rate_in_mega_bytes_per_second: Option<f64>,
@@ -3205,6 +3910,8 @@ impl DbGroupListSerializer {
 
 /// <p>Describes the default cluster parameters for a parameter group family.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefaultClusterParameters {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -3249,6 +3956,8 @@ impl DefaultClusterParametersDeserializer {
 }
 /// <p>Describes a deferred maintenance window</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeferredMaintenanceWindow {
     /// <p> A timestamp for the end of the time period when we defer maintenance.</p>
     pub defer_maintenance_end_time: Option<String>,
@@ -3317,6 +4026,8 @@ impl DeferredMaintenanceWindowsListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterMessage {
     /// <p><p>The identifier of the cluster to be deleted.</p> <p>Constraints:</p> <ul> <li> <p>Must contain lowercase characters.</p> </li> <li> <p>Must contain from 1 to 63 alphanumeric characters or hyphens.</p> </li> <li> <p>First character must be a letter.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub cluster_identifier: String,
@@ -3328,6 +4039,25 @@ pub struct DeleteClusterMessage {
     pub skip_final_cluster_snapshot: Option<bool>,
 }
 
+impl Validate for DeleteClusterMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.final_cluster_snapshot_identifier {
+            errors.check_length(
+                &field_path(path, "final_cluster_snapshot_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DeleteClusterMessage` contents to a `SignedRequest`.
 struct DeleteClusterMessageSerializer;
 impl DeleteClusterMessageSerializer {
@@ -3364,11 +4094,24 @@ impl DeleteClusterMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterParameterGroupMessage {
     /// <p><p>The name of the parameter group to be deleted.</p> <p>Constraints:</p> <ul> <li> <p>Must be the name of an existing cluster parameter group.</p> </li> <li> <p>Cannot delete a default cluster parameter group.</p> </li> </ul></p>
     pub parameter_group_name: String,
 }
 
+impl Validate for DeleteClusterParameterGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_group_name"),
+            self.parameter_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteClusterParameterGroupMessageSerializer;
 impl DeleteClusterParameterGroupMessageSerializer {
@@ -3386,6 +4129,8 @@ impl DeleteClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterResult {
     pub cluster: Option<Cluster>,
 }
@@ -3410,11 +4155,24 @@ impl DeleteClusterResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterSecurityGroupMessage {
     /// <p>The name of the cluster security group to be deleted.</p>
     pub cluster_security_group_name: String,
 }
 
+impl Validate for DeleteClusterSecurityGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_security_group_name"),
+            self.cluster_security_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteClusterSecurityGroupMessage` contents to a `SignedRequest`.
 struct DeleteClusterSecurityGroupMessageSerializer;
 impl DeleteClusterSecurityGroupMessageSerializer {
@@ -3433,6 +4191,8 @@ impl DeleteClusterSecurityGroupMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterSnapshotMessage {
     /// <p>The unique identifier of the cluster the snapshot was created from. This parameter is required if your IAM user has a policy containing a snapshot resource element that specifies anything other than * for the cluster name.</p> <p>Constraints: Must be the name of valid cluster.</p>
     pub snapshot_cluster_identifier: Option<String>,
@@ -3440,6 +4200,25 @@ pub struct DeleteClusterSnapshotMessage {
     pub snapshot_identifier: String,
 }
 
+impl Validate for DeleteClusterSnapshotMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.snapshot_cluster_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteClusterSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteClusterSnapshotMessageSerializer;
 impl DeleteClusterSnapshotMessageSerializer {
@@ -3474,6 +4253,8 @@ impl DeleteClusterSnapshotMessageListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -3502,11 +4283,24 @@ impl DeleteClusterSnapshotResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteClusterSubnetGroupMessage {
     /// <p>The name of the cluster subnet group name to be deleted.</p>
     pub cluster_subnet_group_name: String,
 }
 
+impl Validate for DeleteClusterSubnetGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_subnet_group_name"),
+            self.cluster_subnet_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteClusterSubnetGroupMessage` contents to a `SignedRequest`.
 struct DeleteClusterSubnetGroupMessageSerializer;
 impl DeleteClusterSubnetGroupMessageSerializer {
@@ -3525,11 +4319,24 @@ impl DeleteClusterSubnetGroupMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEventSubscriptionMessage {
     /// <p>The name of the Amazon Redshift event notification subscription to be deleted.</p>
     pub subscription_name: String,
 }
 
+impl Validate for DeleteEventSubscriptionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "subscription_name"),
+            self.subscription_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteEventSubscriptionMessage` contents to a `SignedRequest`.
 struct DeleteEventSubscriptionMessageSerializer;
 impl DeleteEventSubscriptionMessageSerializer {
@@ -3548,11 +4355,24 @@ impl DeleteEventSubscriptionMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHsmClientCertificateMessage {
     /// <p>The identifier of the HSM client certificate to be deleted.</p>
     pub hsm_client_certificate_identifier: String,
 }
 
+impl Validate for DeleteHsmClientCertificateMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hsm_client_certificate_identifier"),
+            self.hsm_client_certificate_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteHsmClientCertificateMessage` contents to a `SignedRequest`.
 struct DeleteHsmClientCertificateMessageSerializer;
 impl DeleteHsmClientCertificateMessageSerializer {
@@ -3571,11 +4391,24 @@ impl DeleteHsmClientCertificateMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHsmConfigurationMessage {
     /// <p>The identifier of the Amazon Redshift HSM configuration to be deleted.</p>
     pub hsm_configuration_identifier: String,
 }
 
+impl Validate for DeleteHsmConfigurationMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hsm_configuration_identifier"),
+            self.hsm_configuration_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteHsmConfigurationMessage` contents to a `SignedRequest`.
 struct DeleteHsmConfigurationMessageSerializer;
 impl DeleteHsmConfigurationMessageSerializer {
@@ -3594,11 +4427,24 @@ impl DeleteHsmConfigurationMessageSerializer {
 
 /// <p>The result of the <code>DeleteSnapshotCopyGrant</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSnapshotCopyGrantMessage {
     /// <p>The name of the snapshot copy grant to delete.</p>
     pub snapshot_copy_grant_name: String,
 }
 
+impl Validate for DeleteSnapshotCopyGrantMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "snapshot_copy_grant_name"),
+            self.snapshot_copy_grant_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteSnapshotCopyGrantMessage` contents to a `SignedRequest`.
 struct DeleteSnapshotCopyGrantMessageSerializer;
 impl DeleteSnapshotCopyGrantMessageSerializer {
@@ -3616,11 +4462,24 @@ impl DeleteSnapshotCopyGrantMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSnapshotScheduleMessage {
     /// <p>A unique identifier of the snapshot schedule to delete.</p>
     pub schedule_identifier: String,
 }
 
+impl Validate for DeleteSnapshotScheduleMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "schedule_identifier"),
+            self.schedule_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DeleteSnapshotScheduleMessage` contents to a `SignedRequest`.
 struct DeleteSnapshotScheduleMessageSerializer;
 impl DeleteSnapshotScheduleMessageSerializer {
@@ -3639,6 +4498,8 @@ impl DeleteSnapshotScheduleMessageSerializer {
 
 /// <p>Contains the output from the <code>DeleteTags</code> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsMessage {
     /// <p>The Amazon Resource Name (ARN) from which you want to remove the tag or tags. For example, <code>arn:aws:redshift:us-east-1:123456789:cluster:t1</code>. </p>
     pub resource_name: String,
@@ -3646,6 +4507,21 @@ pub struct DeleteTagsMessage {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for DeleteTagsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_name"),
+            self.resource_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+        }
+    }
+}
+
 /// Serialize `DeleteTagsMessage` contents to a `SignedRequest`.
 struct DeleteTagsMessageSerializer;
 impl DeleteTagsMessageSerializer {
@@ -3661,11 +4537,24 @@ impl DeleteTagsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountAttributesMessage {
     /// <p>A list of attribute names.</p>
     pub attribute_names: Option<Vec<String>>,
 }
 
+impl Validate for DescribeAccountAttributesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attribute_names"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeAccountAttributesMessage` contents to a `SignedRequest`.
 struct DescribeAccountAttributesMessageSerializer;
 impl DescribeAccountAttributesMessageSerializer {
@@ -3686,6 +4575,8 @@ impl DescribeAccountAttributesMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterDbRevisionsMessage {
     /// <p>A unique identifier for a cluster whose <code>ClusterDbRevisions</code> you are requesting. This parameter is case sensitive. All clusters defined for an account are returned by default.</p>
     pub cluster_identifier: Option<String>,
@@ -3695,6 +4586,27 @@ pub struct DescribeClusterDbRevisionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl Validate for DescribeClusterDbRevisionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_identifier {
+            errors.check_length(
+                &field_path(path, "cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeClusterDbRevisionsMessage` contents to a `SignedRequest`.
 struct DescribeClusterDbRevisionsMessageSerializer;
 impl DescribeClusterDbRevisionsMessageSerializer {
@@ -3718,6 +4630,8 @@ impl DescribeClusterDbRevisionsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterParameterGroupsMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeClusterParameterGroups</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -3731,6 +4645,39 @@ pub struct DescribeClusterParameterGroupsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeClusterParameterGroupsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.parameter_group_name {
+            errors.check_length(
+                &field_path(path, "parameter_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeClusterParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeClusterParameterGroupsMessageSerializer;
 impl DescribeClusterParameterGroupsMessageSerializer {
@@ -3768,6 +4715,8 @@ impl DescribeClusterParameterGroupsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterParametersMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeClusterParameters</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -3779,6 +4728,33 @@ pub struct DescribeClusterParametersMessage {
     pub source: Option<String>,
 }
 
+impl Validate for DescribeClusterParametersMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "parameter_group_name"),
+            self.parameter_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.source {
+            errors.check_length(
+                &field_path(path, "source"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeClusterParametersMessageSerializer;
 impl DescribeClusterParametersMessageSerializer {
@@ -3806,6 +4782,8 @@ impl DescribeClusterParametersMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterSecurityGroupsMessage {
     /// <p>The name of a cluster security group for which you are requesting details. You can specify either the <b>Marker</b> parameter or a <b>ClusterSecurityGroupName</b> parameter, but not both. </p> <p> Example: <code>securitygroup1</code> </p>
     pub cluster_security_group_name: Option<String>,
@@ -3819,15 +4797,48 @@ pub struct DescribeClusterSecurityGroupsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
-/// Serialize `DescribeClusterSecurityGroupsMessage` contents to a `SignedRequest`.
-struct DescribeClusterSecurityGroupsMessageSerializer;
-impl DescribeClusterSecurityGroupsMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeClusterSecurityGroupsMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl Validate for DescribeClusterSecurityGroupsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_security_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_security_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
         }
-
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
+/// Serialize `DescribeClusterSecurityGroupsMessage` contents to a `SignedRequest`.
+struct DescribeClusterSecurityGroupsMessageSerializer;
+impl DescribeClusterSecurityGroupsMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeClusterSecurityGroupsMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
         if let Some(ref field_value) = obj.cluster_security_group_name {
             params.put(
                 &format!("{}{}", prefix, "ClusterSecurityGroupName"),
@@ -3859,6 +4870,8 @@ impl DescribeClusterSecurityGroupsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterSnapshotsMessage {
     /// <p><p>A value that indicates whether to return snapshots only for an existing cluster. You can perform table-level restore only by using a snapshot of an existing cluster, that is, a cluster that has not been deleted. Values for this parameter work as follows: </p> <ul> <li> <p>If <code>ClusterExists</code> is set to <code>true</code>, <code>ClusterIdentifier</code> is required.</p> </li> <li> <p>If <code>ClusterExists</code> is set to <code>false</code> and <code>ClusterIdentifier</code> isn&#39;t specified, all snapshots associated with deleted clusters (orphaned snapshots) are returned. </p> </li> <li> <p>If <code>ClusterExists</code> is set to <code>false</code> and <code>ClusterIdentifier</code> is specified for a deleted cluster, snapshots associated with that cluster are returned.</p> </li> <li> <p>If <code>ClusterExists</code> is set to <code>false</code> and <code>ClusterIdentifier</code> is specified for an existing cluster, no snapshots are returned. </p> </li> </ul></p>
     pub cluster_exists: Option<bool>,
@@ -3886,6 +4899,63 @@ pub struct DescribeClusterSnapshotsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeClusterSnapshotsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_identifier {
+            errors.check_length(
+                &field_path(path, "cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.owner_account {
+            errors.check_length(
+                &field_path(path, "owner_account"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.snapshot_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.snapshot_type {
+            errors.check_length(
+                &field_path(path, "snapshot_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeClusterSnapshotsMessageSerializer;
 impl DescribeClusterSnapshotsMessageSerializer {
@@ -3948,6 +5018,8 @@ impl DescribeClusterSnapshotsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterSubnetGroupsMessage {
     /// <p>The name of the cluster subnet group for which information is requested.</p>
     pub cluster_subnet_group_name: Option<String>,
@@ -3961,6 +5033,39 @@ pub struct DescribeClusterSubnetGroupsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeClusterSubnetGroupsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_subnet_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_subnet_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeClusterSubnetGroupsMessage` contents to a `SignedRequest`.
 struct DescribeClusterSubnetGroupsMessageSerializer;
 impl DescribeClusterSubnetGroupsMessageSerializer {
@@ -4000,6 +5105,8 @@ impl DescribeClusterSubnetGroupsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterTracksMessage {
     /// <p>The name of the maintenance track. </p>
     pub maintenance_track_name: Option<String>,
@@ -4009,6 +5116,27 @@ pub struct DescribeClusterTracksMessage {
     pub max_records: Option<i64>,
 }
 
+impl Validate for DescribeClusterTracksMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.maintenance_track_name {
+            errors.check_length(
+                &field_path(path, "maintenance_track_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeClusterTracksMessage` contents to a `SignedRequest`.
 struct DescribeClusterTracksMessageSerializer;
 impl DescribeClusterTracksMessageSerializer {
@@ -4035,6 +5163,8 @@ impl DescribeClusterTracksMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterVersionsMessage {
     /// <p><p>The name of a specific cluster parameter group family to return details for.</p> <p>Constraints:</p> <ul> <li> <p>Must be 1 to 255 alphanumeric characters</p> </li> <li> <p>First character must be a letter</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens</p> </li> </ul></p>
     pub cluster_parameter_group_family: Option<String>,
@@ -4046,6 +5176,35 @@ pub struct DescribeClusterVersionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl Validate for DescribeClusterVersionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_parameter_group_family {
+            errors.check_length(
+                &field_path(path, "cluster_parameter_group_family"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_version {
+            errors.check_length(
+                &field_path(path, "cluster_version"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeClusterVersionsMessage` contents to a `SignedRequest`.
 struct DescribeClusterVersionsMessageSerializer;
 impl DescribeClusterVersionsMessageSerializer {
@@ -4075,6 +5234,8 @@ impl DescribeClusterVersionsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClustersMessage {
     /// <p>The unique identifier of a cluster whose properties you are requesting. This parameter is case sensitive.</p> <p>The default is that all clusters defined for an account are returned.</p>
     pub cluster_identifier: Option<String>,
@@ -4088,6 +5249,39 @@ pub struct DescribeClustersMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeClustersMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_identifier {
+            errors.check_length(
+                &field_path(path, "cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeClustersMessage` contents to a `SignedRequest`.
 struct DescribeClustersMessageSerializer;
 impl DescribeClustersMessageSerializer {
@@ -4125,6 +5319,8 @@ impl DescribeClustersMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDefaultClusterParametersMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeDefaultClusterParameters</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -4134,6 +5330,25 @@ pub struct DescribeDefaultClusterParametersMessage {
     pub parameter_group_family: String,
 }
 
+impl Validate for DescribeDefaultClusterParametersMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "parameter_group_family"),
+            self.parameter_group_family.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DescribeDefaultClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeDefaultClusterParametersMessageSerializer;
 impl DescribeDefaultClusterParametersMessageSerializer {
@@ -4157,6 +5372,8 @@ impl DescribeDefaultClusterParametersMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDefaultClusterParametersResult {
     pub default_cluster_parameters: Option<DefaultClusterParameters>,
 }
@@ -4189,11 +5406,26 @@ impl DescribeDefaultClusterParametersResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventCategoriesMessage {
     /// <p>The source type, such as cluster or parameter group, to which the described event categories apply.</p> <p>Valid values: cluster, cluster-snapshot, cluster-parameter-group, and cluster-security-group.</p>
     pub source_type: Option<String>,
 }
 
+impl Validate for DescribeEventCategoriesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.source_type {
+            errors.check_length(
+                &field_path(path, "source_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeEventCategoriesMessage` contents to a `SignedRequest`.
 struct DescribeEventCategoriesMessageSerializer;
 impl DescribeEventCategoriesMessageSerializer {
@@ -4211,6 +5443,8 @@ impl DescribeEventCategoriesMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventSubscriptionsMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a DescribeEventSubscriptions request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -4224,6 +5458,39 @@ pub struct DescribeEventSubscriptionsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeEventSubscriptionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.subscription_name {
+            errors.check_length(
+                &field_path(path, "subscription_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeEventSubscriptionsMessage` contents to a `SignedRequest`.
 struct DescribeEventSubscriptionsMessageSerializer;
 impl DescribeEventSubscriptionsMessageSerializer {
@@ -4261,6 +5528,8 @@ impl DescribeEventSubscriptionsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEventsMessage {
     /// <p>The number of minutes prior to the time of the request for which to retrieve events. For example, if the request is sent at 18:00 and you specify a duration of 60, then only events which have occurred after 17:00 will be returned.</p> <p>Default: <code>60</code> </p>
     pub duration: Option<i64>,
@@ -4278,6 +5547,27 @@ pub struct DescribeEventsMessage {
     pub start_time: Option<String>,
 }
 
+impl Validate for DescribeEventsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.source_identifier {
+            errors.check_length(
+                &field_path(path, "source_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
 struct DescribeEventsMessageSerializer;
 impl DescribeEventsMessageSerializer {
@@ -4313,6 +5603,8 @@ impl DescribeEventsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHsmClientCertificatesMessage {
     /// <p>The identifier of a specific HSM client certificate for which you want information. If no identifier is specified, information is returned for all HSM client certificates owned by your AWS customer account.</p>
     pub hsm_client_certificate_identifier: Option<String>,
@@ -4326,6 +5618,39 @@ pub struct DescribeHsmClientCertificatesMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeHsmClientCertificatesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hsm_client_certificate_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_client_certificate_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeHsmClientCertificatesMessage` contents to a `SignedRequest`.
 struct DescribeHsmClientCertificatesMessageSerializer;
 impl DescribeHsmClientCertificatesMessageSerializer {
@@ -4366,6 +5691,8 @@ impl DescribeHsmClientCertificatesMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHsmConfigurationsMessage {
     /// <p>The identifier of a specific Amazon Redshift HSM configuration to be described. If no identifier is specified, information is returned for all HSM configurations owned by your AWS customer account.</p>
     pub hsm_configuration_identifier: Option<String>,
@@ -4379,6 +5706,39 @@ pub struct DescribeHsmConfigurationsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeHsmConfigurationsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hsm_configuration_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_configuration_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeHsmConfigurationsMessage` contents to a `SignedRequest`.
 struct DescribeHsmConfigurationsMessageSerializer;
 impl DescribeHsmConfigurationsMessageSerializer {
@@ -4419,11 +5779,24 @@ impl DescribeHsmConfigurationsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLoggingStatusMessage {
     /// <p>The identifier of the cluster from which to get the logging status.</p> <p>Example: <code>examplecluster</code> </p>
     pub cluster_identifier: String,
 }
 
+impl Validate for DescribeLoggingStatusMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DescribeLoggingStatusMessage` contents to a `SignedRequest`.
 struct DescribeLoggingStatusMessageSerializer;
 impl DescribeLoggingStatusMessageSerializer {
@@ -4442,6 +5815,8 @@ impl DescribeLoggingStatusMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrderableClusterOptionsMessage {
     /// <p>The version filter value. Specify this parameter to show only the available offerings matching the specified version.</p> <p>Default: All versions.</p> <p>Constraints: Must be one of the version returned from <a>DescribeClusterVersions</a>.</p>
     pub cluster_version: Option<String>,
@@ -4453,6 +5828,35 @@ pub struct DescribeOrderableClusterOptionsMessage {
     pub node_type: Option<String>,
 }
 
+impl Validate for DescribeOrderableClusterOptionsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_version {
+            errors.check_length(
+                &field_path(path, "cluster_version"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.node_type {
+            errors.check_length(
+                &field_path(path, "node_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeOrderableClusterOptionsMessage` contents to a `SignedRequest`.
 struct DescribeOrderableClusterOptionsMessageSerializer;
 impl DescribeOrderableClusterOptionsMessageSerializer {
@@ -4479,6 +5883,8 @@ impl DescribeOrderableClusterOptionsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReservedNodeOfferingsMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeReservedNodeOfferings</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -4488,6 +5894,27 @@ pub struct DescribeReservedNodeOfferingsMessage {
     pub reserved_node_offering_id: Option<String>,
 }
 
+impl Validate for DescribeReservedNodeOfferingsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.reserved_node_offering_id {
+            errors.check_length(
+                &field_path(path, "reserved_node_offering_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeReservedNodeOfferingsMessage` contents to a `SignedRequest`.
 struct DescribeReservedNodeOfferingsMessageSerializer;
 impl DescribeReservedNodeOfferingsMessageSerializer {
@@ -4514,6 +5941,8 @@ impl DescribeReservedNodeOfferingsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReservedNodesMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeReservedNodes</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -4523,6 +5952,27 @@ pub struct DescribeReservedNodesMessage {
     pub reserved_node_id: Option<String>,
 }
 
+impl Validate for DescribeReservedNodesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.reserved_node_id {
+            errors.check_length(
+                &field_path(path, "reserved_node_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeReservedNodesMessage` contents to a `SignedRequest`.
 struct DescribeReservedNodesMessageSerializer;
 impl DescribeReservedNodesMessageSerializer {
@@ -4546,11 +5996,24 @@ impl DescribeReservedNodesMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeResizeMessage {
     /// <p>The unique identifier of a cluster whose resize progress you are requesting. This parameter is case-sensitive.</p> <p>By default, resize operations for all clusters defined for an AWS account are returned.</p>
     pub cluster_identifier: String,
 }
 
+impl Validate for DescribeResizeMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DescribeResizeMessage` contents to a `SignedRequest`.
 struct DescribeResizeMessageSerializer;
 impl DescribeResizeMessageSerializer {
@@ -4569,6 +6032,8 @@ impl DescribeResizeMessageSerializer {
 
 /// <p>The result of the <code>DescribeSnapshotCopyGrants</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotCopyGrantsMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <code>DescribeSnapshotCopyGrant</code> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p> <p>Constraints: You can specify either the <b>SnapshotCopyGrantName</b> parameter or the <b>Marker</b> parameter, but not both. </p>
     pub marker: Option<String>,
@@ -4582,6 +6047,39 @@ pub struct DescribeSnapshotCopyGrantsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeSnapshotCopyGrantsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.snapshot_copy_grant_name {
+            errors.check_length(
+                &field_path(path, "snapshot_copy_grant_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeSnapshotCopyGrantsMessage` contents to a `SignedRequest`.
 struct DescribeSnapshotCopyGrantsMessageSerializer;
 impl DescribeSnapshotCopyGrantsMessageSerializer {
@@ -4621,6 +6119,8 @@ impl DescribeSnapshotCopyGrantsMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotSchedulesMessage {
     /// <p>The unique identifier for the cluster whose snapshot schedules you want to view.</p>
     pub cluster_identifier: Option<String>,
@@ -4636,6 +6136,47 @@ pub struct DescribeSnapshotSchedulesMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeSnapshotSchedulesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_identifier {
+            errors.check_length(
+                &field_path(path, "cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.schedule_identifier {
+            errors.check_length(
+                &field_path(path, "schedule_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeSnapshotSchedulesMessage` contents to a `SignedRequest`.
 struct DescribeSnapshotSchedulesMessageSerializer;
 impl DescribeSnapshotSchedulesMessageSerializer {
@@ -4675,6 +6216,8 @@ impl DescribeSnapshotSchedulesMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotSchedulesOutputMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>marker</code> parameter and retrying the command. If the <code>marker</code> field is empty, all response records have been retrieved for the request.</p>
     pub marker: Option<String>,
@@ -4714,6 +6257,8 @@ impl DescribeSnapshotSchedulesOutputMessageDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTableRestoreStatusMessage {
     /// <p>The Amazon Redshift cluster that the table is being restored to.</p>
     pub cluster_identifier: Option<String>,
@@ -4725,6 +6270,35 @@ pub struct DescribeTableRestoreStatusMessage {
     pub table_restore_request_id: Option<String>,
 }
 
+impl Validate for DescribeTableRestoreStatusMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cluster_identifier {
+            errors.check_length(
+                &field_path(path, "cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.table_restore_request_id {
+            errors.check_length(
+                &field_path(path, "table_restore_request_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `DescribeTableRestoreStatusMessage` contents to a `SignedRequest`.
 struct DescribeTableRestoreStatusMessageSerializer;
 impl DescribeTableRestoreStatusMessageSerializer {
@@ -4754,6 +6328,8 @@ impl DescribeTableRestoreStatusMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>marker</code> parameter and retrying the command. If the <code>marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -4769,6 +6345,47 @@ pub struct DescribeTagsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl Validate for DescribeTagsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.resource_name {
+            errors.check_length(
+                &field_path(path, "resource_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.resource_type {
+            errors.check_length(
+                &field_path(path, "resource_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.tag_keys {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.tag_values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `DescribeTagsMessage` contents to a `SignedRequest`.
 struct DescribeTagsMessageSerializer;
 impl DescribeTagsMessageSerializer {
@@ -4809,11 +6426,24 @@ impl DescribeTagsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableLoggingMessage {
     /// <p>The identifier of the cluster on which logging is to be stopped.</p> <p>Example: <code>examplecluster</code> </p>
     pub cluster_identifier: String,
 }
 
+impl Validate for DisableLoggingMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DisableLoggingMessage` contents to a `SignedRequest`.
 struct DisableLoggingMessageSerializer;
 impl DisableLoggingMessageSerializer {
@@ -4832,11 +6462,24 @@ impl DisableLoggingMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableSnapshotCopyMessage {
     /// <p>The unique identifier of the source cluster that you want to disable copying of snapshots to a destination region.</p> <p>Constraints: Must be the valid name of an existing cluster that has cross-region snapshot copy enabled.</p>
     pub cluster_identifier: String,
 }
 
+impl Validate for DisableSnapshotCopyMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `DisableSnapshotCopyMessage` contents to a `SignedRequest`.
 struct DisableSnapshotCopyMessageSerializer;
 impl DisableSnapshotCopyMessageSerializer {
@@ -4854,6 +6497,8 @@ impl DisableSnapshotCopyMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableSnapshotCopyResult {
     pub cluster: Option<Cluster>,
 }
@@ -4885,7 +6530,7 @@ impl DoubleDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4896,7 +6541,7 @@ impl DoubleOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4904,6 +6549,8 @@ impl DoubleOptionalDeserializer {
 }
 /// <p>Describes an Amazon EC2 security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EC2SecurityGroup {
     /// <p>The name of the EC2 Security Group.</p>
     pub ec2_security_group_name: Option<String>,
@@ -4972,6 +6619,8 @@ impl EC2SecurityGroupListDeserializer {
 }
 /// <p>Describes the status of the elastic IP (EIP) address.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ElasticIpStatus {
     /// <p>The elastic IP (EIP) address for the cluster.</p>
     pub elastic_ip: Option<String>,
@@ -5022,6 +6671,8 @@ impl EligibleTracksToUpdateListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableLoggingMessage {
     /// <p><p>The name of an existing S3 bucket where the log files are to be stored.</p> <p>Constraints:</p> <ul> <li> <p>Must be in the same region as the cluster</p> </li> <li> <p>The cluster must have read bucket and put object permissions</p> </li> </ul></p>
     pub bucket_name: String,
@@ -5031,6 +6682,31 @@ pub struct EnableLoggingMessage {
     pub s3_key_prefix: Option<String>,
 }
 
+impl Validate for EnableLoggingMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bucket_name"),
+            self.bucket_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `EnableLoggingMessage` contents to a `SignedRequest`.
 struct EnableLoggingMessageSerializer;
 impl EnableLoggingMessageSerializer {
@@ -5053,6 +6729,8 @@ impl EnableLoggingMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableSnapshotCopyMessage {
     /// <p>The unique identifier of the source cluster to copy snapshots from.</p> <p>Constraints: Must be the valid name of an existing cluster that does not already have cross-region snapshot copy enabled.</p>
     pub cluster_identifier: String,
@@ -5066,6 +6744,31 @@ pub struct EnableSnapshotCopyMessage {
     pub snapshot_copy_grant_name: Option<String>,
 }
 
+impl Validate for EnableSnapshotCopyMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "destination_region"),
+            self.destination_region.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.snapshot_copy_grant_name {
+            errors.check_length(
+                &field_path(path, "snapshot_copy_grant_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `EnableSnapshotCopyMessage` contents to a `SignedRequest`.
 struct EnableSnapshotCopyMessageSerializer;
 impl EnableSnapshotCopyMessageSerializer {
@@ -5102,6 +6805,8 @@ impl EnableSnapshotCopyMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableSnapshotCopyResult {
     pub cluster: Option<Cluster>,
 }
@@ -5130,6 +6835,8 @@ impl EnableSnapshotCopyResultDeserializer {
 }
 /// <p>Describes a connection endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Endpoint {
     /// <p>The DNS address of the Cluster.</p>
     pub address: Option<String>,
@@ -5160,6 +6867,8 @@ impl EndpointDeserializer {
 }
 /// <p>Describes an event.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Event {
     /// <p>The date and time of the event.</p>
     pub date: Option<String>,
@@ -5245,6 +6954,8 @@ impl EventCategoriesListSerializer {
 
 /// <p>Describes event categories.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventCategoriesMap {
     /// <p>The events in the event category.</p>
     pub events: Option<Vec<EventInfoMap>>,
@@ -5297,6 +7008,8 @@ impl EventCategoriesMapListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventCategoriesMessage {
     /// <p>A list of event categories descriptions.</p>
     pub event_categories_map_list: Option<Vec<EventCategoriesMap>>,
@@ -5327,6 +7040,8 @@ impl EventCategoriesMessageDeserializer {
 }
 /// <p>Describes event information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventInfoMap {
     /// <p>The category of an Amazon Redshift event.</p>
     pub event_categories: Option<Vec<String>>,
@@ -5407,6 +7122,8 @@ impl EventListDeserializer {
 }
 /// <p>Describes event subscriptions.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventSubscription {
     /// <p>The name of the Amazon Redshift event notification subscription.</p>
     pub cust_subscription_id: Option<String>,
@@ -5516,6 +7233,8 @@ impl EventSubscriptionsListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventSubscriptionsMessage {
     /// <p>A list of event subscriptions.</p>
     pub event_subscriptions_list: Option<Vec<EventSubscription>>,
@@ -5555,6 +7274,8 @@ impl EventSubscriptionsMessageDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventsMessage {
     /// <p>A list of <code>Event</code> instances. </p>
     pub events: Option<Vec<Event>>,
@@ -5587,6 +7308,8 @@ impl EventsMessageDeserializer {
 }
 /// <p>The request parameters to get cluster credentials.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetClusterCredentialsMessage {
     /// <p>Create a database user with the name specified for the user named in <code>DbUser</code> if one does not exist.</p>
     pub auto_create: Option<bool>,
@@ -5602,6 +7325,37 @@ pub struct GetClusterCredentialsMessage {
     pub duration_seconds: Option<i64>,
 }
 
+impl Validate for GetClusterCredentialsMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.db_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "db_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.db_name {
+            errors.check_length(
+                &field_path(path, "db_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "db_user"),
+            self.db_user.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `GetClusterCredentialsMessage` contents to a `SignedRequest`.
 struct GetClusterCredentialsMessageSerializer;
 impl GetClusterCredentialsMessageSerializer {
@@ -5637,6 +7391,8 @@ impl GetClusterCredentialsMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReservedNodeExchangeOfferingsInputMessage {
     /// <p>A value that indicates the starting point for the next set of ReservedNodeOfferings.</p>
     pub marker: Option<String>,
@@ -5646,6 +7402,25 @@ pub struct GetReservedNodeExchangeOfferingsInputMessage {
     pub reserved_node_id: String,
 }
 
+impl Validate for GetReservedNodeExchangeOfferingsInputMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "reserved_node_id"),
+            self.reserved_node_id.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `GetReservedNodeExchangeOfferingsInputMessage` contents to a `SignedRequest`.
 struct GetReservedNodeExchangeOfferingsInputMessageSerializer;
 impl GetReservedNodeExchangeOfferingsInputMessageSerializer {
@@ -5673,6 +7448,8 @@ impl GetReservedNodeExchangeOfferingsInputMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReservedNodeExchangeOfferingsOutputMessage {
     /// <p>An optional parameter that specifies the starting point for returning a set of response records. When the results of a <code>GetReservedNodeExchangeOfferings</code> request exceed the value specified in MaxRecords, Amazon Redshift returns a value in the marker field of the response. You can retrieve the next set of response records by providing the returned marker value in the marker parameter and retrying the request. </p>
     pub marker: Option<String>,
@@ -5712,6 +7489,8 @@ impl GetReservedNodeExchangeOfferingsOutputMessageDeserializer {
 }
 /// <p>Returns information about an HSM client certificate. The certificate is stored in a secure Hardware Storage Module (HSM), and used by the Amazon Redshift cluster to encrypt data files.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HsmClientCertificate {
     /// <p>The identifier of the HSM client certificate.</p>
     pub hsm_client_certificate_identifier: Option<String>,
@@ -5775,6 +7554,8 @@ impl HsmClientCertificateListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HsmClientCertificateMessage {
     /// <p>A list of the identifiers for one or more HSM client certificates used by Amazon Redshift clusters to store and retrieve database encryption keys in an HSM.</p>
     pub hsm_client_certificates: Option<Vec<HsmClientCertificate>>,
@@ -5814,6 +7595,8 @@ impl HsmClientCertificateMessageDeserializer {
 }
 /// <p>Returns information about an HSM configuration, which is an object that describes to Amazon Redshift clusters the information they require to connect to an HSM where they can store database encryption keys.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HsmConfiguration {
     /// <p>A text description of the HSM configuration.</p>
     pub description: Option<String>,
@@ -5886,6 +7669,8 @@ impl HsmConfigurationListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HsmConfigurationMessage {
     /// <p>A list of <code>HsmConfiguration</code> objects.</p>
     pub hsm_configurations: Option<Vec<HsmConfiguration>>,
@@ -5925,6 +7710,8 @@ impl HsmConfigurationMessageDeserializer {
 }
 /// <p>Describes the status of changes to HSM settings.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HsmStatus {
     /// <p>Specifies the name of the HSM client certificate the Amazon Redshift cluster uses to retrieve the data encryption keys stored in an HSM.</p>
     pub hsm_client_certificate_identifier: Option<String>,
@@ -5966,6 +7753,8 @@ impl HsmStatusDeserializer {
 }
 /// <p>Describes an IP range used in a security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IPRange {
     /// <p>The IP range in Classless Inter-Domain Routing (CIDR) notation.</p>
     pub cidrip: Option<String>,
@@ -6086,7 +7875,7 @@ impl IntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6097,7 +7886,7 @@ impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6105,6 +7894,8 @@ impl IntegerOptionalDeserializer {
 }
 /// <p>Describes the status of logging for a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoggingStatus {
     /// <p>The name of the S3 bucket where the log files are stored.</p>
     pub bucket_name: Option<String>,
@@ -6167,7 +7958,7 @@ impl LongDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6178,7 +7969,7 @@ impl LongOptionalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6186,6 +7977,8 @@ impl LongOptionalDeserializer {
 }
 /// <p>Defines a maintenance track that determines which Amazon Redshift version to apply during a maintenance window. If the value for <code>MaintenanceTrack</code> is <code>current</code>, the cluster is updated to the most recently certified maintenance release. If the value is <code>trailing</code>, the cluster is updated to the previously certified maintenance release. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MaintenanceTrack {
     /// <p>The version number for the cluster release.</p>
     pub database_version: Option<String>,
@@ -6229,6 +8022,8 @@ impl MaintenanceTrackDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterDbRevisionMessage {
     /// <p>The unique identifier of a cluster whose database revision you want to modify. </p> <p>Example: <code>examplecluster</code> </p>
     pub cluster_identifier: String,
@@ -6236,6 +8031,23 @@ pub struct ModifyClusterDbRevisionMessage {
     pub revision_target: String,
 }
 
+impl Validate for ModifyClusterDbRevisionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "revision_target"),
+            self.revision_target.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `ModifyClusterDbRevisionMessage` contents to a `SignedRequest`.
 struct ModifyClusterDbRevisionMessageSerializer;
 impl ModifyClusterDbRevisionMessageSerializer {
@@ -6257,6 +8069,8 @@ impl ModifyClusterDbRevisionMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterDbRevisionResult {
     pub cluster: Option<Cluster>,
 }
@@ -6285,6 +8099,8 @@ impl ModifyClusterDbRevisionResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterIamRolesMessage {
     /// <p>Zero or more IAM roles to associate with the cluster. The roles must be in their Amazon Resource Name (ARN) format. You can associate up to 10 IAM roles with a single cluster in a single request.</p>
     pub add_iam_roles: Option<Vec<String>>,
@@ -6294,6 +8110,29 @@ pub struct ModifyClusterIamRolesMessage {
     pub remove_iam_roles: Option<Vec<String>>,
 }
 
+impl Validate for ModifyClusterIamRolesMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.add_iam_roles {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "add_iam_roles"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.remove_iam_roles {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "remove_iam_roles"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `ModifyClusterIamRolesMessage` contents to a `SignedRequest`.
 struct ModifyClusterIamRolesMessageSerializer;
 impl ModifyClusterIamRolesMessageSerializer {
@@ -6325,6 +8164,8 @@ impl ModifyClusterIamRolesMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterIamRolesResult {
     pub cluster: Option<Cluster>,
 }
@@ -6352,6 +8193,8 @@ impl ModifyClusterIamRolesResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterMaintenanceMessage {
     /// <p>A unique identifier for the cluster.</p>
     pub cluster_identifier: String,
@@ -6367,6 +8210,25 @@ pub struct ModifyClusterMaintenanceMessage {
     pub defer_maintenance_start_time: Option<String>,
 }
 
+impl Validate for ModifyClusterMaintenanceMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.defer_maintenance_identifier {
+            errors.check_length(
+                &field_path(path, "defer_maintenance_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `ModifyClusterMaintenanceMessage` contents to a `SignedRequest`.
 struct ModifyClusterMaintenanceMessageSerializer;
 impl ModifyClusterMaintenanceMessageSerializer {
@@ -6411,6 +8273,8 @@ impl ModifyClusterMaintenanceMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterMaintenanceResult {
     pub cluster: Option<Cluster>,
 }
@@ -6439,6 +8303,8 @@ impl ModifyClusterMaintenanceResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterMessage {
     /// <p>If <code>true</code>, major version upgrades will be applied automatically to the cluster during the maintenance window. </p> <p>Default: <code>false</code> </p>
     pub allow_version_upgrade: Option<bool>,
@@ -6486,7 +8352,118 @@ pub struct ModifyClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `ModifyClusterMessage` contents to a `SignedRequest`.
+impl Validate for ModifyClusterMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.cluster_parameter_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_parameter_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "cluster_security_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.cluster_type {
+            errors.check_length(
+                &field_path(path, "cluster_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_version {
+            errors.check_length(
+                &field_path(path, "cluster_version"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.elastic_ip {
+            errors.check_length(
+                &field_path(path, "elastic_ip"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hsm_client_certificate_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_client_certificate_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hsm_configuration_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_configuration_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.maintenance_track_name {
+            errors.check_length(
+                &field_path(path, "maintenance_track_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.new_cluster_identifier {
+            errors.check_length(
+                &field_path(path, "new_cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.node_type {
+            errors.check_length(
+                &field_path(path, "node_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.preferred_maintenance_window {
+            errors.check_length(
+                &field_path(path, "preferred_maintenance_window"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.vpc_security_group_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "vpc_security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
+/// Serialize `ModifyClusterMessage` contents to a `SignedRequest`.
 struct ModifyClusterMessageSerializer;
 impl ModifyClusterMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &ModifyClusterMessage) {
@@ -6602,6 +8579,8 @@ impl ModifyClusterMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterParameterGroupMessage {
     /// <p>The name of the parameter group to be modified.</p>
     pub parameter_group_name: String,
@@ -6609,6 +8588,21 @@ pub struct ModifyClusterParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl Validate for ModifyClusterParameterGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_group_name"),
+            self.parameter_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        for (i, item) in self.parameters.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "parameters"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `ModifyClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyClusterParameterGroupMessageSerializer;
 impl ModifyClusterParameterGroupMessageSerializer {
@@ -6631,6 +8625,8 @@ impl ModifyClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterResult {
     pub cluster: Option<Cluster>,
 }
@@ -6654,6 +8650,8 @@ impl ModifyClusterResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterSnapshotMessage {
     /// <p>A Boolean option to override an exception if the retention period has already passed.</p>
     pub force: Option<bool>,
@@ -6663,6 +8661,17 @@ pub struct ModifyClusterSnapshotMessage {
     pub snapshot_identifier: String,
 }
 
+impl Validate for ModifyClusterSnapshotMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `ModifyClusterSnapshotMessage` contents to a `SignedRequest`.
 struct ModifyClusterSnapshotMessageSerializer;
 impl ModifyClusterSnapshotMessageSerializer {
@@ -6689,6 +8698,8 @@ impl ModifyClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -6716,6 +8727,8 @@ impl ModifyClusterSnapshotResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterSnapshotScheduleMessage {
     /// <p>A unique identifier for the cluster whose snapshot schedule you want to modify. </p>
     pub cluster_identifier: String,
@@ -6725,6 +8738,25 @@ pub struct ModifyClusterSnapshotScheduleMessage {
     pub schedule_identifier: Option<String>,
 }
 
+impl Validate for ModifyClusterSnapshotScheduleMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.schedule_identifier {
+            errors.check_length(
+                &field_path(path, "schedule_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `ModifyClusterSnapshotScheduleMessage` contents to a `SignedRequest`.
 struct ModifyClusterSnapshotScheduleMessageSerializer;
 impl ModifyClusterSnapshotScheduleMessageSerializer {
@@ -6752,6 +8784,8 @@ impl ModifyClusterSnapshotScheduleMessageSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterSubnetGroupMessage {
     /// <p>The name of the subnet group to be modified.</p>
     pub cluster_subnet_group_name: String,
@@ -6761,6 +8795,29 @@ pub struct ModifyClusterSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl Validate for ModifyClusterSubnetGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_subnet_group_name"),
+            self.cluster_subnet_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+        }
+    }
+}
+
 /// Serialize `ModifyClusterSubnetGroupMessage` contents to a `SignedRequest`.
 struct ModifyClusterSubnetGroupMessageSerializer;
 impl ModifyClusterSubnetGroupMessageSerializer {
@@ -6786,6 +8843,8 @@ impl ModifyClusterSubnetGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClusterSubnetGroupResult {
     pub cluster_subnet_group: Option<ClusterSubnetGroup>,
 }
@@ -6818,6 +8877,8 @@ impl ModifyClusterSubnetGroupResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyEventSubscriptionMessage {
     /// <p>A Boolean value indicating if the subscription is enabled. <code>true</code> indicates the subscription is enabled </p>
     pub enabled: Option<bool>,
@@ -6835,6 +8896,53 @@ pub struct ModifyEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl Validate for ModifyEventSubscriptionMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_categories {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_categories"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.severity {
+            errors.check_length(
+                &field_path(path, "severity"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.sns_topic_arn {
+            errors.check_length(
+                &field_path(path, "sns_topic_arn"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.source_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "source_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.source_type {
+            errors.check_length(
+                &field_path(path, "source_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "subscription_name"),
+            self.subscription_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `ModifyEventSubscriptionMessage` contents to a `SignedRequest`.
 struct ModifyEventSubscriptionMessageSerializer;
 impl ModifyEventSubscriptionMessageSerializer {
@@ -6878,6 +8986,8 @@ impl ModifyEventSubscriptionMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
@@ -6909,6 +9019,8 @@ impl ModifyEventSubscriptionResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifySnapshotCopyRetentionPeriodMessage {
     /// <p>The unique identifier of the cluster for which you want to change the retention period for either automated or manual snapshots that are copied to a destination AWS Region.</p> <p>Constraints: Must be the valid name of an existing cluster that has cross-region snapshot copy enabled.</p>
     pub cluster_identifier: String,
@@ -6918,6 +9030,17 @@ pub struct ModifySnapshotCopyRetentionPeriodMessage {
     pub retention_period: i64,
 }
 
+impl Validate for ModifySnapshotCopyRetentionPeriodMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `ModifySnapshotCopyRetentionPeriodMessage` contents to a `SignedRequest`.
 struct ModifySnapshotCopyRetentionPeriodMessageSerializer;
 impl ModifySnapshotCopyRetentionPeriodMessageSerializer {
@@ -6942,6 +9065,8 @@ impl ModifySnapshotCopyRetentionPeriodMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifySnapshotCopyRetentionPeriodResult {
     pub cluster: Option<Cluster>,
 }
@@ -6969,6 +9094,8 @@ impl ModifySnapshotCopyRetentionPeriodResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifySnapshotScheduleMessage {
     /// <p>An updated list of schedule definitions. A schedule definition is made up of schedule expressions, for example, "cron(30 12 *)" or "rate(12 hours)".</p>
     pub schedule_definitions: Vec<String>,
@@ -6976,6 +9103,21 @@ pub struct ModifySnapshotScheduleMessage {
     pub schedule_identifier: String,
 }
 
+impl Validate for ModifySnapshotScheduleMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.schedule_definitions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "schedule_definitions"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+        }
+        errors.check_length(
+            &field_path(path, "schedule_identifier"),
+            self.schedule_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `ModifySnapshotScheduleMessage` contents to a `SignedRequest`.
 struct ModifySnapshotScheduleMessageSerializer;
 impl ModifySnapshotScheduleMessageSerializer {
@@ -6999,6 +9141,8 @@ impl ModifySnapshotScheduleMessageSerializer {
 
 /// <p>Describes an orderable cluster option.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OrderableClusterOption {
     /// <p>A list of availability zones for the orderable cluster.</p>
     pub availability_zones: Option<Vec<AvailabilityZone>>,
@@ -7062,6 +9206,8 @@ impl OrderableClusterOptionsListDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeOrderableClusterOptions</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OrderableClusterOptionsMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -7101,6 +9247,8 @@ impl OrderableClusterOptionsMessageDeserializer {
 }
 /// <p>Describes a parameter in a cluster parameter group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Parameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
@@ -7122,6 +9270,67 @@ pub struct Parameter {
     pub source: Option<String>,
 }
 
+impl Validate for Parameter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.allowed_values {
+            errors.check_length(
+                &field_path(path, "allowed_values"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.data_type {
+            errors.check_length(
+                &field_path(path, "data_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.minimum_engine_version {
+            errors.check_length(
+                &field_path(path, "minimum_engine_version"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.parameter_name {
+            errors.check_length(
+                &field_path(path, "parameter_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.parameter_value {
+            errors.check_length(
+                &field_path(path, "parameter_value"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.source {
+            errors.check_length(
+                &field_path(path, "source"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
@@ -7296,6 +9505,8 @@ impl PendingActionsListDeserializer {
 }
 /// <p>Describes cluster attributes that are in a pending state. A change to one or more the attributes was requested and is in progress or will be applied.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PendingModifiedValues {
     /// <p>The pending or in-progress change of the automated snapshot retention period.</p>
     pub automated_snapshot_retention_period: Option<i64>,
@@ -7393,6 +9604,8 @@ impl PendingModifiedValuesDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseReservedNodeOfferingMessage {
     /// <p>The number of reserved nodes that you want to purchase.</p> <p>Default: <code>1</code> </p>
     pub node_count: Option<i64>,
@@ -7400,6 +9613,17 @@ pub struct PurchaseReservedNodeOfferingMessage {
     pub reserved_node_offering_id: String,
 }
 
+impl Validate for PurchaseReservedNodeOfferingMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "reserved_node_offering_id"),
+            self.reserved_node_offering_id.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `PurchaseReservedNodeOfferingMessage` contents to a `SignedRequest`.
 struct PurchaseReservedNodeOfferingMessageSerializer;
 impl PurchaseReservedNodeOfferingMessageSerializer {
@@ -7420,6 +9644,8 @@ impl PurchaseReservedNodeOfferingMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurchaseReservedNodeOfferingResult {
     pub reserved_node: Option<ReservedNode>,
 }
@@ -7451,11 +9677,24 @@ impl PurchaseReservedNodeOfferingResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootClusterMessage {
     /// <p>The cluster identifier.</p>
     pub cluster_identifier: String,
 }
 
+impl Validate for RebootClusterMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `RebootClusterMessage` contents to a `SignedRequest`.
 struct RebootClusterMessageSerializer;
 impl RebootClusterMessageSerializer {
@@ -7473,6 +9712,8 @@ impl RebootClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootClusterResult {
     pub cluster: Option<Cluster>,
 }
@@ -7497,6 +9738,8 @@ impl RebootClusterResultDeserializer {
 }
 /// <p>Describes a recurring charge.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecurringCharge {
     /// <p>The amount charged per the period of time specified by the recurring charge frequency.</p>
     pub recurring_charge_amount: Option<f64>,
@@ -7553,6 +9796,8 @@ impl RecurringChargeListDeserializer {
 }
 /// <p>Describes a reserved node. You can call the <a>DescribeReservedNodeOfferings</a> API to obtain the available reserved node offerings. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedNode {
     /// <p>The currency code for the reserved cluster.</p>
     pub currency_code: Option<String>,
@@ -7670,6 +9915,8 @@ impl ReservedNodeListDeserializer {
 }
 /// <p>Describes a reserved node offering.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedNodeOffering {
     /// <p>The currency code for the compute nodes offering.</p>
     pub currency_code: Option<String>,
@@ -7777,6 +10024,8 @@ impl ReservedNodeOfferingTypeDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedNodeOfferingsMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -7816,6 +10065,8 @@ impl ReservedNodeOfferingsMessageDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReservedNodesMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -7848,6 +10099,8 @@ impl ReservedNodesMessageDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetClusterParameterGroupMessage {
     /// <p>The name of the cluster parameter group to be reset.</p>
     pub parameter_group_name: String,
@@ -7857,6 +10110,23 @@ pub struct ResetClusterParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl Validate for ResetClusterParameterGroupMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parameter_group_name"),
+            self.parameter_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.parameters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `ResetClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetClusterParameterGroupMessageSerializer;
 impl ResetClusterParameterGroupMessageSerializer {
@@ -7884,6 +10154,8 @@ impl ResetClusterParameterGroupMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResizeClusterMessage {
     /// <p>A boolean value indicating whether the resize operation is using the classic resize process. If you don't provide this parameter or set the value to <code>false</code>, the resize type is elastic. </p>
     pub classic: Option<bool>,
@@ -7897,6 +10169,33 @@ pub struct ResizeClusterMessage {
     pub number_of_nodes: i64,
 }
 
+impl Validate for ResizeClusterMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.cluster_type {
+            errors.check_length(
+                &field_path(path, "cluster_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.node_type {
+            errors.check_length(
+                &field_path(path, "node_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `ResizeClusterMessage` contents to a `SignedRequest`.
 struct ResizeClusterMessageSerializer;
 impl ResizeClusterMessageSerializer {
@@ -7927,6 +10226,8 @@ impl ResizeClusterMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResizeClusterResult {
     pub cluster: Option<Cluster>,
 }
@@ -7951,6 +10252,8 @@ impl ResizeClusterResultDeserializer {
 }
 /// <p>Describes a resize operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResizeInfo {
     /// <p>A boolean value indicating if the resize operation can be cancelled.</p>
     pub allow_cancel_resize: Option<bool>,
@@ -7984,6 +10287,8 @@ impl ResizeInfoDeserializer {
 }
 /// <p>Describes the result of a cluster resize operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResizeProgressMessage {
     /// <p>The average rate of the resize operation over the last few minutes, measured in megabytes per second. After the resize operation completes, this value shows the average rate of the entire resize operation.</p>
     pub avg_resize_rate_in_mega_bytes_per_second: Option<f64>,
@@ -8144,6 +10449,8 @@ impl RestorableNodeTypeListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreFromClusterSnapshotMessage {
     /// <p>Reserved.</p>
     pub additional_info: Option<String>,
@@ -8197,6 +10504,153 @@ pub struct RestoreFromClusterSnapshotMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl Validate for RestoreFromClusterSnapshotMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_info {
+            errors.check_length(
+                &field_path(path, "additional_info"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.availability_zone {
+            errors.check_length(
+                &field_path(path, "availability_zone"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.cluster_parameter_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_parameter_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.cluster_security_groups {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "cluster_security_groups"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.cluster_subnet_group_name {
+            errors.check_length(
+                &field_path(path, "cluster_subnet_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.elastic_ip {
+            errors.check_length(
+                &field_path(path, "elastic_ip"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hsm_client_certificate_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_client_certificate_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.hsm_configuration_identifier {
+            errors.check_length(
+                &field_path(path, "hsm_configuration_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.iam_roles {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "iam_roles"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.maintenance_track_name {
+            errors.check_length(
+                &field_path(path, "maintenance_track_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.node_type {
+            errors.check_length(
+                &field_path(path, "node_type"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.owner_account {
+            errors.check_length(
+                &field_path(path, "owner_account"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.preferred_maintenance_window {
+            errors.check_length(
+                &field_path(path, "preferred_maintenance_window"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.snapshot_cluster_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.snapshot_schedule_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_schedule_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.vpc_security_group_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "vpc_security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2147483647));
+            }
+        }
+    }
+}
+
 /// Serialize `RestoreFromClusterSnapshotMessage` contents to a `SignedRequest`.
 struct RestoreFromClusterSnapshotMessageSerializer;
 impl RestoreFromClusterSnapshotMessageSerializer {
@@ -8332,6 +10786,8 @@ impl RestoreFromClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreFromClusterSnapshotResult {
     pub cluster: Option<Cluster>,
 }
@@ -8360,6 +10816,8 @@ impl RestoreFromClusterSnapshotResultDeserializer {
 }
 /// <p>Describes the status of a cluster restore action. Returns null if the cluster was not created by restoring a snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreStatus {
     /// <p>The number of megabytes per second being transferred from the backup storage. Returns the average rate for a completed backup.</p>
     pub current_restore_rate_in_mega_bytes_per_second: Option<f64>,
@@ -8423,6 +10881,8 @@ impl RestoreStatusDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreTableFromClusterSnapshotMessage {
     /// <p>The identifier of the Amazon Redshift cluster to restore the table to.</p>
     pub cluster_identifier: String,
@@ -8442,6 +10902,65 @@ pub struct RestoreTableFromClusterSnapshotMessage {
     pub target_schema_name: Option<String>,
 }
 
+impl Validate for RestoreTableFromClusterSnapshotMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "new_table_name"),
+            self.new_table_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        errors.check_length(
+            &field_path(path, "source_database_name"),
+            self.source_database_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.source_schema_name {
+            errors.check_length(
+                &field_path(path, "source_schema_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "source_table_name"),
+            self.source_table_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.target_database_name {
+            errors.check_length(
+                &field_path(path, "target_database_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.target_schema_name {
+            errors.check_length(
+                &field_path(path, "target_schema_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `RestoreTableFromClusterSnapshotMessage` contents to a `SignedRequest`.
 struct RestoreTableFromClusterSnapshotMessageSerializer;
 impl RestoreTableFromClusterSnapshotMessageSerializer {
@@ -8484,6 +11003,8 @@ impl RestoreTableFromClusterSnapshotMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreTableFromClusterSnapshotResult {
     pub table_restore_status: Option<TableRestoreStatus>,
 }
@@ -8516,6 +11037,8 @@ impl RestoreTableFromClusterSnapshotResultDeserializer {
 }
 /// <p>Describes a <code>RevisionTarget</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevisionTarget {
     /// <p>A unique string that identifies the version to update the cluster to. You can use this value in <a>ModifyClusterDbRevision</a>.</p>
     pub database_revision: Option<String>,
@@ -8575,6 +11098,8 @@ impl RevisionTargetsListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeClusterSecurityGroupIngressMessage {
     /// <p>The IP range for which to revoke access. This range must be a valid Classless Inter-Domain Routing (CIDR) block of IP addresses. If <code>CIDRIP</code> is specified, <code>EC2SecurityGroupName</code> and <code>EC2SecurityGroupOwnerId</code> cannot be provided. </p>
     pub cidrip: Option<String>,
@@ -8586,6 +11111,41 @@ pub struct RevokeClusterSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: Option<String>,
 }
 
+impl Validate for RevokeClusterSecurityGroupIngressMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cidrip {
+            errors.check_length(
+                &field_path(path, "cidrip"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "cluster_security_group_name"),
+            self.cluster_security_group_name.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.ec2_security_group_name {
+            errors.check_length(
+                &field_path(path, "ec2_security_group_name"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.ec2_security_group_owner_id {
+            errors.check_length(
+                &field_path(path, "ec2_security_group_owner_id"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 /// Serialize `RevokeClusterSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct RevokeClusterSecurityGroupIngressMessageSerializer;
 impl RevokeClusterSecurityGroupIngressMessageSerializer {
@@ -8618,6 +11178,8 @@ impl RevokeClusterSecurityGroupIngressMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeClusterSecurityGroupIngressResult {
     pub cluster_security_group: Option<ClusterSecurityGroup>,
 }
@@ -8650,6 +11212,8 @@ impl RevokeClusterSecurityGroupIngressResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeSnapshotAccessMessage {
     /// <p>The identifier of the AWS customer account that can no longer restore the specified snapshot.</p>
     pub account_with_restore_access: String,
@@ -8659,6 +11223,31 @@ pub struct RevokeSnapshotAccessMessage {
     pub snapshot_identifier: String,
 }
 
+impl Validate for RevokeSnapshotAccessMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "account_with_restore_access"),
+            self.account_with_restore_access.chars().count(),
+            None,
+            Some(2147483647),
+        );
+        if let Some(ref value) = self.snapshot_cluster_identifier {
+            errors.check_length(
+                &field_path(path, "snapshot_cluster_identifier"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "snapshot_identifier"),
+            self.snapshot_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `RevokeSnapshotAccessMessage` contents to a `SignedRequest`.
 struct RevokeSnapshotAccessMessageSerializer;
 impl RevokeSnapshotAccessMessageSerializer {
@@ -8686,6 +11275,8 @@ impl RevokeSnapshotAccessMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeSnapshotAccessResult {
     pub snapshot: Option<Snapshot>,
 }
@@ -8714,11 +11305,24 @@ impl RevokeSnapshotAccessResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RotateEncryptionKeyMessage {
     /// <p>The unique identifier of the cluster that you want to rotate the encryption keys for.</p> <p>Constraints: Must be the name of valid cluster that has encryption enabled.</p>
     pub cluster_identifier: String,
 }
 
+impl Validate for RotateEncryptionKeyMessage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_identifier"),
+            self.cluster_identifier.chars().count(),
+            None,
+            Some(2147483647),
+        );
+    }
+}
+
 /// Serialize `RotateEncryptionKeyMessage` contents to a `SignedRequest`.
 struct RotateEncryptionKeyMessageSerializer;
 impl RotateEncryptionKeyMessageSerializer {
@@ -8736,6 +11340,8 @@ impl RotateEncryptionKeyMessageSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RotateEncryptionKeyResult {
     pub cluster: Option<Cluster>,
 }
@@ -8835,6 +11441,8 @@ impl SensitiveStringDeserializer {
 }
 /// <p>Describes a snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Snapshot {
     /// <p>A list of the AWS customer accounts authorized to restore the snapshot. Returns <code>null</code> if no accounts are authorized. Visible only to the snapshot owner. </p>
     pub accounts_with_restore_access: Option<Vec<AccountWithRestoreAccess>>,
@@ -9085,6 +11693,8 @@ impl SnapshotDeserializer {
 }
 /// <p>The snapshot copy grant that grants Amazon Redshift permission to encrypt copied snapshots with the specified customer master key (CMK) from AWS KMS in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SnapshotCopyGrant {
     /// <p>The unique identifier of the customer master key (CMK) in AWS KMS to which Amazon Redshift is granted permission.</p>
     pub kms_key_id: Option<String>,
@@ -9145,6 +11755,8 @@ impl SnapshotCopyGrantListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SnapshotCopyGrantMessage {
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <code>DescribeSnapshotCopyGrant</code> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p> <p>Constraints: You can specify either the <b>SnapshotCopyGrantName</b> parameter or the <b>Marker</b> parameter, but not both. </p>
     pub marker: Option<String>,
@@ -9184,6 +11796,8 @@ impl SnapshotCopyGrantMessageDeserializer {
 }
 /// <p>Describes the errors returned by a snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SnapshotErrorMessage {
     /// <p>The failure code for the error.</p>
     pub failure_code: Option<String>,
@@ -9277,6 +11891,8 @@ impl SnapshotListDeserializer {
 }
 /// <p>Contains the output from the <a>DescribeClusterSnapshots</a> action. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SnapshotMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -9309,6 +11925,8 @@ impl SnapshotMessageDeserializer {
 }
 /// <p>Describes a snapshot schedule. You can set a regular interval for creating snapshots of a cluster. You can also schedule snapshots for specific dates. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SnapshotSchedule {
     /// <p>The number of clusters associated with the schedule.</p>
     pub associated_cluster_count: Option<i64>,
@@ -9410,6 +12028,8 @@ impl SnapshotScheduleListDeserializer {
 }
 /// <p>Describes a sorting entity</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SnapshotSortingEntity {
     /// <p>The category for sorting the snapshots.</p>
     pub attribute: String,
@@ -9497,6 +12117,8 @@ impl StringDeserializer {
 }
 /// <p>Describes a subnet.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Subnet {
     /// <p><p/></p>
     pub subnet_availability_zone: Option<AvailabilityZone>,
@@ -9563,6 +12185,8 @@ impl SubnetListDeserializer {
 }
 /// <p>Describes the operations that are allowed on a maintenance track.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SupportedOperation {
     /// <p>A list of the supported operations.</p>
     pub operation_name: Option<String>,
@@ -9609,6 +12233,8 @@ impl SupportedOperationListDeserializer {
 }
 /// <p>A list of supported platforms for orderable clusters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SupportedPlatform {
     /// <p><p/></p>
     pub name: Option<String>,
@@ -9665,6 +12291,8 @@ impl TStampDeserializer {
 }
 /// <p>Describes the status of a <a>RestoreTableFromClusterSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TableRestoreStatus {
     /// <p>The identifier of the Amazon Redshift cluster that the table is being restored to.</p>
     pub cluster_identifier: Option<String>,
@@ -9800,6 +12428,8 @@ impl TableRestoreStatusListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TableRestoreStatusMessage {
     /// <p>A pagination token that can be used in a subsequent <a>DescribeTableRestoreStatus</a> request.</p>
     pub marker: Option<String>,
@@ -9850,6 +12480,8 @@ impl TableRestoreStatusTypeDeserializer {
 }
 /// <p>A tag consisting of a name/value pair for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The key, or name, for the resource tag.</p>
     pub key: Option<String>,
@@ -9857,6 +12489,27 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(2147483647),
+            );
+        }
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -9947,6 +12600,8 @@ impl TagValueListSerializer {
 
 /// <p>A tag and its associated resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TaggedResource {
     /// <p>The Amazon Resource Name (ARN) with which the tag is associated, for example: <code>arn:aws:redshift:us-east-1:123456789:cluster:t1</code>.</p>
     pub resource_name: Option<String>,
@@ -10004,6 +12659,8 @@ impl TaggedResourceListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TaggedResourceListMessage {
     /// <p>A value that indicates the starting point for the next set of response records in a subsequent request. If a value is returned in a response, you can retrieve the next set of records by providing this returned marker value in the <code>Marker</code> parameter and retrying the command. If the <code>Marker</code> field is empty, all response records have been retrieved for the request. </p>
     pub marker: Option<String>,
@@ -10059,6 +12716,8 @@ impl TrackListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TrackListMessage {
     /// <p>A list of maintenance tracks output by the <code>DescribeClusterTracks</code> operation. </p>
     pub maintenance_tracks: Option<Vec<MaintenanceTrack>>,
@@ -10091,6 +12750,8 @@ impl TrackListMessageDeserializer {
 }
 /// <p>A maintenance track that you can switch the current track to.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTarget {
     /// <p>The cluster version for the new maintenance track.</p>
     pub database_version: Option<String>,
@@ -10147,6 +12808,8 @@ impl VpcSecurityGroupIdListSerializer {
 
 /// <p>Describes the members of a VPC security group.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VpcSecurityGroupMembership {
     /// <p>The status of the VPC security group.</p>
     pub status: Option<String>,
@@ -16767,94 +19430,15 @@ pub trait Redshift {
         input: RotateEncryptionKeyMessage,
     ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError>;
 }
-/// A client for the Amazon Redshift API.
-#[derive(Clone)]
-pub struct RedshiftClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl RedshiftClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> RedshiftClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> RedshiftClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
-        RedshiftClient { client, region }
-    }
-}
-
-impl Redshift for RedshiftClient {
-    /// <p>Exchanges a DC1 Reserved Node for a DC2 Reserved Node with no changes to the configuration (term, payment type, or number of nodes) and no additional costs. </p>
+const _: Option<&dyn Redshift> = None;
+impl<'a, T: Redshift + ?Sized> Redshift for &'a T {
     fn accept_reserved_node_exchange(
         &self,
         input: AcceptReservedNodeExchangeInputMessage,
     ) -> RusotoFuture<AcceptReservedNodeExchangeOutputMessage, AcceptReservedNodeExchangeError>
     {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AcceptReservedNodeExchange");
-        params.put("Version", "2012-12-01");
-        AcceptReservedNodeExchangeInputMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AcceptReservedNodeExchangeError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AcceptReservedNodeExchangeOutputMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AcceptReservedNodeExchangeOutputMessageDeserializer::deserialize(
-                        "AcceptReservedNodeExchangeResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).accept_reserved_node_exchange(input)
     }
-
-    /// <p>Adds an inbound (ingress) rule to an Amazon Redshift security group. Depending on whether the application accessing your cluster is running on the Internet or an Amazon EC2 instance, you can authorize inbound access to either a Classless Interdomain Routing (CIDR)/Internet Protocol (IP) range or to an Amazon EC2 security group. You can add as many as 20 ingress rules to an Amazon Redshift security group.</p> <p>If you authorize access to an Amazon EC2 security group, specify <i>EC2SecurityGroupName</i> and <i>EC2SecurityGroupOwnerId</i>. The Amazon EC2 security group and Amazon Redshift cluster must be in the same AWS Region. </p> <p>If you authorize access to a CIDR/IP address range, specify <i>CIDRIP</i>. For an overview of CIDR blocks, see the Wikipedia article on <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>. </p> <p>You must also associate the security group with a cluster so that clients running on these IP addresses or the EC2 instance are authorized to connect to the cluster. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Working with Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn authorize_cluster_security_group_ingress(
         &self,
         input: AuthorizeClusterSecurityGroupIngressMessage,
@@ -16862,2640 +19446,349 @@ impl Redshift for RedshiftClient {
         AuthorizeClusterSecurityGroupIngressResult,
         AuthorizeClusterSecurityGroupIngressError,
     > {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AuthorizeClusterSecurityGroupIngress");
-        params.put("Version", "2012-12-01");
-        AuthorizeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AuthorizeClusterSecurityGroupIngressError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AuthorizeClusterSecurityGroupIngressResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AuthorizeClusterSecurityGroupIngressResultDeserializer::deserialize(
-                        "AuthorizeClusterSecurityGroupIngressResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).authorize_cluster_security_group_ingress(input)
     }
-
-    /// <p>Authorizes the specified AWS customer account to restore the specified snapshot.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn authorize_snapshot_access(
         &self,
         input: AuthorizeSnapshotAccessMessage,
     ) -> RusotoFuture<AuthorizeSnapshotAccessResult, AuthorizeSnapshotAccessError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AuthorizeSnapshotAccess");
-        params.put("Version", "2012-12-01");
-        AuthorizeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AuthorizeSnapshotAccessError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AuthorizeSnapshotAccessResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AuthorizeSnapshotAccessResultDeserializer::deserialize(
-                        "AuthorizeSnapshotAccessResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).authorize_snapshot_access(input)
     }
-
-    /// <p>Deletes a set of cluster snapshots.</p>
     fn batch_delete_cluster_snapshots(
         &self,
         input: BatchDeleteClusterSnapshotsRequest,
     ) -> RusotoFuture<BatchDeleteClusterSnapshotsResult, BatchDeleteClusterSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BatchDeleteClusterSnapshots");
-        params.put("Version", "2012-12-01");
-        BatchDeleteClusterSnapshotsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchDeleteClusterSnapshotsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = BatchDeleteClusterSnapshotsResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = BatchDeleteClusterSnapshotsResultDeserializer::deserialize(
-                        "BatchDeleteClusterSnapshotsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).batch_delete_cluster_snapshots(input)
     }
-
-    /// <p>Modifies the settings for a list of snapshots.</p>
     fn batch_modify_cluster_snapshots(
         &self,
         input: BatchModifyClusterSnapshotsMessage,
     ) -> RusotoFuture<BatchModifyClusterSnapshotsOutputMessage, BatchModifyClusterSnapshotsError>
     {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BatchModifyClusterSnapshots");
-        params.put("Version", "2012-12-01");
-        BatchModifyClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(BatchModifyClusterSnapshotsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = BatchModifyClusterSnapshotsOutputMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = BatchModifyClusterSnapshotsOutputMessageDeserializer::deserialize(
-                        "BatchModifyClusterSnapshotsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).batch_modify_cluster_snapshots(input)
     }
-
-    /// <p>Cancels a resize operation.</p>
     fn cancel_resize(
         &self,
         input: CancelResizeMessage,
     ) -> RusotoFuture<ResizeProgressMessage, CancelResizeError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CancelResize");
-        params.put("Version", "2012-12-01");
-        CancelResizeMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelResizeError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ResizeProgressMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ResizeProgressMessageDeserializer::deserialize(
-                        "CancelResizeResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).cancel_resize(input)
     }
-
-    /// <p>Copies the specified automated cluster snapshot to a new manual cluster snapshot. The source must be an automated snapshot and it must be in the available state.</p> <p>When you delete a cluster, Amazon Redshift deletes any automated snapshots of the cluster. Also, when the retention period of the snapshot expires, Amazon Redshift automatically deletes it. If you want to keep an automated snapshot for a longer period, you can make a manual copy of the snapshot. Manual snapshots are retained until you delete them.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn copy_cluster_snapshot(
         &self,
         input: CopyClusterSnapshotMessage,
     ) -> RusotoFuture<CopyClusterSnapshotResult, CopyClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CopyClusterSnapshot");
-        params.put("Version", "2012-12-01");
-        CopyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CopyClusterSnapshotError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CopyClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CopyClusterSnapshotResultDeserializer::deserialize(
-                        "CopyClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).copy_cluster_snapshot(input)
     }
-
-    /// <p>Creates a new cluster.</p> <p>To create a cluster in Virtual Private Cloud (VPC), you must provide a cluster subnet group name. The cluster subnet group identifies the subnets of your VPC that Amazon Redshift uses when creating the cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn create_cluster(
         &self,
         input: CreateClusterMessage,
     ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateCluster");
-        params.put("Version", "2012-12-01");
-        CreateClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateClusterResultDeserializer::deserialize(
-                        "CreateClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cluster(input)
     }
-
-    /// <p>Creates an Amazon Redshift parameter group.</p> <p>Creating parameter groups is independent of creating clusters. You can associate a cluster with a parameter group when you create the cluster. You can also associate an existing cluster with a parameter group after the cluster is created by using <a>ModifyCluster</a>. </p> <p>Parameters in the parameter group define specific behavior that applies to the databases you create on the cluster. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn create_cluster_parameter_group(
         &self,
         input: CreateClusterParameterGroupMessage,
     ) -> RusotoFuture<CreateClusterParameterGroupResult, CreateClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateClusterParameterGroup");
-        params.put("Version", "2012-12-01");
-        CreateClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateClusterParameterGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateClusterParameterGroupResultDeserializer::deserialize(
-                        "CreateClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cluster_parameter_group(input)
     }
-
-    /// <p>Creates a new Amazon Redshift security group. You use security groups to control access to non-VPC clusters.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn create_cluster_security_group(
         &self,
         input: CreateClusterSecurityGroupMessage,
     ) -> RusotoFuture<CreateClusterSecurityGroupResult, CreateClusterSecurityGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateClusterSecurityGroup");
-        params.put("Version", "2012-12-01");
-        CreateClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateClusterSecurityGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateClusterSecurityGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateClusterSecurityGroupResultDeserializer::deserialize(
-                        "CreateClusterSecurityGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cluster_security_group(input)
     }
-
-    /// <p>Creates a manual snapshot of the specified cluster. The cluster must be in the <code>available</code> state. </p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn create_cluster_snapshot(
         &self,
         input: CreateClusterSnapshotMessage,
     ) -> RusotoFuture<CreateClusterSnapshotResult, CreateClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateClusterSnapshot");
-        params.put("Version", "2012-12-01");
-        CreateClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateClusterSnapshotResultDeserializer::deserialize(
-                        "CreateClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cluster_snapshot(input)
     }
-
-    /// <p>Creates a new Amazon Redshift subnet group. You must provide a list of one or more subnets in your existing Amazon Virtual Private Cloud (Amazon VPC) when creating Amazon Redshift subnet group.</p> <p> For information about subnet groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-cluster-subnet-groups.html">Amazon Redshift Cluster Subnet Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn create_cluster_subnet_group(
         &self,
         input: CreateClusterSubnetGroupMessage,
     ) -> RusotoFuture<CreateClusterSubnetGroupResult, CreateClusterSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateClusterSubnetGroup");
-        params.put("Version", "2012-12-01");
-        CreateClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateClusterSubnetGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateClusterSubnetGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateClusterSubnetGroupResultDeserializer::deserialize(
-                        "CreateClusterSubnetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_cluster_subnet_group(input)
     }
-
-    /// <p>Creates an Amazon Redshift event notification subscription. This action requires an ARN (Amazon Resource Name) of an Amazon SNS topic created by either the Amazon Redshift console, the Amazon SNS console, or the Amazon SNS API. To obtain an ARN with Amazon SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the source type, and lists of Amazon Redshift source IDs, event categories, and event severities. Notifications will be sent for all events you want that match those criteria. For example, you can specify source type = cluster, source ID = my-cluster-1 and mycluster2, event categories = Availability, Backup, and severity = ERROR. The subscription will only send notifications for those ERROR events in the Availability and Backup categories for the specified clusters.</p> <p>If you specify both the source type and source IDs, such as source type = cluster and source identifier = my-cluster-1, notifications will be sent for all the cluster events for my-cluster-1. If you specify a source type but do not specify a source identifier, you will receive notice of the events for the objects of that type in your AWS account. If you do not specify either the SourceType nor the SourceIdentifier, you will be notified of events generated from all Amazon Redshift sources belonging to your AWS account. You must specify a source type if you specify a source ID.</p>
     fn create_event_subscription(
         &self,
         input: CreateEventSubscriptionMessage,
     ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateEventSubscription");
-        params.put("Version", "2012-12-01");
-        CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateEventSubscriptionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateEventSubscriptionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateEventSubscriptionResultDeserializer::deserialize(
-                        "CreateEventSubscriptionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_event_subscription(input)
     }
-
-    /// <p>Creates an HSM client certificate that an Amazon Redshift cluster will use to connect to the client's HSM in order to store and retrieve the keys used to encrypt the cluster databases.</p> <p>The command returns a public key, which you must store in the HSM. In addition to creating the HSM certificate, you must create an Amazon Redshift HSM configuration that provides a cluster the information needed to store and use encryption keys in the HSM. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
     fn create_hsm_client_certificate(
         &self,
         input: CreateHsmClientCertificateMessage,
     ) -> RusotoFuture<CreateHsmClientCertificateResult, CreateHsmClientCertificateError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateHsmClientCertificate");
-        params.put("Version", "2012-12-01");
-        CreateHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateHsmClientCertificateError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateHsmClientCertificateResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateHsmClientCertificateResultDeserializer::deserialize(
-                        "CreateHsmClientCertificateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_hsm_client_certificate(input)
     }
-
-    /// <p>Creates an HSM configuration that contains the information required by an Amazon Redshift cluster to store and use database encryption keys in a Hardware Security Module (HSM). After creating the HSM configuration, you can specify it as a parameter when creating a cluster. The cluster will then store its encryption keys in the HSM.</p> <p>In addition to creating an HSM configuration, you must also create an HSM client certificate. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
     fn create_hsm_configuration(
         &self,
         input: CreateHsmConfigurationMessage,
     ) -> RusotoFuture<CreateHsmConfigurationResult, CreateHsmConfigurationError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateHsmConfiguration");
-        params.put("Version", "2012-12-01");
-        CreateHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateHsmConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateHsmConfigurationResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateHsmConfigurationResultDeserializer::deserialize(
-                        "CreateHsmConfigurationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_hsm_configuration(input)
     }
-
-    /// <p>Creates a snapshot copy grant that permits Amazon Redshift to use a customer master key (CMK) from AWS Key Management Service (AWS KMS) to encrypt copied snapshots in a destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
     fn create_snapshot_copy_grant(
         &self,
         input: CreateSnapshotCopyGrantMessage,
     ) -> RusotoFuture<CreateSnapshotCopyGrantResult, CreateSnapshotCopyGrantError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateSnapshotCopyGrant");
-        params.put("Version", "2012-12-01");
-        CreateSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSnapshotCopyGrantError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateSnapshotCopyGrantResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateSnapshotCopyGrantResultDeserializer::deserialize(
-                        "CreateSnapshotCopyGrantResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_snapshot_copy_grant(input)
     }
-
-    /// <p>Creates a new snapshot schedule.</p>
     fn create_snapshot_schedule(
         &self,
         input: CreateSnapshotScheduleMessage,
     ) -> RusotoFuture<SnapshotSchedule, CreateSnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateSnapshotSchedule");
-        params.put("Version", "2012-12-01");
-        CreateSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSnapshotScheduleError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SnapshotSchedule::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SnapshotScheduleDeserializer::deserialize(
-                        "CreateSnapshotScheduleResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_snapshot_schedule(input)
     }
-
-    /// <p>Adds one or more tags to a specified resource.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, you will receive an error and the attempt will fail.</p> <p>If you specify a key that already exists for the resource, the value for that key will be updated with the new value.</p>
     fn create_tags(&self, input: CreateTagsMessage) -> RusotoFuture<(), CreateTagsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateTags");
-        params.put("Version", "2012-12-01");
-        CreateTagsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).create_tags(input)
     }
-
-    /// <p>Deletes a previously provisioned cluster. A successful response from the web service indicates that the request was received correctly. Use <a>DescribeClusters</a> to monitor the status of the deletion. The delete operation cannot be canceled or reverted once submitted. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you want to shut down the cluster and retain it for future use, set <i>SkipFinalClusterSnapshot</i> to <code>false</code> and specify a name for <i>FinalClusterSnapshotIdentifier</i>. You can later restore this snapshot to resume using the cluster. If a final cluster snapshot is requested, the status of the cluster will be "final-snapshot" while the snapshot is being taken, then it's "deleting" once Amazon Redshift begins deleting the cluster. </p> <p> For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn delete_cluster(
         &self,
         input: DeleteClusterMessage,
     ) -> RusotoFuture<DeleteClusterResult, DeleteClusterError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteCluster");
-        params.put("Version", "2012-12-01");
-        DeleteClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteClusterResultDeserializer::deserialize(
-                        "DeleteClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_cluster(input)
     }
-
-    /// <p><p>Deletes a specified Amazon Redshift parameter group.</p> <note> <p>You cannot delete a parameter group if it is associated with a cluster.</p> </note></p>
     fn delete_cluster_parameter_group(
         &self,
         input: DeleteClusterParameterGroupMessage,
     ) -> RusotoFuture<(), DeleteClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteClusterParameterGroup");
-        params.put("Version", "2012-12-01");
-        DeleteClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cluster_parameter_group(input)
     }
-
-    /// <p>Deletes an Amazon Redshift security group.</p> <note> <p>You cannot delete a security group that is associated with any clusters. You cannot delete the default security group.</p> </note> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn delete_cluster_security_group(
         &self,
         input: DeleteClusterSecurityGroupMessage,
     ) -> RusotoFuture<(), DeleteClusterSecurityGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteClusterSecurityGroup");
-        params.put("Version", "2012-12-01");
-        DeleteClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteClusterSecurityGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cluster_security_group(input)
     }
-
-    /// <p>Deletes the specified manual snapshot. The snapshot must be in the <code>available</code> state, with no other users authorized to access the snapshot. </p> <p>Unlike automated snapshots, manual snapshots are retained even after you delete your cluster. Amazon Redshift does not delete your manual snapshots. You must delete manual snapshot explicitly to avoid getting charged. If other accounts are authorized to access the snapshot, you must revoke all of the authorizations before you can delete the snapshot.</p>
     fn delete_cluster_snapshot(
         &self,
         input: DeleteClusterSnapshotMessage,
     ) -> RusotoFuture<DeleteClusterSnapshotResult, DeleteClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteClusterSnapshot");
-        params.put("Version", "2012-12-01");
-        DeleteClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteClusterSnapshotResultDeserializer::deserialize(
-                        "DeleteClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_cluster_snapshot(input)
     }
-
-    /// <p>Deletes the specified cluster subnet group.</p>
     fn delete_cluster_subnet_group(
         &self,
         input: DeleteClusterSubnetGroupMessage,
     ) -> RusotoFuture<(), DeleteClusterSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteClusterSubnetGroup");
-        params.put("Version", "2012-12-01");
-        DeleteClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteClusterSubnetGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_cluster_subnet_group(input)
     }
-
-    /// <p>Deletes an Amazon Redshift event notification subscription.</p>
     fn delete_event_subscription(
         &self,
         input: DeleteEventSubscriptionMessage,
     ) -> RusotoFuture<(), DeleteEventSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteEventSubscription");
-        params.put("Version", "2012-12-01");
-        DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteEventSubscriptionError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_event_subscription(input)
     }
-
-    /// <p>Deletes the specified HSM client certificate.</p>
     fn delete_hsm_client_certificate(
         &self,
         input: DeleteHsmClientCertificateMessage,
     ) -> RusotoFuture<(), DeleteHsmClientCertificateError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteHsmClientCertificate");
-        params.put("Version", "2012-12-01");
-        DeleteHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteHsmClientCertificateError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_hsm_client_certificate(input)
     }
-
-    /// <p>Deletes the specified Amazon Redshift HSM configuration.</p>
     fn delete_hsm_configuration(
         &self,
         input: DeleteHsmConfigurationMessage,
     ) -> RusotoFuture<(), DeleteHsmConfigurationError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteHsmConfiguration");
-        params.put("Version", "2012-12-01");
-        DeleteHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteHsmConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_hsm_configuration(input)
     }
-
-    /// <p>Deletes the specified snapshot copy grant.</p>
     fn delete_snapshot_copy_grant(
         &self,
         input: DeleteSnapshotCopyGrantMessage,
     ) -> RusotoFuture<(), DeleteSnapshotCopyGrantError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteSnapshotCopyGrant");
-        params.put("Version", "2012-12-01");
-        DeleteSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSnapshotCopyGrantError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_snapshot_copy_grant(input)
     }
-
-    /// <p>Deletes a snapshot schedule.</p>
     fn delete_snapshot_schedule(
         &self,
         input: DeleteSnapshotScheduleMessage,
     ) -> RusotoFuture<(), DeleteSnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteSnapshotSchedule");
-        params.put("Version", "2012-12-01");
-        DeleteSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSnapshotScheduleError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_snapshot_schedule(input)
     }
-
-    /// <p>Deletes a tag or tags from a resource. You must provide the ARN of the resource from which you want to delete the tag or tags.</p>
     fn delete_tags(&self, input: DeleteTagsMessage) -> RusotoFuture<(), DeleteTagsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteTags");
-        params.put("Version", "2012-12-01");
-        DeleteTagsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_tags(input)
     }
-
-    /// <p>Returns a list of attributes attached to an account</p>
     fn describe_account_attributes(
         &self,
         input: DescribeAccountAttributesMessage,
     ) -> RusotoFuture<AccountAttributeList, DescribeAccountAttributesError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAccountAttributes");
-        params.put("Version", "2012-12-01");
-        DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = AccountAttributeList::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = AccountAttributeListDeserializer::deserialize(
-                        "DescribeAccountAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_account_attributes(input)
     }
-
-    /// <p>Returns an array of <code>ClusterDbRevision</code> objects.</p>
     fn describe_cluster_db_revisions(
         &self,
         input: DescribeClusterDbRevisionsMessage,
     ) -> RusotoFuture<ClusterDbRevisionsMessage, DescribeClusterDbRevisionsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterDbRevisions");
-        params.put("Version", "2012-12-01");
-        DescribeClusterDbRevisionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterDbRevisionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterDbRevisionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterDbRevisionsMessageDeserializer::deserialize(
-                        "DescribeClusterDbRevisionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_db_revisions(input)
     }
-
-    /// <p>Returns a list of Amazon Redshift parameter groups, including parameter groups you created and the default parameter group. For each parameter group, the response includes the parameter group name, description, and parameter group family name. You can optionally specify a name to retrieve the description of a specific parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all parameter groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all parameter groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, parameter groups are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_cluster_parameter_groups(
         &self,
         input: DescribeClusterParameterGroupsMessage,
     ) -> RusotoFuture<ClusterParameterGroupsMessage, DescribeClusterParameterGroupsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterParameterGroups");
-        params.put("Version", "2012-12-01");
-        DescribeClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterParameterGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterParameterGroupsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterParameterGroupsMessageDeserializer::deserialize(
-                        "DescribeClusterParameterGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_parameter_groups(input)
     }
-
-    /// <p>Returns a detailed list of parameters contained within the specified Amazon Redshift parameter group. For each parameter the response includes information such as parameter name, description, data type, value, whether the parameter value is modifiable, and so on.</p> <p>You can specify <i>source</i> filter to retrieve parameters of only specific type. For example, to retrieve parameters that were modified by a user action such as from <a>ModifyClusterParameterGroup</a>, you can specify <i>source</i> equal to <i>user</i>.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn describe_cluster_parameters(
         &self,
         input: DescribeClusterParametersMessage,
     ) -> RusotoFuture<ClusterParameterGroupDetails, DescribeClusterParametersError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterParameters");
-        params.put("Version", "2012-12-01");
-        DescribeClusterParametersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterParametersError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterParameterGroupDetails::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterParameterGroupDetailsDeserializer::deserialize(
-                        "DescribeClusterParametersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_parameters(input)
     }
-
-    /// <p>Returns information about Amazon Redshift security groups. If the name of a security group is specified, the response will contain only information about only that security group.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all security groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all security groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, security groups are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_cluster_security_groups(
         &self,
         input: DescribeClusterSecurityGroupsMessage,
     ) -> RusotoFuture<ClusterSecurityGroupMessage, DescribeClusterSecurityGroupsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterSecurityGroups");
-        params.put("Version", "2012-12-01");
-        DescribeClusterSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterSecurityGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterSecurityGroupMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterSecurityGroupMessageDeserializer::deserialize(
-                        "DescribeClusterSecurityGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_security_groups(input)
     }
-
-    /// <p>Returns one or more snapshot objects, which contain metadata about your cluster snapshots. By default, this operation returns information about all snapshots of all clusters that are owned by you AWS customer account. No information is returned for snapshots owned by inactive AWS customer accounts.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all snapshots that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all snapshots that have any combination of those values are returned. Only snapshots that you own are returned in the response; shared snapshots are not returned with the tag key and tag value request parameters.</p> <p>If both tag keys and values are omitted from the request, snapshots are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_cluster_snapshots(
         &self,
         input: DescribeClusterSnapshotsMessage,
     ) -> RusotoFuture<SnapshotMessage, DescribeClusterSnapshotsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterSnapshots");
-        params.put("Version", "2012-12-01");
-        DescribeClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterSnapshotsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SnapshotMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SnapshotMessageDeserializer::deserialize(
-                        "DescribeClusterSnapshotsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_snapshots(input)
     }
-
-    /// <p>Returns one or more cluster subnet group objects, which contain metadata about your cluster subnet groups. By default, this operation returns information about all cluster subnet groups that are defined in you AWS account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all subnet groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subnet groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subnet groups are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_cluster_subnet_groups(
         &self,
         input: DescribeClusterSubnetGroupsMessage,
     ) -> RusotoFuture<ClusterSubnetGroupMessage, DescribeClusterSubnetGroupsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterSubnetGroups");
-        params.put("Version", "2012-12-01");
-        DescribeClusterSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterSubnetGroupsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterSubnetGroupMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterSubnetGroupMessageDeserializer::deserialize(
-                        "DescribeClusterSubnetGroupsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_subnet_groups(input)
     }
-
-    /// <p>Returns a list of all the available maintenance tracks.</p>
     fn describe_cluster_tracks(
         &self,
         input: DescribeClusterTracksMessage,
     ) -> RusotoFuture<TrackListMessage, DescribeClusterTracksError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterTracks");
-        params.put("Version", "2012-12-01");
-        DescribeClusterTracksMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterTracksError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TrackListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TrackListMessageDeserializer::deserialize(
-                        "DescribeClusterTracksResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_tracks(input)
     }
-
-    /// <p>Returns descriptions of the available Amazon Redshift cluster versions. You can call this operation even before creating any clusters to learn more about the Amazon Redshift versions. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn describe_cluster_versions(
         &self,
         input: DescribeClusterVersionsMessage,
     ) -> RusotoFuture<ClusterVersionsMessage, DescribeClusterVersionsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusterVersions");
-        params.put("Version", "2012-12-01");
-        DescribeClusterVersionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClusterVersionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterVersionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterVersionsMessageDeserializer::deserialize(
-                        "DescribeClusterVersionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_cluster_versions(input)
     }
-
-    /// <p>Returns properties of provisioned clusters including general cluster properties, cluster database properties, maintenance and backup properties, and security and access properties. This operation supports pagination. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all clusters that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all clusters that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, clusters are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_clusters(
         &self,
         input: DescribeClustersMessage,
     ) -> RusotoFuture<ClustersMessage, DescribeClustersError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeClusters");
-        params.put("Version", "2012-12-01");
-        DescribeClustersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeClustersError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClustersMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClustersMessageDeserializer::deserialize(
-                        "DescribeClustersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_clusters(input)
     }
-
-    /// <p>Returns a list of parameter settings for the specified parameter group family.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn describe_default_cluster_parameters(
         &self,
         input: DescribeDefaultClusterParametersMessage,
     ) -> RusotoFuture<DescribeDefaultClusterParametersResult, DescribeDefaultClusterParametersError>
     {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeDefaultClusterParameters");
-        params.put("Version", "2012-12-01");
-        DescribeDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDefaultClusterParametersError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeDefaultClusterParametersResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeDefaultClusterParametersResultDeserializer::deserialize(
-                        "DescribeDefaultClusterParametersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_default_cluster_parameters(input)
     }
-
-    /// <p>Displays a list of event categories for all event source types, or for a specified source type. For a list of the event categories and source types, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-event-notifications.html">Amazon Redshift Event Notifications</a>.</p>
     fn describe_event_categories(
         &self,
         input: DescribeEventCategoriesMessage,
     ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEventCategories");
-        params.put("Version", "2012-12-01");
-        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEventCategoriesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventCategoriesMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EventCategoriesMessageDeserializer::deserialize(
-                        "DescribeEventCategoriesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_event_categories(input)
     }
-
-    /// <p>Lists descriptions of all the Amazon Redshift event notification subscriptions for a customer account. If you specify a subscription name, lists the description for that subscription.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all event notification subscriptions that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subscriptions that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subscriptions are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_event_subscriptions(
         &self,
         input: DescribeEventSubscriptionsMessage,
     ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEventSubscriptions");
-        params.put("Version", "2012-12-01");
-        DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEventSubscriptionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventSubscriptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EventSubscriptionsMessageDeserializer::deserialize(
-                        "DescribeEventSubscriptionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_event_subscriptions(input)
     }
-
-    /// <p>Returns events related to clusters, security groups, snapshots, and parameter groups for the past 14 days. Events specific to a particular cluster, security group, snapshot or parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
     fn describe_events(
         &self,
         input: DescribeEventsMessage,
     ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeEvents");
-        params.put("Version", "2012-12-01");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EventsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        EventsMessageDeserializer::deserialize("DescribeEventsResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_events(input)
     }
-
-    /// <p>Returns information about the specified HSM client certificate. If no certificate ID is specified, returns information about all the HSM certificates owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM client certificates that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM client certificates that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM client certificates are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_hsm_client_certificates(
         &self,
         input: DescribeHsmClientCertificatesMessage,
     ) -> RusotoFuture<HsmClientCertificateMessage, DescribeHsmClientCertificatesError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeHsmClientCertificates");
-        params.put("Version", "2012-12-01");
-        DescribeHsmClientCertificatesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeHsmClientCertificatesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = HsmClientCertificateMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = HsmClientCertificateMessageDeserializer::deserialize(
-                        "DescribeHsmClientCertificatesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_hsm_client_certificates(input)
     }
-
-    /// <p>Returns information about the specified Amazon Redshift HSM configuration. If no configuration ID is specified, returns information about all the HSM configurations owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM connections that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM connections that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM connections are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_hsm_configurations(
         &self,
         input: DescribeHsmConfigurationsMessage,
     ) -> RusotoFuture<HsmConfigurationMessage, DescribeHsmConfigurationsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeHsmConfigurations");
-        params.put("Version", "2012-12-01");
-        DescribeHsmConfigurationsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeHsmConfigurationsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = HsmConfigurationMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = HsmConfigurationMessageDeserializer::deserialize(
-                        "DescribeHsmConfigurationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_hsm_configurations(input)
     }
-
-    /// <p>Describes whether information, such as queries and connection attempts, is being logged for the specified Amazon Redshift cluster.</p>
     fn describe_logging_status(
         &self,
         input: DescribeLoggingStatusMessage,
     ) -> RusotoFuture<LoggingStatus, DescribeLoggingStatusError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeLoggingStatus");
-        params.put("Version", "2012-12-01");
-        DescribeLoggingStatusMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeLoggingStatusError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = LoggingStatus::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = LoggingStatusDeserializer::deserialize(
-                        "DescribeLoggingStatusResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_logging_status(input)
     }
-
-    /// <p>Returns a list of orderable cluster options. Before you create a new cluster you can use this operation to find what options are available, such as the EC2 Availability Zones (AZ) in the specific AWS Region that you can specify, and the node types you can request. The node types differ by available storage, memory, CPU and price. With the cost involved you might want to obtain a list of cluster options in the specific region and specify values when creating a cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn describe_orderable_cluster_options(
         &self,
         input: DescribeOrderableClusterOptionsMessage,
     ) -> RusotoFuture<OrderableClusterOptionsMessage, DescribeOrderableClusterOptionsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeOrderableClusterOptions");
-        params.put("Version", "2012-12-01");
-        DescribeOrderableClusterOptionsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeOrderableClusterOptionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = OrderableClusterOptionsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = OrderableClusterOptionsMessageDeserializer::deserialize(
-                        "DescribeOrderableClusterOptionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_orderable_cluster_options(input)
     }
-
-    /// <p>Returns a list of the available reserved node offerings by Amazon Redshift with their descriptions including the node type, the fixed and recurring costs of reserving the node and duration the node will be reserved for you. These descriptions help you determine which reserve node offering you want to purchase. You then use the unique offering ID in you call to <a>PurchaseReservedNodeOffering</a> to reserve one or more nodes for your Amazon Redshift cluster. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn describe_reserved_node_offerings(
         &self,
         input: DescribeReservedNodeOfferingsMessage,
     ) -> RusotoFuture<ReservedNodeOfferingsMessage, DescribeReservedNodeOfferingsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReservedNodeOfferings");
-        params.put("Version", "2012-12-01");
-        DescribeReservedNodeOfferingsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReservedNodeOfferingsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ReservedNodeOfferingsMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ReservedNodeOfferingsMessageDeserializer::deserialize(
-                        "DescribeReservedNodeOfferingsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_reserved_node_offerings(input)
     }
-
-    /// <p>Returns the descriptions of the reserved nodes.</p>
     fn describe_reserved_nodes(
         &self,
         input: DescribeReservedNodesMessage,
     ) -> RusotoFuture<ReservedNodesMessage, DescribeReservedNodesError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReservedNodes");
-        params.put("Version", "2012-12-01");
-        DescribeReservedNodesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReservedNodesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ReservedNodesMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ReservedNodesMessageDeserializer::deserialize(
-                        "DescribeReservedNodesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_reserved_nodes(input)
     }
-
-    /// <p>Returns information about the last resize operation for the specified cluster. If no resize operation has ever been initiated for the specified cluster, a <code>HTTP 404</code> error is returned. If a resize operation was initiated and completed, the status of the resize remains as <code>SUCCEEDED</code> until the next resize. </p> <p>A resize operation can be requested using <a>ModifyCluster</a> and specifying a different number or type of nodes for the cluster. </p>
     fn describe_resize(
         &self,
         input: DescribeResizeMessage,
     ) -> RusotoFuture<ResizeProgressMessage, DescribeResizeError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeResize");
-        params.put("Version", "2012-12-01");
-        DescribeResizeMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeResizeError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ResizeProgressMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ResizeProgressMessageDeserializer::deserialize(
-                        "DescribeResizeResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_resize(input)
     }
-
-    /// <p>Returns a list of snapshot copy grants owned by the AWS account in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
     fn describe_snapshot_copy_grants(
         &self,
         input: DescribeSnapshotCopyGrantsMessage,
     ) -> RusotoFuture<SnapshotCopyGrantMessage, DescribeSnapshotCopyGrantsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeSnapshotCopyGrants");
-        params.put("Version", "2012-12-01");
-        DescribeSnapshotCopyGrantsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSnapshotCopyGrantsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SnapshotCopyGrantMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SnapshotCopyGrantMessageDeserializer::deserialize(
-                        "DescribeSnapshotCopyGrantsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_snapshot_copy_grants(input)
     }
-
-    /// <p>Returns a list of snapshot schedules. </p>
     fn describe_snapshot_schedules(
         &self,
         input: DescribeSnapshotSchedulesMessage,
     ) -> RusotoFuture<DescribeSnapshotSchedulesOutputMessage, DescribeSnapshotSchedulesError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeSnapshotSchedules");
-        params.put("Version", "2012-12-01");
-        DescribeSnapshotSchedulesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSnapshotSchedulesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeSnapshotSchedulesOutputMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeSnapshotSchedulesOutputMessageDeserializer::deserialize(
-                        "DescribeSnapshotSchedulesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_snapshot_schedules(input)
     }
-
-    /// <p>Returns the total amount of snapshot usage and provisioned storage in megabytes.</p>
     fn describe_storage(&self) -> RusotoFuture<CustomerStorageMessage, DescribeStorageError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeStorage");
-        params.put("Version", "2012-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeStorageError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CustomerStorageMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CustomerStorageMessageDeserializer::deserialize(
-                        "DescribeStorageResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_storage()
     }
-
-    /// <p>Lists the status of one or more table restore requests made using the <a>RestoreTableFromClusterSnapshot</a> API action. If you don't specify a value for the <code>TableRestoreRequestId</code> parameter, then <code>DescribeTableRestoreStatus</code> returns the status of all table restore requests ordered by the date and time of the request in ascending order. Otherwise <code>DescribeTableRestoreStatus</code> returns the status of the table specified by <code>TableRestoreRequestId</code>.</p>
     fn describe_table_restore_status(
         &self,
         input: DescribeTableRestoreStatusMessage,
     ) -> RusotoFuture<TableRestoreStatusMessage, DescribeTableRestoreStatusError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTableRestoreStatus");
-        params.put("Version", "2012-12-01");
-        DescribeTableRestoreStatusMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTableRestoreStatusError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TableRestoreStatusMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TableRestoreStatusMessageDeserializer::deserialize(
-                        "DescribeTableRestoreStatusResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_table_restore_status(input)
     }
-
-    /// <p>Returns a list of tags. You can return tags from a specific resource by specifying an ARN, or you can return all tags for a given type of resource, such as clusters, snapshots, and so on.</p> <p>The following are limitations for <code>DescribeTags</code>: </p> <ul> <li> <p>You cannot specify an ARN and a resource-type value together in the same request.</p> </li> <li> <p>You cannot use the <code>MaxRecords</code> and <code>Marker</code> parameters together with the ARN parameter.</p> </li> <li> <p>The <code>MaxRecords</code> parameter can be a range from 10 to 50 results to return in a request.</p> </li> </ul> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all resources that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all resources that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, resources are returned regardless of whether they have tag keys or values associated with them.</p>
     fn describe_tags(
         &self,
         input: DescribeTagsMessage,
     ) -> RusotoFuture<TaggedResourceListMessage, DescribeTagsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeTags");
-        params.put("Version", "2012-12-01");
-        DescribeTagsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TaggedResourceListMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TaggedResourceListMessageDeserializer::deserialize(
-                        "DescribeTagsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_tags(input)
     }
-
-    /// <p>Stops logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
     fn disable_logging(
         &self,
         input: DisableLoggingMessage,
     ) -> RusotoFuture<LoggingStatus, DisableLoggingError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DisableLogging");
-        params.put("Version", "2012-12-01");
-        DisableLoggingMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableLoggingError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = LoggingStatus::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        LoggingStatusDeserializer::deserialize("DisableLoggingResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).disable_logging(input)
     }
-
-    /// <p>Disables the automatic copying of snapshots from one region to another region for a specified cluster.</p> <p>If your cluster and its snapshots are encrypted using a customer master key (CMK) from AWS KMS, use <a>DeleteSnapshotCopyGrant</a> to delete the grant that grants Amazon Redshift permission to the CMK in the destination region. </p>
     fn disable_snapshot_copy(
         &self,
         input: DisableSnapshotCopyMessage,
     ) -> RusotoFuture<DisableSnapshotCopyResult, DisableSnapshotCopyError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DisableSnapshotCopy");
-        params.put("Version", "2012-12-01");
-        DisableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisableSnapshotCopyError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DisableSnapshotCopyResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DisableSnapshotCopyResultDeserializer::deserialize(
-                        "DisableSnapshotCopyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).disable_snapshot_copy(input)
     }
-
-    /// <p>Starts logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
     fn enable_logging(
         &self,
         input: EnableLoggingMessage,
     ) -> RusotoFuture<LoggingStatus, EnableLoggingError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "EnableLogging");
-        params.put("Version", "2012-12-01");
-        EnableLoggingMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableLoggingError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = LoggingStatus::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        LoggingStatusDeserializer::deserialize("EnableLoggingResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).enable_logging(input)
     }
-
-    /// <p>Enables the automatic copy of snapshots from one region to another region for a specified cluster.</p>
     fn enable_snapshot_copy(
         &self,
         input: EnableSnapshotCopyMessage,
     ) -> RusotoFuture<EnableSnapshotCopyResult, EnableSnapshotCopyError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "EnableSnapshotCopy");
-        params.put("Version", "2012-12-01");
-        EnableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableSnapshotCopyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = EnableSnapshotCopyResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = EnableSnapshotCopyResultDeserializer::deserialize(
-                        "EnableSnapshotCopyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).enable_snapshot_copy(input)
     }
-
-    /// <p>Returns a database user name and temporary password with temporary authorization to log on to an Amazon Redshift database. The action returns the database user name prefixed with <code>IAM:</code> if <code>AutoCreate</code> is <code>False</code> or <code>IAMA:</code> if <code>AutoCreate</code> is <code>True</code>. You can optionally specify one or more database user groups that the user will join at log on. By default, the temporary credentials expire in 900 seconds. You can optionally specify a duration between 900 seconds (15 minutes) and 3600 seconds (60 minutes). For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/generating-user-credentials.html">Using IAM Authentication to Generate Database User Credentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>The AWS Identity and Access Management (IAM)user or role that executes GetClusterCredentials must have an IAM policy attached that allows access to all necessary actions and resources. For more information about permissions, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/redshift-iam-access-control-identity-based.html#redshift-policy-resources.getclustercredentials-resources">Resource Policies for GetClusterCredentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If the <code>DbGroups</code> parameter is specified, the IAM policy must allow the <code>redshift:JoinGroup</code> action with access to the listed <code>dbgroups</code>. </p> <p>In addition, if the <code>AutoCreate</code> parameter is set to <code>True</code>, then the policy must include the <code>redshift:CreateClusterUser</code> privilege.</p> <p>If the <code>DbName</code> parameter is specified, the IAM policy must allow access to the resource <code>dbname</code> for the specified database name. </p>
     fn get_cluster_credentials(
         &self,
         input: GetClusterCredentialsMessage,
     ) -> RusotoFuture<ClusterCredentials, GetClusterCredentialsError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetClusterCredentials");
-        params.put("Version", "2012-12-01");
-        GetClusterCredentialsMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetClusterCredentialsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterCredentials::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterCredentialsDeserializer::deserialize(
-                        "GetClusterCredentialsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_cluster_credentials(input)
     }
-
-    /// <p>Returns an array of DC2 ReservedNodeOfferings that matches the payment type, term, and usage price of the given DC1 reserved node.</p>
     fn get_reserved_node_exchange_offerings(
         &self,
         input: GetReservedNodeExchangeOfferingsInputMessage,
@@ -19503,1009 +19796,8620 @@ impl Redshift for RedshiftClient {
         GetReservedNodeExchangeOfferingsOutputMessage,
         GetReservedNodeExchangeOfferingsError,
     > {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetReservedNodeExchangeOfferings");
-        params.put("Version", "2012-12-01");
-        GetReservedNodeExchangeOfferingsInputMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetReservedNodeExchangeOfferingsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetReservedNodeExchangeOfferingsOutputMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        GetReservedNodeExchangeOfferingsOutputMessageDeserializer::deserialize(
-                            "GetReservedNodeExchangeOfferingsResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_reserved_node_exchange_offerings(input)
     }
-
-    /// <p>Modifies the settings for a cluster. For example, you can add another security or parameter group, update the preferred maintenance window, or change the master user password. Resetting a cluster password or modifying the security groups associated with a cluster do not need a reboot. However, modifying a parameter group requires a reboot for parameters to take effect. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>You can also change node type and the number of nodes to scale up or down the cluster. When resizing a cluster, you must specify both the number of nodes and the node type even if one of the parameters does not change.</p>
     fn modify_cluster(
         &self,
         input: ModifyClusterMessage,
     ) -> RusotoFuture<ModifyClusterResult, ModifyClusterError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyCluster");
-        params.put("Version", "2012-12-01");
-        ModifyClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyClusterResultDeserializer::deserialize(
-                        "ModifyClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster(input)
     }
-
-    /// <p>Modifies the database revision of a cluster. The database revision is a unique revision of the database running in a cluster.</p>
     fn modify_cluster_db_revision(
         &self,
         input: ModifyClusterDbRevisionMessage,
     ) -> RusotoFuture<ModifyClusterDbRevisionResult, ModifyClusterDbRevisionError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterDbRevision");
-        params.put("Version", "2012-12-01");
-        ModifyClusterDbRevisionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterDbRevisionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyClusterDbRevisionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyClusterDbRevisionResultDeserializer::deserialize(
-                        "ModifyClusterDbRevisionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster_db_revision(input)
     }
-
-    /// <p>Modifies the list of AWS Identity and Access Management (IAM) roles that can be used by the cluster to access other AWS services.</p> <p>A cluster can have up to 10 IAM roles associated at any time.</p>
     fn modify_cluster_iam_roles(
         &self,
         input: ModifyClusterIamRolesMessage,
     ) -> RusotoFuture<ModifyClusterIamRolesResult, ModifyClusterIamRolesError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterIamRoles");
-        params.put("Version", "2012-12-01");
-        ModifyClusterIamRolesMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterIamRolesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyClusterIamRolesResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyClusterIamRolesResultDeserializer::deserialize(
-                        "ModifyClusterIamRolesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster_iam_roles(input)
     }
-
-    /// <p>Modifies the maintenance settings of a cluster. For example, you can defer a maintenance window. You can also update or cancel a deferment. </p>
     fn modify_cluster_maintenance(
         &self,
         input: ModifyClusterMaintenanceMessage,
     ) -> RusotoFuture<ModifyClusterMaintenanceResult, ModifyClusterMaintenanceError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterMaintenance");
-        params.put("Version", "2012-12-01");
-        ModifyClusterMaintenanceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterMaintenanceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyClusterMaintenanceResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyClusterMaintenanceResultDeserializer::deserialize(
-                        "ModifyClusterMaintenanceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster_maintenance(input)
     }
-
-    /// <p>Modifies the parameters of a parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn modify_cluster_parameter_group(
         &self,
         input: ModifyClusterParameterGroupMessage,
     ) -> RusotoFuture<ClusterParameterGroupNameMessage, ModifyClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterParameterGroup");
-        params.put("Version", "2012-12-01");
-        ModifyClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterParameterGroupNameMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterParameterGroupNameMessageDeserializer::deserialize(
-                        "ModifyClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster_parameter_group(input)
     }
-
-    /// <p>Modifies the settings for a snapshot.</p>
     fn modify_cluster_snapshot(
         &self,
         input: ModifyClusterSnapshotMessage,
     ) -> RusotoFuture<ModifyClusterSnapshotResult, ModifyClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterSnapshot");
-        params.put("Version", "2012-12-01");
-        ModifyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyClusterSnapshotResultDeserializer::deserialize(
-                        "ModifyClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster_snapshot(input)
     }
-
-    /// <p>Modifies a snapshot schedule for a cluster.</p>
     fn modify_cluster_snapshot_schedule(
         &self,
         input: ModifyClusterSnapshotScheduleMessage,
     ) -> RusotoFuture<(), ModifyClusterSnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterSnapshotSchedule");
-        params.put("Version", "2012-12-01");
-        ModifyClusterSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterSnapshotScheduleError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).modify_cluster_snapshot_schedule(input)
     }
-
-    /// <p>Modifies a cluster subnet group to include the specified list of VPC subnets. The operation replaces the existing list of subnets with the new list of subnets.</p>
     fn modify_cluster_subnet_group(
         &self,
         input: ModifyClusterSubnetGroupMessage,
     ) -> RusotoFuture<ModifyClusterSubnetGroupResult, ModifyClusterSubnetGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyClusterSubnetGroup");
-        params.put("Version", "2012-12-01");
-        ModifyClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyClusterSubnetGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyClusterSubnetGroupResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyClusterSubnetGroupResultDeserializer::deserialize(
-                        "ModifyClusterSubnetGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_cluster_subnet_group(input)
     }
-
-    /// <p>Modifies an existing Amazon Redshift event notification subscription.</p>
     fn modify_event_subscription(
         &self,
         input: ModifyEventSubscriptionMessage,
     ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifyEventSubscription");
-        params.put("Version", "2012-12-01");
-        ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyEventSubscriptionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifyEventSubscriptionResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifyEventSubscriptionResultDeserializer::deserialize(
-                        "ModifyEventSubscriptionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_event_subscription(input)
     }
-
-    /// <p>Modifies the number of days to retain snapshots in the destination AWS Region after they are copied from the source AWS Region. By default, this operation only changes the retention period of copied automated snapshots. The retention periods for both new and existing copied automated snapshots are updated with the new retention period. You can set the manual option to change only the retention periods of copied manual snapshots. If you set this option, only newly copied manual snapshots have the new retention period. </p>
     fn modify_snapshot_copy_retention_period(
         &self,
         input: ModifySnapshotCopyRetentionPeriodMessage,
     ) -> RusotoFuture<ModifySnapshotCopyRetentionPeriodResult, ModifySnapshotCopyRetentionPeriodError>
     {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifySnapshotCopyRetentionPeriod");
-        params.put("Version", "2012-12-01");
-        ModifySnapshotCopyRetentionPeriodMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifySnapshotCopyRetentionPeriodError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ModifySnapshotCopyRetentionPeriodResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ModifySnapshotCopyRetentionPeriodResultDeserializer::deserialize(
-                        "ModifySnapshotCopyRetentionPeriodResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_snapshot_copy_retention_period(input)
     }
-
-    /// <p>Modifies a snapshot schedule. Any schedule associated with a cluster is modified asynchronously.</p>
     fn modify_snapshot_schedule(
         &self,
         input: ModifySnapshotScheduleMessage,
     ) -> RusotoFuture<SnapshotSchedule, ModifySnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ModifySnapshotSchedule");
-        params.put("Version", "2012-12-01");
-        ModifySnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifySnapshotScheduleError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SnapshotSchedule::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SnapshotScheduleDeserializer::deserialize(
-                        "ModifySnapshotScheduleResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).modify_snapshot_schedule(input)
     }
-
-    /// <p>Allows you to purchase reserved nodes. Amazon Redshift offers a predefined set of reserved node offerings. You can purchase one or more of the offerings. You can call the <a>DescribeReservedNodeOfferings</a> API to obtain the available reserved node offerings. You can call this API by providing a specific reserved node offering and the number of nodes you want to reserve. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn purchase_reserved_node_offering(
         &self,
         input: PurchaseReservedNodeOfferingMessage,
     ) -> RusotoFuture<PurchaseReservedNodeOfferingResult, PurchaseReservedNodeOfferingError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PurchaseReservedNodeOffering");
-        params.put("Version", "2012-12-01");
-        PurchaseReservedNodeOfferingMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PurchaseReservedNodeOfferingError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PurchaseReservedNodeOfferingResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PurchaseReservedNodeOfferingResultDeserializer::deserialize(
-                        "PurchaseReservedNodeOfferingResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).purchase_reserved_node_offering(input)
     }
-
-    /// <p>Reboots a cluster. This action is taken as soon as possible. It results in a momentary outage to the cluster, during which the cluster status is set to <code>rebooting</code>. A cluster event is created when the reboot is completed. Any pending cluster modifications (see <a>ModifyCluster</a>) are applied at this reboot. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
     fn reboot_cluster(
         &self,
         input: RebootClusterMessage,
     ) -> RusotoFuture<RebootClusterResult, RebootClusterError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RebootCluster");
-        params.put("Version", "2012-12-01");
-        RebootClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebootClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RebootClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RebootClusterResultDeserializer::deserialize(
-                        "RebootClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).reboot_cluster(input)
     }
-
-    /// <p>Sets one or more parameters of the specified parameter group to their default values and sets the source values of the parameters to "engine-default". To reset the entire parameter group specify the <i>ResetAllParameters</i> parameter. For parameter changes to take effect you must reboot any associated clusters. </p>
     fn reset_cluster_parameter_group(
         &self,
         input: ResetClusterParameterGroupMessage,
     ) -> RusotoFuture<ClusterParameterGroupNameMessage, ResetClusterParameterGroupError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ResetClusterParameterGroup");
-        params.put("Version", "2012-12-01");
-        ResetClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ResetClusterParameterGroupError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ClusterParameterGroupNameMessage::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ClusterParameterGroupNameMessageDeserializer::deserialize(
-                        "ResetClusterParameterGroupResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).reset_cluster_parameter_group(input)
     }
-
-    /// <p><p>Changes the size of the cluster. You can change the cluster&#39;s type, or change the number or type of nodes. The default behavior is to use the elastic resize method. With an elastic resize, your cluster is available for read and write operations more quickly than with the classic resize method. </p> <p>Elastic resize operations have the following restrictions:</p> <ul> <li> <p>You can only resize clusters of the following types:</p> <ul> <li> <p>dc2.large</p> </li> <li> <p>dc2.8xlarge</p> </li> <li> <p>ds2.xlarge</p> </li> <li> <p>ds2.8xlarge</p> </li> </ul> </li> <li> <p>The type of nodes that you add must match the node type for the cluster.</p> </li> </ul></p>
     fn resize_cluster(
         &self,
         input: ResizeClusterMessage,
     ) -> RusotoFuture<ResizeClusterResult, ResizeClusterError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ResizeCluster");
-        params.put("Version", "2012-12-01");
-        ResizeClusterMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResizeClusterError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ResizeClusterResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ResizeClusterResultDeserializer::deserialize(
-                        "ResizeClusterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).resize_cluster(input)
+    }
+    fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreFromClusterSnapshotResult, RestoreFromClusterSnapshotError> {
+        (**self).restore_from_cluster_snapshot(input)
+    }
+    fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreTableFromClusterSnapshotResult, RestoreTableFromClusterSnapshotError>
+    {
+        (**self).restore_table_from_cluster_snapshot(input)
+    }
+    fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeClusterSecurityGroupIngressResult, RevokeClusterSecurityGroupIngressError>
+    {
+        (**self).revoke_cluster_security_group_ingress(input)
+    }
+    fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> RusotoFuture<RevokeSnapshotAccessResult, RevokeSnapshotAccessError> {
+        (**self).revoke_snapshot_access(input)
+    }
+    fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError> {
+        (**self).rotate_encryption_key(input)
+    }
+}
+impl<T: Redshift + ?Sized> Redshift for Box<T> {
+    fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> RusotoFuture<AcceptReservedNodeExchangeOutputMessage, AcceptReservedNodeExchangeError>
+    {
+        (**self).accept_reserved_node_exchange(input)
+    }
+    fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeClusterSecurityGroupIngressResult,
+        AuthorizeClusterSecurityGroupIngressError,
+    > {
+        (**self).authorize_cluster_security_group_ingress(input)
+    }
+    fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> RusotoFuture<AuthorizeSnapshotAccessResult, AuthorizeSnapshotAccessError> {
+        (**self).authorize_snapshot_access(input)
+    }
+    fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> RusotoFuture<BatchDeleteClusterSnapshotsResult, BatchDeleteClusterSnapshotsError> {
+        (**self).batch_delete_cluster_snapshots(input)
+    }
+    fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> RusotoFuture<BatchModifyClusterSnapshotsOutputMessage, BatchModifyClusterSnapshotsError>
+    {
+        (**self).batch_modify_cluster_snapshots(input)
+    }
+    fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, CancelResizeError> {
+        (**self).cancel_resize(input)
+    }
+    fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyClusterSnapshotResult, CopyClusterSnapshotError> {
+        (**self).copy_cluster_snapshot(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateClusterParameterGroupResult, CreateClusterParameterGroupError> {
+        (**self).create_cluster_parameter_group(input)
+    }
+    fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> RusotoFuture<CreateClusterSecurityGroupResult, CreateClusterSecurityGroupError> {
+        (**self).create_cluster_security_group(input)
+    }
+    fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateClusterSnapshotResult, CreateClusterSnapshotError> {
+        (**self).create_cluster_snapshot(input)
+    }
+    fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> RusotoFuture<CreateClusterSubnetGroupResult, CreateClusterSubnetGroupError> {
+        (**self).create_cluster_subnet_group(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> RusotoFuture<CreateHsmClientCertificateResult, CreateHsmClientCertificateError> {
+        (**self).create_hsm_client_certificate(input)
+    }
+    fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> RusotoFuture<CreateHsmConfigurationResult, CreateHsmConfigurationError> {
+        (**self).create_hsm_configuration(input)
+    }
+    fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<CreateSnapshotCopyGrantResult, CreateSnapshotCopyGrantError> {
+        (**self).create_snapshot_copy_grant(input)
+    }
+    fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, CreateSnapshotScheduleError> {
+        (**self).create_snapshot_schedule(input)
+    }
+    fn create_tags(&self, input: CreateTagsMessage) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> RusotoFuture<DeleteClusterResult, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterParameterGroupError> {
+        (**self).delete_cluster_parameter_group(input)
+    }
+    fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSecurityGroupError> {
+        (**self).delete_cluster_security_group(input)
+    }
+    fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteClusterSnapshotResult, DeleteClusterSnapshotError> {
+        (**self).delete_cluster_snapshot(input)
+    }
+    fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSubnetGroupError> {
+        (**self).delete_cluster_subnet_group(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<(), DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> RusotoFuture<(), DeleteHsmClientCertificateError> {
+        (**self).delete_hsm_client_certificate(input)
+    }
+    fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteHsmConfigurationError> {
+        (**self).delete_hsm_configuration(input)
+    }
+    fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotCopyGrantError> {
+        (**self).delete_snapshot_copy_grant(input)
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotScheduleError> {
+        (**self).delete_snapshot_schedule(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsMessage) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> RusotoFuture<AccountAttributeList, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes(input)
+    }
+    fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> RusotoFuture<ClusterDbRevisionsMessage, DescribeClusterDbRevisionsError> {
+        (**self).describe_cluster_db_revisions(input)
+    }
+    fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> RusotoFuture<ClusterParameterGroupsMessage, DescribeClusterParameterGroupsError> {
+        (**self).describe_cluster_parameter_groups(input)
+    }
+    fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> RusotoFuture<ClusterParameterGroupDetails, DescribeClusterParametersError> {
+        (**self).describe_cluster_parameters(input)
+    }
+    fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> RusotoFuture<ClusterSecurityGroupMessage, DescribeClusterSecurityGroupsError> {
+        (**self).describe_cluster_security_groups(input)
+    }
+    fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> RusotoFuture<SnapshotMessage, DescribeClusterSnapshotsError> {
+        (**self).describe_cluster_snapshots(input)
+    }
+    fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> RusotoFuture<ClusterSubnetGroupMessage, DescribeClusterSubnetGroupsError> {
+        (**self).describe_cluster_subnet_groups(input)
+    }
+    fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> RusotoFuture<TrackListMessage, DescribeClusterTracksError> {
+        (**self).describe_cluster_tracks(input)
+    }
+    fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> RusotoFuture<ClusterVersionsMessage, DescribeClusterVersionsError> {
+        (**self).describe_cluster_versions(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> RusotoFuture<ClustersMessage, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> RusotoFuture<DescribeDefaultClusterParametersResult, DescribeDefaultClusterParametersError>
+    {
+        (**self).describe_default_cluster_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> RusotoFuture<HsmClientCertificateMessage, DescribeHsmClientCertificatesError> {
+        (**self).describe_hsm_client_certificates(input)
+    }
+    fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> RusotoFuture<HsmConfigurationMessage, DescribeHsmConfigurationsError> {
+        (**self).describe_hsm_configurations(input)
+    }
+    fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> RusotoFuture<LoggingStatus, DescribeLoggingStatusError> {
+        (**self).describe_logging_status(input)
+    }
+    fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> RusotoFuture<OrderableClusterOptionsMessage, DescribeOrderableClusterOptionsError> {
+        (**self).describe_orderable_cluster_options(input)
+    }
+    fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> RusotoFuture<ReservedNodeOfferingsMessage, DescribeReservedNodeOfferingsError> {
+        (**self).describe_reserved_node_offerings(input)
+    }
+    fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> RusotoFuture<ReservedNodesMessage, DescribeReservedNodesError> {
+        (**self).describe_reserved_nodes(input)
+    }
+    fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, DescribeResizeError> {
+        (**self).describe_resize(input)
+    }
+    fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> RusotoFuture<SnapshotCopyGrantMessage, DescribeSnapshotCopyGrantsError> {
+        (**self).describe_snapshot_copy_grants(input)
+    }
+    fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> RusotoFuture<DescribeSnapshotSchedulesOutputMessage, DescribeSnapshotSchedulesError> {
+        (**self).describe_snapshot_schedules(input)
+    }
+    fn describe_storage(&self) -> RusotoFuture<CustomerStorageMessage, DescribeStorageError> {
+        (**self).describe_storage()
+    }
+    fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> RusotoFuture<TableRestoreStatusMessage, DescribeTableRestoreStatusError> {
+        (**self).describe_table_restore_status(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> RusotoFuture<TaggedResourceListMessage, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, DisableLoggingError> {
+        (**self).disable_logging(input)
+    }
+    fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> RusotoFuture<DisableSnapshotCopyResult, DisableSnapshotCopyError> {
+        (**self).disable_snapshot_copy(input)
+    }
+    fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, EnableLoggingError> {
+        (**self).enable_logging(input)
+    }
+    fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> RusotoFuture<EnableSnapshotCopyResult, EnableSnapshotCopyError> {
+        (**self).enable_snapshot_copy(input)
+    }
+    fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> RusotoFuture<ClusterCredentials, GetClusterCredentialsError> {
+        (**self).get_cluster_credentials(input)
+    }
+    fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> RusotoFuture<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        (**self).get_reserved_node_exchange_offerings(input)
+    }
+    fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> RusotoFuture<ModifyClusterResult, ModifyClusterError> {
+        (**self).modify_cluster(input)
+    }
+    fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> RusotoFuture<ModifyClusterDbRevisionResult, ModifyClusterDbRevisionError> {
+        (**self).modify_cluster_db_revision(input)
+    }
+    fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> RusotoFuture<ModifyClusterIamRolesResult, ModifyClusterIamRolesError> {
+        (**self).modify_cluster_iam_roles(input)
+    }
+    fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> RusotoFuture<ModifyClusterMaintenanceResult, ModifyClusterMaintenanceError> {
+        (**self).modify_cluster_maintenance(input)
+    }
+    fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ModifyClusterParameterGroupError> {
+        (**self).modify_cluster_parameter_group(input)
+    }
+    fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> RusotoFuture<ModifyClusterSnapshotResult, ModifyClusterSnapshotError> {
+        (**self).modify_cluster_snapshot(input)
+    }
+    fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), ModifyClusterSnapshotScheduleError> {
+        (**self).modify_cluster_snapshot_schedule(input)
+    }
+    fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyClusterSubnetGroupResult, ModifyClusterSubnetGroupError> {
+        (**self).modify_cluster_subnet_group(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> RusotoFuture<ModifySnapshotCopyRetentionPeriodResult, ModifySnapshotCopyRetentionPeriodError>
+    {
+        (**self).modify_snapshot_copy_retention_period(input)
+    }
+    fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, ModifySnapshotScheduleError> {
+        (**self).modify_snapshot_schedule(input)
+    }
+    fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> RusotoFuture<PurchaseReservedNodeOfferingResult, PurchaseReservedNodeOfferingError> {
+        (**self).purchase_reserved_node_offering(input)
+    }
+    fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> RusotoFuture<RebootClusterResult, RebootClusterError> {
+        (**self).reboot_cluster(input)
+    }
+    fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ResetClusterParameterGroupError> {
+        (**self).reset_cluster_parameter_group(input)
+    }
+    fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> RusotoFuture<ResizeClusterResult, ResizeClusterError> {
+        (**self).resize_cluster(input)
+    }
+    fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreFromClusterSnapshotResult, RestoreFromClusterSnapshotError> {
+        (**self).restore_from_cluster_snapshot(input)
+    }
+    fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreTableFromClusterSnapshotResult, RestoreTableFromClusterSnapshotError>
+    {
+        (**self).restore_table_from_cluster_snapshot(input)
+    }
+    fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeClusterSecurityGroupIngressResult, RevokeClusterSecurityGroupIngressError>
+    {
+        (**self).revoke_cluster_security_group_ingress(input)
+    }
+    fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> RusotoFuture<RevokeSnapshotAccessResult, RevokeSnapshotAccessError> {
+        (**self).revoke_snapshot_access(input)
+    }
+    fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError> {
+        (**self).rotate_encryption_key(input)
+    }
+}
+impl<T: Redshift + ?Sized> Redshift for ::std::sync::Arc<T> {
+    fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> RusotoFuture<AcceptReservedNodeExchangeOutputMessage, AcceptReservedNodeExchangeError>
+    {
+        (**self).accept_reserved_node_exchange(input)
+    }
+    fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeClusterSecurityGroupIngressResult,
+        AuthorizeClusterSecurityGroupIngressError,
+    > {
+        (**self).authorize_cluster_security_group_ingress(input)
+    }
+    fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> RusotoFuture<AuthorizeSnapshotAccessResult, AuthorizeSnapshotAccessError> {
+        (**self).authorize_snapshot_access(input)
+    }
+    fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> RusotoFuture<BatchDeleteClusterSnapshotsResult, BatchDeleteClusterSnapshotsError> {
+        (**self).batch_delete_cluster_snapshots(input)
+    }
+    fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> RusotoFuture<BatchModifyClusterSnapshotsOutputMessage, BatchModifyClusterSnapshotsError>
+    {
+        (**self).batch_modify_cluster_snapshots(input)
+    }
+    fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, CancelResizeError> {
+        (**self).cancel_resize(input)
+    }
+    fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyClusterSnapshotResult, CopyClusterSnapshotError> {
+        (**self).copy_cluster_snapshot(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateClusterParameterGroupResult, CreateClusterParameterGroupError> {
+        (**self).create_cluster_parameter_group(input)
+    }
+    fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> RusotoFuture<CreateClusterSecurityGroupResult, CreateClusterSecurityGroupError> {
+        (**self).create_cluster_security_group(input)
+    }
+    fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateClusterSnapshotResult, CreateClusterSnapshotError> {
+        (**self).create_cluster_snapshot(input)
+    }
+    fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> RusotoFuture<CreateClusterSubnetGroupResult, CreateClusterSubnetGroupError> {
+        (**self).create_cluster_subnet_group(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> RusotoFuture<CreateHsmClientCertificateResult, CreateHsmClientCertificateError> {
+        (**self).create_hsm_client_certificate(input)
+    }
+    fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> RusotoFuture<CreateHsmConfigurationResult, CreateHsmConfigurationError> {
+        (**self).create_hsm_configuration(input)
+    }
+    fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<CreateSnapshotCopyGrantResult, CreateSnapshotCopyGrantError> {
+        (**self).create_snapshot_copy_grant(input)
+    }
+    fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, CreateSnapshotScheduleError> {
+        (**self).create_snapshot_schedule(input)
+    }
+    fn create_tags(&self, input: CreateTagsMessage) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> RusotoFuture<DeleteClusterResult, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterParameterGroupError> {
+        (**self).delete_cluster_parameter_group(input)
+    }
+    fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSecurityGroupError> {
+        (**self).delete_cluster_security_group(input)
+    }
+    fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteClusterSnapshotResult, DeleteClusterSnapshotError> {
+        (**self).delete_cluster_snapshot(input)
+    }
+    fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSubnetGroupError> {
+        (**self).delete_cluster_subnet_group(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<(), DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> RusotoFuture<(), DeleteHsmClientCertificateError> {
+        (**self).delete_hsm_client_certificate(input)
+    }
+    fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteHsmConfigurationError> {
+        (**self).delete_hsm_configuration(input)
+    }
+    fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotCopyGrantError> {
+        (**self).delete_snapshot_copy_grant(input)
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotScheduleError> {
+        (**self).delete_snapshot_schedule(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsMessage) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> RusotoFuture<AccountAttributeList, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes(input)
+    }
+    fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> RusotoFuture<ClusterDbRevisionsMessage, DescribeClusterDbRevisionsError> {
+        (**self).describe_cluster_db_revisions(input)
+    }
+    fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> RusotoFuture<ClusterParameterGroupsMessage, DescribeClusterParameterGroupsError> {
+        (**self).describe_cluster_parameter_groups(input)
+    }
+    fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> RusotoFuture<ClusterParameterGroupDetails, DescribeClusterParametersError> {
+        (**self).describe_cluster_parameters(input)
+    }
+    fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> RusotoFuture<ClusterSecurityGroupMessage, DescribeClusterSecurityGroupsError> {
+        (**self).describe_cluster_security_groups(input)
+    }
+    fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> RusotoFuture<SnapshotMessage, DescribeClusterSnapshotsError> {
+        (**self).describe_cluster_snapshots(input)
+    }
+    fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> RusotoFuture<ClusterSubnetGroupMessage, DescribeClusterSubnetGroupsError> {
+        (**self).describe_cluster_subnet_groups(input)
+    }
+    fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> RusotoFuture<TrackListMessage, DescribeClusterTracksError> {
+        (**self).describe_cluster_tracks(input)
+    }
+    fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> RusotoFuture<ClusterVersionsMessage, DescribeClusterVersionsError> {
+        (**self).describe_cluster_versions(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> RusotoFuture<ClustersMessage, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> RusotoFuture<DescribeDefaultClusterParametersResult, DescribeDefaultClusterParametersError>
+    {
+        (**self).describe_default_cluster_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> RusotoFuture<HsmClientCertificateMessage, DescribeHsmClientCertificatesError> {
+        (**self).describe_hsm_client_certificates(input)
+    }
+    fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> RusotoFuture<HsmConfigurationMessage, DescribeHsmConfigurationsError> {
+        (**self).describe_hsm_configurations(input)
+    }
+    fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> RusotoFuture<LoggingStatus, DescribeLoggingStatusError> {
+        (**self).describe_logging_status(input)
+    }
+    fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> RusotoFuture<OrderableClusterOptionsMessage, DescribeOrderableClusterOptionsError> {
+        (**self).describe_orderable_cluster_options(input)
+    }
+    fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> RusotoFuture<ReservedNodeOfferingsMessage, DescribeReservedNodeOfferingsError> {
+        (**self).describe_reserved_node_offerings(input)
+    }
+    fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> RusotoFuture<ReservedNodesMessage, DescribeReservedNodesError> {
+        (**self).describe_reserved_nodes(input)
+    }
+    fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, DescribeResizeError> {
+        (**self).describe_resize(input)
+    }
+    fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> RusotoFuture<SnapshotCopyGrantMessage, DescribeSnapshotCopyGrantsError> {
+        (**self).describe_snapshot_copy_grants(input)
+    }
+    fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> RusotoFuture<DescribeSnapshotSchedulesOutputMessage, DescribeSnapshotSchedulesError> {
+        (**self).describe_snapshot_schedules(input)
+    }
+    fn describe_storage(&self) -> RusotoFuture<CustomerStorageMessage, DescribeStorageError> {
+        (**self).describe_storage()
+    }
+    fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> RusotoFuture<TableRestoreStatusMessage, DescribeTableRestoreStatusError> {
+        (**self).describe_table_restore_status(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> RusotoFuture<TaggedResourceListMessage, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, DisableLoggingError> {
+        (**self).disable_logging(input)
+    }
+    fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> RusotoFuture<DisableSnapshotCopyResult, DisableSnapshotCopyError> {
+        (**self).disable_snapshot_copy(input)
+    }
+    fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, EnableLoggingError> {
+        (**self).enable_logging(input)
+    }
+    fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> RusotoFuture<EnableSnapshotCopyResult, EnableSnapshotCopyError> {
+        (**self).enable_snapshot_copy(input)
+    }
+    fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> RusotoFuture<ClusterCredentials, GetClusterCredentialsError> {
+        (**self).get_cluster_credentials(input)
+    }
+    fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> RusotoFuture<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        (**self).get_reserved_node_exchange_offerings(input)
+    }
+    fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> RusotoFuture<ModifyClusterResult, ModifyClusterError> {
+        (**self).modify_cluster(input)
+    }
+    fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> RusotoFuture<ModifyClusterDbRevisionResult, ModifyClusterDbRevisionError> {
+        (**self).modify_cluster_db_revision(input)
+    }
+    fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> RusotoFuture<ModifyClusterIamRolesResult, ModifyClusterIamRolesError> {
+        (**self).modify_cluster_iam_roles(input)
+    }
+    fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> RusotoFuture<ModifyClusterMaintenanceResult, ModifyClusterMaintenanceError> {
+        (**self).modify_cluster_maintenance(input)
+    }
+    fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ModifyClusterParameterGroupError> {
+        (**self).modify_cluster_parameter_group(input)
+    }
+    fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> RusotoFuture<ModifyClusterSnapshotResult, ModifyClusterSnapshotError> {
+        (**self).modify_cluster_snapshot(input)
+    }
+    fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), ModifyClusterSnapshotScheduleError> {
+        (**self).modify_cluster_snapshot_schedule(input)
+    }
+    fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyClusterSubnetGroupResult, ModifyClusterSubnetGroupError> {
+        (**self).modify_cluster_subnet_group(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> RusotoFuture<ModifySnapshotCopyRetentionPeriodResult, ModifySnapshotCopyRetentionPeriodError>
+    {
+        (**self).modify_snapshot_copy_retention_period(input)
+    }
+    fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, ModifySnapshotScheduleError> {
+        (**self).modify_snapshot_schedule(input)
+    }
+    fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> RusotoFuture<PurchaseReservedNodeOfferingResult, PurchaseReservedNodeOfferingError> {
+        (**self).purchase_reserved_node_offering(input)
+    }
+    fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> RusotoFuture<RebootClusterResult, RebootClusterError> {
+        (**self).reboot_cluster(input)
+    }
+    fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ResetClusterParameterGroupError> {
+        (**self).reset_cluster_parameter_group(input)
+    }
+    fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> RusotoFuture<ResizeClusterResult, ResizeClusterError> {
+        (**self).resize_cluster(input)
+    }
+    fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreFromClusterSnapshotResult, RestoreFromClusterSnapshotError> {
+        (**self).restore_from_cluster_snapshot(input)
+    }
+    fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreTableFromClusterSnapshotResult, RestoreTableFromClusterSnapshotError>
+    {
+        (**self).restore_table_from_cluster_snapshot(input)
+    }
+    fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeClusterSecurityGroupIngressResult, RevokeClusterSecurityGroupIngressError>
+    {
+        (**self).revoke_cluster_security_group_ingress(input)
+    }
+    fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> RusotoFuture<RevokeSnapshotAccessResult, RevokeSnapshotAccessError> {
+        (**self).revoke_snapshot_access(input)
+    }
+    fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError> {
+        (**self).rotate_encryption_key(input)
+    }
+}
+/// A client for the Amazon Redshift API.
+#[derive(Clone)]
+pub struct RedshiftClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl RedshiftClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RedshiftClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RedshiftClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
+        RedshiftClient { client, region }
+    }
+}
+
+impl Redshift for RedshiftClient {
+    /// <p>Exchanges a DC1 Reserved Node for a DC2 Reserved Node with no changes to the configuration (term, payment type, or number of nodes) and no additional costs. </p>
+    fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> RusotoFuture<AcceptReservedNodeExchangeOutputMessage, AcceptReservedNodeExchangeError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AcceptReservedNodeExchange");
+        let mut params = Params::new();
+
+        params.put("Action", "AcceptReservedNodeExchange");
+        params.put("Version", "2012-12-01");
+        AcceptReservedNodeExchangeInputMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AcceptReservedNodeExchangeError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AcceptReservedNodeExchangeOutputMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AcceptReservedNodeExchangeOutputMessageDeserializer::deserialize(
+                        "AcceptReservedNodeExchangeResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AcceptReservedNodeExchange", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds an inbound (ingress) rule to an Amazon Redshift security group. Depending on whether the application accessing your cluster is running on the Internet or an Amazon EC2 instance, you can authorize inbound access to either a Classless Interdomain Routing (CIDR)/Internet Protocol (IP) range or to an Amazon EC2 security group. You can add as many as 20 ingress rules to an Amazon Redshift security group.</p> <p>If you authorize access to an Amazon EC2 security group, specify <i>EC2SecurityGroupName</i> and <i>EC2SecurityGroupOwnerId</i>. The Amazon EC2 security group and Amazon Redshift cluster must be in the same AWS Region. </p> <p>If you authorize access to a CIDR/IP address range, specify <i>CIDRIP</i>. For an overview of CIDR blocks, see the Wikipedia article on <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>. </p> <p>You must also associate the security group with a cluster so that clients running on these IP addresses or the EC2 instance are authorized to connect to the cluster. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Working with Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeClusterSecurityGroupIngressResult,
+        AuthorizeClusterSecurityGroupIngressError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeClusterSecurityGroupIngress");
+        let mut params = Params::new();
+
+        params.put("Action", "AuthorizeClusterSecurityGroupIngress");
+        params.put("Version", "2012-12-01");
+        AuthorizeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AuthorizeClusterSecurityGroupIngressError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AuthorizeClusterSecurityGroupIngressResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AuthorizeClusterSecurityGroupIngressResultDeserializer::deserialize(
+                        "AuthorizeClusterSecurityGroupIngressResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "AuthorizeClusterSecurityGroupIngress",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Authorizes the specified AWS customer account to restore the specified snapshot.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> RusotoFuture<AuthorizeSnapshotAccessResult, AuthorizeSnapshotAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeSnapshotAccess");
+        let mut params = Params::new();
+
+        params.put("Action", "AuthorizeSnapshotAccess");
+        params.put("Version", "2012-12-01");
+        AuthorizeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AuthorizeSnapshotAccessError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AuthorizeSnapshotAccessResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AuthorizeSnapshotAccessResultDeserializer::deserialize(
+                        "AuthorizeSnapshotAccessResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AuthorizeSnapshotAccess", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a set of cluster snapshots.</p>
+    fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> RusotoFuture<BatchDeleteClusterSnapshotsResult, BatchDeleteClusterSnapshotsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchDeleteClusterSnapshots");
+        let mut params = Params::new();
+
+        params.put("Action", "BatchDeleteClusterSnapshots");
+        params.put("Version", "2012-12-01");
+        BatchDeleteClusterSnapshotsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchDeleteClusterSnapshotsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = BatchDeleteClusterSnapshotsResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = BatchDeleteClusterSnapshotsResultDeserializer::deserialize(
+                        "BatchDeleteClusterSnapshotsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("BatchDeleteClusterSnapshots", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the settings for a list of snapshots.</p>
+    fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> RusotoFuture<BatchModifyClusterSnapshotsOutputMessage, BatchModifyClusterSnapshotsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchModifyClusterSnapshots");
+        let mut params = Params::new();
+
+        params.put("Action", "BatchModifyClusterSnapshots");
+        params.put("Version", "2012-12-01");
+        BatchModifyClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(BatchModifyClusterSnapshotsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = BatchModifyClusterSnapshotsOutputMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = BatchModifyClusterSnapshotsOutputMessageDeserializer::deserialize(
+                        "BatchModifyClusterSnapshotsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("BatchModifyClusterSnapshots", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Cancels a resize operation.</p>
+    fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, CancelResizeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CancelResize");
+        let mut params = Params::new();
+
+        params.put("Action", "CancelResize");
+        params.put("Version", "2012-12-01");
+        CancelResizeMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelResizeError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ResizeProgressMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ResizeProgressMessageDeserializer::deserialize(
+                        "CancelResizeResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CancelResize", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Copies the specified automated cluster snapshot to a new manual cluster snapshot. The source must be an automated snapshot and it must be in the available state.</p> <p>When you delete a cluster, Amazon Redshift deletes any automated snapshots of the cluster. Also, when the retention period of the snapshot expires, Amazon Redshift automatically deletes it. If you want to keep an automated snapshot for a longer period, you can make a manual copy of the snapshot. Manual snapshots are retained until you delete them.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyClusterSnapshotResult, CopyClusterSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CopyClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "CopyClusterSnapshot");
+        params.put("Version", "2012-12-01");
+        CopyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CopyClusterSnapshotError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CopyClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CopyClusterSnapshotResultDeserializer::deserialize(
+                        "CopyClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CopyClusterSnapshot", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new cluster.</p> <p>To create a cluster in Virtual Private Cloud (VPC), you must provide a cluster subnet group name. The cluster subnet group identifies the subnets of your VPC that Amazon Redshift uses when creating the cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateCluster");
+        params.put("Version", "2012-12-01");
+        CreateClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateClusterResultDeserializer::deserialize(
+                        "CreateClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an Amazon Redshift parameter group.</p> <p>Creating parameter groups is independent of creating clusters. You can associate a cluster with a parameter group when you create the cluster. You can also associate an existing cluster with a parameter group after the cluster is created by using <a>ModifyCluster</a>. </p> <p>Parameters in the parameter group define specific behavior that applies to the databases you create on the cluster. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateClusterParameterGroupResult, CreateClusterParameterGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateClusterParameterGroup");
+        params.put("Version", "2012-12-01");
+        CreateClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateClusterParameterGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateClusterParameterGroupResultDeserializer::deserialize(
+                        "CreateClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new Amazon Redshift security group. You use security groups to control access to non-VPC clusters.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> RusotoFuture<CreateClusterSecurityGroupResult, CreateClusterSecurityGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSecurityGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateClusterSecurityGroup");
+        params.put("Version", "2012-12-01");
+        CreateClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateClusterSecurityGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateClusterSecurityGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateClusterSecurityGroupResultDeserializer::deserialize(
+                        "CreateClusterSecurityGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateClusterSecurityGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a manual snapshot of the specified cluster. The cluster must be in the <code>available</code> state. </p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateClusterSnapshotResult, CreateClusterSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateClusterSnapshot");
+        params.put("Version", "2012-12-01");
+        CreateClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateClusterSnapshotResultDeserializer::deserialize(
+                        "CreateClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new Amazon Redshift subnet group. You must provide a list of one or more subnets in your existing Amazon Virtual Private Cloud (Amazon VPC) when creating Amazon Redshift subnet group.</p> <p> For information about subnet groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-cluster-subnet-groups.html">Amazon Redshift Cluster Subnet Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> RusotoFuture<CreateClusterSubnetGroupResult, CreateClusterSubnetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateClusterSubnetGroup");
+        params.put("Version", "2012-12-01");
+        CreateClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateClusterSubnetGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateClusterSubnetGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateClusterSubnetGroupResultDeserializer::deserialize(
+                        "CreateClusterSubnetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateClusterSubnetGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an Amazon Redshift event notification subscription. This action requires an ARN (Amazon Resource Name) of an Amazon SNS topic created by either the Amazon Redshift console, the Amazon SNS console, or the Amazon SNS API. To obtain an ARN with Amazon SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the source type, and lists of Amazon Redshift source IDs, event categories, and event severities. Notifications will be sent for all events you want that match those criteria. For example, you can specify source type = cluster, source ID = my-cluster-1 and mycluster2, event categories = Availability, Backup, and severity = ERROR. The subscription will only send notifications for those ERROR events in the Availability and Backup categories for the specified clusters.</p> <p>If you specify both the source type and source IDs, such as source type = cluster and source identifier = my-cluster-1, notifications will be sent for all the cluster events for my-cluster-1. If you specify a source type but do not specify a source identifier, you will receive notice of the events for the objects of that type in your AWS account. If you do not specify either the SourceType nor the SourceIdentifier, you will be notified of events generated from all Amazon Redshift sources belonging to your AWS account. You must specify a source type if you specify a source ID.</p>
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateEventSubscription");
+        params.put("Version", "2012-12-01");
+        CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateEventSubscriptionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateEventSubscriptionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateEventSubscriptionResultDeserializer::deserialize(
+                        "CreateEventSubscriptionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateEventSubscription", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an HSM client certificate that an Amazon Redshift cluster will use to connect to the client's HSM in order to store and retrieve the keys used to encrypt the cluster databases.</p> <p>The command returns a public key, which you must store in the HSM. In addition to creating the HSM certificate, you must create an Amazon Redshift HSM configuration that provides a cluster the information needed to store and use encryption keys in the HSM. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
+    fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> RusotoFuture<CreateHsmClientCertificateResult, CreateHsmClientCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmClientCertificate");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateHsmClientCertificate");
+        params.put("Version", "2012-12-01");
+        CreateHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateHsmClientCertificateError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateHsmClientCertificateResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateHsmClientCertificateResultDeserializer::deserialize(
+                        "CreateHsmClientCertificateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateHsmClientCertificate", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an HSM configuration that contains the information required by an Amazon Redshift cluster to store and use database encryption keys in a Hardware Security Module (HSM). After creating the HSM configuration, you can specify it as a parameter when creating a cluster. The cluster will then store its encryption keys in the HSM.</p> <p>In addition to creating an HSM configuration, you must also create an HSM client certificate. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
+    fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> RusotoFuture<CreateHsmConfigurationResult, CreateHsmConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateHsmConfiguration");
+        params.put("Version", "2012-12-01");
+        CreateHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateHsmConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateHsmConfigurationResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateHsmConfigurationResultDeserializer::deserialize(
+                        "CreateHsmConfigurationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateHsmConfiguration", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a snapshot copy grant that permits Amazon Redshift to use a customer master key (CMK) from AWS Key Management Service (AWS KMS) to encrypt copied snapshots in a destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<CreateSnapshotCopyGrantResult, CreateSnapshotCopyGrantError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotCopyGrant");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateSnapshotCopyGrant");
+        params.put("Version", "2012-12-01");
+        CreateSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSnapshotCopyGrantError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateSnapshotCopyGrantResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateSnapshotCopyGrantResultDeserializer::deserialize(
+                        "CreateSnapshotCopyGrantResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateSnapshotCopyGrant", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new snapshot schedule.</p>
+    fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, CreateSnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotSchedule");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateSnapshotSchedule");
+        params.put("Version", "2012-12-01");
+        CreateSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSnapshotScheduleError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SnapshotSchedule::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SnapshotScheduleDeserializer::deserialize(
+                        "CreateSnapshotScheduleResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateSnapshotSchedule", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds one or more tags to a specified resource.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, you will receive an error and the attempt will fail.</p> <p>If you specify a key that already exists for the resource, the value for that key will be updated with the new value.</p>
+    fn create_tags(&self, input: CreateTagsMessage) -> RusotoFuture<(), CreateTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateTags");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateTags");
+        params.put("Version", "2012-12-01");
+        CreateTagsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes a previously provisioned cluster. A successful response from the web service indicates that the request was received correctly. Use <a>DescribeClusters</a> to monitor the status of the deletion. The delete operation cannot be canceled or reverted once submitted. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you want to shut down the cluster and retain it for future use, set <i>SkipFinalClusterSnapshot</i> to <code>false</code> and specify a name for <i>FinalClusterSnapshotIdentifier</i>. You can later restore this snapshot to resume using the cluster. If a final cluster snapshot is requested, the status of the cluster will be "final-snapshot" while the snapshot is being taken, then it's "deleting" once Amazon Redshift begins deleting the cluster. </p> <p> For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> RusotoFuture<DeleteClusterResult, DeleteClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteCluster");
+        params.put("Version", "2012-12-01");
+        DeleteClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteClusterResultDeserializer::deserialize(
+                        "DeleteClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a specified Amazon Redshift parameter group.</p> <note> <p>You cannot delete a parameter group if it is associated with a cluster.</p> </note></p>
+    fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterParameterGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteClusterParameterGroup");
+        params.put("Version", "2012-12-01");
+        DeleteClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes an Amazon Redshift security group.</p> <note> <p>You cannot delete a security group that is associated with any clusters. You cannot delete the default security group.</p> </note> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSecurityGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSecurityGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteClusterSecurityGroup");
+        params.put("Version", "2012-12-01");
+        DeleteClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteClusterSecurityGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified manual snapshot. The snapshot must be in the <code>available</code> state, with no other users authorized to access the snapshot. </p> <p>Unlike automated snapshots, manual snapshots are retained even after you delete your cluster. Amazon Redshift does not delete your manual snapshots. You must delete manual snapshot explicitly to avoid getting charged. If other accounts are authorized to access the snapshot, you must revoke all of the authorizations before you can delete the snapshot.</p>
+    fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteClusterSnapshotResult, DeleteClusterSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteClusterSnapshot");
+        params.put("Version", "2012-12-01");
+        DeleteClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteClusterSnapshotResultDeserializer::deserialize(
+                        "DeleteClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified cluster subnet group.</p>
+    fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSubnetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteClusterSubnetGroup");
+        params.put("Version", "2012-12-01");
+        DeleteClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteClusterSubnetGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes an Amazon Redshift event notification subscription.</p>
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<(), DeleteEventSubscriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteEventSubscription");
+        params.put("Version", "2012-12-01");
+        DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteEventSubscriptionError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified HSM client certificate.</p>
+    fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> RusotoFuture<(), DeleteHsmClientCertificateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmClientCertificate");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteHsmClientCertificate");
+        params.put("Version", "2012-12-01");
+        DeleteHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteHsmClientCertificateError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified Amazon Redshift HSM configuration.</p>
+    fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteHsmConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmConfiguration");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteHsmConfiguration");
+        params.put("Version", "2012-12-01");
+        DeleteHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteHsmConfigurationError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified snapshot copy grant.</p>
+    fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotCopyGrantError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotCopyGrant");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteSnapshotCopyGrant");
+        params.put("Version", "2012-12-01");
+        DeleteSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSnapshotCopyGrantError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes a snapshot schedule.</p>
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteSnapshotSchedule");
+        params.put("Version", "2012-12-01");
+        DeleteSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSnapshotScheduleError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes a tag or tags from a resource. You must provide the ARN of the resource from which you want to delete the tag or tags.</p>
+    fn delete_tags(&self, input: DeleteTagsMessage) -> RusotoFuture<(), DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteTags");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteTags");
+        params.put("Version", "2012-12-01");
+        DeleteTagsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Returns a list of attributes attached to an account</p>
+    fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> RusotoFuture<AccountAttributeList, DescribeAccountAttributesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeAccountAttributes");
+        params.put("Version", "2012-12-01");
+        DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = AccountAttributeList::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = AccountAttributeListDeserializer::deserialize(
+                        "DescribeAccountAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeAccountAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns an array of <code>ClusterDbRevision</code> objects.</p>
+    fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> RusotoFuture<ClusterDbRevisionsMessage, DescribeClusterDbRevisionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterDbRevisions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterDbRevisions");
+        params.put("Version", "2012-12-01");
+        DescribeClusterDbRevisionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterDbRevisionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterDbRevisionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterDbRevisionsMessageDeserializer::deserialize(
+                        "DescribeClusterDbRevisionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterDbRevisions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of Amazon Redshift parameter groups, including parameter groups you created and the default parameter group. For each parameter group, the response includes the parameter group name, description, and parameter group family name. You can optionally specify a name to retrieve the description of a specific parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all parameter groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all parameter groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, parameter groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> RusotoFuture<ClusterParameterGroupsMessage, DescribeClusterParameterGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameterGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterParameterGroups");
+        params.put("Version", "2012-12-01");
+        DescribeClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterParameterGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterParameterGroupsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterParameterGroupsMessageDeserializer::deserialize(
+                        "DescribeClusterParameterGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterParameterGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a detailed list of parameters contained within the specified Amazon Redshift parameter group. For each parameter the response includes information such as parameter name, description, data type, value, whether the parameter value is modifiable, and so on.</p> <p>You can specify <i>source</i> filter to retrieve parameters of only specific type. For example, to retrieve parameters that were modified by a user action such as from <a>ModifyClusterParameterGroup</a>, you can specify <i>source</i> equal to <i>user</i>.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> RusotoFuture<ClusterParameterGroupDetails, DescribeClusterParametersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterParameters");
+        params.put("Version", "2012-12-01");
+        DescribeClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterParametersError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterParameterGroupDetails::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterParameterGroupDetailsDeserializer::deserialize(
+                        "DescribeClusterParametersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterParameters", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about Amazon Redshift security groups. If the name of a security group is specified, the response will contain only information about only that security group.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all security groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all security groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, security groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> RusotoFuture<ClusterSecurityGroupMessage, DescribeClusterSecurityGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSecurityGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterSecurityGroups");
+        params.put("Version", "2012-12-01");
+        DescribeClusterSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterSecurityGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterSecurityGroupMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterSecurityGroupMessageDeserializer::deserialize(
+                        "DescribeClusterSecurityGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterSecurityGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns one or more snapshot objects, which contain metadata about your cluster snapshots. By default, this operation returns information about all snapshots of all clusters that are owned by you AWS customer account. No information is returned for snapshots owned by inactive AWS customer accounts.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all snapshots that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all snapshots that have any combination of those values are returned. Only snapshots that you own are returned in the response; shared snapshots are not returned with the tag key and tag value request parameters.</p> <p>If both tag keys and values are omitted from the request, snapshots are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> RusotoFuture<SnapshotMessage, DescribeClusterSnapshotsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSnapshots");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterSnapshots");
+        params.put("Version", "2012-12-01");
+        DescribeClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterSnapshotsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SnapshotMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SnapshotMessageDeserializer::deserialize(
+                        "DescribeClusterSnapshotsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterSnapshots", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns one or more cluster subnet group objects, which contain metadata about your cluster subnet groups. By default, this operation returns information about all cluster subnet groups that are defined in you AWS account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all subnet groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subnet groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subnet groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> RusotoFuture<ClusterSubnetGroupMessage, DescribeClusterSubnetGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSubnetGroups");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterSubnetGroups");
+        params.put("Version", "2012-12-01");
+        DescribeClusterSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterSubnetGroupsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterSubnetGroupMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterSubnetGroupMessageDeserializer::deserialize(
+                        "DescribeClusterSubnetGroupsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterSubnetGroups", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of all the available maintenance tracks.</p>
+    fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> RusotoFuture<TrackListMessage, DescribeClusterTracksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterTracks");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterTracks");
+        params.put("Version", "2012-12-01");
+        DescribeClusterTracksMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterTracksError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TrackListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TrackListMessageDeserializer::deserialize(
+                        "DescribeClusterTracksResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterTracks", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns descriptions of the available Amazon Redshift cluster versions. You can call this operation even before creating any clusters to learn more about the Amazon Redshift versions. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> RusotoFuture<ClusterVersionsMessage, DescribeClusterVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterVersions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusterVersions");
+        params.put("Version", "2012-12-01");
+        DescribeClusterVersionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClusterVersionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterVersionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterVersionsMessageDeserializer::deserialize(
+                        "DescribeClusterVersionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeClusterVersions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns properties of provisioned clusters including general cluster properties, cluster database properties, maintenance and backup properties, and security and access properties. This operation supports pagination. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all clusters that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all clusters that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, clusters are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> RusotoFuture<ClustersMessage, DescribeClustersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeClusters");
+        params.put("Version", "2012-12-01");
+        DescribeClustersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeClustersError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClustersMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClustersMessageDeserializer::deserialize(
+                        "DescribeClustersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeClusters", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of parameter settings for the specified parameter group family.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> RusotoFuture<DescribeDefaultClusterParametersResult, DescribeDefaultClusterParametersError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeDefaultClusterParameters");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeDefaultClusterParameters");
+        params.put("Version", "2012-12-01");
+        DescribeDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDefaultClusterParametersError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeDefaultClusterParametersResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeDefaultClusterParametersResultDeserializer::deserialize(
+                        "DescribeDefaultClusterParametersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeDefaultClusterParameters", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Displays a list of event categories for all event source types, or for a specified source type. For a list of the event categories and source types, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-event-notifications.html">Amazon Redshift Event Notifications</a>.</p>
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEventCategories");
+        params.put("Version", "2012-12-01");
+        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEventCategoriesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventCategoriesMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EventCategoriesMessageDeserializer::deserialize(
+                        "DescribeEventCategoriesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEventCategories", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists descriptions of all the Amazon Redshift event notification subscriptions for a customer account. If you specify a subscription name, lists the description for that subscription.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all event notification subscriptions that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subscriptions that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subscriptions are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEventSubscriptions");
+        params.put("Version", "2012-12-01");
+        DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEventSubscriptionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventSubscriptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EventSubscriptionsMessageDeserializer::deserialize(
+                        "DescribeEventSubscriptionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeEventSubscriptions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns events related to clusters, security groups, snapshots, and parameter groups for the past 14 days. Events specific to a particular cluster, security group, snapshot or parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEvents");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeEvents");
+        params.put("Version", "2012-12-01");
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEventsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EventsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        EventsMessageDeserializer::deserialize("DescribeEventsResult", &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("DescribeEvents", err, &response.body)
+                            })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about the specified HSM client certificate. If no certificate ID is specified, returns information about all the HSM certificates owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM client certificates that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM client certificates that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM client certificates are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> RusotoFuture<HsmClientCertificateMessage, DescribeHsmClientCertificatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmClientCertificates");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeHsmClientCertificates");
+        params.put("Version", "2012-12-01");
+        DescribeHsmClientCertificatesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeHsmClientCertificatesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = HsmClientCertificateMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = HsmClientCertificateMessageDeserializer::deserialize(
+                        "DescribeHsmClientCertificatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeHsmClientCertificates", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about the specified Amazon Redshift HSM configuration. If no configuration ID is specified, returns information about all the HSM configurations owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM connections that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM connections that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM connections are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> RusotoFuture<HsmConfigurationMessage, DescribeHsmConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmConfigurations");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeHsmConfigurations");
+        params.put("Version", "2012-12-01");
+        DescribeHsmConfigurationsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeHsmConfigurationsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = HsmConfigurationMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = HsmConfigurationMessageDeserializer::deserialize(
+                        "DescribeHsmConfigurationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeHsmConfigurations", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Describes whether information, such as queries and connection attempts, is being logged for the specified Amazon Redshift cluster.</p>
+    fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> RusotoFuture<LoggingStatus, DescribeLoggingStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeLoggingStatus");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeLoggingStatus");
+        params.put("Version", "2012-12-01");
+        DescribeLoggingStatusMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeLoggingStatusError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = LoggingStatus::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = LoggingStatusDeserializer::deserialize(
+                        "DescribeLoggingStatusResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeLoggingStatus", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of orderable cluster options. Before you create a new cluster you can use this operation to find what options are available, such as the EC2 Availability Zones (AZ) in the specific AWS Region that you can specify, and the node types you can request. The node types differ by available storage, memory, CPU and price. With the cost involved you might want to obtain a list of cluster options in the specific region and specify values when creating a cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> RusotoFuture<OrderableClusterOptionsMessage, DescribeOrderableClusterOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeOrderableClusterOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeOrderableClusterOptions");
+        params.put("Version", "2012-12-01");
+        DescribeOrderableClusterOptionsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeOrderableClusterOptionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = OrderableClusterOptionsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = OrderableClusterOptionsMessageDeserializer::deserialize(
+                        "DescribeOrderableClusterOptionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeOrderableClusterOptions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the available reserved node offerings by Amazon Redshift with their descriptions including the node type, the fixed and recurring costs of reserving the node and duration the node will be reserved for you. These descriptions help you determine which reserve node offering you want to purchase. You then use the unique offering ID in you call to <a>PurchaseReservedNodeOffering</a> to reserve one or more nodes for your Amazon Redshift cluster. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> RusotoFuture<ReservedNodeOfferingsMessage, DescribeReservedNodeOfferingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodeOfferings");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReservedNodeOfferings");
+        params.put("Version", "2012-12-01");
+        DescribeReservedNodeOfferingsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReservedNodeOfferingsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ReservedNodeOfferingsMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ReservedNodeOfferingsMessageDeserializer::deserialize(
+                        "DescribeReservedNodeOfferingsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeReservedNodeOfferings", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the descriptions of the reserved nodes.</p>
+    fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> RusotoFuture<ReservedNodesMessage, DescribeReservedNodesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodes");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReservedNodes");
+        params.put("Version", "2012-12-01");
+        DescribeReservedNodesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReservedNodesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ReservedNodesMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ReservedNodesMessageDeserializer::deserialize(
+                        "DescribeReservedNodesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeReservedNodes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns information about the last resize operation for the specified cluster. If no resize operation has ever been initiated for the specified cluster, a <code>HTTP 404</code> error is returned. If a resize operation was initiated and completed, the status of the resize remains as <code>SUCCEEDED</code> until the next resize. </p> <p>A resize operation can be requested using <a>ModifyCluster</a> and specifying a different number or type of nodes for the cluster. </p>
+    fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, DescribeResizeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeResize");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeResize");
+        params.put("Version", "2012-12-01");
+        DescribeResizeMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeResizeError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ResizeProgressMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ResizeProgressMessageDeserializer::deserialize(
+                        "DescribeResizeResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeResize", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of snapshot copy grants owned by the AWS account in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> RusotoFuture<SnapshotCopyGrantMessage, DescribeSnapshotCopyGrantsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotCopyGrants");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeSnapshotCopyGrants");
+        params.put("Version", "2012-12-01");
+        DescribeSnapshotCopyGrantsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSnapshotCopyGrantsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SnapshotCopyGrantMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SnapshotCopyGrantMessageDeserializer::deserialize(
+                        "DescribeSnapshotCopyGrantsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeSnapshotCopyGrants", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of snapshot schedules. </p>
+    fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> RusotoFuture<DescribeSnapshotSchedulesOutputMessage, DescribeSnapshotSchedulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedules");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeSnapshotSchedules");
+        params.put("Version", "2012-12-01");
+        DescribeSnapshotSchedulesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSnapshotSchedulesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeSnapshotSchedulesOutputMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeSnapshotSchedulesOutputMessageDeserializer::deserialize(
+                        "DescribeSnapshotSchedulesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeSnapshotSchedules", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the total amount of snapshot usage and provisioned storage in megabytes.</p>
+    fn describe_storage(&self) -> RusotoFuture<CustomerStorageMessage, DescribeStorageError> {
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeStorage");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeStorage");
+        params.put("Version", "2012-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeStorageError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CustomerStorageMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CustomerStorageMessageDeserializer::deserialize(
+                        "DescribeStorageResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeStorage", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the status of one or more table restore requests made using the <a>RestoreTableFromClusterSnapshot</a> API action. If you don't specify a value for the <code>TableRestoreRequestId</code> parameter, then <code>DescribeTableRestoreStatus</code> returns the status of all table restore requests ordered by the date and time of the request in ascending order. Otherwise <code>DescribeTableRestoreStatus</code> returns the status of the table specified by <code>TableRestoreRequestId</code>.</p>
+    fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> RusotoFuture<TableRestoreStatusMessage, DescribeTableRestoreStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTableRestoreStatus");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTableRestoreStatus");
+        params.put("Version", "2012-12-01");
+        DescribeTableRestoreStatusMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTableRestoreStatusError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TableRestoreStatusMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TableRestoreStatusMessageDeserializer::deserialize(
+                        "DescribeTableRestoreStatusResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeTableRestoreStatus", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of tags. You can return tags from a specific resource by specifying an ARN, or you can return all tags for a given type of resource, such as clusters, snapshots, and so on.</p> <p>The following are limitations for <code>DescribeTags</code>: </p> <ul> <li> <p>You cannot specify an ARN and a resource-type value together in the same request.</p> </li> <li> <p>You cannot use the <code>MaxRecords</code> and <code>Marker</code> parameters together with the ARN parameter.</p> </li> <li> <p>The <code>MaxRecords</code> parameter can be a range from 10 to 50 results to return in a request.</p> </li> </ul> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all resources that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all resources that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, resources are returned regardless of whether they have tag keys or values associated with them.</p>
+    fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> RusotoFuture<TaggedResourceListMessage, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTags");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeTags");
+        params.put("Version", "2012-12-01");
+        DescribeTagsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TaggedResourceListMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TaggedResourceListMessageDeserializer::deserialize(
+                        "DescribeTagsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeTags", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Stops logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
+    fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, DisableLoggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableLogging");
+        let mut params = Params::new();
+
+        params.put("Action", "DisableLogging");
+        params.put("Version", "2012-12-01");
+        DisableLoggingMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableLoggingError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = LoggingStatus::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        LoggingStatusDeserializer::deserialize("DisableLoggingResult", &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("DisableLogging", err, &response.body)
+                            })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Disables the automatic copying of snapshots from one region to another region for a specified cluster.</p> <p>If your cluster and its snapshots are encrypted using a customer master key (CMK) from AWS KMS, use <a>DeleteSnapshotCopyGrant</a> to delete the grant that grants Amazon Redshift permission to the CMK in the destination region. </p>
+    fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> RusotoFuture<DisableSnapshotCopyResult, DisableSnapshotCopyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableSnapshotCopy");
+        let mut params = Params::new();
+
+        params.put("Action", "DisableSnapshotCopy");
+        params.put("Version", "2012-12-01");
+        DisableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisableSnapshotCopyError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DisableSnapshotCopyResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DisableSnapshotCopyResultDeserializer::deserialize(
+                        "DisableSnapshotCopyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DisableSnapshotCopy", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Starts logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
+    fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, EnableLoggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableLogging");
+        let mut params = Params::new();
+
+        params.put("Action", "EnableLogging");
+        params.put("Version", "2012-12-01");
+        EnableLoggingMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableLoggingError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = LoggingStatus::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = LoggingStatusDeserializer::deserialize(
+                        "EnableLoggingResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("EnableLogging", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Enables the automatic copy of snapshots from one region to another region for a specified cluster.</p>
+    fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> RusotoFuture<EnableSnapshotCopyResult, EnableSnapshotCopyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableSnapshotCopy");
+        let mut params = Params::new();
+
+        params.put("Action", "EnableSnapshotCopy");
+        params.put("Version", "2012-12-01");
+        EnableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableSnapshotCopyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = EnableSnapshotCopyResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = EnableSnapshotCopyResultDeserializer::deserialize(
+                        "EnableSnapshotCopyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("EnableSnapshotCopy", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a database user name and temporary password with temporary authorization to log on to an Amazon Redshift database. The action returns the database user name prefixed with <code>IAM:</code> if <code>AutoCreate</code> is <code>False</code> or <code>IAMA:</code> if <code>AutoCreate</code> is <code>True</code>. You can optionally specify one or more database user groups that the user will join at log on. By default, the temporary credentials expire in 900 seconds. You can optionally specify a duration between 900 seconds (15 minutes) and 3600 seconds (60 minutes). For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/generating-user-credentials.html">Using IAM Authentication to Generate Database User Credentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>The AWS Identity and Access Management (IAM)user or role that executes GetClusterCredentials must have an IAM policy attached that allows access to all necessary actions and resources. For more information about permissions, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/redshift-iam-access-control-identity-based.html#redshift-policy-resources.getclustercredentials-resources">Resource Policies for GetClusterCredentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If the <code>DbGroups</code> parameter is specified, the IAM policy must allow the <code>redshift:JoinGroup</code> action with access to the listed <code>dbgroups</code>. </p> <p>In addition, if the <code>AutoCreate</code> parameter is set to <code>True</code>, then the policy must include the <code>redshift:CreateClusterUser</code> privilege.</p> <p>If the <code>DbName</code> parameter is specified, the IAM policy must allow access to the resource <code>dbname</code> for the specified database name. </p>
+    fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> RusotoFuture<ClusterCredentials, GetClusterCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetClusterCredentials");
+        let mut params = Params::new();
+
+        params.put("Action", "GetClusterCredentials");
+        params.put("Version", "2012-12-01");
+        GetClusterCredentialsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetClusterCredentialsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterCredentials::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterCredentialsDeserializer::deserialize(
+                        "GetClusterCredentialsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetClusterCredentials", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns an array of DC2 ReservedNodeOfferings that matches the payment type, term, and usage price of the given DC1 reserved node.</p>
+    fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> RusotoFuture<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetReservedNodeExchangeOfferings");
+        let mut params = Params::new();
+
+        params.put("Action", "GetReservedNodeExchangeOfferings");
+        params.put("Version", "2012-12-01");
+        GetReservedNodeExchangeOfferingsInputMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetReservedNodeExchangeOfferingsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetReservedNodeExchangeOfferingsOutputMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        GetReservedNodeExchangeOfferingsOutputMessageDeserializer::deserialize(
+                            "GetReservedNodeExchangeOfferingsResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "GetReservedNodeExchangeOfferings",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the settings for a cluster. For example, you can add another security or parameter group, update the preferred maintenance window, or change the master user password. Resetting a cluster password or modifying the security groups associated with a cluster do not need a reboot. However, modifying a parameter group requires a reboot for parameters to take effect. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>You can also change node type and the number of nodes to scale up or down the cluster. When resizing a cluster, you must specify both the number of nodes and the node type even if one of the parameters does not change.</p>
+    fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> RusotoFuture<ModifyClusterResult, ModifyClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyCluster");
+        params.put("Version", "2012-12-01");
+        ModifyClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ModifyClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyClusterResultDeserializer::deserialize(
+                        "ModifyClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ModifyCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the database revision of a cluster. The database revision is a unique revision of the database running in a cluster.</p>
+    fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> RusotoFuture<ModifyClusterDbRevisionResult, ModifyClusterDbRevisionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterDbRevision");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterDbRevision");
+        params.put("Version", "2012-12-01");
+        ModifyClusterDbRevisionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterDbRevisionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyClusterDbRevisionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyClusterDbRevisionResultDeserializer::deserialize(
+                        "ModifyClusterDbRevisionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyClusterDbRevision", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the list of AWS Identity and Access Management (IAM) roles that can be used by the cluster to access other AWS services.</p> <p>A cluster can have up to 10 IAM roles associated at any time.</p>
+    fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> RusotoFuture<ModifyClusterIamRolesResult, ModifyClusterIamRolesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterIamRoles");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterIamRoles");
+        params.put("Version", "2012-12-01");
+        ModifyClusterIamRolesMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterIamRolesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyClusterIamRolesResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyClusterIamRolesResultDeserializer::deserialize(
+                        "ModifyClusterIamRolesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyClusterIamRoles", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the maintenance settings of a cluster. For example, you can defer a maintenance window. You can also update or cancel a deferment. </p>
+    fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> RusotoFuture<ModifyClusterMaintenanceResult, ModifyClusterMaintenanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterMaintenance");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterMaintenance");
+        params.put("Version", "2012-12-01");
+        ModifyClusterMaintenanceMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterMaintenanceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyClusterMaintenanceResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyClusterMaintenanceResultDeserializer::deserialize(
+                        "ModifyClusterMaintenanceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyClusterMaintenance", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the parameters of a parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ModifyClusterParameterGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterParameterGroup");
+        params.put("Version", "2012-12-01");
+        ModifyClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterParameterGroupNameMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterParameterGroupNameMessageDeserializer::deserialize(
+                        "ModifyClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the settings for a snapshot.</p>
+    fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> RusotoFuture<ModifyClusterSnapshotResult, ModifyClusterSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterSnapshot");
+        params.put("Version", "2012-12-01");
+        ModifyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyClusterSnapshotResultDeserializer::deserialize(
+                        "ModifyClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies a snapshot schedule for a cluster.</p>
+    fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), ModifyClusterSnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshotSchedule");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterSnapshotSchedule");
+        params.put("Version", "2012-12-01");
+        ModifyClusterSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterSnapshotScheduleError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Modifies a cluster subnet group to include the specified list of VPC subnets. The operation replaces the existing list of subnets with the new list of subnets.</p>
+    fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyClusterSubnetGroupResult, ModifyClusterSubnetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSubnetGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyClusterSubnetGroup");
+        params.put("Version", "2012-12-01");
+        ModifyClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyClusterSubnetGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyClusterSubnetGroupResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyClusterSubnetGroupResultDeserializer::deserialize(
+                        "ModifyClusterSubnetGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyClusterSubnetGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies an existing Amazon Redshift event notification subscription.</p>
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifyEventSubscription");
+        params.put("Version", "2012-12-01");
+        ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyEventSubscriptionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifyEventSubscriptionResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifyEventSubscriptionResultDeserializer::deserialize(
+                        "ModifyEventSubscriptionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifyEventSubscription", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies the number of days to retain snapshots in the destination AWS Region after they are copied from the source AWS Region. By default, this operation only changes the retention period of copied automated snapshots. The retention periods for both new and existing copied automated snapshots are updated with the new retention period. You can set the manual option to change only the retention periods of copied manual snapshots. If you set this option, only newly copied manual snapshots have the new retention period. </p>
+    fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> RusotoFuture<ModifySnapshotCopyRetentionPeriodResult, ModifySnapshotCopyRetentionPeriodError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotCopyRetentionPeriod");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifySnapshotCopyRetentionPeriod");
+        params.put("Version", "2012-12-01");
+        ModifySnapshotCopyRetentionPeriodMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifySnapshotCopyRetentionPeriodError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ModifySnapshotCopyRetentionPeriodResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ModifySnapshotCopyRetentionPeriodResultDeserializer::deserialize(
+                        "ModifySnapshotCopyRetentionPeriodResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifySnapshotCopyRetentionPeriod", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Modifies a snapshot schedule. Any schedule associated with a cluster is modified asynchronously.</p>
+    fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, ModifySnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotSchedule");
+        let mut params = Params::new();
+
+        params.put("Action", "ModifySnapshotSchedule");
+        params.put("Version", "2012-12-01");
+        ModifySnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifySnapshotScheduleError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SnapshotSchedule::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SnapshotScheduleDeserializer::deserialize(
+                        "ModifySnapshotScheduleResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ModifySnapshotSchedule", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Allows you to purchase reserved nodes. Amazon Redshift offers a predefined set of reserved node offerings. You can purchase one or more of the offerings. You can call the <a>DescribeReservedNodeOfferings</a> API to obtain the available reserved node offerings. You can call this API by providing a specific reserved node offering and the number of nodes you want to reserve. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> RusotoFuture<PurchaseReservedNodeOfferingResult, PurchaseReservedNodeOfferingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("PurchaseReservedNodeOffering");
+        let mut params = Params::new();
+
+        params.put("Action", "PurchaseReservedNodeOffering");
+        params.put("Version", "2012-12-01");
+        PurchaseReservedNodeOfferingMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PurchaseReservedNodeOfferingError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PurchaseReservedNodeOfferingResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PurchaseReservedNodeOfferingResultDeserializer::deserialize(
+                        "PurchaseReservedNodeOfferingResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("PurchaseReservedNodeOffering", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Reboots a cluster. This action is taken as soon as possible. It results in a momentary outage to the cluster, during which the cluster status is set to <code>rebooting</code>. A cluster event is created when the reboot is completed. Any pending cluster modifications (see <a>ModifyCluster</a>) are applied at this reboot. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> RusotoFuture<RebootClusterResult, RebootClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RebootCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "RebootCluster");
+        params.put("Version", "2012-12-01");
+        RebootClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RebootClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RebootClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RebootClusterResultDeserializer::deserialize(
+                        "RebootClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("RebootCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets one or more parameters of the specified parameter group to their default values and sets the source values of the parameters to "engine-default". To reset the entire parameter group specify the <i>ResetAllParameters</i> parameter. For parameter changes to take effect you must reboot any associated clusters. </p>
+    fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ResetClusterParameterGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResetClusterParameterGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "ResetClusterParameterGroup");
+        params.put("Version", "2012-12-01");
+        ResetClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ResetClusterParameterGroupError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ClusterParameterGroupNameMessage::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ClusterParameterGroupNameMessageDeserializer::deserialize(
+                        "ResetClusterParameterGroupResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ResetClusterParameterGroup", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Changes the size of the cluster. You can change the cluster&#39;s type, or change the number or type of nodes. The default behavior is to use the elastic resize method. With an elastic resize, your cluster is available for read and write operations more quickly than with the classic resize method. </p> <p>Elastic resize operations have the following restrictions:</p> <ul> <li> <p>You can only resize clusters of the following types:</p> <ul> <li> <p>dc2.large</p> </li> <li> <p>dc2.8xlarge</p> </li> <li> <p>ds2.xlarge</p> </li> <li> <p>ds2.8xlarge</p> </li> </ul> </li> <li> <p>The type of nodes that you add must match the node type for the cluster.</p> </li> </ul></p>
+    fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> RusotoFuture<ResizeClusterResult, ResizeClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResizeCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "ResizeCluster");
+        params.put("Version", "2012-12-01");
+        ResizeClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResizeClusterError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ResizeClusterResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ResizeClusterResultDeserializer::deserialize(
+                        "ResizeClusterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ResizeCluster", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new cluster from a snapshot. By default, Amazon Redshift creates the resulting cluster with the same configuration as the original cluster from which the snapshot was created, except that the new cluster is created with the default cluster security and parameter groups. After Amazon Redshift creates the cluster, you can use the <a>ModifyCluster</a> API to associate a different security group and different parameter group with the restored cluster. If you are using a DS node type, you can also choose to change to another DS node type of the same size during restore.</p> <p>If you restore a cluster into a VPC, you must provide a cluster subnet group where you want the cluster restored.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreFromClusterSnapshotResult, RestoreFromClusterSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreFromClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "RestoreFromClusterSnapshot");
+        params.put("Version", "2012-12-01");
+        RestoreFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RestoreFromClusterSnapshotError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RestoreFromClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RestoreFromClusterSnapshotResultDeserializer::deserialize(
+                        "RestoreFromClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RestoreFromClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new table from a table in an Amazon Redshift cluster snapshot. You must create the new table within the Amazon Redshift cluster that the snapshot was taken from.</p> <p>You cannot use <code>RestoreTableFromClusterSnapshot</code> to restore a table with the same name as an existing table in an Amazon Redshift cluster. That is, you cannot overwrite an existing table in a cluster with a restored table. If you want to replace your original table with a new, restored table, then rename or drop your original table before you call <code>RestoreTableFromClusterSnapshot</code>. When you have renamed your original table, then you can pass the original name of the table as the <code>NewTableName</code> parameter value in the call to <code>RestoreTableFromClusterSnapshot</code>. This way, you can replace the original table with the table created from the snapshot.</p>
+    fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreTableFromClusterSnapshotResult, RestoreTableFromClusterSnapshotError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreTableFromClusterSnapshot");
+        let mut params = Params::new();
+
+        params.put("Action", "RestoreTableFromClusterSnapshot");
+        params.put("Version", "2012-12-01");
+        RestoreTableFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RestoreTableFromClusterSnapshotError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RestoreTableFromClusterSnapshotResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RestoreTableFromClusterSnapshotResultDeserializer::deserialize(
+                        "RestoreTableFromClusterSnapshotResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RestoreTableFromClusterSnapshot", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Revokes an ingress rule in an Amazon Redshift security group for a previously authorized IP range or Amazon EC2 security group. To add an ingress rule, see <a>AuthorizeClusterSecurityGroupIngress</a>. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeClusterSecurityGroupIngressResult, RevokeClusterSecurityGroupIngressError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeClusterSecurityGroupIngress");
+        let mut params = Params::new();
+
+        params.put("Action", "RevokeClusterSecurityGroupIngress");
+        params.put("Version", "2012-12-01");
+        RevokeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RevokeClusterSecurityGroupIngressError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RevokeClusterSecurityGroupIngressResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RevokeClusterSecurityGroupIngressResultDeserializer::deserialize(
+                        "RevokeClusterSecurityGroupIngressResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RevokeClusterSecurityGroupIngress", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Removes the ability of the specified AWS customer account to restore the specified snapshot. If the account is currently restoring the snapshot, the restore will run to completion.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> RusotoFuture<RevokeSnapshotAccessResult, RevokeSnapshotAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeSnapshotAccess");
+        let mut params = Params::new();
+
+        params.put("Action", "RevokeSnapshotAccess");
+        params.put("Version", "2012-12-01");
+        RevokeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RevokeSnapshotAccessError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RevokeSnapshotAccessResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RevokeSnapshotAccessResultDeserializer::deserialize(
+                        "RevokeSnapshotAccessResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("RevokeSnapshotAccess", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Rotates the encryption keys for a cluster.</p>
+    fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RotateEncryptionKey");
+        let mut params = Params::new();
+
+        params.put("Action", "RotateEncryptionKey");
+        params.put("Version", "2012-12-01");
+        RotateEncryptionKeyMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RotateEncryptionKeyError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = RotateEncryptionKeyResult::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = RotateEncryptionKeyResultDeserializer::deserialize(
+                        "RotateEncryptionKeyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("RotateEncryptionKey", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+}
+
+/// A mock of the Amazon Redshift API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockRedshift {
+    accept_reserved_node_exchange: Option<
+        Box<
+            dyn Fn(
+                    AcceptReservedNodeExchangeInputMessage,
+                ) -> ::std::result::Result<
+                    AcceptReservedNodeExchangeOutputMessage,
+                    RusotoError<AcceptReservedNodeExchangeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    authorize_cluster_security_group_ingress: Option<
+        Box<
+            dyn Fn(
+                    AuthorizeClusterSecurityGroupIngressMessage,
+                ) -> ::std::result::Result<
+                    AuthorizeClusterSecurityGroupIngressResult,
+                    RusotoError<AuthorizeClusterSecurityGroupIngressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    authorize_snapshot_access: Option<
+        Box<
+            dyn Fn(
+                    AuthorizeSnapshotAccessMessage,
+                ) -> ::std::result::Result<
+                    AuthorizeSnapshotAccessResult,
+                    RusotoError<AuthorizeSnapshotAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_delete_cluster_snapshots: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteClusterSnapshotsRequest,
+                ) -> ::std::result::Result<
+                    BatchDeleteClusterSnapshotsResult,
+                    RusotoError<BatchDeleteClusterSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_modify_cluster_snapshots: Option<
+        Box<
+            dyn Fn(
+                    BatchModifyClusterSnapshotsMessage,
+                ) -> ::std::result::Result<
+                    BatchModifyClusterSnapshotsOutputMessage,
+                    RusotoError<BatchModifyClusterSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    cancel_resize: Option<
+        Box<
+            dyn Fn(
+                    CancelResizeMessage,
+                )
+                    -> ::std::result::Result<ResizeProgressMessage, RusotoError<CancelResizeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    copy_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CopyClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    CopyClusterSnapshotResult,
+                    RusotoError<CopyClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterMessage,
+                )
+                    -> ::std::result::Result<CreateClusterResult, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateClusterParameterGroupResult,
+                    RusotoError<CreateClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cluster_security_group: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterSecurityGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateClusterSecurityGroupResult,
+                    RusotoError<CreateClusterSecurityGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    CreateClusterSnapshotResult,
+                    RusotoError<CreateClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cluster_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    CreateClusterSubnetGroupResult,
+                    RusotoError<CreateClusterSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_event_subscription: Option<
+        Box<
+            dyn Fn(
+                    CreateEventSubscriptionMessage,
+                ) -> ::std::result::Result<
+                    CreateEventSubscriptionResult,
+                    RusotoError<CreateEventSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_hsm_client_certificate: Option<
+        Box<
+            dyn Fn(
+                    CreateHsmClientCertificateMessage,
+                ) -> ::std::result::Result<
+                    CreateHsmClientCertificateResult,
+                    RusotoError<CreateHsmClientCertificateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_hsm_configuration: Option<
+        Box<
+            dyn Fn(
+                    CreateHsmConfigurationMessage,
+                ) -> ::std::result::Result<
+                    CreateHsmConfigurationResult,
+                    RusotoError<CreateHsmConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_snapshot_copy_grant: Option<
+        Box<
+            dyn Fn(
+                    CreateSnapshotCopyGrantMessage,
+                ) -> ::std::result::Result<
+                    CreateSnapshotCopyGrantResult,
+                    RusotoError<CreateSnapshotCopyGrantError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    CreateSnapshotScheduleMessage,
+                ) -> ::std::result::Result<
+                    SnapshotSchedule,
+                    RusotoError<CreateSnapshotScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tags: Option<
+        Box<
+            dyn Fn(CreateTagsMessage) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterMessage,
+                )
+                    -> ::std::result::Result<DeleteClusterResult, RusotoError<DeleteClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterParameterGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteClusterParameterGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster_security_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterSecurityGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteClusterSecurityGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    DeleteClusterSnapshotResult,
+                    RusotoError<DeleteClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_cluster_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteClusterSubnetGroupMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteClusterSubnetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_event_subscription: Option<
+        Box<
+            dyn Fn(
+                    DeleteEventSubscriptionMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteEventSubscriptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_hsm_client_certificate: Option<
+        Box<
+            dyn Fn(
+                    DeleteHsmClientCertificateMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteHsmClientCertificateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_hsm_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteHsmConfigurationMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteHsmConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_snapshot_copy_grant: Option<
+        Box<
+            dyn Fn(
+                    DeleteSnapshotCopyGrantMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteSnapshotCopyGrantError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    DeleteSnapshotScheduleMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteSnapshotScheduleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(DeleteTagsMessage) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_account_attributes: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccountAttributesMessage,
+                ) -> ::std::result::Result<
+                    AccountAttributeList,
+                    RusotoError<DescribeAccountAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_db_revisions: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterDbRevisionsMessage,
+                ) -> ::std::result::Result<
+                    ClusterDbRevisionsMessage,
+                    RusotoError<DescribeClusterDbRevisionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_parameter_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterParameterGroupsMessage,
+                ) -> ::std::result::Result<
+                    ClusterParameterGroupsMessage,
+                    RusotoError<DescribeClusterParameterGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterParametersMessage,
+                ) -> ::std::result::Result<
+                    ClusterParameterGroupDetails,
+                    RusotoError<DescribeClusterParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_security_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterSecurityGroupsMessage,
+                ) -> ::std::result::Result<
+                    ClusterSecurityGroupMessage,
+                    RusotoError<DescribeClusterSecurityGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_snapshots: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterSnapshotsMessage,
+                ) -> ::std::result::Result<
+                    SnapshotMessage,
+                    RusotoError<DescribeClusterSnapshotsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_subnet_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterSubnetGroupsMessage,
+                ) -> ::std::result::Result<
+                    ClusterSubnetGroupMessage,
+                    RusotoError<DescribeClusterSubnetGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_tracks: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterTracksMessage,
+                ) -> ::std::result::Result<
+                    TrackListMessage,
+                    RusotoError<DescribeClusterTracksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster_versions: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterVersionsMessage,
+                ) -> ::std::result::Result<
+                    ClusterVersionsMessage,
+                    RusotoError<DescribeClusterVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_clusters: Option<
+        Box<
+            dyn Fn(
+                    DescribeClustersMessage,
+                )
+                    -> ::std::result::Result<ClustersMessage, RusotoError<DescribeClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_default_cluster_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeDefaultClusterParametersMessage,
+                ) -> ::std::result::Result<
+                    DescribeDefaultClusterParametersResult,
+                    RusotoError<DescribeDefaultClusterParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_categories: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventCategoriesMessage,
+                ) -> ::std::result::Result<
+                    EventCategoriesMessage,
+                    RusotoError<DescribeEventCategoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_event_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventSubscriptionsMessage,
+                ) -> ::std::result::Result<
+                    EventSubscriptionsMessage,
+                    RusotoError<DescribeEventSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_events: Option<
+        Box<
+            dyn Fn(
+                    DescribeEventsMessage,
+                )
+                    -> ::std::result::Result<EventsMessage, RusotoError<DescribeEventsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_hsm_client_certificates: Option<
+        Box<
+            dyn Fn(
+                    DescribeHsmClientCertificatesMessage,
+                ) -> ::std::result::Result<
+                    HsmClientCertificateMessage,
+                    RusotoError<DescribeHsmClientCertificatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_hsm_configurations: Option<
+        Box<
+            dyn Fn(
+                    DescribeHsmConfigurationsMessage,
+                ) -> ::std::result::Result<
+                    HsmConfigurationMessage,
+                    RusotoError<DescribeHsmConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_logging_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeLoggingStatusMessage,
+                )
+                    -> ::std::result::Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_orderable_cluster_options: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrderableClusterOptionsMessage,
+                ) -> ::std::result::Result<
+                    OrderableClusterOptionsMessage,
+                    RusotoError<DescribeOrderableClusterOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_reserved_node_offerings: Option<
+        Box<
+            dyn Fn(
+                    DescribeReservedNodeOfferingsMessage,
+                ) -> ::std::result::Result<
+                    ReservedNodeOfferingsMessage,
+                    RusotoError<DescribeReservedNodeOfferingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_reserved_nodes: Option<
+        Box<
+            dyn Fn(
+                    DescribeReservedNodesMessage,
+                ) -> ::std::result::Result<
+                    ReservedNodesMessage,
+                    RusotoError<DescribeReservedNodesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_resize: Option<
+        Box<
+            dyn Fn(
+                    DescribeResizeMessage,
+                )
+                    -> ::std::result::Result<ResizeProgressMessage, RusotoError<DescribeResizeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_snapshot_copy_grants: Option<
+        Box<
+            dyn Fn(
+                    DescribeSnapshotCopyGrantsMessage,
+                ) -> ::std::result::Result<
+                    SnapshotCopyGrantMessage,
+                    RusotoError<DescribeSnapshotCopyGrantsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_snapshot_schedules: Option<
+        Box<
+            dyn Fn(
+                    DescribeSnapshotSchedulesMessage,
+                ) -> ::std::result::Result<
+                    DescribeSnapshotSchedulesOutputMessage,
+                    RusotoError<DescribeSnapshotSchedulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_storage: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    CustomerStorageMessage,
+                    RusotoError<DescribeStorageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_table_restore_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeTableRestoreStatusMessage,
+                ) -> ::std::result::Result<
+                    TableRestoreStatusMessage,
+                    RusotoError<DescribeTableRestoreStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsMessage,
+                ) -> ::std::result::Result<
+                    TaggedResourceListMessage,
+                    RusotoError<DescribeTagsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_logging: Option<
+        Box<
+            dyn Fn(
+                    DisableLoggingMessage,
+                )
+                    -> ::std::result::Result<LoggingStatus, RusotoError<DisableLoggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_snapshot_copy: Option<
+        Box<
+            dyn Fn(
+                    DisableSnapshotCopyMessage,
+                ) -> ::std::result::Result<
+                    DisableSnapshotCopyResult,
+                    RusotoError<DisableSnapshotCopyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_logging: Option<
+        Box<
+            dyn Fn(
+                    EnableLoggingMessage,
+                )
+                    -> ::std::result::Result<LoggingStatus, RusotoError<EnableLoggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    enable_snapshot_copy: Option<
+        Box<
+            dyn Fn(
+                    EnableSnapshotCopyMessage,
+                ) -> ::std::result::Result<
+                    EnableSnapshotCopyResult,
+                    RusotoError<EnableSnapshotCopyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_cluster_credentials: Option<
+        Box<
+            dyn Fn(
+                    GetClusterCredentialsMessage,
+                ) -> ::std::result::Result<
+                    ClusterCredentials,
+                    RusotoError<GetClusterCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_reserved_node_exchange_offerings: Option<
+        Box<
+            dyn Fn(
+                    GetReservedNodeExchangeOfferingsInputMessage,
+                ) -> ::std::result::Result<
+                    GetReservedNodeExchangeOfferingsOutputMessage,
+                    RusotoError<GetReservedNodeExchangeOfferingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterMessage,
+                )
+                    -> ::std::result::Result<ModifyClusterResult, RusotoError<ModifyClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_db_revision: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterDbRevisionMessage,
+                ) -> ::std::result::Result<
+                    ModifyClusterDbRevisionResult,
+                    RusotoError<ModifyClusterDbRevisionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_iam_roles: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterIamRolesMessage,
+                ) -> ::std::result::Result<
+                    ModifyClusterIamRolesResult,
+                    RusotoError<ModifyClusterIamRolesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_maintenance: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterMaintenanceMessage,
+                ) -> ::std::result::Result<
+                    ModifyClusterMaintenanceResult,
+                    RusotoError<ModifyClusterMaintenanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    ClusterParameterGroupNameMessage,
+                    RusotoError<ModifyClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    ModifyClusterSnapshotResult,
+                    RusotoError<ModifyClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterSnapshotScheduleMessage,
+                )
+                    -> ::std::result::Result<(), RusotoError<ModifyClusterSnapshotScheduleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_cluster_subnet_group: Option<
+        Box<
+            dyn Fn(
+                    ModifyClusterSubnetGroupMessage,
+                ) -> ::std::result::Result<
+                    ModifyClusterSubnetGroupResult,
+                    RusotoError<ModifyClusterSubnetGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_event_subscription: Option<
+        Box<
+            dyn Fn(
+                    ModifyEventSubscriptionMessage,
+                ) -> ::std::result::Result<
+                    ModifyEventSubscriptionResult,
+                    RusotoError<ModifyEventSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_snapshot_copy_retention_period: Option<
+        Box<
+            dyn Fn(
+                    ModifySnapshotCopyRetentionPeriodMessage,
+                ) -> ::std::result::Result<
+                    ModifySnapshotCopyRetentionPeriodResult,
+                    RusotoError<ModifySnapshotCopyRetentionPeriodError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    ModifySnapshotScheduleMessage,
+                ) -> ::std::result::Result<
+                    SnapshotSchedule,
+                    RusotoError<ModifySnapshotScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    purchase_reserved_node_offering: Option<
+        Box<
+            dyn Fn(
+                    PurchaseReservedNodeOfferingMessage,
+                ) -> ::std::result::Result<
+                    PurchaseReservedNodeOfferingResult,
+                    RusotoError<PurchaseReservedNodeOfferingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reboot_cluster: Option<
+        Box<
+            dyn Fn(
+                    RebootClusterMessage,
+                )
+                    -> ::std::result::Result<RebootClusterResult, RusotoError<RebootClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    reset_cluster_parameter_group: Option<
+        Box<
+            dyn Fn(
+                    ResetClusterParameterGroupMessage,
+                ) -> ::std::result::Result<
+                    ClusterParameterGroupNameMessage,
+                    RusotoError<ResetClusterParameterGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resize_cluster: Option<
+        Box<
+            dyn Fn(
+                    ResizeClusterMessage,
+                )
+                    -> ::std::result::Result<ResizeClusterResult, RusotoError<ResizeClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    restore_from_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    RestoreFromClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    RestoreFromClusterSnapshotResult,
+                    RusotoError<RestoreFromClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_table_from_cluster_snapshot: Option<
+        Box<
+            dyn Fn(
+                    RestoreTableFromClusterSnapshotMessage,
+                ) -> ::std::result::Result<
+                    RestoreTableFromClusterSnapshotResult,
+                    RusotoError<RestoreTableFromClusterSnapshotError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    revoke_cluster_security_group_ingress: Option<
+        Box<
+            dyn Fn(
+                    RevokeClusterSecurityGroupIngressMessage,
+                ) -> ::std::result::Result<
+                    RevokeClusterSecurityGroupIngressResult,
+                    RusotoError<RevokeClusterSecurityGroupIngressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    revoke_snapshot_access: Option<
+        Box<
+            dyn Fn(
+                    RevokeSnapshotAccessMessage,
+                ) -> ::std::result::Result<
+                    RevokeSnapshotAccessResult,
+                    RusotoError<RevokeSnapshotAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    rotate_encryption_key: Option<
+        Box<
+            dyn Fn(
+                    RotateEncryptionKeyMessage,
+                ) -> ::std::result::Result<
+                    RotateEncryptionKeyResult,
+                    RusotoError<RotateEncryptionKeyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockRedshift {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockRedshift {
+        Default::default()
+    }
+
+    /// Answers `accept_reserved_node_exchange` with what `handler` returns for its input.
+    pub fn with_accept_reserved_node_exchange<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                AcceptReservedNodeExchangeInputMessage,
+            ) -> ::std::result::Result<
+                AcceptReservedNodeExchangeOutputMessage,
+                RusotoError<AcceptReservedNodeExchangeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_reserved_node_exchange = Some(Box::new(handler));
+        self
+    }
+    /// Answers `authorize_cluster_security_group_ingress` with what `handler` returns for its input.
+    pub fn with_authorize_cluster_security_group_ingress<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                AuthorizeClusterSecurityGroupIngressMessage,
+            ) -> ::std::result::Result<
+                AuthorizeClusterSecurityGroupIngressResult,
+                RusotoError<AuthorizeClusterSecurityGroupIngressError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.authorize_cluster_security_group_ingress = Some(Box::new(handler));
+        self
+    }
+    /// Answers `authorize_snapshot_access` with what `handler` returns for its input.
+    pub fn with_authorize_snapshot_access<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                AuthorizeSnapshotAccessMessage,
+            ) -> ::std::result::Result<
+                AuthorizeSnapshotAccessResult,
+                RusotoError<AuthorizeSnapshotAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.authorize_snapshot_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_delete_cluster_snapshots` with what `handler` returns for its input.
+    pub fn with_batch_delete_cluster_snapshots<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                BatchDeleteClusterSnapshotsRequest,
+            ) -> ::std::result::Result<
+                BatchDeleteClusterSnapshotsResult,
+                RusotoError<BatchDeleteClusterSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_cluster_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_modify_cluster_snapshots` with what `handler` returns for its input.
+    pub fn with_batch_modify_cluster_snapshots<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                BatchModifyClusterSnapshotsMessage,
+            ) -> ::std::result::Result<
+                BatchModifyClusterSnapshotsOutputMessage,
+                RusotoError<BatchModifyClusterSnapshotsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_modify_cluster_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_resize` with what `handler` returns for its input.
+    pub fn with_cancel_resize<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CancelResizeMessage,
+            )
+                -> ::std::result::Result<ResizeProgressMessage, RusotoError<CancelResizeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_resize = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_copy_cluster_snapshot<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CopyClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                CopyClusterSnapshotResult,
+                RusotoError<CopyClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateClusterMessage,
+            )
+                -> ::std::result::Result<CreateClusterResult, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_create_cluster_parameter_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                CreateClusterParameterGroupResult,
+                RusotoError<CreateClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster_security_group` with what `handler` returns for its input.
+    pub fn with_create_cluster_security_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateClusterSecurityGroupMessage,
+            ) -> ::std::result::Result<
+                CreateClusterSecurityGroupResult,
+                RusotoError<CreateClusterSecurityGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_create_cluster_snapshot<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                CreateClusterSnapshotResult,
+                RusotoError<CreateClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster_subnet_group` with what `handler` returns for its input.
+    pub fn with_create_cluster_subnet_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateClusterSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                CreateClusterSubnetGroupResult,
+                RusotoError<CreateClusterSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_event_subscription` with what `handler` returns for its input.
+    pub fn with_create_event_subscription<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateEventSubscriptionMessage,
+            ) -> ::std::result::Result<
+                CreateEventSubscriptionResult,
+                RusotoError<CreateEventSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_event_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hsm_client_certificate` with what `handler` returns for its input.
+    pub fn with_create_hsm_client_certificate<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateHsmClientCertificateMessage,
+            ) -> ::std::result::Result<
+                CreateHsmClientCertificateResult,
+                RusotoError<CreateHsmClientCertificateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hsm_client_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hsm_configuration` with what `handler` returns for its input.
+    pub fn with_create_hsm_configuration<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateHsmConfigurationMessage,
+            ) -> ::std::result::Result<
+                CreateHsmConfigurationResult,
+                RusotoError<CreateHsmConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hsm_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_snapshot_copy_grant` with what `handler` returns for its input.
+    pub fn with_create_snapshot_copy_grant<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateSnapshotCopyGrantMessage,
+            ) -> ::std::result::Result<
+                CreateSnapshotCopyGrantResult,
+                RusotoError<CreateSnapshotCopyGrantError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_snapshot_copy_grant = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_create_snapshot_schedule<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                CreateSnapshotScheduleMessage,
+            )
+                -> ::std::result::Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tags` with what `handler` returns for its input.
+    pub fn with_create_tags<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(CreateTagsMessage) -> ::std::result::Result<(), RusotoError<CreateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster` with what `handler` returns for its input.
+    pub fn with_delete_cluster<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteClusterMessage,
+            )
+                -> ::std::result::Result<DeleteClusterResult, RusotoError<DeleteClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_delete_cluster_parameter_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteClusterParameterGroupMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteClusterParameterGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster_security_group` with what `handler` returns for its input.
+    pub fn with_delete_cluster_security_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteClusterSecurityGroupMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteClusterSecurityGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster_security_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_delete_cluster_snapshot<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                DeleteClusterSnapshotResult,
+                RusotoError<DeleteClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_cluster_subnet_group` with what `handler` returns for its input.
+    pub fn with_delete_cluster_subnet_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteClusterSubnetGroupMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteClusterSubnetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_cluster_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_event_subscription` with what `handler` returns for its input.
+    pub fn with_delete_event_subscription<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteEventSubscriptionMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteEventSubscriptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_event_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hsm_client_certificate` with what `handler` returns for its input.
+    pub fn with_delete_hsm_client_certificate<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteHsmClientCertificateMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteHsmClientCertificateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hsm_client_certificate = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hsm_configuration` with what `handler` returns for its input.
+    pub fn with_delete_hsm_configuration<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteHsmConfigurationMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteHsmConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hsm_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_snapshot_copy_grant` with what `handler` returns for its input.
+    pub fn with_delete_snapshot_copy_grant<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteSnapshotCopyGrantMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteSnapshotCopyGrantError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_snapshot_copy_grant = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_delete_snapshot_schedule<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DeleteSnapshotScheduleMessage,
+            ) -> ::std::result::Result<(), RusotoError<DeleteSnapshotScheduleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(DeleteTagsMessage) -> ::std::result::Result<(), RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_attributes` with what `handler` returns for its input.
+    pub fn with_describe_account_attributes<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeAccountAttributesMessage,
+            ) -> ::std::result::Result<
+                AccountAttributeList,
+                RusotoError<DescribeAccountAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_db_revisions` with what `handler` returns for its input.
+    pub fn with_describe_cluster_db_revisions<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterDbRevisionsMessage,
+            ) -> ::std::result::Result<
+                ClusterDbRevisionsMessage,
+                RusotoError<DescribeClusterDbRevisionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_db_revisions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_parameter_groups` with what `handler` returns for its input.
+    pub fn with_describe_cluster_parameter_groups<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterParameterGroupsMessage,
+            ) -> ::std::result::Result<
+                ClusterParameterGroupsMessage,
+                RusotoError<DescribeClusterParameterGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_parameter_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_parameters` with what `handler` returns for its input.
+    pub fn with_describe_cluster_parameters<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterParametersMessage,
+            ) -> ::std::result::Result<
+                ClusterParameterGroupDetails,
+                RusotoError<DescribeClusterParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_security_groups` with what `handler` returns for its input.
+    pub fn with_describe_cluster_security_groups<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterSecurityGroupsMessage,
+            ) -> ::std::result::Result<
+                ClusterSecurityGroupMessage,
+                RusotoError<DescribeClusterSecurityGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_security_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_snapshots` with what `handler` returns for its input.
+    pub fn with_describe_cluster_snapshots<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterSnapshotsMessage,
+            )
+                -> ::std::result::Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_snapshots = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_subnet_groups` with what `handler` returns for its input.
+    pub fn with_describe_cluster_subnet_groups<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterSubnetGroupsMessage,
+            ) -> ::std::result::Result<
+                ClusterSubnetGroupMessage,
+                RusotoError<DescribeClusterSubnetGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_subnet_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_tracks` with what `handler` returns for its input.
+    pub fn with_describe_cluster_tracks<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterTracksMessage,
+            )
+                -> ::std::result::Result<TrackListMessage, RusotoError<DescribeClusterTracksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_tracks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster_versions` with what `handler` returns for its input.
+    pub fn with_describe_cluster_versions<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClusterVersionsMessage,
+            ) -> ::std::result::Result<
+                ClusterVersionsMessage,
+                RusotoError<DescribeClusterVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_clusters` with what `handler` returns for its input.
+    pub fn with_describe_clusters<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeClustersMessage,
+            )
+                -> ::std::result::Result<ClustersMessage, RusotoError<DescribeClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_default_cluster_parameters` with what `handler` returns for its input.
+    pub fn with_describe_default_cluster_parameters<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeDefaultClusterParametersMessage,
+            ) -> ::std::result::Result<
+                DescribeDefaultClusterParametersResult,
+                RusotoError<DescribeDefaultClusterParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_default_cluster_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_categories` with what `handler` returns for its input.
+    pub fn with_describe_event_categories<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeEventCategoriesMessage,
+            ) -> ::std::result::Result<
+                EventCategoriesMessage,
+                RusotoError<DescribeEventCategoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_categories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_event_subscriptions` with what `handler` returns for its input.
+    pub fn with_describe_event_subscriptions<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeEventSubscriptionsMessage,
+            ) -> ::std::result::Result<
+                EventSubscriptionsMessage,
+                RusotoError<DescribeEventSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_event_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_events` with what `handler` returns for its input.
+    pub fn with_describe_events<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeEventsMessage,
+            )
+                -> ::std::result::Result<EventsMessage, RusotoError<DescribeEventsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_events = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hsm_client_certificates` with what `handler` returns for its input.
+    pub fn with_describe_hsm_client_certificates<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeHsmClientCertificatesMessage,
+            ) -> ::std::result::Result<
+                HsmClientCertificateMessage,
+                RusotoError<DescribeHsmClientCertificatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hsm_client_certificates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hsm_configurations` with what `handler` returns for its input.
+    pub fn with_describe_hsm_configurations<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeHsmConfigurationsMessage,
+            ) -> ::std::result::Result<
+                HsmConfigurationMessage,
+                RusotoError<DescribeHsmConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hsm_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_logging_status` with what `handler` returns for its input.
+    pub fn with_describe_logging_status<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeLoggingStatusMessage,
+            )
+                -> ::std::result::Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_logging_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_orderable_cluster_options` with what `handler` returns for its input.
+    pub fn with_describe_orderable_cluster_options<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeOrderableClusterOptionsMessage,
+            ) -> ::std::result::Result<
+                OrderableClusterOptionsMessage,
+                RusotoError<DescribeOrderableClusterOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_orderable_cluster_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_reserved_node_offerings` with what `handler` returns for its input.
+    pub fn with_describe_reserved_node_offerings<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeReservedNodeOfferingsMessage,
+            ) -> ::std::result::Result<
+                ReservedNodeOfferingsMessage,
+                RusotoError<DescribeReservedNodeOfferingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_reserved_node_offerings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_reserved_nodes` with what `handler` returns for its input.
+    pub fn with_describe_reserved_nodes<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeReservedNodesMessage,
+            ) -> ::std::result::Result<
+                ReservedNodesMessage,
+                RusotoError<DescribeReservedNodesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_reserved_nodes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_resize` with what `handler` returns for its input.
+    pub fn with_describe_resize<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeResizeMessage,
+            )
+                -> ::std::result::Result<ResizeProgressMessage, RusotoError<DescribeResizeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_resize = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_snapshot_copy_grants` with what `handler` returns for its input.
+    pub fn with_describe_snapshot_copy_grants<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeSnapshotCopyGrantsMessage,
+            ) -> ::std::result::Result<
+                SnapshotCopyGrantMessage,
+                RusotoError<DescribeSnapshotCopyGrantsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_snapshot_copy_grants = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_snapshot_schedules` with what `handler` returns for its input.
+    pub fn with_describe_snapshot_schedules<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeSnapshotSchedulesMessage,
+            ) -> ::std::result::Result<
+                DescribeSnapshotSchedulesOutputMessage,
+                RusotoError<DescribeSnapshotSchedulesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_snapshot_schedules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_storage` with what `handler` returns.
+    pub fn with_describe_storage<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn() -> ::std::result::Result<CustomerStorageMessage, RusotoError<DescribeStorageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_storage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_table_restore_status` with what `handler` returns for its input.
+    pub fn with_describe_table_restore_status<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeTableRestoreStatusMessage,
+            ) -> ::std::result::Result<
+                TableRestoreStatusMessage,
+                RusotoError<DescribeTableRestoreStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_table_restore_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DescribeTagsMessage,
+            )
+                -> ::std::result::Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_logging` with what `handler` returns for its input.
+    pub fn with_disable_logging<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DisableLoggingMessage,
+            )
+                -> ::std::result::Result<LoggingStatus, RusotoError<DisableLoggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_snapshot_copy` with what `handler` returns for its input.
+    pub fn with_disable_snapshot_copy<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                DisableSnapshotCopyMessage,
+            ) -> ::std::result::Result<
+                DisableSnapshotCopyResult,
+                RusotoError<DisableSnapshotCopyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_snapshot_copy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_logging` with what `handler` returns for its input.
+    pub fn with_enable_logging<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                EnableLoggingMessage,
+            ) -> ::std::result::Result<LoggingStatus, RusotoError<EnableLoggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_snapshot_copy` with what `handler` returns for its input.
+    pub fn with_enable_snapshot_copy<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                EnableSnapshotCopyMessage,
+            ) -> ::std::result::Result<
+                EnableSnapshotCopyResult,
+                RusotoError<EnableSnapshotCopyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_snapshot_copy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cluster_credentials` with what `handler` returns for its input.
+    pub fn with_get_cluster_credentials<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                GetClusterCredentialsMessage,
+            )
+                -> ::std::result::Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cluster_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_reserved_node_exchange_offerings` with what `handler` returns for its input.
+    pub fn with_get_reserved_node_exchange_offerings<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                GetReservedNodeExchangeOfferingsInputMessage,
+            ) -> ::std::result::Result<
+                GetReservedNodeExchangeOfferingsOutputMessage,
+                RusotoError<GetReservedNodeExchangeOfferingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_reserved_node_exchange_offerings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster` with what `handler` returns for its input.
+    pub fn with_modify_cluster<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterMessage,
+            )
+                -> ::std::result::Result<ModifyClusterResult, RusotoError<ModifyClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_db_revision` with what `handler` returns for its input.
+    pub fn with_modify_cluster_db_revision<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterDbRevisionMessage,
+            ) -> ::std::result::Result<
+                ModifyClusterDbRevisionResult,
+                RusotoError<ModifyClusterDbRevisionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_db_revision = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_iam_roles` with what `handler` returns for its input.
+    pub fn with_modify_cluster_iam_roles<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterIamRolesMessage,
+            ) -> ::std::result::Result<
+                ModifyClusterIamRolesResult,
+                RusotoError<ModifyClusterIamRolesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_iam_roles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_maintenance` with what `handler` returns for its input.
+    pub fn with_modify_cluster_maintenance<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterMaintenanceMessage,
+            ) -> ::std::result::Result<
+                ModifyClusterMaintenanceResult,
+                RusotoError<ModifyClusterMaintenanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_maintenance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_modify_cluster_parameter_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                ClusterParameterGroupNameMessage,
+                RusotoError<ModifyClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_modify_cluster_snapshot<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                ModifyClusterSnapshotResult,
+                RusotoError<ModifyClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_modify_cluster_snapshot_schedule<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterSnapshotScheduleMessage,
+            )
+                -> ::std::result::Result<(), RusotoError<ModifyClusterSnapshotScheduleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_cluster_subnet_group` with what `handler` returns for its input.
+    pub fn with_modify_cluster_subnet_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyClusterSubnetGroupMessage,
+            ) -> ::std::result::Result<
+                ModifyClusterSubnetGroupResult,
+                RusotoError<ModifyClusterSubnetGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_cluster_subnet_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_event_subscription` with what `handler` returns for its input.
+    pub fn with_modify_event_subscription<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifyEventSubscriptionMessage,
+            ) -> ::std::result::Result<
+                ModifyEventSubscriptionResult,
+                RusotoError<ModifyEventSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_event_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_snapshot_copy_retention_period` with what `handler` returns for its input.
+    pub fn with_modify_snapshot_copy_retention_period<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifySnapshotCopyRetentionPeriodMessage,
+            ) -> ::std::result::Result<
+                ModifySnapshotCopyRetentionPeriodResult,
+                RusotoError<ModifySnapshotCopyRetentionPeriodError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_snapshot_copy_retention_period = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_modify_snapshot_schedule<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ModifySnapshotScheduleMessage,
+            )
+                -> ::std::result::Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `purchase_reserved_node_offering` with what `handler` returns for its input.
+    pub fn with_purchase_reserved_node_offering<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                PurchaseReservedNodeOfferingMessage,
+            ) -> ::std::result::Result<
+                PurchaseReservedNodeOfferingResult,
+                RusotoError<PurchaseReservedNodeOfferingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.purchase_reserved_node_offering = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_cluster` with what `handler` returns for its input.
+    pub fn with_reboot_cluster<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                RebootClusterMessage,
+            )
+                -> ::std::result::Result<RebootClusterResult, RusotoError<RebootClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_cluster_parameter_group` with what `handler` returns for its input.
+    pub fn with_reset_cluster_parameter_group<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ResetClusterParameterGroupMessage,
+            ) -> ::std::result::Result<
+                ClusterParameterGroupNameMessage,
+                RusotoError<ResetClusterParameterGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_cluster_parameter_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resize_cluster` with what `handler` returns for its input.
+    pub fn with_resize_cluster<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                ResizeClusterMessage,
+            )
+                -> ::std::result::Result<ResizeClusterResult, RusotoError<ResizeClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resize_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_from_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_restore_from_cluster_snapshot<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                RestoreFromClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                RestoreFromClusterSnapshotResult,
+                RusotoError<RestoreFromClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_from_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_table_from_cluster_snapshot` with what `handler` returns for its input.
+    pub fn with_restore_table_from_cluster_snapshot<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                RestoreTableFromClusterSnapshotMessage,
+            ) -> ::std::result::Result<
+                RestoreTableFromClusterSnapshotResult,
+                RusotoError<RestoreTableFromClusterSnapshotError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_table_from_cluster_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_cluster_security_group_ingress` with what `handler` returns for its input.
+    pub fn with_revoke_cluster_security_group_ingress<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                RevokeClusterSecurityGroupIngressMessage,
+            ) -> ::std::result::Result<
+                RevokeClusterSecurityGroupIngressResult,
+                RusotoError<RevokeClusterSecurityGroupIngressError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_cluster_security_group_ingress = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_snapshot_access` with what `handler` returns for its input.
+    pub fn with_revoke_snapshot_access<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                RevokeSnapshotAccessMessage,
+            ) -> ::std::result::Result<
+                RevokeSnapshotAccessResult,
+                RusotoError<RevokeSnapshotAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_snapshot_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rotate_encryption_key` with what `handler` returns for its input.
+    pub fn with_rotate_encryption_key<F>(mut self, handler: F) -> MockRedshift
+    where
+        F: Fn(
+                RotateEncryptionKeyMessage,
+            ) -> ::std::result::Result<
+                RotateEncryptionKeyResult,
+                RusotoError<RotateEncryptionKeyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.rotate_encryption_key = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Redshift for MockRedshift {
+    fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> RusotoFuture<AcceptReservedNodeExchangeOutputMessage, AcceptReservedNodeExchangeError>
+    {
+        match self.accept_reserved_node_exchange {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for accept_reserved_node_exchange"),
+        }
+    }
+    fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeClusterSecurityGroupIngressResult,
+        AuthorizeClusterSecurityGroupIngressError,
+    > {
+        match self.authorize_cluster_security_group_ingress {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockRedshift has no handler for authorize_cluster_security_group_ingress")
+            }
+        }
+    }
+    fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> RusotoFuture<AuthorizeSnapshotAccessResult, AuthorizeSnapshotAccessError> {
+        match self.authorize_snapshot_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for authorize_snapshot_access"),
+        }
+    }
+    fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> RusotoFuture<BatchDeleteClusterSnapshotsResult, BatchDeleteClusterSnapshotsError> {
+        match self.batch_delete_cluster_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for batch_delete_cluster_snapshots"),
+        }
+    }
+    fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> RusotoFuture<BatchModifyClusterSnapshotsOutputMessage, BatchModifyClusterSnapshotsError>
+    {
+        match self.batch_modify_cluster_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for batch_modify_cluster_snapshots"),
+        }
+    }
+    fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, CancelResizeError> {
+        match self.cancel_resize {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for cancel_resize"),
+        }
+    }
+    fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyClusterSnapshotResult, CopyClusterSnapshotError> {
+        match self.copy_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for copy_cluster_snapshot"),
+        }
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_cluster"),
+        }
+    }
+    fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateClusterParameterGroupResult, CreateClusterParameterGroupError> {
+        match self.create_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_cluster_parameter_group"),
+        }
+    }
+    fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> RusotoFuture<CreateClusterSecurityGroupResult, CreateClusterSecurityGroupError> {
+        match self.create_cluster_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_cluster_security_group"),
+        }
+    }
+    fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateClusterSnapshotResult, CreateClusterSnapshotError> {
+        match self.create_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_cluster_snapshot"),
+        }
+    }
+    fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> RusotoFuture<CreateClusterSubnetGroupResult, CreateClusterSubnetGroupError> {
+        match self.create_cluster_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_cluster_subnet_group"),
+        }
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        match self.create_event_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_event_subscription"),
+        }
+    }
+    fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> RusotoFuture<CreateHsmClientCertificateResult, CreateHsmClientCertificateError> {
+        match self.create_hsm_client_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_hsm_client_certificate"),
+        }
+    }
+    fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> RusotoFuture<CreateHsmConfigurationResult, CreateHsmConfigurationError> {
+        match self.create_hsm_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_hsm_configuration"),
+        }
+    }
+    fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<CreateSnapshotCopyGrantResult, CreateSnapshotCopyGrantError> {
+        match self.create_snapshot_copy_grant {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_snapshot_copy_grant"),
+        }
+    }
+    fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, CreateSnapshotScheduleError> {
+        match self.create_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_snapshot_schedule"),
+        }
+    }
+    fn create_tags(&self, input: CreateTagsMessage) -> RusotoFuture<(), CreateTagsError> {
+        match self.create_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for create_tags"),
+        }
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> RusotoFuture<DeleteClusterResult, DeleteClusterError> {
+        match self.delete_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_cluster"),
+        }
+    }
+    fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterParameterGroupError> {
+        match self.delete_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_cluster_parameter_group"),
+        }
+    }
+    fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSecurityGroupError> {
+        match self.delete_cluster_security_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_cluster_security_group"),
+        }
+    }
+    fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteClusterSnapshotResult, DeleteClusterSnapshotError> {
+        match self.delete_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_cluster_snapshot"),
+        }
+    }
+    fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSubnetGroupError> {
+        match self.delete_cluster_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_cluster_subnet_group"),
+        }
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<(), DeleteEventSubscriptionError> {
+        match self.delete_event_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_event_subscription"),
+        }
+    }
+    fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> RusotoFuture<(), DeleteHsmClientCertificateError> {
+        match self.delete_hsm_client_certificate {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_hsm_client_certificate"),
+        }
+    }
+    fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteHsmConfigurationError> {
+        match self.delete_hsm_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_hsm_configuration"),
+        }
+    }
+    fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotCopyGrantError> {
+        match self.delete_snapshot_copy_grant {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_snapshot_copy_grant"),
+        }
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotScheduleError> {
+        match self.delete_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_snapshot_schedule"),
+        }
+    }
+    fn delete_tags(&self, input: DeleteTagsMessage) -> RusotoFuture<(), DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for delete_tags"),
+        }
+    }
+    fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> RusotoFuture<AccountAttributeList, DescribeAccountAttributesError> {
+        match self.describe_account_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_account_attributes"),
+        }
+    }
+    fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> RusotoFuture<ClusterDbRevisionsMessage, DescribeClusterDbRevisionsError> {
+        match self.describe_cluster_db_revisions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_db_revisions"),
+        }
+    }
+    fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> RusotoFuture<ClusterParameterGroupsMessage, DescribeClusterParameterGroupsError> {
+        match self.describe_cluster_parameter_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_parameter_groups"),
+        }
+    }
+    fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> RusotoFuture<ClusterParameterGroupDetails, DescribeClusterParametersError> {
+        match self.describe_cluster_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_parameters"),
+        }
+    }
+    fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> RusotoFuture<ClusterSecurityGroupMessage, DescribeClusterSecurityGroupsError> {
+        match self.describe_cluster_security_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_security_groups"),
+        }
+    }
+    fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> RusotoFuture<SnapshotMessage, DescribeClusterSnapshotsError> {
+        match self.describe_cluster_snapshots {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_snapshots"),
+        }
+    }
+    fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> RusotoFuture<ClusterSubnetGroupMessage, DescribeClusterSubnetGroupsError> {
+        match self.describe_cluster_subnet_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_subnet_groups"),
+        }
+    }
+    fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> RusotoFuture<TrackListMessage, DescribeClusterTracksError> {
+        match self.describe_cluster_tracks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_tracks"),
+        }
+    }
+    fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> RusotoFuture<ClusterVersionsMessage, DescribeClusterVersionsError> {
+        match self.describe_cluster_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_cluster_versions"),
+        }
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> RusotoFuture<ClustersMessage, DescribeClustersError> {
+        match self.describe_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_clusters"),
+        }
+    }
+    fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> RusotoFuture<DescribeDefaultClusterParametersResult, DescribeDefaultClusterParametersError>
+    {
+        match self.describe_default_cluster_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_default_cluster_parameters"),
+        }
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        match self.describe_event_categories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_event_categories"),
+        }
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        match self.describe_event_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_event_subscriptions"),
+        }
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        match self.describe_events {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_events"),
+        }
+    }
+    fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> RusotoFuture<HsmClientCertificateMessage, DescribeHsmClientCertificatesError> {
+        match self.describe_hsm_client_certificates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_hsm_client_certificates"),
+        }
+    }
+    fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> RusotoFuture<HsmConfigurationMessage, DescribeHsmConfigurationsError> {
+        match self.describe_hsm_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_hsm_configurations"),
+        }
+    }
+    fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> RusotoFuture<LoggingStatus, DescribeLoggingStatusError> {
+        match self.describe_logging_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_logging_status"),
+        }
+    }
+    fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> RusotoFuture<OrderableClusterOptionsMessage, DescribeOrderableClusterOptionsError> {
+        match self.describe_orderable_cluster_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_orderable_cluster_options"),
+        }
+    }
+    fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> RusotoFuture<ReservedNodeOfferingsMessage, DescribeReservedNodeOfferingsError> {
+        match self.describe_reserved_node_offerings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_reserved_node_offerings"),
+        }
+    }
+    fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> RusotoFuture<ReservedNodesMessage, DescribeReservedNodesError> {
+        match self.describe_reserved_nodes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_reserved_nodes"),
+        }
+    }
+    fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, DescribeResizeError> {
+        match self.describe_resize {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_resize"),
+        }
+    }
+    fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> RusotoFuture<SnapshotCopyGrantMessage, DescribeSnapshotCopyGrantsError> {
+        match self.describe_snapshot_copy_grants {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_snapshot_copy_grants"),
+        }
+    }
+    fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> RusotoFuture<DescribeSnapshotSchedulesOutputMessage, DescribeSnapshotSchedulesError> {
+        match self.describe_snapshot_schedules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_snapshot_schedules"),
+        }
+    }
+    fn describe_storage(&self) -> RusotoFuture<CustomerStorageMessage, DescribeStorageError> {
+        match self.describe_storage {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockRedshift has no handler for describe_storage"),
+        }
+    }
+    fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> RusotoFuture<TableRestoreStatusMessage, DescribeTableRestoreStatusError> {
+        match self.describe_table_restore_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_table_restore_status"),
+        }
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> RusotoFuture<TaggedResourceListMessage, DescribeTagsError> {
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for describe_tags"),
+        }
+    }
+    fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, DisableLoggingError> {
+        match self.disable_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for disable_logging"),
+        }
+    }
+    fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> RusotoFuture<DisableSnapshotCopyResult, DisableSnapshotCopyError> {
+        match self.disable_snapshot_copy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for disable_snapshot_copy"),
+        }
+    }
+    fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, EnableLoggingError> {
+        match self.enable_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for enable_logging"),
+        }
+    }
+    fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> RusotoFuture<EnableSnapshotCopyResult, EnableSnapshotCopyError> {
+        match self.enable_snapshot_copy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for enable_snapshot_copy"),
+        }
+    }
+    fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> RusotoFuture<ClusterCredentials, GetClusterCredentialsError> {
+        match self.get_cluster_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for get_cluster_credentials"),
+        }
+    }
+    fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> RusotoFuture<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        match self.get_reserved_node_exchange_offerings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for get_reserved_node_exchange_offerings"),
+        }
+    }
+    fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> RusotoFuture<ModifyClusterResult, ModifyClusterError> {
+        match self.modify_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster"),
+        }
+    }
+    fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> RusotoFuture<ModifyClusterDbRevisionResult, ModifyClusterDbRevisionError> {
+        match self.modify_cluster_db_revision {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_db_revision"),
+        }
+    }
+    fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> RusotoFuture<ModifyClusterIamRolesResult, ModifyClusterIamRolesError> {
+        match self.modify_cluster_iam_roles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_iam_roles"),
+        }
+    }
+    fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> RusotoFuture<ModifyClusterMaintenanceResult, ModifyClusterMaintenanceError> {
+        match self.modify_cluster_maintenance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_maintenance"),
+        }
+    }
+    fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ModifyClusterParameterGroupError> {
+        match self.modify_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_parameter_group"),
+        }
+    }
+    fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> RusotoFuture<ModifyClusterSnapshotResult, ModifyClusterSnapshotError> {
+        match self.modify_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_snapshot"),
+        }
+    }
+    fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), ModifyClusterSnapshotScheduleError> {
+        match self.modify_cluster_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_snapshot_schedule"),
+        }
+    }
+    fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyClusterSubnetGroupResult, ModifyClusterSubnetGroupError> {
+        match self.modify_cluster_subnet_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_cluster_subnet_group"),
+        }
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        match self.modify_event_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_event_subscription"),
+        }
+    }
+    fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> RusotoFuture<ModifySnapshotCopyRetentionPeriodResult, ModifySnapshotCopyRetentionPeriodError>
+    {
+        match self.modify_snapshot_copy_retention_period {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_snapshot_copy_retention_period"),
+        }
+    }
+    fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, ModifySnapshotScheduleError> {
+        match self.modify_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for modify_snapshot_schedule"),
+        }
+    }
+    fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> RusotoFuture<PurchaseReservedNodeOfferingResult, PurchaseReservedNodeOfferingError> {
+        match self.purchase_reserved_node_offering {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for purchase_reserved_node_offering"),
+        }
+    }
+    fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> RusotoFuture<RebootClusterResult, RebootClusterError> {
+        match self.reboot_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for reboot_cluster"),
+        }
+    }
+    fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ResetClusterParameterGroupError> {
+        match self.reset_cluster_parameter_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for reset_cluster_parameter_group"),
+        }
+    }
+    fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> RusotoFuture<ResizeClusterResult, ResizeClusterError> {
+        match self.resize_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for resize_cluster"),
+        }
     }
-
-    /// <p>Creates a new cluster from a snapshot. By default, Amazon Redshift creates the resulting cluster with the same configuration as the original cluster from which the snapshot was created, except that the new cluster is created with the default cluster security and parameter groups. After Amazon Redshift creates the cluster, you can use the <a>ModifyCluster</a> API to associate a different security group and different parameter group with the restored cluster. If you are using a DS node type, you can also choose to change to another DS node type of the same size during restore.</p> <p>If you restore a cluster into a VPC, you must provide a cluster subnet group where you want the cluster restored.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn restore_from_cluster_snapshot(
         &self,
         input: RestoreFromClusterSnapshotMessage,
     ) -> RusotoFuture<RestoreFromClusterSnapshotResult, RestoreFromClusterSnapshotError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RestoreFromClusterSnapshot");
-        params.put("Version", "2012-12-01");
-        RestoreFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RestoreFromClusterSnapshotError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RestoreFromClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RestoreFromClusterSnapshotResultDeserializer::deserialize(
-                        "RestoreFromClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.restore_from_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for restore_from_cluster_snapshot"),
+        }
     }
-
-    /// <p>Creates a new table from a table in an Amazon Redshift cluster snapshot. You must create the new table within the Amazon Redshift cluster that the snapshot was taken from.</p> <p>You cannot use <code>RestoreTableFromClusterSnapshot</code> to restore a table with the same name as an existing table in an Amazon Redshift cluster. That is, you cannot overwrite an existing table in a cluster with a restored table. If you want to replace your original table with a new, restored table, then rename or drop your original table before you call <code>RestoreTableFromClusterSnapshot</code>. When you have renamed your original table, then you can pass the original name of the table as the <code>NewTableName</code> parameter value in the call to <code>RestoreTableFromClusterSnapshot</code>. This way, you can replace the original table with the table created from the snapshot.</p>
     fn restore_table_from_cluster_snapshot(
         &self,
         input: RestoreTableFromClusterSnapshotMessage,
     ) -> RusotoFuture<RestoreTableFromClusterSnapshotResult, RestoreTableFromClusterSnapshotError>
     {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RestoreTableFromClusterSnapshot");
-        params.put("Version", "2012-12-01");
-        RestoreTableFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RestoreTableFromClusterSnapshotError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RestoreTableFromClusterSnapshotResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RestoreTableFromClusterSnapshotResultDeserializer::deserialize(
-                        "RestoreTableFromClusterSnapshotResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.restore_table_from_cluster_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for restore_table_from_cluster_snapshot"),
+        }
     }
-
-    /// <p>Revokes an ingress rule in an Amazon Redshift security group for a previously authorized IP range or Amazon EC2 security group. To add an ingress rule, see <a>AuthorizeClusterSecurityGroupIngress</a>. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
     fn revoke_cluster_security_group_ingress(
         &self,
         input: RevokeClusterSecurityGroupIngressMessage,
     ) -> RusotoFuture<RevokeClusterSecurityGroupIngressResult, RevokeClusterSecurityGroupIngressError>
     {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RevokeClusterSecurityGroupIngress");
-        params.put("Version", "2012-12-01");
-        RevokeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RevokeClusterSecurityGroupIngressError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RevokeClusterSecurityGroupIngressResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RevokeClusterSecurityGroupIngressResultDeserializer::deserialize(
-                        "RevokeClusterSecurityGroupIngressResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.revoke_cluster_security_group_ingress {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for revoke_cluster_security_group_ingress"),
+        }
     }
-
-    /// <p>Removes the ability of the specified AWS customer account to restore the specified snapshot. If the account is currently restoring the snapshot, the restore will run to completion.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
     fn revoke_snapshot_access(
         &self,
         input: RevokeSnapshotAccessMessage,
     ) -> RusotoFuture<RevokeSnapshotAccessResult, RevokeSnapshotAccessError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RevokeSnapshotAccess");
-        params.put("Version", "2012-12-01");
-        RevokeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RevokeSnapshotAccessError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RevokeSnapshotAccessResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RevokeSnapshotAccessResultDeserializer::deserialize(
-                        "RevokeSnapshotAccessResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.revoke_snapshot_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for revoke_snapshot_access"),
+        }
     }
-
-    /// <p>Rotates the encryption keys for a cluster.</p>
     fn rotate_encryption_key(
         &self,
         input: RotateEncryptionKeyMessage,
     ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError> {
-        let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RotateEncryptionKey");
-        params.put("Version", "2012-12-01");
-        RotateEncryptionKeyMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RotateEncryptionKeyError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = RotateEncryptionKeyResult::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = RotateEncryptionKeyResultDeserializer::deserialize(
-                        "RotateEncryptionKeyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.rotate_encryption_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRedshift has no handler for rotate_encryption_key"),
+        }
     }
 }
 
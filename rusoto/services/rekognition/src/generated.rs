@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Structure containing the estimated age range, in years, for a face.</p> <p>Amazon Rekognition estimates an age range for faces detected in the input image. Estimated age ranges can overlap. A face of a 5-year-old might have an estimated range of 4-6, while the face of a 6-year-old might have an estimated range of 4-8.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AgeRange {
     /// <p>The highest estimated age.</p>
     #[serde(rename = "High")]
@@ -39,7 +40,7 @@ pub struct AgeRange {
 
 /// <p>Indicates whether or not the face has a beard, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Beard {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -53,7 +54,7 @@ pub struct Beard {
 
 /// <p><p>Identifies the bounding box around the label, face, or text. The <code>left</code> (x-coordinate) and <code>top</code> (y-coordinate) are coordinates representing the top and left sides of the bounding box. Note that the upper-left corner of the image is the origin (0,0). </p> <p>The <code>top</code> and <code>left</code> values returned are ratios of the overall image size. For example, if the input image is 700x200 pixels, and the top-left coordinate of the bounding box is 350x50 pixels, the API returns a <code>left</code> value of 0.5 (350/700) and a <code>top</code> value of 0.25 (50/200).</p> <p>The <code>width</code> and <code>height</code> values represent the dimensions of the bounding box as a ratio of the overall image dimension. For example, if the input image is 700x200 pixels, and the bounding box width is 70 pixels, the width returned is 0.1. </p> <note> <p> The bounding box coordinates can have negative values. For example, if Amazon Rekognition is able to detect a face that is at the image edge and is only partially visible, the service can return coordinates that are outside the image bounds and, depending on the image edge, you might get negative values or values greater than 1 for the <code>left</code> or <code>top</code> values. </p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BoundingBox {
     /// <p>Height of the bounding box as a ratio of the overall image height.</p>
     #[serde(rename = "Height")]
@@ -75,7 +76,7 @@ pub struct BoundingBox {
 
 /// <p>Provides information about a celebrity recognized by the <a>RecognizeCelebrities</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Celebrity {
     /// <p>Provides information about the celebrity's face, such as its location on the image.</p>
     #[serde(rename = "Face")]
@@ -101,7 +102,7 @@ pub struct Celebrity {
 
 /// <p>Information about a recognized celebrity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CelebrityDetail {
     /// <p>Bounding box around the body of a celebrity.</p>
     #[serde(rename = "BoundingBox")]
@@ -131,7 +132,7 @@ pub struct CelebrityDetail {
 
 /// <p>Information about a detected celebrity and the time the celebrity was detected in a stored video. For more information, see GetCelebrityRecognition in the Amazon Rekognition Developer Guide.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CelebrityRecognition {
     /// <p>Information about a recognized celebrity.</p>
     #[serde(rename = "Celebrity")]
@@ -145,7 +146,7 @@ pub struct CelebrityRecognition {
 
 /// <p>Provides information about a face in a target image that matches the source image face analyzed by <code>CompareFaces</code>. The <code>Face</code> property contains the bounding box of the face in the target image. The <code>Similarity</code> property is the confidence that the source image face matches the face in the bounding box.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompareFacesMatch {
     /// <p>Provides face metadata (bounding box and confidence that the bounding box actually contains a face).</p>
     #[serde(rename = "Face")]
@@ -158,6 +159,7 @@ pub struct CompareFacesMatch {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompareFacesRequest {
     /// <p>The minimum level of confidence in the face matches that a match must meet to be included in the <code>FaceMatches</code> array.</p>
     #[serde(rename = "SimilarityThreshold")]
@@ -171,8 +173,25 @@ pub struct CompareFacesRequest {
     pub target_image: Image,
 }
 
+impl Validate for CompareFacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.similarity_threshold {
+            errors.check_range(
+                &field_path(path, "similarity_threshold"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+        self.source_image
+            .validate_at(&field_path(path, "source_image"), errors);
+        self.target_image
+            .validate_at(&field_path(path, "target_image"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompareFacesResponse {
     /// <p>An array of faces in the target image that match the source image face. Each <code>CompareFacesMatch</code> object provides the bounding box, the confidence level that the bounding box contains a face, and the similarity score for the face in the bounding box and the face in the source image.</p>
     #[serde(rename = "FaceMatches")]
@@ -198,7 +217,7 @@ pub struct CompareFacesResponse {
 
 /// <p>Provides face metadata for target image faces that are analyzed by <code>CompareFaces</code> and <code>RecognizeCelebrities</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComparedFace {
     /// <p>Bounding box of the face.</p>
     #[serde(rename = "BoundingBox")]
@@ -224,7 +243,7 @@ pub struct ComparedFace {
 
 /// <p>Type that describes the face Amazon Rekognition chose to compare with the faces in the target. This contains a bounding box for the selected face and confidence level that the bounding box contains a face. Note that Amazon Rekognition selects the largest face in the source image for this comparison. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComparedSourceImageFace {
     /// <p>Bounding box of the face.</p>
     #[serde(rename = "BoundingBox")]
@@ -238,7 +257,7 @@ pub struct ComparedSourceImageFace {
 
 /// <p>Information about an unsafe content label detection in a stored video.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContentModerationDetection {
     /// <p>The unsafe content label detected by in the stored video.</p>
     #[serde(rename = "ModerationLabel")]
@@ -251,14 +270,31 @@ pub struct ContentModerationDetection {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCollectionRequest {
     /// <p>ID for the collection that you are creating.</p>
     #[serde(rename = "CollectionId")]
     pub collection_id: String,
 }
 
+impl Validate for CreateCollectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCollectionResponse {
     /// <p>Amazon Resource Name (ARN) of the collection. You can use this to manage permissions on your resources. </p>
     #[serde(rename = "CollectionArn")]
@@ -275,6 +311,7 @@ pub struct CreateCollectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStreamProcessorRequest {
     /// <p>Kinesis video stream stream that provides the source streaming video. If you are using the AWS CLI, the parameter name is <code>StreamProcessorInput</code>.</p>
     #[serde(rename = "Input")]
@@ -293,8 +330,29 @@ pub struct CreateStreamProcessorRequest {
     pub settings: StreamProcessorSettings,
 }
 
+impl Validate for CreateStreamProcessorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.input.validate_at(&field_path(path, "input"), errors);
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_.\\-]+");
+        self.output.validate_at(&field_path(path, "output"), errors);
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+        self.settings
+            .validate_at(&field_path(path, "settings"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStreamProcessorResponse {
     /// <p>ARN for the newly create stream processor.</p>
     #[serde(rename = "StreamProcessorArn")]
@@ -303,14 +361,31 @@ pub struct CreateStreamProcessorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCollectionRequest {
     /// <p>ID of the collection to delete.</p>
     #[serde(rename = "CollectionId")]
     pub collection_id: String,
 }
 
+impl Validate for DeleteCollectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCollectionResponse {
     /// <p>HTTP status code that indicates the result of the operation.</p>
     #[serde(rename = "StatusCode")]
@@ -319,6 +394,7 @@ pub struct DeleteCollectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFacesRequest {
     /// <p>Collection from which to remove the specific faces.</p>
     #[serde(rename = "CollectionId")]
@@ -328,8 +404,38 @@ pub struct DeleteFacesRequest {
     pub face_ids: Vec<String>,
 }
 
+impl Validate for DeleteFacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+        errors.check_length(
+            &field_path(path, "face_ids"),
+            self.face_ids.len(),
+            Some(1),
+            Some(4096),
+        );
+        for (i, item) in self.face_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "face_ids"), i);
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFacesResponse {
     /// <p>An array of strings (face IDs) of the faces that were deleted.</p>
     #[serde(rename = "DeletedFaces")]
@@ -338,25 +444,55 @@ pub struct DeleteFacesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStreamProcessorRequest {
     /// <p>The name of the stream processor you want to delete.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteStreamProcessorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_.\\-]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteStreamProcessorResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCollectionRequest {
     /// <p>The ID of the collection to describe.</p>
     #[serde(rename = "CollectionId")]
     pub collection_id: String,
 }
 
+impl Validate for DescribeCollectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCollectionResponse {
     /// <p>The Amazon Resource Name (ARN) of the collection.</p>
     #[serde(rename = "CollectionARN")]
@@ -377,14 +513,27 @@ pub struct DescribeCollectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStreamProcessorRequest {
     /// <p>Name of the stream processor for which you want information.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DescribeStreamProcessorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_.\\-]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStreamProcessorResponse {
     /// <p>Date and time the stream processor was created</p>
     #[serde(rename = "CreationTimestamp")]
@@ -429,6 +578,7 @@ pub struct DescribeStreamProcessorResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectFacesRequest {
     /// <p>An array of facial attributes you want to be returned. This can be the default list of attributes or all attributes. If you don't specify a value for <code>Attributes</code> or if you specify <code>["DEFAULT"]</code>, the API returns the following subset of facial attributes: <code>BoundingBox</code>, <code>Confidence</code>, <code>Pose</code>, <code>Quality</code>, and <code>Landmarks</code>. If you provide <code>["ALL"]</code>, all facial attributes are returned, but the operation takes longer to complete.</p> <p>If you provide both, <code>["ALL", "DEFAULT"]</code>, the service uses a logical AND operator to determine which attributes to return (in this case, all attributes). </p>
     #[serde(rename = "Attributes")]
@@ -439,8 +589,14 @@ pub struct DetectFacesRequest {
     pub image: Image,
 }
 
+impl Validate for DetectFacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.image.validate_at(&field_path(path, "image"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectFacesResponse {
     /// <p>Details of each face found in the image. </p>
     #[serde(rename = "FaceDetails")]
@@ -453,6 +609,7 @@ pub struct DetectFacesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectLabelsRequest {
     /// <p>The input image as base64-encoded bytes or an S3 object. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. Images stored in an S3 Bucket do not need to be base64-encoded.</p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
     #[serde(rename = "Image")]
@@ -467,8 +624,22 @@ pub struct DetectLabelsRequest {
     pub min_confidence: Option<f32>,
 }
 
+impl Validate for DetectLabelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.image.validate_at(&field_path(path, "image"), errors);
+        if let Some(ref value) = self.min_confidence {
+            errors.check_range(
+                &field_path(path, "min_confidence"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectLabelsResponse {
     /// <p>Version number of the label detection model that was used to detect labels.</p>
     #[serde(rename = "LabelModelVersion")]
@@ -485,6 +656,7 @@ pub struct DetectLabelsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectModerationLabelsRequest {
     /// <p>The input image as base64-encoded bytes or an S3 object. If you use the AWS CLI to call Amazon Rekognition operations, passing base64-encoded image bytes is not supported. </p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
     #[serde(rename = "Image")]
@@ -495,8 +667,22 @@ pub struct DetectModerationLabelsRequest {
     pub min_confidence: Option<f32>,
 }
 
+impl Validate for DetectModerationLabelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.image.validate_at(&field_path(path, "image"), errors);
+        if let Some(ref value) = self.min_confidence {
+            errors.check_range(
+                &field_path(path, "min_confidence"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectModerationLabelsResponse {
     /// <p>Array of detected Moderation labels and the time, in milliseconds from the start of the video, they were detected.</p>
     #[serde(rename = "ModerationLabels")]
@@ -509,14 +695,21 @@ pub struct DetectModerationLabelsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectTextRequest {
     /// <p>The input image as base64-encoded bytes or an Amazon S3 object. If you use the AWS CLI to call Amazon Rekognition operations, you can't pass image bytes. </p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
     #[serde(rename = "Image")]
     pub image: Image,
 }
 
+impl Validate for DetectTextRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.image.validate_at(&field_path(path, "image"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectTextResponse {
     /// <p>An array of text that was detected in the input image.</p>
     #[serde(rename = "TextDetections")]
@@ -526,7 +719,7 @@ pub struct DetectTextResponse {
 
 /// <p>The emotions that appear to be expressed on the face, and the confidence level in the determination. The API is only making a determination of the physical appearance of a person's face. It is not a determination of the person’s internal emotional state and should not be used in such a way. For example, a person pretending to have a sad face might not be sad emotionally.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Emotion {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -540,7 +733,7 @@ pub struct Emotion {
 
 /// <p>Indicates whether or not the eyes on the face are open, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EyeOpen {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -554,7 +747,7 @@ pub struct EyeOpen {
 
 /// <p>Indicates whether or not the face is wearing eye glasses, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Eyeglasses {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -568,7 +761,7 @@ pub struct Eyeglasses {
 
 /// <p>Describes the face properties such as the bounding box, face ID, image ID of the input image, and external image ID that you assigned. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Face {
     /// <p>Bounding box of the face.</p>
     #[serde(rename = "BoundingBox")]
@@ -594,7 +787,7 @@ pub struct Face {
 
 /// <p>Structure containing attributes of the face that the algorithm detected.</p> <p>A <code>FaceDetail</code> object contains either the default facial attributes or all facial attributes. The default attributes are <code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>.</p> <p> <a>GetFaceDetection</a> is the only Amazon Rekognition Video stored video operation that can return a <code>FaceDetail</code> object with all attributes. To specify which attributes to return, use the <code>FaceAttributes</code> input parameter for <a>StartFaceDetection</a>. The following Amazon Rekognition Video operations return only the default attributes. The corresponding Start operations don't have a <code>FaceAttributes</code> input parameter.</p> <ul> <li> <p>GetCelebrityRecognition</p> </li> <li> <p>GetPersonTracking</p> </li> <li> <p>GetFaceSearch</p> </li> </ul> <p>The Amazon Rekognition Image <a>DetectFaces</a> and <a>IndexFaces</a> operations can return all facial attributes. To specify which attributes to return, use the <code>Attributes</code> input parameter for <code>DetectFaces</code>. For <code>IndexFaces</code>, use the <code>DetectAttributes</code> input parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaceDetail {
     /// <p>The estimated age range, in years, for the face. Low represents the lowest estimated age and High represents the highest estimated age.</p>
     #[serde(rename = "AgeRange")]
@@ -660,7 +853,7 @@ pub struct FaceDetail {
 
 /// <p>Information about a face detected in a video analysis request and the time the face was detected in the video. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaceDetection {
     /// <p>The face properties for the detected face.</p>
     #[serde(rename = "Face")]
@@ -674,7 +867,7 @@ pub struct FaceDetection {
 
 /// <p>Provides face metadata. In addition, it also provides the confidence in the match of this face with the input face.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaceMatch {
     /// <p>Describes the face properties such as the bounding box, face ID, image ID of the source image, and external image ID that you assigned.</p>
     #[serde(rename = "Face")]
@@ -688,7 +881,7 @@ pub struct FaceMatch {
 
 /// <p>Object containing both the face metadata (stored in the backend database), and facial attributes that are detected but aren't stored in the database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaceRecord {
     /// <p>Describes the face properties such as the bounding box, face ID, image ID of the input image, and external image ID that you assigned. </p>
     #[serde(rename = "Face")]
@@ -713,9 +906,35 @@ pub struct FaceSearchSettings {
     pub face_match_threshold: Option<f32>,
 }
 
+impl Validate for FaceSearchSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.collection_id {
+            errors.check_length(
+                &field_path(path, "collection_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "collection_id"),
+                &value,
+                "[a-zA-Z0-9_.\\-]+",
+            );
+        }
+        if let Some(ref value) = self.face_match_threshold {
+            errors.check_range(
+                &field_path(path, "face_match_threshold"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+    }
+}
+
 /// <p>Gender of the face and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Gender {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -729,7 +948,7 @@ pub struct Gender {
 
 /// <p>Information about where the text detected by <a>DetectText</a> is located on an image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Geometry {
     /// <p>An axis-aligned coarse representation of the detected text's location on the image.</p>
     #[serde(rename = "BoundingBox")]
@@ -742,14 +961,21 @@ pub struct Geometry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCelebrityInfoRequest {
     /// <p>The ID for the celebrity. You get the celebrity ID from a call to the <a>RecognizeCelebrities</a> operation, which recognizes celebrities in an image. </p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for GetCelebrityInfoRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, "[0-9A-Za-z]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCelebrityInfoResponse {
     /// <p>The name of the celebrity.</p>
     #[serde(rename = "Name")]
@@ -762,6 +988,7 @@ pub struct GetCelebrityInfoResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCelebrityRecognitionRequest {
     /// <p>Job identifier for the required celebrity recognition analysis. You can get the job identifer from a call to <code>StartCelebrityRecognition</code>.</p>
     #[serde(rename = "JobId")]
@@ -780,8 +1007,35 @@ pub struct GetCelebrityRecognitionRequest {
     pub sort_by: Option<String>,
 }
 
+impl Validate for GetCelebrityRecognitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCelebrityRecognitionResponse {
     /// <p>Array of celebrities recognized in the video.</p>
     #[serde(rename = "Celebrities")]
@@ -806,6 +1060,7 @@ pub struct GetCelebrityRecognitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetContentModerationRequest {
     /// <p>The identifier for the unsafe content job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetContentModeration</code>.</p>
     #[serde(rename = "JobId")]
@@ -824,8 +1079,35 @@ pub struct GetContentModerationRequest {
     pub sort_by: Option<String>,
 }
 
+impl Validate for GetContentModerationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetContentModerationResponse {
     /// <p>The current status of the unsafe content analysis job.</p>
     #[serde(rename = "JobStatus")]
@@ -854,6 +1136,7 @@ pub struct GetContentModerationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFaceDetectionRequest {
     /// <p>Unique identifier for the face detection job. The <code>JobId</code> is returned from <code>StartFaceDetection</code>.</p>
     #[serde(rename = "JobId")]
@@ -868,8 +1151,35 @@ pub struct GetFaceDetectionRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetFaceDetectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFaceDetectionResponse {
     /// <p>An array of faces detected in the video. Each element contains a detected face's details and the time, in milliseconds from the start of the video, the face was detected. </p>
     #[serde(rename = "Faces")]
@@ -894,6 +1204,7 @@ pub struct GetFaceDetectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFaceSearchRequest {
     /// <p>The job identifer for the search request. You get the job identifier from an initial call to <code>StartFaceSearch</code>.</p>
     #[serde(rename = "JobId")]
@@ -912,8 +1223,35 @@ pub struct GetFaceSearchRequest {
     pub sort_by: Option<String>,
 }
 
+impl Validate for GetFaceSearchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFaceSearchResponse {
     /// <p>The current status of the face search job.</p>
     #[serde(rename = "JobStatus")]
@@ -938,6 +1276,7 @@ pub struct GetFaceSearchResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLabelDetectionRequest {
     /// <p>Job identifier for the label detection operation for which you want results returned. You get the job identifer from an initial call to <code>StartlabelDetection</code>.</p>
     #[serde(rename = "JobId")]
@@ -956,8 +1295,35 @@ pub struct GetLabelDetectionRequest {
     pub sort_by: Option<String>,
 }
 
+impl Validate for GetLabelDetectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLabelDetectionResponse {
     /// <p>The current status of the label detection job.</p>
     #[serde(rename = "JobStatus")]
@@ -986,6 +1352,7 @@ pub struct GetLabelDetectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPersonTrackingRequest {
     /// <p>The identifier for a job that tracks persons in a video. You get the <code>JobId</code> from a call to <code>StartPersonTracking</code>. </p>
     #[serde(rename = "JobId")]
@@ -1004,8 +1371,35 @@ pub struct GetPersonTrackingRequest {
     pub sort_by: Option<String>,
 }
 
+impl Validate for GetPersonTrackingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPersonTrackingResponse {
     /// <p>The current status of the person tracking job.</p>
     #[serde(rename = "JobStatus")]
@@ -1031,6 +1425,7 @@ pub struct GetPersonTrackingResponse {
 
 /// <p>Provides the input image either as bytes or an S3 object.</p> <p>You pass image bytes to an Amazon Rekognition API operation by using the <code>Bytes</code> property. For example, you would use the <code>Bytes</code> property to pass an image loaded from a local file system. Image bytes passed by using the <code>Bytes</code> property must be base64-encoded. Your code may not need to encode image bytes if you are using an AWS SDK to call Amazon Rekognition API operations. </p> <p>For more information, see Analyzing an Image Loaded from a Local File System in the Amazon Rekognition Developer Guide.</p> <p> You pass images stored in an S3 bucket to an Amazon Rekognition API operation by using the <code>S3Object</code> property. Images stored in an S3 bucket do not need to be base64-encoded.</p> <p>The region for the S3 bucket containing the S3 object must match the region you use for Amazon Rekognition operations.</p> <p>If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes using the Bytes property is not supported. You must first upload the image to an Amazon S3 bucket and then call the operation using the S3Object property.</p> <p>For Amazon Rekognition to process an S3 object, the user must have permission to access the S3 object. For more information, see Resource Based Policies in the Amazon Rekognition Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Image {
     /// <p>Blob of image bytes up to 5 MBs.</p>
     #[serde(rename = "Bytes")]
@@ -1047,9 +1442,25 @@ pub struct Image {
     pub s3_object: Option<S3Object>,
 }
 
+impl Validate for Image {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bytes {
+            errors.check_length(
+                &field_path(path, "bytes"),
+                value.len(),
+                Some(1),
+                Some(5242880),
+            );
+        }
+        if let Some(ref value) = self.s3_object {
+            value.validate_at(&field_path(path, "s3_object"), errors);
+        }
+    }
+}
+
 /// <p>Identifies face image brightness and sharpness. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImageQuality {
     /// <p>Value representing brightness of the face. The service returns a value between 0 and 100 (inclusive). A higher value indicates a brighter face image.</p>
     #[serde(rename = "Brightness")]
@@ -1062,6 +1473,7 @@ pub struct ImageQuality {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IndexFacesRequest {
     /// <p>The ID of an existing collection to which you want to add the faces that are detected in the input images.</p>
     #[serde(rename = "CollectionId")]
@@ -1087,8 +1499,41 @@ pub struct IndexFacesRequest {
     pub quality_filter: Option<String>,
 }
 
+impl Validate for IndexFacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+        if let Some(ref value) = self.external_image_id {
+            errors.check_length(
+                &field_path(path, "external_image_id"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "external_image_id"),
+                &value,
+                "[a-zA-Z0-9_.\\-:]+",
+            );
+        }
+        self.image.validate_at(&field_path(path, "image"), errors);
+        if let Some(ref value) = self.max_faces {
+            errors.check_range(&field_path(path, "max_faces"), *value, Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IndexFacesResponse {
     /// <p>The version number of the face detection model that's associated with the input collection (<code>CollectionId</code>).</p>
     #[serde(rename = "FaceModelVersion")]
@@ -1110,7 +1555,7 @@ pub struct IndexFacesResponse {
 
 /// <p>An instance of a label returned by Amazon Rekognition Image (<a>DetectLabels</a>) or by Amazon Rekognition Video (<a>GetLabelDetection</a>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Instance {
     /// <p>The position of the label instance on the image.</p>
     #[serde(rename = "BoundingBox")]
@@ -1131,6 +1576,18 @@ pub struct KinesisDataStream {
     pub arn: Option<String>,
 }
 
+impl Validate for KinesisDataStream {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arn {
+            errors.check_pattern(
+                &field_path(path, "arn"),
+                &value,
+                "(^arn:([a-z\\d-]+):kinesis:([a-z\\d-]+):\\d{12}:.+$)",
+            );
+        }
+    }
+}
+
 /// <p>Kinesis video stream stream that provides the source streaming video for a Amazon Rekognition Video stream processor. For more information, see CreateStreamProcessor in the Amazon Rekognition Developer Guide.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KinesisVideoStream {
@@ -1140,9 +1597,21 @@ pub struct KinesisVideoStream {
     pub arn: Option<String>,
 }
 
+impl Validate for KinesisVideoStream {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arn {
+            errors.check_pattern(
+                &field_path(path, "arn"),
+                &value,
+                "(^arn:([a-z\\d-]+):kinesisvideo:([a-z\\d-]+):\\d{12}:.+$)",
+            );
+        }
+    }
+}
+
 /// <p>Structure containing details about the detected label, including the name, detected instances, parent labels, and level of confidence.</p> <p> </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Label {
     /// <p>Level of confidence.</p>
     #[serde(rename = "Confidence")]
@@ -1164,7 +1633,7 @@ pub struct Label {
 
 /// <p>Information about a label detected in a video analysis request and the time the label was detected in the video. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelDetection {
     /// <p>Details about the detected label.</p>
     #[serde(rename = "Label")]
@@ -1178,7 +1647,7 @@ pub struct LabelDetection {
 
 /// <p>Indicates the location of the landmark on the face.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Landmark {
     /// <p>Type of landmark.</p>
     #[serde(rename = "Type")]
@@ -1195,6 +1664,7 @@ pub struct Landmark {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCollectionsRequest {
     /// <p>Maximum number of collection IDs to return. </p>
     #[serde(rename = "MaxResults")]
@@ -1206,8 +1676,24 @@ pub struct ListCollectionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCollectionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(4096));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCollectionsResponse {
     /// <p>An array of collection IDs.</p>
     #[serde(rename = "CollectionIds")]
@@ -1224,6 +1710,7 @@ pub struct ListCollectionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFacesRequest {
     /// <p>ID of the collection from which to list the faces.</p>
     #[serde(rename = "CollectionId")]
@@ -1238,8 +1725,35 @@ pub struct ListFacesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListFacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(4096));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFacesResponse {
     /// <p>Version number of the face detection model associated with the input collection (<code>CollectionId</code>).</p>
     #[serde(rename = "FaceModelVersion")]
@@ -1256,6 +1770,7 @@ pub struct ListFacesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStreamProcessorsRequest {
     /// <p>Maximum number of stream processors you want Amazon Rekognition Video to return in the response. The default is 1000. </p>
     #[serde(rename = "MaxResults")]
@@ -1267,8 +1782,24 @@ pub struct ListStreamProcessorsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListStreamProcessorsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStreamProcessorsResponse {
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of stream processors. </p>
     #[serde(rename = "NextToken")]
@@ -1282,7 +1813,7 @@ pub struct ListStreamProcessorsResponse {
 
 /// <p>Provides information about a single type of unsafe content found in an image or video. Each type of moderated content has a label within a hierarchical taxonomy. For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModerationLabel {
     /// <p>Specifies the confidence that Amazon Rekognition has that the label has been correctly identified.</p> <p>If you don't specify the <code>MinConfidence</code> parameter in the call to <code>DetectModerationLabels</code>, the operation returns labels with a confidence value greater than or equal to 50 percent.</p>
     #[serde(rename = "Confidence")]
@@ -1300,7 +1831,7 @@ pub struct ModerationLabel {
 
 /// <p>Indicates whether or not the mouth on the face is open, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MouthOpen {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -1314,7 +1845,7 @@ pub struct MouthOpen {
 
 /// <p>Indicates whether or not the face has a mustache, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Mustache {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -1328,6 +1859,7 @@ pub struct Mustache {
 
 /// <p>The Amazon Simple Notification Service topic to which Amazon Rekognition publishes the completion status of a video analysis operation. For more information, see <a>api-video</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationChannel {
     /// <p>The ARN of an IAM role that gives Amazon Rekognition publishing permissions to the Amazon SNS topic. </p>
     #[serde(rename = "RoleArn")]
@@ -1337,9 +1869,24 @@ pub struct NotificationChannel {
     pub sns_topic_arn: String,
 }
 
+impl Validate for NotificationChannel {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+        errors.check_pattern(
+            &field_path(path, "sns_topic_arn"),
+            &self.sns_topic_arn,
+            "(^arn:aws:sns:.*:\\w{12}:.+$)",
+        );
+    }
+}
+
 /// <p>A parent label for a label. A label can have 0, 1, or more parents. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Parent {
     /// <p>The name of the parent label.</p>
     #[serde(rename = "Name")]
@@ -1349,7 +1896,7 @@ pub struct Parent {
 
 /// <p>Details about a person detected in a video analysis request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PersonDetail {
     /// <p>Bounding box around the detected person.</p>
     #[serde(rename = "BoundingBox")]
@@ -1367,7 +1914,7 @@ pub struct PersonDetail {
 
 /// <p>Details and path tracking information for a single time a person's path is tracked in a video. Amazon Rekognition operations that track people's paths return an array of <code>PersonDetection</code> objects with elements for each time a person's path is tracked in a video. </p> <p>For more information, see GetPersonTracking in the Amazon Rekognition Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PersonDetection {
     /// <p>Details about a person whose path was tracked in a video.</p>
     #[serde(rename = "Person")]
@@ -1381,7 +1928,7 @@ pub struct PersonDetection {
 
 /// <p>Information about a person whose face matches a face(s) in an Amazon Rekognition collection. Includes information about the faces in the Amazon Rekognition collection (<a>FaceMatch</a>), information about the person (<a>PersonDetail</a>), and the time stamp for when the person was detected in a video. An array of <code>PersonMatch</code> objects is returned by <a>GetFaceSearch</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PersonMatch {
     /// <p>Information about the faces in the input collection that match the face of a person in the video.</p>
     #[serde(rename = "FaceMatches")]
@@ -1399,7 +1946,7 @@ pub struct PersonMatch {
 
 /// <p>The X and Y coordinates of a point on an image. The X and Y values returned are ratios of the overall image size. For example, if the input image is 700x200 and the operation returns X=0.5 and Y=0.25, then the point is at the (350,50) pixel coordinate on the image.</p> <p>An array of <code>Point</code> objects, <code>Polygon</code>, is returned by <a>DetectText</a>. <code>Polygon</code> represents a fine-grained polygon around detected text. For more information, see Geometry in the Amazon Rekognition Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Point {
     /// <p>The value of the X coordinate for a point on a <code>Polygon</code>.</p>
     #[serde(rename = "X")]
@@ -1413,7 +1960,7 @@ pub struct Point {
 
 /// <p>Indicates the pose of the face as determined by its pitch, roll, and yaw.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Pose {
     /// <p>Value representing the face rotation on the pitch axis.</p>
     #[serde(rename = "Pitch")]
@@ -1430,14 +1977,21 @@ pub struct Pose {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecognizeCelebritiesRequest {
     /// <p>The input image as base64-encoded bytes or an S3 object. If you use the AWS CLI to call Amazon Rekognition operations, passing base64-encoded image bytes is not supported. </p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
     #[serde(rename = "Image")]
     pub image: Image,
 }
 
+impl Validate for RecognizeCelebritiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.image.validate_at(&field_path(path, "image"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecognizeCelebritiesResponse {
     /// <p>Details about each celebrity found in the image. Amazon Rekognition can detect a maximum of 15 celebrities in an image.</p>
     #[serde(rename = "CelebrityFaces")]
@@ -1455,6 +2009,7 @@ pub struct RecognizeCelebritiesResponse {
 
 /// <p>Provides the S3 bucket name and object name.</p> <p>The region for the S3 bucket containing the S3 object must match the region you use for Amazon Rekognition operations.</p> <p>For Amazon Rekognition to process an S3 object, the user must have permission to access the S3 object. For more information, see Resource-Based Policies in the Amazon Rekognition Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Object {
     /// <p>Name of the S3 bucket.</p>
     #[serde(rename = "Bucket")]
@@ -1470,7 +2025,38 @@ pub struct S3Object {
     pub version: Option<String>,
 }
 
+impl Validate for S3Object {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket {
+            errors.check_length(
+                &field_path(path, "bucket"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "bucket"), &value, "[0-9A-Za-z\\.\\-_]*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchFacesByImageRequest {
     /// <p>ID of the collection to search.</p>
     #[serde(rename = "CollectionId")]
@@ -1488,8 +2074,36 @@ pub struct SearchFacesByImageRequest {
     pub max_faces: Option<i64>,
 }
 
+impl Validate for SearchFacesByImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+        if let Some(ref value) = self.face_match_threshold {
+            errors.check_range(
+                &field_path(path, "face_match_threshold"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+        self.image.validate_at(&field_path(path, "image"), errors);
+        if let Some(ref value) = self.max_faces {
+            errors.check_range(&field_path(path, "max_faces"), *value, Some(1), Some(4096));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchFacesByImageResponse {
     /// <p>An array of faces that match the input face, along with the confidence in the match.</p>
     #[serde(rename = "FaceMatches")]
@@ -1510,6 +2124,7 @@ pub struct SearchFacesByImageResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchFacesRequest {
     /// <p>ID of the collection the face belongs to.</p>
     #[serde(rename = "CollectionId")]
@@ -1527,8 +2142,40 @@ pub struct SearchFacesRequest {
     pub max_faces: Option<i64>,
 }
 
+impl Validate for SearchFacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+        errors.check_pattern(
+            &field_path(path, "face_id"),
+            &self.face_id,
+            "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        if let Some(ref value) = self.face_match_threshold {
+            errors.check_range(
+                &field_path(path, "face_match_threshold"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+        if let Some(ref value) = self.max_faces {
+            errors.check_range(&field_path(path, "max_faces"), *value, Some(1), Some(4096));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchFacesResponse {
     /// <p>An array of faces that matched the input face, along with the confidence in the match.</p>
     #[serde(rename = "FaceMatches")]
@@ -1546,7 +2193,7 @@ pub struct SearchFacesResponse {
 
 /// <p>Indicates whether or not the face is smiling, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Smile {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -1559,6 +2206,7 @@ pub struct Smile {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartCelebrityRecognitionRequest {
     /// <p>Idempotent token used to identify the start request. If you use the same token with multiple <code>StartCelebrityRecognition</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidently started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -1577,8 +2225,43 @@ pub struct StartCelebrityRecognitionRequest {
     pub video: Video,
 }
 
+impl Validate for StartCelebrityRecognitionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_tag"),
+                &value,
+                "[a-zA-Z0-9_.\\-:+=\\/]+",
+            );
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+        self.video.validate_at(&field_path(path, "video"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartCelebrityRecognitionResponse {
     /// <p>The identifier for the celebrity recognition analysis job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetCelebrityRecognition</code>.</p>
     #[serde(rename = "JobId")]
@@ -1587,6 +2270,7 @@ pub struct StartCelebrityRecognitionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartContentModerationRequest {
     /// <p>Idempotent token used to identify the start request. If you use the same token with multiple <code>StartContentModeration</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidently started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -1609,8 +2293,51 @@ pub struct StartContentModerationRequest {
     pub video: Video,
 }
 
+impl Validate for StartContentModerationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_tag"),
+                &value,
+                "[a-zA-Z0-9_.\\-:+=\\/]+",
+            );
+        }
+        if let Some(ref value) = self.min_confidence {
+            errors.check_range(
+                &field_path(path, "min_confidence"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+        self.video.validate_at(&field_path(path, "video"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartContentModerationResponse {
     /// <p>The identifier for the unsafe content analysis job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetContentModeration</code>.</p>
     #[serde(rename = "JobId")]
@@ -1619,6 +2346,7 @@ pub struct StartContentModerationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartFaceDetectionRequest {
     /// <p>Idempotent token used to identify the start request. If you use the same token with multiple <code>StartFaceDetection</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidently started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -1641,8 +2369,43 @@ pub struct StartFaceDetectionRequest {
     pub video: Video,
 }
 
+impl Validate for StartFaceDetectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_tag"),
+                &value,
+                "[a-zA-Z0-9_.\\-:+=\\/]+",
+            );
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+        self.video.validate_at(&field_path(path, "video"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartFaceDetectionResponse {
     /// <p>The identifier for the face detection job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetFaceDetection</code>.</p>
     #[serde(rename = "JobId")]
@@ -1651,6 +2414,7 @@ pub struct StartFaceDetectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartFaceSearchRequest {
     /// <p>Idempotent token used to identify the start request. If you use the same token with multiple <code>StartFaceSearch</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidently started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -1676,8 +2440,62 @@ pub struct StartFaceSearchRequest {
     pub video: Video,
 }
 
+impl Validate for StartFaceSearchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "collection_id"),
+            self.collection_id.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "collection_id"),
+            &self.collection_id,
+            "[a-zA-Z0-9_.\\-]+",
+        );
+        if let Some(ref value) = self.face_match_threshold {
+            errors.check_range(
+                &field_path(path, "face_match_threshold"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_tag"),
+                &value,
+                "[a-zA-Z0-9_.\\-:+=\\/]+",
+            );
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+        self.video.validate_at(&field_path(path, "video"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartFaceSearchResponse {
     /// <p>The identifier for the search job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetFaceSearch</code>. </p>
     #[serde(rename = "JobId")]
@@ -1686,6 +2504,7 @@ pub struct StartFaceSearchResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartLabelDetectionRequest {
     /// <p>Idempotent token used to identify the start request. If you use the same token with multiple <code>StartLabelDetection</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidently started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -1708,8 +2527,51 @@ pub struct StartLabelDetectionRequest {
     pub video: Video,
 }
 
+impl Validate for StartLabelDetectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_tag"),
+                &value,
+                "[a-zA-Z0-9_.\\-:+=\\/]+",
+            );
+        }
+        if let Some(ref value) = self.min_confidence {
+            errors.check_range(
+                &field_path(path, "min_confidence"),
+                *value,
+                None,
+                Some(100.0),
+            );
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+        self.video.validate_at(&field_path(path, "video"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartLabelDetectionResponse {
     /// <p>The identifier for the label detection job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetLabelDetection</code>. </p>
     #[serde(rename = "JobId")]
@@ -1718,6 +2580,7 @@ pub struct StartLabelDetectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartPersonTrackingRequest {
     /// <p>Idempotent token used to identify the start request. If you use the same token with multiple <code>StartPersonTracking</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidently started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -1736,8 +2599,43 @@ pub struct StartPersonTrackingRequest {
     pub video: Video,
 }
 
+impl Validate for StartPersonTrackingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_tag"),
+                &value,
+                "[a-zA-Z0-9_.\\-:+=\\/]+",
+            );
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+        self.video.validate_at(&field_path(path, "video"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartPersonTrackingResponse {
     /// <p>The identifier for the person detection job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetPersonTracking</code>.</p>
     #[serde(rename = "JobId")]
@@ -1746,30 +2644,56 @@ pub struct StartPersonTrackingResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartStreamProcessorRequest {
     /// <p>The name of the stream processor to start processing.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StartStreamProcessorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_.\\-]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartStreamProcessorResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopStreamProcessorRequest {
     /// <p>The name of a stream processor created by <a>CreateStreamProcessor</a>.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for StopStreamProcessorRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_.\\-]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopStreamProcessorResponse {}
 
 /// <p>An object that recognizes faces in a streaming video. An Amazon Rekognition stream processor is created by a call to <a>CreateStreamProcessor</a>. The request parameters for <code>CreateStreamProcessor</code> describe the Kinesis video stream source for the streaming video, face recognition parameters, and where to stream the analysis resullts. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StreamProcessor {
     /// <p>Name of the Amazon Rekognition stream processor. </p>
     #[serde(rename = "Name")]
@@ -1790,6 +2714,14 @@ pub struct StreamProcessorInput {
     pub kinesis_video_stream: Option<KinesisVideoStream>,
 }
 
+impl Validate for StreamProcessorInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kinesis_video_stream {
+            value.validate_at(&field_path(path, "kinesis_video_stream"), errors);
+        }
+    }
+}
+
 /// <p>Information about the Amazon Kinesis Data Streams stream to which a Amazon Rekognition Video stream processor streams the results of a video analysis. For more information, see CreateStreamProcessor in the Amazon Rekognition Developer Guide.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamProcessorOutput {
@@ -1799,6 +2731,14 @@ pub struct StreamProcessorOutput {
     pub kinesis_data_stream: Option<KinesisDataStream>,
 }
 
+impl Validate for StreamProcessorOutput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kinesis_data_stream {
+            value.validate_at(&field_path(path, "kinesis_data_stream"), errors);
+        }
+    }
+}
+
 /// <p>Input parameters used to recognize faces in a streaming video analyzed by a Amazon Rekognition stream processor.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamProcessorSettings {
@@ -1808,9 +2748,17 @@ pub struct StreamProcessorSettings {
     pub face_search: Option<FaceSearchSettings>,
 }
 
+impl Validate for StreamProcessorSettings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.face_search {
+            value.validate_at(&field_path(path, "face_search"), errors);
+        }
+    }
+}
+
 /// <p>Indicates whether or not the face is wearing sunglasses, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Sunglasses {
     /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
@@ -1824,7 +2772,7 @@ pub struct Sunglasses {
 
 /// <p>Information about a word or line of text detected by <a>DetectText</a>.</p> <p>The <code>DetectedText</code> field contains the text that Amazon Rekognition detected in the image. </p> <p>Every word and line has an identifier (<code>Id</code>). Each word belongs to a line and has a parent identifier (<code>ParentId</code>) that identifies the line of text in which the word appears. The word <code>Id</code> is also an index for the word within a line of words. </p> <p>For more information, see Detecting Text in the Amazon Rekognition Developer Guide.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TextDetection {
     /// <p>The confidence that Amazon Rekognition has in the accuracy of the detected text and the accuracy of the geometry points around the detected text.</p>
     #[serde(rename = "Confidence")]
@@ -1854,7 +2802,7 @@ pub struct TextDetection {
 
 /// <p>A face that <a>IndexFaces</a> detected, but didn't index. Use the <code>Reasons</code> response attribute to determine why a face wasn't indexed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnindexedFace {
     /// <p>The structure that contains attributes of a face that <code>IndexFaces</code>detected, but didn't index. </p>
     #[serde(rename = "FaceDetail")]
@@ -1868,6 +2816,7 @@ pub struct UnindexedFace {
 
 /// <p>Video file stored in an Amazon S3 bucket. Amazon Rekognition video start operations such as <a>StartLabelDetection</a> use <code>Video</code> to specify a video for analysis. The supported file formats are .mp4, .mov and .avi.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Video {
     /// <p>The Amazon S3 bucket name and file name for the video.</p>
     #[serde(rename = "S3Object")]
@@ -1875,9 +2824,17 @@ pub struct Video {
     pub s3_object: Option<S3Object>,
 }
 
+impl Validate for Video {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_object {
+            value.validate_at(&field_path(path, "s3_object"), errors);
+        }
+    }
+}
+
 /// <p>Information about a video that Amazon Rekognition analyzed. <code>Videometadata</code> is returned in every page of paginated responses from a Amazon Rekognition video operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VideoMetadata {
     /// <p>Type of compression used in the analyzed video. </p>
     #[serde(rename = "Codec")]
@@ -1956,7 +2913,12 @@ impl CompareFacesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CompareFacesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2027,7 +2989,12 @@ impl CreateCollectionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateCollectionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2101,7 +3068,12 @@ impl CreateStreamProcessorError {
                 "ThrottlingException" => {
                     return RusotoError::Service(CreateStreamProcessorError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2169,7 +3141,12 @@ impl DeleteCollectionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteCollectionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2234,7 +3211,12 @@ impl DeleteFacesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteFacesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2310,7 +3292,12 @@ impl DeleteStreamProcessorError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DeleteStreamProcessorError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2378,7 +3365,12 @@ impl DescribeCollectionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeCollectionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2451,7 +3443,12 @@ impl DescribeStreamProcessorError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DescribeStreamProcessorError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2526,7 +3523,12 @@ impl DetectFacesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DetectFacesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2603,7 +3605,12 @@ impl DetectLabelsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DetectLabelsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2690,7 +3697,12 @@ impl DetectModerationLabelsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DetectModerationLabelsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2767,7 +3779,12 @@ impl DetectTextError {
                 "ThrottlingException" => {
                     return RusotoError::Service(DetectTextError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2836,7 +3853,12 @@ impl GetCelebrityInfoError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetCelebrityInfoError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2916,7 +3938,12 @@ impl GetCelebrityRecognitionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetCelebrityRecognitionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2995,7 +4022,12 @@ impl GetContentModerationError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetContentModerationError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3070,7 +4102,12 @@ impl GetFaceDetectionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetFaceDetectionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3143,7 +4180,12 @@ impl GetFaceSearchError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetFaceSearchError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3218,7 +4260,12 @@ impl GetLabelDetectionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetLabelDetectionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3293,7 +4340,12 @@ impl GetPersonTrackingError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetPersonTrackingError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3374,7 +4426,12 @@ impl IndexFacesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(IndexFacesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3449,7 +4506,12 @@ impl ListCollectionsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListCollectionsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3520,7 +4582,12 @@ impl ListFacesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListFacesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3592,7 +4659,12 @@ impl ListStreamProcessorsError {
                 "ThrottlingException" => {
                     return RusotoError::Service(ListStreamProcessorsError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3675,7 +4747,12 @@ impl RecognizeCelebritiesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(RecognizeCelebritiesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3742,7 +4819,12 @@ impl SearchFacesError {
                 "ThrottlingException" => {
                     return RusotoError::Service(SearchFacesError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3826,7 +4908,12 @@ impl SearchFacesByImageError {
                 "ThrottlingException" => {
                     return RusotoError::Service(SearchFacesByImageError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3925,7 +5012,12 @@ impl StartCelebrityRecognitionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4020,7 +5112,12 @@ impl StartContentModerationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4107,7 +5204,12 @@ impl StartFaceDetectionError {
                 "VideoTooLargeException" => {
                     return RusotoError::Service(StartFaceDetectionError::VideoTooLarge(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4197,7 +5299,12 @@ impl StartFaceSearchError {
                 "VideoTooLargeException" => {
                     return RusotoError::Service(StartFaceSearchError::VideoTooLarge(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4287,7 +5394,12 @@ impl StartLabelDetectionError {
                 "VideoTooLargeException" => {
                     return RusotoError::Service(StartLabelDetectionError::VideoTooLarge(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4376,7 +5488,12 @@ impl StartPersonTrackingError {
                 "VideoTooLargeException" => {
                     return RusotoError::Service(StartPersonTrackingError::VideoTooLarge(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4455,7 +5572,12 @@ impl StartStreamProcessorError {
                 "ThrottlingException" => {
                     return RusotoError::Service(StartStreamProcessorError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4532,7 +5654,12 @@ impl StopStreamProcessorError {
                 "ThrottlingException" => {
                     return RusotoError::Service(StopStreamProcessorError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4763,1003 +5890,1919 @@ pub trait Rekognition {
         input: StopStreamProcessorRequest,
     ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError>;
 }
-/// A client for the Amazon Rekognition API.
-#[derive(Clone)]
-pub struct RekognitionClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl RekognitionClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> RekognitionClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> RekognitionClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> RekognitionClient {
-        RekognitionClient { client, region }
-    }
-}
-
-impl Rekognition for RekognitionClient {
-    /// <p>Compares a face in the <i>source</i> input image with each of the 100 largest faces detected in the <i>target</i> input image. </p> <note> <p> If the source image contains multiple faces, the service detects the largest face and compares it with each face detected in the target image. </p> </note> <p>You pass the input and target images either as base64-encoded image bytes or as references to images in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>In response, the operation returns an array of face matches ordered by similarity score in descending order. For each face match, the response provides a bounding box of the face, facial landmarks, pose details (pitch, role, and yaw), quality (brightness and sharpness), and confidence value (indicating the level of confidence that the bounding box contains a face). The response also provides a similarity score, which indicates how closely the faces match. </p> <note> <p>By default, only faces with a similarity score of greater than or equal to 80% are returned in the response. You can change this value by specifying the <code>SimilarityThreshold</code> parameter.</p> </note> <p> <code>CompareFaces</code> also returns an array of faces that don't match the source image. For each face, it returns a bounding box, confidence value, landmarks, pose details, and quality. The response also returns information about the face in the source image, including the bounding box of the face and confidence value.</p> <p>If the image doesn't contain Exif metadata, <code>CompareFaces</code> returns orientation information for the source and target images. Use these values to display the images with the correct image orientation.</p> <p>If no faces are detected in the source or target images, <code>CompareFaces</code> returns an <code>InvalidParameterException</code> error. </p> <note> <p> This is a stateless API operation. That is, data returned by this operation doesn't persist.</p> </note> <p>For an example, see Comparing Faces in Images in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:CompareFaces</code> action.</p>
+const _: Option<&dyn Rekognition> = None;
+impl<'a, T: Rekognition + ?Sized> Rekognition for &'a T {
     fn compare_faces(
         &self,
         input: CompareFacesRequest,
     ) -> RusotoFuture<CompareFacesResponse, CompareFacesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CompareFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CompareFacesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CompareFacesError::from_response(response))),
-                )
-            }
-        })
+        (**self).compare_faces(input)
     }
-
-    /// <p>Creates a collection in an AWS Region. You can add faces to the collection using the <a>IndexFaces</a> operation. </p> <p>For example, you might create collections, one for each of your application users. A user can then index faces using the <code>IndexFaces</code> operation and persist results in a specific collection. Then, a user can search the collection for faces in the user-specific container. </p> <p>When you create a collection, it is associated with the latest version of the face model version.</p> <note> <p>Collection names are case-sensitive.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:CreateCollection</code> action.</p>
     fn create_collection(
         &self,
         input: CreateCollectionRequest,
     ) -> RusotoFuture<CreateCollectionResponse, CreateCollectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CreateCollection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCollectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateCollectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_collection(input)
     }
-
-    /// <p>Creates an Amazon Rekognition stream processor that you can use to detect and recognize faces in a streaming video.</p> <p>Amazon Rekognition Video is a consumer of live video from Amazon Kinesis Video Streams. Amazon Rekognition Video sends analysis results to Amazon Kinesis Data Streams.</p> <p>You provide as input a Kinesis video stream (<code>Input</code>) and a Kinesis data stream (<code>Output</code>) stream. You also specify the face recognition criteria in <code>Settings</code>. For example, the collection containing faces that you want to recognize. Use <code>Name</code> to assign an identifier for the stream processor. You use <code>Name</code> to manage the stream processor. For example, you can start processing the source video by calling <a>StartStreamProcessor</a> with the <code>Name</code> field. </p> <p>After you have finished analyzing a streaming video, use <a>StopStreamProcessor</a> to stop processing. You can delete the stream processor by calling <a>DeleteStreamProcessor</a>.</p>
     fn create_stream_processor(
         &self,
         input: CreateStreamProcessorRequest,
     ) -> RusotoFuture<CreateStreamProcessorResponse, CreateStreamProcessorError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.CreateStreamProcessor");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStreamProcessorResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateStreamProcessorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_stream_processor(input)
     }
-
-    /// <p>Deletes the specified collection. Note that this operation removes all faces in the collection. For an example, see <a>delete-collection-procedure</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteCollection</code> action.</p>
     fn delete_collection(
         &self,
         input: DeleteCollectionRequest,
     ) -> RusotoFuture<DeleteCollectionResponse, DeleteCollectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DeleteCollection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCollectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteCollectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_collection(input)
     }
-
-    /// <p>Deletes faces from a collection. You specify a collection ID and an array of face IDs to remove from the collection.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteFaces</code> action.</p>
     fn delete_faces(
         &self,
         input: DeleteFacesRequest,
     ) -> RusotoFuture<DeleteFacesResponse, DeleteFacesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DeleteFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFacesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFacesError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_faces(input)
     }
-
-    /// <p>Deletes the stream processor identified by <code>Name</code>. You assign the value for <code>Name</code> when you create the stream processor with <a>CreateStreamProcessor</a>. You might not be able to use the same name for a stream processor for a few seconds after calling <code>DeleteStreamProcessor</code>.</p>
     fn delete_stream_processor(
         &self,
         input: DeleteStreamProcessorRequest,
     ) -> RusotoFuture<DeleteStreamProcessorResponse, DeleteStreamProcessorError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DeleteStreamProcessor");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteStreamProcessorResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteStreamProcessorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_stream_processor(input)
     }
-
-    /// <p>Describes the specified collection. You can use <code>DescribeCollection</code> to get information, such as the number of faces indexed into a collection and the version of the model used by the collection for face detection.</p> <p>For more information, see Describing a Collection in the Amazon Rekognition Developer Guide.</p>
     fn describe_collection(
         &self,
         input: DescribeCollectionRequest,
     ) -> RusotoFuture<DescribeCollectionResponse, DescribeCollectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DescribeCollection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCollectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeCollectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_collection(input)
     }
-
-    /// <p>Provides information about a stream processor created by <a>CreateStreamProcessor</a>. You can get information about the input and output streams, the input parameters for the face recognition being performed, and the current status of the stream processor.</p>
     fn describe_stream_processor(
         &self,
         input: DescribeStreamProcessorRequest,
     ) -> RusotoFuture<DescribeStreamProcessorResponse, DescribeStreamProcessorError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DescribeStreamProcessor");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStreamProcessorResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStreamProcessorError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_stream_processor(input)
     }
-
-    /// <p>Detects faces within an image that is provided as input.</p> <p> <code>DetectFaces</code> detects the 100 largest faces in the image. For each face detected, the operation returns face details. These details include a bounding box of the face, a confidence value (that the bounding box contains a face), and a fixed set of attributes such as facial landmarks (for example, coordinates of eye and mouth), gender, presence of beard, sunglasses, and so on. </p> <p>The face-detection algorithm is most effective on frontal faces. For non-frontal or obscured faces, the algorithm might not detect the faces or might detect faces with lower confidence. </p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <note> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:DetectFaces</code> action. </p>
     fn detect_faces(
         &self,
         input: DetectFacesRequest,
     ) -> RusotoFuture<DetectFacesResponse, DetectFacesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DetectFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectFacesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectFacesError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_faces(input)
     }
-
-    /// <p>Detects instances of real-world entities within an image (JPEG or PNG) provided as input. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; and concepts like landscape, evening, and nature. </p> <p>For an example, see Analyzing Images Stored in an Amazon S3 Bucket in the Amazon Rekognition Developer Guide.</p> <note> <p> <code>DetectLabels</code> does not support the detection of activities. However, activity detection is supported for label detection in videos. For more information, see StartLabelDetection in the Amazon Rekognition Developer Guide.</p> </note> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object, scene, and concept the API returns one or more labels. Each label provides the object name, and the level of confidence that the image contains the object. For example, suppose the input image has a lighthouse, the sea, and a rock. The response includes all three labels, one for each object. </p> <p> <code>{Name: lighthouse, Confidence: 98.4629}</code> </p> <p> <code>{Name: rock,Confidence: 79.2097}</code> </p> <p> <code> {Name: sea,Confidence: 75.061}</code> </p> <p>In the preceding example, the operation returns one label for each of the three objects. The operation can also return multiple labels for the same object in the image. For example, if the input image shows a flower (for example, a tulip), the operation might return the following three labels. </p> <p> <code>{Name: flower,Confidence: 99.0562}</code> </p> <p> <code>{Name: plant,Confidence: 99.0562}</code> </p> <p> <code>{Name: tulip,Confidence: 99.0562}</code> </p> <p>In this example, the detection algorithm more precisely identifies the flower as a tulip.</p> <p>In response, the API returns an array of labels. In addition, the response also includes the orientation correction. Optionally, you can specify <code>MinConfidence</code> to control the confidence threshold for the labels returned. The default is 55%. You can also add the <code>MaxLabels</code> parameter to limit the number of labels returned. </p> <note> <p>If the object detected is a person, the operation doesn't provide the same facial details that the <a>DetectFaces</a> operation provides.</p> </note> <p> <code>DetectLabels</code> returns bounding boxes for instances of common object labels in an array of <a>Instance</a> objects. An <code>Instance</code> object contains a <a>BoundingBox</a> object, for the location of the label on the image. It also includes the confidence by which the bounding box was detected.</p> <p> <code>DetectLabels</code> also returns a hierarchical taxonomy of detected labels. For example, a detected car might be assigned the label <i>car</i>. The label <i>car</i> has two parent labels: <i>Vehicle</i> (its parent) and <i>Transportation</i> (its grandparent). The response returns the entire list of ancestors for a label. Each ancestor is a unique label in the response. In the previous example, <i>Car</i>, <i>Vehicle</i>, and <i>Transportation</i> are returned as unique labels in the response. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectLabels</code> action. </p>
     fn detect_labels(
         &self,
         input: DetectLabelsRequest,
     ) -> RusotoFuture<DetectLabelsResponse, DetectLabelsError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DetectLabels");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectLabelsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectLabelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_labels(input)
     }
-
-    /// <p>Detects unsafe content in a specified JPEG or PNG format image. Use <code>DetectModerationLabels</code> to moderate images depending on your requirements. For example, you might want to filter images that contain nudity, but not images containing suggestive content.</p> <p>To filter images, use the labels returned by <code>DetectModerationLabels</code> to determine which types of content are appropriate.</p> <p>For information about moderation labels, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p>
     fn detect_moderation_labels(
         &self,
         input: DetectModerationLabelsRequest,
     ) -> RusotoFuture<DetectModerationLabelsResponse, DetectModerationLabelsError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DetectModerationLabels");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectModerationLabelsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DetectModerationLabelsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).detect_moderation_labels(input)
     }
-
-    /// <p>Detects text in the input image and converts it into machine-readable text.</p> <p>Pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, you must pass it as a reference to an image in an Amazon S3 bucket. For the AWS CLI, passing image bytes is not supported. The image must be either a .png or .jpeg formatted file. </p> <p>The <code>DetectText</code> operation returns text in an array of <a>TextDetection</a> elements, <code>TextDetections</code>. Each <code>TextDetection</code> element provides information about a single word or line of text that was detected in the image. </p> <p>A word is one or more ISO basic latin script characters that are not separated by spaces. <code>DetectText</code> can detect up to 50 words in an image.</p> <p>A line is a string of equally spaced words. A line isn't necessarily a complete sentence. For example, a driver's license number is detected as a line. A line ends when there is no aligned text after it. Also, a line ends when there is a large gap between words, relative to the length of the words. This means, depending on the gap between words, Amazon Rekognition may detect multiple lines in text aligned in the same direction. Periods don't represent the end of a line. If a sentence spans multiple lines, the <code>DetectText</code> operation returns multiple lines.</p> <p>To determine whether a <code>TextDetection</code> element is a line of text or a word, use the <code>TextDetection</code> object <code>Type</code> field. </p> <p>To be detected, text must be within +/- 90 degrees orientation of the horizontal axis.</p> <p>For more information, see DetectText in the Amazon Rekognition Developer Guide.</p>
     fn detect_text(
         &self,
         input: DetectTextRequest,
     ) -> RusotoFuture<DetectTextResponse, DetectTextError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.DetectText");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectTextResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetectTextError::from_response(response))),
-                )
-            }
-        })
+        (**self).detect_text(input)
     }
-
-    /// <p>Gets the name and additional information about a celebrity based on his or her Amazon Rekognition ID. The additional information is returned as an array of URLs. If there is no additional information about the celebrity, this list is empty.</p> <p>For more information, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:GetCelebrityInfo</code> action. </p>
     fn get_celebrity_info(
         &self,
         input: GetCelebrityInfoRequest,
     ) -> RusotoFuture<GetCelebrityInfoResponse, GetCelebrityInfoError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetCelebrityInfo");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCelebrityInfoResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCelebrityInfoError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_celebrity_info(input)
     }
-
-    /// <p>Gets the celebrity recognition results for a Amazon Rekognition Video analysis started by <a>StartCelebrityRecognition</a>.</p> <p>Celebrity recognition in a video is an asynchronous operation. Analysis is started by a call to <a>StartCelebrityRecognition</a> which returns a job identifier (<code>JobId</code>). When the celebrity recognition operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartCelebrityRecognition</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetCelebrityDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityDetection</code>. </p> <p>For more information, see Working With Stored Videos in the Amazon Rekognition Developer Guide.</p> <p> <code>GetCelebrityRecognition</code> returns detected celebrities and the time(s) they are detected in an array (<code>Celebrities</code>) of <a>CelebrityRecognition</a> objects. Each <code>CelebrityRecognition</code> contains information about the celebrity in a <a>CelebrityDetail</a> object and the time, <code>Timestamp</code>, the celebrity was detected. </p> <note> <p> <code>GetCelebrityRecognition</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Celebrities</code> array is sorted by time (milliseconds from the start of the video). You can also sort the array by celebrity by specifying the value <code>ID</code> in the <code>SortBy</code> input parameter.</p> <p>The <code>CelebrityDetail</code> object includes the celebrity identifer and additional information urls. If you don't store the additional information urls, you can get them later by calling <a>GetCelebrityInfo</a> with the celebrity identifer.</p> <p>No information is returned for faces not recognized as celebrities.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetCelebrityDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetCelebrityRecognition</code>.</p>
     fn get_celebrity_recognition(
         &self,
         input: GetCelebrityRecognitionRequest,
     ) -> RusotoFuture<GetCelebrityRecognitionResponse, GetCelebrityRecognitionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetCelebrityRecognition");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCelebrityRecognitionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCelebrityRecognitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_celebrity_recognition(input)
     }
-
-    /// <p>Gets the unsafe content analysis results for a Amazon Rekognition Video analysis started by <a>StartContentModeration</a>.</p> <p>Unsafe content analysis of a video is an asynchronous operation. You start analysis by calling <a>StartContentModeration</a> which returns a job identifier (<code>JobId</code>). When analysis finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartContentModeration</code>. To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetContentModeration</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Working with Stored Videos in the Amazon Rekognition Devlopers Guide.</p> <p> <code>GetContentModeration</code> returns detected unsafe content labels, and the time they are detected, in an array, <code>ModerationLabels</code>, of <a>ContentModerationDetection</a> objects. </p> <p>By default, the moderated labels are returned sorted by time, in milliseconds from the start of the video. You can also sort them by moderated label by specifying <code>NAME</code> for the <code>SortBy</code> input parameter. </p> <p>Since video analysis can return a large number of results, use the <code>MaxResults</code> parameter to limit the number of labels returned in a single call to <code>GetContentModeration</code>. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetContentModeration</code> and populate the <code>NextToken</code> request parameter with the value of <code>NextToken</code> returned from the previous call to <code>GetContentModeration</code>.</p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
     fn get_content_moderation(
         &self,
         input: GetContentModerationRequest,
     ) -> RusotoFuture<GetContentModerationResponse, GetContentModerationError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetContentModeration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetContentModerationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetContentModerationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_content_moderation(input)
     }
-
-    /// <p>Gets face detection results for a Amazon Rekognition Video analysis started by <a>StartFaceDetection</a>.</p> <p>Face detection with Amazon Rekognition Video is an asynchronous operation. You start face detection by calling <a>StartFaceDetection</a> which returns a job identifier (<code>JobId</code>). When the face detection operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceDetection</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p> <code>GetFaceDetection</code> returns an array of detected faces (<code>Faces</code>) sorted by the time the faces were detected. </p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetFaceDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetFaceDetection</code>.</p>
     fn get_face_detection(
         &self,
         input: GetFaceDetectionRequest,
     ) -> RusotoFuture<GetFaceDetectionResponse, GetFaceDetectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetFaceDetection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFaceDetectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFaceDetectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_face_detection(input)
     }
-
-    /// <p>Gets the face search results for Amazon Rekognition Video face search started by <a>StartFaceSearch</a>. The search returns faces in a collection that match the faces of persons detected in a video. It also includes the time(s) that faces are matched in the video.</p> <p>Face search in a video is an asynchronous operation. You start face search by calling to <a>StartFaceSearch</a> which returns a job identifier (<code>JobId</code>). When the search operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceSearch</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetFaceSearch</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>.</p> <p>For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>The search results are retured in an array, <code>Persons</code>, of <a>PersonMatch</a> objects. Each<code>PersonMatch</code> element contains details about the matching faces in the input collection, person information (facial attributes, bounding boxes, and person identifer) for the matched person, and the time the person was matched in the video.</p> <note> <p> <code>GetFaceSearch</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Persons</code> array is sorted by the time, in milliseconds from the start of the video, persons are matched. You can also sort by persons by specifying <code>INDEX</code> for the <code>SORTBY</code> input parameter.</p>
     fn get_face_search(
         &self,
         input: GetFaceSearchRequest,
     ) -> RusotoFuture<GetFaceSearchResponse, GetFaceSearchError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetFaceSearch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFaceSearchResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFaceSearchError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_face_search(input)
     }
-
-    /// <p>Gets the label detection results of a Amazon Rekognition Video analysis started by <a>StartLabelDetection</a>. </p> <p>The label detection operation is started by a call to <a>StartLabelDetection</a> which returns a job identifier (<code>JobId</code>). When the label detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartlabelDetection</code>. To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p> <code>GetLabelDetection</code> returns an array of detected labels (<code>Labels</code>) sorted by the time the labels were detected. You can also sort by the label name by specifying <code>NAME</code> for the <code>SortBy</code> input parameter.</p> <p>The labels returned include the label name, the percentage confidence in the accuracy of the detected label, and the time the label was detected in the video.</p> <p>The returned labels also include bounding box information for common objects, a hierarchical taxonomy of detected labels, and the version of the label model used for detection.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetlabelDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetLabelDetection</code>.</p>
     fn get_label_detection(
         &self,
         input: GetLabelDetectionRequest,
     ) -> RusotoFuture<GetLabelDetectionResponse, GetLabelDetectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetLabelDetection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLabelDetectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetLabelDetectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_label_detection(input)
     }
-
-    /// <p>Gets the path tracking results of a Amazon Rekognition Video analysis started by <a>StartPersonTracking</a>.</p> <p>The person path tracking operation is started by a call to <code>StartPersonTracking</code> which returns a job identifier (<code>JobId</code>). When the operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartPersonTracking</code>.</p> <p>To get the results of the person path tracking operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p> <p> <code>GetPersonTracking</code> returns an array, <code>Persons</code>, of tracked persons and the time(s) their paths were tracked in the video. </p> <note> <p> <code>GetPersonTracking</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. </p> <p>For more information, see FaceDetail in the Amazon Rekognition Developer Guide.</p> </note> <p>By default, the array is sorted by the time(s) a person's path is tracked in the video. You can sort by tracked persons by specifying <code>INDEX</code> for the <code>SortBy</code> input parameter.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of items returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetPersonTracking</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetPersonTracking</code>.</p>
     fn get_person_tracking(
         &self,
         input: GetPersonTrackingRequest,
     ) -> RusotoFuture<GetPersonTrackingResponse, GetPersonTrackingError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.GetPersonTracking");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPersonTrackingResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPersonTrackingError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_person_tracking(input)
     }
-
-    /// <p>Detects faces in the input image and adds them to the specified collection. </p> <p>Amazon Rekognition doesn't save the actual faces that are detected. Instead, the underlying detection algorithm first detects the faces in the input image. For each face, the algorithm extracts facial features into a feature vector, and stores it in the backend database. Amazon Rekognition uses feature vectors when it performs face match and search operations using the <a>SearchFaces</a> and <a>SearchFacesByImage</a> operations.</p> <p>For more information, see Adding Faces to a Collection in the Amazon Rekognition Developer Guide.</p> <p>To get the number of faces in a collection, call <a>DescribeCollection</a>. </p> <p>If you're using version 1.0 of the face detection model, <code>IndexFaces</code> indexes the 15 largest faces in the input image. Later versions of the face detection model index the 100 largest faces in the input image. </p> <p>If you're using version 4 or later of the face model, image orientation information is not returned in the <code>OrientationCorrection</code> field. </p> <p>To determine which version of the model you're using, call <a>DescribeCollection</a> and supply the collection ID. You can also get the model version from the value of <code>FaceModelVersion</code> in the response from <code>IndexFaces</code> </p> <p>For more information, see Model Versioning in the Amazon Rekognition Developer Guide.</p> <p>If you provide the optional <code>ExternalImageID</code> for the input image you provided, Amazon Rekognition associates this ID with all faces that it detects. When you call the <a>ListFaces</a> operation, the response returns the external ID. You can use this external image ID to create a client-side index to associate the faces with each image. You can then use the index to find all faces in an image.</p> <p>You can specify the maximum number of faces to index with the <code>MaxFaces</code> input parameter. This is useful when you want to index the largest faces in an image and don't want to index smaller faces, such as those belonging to people standing in the background.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet the required quality bar chosen by Amazon Rekognition. The quality bar is based on a variety of common use cases. By default, <code>IndexFaces</code> filters detected faces. You can also explicitly filter detected faces by specifying <code>AUTO</code> for the value of <code>QualityFilter</code>. If you do not want to filter detected faces, specify <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>Information about faces detected in an image, but not indexed, is returned in an array of <a>UnindexedFace</a> objects, <code>UnindexedFaces</code>. Faces aren't indexed for reasons such as:</p> <ul> <li> <p>The number of faces detected exceeds the value of the <code>MaxFaces</code> request parameter.</p> </li> <li> <p>The face is too small compared to the image dimensions.</p> </li> <li> <p>The face is too blurry.</p> </li> <li> <p>The image is too dark.</p> </li> <li> <p>The face has an extreme pose.</p> </li> </ul> <p>In response, the <code>IndexFaces</code> operation returns an array of metadata for all detected faces, <code>FaceRecords</code>. This includes: </p> <ul> <li> <p>The bounding box, <code>BoundingBox</code>, of the detected face. </p> </li> <li> <p>A confidence value, <code>Confidence</code>, which indicates the confidence that the bounding box contains a face.</p> </li> <li> <p>A face ID, <code>FaceId</code>, assigned by the service for each face that's detected and stored.</p> </li> <li> <p>An image ID, <code>ImageId</code>, assigned by the service for the input image.</p> </li> </ul> <p>If you request all facial attributes (by using the <code>detectionAttributes</code> parameter), Amazon Rekognition returns detailed facial attributes, such as facial landmarks (for example, location of eye and mouth) and other facial attributes like gender. If you provide the same image, specify the same collection, and use the same external ID in the <code>IndexFaces</code> operation, Amazon Rekognition doesn't save duplicate face metadata.</p> <p/> <p>The input image is passed either as base64-encoded image bytes, or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>This operation requires permissions to perform the <code>rekognition:IndexFaces</code> action.</p>
     fn index_faces(
         &self,
         input: IndexFacesRequest,
     ) -> RusotoFuture<IndexFacesResponse, IndexFacesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.IndexFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<IndexFacesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(IndexFacesError::from_response(response))),
-                )
-            }
-        })
+        (**self).index_faces(input)
     }
-
-    /// <p>Returns list of collection IDs in your account. If the result is truncated, the response also provides a <code>NextToken</code> that you can use in the subsequent request to fetch the next set of collection IDs.</p> <p>For an example, see Listing Collections in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListCollections</code> action.</p>
     fn list_collections(
         &self,
         input: ListCollectionsRequest,
     ) -> RusotoFuture<ListCollectionsResponse, ListCollectionsError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.ListCollections");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCollectionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListCollectionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_collections(input)
     }
-
-    /// <p>Returns metadata for faces in the specified collection. This metadata includes information such as the bounding box coordinates, the confidence (that the bounding box contains a face), and face ID. For an example, see Listing Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListFaces</code> action.</p>
     fn list_faces(
         &self,
         input: ListFacesRequest,
     ) -> RusotoFuture<ListFacesResponse, ListFacesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.ListFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFacesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFacesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_faces(input)
     }
-
-    /// <p>Gets a list of stream processors that you have created with <a>CreateStreamProcessor</a>. </p>
     fn list_stream_processors(
         &self,
         input: ListStreamProcessorsRequest,
     ) -> RusotoFuture<ListStreamProcessorsResponse, ListStreamProcessorsError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.ListStreamProcessors");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListStreamProcessorsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListStreamProcessorsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_stream_processors(input)
     }
-
-    /// <p>Returns an array of celebrities recognized in the input image. For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide. </p> <p> <code>RecognizeCelebrities</code> returns the 100 largest faces in the image. It lists recognized celebrities in the <code>CelebrityFaces</code> array and unrecognized faces in the <code>UnrecognizedFaces</code> array. <code>RecognizeCelebrities</code> doesn't return celebrities whose faces aren't among the largest 100 faces in the image.</p> <p>For each celebrity recognized, <code>RecognizeCelebrities</code> returns a <code>Celebrity</code> object. The <code>Celebrity</code> object contains the celebrity name, ID, URL links to additional information, match confidence, and a <code>ComparedFace</code> object that you can use to locate the celebrity's face on the image.</p> <p>Amazon Rekognition doesn't retain information about which images a celebrity has been recognized in. Your application must store this information and use the <code>Celebrity</code> ID property as a unique identifier for the celebrity. If you don't store the celebrity name or additional information URLs returned by <code>RecognizeCelebrities</code>, you will need the ID to identify the celebrity in a call to the <a>GetCelebrityInfo</a> operation.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p>For an example, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:RecognizeCelebrities</code> operation.</p>
     fn recognize_celebrities(
         &self,
         input: RecognizeCelebritiesRequest,
     ) -> RusotoFuture<RecognizeCelebritiesResponse, RecognizeCelebritiesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.RecognizeCelebrities");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RecognizeCelebritiesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RecognizeCelebritiesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).recognize_celebrities(input)
     }
-
-    /// <p>For a given input face ID, searches for matching faces in the collection the face belongs to. You get a face ID when you add a face to the collection using the <a>IndexFaces</a> operation. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>You can also search faces without indexing faces by using the <code>SearchFacesByImage</code> operation.</p> </note> <p> The operation response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match that is found. Along with the metadata, the response also includes a <code>confidence</code> value for each face match, indicating the confidence that the specific face matches the input face. </p> <p>For an example, see Searching for a Face Using Its Face ID in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFaces</code> action.</p>
     fn search_faces(
         &self,
         input: SearchFacesRequest,
     ) -> RusotoFuture<SearchFacesResponse, SearchFacesError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.SearchFaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchFacesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchFacesError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_faces(input)
     }
-
-    /// <p>For a given input image, first detects the largest face in the image, and then searches the specified collection for matching faces. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>To search for all faces in an input image, you might first call the <a>IndexFaces</a> operation, and then use the face IDs returned in subsequent calls to the <a>SearchFaces</a> operation. </p> <p> You can also call the <code>DetectFaces</code> operation and use the bounding boxes in the response to make face crops, which then you can pass in to the <code>SearchFacesByImage</code> operation. </p> </note> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> The response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match found. Along with the metadata, the response also includes a <code>similarity</code> indicating how similar the face is to the input face. In the response, the operation also returns the bounding box (and a confidence level that the bounding box contains a face) of the face that Amazon Rekognition used for the input image. </p> <p>For an example, Searching for a Face Using an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFacesByImage</code> action.</p>
     fn search_faces_by_image(
         &self,
         input: SearchFacesByImageRequest,
     ) -> RusotoFuture<SearchFacesByImageResponse, SearchFacesByImageError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.SearchFacesByImage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchFacesByImageResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchFacesByImageError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_faces_by_image(input)
     }
-
-    /// <p>Starts asynchronous recognition of celebrities in a stored video.</p> <p>Amazon Rekognition Video can detect celebrities in a video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartCelebrityRecognition</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When celebrity recognition analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetCelebrityRecognition</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityRecognition</code>. </p> <p>For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide.</p>
     fn start_celebrity_recognition(
         &self,
         input: StartCelebrityRecognitionRequest,
     ) -> RusotoFuture<StartCelebrityRecognitionResponse, StartCelebrityRecognitionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "RekognitionService.StartCelebrityRecognition",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartCelebrityRecognitionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartCelebrityRecognitionError::from_response(response))
-                }))
-            }
-        })
+        (**self).start_celebrity_recognition(input)
     }
-
-    /// <p> Starts asynchronous detection of unsafe content in a stored video.</p> <p>Amazon Rekognition Video can moderate content in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartContentModeration</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When unsafe content analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetContentModeration</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
     fn start_content_moderation(
         &self,
         input: StartContentModerationRequest,
     ) -> RusotoFuture<StartContentModerationResponse, StartContentModerationError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StartContentModeration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartContentModerationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartContentModerationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_content_moderation(input)
     }
-
-    /// <p>Starts asynchronous detection of faces in a stored video.</p> <p>Amazon Rekognition Video can detect faces in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When face detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p>For more information, see Detecting Faces in a Stored Video in the Amazon Rekognition Developer Guide.</p>
     fn start_face_detection(
         &self,
         input: StartFaceDetectionRequest,
     ) -> RusotoFuture<StartFaceDetectionResponse, StartFaceDetectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StartFaceDetection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartFaceDetectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartFaceDetectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_face_detection(input)
     }
-
-    /// <p>Starts the asynchronous search for faces in a collection that match the faces of persons detected in a stored video.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceSearch</code> returns a job identifier (<code>JobId</code>) which you use to get the search results once the search has completed. When searching is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceSearch</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>. For more information, see <a>procedure-person-search-videos</a>.</p>
     fn start_face_search(
         &self,
         input: StartFaceSearchRequest,
     ) -> RusotoFuture<StartFaceSearchResponse, StartFaceSearchError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StartFaceSearch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartFaceSearchResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartFaceSearchError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_face_search(input)
     }
-
-    /// <p><p>Starts asynchronous detection of labels in a stored video.</p> <p>Amazon Rekognition Video can detect labels in a video. Labels are instances of real-world entities. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; concepts like landscape, evening, and nature; and activities like a person getting out of a car or a person skiing.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartLabelDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p/></p>
     fn start_label_detection(
         &self,
         input: StartLabelDetectionRequest,
     ) -> RusotoFuture<StartLabelDetectionResponse, StartLabelDetectionError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StartLabelDetection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartLabelDetectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartLabelDetectionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_label_detection(input)
     }
-
-    /// <p>Starts the asynchronous tracking of a person's path in a stored video.</p> <p>Amazon Rekognition Video can track the path of people in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartPersonTracking</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. </p> <p>To get the results of the person detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p>
     fn start_person_tracking(
         &self,
         input: StartPersonTrackingRequest,
     ) -> RusotoFuture<StartPersonTrackingResponse, StartPersonTrackingError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StartPersonTracking");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartPersonTrackingResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartPersonTrackingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_person_tracking(input)
     }
-
-    /// <p>Starts processing a stream processor. You create a stream processor by calling <a>CreateStreamProcessor</a>. To tell <code>StartStreamProcessor</code> which stream processor to start, use the value of the <code>Name</code> field specified in the call to <code>CreateStreamProcessor</code>.</p>
     fn start_stream_processor(
         &self,
         input: StartStreamProcessorRequest,
     ) -> RusotoFuture<StartStreamProcessorResponse, StartStreamProcessorError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StartStreamProcessor");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartStreamProcessorResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartStreamProcessorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_stream_processor(input)
     }
-
-    /// <p>Stops a running stream processor that was created by <a>CreateStreamProcessor</a>.</p>
     fn stop_stream_processor(
         &self,
         input: StopStreamProcessorRequest,
     ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError> {
-        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "RekognitionService.StopStreamProcessor");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
+        (**self).stop_stream_processor(input)
+    }
+}
+impl<T: Rekognition + ?Sized> Rekognition for Box<T> {
+    fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> RusotoFuture<CompareFacesResponse, CompareFacesError> {
+        (**self).compare_faces(input)
+    }
+    fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> RusotoFuture<CreateCollectionResponse, CreateCollectionError> {
+        (**self).create_collection(input)
+    }
+    fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> RusotoFuture<CreateStreamProcessorResponse, CreateStreamProcessorError> {
+        (**self).create_stream_processor(input)
+    }
+    fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> RusotoFuture<DeleteCollectionResponse, DeleteCollectionError> {
+        (**self).delete_collection(input)
+    }
+    fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> RusotoFuture<DeleteFacesResponse, DeleteFacesError> {
+        (**self).delete_faces(input)
+    }
+    fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> RusotoFuture<DeleteStreamProcessorResponse, DeleteStreamProcessorError> {
+        (**self).delete_stream_processor(input)
+    }
+    fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> RusotoFuture<DescribeCollectionResponse, DescribeCollectionError> {
+        (**self).describe_collection(input)
+    }
+    fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> RusotoFuture<DescribeStreamProcessorResponse, DescribeStreamProcessorError> {
+        (**self).describe_stream_processor(input)
+    }
+    fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> RusotoFuture<DetectFacesResponse, DetectFacesError> {
+        (**self).detect_faces(input)
+    }
+    fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> RusotoFuture<DetectLabelsResponse, DetectLabelsError> {
+        (**self).detect_labels(input)
+    }
+    fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> RusotoFuture<DetectModerationLabelsResponse, DetectModerationLabelsError> {
+        (**self).detect_moderation_labels(input)
+    }
+    fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> RusotoFuture<DetectTextResponse, DetectTextError> {
+        (**self).detect_text(input)
+    }
+    fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> RusotoFuture<GetCelebrityInfoResponse, GetCelebrityInfoError> {
+        (**self).get_celebrity_info(input)
+    }
+    fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, GetCelebrityRecognitionError> {
+        (**self).get_celebrity_recognition(input)
+    }
+    fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> RusotoFuture<GetContentModerationResponse, GetContentModerationError> {
+        (**self).get_content_moderation(input)
+    }
+    fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> RusotoFuture<GetFaceDetectionResponse, GetFaceDetectionError> {
+        (**self).get_face_detection(input)
+    }
+    fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> RusotoFuture<GetFaceSearchResponse, GetFaceSearchError> {
+        (**self).get_face_search(input)
+    }
+    fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> RusotoFuture<GetLabelDetectionResponse, GetLabelDetectionError> {
+        (**self).get_label_detection(input)
+    }
+    fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> RusotoFuture<GetPersonTrackingResponse, GetPersonTrackingError> {
+        (**self).get_person_tracking(input)
+    }
+    fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> RusotoFuture<IndexFacesResponse, IndexFacesError> {
+        (**self).index_faces(input)
+    }
+    fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> RusotoFuture<ListCollectionsResponse, ListCollectionsError> {
+        (**self).list_collections(input)
+    }
+    fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> RusotoFuture<ListFacesResponse, ListFacesError> {
+        (**self).list_faces(input)
+    }
+    fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> RusotoFuture<ListStreamProcessorsResponse, ListStreamProcessorsError> {
+        (**self).list_stream_processors(input)
+    }
+    fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> RusotoFuture<RecognizeCelebritiesResponse, RecognizeCelebritiesError> {
+        (**self).recognize_celebrities(input)
+    }
+    fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> RusotoFuture<SearchFacesResponse, SearchFacesError> {
+        (**self).search_faces(input)
+    }
+    fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> RusotoFuture<SearchFacesByImageResponse, SearchFacesByImageError> {
+        (**self).search_faces_by_image(input)
+    }
+    fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> RusotoFuture<StartCelebrityRecognitionResponse, StartCelebrityRecognitionError> {
+        (**self).start_celebrity_recognition(input)
+    }
+    fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> RusotoFuture<StartContentModerationResponse, StartContentModerationError> {
+        (**self).start_content_moderation(input)
+    }
+    fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> RusotoFuture<StartFaceDetectionResponse, StartFaceDetectionError> {
+        (**self).start_face_detection(input)
+    }
+    fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> RusotoFuture<StartFaceSearchResponse, StartFaceSearchError> {
+        (**self).start_face_search(input)
+    }
+    fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> RusotoFuture<StartLabelDetectionResponse, StartLabelDetectionError> {
+        (**self).start_label_detection(input)
+    }
+    fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> RusotoFuture<StartPersonTrackingResponse, StartPersonTrackingError> {
+        (**self).start_person_tracking(input)
+    }
+    fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> RusotoFuture<StartStreamProcessorResponse, StartStreamProcessorError> {
+        (**self).start_stream_processor(input)
+    }
+    fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError> {
+        (**self).stop_stream_processor(input)
+    }
+}
+impl<T: Rekognition + ?Sized> Rekognition for ::std::sync::Arc<T> {
+    fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> RusotoFuture<CompareFacesResponse, CompareFacesError> {
+        (**self).compare_faces(input)
+    }
+    fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> RusotoFuture<CreateCollectionResponse, CreateCollectionError> {
+        (**self).create_collection(input)
+    }
+    fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> RusotoFuture<CreateStreamProcessorResponse, CreateStreamProcessorError> {
+        (**self).create_stream_processor(input)
+    }
+    fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> RusotoFuture<DeleteCollectionResponse, DeleteCollectionError> {
+        (**self).delete_collection(input)
+    }
+    fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> RusotoFuture<DeleteFacesResponse, DeleteFacesError> {
+        (**self).delete_faces(input)
+    }
+    fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> RusotoFuture<DeleteStreamProcessorResponse, DeleteStreamProcessorError> {
+        (**self).delete_stream_processor(input)
+    }
+    fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> RusotoFuture<DescribeCollectionResponse, DescribeCollectionError> {
+        (**self).describe_collection(input)
+    }
+    fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> RusotoFuture<DescribeStreamProcessorResponse, DescribeStreamProcessorError> {
+        (**self).describe_stream_processor(input)
+    }
+    fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> RusotoFuture<DetectFacesResponse, DetectFacesError> {
+        (**self).detect_faces(input)
+    }
+    fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> RusotoFuture<DetectLabelsResponse, DetectLabelsError> {
+        (**self).detect_labels(input)
+    }
+    fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> RusotoFuture<DetectModerationLabelsResponse, DetectModerationLabelsError> {
+        (**self).detect_moderation_labels(input)
+    }
+    fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> RusotoFuture<DetectTextResponse, DetectTextError> {
+        (**self).detect_text(input)
+    }
+    fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> RusotoFuture<GetCelebrityInfoResponse, GetCelebrityInfoError> {
+        (**self).get_celebrity_info(input)
+    }
+    fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, GetCelebrityRecognitionError> {
+        (**self).get_celebrity_recognition(input)
+    }
+    fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> RusotoFuture<GetContentModerationResponse, GetContentModerationError> {
+        (**self).get_content_moderation(input)
+    }
+    fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> RusotoFuture<GetFaceDetectionResponse, GetFaceDetectionError> {
+        (**self).get_face_detection(input)
+    }
+    fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> RusotoFuture<GetFaceSearchResponse, GetFaceSearchError> {
+        (**self).get_face_search(input)
+    }
+    fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> RusotoFuture<GetLabelDetectionResponse, GetLabelDetectionError> {
+        (**self).get_label_detection(input)
+    }
+    fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> RusotoFuture<GetPersonTrackingResponse, GetPersonTrackingError> {
+        (**self).get_person_tracking(input)
+    }
+    fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> RusotoFuture<IndexFacesResponse, IndexFacesError> {
+        (**self).index_faces(input)
+    }
+    fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> RusotoFuture<ListCollectionsResponse, ListCollectionsError> {
+        (**self).list_collections(input)
+    }
+    fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> RusotoFuture<ListFacesResponse, ListFacesError> {
+        (**self).list_faces(input)
+    }
+    fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> RusotoFuture<ListStreamProcessorsResponse, ListStreamProcessorsError> {
+        (**self).list_stream_processors(input)
+    }
+    fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> RusotoFuture<RecognizeCelebritiesResponse, RecognizeCelebritiesError> {
+        (**self).recognize_celebrities(input)
+    }
+    fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> RusotoFuture<SearchFacesResponse, SearchFacesError> {
+        (**self).search_faces(input)
+    }
+    fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> RusotoFuture<SearchFacesByImageResponse, SearchFacesByImageError> {
+        (**self).search_faces_by_image(input)
+    }
+    fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> RusotoFuture<StartCelebrityRecognitionResponse, StartCelebrityRecognitionError> {
+        (**self).start_celebrity_recognition(input)
+    }
+    fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> RusotoFuture<StartContentModerationResponse, StartContentModerationError> {
+        (**self).start_content_moderation(input)
+    }
+    fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> RusotoFuture<StartFaceDetectionResponse, StartFaceDetectionError> {
+        (**self).start_face_detection(input)
+    }
+    fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> RusotoFuture<StartFaceSearchResponse, StartFaceSearchError> {
+        (**self).start_face_search(input)
+    }
+    fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> RusotoFuture<StartLabelDetectionResponse, StartLabelDetectionError> {
+        (**self).start_label_detection(input)
+    }
+    fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> RusotoFuture<StartPersonTrackingResponse, StartPersonTrackingError> {
+        (**self).start_person_tracking(input)
+    }
+    fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> RusotoFuture<StartStreamProcessorResponse, StartStreamProcessorError> {
+        (**self).start_stream_processor(input)
+    }
+    fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError> {
+        (**self).stop_stream_processor(input)
+    }
+}
+/// A client for the Amazon Rekognition API.
+#[derive(Clone)]
+pub struct RekognitionClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl RekognitionClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RekognitionClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RekognitionClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> RekognitionClient {
+        RekognitionClient { client, region }
+    }
+}
+
+impl Rekognition for RekognitionClient {
+    /// <p>Compares a face in the <i>source</i> input image with each of the 100 largest faces detected in the <i>target</i> input image. </p> <note> <p> If the source image contains multiple faces, the service detects the largest face and compares it with each face detected in the target image. </p> </note> <p>You pass the input and target images either as base64-encoded image bytes or as references to images in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>In response, the operation returns an array of face matches ordered by similarity score in descending order. For each face match, the response provides a bounding box of the face, facial landmarks, pose details (pitch, role, and yaw), quality (brightness and sharpness), and confidence value (indicating the level of confidence that the bounding box contains a face). The response also provides a similarity score, which indicates how closely the faces match. </p> <note> <p>By default, only faces with a similarity score of greater than or equal to 80% are returned in the response. You can change this value by specifying the <code>SimilarityThreshold</code> parameter.</p> </note> <p> <code>CompareFaces</code> also returns an array of faces that don't match the source image. For each face, it returns a bounding box, confidence value, landmarks, pose details, and quality. The response also returns information about the face in the source image, including the bounding box of the face and confidence value.</p> <p>If the image doesn't contain Exif metadata, <code>CompareFaces</code> returns orientation information for the source and target images. Use these values to display the images with the correct image orientation.</p> <p>If no faces are detected in the source or target images, <code>CompareFaces</code> returns an <code>InvalidParameterException</code> error. </p> <note> <p> This is a stateless API operation. That is, data returned by this operation doesn't persist.</p> </note> <p>For an example, see Comparing Faces in Images in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:CompareFaces</code> action.</p>
+    fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> RusotoFuture<CompareFacesResponse, CompareFacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CompareFaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.CompareFaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CompareFacesResponse, _>(
+                    response,
+                    "CompareFaces",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CompareFacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a collection in an AWS Region. You can add faces to the collection using the <a>IndexFaces</a> operation. </p> <p>For example, you might create collections, one for each of your application users. A user can then index faces using the <code>IndexFaces</code> operation and persist results in a specific collection. Then, a user can search the collection for faces in the user-specific container. </p> <p>When you create a collection, it is associated with the latest version of the face model version.</p> <note> <p>Collection names are case-sensitive.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:CreateCollection</code> action.</p>
+    fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> RusotoFuture<CreateCollectionResponse, CreateCollectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateCollection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.CreateCollection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCollectionResponse, _>(
+                    response,
+                    "CreateCollection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateCollectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Amazon Rekognition stream processor that you can use to detect and recognize faces in a streaming video.</p> <p>Amazon Rekognition Video is a consumer of live video from Amazon Kinesis Video Streams. Amazon Rekognition Video sends analysis results to Amazon Kinesis Data Streams.</p> <p>You provide as input a Kinesis video stream (<code>Input</code>) and a Kinesis data stream (<code>Output</code>) stream. You also specify the face recognition criteria in <code>Settings</code>. For example, the collection containing faces that you want to recognize. Use <code>Name</code> to assign an identifier for the stream processor. You use <code>Name</code> to manage the stream processor. For example, you can start processing the source video by calling <a>StartStreamProcessor</a> with the <code>Name</code> field. </p> <p>After you have finished analyzing a streaming video, use <a>StopStreamProcessor</a> to stop processing. You can delete the stream processor by calling <a>DeleteStreamProcessor</a>.</p>
+    fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> RusotoFuture<CreateStreamProcessorResponse, CreateStreamProcessorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("CreateStreamProcessor");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.CreateStreamProcessor");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateStreamProcessorResponse, _>(
+                    response,
+                    "CreateStreamProcessor",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateStreamProcessorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified collection. Note that this operation removes all faces in the collection. For an example, see <a>delete-collection-procedure</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteCollection</code> action.</p>
+    fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> RusotoFuture<DeleteCollectionResponse, DeleteCollectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteCollection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DeleteCollection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteCollectionResponse, _>(
+                    response,
+                    "DeleteCollection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteCollectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes faces from a collection. You specify a collection ID and an array of face IDs to remove from the collection.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteFaces</code> action.</p>
+    fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> RusotoFuture<DeleteFacesResponse, DeleteFacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteFaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DeleteFaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteFacesResponse, _>(
+                    response,
+                    "DeleteFaces",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the stream processor identified by <code>Name</code>. You assign the value for <code>Name</code> when you create the stream processor with <a>CreateStreamProcessor</a>. You might not be able to use the same name for a stream processor for a few seconds after calling <code>DeleteStreamProcessor</code>.</p>
+    fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> RusotoFuture<DeleteStreamProcessorResponse, DeleteStreamProcessorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DeleteStreamProcessor");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DeleteStreamProcessor");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteStreamProcessorResponse, _>(
+                    response,
+                    "DeleteStreamProcessor",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteStreamProcessorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the specified collection. You can use <code>DescribeCollection</code> to get information, such as the number of faces indexed into a collection and the version of the model used by the collection for face detection.</p> <p>For more information, see Describing a Collection in the Amazon Rekognition Developer Guide.</p>
+    fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> RusotoFuture<DescribeCollectionResponse, DescribeCollectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeCollection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DescribeCollection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCollectionResponse, _>(
+                    response,
+                    "DescribeCollection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeCollectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides information about a stream processor created by <a>CreateStreamProcessor</a>. You can get information about the input and output streams, the input parameters for the face recognition being performed, and the current status of the stream processor.</p>
+    fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> RusotoFuture<DescribeStreamProcessorResponse, DescribeStreamProcessorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DescribeStreamProcessor");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DescribeStreamProcessor");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStreamProcessorResponse, _>(
+                    response,
+                    "DescribeStreamProcessor",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStreamProcessorError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Detects faces within an image that is provided as input.</p> <p> <code>DetectFaces</code> detects the 100 largest faces in the image. For each face detected, the operation returns face details. These details include a bounding box of the face, a confidence value (that the bounding box contains a face), and a fixed set of attributes such as facial landmarks (for example, coordinates of eye and mouth), gender, presence of beard, sunglasses, and so on. </p> <p>The face-detection algorithm is most effective on frontal faces. For non-frontal or obscured faces, the algorithm might not detect the faces or might detect faces with lower confidence. </p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <note> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:DetectFaces</code> action. </p>
+    fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> RusotoFuture<DetectFacesResponse, DetectFacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectFaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DetectFaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectFacesResponse, _>(
+                    response,
+                    "DetectFaces",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectFacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detects instances of real-world entities within an image (JPEG or PNG) provided as input. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; and concepts like landscape, evening, and nature. </p> <p>For an example, see Analyzing Images Stored in an Amazon S3 Bucket in the Amazon Rekognition Developer Guide.</p> <note> <p> <code>DetectLabels</code> does not support the detection of activities. However, activity detection is supported for label detection in videos. For more information, see StartLabelDetection in the Amazon Rekognition Developer Guide.</p> </note> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object, scene, and concept the API returns one or more labels. Each label provides the object name, and the level of confidence that the image contains the object. For example, suppose the input image has a lighthouse, the sea, and a rock. The response includes all three labels, one for each object. </p> <p> <code>{Name: lighthouse, Confidence: 98.4629}</code> </p> <p> <code>{Name: rock,Confidence: 79.2097}</code> </p> <p> <code> {Name: sea,Confidence: 75.061}</code> </p> <p>In the preceding example, the operation returns one label for each of the three objects. The operation can also return multiple labels for the same object in the image. For example, if the input image shows a flower (for example, a tulip), the operation might return the following three labels. </p> <p> <code>{Name: flower,Confidence: 99.0562}</code> </p> <p> <code>{Name: plant,Confidence: 99.0562}</code> </p> <p> <code>{Name: tulip,Confidence: 99.0562}</code> </p> <p>In this example, the detection algorithm more precisely identifies the flower as a tulip.</p> <p>In response, the API returns an array of labels. In addition, the response also includes the orientation correction. Optionally, you can specify <code>MinConfidence</code> to control the confidence threshold for the labels returned. The default is 55%. You can also add the <code>MaxLabels</code> parameter to limit the number of labels returned. </p> <note> <p>If the object detected is a person, the operation doesn't provide the same facial details that the <a>DetectFaces</a> operation provides.</p> </note> <p> <code>DetectLabels</code> returns bounding boxes for instances of common object labels in an array of <a>Instance</a> objects. An <code>Instance</code> object contains a <a>BoundingBox</a> object, for the location of the label on the image. It also includes the confidence by which the bounding box was detected.</p> <p> <code>DetectLabels</code> also returns a hierarchical taxonomy of detected labels. For example, a detected car might be assigned the label <i>car</i>. The label <i>car</i> has two parent labels: <i>Vehicle</i> (its parent) and <i>Transportation</i> (its grandparent). The response returns the entire list of ancestors for a label. Each ancestor is a unique label in the response. In the previous example, <i>Car</i>, <i>Vehicle</i>, and <i>Transportation</i> are returned as unique labels in the response. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectLabels</code> action. </p>
+    fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> RusotoFuture<DetectLabelsResponse, DetectLabelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectLabels");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DetectLabels");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectLabelsResponse, _>(
+                    response,
+                    "DetectLabels",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectLabelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detects unsafe content in a specified JPEG or PNG format image. Use <code>DetectModerationLabels</code> to moderate images depending on your requirements. For example, you might want to filter images that contain nudity, but not images containing suggestive content.</p> <p>To filter images, use the labels returned by <code>DetectModerationLabels</code> to determine which types of content are appropriate.</p> <p>For information about moderation labels, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p>
+    fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> RusotoFuture<DetectModerationLabelsResponse, DetectModerationLabelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectModerationLabels");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DetectModerationLabels");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectModerationLabelsResponse, _>(
+                    response,
+                    "DetectModerationLabels",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DetectModerationLabelsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Detects text in the input image and converts it into machine-readable text.</p> <p>Pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, you must pass it as a reference to an image in an Amazon S3 bucket. For the AWS CLI, passing image bytes is not supported. The image must be either a .png or .jpeg formatted file. </p> <p>The <code>DetectText</code> operation returns text in an array of <a>TextDetection</a> elements, <code>TextDetections</code>. Each <code>TextDetection</code> element provides information about a single word or line of text that was detected in the image. </p> <p>A word is one or more ISO basic latin script characters that are not separated by spaces. <code>DetectText</code> can detect up to 50 words in an image.</p> <p>A line is a string of equally spaced words. A line isn't necessarily a complete sentence. For example, a driver's license number is detected as a line. A line ends when there is no aligned text after it. Also, a line ends when there is a large gap between words, relative to the length of the words. This means, depending on the gap between words, Amazon Rekognition may detect multiple lines in text aligned in the same direction. Periods don't represent the end of a line. If a sentence spans multiple lines, the <code>DetectText</code> operation returns multiple lines.</p> <p>To determine whether a <code>TextDetection</code> element is a line of text or a word, use the <code>TextDetection</code> object <code>Type</code> field. </p> <p>To be detected, text must be within +/- 90 degrees orientation of the horizontal axis.</p> <p>For more information, see DetectText in the Amazon Rekognition Developer Guide.</p>
+    fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> RusotoFuture<DetectTextResponse, DetectTextError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("DetectText");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.DetectText");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetectTextResponse, _>(response, "DetectText")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetectTextError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the name and additional information about a celebrity based on his or her Amazon Rekognition ID. The additional information is returned as an array of URLs. If there is no additional information about the celebrity, this list is empty.</p> <p>For more information, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:GetCelebrityInfo</code> action. </p>
+    fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> RusotoFuture<GetCelebrityInfoResponse, GetCelebrityInfoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetCelebrityInfo");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetCelebrityInfo");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCelebrityInfoResponse, _>(
+                    response,
+                    "GetCelebrityInfo",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCelebrityInfoError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the celebrity recognition results for a Amazon Rekognition Video analysis started by <a>StartCelebrityRecognition</a>.</p> <p>Celebrity recognition in a video is an asynchronous operation. Analysis is started by a call to <a>StartCelebrityRecognition</a> which returns a job identifier (<code>JobId</code>). When the celebrity recognition operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartCelebrityRecognition</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetCelebrityDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityDetection</code>. </p> <p>For more information, see Working With Stored Videos in the Amazon Rekognition Developer Guide.</p> <p> <code>GetCelebrityRecognition</code> returns detected celebrities and the time(s) they are detected in an array (<code>Celebrities</code>) of <a>CelebrityRecognition</a> objects. Each <code>CelebrityRecognition</code> contains information about the celebrity in a <a>CelebrityDetail</a> object and the time, <code>Timestamp</code>, the celebrity was detected. </p> <note> <p> <code>GetCelebrityRecognition</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Celebrities</code> array is sorted by time (milliseconds from the start of the video). You can also sort the array by celebrity by specifying the value <code>ID</code> in the <code>SortBy</code> input parameter.</p> <p>The <code>CelebrityDetail</code> object includes the celebrity identifer and additional information urls. If you don't store the additional information urls, you can get them later by calling <a>GetCelebrityInfo</a> with the celebrity identifer.</p> <p>No information is returned for faces not recognized as celebrities.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetCelebrityDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetCelebrityRecognition</code>.</p>
+    fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, GetCelebrityRecognitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetCelebrityRecognition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetCelebrityRecognition");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCelebrityRecognitionResponse, _>(
+                    response,
+                    "GetCelebrityRecognition",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCelebrityRecognitionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the unsafe content analysis results for a Amazon Rekognition Video analysis started by <a>StartContentModeration</a>.</p> <p>Unsafe content analysis of a video is an asynchronous operation. You start analysis by calling <a>StartContentModeration</a> which returns a job identifier (<code>JobId</code>). When analysis finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartContentModeration</code>. To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetContentModeration</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Working with Stored Videos in the Amazon Rekognition Devlopers Guide.</p> <p> <code>GetContentModeration</code> returns detected unsafe content labels, and the time they are detected, in an array, <code>ModerationLabels</code>, of <a>ContentModerationDetection</a> objects. </p> <p>By default, the moderated labels are returned sorted by time, in milliseconds from the start of the video. You can also sort them by moderated label by specifying <code>NAME</code> for the <code>SortBy</code> input parameter. </p> <p>Since video analysis can return a large number of results, use the <code>MaxResults</code> parameter to limit the number of labels returned in a single call to <code>GetContentModeration</code>. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetContentModeration</code> and populate the <code>NextToken</code> request parameter with the value of <code>NextToken</code> returned from the previous call to <code>GetContentModeration</code>.</p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
+    fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> RusotoFuture<GetContentModerationResponse, GetContentModerationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetContentModeration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetContentModeration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetContentModerationResponse, _>(
+                    response,
+                    "GetContentModeration",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetContentModerationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets face detection results for a Amazon Rekognition Video analysis started by <a>StartFaceDetection</a>.</p> <p>Face detection with Amazon Rekognition Video is an asynchronous operation. You start face detection by calling <a>StartFaceDetection</a> which returns a job identifier (<code>JobId</code>). When the face detection operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceDetection</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p> <code>GetFaceDetection</code> returns an array of detected faces (<code>Faces</code>) sorted by the time the faces were detected. </p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetFaceDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetFaceDetection</code>.</p>
+    fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> RusotoFuture<GetFaceDetectionResponse, GetFaceDetectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetFaceDetection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetFaceDetection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetFaceDetectionResponse, _>(
+                    response,
+                    "GetFaceDetection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFaceDetectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the face search results for Amazon Rekognition Video face search started by <a>StartFaceSearch</a>. The search returns faces in a collection that match the faces of persons detected in a video. It also includes the time(s) that faces are matched in the video.</p> <p>Face search in a video is an asynchronous operation. You start face search by calling to <a>StartFaceSearch</a> which returns a job identifier (<code>JobId</code>). When the search operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceSearch</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetFaceSearch</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>.</p> <p>For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>The search results are retured in an array, <code>Persons</code>, of <a>PersonMatch</a> objects. Each<code>PersonMatch</code> element contains details about the matching faces in the input collection, person information (facial attributes, bounding boxes, and person identifer) for the matched person, and the time the person was matched in the video.</p> <note> <p> <code>GetFaceSearch</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Persons</code> array is sorted by the time, in milliseconds from the start of the video, persons are matched. You can also sort by persons by specifying <code>INDEX</code> for the <code>SORTBY</code> input parameter.</p>
+    fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> RusotoFuture<GetFaceSearchResponse, GetFaceSearchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetFaceSearch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetFaceSearch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetFaceSearchResponse, _>(
+                    response,
+                    "GetFaceSearch",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFaceSearchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the label detection results of a Amazon Rekognition Video analysis started by <a>StartLabelDetection</a>. </p> <p>The label detection operation is started by a call to <a>StartLabelDetection</a> which returns a job identifier (<code>JobId</code>). When the label detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartlabelDetection</code>. To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p> <code>GetLabelDetection</code> returns an array of detected labels (<code>Labels</code>) sorted by the time the labels were detected. You can also sort by the label name by specifying <code>NAME</code> for the <code>SortBy</code> input parameter.</p> <p>The labels returned include the label name, the percentage confidence in the accuracy of the detected label, and the time the label was detected in the video.</p> <p>The returned labels also include bounding box information for common objects, a hierarchical taxonomy of detected labels, and the version of the label model used for detection.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetlabelDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetLabelDetection</code>.</p>
+    fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> RusotoFuture<GetLabelDetectionResponse, GetLabelDetectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetLabelDetection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetLabelDetection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLabelDetectionResponse, _>(
+                    response,
+                    "GetLabelDetection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetLabelDetectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the path tracking results of a Amazon Rekognition Video analysis started by <a>StartPersonTracking</a>.</p> <p>The person path tracking operation is started by a call to <code>StartPersonTracking</code> which returns a job identifier (<code>JobId</code>). When the operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartPersonTracking</code>.</p> <p>To get the results of the person path tracking operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p> <p> <code>GetPersonTracking</code> returns an array, <code>Persons</code>, of tracked persons and the time(s) their paths were tracked in the video. </p> <note> <p> <code>GetPersonTracking</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. </p> <p>For more information, see FaceDetail in the Amazon Rekognition Developer Guide.</p> </note> <p>By default, the array is sorted by the time(s) a person's path is tracked in the video. You can sort by tracked persons by specifying <code>INDEX</code> for the <code>SortBy</code> input parameter.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of items returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetPersonTracking</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetPersonTracking</code>.</p>
+    fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> RusotoFuture<GetPersonTrackingResponse, GetPersonTrackingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("GetPersonTracking");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.GetPersonTracking");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPersonTrackingResponse, _>(
+                    response,
+                    "GetPersonTracking",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPersonTrackingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Detects faces in the input image and adds them to the specified collection. </p> <p>Amazon Rekognition doesn't save the actual faces that are detected. Instead, the underlying detection algorithm first detects the faces in the input image. For each face, the algorithm extracts facial features into a feature vector, and stores it in the backend database. Amazon Rekognition uses feature vectors when it performs face match and search operations using the <a>SearchFaces</a> and <a>SearchFacesByImage</a> operations.</p> <p>For more information, see Adding Faces to a Collection in the Amazon Rekognition Developer Guide.</p> <p>To get the number of faces in a collection, call <a>DescribeCollection</a>. </p> <p>If you're using version 1.0 of the face detection model, <code>IndexFaces</code> indexes the 15 largest faces in the input image. Later versions of the face detection model index the 100 largest faces in the input image. </p> <p>If you're using version 4 or later of the face model, image orientation information is not returned in the <code>OrientationCorrection</code> field. </p> <p>To determine which version of the model you're using, call <a>DescribeCollection</a> and supply the collection ID. You can also get the model version from the value of <code>FaceModelVersion</code> in the response from <code>IndexFaces</code> </p> <p>For more information, see Model Versioning in the Amazon Rekognition Developer Guide.</p> <p>If you provide the optional <code>ExternalImageID</code> for the input image you provided, Amazon Rekognition associates this ID with all faces that it detects. When you call the <a>ListFaces</a> operation, the response returns the external ID. You can use this external image ID to create a client-side index to associate the faces with each image. You can then use the index to find all faces in an image.</p> <p>You can specify the maximum number of faces to index with the <code>MaxFaces</code> input parameter. This is useful when you want to index the largest faces in an image and don't want to index smaller faces, such as those belonging to people standing in the background.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet the required quality bar chosen by Amazon Rekognition. The quality bar is based on a variety of common use cases. By default, <code>IndexFaces</code> filters detected faces. You can also explicitly filter detected faces by specifying <code>AUTO</code> for the value of <code>QualityFilter</code>. If you do not want to filter detected faces, specify <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>Information about faces detected in an image, but not indexed, is returned in an array of <a>UnindexedFace</a> objects, <code>UnindexedFaces</code>. Faces aren't indexed for reasons such as:</p> <ul> <li> <p>The number of faces detected exceeds the value of the <code>MaxFaces</code> request parameter.</p> </li> <li> <p>The face is too small compared to the image dimensions.</p> </li> <li> <p>The face is too blurry.</p> </li> <li> <p>The image is too dark.</p> </li> <li> <p>The face has an extreme pose.</p> </li> </ul> <p>In response, the <code>IndexFaces</code> operation returns an array of metadata for all detected faces, <code>FaceRecords</code>. This includes: </p> <ul> <li> <p>The bounding box, <code>BoundingBox</code>, of the detected face. </p> </li> <li> <p>A confidence value, <code>Confidence</code>, which indicates the confidence that the bounding box contains a face.</p> </li> <li> <p>A face ID, <code>FaceId</code>, assigned by the service for each face that's detected and stored.</p> </li> <li> <p>An image ID, <code>ImageId</code>, assigned by the service for the input image.</p> </li> </ul> <p>If you request all facial attributes (by using the <code>detectionAttributes</code> parameter), Amazon Rekognition returns detailed facial attributes, such as facial landmarks (for example, location of eye and mouth) and other facial attributes like gender. If you provide the same image, specify the same collection, and use the same external ID in the <code>IndexFaces</code> operation, Amazon Rekognition doesn't save duplicate face metadata.</p> <p/> <p>The input image is passed either as base64-encoded image bytes, or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>This operation requires permissions to perform the <code>rekognition:IndexFaces</code> action.</p>
+    fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> RusotoFuture<IndexFacesResponse, IndexFacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("IndexFaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.IndexFaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<IndexFacesResponse, _>(response, "IndexFaces")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(IndexFacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns list of collection IDs in your account. If the result is truncated, the response also provides a <code>NextToken</code> that you can use in the subsequent request to fetch the next set of collection IDs.</p> <p>For an example, see Listing Collections in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListCollections</code> action.</p>
+    fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> RusotoFuture<ListCollectionsResponse, ListCollectionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListCollections");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.ListCollections");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCollectionsResponse, _>(
+                    response,
+                    "ListCollections",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListCollectionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns metadata for faces in the specified collection. This metadata includes information such as the bounding box coordinates, the confidence (that the bounding box contains a face), and face ID. For an example, see Listing Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListFaces</code> action.</p>
+    fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> RusotoFuture<ListFacesResponse, ListFacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListFaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.ListFaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListFacesResponse, _>(response, "ListFaces")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of stream processors that you have created with <a>CreateStreamProcessor</a>. </p>
+    fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> RusotoFuture<ListStreamProcessorsResponse, ListStreamProcessorsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("ListStreamProcessors");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.ListStreamProcessors");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListStreamProcessorsResponse, _>(
+                    response,
+                    "ListStreamProcessors",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListStreamProcessorsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of celebrities recognized in the input image. For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide. </p> <p> <code>RecognizeCelebrities</code> returns the 100 largest faces in the image. It lists recognized celebrities in the <code>CelebrityFaces</code> array and unrecognized faces in the <code>UnrecognizedFaces</code> array. <code>RecognizeCelebrities</code> doesn't return celebrities whose faces aren't among the largest 100 faces in the image.</p> <p>For each celebrity recognized, <code>RecognizeCelebrities</code> returns a <code>Celebrity</code> object. The <code>Celebrity</code> object contains the celebrity name, ID, URL links to additional information, match confidence, and a <code>ComparedFace</code> object that you can use to locate the celebrity's face on the image.</p> <p>Amazon Rekognition doesn't retain information about which images a celebrity has been recognized in. Your application must store this information and use the <code>Celebrity</code> ID property as a unique identifier for the celebrity. If you don't store the celebrity name or additional information URLs returned by <code>RecognizeCelebrities</code>, you will need the ID to identify the celebrity in a call to the <a>GetCelebrityInfo</a> operation.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p>For an example, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:RecognizeCelebrities</code> operation.</p>
+    fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> RusotoFuture<RecognizeCelebritiesResponse, RecognizeCelebritiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("RecognizeCelebrities");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.RecognizeCelebrities");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RecognizeCelebritiesResponse, _>(
+                    response,
+                    "RecognizeCelebrities",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RecognizeCelebritiesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>For a given input face ID, searches for matching faces in the collection the face belongs to. You get a face ID when you add a face to the collection using the <a>IndexFaces</a> operation. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>You can also search faces without indexing faces by using the <code>SearchFacesByImage</code> operation.</p> </note> <p> The operation response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match that is found. Along with the metadata, the response also includes a <code>confidence</code> value for each face match, indicating the confidence that the specific face matches the input face. </p> <p>For an example, see Searching for a Face Using Its Face ID in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFaces</code> action.</p>
+    fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> RusotoFuture<SearchFacesResponse, SearchFacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("SearchFaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.SearchFaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchFacesResponse, _>(
+                    response,
+                    "SearchFaces",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchFacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>For a given input image, first detects the largest face in the image, and then searches the specified collection for matching faces. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>To search for all faces in an input image, you might first call the <a>IndexFaces</a> operation, and then use the face IDs returned in subsequent calls to the <a>SearchFaces</a> operation. </p> <p> You can also call the <code>DetectFaces</code> operation and use the bounding boxes in the response to make face crops, which then you can pass in to the <code>SearchFacesByImage</code> operation. </p> </note> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> The response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match found. Along with the metadata, the response also includes a <code>similarity</code> indicating how similar the face is to the input face. In the response, the operation also returns the bounding box (and a confidence level that the bounding box contains a face) of the face that Amazon Rekognition used for the input image. </p> <p>For an example, Searching for a Face Using an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFacesByImage</code> action.</p>
+    fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> RusotoFuture<SearchFacesByImageResponse, SearchFacesByImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("SearchFacesByImage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.SearchFacesByImage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchFacesByImageResponse, _>(
+                    response,
+                    "SearchFacesByImage",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchFacesByImageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts asynchronous recognition of celebrities in a stored video.</p> <p>Amazon Rekognition Video can detect celebrities in a video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartCelebrityRecognition</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When celebrity recognition analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetCelebrityRecognition</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityRecognition</code>. </p> <p>For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide.</p>
+    fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> RusotoFuture<StartCelebrityRecognitionResponse, StartCelebrityRecognitionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartCelebrityRecognition");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "RekognitionService.StartCelebrityRecognition",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartCelebrityRecognitionResponse, _>(
+                    response,
+                    "StartCelebrityRecognition",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopStreamProcessorResponse, _>()
+                    Err(StartCelebrityRecognitionError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p> Starts asynchronous detection of unsafe content in a stored video.</p> <p>Amazon Rekognition Video can moderate content in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartContentModeration</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When unsafe content analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetContentModeration</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
+    fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> RusotoFuture<StartContentModerationResponse, StartContentModerationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartContentModeration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StartContentModeration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartContentModerationResponse, _>(
+                    response,
+                    "StartContentModeration",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartContentModerationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts asynchronous detection of faces in a stored video.</p> <p>Amazon Rekognition Video can detect faces in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When face detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p>For more information, see Detecting Faces in a Stored Video in the Amazon Rekognition Developer Guide.</p>
+    fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> RusotoFuture<StartFaceDetectionResponse, StartFaceDetectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartFaceDetection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StartFaceDetection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartFaceDetectionResponse, _>(
+                    response,
+                    "StartFaceDetection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartFaceDetectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the asynchronous search for faces in a collection that match the faces of persons detected in a stored video.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceSearch</code> returns a job identifier (<code>JobId</code>) which you use to get the search results once the search has completed. When searching is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceSearch</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>. For more information, see <a>procedure-person-search-videos</a>.</p>
+    fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> RusotoFuture<StartFaceSearchResponse, StartFaceSearchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartFaceSearch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StartFaceSearch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartFaceSearchResponse, _>(
+                    response,
+                    "StartFaceSearch",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartFaceSearchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Starts asynchronous detection of labels in a stored video.</p> <p>Amazon Rekognition Video can detect labels in a video. Labels are instances of real-world entities. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; concepts like landscape, evening, and nature; and activities like a person getting out of a car or a person skiing.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartLabelDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p/></p>
+    fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> RusotoFuture<StartLabelDetectionResponse, StartLabelDetectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartLabelDetection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StartLabelDetection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartLabelDetectionResponse, _>(
+                    response,
+                    "StartLabelDetection",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartLabelDetectionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts the asynchronous tracking of a person's path in a stored video.</p> <p>Amazon Rekognition Video can track the path of people in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartPersonTracking</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. </p> <p>To get the results of the person detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p>
+    fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> RusotoFuture<StartPersonTrackingResponse, StartPersonTrackingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartPersonTracking");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StartPersonTracking");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartPersonTrackingResponse, _>(
+                    response,
+                    "StartPersonTracking",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartPersonTrackingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts processing a stream processor. You create a stream processor by calling <a>CreateStreamProcessor</a>. To tell <code>StartStreamProcessor</code> which stream processor to start, use the value of the <code>Name</code> field specified in the call to <code>CreateStreamProcessor</code>.</p>
+    fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> RusotoFuture<StartStreamProcessorResponse, StartStreamProcessorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StartStreamProcessor");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StartStreamProcessor");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartStreamProcessorResponse, _>(
+                    response,
+                    "StartStreamProcessor",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartStreamProcessorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a running stream processor that was created by <a>CreateStreamProcessor</a>.</p>
+    fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "rekognition", &self.region, "/");
+        request.set_operation("StopStreamProcessor");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "RekognitionService.StopStreamProcessor");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopStreamProcessorResponse, _>(
+                    response,
+                    "StopStreamProcessor",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -5770,3 +7813,1194 @@ impl Rekognition for RekognitionClient {
         })
     }
 }
+
+/// A mock of the Amazon Rekognition API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockRekognition {
+    compare_faces: Option<
+        Box<
+            dyn Fn(
+                    CompareFacesRequest,
+                )
+                    -> ::std::result::Result<CompareFacesResponse, RusotoError<CompareFacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_collection: Option<
+        Box<
+            dyn Fn(
+                    CreateCollectionRequest,
+                ) -> ::std::result::Result<
+                    CreateCollectionResponse,
+                    RusotoError<CreateCollectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_stream_processor: Option<
+        Box<
+            dyn Fn(
+                    CreateStreamProcessorRequest,
+                ) -> ::std::result::Result<
+                    CreateStreamProcessorResponse,
+                    RusotoError<CreateStreamProcessorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_collection: Option<
+        Box<
+            dyn Fn(
+                    DeleteCollectionRequest,
+                ) -> ::std::result::Result<
+                    DeleteCollectionResponse,
+                    RusotoError<DeleteCollectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_faces: Option<
+        Box<
+            dyn Fn(
+                    DeleteFacesRequest,
+                )
+                    -> ::std::result::Result<DeleteFacesResponse, RusotoError<DeleteFacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_stream_processor: Option<
+        Box<
+            dyn Fn(
+                    DeleteStreamProcessorRequest,
+                ) -> ::std::result::Result<
+                    DeleteStreamProcessorResponse,
+                    RusotoError<DeleteStreamProcessorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_collection: Option<
+        Box<
+            dyn Fn(
+                    DescribeCollectionRequest,
+                ) -> ::std::result::Result<
+                    DescribeCollectionResponse,
+                    RusotoError<DescribeCollectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_stream_processor: Option<
+        Box<
+            dyn Fn(
+                    DescribeStreamProcessorRequest,
+                ) -> ::std::result::Result<
+                    DescribeStreamProcessorResponse,
+                    RusotoError<DescribeStreamProcessorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_faces: Option<
+        Box<
+            dyn Fn(
+                    DetectFacesRequest,
+                )
+                    -> ::std::result::Result<DetectFacesResponse, RusotoError<DetectFacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detect_labels: Option<
+        Box<
+            dyn Fn(
+                    DetectLabelsRequest,
+                )
+                    -> ::std::result::Result<DetectLabelsResponse, RusotoError<DetectLabelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    detect_moderation_labels: Option<
+        Box<
+            dyn Fn(
+                    DetectModerationLabelsRequest,
+                ) -> ::std::result::Result<
+                    DetectModerationLabelsResponse,
+                    RusotoError<DetectModerationLabelsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_text: Option<
+        Box<
+            dyn Fn(
+                    DetectTextRequest,
+                )
+                    -> ::std::result::Result<DetectTextResponse, RusotoError<DetectTextError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_celebrity_info: Option<
+        Box<
+            dyn Fn(
+                    GetCelebrityInfoRequest,
+                ) -> ::std::result::Result<
+                    GetCelebrityInfoResponse,
+                    RusotoError<GetCelebrityInfoError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_celebrity_recognition: Option<
+        Box<
+            dyn Fn(
+                    GetCelebrityRecognitionRequest,
+                ) -> ::std::result::Result<
+                    GetCelebrityRecognitionResponse,
+                    RusotoError<GetCelebrityRecognitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_content_moderation: Option<
+        Box<
+            dyn Fn(
+                    GetContentModerationRequest,
+                ) -> ::std::result::Result<
+                    GetContentModerationResponse,
+                    RusotoError<GetContentModerationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_face_detection: Option<
+        Box<
+            dyn Fn(
+                    GetFaceDetectionRequest,
+                ) -> ::std::result::Result<
+                    GetFaceDetectionResponse,
+                    RusotoError<GetFaceDetectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_face_search: Option<
+        Box<
+            dyn Fn(
+                    GetFaceSearchRequest,
+                )
+                    -> ::std::result::Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_label_detection: Option<
+        Box<
+            dyn Fn(
+                    GetLabelDetectionRequest,
+                ) -> ::std::result::Result<
+                    GetLabelDetectionResponse,
+                    RusotoError<GetLabelDetectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_person_tracking: Option<
+        Box<
+            dyn Fn(
+                    GetPersonTrackingRequest,
+                ) -> ::std::result::Result<
+                    GetPersonTrackingResponse,
+                    RusotoError<GetPersonTrackingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    index_faces: Option<
+        Box<
+            dyn Fn(
+                    IndexFacesRequest,
+                )
+                    -> ::std::result::Result<IndexFacesResponse, RusotoError<IndexFacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_collections: Option<
+        Box<
+            dyn Fn(
+                    ListCollectionsRequest,
+                ) -> ::std::result::Result<
+                    ListCollectionsResponse,
+                    RusotoError<ListCollectionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_faces: Option<
+        Box<
+            dyn Fn(
+                    ListFacesRequest,
+                )
+                    -> ::std::result::Result<ListFacesResponse, RusotoError<ListFacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_stream_processors: Option<
+        Box<
+            dyn Fn(
+                    ListStreamProcessorsRequest,
+                ) -> ::std::result::Result<
+                    ListStreamProcessorsResponse,
+                    RusotoError<ListStreamProcessorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    recognize_celebrities: Option<
+        Box<
+            dyn Fn(
+                    RecognizeCelebritiesRequest,
+                ) -> ::std::result::Result<
+                    RecognizeCelebritiesResponse,
+                    RusotoError<RecognizeCelebritiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_faces: Option<
+        Box<
+            dyn Fn(
+                    SearchFacesRequest,
+                )
+                    -> ::std::result::Result<SearchFacesResponse, RusotoError<SearchFacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    search_faces_by_image: Option<
+        Box<
+            dyn Fn(
+                    SearchFacesByImageRequest,
+                ) -> ::std::result::Result<
+                    SearchFacesByImageResponse,
+                    RusotoError<SearchFacesByImageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_celebrity_recognition: Option<
+        Box<
+            dyn Fn(
+                    StartCelebrityRecognitionRequest,
+                ) -> ::std::result::Result<
+                    StartCelebrityRecognitionResponse,
+                    RusotoError<StartCelebrityRecognitionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_content_moderation: Option<
+        Box<
+            dyn Fn(
+                    StartContentModerationRequest,
+                ) -> ::std::result::Result<
+                    StartContentModerationResponse,
+                    RusotoError<StartContentModerationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_face_detection: Option<
+        Box<
+            dyn Fn(
+                    StartFaceDetectionRequest,
+                ) -> ::std::result::Result<
+                    StartFaceDetectionResponse,
+                    RusotoError<StartFaceDetectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_face_search: Option<
+        Box<
+            dyn Fn(
+                    StartFaceSearchRequest,
+                ) -> ::std::result::Result<
+                    StartFaceSearchResponse,
+                    RusotoError<StartFaceSearchError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_label_detection: Option<
+        Box<
+            dyn Fn(
+                    StartLabelDetectionRequest,
+                ) -> ::std::result::Result<
+                    StartLabelDetectionResponse,
+                    RusotoError<StartLabelDetectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_person_tracking: Option<
+        Box<
+            dyn Fn(
+                    StartPersonTrackingRequest,
+                ) -> ::std::result::Result<
+                    StartPersonTrackingResponse,
+                    RusotoError<StartPersonTrackingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_stream_processor: Option<
+        Box<
+            dyn Fn(
+                    StartStreamProcessorRequest,
+                ) -> ::std::result::Result<
+                    StartStreamProcessorResponse,
+                    RusotoError<StartStreamProcessorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_stream_processor: Option<
+        Box<
+            dyn Fn(
+                    StopStreamProcessorRequest,
+                ) -> ::std::result::Result<
+                    StopStreamProcessorResponse,
+                    RusotoError<StopStreamProcessorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockRekognition {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockRekognition {
+        Default::default()
+    }
+
+    /// Answers `compare_faces` with what `handler` returns for its input.
+    pub fn with_compare_faces<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                CompareFacesRequest,
+            )
+                -> ::std::result::Result<CompareFacesResponse, RusotoError<CompareFacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.compare_faces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_collection` with what `handler` returns for its input.
+    pub fn with_create_collection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                CreateCollectionRequest,
+            ) -> ::std::result::Result<
+                CreateCollectionResponse,
+                RusotoError<CreateCollectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_collection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_stream_processor` with what `handler` returns for its input.
+    pub fn with_create_stream_processor<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                CreateStreamProcessorRequest,
+            ) -> ::std::result::Result<
+                CreateStreamProcessorResponse,
+                RusotoError<CreateStreamProcessorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_stream_processor = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_collection` with what `handler` returns for its input.
+    pub fn with_delete_collection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DeleteCollectionRequest,
+            ) -> ::std::result::Result<
+                DeleteCollectionResponse,
+                RusotoError<DeleteCollectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_collection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_faces` with what `handler` returns for its input.
+    pub fn with_delete_faces<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DeleteFacesRequest,
+            )
+                -> ::std::result::Result<DeleteFacesResponse, RusotoError<DeleteFacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_faces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_stream_processor` with what `handler` returns for its input.
+    pub fn with_delete_stream_processor<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DeleteStreamProcessorRequest,
+            ) -> ::std::result::Result<
+                DeleteStreamProcessorResponse,
+                RusotoError<DeleteStreamProcessorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_stream_processor = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_collection` with what `handler` returns for its input.
+    pub fn with_describe_collection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DescribeCollectionRequest,
+            ) -> ::std::result::Result<
+                DescribeCollectionResponse,
+                RusotoError<DescribeCollectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_collection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_stream_processor` with what `handler` returns for its input.
+    pub fn with_describe_stream_processor<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DescribeStreamProcessorRequest,
+            ) -> ::std::result::Result<
+                DescribeStreamProcessorResponse,
+                RusotoError<DescribeStreamProcessorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_stream_processor = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_faces` with what `handler` returns for its input.
+    pub fn with_detect_faces<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DetectFacesRequest,
+            )
+                -> ::std::result::Result<DetectFacesResponse, RusotoError<DetectFacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_faces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_labels` with what `handler` returns for its input.
+    pub fn with_detect_labels<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DetectLabelsRequest,
+            )
+                -> ::std::result::Result<DetectLabelsResponse, RusotoError<DetectLabelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_labels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_moderation_labels` with what `handler` returns for its input.
+    pub fn with_detect_moderation_labels<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DetectModerationLabelsRequest,
+            ) -> ::std::result::Result<
+                DetectModerationLabelsResponse,
+                RusotoError<DetectModerationLabelsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_moderation_labels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_text` with what `handler` returns for its input.
+    pub fn with_detect_text<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                DetectTextRequest,
+            )
+                -> ::std::result::Result<DetectTextResponse, RusotoError<DetectTextError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_text = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_celebrity_info` with what `handler` returns for its input.
+    pub fn with_get_celebrity_info<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetCelebrityInfoRequest,
+            ) -> ::std::result::Result<
+                GetCelebrityInfoResponse,
+                RusotoError<GetCelebrityInfoError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_celebrity_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_celebrity_recognition` with what `handler` returns for its input.
+    pub fn with_get_celebrity_recognition<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetCelebrityRecognitionRequest,
+            ) -> ::std::result::Result<
+                GetCelebrityRecognitionResponse,
+                RusotoError<GetCelebrityRecognitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_celebrity_recognition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_content_moderation` with what `handler` returns for its input.
+    pub fn with_get_content_moderation<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetContentModerationRequest,
+            ) -> ::std::result::Result<
+                GetContentModerationResponse,
+                RusotoError<GetContentModerationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_content_moderation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_face_detection` with what `handler` returns for its input.
+    pub fn with_get_face_detection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetFaceDetectionRequest,
+            ) -> ::std::result::Result<
+                GetFaceDetectionResponse,
+                RusotoError<GetFaceDetectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_face_detection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_face_search` with what `handler` returns for its input.
+    pub fn with_get_face_search<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetFaceSearchRequest,
+            )
+                -> ::std::result::Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_face_search = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_label_detection` with what `handler` returns for its input.
+    pub fn with_get_label_detection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetLabelDetectionRequest,
+            ) -> ::std::result::Result<
+                GetLabelDetectionResponse,
+                RusotoError<GetLabelDetectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_label_detection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_person_tracking` with what `handler` returns for its input.
+    pub fn with_get_person_tracking<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                GetPersonTrackingRequest,
+            ) -> ::std::result::Result<
+                GetPersonTrackingResponse,
+                RusotoError<GetPersonTrackingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_person_tracking = Some(Box::new(handler));
+        self
+    }
+    /// Answers `index_faces` with what `handler` returns for its input.
+    pub fn with_index_faces<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                IndexFacesRequest,
+            )
+                -> ::std::result::Result<IndexFacesResponse, RusotoError<IndexFacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.index_faces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_collections` with what `handler` returns for its input.
+    pub fn with_list_collections<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                ListCollectionsRequest,
+            )
+                -> ::std::result::Result<ListCollectionsResponse, RusotoError<ListCollectionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_collections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_faces` with what `handler` returns for its input.
+    pub fn with_list_faces<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                ListFacesRequest,
+            ) -> ::std::result::Result<ListFacesResponse, RusotoError<ListFacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_faces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stream_processors` with what `handler` returns for its input.
+    pub fn with_list_stream_processors<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                ListStreamProcessorsRequest,
+            ) -> ::std::result::Result<
+                ListStreamProcessorsResponse,
+                RusotoError<ListStreamProcessorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stream_processors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `recognize_celebrities` with what `handler` returns for its input.
+    pub fn with_recognize_celebrities<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                RecognizeCelebritiesRequest,
+            ) -> ::std::result::Result<
+                RecognizeCelebritiesResponse,
+                RusotoError<RecognizeCelebritiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.recognize_celebrities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_faces` with what `handler` returns for its input.
+    pub fn with_search_faces<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                SearchFacesRequest,
+            )
+                -> ::std::result::Result<SearchFacesResponse, RusotoError<SearchFacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_faces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_faces_by_image` with what `handler` returns for its input.
+    pub fn with_search_faces_by_image<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                SearchFacesByImageRequest,
+            ) -> ::std::result::Result<
+                SearchFacesByImageResponse,
+                RusotoError<SearchFacesByImageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_faces_by_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_celebrity_recognition` with what `handler` returns for its input.
+    pub fn with_start_celebrity_recognition<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartCelebrityRecognitionRequest,
+            ) -> ::std::result::Result<
+                StartCelebrityRecognitionResponse,
+                RusotoError<StartCelebrityRecognitionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_celebrity_recognition = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_content_moderation` with what `handler` returns for its input.
+    pub fn with_start_content_moderation<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartContentModerationRequest,
+            ) -> ::std::result::Result<
+                StartContentModerationResponse,
+                RusotoError<StartContentModerationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_content_moderation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_face_detection` with what `handler` returns for its input.
+    pub fn with_start_face_detection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartFaceDetectionRequest,
+            ) -> ::std::result::Result<
+                StartFaceDetectionResponse,
+                RusotoError<StartFaceDetectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_face_detection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_face_search` with what `handler` returns for its input.
+    pub fn with_start_face_search<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartFaceSearchRequest,
+            )
+                -> ::std::result::Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_face_search = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_label_detection` with what `handler` returns for its input.
+    pub fn with_start_label_detection<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartLabelDetectionRequest,
+            ) -> ::std::result::Result<
+                StartLabelDetectionResponse,
+                RusotoError<StartLabelDetectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_label_detection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_person_tracking` with what `handler` returns for its input.
+    pub fn with_start_person_tracking<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartPersonTrackingRequest,
+            ) -> ::std::result::Result<
+                StartPersonTrackingResponse,
+                RusotoError<StartPersonTrackingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_person_tracking = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_stream_processor` with what `handler` returns for its input.
+    pub fn with_start_stream_processor<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StartStreamProcessorRequest,
+            ) -> ::std::result::Result<
+                StartStreamProcessorResponse,
+                RusotoError<StartStreamProcessorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_stream_processor = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_stream_processor` with what `handler` returns for its input.
+    pub fn with_stop_stream_processor<F>(mut self, handler: F) -> MockRekognition
+    where
+        F: Fn(
+                StopStreamProcessorRequest,
+            ) -> ::std::result::Result<
+                StopStreamProcessorResponse,
+                RusotoError<StopStreamProcessorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_stream_processor = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Rekognition for MockRekognition {
+    fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> RusotoFuture<CompareFacesResponse, CompareFacesError> {
+        match self.compare_faces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for compare_faces"),
+        }
+    }
+    fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> RusotoFuture<CreateCollectionResponse, CreateCollectionError> {
+        match self.create_collection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for create_collection"),
+        }
+    }
+    fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> RusotoFuture<CreateStreamProcessorResponse, CreateStreamProcessorError> {
+        match self.create_stream_processor {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for create_stream_processor"),
+        }
+    }
+    fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> RusotoFuture<DeleteCollectionResponse, DeleteCollectionError> {
+        match self.delete_collection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for delete_collection"),
+        }
+    }
+    fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> RusotoFuture<DeleteFacesResponse, DeleteFacesError> {
+        match self.delete_faces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for delete_faces"),
+        }
+    }
+    fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> RusotoFuture<DeleteStreamProcessorResponse, DeleteStreamProcessorError> {
+        match self.delete_stream_processor {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for delete_stream_processor"),
+        }
+    }
+    fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> RusotoFuture<DescribeCollectionResponse, DescribeCollectionError> {
+        match self.describe_collection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for describe_collection"),
+        }
+    }
+    fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> RusotoFuture<DescribeStreamProcessorResponse, DescribeStreamProcessorError> {
+        match self.describe_stream_processor {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for describe_stream_processor"),
+        }
+    }
+    fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> RusotoFuture<DetectFacesResponse, DetectFacesError> {
+        match self.detect_faces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for detect_faces"),
+        }
+    }
+    fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> RusotoFuture<DetectLabelsResponse, DetectLabelsError> {
+        match self.detect_labels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for detect_labels"),
+        }
+    }
+    fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> RusotoFuture<DetectModerationLabelsResponse, DetectModerationLabelsError> {
+        match self.detect_moderation_labels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for detect_moderation_labels"),
+        }
+    }
+    fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> RusotoFuture<DetectTextResponse, DetectTextError> {
+        match self.detect_text {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for detect_text"),
+        }
+    }
+    fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> RusotoFuture<GetCelebrityInfoResponse, GetCelebrityInfoError> {
+        match self.get_celebrity_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_celebrity_info"),
+        }
+    }
+    fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, GetCelebrityRecognitionError> {
+        match self.get_celebrity_recognition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_celebrity_recognition"),
+        }
+    }
+    fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> RusotoFuture<GetContentModerationResponse, GetContentModerationError> {
+        match self.get_content_moderation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_content_moderation"),
+        }
+    }
+    fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> RusotoFuture<GetFaceDetectionResponse, GetFaceDetectionError> {
+        match self.get_face_detection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_face_detection"),
+        }
+    }
+    fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> RusotoFuture<GetFaceSearchResponse, GetFaceSearchError> {
+        match self.get_face_search {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_face_search"),
+        }
+    }
+    fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> RusotoFuture<GetLabelDetectionResponse, GetLabelDetectionError> {
+        match self.get_label_detection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_label_detection"),
+        }
+    }
+    fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> RusotoFuture<GetPersonTrackingResponse, GetPersonTrackingError> {
+        match self.get_person_tracking {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for get_person_tracking"),
+        }
+    }
+    fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> RusotoFuture<IndexFacesResponse, IndexFacesError> {
+        match self.index_faces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for index_faces"),
+        }
+    }
+    fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> RusotoFuture<ListCollectionsResponse, ListCollectionsError> {
+        match self.list_collections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for list_collections"),
+        }
+    }
+    fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> RusotoFuture<ListFacesResponse, ListFacesError> {
+        match self.list_faces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for list_faces"),
+        }
+    }
+    fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> RusotoFuture<ListStreamProcessorsResponse, ListStreamProcessorsError> {
+        match self.list_stream_processors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for list_stream_processors"),
+        }
+    }
+    fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> RusotoFuture<RecognizeCelebritiesResponse, RecognizeCelebritiesError> {
+        match self.recognize_celebrities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for recognize_celebrities"),
+        }
+    }
+    fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> RusotoFuture<SearchFacesResponse, SearchFacesError> {
+        match self.search_faces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for search_faces"),
+        }
+    }
+    fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> RusotoFuture<SearchFacesByImageResponse, SearchFacesByImageError> {
+        match self.search_faces_by_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for search_faces_by_image"),
+        }
+    }
+    fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> RusotoFuture<StartCelebrityRecognitionResponse, StartCelebrityRecognitionError> {
+        match self.start_celebrity_recognition {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_celebrity_recognition"),
+        }
+    }
+    fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> RusotoFuture<StartContentModerationResponse, StartContentModerationError> {
+        match self.start_content_moderation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_content_moderation"),
+        }
+    }
+    fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> RusotoFuture<StartFaceDetectionResponse, StartFaceDetectionError> {
+        match self.start_face_detection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_face_detection"),
+        }
+    }
+    fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> RusotoFuture<StartFaceSearchResponse, StartFaceSearchError> {
+        match self.start_face_search {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_face_search"),
+        }
+    }
+    fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> RusotoFuture<StartLabelDetectionResponse, StartLabelDetectionError> {
+        match self.start_label_detection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_label_detection"),
+        }
+    }
+    fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> RusotoFuture<StartPersonTrackingResponse, StartPersonTrackingError> {
+        match self.start_person_tracking {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_person_tracking"),
+        }
+    }
+    fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> RusotoFuture<StartStreamProcessorResponse, StartStreamProcessorError> {
+        match self.start_stream_processor {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for start_stream_processor"),
+        }
+    }
+    fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError> {
+        match self.stop_stream_processor {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRekognition has no handler for stop_stream_processor"),
+        }
+    }
+}
@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupInput {
     /// <p>The description of the resource group. Descriptions can have a maximum of 511 characters, including letters, numbers, hyphens, underscores, punctuation, and spaces.</p>
     #[serde(rename = "Description")]
@@ -42,8 +44,42 @@ pub struct CreateGroupInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for CreateGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\sa-zA-Z0-9_\\.-]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_\\.-]+");
+        self.resource_query
+            .validate_at(&field_path(path, "resource_query"), errors);
+        if let Some(ref value) = self.tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupOutput {
     /// <p>A full description of the resource group after it is created.</p>
     #[serde(rename = "Group")]
@@ -60,6 +96,7 @@ pub struct CreateGroupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGroupInput {
     /// <p>The name of the resource group to delete.</p>
     #[serde(rename = "GroupName")]
@@ -67,7 +104,7 @@ pub struct DeleteGroupInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGroupOutput {
     /// <p>A full description of the deleted resource group.</p>
     #[serde(rename = "Group")]
@@ -76,6 +113,7 @@ pub struct DeleteGroupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupInput {
     /// <p>The name of the resource group.</p>
     #[serde(rename = "GroupName")]
@@ -83,7 +121,7 @@ pub struct GetGroupInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupOutput {
     /// <p>A full description of the resource group.</p>
     #[serde(rename = "Group")]
@@ -92,6 +130,7 @@ pub struct GetGroupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupQueryInput {
     /// <p>The name of the resource group.</p>
     #[serde(rename = "GroupName")]
@@ -99,7 +138,7 @@ pub struct GetGroupQueryInput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupQueryOutput {
     /// <p>The resource query associated with the specified group.</p>
     #[serde(rename = "GroupQuery")]
@@ -108,14 +147,27 @@ pub struct GetGroupQueryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagsInput {
     /// <p>The ARN of the resource group for which you want a list of tags. The resource must exist within the account you are using.</p>
     #[serde(rename = "Arn")]
     pub arn: String,
 }
 
+impl Validate for GetTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(12),
+            Some(1600),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:aws(-[a-z]+)*:resource-groups:[a-z]{2}(-[a-z]+)+-\\d{1}:[0-9]{12}:group/[a-zA-Z0-9_\\.-]{1,300}");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTagsOutput {
     /// <p>The ARN of the tagged resource group.</p>
     #[serde(rename = "Arn")]
@@ -129,7 +181,7 @@ pub struct GetTagsOutput {
 
 /// <p>A resource group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Group {
     /// <p>The description of the resource group.</p>
     #[serde(rename = "Description")]
@@ -145,6 +197,7 @@ pub struct Group {
 
 /// <p>A filter name and value pair that is used to obtain more specific results from a list of groups.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GroupFilter {
     /// <p>The name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
@@ -154,9 +207,29 @@ pub struct GroupFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for GroupFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "AWS::(AllSupported|[a-zA-Z0-9]+::[a-zA-Z0-9]+)",
+            );
+        }
+    }
+}
+
 /// <p>The ARN and group name of a group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupIdentifier {
     /// <p>The ARN of a resource group.</p>
     #[serde(rename = "GroupArn")]
@@ -170,7 +243,7 @@ pub struct GroupIdentifier {
 
 /// <p>The underlying resource query of a resource group. Resources that match query results are part of the group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupQuery {
     /// <p>The name of a resource group that is associated with a specific resource query.</p>
     #[serde(rename = "GroupName")]
@@ -181,6 +254,7 @@ pub struct GroupQuery {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupResourcesInput {
     /// <p><p>Filters, formatted as ResourceFilter objects, that you want to apply to a ListGroupResources operation.</p> <ul> <li> <p> <code>resource-type</code> - Filter resources by their type. Specify up to five resource types in the format AWS::ServiceCode::ResourceType. For example, AWS::EC2::Instance, or AWS::S3::Bucket.</p> </li> </ul></p>
     #[serde(rename = "Filters")]
@@ -199,8 +273,35 @@ pub struct ListGroupResourcesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListGroupResourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9+/]*={0,2}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupResourcesOutput {
     /// <p>The NextToken value to include in a subsequent <code>ListGroupResources</code> request, to get more results.</p>
     #[serde(rename = "NextToken")]
@@ -217,6 +318,7 @@ pub struct ListGroupResourcesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupsInput {
     /// <p><p>Filters, formatted as GroupFilter objects, that you want to apply to a ListGroups operation.</p> <ul> <li> <p> <code>resource-type</code> - Filter groups by resource type. Specify up to five resource types in the format AWS::ServiceCode::ResourceType. For example, AWS::EC2::Instance, or AWS::S3::Bucket.</p> </li> </ul></p>
     #[serde(rename = "Filters")]
@@ -232,8 +334,35 @@ pub struct ListGroupsInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListGroupsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9+/]*={0,2}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupsOutput {
     /// <p>A list of GroupIdentifier objects. Each identifier is an object that contains both the GroupName and the GroupArn.</p>
     #[serde(rename = "GroupIdentifiers")]
@@ -247,7 +376,7 @@ pub struct ListGroupsOutput {
 
 /// <p>A two-part error structure that can occur in <code>ListGroupResources</code> or <code>SearchResources</code> operations on CloudFormation stack-based queries. The error occurs if the CloudFormation stack on which the query is based either does not exist, or has a status that renders the stack inactive. A <code>QueryError</code> occurrence does not necessarily mean that AWS Resource Groups could not complete the operation, but the resulting group might have no member resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct QueryError {
     /// <p>Possible values are <code>CLOUDFORMATION_STACK_INACTIVE</code> and <code>CLOUDFORMATION_STACK_NOT_EXISTING</code>.</p>
     #[serde(rename = "ErrorCode")]
@@ -261,6 +390,7 @@ pub struct QueryError {
 
 /// <p>A filter name and value pair that is used to obtain more specific results from a list of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceFilter {
     /// <p>The name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
@@ -270,9 +400,25 @@ pub struct ResourceFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for ResourceFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "AWS::[a-zA-Z0-9]+::[a-zA-Z0-9]+");
+        }
+    }
+}
+
 /// <p>The ARN of a resource, and its resource type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceIdentifier {
     /// <p>The ARN of a resource.</p>
     #[serde(rename = "ResourceArn")]
@@ -295,7 +441,27 @@ pub struct ResourceQuery {
     pub type_: String,
 }
 
+impl Validate for ResourceQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "query"),
+            self.query.chars().count(),
+            None,
+            Some(4096),
+        );
+        errors.check_pattern(&field_path(path, "query"), &self.query, "[\\s\\S]*");
+        errors.check_length(
+            &field_path(path, "type_"),
+            self.type_.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "type_"), &self.type_, "^\\w+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchResourcesInput {
     /// <p>The maximum number of group member ARNs returned by <code>SearchResources</code> in paginated output. By default, this number is 50.</p>
     #[serde(rename = "MaxResults")]
@@ -310,8 +476,31 @@ pub struct SearchResourcesInput {
     pub resource_query: ResourceQuery,
 }
 
+impl Validate for SearchResourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "^[a-zA-Z0-9+/]*={0,2}$",
+            );
+        }
+        self.resource_query
+            .validate_at(&field_path(path, "resource_query"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchResourcesOutput {
     /// <p>The NextToken value to include in a subsequent <code>SearchResources</code> request, to get more results.</p>
     #[serde(rename = "NextToken")]
@@ -328,6 +517,7 @@ pub struct SearchResourcesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagInput {
     /// <p>The ARN of the resource to which to add tags.</p>
     #[serde(rename = "Arn")]
@@ -337,8 +527,25 @@ pub struct TagInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(12),
+            Some(1600),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:aws(-[a-z]+)*:resource-groups:[a-z]{2}(-[a-z]+)+-\\d{1}:[0-9]{12}:group/[a-zA-Z0-9_\\.-]{1,300}");
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagOutput {
     /// <p>The ARN of the tagged resource.</p>
     #[serde(rename = "Arn")]
@@ -351,6 +558,7 @@ pub struct TagOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagInput {
     /// <p>The ARN of the resource from which to remove tags.</p>
     #[serde(rename = "Arn")]
@@ -360,8 +568,25 @@ pub struct UntagInput {
     pub keys: Vec<String>,
 }
 
+impl Validate for UntagInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(12),
+            Some(1600),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:aws(-[a-z]+)*:resource-groups:[a-z]{2}(-[a-z]+)+-\\d{1}:[0-9]{12}:group/[a-zA-Z0-9_\\.-]{1,300}");
+        for (i, item) in self.keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagOutput {
     /// <p>The ARN of the resource from which tags have been removed.</p>
     #[serde(rename = "Arn")]
@@ -374,6 +599,7 @@ pub struct UntagOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGroupInput {
     /// <p>The description of the resource group. Descriptions can have a maximum of 511 characters, including letters, numbers, hyphens, underscores, punctuation, and spaces.</p>
     #[serde(rename = "Description")]
@@ -384,8 +610,26 @@ pub struct UpdateGroupInput {
     pub group_name: String,
 }
 
+impl Validate for UpdateGroupInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\sa-zA-Z0-9_\\.-]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGroupOutput {
     /// <p>The full description of the resource group after it has been updated.</p>
     #[serde(rename = "Group")]
@@ -394,6 +638,7 @@ pub struct UpdateGroupOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGroupQueryInput {
     /// <p>The name of the resource group for which you want to edit the query.</p>
     #[serde(rename = "GroupName")]
@@ -403,8 +648,15 @@ pub struct UpdateGroupQueryInput {
     pub resource_query: ResourceQuery,
 }
 
+impl Validate for UpdateGroupQueryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.resource_query
+            .validate_at(&field_path(path, "resource_query"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGroupQueryOutput {
     /// <p>The resource query associated with the resource group after the update.</p>
     #[serde(rename = "GroupQuery")]
@@ -446,7 +698,12 @@ impl CreateGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -508,7 +765,12 @@ impl DeleteGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -571,7 +833,12 @@ impl GetGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -634,7 +901,12 @@ impl GetGroupQueryError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetGroupQueryError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -697,7 +969,12 @@ impl GetTagsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -767,7 +1044,12 @@ impl ListGroupResourcesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListGroupResourcesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -826,7 +1108,12 @@ impl ListGroupsError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -888,7 +1175,12 @@ impl SearchResourcesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(SearchResourcesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -947,7 +1239,12 @@ impl TagError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(TagError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1008,7 +1305,12 @@ impl UntagError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UntagError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1071,7 +1373,12 @@ impl UpdateGroupError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1136,7 +1443,12 @@ impl UpdateGroupQueryError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateGroupQueryError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1222,6 +1534,193 @@ pub trait ResourceGroups {
         input: UpdateGroupQueryInput,
     ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError>;
 }
+const _: Option<&dyn ResourceGroups> = None;
+impl<'a, T: ResourceGroups + ?Sized> ResourceGroups for &'a T {
+    fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> RusotoFuture<CreateGroupOutput, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> RusotoFuture<DeleteGroupOutput, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn get_group(&self, input: GetGroupInput) -> RusotoFuture<GetGroupOutput, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> RusotoFuture<GetGroupQueryOutput, GetGroupQueryError> {
+        (**self).get_group_query(input)
+    }
+    fn get_tags(&self, input: GetTagsInput) -> RusotoFuture<GetTagsOutput, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> RusotoFuture<ListGroupResourcesOutput, ListGroupResourcesError> {
+        (**self).list_group_resources(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> RusotoFuture<ListGroupsOutput, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> RusotoFuture<SearchResourcesOutput, SearchResourcesError> {
+        (**self).search_resources(input)
+    }
+    fn tag(&self, input: TagInput) -> RusotoFuture<TagOutput, TagError> {
+        (**self).tag(input)
+    }
+    fn untag(&self, input: UntagInput) -> RusotoFuture<UntagOutput, UntagError> {
+        (**self).untag(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> RusotoFuture<UpdateGroupOutput, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError> {
+        (**self).update_group_query(input)
+    }
+}
+impl<T: ResourceGroups + ?Sized> ResourceGroups for Box<T> {
+    fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> RusotoFuture<CreateGroupOutput, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> RusotoFuture<DeleteGroupOutput, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn get_group(&self, input: GetGroupInput) -> RusotoFuture<GetGroupOutput, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> RusotoFuture<GetGroupQueryOutput, GetGroupQueryError> {
+        (**self).get_group_query(input)
+    }
+    fn get_tags(&self, input: GetTagsInput) -> RusotoFuture<GetTagsOutput, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> RusotoFuture<ListGroupResourcesOutput, ListGroupResourcesError> {
+        (**self).list_group_resources(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> RusotoFuture<ListGroupsOutput, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> RusotoFuture<SearchResourcesOutput, SearchResourcesError> {
+        (**self).search_resources(input)
+    }
+    fn tag(&self, input: TagInput) -> RusotoFuture<TagOutput, TagError> {
+        (**self).tag(input)
+    }
+    fn untag(&self, input: UntagInput) -> RusotoFuture<UntagOutput, UntagError> {
+        (**self).untag(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> RusotoFuture<UpdateGroupOutput, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError> {
+        (**self).update_group_query(input)
+    }
+}
+impl<T: ResourceGroups + ?Sized> ResourceGroups for ::std::sync::Arc<T> {
+    fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> RusotoFuture<CreateGroupOutput, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> RusotoFuture<DeleteGroupOutput, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn get_group(&self, input: GetGroupInput) -> RusotoFuture<GetGroupOutput, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> RusotoFuture<GetGroupQueryOutput, GetGroupQueryError> {
+        (**self).get_group_query(input)
+    }
+    fn get_tags(&self, input: GetTagsInput) -> RusotoFuture<GetTagsOutput, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> RusotoFuture<ListGroupResourcesOutput, ListGroupResourcesError> {
+        (**self).list_group_resources(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> RusotoFuture<ListGroupsOutput, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> RusotoFuture<SearchResourcesOutput, SearchResourcesError> {
+        (**self).search_resources(input)
+    }
+    fn tag(&self, input: TagInput) -> RusotoFuture<TagOutput, TagError> {
+        (**self).tag(input)
+    }
+    fn untag(&self, input: UntagInput) -> RusotoFuture<UntagOutput, UntagError> {
+        (**self).untag(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> RusotoFuture<UpdateGroupOutput, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError> {
+        (**self).update_group_query(input)
+    }
+}
 /// A client for the Resource Groups API.
 #[derive(Clone)]
 pub struct ResourceGroupsClient {
@@ -1265,9 +1764,19 @@ impl ResourceGroups for ResourceGroupsClient {
         &self,
         input: CreateGroupInput,
     ) -> RusotoFuture<CreateGroupOutput, CreateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/groups";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1277,6 +1786,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateGroup")
                         .deserialize::<CreateGroupOutput, _>()?;
 
                     Ok(result)
@@ -1301,12 +1811,16 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "resource-groups", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
+        request.add_resource_id("GroupName", &input.group_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteGroup")
                         .deserialize::<DeleteGroupOutput, _>()?;
 
                     Ok(result)
@@ -1327,12 +1841,16 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroup");
+        request.add_resource_id("GroupName", &input.group_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroup")
                         .deserialize::<GetGroupOutput, _>()?;
 
                     Ok(result)
@@ -1356,12 +1874,16 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroupQuery");
+        request.add_resource_id("GroupName", &input.group_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroupQuery")
                         .deserialize::<GetGroupQueryOutput, _>()?;
 
                     Ok(result)
@@ -1379,15 +1901,27 @@ impl ResourceGroups for ResourceGroupsClient {
 
     /// <p>Returns a list of tags that are associated with a resource group, specified by an ARN.</p>
     fn get_tags(&self, input: GetTagsInput) -> RusotoFuture<GetTagsOutput, GetTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetTags");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTags")
                         .deserialize::<GetTagsOutput, _>()?;
 
                     Ok(result)
@@ -1408,12 +1942,23 @@ impl ResourceGroups for ResourceGroupsClient {
         &self,
         input: ListGroupResourcesInput,
     ) -> RusotoFuture<ListGroupResourcesOutput, ListGroupResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/groups/{group_name}/resource-identifiers-list",
             group_name = input.group_name
         );
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroupResources");
+        request.add_resource_id("GroupName", &input.group_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1432,6 +1977,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListGroupResources")
                         .deserialize::<ListGroupResourcesOutput, _>()?;
 
                     Ok(result)
@@ -1452,9 +1998,19 @@ impl ResourceGroups for ResourceGroupsClient {
         &self,
         input: ListGroupsInput,
     ) -> RusotoFuture<ListGroupsOutput, ListGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/groups-list";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroups");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1473,6 +2029,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListGroups")
                         .deserialize::<ListGroupsOutput, _>()?;
 
                     Ok(result)
@@ -1493,9 +2050,19 @@ impl ResourceGroups for ResourceGroupsClient {
         &self,
         input: SearchResourcesInput,
     ) -> RusotoFuture<SearchResourcesOutput, SearchResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/resources/search";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("SearchResources");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1505,6 +2072,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SearchResources")
                         .deserialize::<SearchResourcesOutput, _>()?;
 
                     Ok(result)
@@ -1522,9 +2090,20 @@ impl ResourceGroups for ResourceGroupsClient {
 
     /// <p>Adds tags to a resource group with the specified ARN. Existing tags on a resource group are not changed if they are not specified in the request parameters.</p>
     fn tag(&self, input: TagInput) -> RusotoFuture<TagOutput, TagError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Tag");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1534,6 +2113,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("Tag")
                         .deserialize::<TagOutput, _>()?;
 
                     Ok(result)
@@ -1551,10 +2131,21 @@ impl ResourceGroups for ResourceGroupsClient {
 
     /// <p>Deletes specified tags from a specified resource.</p>
     fn untag(&self, input: UntagInput) -> RusotoFuture<UntagOutput, UntagError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request =
             SignedRequest::new("PATCH", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Untag");
+        request.add_resource_id("Arn", &input.arn.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1564,6 +2155,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("Untag")
                         .deserialize::<UntagOutput, _>()?;
 
                     Ok(result)
@@ -1584,9 +2176,20 @@ impl ResourceGroups for ResourceGroupsClient {
         &self,
         input: UpdateGroupInput,
     ) -> RusotoFuture<UpdateGroupOutput, UpdateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
+        request.add_resource_id("GroupName", &input.group_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1596,6 +2199,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGroup")
                         .deserialize::<UpdateGroupOutput, _>()?;
 
                     Ok(result)
@@ -1616,9 +2220,20 @@ impl ResourceGroups for ResourceGroupsClient {
         &self,
         input: UpdateGroupQueryInput,
     ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroupQuery");
+        request.add_resource_id("GroupName", &input.group_name.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1628,6 +2243,7 @@ impl ResourceGroups for ResourceGroupsClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGroupQuery")
                         .deserialize::<UpdateGroupQueryOutput, _>()?;
 
                     Ok(result)
@@ -1643,3 +2259,391 @@ impl ResourceGroups for ResourceGroupsClient {
         })
     }
 }
+
+/// A mock of the Resource Groups API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockResourceGroups {
+    create_group: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupInput,
+                )
+                    -> ::std::result::Result<CreateGroupOutput, RusotoError<CreateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteGroupInput,
+                )
+                    -> ::std::result::Result<DeleteGroupOutput, RusotoError<DeleteGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_group: Option<
+        Box<
+            dyn Fn(
+                    GetGroupInput,
+                )
+                    -> ::std::result::Result<GetGroupOutput, RusotoError<GetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_group_query: Option<
+        Box<
+            dyn Fn(
+                    GetGroupQueryInput,
+                )
+                    -> ::std::result::Result<GetGroupQueryOutput, RusotoError<GetGroupQueryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_tags: Option<
+        Box<
+            dyn Fn(GetTagsInput) -> ::std::result::Result<GetTagsOutput, RusotoError<GetTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_group_resources: Option<
+        Box<
+            dyn Fn(
+                    ListGroupResourcesInput,
+                ) -> ::std::result::Result<
+                    ListGroupResourcesOutput,
+                    RusotoError<ListGroupResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_groups: Option<
+        Box<
+            dyn Fn(
+                    ListGroupsInput,
+                )
+                    -> ::std::result::Result<ListGroupsOutput, RusotoError<ListGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    search_resources: Option<
+        Box<
+            dyn Fn(
+                    SearchResourcesInput,
+                ) -> ::std::result::Result<
+                    SearchResourcesOutput,
+                    RusotoError<SearchResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag: Option<
+        Box<
+            dyn Fn(TagInput) -> ::std::result::Result<TagOutput, RusotoError<TagError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag: Option<
+        Box<
+            dyn Fn(UntagInput) -> ::std::result::Result<UntagOutput, RusotoError<UntagError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateGroupInput,
+                )
+                    -> ::std::result::Result<UpdateGroupOutput, RusotoError<UpdateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_group_query: Option<
+        Box<
+            dyn Fn(
+                    UpdateGroupQueryInput,
+                ) -> ::std::result::Result<
+                    UpdateGroupQueryOutput,
+                    RusotoError<UpdateGroupQueryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockResourceGroups {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockResourceGroups {
+        Default::default()
+    }
+
+    /// Answers `create_group` with what `handler` returns for its input.
+    pub fn with_create_group<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                CreateGroupInput,
+            )
+                -> ::std::result::Result<CreateGroupOutput, RusotoError<CreateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_group` with what `handler` returns for its input.
+    pub fn with_delete_group<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                DeleteGroupInput,
+            )
+                -> ::std::result::Result<DeleteGroupOutput, RusotoError<DeleteGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group` with what `handler` returns for its input.
+    pub fn with_get_group<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(GetGroupInput) -> ::std::result::Result<GetGroupOutput, RusotoError<GetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group_query` with what `handler` returns for its input.
+    pub fn with_get_group_query<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                GetGroupQueryInput,
+            )
+                -> ::std::result::Result<GetGroupQueryOutput, RusotoError<GetGroupQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group_query = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tags` with what `handler` returns for its input.
+    pub fn with_get_tags<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(GetTagsInput) -> ::std::result::Result<GetTagsOutput, RusotoError<GetTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_group_resources` with what `handler` returns for its input.
+    pub fn with_list_group_resources<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                ListGroupResourcesInput,
+            ) -> ::std::result::Result<
+                ListGroupResourcesOutput,
+                RusotoError<ListGroupResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_group_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_groups` with what `handler` returns for its input.
+    pub fn with_list_groups<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                ListGroupsInput,
+            ) -> ::std::result::Result<ListGroupsOutput, RusotoError<ListGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_resources` with what `handler` returns for its input.
+    pub fn with_search_resources<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                SearchResourcesInput,
+            )
+                -> ::std::result::Result<SearchResourcesOutput, RusotoError<SearchResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag` with what `handler` returns for its input.
+    pub fn with_tag<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(TagInput) -> ::std::result::Result<TagOutput, RusotoError<TagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag` with what `handler` returns for its input.
+    pub fn with_untag<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(UntagInput) -> ::std::result::Result<UntagOutput, RusotoError<UntagError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_group` with what `handler` returns for its input.
+    pub fn with_update_group<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                UpdateGroupInput,
+            )
+                -> ::std::result::Result<UpdateGroupOutput, RusotoError<UpdateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_group_query` with what `handler` returns for its input.
+    pub fn with_update_group_query<F>(mut self, handler: F) -> MockResourceGroups
+    where
+        F: Fn(
+                UpdateGroupQueryInput,
+            )
+                -> ::std::result::Result<UpdateGroupQueryOutput, RusotoError<UpdateGroupQueryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_group_query = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ResourceGroups for MockResourceGroups {
+    fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> RusotoFuture<CreateGroupOutput, CreateGroupError> {
+        match self.create_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for create_group"),
+        }
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> RusotoFuture<DeleteGroupOutput, DeleteGroupError> {
+        match self.delete_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for delete_group"),
+        }
+    }
+    fn get_group(&self, input: GetGroupInput) -> RusotoFuture<GetGroupOutput, GetGroupError> {
+        match self.get_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for get_group"),
+        }
+    }
+    fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> RusotoFuture<GetGroupQueryOutput, GetGroupQueryError> {
+        match self.get_group_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for get_group_query"),
+        }
+    }
+    fn get_tags(&self, input: GetTagsInput) -> RusotoFuture<GetTagsOutput, GetTagsError> {
+        match self.get_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for get_tags"),
+        }
+    }
+    fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> RusotoFuture<ListGroupResourcesOutput, ListGroupResourcesError> {
+        match self.list_group_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for list_group_resources"),
+        }
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> RusotoFuture<ListGroupsOutput, ListGroupsError> {
+        match self.list_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for list_groups"),
+        }
+    }
+    fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> RusotoFuture<SearchResourcesOutput, SearchResourcesError> {
+        match self.search_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for search_resources"),
+        }
+    }
+    fn tag(&self, input: TagInput) -> RusotoFuture<TagOutput, TagError> {
+        match self.tag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for tag"),
+        }
+    }
+    fn untag(&self, input: UntagInput) -> RusotoFuture<UntagOutput, UntagError> {
+        match self.untag {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for untag"),
+        }
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> RusotoFuture<UpdateGroupOutput, UpdateGroupError> {
+        match self.update_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for update_group"),
+        }
+    }
+    fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError> {
+        match self.update_group_query {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroups has no handler for update_group_query"),
+        }
+    }
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Details of the common errors that all actions return.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailureInfo {
     /// <p>The code of the common error. Valid values include <code>InternalServiceException</code>, <code>InvalidParameterException</code>, and any valid error code returned by the AWS service that hosts the resource that you want to tag.</p>
     #[serde(rename = "ErrorCode")]
@@ -42,6 +43,7 @@ pub struct FailureInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourcesInput {
     /// <p>A string that indicates that additional data is available. Leave this value empty for your initial request. If the response includes a <code>PaginationToken</code>, use that string for this value to request an additional page of data.</p>
     #[serde(rename = "PaginationToken")]
@@ -65,8 +67,41 @@ pub struct GetResourcesInput {
     pub tags_per_page: Option<i64>,
 }
 
+impl Validate for GetResourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\s\\S]*");
+        }
+        if let Some(ref value) = self.resource_type_filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_type_filters"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "[\\s\\S]*");
+            }
+        }
+        if let Some(ref value) = self.tag_filters {
+            errors.check_length(
+                &field_path(path, "tag_filters"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourcesOutput {
     /// <p>A string that indicates that the response contains more data than can be returned in a single response. To receive additional data, specify this string for the <code>PaginationToken</code> value in a subsequent request.</p>
     #[serde(rename = "PaginationToken")]
@@ -79,6 +114,7 @@ pub struct GetResourcesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagKeysInput {
     /// <p>A string that indicates that additional data is available. Leave this value empty for your initial request. If the response includes a PaginationToken, use that string for this value to request an additional page of data.</p>
     #[serde(rename = "PaginationToken")]
@@ -86,8 +122,22 @@ pub struct GetTagKeysInput {
     pub pagination_token: Option<String>,
 }
 
+impl Validate for GetTagKeysInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTagKeysOutput {
     /// <p>A string that indicates that the response contains more data than can be returned in a single response. To receive additional data, specify this string for the <code>PaginationToken</code> value in a subsequent request.</p>
     #[serde(rename = "PaginationToken")]
@@ -100,6 +150,7 @@ pub struct GetTagKeysOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTagValuesInput {
     /// <p>The key for which you want to list all existing values in the specified region for the AWS account.</p>
     #[serde(rename = "Key")]
@@ -110,8 +161,29 @@ pub struct GetTagValuesInput {
     pub pagination_token: Option<String>,
 }
 
+impl Validate for GetTagValuesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "key"), &self.key, "[\\s\\S]*");
+        if let Some(ref value) = self.pagination_token {
+            errors.check_length(
+                &field_path(path, "pagination_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "pagination_token"), &value, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTagValuesOutput {
     /// <p>A string that indicates that the response contains more data than can be returned in a single response. To receive additional data, specify this string for the <code>PaginationToken</code> value in a subsequent request.</p>
     #[serde(rename = "PaginationToken")]
@@ -125,7 +197,7 @@ pub struct GetTagValuesOutput {
 
 /// <p>A list of resource ARNs and the tags (keys and values) that are associated with each.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceTagMapping {
     /// <p>The ARN of the resource.</p>
     #[serde(rename = "ResourceARN")]
@@ -139,7 +211,7 @@ pub struct ResourceTagMapping {
 
 /// <p>The metadata that you apply to AWS resources to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define. For more information, see <a href="http://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html#tag-basics">Tag Basics</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Tag {
     /// <p>One part of a key-value pair that make up a tag. A key is a general label that acts like a category for more specific tag values.</p>
     #[serde(rename = "Key")]
@@ -151,6 +223,7 @@ pub struct Tag {
 
 /// <p>A list of tags (keys and values) that are used to specify the associated resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagFilter {
     /// <p>One part of a key-value pair that make up a tag. A key is a general label that acts like a category for more specific tag values.</p>
     #[serde(rename = "Key")]
@@ -162,7 +235,30 @@ pub struct TagFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for TagFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "[\\s\\S]*");
+        }
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, "[\\s\\S]*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourcesInput {
     /// <p>A list of ARNs. An ARN (Amazon Resource Name) uniquely identifies a resource. You can specify a minimum of 1 and a maximum of 20 ARNs (resources) to tag. An ARN can be set to a maximum of 1600 characters. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a> in the <i>AWS General Reference</i>.</p>
     #[serde(rename = "ResourceARNList")]
@@ -172,8 +268,35 @@ pub struct TagResourcesInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn_list"),
+            self.resource_arn_list.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.resource_arn_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_arn_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1011));
+            errors.check_pattern(&item_path, &item, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourcesOutput {
     /// <p>Details of resources that could not be tagged. An error code, status code, and error message are returned for each failed item.</p>
     #[serde(rename = "FailedResourcesMap")]
@@ -182,6 +305,7 @@ pub struct TagResourcesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourcesInput {
     /// <p>A list of ARNs. An ARN (Amazon Resource Name) uniquely identifies a resource. You can specify a minimum of 1 and a maximum of 20 ARNs (resources) to untag. An ARN can be set to a maximum of 1600 characters. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a> in the <i>AWS General Reference</i>.</p>
     #[serde(rename = "ResourceARNList")]
@@ -191,8 +315,35 @@ pub struct UntagResourcesInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourcesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn_list"),
+            self.resource_arn_list.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.resource_arn_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_arn_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1011));
+            errors.check_pattern(&item_path, &item, "[\\s\\S]*");
+        }
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "[\\s\\S]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourcesOutput {
     /// <p>Details of resources that could not be untagged. An error code, status code, and error message are returned for each failed item.</p>
     #[serde(rename = "FailedResourcesMap")]
@@ -229,7 +380,12 @@ impl GetResourcesError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetResourcesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -280,7 +436,12 @@ impl GetTagKeysError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetTagKeysError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -331,7 +492,12 @@ impl GetTagValuesError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetTagValuesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -377,7 +543,12 @@ impl TagResourcesError {
                 "ThrottledException" => {
                     return RusotoError::Service(TagResourcesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -422,7 +593,12 @@ impl UntagResourcesError {
                 "ThrottledException" => {
                     return RusotoError::Service(UntagResourcesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -475,6 +651,103 @@ pub trait ResourceGroupsTaggingApi {
         input: UntagResourcesInput,
     ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError>;
 }
+const _: Option<&dyn ResourceGroupsTaggingApi> = None;
+impl<'a, T: ResourceGroupsTaggingApi + ?Sized> ResourceGroupsTaggingApi for &'a T {
+    fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> RusotoFuture<GetResourcesOutput, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> RusotoFuture<GetTagKeysOutput, GetTagKeysError> {
+        (**self).get_tag_keys(input)
+    }
+    fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> RusotoFuture<GetTagValuesOutput, GetTagValuesError> {
+        (**self).get_tag_values(input)
+    }
+    fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> RusotoFuture<TagResourcesOutput, TagResourcesError> {
+        (**self).tag_resources(input)
+    }
+    fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError> {
+        (**self).untag_resources(input)
+    }
+}
+impl<T: ResourceGroupsTaggingApi + ?Sized> ResourceGroupsTaggingApi for Box<T> {
+    fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> RusotoFuture<GetResourcesOutput, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> RusotoFuture<GetTagKeysOutput, GetTagKeysError> {
+        (**self).get_tag_keys(input)
+    }
+    fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> RusotoFuture<GetTagValuesOutput, GetTagValuesError> {
+        (**self).get_tag_values(input)
+    }
+    fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> RusotoFuture<TagResourcesOutput, TagResourcesError> {
+        (**self).tag_resources(input)
+    }
+    fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError> {
+        (**self).untag_resources(input)
+    }
+}
+impl<T: ResourceGroupsTaggingApi + ?Sized> ResourceGroupsTaggingApi for ::std::sync::Arc<T> {
+    fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> RusotoFuture<GetResourcesOutput, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> RusotoFuture<GetTagKeysOutput, GetTagKeysError> {
+        (**self).get_tag_keys(input)
+    }
+    fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> RusotoFuture<GetTagValuesOutput, GetTagValuesError> {
+        (**self).get_tag_values(input)
+    }
+    fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> RusotoFuture<TagResourcesOutput, TagResourcesError> {
+        (**self).tag_resources(input)
+    }
+    fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError> {
+        (**self).untag_resources(input)
+    }
+}
 /// A client for the AWS Resource Groups Tagging API API.
 #[derive(Clone)]
 pub struct ResourceGroupsTaggingApiClient {
@@ -521,7 +794,16 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
         input: GetResourcesInput,
     ) -> RusotoFuture<GetResourcesOutput, GetResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -533,10 +815,10 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourcesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetResourcesOutput, _>(
+                    response,
+                    "GetResources",
+                )
             } else {
                 Box::new(
                     response
@@ -553,7 +835,16 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
         input: GetTagKeysInput,
     ) -> RusotoFuture<GetTagKeysOutput, GetTagKeysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetTagKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -565,10 +856,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTagKeysOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetTagKeysOutput, _>(response, "GetTagKeys")
             } else {
                 Box::new(
                     response
@@ -585,7 +873,16 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
         input: GetTagValuesInput,
     ) -> RusotoFuture<GetTagValuesOutput, GetTagValuesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("GetTagValues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -597,10 +894,10 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTagValuesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetTagValuesOutput, _>(
+                    response,
+                    "GetTagValues",
+                )
             } else {
                 Box::new(
                     response
@@ -617,7 +914,16 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
         input: TagResourcesInput,
     ) -> RusotoFuture<TagResourcesOutput, TagResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("TagResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -629,10 +935,10 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourcesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourcesOutput, _>(
+                    response,
+                    "TagResources",
+                )
             } else {
                 Box::new(
                     response
@@ -649,7 +955,16 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
         input: UntagResourcesInput,
     ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "tagging", &self.region, "/");
+        request.set_operation("UntagResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -661,10 +976,10 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourcesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourcesOutput, _>(
+                    response,
+                    "UntagResources",
+                )
             } else {
                 Box::new(
                     response
@@ -676,3 +991,189 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         })
     }
 }
+
+/// A mock of the AWS Resource Groups Tagging API API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockResourceGroupsTaggingApi {
+    get_resources: Option<
+        Box<
+            dyn Fn(
+                    GetResourcesInput,
+                )
+                    -> ::std::result::Result<GetResourcesOutput, RusotoError<GetResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_tag_keys: Option<
+        Box<
+            dyn Fn(
+                    GetTagKeysInput,
+                )
+                    -> ::std::result::Result<GetTagKeysOutput, RusotoError<GetTagKeysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_tag_values: Option<
+        Box<
+            dyn Fn(
+                    GetTagValuesInput,
+                )
+                    -> ::std::result::Result<GetTagValuesOutput, RusotoError<GetTagValuesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resources: Option<
+        Box<
+            dyn Fn(
+                    TagResourcesInput,
+                )
+                    -> ::std::result::Result<TagResourcesOutput, RusotoError<TagResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resources: Option<
+        Box<
+            dyn Fn(
+                    UntagResourcesInput,
+                )
+                    -> ::std::result::Result<UntagResourcesOutput, RusotoError<UntagResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockResourceGroupsTaggingApi {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockResourceGroupsTaggingApi {
+        Default::default()
+    }
+
+    /// Answers `get_resources` with what `handler` returns for its input.
+    pub fn with_get_resources<F>(mut self, handler: F) -> MockResourceGroupsTaggingApi
+    where
+        F: Fn(
+                GetResourcesInput,
+            )
+                -> ::std::result::Result<GetResourcesOutput, RusotoError<GetResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tag_keys` with what `handler` returns for its input.
+    pub fn with_get_tag_keys<F>(mut self, handler: F) -> MockResourceGroupsTaggingApi
+    where
+        F: Fn(
+                GetTagKeysInput,
+            ) -> ::std::result::Result<GetTagKeysOutput, RusotoError<GetTagKeysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tag_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_tag_values` with what `handler` returns for its input.
+    pub fn with_get_tag_values<F>(mut self, handler: F) -> MockResourceGroupsTaggingApi
+    where
+        F: Fn(
+                GetTagValuesInput,
+            )
+                -> ::std::result::Result<GetTagValuesOutput, RusotoError<GetTagValuesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_tag_values = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resources` with what `handler` returns for its input.
+    pub fn with_tag_resources<F>(mut self, handler: F) -> MockResourceGroupsTaggingApi
+    where
+        F: Fn(
+                TagResourcesInput,
+            )
+                -> ::std::result::Result<TagResourcesOutput, RusotoError<TagResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resources` with what `handler` returns for its input.
+    pub fn with_untag_resources<F>(mut self, handler: F) -> MockResourceGroupsTaggingApi
+    where
+        F: Fn(
+                UntagResourcesInput,
+            )
+                -> ::std::result::Result<UntagResourcesOutput, RusotoError<UntagResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resources = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ResourceGroupsTaggingApi for MockResourceGroupsTaggingApi {
+    fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> RusotoFuture<GetResourcesOutput, GetResourcesError> {
+        match self.get_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroupsTaggingApi has no handler for get_resources"),
+        }
+    }
+    fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> RusotoFuture<GetTagKeysOutput, GetTagKeysError> {
+        match self.get_tag_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroupsTaggingApi has no handler for get_tag_keys"),
+        }
+    }
+    fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> RusotoFuture<GetTagValuesOutput, GetTagValuesError> {
+        match self.get_tag_values {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroupsTaggingApi has no handler for get_tag_values"),
+        }
+    }
+    fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> RusotoFuture<TagResourcesOutput, TagResourcesError> {
+        match self.tag_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroupsTaggingApi has no handler for tag_resources"),
+        }
+    }
+    fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError> {
+        match self.untag_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockResourceGroupsTaggingApi has no handler for untag_resources"),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Alias records, such as those pointing a domain at the website endpoint of an S3 bucket.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use rusoto_core::Region;
+//! use rusoto_route53::{AliasTarget, Route53Client};
+//!
+//! let region = Region::EuWest1;
+//! let target = AliasTarget {
+//!     dns_name: rusoto_s3::website_endpoint(&region).unwrap().to_owned(),
+//!     evaluate_target_health: false,
+//!     hosted_zone_id: rusoto_s3::website_hosted_zone_id(&region).unwrap().to_owned(),
+//! };
+//! let client = Route53Client::new(Region::UsEast1);
+//! client
+//!     .upsert_alias("Z1D633PJN98FT9", "www.example.com", target)
+//!     .sync()
+//!     .unwrap();
+//! ```
+
+use rusoto_core::RusotoFuture;
+
+use crate::generated::{
+    AliasTarget, Change, ChangeBatch, ChangeResourceRecordSetsError,
+    ChangeResourceRecordSetsRequest, ChangeResourceRecordSetsResponse, ResourceRecordSet, Route53,
+    Route53Client,
+};
+
+/// The change batch creating an alias A record named `record_name` pointing at `target`,
+/// or updating it if it exists.
+pub fn upsert_alias_change_batch(record_name: &str, target: AliasTarget) -> ChangeBatch {
+    ChangeBatch {
+        changes: vec![Change {
+            action: "UPSERT".to_owned(),
+            resource_record_set: ResourceRecordSet {
+                name: record_name.to_owned(),
+                type_: "A".to_owned(),
+                alias_target: Some(target),
+                ..Default::default()
+            },
+        }],
+        comment: None,
+    }
+}
+
+impl Route53Client {
+    /// Create an alias A record named `record_name` pointing at `target` in the hosted zone
+    /// `hosted_zone_id`, or update it if it exists.
+    pub fn upsert_alias(
+        &self,
+        hosted_zone_id: &str,
+        record_name: &str,
+        target: AliasTarget,
+    ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
+        self.change_resource_record_sets(ChangeResourceRecordSetsRequest {
+            hosted_zone_id: hosted_zone_id.to_owned(),
+            change_batch: upsert_alias_change_batch(record_name, target),
+        })
+    }
+}
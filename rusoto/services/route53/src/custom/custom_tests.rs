@@ -2,8 +2,9 @@ extern crate rusoto_mock;
 
 use crate::custom::util::quote_txt_record;
 use crate::generated::{
-    ListResourceRecordSetsError, ListResourceRecordSetsRequest, Route53, Route53Client,
+    AliasTarget, ListResourceRecordSetsError, ListResourceRecordSetsRequest, Route53, Route53Client,
 };
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
 use rusoto_core::{Region, RusotoError};
 
 use self::rusoto_mock::*;
@@ -46,3 +47,43 @@ fn test_quoter() {
     assert_eq!(quote_txt_record("\"foo"), "\"foo\"");
     assert_eq!(quote_txt_record("foo\""), "\"foo\"");
 }
+
+#[test]
+fn upsert_alias_sends_an_alias_change_batch() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("")
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!("POST", request.method);
+            assert_eq!("/2013-04-01/hostedzone/Z1D633PJN98FT9/rrset/", request.path);
+            let body = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    String::from_utf8(buffer.to_vec()).unwrap()
+                }
+                _ => panic!("expected a buffered payload"),
+            };
+            assert_eq!(
+                body,
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+                 <ChangeResourceRecordSetsRequest xmlns=\"https://route53.amazonaws.com/doc/2013-04-01/\">\
+                 <ChangeBatch><Changes><Change><Action>UPSERT</Action>\
+                 <ResourceRecordSet><AliasTarget>\
+                 <DNSName>s3-website-eu-west-1.amazonaws.com</DNSName>\
+                 <EvaluateTargetHealth>false</EvaluateTargetHealth>\
+                 <HostedZoneId>Z1BKCTXD74EZPE</HostedZoneId>\
+                 </AliasTarget><Name>www.example.com</Name><Type>A</Type>\
+                 </ResourceRecordSet></Change></Changes></ChangeBatch>\
+                 </ChangeResourceRecordSetsRequest>"
+            );
+        });
+    let target = AliasTarget {
+        dns_name: "s3-website-eu-west-1.amazonaws.com".to_owned(),
+        evaluate_target_health: false,
+        hosted_zone_id: "Z1BKCTXD74EZPE".to_owned(),
+    };
+
+    let client = Route53Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let result = client
+        .upsert_alias("Z1D633PJN98FT9", "www.example.com", target)
+        .sync();
+    assert!(result.is_ok(), "{:?}", result);
+}
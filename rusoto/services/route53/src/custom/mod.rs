@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod custom_tests;
 
+/// Alias records, such as those pointing at S3 website endpoints
+pub mod alias;
 /// Utility helpers for working with Route53
 pub mod util;
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use std::io::Write;
 use std::str::FromStr;
 use xml;
@@ -37,6 +38,8 @@ use xml::EventWriter;
 
 /// <p>A complex type that contains the type of limit that you specified in the request and the current value for that limit.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountLimit {
     /// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
     pub type_: String,
@@ -99,6 +102,8 @@ impl AccountLimitTypeSerializer {
 
 /// <p>A complex type that identifies the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether the specified health check is healthy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AlarmIdentifier {
     /// <p><p>The name of the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether this health check is healthy.</p> <note> <p>Route 53 supports CloudWatch alarms with the following features:</p> <ul> <li> <p>Standard-resolution metrics. High-resolution metrics aren&#39;t supported. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/publishingMetrics.html#high-resolution-metrics">High-Resolution Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Statistics: Average, Minimum, Maximum, Sum, and SampleCount. Extended statistics aren&#39;t supported.</p> </li> </ul> </note></p>
     pub name: String,
@@ -106,6 +111,23 @@ pub struct AlarmIdentifier {
     pub region: String,
 }
 
+impl Validate for AlarmIdentifier {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "region"),
+            self.region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 struct AlarmIdentifierDeserializer;
 impl AlarmIdentifierDeserializer {
     #[allow(unused_variables)]
@@ -193,7 +215,7 @@ impl AliasHealthEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -222,6 +244,8 @@ impl AliasHealthEnabledSerializer {
 
 /// <p><p> <i>Alias resource record sets only:</i> Information about the AWS resource, such as a CloudFront distribution or an Amazon S3 bucket, that you want to route traffic to.</p> <p>When creating resource record sets for a private hosted zone, note the following:</p> <ul> <li> <p>Creating geolocation alias resource record sets or latency alias resource record sets in a private hosted zone is unsupported.</p> </li> <li> <p>For information about creating failover resource record sets in a private hosted zone, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a>.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AliasTarget {
     /// <p><p> <i>Alias resource record sets only:</i> The value that you specify depends on where you want to route queries:</p> <dl> <dt>Amazon API Gateway custom regional APIs and edge-optimized APIs</dt> <dd> <p>Specify the applicable domain name for your API. You can get the applicable value using the AWS CLI command <a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-domain-names.html">get-domain-names</a>:</p> <ul> <li> <p>For regional APIs, specify the value of <code>regionalDomainName</code>.</p> </li> <li> <p>For edge-optimized APIs, specify the value of <code>distributionDomainName</code>. This is the name of the associated CloudFront distribution, such as <code>da1b2c3d4e5.cloudfront.net</code>.</p> </li> </ul> <note> <p>The name of the record that you&#39;re creating must match a custom domain name for your API, such as <code>api.example.com</code>.</p> </note> </dd> <dt>Amazon Virtual Private Cloud interface VPC endpoint</dt> <dd> <p>Enter the API endpoint for the interface endpoint, such as <code>vpce-123456789abcdef01-example-us-east-1a.elasticloadbalancing.us-east-1.vpce.amazonaws.com</code>. For edge-optimized APIs, this is the domain name for the corresponding CloudFront distribution. You can get the value of <code>DnsName</code> using the AWS CLI command <a href="https://docs.aws.amazon.com/cli/latest/reference/ec2/describe-vpc-endpoints.html">describe-vpc-endpoints</a>.</p> </dd> <dt>CloudFront distribution</dt> <dd> <p>Specify the domain name that CloudFront assigned when you created your distribution.</p> <p>Your CloudFront distribution must include an alternate domain name that matches the name of the resource record set. For example, if the name of the resource record set is <i>acme.example.com</i>, your CloudFront distribution must include <i>acme.example.com</i> as one of the alternate domain names. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/CNAMEs.html">Using Alternate Domain Names (CNAMEs)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p>You can&#39;t create a resource record set in a private hosted zone to route traffic to a CloudFront distribution.</p> <note> <p>For failover alias records, you can&#39;t specify a CloudFront distribution for both the primary and secondary records. A distribution must include an alternate domain name that matches the name of the record. However, the primary and secondary records have the same name, and you can&#39;t include the same alternate domain name in more than one distribution. </p> </note> </dd> <dt>Elastic Beanstalk environment</dt> <dd> <p>If the domain name for your Elastic Beanstalk environment includes the region that you deployed the environment in, you can create an alias record that routes traffic to the environment. For example, the domain name <code>my-environment.<i>us-west-2</i>.elasticbeanstalk.com</code> is a regionalized domain name. </p> <important> <p>For environments that were created before early 2016, the domain name doesn&#39;t include the region. To route traffic to these environments, you must create a CNAME record instead of an alias record. Note that you can&#39;t create a CNAME record for the root domain name. For example, if your domain name is example.com, you can create a record that routes traffic for acme.example.com to your Elastic Beanstalk environment, but you can&#39;t create a record that routes traffic for example.com to your Elastic Beanstalk environment.</p> </important> <p>For Elastic Beanstalk environments that have regionalized subdomains, specify the <code>CNAME</code> attribute for the environment. You can use the following methods to get the value of the CNAME attribute:</p> <ul> <li> <p> <i>AWS Management Console</i>: For information about how to get the value by using the console, see <a href="http://docs.aws.amazon.com/elasticbeanstalk/latest/dg/customdomains.html">Using Custom Domains with AWS Elastic Beanstalk</a> in the <i>AWS Elastic Beanstalk Developer Guide</i>.</p> </li> <li> <p> <i>Elastic Beanstalk API</i>: Use the <code>DescribeEnvironments</code> action to get the value of the <code>CNAME</code> attribute. For more information, see <a href="http://docs.aws.amazon.com/elasticbeanstalk/latest/api/API_DescribeEnvironments.html">DescribeEnvironments</a> in the <i>AWS Elastic Beanstalk API Reference</i>.</p> </li> <li> <p> <i>AWS CLI</i>: Use the <code>describe-environments</code> command to get the value of the <code>CNAME</code> attribute. For more information, see <a href="http://docs.aws.amazon.com/cli/latest/reference/elasticbeanstalk/describe-environments.html">describe-environments</a> in the <i>AWS Command Line Interface Reference</i>.</p> </li> </ul> </dd> <dt>ELB load balancer</dt> <dd> <p>Specify the DNS name that is associated with the load balancer. Get the DNS name by using the AWS Management Console, the ELB API, or the AWS CLI. </p> <ul> <li> <p> <b>AWS Management Console</b>: Go to the EC2 page, choose <b>Load Balancers</b> in the navigation pane, choose the load balancer, choose the <b>Description</b> tab, and get the value of the <b>DNS name</b> field. </p> <p>If you&#39;re routing traffic to a Classic Load Balancer, get the value that begins with <b>dualstack</b>. If you&#39;re routing traffic to another type of load balancer, get the value that applies to the record type, A or AAAA.</p> </li> <li> <p> <b>Elastic Load Balancing API</b>: Use <code>DescribeLoadBalancers</code> to get the value of <code>DNSName</code>. For more information, see the applicable guide:</p> <ul> <li> <p>Classic Load Balancers: <a href="http://docs.aws.amazon.com/elasticloadbalancing/2012-06-01/APIReference/API_DescribeLoadBalancers.html">DescribeLoadBalancers</a> </p> </li> <li> <p>Application and Network Load Balancers: <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/APIReference/API_DescribeLoadBalancers.html">DescribeLoadBalancers</a> </p> </li> </ul> </li> <li> <p> <b>AWS CLI</b>: Use <code>describe-load-balancers</code> to get the value of <code>DNSName</code>. For more information, see the applicable guide:</p> <ul> <li> <p>Classic Load Balancers: <a href="http://docs.aws.amazon.com/cli/latest/reference/elb/describe-load-balancers.html">describe-load-balancers</a> </p> </li> <li> <p>Application and Network Load Balancers: <a href="http://docs.aws.amazon.com/cli/latest/reference/elbv2/describe-load-balancers.html">describe-load-balancers</a> </p> </li> </ul> </li> </ul> </dd> <dt>Amazon S3 bucket that is configured as a static website</dt> <dd> <p>Specify the domain name of the Amazon S3 website endpoint that you created the bucket in, for example, <code>s3-website.us-east-2.amazonaws.com</code>. For more information about valid values, see the table <a href="http://docs.aws.amazon.com/general/latest/gr/rande.html#s3_region">Amazon Simple Storage Service (S3) Website Endpoints</a> in the <i>Amazon Web Services General Reference</i>. For more information about using S3 buckets for websites, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/getting-started.html">Getting Started with Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide.</i> </p> </dd> <dt>Another Route 53 resource record set</dt> <dd> <p>Specify the value of the <code>Name</code> element for a resource record set in the current hosted zone.</p> <note> <p>If you&#39;re creating an alias record that has the same name as the hosted zone (known as the zone apex), you can&#39;t specify the domain name for a record for which the value of <code>Type</code> is <code>CNAME</code>. This is because the alias record must have the same type as the record that you&#39;re routing traffic to, and creating a CNAME record for the zone apex isn&#39;t supported even for an alias record.</p> </note> </dd> </dl></p>
     pub dns_name: String,
@@ -231,6 +255,23 @@ pub struct AliasTarget {
     pub hosted_zone_id: String,
 }
 
+impl Validate for AliasTarget {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "dns_name"),
+            self.dns_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 struct AliasTargetDeserializer;
 impl AliasTargetDeserializer {
     #[allow(unused_variables)]
@@ -314,6 +355,8 @@ impl AssociateVPCCommentSerializer {
 
 /// <p>A complex type that contains information about the request to associate a VPC with a private hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateVPCWithHostedZoneRequest {
     /// <p> <i>Optional:</i> A comment about the association request.</p>
     pub comment: Option<String>,
@@ -323,6 +366,18 @@ pub struct AssociateVPCWithHostedZoneRequest {
     pub vpc: VPC,
 }
 
+impl Validate for AssociateVPCWithHostedZoneRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        self.vpc.validate_at(&field_path(path, "vpc"), errors);
+    }
+}
+
 pub struct AssociateVPCWithHostedZoneRequestSerializer;
 impl AssociateVPCWithHostedZoneRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -345,6 +400,8 @@ impl AssociateVPCWithHostedZoneRequestSerializer {
 }
 /// <p>A complex type that contains the response information for the <code>AssociateVPCWithHostedZone</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateVPCWithHostedZoneResponse {
     /// <p>A complex type that describes the changes made to your hosted zone.</p>
     pub change_info: ChangeInfo,
@@ -374,6 +431,8 @@ impl AssociateVPCWithHostedZoneResponseDeserializer {
 }
 /// <p>The information for each resource record set that you want to change.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Change {
     /// <p><p>The action to perform:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes a existing resource record set.</p> <important> <p>To delete the resource record set that is associated with a traffic policy instance, use <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteTrafficPolicyInstance.html">DeleteTrafficPolicyInstance</a>. Amazon Route 53 will delete the resource record set automatically. If you delete the resource record set by using <code>ChangeResourceRecordSets</code>, Route 53 doesn&#39;t automatically delete the traffic policy instance, and you&#39;ll continue to be charged for it even though it&#39;s no longer in use. </p> </important> </li> <li> <p> <code>UPSERT</code>: If a resource record set doesn&#39;t already exist, Route 53 creates it. If a resource record set does exist, Route 53 updates it with the values in the request.</p> </li> </ul></p>
     pub action: String,
@@ -381,6 +440,13 @@ pub struct Change {
     pub resource_record_set: ResourceRecordSet,
 }
 
+impl Validate for Change {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.resource_record_set
+            .validate_at(&field_path(path, "resource_record_set"), errors);
+    }
+}
+
 pub struct ChangeSerializer;
 impl ChangeSerializer {
     #[allow(unused_variables, warnings)]
@@ -430,6 +496,8 @@ impl ChangeActionSerializer {
 
 /// <p>The information for a change request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeBatch {
     /// <p>Information about the changes to make to the record sets.</p>
     pub changes: Vec<Change>,
@@ -437,6 +505,29 @@ pub struct ChangeBatch {
     pub comment: Option<String>,
 }
 
+impl Validate for ChangeBatch {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "changes"),
+            self.changes.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.changes.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "changes"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 pub struct ChangeBatchSerializer;
 impl ChangeBatchSerializer {
     #[allow(unused_variables, warnings)]
@@ -464,6 +555,8 @@ impl ChangeBatchSerializer {
 
 /// <p>A complex type that describes change information about changes made to your hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeInfo {
     /// <p>A complex type that describes change information about changes made to your hosted zone.</p> <p>This element contains an ID that you use when performing a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a> action to get detailed information about the change.</p>
     pub comment: Option<String>,
@@ -506,6 +599,8 @@ impl ChangeInfoDeserializer {
 }
 /// <p>A complex type that contains change information for the resource record set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeResourceRecordSetsRequest {
     /// <p>A complex type that contains an optional comment and the <code>Changes</code> element.</p>
     pub change_batch: ChangeBatch,
@@ -513,6 +608,19 @@ pub struct ChangeResourceRecordSetsRequest {
     pub hosted_zone_id: String,
 }
 
+impl Validate for ChangeResourceRecordSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.change_batch
+            .validate_at(&field_path(path, "change_batch"), errors);
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 pub struct ChangeResourceRecordSetsRequestSerializer;
 impl ChangeResourceRecordSetsRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -532,6 +640,8 @@ impl ChangeResourceRecordSetsRequestSerializer {
 }
 /// <p>A complex type containing the response for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeResourceRecordSetsResponse {
     /// <p>A complex type that contains information about changes made to your hosted zone.</p> <p>This element contains an ID that you use when performing a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a> action to get detailed information about the change.</p>
     pub change_info: ChangeInfo,
@@ -572,6 +682,8 @@ impl ChangeStatusDeserializer {
 }
 /// <p>A complex type that contains information about the tags that you want to add, edit, or delete.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeTagsForResourceRequest {
     /// <p>A complex type that contains a list of the tags that you want to add to the specified health check or hosted zone and/or the tags that you want to edit <code>Value</code> for.</p> <p>You can add a maximum of 10 tags to a health check or a hosted zone.</p>
     pub add_tags: Option<Vec<Tag>>,
@@ -583,6 +695,41 @@ pub struct ChangeTagsForResourceRequest {
     pub resource_type: String,
 }
 
+impl Validate for ChangeTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.add_tags {
+            errors.check_length(
+                &field_path(path, "add_tags"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "add_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.remove_tag_keys {
+            errors.check_length(
+                &field_path(path, "remove_tag_keys"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "remove_tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(128));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 pub struct ChangeTagsForResourceRequestSerializer;
 impl ChangeTagsForResourceRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -607,6 +754,8 @@ impl ChangeTagsForResourceRequestSerializer {
 }
 /// <p>Empty response for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeTagsForResourceResponse {}
 
 struct ChangeTagsForResourceResponseDeserializer;
@@ -706,6 +855,8 @@ impl ChildHealthCheckListSerializer {
 
 /// <p>A complex type that contains information about the CloudWatch alarm that Amazon Route 53 is monitoring for this health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudWatchAlarmConfiguration {
     /// <p>For the metric that the CloudWatch alarm is associated with, the arithmetic operation that is used for the comparison.</p>
     pub comparison_operator: String,
@@ -851,6 +1002,8 @@ impl ComparisonOperatorDeserializer {
 }
 /// <p>A complex type that contains the health check request information.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHealthCheckRequest {
     /// <p><p>A unique string that identifies the request and that allows you to retry a failed <code>CreateHealthCheck</code> request without the risk of creating two identical health checks:</p> <ul> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> and settings as a previous request, and if the health check doesn&#39;t exist, Amazon Route 53 creates the health check. If the health check does exist, Route 53 returns the settings for the existing health check.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> as a deleted health check, regardless of the settings, Route 53 returns a <code>HealthCheckAlreadyExists</code> error.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with the same <code>CallerReference</code> as an existing health check but with different settings, Route 53 returns a <code>HealthCheckAlreadyExists</code> error.</p> </li> <li> <p>If you send a <code>CreateHealthCheck</code> request with a unique <code>CallerReference</code> but settings identical to an existing health check, Route 53 creates the health check.</p> </li> </ul></p>
     pub caller_reference: String,
@@ -858,6 +1011,19 @@ pub struct CreateHealthCheckRequest {
     pub health_check_config: HealthCheckConfig,
 }
 
+impl Validate for CreateHealthCheckRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "caller_reference"),
+            self.caller_reference.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        self.health_check_config
+            .validate_at(&field_path(path, "health_check_config"), errors);
+    }
+}
+
 pub struct CreateHealthCheckRequestSerializer;
 impl CreateHealthCheckRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -886,6 +1052,8 @@ impl CreateHealthCheckRequestSerializer {
 }
 /// <p>A complex type containing the response information for the new health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHealthCheckResponse {
     /// <p>A complex type that contains identifying information about the health check.</p>
     pub health_check: HealthCheck,
@@ -918,6 +1086,8 @@ impl CreateHealthCheckResponseDeserializer {
 }
 /// <p>A complex type that contains information about the request to create a public or private hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHostedZoneRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreateHostedZone</code> requests to be retried without the risk of executing the operation twice. You must use a unique <code>CallerReference</code> string every time you submit a <code>CreateHostedZone</code> request. <code>CallerReference</code> can be any unique string, for example, a date/time stamp.</p>
     pub caller_reference: String,
@@ -931,6 +1101,37 @@ pub struct CreateHostedZoneRequest {
     pub vpc: Option<VPC>,
 }
 
+impl Validate for CreateHostedZoneRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "caller_reference"),
+            self.caller_reference.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.delegation_set_id {
+            errors.check_length(
+                &field_path(path, "delegation_set_id"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.hosted_zone_config {
+            value.validate_at(&field_path(path, "hosted_zone_config"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(1024),
+        );
+        if let Some(ref value) = self.vpc {
+            value.validate_at(&field_path(path, "vpc"), errors);
+        }
+    }
+}
+
 pub struct CreateHostedZoneRequestSerializer;
 impl CreateHostedZoneRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -960,6 +1161,8 @@ impl CreateHostedZoneRequestSerializer {
 }
 /// <p>A complex type containing the response information for the hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHostedZoneResponse {
     /// <p>A complex type that contains information about the <code>CreateHostedZone</code> request.</p>
     pub change_info: ChangeInfo,
@@ -1006,6 +1209,8 @@ impl CreateHostedZoneResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateQueryLoggingConfigRequest {
     /// <p>The Amazon Resource Name (ARN) for the log group that you want to Amazon Route 53 to send query logs to. This is the format of the ARN:</p> <p>arn:aws:logs:<i>region</i>:<i>account-id</i>:log-group:<i>log_group_name</i> </p> <p>To get the ARN for a log group, you can use the CloudWatch console, the <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_DescribeLogGroups.html">DescribeLogGroups</a> API action, the <a href="https://docs.aws.amazon.com/cli/latest/reference/logs/describe-log-groups.html">describe-log-groups</a> command, or the applicable command in one of the AWS SDKs.</p>
     pub cloud_watch_logs_log_group_arn: String,
@@ -1013,6 +1218,17 @@ pub struct CreateQueryLoggingConfigRequest {
     pub hosted_zone_id: String,
 }
 
+impl Validate for CreateQueryLoggingConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 pub struct CreateQueryLoggingConfigRequestSerializer;
 impl CreateQueryLoggingConfigRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1036,6 +1252,8 @@ impl CreateQueryLoggingConfigRequestSerializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateQueryLoggingConfigResponse {
     /// <p>The unique URL representing the new query logging configuration.</p>
     pub location: String,
@@ -1069,6 +1287,8 @@ impl CreateQueryLoggingConfigResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReusableDelegationSetRequest {
     /// <p>A unique string that identifies the request, and that allows you to retry failed <code>CreateReusableDelegationSet</code> requests without the risk of executing the operation twice. You must use a unique <code>CallerReference</code> string every time you submit a <code>CreateReusableDelegationSet</code> request. <code>CallerReference</code> can be any unique string, for example a date/time stamp.</p>
     pub caller_reference: String,
@@ -1076,6 +1296,25 @@ pub struct CreateReusableDelegationSetRequest {
     pub hosted_zone_id: Option<String>,
 }
 
+impl Validate for CreateReusableDelegationSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "caller_reference"),
+            self.caller_reference.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.hosted_zone_id {
+            errors.check_length(
+                &field_path(path, "hosted_zone_id"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+    }
+}
+
 pub struct CreateReusableDelegationSetRequestSerializer;
 impl CreateReusableDelegationSetRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1097,6 +1336,8 @@ impl CreateReusableDelegationSetRequestSerializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReusableDelegationSetResponse {
     /// <p>A complex type that contains name server information.</p>
     pub delegation_set: DelegationSet,
@@ -1129,6 +1370,8 @@ impl CreateReusableDelegationSetResponseDeserializer {
 }
 /// <p>A complex type that contains information about the resource record sets that you want to create based on a specified traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrafficPolicyInstanceRequest {
     /// <p>The ID of the hosted zone that you want Amazon Route 53 to create resource record sets in by using the configuration in a traffic policy.</p>
     pub hosted_zone_id: String,
@@ -1142,6 +1385,36 @@ pub struct CreateTrafficPolicyInstanceRequest {
     pub traffic_policy_version: i64,
 }
 
+impl Validate for CreateTrafficPolicyInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_range(&field_path(path, "ttl"), self.ttl, None, Some(2147483647));
+        errors.check_length(
+            &field_path(path, "traffic_policy_id"),
+            self.traffic_policy_id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        errors.check_range(
+            &field_path(path, "traffic_policy_version"),
+            self.traffic_policy_version,
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 pub struct CreateTrafficPolicyInstanceRequestSerializer;
 impl CreateTrafficPolicyInstanceRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1173,6 +1446,8 @@ impl CreateTrafficPolicyInstanceRequestSerializer {
 }
 /// <p>A complex type that contains the response information for the <code>CreateTrafficPolicyInstance</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrafficPolicyInstanceResponse {
     /// <p>A unique URL that represents a new traffic policy instance.</p>
     pub location: String,
@@ -1208,6 +1483,8 @@ impl CreateTrafficPolicyInstanceResponseDeserializer {
 }
 /// <p>A complex type that contains information about the traffic policy that you want to create.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrafficPolicyRequest {
     /// <p>(Optional) Any comments that you want to include about the traffic policy.</p>
     pub comment: Option<String>,
@@ -1217,6 +1494,31 @@ pub struct CreateTrafficPolicyRequest {
     pub name: String,
 }
 
+impl Validate for CreateTrafficPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document"),
+            self.document.chars().count(),
+            None,
+            Some(102400),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(512),
+        );
+    }
+}
+
 pub struct CreateTrafficPolicyRequestSerializer;
 impl CreateTrafficPolicyRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1240,6 +1542,8 @@ impl CreateTrafficPolicyRequestSerializer {
 }
 /// <p>A complex type that contains the response information for the <code>CreateTrafficPolicy</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrafficPolicyResponse {
     /// <p>A unique URL that represents a new traffic policy.</p>
     pub location: String,
@@ -1272,6 +1576,8 @@ impl CreateTrafficPolicyResponseDeserializer {
 }
 /// <p>A complex type that contains information about the traffic policy that you want to create a new version for.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrafficPolicyVersionRequest {
     /// <p>The comment that you specified in the <code>CreateTrafficPolicyVersion</code> request, if any.</p>
     pub comment: Option<String>,
@@ -1281,6 +1587,31 @@ pub struct CreateTrafficPolicyVersionRequest {
     pub id: String,
 }
 
+impl Validate for CreateTrafficPolicyVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document"),
+            self.document.chars().count(),
+            None,
+            Some(102400),
+        );
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+    }
+}
+
 pub struct CreateTrafficPolicyVersionRequestSerializer;
 impl CreateTrafficPolicyVersionRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1303,6 +1634,8 @@ impl CreateTrafficPolicyVersionRequestSerializer {
 }
 /// <p>A complex type that contains the response information for the <code>CreateTrafficPolicyVersion</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrafficPolicyVersionResponse {
     /// <p>A unique URL that represents a new traffic policy version.</p>
     pub location: String,
@@ -1335,6 +1668,8 @@ impl CreateTrafficPolicyVersionResponseDeserializer {
 }
 /// <p>A complex type that contains information about the request to authorize associating a VPC with your private hosted zone. Authorization is only required when a private hosted zone and a VPC were created by using different accounts.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVPCAssociationAuthorizationRequest {
     /// <p>The ID of the private hosted zone that you want to authorize associating a VPC with.</p>
     pub hosted_zone_id: String,
@@ -1342,6 +1677,18 @@ pub struct CreateVPCAssociationAuthorizationRequest {
     pub vpc: VPC,
 }
 
+impl Validate for CreateVPCAssociationAuthorizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        self.vpc.validate_at(&field_path(path, "vpc"), errors);
+    }
+}
+
 pub struct CreateVPCAssociationAuthorizationRequestSerializer;
 impl CreateVPCAssociationAuthorizationRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1361,6 +1708,8 @@ impl CreateVPCAssociationAuthorizationRequestSerializer {
 }
 /// <p>A complex type that contains the response information from a <code>CreateVPCAssociationAuthorization</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVPCAssociationAuthorizationResponse {
     /// <p>The ID of the hosted zone that you authorized associating a VPC with.</p>
     pub hosted_zone_id: String,
@@ -1439,6 +1788,8 @@ impl DNSRCodeDeserializer {
 }
 /// <p>A complex type that lists the name servers in a delegation set, as well as the <code>CallerReference</code> and the <code>ID</code> for the delegation set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DelegationSet {
     /// <p>The value that you specified for <code>CallerReference</code> when you created the reusable delegation set.</p>
     pub caller_reference: Option<String>,
@@ -1516,13 +1867,28 @@ impl DelegationSetsDeserializer {
 }
 /// <p>This action deletes a health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHealthCheckRequest {
     /// <p>The ID of the health check that you want to delete.</p>
     pub health_check_id: String,
 }
 
+impl Validate for DeleteHealthCheckRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "health_check_id"),
+            self.health_check_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHealthCheckResponse {}
 
 struct DeleteHealthCheckResponseDeserializer;
@@ -1543,13 +1909,28 @@ impl DeleteHealthCheckResponseDeserializer {
 }
 /// <p>A request to delete a hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHostedZoneRequest {
     /// <p>The ID of the hosted zone you want to delete.</p>
     pub id: String,
 }
 
+impl Validate for DeleteHostedZoneRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>DeleteHostedZone</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteHostedZoneResponse {
     /// <p>A complex type that contains the ID, the status, and the date and time of a request to delete a hosted zone.</p>
     pub change_info: ChangeInfo,
@@ -1578,12 +1959,27 @@ impl DeleteHostedZoneResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteQueryLoggingConfigRequest {
     /// <p>The ID of the configuration that you want to delete. </p>
     pub id: String,
 }
 
+impl Validate for DeleteQueryLoggingConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteQueryLoggingConfigResponse {}
 
 struct DeleteQueryLoggingConfigResponseDeserializer;
@@ -1604,13 +2000,28 @@ impl DeleteQueryLoggingConfigResponseDeserializer {
 }
 /// <p>A request to delete a reusable delegation set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReusableDelegationSetRequest {
     /// <p>The ID of the reusable delegation set that you want to delete.</p>
     pub id: String,
 }
 
+impl Validate for DeleteReusableDelegationSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReusableDelegationSetResponse {}
 
 struct DeleteReusableDelegationSetResponseDeserializer;
@@ -1631,13 +2042,28 @@ impl DeleteReusableDelegationSetResponseDeserializer {
 }
 /// <p>A request to delete a specified traffic policy instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTrafficPolicyInstanceRequest {
     /// <p><p>The ID of the traffic policy instance that you want to delete. </p> <important> <p>When you delete a traffic policy instance, Amazon Route 53 also deletes all of the resource record sets that were created when you created the traffic policy instance.</p> </important></p>
     pub id: String,
 }
 
+impl Validate for DeleteTrafficPolicyInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTrafficPolicyInstanceResponse {}
 
 struct DeleteTrafficPolicyInstanceResponseDeserializer;
@@ -1658,6 +2084,8 @@ impl DeleteTrafficPolicyInstanceResponseDeserializer {
 }
 /// <p>A request to delete a specified traffic policy version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTrafficPolicyRequest {
     /// <p>The ID of the traffic policy that you want to delete.</p>
     pub id: String,
@@ -1665,8 +2093,27 @@ pub struct DeleteTrafficPolicyRequest {
     pub version: i64,
 }
 
+impl Validate for DeleteTrafficPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        errors.check_range(
+            &field_path(path, "version"),
+            self.version,
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 /// <p>An empty element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTrafficPolicyResponse {}
 
 struct DeleteTrafficPolicyResponseDeserializer;
@@ -1687,6 +2134,8 @@ impl DeleteTrafficPolicyResponseDeserializer {
 }
 /// <p>A complex type that contains information about the request to remove authorization to associate a VPC that was created by one AWS account with a hosted zone that was created with a different AWS account. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVPCAssociationAuthorizationRequest {
     /// <p>When removing authorization to associate a VPC that was created by one AWS account with a hosted zone that was created with a different AWS account, the ID of the hosted zone.</p>
     pub hosted_zone_id: String,
@@ -1694,6 +2143,18 @@ pub struct DeleteVPCAssociationAuthorizationRequest {
     pub vpc: VPC,
 }
 
+impl Validate for DeleteVPCAssociationAuthorizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        self.vpc.validate_at(&field_path(path, "vpc"), errors);
+    }
+}
+
 pub struct DeleteVPCAssociationAuthorizationRequestSerializer;
 impl DeleteVPCAssociationAuthorizationRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1713,6 +2174,8 @@ impl DeleteVPCAssociationAuthorizationRequestSerializer {
 }
 /// <p>Empty response for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVPCAssociationAuthorizationResponse {}
 
 struct DeleteVPCAssociationAuthorizationResponseDeserializer;
@@ -1733,6 +2196,8 @@ impl DeleteVPCAssociationAuthorizationResponseDeserializer {
 }
 /// <p>For the metric that the CloudWatch alarm is associated with, a complex type that contains information about one dimension.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Dimension {
     /// <p>For the metric that the CloudWatch alarm is associated with, the name of one dimension.</p>
     pub name: String,
@@ -1794,7 +2259,7 @@ impl DisabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1843,6 +2308,8 @@ impl DisassociateVPCCommentSerializer {
 
 /// <p>A complex type that contains information about the VPC that you want to disassociate from a specified private hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateVPCFromHostedZoneRequest {
     /// <p> <i>Optional:</i> A comment about the disassociation request.</p>
     pub comment: Option<String>,
@@ -1852,6 +2319,18 @@ pub struct DisassociateVPCFromHostedZoneRequest {
     pub vpc: VPC,
 }
 
+impl Validate for DisassociateVPCFromHostedZoneRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        self.vpc.validate_at(&field_path(path, "vpc"), errors);
+    }
+}
+
 pub struct DisassociateVPCFromHostedZoneRequestSerializer;
 impl DisassociateVPCFromHostedZoneRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -1874,6 +2353,8 @@ impl DisassociateVPCFromHostedZoneRequestSerializer {
 }
 /// <p>A complex type that contains the response information for the disassociate request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateVPCFromHostedZoneResponse {
     /// <p>A complex type that describes the changes made to the specified private hosted zone.</p>
     pub change_info: ChangeInfo,
@@ -1906,7 +2387,7 @@ impl EnableSNIDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1938,7 +2419,7 @@ impl EvaluationPeriodsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1949,7 +2430,7 @@ impl FailureThresholdDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2010,6 +2491,8 @@ impl FullyQualifiedDomainNameSerializer {
 
 /// <p>A complex type that contains information about a geographic location.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GeoLocation {
     /// <p>The two-letter code for the continent.</p> <p>Valid values: <code>AF</code> | <code>AN</code> | <code>AS</code> | <code>EU</code> | <code>OC</code> | <code>NA</code> | <code>SA</code> </p> <p>Constraint: Specifying <code>ContinentCode</code> with either <code>CountryCode</code> or <code>SubdivisionCode</code> returns an <code>InvalidInput</code> error.</p>
     pub continent_code: Option<String>,
@@ -2019,6 +2502,35 @@ pub struct GeoLocation {
     pub subdivision_code: Option<String>,
 }
 
+impl Validate for GeoLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.continent_code {
+            errors.check_length(
+                &field_path(path, "continent_code"),
+                value.chars().count(),
+                Some(2),
+                Some(2),
+            );
+        }
+        if let Some(ref value) = self.country_code {
+            errors.check_length(
+                &field_path(path, "country_code"),
+                value.chars().count(),
+                Some(1),
+                Some(2),
+            );
+        }
+        if let Some(ref value) = self.subdivision_code {
+            errors.check_length(
+                &field_path(path, "subdivision_code"),
+                value.chars().count(),
+                Some(1),
+                Some(3),
+            );
+        }
+    }
+}
+
 struct GeoLocationDeserializer;
 impl GeoLocationDeserializer {
     #[allow(unused_variables)]
@@ -2182,6 +2694,8 @@ impl GeoLocationCountryNameDeserializer {
 }
 /// <p>A complex type that contains the codes and full continent, country, and subdivision names for the specified <code>geolocation</code> code.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GeoLocationDetails {
     /// <p>The two-letter code for the continent.</p>
     pub continent_code: Option<String>,
@@ -2315,6 +2829,8 @@ impl GeoLocationSubdivisionNameDeserializer {
 }
 /// <p>A complex type that contains information about the request to create a hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountLimitRequest {
     /// <p><p>The limit that you want to get. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>HEALTH</em>CHECKS<em>BY</em>OWNER</b>: The maximum number of health checks that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>HOSTED</em>ZONES<em>BY</em>OWNER</b>: The maximum number of hosted zones that you can create using the current account.</p> </li> <li> <p> <b>MAX<em>REUSABLE</em>DELEGATION<em>SETS</em>BY<em>OWNER</b>: The maximum number of reusable delegation sets that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICIES</em>BY<em>OWNER</b>: The maximum number of traffic policies that you can create using the current account.</p> </li> <li> <p> <b>MAX</em>TRAFFIC<em>POLICY</em>INSTANCES<em>BY</em>OWNER</b>: The maximum number of traffic policy instances that you can create using the current account. (Traffic policy instances are referred to as traffic flow policy records in the Amazon Route 53 console.)</p> </li> </ul></p>
     pub type_: String,
@@ -2322,6 +2838,8 @@ pub struct GetAccountLimitRequest {
 
 /// <p>A complex type that contains the requested limit. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountLimitResponse {
     /// <p>The current number of entities that you have created of the specified type. For example, if you specified <code>MAX_HEALTH_CHECKS_BY_OWNER</code> for the value of <code>Type</code> in the request, the value of <code>Count</code> is the current number of health checks that you have created using the current account.</p>
     pub count: i64,
@@ -2356,13 +2874,28 @@ impl GetAccountLimitResponseDeserializer {
 }
 /// <p>The input for a GetChange request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetChangeRequest {
     /// <p>The ID of the change batch request. The value that you specify here is the value that <code>ChangeResourceRecordSets</code> returned in the <code>Id</code> element when you submitted the request.</p>
     pub id: String,
 }
 
+impl Validate for GetChangeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(6500),
+        );
+    }
+}
+
 /// <p>A complex type that contains the <code>ChangeInfo</code> element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetChangeResponse {
     /// <p>A complex type that contains information about the specified change batch.</p>
     pub change_info: ChangeInfo,
@@ -2388,10 +2921,14 @@ impl GetChangeResponseDeserializer {
 }
 /// <p>Empty request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCheckerIpRangesRequest {}
 
 /// <p>A complex type that contains the <code>CheckerIpRanges</code> element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCheckerIpRangesResponse {
     /// <p>A complex type that contains sorted list of IP ranges in CIDR format for Amazon Route 53 health checkers.</p>
     pub checker_ip_ranges: Vec<String>,
@@ -2425,6 +2962,8 @@ impl GetCheckerIpRangesResponseDeserializer {
 }
 /// <p>A request for information about whether a specified geographic location is supported for Amazon Route 53 geolocation resource record sets.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGeoLocationRequest {
     /// <p><p>Amazon Route 53 supports the following continent codes:</p> <ul> <li> <p> <b>AF</b>: Africa</p> </li> <li> <p> <b>AN</b>: Antarctica</p> </li> <li> <p> <b>AS</b>: Asia</p> </li> <li> <p> <b>EU</b>: Europe</p> </li> <li> <p> <b>OC</b>: Oceania</p> </li> <li> <p> <b>NA</b>: North America</p> </li> <li> <p> <b>SA</b>: South America</p> </li> </ul></p>
     pub continent_code: Option<String>,
@@ -2434,8 +2973,39 @@ pub struct GetGeoLocationRequest {
     pub subdivision_code: Option<String>,
 }
 
+impl Validate for GetGeoLocationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.continent_code {
+            errors.check_length(
+                &field_path(path, "continent_code"),
+                value.chars().count(),
+                Some(2),
+                Some(2),
+            );
+        }
+        if let Some(ref value) = self.country_code {
+            errors.check_length(
+                &field_path(path, "country_code"),
+                value.chars().count(),
+                Some(1),
+                Some(2),
+            );
+        }
+        if let Some(ref value) = self.subdivision_code {
+            errors.check_length(
+                &field_path(path, "subdivision_code"),
+                value.chars().count(),
+                Some(1),
+                Some(3),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response information for the specified geolocation code.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGeoLocationResponse {
     /// <p>A complex type that contains the codes and full continent, country, and subdivision names for the specified geolocation code.</p>
     pub geo_location_details: GeoLocationDetails,
@@ -2462,10 +3032,14 @@ impl GetGeoLocationResponseDeserializer {
 }
 /// <p>A request for the number of health checks that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckCountRequest {}
 
 /// <p>A complex type that contains the response to a <code>GetHealthCheckCount</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckCountResponse {
     /// <p>The number of health checks associated with the current AWS account.</p>
     pub health_check_count: i64,
@@ -2496,13 +3070,28 @@ impl GetHealthCheckCountResponseDeserializer {
 }
 /// <p>A request for the reason that a health check failed most recently.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckLastFailureReasonRequest {
     /// <p><p>The ID for the health check for which you want the last failure reason. When you created the health check, <code>CreateHealthCheck</code> returned the ID in the response, in the <code>HealthCheckId</code> element.</p> <note> <p>If you want to get the last failure reason for a calculated health check, you must use the Amazon Route 53 console or the CloudWatch console. You can&#39;t use <code>GetHealthCheckLastFailureReason</code> for a calculated health check.</p> </note></p>
     pub health_check_id: String,
 }
 
+impl Validate for GetHealthCheckLastFailureReasonRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "health_check_id"),
+            self.health_check_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheckLastFailureReason</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckLastFailureReasonResponse {
     /// <p>A list that contains one <code>Observation</code> element for each Amazon Route 53 health checker that is reporting a last failure reason. </p>
     pub health_check_observations: Vec<HealthCheckObservation>,
@@ -2537,13 +3126,28 @@ impl GetHealthCheckLastFailureReasonResponseDeserializer {
 }
 /// <p>A request to get information about a specified health check. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckRequest {
     /// <p>The identifier that Amazon Route 53 assigned to the health check when you created it. When you add or update a resource record set, you use this value to specify which health check to use. The value can be up to 64 characters long.</p>
     pub health_check_id: String,
 }
 
+impl Validate for GetHealthCheckRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "health_check_id"),
+            self.health_check_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheck</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckResponse {
     /// <p>A complex type that contains information about one health check that is associated with the current AWS account.</p>
     pub health_check: HealthCheck,
@@ -2569,13 +3173,28 @@ impl GetHealthCheckResponseDeserializer {
 }
 /// <p>A request to get the status for a health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckStatusRequest {
     /// <p><p>The ID for the health check that you want the current status for. When you created the health check, <code>CreateHealthCheck</code> returned the ID in the response, in the <code>HealthCheckId</code> element.</p> <note> <p>If you want to check the status of a calculated health check, you must use the Amazon Route 53 console or the CloudWatch console. You can&#39;t use <code>GetHealthCheckStatus</code> to get the status of a calculated health check.</p> </note></p>
     pub health_check_id: String,
 }
 
+impl Validate for GetHealthCheckStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "health_check_id"),
+            self.health_check_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>GetHealthCheck</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHealthCheckStatusResponse {
     /// <p>A list that contains one <code>HealthCheckObservation</code> element for each Amazon Route 53 health checker that is reporting a status about the health check endpoint.</p>
     pub health_check_observations: Vec<HealthCheckObservation>,
@@ -2610,10 +3229,14 @@ impl GetHealthCheckStatusResponseDeserializer {
 }
 /// <p>A request to retrieve a count of all the hosted zones that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostedZoneCountRequest {}
 
 /// <p>A complex type that contains the response to a <code>GetHostedZoneCount</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostedZoneCountResponse {
     /// <p>The total number of public and private hosted zones that are associated with the current AWS account.</p>
     pub hosted_zone_count: i64,
@@ -2644,6 +3267,8 @@ impl GetHostedZoneCountResponseDeserializer {
 }
 /// <p>A complex type that contains information about the request to create a hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostedZoneLimitRequest {
     /// <p>The ID of the hosted zone that you want to get a limit for.</p>
     pub hosted_zone_id: String,
@@ -2651,8 +3276,21 @@ pub struct GetHostedZoneLimitRequest {
     pub type_: String,
 }
 
+impl Validate for GetHostedZoneLimitRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 /// <p>A complex type that contains the requested limit. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostedZoneLimitResponse {
     /// <p>The current number of entities that you have created of the specified type. For example, if you specified <code>MAX_RRSETS_BY_ZONE</code> for the value of <code>Type</code> in the request, the value of <code>Count</code> is the current number of records that you have created in the specified hosted zone.</p>
     pub count: i64,
@@ -2687,13 +3325,28 @@ impl GetHostedZoneLimitResponseDeserializer {
 }
 /// <p>A request to get information about a specified hosted zone. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostedZoneRequest {
     /// <p>The ID of the hosted zone that you want to get information about.</p>
     pub id: String,
 }
 
+impl Validate for GetHostedZoneRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 /// <p>A complex type that contain the response to a <code>GetHostedZone</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetHostedZoneResponse {
     /// <p>A complex type that lists the Amazon Route 53 name servers for the specified hosted zone.</p>
     pub delegation_set: Option<DelegationSet>,
@@ -2733,12 +3386,27 @@ impl GetHostedZoneResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueryLoggingConfigRequest {
     /// <p>The ID of the configuration for DNS query logging that you want to get information about.</p>
     pub id: String,
 }
 
+impl Validate for GetQueryLoggingConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueryLoggingConfigResponse {
     /// <p>A complex type that contains information about the query logging configuration that you specified in a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetQueryLoggingConfig.html">GetQueryLoggingConfig</a> request.</p>
     pub query_logging_config: QueryLoggingConfig,
@@ -2771,6 +3439,8 @@ impl GetQueryLoggingConfigResponseDeserializer {
 }
 /// <p>A complex type that contains information about the request to create a hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReusableDelegationSetLimitRequest {
     /// <p>The ID of the delegation set that you want to get the limit for.</p>
     pub delegation_set_id: String,
@@ -2778,8 +3448,21 @@ pub struct GetReusableDelegationSetLimitRequest {
     pub type_: String,
 }
 
+impl Validate for GetReusableDelegationSetLimitRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "delegation_set_id"),
+            self.delegation_set_id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 /// <p>A complex type that contains the requested limit. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReusableDelegationSetLimitResponse {
     /// <p>The current number of hosted zones that you can associate with the specified reusable delegation set.</p>
     pub count: i64,
@@ -2815,13 +3498,28 @@ impl GetReusableDelegationSetLimitResponseDeserializer {
 }
 /// <p>A request to get information about a specified reusable delegation set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReusableDelegationSetRequest {
     /// <p>The ID of the reusable delegation set that you want to get a list of name servers for.</p>
     pub id: String,
 }
 
+impl Validate for GetReusableDelegationSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response to the <code>GetReusableDelegationSet</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReusableDelegationSetResponse {
     /// <p>A complex type that contains information about the reusable delegation set.</p>
     pub delegation_set: DelegationSet,
@@ -2852,10 +3550,14 @@ impl GetReusableDelegationSetResponseDeserializer {
 }
 /// <p>Request to get the number of traffic policy instances that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrafficPolicyInstanceCountRequest {}
 
 /// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrafficPolicyInstanceCountResponse {
     /// <p>The number of traffic policy instances that are associated with the current AWS account.</p>
     pub traffic_policy_instance_count: i64,
@@ -2889,13 +3591,28 @@ impl GetTrafficPolicyInstanceCountResponseDeserializer {
 }
 /// <p>Gets information about a specified traffic policy instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrafficPolicyInstanceRequest {
     /// <p>The ID of the traffic policy instance that you want to get information about.</p>
     pub id: String,
 }
 
+impl Validate for GetTrafficPolicyInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+    }
+}
+
 /// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrafficPolicyInstanceResponse {
     /// <p>A complex type that contains settings for the traffic policy instance.</p>
     pub traffic_policy_instance: TrafficPolicyInstance,
@@ -2929,6 +3646,8 @@ impl GetTrafficPolicyInstanceResponseDeserializer {
 }
 /// <p>Gets information about a specific traffic policy version.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrafficPolicyRequest {
     /// <p>The ID of the traffic policy that you want to get information about.</p>
     pub id: String,
@@ -2936,8 +3655,27 @@ pub struct GetTrafficPolicyRequest {
     pub version: i64,
 }
 
+impl Validate for GetTrafficPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        errors.check_range(
+            &field_path(path, "version"),
+            self.version,
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTrafficPolicyResponse {
     /// <p>A complex type that contains settings for the specified traffic policy.</p>
     pub traffic_policy: TrafficPolicy,
@@ -2968,6 +3706,8 @@ impl GetTrafficPolicyResponseDeserializer {
 }
 /// <p>A complex type that contains information about one health check that is associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HealthCheck {
     /// <p>A unique string that you specified when you created the health check.</p>
     pub caller_reference: String,
@@ -3028,6 +3768,8 @@ impl HealthCheckDeserializer {
 }
 /// <p>A complex type that contains information about the health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HealthCheckConfig {
     /// <p>A complex type that identifies the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether the specified health check is healthy.</p>
     pub alarm_identifier: Option<AlarmIdentifier>,
@@ -3065,6 +3807,93 @@ pub struct HealthCheckConfig {
     pub type_: String,
 }
 
+impl Validate for HealthCheckConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alarm_identifier {
+            value.validate_at(&field_path(path, "alarm_identifier"), errors);
+        }
+        if let Some(ref value) = self.child_health_checks {
+            errors.check_length(
+                &field_path(path, "child_health_checks"),
+                value.len(),
+                None,
+                Some(256),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "child_health_checks"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(64));
+            }
+        }
+        if let Some(ref value) = self.failure_threshold {
+            errors.check_range(
+                &field_path(path, "failure_threshold"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.fully_qualified_domain_name {
+            errors.check_length(
+                &field_path(path, "fully_qualified_domain_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.health_threshold {
+            errors.check_range(
+                &field_path(path, "health_threshold"),
+                *value,
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.ip_address {
+            errors.check_length(
+                &field_path(path, "ip_address"),
+                value.chars().count(),
+                None,
+                Some(45),
+            );
+            errors.check_pattern(&field_path(path, "ip_address"), &value, "(^((([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5]))$|^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$)");
+        }
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, Some(1), Some(65535));
+        }
+        if let Some(ref value) = self.regions {
+            errors.check_length(&field_path(path, "regions"), value.len(), Some(3), Some(64));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.request_interval {
+            errors.check_range(
+                &field_path(path, "request_interval"),
+                *value,
+                Some(10),
+                Some(30),
+            );
+        }
+        if let Some(ref value) = self.resource_path {
+            errors.check_length(
+                &field_path(path, "resource_path"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.search_string {
+            errors.check_length(
+                &field_path(path, "search_string"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 struct HealthCheckConfigDeserializer;
 impl HealthCheckConfigDeserializer {
     #[allow(unused_variables)]
@@ -3309,7 +4138,7 @@ impl HealthCheckCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3381,6 +4210,8 @@ impl HealthCheckNonceSerializer {
 
 /// <p>A complex type that contains the last failure reason as reported by one Amazon Route 53 health checker.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HealthCheckObservation {
     /// <p>The IP address of the Amazon Route 53 health checker that provided the failure reason in <code>StatusReport</code>.</p>
     pub ip_address: Option<String>,
@@ -3544,7 +4375,7 @@ impl HealthCheckVersionDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3593,7 +4424,7 @@ impl HealthThresholdDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3622,6 +4453,8 @@ impl HealthThresholdSerializer {
 
 /// <p>A complex type that contains general information about the hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HostedZone {
     /// <p>The value that you specified for <code>CallerReference</code> when you created the hosted zone.</p>
     pub caller_reference: String,
@@ -3680,6 +4513,8 @@ impl HostedZoneDeserializer {
 }
 /// <p>A complex type that contains an optional comment about your hosted zone. If you don't want to specify a comment, omit both the <code>HostedZoneConfig</code> and <code>Comment</code> elements.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HostedZoneConfig {
     /// <p>Any comments that you want to include about the hosted zone.</p>
     pub comment: Option<String>,
@@ -3687,6 +4522,19 @@ pub struct HostedZoneConfig {
     pub private_zone: Option<bool>,
 }
 
+impl Validate for HostedZoneConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 struct HostedZoneConfigDeserializer;
 impl HostedZoneConfigDeserializer {
     #[allow(unused_variables)]
@@ -3751,7 +4599,7 @@ impl HostedZoneCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3759,6 +4607,8 @@ impl HostedZoneCountDeserializer {
 }
 /// <p>A complex type that contains the type of limit that you specified in the request and the current value for that limit.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HostedZoneLimit {
     /// <p><p>The limit that you requested. Valid values include the following:</p> <ul> <li> <p> <b>MAX<em>RRSETS</em>BY<em>ZONE</b>: The maximum number of records that you can create in the specified hosted zone.</p> </li> <li> <p> <b>MAX</em>VPCS<em>ASSOCIATED</em>BY_ZONE</b>: The maximum number of Amazon VPCs that you can associate with the specified private hosted zone.</p> </li> </ul></p>
     pub type_: String,
@@ -3824,7 +4674,7 @@ impl HostedZoneRRSetCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3927,7 +4777,7 @@ impl InvertedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3959,7 +4809,7 @@ impl IsPrivateZoneDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3991,7 +4841,7 @@ impl LimitValueDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3999,6 +4849,8 @@ impl LimitValueDeserializer {
 }
 /// <p>If a health check or hosted zone was created by another service, <code>LinkedService</code> is a complex type that describes the service that created the resource. When a resource is created by another service, you can't edit or delete it using Amazon Route 53. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LinkedService {
     /// <p>If the health check or hosted zone was created by another service, an optional description that can be provided by the other service. When a resource is created by another service, you can't edit or delete it using Amazon Route 53. </p>
     pub description: Option<String>,
@@ -4035,6 +4887,8 @@ impl LinkedServiceDeserializer {
 }
 /// <p>A request to get a list of geographic locations that Amazon Route 53 supports for geolocation resource record sets. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGeoLocationsRequest {
     /// <p>(Optional) The maximum number of geolocations to be included in the response body for this request. If more than <code>maxitems</code> geolocations remain to be listed, then the value of the <code>IsTruncated</code> element in the response is <code>true</code>.</p>
     pub max_items: Option<String>,
@@ -4046,8 +4900,39 @@ pub struct ListGeoLocationsRequest {
     pub start_subdivision_code: Option<String>,
 }
 
+impl Validate for ListGeoLocationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.start_continent_code {
+            errors.check_length(
+                &field_path(path, "start_continent_code"),
+                value.chars().count(),
+                Some(2),
+                Some(2),
+            );
+        }
+        if let Some(ref value) = self.start_country_code {
+            errors.check_length(
+                &field_path(path, "start_country_code"),
+                value.chars().count(),
+                Some(1),
+                Some(2),
+            );
+        }
+        if let Some(ref value) = self.start_subdivision_code {
+            errors.check_length(
+                &field_path(path, "start_subdivision_code"),
+                value.chars().count(),
+                Some(1),
+                Some(3),
+            );
+        }
+    }
+}
+
 /// <p>A complex type containing the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGeoLocationsResponse {
     /// <p>A complex type that contains one <code>GeoLocationDetails</code> element for each location that Amazon Route 53 supports for geolocation.</p>
     pub geo_location_details_list: Vec<GeoLocationDetails>,
@@ -4120,6 +5005,8 @@ impl ListGeoLocationsResponseDeserializer {
 }
 /// <p>A request to retrieve a list of the health checks that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHealthChecksRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more health checks. To get another group, submit another <code>ListHealthChecks</code> request. </p> <p>For the value of <code>marker</code>, specify the value of <code>NextMarker</code> from the previous response, which is the ID of the first health check that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more health checks to get.</p>
     pub marker: Option<String>,
@@ -4127,8 +5014,23 @@ pub struct ListHealthChecksRequest {
     pub max_items: Option<String>,
 }
 
+impl Validate for ListHealthChecksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>ListHealthChecks</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHealthChecksResponse {
     /// <p>A complex type that contains one <code>HealthCheck</code> element for each health check that is associated with the current AWS account.</p>
     pub health_checks: Vec<HealthCheck>,
@@ -4184,6 +5086,8 @@ impl ListHealthChecksResponseDeserializer {
 }
 /// <p>Retrieves a list of the public and private hosted zones that are associated with the current AWS account in ASCII order by domain name. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHostedZonesByNameRequest {
     /// <p>(Optional) For your first request to <code>ListHostedZonesByName</code>, include the <code>dnsname</code> parameter only if you want to specify the name of the first hosted zone in the response. If you don't include the <code>dnsname</code> parameter, Amazon Route 53 returns all of the hosted zones that were created by the current AWS account, in ASCII order. For subsequent requests, include both <code>dnsname</code> and <code>hostedzoneid</code> parameters. For <code>dnsname</code>, specify the value of <code>NextDNSName</code> from the previous response.</p>
     pub dns_name: Option<String>,
@@ -4193,8 +5097,31 @@ pub struct ListHostedZonesByNameRequest {
     pub max_items: Option<String>,
 }
 
+impl Validate for ListHostedZonesByNameRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dns_name {
+            errors.check_length(
+                &field_path(path, "dns_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.hosted_zone_id {
+            errors.check_length(
+                &field_path(path, "hosted_zone_id"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHostedZonesByNameResponse {
     /// <p>For the second and subsequent calls to <code>ListHostedZonesByName</code>, <code>DNSName</code> is the value that you specified for the <code>dnsname</code> parameter in the request that produced the current response.</p>
     pub dns_name: Option<String>,
@@ -4261,6 +5188,8 @@ impl ListHostedZonesByNameResponseDeserializer {
 }
 /// <p>A request to retrieve a list of the public and private hosted zones that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHostedZonesRequest {
     /// <p>If you're using reusable delegation sets and you want to list all of the hosted zones that are associated with a reusable delegation set, specify the ID of that reusable delegation set. </p>
     pub delegation_set_id: Option<String>,
@@ -4270,7 +5199,30 @@ pub struct ListHostedZonesRequest {
     pub max_items: Option<String>,
 }
 
+impl Validate for ListHostedZonesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.delegation_set_id {
+            errors.check_length(
+                &field_path(path, "delegation_set_id"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHostedZonesResponse {
     /// <p>A complex type that contains general information about the hosted zone.</p>
     pub hosted_zones: Vec<HostedZone>,
@@ -4322,6 +5274,8 @@ impl ListHostedZonesResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueryLoggingConfigsRequest {
     /// <p>(Optional) If you want to list the query logging configuration that is associated with a hosted zone, specify the ID in <code>HostedZoneId</code>. </p> <p>If you don't specify a hosted zone ID, <code>ListQueryLoggingConfigs</code> returns all of the configurations that are associated with the current AWS account.</p>
     pub hosted_zone_id: Option<String>,
@@ -4331,7 +5285,30 @@ pub struct ListQueryLoggingConfigsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListQueryLoggingConfigsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hosted_zone_id {
+            errors.check_length(
+                &field_path(path, "hosted_zone_id"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueryLoggingConfigsResponse {
     /// <p>If a response includes the last of the query logging configurations that are associated with the current AWS account, <code>NextToken</code> doesn't appear in the response.</p> <p>If a response doesn't include the last of the configurations, you can get more configurations by submitting another <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ListQueryLoggingConfigs.html">ListQueryLoggingConfigs</a> request. Get the value of <code>NextToken</code> that Amazon Route 53 returned in the previous response and include it in <code>NextToken</code> in the next request.</p>
     pub next_token: Option<String>,
@@ -4374,6 +5351,8 @@ impl ListQueryLoggingConfigsResponseDeserializer {
 }
 /// <p>A request for the resource record sets that are associated with a specified hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceRecordSetsRequest {
     /// <p>The ID of the hosted zone that contains the resource record sets that you want to list.</p>
     pub hosted_zone_id: String,
@@ -4387,8 +5366,37 @@ pub struct ListResourceRecordSetsRequest {
     pub start_record_type: Option<String>,
 }
 
+impl Validate for ListResourceRecordSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        if let Some(ref value) = self.start_record_identifier {
+            errors.check_length(
+                &field_path(path, "start_record_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.start_record_name {
+            errors.check_length(
+                &field_path(path, "start_record_name"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains list information for the resource record set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceRecordSetsResponse {
     /// <p>A flag that indicates whether more resource record sets remain to be listed. If your results were truncated, you can make a follow-up pagination request by using the <code>NextRecordName</code> element.</p>
     pub is_truncated: bool,
@@ -4455,6 +5463,8 @@ impl ListResourceRecordSetsResponseDeserializer {
 }
 /// <p>A request to get a list of the reusable delegation sets that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReusableDelegationSetsRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more reusable delegation sets. To get another group, submit another <code>ListReusableDelegationSets</code> request. </p> <p>For the value of <code>marker</code>, specify the value of <code>NextMarker</code> from the previous response, which is the ID of the first reusable delegation set that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more reusable delegation sets to get.</p>
     pub marker: Option<String>,
@@ -4462,8 +5472,23 @@ pub struct ListReusableDelegationSetsRequest {
     pub max_items: Option<String>,
 }
 
+impl Validate for ListReusableDelegationSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains information about the reusable delegation sets that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReusableDelegationSetsResponse {
     /// <p>A complex type that contains one <code>DelegationSet</code> element for each reusable delegation set that was created by the current AWS account.</p>
     pub delegation_sets: Vec<DelegationSet>,
@@ -4519,6 +5544,8 @@ impl ListReusableDelegationSetsResponseDeserializer {
 }
 /// <p>A complex type containing information about a request for a list of the tags that are associated with an individual resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ID of the resource for which you want to retrieve tags.</p>
     pub resource_id: String,
@@ -4526,8 +5553,21 @@ pub struct ListTagsForResourceRequest {
     pub resource_type: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 /// <p>A complex type that contains information about the health checks or hosted zones for which you want to list tags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>A <code>ResourceTagSet</code> containing tags associated with the specified resource.</p>
     pub resource_tag_set: ResourceTagSet,
@@ -4558,6 +5598,8 @@ impl ListTagsForResourceResponseDeserializer {
 }
 /// <p>A complex type that contains information about the health checks or hosted zones for which you want to list tags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourcesRequest {
     /// <p>A complex type that contains the ResourceId element for each resource for which you want to get a list of tags.</p>
     pub resource_ids: Vec<String>,
@@ -4565,6 +5607,21 @@ pub struct ListTagsForResourcesRequest {
     pub resource_type: String,
 }
 
+impl Validate for ListTagsForResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_ids"),
+            self.resource_ids.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.resource_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(64));
+        }
+    }
+}
+
 pub struct ListTagsForResourcesRequestSerializer;
 impl ListTagsForResourcesRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -4584,6 +5641,8 @@ impl ListTagsForResourcesRequestSerializer {
 }
 /// <p>A complex type containing tags for the specified resources.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourcesResponse {
     /// <p>A list of <code>ResourceTagSet</code>s containing tags associated with the specified resources.</p>
     pub resource_tag_sets: Vec<ResourceTagSet>,
@@ -4617,6 +5676,8 @@ impl ListTagsForResourcesResponseDeserializer {
 }
 /// <p>A complex type that contains the information about the request to list the traffic policies that are associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPoliciesRequest {
     /// <p>(Optional) The maximum number of traffic policies that you want Amazon Route 53 to return in response to this request. If you have more than <code>MaxItems</code> traffic policies, the value of <code>IsTruncated</code> in the response is <code>true</code>, and the value of <code>TrafficPolicyIdMarker</code> is the ID of the first traffic policy that Route 53 will return if you submit another request.</p>
     pub max_items: Option<String>,
@@ -4624,8 +5685,23 @@ pub struct ListTrafficPoliciesRequest {
     pub traffic_policy_id_marker: Option<String>,
 }
 
+impl Validate for ListTrafficPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.traffic_policy_id_marker {
+            errors.check_length(
+                &field_path(path, "traffic_policy_id_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(36),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPoliciesResponse {
     /// <p>A flag that indicates whether there are more traffic policies to be listed. If the response was truncated, you can get the next group of traffic policies by submitting another <code>ListTrafficPolicies</code> request and specifying the value of <code>TrafficPolicyIdMarker</code> in the <code>TrafficPolicyIdMarker</code> request parameter.</p>
     pub is_truncated: bool,
@@ -4679,6 +5755,8 @@ impl ListTrafficPoliciesResponseDeserializer {
 }
 /// <p>A request for the traffic policy instances that you created in a specified hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyInstancesByHostedZoneRequest {
     /// <p>The ID of the hosted zone that you want to list traffic policy instances for.</p>
     pub hosted_zone_id: String,
@@ -4690,8 +5768,29 @@ pub struct ListTrafficPolicyInstancesByHostedZoneRequest {
     pub traffic_policy_instance_type_marker: Option<String>,
 }
 
+impl Validate for ListTrafficPolicyInstancesByHostedZoneRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        if let Some(ref value) = self.traffic_policy_instance_name_marker {
+            errors.check_length(
+                &field_path(path, "traffic_policy_instance_name_marker"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyInstancesByHostedZoneResponse {
     /// <p>A flag that indicates whether there are more traffic policy instances to be listed. If the response was truncated, you can get the next group of traffic policy instances by submitting another <code>ListTrafficPolicyInstancesByHostedZone</code> request and specifying the values of <code>HostedZoneIdMarker</code>, <code>TrafficPolicyInstanceNameMarker</code>, and <code>TrafficPolicyInstanceTypeMarker</code> in the corresponding request parameters.</p>
     pub is_truncated: bool,
@@ -4755,6 +5854,8 @@ impl ListTrafficPolicyInstancesByHostedZoneResponseDeserializer {
 }
 /// <p>A complex type that contains the information about the request to list your traffic policy instances.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyInstancesByPolicyRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstancesByPolicy</code> request. </p> <p>For the value of <code>hostedzoneid</code>, specify the value of <code>HostedZoneIdMarker</code> from the previous response, which is the hosted zone ID of the first traffic policy instance that Amazon Route 53 will return if you submit another request.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub hosted_zone_id_marker: Option<String>,
@@ -4770,8 +5871,43 @@ pub struct ListTrafficPolicyInstancesByPolicyRequest {
     pub traffic_policy_version: i64,
 }
 
+impl Validate for ListTrafficPolicyInstancesByPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hosted_zone_id_marker {
+            errors.check_length(
+                &field_path(path, "hosted_zone_id_marker"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "traffic_policy_id"),
+            self.traffic_policy_id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        if let Some(ref value) = self.traffic_policy_instance_name_marker {
+            errors.check_length(
+                &field_path(path, "traffic_policy_instance_name_marker"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "traffic_policy_version"),
+            self.traffic_policy_version,
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyInstancesByPolicyResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>HostedZoneIdMarker</code> is the ID of the hosted zone of the first traffic policy instance in the next group of traffic policy instances.</p>
     pub hosted_zone_id_marker: Option<String>,
@@ -4843,6 +5979,8 @@ impl ListTrafficPolicyInstancesByPolicyResponseDeserializer {
 }
 /// <p>A request to get information about the traffic policy instances that you created by using the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyInstancesRequest {
     /// <p>If the value of <code>IsTruncated</code> in the previous response was <code>true</code>, you have more traffic policy instances. To get more traffic policy instances, submit another <code>ListTrafficPolicyInstances</code> request. For the value of <code>HostedZoneId</code>, specify the value of <code>HostedZoneIdMarker</code> from the previous response, which is the hosted zone ID of the first traffic policy instance in the next group of traffic policy instances.</p> <p>If the value of <code>IsTruncated</code> in the previous response was <code>false</code>, there are no more traffic policy instances to get.</p>
     pub hosted_zone_id_marker: Option<String>,
@@ -4854,8 +5992,31 @@ pub struct ListTrafficPolicyInstancesRequest {
     pub traffic_policy_instance_type_marker: Option<String>,
 }
 
+impl Validate for ListTrafficPolicyInstancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hosted_zone_id_marker {
+            errors.check_length(
+                &field_path(path, "hosted_zone_id_marker"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.traffic_policy_instance_name_marker {
+            errors.check_length(
+                &field_path(path, "traffic_policy_instance_name_marker"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyInstancesResponse {
     /// <p>If <code>IsTruncated</code> is <code>true</code>, <code>HostedZoneIdMarker</code> is the ID of the hosted zone of the first traffic policy instance that Route 53 will return if you submit another <code>ListTrafficPolicyInstances</code> request. </p>
     pub hosted_zone_id_marker: Option<String>,
@@ -4927,6 +6088,8 @@ impl ListTrafficPolicyInstancesResponseDeserializer {
 }
 /// <p>A complex type that contains the information about the request to list your traffic policies.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyVersionsRequest {
     /// <p>Specify the value of <code>Id</code> of the traffic policy for which you want to list all versions.</p>
     pub id: String,
@@ -4936,8 +6099,29 @@ pub struct ListTrafficPolicyVersionsRequest {
     pub traffic_policy_version_marker: Option<String>,
 }
 
-/// <p>A complex type that contains the response information for the request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+impl Validate for ListTrafficPolicyVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        if let Some(ref value) = self.traffic_policy_version_marker {
+            errors.check_length(
+                &field_path(path, "traffic_policy_version_marker"),
+                value.chars().count(),
+                None,
+                Some(4),
+            );
+        }
+    }
+}
+
+/// <p>A complex type that contains the response information for the request.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrafficPolicyVersionsResponse {
     /// <p>A flag that indicates whether there are more traffic policies to be listed. If the response was truncated, you can get the next group of traffic policies by submitting another <code>ListTrafficPolicyVersions</code> request and specifying the value of <code>NextMarker</code> in the <code>marker</code> parameter.</p>
     pub is_truncated: bool,
@@ -4991,6 +6175,8 @@ impl ListTrafficPolicyVersionsResponseDeserializer {
 }
 /// <p>A complex type that contains information about that can be associated with your hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVPCAssociationAuthorizationsRequest {
     /// <p>The ID of the hosted zone for which you want a list of VPCs that can be associated with the hosted zone.</p>
     pub hosted_zone_id: String,
@@ -5000,8 +6186,29 @@ pub struct ListVPCAssociationAuthorizationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListVPCAssociationAuthorizationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains the response information for the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVPCAssociationAuthorizationsResponse {
     /// <p>The ID of the hosted zone that you can associate the listed VPCs with.</p>
     pub hosted_zone_id: String,
@@ -5070,7 +6277,7 @@ impl MeasureLatencyDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5242,7 +6449,7 @@ impl PageTruncatedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5285,7 +6492,7 @@ impl PeriodDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5296,7 +6503,7 @@ impl PortDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5325,6 +6532,8 @@ impl PortSerializer {
 
 /// <p>A complex type that contains information about a configuration for DNS query logging.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueryLoggingConfig {
     /// <p>The Amazon Resource Name (ARN) of the CloudWatch Logs log group that Amazon Route 53 is publishing logs to.</p>
     pub cloud_watch_logs_log_group_arn: String,
@@ -5515,7 +6724,7 @@ impl RequestIntervalDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5680,11 +6889,24 @@ impl ResourcePathSerializer {
 
 /// <p><p>Information specific to the resource record.</p> <note> <p>If you&#39;re creating an alias resource record set, omit <code>ResourceRecord</code>.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceRecord {
     /// <p><p>The current or new DNS record value, not to exceed 4,000 characters. In the case of a <code>DELETE</code> action, if the current value does not match the actual value, an error is returned. For descriptions about how to format <code>Value</code> for different record types, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/ResourceRecordTypes.html">Supported DNS Resource Record Types</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>You can specify more than one value for all record types except <code>CNAME</code> and <code>SOA</code>. </p> <note> <p>If you&#39;re creating an alias resource record set, omit <code>Value</code>.</p> </note></p>
     pub value: String,
 }
 
+impl Validate for ResourceRecord {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(4000),
+        );
+    }
+}
+
 struct ResourceRecordDeserializer;
 impl ResourceRecordDeserializer {
     #[allow(unused_variables)]
@@ -5728,6 +6950,8 @@ impl ResourceRecordSerializer {
 
 /// <p>Information about the resource record set to create or delete.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceRecordSet {
     /// <p><p> <i>Alias resource record sets only:</i> Information about the AWS resource, such as a CloudFront distribution or an Amazon S3 bucket, that you want to route traffic to. </p> <p>If you&#39;re creating resource records sets for a private hosted zone, note the following:</p> <ul> <li> <p>You can&#39;t create an alias resource record set in a private hosted zone to route traffic to a CloudFront distribution.</p> </li> <li> <p>Creating geolocation alias resource record sets or latency alias resource record sets in a private hosted zone is unsupported.</p> </li> <li> <p>For information about creating failover resource record sets in a private hosted zone, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/dns-failover-private-hosted-zones.html">Configuring Failover in a Private Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> </ul></p>
     pub alias_target: Option<AliasTarget>,
@@ -5757,6 +6981,73 @@ pub struct ResourceRecordSet {
     pub weight: Option<i64>,
 }
 
+impl Validate for ResourceRecordSet {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alias_target {
+            value.validate_at(&field_path(path, "alias_target"), errors);
+        }
+        if let Some(ref value) = self.geo_location {
+            value.validate_at(&field_path(path, "geo_location"), errors);
+        }
+        if let Some(ref value) = self.health_check_id {
+            errors.check_length(
+                &field_path(path, "health_check_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(1024),
+        );
+        if let Some(ref value) = self.region {
+            errors.check_length(
+                &field_path(path, "region"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.resource_records {
+            errors.check_length(
+                &field_path(path, "resource_records"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_records"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.set_identifier {
+            errors.check_length(
+                &field_path(path, "set_identifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.ttl {
+            errors.check_range(&field_path(path, "ttl"), *value, None, Some(2147483647));
+        }
+        if let Some(ref value) = self.traffic_policy_instance_id {
+            errors.check_length(
+                &field_path(path, "traffic_policy_instance_id"),
+                value.chars().count(),
+                Some(1),
+                Some(36),
+            );
+        }
+        if let Some(ref value) = self.weight {
+            errors.check_range(&field_path(path, "weight"), *value, None, Some(255));
+        }
+    }
+}
+
 struct ResourceRecordSetDeserializer;
 impl ResourceRecordSetDeserializer {
     #[allow(unused_variables)]
@@ -6009,7 +7300,7 @@ impl ResourceRecordSetMultiValueAnswerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6073,7 +7364,7 @@ impl ResourceRecordSetWeightDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6163,6 +7454,8 @@ impl ResourceRecordsSerializer {
 
 /// <p>A complex type containing a resource and its associated tags.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResourceTagSet {
     /// <p>The ID for the specified resource.</p>
     pub resource_id: Option<String>,
@@ -6224,6 +7517,8 @@ impl ResourceTagSetListDeserializer {
 }
 /// <p>A complex type that contains the type of limit that you specified in the request and the current value for that limit.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReusableDelegationSetLimit {
     /// <p>The limit that you requested: <code>MAX_ZONES_BY_REUSABLE_DELEGATION_SET</code>, the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p>
     pub type_: String,
@@ -6356,6 +7651,8 @@ impl StatusDeserializer {
 }
 /// <p>A complex type that contains the status that one Amazon Route 53 health checker reports and the time of the health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StatusReport {
     /// <p>The date and time that the health checker performed the health check in <a href="https://en.wikipedia.org/wiki/ISO_8601">ISO 8601 format</a> and Coordinated Universal Time (UTC). For example, the value <code>2017-03-27T17:48:16.751Z</code> represents March 27, 2017 at 17:48:16.751 UTC.</p>
     pub checked_time: Option<String>,
@@ -6411,7 +7708,7 @@ impl TTLDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6440,6 +7737,8 @@ impl TTLSerializer {
 
 /// <p>A complex type that contains information about a tag that you want to add or edit for the specified health check or hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p><p>The value of <code>Key</code> depends on the operation that you want to perform:</p> <ul> <li> <p> <b>Add a tag to a health check or hosted zone</b>: <code>Key</code> is the name that you want to give the new tag.</p> </li> <li> <p> <b>Edit a tag</b>: <code>Key</code> is the name of the tag that you want to change the <code>Value</code> for.</p> </li> <li> <p> <b> Delete a key</b>: <code>Key</code> is the name of the tag you want to remove.</p> </li> <li> <p> <b>Give a name to a health check</b>: Edit the default <code>Name</code> tag. In the Amazon Route 53 console, the list of your health checks includes a <b>Name</b> column that lets you see the name that you&#39;ve given to each health check.</p> </li> </ul></p>
     pub key: Option<String>,
@@ -6447,6 +7746,27 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -6706,6 +8026,8 @@ impl TagValueSerializer {
 
 /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestDNSAnswerRequest {
     /// <p>If the resolver that you specified for resolverip supports EDNS0, specify the IPv4 or IPv6 address of a client in the applicable location, for example, <code>192.0.2.44</code> or <code>2001:db8:85a3::8a2e:370:7334</code>.</p>
     pub edns0_client_subnet_ip: Option<String>,
@@ -6721,8 +8043,53 @@ pub struct TestDNSAnswerRequest {
     pub resolver_ip: Option<String>,
 }
 
+impl Validate for TestDNSAnswerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.edns0_client_subnet_ip {
+            errors.check_length(
+                &field_path(path, "edns0_client_subnet_ip"),
+                value.chars().count(),
+                None,
+                Some(45),
+            );
+            errors.check_pattern(&field_path(path, "edns0_client_subnet_ip"), &value, "(^((([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5]))$|^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$)");
+        }
+        if let Some(ref value) = self.edns0_client_subnet_mask {
+            errors.check_length(
+                &field_path(path, "edns0_client_subnet_mask"),
+                value.chars().count(),
+                None,
+                Some(3),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "hosted_zone_id"),
+            self.hosted_zone_id.chars().count(),
+            None,
+            Some(32),
+        );
+        errors.check_length(
+            &field_path(path, "record_name"),
+            self.record_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        if let Some(ref value) = self.resolver_ip {
+            errors.check_length(
+                &field_path(path, "resolver_ip"),
+                value.chars().count(),
+                None,
+                Some(45),
+            );
+            errors.check_pattern(&field_path(path, "resolver_ip"), &value, "(^((([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5]))$|^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$)");
+        }
+    }
+}
+
 /// <p>A complex type that contains the response to a <code>TestDNSAnswer</code> request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestDNSAnswerResponse {
     /// <p>The Amazon Route 53 name server used to respond to the request.</p>
     pub nameserver: String,
@@ -6777,7 +8144,7 @@ impl ThresholdDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6816,6 +8183,8 @@ impl TrafficPoliciesDeserializer {
 }
 /// <p>A complex type that contains settings for a traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TrafficPolicy {
     /// <p>The comment that you specify in the <code>CreateTrafficPolicy</code> request, if any.</p>
     pub comment: Option<String>,
@@ -6965,6 +8334,8 @@ impl TrafficPolicyIdSerializer {
 
 /// <p>A complex type that contains settings for the new traffic policy instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TrafficPolicyInstance {
     /// <p>The ID of the hosted zone that Amazon Route 53 created resource record sets in.</p>
     pub hosted_zone_id: String,
@@ -7040,7 +8411,7 @@ impl TrafficPolicyInstanceCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7163,6 +8534,8 @@ impl TrafficPolicySummariesDeserializer {
 }
 /// <p>A complex type that contains information about the latest version of one traffic policy that is associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TrafficPolicySummary {
     /// <p>The ID that Amazon Route 53 assigned to the traffic policy when you created it.</p>
     pub id: String,
@@ -7213,7 +8586,7 @@ impl TrafficPolicyVersionDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7285,6 +8658,8 @@ impl TransportProtocolDeserializer {
 }
 /// <p>A complex type that contains information about a request to update a health check.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateHealthCheckRequest {
     /// <p>A complex type that identifies the CloudWatch alarm that you want Amazon Route 53 health checkers to use to determine whether the specified health check is healthy.</p>
     pub alarm_identifier: Option<AlarmIdentifier>,
@@ -7322,6 +8697,111 @@ pub struct UpdateHealthCheckRequest {
     pub search_string: Option<String>,
 }
 
+impl Validate for UpdateHealthCheckRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.alarm_identifier {
+            value.validate_at(&field_path(path, "alarm_identifier"), errors);
+        }
+        if let Some(ref value) = self.child_health_checks {
+            errors.check_length(
+                &field_path(path, "child_health_checks"),
+                value.len(),
+                None,
+                Some(256),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "child_health_checks"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(64));
+            }
+        }
+        if let Some(ref value) = self.failure_threshold {
+            errors.check_range(
+                &field_path(path, "failure_threshold"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.fully_qualified_domain_name {
+            errors.check_length(
+                &field_path(path, "fully_qualified_domain_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "health_check_id"),
+            self.health_check_id.chars().count(),
+            None,
+            Some(64),
+        );
+        if let Some(ref value) = self.health_check_version {
+            errors.check_range(
+                &field_path(path, "health_check_version"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.health_threshold {
+            errors.check_range(
+                &field_path(path, "health_threshold"),
+                *value,
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.ip_address {
+            errors.check_length(
+                &field_path(path, "ip_address"),
+                value.chars().count(),
+                None,
+                Some(45),
+            );
+            errors.check_pattern(&field_path(path, "ip_address"), &value, "(^((([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5]))$|^(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))$)");
+        }
+        if let Some(ref value) = self.port {
+            errors.check_range(&field_path(path, "port"), *value, Some(1), Some(65535));
+        }
+        if let Some(ref value) = self.regions {
+            errors.check_length(&field_path(path, "regions"), value.len(), Some(3), Some(64));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "regions"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.reset_elements {
+            errors.check_length(
+                &field_path(path, "reset_elements"),
+                value.len(),
+                None,
+                Some(64),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "reset_elements"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.resource_path {
+            errors.check_length(
+                &field_path(path, "resource_path"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.search_string {
+            errors.check_length(
+                &field_path(path, "search_string"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 pub struct UpdateHealthCheckRequestSerializer;
 impl UpdateHealthCheckRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7396,6 +8876,8 @@ impl UpdateHealthCheckRequestSerializer {
 }
 /// <p>A complex type that contains the response to the <code>UpdateHealthCheck</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateHealthCheckResponse {
     /// <p>A complex type that contains the response to an <code>UpdateHealthCheck</code> request.</p>
     pub health_check: HealthCheck,
@@ -7426,6 +8908,8 @@ impl UpdateHealthCheckResponseDeserializer {
 }
 /// <p>A request to update the comment for a hosted zone.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateHostedZoneCommentRequest {
     /// <p>The new comment for the hosted zone. If you don't specify a value for <code>Comment</code>, Amazon Route 53 deletes the existing value of the <code>Comment</code> element, if any.</p>
     pub comment: Option<String>,
@@ -7433,6 +8917,25 @@ pub struct UpdateHostedZoneCommentRequest {
     pub id: String,
 }
 
+impl Validate for UpdateHostedZoneCommentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(32),
+        );
+    }
+}
+
 pub struct UpdateHostedZoneCommentRequestSerializer;
 impl UpdateHostedZoneCommentRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7454,6 +8957,8 @@ impl UpdateHostedZoneCommentRequestSerializer {
 }
 /// <p>A complex type that contains the response to the <code>UpdateHostedZoneComment</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateHostedZoneCommentResponse {
     /// <p>A complex type that contains the response to the <code>UpdateHostedZoneComment</code> request.</p>
     pub hosted_zone: HostedZone,
@@ -7483,6 +8988,8 @@ impl UpdateHostedZoneCommentResponseDeserializer {
 }
 /// <p>A complex type that contains information about the traffic policy that you want to update the comment for.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTrafficPolicyCommentRequest {
     /// <p>The new comment for the specified traffic policy and version.</p>
     pub comment: String,
@@ -7492,6 +8999,29 @@ pub struct UpdateTrafficPolicyCommentRequest {
     pub version: i64,
 }
 
+impl Validate for UpdateTrafficPolicyCommentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "comment"),
+            self.comment.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        errors.check_range(
+            &field_path(path, "version"),
+            self.version,
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 pub struct UpdateTrafficPolicyCommentRequestSerializer;
 impl UpdateTrafficPolicyCommentRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7511,6 +9041,8 @@ impl UpdateTrafficPolicyCommentRequestSerializer {
 }
 /// <p>A complex type that contains the response information for the traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTrafficPolicyCommentResponse {
     /// <p>A complex type that contains settings for the specified traffic policy.</p>
     pub traffic_policy: TrafficPolicy,
@@ -7541,6 +9073,8 @@ impl UpdateTrafficPolicyCommentResponseDeserializer {
 }
 /// <p>A complex type that contains information about the resource record sets that you want to update based on a specified traffic policy instance.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTrafficPolicyInstanceRequest {
     /// <p>The ID of the traffic policy instance that you want to update.</p>
     pub id: String,
@@ -7552,6 +9086,30 @@ pub struct UpdateTrafficPolicyInstanceRequest {
     pub traffic_policy_version: i64,
 }
 
+impl Validate for UpdateTrafficPolicyInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        errors.check_range(&field_path(path, "ttl"), self.ttl, None, Some(2147483647));
+        errors.check_length(
+            &field_path(path, "traffic_policy_id"),
+            self.traffic_policy_id.chars().count(),
+            Some(1),
+            Some(36),
+        );
+        errors.check_range(
+            &field_path(path, "traffic_policy_version"),
+            self.traffic_policy_version,
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 pub struct UpdateTrafficPolicyInstanceRequestSerializer;
 impl UpdateTrafficPolicyInstanceRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -7581,6 +9139,8 @@ impl UpdateTrafficPolicyInstanceRequestSerializer {
 }
 /// <p>A complex type that contains information about the resource record sets that Amazon Route 53 created based on a specified traffic policy.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTrafficPolicyInstanceResponse {
     /// <p>A complex type that contains settings for the updated traffic policy instance.</p>
     pub traffic_policy_instance: TrafficPolicyInstance,
@@ -7617,7 +9177,7 @@ impl UsageCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7625,12 +9185,35 @@ impl UsageCountDeserializer {
 }
 /// <p>(Private hosted zones only) A complex type that contains information about an Amazon VPC.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VPC {
     pub vpc_id: Option<String>,
     /// <p>(Private hosted zones only) The region that an Amazon VPC was created in.</p>
     pub vpc_region: Option<String>,
 }
 
+impl Validate for VPC {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.vpc_id {
+            errors.check_length(
+                &field_path(path, "vpc_id"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.vpc_region {
+            errors.check_length(
+                &field_path(path, "vpc_region"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 struct VPCDeserializer;
 impl VPCDeserializer {
     #[allow(unused_variables)]
@@ -11843,580 +13426,68 @@ pub trait Route53 {
         input: UpdateTrafficPolicyInstanceRequest,
     ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError>;
 }
-/// A client for the Route 53 API.
-#[derive(Clone)]
-pub struct Route53Client {
-    client: Client,
-    region: region::Region,
-}
-
-impl Route53Client {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> Route53Client {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> Route53Client
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
-        Route53Client { client, region }
-    }
-}
-
-impl Route53 for Route53Client {
-    /// <p><p>Associates an Amazon VPC with a private hosted zone. </p> <important> <p>To perform the association, the VPC and the private hosted zone must already exist. You can&#39;t convert a public hosted zone into a private hosted zone.</p> </important> <note> <p>If you want to associate a VPC that was created by using one AWS account with a private hosted zone that was created by using a different account, the AWS account that created the private hosted zone must first submit a <code>CreateVPCAssociationAuthorization</code> request. Then the account that created the VPC must submit an <code>AssociateVPCWithHostedZone</code> request.</p> </note></p>
-    #[allow(unused_variables, warnings)]
+const _: Option<&dyn Route53> = None;
+impl<'a, T: Route53 + ?Sized> Route53 for &'a T {
     fn associate_vpc_with_hosted_zone(
         &self,
         input: AssociateVPCWithHostedZoneRequest,
     ) -> RusotoFuture<AssociateVPCWithHostedZoneResponse, AssociateVPCWithHostedZoneError> {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/associatevpc",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        AssociateVPCWithHostedZoneRequestSerializer::serialize(
-            &mut writer,
-            "AssociateVPCWithHostedZoneRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateVPCWithHostedZoneError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = AssociateVPCWithHostedZoneResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = AssociateVPCWithHostedZoneResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).associate_vpc_with_hosted_zone(input)
     }
-
-    /// For TXT records, see <a href="./util/fn.quote_txt_record.html">util::quote_txt_record</a>
-    /// <p>Creates, changes, or deletes a resource record set, which contains authoritative DNS information for a specified domain name or subdomain name. For example, you can use <code>ChangeResourceRecordSets</code> to create a resource record set that routes traffic for test.example.com to a web server that has an IP address of 192.0.2.44.</p> <p> <b>Change Batches and Transactional Changes</b> </p> <p>The request body must include a document with a <code>ChangeResourceRecordSetsRequest</code> element. The request body contains a list of change items, known as a change batch. Change batches are considered transactional changes. When using the Amazon Route 53 API to change resource record sets, Route 53 either makes all or none of the changes in a change batch request. This ensures that Route 53 never partially implements the intended changes to the resource record sets in a hosted zone. </p> <p>For example, a change batch request that deletes the <code>CNAME</code> record for www.example.com and creates an alias resource record set for www.example.com. Route 53 deletes the first resource record set and creates the second resource record set in a single operation. If either the <code>DELETE</code> or the <code>CREATE</code> action fails, then both changes (plus any other changes in the batch) fail, and the original <code>CNAME</code> record continues to exist.</p> <important> <p>Due to the nature of transactional changes, you can't delete the same resource record set more than once in a single change batch. If you attempt to delete the same change batch more than once, Route 53 returns an <code>InvalidChangeBatch</code> error.</p> </important> <p> <b>Traffic Flow</b> </p> <p>To create resource record sets for complex routing configurations, use either the traffic flow visual editor in the Route 53 console or the API actions for traffic policies and traffic policy instances. Save the configuration as a traffic policy, then associate the traffic policy with one or more domain names (such as example.com) or subdomain names (such as www.example.com), in the same hosted zone or in multiple hosted zones. You can roll back the updates if the new configuration isn't performing as expected. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/traffic-flow.html">Using Traffic Flow to Route DNS Traffic</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p> <b>Create, Delete, and Upsert</b> </p> <p>Use <code>ChangeResourceRecordsSetsRequest</code> to perform the following actions:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes an existing resource record set that has the specified values.</p> </li> <li> <p> <code>UPSERT</code>: If a resource record set does not already exist, AWS creates it. If a resource set does exist, Route 53 updates it with the values in the request. </p> </li> </ul> <p> <b>Syntaxes for Creating, Updating, and Deleting Resource Record Sets</b> </p> <p>The syntax for a request depends on the type of resource record set that you want to create, delete, or update, such as weighted, alias, or failover. The XML elements in your request must appear in the order listed in the syntax. </p> <p>For an example for each type of resource record set, see "Examples."</p> <p>Don't refer to the syntax in the "Parameter Syntax" section, which includes all of the elements for every kind of resource record set that you can create, delete, or update by using <code>ChangeResourceRecordSets</code>. </p> <p> <b>Change Propagation to Route 53 DNS Servers</b> </p> <p>When you submit a <code>ChangeResourceRecordSets</code> request, Route 53 propagates your changes to all of the Route 53 authoritative DNS servers. While your changes are propagating, <code>GetChange</code> returns a status of <code>PENDING</code>. When propagation is complete, <code>GetChange</code> returns a status of <code>INSYNC</code>. Changes generally propagate to all Route 53 name servers within 60 seconds. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a>.</p> <p> <b>Limits on ChangeResourceRecordSets Requests</b> </p> <p>For information about the limits on a <code>ChangeResourceRecordSets</code> request, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
     fn change_resource_record_sets(
         &self,
         input: ChangeResourceRecordSetsRequest,
     ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/rrset/",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        ChangeResourceRecordSetsRequestSerializer::serialize(
-            &mut writer,
-            "ChangeResourceRecordSetsRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ChangeResourceRecordSetsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ChangeResourceRecordSetsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ChangeResourceRecordSetsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).change_resource_record_sets(input)
     }
-
-    /// <p>Adds, edits, or deletes tags for a health check or a hosted zone.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
     fn change_tags_for_resource(
         &self,
         input: ChangeTagsForResourceRequest,
     ) -> RusotoFuture<ChangeTagsForResourceResponse, ChangeTagsForResourceError> {
-        let request_uri = format!(
-            "/2013-04-01/tags/{resource_type}/{resource_id}",
-            resource_id = input.resource_id,
-            resource_type = input.resource_type
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        ChangeTagsForResourceRequestSerializer::serialize(
-            &mut writer,
-            "ChangeTagsForResourceRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ChangeTagsForResourceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ChangeTagsForResourceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ChangeTagsForResourceResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).change_tags_for_resource(input)
     }
-
-    /// <p><p>Creates a new health check.</p> <p>For information about adding health checks to resource record sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ResourceRecordSet.html#Route53-Type-ResourceRecordSet-HealthCheckId">HealthCheckId</a> in <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>. </p> <p> <b>ELB Load Balancers</b> </p> <p>If you&#39;re registering EC2 instances with an Elastic Load Balancing (ELB) load balancer, do not create Amazon Route 53 health checks for the EC2 instances. When you register an EC2 instance with a load balancer, you configure settings for an ELB health check, which performs a similar function to a Route 53 health check.</p> <p> <b>Private Hosted Zones</b> </p> <p>You can associate health checks with failover resource record sets in a private hosted zone. Note the following:</p> <ul> <li> <p>Route 53 health checkers are outside the VPC. To check the health of an endpoint within a VPC by IP address, you must assign a public IP address to the instance in the VPC.</p> </li> <li> <p>You can configure a health checker to check the health of an external resource that the instance relies on, such as a database server.</p> </li> <li> <p>You can create a CloudWatch metric, associate an alarm with the metric, and then create a health check that is based on the state of the alarm. For example, you might create a CloudWatch metric that checks the status of the Amazon EC2 <code>StatusCheckFailed</code> metric, add an alarm to the metric, and then create a health check that is based on the state of the alarm. For information about creating CloudWatch metrics and alarms by using the CloudWatch console, see the <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/WhatIsCloudWatch.html">Amazon CloudWatch User Guide</a>.</p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
     fn create_health_check(
         &self,
         input: CreateHealthCheckRequest,
     ) -> RusotoFuture<CreateHealthCheckResponse, CreateHealthCheckError> {
-        let request_uri = "/2013-04-01/healthcheck";
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateHealthCheckRequestSerializer::serialize(
-            &mut writer,
-            "CreateHealthCheckRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateHealthCheckError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateHealthCheckResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateHealthCheckResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_health_check(input)
     }
-
-    /// <p>Creates a new public or private hosted zone. You create records in a public hosted zone to define how you want to route traffic on the internet for a domain, such as example.com, and its subdomains (apex.example.com, acme.example.com). You create records in a private hosted zone to define how you want to route traffic for a domain and its subdomains within one or more Amazon Virtual Private Clouds (Amazon VPCs). </p> <important> <p>You can't convert a public hosted zone to a private hosted zone or vice versa. Instead, you must create a new hosted zone with the same name and create new resource record sets.</p> </important> <p>For more information about charges for hosted zones, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> <p>Note the following:</p> <ul> <li> <p>You can't create a hosted zone for a top-level domain (TLD) such as .com.</p> </li> <li> <p>For public hosted zones, Amazon Route 53 automatically creates a default SOA record and four NS records for the zone. For more information about SOA and NS records, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/SOA-NSrecords.html">NS and SOA Records that Route 53 Creates for a Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you want to use the same name servers for multiple public hosted zones, you can optionally associate a reusable delegation set with the hosted zone. See the <code>DelegationSetId</code> element.</p> </li> <li> <p>If your domain is registered with a registrar other than Route 53, you must update the name servers with your registrar to make Route 53 the DNS service for the domain. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/MigratingDNS.html">Migrating DNS Service for an Existing Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>. </p> </li> </ul> <p>When you submit a <code>CreateHostedZone</code> request, the initial status of the hosted zone is <code>PENDING</code>. For public hosted zones, this means that the NS and SOA records are not yet available on all Route 53 DNS servers. When the NS and SOA records are available, the status of the zone changes to <code>INSYNC</code>.</p>
-    #[allow(unused_variables, warnings)]
     fn create_hosted_zone(
         &self,
         input: CreateHostedZoneRequest,
     ) -> RusotoFuture<CreateHostedZoneResponse, CreateHostedZoneError> {
-        let request_uri = "/2013-04-01/hostedzone";
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateHostedZoneRequestSerializer::serialize(
-            &mut writer,
-            "CreateHostedZoneRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateHostedZoneError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateHostedZoneResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateHostedZoneResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_hosted_zone(input)
     }
-
-    /// <p><p>Creates a configuration for DNS query logging. After you create a query logging configuration, Amazon Route 53 begins to publish log data to an Amazon CloudWatch Logs log group.</p> <p>DNS query logs contain information about the queries that Route 53 receives for a specified public hosted zone, such as the following:</p> <ul> <li> <p>Route 53 edge location that responded to the DNS query</p> </li> <li> <p>Domain or subdomain that was requested</p> </li> <li> <p>DNS record type, such as A or AAAA</p> </li> <li> <p>DNS response code, such as <code>NoError</code> or <code>ServFail</code> </p> </li> </ul> <dl> <dt>Log Group and Resource Policy</dt> <dd> <p>Before you create a query logging configuration, perform the following operations.</p> <note> <p>If you create a query logging configuration using the Route 53 console, Route 53 performs these operations automatically.</p> </note> <ol> <li> <p>Create a CloudWatch Logs log group, and make note of the ARN, which you specify when you create a query logging configuration. Note the following:</p> <ul> <li> <p>You must create the log group in the us-east-1 region.</p> </li> <li> <p>You must use the same AWS account to create the log group and the hosted zone that you want to configure query logging for.</p> </li> <li> <p>When you create log groups for query logging, we recommend that you use a consistent prefix, for example:</p> <p> <code>/aws/route53/<i>hosted zone name</i> </code> </p> <p>In the next step, you&#39;ll create a resource policy, which controls access to one or more log groups and the associated AWS resources, such as Route 53 hosted zones. There&#39;s a limit on the number of resource policies that you can create, so we recommend that you use a consistent prefix so you can use the same resource policy for all the log groups that you create for query logging.</p> </li> </ul> </li> <li> <p>Create a CloudWatch Logs resource policy, and give it the permissions that Route 53 needs to create log streams and to send query logs to log streams. For the value of <code>Resource</code>, specify the ARN for the log group that you created in the previous step. To use the same resource policy for all the CloudWatch Logs log groups that you created for query logging configurations, replace the hosted zone name with <code><em></code>, for example:</p> <p> <code>arn:aws:logs:us-east-1:123412341234:log-group:/aws/route53/</em></code> </p> <note> <p>You can&#39;t use the CloudWatch console to create or edit a resource policy. You must use the CloudWatch API, one of the AWS SDKs, or the AWS CLI.</p> </note> </li> </ol> </dd> <dt>Log Streams and Edge Locations</dt> <dd> <p>When Route 53 finishes creating the configuration for DNS query logging, it does the following:</p> <ul> <li> <p>Creates a log stream for an edge location the first time that the edge location responds to DNS queries for the specified hosted zone. That log stream is used to log all queries that Route 53 responds to for that edge location.</p> </li> <li> <p>Begins to send query logs to the applicable log stream.</p> </li> </ul> <p>The name of each log stream is in the following format:</p> <p> <code> <i>hosted zone ID</i>/<i>edge location code</i> </code> </p> <p>The edge location code is a three-letter code and an arbitrarily assigned number, for example, DFW3. The three-letter code typically corresponds with the International Air Transport Association airport code for an airport near the edge location. (These abbreviations might change in the future.) For a list of edge locations, see &quot;The Route 53 Global Network&quot; on the <a href="http://aws.amazon.com/route53/details/">Route 53 Product Details</a> page.</p> </dd> <dt>Queries That Are Logged</dt> <dd> <p>Query logs contain only the queries that DNS resolvers forward to Route 53. If a DNS resolver has already cached the response to a query (such as the IP address for a load balancer for example.com), the resolver will continue to return the cached response. It doesn&#39;t forward another query to Route 53 until the TTL for the corresponding resource record set expires. Depending on how many DNS queries are submitted for a resource record set, and depending on the TTL for that resource record set, query logs might contain information about only one query out of every several thousand queries that are submitted to DNS. For more information about how DNS works, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/welcome-dns-service.html">Routing Internet Traffic to Your Website or Web Application</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Log File Format</dt> <dd> <p>For a list of the values in each query log and the format of each value, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Pricing</dt> <dd> <p>For information about charges for query logs, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </dd> <dt>How to Stop Logging</dt> <dd> <p>If you want Route 53 to stop sending query logs to CloudWatch Logs, delete the query logging configuration. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteQueryLoggingConfig.html">DeleteQueryLoggingConfig</a>.</p> </dd> </dl></p>
-    #[allow(unused_variables, warnings)]
     fn create_query_logging_config(
         &self,
         input: CreateQueryLoggingConfigRequest,
     ) -> RusotoFuture<CreateQueryLoggingConfigResponse, CreateQueryLoggingConfigError> {
-        let request_uri = "/2013-04-01/queryloggingconfig";
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateQueryLoggingConfigRequestSerializer::serialize(
-            &mut writer,
-            "CreateQueryLoggingConfigRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateQueryLoggingConfigError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateQueryLoggingConfigResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateQueryLoggingConfigResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_query_logging_config(input)
     }
-
-    /// <p><p>Creates a delegation set (a group of four name servers) that can be reused by multiple hosted zones. If a hosted zoned ID is specified, <code>CreateReusableDelegationSet</code> marks the delegation set associated with that zone as reusable.</p> <note> <p>You can&#39;t associate a reusable delegation set with a private hosted zone.</p> </note> <p>For information about using a reusable delegation set to configure white label name servers, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/white-label-name-servers.html">Configuring White Label Name Servers</a>.</p> <p>The process for migrating existing hosted zones to use a reusable delegation set is comparable to the process for configuring white label name servers. You need to perform the following steps:</p> <ol> <li> <p>Create a reusable delegation set.</p> </li> <li> <p>Recreate hosted zones, and reduce the TTL to 60 seconds or less.</p> </li> <li> <p>Recreate resource record sets in the new hosted zones.</p> </li> <li> <p>Change the registrar&#39;s name servers to use the name servers for the new hosted zones.</p> </li> <li> <p>Monitor traffic for the website or application.</p> </li> <li> <p>Change TTLs back to their original values.</p> </li> </ol> <p>If you want to migrate existing hosted zones to use a reusable delegation set, the existing hosted zones can&#39;t use any of the name servers that are assigned to the reusable delegation set. If one or more hosted zones do use one or more name servers that are assigned to the reusable delegation set, you can do one of the following:</p> <ul> <li> <p>For small numbers of hosted zones—up to a few hundred—it&#39;s relatively easy to create reusable delegation sets until you get one that has four name servers that don&#39;t overlap with any of the name servers in your hosted zones.</p> </li> <li> <p>For larger numbers of hosted zones, the easiest solution is to use more than one reusable delegation set.</p> </li> <li> <p>For larger numbers of hosted zones, you can also migrate hosted zones that have overlapping name servers to hosted zones that don&#39;t have overlapping name servers, then migrate the hosted zones again to use the reusable delegation set.</p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
     fn create_reusable_delegation_set(
         &self,
         input: CreateReusableDelegationSetRequest,
     ) -> RusotoFuture<CreateReusableDelegationSetResponse, CreateReusableDelegationSetError> {
-        let request_uri = "/2013-04-01/delegationset";
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateReusableDelegationSetRequestSerializer::serialize(
-            &mut writer,
-            "CreateReusableDelegationSetRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateReusableDelegationSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateReusableDelegationSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateReusableDelegationSetResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_reusable_delegation_set(input)
     }
-
-    /// <p>Creates a traffic policy, which you use to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com).</p>
-    #[allow(unused_variables, warnings)]
     fn create_traffic_policy(
         &self,
         input: CreateTrafficPolicyRequest,
     ) -> RusotoFuture<CreateTrafficPolicyResponse, CreateTrafficPolicyError> {
-        let request_uri = "/2013-04-01/trafficpolicy";
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateTrafficPolicyRequestSerializer::serialize(
-            &mut writer,
-            "CreateTrafficPolicyRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateTrafficPolicyError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateTrafficPolicyResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateTrafficPolicyResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_traffic_policy(input)
     }
-
-    /// <p>Creates resource record sets in a specified hosted zone based on the settings in a specified traffic policy version. In addition, <code>CreateTrafficPolicyInstance</code> associates the resource record sets with a specified domain name (such as example.com) or subdomain name (such as www.example.com). Amazon Route 53 responds to DNS queries for the domain or subdomain name by using the resource record sets that <code>CreateTrafficPolicyInstance</code> created.</p>
-    #[allow(unused_variables, warnings)]
     fn create_traffic_policy_instance(
         &self,
         input: CreateTrafficPolicyInstanceRequest,
     ) -> RusotoFuture<CreateTrafficPolicyInstanceResponse, CreateTrafficPolicyInstanceError> {
-        let request_uri = "/2013-04-01/trafficpolicyinstance";
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateTrafficPolicyInstanceRequestSerializer::serialize(
-            &mut writer,
-            "CreateTrafficPolicyInstanceRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateTrafficPolicyInstanceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateTrafficPolicyInstanceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateTrafficPolicyInstanceResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_traffic_policy_instance(input)
     }
-
-    /// <p>Creates a new version of an existing traffic policy. When you create a new version of a traffic policy, you specify the ID of the traffic policy that you want to update and a JSON-formatted document that describes the new version. You use traffic policies to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com). You can create a maximum of 1000 versions of a traffic policy. If you reach the limit and need to create another version, you'll need to start a new traffic policy.</p>
-    #[allow(unused_variables, warnings)]
     fn create_traffic_policy_version(
         &self,
         input: CreateTrafficPolicyVersionRequest,
     ) -> RusotoFuture<CreateTrafficPolicyVersionResponse, CreateTrafficPolicyVersionError> {
-        let request_uri = format!("/2013-04-01/trafficpolicy/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateTrafficPolicyVersionRequestSerializer::serialize(
-            &mut writer,
-            "CreateTrafficPolicyVersionRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateTrafficPolicyVersionError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateTrafficPolicyVersionResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateTrafficPolicyVersionResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                let value = response.headers.get("Location").unwrap().to_owned();
-                result.location = value; // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_traffic_policy_version(input)
     }
-
-    /// <p><p>Authorizes the AWS account that created a specified VPC to submit an <code>AssociateVPCWithHostedZone</code> request to associate the VPC with a specified hosted zone that was created by a different account. To submit a <code>CreateVPCAssociationAuthorization</code> request, you must use the account that created the hosted zone. After you authorize the association, use the account that created the VPC to submit an <code>AssociateVPCWithHostedZone</code> request.</p> <note> <p>If you want to associate multiple VPCs that you created by using one account with a hosted zone that you created by using a different account, you must submit one authorization request for each VPC.</p> </note></p>
-    #[allow(unused_variables, warnings)]
     fn create_vpc_association_authorization(
         &self,
         input: CreateVPCAssociationAuthorizationRequest,
@@ -12424,332 +13495,44 @@ impl Route53 for Route53Client {
         CreateVPCAssociationAuthorizationResponse,
         CreateVPCAssociationAuthorizationError,
     > {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CreateVPCAssociationAuthorizationRequestSerializer::serialize(
-            &mut writer,
-            "CreateVPCAssociationAuthorizationRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateVPCAssociationAuthorizationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateVPCAssociationAuthorizationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateVPCAssociationAuthorizationResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_vpc_association_authorization(input)
     }
-
-    /// <p><p>Deletes a health check.</p> <important> <p>Amazon Route 53 does not prevent you from deleting a health check even if the health check is associated with one or more resource record sets. If you delete a health check and you don&#39;t update the associated resource record sets, the future status of the health check can&#39;t be predicted and may change. This will affect the routing of DNS queries for your DNS failover configuration. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html#health-checks-deleting.html">Replacing and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
-    #[allow(unused_variables, warnings)]
     fn delete_health_check(
         &self,
         input: DeleteHealthCheckRequest,
     ) -> RusotoFuture<DeleteHealthCheckResponse, DeleteHealthCheckError> {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteHealthCheckError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteHealthCheckResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteHealthCheckResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_health_check(input)
     }
-
-    /// <p><p>Deletes a hosted zone.</p> <p>If the hosted zone was created by another service, such as AWS Cloud Map, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DeleteHostedZone.html#delete-public-hosted-zone-created-by-another-service">Deleting Public Hosted Zones That Were Created by Another Service</a> in the <i>Amazon Route 53 Developer Guide</i> for information about how to delete it. (The process is the same for public and private hosted zones that were created by another service.)</p> <p>If you want to keep your domain registration but you want to stop routing internet traffic to your website or web application, we recommend that you delete resource record sets in the hosted zone instead of deleting the hosted zone.</p> <important> <p>If you delete a hosted zone, you can&#39;t undelete it. You must create a new hosted zone and update the name servers for your domain registration, which can require up to 48 hours to take effect. (If you delegated responsibility for a subdomain to a hosted zone and you delete the child hosted zone, you must update the name servers in the parent hosted zone.) In addition, if you delete a hosted zone, someone could hijack the domain and route traffic to their own resources using your domain name.</p> </important> <p>If you want to avoid the monthly charge for the hosted zone, you can transfer DNS service for the domain to a free DNS service. When you transfer DNS service, you have to update the name servers for the domain registration. If the domain is registered with Route 53, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_UpdateDomainNameservers.html">UpdateDomainNameservers</a> for information about how to replace Route 53 name servers with name servers for the new DNS service. If the domain is registered with another registrar, use the method provided by the registrar to update name servers for the domain registration. For more information, perform an internet search on &quot;free DNS service.&quot;</p> <p>You can delete a hosted zone only if it contains only the default SOA record and NS resource record sets. If the hosted zone contains other resource record sets, you must delete them before you can delete the hosted zone. If you try to delete a hosted zone that contains other resource record sets, the request fails, and Route 53 returns a <code>HostedZoneNotEmpty</code> error. For information about deleting records from your hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>.</p> <p>To verify that the hosted zone has been deleted, do one of the following:</p> <ul> <li> <p>Use the <code>GetHostedZone</code> action to request information about the hosted zone.</p> </li> <li> <p>Use the <code>ListHostedZones</code> action to get a list of the hosted zones associated with the current AWS account.</p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
     fn delete_hosted_zone(
         &self,
         input: DeleteHostedZoneRequest,
     ) -> RusotoFuture<DeleteHostedZoneResponse, DeleteHostedZoneError> {
-        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteHostedZoneError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteHostedZoneResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteHostedZoneResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_hosted_zone(input)
     }
-
-    /// <p>Deletes a configuration for DNS query logging. If you delete a configuration, Amazon Route 53 stops sending query logs to CloudWatch Logs. Route 53 doesn't delete any logs that are already in CloudWatch Logs.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_query_logging_config(
         &self,
         input: DeleteQueryLoggingConfigRequest,
     ) -> RusotoFuture<DeleteQueryLoggingConfigResponse, DeleteQueryLoggingConfigError> {
-        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteQueryLoggingConfigError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteQueryLoggingConfigResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteQueryLoggingConfigResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_query_logging_config(input)
     }
-
-    /// <p>Deletes a reusable delegation set.</p> <important> <p>You can delete a reusable delegation set only if it isn't associated with any hosted zones.</p> </important> <p>To verify that the reusable delegation set is not associated with any hosted zones, submit a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSet.html">GetReusableDelegationSet</a> request and specify the ID of the reusable delegation set that you want to delete.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_reusable_delegation_set(
         &self,
         input: DeleteReusableDelegationSetRequest,
     ) -> RusotoFuture<DeleteReusableDelegationSetResponse, DeleteReusableDelegationSetError> {
-        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteReusableDelegationSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteReusableDelegationSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteReusableDelegationSetResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_reusable_delegation_set(input)
     }
-
-    /// <p>Deletes a traffic policy.</p>
-    #[allow(unused_variables, warnings)]
     fn delete_traffic_policy(
         &self,
         input: DeleteTrafficPolicyRequest,
     ) -> RusotoFuture<DeleteTrafficPolicyResponse, DeleteTrafficPolicyError> {
-        let request_uri = format!(
-            "/2013-04-01/trafficpolicy/{id}/{version}",
-            id = input.id,
-            version = input.version
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteTrafficPolicyError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteTrafficPolicyResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteTrafficPolicyResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_traffic_policy(input)
     }
-
-    /// <p><p>Deletes a traffic policy instance and all of the resource record sets that Amazon Route 53 created when you created the instance.</p> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
-    #[allow(unused_variables, warnings)]
     fn delete_traffic_policy_instance(
         &self,
         input: DeleteTrafficPolicyInstanceRequest,
     ) -> RusotoFuture<DeleteTrafficPolicyInstanceResponse, DeleteTrafficPolicyInstanceError> {
-        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteTrafficPolicyInstanceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteTrafficPolicyInstanceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteTrafficPolicyInstanceResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_traffic_policy_instance(input)
     }
-
-    /// <p><p>Removes authorization to submit an <code>AssociateVPCWithHostedZone</code> request to associate a specified VPC with a hosted zone that was created by a different account. You must use the account that created the hosted zone to submit a <code>DeleteVPCAssociationAuthorization</code> request.</p> <important> <p>Sending this request only prevents the AWS account that created the VPC from associating the VPC with the Amazon Route 53 hosted zone in the future. If the VPC is already associated with the hosted zone, <code>DeleteVPCAssociationAuthorization</code> won&#39;t disassociate the VPC from the hosted zone. If you want to delete an existing association, use <code>DisassociateVPCFromHostedZone</code>.</p> </important></p>
-    #[allow(unused_variables, warnings)]
     fn delete_vpc_association_authorization(
         &self,
         input: DeleteVPCAssociationAuthorizationRequest,
@@ -12757,1490 +13540,6499 @@ impl Route53 for Route53Client {
         DeleteVPCAssociationAuthorizationResponse,
         DeleteVPCAssociationAuthorizationError,
     > {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/deauthorizevpcassociation",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
-            &mut writer,
-            "DeleteVPCAssociationAuthorizationRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVPCAssociationAuthorizationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DeleteVPCAssociationAuthorizationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteVPCAssociationAuthorizationResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_vpc_association_authorization(input)
     }
-
-    /// <p><p>Disassociates a VPC from a Amazon Route 53 private hosted zone. Note the following:</p> <ul> <li> <p>You can&#39;t disassociate the last VPC from a private hosted zone.</p> </li> <li> <p>You can&#39;t convert a private hosted zone into a public hosted zone.</p> </li> <li> <p>You can submit a <code>DisassociateVPCFromHostedZone</code> request using either the account that created the hosted zone or the account that created the VPC.</p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
     fn disassociate_vpc_from_hosted_zone(
         &self,
         input: DisassociateVPCFromHostedZoneRequest,
     ) -> RusotoFuture<DisassociateVPCFromHostedZoneResponse, DisassociateVPCFromHostedZoneError>
     {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/disassociatevpc",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        DisassociateVPCFromHostedZoneRequestSerializer::serialize(
-            &mut writer,
-            "DisassociateVPCFromHostedZoneRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateVPCFromHostedZoneError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = DisassociateVPCFromHostedZoneResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DisassociateVPCFromHostedZoneResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).disassociate_vpc_from_hosted_zone(input)
     }
-
-    /// <p><p>Gets the specified limit for the current account, for example, the maximum number of health checks that you can create using the account.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p> <note> <p>You can also view account limits in AWS Trusted Advisor. Sign in to the AWS Management Console and open the Trusted Advisor console at <a href="https://console.aws.amazon.com/trustedadvisor">https://console.aws.amazon.com/trustedadvisor/</a>. Then choose <b>Service limits</b> in the navigation pane.</p> </note></p>
-    #[allow(unused_variables, warnings)]
     fn get_account_limit(
         &self,
         input: GetAccountLimitRequest,
     ) -> RusotoFuture<GetAccountLimitResponse, GetAccountLimitError> {
-        let request_uri = format!("/2013-04-01/accountlimit/{type}", type = input.type_)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAccountLimitError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetAccountLimitResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetAccountLimitResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_account_limit(input)
     }
-
-    /// <p><p>Returns the current status of a change batch request. The status is one of the following values:</p> <ul> <li> <p> <code>PENDING</code> indicates that the changes in this request have not propagated to all Amazon Route 53 DNS servers. This is the initial status of all change batch requests.</p> </li> <li> <p> <code>INSYNC</code> indicates that the changes have propagated to all Route 53 DNS servers. </p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
     fn get_change(
         &self,
         input: GetChangeRequest,
     ) -> RusotoFuture<GetChangeResponse, GetChangeError> {
-        let request_uri = format!("/2013-04-01/change/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetChangeError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetChangeResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        GetChangeResponseDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_change(input)
     }
-
-    /// <p><important> <p> <code>GetCheckerIpRanges</code> still works, but we recommend that you download ip-ranges.json, which includes IP address ranges for all AWS services. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/route-53-ip-addresses.html">IP Address Ranges of Amazon Route 53 Servers</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
-    #[allow(unused_variables, warnings)]
     fn get_checker_ip_ranges(
         &self,
         input: GetCheckerIpRangesRequest,
     ) -> RusotoFuture<GetCheckerIpRangesResponse, GetCheckerIpRangesError> {
-        let request_uri = "/2013-04-01/checkeripranges";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCheckerIpRangesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetCheckerIpRangesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetCheckerIpRangesResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_checker_ip_ranges(input)
     }
-
-    /// <p>Gets information about whether a specified geographic location is supported for Amazon Route 53 geolocation resource record sets.</p> <p>Use the following syntax to determine whether a continent is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?continentcode=<i>two-letter abbreviation for a continent</i> </code> </p> <p>Use the following syntax to determine whether a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i> </code> </p> <p>Use the following syntax to determine whether a subdivision of a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i>&amp;subdivisioncode=<i>subdivision code</i> </code> </p>
-    #[allow(unused_variables, warnings)]
     fn get_geo_location(
         &self,
         input: GetGeoLocationRequest,
     ) -> RusotoFuture<GetGeoLocationResponse, GetGeoLocationError> {
-        let request_uri = "/2013-04-01/geolocation";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.continent_code {
-            params.put("continentcode", x);
-        }
-        if let Some(ref x) = input.country_code {
-            params.put("countrycode", x);
-        }
-        if let Some(ref x) = input.subdivision_code {
-            params.put("subdivisioncode", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGeoLocationError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetGeoLocationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetGeoLocationResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_geo_location(input)
     }
-
-    /// <p>Gets information about a specified health check.</p>
-    #[allow(unused_variables, warnings)]
     fn get_health_check(
         &self,
         input: GetHealthCheckRequest,
     ) -> RusotoFuture<GetHealthCheckResponse, GetHealthCheckError> {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetHealthCheckError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHealthCheckResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHealthCheckResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_health_check(input)
     }
-
-    /// <p>Retrieves the number of health checks that are associated with the current AWS account.</p>
-    #[allow(unused_variables, warnings)]
     fn get_health_check_count(
         &self,
         input: GetHealthCheckCountRequest,
     ) -> RusotoFuture<GetHealthCheckCountResponse, GetHealthCheckCountError> {
-        let request_uri = "/2013-04-01/healthcheckcount";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetHealthCheckCountError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHealthCheckCountResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHealthCheckCountResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_health_check_count(input)
     }
-
-    /// <p>Gets the reason that a specified health check failed most recently.</p>
-    #[allow(unused_variables, warnings)]
     fn get_health_check_last_failure_reason(
         &self,
         input: GetHealthCheckLastFailureReasonRequest,
     ) -> RusotoFuture<GetHealthCheckLastFailureReasonResponse, GetHealthCheckLastFailureReasonError>
     {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}/lastfailurereason",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetHealthCheckLastFailureReasonError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHealthCheckLastFailureReasonResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHealthCheckLastFailureReasonResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_health_check_last_failure_reason(input)
     }
-
-    /// <p>Gets status of a specified health check. </p>
-    #[allow(unused_variables, warnings)]
     fn get_health_check_status(
         &self,
         input: GetHealthCheckStatusRequest,
     ) -> RusotoFuture<GetHealthCheckStatusResponse, GetHealthCheckStatusError> {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}/status",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetHealthCheckStatusError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHealthCheckStatusResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHealthCheckStatusResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_health_check_status(input)
     }
-
-    /// <p>Gets information about a specified hosted zone including the four name servers assigned to the hosted zone.</p>
-    #[allow(unused_variables, warnings)]
     fn get_hosted_zone(
         &self,
         input: GetHostedZoneRequest,
     ) -> RusotoFuture<GetHostedZoneResponse, GetHostedZoneError> {
-        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetHostedZoneError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHostedZoneResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHostedZoneResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_hosted_zone(input)
     }
-
-    /// <p>Retrieves the number of hosted zones that are associated with the current AWS account.</p>
-    #[allow(unused_variables, warnings)]
     fn get_hosted_zone_count(
         &self,
         input: GetHostedZoneCountRequest,
     ) -> RusotoFuture<GetHostedZoneCountResponse, GetHostedZoneCountError> {
-        let request_uri = "/2013-04-01/hostedzonecount";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetHostedZoneCountError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHostedZoneCountResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHostedZoneCountResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_hosted_zone_count(input)
     }
-
-    /// <p>Gets the specified limit for a specified hosted zone, for example, the maximum number of records that you can create in the hosted zone. </p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
-    #[allow(unused_variables, warnings)]
     fn get_hosted_zone_limit(
         &self,
         input: GetHostedZoneLimitRequest,
     ) -> RusotoFuture<GetHostedZoneLimitResponse, GetHostedZoneLimitError> {
-        let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetHostedZoneLimitError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetHostedZoneLimitResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetHostedZoneLimitResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_hosted_zone_limit(input)
     }
-
-    /// <p>Gets information about a specified configuration for DNS query logging.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a> and <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a>.</p>
-    #[allow(unused_variables, warnings)]
     fn get_query_logging_config(
         &self,
         input: GetQueryLoggingConfigRequest,
     ) -> RusotoFuture<GetQueryLoggingConfigResponse, GetQueryLoggingConfigError> {
-        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetQueryLoggingConfigError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetQueryLoggingConfigResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetQueryLoggingConfigResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_query_logging_config(input)
     }
-
-    /// <p>Retrieves information about a specified reusable delegation set, including the four name servers that are assigned to the delegation set.</p>
-    #[allow(unused_variables, warnings)]
     fn get_reusable_delegation_set(
         &self,
         input: GetReusableDelegationSetRequest,
     ) -> RusotoFuture<GetReusableDelegationSetResponse, GetReusableDelegationSetError> {
-        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetReusableDelegationSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetReusableDelegationSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetReusableDelegationSetResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_reusable_delegation_set(input)
     }
-
-    /// <p>Gets the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
-    #[allow(unused_variables, warnings)]
     fn get_reusable_delegation_set_limit(
         &self,
         input: GetReusableDelegationSetLimitRequest,
     ) -> RusotoFuture<GetReusableDelegationSetLimitResponse, GetReusableDelegationSetLimitError>
     {
-        let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetReusableDelegationSetLimitError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetReusableDelegationSetLimitResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetReusableDelegationSetLimitResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_reusable_delegation_set_limit(input)
     }
-
-    /// <p>Gets information about a specific traffic policy version.</p>
-    #[allow(unused_variables, warnings)]
     fn get_traffic_policy(
         &self,
         input: GetTrafficPolicyRequest,
     ) -> RusotoFuture<GetTrafficPolicyResponse, GetTrafficPolicyError> {
-        let request_uri = format!(
-            "/2013-04-01/trafficpolicy/{id}/{version}",
-            id = input.id,
-            version = input.version
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTrafficPolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetTrafficPolicyResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetTrafficPolicyResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_traffic_policy(input)
     }
-
-    /// <p><p>Gets information about a specified traffic policy instance.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there&#39;s a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
-    #[allow(unused_variables, warnings)]
     fn get_traffic_policy_instance(
         &self,
         input: GetTrafficPolicyInstanceRequest,
     ) -> RusotoFuture<GetTrafficPolicyInstanceResponse, GetTrafficPolicyInstanceError> {
-        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetTrafficPolicyInstanceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetTrafficPolicyInstanceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetTrafficPolicyInstanceResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_traffic_policy_instance(input)
     }
-
-    /// <p>Gets the number of traffic policy instances that are associated with the current AWS account.</p>
-    #[allow(unused_variables, warnings)]
     fn get_traffic_policy_instance_count(
         &self,
         input: GetTrafficPolicyInstanceCountRequest,
     ) -> RusotoFuture<GetTrafficPolicyInstanceCountResponse, GetTrafficPolicyInstanceCountError>
     {
-        let request_uri = "/2013-04-01/trafficpolicyinstancecount";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetTrafficPolicyInstanceCountError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetTrafficPolicyInstanceCountResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetTrafficPolicyInstanceCountResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_traffic_policy_instance_count(input)
     }
-
-    /// <p>Retrieves a list of supported geographic locations.</p> <p>Countries are listed first, and continents are listed last. If Amazon Route 53 supports subdivisions for a country (for example, states or provinces), the subdivisions for that country are listed in alphabetical order immediately after the corresponding country.</p>
-    #[allow(unused_variables, warnings)]
     fn list_geo_locations(
         &self,
         input: ListGeoLocationsRequest,
     ) -> RusotoFuture<ListGeoLocationsResponse, ListGeoLocationsError> {
-        let request_uri = "/2013-04-01/geolocations";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.start_continent_code {
-            params.put("startcontinentcode", x);
-        }
-        if let Some(ref x) = input.start_country_code {
-            params.put("startcountrycode", x);
-        }
-        if let Some(ref x) = input.start_subdivision_code {
-            params.put("startsubdivisioncode", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGeoLocationsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListGeoLocationsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListGeoLocationsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_geo_locations(input)
     }
-
-    /// <p>Retrieve a list of the health checks that are associated with the current AWS account. </p>
-    #[allow(unused_variables, warnings)]
     fn list_health_checks(
         &self,
         input: ListHealthChecksRequest,
     ) -> RusotoFuture<ListHealthChecksResponse, ListHealthChecksError> {
-        let request_uri = "/2013-04-01/healthcheck";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListHealthChecksError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListHealthChecksResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListHealthChecksResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_health_checks(input)
     }
-
-    /// <p>Retrieves a list of the public and private hosted zones that are associated with the current AWS account. The response includes a <code>HostedZones</code> child element for each hosted zone.</p> <p>Amazon Route 53 returns a maximum of 100 items in each response. If you have a lot of hosted zones, you can use the <code>maxitems</code> parameter to list them in groups of up to 100.</p>
-    #[allow(unused_variables, warnings)]
     fn list_hosted_zones(
         &self,
-        input: ListHostedZonesRequest,
-    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
-        let request_uri = "/2013-04-01/hostedzone";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.delegation_set_id {
-            params.put("delegationsetid", x);
+        input: ListHostedZonesRequest,
+    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
+        (**self).list_hosted_zones(input)
+    }
+    fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
+        (**self).list_hosted_zones_by_name(input)
+    }
+    fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
+        (**self).list_query_logging_configs(input)
+    }
+    fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> RusotoFuture<ListResourceRecordSetsResponse, ListResourceRecordSetsError> {
+        (**self).list_resource_record_sets(input)
+    }
+    fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> RusotoFuture<ListReusableDelegationSetsResponse, ListReusableDelegationSetsError> {
+        (**self).list_reusable_delegation_sets(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> RusotoFuture<ListTagsForResourcesResponse, ListTagsForResourcesError> {
+        (**self).list_tags_for_resources(input)
+    }
+    fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
+        (**self).list_traffic_policies(input)
+    }
+    fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> RusotoFuture<ListTrafficPolicyInstancesResponse, ListTrafficPolicyInstancesError> {
+        (**self).list_traffic_policy_instances(input)
+    }
+    fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        ListTrafficPolicyInstancesByHostedZoneError,
+    > {
+        (**self).list_traffic_policy_instances_by_hosted_zone(input)
+    }
+    fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        ListTrafficPolicyInstancesByPolicyError,
+    > {
+        (**self).list_traffic_policy_instances_by_policy(input)
+    }
+    fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> RusotoFuture<ListTrafficPolicyVersionsResponse, ListTrafficPolicyVersionsError> {
+        (**self).list_traffic_policy_versions(input)
+    }
+    fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> RusotoFuture<ListVPCAssociationAuthorizationsResponse, ListVPCAssociationAuthorizationsError>
+    {
+        (**self).list_vpc_association_authorizations(input)
+    }
+    fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> RusotoFuture<TestDNSAnswerResponse, TestDNSAnswerError> {
+        (**self).test_dns_answer(input)
+    }
+    fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> RusotoFuture<UpdateHealthCheckResponse, UpdateHealthCheckError> {
+        (**self).update_health_check(input)
+    }
+    fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> RusotoFuture<UpdateHostedZoneCommentResponse, UpdateHostedZoneCommentError> {
+        (**self).update_hosted_zone_comment(input)
+    }
+    fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyCommentResponse, UpdateTrafficPolicyCommentError> {
+        (**self).update_traffic_policy_comment(input)
+    }
+    fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError> {
+        (**self).update_traffic_policy_instance(input)
+    }
+}
+impl<T: Route53 + ?Sized> Route53 for Box<T> {
+    fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> RusotoFuture<AssociateVPCWithHostedZoneResponse, AssociateVPCWithHostedZoneError> {
+        (**self).associate_vpc_with_hosted_zone(input)
+    }
+    fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
+        (**self).change_resource_record_sets(input)
+    }
+    fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> RusotoFuture<ChangeTagsForResourceResponse, ChangeTagsForResourceError> {
+        (**self).change_tags_for_resource(input)
+    }
+    fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> RusotoFuture<CreateHealthCheckResponse, CreateHealthCheckError> {
+        (**self).create_health_check(input)
+    }
+    fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> RusotoFuture<CreateHostedZoneResponse, CreateHostedZoneError> {
+        (**self).create_hosted_zone(input)
+    }
+    fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> RusotoFuture<CreateQueryLoggingConfigResponse, CreateQueryLoggingConfigError> {
+        (**self).create_query_logging_config(input)
+    }
+    fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> RusotoFuture<CreateReusableDelegationSetResponse, CreateReusableDelegationSetError> {
+        (**self).create_reusable_delegation_set(input)
+    }
+    fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyResponse, CreateTrafficPolicyError> {
+        (**self).create_traffic_policy(input)
+    }
+    fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyInstanceResponse, CreateTrafficPolicyInstanceError> {
+        (**self).create_traffic_policy_instance(input)
+    }
+    fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyVersionResponse, CreateTrafficPolicyVersionError> {
+        (**self).create_traffic_policy_version(input)
+    }
+    fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        CreateVPCAssociationAuthorizationResponse,
+        CreateVPCAssociationAuthorizationError,
+    > {
+        (**self).create_vpc_association_authorization(input)
+    }
+    fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> RusotoFuture<DeleteHealthCheckResponse, DeleteHealthCheckError> {
+        (**self).delete_health_check(input)
+    }
+    fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> RusotoFuture<DeleteHostedZoneResponse, DeleteHostedZoneError> {
+        (**self).delete_hosted_zone(input)
+    }
+    fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> RusotoFuture<DeleteQueryLoggingConfigResponse, DeleteQueryLoggingConfigError> {
+        (**self).delete_query_logging_config(input)
+    }
+    fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> RusotoFuture<DeleteReusableDelegationSetResponse, DeleteReusableDelegationSetError> {
+        (**self).delete_reusable_delegation_set(input)
+    }
+    fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyResponse, DeleteTrafficPolicyError> {
+        (**self).delete_traffic_policy(input)
+    }
+    fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyInstanceResponse, DeleteTrafficPolicyInstanceError> {
+        (**self).delete_traffic_policy_instance(input)
+    }
+    fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        DeleteVPCAssociationAuthorizationResponse,
+        DeleteVPCAssociationAuthorizationError,
+    > {
+        (**self).delete_vpc_association_authorization(input)
+    }
+    fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> RusotoFuture<DisassociateVPCFromHostedZoneResponse, DisassociateVPCFromHostedZoneError>
+    {
+        (**self).disassociate_vpc_from_hosted_zone(input)
+    }
+    fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> RusotoFuture<GetAccountLimitResponse, GetAccountLimitError> {
+        (**self).get_account_limit(input)
+    }
+    fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> RusotoFuture<GetChangeResponse, GetChangeError> {
+        (**self).get_change(input)
+    }
+    fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> RusotoFuture<GetCheckerIpRangesResponse, GetCheckerIpRangesError> {
+        (**self).get_checker_ip_ranges(input)
+    }
+    fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> RusotoFuture<GetGeoLocationResponse, GetGeoLocationError> {
+        (**self).get_geo_location(input)
+    }
+    fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> RusotoFuture<GetHealthCheckResponse, GetHealthCheckError> {
+        (**self).get_health_check(input)
+    }
+    fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> RusotoFuture<GetHealthCheckCountResponse, GetHealthCheckCountError> {
+        (**self).get_health_check_count(input)
+    }
+    fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> RusotoFuture<GetHealthCheckLastFailureReasonResponse, GetHealthCheckLastFailureReasonError>
+    {
+        (**self).get_health_check_last_failure_reason(input)
+    }
+    fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> RusotoFuture<GetHealthCheckStatusResponse, GetHealthCheckStatusError> {
+        (**self).get_health_check_status(input)
+    }
+    fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> RusotoFuture<GetHostedZoneResponse, GetHostedZoneError> {
+        (**self).get_hosted_zone(input)
+    }
+    fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> RusotoFuture<GetHostedZoneCountResponse, GetHostedZoneCountError> {
+        (**self).get_hosted_zone_count(input)
+    }
+    fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> RusotoFuture<GetHostedZoneLimitResponse, GetHostedZoneLimitError> {
+        (**self).get_hosted_zone_limit(input)
+    }
+    fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> RusotoFuture<GetQueryLoggingConfigResponse, GetQueryLoggingConfigError> {
+        (**self).get_query_logging_config(input)
+    }
+    fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetResponse, GetReusableDelegationSetError> {
+        (**self).get_reusable_delegation_set(input)
+    }
+    fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetLimitResponse, GetReusableDelegationSetLimitError>
+    {
+        (**self).get_reusable_delegation_set_limit(input)
+    }
+    fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> RusotoFuture<GetTrafficPolicyResponse, GetTrafficPolicyError> {
+        (**self).get_traffic_policy(input)
+    }
+    fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceResponse, GetTrafficPolicyInstanceError> {
+        (**self).get_traffic_policy_instance(input)
+    }
+    fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceCountResponse, GetTrafficPolicyInstanceCountError>
+    {
+        (**self).get_traffic_policy_instance_count(input)
+    }
+    fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> RusotoFuture<ListGeoLocationsResponse, ListGeoLocationsError> {
+        (**self).list_geo_locations(input)
+    }
+    fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> RusotoFuture<ListHealthChecksResponse, ListHealthChecksError> {
+        (**self).list_health_checks(input)
+    }
+    fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
+        (**self).list_hosted_zones(input)
+    }
+    fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
+        (**self).list_hosted_zones_by_name(input)
+    }
+    fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
+        (**self).list_query_logging_configs(input)
+    }
+    fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> RusotoFuture<ListResourceRecordSetsResponse, ListResourceRecordSetsError> {
+        (**self).list_resource_record_sets(input)
+    }
+    fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> RusotoFuture<ListReusableDelegationSetsResponse, ListReusableDelegationSetsError> {
+        (**self).list_reusable_delegation_sets(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> RusotoFuture<ListTagsForResourcesResponse, ListTagsForResourcesError> {
+        (**self).list_tags_for_resources(input)
+    }
+    fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
+        (**self).list_traffic_policies(input)
+    }
+    fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> RusotoFuture<ListTrafficPolicyInstancesResponse, ListTrafficPolicyInstancesError> {
+        (**self).list_traffic_policy_instances(input)
+    }
+    fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        ListTrafficPolicyInstancesByHostedZoneError,
+    > {
+        (**self).list_traffic_policy_instances_by_hosted_zone(input)
+    }
+    fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        ListTrafficPolicyInstancesByPolicyError,
+    > {
+        (**self).list_traffic_policy_instances_by_policy(input)
+    }
+    fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> RusotoFuture<ListTrafficPolicyVersionsResponse, ListTrafficPolicyVersionsError> {
+        (**self).list_traffic_policy_versions(input)
+    }
+    fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> RusotoFuture<ListVPCAssociationAuthorizationsResponse, ListVPCAssociationAuthorizationsError>
+    {
+        (**self).list_vpc_association_authorizations(input)
+    }
+    fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> RusotoFuture<TestDNSAnswerResponse, TestDNSAnswerError> {
+        (**self).test_dns_answer(input)
+    }
+    fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> RusotoFuture<UpdateHealthCheckResponse, UpdateHealthCheckError> {
+        (**self).update_health_check(input)
+    }
+    fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> RusotoFuture<UpdateHostedZoneCommentResponse, UpdateHostedZoneCommentError> {
+        (**self).update_hosted_zone_comment(input)
+    }
+    fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyCommentResponse, UpdateTrafficPolicyCommentError> {
+        (**self).update_traffic_policy_comment(input)
+    }
+    fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError> {
+        (**self).update_traffic_policy_instance(input)
+    }
+}
+impl<T: Route53 + ?Sized> Route53 for ::std::sync::Arc<T> {
+    fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> RusotoFuture<AssociateVPCWithHostedZoneResponse, AssociateVPCWithHostedZoneError> {
+        (**self).associate_vpc_with_hosted_zone(input)
+    }
+    fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
+        (**self).change_resource_record_sets(input)
+    }
+    fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> RusotoFuture<ChangeTagsForResourceResponse, ChangeTagsForResourceError> {
+        (**self).change_tags_for_resource(input)
+    }
+    fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> RusotoFuture<CreateHealthCheckResponse, CreateHealthCheckError> {
+        (**self).create_health_check(input)
+    }
+    fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> RusotoFuture<CreateHostedZoneResponse, CreateHostedZoneError> {
+        (**self).create_hosted_zone(input)
+    }
+    fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> RusotoFuture<CreateQueryLoggingConfigResponse, CreateQueryLoggingConfigError> {
+        (**self).create_query_logging_config(input)
+    }
+    fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> RusotoFuture<CreateReusableDelegationSetResponse, CreateReusableDelegationSetError> {
+        (**self).create_reusable_delegation_set(input)
+    }
+    fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyResponse, CreateTrafficPolicyError> {
+        (**self).create_traffic_policy(input)
+    }
+    fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyInstanceResponse, CreateTrafficPolicyInstanceError> {
+        (**self).create_traffic_policy_instance(input)
+    }
+    fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyVersionResponse, CreateTrafficPolicyVersionError> {
+        (**self).create_traffic_policy_version(input)
+    }
+    fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        CreateVPCAssociationAuthorizationResponse,
+        CreateVPCAssociationAuthorizationError,
+    > {
+        (**self).create_vpc_association_authorization(input)
+    }
+    fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> RusotoFuture<DeleteHealthCheckResponse, DeleteHealthCheckError> {
+        (**self).delete_health_check(input)
+    }
+    fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> RusotoFuture<DeleteHostedZoneResponse, DeleteHostedZoneError> {
+        (**self).delete_hosted_zone(input)
+    }
+    fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> RusotoFuture<DeleteQueryLoggingConfigResponse, DeleteQueryLoggingConfigError> {
+        (**self).delete_query_logging_config(input)
+    }
+    fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> RusotoFuture<DeleteReusableDelegationSetResponse, DeleteReusableDelegationSetError> {
+        (**self).delete_reusable_delegation_set(input)
+    }
+    fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyResponse, DeleteTrafficPolicyError> {
+        (**self).delete_traffic_policy(input)
+    }
+    fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyInstanceResponse, DeleteTrafficPolicyInstanceError> {
+        (**self).delete_traffic_policy_instance(input)
+    }
+    fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        DeleteVPCAssociationAuthorizationResponse,
+        DeleteVPCAssociationAuthorizationError,
+    > {
+        (**self).delete_vpc_association_authorization(input)
+    }
+    fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> RusotoFuture<DisassociateVPCFromHostedZoneResponse, DisassociateVPCFromHostedZoneError>
+    {
+        (**self).disassociate_vpc_from_hosted_zone(input)
+    }
+    fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> RusotoFuture<GetAccountLimitResponse, GetAccountLimitError> {
+        (**self).get_account_limit(input)
+    }
+    fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> RusotoFuture<GetChangeResponse, GetChangeError> {
+        (**self).get_change(input)
+    }
+    fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> RusotoFuture<GetCheckerIpRangesResponse, GetCheckerIpRangesError> {
+        (**self).get_checker_ip_ranges(input)
+    }
+    fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> RusotoFuture<GetGeoLocationResponse, GetGeoLocationError> {
+        (**self).get_geo_location(input)
+    }
+    fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> RusotoFuture<GetHealthCheckResponse, GetHealthCheckError> {
+        (**self).get_health_check(input)
+    }
+    fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> RusotoFuture<GetHealthCheckCountResponse, GetHealthCheckCountError> {
+        (**self).get_health_check_count(input)
+    }
+    fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> RusotoFuture<GetHealthCheckLastFailureReasonResponse, GetHealthCheckLastFailureReasonError>
+    {
+        (**self).get_health_check_last_failure_reason(input)
+    }
+    fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> RusotoFuture<GetHealthCheckStatusResponse, GetHealthCheckStatusError> {
+        (**self).get_health_check_status(input)
+    }
+    fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> RusotoFuture<GetHostedZoneResponse, GetHostedZoneError> {
+        (**self).get_hosted_zone(input)
+    }
+    fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> RusotoFuture<GetHostedZoneCountResponse, GetHostedZoneCountError> {
+        (**self).get_hosted_zone_count(input)
+    }
+    fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> RusotoFuture<GetHostedZoneLimitResponse, GetHostedZoneLimitError> {
+        (**self).get_hosted_zone_limit(input)
+    }
+    fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> RusotoFuture<GetQueryLoggingConfigResponse, GetQueryLoggingConfigError> {
+        (**self).get_query_logging_config(input)
+    }
+    fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetResponse, GetReusableDelegationSetError> {
+        (**self).get_reusable_delegation_set(input)
+    }
+    fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetLimitResponse, GetReusableDelegationSetLimitError>
+    {
+        (**self).get_reusable_delegation_set_limit(input)
+    }
+    fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> RusotoFuture<GetTrafficPolicyResponse, GetTrafficPolicyError> {
+        (**self).get_traffic_policy(input)
+    }
+    fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceResponse, GetTrafficPolicyInstanceError> {
+        (**self).get_traffic_policy_instance(input)
+    }
+    fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceCountResponse, GetTrafficPolicyInstanceCountError>
+    {
+        (**self).get_traffic_policy_instance_count(input)
+    }
+    fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> RusotoFuture<ListGeoLocationsResponse, ListGeoLocationsError> {
+        (**self).list_geo_locations(input)
+    }
+    fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> RusotoFuture<ListHealthChecksResponse, ListHealthChecksError> {
+        (**self).list_health_checks(input)
+    }
+    fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
+        (**self).list_hosted_zones(input)
+    }
+    fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
+        (**self).list_hosted_zones_by_name(input)
+    }
+    fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
+        (**self).list_query_logging_configs(input)
+    }
+    fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> RusotoFuture<ListResourceRecordSetsResponse, ListResourceRecordSetsError> {
+        (**self).list_resource_record_sets(input)
+    }
+    fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> RusotoFuture<ListReusableDelegationSetsResponse, ListReusableDelegationSetsError> {
+        (**self).list_reusable_delegation_sets(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> RusotoFuture<ListTagsForResourcesResponse, ListTagsForResourcesError> {
+        (**self).list_tags_for_resources(input)
+    }
+    fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
+        (**self).list_traffic_policies(input)
+    }
+    fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> RusotoFuture<ListTrafficPolicyInstancesResponse, ListTrafficPolicyInstancesError> {
+        (**self).list_traffic_policy_instances(input)
+    }
+    fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        ListTrafficPolicyInstancesByHostedZoneError,
+    > {
+        (**self).list_traffic_policy_instances_by_hosted_zone(input)
+    }
+    fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        ListTrafficPolicyInstancesByPolicyError,
+    > {
+        (**self).list_traffic_policy_instances_by_policy(input)
+    }
+    fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> RusotoFuture<ListTrafficPolicyVersionsResponse, ListTrafficPolicyVersionsError> {
+        (**self).list_traffic_policy_versions(input)
+    }
+    fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> RusotoFuture<ListVPCAssociationAuthorizationsResponse, ListVPCAssociationAuthorizationsError>
+    {
+        (**self).list_vpc_association_authorizations(input)
+    }
+    fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> RusotoFuture<TestDNSAnswerResponse, TestDNSAnswerError> {
+        (**self).test_dns_answer(input)
+    }
+    fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> RusotoFuture<UpdateHealthCheckResponse, UpdateHealthCheckError> {
+        (**self).update_health_check(input)
+    }
+    fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> RusotoFuture<UpdateHostedZoneCommentResponse, UpdateHostedZoneCommentError> {
+        (**self).update_hosted_zone_comment(input)
+    }
+    fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyCommentResponse, UpdateTrafficPolicyCommentError> {
+        (**self).update_traffic_policy_comment(input)
+    }
+    fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError> {
+        (**self).update_traffic_policy_instance(input)
+    }
+}
+/// A client for the Route 53 API.
+#[derive(Clone)]
+pub struct Route53Client {
+    client: Client,
+    region: region::Region,
+}
+
+impl Route53Client {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Route53Client {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53Client
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
+        Route53Client { client, region }
+    }
+}
+
+impl Route53 for Route53Client {
+    /// <p><p>Associates an Amazon VPC with a private hosted zone. </p> <important> <p>To perform the association, the VPC and the private hosted zone must already exist. You can&#39;t convert a public hosted zone into a private hosted zone.</p> </important> <note> <p>If you want to associate a VPC that was created by using one AWS account with a private hosted zone that was created by using a different account, the AWS account that created the private hosted zone must first submit a <code>CreateVPCAssociationAuthorization</code> request. Then the account that created the VPC must submit an <code>AssociateVPCWithHostedZone</code> request.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> RusotoFuture<AssociateVPCWithHostedZoneResponse, AssociateVPCWithHostedZoneError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/associatevpc",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("AssociateVPCWithHostedZone");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        AssociateVPCWithHostedZoneRequestSerializer::serialize(
+            &mut writer,
+            "AssociateVPCWithHostedZoneRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateVPCWithHostedZoneError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = AssociateVPCWithHostedZoneResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = AssociateVPCWithHostedZoneResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AssociateVPCWithHostedZone", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// For TXT records, see <a href="./util/fn.quote_txt_record.html">util::quote_txt_record</a>
+    /// <p>Creates, changes, or deletes a resource record set, which contains authoritative DNS information for a specified domain name or subdomain name. For example, you can use <code>ChangeResourceRecordSets</code> to create a resource record set that routes traffic for test.example.com to a web server that has an IP address of 192.0.2.44.</p> <p> <b>Change Batches and Transactional Changes</b> </p> <p>The request body must include a document with a <code>ChangeResourceRecordSetsRequest</code> element. The request body contains a list of change items, known as a change batch. Change batches are considered transactional changes. When using the Amazon Route 53 API to change resource record sets, Route 53 either makes all or none of the changes in a change batch request. This ensures that Route 53 never partially implements the intended changes to the resource record sets in a hosted zone. </p> <p>For example, a change batch request that deletes the <code>CNAME</code> record for www.example.com and creates an alias resource record set for www.example.com. Route 53 deletes the first resource record set and creates the second resource record set in a single operation. If either the <code>DELETE</code> or the <code>CREATE</code> action fails, then both changes (plus any other changes in the batch) fail, and the original <code>CNAME</code> record continues to exist.</p> <important> <p>Due to the nature of transactional changes, you can't delete the same resource record set more than once in a single change batch. If you attempt to delete the same change batch more than once, Route 53 returns an <code>InvalidChangeBatch</code> error.</p> </important> <p> <b>Traffic Flow</b> </p> <p>To create resource record sets for complex routing configurations, use either the traffic flow visual editor in the Route 53 console or the API actions for traffic policies and traffic policy instances. Save the configuration as a traffic policy, then associate the traffic policy with one or more domain names (such as example.com) or subdomain names (such as www.example.com), in the same hosted zone or in multiple hosted zones. You can roll back the updates if the new configuration isn't performing as expected. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/traffic-flow.html">Using Traffic Flow to Route DNS Traffic</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p> <b>Create, Delete, and Upsert</b> </p> <p>Use <code>ChangeResourceRecordsSetsRequest</code> to perform the following actions:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes an existing resource record set that has the specified values.</p> </li> <li> <p> <code>UPSERT</code>: If a resource record set does not already exist, AWS creates it. If a resource set does exist, Route 53 updates it with the values in the request. </p> </li> </ul> <p> <b>Syntaxes for Creating, Updating, and Deleting Resource Record Sets</b> </p> <p>The syntax for a request depends on the type of resource record set that you want to create, delete, or update, such as weighted, alias, or failover. The XML elements in your request must appear in the order listed in the syntax. </p> <p>For an example for each type of resource record set, see "Examples."</p> <p>Don't refer to the syntax in the "Parameter Syntax" section, which includes all of the elements for every kind of resource record set that you can create, delete, or update by using <code>ChangeResourceRecordSets</code>. </p> <p> <b>Change Propagation to Route 53 DNS Servers</b> </p> <p>When you submit a <code>ChangeResourceRecordSets</code> request, Route 53 propagates your changes to all of the Route 53 authoritative DNS servers. While your changes are propagating, <code>GetChange</code> returns a status of <code>PENDING</code>. When propagation is complete, <code>GetChange</code> returns a status of <code>INSYNC</code>. Changes generally propagate to all Route 53 name servers within 60 seconds. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a>.</p> <p> <b>Limits on ChangeResourceRecordSets Requests</b> </p> <p>For information about the limits on a <code>ChangeResourceRecordSets</code> request, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/rrset/",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeResourceRecordSets");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        ChangeResourceRecordSetsRequestSerializer::serialize(
+            &mut writer,
+            "ChangeResourceRecordSetsRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ChangeResourceRecordSetsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ChangeResourceRecordSetsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ChangeResourceRecordSetsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ChangeResourceRecordSets", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds, edits, or deletes tags for a health check or a hosted zone.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> RusotoFuture<ChangeTagsForResourceResponse, ChangeTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/tags/{resource_type}/{resource_id}",
+            resource_id = input.resource_id,
+            resource_type = input.resource_type
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeTagsForResource");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.add_resource_id("ResourceType", &input.resource_type.to_string());
+
+        let mut writer = EventWriter::new(Vec::new());
+        ChangeTagsForResourceRequestSerializer::serialize(
+            &mut writer,
+            "ChangeTagsForResourceRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ChangeTagsForResourceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ChangeTagsForResourceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ChangeTagsForResourceResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ChangeTagsForResource", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a new health check.</p> <p>For information about adding health checks to resource record sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ResourceRecordSet.html#Route53-Type-ResourceRecordSet-HealthCheckId">HealthCheckId</a> in <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>. </p> <p> <b>ELB Load Balancers</b> </p> <p>If you&#39;re registering EC2 instances with an Elastic Load Balancing (ELB) load balancer, do not create Amazon Route 53 health checks for the EC2 instances. When you register an EC2 instance with a load balancer, you configure settings for an ELB health check, which performs a similar function to a Route 53 health check.</p> <p> <b>Private Hosted Zones</b> </p> <p>You can associate health checks with failover resource record sets in a private hosted zone. Note the following:</p> <ul> <li> <p>Route 53 health checkers are outside the VPC. To check the health of an endpoint within a VPC by IP address, you must assign a public IP address to the instance in the VPC.</p> </li> <li> <p>You can configure a health checker to check the health of an external resource that the instance relies on, such as a database server.</p> </li> <li> <p>You can create a CloudWatch metric, associate an alarm with the metric, and then create a health check that is based on the state of the alarm. For example, you might create a CloudWatch metric that checks the status of the Amazon EC2 <code>StatusCheckFailed</code> metric, add an alarm to the metric, and then create a health check that is based on the state of the alarm. For information about creating CloudWatch metrics and alarms by using the CloudWatch console, see the <a href="http://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/WhatIsCloudWatch.html">Amazon CloudWatch User Guide</a>.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> RusotoFuture<CreateHealthCheckResponse, CreateHealthCheckError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/healthcheck";
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHealthCheck");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateHealthCheckRequestSerializer::serialize(
+            &mut writer,
+            "CreateHealthCheckRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateHealthCheckError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateHealthCheckResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateHealthCheckResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateHealthCheck", err, &response.body))?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new public or private hosted zone. You create records in a public hosted zone to define how you want to route traffic on the internet for a domain, such as example.com, and its subdomains (apex.example.com, acme.example.com). You create records in a private hosted zone to define how you want to route traffic for a domain and its subdomains within one or more Amazon Virtual Private Clouds (Amazon VPCs). </p> <important> <p>You can't convert a public hosted zone to a private hosted zone or vice versa. Instead, you must create a new hosted zone with the same name and create new resource record sets.</p> </important> <p>For more information about charges for hosted zones, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> <p>Note the following:</p> <ul> <li> <p>You can't create a hosted zone for a top-level domain (TLD) such as .com.</p> </li> <li> <p>For public hosted zones, Amazon Route 53 automatically creates a default SOA record and four NS records for the zone. For more information about SOA and NS records, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/SOA-NSrecords.html">NS and SOA Records that Route 53 Creates for a Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you want to use the same name servers for multiple public hosted zones, you can optionally associate a reusable delegation set with the hosted zone. See the <code>DelegationSetId</code> element.</p> </li> <li> <p>If your domain is registered with a registrar other than Route 53, you must update the name servers with your registrar to make Route 53 the DNS service for the domain. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/MigratingDNS.html">Migrating DNS Service for an Existing Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>. </p> </li> </ul> <p>When you submit a <code>CreateHostedZone</code> request, the initial status of the hosted zone is <code>PENDING</code>. For public hosted zones, this means that the NS and SOA records are not yet available on all Route 53 DNS servers. When the NS and SOA records are available, the status of the zone changes to <code>INSYNC</code>.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> RusotoFuture<CreateHostedZoneResponse, CreateHostedZoneError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/hostedzone";
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHostedZone");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateHostedZoneRequestSerializer::serialize(
+            &mut writer,
+            "CreateHostedZoneRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateHostedZoneError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateHostedZoneResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateHostedZoneResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateHostedZone", err, &response.body))?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a configuration for DNS query logging. After you create a query logging configuration, Amazon Route 53 begins to publish log data to an Amazon CloudWatch Logs log group.</p> <p>DNS query logs contain information about the queries that Route 53 receives for a specified public hosted zone, such as the following:</p> <ul> <li> <p>Route 53 edge location that responded to the DNS query</p> </li> <li> <p>Domain or subdomain that was requested</p> </li> <li> <p>DNS record type, such as A or AAAA</p> </li> <li> <p>DNS response code, such as <code>NoError</code> or <code>ServFail</code> </p> </li> </ul> <dl> <dt>Log Group and Resource Policy</dt> <dd> <p>Before you create a query logging configuration, perform the following operations.</p> <note> <p>If you create a query logging configuration using the Route 53 console, Route 53 performs these operations automatically.</p> </note> <ol> <li> <p>Create a CloudWatch Logs log group, and make note of the ARN, which you specify when you create a query logging configuration. Note the following:</p> <ul> <li> <p>You must create the log group in the us-east-1 region.</p> </li> <li> <p>You must use the same AWS account to create the log group and the hosted zone that you want to configure query logging for.</p> </li> <li> <p>When you create log groups for query logging, we recommend that you use a consistent prefix, for example:</p> <p> <code>/aws/route53/<i>hosted zone name</i> </code> </p> <p>In the next step, you&#39;ll create a resource policy, which controls access to one or more log groups and the associated AWS resources, such as Route 53 hosted zones. There&#39;s a limit on the number of resource policies that you can create, so we recommend that you use a consistent prefix so you can use the same resource policy for all the log groups that you create for query logging.</p> </li> </ul> </li> <li> <p>Create a CloudWatch Logs resource policy, and give it the permissions that Route 53 needs to create log streams and to send query logs to log streams. For the value of <code>Resource</code>, specify the ARN for the log group that you created in the previous step. To use the same resource policy for all the CloudWatch Logs log groups that you created for query logging configurations, replace the hosted zone name with <code><em></code>, for example:</p> <p> <code>arn:aws:logs:us-east-1:123412341234:log-group:/aws/route53/</em></code> </p> <note> <p>You can&#39;t use the CloudWatch console to create or edit a resource policy. You must use the CloudWatch API, one of the AWS SDKs, or the AWS CLI.</p> </note> </li> </ol> </dd> <dt>Log Streams and Edge Locations</dt> <dd> <p>When Route 53 finishes creating the configuration for DNS query logging, it does the following:</p> <ul> <li> <p>Creates a log stream for an edge location the first time that the edge location responds to DNS queries for the specified hosted zone. That log stream is used to log all queries that Route 53 responds to for that edge location.</p> </li> <li> <p>Begins to send query logs to the applicable log stream.</p> </li> </ul> <p>The name of each log stream is in the following format:</p> <p> <code> <i>hosted zone ID</i>/<i>edge location code</i> </code> </p> <p>The edge location code is a three-letter code and an arbitrarily assigned number, for example, DFW3. The three-letter code typically corresponds with the International Air Transport Association airport code for an airport near the edge location. (These abbreviations might change in the future.) For a list of edge locations, see &quot;The Route 53 Global Network&quot; on the <a href="http://aws.amazon.com/route53/details/">Route 53 Product Details</a> page.</p> </dd> <dt>Queries That Are Logged</dt> <dd> <p>Query logs contain only the queries that DNS resolvers forward to Route 53. If a DNS resolver has already cached the response to a query (such as the IP address for a load balancer for example.com), the resolver will continue to return the cached response. It doesn&#39;t forward another query to Route 53 until the TTL for the corresponding resource record set expires. Depending on how many DNS queries are submitted for a resource record set, and depending on the TTL for that resource record set, query logs might contain information about only one query out of every several thousand queries that are submitted to DNS. For more information about how DNS works, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/welcome-dns-service.html">Routing Internet Traffic to Your Website or Web Application</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Log File Format</dt> <dd> <p>For a list of the values in each query log and the format of each value, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Pricing</dt> <dd> <p>For information about charges for query logs, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </dd> <dt>How to Stop Logging</dt> <dd> <p>If you want Route 53 to stop sending query logs to CloudWatch Logs, delete the query logging configuration. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteQueryLoggingConfig.html">DeleteQueryLoggingConfig</a>.</p> </dd> </dl></p>
+    #[allow(unused_variables, warnings)]
+    fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> RusotoFuture<CreateQueryLoggingConfigResponse, CreateQueryLoggingConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/queryloggingconfig";
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateQueryLoggingConfig");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateQueryLoggingConfigRequestSerializer::serialize(
+            &mut writer,
+            "CreateQueryLoggingConfigRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateQueryLoggingConfigError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateQueryLoggingConfigResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateQueryLoggingConfigResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateQueryLoggingConfig", err, &response.body)
+                    })?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Creates a delegation set (a group of four name servers) that can be reused by multiple hosted zones. If a hosted zoned ID is specified, <code>CreateReusableDelegationSet</code> marks the delegation set associated with that zone as reusable.</p> <note> <p>You can&#39;t associate a reusable delegation set with a private hosted zone.</p> </note> <p>For information about using a reusable delegation set to configure white label name servers, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/white-label-name-servers.html">Configuring White Label Name Servers</a>.</p> <p>The process for migrating existing hosted zones to use a reusable delegation set is comparable to the process for configuring white label name servers. You need to perform the following steps:</p> <ol> <li> <p>Create a reusable delegation set.</p> </li> <li> <p>Recreate hosted zones, and reduce the TTL to 60 seconds or less.</p> </li> <li> <p>Recreate resource record sets in the new hosted zones.</p> </li> <li> <p>Change the registrar&#39;s name servers to use the name servers for the new hosted zones.</p> </li> <li> <p>Monitor traffic for the website or application.</p> </li> <li> <p>Change TTLs back to their original values.</p> </li> </ol> <p>If you want to migrate existing hosted zones to use a reusable delegation set, the existing hosted zones can&#39;t use any of the name servers that are assigned to the reusable delegation set. If one or more hosted zones do use one or more name servers that are assigned to the reusable delegation set, you can do one of the following:</p> <ul> <li> <p>For small numbers of hosted zones—up to a few hundred—it&#39;s relatively easy to create reusable delegation sets until you get one that has four name servers that don&#39;t overlap with any of the name servers in your hosted zones.</p> </li> <li> <p>For larger numbers of hosted zones, the easiest solution is to use more than one reusable delegation set.</p> </li> <li> <p>For larger numbers of hosted zones, you can also migrate hosted zones that have overlapping name servers to hosted zones that don&#39;t have overlapping name servers, then migrate the hosted zones again to use the reusable delegation set.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> RusotoFuture<CreateReusableDelegationSetResponse, CreateReusableDelegationSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/delegationset";
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateReusableDelegationSet");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateReusableDelegationSetRequestSerializer::serialize(
+            &mut writer,
+            "CreateReusableDelegationSetRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateReusableDelegationSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateReusableDelegationSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateReusableDelegationSetResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateReusableDelegationSet", err, &response.body)
+                    })?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a traffic policy, which you use to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com).</p>
+    #[allow(unused_variables, warnings)]
+    fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyResponse, CreateTrafficPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/trafficpolicy";
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicy");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateTrafficPolicyRequestSerializer::serialize(
+            &mut writer,
+            "CreateTrafficPolicyRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateTrafficPolicyError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateTrafficPolicyResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateTrafficPolicyResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateTrafficPolicy", err, &response.body))?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates resource record sets in a specified hosted zone based on the settings in a specified traffic policy version. In addition, <code>CreateTrafficPolicyInstance</code> associates the resource record sets with a specified domain name (such as example.com) or subdomain name (such as www.example.com). Amazon Route 53 responds to DNS queries for the domain or subdomain name by using the resource record sets that <code>CreateTrafficPolicyInstance</code> created.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyInstanceResponse, CreateTrafficPolicyInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/trafficpolicyinstance";
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyInstance");
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateTrafficPolicyInstanceRequestSerializer::serialize(
+            &mut writer,
+            "CreateTrafficPolicyInstanceRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateTrafficPolicyInstanceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateTrafficPolicyInstanceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateTrafficPolicyInstanceResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateTrafficPolicyInstance", err, &response.body)
+                    })?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new version of an existing traffic policy. When you create a new version of a traffic policy, you specify the ID of the traffic policy that you want to update and a JSON-formatted document that describes the new version. You use traffic policies to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com). You can create a maximum of 1000 versions of a traffic policy. If you reach the limit and need to create another version, you'll need to start a new traffic policy.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyVersionResponse, CreateTrafficPolicyVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/trafficpolicy/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyVersion");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateTrafficPolicyVersionRequestSerializer::serialize(
+            &mut writer,
+            "CreateTrafficPolicyVersionRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateTrafficPolicyVersionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateTrafficPolicyVersionResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateTrafficPolicyVersionResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateTrafficPolicyVersion", err, &response.body)
+                    })?;
+                }
+                let value = response.headers.get("Location").unwrap().to_owned();
+                result.location = value; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Authorizes the AWS account that created a specified VPC to submit an <code>AssociateVPCWithHostedZone</code> request to associate the VPC with a specified hosted zone that was created by a different account. To submit a <code>CreateVPCAssociationAuthorization</code> request, you must use the account that created the hosted zone. After you authorize the association, use the account that created the VPC to submit an <code>AssociateVPCWithHostedZone</code> request.</p> <note> <p>If you want to associate multiple VPCs that you created by using one account with a hosted zone that you created by using a different account, you must submit one authorization request for each VPC.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        CreateVPCAssociationAuthorizationResponse,
+        CreateVPCAssociationAuthorizationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateVPCAssociationAuthorization");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        CreateVPCAssociationAuthorizationRequestSerializer::serialize(
+            &mut writer,
+            "CreateVPCAssociationAuthorizationRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateVPCAssociationAuthorizationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateVPCAssociationAuthorizationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CreateVPCAssociationAuthorizationResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateVPCAssociationAuthorization", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a health check.</p> <important> <p>Amazon Route 53 does not prevent you from deleting a health check even if the health check is associated with one or more resource record sets. If you delete a health check and you don&#39;t update the associated resource record sets, the future status of the health check can&#39;t be predicted and may change. This will affect the routing of DNS queries for your DNS failover configuration. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html#health-checks-deleting.html">Replacing and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> RusotoFuture<DeleteHealthCheckResponse, DeleteHealthCheckError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHealthCheck");
+        request.add_resource_id("HealthCheckId", &input.health_check_id.to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteHealthCheckError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteHealthCheckResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteHealthCheckResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteHealthCheck", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a hosted zone.</p> <p>If the hosted zone was created by another service, such as AWS Cloud Map, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DeleteHostedZone.html#delete-public-hosted-zone-created-by-another-service">Deleting Public Hosted Zones That Were Created by Another Service</a> in the <i>Amazon Route 53 Developer Guide</i> for information about how to delete it. (The process is the same for public and private hosted zones that were created by another service.)</p> <p>If you want to keep your domain registration but you want to stop routing internet traffic to your website or web application, we recommend that you delete resource record sets in the hosted zone instead of deleting the hosted zone.</p> <important> <p>If you delete a hosted zone, you can&#39;t undelete it. You must create a new hosted zone and update the name servers for your domain registration, which can require up to 48 hours to take effect. (If you delegated responsibility for a subdomain to a hosted zone and you delete the child hosted zone, you must update the name servers in the parent hosted zone.) In addition, if you delete a hosted zone, someone could hijack the domain and route traffic to their own resources using your domain name.</p> </important> <p>If you want to avoid the monthly charge for the hosted zone, you can transfer DNS service for the domain to a free DNS service. When you transfer DNS service, you have to update the name servers for the domain registration. If the domain is registered with Route 53, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_UpdateDomainNameservers.html">UpdateDomainNameservers</a> for information about how to replace Route 53 name servers with name servers for the new DNS service. If the domain is registered with another registrar, use the method provided by the registrar to update name servers for the domain registration. For more information, perform an internet search on &quot;free DNS service.&quot;</p> <p>You can delete a hosted zone only if it contains only the default SOA record and NS resource record sets. If the hosted zone contains other resource record sets, you must delete them before you can delete the hosted zone. If you try to delete a hosted zone that contains other resource record sets, the request fails, and Route 53 returns a <code>HostedZoneNotEmpty</code> error. For information about deleting records from your hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>.</p> <p>To verify that the hosted zone has been deleted, do one of the following:</p> <ul> <li> <p>Use the <code>GetHostedZone</code> action to request information about the hosted zone.</p> </li> <li> <p>Use the <code>ListHostedZones</code> action to get a list of the hosted zones associated with the current AWS account.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> RusotoFuture<DeleteHostedZoneResponse, DeleteHostedZoneError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHostedZone");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteHostedZoneError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteHostedZoneResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteHostedZoneResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteHostedZone", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a configuration for DNS query logging. If you delete a configuration, Amazon Route 53 stops sending query logs to CloudWatch Logs. Route 53 doesn't delete any logs that are already in CloudWatch Logs.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> RusotoFuture<DeleteQueryLoggingConfigResponse, DeleteQueryLoggingConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteQueryLoggingConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteQueryLoggingConfigError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteQueryLoggingConfigResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteQueryLoggingConfigResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteQueryLoggingConfig", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a reusable delegation set.</p> <important> <p>You can delete a reusable delegation set only if it isn't associated with any hosted zones.</p> </important> <p>To verify that the reusable delegation set is not associated with any hosted zones, submit a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSet.html">GetReusableDelegationSet</a> request and specify the ID of the reusable delegation set that you want to delete.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> RusotoFuture<DeleteReusableDelegationSetResponse, DeleteReusableDelegationSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteReusableDelegationSet");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteReusableDelegationSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteReusableDelegationSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteReusableDelegationSetResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteReusableDelegationSet", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a traffic policy.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyResponse, DeleteTrafficPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/trafficpolicy/{id}/{version}",
+            id = input.id,
+            version = input.version
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicy");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.add_resource_id("Version", &input.version.to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteTrafficPolicyError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteTrafficPolicyResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteTrafficPolicyResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteTrafficPolicy", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes a traffic policy instance and all of the resource record sets that Amazon Route 53 created when you created the instance.</p> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyInstanceResponse, DeleteTrafficPolicyInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicyInstance");
+        request.add_resource_id("Id", &input.id.to_string());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteTrafficPolicyInstanceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteTrafficPolicyInstanceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteTrafficPolicyInstanceResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteTrafficPolicyInstance", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Removes authorization to submit an <code>AssociateVPCWithHostedZone</code> request to associate a specified VPC with a hosted zone that was created by a different account. You must use the account that created the hosted zone to submit a <code>DeleteVPCAssociationAuthorization</code> request.</p> <important> <p>Sending this request only prevents the AWS account that created the VPC from associating the VPC with the Amazon Route 53 hosted zone in the future. If the VPC is already associated with the hosted zone, <code>DeleteVPCAssociationAuthorization</code> won&#39;t disassociate the VPC from the hosted zone. If you want to delete an existing association, use <code>DisassociateVPCFromHostedZone</code>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        DeleteVPCAssociationAuthorizationResponse,
+        DeleteVPCAssociationAuthorizationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/deauthorizevpcassociation",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteVPCAssociationAuthorization");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+
+        let mut writer = EventWriter::new(Vec::new());
+        DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
+            &mut writer,
+            "DeleteVPCAssociationAuthorizationRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVPCAssociationAuthorizationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteVPCAssociationAuthorizationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteVPCAssociationAuthorizationResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteVPCAssociationAuthorization", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Disassociates a VPC from a Amazon Route 53 private hosted zone. Note the following:</p> <ul> <li> <p>You can&#39;t disassociate the last VPC from a private hosted zone.</p> </li> <li> <p>You can&#39;t convert a private hosted zone into a public hosted zone.</p> </li> <li> <p>You can submit a <code>DisassociateVPCFromHostedZone</code> request using either the account that created the hosted zone or the account that created the VPC.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> RusotoFuture<DisassociateVPCFromHostedZoneResponse, DisassociateVPCFromHostedZoneError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/disassociatevpc",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DisassociateVPCFromHostedZone");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        DisassociateVPCFromHostedZoneRequestSerializer::serialize(
+            &mut writer,
+            "DisassociateVPCFromHostedZoneRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateVPCFromHostedZoneError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DisassociateVPCFromHostedZoneResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DisassociateVPCFromHostedZoneResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DisassociateVPCFromHostedZone", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Gets the specified limit for the current account, for example, the maximum number of health checks that you can create using the account.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p> <note> <p>You can also view account limits in AWS Trusted Advisor. Sign in to the AWS Management Console and open the Trusted Advisor console at <a href="https://console.aws.amazon.com/trustedadvisor">https://console.aws.amazon.com/trustedadvisor/</a>. Then choose <b>Service limits</b> in the navigation pane.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> RusotoFuture<GetAccountLimitResponse, GetAccountLimitError> {
+        let request_uri = format!("/2013-04-01/accountlimit/{type}", type = input.type_)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetAccountLimit");
+        request.add_resource_id("Type", &input.type_.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAccountLimitError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetAccountLimitResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetAccountLimitResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetAccountLimit", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Returns the current status of a change batch request. The status is one of the following values:</p> <ul> <li> <p> <code>PENDING</code> indicates that the changes in this request have not propagated to all Amazon Route 53 DNS servers. This is the initial status of all change batch requests.</p> </li> <li> <p> <code>INSYNC</code> indicates that the changes have propagated to all Route 53 DNS servers. </p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> RusotoFuture<GetChangeResponse, GetChangeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/change/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetChange");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetChangeError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetChangeResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        GetChangeResponseDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("GetChange", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><important> <p> <code>GetCheckerIpRanges</code> still works, but we recommend that you download ip-ranges.json, which includes IP address ranges for all AWS services. For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/route-53-ip-addresses.html">IP Address Ranges of Amazon Route 53 Servers</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> RusotoFuture<GetCheckerIpRangesResponse, GetCheckerIpRangesError> {
+        let request_uri = "/2013-04-01/checkeripranges";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetCheckerIpRanges");
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCheckerIpRangesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetCheckerIpRangesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetCheckerIpRangesResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetCheckerIpRanges", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about whether a specified geographic location is supported for Amazon Route 53 geolocation resource record sets.</p> <p>Use the following syntax to determine whether a continent is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?continentcode=<i>two-letter abbreviation for a continent</i> </code> </p> <p>Use the following syntax to determine whether a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i> </code> </p> <p>Use the following syntax to determine whether a subdivision of a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i>&amp;subdivisioncode=<i>subdivision code</i> </code> </p>
+    #[allow(unused_variables, warnings)]
+    fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> RusotoFuture<GetGeoLocationResponse, GetGeoLocationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/geolocation";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetGeoLocation");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.continent_code {
+            params.put("continentcode", x);
+        }
+        if let Some(ref x) = input.country_code {
+            params.put("countrycode", x);
+        }
+        if let Some(ref x) = input.subdivision_code {
+            params.put("subdivisioncode", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGeoLocationError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetGeoLocationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetGeoLocationResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetGeoLocation", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about a specified health check.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> RusotoFuture<GetHealthCheckResponse, GetHealthCheckError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheck");
+        request.add_resource_id("HealthCheckId", &input.health_check_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetHealthCheckError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHealthCheckResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHealthCheckResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetHealthCheck", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves the number of health checks that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> RusotoFuture<GetHealthCheckCountResponse, GetHealthCheckCountError> {
+        let request_uri = "/2013-04-01/healthcheckcount";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckCount");
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetHealthCheckCountError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHealthCheckCountResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHealthCheckCountResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetHealthCheckCount", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets the reason that a specified health check failed most recently.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> RusotoFuture<GetHealthCheckLastFailureReasonResponse, GetHealthCheckLastFailureReasonError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}/lastfailurereason",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckLastFailureReason");
+        request.add_resource_id("HealthCheckId", &input.health_check_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetHealthCheckLastFailureReasonError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHealthCheckLastFailureReasonResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHealthCheckLastFailureReasonResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetHealthCheckLastFailureReason", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets status of a specified health check. </p>
+    #[allow(unused_variables, warnings)]
+    fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> RusotoFuture<GetHealthCheckStatusResponse, GetHealthCheckStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}/status",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckStatus");
+        request.add_resource_id("HealthCheckId", &input.health_check_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetHealthCheckStatusError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHealthCheckStatusResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHealthCheckStatusResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetHealthCheckStatus", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about a specified hosted zone including the four name servers assigned to the hosted zone.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> RusotoFuture<GetHostedZoneResponse, GetHostedZoneError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZone");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetHostedZoneError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHostedZoneResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHostedZoneResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetHostedZone", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves the number of hosted zones that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> RusotoFuture<GetHostedZoneCountResponse, GetHostedZoneCountError> {
+        let request_uri = "/2013-04-01/hostedzonecount";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneCount");
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetHostedZoneCountError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHostedZoneCountResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHostedZoneCountResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetHostedZoneCount", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets the specified limit for a specified hosted zone, for example, the maximum number of records that you can create in the hosted zone. </p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> RusotoFuture<GetHostedZoneLimitResponse, GetHostedZoneLimitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneLimit");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.add_resource_id("Type", &input.type_.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetHostedZoneLimitError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetHostedZoneLimitResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetHostedZoneLimitResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetHostedZoneLimit", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about a specified configuration for DNS query logging.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a> and <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a>.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> RusotoFuture<GetQueryLoggingConfigResponse, GetQueryLoggingConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/queryloggingconfig/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetQueryLoggingConfig");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetQueryLoggingConfigError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetQueryLoggingConfigResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetQueryLoggingConfigResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetQueryLoggingConfig", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves information about a specified reusable delegation set, including the four name servers that are assigned to the delegation set.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetResponse, GetReusableDelegationSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/delegationset/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSet");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetReusableDelegationSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetReusableDelegationSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetReusableDelegationSetResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetReusableDelegationSet", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetLimitResponse, GetReusableDelegationSetLimitError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSetLimit");
+        request.add_resource_id("DelegationSetId", &input.delegation_set_id.to_string());
+        request.add_resource_id("Type", &input.type_.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetReusableDelegationSetLimitError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetReusableDelegationSetLimitResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetReusableDelegationSetLimitResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetReusableDelegationSetLimit", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about a specific traffic policy version.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> RusotoFuture<GetTrafficPolicyResponse, GetTrafficPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/trafficpolicy/{id}/{version}",
+            id = input.id,
+            version = input.version
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicy");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.add_resource_id("Version", &input.version.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTrafficPolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetTrafficPolicyResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetTrafficPolicyResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetTrafficPolicy", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Gets information about a specified traffic policy instance.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there&#39;s a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
+    #[allow(unused_variables, warnings)]
+    fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceResponse, GetTrafficPolicyInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstance");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetTrafficPolicyInstanceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetTrafficPolicyInstanceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetTrafficPolicyInstanceResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetTrafficPolicyInstance", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets the number of traffic policy instances that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceCountResponse, GetTrafficPolicyInstanceCountError>
+    {
+        let request_uri = "/2013-04-01/trafficpolicyinstancecount";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstanceCount");
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetTrafficPolicyInstanceCountError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetTrafficPolicyInstanceCountResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetTrafficPolicyInstanceCountResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetTrafficPolicyInstanceCount", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves a list of supported geographic locations.</p> <p>Countries are listed first, and continents are listed last. If Amazon Route 53 supports subdivisions for a country (for example, states or provinces), the subdivisions for that country are listed in alphabetical order immediately after the corresponding country.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> RusotoFuture<ListGeoLocationsResponse, ListGeoLocationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/geolocations";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListGeoLocations");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        if let Some(ref x) = input.start_continent_code {
+            params.put("startcontinentcode", x);
+        }
+        if let Some(ref x) = input.start_country_code {
+            params.put("startcountrycode", x);
+        }
+        if let Some(ref x) = input.start_subdivision_code {
+            params.put("startsubdivisioncode", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGeoLocationsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListGeoLocationsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListGeoLocationsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListGeoLocations", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieve a list of the health checks that are associated with the current AWS account. </p>
+    #[allow(unused_variables, warnings)]
+    fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> RusotoFuture<ListHealthChecksResponse, ListHealthChecksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/healthcheck";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHealthChecks");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListHealthChecksError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListHealthChecksResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListHealthChecksResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListHealthChecks", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves a list of the public and private hosted zones that are associated with the current AWS account. The response includes a <code>HostedZones</code> child element for each hosted zone.</p> <p>Amazon Route 53 returns a maximum of 100 items in each response. If you have a lot of hosted zones, you can use the <code>maxitems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/hostedzone";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZones");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.delegation_set_id {
+            params.put("delegationsetid", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListHostedZonesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListHostedZonesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListHostedZonesResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListHostedZones", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Retrieves a list of your hosted zones in lexicographic order. The response includes a <code>HostedZones</code> child element for each hosted zone created by the current AWS account. </p> <p> <code>ListHostedZonesByName</code> sorts hosted zones by name with the labels reversed. For example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order in some circumstances.</p> <p>If the domain name includes escape characters or Punycode, <code>ListHostedZonesByName</code> alphabetizes the domain name using the escaped or Punycoded value, which is the format that Amazon Route 53 saves in its database. For example, to create a hosted zone for exämple.com, you specify ex\344mple.com for the domain name. <code>ListHostedZonesByName</code> alphabetizes it as:</p> <p> <code>com.ex\344mple.</code> </p> <p>The labels are reversed and alphabetized using the escaped value. For more information about valid domain name formats, including internationalized domain names, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Route 53 returns up to 100 items in each response. If you have a lot of hosted zones, use the <code>MaxItems</code> parameter to list them in groups of up to 100. The response includes values that help navigate from one group of <code>MaxItems</code> hosted zones to the next:</p> <ul> <li> <p>The <code>DNSName</code> and <code>HostedZoneId</code> elements in the response contain the values, if any, specified for the <code>dnsname</code> and <code>hostedzoneid</code> parameters in the request that produced the current response.</p> </li> <li> <p>The <code>MaxItems</code> element in the response contains the value, if any, that you specified for the <code>maxitems</code> parameter in the request that produced the current response.</p> </li> <li> <p>If the value of <code>IsTruncated</code> in the response is true, there are more hosted zones associated with the current AWS account. </p> <p>If <code>IsTruncated</code> is false, this response includes the last hosted zone that is associated with the current account. The <code>NextDNSName</code> element and <code>NextHostedZoneId</code> elements are omitted from the response.</p> </li> <li> <p>The <code>NextDNSName</code> and <code>NextHostedZoneId</code> elements in the response contain the domain name and the hosted zone ID of the next hosted zone that is associated with the current AWS account. If you want to list more hosted zones, make another call to <code>ListHostedZonesByName</code>, and specify the value of <code>NextDNSName</code> and <code>NextHostedZoneId</code> in the <code>dnsname</code> and <code>hostedzoneid</code> parameters, respectively.</p> </li> </ul></p>
+    #[allow(unused_variables, warnings)]
+    fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/hostedzonesbyname";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByName");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.dns_name {
+            params.put("dnsname", x);
+        }
+        if let Some(ref x) = input.hosted_zone_id {
+            params.put("hostedzoneid", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListHostedZonesByNameError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListHostedZonesByNameResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListHostedZonesByNameResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListHostedZonesByName", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the configurations for DNS query logging that are associated with the current AWS account or the configuration that is associated with a specified hosted zone.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>. Additional information, including the format of DNS query logs, appears in <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/queryloggingconfig";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListQueryLoggingConfigs");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.hosted_zone_id {
+            params.put("hostedzoneid", x);
+        }
+        if let Some(ref x) = input.max_results {
+            params.put("maxresults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nexttoken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListQueryLoggingConfigsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListQueryLoggingConfigsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListQueryLoggingConfigsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListQueryLoggingConfigs", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the resource record sets in a specified hosted zone.</p> <p> <code>ListResourceRecordSets</code> returns up to 100 resource record sets at a time in ASCII order, beginning at a position specified by the <code>name</code> and <code>type</code> elements.</p> <p> <b>Sort order</b> </p> <p> <code>ListResourceRecordSets</code> sorts results first by DNS name with the labels reversed, for example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order when the record name contains characters that appear before <code>.</code> (decimal 46) in the ASCII table. These characters include the following: <code>! " # $ % &amp; ' ( ) * + , -</code> </p> <p>When multiple records have the same DNS name, <code>ListResourceRecordSets</code> sorts results by the record type.</p> <p> <b>Specifying where to start listing records</b> </p> <p>You can use the name and type elements to specify the resource record set that the list begins with:</p> <dl> <dt>If you do not specify Name or Type</dt> <dd> <p>The results begin with the first resource record set that the hosted zone contains.</p> </dd> <dt>If you specify Name but not Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>.</p> </dd> <dt>If you specify Type but not Name</dt> <dd> <p>Amazon Route 53 returns the <code>InvalidInput</code> error.</p> </dd> <dt>If you specify both Name and Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>, and whose type is greater than or equal to <code>Type</code>.</p> </dd> </dl> <p> <b>Resource record sets that are PENDING</b> </p> <p>This action returns the most current version of the records. This includes records that are <code>PENDING</code>, and that are not yet available on all Route 53 DNS servers.</p> <p> <b>Changing resource record sets</b> </p> <p>To ensure that you get an accurate listing of the resource record sets for a hosted zone at a point in time, do not submit a <code>ChangeResourceRecordSets</code> request while you're paging through the results of a <code>ListResourceRecordSets</code> request. If you do, some pages may display results without the latest changes while other pages display results with the latest changes.</p> <p> <b>Displaying the next page of results</b> </p> <p>If a <code>ListResourceRecordSets</code> command returns more than one page of results, the value of <code>IsTruncated</code> is <code>true</code>. To display the next page of results, get the values of <code>NextRecordName</code>, <code>NextRecordType</code>, and <code>NextRecordIdentifier</code> (if any) from the response. Then submit another <code>ListResourceRecordSets</code> request, and specify those values for <code>StartRecordName</code>, <code>StartRecordType</code>, and <code>StartRecordIdentifier</code>.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> RusotoFuture<ListResourceRecordSetsResponse, ListResourceRecordSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/rrset",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListResourceRecordSets");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        if let Some(ref x) = input.start_record_identifier {
+            params.put("identifier", x);
+        }
+        if let Some(ref x) = input.start_record_name {
+            params.put("name", x);
+        }
+        if let Some(ref x) = input.start_record_type {
+            params.put("type", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListResourceRecordSetsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListResourceRecordSetsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListResourceRecordSetsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListResourceRecordSets", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves a list of the reusable delegation sets that are associated with the current AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> RusotoFuture<ListReusableDelegationSetsResponse, ListReusableDelegationSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/delegationset";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListReusableDelegationSets");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListReusableDelegationSetsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListReusableDelegationSetsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListReusableDelegationSetsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListReusableDelegationSets", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists tags for one health check or hosted zone. </p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/tags/{resource_type}/{resource_id}",
+            resource_id = input.resource_id,
+            resource_type = input.resource_type
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.add_resource_id("ResourceType", &input.resource_type.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTagsForResourceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTagsForResourceResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists tags for up to 10 health checks or hosted zones.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> RusotoFuture<ListTagsForResourcesResponse, ListTagsForResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/tags/{resource_type}",
+            resource_type = input.resource_type
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResources");
+        request.add_resource_id("ResourceType", &input.resource_type.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        ListTagsForResourcesRequestSerializer::serialize(
+            &mut writer,
+            "ListTagsForResourcesRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourcesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTagsForResourcesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTagsForResourcesResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListTagsForResources", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about the latest version for every traffic policy that is associated with the current AWS account. Policies are listed in the order that they were created in. </p>
+    #[allow(unused_variables, warnings)]
+    fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/trafficpolicies";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicies");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        if let Some(ref x) = input.traffic_policy_id_marker {
+            params.put("trafficpolicyid", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTrafficPoliciesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTrafficPoliciesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTrafficPoliciesResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTrafficPolicies", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created by using the current AWS account.</p> <note> <p>After you submit an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> RusotoFuture<ListTrafficPolicyInstancesResponse, ListTrafficPolicyInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/trafficpolicyinstances";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstances");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.hosted_zone_id_marker {
+            params.put("hostedzoneid", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        if let Some(ref x) = input.traffic_policy_instance_name_marker {
+            params.put("trafficpolicyinstancename", x);
+        }
+        if let Some(ref x) = input.traffic_policy_instance_type_marker {
+            params.put("trafficpolicyinstancetype", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTrafficPolicyInstancesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTrafficPolicyInstancesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTrafficPolicyInstancesResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListTrafficPolicyInstances", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created in a specified hosted zone.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        ListTrafficPolicyInstancesByHostedZoneError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByHostedZone");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        params.put("id", &input.hosted_zone_id);
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        if let Some(ref x) = input.traffic_policy_instance_name_marker {
+            params.put("trafficpolicyinstancename", x);
+        }
+        if let Some(ref x) = input.traffic_policy_instance_type_marker {
+            params.put("trafficpolicyinstancetype", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTrafficPolicyInstancesByHostedZoneError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTrafficPolicyInstancesByHostedZoneResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        ListTrafficPolicyInstancesByHostedZoneResponseDeserializer::deserialize(
+                            &actual_tag_name,
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "ListTrafficPolicyInstancesByHostedZone",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created by using a specify traffic policy version.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> RusotoFuture<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        ListTrafficPolicyInstancesByPolicyError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByPolicy");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.hosted_zone_id_marker {
+            params.put("hostedzoneid", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        params.put("id", &input.traffic_policy_id);
+        if let Some(ref x) = input.traffic_policy_instance_name_marker {
+            params.put("trafficpolicyinstancename", x);
+        }
+        if let Some(ref x) = input.traffic_policy_instance_type_marker {
+            params.put("trafficpolicyinstancetype", x);
+        }
+        params.put("version", &input.traffic_policy_version);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTrafficPolicyInstancesByPolicyError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTrafficPolicyInstancesByPolicyResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTrafficPolicyInstancesByPolicyResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListTrafficPolicyInstancesByPolicy", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets information about all of the versions for a specified traffic policy.</p> <p>Traffic policy versions are listed in numerical order by <code>VersionNumber</code>.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> RusotoFuture<ListTrafficPolicyVersionsResponse, ListTrafficPolicyVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/trafficpolicies/{id}/versions", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyVersions");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_items {
+            params.put("maxitems", x);
+        }
+        if let Some(ref x) = input.traffic_policy_version_marker {
+            params.put("trafficpolicyversion", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTrafficPolicyVersionsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListTrafficPolicyVersionsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListTrafficPolicyVersionsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListTrafficPolicyVersions", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets a list of the VPCs that were created by other accounts and that can be associated with a specified hosted zone because you've submitted one or more <code>CreateVPCAssociationAuthorization</code> requests. </p> <p>The response includes a <code>VPCs</code> element with a <code>VPC</code> child element for each VPC that can be associated with the hosted zone.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> RusotoFuture<ListVPCAssociationAuthorizationsResponse, ListVPCAssociationAuthorizationsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
+            id = input.hosted_zone_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListVPCAssociationAuthorizations");
+        request.add_resource_id("HostedZoneId", &input.hosted_zone_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("maxresults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("nexttoken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListVPCAssociationAuthorizationsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListVPCAssociationAuthorizationsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListVPCAssociationAuthorizationsResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListVPCAssociationAuthorizations", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
+    #[allow(unused_variables, warnings)]
+    fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> RusotoFuture<TestDNSAnswerResponse, TestDNSAnswerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/2013-04-01/testdnsanswer";
+
+        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("TestDNSAnswer");
+        request.set_accept("application/xml".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.edns0_client_subnet_ip {
+            params.put("edns0clientsubnetip", x);
+        }
+        if let Some(ref x) = input.edns0_client_subnet_mask {
+            params.put("edns0clientsubnetmask", x);
+        }
+        params.put("hostedzoneid", &input.hosted_zone_id);
+        params.put("recordname", &input.record_name);
+        params.put("recordtype", &input.record_type);
+        if let Some(ref x) = input.resolver_ip {
+            params.put("resolverip", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestDNSAnswerError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = TestDNSAnswerResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = TestDNSAnswerResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("TestDNSAnswer", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates an existing health check. Note that some values can't be updated. </p> <p>For more information about updating health checks, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html">Creating, Updating, and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> RusotoFuture<UpdateHealthCheckResponse, UpdateHealthCheckError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/healthcheck/{health_check_id}",
+            health_check_id = input.health_check_id
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHealthCheck");
+        request.add_resource_id("HealthCheckId", &input.health_check_id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        UpdateHealthCheckRequestSerializer::serialize(
+            &mut writer,
+            "UpdateHealthCheckRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateHealthCheckError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateHealthCheckResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateHealthCheckResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateHealthCheck", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates the comment for a specified hosted zone.</p>
+    #[allow(unused_variables, warnings)]
+    fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> RusotoFuture<UpdateHostedZoneCommentResponse, UpdateHostedZoneCommentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHostedZoneComment");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        UpdateHostedZoneCommentRequestSerializer::serialize(
+            &mut writer,
+            "UpdateHostedZoneCommentRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateHostedZoneCommentError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateHostedZoneCommentResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateHostedZoneCommentResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateHostedZoneComment", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates the comment for a specified traffic policy version.</p>
+    #[allow(unused_variables, warnings)]
+    fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyCommentResponse, UpdateTrafficPolicyCommentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/2013-04-01/trafficpolicy/{id}/{version}",
+            id = input.id,
+            version = input.version
+        )
+        .replace("/hostedzone/hostedzone/", "/hostedzone/")
+        .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyComment");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.add_resource_id("Version", &input.version.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        UpdateTrafficPolicyCommentRequestSerializer::serialize(
+            &mut writer,
+            "UpdateTrafficPolicyCommentRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateTrafficPolicyCommentError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateTrafficPolicyCommentResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateTrafficPolicyCommentResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateTrafficPolicyComment", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Updates the resource record sets in a specified hosted zone that were created based on the settings in a specified traffic policy version.</p> <p>When you update a traffic policy instance, Amazon Route 53 continues to respond to DNS queries for the root resource record set name (such as example.com) while it replaces one group of resource record sets with another. Route 53 performs the following operations:</p> <ol> <li> <p>Route 53 creates a new group of resource record sets based on the specified traffic policy. This is true regardless of how significant the differences are between the existing resource record sets and the new resource record sets. </p> </li> <li> <p>When all of the new resource record sets have been created, Route 53 starts to respond to DNS queries for the root resource record set name (such as example.com) by using the new resource record sets.</p> </li> <li> <p>Route 53 deletes the old group of resource record sets that are associated with the root resource record set name.</p> </li> </ol></p>
+    #[allow(unused_variables, warnings)]
+    fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
+            .replace("/hostedzone/hostedzone/", "/hostedzone/")
+            .replace("/change/change/", "/change/");
+
+        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyInstance");
+        request.add_resource_id("Id", &input.id.to_string());
+        request.set_accept("application/xml".to_owned());
+
+        let mut writer = EventWriter::new(Vec::new());
+        UpdateTrafficPolicyInstanceRequestSerializer::serialize(
+            &mut writer,
+            "UpdateTrafficPolicyInstanceRequest",
+            &input,
+            "https://route53.amazonaws.com/doc/2013-04-01/",
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateTrafficPolicyInstanceError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UpdateTrafficPolicyInstanceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = UpdateTrafficPolicyInstanceResponseDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("UpdateTrafficPolicyInstance", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+}
+
+/// A mock of the Route 53 API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockRoute53 {
+    associate_vpc_with_hosted_zone: Option<
+        Box<
+            dyn Fn(
+                    AssociateVPCWithHostedZoneRequest,
+                ) -> ::std::result::Result<
+                    AssociateVPCWithHostedZoneResponse,
+                    RusotoError<AssociateVPCWithHostedZoneError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    change_resource_record_sets: Option<
+        Box<
+            dyn Fn(
+                    ChangeResourceRecordSetsRequest,
+                ) -> ::std::result::Result<
+                    ChangeResourceRecordSetsResponse,
+                    RusotoError<ChangeResourceRecordSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    change_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ChangeTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ChangeTagsForResourceResponse,
+                    RusotoError<ChangeTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_health_check: Option<
+        Box<
+            dyn Fn(
+                    CreateHealthCheckRequest,
+                ) -> ::std::result::Result<
+                    CreateHealthCheckResponse,
+                    RusotoError<CreateHealthCheckError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_hosted_zone: Option<
+        Box<
+            dyn Fn(
+                    CreateHostedZoneRequest,
+                ) -> ::std::result::Result<
+                    CreateHostedZoneResponse,
+                    RusotoError<CreateHostedZoneError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_query_logging_config: Option<
+        Box<
+            dyn Fn(
+                    CreateQueryLoggingConfigRequest,
+                ) -> ::std::result::Result<
+                    CreateQueryLoggingConfigResponse,
+                    RusotoError<CreateQueryLoggingConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_reusable_delegation_set: Option<
+        Box<
+            dyn Fn(
+                    CreateReusableDelegationSetRequest,
+                ) -> ::std::result::Result<
+                    CreateReusableDelegationSetResponse,
+                    RusotoError<CreateReusableDelegationSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_traffic_policy: Option<
+        Box<
+            dyn Fn(
+                    CreateTrafficPolicyRequest,
+                ) -> ::std::result::Result<
+                    CreateTrafficPolicyResponse,
+                    RusotoError<CreateTrafficPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_traffic_policy_instance: Option<
+        Box<
+            dyn Fn(
+                    CreateTrafficPolicyInstanceRequest,
+                ) -> ::std::result::Result<
+                    CreateTrafficPolicyInstanceResponse,
+                    RusotoError<CreateTrafficPolicyInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_traffic_policy_version: Option<
+        Box<
+            dyn Fn(
+                    CreateTrafficPolicyVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateTrafficPolicyVersionResponse,
+                    RusotoError<CreateTrafficPolicyVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_vpc_association_authorization: Option<
+        Box<
+            dyn Fn(
+                    CreateVPCAssociationAuthorizationRequest,
+                ) -> ::std::result::Result<
+                    CreateVPCAssociationAuthorizationResponse,
+                    RusotoError<CreateVPCAssociationAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_health_check: Option<
+        Box<
+            dyn Fn(
+                    DeleteHealthCheckRequest,
+                ) -> ::std::result::Result<
+                    DeleteHealthCheckResponse,
+                    RusotoError<DeleteHealthCheckError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_hosted_zone: Option<
+        Box<
+            dyn Fn(
+                    DeleteHostedZoneRequest,
+                ) -> ::std::result::Result<
+                    DeleteHostedZoneResponse,
+                    RusotoError<DeleteHostedZoneError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_query_logging_config: Option<
+        Box<
+            dyn Fn(
+                    DeleteQueryLoggingConfigRequest,
+                ) -> ::std::result::Result<
+                    DeleteQueryLoggingConfigResponse,
+                    RusotoError<DeleteQueryLoggingConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_reusable_delegation_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteReusableDelegationSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteReusableDelegationSetResponse,
+                    RusotoError<DeleteReusableDelegationSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_traffic_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteTrafficPolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteTrafficPolicyResponse,
+                    RusotoError<DeleteTrafficPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_traffic_policy_instance: Option<
+        Box<
+            dyn Fn(
+                    DeleteTrafficPolicyInstanceRequest,
+                ) -> ::std::result::Result<
+                    DeleteTrafficPolicyInstanceResponse,
+                    RusotoError<DeleteTrafficPolicyInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_vpc_association_authorization: Option<
+        Box<
+            dyn Fn(
+                    DeleteVPCAssociationAuthorizationRequest,
+                ) -> ::std::result::Result<
+                    DeleteVPCAssociationAuthorizationResponse,
+                    RusotoError<DeleteVPCAssociationAuthorizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_vpc_from_hosted_zone: Option<
+        Box<
+            dyn Fn(
+                    DisassociateVPCFromHostedZoneRequest,
+                ) -> ::std::result::Result<
+                    DisassociateVPCFromHostedZoneResponse,
+                    RusotoError<DisassociateVPCFromHostedZoneError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_account_limit: Option<
+        Box<
+            dyn Fn(
+                    GetAccountLimitRequest,
+                ) -> ::std::result::Result<
+                    GetAccountLimitResponse,
+                    RusotoError<GetAccountLimitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_change: Option<
+        Box<
+            dyn Fn(
+                    GetChangeRequest,
+                )
+                    -> ::std::result::Result<GetChangeResponse, RusotoError<GetChangeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_checker_ip_ranges: Option<
+        Box<
+            dyn Fn(
+                    GetCheckerIpRangesRequest,
+                ) -> ::std::result::Result<
+                    GetCheckerIpRangesResponse,
+                    RusotoError<GetCheckerIpRangesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_geo_location: Option<
+        Box<
+            dyn Fn(
+                    GetGeoLocationRequest,
+                ) -> ::std::result::Result<
+                    GetGeoLocationResponse,
+                    RusotoError<GetGeoLocationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_health_check: Option<
+        Box<
+            dyn Fn(
+                    GetHealthCheckRequest,
+                ) -> ::std::result::Result<
+                    GetHealthCheckResponse,
+                    RusotoError<GetHealthCheckError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_health_check_count: Option<
+        Box<
+            dyn Fn(
+                    GetHealthCheckCountRequest,
+                ) -> ::std::result::Result<
+                    GetHealthCheckCountResponse,
+                    RusotoError<GetHealthCheckCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_health_check_last_failure_reason: Option<
+        Box<
+            dyn Fn(
+                    GetHealthCheckLastFailureReasonRequest,
+                ) -> ::std::result::Result<
+                    GetHealthCheckLastFailureReasonResponse,
+                    RusotoError<GetHealthCheckLastFailureReasonError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_health_check_status: Option<
+        Box<
+            dyn Fn(
+                    GetHealthCheckStatusRequest,
+                ) -> ::std::result::Result<
+                    GetHealthCheckStatusResponse,
+                    RusotoError<GetHealthCheckStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_hosted_zone: Option<
+        Box<
+            dyn Fn(
+                    GetHostedZoneRequest,
+                )
+                    -> ::std::result::Result<GetHostedZoneResponse, RusotoError<GetHostedZoneError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_hosted_zone_count: Option<
+        Box<
+            dyn Fn(
+                    GetHostedZoneCountRequest,
+                ) -> ::std::result::Result<
+                    GetHostedZoneCountResponse,
+                    RusotoError<GetHostedZoneCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_hosted_zone_limit: Option<
+        Box<
+            dyn Fn(
+                    GetHostedZoneLimitRequest,
+                ) -> ::std::result::Result<
+                    GetHostedZoneLimitResponse,
+                    RusotoError<GetHostedZoneLimitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_query_logging_config: Option<
+        Box<
+            dyn Fn(
+                    GetQueryLoggingConfigRequest,
+                ) -> ::std::result::Result<
+                    GetQueryLoggingConfigResponse,
+                    RusotoError<GetQueryLoggingConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_reusable_delegation_set: Option<
+        Box<
+            dyn Fn(
+                    GetReusableDelegationSetRequest,
+                ) -> ::std::result::Result<
+                    GetReusableDelegationSetResponse,
+                    RusotoError<GetReusableDelegationSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_reusable_delegation_set_limit: Option<
+        Box<
+            dyn Fn(
+                    GetReusableDelegationSetLimitRequest,
+                ) -> ::std::result::Result<
+                    GetReusableDelegationSetLimitResponse,
+                    RusotoError<GetReusableDelegationSetLimitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_traffic_policy: Option<
+        Box<
+            dyn Fn(
+                    GetTrafficPolicyRequest,
+                ) -> ::std::result::Result<
+                    GetTrafficPolicyResponse,
+                    RusotoError<GetTrafficPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_traffic_policy_instance: Option<
+        Box<
+            dyn Fn(
+                    GetTrafficPolicyInstanceRequest,
+                ) -> ::std::result::Result<
+                    GetTrafficPolicyInstanceResponse,
+                    RusotoError<GetTrafficPolicyInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_traffic_policy_instance_count: Option<
+        Box<
+            dyn Fn(
+                    GetTrafficPolicyInstanceCountRequest,
+                ) -> ::std::result::Result<
+                    GetTrafficPolicyInstanceCountResponse,
+                    RusotoError<GetTrafficPolicyInstanceCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_geo_locations: Option<
+        Box<
+            dyn Fn(
+                    ListGeoLocationsRequest,
+                ) -> ::std::result::Result<
+                    ListGeoLocationsResponse,
+                    RusotoError<ListGeoLocationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_health_checks: Option<
+        Box<
+            dyn Fn(
+                    ListHealthChecksRequest,
+                ) -> ::std::result::Result<
+                    ListHealthChecksResponse,
+                    RusotoError<ListHealthChecksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_hosted_zones: Option<
+        Box<
+            dyn Fn(
+                    ListHostedZonesRequest,
+                ) -> ::std::result::Result<
+                    ListHostedZonesResponse,
+                    RusotoError<ListHostedZonesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_hosted_zones_by_name: Option<
+        Box<
+            dyn Fn(
+                    ListHostedZonesByNameRequest,
+                ) -> ::std::result::Result<
+                    ListHostedZonesByNameResponse,
+                    RusotoError<ListHostedZonesByNameError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_query_logging_configs: Option<
+        Box<
+            dyn Fn(
+                    ListQueryLoggingConfigsRequest,
+                ) -> ::std::result::Result<
+                    ListQueryLoggingConfigsResponse,
+                    RusotoError<ListQueryLoggingConfigsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_record_sets: Option<
+        Box<
+            dyn Fn(
+                    ListResourceRecordSetsRequest,
+                ) -> ::std::result::Result<
+                    ListResourceRecordSetsResponse,
+                    RusotoError<ListResourceRecordSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_reusable_delegation_sets: Option<
+        Box<
+            dyn Fn(
+                    ListReusableDelegationSetsRequest,
+                ) -> ::std::result::Result<
+                    ListReusableDelegationSetsResponse,
+                    RusotoError<ListReusableDelegationSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resources: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourcesRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourcesResponse,
+                    RusotoError<ListTagsForResourcesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_traffic_policies: Option<
+        Box<
+            dyn Fn(
+                    ListTrafficPoliciesRequest,
+                ) -> ::std::result::Result<
+                    ListTrafficPoliciesResponse,
+                    RusotoError<ListTrafficPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_traffic_policy_instances: Option<
+        Box<
+            dyn Fn(
+                    ListTrafficPolicyInstancesRequest,
+                ) -> ::std::result::Result<
+                    ListTrafficPolicyInstancesResponse,
+                    RusotoError<ListTrafficPolicyInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_traffic_policy_instances_by_hosted_zone: Option<
+        Box<
+            dyn Fn(
+                    ListTrafficPolicyInstancesByHostedZoneRequest,
+                ) -> ::std::result::Result<
+                    ListTrafficPolicyInstancesByHostedZoneResponse,
+                    RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_traffic_policy_instances_by_policy: Option<
+        Box<
+            dyn Fn(
+                    ListTrafficPolicyInstancesByPolicyRequest,
+                ) -> ::std::result::Result<
+                    ListTrafficPolicyInstancesByPolicyResponse,
+                    RusotoError<ListTrafficPolicyInstancesByPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_traffic_policy_versions: Option<
+        Box<
+            dyn Fn(
+                    ListTrafficPolicyVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListTrafficPolicyVersionsResponse,
+                    RusotoError<ListTrafficPolicyVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_vpc_association_authorizations: Option<
+        Box<
+            dyn Fn(
+                    ListVPCAssociationAuthorizationsRequest,
+                ) -> ::std::result::Result<
+                    ListVPCAssociationAuthorizationsResponse,
+                    RusotoError<ListVPCAssociationAuthorizationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    test_dns_answer: Option<
+        Box<
+            dyn Fn(
+                    TestDNSAnswerRequest,
+                )
+                    -> ::std::result::Result<TestDNSAnswerResponse, RusotoError<TestDNSAnswerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_health_check: Option<
+        Box<
+            dyn Fn(
+                    UpdateHealthCheckRequest,
+                ) -> ::std::result::Result<
+                    UpdateHealthCheckResponse,
+                    RusotoError<UpdateHealthCheckError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_hosted_zone_comment: Option<
+        Box<
+            dyn Fn(
+                    UpdateHostedZoneCommentRequest,
+                ) -> ::std::result::Result<
+                    UpdateHostedZoneCommentResponse,
+                    RusotoError<UpdateHostedZoneCommentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_traffic_policy_comment: Option<
+        Box<
+            dyn Fn(
+                    UpdateTrafficPolicyCommentRequest,
+                ) -> ::std::result::Result<
+                    UpdateTrafficPolicyCommentResponse,
+                    RusotoError<UpdateTrafficPolicyCommentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_traffic_policy_instance: Option<
+        Box<
+            dyn Fn(
+                    UpdateTrafficPolicyInstanceRequest,
+                ) -> ::std::result::Result<
+                    UpdateTrafficPolicyInstanceResponse,
+                    RusotoError<UpdateTrafficPolicyInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockRoute53 {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockRoute53 {
+        Default::default()
+    }
+
+    /// Answers `associate_vpc_with_hosted_zone` with what `handler` returns for its input.
+    pub fn with_associate_vpc_with_hosted_zone<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                AssociateVPCWithHostedZoneRequest,
+            ) -> ::std::result::Result<
+                AssociateVPCWithHostedZoneResponse,
+                RusotoError<AssociateVPCWithHostedZoneError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_vpc_with_hosted_zone = Some(Box::new(handler));
+        self
+    }
+    /// Answers `change_resource_record_sets` with what `handler` returns for its input.
+    pub fn with_change_resource_record_sets<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ChangeResourceRecordSetsRequest,
+            ) -> ::std::result::Result<
+                ChangeResourceRecordSetsResponse,
+                RusotoError<ChangeResourceRecordSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.change_resource_record_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `change_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_change_tags_for_resource<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ChangeTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ChangeTagsForResourceResponse,
+                RusotoError<ChangeTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.change_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_health_check` with what `handler` returns for its input.
+    pub fn with_create_health_check<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateHealthCheckRequest,
+            ) -> ::std::result::Result<
+                CreateHealthCheckResponse,
+                RusotoError<CreateHealthCheckError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_health_check = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hosted_zone` with what `handler` returns for its input.
+    pub fn with_create_hosted_zone<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateHostedZoneRequest,
+            ) -> ::std::result::Result<
+                CreateHostedZoneResponse,
+                RusotoError<CreateHostedZoneError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hosted_zone = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_query_logging_config` with what `handler` returns for its input.
+    pub fn with_create_query_logging_config<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateQueryLoggingConfigRequest,
+            ) -> ::std::result::Result<
+                CreateQueryLoggingConfigResponse,
+                RusotoError<CreateQueryLoggingConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_query_logging_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_reusable_delegation_set` with what `handler` returns for its input.
+    pub fn with_create_reusable_delegation_set<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateReusableDelegationSetRequest,
+            ) -> ::std::result::Result<
+                CreateReusableDelegationSetResponse,
+                RusotoError<CreateReusableDelegationSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_reusable_delegation_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_traffic_policy` with what `handler` returns for its input.
+    pub fn with_create_traffic_policy<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateTrafficPolicyRequest,
+            ) -> ::std::result::Result<
+                CreateTrafficPolicyResponse,
+                RusotoError<CreateTrafficPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_traffic_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_traffic_policy_instance` with what `handler` returns for its input.
+    pub fn with_create_traffic_policy_instance<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateTrafficPolicyInstanceRequest,
+            ) -> ::std::result::Result<
+                CreateTrafficPolicyInstanceResponse,
+                RusotoError<CreateTrafficPolicyInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_traffic_policy_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_traffic_policy_version` with what `handler` returns for its input.
+    pub fn with_create_traffic_policy_version<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateTrafficPolicyVersionRequest,
+            ) -> ::std::result::Result<
+                CreateTrafficPolicyVersionResponse,
+                RusotoError<CreateTrafficPolicyVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_traffic_policy_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_vpc_association_authorization` with what `handler` returns for its input.
+    pub fn with_create_vpc_association_authorization<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                CreateVPCAssociationAuthorizationRequest,
+            ) -> ::std::result::Result<
+                CreateVPCAssociationAuthorizationResponse,
+                RusotoError<CreateVPCAssociationAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vpc_association_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_health_check` with what `handler` returns for its input.
+    pub fn with_delete_health_check<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteHealthCheckRequest,
+            ) -> ::std::result::Result<
+                DeleteHealthCheckResponse,
+                RusotoError<DeleteHealthCheckError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_health_check = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_hosted_zone` with what `handler` returns for its input.
+    pub fn with_delete_hosted_zone<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteHostedZoneRequest,
+            ) -> ::std::result::Result<
+                DeleteHostedZoneResponse,
+                RusotoError<DeleteHostedZoneError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_hosted_zone = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_query_logging_config` with what `handler` returns for its input.
+    pub fn with_delete_query_logging_config<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteQueryLoggingConfigRequest,
+            ) -> ::std::result::Result<
+                DeleteQueryLoggingConfigResponse,
+                RusotoError<DeleteQueryLoggingConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_query_logging_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_reusable_delegation_set` with what `handler` returns for its input.
+    pub fn with_delete_reusable_delegation_set<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteReusableDelegationSetRequest,
+            ) -> ::std::result::Result<
+                DeleteReusableDelegationSetResponse,
+                RusotoError<DeleteReusableDelegationSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_reusable_delegation_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_traffic_policy` with what `handler` returns for its input.
+    pub fn with_delete_traffic_policy<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteTrafficPolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteTrafficPolicyResponse,
+                RusotoError<DeleteTrafficPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_traffic_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_traffic_policy_instance` with what `handler` returns for its input.
+    pub fn with_delete_traffic_policy_instance<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteTrafficPolicyInstanceRequest,
+            ) -> ::std::result::Result<
+                DeleteTrafficPolicyInstanceResponse,
+                RusotoError<DeleteTrafficPolicyInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_traffic_policy_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vpc_association_authorization` with what `handler` returns for its input.
+    pub fn with_delete_vpc_association_authorization<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DeleteVPCAssociationAuthorizationRequest,
+            ) -> ::std::result::Result<
+                DeleteVPCAssociationAuthorizationResponse,
+                RusotoError<DeleteVPCAssociationAuthorizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vpc_association_authorization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_vpc_from_hosted_zone` with what `handler` returns for its input.
+    pub fn with_disassociate_vpc_from_hosted_zone<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                DisassociateVPCFromHostedZoneRequest,
+            ) -> ::std::result::Result<
+                DisassociateVPCFromHostedZoneResponse,
+                RusotoError<DisassociateVPCFromHostedZoneError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_vpc_from_hosted_zone = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account_limit` with what `handler` returns for its input.
+    pub fn with_get_account_limit<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetAccountLimitRequest,
+            )
+                -> ::std::result::Result<GetAccountLimitResponse, RusotoError<GetAccountLimitError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account_limit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_change` with what `handler` returns for its input.
+    pub fn with_get_change<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetChangeRequest,
+            ) -> ::std::result::Result<GetChangeResponse, RusotoError<GetChangeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_change = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_checker_ip_ranges` with what `handler` returns for its input.
+    pub fn with_get_checker_ip_ranges<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetCheckerIpRangesRequest,
+            ) -> ::std::result::Result<
+                GetCheckerIpRangesResponse,
+                RusotoError<GetCheckerIpRangesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_checker_ip_ranges = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_geo_location` with what `handler` returns for its input.
+    pub fn with_get_geo_location<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetGeoLocationRequest,
+            )
+                -> ::std::result::Result<GetGeoLocationResponse, RusotoError<GetGeoLocationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_geo_location = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_health_check` with what `handler` returns for its input.
+    pub fn with_get_health_check<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHealthCheckRequest,
+            )
+                -> ::std::result::Result<GetHealthCheckResponse, RusotoError<GetHealthCheckError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_health_check = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_health_check_count` with what `handler` returns for its input.
+    pub fn with_get_health_check_count<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHealthCheckCountRequest,
+            ) -> ::std::result::Result<
+                GetHealthCheckCountResponse,
+                RusotoError<GetHealthCheckCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_health_check_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_health_check_last_failure_reason` with what `handler` returns for its input.
+    pub fn with_get_health_check_last_failure_reason<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHealthCheckLastFailureReasonRequest,
+            ) -> ::std::result::Result<
+                GetHealthCheckLastFailureReasonResponse,
+                RusotoError<GetHealthCheckLastFailureReasonError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_health_check_last_failure_reason = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_health_check_status` with what `handler` returns for its input.
+    pub fn with_get_health_check_status<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHealthCheckStatusRequest,
+            ) -> ::std::result::Result<
+                GetHealthCheckStatusResponse,
+                RusotoError<GetHealthCheckStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_health_check_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_hosted_zone` with what `handler` returns for its input.
+    pub fn with_get_hosted_zone<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHostedZoneRequest,
+            )
+                -> ::std::result::Result<GetHostedZoneResponse, RusotoError<GetHostedZoneError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_hosted_zone = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_hosted_zone_count` with what `handler` returns for its input.
+    pub fn with_get_hosted_zone_count<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHostedZoneCountRequest,
+            ) -> ::std::result::Result<
+                GetHostedZoneCountResponse,
+                RusotoError<GetHostedZoneCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_hosted_zone_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_hosted_zone_limit` with what `handler` returns for its input.
+    pub fn with_get_hosted_zone_limit<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetHostedZoneLimitRequest,
+            ) -> ::std::result::Result<
+                GetHostedZoneLimitResponse,
+                RusotoError<GetHostedZoneLimitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_hosted_zone_limit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_query_logging_config` with what `handler` returns for its input.
+    pub fn with_get_query_logging_config<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetQueryLoggingConfigRequest,
+            ) -> ::std::result::Result<
+                GetQueryLoggingConfigResponse,
+                RusotoError<GetQueryLoggingConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_query_logging_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_reusable_delegation_set` with what `handler` returns for its input.
+    pub fn with_get_reusable_delegation_set<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetReusableDelegationSetRequest,
+            ) -> ::std::result::Result<
+                GetReusableDelegationSetResponse,
+                RusotoError<GetReusableDelegationSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_reusable_delegation_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_reusable_delegation_set_limit` with what `handler` returns for its input.
+    pub fn with_get_reusable_delegation_set_limit<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetReusableDelegationSetLimitRequest,
+            ) -> ::std::result::Result<
+                GetReusableDelegationSetLimitResponse,
+                RusotoError<GetReusableDelegationSetLimitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_reusable_delegation_set_limit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_traffic_policy` with what `handler` returns for its input.
+    pub fn with_get_traffic_policy<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetTrafficPolicyRequest,
+            ) -> ::std::result::Result<
+                GetTrafficPolicyResponse,
+                RusotoError<GetTrafficPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_traffic_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_traffic_policy_instance` with what `handler` returns for its input.
+    pub fn with_get_traffic_policy_instance<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetTrafficPolicyInstanceRequest,
+            ) -> ::std::result::Result<
+                GetTrafficPolicyInstanceResponse,
+                RusotoError<GetTrafficPolicyInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_traffic_policy_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_traffic_policy_instance_count` with what `handler` returns for its input.
+    pub fn with_get_traffic_policy_instance_count<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                GetTrafficPolicyInstanceCountRequest,
+            ) -> ::std::result::Result<
+                GetTrafficPolicyInstanceCountResponse,
+                RusotoError<GetTrafficPolicyInstanceCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_traffic_policy_instance_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_geo_locations` with what `handler` returns for its input.
+    pub fn with_list_geo_locations<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListGeoLocationsRequest,
+            ) -> ::std::result::Result<
+                ListGeoLocationsResponse,
+                RusotoError<ListGeoLocationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_geo_locations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_health_checks` with what `handler` returns for its input.
+    pub fn with_list_health_checks<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListHealthChecksRequest,
+            ) -> ::std::result::Result<
+                ListHealthChecksResponse,
+                RusotoError<ListHealthChecksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_health_checks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hosted_zones` with what `handler` returns for its input.
+    pub fn with_list_hosted_zones<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListHostedZonesRequest,
+            )
+                -> ::std::result::Result<ListHostedZonesResponse, RusotoError<ListHostedZonesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hosted_zones = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hosted_zones_by_name` with what `handler` returns for its input.
+    pub fn with_list_hosted_zones_by_name<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListHostedZonesByNameRequest,
+            ) -> ::std::result::Result<
+                ListHostedZonesByNameResponse,
+                RusotoError<ListHostedZonesByNameError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hosted_zones_by_name = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_query_logging_configs` with what `handler` returns for its input.
+    pub fn with_list_query_logging_configs<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListQueryLoggingConfigsRequest,
+            ) -> ::std::result::Result<
+                ListQueryLoggingConfigsResponse,
+                RusotoError<ListQueryLoggingConfigsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_query_logging_configs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_record_sets` with what `handler` returns for its input.
+    pub fn with_list_resource_record_sets<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListResourceRecordSetsRequest,
+            ) -> ::std::result::Result<
+                ListResourceRecordSetsResponse,
+                RusotoError<ListResourceRecordSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_record_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_reusable_delegation_sets` with what `handler` returns for its input.
+    pub fn with_list_reusable_delegation_sets<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListReusableDelegationSetsRequest,
+            ) -> ::std::result::Result<
+                ListReusableDelegationSetsResponse,
+                RusotoError<ListReusableDelegationSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_reusable_delegation_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resources` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resources<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTagsForResourcesRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourcesResponse,
+                RusotoError<ListTagsForResourcesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_traffic_policies` with what `handler` returns for its input.
+    pub fn with_list_traffic_policies<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTrafficPoliciesRequest,
+            ) -> ::std::result::Result<
+                ListTrafficPoliciesResponse,
+                RusotoError<ListTrafficPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_traffic_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_traffic_policy_instances` with what `handler` returns for its input.
+    pub fn with_list_traffic_policy_instances<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTrafficPolicyInstancesRequest,
+            ) -> ::std::result::Result<
+                ListTrafficPolicyInstancesResponse,
+                RusotoError<ListTrafficPolicyInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_traffic_policy_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_traffic_policy_instances_by_hosted_zone` with what `handler` returns for its input.
+    pub fn with_list_traffic_policy_instances_by_hosted_zone<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTrafficPolicyInstancesByHostedZoneRequest,
+            ) -> ::std::result::Result<
+                ListTrafficPolicyInstancesByHostedZoneResponse,
+                RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_traffic_policy_instances_by_hosted_zone = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_traffic_policy_instances_by_policy` with what `handler` returns for its input.
+    pub fn with_list_traffic_policy_instances_by_policy<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTrafficPolicyInstancesByPolicyRequest,
+            ) -> ::std::result::Result<
+                ListTrafficPolicyInstancesByPolicyResponse,
+                RusotoError<ListTrafficPolicyInstancesByPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_traffic_policy_instances_by_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_traffic_policy_versions` with what `handler` returns for its input.
+    pub fn with_list_traffic_policy_versions<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListTrafficPolicyVersionsRequest,
+            ) -> ::std::result::Result<
+                ListTrafficPolicyVersionsResponse,
+                RusotoError<ListTrafficPolicyVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_traffic_policy_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_vpc_association_authorizations` with what `handler` returns for its input.
+    pub fn with_list_vpc_association_authorizations<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                ListVPCAssociationAuthorizationsRequest,
+            ) -> ::std::result::Result<
+                ListVPCAssociationAuthorizationsResponse,
+                RusotoError<ListVPCAssociationAuthorizationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_vpc_association_authorizations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_dns_answer` with what `handler` returns for its input.
+    pub fn with_test_dns_answer<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                TestDNSAnswerRequest,
+            )
+                -> ::std::result::Result<TestDNSAnswerResponse, RusotoError<TestDNSAnswerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.test_dns_answer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_health_check` with what `handler` returns for its input.
+    pub fn with_update_health_check<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                UpdateHealthCheckRequest,
+            ) -> ::std::result::Result<
+                UpdateHealthCheckResponse,
+                RusotoError<UpdateHealthCheckError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_health_check = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_hosted_zone_comment` with what `handler` returns for its input.
+    pub fn with_update_hosted_zone_comment<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                UpdateHostedZoneCommentRequest,
+            ) -> ::std::result::Result<
+                UpdateHostedZoneCommentResponse,
+                RusotoError<UpdateHostedZoneCommentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_hosted_zone_comment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_traffic_policy_comment` with what `handler` returns for its input.
+    pub fn with_update_traffic_policy_comment<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                UpdateTrafficPolicyCommentRequest,
+            ) -> ::std::result::Result<
+                UpdateTrafficPolicyCommentResponse,
+                RusotoError<UpdateTrafficPolicyCommentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_traffic_policy_comment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_traffic_policy_instance` with what `handler` returns for its input.
+    pub fn with_update_traffic_policy_instance<F>(mut self, handler: F) -> MockRoute53
+    where
+        F: Fn(
+                UpdateTrafficPolicyInstanceRequest,
+            ) -> ::std::result::Result<
+                UpdateTrafficPolicyInstanceResponse,
+                RusotoError<UpdateTrafficPolicyInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_traffic_policy_instance = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Route53 for MockRoute53 {
+    fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> RusotoFuture<AssociateVPCWithHostedZoneResponse, AssociateVPCWithHostedZoneError> {
+        match self.associate_vpc_with_hosted_zone {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for associate_vpc_with_hosted_zone"),
+        }
+    }
+    fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> RusotoFuture<ChangeResourceRecordSetsResponse, ChangeResourceRecordSetsError> {
+        match self.change_resource_record_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for change_resource_record_sets"),
+        }
+    }
+    fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> RusotoFuture<ChangeTagsForResourceResponse, ChangeTagsForResourceError> {
+        match self.change_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for change_tags_for_resource"),
+        }
+    }
+    fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> RusotoFuture<CreateHealthCheckResponse, CreateHealthCheckError> {
+        match self.create_health_check {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_health_check"),
+        }
+    }
+    fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> RusotoFuture<CreateHostedZoneResponse, CreateHostedZoneError> {
+        match self.create_hosted_zone {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_hosted_zone"),
+        }
+    }
+    fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> RusotoFuture<CreateQueryLoggingConfigResponse, CreateQueryLoggingConfigError> {
+        match self.create_query_logging_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_query_logging_config"),
+        }
+    }
+    fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> RusotoFuture<CreateReusableDelegationSetResponse, CreateReusableDelegationSetError> {
+        match self.create_reusable_delegation_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_reusable_delegation_set"),
+        }
+    }
+    fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyResponse, CreateTrafficPolicyError> {
+        match self.create_traffic_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_traffic_policy"),
+        }
+    }
+    fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyInstanceResponse, CreateTrafficPolicyInstanceError> {
+        match self.create_traffic_policy_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_traffic_policy_instance"),
+        }
+    }
+    fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> RusotoFuture<CreateTrafficPolicyVersionResponse, CreateTrafficPolicyVersionError> {
+        match self.create_traffic_policy_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_traffic_policy_version"),
+        }
+    }
+    fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        CreateVPCAssociationAuthorizationResponse,
+        CreateVPCAssociationAuthorizationError,
+    > {
+        match self.create_vpc_association_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for create_vpc_association_authorization"),
+        }
+    }
+    fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> RusotoFuture<DeleteHealthCheckResponse, DeleteHealthCheckError> {
+        match self.delete_health_check {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_health_check"),
+        }
+    }
+    fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> RusotoFuture<DeleteHostedZoneResponse, DeleteHostedZoneError> {
+        match self.delete_hosted_zone {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_hosted_zone"),
+        }
+    }
+    fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> RusotoFuture<DeleteQueryLoggingConfigResponse, DeleteQueryLoggingConfigError> {
+        match self.delete_query_logging_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_query_logging_config"),
+        }
+    }
+    fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> RusotoFuture<DeleteReusableDelegationSetResponse, DeleteReusableDelegationSetError> {
+        match self.delete_reusable_delegation_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_reusable_delegation_set"),
+        }
+    }
+    fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyResponse, DeleteTrafficPolicyError> {
+        match self.delete_traffic_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_traffic_policy"),
+        }
+    }
+    fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<DeleteTrafficPolicyInstanceResponse, DeleteTrafficPolicyInstanceError> {
+        match self.delete_traffic_policy_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_traffic_policy_instance"),
+        }
+    }
+    fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> RusotoFuture<
+        DeleteVPCAssociationAuthorizationResponse,
+        DeleteVPCAssociationAuthorizationError,
+    > {
+        match self.delete_vpc_association_authorization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for delete_vpc_association_authorization"),
+        }
+    }
+    fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> RusotoFuture<DisassociateVPCFromHostedZoneResponse, DisassociateVPCFromHostedZoneError>
+    {
+        match self.disassociate_vpc_from_hosted_zone {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for disassociate_vpc_from_hosted_zone"),
+        }
+    }
+    fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> RusotoFuture<GetAccountLimitResponse, GetAccountLimitError> {
+        match self.get_account_limit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_account_limit"),
         }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
+    }
+    fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> RusotoFuture<GetChangeResponse, GetChangeError> {
+        match self.get_change {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_change"),
         }
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
+    }
+    fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> RusotoFuture<GetCheckerIpRangesResponse, GetCheckerIpRangesError> {
+        match self.get_checker_ip_ranges {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_checker_ip_ranges"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListHostedZonesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListHostedZonesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListHostedZonesResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p><p>Retrieves a list of your hosted zones in lexicographic order. The response includes a <code>HostedZones</code> child element for each hosted zone created by the current AWS account. </p> <p> <code>ListHostedZonesByName</code> sorts hosted zones by name with the labels reversed. For example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order in some circumstances.</p> <p>If the domain name includes escape characters or Punycode, <code>ListHostedZonesByName</code> alphabetizes the domain name using the escaped or Punycoded value, which is the format that Amazon Route 53 saves in its database. For example, to create a hosted zone for exämple.com, you specify ex\344mple.com for the domain name. <code>ListHostedZonesByName</code> alphabetizes it as:</p> <p> <code>com.ex\344mple.</code> </p> <p>The labels are reversed and alphabetized using the escaped value. For more information about valid domain name formats, including internationalized domain names, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Route 53 returns up to 100 items in each response. If you have a lot of hosted zones, use the <code>MaxItems</code> parameter to list them in groups of up to 100. The response includes values that help navigate from one group of <code>MaxItems</code> hosted zones to the next:</p> <ul> <li> <p>The <code>DNSName</code> and <code>HostedZoneId</code> elements in the response contain the values, if any, specified for the <code>dnsname</code> and <code>hostedzoneid</code> parameters in the request that produced the current response.</p> </li> <li> <p>The <code>MaxItems</code> element in the response contains the value, if any, that you specified for the <code>maxitems</code> parameter in the request that produced the current response.</p> </li> <li> <p>If the value of <code>IsTruncated</code> in the response is true, there are more hosted zones associated with the current AWS account. </p> <p>If <code>IsTruncated</code> is false, this response includes the last hosted zone that is associated with the current account. The <code>NextDNSName</code> element and <code>NextHostedZoneId</code> elements are omitted from the response.</p> </li> <li> <p>The <code>NextDNSName</code> and <code>NextHostedZoneId</code> elements in the response contain the domain name and the hosted zone ID of the next hosted zone that is associated with the current AWS account. If you want to list more hosted zones, make another call to <code>ListHostedZonesByName</code>, and specify the value of <code>NextDNSName</code> and <code>NextHostedZoneId</code> in the <code>dnsname</code> and <code>hostedzoneid</code> parameters, respectively.</p> </li> </ul></p>
-    #[allow(unused_variables, warnings)]
-    fn list_hosted_zones_by_name(
+    fn get_geo_location(
         &self,
-        input: ListHostedZonesByNameRequest,
-    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
-        let request_uri = "/2013-04-01/hostedzonesbyname";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.dns_name {
-            params.put("dnsname", x);
+        input: GetGeoLocationRequest,
+    ) -> RusotoFuture<GetGeoLocationResponse, GetGeoLocationError> {
+        match self.get_geo_location {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_geo_location"),
         }
-        if let Some(ref x) = input.hosted_zone_id {
-            params.put("hostedzoneid", x);
+    }
+    fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> RusotoFuture<GetHealthCheckResponse, GetHealthCheckError> {
+        match self.get_health_check {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_health_check"),
         }
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
+    }
+    fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> RusotoFuture<GetHealthCheckCountResponse, GetHealthCheckCountError> {
+        match self.get_health_check_count {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_health_check_count"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListHostedZonesByNameError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListHostedZonesByNameResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListHostedZonesByNameResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Lists the configurations for DNS query logging that are associated with the current AWS account or the configuration that is associated with a specified hosted zone.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>. Additional information, including the format of DNS query logs, appears in <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
-    fn list_query_logging_configs(
+    fn get_health_check_last_failure_reason(
         &self,
-        input: ListQueryLoggingConfigsRequest,
-    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
-        let request_uri = "/2013-04-01/queryloggingconfig";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.hosted_zone_id {
-            params.put("hostedzoneid", x);
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> RusotoFuture<GetHealthCheckLastFailureReasonResponse, GetHealthCheckLastFailureReasonError>
+    {
+        match self.get_health_check_last_failure_reason {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_health_check_last_failure_reason"),
         }
-        if let Some(ref x) = input.max_results {
-            params.put("maxresults", x);
+    }
+    fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> RusotoFuture<GetHealthCheckStatusResponse, GetHealthCheckStatusError> {
+        match self.get_health_check_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_health_check_status"),
         }
-        if let Some(ref x) = input.next_token {
-            params.put("nexttoken", x);
+    }
+    fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> RusotoFuture<GetHostedZoneResponse, GetHostedZoneError> {
+        match self.get_hosted_zone {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_hosted_zone"),
+        }
+    }
+    fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> RusotoFuture<GetHostedZoneCountResponse, GetHostedZoneCountError> {
+        match self.get_hosted_zone_count {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_hosted_zone_count"),
+        }
+    }
+    fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> RusotoFuture<GetHostedZoneLimitResponse, GetHostedZoneLimitError> {
+        match self.get_hosted_zone_limit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_hosted_zone_limit"),
+        }
+    }
+    fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> RusotoFuture<GetQueryLoggingConfigResponse, GetQueryLoggingConfigError> {
+        match self.get_query_logging_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_query_logging_config"),
+        }
+    }
+    fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetResponse, GetReusableDelegationSetError> {
+        match self.get_reusable_delegation_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_reusable_delegation_set"),
+        }
+    }
+    fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> RusotoFuture<GetReusableDelegationSetLimitResponse, GetReusableDelegationSetLimitError>
+    {
+        match self.get_reusable_delegation_set_limit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_reusable_delegation_set_limit"),
+        }
+    }
+    fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> RusotoFuture<GetTrafficPolicyResponse, GetTrafficPolicyError> {
+        match self.get_traffic_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_traffic_policy"),
+        }
+    }
+    fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceResponse, GetTrafficPolicyInstanceError> {
+        match self.get_traffic_policy_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_traffic_policy_instance"),
+        }
+    }
+    fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> RusotoFuture<GetTrafficPolicyInstanceCountResponse, GetTrafficPolicyInstanceCountError>
+    {
+        match self.get_traffic_policy_instance_count {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for get_traffic_policy_instance_count"),
+        }
+    }
+    fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> RusotoFuture<ListGeoLocationsResponse, ListGeoLocationsError> {
+        match self.list_geo_locations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_geo_locations"),
+        }
+    }
+    fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> RusotoFuture<ListHealthChecksResponse, ListHealthChecksError> {
+        match self.list_health_checks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_health_checks"),
+        }
+    }
+    fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> RusotoFuture<ListHostedZonesResponse, ListHostedZonesError> {
+        match self.list_hosted_zones {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_hosted_zones"),
+        }
+    }
+    fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> RusotoFuture<ListHostedZonesByNameResponse, ListHostedZonesByNameError> {
+        match self.list_hosted_zones_by_name {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_hosted_zones_by_name"),
+        }
+    }
+    fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> RusotoFuture<ListQueryLoggingConfigsResponse, ListQueryLoggingConfigsError> {
+        match self.list_query_logging_configs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_query_logging_configs"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListQueryLoggingConfigsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListQueryLoggingConfigsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListQueryLoggingConfigsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Lists the resource record sets in a specified hosted zone.</p> <p> <code>ListResourceRecordSets</code> returns up to 100 resource record sets at a time in ASCII order, beginning at a position specified by the <code>name</code> and <code>type</code> elements.</p> <p> <b>Sort order</b> </p> <p> <code>ListResourceRecordSets</code> sorts results first by DNS name with the labels reversed, for example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order when the record name contains characters that appear before <code>.</code> (decimal 46) in the ASCII table. These characters include the following: <code>! " # $ % &amp; ' ( ) * + , -</code> </p> <p>When multiple records have the same DNS name, <code>ListResourceRecordSets</code> sorts results by the record type.</p> <p> <b>Specifying where to start listing records</b> </p> <p>You can use the name and type elements to specify the resource record set that the list begins with:</p> <dl> <dt>If you do not specify Name or Type</dt> <dd> <p>The results begin with the first resource record set that the hosted zone contains.</p> </dd> <dt>If you specify Name but not Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>.</p> </dd> <dt>If you specify Type but not Name</dt> <dd> <p>Amazon Route 53 returns the <code>InvalidInput</code> error.</p> </dd> <dt>If you specify both Name and Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>, and whose type is greater than or equal to <code>Type</code>.</p> </dd> </dl> <p> <b>Resource record sets that are PENDING</b> </p> <p>This action returns the most current version of the records. This includes records that are <code>PENDING</code>, and that are not yet available on all Route 53 DNS servers.</p> <p> <b>Changing resource record sets</b> </p> <p>To ensure that you get an accurate listing of the resource record sets for a hosted zone at a point in time, do not submit a <code>ChangeResourceRecordSets</code> request while you're paging through the results of a <code>ListResourceRecordSets</code> request. If you do, some pages may display results without the latest changes while other pages display results with the latest changes.</p> <p> <b>Displaying the next page of results</b> </p> <p>If a <code>ListResourceRecordSets</code> command returns more than one page of results, the value of <code>IsTruncated</code> is <code>true</code>. To display the next page of results, get the values of <code>NextRecordName</code>, <code>NextRecordType</code>, and <code>NextRecordIdentifier</code> (if any) from the response. Then submit another <code>ListResourceRecordSets</code> request, and specify those values for <code>StartRecordName</code>, <code>StartRecordType</code>, and <code>StartRecordIdentifier</code>.</p>
-    #[allow(unused_variables, warnings)]
     fn list_resource_record_sets(
         &self,
         input: ListResourceRecordSetsRequest,
     ) -> RusotoFuture<ListResourceRecordSetsResponse, ListResourceRecordSetsError> {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/rrset",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.start_record_identifier {
-            params.put("identifier", x);
-        }
-        if let Some(ref x) = input.start_record_name {
-            params.put("name", x);
+        match self.list_resource_record_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_resource_record_sets"),
         }
-        if let Some(ref x) = input.start_record_type {
-            params.put("type", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListResourceRecordSetsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListResourceRecordSetsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListResourceRecordSetsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Retrieves a list of the reusable delegation sets that are associated with the current AWS account.</p>
-    #[allow(unused_variables, warnings)]
     fn list_reusable_delegation_sets(
         &self,
         input: ListReusableDelegationSetsRequest,
     ) -> RusotoFuture<ListReusableDelegationSetsResponse, ListReusableDelegationSetsError> {
-        let request_uri = "/2013-04-01/delegationset";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
+        match self.list_reusable_delegation_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_reusable_delegation_sets"),
         }
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListReusableDelegationSetsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListReusableDelegationSetsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListReusableDelegationSetsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Lists tags for one health check or hosted zone. </p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = format!(
-            "/2013-04-01/tags/{resource_type}/{resource_id}",
-            resource_id = input.resource_id,
-            resource_type = input.resource_type
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTagsForResourceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTagsForResourceResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_tags_for_resource"),
+        }
     }
-
-    /// <p>Lists tags for up to 10 health checks or hosted zones.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
     fn list_tags_for_resources(
         &self,
         input: ListTagsForResourcesRequest,
     ) -> RusotoFuture<ListTagsForResourcesResponse, ListTagsForResourcesError> {
-        let request_uri = format!(
-            "/2013-04-01/tags/{resource_type}",
-            resource_type = input.resource_type
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        ListTagsForResourcesRequestSerializer::serialize(
-            &mut writer,
-            "ListTagsForResourcesRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourcesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTagsForResourcesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTagsForResourcesResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.list_tags_for_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_tags_for_resources"),
+        }
     }
-
-    /// <p>Gets information about the latest version for every traffic policy that is associated with the current AWS account. Policies are listed in the order that they were created in. </p>
-    #[allow(unused_variables, warnings)]
     fn list_traffic_policies(
         &self,
         input: ListTrafficPoliciesRequest,
-    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
-        let request_uri = "/2013-04-01/trafficpolicies";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.traffic_policy_id_marker {
-            params.put("trafficpolicyid", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTrafficPoliciesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTrafficPoliciesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTrafficPoliciesResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+    ) -> RusotoFuture<ListTrafficPoliciesResponse, ListTrafficPoliciesError> {
+        match self.list_traffic_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_traffic_policies"),
+        }
     }
-
-    /// <p>Gets information about the traffic policy instances that you created by using the current AWS account.</p> <note> <p>After you submit an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
-    #[allow(unused_variables, warnings)]
     fn list_traffic_policy_instances(
         &self,
         input: ListTrafficPolicyInstancesRequest,
     ) -> RusotoFuture<ListTrafficPolicyInstancesResponse, ListTrafficPolicyInstancesError> {
-        let request_uri = "/2013-04-01/trafficpolicyinstances";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.hosted_zone_id_marker {
-            params.put("hostedzoneid", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.traffic_policy_instance_name_marker {
-            params.put("trafficpolicyinstancename", x);
-        }
-        if let Some(ref x) = input.traffic_policy_instance_type_marker {
-            params.put("trafficpolicyinstancetype", x);
+        match self.list_traffic_policy_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_traffic_policy_instances"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTrafficPolicyInstancesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTrafficPolicyInstancesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTrafficPolicyInstancesResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Gets information about the traffic policy instances that you created in a specified hosted zone.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
-    #[allow(unused_variables, warnings)]
     fn list_traffic_policy_instances_by_hosted_zone(
         &self,
         input: ListTrafficPolicyInstancesByHostedZoneRequest,
@@ -14248,59 +20040,13 @@ impl Route53 for Route53Client {
         ListTrafficPolicyInstancesByHostedZoneResponse,
         ListTrafficPolicyInstancesByHostedZoneError,
     > {
-        let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("id", &input.hosted_zone_id);
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.traffic_policy_instance_name_marker {
-            params.put("trafficpolicyinstancename", x);
-        }
-        if let Some(ref x) = input.traffic_policy_instance_type_marker {
-            params.put("trafficpolicyinstancetype", x);
+        match self.list_traffic_policy_instances_by_hosted_zone {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockRoute53 has no handler for list_traffic_policy_instances_by_hosted_zone"
+            ),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTrafficPolicyInstancesByHostedZoneError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTrafficPolicyInstancesByHostedZoneResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        ListTrafficPolicyInstancesByHostedZoneResponseDeserializer::deserialize(
-                            &actual_tag_name,
-                            &mut stack,
-                        )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Gets information about the traffic policy instances that you created by using a specify traffic policy version.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
-    #[allow(unused_variables, warnings)]
     fn list_traffic_policy_instances_by_policy(
         &self,
         input: ListTrafficPolicyInstancesByPolicyRequest,
@@ -14308,445 +20054,76 @@ impl Route53 for Route53Client {
         ListTrafficPolicyInstancesByPolicyResponse,
         ListTrafficPolicyInstancesByPolicyError,
     > {
-        let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.hosted_zone_id_marker {
-            params.put("hostedzoneid", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        params.put("id", &input.traffic_policy_id);
-        if let Some(ref x) = input.traffic_policy_instance_name_marker {
-            params.put("trafficpolicyinstancename", x);
-        }
-        if let Some(ref x) = input.traffic_policy_instance_type_marker {
-            params.put("trafficpolicyinstancetype", x);
-        }
-        params.put("version", &input.traffic_policy_version);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTrafficPolicyInstancesByPolicyError::from_response(
-                        response,
-                    ))
-                }));
+        match self.list_traffic_policy_instances_by_policy {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockRoute53 has no handler for list_traffic_policy_instances_by_policy")
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTrafficPolicyInstancesByPolicyResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTrafficPolicyInstancesByPolicyResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        }
     }
-
-    /// <p>Gets information about all of the versions for a specified traffic policy.</p> <p>Traffic policy versions are listed in numerical order by <code>VersionNumber</code>.</p>
-    #[allow(unused_variables, warnings)]
     fn list_traffic_policy_versions(
         &self,
         input: ListTrafficPolicyVersionsRequest,
     ) -> RusotoFuture<ListTrafficPolicyVersionsResponse, ListTrafficPolicyVersionsError> {
-        let request_uri = format!("/2013-04-01/trafficpolicies/{id}/versions", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_items {
-            params.put("maxitems", x);
-        }
-        if let Some(ref x) = input.traffic_policy_version_marker {
-            params.put("trafficpolicyversion", x);
+        match self.list_traffic_policy_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_traffic_policy_versions"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTrafficPolicyVersionsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListTrafficPolicyVersionsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListTrafficPolicyVersionsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Gets a list of the VPCs that were created by other accounts and that can be associated with a specified hosted zone because you've submitted one or more <code>CreateVPCAssociationAuthorization</code> requests. </p> <p>The response includes a <code>VPCs</code> element with a <code>VPC</code> child element for each VPC that can be associated with the hosted zone.</p>
-    #[allow(unused_variables, warnings)]
     fn list_vpc_association_authorizations(
         &self,
         input: ListVPCAssociationAuthorizationsRequest,
     ) -> RusotoFuture<ListVPCAssociationAuthorizationsResponse, ListVPCAssociationAuthorizationsError>
     {
-        let request_uri = format!(
-            "/2013-04-01/hostedzone/{id}/authorizevpcassociation",
-            id = input.hosted_zone_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("maxresults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("nexttoken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListVPCAssociationAuthorizationsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListVPCAssociationAuthorizationsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListVPCAssociationAuthorizationsResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.list_vpc_association_authorizations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for list_vpc_association_authorizations"),
+        }
     }
-
-    /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
-    #[allow(unused_variables, warnings)]
     fn test_dns_answer(
         &self,
         input: TestDNSAnswerRequest,
     ) -> RusotoFuture<TestDNSAnswerResponse, TestDNSAnswerError> {
-        let request_uri = "/2013-04-01/testdnsanswer";
-
-        let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.edns0_client_subnet_ip {
-            params.put("edns0clientsubnetip", x);
-        }
-        if let Some(ref x) = input.edns0_client_subnet_mask {
-            params.put("edns0clientsubnetmask", x);
-        }
-        params.put("hostedzoneid", &input.hosted_zone_id);
-        params.put("recordname", &input.record_name);
-        params.put("recordtype", &input.record_type);
-        if let Some(ref x) = input.resolver_ip {
-            params.put("resolverip", x);
+        match self.test_dns_answer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for test_dns_answer"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestDNSAnswerError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = TestDNSAnswerResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = TestDNSAnswerResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Updates an existing health check. Note that some values can't be updated. </p> <p>For more information about updating health checks, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html">Creating, Updating, and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
     fn update_health_check(
         &self,
         input: UpdateHealthCheckRequest,
     ) -> RusotoFuture<UpdateHealthCheckResponse, UpdateHealthCheckError> {
-        let request_uri = format!(
-            "/2013-04-01/healthcheck/{health_check_id}",
-            health_check_id = input.health_check_id
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        UpdateHealthCheckRequestSerializer::serialize(
-            &mut writer,
-            "UpdateHealthCheckRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateHealthCheckError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateHealthCheckResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateHealthCheckResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_health_check {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for update_health_check"),
+        }
     }
-
-    /// <p>Updates the comment for a specified hosted zone.</p>
-    #[allow(unused_variables, warnings)]
     fn update_hosted_zone_comment(
         &self,
         input: UpdateHostedZoneCommentRequest,
     ) -> RusotoFuture<UpdateHostedZoneCommentResponse, UpdateHostedZoneCommentError> {
-        let request_uri = format!("/2013-04-01/hostedzone/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        UpdateHostedZoneCommentRequestSerializer::serialize(
-            &mut writer,
-            "UpdateHostedZoneCommentRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateHostedZoneCommentError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateHostedZoneCommentResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateHostedZoneCommentResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_hosted_zone_comment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for update_hosted_zone_comment"),
+        }
     }
-
-    /// <p>Updates the comment for a specified traffic policy version.</p>
-    #[allow(unused_variables, warnings)]
     fn update_traffic_policy_comment(
         &self,
         input: UpdateTrafficPolicyCommentRequest,
     ) -> RusotoFuture<UpdateTrafficPolicyCommentResponse, UpdateTrafficPolicyCommentError> {
-        let request_uri = format!(
-            "/2013-04-01/trafficpolicy/{id}/{version}",
-            id = input.id,
-            version = input.version
-        )
-        .replace("/hostedzone/hostedzone/", "/hostedzone/")
-        .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        UpdateTrafficPolicyCommentRequestSerializer::serialize(
-            &mut writer,
-            "UpdateTrafficPolicyCommentRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateTrafficPolicyCommentError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateTrafficPolicyCommentResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateTrafficPolicyCommentResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_traffic_policy_comment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for update_traffic_policy_comment"),
+        }
     }
-
-    /// <p><p>Updates the resource record sets in a specified hosted zone that were created based on the settings in a specified traffic policy version.</p> <p>When you update a traffic policy instance, Amazon Route 53 continues to respond to DNS queries for the root resource record set name (such as example.com) while it replaces one group of resource record sets with another. Route 53 performs the following operations:</p> <ol> <li> <p>Route 53 creates a new group of resource record sets based on the specified traffic policy. This is true regardless of how significant the differences are between the existing resource record sets and the new resource record sets. </p> </li> <li> <p>When all of the new resource record sets have been created, Route 53 starts to respond to DNS queries for the root resource record set name (such as example.com) by using the new resource record sets.</p> </li> <li> <p>Route 53 deletes the old group of resource record sets that are associated with the root resource record set name.</p> </li> </ol></p>
-    #[allow(unused_variables, warnings)]
     fn update_traffic_policy_instance(
         &self,
         input: UpdateTrafficPolicyInstanceRequest,
     ) -> RusotoFuture<UpdateTrafficPolicyInstanceResponse, UpdateTrafficPolicyInstanceError> {
-        let request_uri = format!("/2013-04-01/trafficpolicyinstance/{id}", id = input.id)
-            .replace("/hostedzone/hostedzone/", "/hostedzone/")
-            .replace("/change/change/", "/change/");
-
-        let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        UpdateTrafficPolicyInstanceRequestSerializer::serialize(
-            &mut writer,
-            "UpdateTrafficPolicyInstanceRequest",
-            &input,
-            "https://route53.amazonaws.com/doc/2013-04-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateTrafficPolicyInstanceError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UpdateTrafficPolicyInstanceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UpdateTrafficPolicyInstanceResponseDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_traffic_policy_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53 has no handler for update_traffic_policy_instance"),
+        }
     }
 }
 
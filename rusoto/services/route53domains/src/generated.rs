@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Information for one billing record.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BillingRecord {
     /// <p>The date that the operation was billed, in Unix format.</p>
     #[serde(rename = "BillDate")]
@@ -51,6 +52,7 @@ pub struct BillingRecord {
 
 /// <p>The CheckDomainAvailability request contains the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CheckDomainAvailabilityRequest {
     /// <p>The name of the domain that you want to get availability for.</p> <p>Constraints: The domain name can contain only the letters a through z, the numbers 0 through 9, and hyphen (-). Internationalized Domain Names are not supported.</p>
     #[serde(rename = "DomainName")]
@@ -61,9 +63,28 @@ pub struct CheckDomainAvailabilityRequest {
     pub idn_lang_code: Option<String>,
 }
 
+impl Validate for CheckDomainAvailabilityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        if let Some(ref value) = self.idn_lang_code {
+            errors.check_length(
+                &field_path(path, "idn_lang_code"),
+                value.chars().count(),
+                None,
+                Some(3),
+            );
+        }
+    }
+}
+
 /// <p>The CheckDomainAvailability response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CheckDomainAvailabilityResponse {
     /// <p><p>Whether the domain name is available for registering.</p> <note> <p>You can register only domains designated as <code>AVAILABLE</code>.</p> </note> <p>Valid values:</p> <dl> <dt>AVAILABLE</dt> <dd> <p>The domain name is available.</p> </dd> <dt>AVAILABLE<em>RESERVED</dt> <dd> <p>The domain name is reserved under specific conditions.</p> </dd> <dt>AVAILABLE</em>PREORDER</dt> <dd> <p>The domain name is available and can be preordered.</p> </dd> <dt>DONT<em>KNOW</dt> <dd> <p>The TLD registry didn&#39;t reply with a definitive answer about whether the domain name is available. Amazon Route 53 can return this response for a variety of reasons, for example, the registry is performing maintenance. Try again later.</p> </dd> <dt>PENDING</dt> <dd> <p>The TLD registry didn&#39;t return a response in the expected amount of time. When the response is delayed, it usually takes just a few extra seconds. You can resubmit the request immediately.</p> </dd> <dt>RESERVED</dt> <dd> <p>The domain name has been reserved for another person or organization.</p> </dd> <dt>UNAVAILABLE</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE</em>PREMIUM</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE_RESTRICTED</dt> <dd> <p>The domain name is forbidden.</p> </dd> </dl></p>
     #[serde(rename = "Availability")]
@@ -72,6 +93,7 @@ pub struct CheckDomainAvailabilityResponse {
 
 /// <p>The CheckDomainTransferability request contains the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CheckDomainTransferabilityRequest {
     /// <p>If the registrar for the top-level domain (TLD) requires an authorization code to transfer the domain, the code that you got from the current registrar for the domain.</p>
     #[serde(rename = "AuthCode")]
@@ -82,9 +104,28 @@ pub struct CheckDomainTransferabilityRequest {
     pub domain_name: String,
 }
 
+impl Validate for CheckDomainTransferabilityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.auth_code {
+            errors.check_length(
+                &field_path(path, "auth_code"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The CheckDomainTransferability response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CheckDomainTransferabilityResponse {
     /// <p>A complex type that contains information about whether the specified domain can be transferred to Amazon Route 53.</p>
     #[serde(rename = "Transferability")]
@@ -152,8 +193,108 @@ pub struct ContactDetail {
     pub zip_code: Option<String>,
 }
 
+impl Validate for ContactDetail {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.address_line_1 {
+            errors.check_length(
+                &field_path(path, "address_line_1"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.address_line_2 {
+            errors.check_length(
+                &field_path(path, "address_line_2"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.city {
+            errors.check_length(
+                &field_path(path, "city"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.email {
+            errors.check_length(
+                &field_path(path, "email"),
+                value.chars().count(),
+                None,
+                Some(254),
+            );
+        }
+        if let Some(ref value) = self.extra_params {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "extra_params"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.fax {
+            errors.check_length(
+                &field_path(path, "fax"),
+                value.chars().count(),
+                None,
+                Some(30),
+            );
+        }
+        if let Some(ref value) = self.first_name {
+            errors.check_length(
+                &field_path(path, "first_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.last_name {
+            errors.check_length(
+                &field_path(path, "last_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.organization_name {
+            errors.check_length(
+                &field_path(path, "organization_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.phone_number {
+            errors.check_length(
+                &field_path(path, "phone_number"),
+                value.chars().count(),
+                None,
+                Some(30),
+            );
+        }
+        if let Some(ref value) = self.state {
+            errors.check_length(
+                &field_path(path, "state"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.zip_code {
+            errors.check_length(
+                &field_path(path, "zip_code"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>The DeleteTagsForDomainRequest includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsForDomainRequest {
     /// <p>The domain for which you want to delete one or more tags.</p>
     #[serde(rename = "DomainName")]
@@ -163,32 +304,71 @@ pub struct DeleteTagsForDomainRequest {
     pub tags_to_delete: Vec<String>,
 }
 
+impl Validate for DeleteTagsForDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        for (i, item) in self.tags_to_delete.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags_to_delete"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTagsForDomainResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableDomainAutoRenewRequest {
     /// <p>The name of the domain that you want to disable automatic renewal for.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for DisableDomainAutoRenewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableDomainAutoRenewResponse {}
 
 /// <p>The DisableDomainTransferLock request includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableDomainTransferLockRequest {
     /// <p>The name of the domain that you want to remove the transfer lock for.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for DisableDomainTransferLockRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The DisableDomainTransferLock response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableDomainTransferLockResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
     #[serde(rename = "OperationId")]
@@ -197,7 +377,7 @@ pub struct DisableDomainTransferLockResponse {
 
 /// <p>Information about one suggested domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainSuggestion {
     /// <p><p>Whether the domain name is available for registering.</p> <note> <p>You can register only the domains that are designated as <code>AVAILABLE</code>.</p> </note> <p>Valid values:</p> <dl> <dt>AVAILABLE</dt> <dd> <p>The domain name is available.</p> </dd> <dt>AVAILABLE<em>RESERVED</dt> <dd> <p>The domain name is reserved under specific conditions.</p> </dd> <dt>AVAILABLE</em>PREORDER</dt> <dd> <p>The domain name is available and can be preordered.</p> </dd> <dt>DONT<em>KNOW</dt> <dd> <p>The TLD registry didn&#39;t reply with a definitive answer about whether the domain name is available. Amazon Route 53 can return this response for a variety of reasons, for example, the registry is performing maintenance. Try again later.</p> </dd> <dt>PENDING</dt> <dd> <p>The TLD registry didn&#39;t return a response in the expected amount of time. When the response is delayed, it usually takes just a few extra seconds. You can resubmit the request immediately.</p> </dd> <dt>RESERVED</dt> <dd> <p>The domain name has been reserved for another person or organization.</p> </dd> <dt>UNAVAILABLE</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE</em>PREMIUM</dt> <dd> <p>The domain name is not available.</p> </dd> <dt>UNAVAILABLE_RESTRICTED</dt> <dd> <p>The domain name is forbidden.</p> </dd> </dl></p>
     #[serde(rename = "Availability")]
@@ -211,7 +391,7 @@ pub struct DomainSuggestion {
 
 /// <p>Summary information about one domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainSummary {
     /// <p>Indicates whether the domain is automatically renewed upon expiration.</p>
     #[serde(rename = "AutoRenew")]
@@ -232,7 +412,7 @@ pub struct DomainSummary {
 
 /// <p>A complex type that contains information about whether the specified domain can be transferred to Amazon Route 53.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainTransferability {
     #[serde(rename = "Transferable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -240,27 +420,51 @@ pub struct DomainTransferability {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableDomainAutoRenewRequest {
     /// <p>The name of the domain that you want to enable automatic renewal for.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for EnableDomainAutoRenewRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableDomainAutoRenewResponse {}
 
 /// <p>A request to set the transfer lock for the specified domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableDomainTransferLockRequest {
     /// <p>The name of the domain that you want to set the transfer lock for.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for EnableDomainTransferLockRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The EnableDomainTransferLock response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableDomainTransferLockResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use GetOperationDetail.</p>
     #[serde(rename = "OperationId")]
@@ -278,7 +482,19 @@ pub struct ExtraParam {
     pub value: String,
 }
 
+impl Validate for ExtraParam {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetContactReachabilityStatusRequest {
     /// <p>The name of the domain for which you want to know whether the registrant contact has confirmed that the email address is valid.</p>
     #[serde(rename = "domainName")]
@@ -286,8 +502,21 @@ pub struct GetContactReachabilityStatusRequest {
     pub domain_name: Option<String>,
 }
 
+impl Validate for GetContactReachabilityStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.domain_name {
+            errors.check_length(
+                &field_path(path, "domain_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetContactReachabilityStatusResponse {
     /// <p>The domain name for which you requested the reachability status.</p>
     #[serde(rename = "domainName")]
@@ -301,15 +530,27 @@ pub struct GetContactReachabilityStatusResponse {
 
 /// <p>The GetDomainDetail request includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainDetailRequest {
     /// <p>The name of the domain that you want to get detailed information about.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for GetDomainDetailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The GetDomainDetail response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainDetailResponse {
     /// <p>Email address to contact to report incorrect contact information for a domain, to report that the domain is being used to send spam, to report that someone is cybersquatting on a domain name, or report some other type of abuse.</p>
     #[serde(rename = "AbuseContactEmail")]
@@ -393,6 +634,7 @@ pub struct GetDomainDetailResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDomainSuggestionsRequest {
     /// <p>A domain name that you want to use as the basis for a list of possible domain names. The domain name must contain a top-level domain (TLD), such as .com, that Amazon Route 53 supports. For a list of TLDs, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/registrar-tld-list.html">Domains that You Can Register with Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
     #[serde(rename = "DomainName")]
@@ -405,8 +647,19 @@ pub struct GetDomainSuggestionsRequest {
     pub suggestion_count: i64,
 }
 
+impl Validate for GetDomainSuggestionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDomainSuggestionsResponse {
     /// <p>A list of possible domain names. If you specified <code>true</code> for <code>OnlyAvailable</code> in the request, the list contains only domains that are available for registration.</p>
     #[serde(rename = "SuggestionsList")]
@@ -416,15 +669,27 @@ pub struct GetDomainSuggestionsResponse {
 
 /// <p>The <a>GetOperationDetail</a> request includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOperationDetailRequest {
     /// <p>The identifier for the operation for which you want to get the status. Amazon Route 53 returned the identifier in the response to the original request.</p>
     #[serde(rename = "OperationId")]
     pub operation_id: String,
 }
 
+impl Validate for GetOperationDetailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "operation_id"),
+            self.operation_id.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The GetOperationDetail response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOperationDetailResponse {
     /// <p>The name of a domain.</p>
     #[serde(rename = "DomainName")]
@@ -454,6 +719,7 @@ pub struct GetOperationDetailResponse {
 
 /// <p>The ListDomains request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainsRequest {
     /// <p>For an initial request for a list of domains, omit this element. If the number of domains that are associated with the current AWS account is greater than the value that you specified for <code>MaxItems</code>, you can use <code>Marker</code> to return additional domains. Get the value of <code>NextPageMarker</code> from the previous response, and submit another request that includes the value of <code>NextPageMarker</code> in the <code>Marker</code> element.</p> <p>Constraints: The marker must match the value specified in the previous request.</p>
     #[serde(rename = "Marker")]
@@ -465,9 +731,25 @@ pub struct ListDomainsRequest {
     pub max_items: Option<i64>,
 }
 
+impl Validate for ListDomainsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, None, Some(100));
+        }
+    }
+}
+
 /// <p>The ListDomains response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDomainsResponse {
     /// <p>A summary of domains.</p>
     #[serde(rename = "Domains")]
@@ -480,6 +762,7 @@ pub struct ListDomainsResponse {
 
 /// <p>The ListOperations request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOperationsRequest {
     /// <p>For an initial request for a list of operations, omit this element. If the number of operations that are not yet complete is greater than the value that you specified for <code>MaxItems</code>, you can use <code>Marker</code> to return additional operations. Get the value of <code>NextPageMarker</code> from the previous response, and submit another request that includes the value of <code>NextPageMarker</code> in the <code>Marker</code> element.</p>
     #[serde(rename = "Marker")]
@@ -495,9 +778,25 @@ pub struct ListOperationsRequest {
     pub submitted_since: Option<f64>,
 }
 
+impl Validate for ListOperationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, None, Some(100));
+        }
+    }
+}
+
 /// <p>The ListOperations response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOperationsResponse {
     /// <p>If there are more operations than you specified for <code>MaxItems</code> in the request, submit another request and include the value of <code>NextPageMarker</code> in the value of <code>Marker</code>.</p>
     #[serde(rename = "NextPageMarker")]
@@ -510,15 +809,27 @@ pub struct ListOperationsResponse {
 
 /// <p>The ListTagsForDomainRequest includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForDomainRequest {
     /// <p>The domain for which you want to get a list of tags.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for ListTagsForDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The ListTagsForDomain response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForDomainResponse {
     /// <p>A list of the tags that are associated with the specified domain.</p>
     #[serde(rename = "TagList")]
@@ -537,9 +848,27 @@ pub struct Nameserver {
     pub name: String,
 }
 
+impl Validate for Nameserver {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.glue_ips {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "glue_ips"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(45));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[a-zA-Z0-9_\\-.]*");
+    }
+}
+
 /// <p>OperationSummary includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OperationSummary {
     /// <p>Identifier returned to track the requested action.</p>
     #[serde(rename = "OperationId")]
@@ -557,6 +886,7 @@ pub struct OperationSummary {
 
 /// <p>The RegisterDomain request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterDomainRequest {
     /// <p>Provides detailed contact information.</p>
     #[serde(rename = "AdminContact")]
@@ -595,9 +925,40 @@ pub struct RegisterDomainRequest {
     pub tech_contact: ContactDetail,
 }
 
+impl Validate for RegisterDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.admin_contact
+            .validate_at(&field_path(path, "admin_contact"), errors);
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_range(
+            &field_path(path, "duration_in_years"),
+            self.duration_in_years,
+            Some(1),
+            Some(10),
+        );
+        if let Some(ref value) = self.idn_lang_code {
+            errors.check_length(
+                &field_path(path, "idn_lang_code"),
+                value.chars().count(),
+                None,
+                Some(3),
+            );
+        }
+        self.registrant_contact
+            .validate_at(&field_path(path, "registrant_contact"), errors);
+        self.tech_contact
+            .validate_at(&field_path(path, "tech_contact"), errors);
+    }
+}
+
 /// <p>The RegisterDomain response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterDomainResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
     #[serde(rename = "OperationId")]
@@ -606,6 +967,7 @@ pub struct RegisterDomainResponse {
 
 /// <p>A <code>RenewDomain</code> request includes the number of years that you want to renew for and the current expiration year.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RenewDomainRequest {
     /// <p>The year when the registration for the domain is set to expire. This value must match the current expiration date for the domain.</p>
     #[serde(rename = "CurrentExpiryYear")]
@@ -619,8 +981,27 @@ pub struct RenewDomainRequest {
     pub duration_in_years: Option<i64>,
 }
 
+impl Validate for RenewDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        if let Some(ref value) = self.duration_in_years {
+            errors.check_range(
+                &field_path(path, "duration_in_years"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RenewDomainResponse {
     /// <p>The identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
     #[serde(rename = "OperationId")]
@@ -628,6 +1009,7 @@ pub struct RenewDomainResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResendContactReachabilityEmailRequest {
     /// <p>The name of the domain for which you want Amazon Route 53 to resend a confirmation email to the registrant contact.</p>
     #[serde(rename = "domainName")]
@@ -635,8 +1017,21 @@ pub struct ResendContactReachabilityEmailRequest {
     pub domain_name: Option<String>,
 }
 
+impl Validate for ResendContactReachabilityEmailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.domain_name {
+            errors.check_length(
+                &field_path(path, "domain_name"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResendContactReachabilityEmailResponse {
     /// <p>The domain name for which you requested a confirmation email.</p>
     #[serde(rename = "domainName")]
@@ -654,15 +1049,27 @@ pub struct ResendContactReachabilityEmailResponse {
 
 /// <p>A request for the authorization code for the specified domain. To transfer a domain to another registrar, you provide this value to the new registrar.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetrieveDomainAuthCodeRequest {
     /// <p>The name of the domain that you want to get an authorization code for.</p>
     #[serde(rename = "DomainName")]
     pub domain_name: String,
 }
 
+impl Validate for RetrieveDomainAuthCodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The RetrieveDomainAuthCode response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RetrieveDomainAuthCodeResponse {
     /// <p>The authorization code for the domain.</p>
     #[serde(rename = "AuthCode")]
@@ -682,8 +1089,30 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>The TransferDomain request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TransferDomainRequest {
     /// <p>Provides detailed contact information.</p>
     #[serde(rename = "AdminContact")]
@@ -730,9 +1159,54 @@ pub struct TransferDomainRequest {
     pub tech_contact: ContactDetail,
 }
 
+impl Validate for TransferDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.admin_contact
+            .validate_at(&field_path(path, "admin_contact"), errors);
+        if let Some(ref value) = self.auth_code {
+            errors.check_length(
+                &field_path(path, "auth_code"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_range(
+            &field_path(path, "duration_in_years"),
+            self.duration_in_years,
+            Some(1),
+            Some(10),
+        );
+        if let Some(ref value) = self.idn_lang_code {
+            errors.check_length(
+                &field_path(path, "idn_lang_code"),
+                value.chars().count(),
+                None,
+                Some(3),
+            );
+        }
+        if let Some(ref value) = self.nameservers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "nameservers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.registrant_contact
+            .validate_at(&field_path(path, "registrant_contact"), errors);
+        self.tech_contact
+            .validate_at(&field_path(path, "tech_contact"), errors);
+    }
+}
+
 /// <p>The TranserDomain response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TransferDomainResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
     #[serde(rename = "OperationId")]
@@ -741,6 +1215,7 @@ pub struct TransferDomainResponse {
 
 /// <p>The UpdateDomainContactPrivacy request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainContactPrivacyRequest {
     /// <p>Whether you want to conceal contact information from WHOIS queries. If you specify <code>true</code>, WHOIS ("who is") queries return contact information either for Amazon Registrar (for .com, .net, and .org domains) or for our registrar associate, Gandi (for all other TLDs). If you specify <code>false</code>, WHOIS queries return the information that you entered for the admin contact.</p>
     #[serde(rename = "AdminPrivacy")]
@@ -759,9 +1234,20 @@ pub struct UpdateDomainContactPrivacyRequest {
     pub tech_privacy: Option<bool>,
 }
 
+impl Validate for UpdateDomainContactPrivacyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+    }
+}
+
 /// <p>The UpdateDomainContactPrivacy response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainContactPrivacyResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use GetOperationDetail.</p>
     #[serde(rename = "OperationId")]
@@ -770,6 +1256,7 @@ pub struct UpdateDomainContactPrivacyResponse {
 
 /// <p>The UpdateDomainContact request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainContactRequest {
     /// <p>Provides detailed contact information.</p>
     #[serde(rename = "AdminContact")]
@@ -788,9 +1275,29 @@ pub struct UpdateDomainContactRequest {
     pub tech_contact: Option<ContactDetail>,
 }
 
+impl Validate for UpdateDomainContactRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.admin_contact {
+            value.validate_at(&field_path(path, "admin_contact"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        if let Some(ref value) = self.registrant_contact {
+            value.validate_at(&field_path(path, "registrant_contact"), errors);
+        }
+        if let Some(ref value) = self.tech_contact {
+            value.validate_at(&field_path(path, "tech_contact"), errors);
+        }
+    }
+}
+
 /// <p>The UpdateDomainContact response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainContactResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
     #[serde(rename = "OperationId")]
@@ -799,6 +1306,7 @@ pub struct UpdateDomainContactResponse {
 
 /// <p>Replaces the current set of name servers for the domain with the specified set of name servers. If you use Amazon Route 53 as your DNS service, specify the four name servers in the delegation set for the hosted zone for the domain.</p> <p>If successful, this operation returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainNameserversRequest {
     /// <p>The name of the domain that you want to change name servers for.</p>
     #[serde(rename = "DomainName")]
@@ -808,9 +1316,24 @@ pub struct UpdateDomainNameserversRequest {
     pub nameservers: Vec<Nameserver>,
 }
 
+impl Validate for UpdateDomainNameserversRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        for (i, item) in self.nameservers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "nameservers"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>The UpdateDomainNameservers response includes the following element.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainNameserversResponse {
     /// <p>Identifier for tracking the progress of the request. To use this ID to query the operation status, use <a>GetOperationDetail</a>.</p>
     #[serde(rename = "OperationId")]
@@ -819,6 +1342,7 @@ pub struct UpdateDomainNameserversResponse {
 
 /// <p>The UpdateTagsForDomainRequest includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTagsForDomainRequest {
     /// <p>The domain for which you want to add or update tags.</p>
     #[serde(rename = "DomainName")]
@@ -829,12 +1353,30 @@ pub struct UpdateTagsForDomainRequest {
     pub tags_to_update: Option<Vec<Tag>>,
 }
 
+impl Validate for UpdateTagsForDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            None,
+            Some(255),
+        );
+        if let Some(ref value) = self.tags_to_update {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags_to_update"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTagsForDomainResponse {}
 
 /// <p>The ViewBilling request includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ViewBillingRequest {
     /// <p>The end date and time for the time period for which you want a list of billing records. Specify the date and time in Coordinated Universal time (UTC).</p>
     #[serde(rename = "End")]
@@ -854,9 +1396,25 @@ pub struct ViewBillingRequest {
     pub start: Option<f64>,
 }
 
+impl Validate for ViewBillingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, None, Some(100));
+        }
+    }
+}
+
 /// <p>The ViewBilling response includes the following elements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ViewBillingResponse {
     /// <p>A summary of billing records.</p>
     #[serde(rename = "BillingRecords")]
@@ -891,7 +1449,12 @@ impl CheckDomainAvailabilityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -936,7 +1499,12 @@ impl CheckDomainTransferabilityError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -982,7 +1550,12 @@ impl DeleteTagsForDomainError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(DeleteTagsForDomainError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1024,7 +1597,12 @@ impl DisableDomainAutoRenewError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1088,7 +1666,12 @@ impl DisableDomainTransferLockError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1139,7 +1722,12 @@ impl EnableDomainAutoRenewError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1204,7 +1792,12 @@ impl EnableDomainTransferLockError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1259,7 +1852,12 @@ impl GetContactReachabilityStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1299,7 +1897,12 @@ impl GetDomainDetailError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(GetDomainDetailError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1338,7 +1941,12 @@ impl GetDomainSuggestionsError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(GetDomainSuggestionsError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1372,7 +1980,12 @@ impl GetOperationDetailError {
                 "InvalidInput" => {
                     return RusotoError::Service(GetOperationDetailError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1405,7 +2018,12 @@ impl ListDomainsError {
                 "InvalidInput" => {
                     return RusotoError::Service(ListDomainsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1438,7 +2056,12 @@ impl ListOperationsError {
                 "InvalidInput" => {
                     return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1483,7 +2106,12 @@ impl ListTagsForDomainError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(ListTagsForDomainError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1545,7 +2173,12 @@ impl RegisterDomainError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(RegisterDomainError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1603,7 +2236,12 @@ impl RenewDomainError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(RenewDomainError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1658,7 +2296,12 @@ impl ResendContactReachabilityEmailError {
                         ResendContactReachabilityEmailError::UnsupportedTLD(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1700,7 +2343,12 @@ impl RetrieveDomainAuthCodeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1761,7 +2409,12 @@ impl TransferDomainError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(TransferDomainError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1825,7 +2478,12 @@ impl UpdateDomainContactError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(UpdateDomainContactError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1894,7 +2552,12 @@ impl UpdateDomainContactPrivacyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1961,7 +2624,12 @@ impl UpdateDomainNameserversError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2010,7 +2678,12 @@ impl UpdateTagsForDomainError {
                 "UnsupportedTLD" => {
                     return RusotoError::Service(UpdateTagsForDomainError::UnsupportedTLD(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2045,7 +2718,12 @@ impl ViewBillingError {
                 "InvalidInput" => {
                     return RusotoError::Service(ViewBillingError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2210,123 +2888,592 @@ pub trait Route53Domains {
         input: ViewBillingRequest,
     ) -> RusotoFuture<ViewBillingResponse, ViewBillingError>;
 }
-/// A client for the Amazon Route 53 Domains API.
-#[derive(Clone)]
-pub struct Route53DomainsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl Route53DomainsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> Route53DomainsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> Route53DomainsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsClient {
-        Route53DomainsClient { client, region }
-    }
-}
-
-impl Route53Domains for Route53DomainsClient {
-    /// <p>This operation checks the availability of one domain name. Note that if the availability status of a domain is pending, you must submit another request to determine the availability of the domain name.</p>
+const _: Option<&dyn Route53Domains> = None;
+impl<'a, T: Route53Domains + ?Sized> Route53Domains for &'a T {
     fn check_domain_availability(
         &self,
         input: CheckDomainAvailabilityRequest,
     ) -> RusotoFuture<CheckDomainAvailabilityResponse, CheckDomainAvailabilityError> {
-        let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Route53Domains_v20140515.CheckDomainAvailability",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CheckDomainAvailabilityResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CheckDomainAvailabilityError::from_response(response))
-                }))
-            }
-        })
+        (**self).check_domain_availability(input)
     }
-
-    /// <p>Checks whether a domain name can be transferred to Amazon Route 53. </p>
     fn check_domain_transferability(
         &self,
         input: CheckDomainTransferabilityRequest,
     ) -> RusotoFuture<CheckDomainTransferabilityResponse, CheckDomainTransferabilityError> {
-        let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Route53Domains_v20140515.CheckDomainTransferability",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CheckDomainTransferabilityResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CheckDomainTransferabilityError::from_response(response))
-                }))
-            }
-        })
+        (**self).check_domain_transferability(input)
     }
-
-    /// <p>This operation deletes the specified tags for a domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
     fn delete_tags_for_domain(
         &self,
         input: DeleteTagsForDomainRequest,
     ) -> RusotoFuture<DeleteTagsForDomainResponse, DeleteTagsForDomainError> {
-        let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
-
+        (**self).delete_tags_for_domain(input)
+    }
+    fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> RusotoFuture<DisableDomainAutoRenewResponse, DisableDomainAutoRenewError> {
+        (**self).disable_domain_auto_renew(input)
+    }
+    fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> RusotoFuture<DisableDomainTransferLockResponse, DisableDomainTransferLockError> {
+        (**self).disable_domain_transfer_lock(input)
+    }
+    fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> RusotoFuture<EnableDomainAutoRenewResponse, EnableDomainAutoRenewError> {
+        (**self).enable_domain_auto_renew(input)
+    }
+    fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> RusotoFuture<EnableDomainTransferLockResponse, EnableDomainTransferLockError> {
+        (**self).enable_domain_transfer_lock(input)
+    }
+    fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> RusotoFuture<GetContactReachabilityStatusResponse, GetContactReachabilityStatusError> {
+        (**self).get_contact_reachability_status(input)
+    }
+    fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> RusotoFuture<GetDomainDetailResponse, GetDomainDetailError> {
+        (**self).get_domain_detail(input)
+    }
+    fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> RusotoFuture<GetDomainSuggestionsResponse, GetDomainSuggestionsError> {
+        (**self).get_domain_suggestions(input)
+    }
+    fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> RusotoFuture<GetOperationDetailResponse, GetOperationDetailError> {
+        (**self).get_operation_detail(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> RusotoFuture<ListTagsForDomainResponse, ListTagsForDomainError> {
+        (**self).list_tags_for_domain(input)
+    }
+    fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> RusotoFuture<RegisterDomainResponse, RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> RusotoFuture<RenewDomainResponse, RenewDomainError> {
+        (**self).renew_domain(input)
+    }
+    fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> RusotoFuture<ResendContactReachabilityEmailResponse, ResendContactReachabilityEmailError>
+    {
+        (**self).resend_contact_reachability_email(input)
+    }
+    fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> RusotoFuture<RetrieveDomainAuthCodeResponse, RetrieveDomainAuthCodeError> {
+        (**self).retrieve_domain_auth_code(input)
+    }
+    fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> RusotoFuture<TransferDomainResponse, TransferDomainError> {
+        (**self).transfer_domain(input)
+    }
+    fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> RusotoFuture<UpdateDomainContactResponse, UpdateDomainContactError> {
+        (**self).update_domain_contact(input)
+    }
+    fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> RusotoFuture<UpdateDomainContactPrivacyResponse, UpdateDomainContactPrivacyError> {
+        (**self).update_domain_contact_privacy(input)
+    }
+    fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> RusotoFuture<UpdateDomainNameserversResponse, UpdateDomainNameserversError> {
+        (**self).update_domain_nameservers(input)
+    }
+    fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> RusotoFuture<UpdateTagsForDomainResponse, UpdateTagsForDomainError> {
+        (**self).update_tags_for_domain(input)
+    }
+    fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> RusotoFuture<ViewBillingResponse, ViewBillingError> {
+        (**self).view_billing(input)
+    }
+}
+impl<T: Route53Domains + ?Sized> Route53Domains for Box<T> {
+    fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> RusotoFuture<CheckDomainAvailabilityResponse, CheckDomainAvailabilityError> {
+        (**self).check_domain_availability(input)
+    }
+    fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> RusotoFuture<CheckDomainTransferabilityResponse, CheckDomainTransferabilityError> {
+        (**self).check_domain_transferability(input)
+    }
+    fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> RusotoFuture<DeleteTagsForDomainResponse, DeleteTagsForDomainError> {
+        (**self).delete_tags_for_domain(input)
+    }
+    fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> RusotoFuture<DisableDomainAutoRenewResponse, DisableDomainAutoRenewError> {
+        (**self).disable_domain_auto_renew(input)
+    }
+    fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> RusotoFuture<DisableDomainTransferLockResponse, DisableDomainTransferLockError> {
+        (**self).disable_domain_transfer_lock(input)
+    }
+    fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> RusotoFuture<EnableDomainAutoRenewResponse, EnableDomainAutoRenewError> {
+        (**self).enable_domain_auto_renew(input)
+    }
+    fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> RusotoFuture<EnableDomainTransferLockResponse, EnableDomainTransferLockError> {
+        (**self).enable_domain_transfer_lock(input)
+    }
+    fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> RusotoFuture<GetContactReachabilityStatusResponse, GetContactReachabilityStatusError> {
+        (**self).get_contact_reachability_status(input)
+    }
+    fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> RusotoFuture<GetDomainDetailResponse, GetDomainDetailError> {
+        (**self).get_domain_detail(input)
+    }
+    fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> RusotoFuture<GetDomainSuggestionsResponse, GetDomainSuggestionsError> {
+        (**self).get_domain_suggestions(input)
+    }
+    fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> RusotoFuture<GetOperationDetailResponse, GetOperationDetailError> {
+        (**self).get_operation_detail(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> RusotoFuture<ListTagsForDomainResponse, ListTagsForDomainError> {
+        (**self).list_tags_for_domain(input)
+    }
+    fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> RusotoFuture<RegisterDomainResponse, RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> RusotoFuture<RenewDomainResponse, RenewDomainError> {
+        (**self).renew_domain(input)
+    }
+    fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> RusotoFuture<ResendContactReachabilityEmailResponse, ResendContactReachabilityEmailError>
+    {
+        (**self).resend_contact_reachability_email(input)
+    }
+    fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> RusotoFuture<RetrieveDomainAuthCodeResponse, RetrieveDomainAuthCodeError> {
+        (**self).retrieve_domain_auth_code(input)
+    }
+    fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> RusotoFuture<TransferDomainResponse, TransferDomainError> {
+        (**self).transfer_domain(input)
+    }
+    fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> RusotoFuture<UpdateDomainContactResponse, UpdateDomainContactError> {
+        (**self).update_domain_contact(input)
+    }
+    fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> RusotoFuture<UpdateDomainContactPrivacyResponse, UpdateDomainContactPrivacyError> {
+        (**self).update_domain_contact_privacy(input)
+    }
+    fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> RusotoFuture<UpdateDomainNameserversResponse, UpdateDomainNameserversError> {
+        (**self).update_domain_nameservers(input)
+    }
+    fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> RusotoFuture<UpdateTagsForDomainResponse, UpdateTagsForDomainError> {
+        (**self).update_tags_for_domain(input)
+    }
+    fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> RusotoFuture<ViewBillingResponse, ViewBillingError> {
+        (**self).view_billing(input)
+    }
+}
+impl<T: Route53Domains + ?Sized> Route53Domains for ::std::sync::Arc<T> {
+    fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> RusotoFuture<CheckDomainAvailabilityResponse, CheckDomainAvailabilityError> {
+        (**self).check_domain_availability(input)
+    }
+    fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> RusotoFuture<CheckDomainTransferabilityResponse, CheckDomainTransferabilityError> {
+        (**self).check_domain_transferability(input)
+    }
+    fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> RusotoFuture<DeleteTagsForDomainResponse, DeleteTagsForDomainError> {
+        (**self).delete_tags_for_domain(input)
+    }
+    fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> RusotoFuture<DisableDomainAutoRenewResponse, DisableDomainAutoRenewError> {
+        (**self).disable_domain_auto_renew(input)
+    }
+    fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> RusotoFuture<DisableDomainTransferLockResponse, DisableDomainTransferLockError> {
+        (**self).disable_domain_transfer_lock(input)
+    }
+    fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> RusotoFuture<EnableDomainAutoRenewResponse, EnableDomainAutoRenewError> {
+        (**self).enable_domain_auto_renew(input)
+    }
+    fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> RusotoFuture<EnableDomainTransferLockResponse, EnableDomainTransferLockError> {
+        (**self).enable_domain_transfer_lock(input)
+    }
+    fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> RusotoFuture<GetContactReachabilityStatusResponse, GetContactReachabilityStatusError> {
+        (**self).get_contact_reachability_status(input)
+    }
+    fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> RusotoFuture<GetDomainDetailResponse, GetDomainDetailError> {
+        (**self).get_domain_detail(input)
+    }
+    fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> RusotoFuture<GetDomainSuggestionsResponse, GetDomainSuggestionsError> {
+        (**self).get_domain_suggestions(input)
+    }
+    fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> RusotoFuture<GetOperationDetailResponse, GetOperationDetailError> {
+        (**self).get_operation_detail(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> RusotoFuture<ListTagsForDomainResponse, ListTagsForDomainError> {
+        (**self).list_tags_for_domain(input)
+    }
+    fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> RusotoFuture<RegisterDomainResponse, RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> RusotoFuture<RenewDomainResponse, RenewDomainError> {
+        (**self).renew_domain(input)
+    }
+    fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> RusotoFuture<ResendContactReachabilityEmailResponse, ResendContactReachabilityEmailError>
+    {
+        (**self).resend_contact_reachability_email(input)
+    }
+    fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> RusotoFuture<RetrieveDomainAuthCodeResponse, RetrieveDomainAuthCodeError> {
+        (**self).retrieve_domain_auth_code(input)
+    }
+    fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> RusotoFuture<TransferDomainResponse, TransferDomainError> {
+        (**self).transfer_domain(input)
+    }
+    fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> RusotoFuture<UpdateDomainContactResponse, UpdateDomainContactError> {
+        (**self).update_domain_contact(input)
+    }
+    fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> RusotoFuture<UpdateDomainContactPrivacyResponse, UpdateDomainContactPrivacyError> {
+        (**self).update_domain_contact_privacy(input)
+    }
+    fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> RusotoFuture<UpdateDomainNameserversResponse, UpdateDomainNameserversError> {
+        (**self).update_domain_nameservers(input)
+    }
+    fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> RusotoFuture<UpdateTagsForDomainResponse, UpdateTagsForDomainError> {
+        (**self).update_tags_for_domain(input)
+    }
+    fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> RusotoFuture<ViewBillingResponse, ViewBillingError> {
+        (**self).view_billing(input)
+    }
+}
+/// A client for the Amazon Route 53 Domains API.
+#[derive(Clone)]
+pub struct Route53DomainsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl Route53DomainsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Route53DomainsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53DomainsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsClient {
+        Route53DomainsClient { client, region }
+    }
+}
+
+impl Route53Domains for Route53DomainsClient {
+    /// <p>This operation checks the availability of one domain name. Note that if the availability status of a domain is pending, you must submit another request to determine the availability of the domain name.</p>
+    fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> RusotoFuture<CheckDomainAvailabilityResponse, CheckDomainAvailabilityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("CheckDomainAvailability");
+
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "Route53Domains_v20140515.DeleteTagsForDomain",
+            "Route53Domains_v20140515.CheckDomainAvailability",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<CheckDomainAvailabilityResponse, _>(
+                    response,
+                    "CheckDomainAvailability",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTagsForDomainResponse, _>()
+                    Err(CheckDomainAvailabilityError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Checks whether a domain name can be transferred to Amazon Route 53. </p>
+    fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> RusotoFuture<CheckDomainTransferabilityResponse, CheckDomainTransferabilityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("CheckDomainTransferability");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Route53Domains_v20140515.CheckDomainTransferability",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CheckDomainTransferabilityResponse, _>(
+                    response,
+                    "CheckDomainTransferability",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CheckDomainTransferabilityError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>This operation deletes the specified tags for a domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> RusotoFuture<DeleteTagsForDomainResponse, DeleteTagsForDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DeleteTagsForDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Route53Domains_v20140515.DeleteTagsForDomain",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTagsForDomainResponse, _>(
+                    response,
+                    "DeleteTagsForDomain",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2342,7 +3489,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: DisableDomainAutoRenewRequest,
     ) -> RusotoFuture<DisableDomainAutoRenewResponse, DisableDomainAutoRenewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DisableDomainAutoRenew");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2354,10 +3510,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableDomainAutoRenewResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DisableDomainAutoRenewResponse, _>(
+                    response,
+                    "DisableDomainAutoRenew",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2373,7 +3529,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: DisableDomainTransferLockRequest,
     ) -> RusotoFuture<DisableDomainTransferLockResponse, DisableDomainTransferLockError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("DisableDomainTransferLock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2385,10 +3550,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableDomainTransferLockResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DisableDomainTransferLockResponse, _>(
+                    response,
+                    "DisableDomainTransferLock",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DisableDomainTransferLockError::from_response(response))
@@ -2402,7 +3567,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: EnableDomainAutoRenewRequest,
     ) -> RusotoFuture<EnableDomainAutoRenewResponse, EnableDomainAutoRenewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("EnableDomainAutoRenew");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2414,10 +3588,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableDomainAutoRenewResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<EnableDomainAutoRenewResponse, _>(
+                    response,
+                    "EnableDomainAutoRenew",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2433,7 +3607,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: EnableDomainTransferLockRequest,
     ) -> RusotoFuture<EnableDomainTransferLockResponse, EnableDomainTransferLockError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("EnableDomainTransferLock");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2445,10 +3628,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableDomainTransferLockResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<EnableDomainTransferLockResponse, _>(
+                    response,
+                    "EnableDomainTransferLock",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(EnableDomainTransferLockError::from_response(response))
@@ -2462,7 +3645,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: GetContactReachabilityStatusRequest,
     ) -> RusotoFuture<GetContactReachabilityStatusResponse, GetContactReachabilityStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetContactReachabilityStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2474,10 +3666,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetContactReachabilityStatusResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetContactReachabilityStatusResponse, _>(
+                    response,
+                    "GetContactReachabilityStatus",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetContactReachabilityStatusError::from_response(response))
@@ -2491,7 +3683,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: GetDomainDetailRequest,
     ) -> RusotoFuture<GetDomainDetailResponse, GetDomainDetailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetDomainDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.GetDomainDetail");
@@ -2500,10 +3701,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDomainDetailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDomainDetailResponse, _>(
+                    response,
+                    "GetDomainDetail",
+                )
             } else {
                 Box::new(
                     response
@@ -2520,7 +3721,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: GetDomainSuggestionsRequest,
     ) -> RusotoFuture<GetDomainSuggestionsResponse, GetDomainSuggestionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetDomainSuggestions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2532,10 +3742,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDomainSuggestionsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDomainSuggestionsResponse, _>(
+                    response,
+                    "GetDomainSuggestions",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2551,7 +3761,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: GetOperationDetailRequest,
     ) -> RusotoFuture<GetOperationDetailResponse, GetOperationDetailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("GetOperationDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2563,10 +3782,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOperationDetailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetOperationDetailResponse, _>(
+                    response,
+                    "GetOperationDetail",
+                )
             } else {
                 Box::new(
                     response
@@ -2583,7 +3802,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: ListDomainsRequest,
     ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListDomains");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListDomains");
@@ -2592,10 +3820,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDomainsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListDomainsResponse, _>(
+                    response,
+                    "ListDomains",
+                )
             } else {
                 Box::new(
                     response
@@ -2612,7 +3840,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: ListOperationsRequest,
     ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListOperations");
@@ -2621,10 +3858,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOperationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListOperationsResponse, _>(
+                    response,
+                    "ListOperations",
+                )
             } else {
                 Box::new(
                     response
@@ -2641,7 +3878,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: ListTagsForDomainRequest,
     ) -> RusotoFuture<ListTagsForDomainResponse, ListTagsForDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ListTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListTagsForDomain");
@@ -2650,10 +3896,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForDomainResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForDomainResponse, _>(
+                    response,
+                    "ListTagsForDomain",
+                )
             } else {
                 Box::new(
                     response
@@ -2670,7 +3916,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: RegisterDomainRequest,
     ) -> RusotoFuture<RegisterDomainResponse, RegisterDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RegisterDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.RegisterDomain");
@@ -2679,10 +3934,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterDomainResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RegisterDomainResponse, _>(
+                    response,
+                    "RegisterDomain",
+                )
             } else {
                 Box::new(
                     response
@@ -2699,7 +3954,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: RenewDomainRequest,
     ) -> RusotoFuture<RenewDomainResponse, RenewDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RenewDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.RenewDomain");
@@ -2708,10 +3972,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RenewDomainResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RenewDomainResponse, _>(
+                    response,
+                    "RenewDomain",
+                )
             } else {
                 Box::new(
                     response
@@ -2729,7 +3993,16 @@ impl Route53Domains for Route53DomainsClient {
         input: ResendContactReachabilityEmailRequest,
     ) -> RusotoFuture<ResendContactReachabilityEmailResponse, ResendContactReachabilityEmailError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ResendContactReachabilityEmail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2741,10 +4014,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResendContactReachabilityEmailResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ResendContactReachabilityEmailResponse, _>(
+                    response,
+                    "ResendContactReachabilityEmail",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(ResendContactReachabilityEmailError::from_response(response))
@@ -2758,7 +4031,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: RetrieveDomainAuthCodeRequest,
     ) -> RusotoFuture<RetrieveDomainAuthCodeResponse, RetrieveDomainAuthCodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("RetrieveDomainAuthCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2770,10 +4052,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RetrieveDomainAuthCodeResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RetrieveDomainAuthCodeResponse, _>(
+                    response,
+                    "RetrieveDomainAuthCode",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2789,7 +4071,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: TransferDomainRequest,
     ) -> RusotoFuture<TransferDomainResponse, TransferDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("TransferDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.TransferDomain");
@@ -2798,10 +4089,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TransferDomainResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TransferDomainResponse, _>(
+                    response,
+                    "TransferDomain",
+                )
             } else {
                 Box::new(
                     response
@@ -2818,7 +4109,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: UpdateDomainContactRequest,
     ) -> RusotoFuture<UpdateDomainContactResponse, UpdateDomainContactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2830,10 +4130,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainContactResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateDomainContactResponse, _>(
+                    response,
+                    "UpdateDomainContact",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2849,7 +4149,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: UpdateDomainContactPrivacyRequest,
     ) -> RusotoFuture<UpdateDomainContactPrivacyResponse, UpdateDomainContactPrivacyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainContactPrivacy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2861,10 +4170,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainContactPrivacyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateDomainContactPrivacyResponse, _>(
+                    response,
+                    "UpdateDomainContactPrivacy",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateDomainContactPrivacyError::from_response(response))
@@ -2878,7 +4187,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: UpdateDomainNameserversRequest,
     ) -> RusotoFuture<UpdateDomainNameserversResponse, UpdateDomainNameserversError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateDomainNameservers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2890,10 +4208,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainNameserversResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateDomainNameserversResponse, _>(
+                    response,
+                    "UpdateDomainNameservers",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateDomainNameserversError::from_response(response))
@@ -2907,7 +4225,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: UpdateTagsForDomainRequest,
     ) -> RusotoFuture<UpdateTagsForDomainResponse, UpdateTagsForDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("UpdateTagsForDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2919,10 +4246,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTagsForDomainResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateTagsForDomainResponse, _>(
+                    response,
+                    "UpdateTagsForDomain",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2938,7 +4265,16 @@ impl Route53Domains for Route53DomainsClient {
         &self,
         input: ViewBillingRequest,
     ) -> RusotoFuture<ViewBillingResponse, ViewBillingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "route53domains", &self.region, "/");
+        request.set_operation("ViewBilling");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53Domains_v20140515.ViewBilling");
@@ -2947,10 +4283,10 @@ impl Route53Domains for Route53DomainsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ViewBillingResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ViewBillingResponse, _>(
+                    response,
+                    "ViewBilling",
+                )
             } else {
                 Box::new(
                     response
@@ -2962,3 +4298,858 @@ impl Route53Domains for Route53DomainsClient {
         })
     }
 }
+
+/// A mock of the Amazon Route 53 Domains API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockRoute53Domains {
+    check_domain_availability: Option<
+        Box<
+            dyn Fn(
+                    CheckDomainAvailabilityRequest,
+                ) -> ::std::result::Result<
+                    CheckDomainAvailabilityResponse,
+                    RusotoError<CheckDomainAvailabilityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    check_domain_transferability: Option<
+        Box<
+            dyn Fn(
+                    CheckDomainTransferabilityRequest,
+                ) -> ::std::result::Result<
+                    CheckDomainTransferabilityResponse,
+                    RusotoError<CheckDomainTransferabilityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tags_for_domain: Option<
+        Box<
+            dyn Fn(
+                    DeleteTagsForDomainRequest,
+                ) -> ::std::result::Result<
+                    DeleteTagsForDomainResponse,
+                    RusotoError<DeleteTagsForDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_domain_auto_renew: Option<
+        Box<
+            dyn Fn(
+                    DisableDomainAutoRenewRequest,
+                ) -> ::std::result::Result<
+                    DisableDomainAutoRenewResponse,
+                    RusotoError<DisableDomainAutoRenewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_domain_transfer_lock: Option<
+        Box<
+            dyn Fn(
+                    DisableDomainTransferLockRequest,
+                ) -> ::std::result::Result<
+                    DisableDomainTransferLockResponse,
+                    RusotoError<DisableDomainTransferLockError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_domain_auto_renew: Option<
+        Box<
+            dyn Fn(
+                    EnableDomainAutoRenewRequest,
+                ) -> ::std::result::Result<
+                    EnableDomainAutoRenewResponse,
+                    RusotoError<EnableDomainAutoRenewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_domain_transfer_lock: Option<
+        Box<
+            dyn Fn(
+                    EnableDomainTransferLockRequest,
+                ) -> ::std::result::Result<
+                    EnableDomainTransferLockResponse,
+                    RusotoError<EnableDomainTransferLockError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_contact_reachability_status: Option<
+        Box<
+            dyn Fn(
+                    GetContactReachabilityStatusRequest,
+                ) -> ::std::result::Result<
+                    GetContactReachabilityStatusResponse,
+                    RusotoError<GetContactReachabilityStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_domain_detail: Option<
+        Box<
+            dyn Fn(
+                    GetDomainDetailRequest,
+                ) -> ::std::result::Result<
+                    GetDomainDetailResponse,
+                    RusotoError<GetDomainDetailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_domain_suggestions: Option<
+        Box<
+            dyn Fn(
+                    GetDomainSuggestionsRequest,
+                ) -> ::std::result::Result<
+                    GetDomainSuggestionsResponse,
+                    RusotoError<GetDomainSuggestionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_operation_detail: Option<
+        Box<
+            dyn Fn(
+                    GetOperationDetailRequest,
+                ) -> ::std::result::Result<
+                    GetOperationDetailResponse,
+                    RusotoError<GetOperationDetailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_domains: Option<
+        Box<
+            dyn Fn(
+                    ListDomainsRequest,
+                )
+                    -> ::std::result::Result<ListDomainsResponse, RusotoError<ListDomainsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_operations: Option<
+        Box<
+            dyn Fn(
+                    ListOperationsRequest,
+                ) -> ::std::result::Result<
+                    ListOperationsResponse,
+                    RusotoError<ListOperationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_domain: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForDomainRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForDomainResponse,
+                    RusotoError<ListTagsForDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_domain: Option<
+        Box<
+            dyn Fn(
+                    RegisterDomainRequest,
+                ) -> ::std::result::Result<
+                    RegisterDomainResponse,
+                    RusotoError<RegisterDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    renew_domain: Option<
+        Box<
+            dyn Fn(
+                    RenewDomainRequest,
+                )
+                    -> ::std::result::Result<RenewDomainResponse, RusotoError<RenewDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    resend_contact_reachability_email: Option<
+        Box<
+            dyn Fn(
+                    ResendContactReachabilityEmailRequest,
+                ) -> ::std::result::Result<
+                    ResendContactReachabilityEmailResponse,
+                    RusotoError<ResendContactReachabilityEmailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    retrieve_domain_auth_code: Option<
+        Box<
+            dyn Fn(
+                    RetrieveDomainAuthCodeRequest,
+                ) -> ::std::result::Result<
+                    RetrieveDomainAuthCodeResponse,
+                    RusotoError<RetrieveDomainAuthCodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    transfer_domain: Option<
+        Box<
+            dyn Fn(
+                    TransferDomainRequest,
+                ) -> ::std::result::Result<
+                    TransferDomainResponse,
+                    RusotoError<TransferDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_domain_contact: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainContactRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainContactResponse,
+                    RusotoError<UpdateDomainContactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_domain_contact_privacy: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainContactPrivacyRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainContactPrivacyResponse,
+                    RusotoError<UpdateDomainContactPrivacyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_domain_nameservers: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainNameserversRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainNameserversResponse,
+                    RusotoError<UpdateDomainNameserversError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_tags_for_domain: Option<
+        Box<
+            dyn Fn(
+                    UpdateTagsForDomainRequest,
+                ) -> ::std::result::Result<
+                    UpdateTagsForDomainResponse,
+                    RusotoError<UpdateTagsForDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    view_billing: Option<
+        Box<
+            dyn Fn(
+                    ViewBillingRequest,
+                )
+                    -> ::std::result::Result<ViewBillingResponse, RusotoError<ViewBillingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockRoute53Domains {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockRoute53Domains {
+        Default::default()
+    }
+
+    /// Answers `check_domain_availability` with what `handler` returns for its input.
+    pub fn with_check_domain_availability<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                CheckDomainAvailabilityRequest,
+            ) -> ::std::result::Result<
+                CheckDomainAvailabilityResponse,
+                RusotoError<CheckDomainAvailabilityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.check_domain_availability = Some(Box::new(handler));
+        self
+    }
+    /// Answers `check_domain_transferability` with what `handler` returns for its input.
+    pub fn with_check_domain_transferability<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                CheckDomainTransferabilityRequest,
+            ) -> ::std::result::Result<
+                CheckDomainTransferabilityResponse,
+                RusotoError<CheckDomainTransferabilityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.check_domain_transferability = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags_for_domain` with what `handler` returns for its input.
+    pub fn with_delete_tags_for_domain<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                DeleteTagsForDomainRequest,
+            ) -> ::std::result::Result<
+                DeleteTagsForDomainResponse,
+                RusotoError<DeleteTagsForDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags_for_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_domain_auto_renew` with what `handler` returns for its input.
+    pub fn with_disable_domain_auto_renew<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                DisableDomainAutoRenewRequest,
+            ) -> ::std::result::Result<
+                DisableDomainAutoRenewResponse,
+                RusotoError<DisableDomainAutoRenewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_domain_auto_renew = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_domain_transfer_lock` with what `handler` returns for its input.
+    pub fn with_disable_domain_transfer_lock<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                DisableDomainTransferLockRequest,
+            ) -> ::std::result::Result<
+                DisableDomainTransferLockResponse,
+                RusotoError<DisableDomainTransferLockError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_domain_transfer_lock = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_domain_auto_renew` with what `handler` returns for its input.
+    pub fn with_enable_domain_auto_renew<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                EnableDomainAutoRenewRequest,
+            ) -> ::std::result::Result<
+                EnableDomainAutoRenewResponse,
+                RusotoError<EnableDomainAutoRenewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_domain_auto_renew = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_domain_transfer_lock` with what `handler` returns for its input.
+    pub fn with_enable_domain_transfer_lock<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                EnableDomainTransferLockRequest,
+            ) -> ::std::result::Result<
+                EnableDomainTransferLockResponse,
+                RusotoError<EnableDomainTransferLockError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_domain_transfer_lock = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_contact_reachability_status` with what `handler` returns for its input.
+    pub fn with_get_contact_reachability_status<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                GetContactReachabilityStatusRequest,
+            ) -> ::std::result::Result<
+                GetContactReachabilityStatusResponse,
+                RusotoError<GetContactReachabilityStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_contact_reachability_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_detail` with what `handler` returns for its input.
+    pub fn with_get_domain_detail<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                GetDomainDetailRequest,
+            )
+                -> ::std::result::Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_detail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_domain_suggestions` with what `handler` returns for its input.
+    pub fn with_get_domain_suggestions<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                GetDomainSuggestionsRequest,
+            ) -> ::std::result::Result<
+                GetDomainSuggestionsResponse,
+                RusotoError<GetDomainSuggestionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_domain_suggestions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_operation_detail` with what `handler` returns for its input.
+    pub fn with_get_operation_detail<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                GetOperationDetailRequest,
+            ) -> ::std::result::Result<
+                GetOperationDetailResponse,
+                RusotoError<GetOperationDetailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_operation_detail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_domains` with what `handler` returns for its input.
+    pub fn with_list_domains<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                ListDomainsRequest,
+            )
+                -> ::std::result::Result<ListDomainsResponse, RusotoError<ListDomainsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_domains = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_operations` with what `handler` returns for its input.
+    pub fn with_list_operations<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                ListOperationsRequest,
+            )
+                -> ::std::result::Result<ListOperationsResponse, RusotoError<ListOperationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_operations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_domain` with what `handler` returns for its input.
+    pub fn with_list_tags_for_domain<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                ListTagsForDomainRequest,
+            ) -> ::std::result::Result<
+                ListTagsForDomainResponse,
+                RusotoError<ListTagsForDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_domain` with what `handler` returns for its input.
+    pub fn with_register_domain<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                RegisterDomainRequest,
+            )
+                -> ::std::result::Result<RegisterDomainResponse, RusotoError<RegisterDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `renew_domain` with what `handler` returns for its input.
+    pub fn with_renew_domain<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                RenewDomainRequest,
+            )
+                -> ::std::result::Result<RenewDomainResponse, RusotoError<RenewDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.renew_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resend_contact_reachability_email` with what `handler` returns for its input.
+    pub fn with_resend_contact_reachability_email<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                ResendContactReachabilityEmailRequest,
+            ) -> ::std::result::Result<
+                ResendContactReachabilityEmailResponse,
+                RusotoError<ResendContactReachabilityEmailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.resend_contact_reachability_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `retrieve_domain_auth_code` with what `handler` returns for its input.
+    pub fn with_retrieve_domain_auth_code<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                RetrieveDomainAuthCodeRequest,
+            ) -> ::std::result::Result<
+                RetrieveDomainAuthCodeResponse,
+                RusotoError<RetrieveDomainAuthCodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.retrieve_domain_auth_code = Some(Box::new(handler));
+        self
+    }
+    /// Answers `transfer_domain` with what `handler` returns for its input.
+    pub fn with_transfer_domain<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                TransferDomainRequest,
+            )
+                -> ::std::result::Result<TransferDomainResponse, RusotoError<TransferDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.transfer_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_contact` with what `handler` returns for its input.
+    pub fn with_update_domain_contact<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                UpdateDomainContactRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainContactResponse,
+                RusotoError<UpdateDomainContactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_contact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_contact_privacy` with what `handler` returns for its input.
+    pub fn with_update_domain_contact_privacy<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                UpdateDomainContactPrivacyRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainContactPrivacyResponse,
+                RusotoError<UpdateDomainContactPrivacyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_contact_privacy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_nameservers` with what `handler` returns for its input.
+    pub fn with_update_domain_nameservers<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                UpdateDomainNameserversRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainNameserversResponse,
+                RusotoError<UpdateDomainNameserversError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_nameservers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_tags_for_domain` with what `handler` returns for its input.
+    pub fn with_update_tags_for_domain<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                UpdateTagsForDomainRequest,
+            ) -> ::std::result::Result<
+                UpdateTagsForDomainResponse,
+                RusotoError<UpdateTagsForDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_tags_for_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `view_billing` with what `handler` returns for its input.
+    pub fn with_view_billing<F>(mut self, handler: F) -> MockRoute53Domains
+    where
+        F: Fn(
+                ViewBillingRequest,
+            )
+                -> ::std::result::Result<ViewBillingResponse, RusotoError<ViewBillingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.view_billing = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Route53Domains for MockRoute53Domains {
+    fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> RusotoFuture<CheckDomainAvailabilityResponse, CheckDomainAvailabilityError> {
+        match self.check_domain_availability {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for check_domain_availability"),
+        }
+    }
+    fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> RusotoFuture<CheckDomainTransferabilityResponse, CheckDomainTransferabilityError> {
+        match self.check_domain_transferability {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for check_domain_transferability"),
+        }
+    }
+    fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> RusotoFuture<DeleteTagsForDomainResponse, DeleteTagsForDomainError> {
+        match self.delete_tags_for_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for delete_tags_for_domain"),
+        }
+    }
+    fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> RusotoFuture<DisableDomainAutoRenewResponse, DisableDomainAutoRenewError> {
+        match self.disable_domain_auto_renew {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for disable_domain_auto_renew"),
+        }
+    }
+    fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> RusotoFuture<DisableDomainTransferLockResponse, DisableDomainTransferLockError> {
+        match self.disable_domain_transfer_lock {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for disable_domain_transfer_lock"),
+        }
+    }
+    fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> RusotoFuture<EnableDomainAutoRenewResponse, EnableDomainAutoRenewError> {
+        match self.enable_domain_auto_renew {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for enable_domain_auto_renew"),
+        }
+    }
+    fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> RusotoFuture<EnableDomainTransferLockResponse, EnableDomainTransferLockError> {
+        match self.enable_domain_transfer_lock {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for enable_domain_transfer_lock"),
+        }
+    }
+    fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> RusotoFuture<GetContactReachabilityStatusResponse, GetContactReachabilityStatusError> {
+        match self.get_contact_reachability_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for get_contact_reachability_status"),
+        }
+    }
+    fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> RusotoFuture<GetDomainDetailResponse, GetDomainDetailError> {
+        match self.get_domain_detail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for get_domain_detail"),
+        }
+    }
+    fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> RusotoFuture<GetDomainSuggestionsResponse, GetDomainSuggestionsError> {
+        match self.get_domain_suggestions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for get_domain_suggestions"),
+        }
+    }
+    fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> RusotoFuture<GetOperationDetailResponse, GetOperationDetailError> {
+        match self.get_operation_detail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for get_operation_detail"),
+        }
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        match self.list_domains {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for list_domains"),
+        }
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        match self.list_operations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for list_operations"),
+        }
+    }
+    fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> RusotoFuture<ListTagsForDomainResponse, ListTagsForDomainError> {
+        match self.list_tags_for_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for list_tags_for_domain"),
+        }
+    }
+    fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> RusotoFuture<RegisterDomainResponse, RegisterDomainError> {
+        match self.register_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for register_domain"),
+        }
+    }
+    fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> RusotoFuture<RenewDomainResponse, RenewDomainError> {
+        match self.renew_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for renew_domain"),
+        }
+    }
+    fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> RusotoFuture<ResendContactReachabilityEmailResponse, ResendContactReachabilityEmailError>
+    {
+        match self.resend_contact_reachability_email {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockRoute53Domains has no handler for resend_contact_reachability_email")
+            }
+        }
+    }
+    fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> RusotoFuture<RetrieveDomainAuthCodeResponse, RetrieveDomainAuthCodeError> {
+        match self.retrieve_domain_auth_code {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for retrieve_domain_auth_code"),
+        }
+    }
+    fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> RusotoFuture<TransferDomainResponse, TransferDomainError> {
+        match self.transfer_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for transfer_domain"),
+        }
+    }
+    fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> RusotoFuture<UpdateDomainContactResponse, UpdateDomainContactError> {
+        match self.update_domain_contact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for update_domain_contact"),
+        }
+    }
+    fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> RusotoFuture<UpdateDomainContactPrivacyResponse, UpdateDomainContactPrivacyError> {
+        match self.update_domain_contact_privacy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for update_domain_contact_privacy"),
+        }
+    }
+    fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> RusotoFuture<UpdateDomainNameserversResponse, UpdateDomainNameserversError> {
+        match self.update_domain_nameservers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for update_domain_nameservers"),
+        }
+    }
+    fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> RusotoFuture<UpdateTagsForDomainResponse, UpdateTagsForDomainError> {
+        match self.update_tags_for_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for update_tags_for_domain"),
+        }
+    }
+    fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> RusotoFuture<ViewBillingResponse, ViewBillingError> {
+        match self.view_billing {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockRoute53Domains has no handler for view_billing"),
+        }
+    }
+}
@@ -0,0 +1,413 @@
+//! Requests to S3 access points, addressed by their ARNs.
+//!
+//! An access point, an Object Lambda access point or an Outposts access point is named by
+//! its ARN in place of a bucket name, as the `bucket` of the input of object operations:
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_s3::{GetObjectRequest, S3Client, S3};
+//!
+//! let client = S3Client::new(Region::UsWest2);
+//! let request = GetObjectRequest {
+//!     bucket: "arn:aws:s3:us-west-2:123456789012:accesspoint/reports".to_owned(),
+//!     key: "2019/summary.csv".to_owned(),
+//!     ..Default::default()
+//! };
+//! let object = client.get_object(request).sync().unwrap();
+//! ```
+//!
+//! Requests to access points are sent to the endpoint of the access point, such as
+//! `reports-123456789012.s3-accesspoint.us-west-2.amazonaws.com`, rather than to the
+//! bucket's path, and requests to Object Lambda and Outposts access points are signed
+//! for `s3-object-lambda` and `s3-outposts`. A client only sends requests to the access
+//! points of its own region: those to another region, or another partition, fail with
+//! an [`AccessPointError`](enum.AccessPointError.html) before being sent, as do calls
+//! to operations access points don't take, such as `DeleteBucket`.
+//!
+//! Clients of custom regions prefix the hostname of their endpoint with that of the
+//! access point, which endpoints only taking path-style requests, such as those
+//! addressed by IP, can't serve.
+
+use std::error::Error;
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use rusoto_core::arn::Arn;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::ValidationError;
+use rusoto_core::{Region, RusotoError};
+
+/// The operations on buckets, rather than objects, which access points take.
+const BUCKET_OPERATIONS: &[&str] = &["ListMultipartUploads", "ListObjects", "ListObjectsV2"];
+
+/// The operations Object Lambda access points take.
+const OBJECT_LAMBDA_OPERATIONS: &[&str] =
+    &["GetObject", "HeadObject", "ListObjects", "ListObjectsV2"];
+
+/// The kind of access point an ARN names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessPointKind {
+    /// An access point, `arn:aws:s3:region:account-id:accesspoint/name`.
+    Standard,
+    /// An Object Lambda access point,
+    /// `arn:aws:s3-object-lambda:region:account-id:accesspoint/name`.
+    ObjectLambda,
+    /// An access point of an outpost,
+    /// `arn:aws:s3-outposts:region:account-id:outpost/outpost-id/accesspoint/name`.
+    Outposts {
+        /// The ID of the outpost, such as `op-01234567890123456`.
+        outpost_id: String,
+    },
+}
+
+/// The ARN of an access point, parsed from a bucket name.
+///
+/// ```rust
+/// use rusoto_core::Region;
+/// use rusoto_s3::access_points::AccessPointArn;
+///
+/// let arn: AccessPointArn = "arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/redacted"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(arn.name(), "redacted");
+/// assert_eq!(arn.signing_service(), "s3-object-lambda");
+/// assert_eq!(
+///     arn.endpoint(&Region::UsWest2).unwrap(),
+///     "redacted-123456789012.s3-object-lambda.us-west-2.amazonaws.com"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessPointArn {
+    partition: String,
+    region: String,
+    account_id: String,
+    name: String,
+    kind: AccessPointKind,
+}
+
+impl AccessPointArn {
+    /// The partition of the access point, such as `aws` or `aws-cn`.
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The region of the access point.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The ID of the account owning the access point.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The name of the access point.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The kind of the access point.
+    pub fn kind(&self) -> &AccessPointKind {
+        &self.kind
+    }
+
+    /// The service requests to the access point are signed for.
+    pub fn signing_service(&self) -> &'static str {
+        match self.kind {
+            AccessPointKind::Standard => "s3",
+            AccessPointKind::ObjectLambda => "s3-object-lambda",
+            AccessPointKind::Outposts { .. } => "s3-outposts",
+        }
+    }
+
+    /// The hostname requests of a client in `region` are sent to, failing if the access
+    /// point is in an unknown partition, in another region or partition, or if the
+    /// endpoint of a custom region only takes path-style requests.
+    pub fn endpoint(&self, region: &Region) -> Result<String, AccessPointError> {
+        if !PARTITIONS.iter().any(|p| p.0 == self.partition) {
+            return Err(AccessPointError::UnknownPartition {
+                partition: self.partition.clone(),
+            });
+        }
+        let client_partition = partition(region.name());
+        if self.partition != client_partition {
+            return Err(AccessPointError::CrossPartition {
+                arn_partition: self.partition.clone(),
+                client_partition: client_partition.to_owned(),
+            });
+        }
+        if self.region != region.name() {
+            return Err(AccessPointError::CrossRegion {
+                arn_region: self.region.clone(),
+                client_region: region.name().to_owned(),
+            });
+        }
+
+        let mut prefix = format!("{}-{}", self.name, self.account_id);
+        if let AccessPointKind::Outposts { ref outpost_id } = self.kind {
+            prefix = format!("{}.{}", prefix, outpost_id);
+        }
+        match *region {
+            Region::Custom { ref endpoint, .. } => {
+                let host = endpoint_host(endpoint);
+                if is_path_style_only(host) {
+                    return Err(AccessPointError::PathStyleOnly {
+                        endpoint: endpoint.clone(),
+                    });
+                }
+                Ok(format!("{}.{}", prefix, host))
+            }
+            _ => {
+                let service = match self.kind {
+                    AccessPointKind::Standard => "s3-accesspoint",
+                    AccessPointKind::ObjectLambda => "s3-object-lambda",
+                    AccessPointKind::Outposts { .. } => "s3-outposts",
+                };
+                let dns_suffix = if self.partition == "aws-cn" {
+                    "amazonaws.com.cn"
+                } else {
+                    "amazonaws.com"
+                };
+                Ok(format!(
+                    "{}.{}.{}.{}",
+                    prefix, service, self.region, dns_suffix
+                ))
+            }
+        }
+    }
+
+    /// Whether the access point takes `operation`, which acts on an object if
+    /// `on_object`.
+    fn supports(&self, operation: &str, on_object: bool) -> bool {
+        match self.kind {
+            AccessPointKind::ObjectLambda => OBJECT_LAMBDA_OPERATIONS.contains(&operation),
+            _ => on_object || BUCKET_OPERATIONS.contains(&operation),
+        }
+    }
+}
+
+impl FromStr for AccessPointArn {
+    type Err = AccessPointError;
+
+    fn from_str(s: &str) -> Result<AccessPointArn, AccessPointError> {
+        let invalid = |reason: &str| AccessPointError::InvalidArn {
+            arn: s.to_owned(),
+            reason: reason.to_owned(),
+        };
+        let arn: Arn = s.parse().map_err(|_| invalid("not an ARN"))?;
+        let resource: Vec<&str> = arn.resource().split(&['/', ':'][..]).collect();
+        let (kind, name) = match (arn.service(), &resource[..]) {
+            ("s3", ["accesspoint", name]) => (AccessPointKind::Standard, name),
+            ("s3-object-lambda", ["accesspoint", name]) => (AccessPointKind::ObjectLambda, name),
+            ("s3-outposts", ["outpost", outpost_id, "accesspoint", name]) => {
+                if !is_host_label(outpost_id) {
+                    return Err(invalid("the outpost ID isn't a valid host label"));
+                }
+                let outpost_id = (*outpost_id).to_owned();
+                (AccessPointKind::Outposts { outpost_id }, name)
+            }
+            _ => return Err(invalid("not the ARN of an access point")),
+        };
+        let region = arn
+            .region()
+            .ok_or_else(|| invalid("the region is missing"))?;
+        let account_id = arn
+            .account_id()
+            .ok_or_else(|| invalid("the account ID is missing"))?;
+        if !is_host_label(region) {
+            return Err(invalid("the region isn't a valid host label"));
+        }
+        if !is_host_label(account_id) {
+            return Err(invalid("the account ID isn't a valid host label"));
+        }
+        if !is_host_label(name) {
+            return Err(invalid("the access point name isn't a valid host label"));
+        }
+        Ok(AccessPointArn {
+            partition: arn.partition().to_owned(),
+            region: region.to_owned(),
+            account_id: account_id.to_owned(),
+            name: (*name).to_owned(),
+            kind,
+        })
+    }
+}
+
+/// An error produced when a request can't be sent to the access point named as its
+/// bucket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessPointError {
+    /// The bucket starts with `arn:` but isn't the ARN of an access point.
+    InvalidArn {
+        /// The bucket.
+        arn: String,
+        /// Why the bucket isn't the ARN of an access point.
+        reason: String,
+    },
+    /// The access point is in a partition whose endpoints aren't known.
+    UnknownPartition {
+        /// The partition of the access point.
+        partition: String,
+    },
+    /// The access point is in another partition than the region of the client.
+    CrossPartition {
+        /// The partition of the access point.
+        arn_partition: String,
+        /// The partition of the region of the client.
+        client_partition: String,
+    },
+    /// The access point is in another region than the client.
+    CrossRegion {
+        /// The region of the access point.
+        arn_region: String,
+        /// The region of the client.
+        client_region: String,
+    },
+    /// The operation doesn't take access points of the kind of the ARN.
+    UnsupportedOperation {
+        /// The operation, such as `DeleteBucket`.
+        operation: String,
+        /// The ARN of the access point.
+        arn: String,
+    },
+    /// The endpoint of the custom region of the client only takes path-style requests,
+    /// which can't be addressed to access points.
+    PathStyleOnly {
+        /// The endpoint of the region.
+        endpoint: String,
+    },
+}
+
+impl Error for AccessPointError {}
+
+impl fmt::Display for AccessPointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AccessPointError::InvalidArn {
+                ref arn,
+                ref reason,
+            } => write!(f, "Invalid access point ARN {}: {}", arn, reason),
+            AccessPointError::UnknownPartition { ref partition } => {
+                write!(f, "The access point is in unknown partition {}", partition)
+            }
+            AccessPointError::CrossPartition {
+                ref arn_partition,
+                ref client_partition,
+            } => write!(
+                f,
+                "The access point is in partition {}, but the client is in partition {}",
+                arn_partition, client_partition
+            ),
+            AccessPointError::CrossRegion {
+                ref arn_region,
+                ref client_region,
+            } => write!(
+                f,
+                "The access point is in region {}, but the client is in region {}",
+                arn_region, client_region
+            ),
+            AccessPointError::UnsupportedOperation {
+                ref operation,
+                ref arn,
+            } => write!(f, "{} doesn't take the access point {}", operation, arn),
+            AccessPointError::PathStyleOnly { ref endpoint } => write!(
+                f,
+                "Path-style requests to {} can't be addressed to access points",
+                endpoint
+            ),
+        }
+    }
+}
+
+impl<E> From<AccessPointError> for RusotoError<E> {
+    fn from(err: AccessPointError) -> Self {
+        RusotoError::Validation {
+            message: err.to_string(),
+            fields: vec![ValidationError {
+                field: "Bucket".to_owned(),
+                message: err.to_string(),
+            }],
+        }
+    }
+}
+
+/// Addresses `request` to the access point named by `bucket`, if it is an ARN, rather
+/// than to the path of a bucket.
+///
+/// The bucket is taken out of the path of the request, which starts with it.
+pub(crate) fn route(request: &mut SignedRequest, bucket: &str) -> Result<(), AccessPointError> {
+    if !bucket.starts_with("arn:") {
+        return Ok(());
+    }
+    let arn: AccessPointArn = bucket.parse()?;
+    let operation = request.operation().unwrap_or_default().to_owned();
+    if !arn.supports(&operation, request.resource_ids.contains_key("Key")) {
+        return Err(AccessPointError::UnsupportedOperation {
+            operation,
+            arn: bucket.to_owned(),
+        });
+    }
+
+    let hostname = arn.endpoint(&request.region)?;
+    request.set_hostname(Some(hostname));
+    request.service = arn.signing_service().to_owned();
+    let prefix = format!("/{}", bucket);
+    if request.path.starts_with(&prefix) {
+        request.path = request.path[prefix.len()..].to_owned();
+    }
+    if request.path.is_empty() {
+        request.path = "/".to_owned();
+    }
+    Ok(())
+}
+
+/// The partitions access points can be in, with the prefix of the names of their
+/// regions.
+///
+/// Clients only reach the ISO partitions through custom regions, whose endpoints are
+/// used as they are. `us-isob-` comes before `us-iso-`, which it starts with.
+const PARTITIONS: &[(&str, &str)] = &[
+    ("aws-cn", "cn-"),
+    ("aws-us-gov", "us-gov-"),
+    ("aws-iso-b", "us-isob-"),
+    ("aws-iso", "us-iso-"),
+    ("aws", ""),
+];
+
+/// The partition of the region named `region`.
+fn partition(region: &str) -> &'static str {
+    PARTITIONS
+        .iter()
+        .find(|p| region.starts_with(p.1))
+        .map_or("aws", |p| p.0)
+}
+
+/// The host, and port, of the endpoint of a custom region.
+fn endpoint_host(endpoint: &str) -> &str {
+    let authority = match endpoint.find("://") {
+        Some(scheme_end) => &endpoint[scheme_end + 3..],
+        None => endpoint,
+    };
+    authority.split('/').next().unwrap_or_default()
+}
+
+/// Whether `host` has no subdomains to address access points with, being an IP address
+/// or `localhost`.
+fn is_path_style_only(host: &str) -> bool {
+    // IPv6 addresses are bracketed
+    if host.starts_with('[') {
+        return true;
+    }
+    let name = host.split(':').next().unwrap_or_default();
+    name == "localhost" || name.parse::<Ipv4Addr>().is_ok()
+}
+
+/// Whether `label` can be a label of a hostname.
+fn is_host_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
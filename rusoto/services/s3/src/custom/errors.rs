@@ -0,0 +1,64 @@
+//! The codes of S3 errors, whichever operation returned them.
+//!
+//! The error type of each S3 operation only has variants for the few codes its
+//! documentation lists, so most errors, such as `AccessDenied` or `SlowDown`, end up as
+//! `RusotoError::Unknown` with the XML error response as their body.
+//! [`S3ErrorExt`](trait.S3ErrorExt.html) gives the code of either kind of error as an
+//! [`S3ErrorCode`](../enum.S3ErrorCode.html):
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_s3::errors::S3ErrorExt;
+//! use rusoto_s3::{GetObjectRequest, S3Client, S3ErrorCode, S3};
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let request = GetObjectRequest {
+//!     bucket: "my-bucket".to_owned(),
+//!     key: "missing".to_owned(),
+//!     ..Default::default()
+//! };
+//! match client.get_object(request).sync() {
+//!     Ok(_) => println!("found"),
+//!     Err(ref err) if err.s3_error_code() == Some(S3ErrorCode::NoSuchKey) => println!("missing"),
+//!     Err(err) => panic!("{}", err),
+//! }
+//! ```
+
+use std::fmt::Debug;
+
+use rusoto_core::proto::xml::error::XmlErrorDeserializer;
+use rusoto_core::proto::xml::util::{find_start_element, XmlResponse};
+use rusoto_core::RusotoError;
+use xml::reader::EventReader;
+
+use crate::generated::S3ErrorCode;
+
+/// The S3 error code of an error, whichever operation returned it.
+pub trait S3ErrorExt {
+    /// The code of the error, or `None` for errors which aren't S3 error responses, such
+    /// as failures to dispatch the request.
+    fn s3_error_code(&self) -> Option<S3ErrorCode>;
+}
+
+impl<E: Debug> S3ErrorExt for RusotoError<E> {
+    fn s3_error_code(&self) -> Option<S3ErrorCode> {
+        match *self {
+            // The variants of the operation error types are named after their codes.
+            RusotoError::Service(ref err) => {
+                let debug = format!("{:?}", err);
+                let name = debug.split('(').next().unwrap_or_default();
+                Some(S3ErrorCode::from_code(name))
+            }
+            RusotoError::Unknown(ref response) => {
+                let reader = EventReader::new(response.body.as_ref());
+                let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                find_start_element(&mut stack);
+                XmlErrorDeserializer::deserialize("Error", &mut stack)
+                    .ok()
+                    .filter(|err| !err.code.is_empty())
+                    .map(|err| S3ErrorCode::from_code(&err.code))
+            }
+            _ => None,
+        }
+    }
+}
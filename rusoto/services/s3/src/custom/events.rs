@@ -0,0 +1,463 @@
+//! Types for S3 event notifications.
+//!
+//! S3 publishes [event notifications](https://docs.aws.amazon.com/AmazonS3/latest/dev/notification-content-structure.html)
+//! to SQS queues, SNS topics and Lambda functions. The same JSON document is delivered in all
+//! cases, but it may be wrapped in an SNS or SQS envelope depending on how the consumer is wired
+//! up. [`parse_notification`](fn.parse_notification.html) accepts any of these shapes.
+//!
+//! Unknown fields are ignored so new attributes added by AWS don't break parsing.
+//!
+//! # Examples
+//!
+//! ```
+//! use rusoto_s3::events::{parse_notification, S3EventName, S3Notification};
+//!
+//! let body = r#"{"Records":[{"eventVersion":"2.1","eventSource":"aws:s3",
+//!     "eventName":"ObjectCreated:Put","s3":{"bucket":{"name":"my-bucket"},
+//!     "object":{"key":"photos/my+photo.jpg","size":1024}}}]}"#;
+//!
+//! match parse_notification(body).unwrap() {
+//!     S3Notification::Records(event) => {
+//!         let record = &event.records[0];
+//!         assert_eq!(record.event_name, S3EventName::ObjectCreatedPut);
+//!         assert_eq!(record.s3.object.decoded_key(), "photos/my photo.jpg");
+//!     }
+//!     S3Notification::Test(_) => unreachable!(),
+//! }
+//! ```
+
+use std::fmt;
+
+use rusoto_core::signature::decode_uri;
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use serde_json;
+
+/// Name of the event that triggered a notification, such as `ObjectCreated:Put`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum S3EventName {
+    /// `ObjectCreated:Put`
+    ObjectCreatedPut,
+    /// `ObjectCreated:Post`
+    ObjectCreatedPost,
+    /// `ObjectCreated:Copy`
+    ObjectCreatedCopy,
+    /// `ObjectCreated:CompleteMultipartUpload`
+    ObjectCreatedCompleteMultipartUpload,
+    /// `ObjectRemoved:Delete`
+    ObjectRemovedDelete,
+    /// `ObjectRemoved:DeleteMarkerCreated`
+    ObjectRemovedDeleteMarkerCreated,
+    /// `ObjectRestore:Post`
+    ObjectRestorePost,
+    /// `ObjectRestore:Completed`
+    ObjectRestoreCompleted,
+    /// `ReducedRedundancyLostObject`
+    ReducedRedundancyLostObject,
+    /// An event name not known to this version of rusoto.
+    Unknown(String),
+}
+
+impl S3EventName {
+    /// The event name as it appears in the notification.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3EventName::ObjectCreatedPut => "ObjectCreated:Put",
+            S3EventName::ObjectCreatedPost => "ObjectCreated:Post",
+            S3EventName::ObjectCreatedCopy => "ObjectCreated:Copy",
+            S3EventName::ObjectCreatedCompleteMultipartUpload => {
+                "ObjectCreated:CompleteMultipartUpload"
+            }
+            S3EventName::ObjectRemovedDelete => "ObjectRemoved:Delete",
+            S3EventName::ObjectRemovedDeleteMarkerCreated => "ObjectRemoved:DeleteMarkerCreated",
+            S3EventName::ObjectRestorePost => "ObjectRestore:Post",
+            S3EventName::ObjectRestoreCompleted => "ObjectRestore:Completed",
+            S3EventName::ReducedRedundancyLostObject => "ReducedRedundancyLostObject",
+            S3EventName::Unknown(ref name) => name,
+        }
+    }
+
+    /// Whether this is one of the `ObjectCreated:*` events.
+    pub fn is_object_created(&self) -> bool {
+        self.as_str().starts_with("ObjectCreated:")
+    }
+
+    /// Whether this is one of the `ObjectRemoved:*` events.
+    pub fn is_object_removed(&self) -> bool {
+        self.as_str().starts_with("ObjectRemoved:")
+    }
+
+    /// Whether this is one of the `ObjectRestore:*` events.
+    pub fn is_object_restore(&self) -> bool {
+        self.as_str().starts_with("ObjectRestore:")
+    }
+}
+
+impl Default for S3EventName {
+    fn default() -> S3EventName {
+        S3EventName::Unknown(String::new())
+    }
+}
+
+impl fmt::Display for S3EventName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for S3EventName {
+    fn from(s: &'a str) -> S3EventName {
+        match s {
+            "ObjectCreated:Put" => S3EventName::ObjectCreatedPut,
+            "ObjectCreated:Post" => S3EventName::ObjectCreatedPost,
+            "ObjectCreated:Copy" => S3EventName::ObjectCreatedCopy,
+            "ObjectCreated:CompleteMultipartUpload" => {
+                S3EventName::ObjectCreatedCompleteMultipartUpload
+            }
+            "ObjectRemoved:Delete" => S3EventName::ObjectRemovedDelete,
+            "ObjectRemoved:DeleteMarkerCreated" => S3EventName::ObjectRemovedDeleteMarkerCreated,
+            "ObjectRestore:Post" => S3EventName::ObjectRestorePost,
+            "ObjectRestore:Completed" => S3EventName::ObjectRestoreCompleted,
+            "ReducedRedundancyLostObject" => S3EventName::ReducedRedundancyLostObject,
+            other => S3EventName::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl Serialize for S3EventName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for S3EventName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        Ok(S3EventName::from(name.as_str()))
+    }
+}
+
+/// A batch of S3 event records, as delivered to SQS, SNS or Lambda.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3Event {
+    /// The event records. S3 currently sends one record per notification.
+    #[serde(rename = "Records", default)]
+    pub records: Vec<S3EventRecord>,
+}
+
+/// A single S3 event record.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3EventRecord {
+    /// Version of the notification format, e.g. `2.1`.
+    #[serde(rename = "eventVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_version: Option<String>,
+    /// Always `aws:s3`.
+    #[serde(rename = "eventSource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_source: Option<String>,
+    /// Region of the bucket the event originated from.
+    #[serde(rename = "awsRegion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    /// Time S3 finished processing the request, in ISO-8601 format.
+    #[serde(rename = "eventTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_time: Option<String>,
+    /// The event that triggered this notification.
+    #[serde(rename = "eventName")]
+    pub event_name: S3EventName,
+    /// Identity of the requester that caused the event.
+    #[serde(rename = "userIdentity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_identity: Option<S3UserIdentity>,
+    /// Parameters of the request that caused the event.
+    #[serde(rename = "requestParameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_parameters: Option<S3RequestParameters>,
+    /// Identifiers from the response to the request that caused the event.
+    #[serde(rename = "responseElements")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_elements: Option<S3ResponseElements>,
+    /// The bucket and object the event refers to.
+    #[serde(rename = "s3")]
+    pub s3: S3Entity,
+    /// Only present for `ObjectRestore:Completed` events.
+    #[serde(rename = "glacierEventData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glacier_event_data: Option<S3GlacierEventData>,
+}
+
+/// The principal that made a request.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3UserIdentity {
+    /// Amazon customer ID of the principal.
+    #[serde(rename = "principalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+}
+
+/// Parameters of the request that caused an event.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3RequestParameters {
+    /// IP address the request originated from.
+    #[serde(rename = "sourceIPAddress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_ip_address: Option<String>,
+}
+
+/// Identifiers useful when contacting AWS support about an event.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3ResponseElements {
+    /// Request ID of the request that caused the event.
+    #[serde(rename = "x-amz-request-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Host that processed the request.
+    #[serde(rename = "x-amz-id-2")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_2: Option<String>,
+}
+
+/// The S3 specific part of an event record.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3Entity {
+    /// Version of the `s3` element schema, e.g. `1.0`.
+    #[serde(rename = "s3SchemaVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<String>,
+    /// ID of the bucket notification configuration that matched.
+    #[serde(rename = "configurationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration_id: Option<String>,
+    /// The bucket the event occurred in.
+    #[serde(rename = "bucket")]
+    pub bucket: S3Bucket,
+    /// The object the event refers to.
+    #[serde(rename = "object")]
+    pub object: S3Object,
+}
+
+/// Bucket information in an event record.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3Bucket {
+    /// Name of the bucket.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Owner of the bucket.
+    #[serde(rename = "ownerIdentity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_identity: Option<S3UserIdentity>,
+    /// ARN of the bucket.
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+}
+
+/// Object information in an event record.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3Object {
+    /// URL encoded object key. Use [`decoded_key`](#method.decoded_key) to get the real key.
+    #[serde(rename = "key")]
+    pub key: String,
+    /// Object size in bytes. Not present for delete events.
+    #[serde(rename = "size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// Object ETag. Not present for delete events.
+    #[serde(rename = "eTag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    /// Object version, if the bucket is versioned.
+    #[serde(rename = "versionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    /// Hex value that can be compared to order events for the same object key.
+    #[serde(rename = "sequencer")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequencer: Option<String>,
+}
+
+impl S3Object {
+    /// Returns the object key with the URL encoding applied by S3 removed.
+    ///
+    /// S3 encodes keys in notifications as form values, so spaces arrive as `+` and
+    /// a literal `+` arrives as `%2B`.
+    pub fn decoded_key(&self) -> String {
+        decode_key(&self.key)
+    }
+}
+
+/// Decodes an object key as it appears in an event notification.
+pub fn decode_key(key: &str) -> String {
+    decode_uri(&key.replace('+', " "))
+}
+
+/// Glacier specific data of an event record.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3GlacierEventData {
+    /// Details of a completed restore.
+    #[serde(rename = "restoreEventData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_event_data: Option<S3RestoreEventData>,
+}
+
+/// Details of a completed restore from Glacier.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3RestoreEventData {
+    /// Time at which the restored copy of the object will be removed.
+    #[serde(rename = "lifecycleRestorationExpiryTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_restoration_expiry_time: Option<String>,
+    /// Storage class of the archived object.
+    #[serde(rename = "lifecycleRestoreStorageClass")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_restore_storage_class: Option<String>,
+}
+
+/// The `s3:TestEvent` sent when a notification configuration is first set on a bucket.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S3TestEvent {
+    /// Always `Amazon S3`.
+    #[serde(rename = "Service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Always `s3:TestEvent`.
+    #[serde(rename = "Event")]
+    pub event: String,
+    /// Time the test event was sent.
+    #[serde(rename = "Time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// Name of the bucket the configuration was set on.
+    #[serde(rename = "Bucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+    /// Request ID of the configuration request.
+    #[serde(rename = "RequestId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Host that processed the configuration request.
+    #[serde(rename = "HostId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<String>,
+}
+
+/// An S3 notification, as returned by [`parse_notification`](fn.parse_notification.html).
+#[derive(Debug, Clone, PartialEq)]
+pub enum S3Notification {
+    /// One or more event records.
+    Records(S3Event),
+    /// The test event sent when notifications are configured.
+    Test(S3TestEvent),
+}
+
+/// The envelope SNS wraps around a notification published to a topic.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnsEnvelope {
+    /// Always `Notification`.
+    #[serde(rename = "Type")]
+    pub type_: String,
+    /// Unique ID of the SNS message.
+    #[serde(rename = "MessageId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+    /// ARN of the topic the message was published to.
+    #[serde(rename = "TopicArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_arn: Option<String>,
+    /// Subject of the message, `Amazon S3 Notification` for S3 events.
+    #[serde(rename = "Subject")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// The S3 notification, serialized as a JSON string.
+    #[serde(rename = "Message")]
+    pub message: String,
+    /// Time the message was published.
+    #[serde(rename = "Timestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+/// The event a Lambda function receives when it is triggered by an SQS queue.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqsEvent {
+    /// The SQS messages in this batch.
+    #[serde(rename = "Records", default)]
+    pub records: Vec<SqsMessage>,
+}
+
+/// A single SQS message delivered to a Lambda function.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqsMessage {
+    /// ID of the SQS message.
+    #[serde(rename = "messageId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+    /// Receipt handle needed to delete the message.
+    #[serde(rename = "receiptHandle")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_handle: Option<String>,
+    /// The message body: an S3 notification, possibly inside an SNS envelope.
+    #[serde(rename = "body")]
+    pub body: String,
+    /// Always `aws:sqs`.
+    #[serde(rename = "eventSource")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_source: Option<String>,
+    /// ARN of the queue the message was received from.
+    #[serde(rename = "eventSourceARN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_source_arn: Option<String>,
+}
+
+impl SqsMessage {
+    /// Parses the message body as an S3 notification.
+    pub fn notification(&self) -> Result<S3Notification, serde_json::Error> {
+        parse_notification(&self.body)
+    }
+}
+
+/// Parses an S3 notification from a message body.
+///
+/// Accepts a bare notification (as received by Lambda or read from an SQS queue S3 publishes
+/// to directly), a notification wrapped in an SNS envelope (as read from an SQS queue subscribed
+/// to an SNS topic, or received by a Lambda function subscribed to one), and the `s3:TestEvent`.
+pub fn parse_notification(body: &str) -> Result<S3Notification, serde_json::Error> {
+    let value: serde_json::Value = serde_json::from_str(body)?;
+    notification_from_value(value)
+}
+
+fn notification_from_value(value: serde_json::Value) -> Result<S3Notification, serde_json::Error> {
+    if value.get("Records").is_some() {
+        if let Some(sns_records) = sns_records(&value) {
+            return sns_records;
+        }
+        return Ok(S3Notification::Records(serde_json::from_value(value)?));
+    }
+    if value.get("Event").is_some() {
+        return Ok(S3Notification::Test(serde_json::from_value(value)?));
+    }
+    if value.get("Type").and_then(|t| t.as_str()) == Some("Notification") {
+        let envelope: SnsEnvelope = serde_json::from_value(value)?;
+        return parse_notification(&envelope.message);
+    }
+    Err(de::Error::custom(
+        "expected S3 event records, an s3:TestEvent or an SNS envelope",
+    ))
+}
+
+/// Lambda functions subscribed to an SNS topic receive `{"Records": [{"Sns": {...}}]}`,
+/// with a single record per invocation.
+fn sns_records(value: &serde_json::Value) -> Option<Result<S3Notification, serde_json::Error>> {
+    let envelope = value.get("Records")?.get(0)?.get("Sns")?;
+    let message = envelope.get("Message")?.as_str()?;
+    Some(parse_notification(message))
+}
@@ -0,0 +1,183 @@
+//! Streaming the objects and common prefixes of a bucket.
+//!
+//! [`S3Client::list_objects_stream`](../struct.S3Client.html#method.list_objects_stream)
+//! pages through `ListObjectsV2` and streams what each page lists, in key order. With a
+//! `delimiter`, keys sharing the part of their name up to the delimiter are rolled up into
+//! a common prefix, like the folders of a file system. Common prefixes are streamed
+//! along with the objects, or collected separately so that the stream only has objects.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::{Future, Stream};
+//! use rusoto_core::Region;
+//! use rusoto_s3::list::ListObjectsOptions;
+//! use rusoto_s3::S3Client;
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let listing = client.list_objects_stream(
+//!     "my-bucket".to_owned(),
+//!     ListObjectsOptions {
+//!         prefix: Some("photos/".to_owned()),
+//!         delimiter: Some("/".to_owned()),
+//!         ..Default::default()
+//!     },
+//! );
+//! let folders = listing.common_prefixes();
+//! let photos = listing.collect().wait().unwrap();
+//! println!("{} photos in {} folders", photos.len(), folders.to_vec().len());
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use futures::{Future, Poll, Stream};
+use rusoto_core::pagination::{PaginationProgress, Paginator};
+use rusoto_core::RusotoError;
+
+use crate::generated::{ListObjectsV2Error, ListObjectsV2Request, Object, S3Client, S3};
+
+/// What to list, and how.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListObjectsOptions {
+    /// Only list keys starting with this prefix.
+    pub prefix: Option<String>,
+    /// Roll up the keys containing this delimiter after the prefix into common prefixes.
+    pub delimiter: Option<String>,
+    /// Only list keys after this one. It only applies to the first page, later pages
+    /// carrying on from where the previous one stopped.
+    pub start_after: Option<String>,
+    /// The most keys and common prefixes to list per page. S3 lists up to 1,000.
+    pub max_keys: Option<i64>,
+    /// Whether common prefixes are streamed along with the objects, rather than only
+    /// collected in [`ObjectListing::common_prefixes`](struct.ObjectListing.html#method.common_prefixes).
+    pub include_common_prefixes: bool,
+}
+
+/// An object or common prefix of a listing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListEntry {
+    /// An object.
+    Object(Object),
+    /// A prefix shared by the keys rolled up at the delimiter, including the delimiter.
+    CommonPrefix(String),
+}
+
+impl ListEntry {
+    /// The key of the object, or the common prefix.
+    pub fn key(&self) -> &str {
+        match *self {
+            ListEntry::Object(ref object) => object.key.as_ref().map_or("", String::as_str),
+            ListEntry::CommonPrefix(ref prefix) => prefix,
+        }
+    }
+}
+
+/// The common prefixes of a listing so far, in key order and without repetitions.
+///
+/// Clones share their prefixes, so a clone taken before consuming the listing has all of
+/// them afterwards.
+#[derive(Clone, Debug, Default)]
+pub struct CommonPrefixes {
+    inner: Arc<Mutex<Vec<String>>>,
+}
+
+impl CommonPrefixes {
+    /// The common prefixes listed so far.
+    pub fn to_vec(&self) -> Vec<String> {
+        self.inner.lock().unwrap().clone()
+    }
+}
+
+/// A stream of the entries of a bucket, fetching a page of `ListObjectsV2` at a time.
+pub struct ObjectListing {
+    entries: Paginator<ListEntry, RusotoError<ListObjectsV2Error>>,
+    common_prefixes: CommonPrefixes,
+}
+
+impl ObjectListing {
+    /// A handle on the common prefixes listed, whether or not they're streamed.
+    pub fn common_prefixes(&self) -> CommonPrefixes {
+        self.common_prefixes.clone()
+    }
+
+    /// A handle on the number of pages and entries fetched.
+    pub fn progress(&self) -> PaginationProgress {
+        self.entries.progress()
+    }
+}
+
+impl Stream for ObjectListing {
+    type Item = ListEntry;
+    type Error = RusotoError<ListObjectsV2Error>;
+
+    fn poll(&mut self) -> Poll<Option<ListEntry>, Self::Error> {
+        self.entries.poll()
+    }
+}
+
+impl S3Client {
+    /// Streams the objects of `bucket`, and its common prefixes when asked to, in key
+    /// order, across as many pages of `ListObjectsV2` as it takes.
+    ///
+    /// A common prefix is listed once, even when several pages roll keys up into it.
+    pub fn list_objects_stream(
+        &self,
+        bucket: String,
+        options: ListObjectsOptions,
+    ) -> ObjectListing {
+        let client = self.clone();
+        let common_prefixes = CommonPrefixes::default();
+        let listed = common_prefixes.clone();
+
+        let entries = Paginator::new(move |continuation_token: Option<String>| {
+            let start_after = if continuation_token.is_none() {
+                options.start_after.clone()
+            } else {
+                None
+            };
+            let request = ListObjectsV2Request {
+                bucket: bucket.clone(),
+                continuation_token,
+                delimiter: options.delimiter.clone(),
+                max_keys: options.max_keys,
+                prefix: options.prefix.clone(),
+                start_after,
+                ..Default::default()
+            };
+            let include_common_prefixes = options.include_common_prefixes;
+            let listed = listed.clone();
+            client.list_objects_v2(request).map(move |page| {
+                let mut entries: Vec<ListEntry> = page
+                    .contents
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(ListEntry::Object)
+                    .collect();
+
+                let mut listed = listed.inner.lock().unwrap();
+                for prefix in page.common_prefixes.unwrap_or_default() {
+                    let prefix = match prefix.prefix {
+                        Some(prefix) => prefix,
+                        None => continue,
+                    };
+                    match listed.binary_search(&prefix) {
+                        Ok(_) => continue,
+                        Err(position) => listed.insert(position, prefix.clone()),
+                    }
+                    if include_common_prefixes {
+                        entries.push(ListEntry::CommonPrefix(prefix));
+                    }
+                }
+
+                // S3 lists keys and common prefixes in one order, but in separate lists
+                entries.sort_by(|a, b| a.key().cmp(b.key()));
+                (entries, page.next_continuation_token)
+            })
+        });
+
+        ObjectListing {
+            entries,
+            common_prefixes,
+        }
+    }
+}
@@ -1,3 +1,19 @@
+/// Requests to access points addressed by their ARNs
+pub mod access_points;
+/// Codes of S3 errors, whichever operation returned them
+pub mod errors;
+/// Types for S3 event notifications
+pub mod events;
+/// Streaming the objects and common prefixes of buckets
+pub mod list;
+/// Uploads which switch to multipart uploads for large objects
+pub mod multipart;
+/// Requests to requester-pays buckets
+pub mod requester_pays;
+/// Syncing local directories to prefixes of buckets
+pub mod sync;
+/// In-place updates of the metadata and tags of objects
+pub mod update;
 /// Utility helpers for working with S3
 pub mod util;
 
@@ -0,0 +1,539 @@
+//! Streaming uploads which switch to a multipart upload for large objects.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::Future;
+//! use rusoto_core::Region;
+//! use rusoto_s3::multipart::{upload_object, MultipartUploadOptions};
+//! use rusoto_s3::{PutObjectRequest, S3Client};
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let request = PutObjectRequest {
+//!     bucket: "my-bucket".to_owned(),
+//!     key: "my-key".to_owned(),
+//!     body: Some(vec![0; 64 * 1024 * 1024].into()),
+//!     ..Default::default()
+//! };
+//! let output = upload_object(client, request, MultipartUploadOptions::default())
+//!     .wait()
+//!     .unwrap();
+//! println!("uploaded {:?} with upload id {:?}", output.e_tag, output.upload_id);
+//! ```
+//!
+//! The progress of an upload is reported to the listener of its options, which is told of
+//! the progress of each part too. Downloads report theirs with
+//! [`ByteStream::with_progress`](../../rusoto_core/struct.ByteStream.html#method.with_progress)
+//! on the body of a `GetObject` call, which knows its length.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use bytes::{Bytes, BytesMut};
+use futures::future::{self, Either, Loop};
+use futures::stream::{self, Fuse};
+use futures::{Async, Future, Poll, Stream};
+use rusoto_core::cancellation::{CancellationToken, Cancelled};
+use rusoto_core::progress::Progress;
+use rusoto_core::{ByteStream, RusotoError};
+
+use crate::generated::{
+    AbortMultipartUploadRequest, CompleteMultipartUploadError, CompleteMultipartUploadRequest,
+    CompletedMultipartUpload, CompletedPart, CreateMultipartUploadError,
+    CreateMultipartUploadRequest, PutObjectError, PutObjectRequest, UploadPartError,
+    UploadPartRequest, S3,
+};
+
+/// The smallest part size S3 accepts for all but the last part of a multipart upload.
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Options controlling how `upload_object` splits and uploads an object.
+pub struct MultipartUploadOptions {
+    /// Size in bytes of each uploaded part. Objects smaller than a single part are
+    /// uploaded with one `PutObject` call instead. S3 rejects parts smaller than
+    /// `MIN_PART_SIZE`, other than the last one.
+    pub part_size: usize,
+    /// Maximum number of parts uploaded at the same time. This also bounds how much
+    /// of the body is buffered in memory, to roughly `part_size * concurrency` bytes.
+    pub concurrency: usize,
+    /// Maximum number of attempts made to upload each part. Only dispatch errors and
+    /// server errors are retried.
+    pub part_attempts: usize,
+    /// Token stopping the upload when cancelled. A multipart upload already initiated is
+    /// aborted before `upload_object` fails with `UploadError::Cancelled`.
+    pub cancellation: Option<CancellationToken>,
+    /// Listener told of the progress of the upload and of each of its parts.
+    pub progress: Option<Arc<dyn UploadProgressListener>>,
+}
+
+impl Default for MultipartUploadOptions {
+    fn default() -> Self {
+        Self {
+            part_size: 8 * 1024 * 1024,
+            concurrency: 4,
+            part_attempts: 3,
+            cancellation: None,
+            progress: None,
+        }
+    }
+}
+
+/// Told of the progress of an `upload_object`.
+///
+/// Listeners are called from the tasks uploading the parts, which they shouldn't block.
+/// A listener which panics is ignored: the upload goes on.
+pub trait UploadProgressListener: Send + Sync {
+    /// Called with the number of bytes uploaded so far, which never goes down as parts are
+    /// retried. The total is known when the size of the body of the request is.
+    fn progress(&self, progress: &Progress);
+
+    /// Called with the progress of the attempt uploading the part `part_number`, before
+    /// `progress` is. The total is the size of the part, and an attempt retrying the part
+    /// reports it again from its start.
+    fn part_progress(&self, part_number: i64, progress: &Progress) {
+        let _ = (part_number, progress);
+    }
+}
+
+impl<F> UploadProgressListener for F
+where
+    F: Fn(&Progress) + Send + Sync,
+{
+    fn progress(&self, progress: &Progress) {
+        self(progress)
+    }
+}
+
+/// The result of a successful `upload_object`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UploadOutput {
+    /// Entity tag of the uploaded object.
+    pub e_tag: Option<String>,
+    /// Version of the uploaded object, if the bucket has versioning enabled.
+    pub version_id: Option<String>,
+    /// Id of the multipart upload, or `None` when the object was small enough to be
+    /// uploaded with a single `PutObject` call.
+    pub upload_id: Option<String>,
+}
+
+/// Errors returned by `upload_object`.
+#[derive(Debug)]
+pub enum UploadError {
+    /// Reading the request body failed.
+    Body(io::Error),
+    /// The single `PutObject` call used for small objects failed.
+    PutObject(RusotoError<PutObjectError>),
+    /// Initiating the multipart upload failed.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// A part still failed after all attempts. The multipart upload was aborted.
+    UploadPart {
+        /// Number of the failed part, starting at 1.
+        part_number: i64,
+        /// The error of the last attempt.
+        error: RusotoError<UploadPartError>,
+    },
+    /// Completing the multipart upload failed. The multipart upload was aborted.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// The cancellation token of the upload was cancelled. The multipart upload, if one
+    /// was initiated, was aborted.
+    Cancelled,
+}
+
+impl From<Cancelled> for UploadError {
+    fn from(_: Cancelled) -> Self {
+        UploadError::Cancelled
+    }
+}
+
+impl fmt::Display for UploadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UploadError::Body(ref err) => write!(f, "failed to read upload body: {}", err),
+            UploadError::PutObject(ref err) => write!(f, "failed to put object: {}", err),
+            UploadError::CreateMultipartUpload(ref err) => {
+                write!(f, "failed to create multipart upload: {}", err)
+            }
+            UploadError::UploadPart {
+                part_number,
+                ref error,
+            } => write!(f, "failed to upload part {}: {}", part_number, error),
+            UploadError::CompleteMultipartUpload(ref err) => {
+                write!(f, "failed to complete multipart upload: {}", err)
+            }
+            UploadError::Cancelled => write!(f, "upload was cancelled"),
+        }
+    }
+}
+
+impl Error for UploadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UploadError::Body(ref err) => Some(err),
+            UploadError::PutObject(ref err) => Some(err),
+            UploadError::CreateMultipartUpload(ref err) => Some(err),
+            UploadError::UploadPart { ref error, .. } => Some(error),
+            UploadError::CompleteMultipartUpload(ref err) => Some(err),
+            UploadError::Cancelled => None,
+        }
+    }
+}
+
+/// Uploads the body of `request`, using a multipart upload when the body is at least
+/// `options.part_size` bytes long and a single `PutObject` call otherwise.
+///
+/// Parts are read from the body as they are needed and uploaded concurrently, each
+/// being retried on its own. When a part can't be uploaded or the upload can't be
+/// completed, the multipart upload is aborted so that no parts are left behind.
+///
+/// All the object settings of `request`, such as its metadata, ACL or encryption,
+/// apply to the uploaded object. `content_length` is computed for each request, and
+/// `content_md5` is only sent when the object is uploaded with `PutObject`.
+pub fn upload_object<C>(
+    client: C,
+    mut request: PutObjectRequest,
+    options: MultipartUploadOptions,
+) -> Box<dyn Future<Item = UploadOutput, Error = UploadError> + Send>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    let body = request
+        .body
+        .take()
+        .unwrap_or_else(|| ByteStream::from(Vec::new()));
+    let part_size = options.part_size.max(1);
+    let cancellation = options.cancellation.clone();
+    let progress = options.progress.clone().map(|listener| {
+        Arc::new(UploadProgress {
+            listener,
+            total: body.size_hint().map(|size| size as u64),
+            started: Instant::now(),
+            parts: Mutex::new(HashMap::new()),
+        })
+    });
+
+    let first_part = Parts::new(body, part_size)
+        .into_future()
+        .map_err(|(err, _)| UploadError::Body(err));
+    let upload = cancelable(first_part, &cancellation).and_then(move |(first, rest)| {
+        let first = first.unwrap_or_else(Bytes::new);
+        if first.len() < part_size {
+            Either::A(cancelable(
+                put_object(client, request, first, progress),
+                &cancellation,
+            ))
+        } else {
+            Either::B(multipart_upload(
+                client, request, first, rest, options, progress,
+            ))
+        }
+    });
+    Box::new(upload)
+}
+
+fn put_object<C: S3>(
+    client: C,
+    mut request: PutObjectRequest,
+    body: Bytes,
+    progress: Option<Arc<UploadProgress>>,
+) -> impl Future<Item = UploadOutput, Error = UploadError> {
+    request.content_length = Some(body.len() as i64);
+    request.body = Some(with_progress(body, None, &progress));
+    client
+        .put_object(request)
+        .map(|output| UploadOutput {
+            e_tag: output.e_tag,
+            version_id: output.version_id,
+            upload_id: None,
+        })
+        .map_err(UploadError::PutObject)
+}
+
+fn multipart_upload<C>(
+    client: C,
+    request: PutObjectRequest,
+    first: Bytes,
+    rest: Parts,
+    options: MultipartUploadOptions,
+    progress: Option<Arc<UploadProgress>>,
+) -> impl Future<Item = UploadOutput, Error = UploadError>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    let create = CreateMultipartUploadRequest {
+        acl: request.acl,
+        bucket: request.bucket.clone(),
+        cache_control: request.cache_control,
+        content_disposition: request.content_disposition,
+        content_encoding: request.content_encoding,
+        content_language: request.content_language,
+        content_type: request.content_type,
+        expires: request.expires,
+        grant_full_control: request.grant_full_control,
+        grant_read: request.grant_read,
+        grant_read_acp: request.grant_read_acp,
+        grant_write_acp: request.grant_write_acp,
+        key: request.key.clone(),
+        metadata: request.metadata,
+        object_lock_legal_hold_status: request.object_lock_legal_hold_status,
+        object_lock_mode: request.object_lock_mode,
+        object_lock_retain_until_date: request.object_lock_retain_until_date,
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        ssekms_encryption_context: request.ssekms_encryption_context,
+        ssekms_key_id: request.ssekms_key_id,
+        server_side_encryption: request.server_side_encryption,
+        storage_class: request.storage_class,
+        tagging: request.tagging,
+        website_redirect_location: request.website_redirect_location,
+    };
+    let mut upload = Upload {
+        bucket: request.bucket,
+        key: request.key,
+        upload_id: String::new(),
+        request_payer: request.request_payer,
+        sse_customer_algorithm: request.sse_customer_algorithm,
+        sse_customer_key: request.sse_customer_key,
+        sse_customer_key_md5: request.sse_customer_key_md5,
+    };
+
+    let cancellation = options.cancellation.clone();
+    let created = client
+        .create_multipart_upload(create)
+        .map_err(UploadError::CreateMultipartUpload);
+    cancelable(created, &cancellation).and_then(move |output| {
+        upload.upload_id = output.upload_id.unwrap_or_default();
+
+        let attempts = options.part_attempts;
+        let mut part_number = 0;
+        let parts = {
+            let client = client.clone();
+            let upload = upload.clone();
+            stream::once(Ok(first))
+                .chain(rest)
+                .map_err(UploadError::Body)
+                .map(move |part| {
+                    part_number += 1;
+                    upload_part(
+                        client.clone(),
+                        upload.clone(),
+                        part_number,
+                        part,
+                        attempts,
+                        progress.clone(),
+                    )
+                })
+                .buffer_unordered(options.concurrency.max(1))
+                .collect()
+        };
+
+        let uploaded = parts.and_then({
+            let client = client.clone();
+            let upload = upload.clone();
+            move |parts| complete(client, upload, parts)
+        });
+        // the abort runs whatever the token says
+        cancelable(uploaded, &cancellation)
+            .or_else(move |err| abort(client, upload).then(move |_| Err(err)))
+    })
+}
+
+/// The settings shared by every request made for one multipart upload.
+#[derive(Clone)]
+struct Upload {
+    bucket: String,
+    key: String,
+    upload_id: String,
+    request_payer: Option<String>,
+    sse_customer_algorithm: Option<String>,
+    sse_customer_key: Option<String>,
+    sse_customer_key_md5: Option<String>,
+}
+
+fn upload_part<C>(
+    client: C,
+    upload: Upload,
+    part_number: i64,
+    part: Bytes,
+    attempts: usize,
+    progress: Option<Arc<UploadProgress>>,
+) -> impl Future<Item = CompletedPart, Error = UploadError>
+where
+    C: S3,
+{
+    future::loop_fn(1, move |attempt| {
+        let request = UploadPartRequest {
+            body: Some(with_progress(part.clone(), Some(part_number), &progress)),
+            bucket: upload.bucket.clone(),
+            content_length: Some(part.len() as i64),
+            key: upload.key.clone(),
+            part_number,
+            request_payer: upload.request_payer.clone(),
+            sse_customer_algorithm: upload.sse_customer_algorithm.clone(),
+            sse_customer_key: upload.sse_customer_key.clone(),
+            sse_customer_key_md5: upload.sse_customer_key_md5.clone(),
+            upload_id: upload.upload_id.clone(),
+            ..Default::default()
+        };
+        client
+            .upload_part(request)
+            .then(move |result| match result {
+                Ok(output) => Ok(Loop::Break(CompletedPart {
+                    e_tag: output.e_tag,
+                    part_number: Some(part_number),
+                })),
+                Err(ref error) if attempt < attempts && is_retryable(error) => {
+                    Ok(Loop::Continue(attempt + 1))
+                }
+                Err(error) => Err(UploadError::UploadPart { part_number, error }),
+            })
+    })
+}
+
+/// Adds up the progress of the parts of an upload for its listener.
+struct UploadProgress {
+    listener: Arc<dyn UploadProgressListener>,
+    total: Option<u64>,
+    started: Instant,
+    /// The most bytes any attempt uploaded of each part, by part number, or 0 for an
+    /// object uploaded with `PutObject`.
+    parts: Mutex<HashMap<i64, u64>>,
+}
+
+impl UploadProgress {
+    fn report(&self, part_number: Option<i64>, attempt: &Progress) {
+        let mut parts = self.parts.lock().unwrap();
+        let uploaded = parts.entry(part_number.unwrap_or(0)).or_insert(0);
+        *uploaded = (*uploaded).max(attempt.transferred);
+        let progress = Progress {
+            transferred: parts.values().sum(),
+            total: self.total,
+            elapsed: self.started.elapsed(),
+        };
+        // the listener is called with the lock held, so that its reports are in order, and
+        // mustn't poison it
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            if let Some(part_number) = part_number {
+                self.listener.part_progress(part_number, attempt);
+            }
+            self.listener.progress(&progress);
+        }));
+    }
+}
+
+/// The body of a request uploading `bytes`, reporting its progress if there's a listener.
+fn with_progress(
+    bytes: Bytes,
+    part_number: Option<i64>,
+    progress: &Option<Arc<UploadProgress>>,
+) -> ByteStream {
+    let body = ByteStream::from(bytes);
+    match *progress {
+        Some(ref progress) => {
+            let progress = progress.clone();
+            body.with_progress(move |attempt: &Progress| progress.report(part_number, attempt))
+        }
+        None => body,
+    }
+}
+
+/// Stops `future` with `UploadError::Cancelled` once `cancellation` is cancelled.
+fn cancelable<F>(
+    future: F,
+    cancellation: &Option<CancellationToken>,
+) -> impl Future<Item = F::Item, Error = UploadError>
+where
+    F: Future<Error = UploadError>,
+{
+    match *cancellation {
+        Some(ref token) => Either::A(token.guard(future)),
+        None => Either::B(future),
+    }
+}
+
+pub(crate) fn is_retryable<E>(error: &RusotoError<E>) -> bool {
+    match *error {
+        RusotoError::HttpDispatch(_) => true,
+        RusotoError::Unknown(ref response) => response.status.is_server_error(),
+        _ => false,
+    }
+}
+
+fn complete<C: S3>(
+    client: C,
+    upload: Upload,
+    mut parts: Vec<CompletedPart>,
+) -> impl Future<Item = UploadOutput, Error = UploadError> {
+    parts.sort_by_key(|part| part.part_number);
+    let upload_id = upload.upload_id.clone();
+    let request = CompleteMultipartUploadRequest {
+        bucket: upload.bucket,
+        key: upload.key,
+        multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+        request_payer: upload.request_payer,
+        upload_id: upload.upload_id,
+    };
+    client
+        .complete_multipart_upload(request)
+        .map(move |output| UploadOutput {
+            e_tag: output.e_tag,
+            version_id: output.version_id,
+            upload_id: Some(upload_id),
+        })
+        .map_err(UploadError::CompleteMultipartUpload)
+}
+
+fn abort<C: S3>(client: C, upload: Upload) -> impl Future<Item = (), Error = ()> {
+    let request = AbortMultipartUploadRequest {
+        bucket: upload.bucket,
+        key: upload.key,
+        request_payer: upload.request_payer,
+        upload_id: upload.upload_id,
+    };
+    // the original error is more useful to the caller than a failure to abort
+    client.abort_multipart_upload(request).then(|_| Ok(()))
+}
+
+/// Splits a body into parts of `part_size` bytes, the last part holding what's left.
+struct Parts {
+    body: Fuse<ByteStream>,
+    buffer: BytesMut,
+    part_size: usize,
+}
+
+impl Parts {
+    fn new(body: ByteStream, part_size: usize) -> Self {
+        Parts {
+            body: body.fuse(),
+            buffer: BytesMut::new(),
+            part_size,
+        }
+    }
+}
+
+impl Stream for Parts {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            if self.buffer.len() >= self.part_size {
+                return Ok(Async::Ready(Some(
+                    self.buffer.split_to(self.part_size).freeze(),
+                )));
+            }
+            match self.body.poll()? {
+                Async::NotReady => return Ok(Async::NotReady),
+                Async::Ready(Some(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Async::Ready(None) if self.buffer.is_empty() => return Ok(Async::Ready(None)),
+                Async::Ready(None) => return Ok(Async::Ready(Some(self.buffer.take().freeze()))),
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Requests to requester-pays buckets.
+//!
+//! The owner of a requester-pays bucket only pays for storing its objects: the
+//! requests to the bucket, and the data transferred out of it, are billed to the
+//! account making them. S3 requires requesters to acknowledge these charges with the
+//! `x-amz-request-payer: requester` header, and denies requests without it with an
+//! `AccessDenied` error, even when the bucket policy grants access to the requester.
+//! An `AccessDenied` error from a bucket shared by another account is often the sign
+//! of a missing header.
+//!
+//! The inputs of the object operations which accept the header implement
+//! [`RequesterPays`](trait.RequesterPays.html):
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_s3::requester_pays::RequesterPays;
+//! use rusoto_s3::{GetObjectRequest, S3Client, S3};
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let request = GetObjectRequest {
+//!     bucket: "shared-dataset".to_owned(),
+//!     key: "2019/01/part-00000.csv".to_owned(),
+//!     ..Default::default()
+//! }
+//! .requester_pays();
+//! let object = client.get_object(request).sync().unwrap();
+//! ```
+//!
+//! Other calls, such as `GetBucketLocation` on a requester-pays bucket, send the
+//! header with [`RequesterPaysFuture`](trait.RequesterPaysFuture.html):
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_s3::requester_pays::RequesterPaysFuture;
+//! use rusoto_s3::{GetBucketLocationRequest, S3Client, S3};
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let request = GetBucketLocationRequest {
+//!     bucket: "shared-dataset".to_owned(),
+//! };
+//! let location = client
+//!     .get_bucket_location(request)
+//!     .requester_pays()
+//!     .sync()
+//!     .unwrap();
+//! ```
+
+use std::fmt;
+
+use rusoto_core::RusotoFuture;
+
+use crate::generated::*;
+
+/// The header acknowledging the charges of requests to requester-pays buckets.
+pub const REQUEST_PAYER_HEADER: &str = "x-amz-request-payer";
+
+/// Who pays for a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RequestPayer {
+    /// The account making the request.
+    Requester,
+}
+
+impl RequestPayer {
+    /// The value of the `x-amz-request-payer` header.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RequestPayer::Requester => "requester",
+        }
+    }
+}
+
+impl fmt::Display for RequestPayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<RequestPayer> for String {
+    fn from(payer: RequestPayer) -> String {
+        payer.as_str().to_owned()
+    }
+}
+
+/// Inputs of the operations which accept the `x-amz-request-payer` header.
+pub trait RequesterPays: Sized {
+    /// Sets who pays for the request.
+    fn set_request_payer(&mut self, payer: RequestPayer);
+
+    /// Acknowledges that the requester pays for the request.
+    fn requester_pays(mut self) -> Self {
+        self.set_request_payer(RequestPayer::Requester);
+        self
+    }
+}
+
+macro_rules! requester_pays {
+    ($($request:ident),*) => {
+        $(
+            impl RequesterPays for $request {
+                fn set_request_payer(&mut self, payer: RequestPayer) {
+                    self.request_payer = Some(payer.into());
+                }
+            }
+        )*
+    };
+}
+
+requester_pays!(
+    AbortMultipartUploadRequest,
+    CompleteMultipartUploadRequest,
+    CopyObjectRequest,
+    CreateMultipartUploadRequest,
+    DeleteObjectRequest,
+    DeleteObjectsRequest,
+    GetObjectAclRequest,
+    GetObjectLegalHoldRequest,
+    GetObjectRequest,
+    GetObjectRetentionRequest,
+    GetObjectTorrentRequest,
+    HeadObjectRequest,
+    ListObjectsRequest,
+    ListObjectsV2Request,
+    ListPartsRequest,
+    PutObjectAclRequest,
+    PutObjectLegalHoldRequest,
+    PutObjectLockConfigurationRequest,
+    PutObjectRequest,
+    PutObjectRetentionRequest,
+    RestoreObjectRequest,
+    UploadPartCopyRequest,
+    UploadPartRequest
+);
+
+/// Calls to which the `x-amz-request-payer` header can be added, whatever their input.
+pub trait RequesterPaysFuture {
+    /// Sets who pays for the call, before it is signed.
+    fn with_request_payer(self, payer: RequestPayer) -> Self;
+
+    /// Acknowledges that the requester pays for the call, before it is signed.
+    fn requester_pays(self) -> Self
+    where
+        Self: Sized,
+    {
+        self.with_request_payer(RequestPayer::Requester)
+    }
+}
+
+impl<T, E> RequesterPaysFuture for RusotoFuture<T, E> {
+    fn with_request_payer(self, payer: RequestPayer) -> Self {
+        self.with_header(REQUEST_PAYER_HEADER, payer.as_str())
+    }
+}
@@ -0,0 +1,724 @@
+//! Syncing a local directory to a prefix of a bucket.
+//!
+//! [`S3Sync::sync_up`](struct.S3Sync.html#method.sync_up) compares the files of a local
+//! directory with the objects under a prefix, uploads the files which are new or changed
+//! and, if asked to, deletes the objects without a local file. Files are uploaded a few at
+//! a time with [`upload_object`](../multipart/fn.upload_object.html), so large files are
+//! uploaded in parts and only a bounded part of them is held in memory.
+//!
+//! # Symlinks and empty directories
+//!
+//! A symlink to a file is uploaded with the content of the file it points to. Symlinks to
+//! directories aren't followed, so that a link can't make the walk loop or leave the
+//! directory, and dangling symlinks are skipped. S3 has no directories: empty directories
+//! are skipped, and objects whose key ends with `/`, as created by the console for
+//! folders, are never deleted.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::Future;
+//! use rusoto_core::Region;
+//! use rusoto_s3::sync::{S3Sync, SyncOptions};
+//! use rusoto_s3::S3Client;
+//!
+//! let sync = S3Sync::new(S3Client::new(Region::UsEast1));
+//! let options = SyncOptions {
+//!     delete: true,
+//!     exclude: vec!["**/.DS_Store".to_owned(), "drafts/**".to_owned()],
+//!     ..Default::default()
+//! };
+//! let summary = sync
+//!     .sync_up("public", "my-bucket", "site", options)
+//!     .wait()
+//!     .unwrap();
+//! println!(
+//!     "uploaded {} files, deleted {} objects, {} unchanged",
+//!     summary.uploaded, summary.deleted, summary.unchanged
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use bytes::Bytes;
+use futures::future::{self, Either};
+use futures::{stream, Async, Future, Poll, Stream};
+use rusoto_core::pagination::Paginator;
+use rusoto_core::{ByteStream, RusotoError};
+
+use crate::generated::{
+    Delete, DeleteObjectsError, DeleteObjectsRequest, ListObjectsV2Error, ListObjectsV2Request,
+    Object, ObjectIdentifier, PutObjectRequest, S3,
+};
+use crate::multipart::{upload_object, MultipartUploadOptions, UploadError};
+
+/// The most keys S3 deletes with a single `DeleteObjects` call.
+const MAX_DELETE_KEYS: usize = 1000;
+
+/// Size of the chunks files are read in.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How a local file is compared with the object of the same key to tell whether it changed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncCompare {
+    /// The file changed if its size differs from the size of the object, or if it was
+    /// modified after the object. When either time is unknown the file is uploaded.
+    SizeAndModified,
+    /// The file changed if its size differs from the size of the object, or if its MD5
+    /// digest differs from the entity tag of the object. Files of at least
+    /// `multipart.part_size` bytes are compared with the entity tag of a multipart upload
+    /// with parts of that size, so objects uploaded with another part size are uploaded
+    /// again. Every file the same size as its object is read.
+    ETag,
+}
+
+/// Options controlling what `S3Sync::sync_up` uploads and deletes.
+pub struct SyncOptions {
+    /// How local files are compared with the objects already under the prefix.
+    pub compare: SyncCompare,
+    /// Whether objects under the prefix without a local file are deleted.
+    pub delete: bool,
+    /// Glob patterns of the paths to sync, relative to the directory and separated by
+    /// `/`, such as `assets/**/*.css`. All paths are synced when there are none. `*` and
+    /// `?` match within a path segment, `**` matches across segments.
+    pub include: Vec<String>,
+    /// Glob patterns of the paths to leave out, even when they are included. Objects
+    /// whose key matches are neither uploaded nor deleted.
+    pub exclude: Vec<String>,
+    /// Whether to only plan the operations, without uploading or deleting anything.
+    pub dry_run: bool,
+    /// Maximum number of files uploaded at the same time. Each of them may upload
+    /// `multipart.concurrency` parts at the same time.
+    pub concurrency: usize,
+    /// How each file is uploaded. Its progress listener isn't used, as the reports of
+    /// files uploaded at the same time couldn't be told apart.
+    pub multipart: MultipartUploadOptions,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        SyncOptions {
+            compare: SyncCompare::SizeAndModified,
+            delete: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            dry_run: false,
+            concurrency: 4,
+            multipart: MultipartUploadOptions::default(),
+        }
+    }
+}
+
+/// Why a file is uploaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UploadReason {
+    /// There is no object with the key of the file.
+    New,
+    /// The object with the key of the file differs from it.
+    Changed,
+}
+
+/// An operation planned by `S3Sync::sync_up`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SyncOperation {
+    /// Uploads a local file.
+    Upload {
+        /// Path of the file.
+        path: PathBuf,
+        /// Key of the object.
+        key: String,
+        /// Size of the file in bytes.
+        size: u64,
+        /// Why the file is uploaded.
+        reason: UploadReason,
+    },
+    /// Deletes an object without a local file.
+    Delete {
+        /// Key of the object.
+        key: String,
+    },
+}
+
+/// The result of a successful `S3Sync::sync_up`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyncSummary {
+    /// The planned operations: the uploads, then the deletions, each in key order.
+    pub operations: Vec<SyncOperation>,
+    /// Number of files which were left alone as they didn't change.
+    pub unchanged: usize,
+    /// Number of files uploaded, 0 in a dry run.
+    pub uploaded: usize,
+    /// Number of objects deleted, 0 in a dry run.
+    pub deleted: usize,
+    /// Number of bytes uploaded, 0 in a dry run.
+    pub bytes_uploaded: u64,
+    /// Whether this was a dry run, which only planned the operations.
+    pub dry_run: bool,
+}
+
+/// Errors returned by `S3Sync::sync_up`.
+#[derive(Debug)]
+pub enum SyncError {
+    /// Reading the local directory or one of its files failed.
+    Io {
+        /// Path of the file or directory.
+        path: PathBuf,
+        /// The error reading it.
+        error: io::Error,
+    },
+    /// A local path isn't valid UTF-8, so it has no key.
+    InvalidPath(PathBuf),
+    /// Listing the objects under the prefix failed.
+    ListObjects(RusotoError<ListObjectsV2Error>),
+    /// Uploading a file failed. Files which were being uploaded at the same time may
+    /// have been uploaded or not, and no object was deleted.
+    Upload {
+        /// Key of the object.
+        key: String,
+        /// The error uploading it.
+        error: UploadError,
+    },
+    /// A `DeleteObjects` call failed.
+    DeleteObjects(RusotoError<DeleteObjectsError>),
+    /// S3 couldn't delete an object.
+    DeleteObject {
+        /// Key of the object.
+        key: String,
+        /// The error message of S3.
+        message: String,
+    },
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SyncError::Io {
+                ref path,
+                ref error,
+            } => write!(f, "failed to read {}: {}", path.display(), error),
+            SyncError::InvalidPath(ref path) => {
+                write!(f, "path {} is not valid UTF-8", path.display())
+            }
+            SyncError::ListObjects(ref err) => write!(f, "failed to list objects: {}", err),
+            SyncError::Upload { ref key, ref error } => {
+                write!(f, "failed to upload {}: {}", key, error)
+            }
+            SyncError::DeleteObjects(ref err) => write!(f, "failed to delete objects: {}", err),
+            SyncError::DeleteObject {
+                ref key,
+                ref message,
+            } => write!(f, "failed to delete {}: {}", key, message),
+        }
+    }
+}
+
+impl Error for SyncError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SyncError::Io { ref error, .. } => Some(error),
+            SyncError::ListObjects(ref err) => Some(err),
+            SyncError::Upload { ref error, .. } => Some(error),
+            SyncError::DeleteObjects(ref err) => Some(err),
+            SyncError::InvalidPath(_) | SyncError::DeleteObject { .. } => None,
+        }
+    }
+}
+
+/// Syncs local directories to prefixes of buckets.
+pub struct S3Sync<C> {
+    client: C,
+}
+
+impl<C> S3Sync<C>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    /// Syncs with the client `client`.
+    pub fn new(client: C) -> S3Sync<C> {
+        S3Sync { client }
+    }
+
+    /// Syncs the files of `local_dir` to the objects of `bucket` under `prefix`.
+    ///
+    /// A file with the path `a/b.txt` relative to `local_dir` is uploaded with the key
+    /// `prefix/a/b.txt`, or `a/b.txt` if the prefix is empty. The directory is walked and
+    /// the objects listed before anything is uploaded, and deletions are only made once
+    /// every upload succeeded.
+    pub fn sync_up<P: AsRef<Path>>(
+        &self,
+        local_dir: P,
+        bucket: &str,
+        prefix: &str,
+        options: SyncOptions,
+    ) -> Box<dyn Future<Item = SyncSummary, Error = SyncError> + Send> {
+        let local_dir = local_dir.as_ref().to_path_buf();
+        let client = self.client.clone();
+        let bucket = bucket.to_owned();
+        let prefix = match prefix.trim_matches('/') {
+            "" => String::new(),
+            prefix => format!("{}/", prefix),
+        };
+
+        let local = future::lazy(move || local_files(&local_dir));
+        let remote = remote_objects(client.clone(), bucket.clone(), prefix.clone());
+        let sync = local
+            .join(remote)
+            .and_then(move |(local, remote)| {
+                let plan = plan(local, remote, &prefix, &options)?;
+                Ok((plan, options))
+            })
+            .and_then(move |(plan, options)| {
+                if options.dry_run {
+                    return Either::A(future::ok(SyncSummary {
+                        operations: plan.operations,
+                        unchanged: plan.unchanged,
+                        dry_run: true,
+                        ..Default::default()
+                    }));
+                }
+                Either::B(execute(client, bucket, plan, options))
+            });
+        Box::new(sync)
+    }
+}
+
+/// A file of the local directory.
+struct LocalFile {
+    path: PathBuf,
+    size: u64,
+    /// Seconds since the epoch of the last modification, if known.
+    modified: Option<u64>,
+}
+
+/// The files of `root` by their path relative to it, separated by `/`.
+fn local_files(root: &Path) -> Result<BTreeMap<String, LocalFile>, SyncError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| SyncError::Io { path, error }
+    };
+
+    let mut files = BTreeMap::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).map_err(io_error(&dir))? {
+            let entry = entry.map_err(io_error(&dir))?;
+            let path = entry.path();
+            let file_type = entry.file_type().map_err(io_error(&path))?;
+            if file_type.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            // follows symlinks, which are only taken when they point to a file
+            let metadata = match fs::metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(_) if file_type.is_symlink() => continue,
+                Err(error) => return Err(SyncError::Io { path, error }),
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .map(|since_epoch| since_epoch.as_secs());
+            files.insert(
+                relative_path(root, &path)?,
+                LocalFile {
+                    path,
+                    size: metadata.len(),
+                    modified,
+                },
+            );
+        }
+    }
+    Ok(files)
+}
+
+fn relative_path(root: &Path, path: &Path) -> Result<String, SyncError> {
+    let mut segments = Vec::new();
+    for component in path.strip_prefix(root).unwrap_or(path).components() {
+        if let Component::Normal(segment) = component {
+            match segment.to_str() {
+                Some(segment) => segments.push(segment),
+                None => return Err(SyncError::InvalidPath(path.to_path_buf())),
+            }
+        }
+    }
+    Ok(segments.join("/"))
+}
+
+/// The objects of `bucket` under `prefix` by their key relative to it.
+fn remote_objects<C>(
+    client: C,
+    bucket: String,
+    prefix: String,
+) -> impl Future<Item = BTreeMap<String, Object>, Error = SyncError>
+where
+    C: S3 + Send + 'static,
+{
+    let list_prefix = prefix.clone();
+    Paginator::new(move |continuation_token| {
+        let request = ListObjectsV2Request {
+            bucket: bucket.clone(),
+            continuation_token,
+            prefix: Some(list_prefix.clone()).filter(|prefix| !prefix.is_empty()),
+            ..Default::default()
+        };
+        client.list_objects_v2(request).map(|page| {
+            (
+                page.contents.unwrap_or_default(),
+                page.next_continuation_token,
+            )
+        })
+    })
+    .map_err(SyncError::ListObjects)
+    .fold(BTreeMap::new(), move |mut objects, object| {
+        let relative = object
+            .key
+            .as_ref()
+            .filter(|key| !key.ends_with('/'))
+            .and_then(|key| key.get(prefix.len()..))
+            .map(str::to_owned);
+        if let Some(relative) = relative {
+            objects.insert(relative, object);
+        }
+        Ok::<_, SyncError>(objects)
+    })
+}
+
+/// The operations syncing the local files with the remote objects.
+struct Plan {
+    operations: Vec<SyncOperation>,
+    unchanged: usize,
+}
+
+fn plan(
+    local: BTreeMap<String, LocalFile>,
+    mut remote: BTreeMap<String, Object>,
+    prefix: &str,
+    options: &SyncOptions,
+) -> Result<Plan, SyncError> {
+    let filter = Filter {
+        include: &options.include,
+        exclude: &options.exclude,
+    };
+    let mut plan = Plan {
+        operations: Vec::new(),
+        unchanged: 0,
+    };
+
+    for (relative, file) in local {
+        if !filter.matches(&relative) {
+            continue;
+        }
+        let reason = match remote.remove(&relative) {
+            None => UploadReason::New,
+            Some(ref object) if changed(&file, object, options)? => UploadReason::Changed,
+            Some(_) => {
+                plan.unchanged += 1;
+                continue;
+            }
+        };
+        plan.operations.push(SyncOperation::Upload {
+            path: file.path,
+            key: format!("{}{}", prefix, relative),
+            size: file.size,
+            reason,
+        });
+    }
+
+    if options.delete {
+        for relative in remote.keys().filter(|relative| filter.matches(relative)) {
+            plan.operations.push(SyncOperation::Delete {
+                key: format!("{}{}", prefix, relative),
+            });
+        }
+    }
+    Ok(plan)
+}
+
+/// Whether `file` differs from the object of the same key.
+fn changed(file: &LocalFile, object: &Object, options: &SyncOptions) -> Result<bool, SyncError> {
+    if object.size != Some(file.size as i64) {
+        return Ok(true);
+    }
+    match options.compare {
+        SyncCompare::SizeAndModified => {
+            let uploaded = object
+                .last_modified
+                .as_ref()
+                .and_then(|t| parse_timestamp(t));
+            Ok(match (file.modified, uploaded) {
+                (Some(modified), Some(uploaded)) => modified > uploaded,
+                _ => true,
+            })
+        }
+        SyncCompare::ETag => {
+            let e_tag = object.e_tag.as_ref().map(|e_tag| e_tag.trim_matches('"'));
+            let local =
+                local_e_tag(&file.path, options.multipart.part_size.max(1)).map_err(|error| {
+                    SyncError::Io {
+                        path: file.path.clone(),
+                        error,
+                    }
+                })?;
+            Ok(e_tag != Some(local.as_str()))
+        }
+    }
+}
+
+/// The entity tag S3 gives the object uploaded from the file at `path` by `upload_object`:
+/// the MD5 digest of the content for a single `PutObject` call, and the digest of the
+/// digests of the parts followed by the number of parts for a multipart upload.
+pub(crate) fn local_e_tag(path: &Path, part_size: usize) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut part = vec![0; part_size];
+    let mut digests = Vec::new();
+    // upload_object switches to a multipart upload as soon as the first part is full
+    let mut multipart = false;
+    loop {
+        let mut len = 0;
+        while len < part_size {
+            match file.read(&mut part[len..])? {
+                0 => break,
+                read => len += read,
+            }
+        }
+        multipart |= len == part_size;
+        if len > 0 || digests.is_empty() {
+            digests.push(md5::compute(&part[..len]));
+        }
+        if len < part_size {
+            break;
+        }
+    }
+    if !multipart {
+        return Ok(format!("{:x}", digests[0]));
+    }
+    let mut context = md5::Context::new();
+    for digest in &digests {
+        context.consume(&digest[..]);
+    }
+    Ok(format!("{:x}-{}", context.compute(), digests.len()))
+}
+
+/// Seconds since the epoch of an S3 timestamp, such as `2019-10-07T12:30:00.000Z`.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let field =
+        |start: usize, end: usize| -> Option<u64> { timestamp.get(start..end)?.parse().ok() };
+    let (year, month, day) = (field(0, 4)?, field(5, 7)?, field(8, 10)?);
+    let (hour, minute, second) = (field(11, 13)?, field(14, 16)?, field(17, 19)?);
+
+    // days since the epoch of the civil date, counting years from March
+    let (year, month) = if month <= 2 {
+        (year.checked_sub(1)?, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let year_of_era = year % 400;
+    let day_of_year = (153 * month + 2) / 5 + day.checked_sub(1)?;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (year / 400 * 146_097 + day_of_era).checked_sub(719_468)?;
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+/// The include and exclude patterns of the options.
+struct Filter<'a> {
+    include: &'a [String],
+    exclude: &'a [String],
+}
+
+impl<'a> Filter<'a> {
+    /// Whether the file or object with the relative `path` is synced.
+    fn matches(&self, path: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|pattern| glob(pattern, path)))
+            && !self.exclude.iter().any(|pattern| glob(pattern, path))
+    }
+}
+
+/// Whether `path` matches the glob `pattern`, where `*` and `?` match within a path
+/// segment and `**` matches across segments, including none for `**/`.
+pub(crate) fn glob(pattern: &str, path: &str) -> bool {
+    if pattern.starts_with("**") {
+        let rest = &pattern[2..];
+        if rest.starts_with('/') && glob(&rest[1..], path) {
+            return true;
+        }
+        return path
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(Some(path.len()))
+            .any(|index| glob(rest, &path[index..]));
+    }
+    let mut chars = pattern.chars();
+    match chars.next() {
+        None => path.is_empty(),
+        Some('*') => {
+            let segment_end = path.find('/').unwrap_or(path.len());
+            path[..segment_end]
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain(Some(segment_end))
+                .any(|index| glob(chars.as_str(), &path[index..]))
+        }
+        Some(expected) => {
+            let mut path_chars = path.chars();
+            match path_chars.next() {
+                Some(actual) if actual == expected || (expected == '?' && actual != '/') => {
+                    glob(chars.as_str(), path_chars.as_str())
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
+fn execute<C>(
+    client: C,
+    bucket: String,
+    plan: Plan,
+    options: SyncOptions,
+) -> impl Future<Item = SyncSummary, Error = SyncError>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    let mut uploads = Vec::new();
+    let mut deletes = Vec::new();
+    for operation in &plan.operations {
+        match *operation {
+            SyncOperation::Upload {
+                ref path,
+                ref key,
+                size,
+                ..
+            } => uploads.push((path.clone(), key.clone(), size)),
+            SyncOperation::Delete { ref key } => deletes.push(key.clone()),
+        }
+    }
+    let summary = SyncSummary {
+        operations: plan.operations,
+        unchanged: plan.unchanged,
+        ..Default::default()
+    };
+
+    let multipart = options.multipart;
+    let uploaded = {
+        let client = client.clone();
+        let bucket = bucket.clone();
+        stream::iter_ok(uploads)
+            .map(move |(path, key, size)| {
+                let options = MultipartUploadOptions {
+                    part_size: multipart.part_size,
+                    concurrency: multipart.concurrency,
+                    part_attempts: multipart.part_attempts,
+                    cancellation: multipart.cancellation.clone(),
+                    progress: None,
+                };
+                upload_file(client.clone(), bucket.clone(), path, key, options).map(move |_| size)
+            })
+            .buffer_unordered(options.concurrency.max(1))
+            .fold(summary, |mut summary, size| {
+                summary.uploaded += 1;
+                summary.bytes_uploaded += size;
+                Ok::<_, SyncError>(summary)
+            })
+    };
+
+    uploaded.and_then(move |summary| {
+        let batches: Vec<Vec<String>> = deletes
+            .chunks(MAX_DELETE_KEYS)
+            .map(<[String]>::to_vec)
+            .collect();
+        stream::iter_ok(batches).fold(summary, move |mut summary, keys| {
+            summary.deleted += keys.len();
+            delete_objects(client.clone(), bucket.clone(), keys).map(move |_| summary)
+        })
+    })
+}
+
+fn upload_file<C>(
+    client: C,
+    bucket: String,
+    path: PathBuf,
+    key: String,
+    options: MultipartUploadOptions,
+) -> impl Future<Item = (), Error = SyncError>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(error) => return Either::A(future::err(SyncError::Io { path, error })),
+    };
+    let request = PutObjectRequest {
+        bucket,
+        key: key.clone(),
+        body: Some(ByteStream::new(FileChunks { file })),
+        ..Default::default()
+    };
+    Either::B(
+        upload_object(client, request, options)
+            .map(|_| ())
+            .map_err(move |error| SyncError::Upload { key, error }),
+    )
+}
+
+fn delete_objects<C: S3>(
+    client: C,
+    bucket: String,
+    keys: Vec<String>,
+) -> impl Future<Item = (), Error = SyncError> {
+    let request = DeleteObjectsRequest {
+        bucket,
+        delete: Delete {
+            objects: keys
+                .into_iter()
+                .map(|key| ObjectIdentifier {
+                    key,
+                    version_id: None,
+                })
+                .collect(),
+            quiet: Some(true),
+        },
+        ..Default::default()
+    };
+    client
+        .delete_objects(request)
+        .map_err(SyncError::DeleteObjects)
+        .and_then(
+            |output| match output.errors.unwrap_or_default().into_iter().next() {
+                Some(error) => Err(SyncError::DeleteObject {
+                    key: error.key.unwrap_or_default(),
+                    message: error.message.or(error.code).unwrap_or_default(),
+                }),
+                None => Ok(()),
+            },
+        )
+}
+
+/// The content of a file, read as it is needed.
+struct FileChunks {
+    file: File,
+}
+
+impl Stream for FileChunks {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Bytes>, io::Error> {
+        let mut chunk = vec![0; READ_CHUNK_SIZE];
+        let len = self.file.read(&mut chunk)?;
+        if len == 0 {
+            return Ok(Async::Ready(None));
+        }
+        chunk.truncate(len);
+        Ok(Async::Ready(Some(Bytes::from(chunk))))
+    }
+}
@@ -0,0 +1,542 @@
+//! In-place updates of the metadata and tags of existing objects.
+//!
+//! S3 objects are immutable, so their metadata can only be changed by copying an object
+//! onto itself. A self-copy silently resets everything it isn't given: a wrong
+//! `MetadataDirective` drops the user metadata and content headers, and the copy gets the
+//! standard storage class, no encryption and a private ACL unless told otherwise. The
+//! helpers in this module read the current state of the object first and carry it over.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::collections::HashMap;
+//!
+//! use futures::Future;
+//! use rusoto_core::Region;
+//! use rusoto_s3::S3Client;
+//!
+//! let client = S3Client::new(Region::UsEast1);
+//! let mut metadata = HashMap::new();
+//! metadata.insert("reviewed".to_owned(), "true".to_owned());
+//! let output = client
+//!     .update_object_metadata("my-bucket".to_owned(), "my-key".to_owned(), metadata, true)
+//!     .wait()
+//!     .unwrap();
+//! println!("new version: {:?}", output.version_id);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Either, Loop};
+use futures::{stream, Future, Stream};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    AbortMultipartUploadRequest, AccessControlPolicy, CompleteMultipartUploadError,
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart, CopyObjectError,
+    CopyObjectRequest, CreateMultipartUploadError, CreateMultipartUploadRequest, GetObjectAclError,
+    GetObjectAclRequest, GetObjectTaggingError, GetObjectTaggingRequest, HeadObjectError,
+    HeadObjectOutput, HeadObjectRequest, PutObjectAclError, PutObjectAclRequest, S3Client,
+    UploadPartCopyError, UploadPartCopyRequest, S3,
+};
+use crate::multipart::is_retryable;
+
+/// The largest object S3 copies with a single `CopyObject` call. Larger objects are
+/// copied part by part.
+pub const MAX_COPY_SIZE: i64 = 5 * 1024 * 1024 * 1024;
+
+/// Size of the parts of objects copied part by part.
+const COPY_PART_SIZE: i64 = 1024 * 1024 * 1024;
+const COPY_CONCURRENCY: usize = 4;
+const COPY_PART_ATTEMPTS: usize = 3;
+
+/// A change to the metadata, tags or settings of an existing object.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectUpdate {
+    /// Bucket of the object.
+    pub bucket: String,
+    /// Key of the object.
+    pub key: String,
+    /// New user metadata, or `None` to keep the current metadata.
+    pub metadata: Option<HashMap<String, String>>,
+    /// New tags, or `None` to keep the current tags.
+    pub tags: Option<HashMap<String, String>>,
+    /// Whether the new metadata and tags are added to the current ones, overwriting
+    /// entries with the same key, rather than replacing them.
+    pub merge: bool,
+    /// Storage class to move the object to. The current one is kept otherwise.
+    pub storage_class: Option<String>,
+    /// Canned ACL to apply to the object. The current ACL is kept otherwise.
+    pub acl: Option<String>,
+    /// Server-side encryption to apply to the object, along with `ssekms_key_id` for
+    /// `aws:kms`. The current encryption is kept otherwise.
+    pub server_side_encryption: Option<String>,
+    /// KMS key to encrypt the object with when `server_side_encryption` is `aws:kms`.
+    pub ssekms_key_id: Option<String>,
+}
+
+/// The result of a successful object update.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UpdateOutput {
+    /// Entity tag of the updated object.
+    pub e_tag: Option<String>,
+    /// The new version of the object, if the bucket has versioning enabled.
+    pub version_id: Option<String>,
+}
+
+/// Errors returned when updating an object.
+#[derive(Debug)]
+pub enum UpdateError {
+    /// Reading the current state of the object failed.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// Reading the current tags of the object failed.
+    GetObjectTagging(RusotoError<GetObjectTaggingError>),
+    /// Reading the current ACL of the object failed.
+    GetObjectAcl(RusotoError<GetObjectAclError>),
+    /// Copying the object onto itself failed.
+    CopyObject(RusotoError<CopyObjectError>),
+    /// Initiating the multipart copy of a large object failed.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// A part of a large object still failed to copy after all attempts. The multipart
+    /// copy was aborted.
+    UploadPartCopy {
+        /// Number of the failed part, starting at 1.
+        part_number: i64,
+        /// The error of the last attempt.
+        error: RusotoError<UploadPartCopyError>,
+    },
+    /// Completing the multipart copy of a large object failed. The multipart copy was
+    /// aborted.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// Restoring the ACL of the updated object failed. The object was updated but is
+    /// left with a private ACL.
+    PutObjectAcl(RusotoError<PutObjectAclError>),
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UpdateError::HeadObject(ref err) => write!(f, "failed to read object: {}", err),
+            UpdateError::GetObjectTagging(ref err) => {
+                write!(f, "failed to read object tags: {}", err)
+            }
+            UpdateError::GetObjectAcl(ref err) => write!(f, "failed to read object ACL: {}", err),
+            UpdateError::CopyObject(ref err) => write!(f, "failed to copy object: {}", err),
+            UpdateError::CreateMultipartUpload(ref err) => {
+                write!(f, "failed to create multipart copy: {}", err)
+            }
+            UpdateError::UploadPartCopy {
+                part_number,
+                ref error,
+            } => write!(f, "failed to copy part {}: {}", part_number, error),
+            UpdateError::CompleteMultipartUpload(ref err) => {
+                write!(f, "failed to complete multipart copy: {}", err)
+            }
+            UpdateError::PutObjectAcl(ref err) => {
+                write!(f, "failed to restore object ACL: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for UpdateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UpdateError::HeadObject(ref err) => Some(err),
+            UpdateError::GetObjectTagging(ref err) => Some(err),
+            UpdateError::GetObjectAcl(ref err) => Some(err),
+            UpdateError::CopyObject(ref err) => Some(err),
+            UpdateError::CreateMultipartUpload(ref err) => Some(err),
+            UpdateError::UploadPartCopy { ref error, .. } => Some(error),
+            UpdateError::CompleteMultipartUpload(ref err) => Some(err),
+            UpdateError::PutObjectAcl(ref err) => Some(err),
+        }
+    }
+}
+
+impl S3Client {
+    /// Replaces the user metadata of an object, or adds to it when `merge` is set.
+    ///
+    /// The object keeps its tags, content headers, storage class, encryption and ACL.
+    pub fn update_object_metadata(
+        &self,
+        bucket: String,
+        key: String,
+        metadata: HashMap<String, String>,
+        merge: bool,
+    ) -> Box<dyn Future<Item = UpdateOutput, Error = UpdateError> + Send> {
+        self.update_object(ObjectUpdate {
+            bucket,
+            key,
+            metadata: Some(metadata),
+            merge,
+            ..Default::default()
+        })
+    }
+
+    /// Replaces the tags of an object, or adds to them when `merge` is set, by copying
+    /// the object onto itself.
+    ///
+    /// The object keeps its metadata, content headers, storage class, encryption and ACL.
+    pub fn update_object_tags(
+        &self,
+        bucket: String,
+        key: String,
+        tags: HashMap<String, String>,
+        merge: bool,
+    ) -> Box<dyn Future<Item = UpdateOutput, Error = UpdateError> + Send> {
+        self.update_object(ObjectUpdate {
+            bucket,
+            key,
+            tags: Some(tags),
+            merge,
+            ..Default::default()
+        })
+    }
+
+    /// Applies `update` to an object by copying it onto itself.
+    ///
+    /// Whatever `update` doesn't change is carried over from the current object, which
+    /// is read first. Objects larger than `MAX_COPY_SIZE` are copied part by part, and
+    /// their ACL is restored once the copy completes.
+    pub fn update_object(
+        &self,
+        update: ObjectUpdate,
+    ) -> Box<dyn Future<Item = UpdateOutput, Error = UpdateError> + Send> {
+        Box::new(update_object(self.clone(), update))
+    }
+}
+
+fn update_object<C>(
+    client: C,
+    update: ObjectUpdate,
+) -> impl Future<Item = UpdateOutput, Error = UpdateError>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    let head = HeadObjectRequest {
+        bucket: update.bucket.clone(),
+        key: update.key.clone(),
+        ..Default::default()
+    };
+    client
+        .head_object(head)
+        .map_err(UpdateError::HeadObject)
+        .and_then(move |head| {
+            let size = head.content_length.unwrap_or_default();
+            // a multipart copy can't copy tags, so they are always given explicitly
+            let current_tags = if (update.tags.is_some() && update.merge)
+                || (update.tags.is_none() && size > MAX_COPY_SIZE)
+            {
+                Either::A(get_tags(&client, &update, &head))
+            } else {
+                Either::B(future::ok(None))
+            };
+            let current_acl = if update.acl.is_none() {
+                Either::A(get_acl(&client, &update, &head))
+            } else {
+                Either::B(future::ok(None))
+            };
+            current_tags
+                .join(current_acl)
+                .and_then(move |(current_tags, current_acl)| {
+                    let copy = copy_request(&update, &head, current_tags);
+                    let copied = if size > MAX_COPY_SIZE {
+                        Either::A(multipart_copy(client.clone(), copy, size))
+                    } else {
+                        Either::B(
+                            client
+                                .copy_object(copy)
+                                .map(|output| UpdateOutput {
+                                    e_tag: output.copy_object_result.and_then(|r| r.e_tag),
+                                    version_id: output.version_id,
+                                })
+                                .map_err(UpdateError::CopyObject),
+                        )
+                    };
+                    copied.and_then(move |output| match current_acl {
+                        Some(policy) => Either::A(restore_acl(client, update, policy, output)),
+                        None => Either::B(future::ok(output)),
+                    })
+                })
+        })
+}
+
+fn get_tags<C: S3>(
+    client: &C,
+    update: &ObjectUpdate,
+    head: &HeadObjectOutput,
+) -> impl Future<Item = Option<BTreeMap<String, String>>, Error = UpdateError> {
+    let request = GetObjectTaggingRequest {
+        bucket: update.bucket.clone(),
+        key: update.key.clone(),
+        version_id: head.version_id.clone(),
+    };
+    client
+        .get_object_tagging(request)
+        .map(|output| {
+            Some(
+                output
+                    .tag_set
+                    .into_iter()
+                    .map(|tag| (tag.key, tag.value))
+                    .collect(),
+            )
+        })
+        .map_err(UpdateError::GetObjectTagging)
+}
+
+fn get_acl<C: S3>(
+    client: &C,
+    update: &ObjectUpdate,
+    head: &HeadObjectOutput,
+) -> impl Future<Item = Option<AccessControlPolicy>, Error = UpdateError> {
+    let request = GetObjectAclRequest {
+        bucket: update.bucket.clone(),
+        key: update.key.clone(),
+        version_id: head.version_id.clone(),
+        ..Default::default()
+    };
+    client
+        .get_object_acl(request)
+        .map(|output| {
+            Some(AccessControlPolicy {
+                grants: output.grants,
+                owner: output.owner,
+            })
+        })
+        .map_err(UpdateError::GetObjectAcl)
+}
+
+fn restore_acl<C: S3>(
+    client: C,
+    update: ObjectUpdate,
+    policy: AccessControlPolicy,
+    output: UpdateOutput,
+) -> impl Future<Item = UpdateOutput, Error = UpdateError> {
+    let request = PutObjectAclRequest {
+        access_control_policy: Some(policy),
+        bucket: update.bucket,
+        key: update.key,
+        version_id: output.version_id.clone(),
+        ..Default::default()
+    };
+    client
+        .put_object_acl(request)
+        .map(move |_| output)
+        .map_err(UpdateError::PutObjectAcl)
+}
+
+/// The self-copy applying `update`, carrying over everything else from `head`.
+fn copy_request(
+    update: &ObjectUpdate,
+    head: &HeadObjectOutput,
+    current_tags: Option<BTreeMap<String, String>>,
+) -> CopyObjectRequest {
+    let metadata = match update.metadata {
+        Some(ref metadata) if update.merge => {
+            let mut merged = head.metadata.clone().unwrap_or_default();
+            merged.extend(metadata.clone());
+            merged
+        }
+        Some(ref metadata) => metadata.clone(),
+        None => head.metadata.clone().unwrap_or_default(),
+    };
+    let (tagging, tagging_directive) = match update.tags {
+        Some(ref tags) => {
+            let mut tagging = if update.merge {
+                current_tags.unwrap_or_default()
+            } else {
+                BTreeMap::new()
+            };
+            tagging.extend(tags.clone());
+            (Some(encode_tags(&tagging)), "REPLACE")
+        }
+        None => (current_tags.map(|tags| encode_tags(&tags)), "COPY"),
+    };
+    let (server_side_encryption, ssekms_key_id) = if update.server_side_encryption.is_some() {
+        (
+            update.server_side_encryption.clone(),
+            update.ssekms_key_id.clone(),
+        )
+    } else {
+        (
+            head.server_side_encryption.clone(),
+            head.ssekms_key_id.clone(),
+        )
+    };
+
+    let mut copy_source = format!("{}/{}", update.bucket, encode(&update.key, true));
+    if let Some(ref version_id) = head.version_id {
+        copy_source = format!("{}?versionId={}", copy_source, encode(version_id, false));
+    }
+
+    CopyObjectRequest {
+        acl: update.acl.clone(),
+        bucket: update.bucket.clone(),
+        cache_control: head.cache_control.clone(),
+        content_disposition: head.content_disposition.clone(),
+        content_encoding: head.content_encoding.clone(),
+        content_language: head.content_language.clone(),
+        content_type: head.content_type.clone(),
+        copy_source,
+        expires: head.expires.clone(),
+        key: update.key.clone(),
+        metadata: Some(metadata),
+        metadata_directive: Some("REPLACE".to_owned()),
+        server_side_encryption,
+        ssekms_key_id,
+        storage_class: update
+            .storage_class
+            .clone()
+            .or_else(|| head.storage_class.clone()),
+        tagging,
+        tagging_directive: Some(tagging_directive.to_owned()),
+        website_redirect_location: head.website_redirect_location.clone(),
+        ..Default::default()
+    }
+}
+
+/// Copies an object larger than `MAX_COPY_SIZE` part by part, with the settings of `copy`.
+fn multipart_copy<C>(
+    client: C,
+    copy: CopyObjectRequest,
+    size: i64,
+) -> impl Future<Item = UpdateOutput, Error = UpdateError>
+where
+    C: S3 + Clone + Send + 'static,
+{
+    let create = CreateMultipartUploadRequest {
+        acl: copy.acl,
+        bucket: copy.bucket.clone(),
+        cache_control: copy.cache_control,
+        content_disposition: copy.content_disposition,
+        content_encoding: copy.content_encoding,
+        content_language: copy.content_language,
+        content_type: copy.content_type,
+        expires: copy.expires,
+        key: copy.key.clone(),
+        metadata: copy.metadata,
+        server_side_encryption: copy.server_side_encryption,
+        ssekms_key_id: copy.ssekms_key_id,
+        storage_class: copy.storage_class,
+        tagging: copy.tagging,
+        website_redirect_location: copy.website_redirect_location,
+        ..Default::default()
+    };
+    let bucket = copy.bucket;
+    let key = copy.key;
+    let copy_source = copy.copy_source;
+
+    client
+        .create_multipart_upload(create)
+        .map_err(UpdateError::CreateMultipartUpload)
+        .and_then(move |output| {
+            let upload_id = output.upload_id.unwrap_or_default();
+            let part_count = (size + COPY_PART_SIZE - 1) / COPY_PART_SIZE;
+            let parts = {
+                let client = client.clone();
+                let request = UploadPartCopyRequest {
+                    bucket: bucket.clone(),
+                    copy_source,
+                    key: key.clone(),
+                    upload_id: upload_id.clone(),
+                    ..Default::default()
+                };
+                stream::iter_ok(1..=part_count)
+                    .map(move |part_number| {
+                        let start = (part_number - 1) * COPY_PART_SIZE;
+                        let end = (start + COPY_PART_SIZE).min(size) - 1;
+                        let request = UploadPartCopyRequest {
+                            copy_source_range: Some(format!("bytes={}-{}", start, end)),
+                            part_number,
+                            ..request.clone()
+                        };
+                        copy_part(client.clone(), request)
+                    })
+                    .buffer_unordered(COPY_CONCURRENCY)
+                    .collect()
+            };
+
+            parts
+                .and_then({
+                    let client = client.clone();
+                    let (bucket, key, upload_id) = (bucket.clone(), key.clone(), upload_id.clone());
+                    move |mut parts: Vec<CompletedPart>| {
+                        parts.sort_by_key(|part| part.part_number);
+                        let request = CompleteMultipartUploadRequest {
+                            bucket,
+                            key,
+                            multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+                            upload_id,
+                            ..Default::default()
+                        };
+                        client
+                            .complete_multipart_upload(request)
+                            .map(|output| UpdateOutput {
+                                e_tag: output.e_tag,
+                                version_id: output.version_id,
+                            })
+                            .map_err(UpdateError::CompleteMultipartUpload)
+                    }
+                })
+                .or_else(move |err| {
+                    let request = AbortMultipartUploadRequest {
+                        bucket,
+                        key,
+                        upload_id,
+                        ..Default::default()
+                    };
+                    // the original error is more useful to the caller than a failure to abort
+                    client
+                        .abort_multipart_upload(request)
+                        .then(move |_| Err(err))
+                })
+        })
+}
+
+fn copy_part<C: S3>(
+    client: C,
+    request: UploadPartCopyRequest,
+) -> impl Future<Item = CompletedPart, Error = UpdateError> {
+    future::loop_fn(1, move |attempt| {
+        let part_number = request.part_number;
+        client
+            .upload_part_copy(request.clone())
+            .then(move |result| match result {
+                Ok(output) => Ok(Loop::Break(CompletedPart {
+                    e_tag: output.copy_part_result.and_then(|result| result.e_tag),
+                    part_number: Some(part_number),
+                })),
+                Err(ref error) if attempt < COPY_PART_ATTEMPTS && is_retryable(error) => {
+                    Ok(Loop::Continue(attempt + 1))
+                }
+                Err(error) => Err(UpdateError::UploadPartCopy { part_number, error }),
+            })
+    })
+}
+
+/// Tags in the URL query form of the `x-amz-tagging` header.
+fn encode_tags(tags: &BTreeMap<String, String>) -> String {
+    tags.iter()
+        .map(|(key, value)| format!("{}={}", encode(key, false), encode(value, false)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Percent-encodes all but unreserved characters, and slashes when `keep_slashes` is set.
+fn encode(value: &str, keep_slashes: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if keep_slashes => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
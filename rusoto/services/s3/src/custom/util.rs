@@ -26,6 +26,28 @@ pub fn encode_key<T: AsRef<str>>(key: T) -> String {
     signature::encode_uri_path(key.as_ref())
 }
 
+/// Builds the URL encoded request path for an object in a bucket.
+///
+/// The `/` separators within the key are left as-is, since S3 treats them as a
+/// hierarchy, while every other reserved or non-ASCII character is percent encoded.
+/// The result matches the canonical path used when signing a request for the same
+/// bucket and key, so it can safely be used to build URLs by hand.
+///
+/// Note that `SignedRequest` expects an unencoded path and performs this encoding
+/// itself; passing the result of this function to it would encode the key twice.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     rusoto_s3::util::object_path("my-bucket", "photos/2019/a+b c%.jpg"),
+///     "/my-bucket/photos/2019/a%2Bb%20c%25.jpg"
+/// );
+/// ```
+pub fn object_path<B: AsRef<str>, K: AsRef<str>>(bucket: B, key: K) -> String {
+    encode_key(format!("/{}/{}", bucket.as_ref(), key.as_ref()))
+}
+
 macro_rules! add_headers {
     (
         $input:ident , $req:ident ; $p:ident , $e:expr ; $( $t:tt )*
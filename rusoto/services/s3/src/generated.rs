@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use std::io::Write;
 use std::str::FromStr;
 use xml;
@@ -37,6 +38,8 @@ use xml::EventWriter;
 
 /// <p>Specifies the days since the initiation of an incomplete multipart upload that Amazon S3 will wait before permanently removing all parts of the upload. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html#mpu-abort-incomplete-mpu-lifecycle-config"> Aborting Incomplete Multipart Uploads Using a Bucket Lifecycle Policy</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortIncompleteMultipartUpload {
     /// <p>Specifies the number of days after which Amazon S3 aborts an incomplete multipart upload.</p>
     pub days_after_initiation: Option<i64>,
@@ -94,6 +97,8 @@ impl AbortIncompleteMultipartUploadSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortMultipartUploadOutput {
     pub request_charged: Option<String>,
 }
@@ -115,6 +120,8 @@ impl AbortMultipartUploadOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortMultipartUploadRequest {
     /// <p>Name of the bucket to which the multipart upload was initiated.</p>
     pub bucket: String,
@@ -125,8 +132,21 @@ pub struct AbortMultipartUploadRequest {
     pub upload_id: String,
 }
 
+impl Validate for AbortMultipartUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Configures the transfer acceleration state for an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/transfer-acceleration.html">Amazon S3 Transfer Acceleration</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccelerateConfiguration {
     /// <p>Specifies the transfer acceleration status of the bucket.</p>
     pub status: Option<String>,
@@ -158,6 +178,8 @@ impl AccelerateConfigurationSerializer {
 
 /// <p>Contains the elements that set the ACL permissions for an object per grantee.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccessControlPolicy {
     /// <p>A list of grants.</p>
     pub grants: Option<Vec<Grant>>,
@@ -189,6 +211,8 @@ impl AccessControlPolicySerializer {
 
 /// <p>A container for information about access control for replicas.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccessControlTranslation {
     /// <p>Specifies the replica ownership. For default and valid values, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTreplication.html">PUT bucket replication</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
     pub owner: String,
@@ -527,6 +551,8 @@ impl AllowedOriginsSerializer {
 
 /// <p>A conjunction (logical AND) of predicates, which is used in evaluating a metrics filter. The operator must have at least two predicates in any combination, and an object must match all of the predicates for the filter to apply.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyticsAndOperator {
     /// <p>The prefix to use when evaluating an AND predicate: The prefix that an object must have to be included in the metrics results.</p>
     pub prefix: Option<String>,
@@ -534,6 +560,17 @@ pub struct AnalyticsAndOperator {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for AnalyticsAndOperator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct AnalyticsAndOperatorDeserializer;
 impl AnalyticsAndOperatorDeserializer {
     #[allow(unused_variables)]
@@ -587,6 +624,8 @@ impl AnalyticsAndOperatorSerializer {
 
 /// <p> Specifies the configuration and any analyses for the analytics filter of an Amazon S3 bucket.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketGETAnalyticsConfig.html">GET Bucket analytics</a> in the <i>Amazon Simple Storage Service API Reference</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyticsConfiguration {
     /// <p>The filter used to describe a set of objects for analyses. A filter must have exactly one prefix, one tag, or one conjunction (AnalyticsAndOperator). If no filter is provided, all objects will be considered in any analysis.</p>
     pub filter: Option<AnalyticsFilter>,
@@ -596,6 +635,14 @@ pub struct AnalyticsConfiguration {
     pub storage_class_analysis: StorageClassAnalysis,
 }
 
+impl Validate for AnalyticsConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+    }
+}
+
 struct AnalyticsConfigurationDeserializer;
 impl AnalyticsConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -685,6 +732,8 @@ impl AnalyticsConfigurationListDeserializer {
 }
 /// <p>Where to publish the analytics results.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyticsExportDestination {
     /// <p>A destination signifying output to an S3 bucket.</p>
     pub s3_bucket_destination: AnalyticsS3BucketDestination,
@@ -740,6 +789,8 @@ impl AnalyticsExportDestinationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyticsFilter {
     /// <p>A conjunction (logical AND) of predicates, which is used in evaluating an analytics filter. The operator must have at least two predicates.</p>
     pub and: Option<AnalyticsAndOperator>,
@@ -749,6 +800,17 @@ pub struct AnalyticsFilter {
     pub tag: Option<Tag>,
 }
 
+impl Validate for AnalyticsFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.and {
+            value.validate_at(&field_path(path, "and"), errors);
+        }
+        if let Some(ref value) = self.tag {
+            value.validate_at(&field_path(path, "tag"), errors);
+        }
+    }
+}
+
 struct AnalyticsFilterDeserializer;
 impl AnalyticsFilterDeserializer {
     #[allow(unused_variables)]
@@ -838,6 +900,8 @@ impl AnalyticsIdSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyticsS3BucketDestination {
     /// <p>The Amazon Resource Name (ARN) of the bucket to which data is exported.</p>
     pub bucket: String,
@@ -999,6 +1063,8 @@ impl BodySerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Bucket {
     /// <p>Date the bucket was created.</p>
     pub creation_date: Option<String>,
@@ -1061,11 +1127,22 @@ impl BucketAccelerateStatusSerializer {
 
 /// <p>Specifies the lifecycle configuration for objects in an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/object-lifecycle-mgmt.html">Object Lifecycle Management</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BucketLifecycleConfiguration {
     /// <p>A lifecycle rule for individual objects in an Amazon S3 bucket.</p>
     pub rules: Vec<LifecycleRule>,
 }
 
+impl Validate for BucketLifecycleConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.rules.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rules"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 pub struct BucketLifecycleConfigurationSerializer;
 impl BucketLifecycleConfigurationSerializer {
     #[allow(unused_variables, warnings)]
@@ -1117,6 +1194,8 @@ impl BucketLocationConstraintSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BucketLoggingStatus {
     /// <p><p/></p>
     pub logging_enabled: Option<LoggingEnabled>,
@@ -1259,7 +1338,7 @@ impl BytesProcessedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1270,7 +1349,7 @@ impl BytesReturnedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1281,7 +1360,7 @@ impl BytesScannedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1289,6 +1368,8 @@ impl BytesScannedDeserializer {
 }
 /// <p>Describes the cross-origin access configuration for objects in an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/cors.html">Enabling Cross-Origin Resource Sharing</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CORSConfiguration {
     /// <p>A set of allowed origins and methods.</p>
     pub cors_rules: Vec<CORSRule>,
@@ -1313,6 +1394,8 @@ impl CORSConfigurationSerializer {
 
 /// <p>Specifies a cross-origin access rule for an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CORSRule {
     /// <p>Headers that are specified in the <code>Access-Control-Request-Headers</code> header. These headers are allowed in a preflight OPTIONS request. In response to any preflight OPTIONS request, Amazon S3 returns any requested headers that are allowed.</p>
     pub allowed_headers: Option<Vec<String>>,
@@ -1452,6 +1535,8 @@ impl CORSRulesSerializer {
 
 /// <p>Describes how a CSV-formatted input object is formatted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CSVInput {
     /// <p>Specifies that CSV field values may contain quoted record delimiters and such records should be allowed. Default value is FALSE. Setting this value to TRUE may lower performance.</p>
     pub allow_quoted_record_delimiter: Option<bool>,
@@ -1545,6 +1630,8 @@ impl CSVInputSerializer {
 
 /// <p>Describes how CSV-formatted results are formatted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CSVOutput {
     /// <p>The value used to separate individual fields in a record.</p>
     pub field_delimiter: Option<String>,
@@ -1648,6 +1735,8 @@ impl CloudFunctionSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudFunctionConfiguration {
     /// <p><p/></p>
     pub cloud_function: Option<String>,
@@ -1808,6 +1897,8 @@ impl CommentsSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CommonPrefix {
     /// <p><p/></p>
     pub prefix: Option<String>,
@@ -1859,6 +1950,8 @@ impl CommonPrefixListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteMultipartUploadOutput {
     /// <p><p/></p>
     pub bucket: Option<String>,
@@ -1911,6 +2004,8 @@ impl CompleteMultipartUploadOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteMultipartUploadRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -1923,8 +2018,21 @@ pub struct CompleteMultipartUploadRequest {
     pub upload_id: String,
 }
 
+impl Validate for CompleteMultipartUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompletedMultipartUpload {
     /// <p><p/></p>
     pub parts: Option<Vec<CompletedPart>>,
@@ -1951,6 +2059,8 @@ impl CompletedMultipartUploadSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompletedPart {
     /// <p>Entity tag returned when the part was uploaded.</p>
     pub e_tag: Option<String>,
@@ -2030,6 +2140,8 @@ impl CompressionTypeSerializer {
 
 /// <p>Specifies a condition that must be met for a redirect to apply. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Condition {
     /// <p>The HTTP error code when the redirect is applied. In the event of an error, if the error code equals this value, then the specified redirect is applied. Required when parent element <code>Condition</code> is specified and sibling <code>KeyPrefixEquals</code> is not specified. If both are specified, then both must be true for the redirect to be applied.</p>
     pub http_error_code_returned_equals: Option<String>,
@@ -2102,6 +2214,8 @@ impl ConditionSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContinuationEvent {}
 
 struct ContinuationEventDeserializer;
@@ -2121,6 +2235,8 @@ impl ContinuationEventDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyObjectOutput {
     /// <p><p/></p>
     pub copy_object_result: Option<CopyObjectResult>,
@@ -2160,6 +2276,8 @@ impl CopyObjectOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyObjectRequest {
     /// <p>The canned ACL to apply to the object.</p>
     pub acl: Option<String>,
@@ -2236,8 +2354,26 @@ pub struct CopyObjectRequest {
     pub website_redirect_location: Option<String>,
 }
 
+impl Validate for CopyObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "copy_source"),
+            &self.copy_source,
+            "\\/.+\\/.+",
+        );
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyObjectResult {
     /// <p><p/></p>
     pub e_tag: Option<String>,
@@ -2271,6 +2407,8 @@ impl CopyObjectResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyPartResult {
     /// <p>Entity tag of the object.</p>
     pub e_tag: Option<String>,
@@ -2304,6 +2442,8 @@ impl CopyPartResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBucketConfiguration {
     /// <p>Specifies the region where the bucket will be created. If you don't specify a region, the bucket is created in US East (N. Virginia) Region (us-east-1).</p>
     pub location_constraint: Option<String>,
@@ -2334,6 +2474,8 @@ impl CreateBucketConfigurationSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBucketOutput {
     /// <p><p/></p>
     pub location: Option<String>,
@@ -2356,6 +2498,8 @@ impl CreateBucketOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateBucketRequest {
     /// <p>The canned ACL to apply to the bucket.</p>
     pub acl: Option<String>,
@@ -2378,6 +2522,8 @@ pub struct CreateBucketRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMultipartUploadOutput {
     /// <p>Date when multipart upload will become eligible for abort operation by lifecycle.</p>
     pub abort_date: Option<String>,
@@ -2433,6 +2579,8 @@ impl CreateMultipartUploadOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMultipartUploadRequest {
     /// <p>The canned ACL to apply to the object.</p>
     pub acl: Option<String>,
@@ -2489,6 +2637,17 @@ pub struct CreateMultipartUploadRequest {
     pub website_redirect_location: Option<String>,
 }
 
+impl Validate for CreateMultipartUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 struct CreationDateDeserializer;
 impl CreationDateDeserializer {
     #[allow(unused_variables)]
@@ -2537,7 +2696,7 @@ impl DaysDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2569,7 +2728,7 @@ impl DaysAfterInitiationDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2598,6 +2757,8 @@ impl DaysAfterInitiationSerializer {
 
 /// <p>The container element for specifying the default object lock retention settings for new objects placed in the specified bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DefaultRetention {
     /// <p>The number of days that you want to specify for the default retention period.</p>
     pub days: Option<i64>,
@@ -2676,6 +2837,8 @@ impl DefaultRetentionSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Delete {
     /// <p><p/></p>
     pub objects: Vec<ObjectIdentifier>,
@@ -2683,6 +2846,15 @@ pub struct Delete {
     pub quiet: Option<bool>,
 }
 
+impl Validate for Delete {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.objects.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "objects"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 pub struct DeleteSerializer;
 impl DeleteSerializer {
     #[allow(unused_variables, warnings)]
@@ -2709,6 +2881,8 @@ impl DeleteSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketAnalyticsConfigurationRequest {
     /// <p>The name of the bucket from which an analytics configuration is deleted.</p>
     pub bucket: String,
@@ -2717,18 +2891,24 @@ pub struct DeleteBucketAnalyticsConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketCorsRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketEncryptionRequest {
     /// <p>The name of the bucket containing the server-side encryption configuration to delete.</p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketInventoryConfigurationRequest {
     /// <p>The name of the bucket containing the inventory configuration to delete.</p>
     pub bucket: String,
@@ -2737,12 +2917,16 @@ pub struct DeleteBucketInventoryConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketLifecycleRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketMetricsConfigurationRequest {
     /// <p>The name of the bucket containing the metrics configuration to delete.</p>
     pub bucket: String,
@@ -2751,30 +2935,40 @@ pub struct DeleteBucketMetricsConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketPolicyRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketReplicationRequest {
     /// <p><p> The bucket name. </p> <note> <p>It can take a while to propagate the deletion of a replication configuration to all Amazon S3 systems.</p> </note></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketTaggingRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBucketWebsiteRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -2785,7 +2979,7 @@ impl DeleteMarkerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2793,6 +2987,8 @@ impl DeleteMarkerDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMarkerEntry {
     /// <p>Specifies whether the object is (true) or is not (false) the latest version of an object.</p>
     pub is_latest: Option<bool>,
@@ -2844,6 +3040,8 @@ impl DeleteMarkerEntryDeserializer {
 }
 /// <p>Specifies whether Amazon S3 should replicate delete makers.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMarkerReplication {
     /// <p><p>The status of the delete marker replication.</p> <note> <p> In the current implementation, Amazon S3 doesn&#39;t replicate the delete markers. The status must be <code>Disabled</code>. </p> </note></p>
     pub status: Option<String>,
@@ -2969,6 +3167,8 @@ impl DeleteMarkersDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectOutput {
     /// <p>Specifies whether the versioned object that was permanently deleted was (true) or was not (false) a delete marker.</p>
     pub delete_marker: Option<bool>,
@@ -2994,6 +3194,8 @@ impl DeleteObjectOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -3008,7 +3210,20 @@ pub struct DeleteObjectRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for DeleteObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectTaggingOutput {
     /// <p>The versionId of the object the tag-set was removed from.</p>
     pub version_id: Option<String>,
@@ -3031,6 +3246,8 @@ impl DeleteObjectTaggingOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectTaggingRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -3040,7 +3257,20 @@ pub struct DeleteObjectTaggingRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for DeleteObjectTaggingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectsOutput {
     /// <p><p/></p>
     pub deleted: Option<Vec<DeletedObject>>,
@@ -3075,6 +3305,8 @@ impl DeleteObjectsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteObjectsRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -3087,7 +3319,15 @@ pub struct DeleteObjectsRequest {
     pub request_payer: Option<String>,
 }
 
+impl Validate for DeleteObjectsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.delete.validate_at(&field_path(path, "delete"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePublicAccessBlockRequest {
     /// <p>The Amazon S3 bucket whose <code>PublicAccessBlock</code> configuration you want to delete. </p>
     pub bucket: String,
@@ -3095,6 +3335,8 @@ pub struct DeletePublicAccessBlockRequest {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletedObject {
     /// <p><p/></p>
     pub delete_marker: Option<bool>,
@@ -3224,6 +3466,8 @@ impl DescriptionSerializer {
 
 /// <p>Specifies information about where to publish analysis or configuration results for an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Destination {
     /// <p>Specify this only in a cross-account scenario (where source and destination bucket owners are not the same), and you want to change replica ownership to the AWS account that owns the destination bucket. If this is not specified in the replication configuration, the replicas are owned by same AWS account that owns the source object.</p>
     pub access_control_translation: Option<AccessControlTranslation>,
@@ -3481,6 +3725,8 @@ impl EncodingTypeSerializer {
 
 /// <p>Describes the server-side encryption that will be applied to the restore results.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Encryption {
     /// <p>The server-side encryption algorithm used when storing job results in Amazon S3 (e.g., AES256, aws:kms).</p>
     pub encryption_type: String,
@@ -3530,6 +3776,8 @@ impl EncryptionSerializer {
 
 /// <p>Specifies encryption-related information for an Amazon S3 bucket that is a destination for replicated objects.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EncryptionConfiguration {
     /// <p>Specifies the AWS KMS Key ID (Key ARN or Alias ARN) for the destination bucket. Amazon S3 uses this key to encrypt replica objects.</p>
     pub replica_kms_key_id: Option<String>,
@@ -3587,6 +3835,8 @@ impl EncryptionConfigurationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EndEvent {}
 
 struct EndEventDeserializer;
@@ -3607,6 +3857,8 @@ impl EndEventDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Error {
     /// <p><p/></p>
     pub code: Option<String>,
@@ -3650,11 +3902,24 @@ impl S3ErrorDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ErrorDocument {
     /// <p>The object key name to use when a 4XX class error occurs.</p>
     pub key: String,
 }
 
+impl Validate for ErrorDocument {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 struct ErrorDocumentDeserializer;
 impl ErrorDocumentDeserializer {
     #[allow(unused_variables)]
@@ -3838,7 +4103,7 @@ impl ExpiredObjectDeleteMarkerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4045,6 +4310,8 @@ impl FileHeaderInfoSerializer {
 
 /// <p>Specifies the Amazon S3 object key name to filter on and whether to filter on the suffix or prefix of the key name.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FilterRule {
     /// <p>The object key name prefix or suffix identifying one or more objects to which the filtering rule applies. The maximum length is 1,024 characters. Overlapping prefixes and suffixes are not supported. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Event Notifications</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub name: Option<String>,
@@ -4217,6 +4484,8 @@ impl FilterRuleValueSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketAccelerateConfigurationOutput {
     /// <p>The accelerate configuration of the bucket.</p>
     pub status: Option<String>,
@@ -4247,12 +4516,16 @@ impl GetBucketAccelerateConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketAccelerateConfigurationRequest {
     /// <p>Name of the bucket for which the accelerate configuration is retrieved.</p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketAclOutput {
     /// <p>A list of grants.</p>
     pub grants: Option<Vec<Grant>>,
@@ -4284,12 +4557,16 @@ impl GetBucketAclOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketAclRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketAnalyticsConfigurationOutput {
     /// <p>The configuration and any analyses for the analytics filter.</p>
     pub analytics_configuration: Option<AnalyticsConfiguration>,
@@ -4312,6 +4589,8 @@ impl GetBucketAnalyticsConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketAnalyticsConfigurationRequest {
     /// <p>The name of the bucket from which an analytics configuration is retrieved.</p>
     pub bucket: String,
@@ -4320,6 +4599,8 @@ pub struct GetBucketAnalyticsConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketCorsOutput {
     /// <p><p/></p>
     pub cors_rules: Option<Vec<CORSRule>>,
@@ -4346,12 +4627,16 @@ impl GetBucketCorsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketCorsRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketEncryptionOutput {
     /// <p><p/></p>
     pub server_side_encryption_configuration: Option<ServerSideEncryptionConfiguration>,
@@ -4376,12 +4661,16 @@ impl GetBucketEncryptionOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketEncryptionRequest {
     /// <p>The name of the bucket from which the server-side encryption configuration is retrieved.</p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketInventoryConfigurationOutput {
     /// <p>Specifies the inventory configuration.</p>
     pub inventory_configuration: Option<InventoryConfiguration>,
@@ -4404,6 +4693,8 @@ impl GetBucketInventoryConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketInventoryConfigurationRequest {
     /// <p>The name of the bucket containing the inventory configuration to retrieve.</p>
     pub bucket: String,
@@ -4412,6 +4703,8 @@ pub struct GetBucketInventoryConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLifecycleConfigurationOutput {
     /// <p><p/></p>
     pub rules: Option<Vec<LifecycleRule>>,
@@ -4442,12 +4735,16 @@ impl GetBucketLifecycleConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLifecycleConfigurationRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLifecycleOutput {
     /// <p><p/></p>
     pub rules: Option<Vec<Rule>>,
@@ -4478,12 +4775,16 @@ impl GetBucketLifecycleOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLifecycleRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLocationOutput {
     /// <p><p/></p>
     pub location_constraint: Option<String>,
@@ -4505,12 +4806,16 @@ impl GetBucketLocationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLocationRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLoggingOutput {
     /// <p><p/></p>
     pub logging_enabled: Option<LoggingEnabled>,
@@ -4538,12 +4843,16 @@ impl GetBucketLoggingOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketLoggingRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketMetricsConfigurationOutput {
     /// <p>Specifies the metrics configuration.</p>
     pub metrics_configuration: Option<MetricsConfiguration>,
@@ -4566,6 +4875,8 @@ impl GetBucketMetricsConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketMetricsConfigurationRequest {
     /// <p>The name of the bucket containing the metrics configuration to retrieve.</p>
     pub bucket: String,
@@ -4574,24 +4885,32 @@ pub struct GetBucketMetricsConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketNotificationConfigurationRequest {
     /// <p>Name of the bucket to get the notification configuration for.</p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketPolicyOutput {
     /// <p>The bucket policy as a JSON document.</p>
     pub policy: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketPolicyRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketPolicyStatusOutput {
     /// <p>The policy status for the specified bucket.</p>
     pub policy_status: Option<PolicyStatus>,
@@ -4614,12 +4933,16 @@ impl GetBucketPolicyStatusOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketPolicyStatusRequest {
     /// <p>The name of the Amazon S3 bucket whose policy status you want to retrieve.</p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketReplicationOutput {
     /// <p><p/></p>
     pub replication_configuration: Option<ReplicationConfiguration>,
@@ -4642,12 +4965,16 @@ impl GetBucketReplicationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketReplicationRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketRequestPaymentOutput {
     /// <p>Specifies who pays for the download and request fees.</p>
     pub payer: Option<String>,
@@ -4676,12 +5003,16 @@ impl GetBucketRequestPaymentOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketRequestPaymentRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketTaggingOutput {
     /// <p><p/></p>
     pub tag_set: Vec<Tag>,
@@ -4707,12 +5038,16 @@ impl GetBucketTaggingOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketTaggingRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketVersioningOutput {
     /// <p>Specifies whether MFA delete is enabled in the bucket versioning configuration. This element is only returned if the bucket has been configured with MFA delete. If the bucket has never been so configured, this element is not returned.</p>
     pub mfa_delete: Option<String>,
@@ -4751,12 +5086,16 @@ impl GetBucketVersioningOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketVersioningRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketWebsiteOutput {
     /// <p><p/></p>
     pub error_document: Option<ErrorDocument>,
@@ -4808,12 +5147,16 @@ impl GetBucketWebsiteOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetBucketWebsiteRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectAclOutput {
     /// <p>A list of grants.</p>
     pub grants: Option<Vec<Grant>>,
@@ -4846,6 +5189,8 @@ impl GetObjectAclOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectAclRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -4856,7 +5201,20 @@ pub struct GetObjectAclRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetObjectAclRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectLegalHoldOutput {
     /// <p>The current Legal Hold status for the specified object.</p>
     pub legal_hold: Option<ObjectLockLegalHold>,
@@ -4879,6 +5237,8 @@ impl GetObjectLegalHoldOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectLegalHoldRequest {
     /// <p>The bucket containing the object whose Legal Hold status you want to retrieve.</p>
     pub bucket: String,
@@ -4889,7 +5249,20 @@ pub struct GetObjectLegalHoldRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetObjectLegalHoldRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectLockConfigurationOutput {
     /// <p>The specified bucket's object lock configuration.</p>
     pub object_lock_configuration: Option<ObjectLockConfiguration>,
@@ -4912,16 +5285,24 @@ impl GetObjectLockConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectLockConfigurationRequest {
     /// <p>The bucket whose object lock configuration you want to retrieve.</p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectOutput {
     /// <p><p/></p>
     pub accept_ranges: Option<String>,
     /// <p>Object data.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(skip)
+    )]
     pub body: Option<StreamingBody>,
     /// <p>Specifies caching behavior along the request/reply chain.</p>
     pub cache_control: Option<String>,
@@ -4983,6 +5364,8 @@ pub struct GetObjectOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -5023,7 +5406,20 @@ pub struct GetObjectRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectRetentionOutput {
     /// <p>The container element for an object's retention settings.</p>
     pub retention: Option<ObjectLockRetention>,
@@ -5046,6 +5442,8 @@ impl GetObjectRetentionOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectRetentionRequest {
     /// <p>The bucket containing the object whose retention settings you want to retrieve.</p>
     pub bucket: String,
@@ -5056,7 +5454,20 @@ pub struct GetObjectRetentionRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetObjectRetentionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectTaggingOutput {
     /// <p><p/></p>
     pub tag_set: Vec<Tag>,
@@ -5084,6 +5495,8 @@ impl GetObjectTaggingOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectTaggingRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -5093,14 +5506,33 @@ pub struct GetObjectTaggingRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for GetObjectTaggingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectTorrentOutput {
     /// <p><p/></p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(skip)
+    )]
     pub body: Option<StreamingBody>,
     pub request_charged: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetObjectTorrentRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -5109,7 +5541,20 @@ pub struct GetObjectTorrentRequest {
     pub request_payer: Option<String>,
 }
 
+impl Validate for GetObjectTorrentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPublicAccessBlockOutput {
     /// <p>The <code>PublicAccessBlock</code> configuration currently in effect for this Amazon S3 bucket.</p>
     pub public_access_block_configuration: Option<PublicAccessBlockConfiguration>,
@@ -5134,6 +5579,8 @@ impl GetPublicAccessBlockOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPublicAccessBlockRequest {
     /// <p>The name of the Amazon S3 bucket whose <code>PublicAccessBlock</code> configuration you want to retrieve. </p>
     pub bucket: String,
@@ -5141,6 +5588,8 @@ pub struct GetPublicAccessBlockRequest {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GlacierJobParameters {
     /// <p>Glacier retrieval tier at which the restore will be processed.</p>
     pub tier: String,
@@ -5170,6 +5619,8 @@ impl GlacierJobParametersSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Grant {
     /// <p><p/></p>
     pub grantee: Option<Grantee>,
@@ -5226,6 +5677,8 @@ impl GrantSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Grantee {
     /// <p>Screen name of the grantee.</p>
     pub display_name: Option<String>,
@@ -5367,12 +5820,16 @@ impl GrantsSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HeadBucketRequest {
     /// <p><p/></p>
     pub bucket: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HeadObjectOutput {
     /// <p><p/></p>
     pub accept_ranges: Option<String>,
@@ -5448,6 +5905,8 @@ impl HeadObjectOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct HeadObjectRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -5476,6 +5935,17 @@ pub struct HeadObjectRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for HeadObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 struct HostNameDeserializer;
 impl HostNameDeserializer {
     #[allow(unused_variables)]
@@ -5606,6 +6076,8 @@ impl IDSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IndexDocument {
     /// <p>A suffix that is appended to a request that is for a directory on the website endpoint (e.g. if the suffix is index.html and you make a request to samplebucket/images/ the data that is returned will be for the object with the key name images/index.html) The suffix must not be empty and must not include a slash character.</p>
     pub suffix: String,
@@ -5665,6 +6137,8 @@ impl InitiatedDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Initiator {
     /// <p>Name of the Principal.</p>
     pub display_name: Option<String>,
@@ -5696,6 +6170,8 @@ impl InitiatorDeserializer {
 }
 /// <p>Describes the serialization format of the object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InputSerialization {
     /// <p>Describes the serialization of a CSV-encoded object.</p>
     pub csv: Option<CSVInput>,
@@ -5742,6 +6218,8 @@ impl InputSerializationSerializer {
 
 /// <p>Specifies the inventory configuration for an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketGETInventoryConfig.html">GET Bucket inventory</a> in the <i>Amazon Simple Storage Service API Reference</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryConfiguration {
     /// <p>Contains information about where to publish the inventory results.</p>
     pub destination: InventoryDestination,
@@ -5878,6 +6356,8 @@ impl InventoryConfigurationListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryDestination {
     /// <p>Contains the bucket name, file format, bucket owner (optional), and prefix (optional) where inventory results are published.</p>
     pub s3_bucket_destination: InventoryS3BucketDestination,
@@ -5929,6 +6409,8 @@ impl InventoryDestinationSerializer {
 
 /// <p>Contains the type of server-side encryption used to encrypt the inventory results.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryEncryption {
     /// <p>Specifies the use of SSE-KMS to encrypt delivered Inventory reports.</p>
     pub ssekms: Option<SSEKMS>,
@@ -5982,6 +6464,8 @@ impl InventoryEncryptionSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryFilter {
     /// <p>The prefix that an object must have to be included in the inventory results.</p>
     pub prefix: String,
@@ -6230,6 +6714,8 @@ impl InventoryOptionalFieldsSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryS3BucketDestination {
     /// <p>The ID of the account that owns the destination bucket.</p>
     pub account_id: Option<String>,
@@ -6331,6 +6817,8 @@ impl InventoryS3BucketDestinationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventorySchedule {
     /// <p>Specifies how frequently inventory results are produced.</p>
     pub frequency: String,
@@ -6383,7 +6871,7 @@ impl IsEnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6415,7 +6903,7 @@ impl IsLatestDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6426,7 +6914,7 @@ impl IsPublicDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6437,7 +6925,7 @@ impl IsTruncatedDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6445,6 +6933,8 @@ impl IsTruncatedDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct JSONInput {
     /// <p>The type of JSON. Valid values: Document, Lines.</p>
     pub type_: Option<String>,
@@ -6476,6 +6966,8 @@ impl JSONInputSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct JSONOutput {
     /// <p>The value used to separate individual records in the output.</p>
     pub record_delimiter: Option<String>,
@@ -6550,7 +7042,7 @@ impl KeyCountDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6654,6 +7146,8 @@ impl LambdaFunctionArnSerializer {
 
 /// <p>A container for specifying the configuration for AWS Lambda notifications.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LambdaFunctionConfiguration {
     /// <p>The Amazon S3 bucket event for which to invoke the AWS Lambda function. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Supported Event Types</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub events: Vec<String>,
@@ -6796,6 +7290,8 @@ impl LastModifiedDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleConfiguration {
     /// <p><p/></p>
     pub rules: Vec<Rule>,
@@ -6820,6 +7316,8 @@ impl LifecycleConfigurationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleExpiration {
     /// <p>Indicates at what date the object is to be moved or deleted. Should be in GMT ISO 8601 Format.</p>
     pub date: Option<String>,
@@ -6902,6 +7400,8 @@ impl LifecycleExpirationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleRule {
     /// <p><p/></p>
     pub abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUpload>,
@@ -6921,6 +7421,14 @@ pub struct LifecycleRule {
     pub transitions: Option<Vec<Transition>>,
 }
 
+impl Validate for LifecycleRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+    }
+}
+
 struct LifecycleRuleDeserializer;
 impl LifecycleRuleDeserializer {
     #[allow(unused_variables)]
@@ -7043,6 +7551,8 @@ impl LifecycleRuleSerializer {
 
 /// <p>This is used in a Lifecycle Rule Filter to apply a logical AND to two or more predicates. The Lifecycle Rule will apply to any object matching all of the predicates configured inside the And operator.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleRuleAndOperator {
     /// <p><p/></p>
     pub prefix: Option<String>,
@@ -7050,6 +7560,17 @@ pub struct LifecycleRuleAndOperator {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for LifecycleRuleAndOperator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct LifecycleRuleAndOperatorDeserializer;
 impl LifecycleRuleAndOperatorDeserializer {
     #[allow(unused_variables)]
@@ -7107,6 +7628,8 @@ impl LifecycleRuleAndOperatorSerializer {
 
 /// <p>The Filter is used to identify objects that a Lifecycle Rule applies to. A Filter must have exactly one of Prefix, Tag, or And specified.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LifecycleRuleFilter {
     /// <p><p/></p>
     pub and: Option<LifecycleRuleAndOperator>,
@@ -7116,6 +7639,17 @@ pub struct LifecycleRuleFilter {
     pub tag: Option<Tag>,
 }
 
+impl Validate for LifecycleRuleFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.and {
+            value.validate_at(&field_path(path, "and"), errors);
+        }
+        if let Some(ref value) = self.tag {
+            value.validate_at(&field_path(path, "tag"), errors);
+        }
+    }
+}
+
 struct LifecycleRuleFilterDeserializer;
 impl LifecycleRuleFilterDeserializer {
     #[allow(unused_variables)]
@@ -7220,6 +7754,8 @@ impl LifecycleRulesSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketAnalyticsConfigurationsOutput {
     /// <p>The list of analytics configurations for a bucket.</p>
     pub analytics_configuration_list: Option<Vec<AnalyticsConfiguration>>,
@@ -7273,6 +7809,8 @@ impl ListBucketAnalyticsConfigurationsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketAnalyticsConfigurationsRequest {
     /// <p>The name of the bucket from which analytics configurations are retrieved.</p>
     pub bucket: String,
@@ -7281,6 +7819,8 @@ pub struct ListBucketAnalyticsConfigurationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketInventoryConfigurationsOutput {
     /// <p>If sent in the request, the marker that is used as a starting point for this inventory configuration list response.</p>
     pub continuation_token: Option<String>,
@@ -7334,6 +7874,8 @@ impl ListBucketInventoryConfigurationsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketInventoryConfigurationsRequest {
     /// <p>The name of the bucket containing the inventory configurations to retrieve.</p>
     pub bucket: String,
@@ -7342,6 +7884,8 @@ pub struct ListBucketInventoryConfigurationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketMetricsConfigurationsOutput {
     /// <p>The marker that is used as a starting point for this metrics configuration list response. This value is present if it was sent in the request.</p>
     pub continuation_token: Option<String>,
@@ -7395,6 +7939,8 @@ impl ListBucketMetricsConfigurationsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketMetricsConfigurationsRequest {
     /// <p>The name of the bucket containing the metrics configurations to retrieve.</p>
     pub bucket: String,
@@ -7403,6 +7949,8 @@ pub struct ListBucketMetricsConfigurationsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBucketsOutput {
     /// <p><p/></p>
     pub buckets: Option<Vec<Bucket>>,
@@ -7434,6 +7982,8 @@ impl ListBucketsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMultipartUploadsOutput {
     /// <p>Name of the bucket to which the multipart upload was initiated.</p>
     pub bucket: Option<String>,
@@ -7538,6 +8088,8 @@ impl ListMultipartUploadsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMultipartUploadsRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -7555,6 +8107,8 @@ pub struct ListMultipartUploadsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectVersionsOutput {
     /// <p><p/></p>
     pub common_prefixes: Option<Vec<CommonPrefix>>,
@@ -7665,6 +8219,8 @@ impl ListObjectVersionsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectVersionsRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -7682,6 +8238,8 @@ pub struct ListObjectVersionsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectsOutput {
     /// <p><p/></p>
     pub common_prefixes: Option<Vec<CommonPrefix>>,
@@ -7760,6 +8318,8 @@ impl ListObjectsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectsRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -7777,6 +8337,8 @@ pub struct ListObjectsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectsV2Output {
     /// <p>CommonPrefixes contains all (if there are any) keys between Prefix and the next occurrence of the string specified by delimiter</p>
     pub common_prefixes: Option<Vec<CommonPrefix>>,
@@ -7869,6 +8431,8 @@ impl ListObjectsV2OutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListObjectsV2Request {
     /// <p>Name of the bucket to list.</p>
     pub bucket: String,
@@ -7891,6 +8455,8 @@ pub struct ListObjectsV2Request {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPartsOutput {
     /// <p>Date when multipart upload will become eligible for abort operation by lifecycle.</p>
     pub abort_date: Option<String>,
@@ -7985,6 +8551,8 @@ impl ListPartsOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPartsRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -7999,6 +8567,17 @@ pub struct ListPartsRequest {
     pub upload_id: String,
 }
 
+impl Validate for ListPartsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 struct LocationDeserializer;
 impl LocationDeserializer {
     #[allow(unused_variables)]
@@ -8033,6 +8612,8 @@ impl LocationPrefixSerializer {
 
 /// <p>Describes where logs are stored and the prefix that Amazon S3 assigns to all log object keys for a bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTlogging.html">PUT Bucket logging</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LoggingEnabled {
     /// <p>Specifies the bucket where you want Amazon S3 to store server access logs. You can have your logs delivered to any bucket that you own, including the same bucket that is being logged. You can also configure multiple buckets to deliver their logs to the same target bucket. In this case you should choose a different TargetPrefix for each source bucket so that the delivered log files can be distinguished by key.</p>
     pub target_bucket: String,
@@ -8170,7 +8751,7 @@ impl MaxAgeSecondsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8202,7 +8783,7 @@ impl MaxKeysDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8234,7 +8815,7 @@ impl MaxPartsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8266,7 +8847,7 @@ impl MaxUploadsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8306,6 +8887,8 @@ impl MessageDeserializer {
 }
 /// <p>A metadata key-value pair to store with an object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetadataEntry {
     /// <p><p/></p>
     pub name: Option<String>,
@@ -8387,6 +8970,8 @@ impl MetadataValueSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricsAndOperator {
     /// <p>The prefix used when evaluating an AND predicate.</p>
     pub prefix: Option<String>,
@@ -8394,6 +8979,17 @@ pub struct MetricsAndOperator {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for MetricsAndOperator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct MetricsAndOperatorDeserializer;
 impl MetricsAndOperatorDeserializer {
     #[allow(unused_variables)]
@@ -8447,6 +9043,8 @@ impl MetricsAndOperatorSerializer {
 
 /// <p>Specifies a metrics configuration for the CloudWatch request metrics (specified by the metrics configuration ID) from an Amazon S3 bucket. If you're updating an existing metrics configuration, note that this is a full replacement of the existing metrics configuration. If you don't include the elements you want to keep, they are erased. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTMetricConfiguration.html"> PUT Bucket metrics</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricsConfiguration {
     /// <p>Specifies a metrics configuration filter. The metrics configuration will only include objects that meet the filter's criteria. A filter must be a prefix, a tag, or a conjunction (MetricsAndOperator).</p>
     pub filter: Option<MetricsFilter>,
@@ -8454,6 +9052,14 @@ pub struct MetricsConfiguration {
     pub id: String,
 }
 
+impl Validate for MetricsConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+    }
+}
+
 struct MetricsConfigurationDeserializer;
 impl MetricsConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -8532,6 +9138,8 @@ impl MetricsConfigurationListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MetricsFilter {
     /// <p>A conjunction (logical AND) of predicates, which is used in evaluating a metrics filter. The operator must have at least two predicates, and an object must match all of the predicates in order for the filter to apply.</p>
     pub and: Option<MetricsAndOperator>,
@@ -8541,6 +9149,17 @@ pub struct MetricsFilter {
     pub tag: Option<Tag>,
 }
 
+impl Validate for MetricsFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.and {
+            value.validate_at(&field_path(path, "and"), errors);
+        }
+        if let Some(ref value) = self.tag {
+            value.validate_at(&field_path(path, "tag"), errors);
+        }
+    }
+}
+
 struct MetricsFilterDeserializer;
 impl MetricsFilterDeserializer {
     #[allow(unused_variables)]
@@ -8630,6 +9249,8 @@ impl MetricsIdSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MultipartUpload {
     /// <p>Date and time at which the multipart upload was initiated.</p>
     pub initiated: Option<String>,
@@ -8769,7 +9390,7 @@ impl NextPartNumberMarkerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8810,6 +9431,8 @@ impl NextVersionIdMarkerDeserializer {
 }
 /// <p>Specifies when noncurrent object versions expire. Upon expiration, Amazon S3 permanently deletes the noncurrent object versions. You set this lifecycle configuration action on a bucket that has versioning enabled (or suspended) to request that Amazon S3 delete noncurrent object versions at a specific period in the object's lifetime.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NoncurrentVersionExpiration {
     /// <p>Specifies the number of days an object is noncurrent before Amazon S3 can perform the associated action. For information about the noncurrent days calculations, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/intro-lifecycle-rules.html#non-current-days-calculations">How Amazon S3 Calculates When an Object Became Noncurrent</a> in the Amazon Simple Storage Service Developer Guide.</p>
     pub noncurrent_days: Option<i64>,
@@ -8865,6 +9488,8 @@ impl NoncurrentVersionExpirationSerializer {
 
 /// <p>Container for the transition rule that describes when noncurrent objects transition to the <code>STANDARD_IA</code>, <code>ONEZONE_IA</code>, <code>INTELLIGENT_TIERING</code>, <code>GLACIER</code>, or <code>DEEP_ARCHIVE</code> storage class. If your bucket is versioning-enabled (or versioning is suspended), you can set this action to request that Amazon S3 transition noncurrent object versions to the <code>STANDARD_IA</code>, <code>ONEZONE_IA</code>, <code>INTELLIGENT_TIERING</code>, <code>GLACIER</code>, or <code>DEEP_ARCHIVE</code> storage class at a specific period in the object's lifetime.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NoncurrentVersionTransition {
     /// <p>Specifies the number of days an object is noncurrent before Amazon S3 can perform the associated action. For information about the noncurrent days calculations, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-access-control.html">How Amazon S3 Calculates When an Object Became Noncurrent</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub noncurrent_days: Option<i64>,
@@ -8984,6 +9609,8 @@ impl NoncurrentVersionTransitionListSerializer {
 
 /// <p>A container for specifying the notification configuration of the bucket. If this element is empty, notifications are turned off for the bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationConfiguration {
     /// <p>Describes the AWS Lambda functions to invoke and the events for which to invoke them.</p>
     pub lambda_function_configurations: Option<Vec<LambdaFunctionConfiguration>>,
@@ -9067,6 +9694,8 @@ impl NotificationConfigurationSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationConfigurationDeprecated {
     /// <p><p/></p>
     pub cloud_function_configuration: Option<CloudFunctionConfiguration>,
@@ -9156,6 +9785,8 @@ impl NotificationConfigurationDeprecatedSerializer {
 
 /// <p>Specifies object key name filtering rules. For information about key name filtering, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Configuring Event Notifications</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationConfigurationFilter {
     /// <p><p/></p>
     pub key: Option<S3KeyFilter>,
@@ -9237,6 +9868,8 @@ impl NotificationIdSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Object {
     /// <p><p/></p>
     pub e_tag: Option<String>,
@@ -9311,6 +9944,8 @@ impl ObjectCannedACLSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectIdentifier {
     /// <p>Key name of the object to delete.</p>
     pub key: String,
@@ -9318,6 +9953,17 @@ pub struct ObjectIdentifier {
     pub version_id: Option<String>,
 }
 
+impl Validate for ObjectIdentifier {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 pub struct ObjectIdentifierSerializer;
 impl ObjectIdentifierSerializer {
     #[allow(unused_variables, warnings)]
@@ -9427,6 +10073,8 @@ impl ObjectListDeserializer {
 }
 /// <p>The container element for object lock configuration parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectLockConfiguration {
     /// <p>Indicates whether this bucket has an object lock configuration enabled.</p>
     pub object_lock_enabled: Option<String>,
@@ -9524,6 +10172,8 @@ impl ObjectLockEnabledSerializer {
 
 /// <p>A Legal Hold configuration for an object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectLockLegalHold {
     /// <p>Indicates whether the specified object has a Legal Hold in place.</p>
     pub status: Option<String>,
@@ -9608,6 +10258,8 @@ impl ObjectLockLegalHoldStatusSerializer {
 
 /// <p>A Retention configuration for an object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectLockRetention {
     /// <p>Indicates the Retention mode for the specified object.</p>
     pub mode: Option<String>,
@@ -9706,6 +10358,8 @@ impl ObjectLockRetentionModeSerializer {
 
 /// <p>The container element for an object lock rule.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectLockRule {
     /// <p>The default retention period that you want to apply to new objects placed in the specified bucket.</p>
     pub default_retention: Option<DefaultRetention>,
@@ -9765,6 +10419,8 @@ impl ObjectStorageClassDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ObjectVersion {
     /// <p><p/></p>
     pub e_tag: Option<String>,
@@ -9904,11 +10560,21 @@ impl ObjectVersionStorageClassDeserializer {
 }
 /// <p>Describes the location where the restore job's output is stored.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OutputLocation {
     /// <p>Describes an S3 location that will receive the results of the restore request.</p>
     pub s3: Option<S3Location>,
 }
 
+impl Validate for OutputLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3 {
+            value.validate_at(&field_path(path, "s3"), errors);
+        }
+    }
+}
+
 pub struct OutputLocationSerializer;
 impl OutputLocationSerializer {
     #[allow(unused_variables, warnings)]
@@ -9930,6 +10596,8 @@ impl OutputLocationSerializer {
 
 /// <p>Describes how results of the Select job are serialized.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OutputSerialization {
     /// <p>Describes the serialization of CSV-encoded Select results.</p>
     pub csv: Option<CSVOutput>,
@@ -9961,6 +10629,8 @@ impl OutputSerializationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Owner {
     /// <p><p/></p>
     pub display_name: Option<String>,
@@ -10054,6 +10724,8 @@ impl OwnerOverrideSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParquetInput {}
 
 pub struct ParquetInputSerializer;
@@ -10074,6 +10746,8 @@ impl ParquetInputSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Part {
     /// <p>Entity tag returned when the part was uploaded.</p>
     pub e_tag: Option<String>,
@@ -10118,7 +10792,7 @@ impl PartNumberDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10150,7 +10824,7 @@ impl PartNumberMarkerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10270,6 +10944,8 @@ impl PermissionSerializer {
 
 /// <p>The container element for a bucket's policy status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyStatus {
     /// <p>The policy status for this bucket. <code>TRUE</code> indicates that this bucket is public. <code>FALSE</code> indicates that the bucket is not public.</p>
     pub is_public: Option<bool>,
@@ -10330,7 +11006,7 @@ impl PriorityDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -10359,6 +11035,8 @@ impl PrioritySerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Progress {
     /// <p>The current number of uncompressed object bytes processed.</p>
     pub bytes_processed: Option<i64>,
@@ -10403,6 +11081,8 @@ impl ProgressDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProgressEvent {
     /// <p>The Progress event details.</p>
     pub details: Option<Progress>,
@@ -10460,6 +11140,8 @@ impl ProtocolSerializer {
 
 /// <p>Specifies the Block Public Access configuration for an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublicAccessBlockConfiguration {
     /// <p>Specifies whether Amazon S3 should block public access control lists (ACLs) for this bucket and objects in this bucket. Setting this element to <code>TRUE</code> causes the following behavior:</p> <ul> <li> <p>PUT Bucket acl and PUT Object acl calls fail if the specified ACL is public.</p> </li> <li> <p>PUT Object calls fail if the request includes a public ACL.</p> </li> </ul> <p>Enabling this setting doesn't affect existing policies or ACLs.</p>
     pub block_public_acls: Option<bool>,
@@ -10562,6 +11244,8 @@ impl PublicAccessBlockConfigurationSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketAccelerateConfigurationRequest {
     /// <p>Specifies the Accelerate Configuration you want to set for the bucket.</p>
     pub accelerate_configuration: AccelerateConfiguration,
@@ -10570,6 +11254,8 @@ pub struct PutBucketAccelerateConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketAclRequest {
     /// <p>The canned ACL to apply to the bucket.</p>
     pub acl: Option<String>,
@@ -10592,6 +11278,8 @@ pub struct PutBucketAclRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketAnalyticsConfigurationRequest {
     /// <p>The configuration and any analyses for the analytics filter.</p>
     pub analytics_configuration: AnalyticsConfiguration,
@@ -10601,7 +11289,16 @@ pub struct PutBucketAnalyticsConfigurationRequest {
     pub id: String,
 }
 
+impl Validate for PutBucketAnalyticsConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.analytics_configuration
+            .validate_at(&field_path(path, "analytics_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketCorsRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10612,6 +11309,8 @@ pub struct PutBucketCorsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketEncryptionRequest {
     /// <p>Specifies default encryption for a bucket using server-side encryption with Amazon S3-managed keys (SSE-S3) or AWS KMS-managed keys (SSE-KMS). For information about the Amazon S3 default encryption feature, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/bucket-encryption.html">Amazon S3 Default Bucket Encryption</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub bucket: String,
@@ -10622,6 +11321,8 @@ pub struct PutBucketEncryptionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketInventoryConfigurationRequest {
     /// <p>The name of the bucket where the inventory configuration will be stored.</p>
     pub bucket: String,
@@ -10632,6 +11333,8 @@ pub struct PutBucketInventoryConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketLifecycleConfigurationRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10639,7 +11342,17 @@ pub struct PutBucketLifecycleConfigurationRequest {
     pub lifecycle_configuration: Option<BucketLifecycleConfiguration>,
 }
 
+impl Validate for PutBucketLifecycleConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.lifecycle_configuration {
+            value.validate_at(&field_path(path, "lifecycle_configuration"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketLifecycleRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10650,6 +11363,8 @@ pub struct PutBucketLifecycleRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketLoggingRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10660,6 +11375,8 @@ pub struct PutBucketLoggingRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketMetricsConfigurationRequest {
     /// <p>The name of the bucket for which the metrics configuration is set.</p>
     pub bucket: String,
@@ -10669,7 +11386,16 @@ pub struct PutBucketMetricsConfigurationRequest {
     pub metrics_configuration: MetricsConfiguration,
 }
 
+impl Validate for PutBucketMetricsConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.metrics_configuration
+            .validate_at(&field_path(path, "metrics_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketNotificationConfigurationRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10678,6 +11404,8 @@ pub struct PutBucketNotificationConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketNotificationRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10688,6 +11416,8 @@ pub struct PutBucketNotificationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketPolicyRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10700,6 +11430,8 @@ pub struct PutBucketPolicyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketReplicationRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10711,7 +11443,16 @@ pub struct PutBucketReplicationRequest {
     pub token: Option<String>,
 }
 
+impl Validate for PutBucketReplicationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.replication_configuration
+            .validate_at(&field_path(path, "replication_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketRequestPaymentRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10722,6 +11463,8 @@ pub struct PutBucketRequestPaymentRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketTaggingRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10731,7 +11474,16 @@ pub struct PutBucketTaggingRequest {
     pub tagging: Tagging,
 }
 
+impl Validate for PutBucketTaggingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.tagging
+            .validate_at(&field_path(path, "tagging"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketVersioningRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10744,6 +11496,8 @@ pub struct PutBucketVersioningRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutBucketWebsiteRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -10753,7 +11507,16 @@ pub struct PutBucketWebsiteRequest {
     pub website_configuration: WebsiteConfiguration,
 }
 
+impl Validate for PutBucketWebsiteRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.website_configuration
+            .validate_at(&field_path(path, "website_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectAclOutput {
     pub request_charged: Option<String>,
 }
@@ -10775,6 +11538,8 @@ impl PutObjectAclOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectAclRequest {
     /// <p>The canned ACL to apply to the object.</p>
     pub acl: Option<String>,
@@ -10801,7 +11566,20 @@ pub struct PutObjectAclRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for PutObjectAclRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectLegalHoldOutput {
     pub request_charged: Option<String>,
 }
@@ -10823,6 +11601,8 @@ impl PutObjectLegalHoldOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectLegalHoldRequest {
     /// <p>The bucket containing the object that you want to place a Legal Hold on.</p>
     pub bucket: String,
@@ -10837,7 +11617,20 @@ pub struct PutObjectLegalHoldRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for PutObjectLegalHoldRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectLockConfigurationOutput {
     pub request_charged: Option<String>,
 }
@@ -10859,6 +11652,8 @@ impl PutObjectLockConfigurationOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectLockConfigurationRequest {
     /// <p>The bucket whose object lock configuration you want to create or replace.</p>
     pub bucket: String,
@@ -10872,6 +11667,8 @@ pub struct PutObjectLockConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectOutput {
     /// <p>Entity tag for the uploaded object.</p>
     pub e_tag: Option<String>,
@@ -10909,10 +11706,16 @@ impl PutObjectOutputDeserializer {
     }
 }
 #[derive(Default, Debug)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectRequest {
     /// <p>The canned ACL to apply to the object.</p>
     pub acl: Option<String>,
     /// <p>Object data.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(skip)
+    )]
     pub body: Option<StreamingBody>,
     /// <p>Name of the bucket to which the PUT operation was initiated.</p>
     pub bucket: String,
@@ -10971,7 +11774,20 @@ pub struct PutObjectRequest {
     pub website_redirect_location: Option<String>,
 }
 
+impl Validate for PutObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectRetentionOutput {
     pub request_charged: Option<String>,
 }
@@ -10993,6 +11809,8 @@ impl PutObjectRetentionOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectRetentionRequest {
     /// <p>The bucket that contains the object you want to apply this Object Retention configuration to.</p>
     pub bucket: String,
@@ -11009,7 +11827,20 @@ pub struct PutObjectRetentionRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for PutObjectRetentionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectTaggingOutput {
     /// <p><p/></p>
     pub version_id: Option<String>,
@@ -11032,6 +11863,8 @@ impl PutObjectTaggingOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutObjectTaggingRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -11045,7 +11878,22 @@ pub struct PutObjectTaggingRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for PutObjectTaggingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+        self.tagging
+            .validate_at(&field_path(path, "tagging"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPublicAccessBlockRequest {
     /// <p>The name of the Amazon S3 bucket whose <code>PublicAccessBlock</code> configuration you want to set.</p>
     pub bucket: String,
@@ -11089,6 +11937,8 @@ impl QueueArnSerializer {
 
 /// <p>Specifies the configuration for publishing messages to an Amazon Simple Queue Service (Amazon SQS) queue when Amazon S3 detects specified events.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueueConfiguration {
     /// <p><p/></p>
     pub events: Vec<String>,
@@ -11166,6 +12016,8 @@ impl QueueConfigurationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct QueueConfigurationDeprecated {
     /// <p><p/></p>
     pub events: Option<Vec<String>>,
@@ -11390,8 +12242,18 @@ impl RecordDelimiterSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecordsEvent {
     /// <p>The byte array of partial, one or more result records.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+            default
+        )
+    )]
     pub payload: Option<bytes::Bytes>,
 }
 
@@ -11415,6 +12277,8 @@ impl RecordsEventDeserializer {
 }
 /// <p>Specifies how requests are redirected. In the event of an error, you can specify a different error code to return.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Redirect {
     /// <p>The host name to use in the redirect request.</p>
     pub host_name: Option<String>,
@@ -11527,6 +12391,8 @@ impl RedirectSerializer {
 
 /// <p>Specifies the redirect behavior of all requests to a website endpoint of an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RedirectAllRequestsTo {
     /// <p>Name of the host where requests are redirected.</p>
     pub host_name: String,
@@ -11684,6 +12550,8 @@ impl ReplicaKmsKeyIDSerializer {
 
 /// <p>A container for replication rules. You can add up to 1,000 rules. The maximum size of a replication configuration is 2 MB.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationConfiguration {
     /// <p>The Amazon Resource Name (ARN) of the AWS Identity and Access Management (IAM) role that Amazon S3 assumes when replicating objects. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/crr-how-setup.html">How to Set Up Cross-Region Replication</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub role: String,
@@ -11691,6 +12559,15 @@ pub struct ReplicationConfiguration {
     pub rules: Vec<ReplicationRule>,
 }
 
+impl Validate for ReplicationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.rules.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rules"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 struct ReplicationConfigurationDeserializer;
 impl ReplicationConfigurationDeserializer {
     #[allow(unused_variables)]
@@ -11743,6 +12620,8 @@ impl ReplicationConfigurationSerializer {
 
 /// <p>Specifies which Amazon S3 objects to replicate and where to store the replicas.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationRule {
     /// <p><p/></p>
     pub delete_marker_replication: Option<DeleteMarkerReplication>,
@@ -11760,6 +12639,14 @@ pub struct ReplicationRule {
     pub status: String,
 }
 
+impl Validate for ReplicationRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filter {
+            value.validate_at(&field_path(path, "filter"), errors);
+        }
+    }
+}
+
 struct ReplicationRuleDeserializer;
 impl ReplicationRuleDeserializer {
     #[allow(unused_variables)]
@@ -11865,6 +12752,8 @@ impl ReplicationRuleSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationRuleAndOperator {
     /// <p><p/></p>
     pub prefix: Option<String>,
@@ -11872,6 +12761,17 @@ pub struct ReplicationRuleAndOperator {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for ReplicationRuleAndOperator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 struct ReplicationRuleAndOperatorDeserializer;
 impl ReplicationRuleAndOperatorDeserializer {
     #[allow(unused_variables)]
@@ -11929,6 +12829,8 @@ impl ReplicationRuleAndOperatorSerializer {
 
 /// <p>A filter that identifies the subset of objects to which the replication rule applies. A <code>Filter</code> must specify exactly one <code>Prefix</code>, <code>Tag</code>, or an <code>And</code> child element.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplicationRuleFilter {
     /// <p><p>A container for specifying rule filters. The filters determine the subset of objects to which the rule applies. This element is required only if you specify more than one filter. For example: </p> <ul> <li> <p>If you specify both a <code>Prefix</code> and a <code>Tag</code> filter, wrap these filters in an <code>And</code> tag.</p> </li> <li> <p>If you specify a filter based on multiple tags, wrap the <code>Tag</code> elements in an <code>And</code> tag.</p> </li> </ul></p>
     pub and: Option<ReplicationRuleAndOperator>,
@@ -11938,6 +12840,17 @@ pub struct ReplicationRuleFilter {
     pub tag: Option<Tag>,
 }
 
+impl Validate for ReplicationRuleFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.and {
+            value.validate_at(&field_path(path, "and"), errors);
+        }
+        if let Some(ref value) = self.tag {
+            value.validate_at(&field_path(path, "tag"), errors);
+        }
+    }
+}
+
 struct ReplicationRuleFilterDeserializer;
 impl ReplicationRuleFilterDeserializer {
     #[allow(unused_variables)]
@@ -12075,6 +12988,8 @@ impl ReplicationRulesSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestPaymentConfiguration {
     /// <p>Specifies who pays for the download and request fees.</p>
     pub payer: String,
@@ -12104,6 +13019,8 @@ impl RequestPaymentConfigurationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestProgress {
     /// <p>Specifies whether periodic QueryProgress frames should be sent. Valid values: TRUE, FALSE. Default value: FALSE.</p>
     pub enabled: Option<bool>,
@@ -12254,6 +13171,8 @@ impl ResponseExpiresSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreObjectOutput {
     pub request_charged: Option<String>,
     /// <p>Indicates the path in the provided S3 output location where Select results will be restored to.</p>
@@ -12277,6 +13196,8 @@ impl RestoreObjectOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreObjectRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -12289,8 +13210,24 @@ pub struct RestoreObjectRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for RestoreObjectRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.restore_request {
+            value.validate_at(&field_path(path, "restore_request"), errors);
+        }
+    }
+}
+
 /// <p>Container for restore job parameters.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreRequest {
     /// <p>Lifetime of the active copy in days. Do not use with restores that specify OutputLocation.</p>
     pub days: Option<i64>,
@@ -12308,6 +13245,14 @@ pub struct RestoreRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for RestoreRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.output_location {
+            value.validate_at(&field_path(path, "output_location"), errors);
+        }
+    }
+}
+
 pub struct RestoreRequestSerializer;
 impl RestoreRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -12419,6 +13364,8 @@ impl RoleSerializer {
 
 /// <p>Specifies the redirect behavior and when a redirect is applied.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RoutingRule {
     /// <p>A container for describing a condition that must be met for the specified redirect to apply. For example, 1. If request is for pages in the <code>/docs</code> folder, redirect to the <code>/documents</code> folder. 2. If request results in HTTP error 4xx, redirect request to another host where you might process the error.</p>
     pub condition: Option<Condition>,
@@ -12508,6 +13455,8 @@ impl RoutingRulesSerializer {
 
 /// <p>Specifies lifecycle rules for an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTlifecycle.html">PUT Bucket lifecycle</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Rule {
     /// <p><p/></p>
     pub abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUpload>,
@@ -12691,6 +13640,8 @@ impl RulesSerializer {
 
 /// <p>A container for object key name prefix and suffix filtering rules.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3KeyFilter {
     /// <p><p/></p>
     pub filter_rules: Option<Vec<FilterRule>>,
@@ -12738,6 +13689,8 @@ impl S3KeyFilterSerializer {
 
 /// <p>Describes an S3 location that will receive the results of the restore request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Location {
     /// <p>A list of grants that control access to the staged results.</p>
     pub access_control_list: Option<Vec<Grant>>,
@@ -12757,6 +13710,14 @@ pub struct S3Location {
     pub user_metadata: Option<Vec<MetadataEntry>>,
 }
 
+impl Validate for S3Location {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tagging {
+            value.validate_at(&field_path(path, "tagging"), errors);
+        }
+    }
+}
+
 pub struct S3LocationSerializer;
 impl S3LocationSerializer {
     #[allow(unused_variables, warnings)]
@@ -12815,6 +13776,8 @@ impl S3LocationSerializer {
 
 /// <p>Specifies the use of SSE-KMS to encrypt delivered Inventory reports.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SSEKMS {
     /// <p>Specifies the ID of the AWS Key Management Service (KMS) master encryption key to use for encrypting Inventory reports.</p>
     pub key_id: String,
@@ -12892,6 +13855,8 @@ impl SSEKMSKeyIdSerializer {
 
 /// <p>Specifies the use of SSE-S3 to encrypt delivered Inventory reports.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SSES3 {}
 
 struct SSES3Deserializer;
@@ -12926,6 +13891,8 @@ impl SSES3Serializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectObjectContentEventStream {
     /// <p>The Continuation Event.</p>
     pub cont: Option<ContinuationEvent>,
@@ -12976,6 +13943,8 @@ impl SelectObjectContentEventStreamDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectObjectContentOutput {
     /// <p><p/></p>
     pub payload: Option<SelectObjectContentEventStream>,
@@ -12998,6 +13967,8 @@ impl SelectObjectContentOutputDeserializer {
 }
 /// <p>Request to filter the contents of an Amazon S3 object based on a simple Structured Query Language (SQL) statement. In the request, along with the SQL expression, you must specify a data serialization format (JSON or CSV) of the object. Amazon S3 uses this to parse object data into records. It returns only records that match the specified SQL expression. You must also specify the data serialization format for the response. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectSELECTContent.html">S3Select API Documentation</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectObjectContentRequest {
     /// <p>The S3 bucket.</p>
     pub bucket: String,
@@ -13021,6 +13992,17 @@ pub struct SelectObjectContentRequest {
     pub sse_customer_key_md5: Option<String>,
 }
 
+impl Validate for SelectObjectContentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 pub struct SelectObjectContentRequestSerializer;
 impl SelectObjectContentRequestSerializer {
     #[allow(unused_variables, warnings)]
@@ -13054,6 +14036,8 @@ impl SelectObjectContentRequestSerializer {
 }
 /// <p>Describes the parameters for Select job types.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectParameters {
     /// <p>The expression that is used to query the object.</p>
     pub expression: String,
@@ -13137,6 +14121,8 @@ impl ServerSideEncryptionSerializer {
 
 /// <p>Describes the default server-side encryption to apply to new objects in the bucket. If a PUT Object request doesn't specify any server-side encryption, this default encryption will be applied. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTencryption.html">PUT Bucket encryption</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServerSideEncryptionByDefault {
     /// <p>KMS master key ID to use for the default encryption. This parameter is allowed if and only if <code>SSEAlgorithm</code> is set to <code>aws:kms</code>.</p>
     pub kms_master_key_id: Option<String>,
@@ -13206,6 +14192,8 @@ impl ServerSideEncryptionByDefaultSerializer {
 
 /// <p>Specifies the default server-side-encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServerSideEncryptionConfiguration {
     /// <p>Container for information about a particular server-side encryption configuration rule.</p>
     pub rules: Vec<ServerSideEncryptionRule>,
@@ -13256,6 +14244,8 @@ impl ServerSideEncryptionConfigurationSerializer {
 
 /// <p>Specifies the default server-side encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServerSideEncryptionRule {
     /// <p>Specifies the default server-side encryption to apply to new objects in the bucket. If a PUT Object request doesn't specify any server-side encryption, this default encryption will be applied.</p>
     pub apply_server_side_encryption_by_default: Option<ServerSideEncryptionByDefault>,
@@ -13364,7 +14354,7 @@ impl SettingDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -13396,7 +14386,7 @@ impl SizeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -13404,6 +14394,8 @@ impl SizeDeserializer {
 }
 /// <p>A container that describes additional filters for identifying the source objects that you want to replicate. You can choose to enable or disable the replication of these objects. Currently, Amazon S3 supports only the filter that you can specify for objects created with server-side encryption using an AWS KMS-Managed Key (SSE-KMS).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SourceSelectionCriteria {
     /// <p> A container for filter information for the selection of Amazon S3 objects encrypted with AWS KMS. If you include <code>SourceSelectionCriteria</code> in the replication configuration, this element is required. </p>
     pub sse_kms_encrypted_objects: Option<SseKmsEncryptedObjects>,
@@ -13461,6 +14453,8 @@ impl SourceSelectionCriteriaSerializer {
 
 /// <p>A container for filter information for the selection of S3 objects encrypted with AWS KMS.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SseKmsEncryptedObjects {
     /// <p>Specifies whether Amazon S3 replicates objects created with server-side encryption using an AWS KMS-managed key.</p>
     pub status: String,
@@ -13574,6 +14568,8 @@ impl StartAfterSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Stats {
     /// <p>The total number of uncompressed object bytes processed.</p>
     pub bytes_processed: Option<i64>,
@@ -13615,6 +14611,8 @@ impl StatsDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StatsEvent {
     /// <p>The Stats event details.</p>
     pub details: Option<Stats>,
@@ -13672,6 +14670,8 @@ impl StorageClassSerializer {
 
 /// <p>Specifies data related to access patterns to be collected and made available to analyze the tradeoffs between different storage classes for an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StorageClassAnalysis {
     /// <p>Specifies how data related to the storage class analysis for an Amazon S3 bucket should be exported.</p>
     pub data_export: Option<StorageClassAnalysisDataExport>,
@@ -13721,6 +14721,8 @@ impl StorageClassAnalysisSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StorageClassAnalysisDataExport {
     /// <p>The place to store the data for an analysis.</p>
     pub destination: AnalyticsExportDestination,
@@ -13854,6 +14856,8 @@ impl SuffixSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>Name of the tag.</p>
     pub key: String,
@@ -13861,6 +14865,17 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -13948,11 +14963,22 @@ impl TagSetSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tagging {
     /// <p><p/></p>
     pub tag_set: Vec<Tag>,
 }
 
+impl Validate for Tagging {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tag_set.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_set"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 pub struct TaggingSerializer;
 impl TaggingSerializer {
     #[allow(unused_variables, warnings)]
@@ -14004,6 +15030,8 @@ impl TargetBucketSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TargetGrant {
     /// <p><p/></p>
     pub grantee: Option<Grantee>,
@@ -14219,6 +15247,8 @@ impl TopicArnSerializer {
 
 /// <p>A container for specifying the configuration for publication of messages to an Amazon Simple Notification Service (Amazon SNS) topic when Amazon S3 detects specified events.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TopicConfiguration {
     /// <p>The Amazon S3 bucket event about which to send notifications. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/NotificationHowTo.html">Supported Event Types</a> in the <i>Amazon Simple Storage Service Developer Guide</i>.</p>
     pub events: Vec<String>,
@@ -14296,6 +15326,8 @@ impl TopicConfigurationSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TopicConfigurationDeprecated {
     /// <p><p/></p>
     pub events: Option<Vec<String>>,
@@ -14420,6 +15452,8 @@ impl TopicConfigurationListSerializer {
 
 /// <p>Specifies when an object transitions to a specified storage class.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Transition {
     /// <p>Indicates when objects are transitioned to the specified storage class. The date value must be in ISO 8601 format. The time is always midnight UTC.</p>
     pub date: Option<String>,
@@ -14672,6 +15706,8 @@ impl UploadIdMarkerSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadPartCopyOutput {
     /// <p><p/></p>
     pub copy_part_result: Option<CopyPartResult>,
@@ -14705,6 +15741,8 @@ impl UploadPartCopyOutputDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadPartCopyRequest {
     /// <p><p/></p>
     pub bucket: String,
@@ -14741,7 +15779,25 @@ pub struct UploadPartCopyRequest {
     pub upload_id: String,
 }
 
+impl Validate for UploadPartCopyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "copy_source"),
+            &self.copy_source,
+            "\\/.+\\/.+",
+        );
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadPartOutput {
     /// <p>Entity tag for the uploaded object.</p>
     pub e_tag: Option<String>,
@@ -14773,8 +15829,14 @@ impl UploadPartOutputDeserializer {
     }
 }
 #[derive(Default, Debug)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UploadPartRequest {
     /// <p>Object data.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(skip)
+    )]
     pub body: Option<StreamingBody>,
     /// <p>Name of the bucket to which the multipart upload was initiated.</p>
     pub bucket: String,
@@ -14797,6 +15859,17 @@ pub struct UploadPartRequest {
     pub upload_id: String,
 }
 
+impl Validate for UploadPartRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 pub struct UserMetadataSerializer;
 impl UserMetadataSerializer {
     #[allow(unused_variables, warnings)]
@@ -14883,6 +15956,8 @@ impl VersionIdMarkerSerializer {
 
 /// <p>Describes the versioning state of an Amazon S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTBucketPUTVersioningStatus.html">PUT Bucket versioning</a> in the <i>Amazon Simple Storage Service API Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VersioningConfiguration {
     /// <p>Specifies whether MFA delete is enabled in the bucket versioning configuration. This element is only returned if the bucket has been configured with MFA delete. If the bucket has never been so configured, this element is not returned.</p>
     pub mfa_delete: Option<String>,
@@ -14924,6 +15999,8 @@ impl VersioningConfigurationSerializer {
 
 /// <p>Specifies website configuration parameters for an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct WebsiteConfiguration {
     /// <p>The name of the error document for the website.</p>
     pub error_document: Option<ErrorDocument>,
@@ -14935,6 +16012,14 @@ pub struct WebsiteConfiguration {
     pub routing_rules: Option<Vec<RoutingRule>>,
 }
 
+impl Validate for WebsiteConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.error_document {
+            value.validate_at(&field_path(path, "error_document"), errors);
+        }
+    }
+}
+
 pub struct WebsiteConfigurationSerializer;
 impl WebsiteConfigurationSerializer {
     #[allow(unused_variables, warnings)]
@@ -14972,7 +16057,7 @@ impl YearsDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -18327,6 +19412,410 @@ impl Error for UploadPartCopyError {
         match *self {}
     }
 }
+/// The code of an error returned by S3, whichever operation returned it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum S3ErrorCode {
+    /// Access denied.
+    AccessDenied,
+    /// There is a problem with your AWS account that prevents the action from completing successfully.
+    AccountProblem,
+    /// All access to this Amazon S3 resource has been disabled.
+    AllAccessDisabled,
+    /// The email address you provided is associated with more than one account.
+    AmbiguousGrantByEmailAddress,
+    /// The authorization header you provided is invalid.
+    AuthorizationHeaderMalformed,
+    /// The Content-MD5 you specified did not match what was received.
+    BadDigest,
+    /// The requested bucket name is not available.
+    BucketAlreadyExists,
+    /// The bucket you tried to create already exists, and you own it.
+    BucketAlreadyOwnedByYou,
+    /// The bucket you tried to delete is not empty.
+    BucketNotEmpty,
+    /// This request does not support credentials.
+    CredentialsNotSupported,
+    /// Cross-location logging not allowed.
+    CrossLocationLoggingProhibited,
+    /// Your proposed upload exceeds the maximum allowed object size.
+    EntityTooLarge,
+    /// Your proposed upload is smaller than the minimum allowed object size.
+    EntityTooSmall,
+    /// The provided token has expired.
+    ExpiredToken,
+    /// The bucket was addressed through an endpoint of a different region than its own.
+    IllegalLocationConstraintException,
+    /// The versioning configuration specified in the request is invalid.
+    IllegalVersioningConfigurationException,
+    /// You did not provide the number of bytes specified by the Content-Length HTTP header.
+    IncompleteBody,
+    /// POST requires exactly one file upload per request.
+    IncorrectNumberOfFilesInPostRequest,
+    /// Inline data exceeds the maximum allowed size.
+    InlineDataTooLarge,
+    /// We encountered an internal error. Please try again.
+    InternalError,
+    /// The AWS access key ID you provided does not exist in our records.
+    InvalidAccessKeyId,
+    /// You must specify the Anonymous role.
+    InvalidAddressingHeader,
+    /// Invalid argument.
+    InvalidArgument,
+    /// The specified bucket is not valid.
+    InvalidBucketName,
+    /// The request is not valid with the current state of the bucket.
+    InvalidBucketState,
+    /// The Content-MD5 you specified is not valid.
+    InvalidDigest,
+    /// The encryption request you specified is not valid.
+    InvalidEncryptionAlgorithmError,
+    /// The specified location constraint is not valid.
+    InvalidLocationConstraint,
+    /// The operation is not valid for the current state of the object.
+    InvalidObjectState,
+    /// One or more of the specified parts could not be found.
+    InvalidPart,
+    /// The list of parts was not in ascending order.
+    InvalidPartOrder,
+    /// All access to this object has been disabled.
+    InvalidPayer,
+    /// The content of the form does not meet the conditions specified in the policy document.
+    InvalidPolicyDocument,
+    /// The requested range cannot be satisfied.
+    InvalidRange,
+    /// The request is not valid.
+    InvalidRequest,
+    /// The SOAP request body is invalid.
+    InvalidSOAPRequest,
+    /// The provided security credentials are not valid.
+    InvalidSecurity,
+    /// The storage class you specified is not valid.
+    InvalidStorageClass,
+    /// The target bucket for logging does not exist, is not owned by you, or does not have the appropriate grants for the log-delivery group.
+    InvalidTargetBucketForLogging,
+    /// The provided token is malformed or otherwise invalid.
+    InvalidToken,
+    /// Couldn't parse the specified URI.
+    InvalidURI,
+    /// Your key is too long.
+    KeyTooLongError,
+    /// The XML you provided was not well-formed or did not validate against our published schema.
+    MalformedACLError,
+    /// The body of your POST request is not well-formed multipart/form-data.
+    MalformedPOSTRequest,
+    /// The XML you provided was not well-formed or did not validate against our published schema.
+    MalformedXML,
+    /// Your request was too big.
+    MaxMessageLengthExceeded,
+    /// Your POST request fields preceding the upload file were too large.
+    MaxPostPreDataLengthExceededError,
+    /// Your metadata headers exceed the maximum allowed metadata size.
+    MetadataTooLarge,
+    /// The specified method is not allowed against this resource.
+    MethodNotAllowed,
+    /// A SOAP attachment was expected, but none were found.
+    MissingAttachment,
+    /// You must provide the Content-Length HTTP header.
+    MissingContentLength,
+    /// The request body is empty.
+    MissingRequestBodyError,
+    /// The SOAP 1.1 request is missing a security element.
+    MissingSecurityElement,
+    /// Your request is missing a required header.
+    MissingSecurityHeader,
+    /// There is no such thing as a logging status subresource for a key.
+    NoLoggingStatusForKey,
+    /// The specified bucket does not exist.
+    NoSuchBucket,
+    /// The specified bucket does not have a bucket policy.
+    NoSuchBucketPolicy,
+    /// The specified bucket does not have a CORS configuration.
+    NoSuchCORSConfiguration,
+    /// The specified key does not exist.
+    NoSuchKey,
+    /// The lifecycle configuration does not exist.
+    NoSuchLifecycleConfiguration,
+    /// The specified tag set does not exist.
+    NoSuchTagSet,
+    /// The specified multipart upload does not exist.
+    NoSuchUpload,
+    /// The version ID specified in the request does not match an existing version.
+    NoSuchVersion,
+    /// The specified bucket does not have a website configuration.
+    NoSuchWebsiteConfiguration,
+    /// A header you provided implies functionality that is not implemented.
+    NotImplemented,
+    /// Your account is not signed up for the Amazon S3 service.
+    NotSignedUp,
+    /// This operation is not allowed against this storage tier.
+    ObjectAlreadyInActiveTierError,
+    /// The bucket does not have an Object Lock configuration.
+    ObjectLockConfigurationNotFoundError,
+    /// The source object of the COPY operation is not in the active tier and is only stored in Amazon S3 Glacier.
+    ObjectNotInActiveTierError,
+    /// A conflicting conditional operation is currently in progress against this resource.
+    OperationAborted,
+    /// The bucket you are attempting to access must be addressed using the specified endpoint.
+    PermanentRedirect,
+    /// At least one of the preconditions you specified did not hold.
+    PreconditionFailed,
+    /// Temporary redirect.
+    Redirect,
+    /// The bucket does not have a replication configuration.
+    ReplicationConfigurationNotFoundError,
+    /// Bucket POST must be of the enclosure-type multipart/form-data.
+    RequestIsNotMultiPartContent,
+    /// The difference between the request time and the server's time is too large.
+    RequestTimeTooSkewed,
+    /// Your socket connection to the server was not read from or written to within the timeout period.
+    RequestTimeout,
+    /// Requesting the torrent file of a bucket is not permitted.
+    RequestTorrentOfBucketError,
+    /// Object restore is already in progress.
+    RestoreAlreadyInProgress,
+    /// The server side encryption configuration was not found.
+    ServerSideEncryptionConfigurationNotFoundError,
+    /// Reduce your request rate.
+    ServiceUnavailable,
+    /// The request signature we calculated does not match the signature you provided.
+    SignatureDoesNotMatch,
+    /// Reduce your request rate.
+    SlowDown,
+    /// You are being redirected to the bucket while DNS updates.
+    TemporaryRedirect,
+    /// The provided token must be refreshed.
+    TokenRefreshRequired,
+    /// You have attempted to create more buckets than allowed.
+    TooManyBuckets,
+    /// This request does not support content.
+    UnexpectedContent,
+    /// The email address you provided does not match any account on record.
+    UnresolvableGrantByEmailAddress,
+    /// The bucket POST must contain the specified field name.
+    UserKeyMustBeSpecified,
+    /// A code which wasn't documented when this crate was generated.
+    Unknown(String),
+}
+
+impl S3ErrorCode {
+    /// The error code given in the `Code` of an error response.
+    pub fn from_code(code: &str) -> S3ErrorCode {
+        match code {
+            "AccessDenied" => S3ErrorCode::AccessDenied,
+            "AccountProblem" => S3ErrorCode::AccountProblem,
+            "AllAccessDisabled" => S3ErrorCode::AllAccessDisabled,
+            "AmbiguousGrantByEmailAddress" => S3ErrorCode::AmbiguousGrantByEmailAddress,
+            "AuthorizationHeaderMalformed" => S3ErrorCode::AuthorizationHeaderMalformed,
+            "BadDigest" => S3ErrorCode::BadDigest,
+            "BucketAlreadyExists" => S3ErrorCode::BucketAlreadyExists,
+            "BucketAlreadyOwnedByYou" => S3ErrorCode::BucketAlreadyOwnedByYou,
+            "BucketNotEmpty" => S3ErrorCode::BucketNotEmpty,
+            "CredentialsNotSupported" => S3ErrorCode::CredentialsNotSupported,
+            "CrossLocationLoggingProhibited" => S3ErrorCode::CrossLocationLoggingProhibited,
+            "EntityTooLarge" => S3ErrorCode::EntityTooLarge,
+            "EntityTooSmall" => S3ErrorCode::EntityTooSmall,
+            "ExpiredToken" => S3ErrorCode::ExpiredToken,
+            "IllegalLocationConstraintException" => S3ErrorCode::IllegalLocationConstraintException,
+            "IllegalVersioningConfigurationException" => {
+                S3ErrorCode::IllegalVersioningConfigurationException
+            }
+            "IncompleteBody" => S3ErrorCode::IncompleteBody,
+            "IncorrectNumberOfFilesInPostRequest" => {
+                S3ErrorCode::IncorrectNumberOfFilesInPostRequest
+            }
+            "InlineDataTooLarge" => S3ErrorCode::InlineDataTooLarge,
+            "InternalError" => S3ErrorCode::InternalError,
+            "InvalidAccessKeyId" => S3ErrorCode::InvalidAccessKeyId,
+            "InvalidAddressingHeader" => S3ErrorCode::InvalidAddressingHeader,
+            "InvalidArgument" => S3ErrorCode::InvalidArgument,
+            "InvalidBucketName" => S3ErrorCode::InvalidBucketName,
+            "InvalidBucketState" => S3ErrorCode::InvalidBucketState,
+            "InvalidDigest" => S3ErrorCode::InvalidDigest,
+            "InvalidEncryptionAlgorithmError" => S3ErrorCode::InvalidEncryptionAlgorithmError,
+            "InvalidLocationConstraint" => S3ErrorCode::InvalidLocationConstraint,
+            "InvalidObjectState" => S3ErrorCode::InvalidObjectState,
+            "InvalidPart" => S3ErrorCode::InvalidPart,
+            "InvalidPartOrder" => S3ErrorCode::InvalidPartOrder,
+            "InvalidPayer" => S3ErrorCode::InvalidPayer,
+            "InvalidPolicyDocument" => S3ErrorCode::InvalidPolicyDocument,
+            "InvalidRange" => S3ErrorCode::InvalidRange,
+            "InvalidRequest" => S3ErrorCode::InvalidRequest,
+            "InvalidSOAPRequest" => S3ErrorCode::InvalidSOAPRequest,
+            "InvalidSecurity" => S3ErrorCode::InvalidSecurity,
+            "InvalidStorageClass" => S3ErrorCode::InvalidStorageClass,
+            "InvalidTargetBucketForLogging" => S3ErrorCode::InvalidTargetBucketForLogging,
+            "InvalidToken" => S3ErrorCode::InvalidToken,
+            "InvalidURI" => S3ErrorCode::InvalidURI,
+            "KeyTooLongError" => S3ErrorCode::KeyTooLongError,
+            "MalformedACLError" => S3ErrorCode::MalformedACLError,
+            "MalformedPOSTRequest" => S3ErrorCode::MalformedPOSTRequest,
+            "MalformedXML" => S3ErrorCode::MalformedXML,
+            "MaxMessageLengthExceeded" => S3ErrorCode::MaxMessageLengthExceeded,
+            "MaxPostPreDataLengthExceededError" => S3ErrorCode::MaxPostPreDataLengthExceededError,
+            "MetadataTooLarge" => S3ErrorCode::MetadataTooLarge,
+            "MethodNotAllowed" => S3ErrorCode::MethodNotAllowed,
+            "MissingAttachment" => S3ErrorCode::MissingAttachment,
+            "MissingContentLength" => S3ErrorCode::MissingContentLength,
+            "MissingRequestBodyError" => S3ErrorCode::MissingRequestBodyError,
+            "MissingSecurityElement" => S3ErrorCode::MissingSecurityElement,
+            "MissingSecurityHeader" => S3ErrorCode::MissingSecurityHeader,
+            "NoLoggingStatusForKey" => S3ErrorCode::NoLoggingStatusForKey,
+            "NoSuchBucket" => S3ErrorCode::NoSuchBucket,
+            "NoSuchBucketPolicy" => S3ErrorCode::NoSuchBucketPolicy,
+            "NoSuchCORSConfiguration" => S3ErrorCode::NoSuchCORSConfiguration,
+            "NoSuchKey" => S3ErrorCode::NoSuchKey,
+            "NoSuchLifecycleConfiguration" => S3ErrorCode::NoSuchLifecycleConfiguration,
+            "NoSuchTagSet" => S3ErrorCode::NoSuchTagSet,
+            "NoSuchUpload" => S3ErrorCode::NoSuchUpload,
+            "NoSuchVersion" => S3ErrorCode::NoSuchVersion,
+            "NoSuchWebsiteConfiguration" => S3ErrorCode::NoSuchWebsiteConfiguration,
+            "NotImplemented" => S3ErrorCode::NotImplemented,
+            "NotSignedUp" => S3ErrorCode::NotSignedUp,
+            "ObjectAlreadyInActiveTierError" => S3ErrorCode::ObjectAlreadyInActiveTierError,
+            "ObjectLockConfigurationNotFoundError" => {
+                S3ErrorCode::ObjectLockConfigurationNotFoundError
+            }
+            "ObjectNotInActiveTierError" => S3ErrorCode::ObjectNotInActiveTierError,
+            "OperationAborted" => S3ErrorCode::OperationAborted,
+            "PermanentRedirect" => S3ErrorCode::PermanentRedirect,
+            "PreconditionFailed" => S3ErrorCode::PreconditionFailed,
+            "Redirect" => S3ErrorCode::Redirect,
+            "ReplicationConfigurationNotFoundError" => {
+                S3ErrorCode::ReplicationConfigurationNotFoundError
+            }
+            "RequestIsNotMultiPartContent" => S3ErrorCode::RequestIsNotMultiPartContent,
+            "RequestTimeTooSkewed" => S3ErrorCode::RequestTimeTooSkewed,
+            "RequestTimeout" => S3ErrorCode::RequestTimeout,
+            "RequestTorrentOfBucketError" => S3ErrorCode::RequestTorrentOfBucketError,
+            "RestoreAlreadyInProgress" => S3ErrorCode::RestoreAlreadyInProgress,
+            "ServerSideEncryptionConfigurationNotFoundError" => {
+                S3ErrorCode::ServerSideEncryptionConfigurationNotFoundError
+            }
+            "ServiceUnavailable" => S3ErrorCode::ServiceUnavailable,
+            "SignatureDoesNotMatch" => S3ErrorCode::SignatureDoesNotMatch,
+            "SlowDown" => S3ErrorCode::SlowDown,
+            "TemporaryRedirect" => S3ErrorCode::TemporaryRedirect,
+            "TokenRefreshRequired" => S3ErrorCode::TokenRefreshRequired,
+            "TooManyBuckets" => S3ErrorCode::TooManyBuckets,
+            "UnexpectedContent" => S3ErrorCode::UnexpectedContent,
+            "UnresolvableGrantByEmailAddress" => S3ErrorCode::UnresolvableGrantByEmailAddress,
+            "UserKeyMustBeSpecified" => S3ErrorCode::UserKeyMustBeSpecified,
+            _ => S3ErrorCode::Unknown(code.to_owned()),
+        }
+    }
+
+    /// The code as it appears in error responses.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3ErrorCode::AccessDenied => "AccessDenied",
+            S3ErrorCode::AccountProblem => "AccountProblem",
+            S3ErrorCode::AllAccessDisabled => "AllAccessDisabled",
+            S3ErrorCode::AmbiguousGrantByEmailAddress => "AmbiguousGrantByEmailAddress",
+            S3ErrorCode::AuthorizationHeaderMalformed => "AuthorizationHeaderMalformed",
+            S3ErrorCode::BadDigest => "BadDigest",
+            S3ErrorCode::BucketAlreadyExists => "BucketAlreadyExists",
+            S3ErrorCode::BucketAlreadyOwnedByYou => "BucketAlreadyOwnedByYou",
+            S3ErrorCode::BucketNotEmpty => "BucketNotEmpty",
+            S3ErrorCode::CredentialsNotSupported => "CredentialsNotSupported",
+            S3ErrorCode::CrossLocationLoggingProhibited => "CrossLocationLoggingProhibited",
+            S3ErrorCode::EntityTooLarge => "EntityTooLarge",
+            S3ErrorCode::EntityTooSmall => "EntityTooSmall",
+            S3ErrorCode::ExpiredToken => "ExpiredToken",
+            S3ErrorCode::IllegalLocationConstraintException => "IllegalLocationConstraintException",
+            S3ErrorCode::IllegalVersioningConfigurationException => {
+                "IllegalVersioningConfigurationException"
+            }
+            S3ErrorCode::IncompleteBody => "IncompleteBody",
+            S3ErrorCode::IncorrectNumberOfFilesInPostRequest => {
+                "IncorrectNumberOfFilesInPostRequest"
+            }
+            S3ErrorCode::InlineDataTooLarge => "InlineDataTooLarge",
+            S3ErrorCode::InternalError => "InternalError",
+            S3ErrorCode::InvalidAccessKeyId => "InvalidAccessKeyId",
+            S3ErrorCode::InvalidAddressingHeader => "InvalidAddressingHeader",
+            S3ErrorCode::InvalidArgument => "InvalidArgument",
+            S3ErrorCode::InvalidBucketName => "InvalidBucketName",
+            S3ErrorCode::InvalidBucketState => "InvalidBucketState",
+            S3ErrorCode::InvalidDigest => "InvalidDigest",
+            S3ErrorCode::InvalidEncryptionAlgorithmError => "InvalidEncryptionAlgorithmError",
+            S3ErrorCode::InvalidLocationConstraint => "InvalidLocationConstraint",
+            S3ErrorCode::InvalidObjectState => "InvalidObjectState",
+            S3ErrorCode::InvalidPart => "InvalidPart",
+            S3ErrorCode::InvalidPartOrder => "InvalidPartOrder",
+            S3ErrorCode::InvalidPayer => "InvalidPayer",
+            S3ErrorCode::InvalidPolicyDocument => "InvalidPolicyDocument",
+            S3ErrorCode::InvalidRange => "InvalidRange",
+            S3ErrorCode::InvalidRequest => "InvalidRequest",
+            S3ErrorCode::InvalidSOAPRequest => "InvalidSOAPRequest",
+            S3ErrorCode::InvalidSecurity => "InvalidSecurity",
+            S3ErrorCode::InvalidStorageClass => "InvalidStorageClass",
+            S3ErrorCode::InvalidTargetBucketForLogging => "InvalidTargetBucketForLogging",
+            S3ErrorCode::InvalidToken => "InvalidToken",
+            S3ErrorCode::InvalidURI => "InvalidURI",
+            S3ErrorCode::KeyTooLongError => "KeyTooLongError",
+            S3ErrorCode::MalformedACLError => "MalformedACLError",
+            S3ErrorCode::MalformedPOSTRequest => "MalformedPOSTRequest",
+            S3ErrorCode::MalformedXML => "MalformedXML",
+            S3ErrorCode::MaxMessageLengthExceeded => "MaxMessageLengthExceeded",
+            S3ErrorCode::MaxPostPreDataLengthExceededError => "MaxPostPreDataLengthExceededError",
+            S3ErrorCode::MetadataTooLarge => "MetadataTooLarge",
+            S3ErrorCode::MethodNotAllowed => "MethodNotAllowed",
+            S3ErrorCode::MissingAttachment => "MissingAttachment",
+            S3ErrorCode::MissingContentLength => "MissingContentLength",
+            S3ErrorCode::MissingRequestBodyError => "MissingRequestBodyError",
+            S3ErrorCode::MissingSecurityElement => "MissingSecurityElement",
+            S3ErrorCode::MissingSecurityHeader => "MissingSecurityHeader",
+            S3ErrorCode::NoLoggingStatusForKey => "NoLoggingStatusForKey",
+            S3ErrorCode::NoSuchBucket => "NoSuchBucket",
+            S3ErrorCode::NoSuchBucketPolicy => "NoSuchBucketPolicy",
+            S3ErrorCode::NoSuchCORSConfiguration => "NoSuchCORSConfiguration",
+            S3ErrorCode::NoSuchKey => "NoSuchKey",
+            S3ErrorCode::NoSuchLifecycleConfiguration => "NoSuchLifecycleConfiguration",
+            S3ErrorCode::NoSuchTagSet => "NoSuchTagSet",
+            S3ErrorCode::NoSuchUpload => "NoSuchUpload",
+            S3ErrorCode::NoSuchVersion => "NoSuchVersion",
+            S3ErrorCode::NoSuchWebsiteConfiguration => "NoSuchWebsiteConfiguration",
+            S3ErrorCode::NotImplemented => "NotImplemented",
+            S3ErrorCode::NotSignedUp => "NotSignedUp",
+            S3ErrorCode::ObjectAlreadyInActiveTierError => "ObjectAlreadyInActiveTierError",
+            S3ErrorCode::ObjectLockConfigurationNotFoundError => {
+                "ObjectLockConfigurationNotFoundError"
+            }
+            S3ErrorCode::ObjectNotInActiveTierError => "ObjectNotInActiveTierError",
+            S3ErrorCode::OperationAborted => "OperationAborted",
+            S3ErrorCode::PermanentRedirect => "PermanentRedirect",
+            S3ErrorCode::PreconditionFailed => "PreconditionFailed",
+            S3ErrorCode::Redirect => "Redirect",
+            S3ErrorCode::ReplicationConfigurationNotFoundError => {
+                "ReplicationConfigurationNotFoundError"
+            }
+            S3ErrorCode::RequestIsNotMultiPartContent => "RequestIsNotMultiPartContent",
+            S3ErrorCode::RequestTimeTooSkewed => "RequestTimeTooSkewed",
+            S3ErrorCode::RequestTimeout => "RequestTimeout",
+            S3ErrorCode::RequestTorrentOfBucketError => "RequestTorrentOfBucketError",
+            S3ErrorCode::RestoreAlreadyInProgress => "RestoreAlreadyInProgress",
+            S3ErrorCode::ServerSideEncryptionConfigurationNotFoundError => {
+                "ServerSideEncryptionConfigurationNotFoundError"
+            }
+            S3ErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            S3ErrorCode::SignatureDoesNotMatch => "SignatureDoesNotMatch",
+            S3ErrorCode::SlowDown => "SlowDown",
+            S3ErrorCode::TemporaryRedirect => "TemporaryRedirect",
+            S3ErrorCode::TokenRefreshRequired => "TokenRefreshRequired",
+            S3ErrorCode::TooManyBuckets => "TooManyBuckets",
+            S3ErrorCode::UnexpectedContent => "UnexpectedContent",
+            S3ErrorCode::UnresolvableGrantByEmailAddress => "UnresolvableGrantByEmailAddress",
+            S3ErrorCode::UserKeyMustBeSpecified => "UserKeyMustBeSpecified",
+            S3ErrorCode::Unknown(ref code) => code,
+        }
+    }
+}
+
+impl fmt::Display for S3ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 /// Trait representing the capabilities of the Amazon S3 API. Amazon S3 clients implement this trait.
 pub trait S3 {
     /// <p>Aborts a multipart upload.</p> <p>To verify that all parts have been removed, so you don't get charged for the part storage, you should call the List Parts operation and ensure the parts list is empty.</p>
@@ -18833,36 +20322,1576 @@ pub trait S3 {
         input: UploadPartCopyRequest,
     ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError>;
 }
-/// A client for the Amazon S3 API.
-#[derive(Clone)]
-pub struct S3Client {
-    client: Client,
-    region: region::Region,
-}
-
-impl S3Client {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> S3Client {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> S3Client
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+const _: Option<&dyn S3> = None;
+impl<'a, T: S3 + ?Sized> S3 for &'a T {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> RusotoFuture<AbortMultipartUploadOutput, AbortMultipartUploadError> {
+        (**self).abort_multipart_upload(input)
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> RusotoFuture<CompleteMultipartUploadOutput, CompleteMultipartUploadError> {
+        (**self).complete_multipart_upload(input)
+    }
+    fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        (**self).copy_object(input)
+    }
+    fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        (**self).create_bucket(input)
+    }
+    fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
+        (**self).create_multipart_upload(input)
+    }
+    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
+        (**self).delete_bucket(input)
+    }
+    fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
+        (**self).delete_bucket_analytics_configuration(input)
+    }
+    fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        (**self).delete_bucket_cors(input)
+    }
+    fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        (**self).delete_bucket_encryption(input)
+    }
+    fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        (**self).delete_bucket_inventory_configuration(input)
+    }
+    fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        (**self).delete_bucket_lifecycle(input)
+    }
+    fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        (**self).delete_bucket_metrics_configuration(input)
+    }
+    fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        (**self).delete_bucket_policy(input)
+    }
+    fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
+        (**self).delete_bucket_replication(input)
+    }
+    fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        (**self).delete_bucket_tagging(input)
+    }
+    fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        (**self).delete_bucket_website(input)
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        (**self).delete_object(input)
+    }
+    fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
+        (**self).delete_object_tagging(input)
+    }
+    fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        (**self).delete_objects(input)
+    }
+    fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
+        (**self).delete_public_access_block(input)
+    }
+    fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
+    {
+        (**self).get_bucket_accelerate_configuration(input)
+    }
+    fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
+        (**self).get_bucket_acl(input)
+    }
+    fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
+    {
+        (**self).get_bucket_analytics_configuration(input)
+    }
+    fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
+        (**self).get_bucket_cors(input)
+    }
+    fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
+        (**self).get_bucket_encryption(input)
+    }
+    fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
+    {
+        (**self).get_bucket_inventory_configuration(input)
+    }
+    fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        (**self).get_bucket_lifecycle(input)
+    }
+    fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
+    {
+        (**self).get_bucket_lifecycle_configuration(input)
+    }
+    fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
+        (**self).get_bucket_location(input)
+    }
+    fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
+        (**self).get_bucket_logging(input)
+    }
+    fn get_bucket_metrics_configuration(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
+        (**self).get_bucket_metrics_configuration(input)
+    }
+    fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
+        (**self).get_bucket_notification(input)
+    }
+    fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
+        (**self).get_bucket_notification_configuration(input)
+    }
+    fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
+        (**self).get_bucket_policy(input)
+    }
+    fn get_bucket_policy_status(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
+        (**self).get_bucket_policy_status(input)
+    }
+    fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
+        (**self).get_bucket_replication(input)
+    }
+    fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
+        (**self).get_bucket_request_payment(input)
+    }
+    fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
+        (**self).get_bucket_tagging(input)
+    }
+    fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
+        (**self).get_bucket_versioning(input)
+    }
+    fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
+        (**self).get_bucket_website(input)
+    }
+    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
+        (**self).get_object(input)
+    }
+    fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
+        (**self).get_object_acl(input)
+    }
+    fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
+        (**self).get_object_legal_hold(input)
+    }
+    fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
+        (**self).get_object_lock_configuration(input)
+    }
+    fn get_object_retention(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
+        (**self).get_object_retention(input)
+    }
+    fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+        (**self).get_object_tagging(input)
+    }
+    fn get_object_torrent(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
+        (**self).get_object_torrent(input)
+    }
+    fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
+        (**self).get_public_access_block(input)
+    }
+    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
+        (**self).head_bucket(input)
+    }
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
+        (**self).head_object(input)
+    }
+    fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
+    {
+        (**self).list_bucket_analytics_configurations(input)
+    }
+    fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
+    {
+        (**self).list_bucket_inventory_configurations(input)
+    }
+    fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
+    {
+        (**self).list_bucket_metrics_configurations(input)
+    }
+    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
+        (**self).list_buckets()
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        (**self).list_multipart_uploads(input)
+    }
+    fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        (**self).list_object_versions(input)
+    }
+    fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
+        (**self).list_objects(input)
+    }
+    fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        (**self).list_objects_v2(input)
+    }
+    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        (**self).list_parts(input)
+    }
+    fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        (**self).put_bucket_accelerate_configuration(input)
+    }
+    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
+        (**self).put_bucket_acl(input)
+    }
+    fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
+        (**self).put_bucket_analytics_configuration(input)
+    }
+    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
+        (**self).put_bucket_cors(input)
+    }
+    fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> RusotoFuture<(), PutBucketEncryptionError> {
+        (**self).put_bucket_encryption(input)
+    }
+    fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
+        (**self).put_bucket_inventory_configuration(input)
+    }
+    fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleError> {
+        (**self).put_bucket_lifecycle(input)
+    }
+    fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
+        (**self).put_bucket_lifecycle_configuration(input)
+    }
+    fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> RusotoFuture<(), PutBucketLoggingError> {
+        (**self).put_bucket_logging(input)
+    }
+    fn put_bucket_metrics_configuration(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
+        (**self).put_bucket_metrics_configuration(input)
+    }
+    fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        (**self).put_bucket_notification(input)
+    }
+    fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
+        (**self).put_bucket_notification_configuration(input)
+    }
+    fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> RusotoFuture<(), PutBucketPolicyError> {
+        (**self).put_bucket_policy(input)
+    }
+    fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> RusotoFuture<(), PutBucketReplicationError> {
+        (**self).put_bucket_replication(input)
+    }
+    fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
+        (**self).put_bucket_request_payment(input)
+    }
+    fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> RusotoFuture<(), PutBucketTaggingError> {
+        (**self).put_bucket_tagging(input)
+    }
+    fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> RusotoFuture<(), PutBucketVersioningError> {
+        (**self).put_bucket_versioning(input)
+    }
+    fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> RusotoFuture<(), PutBucketWebsiteError> {
+        (**self).put_bucket_website(input)
+    }
+    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+        (**self).put_object(input)
+    }
+    fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
+        (**self).put_object_acl(input)
+    }
+    fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
+        (**self).put_object_legal_hold(input)
+    }
+    fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
+        (**self).put_object_lock_configuration(input)
+    }
+    fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
+        (**self).put_object_retention(input)
+    }
+    fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
+        (**self).put_object_tagging(input)
+    }
+    fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
+        (**self).put_public_access_block(input)
+    }
+    fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
+        (**self).restore_object(input)
+    }
+    fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
+        (**self).select_object_content(input)
+    }
+    fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
+        (**self).upload_part(input)
+    }
+    fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
+        (**self).upload_part_copy(input)
+    }
+}
+impl<T: S3 + ?Sized> S3 for Box<T> {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> RusotoFuture<AbortMultipartUploadOutput, AbortMultipartUploadError> {
+        (**self).abort_multipart_upload(input)
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> RusotoFuture<CompleteMultipartUploadOutput, CompleteMultipartUploadError> {
+        (**self).complete_multipart_upload(input)
+    }
+    fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        (**self).copy_object(input)
+    }
+    fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        (**self).create_bucket(input)
+    }
+    fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
+        (**self).create_multipart_upload(input)
+    }
+    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
+        (**self).delete_bucket(input)
+    }
+    fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
+        (**self).delete_bucket_analytics_configuration(input)
+    }
+    fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        (**self).delete_bucket_cors(input)
+    }
+    fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        (**self).delete_bucket_encryption(input)
+    }
+    fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        (**self).delete_bucket_inventory_configuration(input)
+    }
+    fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        (**self).delete_bucket_lifecycle(input)
+    }
+    fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        (**self).delete_bucket_metrics_configuration(input)
+    }
+    fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        (**self).delete_bucket_policy(input)
+    }
+    fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
+        (**self).delete_bucket_replication(input)
+    }
+    fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        (**self).delete_bucket_tagging(input)
+    }
+    fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        (**self).delete_bucket_website(input)
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        (**self).delete_object(input)
+    }
+    fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
+        (**self).delete_object_tagging(input)
+    }
+    fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        (**self).delete_objects(input)
+    }
+    fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
+        (**self).delete_public_access_block(input)
+    }
+    fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
+    {
+        (**self).get_bucket_accelerate_configuration(input)
+    }
+    fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
+        (**self).get_bucket_acl(input)
+    }
+    fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
+    {
+        (**self).get_bucket_analytics_configuration(input)
+    }
+    fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
+        (**self).get_bucket_cors(input)
+    }
+    fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
+        (**self).get_bucket_encryption(input)
+    }
+    fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
+    {
+        (**self).get_bucket_inventory_configuration(input)
+    }
+    fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        (**self).get_bucket_lifecycle(input)
+    }
+    fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
+    {
+        (**self).get_bucket_lifecycle_configuration(input)
+    }
+    fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
+        (**self).get_bucket_location(input)
+    }
+    fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
+        (**self).get_bucket_logging(input)
+    }
+    fn get_bucket_metrics_configuration(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
+        (**self).get_bucket_metrics_configuration(input)
+    }
+    fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
+        (**self).get_bucket_notification(input)
+    }
+    fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
+        (**self).get_bucket_notification_configuration(input)
+    }
+    fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
+        (**self).get_bucket_policy(input)
+    }
+    fn get_bucket_policy_status(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
+        (**self).get_bucket_policy_status(input)
+    }
+    fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
+        (**self).get_bucket_replication(input)
+    }
+    fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
+        (**self).get_bucket_request_payment(input)
+    }
+    fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
+        (**self).get_bucket_tagging(input)
+    }
+    fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
+        (**self).get_bucket_versioning(input)
+    }
+    fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
+        (**self).get_bucket_website(input)
+    }
+    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
+        (**self).get_object(input)
+    }
+    fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
+        (**self).get_object_acl(input)
+    }
+    fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
+        (**self).get_object_legal_hold(input)
+    }
+    fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
+        (**self).get_object_lock_configuration(input)
+    }
+    fn get_object_retention(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
+        (**self).get_object_retention(input)
+    }
+    fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+        (**self).get_object_tagging(input)
+    }
+    fn get_object_torrent(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
+        (**self).get_object_torrent(input)
+    }
+    fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
+        (**self).get_public_access_block(input)
+    }
+    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
+        (**self).head_bucket(input)
+    }
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
+        (**self).head_object(input)
+    }
+    fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
+    {
+        (**self).list_bucket_analytics_configurations(input)
+    }
+    fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
+    {
+        (**self).list_bucket_inventory_configurations(input)
+    }
+    fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
+    {
+        (**self).list_bucket_metrics_configurations(input)
+    }
+    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
+        (**self).list_buckets()
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        (**self).list_multipart_uploads(input)
+    }
+    fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        (**self).list_object_versions(input)
+    }
+    fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
+        (**self).list_objects(input)
+    }
+    fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        (**self).list_objects_v2(input)
+    }
+    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        (**self).list_parts(input)
+    }
+    fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        (**self).put_bucket_accelerate_configuration(input)
+    }
+    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
+        (**self).put_bucket_acl(input)
+    }
+    fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
+        (**self).put_bucket_analytics_configuration(input)
+    }
+    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
+        (**self).put_bucket_cors(input)
+    }
+    fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> RusotoFuture<(), PutBucketEncryptionError> {
+        (**self).put_bucket_encryption(input)
+    }
+    fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
+        (**self).put_bucket_inventory_configuration(input)
+    }
+    fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleError> {
+        (**self).put_bucket_lifecycle(input)
+    }
+    fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
+        (**self).put_bucket_lifecycle_configuration(input)
+    }
+    fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> RusotoFuture<(), PutBucketLoggingError> {
+        (**self).put_bucket_logging(input)
+    }
+    fn put_bucket_metrics_configuration(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
+        (**self).put_bucket_metrics_configuration(input)
+    }
+    fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        (**self).put_bucket_notification(input)
+    }
+    fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
+        (**self).put_bucket_notification_configuration(input)
+    }
+    fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> RusotoFuture<(), PutBucketPolicyError> {
+        (**self).put_bucket_policy(input)
+    }
+    fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> RusotoFuture<(), PutBucketReplicationError> {
+        (**self).put_bucket_replication(input)
+    }
+    fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
+        (**self).put_bucket_request_payment(input)
+    }
+    fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> RusotoFuture<(), PutBucketTaggingError> {
+        (**self).put_bucket_tagging(input)
+    }
+    fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> RusotoFuture<(), PutBucketVersioningError> {
+        (**self).put_bucket_versioning(input)
+    }
+    fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> RusotoFuture<(), PutBucketWebsiteError> {
+        (**self).put_bucket_website(input)
+    }
+    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+        (**self).put_object(input)
+    }
+    fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
+        (**self).put_object_acl(input)
+    }
+    fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
+        (**self).put_object_legal_hold(input)
+    }
+    fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
+        (**self).put_object_lock_configuration(input)
+    }
+    fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
+        (**self).put_object_retention(input)
+    }
+    fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
+        (**self).put_object_tagging(input)
+    }
+    fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
+        (**self).put_public_access_block(input)
+    }
+    fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
+        (**self).restore_object(input)
+    }
+    fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
+        (**self).select_object_content(input)
+    }
+    fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
+        (**self).upload_part(input)
+    }
+    fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
+        (**self).upload_part_copy(input)
+    }
+}
+impl<T: S3 + ?Sized> S3 for ::std::sync::Arc<T> {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> RusotoFuture<AbortMultipartUploadOutput, AbortMultipartUploadError> {
+        (**self).abort_multipart_upload(input)
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> RusotoFuture<CompleteMultipartUploadOutput, CompleteMultipartUploadError> {
+        (**self).complete_multipart_upload(input)
+    }
+    fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        (**self).copy_object(input)
+    }
+    fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        (**self).create_bucket(input)
+    }
+    fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
+        (**self).create_multipart_upload(input)
+    }
+    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
+        (**self).delete_bucket(input)
+    }
+    fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
+        (**self).delete_bucket_analytics_configuration(input)
+    }
+    fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        (**self).delete_bucket_cors(input)
+    }
+    fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        (**self).delete_bucket_encryption(input)
+    }
+    fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        (**self).delete_bucket_inventory_configuration(input)
+    }
+    fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        (**self).delete_bucket_lifecycle(input)
+    }
+    fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        (**self).delete_bucket_metrics_configuration(input)
+    }
+    fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        (**self).delete_bucket_policy(input)
+    }
+    fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
+        (**self).delete_bucket_replication(input)
+    }
+    fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        (**self).delete_bucket_tagging(input)
+    }
+    fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        (**self).delete_bucket_website(input)
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        (**self).delete_object(input)
+    }
+    fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
+        (**self).delete_object_tagging(input)
+    }
+    fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        (**self).delete_objects(input)
+    }
+    fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
+        (**self).delete_public_access_block(input)
+    }
+    fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
+    {
+        (**self).get_bucket_accelerate_configuration(input)
+    }
+    fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
+        (**self).get_bucket_acl(input)
+    }
+    fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
+    {
+        (**self).get_bucket_analytics_configuration(input)
+    }
+    fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
+        (**self).get_bucket_cors(input)
+    }
+    fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
+        (**self).get_bucket_encryption(input)
+    }
+    fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
+    {
+        (**self).get_bucket_inventory_configuration(input)
+    }
+    fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        (**self).get_bucket_lifecycle(input)
+    }
+    fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
+    {
+        (**self).get_bucket_lifecycle_configuration(input)
+    }
+    fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
+        (**self).get_bucket_location(input)
+    }
+    fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
+        (**self).get_bucket_logging(input)
+    }
+    fn get_bucket_metrics_configuration(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
+        (**self).get_bucket_metrics_configuration(input)
+    }
+    fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
+        (**self).get_bucket_notification(input)
+    }
+    fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
+        (**self).get_bucket_notification_configuration(input)
+    }
+    fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
+        (**self).get_bucket_policy(input)
+    }
+    fn get_bucket_policy_status(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
+        (**self).get_bucket_policy_status(input)
+    }
+    fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
+        (**self).get_bucket_replication(input)
+    }
+    fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
+        (**self).get_bucket_request_payment(input)
+    }
+    fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
+        (**self).get_bucket_tagging(input)
+    }
+    fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
+        (**self).get_bucket_versioning(input)
+    }
+    fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
+        (**self).get_bucket_website(input)
+    }
+    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
+        (**self).get_object(input)
+    }
+    fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
+        (**self).get_object_acl(input)
+    }
+    fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
+        (**self).get_object_legal_hold(input)
+    }
+    fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
+        (**self).get_object_lock_configuration(input)
+    }
+    fn get_object_retention(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
+        (**self).get_object_retention(input)
+    }
+    fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+        (**self).get_object_tagging(input)
+    }
+    fn get_object_torrent(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
+        (**self).get_object_torrent(input)
+    }
+    fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
+        (**self).get_public_access_block(input)
+    }
+    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
+        (**self).head_bucket(input)
+    }
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
+        (**self).head_object(input)
+    }
+    fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
+    {
+        (**self).list_bucket_analytics_configurations(input)
+    }
+    fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
+    {
+        (**self).list_bucket_inventory_configurations(input)
+    }
+    fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
+    {
+        (**self).list_bucket_metrics_configurations(input)
+    }
+    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
+        (**self).list_buckets()
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        (**self).list_multipart_uploads(input)
+    }
+    fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        (**self).list_object_versions(input)
+    }
+    fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
+        (**self).list_objects(input)
+    }
+    fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        (**self).list_objects_v2(input)
+    }
+    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        (**self).list_parts(input)
+    }
+    fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        (**self).put_bucket_accelerate_configuration(input)
+    }
+    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
+        (**self).put_bucket_acl(input)
+    }
+    fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
+        (**self).put_bucket_analytics_configuration(input)
+    }
+    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
+        (**self).put_bucket_cors(input)
+    }
+    fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> RusotoFuture<(), PutBucketEncryptionError> {
+        (**self).put_bucket_encryption(input)
+    }
+    fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
+        (**self).put_bucket_inventory_configuration(input)
+    }
+    fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleError> {
+        (**self).put_bucket_lifecycle(input)
+    }
+    fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
+        (**self).put_bucket_lifecycle_configuration(input)
+    }
+    fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> RusotoFuture<(), PutBucketLoggingError> {
+        (**self).put_bucket_logging(input)
+    }
+    fn put_bucket_metrics_configuration(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
+        (**self).put_bucket_metrics_configuration(input)
+    }
+    fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        (**self).put_bucket_notification(input)
+    }
+    fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
+        (**self).put_bucket_notification_configuration(input)
+    }
+    fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> RusotoFuture<(), PutBucketPolicyError> {
+        (**self).put_bucket_policy(input)
+    }
+    fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> RusotoFuture<(), PutBucketReplicationError> {
+        (**self).put_bucket_replication(input)
+    }
+    fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
+        (**self).put_bucket_request_payment(input)
+    }
+    fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> RusotoFuture<(), PutBucketTaggingError> {
+        (**self).put_bucket_tagging(input)
+    }
+    fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> RusotoFuture<(), PutBucketVersioningError> {
+        (**self).put_bucket_versioning(input)
+    }
+    fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> RusotoFuture<(), PutBucketWebsiteError> {
+        (**self).put_bucket_website(input)
+    }
+    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+        (**self).put_object(input)
+    }
+    fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
+        (**self).put_object_acl(input)
+    }
+    fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
+        (**self).put_object_legal_hold(input)
+    }
+    fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
+        (**self).put_object_lock_configuration(input)
+    }
+    fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
+        (**self).put_object_retention(input)
+    }
+    fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
+        (**self).put_object_tagging(input)
+    }
+    fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
+        (**self).put_public_access_block(input)
+    }
+    fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
+        (**self).restore_object(input)
+    }
+    fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
+        (**self).select_object_content(input)
+    }
+    fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
+        (**self).upload_part(input)
+    }
+    fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
+        (**self).upload_part_copy(input)
+    }
+}
+/// A client for the Amazon S3 API.
+#[derive(Clone)]
+pub struct S3Client {
+    client: Client,
+    region: region::Region,
+}
+
+impl S3Client {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> S3Client {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> S3Client
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> S3Client {
@@ -18877,9 +21906,23 @@ impl S3 for S3Client {
         &self,
         input: AbortMultipartUploadRequest,
     ) -> RusotoFuture<AbortMultipartUploadOutput, AbortMultipartUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -18903,6 +21946,7 @@ impl S3 for S3Client {
                 if response.body.is_empty() {
                     result = AbortMultipartUploadOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -18913,7 +21957,10 @@ impl S3 for S3Client {
                     result = AbortMultipartUploadOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AbortMultipartUpload", err, &response.body)
+                    })?;
                 }
                 if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
                     let value = request_charged.to_owned();
@@ -18930,9 +21977,24 @@ impl S3 for S3Client {
         &self,
         input: CompleteMultipartUploadRequest,
     ) -> RusotoFuture<CompleteMultipartUploadOutput, CompleteMultipartUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
@@ -18947,24 +22009,302 @@ impl S3 for S3Client {
                 "CompleteMultipartUpload",
                 input.multipart_upload.as_ref().unwrap(),
             );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CompleteMultipartUploadError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CompleteMultipartUploadOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = CompleteMultipartUploadOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CompleteMultipartUpload", err, &response.body)
+                    })?;
+                }
+                if let Some(expiration) = response.headers.get("x-amz-expiration") {
+                    let value = expiration.to_owned();
+                    result.expiration = Some(value)
+                };
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                };
+                if let Some(ssekms_key_id) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-aws-kms-key-id")
+                {
+                    let value = ssekms_key_id.to_owned();
+                    result.ssekms_key_id = Some(value)
+                };
+                if let Some(server_side_encryption) =
+                    response.headers.get("x-amz-server-side-encryption")
+                {
+                    let value = server_side_encryption.to_owned();
+                    result.server_side_encryption = Some(value)
+                };
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a copy of an object that is already stored in Amazon S3.</p>
+    #[allow(unused_variables, warnings)]
+    fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CopyObject");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref acl) = input.acl {
+            request.add_header("x-amz-acl", &acl.to_string());
+        }
+
+        if let Some(ref cache_control) = input.cache_control {
+            request.add_header("Cache-Control", &cache_control.to_string());
+        }
+
+        if let Some(ref content_disposition) = input.content_disposition {
+            request.add_header("Content-Disposition", &content_disposition.to_string());
+        }
+
+        if let Some(ref content_encoding) = input.content_encoding {
+            request.add_header("Content-Encoding", &content_encoding.to_string());
+        }
+
+        if let Some(ref content_language) = input.content_language {
+            request.add_header("Content-Language", &content_language.to_string());
+        }
+
+        if let Some(ref content_type) = input.content_type {
+            request.add_header("Content-Type", &content_type.to_string());
+        }
+        request.add_header("x-amz-copy-source", &input.copy_source);
+
+        if let Some(ref copy_source_if_match) = input.copy_source_if_match {
+            request.add_header(
+                "x-amz-copy-source-if-match",
+                &copy_source_if_match.to_string(),
+            );
+        }
+
+        if let Some(ref copy_source_if_modified_since) = input.copy_source_if_modified_since {
+            request.add_header(
+                "x-amz-copy-source-if-modified-since",
+                &copy_source_if_modified_since.to_string(),
+            );
+        }
+
+        if let Some(ref copy_source_if_none_match) = input.copy_source_if_none_match {
+            request.add_header(
+                "x-amz-copy-source-if-none-match",
+                &copy_source_if_none_match.to_string(),
+            );
+        }
+
+        if let Some(ref copy_source_if_unmodified_since) = input.copy_source_if_unmodified_since {
+            request.add_header(
+                "x-amz-copy-source-if-unmodified-since",
+                &copy_source_if_unmodified_since.to_string(),
+            );
+        }
+
+        if let Some(ref copy_source_sse_customer_algorithm) =
+            input.copy_source_sse_customer_algorithm
+        {
+            request.add_header(
+                "x-amz-copy-source-server-side-encryption-customer-algorithm",
+                &copy_source_sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref copy_source_sse_customer_key) = input.copy_source_sse_customer_key {
+            request.add_header(
+                "x-amz-copy-source-server-side-encryption-customer-key",
+                &copy_source_sse_customer_key.to_string(),
+            );
+        }
+
+        if let Some(ref copy_source_sse_customer_key_md5) = input.copy_source_sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-copy-source-server-side-encryption-customer-key-MD5",
+                &copy_source_sse_customer_key_md5.to_string(),
+            );
+        }
+
+        if let Some(ref expires) = input.expires {
+            request.add_header("Expires", &expires.to_string());
+        }
+
+        if let Some(ref grant_full_control) = input.grant_full_control {
+            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+        }
+
+        if let Some(ref grant_read) = input.grant_read {
+            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        }
+
+        if let Some(ref grant_read_acp) = input.grant_read_acp {
+            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        }
+
+        if let Some(ref grant_write_acp) = input.grant_write_acp {
+            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
+        }
+
+        if let Some(ref metadata) = input.metadata {
+            for (header_name, header_value) in metadata.iter() {
+                let header = format!("x-amz-meta-{}", header_name);
+                request.add_header(header, header_value);
+            }
+        }
+
+        if let Some(ref metadata_directive) = input.metadata_directive {
+            request.add_header("x-amz-metadata-directive", &metadata_directive.to_string());
+        }
+
+        if let Some(ref object_lock_legal_hold_status) = input.object_lock_legal_hold_status {
+            request.add_header(
+                "x-amz-object-lock-legal-hold",
+                &object_lock_legal_hold_status.to_string(),
+            );
+        }
+
+        if let Some(ref object_lock_mode) = input.object_lock_mode {
+            request.add_header("x-amz-object-lock-mode", &object_lock_mode.to_string());
+        }
+
+        if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
+            request.add_header(
+                "x-amz-object-lock-retain-until-date",
+                &object_lock_retain_until_date.to_string(),
+            );
+        }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
+
+        if let Some(ref ssekms_encryption_context) = input.ssekms_encryption_context {
+            request.add_header(
+                "x-amz-server-side-encryption-context",
+                &ssekms_encryption_context.to_string(),
+            );
+        }
+
+        if let Some(ref ssekms_key_id) = input.ssekms_key_id {
+            request.add_header(
+                "x-amz-server-side-encryption-aws-kms-key-id",
+                &ssekms_key_id.to_string(),
+            );
+        }
+
+        if let Some(ref server_side_encryption) = input.server_side_encryption {
+            request.add_header(
+                "x-amz-server-side-encryption",
+                &server_side_encryption.to_string(),
+            );
+        }
+
+        if let Some(ref storage_class) = input.storage_class {
+            request.add_header("x-amz-storage-class", &storage_class.to_string());
+        }
+
+        if let Some(ref tagging) = input.tagging {
+            request.add_header("x-amz-tagging", &tagging.to_string());
+        }
+
+        if let Some(ref tagging_directive) = input.tagging_directive {
+            request.add_header("x-amz-tagging-directive", &tagging_directive.to_string());
+        }
+
+        if let Some(ref website_redirect_location) = input.website_redirect_location {
+            request.add_header(
+                "x-amz-website-redirect-location",
+                &website_redirect_location.to_string(),
+            );
         }
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CompleteMultipartUploadError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CopyObjectError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CompleteMultipartUploadOutput::default();
+                    result = CopyObjectOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -18972,11 +22312,16 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CompleteMultipartUploadOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    result =
+                        CopyObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("CopyObject", err, &response.body))?;
                 }
+                if let Some(copy_source_version_id) =
+                    response.headers.get("x-amz-copy-source-version-id")
+                {
+                    let value = copy_source_version_id.to_owned();
+                    result.copy_source_version_id = Some(value)
+                };
                 if let Some(expiration) = response.headers.get("x-amz-expiration") {
                     let value = expiration.to_owned();
                     result.expiration = Some(value)
@@ -18985,6 +22330,26 @@ impl S3 for S3Client {
                     let value = request_charged.to_owned();
                     result.request_charged = Some(value)
                 };
+                if let Some(sse_customer_algorithm) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-algorithm")
+                {
+                    let value = sse_customer_algorithm.to_owned();
+                    result.sse_customer_algorithm = Some(value)
+                };
+                if let Some(sse_customer_key_md5) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-key-MD5")
+                {
+                    let value = sse_customer_key_md5.to_owned();
+                    result.sse_customer_key_md5 = Some(value)
+                };
+                if let Some(ssekms_encryption_context) =
+                    response.headers.get("x-amz-server-side-encryption-context")
+                {
+                    let value = ssekms_encryption_context.to_owned();
+                    result.ssekms_encryption_context = Some(value)
+                };
                 if let Some(ssekms_key_id) = response
                     .headers
                     .get("x-amz-server-side-encryption-aws-kms-key-id")
@@ -19007,90 +22372,148 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Creates a copy of an object that is already stored in Amazon S3.</p>
+    /// <p>Creates a new bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn copy_object(
+    fn create_bucket(
         &self,
-        input: CopyObjectRequest,
-    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        input: CreateBucketRequest,
+    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CreateBucket");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
         }
 
-        if let Some(ref cache_control) = input.cache_control {
-            request.add_header("Cache-Control", &cache_control.to_string());
+        if let Some(ref grant_full_control) = input.grant_full_control {
+            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
         }
 
-        if let Some(ref content_disposition) = input.content_disposition {
-            request.add_header("Content-Disposition", &content_disposition.to_string());
+        if let Some(ref grant_read) = input.grant_read {
+            request.add_header("x-amz-grant-read", &grant_read.to_string());
         }
 
-        if let Some(ref content_encoding) = input.content_encoding {
-            request.add_header("Content-Encoding", &content_encoding.to_string());
+        if let Some(ref grant_read_acp) = input.grant_read_acp {
+            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
         }
 
-        if let Some(ref content_language) = input.content_language {
-            request.add_header("Content-Language", &content_language.to_string());
+        if let Some(ref grant_write) = input.grant_write {
+            request.add_header("x-amz-grant-write", &grant_write.to_string());
         }
 
-        if let Some(ref content_type) = input.content_type {
-            request.add_header("Content-Type", &content_type.to_string());
+        if let Some(ref grant_write_acp) = input.grant_write_acp {
+            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
         }
-        request.add_header("x-amz-copy-source", &input.copy_source);
 
-        if let Some(ref copy_source_if_match) = input.copy_source_if_match {
+        if let Some(ref object_lock_enabled_for_bucket) = input.object_lock_enabled_for_bucket {
             request.add_header(
-                "x-amz-copy-source-if-match",
-                &copy_source_if_match.to_string(),
+                "x-amz-bucket-object-lock-enabled",
+                &object_lock_enabled_for_bucket.to_string(),
             );
         }
 
-        if let Some(ref copy_source_if_modified_since) = input.copy_source_if_modified_since {
-            request.add_header(
-                "x-amz-copy-source-if-modified-since",
-                &copy_source_if_modified_since.to_string(),
+        if input.create_bucket_configuration.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            CreateBucketConfigurationSerializer::serialize(
+                &mut writer,
+                "CreateBucketConfiguration",
+                input.create_bucket_configuration.as_ref().unwrap(),
             );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
         }
 
-        if let Some(ref copy_source_if_none_match) = input.copy_source_if_none_match {
-            request.add_header(
-                "x-amz-copy-source-if-none-match",
-                &copy_source_if_none_match.to_string(),
-            );
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateBucketError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = CreateBucketOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        CreateBucketOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("CreateBucket", err, &response.body))?;
+                }
+                if let Some(location) = response.headers.get("Location") {
+                    let value = location.to_owned();
+                    result.location = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Initiates a multipart upload and returns an upload ID.</p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p>
+    #[allow(unused_variables, warnings)]
+    fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
         }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
-        if let Some(ref copy_source_if_unmodified_since) = input.copy_source_if_unmodified_since {
-            request.add_header(
-                "x-amz-copy-source-if-unmodified-since",
-                &copy_source_if_unmodified_since.to_string(),
-            );
+        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CreateMultipartUpload");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
 
-        if let Some(ref copy_source_sse_customer_algorithm) =
-            input.copy_source_sse_customer_algorithm
-        {
-            request.add_header(
-                "x-amz-copy-source-server-side-encryption-customer-algorithm",
-                &copy_source_sse_customer_algorithm.to_string(),
-            );
+        if let Some(ref acl) = input.acl {
+            request.add_header("x-amz-acl", &acl.to_string());
         }
 
-        if let Some(ref copy_source_sse_customer_key) = input.copy_source_sse_customer_key {
-            request.add_header(
-                "x-amz-copy-source-server-side-encryption-customer-key",
-                &copy_source_sse_customer_key.to_string(),
-            );
+        if let Some(ref cache_control) = input.cache_control {
+            request.add_header("Cache-Control", &cache_control.to_string());
         }
 
-        if let Some(ref copy_source_sse_customer_key_md5) = input.copy_source_sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-copy-source-server-side-encryption-customer-key-MD5",
-                &copy_source_sse_customer_key_md5.to_string(),
-            );
+        if let Some(ref content_disposition) = input.content_disposition {
+            request.add_header("Content-Disposition", &content_disposition.to_string());
+        }
+
+        if let Some(ref content_encoding) = input.content_encoding {
+            request.add_header("Content-Encoding", &content_encoding.to_string());
+        }
+
+        if let Some(ref content_language) = input.content_language {
+            request.add_header("Content-Language", &content_language.to_string());
+        }
+
+        if let Some(ref content_type) = input.content_type {
+            request.add_header("Content-Type", &content_type.to_string());
         }
 
         if let Some(ref expires) = input.expires {
@@ -19120,10 +22543,6 @@ impl S3 for S3Client {
             }
         }
 
-        if let Some(ref metadata_directive) = input.metadata_directive {
-            request.add_header("x-amz-metadata-directive", &metadata_directive.to_string());
-        }
-
         if let Some(ref object_lock_legal_hold_status) = input.object_lock_legal_hold_status {
             request.add_header(
                 "x-amz-object-lock-legal-hold",
@@ -19196,33 +22615,30 @@ impl S3 for S3Client {
             request.add_header("x-amz-tagging", &tagging.to_string());
         }
 
-        if let Some(ref tagging_directive) = input.tagging_directive {
-            request.add_header("x-amz-tagging-directive", &tagging_directive.to_string());
-        }
-
         if let Some(ref website_redirect_location) = input.website_redirect_location {
             request.add_header(
                 "x-amz-website-redirect-location",
                 &website_redirect_location.to_string(),
             );
         }
+        let mut params = Params::new();
+        params.put_key("uploads");
+        request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CopyObjectError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateMultipartUploadError::from_response(response))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CopyObjectOutput::default();
+                    result = CreateMultipartUploadOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -19230,18 +22646,21 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        CopyObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+                    result = CreateMultipartUploadOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateMultipartUpload", err, &response.body)
+                    })?;
                 }
-                if let Some(copy_source_version_id) =
-                    response.headers.get("x-amz-copy-source-version-id")
-                {
-                    let value = copy_source_version_id.to_owned();
-                    result.copy_source_version_id = Some(value)
+                if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
+                    let value = abort_date.to_owned();
+                    result.abort_date = Some(value)
                 };
-                if let Some(expiration) = response.headers.get("x-amz-expiration") {
-                    let value = expiration.to_owned();
-                    result.expiration = Some(value)
+                if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
+                    let value = abort_rule_id.to_owned();
+                    result.abort_rule_id = Some(value)
                 };
                 if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
                     let value = request_charged.to_owned();
@@ -19279,68 +22698,89 @@ impl S3 for S3Client {
                 {
                     let value = server_side_encryption.to_owned();
                     result.server_side_encryption = Some(value)
-                };
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
                 }; // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Creates a new bucket.</p>
+    /// <p>Deletes the bucket. All objects (including all object versions and Delete Markers) in the bucket must be deleted before the bucket itself can be deleted.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucket");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBucketError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes an analytics configuration for the bucket (specified by the analytics configuration ID).</p> <p>To use this operation, you must have permissions to perform the s3:PutAnalyticsConfiguration action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. </p>
     #[allow(unused_variables, warnings)]
-    fn create_bucket(
+    fn delete_bucket_analytics_configuration(
         &self,
-        input: CreateBucketRequest,
-    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref acl) = input.acl {
-            request.add_header("x-amz-acl", &acl.to_string());
-        }
-
-        if let Some(ref grant_full_control) = input.grant_full_control {
-            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketAnalyticsConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref grant_read) = input.grant_read {
-            request.add_header("x-amz-grant-read", &grant_read.to_string());
-        }
+        let mut params = Params::new();
+        params.put("id", &input.id);
+        params.put_key("analytics");
+        request.set_params(params);
 
-        if let Some(ref grant_read_acp) = input.grant_read_acp {
-            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
-        }
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBucketAnalyticsConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
 
-        if let Some(ref grant_write) = input.grant_write {
-            request.add_header("x-amz-grant-write", &grant_write.to_string());
-        }
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
 
-        if let Some(ref grant_write_acp) = input.grant_write_acp {
-            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
-        }
+    /// <p>Deletes the CORS configuration information set for the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref object_lock_enabled_for_bucket) = input.object_lock_enabled_for_bucket {
-            request.add_header(
-                "x-amz-bucket-object-lock-enabled",
-                &object_lock_enabled_for_bucket.to_string(),
-            );
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketCors");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if input.create_bucket_configuration.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            CreateBucketConfigurationSerializer::serialize(
-                &mut writer,
-                "CreateBucketConfiguration",
-                input.create_bucket_configuration.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
-        }
+        let mut params = Params::new();
+        params.put_key("cors");
+        request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -19348,191 +22788,328 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(CreateBucketError::from_response(response))),
+                        .and_then(|response| Err(DeleteBucketCorsError::from_response(response))),
                 );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = CreateBucketOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        CreateBucketOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(location) = response.headers.get("Location") {
-                    let value = location.to_owned();
-                    result.location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Initiates a multipart upload and returns an upload ID.</p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p>
+    /// <p>Deletes the server-side encryption configuration from the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn create_multipart_upload(
+    fn delete_bucket_encryption(
         &self,
-        input: CreateMultipartUploadRequest,
-    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        input: DeleteBucketEncryptionRequest,
+    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref acl) = input.acl {
-            request.add_header("x-amz-acl", &acl.to_string());
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketEncryption");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref cache_control) = input.cache_control {
-            request.add_header("Cache-Control", &cache_control.to_string());
-        }
+        let mut params = Params::new();
+        params.put_key("encryption");
+        request.set_params(params);
 
-        if let Some(ref content_disposition) = input.content_disposition {
-            request.add_header("Content-Disposition", &content_disposition.to_string());
-        }
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBucketEncryptionError::from_response(response))
+                }));
+            }
 
-        if let Some(ref content_encoding) = input.content_encoding {
-            request.add_header("Content-Encoding", &content_encoding.to_string());
-        }
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
 
-        if let Some(ref content_language) = input.content_language {
-            request.add_header("Content-Language", &content_language.to_string());
-        }
+    /// <p>Deletes an inventory configuration (identified by the inventory ID) from the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref content_type) = input.content_type {
-            request.add_header("Content-Type", &content_type.to_string());
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketInventoryConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref expires) = input.expires {
-            request.add_header("Expires", &expires.to_string());
+        let mut params = Params::new();
+        params.put("id", &input.id);
+        params.put_key("inventory");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBucketInventoryConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the lifecycle configuration from the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketLifecycle");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref grant_full_control) = input.grant_full_control {
-            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+        let mut params = Params::new();
+        params.put_key("lifecycle");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBucketLifecycleError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes a metrics configuration (specified by the metrics configuration ID) from the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketMetricsConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref grant_read) = input.grant_read {
-            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        let mut params = Params::new();
+        params.put("id", &input.id);
+        params.put_key("metrics");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBucketMetricsConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the policy from the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketPolicy");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref grant_read_acp) = input.grant_read_acp {
-            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        let mut params = Params::new();
+        params.put_key("policy");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteBucketPolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p> Deletes the replication configuration from the bucket. For information about replication configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/crr.html">Cross-Region Replication (CRR)</a> in the <i>Amazon S3 Developer Guide</i>. </p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketReplication");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref grant_write_acp) = input.grant_write_acp {
-            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
-        }
+        let mut params = Params::new();
+        params.put_key("replication");
+        request.set_params(params);
 
-        if let Some(ref metadata) = input.metadata {
-            for (header_name, header_value) in metadata.iter() {
-                let header = format!("x-amz-meta-{}", header_name);
-                request.add_header(header, header_value);
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBucketReplicationError::from_response(response))
+                }));
             }
-        }
 
-        if let Some(ref object_lock_legal_hold_status) = input.object_lock_legal_hold_status {
-            request.add_header(
-                "x-amz-object-lock-legal-hold",
-                &object_lock_legal_hold_status.to_string(),
-            );
-        }
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
 
-        if let Some(ref object_lock_mode) = input.object_lock_mode {
-            request.add_header("x-amz-object-lock-mode", &object_lock_mode.to_string());
-        }
+    /// <p>Deletes the tags from the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
-            request.add_header(
-                "x-amz-object-lock-retain-until-date",
-                &object_lock_retain_until_date.to_string(),
-            );
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketTagging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
+        let mut params = Params::new();
+        params.put_key("tagging");
+        request.set_params(params);
 
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
-        }
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteBucketTaggingError::from_response(response))
+                    }),
+                );
+            }
 
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
-        }
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
 
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
+    /// <p>This operation removes the website configuration from the bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketWebsite");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref ssekms_encryption_context) = input.ssekms_encryption_context {
-            request.add_header(
-                "x-amz-server-side-encryption-context",
-                &ssekms_encryption_context.to_string(),
-            );
+        let mut params = Params::new();
+        params.put_key("website");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteBucketWebsiteError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Removes the null version (if there is one) of an object and inserts a delete marker, which becomes the latest version of the object. If there isn't a null version, Amazon S3 does not remove any objects.</p>
+    #[allow(unused_variables, warnings)]
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
         }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
-        if let Some(ref ssekms_key_id) = input.ssekms_key_id {
-            request.add_header(
-                "x-amz-server-side-encryption-aws-kms-key-id",
-                &ssekms_key_id.to_string(),
-            );
+        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref server_side_encryption) = input.server_side_encryption {
+        if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
-                "x-amz-server-side-encryption",
-                &server_side_encryption.to_string(),
+                "x-amz-bypass-governance-retention",
+                &bypass_governance_retention.to_string(),
             );
         }
 
-        if let Some(ref storage_class) = input.storage_class {
-            request.add_header("x-amz-storage-class", &storage_class.to_string());
-        }
-
-        if let Some(ref tagging) = input.tagging {
-            request.add_header("x-amz-tagging", &tagging.to_string());
+        if let Some(ref mfa) = input.mfa {
+            request.add_header("x-amz-mfa", &mfa.to_string());
         }
 
-        if let Some(ref website_redirect_location) = input.website_redirect_location {
-            request.add_header(
-                "x-amz-website-redirect-location",
-                &website_redirect_location.to_string(),
-            );
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
         let mut params = Params::new();
-        params.put_key("uploads");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateMultipartUploadError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteObjectError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = CreateMultipartUploadOutput::default();
+                    result = DeleteObjectOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -19540,123 +23117,141 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = CreateMultipartUploadOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    result =
+                        DeleteObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("DeleteObject", err, &response.body))?;
                 }
-                if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
-                    let value = abort_date.to_owned();
-                    result.abort_date = Some(value)
-                };
-                if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
-                    let value = abort_rule_id.to_owned();
-                    result.abort_rule_id = Some(value)
+                if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
+                    let value = delete_marker.to_owned();
+                    result.delete_marker = Some(value.parse::<bool>().unwrap())
                 };
                 if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
                     let value = request_charged.to_owned();
                     result.request_charged = Some(value)
                 };
-                if let Some(sse_customer_algorithm) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-algorithm")
-                {
-                    let value = sse_customer_algorithm.to_owned();
-                    result.sse_customer_algorithm = Some(value)
-                };
-                if let Some(sse_customer_key_md5) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-key-MD5")
-                {
-                    let value = sse_customer_key_md5.to_owned();
-                    result.sse_customer_key_md5 = Some(value)
-                };
-                if let Some(ssekms_encryption_context) =
-                    response.headers.get("x-amz-server-side-encryption-context")
-                {
-                    let value = ssekms_encryption_context.to_owned();
-                    result.ssekms_encryption_context = Some(value)
-                };
-                if let Some(ssekms_key_id) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-aws-kms-key-id")
-                {
-                    let value = ssekms_key_id.to_owned();
-                    result.ssekms_key_id = Some(value)
-                };
-                if let Some(server_side_encryption) =
-                    response.headers.get("x-amz-server-side-encryption")
-                {
-                    let value = server_side_encryption.to_owned();
-                    result.server_side_encryption = Some(value)
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
                 }; // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Deletes the bucket. All objects (including all object versions and Delete Markers) in the bucket must be deleted before the bucket itself can be deleted.</p>
-    #[allow(unused_variables, warnings)]
-    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBucketError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Deletes an analytics configuration for the bucket (specified by the analytics configuration ID).</p> <p>To use this operation, you must have permissions to perform the s3:PutAnalyticsConfiguration action. The bucket owner has this permission by default. The bucket owner can grant this permission to others. </p>
+    /// <p>Removes the tag-set from an existing object.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_analytics_configuration(
+    fn delete_object_tagging(
         &self,
-        input: DeleteBucketAnalyticsConfigurationRequest,
-    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: DeleteObjectTaggingRequest,
+    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjectTagging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("analytics");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("tagging");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBucketAnalyticsConfigurationError::from_response(
-                        response,
-                    ))
-                }));
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteObjectTaggingError::from_response(response))
+                    }),
+                );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteObjectTaggingOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = DeleteObjectTaggingOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteObjectTagging", err, &response.body))?;
+                }
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Deletes the CORS configuration information set for the bucket.</p>
+    /// <p>This operation enables you to delete multiple objects from a bucket using a single HTTP request. You may specify up to 1000 keys.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_cors(
+    fn delete_objects(
         &self,
-        input: DeleteBucketCorsRequest,
-    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        input: DeleteObjectsRequest,
+    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjects");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
+            request.add_header(
+                "x-amz-bypass-governance-retention",
+                &bypass_governance_retention.to_string(),
+            );
+        }
+
+        if let Some(ref mfa) = input.mfa {
+            request.add_header("x-amz-mfa", &mfa.to_string());
+        }
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("cors");
+        params.put_key("delete");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        DeleteSerializer::serialize(&mut writer, "Delete", &input.delete);
+        request.set_payload(Some(writer.into_inner()));
+        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -19664,32 +23259,62 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DeleteBucketCorsError::from_response(response))),
+                        .and_then(|response| Err(DeleteObjectsError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = DeleteObjectsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        DeleteObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("DeleteObjects", err, &response.body)
+                            })?;
+                }
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Deletes the server-side encryption configuration from the bucket.</p>
+    /// <p>Removes the <code>PublicAccessBlock</code> configuration from an Amazon S3 bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_encryption(
+    fn delete_public_access_block(
         &self,
-        input: DeleteBucketEncryptionRequest,
-    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        input: DeletePublicAccessBlockRequest,
+    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeletePublicAccessBlock");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         let mut params = Params::new();
-        params.put_key("encryption");
+        params.put_key("publicAccessBlock");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBucketEncryptionError::from_response(response))
+                    Err(DeletePublicAccessBlockError::from_response(response))
                 }));
             }
 
@@ -19697,99 +23322,195 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Deletes an inventory configuration (identified by the inventory ID) from the bucket.</p>
+    /// <p>Returns the accelerate configuration of a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_inventory_configuration(
+    fn get_bucket_accelerate_configuration(
         &self,
-        input: DeleteBucketInventoryConfigurationRequest,
-    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
+    {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAccelerateConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("inventory");
+        params.put_key("accelerate");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBucketInventoryConfigurationError::from_response(
+                    Err(GetBucketAccelerateConfigurationError::from_response(
                         response,
                     ))
                 }));
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketAccelerateConfigurationOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetBucketAccelerateConfigurationOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketAccelerateConfiguration", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Deletes the lifecycle configuration from the bucket.</p>
+    /// <p>Gets the access control policy for the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_lifecycle(
+    fn get_bucket_acl(
         &self,
-        input: DeleteBucketLifecycleRequest,
-    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        input: GetBucketAclRequest,
+    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAcl");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("lifecycle");
+        params.put_key("acl");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBucketLifecycleError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBucketAclError::from_response(response))),
+                );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketAclOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        GetBucketAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("GetBucketAcl", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Deletes a metrics configuration (specified by the metrics configuration ID) from the bucket.</p>
+    /// <p>Gets an analytics configuration for the bucket (specified by the analytics configuration ID).</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_metrics_configuration(
+    fn get_bucket_analytics_configuration(
         &self,
-        input: DeleteBucketMetricsConfigurationRequest,
-    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
+    {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAnalyticsConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
         params.put("id", &input.id);
-        params.put_key("metrics");
+        params.put_key("analytics");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBucketMetricsConfigurationError::from_response(
+                    Err(GetBucketAnalyticsConfigurationError::from_response(
                         response,
                     ))
                 }));
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketAnalyticsConfigurationOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetBucketAnalyticsConfigurationOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketAnalyticsConfiguration", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Deletes the policy from the bucket.</p>
+    /// <p>Returns the CORS configuration for the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_policy(
+    fn get_bucket_cors(
         &self,
-        input: DeleteBucketPolicyRequest,
-    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        input: GetBucketCorsRequest,
+    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketCors");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("policy");
+        params.put_key("cors");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -19798,121 +23519,168 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DeleteBucketPolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p> Deletes the replication configuration from the bucket. For information about replication configuration, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/crr.html">Cross-Region Replication (CRR)</a> in the <i>Amazon S3 Developer Guide</i>. </p>
-    #[allow(unused_variables, warnings)]
-    fn delete_bucket_replication(
-        &self,
-        input: DeleteBucketReplicationRequest,
-    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put_key("replication");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBucketReplicationError::from_response(response))
-                }));
+                        .and_then(|response| Err(GetBucketCorsError::from_response(response))),
+                );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketCorsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        GetBucketCorsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("GetBucketCors", err, &response.body)
+                            })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Deletes the tags from the bucket.</p>
+    /// <p>Returns the server-side encryption configuration of a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_tagging(
+    fn get_bucket_encryption(
         &self,
-        input: DeleteBucketTaggingRequest,
-    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        input: GetBucketEncryptionRequest,
+    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketEncryption");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("tagging");
+        params.put_key("encryption");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
                     response.buffer().from_err().and_then(|response| {
-                        Err(DeleteBucketTaggingError::from_response(response))
+                        Err(GetBucketEncryptionError::from_response(response))
                     }),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketEncryptionOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetBucketEncryptionOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketEncryption", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>This operation removes the website configuration from the bucket.</p>
+    /// <p>Returns an inventory configuration (identified by the inventory ID) from the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_bucket_website(
+    fn get_bucket_inventory_configuration(
         &self,
-        input: DeleteBucketWebsiteRequest,
-    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
+    {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketInventoryConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("website");
+        params.put("id", &input.id);
+        params.put_key("inventory");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteBucketWebsiteError::from_response(response))
-                    }),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBucketInventoryConfigurationError::from_response(
+                        response,
+                    ))
+                }));
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketInventoryConfigurationOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetBucketInventoryConfigurationOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketInventoryConfiguration", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Removes the null version (if there is one) of an object and inserts a delete marker, which becomes the latest version of the object. If there isn't a null version, Amazon S3 does not remove any objects.</p>
+    /// <p> No longer used, see the GetBucketLifecycleConfiguration operation.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_object(
+    fn get_bucket_lifecycle(
         &self,
-        input: DeleteObjectRequest,
-    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
-
-        if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
-            request.add_header(
-                "x-amz-bypass-governance-retention",
-                &bypass_governance_retention.to_string(),
-            );
-        }
+        input: GetBucketLifecycleRequest,
+    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref mfa) = input.mfa {
-            request.add_header("x-amz-mfa", &mfa.to_string());
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycle");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
+        params.put_key("lifecycle");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -19921,7 +23689,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DeleteObjectError::from_response(response))),
+                        .and_then(|response| Err(GetBucketLifecycleError::from_response(response))),
                 );
             }
 
@@ -19929,8 +23697,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = DeleteObjectOutput::default();
+                    result = GetBucketLifecycleOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -19938,58 +23707,55 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        DeleteObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+                    result = GetBucketLifecycleOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketLifecycle", err, &response.body))?;
                 }
-                if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
-                    let value = delete_marker.to_owned();
-                    result.delete_marker = Some(value.parse::<bool>().unwrap())
-                };
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                };
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
-                }; // parse non-payload
+                // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Removes the tag-set from an existing object.</p>
+    /// <p>Returns the lifecycle configuration information set on the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_object_tagging(
+    fn get_bucket_lifecycle_configuration(
         &self,
-        input: DeleteObjectTaggingRequest,
-    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
+    {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycleConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        params.put_key("tagging");
+        params.put_key("lifecycle");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteObjectTaggingError::from_response(response))
-                    }),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBucketLifecycleConfigurationError::from_response(
+                        response,
+                    ))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = DeleteObjectTaggingOutput::default();
+                    result = GetBucketLifecycleConfigurationOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -19997,51 +23763,39 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = DeleteObjectTaggingOutputDeserializer::deserialize(
+                    result = GetBucketLifecycleConfigurationOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketLifecycleConfiguration", err, &response.body)
+                    })?;
                 }
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
-                }; // parse non-payload
+                // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>This operation enables you to delete multiple objects from a bucket using a single HTTP request. You may specify up to 1000 keys.</p>
+    /// <p>Returns the region the bucket resides in.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_objects(
+    fn get_bucket_location(
         &self,
-        input: DeleteObjectsRequest,
-    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        input: GetBucketLocationRequest,
+    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
-
-        if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
-            request.add_header(
-                "x-amz-bypass-governance-retention",
-                &bypass_governance_retention.to_string(),
-            );
-        }
-
-        if let Some(ref mfa) = input.mfa {
-            request.add_header("x-amz-mfa", &mfa.to_string());
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLocation");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
         let mut params = Params::new();
-        params.put_key("delete");
+        params.put_key("location");
         request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        DeleteSerializer::serialize(&mut writer, "Delete", &input.delete);
-        request.set_payload(Some(writer.into_inner()));
-        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -20049,7 +23803,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DeleteObjectsError::from_response(response))),
+                        .and_then(|response| Err(GetBucketLocationError::from_response(response))),
                 );
             }
 
@@ -20057,8 +23811,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = DeleteObjectsOutput::default();
+                    result = GetBucketLocationOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20066,64 +23821,99 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        DeleteObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+                    result = GetBucketLocationOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketLocation", err, &response.body))?;
                 }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
+                // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Removes the <code>PublicAccessBlock</code> configuration from an Amazon S3 bucket.</p>
+    /// <p>Returns the logging status of a bucket and the permissions users have to view and modify that status. To use GET, you must be the bucket owner.</p>
     #[allow(unused_variables, warnings)]
-    fn delete_public_access_block(
+    fn get_bucket_logging(
         &self,
-        input: DeletePublicAccessBlockRequest,
-    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
+        input: GetBucketLoggingRequest,
+    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLogging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("publicAccessBlock");
+        params.put_key("logging");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeletePublicAccessBlockError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBucketLoggingError::from_response(response))),
+                );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = GetBucketLoggingOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = GetBucketLoggingOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketLogging", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Returns the accelerate configuration of a bucket.</p>
+    /// <p>Gets a metrics configuration (specified by the metrics configuration ID) from the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_accelerate_configuration(
+    fn get_bucket_metrics_configuration(
         &self,
-        input: GetBucketAccelerateConfigurationRequest,
-    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
-    {
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketMetricsConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("accelerate");
+        params.put("id", &input.id);
+        params.put_key("metrics");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketAccelerateConfigurationError::from_response(
-                        response,
-                    ))
+                    Err(GetBucketMetricsConfigurationError::from_response(response))
                 }));
             }
 
@@ -20131,8 +23921,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketAccelerateConfigurationOutput::default();
+                    result = GetBucketMetricsConfigurationOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20140,10 +23931,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketAccelerateConfigurationOutputDeserializer::deserialize(
+                    result = GetBucketMetricsConfigurationOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketMetricsConfiguration", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20151,36 +23945,40 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Gets the access control policy for the bucket.</p>
+    /// <p> No longer used, see the GetBucketNotificationConfiguration operation.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_acl(
+    fn get_bucket_notification(
         &self,
-        input: GetBucketAclRequest,
-    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotification");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("acl");
+        params.put_key("notification");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBucketAclError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBucketNotificationError::from_response(response))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketAclOutput::default();
+                    result = NotificationConfigurationDeprecated::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20188,8 +23986,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        GetBucketAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+                    result = NotificationConfigurationDeprecatedDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketNotification", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20197,26 +24000,30 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Gets an analytics configuration for the bucket (specified by the analytics configuration ID).</p>
+    /// <p>Returns the notification configuration of a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_analytics_configuration(
+    fn get_bucket_notification_configuration(
         &self,
-        input: GetBucketAnalyticsConfigurationRequest,
-    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
-    {
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotificationConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("analytics");
+        params.put_key("notification");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketAnalyticsConfigurationError::from_response(
+                    Err(GetBucketNotificationConfigurationError::from_response(
                         response,
                     ))
                 }));
@@ -20226,8 +24033,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketAnalyticsConfigurationOutput::default();
+                    result = NotificationConfiguration::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20235,10 +24043,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketAnalyticsConfigurationOutputDeserializer::deserialize(
+                    result = NotificationConfigurationDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketNotificationConfiguration", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20246,18 +24057,23 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the CORS configuration for the bucket.</p>
+    /// <p>Returns the policy of a specified bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_cors(
+    fn get_bucket_policy(
         &self,
-        input: GetBucketCorsRequest,
-    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
+        input: GetBucketPolicyRequest,
+    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicy");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         let mut params = Params::new();
-        params.put_key("cors");
+        params.put_key("policy");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -20266,61 +24082,53 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetBucketCorsError::from_response(response))),
+                        .and_then(|response| Err(GetBucketPolicyError::from_response(response))),
                 );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
+            Box::new(response.buffer().from_err().map(move |response| {
+                let mut result = GetBucketPolicyOutput::default();
+                result.policy = Some(String::from_utf8_lossy(response.body.as_ref()).into());
 
-                if response.body.is_empty() {
-                    result = GetBucketCorsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        GetBucketCorsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
+                result
             }))
         })
     }
 
-    /// <p>Returns the server-side encryption configuration of a bucket.</p>
+    /// <p>Retrieves the policy status for an Amazon S3 bucket, indicating whether the bucket is public.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_encryption(
+    fn get_bucket_policy_status(
         &self,
-        input: GetBucketEncryptionRequest,
-    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
+        input: GetBucketPolicyStatusRequest,
+    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicyStatus");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("encryption");
+        params.put_key("policyStatus");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetBucketEncryptionError::from_response(response))
-                    }),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBucketPolicyStatusError::from_response(response))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketEncryptionOutput::default();
+                    result = GetBucketPolicyStatusOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20328,10 +24136,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketEncryptionOutputDeserializer::deserialize(
+                    result = GetBucketPolicyStatusOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketPolicyStatus", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20339,37 +24150,42 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns an inventory configuration (identified by the inventory ID) from the bucket.</p>
+    /// <p><p>Returns the replication configuration of a bucket.</p> <note> <p> It can take a while to propagate the put or delete a replication configuration to all Amazon S3 systems. Therefore, a get request soon after put or delete can return a wrong result. </p> </note></p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_inventory_configuration(
+    fn get_bucket_replication(
         &self,
-        input: GetBucketInventoryConfigurationRequest,
-    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
-    {
+        input: GetBucketReplicationRequest,
+    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketReplication");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("inventory");
+        params.put_key("replication");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketInventoryConfigurationError::from_response(
-                        response,
-                    ))
-                }));
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetBucketReplicationError::from_response(response))
+                    }),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketInventoryConfigurationOutput::default();
+                    result = GetBucketReplicationOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20377,10 +24193,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketInventoryConfigurationOutputDeserializer::deserialize(
+                    result = GetBucketReplicationOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketReplication", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20388,36 +24207,40 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p> No longer used, see the GetBucketLifecycleConfiguration operation.</p>
+    /// <p>Returns the request payment configuration of a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_lifecycle(
+    fn get_bucket_request_payment(
         &self,
-        input: GetBucketLifecycleRequest,
-    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        input: GetBucketRequestPaymentRequest,
+    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketRequestPayment");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("lifecycle");
+        params.put_key("requestPayment");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBucketLifecycleError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetBucketRequestPaymentError::from_response(response))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketLifecycleOutput::default();
+                    result = GetBucketRequestPaymentOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20425,10 +24248,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketLifecycleOutputDeserializer::deserialize(
+                    result = GetBucketRequestPaymentOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetBucketRequestPayment", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20436,36 +24262,43 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the lifecycle configuration information set on the bucket.</p>
+    /// <p>Returns the tag set associated with the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_lifecycle_configuration(
+    fn get_bucket_tagging(
         &self,
-        input: GetBucketLifecycleConfigurationRequest,
-    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
-    {
+        input: GetBucketTaggingRequest,
+    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketTagging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("lifecycle");
+        params.put_key("tagging");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketLifecycleConfigurationError::from_response(
-                        response,
-                    ))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetBucketTaggingError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketLifecycleConfigurationOutput::default();
+                    result = GetBucketTaggingOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20473,10 +24306,11 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketLifecycleConfigurationOutputDeserializer::deserialize(
+                    result = GetBucketTaggingOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketTagging", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20484,27 +24318,32 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the region the bucket resides in.</p>
+    /// <p>Returns the versioning state of a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_location(
+    fn get_bucket_versioning(
         &self,
-        input: GetBucketLocationRequest,
-    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
+        input: GetBucketVersioningRequest,
+    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketVersioning");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("location");
+        params.put_key("versioning");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBucketLocationError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetBucketVersioningError::from_response(response))
+                    }),
                 );
             }
 
@@ -20512,8 +24351,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketLocationOutput::default();
+                    result = GetBucketVersioningOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20521,10 +24361,11 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketLocationOutputDeserializer::deserialize(
+                    result = GetBucketVersioningOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketVersioning", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20532,18 +24373,24 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the logging status of a bucket and the permissions users have to view and modify that status. To use GET, you must be the bucket owner.</p>
+    /// <p>Returns the website configuration for a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_logging(
-        &self,
-        input: GetBucketLoggingRequest,
-    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
+    fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketWebsite");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("logging");
+        params.put_key("website");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -20552,7 +24399,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetBucketLoggingError::from_response(response))),
+                        .and_then(|response| Err(GetBucketWebsiteError::from_response(response))),
                 );
             }
 
@@ -20560,8 +24407,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketLoggingOutput::default();
+                    result = GetBucketWebsiteOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20569,10 +24417,11 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketLoggingOutputDeserializer::deserialize(
+                    result = GetBucketWebsiteOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetBucketWebsite", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20580,34 +24429,303 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Gets a metrics configuration (specified by the metrics configuration ID) from the bucket.</p>
+    /// <p>Retrieves objects from Amazon S3.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_metrics_configuration(
+    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObject");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        if let Some(ref if_modified_since) = input.if_modified_since {
+            request.add_header("If-Modified-Since", &if_modified_since.to_string());
+        }
+
+        if let Some(ref if_none_match) = input.if_none_match {
+            request.add_header("If-None-Match", &if_none_match.to_string());
+        }
+
+        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
+            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
+        }
+
+        if let Some(ref range) = input.range {
+            request.add_header("Range", &range.to_string());
+        }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.part_number {
+            params.put("partNumber", x);
+        }
+        if let Some(ref x) = input.response_cache_control {
+            params.put("response-cache-control", x);
+        }
+        if let Some(ref x) = input.response_content_disposition {
+            params.put("response-content-disposition", x);
+        }
+        if let Some(ref x) = input.response_content_encoding {
+            params.put("response-content-encoding", x);
+        }
+        if let Some(ref x) = input.response_content_language {
+            params.put("response-content-language", x);
+        }
+        if let Some(ref x) = input.response_content_type {
+            params.put("response-content-type", x);
+        }
+        if let Some(ref x) = input.response_expires {
+            params.put("response-expires", x);
+        }
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetObjectError::from_response(response))),
+                );
+            }
+
+            let mut result = GetObjectOutput::default();
+            result.body = Some(response.body);
+            if let Some(accept_ranges) = response.headers.get("accept-ranges") {
+                let value = accept_ranges.to_owned();
+                result.accept_ranges = Some(value)
+            };
+            if let Some(cache_control) = response.headers.get("Cache-Control") {
+                let value = cache_control.to_owned();
+                result.cache_control = Some(value)
+            };
+            if let Some(content_disposition) = response.headers.get("Content-Disposition") {
+                let value = content_disposition.to_owned();
+                result.content_disposition = Some(value)
+            };
+            if let Some(content_encoding) = response.headers.get("Content-Encoding") {
+                let value = content_encoding.to_owned();
+                result.content_encoding = Some(value)
+            };
+            if let Some(content_language) = response.headers.get("Content-Language") {
+                let value = content_language.to_owned();
+                result.content_language = Some(value)
+            };
+            if let Some(content_length) = response.headers.get("Content-Length") {
+                let value = content_length.to_owned();
+                result.content_length = Some(value.parse::<i64>().unwrap())
+            };
+            if let Some(content_range) = response.headers.get("Content-Range") {
+                let value = content_range.to_owned();
+                result.content_range = Some(value)
+            };
+            if let Some(content_type) = response.headers.get("Content-Type") {
+                let value = content_type.to_owned();
+                result.content_type = Some(value)
+            };
+            if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
+                let value = delete_marker.to_owned();
+                result.delete_marker = Some(value.parse::<bool>().unwrap())
+            };
+            if let Some(e_tag) = response.headers.get("ETag") {
+                let value = e_tag.to_owned();
+                result.e_tag = Some(value)
+            };
+            if let Some(expiration) = response.headers.get("x-amz-expiration") {
+                let value = expiration.to_owned();
+                result.expiration = Some(value)
+            };
+            if let Some(expires) = response.headers.get("Expires") {
+                let value = expires.to_owned();
+                result.expires = Some(value)
+            };
+            if let Some(last_modified) = response.headers.get("Last-Modified") {
+                let value = last_modified.to_owned();
+                result.last_modified = Some(value)
+            };
+            result.metadata = Some(rusoto_core::proto::headers::prefixed(
+                &response.headers,
+                "x-amz-meta-",
+            ));
+            if let Some(missing_meta) = response.headers.get("x-amz-missing-meta") {
+                let value = missing_meta.to_owned();
+                result.missing_meta = Some(value.parse::<i64>().unwrap())
+            };
+            if let Some(object_lock_legal_hold_status) =
+                response.headers.get("x-amz-object-lock-legal-hold")
+            {
+                let value = object_lock_legal_hold_status.to_owned();
+                result.object_lock_legal_hold_status = Some(value)
+            };
+            if let Some(object_lock_mode) = response.headers.get("x-amz-object-lock-mode") {
+                let value = object_lock_mode.to_owned();
+                result.object_lock_mode = Some(value)
+            };
+            if let Some(object_lock_retain_until_date) =
+                response.headers.get("x-amz-object-lock-retain-until-date")
+            {
+                let value = object_lock_retain_until_date.to_owned();
+                result.object_lock_retain_until_date = Some(value)
+            };
+            if let Some(parts_count) = response.headers.get("x-amz-mp-parts-count") {
+                let value = parts_count.to_owned();
+                result.parts_count = Some(value.parse::<i64>().unwrap())
+            };
+            if let Some(replication_status) = response.headers.get("x-amz-replication-status") {
+                let value = replication_status.to_owned();
+                result.replication_status = Some(value)
+            };
+            if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                let value = request_charged.to_owned();
+                result.request_charged = Some(value)
+            };
+            if let Some(restore) = response.headers.get("x-amz-restore") {
+                let value = restore.to_owned();
+                result.restore = Some(value)
+            };
+            if let Some(sse_customer_algorithm) = response
+                .headers
+                .get("x-amz-server-side-encryption-customer-algorithm")
+            {
+                let value = sse_customer_algorithm.to_owned();
+                result.sse_customer_algorithm = Some(value)
+            };
+            if let Some(sse_customer_key_md5) = response
+                .headers
+                .get("x-amz-server-side-encryption-customer-key-MD5")
+            {
+                let value = sse_customer_key_md5.to_owned();
+                result.sse_customer_key_md5 = Some(value)
+            };
+            if let Some(ssekms_key_id) = response
+                .headers
+                .get("x-amz-server-side-encryption-aws-kms-key-id")
+            {
+                let value = ssekms_key_id.to_owned();
+                result.ssekms_key_id = Some(value)
+            };
+            if let Some(server_side_encryption) =
+                response.headers.get("x-amz-server-side-encryption")
+            {
+                let value = server_side_encryption.to_owned();
+                result.server_side_encryption = Some(value)
+            };
+            if let Some(storage_class) = response.headers.get("x-amz-storage-class") {
+                let value = storage_class.to_owned();
+                result.storage_class = Some(value)
+            };
+            if let Some(tag_count) = response.headers.get("x-amz-tagging-count") {
+                let value = tag_count.to_owned();
+                result.tag_count = Some(value.parse::<i64>().unwrap())
+            };
+            if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                let value = version_id.to_owned();
+                result.version_id = Some(value)
+            };
+            if let Some(website_redirect_location) =
+                response.headers.get("x-amz-website-redirect-location")
+            {
+                let value = website_redirect_location.to_owned();
+                result.website_redirect_location = Some(value)
+            };
+            Box::new(future::ok(result))
+        })
+    }
+
+    /// <p>Returns the access control list (ACL) of an object.</p>
+    #[allow(unused_variables, warnings)]
+    fn get_object_acl(
         &self,
-        input: GetBucketMetricsConfigurationRequest,
-    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: GetObjectAclRequest,
+    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectAcl");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("metrics");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("acl");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketMetricsConfigurationError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetObjectAclError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketMetricsConfigurationOutput::default();
+                    result = GetObjectAclOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20615,44 +24733,71 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketMetricsConfigurationOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    result =
+                        GetObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("GetObjectAcl", err, &response.body))?;
                 }
-                // parse non-payload
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                }; // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p> No longer used, see the GetBucketNotificationConfiguration operation.</p>
+    /// <p>Gets an object's current Legal Hold status.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_notification(
+    fn get_object_legal_hold(
         &self,
-        input: GetBucketNotificationConfigurationRequest,
-    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: GetObjectLegalHoldRequest,
+    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLegalHold");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("notification");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("legal-hold");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketNotificationError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetObjectLegalHoldError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = NotificationConfigurationDeprecated::default();
+                    result = GetObjectLegalHoldOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20660,10 +24805,11 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = NotificationConfigurationDeprecatedDeserializer::deserialize(
+                    result = GetObjectLegalHoldOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetObjectLegalHold", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20671,26 +24817,30 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the notification configuration of a bucket.</p>
+    /// <p>Gets the object lock configuration for a bucket. The rule specified in the object lock configuration will be applied by default to every new object placed in the specified bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_notification_configuration(
+    fn get_object_lock_configuration(
         &self,
-        input: GetBucketNotificationConfigurationRequest,
-    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
+        input: GetObjectLockConfigurationRequest,
+    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLockConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("notification");
+        params.put_key("object-lock");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketNotificationConfigurationError::from_response(
-                        response,
-                    ))
+                    Err(GetObjectLockConfigurationError::from_response(response))
                 }));
             }
 
@@ -20698,8 +24848,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = NotificationConfiguration::default();
+                    result = GetObjectLockConfigurationOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20707,10 +24858,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = NotificationConfigurationDeserializer::deserialize(
+                    result = GetObjectLockConfigurationOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetObjectLockConfiguration", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20718,18 +24872,39 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the policy of a specified bucket.</p>
+    /// <p>Retrieves an object's retention settings.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_policy(
+    fn get_object_retention(
         &self,
-        input: GetBucketPolicyRequest,
-    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: GetObjectRetentionRequest,
+    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectRetention");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("policy");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("retention");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -20738,46 +24913,17 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetBucketPolicyError::from_response(response))),
+                        .and_then(|response| Err(GetObjectRetentionError::from_response(response))),
                 );
             }
 
-            Box::new(response.buffer().from_err().map(move |response| {
-                let mut result = GetBucketPolicyOutput::default();
-                result.policy = Some(String::from_utf8_lossy(response.body.as_ref()).into());
-
-                result
-            }))
-        })
-    }
-
-    /// <p>Retrieves the policy status for an Amazon S3 bucket, indicating whether the bucket is public.</p>
-    #[allow(unused_variables, warnings)]
-    fn get_bucket_policy_status(
-        &self,
-        input: GetBucketPolicyStatusRequest,
-    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put_key("policyStatus");
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketPolicyStatusError::from_response(response))
-                }));
-            }
-
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketPolicyStatusOutput::default();
+                    result = GetObjectRetentionOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20785,10 +24931,11 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketPolicyStatusOutputDeserializer::deserialize(
+                    result = GetObjectRetentionOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetObjectRetention", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20796,26 +24943,45 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p><p>Returns the replication configuration of a bucket.</p> <note> <p> It can take a while to propagate the put or delete a replication configuration to all Amazon S3 systems. Therefore, a get request soon after put or delete can return a wrong result. </p> </note></p>
+    /// <p>Returns the tag-set of an object.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_replication(
+    fn get_object_tagging(
         &self,
-        input: GetBucketReplicationRequest,
-    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: GetObjectTaggingRequest,
+    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTagging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("replication");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("tagging");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetBucketReplicationError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetObjectTaggingError::from_response(response))),
                 );
             }
 
@@ -20823,8 +24989,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketReplicationOutput::default();
+                    result = GetObjectTaggingOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20832,83 +24999,98 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketReplicationOutputDeserializer::deserialize(
+                    result = GetObjectTaggingOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetObjectTagging", err, &response.body))?;
                 }
-                // parse non-payload
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
+                }; // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Returns the request payment configuration of a bucket.</p>
+    /// <p>Return torrent files from a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_request_payment(
+    fn get_object_torrent(
         &self,
-        input: GetBucketRequestPaymentRequest,
-    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: GetObjectTorrentRequest,
+    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTorrent");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("requestPayment");
+        params.put_key("torrent");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetBucketRequestPaymentError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetObjectTorrentError::from_response(response))),
+                );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetBucketRequestPaymentOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketRequestPaymentOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            let mut result = GetObjectTorrentOutput::default();
+            result.body = Some(response.body);
+            if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                let value = request_charged.to_owned();
+                result.request_charged = Some(value)
+            };
+            Box::new(future::ok(result))
         })
     }
 
-    /// <p>Returns the tag set associated with the bucket.</p>
+    /// <p>Retrieves the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_tagging(
+    fn get_public_access_block(
         &self,
-        input: GetBucketTaggingRequest,
-    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
+        input: GetPublicAccessBlockRequest,
+    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetPublicAccessBlock");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("tagging");
+        params.put_key("publicAccessBlock");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBucketTaggingError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetPublicAccessBlockError::from_response(response))
+                    }),
                 );
             }
 
@@ -20916,8 +25098,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketTaggingOutput::default();
+                    result = GetPublicAccessBlockOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20925,10 +25108,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketTaggingOutputDeserializer::deserialize(
+                    result = GetPublicAccessBlockOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetPublicAccessBlock", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -20936,26 +25122,116 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the versioning state of a bucket.</p>
+    /// <p>This operation is useful to determine if a bucket exists and you have permission to access it.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_versioning(
-        &self,
-        input: GetBucketVersioningRequest,
-    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
+    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadBucket");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(HeadBucketError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>The HEAD operation retrieves metadata from an object without returning the object itself. This operation is useful if you're only interested in an object's metadata. To use HEAD, you must have READ access to the object.</p>
+    #[allow(unused_variables, warnings)]
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+
+        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadObject");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        if let Some(ref if_modified_since) = input.if_modified_since {
+            request.add_header("If-Modified-Since", &if_modified_since.to_string());
+        }
+
+        if let Some(ref if_none_match) = input.if_none_match {
+            request.add_header("If-None-Match", &if_none_match.to_string());
+        }
+
+        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
+            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
+        }
+
+        if let Some(ref range) = input.range {
+            request.add_header("Range", &range.to_string());
+        }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
 
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
         let mut params = Params::new();
-        params.put_key("versioning");
+        if let Some(ref x) = input.part_number {
+            params.put("partNumber", x);
+        }
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetBucketVersioningError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(HeadObjectError::from_response(response))),
                 );
             }
 
@@ -20963,8 +25239,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketVersioningOutput::default();
+                    result = HeadObjectOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -20972,47 +25249,184 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketVersioningOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    result =
+                        HeadObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("HeadObject", err, &response.body))?;
                 }
-                // parse non-payload
+                if let Some(accept_ranges) = response.headers.get("accept-ranges") {
+                    let value = accept_ranges.to_owned();
+                    result.accept_ranges = Some(value)
+                };
+                if let Some(cache_control) = response.headers.get("Cache-Control") {
+                    let value = cache_control.to_owned();
+                    result.cache_control = Some(value)
+                };
+                if let Some(content_disposition) = response.headers.get("Content-Disposition") {
+                    let value = content_disposition.to_owned();
+                    result.content_disposition = Some(value)
+                };
+                if let Some(content_encoding) = response.headers.get("Content-Encoding") {
+                    let value = content_encoding.to_owned();
+                    result.content_encoding = Some(value)
+                };
+                if let Some(content_language) = response.headers.get("Content-Language") {
+                    let value = content_language.to_owned();
+                    result.content_language = Some(value)
+                };
+                if let Some(content_length) = response.headers.get("Content-Length") {
+                    let value = content_length.to_owned();
+                    result.content_length = Some(value.parse::<i64>().unwrap())
+                };
+                if let Some(content_type) = response.headers.get("Content-Type") {
+                    let value = content_type.to_owned();
+                    result.content_type = Some(value)
+                };
+                if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
+                    let value = delete_marker.to_owned();
+                    result.delete_marker = Some(value.parse::<bool>().unwrap())
+                };
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(expiration) = response.headers.get("x-amz-expiration") {
+                    let value = expiration.to_owned();
+                    result.expiration = Some(value)
+                };
+                if let Some(expires) = response.headers.get("Expires") {
+                    let value = expires.to_owned();
+                    result.expires = Some(value)
+                };
+                if let Some(last_modified) = response.headers.get("Last-Modified") {
+                    let value = last_modified.to_owned();
+                    result.last_modified = Some(value)
+                };
+                result.metadata = Some(rusoto_core::proto::headers::prefixed(
+                    &response.headers,
+                    "x-amz-meta-",
+                ));
+                if let Some(missing_meta) = response.headers.get("x-amz-missing-meta") {
+                    let value = missing_meta.to_owned();
+                    result.missing_meta = Some(value.parse::<i64>().unwrap())
+                };
+                if let Some(object_lock_legal_hold_status) =
+                    response.headers.get("x-amz-object-lock-legal-hold")
+                {
+                    let value = object_lock_legal_hold_status.to_owned();
+                    result.object_lock_legal_hold_status = Some(value)
+                };
+                if let Some(object_lock_mode) = response.headers.get("x-amz-object-lock-mode") {
+                    let value = object_lock_mode.to_owned();
+                    result.object_lock_mode = Some(value)
+                };
+                if let Some(object_lock_retain_until_date) =
+                    response.headers.get("x-amz-object-lock-retain-until-date")
+                {
+                    let value = object_lock_retain_until_date.to_owned();
+                    result.object_lock_retain_until_date = Some(value)
+                };
+                if let Some(parts_count) = response.headers.get("x-amz-mp-parts-count") {
+                    let value = parts_count.to_owned();
+                    result.parts_count = Some(value.parse::<i64>().unwrap())
+                };
+                if let Some(replication_status) = response.headers.get("x-amz-replication-status") {
+                    let value = replication_status.to_owned();
+                    result.replication_status = Some(value)
+                };
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                };
+                if let Some(restore) = response.headers.get("x-amz-restore") {
+                    let value = restore.to_owned();
+                    result.restore = Some(value)
+                };
+                if let Some(sse_customer_algorithm) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-algorithm")
+                {
+                    let value = sse_customer_algorithm.to_owned();
+                    result.sse_customer_algorithm = Some(value)
+                };
+                if let Some(sse_customer_key_md5) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-key-MD5")
+                {
+                    let value = sse_customer_key_md5.to_owned();
+                    result.sse_customer_key_md5 = Some(value)
+                };
+                if let Some(ssekms_key_id) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-aws-kms-key-id")
+                {
+                    let value = ssekms_key_id.to_owned();
+                    result.ssekms_key_id = Some(value)
+                };
+                if let Some(server_side_encryption) =
+                    response.headers.get("x-amz-server-side-encryption")
+                {
+                    let value = server_side_encryption.to_owned();
+                    result.server_side_encryption = Some(value)
+                };
+                if let Some(storage_class) = response.headers.get("x-amz-storage-class") {
+                    let value = storage_class.to_owned();
+                    result.storage_class = Some(value)
+                };
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
+                };
+                if let Some(website_redirect_location) =
+                    response.headers.get("x-amz-website-redirect-location")
+                {
+                    let value = website_redirect_location.to_owned();
+                    result.website_redirect_location = Some(value)
+                }; // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Returns the website configuration for a bucket.</p>
+    /// <p>Lists the analytics configurations for the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_bucket_website(
+    fn list_bucket_analytics_configurations(
         &self,
-        input: GetBucketWebsiteRequest,
-    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
+    {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketAnalyticsConfigurations");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         let mut params = Params::new();
-        params.put_key("website");
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
+        }
+        params.put_key("analytics");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetBucketWebsiteError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListBucketAnalyticsConfigurationsError::from_response(
+                        response,
+                    ))
+                }));
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetBucketWebsiteOutput::default();
+                    result = ListBucketAnalyticsConfigurationsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -21020,10 +25434,13 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetBucketWebsiteOutputDeserializer::deserialize(
+                    result = ListBucketAnalyticsConfigurationsOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListBucketAnalyticsConfigurations", err, &response.body)
+                    })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -21031,270 +25448,144 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Retrieves objects from Amazon S3.</p>
+    /// <p>Returns a list of inventory configurations for the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+    fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
+    {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        if let Some(ref if_modified_since) = input.if_modified_since {
-            request.add_header("If-Modified-Since", &if_modified_since.to_string());
-        }
-
-        if let Some(ref if_none_match) = input.if_none_match {
-            request.add_header("If-None-Match", &if_none_match.to_string());
-        }
-
-        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
-            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
-        }
-
-        if let Some(ref range) = input.range {
-            request.add_header("Range", &range.to_string());
+        request.set_operation("ListBucketInventoryConfigurations");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
-
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
-        }
-
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
-        }
-
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
-        }
         let mut params = Params::new();
-        if let Some(ref x) = input.part_number {
-            params.put("partNumber", x);
-        }
-        if let Some(ref x) = input.response_cache_control {
-            params.put("response-cache-control", x);
-        }
-        if let Some(ref x) = input.response_content_disposition {
-            params.put("response-content-disposition", x);
-        }
-        if let Some(ref x) = input.response_content_encoding {
-            params.put("response-content-encoding", x);
-        }
-        if let Some(ref x) = input.response_content_language {
-            params.put("response-content-language", x);
-        }
-        if let Some(ref x) = input.response_content_type {
-            params.put("response-content-type", x);
-        }
-        if let Some(ref x) = input.response_expires {
-            params.put("response-expires", x);
-        }
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
         }
+        params.put_key("inventory");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetObjectError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListBucketInventoryConfigurationsError::from_response(
+                        response,
+                    ))
+                }));
             }
 
-            let mut result = GetObjectOutput::default();
-            result.body = Some(response.body);
-            if let Some(accept_ranges) = response.headers.get("accept-ranges") {
-                let value = accept_ranges.to_owned();
-                result.accept_ranges = Some(value)
-            };
-            if let Some(cache_control) = response.headers.get("Cache-Control") {
-                let value = cache_control.to_owned();
-                result.cache_control = Some(value)
-            };
-            if let Some(content_disposition) = response.headers.get("Content-Disposition") {
-                let value = content_disposition.to_owned();
-                result.content_disposition = Some(value)
-            };
-            if let Some(content_encoding) = response.headers.get("Content-Encoding") {
-                let value = content_encoding.to_owned();
-                result.content_encoding = Some(value)
-            };
-            if let Some(content_language) = response.headers.get("Content-Language") {
-                let value = content_language.to_owned();
-                result.content_language = Some(value)
-            };
-            if let Some(content_length) = response.headers.get("Content-Length") {
-                let value = content_length.to_owned();
-                result.content_length = Some(value.parse::<i64>().unwrap())
-            };
-            if let Some(content_range) = response.headers.get("Content-Range") {
-                let value = content_range.to_owned();
-                result.content_range = Some(value)
-            };
-            if let Some(content_type) = response.headers.get("Content-Type") {
-                let value = content_type.to_owned();
-                result.content_type = Some(value)
-            };
-            if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
-                let value = delete_marker.to_owned();
-                result.delete_marker = Some(value.parse::<bool>().unwrap())
-            };
-            if let Some(e_tag) = response.headers.get("ETag") {
-                let value = e_tag.to_owned();
-                result.e_tag = Some(value)
-            };
-            if let Some(expiration) = response.headers.get("x-amz-expiration") {
-                let value = expiration.to_owned();
-                result.expiration = Some(value)
-            };
-            if let Some(expires) = response.headers.get("Expires") {
-                let value = expires.to_owned();
-                result.expires = Some(value)
-            };
-            if let Some(last_modified) = response.headers.get("Last-Modified") {
-                let value = last_modified.to_owned();
-                result.last_modified = Some(value)
-            };
-            let mut values = ::std::collections::HashMap::new();
-            for (key, value) in response.headers.iter() {
-                if key.as_str().starts_with("x-amz-meta-") {
-                    values.insert(
-                        key.as_str()["x-amz-meta-".len()..].to_owned(),
-                        value.to_owned(),
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListBucketInventoryConfigurationsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
                     );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListBucketInventoryConfigurationsOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListBucketInventoryConfigurations", err, &response.body)
+                    })?;
                 }
-            }
-            result.metadata = Some(values);
-            if let Some(missing_meta) = response.headers.get("x-amz-missing-meta") {
-                let value = missing_meta.to_owned();
-                result.missing_meta = Some(value.parse::<i64>().unwrap())
-            };
-            if let Some(object_lock_legal_hold_status) =
-                response.headers.get("x-amz-object-lock-legal-hold")
-            {
-                let value = object_lock_legal_hold_status.to_owned();
-                result.object_lock_legal_hold_status = Some(value)
-            };
-            if let Some(object_lock_mode) = response.headers.get("x-amz-object-lock-mode") {
-                let value = object_lock_mode.to_owned();
-                result.object_lock_mode = Some(value)
-            };
-            if let Some(object_lock_retain_until_date) =
-                response.headers.get("x-amz-object-lock-retain-until-date")
-            {
-                let value = object_lock_retain_until_date.to_owned();
-                result.object_lock_retain_until_date = Some(value)
-            };
-            if let Some(parts_count) = response.headers.get("x-amz-mp-parts-count") {
-                let value = parts_count.to_owned();
-                result.parts_count = Some(value.parse::<i64>().unwrap())
-            };
-            if let Some(replication_status) = response.headers.get("x-amz-replication-status") {
-                let value = replication_status.to_owned();
-                result.replication_status = Some(value)
-            };
-            if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                let value = request_charged.to_owned();
-                result.request_charged = Some(value)
-            };
-            if let Some(restore) = response.headers.get("x-amz-restore") {
-                let value = restore.to_owned();
-                result.restore = Some(value)
-            };
-            if let Some(sse_customer_algorithm) = response
-                .headers
-                .get("x-amz-server-side-encryption-customer-algorithm")
-            {
-                let value = sse_customer_algorithm.to_owned();
-                result.sse_customer_algorithm = Some(value)
-            };
-            if let Some(sse_customer_key_md5) = response
-                .headers
-                .get("x-amz-server-side-encryption-customer-key-MD5")
-            {
-                let value = sse_customer_key_md5.to_owned();
-                result.sse_customer_key_md5 = Some(value)
-            };
-            if let Some(ssekms_key_id) = response
-                .headers
-                .get("x-amz-server-side-encryption-aws-kms-key-id")
-            {
-                let value = ssekms_key_id.to_owned();
-                result.ssekms_key_id = Some(value)
-            };
-            if let Some(server_side_encryption) =
-                response.headers.get("x-amz-server-side-encryption")
-            {
-                let value = server_side_encryption.to_owned();
-                result.server_side_encryption = Some(value)
-            };
-            if let Some(storage_class) = response.headers.get("x-amz-storage-class") {
-                let value = storage_class.to_owned();
-                result.storage_class = Some(value)
-            };
-            if let Some(tag_count) = response.headers.get("x-amz-tagging-count") {
-                let value = tag_count.to_owned();
-                result.tag_count = Some(value.parse::<i64>().unwrap())
-            };
-            if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                let value = version_id.to_owned();
-                result.version_id = Some(value)
-            };
-            if let Some(website_redirect_location) =
-                response.headers.get("x-amz-website-redirect-location")
-            {
-                let value = website_redirect_location.to_owned();
-                result.website_redirect_location = Some(value)
-            };
-            Box::new(future::ok(result))
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Returns the access control list (ACL) of an object.</p>
+    /// <p>Lists the metrics configurations for the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object_acl(
-        &self,
-        input: GetObjectAclRequest,
-    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+    fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
+    {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        request.set_operation("ListBucketMetricsConfigurations");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
+
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
         }
-        params.put_key("acl");
+        params.put_key("metrics");
         request.set_params(params);
 
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListBucketMetricsConfigurationsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListBucketMetricsConfigurationsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListBucketMetricsConfigurationsOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListBucketMetricsConfigurations", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of all buckets owned by the authenticated sender of the request.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
+        let request_uri = "/";
+
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBuckets");
+        request.set_accept("application/xml".to_owned());
+
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetObjectAclError::from_response(response))),
+                        .and_then(|response| Err(ListBucketsError::from_response(response))),
                 );
             }
 
@@ -21302,8 +25593,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetObjectAclOutput::default();
+                    result = ListBucketsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -21312,35 +25604,126 @@ impl S3 for S3Client {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     result =
-                        GetObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+                        ListBucketsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("ListBuckets", err, &response.body))?;
                 }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
+                // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Gets an object's current Legal Hold status.</p>
+    /// <p>This operation lists in-progress multipart uploads.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object_legal_hold(
+    fn list_multipart_uploads(
         &self,
-        input: GetObjectLegalHoldRequest,
-    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        input: ListMultipartUploadsRequest,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        let mut params = Params::new();
+        if let Some(ref x) = input.delimiter {
+            params.put("delimiter", x);
+        }
+        if let Some(ref x) = input.encoding_type {
+            params.put("encoding-type", x);
+        }
+        if let Some(ref x) = input.key_marker {
+            params.put("key-marker", x);
+        }
+        if let Some(ref x) = input.max_uploads {
+            params.put("max-uploads", x);
+        }
+        if let Some(ref x) = input.prefix {
+            params.put("prefix", x);
+        }
+        if let Some(ref x) = input.upload_id_marker {
+            params.put("upload-id-marker", x);
+        }
+        params.put_key("uploads");
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListMultipartUploadsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = ListMultipartUploadsOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = ListMultipartUploadsOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListMultipartUploads", err, &response.body)
+                    })?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns metadata about all of the versions of objects in a bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectVersions");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
+
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+        if let Some(ref x) = input.delimiter {
+            params.put("delimiter", x);
         }
-        params.put_key("legal-hold");
+        if let Some(ref x) = input.encoding_type {
+            params.put("encoding-type", x);
+        }
+        if let Some(ref x) = input.key_marker {
+            params.put("key-marker", x);
+        }
+        if let Some(ref x) = input.max_keys {
+            params.put("max-keys", x);
+        }
+        if let Some(ref x) = input.prefix {
+            params.put("prefix", x);
+        }
+        if let Some(ref x) = input.version_id_marker {
+            params.put("version-id-marker", x);
+        }
+        params.put_key("versions");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -21349,7 +25732,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetObjectLegalHoldError::from_response(response))),
+                        .and_then(|response| Err(ListObjectVersionsError::from_response(response))),
                 );
             }
 
@@ -21357,8 +25740,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetObjectLegalHoldOutput::default();
+                    result = ListObjectVersionsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -21366,10 +25750,11 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetObjectLegalHoldOutputDeserializer::deserialize(
+                    result = ListObjectVersionsOutputDeserializer::deserialize(
                         &actual_tag_name,
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ListObjectVersions", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -21377,33 +25762,60 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Gets the object lock configuration for a bucket. The rule specified in the object lock configuration will be applied by default to every new object placed in the specified bucket.</p>
+    /// <p>Returns some or all (up to 1000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object_lock_configuration(
+    fn list_objects(
         &self,
-        input: GetObjectLockConfigurationRequest,
-    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
+        input: ListObjectsRequest,
+    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjects");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("object-lock");
+        if let Some(ref x) = input.delimiter {
+            params.put("delimiter", x);
+        }
+        if let Some(ref x) = input.encoding_type {
+            params.put("encoding-type", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.max_keys {
+            params.put("max-keys", x);
+        }
+        if let Some(ref x) = input.prefix {
+            params.put("prefix", x);
+        }
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetObjectLockConfigurationError::from_response(response))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListObjectsError::from_response(response))),
+                );
             }
 
             Box::new(response.buffer().from_err().and_then(move |response| {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetObjectLockConfigurationOutput::default();
+                    result = ListObjectsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -21411,10 +25823,9 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetObjectLockConfigurationOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    result =
+                        ListObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("ListObjects", err, &response.body))?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -21422,24 +25833,48 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Retrieves an object's retention settings.</p>
+    /// <p>Returns some or all (up to 1000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket. Note: ListObjectsV2 is the revised List Objects API and we recommend you use this revised API for new application development.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object_retention(
+    fn list_objects_v2(
         &self,
-        input: GetObjectRetentionRequest,
-    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        input: ListObjectsV2Request,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectsV2");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
         if let Some(ref request_payer) = input.request_payer {
             request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+        if let Some(ref x) = input.continuation_token {
+            params.put("continuation-token", x);
         }
-        params.put_key("retention");
+        if let Some(ref x) = input.delimiter {
+            params.put("delimiter", x);
+        }
+        if let Some(ref x) = input.encoding_type {
+            params.put("encoding-type", x);
+        }
+        if let Some(ref x) = input.fetch_owner {
+            params.put("fetch-owner", x);
+        }
+        if let Some(ref x) = input.max_keys {
+            params.put("max-keys", x);
+        }
+        if let Some(ref x) = input.prefix {
+            params.put("prefix", x);
+        }
+        if let Some(ref x) = input.start_after {
+            params.put("start-after", x);
+        }
+        params.put("list-type", "2");
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -21448,7 +25883,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetObjectRetentionError::from_response(response))),
+                        .and_then(|response| Err(ListObjectsV2Error::from_response(response))),
                 );
             }
 
@@ -21456,19 +25891,21 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetObjectRetentionOutput::default();
+                    result = ListObjectsV2Output::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
                     );
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetObjectRetentionOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        ListObjectsV2OutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("ListObjectsV2", err, &response.body)
+                            })?;
                 }
                 // parse non-payload
                 Ok(result)
@@ -21476,21 +25913,39 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>Returns the tag-set of an object.</p>
+    /// <p>Lists the parts that have been uploaded for a specific multipart upload.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object_tagging(
-        &self,
-        input: GetObjectTaggingRequest,
-    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListParts");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+        if let Some(ref x) = input.max_parts {
+            params.put("max-parts", x);
         }
-        params.put_key("tagging");
+        if let Some(ref x) = input.part_number_marker {
+            params.put("part-number-marker", x);
+        }
+        params.put("uploadId", &input.upload_id);
         request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
@@ -21499,7 +25954,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetObjectTaggingError::from_response(response))),
+                        .and_then(|response| Err(ListPartsError::from_response(response))),
                 );
             }
 
@@ -21507,8 +25962,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = GetObjectTaggingOutput::default();
+                    result = ListPartsOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -21516,36 +25972,118 @@ impl S3 for S3Client {
                     let mut stack = XmlResponse::new(reader.into_iter().peekable());
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetObjectTaggingOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
+                    result = ListPartsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                        .map_err(|err| stack.parse_error("ListParts", err, &response.body))?;
                 }
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
+                if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
+                    let value = abort_date.to_owned();
+                    result.abort_date = Some(value)
+                };
+                if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
+                    let value = abort_rule_id.to_owned();
+                    result.abort_rule_id = Some(value)
+                };
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
                 }; // parse non-payload
                 Ok(result)
             }))
         })
     }
 
-    /// <p>Return torrent files from a bucket.</p>
+    /// <p>Sets the accelerate configuration of an existing bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn get_object_torrent(
+    fn put_bucket_accelerate_configuration(
         &self,
-        input: GetObjectTorrentRequest,
-    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAccelerateConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        let mut params = Params::new();
+        params.put_key("accelerate");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        AccelerateConfigurationSerializer::serialize(
+            &mut writer,
+            "AccelerateConfiguration",
+            &input.accelerate_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBucketAccelerateConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Sets the permissions on a bucket using access control lists (ACL).</p>
+    #[allow(unused_variables, warnings)]
+    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAcl");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref acl) = input.acl {
+            request.add_header("x-amz-acl", &acl.to_string());
+        }
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
+        }
+
+        if let Some(ref grant_full_control) = input.grant_full_control {
+            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+        }
+
+        if let Some(ref grant_read) = input.grant_read {
+            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        }
+
+        if let Some(ref grant_read_acp) = input.grant_read_acp {
+            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        }
+
+        if let Some(ref grant_write) = input.grant_write {
+            request.add_header("x-amz-grant-write", &grant_write.to_string());
+        }
+
+        if let Some(ref grant_write_acp) = input.grant_write_acp {
+            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
         }
         let mut params = Params::new();
-        params.put_key("torrent");
+        params.put_key("acl");
         request.set_params(params);
+        if input.access_control_policy.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            AccessControlPolicySerializer::serialize(
+                &mut writer,
+                "AccessControlPolicy",
+                input.access_control_policy.as_ref().unwrap(),
+            );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -21553,73 +26091,88 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(GetObjectTorrentError::from_response(response))),
+                        .and_then(|response| Err(PutBucketAclError::from_response(response))),
                 );
             }
 
-            let mut result = GetObjectTorrentOutput::default();
-            result.body = Some(response.body);
-            if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                let value = request_charged.to_owned();
-                result.request_charged = Some(value)
-            };
-            Box::new(future::ok(result))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Retrieves the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket.</p>
+    /// <p>Sets an analytics configuration for the bucket (specified by the analytics configuration ID).</p>
     #[allow(unused_variables, warnings)]
-    fn get_public_access_block(
+    fn put_bucket_analytics_configuration(
         &self,
-        input: GetPublicAccessBlockRequest,
-    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAnalyticsConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         let mut params = Params::new();
-        params.put_key("publicAccessBlock");
+        params.put("id", &input.id);
+        params.put_key("analytics");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        AnalyticsConfigurationSerializer::serialize(
+            &mut writer,
+            "AnalyticsConfiguration",
+            &input.analytics_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetPublicAccessBlockError::from_response(response))
-                    }),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBucketAnalyticsConfigurationError::from_response(
+                        response,
+                    ))
+                }));
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = GetPublicAccessBlockOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = GetPublicAccessBlockOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>This operation is useful to determine if a bucket exists and you have permission to access it.</p>
+    /// <p>Sets the CORS configuration for a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
+    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketCors");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
+        }
+        let mut params = Params::new();
+        params.put_key("cors");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        CORSConfigurationSerializer::serialize(
+            &mut writer,
+            "CORSConfiguration",
+            &input.cors_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
+        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -21627,7 +26180,49 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(HeadBucketError::from_response(response))),
+                        .and_then(|response| Err(PutBucketCorsError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Creates a new server-side encryption configuration (or replaces an existing one, if present).</p>
+    #[allow(unused_variables, warnings)]
+    fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> RusotoFuture<(), PutBucketEncryptionError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketEncryption");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
+        }
+        let mut params = Params::new();
+        params.put_key("encryption");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        ServerSideEncryptionConfigurationSerializer::serialize(
+            &mut writer,
+            "ServerSideEncryptionConfiguration",
+            &input.server_side_encryption_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutBucketEncryptionError::from_response(response))
+                    }),
                 );
             }
 
@@ -21635,658 +26230,487 @@ impl S3 for S3Client {
         })
     }
 
-    /// <p>The HEAD operation retrieves metadata from an object without returning the object itself. This operation is useful if you're only interested in an object's metadata. To use HEAD, you must have READ access to the object.</p>
+    /// <p>Adds an inventory configuration (identified by the inventory ID) from the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn head_object(
+    fn put_bucket_inventory_configuration(
         &self,
-        input: HeadObjectRequest,
-    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
-
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
-
-        if let Some(ref if_modified_since) = input.if_modified_since {
-            request.add_header("If-Modified-Since", &if_modified_since.to_string());
-        }
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref if_none_match) = input.if_none_match {
-            request.add_header("If-None-Match", &if_none_match.to_string());
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketInventoryConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref if_unmodified_since) = input.if_unmodified_since {
-            request.add_header("If-Unmodified-Since", &if_unmodified_since.to_string());
-        }
+        let mut params = Params::new();
+        params.put("id", &input.id);
+        params.put_key("inventory");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        InventoryConfigurationSerializer::serialize(
+            &mut writer,
+            "InventoryConfiguration",
+            &input.inventory_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
-        if let Some(ref range) = input.range {
-            request.add_header("Range", &range.to_string());
-        }
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBucketInventoryConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
 
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
-        }
+    /// <p> No longer used, see the PutBucketLifecycleConfiguration operation.</p>
+    #[allow(unused_variables, warnings)]
+    fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycle");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
 
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
         let mut params = Params::new();
-        if let Some(ref x) = input.part_number {
-            params.put("partNumber", x);
-        }
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
+        params.put_key("lifecycle");
         request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(HeadObjectError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = HeadObjectOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        HeadObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(accept_ranges) = response.headers.get("accept-ranges") {
-                    let value = accept_ranges.to_owned();
-                    result.accept_ranges = Some(value)
-                };
-                if let Some(cache_control) = response.headers.get("Cache-Control") {
-                    let value = cache_control.to_owned();
-                    result.cache_control = Some(value)
-                };
-                if let Some(content_disposition) = response.headers.get("Content-Disposition") {
-                    let value = content_disposition.to_owned();
-                    result.content_disposition = Some(value)
-                };
-                if let Some(content_encoding) = response.headers.get("Content-Encoding") {
-                    let value = content_encoding.to_owned();
-                    result.content_encoding = Some(value)
-                };
-                if let Some(content_language) = response.headers.get("Content-Language") {
-                    let value = content_language.to_owned();
-                    result.content_language = Some(value)
-                };
-                if let Some(content_length) = response.headers.get("Content-Length") {
-                    let value = content_length.to_owned();
-                    result.content_length = Some(value.parse::<i64>().unwrap())
-                };
-                if let Some(content_type) = response.headers.get("Content-Type") {
-                    let value = content_type.to_owned();
-                    result.content_type = Some(value)
-                };
-                if let Some(delete_marker) = response.headers.get("x-amz-delete-marker") {
-                    let value = delete_marker.to_owned();
-                    result.delete_marker = Some(value.parse::<bool>().unwrap())
-                };
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(expiration) = response.headers.get("x-amz-expiration") {
-                    let value = expiration.to_owned();
-                    result.expiration = Some(value)
-                };
-                if let Some(expires) = response.headers.get("Expires") {
-                    let value = expires.to_owned();
-                    result.expires = Some(value)
-                };
-                if let Some(last_modified) = response.headers.get("Last-Modified") {
-                    let value = last_modified.to_owned();
-                    result.last_modified = Some(value)
-                };
-                let mut values = ::std::collections::HashMap::new();
-                for (key, value) in response.headers.iter() {
-                    if key.as_str().starts_with("x-amz-meta-") {
-                        values.insert(
-                            key.as_str()["x-amz-meta-".len()..].to_owned(),
-                            value.to_owned(),
-                        );
-                    }
-                }
-                result.metadata = Some(values);
-                if let Some(missing_meta) = response.headers.get("x-amz-missing-meta") {
-                    let value = missing_meta.to_owned();
-                    result.missing_meta = Some(value.parse::<i64>().unwrap())
-                };
-                if let Some(object_lock_legal_hold_status) =
-                    response.headers.get("x-amz-object-lock-legal-hold")
-                {
-                    let value = object_lock_legal_hold_status.to_owned();
-                    result.object_lock_legal_hold_status = Some(value)
-                };
-                if let Some(object_lock_mode) = response.headers.get("x-amz-object-lock-mode") {
-                    let value = object_lock_mode.to_owned();
-                    result.object_lock_mode = Some(value)
-                };
-                if let Some(object_lock_retain_until_date) =
-                    response.headers.get("x-amz-object-lock-retain-until-date")
-                {
-                    let value = object_lock_retain_until_date.to_owned();
-                    result.object_lock_retain_until_date = Some(value)
-                };
-                if let Some(parts_count) = response.headers.get("x-amz-mp-parts-count") {
-                    let value = parts_count.to_owned();
-                    result.parts_count = Some(value.parse::<i64>().unwrap())
-                };
-                if let Some(replication_status) = response.headers.get("x-amz-replication-status") {
-                    let value = replication_status.to_owned();
-                    result.replication_status = Some(value)
-                };
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                };
-                if let Some(restore) = response.headers.get("x-amz-restore") {
-                    let value = restore.to_owned();
-                    result.restore = Some(value)
-                };
-                if let Some(sse_customer_algorithm) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-algorithm")
-                {
-                    let value = sse_customer_algorithm.to_owned();
-                    result.sse_customer_algorithm = Some(value)
-                };
-                if let Some(sse_customer_key_md5) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-key-MD5")
-                {
-                    let value = sse_customer_key_md5.to_owned();
-                    result.sse_customer_key_md5 = Some(value)
-                };
-                if let Some(ssekms_key_id) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-aws-kms-key-id")
-                {
-                    let value = ssekms_key_id.to_owned();
-                    result.ssekms_key_id = Some(value)
-                };
-                if let Some(server_side_encryption) =
-                    response.headers.get("x-amz-server-side-encryption")
-                {
-                    let value = server_side_encryption.to_owned();
-                    result.server_side_encryption = Some(value)
-                };
-                if let Some(storage_class) = response.headers.get("x-amz-storage-class") {
-                    let value = storage_class.to_owned();
-                    result.storage_class = Some(value)
-                };
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
-                };
-                if let Some(website_redirect_location) =
-                    response.headers.get("x-amz-website-redirect-location")
-                {
-                    let value = website_redirect_location.to_owned();
-                    result.website_redirect_location = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
+        if input.lifecycle_configuration.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            LifecycleConfigurationSerializer::serialize(
+                &mut writer,
+                "LifecycleConfiguration",
+                input.lifecycle_configuration.as_ref().unwrap(),
+            );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
+        request.set_content_md5_header();
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutBucketLifecycleError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Lists the analytics configurations for the bucket.</p>
+    /// <p>Sets lifecycle configuration for your bucket. If a lifecycle configuration exists, it replaces it.</p>
     #[allow(unused_variables, warnings)]
-    fn list_bucket_analytics_configurations(
+    fn put_bucket_lifecycle_configuration(
         &self,
-        input: ListBucketAnalyticsConfigurationsRequest,
-    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
-    {
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycleConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         let mut params = Params::new();
-        if let Some(ref x) = input.continuation_token {
-            params.put("continuation-token", x);
-        }
-        params.put_key("analytics");
+        params.put_key("lifecycle");
         request.set_params(params);
+        if input.lifecycle_configuration.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            BucketLifecycleConfigurationSerializer::serialize(
+                &mut writer,
+                "LifecycleConfiguration",
+                input.lifecycle_configuration.as_ref().unwrap(),
+            );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
+        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListBucketAnalyticsConfigurationsError::from_response(
+                    Err(PutBucketLifecycleConfigurationError::from_response(
                         response,
                     ))
                 }));
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListBucketAnalyticsConfigurationsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListBucketAnalyticsConfigurationsOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Returns a list of inventory configurations for the bucket.</p>
+    /// <p>Set the logging parameters for a bucket and to specify permissions for who can view and modify the logging parameters. To set the logging status of a bucket, you must be the bucket owner.</p>
     #[allow(unused_variables, warnings)]
-    fn list_bucket_inventory_configurations(
+    fn put_bucket_logging(
         &self,
-        input: ListBucketInventoryConfigurationsRequest,
-    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
-    {
+        input: PutBucketLoggingRequest,
+    ) -> RusotoFuture<(), PutBucketLoggingError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLogging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
-        let mut params = Params::new();
-        if let Some(ref x) = input.continuation_token {
-            params.put("continuation-token", x);
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
-        params.put_key("inventory");
+        let mut params = Params::new();
+        params.put_key("logging");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        BucketLoggingStatusSerializer::serialize(
+            &mut writer,
+            "BucketLoggingStatus",
+            &input.bucket_logging_status,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListBucketInventoryConfigurationsError::from_response(
-                        response,
-                    ))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutBucketLoggingError::from_response(response))),
+                );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListBucketInventoryConfigurationsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListBucketInventoryConfigurationsOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Lists the metrics configurations for the bucket.</p>
+    /// <p>Sets a metrics configuration (specified by the metrics configuration ID) for the bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn list_bucket_metrics_configurations(
+    fn put_bucket_metrics_configuration(
         &self,
-        input: ListBucketMetricsConfigurationsRequest,
-    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
-    {
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketMetricsConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         let mut params = Params::new();
-        if let Some(ref x) = input.continuation_token {
-            params.put("continuation-token", x);
-        }
+        params.put("id", &input.id);
         params.put_key("metrics");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        MetricsConfigurationSerializer::serialize(
+            &mut writer,
+            "MetricsConfiguration",
+            &input.metrics_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListBucketMetricsConfigurationsError::from_response(
-                        response,
-                    ))
+                    Err(PutBucketMetricsConfigurationError::from_response(response))
                 }));
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListBucketMetricsConfigurationsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListBucketMetricsConfigurationsOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Returns a list of all buckets owned by the authenticated sender of the request.</p>
+    /// <p> No longer used, see the PutBucketNotificationConfiguration operation.</p>
     #[allow(unused_variables, warnings)]
-    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
-        let request_uri = "/";
+    fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotification");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
+        }
+        let mut params = Params::new();
+        params.put_key("notification");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        NotificationConfigurationDeprecatedSerializer::serialize(
+            &mut writer,
+            "NotificationConfigurationDeprecated",
+            &input.notification_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBucketsError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBucketNotificationError::from_response(response))
+                }));
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
 
-                if response.body.is_empty() {
-                    result = ListBucketsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        ListBucketsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+    /// <p>Enables notifications of specified events for a bucket.</p>
+    #[allow(unused_variables, warnings)]
+    fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
+
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotificationConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        let mut params = Params::new();
+        params.put_key("notification");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        NotificationConfigurationSerializer::serialize(
+            &mut writer,
+            "NotificationConfiguration",
+            &input.notification_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBucketNotificationConfigurationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>This operation lists in-progress multipart uploads.</p>
+    /// <p>Applies an Amazon S3 bucket policy to an Amazon S3 bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn list_multipart_uploads(
+    fn put_bucket_policy(
         &self,
-        input: ListMultipartUploadsRequest,
-    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        input: PutBucketPolicyRequest,
+    ) -> RusotoFuture<(), PutBucketPolicyError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.delimiter {
-            params.put("delimiter", x);
-        }
-        if let Some(ref x) = input.encoding_type {
-            params.put("encoding-type", x);
-        }
-        if let Some(ref x) = input.key_marker {
-            params.put("key-marker", x);
-        }
-        if let Some(ref x) = input.max_uploads {
-            params.put("max-uploads", x);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketPolicy");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
-        if let Some(ref x) = input.prefix {
-            params.put("prefix", x);
+
+        if let Some(ref confirm_remove_self_bucket_access) = input.confirm_remove_self_bucket_access
+        {
+            request.add_header(
+                "x-amz-confirm-remove-self-bucket-access",
+                &confirm_remove_self_bucket_access.to_string(),
+            );
         }
-        if let Some(ref x) = input.upload_id_marker {
-            params.put("upload-id-marker", x);
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
-        params.put_key("uploads");
+        let mut params = Params::new();
+        params.put_key("policy");
         request.set_params(params);
+        request.set_payload(Some(input.policy.into_bytes()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListMultipartUploadsError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutBucketPolicyError::from_response(response))),
                 );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListMultipartUploadsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListMultipartUploadsOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Returns metadata about all of the versions of objects in a bucket.</p>
+    /// <p> Creates a replication configuration or replaces an existing one. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/crr.html">Cross-Region Replication (CRR)</a> in the <i>Amazon S3 Developer Guide</i>. </p>
     #[allow(unused_variables, warnings)]
-    fn list_object_versions(
+    fn put_bucket_replication(
         &self,
-        input: ListObjectVersionsRequest,
-    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        input: PutBucketReplicationRequest,
+    ) -> RusotoFuture<(), PutBucketReplicationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.delimiter {
-            params.put("delimiter", x);
-        }
-        if let Some(ref x) = input.encoding_type {
-            params.put("encoding-type", x);
-        }
-        if let Some(ref x) = input.key_marker {
-            params.put("key-marker", x);
-        }
-        if let Some(ref x) = input.max_keys {
-            params.put("max-keys", x);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketReplication");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
-        if let Some(ref x) = input.prefix {
-            params.put("prefix", x);
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
-        if let Some(ref x) = input.version_id_marker {
-            params.put("version-id-marker", x);
+
+        if let Some(ref token) = input.token {
+            request.add_header("x-amz-bucket-object-lock-token", &token.to_string());
         }
-        params.put_key("versions");
+        let mut params = Params::new();
+        params.put_key("replication");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        ReplicationConfigurationSerializer::serialize(
+            &mut writer,
+            "ReplicationConfiguration",
+            &input.replication_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
+        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListObjectVersionsError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutBucketReplicationError::from_response(response))
+                    }),
                 );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListObjectVersionsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = ListObjectVersionsOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Returns some or all (up to 1000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket.</p>
+    /// <p>Sets the request payment configuration for a bucket. By default, the bucket owner pays for downloads from the bucket. This configuration parameter enables the bucket owner (only) to specify that the person requesting the download will be charged for the download. Documentation on requester pays buckets can be found at http://docs.aws.amazon.com/AmazonS3/latest/dev/RequesterPaysBuckets.html</p>
     #[allow(unused_variables, warnings)]
-    fn list_objects(
+    fn put_bucket_request_payment(
         &self,
-        input: ListObjectsRequest,
-    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
+        input: PutBucketRequestPaymentRequest,
+    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketRequestPayment");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
         let mut params = Params::new();
-        if let Some(ref x) = input.delimiter {
-            params.put("delimiter", x);
-        }
-        if let Some(ref x) = input.encoding_type {
-            params.put("encoding-type", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.max_keys {
-            params.put("max-keys", x);
-        }
-        if let Some(ref x) = input.prefix {
-            params.put("prefix", x);
-        }
+        params.put_key("requestPayment");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        RequestPaymentConfigurationSerializer::serialize(
+            &mut writer,
+            "RequestPaymentConfiguration",
+            &input.request_payment_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListObjectsError::from_response(response))),
-                );
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutBucketRequestPaymentError::from_response(response))
+                }));
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListObjectsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        ListObjectsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Returns some or all (up to 1000) of the objects in a bucket. You can use the request parameters as selection criteria to return a subset of the objects in a bucket. Note: ListObjectsV2 is the revised List Objects API and we recommend you use this revised API for new application development.</p>
+    /// <p>Sets the tags for a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn list_objects_v2(
+    fn put_bucket_tagging(
         &self,
-        input: ListObjectsV2Request,
-    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        input: PutBucketTaggingRequest,
+    ) -> RusotoFuture<(), PutBucketTaggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.continuation_token {
-            params.put("continuation-token", x);
-        }
-        if let Some(ref x) = input.delimiter {
-            params.put("delimiter", x);
-        }
-        if let Some(ref x) = input.encoding_type {
-            params.put("encoding-type", x);
-        }
-        if let Some(ref x) = input.fetch_owner {
-            params.put("fetch-owner", x);
-        }
-        if let Some(ref x) = input.max_keys {
-            params.put("max-keys", x);
-        }
-        if let Some(ref x) = input.prefix {
-            params.put("prefix", x);
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketTagging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
-        if let Some(ref x) = input.start_after {
-            params.put("start-after", x);
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
-        params.put("list-type", "2");
+        let mut params = Params::new();
+        params.put_key("tagging");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
+        request.set_payload(Some(writer.into_inner()));
+        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -22294,176 +26718,272 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(ListObjectsV2Error::from_response(response))),
+                        .and_then(|response| Err(PutBucketTaggingError::from_response(response))),
                 );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListObjectsV2Output::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        ListObjectsV2OutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Lists the parts that have been uploaded for a specific multipart upload.</p>
+    /// <p>Sets the versioning state of an existing bucket. To set the versioning state, you must be the bucket owner.</p>
     #[allow(unused_variables, warnings)]
-    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+    fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> RusotoFuture<(), PutBucketVersioningError> {
+        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketVersioning");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_parts {
-            params.put("max-parts", x);
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
         }
-        if let Some(ref x) = input.part_number_marker {
-            params.put("part-number-marker", x);
+
+        if let Some(ref mfa) = input.mfa {
+            request.add_header("x-amz-mfa", &mfa.to_string());
         }
-        params.put("uploadId", &input.upload_id);
+        let mut params = Params::new();
+        params.put_key("versioning");
         request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        VersioningConfigurationSerializer::serialize(
+            &mut writer,
+            "VersioningConfiguration",
+            &input.versioning_configuration,
+        );
+        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPartsError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutBucketVersioningError::from_response(response))
+                    }),
                 );
             }
 
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = ListPartsOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        ListPartsOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(abort_date) = response.headers.get("x-amz-abort-date") {
-                    let value = abort_date.to_owned();
-                    result.abort_date = Some(value)
-                };
-                if let Some(abort_rule_id) = response.headers.get("x-amz-abort-rule-id") {
-                    let value = abort_rule_id.to_owned();
-                    result.abort_rule_id = Some(value)
-                };
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
+            Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Sets the accelerate configuration of an existing bucket.</p>
+    /// <p>Set the website configuration for a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_accelerate_configuration(
+    fn put_bucket_website(
         &self,
-        input: PutBucketAccelerateConfigurationRequest,
-    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        input: PutBucketWebsiteRequest,
+    ) -> RusotoFuture<(), PutBucketWebsiteError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketWebsite");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("accelerate");
+        params.put_key("website");
         request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        AccelerateConfigurationSerializer::serialize(
+        WebsiteConfigurationSerializer::serialize(
             &mut writer,
-            "AccelerateConfiguration",
-            &input.accelerate_configuration,
+            "WebsiteConfiguration",
+            &input.website_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketAccelerateConfigurationError::from_response(
-                        response,
-                    ))
-                }));
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutBucketWebsiteError::from_response(response))),
+                );
             }
 
             Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Sets the permissions on a bucket using access control lists (ACL).</p>
+    /// <p>Adds an object to a bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObject");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         if let Some(ref acl) = input.acl {
             request.add_header("x-amz-acl", &acl.to_string());
         }
 
+        if let Some(ref cache_control) = input.cache_control {
+            request.add_header("Cache-Control", &cache_control.to_string());
+        }
+
+        if let Some(ref content_disposition) = input.content_disposition {
+            request.add_header("Content-Disposition", &content_disposition.to_string());
+        }
+
+        if let Some(ref content_encoding) = input.content_encoding {
+            request.add_header("Content-Encoding", &content_encoding.to_string());
+        }
+
+        if let Some(ref content_language) = input.content_language {
+            request.add_header("Content-Language", &content_language.to_string());
+        }
+
+        if let Some(ref content_length) = input.content_length {
+            request.add_header("Content-Length", &content_length.to_string());
+        }
+
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
 
+        if let Some(ref content_type) = input.content_type {
+            request.add_header("Content-Type", &content_type.to_string());
+        }
+
+        if let Some(ref expires) = input.expires {
+            request.add_header("Expires", &expires.to_string());
+        }
+
         if let Some(ref grant_full_control) = input.grant_full_control {
             request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
         }
 
-        if let Some(ref grant_read) = input.grant_read {
-            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        if let Some(ref grant_read) = input.grant_read {
+            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        }
+
+        if let Some(ref grant_read_acp) = input.grant_read_acp {
+            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        }
+
+        if let Some(ref grant_write_acp) = input.grant_write_acp {
+            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
+        }
+
+        if let Some(ref metadata) = input.metadata {
+            for (header_name, header_value) in metadata.iter() {
+                let header = format!("x-amz-meta-{}", header_name);
+                request.add_header(header, header_value);
+            }
+        }
+
+        if let Some(ref object_lock_legal_hold_status) = input.object_lock_legal_hold_status {
+            request.add_header(
+                "x-amz-object-lock-legal-hold",
+                &object_lock_legal_hold_status.to_string(),
+            );
+        }
+
+        if let Some(ref object_lock_mode) = input.object_lock_mode {
+            request.add_header("x-amz-object-lock-mode", &object_lock_mode.to_string());
+        }
+
+        if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
+            request.add_header(
+                "x-amz-object-lock-retain-until-date",
+                &object_lock_retain_until_date.to_string(),
+            );
+        }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
+
+        if let Some(ref ssekms_encryption_context) = input.ssekms_encryption_context {
+            request.add_header(
+                "x-amz-server-side-encryption-context",
+                &ssekms_encryption_context.to_string(),
+            );
+        }
+
+        if let Some(ref ssekms_key_id) = input.ssekms_key_id {
+            request.add_header(
+                "x-amz-server-side-encryption-aws-kms-key-id",
+                &ssekms_key_id.to_string(),
+            );
+        }
+
+        if let Some(ref server_side_encryption) = input.server_side_encryption {
+            request.add_header(
+                "x-amz-server-side-encryption",
+                &server_side_encryption.to_string(),
+            );
+        }
+
+        if let Some(ref storage_class) = input.storage_class {
+            request.add_header("x-amz-storage-class", &storage_class.to_string());
         }
 
-        if let Some(ref grant_read_acp) = input.grant_read_acp {
-            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        if let Some(ref tagging) = input.tagging {
+            request.add_header("x-amz-tagging", &tagging.to_string());
         }
 
-        if let Some(ref grant_write) = input.grant_write {
-            request.add_header("x-amz-grant-write", &grant_write.to_string());
+        if let Some(ref website_redirect_location) = input.website_redirect_location {
+            request.add_header(
+                "x-amz-website-redirect-location",
+                &website_redirect_location.to_string(),
+            );
         }
 
-        if let Some(ref grant_write_acp) = input.grant_write_acp {
-            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
-        }
-        let mut params = Params::new();
-        params.put_key("acl");
-        request.set_params(params);
-        if input.access_control_policy.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            AccessControlPolicySerializer::serialize(
-                &mut writer,
-                "AccessControlPolicy",
-                input.access_control_policy.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+        if let Some(__body) = input.body {
+            request.set_payload_stream(__body);
         }
 
         self.client.sign_and_dispatch(request, |response| {
@@ -22472,185 +26992,153 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutBucketAclError::from_response(response))),
+                        .and_then(|response| Err(PutObjectError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Sets an analytics configuration for the bucket (specified by the analytics configuration ID).</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_analytics_configuration(
-        &self,
-        input: PutBucketAnalyticsConfigurationRequest,
-    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("analytics");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        AnalyticsConfigurationSerializer::serialize(
-            &mut writer,
-            "AnalyticsConfiguration",
-            &input.analytics_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketAnalyticsConfigurationError::from_response(
-                        response,
-                    ))
-                }));
-            }
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
 
-            Box::new(future::ok(::std::mem::drop(response)))
+                if response.body.is_empty() {
+                    result = PutObjectOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = PutObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                        .map_err(|err| stack.parse_error("PutObject", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(expiration) = response.headers.get("x-amz-expiration") {
+                    let value = expiration.to_owned();
+                    result.expiration = Some(value)
+                };
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                };
+                if let Some(sse_customer_algorithm) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-algorithm")
+                {
+                    let value = sse_customer_algorithm.to_owned();
+                    result.sse_customer_algorithm = Some(value)
+                };
+                if let Some(sse_customer_key_md5) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-key-MD5")
+                {
+                    let value = sse_customer_key_md5.to_owned();
+                    result.sse_customer_key_md5 = Some(value)
+                };
+                if let Some(ssekms_encryption_context) =
+                    response.headers.get("x-amz-server-side-encryption-context")
+                {
+                    let value = ssekms_encryption_context.to_owned();
+                    result.ssekms_encryption_context = Some(value)
+                };
+                if let Some(ssekms_key_id) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-aws-kms-key-id")
+                {
+                    let value = ssekms_key_id.to_owned();
+                    result.ssekms_key_id = Some(value)
+                };
+                if let Some(server_side_encryption) =
+                    response.headers.get("x-amz-server-side-encryption")
+                {
+                    let value = server_side_encryption.to_owned();
+                    result.server_side_encryption = Some(value)
+                };
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Sets the CORS configuration for a bucket.</p>
+    /// <p>uses the acl subresource to set the access control list (ACL) permissions for an object that already exists in a bucket</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+    fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        request.set_operation("PutObjectAcl");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
-        let mut params = Params::new();
-        params.put_key("cors");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        CORSConfigurationSerializer::serialize(
-            &mut writer,
-            "CORSConfiguration",
-            &input.cors_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
-        request.set_content_md5_header();
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutBucketCorsError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Creates a new server-side encryption configuration (or replaces an existing one, if present).</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_encryption(
-        &self,
-        input: PutBucketEncryptionRequest,
-    ) -> RusotoFuture<(), PutBucketEncryptionError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        if let Some(ref acl) = input.acl {
+            request.add_header("x-amz-acl", &acl.to_string());
+        }
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
-        let mut params = Params::new();
-        params.put_key("encryption");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        ServerSideEncryptionConfigurationSerializer::serialize(
-            &mut writer,
-            "ServerSideEncryptionConfiguration",
-            &input.server_side_encryption_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutBucketEncryptionError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Adds an inventory configuration (identified by the inventory ID) from the bucket.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_inventory_configuration(
-        &self,
-        input: PutBucketInventoryConfigurationRequest,
-    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("inventory");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        InventoryConfigurationSerializer::serialize(
-            &mut writer,
-            "InventoryConfiguration",
-            &input.inventory_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketInventoryConfigurationError::from_response(
-                        response,
-                    ))
-                }));
-            }
+        if let Some(ref grant_full_control) = input.grant_full_control {
+            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+        }
 
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
+        if let Some(ref grant_read) = input.grant_read {
+            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        }
 
-    /// <p> No longer used, see the PutBucketLifecycleConfiguration operation.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_lifecycle(
-        &self,
-        input: PutBucketLifecycleRequest,
-    ) -> RusotoFuture<(), PutBucketLifecycleError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        if let Some(ref grant_read_acp) = input.grant_read_acp {
+            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        }
 
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        if let Some(ref grant_write) = input.grant_write {
+            request.add_header("x-amz-grant-write", &grant_write.to_string());
+        }
 
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        if let Some(ref grant_write_acp) = input.grant_write_acp {
+            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
+        }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
         let mut params = Params::new();
-        params.put_key("lifecycle");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("acl");
         request.set_params(params);
-        if input.lifecycle_configuration.is_some() {
+        if input.access_control_policy.is_some() {
             let mut writer = EventWriter::new(Vec::new());
-            LifecycleConfigurationSerializer::serialize(
+            AccessControlPolicySerializer::serialize(
                 &mut writer,
-                "LifecycleConfiguration",
-                input.lifecycle_configuration.as_ref().unwrap(),
+                "AccessControlPolicy",
+                input.access_control_policy.as_ref().unwrap(),
             );
             request.set_payload(Some(writer.into_inner()));
         } else {
             request.set_payload(Some(Vec::new()));
         }
-        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -22658,76 +27146,85 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutBucketLifecycleError::from_response(response))),
+                        .and_then(|response| Err(PutObjectAclError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = PutObjectAclOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        PutObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("PutObjectAcl", err, &response.body))?;
+                }
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Sets lifecycle configuration for your bucket. If a lifecycle configuration exists, it replaces it.</p>
+    /// <p>Applies a Legal Hold configuration to the specified object.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_lifecycle_configuration(
+    fn put_object_legal_hold(
         &self,
-        input: PutBucketLifecycleConfigurationRequest,
-    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: PutObjectLegalHoldRequest,
+    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLegalHold");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref content_md5) = input.content_md5 {
+            request.add_header("Content-MD5", &content_md5.to_string());
+        }
 
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("lifecycle");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("legal-hold");
         request.set_params(params);
-        if input.lifecycle_configuration.is_some() {
+        if input.legal_hold.is_some() {
             let mut writer = EventWriter::new(Vec::new());
-            BucketLifecycleConfigurationSerializer::serialize(
+            ObjectLockLegalHoldSerializer::serialize(
                 &mut writer,
-                "LifecycleConfiguration",
-                input.lifecycle_configuration.as_ref().unwrap(),
+                "LegalHold",
+                input.legal_hold.as_ref().unwrap(),
             );
             request.set_payload(Some(writer.into_inner()));
         } else {
             request.set_payload(Some(Vec::new()));
         }
-        request.set_content_md5_header();
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketLifecycleConfigurationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Set the logging parameters for a bucket and to specify permissions for who can view and modify the logging parameters. To set the logging status of a bucket, you must be the bucket owner.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_logging(
-        &self,
-        input: PutBucketLoggingRequest,
-    ) -> RusotoFuture<(), PutBucketLoggingError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
-        }
-        let mut params = Params::new();
-        params.put_key("logging");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        BucketLoggingStatusSerializer::serialize(
-            &mut writer,
-            "BucketLoggingStatus",
-            &input.bucket_logging_status,
-        );
-        request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -22735,141 +27232,173 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutBucketLoggingError::from_response(response))),
+                        .and_then(|response| Err(PutObjectLegalHoldError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Sets a metrics configuration (specified by the metrics configuration ID) for the bucket.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_metrics_configuration(
-        &self,
-        input: PutBucketMetricsConfigurationRequest,
-    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        params.put("id", &input.id);
-        params.put_key("metrics");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        MetricsConfigurationSerializer::serialize(
-            &mut writer,
-            "MetricsConfiguration",
-            &input.metrics_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketMetricsConfigurationError::from_response(response))
-                }));
-            }
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
 
-            Box::new(future::ok(::std::mem::drop(response)))
+                if response.body.is_empty() {
+                    result = PutObjectLegalHoldOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = PutObjectLegalHoldOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("PutObjectLegalHold", err, &response.body))?;
+                }
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p> No longer used, see the PutBucketNotificationConfiguration operation.</p>
+    /// <p>Places an object lock configuration on the specified bucket. The rule specified in the object lock configuration will be applied by default to every new object placed in the specified bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_notification(
+    fn put_object_lock_configuration(
         &self,
-        input: PutBucketNotificationRequest,
-    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        input: PutObjectLockConfigurationRequest,
+    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLockConfiguration");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
-        let mut params = Params::new();
-        params.put_key("notification");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        NotificationConfigurationDeprecatedSerializer::serialize(
-            &mut writer,
-            "NotificationConfigurationDeprecated",
-            &input.notification_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketNotificationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Enables notifications of specified events for a bucket.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_bucket_notification_configuration(
-        &self,
-        input: PutBucketNotificationConfigurationRequest,
-    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
 
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
 
+        if let Some(ref token) = input.token {
+            request.add_header("x-amz-bucket-object-lock-token", &token.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("notification");
+        params.put_key("object-lock");
         request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        NotificationConfigurationSerializer::serialize(
-            &mut writer,
-            "NotificationConfiguration",
-            &input.notification_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
+        if input.object_lock_configuration.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            ObjectLockConfigurationSerializer::serialize(
+                &mut writer,
+                "ObjectLockConfiguration",
+                input.object_lock_configuration.as_ref().unwrap(),
+            );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketNotificationConfigurationError::from_response(
-                        response,
-                    ))
+                    Err(PutObjectLockConfigurationError::from_response(response))
                 }));
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = PutObjectLockConfigurationOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = PutObjectLockConfigurationOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("PutObjectLockConfiguration", err, &response.body)
+                    })?;
+                }
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Applies an Amazon S3 bucket policy to an Amazon S3 bucket.</p>
+    /// <p>Places an Object Retention configuration on an object.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_policy(
+    fn put_object_retention(
         &self,
-        input: PutBucketPolicyRequest,
-    ) -> RusotoFuture<(), PutBucketPolicyError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: PutObjectRetentionRequest,
+    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectRetention");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
-        if let Some(ref confirm_remove_self_bucket_access) = input.confirm_remove_self_bucket_access
-        {
+        if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
             request.add_header(
-                "x-amz-confirm-remove-self-bucket-access",
-                &confirm_remove_self_bucket_access.to_string(),
+                "x-amz-bypass-governance-retention",
+                &bypass_governance_retention.to_string(),
             );
         }
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
         let mut params = Params::new();
-        params.put_key("policy");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("retention");
         request.set_params(params);
-        request.set_payload(Some(input.policy.into_bytes()));
+        if input.retention.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            ObjectLockRetentionSerializer::serialize(
+                &mut writer,
+                "Retention",
+                input.retention.as_ref().unwrap(),
+            );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -22877,111 +27406,201 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutBucketPolicyError::from_response(response))),
+                        .and_then(|response| Err(PutObjectRetentionError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = PutObjectRetentionOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = PutObjectRetentionOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("PutObjectRetention", err, &response.body))?;
+                }
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p> Creates a replication configuration or replaces an existing one. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/crr.html">Cross-Region Replication (CRR)</a> in the <i>Amazon S3 Developer Guide</i>. </p>
+    /// <p>Sets the supplied tag-set to an object that already exists in a bucket</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_replication(
+    fn put_object_tagging(
         &self,
-        input: PutBucketReplicationRequest,
-    ) -> RusotoFuture<(), PutBucketReplicationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: PutObjectTaggingRequest,
+    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectTagging");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
-
-        if let Some(ref token) = input.token {
-            request.add_header("x-amz-bucket-object-lock-token", &token.to_string());
-        }
         let mut params = Params::new();
-        params.put_key("replication");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("tagging");
         request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        ReplicationConfigurationSerializer::serialize(
-            &mut writer,
-            "ReplicationConfiguration",
-            &input.replication_configuration,
-        );
+        TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
         request.set_payload(Some(writer.into_inner()));
-        request.set_content_md5_header();
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
                 return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutBucketReplicationError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutObjectTaggingError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = PutObjectTaggingOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = PutObjectTaggingOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("PutObjectTagging", err, &response.body))?;
+                }
+                if let Some(version_id) = response.headers.get("x-amz-version-id") {
+                    let value = version_id.to_owned();
+                    result.version_id = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Sets the request payment configuration for a bucket. By default, the bucket owner pays for downloads from the bucket. This configuration parameter enables the bucket owner (only) to specify that the person requesting the download will be charged for the download. Documentation on requester pays buckets can be found at http://docs.aws.amazon.com/AmazonS3/latest/dev/RequesterPaysBuckets.html</p>
+    /// <p>Creates or modifies the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_request_payment(
+    fn put_public_access_block(
         &self,
-        input: PutBucketRequestPaymentRequest,
-    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
+        input: PutPublicAccessBlockRequest,
+    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutPublicAccessBlock");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
         let mut params = Params::new();
-        params.put_key("requestPayment");
+        params.put_key("publicAccessBlock");
         request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        RequestPaymentConfigurationSerializer::serialize(
+        PublicAccessBlockConfigurationSerializer::serialize(
             &mut writer,
-            "RequestPaymentConfiguration",
-            &input.request_payment_configuration,
+            "PublicAccessBlockConfiguration",
+            &input.public_access_block_configuration,
         );
         request.set_payload(Some(writer.into_inner()));
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutBucketRequestPaymentError::from_response(response))
-                }));
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutPublicAccessBlockError::from_response(response))
+                    }),
+                );
             }
 
             Box::new(future::ok(::std::mem::drop(response)))
         })
     }
 
-    /// <p>Sets the tags for a bucket.</p>
+    /// <p>Restores an archived copy of an object back into Amazon S3</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_tagging(
+    fn restore_object(
         &self,
-        input: PutBucketTaggingRequest,
-    ) -> RusotoFuture<(), PutBucketTaggingError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: RestoreObjectRequest,
+    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("RestoreObject");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
 
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
         }
         let mut params = Params::new();
-        params.put_key("tagging");
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        params.put_key("restore");
         request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
-        request.set_payload(Some(writer.into_inner()));
-        request.set_content_md5_header();
+        if input.restore_request.is_some() {
+            let mut writer = EventWriter::new(Vec::new());
+            RestoreRequestSerializer::serialize(
+                &mut writer,
+                "RestoreRequest",
+                input.restore_request.as_ref().unwrap(),
+            );
+            request.set_payload(Some(writer.into_inner()));
+        } else {
+            request.set_payload(Some(Vec::new()));
+        }
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -22989,39 +27608,99 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutBucketTaggingError::from_response(response))),
+                        .and_then(|response| Err(RestoreObjectError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = RestoreObjectOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        RestoreObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("RestoreObject", err, &response.body)
+                            })?;
+                }
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                };
+                if let Some(restore_output_path) = response.headers.get("x-amz-restore-output-path")
+                {
+                    let value = restore_output_path.to_owned();
+                    result.restore_output_path = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Sets the versioning state of an existing bucket. To set the versioning state, you must be the bucket owner.</p>
+    /// <p>This operation filters the contents of an Amazon S3 object based on a simple Structured Query Language (SQL) statement. In the request, along with the SQL expression, you must also specify a data serialization format (JSON or CSV) of the object. Amazon S3 uses this to parse object data into records, and returns only records that match the specified SQL expression. You must also specify the data serialization format for the response.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_versioning(
+    fn select_object_content(
         &self,
-        input: PutBucketVersioningRequest,
-    ) -> RusotoFuture<(), PutBucketVersioningError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: SelectObjectContentRequest,
+    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("SelectObjectContent");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+        request.set_accept("application/xml".to_owned());
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
 
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
         }
 
-        if let Some(ref mfa) = input.mfa {
-            request.add_header("x-amz-mfa", &mfa.to_string());
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
         }
         let mut params = Params::new();
-        params.put_key("versioning");
+        params.put_key("select");
+        params.put("select-type", "2");
         request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        VersioningConfigurationSerializer::serialize(
+        SelectObjectContentRequestSerializer::serialize(
             &mut writer,
-            "VersioningConfiguration",
-            &input.versioning_configuration,
+            "SelectObjectContentRequest",
+            &input,
+            "http://s3.amazonaws.com/doc/2006-03-01/",
         );
         request.set_payload(Some(writer.into_inner()));
 
@@ -23029,38 +27708,100 @@ impl S3 for S3Client {
             if !response.status.is_success() {
                 return Box::new(
                     response.buffer().from_err().and_then(|response| {
-                        Err(PutBucketVersioningError::from_response(response))
+                        Err(SelectObjectContentError::from_response(response))
                     }),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = SelectObjectContentOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result = SelectObjectContentOutputDeserializer::deserialize(
+                        &actual_tag_name,
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SelectObjectContent", err, &response.body))?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Set the website configuration for a bucket.</p>
+    /// <p>Uploads a part in a multipart upload.</p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p>
     #[allow(unused_variables, warnings)]
-    fn put_bucket_website(
+    fn upload_part(
         &self,
-        input: PutBucketWebsiteRequest,
-    ) -> RusotoFuture<(), PutBucketWebsiteError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
+        input: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPart");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }
+
+        if let Some(ref content_length) = input.content_length {
+            request.add_header("Content-Length", &content_length.to_string());
+        }
 
         if let Some(ref content_md5) = input.content_md5 {
             request.add_header("Content-MD5", &content_md5.to_string());
         }
+
+        if let Some(ref request_payer) = input.request_payer {
+            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        }
+
+        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-algorithm",
+                &sse_customer_algorithm.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key) = input.sse_customer_key {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key",
+                &sse_customer_key.to_string(),
+            );
+        }
+
+        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
+            request.add_header(
+                "x-amz-server-side-encryption-customer-key-MD5",
+                &sse_customer_key_md5.to_string(),
+            );
+        }
         let mut params = Params::new();
-        params.put_key("website");
+        params.put("partNumber", &input.part_number);
+        params.put("uploadId", &input.upload_id);
         request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        WebsiteConfigurationSerializer::serialize(
-            &mut writer,
-            "WebsiteConfiguration",
-            &input.website_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
+        if let Some(__body) = input.body {
+            request.set_payload_stream(__body);
+        }
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -23068,95 +27809,147 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutBucketWebsiteError::from_response(response))),
+                        .and_then(|response| Err(UploadPartError::from_response(response))),
                 );
             }
 
-            Box::new(future::ok(::std::mem::drop(response)))
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let mut result;
+
+                if response.body.is_empty() {
+                    result = UploadPartOutput::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    result =
+                        UploadPartOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| stack.parse_error("UploadPart", err, &response.body))?;
+                }
+                if let Some(e_tag) = response.headers.get("ETag") {
+                    let value = e_tag.to_owned();
+                    result.e_tag = Some(value)
+                };
+                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
+                    let value = request_charged.to_owned();
+                    result.request_charged = Some(value)
+                };
+                if let Some(sse_customer_algorithm) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-algorithm")
+                {
+                    let value = sse_customer_algorithm.to_owned();
+                    result.sse_customer_algorithm = Some(value)
+                };
+                if let Some(sse_customer_key_md5) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-customer-key-MD5")
+                {
+                    let value = sse_customer_key_md5.to_owned();
+                    result.sse_customer_key_md5 = Some(value)
+                };
+                if let Some(ssekms_key_id) = response
+                    .headers
+                    .get("x-amz-server-side-encryption-aws-kms-key-id")
+                {
+                    let value = ssekms_key_id.to_owned();
+                    result.ssekms_key_id = Some(value)
+                };
+                if let Some(server_side_encryption) =
+                    response.headers.get("x-amz-server-side-encryption")
+                {
+                    let value = server_side_encryption.to_owned();
+                    result.server_side_encryption = Some(value)
+                }; // parse non-payload
+                Ok(result)
+            }))
         })
     }
 
-    /// <p>Adds an object to a bucket.</p>
+    /// <p>Uploads a part by copying data from an existing object as data source.</p>
     #[allow(unused_variables, warnings)]
-    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+    fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref acl) = input.acl {
-            request.add_header("x-amz-acl", &acl.to_string());
-        }
-
-        if let Some(ref cache_control) = input.cache_control {
-            request.add_header("Cache-Control", &cache_control.to_string());
-        }
-
-        if let Some(ref content_disposition) = input.content_disposition {
-            request.add_header("Content-Disposition", &content_disposition.to_string());
-        }
-
-        if let Some(ref content_encoding) = input.content_encoding {
-            request.add_header("Content-Encoding", &content_encoding.to_string());
-        }
-
-        if let Some(ref content_language) = input.content_language {
-            request.add_header("Content-Language", &content_language.to_string());
-        }
-
-        if let Some(ref content_length) = input.content_length {
-            request.add_header("Content-Length", &content_length.to_string());
-        }
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
-        }
-
-        if let Some(ref content_type) = input.content_type {
-            request.add_header("Content-Type", &content_type.to_string());
+        request.set_operation("UploadPartCopy");
+        request.add_resource_id("Bucket", &input.bucket.to_string());
+        request.add_resource_id("Key", &input.key.to_string());
+        if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
         }
+        request.set_accept("application/xml".to_owned());
 
-        if let Some(ref expires) = input.expires {
-            request.add_header("Expires", &expires.to_string());
-        }
+        request.add_header("x-amz-copy-source", &input.copy_source);
 
-        if let Some(ref grant_full_control) = input.grant_full_control {
-            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+        if let Some(ref copy_source_if_match) = input.copy_source_if_match {
+            request.add_header(
+                "x-amz-copy-source-if-match",
+                &copy_source_if_match.to_string(),
+            );
         }
 
-        if let Some(ref grant_read) = input.grant_read {
-            request.add_header("x-amz-grant-read", &grant_read.to_string());
+        if let Some(ref copy_source_if_modified_since) = input.copy_source_if_modified_since {
+            request.add_header(
+                "x-amz-copy-source-if-modified-since",
+                &copy_source_if_modified_since.to_string(),
+            );
         }
 
-        if let Some(ref grant_read_acp) = input.grant_read_acp {
-            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+        if let Some(ref copy_source_if_none_match) = input.copy_source_if_none_match {
+            request.add_header(
+                "x-amz-copy-source-if-none-match",
+                &copy_source_if_none_match.to_string(),
+            );
         }
 
-        if let Some(ref grant_write_acp) = input.grant_write_acp {
-            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
+        if let Some(ref copy_source_if_unmodified_since) = input.copy_source_if_unmodified_since {
+            request.add_header(
+                "x-amz-copy-source-if-unmodified-since",
+                &copy_source_if_unmodified_since.to_string(),
+            );
         }
 
-        if let Some(ref metadata) = input.metadata {
-            for (header_name, header_value) in metadata.iter() {
-                let header = format!("x-amz-meta-{}", header_name);
-                request.add_header(header, header_value);
-            }
+        if let Some(ref copy_source_range) = input.copy_source_range {
+            request.add_header("x-amz-copy-source-range", &copy_source_range.to_string());
         }
 
-        if let Some(ref object_lock_legal_hold_status) = input.object_lock_legal_hold_status {
+        if let Some(ref copy_source_sse_customer_algorithm) =
+            input.copy_source_sse_customer_algorithm
+        {
             request.add_header(
-                "x-amz-object-lock-legal-hold",
-                &object_lock_legal_hold_status.to_string(),
+                "x-amz-copy-source-server-side-encryption-customer-algorithm",
+                &copy_source_sse_customer_algorithm.to_string(),
             );
         }
 
-        if let Some(ref object_lock_mode) = input.object_lock_mode {
-            request.add_header("x-amz-object-lock-mode", &object_lock_mode.to_string());
+        if let Some(ref copy_source_sse_customer_key) = input.copy_source_sse_customer_key {
+            request.add_header(
+                "x-amz-copy-source-server-side-encryption-customer-key",
+                &copy_source_sse_customer_key.to_string(),
+            );
         }
 
-        if let Some(ref object_lock_retain_until_date) = input.object_lock_retain_until_date {
+        if let Some(ref copy_source_sse_customer_key_md5) = input.copy_source_sse_customer_key_md5 {
             request.add_header(
-                "x-amz-object-lock-retain-until-date",
-                &object_lock_retain_until_date.to_string(),
+                "x-amz-copy-source-server-side-encryption-customer-key-MD5",
+                &copy_source_sse_customer_key_md5.to_string(),
             );
         }
 
@@ -23184,46 +27977,10 @@ impl S3 for S3Client {
                 &sse_customer_key_md5.to_string(),
             );
         }
-
-        if let Some(ref ssekms_encryption_context) = input.ssekms_encryption_context {
-            request.add_header(
-                "x-amz-server-side-encryption-context",
-                &ssekms_encryption_context.to_string(),
-            );
-        }
-
-        if let Some(ref ssekms_key_id) = input.ssekms_key_id {
-            request.add_header(
-                "x-amz-server-side-encryption-aws-kms-key-id",
-                &ssekms_key_id.to_string(),
-            );
-        }
-
-        if let Some(ref server_side_encryption) = input.server_side_encryption {
-            request.add_header(
-                "x-amz-server-side-encryption",
-                &server_side_encryption.to_string(),
-            );
-        }
-
-        if let Some(ref storage_class) = input.storage_class {
-            request.add_header("x-amz-storage-class", &storage_class.to_string());
-        }
-
-        if let Some(ref tagging) = input.tagging {
-            request.add_header("x-amz-tagging", &tagging.to_string());
-        }
-
-        if let Some(ref website_redirect_location) = input.website_redirect_location {
-            request.add_header(
-                "x-amz-website-redirect-location",
-                &website_redirect_location.to_string(),
-            );
-        }
-
-        if let Some(__body) = input.body {
-            request.set_payload_stream(__body);
-        }
+        let mut params = Params::new();
+        params.put("partNumber", &input.part_number);
+        params.put("uploadId", &input.upload_id);
+        request.set_params(params);
 
         self.client.sign_and_dispatch(request, |response| {
             if !response.status.is_success() {
@@ -23231,7 +27988,7 @@ impl S3 for S3Client {
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(PutObjectError::from_response(response))),
+                        .and_then(|response| Err(UploadPartCopyError::from_response(response))),
                 );
             }
 
@@ -23239,8 +27996,9 @@ impl S3 for S3Client {
                 let mut result;
 
                 if response.body.is_empty() {
-                    result = PutObjectOutput::default();
+                    result = UploadPartCopyOutput::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -23249,15 +28007,16 @@ impl S3 for S3Client {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     result =
-                        PutObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
+                        UploadPartCopyOutputDeserializer::deserialize(&actual_tag_name, &mut stack)
+                            .map_err(|err| {
+                                stack.parse_error("UploadPartCopy", err, &response.body)
+                            })?;
                 }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(expiration) = response.headers.get("x-amz-expiration") {
-                    let value = expiration.to_owned();
-                    result.expiration = Some(value)
+                if let Some(copy_source_version_id) =
+                    response.headers.get("x-amz-copy-source-version-id")
+                {
+                    let value = copy_source_version_id.to_owned();
+                    result.copy_source_version_id = Some(value)
                 };
                 if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
                     let value = request_charged.to_owned();
@@ -23277,12 +28036,6 @@ impl S3 for S3Client {
                     let value = sse_customer_key_md5.to_owned();
                     result.sse_customer_key_md5 = Some(value)
                 };
-                if let Some(ssekms_encryption_context) =
-                    response.headers.get("x-amz-server-side-encryption-context")
-                {
-                    let value = ssekms_encryption_context.to_owned();
-                    result.ssekms_encryption_context = Some(value)
-                };
                 if let Some(ssekms_key_id) = response
                     .headers
                     .get("x-amz-server-side-encryption-aws-kms-key-id")
@@ -23295,855 +28048,2994 @@ impl S3 for S3Client {
                 {
                     let value = server_side_encryption.to_owned();
                     result.server_side_encryption = Some(value)
-                };
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
                 }; // parse non-payload
                 Ok(result)
             }))
         })
     }
+}
 
-    /// <p>uses the acl subresource to set the access control list (ACL) permissions for an object that already exists in a bucket</p>
-    #[allow(unused_variables, warnings)]
-    fn put_object_acl(
+/// A mock of the Amazon S3 API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockS3 {
+    abort_multipart_upload: Option<
+        Box<
+            dyn Fn(
+                    AbortMultipartUploadRequest,
+                ) -> ::std::result::Result<
+                    AbortMultipartUploadOutput,
+                    RusotoError<AbortMultipartUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    complete_multipart_upload: Option<
+        Box<
+            dyn Fn(
+                    CompleteMultipartUploadRequest,
+                ) -> ::std::result::Result<
+                    CompleteMultipartUploadOutput,
+                    RusotoError<CompleteMultipartUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_object: Option<
+        Box<
+            dyn Fn(
+                    CopyObjectRequest,
+                )
+                    -> ::std::result::Result<CopyObjectOutput, RusotoError<CopyObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_bucket: Option<
+        Box<
+            dyn Fn(
+                    CreateBucketRequest,
+                )
+                    -> ::std::result::Result<CreateBucketOutput, RusotoError<CreateBucketError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_multipart_upload: Option<
+        Box<
+            dyn Fn(
+                    CreateMultipartUploadRequest,
+                ) -> ::std::result::Result<
+                    CreateMultipartUploadOutput,
+                    RusotoError<CreateMultipartUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket: Option<
+        Box<
+            dyn Fn(DeleteBucketRequest) -> ::std::result::Result<(), RusotoError<DeleteBucketError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_analytics_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketAnalyticsConfigurationRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<DeleteBucketAnalyticsConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_cors: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketCorsRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteBucketCorsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_encryption: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketEncryptionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketEncryptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_inventory_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketInventoryConfigurationRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<DeleteBucketInventoryConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_lifecycle: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketLifecycleRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketLifecycleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_metrics_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketMetricsConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketMetricsConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketPolicyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_replication: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketReplicationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketReplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_tagging: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketTaggingRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketTaggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bucket_website: Option<
+        Box<
+            dyn Fn(
+                    DeleteBucketWebsiteRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteBucketWebsiteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_object: Option<
+        Box<
+            dyn Fn(
+                    DeleteObjectRequest,
+                )
+                    -> ::std::result::Result<DeleteObjectOutput, RusotoError<DeleteObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_object_tagging: Option<
+        Box<
+            dyn Fn(
+                    DeleteObjectTaggingRequest,
+                ) -> ::std::result::Result<
+                    DeleteObjectTaggingOutput,
+                    RusotoError<DeleteObjectTaggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_objects: Option<
+        Box<
+            dyn Fn(
+                    DeleteObjectsRequest,
+                )
+                    -> ::std::result::Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_public_access_block: Option<
+        Box<
+            dyn Fn(
+                    DeletePublicAccessBlockRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeletePublicAccessBlockError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_accelerate_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetBucketAccelerateConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketAccelerateConfigurationOutput,
+                    RusotoError<GetBucketAccelerateConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_acl: Option<
+        Box<
+            dyn Fn(
+                    GetBucketAclRequest,
+                )
+                    -> ::std::result::Result<GetBucketAclOutput, RusotoError<GetBucketAclError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_analytics_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetBucketAnalyticsConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketAnalyticsConfigurationOutput,
+                    RusotoError<GetBucketAnalyticsConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_cors: Option<
+        Box<
+            dyn Fn(
+                    GetBucketCorsRequest,
+                )
+                    -> ::std::result::Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_encryption: Option<
+        Box<
+            dyn Fn(
+                    GetBucketEncryptionRequest,
+                ) -> ::std::result::Result<
+                    GetBucketEncryptionOutput,
+                    RusotoError<GetBucketEncryptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_inventory_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetBucketInventoryConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketInventoryConfigurationOutput,
+                    RusotoError<GetBucketInventoryConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_lifecycle: Option<
+        Box<
+            dyn Fn(
+                    GetBucketLifecycleRequest,
+                ) -> ::std::result::Result<
+                    GetBucketLifecycleOutput,
+                    RusotoError<GetBucketLifecycleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_lifecycle_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetBucketLifecycleConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketLifecycleConfigurationOutput,
+                    RusotoError<GetBucketLifecycleConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_location: Option<
+        Box<
+            dyn Fn(
+                    GetBucketLocationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketLocationOutput,
+                    RusotoError<GetBucketLocationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_logging: Option<
+        Box<
+            dyn Fn(
+                    GetBucketLoggingRequest,
+                ) -> ::std::result::Result<
+                    GetBucketLoggingOutput,
+                    RusotoError<GetBucketLoggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_metrics_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetBucketMetricsConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketMetricsConfigurationOutput,
+                    RusotoError<GetBucketMetricsConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_notification: Option<
+        Box<
+            dyn Fn(
+                    GetBucketNotificationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    NotificationConfigurationDeprecated,
+                    RusotoError<GetBucketNotificationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_notification_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetBucketNotificationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    NotificationConfiguration,
+                    RusotoError<GetBucketNotificationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_policy: Option<
+        Box<
+            dyn Fn(
+                    GetBucketPolicyRequest,
+                ) -> ::std::result::Result<
+                    GetBucketPolicyOutput,
+                    RusotoError<GetBucketPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_policy_status: Option<
+        Box<
+            dyn Fn(
+                    GetBucketPolicyStatusRequest,
+                ) -> ::std::result::Result<
+                    GetBucketPolicyStatusOutput,
+                    RusotoError<GetBucketPolicyStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_replication: Option<
+        Box<
+            dyn Fn(
+                    GetBucketReplicationRequest,
+                ) -> ::std::result::Result<
+                    GetBucketReplicationOutput,
+                    RusotoError<GetBucketReplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_request_payment: Option<
+        Box<
+            dyn Fn(
+                    GetBucketRequestPaymentRequest,
+                ) -> ::std::result::Result<
+                    GetBucketRequestPaymentOutput,
+                    RusotoError<GetBucketRequestPaymentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_tagging: Option<
+        Box<
+            dyn Fn(
+                    GetBucketTaggingRequest,
+                ) -> ::std::result::Result<
+                    GetBucketTaggingOutput,
+                    RusotoError<GetBucketTaggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_versioning: Option<
+        Box<
+            dyn Fn(
+                    GetBucketVersioningRequest,
+                ) -> ::std::result::Result<
+                    GetBucketVersioningOutput,
+                    RusotoError<GetBucketVersioningError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_bucket_website: Option<
+        Box<
+            dyn Fn(
+                    GetBucketWebsiteRequest,
+                ) -> ::std::result::Result<
+                    GetBucketWebsiteOutput,
+                    RusotoError<GetBucketWebsiteError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object: Option<
+        Box<
+            dyn Fn(
+                    GetObjectRequest,
+                )
+                    -> ::std::result::Result<GetObjectOutput, RusotoError<GetObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_object_acl: Option<
+        Box<
+            dyn Fn(
+                    GetObjectAclRequest,
+                )
+                    -> ::std::result::Result<GetObjectAclOutput, RusotoError<GetObjectAclError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_object_legal_hold: Option<
+        Box<
+            dyn Fn(
+                    GetObjectLegalHoldRequest,
+                ) -> ::std::result::Result<
+                    GetObjectLegalHoldOutput,
+                    RusotoError<GetObjectLegalHoldError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object_lock_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetObjectLockConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetObjectLockConfigurationOutput,
+                    RusotoError<GetObjectLockConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object_retention: Option<
+        Box<
+            dyn Fn(
+                    GetObjectRetentionRequest,
+                ) -> ::std::result::Result<
+                    GetObjectRetentionOutput,
+                    RusotoError<GetObjectRetentionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object_tagging: Option<
+        Box<
+            dyn Fn(
+                    GetObjectTaggingRequest,
+                ) -> ::std::result::Result<
+                    GetObjectTaggingOutput,
+                    RusotoError<GetObjectTaggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_object_torrent: Option<
+        Box<
+            dyn Fn(
+                    GetObjectTorrentRequest,
+                ) -> ::std::result::Result<
+                    GetObjectTorrentOutput,
+                    RusotoError<GetObjectTorrentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_public_access_block: Option<
+        Box<
+            dyn Fn(
+                    GetPublicAccessBlockRequest,
+                ) -> ::std::result::Result<
+                    GetPublicAccessBlockOutput,
+                    RusotoError<GetPublicAccessBlockError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    head_bucket: Option<
+        Box<
+            dyn Fn(HeadBucketRequest) -> ::std::result::Result<(), RusotoError<HeadBucketError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    head_object: Option<
+        Box<
+            dyn Fn(
+                    HeadObjectRequest,
+                )
+                    -> ::std::result::Result<HeadObjectOutput, RusotoError<HeadObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_bucket_analytics_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListBucketAnalyticsConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListBucketAnalyticsConfigurationsOutput,
+                    RusotoError<ListBucketAnalyticsConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_bucket_inventory_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListBucketInventoryConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListBucketInventoryConfigurationsOutput,
+                    RusotoError<ListBucketInventoryConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_bucket_metrics_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListBucketMetricsConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListBucketMetricsConfigurationsOutput,
+                    RusotoError<ListBucketMetricsConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_buckets: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<ListBucketsOutput, RusotoError<ListBucketsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_multipart_uploads: Option<
+        Box<
+            dyn Fn(
+                    ListMultipartUploadsRequest,
+                ) -> ::std::result::Result<
+                    ListMultipartUploadsOutput,
+                    RusotoError<ListMultipartUploadsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_object_versions: Option<
+        Box<
+            dyn Fn(
+                    ListObjectVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListObjectVersionsOutput,
+                    RusotoError<ListObjectVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_objects: Option<
+        Box<
+            dyn Fn(
+                    ListObjectsRequest,
+                )
+                    -> ::std::result::Result<ListObjectsOutput, RusotoError<ListObjectsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_objects_v2: Option<
+        Box<
+            dyn Fn(
+                    ListObjectsV2Request,
+                )
+                    -> ::std::result::Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_parts: Option<
+        Box<
+            dyn Fn(
+                    ListPartsRequest,
+                )
+                    -> ::std::result::Result<ListPartsOutput, RusotoError<ListPartsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_accelerate_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBucketAccelerateConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketAccelerateConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_acl: Option<
+        Box<
+            dyn Fn(PutBucketAclRequest) -> ::std::result::Result<(), RusotoError<PutBucketAclError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_analytics_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBucketAnalyticsConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketAnalyticsConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_cors: Option<
+        Box<
+            dyn Fn(
+                    PutBucketCorsRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutBucketCorsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_encryption: Option<
+        Box<
+            dyn Fn(
+                    PutBucketEncryptionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketEncryptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_inventory_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBucketInventoryConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketInventoryConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_lifecycle: Option<
+        Box<
+            dyn Fn(
+                    PutBucketLifecycleRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketLifecycleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_lifecycle_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBucketLifecycleConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketLifecycleConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_logging: Option<
+        Box<
+            dyn Fn(
+                    PutBucketLoggingRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutBucketLoggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_metrics_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBucketMetricsConfigurationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketMetricsConfigurationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_notification: Option<
+        Box<
+            dyn Fn(
+                    PutBucketNotificationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketNotificationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_notification_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutBucketNotificationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<PutBucketNotificationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_policy: Option<
+        Box<
+            dyn Fn(
+                    PutBucketPolicyRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutBucketPolicyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_replication: Option<
+        Box<
+            dyn Fn(
+                    PutBucketReplicationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketReplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_request_payment: Option<
+        Box<
+            dyn Fn(
+                    PutBucketRequestPaymentRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketRequestPaymentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_tagging: Option<
+        Box<
+            dyn Fn(
+                    PutBucketTaggingRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutBucketTaggingError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_versioning: Option<
+        Box<
+            dyn Fn(
+                    PutBucketVersioningRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutBucketVersioningError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_bucket_website: Option<
+        Box<
+            dyn Fn(
+                    PutBucketWebsiteRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutBucketWebsiteError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_object: Option<
+        Box<
+            dyn Fn(
+                    PutObjectRequest,
+                )
+                    -> ::std::result::Result<PutObjectOutput, RusotoError<PutObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_object_acl: Option<
+        Box<
+            dyn Fn(
+                    PutObjectAclRequest,
+                )
+                    -> ::std::result::Result<PutObjectAclOutput, RusotoError<PutObjectAclError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_object_legal_hold: Option<
+        Box<
+            dyn Fn(
+                    PutObjectLegalHoldRequest,
+                ) -> ::std::result::Result<
+                    PutObjectLegalHoldOutput,
+                    RusotoError<PutObjectLegalHoldError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_object_lock_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutObjectLockConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutObjectLockConfigurationOutput,
+                    RusotoError<PutObjectLockConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_object_retention: Option<
+        Box<
+            dyn Fn(
+                    PutObjectRetentionRequest,
+                ) -> ::std::result::Result<
+                    PutObjectRetentionOutput,
+                    RusotoError<PutObjectRetentionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_object_tagging: Option<
+        Box<
+            dyn Fn(
+                    PutObjectTaggingRequest,
+                ) -> ::std::result::Result<
+                    PutObjectTaggingOutput,
+                    RusotoError<PutObjectTaggingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_public_access_block: Option<
+        Box<
+            dyn Fn(
+                    PutPublicAccessBlockRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<PutPublicAccessBlockError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    restore_object: Option<
+        Box<
+            dyn Fn(
+                    RestoreObjectRequest,
+                )
+                    -> ::std::result::Result<RestoreObjectOutput, RusotoError<RestoreObjectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    select_object_content: Option<
+        Box<
+            dyn Fn(
+                    SelectObjectContentRequest,
+                ) -> ::std::result::Result<
+                    SelectObjectContentOutput,
+                    RusotoError<SelectObjectContentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    upload_part: Option<
+        Box<
+            dyn Fn(
+                    UploadPartRequest,
+                )
+                    -> ::std::result::Result<UploadPartOutput, RusotoError<UploadPartError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    upload_part_copy: Option<
+        Box<
+            dyn Fn(
+                    UploadPartCopyRequest,
+                )
+                    -> ::std::result::Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockS3 {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockS3 {
+        Default::default()
+    }
+
+    /// Answers `abort_multipart_upload` with what `handler` returns for its input.
+    pub fn with_abort_multipart_upload<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                AbortMultipartUploadRequest,
+            ) -> ::std::result::Result<
+                AbortMultipartUploadOutput,
+                RusotoError<AbortMultipartUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.abort_multipart_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `complete_multipart_upload` with what `handler` returns for its input.
+    pub fn with_complete_multipart_upload<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                CompleteMultipartUploadRequest,
+            ) -> ::std::result::Result<
+                CompleteMultipartUploadOutput,
+                RusotoError<CompleteMultipartUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.complete_multipart_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_object` with what `handler` returns for its input.
+    pub fn with_copy_object<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                CopyObjectRequest,
+            ) -> ::std::result::Result<CopyObjectOutput, RusotoError<CopyObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_bucket` with what `handler` returns for its input.
+    pub fn with_create_bucket<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                CreateBucketRequest,
+            )
+                -> ::std::result::Result<CreateBucketOutput, RusotoError<CreateBucketError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_bucket = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_multipart_upload` with what `handler` returns for its input.
+    pub fn with_create_multipart_upload<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                CreateMultipartUploadRequest,
+            ) -> ::std::result::Result<
+                CreateMultipartUploadOutput,
+                RusotoError<CreateMultipartUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_multipart_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket` with what `handler` returns for its input.
+    pub fn with_delete_bucket<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(DeleteBucketRequest) -> ::std::result::Result<(), RusotoError<DeleteBucketError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_analytics_configuration` with what `handler` returns for its input.
+    pub fn with_delete_bucket_analytics_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketAnalyticsConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteBucketAnalyticsConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_analytics_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_cors` with what `handler` returns for its input.
+    pub fn with_delete_bucket_cors<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketCorsRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketCorsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_cors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_encryption` with what `handler` returns for its input.
+    pub fn with_delete_bucket_encryption<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketEncryptionRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketEncryptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_inventory_configuration` with what `handler` returns for its input.
+    pub fn with_delete_bucket_inventory_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketInventoryConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteBucketInventoryConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_inventory_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_lifecycle` with what `handler` returns for its input.
+    pub fn with_delete_bucket_lifecycle<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketLifecycleRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketLifecycleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_lifecycle = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_metrics_configuration` with what `handler` returns for its input.
+    pub fn with_delete_bucket_metrics_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketMetricsConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteBucketMetricsConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_metrics_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_policy` with what `handler` returns for its input.
+    pub fn with_delete_bucket_policy<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketPolicyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_replication` with what `handler` returns for its input.
+    pub fn with_delete_bucket_replication<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketReplicationRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketReplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_replication = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_tagging` with what `handler` returns for its input.
+    pub fn with_delete_bucket_tagging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketTaggingRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketTaggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_tagging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bucket_website` with what `handler` returns for its input.
+    pub fn with_delete_bucket_website<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteBucketWebsiteRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteBucketWebsiteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bucket_website = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_object` with what `handler` returns for its input.
+    pub fn with_delete_object<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteObjectRequest,
+            )
+                -> ::std::result::Result<DeleteObjectOutput, RusotoError<DeleteObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_object_tagging` with what `handler` returns for its input.
+    pub fn with_delete_object_tagging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteObjectTaggingRequest,
+            ) -> ::std::result::Result<
+                DeleteObjectTaggingOutput,
+                RusotoError<DeleteObjectTaggingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_object_tagging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_objects` with what `handler` returns for its input.
+    pub fn with_delete_objects<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeleteObjectsRequest,
+            )
+                -> ::std::result::Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_objects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_public_access_block` with what `handler` returns for its input.
+    pub fn with_delete_public_access_block<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                DeletePublicAccessBlockRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeletePublicAccessBlockError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_public_access_block = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_accelerate_configuration` with what `handler` returns for its input.
+    pub fn with_get_bucket_accelerate_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketAccelerateConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetBucketAccelerateConfigurationOutput,
+                RusotoError<GetBucketAccelerateConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_accelerate_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_acl` with what `handler` returns for its input.
+    pub fn with_get_bucket_acl<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketAclRequest,
+            )
+                -> ::std::result::Result<GetBucketAclOutput, RusotoError<GetBucketAclError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_analytics_configuration` with what `handler` returns for its input.
+    pub fn with_get_bucket_analytics_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketAnalyticsConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetBucketAnalyticsConfigurationOutput,
+                RusotoError<GetBucketAnalyticsConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_analytics_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_cors` with what `handler` returns for its input.
+    pub fn with_get_bucket_cors<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketCorsRequest,
+            )
+                -> ::std::result::Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_cors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_encryption` with what `handler` returns for its input.
+    pub fn with_get_bucket_encryption<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketEncryptionRequest,
+            ) -> ::std::result::Result<
+                GetBucketEncryptionOutput,
+                RusotoError<GetBucketEncryptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_inventory_configuration` with what `handler` returns for its input.
+    pub fn with_get_bucket_inventory_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketInventoryConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetBucketInventoryConfigurationOutput,
+                RusotoError<GetBucketInventoryConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_inventory_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_lifecycle` with what `handler` returns for its input.
+    pub fn with_get_bucket_lifecycle<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketLifecycleRequest,
+            ) -> ::std::result::Result<
+                GetBucketLifecycleOutput,
+                RusotoError<GetBucketLifecycleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_lifecycle = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_lifecycle_configuration` with what `handler` returns for its input.
+    pub fn with_get_bucket_lifecycle_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketLifecycleConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetBucketLifecycleConfigurationOutput,
+                RusotoError<GetBucketLifecycleConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_lifecycle_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_location` with what `handler` returns for its input.
+    pub fn with_get_bucket_location<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketLocationRequest,
+            ) -> ::std::result::Result<
+                GetBucketLocationOutput,
+                RusotoError<GetBucketLocationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_location = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_logging` with what `handler` returns for its input.
+    pub fn with_get_bucket_logging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketLoggingRequest,
+            )
+                -> ::std::result::Result<GetBucketLoggingOutput, RusotoError<GetBucketLoggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_metrics_configuration` with what `handler` returns for its input.
+    pub fn with_get_bucket_metrics_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketMetricsConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetBucketMetricsConfigurationOutput,
+                RusotoError<GetBucketMetricsConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_metrics_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_notification` with what `handler` returns for its input.
+    pub fn with_get_bucket_notification<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketNotificationConfigurationRequest,
+            ) -> ::std::result::Result<
+                NotificationConfigurationDeprecated,
+                RusotoError<GetBucketNotificationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_notification_configuration` with what `handler` returns for its input.
+    pub fn with_get_bucket_notification_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketNotificationConfigurationRequest,
+            ) -> ::std::result::Result<
+                NotificationConfiguration,
+                RusotoError<GetBucketNotificationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_notification_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_policy` with what `handler` returns for its input.
+    pub fn with_get_bucket_policy<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketPolicyRequest,
+            )
+                -> ::std::result::Result<GetBucketPolicyOutput, RusotoError<GetBucketPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_policy_status` with what `handler` returns for its input.
+    pub fn with_get_bucket_policy_status<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketPolicyStatusRequest,
+            ) -> ::std::result::Result<
+                GetBucketPolicyStatusOutput,
+                RusotoError<GetBucketPolicyStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_policy_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_replication` with what `handler` returns for its input.
+    pub fn with_get_bucket_replication<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketReplicationRequest,
+            ) -> ::std::result::Result<
+                GetBucketReplicationOutput,
+                RusotoError<GetBucketReplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_replication = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_request_payment` with what `handler` returns for its input.
+    pub fn with_get_bucket_request_payment<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketRequestPaymentRequest,
+            ) -> ::std::result::Result<
+                GetBucketRequestPaymentOutput,
+                RusotoError<GetBucketRequestPaymentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_request_payment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_tagging` with what `handler` returns for its input.
+    pub fn with_get_bucket_tagging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketTaggingRequest,
+            )
+                -> ::std::result::Result<GetBucketTaggingOutput, RusotoError<GetBucketTaggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_tagging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_versioning` with what `handler` returns for its input.
+    pub fn with_get_bucket_versioning<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketVersioningRequest,
+            ) -> ::std::result::Result<
+                GetBucketVersioningOutput,
+                RusotoError<GetBucketVersioningError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_versioning = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_bucket_website` with what `handler` returns for its input.
+    pub fn with_get_bucket_website<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetBucketWebsiteRequest,
+            )
+                -> ::std::result::Result<GetBucketWebsiteOutput, RusotoError<GetBucketWebsiteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_bucket_website = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object` with what `handler` returns for its input.
+    pub fn with_get_object<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectRequest,
+            ) -> ::std::result::Result<GetObjectOutput, RusotoError<GetObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_acl` with what `handler` returns for its input.
+    pub fn with_get_object_acl<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectAclRequest,
+            )
+                -> ::std::result::Result<GetObjectAclOutput, RusotoError<GetObjectAclError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_legal_hold` with what `handler` returns for its input.
+    pub fn with_get_object_legal_hold<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectLegalHoldRequest,
+            ) -> ::std::result::Result<
+                GetObjectLegalHoldOutput,
+                RusotoError<GetObjectLegalHoldError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_legal_hold = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_lock_configuration` with what `handler` returns for its input.
+    pub fn with_get_object_lock_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectLockConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetObjectLockConfigurationOutput,
+                RusotoError<GetObjectLockConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_lock_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_retention` with what `handler` returns for its input.
+    pub fn with_get_object_retention<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectRetentionRequest,
+            ) -> ::std::result::Result<
+                GetObjectRetentionOutput,
+                RusotoError<GetObjectRetentionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_retention = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_tagging` with what `handler` returns for its input.
+    pub fn with_get_object_tagging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectTaggingRequest,
+            )
+                -> ::std::result::Result<GetObjectTaggingOutput, RusotoError<GetObjectTaggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_tagging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_object_torrent` with what `handler` returns for its input.
+    pub fn with_get_object_torrent<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetObjectTorrentRequest,
+            )
+                -> ::std::result::Result<GetObjectTorrentOutput, RusotoError<GetObjectTorrentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_object_torrent = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_public_access_block` with what `handler` returns for its input.
+    pub fn with_get_public_access_block<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                GetPublicAccessBlockRequest,
+            ) -> ::std::result::Result<
+                GetPublicAccessBlockOutput,
+                RusotoError<GetPublicAccessBlockError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_public_access_block = Some(Box::new(handler));
+        self
+    }
+    /// Answers `head_bucket` with what `handler` returns for its input.
+    pub fn with_head_bucket<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(HeadBucketRequest) -> ::std::result::Result<(), RusotoError<HeadBucketError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.head_bucket = Some(Box::new(handler));
+        self
+    }
+    /// Answers `head_object` with what `handler` returns for its input.
+    pub fn with_head_object<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                HeadObjectRequest,
+            ) -> ::std::result::Result<HeadObjectOutput, RusotoError<HeadObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.head_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bucket_analytics_configurations` with what `handler` returns for its input.
+    pub fn with_list_bucket_analytics_configurations<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListBucketAnalyticsConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListBucketAnalyticsConfigurationsOutput,
+                RusotoError<ListBucketAnalyticsConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bucket_analytics_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bucket_inventory_configurations` with what `handler` returns for its input.
+    pub fn with_list_bucket_inventory_configurations<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListBucketInventoryConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListBucketInventoryConfigurationsOutput,
+                RusotoError<ListBucketInventoryConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bucket_inventory_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_bucket_metrics_configurations` with what `handler` returns for its input.
+    pub fn with_list_bucket_metrics_configurations<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListBucketMetricsConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListBucketMetricsConfigurationsOutput,
+                RusotoError<ListBucketMetricsConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_bucket_metrics_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_buckets` with what `handler` returns.
+    pub fn with_list_buckets<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn() -> ::std::result::Result<ListBucketsOutput, RusotoError<ListBucketsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_buckets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_multipart_uploads` with what `handler` returns for its input.
+    pub fn with_list_multipart_uploads<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListMultipartUploadsRequest,
+            ) -> ::std::result::Result<
+                ListMultipartUploadsOutput,
+                RusotoError<ListMultipartUploadsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_multipart_uploads = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_object_versions` with what `handler` returns for its input.
+    pub fn with_list_object_versions<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListObjectVersionsRequest,
+            ) -> ::std::result::Result<
+                ListObjectVersionsOutput,
+                RusotoError<ListObjectVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_object_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_objects` with what `handler` returns for its input.
+    pub fn with_list_objects<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListObjectsRequest,
+            )
+                -> ::std::result::Result<ListObjectsOutput, RusotoError<ListObjectsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_objects = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_objects_v2` with what `handler` returns for its input.
+    pub fn with_list_objects_v2<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListObjectsV2Request,
+            )
+                -> ::std::result::Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_objects_v2 = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_parts` with what `handler` returns for its input.
+    pub fn with_list_parts<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                ListPartsRequest,
+            ) -> ::std::result::Result<ListPartsOutput, RusotoError<ListPartsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_parts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_accelerate_configuration` with what `handler` returns for its input.
+    pub fn with_put_bucket_accelerate_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketAccelerateConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<PutBucketAccelerateConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_accelerate_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_acl` with what `handler` returns for its input.
+    pub fn with_put_bucket_acl<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(PutBucketAclRequest) -> ::std::result::Result<(), RusotoError<PutBucketAclError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_analytics_configuration` with what `handler` returns for its input.
+    pub fn with_put_bucket_analytics_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketAnalyticsConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<PutBucketAnalyticsConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_analytics_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_cors` with what `handler` returns for its input.
+    pub fn with_put_bucket_cors<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(PutBucketCorsRequest) -> ::std::result::Result<(), RusotoError<PutBucketCorsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_cors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_encryption` with what `handler` returns for its input.
+    pub fn with_put_bucket_encryption<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketEncryptionRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketEncryptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_encryption = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_inventory_configuration` with what `handler` returns for its input.
+    pub fn with_put_bucket_inventory_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketInventoryConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<PutBucketInventoryConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_inventory_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_lifecycle` with what `handler` returns for its input.
+    pub fn with_put_bucket_lifecycle<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketLifecycleRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketLifecycleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_lifecycle = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_lifecycle_configuration` with what `handler` returns for its input.
+    pub fn with_put_bucket_lifecycle_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketLifecycleConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<PutBucketLifecycleConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_lifecycle_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_logging` with what `handler` returns for its input.
+    pub fn with_put_bucket_logging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketLoggingRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketLoggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_logging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_metrics_configuration` with what `handler` returns for its input.
+    pub fn with_put_bucket_metrics_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketMetricsConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<PutBucketMetricsConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_metrics_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_notification` with what `handler` returns for its input.
+    pub fn with_put_bucket_notification<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketNotificationRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketNotificationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_notification = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_notification_configuration` with what `handler` returns for its input.
+    pub fn with_put_bucket_notification_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketNotificationConfigurationRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<PutBucketNotificationConfigurationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_notification_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_policy` with what `handler` returns for its input.
+    pub fn with_put_bucket_policy<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketPolicyRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketPolicyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_replication` with what `handler` returns for its input.
+    pub fn with_put_bucket_replication<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketReplicationRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketReplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_replication = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_request_payment` with what `handler` returns for its input.
+    pub fn with_put_bucket_request_payment<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketRequestPaymentRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketRequestPaymentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_request_payment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_tagging` with what `handler` returns for its input.
+    pub fn with_put_bucket_tagging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketTaggingRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketTaggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_tagging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_versioning` with what `handler` returns for its input.
+    pub fn with_put_bucket_versioning<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketVersioningRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketVersioningError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_versioning = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_bucket_website` with what `handler` returns for its input.
+    pub fn with_put_bucket_website<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutBucketWebsiteRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutBucketWebsiteError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_bucket_website = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_object` with what `handler` returns for its input.
+    pub fn with_put_object<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutObjectRequest,
+            ) -> ::std::result::Result<PutObjectOutput, RusotoError<PutObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_object_acl` with what `handler` returns for its input.
+    pub fn with_put_object_acl<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutObjectAclRequest,
+            )
+                -> ::std::result::Result<PutObjectAclOutput, RusotoError<PutObjectAclError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_object_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_object_legal_hold` with what `handler` returns for its input.
+    pub fn with_put_object_legal_hold<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutObjectLegalHoldRequest,
+            ) -> ::std::result::Result<
+                PutObjectLegalHoldOutput,
+                RusotoError<PutObjectLegalHoldError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_object_legal_hold = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_object_lock_configuration` with what `handler` returns for its input.
+    pub fn with_put_object_lock_configuration<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutObjectLockConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutObjectLockConfigurationOutput,
+                RusotoError<PutObjectLockConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_object_lock_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_object_retention` with what `handler` returns for its input.
+    pub fn with_put_object_retention<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutObjectRetentionRequest,
+            ) -> ::std::result::Result<
+                PutObjectRetentionOutput,
+                RusotoError<PutObjectRetentionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_object_retention = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_object_tagging` with what `handler` returns for its input.
+    pub fn with_put_object_tagging<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutObjectTaggingRequest,
+            )
+                -> ::std::result::Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_object_tagging = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_public_access_block` with what `handler` returns for its input.
+    pub fn with_put_public_access_block<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                PutPublicAccessBlockRequest,
+            ) -> ::std::result::Result<(), RusotoError<PutPublicAccessBlockError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_public_access_block = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_object` with what `handler` returns for its input.
+    pub fn with_restore_object<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                RestoreObjectRequest,
+            )
+                -> ::std::result::Result<RestoreObjectOutput, RusotoError<RestoreObjectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_object = Some(Box::new(handler));
+        self
+    }
+    /// Answers `select_object_content` with what `handler` returns for its input.
+    pub fn with_select_object_content<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                SelectObjectContentRequest,
+            ) -> ::std::result::Result<
+                SelectObjectContentOutput,
+                RusotoError<SelectObjectContentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.select_object_content = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upload_part` with what `handler` returns for its input.
+    pub fn with_upload_part<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                UploadPartRequest,
+            ) -> ::std::result::Result<UploadPartOutput, RusotoError<UploadPartError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.upload_part = Some(Box::new(handler));
+        self
+    }
+    /// Answers `upload_part_copy` with what `handler` returns for its input.
+    pub fn with_upload_part_copy<F>(mut self, handler: F) -> MockS3
+    where
+        F: Fn(
+                UploadPartCopyRequest,
+            )
+                -> ::std::result::Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.upload_part_copy = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl S3 for MockS3 {
+    fn abort_multipart_upload(
         &self,
-        input: PutObjectAclRequest,
-    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref acl) = input.acl {
-            request.add_header("x-amz-acl", &acl.to_string());
+        input: AbortMultipartUploadRequest,
+    ) -> RusotoFuture<AbortMultipartUploadOutput, AbortMultipartUploadError> {
+        match self.abort_multipart_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for abort_multipart_upload"),
         }
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> RusotoFuture<CompleteMultipartUploadOutput, CompleteMultipartUploadError> {
+        match self.complete_multipart_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for complete_multipart_upload"),
         }
-
-        if let Some(ref grant_full_control) = input.grant_full_control {
-            request.add_header("x-amz-grant-full-control", &grant_full_control.to_string());
+    }
+    fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> RusotoFuture<CopyObjectOutput, CopyObjectError> {
+        match self.copy_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for copy_object"),
         }
-
-        if let Some(ref grant_read) = input.grant_read {
-            request.add_header("x-amz-grant-read", &grant_read.to_string());
+    }
+    fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> RusotoFuture<CreateBucketOutput, CreateBucketError> {
+        match self.create_bucket {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for create_bucket"),
         }
-
-        if let Some(ref grant_read_acp) = input.grant_read_acp {
-            request.add_header("x-amz-grant-read-acp", &grant_read_acp.to_string());
+    }
+    fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> RusotoFuture<CreateMultipartUploadOutput, CreateMultipartUploadError> {
+        match self.create_multipart_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for create_multipart_upload"),
         }
-
-        if let Some(ref grant_write) = input.grant_write {
-            request.add_header("x-amz-grant-write", &grant_write.to_string());
+    }
+    fn delete_bucket(&self, input: DeleteBucketRequest) -> RusotoFuture<(), DeleteBucketError> {
+        match self.delete_bucket {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket"),
         }
-
-        if let Some(ref grant_write_acp) = input.grant_write_acp {
-            request.add_header("x-amz-grant-write-acp", &grant_write_acp.to_string());
+    }
+    fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketAnalyticsConfigurationError> {
+        match self.delete_bucket_analytics_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_analytics_configuration"),
+        }
+    }
+    fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> RusotoFuture<(), DeleteBucketCorsError> {
+        match self.delete_bucket_cors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_cors"),
+        }
+    }
+    fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> RusotoFuture<(), DeleteBucketEncryptionError> {
+        match self.delete_bucket_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_encryption"),
+        }
+    }
+    fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketInventoryConfigurationError> {
+        match self.delete_bucket_inventory_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_inventory_configuration"),
+        }
+    }
+    fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> RusotoFuture<(), DeleteBucketLifecycleError> {
+        match self.delete_bucket_lifecycle {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_lifecycle"),
+        }
+    }
+    fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteBucketMetricsConfigurationError> {
+        match self.delete_bucket_metrics_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_metrics_configuration"),
+        }
+    }
+    fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> RusotoFuture<(), DeleteBucketPolicyError> {
+        match self.delete_bucket_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_policy"),
+        }
+    }
+    fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> RusotoFuture<(), DeleteBucketReplicationError> {
+        match self.delete_bucket_replication {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_replication"),
+        }
+    }
+    fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> RusotoFuture<(), DeleteBucketTaggingError> {
+        match self.delete_bucket_tagging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_tagging"),
+        }
+    }
+    fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> RusotoFuture<(), DeleteBucketWebsiteError> {
+        match self.delete_bucket_website {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_bucket_website"),
+        }
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectOutput, DeleteObjectError> {
+        match self.delete_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_object"),
+        }
+    }
+    fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> RusotoFuture<DeleteObjectTaggingOutput, DeleteObjectTaggingError> {
+        match self.delete_object_tagging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_object_tagging"),
+        }
+    }
+    fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> RusotoFuture<DeleteObjectsOutput, DeleteObjectsError> {
+        match self.delete_objects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_objects"),
+        }
+    }
+    fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> RusotoFuture<(), DeletePublicAccessBlockError> {
+        match self.delete_public_access_block {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for delete_public_access_block"),
+        }
+    }
+    fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAccelerateConfigurationOutput, GetBucketAccelerateConfigurationError>
+    {
+        match self.get_bucket_accelerate_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_accelerate_configuration"),
+        }
+    }
+    fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> RusotoFuture<GetBucketAclOutput, GetBucketAclError> {
+        match self.get_bucket_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_acl"),
+        }
+    }
+    fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketAnalyticsConfigurationOutput, GetBucketAnalyticsConfigurationError>
+    {
+        match self.get_bucket_analytics_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_analytics_configuration"),
+        }
+    }
+    fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> RusotoFuture<GetBucketCorsOutput, GetBucketCorsError> {
+        match self.get_bucket_cors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_cors"),
+        }
+    }
+    fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> RusotoFuture<GetBucketEncryptionOutput, GetBucketEncryptionError> {
+        match self.get_bucket_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_encryption"),
+        }
+    }
+    fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<GetBucketInventoryConfigurationOutput, GetBucketInventoryConfigurationError>
+    {
+        match self.get_bucket_inventory_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_inventory_configuration"),
+        }
+    }
+    fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> RusotoFuture<GetBucketLifecycleOutput, GetBucketLifecycleError> {
+        match self.get_bucket_lifecycle {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_lifecycle"),
         }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+    }
+    fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<GetBucketLifecycleConfigurationOutput, GetBucketLifecycleConfigurationError>
+    {
+        match self.get_bucket_lifecycle_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_lifecycle_configuration"),
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+    }
+    fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> RusotoFuture<GetBucketLocationOutput, GetBucketLocationError> {
+        match self.get_bucket_location {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_location"),
         }
-        params.put_key("acl");
-        request.set_params(params);
-        if input.access_control_policy.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            AccessControlPolicySerializer::serialize(
-                &mut writer,
-                "AccessControlPolicy",
-                input.access_control_policy.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+    }
+    fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> RusotoFuture<GetBucketLoggingOutput, GetBucketLoggingError> {
+        match self.get_bucket_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_logging"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutObjectAclError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = PutObjectAclOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        PutObjectAclOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Applies a Legal Hold configuration to the specified object.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_object_legal_hold(
+    fn get_bucket_metrics_configuration(
         &self,
-        input: PutObjectLegalHoldRequest,
-    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<GetBucketMetricsConfigurationOutput, GetBucketMetricsConfigurationError> {
+        match self.get_bucket_metrics_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_metrics_configuration"),
         }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+    }
+    fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfigurationDeprecated, GetBucketNotificationError> {
+        match self.get_bucket_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_notification"),
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+    }
+    fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<NotificationConfiguration, GetBucketNotificationConfigurationError> {
+        match self.get_bucket_notification_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_notification_configuration"),
         }
-        params.put_key("legal-hold");
-        request.set_params(params);
-        if input.legal_hold.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            ObjectLockLegalHoldSerializer::serialize(
-                &mut writer,
-                "LegalHold",
-                input.legal_hold.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+    }
+    fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> RusotoFuture<GetBucketPolicyOutput, GetBucketPolicyError> {
+        match self.get_bucket_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_policy"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutObjectLegalHoldError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = PutObjectLegalHoldOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = PutObjectLegalHoldOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Places an object lock configuration on the specified bucket. The rule specified in the object lock configuration will be applied by default to every new object placed in the specified bucket.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_object_lock_configuration(
+    fn get_bucket_policy_status(
         &self,
-        input: PutObjectLockConfigurationRequest,
-    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        input: GetBucketPolicyStatusRequest,
+    ) -> RusotoFuture<GetBucketPolicyStatusOutput, GetBucketPolicyStatusError> {
+        match self.get_bucket_policy_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_policy_status"),
         }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+    }
+    fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> RusotoFuture<GetBucketReplicationOutput, GetBucketReplicationError> {
+        match self.get_bucket_replication {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_replication"),
         }
-
-        if let Some(ref token) = input.token {
-            request.add_header("x-amz-bucket-object-lock-token", &token.to_string());
+    }
+    fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> RusotoFuture<GetBucketRequestPaymentOutput, GetBucketRequestPaymentError> {
+        match self.get_bucket_request_payment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_request_payment"),
         }
-        let mut params = Params::new();
-        params.put_key("object-lock");
-        request.set_params(params);
-        if input.object_lock_configuration.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            ObjectLockConfigurationSerializer::serialize(
-                &mut writer,
-                "ObjectLockConfiguration",
-                input.object_lock_configuration.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+    }
+    fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> RusotoFuture<GetBucketTaggingOutput, GetBucketTaggingError> {
+        match self.get_bucket_tagging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_tagging"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutObjectLockConfigurationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = PutObjectLockConfigurationOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = PutObjectLockConfigurationOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Places an Object Retention configuration on an object.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_object_retention(
+    fn get_bucket_versioning(
         &self,
-        input: PutObjectRetentionRequest,
-    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref bypass_governance_retention) = input.bypass_governance_retention {
-            request.add_header(
-                "x-amz-bypass-governance-retention",
-                &bypass_governance_retention.to_string(),
-            );
+        input: GetBucketVersioningRequest,
+    ) -> RusotoFuture<GetBucketVersioningOutput, GetBucketVersioningError> {
+        match self.get_bucket_versioning {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_versioning"),
+        }
+    }
+    fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> RusotoFuture<GetBucketWebsiteOutput, GetBucketWebsiteError> {
+        match self.get_bucket_website {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_bucket_website"),
         }
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+    }
+    fn get_object(&self, input: GetObjectRequest) -> RusotoFuture<GetObjectOutput, GetObjectError> {
+        match self.get_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object"),
         }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+    }
+    fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> RusotoFuture<GetObjectAclOutput, GetObjectAclError> {
+        match self.get_object_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object_acl"),
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+    }
+    fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> RusotoFuture<GetObjectLegalHoldOutput, GetObjectLegalHoldError> {
+        match self.get_object_legal_hold {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object_legal_hold"),
         }
-        params.put_key("retention");
-        request.set_params(params);
-        if input.retention.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            ObjectLockRetentionSerializer::serialize(
-                &mut writer,
-                "Retention",
-                input.retention.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+    }
+    fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> RusotoFuture<GetObjectLockConfigurationOutput, GetObjectLockConfigurationError> {
+        match self.get_object_lock_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object_lock_configuration"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutObjectRetentionError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = PutObjectRetentionOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = PutObjectRetentionOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Sets the supplied tag-set to an object that already exists in a bucket</p>
-    #[allow(unused_variables, warnings)]
-    fn put_object_tagging(
+    fn get_object_retention(
         &self,
-        input: PutObjectTaggingRequest,
-    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        input: GetObjectRetentionRequest,
+    ) -> RusotoFuture<GetObjectRetentionOutput, GetObjectRetentionError> {
+        match self.get_object_retention {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object_retention"),
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+    }
+    fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> RusotoFuture<GetObjectTaggingOutput, GetObjectTaggingError> {
+        match self.get_object_tagging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object_tagging"),
         }
-        params.put_key("tagging");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        TaggingSerializer::serialize(&mut writer, "Tagging", &input.tagging);
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutObjectTaggingError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = PutObjectTaggingOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = PutObjectTaggingOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(version_id) = response.headers.get("x-amz-version-id") {
-                    let value = version_id.to_owned();
-                    result.version_id = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Creates or modifies the <code>PublicAccessBlock</code> configuration for an Amazon S3 bucket.</p>
-    #[allow(unused_variables, warnings)]
-    fn put_public_access_block(
+    fn get_object_torrent(
         &self,
-        input: PutPublicAccessBlockRequest,
-    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
-        let request_uri = format!("/{bucket}", bucket = input.bucket);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+        input: GetObjectTorrentRequest,
+    ) -> RusotoFuture<GetObjectTorrentOutput, GetObjectTorrentError> {
+        match self.get_object_torrent {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_object_torrent"),
         }
-        let mut params = Params::new();
-        params.put_key("publicAccessBlock");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        PublicAccessBlockConfigurationSerializer::serialize(
-            &mut writer,
-            "PublicAccessBlockConfiguration",
-            &input.public_access_block_configuration,
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutPublicAccessBlockError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
     }
-
-    /// <p>Restores an archived copy of an object back into Amazon S3</p>
-    #[allow(unused_variables, warnings)]
-    fn restore_object(
+    fn get_public_access_block(
         &self,
-        input: RestoreObjectRequest,
-    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+        input: GetPublicAccessBlockRequest,
+    ) -> RusotoFuture<GetPublicAccessBlockOutput, GetPublicAccessBlockError> {
+        match self.get_public_access_block {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for get_public_access_block"),
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
+    }
+    fn head_bucket(&self, input: HeadBucketRequest) -> RusotoFuture<(), HeadBucketError> {
+        match self.head_bucket {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for head_bucket"),
         }
-        params.put_key("restore");
-        request.set_params(params);
-        if input.restore_request.is_some() {
-            let mut writer = EventWriter::new(Vec::new());
-            RestoreRequestSerializer::serialize(
-                &mut writer,
-                "RestoreRequest",
-                input.restore_request.as_ref().unwrap(),
-            );
-            request.set_payload(Some(writer.into_inner()));
-        } else {
-            request.set_payload(Some(Vec::new()));
+    }
+    fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> RusotoFuture<HeadObjectOutput, HeadObjectError> {
+        match self.head_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for head_object"),
+        }
+    }
+    fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketAnalyticsConfigurationsOutput, ListBucketAnalyticsConfigurationsError>
+    {
+        match self.list_bucket_analytics_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_bucket_analytics_configurations"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RestoreObjectError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = RestoreObjectOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        RestoreObjectOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                };
-                if let Some(restore_output_path) = response.headers.get("x-amz-restore-output-path")
-                {
-                    let value = restore_output_path.to_owned();
-                    result.restore_output_path = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>This operation filters the contents of an Amazon S3 object based on a simple Structured Query Language (SQL) statement. In the request, along with the SQL expression, you must also specify a data serialization format (JSON or CSV) of the object. Amazon S3 uses this to parse object data into records, and returns only records that match the specified SQL expression. You must also specify the data serialization format for the response.</p>
-    #[allow(unused_variables, warnings)]
-    fn select_object_content(
+    fn list_bucket_inventory_configurations(
         &self,
-        input: SelectObjectContentRequest,
-    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
-
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketInventoryConfigurationsOutput, ListBucketInventoryConfigurationsError>
+    {
+        match self.list_bucket_inventory_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_bucket_inventory_configurations"),
         }
-
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
+    }
+    fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> RusotoFuture<ListBucketMetricsConfigurationsOutput, ListBucketMetricsConfigurationsError>
+    {
+        match self.list_bucket_metrics_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_bucket_metrics_configurations"),
         }
-
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
+    }
+    fn list_buckets(&self) -> RusotoFuture<ListBucketsOutput, ListBucketsError> {
+        match self.list_buckets {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockS3 has no handler for list_buckets"),
         }
-        let mut params = Params::new();
-        params.put_key("select");
-        params.put("select-type", "2");
-        request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        SelectObjectContentRequestSerializer::serialize(
-            &mut writer,
-            "SelectObjectContentRequest",
-            &input,
-            "http://s3.amazonaws.com/doc/2006-03-01/",
-        );
-        request.set_payload(Some(writer.into_inner()));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SelectObjectContentError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = SelectObjectContentOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = SelectObjectContentOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Uploads a part in a multipart upload.</p> <p> <b>Note:</b> After you initiate multipart upload and upload one or more parts, you must either complete or abort multipart upload in order to stop getting charged for storage of the uploaded parts. Only after you either complete or abort multipart upload, Amazon S3 frees up the parts storage and stops charging you for the parts storage.</p>
-    #[allow(unused_variables, warnings)]
-    fn upload_part(
+    fn list_multipart_uploads(
         &self,
-        input: UploadPartRequest,
-    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        if let Some(ref content_length) = input.content_length {
-            request.add_header("Content-Length", &content_length.to_string());
+        input: ListMultipartUploadsRequest,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        match self.list_multipart_uploads {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_multipart_uploads"),
         }
-
-        if let Some(ref content_md5) = input.content_md5 {
-            request.add_header("Content-MD5", &content_md5.to_string());
+    }
+    fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> RusotoFuture<ListObjectVersionsOutput, ListObjectVersionsError> {
+        match self.list_object_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_object_versions"),
         }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+    }
+    fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> RusotoFuture<ListObjectsOutput, ListObjectsError> {
+        match self.list_objects {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_objects"),
         }
-
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
+    }
+    fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> RusotoFuture<ListObjectsV2Output, ListObjectsV2Error> {
+        match self.list_objects_v2 {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_objects_v2"),
         }
-
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
+    }
+    fn list_parts(&self, input: ListPartsRequest) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        match self.list_parts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for list_parts"),
         }
-
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
+    }
+    fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAccelerateConfigurationError> {
+        match self.put_bucket_accelerate_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_accelerate_configuration"),
         }
-        let mut params = Params::new();
-        params.put("partNumber", &input.part_number);
-        params.put("uploadId", &input.upload_id);
-        request.set_params(params);
-        if let Some(__body) = input.body {
-            request.set_payload_stream(__body);
+    }
+    fn put_bucket_acl(&self, input: PutBucketAclRequest) -> RusotoFuture<(), PutBucketAclError> {
+        match self.put_bucket_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_acl"),
+        }
+    }
+    fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketAnalyticsConfigurationError> {
+        match self.put_bucket_analytics_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_analytics_configuration"),
+        }
+    }
+    fn put_bucket_cors(&self, input: PutBucketCorsRequest) -> RusotoFuture<(), PutBucketCorsError> {
+        match self.put_bucket_cors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_cors"),
+        }
+    }
+    fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> RusotoFuture<(), PutBucketEncryptionError> {
+        match self.put_bucket_encryption {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_encryption"),
+        }
+    }
+    fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketInventoryConfigurationError> {
+        match self.put_bucket_inventory_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_inventory_configuration"),
+        }
+    }
+    fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleError> {
+        match self.put_bucket_lifecycle {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_lifecycle"),
+        }
+    }
+    fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketLifecycleConfigurationError> {
+        match self.put_bucket_lifecycle_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_lifecycle_configuration"),
+        }
+    }
+    fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> RusotoFuture<(), PutBucketLoggingError> {
+        match self.put_bucket_logging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_logging"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UploadPartError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UploadPartOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result =
-                        UploadPartOutputDeserializer::deserialize(&actual_tag_name, &mut stack)?;
-                }
-                if let Some(e_tag) = response.headers.get("ETag") {
-                    let value = e_tag.to_owned();
-                    result.e_tag = Some(value)
-                };
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                };
-                if let Some(sse_customer_algorithm) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-algorithm")
-                {
-                    let value = sse_customer_algorithm.to_owned();
-                    result.sse_customer_algorithm = Some(value)
-                };
-                if let Some(sse_customer_key_md5) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-key-MD5")
-                {
-                    let value = sse_customer_key_md5.to_owned();
-                    result.sse_customer_key_md5 = Some(value)
-                };
-                if let Some(ssekms_key_id) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-aws-kms-key-id")
-                {
-                    let value = ssekms_key_id.to_owned();
-                    result.ssekms_key_id = Some(value)
-                };
-                if let Some(server_side_encryption) =
-                    response.headers.get("x-amz-server-side-encryption")
-                {
-                    let value = server_side_encryption.to_owned();
-                    result.server_side_encryption = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
     }
-
-    /// <p>Uploads a part by copying data from an existing object as data source.</p>
-    #[allow(unused_variables, warnings)]
-    fn upload_part_copy(
+    fn put_bucket_metrics_configuration(
         &self,
-        input: UploadPartCopyRequest,
-    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
-        let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
-
-        let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
-
-        request.add_header("x-amz-copy-source", &input.copy_source);
-
-        if let Some(ref copy_source_if_match) = input.copy_source_if_match {
-            request.add_header(
-                "x-amz-copy-source-if-match",
-                &copy_source_if_match.to_string(),
-            );
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketMetricsConfigurationError> {
+        match self.put_bucket_metrics_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_metrics_configuration"),
         }
-
-        if let Some(ref copy_source_if_modified_since) = input.copy_source_if_modified_since {
-            request.add_header(
-                "x-amz-copy-source-if-modified-since",
-                &copy_source_if_modified_since.to_string(),
-            );
+    }
+    fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationError> {
+        match self.put_bucket_notification {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_notification"),
         }
-
-        if let Some(ref copy_source_if_none_match) = input.copy_source_if_none_match {
-            request.add_header(
-                "x-amz-copy-source-if-none-match",
-                &copy_source_if_none_match.to_string(),
-            );
+    }
+    fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> RusotoFuture<(), PutBucketNotificationConfigurationError> {
+        match self.put_bucket_notification_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_notification_configuration"),
         }
-
-        if let Some(ref copy_source_if_unmodified_since) = input.copy_source_if_unmodified_since {
-            request.add_header(
-                "x-amz-copy-source-if-unmodified-since",
-                &copy_source_if_unmodified_since.to_string(),
-            );
+    }
+    fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> RusotoFuture<(), PutBucketPolicyError> {
+        match self.put_bucket_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_policy"),
         }
-
-        if let Some(ref copy_source_range) = input.copy_source_range {
-            request.add_header("x-amz-copy-source-range", &copy_source_range.to_string());
+    }
+    fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> RusotoFuture<(), PutBucketReplicationError> {
+        match self.put_bucket_replication {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_replication"),
         }
-
-        if let Some(ref copy_source_sse_customer_algorithm) =
-            input.copy_source_sse_customer_algorithm
-        {
-            request.add_header(
-                "x-amz-copy-source-server-side-encryption-customer-algorithm",
-                &copy_source_sse_customer_algorithm.to_string(),
-            );
+    }
+    fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> RusotoFuture<(), PutBucketRequestPaymentError> {
+        match self.put_bucket_request_payment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_request_payment"),
         }
-
-        if let Some(ref copy_source_sse_customer_key) = input.copy_source_sse_customer_key {
-            request.add_header(
-                "x-amz-copy-source-server-side-encryption-customer-key",
-                &copy_source_sse_customer_key.to_string(),
-            );
+    }
+    fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> RusotoFuture<(), PutBucketTaggingError> {
+        match self.put_bucket_tagging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_tagging"),
         }
-
-        if let Some(ref copy_source_sse_customer_key_md5) = input.copy_source_sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-copy-source-server-side-encryption-customer-key-MD5",
-                &copy_source_sse_customer_key_md5.to_string(),
-            );
+    }
+    fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> RusotoFuture<(), PutBucketVersioningError> {
+        match self.put_bucket_versioning {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_versioning"),
         }
-
-        if let Some(ref request_payer) = input.request_payer {
-            request.add_header("x-amz-request-payer", &request_payer.to_string());
+    }
+    fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> RusotoFuture<(), PutBucketWebsiteError> {
+        match self.put_bucket_website {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_bucket_website"),
         }
-
-        if let Some(ref sse_customer_algorithm) = input.sse_customer_algorithm {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-algorithm",
-                &sse_customer_algorithm.to_string(),
-            );
+    }
+    fn put_object(&self, input: PutObjectRequest) -> RusotoFuture<PutObjectOutput, PutObjectError> {
+        match self.put_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_object"),
         }
-
-        if let Some(ref sse_customer_key) = input.sse_customer_key {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key",
-                &sse_customer_key.to_string(),
-            );
+    }
+    fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> RusotoFuture<PutObjectAclOutput, PutObjectAclError> {
+        match self.put_object_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_object_acl"),
         }
-
-        if let Some(ref sse_customer_key_md5) = input.sse_customer_key_md5 {
-            request.add_header(
-                "x-amz-server-side-encryption-customer-key-MD5",
-                &sse_customer_key_md5.to_string(),
-            );
+    }
+    fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> RusotoFuture<PutObjectLegalHoldOutput, PutObjectLegalHoldError> {
+        match self.put_object_legal_hold {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_object_legal_hold"),
         }
-        let mut params = Params::new();
-        params.put("partNumber", &input.part_number);
-        params.put("uploadId", &input.upload_id);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UploadPartCopyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let mut result;
-
-                if response.body.is_empty() {
-                    result = UploadPartCopyOutput::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    result = UploadPartCopyOutputDeserializer::deserialize(
-                        &actual_tag_name,
-                        &mut stack,
-                    )?;
-                }
-                if let Some(copy_source_version_id) =
-                    response.headers.get("x-amz-copy-source-version-id")
-                {
-                    let value = copy_source_version_id.to_owned();
-                    result.copy_source_version_id = Some(value)
-                };
-                if let Some(request_charged) = response.headers.get("x-amz-request-charged") {
-                    let value = request_charged.to_owned();
-                    result.request_charged = Some(value)
-                };
-                if let Some(sse_customer_algorithm) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-algorithm")
-                {
-                    let value = sse_customer_algorithm.to_owned();
-                    result.sse_customer_algorithm = Some(value)
-                };
-                if let Some(sse_customer_key_md5) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-customer-key-MD5")
-                {
-                    let value = sse_customer_key_md5.to_owned();
-                    result.sse_customer_key_md5 = Some(value)
-                };
-                if let Some(ssekms_key_id) = response
-                    .headers
-                    .get("x-amz-server-side-encryption-aws-kms-key-id")
-                {
-                    let value = ssekms_key_id.to_owned();
-                    result.ssekms_key_id = Some(value)
-                };
-                if let Some(server_side_encryption) =
-                    response.headers.get("x-amz-server-side-encryption")
-                {
-                    let value = server_side_encryption.to_owned();
-                    result.server_side_encryption = Some(value)
-                }; // parse non-payload
-                Ok(result)
-            }))
-        })
+    }
+    fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> RusotoFuture<PutObjectLockConfigurationOutput, PutObjectLockConfigurationError> {
+        match self.put_object_lock_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_object_lock_configuration"),
+        }
+    }
+    fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> RusotoFuture<PutObjectRetentionOutput, PutObjectRetentionError> {
+        match self.put_object_retention {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_object_retention"),
+        }
+    }
+    fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> RusotoFuture<PutObjectTaggingOutput, PutObjectTaggingError> {
+        match self.put_object_tagging {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_object_tagging"),
+        }
+    }
+    fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> RusotoFuture<(), PutPublicAccessBlockError> {
+        match self.put_public_access_block {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for put_public_access_block"),
+        }
+    }
+    fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> RusotoFuture<RestoreObjectOutput, RestoreObjectError> {
+        match self.restore_object {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for restore_object"),
+        }
+    }
+    fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> RusotoFuture<SelectObjectContentOutput, SelectObjectContentError> {
+        match self.select_object_content {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for select_object_content"),
+        }
+    }
+    fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> RusotoFuture<UploadPartOutput, UploadPartError> {
+        match self.upload_part {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for upload_part"),
+        }
+    }
+    fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> RusotoFuture<UploadPartCopyOutput, UploadPartCopyError> {
+        match self.upload_part_copy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockS3 has no handler for upload_part_copy"),
+        }
+    }
+}
+
+/// The host name of the website endpoint of buckets in `region`, or `None` for custom regions.
+pub fn website_endpoint(region: &region::Region) -> Option<&'static str> {
+    match *region {
+        region::Region::ApEast1 => Some("s3-website.ap-east-1.amazonaws.com"),
+        region::Region::ApNortheast1 => Some("s3-website-ap-northeast-1.amazonaws.com"),
+        region::Region::ApNortheast2 => Some("s3-website.ap-northeast-2.amazonaws.com"),
+        region::Region::ApNortheast3 => Some("s3-website.ap-northeast-3.amazonaws.com"),
+        region::Region::ApSouth1 => Some("s3-website.ap-south-1.amazonaws.com"),
+        region::Region::ApSoutheast1 => Some("s3-website-ap-southeast-1.amazonaws.com"),
+        region::Region::ApSoutheast2 => Some("s3-website-ap-southeast-2.amazonaws.com"),
+        region::Region::CaCentral1 => Some("s3-website.ca-central-1.amazonaws.com"),
+        region::Region::CnNorth1 => Some("s3-website.cn-north-1.amazonaws.com.cn"),
+        region::Region::CnNorthwest1 => Some("s3-website.cn-northwest-1.amazonaws.com.cn"),
+        region::Region::EuCentral1 => Some("s3-website.eu-central-1.amazonaws.com"),
+        region::Region::EuNorth1 => Some("s3-website.eu-north-1.amazonaws.com"),
+        region::Region::EuWest1 => Some("s3-website-eu-west-1.amazonaws.com"),
+        region::Region::EuWest2 => Some("s3-website.eu-west-2.amazonaws.com"),
+        region::Region::EuWest3 => Some("s3-website.eu-west-3.amazonaws.com"),
+        region::Region::MeSouth1 => Some("s3-website.me-south-1.amazonaws.com"),
+        region::Region::SaEast1 => Some("s3-website-sa-east-1.amazonaws.com"),
+        region::Region::UsEast1 => Some("s3-website-us-east-1.amazonaws.com"),
+        region::Region::UsEast2 => Some("s3-website.us-east-2.amazonaws.com"),
+        region::Region::UsGovEast1 => Some("s3-website.us-gov-east-1.amazonaws.com"),
+        region::Region::UsGovWest1 => Some("s3-website-us-gov-west-1.amazonaws.com"),
+        region::Region::UsWest1 => Some("s3-website-us-west-1.amazonaws.com"),
+        region::Region::UsWest2 => Some("s3-website-us-west-2.amazonaws.com"),
+        region::Region::Custom { .. } => None,
+    }
+}
+
+/// The Route 53 hosted zone of the website endpoint of buckets in `region`, to point alias
+/// records at, or `None` for custom regions.
+pub fn website_hosted_zone_id(region: &region::Region) -> Option<&'static str> {
+    match *region {
+        region::Region::ApEast1 => Some("ZNB98KWMFR0R6"),
+        region::Region::ApNortheast1 => Some("Z2M4EHUR26P7ZW"),
+        region::Region::ApNortheast2 => Some("Z3W03O7B5YMIYP"),
+        region::Region::ApNortheast3 => Some("Z2YQB5RD63NC85"),
+        region::Region::ApSouth1 => Some("Z11RGJOFQNVJUP"),
+        region::Region::ApSoutheast1 => Some("Z3O0J2DXBE1FTB"),
+        region::Region::ApSoutheast2 => Some("Z1WCIGYICN2BYD"),
+        region::Region::CaCentral1 => Some("Z1QDHH18159H29"),
+        region::Region::CnNorth1 => Some("Z5CN8UMXT92WN"),
+        region::Region::CnNorthwest1 => Some("Z282HJ1KT0DH03"),
+        region::Region::EuCentral1 => Some("Z21DNDUVLTQW6Q"),
+        region::Region::EuNorth1 => Some("Z3BAZG2TWCNX0D"),
+        region::Region::EuWest1 => Some("Z1BKCTXD74EZPE"),
+        region::Region::EuWest2 => Some("Z3GKZC51ZF0DB4"),
+        region::Region::EuWest3 => Some("Z3R1K369G5AVDG"),
+        region::Region::MeSouth1 => Some("Z1MPMWCPA7YB62"),
+        region::Region::SaEast1 => Some("Z7KQH4QJS55SO"),
+        region::Region::UsEast1 => Some("Z3AQBSTGFYJSTF"),
+        region::Region::UsEast2 => Some("Z2O1EMRO9K5GLX"),
+        region::Region::UsGovEast1 => Some("Z2NIFVYYW2VKV1"),
+        region::Region::UsGovWest1 => Some("Z31GFT0UA1I2HV"),
+        region::Region::UsWest1 => Some("Z2F56UZL2M1ACD"),
+        region::Region::UsWest2 => Some("Z3BJ6K6RIION7M"),
+        region::Region::Custom { .. } => None,
     }
 }
 
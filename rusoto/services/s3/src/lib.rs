@@ -18,7 +18,12 @@
 
 extern crate bytes;
 extern crate futures;
+extern crate md5;
 extern crate rusoto_core;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 extern crate xml;
 #[cfg(nightly)]
 extern crate test;
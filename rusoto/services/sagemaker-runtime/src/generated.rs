@@ -22,7 +22,9 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InvokeEndpointInput {
     /// <p>The desired MIME type of the inference in the response.</p>
     #[serde(rename = "Accept")]
@@ -49,6 +51,59 @@ pub struct InvokeEndpointInput {
     pub endpoint_name: String,
 }
 
+impl Validate for InvokeEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept {
+            errors.check_length(
+                &field_path(path, "accept"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "accept"), &value, "\\p{ASCII}*");
+        }
+        errors.check_length(
+            &field_path(path, "body"),
+            self.body.len(),
+            None,
+            Some(6291456),
+        );
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "content_type"), &value, "\\p{ASCII}*");
+        }
+        if let Some(ref value) = self.custom_attributes {
+            errors.check_length(
+                &field_path(path, "custom_attributes"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "custom_attributes"),
+                &value,
+                "\\p{ASCII}*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "endpoint_name"),
+            self.endpoint_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_name"),
+            &self.endpoint_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct InvokeEndpointOutput {
     /// <p>Includes the inference provided by the model.</p> <p>For information about the format of the response body, see <a href="http://docs.aws.amazon.com/sagemaker/latest/dg/cdf-inference.html">Common Data Formats—Inference</a>.</p>
@@ -90,7 +145,12 @@ impl InvokeEndpointError {
                 "ValidationError" => {
                     return RusotoError::Service(InvokeEndpointError::ValidationError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -120,6 +180,31 @@ pub trait SageMakerRuntime {
         input: InvokeEndpointInput,
     ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError>;
 }
+const _: Option<&dyn SageMakerRuntime> = None;
+impl<'a, T: SageMakerRuntime + ?Sized> SageMakerRuntime for &'a T {
+    fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError> {
+        (**self).invoke_endpoint(input)
+    }
+}
+impl<T: SageMakerRuntime + ?Sized> SageMakerRuntime for Box<T> {
+    fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError> {
+        (**self).invoke_endpoint(input)
+    }
+}
+impl<T: SageMakerRuntime + ?Sized> SageMakerRuntime for ::std::sync::Arc<T> {
+    fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError> {
+        (**self).invoke_endpoint(input)
+    }
+}
 /// A client for the Amazon SageMaker Runtime API.
 #[derive(Clone)]
 pub struct SageMakerRuntimeClient {
@@ -163,12 +248,22 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         &self,
         input: InvokeEndpointInput,
     ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/endpoints/{endpoint_name}/invocations",
             endpoint_name = input.endpoint_name
         );
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("InvokeEndpoint");
+        request.add_resource_id("EndpointName", &input.endpoint_name.to_string());
         if input.content_type.is_none() {
             request.set_content_type("application/x-amz-json-1.1".to_owned());
         }
@@ -228,3 +323,58 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         })
     }
 }
+
+/// A mock of the Amazon SageMaker Runtime API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSageMakerRuntime {
+    invoke_endpoint: Option<
+        Box<
+            dyn Fn(
+                    InvokeEndpointInput,
+                )
+                    -> ::std::result::Result<InvokeEndpointOutput, RusotoError<InvokeEndpointError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSageMakerRuntime {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSageMakerRuntime {
+        Default::default()
+    }
+
+    /// Answers `invoke_endpoint` with what `handler` returns for its input.
+    pub fn with_invoke_endpoint<F>(mut self, handler: F) -> MockSageMakerRuntime
+    where
+        F: Fn(
+                InvokeEndpointInput,
+            )
+                -> ::std::result::Result<InvokeEndpointOutput, RusotoError<InvokeEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.invoke_endpoint = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl SageMakerRuntime for MockSageMakerRuntime {
+    fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> RusotoFuture<InvokeEndpointOutput, InvokeEndpointError> {
+        match self.invoke_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMakerRuntime has no handler for invoke_endpoint"),
+        }
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsInput {
     /// <p>The Amazon Resource Name (ARN) of the resource that you want to tag.</p>
     #[serde(rename = "ResourceArn")]
@@ -33,8 +35,29 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:aws[a-z-]*:sagemaker:[a-z0-9-]*:[0-9]{12}:.+",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(50));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsOutput {
     /// <p>A list of tags associated with the Amazon SageMaker resource.</p>
     #[serde(rename = "Tags")]
@@ -62,9 +85,44 @@ pub struct AlgorithmSpecification {
     pub training_input_mode: String,
 }
 
+impl Validate for AlgorithmSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.algorithm_name {
+            errors.check_length(
+                &field_path(path, "algorithm_name"),
+                value.chars().count(),
+                Some(1),
+                Some(170),
+            );
+            errors.check_pattern(&field_path(path, "algorithm_name"), &value, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)$");
+        }
+        if let Some(ref value) = self.metric_definitions {
+            errors.check_length(
+                &field_path(path, "metric_definitions"),
+                value.len(),
+                None,
+                Some(40),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metric_definitions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.training_image {
+            errors.check_length(
+                &field_path(path, "training_image"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "training_image"), &value, ".*");
+        }
+    }
+}
+
 /// <p>Specifies the validation and image scan statuses of the algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AlgorithmStatusDetails {
     /// <p>The status of the scan of the algorithm's Docker image container.</p>
     #[serde(rename = "ImageScanStatuses")]
@@ -78,7 +136,7 @@ pub struct AlgorithmStatusDetails {
 
 /// <p>Represents the overall status of an algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AlgorithmStatusItem {
     /// <p>if the overall status is <code>Failed</code>, the reason for the failure.</p>
     #[serde(rename = "FailureReason")]
@@ -94,7 +152,7 @@ pub struct AlgorithmStatusItem {
 
 /// <p>Provides summary information about an algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AlgorithmSummary {
     /// <p>The Amazon Resource Name (ARN) of the algorithm.</p>
     #[serde(rename = "AlgorithmArn")]
@@ -129,6 +187,27 @@ pub struct AlgorithmValidationProfile {
     pub transform_job_definition: Option<TransformJobDefinition>,
 }
 
+impl Validate for AlgorithmValidationProfile {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "profile_name"),
+            self.profile_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "profile_name"),
+            &self.profile_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        self.training_job_definition
+            .validate_at(&field_path(path, "training_job_definition"), errors);
+        if let Some(ref value) = self.transform_job_definition {
+            value.validate_at(&field_path(path, "transform_job_definition"), errors);
+        }
+    }
+}
+
 /// <p>Specifies configurations for one or more training jobs that Amazon SageMaker runs to test the algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AlgorithmValidationSpecification {
@@ -140,6 +219,32 @@ pub struct AlgorithmValidationSpecification {
     pub validation_role: String,
 }
 
+impl Validate for AlgorithmValidationSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "validation_profiles"),
+            self.validation_profiles.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.validation_profiles.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "validation_profiles"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "validation_role"),
+            self.validation_role.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "validation_role"),
+            &self.validation_role,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+    }
+}
+
 /// <p>Configures how labels are consolidated across human workers.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AnnotationConsolidationConfig {
@@ -148,6 +253,22 @@ pub struct AnnotationConsolidationConfig {
     pub annotation_consolidation_lambda_arn: String,
 }
 
+impl Validate for AnnotationConsolidationConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "annotation_consolidation_lambda_arn"),
+            self.annotation_consolidation_lambda_arn.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "annotation_consolidation_lambda_arn"),
+            &self.annotation_consolidation_lambda_arn,
+            "arn:aws[a-z\\-]*:lambda:[a-z0-9\\-]*:[0-9]{12}:function:.*",
+        );
+    }
+}
+
 /// <p>A list of categorical hyperparameters to tune.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategoricalParameterRange {
@@ -159,6 +280,29 @@ pub struct CategoricalParameterRange {
     pub values: Vec<String>,
 }
 
+impl Validate for CategoricalParameterRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*");
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(30),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, ".*");
+        }
+    }
+}
+
 /// <p>Defines the possible values for a categorical hyperparameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategoricalParameterRangeSpecification {
@@ -167,6 +311,22 @@ pub struct CategoricalParameterRangeSpecification {
     pub values: Vec<String>,
 }
 
+impl Validate for CategoricalParameterRangeSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(30),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, ".*");
+        }
+    }
+}
+
 /// <p>A channel is a named input source that training algorithms can consume. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Channel {
@@ -198,6 +358,33 @@ pub struct Channel {
     pub shuffle_config: Option<ShuffleConfig>,
 }
 
+impl Validate for Channel {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "channel_name"),
+            self.channel_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "channel_name"),
+            &self.channel_name,
+            "[A-Za-z0-9\\.\\-_]+",
+        );
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "content_type"), &value, ".*");
+        }
+        self.data_source
+            .validate_at(&field_path(path, "data_source"), errors);
+    }
+}
+
 /// <p>Defines a named input source, called a channel, to be used by an algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChannelSpecification {
@@ -224,6 +411,42 @@ pub struct ChannelSpecification {
     pub supported_input_modes: Vec<String>,
 }
 
+impl Validate for ChannelSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\p{L}\\p{M}\\p{Z}\\p{S}\\p{N}\\p{P}]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[A-Za-z0-9\\.\\-_]+");
+        for (i, item) in self.supported_content_types.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "supported_content_types"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "supported_input_modes"),
+            self.supported_input_modes.len(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Contains information about the output location for managed spot training checkpoint data. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CheckpointConfig {
@@ -236,9 +459,34 @@ pub struct CheckpointConfig {
     pub s3_uri: String,
 }
 
+impl Validate for CheckpointConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.local_path {
+            errors.check_length(
+                &field_path(path, "local_path"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "local_path"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>Specifies summary information about a Git repository.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CodeRepositorySummary {
     /// <p>The Amazon Resource Name (ARN) of the Git repository.</p>
     #[serde(rename = "CodeRepositoryArn")]
@@ -272,9 +520,43 @@ pub struct CognitoMemberDefinition {
     pub user_pool: String,
 }
 
+impl Validate for CognitoMemberDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "client_id"), &self.client_id, "[ -~]+");
+        errors.check_length(
+            &field_path(path, "user_group"),
+            self.user_group.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_group"),
+            &self.user_group,
+            "[\\p{L}\\p{M}\\p{S}\\p{N}\\p{P}]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_pool"),
+            self.user_pool.chars().count(),
+            Some(1),
+            Some(55),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_pool"),
+            &self.user_pool,
+            "[\\w-]+_[0-9a-zA-Z]+",
+        );
+    }
+}
+
 /// <p>A summary of a model compilation job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompilationJobSummary {
     /// <p>The time when the model compilation job completed.</p>
     #[serde(rename = "CompilationEndTime")]
@@ -330,6 +612,68 @@ pub struct ContainerDefinition {
     pub model_package_name: Option<String>,
 }
 
+impl Validate for ContainerDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.container_hostname {
+            errors.check_length(
+                &field_path(path, "container_hostname"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "container_hostname"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+            );
+        }
+        if let Some(ref value) = self.environment {
+            errors.check_length(
+                &field_path(path, "environment"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+        if let Some(ref value) = self.image {
+            errors.check_length(
+                &field_path(path, "image"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "image"), &value, "[\\S]+");
+        }
+        if let Some(ref value) = self.model_data_url {
+            errors.check_length(
+                &field_path(path, "model_data_url"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "model_data_url"),
+                &value,
+                "^(https|s3)://([^/]+)/?(.*)$",
+            );
+        }
+        if let Some(ref value) = self.model_package_name {
+            errors.check_length(
+                &field_path(path, "model_package_name"),
+                value.chars().count(),
+                Some(1),
+                Some(176),
+            );
+            errors.check_pattern(&field_path(path, "model_package_name"), &value, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)(\\/[0-9]{1,5})?$");
+        }
+    }
+}
+
 /// <p>A list of continuous hyperparameters to tune.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContinuousParameterRange {
@@ -348,6 +692,32 @@ pub struct ContinuousParameterRange {
     pub scaling_type: Option<String>,
 }
 
+impl Validate for ContinuousParameterRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "max_value"),
+            self.max_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "max_value"), &self.max_value, ".*");
+        errors.check_length(
+            &field_path(path, "min_value"),
+            self.min_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "min_value"), &self.min_value, ".*");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*");
+    }
+}
+
 /// <p>Defines the possible values for a continuous hyperparameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContinuousParameterRangeSpecification {
@@ -359,7 +729,27 @@ pub struct ContinuousParameterRangeSpecification {
     pub min_value: String,
 }
 
+impl Validate for ContinuousParameterRangeSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "max_value"),
+            self.max_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "max_value"), &self.max_value, ".*");
+        errors.check_length(
+            &field_path(path, "min_value"),
+            self.min_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "min_value"), &self.min_value, ".*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAlgorithmInput {
     /// <p>A description of the algorithm.</p>
     #[serde(rename = "AlgorithmDescription")]
@@ -385,8 +775,45 @@ pub struct CreateAlgorithmInput {
     pub validation_specification: Option<AlgorithmValidationSpecification>,
 }
 
+impl Validate for CreateAlgorithmInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.algorithm_description {
+            errors.check_length(
+                &field_path(path, "algorithm_description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "algorithm_description"),
+                &value,
+                "[\\p{L}\\p{M}\\p{Z}\\p{S}\\p{N}\\p{P}]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "algorithm_name"),
+            self.algorithm_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "algorithm_name"),
+            &self.algorithm_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        if let Some(ref value) = self.inference_specification {
+            value.validate_at(&field_path(path, "inference_specification"), errors);
+        }
+        self.training_specification
+            .validate_at(&field_path(path, "training_specification"), errors);
+        if let Some(ref value) = self.validation_specification {
+            value.validate_at(&field_path(path, "validation_specification"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAlgorithmOutput {
     /// <p>The Amazon Resource Name (ARN) of the new algorithm.</p>
     #[serde(rename = "AlgorithmArn")]
@@ -394,6 +821,7 @@ pub struct CreateAlgorithmOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCodeRepositoryInput {
     /// <p>The name of the Git repository. The name must have 1 to 63 characters. Valid characters are a-z, A-Z, 0-9, and - (hyphen).</p>
     #[serde(rename = "CodeRepositoryName")]
@@ -403,8 +831,26 @@ pub struct CreateCodeRepositoryInput {
     pub git_config: GitConfig,
 }
 
+impl Validate for CreateCodeRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "code_repository_name"),
+            self.code_repository_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "code_repository_name"),
+            &self.code_repository_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        self.git_config
+            .validate_at(&field_path(path, "git_config"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCodeRepositoryOutput {
     /// <p>The Amazon Resource Name (ARN) of the new repository.</p>
     #[serde(rename = "CodeRepositoryArn")]
@@ -412,6 +858,7 @@ pub struct CreateCodeRepositoryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCompilationJobRequest {
     /// <p>A name for the model compilation job. The name must be unique within the AWS Region and within your AWS account. </p>
     #[serde(rename = "CompilationJobName")]
@@ -430,8 +877,41 @@ pub struct CreateCompilationJobRequest {
     pub stopping_condition: StoppingCondition,
 }
 
+impl Validate for CreateCompilationJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "compilation_job_name"),
+            self.compilation_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "compilation_job_name"),
+            &self.compilation_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        self.input_config
+            .validate_at(&field_path(path, "input_config"), errors);
+        self.output_config
+            .validate_at(&field_path(path, "output_config"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        self.stopping_condition
+            .validate_at(&field_path(path, "stopping_condition"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCompilationJobResponse {
     /// <p><p>If the action is successful, the service sends back an HTTP 200 response. Amazon SageMaker returns the following data in JSON format:</p> <ul> <li> <p> <code>CompilationJobArn</code>: The Amazon Resource Name (ARN) of the compiled job.</p> </li> </ul></p>
     #[serde(rename = "CompilationJobArn")]
@@ -439,6 +919,7 @@ pub struct CreateCompilationJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEndpointConfigInput {
     /// <p>The name of the endpoint configuration. You specify this name in a <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpoint.html">CreateEndpoint</a> request. </p>
     #[serde(rename = "EndpointConfigName")]
@@ -456,8 +937,50 @@ pub struct CreateEndpointConfigInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateEndpointConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_config_name"),
+            self.endpoint_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_config_name"),
+            &self.endpoint_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "production_variants"),
+            self.production_variants.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.production_variants.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "production_variants"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEndpointConfigOutput {
     /// <p>The Amazon Resource Name (ARN) of the endpoint configuration. </p>
     #[serde(rename = "EndpointConfigArn")]
@@ -465,6 +988,7 @@ pub struct CreateEndpointConfigOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEndpointInput {
     /// <p>The name of an endpoint configuration. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpointConfig.html">CreateEndpointConfig</a>. </p>
     #[serde(rename = "EndpointConfigName")]
@@ -478,8 +1002,42 @@ pub struct CreateEndpointInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_config_name"),
+            self.endpoint_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_config_name"),
+            &self.endpoint_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        errors.check_length(
+            &field_path(path, "endpoint_name"),
+            self.endpoint_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_name"),
+            &self.endpoint_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateEndpointOutput {
     /// <p>The Amazon Resource Name (ARN) of the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -487,6 +1045,7 @@ pub struct CreateEndpointOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHyperParameterTuningJobRequest {
     /// <p>The <a>HyperParameterTuningJobConfig</a> object that describes the tuning job, including the search strategy, the objective metric used to evaluate training jobs, ranges of parameters to search, and resource limits for the tuning job. For more information, see <a>automatic-model-tuning</a> </p>
     #[serde(rename = "HyperParameterTuningJobConfig")]
@@ -508,8 +1067,41 @@ pub struct CreateHyperParameterTuningJobRequest {
     pub warm_start_config: Option<HyperParameterTuningJobWarmStartConfig>,
 }
 
+impl Validate for CreateHyperParameterTuningJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.hyper_parameter_tuning_job_config.validate_at(
+            &field_path(path, "hyper_parameter_tuning_job_config"),
+            errors,
+        );
+        errors.check_length(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            self.hyper_parameter_tuning_job_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            &self.hyper_parameter_tuning_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,31}",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.training_job_definition {
+            value.validate_at(&field_path(path, "training_job_definition"), errors);
+        }
+        if let Some(ref value) = self.warm_start_config {
+            value.validate_at(&field_path(path, "warm_start_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHyperParameterTuningJobResponse {
     /// <p>The Amazon Resource Name (ARN) of the tuning job. Amazon SageMaker assigns an ARN to a hyperparameter tuning job when you create it.</p>
     #[serde(rename = "HyperParameterTuningJobArn")]
@@ -517,6 +1109,7 @@ pub struct CreateHyperParameterTuningJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLabelingJobRequest {
     /// <p>Configures the information required for human workers to complete a labeling task.</p>
     #[serde(rename = "HumanTaskConfig")]
@@ -554,8 +1147,78 @@ pub struct CreateLabelingJobRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateLabelingJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.human_task_config
+            .validate_at(&field_path(path, "human_task_config"), errors);
+        self.input_config
+            .validate_at(&field_path(path, "input_config"), errors);
+        errors.check_length(
+            &field_path(path, "label_attribute_name"),
+            self.label_attribute_name.chars().count(),
+            Some(1),
+            Some(127),
+        );
+        errors.check_pattern(
+            &field_path(path, "label_attribute_name"),
+            &self.label_attribute_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,126}",
+        );
+        if let Some(ref value) = self.label_category_config_s3_uri {
+            errors.check_length(
+                &field_path(path, "label_category_config_s3_uri"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "label_category_config_s3_uri"),
+                &value,
+                "^(https|s3)://([^/]+)/?(.*)$",
+            );
+        }
+        if let Some(ref value) = self.labeling_job_algorithms_config {
+            value.validate_at(&field_path(path, "labeling_job_algorithms_config"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "labeling_job_name"),
+            self.labeling_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "labeling_job_name"),
+            &self.labeling_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        self.output_config
+            .validate_at(&field_path(path, "output_config"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        if let Some(ref value) = self.stopping_conditions {
+            value.validate_at(&field_path(path, "stopping_conditions"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLabelingJobResponse {
     /// <p>The Amazon Resource Name (ARN) of the labeling job. You use this ARN to identify the labeling job.</p>
     #[serde(rename = "LabelingJobArn")]
@@ -563,6 +1226,7 @@ pub struct CreateLabelingJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateModelInput {
     /// <p>Specifies the containers in the inference pipeline.</p>
     #[serde(rename = "Containers")]
@@ -592,8 +1256,55 @@ pub struct CreateModelInput {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.containers {
+            errors.check_length(&field_path(path, "containers"), value.len(), None, Some(15));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "containers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "execution_role_arn"),
+            self.execution_role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "execution_role_arn"),
+            &self.execution_role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        errors.check_length(
+            &field_path(path, "model_name"),
+            self.model_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "model_name"),
+            &self.model_name,
+            "^[a-zA-Z0-9]([\\-a-zA-Z0-9]*[a-zA-Z0-9])?",
+        );
+        if let Some(ref value) = self.primary_container {
+            value.validate_at(&field_path(path, "primary_container"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateModelOutput {
     /// <p>The ARN of the model created in Amazon SageMaker.</p>
     #[serde(rename = "ModelArn")]
@@ -601,6 +1312,7 @@ pub struct CreateModelOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateModelPackageInput {
     /// <p>Whether to certify the model package for listing on AWS Marketplace.</p>
     #[serde(rename = "CertifyForMarketplace")]
@@ -627,8 +1339,46 @@ pub struct CreateModelPackageInput {
     pub validation_specification: Option<ModelPackageValidationSpecification>,
 }
 
+impl Validate for CreateModelPackageInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.inference_specification {
+            value.validate_at(&field_path(path, "inference_specification"), errors);
+        }
+        if let Some(ref value) = self.model_package_description {
+            errors.check_length(
+                &field_path(path, "model_package_description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "model_package_description"),
+                &value,
+                "[\\p{L}\\p{M}\\p{Z}\\p{S}\\p{N}\\p{P}]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "model_package_name"),
+            self.model_package_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "model_package_name"),
+            &self.model_package_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        if let Some(ref value) = self.source_algorithm_specification {
+            value.validate_at(&field_path(path, "source_algorithm_specification"), errors);
+        }
+        if let Some(ref value) = self.validation_specification {
+            value.validate_at(&field_path(path, "validation_specification"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateModelPackageOutput {
     /// <p>The Amazon Resource Name (ARN) of the new model package.</p>
     #[serde(rename = "ModelPackageArn")]
@@ -636,6 +1386,7 @@ pub struct CreateModelPackageOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNotebookInstanceInput {
     /// <p>A list of Elastic Inference (EI) instance types to associate with this notebook instance. Currently, only one instance type can be associated with a notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ei.html">Using Elastic Inference in Amazon SageMaker</a>.</p>
     #[serde(rename = "AcceleratorTypes")]
@@ -692,7 +1443,128 @@ pub struct CreateNotebookInstanceInput {
     pub volume_size_in_gb: Option<i64>,
 }
 
+impl Validate for CreateNotebookInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_code_repositories {
+            errors.check_length(
+                &field_path(path, "additional_code_repositories"),
+                value.len(),
+                None,
+                Some(3),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "additional_code_repositories"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^https://([^/]+)/?(.*)$|^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+                );
+            }
+        }
+        if let Some(ref value) = self.default_code_repository {
+            errors.check_length(
+                &field_path(path, "default_code_repository"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "default_code_repository"),
+                &value,
+                "^https://([^/]+)/?(.*)$|^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+            );
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, ".*");
+        }
+        if let Some(ref value) = self.lifecycle_config_name {
+            errors.check_length(
+                &field_path(path, "lifecycle_config_name"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "lifecycle_config_name"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        if let Some(ref value) = self.security_group_ids {
+            errors.check_length(
+                &field_path(path, "security_group_ids"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(32));
+                errors.check_pattern(&item_path, &item, "[-0-9a-zA-Z]+");
+            }
+        }
+        if let Some(ref value) = self.subnet_id {
+            errors.check_length(
+                &field_path(path, "subnet_id"),
+                value.chars().count(),
+                None,
+                Some(32),
+            );
+            errors.check_pattern(&field_path(path, "subnet_id"), &value, "[-0-9a-zA-Z]+");
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.volume_size_in_gb {
+            errors.check_range(
+                &field_path(path, "volume_size_in_gb"),
+                *value,
+                Some(5),
+                Some(16384),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNotebookInstanceLifecycleConfigInput {
     /// <p>The name of the lifecycle configuration.</p>
     #[serde(rename = "NotebookInstanceLifecycleConfigName")]
@@ -707,8 +1579,38 @@ pub struct CreateNotebookInstanceLifecycleConfigInput {
     pub on_start: Option<Vec<NotebookInstanceLifecycleHook>>,
 }
 
+impl Validate for CreateNotebookInstanceLifecycleConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            self.notebook_instance_lifecycle_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            &self.notebook_instance_lifecycle_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+        if let Some(ref value) = self.on_create {
+            errors.check_length(&field_path(path, "on_create"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "on_create"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.on_start {
+            errors.check_length(&field_path(path, "on_start"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "on_start"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNotebookInstanceLifecycleConfigOutput {
     /// <p>The Amazon Resource Name (ARN) of the lifecycle configuration.</p>
     #[serde(rename = "NotebookInstanceLifecycleConfigArn")]
@@ -717,7 +1619,7 @@ pub struct CreateNotebookInstanceLifecycleConfigOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNotebookInstanceOutput {
     /// <p>The Amazon Resource Name (ARN) of the notebook instance. </p>
     #[serde(rename = "NotebookInstanceArn")]
@@ -726,6 +1628,7 @@ pub struct CreateNotebookInstanceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePresignedNotebookInstanceUrlInput {
     /// <p>The name of the notebook instance.</p>
     #[serde(rename = "NotebookInstanceName")]
@@ -736,8 +1639,32 @@ pub struct CreatePresignedNotebookInstanceUrlInput {
     pub session_expiration_duration_in_seconds: Option<i64>,
 }
 
+impl Validate for CreatePresignedNotebookInstanceUrlInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+        if let Some(ref value) = self.session_expiration_duration_in_seconds {
+            errors.check_range(
+                &field_path(path, "session_expiration_duration_in_seconds"),
+                *value,
+                Some(1800),
+                Some(43200),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePresignedNotebookInstanceUrlOutput {
     /// <p>A JSON object that contains the URL string. </p>
     #[serde(rename = "AuthorizedUrl")]
@@ -746,6 +1673,7 @@ pub struct CreatePresignedNotebookInstanceUrlOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTrainingJobRequest {
     /// <p>The registry path of the Docker image that contains the training algorithm and algorithm-specific metadata, including the input mode. For more information about algorithms provided by Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/algos.html">Algorithms</a>. For information about providing your own algorithms, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/your-algorithms.html">Using Your Own Algorithms with Amazon SageMaker</a>. </p>
     #[serde(rename = "AlgorithmSpecification")]
@@ -799,8 +1727,81 @@ pub struct CreateTrainingJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for CreateTrainingJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.algorithm_specification
+            .validate_at(&field_path(path, "algorithm_specification"), errors);
+        if let Some(ref value) = self.checkpoint_config {
+            value.validate_at(&field_path(path, "checkpoint_config"), errors);
+        }
+        if let Some(ref value) = self.hyper_parameters {
+            errors.check_length(
+                &field_path(path, "hyper_parameters"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "hyper_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2500));
+                errors.check_pattern(&item_path, &item, ".*");
+            }
+        }
+        if let Some(ref value) = self.input_data_config {
+            errors.check_length(
+                &field_path(path, "input_data_config"),
+                value.len(),
+                Some(1),
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_data_config"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        self.resource_config
+            .validate_at(&field_path(path, "resource_config"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        self.stopping_condition
+            .validate_at(&field_path(path, "stopping_condition"), errors);
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "training_job_name"),
+            self.training_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "training_job_name"),
+            &self.training_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTrainingJobResponse {
     /// <p>The Amazon Resource Name (ARN) of the training job.</p>
     #[serde(rename = "TrainingJobArn")]
@@ -808,6 +1809,7 @@ pub struct CreateTrainingJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTransformJobRequest {
     /// <p>Specifies the number of records to include in a mini-batch for an HTTP inference request. A <i>record</i> <i/> is a single unit of input data that inference can be made on. For example, a single line in a CSV file is a record. </p> <p>To enable the batch strategy, you must set <code>SplitType</code> to <code>Line</code>, <code>RecordIO</code>, or <code>TFRecord</code>.</p> <p>To use only one record when making an HTTP invocation request to a container, set <code>BatchStrategy</code> to <code>SingleRecord</code> and <code>SplitType</code> to <code>Line</code>.</p> <p>To fit as many records in a mini-batch as can fit within the <code>MaxPayloadInMB</code> limit, set <code>BatchStrategy</code> to <code>MultiRecord</code> and <code>SplitType</code> to <code>Line</code>.</p>
     #[serde(rename = "BatchStrategy")]
@@ -850,8 +1852,64 @@ pub struct CreateTransformJobRequest {
     pub transform_resources: TransformResources,
 }
 
+impl Validate for CreateTransformJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_processing {
+            value.validate_at(&field_path(path, "data_processing"), errors);
+        }
+        if let Some(ref value) = self.environment {
+            errors.check_length(
+                &field_path(path, "environment"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10240));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "model_name"),
+            self.model_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "model_name"),
+            &self.model_name,
+            "^[a-zA-Z0-9]([\\-a-zA-Z0-9]*[a-zA-Z0-9])?",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.transform_input
+            .validate_at(&field_path(path, "transform_input"), errors);
+        errors.check_length(
+            &field_path(path, "transform_job_name"),
+            self.transform_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_job_name"),
+            &self.transform_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        self.transform_output
+            .validate_at(&field_path(path, "transform_output"), errors);
+        self.transform_resources
+            .validate_at(&field_path(path, "transform_resources"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTransformJobResponse {
     /// <p>The Amazon Resource Name (ARN) of the transform job.</p>
     #[serde(rename = "TransformJobArn")]
@@ -859,6 +1917,7 @@ pub struct CreateTransformJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWorkteamRequest {
     /// <p>A description of the work team.</p>
     #[serde(rename = "Description")]
@@ -879,8 +1938,51 @@ pub struct CreateWorkteamRequest {
     pub workteam_name: String,
 }
 
+impl Validate for CreateWorkteamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(&field_path(path, "description"), &self.description, ".+");
+        errors.check_length(
+            &field_path(path, "member_definitions"),
+            self.member_definitions.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.member_definitions.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "member_definitions"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.notification_configuration {
+            value.validate_at(&field_path(path, "notification_configuration"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "workteam_name"),
+            self.workteam_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_name"),
+            &self.workteam_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWorkteamResponse {
     /// <p>The Amazon Resource Name (ARN) of the work team. You can use this ARN to identify the work team.</p>
     #[serde(rename = "WorkteamArn")]
@@ -905,6 +2007,27 @@ pub struct DataProcessing {
     pub output_filter: Option<String>,
 }
 
+impl Validate for DataProcessing {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input_filter {
+            errors.check_length(
+                &field_path(path, "input_filter"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.output_filter {
+            errors.check_length(
+                &field_path(path, "output_filter"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+        }
+    }
+}
+
 /// <p>Describes the location of the channel data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataSource {
@@ -918,63 +2041,207 @@ pub struct DataSource {
     pub s3_data_source: Option<S3DataSource>,
 }
 
+impl Validate for DataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.file_system_data_source {
+            value.validate_at(&field_path(path, "file_system_data_source"), errors);
+        }
+        if let Some(ref value) = self.s3_data_source {
+            value.validate_at(&field_path(path, "s3_data_source"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAlgorithmInput {
     /// <p>The name of the algorithm to delete.</p>
     #[serde(rename = "AlgorithmName")]
     pub algorithm_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl Validate for DeleteAlgorithmInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "algorithm_name"),
+            self.algorithm_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "algorithm_name"),
+            &self.algorithm_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCodeRepositoryInput {
     /// <p>The name of the Git repository to delete.</p>
     #[serde(rename = "CodeRepositoryName")]
     pub code_repository_name: String,
 }
 
+impl Validate for DeleteCodeRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "code_repository_name"),
+            self.code_repository_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "code_repository_name"),
+            &self.code_repository_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEndpointConfigInput {
     /// <p>The name of the endpoint configuration that you want to delete.</p>
     #[serde(rename = "EndpointConfigName")]
     pub endpoint_config_name: String,
 }
 
+impl Validate for DeleteEndpointConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_config_name"),
+            self.endpoint_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_config_name"),
+            &self.endpoint_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEndpointInput {
     /// <p>The name of the endpoint that you want to delete.</p>
     #[serde(rename = "EndpointName")]
     pub endpoint_name: String,
 }
 
+impl Validate for DeleteEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_name"),
+            self.endpoint_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_name"),
+            &self.endpoint_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteModelInput {
     /// <p>The name of the model to delete.</p>
     #[serde(rename = "ModelName")]
     pub model_name: String,
 }
 
+impl Validate for DeleteModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "model_name"),
+            self.model_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "model_name"),
+            &self.model_name,
+            "^[a-zA-Z0-9]([\\-a-zA-Z0-9]*[a-zA-Z0-9])?",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteModelPackageInput {
     /// <p>The name of the model package. The name must have 1 to 63 characters. Valid characters are a-z, A-Z, 0-9, and - (hyphen).</p>
     #[serde(rename = "ModelPackageName")]
     pub model_package_name: String,
 }
 
+impl Validate for DeleteModelPackageInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "model_package_name"),
+            self.model_package_name.chars().count(),
+            Some(1),
+            Some(176),
+        );
+        errors.check_pattern(&field_path(path, "model_package_name"), &self.model_package_name, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)(\\/[0-9]{1,5})?$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNotebookInstanceInput {
     /// <p>The name of the Amazon SageMaker notebook instance to delete.</p>
     #[serde(rename = "NotebookInstanceName")]
     pub notebook_instance_name: String,
 }
 
+impl Validate for DeleteNotebookInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNotebookInstanceLifecycleConfigInput {
     /// <p>The name of the lifecycle configuration to delete.</p>
     #[serde(rename = "NotebookInstanceLifecycleConfigName")]
     pub notebook_instance_lifecycle_config_name: String,
 }
 
+impl Validate for DeleteNotebookInstanceLifecycleConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            self.notebook_instance_lifecycle_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            &self.notebook_instance_lifecycle_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsInput {
     /// <p>The Amazon Resource Name (ARN) of the resource whose tags you want to delete.</p>
     #[serde(rename = "ResourceArn")]
@@ -984,19 +2251,63 @@ pub struct DeleteTagsInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for DeleteTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:aws[a-z-]*:sagemaker:[a-z0-9-]*:[0-9]{12}:.+",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTagsOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWorkteamRequest {
     /// <p>The name of the work team to delete.</p>
     #[serde(rename = "WorkteamName")]
     pub workteam_name: String,
 }
 
+impl Validate for DeleteWorkteamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "workteam_name"),
+            self.workteam_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_name"),
+            &self.workteam_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWorkteamResponse {
     /// <p>Returns <code>true</code> if the work team was successfully deleted; otherwise, returns <code>false</code>.</p>
     #[serde(rename = "Success")]
@@ -1005,7 +2316,7 @@ pub struct DeleteWorkteamResponse {
 
 /// <p>Gets the Amazon EC2 Container Registry path of the docker image of the model that is hosted in this <a>ProductionVariant</a>.</p> <p>If you used the <code>registry/repository[:tag]</code> form to specify the image path of the primary container when you created the model hosted in this <code>ProductionVariant</code>, the path resolves to a path of the form <code>registry/repository[@digest]</code>. A digest is a hash value that identifies a specific version of an image. For information about Amazon ECR paths, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/docker-pull-ecr-image.html">Pulling an Image</a> in the <i>Amazon ECR User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeployedImage {
     /// <p>The date and time when the image path for the model resolved to the <code>ResolvedImage</code> </p>
     #[serde(rename = "ResolutionTime")]
@@ -1022,14 +2333,27 @@ pub struct DeployedImage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAlgorithmInput {
     /// <p>The name of the algorithm to describe.</p>
     #[serde(rename = "AlgorithmName")]
     pub algorithm_name: String,
 }
 
+impl Validate for DescribeAlgorithmInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "algorithm_name"),
+            self.algorithm_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "algorithm_name"), &self.algorithm_name, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAlgorithmOutput {
     /// <p>The Amazon Resource Name (ARN) of the algorithm.</p>
     #[serde(rename = "AlgorithmArn")]
@@ -1072,14 +2396,31 @@ pub struct DescribeAlgorithmOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCodeRepositoryInput {
     /// <p>The name of the Git repository to describe.</p>
     #[serde(rename = "CodeRepositoryName")]
     pub code_repository_name: String,
 }
 
+impl Validate for DescribeCodeRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "code_repository_name"),
+            self.code_repository_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "code_repository_name"),
+            &self.code_repository_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCodeRepositoryOutput {
     /// <p>The Amazon Resource Name (ARN) of the Git repository.</p>
     #[serde(rename = "CodeRepositoryArn")]
@@ -1100,14 +2441,31 @@ pub struct DescribeCodeRepositoryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCompilationJobRequest {
     /// <p>The name of the model compilation job that you want information about.</p>
     #[serde(rename = "CompilationJobName")]
     pub compilation_job_name: String,
 }
 
+impl Validate for DescribeCompilationJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "compilation_job_name"),
+            self.compilation_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "compilation_job_name"),
+            &self.compilation_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCompilationJobResponse {
     /// <p>The time when the model compilation job on a compilation job instance ended. For a successful or stopped job, this is when the job's model artifacts have finished uploading. For a failed job, this is when Amazon SageMaker detected that the job failed. </p>
     #[serde(rename = "CompilationEndTime")]
@@ -1153,14 +2511,31 @@ pub struct DescribeCompilationJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndpointConfigInput {
     /// <p>The name of the endpoint configuration.</p>
     #[serde(rename = "EndpointConfigName")]
     pub endpoint_config_name: String,
 }
 
+impl Validate for DescribeEndpointConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_config_name"),
+            self.endpoint_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_config_name"),
+            &self.endpoint_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEndpointConfigOutput {
     /// <p>A timestamp that shows when the endpoint configuration was created.</p>
     #[serde(rename = "CreationTime")]
@@ -1181,14 +2556,31 @@ pub struct DescribeEndpointConfigOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEndpointInput {
     /// <p>The name of the endpoint.</p>
     #[serde(rename = "EndpointName")]
     pub endpoint_name: String,
 }
 
+impl Validate for DescribeEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_name"),
+            self.endpoint_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_name"),
+            &self.endpoint_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEndpointOutput {
     /// <p>A timestamp that shows when the endpoint was created.</p>
     #[serde(rename = "CreationTime")]
@@ -1219,14 +2611,31 @@ pub struct DescribeEndpointOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHyperParameterTuningJobRequest {
     /// <p>The name of the tuning job to describe.</p>
     #[serde(rename = "HyperParameterTuningJobName")]
     pub hyper_parameter_tuning_job_name: String,
 }
 
+impl Validate for DescribeHyperParameterTuningJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            self.hyper_parameter_tuning_job_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            &self.hyper_parameter_tuning_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,31}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeHyperParameterTuningJobResponse {
     /// <p>A <a>TrainingJobSummary</a> object that describes the training job that completed with the best current <a>HyperParameterTuningJobObjective</a>.</p>
     #[serde(rename = "BestTrainingJob")]
@@ -1280,14 +2689,31 @@ pub struct DescribeHyperParameterTuningJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeLabelingJobRequest {
     /// <p>The name of the labeling job to return information for.</p>
     #[serde(rename = "LabelingJobName")]
     pub labeling_job_name: String,
 }
 
+impl Validate for DescribeLabelingJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "labeling_job_name"),
+            self.labeling_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "labeling_job_name"),
+            &self.labeling_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeLabelingJobResponse {
     /// <p>The date and time that the labeling job was created.</p>
     #[serde(rename = "CreationTime")]
@@ -1353,14 +2779,31 @@ pub struct DescribeLabelingJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeModelInput {
     /// <p>The name of the model.</p>
     #[serde(rename = "ModelName")]
     pub model_name: String,
 }
 
+impl Validate for DescribeModelInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "model_name"),
+            self.model_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "model_name"),
+            &self.model_name,
+            "^[a-zA-Z0-9]([\\-a-zA-Z0-9]*[a-zA-Z0-9])?",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeModelOutput {
     /// <p>The containers in the inference pipeline.</p>
     #[serde(rename = "Containers")]
@@ -1393,14 +2836,27 @@ pub struct DescribeModelOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeModelPackageInput {
     /// <p>The name of the model package to describe.</p>
     #[serde(rename = "ModelPackageName")]
     pub model_package_name: String,
 }
 
+impl Validate for DescribeModelPackageInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "model_package_name"),
+            self.model_package_name.chars().count(),
+            Some(1),
+            Some(176),
+        );
+        errors.check_pattern(&field_path(path, "model_package_name"), &self.model_package_name, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)(\\/[0-9]{1,5})?$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeModelPackageOutput {
     /// <p>Whether the model package is certified for listing on AWS Marketplace.</p>
     #[serde(rename = "CertifyForMarketplace")]
@@ -1440,21 +2896,55 @@ pub struct DescribeModelPackageOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNotebookInstanceInput {
     /// <p>The name of the notebook instance that you want information about.</p>
     #[serde(rename = "NotebookInstanceName")]
     pub notebook_instance_name: String,
 }
 
+impl Validate for DescribeNotebookInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNotebookInstanceLifecycleConfigInput {
     /// <p>The name of the lifecycle configuration to describe.</p>
     #[serde(rename = "NotebookInstanceLifecycleConfigName")]
     pub notebook_instance_lifecycle_config_name: String,
 }
 
+impl Validate for DescribeNotebookInstanceLifecycleConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            self.notebook_instance_lifecycle_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            &self.notebook_instance_lifecycle_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeNotebookInstanceLifecycleConfigOutput {
     /// <p>A timestamp that tells when the lifecycle configuration was created.</p>
     #[serde(rename = "CreationTime")]
@@ -1483,7 +2973,7 @@ pub struct DescribeNotebookInstanceLifecycleConfigOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeNotebookInstanceOutput {
     /// <p>A list of the Elastic Inference (EI) instance types associated with this notebook instance. Currently only one EI instance type can be associated with a notebook instance. For more information, see <a href="sagemaker/latest/dg/ei.html">Using Elastic Inference in Amazon SageMaker</a>.</p>
     #[serde(rename = "AcceleratorTypes")]
@@ -1568,14 +3058,31 @@ pub struct DescribeNotebookInstanceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSubscribedWorkteamRequest {
     /// <p>The Amazon Resource Name (ARN) of the subscribed work team to describe.</p>
     #[serde(rename = "WorkteamArn")]
     pub workteam_arn: String,
 }
 
+impl Validate for DescribeSubscribedWorkteamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "workteam_arn"),
+            self.workteam_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_arn"),
+            &self.workteam_arn,
+            "arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:workteam/.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSubscribedWorkteamResponse {
     /// <p>A <code>Workteam</code> instance that contains information about the work team.</p>
     #[serde(rename = "SubscribedWorkteam")]
@@ -1583,14 +3090,31 @@ pub struct DescribeSubscribedWorkteamResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrainingJobRequest {
     /// <p>The name of the training job.</p>
     #[serde(rename = "TrainingJobName")]
     pub training_job_name: String,
 }
 
+impl Validate for DescribeTrainingJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "training_job_name"),
+            self.training_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "training_job_name"),
+            &self.training_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrainingJobResponse {
     /// <p>Information about the algorithm used for training, and algorithm metadata. </p>
     #[serde(rename = "AlgorithmSpecification")]
@@ -1697,14 +3221,31 @@ pub struct DescribeTrainingJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTransformJobRequest {
     /// <p>The name of the transform job that you want to view details of.</p>
     #[serde(rename = "TransformJobName")]
     pub transform_job_name: String,
 }
 
+impl Validate for DescribeTransformJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_job_name"),
+            self.transform_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_job_name"),
+            &self.transform_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTransformJobResponse {
     /// <p>Specifies the number of records to include in a mini-batch for an HTTP inference request. A <i>record</i> <i/> is a single unit of input data that inference can be made on. For example, a single line in a CSV file is a record. </p> <p>To enable the batch strategy, you must set <code>SplitType</code> to <code>Line</code>, <code>RecordIO</code>, or <code>TFRecord</code>.</p>
     #[serde(rename = "BatchStrategy")]
@@ -1769,14 +3310,31 @@ pub struct DescribeTransformJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkteamRequest {
     /// <p>The name of the work team to return a description of.</p>
     #[serde(rename = "WorkteamName")]
     pub workteam_name: String,
 }
 
+impl Validate for DescribeWorkteamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "workteam_name"),
+            self.workteam_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_name"),
+            &self.workteam_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkteamResponse {
     /// <p>A <code>Workteam</code> instance that contains information about the work team. </p>
     #[serde(rename = "Workteam")]
@@ -1785,6 +3343,7 @@ pub struct DescribeWorkteamResponse {
 
 /// <p>Specifies weight and capacity values for a production variant.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DesiredWeightAndCapacity {
     /// <p>The variant's capacity.</p>
     #[serde(rename = "DesiredInstanceCount")]
@@ -1799,9 +3358,25 @@ pub struct DesiredWeightAndCapacity {
     pub variant_name: String,
 }
 
+impl Validate for DesiredWeightAndCapacity {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "variant_name"),
+            self.variant_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "variant_name"),
+            &self.variant_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 /// <p>Provides summary information for an endpoint configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EndpointConfigSummary {
     /// <p>A timestamp that shows when the endpoint configuration was created.</p>
     #[serde(rename = "CreationTime")]
@@ -1816,7 +3391,7 @@ pub struct EndpointConfigSummary {
 
 /// <p>Provides summary information for an endpoint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EndpointSummary {
     /// <p>A timestamp that shows when the endpoint was created.</p>
     #[serde(rename = "CreationTime")]
@@ -1852,8 +3427,36 @@ pub struct FileSystemDataSource {
     pub file_system_type: String,
 }
 
+impl Validate for FileSystemDataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "directory_path"),
+            self.directory_path.chars().count(),
+            None,
+            Some(4096),
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_path"),
+            &self.directory_path,
+            ".*",
+        );
+        errors.check_length(
+            &field_path(path, "file_system_id"),
+            self.file_system_id.chars().count(),
+            Some(11),
+            Some(21),
+        );
+        errors.check_pattern(
+            &field_path(path, "file_system_id"),
+            &self.file_system_id,
+            "^(fs-[0-9a-f]{8,})$",
+        );
+    }
+}
+
 /// <p><p>A conditional statement for a search expression that includes a Boolean operator, a resource property, and a value.</p> <p>If you don&#39;t specify an <code>Operator</code> and a <code>Value</code>, the filter searches for only the specified property. For example, defining a <code>Filter</code> for the <code>FailureReason</code> for the <code>TrainingJob</code> <code>Resource</code> searches for training job objects that have a value in the <code>FailureReason</code> field.</p> <p>If you specify a <code>Value</code>, but not an <code>Operator</code>, Amazon SageMaker uses the equals operator as the default.</p> <p>In search, there are several property types:</p> <dl> <dt>Metrics</dt> <dd> <p>To define a metric filter, enter a value using the form <code>&quot;Metrics.&lt;name&gt;&quot;</code>, where <code>&lt;name&gt;</code> is a metric name. For example, the following filter searches for training jobs with an <code>&quot;accuracy&quot;</code> metric greater than <code>&quot;0.9&quot;</code>:</p> <p> <code>{</code> </p> <p> <code>&quot;Name&quot;: &quot;Metrics.accuracy&quot;,</code> </p> <p> <code>&quot;Operator&quot;: &quot;GREATER<em>THAN&quot;,</code> </p> <p> <code>&quot;Value&quot;: &quot;0.9&quot;</code> </p> <p> <code>}</code> </p> </dd> <dt>HyperParameters</dt> <dd> <p>To define a hyperparameter filter, enter a value with the form <code>&quot;HyperParameters.&lt;name&gt;&quot;</code>. Decimal hyperparameter values are treated as a decimal in a comparison if the specified <code>Value</code> is also a decimal value. If the specified <code>Value</code> is an integer, the decimal hyperparameter values are treated as integers. For example, the following filter is satisfied by training jobs with a <code>&quot;learning</em>rate&quot;</code> hyperparameter that is less than <code>&quot;0.5&quot;</code>:</p> <p> <code> {</code> </p> <p> <code> &quot;Name&quot;: &quot;HyperParameters.learning<em>rate&quot;,</code> </p> <p> <code> &quot;Operator&quot;: &quot;LESS</em>THAN&quot;,</code> </p> <p> <code> &quot;Value&quot;: &quot;0.5&quot;</code> </p> <p> <code> }</code> </p> </dd> <dt>Tags</dt> <dd> <p>To define a tag filter, enter a value with the form <code>&quot;Tags.&lt;key&gt;&quot;</code>.</p> </dd> </dl></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Filter {
     /// <p>A property name. For example, <code>TrainingJobName</code>. For the list of valid property names returned in a search result for each supported resource, see <a>TrainingJob</a> properties. You must specify a valid property name for the resource.</p>
     #[serde(rename = "Name")]
@@ -1868,9 +3471,30 @@ pub struct Filter {
     pub value: Option<String>,
 }
 
+impl Validate for Filter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".+");
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "value"), &value, ".+");
+        }
+    }
+}
+
 /// <p>Shows the final value for the objective metric for a training job that was launched by a hyperparameter tuning job. You define the objective metric in the <code>HyperParameterTuningJobObjective</code> parameter of <a>HyperParameterTuningJobConfig</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FinalHyperParameterTuningJobObjectiveMetric {
     /// <p>The name of the objective metric.</p>
     #[serde(rename = "MetricName")]
@@ -1885,6 +3509,7 @@ pub struct FinalHyperParameterTuningJobObjectiveMetric {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSearchSuggestionsRequest {
     /// <p>The name of the Amazon SageMaker resource to Search for. The only valid <code>Resource</code> value is <code>TrainingJob</code>.</p>
     #[serde(rename = "Resource")]
@@ -1895,8 +3520,16 @@ pub struct GetSearchSuggestionsRequest {
     pub suggestion_query: Option<SuggestionQuery>,
 }
 
+impl Validate for GetSearchSuggestionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.suggestion_query {
+            value.validate_at(&field_path(path, "suggestion_query"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSearchSuggestionsResponse {
     /// <p>A list of property names for a <code>Resource</code> that match a <code>SuggestionQuery</code>.</p>
     #[serde(rename = "PropertyNameSuggestions")]
@@ -1920,8 +3553,47 @@ pub struct GitConfig {
     pub secret_arn: Option<String>,
 }
 
+impl Validate for GitConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.branch {
+            errors.check_length(
+                &field_path(path, "branch"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "branch"), &value, "[^ ~^:?*\\[]+");
+        }
+        errors.check_length(
+            &field_path(path, "repository_url"),
+            self.repository_url.chars().count(),
+            Some(11),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "repository_url"),
+            &self.repository_url,
+            "^https://([^/]+)/?.{3,1016}$",
+        );
+        if let Some(ref value) = self.secret_arn {
+            errors.check_length(
+                &field_path(path, "secret_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "secret_arn"),
+                &value,
+                "arn:aws[a-z\\-]*:secretsmanager:[a-z0-9\\-]*:[0-9]{12}:secret:.*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies configuration details for a Git repository when the repository is updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GitConfigForUpdate {
     /// <p>The Amazon Resource Name (ARN) of the AWS Secrets Manager secret that contains the credentials used to access the git repository. The secret must have a staging label of <code>AWSCURRENT</code> and must be in the following format:</p> <p> <code>{"username": <i>UserName</i>, "password": <i>Password</i>}</code> </p>
     #[serde(rename = "SecretArn")]
@@ -1929,6 +3601,24 @@ pub struct GitConfigForUpdate {
     pub secret_arn: Option<String>,
 }
 
+impl Validate for GitConfigForUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.secret_arn {
+            errors.check_length(
+                &field_path(path, "secret_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "secret_arn"),
+                &value,
+                "arn:aws[a-z\\-]*:secretsmanager:[a-z0-9\\-]*:[0-9]{12}:secret:.*",
+            );
+        }
+    }
+}
+
 /// <p>Information required for human workers to complete a labeling task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HumanTaskConfig {
@@ -1974,6 +3664,103 @@ pub struct HumanTaskConfig {
     pub workteam_arn: String,
 }
 
+impl Validate for HumanTaskConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.annotation_consolidation_config
+            .validate_at(&field_path(path, "annotation_consolidation_config"), errors);
+        if let Some(ref value) = self.max_concurrent_task_count {
+            errors.check_range(
+                &field_path(path, "max_concurrent_task_count"),
+                *value,
+                Some(1),
+                Some(5000),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "number_of_human_workers_per_data_object"),
+            self.number_of_human_workers_per_data_object,
+            Some(1),
+            Some(9),
+        );
+        errors.check_length(
+            &field_path(path, "pre_human_task_lambda_arn"),
+            self.pre_human_task_lambda_arn.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "pre_human_task_lambda_arn"),
+            &self.pre_human_task_lambda_arn,
+            "arn:aws[a-z\\-]*:lambda:[a-z0-9\\-]*:[0-9]{12}:function:.*",
+        );
+        if let Some(ref value) = self.public_workforce_task_price {
+            value.validate_at(&field_path(path, "public_workforce_task_price"), errors);
+        }
+        if let Some(ref value) = self.task_availability_lifetime_in_seconds {
+            errors.check_range(
+                &field_path(path, "task_availability_lifetime_in_seconds"),
+                *value,
+                Some(60),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_description"),
+            self.task_description.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_description"),
+            &self.task_description,
+            ".+",
+        );
+        if let Some(ref value) = self.task_keywords {
+            errors.check_length(
+                &field_path(path, "task_keywords"),
+                value.len(),
+                Some(1),
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "task_keywords"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(30));
+                errors.check_pattern(&item_path, &item, "^[A-Za-z0-9]+( [A-Za-z0-9]+)*$");
+            }
+        }
+        errors.check_range(
+            &field_path(path, "task_time_limit_in_seconds"),
+            self.task_time_limit_in_seconds,
+            Some(30),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "task_title"),
+            self.task_title.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_title"),
+            &self.task_title,
+            "^[\\t\\n\\r -\\uD7FF\\uE000-\\uFFFD]*$",
+        );
+        self.ui_config
+            .validate_at(&field_path(path, "ui_config"), errors);
+        errors.check_length(
+            &field_path(path, "workteam_arn"),
+            self.workteam_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_arn"),
+            &self.workteam_arn,
+            "arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:workteam/.*",
+        );
+    }
+}
+
 /// <p>Specifies which training algorithm to use for training jobs that a hyperparameter tuning job launches and the metrics to monitor.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HyperParameterAlgorithmSpecification {
@@ -1994,6 +3781,41 @@ pub struct HyperParameterAlgorithmSpecification {
     pub training_input_mode: String,
 }
 
+impl Validate for HyperParameterAlgorithmSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.algorithm_name {
+            errors.check_length(
+                &field_path(path, "algorithm_name"),
+                value.chars().count(),
+                Some(1),
+                Some(170),
+            );
+            errors.check_pattern(&field_path(path, "algorithm_name"), &value, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)$");
+        }
+        if let Some(ref value) = self.metric_definitions {
+            errors.check_length(
+                &field_path(path, "metric_definitions"),
+                value.len(),
+                None,
+                Some(40),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metric_definitions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.training_image {
+            errors.check_length(
+                &field_path(path, "training_image"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "training_image"), &value, ".*");
+        }
+    }
+}
+
 /// <p>Defines a hyperparameter to be used by an algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HyperParameterSpecification {
@@ -2025,6 +3847,47 @@ pub struct HyperParameterSpecification {
     pub type_: String,
 }
 
+impl Validate for HyperParameterSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_value {
+            errors.check_length(
+                &field_path(path, "default_value"),
+                value.chars().count(),
+                None,
+                Some(2500),
+            );
+            errors.check_pattern(&field_path(path, "default_value"), &value, ".*");
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\p{L}\\p{M}\\p{Z}\\p{S}\\p{N}\\p{P}]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\p{L}\\p{M}\\p{Z}\\p{S}\\p{N}\\p{P}]*",
+        );
+        if let Some(ref value) = self.range {
+            value.validate_at(&field_path(path, "range"), errors);
+        }
+    }
+}
+
 /// <p>Defines the training jobs launched by a hyperparameter tuning job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HyperParameterTrainingJobDefinition {
@@ -2072,9 +3935,64 @@ pub struct HyperParameterTrainingJobDefinition {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl Validate for HyperParameterTrainingJobDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.algorithm_specification
+            .validate_at(&field_path(path, "algorithm_specification"), errors);
+        if let Some(ref value) = self.checkpoint_config {
+            value.validate_at(&field_path(path, "checkpoint_config"), errors);
+        }
+        if let Some(ref value) = self.input_data_config {
+            errors.check_length(
+                &field_path(path, "input_data_config"),
+                value.len(),
+                Some(1),
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "input_data_config"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        self.resource_config
+            .validate_at(&field_path(path, "resource_config"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        if let Some(ref value) = self.static_hyper_parameters {
+            errors.check_length(
+                &field_path(path, "static_hyper_parameters"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "static_hyper_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2500));
+                errors.check_pattern(&item_path, &item, ".*");
+            }
+        }
+        self.stopping_condition
+            .validate_at(&field_path(path, "stopping_condition"), errors);
+        if let Some(ref value) = self.vpc_config {
+            value.validate_at(&field_path(path, "vpc_config"), errors);
+        }
+    }
+}
+
 /// <p>Specifies summary information about a training job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HyperParameterTrainingJobSummary {
     /// <p>The date and time that the training job was created.</p>
     #[serde(rename = "CreationTime")]
@@ -2141,6 +4059,22 @@ pub struct HyperParameterTuningJobConfig {
     pub training_job_early_stopping_type: Option<String>,
 }
 
+impl Validate for HyperParameterTuningJobConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hyper_parameter_tuning_job_objective {
+            value.validate_at(
+                &field_path(path, "hyper_parameter_tuning_job_objective"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.parameter_ranges {
+            value.validate_at(&field_path(path, "parameter_ranges"), errors);
+        }
+        self.resource_limits
+            .validate_at(&field_path(path, "resource_limits"), errors);
+    }
+}
+
 /// <p>Defines the objective metric for a hyperparameter tuning job. Hyperparameter tuning uses the value of this metric to evaluate the training jobs it launches, and returns the training job that results in either the highest or lowest value for this metric, depending on the value you specify for the <code>Type</code> parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HyperParameterTuningJobObjective {
@@ -2152,9 +4086,21 @@ pub struct HyperParameterTuningJobObjective {
     pub type_: String,
 }
 
+impl Validate for HyperParameterTuningJobObjective {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "metric_name"), &self.metric_name, ".+");
+    }
+}
+
 /// <p>Provides summary information about a hyperparameter tuning job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HyperParameterTuningJobSummary {
     /// <p>The date and time that the tuning job was created.</p>
     #[serde(rename = "CreationTime")]
@@ -2202,6 +4148,25 @@ pub struct HyperParameterTuningJobWarmStartConfig {
     pub warm_start_type: String,
 }
 
+impl Validate for HyperParameterTuningJobWarmStartConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "parent_hyper_parameter_tuning_jobs"),
+            self.parent_hyper_parameter_tuning_jobs.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.parent_hyper_parameter_tuning_jobs.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "parent_hyper_parameter_tuning_jobs"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Defines how to perform inference generation after a training job is run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InferenceSpecification {
@@ -2222,6 +4187,41 @@ pub struct InferenceSpecification {
     pub supported_transform_instance_types: Vec<String>,
 }
 
+impl Validate for InferenceSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "containers"),
+            self.containers.len(),
+            Some(1),
+            Some(15),
+        );
+        for (i, item) in self.containers.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "containers"), i);
+            item.validate_at(&item_path, errors);
+        }
+        for (i, item) in self.supported_content_types.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "supported_content_types"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            errors.check_pattern(&item_path, &item, ".*");
+        }
+        for (i, item) in self.supported_response_mime_types.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "supported_response_mime_types"),
+                i
+            );
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+            errors.check_pattern(&item_path, &item, "^[-\\w]+\\/.+$");
+        }
+        errors.check_length(
+            &field_path(path, "supported_transform_instance_types"),
+            self.supported_transform_instance_types.len(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Contains information about the location of input model artifacts, the name and shape of the expected data inputs, and the framework in which the model was trained.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputConfig {
@@ -2236,6 +4236,33 @@ pub struct InputConfig {
     pub s3_uri: String,
 }
 
+impl Validate for InputConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_input_config"),
+            self.data_input_config.chars().count(),
+            Some(1),
+            Some(16384),
+        );
+        errors.check_pattern(
+            &field_path(path, "data_input_config"),
+            &self.data_input_config,
+            "[\\S\\s]+",
+        );
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>For a hyperparameter of the integer type, specifies the range that a hyperparameter tuning job searches.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IntegerParameterRange {
@@ -2254,6 +4281,32 @@ pub struct IntegerParameterRange {
     pub scaling_type: Option<String>,
 }
 
+impl Validate for IntegerParameterRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "max_value"),
+            self.max_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "max_value"), &self.max_value, ".*");
+        errors.check_length(
+            &field_path(path, "min_value"),
+            self.min_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "min_value"), &self.min_value, ".*");
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*");
+    }
+}
+
 /// <p>Defines the possible values for an integer hyperparameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IntegerParameterRangeSpecification {
@@ -2265,9 +4318,28 @@ pub struct IntegerParameterRangeSpecification {
     pub min_value: String,
 }
 
+impl Validate for IntegerParameterRangeSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "max_value"),
+            self.max_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "max_value"), &self.max_value, ".*");
+        errors.check_length(
+            &field_path(path, "min_value"),
+            self.min_value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "min_value"), &self.min_value, ".*");
+    }
+}
+
 /// <p>Provides a breakdown of the number of objects labeled.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelCounters {
     /// <p>The total number of objects that could not be labeled due to an error.</p>
     #[serde(rename = "FailedNonRetryableError")]
@@ -2293,7 +4365,7 @@ pub struct LabelCounters {
 
 /// <p>Provides counts for human-labeled tasks in the labeling job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelCountersForWorkteam {
     /// <p>The total number of data objects labeled by a human worker.</p>
     #[serde(rename = "HumanLabeled")]
@@ -2325,6 +4397,40 @@ pub struct LabelingJobAlgorithmsConfig {
     pub labeling_job_resource_config: Option<LabelingJobResourceConfig>,
 }
 
+impl Validate for LabelingJobAlgorithmsConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.initial_active_learning_model_arn {
+            errors.check_length(
+                &field_path(path, "initial_active_learning_model_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "initial_active_learning_model_arn"),
+                &value,
+                "arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:model/.*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "labeling_job_algorithm_specification_arn"),
+            self.labeling_job_algorithm_specification_arn
+                .chars()
+                .count(),
+            None,
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "labeling_job_algorithm_specification_arn"),
+            &self.labeling_job_algorithm_specification_arn,
+            "arn:.*",
+        );
+        if let Some(ref value) = self.labeling_job_resource_config {
+            value.validate_at(&field_path(path, "labeling_job_resource_config"), errors);
+        }
+    }
+}
+
 /// <p>Attributes of the data specified by the customer. Use these to describe the data to be labeled.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LabelingJobDataAttributes {
@@ -2334,6 +4440,19 @@ pub struct LabelingJobDataAttributes {
     pub content_classifiers: Option<Vec<String>>,
 }
 
+impl Validate for LabelingJobDataAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content_classifiers {
+            errors.check_length(
+                &field_path(path, "content_classifiers"),
+                value.len(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Provides information about the location of input data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LabelingJobDataSource {
@@ -2342,9 +4461,16 @@ pub struct LabelingJobDataSource {
     pub s3_data_source: LabelingJobS3DataSource,
 }
 
+impl Validate for LabelingJobDataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.s3_data_source
+            .validate_at(&field_path(path, "s3_data_source"), errors);
+    }
+}
+
 /// <p>Provides summary information for a work team.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelingJobForWorkteamSummary {
     /// <p>The date and time that the labeling job was created.</p>
     #[serde(rename = "CreationTime")]
@@ -2381,9 +4507,19 @@ pub struct LabelingJobInputConfig {
     pub data_source: LabelingJobDataSource,
 }
 
-/// <p>Specifies the location of the output produced by the labeling job. </p>
+impl Validate for LabelingJobInputConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data_attributes {
+            value.validate_at(&field_path(path, "data_attributes"), errors);
+        }
+        self.data_source
+            .validate_at(&field_path(path, "data_source"), errors);
+    }
+}
+
+/// <p>Specifies the location of the output produced by the labeling job. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelingJobOutput {
     /// <p>The Amazon Resource Name (ARN) for the most recent Amazon SageMaker model trained as part of automated data labeling. </p>
     #[serde(rename = "FinalActiveLearningModelArn")]
@@ -2406,6 +4542,31 @@ pub struct LabelingJobOutputConfig {
     pub s3_output_path: String,
 }
 
+impl Validate for LabelingJobOutputConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "s3_output_path"),
+            self.s3_output_path.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_output_path"),
+            &self.s3_output_path,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>Provides configuration information for labeling jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LabelingJobResourceConfig {
@@ -2415,6 +4576,20 @@ pub struct LabelingJobResourceConfig {
     pub volume_kms_key_id: Option<String>,
 }
 
+impl Validate for LabelingJobResourceConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "volume_kms_key_id"), &value, ".*");
+        }
+    }
+}
+
 /// <p>The Amazon S3 location of the input data objects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LabelingJobS3DataSource {
@@ -2423,6 +4598,22 @@ pub struct LabelingJobS3DataSource {
     pub manifest_s3_uri: String,
 }
 
+impl Validate for LabelingJobS3DataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "manifest_s3_uri"),
+            self.manifest_s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "manifest_s3_uri"),
+            &self.manifest_s3_uri,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>A set of conditions for stopping a labeling job. If any of the conditions are met, the job is automatically stopped. You can use these conditions to control the cost of data labeling.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LabelingJobStoppingConditions {
@@ -2436,9 +4627,30 @@ pub struct LabelingJobStoppingConditions {
     pub max_percentage_of_input_dataset_labeled: Option<i64>,
 }
 
+impl Validate for LabelingJobStoppingConditions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_human_labeled_object_count {
+            errors.check_range(
+                &field_path(path, "max_human_labeled_object_count"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.max_percentage_of_input_dataset_labeled {
+            errors.check_range(
+                &field_path(path, "max_percentage_of_input_dataset_labeled"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Provides summary information about a labeling job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelingJobSummary {
     /// <p>The Amazon Resource Name (ARN) of the Lambda function used to consolidate the annotations from individual workers into a label for a data object. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-annotation-consolidation.html">Annotation Consolidation</a>.</p>
     #[serde(rename = "AnnotationConsolidationLambdaArn")]
@@ -2483,6 +4695,7 @@ pub struct LabelingJobSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAlgorithmsInput {
     /// <p>A filter that returns only algorithms created after the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2514,8 +4727,38 @@ pub struct ListAlgorithmsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListAlgorithmsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAlgorithmsOutput {
     /// <p>&gt;An array of <code>AlgorithmSummary</code> objects, each of which lists an algorithm.</p>
     #[serde(rename = "AlgorithmSummaryList")]
@@ -2527,6 +4770,7 @@ pub struct ListAlgorithmsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCodeRepositoriesInput {
     /// <p>A filter that returns only Git repositories that were created after the specified time.</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2566,8 +4810,34 @@ pub struct ListCodeRepositoriesInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListCodeRepositoriesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(&field_path(path, "name_contains"), &value, "[a-zA-Z0-9-]+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCodeRepositoriesOutput {
     /// <p><p>Gets a list of summaries of the Git repositories. Each summary specifies the following values for the repository: </p> <ul> <li> <p>Name</p> </li> <li> <p>Amazon Resource Name (ARN)</p> </li> <li> <p>Creation time</p> </li> <li> <p>Last modified time</p> </li> <li> <p>Configuration information, including the URL location of the repository and the ARN of the AWS Secrets Manager secret that contains the credentials used to access the repository.</p> </li> </ul></p>
     #[serde(rename = "CodeRepositorySummaryList")]
@@ -2579,6 +4849,7 @@ pub struct ListCodeRepositoriesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCompilationJobsRequest {
     /// <p>A filter that returns the model compilation jobs that were created after a specified time. </p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2622,8 +4893,38 @@ pub struct ListCompilationJobsRequest {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListCompilationJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCompilationJobsResponse {
     /// <p>An array of <a>CompilationJobSummary</a> objects, each describing a model compilation job. </p>
     #[serde(rename = "CompilationJobSummaries")]
@@ -2635,6 +4936,7 @@ pub struct ListCompilationJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEndpointConfigsInput {
     /// <p>A filter that returns only endpoint configurations with a creation time greater than or equal to the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2666,8 +4968,34 @@ pub struct ListEndpointConfigsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListEndpointConfigsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(&field_path(path, "name_contains"), &value, "[a-zA-Z0-9-]+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEndpointConfigsOutput {
     /// <p>An array of endpoint configurations.</p>
     #[serde(rename = "EndpointConfigs")]
@@ -2679,6 +5007,7 @@ pub struct ListEndpointConfigsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEndpointsInput {
     /// <p>A filter that returns only endpoints with a creation time greater than or equal to the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2722,8 +5051,34 @@ pub struct ListEndpointsInput {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListEndpointsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(&field_path(path, "name_contains"), &value, "[a-zA-Z0-9-]+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEndpointsOutput {
     /// <p> An array or endpoint objects. </p>
     #[serde(rename = "Endpoints")]
@@ -2735,6 +5090,7 @@ pub struct ListEndpointsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListHyperParameterTuningJobsRequest {
     /// <p>A filter that returns only tuning jobs that were created after the specified time.</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2778,8 +5134,38 @@ pub struct ListHyperParameterTuningJobsRequest {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListHyperParameterTuningJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListHyperParameterTuningJobsResponse {
     /// <p>A list of <a>HyperParameterTuningJobSummary</a> objects that describe the tuning jobs that the <code>ListHyperParameterTuningJobs</code> request returned.</p>
     #[serde(rename = "HyperParameterTuningJobSummaries")]
@@ -2791,6 +5177,7 @@ pub struct ListHyperParameterTuningJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLabelingJobsForWorkteamRequest {
     /// <p>A filter that returns only labeling jobs created after the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2825,8 +5212,49 @@ pub struct ListLabelingJobsForWorkteamRequest {
     pub workteam_arn: String,
 }
 
+impl Validate for ListLabelingJobsForWorkteamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_reference_code_contains {
+            errors.check_length(
+                &field_path(path, "job_reference_code_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_reference_code_contains"),
+                &value,
+                ".+",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "workteam_arn"),
+            self.workteam_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_arn"),
+            &self.workteam_arn,
+            "arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:workteam/.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLabelingJobsForWorkteamResponse {
     /// <p>An array of <code>LabelingJobSummary</code> objects, each describing a labeling job.</p>
     #[serde(rename = "LabelingJobSummaryList")]
@@ -2838,6 +5266,7 @@ pub struct ListLabelingJobsForWorkteamResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLabelingJobsRequest {
     /// <p>A filter that returns only labeling jobs created after the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2881,8 +5310,38 @@ pub struct ListLabelingJobsRequest {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListLabelingJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLabelingJobsResponse {
     /// <p>An array of <code>LabelingJobSummary</code> objects, each describing a labeling job.</p>
     #[serde(rename = "LabelingJobSummaryList")]
@@ -2895,6 +5354,7 @@ pub struct ListLabelingJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListModelPackagesInput {
     /// <p>A filter that returns only model packages created after the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2926,8 +5386,38 @@ pub struct ListModelPackagesInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListModelPackagesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListModelPackagesOutput {
     /// <p>An array of <code>ModelPackageSummary</code> objects, each of which lists a model package.</p>
     #[serde(rename = "ModelPackageSummaryList")]
@@ -2939,6 +5429,7 @@ pub struct ListModelPackagesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListModelsInput {
     /// <p>A filter that returns only models with a creation time greater than or equal to the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -2970,8 +5461,34 @@ pub struct ListModelsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListModelsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(&field_path(path, "name_contains"), &value, "[a-zA-Z0-9-]+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListModelsOutput {
     /// <p>An array of <code>ModelSummary</code> objects, each of which lists a model.</p>
     #[serde(rename = "Models")]
@@ -2983,6 +5500,7 @@ pub struct ListModelsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListNotebookInstanceLifecycleConfigsInput {
     /// <p>A filter that returns only lifecycle configurations that were created after the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -3022,8 +5540,34 @@ pub struct ListNotebookInstanceLifecycleConfigsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListNotebookInstanceLifecycleConfigsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(&field_path(path, "name_contains"), &value, "[a-zA-Z0-9-]+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListNotebookInstanceLifecycleConfigsOutput {
     /// <p>If the response is truncated, Amazon SageMaker returns this token. To get the next set of lifecycle configurations, use it in the next request. </p>
     #[serde(rename = "NextToken")]
@@ -3036,6 +5580,7 @@ pub struct ListNotebookInstanceLifecycleConfigsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListNotebookInstancesInput {
     /// <p>A filter that returns only notebook instances with associated with the specified git repository.</p>
     #[serde(rename = "AdditionalCodeRepositoryEquals")]
@@ -3091,8 +5636,73 @@ pub struct ListNotebookInstancesInput {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListNotebookInstancesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_code_repository_equals {
+            errors.check_length(
+                &field_path(path, "additional_code_repository_equals"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "additional_code_repository_equals"),
+                &value,
+                "^https://([^/]+)/?(.*)$|^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+            );
+        }
+        if let Some(ref value) = self.default_code_repository_contains {
+            errors.check_length(
+                &field_path(path, "default_code_repository_contains"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "default_code_repository_contains"),
+                &value,
+                "[a-zA-Z0-9-]+",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(&field_path(path, "name_contains"), &value, "[a-zA-Z0-9-]+");
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+        if let Some(ref value) = self.notebook_instance_lifecycle_config_name_contains {
+            errors.check_length(
+                &field_path(path, "notebook_instance_lifecycle_config_name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "notebook_instance_lifecycle_config_name_contains"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListNotebookInstancesOutput {
     /// <p>If the response to the previous <code>ListNotebookInstances</code> request was truncated, Amazon SageMaker returns this token. To retrieve the next set of notebook instances, use the token in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -3105,6 +5715,7 @@ pub struct ListNotebookInstancesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscribedWorkteamsRequest {
     /// <p>The maximum number of work teams to return in each page of the response.</p>
     #[serde(rename = "MaxResults")]
@@ -3120,8 +5731,38 @@ pub struct ListSubscribedWorkteamsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSubscribedWorkteamsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSubscribedWorkteamsResponse {
     /// <p>If the response is truncated, Amazon SageMaker returns this token. To retrieve the next set of work teams, use it in the subsequent request.</p>
     #[serde(rename = "NextToken")]
@@ -3133,6 +5774,7 @@ pub struct ListSubscribedWorkteamsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsInput {
     /// <p>Maximum number of tags to return.</p>
     #[serde(rename = "MaxResults")]
@@ -3147,8 +5789,36 @@ pub struct ListTagsInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(50), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "arn:aws[a-z-]*:sagemaker:[a-z0-9-]*:[0-9]{12}:.+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsOutput {
     /// <p> If response is truncated, Amazon SageMaker includes a token in the response. You can use this token in your subsequent request to fetch next set of tokens. </p>
     #[serde(rename = "NextToken")]
@@ -3161,6 +5831,7 @@ pub struct ListTagsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrainingJobsForHyperParameterTuningJobRequest {
     /// <p>The name of the tuning job whose training jobs you want to list.</p>
     #[serde(rename = "HyperParameterTuningJobName")]
@@ -3187,8 +5858,36 @@ pub struct ListTrainingJobsForHyperParameterTuningJobRequest {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListTrainingJobsForHyperParameterTuningJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            self.hyper_parameter_tuning_job_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            &self.hyper_parameter_tuning_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,31}",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTrainingJobsForHyperParameterTuningJobResponse {
     /// <p>If the result of this <code>ListTrainingJobsForHyperParameterTuningJob</code> request was truncated, the response includes a <code>NextToken</code>. To retrieve the next set of training jobs, use the token in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -3200,6 +5899,7 @@ pub struct ListTrainingJobsForHyperParameterTuningJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTrainingJobsRequest {
     /// <p>A filter that returns only training jobs created after the specified time (timestamp).</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -3243,8 +5943,38 @@ pub struct ListTrainingJobsRequest {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListTrainingJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTrainingJobsResponse {
     /// <p>If the response is truncated, Amazon SageMaker returns this token. To retrieve the next set of training jobs, use it in the subsequent request.</p>
     #[serde(rename = "NextToken")]
@@ -3256,6 +5986,7 @@ pub struct ListTrainingJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTransformJobsRequest {
     /// <p>A filter that returns only transform jobs created after the specified time.</p>
     #[serde(rename = "CreationTimeAfter")]
@@ -3299,8 +6030,38 @@ pub struct ListTransformJobsRequest {
     pub status_equals: Option<String>,
 }
 
+impl Validate for ListTransformJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "[a-zA-Z0-9\\-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTransformJobsResponse {
     /// <p>If the response is truncated, Amazon SageMaker returns this token. To retrieve the next set of transform jobs, use it in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -3312,6 +6073,7 @@ pub struct ListTransformJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWorkteamsRequest {
     /// <p>The maximum number of work teams to return in each page of the response.</p>
     #[serde(rename = "MaxResults")]
@@ -3335,8 +6097,38 @@ pub struct ListWorkteamsRequest {
     pub sort_order: Option<String>,
 }
 
+impl Validate for ListWorkteamsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWorkteamsResponse {
     /// <p>If the response is truncated, Amazon SageMaker returns this token. To retrieve the next set of work teams, use it in the subsequent request.</p>
     #[serde(rename = "NextToken")]
@@ -3356,9 +6148,17 @@ pub struct MemberDefinition {
     pub cognito_member_definition: Option<CognitoMemberDefinition>,
 }
 
+impl Validate for MemberDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cognito_member_definition {
+            value.validate_at(&field_path(path, "cognito_member_definition"), errors);
+        }
+    }
+}
+
 /// <p>The name, value, and date and time of a metric that was emitted to Amazon CloudWatch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MetricData {
     /// <p>The name of the metric.</p>
     #[serde(rename = "MetricName")]
@@ -3385,9 +6185,28 @@ pub struct MetricDefinition {
     pub regex: String,
 }
 
+impl Validate for MetricDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".+");
+        errors.check_length(
+            &field_path(path, "regex"),
+            self.regex.chars().count(),
+            Some(1),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "regex"), &self.regex, ".+");
+    }
+}
+
 /// <p>Provides information about the location that is configured for storing model artifacts. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModelArtifacts {
     /// <p>The path of the S3 object that contains the model artifacts. For example, <code>s3://bucket-name/keynameprefix/model.tar.gz</code>.</p>
     #[serde(rename = "S3ModelArtifacts")]
@@ -3418,9 +6237,73 @@ pub struct ModelPackageContainerDefinition {
     pub product_id: Option<String>,
 }
 
+impl Validate for ModelPackageContainerDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.container_hostname {
+            errors.check_length(
+                &field_path(path, "container_hostname"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "container_hostname"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "image"),
+            self.image.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(&field_path(path, "image"), &self.image, "[\\S]+");
+        if let Some(ref value) = self.image_digest {
+            errors.check_length(
+                &field_path(path, "image_digest"),
+                value.chars().count(),
+                None,
+                Some(72),
+            );
+            errors.check_pattern(
+                &field_path(path, "image_digest"),
+                &value,
+                "^[Ss][Hh][Aa]256:[0-9a-fA-F]{64}$",
+            );
+        }
+        if let Some(ref value) = self.model_data_url {
+            errors.check_length(
+                &field_path(path, "model_data_url"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "model_data_url"),
+                &value,
+                "^(https|s3)://([^/]+)/?(.*)$",
+            );
+        }
+        if let Some(ref value) = self.product_id {
+            errors.check_length(
+                &field_path(path, "product_id"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "product_id"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9])*$",
+            );
+        }
+    }
+}
+
 /// <p>Specifies the validation and image scan statuses of the model package.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModelPackageStatusDetails {
     /// <p>The status of the scan of the Docker image container for the model package.</p>
     #[serde(rename = "ImageScanStatuses")]
@@ -3433,7 +6316,7 @@ pub struct ModelPackageStatusDetails {
 
 /// <p>Represents the overall status of a model package.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModelPackageStatusItem {
     /// <p>if the overall status is <code>Failed</code>, the reason for the failure.</p>
     #[serde(rename = "FailureReason")]
@@ -3449,7 +6332,7 @@ pub struct ModelPackageStatusItem {
 
 /// <p>Provides summary information about a model package.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModelPackageSummary {
     /// <p>A timestamp that shows when the model package was created.</p>
     #[serde(rename = "CreationTime")]
@@ -3480,6 +6363,24 @@ pub struct ModelPackageValidationProfile {
     pub transform_job_definition: TransformJobDefinition,
 }
 
+impl Validate for ModelPackageValidationProfile {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "profile_name"),
+            self.profile_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "profile_name"),
+            &self.profile_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        self.transform_job_definition
+            .validate_at(&field_path(path, "transform_job_definition"), errors);
+    }
+}
+
 /// <p>Specifies batch transform jobs that Amazon SageMaker runs to validate your model package.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelPackageValidationSpecification {
@@ -3491,9 +6392,35 @@ pub struct ModelPackageValidationSpecification {
     pub validation_role: String,
 }
 
+impl Validate for ModelPackageValidationSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "validation_profiles"),
+            self.validation_profiles.len(),
+            None,
+            Some(1),
+        );
+        for (i, item) in self.validation_profiles.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "validation_profiles"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "validation_role"),
+            self.validation_role.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "validation_role"),
+            &self.validation_role,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+    }
+}
+
 /// <p>Provides summary information about a model.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModelSummary {
     /// <p>A timestamp that indicates when the model was created.</p>
     #[serde(rename = "CreationTime")]
@@ -3508,6 +6435,7 @@ pub struct ModelSummary {
 
 /// <p><p>Defines a list of <code>NestedFilters</code> objects. To satisfy the conditions specified in the <code>NestedFilters</code> call, a resource must satisfy the conditions of all of the filters.</p> <p>For example, you could define a <code>NestedFilters</code> using the training job&#39;s <code>InputDataConfig</code> property to filter on <code>Channel</code> objects. </p> <p>A <code>NestedFilters</code> object contains multiple filters. For example, to find all training jobs whose name contains <code>train</code> and that have <code>cat/data</code> in their <code>S3Uri</code> (specified in <code>InputDataConfig</code>), you need to create a <code>NestedFilters</code> object that specifies the <code>InputDataConfig</code> property with the following <code>Filter</code> objects:</p> <ul> <li> <p> <code>&#39;{Name:&quot;InputDataConfig.ChannelName&quot;, &quot;Operator&quot;:&quot;EQUALS&quot;, &quot;Value&quot;:&quot;train&quot;}&#39;,</code> </p> </li> <li> <p> <code>&#39;{Name:&quot;InputDataConfig.DataSource.S3DataSource.S3Uri&quot;, &quot;Operator&quot;:&quot;CONTAINS&quot;, &quot;Value&quot;:&quot;cat/data&quot;}&#39;</code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NestedFilters {
     /// <p>A list of filters. Each filter acts on a property. Filters must contain at least one <code>Filters</code> value. For example, a <code>NestedFilters</code> call might include a filter on the <code>PropertyName</code> parameter of the <code>InputDataConfig</code> property: <code>InputDataConfig.DataSource.S3DataSource.S3Uri</code>.</p>
     #[serde(rename = "Filters")]
@@ -3517,9 +6445,35 @@ pub struct NestedFilters {
     pub nested_property_name: String,
 }
 
+impl Validate for NestedFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filters"),
+            self.filters.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.filters.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "nested_property_name"),
+            self.nested_property_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "nested_property_name"),
+            &self.nested_property_name,
+            ".+",
+        );
+    }
+}
+
 /// <p>Provides a summary of a notebook instance lifecycle configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotebookInstanceLifecycleConfigSummary {
     /// <p>A timestamp that tells when the lifecycle configuration was created.</p>
     #[serde(rename = "CreationTime")]
@@ -3546,9 +6500,23 @@ pub struct NotebookInstanceLifecycleHook {
     pub content: Option<String>,
 }
 
+impl Validate for NotebookInstanceLifecycleHook {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content {
+            errors.check_length(
+                &field_path(path, "content"),
+                value.chars().count(),
+                Some(1),
+                Some(16384),
+            );
+            errors.check_pattern(&field_path(path, "content"), &value, "[\\S\\s]+");
+        }
+    }
+}
+
 /// <p>Provides summary information for an Amazon SageMaker notebook instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotebookInstanceSummary {
     /// <p>An array of up to three Git repositories associated with the notebook instance. These can be either the names of Git repositories stored as resources in your account, or the URL of Git repositories in <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/welcome.html">AWS CodeCommit</a> or in any other Git repository. These repositories are cloned at the same level as the default repository of your notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/nbi-git-repo.html">Associating Git Repositories with Amazon SageMaker Notebook Instances</a>.</p>
     #[serde(rename = "AdditionalCodeRepositories")]
@@ -3599,9 +6567,21 @@ pub struct NotificationConfiguration {
     pub notification_topic_arn: Option<String>,
 }
 
+impl Validate for NotificationConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.notification_topic_arn {
+            errors.check_pattern(
+                &field_path(path, "notification_topic_arn"),
+                &value,
+                "arn:aws[a-z\\-]*:sns:[a-z0-9\\-]*:[0-9]{12}:[a-zA-Z0-9_.-]*",
+            );
+        }
+    }
+}
+
 /// <p>Specifies the number of training jobs that this hyperparameter tuning job launched, categorized by the status of their objective metric. The objective metric status shows whether the final objective metric for the training job has been evaluated by the tuning job and used in the hyperparameter tuning process.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ObjectiveStatusCounters {
     /// <p>The number of training jobs whose final objective metric was not evaluated and used in the hyperparameter tuning process. This typically occurs when the training job failed or did not emit an objective metric.</p>
     #[serde(rename = "Failed")]
@@ -3628,6 +6608,22 @@ pub struct OutputConfig {
     pub target_device: String,
 }
 
+impl Validate for OutputConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_output_location"),
+            self.s3_output_location.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_output_location"),
+            &self.s3_output_location,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>Provides information about how to store model training results (model artifacts).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputDataConfig {
@@ -3640,6 +6636,31 @@ pub struct OutputDataConfig {
     pub s3_output_path: String,
 }
 
+impl Validate for OutputDataConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "s3_output_path"),
+            self.s3_output_path.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_output_path"),
+            &self.s3_output_path,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>Defines the possible values for categorical, continuous, and integer hyperparameters to be used by an algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterRange {
@@ -3657,6 +6678,29 @@ pub struct ParameterRange {
     pub integer_parameter_range_specification: Option<IntegerParameterRangeSpecification>,
 }
 
+impl Validate for ParameterRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.categorical_parameter_range_specification {
+            value.validate_at(
+                &field_path(path, "categorical_parameter_range_specification"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.continuous_parameter_range_specification {
+            value.validate_at(
+                &field_path(path, "continuous_parameter_range_specification"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.integer_parameter_range_specification {
+            value.validate_at(
+                &field_path(path, "integer_parameter_range_specification"),
+                errors,
+            );
+        }
+    }
+}
+
 /// <p><p>Specifies ranges of integer, continuous, and categorical hyperparameters that a hyperparameter tuning job searches. The hyperparameter tuning job launches training jobs with hyperparameter values within these ranges to find the combination of values that result in the training job with the best performance as measured by the objective metric of the hyperparameter tuning job.</p> <note> <p>You can specify a maximum of 20 hyperparameters that a hyperparameter tuning job can search over. Every possible value of a categorical parameter range counts against this limit.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterRanges {
@@ -3674,6 +6718,52 @@ pub struct ParameterRanges {
     pub integer_parameter_ranges: Option<Vec<IntegerParameterRange>>,
 }
 
+impl Validate for ParameterRanges {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.categorical_parameter_ranges {
+            errors.check_length(
+                &field_path(path, "categorical_parameter_ranges"),
+                value.len(),
+                None,
+                Some(30),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "categorical_parameter_ranges"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.continuous_parameter_ranges {
+            errors.check_length(
+                &field_path(path, "continuous_parameter_ranges"),
+                value.len(),
+                None,
+                Some(30),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "continuous_parameter_ranges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.integer_parameter_ranges {
+            errors.check_length(
+                &field_path(path, "integer_parameter_ranges"),
+                value.len(),
+                None,
+                Some(30),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "integer_parameter_ranges"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>A previously completed or stopped hyperparameter tuning job to be used as a starting point for a new hyperparameter tuning job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParentHyperParameterTuningJob {
@@ -3683,6 +6773,24 @@ pub struct ParentHyperParameterTuningJob {
     pub hyper_parameter_tuning_job_name: Option<String>,
 }
 
+impl Validate for ParentHyperParameterTuningJob {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hyper_parameter_tuning_job_name {
+            errors.check_length(
+                &field_path(path, "hyper_parameter_tuning_job_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+            errors.check_pattern(
+                &field_path(path, "hyper_parameter_tuning_job_name"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,31}",
+            );
+        }
+    }
+}
+
 /// <p>Identifies a model that you want to host and the resources to deploy for hosting it. If you are deploying multiple models, tell Amazon SageMaker how to distribute traffic among the models by specifying variant weights. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProductionVariant {
@@ -3708,9 +6816,42 @@ pub struct ProductionVariant {
     pub variant_name: String,
 }
 
+impl Validate for ProductionVariant {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "initial_instance_count"),
+            self.initial_instance_count,
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "model_name"),
+            self.model_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "model_name"),
+            &self.model_name,
+            "^[a-zA-Z0-9]([\\-a-zA-Z0-9]*[a-zA-Z0-9])?",
+        );
+        errors.check_length(
+            &field_path(path, "variant_name"),
+            self.variant_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "variant_name"),
+            &self.variant_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 /// <p>Describes weight and capacities for a production variant associated with an endpoint. If you sent a request to the <code>UpdateEndpointWeightsAndCapacities</code> API and the endpoint status is <code>Updating</code>, you get different desired and current values. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProductionVariantSummary {
     /// <p>The number of instances associated with the variant.</p>
     #[serde(rename = "CurrentInstanceCount")]
@@ -3739,15 +6880,32 @@ pub struct ProductionVariantSummary {
 
 /// <p>A type of <code>SuggestionQuery</code>. A suggestion query for retrieving property names that match the specified hint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PropertyNameQuery {
     /// <p>Text that is part of a property's name. The property names of hyperparameter, metric, and tag key names that begin with the specified text in the <code>PropertyNameHint</code>.</p>
     #[serde(rename = "PropertyNameHint")]
     pub property_name_hint: String,
 }
 
+impl Validate for PropertyNameQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "property_name_hint"),
+            self.property_name_hint.chars().count(),
+            None,
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "property_name_hint"),
+            &self.property_name_hint,
+            ".*",
+        );
+    }
+}
+
 /// <p>A property name returned from a <code>GetSearchSuggestions</code> call that specifies a value in the <code>PropertyNameQuery</code> field.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PropertyNameSuggestion {
     /// <p>A suggested property name based on what you entered in the search textbox in the Amazon SageMaker console.</p>
     #[serde(rename = "PropertyName")]
@@ -3764,7 +6922,16 @@ pub struct PublicWorkforceTaskPrice {
     pub amount_in_usd: Option<USD>,
 }
 
+impl Validate for PublicWorkforceTaskPrice {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.amount_in_usd {
+            value.validate_at(&field_path(path, "amount_in_usd"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RenderUiTemplateRequest {
     /// <p>The Amazon Resource Name (ARN) that has access to the S3 objects that are used by the template.</p>
     #[serde(rename = "RoleArn")]
@@ -3777,8 +6944,27 @@ pub struct RenderUiTemplateRequest {
     pub ui_template: UiTemplate,
 }
 
+impl Validate for RenderUiTemplateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+        );
+        self.task.validate_at(&field_path(path, "task"), errors);
+        self.ui_template
+            .validate_at(&field_path(path, "ui_template"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RenderUiTemplateResponse {
     /// <p>A list of one or more <code>RenderingError</code> objects if any were encountered while rendering the template. If there were no errors, the list is empty.</p>
     #[serde(rename = "Errors")]
@@ -3790,15 +6976,28 @@ pub struct RenderUiTemplateResponse {
 
 /// <p>Contains input values for a task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RenderableTask {
     /// <p>A JSON object that contains values for the variables defined in the template. It is made available to the template under the substitution variable <code>task.input</code>. For example, if you define a variable <code>task.input.text</code> in your template, you can supply the variable in the JSON object as <code>"text": "sample text"</code>.</p>
     #[serde(rename = "Input")]
     pub input: String,
 }
 
+impl Validate for RenderableTask {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "input"),
+            self.input.chars().count(),
+            Some(2),
+            Some(128000),
+        );
+        errors.check_pattern(&field_path(path, "input"), &self.input, "[\\S\\s]+");
+    }
+}
+
 /// <p>A description of an error that occurred while rendering the template.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RenderingError {
     /// <p>A unique identifier for a specific class of errors.</p>
     #[serde(rename = "Code")]
@@ -3826,6 +7025,26 @@ pub struct ResourceConfig {
     pub volume_size_in_gb: i64,
 }
 
+impl Validate for ResourceConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "volume_kms_key_id"), &value, ".*");
+        }
+        errors.check_range(
+            &field_path(path, "volume_size_in_gb"),
+            self.volume_size_in_gb,
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Specifies the maximum number of training jobs and parallel training jobs that a hyperparameter tuning job can launch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceLimits {
@@ -3837,6 +7056,23 @@ pub struct ResourceLimits {
     pub max_parallel_training_jobs: i64,
 }
 
+impl Validate for ResourceLimits {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "max_number_of_training_jobs"),
+            self.max_number_of_training_jobs,
+            Some(1),
+            None,
+        );
+        errors.check_range(
+            &field_path(path, "max_parallel_training_jobs"),
+            self.max_parallel_training_jobs,
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Describes the S3 data source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3DataSource {
@@ -3856,12 +7092,42 @@ pub struct S3DataSource {
     pub s3_uri: String,
 }
 
-/// <p><p>A multi-expression that searches for the specified resource or resources in a search. All resource objects that satisfy the expression&#39;s condition are included in the search results. You must specify at least one subexpression, filter, or nested filter. A <code>SearchExpression</code> can contain up to twenty elements.</p> <p>A <code>SearchExpression</code> contains the following components:</p> <ul> <li> <p>A list of <code>Filter</code> objects. Each filter defines a simple Boolean expression comprised of a resource property name, Boolean operator, and value.</p> </li> <li> <p>A list of <code>NestedFilter</code> objects. Each nested filter defines a list of Boolean expressions using a list of resource properties. A nested filter is satisfied if a single object in the list satisfies all Boolean expressions.</p> </li> <li> <p>A list of <code>SearchExpression</code> objects. A search expression object can be nested in a list of search expression objects.</p> </li> <li> <p>A Boolean operator: <code>And</code> or <code>Or</code>.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SearchExpression {
-    /// <p>A list of filter objects.</p>
-    #[serde(rename = "Filters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl Validate for S3DataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attribute_names {
+            errors.check_length(
+                &field_path(path, "attribute_names"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attribute_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, ".+");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
+/// <p><p>A multi-expression that searches for the specified resource or resources in a search. All resource objects that satisfy the expression&#39;s condition are included in the search results. You must specify at least one subexpression, filter, or nested filter. A <code>SearchExpression</code> can contain up to twenty elements.</p> <p>A <code>SearchExpression</code> contains the following components:</p> <ul> <li> <p>A list of <code>Filter</code> objects. Each filter defines a simple Boolean expression comprised of a resource property name, Boolean operator, and value.</p> </li> <li> <p>A list of <code>NestedFilter</code> objects. Each nested filter defines a list of Boolean expressions using a list of resource properties. A nested filter is satisfied if a single object in the list satisfies all Boolean expressions.</p> </li> <li> <p>A list of <code>SearchExpression</code> objects. A search expression object can be nested in a list of search expression objects.</p> </li> <li> <p>A Boolean operator: <code>And</code> or <code>Or</code>.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
+pub struct SearchExpression {
+    /// <p>A list of filter objects.</p>
+    #[serde(rename = "Filters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub filters: Option<Vec<Filter>>,
     /// <p>A list of nested filter objects.</p>
     #[serde(rename = "NestedFilters")]
@@ -3877,9 +7143,45 @@ pub struct SearchExpression {
     pub sub_expressions: Option<Vec<SearchExpression>>,
 }
 
+impl Validate for SearchExpression {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.nested_filters {
+            errors.check_length(
+                &field_path(path, "nested_filters"),
+                value.len(),
+                Some(1),
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "nested_filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.sub_expressions {
+            errors.check_length(
+                &field_path(path, "sub_expressions"),
+                value.len(),
+                Some(1),
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sub_expressions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>An individual search result record that contains a single resource object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchRecord {
     /// <p>A <code>TrainingJob</code> object that is returned as part of a <code>Search</code> request.</p>
     #[serde(rename = "TrainingJob")]
@@ -3888,6 +7190,7 @@ pub struct SearchRecord {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchRequest {
     /// <p>The maximum number of results to return in a <code>SearchResponse</code>.</p>
     #[serde(rename = "MaxResults")]
@@ -3914,8 +7217,37 @@ pub struct SearchRequest {
     pub sort_order: Option<String>,
 }
 
+impl Validate for SearchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+        if let Some(ref value) = self.search_expression {
+            value.validate_at(&field_path(path, "search_expression"), errors);
+        }
+        if let Some(ref value) = self.sort_by {
+            errors.check_length(
+                &field_path(path, "sort_by"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "sort_by"), &value, ".+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchResponse {
     /// <p>If the result of the previous <code>Search</code> request was truncated, the response includes a NextToken. To retrieve the next set of results, use the token in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -3929,7 +7261,7 @@ pub struct SearchResponse {
 
 /// <p><p>An array element of <a>DescribeTrainingJobResponse$SecondaryStatusTransitions</a>. It provides additional details about a status that the training job has transitioned through. A training job can be in one of several states, for example, starting, downloading, training, or uploading. Within each state, there are a number of intermediate states. For example, within the starting state, Amazon SageMaker could be starting the training job or launching the ML instances. These transitional states are referred to as the job&#39;s secondary status. </p> <p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecondaryStatusTransition {
     /// <p>A timestamp that shows when the training job transitioned out of this secondary status state into another secondary status state or when the training job has ended.</p>
     #[serde(rename = "EndTime")]
@@ -3967,6 +7299,31 @@ pub struct SourceAlgorithm {
     pub model_data_url: Option<String>,
 }
 
+impl Validate for SourceAlgorithm {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "algorithm_name"),
+            self.algorithm_name.chars().count(),
+            Some(1),
+            Some(170),
+        );
+        errors.check_pattern(&field_path(path, "algorithm_name"), &self.algorithm_name, "(arn:aws[a-z\\-]*:sagemaker:[a-z0-9\\-]*:[0-9]{12}:[a-z\\-]*\\/)?([a-zA-Z0-9]([a-zA-Z0-9-]){0,62})(?<!-)$");
+        if let Some(ref value) = self.model_data_url {
+            errors.check_length(
+                &field_path(path, "model_data_url"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "model_data_url"),
+                &value,
+                "^(https|s3)://([^/]+)/?(.*)$",
+            );
+        }
+    }
+}
+
 /// <p>A list of algorithms that were used to create a model package.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceAlgorithmSpecification {
@@ -3975,55 +7332,189 @@ pub struct SourceAlgorithmSpecification {
     pub source_algorithms: Vec<SourceAlgorithm>,
 }
 
+impl Validate for SourceAlgorithmSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "source_algorithms"),
+            self.source_algorithms.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.source_algorithms.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "source_algorithms"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartNotebookInstanceInput {
     /// <p>The name of the notebook instance to start.</p>
     #[serde(rename = "NotebookInstanceName")]
     pub notebook_instance_name: String,
 }
 
+impl Validate for StartNotebookInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopCompilationJobRequest {
     /// <p>The name of the model compilation job to stop.</p>
     #[serde(rename = "CompilationJobName")]
     pub compilation_job_name: String,
 }
 
+impl Validate for StopCompilationJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "compilation_job_name"),
+            self.compilation_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "compilation_job_name"),
+            &self.compilation_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopHyperParameterTuningJobRequest {
     /// <p>The name of the tuning job to stop.</p>
     #[serde(rename = "HyperParameterTuningJobName")]
     pub hyper_parameter_tuning_job_name: String,
 }
 
+impl Validate for StopHyperParameterTuningJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            self.hyper_parameter_tuning_job_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "hyper_parameter_tuning_job_name"),
+            &self.hyper_parameter_tuning_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,31}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopLabelingJobRequest {
     /// <p>The name of the labeling job to stop.</p>
     #[serde(rename = "LabelingJobName")]
     pub labeling_job_name: String,
 }
 
+impl Validate for StopLabelingJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "labeling_job_name"),
+            self.labeling_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "labeling_job_name"),
+            &self.labeling_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopNotebookInstanceInput {
     /// <p>The name of the notebook instance to terminate.</p>
     #[serde(rename = "NotebookInstanceName")]
     pub notebook_instance_name: String,
 }
 
+impl Validate for StopNotebookInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopTrainingJobRequest {
     /// <p>The name of the training job to stop.</p>
     #[serde(rename = "TrainingJobName")]
     pub training_job_name: String,
 }
 
+impl Validate for StopTrainingJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "training_job_name"),
+            self.training_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "training_job_name"),
+            &self.training_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopTransformJobRequest {
     /// <p>The name of the transform job to stop.</p>
     #[serde(rename = "TransformJobName")]
     pub transform_job_name: String,
 }
 
+impl Validate for StopTransformJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transform_job_name"),
+            self.transform_job_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "transform_job_name"),
+            &self.transform_job_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 /// <p><p>Specifies a limit to how long a model training or compilation job can run. It also specifies how long you are willing to wait for a managed spot training job to complete. When the job reaches the time limit, Amazon SageMaker ends the training or compilation job. Use this API to cap model training costs.</p> <p>To stop a job, Amazon SageMaker sends the algorithm the <code>SIGTERM</code> signal, which delays job termination for 120 seconds. Algorithms can use this 120-second window to save the model artifacts, so the results of training are not lost. </p> <p>The training algorithms provided by Amazon SageMaker automatically save the intermediate results of a model training job when possible. This attempt to save artifacts is only a best effort case as model might not be in a state from which it can be saved. For example, if training has just started, the model might not be ready to save. When saved, this intermediate data is a valid model artifact. You can use it to create a model with <code>CreateModel</code>.</p> <note> <p>The Neural Topic Model (NTM) currently does not support saving intermediate model artifacts. When training NTMs, make sure that the maximum runtime is sufficient for the training job to complete.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StoppingCondition {
@@ -4037,9 +7528,30 @@ pub struct StoppingCondition {
     pub max_wait_time_in_seconds: Option<i64>,
 }
 
+impl Validate for StoppingCondition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_runtime_in_seconds {
+            errors.check_range(
+                &field_path(path, "max_runtime_in_seconds"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.max_wait_time_in_seconds {
+            errors.check_range(
+                &field_path(path, "max_wait_time_in_seconds"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Describes a work team of a vendor that does the a labelling job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscribedWorkteam {
     /// <p><p/></p>
     #[serde(rename = "ListingId")]
@@ -4064,6 +7576,7 @@ pub struct SubscribedWorkteam {
 
 /// <p>Limits the property names that are included in the response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SuggestionQuery {
     /// <p>A type of <code>SuggestionQuery</code>. Defines a property name hint. Only property names that match the specified hint are included in the response.</p>
     #[serde(rename = "PropertyNameQuery")]
@@ -4071,6 +7584,14 @@ pub struct SuggestionQuery {
     pub property_name_query: Option<PropertyNameQuery>,
 }
 
+impl Validate for SuggestionQuery {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.property_name_query {
+            value.validate_at(&field_path(path, "property_name_query"), errors);
+        }
+    }
+}
+
 /// <p>Describes a tag. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -4082,9 +7603,36 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 /// <p>Contains information about a training job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrainingJob {
     /// <p>Information about the algorithm used for training, and algorithm metadata.</p>
     #[serde(rename = "AlgorithmSpecification")]
@@ -4212,9 +7760,43 @@ pub struct TrainingJobDefinition {
     pub training_input_mode: String,
 }
 
+impl Validate for TrainingJobDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hyper_parameters {
+            errors.check_length(
+                &field_path(path, "hyper_parameters"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "hyper_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2500));
+                errors.check_pattern(&item_path, &item, ".*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "input_data_config"),
+            self.input_data_config.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.input_data_config.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "input_data_config"), i);
+            item.validate_at(&item_path, errors);
+        }
+        self.output_data_config
+            .validate_at(&field_path(path, "output_data_config"), errors);
+        self.resource_config
+            .validate_at(&field_path(path, "resource_config"), errors);
+        self.stopping_condition
+            .validate_at(&field_path(path, "stopping_condition"), errors);
+    }
+}
+
 /// <p>The numbers of training jobs launched by a hyperparameter tuning job, categorized by status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrainingJobStatusCounters {
     /// <p>The number of completed training jobs launched by the hyperparameter tuning job.</p>
     #[serde(rename = "Completed")]
@@ -4240,7 +7822,7 @@ pub struct TrainingJobStatusCounters {
 
 /// <p>Provides summary information about a training job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrainingJobSummary {
     /// <p>A timestamp that shows when the training job was created.</p>
     #[serde(rename = "CreationTime")]
@@ -4298,6 +7880,80 @@ pub struct TrainingSpecification {
     pub training_image_digest: Option<String>,
 }
 
+impl Validate for TrainingSpecification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.metric_definitions {
+            errors.check_length(
+                &field_path(path, "metric_definitions"),
+                value.len(),
+                None,
+                Some(40),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "metric_definitions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.supported_hyper_parameters {
+            errors.check_length(
+                &field_path(path, "supported_hyper_parameters"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "supported_hyper_parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.supported_tuning_job_objective_metrics {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "supported_tuning_job_objective_metrics"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "training_channels"),
+            self.training_channels.len(),
+            Some(1),
+            Some(8),
+        );
+        for (i, item) in self.training_channels.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "training_channels"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "training_image"),
+            self.training_image.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "training_image"),
+            &self.training_image,
+            "[\\S]+",
+        );
+        if let Some(ref value) = self.training_image_digest {
+            errors.check_length(
+                &field_path(path, "training_image_digest"),
+                value.chars().count(),
+                None,
+                Some(72),
+            );
+            errors.check_pattern(
+                &field_path(path, "training_image_digest"),
+                &value,
+                "^[Ss][Hh][Aa]256:[0-9a-fA-F]{64}$",
+            );
+        }
+    }
+}
+
 /// <p>Describes the location of the channel data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformDataSource {
@@ -4306,6 +7962,13 @@ pub struct TransformDataSource {
     pub s3_data_source: TransformS3DataSource,
 }
 
+impl Validate for TransformDataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.s3_data_source
+            .validate_at(&field_path(path, "s3_data_source"), errors);
+    }
+}
+
 /// <p>Describes the input source of a transform job and the way the transform job consumes it.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformInput {
@@ -4326,6 +7989,22 @@ pub struct TransformInput {
     pub split_type: Option<String>,
 }
 
+impl Validate for TransformInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "content_type"), &value, ".*");
+        }
+        self.data_source
+            .validate_at(&field_path(path, "data_source"), errors);
+    }
+}
+
 /// <p>Defines the input needed to run a transform job using the inference specification specified in the algorithm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformJobDefinition {
@@ -4356,9 +8035,33 @@ pub struct TransformJobDefinition {
     pub transform_resources: TransformResources,
 }
 
+impl Validate for TransformJobDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.environment {
+            errors.check_length(
+                &field_path(path, "environment"),
+                value.len(),
+                None,
+                Some(16),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "environment"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(10240));
+                errors.check_pattern(&item_path, &item, "[\\S\\s]*");
+            }
+        }
+        self.transform_input
+            .validate_at(&field_path(path, "transform_input"), errors);
+        self.transform_output
+            .validate_at(&field_path(path, "transform_output"), errors);
+        self.transform_resources
+            .validate_at(&field_path(path, "transform_resources"), errors);
+    }
+}
+
 /// <p>Provides a summary of a transform job. Multiple <code>TransformJobSummary</code> objects are returned as a list after in response to a <a>ListTransformJobs</a> call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TransformJobSummary {
     /// <p>A timestamp that shows when the transform Job was created.</p>
     #[serde(rename = "CreationTime")]
@@ -4406,6 +8109,40 @@ pub struct TransformOutput {
     pub s3_output_path: String,
 }
 
+impl Validate for TransformOutput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept {
+            errors.check_length(
+                &field_path(path, "accept"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "accept"), &value, ".*");
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key_id"), &value, ".*");
+        }
+        errors.check_length(
+            &field_path(path, "s3_output_path"),
+            self.s3_output_path.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_output_path"),
+            &self.s3_output_path,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>Describes the resources, including ML instance types and ML instance count, to use for transform job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformResources {
@@ -4421,6 +8158,26 @@ pub struct TransformResources {
     pub volume_kms_key_id: Option<String>,
 }
 
+impl Validate for TransformResources {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "instance_count"),
+            self.instance_count,
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.volume_kms_key_id {
+            errors.check_length(
+                &field_path(path, "volume_kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "volume_kms_key_id"), &value, ".*");
+        }
+    }
+}
+
 /// <p>Describes the S3 data source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformS3DataSource {
@@ -4432,6 +8189,22 @@ pub struct TransformS3DataSource {
     pub s3_uri: String,
 }
 
+impl Validate for TransformS3DataSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_uri"),
+            self.s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "s3_uri"),
+            &self.s3_uri,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>Represents an amount of money in United States dollars/</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct USD {
@@ -4449,6 +8222,25 @@ pub struct USD {
     pub tenth_fractions_of_a_cent: Option<i64>,
 }
 
+impl Validate for USD {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cents {
+            errors.check_range(&field_path(path, "cents"), *value, None, Some(99));
+        }
+        if let Some(ref value) = self.dollars {
+            errors.check_range(&field_path(path, "dollars"), *value, None, Some(2));
+        }
+        if let Some(ref value) = self.tenth_fractions_of_a_cent {
+            errors.check_range(
+                &field_path(path, "tenth_fractions_of_a_cent"),
+                *value,
+                None,
+                Some(9),
+            );
+        }
+    }
+}
+
 /// <p>Provided configuration information for the worker UI for a labeling job. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UiConfig {
@@ -4457,15 +8249,45 @@ pub struct UiConfig {
     pub ui_template_s3_uri: String,
 }
 
+impl Validate for UiConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ui_template_s3_uri"),
+            self.ui_template_s3_uri.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "ui_template_s3_uri"),
+            &self.ui_template_s3_uri,
+            "^(https|s3)://([^/]+)/?(.*)$",
+        );
+    }
+}
+
 /// <p>The Liquid template for the worker user interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UiTemplate {
     /// <p>The content of the Liquid template for the worker user interface.</p>
     #[serde(rename = "Content")]
     pub content: String,
 }
 
+impl Validate for UiTemplate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "content"),
+            self.content.chars().count(),
+            Some(1),
+            Some(128000),
+        );
+        errors.check_pattern(&field_path(path, "content"), &self.content, "[\\S\\s]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCodeRepositoryInput {
     /// <p>The name of the Git repository to update.</p>
     #[serde(rename = "CodeRepositoryName")]
@@ -4476,8 +8298,27 @@ pub struct UpdateCodeRepositoryInput {
     pub git_config: Option<GitConfigForUpdate>,
 }
 
+impl Validate for UpdateCodeRepositoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "code_repository_name"),
+            self.code_repository_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "code_repository_name"),
+            &self.code_repository_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}$",
+        );
+        if let Some(ref value) = self.git_config {
+            value.validate_at(&field_path(path, "git_config"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCodeRepositoryOutput {
     /// <p>The ARN of the Git repository.</p>
     #[serde(rename = "CodeRepositoryArn")]
@@ -4485,6 +8326,7 @@ pub struct UpdateCodeRepositoryOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEndpointInput {
     /// <p>The name of the new endpoint configuration.</p>
     #[serde(rename = "EndpointConfigName")]
@@ -4494,8 +8336,35 @@ pub struct UpdateEndpointInput {
     pub endpoint_name: String,
 }
 
+impl Validate for UpdateEndpointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint_config_name"),
+            self.endpoint_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_config_name"),
+            &self.endpoint_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+        errors.check_length(
+            &field_path(path, "endpoint_name"),
+            self.endpoint_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_name"),
+            &self.endpoint_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateEndpointOutput {
     /// <p>The Amazon Resource Name (ARN) of the endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -4503,6 +8372,7 @@ pub struct UpdateEndpointOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEndpointWeightsAndCapacitiesInput {
     /// <p>An object that provides new capacity and weight values for a variant.</p>
     #[serde(rename = "DesiredWeightsAndCapacities")]
@@ -4512,8 +8382,38 @@ pub struct UpdateEndpointWeightsAndCapacitiesInput {
     pub endpoint_name: String,
 }
 
+impl Validate for UpdateEndpointWeightsAndCapacitiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "desired_weights_and_capacities"),
+            self.desired_weights_and_capacities.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.desired_weights_and_capacities.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "desired_weights_and_capacities"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "endpoint_name"),
+            self.endpoint_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "endpoint_name"),
+            &self.endpoint_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateEndpointWeightsAndCapacitiesOutput {
     /// <p>The Amazon Resource Name (ARN) of the updated endpoint.</p>
     #[serde(rename = "EndpointArn")]
@@ -4521,6 +8421,7 @@ pub struct UpdateEndpointWeightsAndCapacitiesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNotebookInstanceInput {
     /// <p>A list of the Elastic Inference (EI) instance types to associate with this notebook instance. Currently only one EI instance type can be associated with a notebook instance. For more information, see <a href="sagemaker/latest/dg/ei.html">Using Elastic Inference in Amazon SageMaker</a>.</p>
     #[serde(rename = "AcceleratorTypes")]
@@ -4575,7 +8476,92 @@ pub struct UpdateNotebookInstanceInput {
     pub volume_size_in_gb: Option<i64>,
 }
 
+impl Validate for UpdateNotebookInstanceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_code_repositories {
+            errors.check_length(
+                &field_path(path, "additional_code_repositories"),
+                value.len(),
+                None,
+                Some(3),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "additional_code_repositories"),
+                    i
+                );
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^https://([^/]+)/?(.*)$|^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+                );
+            }
+        }
+        if let Some(ref value) = self.default_code_repository {
+            errors.check_length(
+                &field_path(path, "default_code_repository"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "default_code_repository"),
+                &value,
+                "^https://([^/]+)/?(.*)$|^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+            );
+        }
+        if let Some(ref value) = self.lifecycle_config_name {
+            errors.check_length(
+                &field_path(path, "lifecycle_config_name"),
+                value.chars().count(),
+                None,
+                Some(63),
+            );
+            errors.check_pattern(
+                &field_path(path, "lifecycle_config_name"),
+                &value,
+                "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "notebook_instance_name"),
+            self.notebook_instance_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_name"),
+            &self.notebook_instance_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "^arn:aws[a-z\\-]*:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+$",
+            );
+        }
+        if let Some(ref value) = self.volume_size_in_gb {
+            errors.check_range(
+                &field_path(path, "volume_size_in_gb"),
+                *value,
+                Some(5),
+                Some(16384),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNotebookInstanceLifecycleConfigInput {
     /// <p>The name of the lifecycle configuration.</p>
     #[serde(rename = "NotebookInstanceLifecycleConfigName")]
@@ -4590,15 +8576,46 @@ pub struct UpdateNotebookInstanceLifecycleConfigInput {
     pub on_start: Option<Vec<NotebookInstanceLifecycleHook>>,
 }
 
+impl Validate for UpdateNotebookInstanceLifecycleConfigInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            self.notebook_instance_lifecycle_config_name.chars().count(),
+            None,
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "notebook_instance_lifecycle_config_name"),
+            &self.notebook_instance_lifecycle_config_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9])*",
+        );
+        if let Some(ref value) = self.on_create {
+            errors.check_length(&field_path(path, "on_create"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "on_create"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.on_start {
+            errors.check_length(&field_path(path, "on_start"), value.len(), None, Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "on_start"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNotebookInstanceLifecycleConfigOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNotebookInstanceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateWorkteamRequest {
     /// <p>An updated description for the work team.</p>
     #[serde(rename = "Description")]
@@ -4617,8 +8634,48 @@ pub struct UpdateWorkteamRequest {
     pub workteam_name: String,
 }
 
+impl Validate for UpdateWorkteamRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, ".+");
+        }
+        if let Some(ref value) = self.member_definitions {
+            errors.check_length(
+                &field_path(path, "member_definitions"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "member_definitions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.notification_configuration {
+            value.validate_at(&field_path(path, "notification_configuration"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "workteam_name"),
+            self.workteam_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+        errors.check_pattern(
+            &field_path(path, "workteam_name"),
+            &self.workteam_name,
+            "^[a-zA-Z0-9](-*[a-zA-Z0-9]){0,62}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateWorkteamResponse {
     /// <p>A <code>Workteam</code> object that describes the updated work team.</p>
     #[serde(rename = "Workteam")]
@@ -4636,9 +8693,36 @@ pub struct VpcConfig {
     pub subnets: Vec<String>,
 }
 
+impl Validate for VpcConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "security_group_ids"),
+            self.security_group_ids.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.security_group_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(32));
+            errors.check_pattern(&item_path, &item, "[-0-9a-zA-Z]+");
+        }
+        errors.check_length(
+            &field_path(path, "subnets"),
+            self.subnets.len(),
+            Some(1),
+            Some(16),
+        );
+        for (i, item) in self.subnets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnets"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(32));
+            errors.check_pattern(&item_path, &item, "[-0-9a-zA-Z]+");
+        }
+    }
+}
+
 /// <p>Provides details about a labeling work team.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Workteam {
     /// <p>The date and time that the work team was created (timestamp).</p>
     #[serde(rename = "CreateDate")]
@@ -4682,7 +8766,12 @@ impl AddTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AddTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4707,7 +8796,12 @@ impl CreateAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4732,7 +8826,12 @@ impl CreateCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4770,7 +8869,12 @@ impl CreateCompilationJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4806,7 +8910,12 @@ impl CreateEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4841,7 +8950,12 @@ impl CreateEndpointConfigError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4885,7 +8999,12 @@ impl CreateHyperParameterTuningJobError {
                         CreateHyperParameterTuningJobError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4926,7 +9045,12 @@ impl CreateLabelingJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4960,7 +9084,12 @@ impl CreateModelError {
                 "ResourceLimitExceeded" => {
                     return RusotoError::Service(CreateModelError::ResourceLimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4987,7 +9116,12 @@ impl CreateModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5020,7 +9154,12 @@ impl CreateNotebookInstanceError {
                         CreateNotebookInstanceError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5057,7 +9196,12 @@ impl CreateNotebookInstanceLifecycleConfigError {
                         CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5086,7 +9230,12 @@ impl CreatePresignedNotebookInstanceUrlError {
     ) -> RusotoError<CreatePresignedNotebookInstanceUrlError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5124,7 +9273,12 @@ impl CreateTrainingJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5165,7 +9319,12 @@ impl CreateTransformJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5206,7 +9365,12 @@ impl CreateWorkteamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5234,7 +9398,12 @@ impl DeleteAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5259,7 +9428,12 @@ impl DeleteCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5284,7 +9458,12 @@ impl DeleteEndpointError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEndpointError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5309,7 +9488,12 @@ impl DeleteEndpointConfigError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEndpointConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5334,7 +9518,12 @@ impl DeleteModelError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteModelError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5359,7 +9548,12 @@ impl DeleteModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5384,7 +9578,12 @@ impl DeleteNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5411,7 +9610,12 @@ impl DeleteNotebookInstanceLifecycleConfigError {
     ) -> RusotoError<DeleteNotebookInstanceLifecycleConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5436,7 +9640,12 @@ impl DeleteTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5469,7 +9678,12 @@ impl DeleteWorkteamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5496,7 +9710,12 @@ impl DescribeAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5521,7 +9740,12 @@ impl DescribeCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5554,7 +9778,12 @@ impl DescribeCompilationJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5581,7 +9810,12 @@ impl DescribeEndpointError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5606,7 +9840,12 @@ impl DescribeEndpointConfigError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5641,7 +9880,12 @@ impl DescribeHyperParameterTuningJobError {
                         DescribeHyperParameterTuningJobError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5676,7 +9920,12 @@ impl DescribeLabelingJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5703,7 +9952,12 @@ impl DescribeModelError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5728,7 +9982,12 @@ impl DescribeModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5753,7 +10012,12 @@ impl DescribeNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5780,7 +10044,12 @@ impl DescribeNotebookInstanceLifecycleConfigError {
     ) -> RusotoError<DescribeNotebookInstanceLifecycleConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5807,7 +10076,12 @@ impl DescribeSubscribedWorkteamError {
     ) -> RusotoError<DescribeSubscribedWorkteamError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5840,7 +10114,12 @@ impl DescribeTrainingJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5875,7 +10154,12 @@ impl DescribeTransformJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5902,7 +10186,12 @@ impl DescribeWorkteamError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeWorkteamError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5927,7 +10216,12 @@ impl GetSearchSuggestionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSearchSuggestionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5952,7 +10246,12 @@ impl ListAlgorithmsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAlgorithmsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5977,7 +10276,12 @@ impl ListCodeRepositoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCodeRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6002,7 +10306,12 @@ impl ListCompilationJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCompilationJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6027,7 +10336,12 @@ impl ListEndpointConfigsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEndpointConfigsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6052,7 +10366,12 @@ impl ListEndpointsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEndpointsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6079,7 +10398,12 @@ impl ListHyperParameterTuningJobsError {
     ) -> RusotoError<ListHyperParameterTuningJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6104,7 +10428,12 @@ impl ListLabelingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLabelingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6139,7 +10468,12 @@ impl ListLabelingJobsForWorkteamError {
                         ListLabelingJobsForWorkteamError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6166,7 +10500,12 @@ impl ListModelPackagesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelPackagesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6191,7 +10530,12 @@ impl ListModelsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6218,7 +10562,12 @@ impl ListNotebookInstanceLifecycleConfigsError {
     ) -> RusotoError<ListNotebookInstanceLifecycleConfigsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6243,7 +10592,12 @@ impl ListNotebookInstancesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListNotebookInstancesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6268,7 +10622,12 @@ impl ListSubscribedWorkteamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSubscribedWorkteamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6293,7 +10652,12 @@ impl ListTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6318,7 +10682,12 @@ impl ListTrainingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTrainingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6353,7 +10722,12 @@ impl ListTrainingJobsForHyperParameterTuningJobError {
                         ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6380,7 +10754,12 @@ impl ListTransformJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTransformJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6405,7 +10784,12 @@ impl ListWorkteamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListWorkteamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6430,7 +10814,12 @@ impl RenderUiTemplateError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RenderUiTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6455,7 +10844,12 @@ impl SearchError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6488,7 +10882,12 @@ impl StartNotebookInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6521,7 +10920,12 @@ impl StopCompilationJobError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(StopCompilationJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6558,7 +10962,12 @@ impl StopHyperParameterTuningJobError {
                         StopHyperParameterTuningJobError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6591,7 +11000,12 @@ impl StopLabelingJobError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(StopLabelingJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6618,7 +11032,12 @@ impl StopNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6649,7 +11068,12 @@ impl StopTrainingJobError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(StopTrainingJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6682,7 +11106,12 @@ impl StopTransformJobError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(StopTransformJobError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6709,7 +11138,12 @@ impl UpdateCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6742,7 +11176,12 @@ impl UpdateEndpointError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6779,7 +11218,12 @@ impl UpdateEndpointWeightsAndCapacitiesError {
                         UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6814,7 +11258,12 @@ impl UpdateNotebookInstanceError {
                         UpdateNotebookInstanceError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6851,7 +11300,12 @@ impl UpdateNotebookInstanceLifecycleConfigError {
                         UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6886,7 +11340,12 @@ impl UpdateWorkteamError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7363,354 +11822,72 @@ pub trait SageMaker {
         input: UpdateWorkteamRequest,
     ) -> RusotoFuture<UpdateWorkteamResponse, UpdateWorkteamError>;
 }
-/// A client for the SageMaker API.
-#[derive(Clone)]
-pub struct SageMakerClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SageMakerClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SageMakerClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SageMakerClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SageMakerClient {
-        SageMakerClient { client, region }
-    }
-}
-
-impl SageMaker for SageMakerClient {
-    /// <p><p>Adds or overwrites one or more tags for the specified Amazon SageMaker resource. You can add tags to notebook instances, training jobs, hyperparameter tuning jobs, batch transform jobs, models, labeling jobs, work teams, endpoint configurations, and endpoints.</p> <p>Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p> <note> <p>Tags that you add to a hyperparameter tuning job by calling this API are also added to any training jobs that the hyperparameter tuning job launches after you call this API, but not to training jobs that the hyperparameter tuning job launched before you called this API. To make sure that the tags associated with a hyperparameter tuning job are also added to all training jobs that the hyperparameter tuning job launches, add the tags when you first create the tuning job by specifying them in the <code>Tags</code> parameter of <a>CreateHyperParameterTuningJob</a> </p> </note></p>
+const _: Option<&dyn SageMaker> = None;
+impl<'a, T: SageMaker + ?Sized> SageMaker for &'a T {
     fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.AddTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags(input)
     }
-
-    /// <p>Create a machine learning algorithm that you can use in Amazon SageMaker and list in the AWS Marketplace.</p>
     fn create_algorithm(
         &self,
         input: CreateAlgorithmInput,
     ) -> RusotoFuture<CreateAlgorithmOutput, CreateAlgorithmError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateAlgorithm");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAlgorithmOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAlgorithmError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_algorithm(input)
     }
-
-    /// <p>Creates a Git repository as a resource in your Amazon SageMaker account. You can associate the repository with notebook instances so that you can use Git source control for the notebooks you create. The Git repository is a resource in your Amazon SageMaker account, so it can be associated with more than one notebook instance, and it persists independently from the lifecycle of any notebook instances it is associated with.</p> <p>The repository can be hosted either in <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/welcome.html">AWS CodeCommit</a> or in any other Git repository.</p>
     fn create_code_repository(
         &self,
         input: CreateCodeRepositoryInput,
     ) -> RusotoFuture<CreateCodeRepositoryOutput, CreateCodeRepositoryError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateCodeRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCodeRepositoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateCodeRepositoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_code_repository(input)
     }
-
-    /// <p>Starts a model compilation job. After the model has been compiled, Amazon SageMaker saves the resulting model artifacts to an Amazon Simple Storage Service (Amazon S3) bucket that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts with AWS IoT Greengrass. In that case, deploy them as an ML resource.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p>A name for the compilation job</p> </li> <li> <p> Information about the input model artifacts </p> </li> <li> <p>The output location for the compiled model and the device (target) that the model runs on </p> </li> <li> <p> <code>The Amazon Resource Name (ARN) of the IAM role that Amazon SageMaker assumes to perform the model compilation job</code> </p> </li> </ul> <p>You can also provide a <code>Tag</code> to track the model compilation job's resource use and costs. The response body contains the <code>CompilationJobArn</code> for the compiled job.</p> <p>To stop a model compilation job, use <a>StopCompilationJob</a>. To get information about a particular model compilation job, use <a>DescribeCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
     fn create_compilation_job(
         &self,
         input: CreateCompilationJobRequest,
     ) -> RusotoFuture<CreateCompilationJobResponse, CreateCompilationJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateCompilationJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCompilationJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateCompilationJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_compilation_job(input)
     }
-
-    /// <p>Creates an endpoint using the endpoint configuration specified in the request. Amazon SageMaker uses the endpoint to provision resources and deploy models. You create the endpoint configuration with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpointConfig.html">CreateEndpointConfig</a> API. </p> <note> <p> Use this API only for hosting models using Amazon SageMaker hosting services. </p> <p> You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note> <p>The endpoint name must be unique within an AWS Region in your AWS account. </p> <p>When it receives the request, Amazon SageMaker creates the endpoint, launches the resources (ML compute instances), and deploys the model(s) on them. </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Creating</code>. After it creates the endpoint, it sets the status to <code>InService</code>. Amazon SageMaker can then process incoming requests for inferences. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API.</p> <p>For an example, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1.html">Exercise 1: Using the K-Means Algorithm Provided by Amazon SageMaker</a>. </p> <p>If any of the models hosted at this endpoint get model data from an Amazon S3 location, Amazon SageMaker uses AWS Security Token Service to download model artifacts from the S3 path you provided. AWS STS is activated in your IAM user account by default. If you previously deactivated AWS STS for a region, you need to reactivate AWS STS for that region. For more information, see <a href="IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS i an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p>
     fn create_endpoint(
         &self,
         input: CreateEndpointInput,
     ) -> RusotoFuture<CreateEndpointOutput, CreateEndpointError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEndpointOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_endpoint(input)
     }
-
-    /// <p>Creates an endpoint configuration that Amazon SageMaker hosting services uses to deploy models. In the configuration, you identify one or more models, created using the <code>CreateModel</code> API, to deploy and the resources that you want Amazon SageMaker to provision. Then you call the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpoint.html">CreateEndpoint</a> API.</p> <note> <p> Use this API only if you want to use Amazon SageMaker hosting services to deploy models into production. </p> </note> <p>In the request, you define one or more <code>ProductionVariant</code>s, each of which identifies a model. Each <code>ProductionVariant</code> parameter also describes the resources that you want Amazon SageMaker to provision. This includes the number and type of ML compute instances to deploy. </p> <p>If you are hosting multiple models, you also assign a <code>VariantWeight</code> to specify how much traffic you want to allocate to each model. For example, suppose that you want to host two models, A and B, and you assign traffic weight 2 for model A and 1 for model B. Amazon SageMaker distributes two-thirds of the traffic to Model A, and one-third to model B. </p>
     fn create_endpoint_config(
         &self,
         input: CreateEndpointConfigInput,
     ) -> RusotoFuture<CreateEndpointConfigOutput, CreateEndpointConfigError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateEndpointConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateEndpointConfigOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateEndpointConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_endpoint_config(input)
     }
-
-    /// <p>Starts a hyperparameter tuning job. A hyperparameter tuning job finds the best version of a model by running many training jobs on your dataset using the algorithm you choose and values for hyperparameters within ranges that you specify. It then chooses the hyperparameter values that result in a model that performs the best, as measured by an objective metric that you choose.</p>
     fn create_hyper_parameter_tuning_job(
         &self,
         input: CreateHyperParameterTuningJobRequest,
     ) -> RusotoFuture<CreateHyperParameterTuningJobResponse, CreateHyperParameterTuningJobError>
     {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateHyperParameterTuningJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHyperParameterTuningJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateHyperParameterTuningJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_hyper_parameter_tuning_job(input)
     }
-
-    /// <p>Creates a job that uses workers to label the data objects in your input dataset. You can use the labeled data to train machine learning models.</p> <p>You can select your workforce from one of three providers:</p> <ul> <li> <p>A private workforce that you create. It can include employees, contractors, and outside experts. Use a private workforce when want the data to stay within your organization or when a specific set of skills is required.</p> </li> <li> <p>One or more vendors that you select from the AWS Marketplace. Vendors provide expertise in specific areas. </p> </li> <li> <p>The Amazon Mechanical Turk workforce. This is the largest workforce, but it should only be used for public data or data that has been stripped of any personally identifiable information.</p> </li> </ul> <p>You can also use <i>automated data labeling</i> to reduce the number of data objects that need to be labeled by a human. Automated data labeling uses <i>active learning</i> to determine if a data object can be labeled by machine or if it needs to be sent to a human worker. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-automated-labeling.html">Using Automated Data Labeling</a>.</p> <p>The data objects to be labeled are contained in an Amazon S3 bucket. You create a <i>manifest file</i> that describes the location of each object. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-data.html">Using Input and Output Data</a>.</p> <p>The output can be used as the manifest file for another labeling job or as training data for your machine learning models.</p>
     fn create_labeling_job(
         &self,
         input: CreateLabelingJobRequest,
     ) -> RusotoFuture<CreateLabelingJobResponse, CreateLabelingJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateLabelingJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLabelingJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLabelingJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_labeling_job(input)
     }
-
-    /// <p>Creates a model in Amazon SageMaker. In the request, you name the model and describe a primary container. For the primary container, you specify the docker image containing inference code, artifacts (from prior training), and custom environment map that the inference code uses when you deploy the model for predictions.</p> <p>Use this API to create a model if you want to use Amazon SageMaker hosting services or run a batch transform job.</p> <p>To host your model, you create an endpoint configuration with the <code>CreateEndpointConfig</code> API, and then create an endpoint with the <code>CreateEndpoint</code> API. Amazon SageMaker then deploys all of the containers that you defined for the model in the hosting environment. </p> <p>To run a batch transform using your model, you start a job with the <code>CreateTransformJob</code> API. Amazon SageMaker uses your model and your dataset to get inferences which are then saved to a specified S3 location.</p> <p>In the <code>CreateModel</code> request, you must define a container with the <code>PrimaryContainer</code> parameter.</p> <p>In the request, you also provide an IAM role that Amazon SageMaker can assume to access model artifacts and docker image for deployment on ML compute hosting instances or for batch transform jobs. In addition, you also use the IAM role to manage permissions the inference code needs. For example, if the inference code access any other AWS resources, you grant necessary permissions via this role.</p>
     fn create_model(
         &self,
         input: CreateModelInput,
     ) -> RusotoFuture<CreateModelOutput, CreateModelError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateModelOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_model(input)
     }
-
-    /// <p>Creates a model package that you can use to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p> <p>To create a model package by specifying a Docker container that contains your inference code and the Amazon S3 location of your model artifacts, provide values for <code>InferenceSpecification</code>. To create a model from an algorithm resource that you created or subscribed to in AWS Marketplace, provide a value for <code>SourceAlgorithmSpecification</code>.</p>
     fn create_model_package(
         &self,
         input: CreateModelPackageInput,
     ) -> RusotoFuture<CreateModelPackageOutput, CreateModelPackageError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateModelPackage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateModelPackageOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateModelPackageError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_model_package(input)
     }
-
-    /// <p>Creates an Amazon SageMaker notebook instance. A notebook instance is a machine learning (ML) compute instance running on a Jupyter notebook. </p> <p>In a <code>CreateNotebookInstance</code> request, specify the type of ML compute instance that you want to run. Amazon SageMaker launches the instance, installs common libraries that you can use to explore datasets for model training, and attaches an ML storage volume to the notebook instance. </p> <p>Amazon SageMaker also provides a set of example notebooks. Each notebook demonstrates how to use Amazon SageMaker with a specific algorithm or with a machine learning framework. </p> <p>After receiving the request, Amazon SageMaker does the following:</p> <ol> <li> <p>Creates a network interface in the Amazon SageMaker VPC.</p> </li> <li> <p>(Option) If you specified <code>SubnetId</code>, Amazon SageMaker creates a network interface in your own VPC, which is inferred from the subnet ID that you provide in the input. When creating this network interface, Amazon SageMaker attaches the security group that you specified in the request to the network interface that it creates in your VPC.</p> </li> <li> <p>Launches an EC2 instance of the type specified in the request in the Amazon SageMaker VPC. If you specified <code>SubnetId</code> of your VPC, Amazon SageMaker specifies both network interfaces when launching this instance. This enables inbound traffic from your own VPC to the notebook instance, assuming that the security groups allow it.</p> </li> </ol> <p>After creating the notebook instance, Amazon SageMaker returns its Amazon Resource Name (ARN). You can't change the name of a notebook instance after you create it.</p> <p>After Amazon SageMaker creates the notebook instance, you can connect to the Jupyter server and work in Jupyter notebooks. For example, you can write code to explore a dataset that you can use for model training, train a model, host models by creating Amazon SageMaker endpoints, and validate hosted models. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
     fn create_notebook_instance(
         &self,
         input: CreateNotebookInstanceInput,
     ) -> RusotoFuture<CreateNotebookInstanceOutput, CreateNotebookInstanceError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateNotebookInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNotebookInstanceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateNotebookInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_notebook_instance(input)
     }
-
-    /// <p>Creates a lifecycle configuration that you can associate with a notebook instance. A <i>lifecycle configuration</i> is a collection of shell scripts that run when you create or start a notebook instance.</p> <p>Each lifecycle configuration script has a limit of 16384 characters.</p> <p>The value of the <code>$PATH</code> environment variable that is available to both scripts is <code>/sbin:bin:/usr/sbin:/usr/bin</code>.</p> <p>View CloudWatch Logs for notebook instance lifecycle configurations in log group <code>/aws/sagemaker/NotebookInstances</code> in log stream <code>[notebook-instance-name]/[LifecycleConfigHook]</code>.</p> <p>Lifecycle configuration scripts cannot run for longer than 5 minutes. If a script runs for longer than 5 minutes, it fails and the notebook instance is not created or started.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
     fn create_notebook_instance_lifecycle_config(
         &self,
         input: CreateNotebookInstanceLifecycleConfigInput,
@@ -7718,33 +11895,8 @@ impl SageMaker for SageMakerClient {
         CreateNotebookInstanceLifecycleConfigOutput,
         CreateNotebookInstanceLifecycleConfigError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.CreateNotebookInstanceLifecycleConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNotebookInstanceLifecycleConfigOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateNotebookInstanceLifecycleConfigError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_notebook_instance_lifecycle_config(input)
     }
-
-    /// <p><p>Returns a URL that you can use to connect to the Jupyter server from a notebook instance. In the Amazon SageMaker console, when you choose <code>Open</code> next to a notebook instance, Amazon SageMaker opens a new tab showing the Jupyter server home page from the notebook instance. The console uses this API to get the URL and show the page.</p> <p>IAM authorization policies for this API are also enforced for every HTTP request and WebSocket frame that attempts to connect to the notebook instance.For example, you can restrict access to this API and to the URL that it returns to a list of IP addresses that you specify. Use the <code>NotIpAddress</code> condition operator and the <code>aws:SourceIP</code> condition context key to specify the list of IP addresses that you want to have access to the notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/nbi-ip-filter.html">Limit Access to a Notebook Instance by IP Address</a>.</p> <note> <p>The URL that you get from a call to is valid only for 5 minutes. If you try to use the URL after the 5-minute limit expires, you are directed to the AWS console sign-in page.</p> </note></p>
     fn create_presigned_notebook_instance_url(
         &self,
         input: CreatePresignedNotebookInstanceUrlInput,
@@ -7752,661 +11904,141 @@ impl SageMaker for SageMakerClient {
         CreatePresignedNotebookInstanceUrlOutput,
         CreatePresignedNotebookInstanceUrlError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.CreatePresignedNotebookInstanceUrl",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePresignedNotebookInstanceUrlOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePresignedNotebookInstanceUrlError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_presigned_notebook_instance_url(input)
     }
-
-    /// <p>Starts a model training job. After training completes, Amazon SageMaker saves the resulting model artifacts to an Amazon S3 location that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts in a machine learning service other than Amazon SageMaker, provided that you know how to use them for inferences. </p> <p>In the request body, you provide the following: </p> <ul> <li> <p> <code>AlgorithmSpecification</code> - Identifies the training algorithm to use. </p> </li> <li> <p> <code>HyperParameters</code> - Specify these algorithm-specific parameters to enable the estimation of model parameters during training. Hyperparameters can be tuned to optimize this learning process. For a list of hyperparameters for each training algorithm provided by Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/algos.html">Algorithms</a>. </p> </li> <li> <p> <code>InputDataConfig</code> - Describes the training dataset and the Amazon S3, EFS, or FSx location where it is stored.</p> </li> <li> <p> <code>OutputDataConfig</code> - Identifies the Amazon S3 bucket where you want Amazon SageMaker to save the results of model training. </p> <p/> </li> <li> <p> <code>ResourceConfig</code> - Identifies the resources, ML compute instances, and ML storage volumes to deploy for model training. In distributed training, you specify more than one instance. </p> </li> <li> <p> <code>EnableManagedSpotTraining</code> - Optimize the cost of training machine learning models by up to 80% by using Amazon EC2 Spot instances. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/model-managed-spot-training.html">Managed Spot Training</a>. </p> </li> <li> <p> <code>RoleARN</code> - The Amazon Resource Number (ARN) that Amazon SageMaker assumes to perform tasks on your behalf during model training. You must grant this role the necessary permissions so that Amazon SageMaker can successfully complete model training. </p> </li> <li> <p> <code>StoppingCondition</code> - To help cap training costs, use <code>MaxRuntimeInSeconds</code> to set a time limit for training. Use <code>MaxWaitTimeInSeconds</code> to specify how long you are willing to to wait for a managed spot training job to complete. </p> </li> </ul> <p> For more information about Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
     fn create_training_job(
         &self,
         input: CreateTrainingJobRequest,
     ) -> RusotoFuture<CreateTrainingJobResponse, CreateTrainingJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateTrainingJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTrainingJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTrainingJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_training_job(input)
     }
-
-    /// <p>Starts a transform job. A transform job uses a trained model to get inferences on a dataset and saves these results to an Amazon S3 location that you specify.</p> <p>To perform batch transformations, you create a transform job and use the data that you have readily available.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p> <code>TransformJobName</code> - Identifies the transform job. The name must be unique within an AWS Region in an AWS account.</p> </li> <li> <p> <code>ModelName</code> - Identifies the model to use. <code>ModelName</code> must be the name of an existing Amazon SageMaker model in the same AWS Region and AWS account. For information on creating a model, see <a>CreateModel</a>.</p> </li> <li> <p> <code>TransformInput</code> - Describes the dataset to be transformed and the Amazon S3 location where it is stored.</p> </li> <li> <p> <code>TransformOutput</code> - Identifies the Amazon S3 location where you want Amazon SageMaker to save the results from the transform job.</p> </li> <li> <p> <code>TransformResources</code> - Identifies the ML compute instances for the transform job.</p> </li> </ul> <p> For more information about how batch transformation works Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/batch-transform.html">How It Works</a>. </p>
     fn create_transform_job(
         &self,
         input: CreateTransformJobRequest,
     ) -> RusotoFuture<CreateTransformJobResponse, CreateTransformJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateTransformJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTransformJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTransformJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_transform_job(input)
     }
-
-    /// <p>Creates a new work team for labeling your data. A work team is defined by one or more Amazon Cognito user pools. You must first create the user pools before you can create a work team.</p> <p>You cannot create more than 25 work teams in an account and region.</p>
     fn create_workteam(
         &self,
         input: CreateWorkteamRequest,
     ) -> RusotoFuture<CreateWorkteamResponse, CreateWorkteamError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.CreateWorkteam");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWorkteamResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateWorkteamError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_workteam(input)
     }
-
-    /// <p>Removes the specified algorithm from your account.</p>
     fn delete_algorithm(
         &self,
         input: DeleteAlgorithmInput,
     ) -> RusotoFuture<(), DeleteAlgorithmError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteAlgorithm");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAlgorithmError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_algorithm(input)
     }
-
-    /// <p>Deletes the specified Git repository from your account.</p>
     fn delete_code_repository(
         &self,
         input: DeleteCodeRepositoryInput,
     ) -> RusotoFuture<(), DeleteCodeRepositoryError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteCodeRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteCodeRepositoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_code_repository(input)
     }
-
-    /// <p>Deletes an endpoint. Amazon SageMaker frees up all of the resources that were deployed when the endpoint was created. </p> <p>Amazon SageMaker retires any custom KMS key grants associated with the endpoint, meaning you don't need to use the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_RevokeGrant.html">RevokeGrant</a> API call.</p>
     fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_endpoint(input)
     }
-
-    /// <p>Deletes an endpoint configuration. The <code>DeleteEndpointConfig</code> API deletes only the specified configuration. It does not delete endpoints created using the configuration. </p>
     fn delete_endpoint_config(
         &self,
         input: DeleteEndpointConfigInput,
     ) -> RusotoFuture<(), DeleteEndpointConfigError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteEndpointConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteEndpointConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_endpoint_config(input)
     }
-
-    /// <p>Deletes a model. The <code>DeleteModel</code> API deletes only the model entry that was created in Amazon SageMaker when you called the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API. It does not delete model artifacts, inference code, or the IAM role that you specified when creating the model. </p>
     fn delete_model(&self, input: DeleteModelInput) -> RusotoFuture<(), DeleteModelError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_model(input)
     }
-
-    /// <p>Deletes a model package.</p> <p>A model package is used to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p>
     fn delete_model_package(
         &self,
         input: DeleteModelPackageInput,
     ) -> RusotoFuture<(), DeleteModelPackageError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteModelPackage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteModelPackageError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_model_package(input)
     }
-
-    /// <p><p> Deletes an Amazon SageMaker notebook instance. Before you can delete a notebook instance, you must call the <code>StopNotebookInstance</code> API. </p> <important> <p>When you delete a notebook instance, you lose all of your data. Amazon SageMaker removes the ML compute instance, and deletes the ML storage volume and the network interface associated with the notebook instance. </p> </important></p>
     fn delete_notebook_instance(
         &self,
         input: DeleteNotebookInstanceInput,
     ) -> RusotoFuture<(), DeleteNotebookInstanceError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteNotebookInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteNotebookInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_notebook_instance(input)
     }
-
-    /// <p>Deletes a notebook instance lifecycle configuration.</p>
     fn delete_notebook_instance_lifecycle_config(
         &self,
         input: DeleteNotebookInstanceLifecycleConfigInput,
     ) -> RusotoFuture<(), DeleteNotebookInstanceLifecycleConfigError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.DeleteNotebookInstanceLifecycleConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteNotebookInstanceLifecycleConfigError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_notebook_instance_lifecycle_config(input)
     }
-
-    /// <p><p>Deletes the specified tags from an Amazon SageMaker resource.</p> <p>To list a resource&#39;s tags, use the <code>ListTags</code> API. </p> <note> <p>When you call this API to delete tags from a hyperparameter tuning job, the deleted tags are not removed from training jobs that the hyperparameter tuning job launched before you called this API.</p> </note></p>
     fn delete_tags(
         &self,
         input: DeleteTagsInput,
     ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tags(input)
     }
-
-    /// <p>Deletes an existing work team. This operation can't be undone.</p>
     fn delete_workteam(
         &self,
         input: DeleteWorkteamRequest,
     ) -> RusotoFuture<DeleteWorkteamResponse, DeleteWorkteamError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DeleteWorkteam");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWorkteamResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteWorkteamError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_workteam(input)
     }
-
-    /// <p>Returns a description of the specified algorithm that is in your account.</p>
     fn describe_algorithm(
         &self,
         input: DescribeAlgorithmInput,
     ) -> RusotoFuture<DescribeAlgorithmOutput, DescribeAlgorithmError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeAlgorithm");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAlgorithmOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeAlgorithmError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_algorithm(input)
     }
-
-    /// <p>Gets details about the specified Git repository.</p>
     fn describe_code_repository(
         &self,
         input: DescribeCodeRepositoryInput,
     ) -> RusotoFuture<DescribeCodeRepositoryOutput, DescribeCodeRepositoryError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeCodeRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCodeRepositoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeCodeRepositoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_code_repository(input)
     }
-
-    /// <p>Returns information about a model compilation job.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
     fn describe_compilation_job(
         &self,
         input: DescribeCompilationJobRequest,
     ) -> RusotoFuture<DescribeCompilationJobResponse, DescribeCompilationJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeCompilationJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCompilationJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeCompilationJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_compilation_job(input)
     }
-
-    /// <p>Returns the description of an endpoint.</p>
     fn describe_endpoint(
         &self,
         input: DescribeEndpointInput,
     ) -> RusotoFuture<DescribeEndpointOutput, DescribeEndpointError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEndpointOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeEndpointError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_endpoint(input)
     }
-
-    /// <p>Returns the description of an endpoint configuration created using the <code>CreateEndpointConfig</code> API.</p>
     fn describe_endpoint_config(
         &self,
         input: DescribeEndpointConfigInput,
     ) -> RusotoFuture<DescribeEndpointConfigOutput, DescribeEndpointConfigError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeEndpointConfig");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEndpointConfigOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeEndpointConfigError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_endpoint_config(input)
     }
-
-    /// <p>Gets a description of a hyperparameter tuning job.</p>
     fn describe_hyper_parameter_tuning_job(
         &self,
         input: DescribeHyperParameterTuningJobRequest,
     ) -> RusotoFuture<DescribeHyperParameterTuningJobResponse, DescribeHyperParameterTuningJobError>
     {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeHyperParameterTuningJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeHyperParameterTuningJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeHyperParameterTuningJobError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_hyper_parameter_tuning_job(input)
     }
-
-    /// <p>Gets information about a labeling job.</p>
     fn describe_labeling_job(
         &self,
         input: DescribeLabelingJobRequest,
     ) -> RusotoFuture<DescribeLabelingJobResponse, DescribeLabelingJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeLabelingJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLabelingJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeLabelingJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_labeling_job(input)
     }
-
-    /// <p>Describes a model that you created using the <code>CreateModel</code> API.</p>
     fn describe_model(
         &self,
         input: DescribeModelInput,
     ) -> RusotoFuture<DescribeModelOutput, DescribeModelError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeModel");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeModelOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeModelError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_model(input)
     }
-
-    /// <p>Returns a description of the specified model package, which is used to create Amazon SageMaker models or list them on AWS Marketplace.</p> <p>To create models in Amazon SageMaker, buyers can subscribe to model packages listed on AWS Marketplace.</p>
     fn describe_model_package(
         &self,
         input: DescribeModelPackageInput,
     ) -> RusotoFuture<DescribeModelPackageOutput, DescribeModelPackageError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeModelPackage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeModelPackageOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeModelPackageError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_model_package(input)
     }
-
-    /// <p>Returns information about a notebook instance.</p>
     fn describe_notebook_instance(
         &self,
         input: DescribeNotebookInstanceInput,
     ) -> RusotoFuture<DescribeNotebookInstanceOutput, DescribeNotebookInstanceError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeNotebookInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeNotebookInstanceOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeNotebookInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_notebook_instance(input)
     }
-
-    /// <p>Returns a description of a notebook instance lifecycle configuration.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
     fn describe_notebook_instance_lifecycle_config(
         &self,
         input: DescribeNotebookInstanceLifecycleConfigInput,
@@ -8414,453 +12046,98 @@ impl SageMaker for SageMakerClient {
         DescribeNotebookInstanceLifecycleConfigOutput,
         DescribeNotebookInstanceLifecycleConfigError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.DescribeNotebookInstanceLifecycleConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeNotebookInstanceLifecycleConfigOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeNotebookInstanceLifecycleConfigError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_notebook_instance_lifecycle_config(input)
     }
-
-    /// <p>Gets information about a work team provided by a vendor. It returns details about the subscription with a vendor in the AWS Marketplace.</p>
     fn describe_subscribed_workteam(
         &self,
         input: DescribeSubscribedWorkteamRequest,
     ) -> RusotoFuture<DescribeSubscribedWorkteamResponse, DescribeSubscribedWorkteamError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeSubscribedWorkteam");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSubscribedWorkteamResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSubscribedWorkteamError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_subscribed_workteam(input)
     }
-
-    /// <p>Returns information about a training job.</p>
     fn describe_training_job(
         &self,
         input: DescribeTrainingJobRequest,
     ) -> RusotoFuture<DescribeTrainingJobResponse, DescribeTrainingJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeTrainingJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrainingJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeTrainingJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_training_job(input)
     }
-
-    /// <p>Returns information about a transform job.</p>
     fn describe_transform_job(
         &self,
         input: DescribeTransformJobRequest,
     ) -> RusotoFuture<DescribeTransformJobResponse, DescribeTransformJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeTransformJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTransformJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeTransformJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_transform_job(input)
     }
-
-    /// <p>Gets information about a specific work team. You can see information such as the create date, the last updated date, membership information, and the work team's Amazon Resource Name (ARN).</p>
     fn describe_workteam(
         &self,
         input: DescribeWorkteamRequest,
     ) -> RusotoFuture<DescribeWorkteamResponse, DescribeWorkteamError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.DescribeWorkteam");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkteamResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeWorkteamError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_workteam(input)
     }
-
-    /// <p>An auto-complete API for the search functionality in the Amazon SageMaker console. It returns suggestions of possible matches for the property name to use in <code>Search</code> queries. Provides suggestions for <code>HyperParameters</code>, <code>Tags</code>, and <code>Metrics</code>.</p>
     fn get_search_suggestions(
         &self,
         input: GetSearchSuggestionsRequest,
     ) -> RusotoFuture<GetSearchSuggestionsResponse, GetSearchSuggestionsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.GetSearchSuggestions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSearchSuggestionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetSearchSuggestionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_search_suggestions(input)
     }
-
-    /// <p>Lists the machine learning algorithms that have been created.</p>
     fn list_algorithms(
         &self,
         input: ListAlgorithmsInput,
     ) -> RusotoFuture<ListAlgorithmsOutput, ListAlgorithmsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListAlgorithms");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAlgorithmsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAlgorithmsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_algorithms(input)
     }
-
-    /// <p>Gets a list of the Git repositories in your account.</p>
     fn list_code_repositories(
         &self,
         input: ListCodeRepositoriesInput,
     ) -> RusotoFuture<ListCodeRepositoriesOutput, ListCodeRepositoriesError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListCodeRepositories");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCodeRepositoriesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListCodeRepositoriesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_code_repositories(input)
     }
-
-    /// <p>Lists model compilation jobs that satisfy various filters.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about a particular model compilation job you have created, use <a>DescribeCompilationJob</a>.</p>
     fn list_compilation_jobs(
         &self,
         input: ListCompilationJobsRequest,
     ) -> RusotoFuture<ListCompilationJobsResponse, ListCompilationJobsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListCompilationJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCompilationJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListCompilationJobsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_compilation_jobs(input)
     }
-
-    /// <p>Lists endpoint configurations.</p>
     fn list_endpoint_configs(
         &self,
         input: ListEndpointConfigsInput,
     ) -> RusotoFuture<ListEndpointConfigsOutput, ListEndpointConfigsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListEndpointConfigs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEndpointConfigsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListEndpointConfigsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_endpoint_configs(input)
     }
-
-    /// <p>Lists endpoints.</p>
     fn list_endpoints(
         &self,
         input: ListEndpointsInput,
     ) -> RusotoFuture<ListEndpointsOutput, ListEndpointsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListEndpoints");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEndpointsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListEndpointsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_endpoints(input)
     }
-
-    /// <p>Gets a list of <a>HyperParameterTuningJobSummary</a> objects that describe the hyperparameter tuning jobs launched in your account.</p>
     fn list_hyper_parameter_tuning_jobs(
         &self,
         input: ListHyperParameterTuningJobsRequest,
     ) -> RusotoFuture<ListHyperParameterTuningJobsResponse, ListHyperParameterTuningJobsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListHyperParameterTuningJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListHyperParameterTuningJobsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListHyperParameterTuningJobsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_hyper_parameter_tuning_jobs(input)
     }
-
-    /// <p>Gets a list of labeling jobs.</p>
     fn list_labeling_jobs(
         &self,
         input: ListLabelingJobsRequest,
     ) -> RusotoFuture<ListLabelingJobsResponse, ListLabelingJobsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListLabelingJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLabelingJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListLabelingJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_labeling_jobs(input)
     }
-
-    /// <p>Gets a list of labeling jobs assigned to a specified work team.</p>
     fn list_labeling_jobs_for_workteam(
         &self,
         input: ListLabelingJobsForWorkteamRequest,
     ) -> RusotoFuture<ListLabelingJobsForWorkteamResponse, ListLabelingJobsForWorkteamError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListLabelingJobsForWorkteam");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLabelingJobsForWorkteamResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListLabelingJobsForWorkteamError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_labeling_jobs_for_workteam(input)
     }
-
-    /// <p>Lists the model packages that have been created.</p>
     fn list_model_packages(
         &self,
         input: ListModelPackagesInput,
     ) -> RusotoFuture<ListModelPackagesOutput, ListModelPackagesError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListModelPackages");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListModelPackagesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListModelPackagesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_model_packages(input)
     }
-
-    /// <p>Lists models created with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API.</p>
     fn list_models(
         &self,
         input: ListModelsInput,
     ) -> RusotoFuture<ListModelsOutput, ListModelsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListModels");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListModelsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListModelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_models(input)
     }
-
-    /// <p>Lists notebook instance lifestyle configurations created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
     fn list_notebook_instance_lifecycle_configs(
         &self,
         input: ListNotebookInstanceLifecycleConfigsInput,
@@ -8868,141 +12145,29 @@ impl SageMaker for SageMakerClient {
         ListNotebookInstanceLifecycleConfigsOutput,
         ListNotebookInstanceLifecycleConfigsError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.ListNotebookInstanceLifecycleConfigs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListNotebookInstanceLifecycleConfigsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListNotebookInstanceLifecycleConfigsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_notebook_instance_lifecycle_configs(input)
     }
-
-    /// <p>Returns a list of the Amazon SageMaker notebook instances in the requester's account in an AWS Region. </p>
     fn list_notebook_instances(
         &self,
         input: ListNotebookInstancesInput,
     ) -> RusotoFuture<ListNotebookInstancesOutput, ListNotebookInstancesError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListNotebookInstances");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListNotebookInstancesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListNotebookInstancesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_notebook_instances(input)
     }
-
-    /// <p>Gets a list of the work teams that you are subscribed to in the AWS Marketplace. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
     fn list_subscribed_workteams(
         &self,
         input: ListSubscribedWorkteamsRequest,
     ) -> RusotoFuture<ListSubscribedWorkteamsResponse, ListSubscribedWorkteamsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListSubscribedWorkteams");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSubscribedWorkteamsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSubscribedWorkteamsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_subscribed_workteams(input)
     }
-
-    /// <p>Returns the tags for the specified Amazon SageMaker resource.</p>
     fn list_tags(&self, input: ListTagsInput) -> RusotoFuture<ListTagsOutput, ListTagsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tags(input)
     }
-
-    /// <p>Lists training jobs.</p>
     fn list_training_jobs(
         &self,
         input: ListTrainingJobsRequest,
     ) -> RusotoFuture<ListTrainingJobsResponse, ListTrainingJobsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListTrainingJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTrainingJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTrainingJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_training_jobs(input)
     }
-
-    /// <p>Gets a list of <a>TrainingJobSummary</a> objects that describe the training jobs that a hyperparameter tuning job launched.</p>
     fn list_training_jobs_for_hyper_parameter_tuning_job(
         &self,
         input: ListTrainingJobsForHyperParameterTuningJobRequest,
@@ -9010,377 +12175,6353 @@ impl SageMaker for SageMakerClient {
         ListTrainingJobsForHyperParameterTuningJobResponse,
         ListTrainingJobsForHyperParameterTuningJobError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.ListTrainingJobsForHyperParameterTuningJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTrainingJobsForHyperParameterTuningJobResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListTrainingJobsForHyperParameterTuningJobError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_training_jobs_for_hyper_parameter_tuning_job(input)
     }
-
-    /// <p>Lists transform jobs.</p>
     fn list_transform_jobs(
         &self,
         input: ListTransformJobsRequest,
     ) -> RusotoFuture<ListTransformJobsResponse, ListTransformJobsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListTransformJobs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTransformJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTransformJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_transform_jobs(input)
     }
-
-    /// <p>Gets a list of work teams that you have defined in a region. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
     fn list_workteams(
         &self,
         input: ListWorkteamsRequest,
     ) -> RusotoFuture<ListWorkteamsResponse, ListWorkteamsError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.ListWorkteams");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWorkteamsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListWorkteamsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_workteams(input)
     }
-
-    /// <p>Renders the UI template so that you can preview the worker's experience. </p>
     fn render_ui_template(
         &self,
         input: RenderUiTemplateRequest,
     ) -> RusotoFuture<RenderUiTemplateResponse, RenderUiTemplateError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.RenderUiTemplate");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RenderUiTemplateResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RenderUiTemplateError::from_response(response))),
-                )
-            }
-        })
+        (**self).render_ui_template(input)
     }
-
-    /// <p>Finds Amazon SageMaker resources that match a search query. Matching resource objects are returned as a list of <code>SearchResult</code> objects in the response. You can sort the search results by any resource property in a ascending or descending order.</p> <p>You can query against the following value types: numerical, text, Booleans, and timestamps.</p>
     fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.Search");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<SearchResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchError::from_response(response))),
-                )
-            }
-        })
+        (**self).search(input)
     }
-
-    /// <p>Launches an ML compute instance with the latest version of the libraries and attaches your ML storage volume. After configuring the notebook instance, Amazon SageMaker sets the notebook instance status to <code>InService</code>. A notebook instance's status must be <code>InService</code> before you can connect to your Jupyter notebook. </p>
     fn start_notebook_instance(
         &self,
         input: StartNotebookInstanceInput,
     ) -> RusotoFuture<(), StartNotebookInstanceError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StartNotebookInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartNotebookInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).start_notebook_instance(input)
     }
-
-    /// <p>Stops a model compilation job.</p> <p> To stop a job, Amazon SageMaker sends the algorithm the SIGTERM signal. This gracefully shuts the job down. If the job hasn't stopped, it sends the SIGKILL signal.</p> <p>When it receives a <code>StopCompilationJob</code> request, Amazon SageMaker changes the <a>CompilationJobSummary$CompilationJobStatus</a> of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the <a>CompilationJobSummary$CompilationJobStatus</a> to <code>Stopped</code>. </p>
     fn stop_compilation_job(
         &self,
         input: StopCompilationJobRequest,
     ) -> RusotoFuture<(), StopCompilationJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StopCompilationJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopCompilationJobError::from_response(response))),
-                )
-            }
-        })
+        (**self).stop_compilation_job(input)
+    }
+    fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<(), StopHyperParameterTuningJobError> {
+        (**self).stop_hyper_parameter_tuning_job(input)
+    }
+    fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> RusotoFuture<(), StopLabelingJobError> {
+        (**self).stop_labeling_job(input)
+    }
+    fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> RusotoFuture<(), StopNotebookInstanceError> {
+        (**self).stop_notebook_instance(input)
+    }
+    fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> RusotoFuture<(), StopTrainingJobError> {
+        (**self).stop_training_job(input)
+    }
+    fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> RusotoFuture<(), StopTransformJobError> {
+        (**self).stop_transform_job(input)
+    }
+    fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> RusotoFuture<UpdateCodeRepositoryOutput, UpdateCodeRepositoryError> {
+        (**self).update_code_repository(input)
+    }
+    fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> RusotoFuture<UpdateEndpointOutput, UpdateEndpointError> {
+        (**self).update_endpoint(input)
+    }
+    fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> RusotoFuture<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        UpdateEndpointWeightsAndCapacitiesError,
+    > {
+        (**self).update_endpoint_weights_and_capacities(input)
+    }
+    fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> RusotoFuture<UpdateNotebookInstanceOutput, UpdateNotebookInstanceError> {
+        (**self).update_notebook_instance(input)
+    }
+    fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        UpdateNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).update_notebook_instance_lifecycle_config(input)
+    }
+    fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> RusotoFuture<UpdateWorkteamResponse, UpdateWorkteamError> {
+        (**self).update_workteam(input)
+    }
+}
+impl<T: SageMaker + ?Sized> SageMaker for Box<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> RusotoFuture<CreateAlgorithmOutput, CreateAlgorithmError> {
+        (**self).create_algorithm(input)
+    }
+    fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> RusotoFuture<CreateCodeRepositoryOutput, CreateCodeRepositoryError> {
+        (**self).create_code_repository(input)
+    }
+    fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> RusotoFuture<CreateCompilationJobResponse, CreateCompilationJobError> {
+        (**self).create_compilation_job(input)
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> RusotoFuture<CreateEndpointOutput, CreateEndpointError> {
+        (**self).create_endpoint(input)
+    }
+    fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> RusotoFuture<CreateEndpointConfigOutput, CreateEndpointConfigError> {
+        (**self).create_endpoint_config(input)
+    }
+    fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<CreateHyperParameterTuningJobResponse, CreateHyperParameterTuningJobError>
+    {
+        (**self).create_hyper_parameter_tuning_job(input)
+    }
+    fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> RusotoFuture<CreateLabelingJobResponse, CreateLabelingJobError> {
+        (**self).create_labeling_job(input)
+    }
+    fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> RusotoFuture<CreateModelOutput, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> RusotoFuture<CreateModelPackageOutput, CreateModelPackageError> {
+        (**self).create_model_package(input)
+    }
+    fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> RusotoFuture<CreateNotebookInstanceOutput, CreateNotebookInstanceError> {
+        (**self).create_notebook_instance(input)
+    }
+    fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        CreateNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).create_notebook_instance_lifecycle_config(input)
+    }
+    fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> RusotoFuture<
+        CreatePresignedNotebookInstanceUrlOutput,
+        CreatePresignedNotebookInstanceUrlError,
+    > {
+        (**self).create_presigned_notebook_instance_url(input)
+    }
+    fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> RusotoFuture<CreateTrainingJobResponse, CreateTrainingJobError> {
+        (**self).create_training_job(input)
+    }
+    fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> RusotoFuture<CreateTransformJobResponse, CreateTransformJobError> {
+        (**self).create_transform_job(input)
+    }
+    fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> RusotoFuture<CreateWorkteamResponse, CreateWorkteamError> {
+        (**self).create_workteam(input)
+    }
+    fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> RusotoFuture<(), DeleteAlgorithmError> {
+        (**self).delete_algorithm(input)
+    }
+    fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> RusotoFuture<(), DeleteCodeRepositoryError> {
+        (**self).delete_code_repository(input)
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> RusotoFuture<(), DeleteEndpointConfigError> {
+        (**self).delete_endpoint_config(input)
+    }
+    fn delete_model(&self, input: DeleteModelInput) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> RusotoFuture<(), DeleteModelPackageError> {
+        (**self).delete_model_package(input)
+    }
+    fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceError> {
+        (**self).delete_notebook_instance(input)
+    }
+    fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceLifecycleConfigError> {
+        (**self).delete_notebook_instance_lifecycle_config(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> RusotoFuture<DeleteWorkteamResponse, DeleteWorkteamError> {
+        (**self).delete_workteam(input)
+    }
+    fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> RusotoFuture<DescribeAlgorithmOutput, DescribeAlgorithmError> {
+        (**self).describe_algorithm(input)
+    }
+    fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> RusotoFuture<DescribeCodeRepositoryOutput, DescribeCodeRepositoryError> {
+        (**self).describe_code_repository(input)
+    }
+    fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> RusotoFuture<DescribeCompilationJobResponse, DescribeCompilationJobError> {
+        (**self).describe_compilation_job(input)
+    }
+    fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> RusotoFuture<DescribeEndpointOutput, DescribeEndpointError> {
+        (**self).describe_endpoint(input)
+    }
+    fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> RusotoFuture<DescribeEndpointConfigOutput, DescribeEndpointConfigError> {
+        (**self).describe_endpoint_config(input)
+    }
+    fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<DescribeHyperParameterTuningJobResponse, DescribeHyperParameterTuningJobError>
+    {
+        (**self).describe_hyper_parameter_tuning_job(input)
+    }
+    fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> RusotoFuture<DescribeLabelingJobResponse, DescribeLabelingJobError> {
+        (**self).describe_labeling_job(input)
+    }
+    fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> RusotoFuture<DescribeModelOutput, DescribeModelError> {
+        (**self).describe_model(input)
+    }
+    fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> RusotoFuture<DescribeModelPackageOutput, DescribeModelPackageError> {
+        (**self).describe_model_package(input)
+    }
+    fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> RusotoFuture<DescribeNotebookInstanceOutput, DescribeNotebookInstanceError> {
+        (**self).describe_notebook_instance(input)
+    }
+    fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        DescribeNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).describe_notebook_instance_lifecycle_config(input)
+    }
+    fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> RusotoFuture<DescribeSubscribedWorkteamResponse, DescribeSubscribedWorkteamError> {
+        (**self).describe_subscribed_workteam(input)
+    }
+    fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> RusotoFuture<DescribeTrainingJobResponse, DescribeTrainingJobError> {
+        (**self).describe_training_job(input)
+    }
+    fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> RusotoFuture<DescribeTransformJobResponse, DescribeTransformJobError> {
+        (**self).describe_transform_job(input)
+    }
+    fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> RusotoFuture<DescribeWorkteamResponse, DescribeWorkteamError> {
+        (**self).describe_workteam(input)
+    }
+    fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> RusotoFuture<GetSearchSuggestionsResponse, GetSearchSuggestionsError> {
+        (**self).get_search_suggestions(input)
+    }
+    fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> RusotoFuture<ListAlgorithmsOutput, ListAlgorithmsError> {
+        (**self).list_algorithms(input)
+    }
+    fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> RusotoFuture<ListCodeRepositoriesOutput, ListCodeRepositoriesError> {
+        (**self).list_code_repositories(input)
+    }
+    fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> RusotoFuture<ListCompilationJobsResponse, ListCompilationJobsError> {
+        (**self).list_compilation_jobs(input)
+    }
+    fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> RusotoFuture<ListEndpointConfigsOutput, ListEndpointConfigsError> {
+        (**self).list_endpoint_configs(input)
+    }
+    fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> RusotoFuture<ListEndpointsOutput, ListEndpointsError> {
+        (**self).list_endpoints(input)
+    }
+    fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> RusotoFuture<ListHyperParameterTuningJobsResponse, ListHyperParameterTuningJobsError> {
+        (**self).list_hyper_parameter_tuning_jobs(input)
+    }
+    fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> RusotoFuture<ListLabelingJobsResponse, ListLabelingJobsError> {
+        (**self).list_labeling_jobs(input)
+    }
+    fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> RusotoFuture<ListLabelingJobsForWorkteamResponse, ListLabelingJobsForWorkteamError> {
+        (**self).list_labeling_jobs_for_workteam(input)
+    }
+    fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> RusotoFuture<ListModelPackagesOutput, ListModelPackagesError> {
+        (**self).list_model_packages(input)
+    }
+    fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> RusotoFuture<ListModelsOutput, ListModelsError> {
+        (**self).list_models(input)
+    }
+    fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> RusotoFuture<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        ListNotebookInstanceLifecycleConfigsError,
+    > {
+        (**self).list_notebook_instance_lifecycle_configs(input)
+    }
+    fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> RusotoFuture<ListNotebookInstancesOutput, ListNotebookInstancesError> {
+        (**self).list_notebook_instances(input)
+    }
+    fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> RusotoFuture<ListSubscribedWorkteamsResponse, ListSubscribedWorkteamsError> {
+        (**self).list_subscribed_workteams(input)
+    }
+    fn list_tags(&self, input: ListTagsInput) -> RusotoFuture<ListTagsOutput, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> RusotoFuture<ListTrainingJobsResponse, ListTrainingJobsError> {
+        (**self).list_training_jobs(input)
+    }
+    fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        ListTrainingJobsForHyperParameterTuningJobError,
+    > {
+        (**self).list_training_jobs_for_hyper_parameter_tuning_job(input)
+    }
+    fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> RusotoFuture<ListTransformJobsResponse, ListTransformJobsError> {
+        (**self).list_transform_jobs(input)
+    }
+    fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> RusotoFuture<ListWorkteamsResponse, ListWorkteamsError> {
+        (**self).list_workteams(input)
+    }
+    fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> RusotoFuture<RenderUiTemplateResponse, RenderUiTemplateError> {
+        (**self).render_ui_template(input)
+    }
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        (**self).search(input)
+    }
+    fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> RusotoFuture<(), StartNotebookInstanceError> {
+        (**self).start_notebook_instance(input)
+    }
+    fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> RusotoFuture<(), StopCompilationJobError> {
+        (**self).stop_compilation_job(input)
+    }
+    fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<(), StopHyperParameterTuningJobError> {
+        (**self).stop_hyper_parameter_tuning_job(input)
+    }
+    fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> RusotoFuture<(), StopLabelingJobError> {
+        (**self).stop_labeling_job(input)
+    }
+    fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> RusotoFuture<(), StopNotebookInstanceError> {
+        (**self).stop_notebook_instance(input)
+    }
+    fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> RusotoFuture<(), StopTrainingJobError> {
+        (**self).stop_training_job(input)
+    }
+    fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> RusotoFuture<(), StopTransformJobError> {
+        (**self).stop_transform_job(input)
+    }
+    fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> RusotoFuture<UpdateCodeRepositoryOutput, UpdateCodeRepositoryError> {
+        (**self).update_code_repository(input)
+    }
+    fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> RusotoFuture<UpdateEndpointOutput, UpdateEndpointError> {
+        (**self).update_endpoint(input)
+    }
+    fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> RusotoFuture<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        UpdateEndpointWeightsAndCapacitiesError,
+    > {
+        (**self).update_endpoint_weights_and_capacities(input)
+    }
+    fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> RusotoFuture<UpdateNotebookInstanceOutput, UpdateNotebookInstanceError> {
+        (**self).update_notebook_instance(input)
+    }
+    fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        UpdateNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).update_notebook_instance_lifecycle_config(input)
+    }
+    fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> RusotoFuture<UpdateWorkteamResponse, UpdateWorkteamError> {
+        (**self).update_workteam(input)
+    }
+}
+impl<T: SageMaker + ?Sized> SageMaker for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> RusotoFuture<CreateAlgorithmOutput, CreateAlgorithmError> {
+        (**self).create_algorithm(input)
+    }
+    fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> RusotoFuture<CreateCodeRepositoryOutput, CreateCodeRepositoryError> {
+        (**self).create_code_repository(input)
+    }
+    fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> RusotoFuture<CreateCompilationJobResponse, CreateCompilationJobError> {
+        (**self).create_compilation_job(input)
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> RusotoFuture<CreateEndpointOutput, CreateEndpointError> {
+        (**self).create_endpoint(input)
+    }
+    fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> RusotoFuture<CreateEndpointConfigOutput, CreateEndpointConfigError> {
+        (**self).create_endpoint_config(input)
+    }
+    fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<CreateHyperParameterTuningJobResponse, CreateHyperParameterTuningJobError>
+    {
+        (**self).create_hyper_parameter_tuning_job(input)
+    }
+    fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> RusotoFuture<CreateLabelingJobResponse, CreateLabelingJobError> {
+        (**self).create_labeling_job(input)
+    }
+    fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> RusotoFuture<CreateModelOutput, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> RusotoFuture<CreateModelPackageOutput, CreateModelPackageError> {
+        (**self).create_model_package(input)
+    }
+    fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> RusotoFuture<CreateNotebookInstanceOutput, CreateNotebookInstanceError> {
+        (**self).create_notebook_instance(input)
+    }
+    fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        CreateNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).create_notebook_instance_lifecycle_config(input)
+    }
+    fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> RusotoFuture<
+        CreatePresignedNotebookInstanceUrlOutput,
+        CreatePresignedNotebookInstanceUrlError,
+    > {
+        (**self).create_presigned_notebook_instance_url(input)
+    }
+    fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> RusotoFuture<CreateTrainingJobResponse, CreateTrainingJobError> {
+        (**self).create_training_job(input)
+    }
+    fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> RusotoFuture<CreateTransformJobResponse, CreateTransformJobError> {
+        (**self).create_transform_job(input)
+    }
+    fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> RusotoFuture<CreateWorkteamResponse, CreateWorkteamError> {
+        (**self).create_workteam(input)
+    }
+    fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> RusotoFuture<(), DeleteAlgorithmError> {
+        (**self).delete_algorithm(input)
+    }
+    fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> RusotoFuture<(), DeleteCodeRepositoryError> {
+        (**self).delete_code_repository(input)
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> RusotoFuture<(), DeleteEndpointConfigError> {
+        (**self).delete_endpoint_config(input)
+    }
+    fn delete_model(&self, input: DeleteModelInput) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> RusotoFuture<(), DeleteModelPackageError> {
+        (**self).delete_model_package(input)
+    }
+    fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceError> {
+        (**self).delete_notebook_instance(input)
+    }
+    fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceLifecycleConfigError> {
+        (**self).delete_notebook_instance_lifecycle_config(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> RusotoFuture<DeleteWorkteamResponse, DeleteWorkteamError> {
+        (**self).delete_workteam(input)
+    }
+    fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> RusotoFuture<DescribeAlgorithmOutput, DescribeAlgorithmError> {
+        (**self).describe_algorithm(input)
+    }
+    fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> RusotoFuture<DescribeCodeRepositoryOutput, DescribeCodeRepositoryError> {
+        (**self).describe_code_repository(input)
+    }
+    fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> RusotoFuture<DescribeCompilationJobResponse, DescribeCompilationJobError> {
+        (**self).describe_compilation_job(input)
+    }
+    fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> RusotoFuture<DescribeEndpointOutput, DescribeEndpointError> {
+        (**self).describe_endpoint(input)
+    }
+    fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> RusotoFuture<DescribeEndpointConfigOutput, DescribeEndpointConfigError> {
+        (**self).describe_endpoint_config(input)
+    }
+    fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<DescribeHyperParameterTuningJobResponse, DescribeHyperParameterTuningJobError>
+    {
+        (**self).describe_hyper_parameter_tuning_job(input)
+    }
+    fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> RusotoFuture<DescribeLabelingJobResponse, DescribeLabelingJobError> {
+        (**self).describe_labeling_job(input)
+    }
+    fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> RusotoFuture<DescribeModelOutput, DescribeModelError> {
+        (**self).describe_model(input)
+    }
+    fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> RusotoFuture<DescribeModelPackageOutput, DescribeModelPackageError> {
+        (**self).describe_model_package(input)
+    }
+    fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> RusotoFuture<DescribeNotebookInstanceOutput, DescribeNotebookInstanceError> {
+        (**self).describe_notebook_instance(input)
+    }
+    fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        DescribeNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).describe_notebook_instance_lifecycle_config(input)
+    }
+    fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> RusotoFuture<DescribeSubscribedWorkteamResponse, DescribeSubscribedWorkteamError> {
+        (**self).describe_subscribed_workteam(input)
+    }
+    fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> RusotoFuture<DescribeTrainingJobResponse, DescribeTrainingJobError> {
+        (**self).describe_training_job(input)
+    }
+    fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> RusotoFuture<DescribeTransformJobResponse, DescribeTransformJobError> {
+        (**self).describe_transform_job(input)
+    }
+    fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> RusotoFuture<DescribeWorkteamResponse, DescribeWorkteamError> {
+        (**self).describe_workteam(input)
+    }
+    fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> RusotoFuture<GetSearchSuggestionsResponse, GetSearchSuggestionsError> {
+        (**self).get_search_suggestions(input)
+    }
+    fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> RusotoFuture<ListAlgorithmsOutput, ListAlgorithmsError> {
+        (**self).list_algorithms(input)
+    }
+    fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> RusotoFuture<ListCodeRepositoriesOutput, ListCodeRepositoriesError> {
+        (**self).list_code_repositories(input)
+    }
+    fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> RusotoFuture<ListCompilationJobsResponse, ListCompilationJobsError> {
+        (**self).list_compilation_jobs(input)
+    }
+    fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> RusotoFuture<ListEndpointConfigsOutput, ListEndpointConfigsError> {
+        (**self).list_endpoint_configs(input)
+    }
+    fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> RusotoFuture<ListEndpointsOutput, ListEndpointsError> {
+        (**self).list_endpoints(input)
+    }
+    fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> RusotoFuture<ListHyperParameterTuningJobsResponse, ListHyperParameterTuningJobsError> {
+        (**self).list_hyper_parameter_tuning_jobs(input)
+    }
+    fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> RusotoFuture<ListLabelingJobsResponse, ListLabelingJobsError> {
+        (**self).list_labeling_jobs(input)
+    }
+    fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> RusotoFuture<ListLabelingJobsForWorkteamResponse, ListLabelingJobsForWorkteamError> {
+        (**self).list_labeling_jobs_for_workteam(input)
+    }
+    fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> RusotoFuture<ListModelPackagesOutput, ListModelPackagesError> {
+        (**self).list_model_packages(input)
+    }
+    fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> RusotoFuture<ListModelsOutput, ListModelsError> {
+        (**self).list_models(input)
+    }
+    fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> RusotoFuture<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        ListNotebookInstanceLifecycleConfigsError,
+    > {
+        (**self).list_notebook_instance_lifecycle_configs(input)
+    }
+    fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> RusotoFuture<ListNotebookInstancesOutput, ListNotebookInstancesError> {
+        (**self).list_notebook_instances(input)
+    }
+    fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> RusotoFuture<ListSubscribedWorkteamsResponse, ListSubscribedWorkteamsError> {
+        (**self).list_subscribed_workteams(input)
+    }
+    fn list_tags(&self, input: ListTagsInput) -> RusotoFuture<ListTagsOutput, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> RusotoFuture<ListTrainingJobsResponse, ListTrainingJobsError> {
+        (**self).list_training_jobs(input)
+    }
+    fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        ListTrainingJobsForHyperParameterTuningJobError,
+    > {
+        (**self).list_training_jobs_for_hyper_parameter_tuning_job(input)
+    }
+    fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> RusotoFuture<ListTransformJobsResponse, ListTransformJobsError> {
+        (**self).list_transform_jobs(input)
+    }
+    fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> RusotoFuture<ListWorkteamsResponse, ListWorkteamsError> {
+        (**self).list_workteams(input)
+    }
+    fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> RusotoFuture<RenderUiTemplateResponse, RenderUiTemplateError> {
+        (**self).render_ui_template(input)
+    }
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        (**self).search(input)
+    }
+    fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> RusotoFuture<(), StartNotebookInstanceError> {
+        (**self).start_notebook_instance(input)
+    }
+    fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> RusotoFuture<(), StopCompilationJobError> {
+        (**self).stop_compilation_job(input)
+    }
+    fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<(), StopHyperParameterTuningJobError> {
+        (**self).stop_hyper_parameter_tuning_job(input)
+    }
+    fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> RusotoFuture<(), StopLabelingJobError> {
+        (**self).stop_labeling_job(input)
+    }
+    fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> RusotoFuture<(), StopNotebookInstanceError> {
+        (**self).stop_notebook_instance(input)
+    }
+    fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> RusotoFuture<(), StopTrainingJobError> {
+        (**self).stop_training_job(input)
+    }
+    fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> RusotoFuture<(), StopTransformJobError> {
+        (**self).stop_transform_job(input)
+    }
+    fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> RusotoFuture<UpdateCodeRepositoryOutput, UpdateCodeRepositoryError> {
+        (**self).update_code_repository(input)
+    }
+    fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> RusotoFuture<UpdateEndpointOutput, UpdateEndpointError> {
+        (**self).update_endpoint(input)
+    }
+    fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> RusotoFuture<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        UpdateEndpointWeightsAndCapacitiesError,
+    > {
+        (**self).update_endpoint_weights_and_capacities(input)
+    }
+    fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> RusotoFuture<UpdateNotebookInstanceOutput, UpdateNotebookInstanceError> {
+        (**self).update_notebook_instance(input)
+    }
+    fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        UpdateNotebookInstanceLifecycleConfigError,
+    > {
+        (**self).update_notebook_instance_lifecycle_config(input)
+    }
+    fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> RusotoFuture<UpdateWorkteamResponse, UpdateWorkteamError> {
+        (**self).update_workteam(input)
+    }
+}
+/// A client for the SageMaker API.
+#[derive(Clone)]
+pub struct SageMakerClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SageMakerClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SageMakerClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SageMakerClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SageMakerClient {
+        SageMakerClient { client, region }
+    }
+}
+
+impl SageMaker for SageMakerClient {
+    /// <p><p>Adds or overwrites one or more tags for the specified Amazon SageMaker resource. You can add tags to notebook instances, training jobs, hyperparameter tuning jobs, batch transform jobs, models, labeling jobs, work teams, endpoint configurations, and endpoints.</p> <p>Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p> <note> <p>Tags that you add to a hyperparameter tuning job by calling this API are also added to any training jobs that the hyperparameter tuning job launches after you call this API, but not to training jobs that the hyperparameter tuning job launched before you called this API. To make sure that the tags associated with a hyperparameter tuning job are also added to all training jobs that the hyperparameter tuning job launches, add the tags when you first create the tuning job by specifying them in the <code>Tags</code> parameter of <a>CreateHyperParameterTuningJob</a> </p> </note></p>
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("AddTags");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.AddTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsOutput, _>(response, "AddTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Create a machine learning algorithm that you can use in Amazon SageMaker and list in the AWS Marketplace.</p>
+    fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> RusotoFuture<CreateAlgorithmOutput, CreateAlgorithmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateAlgorithm");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateAlgorithm");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAlgorithmOutput, _>(
+                    response,
+                    "CreateAlgorithm",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAlgorithmError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Git repository as a resource in your Amazon SageMaker account. You can associate the repository with notebook instances so that you can use Git source control for the notebooks you create. The Git repository is a resource in your Amazon SageMaker account, so it can be associated with more than one notebook instance, and it persists independently from the lifecycle of any notebook instances it is associated with.</p> <p>The repository can be hosted either in <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/welcome.html">AWS CodeCommit</a> or in any other Git repository.</p>
+    fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> RusotoFuture<CreateCodeRepositoryOutput, CreateCodeRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateCodeRepository");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateCodeRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCodeRepositoryOutput, _>(
+                    response,
+                    "CreateCodeRepository",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateCodeRepositoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a model compilation job. After the model has been compiled, Amazon SageMaker saves the resulting model artifacts to an Amazon Simple Storage Service (Amazon S3) bucket that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts with AWS IoT Greengrass. In that case, deploy them as an ML resource.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p>A name for the compilation job</p> </li> <li> <p> Information about the input model artifacts </p> </li> <li> <p>The output location for the compiled model and the device (target) that the model runs on </p> </li> <li> <p> <code>The Amazon Resource Name (ARN) of the IAM role that Amazon SageMaker assumes to perform the model compilation job</code> </p> </li> </ul> <p>You can also provide a <code>Tag</code> to track the model compilation job's resource use and costs. The response body contains the <code>CompilationJobArn</code> for the compiled job.</p> <p>To stop a model compilation job, use <a>StopCompilationJob</a>. To get information about a particular model compilation job, use <a>DescribeCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
+    fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> RusotoFuture<CreateCompilationJobResponse, CreateCompilationJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateCompilationJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateCompilationJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCompilationJobResponse, _>(
+                    response,
+                    "CreateCompilationJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateCompilationJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an endpoint using the endpoint configuration specified in the request. Amazon SageMaker uses the endpoint to provision resources and deploy models. You create the endpoint configuration with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpointConfig.html">CreateEndpointConfig</a> API. </p> <note> <p> Use this API only for hosting models using Amazon SageMaker hosting services. </p> <p> You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note> <p>The endpoint name must be unique within an AWS Region in your AWS account. </p> <p>When it receives the request, Amazon SageMaker creates the endpoint, launches the resources (ML compute instances), and deploys the model(s) on them. </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Creating</code>. After it creates the endpoint, it sets the status to <code>InService</code>. Amazon SageMaker can then process incoming requests for inferences. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API.</p> <p>For an example, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1.html">Exercise 1: Using the K-Means Algorithm Provided by Amazon SageMaker</a>. </p> <p>If any of the models hosted at this endpoint get model data from an Amazon S3 location, Amazon SageMaker uses AWS Security Token Service to download model artifacts from the S3 path you provided. AWS STS is activated in your IAM user account by default. If you previously deactivated AWS STS for a region, you need to reactivate AWS STS for that region. For more information, see <a href="IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS i an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p>
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> RusotoFuture<CreateEndpointOutput, CreateEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateEndpoint");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEndpointOutput, _>(
+                    response,
+                    "CreateEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an endpoint configuration that Amazon SageMaker hosting services uses to deploy models. In the configuration, you identify one or more models, created using the <code>CreateModel</code> API, to deploy and the resources that you want Amazon SageMaker to provision. Then you call the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateEndpoint.html">CreateEndpoint</a> API.</p> <note> <p> Use this API only if you want to use Amazon SageMaker hosting services to deploy models into production. </p> </note> <p>In the request, you define one or more <code>ProductionVariant</code>s, each of which identifies a model. Each <code>ProductionVariant</code> parameter also describes the resources that you want Amazon SageMaker to provision. This includes the number and type of ML compute instances to deploy. </p> <p>If you are hosting multiple models, you also assign a <code>VariantWeight</code> to specify how much traffic you want to allocate to each model. For example, suppose that you want to host two models, A and B, and you assign traffic weight 2 for model A and 1 for model B. Amazon SageMaker distributes two-thirds of the traffic to Model A, and one-third to model B. </p>
+    fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> RusotoFuture<CreateEndpointConfigOutput, CreateEndpointConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateEndpointConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateEndpointConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateEndpointConfigOutput, _>(
+                    response,
+                    "CreateEndpointConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateEndpointConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a hyperparameter tuning job. A hyperparameter tuning job finds the best version of a model by running many training jobs on your dataset using the algorithm you choose and values for hyperparameters within ranges that you specify. It then chooses the hyperparameter values that result in a model that performs the best, as measured by an objective metric that you choose.</p>
+    fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<CreateHyperParameterTuningJobResponse, CreateHyperParameterTuningJobError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateHyperParameterTuningJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateHyperParameterTuningJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHyperParameterTuningJobResponse, _>(
+                    response,
+                    "CreateHyperParameterTuningJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateHyperParameterTuningJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a job that uses workers to label the data objects in your input dataset. You can use the labeled data to train machine learning models.</p> <p>You can select your workforce from one of three providers:</p> <ul> <li> <p>A private workforce that you create. It can include employees, contractors, and outside experts. Use a private workforce when want the data to stay within your organization or when a specific set of skills is required.</p> </li> <li> <p>One or more vendors that you select from the AWS Marketplace. Vendors provide expertise in specific areas. </p> </li> <li> <p>The Amazon Mechanical Turk workforce. This is the largest workforce, but it should only be used for public data or data that has been stripped of any personally identifiable information.</p> </li> </ul> <p>You can also use <i>automated data labeling</i> to reduce the number of data objects that need to be labeled by a human. Automated data labeling uses <i>active learning</i> to determine if a data object can be labeled by machine or if it needs to be sent to a human worker. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-automated-labeling.html">Using Automated Data Labeling</a>.</p> <p>The data objects to be labeled are contained in an Amazon S3 bucket. You create a <i>manifest file</i> that describes the location of each object. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-data.html">Using Input and Output Data</a>.</p> <p>The output can be used as the manifest file for another labeling job or as training data for your machine learning models.</p>
+    fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> RusotoFuture<CreateLabelingJobResponse, CreateLabelingJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateLabelingJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateLabelingJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateLabelingJobResponse, _>(
+                    response,
+                    "CreateLabelingJob",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLabelingJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a model in Amazon SageMaker. In the request, you name the model and describe a primary container. For the primary container, you specify the docker image containing inference code, artifacts (from prior training), and custom environment map that the inference code uses when you deploy the model for predictions.</p> <p>Use this API to create a model if you want to use Amazon SageMaker hosting services or run a batch transform job.</p> <p>To host your model, you create an endpoint configuration with the <code>CreateEndpointConfig</code> API, and then create an endpoint with the <code>CreateEndpoint</code> API. Amazon SageMaker then deploys all of the containers that you defined for the model in the hosting environment. </p> <p>To run a batch transform using your model, you start a job with the <code>CreateTransformJob</code> API. Amazon SageMaker uses your model and your dataset to get inferences which are then saved to a specified S3 location.</p> <p>In the <code>CreateModel</code> request, you must define a container with the <code>PrimaryContainer</code> parameter.</p> <p>In the request, you also provide an IAM role that Amazon SageMaker can assume to access model artifacts and docker image for deployment on ML compute hosting instances or for batch transform jobs. In addition, you also use the IAM role to manage permissions the inference code needs. For example, if the inference code access any other AWS resources, you grant necessary permissions via this role.</p>
+    fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> RusotoFuture<CreateModelOutput, CreateModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateModel");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateModelOutput, _>(response, "CreateModel")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a model package that you can use to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p> <p>To create a model package by specifying a Docker container that contains your inference code and the Amazon S3 location of your model artifacts, provide values for <code>InferenceSpecification</code>. To create a model from an algorithm resource that you created or subscribed to in AWS Marketplace, provide a value for <code>SourceAlgorithmSpecification</code>.</p>
+    fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> RusotoFuture<CreateModelPackageOutput, CreateModelPackageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateModelPackage");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateModelPackage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateModelPackageOutput, _>(
+                    response,
+                    "CreateModelPackage",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateModelPackageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an Amazon SageMaker notebook instance. A notebook instance is a machine learning (ML) compute instance running on a Jupyter notebook. </p> <p>In a <code>CreateNotebookInstance</code> request, specify the type of ML compute instance that you want to run. Amazon SageMaker launches the instance, installs common libraries that you can use to explore datasets for model training, and attaches an ML storage volume to the notebook instance. </p> <p>Amazon SageMaker also provides a set of example notebooks. Each notebook demonstrates how to use Amazon SageMaker with a specific algorithm or with a machine learning framework. </p> <p>After receiving the request, Amazon SageMaker does the following:</p> <ol> <li> <p>Creates a network interface in the Amazon SageMaker VPC.</p> </li> <li> <p>(Option) If you specified <code>SubnetId</code>, Amazon SageMaker creates a network interface in your own VPC, which is inferred from the subnet ID that you provide in the input. When creating this network interface, Amazon SageMaker attaches the security group that you specified in the request to the network interface that it creates in your VPC.</p> </li> <li> <p>Launches an EC2 instance of the type specified in the request in the Amazon SageMaker VPC. If you specified <code>SubnetId</code> of your VPC, Amazon SageMaker specifies both network interfaces when launching this instance. This enables inbound traffic from your own VPC to the notebook instance, assuming that the security groups allow it.</p> </li> </ol> <p>After creating the notebook instance, Amazon SageMaker returns its Amazon Resource Name (ARN). You can't change the name of a notebook instance after you create it.</p> <p>After Amazon SageMaker creates the notebook instance, you can connect to the Jupyter server and work in Jupyter notebooks. For example, you can write code to explore a dataset that you can use for model training, train a model, host models by creating Amazon SageMaker endpoints, and validate hosted models. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
+    fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> RusotoFuture<CreateNotebookInstanceOutput, CreateNotebookInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateNotebookInstance");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateNotebookInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNotebookInstanceOutput, _>(
+                    response,
+                    "CreateNotebookInstance",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateNotebookInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a lifecycle configuration that you can associate with a notebook instance. A <i>lifecycle configuration</i> is a collection of shell scripts that run when you create or start a notebook instance.</p> <p>Each lifecycle configuration script has a limit of 16384 characters.</p> <p>The value of the <code>$PATH</code> environment variable that is available to both scripts is <code>/sbin:bin:/usr/sbin:/usr/bin</code>.</p> <p>View CloudWatch Logs for notebook instance lifecycle configurations in log group <code>/aws/sagemaker/NotebookInstances</code> in log stream <code>[notebook-instance-name]/[LifecycleConfigHook]</code>.</p> <p>Lifecycle configuration scripts cannot run for longer than 5 minutes. If a script runs for longer than 5 minutes, it fails and the notebook instance is not created or started.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
+    fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        CreateNotebookInstanceLifecycleConfigError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateNotebookInstanceLifecycleConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.CreateNotebookInstanceLifecycleConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNotebookInstanceLifecycleConfigOutput, _>(response, "CreateNotebookInstanceLifecycleConfig")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateNotebookInstanceLifecycleConfigError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Returns a URL that you can use to connect to the Jupyter server from a notebook instance. In the Amazon SageMaker console, when you choose <code>Open</code> next to a notebook instance, Amazon SageMaker opens a new tab showing the Jupyter server home page from the notebook instance. The console uses this API to get the URL and show the page.</p> <p>IAM authorization policies for this API are also enforced for every HTTP request and WebSocket frame that attempts to connect to the notebook instance.For example, you can restrict access to this API and to the URL that it returns to a list of IP addresses that you specify. Use the <code>NotIpAddress</code> condition operator and the <code>aws:SourceIP</code> condition context key to specify the list of IP addresses that you want to have access to the notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/nbi-ip-filter.html">Limit Access to a Notebook Instance by IP Address</a>.</p> <note> <p>The URL that you get from a call to is valid only for 5 minutes. If you try to use the URL after the 5-minute limit expires, you are directed to the AWS console sign-in page.</p> </note></p>
+    fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> RusotoFuture<
+        CreatePresignedNotebookInstanceUrlOutput,
+        CreatePresignedNotebookInstanceUrlError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreatePresignedNotebookInstanceUrl");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.CreatePresignedNotebookInstanceUrl",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePresignedNotebookInstanceUrlOutput, _>(
+                    response,
+                    "CreatePresignedNotebookInstanceUrl",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePresignedNotebookInstanceUrlError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts a model training job. After training completes, Amazon SageMaker saves the resulting model artifacts to an Amazon S3 location that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts in a machine learning service other than Amazon SageMaker, provided that you know how to use them for inferences. </p> <p>In the request body, you provide the following: </p> <ul> <li> <p> <code>AlgorithmSpecification</code> - Identifies the training algorithm to use. </p> </li> <li> <p> <code>HyperParameters</code> - Specify these algorithm-specific parameters to enable the estimation of model parameters during training. Hyperparameters can be tuned to optimize this learning process. For a list of hyperparameters for each training algorithm provided by Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/algos.html">Algorithms</a>. </p> </li> <li> <p> <code>InputDataConfig</code> - Describes the training dataset and the Amazon S3, EFS, or FSx location where it is stored.</p> </li> <li> <p> <code>OutputDataConfig</code> - Identifies the Amazon S3 bucket where you want Amazon SageMaker to save the results of model training. </p> <p/> </li> <li> <p> <code>ResourceConfig</code> - Identifies the resources, ML compute instances, and ML storage volumes to deploy for model training. In distributed training, you specify more than one instance. </p> </li> <li> <p> <code>EnableManagedSpotTraining</code> - Optimize the cost of training machine learning models by up to 80% by using Amazon EC2 Spot instances. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/model-managed-spot-training.html">Managed Spot Training</a>. </p> </li> <li> <p> <code>RoleARN</code> - The Amazon Resource Number (ARN) that Amazon SageMaker assumes to perform tasks on your behalf during model training. You must grant this role the necessary permissions so that Amazon SageMaker can successfully complete model training. </p> </li> <li> <p> <code>StoppingCondition</code> - To help cap training costs, use <code>MaxRuntimeInSeconds</code> to set a time limit for training. Use <code>MaxWaitTimeInSeconds</code> to specify how long you are willing to to wait for a managed spot training job to complete. </p> </li> </ul> <p> For more information about Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
+    fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> RusotoFuture<CreateTrainingJobResponse, CreateTrainingJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateTrainingJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateTrainingJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTrainingJobResponse, _>(
+                    response,
+                    "CreateTrainingJob",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTrainingJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a transform job. A transform job uses a trained model to get inferences on a dataset and saves these results to an Amazon S3 location that you specify.</p> <p>To perform batch transformations, you create a transform job and use the data that you have readily available.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p> <code>TransformJobName</code> - Identifies the transform job. The name must be unique within an AWS Region in an AWS account.</p> </li> <li> <p> <code>ModelName</code> - Identifies the model to use. <code>ModelName</code> must be the name of an existing Amazon SageMaker model in the same AWS Region and AWS account. For information on creating a model, see <a>CreateModel</a>.</p> </li> <li> <p> <code>TransformInput</code> - Describes the dataset to be transformed and the Amazon S3 location where it is stored.</p> </li> <li> <p> <code>TransformOutput</code> - Identifies the Amazon S3 location where you want Amazon SageMaker to save the results from the transform job.</p> </li> <li> <p> <code>TransformResources</code> - Identifies the ML compute instances for the transform job.</p> </li> </ul> <p> For more information about how batch transformation works Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/batch-transform.html">How It Works</a>. </p>
+    fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> RusotoFuture<CreateTransformJobResponse, CreateTransformJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateTransformJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateTransformJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTransformJobResponse, _>(
+                    response,
+                    "CreateTransformJob",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTransformJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new work team for labeling your data. A work team is defined by one or more Amazon Cognito user pools. You must first create the user pools before you can create a work team.</p> <p>You cannot create more than 25 work teams in an account and region.</p>
+    fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> RusotoFuture<CreateWorkteamResponse, CreateWorkteamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("CreateWorkteam");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.CreateWorkteam");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateWorkteamResponse, _>(
+                    response,
+                    "CreateWorkteam",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWorkteamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the specified algorithm from your account.</p>
+    fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> RusotoFuture<(), DeleteAlgorithmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteAlgorithm");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteAlgorithm");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAlgorithmError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified Git repository from your account.</p>
+    fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> RusotoFuture<(), DeleteCodeRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteCodeRepository");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteCodeRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteCodeRepositoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an endpoint. Amazon SageMaker frees up all of the resources that were deployed when the endpoint was created. </p> <p>Amazon SageMaker retires any custom KMS key grants associated with the endpoint, meaning you don't need to use the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_RevokeGrant.html">RevokeGrant</a> API call.</p>
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an endpoint configuration. The <code>DeleteEndpointConfig</code> API deletes only the specified configuration. It does not delete endpoints created using the configuration. </p>
+    fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> RusotoFuture<(), DeleteEndpointConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteEndpointConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteEndpointConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteEndpointConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a model. The <code>DeleteModel</code> API deletes only the model entry that was created in Amazon SageMaker when you called the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API. It does not delete model artifacts, inference code, or the IAM role that you specified when creating the model. </p>
+    fn delete_model(&self, input: DeleteModelInput) -> RusotoFuture<(), DeleteModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteModel");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a model package.</p> <p>A model package is used to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p>
+    fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> RusotoFuture<(), DeleteModelPackageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteModelPackage");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteModelPackage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteModelPackageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p> Deletes an Amazon SageMaker notebook instance. Before you can delete a notebook instance, you must call the <code>StopNotebookInstance</code> API. </p> <important> <p>When you delete a notebook instance, you lose all of your data. Amazon SageMaker removes the ML compute instance, and deletes the ML storage volume and the network interface associated with the notebook instance. </p> </important></p>
+    fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteNotebookInstance");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteNotebookInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteNotebookInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a notebook instance lifecycle configuration.</p>
+    fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceLifecycleConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteNotebookInstanceLifecycleConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.DeleteNotebookInstanceLifecycleConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteNotebookInstanceLifecycleConfigError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Deletes the specified tags from an Amazon SageMaker resource.</p> <p>To list a resource&#39;s tags, use the <code>ListTags</code> API. </p> <note> <p>When you call this API to delete tags from a hyperparameter tuning job, the deleted tags are not removed from training jobs that the hyperparameter tuning job launched before you called this API.</p> </note></p>
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteTags");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTagsOutput, _>(response, "DeleteTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing work team. This operation can't be undone.</p>
+    fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> RusotoFuture<DeleteWorkteamResponse, DeleteWorkteamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DeleteWorkteam");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DeleteWorkteam");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteWorkteamResponse, _>(
+                    response,
+                    "DeleteWorkteam",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteWorkteamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a description of the specified algorithm that is in your account.</p>
+    fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> RusotoFuture<DescribeAlgorithmOutput, DescribeAlgorithmError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeAlgorithm");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeAlgorithm");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAlgorithmOutput, _>(
+                    response,
+                    "DescribeAlgorithm",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeAlgorithmError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets details about the specified Git repository.</p>
+    fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> RusotoFuture<DescribeCodeRepositoryOutput, DescribeCodeRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeCodeRepository");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeCodeRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCodeRepositoryOutput, _>(
+                    response,
+                    "DescribeCodeRepository",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeCodeRepositoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a model compilation job.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
+    fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> RusotoFuture<DescribeCompilationJobResponse, DescribeCompilationJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeCompilationJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeCompilationJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCompilationJobResponse, _>(
+                    response,
+                    "DescribeCompilationJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeCompilationJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the description of an endpoint.</p>
+    fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> RusotoFuture<DescribeEndpointOutput, DescribeEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeEndpoint");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEndpointOutput, _>(
+                    response,
+                    "DescribeEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the description of an endpoint configuration created using the <code>CreateEndpointConfig</code> API.</p>
+    fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> RusotoFuture<DescribeEndpointConfigOutput, DescribeEndpointConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeEndpointConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeEndpointConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEndpointConfigOutput, _>(
+                    response,
+                    "DescribeEndpointConfig",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeEndpointConfigError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a description of a hyperparameter tuning job.</p>
+    fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<DescribeHyperParameterTuningJobResponse, DescribeHyperParameterTuningJobError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeHyperParameterTuningJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeHyperParameterTuningJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeHyperParameterTuningJobResponse, _>(
+                    response,
+                    "DescribeHyperParameterTuningJob",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeHyperParameterTuningJobError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about a labeling job.</p>
+    fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> RusotoFuture<DescribeLabelingJobResponse, DescribeLabelingJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeLabelingJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeLabelingJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeLabelingJobResponse, _>(
+                    response,
+                    "DescribeLabelingJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeLabelingJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes a model that you created using the <code>CreateModel</code> API.</p>
+    fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> RusotoFuture<DescribeModelOutput, DescribeModelError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeModel");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeModel");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeModelOutput, _>(
+                    response,
+                    "DescribeModel",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeModelError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a description of the specified model package, which is used to create Amazon SageMaker models or list them on AWS Marketplace.</p> <p>To create models in Amazon SageMaker, buyers can subscribe to model packages listed on AWS Marketplace.</p>
+    fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> RusotoFuture<DescribeModelPackageOutput, DescribeModelPackageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeModelPackage");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeModelPackage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeModelPackageOutput, _>(
+                    response,
+                    "DescribeModelPackage",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeModelPackageError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a notebook instance.</p>
+    fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> RusotoFuture<DescribeNotebookInstanceOutput, DescribeNotebookInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeNotebookInstance");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeNotebookInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeNotebookInstanceOutput, _>(
+                    response,
+                    "DescribeNotebookInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeNotebookInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a description of a notebook instance lifecycle configuration.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
+    fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        DescribeNotebookInstanceLifecycleConfigError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeNotebookInstanceLifecycleConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.DescribeNotebookInstanceLifecycleConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeNotebookInstanceLifecycleConfigOutput, _>(response, "DescribeNotebookInstanceLifecycleConfig")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeNotebookInstanceLifecycleConfigError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about a work team provided by a vendor. It returns details about the subscription with a vendor in the AWS Marketplace.</p>
+    fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> RusotoFuture<DescribeSubscribedWorkteamResponse, DescribeSubscribedWorkteamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeSubscribedWorkteam");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeSubscribedWorkteam");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSubscribedWorkteamResponse, _>(
+                    response,
+                    "DescribeSubscribedWorkteam",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSubscribedWorkteamError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about a training job.</p>
+    fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> RusotoFuture<DescribeTrainingJobResponse, DescribeTrainingJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeTrainingJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeTrainingJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTrainingJobResponse, _>(
+                    response,
+                    "DescribeTrainingJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeTrainingJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about a transform job.</p>
+    fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> RusotoFuture<DescribeTransformJobResponse, DescribeTransformJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeTransformJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeTransformJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTransformJobResponse, _>(
+                    response,
+                    "DescribeTransformJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeTransformJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about a specific work team. You can see information such as the create date, the last updated date, membership information, and the work team's Amazon Resource Name (ARN).</p>
+    fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> RusotoFuture<DescribeWorkteamResponse, DescribeWorkteamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("DescribeWorkteam");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.DescribeWorkteam");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeWorkteamResponse, _>(
+                    response,
+                    "DescribeWorkteam",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeWorkteamError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>An auto-complete API for the search functionality in the Amazon SageMaker console. It returns suggestions of possible matches for the property name to use in <code>Search</code> queries. Provides suggestions for <code>HyperParameters</code>, <code>Tags</code>, and <code>Metrics</code>.</p>
+    fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> RusotoFuture<GetSearchSuggestionsResponse, GetSearchSuggestionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("GetSearchSuggestions");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.GetSearchSuggestions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSearchSuggestionsResponse, _>(
+                    response,
+                    "GetSearchSuggestions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetSearchSuggestionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the machine learning algorithms that have been created.</p>
+    fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> RusotoFuture<ListAlgorithmsOutput, ListAlgorithmsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListAlgorithms");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListAlgorithms");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAlgorithmsOutput, _>(
+                    response,
+                    "ListAlgorithms",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAlgorithmsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of the Git repositories in your account.</p>
+    fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> RusotoFuture<ListCodeRepositoriesOutput, ListCodeRepositoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListCodeRepositories");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListCodeRepositories");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCodeRepositoriesOutput, _>(
+                    response,
+                    "ListCodeRepositories",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListCodeRepositoriesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists model compilation jobs that satisfy various filters.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about a particular model compilation job you have created, use <a>DescribeCompilationJob</a>.</p>
+    fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> RusotoFuture<ListCompilationJobsResponse, ListCompilationJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListCompilationJobs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListCompilationJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCompilationJobsResponse, _>(
+                    response,
+                    "ListCompilationJobs",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListCompilationJobsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists endpoint configurations.</p>
+    fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> RusotoFuture<ListEndpointConfigsOutput, ListEndpointConfigsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListEndpointConfigs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListEndpointConfigs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEndpointConfigsOutput, _>(
+                    response,
+                    "ListEndpointConfigs",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListEndpointConfigsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists endpoints.</p>
+    fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> RusotoFuture<ListEndpointsOutput, ListEndpointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListEndpoints");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListEndpoints");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListEndpointsOutput, _>(
+                    response,
+                    "ListEndpoints",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListEndpointsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of <a>HyperParameterTuningJobSummary</a> objects that describe the hyperparameter tuning jobs launched in your account.</p>
+    fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> RusotoFuture<ListHyperParameterTuningJobsResponse, ListHyperParameterTuningJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListHyperParameterTuningJobs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListHyperParameterTuningJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListHyperParameterTuningJobsResponse, _>(
+                    response,
+                    "ListHyperParameterTuningJobs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListHyperParameterTuningJobsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a list of labeling jobs.</p>
+    fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> RusotoFuture<ListLabelingJobsResponse, ListLabelingJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListLabelingJobs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListLabelingJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListLabelingJobsResponse, _>(
+                    response,
+                    "ListLabelingJobs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListLabelingJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of labeling jobs assigned to a specified work team.</p>
+    fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> RusotoFuture<ListLabelingJobsForWorkteamResponse, ListLabelingJobsForWorkteamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListLabelingJobsForWorkteam");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListLabelingJobsForWorkteam");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListLabelingJobsForWorkteamResponse, _>(
+                    response,
+                    "ListLabelingJobsForWorkteam",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListLabelingJobsForWorkteamError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the model packages that have been created.</p>
+    fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> RusotoFuture<ListModelPackagesOutput, ListModelPackagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListModelPackages");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListModelPackages");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListModelPackagesOutput, _>(
+                    response,
+                    "ListModelPackages",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListModelPackagesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists models created with the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_CreateModel.html">CreateModel</a> API.</p>
+    fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> RusotoFuture<ListModelsOutput, ListModelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListModels");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListModels");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListModelsOutput, _>(response, "ListModels")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListModelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists notebook instance lifestyle configurations created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
+    fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> RusotoFuture<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        ListNotebookInstanceLifecycleConfigsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListNotebookInstanceLifecycleConfigs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.ListNotebookInstanceLifecycleConfigs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListNotebookInstanceLifecycleConfigsOutput, _>(
+                    response,
+                    "ListNotebookInstanceLifecycleConfigs",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListNotebookInstanceLifecycleConfigsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a list of the Amazon SageMaker notebook instances in the requester's account in an AWS Region. </p>
+    fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> RusotoFuture<ListNotebookInstancesOutput, ListNotebookInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListNotebookInstances");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListNotebookInstances");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListNotebookInstancesOutput, _>(
+                    response,
+                    "ListNotebookInstances",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListNotebookInstancesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of the work teams that you are subscribed to in the AWS Marketplace. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
+    fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> RusotoFuture<ListSubscribedWorkteamsResponse, ListSubscribedWorkteamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListSubscribedWorkteams");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListSubscribedWorkteams");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSubscribedWorkteamsResponse, _>(
+                    response,
+                    "ListSubscribedWorkteams",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSubscribedWorkteamsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the tags for the specified Amazon SageMaker resource.</p>
+    fn list_tags(&self, input: ListTagsInput) -> RusotoFuture<ListTagsOutput, ListTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTags");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsOutput, _>(response, "ListTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists training jobs.</p>
+    fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> RusotoFuture<ListTrainingJobsResponse, ListTrainingJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTrainingJobs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListTrainingJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTrainingJobsResponse, _>(
+                    response,
+                    "ListTrainingJobs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTrainingJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of <a>TrainingJobSummary</a> objects that describe the training jobs that a hyperparameter tuning job launched.</p>
+    fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        ListTrainingJobsForHyperParameterTuningJobError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTrainingJobsForHyperParameterTuningJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.ListTrainingJobsForHyperParameterTuningJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    ListTrainingJobsForHyperParameterTuningJobResponse,
+                    _,
+                >(response, "ListTrainingJobsForHyperParameterTuningJob")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListTrainingJobsForHyperParameterTuningJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists transform jobs.</p>
+    fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> RusotoFuture<ListTransformJobsResponse, ListTransformJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListTransformJobs");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListTransformJobs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTransformJobsResponse, _>(
+                    response,
+                    "ListTransformJobs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTransformJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of work teams that you have defined in a region. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
+    fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> RusotoFuture<ListWorkteamsResponse, ListWorkteamsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("ListWorkteams");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.ListWorkteams");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListWorkteamsResponse, _>(
+                    response,
+                    "ListWorkteams",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWorkteamsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Renders the UI template so that you can preview the worker's experience. </p>
+    fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> RusotoFuture<RenderUiTemplateResponse, RenderUiTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("RenderUiTemplate");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.RenderUiTemplate");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RenderUiTemplateResponse, _>(
+                    response,
+                    "RenderUiTemplate",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RenderUiTemplateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Finds Amazon SageMaker resources that match a search query. Matching resource objects are returned as a list of <code>SearchResult</code> objects in the response. You can sort the search results by any resource property in a ascending or descending order.</p> <p>You can query against the following value types: numerical, text, Booleans, and timestamps.</p>
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("Search");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.Search");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchResponse, _>(response, "Search")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Launches an ML compute instance with the latest version of the libraries and attaches your ML storage volume. After configuring the notebook instance, Amazon SageMaker sets the notebook instance status to <code>InService</code>. A notebook instance's status must be <code>InService</code> before you can connect to your Jupyter notebook. </p>
+    fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> RusotoFuture<(), StartNotebookInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StartNotebookInstance");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StartNotebookInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartNotebookInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a model compilation job.</p> <p> To stop a job, Amazon SageMaker sends the algorithm the SIGTERM signal. This gracefully shuts the job down. If the job hasn't stopped, it sends the SIGKILL signal.</p> <p>When it receives a <code>StopCompilationJob</code> request, Amazon SageMaker changes the <a>CompilationJobSummary$CompilationJobStatus</a> of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the <a>CompilationJobSummary$CompilationJobStatus</a> to <code>Stopped</code>. </p>
+    fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> RusotoFuture<(), StopCompilationJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopCompilationJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StopCompilationJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopCompilationJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a running hyperparameter tuning job and all running training jobs that the tuning job launched.</p> <p>All model artifacts output from the training jobs are stored in Amazon Simple Storage Service (Amazon S3). All data that the training jobs write to Amazon CloudWatch Logs are still available in CloudWatch. After the tuning job moves to the <code>Stopped</code> state, it releases all reserved resources for the tuning job.</p>
+    fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<(), StopHyperParameterTuningJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopHyperParameterTuningJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StopHyperParameterTuningJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopHyperParameterTuningJobError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Stops a running labeling job. A job that is stopped cannot be restarted. Any results obtained before the job is stopped are placed in the Amazon S3 output bucket.</p>
+    fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> RusotoFuture<(), StopLabelingJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopLabelingJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StopLabelingJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopLabelingJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Terminates the ML compute instance. Before terminating the instance, Amazon SageMaker disconnects the ML storage volume from it. Amazon SageMaker preserves the ML storage volume. Amazon SageMaker stops charging you for the ML compute instance when you call <code>StopNotebookInstance</code>.</p> <p>To access data on the ML storage volume for a notebook instance that has been terminated, call the <code>StartNotebookInstance</code> API. <code>StartNotebookInstance</code> launches another ML compute instance, configures it, and attaches the preserved ML storage volume so you can continue your work. </p>
+    fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> RusotoFuture<(), StopNotebookInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopNotebookInstance");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StopNotebookInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StopNotebookInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a training job. To stop a job, Amazon SageMaker sends the algorithm the <code>SIGTERM</code> signal, which delays job termination for 120 seconds. Algorithms might use this 120-second window to save the model artifacts, so the results of the training is not lost. </p> <p>When it receives a <code>StopTrainingJob</code> request, Amazon SageMaker changes the status of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the status to <code>Stopped</code>.</p>
+    fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> RusotoFuture<(), StopTrainingJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopTrainingJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StopTrainingJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopTrainingJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a transform job.</p> <p>When Amazon SageMaker receives a <code>StopTransformJob</code> request, the status of the job changes to <code>Stopping</code>. After Amazon SageMaker stops the job, the status is set to <code>Stopped</code>. When you stop a transform job before it is completed, Amazon SageMaker doesn't store the job's output in Amazon S3.</p>
+    fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> RusotoFuture<(), StopTransformJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("StopTransformJob");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.StopTransformJob");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopTransformJobError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified Git repository with the specified values.</p>
+    fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> RusotoFuture<UpdateCodeRepositoryOutput, UpdateCodeRepositoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateCodeRepository");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.UpdateCodeRepository");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateCodeRepositoryOutput, _>(
+                    response,
+                    "UpdateCodeRepository",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateCodeRepositoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deploys the new <code>EndpointConfig</code> specified in the request, switches to using newly created endpoint, and then deletes resources provisioned for the endpoint using the previous <code>EndpointConfig</code> (there is no availability loss). </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p> <note> <p>You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note></p>
+    fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> RusotoFuture<UpdateEndpointOutput, UpdateEndpointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateEndpoint");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.UpdateEndpoint");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateEndpointOutput, _>(
+                    response,
+                    "UpdateEndpoint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateEndpointError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates variant weight of one or more variants associated with an existing endpoint, or capacity of one variant associated with an existing endpoint. When it receives the request, Amazon SageMaker sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p>
+    fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> RusotoFuture<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        UpdateEndpointWeightsAndCapacitiesError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateEndpointWeightsAndCapacities");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.UpdateEndpointWeightsAndCapacities",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateEndpointWeightsAndCapacitiesOutput, _>(
+                    response,
+                    "UpdateEndpointWeightsAndCapacities",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateEndpointWeightsAndCapacitiesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a notebook instance. NotebookInstance updates include upgrading or downgrading the ML compute instance used for your notebook instance to accommodate changes in your workload requirements.</p>
+    fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> RusotoFuture<UpdateNotebookInstanceOutput, UpdateNotebookInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateNotebookInstance");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.UpdateNotebookInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNotebookInstanceOutput, _>(
+                    response,
+                    "UpdateNotebookInstance",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateNotebookInstanceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a notebook instance lifecycle configuration created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
+    fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        UpdateNotebookInstanceLifecycleConfigError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateNotebookInstanceLifecycleConfig");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SageMaker.UpdateNotebookInstanceLifecycleConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNotebookInstanceLifecycleConfigOutput, _>(response, "UpdateNotebookInstanceLifecycleConfig")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateNotebookInstanceLifecycleConfigError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates an existing work team with new member definitions or description.</p>
+    fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> RusotoFuture<UpdateWorkteamResponse, UpdateWorkteamError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
+        request.set_operation("UpdateWorkteam");
+        request.set_endpoint_prefix("api.sagemaker".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "SageMaker.UpdateWorkteam");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateWorkteamResponse, _>(
+                    response,
+                    "UpdateWorkteam",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateWorkteamError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the SageMaker API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSageMaker {
+    add_tags: Option<
+        Box<
+            dyn Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_algorithm: Option<
+        Box<
+            dyn Fn(
+                    CreateAlgorithmInput,
+                ) -> ::std::result::Result<
+                    CreateAlgorithmOutput,
+                    RusotoError<CreateAlgorithmError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_code_repository: Option<
+        Box<
+            dyn Fn(
+                    CreateCodeRepositoryInput,
+                ) -> ::std::result::Result<
+                    CreateCodeRepositoryOutput,
+                    RusotoError<CreateCodeRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_compilation_job: Option<
+        Box<
+            dyn Fn(
+                    CreateCompilationJobRequest,
+                ) -> ::std::result::Result<
+                    CreateCompilationJobResponse,
+                    RusotoError<CreateCompilationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_endpoint: Option<
+        Box<
+            dyn Fn(
+                    CreateEndpointInput,
+                )
+                    -> ::std::result::Result<CreateEndpointOutput, RusotoError<CreateEndpointError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_endpoint_config: Option<
+        Box<
+            dyn Fn(
+                    CreateEndpointConfigInput,
+                ) -> ::std::result::Result<
+                    CreateEndpointConfigOutput,
+                    RusotoError<CreateEndpointConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_hyper_parameter_tuning_job: Option<
+        Box<
+            dyn Fn(
+                    CreateHyperParameterTuningJobRequest,
+                ) -> ::std::result::Result<
+                    CreateHyperParameterTuningJobResponse,
+                    RusotoError<CreateHyperParameterTuningJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_labeling_job: Option<
+        Box<
+            dyn Fn(
+                    CreateLabelingJobRequest,
+                ) -> ::std::result::Result<
+                    CreateLabelingJobResponse,
+                    RusotoError<CreateLabelingJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_model: Option<
+        Box<
+            dyn Fn(
+                    CreateModelInput,
+                )
+                    -> ::std::result::Result<CreateModelOutput, RusotoError<CreateModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_model_package: Option<
+        Box<
+            dyn Fn(
+                    CreateModelPackageInput,
+                ) -> ::std::result::Result<
+                    CreateModelPackageOutput,
+                    RusotoError<CreateModelPackageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_notebook_instance: Option<
+        Box<
+            dyn Fn(
+                    CreateNotebookInstanceInput,
+                ) -> ::std::result::Result<
+                    CreateNotebookInstanceOutput,
+                    RusotoError<CreateNotebookInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_notebook_instance_lifecycle_config: Option<
+        Box<
+            dyn Fn(
+                    CreateNotebookInstanceLifecycleConfigInput,
+                ) -> ::std::result::Result<
+                    CreateNotebookInstanceLifecycleConfigOutput,
+                    RusotoError<CreateNotebookInstanceLifecycleConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_presigned_notebook_instance_url: Option<
+        Box<
+            dyn Fn(
+                    CreatePresignedNotebookInstanceUrlInput,
+                ) -> ::std::result::Result<
+                    CreatePresignedNotebookInstanceUrlOutput,
+                    RusotoError<CreatePresignedNotebookInstanceUrlError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_training_job: Option<
+        Box<
+            dyn Fn(
+                    CreateTrainingJobRequest,
+                ) -> ::std::result::Result<
+                    CreateTrainingJobResponse,
+                    RusotoError<CreateTrainingJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_transform_job: Option<
+        Box<
+            dyn Fn(
+                    CreateTransformJobRequest,
+                ) -> ::std::result::Result<
+                    CreateTransformJobResponse,
+                    RusotoError<CreateTransformJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_workteam: Option<
+        Box<
+            dyn Fn(
+                    CreateWorkteamRequest,
+                ) -> ::std::result::Result<
+                    CreateWorkteamResponse,
+                    RusotoError<CreateWorkteamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_algorithm: Option<
+        Box<
+            dyn Fn(
+                    DeleteAlgorithmInput,
+                ) -> ::std::result::Result<(), RusotoError<DeleteAlgorithmError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_code_repository: Option<
+        Box<
+            dyn Fn(
+                    DeleteCodeRepositoryInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteCodeRepositoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DeleteEndpointInput,
+                ) -> ::std::result::Result<(), RusotoError<DeleteEndpointError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_endpoint_config: Option<
+        Box<
+            dyn Fn(
+                    DeleteEndpointConfigInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteEndpointConfigError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_model: Option<
+        Box<
+            dyn Fn(DeleteModelInput) -> ::std::result::Result<(), RusotoError<DeleteModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_model_package: Option<
+        Box<
+            dyn Fn(
+                    DeleteModelPackageInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteModelPackageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_notebook_instance: Option<
+        Box<
+            dyn Fn(
+                    DeleteNotebookInstanceInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteNotebookInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_notebook_instance_lifecycle_config: Option<
+        Box<
+            dyn Fn(
+                    DeleteNotebookInstanceLifecycleConfigInput,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<DeleteNotebookInstanceLifecycleConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(
+                    DeleteTagsInput,
+                )
+                    -> ::std::result::Result<DeleteTagsOutput, RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_workteam: Option<
+        Box<
+            dyn Fn(
+                    DeleteWorkteamRequest,
+                ) -> ::std::result::Result<
+                    DeleteWorkteamResponse,
+                    RusotoError<DeleteWorkteamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_algorithm: Option<
+        Box<
+            dyn Fn(
+                    DescribeAlgorithmInput,
+                ) -> ::std::result::Result<
+                    DescribeAlgorithmOutput,
+                    RusotoError<DescribeAlgorithmError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_code_repository: Option<
+        Box<
+            dyn Fn(
+                    DescribeCodeRepositoryInput,
+                ) -> ::std::result::Result<
+                    DescribeCodeRepositoryOutput,
+                    RusotoError<DescribeCodeRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_compilation_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeCompilationJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeCompilationJobResponse,
+                    RusotoError<DescribeCompilationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DescribeEndpointInput,
+                ) -> ::std::result::Result<
+                    DescribeEndpointOutput,
+                    RusotoError<DescribeEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_endpoint_config: Option<
+        Box<
+            dyn Fn(
+                    DescribeEndpointConfigInput,
+                ) -> ::std::result::Result<
+                    DescribeEndpointConfigOutput,
+                    RusotoError<DescribeEndpointConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_hyper_parameter_tuning_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeHyperParameterTuningJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeHyperParameterTuningJobResponse,
+                    RusotoError<DescribeHyperParameterTuningJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_labeling_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeLabelingJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeLabelingJobResponse,
+                    RusotoError<DescribeLabelingJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_model: Option<
+        Box<
+            dyn Fn(
+                    DescribeModelInput,
+                )
+                    -> ::std::result::Result<DescribeModelOutput, RusotoError<DescribeModelError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_model_package: Option<
+        Box<
+            dyn Fn(
+                    DescribeModelPackageInput,
+                ) -> ::std::result::Result<
+                    DescribeModelPackageOutput,
+                    RusotoError<DescribeModelPackageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_notebook_instance: Option<
+        Box<
+            dyn Fn(
+                    DescribeNotebookInstanceInput,
+                ) -> ::std::result::Result<
+                    DescribeNotebookInstanceOutput,
+                    RusotoError<DescribeNotebookInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_notebook_instance_lifecycle_config: Option<
+        Box<
+            dyn Fn(
+                    DescribeNotebookInstanceLifecycleConfigInput,
+                ) -> ::std::result::Result<
+                    DescribeNotebookInstanceLifecycleConfigOutput,
+                    RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_subscribed_workteam: Option<
+        Box<
+            dyn Fn(
+                    DescribeSubscribedWorkteamRequest,
+                ) -> ::std::result::Result<
+                    DescribeSubscribedWorkteamResponse,
+                    RusotoError<DescribeSubscribedWorkteamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_training_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrainingJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeTrainingJobResponse,
+                    RusotoError<DescribeTrainingJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_transform_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeTransformJobRequest,
+                ) -> ::std::result::Result<
+                    DescribeTransformJobResponse,
+                    RusotoError<DescribeTransformJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_workteam: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkteamRequest,
+                ) -> ::std::result::Result<
+                    DescribeWorkteamResponse,
+                    RusotoError<DescribeWorkteamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_search_suggestions: Option<
+        Box<
+            dyn Fn(
+                    GetSearchSuggestionsRequest,
+                ) -> ::std::result::Result<
+                    GetSearchSuggestionsResponse,
+                    RusotoError<GetSearchSuggestionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_algorithms: Option<
+        Box<
+            dyn Fn(
+                    ListAlgorithmsInput,
+                )
+                    -> ::std::result::Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_code_repositories: Option<
+        Box<
+            dyn Fn(
+                    ListCodeRepositoriesInput,
+                ) -> ::std::result::Result<
+                    ListCodeRepositoriesOutput,
+                    RusotoError<ListCodeRepositoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_compilation_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListCompilationJobsRequest,
+                ) -> ::std::result::Result<
+                    ListCompilationJobsResponse,
+                    RusotoError<ListCompilationJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_endpoint_configs: Option<
+        Box<
+            dyn Fn(
+                    ListEndpointConfigsInput,
+                ) -> ::std::result::Result<
+                    ListEndpointConfigsOutput,
+                    RusotoError<ListEndpointConfigsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_endpoints: Option<
+        Box<
+            dyn Fn(
+                    ListEndpointsInput,
+                )
+                    -> ::std::result::Result<ListEndpointsOutput, RusotoError<ListEndpointsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_hyper_parameter_tuning_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListHyperParameterTuningJobsRequest,
+                ) -> ::std::result::Result<
+                    ListHyperParameterTuningJobsResponse,
+                    RusotoError<ListHyperParameterTuningJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_labeling_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListLabelingJobsRequest,
+                ) -> ::std::result::Result<
+                    ListLabelingJobsResponse,
+                    RusotoError<ListLabelingJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_labeling_jobs_for_workteam: Option<
+        Box<
+            dyn Fn(
+                    ListLabelingJobsForWorkteamRequest,
+                ) -> ::std::result::Result<
+                    ListLabelingJobsForWorkteamResponse,
+                    RusotoError<ListLabelingJobsForWorkteamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_model_packages: Option<
+        Box<
+            dyn Fn(
+                    ListModelPackagesInput,
+                ) -> ::std::result::Result<
+                    ListModelPackagesOutput,
+                    RusotoError<ListModelPackagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_models: Option<
+        Box<
+            dyn Fn(
+                    ListModelsInput,
+                )
+                    -> ::std::result::Result<ListModelsOutput, RusotoError<ListModelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_notebook_instance_lifecycle_configs: Option<
+        Box<
+            dyn Fn(
+                    ListNotebookInstanceLifecycleConfigsInput,
+                ) -> ::std::result::Result<
+                    ListNotebookInstanceLifecycleConfigsOutput,
+                    RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_notebook_instances: Option<
+        Box<
+            dyn Fn(
+                    ListNotebookInstancesInput,
+                ) -> ::std::result::Result<
+                    ListNotebookInstancesOutput,
+                    RusotoError<ListNotebookInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_subscribed_workteams: Option<
+        Box<
+            dyn Fn(
+                    ListSubscribedWorkteamsRequest,
+                ) -> ::std::result::Result<
+                    ListSubscribedWorkteamsResponse,
+                    RusotoError<ListSubscribedWorkteamsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags: Option<
+        Box<
+            dyn Fn(
+                    ListTagsInput,
+                )
+                    -> ::std::result::Result<ListTagsOutput, RusotoError<ListTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_training_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListTrainingJobsRequest,
+                ) -> ::std::result::Result<
+                    ListTrainingJobsResponse,
+                    RusotoError<ListTrainingJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_training_jobs_for_hyper_parameter_tuning_job: Option<
+        Box<
+            dyn Fn(
+                    ListTrainingJobsForHyperParameterTuningJobRequest,
+                ) -> ::std::result::Result<
+                    ListTrainingJobsForHyperParameterTuningJobResponse,
+                    RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_transform_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListTransformJobsRequest,
+                ) -> ::std::result::Result<
+                    ListTransformJobsResponse,
+                    RusotoError<ListTransformJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_workteams: Option<
+        Box<
+            dyn Fn(
+                    ListWorkteamsRequest,
+                )
+                    -> ::std::result::Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    render_ui_template: Option<
+        Box<
+            dyn Fn(
+                    RenderUiTemplateRequest,
+                ) -> ::std::result::Result<
+                    RenderUiTemplateResponse,
+                    RusotoError<RenderUiTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search: Option<
+        Box<
+            dyn Fn(SearchRequest) -> ::std::result::Result<SearchResponse, RusotoError<SearchError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_notebook_instance: Option<
+        Box<
+            dyn Fn(
+                    StartNotebookInstanceInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<StartNotebookInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_compilation_job: Option<
+        Box<
+            dyn Fn(
+                    StopCompilationJobRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<StopCompilationJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_hyper_parameter_tuning_job: Option<
+        Box<
+            dyn Fn(
+                    StopHyperParameterTuningJobRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<StopHyperParameterTuningJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_labeling_job: Option<
+        Box<
+            dyn Fn(
+                    StopLabelingJobRequest,
+                ) -> ::std::result::Result<(), RusotoError<StopLabelingJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_notebook_instance: Option<
+        Box<
+            dyn Fn(
+                    StopNotebookInstanceInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<StopNotebookInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_training_job: Option<
+        Box<
+            dyn Fn(
+                    StopTrainingJobRequest,
+                ) -> ::std::result::Result<(), RusotoError<StopTrainingJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_transform_job: Option<
+        Box<
+            dyn Fn(
+                    StopTransformJobRequest,
+                ) -> ::std::result::Result<(), RusotoError<StopTransformJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_code_repository: Option<
+        Box<
+            dyn Fn(
+                    UpdateCodeRepositoryInput,
+                ) -> ::std::result::Result<
+                    UpdateCodeRepositoryOutput,
+                    RusotoError<UpdateCodeRepositoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_endpoint: Option<
+        Box<
+            dyn Fn(
+                    UpdateEndpointInput,
+                )
+                    -> ::std::result::Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_endpoint_weights_and_capacities: Option<
+        Box<
+            dyn Fn(
+                    UpdateEndpointWeightsAndCapacitiesInput,
+                ) -> ::std::result::Result<
+                    UpdateEndpointWeightsAndCapacitiesOutput,
+                    RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_notebook_instance: Option<
+        Box<
+            dyn Fn(
+                    UpdateNotebookInstanceInput,
+                ) -> ::std::result::Result<
+                    UpdateNotebookInstanceOutput,
+                    RusotoError<UpdateNotebookInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_notebook_instance_lifecycle_config: Option<
+        Box<
+            dyn Fn(
+                    UpdateNotebookInstanceLifecycleConfigInput,
+                ) -> ::std::result::Result<
+                    UpdateNotebookInstanceLifecycleConfigOutput,
+                    RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_workteam: Option<
+        Box<
+            dyn Fn(
+                    UpdateWorkteamRequest,
+                ) -> ::std::result::Result<
+                    UpdateWorkteamResponse,
+                    RusotoError<UpdateWorkteamError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSageMaker {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSageMaker {
+        Default::default()
+    }
+
+    /// Answers `add_tags` with what `handler` returns for its input.
+    pub fn with_add_tags<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(AddTagsInput) -> ::std::result::Result<AddTagsOutput, RusotoError<AddTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_algorithm` with what `handler` returns for its input.
+    pub fn with_create_algorithm<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateAlgorithmInput,
+            )
+                -> ::std::result::Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_algorithm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_code_repository` with what `handler` returns for its input.
+    pub fn with_create_code_repository<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateCodeRepositoryInput,
+            ) -> ::std::result::Result<
+                CreateCodeRepositoryOutput,
+                RusotoError<CreateCodeRepositoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_code_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_compilation_job` with what `handler` returns for its input.
+    pub fn with_create_compilation_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateCompilationJobRequest,
+            ) -> ::std::result::Result<
+                CreateCompilationJobResponse,
+                RusotoError<CreateCompilationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_compilation_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_endpoint` with what `handler` returns for its input.
+    pub fn with_create_endpoint<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateEndpointInput,
+            )
+                -> ::std::result::Result<CreateEndpointOutput, RusotoError<CreateEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_endpoint_config` with what `handler` returns for its input.
+    pub fn with_create_endpoint_config<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateEndpointConfigInput,
+            ) -> ::std::result::Result<
+                CreateEndpointConfigOutput,
+                RusotoError<CreateEndpointConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_endpoint_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_hyper_parameter_tuning_job` with what `handler` returns for its input.
+    pub fn with_create_hyper_parameter_tuning_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateHyperParameterTuningJobRequest,
+            ) -> ::std::result::Result<
+                CreateHyperParameterTuningJobResponse,
+                RusotoError<CreateHyperParameterTuningJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_hyper_parameter_tuning_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_labeling_job` with what `handler` returns for its input.
+    pub fn with_create_labeling_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateLabelingJobRequest,
+            ) -> ::std::result::Result<
+                CreateLabelingJobResponse,
+                RusotoError<CreateLabelingJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_labeling_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_model` with what `handler` returns for its input.
+    pub fn with_create_model<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateModelInput,
+            )
+                -> ::std::result::Result<CreateModelOutput, RusotoError<CreateModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_model_package` with what `handler` returns for its input.
+    pub fn with_create_model_package<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateModelPackageInput,
+            ) -> ::std::result::Result<
+                CreateModelPackageOutput,
+                RusotoError<CreateModelPackageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_model_package = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_notebook_instance` with what `handler` returns for its input.
+    pub fn with_create_notebook_instance<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateNotebookInstanceInput,
+            ) -> ::std::result::Result<
+                CreateNotebookInstanceOutput,
+                RusotoError<CreateNotebookInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_notebook_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_notebook_instance_lifecycle_config` with what `handler` returns for its input.
+    pub fn with_create_notebook_instance_lifecycle_config<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateNotebookInstanceLifecycleConfigInput,
+            ) -> ::std::result::Result<
+                CreateNotebookInstanceLifecycleConfigOutput,
+                RusotoError<CreateNotebookInstanceLifecycleConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_notebook_instance_lifecycle_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_presigned_notebook_instance_url` with what `handler` returns for its input.
+    pub fn with_create_presigned_notebook_instance_url<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreatePresignedNotebookInstanceUrlInput,
+            ) -> ::std::result::Result<
+                CreatePresignedNotebookInstanceUrlOutput,
+                RusotoError<CreatePresignedNotebookInstanceUrlError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_presigned_notebook_instance_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_training_job` with what `handler` returns for its input.
+    pub fn with_create_training_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateTrainingJobRequest,
+            ) -> ::std::result::Result<
+                CreateTrainingJobResponse,
+                RusotoError<CreateTrainingJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_training_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_transform_job` with what `handler` returns for its input.
+    pub fn with_create_transform_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateTransformJobRequest,
+            ) -> ::std::result::Result<
+                CreateTransformJobResponse,
+                RusotoError<CreateTransformJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_transform_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_workteam` with what `handler` returns for its input.
+    pub fn with_create_workteam<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                CreateWorkteamRequest,
+            )
+                -> ::std::result::Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_workteam = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_algorithm` with what `handler` returns for its input.
+    pub fn with_delete_algorithm<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(DeleteAlgorithmInput) -> ::std::result::Result<(), RusotoError<DeleteAlgorithmError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_algorithm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_code_repository` with what `handler` returns for its input.
+    pub fn with_delete_code_repository<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteCodeRepositoryInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteCodeRepositoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_code_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_endpoint` with what `handler` returns for its input.
+    pub fn with_delete_endpoint<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(DeleteEndpointInput) -> ::std::result::Result<(), RusotoError<DeleteEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_endpoint_config` with what `handler` returns for its input.
+    pub fn with_delete_endpoint_config<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteEndpointConfigInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteEndpointConfigError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_endpoint_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_model` with what `handler` returns for its input.
+    pub fn with_delete_model<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(DeleteModelInput) -> ::std::result::Result<(), RusotoError<DeleteModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_model_package` with what `handler` returns for its input.
+    pub fn with_delete_model_package<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteModelPackageInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteModelPackageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_model_package = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_notebook_instance` with what `handler` returns for its input.
+    pub fn with_delete_notebook_instance<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteNotebookInstanceInput,
+            ) -> ::std::result::Result<(), RusotoError<DeleteNotebookInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_notebook_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_notebook_instance_lifecycle_config` with what `handler` returns for its input.
+    pub fn with_delete_notebook_instance_lifecycle_config<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteNotebookInstanceLifecycleConfigInput,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_notebook_instance_lifecycle_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteTagsInput,
+            ) -> ::std::result::Result<DeleteTagsOutput, RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_workteam` with what `handler` returns for its input.
+    pub fn with_delete_workteam<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DeleteWorkteamRequest,
+            )
+                -> ::std::result::Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_workteam = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_algorithm` with what `handler` returns for its input.
+    pub fn with_describe_algorithm<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeAlgorithmInput,
+            ) -> ::std::result::Result<
+                DescribeAlgorithmOutput,
+                RusotoError<DescribeAlgorithmError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_algorithm = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_code_repository` with what `handler` returns for its input.
+    pub fn with_describe_code_repository<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeCodeRepositoryInput,
+            ) -> ::std::result::Result<
+                DescribeCodeRepositoryOutput,
+                RusotoError<DescribeCodeRepositoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_code_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_compilation_job` with what `handler` returns for its input.
+    pub fn with_describe_compilation_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeCompilationJobRequest,
+            ) -> ::std::result::Result<
+                DescribeCompilationJobResponse,
+                RusotoError<DescribeCompilationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_compilation_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_endpoint` with what `handler` returns for its input.
+    pub fn with_describe_endpoint<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeEndpointInput,
+            )
+                -> ::std::result::Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_endpoint_config` with what `handler` returns for its input.
+    pub fn with_describe_endpoint_config<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeEndpointConfigInput,
+            ) -> ::std::result::Result<
+                DescribeEndpointConfigOutput,
+                RusotoError<DescribeEndpointConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_endpoint_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hyper_parameter_tuning_job` with what `handler` returns for its input.
+    pub fn with_describe_hyper_parameter_tuning_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeHyperParameterTuningJobRequest,
+            ) -> ::std::result::Result<
+                DescribeHyperParameterTuningJobResponse,
+                RusotoError<DescribeHyperParameterTuningJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hyper_parameter_tuning_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_labeling_job` with what `handler` returns for its input.
+    pub fn with_describe_labeling_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeLabelingJobRequest,
+            ) -> ::std::result::Result<
+                DescribeLabelingJobResponse,
+                RusotoError<DescribeLabelingJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_labeling_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_model` with what `handler` returns for its input.
+    pub fn with_describe_model<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeModelInput,
+            )
+                -> ::std::result::Result<DescribeModelOutput, RusotoError<DescribeModelError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_model = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_model_package` with what `handler` returns for its input.
+    pub fn with_describe_model_package<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeModelPackageInput,
+            ) -> ::std::result::Result<
+                DescribeModelPackageOutput,
+                RusotoError<DescribeModelPackageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_model_package = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_notebook_instance` with what `handler` returns for its input.
+    pub fn with_describe_notebook_instance<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeNotebookInstanceInput,
+            ) -> ::std::result::Result<
+                DescribeNotebookInstanceOutput,
+                RusotoError<DescribeNotebookInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_notebook_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_notebook_instance_lifecycle_config` with what `handler` returns for its input.
+    pub fn with_describe_notebook_instance_lifecycle_config<F>(
+        mut self,
+        handler: F,
+    ) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeNotebookInstanceLifecycleConfigInput,
+            ) -> ::std::result::Result<
+                DescribeNotebookInstanceLifecycleConfigOutput,
+                RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_notebook_instance_lifecycle_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_subscribed_workteam` with what `handler` returns for its input.
+    pub fn with_describe_subscribed_workteam<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeSubscribedWorkteamRequest,
+            ) -> ::std::result::Result<
+                DescribeSubscribedWorkteamResponse,
+                RusotoError<DescribeSubscribedWorkteamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_subscribed_workteam = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_training_job` with what `handler` returns for its input.
+    pub fn with_describe_training_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeTrainingJobRequest,
+            ) -> ::std::result::Result<
+                DescribeTrainingJobResponse,
+                RusotoError<DescribeTrainingJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_training_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_transform_job` with what `handler` returns for its input.
+    pub fn with_describe_transform_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeTransformJobRequest,
+            ) -> ::std::result::Result<
+                DescribeTransformJobResponse,
+                RusotoError<DescribeTransformJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_transform_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workteam` with what `handler` returns for its input.
+    pub fn with_describe_workteam<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                DescribeWorkteamRequest,
+            ) -> ::std::result::Result<
+                DescribeWorkteamResponse,
+                RusotoError<DescribeWorkteamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workteam = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_search_suggestions` with what `handler` returns for its input.
+    pub fn with_get_search_suggestions<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                GetSearchSuggestionsRequest,
+            ) -> ::std::result::Result<
+                GetSearchSuggestionsResponse,
+                RusotoError<GetSearchSuggestionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_search_suggestions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_algorithms` with what `handler` returns for its input.
+    pub fn with_list_algorithms<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListAlgorithmsInput,
+            )
+                -> ::std::result::Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_algorithms = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_code_repositories` with what `handler` returns for its input.
+    pub fn with_list_code_repositories<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListCodeRepositoriesInput,
+            ) -> ::std::result::Result<
+                ListCodeRepositoriesOutput,
+                RusotoError<ListCodeRepositoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_code_repositories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_compilation_jobs` with what `handler` returns for its input.
+    pub fn with_list_compilation_jobs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListCompilationJobsRequest,
+            ) -> ::std::result::Result<
+                ListCompilationJobsResponse,
+                RusotoError<ListCompilationJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_compilation_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_endpoint_configs` with what `handler` returns for its input.
+    pub fn with_list_endpoint_configs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListEndpointConfigsInput,
+            ) -> ::std::result::Result<
+                ListEndpointConfigsOutput,
+                RusotoError<ListEndpointConfigsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_endpoint_configs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_endpoints` with what `handler` returns for its input.
+    pub fn with_list_endpoints<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListEndpointsInput,
+            )
+                -> ::std::result::Result<ListEndpointsOutput, RusotoError<ListEndpointsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_endpoints = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_hyper_parameter_tuning_jobs` with what `handler` returns for its input.
+    pub fn with_list_hyper_parameter_tuning_jobs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListHyperParameterTuningJobsRequest,
+            ) -> ::std::result::Result<
+                ListHyperParameterTuningJobsResponse,
+                RusotoError<ListHyperParameterTuningJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_hyper_parameter_tuning_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_labeling_jobs` with what `handler` returns for its input.
+    pub fn with_list_labeling_jobs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListLabelingJobsRequest,
+            ) -> ::std::result::Result<
+                ListLabelingJobsResponse,
+                RusotoError<ListLabelingJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_labeling_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_labeling_jobs_for_workteam` with what `handler` returns for its input.
+    pub fn with_list_labeling_jobs_for_workteam<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListLabelingJobsForWorkteamRequest,
+            ) -> ::std::result::Result<
+                ListLabelingJobsForWorkteamResponse,
+                RusotoError<ListLabelingJobsForWorkteamError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_labeling_jobs_for_workteam = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_model_packages` with what `handler` returns for its input.
+    pub fn with_list_model_packages<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListModelPackagesInput,
+            ) -> ::std::result::Result<
+                ListModelPackagesOutput,
+                RusotoError<ListModelPackagesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_model_packages = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_models` with what `handler` returns for its input.
+    pub fn with_list_models<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListModelsInput,
+            ) -> ::std::result::Result<ListModelsOutput, RusotoError<ListModelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_models = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_notebook_instance_lifecycle_configs` with what `handler` returns for its input.
+    pub fn with_list_notebook_instance_lifecycle_configs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListNotebookInstanceLifecycleConfigsInput,
+            ) -> ::std::result::Result<
+                ListNotebookInstanceLifecycleConfigsOutput,
+                RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_notebook_instance_lifecycle_configs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_notebook_instances` with what `handler` returns for its input.
+    pub fn with_list_notebook_instances<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListNotebookInstancesInput,
+            ) -> ::std::result::Result<
+                ListNotebookInstancesOutput,
+                RusotoError<ListNotebookInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_notebook_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_subscribed_workteams` with what `handler` returns for its input.
+    pub fn with_list_subscribed_workteams<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListSubscribedWorkteamsRequest,
+            ) -> ::std::result::Result<
+                ListSubscribedWorkteamsResponse,
+                RusotoError<ListSubscribedWorkteamsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_subscribed_workteams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags` with what `handler` returns for its input.
+    pub fn with_list_tags<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(ListTagsInput) -> ::std::result::Result<ListTagsOutput, RusotoError<ListTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_training_jobs` with what `handler` returns for its input.
+    pub fn with_list_training_jobs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListTrainingJobsRequest,
+            ) -> ::std::result::Result<
+                ListTrainingJobsResponse,
+                RusotoError<ListTrainingJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_training_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_training_jobs_for_hyper_parameter_tuning_job` with what `handler` returns for its input.
+    pub fn with_list_training_jobs_for_hyper_parameter_tuning_job<F>(
+        mut self,
+        handler: F,
+    ) -> MockSageMaker
+    where
+        F: Fn(
+                ListTrainingJobsForHyperParameterTuningJobRequest,
+            ) -> ::std::result::Result<
+                ListTrainingJobsForHyperParameterTuningJobResponse,
+                RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_training_jobs_for_hyper_parameter_tuning_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_transform_jobs` with what `handler` returns for its input.
+    pub fn with_list_transform_jobs<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListTransformJobsRequest,
+            ) -> ::std::result::Result<
+                ListTransformJobsResponse,
+                RusotoError<ListTransformJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_transform_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_workteams` with what `handler` returns for its input.
+    pub fn with_list_workteams<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                ListWorkteamsRequest,
+            )
+                -> ::std::result::Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_workteams = Some(Box::new(handler));
+        self
+    }
+    /// Answers `render_ui_template` with what `handler` returns for its input.
+    pub fn with_render_ui_template<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                RenderUiTemplateRequest,
+            ) -> ::std::result::Result<
+                RenderUiTemplateResponse,
+                RusotoError<RenderUiTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.render_ui_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search` with what `handler` returns for its input.
+    pub fn with_search<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(SearchRequest) -> ::std::result::Result<SearchResponse, RusotoError<SearchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_notebook_instance` with what `handler` returns for its input.
+    pub fn with_start_notebook_instance<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StartNotebookInstanceInput,
+            ) -> ::std::result::Result<(), RusotoError<StartNotebookInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_notebook_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_compilation_job` with what `handler` returns for its input.
+    pub fn with_stop_compilation_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StopCompilationJobRequest,
+            ) -> ::std::result::Result<(), RusotoError<StopCompilationJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_compilation_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_hyper_parameter_tuning_job` with what `handler` returns for its input.
+    pub fn with_stop_hyper_parameter_tuning_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StopHyperParameterTuningJobRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<StopHyperParameterTuningJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_hyper_parameter_tuning_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_labeling_job` with what `handler` returns for its input.
+    pub fn with_stop_labeling_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StopLabelingJobRequest,
+            ) -> ::std::result::Result<(), RusotoError<StopLabelingJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_labeling_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_notebook_instance` with what `handler` returns for its input.
+    pub fn with_stop_notebook_instance<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StopNotebookInstanceInput,
+            ) -> ::std::result::Result<(), RusotoError<StopNotebookInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_notebook_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_training_job` with what `handler` returns for its input.
+    pub fn with_stop_training_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StopTrainingJobRequest,
+            ) -> ::std::result::Result<(), RusotoError<StopTrainingJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_training_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_transform_job` with what `handler` returns for its input.
+    pub fn with_stop_transform_job<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                StopTransformJobRequest,
+            ) -> ::std::result::Result<(), RusotoError<StopTransformJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_transform_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_code_repository` with what `handler` returns for its input.
+    pub fn with_update_code_repository<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                UpdateCodeRepositoryInput,
+            ) -> ::std::result::Result<
+                UpdateCodeRepositoryOutput,
+                RusotoError<UpdateCodeRepositoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_code_repository = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_endpoint` with what `handler` returns for its input.
+    pub fn with_update_endpoint<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                UpdateEndpointInput,
+            )
+                -> ::std::result::Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_endpoint_weights_and_capacities` with what `handler` returns for its input.
+    pub fn with_update_endpoint_weights_and_capacities<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                UpdateEndpointWeightsAndCapacitiesInput,
+            ) -> ::std::result::Result<
+                UpdateEndpointWeightsAndCapacitiesOutput,
+                RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_endpoint_weights_and_capacities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_notebook_instance` with what `handler` returns for its input.
+    pub fn with_update_notebook_instance<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                UpdateNotebookInstanceInput,
+            ) -> ::std::result::Result<
+                UpdateNotebookInstanceOutput,
+                RusotoError<UpdateNotebookInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_notebook_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_notebook_instance_lifecycle_config` with what `handler` returns for its input.
+    pub fn with_update_notebook_instance_lifecycle_config<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                UpdateNotebookInstanceLifecycleConfigInput,
+            ) -> ::std::result::Result<
+                UpdateNotebookInstanceLifecycleConfigOutput,
+                RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_notebook_instance_lifecycle_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_workteam` with what `handler` returns for its input.
+    pub fn with_update_workteam<F>(mut self, handler: F) -> MockSageMaker
+    where
+        F: Fn(
+                UpdateWorkteamRequest,
+            )
+                -> ::std::result::Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_workteam = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl SageMaker for MockSageMaker {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        match self.add_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for add_tags"),
+        }
+    }
+    fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> RusotoFuture<CreateAlgorithmOutput, CreateAlgorithmError> {
+        match self.create_algorithm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_algorithm"),
+        }
+    }
+    fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> RusotoFuture<CreateCodeRepositoryOutput, CreateCodeRepositoryError> {
+        match self.create_code_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_code_repository"),
+        }
+    }
+    fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> RusotoFuture<CreateCompilationJobResponse, CreateCompilationJobError> {
+        match self.create_compilation_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_compilation_job"),
+        }
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> RusotoFuture<CreateEndpointOutput, CreateEndpointError> {
+        match self.create_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_endpoint"),
+        }
+    }
+    fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> RusotoFuture<CreateEndpointConfigOutput, CreateEndpointConfigError> {
+        match self.create_endpoint_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_endpoint_config"),
+        }
+    }
+    fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<CreateHyperParameterTuningJobResponse, CreateHyperParameterTuningJobError>
+    {
+        match self.create_hyper_parameter_tuning_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_hyper_parameter_tuning_job"),
+        }
+    }
+    fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> RusotoFuture<CreateLabelingJobResponse, CreateLabelingJobError> {
+        match self.create_labeling_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_labeling_job"),
+        }
+    }
+    fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> RusotoFuture<CreateModelOutput, CreateModelError> {
+        match self.create_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_model"),
+        }
+    }
+    fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> RusotoFuture<CreateModelPackageOutput, CreateModelPackageError> {
+        match self.create_model_package {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_model_package"),
+        }
+    }
+    fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> RusotoFuture<CreateNotebookInstanceOutput, CreateNotebookInstanceError> {
+        match self.create_notebook_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_notebook_instance"),
+        }
+    }
+    fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        CreateNotebookInstanceLifecycleConfigError,
+    > {
+        match self.create_notebook_instance_lifecycle_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSageMaker has no handler for create_notebook_instance_lifecycle_config")
+            }
+        }
+    }
+    fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> RusotoFuture<
+        CreatePresignedNotebookInstanceUrlOutput,
+        CreatePresignedNotebookInstanceUrlError,
+    > {
+        match self.create_presigned_notebook_instance_url {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSageMaker has no handler for create_presigned_notebook_instance_url")
+            }
+        }
+    }
+    fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> RusotoFuture<CreateTrainingJobResponse, CreateTrainingJobError> {
+        match self.create_training_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_training_job"),
+        }
+    }
+    fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> RusotoFuture<CreateTransformJobResponse, CreateTransformJobError> {
+        match self.create_transform_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_transform_job"),
+        }
+    }
+    fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> RusotoFuture<CreateWorkteamResponse, CreateWorkteamError> {
+        match self.create_workteam {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for create_workteam"),
+        }
+    }
+    fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> RusotoFuture<(), DeleteAlgorithmError> {
+        match self.delete_algorithm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_algorithm"),
+        }
+    }
+    fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> RusotoFuture<(), DeleteCodeRepositoryError> {
+        match self.delete_code_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_code_repository"),
+        }
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        match self.delete_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_endpoint"),
+        }
+    }
+    fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> RusotoFuture<(), DeleteEndpointConfigError> {
+        match self.delete_endpoint_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_endpoint_config"),
+        }
+    }
+    fn delete_model(&self, input: DeleteModelInput) -> RusotoFuture<(), DeleteModelError> {
+        match self.delete_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_model"),
+        }
+    }
+    fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> RusotoFuture<(), DeleteModelPackageError> {
+        match self.delete_model_package {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_model_package"),
+        }
+    }
+    fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceError> {
+        match self.delete_notebook_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_notebook_instance"),
+        }
+    }
+    fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<(), DeleteNotebookInstanceLifecycleConfigError> {
+        match self.delete_notebook_instance_lifecycle_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSageMaker has no handler for delete_notebook_instance_lifecycle_config")
+            }
+        }
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_tags"),
+        }
+    }
+    fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> RusotoFuture<DeleteWorkteamResponse, DeleteWorkteamError> {
+        match self.delete_workteam {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for delete_workteam"),
+        }
+    }
+    fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> RusotoFuture<DescribeAlgorithmOutput, DescribeAlgorithmError> {
+        match self.describe_algorithm {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_algorithm"),
+        }
+    }
+    fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> RusotoFuture<DescribeCodeRepositoryOutput, DescribeCodeRepositoryError> {
+        match self.describe_code_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_code_repository"),
+        }
+    }
+    fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> RusotoFuture<DescribeCompilationJobResponse, DescribeCompilationJobError> {
+        match self.describe_compilation_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_compilation_job"),
+        }
+    }
+    fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> RusotoFuture<DescribeEndpointOutput, DescribeEndpointError> {
+        match self.describe_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_endpoint"),
+        }
+    }
+    fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> RusotoFuture<DescribeEndpointConfigOutput, DescribeEndpointConfigError> {
+        match self.describe_endpoint_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_endpoint_config"),
+        }
+    }
+    fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<DescribeHyperParameterTuningJobResponse, DescribeHyperParameterTuningJobError>
+    {
+        match self.describe_hyper_parameter_tuning_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_hyper_parameter_tuning_job"),
+        }
+    }
+    fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> RusotoFuture<DescribeLabelingJobResponse, DescribeLabelingJobError> {
+        match self.describe_labeling_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_labeling_job"),
+        }
+    }
+    fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> RusotoFuture<DescribeModelOutput, DescribeModelError> {
+        match self.describe_model {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_model"),
+        }
+    }
+    fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> RusotoFuture<DescribeModelPackageOutput, DescribeModelPackageError> {
+        match self.describe_model_package {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_model_package"),
+        }
+    }
+    fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> RusotoFuture<DescribeNotebookInstanceOutput, DescribeNotebookInstanceError> {
+        match self.describe_notebook_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_notebook_instance"),
+        }
+    }
+    fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> RusotoFuture<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        DescribeNotebookInstanceLifecycleConfigError,
+    > {
+        match self.describe_notebook_instance_lifecycle_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockSageMaker has no handler for describe_notebook_instance_lifecycle_config"
+            ),
+        }
+    }
+    fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> RusotoFuture<DescribeSubscribedWorkteamResponse, DescribeSubscribedWorkteamError> {
+        match self.describe_subscribed_workteam {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_subscribed_workteam"),
+        }
+    }
+    fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> RusotoFuture<DescribeTrainingJobResponse, DescribeTrainingJobError> {
+        match self.describe_training_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_training_job"),
+        }
+    }
+    fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> RusotoFuture<DescribeTransformJobResponse, DescribeTransformJobError> {
+        match self.describe_transform_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_transform_job"),
+        }
+    }
+    fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> RusotoFuture<DescribeWorkteamResponse, DescribeWorkteamError> {
+        match self.describe_workteam {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for describe_workteam"),
+        }
+    }
+    fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> RusotoFuture<GetSearchSuggestionsResponse, GetSearchSuggestionsError> {
+        match self.get_search_suggestions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for get_search_suggestions"),
+        }
+    }
+    fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> RusotoFuture<ListAlgorithmsOutput, ListAlgorithmsError> {
+        match self.list_algorithms {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_algorithms"),
+        }
+    }
+    fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> RusotoFuture<ListCodeRepositoriesOutput, ListCodeRepositoriesError> {
+        match self.list_code_repositories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_code_repositories"),
+        }
+    }
+    fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> RusotoFuture<ListCompilationJobsResponse, ListCompilationJobsError> {
+        match self.list_compilation_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_compilation_jobs"),
+        }
+    }
+    fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> RusotoFuture<ListEndpointConfigsOutput, ListEndpointConfigsError> {
+        match self.list_endpoint_configs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_endpoint_configs"),
+        }
+    }
+    fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> RusotoFuture<ListEndpointsOutput, ListEndpointsError> {
+        match self.list_endpoints {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_endpoints"),
+        }
+    }
+    fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> RusotoFuture<ListHyperParameterTuningJobsResponse, ListHyperParameterTuningJobsError> {
+        match self.list_hyper_parameter_tuning_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_hyper_parameter_tuning_jobs"),
+        }
+    }
+    fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> RusotoFuture<ListLabelingJobsResponse, ListLabelingJobsError> {
+        match self.list_labeling_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_labeling_jobs"),
+        }
+    }
+    fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> RusotoFuture<ListLabelingJobsForWorkteamResponse, ListLabelingJobsForWorkteamError> {
+        match self.list_labeling_jobs_for_workteam {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_labeling_jobs_for_workteam"),
+        }
+    }
+    fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> RusotoFuture<ListModelPackagesOutput, ListModelPackagesError> {
+        match self.list_model_packages {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_model_packages"),
+        }
+    }
+    fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> RusotoFuture<ListModelsOutput, ListModelsError> {
+        match self.list_models {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_models"),
+        }
+    }
+    fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> RusotoFuture<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        ListNotebookInstanceLifecycleConfigsError,
+    > {
+        match self.list_notebook_instance_lifecycle_configs {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSageMaker has no handler for list_notebook_instance_lifecycle_configs")
+            }
+        }
+    }
+    fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> RusotoFuture<ListNotebookInstancesOutput, ListNotebookInstancesError> {
+        match self.list_notebook_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_notebook_instances"),
+        }
+    }
+    fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> RusotoFuture<ListSubscribedWorkteamsResponse, ListSubscribedWorkteamsError> {
+        match self.list_subscribed_workteams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_subscribed_workteams"),
+        }
+    }
+    fn list_tags(&self, input: ListTagsInput) -> RusotoFuture<ListTagsOutput, ListTagsError> {
+        match self.list_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_tags"),
+        }
+    }
+    fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> RusotoFuture<ListTrainingJobsResponse, ListTrainingJobsError> {
+        match self.list_training_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_training_jobs"),
+        }
+    }
+    fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> RusotoFuture<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        ListTrainingJobsForHyperParameterTuningJobError,
+    > {
+        match self.list_training_jobs_for_hyper_parameter_tuning_job {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockSageMaker has no handler for list_training_jobs_for_hyper_parameter_tuning_job"),
+}
+    }
+    fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> RusotoFuture<ListTransformJobsResponse, ListTransformJobsError> {
+        match self.list_transform_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_transform_jobs"),
+        }
+    }
+    fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> RusotoFuture<ListWorkteamsResponse, ListWorkteamsError> {
+        match self.list_workteams {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for list_workteams"),
+        }
+    }
+    fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> RusotoFuture<RenderUiTemplateResponse, RenderUiTemplateError> {
+        match self.render_ui_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for render_ui_template"),
+        }
+    }
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        match self.search {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for search"),
+        }
+    }
+    fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> RusotoFuture<(), StartNotebookInstanceError> {
+        match self.start_notebook_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for start_notebook_instance"),
+        }
+    }
+    fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> RusotoFuture<(), StopCompilationJobError> {
+        match self.stop_compilation_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for stop_compilation_job"),
+        }
     }
-
-    /// <p>Stops a running hyperparameter tuning job and all running training jobs that the tuning job launched.</p> <p>All model artifacts output from the training jobs are stored in Amazon Simple Storage Service (Amazon S3). All data that the training jobs write to Amazon CloudWatch Logs are still available in CloudWatch. After the tuning job moves to the <code>Stopped</code> state, it releases all reserved resources for the tuning job.</p>
     fn stop_hyper_parameter_tuning_job(
         &self,
         input: StopHyperParameterTuningJobRequest,
     ) -> RusotoFuture<(), StopHyperParameterTuningJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StopHyperParameterTuningJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopHyperParameterTuningJobError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_hyper_parameter_tuning_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for stop_hyper_parameter_tuning_job"),
+        }
     }
-
-    /// <p>Stops a running labeling job. A job that is stopped cannot be restarted. Any results obtained before the job is stopped are placed in the Amazon S3 output bucket.</p>
     fn stop_labeling_job(
         &self,
         input: StopLabelingJobRequest,
     ) -> RusotoFuture<(), StopLabelingJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StopLabelingJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopLabelingJobError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_labeling_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for stop_labeling_job"),
+        }
     }
-
-    /// <p>Terminates the ML compute instance. Before terminating the instance, Amazon SageMaker disconnects the ML storage volume from it. Amazon SageMaker preserves the ML storage volume. Amazon SageMaker stops charging you for the ML compute instance when you call <code>StopNotebookInstance</code>.</p> <p>To access data on the ML storage volume for a notebook instance that has been terminated, call the <code>StartNotebookInstance</code> API. <code>StartNotebookInstance</code> launches another ML compute instance, configures it, and attaches the preserved ML storage volume so you can continue your work. </p>
     fn stop_notebook_instance(
         &self,
         input: StopNotebookInstanceInput,
     ) -> RusotoFuture<(), StopNotebookInstanceError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StopNotebookInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StopNotebookInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.stop_notebook_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for stop_notebook_instance"),
+        }
     }
-
-    /// <p>Stops a training job. To stop a job, Amazon SageMaker sends the algorithm the <code>SIGTERM</code> signal, which delays job termination for 120 seconds. Algorithms might use this 120-second window to save the model artifacts, so the results of the training is not lost. </p> <p>When it receives a <code>StopTrainingJob</code> request, Amazon SageMaker changes the status of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the status to <code>Stopped</code>.</p>
     fn stop_training_job(
         &self,
         input: StopTrainingJobRequest,
     ) -> RusotoFuture<(), StopTrainingJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StopTrainingJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopTrainingJobError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_training_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for stop_training_job"),
+        }
     }
-
-    /// <p>Stops a transform job.</p> <p>When Amazon SageMaker receives a <code>StopTransformJob</code> request, the status of the job changes to <code>Stopping</code>. After Amazon SageMaker stops the job, the status is set to <code>Stopped</code>. When you stop a transform job before it is completed, Amazon SageMaker doesn't store the job's output in Amazon S3.</p>
     fn stop_transform_job(
         &self,
         input: StopTransformJobRequest,
     ) -> RusotoFuture<(), StopTransformJobError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.StopTransformJob");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopTransformJobError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_transform_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for stop_transform_job"),
+        }
     }
-
-    /// <p>Updates the specified Git repository with the specified values.</p>
     fn update_code_repository(
         &self,
         input: UpdateCodeRepositoryInput,
     ) -> RusotoFuture<UpdateCodeRepositoryOutput, UpdateCodeRepositoryError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.UpdateCodeRepository");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCodeRepositoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateCodeRepositoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_code_repository {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for update_code_repository"),
+        }
     }
-
-    /// <p><p>Deploys the new <code>EndpointConfig</code> specified in the request, switches to using newly created endpoint, and then deletes resources provisioned for the endpoint using the previous <code>EndpointConfig</code> (there is no availability loss). </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p> <note> <p>You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note></p>
     fn update_endpoint(
         &self,
         input: UpdateEndpointInput,
     ) -> RusotoFuture<UpdateEndpointOutput, UpdateEndpointError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.UpdateEndpoint");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateEndpointOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateEndpointError::from_response(response))),
-                )
-            }
-        })
+        match self.update_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for update_endpoint"),
+        }
     }
-
-    /// <p>Updates variant weight of one or more variants associated with an existing endpoint, or capacity of one variant associated with an existing endpoint. When it receives the request, Amazon SageMaker sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/API_DescribeEndpoint.html">DescribeEndpoint</a> API. </p>
     fn update_endpoint_weights_and_capacities(
         &self,
         input: UpdateEndpointWeightsAndCapacitiesInput,
@@ -9388,61 +18529,22 @@ impl SageMaker for SageMakerClient {
         UpdateEndpointWeightsAndCapacitiesOutput,
         UpdateEndpointWeightsAndCapacitiesError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.UpdateEndpointWeightsAndCapacities",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateEndpointWeightsAndCapacitiesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateEndpointWeightsAndCapacitiesError::from_response(
-                        response,
-                    ))
-                }))
+        match self.update_endpoint_weights_and_capacities {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSageMaker has no handler for update_endpoint_weights_and_capacities")
             }
-        })
+        }
     }
-
-    /// <p>Updates a notebook instance. NotebookInstance updates include upgrading or downgrading the ML compute instance used for your notebook instance to accommodate changes in your workload requirements.</p>
     fn update_notebook_instance(
         &self,
         input: UpdateNotebookInstanceInput,
     ) -> RusotoFuture<UpdateNotebookInstanceOutput, UpdateNotebookInstanceError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.UpdateNotebookInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNotebookInstanceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateNotebookInstanceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_notebook_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for update_notebook_instance"),
+        }
     }
-
-    /// <p>Updates a notebook instance lifecycle configuration created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
     fn update_notebook_instance_lifecycle_config(
         &self,
         input: UpdateNotebookInstanceLifecycleConfigInput,
@@ -9450,58 +18552,20 @@ impl SageMaker for SageMakerClient {
         UpdateNotebookInstanceLifecycleConfigOutput,
         UpdateNotebookInstanceLifecycleConfigError,
     > {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SageMaker.UpdateNotebookInstanceLifecycleConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNotebookInstanceLifecycleConfigOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateNotebookInstanceLifecycleConfigError::from_response(
-                        response,
-                    ))
-                }))
+        match self.update_notebook_instance_lifecycle_config {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSageMaker has no handler for update_notebook_instance_lifecycle_config")
             }
-        })
+        }
     }
-
-    /// <p>Updates an existing work team with new member definitions or description.</p>
     fn update_workteam(
         &self,
         input: UpdateWorkteamRequest,
     ) -> RusotoFuture<UpdateWorkteamResponse, UpdateWorkteamError> {
-        let mut request = SignedRequest::new("POST", "sagemaker", &self.region, "/");
-        request.set_endpoint_prefix("api.sagemaker".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "SageMaker.UpdateWorkteam");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateWorkteamResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateWorkteamError::from_response(response))),
-                )
-            }
-        })
+        match self.update_workteam {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSageMaker has no handler for update_workteam"),
+        }
     }
 }
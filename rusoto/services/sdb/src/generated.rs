@@ -23,8 +23,8 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
@@ -35,6 +35,8 @@ use xml::EventReader;
 
 /// <p></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Attribute {
     /// <p></p>
     pub alternate_name_encoding: Option<String>,
@@ -157,6 +159,8 @@ impl AttributeNameListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDeleteAttributesRequest {
     /// <p>The name of the domain in which the attributes are being deleted.</p>
     pub domain_name: String,
@@ -183,6 +187,8 @@ impl BatchDeleteAttributesRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchPutAttributesRequest {
     /// <p>The name of the domain in which the attributes are being stored.</p>
     pub domain_name: String,
@@ -209,6 +215,8 @@ impl BatchPutAttributesRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDomainRequest {
     /// <p>The name of the domain to create. The name can range between 3 and 255 characters and can contain the following characters: a-z, A-Z, 0-9, &#39;_&#39;, &#39;-&#39;, and &#39;.&#39;.</p>
     pub domain_name: String,
@@ -228,6 +236,8 @@ impl CreateDomainRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletableItem {
     pub attributes: Option<Vec<Attribute>>,
     pub name: String,
@@ -265,6 +275,8 @@ impl DeletableItemListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAttributesRequest {
     /// <p>A list of Attributes. Similar to columns on a spreadsheet, attributes represent categories of data that can be assigned to items.</p>
     pub attributes: Option<Vec<Attribute>>,
@@ -305,6 +317,8 @@ impl DeleteAttributesRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDomainRequest {
     /// <p>The name of the domain to delete.</p>
     pub domain_name: String,
@@ -324,6 +338,8 @@ impl DeleteDomainRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DomainMetadataRequest {
     /// <p>The name of the domain for which to display the metadata of.</p>
     pub domain_name: String,
@@ -343,6 +359,8 @@ impl DomainMetadataRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DomainMetadataResult {
     /// <p>The number of unique attribute names in the domain.</p>
     pub attribute_name_count: Option<i64>,
@@ -437,6 +455,8 @@ impl DomainNameListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAttributesRequest {
     /// <p>The names of the attributes.</p>
     pub attribute_names: Option<Vec<String>>,
@@ -473,6 +493,8 @@ impl GetAttributesRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAttributesResult {
     /// <p>The list of attributes returned by the operation.</p>
     pub attributes: Option<Vec<Attribute>>,
@@ -503,7 +525,7 @@ impl IntegerDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -511,6 +533,8 @@ impl IntegerDeserializer {
 }
 /// <p></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Item {
     /// <p></p>
     pub alternate_name_encoding: Option<String>,
@@ -573,6 +597,8 @@ impl ItemListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainsRequest {
     /// <p>The maximum number of domain names you want returned. The range is 1 to 100. The default setting is 100.</p>
     pub max_number_of_domains: Option<i64>,
@@ -599,6 +625,8 @@ impl ListDomainsRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainsResult {
     /// <p>A list of domain names that match the expression.</p>
     pub domain_names: Option<Vec<String>>,
@@ -634,13 +662,15 @@ impl LongDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAttributesRequest {
     /// <p>The list of attributes.</p>
     pub attributes: Vec<ReplaceableAttribute>,
@@ -680,6 +710,8 @@ impl PutAttributesRequestSerializer {
 
 /// <p></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplaceableAttribute {
     /// <p>The name of the replaceable attribute.</p>
     pub name: String,
@@ -719,6 +751,8 @@ impl ReplaceableAttributeListSerializer {
 
 /// <p></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReplaceableItem {
     /// <p>The list of attributes for a replaceable item.</p>
     pub attributes: Vec<ReplaceableAttribute>,
@@ -756,6 +790,8 @@ impl ReplaceableItemListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectRequest {
     /// <p>Determines whether or not strong consistency should be enforced when data is read from SimpleDB. If <code>true</code>, any data previously written to SimpleDB will be returned. Otherwise, results will be consistent eventually, and the client may not see data that was written immediately before your read.</p>
     pub consistent_read: Option<bool>,
@@ -788,6 +824,8 @@ impl SelectRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SelectResult {
     /// <p>A list of items that match the select expression.</p>
     pub items: Option<Vec<Item>>,
@@ -831,6 +869,8 @@ impl StringDeserializer {
 }
 /// <p> Specifies the conditions under which data should be updated. If an update condition is specified for a request, the data will only be updated if the condition is satisfied. For example, if an attribute with a specific name and value exists, or if a specific attribute doesn't exist. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCondition {
     /// <p>A value specifying whether or not the specified attribute must exist with the specified value in order for the update condition to be satisfied. Specify <code>true</code> if the attribute must exist for the update condition to be satisfied. Specify <code>false</code> if the attribute should not exist in order for the update condition to be satisfied.</p>
     pub exists: Option<bool>,
@@ -1631,6 +1671,157 @@ pub trait SimpleDb {
     /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
     fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError>;
 }
+const _: Option<&dyn SimpleDb> = None;
+impl<'a, T: SimpleDb + ?Sized> SimpleDb for &'a T {
+    fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> RusotoFuture<(), BatchDeleteAttributesError> {
+        (**self).batch_delete_attributes(input)
+    }
+    fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> RusotoFuture<(), BatchPutAttributesError> {
+        (**self).batch_put_attributes(input)
+    }
+    fn create_domain(&self, input: CreateDomainRequest) -> RusotoFuture<(), CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<(), DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_domain(&self, input: DeleteDomainRequest) -> RusotoFuture<(), DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> RusotoFuture<DomainMetadataResult, DomainMetadataError> {
+        (**self).domain_metadata(input)
+    }
+    fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> RusotoFuture<GetAttributesResult, GetAttributesError> {
+        (**self).get_attributes(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResult, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn put_attributes(&self, input: PutAttributesRequest) -> RusotoFuture<(), PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError> {
+        (**self).select(input)
+    }
+}
+impl<T: SimpleDb + ?Sized> SimpleDb for Box<T> {
+    fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> RusotoFuture<(), BatchDeleteAttributesError> {
+        (**self).batch_delete_attributes(input)
+    }
+    fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> RusotoFuture<(), BatchPutAttributesError> {
+        (**self).batch_put_attributes(input)
+    }
+    fn create_domain(&self, input: CreateDomainRequest) -> RusotoFuture<(), CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<(), DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_domain(&self, input: DeleteDomainRequest) -> RusotoFuture<(), DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> RusotoFuture<DomainMetadataResult, DomainMetadataError> {
+        (**self).domain_metadata(input)
+    }
+    fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> RusotoFuture<GetAttributesResult, GetAttributesError> {
+        (**self).get_attributes(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResult, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn put_attributes(&self, input: PutAttributesRequest) -> RusotoFuture<(), PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError> {
+        (**self).select(input)
+    }
+}
+impl<T: SimpleDb + ?Sized> SimpleDb for ::std::sync::Arc<T> {
+    fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> RusotoFuture<(), BatchDeleteAttributesError> {
+        (**self).batch_delete_attributes(input)
+    }
+    fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> RusotoFuture<(), BatchPutAttributesError> {
+        (**self).batch_put_attributes(input)
+    }
+    fn create_domain(&self, input: CreateDomainRequest) -> RusotoFuture<(), CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<(), DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_domain(&self, input: DeleteDomainRequest) -> RusotoFuture<(), DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> RusotoFuture<DomainMetadataResult, DomainMetadataError> {
+        (**self).domain_metadata(input)
+    }
+    fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> RusotoFuture<GetAttributesResult, GetAttributesError> {
+        (**self).get_attributes(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResult, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn put_attributes(&self, input: PutAttributesRequest) -> RusotoFuture<(), PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError> {
+        (**self).select(input)
+    }
+}
 /// A client for the Amazon SimpleDB API.
 #[derive(Clone)]
 pub struct SimpleDbClient {
@@ -1675,6 +1866,7 @@ impl SimpleDb for SimpleDbClient {
         input: BatchDeleteAttributesRequest,
     ) -> RusotoFuture<(), BatchDeleteAttributesError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchDeleteAttributes");
         let mut params = Params::new();
 
         params.put("Action", "BatchDeleteAttributes");
@@ -1700,6 +1892,7 @@ impl SimpleDb for SimpleDbClient {
         input: BatchPutAttributesRequest,
     ) -> RusotoFuture<(), BatchPutAttributesError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("BatchPutAttributes");
         let mut params = Params::new();
 
         params.put("Action", "BatchPutAttributes");
@@ -1725,6 +1918,7 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The <code>CreateDomain</code> operation creates a new domain. The domain name should be unique among the domains associated with the Access Key ID provided in the request. The <code>CreateDomain</code> operation may take 10 or more seconds to complete. </p> <p> The client can create up to 100 domains per account. </p> <p> If the client requires additional domains, go to <a href="http://aws.amazon.com/contact-us/simpledb-limit-request/"> http://aws.amazon.com/contact-us/simpledb-limit-request/</a>. </p>
     fn create_domain(&self, input: CreateDomainRequest) -> RusotoFuture<(), CreateDomainError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("CreateDomain");
         let mut params = Params::new();
 
         params.put("Action", "CreateDomain");
@@ -1753,6 +1947,7 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteAttributesRequest,
     ) -> RusotoFuture<(), DeleteAttributesError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteAttributes");
         let mut params = Params::new();
 
         params.put("Action", "DeleteAttributes");
@@ -1778,6 +1973,7 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The <code>DeleteDomain</code> operation deletes a domain. Any items (and their attributes) in the domain are deleted as well. The <code>DeleteDomain</code> operation might take 10 or more seconds to complete. </p>
     fn delete_domain(&self, input: DeleteDomainRequest) -> RusotoFuture<(), DeleteDomainError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let mut params = Params::new();
 
         params.put("Action", "DeleteDomain");
@@ -1806,6 +2002,7 @@ impl SimpleDb for SimpleDbClient {
         input: DomainMetadataRequest,
     ) -> RusotoFuture<DomainMetadataResult, DomainMetadataError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("DomainMetadata");
         let mut params = Params::new();
 
         params.put("Action", "DomainMetadata");
@@ -1830,6 +2027,7 @@ impl SimpleDb for SimpleDbClient {
                 if response.body.is_empty() {
                     result = DomainMetadataResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1841,7 +2039,8 @@ impl SimpleDb for SimpleDbClient {
                     result = DomainMetadataResultDeserializer::deserialize(
                         "DomainMetadataResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DomainMetadata", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1857,6 +2056,7 @@ impl SimpleDb for SimpleDbClient {
         input: GetAttributesRequest,
     ) -> RusotoFuture<GetAttributesResult, GetAttributesError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("GetAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetAttributes");
@@ -1881,6 +2081,7 @@ impl SimpleDb for SimpleDbClient {
                 if response.body.is_empty() {
                     result = GetAttributesResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1892,7 +2093,8 @@ impl SimpleDb for SimpleDbClient {
                     result = GetAttributesResultDeserializer::deserialize(
                         "GetAttributesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetAttributes", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1908,6 +2110,7 @@ impl SimpleDb for SimpleDbClient {
         input: ListDomainsRequest,
     ) -> RusotoFuture<ListDomainsResult, ListDomainsError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("ListDomains");
         let mut params = Params::new();
 
         params.put("Action", "ListDomains");
@@ -1932,6 +2135,7 @@ impl SimpleDb for SimpleDbClient {
                 if response.body.is_empty() {
                     result = ListDomainsResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1940,10 +2144,9 @@ impl SimpleDb for SimpleDbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = ListDomainsResultDeserializer::deserialize(
-                        "ListDomainsResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        ListDomainsResultDeserializer::deserialize("ListDomainsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListDomains", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1956,6 +2159,7 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The PutAttributes operation creates or replaces attributes in an item. The client may specify new attributes using a combination of the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> parameters. The client specifies the first attribute by the parameters <code>Attribute.0.Name</code> and <code>Attribute.0.Value</code>, the second attribute by the parameters <code>Attribute.1.Name</code> and <code>Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified in an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", second_value" }</code>. However, it cannot have two attribute instances where both the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> are the same. </p> <p> Optionally, the requestor can supply the <code>Replace</code> parameter for each individual attribute. Setting this value to <code>true</code> causes the new attribute value to replace the existing attribute value(s). For example, if an item has the attributes <code>{ 'a', '1' }</code>, <code>{ 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requestor calls <code>PutAttributes</code> using the attributes <code>{ 'b', '4' }</code> with the <code>Replace</code> parameter set to true, the final attributes of the item are changed to <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, which replaces the previous values of the 'b' attribute with the new value. </p> <p> You cannot specify an empty string as an attribute name. </p> <p> Because Amazon SimpleDB makes multiple copies of client data and uses an eventual consistency update model, an immediate <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <a>PutAttributes</a> or <a>DeleteAttributes</a> operation (write) might not return the updated data. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 total attribute name-value pairs per item</li> <li>One billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> </ul> </p>
     fn put_attributes(&self, input: PutAttributesRequest) -> RusotoFuture<(), PutAttributesError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("PutAttributes");
         let mut params = Params::new();
 
         params.put("Action", "PutAttributes");
@@ -1981,6 +2185,7 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
     fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation("Select");
         let mut params = Params::new();
 
         params.put("Action", "Select");
@@ -2005,6 +2210,7 @@ impl SimpleDb for SimpleDbClient {
                 if response.body.is_empty() {
                     result = SelectResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -2013,7 +2219,8 @@ impl SimpleDb for SimpleDbClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = SelectResultDeserializer::deserialize("SelectResult", &mut stack)?;
+                    result = SelectResultDeserializer::deserialize("SelectResult", &mut stack)
+                        .map_err(|err| stack.parse_error("Select", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -2023,3 +2230,317 @@ impl SimpleDb for SimpleDbClient {
         })
     }
 }
+
+/// A mock of the Amazon SimpleDB API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSimpleDb {
+    batch_delete_attributes: Option<
+        Box<
+            dyn Fn(
+                    BatchDeleteAttributesRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<BatchDeleteAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    batch_put_attributes: Option<
+        Box<
+            dyn Fn(
+                    BatchPutAttributesRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<BatchPutAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_domain: Option<
+        Box<
+            dyn Fn(CreateDomainRequest) -> ::std::result::Result<(), RusotoError<CreateDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_attributes: Option<
+        Box<
+            dyn Fn(
+                    DeleteAttributesRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_domain: Option<
+        Box<
+            dyn Fn(DeleteDomainRequest) -> ::std::result::Result<(), RusotoError<DeleteDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    domain_metadata: Option<
+        Box<
+            dyn Fn(
+                    DomainMetadataRequest,
+                )
+                    -> ::std::result::Result<DomainMetadataResult, RusotoError<DomainMetadataError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetAttributesRequest,
+                )
+                    -> ::std::result::Result<GetAttributesResult, RusotoError<GetAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_domains: Option<
+        Box<
+            dyn Fn(
+                    ListDomainsRequest,
+                )
+                    -> ::std::result::Result<ListDomainsResult, RusotoError<ListDomainsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_attributes: Option<
+        Box<
+            dyn Fn(
+                    PutAttributesRequest,
+                ) -> ::std::result::Result<(), RusotoError<PutAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    select: Option<
+        Box<
+            dyn Fn(SelectRequest) -> ::std::result::Result<SelectResult, RusotoError<SelectError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSimpleDb {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSimpleDb {
+        Default::default()
+    }
+
+    /// Answers `batch_delete_attributes` with what `handler` returns for its input.
+    pub fn with_batch_delete_attributes<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(
+                BatchDeleteAttributesRequest,
+            ) -> ::std::result::Result<(), RusotoError<BatchDeleteAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_delete_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_put_attributes` with what `handler` returns for its input.
+    pub fn with_batch_put_attributes<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(
+                BatchPutAttributesRequest,
+            ) -> ::std::result::Result<(), RusotoError<BatchPutAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_put_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_domain` with what `handler` returns for its input.
+    pub fn with_create_domain<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(CreateDomainRequest) -> ::std::result::Result<(), RusotoError<CreateDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_attributes` with what `handler` returns for its input.
+    pub fn with_delete_attributes<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(
+                DeleteAttributesRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_domain` with what `handler` returns for its input.
+    pub fn with_delete_domain<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(DeleteDomainRequest) -> ::std::result::Result<(), RusotoError<DeleteDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `domain_metadata` with what `handler` returns for its input.
+    pub fn with_domain_metadata<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(
+                DomainMetadataRequest,
+            )
+                -> ::std::result::Result<DomainMetadataResult, RusotoError<DomainMetadataError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.domain_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_attributes` with what `handler` returns for its input.
+    pub fn with_get_attributes<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(
+                GetAttributesRequest,
+            )
+                -> ::std::result::Result<GetAttributesResult, RusotoError<GetAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_domains` with what `handler` returns for its input.
+    pub fn with_list_domains<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(
+                ListDomainsRequest,
+            )
+                -> ::std::result::Result<ListDomainsResult, RusotoError<ListDomainsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_domains = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_attributes` with what `handler` returns for its input.
+    pub fn with_put_attributes<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(PutAttributesRequest) -> ::std::result::Result<(), RusotoError<PutAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `select` with what `handler` returns for its input.
+    pub fn with_select<F>(mut self, handler: F) -> MockSimpleDb
+    where
+        F: Fn(SelectRequest) -> ::std::result::Result<SelectResult, RusotoError<SelectError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.select = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl SimpleDb for MockSimpleDb {
+    fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> RusotoFuture<(), BatchDeleteAttributesError> {
+        match self.batch_delete_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for batch_delete_attributes"),
+        }
+    }
+    fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> RusotoFuture<(), BatchPutAttributesError> {
+        match self.batch_put_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for batch_put_attributes"),
+        }
+    }
+    fn create_domain(&self, input: CreateDomainRequest) -> RusotoFuture<(), CreateDomainError> {
+        match self.create_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for create_domain"),
+        }
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<(), DeleteAttributesError> {
+        match self.delete_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for delete_attributes"),
+        }
+    }
+    fn delete_domain(&self, input: DeleteDomainRequest) -> RusotoFuture<(), DeleteDomainError> {
+        match self.delete_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for delete_domain"),
+        }
+    }
+    fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> RusotoFuture<DomainMetadataResult, DomainMetadataError> {
+        match self.domain_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for domain_metadata"),
+        }
+    }
+    fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> RusotoFuture<GetAttributesResult, GetAttributesError> {
+        match self.get_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for get_attributes"),
+        }
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResult, ListDomainsError> {
+        match self.list_domains {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for list_domains"),
+        }
+    }
+    fn put_attributes(&self, input: PutAttributesRequest) -> RusotoFuture<(), PutAttributesError> {
+        match self.put_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for put_attributes"),
+        }
+    }
+    fn select(&self, input: SelectRequest) -> RusotoFuture<SelectResult, SelectError> {
+        match self.select {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSimpleDb has no handler for select"),
+        }
+    }
+}
@@ -22,16 +22,29 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelRotateSecretRequest {
     /// <p><p>Specifies the secret for which you want to cancel a rotation request. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
     pub secret_id: String,
 }
 
+impl Validate for CancelRotateSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelRotateSecretResponse {
     /// <p>The ARN of the secret for which rotation was canceled.</p>
     #[serde(rename = "ARN")]
@@ -48,6 +61,7 @@ pub struct CancelRotateSecretResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSecretRequest {
     /// <p>(Optional) If you include <code>SecretString</code> or <code>SecretBinary</code>, then an initial version is created as part of the secret, and this parameter specifies a unique identifier for the new version. </p> <note> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes it as the value for this parameter in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for the new version and include that value in the request.</p> </note> <p>This value helps ensure idempotency. Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during a rotation. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness of your versions within the specified secret. </p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are the same as those in the request, then the request is ignored (the operation is idempotent).</p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are different from those in the request then the request fails because you cannot modify an existing version. Instead, use <a>PutSecretValue</a> to create a new version.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -83,8 +97,65 @@ pub struct CreateSecretRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        if let Some(ref value) = self.secret_binary {
+            errors.check_length(
+                &field_path(path, "secret_binary"),
+                value.len(),
+                Some(1),
+                Some(65536),
+            );
+        }
+        if let Some(ref value) = self.secret_string {
+            errors.check_length(
+                &field_path(path, "secret_string"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSecretResponse {
     /// <p><p>The Amazon Resource Name (ARN) of the secret that you just created.</p> <note> <p>Secrets Manager automatically adds several random characters to the name at the end of the ARN when you initially create a secret. This affects only the ARN and not the actual friendly name. This ensures that if you create a new secret with the same name as an old secret that you previously deleted, then users with access to the old secret <i>don&#39;t</i> automatically get access to the new secret because the ARNs are different.</p> </note></p>
     #[serde(rename = "ARN")]
@@ -101,14 +172,26 @@ pub struct CreateSecretResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourcePolicyRequest {
     /// <p><p>Specifies the secret that you want to delete the attached resource-based policy for. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
     pub secret_id: String,
 }
 
+impl Validate for DeleteResourcePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResourcePolicyResponse {
     /// <p>The ARN of the secret that the resource-based policy was deleted for.</p>
     #[serde(rename = "ARN")]
@@ -121,6 +204,7 @@ pub struct DeleteResourcePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSecretRequest {
     /// <p><p>(Optional) Specifies that the secret is to be deleted without any recovery window. You can&#39;t use both this parameter and the <code>RecoveryWindowInDays</code> parameter in the same API call.</p> <p>An asynchronous background process performs the actual deletion, so there can be a short delay before the operation completes. If you write code to delete and then immediately recreate a secret with the same name, ensure that your code includes appropriate back off and retry logic.</p> <important> <p>Use this parameter with caution. This parameter causes the operation to skip the normal waiting period before the permanent deletion that AWS would normally impose with the <code>RecoveryWindowInDays</code> parameter. If you delete a secret with the <code>ForceDeleteWithouRecovery</code> parameter, then you have no opportunity to recover the secret. It is permanently lost.</p> </important></p>
     #[serde(rename = "ForceDeleteWithoutRecovery")]
@@ -135,8 +219,19 @@ pub struct DeleteSecretRequest {
     pub secret_id: String,
 }
 
+impl Validate for DeleteSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSecretResponse {
     /// <p>The ARN of the secret that is now scheduled for deletion.</p>
     #[serde(rename = "ARN")]
@@ -153,14 +248,26 @@ pub struct DeleteSecretResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSecretRequest {
     /// <p><p>The identifier of the secret whose details you want to retrieve. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
     pub secret_id: String,
 }
 
+impl Validate for DescribeSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSecretResponse {
     /// <p>The ARN of the secret.</p>
     #[serde(rename = "ARN")]
@@ -220,6 +327,7 @@ pub struct DescribeSecretResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRandomPasswordRequest {
     /// <p>A string that includes characters that should not be included in the generated password. The default is that all characters from the included sets can be used.</p>
     #[serde(rename = "ExcludeCharacters")]
@@ -255,8 +363,29 @@ pub struct GetRandomPasswordRequest {
     pub require_each_included_type: Option<bool>,
 }
 
+impl Validate for GetRandomPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.exclude_characters {
+            errors.check_length(
+                &field_path(path, "exclude_characters"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.password_length {
+            errors.check_range(
+                &field_path(path, "password_length"),
+                *value,
+                Some(1),
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRandomPasswordResponse {
     /// <p>A string with the generated password.</p>
     #[serde(rename = "RandomPassword")]
@@ -265,14 +394,26 @@ pub struct GetRandomPasswordResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourcePolicyRequest {
     /// <p><p>Specifies the secret that you want to retrieve the attached resource-based policy for. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
     pub secret_id: String,
 }
 
+impl Validate for GetResourcePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourcePolicyResponse {
     /// <p>The ARN of the secret that the resource-based policy was retrieved for.</p>
     #[serde(rename = "ARN")]
@@ -289,6 +430,7 @@ pub struct GetResourcePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSecretValueRequest {
     /// <p><p>Specifies the secret containing the version that you want to retrieve. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
@@ -303,8 +445,35 @@ pub struct GetSecretValueRequest {
     pub version_stage: Option<String>,
 }
 
+impl Validate for GetSecretValueRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.version_stage {
+            errors.check_length(
+                &field_path(path, "version_stage"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSecretValueResponse {
     /// <p>The ARN of the secret.</p>
     #[serde(rename = "ARN")]
@@ -342,6 +511,7 @@ pub struct GetSecretValueResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSecretVersionIdsRequest {
     /// <p>(Optional) Specifies that you want the results to include versions that do not have any staging labels attached to them. Such versions are considered deprecated and are subject to deletion by Secrets Manager as needed.</p>
     #[serde(rename = "IncludeDeprecated")]
@@ -360,8 +530,30 @@ pub struct ListSecretVersionIdsRequest {
     pub secret_id: String,
 }
 
+impl Validate for ListSecretVersionIdsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSecretVersionIdsResponse {
     /// <p><p>The Amazon Resource Name (ARN) for the secret.</p> <note> <p>Secrets Manager automatically adds several random characters to the name at the end of the ARN when you initially create a secret. This affects only the ARN and not the actual friendly name. This ensures that if you create a new secret with the same name as an old secret that you previously deleted, then users with access to the old secret <i>don&#39;t</i> automatically get access to the new secret because the ARNs are different.</p> </note></p>
     #[serde(rename = "ARN")]
@@ -382,6 +574,7 @@ pub struct ListSecretVersionIdsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSecretsRequest {
     /// <p>(Optional) Limits the number of results that you want to include in the response. If you don't include this parameter, it defaults to a value that's specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (isn't null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Secrets Manager might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
@@ -393,8 +586,24 @@ pub struct ListSecretsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListSecretsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSecretsResponse {
     /// <p>If present in the response, this value indicates that there's more output available than what's included in the current response. This can occur even when the response includes no values at all, such as when you ask for a filtered view of a very long list. Use this value in the <code>NextToken</code> request parameter in a subsequent call to the operation to continue processing and get the next part of the output. You should repeat this until the <code>NextToken</code> response element comes back empty (as <code>null</code>).</p>
     #[serde(rename = "NextToken")]
@@ -407,6 +616,7 @@ pub struct ListSecretsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutResourcePolicyRequest {
     /// <p>A JSON-formatted string that's constructed according to the grammar and syntax for an AWS resource-based policy. The policy in the string identifies who can access or manage this secret and its versions. For information on how to format a JSON parameter for the various command line tool environments, see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#cli-using-param-json">Using JSON for Parameters</a> in the <i>AWS CLI User Guide</i>.</p>
     #[serde(rename = "ResourcePolicy")]
@@ -416,8 +626,25 @@ pub struct PutResourcePolicyRequest {
     pub secret_id: String,
 }
 
+impl Validate for PutResourcePolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_policy"),
+            self.resource_policy.chars().count(),
+            Some(1),
+            Some(20480),
+        );
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutResourcePolicyResponse {
     /// <p>The ARN of the secret that the resource-based policy was retrieved for.</p>
     #[serde(rename = "ARN")]
@@ -430,6 +657,7 @@ pub struct PutResourcePolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutSecretValueRequest {
     /// <p>(Optional) Specifies a unique identifier for the new version of the secret. </p> <note> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request. </p> </note> <p>This value helps ensure idempotency. Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the Lambda rotation function's processing. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> or <code>SecretBinary</code> values are the same as those in the request then the request is ignored (the operation is idempotent). </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are different from those in the request then the request fails because you cannot modify an existing secret version. You can only create new versions to store new secret values.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -457,8 +685,55 @@ pub struct PutSecretValueRequest {
     pub version_stages: Option<Vec<String>>,
 }
 
+impl Validate for PutSecretValueRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.secret_binary {
+            errors.check_length(
+                &field_path(path, "secret_binary"),
+                value.len(),
+                Some(1),
+                Some(65536),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.secret_string {
+            errors.check_length(
+                &field_path(path, "secret_string"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+        }
+        if let Some(ref value) = self.version_stages {
+            errors.check_length(
+                &field_path(path, "version_stages"),
+                value.len(),
+                Some(1),
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "version_stages"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutSecretValueResponse {
     /// <p>The Amazon Resource Name (ARN) for the secret for which you just created a version.</p>
     #[serde(rename = "ARN")]
@@ -479,14 +754,26 @@ pub struct PutSecretValueResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreSecretRequest {
     /// <p><p>Specifies the secret that you want to restore from a previously scheduled deletion. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
     pub secret_id: String,
 }
 
+impl Validate for RestoreSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreSecretResponse {
     /// <p>The ARN of the secret that was restored.</p>
     #[serde(rename = "ARN")]
@@ -499,6 +786,7 @@ pub struct RestoreSecretResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RotateSecretRequest {
     /// <p>(Optional) Specifies a unique identifier for the new version of the secret that helps ensure idempotency. </p> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request for this parameter. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request.</p> <p>You only need to specify your own value if you are implementing your own retry logic and want to ensure that a given secret is not created twice. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <p>Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the function's processing. This value becomes the <code>VersionId</code> of the new version.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -517,8 +805,38 @@ pub struct RotateSecretRequest {
     pub secret_id: String,
 }
 
+impl Validate for RotateSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.rotation_lambda_arn {
+            errors.check_length(
+                &field_path(path, "rotation_lambda_arn"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.rotation_rules {
+            value.validate_at(&field_path(path, "rotation_rules"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RotateSecretResponse {
     /// <p>The ARN of the secret.</p>
     #[serde(rename = "ARN")]
@@ -543,9 +861,22 @@ pub struct RotationRulesType {
     pub automatically_after_days: Option<i64>,
 }
 
+impl Validate for RotationRulesType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.automatically_after_days {
+            errors.check_range(
+                &field_path(path, "automatically_after_days"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>A structure that contains the details about a secret. It does not include the encrypted <code>SecretString</code> and <code>SecretBinary</code> values. To get those values, use the <a>GetSecretValue</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecretListEntry {
     /// <p>The Amazon Resource Name (ARN) of the secret.</p> <p>For more information about ARNs in Secrets Manager, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/reference_iam-permissions.html#iam-resources">Policy Resources</a> in the <i>AWS Secrets Manager User Guide</i>.</p>
     #[serde(rename = "ARN")]
@@ -606,7 +937,7 @@ pub struct SecretListEntry {
 
 /// <p>A structure that contains information about one version of a secret.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SecretVersionsListEntry {
     /// <p>The date and time this version of the secret was created.</p>
     #[serde(rename = "CreatedDate")]
@@ -639,7 +970,29 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p><p>The identifier for the secret that you want to attach tags to. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
@@ -649,7 +1002,23 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p><p>The identifier for the secret that you want to remove tags from. You can specify either the Amazon Resource Name (ARN) or the friendly name of the secret.</p> <note> <p>If you specify an ARN, we generally recommend that you specify a complete ARN. You can specify a partial ARN too—for example, if you don’t include the final hyphen and six random characters that Secrets Manager adds at the end of the ARN when you created the secret. A partial ARN match can work as long as it uniquely matches only one secret. However, if your secret has a name that ends in a hyphen followed by six characters (before Secrets Manager adds the hyphen and six characters to the ARN) and you try to use that as a partial ARN, then those characters cause Secrets Manager to assume that you’re specifying a complete ARN. This confusion can cause unexpected results. To avoid this situation, we recommend that you don’t create secret names that end with a hyphen followed by six characters.</p> </note></p>
     #[serde(rename = "SecretId")]
@@ -659,7 +1028,23 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSecretRequest {
     /// <p>(Optional) If you want to add a new version to the secret, this parameter specifies a unique identifier for the new version that helps ensure idempotency. </p> <p>If you use the AWS CLI or one of the AWS SDK to call this operation, then you can leave this parameter empty. The CLI or SDK generates a random UUID for you and includes that in the request. If you don't use the SDK and instead generate a raw HTTP request to the Secrets Manager service endpoint, then you must generate a <code>ClientRequestToken</code> yourself for new versions and include that value in the request.</p> <p>You typically only need to interact with this value if you implement your own retry logic and want to ensure that a given secret is not created twice. We recommend that you generate a <a href="https://wikipedia.org/wiki/Universally_unique_identifier">UUID-type</a> value to ensure uniqueness within the specified secret. </p> <p>Secrets Manager uses this value to prevent the accidental creation of duplicate versions if there are failures and retries during the Lambda rotation function's processing.</p> <ul> <li> <p>If the <code>ClientRequestToken</code> value isn't already associated with a version of the secret then a new version of the secret is created. </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are the same as those in the request then the request is ignored (the operation is idempotent). </p> </li> <li> <p>If a version with this value already exists and that version's <code>SecretString</code> and <code>SecretBinary</code> values are different from the request then an error occurs because you cannot modify an existing secret value.</p> </li> </ul> <p>This value becomes the <code>VersionId</code> of the new version.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -691,8 +1076,59 @@ pub struct UpdateSecretRequest {
     pub secret_string: Option<String>,
 }
 
+impl Validate for UpdateSecretRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.kms_key_id {
+            errors.check_length(
+                &field_path(path, "kms_key_id"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.secret_binary {
+            errors.check_length(
+                &field_path(path, "secret_binary"),
+                value.len(),
+                Some(1),
+                Some(65536),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.secret_string {
+            errors.check_length(
+                &field_path(path, "secret_string"),
+                value.chars().count(),
+                Some(1),
+                Some(65536),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSecretResponse {
     /// <p><p>The ARN of the secret that was updated.</p> <note> <p>Secrets Manager automatically adds several random characters to the name at the end of the ARN when you initially create a secret. This affects only the ARN and not the actual friendly name. This ensures that if you create a new secret with the same name as an old secret that you previously deleted, then users with access to the old secret <i>don&#39;t</i> automatically get access to the new secret because the ARNs are different.</p> </note></p>
     #[serde(rename = "ARN")]
@@ -709,6 +1145,7 @@ pub struct UpdateSecretResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSecretVersionStageRequest {
     /// <p>(Optional) The secret version ID that you want to add the staging label to. If you want to remove a label from a version, then do not specify this parameter.</p> <p>If the staging label is already attached to a different version of the secret, then you must also specify the <code>RemoveFromVersionId</code> parameter. </p>
     #[serde(rename = "MoveToVersionId")]
@@ -726,8 +1163,41 @@ pub struct UpdateSecretVersionStageRequest {
     pub version_stage: String,
 }
 
+impl Validate for UpdateSecretVersionStageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.move_to_version_id {
+            errors.check_length(
+                &field_path(path, "move_to_version_id"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.remove_from_version_id {
+            errors.check_length(
+                &field_path(path, "remove_from_version_id"),
+                value.chars().count(),
+                Some(32),
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "secret_id"),
+            self.secret_id.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "version_stage"),
+            self.version_stage.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSecretVersionStageResponse {
     /// <p>The ARN of the secret with the staging label that was modified.</p>
     #[serde(rename = "ARN")]
@@ -770,7 +1240,12 @@ impl CancelRotateSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CancelRotateSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -848,7 +1323,12 @@ impl CreateSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -903,7 +1383,12 @@ impl DeleteResourcePolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -953,7 +1438,12 @@ impl DeleteSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -994,7 +1484,12 @@ impl DescribeSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1040,7 +1535,12 @@ impl GetRandomPasswordError {
                 "InvalidRequestException" => {
                     return RusotoError::Service(GetRandomPasswordError::InvalidRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1087,7 +1587,12 @@ impl GetResourcePolicyError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1142,7 +1647,12 @@ impl GetSecretValueError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetSecretValueError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1195,7 +1705,12 @@ impl ListSecretVersionIdsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1240,7 +1755,12 @@ impl ListSecretsError {
                 "InvalidParameterException" => {
                     return RusotoError::Service(ListSecretsError::InvalidParameter(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1299,7 +1819,12 @@ impl PutResourcePolicyError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1366,7 +1891,12 @@ impl PutSecretValueError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(PutSecretValueError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1420,7 +1950,12 @@ impl RestoreSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RestoreSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1471,7 +2006,12 @@ impl RotateSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RotateSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1522,7 +2062,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1573,7 +2118,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1651,7 +2201,12 @@ impl UpdateSecretError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateSecretError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1722,7 +2277,12 @@ impl UpdateSecretVersionStageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1849,183 +2409,541 @@ pub trait SecretsManager {
         input: UpdateSecretVersionStageRequest,
     ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError>;
 }
-/// A client for the AWS Secrets Manager API.
-#[derive(Clone)]
-pub struct SecretsManagerClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SecretsManagerClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SecretsManagerClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SecretsManagerClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerClient {
-        SecretsManagerClient { client, region }
-    }
-}
-
-impl SecretsManager for SecretsManagerClient {
-    /// <p><p>Disables automatic scheduled rotation and cancels the rotation of a secret if one is currently in progress.</p> <p>To re-enable scheduled rotation, call <a>RotateSecret</a> with <code>AutomaticallyRotateAfterDays</code> set to a value greater than 0. This will immediately rotate your secret and then enable the automatic schedule.</p> <note> <p>If you cancel a rotation that is in progress, it can leave the <code>VersionStage</code> labels in an unexpected state. Depending on what step of the rotation was in progress, you might need to remove the staging label <code>AWSPENDING</code> from the partially created version, specified by the <code>VersionId</code> response value. You should also evaluate the partially rotated new version to see if it should be deleted, which you can do by removing all staging labels from the new version&#39;s <code>VersionStage</code> field.</p> </note> <p>To successfully start a rotation, the staging label <code>AWSPENDING</code> must be in one of the following states:</p> <ul> <li> <p>Not be attached to any version at all</p> </li> <li> <p>Attached to the same version as the staging label <code>AWSCURRENT</code> </p> </li> </ul> <p>If the staging label <code>AWSPENDING</code> is attached to a different version than the version with <code>AWSCURRENT</code> then the attempt to rotate fails.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CancelRotateSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To configure rotation for a secret or to manually trigger a rotation, use <a>RotateSecret</a>.</p> </li> <li> <p>To get the rotation configuration details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> <li> <p>To list all of the versions currently associated with a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+const _: Option<&dyn SecretsManager> = None;
+impl<'a, T: SecretsManager + ?Sized> SecretsManager for &'a T {
     fn cancel_rotate_secret(
         &self,
         input: CancelRotateSecretRequest,
     ) -> RusotoFuture<CancelRotateSecretResponse, CancelRotateSecretError> {
-        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "secretsmanager.CancelRotateSecret");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelRotateSecretResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelRotateSecretError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_rotate_secret(input)
     }
-
-    /// <p><p>Creates a new secret. A secret in Secrets Manager consists of both the protected secret data and the important information needed to manage the secret.</p> <p>Secrets Manager stores the encrypted secret data in one of a collection of &quot;versions&quot; associated with the secret. Each version contains a copy of the encrypted secret data. Each version is associated with one or more &quot;staging labels&quot; that identify where the version is in the rotation cycle. The <code>SecretVersionsToStages</code> field of the secret contains the mapping of staging labels to the active versions of the secret. Versions without a staging label are considered deprecated and are not included in the list.</p> <p>You provide the secret data to be encrypted by putting text in either the <code>SecretString</code> parameter or binary data in the <code>SecretBinary</code> parameter, but not both. If you include <code>SecretString</code> or <code>SecretBinary</code> then Secrets Manager also creates an initial secret version and automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CreateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>secretsmanager:TagResource - needed only if you include the <code>Tags</code> parameter. </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> <li> <p>To modify an existing secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the encrypted secure string and secure binary values, use <a>GetSecretValue</a>.</p> </li> <li> <p>To retrieve all other details for a secret, use <a>DescribeSecret</a>. This does not include the encrypted secure string and secure binary values.</p> </li> <li> <p>To retrieve the list of secret versions associated with the current secret, use <a>DescribeSecret</a> and examine the <code>SecretVersionsToStages</code> response value.</p> </li> </ul></p>
     fn create_secret(
         &self,
         input: CreateSecretRequest,
     ) -> RusotoFuture<CreateSecretResponse, CreateSecretError> {
-        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "secretsmanager.CreateSecret");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSecretResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSecretError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_secret(input)
     }
-
-    /// <p><p>Deletes the resource-based permission policy that&#39;s attached to the secret.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To retrieve the current resource-based policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
     fn delete_resource_policy(
         &self,
         input: DeleteResourcePolicyRequest,
     ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
-        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "secretsmanager.DeleteResourcePolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteResourcePolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteResourcePolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_resource_policy(input)
     }
-
-    /// <p><p>Deletes an entire secret and all of its versions. You can optionally include a recovery window during which you can restore the secret. If you don&#39;t specify a recovery window value, the operation defaults to 30 days. Secrets Manager attaches a <code>DeletionDate</code> stamp to the secret that specifies the end of the recovery window. At the end of the recovery window, Secrets Manager deletes the secret permanently.</p> <p>At any time before recovery window ends, you can use <a>RestoreSecret</a> to remove the <code>DeletionDate</code> and cancel the deletion of the secret.</p> <p>You cannot access the encrypted secret information in any secret that is scheduled for deletion. If you need to access that information, you must cancel the deletion with <a>RestoreSecret</a> and then retrieve the information.</p> <note> <ul> <li> <p>There is no explicit operation to delete a version of a secret. Instead, remove all staging labels from the <code>VersionStage</code> field of a version. That marks the version as deprecated and allows Secrets Manager to delete it as needed. Versions that do not have any staging labels do not show up in <a>ListSecretVersionIds</a> unless you specify <code>IncludeDeprecated</code>.</p> </li> <li> <p>The permanent secret deletion at the end of the waiting period is performed as a background task with low priority. There is no guarantee of a specific time after the recovery window for the actual delete operation to occur.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To cancel deletion of a version of a secret before the recovery window has expired, use <a>RestoreSecret</a>.</p> </li> </ul></p>
     fn delete_secret(
         &self,
         input: DeleteSecretRequest,
     ) -> RusotoFuture<DeleteSecretResponse, DeleteSecretError> {
-        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "secretsmanager.DeleteSecret");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSecretResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSecretError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_secret(input)
     }
-
-    /// <p><p>Retrieves the details of a secret. It does not include the encrypted fields. Only those fields that are populated with a value are returned in the response. </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DescribeSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To modify a secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To retrieve the encrypted secret information in a version of the secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To list all of the secrets in the AWS account, use <a>ListSecrets</a>.</p> </li> </ul></p>
     fn describe_secret(
         &self,
         input: DescribeSecretRequest,
     ) -> RusotoFuture<DescribeSecretResponse, DescribeSecretError> {
-        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "secretsmanager.DescribeSecret");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSecretResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeSecretError::from_response(response))),
+        (**self).describe_secret(input)
+    }
+    fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> RusotoFuture<GetRandomPasswordResponse, GetRandomPasswordError> {
+        (**self).get_random_password(input)
+    }
+    fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy(input)
+    }
+    fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> RusotoFuture<GetSecretValueResponse, GetSecretValueError> {
+        (**self).get_secret_value(input)
+    }
+    fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> RusotoFuture<ListSecretVersionIdsResponse, ListSecretVersionIdsError> {
+        (**self).list_secret_version_ids(input)
+    }
+    fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> RusotoFuture<ListSecretsResponse, ListSecretsError> {
+        (**self).list_secrets(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> RusotoFuture<PutSecretValueResponse, PutSecretValueError> {
+        (**self).put_secret_value(input)
+    }
+    fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> RusotoFuture<RestoreSecretResponse, RestoreSecretError> {
+        (**self).restore_secret(input)
+    }
+    fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> RusotoFuture<RotateSecretResponse, RotateSecretError> {
+        (**self).rotate_secret(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> RusotoFuture<UpdateSecretResponse, UpdateSecretError> {
+        (**self).update_secret(input)
+    }
+    fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError> {
+        (**self).update_secret_version_stage(input)
+    }
+}
+impl<T: SecretsManager + ?Sized> SecretsManager for Box<T> {
+    fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> RusotoFuture<CancelRotateSecretResponse, CancelRotateSecretError> {
+        (**self).cancel_rotate_secret(input)
+    }
+    fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> RusotoFuture<CreateSecretResponse, CreateSecretError> {
+        (**self).create_secret(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> RusotoFuture<DeleteSecretResponse, DeleteSecretError> {
+        (**self).delete_secret(input)
+    }
+    fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> RusotoFuture<DescribeSecretResponse, DescribeSecretError> {
+        (**self).describe_secret(input)
+    }
+    fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> RusotoFuture<GetRandomPasswordResponse, GetRandomPasswordError> {
+        (**self).get_random_password(input)
+    }
+    fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy(input)
+    }
+    fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> RusotoFuture<GetSecretValueResponse, GetSecretValueError> {
+        (**self).get_secret_value(input)
+    }
+    fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> RusotoFuture<ListSecretVersionIdsResponse, ListSecretVersionIdsError> {
+        (**self).list_secret_version_ids(input)
+    }
+    fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> RusotoFuture<ListSecretsResponse, ListSecretsError> {
+        (**self).list_secrets(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> RusotoFuture<PutSecretValueResponse, PutSecretValueError> {
+        (**self).put_secret_value(input)
+    }
+    fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> RusotoFuture<RestoreSecretResponse, RestoreSecretError> {
+        (**self).restore_secret(input)
+    }
+    fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> RusotoFuture<RotateSecretResponse, RotateSecretError> {
+        (**self).rotate_secret(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> RusotoFuture<UpdateSecretResponse, UpdateSecretError> {
+        (**self).update_secret(input)
+    }
+    fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError> {
+        (**self).update_secret_version_stage(input)
+    }
+}
+impl<T: SecretsManager + ?Sized> SecretsManager for ::std::sync::Arc<T> {
+    fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> RusotoFuture<CancelRotateSecretResponse, CancelRotateSecretError> {
+        (**self).cancel_rotate_secret(input)
+    }
+    fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> RusotoFuture<CreateSecretResponse, CreateSecretError> {
+        (**self).create_secret(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> RusotoFuture<DeleteSecretResponse, DeleteSecretError> {
+        (**self).delete_secret(input)
+    }
+    fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> RusotoFuture<DescribeSecretResponse, DescribeSecretError> {
+        (**self).describe_secret(input)
+    }
+    fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> RusotoFuture<GetRandomPasswordResponse, GetRandomPasswordError> {
+        (**self).get_random_password(input)
+    }
+    fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy(input)
+    }
+    fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> RusotoFuture<GetSecretValueResponse, GetSecretValueError> {
+        (**self).get_secret_value(input)
+    }
+    fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> RusotoFuture<ListSecretVersionIdsResponse, ListSecretVersionIdsError> {
+        (**self).list_secret_version_ids(input)
+    }
+    fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> RusotoFuture<ListSecretsResponse, ListSecretsError> {
+        (**self).list_secrets(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> RusotoFuture<PutSecretValueResponse, PutSecretValueError> {
+        (**self).put_secret_value(input)
+    }
+    fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> RusotoFuture<RestoreSecretResponse, RestoreSecretError> {
+        (**self).restore_secret(input)
+    }
+    fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> RusotoFuture<RotateSecretResponse, RotateSecretError> {
+        (**self).rotate_secret(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> RusotoFuture<UpdateSecretResponse, UpdateSecretError> {
+        (**self).update_secret(input)
+    }
+    fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError> {
+        (**self).update_secret_version_stage(input)
+    }
+}
+/// A client for the AWS Secrets Manager API.
+#[derive(Clone)]
+pub struct SecretsManagerClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SecretsManagerClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SecretsManagerClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SecretsManagerClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerClient {
+        SecretsManagerClient { client, region }
+    }
+}
+
+impl SecretsManager for SecretsManagerClient {
+    /// <p><p>Disables automatic scheduled rotation and cancels the rotation of a secret if one is currently in progress.</p> <p>To re-enable scheduled rotation, call <a>RotateSecret</a> with <code>AutomaticallyRotateAfterDays</code> set to a value greater than 0. This will immediately rotate your secret and then enable the automatic schedule.</p> <note> <p>If you cancel a rotation that is in progress, it can leave the <code>VersionStage</code> labels in an unexpected state. Depending on what step of the rotation was in progress, you might need to remove the staging label <code>AWSPENDING</code> from the partially created version, specified by the <code>VersionId</code> response value. You should also evaluate the partially rotated new version to see if it should be deleted, which you can do by removing all staging labels from the new version&#39;s <code>VersionStage</code> field.</p> </note> <p>To successfully start a rotation, the staging label <code>AWSPENDING</code> must be in one of the following states:</p> <ul> <li> <p>Not be attached to any version at all</p> </li> <li> <p>Attached to the same version as the staging label <code>AWSCURRENT</code> </p> </li> </ul> <p>If the staging label <code>AWSPENDING</code> is attached to a different version than the version with <code>AWSCURRENT</code> then the attempt to rotate fails.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CancelRotateSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To configure rotation for a secret or to manually trigger a rotation, use <a>RotateSecret</a>.</p> </li> <li> <p>To get the rotation configuration details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> <li> <p>To list all of the versions currently associated with a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> RusotoFuture<CancelRotateSecretResponse, CancelRotateSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("CancelRotateSecret");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "secretsmanager.CancelRotateSecret");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelRotateSecretResponse, _>(
+                    response,
+                    "CancelRotateSecret",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelRotateSecretError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a new secret. A secret in Secrets Manager consists of both the protected secret data and the important information needed to manage the secret.</p> <p>Secrets Manager stores the encrypted secret data in one of a collection of &quot;versions&quot; associated with the secret. Each version contains a copy of the encrypted secret data. Each version is associated with one or more &quot;staging labels&quot; that identify where the version is in the rotation cycle. The <code>SecretVersionsToStages</code> field of the secret contains the mapping of staging labels to the active versions of the secret. Versions without a staging label are considered deprecated and are not included in the list.</p> <p>You provide the secret data to be encrypted by putting text in either the <code>SecretString</code> parameter or binary data in the <code>SecretBinary</code> parameter, but not both. If you include <code>SecretString</code> or <code>SecretBinary</code> then Secrets Manager also creates an initial secret version and automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> <note> <ul> <li> <p>If you call an operation that needs to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS having to create the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret is in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CreateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>secretsmanager:TagResource - needed only if you include the <code>Tags</code> parameter. </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> <li> <p>To modify an existing secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the encrypted secure string and secure binary values, use <a>GetSecretValue</a>.</p> </li> <li> <p>To retrieve all other details for a secret, use <a>DescribeSecret</a>. This does not include the encrypted secure string and secure binary values.</p> </li> <li> <p>To retrieve the list of secret versions associated with the current secret, use <a>DescribeSecret</a> and examine the <code>SecretVersionsToStages</code> response value.</p> </li> </ul></p>
+    fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> RusotoFuture<CreateSecretResponse, CreateSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("CreateSecret");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "secretsmanager.CreateSecret");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSecretResponse, _>(
+                    response,
+                    "CreateSecret",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSecretError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the resource-based permission policy that&#39;s attached to the secret.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To retrieve the current resource-based policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "secretsmanager.DeleteResourcePolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteResourcePolicyResponse, _>(
+                    response,
+                    "DeleteResourcePolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteResourcePolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes an entire secret and all of its versions. You can optionally include a recovery window during which you can restore the secret. If you don&#39;t specify a recovery window value, the operation defaults to 30 days. Secrets Manager attaches a <code>DeletionDate</code> stamp to the secret that specifies the end of the recovery window. At the end of the recovery window, Secrets Manager deletes the secret permanently.</p> <p>At any time before recovery window ends, you can use <a>RestoreSecret</a> to remove the <code>DeletionDate</code> and cancel the deletion of the secret.</p> <p>You cannot access the encrypted secret information in any secret that is scheduled for deletion. If you need to access that information, you must cancel the deletion with <a>RestoreSecret</a> and then retrieve the information.</p> <note> <ul> <li> <p>There is no explicit operation to delete a version of a secret. Instead, remove all staging labels from the <code>VersionStage</code> field of a version. That marks the version as deprecated and allows Secrets Manager to delete it as needed. Versions that do not have any staging labels do not show up in <a>ListSecretVersionIds</a> unless you specify <code>IncludeDeprecated</code>.</p> </li> <li> <p>The permanent secret deletion at the end of the waiting period is performed as a background task with low priority. There is no guarantee of a specific time after the recovery window for the actual delete operation to occur.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To cancel deletion of a version of a secret before the recovery window has expired, use <a>RestoreSecret</a>.</p> </li> </ul></p>
+    fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> RusotoFuture<DeleteSecretResponse, DeleteSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DeleteSecret");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "secretsmanager.DeleteSecret");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSecretResponse, _>(
+                    response,
+                    "DeleteSecret",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSecretError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the details of a secret. It does not include the encrypted fields. Only those fields that are populated with a value are returned in the response. </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DescribeSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To modify a secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To retrieve the encrypted secret information in a version of the secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To list all of the secrets in the AWS account, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> RusotoFuture<DescribeSecretResponse, DescribeSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("DescribeSecret");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "secretsmanager.DescribeSecret");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSecretResponse, _>(
+                    response,
+                    "DescribeSecret",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeSecretError::from_response(response))),
                 )
             }
         })
@@ -2036,7 +2954,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: GetRandomPasswordRequest,
     ) -> RusotoFuture<GetRandomPasswordResponse, GetRandomPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetRandomPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetRandomPassword");
@@ -2045,10 +2972,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRandomPasswordResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetRandomPasswordResponse, _>(
+                    response,
+                    "GetRandomPassword",
+                )
             } else {
                 Box::new(
                     response
@@ -2065,7 +2992,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: GetResourcePolicyRequest,
     ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetResourcePolicy");
@@ -2074,10 +3010,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourcePolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetResourcePolicyResponse, _>(
+                    response,
+                    "GetResourcePolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -2094,7 +3030,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: GetSecretValueRequest,
     ) -> RusotoFuture<GetSecretValueResponse, GetSecretValueError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("GetSecretValue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.GetSecretValue");
@@ -2103,10 +3048,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSecretValueResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetSecretValueResponse, _>(
+                    response,
+                    "GetSecretValue",
+                )
             } else {
                 Box::new(
                     response
@@ -2123,7 +3068,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: ListSecretVersionIdsRequest,
     ) -> RusotoFuture<ListSecretVersionIdsResponse, ListSecretVersionIdsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("ListSecretVersionIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.ListSecretVersionIds");
@@ -2132,10 +3086,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSecretVersionIdsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListSecretVersionIdsResponse, _>(
+                    response,
+                    "ListSecretVersionIds",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2151,7 +3105,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: ListSecretsRequest,
     ) -> RusotoFuture<ListSecretsResponse, ListSecretsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("ListSecrets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.ListSecrets");
@@ -2160,10 +3123,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSecretsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListSecretsResponse, _>(
+                    response,
+                    "ListSecrets",
+                )
             } else {
                 Box::new(
                     response
@@ -2180,7 +3143,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: PutResourcePolicyRequest,
     ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.PutResourcePolicy");
@@ -2189,10 +3161,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutResourcePolicyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutResourcePolicyResponse, _>(
+                    response,
+                    "PutResourcePolicy",
+                )
             } else {
                 Box::new(
                     response
@@ -2209,7 +3181,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: PutSecretValueRequest,
     ) -> RusotoFuture<PutSecretValueResponse, PutSecretValueError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("PutSecretValue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.PutSecretValue");
@@ -2218,10 +3199,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutSecretValueResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<PutSecretValueResponse, _>(
+                    response,
+                    "PutSecretValue",
+                )
             } else {
                 Box::new(
                     response
@@ -2238,7 +3219,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: RestoreSecretRequest,
     ) -> RusotoFuture<RestoreSecretResponse, RestoreSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("RestoreSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.RestoreSecret");
@@ -2247,10 +3237,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreSecretResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RestoreSecretResponse, _>(
+                    response,
+                    "RestoreSecret",
+                )
             } else {
                 Box::new(
                     response
@@ -2267,7 +3257,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: RotateSecretRequest,
     ) -> RusotoFuture<RotateSecretResponse, RotateSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("RotateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.RotateSecret");
@@ -2276,10 +3275,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RotateSecretResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RotateSecretResponse, _>(
+                    response,
+                    "RotateSecret",
+                )
             } else {
                 Box::new(
                     response
@@ -2293,7 +3292,16 @@ impl SecretsManager for SecretsManagerClient {
 
     /// <p><p>Attaches one or more tags, each consisting of a key name and a value, to the specified secret. Tags are part of the secret&#39;s overall metadata, and are not associated with any specific version of the secret. This operation only appends tags to the existing list of tags. To remove tags, you must use <a>UntagResource</a>.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per secret—50</p> </li> <li> <p>Maximum key length—127 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length—255 Unicode characters in UTF-8</p> </li> <li> <p>Tag keys and values are case sensitive.</p> </li> <li> <p>Do not use the <code>aws:</code> prefix in your tag names or values because it is reserved for AWS use. You can&#39;t edit or delete tag names or values with this prefix. Tags with this prefix do not count against your tags per secret limit.</p> </li> <li> <p>If your tagging schema will be used across multiple services and resources, remember that other services might have restrictions on allowed characters. Generally allowed characters are: letters, spaces, and numbers representable in UTF-8, plus the following special characters: + - = . _ : / @.</p> </li> </ul> <important> <p>If you use tags as part of your security strategy, then adding or removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:TagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To remove one or more tags from the collection attached to a secret, use <a>UntagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.TagResource");
@@ -2316,7 +3324,16 @@ impl SecretsManager for SecretsManagerClient {
 
     /// <p><p>Removes one or more tags from the specified secret.</p> <p>This operation is idempotent. If a requested tag is not attached to the secret, no error is returned and the secret metadata is unchanged.</p> <important> <p>If you use tags as part of your security strategy, then removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UntagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To add one or more tags to the collection attached to a secret, use <a>TagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UntagResource");
@@ -2342,7 +3359,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: UpdateSecretRequest,
     ) -> RusotoFuture<UpdateSecretResponse, UpdateSecretError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UpdateSecret");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecret");
@@ -2351,10 +3377,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSecretResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateSecretResponse, _>(
+                    response,
+                    "UpdateSecret",
+                )
             } else {
                 Box::new(
                     response
@@ -2371,7 +3397,16 @@ impl SecretsManager for SecretsManagerClient {
         &self,
         input: UpdateSecretVersionStageRequest,
     ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "secretsmanager", &self.region, "/");
+        request.set_operation("UpdateSecretVersionStage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecretVersionStage");
@@ -2380,10 +3415,10 @@ impl SecretsManager for SecretsManagerClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSecretVersionStageResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateSecretVersionStageResponse, _>(
+                    response,
+                    "UpdateSecretVersionStage",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateSecretVersionStageError::from_response(response))
@@ -2392,3 +3427,620 @@ impl SecretsManager for SecretsManagerClient {
         })
     }
 }
+
+/// A mock of the AWS Secrets Manager API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSecretsManager {
+    cancel_rotate_secret: Option<
+        Box<
+            dyn Fn(
+                    CancelRotateSecretRequest,
+                ) -> ::std::result::Result<
+                    CancelRotateSecretResponse,
+                    RusotoError<CancelRotateSecretError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_secret: Option<
+        Box<
+            dyn Fn(
+                    CreateSecretRequest,
+                )
+                    -> ::std::result::Result<CreateSecretResponse, RusotoError<CreateSecretError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourcePolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteResourcePolicyResponse,
+                    RusotoError<DeleteResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_secret: Option<
+        Box<
+            dyn Fn(
+                    DeleteSecretRequest,
+                )
+                    -> ::std::result::Result<DeleteSecretResponse, RusotoError<DeleteSecretError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_secret: Option<
+        Box<
+            dyn Fn(
+                    DescribeSecretRequest,
+                ) -> ::std::result::Result<
+                    DescribeSecretResponse,
+                    RusotoError<DescribeSecretError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_random_password: Option<
+        Box<
+            dyn Fn(
+                    GetRandomPasswordRequest,
+                ) -> ::std::result::Result<
+                    GetRandomPasswordResponse,
+                    RusotoError<GetRandomPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    GetResourcePolicyRequest,
+                ) -> ::std::result::Result<
+                    GetResourcePolicyResponse,
+                    RusotoError<GetResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_secret_value: Option<
+        Box<
+            dyn Fn(
+                    GetSecretValueRequest,
+                ) -> ::std::result::Result<
+                    GetSecretValueResponse,
+                    RusotoError<GetSecretValueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_secret_version_ids: Option<
+        Box<
+            dyn Fn(
+                    ListSecretVersionIdsRequest,
+                ) -> ::std::result::Result<
+                    ListSecretVersionIdsResponse,
+                    RusotoError<ListSecretVersionIdsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_secrets: Option<
+        Box<
+            dyn Fn(
+                    ListSecretsRequest,
+                )
+                    -> ::std::result::Result<ListSecretsResponse, RusotoError<ListSecretsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_resource_policy: Option<
+        Box<
+            dyn Fn(
+                    PutResourcePolicyRequest,
+                ) -> ::std::result::Result<
+                    PutResourcePolicyResponse,
+                    RusotoError<PutResourcePolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_secret_value: Option<
+        Box<
+            dyn Fn(
+                    PutSecretValueRequest,
+                ) -> ::std::result::Result<
+                    PutSecretValueResponse,
+                    RusotoError<PutSecretValueError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_secret: Option<
+        Box<
+            dyn Fn(
+                    RestoreSecretRequest,
+                )
+                    -> ::std::result::Result<RestoreSecretResponse, RusotoError<RestoreSecretError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    rotate_secret: Option<
+        Box<
+            dyn Fn(
+                    RotateSecretRequest,
+                )
+                    -> ::std::result::Result<RotateSecretResponse, RusotoError<RotateSecretError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_secret: Option<
+        Box<
+            dyn Fn(
+                    UpdateSecretRequest,
+                )
+                    -> ::std::result::Result<UpdateSecretResponse, RusotoError<UpdateSecretError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_secret_version_stage: Option<
+        Box<
+            dyn Fn(
+                    UpdateSecretVersionStageRequest,
+                ) -> ::std::result::Result<
+                    UpdateSecretVersionStageResponse,
+                    RusotoError<UpdateSecretVersionStageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSecretsManager {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSecretsManager {
+        Default::default()
+    }
+
+    /// Answers `cancel_rotate_secret` with what `handler` returns for its input.
+    pub fn with_cancel_rotate_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                CancelRotateSecretRequest,
+            ) -> ::std::result::Result<
+                CancelRotateSecretResponse,
+                RusotoError<CancelRotateSecretError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_rotate_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_secret` with what `handler` returns for its input.
+    pub fn with_create_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                CreateSecretRequest,
+            )
+                -> ::std::result::Result<CreateSecretResponse, RusotoError<CreateSecretError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_policy` with what `handler` returns for its input.
+    pub fn with_delete_resource_policy<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                DeleteResourcePolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteResourcePolicyResponse,
+                RusotoError<DeleteResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_secret` with what `handler` returns for its input.
+    pub fn with_delete_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                DeleteSecretRequest,
+            )
+                -> ::std::result::Result<DeleteSecretResponse, RusotoError<DeleteSecretError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_secret` with what `handler` returns for its input.
+    pub fn with_describe_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                DescribeSecretRequest,
+            )
+                -> ::std::result::Result<DescribeSecretResponse, RusotoError<DescribeSecretError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_random_password` with what `handler` returns for its input.
+    pub fn with_get_random_password<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                GetRandomPasswordRequest,
+            ) -> ::std::result::Result<
+                GetRandomPasswordResponse,
+                RusotoError<GetRandomPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_random_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resource_policy` with what `handler` returns for its input.
+    pub fn with_get_resource_policy<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                GetResourcePolicyRequest,
+            ) -> ::std::result::Result<
+                GetResourcePolicyResponse,
+                RusotoError<GetResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_secret_value` with what `handler` returns for its input.
+    pub fn with_get_secret_value<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                GetSecretValueRequest,
+            )
+                -> ::std::result::Result<GetSecretValueResponse, RusotoError<GetSecretValueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_secret_value = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_secret_version_ids` with what `handler` returns for its input.
+    pub fn with_list_secret_version_ids<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                ListSecretVersionIdsRequest,
+            ) -> ::std::result::Result<
+                ListSecretVersionIdsResponse,
+                RusotoError<ListSecretVersionIdsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_secret_version_ids = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_secrets` with what `handler` returns for its input.
+    pub fn with_list_secrets<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                ListSecretsRequest,
+            )
+                -> ::std::result::Result<ListSecretsResponse, RusotoError<ListSecretsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_secrets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_resource_policy` with what `handler` returns for its input.
+    pub fn with_put_resource_policy<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                PutResourcePolicyRequest,
+            ) -> ::std::result::Result<
+                PutResourcePolicyResponse,
+                RusotoError<PutResourcePolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_resource_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_secret_value` with what `handler` returns for its input.
+    pub fn with_put_secret_value<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                PutSecretValueRequest,
+            )
+                -> ::std::result::Result<PutSecretValueResponse, RusotoError<PutSecretValueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_secret_value = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_secret` with what `handler` returns for its input.
+    pub fn with_restore_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                RestoreSecretRequest,
+            )
+                -> ::std::result::Result<RestoreSecretResponse, RusotoError<RestoreSecretError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rotate_secret` with what `handler` returns for its input.
+    pub fn with_rotate_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                RotateSecretRequest,
+            )
+                -> ::std::result::Result<RotateSecretResponse, RusotoError<RotateSecretError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.rotate_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_secret` with what `handler` returns for its input.
+    pub fn with_update_secret<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                UpdateSecretRequest,
+            )
+                -> ::std::result::Result<UpdateSecretResponse, RusotoError<UpdateSecretError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_secret = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_secret_version_stage` with what `handler` returns for its input.
+    pub fn with_update_secret_version_stage<F>(mut self, handler: F) -> MockSecretsManager
+    where
+        F: Fn(
+                UpdateSecretVersionStageRequest,
+            ) -> ::std::result::Result<
+                UpdateSecretVersionStageResponse,
+                RusotoError<UpdateSecretVersionStageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_secret_version_stage = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl SecretsManager for MockSecretsManager {
+    fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> RusotoFuture<CancelRotateSecretResponse, CancelRotateSecretError> {
+        match self.cancel_rotate_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for cancel_rotate_secret"),
+        }
+    }
+    fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> RusotoFuture<CreateSecretResponse, CreateSecretError> {
+        match self.create_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for create_secret"),
+        }
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        match self.delete_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for delete_resource_policy"),
+        }
+    }
+    fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> RusotoFuture<DeleteSecretResponse, DeleteSecretError> {
+        match self.delete_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for delete_secret"),
+        }
+    }
+    fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> RusotoFuture<DescribeSecretResponse, DescribeSecretError> {
+        match self.describe_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for describe_secret"),
+        }
+    }
+    fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> RusotoFuture<GetRandomPasswordResponse, GetRandomPasswordError> {
+        match self.get_random_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for get_random_password"),
+        }
+    }
+    fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        match self.get_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for get_resource_policy"),
+        }
+    }
+    fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> RusotoFuture<GetSecretValueResponse, GetSecretValueError> {
+        match self.get_secret_value {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for get_secret_value"),
+        }
+    }
+    fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> RusotoFuture<ListSecretVersionIdsResponse, ListSecretVersionIdsError> {
+        match self.list_secret_version_ids {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for list_secret_version_ids"),
+        }
+    }
+    fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> RusotoFuture<ListSecretsResponse, ListSecretsError> {
+        match self.list_secrets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for list_secrets"),
+        }
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        match self.put_resource_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for put_resource_policy"),
+        }
+    }
+    fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> RusotoFuture<PutSecretValueResponse, PutSecretValueError> {
+        match self.put_secret_value {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for put_secret_value"),
+        }
+    }
+    fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> RusotoFuture<RestoreSecretResponse, RestoreSecretError> {
+        match self.restore_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for restore_secret"),
+        }
+    }
+    fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> RusotoFuture<RotateSecretResponse, RotateSecretError> {
+        match self.rotate_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for rotate_secret"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for untag_resource"),
+        }
+    }
+    fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> RusotoFuture<UpdateSecretResponse, UpdateSecretError> {
+        match self.update_secret {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for update_secret"),
+        }
+    }
+    fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> RusotoFuture<UpdateSecretVersionStageResponse, UpdateSecretVersionStageError> {
+        match self.update_secret_version_stage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecretsManager has no handler for update_secret_version_stage"),
+        }
+    }
+}
@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptInvitationRequest {
     /// <p>The ID of the invitation sent from the Security Hub master account.</p>
     #[serde(rename = "InvitationId")]
@@ -34,12 +36,24 @@ pub struct AcceptInvitationRequest {
     pub master_id: String,
 }
 
+impl Validate for AcceptInvitationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "invitation_id"),
+            &self.invitation_id,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "master_id"), &self.master_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptInvitationResponse {}
 
 /// <p>The details of an AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccountDetails {
     /// <p>The ID of an AWS account.</p>
     #[serde(rename = "AccountId")]
@@ -51,9 +65,17 @@ pub struct AccountDetails {
     pub email: Option<String>,
 }
 
+impl Validate for AccountDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.email {
+            errors.check_pattern(&field_path(path, "email"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>An <code>ActionTarget</code> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActionTarget {
     /// <p>The ARN for the target action.</p>
     #[serde(rename = "ActionTargetArn")]
@@ -107,6 +129,48 @@ pub struct AwsEc2InstanceDetails {
     pub vpc_id: Option<String>,
 }
 
+impl Validate for AwsEc2InstanceDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.iam_instance_profile_arn {
+            errors.check_pattern(
+                &field_path(path, "iam_instance_profile_arn"),
+                &value,
+                ".*\\S.*",
+            );
+        }
+        if let Some(ref value) = self.image_id {
+            errors.check_pattern(&field_path(path, "image_id"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.ip_v4_addresses {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ip_v4_addresses"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.ip_v6_addresses {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ip_v6_addresses"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.key_name {
+            errors.check_pattern(&field_path(path, "key_name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.launched_at {
+            errors.check_pattern(&field_path(path, "launched_at"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.subnet_id {
+            errors.check_pattern(&field_path(path, "subnet_id"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.type_ {
+            errors.check_pattern(&field_path(path, "type_"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.vpc_id {
+            errors.check_pattern(&field_path(path, "vpc_id"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>IAM access key details related to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsIamAccessKeyDetails {
@@ -124,6 +188,14 @@ pub struct AwsIamAccessKeyDetails {
     pub user_name: Option<String>,
 }
 
+impl Validate for AwsIamAccessKeyDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.created_at {
+            errors.check_pattern(&field_path(path, "created_at"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>The details of an Amazon S3 bucket.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsS3BucketDetails {
@@ -137,6 +209,17 @@ pub struct AwsS3BucketDetails {
     pub owner_name: Option<String>,
 }
 
+impl Validate for AwsS3BucketDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.owner_id {
+            errors.check_pattern(&field_path(path, "owner_id"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.owner_name {
+            errors.check_pattern(&field_path(path, "owner_name"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p><p>Provides consistent format for the contents of the Security Hub-aggregated findings. <code>AwsSecurityFinding</code> format enables you to share findings between AWS security services and third-party solutions, and compliance checks.</p> <note> <p>A finding is a potential security issue generated either by AWS services (Amazon GuardDuty, Amazon Inspector, and Amazon Macie) or by the integrated third-party solutions and compliance checks.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsSecurityFinding {
@@ -250,6 +333,99 @@ pub struct AwsSecurityFinding {
     pub workflow_state: Option<String>,
 }
 
+impl Validate for AwsSecurityFinding {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "aws_account_id"),
+            &self.aws_account_id,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "created_at"), &self.created_at, ".*\\S.*");
+        errors.check_pattern(
+            &field_path(path, "description"),
+            &self.description,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.first_observed_at {
+            errors.check_pattern(&field_path(path, "first_observed_at"), &value, ".*\\S.*");
+        }
+        errors.check_pattern(
+            &field_path(path, "generator_id"),
+            &self.generator_id,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, ".*\\S.*");
+        if let Some(ref value) = self.last_observed_at {
+            errors.check_pattern(&field_path(path, "last_observed_at"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.malware {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "malware"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network {
+            value.validate_at(&field_path(path, "network"), errors);
+        }
+        if let Some(ref value) = self.note {
+            value.validate_at(&field_path(path, "note"), errors);
+        }
+        if let Some(ref value) = self.process {
+            value.validate_at(&field_path(path, "process"), errors);
+        }
+        errors.check_pattern(
+            &field_path(path, "product_arn"),
+            &self.product_arn,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.product_fields {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "product_fields"), key);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.related_findings {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "related_findings"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.remediation {
+            value.validate_at(&field_path(path, "remediation"), errors);
+        }
+        for (i, item) in self.resources.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resources"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_pattern(
+            &field_path(path, "schema_version"),
+            &self.schema_version,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.source_url {
+            errors.check_pattern(&field_path(path, "source_url"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.threat_intel_indicators {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "threat_intel_indicators"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(&field_path(path, "title"), &self.title, ".*\\S.*");
+        for (i, item) in self.types.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "types"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+        errors.check_pattern(&field_path(path, "updated_at"), &self.updated_at, ".*\\S.*");
+        if let Some(ref value) = self.user_defined_fields {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "user_defined_fields"), key);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 /// <p>A collection of attributes that are applied to all active Security Hub-aggregated findings and that result in a subset of findings that are included in this insight. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsSecurityFindingFilters {
@@ -587,15 +763,561 @@ pub struct AwsSecurityFindingFilters {
     pub workflow_state: Option<Vec<StringFilter>>,
 }
 
+impl Validate for AwsSecurityFindingFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_account_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "aws_account_id"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.company_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "company_name"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.compliance_status {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "compliance_status"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.created_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "created_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "description"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.first_observed_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "first_observed_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.generator_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "generator_id"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "id"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.last_observed_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "last_observed_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.malware_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "malware_name"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.malware_path {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "malware_path"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.malware_state {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "malware_state"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.malware_type {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "malware_type"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_destination_domain {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "network_destination_domain"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_destination_ip_v4 {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_destination_ip_v4"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_destination_ip_v6 {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_destination_ip_v6"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_direction {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_direction"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_protocol {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_protocol"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_source_domain {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_source_domain"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_source_ip_v4 {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_source_ip_v4"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_source_ip_v6 {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_source_ip_v6"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.network_source_mac {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "network_source_mac"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.note_text {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "note_text"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.note_updated_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "note_updated_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.note_updated_by {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "note_updated_by"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.process_launched_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "process_launched_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.process_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "process_name"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.process_path {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "process_path"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.process_terminated_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "process_terminated_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.product_arn {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "product_arn"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.product_fields {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "product_fields"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.product_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "product_name"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.recommendation_text {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "recommendation_text"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.record_state {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "record_state"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.related_findings_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "related_findings_id"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.related_findings_product_arn {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "related_findings_product_arn"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_iam_instance_profile_arn {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_iam_instance_profile_arn"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_image_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_image_id"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_ip_v4_addresses {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_ip_v4_addresses"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_ip_v6_addresses {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_ip_v6_addresses"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_key_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_key_name"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_launched_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_launched_at"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_subnet_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_subnet_id"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_type {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_type"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_ec_2_instance_vpc_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_ec_2_instance_vpc_id"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_iam_access_key_created_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_iam_access_key_created_at"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_iam_access_key_status {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_iam_access_key_status"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_s3_bucket_owner_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_s3_bucket_owner_id"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_aws_s3_bucket_owner_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_aws_s3_bucket_owner_name"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_container_image_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "resource_container_image_id"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_container_image_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_container_image_name"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_container_launched_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "resource_container_launched_at"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_container_name {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_container_name"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_details_other {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_details_other"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_id {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_id"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_partition {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_partition"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_region {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_region"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.resource_type {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_type"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.severity_label {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "severity_label"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.source_url {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "source_url"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threat_intel_indicator_category {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "threat_intel_indicator_category"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threat_intel_indicator_last_observed_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "threat_intel_indicator_last_observed_at"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threat_intel_indicator_source {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "threat_intel_indicator_source"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threat_intel_indicator_source_url {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "threat_intel_indicator_source_url"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threat_intel_indicator_type {
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "threat_intel_indicator_type"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.threat_intel_indicator_value {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "threat_intel_indicator_value"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.title {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "title"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.type_ {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "type_"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.updated_at {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "updated_at"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.user_defined_fields {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "user_defined_fields"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.verification_state {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "verification_state"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.workflow_state {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "workflow_state"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDisableStandardsRequest {
     /// <p>The ARNs of the standards subscriptions to disable.</p>
     #[serde(rename = "StandardsSubscriptionArns")]
     pub standards_subscription_arns: Vec<String>,
 }
 
+impl Validate for BatchDisableStandardsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "standards_subscription_arns"),
+            self.standards_subscription_arns.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.standards_subscription_arns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "standards_subscription_arns"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDisableStandardsResponse {
     /// <p>The details of the standards subscriptions that were disabled.</p>
     #[serde(rename = "StandardsSubscriptions")]
@@ -604,14 +1326,34 @@ pub struct BatchDisableStandardsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchEnableStandardsRequest {
     /// <p><p>The list of standards compliance checks to enable.</p> <important> <p>In this release, Security Hub supports only the CIS AWS Foundations standard.</p> <p>The ARN for the standard is <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0</code>.</p> </important></p>
     #[serde(rename = "StandardsSubscriptionRequests")]
     pub standards_subscription_requests: Vec<StandardsSubscriptionRequest>,
 }
 
+impl Validate for BatchEnableStandardsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "standards_subscription_requests"),
+            self.standards_subscription_requests.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.standards_subscription_requests.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "standards_subscription_requests"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchEnableStandardsResponse {
     /// <p>The details of the standards subscriptions that were enabled.</p>
     #[serde(rename = "StandardsSubscriptions")]
@@ -620,14 +1362,30 @@ pub struct BatchEnableStandardsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchImportFindingsRequest {
     /// <p>A list of findings to import. To successfully import a finding, it must follow the <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-findings-format.html">AWS Security Finding Format</a>.</p>
     #[serde(rename = "Findings")]
     pub findings: Vec<AwsSecurityFinding>,
 }
 
+impl Validate for BatchImportFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "findings"),
+            self.findings.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.findings.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "findings"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchImportFindingsResponse {
     /// <p>The number of findings that failed to import.</p>
     #[serde(rename = "FailedCount")]
@@ -671,7 +1429,25 @@ pub struct ContainerDetails {
     pub name: Option<String>,
 }
 
+impl Validate for ContainerDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.image_id {
+            errors.check_pattern(&field_path(path, "image_id"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.image_name {
+            errors.check_pattern(&field_path(path, "image_name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.launched_at {
+            errors.check_pattern(&field_path(path, "launched_at"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateActionTargetRequest {
     /// <p>The description for the custom action target.</p>
     #[serde(rename = "Description")]
@@ -684,8 +1460,20 @@ pub struct CreateActionTargetRequest {
     pub name: String,
 }
 
+impl Validate for CreateActionTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "description"),
+            &self.description,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateActionTargetResponse {
     /// <p>The ARN for the custom action target.</p>
     #[serde(rename = "ActionTargetArn")]
@@ -693,6 +1481,7 @@ pub struct CreateActionTargetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateInsightRequest {
     /// <p>One or more attributes used to filter the findings included in the insight. Only findings that match the criteria defined in the filters are included in the insight.</p>
     #[serde(rename = "Filters")]
@@ -705,8 +1494,21 @@ pub struct CreateInsightRequest {
     pub name: String,
 }
 
+impl Validate for CreateInsightRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.filters
+            .validate_at(&field_path(path, "filters"), errors);
+        errors.check_pattern(
+            &field_path(path, "group_by_attribute"),
+            &self.group_by_attribute,
+            ".*\\S.*",
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateInsightResponse {
     /// <p>The ARN of the insight created.</p>
     #[serde(rename = "InsightArn")]
@@ -714,6 +1516,7 @@ pub struct CreateInsightResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMembersRequest {
     /// <p>A list of account ID and email address pairs of the accounts to associate with the Security Hub master account.</p>
     #[serde(rename = "AccountDetails")]
@@ -721,8 +1524,19 @@ pub struct CreateMembersRequest {
     pub account_details: Option<Vec<AccountDetails>>,
 }
 
+impl Validate for CreateMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_details {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "account_details"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMembersResponse {
     /// <p>A list of account ID and email address pairs of the AWS accounts that weren't processed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -747,6 +1561,17 @@ pub struct DateFilter {
     pub start: Option<String>,
 }
 
+impl Validate for DateFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.end {
+            errors.check_pattern(&field_path(path, "end"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.start {
+            errors.check_pattern(&field_path(path, "start"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>A date range for the date filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DateRange {
@@ -761,14 +1586,24 @@ pub struct DateRange {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeclineInvitationsRequest {
     /// <p>A list of account IDs that specify the accounts that invitations to Security Hub are declined from.</p>
     #[serde(rename = "AccountIds")]
     pub account_ids: Vec<String>,
 }
 
+impl Validate for DeclineInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeclineInvitationsResponse {
     /// <p>A list of account ID and email address pairs of the AWS accounts that weren't processed.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -777,14 +1612,25 @@ pub struct DeclineInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteActionTargetRequest {
     /// <p>The ARN of the custom action target to delete.</p>
     #[serde(rename = "ActionTargetArn")]
     pub action_target_arn: String,
 }
 
+impl Validate for DeleteActionTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "action_target_arn"),
+            &self.action_target_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteActionTargetResponse {
     /// <p>The ARN of the custom action target that was deleted.</p>
     #[serde(rename = "ActionTargetArn")]
@@ -792,14 +1638,25 @@ pub struct DeleteActionTargetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInsightRequest {
     /// <p>The ARN of the insight to delete.</p>
     #[serde(rename = "InsightArn")]
     pub insight_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl Validate for DeleteInsightRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "insight_arn"),
+            &self.insight_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInsightResponse {
     /// <p>The ARN of the insight that was deleted.</p>
     #[serde(rename = "InsightArn")]
@@ -807,14 +1664,24 @@ pub struct DeleteInsightResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInvitationsRequest {
     /// <p>A list of the account IDs that sent the invitations to delete.</p>
     #[serde(rename = "AccountIds")]
     pub account_ids: Vec<String>,
 }
 
+impl Validate for DeleteInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInvitationsResponse {
     /// <p>A list of account ID and email address pairs of the AWS accounts that invitations weren't deleted for.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -823,6 +1690,7 @@ pub struct DeleteInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMembersRequest {
     /// <p>A list of account IDs of the member accounts to delete.</p>
     #[serde(rename = "AccountIds")]
@@ -830,8 +1698,19 @@ pub struct DeleteMembersRequest {
     pub account_ids: Option<Vec<String>>,
 }
 
+impl Validate for DeleteMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMembersResponse {
     /// <p>A list of account ID and email address pairs of the AWS accounts that weren't deleted.</p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -840,6 +1719,7 @@ pub struct DeleteMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActionTargetsRequest {
     /// <p>A list of custom action target ARNs for the custom action targets to retrieve.</p>
     #[serde(rename = "ActionTargetArns")]
@@ -855,8 +1735,22 @@ pub struct DescribeActionTargetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeActionTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.action_target_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "action_target_arns"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeActionTargetsResponse {
     /// <p>A list of <code>ActionTarget</code> objects. Each object includes the <code>ActionTargetArn</code>, <code>Description</code>, and <code>Name</code> of a custom action target available in Security Hub.</p>
     #[serde(rename = "ActionTargets")]
@@ -868,6 +1762,7 @@ pub struct DescribeActionTargetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeHubRequest {
     /// <p>The ARN of the Hub resource to retrieve.</p>
     #[serde(rename = "HubArn")]
@@ -875,8 +1770,16 @@ pub struct DescribeHubRequest {
     pub hub_arn: Option<String>,
 }
 
+impl Validate for DescribeHubRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.hub_arn {
+            errors.check_pattern(&field_path(path, "hub_arn"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeHubResponse {
     /// <p>The ARN of the Hub resource retrieved.</p>
     #[serde(rename = "HubArn")]
@@ -889,6 +1792,7 @@ pub struct DescribeHubResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProductsRequest {
     /// <p>The maximum number of results to return.</p>
     #[serde(rename = "MaxResults")]
@@ -900,8 +1804,16 @@ pub struct DescribeProductsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeProductsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProductsResponse {
     /// <p>The token that is required for pagination.</p>
     #[serde(rename = "NextToken")]
@@ -913,31 +1825,45 @@ pub struct DescribeProductsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableImportFindingsForProductRequest {
     /// <p>The ARN of the integrated product to disable the integration for.</p>
     #[serde(rename = "ProductSubscriptionArn")]
     pub product_subscription_arn: String,
 }
 
+impl Validate for DisableImportFindingsForProductRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "product_subscription_arn"),
+            &self.product_subscription_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableImportFindingsForProductResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableSecurityHubRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableSecurityHubResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateFromMasterAccountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateFromMasterAccountResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateMembersRequest {
     /// <p>The account IDs of the member accounts to disassociate from the master account.</p>
     #[serde(rename = "AccountIds")]
@@ -945,19 +1871,41 @@ pub struct DisassociateMembersRequest {
     pub account_ids: Option<Vec<String>>,
 }
 
+impl Validate for DisassociateMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateMembersResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableImportFindingsForProductRequest {
     /// <p>The ARN of the product to enable the integration for.</p>
     #[serde(rename = "ProductArn")]
     pub product_arn: String,
 }
 
+impl Validate for EnableImportFindingsForProductRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "product_arn"),
+            &self.product_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableImportFindingsForProductResponse {
     /// <p>The ARN of your subscription to the product to enable integrations for.</p>
     #[serde(rename = "ProductSubscriptionArn")]
@@ -966,6 +1914,7 @@ pub struct EnableImportFindingsForProductResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableSecurityHubRequest {
     /// <p>The tags to add to the Hub resource when you enable Security Hub.</p>
     #[serde(rename = "Tags")]
@@ -973,11 +1922,24 @@ pub struct EnableSecurityHubRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for EnableSecurityHubRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableSecurityHubResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEnabledStandardsRequest {
     /// <p>The maximum number of results to return in the response.</p>
     #[serde(rename = "MaxResults")]
@@ -993,8 +1955,29 @@ pub struct GetEnabledStandardsRequest {
     pub standards_subscription_arns: Option<Vec<String>>,
 }
 
+impl Validate for GetEnabledStandardsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.standards_subscription_arns {
+            errors.check_length(
+                &field_path(path, "standards_subscription_arns"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path =
+                    format!("{}[{}]", field_path(path, "standards_subscription_arns"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetEnabledStandardsResponse {
     /// <p>The token that is required for pagination.</p>
     #[serde(rename = "NextToken")]
@@ -1007,6 +1990,7 @@ pub struct GetEnabledStandardsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFindingsRequest {
     /// <p>The findings attributes used to define a condition to filter the findings returned.</p>
     #[serde(rename = "Filters")]
@@ -1026,8 +2010,25 @@ pub struct GetFindingsRequest {
     pub sort_criteria: Option<Vec<SortCriterion>>,
 }
 
+impl Validate for GetFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.sort_criteria {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sort_criteria"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFindingsResponse {
     /// <p>The findings that matched the filters specified in the request.</p>
     #[serde(rename = "Findings")]
@@ -1039,14 +2040,25 @@ pub struct GetFindingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInsightResultsRequest {
     /// <p>The ARN of the insight whose results you want to see.</p>
     #[serde(rename = "InsightArn")]
     pub insight_arn: String,
 }
 
+impl Validate for GetInsightResultsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "insight_arn"),
+            &self.insight_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInsightResultsResponse {
     /// <p>The insight results returned by the operation.</p>
     #[serde(rename = "InsightResults")]
@@ -1054,6 +2066,7 @@ pub struct GetInsightResultsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInsightsRequest {
     /// <p>The ARNs of the insights that you want to describe.</p>
     #[serde(rename = "InsightArns")]
@@ -1069,8 +2082,22 @@ pub struct GetInsightsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetInsightsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.insight_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "insight_arns"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInsightsResponse {
     /// <p>The insights returned by the operation.</p>
     #[serde(rename = "Insights")]
@@ -1082,10 +2109,11 @@ pub struct GetInsightsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInvitationsCountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInvitationsCountResponse {
     /// <p>The number of all membership invitations sent to this Security Hub member account, not including the currently accepted invitation. </p>
     #[serde(rename = "InvitationsCount")]
@@ -1094,10 +2122,11 @@ pub struct GetInvitationsCountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMasterAccountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMasterAccountResponse {
     /// <p>A list of details about the Security Hub master account for the current member account. </p>
     #[serde(rename = "Master")]
@@ -1106,14 +2135,24 @@ pub struct GetMasterAccountResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMembersRequest {
     /// <p>A list of account IDs for the Security Hub member accounts that you want to return the details for. </p>
     #[serde(rename = "AccountIds")]
     pub account_ids: Vec<String>,
 }
 
+impl Validate for GetMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.account_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMembersResponse {
     /// <p>A list of details about the Security Hub member accounts.</p>
     #[serde(rename = "Members")]
@@ -1127,7 +2166,7 @@ pub struct GetMembersResponse {
 
 /// <p>Includes details of the list of the findings that can't be imported.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportFindingsError {
     /// <p>The code of the error made during the <code>BatchImportFindings</code> operation.</p>
     #[serde(rename = "ErrorCode")]
@@ -1142,7 +2181,7 @@ pub struct ImportFindingsError {
 
 /// <p>Contains information about a Security Hub insight.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Insight {
     /// <p>One or more attributes used to filter the findings included in the insight. Only findings that match the criteria defined in the filters are included in the insight.</p>
     #[serde(rename = "Filters")]
@@ -1160,7 +2199,7 @@ pub struct Insight {
 
 /// <p>The insight result values returned by the <code>GetInsightResults</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InsightResultValue {
     /// <p>The number of findings returned for each <code>GroupByAttributeValue</code>.</p>
     #[serde(rename = "Count")]
@@ -1172,7 +2211,7 @@ pub struct InsightResultValue {
 
 /// <p>The insight results returned by the <code>GetInsightResults</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InsightResults {
     /// <p>The attribute that the findings are grouped by for the insight whose results are returned by the <code>GetInsightResults</code> operation.</p>
     #[serde(rename = "GroupByAttribute")]
@@ -1187,7 +2226,7 @@ pub struct InsightResults {
 
 /// <p>Details about an invitation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Invitation {
     /// <p>The account ID of the Security Hub master account that the invitation was sent from.</p>
     #[serde(rename = "AccountId")]
@@ -1208,6 +2247,7 @@ pub struct Invitation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InviteMembersRequest {
     /// <p>A list of IDs of the AWS accounts that you want to invite to Security Hub as members. </p>
     #[serde(rename = "AccountIds")]
@@ -1215,8 +2255,19 @@ pub struct InviteMembersRequest {
     pub account_ids: Option<Vec<String>>,
 }
 
+impl Validate for InviteMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "account_ids"), i);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InviteMembersResponse {
     /// <p>A list of account ID and email address pairs of the AWS accounts that couldn't be processed. </p>
     #[serde(rename = "UnprocessedAccounts")]
@@ -1233,6 +2284,14 @@ pub struct IpFilter {
     pub cidr: Option<String>,
 }
 
+impl Validate for IpFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cidr {
+            errors.check_pattern(&field_path(path, "cidr"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>A keyword filter for querying findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeywordFilter {
@@ -1242,7 +2301,16 @@ pub struct KeywordFilter {
     pub value: Option<String>,
 }
 
+impl Validate for KeywordFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.value {
+            errors.check_pattern(&field_path(path, "value"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEnabledProductsForImportRequest {
     /// <p>The maximum number of items that you want in the response.</p>
     #[serde(rename = "MaxResults")]
@@ -1254,8 +2322,16 @@ pub struct ListEnabledProductsForImportRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListEnabledProductsForImportRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListEnabledProductsForImportResponse {
     /// <p>The token that is required for pagination.</p>
     #[serde(rename = "NextToken")]
@@ -1268,6 +2344,7 @@ pub struct ListEnabledProductsForImportResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInvitationsRequest {
     /// <p>The maximum number of items that you want in the response. </p>
     #[serde(rename = "MaxResults")]
@@ -1279,8 +2356,16 @@ pub struct ListInvitationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListInvitationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInvitationsResponse {
     /// <p>The details of the invitations returned by the operation.</p>
     #[serde(rename = "Invitations")]
@@ -1293,6 +2378,7 @@ pub struct ListInvitationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMembersRequest {
     /// <p>The maximum number of items that you want in the response. </p>
     #[serde(rename = "MaxResults")]
@@ -1308,8 +2394,16 @@ pub struct ListMembersRequest {
     pub only_associated: Option<bool>,
 }
 
+impl Validate for ListMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMembersResponse {
     /// <p>Member details returned by the operation.</p>
     #[serde(rename = "Members")]
@@ -1322,14 +2416,25 @@ pub struct ListMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the resource to retrieve tags for.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:securityhub:.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags associated with a resource.</p>
     #[serde(rename = "Tags")]
@@ -1357,6 +2462,15 @@ pub struct Malware {
     pub type_: Option<String>,
 }
 
+impl Validate for Malware {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+        if let Some(ref value) = self.path {
+            errors.check_pattern(&field_path(path, "path"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>The map filter for querying findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MapFilter {
@@ -1374,9 +2488,20 @@ pub struct MapFilter {
     pub value: Option<String>,
 }
 
+impl Validate for MapFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_pattern(&field_path(path, "key"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.value {
+            errors.check_pattern(&field_path(path, "value"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>The details about a member account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Member {
     /// <p>The AWS account ID of the member account.</p>
     #[serde(rename = "AccountId")]
@@ -1453,6 +2578,35 @@ pub struct Network {
     pub source_port: Option<i64>,
 }
 
+impl Validate for Network {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.destination_domain {
+            errors.check_pattern(&field_path(path, "destination_domain"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.destination_ip_v4 {
+            errors.check_pattern(&field_path(path, "destination_ip_v4"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.destination_ip_v6 {
+            errors.check_pattern(&field_path(path, "destination_ip_v6"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.protocol {
+            errors.check_pattern(&field_path(path, "protocol"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.source_domain {
+            errors.check_pattern(&field_path(path, "source_domain"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.source_ip_v4 {
+            errors.check_pattern(&field_path(path, "source_ip_v4"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.source_ip_v6 {
+            errors.check_pattern(&field_path(path, "source_ip_v6"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.source_mac {
+            errors.check_pattern(&field_path(path, "source_mac"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>A user-defined note added to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Note {
@@ -1467,8 +2621,17 @@ pub struct Note {
     pub updated_by: String,
 }
 
+impl Validate for Note {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "text"), &self.text, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "updated_at"), &self.updated_at, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "updated_by"), &self.updated_by, ".*\\S.*");
+    }
+}
+
 /// <p>The updated note.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NoteUpdate {
     /// <p>The updated note text.</p>
     #[serde(rename = "Text")]
@@ -1478,6 +2641,13 @@ pub struct NoteUpdate {
     pub updated_by: String,
 }
 
+impl Validate for NoteUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "text"), &self.text, ".*\\S.*");
+        errors.check_pattern(&field_path(path, "updated_by"), &self.updated_by, ".*\\S.*");
+    }
+}
+
 /// <p>A number filter for querying findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NumberFilter {
@@ -1524,9 +2694,26 @@ pub struct ProcessDetails {
     pub terminated_at: Option<String>,
 }
 
+impl Validate for ProcessDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.launched_at {
+            errors.check_pattern(&field_path(path, "launched_at"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.path {
+            errors.check_pattern(&field_path(path, "path"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.terminated_at {
+            errors.check_pattern(&field_path(path, "terminated_at"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>Contains details about a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Product {
     /// <p>The URL used to activate the product.</p>
     #[serde(rename = "ActivationUrl")]
@@ -1574,6 +2761,17 @@ pub struct Recommendation {
     pub url: Option<String>,
 }
 
+impl Validate for Recommendation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.text {
+            errors.check_pattern(&field_path(path, "text"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.url {
+            errors.check_pattern(&field_path(path, "url"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>Details about a related finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelatedFinding {
@@ -1585,6 +2783,17 @@ pub struct RelatedFinding {
     pub product_arn: String,
 }
 
+impl Validate for RelatedFinding {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "id"), &self.id, ".*\\S.*");
+        errors.check_pattern(
+            &field_path(path, "product_arn"),
+            &self.product_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>Details about the remediation steps for a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Remediation {
@@ -1594,6 +2803,14 @@ pub struct Remediation {
     pub recommendation: Option<Recommendation>,
 }
 
+impl Validate for Remediation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.recommendation {
+            value.validate_at(&field_path(path, "recommendation"), errors);
+        }
+    }
+}
+
 /// <p>A resource related to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
@@ -1621,6 +2838,25 @@ pub struct Resource {
     pub type_: String,
 }
 
+impl Validate for Resource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            value.validate_at(&field_path(path, "details"), errors);
+        }
+        errors.check_pattern(&field_path(path, "id"), &self.id, ".*\\S.*");
+        if let Some(ref value) = self.region {
+            errors.check_pattern(&field_path(path, "region"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.tags {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+        errors.check_pattern(&field_path(path, "type_"), &self.type_, ".*\\S.*");
+    }
+}
+
 /// <p>Additional details about a resource related to a finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceDetails {
@@ -1646,9 +2882,32 @@ pub struct ResourceDetails {
     pub other: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for ResourceDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aws_ec_2_instance {
+            value.validate_at(&field_path(path, "aws_ec_2_instance"), errors);
+        }
+        if let Some(ref value) = self.aws_iam_access_key {
+            value.validate_at(&field_path(path, "aws_iam_access_key"), errors);
+        }
+        if let Some(ref value) = self.aws_s3_bucket {
+            value.validate_at(&field_path(path, "aws_s3_bucket"), errors);
+        }
+        if let Some(ref value) = self.container {
+            value.validate_at(&field_path(path, "container"), errors);
+        }
+        if let Some(ref value) = self.other {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "other"), key);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 /// <p>Details about the account that wasn't processed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Result {
     /// <p>An AWS account ID of the account that wasn't be processed.</p>
     #[serde(rename = "AccountId")]
@@ -1674,6 +2933,7 @@ pub struct Severity {
 
 /// <p>A collection of finding attributes used to sort findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SortCriterion {
     /// <p>The finding attribute used to sort findings.</p>
     #[serde(rename = "Field")]
@@ -1685,9 +2945,17 @@ pub struct SortCriterion {
     pub sort_order: Option<String>,
 }
 
+impl Validate for SortCriterion {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.field {
+            errors.check_pattern(&field_path(path, "field"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>A resource that represents your subscription to a supported standard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StandardsSubscription {
     /// <p>The ARN of a standard.</p> <p>In this release, Security Hub supports only the CIS AWS Foundations standard, which uses the following ARN: <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0.</code> </p>
     #[serde(rename = "StandardsArn")]
@@ -1705,6 +2973,7 @@ pub struct StandardsSubscription {
 
 /// <p>The standard that you want to enable.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StandardsSubscriptionRequest {
     /// <p><p>The ARN of the standard that you want to enable.</p> <important> <p>In this release, Security Hub only supports the CIS AWS Foundations standard. </p> <p>Its ARN is <code>arn:aws:securityhub:::ruleset/cis-aws-foundations-benchmark/v/1.2.0</code>.</p> </important></p>
     #[serde(rename = "StandardsArn")]
@@ -1715,6 +2984,22 @@ pub struct StandardsSubscriptionRequest {
     pub standards_input: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for StandardsSubscriptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "standards_arn"),
+            &self.standards_arn,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.standards_input {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "standards_input"), key);
+                errors.check_pattern(&item_path, &item, ".*\\S.*");
+            }
+        }
+    }
+}
+
 /// <p>A string filter for querying findings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StringFilter {
@@ -1728,7 +3013,16 @@ pub struct StringFilter {
     pub value: Option<String>,
 }
 
+impl Validate for StringFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.value {
+            errors.check_pattern(&field_path(path, "value"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the resource to apply the tags to.</p>
     #[serde(rename = "ResourceArn")]
@@ -1738,8 +3032,28 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:securityhub:.*",
+        );
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (key, item) in self.tags.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>Details about the threat intel related to a finding.</p>
@@ -1771,7 +3085,25 @@ pub struct ThreatIntelIndicator {
     pub value: Option<String>,
 }
 
+impl Validate for ThreatIntelIndicator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.last_observed_at {
+            errors.check_pattern(&field_path(path, "last_observed_at"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.source {
+            errors.check_pattern(&field_path(path, "source"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.source_url {
+            errors.check_pattern(&field_path(path, "source_url"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.value {
+            errors.check_pattern(&field_path(path, "value"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the resource to remove the tags from.</p>
     #[serde(rename = "ResourceArn")]
@@ -1781,11 +3113,33 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws:securityhub:.*",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^(?!aws:)[a-zA-Z+-=._:/]+$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateActionTargetRequest {
     /// <p>The ARN of the custom action target to update.</p>
     #[serde(rename = "ActionTargetArn")]
@@ -1800,11 +3154,28 @@ pub struct UpdateActionTargetRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateActionTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "action_target_arn"),
+            &self.action_target_arn,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_pattern(&field_path(path, "description"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateActionTargetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFindingsRequest {
     /// <p>A collection of attributes that specify which findings you want to update.</p>
     #[serde(rename = "Filters")]
@@ -1819,11 +3190,22 @@ pub struct UpdateFindingsRequest {
     pub record_state: Option<String>,
 }
 
+impl Validate for UpdateFindingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.filters
+            .validate_at(&field_path(path, "filters"), errors);
+        if let Some(ref value) = self.note {
+            value.validate_at(&field_path(path, "note"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFindingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateInsightRequest {
     /// <p>The updated filters that define this insight.</p>
     #[serde(rename = "Filters")]
@@ -1842,8 +3224,27 @@ pub struct UpdateInsightRequest {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateInsightRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.group_by_attribute {
+            errors.check_pattern(&field_path(path, "group_by_attribute"), &value, ".*\\S.*");
+        }
+        errors.check_pattern(
+            &field_path(path, "insight_arn"),
+            &self.insight_arn,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateInsightResponse {}
 
 /// Errors returned by AcceptInvitation
@@ -1880,7 +3281,12 @@ impl AcceptInvitationError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AcceptInvitationError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1932,7 +3338,12 @@ impl BatchDisableStandardsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(BatchDisableStandardsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1983,7 +3394,12 @@ impl BatchEnableStandardsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(BatchEnableStandardsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2034,7 +3450,12 @@ impl BatchImportFindingsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(BatchImportFindingsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2090,7 +3511,12 @@ impl CreateActionTargetError {
                 "ResourceConflictException" => {
                     return RusotoError::Service(CreateActionTargetError::ResourceConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2147,7 +3573,12 @@ impl CreateInsightError {
                 "ResourceConflictException" => {
                     return RusotoError::Service(CreateInsightError::ResourceConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2204,7 +3635,12 @@ impl CreateMembersError {
                 "ResourceConflictException" => {
                     return RusotoError::Service(CreateMembersError::ResourceConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2256,7 +3692,12 @@ impl DeclineInvitationsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeclineInvitationsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2307,7 +3748,12 @@ impl DeleteActionTargetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteActionTargetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2363,7 +3809,12 @@ impl DeleteInsightError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteInsightError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2420,7 +3871,12 @@ impl DeleteInvitationsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteInvitationsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2477,7 +3933,12 @@ impl DeleteMembersError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteMembersError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2531,7 +3992,12 @@ impl DescribeActionTargetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2587,7 +4053,12 @@ impl DescribeHubError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeHubError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2639,7 +4110,12 @@ impl DescribeProductsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(DescribeProductsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2707,7 +4183,12 @@ impl DisableImportFindingsForProductError {
                         DisableImportFindingsForProductError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2759,7 +4240,12 @@ impl DisableSecurityHubError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DisableSecurityHubError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2827,7 +4313,12 @@ impl DisassociateFromMasterAccountError {
                         DisassociateFromMasterAccountError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2886,7 +4377,12 @@ impl DisassociateMembersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2955,7 +4451,12 @@ impl EnableImportFindingsForProductError {
                         EnableImportFindingsForProductError::ResourceConflict(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3012,7 +4513,12 @@ impl EnableSecurityHubError {
                 "ResourceConflictException" => {
                     return RusotoError::Service(EnableSecurityHubError::ResourceConflict(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3064,7 +4570,12 @@ impl GetEnabledStandardsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetEnabledStandardsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3115,7 +4626,12 @@ impl GetFindingsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetFindingsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3171,7 +4687,12 @@ impl GetInsightResultsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetInsightResultsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3228,7 +4749,12 @@ impl GetInsightsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetInsightsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3280,7 +4806,12 @@ impl GetInvitationsCountError {
                 "LimitExceededException" => {
                     return RusotoError::Service(GetInvitationsCountError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3336,7 +4867,12 @@ impl GetMasterAccountError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetMasterAccountError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3393,7 +4929,12 @@ impl GetMembersError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(GetMembersError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3450,7 +4991,12 @@ impl InviteMembersError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(InviteMembersError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3505,7 +5051,12 @@ impl ListEnabledProductsForImportError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3555,7 +5106,12 @@ impl ListInvitationsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(ListInvitationsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3606,7 +5162,12 @@ impl ListMembersError {
                 "LimitExceededException" => {
                     return RusotoError::Service(ListMembersError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3654,7 +5215,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3699,7 +5265,12 @@ impl TagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3744,7 +5315,12 @@ impl UntagResourceError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3794,7 +5370,12 @@ impl UpdateActionTargetError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateActionTargetError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3850,7 +5431,12 @@ impl UpdateFindingsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3907,7 +5493,12 @@ impl UpdateInsightError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateInsightError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4154,1254 +5745,3630 @@ pub trait SecurityHub {
         input: UpdateInsightRequest,
     ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError>;
 }
-/// A client for the AWS SecurityHub API.
-#[derive(Clone)]
-pub struct SecurityHubClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SecurityHubClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SecurityHubClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SecurityHubClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubClient {
-        SecurityHubClient { client, region }
-    }
-}
-
-impl SecurityHub for SecurityHubClient {
-    /// <p>Accepts the invitation to be a member account and be monitored by the Security Hub master account that the invitation was sent from. When the member account accepts the invitation, permission is granted to the master account to view findings generated in the member account.</p>
+const _: Option<&dyn SecurityHub> = None;
+impl<'a, T: SecurityHub + ?Sized> SecurityHub for &'a T {
     fn accept_invitation(
         &self,
         input: AcceptInvitationRequest,
     ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
-        let request_uri = "/master";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptInvitationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AcceptInvitationError::from_response(response))),
-                )
-            }
-        })
+        (**self).accept_invitation(input)
     }
-
-    /// <p>Disables the standards specified by the provided <code>StandardsSubscriptionArns</code>. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
     fn batch_disable_standards(
         &self,
         input: BatchDisableStandardsRequest,
     ) -> RusotoFuture<BatchDisableStandardsResponse, BatchDisableStandardsError> {
-        let request_uri = "/standards/deregister";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchDisableStandardsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchDisableStandardsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_disable_standards(input)
     }
-
-    /// <p>Enables the standards specified by the provided <code>standardsArn</code>. In this release, only CIS AWS Foundations standards are supported. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
     fn batch_enable_standards(
         &self,
         input: BatchEnableStandardsRequest,
     ) -> RusotoFuture<BatchEnableStandardsResponse, BatchEnableStandardsError> {
-        let request_uri = "/standards/register";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchEnableStandardsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchEnableStandardsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_enable_standards(input)
     }
-
-    /// <p>Imports security findings generated from an integrated third-party product into Security Hub. This action is requested by the integrated product to import its findings into Security Hub. The maximum allowed size for a finding is 240 Kb. An error is returned for any finding larger than 240 Kb.</p>
     fn batch_import_findings(
         &self,
         input: BatchImportFindingsRequest,
     ) -> RusotoFuture<BatchImportFindingsResponse, BatchImportFindingsError> {
-        let request_uri = "/findings/import";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchImportFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(BatchImportFindingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).batch_import_findings(input)
     }
-
-    /// <p>Creates a custom action target in Security Hub. You can use custom actions on findings and insights in Security Hub to trigger target actions in Amazon CloudWatch Events.</p>
     fn create_action_target(
         &self,
         input: CreateActionTargetRequest,
     ) -> RusotoFuture<CreateActionTargetResponse, CreateActionTargetError> {
-        let request_uri = "/actionTargets";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateActionTargetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateActionTargetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_action_target(input)
     }
-
-    /// <p>Creates a custom insight in Security Hub. An insight is a consolidation of findings that relate to a security issue that requires attention or remediation. Use the <code>GroupByAttribute</code> to group the related findings in the insight.</p>
     fn create_insight(
         &self,
         input: CreateInsightRequest,
     ) -> RusotoFuture<CreateInsightResponse, CreateInsightError> {
-        let request_uri = "/insights";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateInsightResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateInsightError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_insight(input)
     }
-
-    /// <p>Creates a member association in Security Hub between the specified accounts and the account used to make the request, which is the master account. To successfully create a member, you must use this action from an account that already has Security Hub enabled. You can use the <a>EnableSecurityHub</a> to enable Security Hub.</p> <p>After you use <code>CreateMembers</code> to create member account associations in Security Hub, you need to use the <a>InviteMembers</a> action, which invites the accounts to enable Security Hub and become member accounts in Security Hub. If the invitation is accepted by the account owner, the account becomes a member account in Security Hub, and a permission policy is added that permits the master account to view the findings generated in the member account. When Security Hub is enabled in the invited account, findings start being sent to both the member and master accounts.</p> <p>You can remove the association between the master and member accounts by using the <a>DisassociateFromMasterAccount</a> or <a>DisassociateMembers</a> operation.</p>
     fn create_members(
         &self,
         input: CreateMembersRequest,
     ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
-        let request_uri = "/members";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_members(input)
     }
-
-    /// <p>Declines invitations to become a member account.</p>
     fn decline_invitations(
         &self,
         input: DeclineInvitationsRequest,
     ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
-        let request_uri = "/invitations/decline";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeclineInvitationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeclineInvitationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).decline_invitations(input)
     }
-
-    /// <p>Deletes a custom action target from Security Hub. Deleting a custom action target doesn't affect any findings or insights that were already sent to Amazon CloudWatch Events using the custom action.</p>
     fn delete_action_target(
         &self,
         input: DeleteActionTargetRequest,
     ) -> RusotoFuture<DeleteActionTargetResponse, DeleteActionTargetError> {
-        let request_uri = format!(
-            "/actionTargets/{action_target_arn}",
-            action_target_arn = input.action_target_arn
-        );
-
-        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteActionTargetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteActionTargetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_action_target(input)
     }
-
-    /// <p>Deletes the insight specified by the <code>InsightArn</code>.</p>
     fn delete_insight(
         &self,
         input: DeleteInsightRequest,
     ) -> RusotoFuture<DeleteInsightResponse, DeleteInsightError> {
-        let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
-
-        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInsightResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInsightError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_insight(input)
     }
-
-    /// <p>Deletes invitations received by the AWS account to become a member account.</p>
     fn delete_invitations(
         &self,
         input: DeleteInvitationsRequest,
     ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
-        let request_uri = "/invitations/delete";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInvitationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInvitationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_invitations(input)
     }
-
-    /// <p>Deletes the specified member accounts from Security Hub.</p>
     fn delete_members(
         &self,
         input: DeleteMembersRequest,
     ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
-        let request_uri = "/members/delete";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_members(input)
     }
-
-    /// <p>Returns a list of the custom action targets in Security Hub in your account.</p>
     fn describe_action_targets(
         &self,
         input: DescribeActionTargetsRequest,
     ) -> RusotoFuture<DescribeActionTargetsResponse, DescribeActionTargetsError> {
-        let request_uri = "/actionTargets/get";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeActionTargetsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeActionTargetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_action_targets(input)
     }
-
-    /// <p>Returns details about the Hub resource in your account, including the <code>HubArn</code> and the time when you enabled Security Hub.</p>
     fn describe_hub(
         &self,
         input: DescribeHubRequest,
     ) -> RusotoFuture<DescribeHubResponse, DescribeHubError> {
-        let request_uri = "/accounts";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.hub_arn {
-            params.put("HubArn", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeHubResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeHubError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_hub(input)
     }
-
-    /// <p>Returns information about the products available that you can subscribe to and integrate with Security Hub to consolidate findings.</p>
     fn describe_products(
         &self,
         input: DescribeProductsRequest,
     ) -> RusotoFuture<DescribeProductsResponse, DescribeProductsError> {
-        let request_uri = "/products";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProductsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeProductsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_products(input)
     }
-
-    /// <p>Disables the integration of the specified product with Security Hub. Findings from that product are no longer sent to Security Hub after the integration is disabled.</p>
     fn disable_import_findings_for_product(
         &self,
         input: DisableImportFindingsForProductRequest,
     ) -> RusotoFuture<DisableImportFindingsForProductResponse, DisableImportFindingsForProductError>
     {
-        let request_uri = format!(
-            "/productSubscriptions/{product_subscription_arn}",
-            product_subscription_arn = input.product_subscription_arn
-        );
-
-        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableImportFindingsForProductResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisableImportFindingsForProductError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disable_import_findings_for_product(input)
     }
-
-    /// <p>Disables Security Hub in your account only in the current Region. To disable Security Hub in all Regions, you must submit one request per Region where you have enabled Security Hub. When you disable Security Hub for a master account, it doesn't disable Security Hub for any associated member accounts.</p> <p>When you disable Security Hub, your existing findings and insights and any Security Hub configuration settings are deleted after 90 days and can't be recovered. Any standards that were enabled are disabled, and your master and member account associations are removed. If you want to save your existing findings, you must export them before you disable Security Hub.</p>
     fn disable_security_hub(
         &self,
     ) -> RusotoFuture<DisableSecurityHubResponse, DisableSecurityHubError> {
-        let request_uri = "/accounts";
-
-        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableSecurityHubResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableSecurityHubError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_security_hub()
     }
-
-    /// <p>Disassociates the current Security Hub member account from the associated master account.</p>
     fn disassociate_from_master_account(
         &self,
     ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
     {
-        let request_uri = "/master/disassociate";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateFromMasterAccountError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_from_master_account()
     }
-
-    /// <p>Disassociates the specified member accounts from the associated master account.</p>
     fn disassociate_members(
         &self,
         input: DisassociateMembersRequest,
     ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
-        let request_uri = "/members/disassociate";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisassociateMembersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).disassociate_members(input)
     }
-
-    /// <p>Enables the integration of a partner product with Security Hub. Integrated products send findings to Security Hub. When you enable a product integration, a permission policy that grants permission for the product to send findings to Security Hub is applied.</p>
     fn enable_import_findings_for_product(
         &self,
         input: EnableImportFindingsForProductRequest,
     ) -> RusotoFuture<EnableImportFindingsForProductResponse, EnableImportFindingsForProductError>
     {
-        let request_uri = "/productSubscriptions";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableImportFindingsForProductResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(EnableImportFindingsForProductError::from_response(response))
-                }))
-            }
-        })
+        (**self).enable_import_findings_for_product(input)
     }
-
-    /// <p>Enables Security Hub for your account in the current Region or the Region you specify in the request. When you enable Security Hub, you grant to Security Hub the permissions necessary to gather findings from AWS Config, Amazon GuardDuty, Amazon Inspector, and Amazon Macie. To learn more, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-settingup.html">Setting Up AWS Security Hub</a>.</p>
     fn enable_security_hub(
         &self,
         input: EnableSecurityHubRequest,
     ) -> RusotoFuture<EnableSecurityHubResponse, EnableSecurityHubError> {
-        let request_uri = "/accounts";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableSecurityHubResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(EnableSecurityHubError::from_response(response))),
-                )
-            }
-        })
+        (**self).enable_security_hub(input)
     }
-
-    /// <p>Returns a list of the standards that are currently enabled.</p>
     fn get_enabled_standards(
         &self,
         input: GetEnabledStandardsRequest,
     ) -> RusotoFuture<GetEnabledStandardsResponse, GetEnabledStandardsError> {
-        let request_uri = "/standards/get";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetEnabledStandardsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetEnabledStandardsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_enabled_standards(input)
     }
-
-    /// <p>Returns a list of findings that match the specified criteria.</p>
     fn get_findings(
         &self,
         input: GetFindingsRequest,
     ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
-        let request_uri = "/findings";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFindingsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_findings(input)
     }
-
-    /// <p>Lists the results of the Security Hub insight that the insight ARN specifies.</p>
     fn get_insight_results(
         &self,
         input: GetInsightResultsRequest,
     ) -> RusotoFuture<GetInsightResultsResponse, GetInsightResultsError> {
-        let request_uri = format!(
-            "/insights/results/{insight_arn}",
-            insight_arn = input.insight_arn
-        );
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInsightResultsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInsightResultsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_insight_results(input)
     }
-
-    /// <p>Lists and describes insights that insight ARNs specify.</p>
     fn get_insights(
         &self,
         input: GetInsightsRequest,
     ) -> RusotoFuture<GetInsightsResponse, GetInsightsError> {
-        let request_uri = "/insights/get";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInsightsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInsightsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_insights(input)
     }
-
-    /// <p>Returns the count of all Security Hub membership invitations that were sent to the current member account, not including the currently accepted invitation. </p>
     fn get_invitations_count(
         &self,
     ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
-        let request_uri = "/invitations/count";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInvitationsCountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetInvitationsCountError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_invitations_count()
     }
-
-    /// <p>Provides the details for the Security Hub master account to the current member account. </p>
     fn get_master_account(&self) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
-        let request_uri = "/master";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMasterAccountResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMasterAccountError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_master_account()
     }
-
-    /// <p>Returns the details on the Security Hub member accounts that the account IDs specify.</p>
     fn get_members(
         &self,
         input: GetMembersRequest,
     ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
-        let request_uri = "/members/get";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_members(input)
     }
-
-    /// <p>Invites other AWS accounts to become member accounts for the Security Hub master account that the invitation is sent from. Before you can use this action to invite a member, you must first create the member account in Security Hub by using the <a>CreateMembers</a> action. When the account owner accepts the invitation to become a member account and enables Security Hub, the master account can view the findings generated from member account.</p>
     fn invite_members(
         &self,
         input: InviteMembersRequest,
     ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
-        let request_uri = "/members/invite";
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InviteMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(InviteMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).invite_members(input)
     }
-
-    /// <p>Lists all findings-generating solutions (products) whose findings you have subscribed to receive in Security Hub.</p>
     fn list_enabled_products_for_import(
         &self,
         input: ListEnabledProductsForImportRequest,
     ) -> RusotoFuture<ListEnabledProductsForImportResponse, ListEnabledProductsForImportError> {
-        let request_uri = "/productSubscriptions";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
+        (**self).list_enabled_products_for_import(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> RusotoFuture<UpdateActionTargetResponse, UpdateActionTargetError> {
+        (**self).update_action_target(input)
+    }
+    fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> RusotoFuture<UpdateFindingsResponse, UpdateFindingsError> {
+        (**self).update_findings(input)
+    }
+    fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError> {
+        (**self).update_insight(input)
+    }
+}
+impl<T: SecurityHub + ?Sized> SecurityHub for Box<T> {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        (**self).accept_invitation(input)
+    }
+    fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> RusotoFuture<BatchDisableStandardsResponse, BatchDisableStandardsError> {
+        (**self).batch_disable_standards(input)
+    }
+    fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> RusotoFuture<BatchEnableStandardsResponse, BatchEnableStandardsError> {
+        (**self).batch_enable_standards(input)
+    }
+    fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> RusotoFuture<BatchImportFindingsResponse, BatchImportFindingsError> {
+        (**self).batch_import_findings(input)
+    }
+    fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> RusotoFuture<CreateActionTargetResponse, CreateActionTargetError> {
+        (**self).create_action_target(input)
+    }
+    fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> RusotoFuture<CreateInsightResponse, CreateInsightError> {
+        (**self).create_insight(input)
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        (**self).create_members(input)
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        (**self).decline_invitations(input)
+    }
+    fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> RusotoFuture<DeleteActionTargetResponse, DeleteActionTargetError> {
+        (**self).delete_action_target(input)
+    }
+    fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> RusotoFuture<DeleteInsightResponse, DeleteInsightError> {
+        (**self).delete_insight(input)
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        (**self).delete_invitations(input)
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        (**self).delete_members(input)
+    }
+    fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> RusotoFuture<DescribeActionTargetsResponse, DescribeActionTargetsError> {
+        (**self).describe_action_targets(input)
+    }
+    fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> RusotoFuture<DescribeHubResponse, DescribeHubError> {
+        (**self).describe_hub(input)
+    }
+    fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> RusotoFuture<DescribeProductsResponse, DescribeProductsError> {
+        (**self).describe_products(input)
+    }
+    fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> RusotoFuture<DisableImportFindingsForProductResponse, DisableImportFindingsForProductError>
+    {
+        (**self).disable_import_findings_for_product(input)
+    }
+    fn disable_security_hub(
+        &self,
+    ) -> RusotoFuture<DisableSecurityHubResponse, DisableSecurityHubError> {
+        (**self).disable_security_hub()
+    }
+    fn disassociate_from_master_account(
+        &self,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        (**self).disassociate_from_master_account()
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        (**self).disassociate_members(input)
+    }
+    fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> RusotoFuture<EnableImportFindingsForProductResponse, EnableImportFindingsForProductError>
+    {
+        (**self).enable_import_findings_for_product(input)
+    }
+    fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> RusotoFuture<EnableSecurityHubResponse, EnableSecurityHubError> {
+        (**self).enable_security_hub(input)
+    }
+    fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> RusotoFuture<GetEnabledStandardsResponse, GetEnabledStandardsError> {
+        (**self).get_enabled_standards(input)
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        (**self).get_findings(input)
+    }
+    fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> RusotoFuture<GetInsightResultsResponse, GetInsightResultsError> {
+        (**self).get_insight_results(input)
+    }
+    fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> RusotoFuture<GetInsightsResponse, GetInsightsError> {
+        (**self).get_insights(input)
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        (**self).get_invitations_count()
+    }
+    fn get_master_account(&self) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        (**self).get_master_account()
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        (**self).get_members(input)
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        (**self).invite_members(input)
+    }
+    fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> RusotoFuture<ListEnabledProductsForImportResponse, ListEnabledProductsForImportError> {
+        (**self).list_enabled_products_for_import(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> RusotoFuture<UpdateActionTargetResponse, UpdateActionTargetError> {
+        (**self).update_action_target(input)
+    }
+    fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> RusotoFuture<UpdateFindingsResponse, UpdateFindingsError> {
+        (**self).update_findings(input)
+    }
+    fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError> {
+        (**self).update_insight(input)
+    }
+}
+impl<T: SecurityHub + ?Sized> SecurityHub for ::std::sync::Arc<T> {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        (**self).accept_invitation(input)
+    }
+    fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> RusotoFuture<BatchDisableStandardsResponse, BatchDisableStandardsError> {
+        (**self).batch_disable_standards(input)
+    }
+    fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> RusotoFuture<BatchEnableStandardsResponse, BatchEnableStandardsError> {
+        (**self).batch_enable_standards(input)
+    }
+    fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> RusotoFuture<BatchImportFindingsResponse, BatchImportFindingsError> {
+        (**self).batch_import_findings(input)
+    }
+    fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> RusotoFuture<CreateActionTargetResponse, CreateActionTargetError> {
+        (**self).create_action_target(input)
+    }
+    fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> RusotoFuture<CreateInsightResponse, CreateInsightError> {
+        (**self).create_insight(input)
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        (**self).create_members(input)
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        (**self).decline_invitations(input)
+    }
+    fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> RusotoFuture<DeleteActionTargetResponse, DeleteActionTargetError> {
+        (**self).delete_action_target(input)
+    }
+    fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> RusotoFuture<DeleteInsightResponse, DeleteInsightError> {
+        (**self).delete_insight(input)
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        (**self).delete_invitations(input)
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        (**self).delete_members(input)
+    }
+    fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> RusotoFuture<DescribeActionTargetsResponse, DescribeActionTargetsError> {
+        (**self).describe_action_targets(input)
+    }
+    fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> RusotoFuture<DescribeHubResponse, DescribeHubError> {
+        (**self).describe_hub(input)
+    }
+    fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> RusotoFuture<DescribeProductsResponse, DescribeProductsError> {
+        (**self).describe_products(input)
+    }
+    fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> RusotoFuture<DisableImportFindingsForProductResponse, DisableImportFindingsForProductError>
+    {
+        (**self).disable_import_findings_for_product(input)
+    }
+    fn disable_security_hub(
+        &self,
+    ) -> RusotoFuture<DisableSecurityHubResponse, DisableSecurityHubError> {
+        (**self).disable_security_hub()
+    }
+    fn disassociate_from_master_account(
+        &self,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        (**self).disassociate_from_master_account()
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        (**self).disassociate_members(input)
+    }
+    fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> RusotoFuture<EnableImportFindingsForProductResponse, EnableImportFindingsForProductError>
+    {
+        (**self).enable_import_findings_for_product(input)
+    }
+    fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> RusotoFuture<EnableSecurityHubResponse, EnableSecurityHubError> {
+        (**self).enable_security_hub(input)
+    }
+    fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> RusotoFuture<GetEnabledStandardsResponse, GetEnabledStandardsError> {
+        (**self).get_enabled_standards(input)
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        (**self).get_findings(input)
+    }
+    fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> RusotoFuture<GetInsightResultsResponse, GetInsightResultsError> {
+        (**self).get_insight_results(input)
+    }
+    fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> RusotoFuture<GetInsightsResponse, GetInsightsError> {
+        (**self).get_insights(input)
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        (**self).get_invitations_count()
+    }
+    fn get_master_account(&self) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        (**self).get_master_account()
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        (**self).get_members(input)
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        (**self).invite_members(input)
+    }
+    fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> RusotoFuture<ListEnabledProductsForImportResponse, ListEnabledProductsForImportError> {
+        (**self).list_enabled_products_for_import(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> RusotoFuture<UpdateActionTargetResponse, UpdateActionTargetError> {
+        (**self).update_action_target(input)
+    }
+    fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> RusotoFuture<UpdateFindingsResponse, UpdateFindingsError> {
+        (**self).update_findings(input)
+    }
+    fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError> {
+        (**self).update_insight(input)
+    }
+}
+/// A client for the AWS SecurityHub API.
+#[derive(Clone)]
+pub struct SecurityHubClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SecurityHubClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SecurityHubClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SecurityHubClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubClient {
+        SecurityHubClient { client, region }
+    }
+}
+
+impl SecurityHub for SecurityHubClient {
+    /// <p>Accepts the invitation to be a member account and be monitored by the Security Hub master account that the invitation was sent from. When the member account accepts the invitation, permission is granted to the master account to view findings generated in the member account.</p>
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/master";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AcceptInvitation")
+                        .deserialize::<AcceptInvitationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AcceptInvitationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the standards specified by the provided <code>StandardsSubscriptionArns</code>. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
+    fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> RusotoFuture<BatchDisableStandardsResponse, BatchDisableStandardsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/standards/deregister";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchDisableStandards");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchDisableStandards")
+                        .deserialize::<BatchDisableStandardsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchDisableStandardsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables the standards specified by the provided <code>standardsArn</code>. In this release, only CIS AWS Foundations standards are supported. For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Standards Supported in AWS Security Hub</a>.</p>
+    fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> RusotoFuture<BatchEnableStandardsResponse, BatchEnableStandardsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/standards/register";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchEnableStandards");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchEnableStandards")
+                        .deserialize::<BatchEnableStandardsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchEnableStandardsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Imports security findings generated from an integrated third-party product into Security Hub. This action is requested by the integrated product to import its findings into Security Hub. The maximum allowed size for a finding is 240 Kb. An error is returned for any finding larger than 240 Kb.</p>
+    fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> RusotoFuture<BatchImportFindingsResponse, BatchImportFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/findings/import";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("BatchImportFindings");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchImportFindings")
+                        .deserialize::<BatchImportFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(BatchImportFindingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a custom action target in Security Hub. You can use custom actions on findings and insights in Security Hub to trigger target actions in Amazon CloudWatch Events.</p>
+    fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> RusotoFuture<CreateActionTargetResponse, CreateActionTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/actionTargets";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateActionTarget");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateActionTarget")
+                        .deserialize::<CreateActionTargetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateActionTargetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a custom insight in Security Hub. An insight is a consolidation of findings that relate to a security issue that requires attention or remediation. Use the <code>GroupByAttribute</code> to group the related findings in the insight.</p>
+    fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> RusotoFuture<CreateInsightResponse, CreateInsightError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/insights";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateInsight");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateInsight")
+                        .deserialize::<CreateInsightResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateInsightError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a member association in Security Hub between the specified accounts and the account used to make the request, which is the master account. To successfully create a member, you must use this action from an account that already has Security Hub enabled. You can use the <a>EnableSecurityHub</a> to enable Security Hub.</p> <p>After you use <code>CreateMembers</code> to create member account associations in Security Hub, you need to use the <a>InviteMembers</a> action, which invites the accounts to enable Security Hub and become member accounts in Security Hub. If the invitation is accepted by the account owner, the account becomes a member account in Security Hub, and a permission policy is added that permits the master account to view the findings generated in the member account. When Security Hub is enabled in the invited account, findings start being sent to both the member and master accounts.</p> <p>You can remove the association between the master and member accounts by using the <a>DisassociateFromMasterAccount</a> or <a>DisassociateMembers</a> operation.</p>
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/members";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateMembers")
+                        .deserialize::<CreateMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Declines invitations to become a member account.</p>
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/invitations/decline";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeclineInvitations")
+                        .deserialize::<DeclineInvitationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeclineInvitationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a custom action target from Security Hub. Deleting a custom action target doesn't affect any findings or insights that were already sent to Amazon CloudWatch Events using the custom action.</p>
+    fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> RusotoFuture<DeleteActionTargetResponse, DeleteActionTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/actionTargets/{action_target_arn}",
+            action_target_arn = input.action_target_arn
+        );
+
+        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteActionTarget");
+        request.add_resource_id("ActionTargetArn", &input.action_target_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteActionTarget")
+                        .deserialize::<DeleteActionTargetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteActionTargetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the insight specified by the <code>InsightArn</code>.</p>
+    fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> RusotoFuture<DeleteInsightResponse, DeleteInsightError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
+
+        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInsight");
+        request.add_resource_id("InsightArn", &input.insight_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteInsight")
+                        .deserialize::<DeleteInsightResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInsightError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes invitations received by the AWS account to become a member account.</p>
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/invitations/delete";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteInvitations")
+                        .deserialize::<DeleteInvitationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInvitationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified member accounts from Security Hub.</p>
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/members/delete";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteMembers")
+                        .deserialize::<DeleteMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of the custom action targets in Security Hub in your account.</p>
+    fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> RusotoFuture<DescribeActionTargetsResponse, DescribeActionTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/actionTargets/get";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeActionTargets");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeActionTargets")
+                        .deserialize::<DescribeActionTargetsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeActionTargetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns details about the Hub resource in your account, including the <code>HubArn</code> and the time when you enabled Security Hub.</p>
+    fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> RusotoFuture<DescribeHubResponse, DescribeHubError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/accounts";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeHub");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.hub_arn {
+            params.put("HubArn", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeHub")
+                        .deserialize::<DescribeHubResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeHubError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the products available that you can subscribe to and integrate with Security Hub to consolidate findings.</p>
+    fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> RusotoFuture<DescribeProductsResponse, DescribeProductsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/products";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("DescribeProducts");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeProducts")
+                        .deserialize::<DescribeProductsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeProductsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disables the integration of the specified product with Security Hub. Findings from that product are no longer sent to Security Hub after the integration is disabled.</p>
+    fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> RusotoFuture<DisableImportFindingsForProductResponse, DisableImportFindingsForProductError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/productSubscriptions/{product_subscription_arn}",
+            product_subscription_arn = input.product_subscription_arn
+        );
+
+        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableImportFindingsForProduct");
+        request.add_resource_id(
+            "ProductSubscriptionArn",
+            &input.product_subscription_arn.to_string(),
+        );
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisableImportFindingsForProduct")
+                        .deserialize::<DisableImportFindingsForProductResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisableImportFindingsForProductError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disables Security Hub in your account only in the current Region. To disable Security Hub in all Regions, you must submit one request per Region where you have enabled Security Hub. When you disable Security Hub for a master account, it doesn't disable Security Hub for any associated member accounts.</p> <p>When you disable Security Hub, your existing findings and insights and any Security Hub configuration settings are deleted after 90 days and can't be recovered. Any standards that were enabled are disabled, and your master and member account associations are removed. If you want to save your existing findings, you must export them before you disable Security Hub.</p>
+    fn disable_security_hub(
+        &self,
+    ) -> RusotoFuture<DisableSecurityHubResponse, DisableSecurityHubError> {
+        let request_uri = "/accounts";
+
+        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisableSecurityHub");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisableSecurityHub")
+                        .deserialize::<DisableSecurityHubResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableSecurityHubError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates the current Security Hub member account from the associated master account.</p>
+    fn disassociate_from_master_account(
+        &self,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        let request_uri = "/master/disassociate";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateFromMasterAccount")
+                        .deserialize::<DisassociateFromMasterAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateFromMasterAccountError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified member accounts from the associated master account.</p>
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/members/disassociate";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateMembers")
+                        .deserialize::<DisassociateMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisassociateMembersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables the integration of a partner product with Security Hub. Integrated products send findings to Security Hub. When you enable a product integration, a permission policy that grants permission for the product to send findings to Security Hub is applied.</p>
+    fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> RusotoFuture<EnableImportFindingsForProductResponse, EnableImportFindingsForProductError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/productSubscriptions";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableImportFindingsForProduct");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("EnableImportFindingsForProduct")
+                        .deserialize::<EnableImportFindingsForProductResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(EnableImportFindingsForProductError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Enables Security Hub for your account in the current Region or the Region you specify in the request. When you enable Security Hub, you grant to Security Hub the permissions necessary to gather findings from AWS Config, Amazon GuardDuty, Amazon Inspector, and Amazon Macie. To learn more, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-settingup.html">Setting Up AWS Security Hub</a>.</p>
+    fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> RusotoFuture<EnableSecurityHubResponse, EnableSecurityHubError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/accounts";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("EnableSecurityHub");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("EnableSecurityHub")
+                        .deserialize::<EnableSecurityHubResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(EnableSecurityHubError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of the standards that are currently enabled.</p>
+    fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> RusotoFuture<GetEnabledStandardsResponse, GetEnabledStandardsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/standards/get";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetEnabledStandards");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetEnabledStandards")
+                        .deserialize::<GetEnabledStandardsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetEnabledStandardsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of findings that match the specified criteria.</p>
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/findings";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetFindings");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFindings")
+                        .deserialize::<GetFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the results of the Security Hub insight that the insight ARN specifies.</p>
+    fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> RusotoFuture<GetInsightResultsResponse, GetInsightResultsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/insights/results/{insight_arn}",
+            insight_arn = input.insight_arn
+        );
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsightResults");
+        request.add_resource_id("InsightArn", &input.insight_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetInsightResults")
+                        .deserialize::<GetInsightResultsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInsightResultsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists and describes insights that insight ARNs specify.</p>
+    fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> RusotoFuture<GetInsightsResponse, GetInsightsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/insights/get";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInsights");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetInsights")
+                        .deserialize::<GetInsightsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInsightsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the count of all Security Hub membership invitations that were sent to the current member account, not including the currently accepted invitation. </p>
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        let request_uri = "/invitations/count";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetInvitationsCount")
+                        .deserialize::<GetInvitationsCountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetInvitationsCountError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides the details for the Security Hub master account to the current member account. </p>
+    fn get_master_account(&self) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        let request_uri = "/master";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMasterAccount")
+                        .deserialize::<GetMasterAccountResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMasterAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the details on the Security Hub member accounts that the account IDs specify.</p>
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/members/get";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("GetMembers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetMembers")
+                        .deserialize::<GetMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Invites other AWS accounts to become member accounts for the Security Hub master account that the invitation is sent from. Before you can use this action to invite a member, you must first create the member account in Security Hub by using the <a>CreateMembers</a> action. When the account owner accepts the invitation to become a member account and enables Security Hub, the master account can view the findings generated from member account.</p>
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/members/invite";
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InviteMembers")
+                        .deserialize::<InviteMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(InviteMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all findings-generating solutions (products) whose findings you have subscribed to receive in Security Hub.</p>
+    fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> RusotoFuture<ListEnabledProductsForImportResponse, ListEnabledProductsForImportError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/productSubscriptions";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListEnabledProductsForImport");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListEnabledProductsForImport")
+                        .deserialize::<ListEnabledProductsForImportResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListEnabledProductsForImportError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all Security Hub membership invitations that were sent to the current AWS account. </p>
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/invitations";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListInvitations")
+                        .deserialize::<ListInvitationsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListInvitationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists details about all member accounts for the current Security Hub master account.</p>
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/members";
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListMembers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.max_results {
+            params.put("MaxResults", x);
+        }
+        if let Some(ref x) = input.next_token {
+            params.put("NextToken", x);
+        }
+        if let Some(ref x) = input.only_associated {
+            params.put("OnlyAssociated", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListMembers")
+                        .deserialize::<ListMembersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of tags associated with a resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListTagsForResource")
+                        .deserialize::<ListTagsForResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds one or more tags to a resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation("TagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("TagResource")
+                        .deserialize::<TagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes one or more tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
+
+        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation("UntagResource");
+        request.add_resource_id("ResourceArn", &input.resource_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        for item in input.tag_keys.iter() {
+            params.put("tagKeys", item);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UntagResource")
+                        .deserialize::<UntagResourceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the name and description of a custom action target in Security Hub.</p>
+    fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> RusotoFuture<UpdateActionTargetResponse, UpdateActionTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/actionTargets/{action_target_arn}",
+            action_target_arn = input.action_target_arn
+        );
+
+        let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateActionTarget");
+        request.add_resource_id("ActionTargetArn", &input.action_target_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateActionTarget")
+                        .deserialize::<UpdateActionTargetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateActionTargetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the <code>Note</code> and <code>RecordState</code> of the Security Hub-aggregated findings that the filter attributes specify. Any member account that can view the finding also sees the update to the finding.</p>
+    fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> RusotoFuture<UpdateFindingsResponse, UpdateFindingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/findings";
+
+        let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFindings")
+                        .deserialize::<UpdateFindingsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFindingsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the Security Hub insight that the insight ARN specifies.</p>
+    fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
+
+        let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation("UpdateInsight");
+        request.add_resource_id("InsightArn", &input.insight_arn.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateInsight")
+                        .deserialize::<UpdateInsightResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateInsightError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS SecurityHub API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSecurityHub {
+    accept_invitation: Option<
+        Box<
+            dyn Fn(
+                    AcceptInvitationRequest,
+                ) -> ::std::result::Result<
+                    AcceptInvitationResponse,
+                    RusotoError<AcceptInvitationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_disable_standards: Option<
+        Box<
+            dyn Fn(
+                    BatchDisableStandardsRequest,
+                ) -> ::std::result::Result<
+                    BatchDisableStandardsResponse,
+                    RusotoError<BatchDisableStandardsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_enable_standards: Option<
+        Box<
+            dyn Fn(
+                    BatchEnableStandardsRequest,
+                ) -> ::std::result::Result<
+                    BatchEnableStandardsResponse,
+                    RusotoError<BatchEnableStandardsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_import_findings: Option<
+        Box<
+            dyn Fn(
+                    BatchImportFindingsRequest,
+                ) -> ::std::result::Result<
+                    BatchImportFindingsResponse,
+                    RusotoError<BatchImportFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_action_target: Option<
+        Box<
+            dyn Fn(
+                    CreateActionTargetRequest,
+                ) -> ::std::result::Result<
+                    CreateActionTargetResponse,
+                    RusotoError<CreateActionTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_insight: Option<
+        Box<
+            dyn Fn(
+                    CreateInsightRequest,
+                )
+                    -> ::std::result::Result<CreateInsightResponse, RusotoError<CreateInsightError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_members: Option<
+        Box<
+            dyn Fn(
+                    CreateMembersRequest,
+                )
+                    -> ::std::result::Result<CreateMembersResponse, RusotoError<CreateMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    decline_invitations: Option<
+        Box<
+            dyn Fn(
+                    DeclineInvitationsRequest,
+                ) -> ::std::result::Result<
+                    DeclineInvitationsResponse,
+                    RusotoError<DeclineInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_action_target: Option<
+        Box<
+            dyn Fn(
+                    DeleteActionTargetRequest,
+                ) -> ::std::result::Result<
+                    DeleteActionTargetResponse,
+                    RusotoError<DeleteActionTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_insight: Option<
+        Box<
+            dyn Fn(
+                    DeleteInsightRequest,
+                )
+                    -> ::std::result::Result<DeleteInsightResponse, RusotoError<DeleteInsightError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_invitations: Option<
+        Box<
+            dyn Fn(
+                    DeleteInvitationsRequest,
+                ) -> ::std::result::Result<
+                    DeleteInvitationsResponse,
+                    RusotoError<DeleteInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_members: Option<
+        Box<
+            dyn Fn(
+                    DeleteMembersRequest,
+                )
+                    -> ::std::result::Result<DeleteMembersResponse, RusotoError<DeleteMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_action_targets: Option<
+        Box<
+            dyn Fn(
+                    DescribeActionTargetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeActionTargetsResponse,
+                    RusotoError<DescribeActionTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_hub: Option<
+        Box<
+            dyn Fn(
+                    DescribeHubRequest,
+                )
+                    -> ::std::result::Result<DescribeHubResponse, RusotoError<DescribeHubError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_products: Option<
+        Box<
+            dyn Fn(
+                    DescribeProductsRequest,
+                ) -> ::std::result::Result<
+                    DescribeProductsResponse,
+                    RusotoError<DescribeProductsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_import_findings_for_product: Option<
+        Box<
+            dyn Fn(
+                    DisableImportFindingsForProductRequest,
+                ) -> ::std::result::Result<
+                    DisableImportFindingsForProductResponse,
+                    RusotoError<DisableImportFindingsForProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_security_hub: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DisableSecurityHubResponse,
+                    RusotoError<DisableSecurityHubError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_from_master_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DisassociateFromMasterAccountResponse,
+                    RusotoError<DisassociateFromMasterAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_members: Option<
+        Box<
+            dyn Fn(
+                    DisassociateMembersRequest,
+                ) -> ::std::result::Result<
+                    DisassociateMembersResponse,
+                    RusotoError<DisassociateMembersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_import_findings_for_product: Option<
+        Box<
+            dyn Fn(
+                    EnableImportFindingsForProductRequest,
+                ) -> ::std::result::Result<
+                    EnableImportFindingsForProductResponse,
+                    RusotoError<EnableImportFindingsForProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_security_hub: Option<
+        Box<
+            dyn Fn(
+                    EnableSecurityHubRequest,
+                ) -> ::std::result::Result<
+                    EnableSecurityHubResponse,
+                    RusotoError<EnableSecurityHubError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_enabled_standards: Option<
+        Box<
+            dyn Fn(
+                    GetEnabledStandardsRequest,
+                ) -> ::std::result::Result<
+                    GetEnabledStandardsResponse,
+                    RusotoError<GetEnabledStandardsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_findings: Option<
+        Box<
+            dyn Fn(
+                    GetFindingsRequest,
+                )
+                    -> ::std::result::Result<GetFindingsResponse, RusotoError<GetFindingsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_insight_results: Option<
+        Box<
+            dyn Fn(
+                    GetInsightResultsRequest,
+                ) -> ::std::result::Result<
+                    GetInsightResultsResponse,
+                    RusotoError<GetInsightResultsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_insights: Option<
+        Box<
+            dyn Fn(
+                    GetInsightsRequest,
+                )
+                    -> ::std::result::Result<GetInsightsResponse, RusotoError<GetInsightsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_invitations_count: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetInvitationsCountResponse,
+                    RusotoError<GetInvitationsCountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_master_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetMasterAccountResponse,
+                    RusotoError<GetMasterAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_members: Option<
+        Box<
+            dyn Fn(
+                    GetMembersRequest,
+                )
+                    -> ::std::result::Result<GetMembersResponse, RusotoError<GetMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    invite_members: Option<
+        Box<
+            dyn Fn(
+                    InviteMembersRequest,
+                )
+                    -> ::std::result::Result<InviteMembersResponse, RusotoError<InviteMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_enabled_products_for_import: Option<
+        Box<
+            dyn Fn(
+                    ListEnabledProductsForImportRequest,
+                ) -> ::std::result::Result<
+                    ListEnabledProductsForImportResponse,
+                    RusotoError<ListEnabledProductsForImportError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_invitations: Option<
+        Box<
+            dyn Fn(
+                    ListInvitationsRequest,
+                ) -> ::std::result::Result<
+                    ListInvitationsResponse,
+                    RusotoError<ListInvitationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_members: Option<
+        Box<
+            dyn Fn(
+                    ListMembersRequest,
+                )
+                    -> ::std::result::Result<ListMembersResponse, RusotoError<ListMembersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_action_target: Option<
+        Box<
+            dyn Fn(
+                    UpdateActionTargetRequest,
+                ) -> ::std::result::Result<
+                    UpdateActionTargetResponse,
+                    RusotoError<UpdateActionTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_findings: Option<
+        Box<
+            dyn Fn(
+                    UpdateFindingsRequest,
+                ) -> ::std::result::Result<
+                    UpdateFindingsResponse,
+                    RusotoError<UpdateFindingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_insight: Option<
+        Box<
+            dyn Fn(
+                    UpdateInsightRequest,
+                )
+                    -> ::std::result::Result<UpdateInsightResponse, RusotoError<UpdateInsightError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSecurityHub {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSecurityHub {
+        Default::default()
+    }
+
+    /// Answers `accept_invitation` with what `handler` returns for its input.
+    pub fn with_accept_invitation<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                AcceptInvitationRequest,
+            ) -> ::std::result::Result<
+                AcceptInvitationResponse,
+                RusotoError<AcceptInvitationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_invitation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_disable_standards` with what `handler` returns for its input.
+    pub fn with_batch_disable_standards<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                BatchDisableStandardsRequest,
+            ) -> ::std::result::Result<
+                BatchDisableStandardsResponse,
+                RusotoError<BatchDisableStandardsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_disable_standards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_enable_standards` with what `handler` returns for its input.
+    pub fn with_batch_enable_standards<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                BatchEnableStandardsRequest,
+            ) -> ::std::result::Result<
+                BatchEnableStandardsResponse,
+                RusotoError<BatchEnableStandardsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_enable_standards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_import_findings` with what `handler` returns for its input.
+    pub fn with_batch_import_findings<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                BatchImportFindingsRequest,
+            ) -> ::std::result::Result<
+                BatchImportFindingsResponse,
+                RusotoError<BatchImportFindingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_import_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_action_target` with what `handler` returns for its input.
+    pub fn with_create_action_target<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                CreateActionTargetRequest,
+            ) -> ::std::result::Result<
+                CreateActionTargetResponse,
+                RusotoError<CreateActionTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_action_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_insight` with what `handler` returns for its input.
+    pub fn with_create_insight<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                CreateInsightRequest,
+            )
+                -> ::std::result::Result<CreateInsightResponse, RusotoError<CreateInsightError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_insight = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_members` with what `handler` returns for its input.
+    pub fn with_create_members<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                CreateMembersRequest,
+            )
+                -> ::std::result::Result<CreateMembersResponse, RusotoError<CreateMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decline_invitations` with what `handler` returns for its input.
+    pub fn with_decline_invitations<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DeclineInvitationsRequest,
+            ) -> ::std::result::Result<
+                DeclineInvitationsResponse,
+                RusotoError<DeclineInvitationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.decline_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_action_target` with what `handler` returns for its input.
+    pub fn with_delete_action_target<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DeleteActionTargetRequest,
+            ) -> ::std::result::Result<
+                DeleteActionTargetResponse,
+                RusotoError<DeleteActionTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_action_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_insight` with what `handler` returns for its input.
+    pub fn with_delete_insight<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DeleteInsightRequest,
+            )
+                -> ::std::result::Result<DeleteInsightResponse, RusotoError<DeleteInsightError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_insight = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_invitations` with what `handler` returns for its input.
+    pub fn with_delete_invitations<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DeleteInvitationsRequest,
+            ) -> ::std::result::Result<
+                DeleteInvitationsResponse,
+                RusotoError<DeleteInvitationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_members` with what `handler` returns for its input.
+    pub fn with_delete_members<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DeleteMembersRequest,
+            )
+                -> ::std::result::Result<DeleteMembersResponse, RusotoError<DeleteMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_action_targets` with what `handler` returns for its input.
+    pub fn with_describe_action_targets<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DescribeActionTargetsRequest,
+            ) -> ::std::result::Result<
+                DescribeActionTargetsResponse,
+                RusotoError<DescribeActionTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_action_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_hub` with what `handler` returns for its input.
+    pub fn with_describe_hub<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DescribeHubRequest,
+            )
+                -> ::std::result::Result<DescribeHubResponse, RusotoError<DescribeHubError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_hub = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_products` with what `handler` returns for its input.
+    pub fn with_describe_products<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DescribeProductsRequest,
+            ) -> ::std::result::Result<
+                DescribeProductsResponse,
+                RusotoError<DescribeProductsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_products = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_import_findings_for_product` with what `handler` returns for its input.
+    pub fn with_disable_import_findings_for_product<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DisableImportFindingsForProductRequest,
+            ) -> ::std::result::Result<
+                DisableImportFindingsForProductResponse,
+                RusotoError<DisableImportFindingsForProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_import_findings_for_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_security_hub` with what `handler` returns.
+    pub fn with_disable_security_hub<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn() -> ::std::result::Result<
+                DisableSecurityHubResponse,
+                RusotoError<DisableSecurityHubError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_security_hub = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_from_master_account` with what `handler` returns.
+    pub fn with_disassociate_from_master_account<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn() -> ::std::result::Result<
+                DisassociateFromMasterAccountResponse,
+                RusotoError<DisassociateFromMasterAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_from_master_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_members` with what `handler` returns for its input.
+    pub fn with_disassociate_members<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                DisassociateMembersRequest,
+            ) -> ::std::result::Result<
+                DisassociateMembersResponse,
+                RusotoError<DisassociateMembersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_import_findings_for_product` with what `handler` returns for its input.
+    pub fn with_enable_import_findings_for_product<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                EnableImportFindingsForProductRequest,
+            ) -> ::std::result::Result<
+                EnableImportFindingsForProductResponse,
+                RusotoError<EnableImportFindingsForProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_import_findings_for_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_security_hub` with what `handler` returns for its input.
+    pub fn with_enable_security_hub<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                EnableSecurityHubRequest,
+            ) -> ::std::result::Result<
+                EnableSecurityHubResponse,
+                RusotoError<EnableSecurityHubError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_security_hub = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_enabled_standards` with what `handler` returns for its input.
+    pub fn with_get_enabled_standards<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                GetEnabledStandardsRequest,
+            ) -> ::std::result::Result<
+                GetEnabledStandardsResponse,
+                RusotoError<GetEnabledStandardsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_enabled_standards = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_findings` with what `handler` returns for its input.
+    pub fn with_get_findings<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                GetFindingsRequest,
+            )
+                -> ::std::result::Result<GetFindingsResponse, RusotoError<GetFindingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_insight_results` with what `handler` returns for its input.
+    pub fn with_get_insight_results<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                GetInsightResultsRequest,
+            ) -> ::std::result::Result<
+                GetInsightResultsResponse,
+                RusotoError<GetInsightResultsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_insight_results = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_insights` with what `handler` returns for its input.
+    pub fn with_get_insights<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                GetInsightsRequest,
+            )
+                -> ::std::result::Result<GetInsightsResponse, RusotoError<GetInsightsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_insights = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_invitations_count` with what `handler` returns.
+    pub fn with_get_invitations_count<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn() -> ::std::result::Result<
+                GetInvitationsCountResponse,
+                RusotoError<GetInvitationsCountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_invitations_count = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_master_account` with what `handler` returns.
+    pub fn with_get_master_account<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn() -> ::std::result::Result<
+                GetMasterAccountResponse,
+                RusotoError<GetMasterAccountError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_master_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_members` with what `handler` returns for its input.
+    pub fn with_get_members<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                GetMembersRequest,
+            )
+                -> ::std::result::Result<GetMembersResponse, RusotoError<GetMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `invite_members` with what `handler` returns for its input.
+    pub fn with_invite_members<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                InviteMembersRequest,
+            )
+                -> ::std::result::Result<InviteMembersResponse, RusotoError<InviteMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.invite_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_enabled_products_for_import` with what `handler` returns for its input.
+    pub fn with_list_enabled_products_for_import<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                ListEnabledProductsForImportRequest,
+            ) -> ::std::result::Result<
+                ListEnabledProductsForImportResponse,
+                RusotoError<ListEnabledProductsForImportError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_enabled_products_for_import = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_invitations` with what `handler` returns for its input.
+    pub fn with_list_invitations<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                ListInvitationsRequest,
+            )
+                -> ::std::result::Result<ListInvitationsResponse, RusotoError<ListInvitationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_invitations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_members` with what `handler` returns for its input.
+    pub fn with_list_members<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                ListMembersRequest,
+            )
+                -> ::std::result::Result<ListMembersResponse, RusotoError<ListMembersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_action_target` with what `handler` returns for its input.
+    pub fn with_update_action_target<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                UpdateActionTargetRequest,
+            ) -> ::std::result::Result<
+                UpdateActionTargetResponse,
+                RusotoError<UpdateActionTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_action_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_findings` with what `handler` returns for its input.
+    pub fn with_update_findings<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                UpdateFindingsRequest,
+            )
+                -> ::std::result::Result<UpdateFindingsResponse, RusotoError<UpdateFindingsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_findings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_insight` with what `handler` returns for its input.
+    pub fn with_update_insight<F>(mut self, handler: F) -> MockSecurityHub
+    where
+        F: Fn(
+                UpdateInsightRequest,
+            )
+                -> ::std::result::Result<UpdateInsightResponse, RusotoError<UpdateInsightError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_insight = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl SecurityHub for MockSecurityHub {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        match self.accept_invitation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for accept_invitation"),
+        }
+    }
+    fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> RusotoFuture<BatchDisableStandardsResponse, BatchDisableStandardsError> {
+        match self.batch_disable_standards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for batch_disable_standards"),
+        }
+    }
+    fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> RusotoFuture<BatchEnableStandardsResponse, BatchEnableStandardsError> {
+        match self.batch_enable_standards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for batch_enable_standards"),
+        }
+    }
+    fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> RusotoFuture<BatchImportFindingsResponse, BatchImportFindingsError> {
+        match self.batch_import_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for batch_import_findings"),
+        }
+    }
+    fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> RusotoFuture<CreateActionTargetResponse, CreateActionTargetError> {
+        match self.create_action_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for create_action_target"),
+        }
+    }
+    fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> RusotoFuture<CreateInsightResponse, CreateInsightError> {
+        match self.create_insight {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for create_insight"),
+        }
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        match self.create_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for create_members"),
+        }
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        match self.decline_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for decline_invitations"),
+        }
+    }
+    fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> RusotoFuture<DeleteActionTargetResponse, DeleteActionTargetError> {
+        match self.delete_action_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for delete_action_target"),
+        }
+    }
+    fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> RusotoFuture<DeleteInsightResponse, DeleteInsightError> {
+        match self.delete_insight {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for delete_insight"),
+        }
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        match self.delete_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for delete_invitations"),
+        }
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        match self.delete_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for delete_members"),
+        }
+    }
+    fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> RusotoFuture<DescribeActionTargetsResponse, DescribeActionTargetsError> {
+        match self.describe_action_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for describe_action_targets"),
+        }
+    }
+    fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> RusotoFuture<DescribeHubResponse, DescribeHubError> {
+        match self.describe_hub {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for describe_hub"),
+        }
+    }
+    fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> RusotoFuture<DescribeProductsResponse, DescribeProductsError> {
+        match self.describe_products {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for describe_products"),
+        }
+    }
+    fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> RusotoFuture<DisableImportFindingsForProductResponse, DisableImportFindingsForProductError>
+    {
+        match self.disable_import_findings_for_product {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSecurityHub has no handler for disable_import_findings_for_product")
+            }
+        }
+    }
+    fn disable_security_hub(
+        &self,
+    ) -> RusotoFuture<DisableSecurityHubResponse, DisableSecurityHubError> {
+        match self.disable_security_hub {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSecurityHub has no handler for disable_security_hub"),
+        }
+    }
+    fn disassociate_from_master_account(
+        &self,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        match self.disassociate_from_master_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSecurityHub has no handler for disassociate_from_master_account"),
+        }
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        match self.disassociate_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for disassociate_members"),
+        }
+    }
+    fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> RusotoFuture<EnableImportFindingsForProductResponse, EnableImportFindingsForProductError>
+    {
+        match self.enable_import_findings_for_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for enable_import_findings_for_product"),
+        }
+    }
+    fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> RusotoFuture<EnableSecurityHubResponse, EnableSecurityHubError> {
+        match self.enable_security_hub {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for enable_security_hub"),
+        }
+    }
+    fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> RusotoFuture<GetEnabledStandardsResponse, GetEnabledStandardsError> {
+        match self.get_enabled_standards {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for get_enabled_standards"),
+        }
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        match self.get_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for get_findings"),
+        }
+    }
+    fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> RusotoFuture<GetInsightResultsResponse, GetInsightResultsError> {
+        match self.get_insight_results {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for get_insight_results"),
+        }
+    }
+    fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> RusotoFuture<GetInsightsResponse, GetInsightsError> {
+        match self.get_insights {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for get_insights"),
+        }
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        match self.get_invitations_count {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSecurityHub has no handler for get_invitations_count"),
+        }
+    }
+    fn get_master_account(&self) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        match self.get_master_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSecurityHub has no handler for get_master_account"),
+        }
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        match self.get_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for get_members"),
+        }
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        match self.invite_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for invite_members"),
+        }
+    }
+    fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> RusotoFuture<ListEnabledProductsForImportResponse, ListEnabledProductsForImportError> {
+        match self.list_enabled_products_for_import {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for list_enabled_products_for_import"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListEnabledProductsForImportResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListEnabledProductsForImportError::from_response(response))
-                }))
-            }
-        })
     }
-
-    /// <p>Lists all Security Hub membership invitations that were sent to the current AWS account. </p>
     fn list_invitations(
         &self,
         input: ListInvitationsRequest,
     ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
-        let request_uri = "/invitations";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
+        match self.list_invitations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for list_invitations"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInvitationsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListInvitationsError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Lists details about all member accounts for the current Security Hub master account.</p>
     fn list_members(
         &self,
         input: ListMembersRequest,
     ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
-        let request_uri = "/members";
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.max_results {
-            params.put("MaxResults", x);
-        }
-        if let Some(ref x) = input.next_token {
-            params.put("NextToken", x);
-        }
-        if let Some(ref x) = input.only_associated {
-            params.put("OnlyAssociated", x);
+        match self.list_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for list_members"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMembersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListMembersError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Returns a list of tags associated with a resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for list_tags_for_resource"),
+        }
     }
-
-    /// <p>Adds one or more tags to a resource.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Removes one or more tags from a resource.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
-
-        let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        for item in input.tag_keys.iter() {
-            params.put("tagKeys", item);
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for untag_resource"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Updates the name and description of a custom action target in Security Hub.</p>
     fn update_action_target(
         &self,
         input: UpdateActionTargetRequest,
     ) -> RusotoFuture<UpdateActionTargetResponse, UpdateActionTargetError> {
-        let request_uri = format!(
-            "/actionTargets/{action_target_arn}",
-            action_target_arn = input.action_target_arn
-        );
-
-        let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateActionTargetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateActionTargetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_action_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for update_action_target"),
+        }
     }
-
-    /// <p>Updates the <code>Note</code> and <code>RecordState</code> of the Security Hub-aggregated findings that the filter attributes specify. Any member account that can view the finding also sees the update to the finding.</p>
     fn update_findings(
         &self,
         input: UpdateFindingsRequest,
     ) -> RusotoFuture<UpdateFindingsResponse, UpdateFindingsError> {
-        let request_uri = "/findings";
-
-        let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFindingsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFindingsError::from_response(response))),
-                )
-            }
-        })
+        match self.update_findings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for update_findings"),
+        }
     }
-
-    /// <p>Updates the Security Hub insight that the insight ARN specifies.</p>
     fn update_insight(
         &self,
         input: UpdateInsightRequest,
     ) -> RusotoFuture<UpdateInsightResponse, UpdateInsightError> {
-        let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
-
-        let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateInsightResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateInsightError::from_response(response))),
-                )
-            }
-        })
+        match self.update_insight {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSecurityHub has no handler for update_insight"),
+        }
     }
 }
@@ -23,10 +23,11 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>A nested application summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplicationDependencySummary {
     /// <p>The Amazon Resource Name (ARN) of the nested application.</p>
     #[serde(rename = "ApplicationId")]
@@ -54,7 +55,7 @@ pub struct ApplicationPolicyStatement {
 
 /// <p>Summary of details about the application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ApplicationSummary {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -87,6 +88,7 @@ pub struct ApplicationSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationRequest {
     /// <p>The name of the author publishing the app.</p><p>Minimum length=1. Maximum length=127.</p><p>Pattern "^[a-z0-9](([a-z0-9]|-(?!-))*[a-z0-9])?$";</p>
     #[serde(rename = "Author")]
@@ -153,7 +155,7 @@ pub struct CreateApplicationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApplicationResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -202,6 +204,7 @@ pub struct CreateApplicationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateApplicationVersionRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -228,7 +231,7 @@ pub struct CreateApplicationVersionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateApplicationVersionResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -294,6 +297,7 @@ pub struct CreateApplicationVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCloudFormationChangeSetRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -380,7 +384,7 @@ pub struct CreateCloudFormationChangeSetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCloudFormationChangeSetResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -403,6 +407,7 @@ pub struct CreateCloudFormationChangeSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCloudFormationTemplateRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -416,7 +421,7 @@ pub struct CreateCloudFormationTemplateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCloudFormationTemplateResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -454,6 +459,7 @@ pub struct CreateCloudFormationTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteApplicationRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -461,6 +467,7 @@ pub struct DeleteApplicationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApplicationPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -468,7 +475,7 @@ pub struct GetApplicationPolicyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApplicationPolicyResponse {
     /// <p>An array of policy statements applied to the application.</p>
     #[serde(rename = "Statements")]
@@ -477,6 +484,7 @@ pub struct GetApplicationPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetApplicationRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -488,7 +496,7 @@ pub struct GetApplicationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetApplicationResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -537,6 +545,7 @@ pub struct GetApplicationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCloudFormationTemplateRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -547,7 +556,7 @@ pub struct GetCloudFormationTemplateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCloudFormationTemplateResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -585,6 +594,7 @@ pub struct GetCloudFormationTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApplicationDependenciesRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -603,8 +613,16 @@ pub struct ListApplicationDependenciesRequest {
     pub semantic_version: Option<String>,
 }
 
+impl Validate for ListApplicationDependenciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApplicationDependenciesResponse {
     /// <p>An array of application summaries nested in the application.</p>
     #[serde(rename = "Dependencies")]
@@ -617,6 +635,7 @@ pub struct ListApplicationDependenciesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApplicationVersionsRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -631,8 +650,16 @@ pub struct ListApplicationVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListApplicationVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApplicationVersionsResponse {
     /// <p>The token to request the next page of results.</p>
     #[serde(rename = "NextToken")]
@@ -645,6 +672,7 @@ pub struct ListApplicationVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListApplicationsRequest {
     /// <p>The total number of items to return.</p>
     #[serde(rename = "MaxItems")]
@@ -656,8 +684,16 @@ pub struct ListApplicationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListApplicationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_items {
+            errors.check_range(&field_path(path, "max_items"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListApplicationsResponse {
     /// <p>An array of application summaries.</p>
     #[serde(rename = "Applications")]
@@ -671,7 +707,7 @@ pub struct ListApplicationsResponse {
 
 /// <p>Parameters supported by the application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterDefinition {
     /// <p>A regular expression that represents the patterns to allow for String types.</p>
     #[serde(rename = "AllowedPattern")]
@@ -744,6 +780,7 @@ pub struct ParameterDefinition {
 
 /// <p>Parameter value of the application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParameterValue {
     /// <p>The key associated with the parameter. If you don't specify a key and value for a particular parameter, AWS CloudFormation
     /// uses the default value that is specified in your template.</p>
@@ -755,6 +792,7 @@ pub struct ParameterValue {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutApplicationPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -765,7 +803,7 @@ pub struct PutApplicationPolicyRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutApplicationPolicyResponse {
     /// <p>An array of policy statements applied to the application.</p>
     #[serde(rename = "Statements")]
@@ -776,6 +814,7 @@ pub struct PutApplicationPolicyResponse {
 /// <p>This property corresponds to the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/RollbackConfiguration">RollbackConfiguration</a>
 /// </i> Data Type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RollbackConfiguration {
     /// <p>This property corresponds to the content of the same name for the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/RollbackConfiguration">RollbackConfiguration</a>
     /// </i> Data Type.</p>
@@ -792,6 +831,7 @@ pub struct RollbackConfiguration {
 /// <p>This property corresponds to the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/RollbackTrigger">RollbackTrigger</a>
 /// </i> Data Type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RollbackTrigger {
     /// <p>This property corresponds to the content of the same name for the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/RollbackTrigger">RollbackTrigger</a>
     /// </i> Data Type.</p>
@@ -806,6 +846,7 @@ pub struct RollbackTrigger {
 /// <p>This property corresponds to the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/Tag">Tag</a>
 /// </i> Data Type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>This property corresponds to the content of the same name for the <i>AWS CloudFormation <a href="https://docs.aws.amazon.com/goto/WebAPI/cloudformation-2010-05-15/Tag">Tag</a>
     /// </i> Data Type.</p>
@@ -820,6 +861,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateApplicationRequest {
     /// <p>The Amazon Resource Name (ARN) of the application.</p>
     #[serde(rename = "ApplicationId")]
@@ -851,7 +893,7 @@ pub struct UpdateApplicationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateApplicationResponse {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -901,7 +943,7 @@ pub struct UpdateApplicationResponse {
 
 /// <p>Application version details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Version {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -961,7 +1003,7 @@ pub struct Version {
 
 /// <p>An application version summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VersionSummary {
     /// <p>The application Amazon Resource Name (ARN).</p>
     #[serde(rename = "ApplicationId")]
@@ -1016,7 +1058,12 @@ impl CreateApplicationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(CreateApplicationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1077,7 +1124,12 @@ impl CreateApplicationVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1139,7 +1191,12 @@ impl CreateCloudFormationChangeSetError {
                         CreateCloudFormationChangeSetError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1207,7 +1264,12 @@ impl CreateCloudFormationTemplateError {
                         CreateCloudFormationTemplateError::TooManyRequests(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1271,7 +1333,12 @@ impl DeleteApplicationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteApplicationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1329,7 +1396,12 @@ impl GetApplicationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetApplicationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1390,7 +1462,12 @@ impl GetApplicationPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1453,7 +1530,12 @@ impl GetCloudFormationTemplateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1522,7 +1604,12 @@ impl ListApplicationDependenciesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1583,7 +1670,12 @@ impl ListApplicationVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1637,7 +1729,12 @@ impl ListApplicationsError {
                 "NotFoundException" => {
                     return RusotoError::Service(ListApplicationsError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1697,7 +1794,12 @@ impl PutApplicationPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1761,7 +1863,12 @@ impl UpdateApplicationError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(UpdateApplicationError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1868,6 +1975,250 @@ pub trait ServerlessRepo {
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
 }
+const _: Option<&dyn ServerlessRepo> = None;
+impl<'a, T: ServerlessRepo + ?Sized> ServerlessRepo for &'a T {
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> RusotoFuture<CreateApplicationVersionResponse, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> RusotoFuture<CreateCloudFormationChangeSetResponse, CreateCloudFormationChangeSetError>
+    {
+        (**self).create_cloud_formation_change_set(input)
+    }
+    fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> RusotoFuture<CreateCloudFormationTemplateResponse, CreateCloudFormationTemplateError> {
+        (**self).create_cloud_formation_template(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> RusotoFuture<GetApplicationResponse, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> RusotoFuture<GetApplicationPolicyResponse, GetApplicationPolicyError> {
+        (**self).get_application_policy(input)
+    }
+    fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> RusotoFuture<GetCloudFormationTemplateResponse, GetCloudFormationTemplateError> {
+        (**self).get_cloud_formation_template(input)
+    }
+    fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> RusotoFuture<ListApplicationDependenciesResponse, ListApplicationDependenciesError> {
+        (**self).list_application_dependencies(input)
+    }
+    fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> RusotoFuture<ListApplicationVersionsResponse, ListApplicationVersionsError> {
+        (**self).list_application_versions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> RusotoFuture<PutApplicationPolicyResponse, PutApplicationPolicyError> {
+        (**self).put_application_policy(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+impl<T: ServerlessRepo + ?Sized> ServerlessRepo for Box<T> {
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> RusotoFuture<CreateApplicationVersionResponse, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> RusotoFuture<CreateCloudFormationChangeSetResponse, CreateCloudFormationChangeSetError>
+    {
+        (**self).create_cloud_formation_change_set(input)
+    }
+    fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> RusotoFuture<CreateCloudFormationTemplateResponse, CreateCloudFormationTemplateError> {
+        (**self).create_cloud_formation_template(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> RusotoFuture<GetApplicationResponse, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> RusotoFuture<GetApplicationPolicyResponse, GetApplicationPolicyError> {
+        (**self).get_application_policy(input)
+    }
+    fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> RusotoFuture<GetCloudFormationTemplateResponse, GetCloudFormationTemplateError> {
+        (**self).get_cloud_formation_template(input)
+    }
+    fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> RusotoFuture<ListApplicationDependenciesResponse, ListApplicationDependenciesError> {
+        (**self).list_application_dependencies(input)
+    }
+    fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> RusotoFuture<ListApplicationVersionsResponse, ListApplicationVersionsError> {
+        (**self).list_application_versions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> RusotoFuture<PutApplicationPolicyResponse, PutApplicationPolicyError> {
+        (**self).put_application_policy(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+impl<T: ServerlessRepo + ?Sized> ServerlessRepo for ::std::sync::Arc<T> {
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> RusotoFuture<CreateApplicationVersionResponse, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> RusotoFuture<CreateCloudFormationChangeSetResponse, CreateCloudFormationChangeSetError>
+    {
+        (**self).create_cloud_formation_change_set(input)
+    }
+    fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> RusotoFuture<CreateCloudFormationTemplateResponse, CreateCloudFormationTemplateError> {
+        (**self).create_cloud_formation_template(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> RusotoFuture<GetApplicationResponse, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> RusotoFuture<GetApplicationPolicyResponse, GetApplicationPolicyError> {
+        (**self).get_application_policy(input)
+    }
+    fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> RusotoFuture<GetCloudFormationTemplateResponse, GetCloudFormationTemplateError> {
+        (**self).get_cloud_formation_template(input)
+    }
+    fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> RusotoFuture<ListApplicationDependenciesResponse, ListApplicationDependenciesError> {
+        (**self).list_application_dependencies(input)
+    }
+    fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> RusotoFuture<ListApplicationVersionsResponse, ListApplicationVersionsError> {
+        (**self).list_application_versions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> RusotoFuture<PutApplicationPolicyResponse, PutApplicationPolicyError> {
+        (**self).put_application_policy(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
 /// A client for the AWSServerlessApplicationRepository API.
 #[derive(Clone)]
 pub struct ServerlessRepoClient {
@@ -1914,6 +2265,8 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1923,6 +2276,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApplication")
                         .deserialize::<CreateApplicationResponse, _>()?;
 
                     Ok(result)
@@ -1950,6 +2304,10 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateApplicationVersion");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.add_resource_id("SemanticVersion", &input.semantic_version.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1959,6 +2317,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateApplicationVersion")
                         .deserialize::<CreateApplicationVersionResponse, _>()?;
 
                     Ok(result)
@@ -1983,6 +2342,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationChangeSet");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1992,6 +2354,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCloudFormationChangeSet")
                         .deserialize::<CreateCloudFormationChangeSetResponse, _>()?;
 
                     Ok(result)
@@ -2015,6 +2378,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("CreateCloudFormationTemplate");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2024,6 +2390,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 201 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCloudFormationTemplate")
                         .deserialize::<CreateCloudFormationTemplateResponse, _>()?;
 
                     Ok(result)
@@ -2048,6 +2415,8 @@ impl ServerlessRepo for ServerlessRepoClient {
 
         let mut request =
             SignedRequest::new("DELETE", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
@@ -2079,6 +2448,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplication");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2091,6 +2463,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApplication")
                         .deserialize::<GetApplicationResponse, _>()?;
 
                     Ok(result)
@@ -2117,12 +2490,16 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetApplicationPolicy");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetApplicationPolicy")
                         .deserialize::<GetApplicationPolicyResponse, _>()?;
 
                     Ok(result)
@@ -2149,12 +2526,17 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("GetCloudFormationTemplate");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.add_resource_id("TemplateId", &input.template_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetCloudFormationTemplate")
                         .deserialize::<GetCloudFormationTemplateResponse, _>()?;
 
                     Ok(result)
@@ -2172,12 +2554,23 @@ impl ServerlessRepo for ServerlessRepoClient {
         &self,
         input: ListApplicationDependenciesRequest,
     ) -> RusotoFuture<ListApplicationDependenciesResponse, ListApplicationDependenciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/applications/{application_id}/dependencies",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationDependencies");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2196,6 +2589,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListApplicationDependencies")
                         .deserialize::<ListApplicationDependenciesResponse, _>()?;
 
                     Ok(result)
@@ -2213,12 +2607,23 @@ impl ServerlessRepo for ServerlessRepoClient {
         &self,
         input: ListApplicationVersionsRequest,
     ) -> RusotoFuture<ListApplicationVersionsResponse, ListApplicationVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = format!(
             "/applications/{application_id}/versions",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplicationVersions");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2234,6 +2639,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListApplicationVersions")
                         .deserialize::<ListApplicationVersionsResponse, _>()?;
 
                     Ok(result)
@@ -2251,9 +2657,19 @@ impl ServerlessRepo for ServerlessRepoClient {
         &self,
         input: ListApplicationsRequest,
     ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("ListApplications");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2269,6 +2685,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListApplications")
                         .deserialize::<ListApplicationsResponse, _>()?;
 
                     Ok(result)
@@ -2298,6 +2715,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("PutApplicationPolicy");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2307,6 +2727,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutApplicationPolicy")
                         .deserialize::<PutApplicationPolicyResponse, _>()?;
 
                     Ok(result)
@@ -2332,6 +2753,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
+        request.add_resource_id("ApplicationId", &input.application_id.to_string());
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2341,6 +2765,7 @@ impl ServerlessRepo for ServerlessRepoClient {
             if response.status.as_u16() == 200 {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateApplication")
                         .deserialize::<UpdateApplicationResponse, _>()?;
 
                     Ok(result)
@@ -2356,3 +2781,479 @@ impl ServerlessRepo for ServerlessRepoClient {
         })
     }
 }
+
+/// A mock of the AWSServerlessApplicationRepository API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockServerlessRepo {
+    create_application: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationRequest,
+                ) -> ::std::result::Result<
+                    CreateApplicationResponse,
+                    RusotoError<CreateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_application_version: Option<
+        Box<
+            dyn Fn(
+                    CreateApplicationVersionRequest,
+                ) -> ::std::result::Result<
+                    CreateApplicationVersionResponse,
+                    RusotoError<CreateApplicationVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cloud_formation_change_set: Option<
+        Box<
+            dyn Fn(
+                    CreateCloudFormationChangeSetRequest,
+                ) -> ::std::result::Result<
+                    CreateCloudFormationChangeSetResponse,
+                    RusotoError<CreateCloudFormationChangeSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cloud_formation_template: Option<
+        Box<
+            dyn Fn(
+                    CreateCloudFormationTemplateRequest,
+                ) -> ::std::result::Result<
+                    CreateCloudFormationTemplateResponse,
+                    RusotoError<CreateCloudFormationTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_application: Option<
+        Box<
+            dyn Fn(
+                    DeleteApplicationRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteApplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_application: Option<
+        Box<
+            dyn Fn(
+                    GetApplicationRequest,
+                ) -> ::std::result::Result<
+                    GetApplicationResponse,
+                    RusotoError<GetApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_application_policy: Option<
+        Box<
+            dyn Fn(
+                    GetApplicationPolicyRequest,
+                ) -> ::std::result::Result<
+                    GetApplicationPolicyResponse,
+                    RusotoError<GetApplicationPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_cloud_formation_template: Option<
+        Box<
+            dyn Fn(
+                    GetCloudFormationTemplateRequest,
+                ) -> ::std::result::Result<
+                    GetCloudFormationTemplateResponse,
+                    RusotoError<GetCloudFormationTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_application_dependencies: Option<
+        Box<
+            dyn Fn(
+                    ListApplicationDependenciesRequest,
+                ) -> ::std::result::Result<
+                    ListApplicationDependenciesResponse,
+                    RusotoError<ListApplicationDependenciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_application_versions: Option<
+        Box<
+            dyn Fn(
+                    ListApplicationVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListApplicationVersionsResponse,
+                    RusotoError<ListApplicationVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_applications: Option<
+        Box<
+            dyn Fn(
+                    ListApplicationsRequest,
+                ) -> ::std::result::Result<
+                    ListApplicationsResponse,
+                    RusotoError<ListApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_application_policy: Option<
+        Box<
+            dyn Fn(
+                    PutApplicationPolicyRequest,
+                ) -> ::std::result::Result<
+                    PutApplicationPolicyResponse,
+                    RusotoError<PutApplicationPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_application: Option<
+        Box<
+            dyn Fn(
+                    UpdateApplicationRequest,
+                ) -> ::std::result::Result<
+                    UpdateApplicationResponse,
+                    RusotoError<UpdateApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockServerlessRepo {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockServerlessRepo {
+        Default::default()
+    }
+
+    /// Answers `create_application` with what `handler` returns for its input.
+    pub fn with_create_application<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                CreateApplicationRequest,
+            ) -> ::std::result::Result<
+                CreateApplicationResponse,
+                RusotoError<CreateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_application_version` with what `handler` returns for its input.
+    pub fn with_create_application_version<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                CreateApplicationVersionRequest,
+            ) -> ::std::result::Result<
+                CreateApplicationVersionResponse,
+                RusotoError<CreateApplicationVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_application_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cloud_formation_change_set` with what `handler` returns for its input.
+    pub fn with_create_cloud_formation_change_set<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                CreateCloudFormationChangeSetRequest,
+            ) -> ::std::result::Result<
+                CreateCloudFormationChangeSetResponse,
+                RusotoError<CreateCloudFormationChangeSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cloud_formation_change_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cloud_formation_template` with what `handler` returns for its input.
+    pub fn with_create_cloud_formation_template<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                CreateCloudFormationTemplateRequest,
+            ) -> ::std::result::Result<
+                CreateCloudFormationTemplateResponse,
+                RusotoError<CreateCloudFormationTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cloud_formation_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_application` with what `handler` returns for its input.
+    pub fn with_delete_application<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                DeleteApplicationRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_application` with what `handler` returns for its input.
+    pub fn with_get_application<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                GetApplicationRequest,
+            )
+                -> ::std::result::Result<GetApplicationResponse, RusotoError<GetApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_application_policy` with what `handler` returns for its input.
+    pub fn with_get_application_policy<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                GetApplicationPolicyRequest,
+            ) -> ::std::result::Result<
+                GetApplicationPolicyResponse,
+                RusotoError<GetApplicationPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_application_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_cloud_formation_template` with what `handler` returns for its input.
+    pub fn with_get_cloud_formation_template<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                GetCloudFormationTemplateRequest,
+            ) -> ::std::result::Result<
+                GetCloudFormationTemplateResponse,
+                RusotoError<GetCloudFormationTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_cloud_formation_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_application_dependencies` with what `handler` returns for its input.
+    pub fn with_list_application_dependencies<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                ListApplicationDependenciesRequest,
+            ) -> ::std::result::Result<
+                ListApplicationDependenciesResponse,
+                RusotoError<ListApplicationDependenciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_application_dependencies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_application_versions` with what `handler` returns for its input.
+    pub fn with_list_application_versions<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                ListApplicationVersionsRequest,
+            ) -> ::std::result::Result<
+                ListApplicationVersionsResponse,
+                RusotoError<ListApplicationVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_application_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_applications` with what `handler` returns for its input.
+    pub fn with_list_applications<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                ListApplicationsRequest,
+            ) -> ::std::result::Result<
+                ListApplicationsResponse,
+                RusotoError<ListApplicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_application_policy` with what `handler` returns for its input.
+    pub fn with_put_application_policy<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                PutApplicationPolicyRequest,
+            ) -> ::std::result::Result<
+                PutApplicationPolicyResponse,
+                RusotoError<PutApplicationPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_application_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_application` with what `handler` returns for its input.
+    pub fn with_update_application<F>(mut self, handler: F) -> MockServerlessRepo
+    where
+        F: Fn(
+                UpdateApplicationRequest,
+            ) -> ::std::result::Result<
+                UpdateApplicationResponse,
+                RusotoError<UpdateApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_application = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ServerlessRepo for MockServerlessRepo {
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        match self.create_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for create_application"),
+        }
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> RusotoFuture<CreateApplicationVersionResponse, CreateApplicationVersionError> {
+        match self.create_application_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for create_application_version"),
+        }
+    }
+    fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> RusotoFuture<CreateCloudFormationChangeSetResponse, CreateCloudFormationChangeSetError>
+    {
+        match self.create_cloud_formation_change_set {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServerlessRepo has no handler for create_cloud_formation_change_set")
+            }
+        }
+    }
+    fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> RusotoFuture<CreateCloudFormationTemplateResponse, CreateCloudFormationTemplateError> {
+        match self.create_cloud_formation_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for create_cloud_formation_template"),
+        }
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        match self.delete_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for delete_application"),
+        }
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> RusotoFuture<GetApplicationResponse, GetApplicationError> {
+        match self.get_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for get_application"),
+        }
+    }
+    fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> RusotoFuture<GetApplicationPolicyResponse, GetApplicationPolicyError> {
+        match self.get_application_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for get_application_policy"),
+        }
+    }
+    fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> RusotoFuture<GetCloudFormationTemplateResponse, GetCloudFormationTemplateError> {
+        match self.get_cloud_formation_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for get_cloud_formation_template"),
+        }
+    }
+    fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> RusotoFuture<ListApplicationDependenciesResponse, ListApplicationDependenciesError> {
+        match self.list_application_dependencies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for list_application_dependencies"),
+        }
+    }
+    fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> RusotoFuture<ListApplicationVersionsResponse, ListApplicationVersionsError> {
+        match self.list_application_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for list_application_versions"),
+        }
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        match self.list_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for list_applications"),
+        }
+    }
+    fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> RusotoFuture<PutApplicationPolicyResponse, PutApplicationPolicyError> {
+        match self.put_application_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for put_application_policy"),
+        }
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        match self.update_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerlessRepo has no handler for update_application"),
+        }
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AcceptPortfolioShareInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -38,12 +40,37 @@ pub struct AcceptPortfolioShareInput {
     pub portfolio_share_type: Option<String>,
 }
 
+impl Validate for AcceptPortfolioShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AcceptPortfolioShareOutput {}
 
 /// <p>The access level to use to filter results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AccessLevelFilter {
     /// <p><p>The access level.</p> <ul> <li> <p> <code>Account</code> - Filter results based on the account.</p> </li> <li> <p> <code>Role</code> - Filter results based on the federated role of the specified user.</p> </li> <li> <p> <code>User</code> - Filter results based on the specified user.</p> </li> </ul></p>
     #[serde(rename = "Key")]
@@ -56,6 +83,7 @@ pub struct AccessLevelFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateBudgetWithResourceInput {
     /// <p>The name of the budget you want to associate.</p>
     #[serde(rename = "BudgetName")]
@@ -65,11 +93,34 @@ pub struct AssociateBudgetWithResourceInput {
     pub resource_id: String,
 }
 
+impl Validate for AssociateBudgetWithResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateBudgetWithResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociatePrincipalWithPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -86,11 +137,42 @@ pub struct AssociatePrincipalWithPortfolioInput {
     pub principal_type: String,
 }
 
+impl Validate for AssociatePrincipalWithPortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "principal_arn"),
+            self.principal_arn.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociatePrincipalWithPortfolioOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateProductWithPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -108,11 +190,60 @@ pub struct AssociateProductWithPortfolioInput {
     pub source_portfolio_id: Option<String>,
 }
 
+impl Validate for AssociateProductWithPortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        if let Some(ref value) = self.source_portfolio_id {
+            errors.check_length(
+                &field_path(path, "source_portfolio_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "source_portfolio_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateProductWithPortfolioOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateServiceActionWithProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -129,11 +260,58 @@ pub struct AssociateServiceActionWithProvisioningArtifactInput {
     pub service_action_id: String,
 }
 
+impl Validate for AssociateServiceActionWithProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "service_action_id"),
+            self.service_action_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_action_id"),
+            &self.service_action_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateServiceActionWithProvisioningArtifactOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateTagOptionWithResourceInput {
     /// <p>The resource identifier.</p>
     #[serde(rename = "ResourceId")]
@@ -143,11 +321,23 @@ pub struct AssociateTagOptionWithResourceInput {
     pub tag_option_id: String,
 }
 
+impl Validate for AssociateTagOptionWithResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tag_option_id"),
+            self.tag_option_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateTagOptionWithResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchAssociateServiceActionWithProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -158,8 +348,31 @@ pub struct BatchAssociateServiceActionWithProvisioningArtifactInput {
     pub service_action_associations: Vec<ServiceActionAssociation>,
 }
 
+impl Validate for BatchAssociateServiceActionWithProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_action_associations"),
+            self.service_action_associations.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.service_action_associations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "service_action_associations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchAssociateServiceActionWithProvisioningArtifactOutput {
     /// <p>An object that contains a list of errors, along with information to help you identify the self-service action.</p>
     #[serde(rename = "FailedServiceActionAssociations")]
@@ -168,6 +381,7 @@ pub struct BatchAssociateServiceActionWithProvisioningArtifactOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchDisassociateServiceActionFromProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -178,8 +392,31 @@ pub struct BatchDisassociateServiceActionFromProvisioningArtifactInput {
     pub service_action_associations: Vec<ServiceActionAssociation>,
 }
 
+impl Validate for BatchDisassociateServiceActionFromProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_action_associations"),
+            self.service_action_associations.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.service_action_associations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "service_action_associations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchDisassociateServiceActionFromProvisioningArtifactOutput {
     /// <p>An object that contains a list of errors, along with information to help you identify the self-service action.</p>
     #[serde(rename = "FailedServiceActionAssociations")]
@@ -189,7 +426,7 @@ pub struct BatchDisassociateServiceActionFromProvisioningArtifactOutput {
 
 /// <p>Information about a budget.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BudgetDetail {
     /// <p>Name of the associated budget.</p>
     #[serde(rename = "BudgetName")]
@@ -199,7 +436,7 @@ pub struct BudgetDetail {
 
 /// <p>Information about a CloudWatch dashboard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CloudWatchDashboard {
     /// <p>The name of the CloudWatch dashboard.</p>
     #[serde(rename = "Name")]
@@ -209,7 +446,7 @@ pub struct CloudWatchDashboard {
 
 /// <p>Information about a constraint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConstraintDetail {
     /// <p>The identifier of the constraint.</p>
     #[serde(rename = "ConstraintId")]
@@ -231,7 +468,7 @@ pub struct ConstraintDetail {
 
 /// <p>Summary information about a constraint.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ConstraintSummary {
     /// <p>The description of the constraint.</p>
     #[serde(rename = "Description")]
@@ -244,6 +481,7 @@ pub struct ConstraintSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -274,8 +512,60 @@ pub struct CopyProductInput {
     pub target_product_name: Option<String>,
 }
 
+impl Validate for CopyProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "source_product_arn"),
+            self.source_product_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(&field_path(path, "source_product_arn"), &self.source_product_arn, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        if let Some(ref value) = self.target_product_id {
+            errors.check_length(
+                &field_path(path, "target_product_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "target_product_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+        if let Some(ref value) = self.target_product_name {
+            errors.check_length(
+                &field_path(path, "target_product_name"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CopyProductOutput {
     /// <p>The token to use to track the progress of the operation.</p>
     #[serde(rename = "CopyProductToken")]
@@ -284,6 +574,7 @@ pub struct CopyProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConstraintInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -310,8 +601,68 @@ pub struct CreateConstraintInput {
     pub type_: String,
 }
 
+impl Validate for CreateConstraintInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "type_"),
+            self.type_.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateConstraintOutput {
     /// <p>Information about the constraint.</p>
     #[serde(rename = "ConstraintDetail")]
@@ -328,6 +679,7 @@ pub struct CreateConstraintOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -352,8 +704,59 @@ pub struct CreatePortfolioInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreatePortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "display_name"),
+            self.display_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provider_name"),
+            self.provider_name.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePortfolioOutput {
     /// <p>Information about the portfolio.</p>
     #[serde(rename = "PortfolioDetail")]
@@ -366,6 +769,7 @@ pub struct CreatePortfolioOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePortfolioShareInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -384,8 +788,38 @@ pub struct CreatePortfolioShareInput {
     pub portfolio_id: String,
 }
 
+impl Validate for CreatePortfolioShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "^[0-9]{12}$");
+        }
+        if let Some(ref value) = self.organization_node {
+            value.validate_at(&field_path(path, "organization_node"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePortfolioShareOutput {
     /// <p>The portfolio share unique identifier. This will only be returned if portfolio is shared to an organization node.</p>
     #[serde(rename = "PortfolioShareToken")]
@@ -394,6 +828,7 @@ pub struct CreatePortfolioShareOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -440,8 +875,101 @@ pub struct CreateProductInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        if let Some(ref value) = self.distributor {
+            errors.check_length(
+                &field_path(path, "distributor"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(8191),
+        );
+        errors.check_length(
+            &field_path(path, "owner"),
+            self.owner.chars().count(),
+            None,
+            Some(8191),
+        );
+        errors.check_length(
+            &field_path(path, "product_type"),
+            self.product_type.chars().count(),
+            None,
+            Some(8191),
+        );
+        self.provisioning_artifact_parameters.validate_at(
+            &field_path(path, "provisioning_artifact_parameters"),
+            errors,
+        );
+        if let Some(ref value) = self.support_description {
+            errors.check_length(
+                &field_path(path, "support_description"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        if let Some(ref value) = self.support_email {
+            errors.check_length(
+                &field_path(path, "support_email"),
+                value.chars().count(),
+                None,
+                Some(254),
+            );
+        }
+        if let Some(ref value) = self.support_url {
+            errors.check_length(
+                &field_path(path, "support_url"),
+                value.chars().count(),
+                None,
+                Some(2083),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProductOutput {
     /// <p>Information about the product view.</p>
     #[serde(rename = "ProductViewDetail")]
@@ -458,6 +986,7 @@ pub struct CreateProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProvisionedProductPlanInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -499,8 +1028,100 @@ pub struct CreateProvisionedProductPlanInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateProvisionedProductPlanInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        if let Some(ref value) = self.notification_arns {
+            errors.check_length(
+                &field_path(path, "notification_arns"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "notification_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1224));
+                errors.check_pattern(&item_path, &item, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+            }
+        }
+        if let Some(ref value) = self.path_id {
+            errors.check_length(
+                &field_path(path, "path_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "path_id"), &value, "^[a-zA-Z0-9_\\-]*");
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioned_product_name"),
+            self.provisioned_product_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioned_product_name"),
+            &self.provisioned_product_name,
+            "[a-zA-Z0-9][a-zA-Z0-9._-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        if let Some(ref value) = self.provisioning_parameters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "provisioning_parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProvisionedProductPlanOutput {
     /// <p>The plan identifier.</p>
     #[serde(rename = "PlanId")]
@@ -525,6 +1146,7 @@ pub struct CreateProvisionedProductPlanOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -541,8 +1163,45 @@ pub struct CreateProvisioningArtifactInput {
     pub product_id: String,
 }
 
+impl Validate for CreateProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        self.parameters
+            .validate_at(&field_path(path, "parameters"), errors);
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProvisioningArtifactOutput {
     /// <p>The URL of the CloudFormation template in Amazon S3, in JSON format.</p>
     #[serde(rename = "Info")]
@@ -559,6 +1218,7 @@ pub struct CreateProvisioningArtifactOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -582,8 +1242,57 @@ pub struct CreateServiceActionInput {
     pub name: String,
 }
 
+impl Validate for CreateServiceActionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "definition"),
+            self.definition.len(),
+            Some(1),
+            Some(100),
+        );
+        for (key, item) in self.definition.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "definition"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[a-zA-Z0-9_\\-.]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateServiceActionOutput {
     /// <p>An object containing information about the self-service action.</p>
     #[serde(rename = "ServiceActionDetail")]
@@ -592,6 +1301,7 @@ pub struct CreateServiceActionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagOptionInput {
     /// <p>The TagOption key.</p>
     #[serde(rename = "Key")]
@@ -601,8 +1311,35 @@ pub struct CreateTagOptionInput {
     pub value: String,
 }
 
+impl Validate for CreateTagOptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTagOptionOutput {
     /// <p>Information about the TagOption.</p>
     #[serde(rename = "TagOptionDetail")]
@@ -611,6 +1348,7 @@ pub struct CreateTagOptionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConstraintInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -621,11 +1359,32 @@ pub struct DeleteConstraintInput {
     pub id: String,
 }
 
+impl Validate for DeleteConstraintInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteConstraintOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -636,11 +1395,32 @@ pub struct DeletePortfolioInput {
     pub id: String,
 }
 
+impl Validate for DeletePortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePortfolioOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePortfolioShareInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -659,8 +1439,38 @@ pub struct DeletePortfolioShareInput {
     pub portfolio_id: String,
 }
 
+impl Validate for DeletePortfolioShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.account_id {
+            errors.check_pattern(&field_path(path, "account_id"), &value, "^[0-9]{12}$");
+        }
+        if let Some(ref value) = self.organization_node {
+            value.validate_at(&field_path(path, "organization_node"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePortfolioShareOutput {
     /// <p>The portfolio share unique identifier. This will only be returned if delete is made to an organization node.</p>
     #[serde(rename = "PortfolioShareToken")]
@@ -669,6 +1479,7 @@ pub struct DeletePortfolioShareOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -679,11 +1490,32 @@ pub struct DeleteProductInput {
     pub id: String,
 }
 
+impl Validate for DeleteProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProductOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProvisionedProductPlanInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -698,11 +1530,36 @@ pub struct DeleteProvisionedProductPlanInput {
     pub plan_id: String,
 }
 
+impl Validate for DeleteProvisionedProductPlanInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "plan_id"),
+            self.plan_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "plan_id"),
+            &self.plan_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProvisionedProductPlanOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -716,11 +1573,47 @@ pub struct DeleteProvisioningArtifactInput {
     pub provisioning_artifact_id: String,
 }
 
+impl Validate for DeleteProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProvisioningArtifactOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -731,22 +1624,55 @@ pub struct DeleteServiceActionInput {
     pub id: String,
 }
 
+impl Validate for DeleteServiceActionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteServiceActionOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagOptionInput {
     /// <p>The TagOption identifier.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for DeleteTagOptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTagOptionOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConstraintInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -757,8 +1683,28 @@ pub struct DescribeConstraintInput {
     pub id: String,
 }
 
+impl Validate for DescribeConstraintInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeConstraintOutput {
     /// <p>Information about the constraint.</p>
     #[serde(rename = "ConstraintDetail")]
@@ -775,6 +1721,7 @@ pub struct DescribeConstraintOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCopyProductStatusInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -785,8 +1732,32 @@ pub struct DescribeCopyProductStatusInput {
     pub copy_product_token: String,
 }
 
+impl Validate for DescribeCopyProductStatusInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "copy_product_token"),
+            self.copy_product_token.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "copy_product_token"),
+            &self.copy_product_token,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCopyProductStatusOutput {
     /// <p>The status of the copy product operation.</p>
     #[serde(rename = "CopyProductStatus")]
@@ -803,6 +1774,7 @@ pub struct DescribeCopyProductStatusOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -813,8 +1785,28 @@ pub struct DescribePortfolioInput {
     pub id: String,
 }
 
+impl Validate for DescribePortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePortfolioOutput {
     /// <p>Information about the associated budgets.</p>
     #[serde(rename = "Budgets")]
@@ -835,14 +1827,31 @@ pub struct DescribePortfolioOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePortfolioShareStatusInput {
     /// <p>The token for the portfolio share operation. This token is returned either by CreatePortfolioShare or by DeletePortfolioShare.</p>
     #[serde(rename = "PortfolioShareToken")]
     pub portfolio_share_token: String,
 }
 
+impl Validate for DescribePortfolioShareStatusInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "portfolio_share_token"),
+            self.portfolio_share_token.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_share_token"),
+            &self.portfolio_share_token,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePortfolioShareStatusOutput {
     /// <p>Organization node identifier. It can be either account id, organizational unit id or organization id.</p>
     #[serde(rename = "OrganizationNodeValue")]
@@ -867,6 +1876,7 @@ pub struct DescribePortfolioShareStatusOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProductAsAdminInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -877,8 +1887,28 @@ pub struct DescribeProductAsAdminInput {
     pub id: String,
 }
 
+impl Validate for DescribeProductAsAdminInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProductAsAdminOutput {
     /// <p>Information about the associated budgets.</p>
     #[serde(rename = "Budgets")]
@@ -903,6 +1933,7 @@ pub struct DescribeProductAsAdminOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -913,8 +1944,28 @@ pub struct DescribeProductInput {
     pub id: String,
 }
 
+impl Validate for DescribeProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProductOutput {
     /// <p>Information about the associated budgets.</p>
     #[serde(rename = "Budgets")]
@@ -931,6 +1982,7 @@ pub struct DescribeProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProductViewInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -941,8 +1993,28 @@ pub struct DescribeProductViewInput {
     pub id: String,
 }
 
+impl Validate for DescribeProductViewInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProductViewOutput {
     /// <p>Summary information about the product.</p>
     #[serde(rename = "ProductViewSummary")]
@@ -955,6 +2027,7 @@ pub struct DescribeProductViewOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProvisionedProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -965,8 +2038,28 @@ pub struct DescribeProvisionedProductInput {
     pub id: String,
 }
 
+impl Validate for DescribeProvisionedProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProvisionedProductOutput {
     /// <p>Any CloudWatch dashboards that were created when provisioning the product.</p>
     #[serde(rename = "CloudWatchDashboards")]
@@ -979,6 +2072,7 @@ pub struct DescribeProvisionedProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProvisionedProductPlanInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -997,8 +2091,48 @@ pub struct DescribeProvisionedProductPlanInput {
     pub plan_id: String,
 }
 
+impl Validate for DescribeProvisionedProductPlanInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "plan_id"),
+            self.plan_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "plan_id"),
+            &self.plan_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProvisionedProductPlanOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1015,6 +2149,7 @@ pub struct DescribeProvisionedProductPlanOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1032,13 +2167,48 @@ pub struct DescribeProvisioningArtifactInput {
     pub verbose: Option<bool>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeProvisioningArtifactOutput {
-    /// <p>The URL of the CloudFormation template in Amazon S3.</p>
-    #[serde(rename = "Info")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub info: Option<::std::collections::HashMap<String, String>>,
+impl Validate for DescribeProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DescribeProvisioningArtifactOutput {
+    /// <p>The URL of the CloudFormation template in Amazon S3.</p>
+    #[serde(rename = "Info")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<::std::collections::HashMap<String, String>>,
     /// <p>Information about the provisioning artifact.</p>
     #[serde(rename = "ProvisioningArtifactDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1050,6 +2220,7 @@ pub struct DescribeProvisioningArtifactOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProvisioningParametersInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1067,8 +2238,52 @@ pub struct DescribeProvisioningParametersInput {
     pub provisioning_artifact_id: String,
 }
 
+impl Validate for DescribeProvisioningParametersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.path_id {
+            errors.check_length(
+                &field_path(path, "path_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "path_id"), &value, "^[a-zA-Z0-9_\\-]*");
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProvisioningParametersOutput {
     /// <p>Information about the constraints used to provision the product.</p>
     #[serde(rename = "ConstraintSummaries")]
@@ -1093,6 +2308,7 @@ pub struct DescribeProvisioningParametersOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRecordInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1111,8 +2327,44 @@ pub struct DescribeRecordInput {
     pub page_token: Option<String>,
 }
 
+impl Validate for DescribeRecordInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRecordOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1129,6 +2381,7 @@ pub struct DescribeRecordOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServiceActionExecutionParametersInput {
     #[serde(rename = "AcceptLanguage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1139,8 +2392,43 @@ pub struct DescribeServiceActionExecutionParametersInput {
     pub service_action_id: String,
 }
 
+impl Validate for DescribeServiceActionExecutionParametersInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "provisioned_product_id"),
+            self.provisioned_product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioned_product_id"),
+            &self.provisioned_product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "service_action_id"),
+            self.service_action_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_action_id"),
+            &self.service_action_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServiceActionExecutionParametersOutput {
     #[serde(rename = "ServiceActionParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1148,6 +2436,7 @@ pub struct DescribeServiceActionExecutionParametersOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1158,8 +2447,28 @@ pub struct DescribeServiceActionInput {
     pub id: String,
 }
 
+impl Validate for DescribeServiceActionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServiceActionOutput {
     /// <p>Detailed information about the self-service action.</p>
     #[serde(rename = "ServiceActionDetail")]
@@ -1168,14 +2477,26 @@ pub struct DescribeServiceActionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagOptionInput {
     /// <p>The TagOption identifier.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for DescribeTagOptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTagOptionOutput {
     /// <p>Information about the TagOption.</p>
     #[serde(rename = "TagOptionDetail")]
@@ -1184,13 +2505,15 @@ pub struct DescribeTagOptionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableAWSOrganizationsAccessInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableAWSOrganizationsAccessOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateBudgetFromResourceInput {
     /// <p>The name of the budget you want to disassociate.</p>
     #[serde(rename = "BudgetName")]
@@ -1200,11 +2523,34 @@ pub struct DisassociateBudgetFromResourceInput {
     pub resource_id: String,
 }
 
+impl Validate for DisassociateBudgetFromResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "budget_name"),
+            self.budget_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateBudgetFromResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociatePrincipalFromPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1218,11 +2564,42 @@ pub struct DisassociatePrincipalFromPortfolioInput {
     pub principal_arn: String,
 }
 
+impl Validate for DisassociatePrincipalFromPortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "principal_arn"),
+            self.principal_arn.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociatePrincipalFromPortfolioOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateProductFromPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1236,11 +2613,47 @@ pub struct DisassociateProductFromPortfolioInput {
     pub product_id: String,
 }
 
+impl Validate for DisassociateProductFromPortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateProductFromPortfolioOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateServiceActionFromProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1257,11 +2670,58 @@ pub struct DisassociateServiceActionFromProvisioningArtifactInput {
     pub service_action_id: String,
 }
 
+impl Validate for DisassociateServiceActionFromProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "service_action_id"),
+            self.service_action_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_action_id"),
+            &self.service_action_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateServiceActionFromProvisioningArtifactOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateTagOptionFromResourceInput {
     /// <p>The resource identifier.</p>
     #[serde(rename = "ResourceId")]
@@ -1271,18 +2731,31 @@ pub struct DisassociateTagOptionFromResourceInput {
     pub tag_option_id: String,
 }
 
+impl Validate for DisassociateTagOptionFromResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tag_option_id"),
+            self.tag_option_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateTagOptionFromResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EnableAWSOrganizationsAccessInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EnableAWSOrganizationsAccessOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteProvisionedProductPlanInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1296,8 +2769,43 @@ pub struct ExecuteProvisionedProductPlanInput {
     pub plan_id: String,
 }
 
+impl Validate for ExecuteProvisionedProductPlanInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "plan_id"),
+            self.plan_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "plan_id"),
+            &self.plan_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecuteProvisionedProductPlanOutput {
     /// <p>Information about the result of provisioning the product.</p>
     #[serde(rename = "RecordDetail")]
@@ -1306,6 +2814,7 @@ pub struct ExecuteProvisionedProductPlanOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecuteProvisionedProductServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1325,8 +2834,70 @@ pub struct ExecuteProvisionedProductServiceActionInput {
     pub service_action_id: String,
 }
 
+impl Validate for ExecuteProvisionedProductServiceActionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "execute_token"),
+            self.execute_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "execute_token"),
+            &self.execute_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        if let Some(ref value) = self.parameters {
+            errors.check_length(
+                &field_path(path, "parameters"),
+                value.len(),
+                Some(1),
+                Some(200),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.len(), None, Some(25));
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), None, Some(512));
+                }
+            }
+        }
+        errors.check_length(
+            &field_path(path, "provisioned_product_id"),
+            self.provisioned_product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioned_product_id"),
+            &self.provisioned_product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "service_action_id"),
+            self.service_action_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_action_id"),
+            &self.service_action_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecuteProvisionedProductServiceActionOutput {
     /// <p>An object containing detailed information about the result of provisioning the product.</p>
     #[serde(rename = "RecordDetail")]
@@ -1335,7 +2906,7 @@ pub struct ExecuteProvisionedProductServiceActionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionParameter {
     #[serde(rename = "DefaultValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1350,7 +2921,7 @@ pub struct ExecutionParameter {
 
 /// <p>An object containing information about the error, along with identifying information about the self-service action and its associations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedServiceActionAssociation {
     /// <p>The error code. Valid values are listed below.</p>
     #[serde(rename = "ErrorCode")]
@@ -1375,10 +2946,11 @@ pub struct FailedServiceActionAssociation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAWSOrganizationsAccessStatusInput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAWSOrganizationsAccessStatusOutput {
     /// <p>The status of the portfolio share feature.</p>
     #[serde(rename = "AccessStatus")]
@@ -1388,7 +2960,7 @@ pub struct GetAWSOrganizationsAccessStatusOutput {
 
 /// <p>Summary information about a product path for a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LaunchPathSummary {
     /// <p>The constraints on the portfolio-product relationship.</p>
     #[serde(rename = "ConstraintSummaries")]
@@ -1409,6 +2981,7 @@ pub struct LaunchPathSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAcceptedPortfolioSharesInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1428,8 +3001,37 @@ pub struct ListAcceptedPortfolioSharesInput {
     pub portfolio_share_type: Option<String>,
 }
 
+impl Validate for ListAcceptedPortfolioSharesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAcceptedPortfolioSharesOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1442,6 +3044,7 @@ pub struct ListAcceptedPortfolioSharesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListBudgetsForResourceInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1460,8 +3063,48 @@ pub struct ListBudgetsForResourceInput {
     pub resource_id: String,
 }
 
+impl Validate for ListBudgetsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListBudgetsForResourceOutput {
     /// <p>Information about the associated budgets.</p>
     #[serde(rename = "Budgets")]
@@ -1474,6 +3117,7 @@ pub struct ListBudgetsForResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConstraintsForPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1496,8 +3140,57 @@ pub struct ListConstraintsForPortfolioInput {
     pub product_id: Option<String>,
 }
 
+impl Validate for ListConstraintsForPortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        if let Some(ref value) = self.product_id {
+            errors.check_length(
+                &field_path(path, "product_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "product_id"), &value, "^[a-zA-Z0-9_\\-]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListConstraintsForPortfolioOutput {
     /// <p>Information about the constraints.</p>
     #[serde(rename = "ConstraintDetails")]
@@ -1510,6 +3203,7 @@ pub struct ListConstraintsForPortfolioOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLaunchPathsInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1528,8 +3222,48 @@ pub struct ListLaunchPathsInput {
     pub product_id: String,
 }
 
+impl Validate for ListLaunchPathsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLaunchPathsOutput {
     /// <p>Information about the launch path.</p>
     #[serde(rename = "LaunchPathSummaries")]
@@ -1542,6 +3276,7 @@ pub struct ListLaunchPathsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOrganizationPortfolioAccessInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1563,8 +3298,48 @@ pub struct ListOrganizationPortfolioAccessInput {
     pub portfolio_id: String,
 }
 
+impl Validate for ListOrganizationPortfolioAccessInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOrganizationPortfolioAccessOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1577,6 +3352,7 @@ pub struct ListOrganizationPortfolioAccessOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPortfolioAccessInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1587,8 +3363,32 @@ pub struct ListPortfolioAccessInput {
     pub portfolio_id: String,
 }
 
+impl Validate for ListPortfolioAccessInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPortfolioAccessOutput {
     /// <p>Information about the AWS accounts with access to the portfolio.</p>
     #[serde(rename = "AccountIds")]
@@ -1601,6 +3401,7 @@ pub struct ListPortfolioAccessOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPortfoliosForProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1619,8 +3420,48 @@ pub struct ListPortfoliosForProductInput {
     pub product_id: String,
 }
 
+impl Validate for ListPortfoliosForProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPortfoliosForProductOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1633,6 +3474,7 @@ pub struct ListPortfoliosForProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPortfoliosInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1648,8 +3490,37 @@ pub struct ListPortfoliosInput {
     pub page_token: Option<String>,
 }
 
+impl Validate for ListPortfoliosInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPortfoliosOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1662,6 +3533,7 @@ pub struct ListPortfoliosOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPrincipalsForPortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1680,8 +3552,48 @@ pub struct ListPrincipalsForPortfolioInput {
     pub portfolio_id: String,
 }
 
+impl Validate for ListPrincipalsForPortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListPrincipalsForPortfolioOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1694,6 +3606,7 @@ pub struct ListPrincipalsForPortfolioOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProvisionedProductPlansInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1717,8 +3630,50 @@ pub struct ListProvisionedProductPlansInput {
     pub provision_product_id: Option<String>,
 }
 
+impl Validate for ListProvisionedProductPlansInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        if let Some(ref value) = self.provision_product_id {
+            errors.check_length(
+                &field_path(path, "provision_product_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "provision_product_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProvisionedProductPlansOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1731,6 +3686,7 @@ pub struct ListProvisionedProductPlansOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProvisioningArtifactsForServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1749,8 +3705,48 @@ pub struct ListProvisioningArtifactsForServiceActionInput {
     pub service_action_id: String,
 }
 
+impl Validate for ListProvisioningArtifactsForServiceActionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_action_id"),
+            self.service_action_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_action_id"),
+            &self.service_action_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProvisioningArtifactsForServiceActionOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1763,6 +3759,7 @@ pub struct ListProvisioningArtifactsForServiceActionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProvisioningArtifactsInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1773,8 +3770,32 @@ pub struct ListProvisioningArtifactsInput {
     pub product_id: String,
 }
 
+impl Validate for ListProvisioningArtifactsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProvisioningArtifactsOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1787,6 +3808,7 @@ pub struct ListProvisioningArtifactsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRecordHistoryInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1810,8 +3832,37 @@ pub struct ListRecordHistoryInput {
     pub search_filter: Option<ListRecordHistorySearchFilter>,
 }
 
+impl Validate for ListRecordHistoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRecordHistoryOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1825,6 +3876,7 @@ pub struct ListRecordHistoryOutput {
 
 /// <p>The search filter to use when listing history records.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRecordHistorySearchFilter {
     /// <p><p>The filter key.</p> <ul> <li> <p> <code>product</code> - Filter results based on the specified product identifier.</p> </li> <li> <p> <code>provisionedproduct</code> - Filter results based on the provisioned product identifier.</p> </li> </ul></p>
     #[serde(rename = "Key")]
@@ -1837,6 +3889,7 @@ pub struct ListRecordHistorySearchFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourcesForTagOptionInput {
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "PageSize")]
@@ -1855,8 +3908,35 @@ pub struct ListResourcesForTagOptionInput {
     pub tag_option_id: String,
 }
 
+impl Validate for ListResourcesForTagOptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "tag_option_id"),
+            self.tag_option_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourcesForTagOptionOutput {
     /// <p>The page token for the next set of results. To retrieve the first set of results, use null.</p>
     #[serde(rename = "PageToken")]
@@ -1869,6 +3949,7 @@ pub struct ListResourcesForTagOptionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListServiceActionsForProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1890,8 +3971,59 @@ pub struct ListServiceActionsForProvisioningArtifactInput {
     pub provisioning_artifact_id: String,
 }
 
+impl Validate for ListServiceActionsForProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListServiceActionsForProvisioningArtifactOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1904,6 +4036,7 @@ pub struct ListServiceActionsForProvisioningArtifactOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListServiceActionsInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1919,8 +4052,37 @@ pub struct ListServiceActionsInput {
     pub page_token: Option<String>,
 }
 
+impl Validate for ListServiceActionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListServiceActionsOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1933,6 +4095,7 @@ pub struct ListServiceActionsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStackInstancesForProvisionedProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -1951,8 +4114,48 @@ pub struct ListStackInstancesForProvisionedProductInput {
     pub provisioned_product_id: String,
 }
 
+impl Validate for ListStackInstancesForProvisionedProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "provisioned_product_id"),
+            self.provisioned_product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioned_product_id"),
+            &self.provisioned_product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStackInstancesForProvisionedProductOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -1966,6 +4169,7 @@ pub struct ListStackInstancesForProvisionedProductOutput {
 
 /// <p>Filters to use when listing TagOptions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagOptionsFilters {
     /// <p>The active state.</p>
     #[serde(rename = "Active")]
@@ -1981,7 +4185,39 @@ pub struct ListTagOptionsFilters {
     pub value: Option<String>,
 }
 
+impl Validate for ListTagOptionsFilters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "key"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagOptionsInput {
     /// <p>The search filters. If no search filters are specified, the output includes all TagOptions.</p>
     #[serde(rename = "Filters")]
@@ -1997,8 +4233,32 @@ pub struct ListTagOptionsInput {
     pub page_token: Option<String>,
 }
 
+impl Validate for ListTagOptionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            value.validate_at(&field_path(path, "filters"), errors);
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagOptionsOutput {
     /// <p>The page token for the next set of results. To retrieve the first set of results, use null.</p>
     #[serde(rename = "PageToken")]
@@ -2023,9 +4283,17 @@ pub struct OrganizationNode {
     pub value: Option<String>,
 }
 
+impl Validate for OrganizationNode {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.value {
+            errors.check_pattern(&field_path(path, "value"), &value, "(^[0-9]{12}$)|(^arn:aws:organizations::\\d{12}:organization\\/o-[a-z0-9]{10,32})|(^o-[a-z0-9]{10,32}$)|(^arn:aws:organizations::\\d{12}:ou\\/o-[a-z0-9]{10,32}\\/ou-[0-9a-z]{4,32}-[0-9a-z]{8,32}$)|(^ou-[0-9a-z]{4,32}-[a-z0-9]{8,32}$)");
+        }
+    }
+}
+
 /// <p>The constraints that the administrator has put on the parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterConstraints {
     /// <p>The values that the administrator has allowed for the parameter.</p>
     #[serde(rename = "AllowedValues")]
@@ -2035,7 +4303,7 @@ pub struct ParameterConstraints {
 
 /// <p>Information about a portfolio.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PortfolioDetail {
     /// <p>The ARN assigned to the portfolio.</p>
     #[serde(rename = "ARN")]
@@ -2065,7 +4333,7 @@ pub struct PortfolioDetail {
 
 /// <p>Information about a principal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Principal {
     /// <p>The ARN of the principal (IAM user, role, or group).</p>
     #[serde(rename = "PrincipalARN")]
@@ -2079,7 +4347,7 @@ pub struct Principal {
 
 /// <p>A single product view aggregation value/count pair, containing metadata about each product to which the calling user has access.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProductViewAggregationValue {
     /// <p>An approximate count of the products that match the value.</p>
     #[serde(rename = "ApproximateCount")]
@@ -2093,7 +4361,7 @@ pub struct ProductViewAggregationValue {
 
 /// <p>Information about a product view.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProductViewDetail {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
@@ -2115,7 +4383,7 @@ pub struct ProductViewDetail {
 
 /// <p>Summary information about a product view.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProductViewSummary {
     /// <p>The distributor of the product. Contact the product administrator for the significance of this value.</p>
     #[serde(rename = "Distributor")]
@@ -2164,6 +4432,7 @@ pub struct ProductViewSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProvisionProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -2203,22 +4472,117 @@ pub struct ProvisionProductInput {
     pub tags: Option<Vec<Tag>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ProvisionProductOutput {
-    /// <p>Information about the result of provisioning the product.</p>
-    #[serde(rename = "RecordDetail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_detail: Option<RecordDetail>,
-}
-
-/// <p>Information about a provisioned product.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ProvisionedProductAttribute {
-    /// <p>The ARN of the provisioned product.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl Validate for ProvisionProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.notification_arns {
+            errors.check_length(
+                &field_path(path, "notification_arns"),
+                value.len(),
+                None,
+                Some(5),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "notification_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1224));
+                errors.check_pattern(&item_path, &item, "arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+            }
+        }
+        if let Some(ref value) = self.path_id {
+            errors.check_length(
+                &field_path(path, "path_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "path_id"), &value, "^[a-zA-Z0-9_\\-]*");
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provision_token"),
+            self.provision_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "provision_token"),
+            &self.provision_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioned_product_name"),
+            self.provisioned_product_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioned_product_name"),
+            &self.provisioned_product_name,
+            "[a-zA-Z0-9][a-zA-Z0-9._-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        if let Some(ref value) = self.provisioning_parameters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "provisioning_parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.provisioning_preferences {
+            value.validate_at(&field_path(path, "provisioning_preferences"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProvisionProductOutput {
+    /// <p>Information about the result of provisioning the product.</p>
+    #[serde(rename = "RecordDetail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_detail: Option<RecordDetail>,
+}
+
+/// <p>Information about a provisioned product.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct ProvisionedProductAttribute {
+    /// <p>The ARN of the provisioned product.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
@@ -2280,7 +4644,7 @@ pub struct ProvisionedProductAttribute {
 
 /// <p>Information about a provisioned product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisionedProductDetail {
     /// <p>The ARN of the provisioned product.</p>
     #[serde(rename = "Arn")]
@@ -2330,7 +4694,7 @@ pub struct ProvisionedProductDetail {
 
 /// <p>Information about a plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisionedProductPlanDetails {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
@@ -2396,7 +4760,7 @@ pub struct ProvisionedProductPlanDetails {
 
 /// <p>Summary information about a plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisionedProductPlanSummary {
     /// <p>The plan identifier.</p>
     #[serde(rename = "PlanId")]
@@ -2426,7 +4790,7 @@ pub struct ProvisionedProductPlanSummary {
 
 /// <p>Information about a provisioning artifact. A provisioning artifact is also known as a product version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifact {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
@@ -2452,7 +4816,7 @@ pub struct ProvisioningArtifact {
 
 /// <p>Information about a provisioning artifact (also known as a version) for a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifactDetail {
     /// <p>Indicates whether the product version is active.</p>
     #[serde(rename = "Active")]
@@ -2486,7 +4850,7 @@ pub struct ProvisioningArtifactDetail {
 
 /// <p>Information about a parameter used to provision a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifactParameter {
     /// <p>The default value.</p>
     #[serde(rename = "DefaultValue")]
@@ -2516,7 +4880,7 @@ pub struct ProvisioningArtifactParameter {
 
 /// <p>The user-defined preferences that will be applied during product provisioning, unless overridden by <code>ProvisioningPreferences</code> or <code>UpdateProvisioningPreferences</code>.</p> <p>For more information on maximum concurrent accounts and failure tolerance, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-concepts.html#stackset-ops-options">Stack set operation options</a> in the <i>AWS CloudFormation User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifactPreferences {
     /// <p>One or more AWS accounts where stack instances are deployed from the stack set. These accounts can be scoped in <code>ProvisioningPreferences$StackSetAccounts</code> and <code>UpdateProvisioningPreferences$StackSetAccounts</code>.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p>
     #[serde(rename = "StackSetAccounts")]
@@ -2530,6 +4894,7 @@ pub struct ProvisioningArtifactPreferences {
 
 /// <p>Information about a provisioning artifact (also known as a version) for a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProvisioningArtifactProperties {
     /// <p>The description of the provisioning artifact, including how it differs from the previous provisioning artifact.</p>
     #[serde(rename = "Description")]
@@ -2552,9 +4917,36 @@ pub struct ProvisioningArtifactProperties {
     pub type_: Option<String>,
 }
 
+impl Validate for ProvisioningArtifactProperties {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "info"),
+            self.info.len(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+    }
+}
+
 /// <p>Summary information about a provisioning artifact (also known as a version) for a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifactSummary {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
@@ -2580,7 +4972,7 @@ pub struct ProvisioningArtifactSummary {
 
 /// <p>An object that contains summary information about a product view and a provisioning artifact.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProvisioningArtifactView {
     /// <p>Summary information about a product view.</p>
     #[serde(rename = "ProductViewSummary")]
@@ -2594,6 +4986,7 @@ pub struct ProvisioningArtifactView {
 
 /// <p>Information about a parameter used to provision a product.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProvisioningParameter {
     /// <p>The parameter key.</p>
     #[serde(rename = "Key")]
@@ -2605,8 +4998,30 @@ pub struct ProvisioningParameter {
     pub value: Option<String>,
 }
 
+impl Validate for ProvisioningParameter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 /// <p>The user-defined preferences that will be applied when updating a provisioned product. Not all preferences are applicable to all provisioned product types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ProvisioningPreferences {
     /// <p>One or more AWS accounts that will have access to the provisioned product.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <p>The AWS accounts specified should be within the list of accounts in the <code>STACKSET</code> constraint. To get the list of accounts in the <code>STACKSET</code> constraint, use the <code>DescribeProvisioningParameters</code> operation.</p> <p>If no values are specified, the default value is all accounts from the <code>STACKSET</code> constraint.</p>
     #[serde(rename = "StackSetAccounts")]
@@ -2634,9 +5049,44 @@ pub struct ProvisioningPreferences {
     pub stack_set_regions: Option<Vec<String>>,
 }
 
+impl Validate for ProvisioningPreferences {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stack_set_accounts {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "stack_set_accounts"), i);
+                errors.check_pattern(&item_path, &item, "^[0-9]{12}$");
+            }
+        }
+        if let Some(ref value) = self.stack_set_failure_tolerance_percentage {
+            errors.check_range(
+                &field_path(path, "stack_set_failure_tolerance_percentage"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.stack_set_max_concurrency_count {
+            errors.check_range(
+                &field_path(path, "stack_set_max_concurrency_count"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.stack_set_max_concurrency_percentage {
+            errors.check_range(
+                &field_path(path, "stack_set_max_concurrency_percentage"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Information about a request operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecordDetail {
     /// <p>The UTC time stamp of the creation time.</p>
     #[serde(rename = "CreatedTime")]
@@ -2694,7 +5144,7 @@ pub struct RecordDetail {
 
 /// <p>The error code and description resulting from an operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecordError {
     /// <p>The numeric value of the error.</p>
     #[serde(rename = "Code")]
@@ -2708,7 +5158,7 @@ pub struct RecordError {
 
 /// <p>The output for the product created as the result of a request. For example, the output for a CloudFormation-backed product that creates an S3 bucket would include the S3 bucket URL.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecordOutput {
     /// <p>The description of the output.</p>
     #[serde(rename = "Description")]
@@ -2726,7 +5176,7 @@ pub struct RecordOutput {
 
 /// <p>Information about a tag, which is a key-value pair.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecordTag {
     /// <p>The key for this tag.</p>
     #[serde(rename = "Key")]
@@ -2739,6 +5189,7 @@ pub struct RecordTag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RejectPortfolioShareInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -2753,13 +5204,37 @@ pub struct RejectPortfolioShareInput {
     pub portfolio_share_type: Option<String>,
 }
 
+impl Validate for RejectPortfolioShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "portfolio_id"),
+            self.portfolio_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "portfolio_id"),
+            &self.portfolio_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RejectPortfolioShareOutput {}
 
 /// <p>Information about a resource change that will occur when a plan is executed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceChange {
     /// <p>The change action.</p>
     #[serde(rename = "Action")]
@@ -2793,7 +5268,7 @@ pub struct ResourceChange {
 
 /// <p>Information about a change to a resource attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceChangeDetail {
     /// <p>The ID of the entity that caused the change.</p>
     #[serde(rename = "CausingEntity")]
@@ -2811,7 +5286,7 @@ pub struct ResourceChangeDetail {
 
 /// <p>Information about a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceDetail {
     /// <p>The ARN of the resource.</p>
     #[serde(rename = "ARN")]
@@ -2837,7 +5312,7 @@ pub struct ResourceDetail {
 
 /// <p>Information about a change to a resource attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceTargetDefinition {
     /// <p>The attribute to be changed.</p>
     #[serde(rename = "Attribute")]
@@ -2854,6 +5329,7 @@ pub struct ResourceTargetDefinition {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScanProvisionedProductsInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -2873,8 +5349,37 @@ pub struct ScanProvisionedProductsInput {
     pub page_token: Option<String>,
 }
 
+impl Validate for ScanProvisionedProductsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScanProvisionedProductsOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -2887,6 +5392,7 @@ pub struct ScanProvisionedProductsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchProductsAsAdminInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -2922,8 +5428,50 @@ pub struct SearchProductsAsAdminInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for SearchProductsAsAdminInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(20));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+        if let Some(ref value) = self.portfolio_id {
+            errors.check_length(
+                &field_path(path, "portfolio_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "portfolio_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchProductsAsAdminOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -2936,6 +5484,7 @@ pub struct SearchProductsAsAdminOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchProductsInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -2963,8 +5512,37 @@ pub struct SearchProductsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for SearchProductsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchProductsOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -2982,6 +5560,7 @@ pub struct SearchProductsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SearchProvisionedProductsInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3013,8 +5592,37 @@ pub struct SearchProvisionedProductsInput {
     pub sort_order: Option<String>,
 }
 
+impl Validate for SearchProvisionedProductsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.page_size {
+            errors.check_range(&field_path(path, "page_size"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.page_token {
+            errors.check_length(
+                &field_path(path, "page_token"),
+                value.chars().count(),
+                None,
+                Some(2024),
+            );
+            errors.check_pattern(
+                &field_path(path, "page_token"),
+                &value,
+                "[\\u0009\\u000a\\u000d\\u0020-\\uD7FF\\uE000-\\uFFFD]*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SearchProvisionedProductsOutput {
     /// <p>The page token to use to retrieve the next set of results. If there are no additional results, this value is null.</p>
     #[serde(rename = "NextPageToken")]
@@ -3032,6 +5640,7 @@ pub struct SearchProvisionedProductsOutput {
 
 /// <p>A self-service action association consisting of the Action ID, the Product ID, and the Provisioning Artifact ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServiceActionAssociation {
     /// <p>The product identifier. For example, <code>prod-abcdzk7xy33qa</code>.</p>
     #[serde(rename = "ProductId")]
@@ -3044,9 +5653,47 @@ pub struct ServiceActionAssociation {
     pub service_action_id: String,
 }
 
+impl Validate for ServiceActionAssociation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "service_action_id"),
+            self.service_action_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "service_action_id"),
+            &self.service_action_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 /// <p>An object containing detailed information about the self-service action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceActionDetail {
     /// <p>A map that defines the self-service action.</p>
     #[serde(rename = "Definition")]
@@ -3060,7 +5707,7 @@ pub struct ServiceActionDetail {
 
 /// <p>Detailed information about the self-service action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceActionSummary {
     /// <p>The self-service action definition type. For example, <code>SSM_AUTOMATION</code>.</p>
     #[serde(rename = "DefinitionType")]
@@ -3082,7 +5729,7 @@ pub struct ServiceActionSummary {
 
 /// <p>Information about the portfolio share operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ShareDetails {
     /// <p>List of errors.</p>
     #[serde(rename = "ShareErrors")]
@@ -3096,7 +5743,7 @@ pub struct ShareDetails {
 
 /// <p>Errors that occurred during the portfolio share operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ShareError {
     /// <p>List of accounts impacted by the error.</p>
     #[serde(rename = "Accounts")]
@@ -3114,7 +5761,7 @@ pub struct ShareError {
 
 /// <p>An AWS CloudFormation stack, in a specific account and region, that's part of a stack set operation. A stack instance is a reference to an attempted or actual stack in a given account within a given region. A stack instance can exist without a stack—for example, if the stack couldn't be created for some reason. A stack instance is associated with only one stack set. Each stack instance contains the ID of its associated stack set, as well as the ID of the actual stack and the stack status. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StackInstance {
     /// <p>The name of the AWS account that the stack instance is associated with.</p>
     #[serde(rename = "Account")]
@@ -3141,9 +5788,36 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 /// <p>Information about a TagOption.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagOptionDetail {
     /// <p>The TagOption active state.</p>
     #[serde(rename = "Active")]
@@ -3165,7 +5839,7 @@ pub struct TagOptionDetail {
 
 /// <p>Summary information about a TagOption.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagOptionSummary {
     /// <p>The TagOption key.</p>
     #[serde(rename = "Key")]
@@ -3178,6 +5852,7 @@ pub struct TagOptionSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateProvisionedProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3200,8 +5875,54 @@ pub struct TerminateProvisionedProductInput {
     pub terminate_token: String,
 }
 
+impl Validate for TerminateProvisionedProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.provisioned_product_id {
+            errors.check_length(
+                &field_path(path, "provisioned_product_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "provisioned_product_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+        if let Some(ref value) = self.provisioned_product_name {
+            errors.check_length(
+                &field_path(path, "provisioned_product_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "provisioned_product_name"), &value, "[a-zA-Z0-9][a-zA-Z0-9._-]{0,127}|arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        errors.check_length(
+            &field_path(path, "terminate_token"),
+            self.terminate_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "terminate_token"),
+            &self.terminate_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminateProvisionedProductOutput {
     /// <p>Information about the result of this request.</p>
     #[serde(rename = "RecordDetail")]
@@ -3210,6 +5931,7 @@ pub struct TerminateProvisionedProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConstraintInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3228,8 +5950,36 @@ pub struct UpdateConstraintInput {
     pub parameters: Option<String>,
 }
 
+impl Validate for UpdateConstraintInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateConstraintOutput {
     /// <p>Information about the constraint.</p>
     #[serde(rename = "ConstraintDetail")]
@@ -3246,6 +5996,7 @@ pub struct UpdateConstraintOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePortfolioInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3276,8 +6027,66 @@ pub struct UpdatePortfolioInput {
     pub remove_tags: Option<Vec<String>>,
 }
 
+impl Validate for UpdatePortfolioInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.add_tags {
+            errors.check_length(&field_path(path, "add_tags"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "add_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(2000),
+            );
+        }
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+        if let Some(ref value) = self.provider_name {
+            errors.check_length(
+                &field_path(path, "provider_name"),
+                value.chars().count(),
+                Some(1),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.remove_tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "remove_tags"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePortfolioOutput {
     /// <p>Information about the portfolio.</p>
     #[serde(rename = "PortfolioDetail")]
@@ -3290,6 +6099,7 @@ pub struct UpdatePortfolioOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3336,8 +6146,98 @@ pub struct UpdateProductInput {
     pub support_url: Option<String>,
 }
 
+impl Validate for UpdateProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.add_tags {
+            errors.check_length(&field_path(path, "add_tags"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "add_tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        if let Some(ref value) = self.distributor {
+            errors.check_length(
+                &field_path(path, "distributor"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        if let Some(ref value) = self.owner {
+            errors.check_length(
+                &field_path(path, "owner"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        if let Some(ref value) = self.remove_tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "remove_tags"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+            }
+        }
+        if let Some(ref value) = self.support_description {
+            errors.check_length(
+                &field_path(path, "support_description"),
+                value.chars().count(),
+                None,
+                Some(8191),
+            );
+        }
+        if let Some(ref value) = self.support_email {
+            errors.check_length(
+                &field_path(path, "support_email"),
+                value.chars().count(),
+                None,
+                Some(254),
+            );
+        }
+        if let Some(ref value) = self.support_url {
+            errors.check_length(
+                &field_path(path, "support_url"),
+                value.chars().count(),
+                None,
+                Some(2083),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProductOutput {
     /// <p>Information about the product view.</p>
     #[serde(rename = "ProductViewDetail")]
@@ -3350,6 +6250,7 @@ pub struct UpdateProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProvisionedProductInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3392,8 +6293,101 @@ pub struct UpdateProvisionedProductInput {
     pub update_token: String,
 }
 
+impl Validate for UpdateProvisionedProductInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.path_id {
+            errors.check_length(
+                &field_path(path, "path_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "path_id"), &value, "^[a-zA-Z0-9_\\-]*");
+        }
+        if let Some(ref value) = self.product_id {
+            errors.check_length(
+                &field_path(path, "product_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "product_id"), &value, "^[a-zA-Z0-9_\\-]*");
+        }
+        if let Some(ref value) = self.provisioned_product_id {
+            errors.check_length(
+                &field_path(path, "provisioned_product_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "provisioned_product_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+        if let Some(ref value) = self.provisioned_product_name {
+            errors.check_length(
+                &field_path(path, "provisioned_product_name"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "provisioned_product_name"), &value, "[a-zA-Z0-9][a-zA-Z0-9._-]{0,127}|arn:[a-z0-9-\\.]{1,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[a-z0-9-\\.]{0,63}:[^/].{0,1023}");
+        }
+        if let Some(ref value) = self.provisioning_artifact_id {
+            errors.check_length(
+                &field_path(path, "provisioning_artifact_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(
+                &field_path(path, "provisioning_artifact_id"),
+                &value,
+                "^[a-zA-Z0-9_\\-]*",
+            );
+        }
+        if let Some(ref value) = self.provisioning_parameters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "provisioning_parameters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.provisioning_preferences {
+            value.validate_at(&field_path(path, "provisioning_preferences"), errors);
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "update_token"),
+            self.update_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "update_token"),
+            &self.update_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProvisionedProductOutput {
     /// <p>Information about the result of the request.</p>
     #[serde(rename = "RecordDetail")]
@@ -3402,6 +6396,7 @@ pub struct UpdateProvisionedProductOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProvisionedProductPropertiesInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3418,8 +6413,57 @@ pub struct UpdateProvisionedProductPropertiesInput {
     pub provisioned_product_properties: ::std::collections::HashMap<String, String>,
 }
 
+impl Validate for UpdateProvisionedProductPropertiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "idempotency_token"),
+            self.idempotency_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "idempotency_token"),
+            &self.idempotency_token,
+            "[a-zA-Z0-9][a-zA-Z0-9_-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioned_product_id"),
+            self.provisioned_product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioned_product_id"),
+            &self.provisioned_product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioned_product_properties"),
+            self.provisioned_product_properties.len(),
+            Some(1),
+            Some(100),
+        );
+        for (key, item) in self.provisioned_product_properties.iter() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "provisioned_product_properties"),
+                key
+            );
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProvisionedProductPropertiesOutput {
     /// <p>The provisioned product identifier.</p>
     #[serde(rename = "ProvisionedProductId")]
@@ -3440,6 +6484,7 @@ pub struct UpdateProvisionedProductPropertiesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProvisioningArtifactInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3469,8 +6514,59 @@ pub struct UpdateProvisioningArtifactInput {
     pub provisioning_artifact_id: String,
 }
 
+impl Validate for UpdateProvisioningArtifactInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "product_id"),
+            self.product_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "product_id"),
+            &self.product_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "provisioning_artifact_id"),
+            self.provisioning_artifact_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "provisioning_artifact_id"),
+            &self.provisioning_artifact_id,
+            "^[a-zA-Z0-9_\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateProvisioningArtifactOutput {
     /// <p>The URL of the CloudFormation template in Amazon S3.</p>
     #[serde(rename = "Info")]
@@ -3503,8 +6599,30 @@ pub struct UpdateProvisioningParameter {
     pub value: Option<String>,
 }
 
+impl Validate for UpdateProvisioningParameter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 /// <p>The user-defined preferences that will be applied when updating a provisioned product. Not all preferences are applicable to all provisioned product types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateProvisioningPreferences {
     /// <p>One or more AWS accounts that will have access to the provisioned product.</p> <p>Applicable only to a <code>CFN_STACKSET</code> provisioned product type.</p> <p>The AWS accounts specified should be within the list of accounts in the <code>STACKSET</code> constraint. To get the list of accounts in the <code>STACKSET</code> constraint, use the <code>DescribeProvisioningParameters</code> operation.</p> <p>If no values are specified, the default value is all accounts from the <code>STACKSET</code> constraint.</p>
     #[serde(rename = "StackSetAccounts")]
@@ -3536,7 +6654,43 @@ pub struct UpdateProvisioningPreferences {
     pub stack_set_regions: Option<Vec<String>>,
 }
 
+impl Validate for UpdateProvisioningPreferences {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.stack_set_accounts {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "stack_set_accounts"), i);
+                errors.check_pattern(&item_path, &item, "^[0-9]{12}$");
+            }
+        }
+        if let Some(ref value) = self.stack_set_failure_tolerance_percentage {
+            errors.check_range(
+                &field_path(path, "stack_set_failure_tolerance_percentage"),
+                *value,
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.stack_set_max_concurrency_count {
+            errors.check_range(
+                &field_path(path, "stack_set_max_concurrency_count"),
+                *value,
+                Some(1),
+                None,
+            );
+        }
+        if let Some(ref value) = self.stack_set_max_concurrency_percentage {
+            errors.check_range(
+                &field_path(path, "stack_set_max_concurrency_percentage"),
+                *value,
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceActionInput {
     /// <p><p>The language code.</p> <ul> <li> <p> <code>en</code> - English (default)</p> </li> <li> <p> <code>jp</code> - Japanese</p> </li> <li> <p> <code>zh</code> - Chinese</p> </li> </ul></p>
     #[serde(rename = "AcceptLanguage")]
@@ -3559,8 +6713,57 @@ pub struct UpdateServiceActionInput {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateServiceActionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accept_language {
+            errors.check_length(
+                &field_path(path, "accept_language"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.definition {
+            errors.check_length(
+                &field_path(path, "definition"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "definition"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "^[a-zA-Z0-9_\\-]*");
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, "^[a-zA-Z0-9_\\-.]*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServiceActionOutput {
     /// <p>Detailed information about the self-service action.</p>
     #[serde(rename = "ServiceActionDetail")]
@@ -3569,6 +6772,7 @@ pub struct UpdateServiceActionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTagOptionInput {
     /// <p>The updated active state.</p>
     #[serde(rename = "Active")]
@@ -3583,8 +6787,32 @@ pub struct UpdateTagOptionInput {
     pub value: Option<String>,
 }
 
+impl Validate for UpdateTagOptionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "value"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateTagOptionOutput {
     /// <p>Information about the TagOption.</p>
     #[serde(rename = "TagOptionDetail")]
@@ -3594,7 +6822,7 @@ pub struct UpdateTagOptionOutput {
 
 /// <p>Additional information provided by the administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UsageInstruction {
     /// <p>The usage instruction type for the value.</p>
     #[serde(rename = "Type")]
@@ -3634,7 +6862,12 @@ impl AcceptPortfolioShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3694,7 +6927,12 @@ impl AssociateBudgetWithResourceError {
                         AssociateBudgetWithResourceError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3748,7 +6986,12 @@ impl AssociatePrincipalWithPortfolioError {
                         AssociatePrincipalWithPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3801,7 +7044,12 @@ impl AssociateProductWithPortfolioError {
                         AssociateProductWithPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3858,7 +7106,12 @@ impl AssociateServiceActionWithProvisioningArtifactError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3936,7 +7189,12 @@ impl AssociateTagOptionWithResourceError {
                         AssociateTagOptionWithResourceError::TagOptionNotMigrated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3980,7 +7238,12 @@ impl BatchAssociateServiceActionWithProvisioningArtifactError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4019,7 +7282,12 @@ impl BatchDisassociateServiceActionFromProvisioningArtifactError {
                         err.msg,
                     ),
                 ),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4059,7 +7327,12 @@ impl CopyProductError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CopyProductError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4108,7 +7381,12 @@ impl CreateConstraintError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateConstraintError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4156,7 +7434,12 @@ impl CreatePortfolioError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4217,7 +7500,12 @@ impl CreatePortfolioShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4264,7 +7552,12 @@ impl CreateProductError {
                 "TagOptionNotMigratedException" => {
                     return RusotoError::Service(CreateProductError::TagOptionNotMigrated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4317,7 +7610,12 @@ impl CreateProvisionedProductPlanError {
                         CreateProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4370,7 +7668,12 @@ impl CreateProvisioningArtifactError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4412,7 +7715,12 @@ impl CreateServiceActionError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateServiceActionError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4458,7 +7766,12 @@ impl CreateTagOptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4498,7 +7811,12 @@ impl DeleteConstraintError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteConstraintError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4549,7 +7867,12 @@ impl DeletePortfolioError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4606,7 +7929,12 @@ impl DeletePortfolioShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4657,7 +7985,12 @@ impl DeleteProductError {
                 "TagOptionNotMigratedException" => {
                     return RusotoError::Service(DeleteProductError::TagOptionNotMigrated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4704,7 +8037,12 @@ impl DeleteProvisionedProductPlanError {
                         DeleteProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4756,7 +8094,12 @@ impl DeleteProvisioningArtifactError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4798,7 +8141,12 @@ impl DeleteServiceActionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4844,7 +8192,12 @@ impl DeleteTagOptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4879,7 +8232,12 @@ impl DescribeConstraintError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeConstraintError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4914,7 +8272,12 @@ impl DescribeCopyProductStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4947,7 +8310,12 @@ impl DescribePortfolioError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribePortfolioError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4998,7 +8366,12 @@ impl DescribePortfolioShareStatusError {
                         DescribePortfolioShareStatusError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5038,7 +8411,12 @@ impl DescribeProductError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeProductError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5074,7 +8452,12 @@ impl DescribeProductAsAdminError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5116,7 +8499,12 @@ impl DescribeProductViewError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5154,7 +8542,12 @@ impl DescribeProvisionedProductError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5198,7 +8591,12 @@ impl DescribeProvisionedProductPlanError {
                         DescribeProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5236,7 +8634,12 @@ impl DescribeProvisioningArtifactError {
                         DescribeProvisioningArtifactError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5280,7 +8683,12 @@ impl DescribeProvisioningParametersError {
                         DescribeProvisioningParametersError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5314,7 +8722,12 @@ impl DescribeRecordError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeRecordError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5349,7 +8762,12 @@ impl DescribeServiceActionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5393,7 +8811,12 @@ impl DescribeServiceActionExecutionParametersError {
                         DescribeServiceActionExecutionParametersError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5434,7 +8857,12 @@ impl DescribeTagOptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5486,7 +8914,12 @@ impl DisableAWSOrganizationsAccessError {
                         DisableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5525,7 +8958,12 @@ impl DisassociateBudgetFromResourceError {
                         DisassociateBudgetFromResourceError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5569,7 +9007,12 @@ impl DisassociatePrincipalFromPortfolioError {
                         DisassociatePrincipalFromPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5621,7 +9064,12 @@ impl DisassociateProductFromPortfolioError {
                         DisassociateProductFromPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5662,7 +9110,12 @@ impl DisassociateServiceActionFromProvisioningArtifactError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5708,7 +9161,12 @@ impl DisassociateTagOptionFromResourceError {
                         DisassociateTagOptionFromResourceError::TagOptionNotMigrated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5760,7 +9218,12 @@ impl EnableAWSOrganizationsAccessError {
                         EnableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5813,7 +9276,12 @@ impl ExecuteProvisionedProductPlanError {
                         ExecuteProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5866,7 +9334,12 @@ impl ExecuteProvisionedProductServiceActionError {
                         ExecuteProvisionedProductServiceActionError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5912,7 +9385,12 @@ impl GetAWSOrganizationsAccessStatusError {
                         GetAWSOrganizationsAccessStatusError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5957,7 +9435,12 @@ impl ListAcceptedPortfolioSharesError {
                         ListAcceptedPortfolioSharesError::OperationNotSupported(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6000,7 +9483,12 @@ impl ListBudgetsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6045,7 +9533,12 @@ impl ListConstraintsForPortfolioError {
                         ListConstraintsForPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6084,7 +9577,12 @@ impl ListLaunchPathsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListLaunchPathsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6136,7 +9634,12 @@ impl ListOrganizationPortfolioAccessError {
                         ListOrganizationPortfolioAccessError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6173,7 +9676,12 @@ impl ListPortfolioAccessError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6206,7 +9714,12 @@ impl ListPortfoliosError {
                 "InvalidParametersException" => {
                     return RusotoError::Service(ListPortfoliosError::InvalidParameters(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6248,7 +9761,12 @@ impl ListPortfoliosForProductError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6293,7 +9811,12 @@ impl ListPrincipalsForPortfolioError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6338,7 +9861,12 @@ impl ListProvisionedProductPlansError {
                         ListProvisionedProductPlansError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6381,7 +9909,12 @@ impl ListProvisioningArtifactsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6426,7 +9959,12 @@ impl ListProvisioningArtifactsForServiceActionError {
                         ListProvisioningArtifactsForServiceActionError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6460,7 +9998,12 @@ impl ListRecordHistoryError {
                 "InvalidParametersException" => {
                     return RusotoError::Service(ListRecordHistoryError::InvalidParameters(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6509,7 +10052,12 @@ impl ListResourcesForTagOptionError {
                         ListResourcesForTagOptionError::TagOptionNotMigrated(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6546,7 +10094,12 @@ impl ListServiceActionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6590,7 +10143,12 @@ impl ListServiceActionsForProvisioningArtifactError {
                         ListServiceActionsForProvisioningArtifactError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6635,7 +10193,12 @@ impl ListStackInstancesForProvisionedProductError {
                         ListStackInstancesForProvisionedProductError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6674,7 +10237,12 @@ impl ListTagOptionsError {
                 "TagOptionNotMigratedException" => {
                     return RusotoError::Service(ListTagOptionsError::TagOptionNotMigrated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6718,7 +10286,12 @@ impl ProvisionProductError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ProvisionProductError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6755,7 +10328,12 @@ impl RejectPortfolioShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6790,7 +10368,12 @@ impl ScanProvisionedProductsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6823,7 +10406,12 @@ impl SearchProductsError {
                 "InvalidParametersException" => {
                     return RusotoError::Service(SearchProductsError::InvalidParameters(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6865,7 +10453,12 @@ impl SearchProductsAsAdminError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6901,7 +10494,12 @@ impl SearchProvisionedProductsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6938,7 +10536,12 @@ impl TerminateProvisionedProductError {
                         TerminateProvisionedProductError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6976,7 +10579,12 @@ impl UpdateConstraintError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateConstraintError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7027,7 +10635,12 @@ impl UpdatePortfolioError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7073,7 +10686,12 @@ impl UpdateProductError {
                 "TagOptionNotMigratedException" => {
                     return RusotoError::Service(UpdateProductError::TagOptionNotMigrated(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7117,7 +10735,12 @@ impl UpdateProvisionedProductError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7169,7 +10792,12 @@ impl UpdateProvisionedProductPropertiesError {
                         UpdateProvisionedProductPropertiesError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7215,7 +10843,12 @@ impl UpdateProvisioningArtifactError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7258,7 +10891,12 @@ impl UpdateServiceActionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7309,7 +10947,12 @@ impl UpdateTagOptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7860,166 +11503,33 @@ pub trait ServiceCatalog {
         input: UpdateTagOptionInput,
     ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError>;
 }
-/// A client for the AWS Service Catalog API.
-#[derive(Clone)]
-pub struct ServiceCatalogClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ServiceCatalogClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ServiceCatalogClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ServiceCatalogClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogClient {
-        ServiceCatalogClient { client, region }
-    }
-}
-
-impl ServiceCatalog for ServiceCatalogClient {
-    /// <p>Accepts an offer to share the specified portfolio.</p>
+const _: Option<&dyn ServiceCatalog> = None;
+impl<'a, T: ServiceCatalog + ?Sized> ServiceCatalog for &'a T {
     fn accept_portfolio_share(
         &self,
         input: AcceptPortfolioShareInput,
     ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.AcceptPortfolioShare",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AcceptPortfolioShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AcceptPortfolioShareError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).accept_portfolio_share(input)
     }
-
-    /// <p>Associates the specified budget with the specified resource.</p>
     fn associate_budget_with_resource(
         &self,
         input: AssociateBudgetWithResourceInput,
     ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.AssociateBudgetWithResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateBudgetWithResourceOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateBudgetWithResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_budget_with_resource(input)
     }
-
-    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
     fn associate_principal_with_portfolio(
         &self,
         input: AssociatePrincipalWithPortfolioInput,
     ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.AssociatePrincipalWithPortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociatePrincipalWithPortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociatePrincipalWithPortfolioError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_principal_with_portfolio(input)
     }
-
-    /// <p>Associates the specified product with the specified portfolio.</p>
     fn associate_product_with_portfolio(
         &self,
         input: AssociateProductWithPortfolioInput,
     ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.AssociateProductWithPortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateProductWithPortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateProductWithPortfolioError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_product_with_portfolio(input)
     }
-
-    /// <p>Associates a self-service action with a provisioning artifact.</p>
     fn associate_service_action_with_provisioning_artifact(
         &self,
         input: AssociateServiceActionWithProvisioningArtifactInput,
@@ -8027,60 +11537,15 @@ impl ServiceCatalog for ServiceCatalogClient {
         AssociateServiceActionWithProvisioningArtifactOutput,
         AssociateServiceActionWithProvisioningArtifactError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.AssociateServiceActionWithProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AssociateServiceActionWithProvisioningArtifactOutput, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(AssociateServiceActionWithProvisioningArtifactError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).associate_service_action_with_provisioning_artifact(input)
     }
-
-    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
     fn associate_tag_option_with_resource(
         &self,
         input: AssociateTagOptionWithResourceInput,
     ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.AssociateTagOptionWithResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateTagOptionWithResourceOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateTagOptionWithResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_tag_option_with_resource(input)
     }
-
-    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
     fn batch_associate_service_action_with_provisioning_artifact(
         &self,
         input: BatchAssociateServiceActionWithProvisioningArtifactInput,
@@ -8088,30 +11553,8 @@ impl ServiceCatalog for ServiceCatalogClient {
         BatchAssociateServiceActionWithProvisioningArtifactOutput,
         BatchAssociateServiceActionWithProvisioningArtifactError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.BatchAssociateServiceActionWithProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<BatchAssociateServiceActionWithProvisioningArtifactOutput, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(BatchAssociateServiceActionWithProvisioningArtifactError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).batch_associate_service_action_with_provisioning_artifact(input)
     }
-
-    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
     fn batch_disassociate_service_action_from_provisioning_artifact(
         &self,
         input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
@@ -8119,942 +11562,190 @@ impl ServiceCatalog for ServiceCatalogClient {
         BatchDisassociateServiceActionFromProvisioningArtifactOutput,
         BatchDisassociateServiceActionFromProvisioningArtifactError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.BatchDisassociateServiceActionFromProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<BatchDisassociateServiceActionFromProvisioningArtifactOutput, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(BatchDisassociateServiceActionFromProvisioningArtifactError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).batch_disassociate_service_action_from_provisioning_artifact(input)
     }
-
-    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
     fn copy_product(
         &self,
         input: CopyProductInput,
     ) -> RusotoFuture<CopyProductOutput, CopyProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CopyProductOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CopyProductError::from_response(response))),
-                )
-            }
-        })
+        (**self).copy_product(input)
     }
-
-    /// <p>Creates a constraint.</p>
     fn create_constraint(
         &self,
         input: CreateConstraintInput,
     ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreateConstraint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateConstraintOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateConstraintError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_constraint(input)
     }
-
-    /// <p>Creates a portfolio.</p>
     fn create_portfolio(
         &self,
         input: CreatePortfolioInput,
     ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreatePortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreatePortfolioError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_portfolio(input)
     }
-
-    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an Organization. AWSOrganizationsAccess must be enabled in order to create a portfolio share to an organization node.</p>
     fn create_portfolio_share(
         &self,
         input: CreatePortfolioShareInput,
     ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreatePortfolioShare",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePortfolioShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreatePortfolioShareError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_portfolio_share(input)
     }
-
-    /// <p>Creates a product.</p>
     fn create_product(
         &self,
         input: CreateProductInput,
     ) -> RusotoFuture<CreateProductOutput, CreateProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProductOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateProductError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_product(input)
     }
-
-    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
     fn create_provisioned_product_plan(
         &self,
         input: CreateProvisionedProductPlanInput,
     ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreateProvisionedProductPlan",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProvisionedProductPlanOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateProvisionedProductPlanError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_provisioned_product_plan(input)
     }
-
-    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
     fn create_provisioning_artifact(
         &self,
         input: CreateProvisioningArtifactInput,
     ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreateProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProvisioningArtifactOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateProvisioningArtifactError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_provisioning_artifact(input)
     }
-
-    /// <p>Creates a self-service action.</p>
     fn create_service_action(
         &self,
         input: CreateServiceActionInput,
     ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreateServiceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateServiceActionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateServiceActionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_service_action(input)
     }
-
-    /// <p>Creates a TagOption.</p>
     fn create_tag_option(
         &self,
         input: CreateTagOptionInput,
     ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.CreateTagOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTagOptionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTagOptionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_tag_option(input)
     }
-
-    /// <p>Deletes the specified constraint.</p>
     fn delete_constraint(
         &self,
         input: DeleteConstraintInput,
     ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeleteConstraint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteConstraintOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteConstraintError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_constraint(input)
     }
-
-    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p>
     fn delete_portfolio(
         &self,
         input: DeletePortfolioInput,
     ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeletePortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeletePortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeletePortfolioError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_portfolio(input)
     }
-
-    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an Organization.</p>
     fn delete_portfolio_share(
         &self,
         input: DeletePortfolioShareInput,
     ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeletePortfolioShare",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeletePortfolioShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeletePortfolioShareError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_portfolio_share(input)
     }
-
-    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p>
     fn delete_product(
         &self,
         input: DeleteProductInput,
     ) -> RusotoFuture<DeleteProductOutput, DeleteProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.DeleteProduct");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProductOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteProductError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_product(input)
     }
-
-    /// <p>Deletes the specified plan.</p>
     fn delete_provisioned_product_plan(
         &self,
         input: DeleteProvisionedProductPlanInput,
     ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeleteProvisionedProductPlan",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProvisionedProductPlanOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteProvisionedProductPlanError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_provisioned_product_plan(input)
     }
-
-    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
     fn delete_provisioning_artifact(
         &self,
         input: DeleteProvisioningArtifactInput,
     ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeleteProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProvisioningArtifactOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteProvisioningArtifactError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_provisioning_artifact(input)
     }
-
-    /// <p>Deletes a self-service action.</p>
     fn delete_service_action(
         &self,
         input: DeleteServiceActionInput,
     ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeleteServiceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteServiceActionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteServiceActionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_service_action(input)
     }
-
-    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
     fn delete_tag_option(
         &self,
         input: DeleteTagOptionInput,
     ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DeleteTagOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTagOptionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTagOptionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tag_option(input)
     }
-
-    /// <p>Gets information about the specified constraint.</p>
     fn describe_constraint(
         &self,
         input: DescribeConstraintInput,
     ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeConstraint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeConstraintOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeConstraintError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_constraint(input)
     }
-
-    /// <p>Gets the status of the specified copy product operation.</p>
     fn describe_copy_product_status(
         &self,
         input: DescribeCopyProductStatusInput,
     ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeCopyProductStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCopyProductStatusOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCopyProductStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_copy_product_status(input)
     }
-
-    /// <p>Gets information about the specified portfolio.</p>
     fn describe_portfolio(
         &self,
         input: DescribePortfolioInput,
     ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribePortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribePortfolioError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_portfolio(input)
     }
-
-    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization.</p>
     fn describe_portfolio_share_status(
         &self,
         input: DescribePortfolioShareStatusInput,
     ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribePortfolioShareStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePortfolioShareStatusOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePortfolioShareStatusError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_portfolio_share_status(input)
     }
-
-    /// <p>Gets information about the specified product.</p>
     fn describe_product(
         &self,
         input: DescribeProductInput,
     ) -> RusotoFuture<DescribeProductOutput, DescribeProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProductOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeProductError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_product(input)
     }
-
-    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
     fn describe_product_as_admin(
         &self,
         input: DescribeProductAsAdminInput,
     ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProductAsAdmin",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProductAsAdminOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeProductAsAdminError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_product_as_admin(input)
     }
-
-    /// <p>Gets information about the specified product.</p>
     fn describe_product_view(
         &self,
         input: DescribeProductViewInput,
     ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProductView",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProductViewOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeProductViewError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_product_view(input)
     }
-
-    /// <p>Gets information about the specified provisioned product.</p>
     fn describe_provisioned_product(
         &self,
         input: DescribeProvisionedProductInput,
     ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProvisionedProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProvisionedProductOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeProvisionedProductError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_provisioned_product(input)
     }
-
-    /// <p>Gets information about the resource changes for the specified plan.</p>
     fn describe_provisioned_product_plan(
         &self,
         input: DescribeProvisionedProductPlanInput,
     ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProvisionedProductPlan",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProvisionedProductPlanOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeProvisionedProductPlanError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_provisioned_product_plan(input)
     }
-
-    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
     fn describe_provisioning_artifact(
         &self,
         input: DescribeProvisioningArtifactInput,
     ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProvisioningArtifactOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeProvisioningArtifactError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_provisioning_artifact(input)
     }
-
-    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
     fn describe_provisioning_parameters(
         &self,
         input: DescribeProvisioningParametersInput,
     ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeProvisioningParameters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProvisioningParametersOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeProvisioningParametersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_provisioning_parameters(input)
     }
-
-    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
     fn describe_record(
         &self,
         input: DescribeRecordInput,
     ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.DescribeRecord");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRecordOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeRecordError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_record(input)
     }
-
-    /// <p>Describes a self-service action.</p>
     fn describe_service_action(
         &self,
         input: DescribeServiceActionInput,
     ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeServiceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServiceActionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeServiceActionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_service_action(input)
     }
-
     fn describe_service_action_execution_parameters(
         &self,
         input: DescribeServiceActionExecutionParametersInput,
@@ -9062,120 +11753,26 @@ impl ServiceCatalog for ServiceCatalogClient {
         DescribeServiceActionExecutionParametersOutput,
         DescribeServiceActionExecutionParametersError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeServiceActionExecutionParameters",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServiceActionExecutionParametersOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeServiceActionExecutionParametersError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_service_action_execution_parameters(input)
     }
-
-    /// <p>Gets information about the specified TagOption.</p>
     fn describe_tag_option(
         &self,
         input: DescribeTagOptionInput,
     ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DescribeTagOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTagOptionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagOptionError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_tag_option(input)
     }
-
-    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p>
     fn disable_aws_organizations_access(
         &self,
     ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DisableAWSOrganizationsAccess",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableAWSOrganizationsAccessOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisableAWSOrganizationsAccessError::from_response(response))
-                }))
-            }
-        })
+        (**self).disable_aws_organizations_access()
     }
-
-    /// <p>Disassociates the specified budget from the specified resource.</p>
     fn disassociate_budget_from_resource(
         &self,
         input: DisassociateBudgetFromResourceInput,
     ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DisassociateBudgetFromResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateBudgetFromResourceOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateBudgetFromResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_budget_from_resource(input)
     }
-
-    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
     fn disassociate_principal_from_portfolio(
         &self,
         input: DisassociatePrincipalFromPortfolioInput,
@@ -9183,65 +11780,15 @@ impl ServiceCatalog for ServiceCatalogClient {
         DisassociatePrincipalFromPortfolioOutput,
         DisassociatePrincipalFromPortfolioError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DisassociatePrincipalFromPortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociatePrincipalFromPortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociatePrincipalFromPortfolioError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_principal_from_portfolio(input)
     }
-
-    /// <p>Disassociates the specified product from the specified portfolio. </p>
     fn disassociate_product_from_portfolio(
         &self,
         input: DisassociateProductFromPortfolioInput,
     ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DisassociateProductFromPortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateProductFromPortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateProductFromPortfolioError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_product_from_portfolio(input)
     }
-
-    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
     fn disassociate_service_action_from_provisioning_artifact(
         &self,
         input: DisassociateServiceActionFromProvisioningArtifactInput,
@@ -9249,118 +11796,26 @@ impl ServiceCatalog for ServiceCatalogClient {
         DisassociateServiceActionFromProvisioningArtifactOutput,
         DisassociateServiceActionFromProvisioningArtifactError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DisassociateServiceActionFromProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DisassociateServiceActionFromProvisioningArtifactOutput, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(DisassociateServiceActionFromProvisioningArtifactError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).disassociate_service_action_from_provisioning_artifact(input)
     }
-
-    /// <p>Disassociates the specified TagOption from the specified resource.</p>
     fn disassociate_tag_option_from_resource(
         &self,
         input: DisassociateTagOptionFromResourceInput,
     ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.DisassociateTagOptionFromResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateTagOptionFromResourceOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateTagOptionFromResourceError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_tag_option_from_resource(input)
     }
-
-    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p>
     fn enable_aws_organizations_access(
         &self,
     ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.EnableAWSOrganizationsAccess",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<EnableAWSOrganizationsAccessOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(EnableAWSOrganizationsAccessError::from_response(response))
-                }))
-            }
-        })
+        (**self).enable_aws_organizations_access()
     }
-
-    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
     fn execute_provisioned_product_plan(
         &self,
         input: ExecuteProvisionedProductPlanInput,
     ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ExecuteProvisionedProductPlan",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExecuteProvisionedProductPlanOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ExecuteProvisionedProductPlanError::from_response(response))
-                }))
-            }
-        })
+        (**self).execute_provisioned_product_plan(input)
     }
-
-    /// <p>Executes a self-service action against a provisioned product.</p>
     fn execute_provisioned_product_service_action(
         &self,
         input: ExecuteProvisionedProductServiceActionInput,
@@ -9368,392 +11823,81 @@ impl ServiceCatalog for ServiceCatalogClient {
         ExecuteProvisionedProductServiceActionOutput,
         ExecuteProvisionedProductServiceActionError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ExecuteProvisionedProductServiceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ExecuteProvisionedProductServiceActionOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ExecuteProvisionedProductServiceActionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).execute_provisioned_product_service_action(input)
     }
-
-    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization.</p>
     fn get_aws_organizations_access_status(
         &self,
     ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.GetAWSOrganizationsAccessStatus",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAWSOrganizationsAccessStatusOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAWSOrganizationsAccessStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_aws_organizations_access_status()
     }
-
-    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
     fn list_accepted_portfolio_shares(
         &self,
         input: ListAcceptedPortfolioSharesInput,
     ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListAcceptedPortfolioShares",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAcceptedPortfolioSharesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAcceptedPortfolioSharesError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_accepted_portfolio_shares(input)
     }
-
-    /// <p>Lists all the budgets associated to the specified resource.</p>
     fn list_budgets_for_resource(
         &self,
         input: ListBudgetsForResourceInput,
     ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListBudgetsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBudgetsForResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListBudgetsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_budgets_for_resource(input)
     }
-
-    /// <p>Lists the constraints for the specified portfolio and product.</p>
     fn list_constraints_for_portfolio(
         &self,
         input: ListConstraintsForPortfolioInput,
     ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListConstraintsForPortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListConstraintsForPortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListConstraintsForPortfolioError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_constraints_for_portfolio(input)
     }
-
-    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
     fn list_launch_paths(
         &self,
         input: ListLaunchPathsInput,
     ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListLaunchPaths",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLaunchPathsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListLaunchPathsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_launch_paths(input)
     }
-
-    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization.</p>
     fn list_organization_portfolio_access(
         &self,
         input: ListOrganizationPortfolioAccessInput,
     ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>
     {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListOrganizationPortfolioAccess",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOrganizationPortfolioAccessOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListOrganizationPortfolioAccessError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_organization_portfolio_access(input)
     }
-
-    /// <p>Lists the account IDs that have access to the specified portfolio.</p>
     fn list_portfolio_access(
         &self,
         input: ListPortfolioAccessInput,
     ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListPortfolioAccess",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPortfolioAccessOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListPortfolioAccessError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_portfolio_access(input)
     }
-
-    /// <p>Lists all portfolios in the catalog.</p>
     fn list_portfolios(
         &self,
         input: ListPortfoliosInput,
     ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListPortfolios");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPortfoliosOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListPortfoliosError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_portfolios(input)
     }
-
-    /// <p>Lists all portfolios that the specified product is associated with.</p>
     fn list_portfolios_for_product(
         &self,
         input: ListPortfoliosForProductInput,
     ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListPortfoliosForProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPortfoliosForProductOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPortfoliosForProductError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_portfolios_for_product(input)
     }
-
-    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
     fn list_principals_for_portfolio(
         &self,
         input: ListPrincipalsForPortfolioInput,
     ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListPrincipalsForPortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListPrincipalsForPortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPrincipalsForPortfolioError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_principals_for_portfolio(input)
     }
-
-    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
     fn list_provisioned_product_plans(
         &self,
         input: ListProvisionedProductPlansInput,
     ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListProvisionedProductPlans",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProvisionedProductPlansOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListProvisionedProductPlansError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_provisioned_product_plans(input)
     }
-
-    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
     fn list_provisioning_artifacts(
         &self,
         input: ListProvisioningArtifactsInput,
     ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListProvisioningArtifacts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProvisioningArtifactsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListProvisioningArtifactsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_provisioning_artifacts(input)
     }
-
-    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
     fn list_provisioning_artifacts_for_service_action(
         &self,
         input: ListProvisioningArtifactsForServiceActionInput,
@@ -9761,124 +11905,26 @@ impl ServiceCatalog for ServiceCatalogClient {
         ListProvisioningArtifactsForServiceActionOutput,
         ListProvisioningArtifactsForServiceActionError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListProvisioningArtifactsForServiceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProvisioningArtifactsForServiceActionOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListProvisioningArtifactsForServiceActionError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_provisioning_artifacts_for_service_action(input)
     }
-
-    /// <p>Lists the specified requests or all performed requests.</p>
     fn list_record_history(
         &self,
         input: ListRecordHistoryInput,
     ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListRecordHistory",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRecordHistoryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRecordHistoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_record_history(input)
     }
-
-    /// <p>Lists the resources associated with the specified TagOption.</p>
     fn list_resources_for_tag_option(
         &self,
         input: ListResourcesForTagOptionInput,
     ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListResourcesForTagOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourcesForTagOptionOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListResourcesForTagOptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_resources_for_tag_option(input)
     }
-
-    /// <p>Lists all self-service actions.</p>
     fn list_service_actions(
         &self,
         input: ListServiceActionsInput,
     ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListServiceActions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListServiceActionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListServiceActionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_service_actions(input)
     }
-
-    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
     fn list_service_actions_for_provisioning_artifact(
         &self,
         input: ListServiceActionsForProvisioningArtifactInput,
@@ -9886,31 +11932,8 @@ impl ServiceCatalog for ServiceCatalogClient {
         ListServiceActionsForProvisioningArtifactOutput,
         ListServiceActionsForProvisioningArtifactError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListServiceActionsForProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListServiceActionsForProvisioningArtifactOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListServiceActionsForProvisioningArtifactError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_service_actions_for_provisioning_artifact(input)
     }
-
-    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
     fn list_stack_instances_for_provisioned_product(
         &self,
         input: ListStackInstancesForProvisionedProductInput,
@@ -9918,394 +11941,7441 @@ impl ServiceCatalog for ServiceCatalogClient {
         ListStackInstancesForProvisionedProductOutput,
         ListStackInstancesForProvisionedProductError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ListStackInstancesForProvisionedProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListStackInstancesForProvisionedProductOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListStackInstancesForProvisionedProductError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_stack_instances_for_provisioned_product(input)
     }
-
-    /// <p>Lists the specified TagOptions or all TagOptions.</p>
     fn list_tag_options(
         &self,
         input: ListTagOptionsInput,
     ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListTagOptions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagOptionsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagOptionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tag_options(input)
     }
-
-    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
     fn provision_product(
         &self,
         input: ProvisionProductInput,
     ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ProvisionProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ProvisionProductOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ProvisionProductError::from_response(response))),
-                )
-            }
-        })
+        (**self).provision_product(input)
     }
-
-    /// <p>Rejects an offer to share the specified portfolio.</p>
     fn reject_portfolio_share(
         &self,
         input: RejectPortfolioShareInput,
     ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.RejectPortfolioShare",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RejectPortfolioShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RejectPortfolioShareError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).reject_portfolio_share(input)
     }
-
-    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
     fn scan_provisioned_products(
         &self,
         input: ScanProvisionedProductsInput,
     ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.ScanProvisionedProducts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ScanProvisionedProductsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ScanProvisionedProductsError::from_response(response))
-                }))
-            }
-        })
+        (**self).scan_provisioned_products(input)
     }
-
-    /// <p>Gets information about the products to which the caller has access.</p>
     fn search_products(
         &self,
         input: SearchProductsInput,
     ) -> RusotoFuture<SearchProductsOutput, SearchProductsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.SearchProducts");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchProductsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SearchProductsError::from_response(response))),
-                )
-            }
-        })
+        (**self).search_products(input)
     }
-
-    /// <p>Gets information about the products for the specified portfolio or all products.</p>
     fn search_products_as_admin(
         &self,
         input: SearchProductsAsAdminInput,
     ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.SearchProductsAsAdmin",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchProductsAsAdminOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SearchProductsAsAdminError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).search_products_as_admin(input)
+    }
+    fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError> {
+        (**self).search_provisioned_products(input)
+    }
+    fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError> {
+        (**self).terminate_provisioned_product(input)
+    }
+    fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError> {
+        (**self).update_constraint(input)
+    }
+    fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError> {
+        (**self).update_portfolio(input)
+    }
+    fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError> {
+        (**self).update_product(input)
+    }
+    fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError> {
+        (**self).update_provisioned_product(input)
+    }
+    fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> RusotoFuture<
+        UpdateProvisionedProductPropertiesOutput,
+        UpdateProvisionedProductPropertiesError,
+    > {
+        (**self).update_provisioned_product_properties(input)
+    }
+    fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError> {
+        (**self).update_provisioning_artifact(input)
+    }
+    fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError> {
+        (**self).update_service_action(input)
+    }
+    fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError> {
+        (**self).update_tag_option(input)
+    }
+}
+impl<T: ServiceCatalog + ?Sized> ServiceCatalog for Box<T> {
+    fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError> {
+        (**self).accept_portfolio_share(input)
+    }
+    fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError> {
+        (**self).associate_budget_with_resource(input)
+    }
+    fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>
+    {
+        (**self).associate_principal_with_portfolio(input)
+    }
+    fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError> {
+        (**self).associate_product_with_portfolio(input)
+    }
+    fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        AssociateServiceActionWithProvisioningArtifactError,
+    > {
+        (**self).associate_service_action_with_provisioning_artifact(input)
+    }
+    fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>
+    {
+        (**self).associate_tag_option_with_resource(input)
+    }
+    fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        BatchAssociateServiceActionWithProvisioningArtifactError,
+    > {
+        (**self).batch_associate_service_action_with_provisioning_artifact(input)
+    }
+    fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        BatchDisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        (**self).batch_disassociate_service_action_from_provisioning_artifact(input)
+    }
+    fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> RusotoFuture<CopyProductOutput, CopyProductError> {
+        (**self).copy_product(input)
+    }
+    fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError> {
+        (**self).create_constraint(input)
+    }
+    fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError> {
+        (**self).create_portfolio(input)
+    }
+    fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError> {
+        (**self).create_portfolio_share(input)
+    }
+    fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> RusotoFuture<CreateProductOutput, CreateProductError> {
+        (**self).create_product(input)
+    }
+    fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError> {
+        (**self).create_provisioned_product_plan(input)
+    }
+    fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError> {
+        (**self).create_provisioning_artifact(input)
+    }
+    fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError> {
+        (**self).create_service_action(input)
+    }
+    fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError> {
+        (**self).create_tag_option(input)
+    }
+    fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError> {
+        (**self).delete_constraint(input)
+    }
+    fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError> {
+        (**self).delete_portfolio(input)
+    }
+    fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError> {
+        (**self).delete_portfolio_share(input)
+    }
+    fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError> {
+        (**self).delete_product(input)
+    }
+    fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError> {
+        (**self).delete_provisioned_product_plan(input)
+    }
+    fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError> {
+        (**self).delete_provisioning_artifact(input)
+    }
+    fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError> {
+        (**self).delete_service_action(input)
+    }
+    fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError> {
+        (**self).delete_tag_option(input)
+    }
+    fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError> {
+        (**self).describe_constraint(input)
+    }
+    fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError> {
+        (**self).describe_copy_product_status(input)
+    }
+    fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError> {
+        (**self).describe_portfolio(input)
+    }
+    fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError> {
+        (**self).describe_portfolio_share_status(input)
+    }
+    fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError> {
+        (**self).describe_product(input)
+    }
+    fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError> {
+        (**self).describe_product_as_admin(input)
+    }
+    fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError> {
+        (**self).describe_product_view(input)
+    }
+    fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError> {
+        (**self).describe_provisioned_product(input)
+    }
+    fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>
+    {
+        (**self).describe_provisioned_product_plan(input)
+    }
+    fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError> {
+        (**self).describe_provisioning_artifact(input)
+    }
+    fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>
+    {
+        (**self).describe_provisioning_parameters(input)
+    }
+    fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError> {
+        (**self).describe_record(input)
+    }
+    fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError> {
+        (**self).describe_service_action(input)
+    }
+    fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> RusotoFuture<
+        DescribeServiceActionExecutionParametersOutput,
+        DescribeServiceActionExecutionParametersError,
+    > {
+        (**self).describe_service_action_execution_parameters(input)
+    }
+    fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError> {
+        (**self).describe_tag_option(input)
+    }
+    fn disable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError> {
+        (**self).disable_aws_organizations_access()
+    }
+    fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>
+    {
+        (**self).disassociate_budget_from_resource(input)
+    }
+    fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> RusotoFuture<
+        DisassociatePrincipalFromPortfolioOutput,
+        DisassociatePrincipalFromPortfolioError,
+    > {
+        (**self).disassociate_principal_from_portfolio(input)
+    }
+    fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>
+    {
+        (**self).disassociate_product_from_portfolio(input)
+    }
+    fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        DisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        (**self).disassociate_service_action_from_provisioning_artifact(input)
+    }
+    fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>
+    {
+        (**self).disassociate_tag_option_from_resource(input)
+    }
+    fn enable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError> {
+        (**self).enable_aws_organizations_access()
+    }
+    fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError> {
+        (**self).execute_provisioned_product_plan(input)
+    }
+    fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> RusotoFuture<
+        ExecuteProvisionedProductServiceActionOutput,
+        ExecuteProvisionedProductServiceActionError,
+    > {
+        (**self).execute_provisioned_product_service_action(input)
+    }
+    fn get_aws_organizations_access_status(
+        &self,
+    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>
+    {
+        (**self).get_aws_organizations_access_status()
+    }
+    fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError> {
+        (**self).list_accepted_portfolio_shares(input)
+    }
+    fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError> {
+        (**self).list_budgets_for_resource(input)
+    }
+    fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError> {
+        (**self).list_constraints_for_portfolio(input)
+    }
+    fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError> {
+        (**self).list_launch_paths(input)
+    }
+    fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>
+    {
+        (**self).list_organization_portfolio_access(input)
+    }
+    fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError> {
+        (**self).list_portfolio_access(input)
+    }
+    fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError> {
+        (**self).list_portfolios(input)
+    }
+    fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError> {
+        (**self).list_portfolios_for_product(input)
+    }
+    fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError> {
+        (**self).list_principals_for_portfolio(input)
+    }
+    fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError> {
+        (**self).list_provisioned_product_plans(input)
+    }
+    fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError> {
+        (**self).list_provisioning_artifacts(input)
+    }
+    fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> RusotoFuture<
+        ListProvisioningArtifactsForServiceActionOutput,
+        ListProvisioningArtifactsForServiceActionError,
+    > {
+        (**self).list_provisioning_artifacts_for_service_action(input)
+    }
+    fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError> {
+        (**self).list_record_history(input)
+    }
+    fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError> {
+        (**self).list_resources_for_tag_option(input)
+    }
+    fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError> {
+        (**self).list_service_actions(input)
+    }
+    fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        ListServiceActionsForProvisioningArtifactOutput,
+        ListServiceActionsForProvisioningArtifactError,
+    > {
+        (**self).list_service_actions_for_provisioning_artifact(input)
+    }
+    fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> RusotoFuture<
+        ListStackInstancesForProvisionedProductOutput,
+        ListStackInstancesForProvisionedProductError,
+    > {
+        (**self).list_stack_instances_for_provisioned_product(input)
+    }
+    fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError> {
+        (**self).list_tag_options(input)
+    }
+    fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError> {
+        (**self).provision_product(input)
+    }
+    fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError> {
+        (**self).reject_portfolio_share(input)
+    }
+    fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError> {
+        (**self).scan_provisioned_products(input)
+    }
+    fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError> {
+        (**self).search_products(input)
+    }
+    fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError> {
+        (**self).search_products_as_admin(input)
+    }
+    fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError> {
+        (**self).search_provisioned_products(input)
+    }
+    fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError> {
+        (**self).terminate_provisioned_product(input)
+    }
+    fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError> {
+        (**self).update_constraint(input)
+    }
+    fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError> {
+        (**self).update_portfolio(input)
+    }
+    fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError> {
+        (**self).update_product(input)
+    }
+    fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError> {
+        (**self).update_provisioned_product(input)
+    }
+    fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> RusotoFuture<
+        UpdateProvisionedProductPropertiesOutput,
+        UpdateProvisionedProductPropertiesError,
+    > {
+        (**self).update_provisioned_product_properties(input)
+    }
+    fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError> {
+        (**self).update_provisioning_artifact(input)
+    }
+    fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError> {
+        (**self).update_service_action(input)
+    }
+    fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError> {
+        (**self).update_tag_option(input)
+    }
+}
+impl<T: ServiceCatalog + ?Sized> ServiceCatalog for ::std::sync::Arc<T> {
+    fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError> {
+        (**self).accept_portfolio_share(input)
+    }
+    fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError> {
+        (**self).associate_budget_with_resource(input)
+    }
+    fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>
+    {
+        (**self).associate_principal_with_portfolio(input)
+    }
+    fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError> {
+        (**self).associate_product_with_portfolio(input)
+    }
+    fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        AssociateServiceActionWithProvisioningArtifactError,
+    > {
+        (**self).associate_service_action_with_provisioning_artifact(input)
+    }
+    fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>
+    {
+        (**self).associate_tag_option_with_resource(input)
+    }
+    fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        BatchAssociateServiceActionWithProvisioningArtifactError,
+    > {
+        (**self).batch_associate_service_action_with_provisioning_artifact(input)
+    }
+    fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        BatchDisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        (**self).batch_disassociate_service_action_from_provisioning_artifact(input)
+    }
+    fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> RusotoFuture<CopyProductOutput, CopyProductError> {
+        (**self).copy_product(input)
+    }
+    fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError> {
+        (**self).create_constraint(input)
+    }
+    fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError> {
+        (**self).create_portfolio(input)
+    }
+    fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError> {
+        (**self).create_portfolio_share(input)
+    }
+    fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> RusotoFuture<CreateProductOutput, CreateProductError> {
+        (**self).create_product(input)
+    }
+    fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError> {
+        (**self).create_provisioned_product_plan(input)
+    }
+    fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError> {
+        (**self).create_provisioning_artifact(input)
+    }
+    fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError> {
+        (**self).create_service_action(input)
+    }
+    fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError> {
+        (**self).create_tag_option(input)
+    }
+    fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError> {
+        (**self).delete_constraint(input)
+    }
+    fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError> {
+        (**self).delete_portfolio(input)
+    }
+    fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError> {
+        (**self).delete_portfolio_share(input)
+    }
+    fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError> {
+        (**self).delete_product(input)
+    }
+    fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError> {
+        (**self).delete_provisioned_product_plan(input)
+    }
+    fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError> {
+        (**self).delete_provisioning_artifact(input)
+    }
+    fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError> {
+        (**self).delete_service_action(input)
+    }
+    fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError> {
+        (**self).delete_tag_option(input)
+    }
+    fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError> {
+        (**self).describe_constraint(input)
+    }
+    fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError> {
+        (**self).describe_copy_product_status(input)
+    }
+    fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError> {
+        (**self).describe_portfolio(input)
+    }
+    fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError> {
+        (**self).describe_portfolio_share_status(input)
+    }
+    fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError> {
+        (**self).describe_product(input)
+    }
+    fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError> {
+        (**self).describe_product_as_admin(input)
+    }
+    fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError> {
+        (**self).describe_product_view(input)
+    }
+    fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError> {
+        (**self).describe_provisioned_product(input)
+    }
+    fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>
+    {
+        (**self).describe_provisioned_product_plan(input)
+    }
+    fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError> {
+        (**self).describe_provisioning_artifact(input)
+    }
+    fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>
+    {
+        (**self).describe_provisioning_parameters(input)
+    }
+    fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError> {
+        (**self).describe_record(input)
+    }
+    fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError> {
+        (**self).describe_service_action(input)
+    }
+    fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> RusotoFuture<
+        DescribeServiceActionExecutionParametersOutput,
+        DescribeServiceActionExecutionParametersError,
+    > {
+        (**self).describe_service_action_execution_parameters(input)
+    }
+    fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError> {
+        (**self).describe_tag_option(input)
+    }
+    fn disable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError> {
+        (**self).disable_aws_organizations_access()
+    }
+    fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>
+    {
+        (**self).disassociate_budget_from_resource(input)
+    }
+    fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> RusotoFuture<
+        DisassociatePrincipalFromPortfolioOutput,
+        DisassociatePrincipalFromPortfolioError,
+    > {
+        (**self).disassociate_principal_from_portfolio(input)
+    }
+    fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>
+    {
+        (**self).disassociate_product_from_portfolio(input)
+    }
+    fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        DisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        (**self).disassociate_service_action_from_provisioning_artifact(input)
+    }
+    fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>
+    {
+        (**self).disassociate_tag_option_from_resource(input)
+    }
+    fn enable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError> {
+        (**self).enable_aws_organizations_access()
+    }
+    fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError> {
+        (**self).execute_provisioned_product_plan(input)
+    }
+    fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> RusotoFuture<
+        ExecuteProvisionedProductServiceActionOutput,
+        ExecuteProvisionedProductServiceActionError,
+    > {
+        (**self).execute_provisioned_product_service_action(input)
+    }
+    fn get_aws_organizations_access_status(
+        &self,
+    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>
+    {
+        (**self).get_aws_organizations_access_status()
+    }
+    fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError> {
+        (**self).list_accepted_portfolio_shares(input)
+    }
+    fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError> {
+        (**self).list_budgets_for_resource(input)
+    }
+    fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError> {
+        (**self).list_constraints_for_portfolio(input)
+    }
+    fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError> {
+        (**self).list_launch_paths(input)
+    }
+    fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>
+    {
+        (**self).list_organization_portfolio_access(input)
+    }
+    fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError> {
+        (**self).list_portfolio_access(input)
+    }
+    fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError> {
+        (**self).list_portfolios(input)
+    }
+    fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError> {
+        (**self).list_portfolios_for_product(input)
+    }
+    fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError> {
+        (**self).list_principals_for_portfolio(input)
+    }
+    fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError> {
+        (**self).list_provisioned_product_plans(input)
+    }
+    fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError> {
+        (**self).list_provisioning_artifacts(input)
+    }
+    fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> RusotoFuture<
+        ListProvisioningArtifactsForServiceActionOutput,
+        ListProvisioningArtifactsForServiceActionError,
+    > {
+        (**self).list_provisioning_artifacts_for_service_action(input)
+    }
+    fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError> {
+        (**self).list_record_history(input)
+    }
+    fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError> {
+        (**self).list_resources_for_tag_option(input)
+    }
+    fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError> {
+        (**self).list_service_actions(input)
+    }
+    fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        ListServiceActionsForProvisioningArtifactOutput,
+        ListServiceActionsForProvisioningArtifactError,
+    > {
+        (**self).list_service_actions_for_provisioning_artifact(input)
+    }
+    fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> RusotoFuture<
+        ListStackInstancesForProvisionedProductOutput,
+        ListStackInstancesForProvisionedProductError,
+    > {
+        (**self).list_stack_instances_for_provisioned_product(input)
+    }
+    fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError> {
+        (**self).list_tag_options(input)
+    }
+    fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError> {
+        (**self).provision_product(input)
+    }
+    fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError> {
+        (**self).reject_portfolio_share(input)
+    }
+    fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError> {
+        (**self).scan_provisioned_products(input)
+    }
+    fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError> {
+        (**self).search_products(input)
+    }
+    fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError> {
+        (**self).search_products_as_admin(input)
+    }
+    fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError> {
+        (**self).search_provisioned_products(input)
+    }
+    fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError> {
+        (**self).terminate_provisioned_product(input)
+    }
+    fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError> {
+        (**self).update_constraint(input)
+    }
+    fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError> {
+        (**self).update_portfolio(input)
+    }
+    fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError> {
+        (**self).update_product(input)
+    }
+    fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError> {
+        (**self).update_provisioned_product(input)
+    }
+    fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> RusotoFuture<
+        UpdateProvisionedProductPropertiesOutput,
+        UpdateProvisionedProductPropertiesError,
+    > {
+        (**self).update_provisioned_product_properties(input)
+    }
+    fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError> {
+        (**self).update_provisioning_artifact(input)
+    }
+    fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError> {
+        (**self).update_service_action(input)
+    }
+    fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError> {
+        (**self).update_tag_option(input)
+    }
+}
+/// A client for the AWS Service Catalog API.
+#[derive(Clone)]
+pub struct ServiceCatalogClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ServiceCatalogClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServiceCatalogClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceCatalogClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogClient {
+        ServiceCatalogClient { client, region }
+    }
+}
+
+impl ServiceCatalog for ServiceCatalogClient {
+    /// <p>Accepts an offer to share the specified portfolio.</p>
+    fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AcceptPortfolioShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.AcceptPortfolioShare",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AcceptPortfolioShareOutput, _>(
+                    response,
+                    "AcceptPortfolioShare",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AcceptPortfolioShareError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified budget with the specified resource.</p>
+    fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateBudgetWithResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.AssociateBudgetWithResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateBudgetWithResourceOutput, _>(
+                    response,
+                    "AssociateBudgetWithResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateBudgetWithResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
+    fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociatePrincipalWithPortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.AssociatePrincipalWithPortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociatePrincipalWithPortfolioOutput, _>(
+                    response,
+                    "AssociatePrincipalWithPortfolio",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociatePrincipalWithPortfolioError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates the specified product with the specified portfolio.</p>
+    fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateProductWithPortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.AssociateProductWithPortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateProductWithPortfolioOutput, _>(
+                    response,
+                    "AssociateProductWithPortfolio",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateProductWithPortfolioError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates a self-service action with a provisioning artifact.</p>
+    fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        AssociateServiceActionWithProvisioningArtifactError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateServiceActionWithProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.AssociateServiceActionWithProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    AssociateServiceActionWithProvisioningArtifactOutput,
+                    _,
+                >(response, "AssociateServiceActionWithProvisioningArtifact")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        AssociateServiceActionWithProvisioningArtifactError::from_response(
+                            response,
+                        ),
+                    )
+                }))
+            }
+        })
+    }
+
+    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
+    fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("AssociateTagOptionWithResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.AssociateTagOptionWithResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateTagOptionWithResourceOutput, _>(
+                    response,
+                    "AssociateTagOptionWithResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateTagOptionWithResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
+    fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        BatchAssociateServiceActionWithProvisioningArtifactError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("BatchAssociateServiceActionWithProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.BatchAssociateServiceActionWithProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    BatchAssociateServiceActionWithProvisioningArtifactOutput,
+                    _,
+                >(
+                    response,
+                    "BatchAssociateServiceActionWithProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        BatchAssociateServiceActionWithProvisioningArtifactError::from_response(
+                            response,
+                        ),
+                    )
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
+    fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        BatchDisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("BatchDisassociateServiceActionFromProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.BatchDisassociateServiceActionFromProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+                    _,
+                >(
+                    response,
+                    "BatchDisassociateServiceActionFromProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        BatchDisassociateServiceActionFromProvisioningArtifactError::from_response(
+                            response,
+                        ),
+                    )
+                }))
+            }
+        })
+    }
+
+    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
+    fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> RusotoFuture<CopyProductOutput, CopyProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CopyProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CopyProductOutput, _>(response, "CopyProduct")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CopyProductError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a constraint.</p>
+    fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateConstraint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreateConstraint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateConstraintOutput, _>(
+                    response,
+                    "CreateConstraint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateConstraintError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a portfolio.</p>
+    fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreatePortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreatePortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePortfolioOutput, _>(
+                    response,
+                    "CreatePortfolio",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreatePortfolioError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an Organization. AWSOrganizationsAccess must be enabled in order to create a portfolio share to an organization node.</p>
+    fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreatePortfolioShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreatePortfolioShare",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePortfolioShareOutput, _>(
+                    response,
+                    "CreatePortfolioShare",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreatePortfolioShareError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a product.</p>
+    fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> RusotoFuture<CreateProductOutput, CreateProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProductOutput, _>(
+                    response,
+                    "CreateProduct",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateProductError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
+    fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProvisionedProductPlan");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreateProvisionedProductPlan",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProvisionedProductPlanOutput, _>(
+                    response,
+                    "CreateProvisionedProductPlan",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateProvisionedProductPlanError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
+    fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreateProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProvisioningArtifactOutput, _>(
+                    response,
+                    "CreateProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateProvisioningArtifactError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a self-service action.</p>
+    fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateServiceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreateServiceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateServiceActionOutput, _>(
+                    response,
+                    "CreateServiceAction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateServiceActionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a TagOption.</p>
+    fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("CreateTagOption");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.CreateTagOption",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTagOptionOutput, _>(
+                    response,
+                    "CreateTagOption",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTagOptionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified constraint.</p>
+    fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteConstraint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeleteConstraint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteConstraintOutput, _>(
+                    response,
+                    "DeleteConstraint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteConstraintError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p>
+    fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeletePortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeletePortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeletePortfolioOutput, _>(
+                    response,
+                    "DeletePortfolio",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeletePortfolioError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an Organization.</p>
+    fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeletePortfolioShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeletePortfolioShare",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeletePortfolioShareOutput, _>(
+                    response,
+                    "DeletePortfolioShare",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeletePortfolioShareError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p>
+    fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.DeleteProduct");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProductOutput, _>(
+                    response,
+                    "DeleteProduct",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteProductError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified plan.</p>
+    fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProvisionedProductPlan");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeleteProvisionedProductPlan",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProvisionedProductPlanOutput, _>(
+                    response,
+                    "DeleteProvisionedProductPlan",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteProvisionedProductPlanError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
+    fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeleteProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProvisioningArtifactOutput, _>(
+                    response,
+                    "DeleteProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteProvisioningArtifactError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes a self-service action.</p>
+    fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteServiceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeleteServiceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteServiceActionOutput, _>(
+                    response,
+                    "DeleteServiceAction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteServiceActionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
+    fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DeleteTagOption");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DeleteTagOption",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTagOptionOutput, _>(
+                    response,
+                    "DeleteTagOption",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagOptionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified constraint.</p>
+    fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeConstraint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeConstraint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeConstraintOutput, _>(
+                    response,
+                    "DescribeConstraint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeConstraintError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the status of the specified copy product operation.</p>
+    fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeCopyProductStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeCopyProductStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCopyProductStatusOutput, _>(
+                    response,
+                    "DescribeCopyProductStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCopyProductStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified portfolio.</p>
+    fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribePortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribePortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePortfolioOutput, _>(
+                    response,
+                    "DescribePortfolio",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribePortfolioError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization.</p>
+    fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribePortfolioShareStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribePortfolioShareStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePortfolioShareStatusOutput, _>(
+                    response,
+                    "DescribePortfolioShareStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePortfolioShareStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified product.</p>
+    fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProductOutput, _>(
+                    response,
+                    "DescribeProduct",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeProductError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
+    fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProductAsAdmin");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProductAsAdmin",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProductAsAdminOutput, _>(
+                    response,
+                    "DescribeProductAsAdmin",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeProductAsAdminError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified product.</p>
+    fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProductView");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProductView",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProductViewOutput, _>(
+                    response,
+                    "DescribeProductView",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeProductViewError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified provisioned product.</p>
+    fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisionedProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProvisionedProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProvisionedProductOutput, _>(
+                    response,
+                    "DescribeProvisionedProduct",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeProvisionedProductError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the resource changes for the specified plan.</p>
+    fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisionedProductPlan");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProvisionedProductPlan",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProvisionedProductPlanOutput, _>(
+                    response,
+                    "DescribeProvisionedProductPlan",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeProvisionedProductPlanError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
+    fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProvisioningArtifactOutput, _>(
+                    response,
+                    "DescribeProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeProvisioningArtifactError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
+    fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeProvisioningParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeProvisioningParameters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeProvisioningParametersOutput, _>(
+                    response,
+                    "DescribeProvisioningParameters",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeProvisioningParametersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
+    fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeRecord");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.DescribeRecord");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeRecordOutput, _>(
+                    response,
+                    "DescribeRecord",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeRecordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes a self-service action.</p>
+    fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeServiceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeServiceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeServiceActionOutput, _>(
+                    response,
+                    "DescribeServiceAction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeServiceActionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> RusotoFuture<
+        DescribeServiceActionExecutionParametersOutput,
+        DescribeServiceActionExecutionParametersError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeServiceActionExecutionParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeServiceActionExecutionParameters",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeServiceActionExecutionParametersOutput,
+                    _,
+                >(response, "DescribeServiceActionExecutionParameters")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeServiceActionExecutionParametersError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the specified TagOption.</p>
+    fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DescribeTagOption");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DescribeTagOption",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTagOptionOutput, _>(
+                    response,
+                    "DescribeTagOption",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagOptionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p>
+    fn disable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError> {
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisableAWSOrganizationsAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DisableAWSOrganizationsAccess",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisableAWSOrganizationsAccessOutput, _>(
+                    response,
+                    "DisableAWSOrganizationsAccess",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisableAWSOrganizationsAccessError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified budget from the specified resource.</p>
+    fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateBudgetFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DisassociateBudgetFromResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateBudgetFromResourceOutput, _>(
+                    response,
+                    "DisassociateBudgetFromResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateBudgetFromResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
+    fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> RusotoFuture<
+        DisassociatePrincipalFromPortfolioOutput,
+        DisassociatePrincipalFromPortfolioError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociatePrincipalFromPortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DisassociatePrincipalFromPortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociatePrincipalFromPortfolioOutput, _>(
+                    response,
+                    "DisassociatePrincipalFromPortfolio",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociatePrincipalFromPortfolioError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified product from the specified portfolio. </p>
+    fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateProductFromPortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DisassociateProductFromPortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateProductFromPortfolioOutput, _>(
+                    response,
+                    "DisassociateProductFromPortfolio",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateProductFromPortfolioError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
+    fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        DisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateServiceActionFromProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DisassociateServiceActionFromProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DisassociateServiceActionFromProvisioningArtifactOutput,
+                    _,
+                >(
+                    response,
+                    "DisassociateServiceActionFromProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        DisassociateServiceActionFromProvisioningArtifactError::from_response(
+                            response,
+                        ),
+                    )
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified TagOption from the specified resource.</p>
+    fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("DisassociateTagOptionFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.DisassociateTagOptionFromResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateTagOptionFromResourceOutput, _>(
+                    response,
+                    "DisassociateTagOptionFromResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateTagOptionFromResourceError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p>
+    fn enable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError> {
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("EnableAWSOrganizationsAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.EnableAWSOrganizationsAccess",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<EnableAWSOrganizationsAccessOutput, _>(
+                    response,
+                    "EnableAWSOrganizationsAccess",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(EnableAWSOrganizationsAccessError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
+    fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ExecuteProvisionedProductPlan");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ExecuteProvisionedProductPlan",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ExecuteProvisionedProductPlanOutput, _>(
+                    response,
+                    "ExecuteProvisionedProductPlan",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ExecuteProvisionedProductPlanError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Executes a self-service action against a provisioned product.</p>
+    fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> RusotoFuture<
+        ExecuteProvisionedProductServiceActionOutput,
+        ExecuteProvisionedProductServiceActionError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ExecuteProvisionedProductServiceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ExecuteProvisionedProductServiceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ExecuteProvisionedProductServiceActionOutput, _>(response, "ExecuteProvisionedProductServiceAction")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ExecuteProvisionedProductServiceActionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization.</p>
+    fn get_aws_organizations_access_status(
+        &self,
+    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>
+    {
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("GetAWSOrganizationsAccessStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.GetAWSOrganizationsAccessStatus",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAWSOrganizationsAccessStatusOutput, _>(
+                    response,
+                    "GetAWSOrganizationsAccessStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAWSOrganizationsAccessStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
+    fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListAcceptedPortfolioShares");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListAcceptedPortfolioShares",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAcceptedPortfolioSharesOutput, _>(
+                    response,
+                    "ListAcceptedPortfolioShares",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAcceptedPortfolioSharesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all the budgets associated to the specified resource.</p>
+    fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListBudgetsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListBudgetsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListBudgetsForResourceOutput, _>(
+                    response,
+                    "ListBudgetsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListBudgetsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the constraints for the specified portfolio and product.</p>
+    fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListConstraintsForPortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListConstraintsForPortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListConstraintsForPortfolioOutput, _>(
+                    response,
+                    "ListConstraintsForPortfolio",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListConstraintsForPortfolioError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
+    fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListLaunchPaths");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListLaunchPaths",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListLaunchPathsOutput, _>(
+                    response,
+                    "ListLaunchPaths",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListLaunchPathsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization.</p>
+    fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListOrganizationPortfolioAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListOrganizationPortfolioAccess",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOrganizationPortfolioAccessOutput, _>(
+                    response,
+                    "ListOrganizationPortfolioAccess",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListOrganizationPortfolioAccessError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the account IDs that have access to the specified portfolio.</p>
+    fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfolioAccess");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListPortfolioAccess",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPortfolioAccessOutput, _>(
+                    response,
+                    "ListPortfolioAccess",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListPortfolioAccessError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all portfolios in the catalog.</p>
+    fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfolios");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListPortfolios");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPortfoliosOutput, _>(
+                    response,
+                    "ListPortfolios",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListPortfoliosError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists all portfolios that the specified product is associated with.</p>
+    fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPortfoliosForProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListPortfoliosForProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPortfoliosForProductOutput, _>(
+                    response,
+                    "ListPortfoliosForProduct",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPortfoliosForProductError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
+    fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListPrincipalsForPortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListPrincipalsForPortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListPrincipalsForPortfolioOutput, _>(
+                    response,
+                    "ListPrincipalsForPortfolio",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPrincipalsForPortfolioError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
+    fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisionedProductPlans");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListProvisionedProductPlans",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListProvisionedProductPlansOutput, _>(
+                    response,
+                    "ListProvisionedProductPlans",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListProvisionedProductPlansError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
+    fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisioningArtifacts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListProvisioningArtifacts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListProvisioningArtifactsOutput, _>(
+                    response,
+                    "ListProvisioningArtifacts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListProvisioningArtifactsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
+    fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> RusotoFuture<
+        ListProvisioningArtifactsForServiceActionOutput,
+        ListProvisioningArtifactsForServiceActionError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListProvisioningArtifactsForServiceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListProvisioningArtifactsForServiceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    ListProvisioningArtifactsForServiceActionOutput,
+                    _,
+                >(response, "ListProvisioningArtifactsForServiceAction")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListProvisioningArtifactsForServiceActionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the specified requests or all performed requests.</p>
+    fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListRecordHistory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListRecordHistory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRecordHistoryOutput, _>(
+                    response,
+                    "ListRecordHistory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRecordHistoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the resources associated with the specified TagOption.</p>
+    fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListResourcesForTagOption");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListResourcesForTagOption",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourcesForTagOptionOutput, _>(
+                    response,
+                    "ListResourcesForTagOption",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListResourcesForTagOptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all self-service actions.</p>
+    fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListServiceActions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListServiceActions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListServiceActionsOutput, _>(
+                    response,
+                    "ListServiceActions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListServiceActionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
+    fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        ListServiceActionsForProvisioningArtifactOutput,
+        ListServiceActionsForProvisioningArtifactError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListServiceActionsForProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListServiceActionsForProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    ListServiceActionsForProvisioningArtifactOutput,
+                    _,
+                >(response, "ListServiceActionsForProvisioningArtifact")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListServiceActionsForProvisioningArtifactError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
+    fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> RusotoFuture<
+        ListStackInstancesForProvisionedProductOutput,
+        ListStackInstancesForProvisionedProductError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListStackInstancesForProvisionedProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ListStackInstancesForProvisionedProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListStackInstancesForProvisionedProductOutput, _>(response, "ListStackInstancesForProvisionedProduct")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListStackInstancesForProvisionedProductError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the specified TagOptions or all TagOptions.</p>
+    fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ListTagOptions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListTagOptions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagOptionsOutput, _>(
+                    response,
+                    "ListTagOptions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTagOptionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
+    fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ProvisionProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ProvisionProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ProvisionProductOutput, _>(
+                    response,
+                    "ProvisionProduct",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ProvisionProductError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Rejects an offer to share the specified portfolio.</p>
+    fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("RejectPortfolioShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.RejectPortfolioShare",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RejectPortfolioShareOutput, _>(
+                    response,
+                    "RejectPortfolioShare",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RejectPortfolioShareError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
+    fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("ScanProvisionedProducts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.ScanProvisionedProducts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ScanProvisionedProductsOutput, _>(
+                    response,
+                    "ScanProvisionedProducts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ScanProvisionedProductsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets information about the products to which the caller has access.</p>
+    fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProducts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.SearchProducts");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchProductsOutput, _>(
+                    response,
+                    "SearchProducts",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SearchProductsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the products for the specified portfolio or all products.</p>
+    fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProductsAsAdmin");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.SearchProductsAsAdmin",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchProductsAsAdminOutput, _>(
+                    response,
+                    "SearchProductsAsAdmin",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SearchProductsAsAdminError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
+    fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("SearchProvisionedProducts");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.SearchProvisionedProducts",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SearchProvisionedProductsOutput, _>(
+                    response,
+                    "SearchProvisionedProducts",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SearchProvisionedProductsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("TerminateProvisionedProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.TerminateProvisionedProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TerminateProvisionedProductOutput, _>(
+                    response,
+                    "TerminateProvisionedProduct",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(TerminateProvisionedProductError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the specified constraint.</p>
+    fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateConstraint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdateConstraint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateConstraintOutput, _>(
+                    response,
+                    "UpdateConstraint",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateConstraintError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
+    fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdatePortfolio");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdatePortfolio",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePortfolioOutput, _>(
+                    response,
+                    "UpdatePortfolio",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdatePortfolioError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified product.</p>
+    fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> RusotoFuture<UpdateProductOutput, UpdateProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWS242ServiceCatalogService.UpdateProduct");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateProductOutput, _>(
+                    response,
+                    "UpdateProduct",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateProductError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisionedProduct");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdateProvisionedProduct",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateProvisionedProductOutput, _>(
+                    response,
+                    "UpdateProvisionedProduct",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateProvisionedProductError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Requests updates to the properties of the specified provisioned product.</p>
+    fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> RusotoFuture<
+        UpdateProvisionedProductPropertiesOutput,
+        UpdateProvisionedProductPropertiesError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisionedProductProperties");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdateProvisionedProductProperties",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateProvisionedProductPropertiesOutput, _>(
+                    response,
+                    "UpdateProvisionedProductProperties",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateProvisionedProductPropertiesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
+    fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateProvisioningArtifact");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdateProvisioningArtifact",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateProvisioningArtifactOutput, _>(
+                    response,
+                    "UpdateProvisioningArtifact",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateProvisioningArtifactError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a self-service action.</p>
+    fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateServiceAction");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdateServiceAction",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateServiceActionOutput, _>(
+                    response,
+                    "UpdateServiceAction",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateServiceActionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified TagOption.</p>
+    fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
+        request.set_operation("UpdateTagOption");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWS242ServiceCatalogService.UpdateTagOption",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateTagOptionOutput, _>(
+                    response,
+                    "UpdateTagOption",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTagOptionError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Service Catalog API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockServiceCatalog {
+    accept_portfolio_share: Option<
+        Box<
+            dyn Fn(
+                    AcceptPortfolioShareInput,
+                ) -> ::std::result::Result<
+                    AcceptPortfolioShareOutput,
+                    RusotoError<AcceptPortfolioShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_budget_with_resource: Option<
+        Box<
+            dyn Fn(
+                    AssociateBudgetWithResourceInput,
+                ) -> ::std::result::Result<
+                    AssociateBudgetWithResourceOutput,
+                    RusotoError<AssociateBudgetWithResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_principal_with_portfolio: Option<
+        Box<
+            dyn Fn(
+                    AssociatePrincipalWithPortfolioInput,
+                ) -> ::std::result::Result<
+                    AssociatePrincipalWithPortfolioOutput,
+                    RusotoError<AssociatePrincipalWithPortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_product_with_portfolio: Option<
+        Box<
+            dyn Fn(
+                    AssociateProductWithPortfolioInput,
+                ) -> ::std::result::Result<
+                    AssociateProductWithPortfolioOutput,
+                    RusotoError<AssociateProductWithPortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_service_action_with_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    AssociateServiceActionWithProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    AssociateServiceActionWithProvisioningArtifactOutput,
+                    RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_tag_option_with_resource: Option<
+        Box<
+            dyn Fn(
+                    AssociateTagOptionWithResourceInput,
+                ) -> ::std::result::Result<
+                    AssociateTagOptionWithResourceOutput,
+                    RusotoError<AssociateTagOptionWithResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_associate_service_action_with_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    BatchAssociateServiceActionWithProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    BatchAssociateServiceActionWithProvisioningArtifactOutput,
+                    RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    batch_disassociate_service_action_from_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    BatchDisassociateServiceActionFromProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+                    RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_product: Option<
+        Box<
+            dyn Fn(
+                    CopyProductInput,
+                )
+                    -> ::std::result::Result<CopyProductOutput, RusotoError<CopyProductError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_constraint: Option<
+        Box<
+            dyn Fn(
+                    CreateConstraintInput,
+                ) -> ::std::result::Result<
+                    CreateConstraintOutput,
+                    RusotoError<CreateConstraintError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_portfolio: Option<
+        Box<
+            dyn Fn(
+                    CreatePortfolioInput,
+                ) -> ::std::result::Result<
+                    CreatePortfolioOutput,
+                    RusotoError<CreatePortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_portfolio_share: Option<
+        Box<
+            dyn Fn(
+                    CreatePortfolioShareInput,
+                ) -> ::std::result::Result<
+                    CreatePortfolioShareOutput,
+                    RusotoError<CreatePortfolioShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_product: Option<
+        Box<
+            dyn Fn(
+                    CreateProductInput,
+                )
+                    -> ::std::result::Result<CreateProductOutput, RusotoError<CreateProductError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_provisioned_product_plan: Option<
+        Box<
+            dyn Fn(
+                    CreateProvisionedProductPlanInput,
+                ) -> ::std::result::Result<
+                    CreateProvisionedProductPlanOutput,
+                    RusotoError<CreateProvisionedProductPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    CreateProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    CreateProvisioningArtifactOutput,
+                    RusotoError<CreateProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_service_action: Option<
+        Box<
+            dyn Fn(
+                    CreateServiceActionInput,
+                ) -> ::std::result::Result<
+                    CreateServiceActionOutput,
+                    RusotoError<CreateServiceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tag_option: Option<
+        Box<
+            dyn Fn(
+                    CreateTagOptionInput,
+                ) -> ::std::result::Result<
+                    CreateTagOptionOutput,
+                    RusotoError<CreateTagOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_constraint: Option<
+        Box<
+            dyn Fn(
+                    DeleteConstraintInput,
+                ) -> ::std::result::Result<
+                    DeleteConstraintOutput,
+                    RusotoError<DeleteConstraintError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_portfolio: Option<
+        Box<
+            dyn Fn(
+                    DeletePortfolioInput,
+                ) -> ::std::result::Result<
+                    DeletePortfolioOutput,
+                    RusotoError<DeletePortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_portfolio_share: Option<
+        Box<
+            dyn Fn(
+                    DeletePortfolioShareInput,
+                ) -> ::std::result::Result<
+                    DeletePortfolioShareOutput,
+                    RusotoError<DeletePortfolioShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_product: Option<
+        Box<
+            dyn Fn(
+                    DeleteProductInput,
+                )
+                    -> ::std::result::Result<DeleteProductOutput, RusotoError<DeleteProductError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_provisioned_product_plan: Option<
+        Box<
+            dyn Fn(
+                    DeleteProvisionedProductPlanInput,
+                ) -> ::std::result::Result<
+                    DeleteProvisionedProductPlanOutput,
+                    RusotoError<DeleteProvisionedProductPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    DeleteProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    DeleteProvisioningArtifactOutput,
+                    RusotoError<DeleteProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_service_action: Option<
+        Box<
+            dyn Fn(
+                    DeleteServiceActionInput,
+                ) -> ::std::result::Result<
+                    DeleteServiceActionOutput,
+                    RusotoError<DeleteServiceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tag_option: Option<
+        Box<
+            dyn Fn(
+                    DeleteTagOptionInput,
+                ) -> ::std::result::Result<
+                    DeleteTagOptionOutput,
+                    RusotoError<DeleteTagOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_constraint: Option<
+        Box<
+            dyn Fn(
+                    DescribeConstraintInput,
+                ) -> ::std::result::Result<
+                    DescribeConstraintOutput,
+                    RusotoError<DescribeConstraintError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_copy_product_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeCopyProductStatusInput,
+                ) -> ::std::result::Result<
+                    DescribeCopyProductStatusOutput,
+                    RusotoError<DescribeCopyProductStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_portfolio: Option<
+        Box<
+            dyn Fn(
+                    DescribePortfolioInput,
+                ) -> ::std::result::Result<
+                    DescribePortfolioOutput,
+                    RusotoError<DescribePortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_portfolio_share_status: Option<
+        Box<
+            dyn Fn(
+                    DescribePortfolioShareStatusInput,
+                ) -> ::std::result::Result<
+                    DescribePortfolioShareStatusOutput,
+                    RusotoError<DescribePortfolioShareStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_product: Option<
+        Box<
+            dyn Fn(
+                    DescribeProductInput,
+                ) -> ::std::result::Result<
+                    DescribeProductOutput,
+                    RusotoError<DescribeProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_product_as_admin: Option<
+        Box<
+            dyn Fn(
+                    DescribeProductAsAdminInput,
+                ) -> ::std::result::Result<
+                    DescribeProductAsAdminOutput,
+                    RusotoError<DescribeProductAsAdminError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_product_view: Option<
+        Box<
+            dyn Fn(
+                    DescribeProductViewInput,
+                ) -> ::std::result::Result<
+                    DescribeProductViewOutput,
+                    RusotoError<DescribeProductViewError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_provisioned_product: Option<
+        Box<
+            dyn Fn(
+                    DescribeProvisionedProductInput,
+                ) -> ::std::result::Result<
+                    DescribeProvisionedProductOutput,
+                    RusotoError<DescribeProvisionedProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_provisioned_product_plan: Option<
+        Box<
+            dyn Fn(
+                    DescribeProvisionedProductPlanInput,
+                ) -> ::std::result::Result<
+                    DescribeProvisionedProductPlanOutput,
+                    RusotoError<DescribeProvisionedProductPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    DescribeProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    DescribeProvisioningArtifactOutput,
+                    RusotoError<DescribeProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_provisioning_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeProvisioningParametersInput,
+                ) -> ::std::result::Result<
+                    DescribeProvisioningParametersOutput,
+                    RusotoError<DescribeProvisioningParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_record: Option<
+        Box<
+            dyn Fn(
+                    DescribeRecordInput,
+                )
+                    -> ::std::result::Result<DescribeRecordOutput, RusotoError<DescribeRecordError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_service_action: Option<
+        Box<
+            dyn Fn(
+                    DescribeServiceActionInput,
+                ) -> ::std::result::Result<
+                    DescribeServiceActionOutput,
+                    RusotoError<DescribeServiceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_service_action_execution_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeServiceActionExecutionParametersInput,
+                ) -> ::std::result::Result<
+                    DescribeServiceActionExecutionParametersOutput,
+                    RusotoError<DescribeServiceActionExecutionParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tag_option: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagOptionInput,
+                ) -> ::std::result::Result<
+                    DescribeTagOptionOutput,
+                    RusotoError<DescribeTagOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disable_aws_organizations_access: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DisableAWSOrganizationsAccessOutput,
+                    RusotoError<DisableAWSOrganizationsAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_budget_from_resource: Option<
+        Box<
+            dyn Fn(
+                    DisassociateBudgetFromResourceInput,
+                ) -> ::std::result::Result<
+                    DisassociateBudgetFromResourceOutput,
+                    RusotoError<DisassociateBudgetFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_principal_from_portfolio: Option<
+        Box<
+            dyn Fn(
+                    DisassociatePrincipalFromPortfolioInput,
+                ) -> ::std::result::Result<
+                    DisassociatePrincipalFromPortfolioOutput,
+                    RusotoError<DisassociatePrincipalFromPortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_product_from_portfolio: Option<
+        Box<
+            dyn Fn(
+                    DisassociateProductFromPortfolioInput,
+                ) -> ::std::result::Result<
+                    DisassociateProductFromPortfolioOutput,
+                    RusotoError<DisassociateProductFromPortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_service_action_from_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    DisassociateServiceActionFromProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    DisassociateServiceActionFromProvisioningArtifactOutput,
+                    RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_tag_option_from_resource: Option<
+        Box<
+            dyn Fn(
+                    DisassociateTagOptionFromResourceInput,
+                ) -> ::std::result::Result<
+                    DisassociateTagOptionFromResourceOutput,
+                    RusotoError<DisassociateTagOptionFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    enable_aws_organizations_access: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    EnableAWSOrganizationsAccessOutput,
+                    RusotoError<EnableAWSOrganizationsAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    execute_provisioned_product_plan: Option<
+        Box<
+            dyn Fn(
+                    ExecuteProvisionedProductPlanInput,
+                ) -> ::std::result::Result<
+                    ExecuteProvisionedProductPlanOutput,
+                    RusotoError<ExecuteProvisionedProductPlanError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    execute_provisioned_product_service_action: Option<
+        Box<
+            dyn Fn(
+                    ExecuteProvisionedProductServiceActionInput,
+                ) -> ::std::result::Result<
+                    ExecuteProvisionedProductServiceActionOutput,
+                    RusotoError<ExecuteProvisionedProductServiceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_aws_organizations_access_status: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetAWSOrganizationsAccessStatusOutput,
+                    RusotoError<GetAWSOrganizationsAccessStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_accepted_portfolio_shares: Option<
+        Box<
+            dyn Fn(
+                    ListAcceptedPortfolioSharesInput,
+                ) -> ::std::result::Result<
+                    ListAcceptedPortfolioSharesOutput,
+                    RusotoError<ListAcceptedPortfolioSharesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_budgets_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListBudgetsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListBudgetsForResourceOutput,
+                    RusotoError<ListBudgetsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_constraints_for_portfolio: Option<
+        Box<
+            dyn Fn(
+                    ListConstraintsForPortfolioInput,
+                ) -> ::std::result::Result<
+                    ListConstraintsForPortfolioOutput,
+                    RusotoError<ListConstraintsForPortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_launch_paths: Option<
+        Box<
+            dyn Fn(
+                    ListLaunchPathsInput,
+                ) -> ::std::result::Result<
+                    ListLaunchPathsOutput,
+                    RusotoError<ListLaunchPathsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_organization_portfolio_access: Option<
+        Box<
+            dyn Fn(
+                    ListOrganizationPortfolioAccessInput,
+                ) -> ::std::result::Result<
+                    ListOrganizationPortfolioAccessOutput,
+                    RusotoError<ListOrganizationPortfolioAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_portfolio_access: Option<
+        Box<
+            dyn Fn(
+                    ListPortfolioAccessInput,
+                ) -> ::std::result::Result<
+                    ListPortfolioAccessOutput,
+                    RusotoError<ListPortfolioAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_portfolios: Option<
+        Box<
+            dyn Fn(
+                    ListPortfoliosInput,
+                )
+                    -> ::std::result::Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_portfolios_for_product: Option<
+        Box<
+            dyn Fn(
+                    ListPortfoliosForProductInput,
+                ) -> ::std::result::Result<
+                    ListPortfoliosForProductOutput,
+                    RusotoError<ListPortfoliosForProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_principals_for_portfolio: Option<
+        Box<
+            dyn Fn(
+                    ListPrincipalsForPortfolioInput,
+                ) -> ::std::result::Result<
+                    ListPrincipalsForPortfolioOutput,
+                    RusotoError<ListPrincipalsForPortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_provisioned_product_plans: Option<
+        Box<
+            dyn Fn(
+                    ListProvisionedProductPlansInput,
+                ) -> ::std::result::Result<
+                    ListProvisionedProductPlansOutput,
+                    RusotoError<ListProvisionedProductPlansError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_provisioning_artifacts: Option<
+        Box<
+            dyn Fn(
+                    ListProvisioningArtifactsInput,
+                ) -> ::std::result::Result<
+                    ListProvisioningArtifactsOutput,
+                    RusotoError<ListProvisioningArtifactsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_provisioning_artifacts_for_service_action: Option<
+        Box<
+            dyn Fn(
+                    ListProvisioningArtifactsForServiceActionInput,
+                ) -> ::std::result::Result<
+                    ListProvisioningArtifactsForServiceActionOutput,
+                    RusotoError<ListProvisioningArtifactsForServiceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_record_history: Option<
+        Box<
+            dyn Fn(
+                    ListRecordHistoryInput,
+                ) -> ::std::result::Result<
+                    ListRecordHistoryOutput,
+                    RusotoError<ListRecordHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resources_for_tag_option: Option<
+        Box<
+            dyn Fn(
+                    ListResourcesForTagOptionInput,
+                ) -> ::std::result::Result<
+                    ListResourcesForTagOptionOutput,
+                    RusotoError<ListResourcesForTagOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_service_actions: Option<
+        Box<
+            dyn Fn(
+                    ListServiceActionsInput,
+                ) -> ::std::result::Result<
+                    ListServiceActionsOutput,
+                    RusotoError<ListServiceActionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_service_actions_for_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    ListServiceActionsForProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    ListServiceActionsForProvisioningArtifactOutput,
+                    RusotoError<ListServiceActionsForProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_stack_instances_for_provisioned_product: Option<
+        Box<
+            dyn Fn(
+                    ListStackInstancesForProvisionedProductInput,
+                ) -> ::std::result::Result<
+                    ListStackInstancesForProvisionedProductOutput,
+                    RusotoError<ListStackInstancesForProvisionedProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tag_options: Option<
+        Box<
+            dyn Fn(
+                    ListTagOptionsInput,
+                )
+                    -> ::std::result::Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    provision_product: Option<
+        Box<
+            dyn Fn(
+                    ProvisionProductInput,
+                ) -> ::std::result::Result<
+                    ProvisionProductOutput,
+                    RusotoError<ProvisionProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reject_portfolio_share: Option<
+        Box<
+            dyn Fn(
+                    RejectPortfolioShareInput,
+                ) -> ::std::result::Result<
+                    RejectPortfolioShareOutput,
+                    RusotoError<RejectPortfolioShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    scan_provisioned_products: Option<
+        Box<
+            dyn Fn(
+                    ScanProvisionedProductsInput,
+                ) -> ::std::result::Result<
+                    ScanProvisionedProductsOutput,
+                    RusotoError<ScanProvisionedProductsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_products: Option<
+        Box<
+            dyn Fn(
+                    SearchProductsInput,
+                )
+                    -> ::std::result::Result<SearchProductsOutput, RusotoError<SearchProductsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    search_products_as_admin: Option<
+        Box<
+            dyn Fn(
+                    SearchProductsAsAdminInput,
+                ) -> ::std::result::Result<
+                    SearchProductsAsAdminOutput,
+                    RusotoError<SearchProductsAsAdminError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    search_provisioned_products: Option<
+        Box<
+            dyn Fn(
+                    SearchProvisionedProductsInput,
+                ) -> ::std::result::Result<
+                    SearchProvisionedProductsOutput,
+                    RusotoError<SearchProvisionedProductsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    terminate_provisioned_product: Option<
+        Box<
+            dyn Fn(
+                    TerminateProvisionedProductInput,
+                ) -> ::std::result::Result<
+                    TerminateProvisionedProductOutput,
+                    RusotoError<TerminateProvisionedProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_constraint: Option<
+        Box<
+            dyn Fn(
+                    UpdateConstraintInput,
+                ) -> ::std::result::Result<
+                    UpdateConstraintOutput,
+                    RusotoError<UpdateConstraintError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_portfolio: Option<
+        Box<
+            dyn Fn(
+                    UpdatePortfolioInput,
+                ) -> ::std::result::Result<
+                    UpdatePortfolioOutput,
+                    RusotoError<UpdatePortfolioError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_product: Option<
+        Box<
+            dyn Fn(
+                    UpdateProductInput,
+                )
+                    -> ::std::result::Result<UpdateProductOutput, RusotoError<UpdateProductError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_provisioned_product: Option<
+        Box<
+            dyn Fn(
+                    UpdateProvisionedProductInput,
+                ) -> ::std::result::Result<
+                    UpdateProvisionedProductOutput,
+                    RusotoError<UpdateProvisionedProductError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_provisioned_product_properties: Option<
+        Box<
+            dyn Fn(
+                    UpdateProvisionedProductPropertiesInput,
+                ) -> ::std::result::Result<
+                    UpdateProvisionedProductPropertiesOutput,
+                    RusotoError<UpdateProvisionedProductPropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_provisioning_artifact: Option<
+        Box<
+            dyn Fn(
+                    UpdateProvisioningArtifactInput,
+                ) -> ::std::result::Result<
+                    UpdateProvisioningArtifactOutput,
+                    RusotoError<UpdateProvisioningArtifactError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_service_action: Option<
+        Box<
+            dyn Fn(
+                    UpdateServiceActionInput,
+                ) -> ::std::result::Result<
+                    UpdateServiceActionOutput,
+                    RusotoError<UpdateServiceActionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_tag_option: Option<
+        Box<
+            dyn Fn(
+                    UpdateTagOptionInput,
+                ) -> ::std::result::Result<
+                    UpdateTagOptionOutput,
+                    RusotoError<UpdateTagOptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockServiceCatalog {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockServiceCatalog {
+        Default::default()
+    }
+
+    /// Answers `accept_portfolio_share` with what `handler` returns for its input.
+    pub fn with_accept_portfolio_share<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                AcceptPortfolioShareInput,
+            ) -> ::std::result::Result<
+                AcceptPortfolioShareOutput,
+                RusotoError<AcceptPortfolioShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.accept_portfolio_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_budget_with_resource` with what `handler` returns for its input.
+    pub fn with_associate_budget_with_resource<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                AssociateBudgetWithResourceInput,
+            ) -> ::std::result::Result<
+                AssociateBudgetWithResourceOutput,
+                RusotoError<AssociateBudgetWithResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_budget_with_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_principal_with_portfolio` with what `handler` returns for its input.
+    pub fn with_associate_principal_with_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                AssociatePrincipalWithPortfolioInput,
+            ) -> ::std::result::Result<
+                AssociatePrincipalWithPortfolioOutput,
+                RusotoError<AssociatePrincipalWithPortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_principal_with_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_product_with_portfolio` with what `handler` returns for its input.
+    pub fn with_associate_product_with_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                AssociateProductWithPortfolioInput,
+            ) -> ::std::result::Result<
+                AssociateProductWithPortfolioOutput,
+                RusotoError<AssociateProductWithPortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_product_with_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_service_action_with_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_associate_service_action_with_provisioning_artifact<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                AssociateServiceActionWithProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                AssociateServiceActionWithProvisioningArtifactOutput,
+                RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_service_action_with_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_tag_option_with_resource` with what `handler` returns for its input.
+    pub fn with_associate_tag_option_with_resource<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                AssociateTagOptionWithResourceInput,
+            ) -> ::std::result::Result<
+                AssociateTagOptionWithResourceOutput,
+                RusotoError<AssociateTagOptionWithResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_tag_option_with_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_associate_service_action_with_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_batch_associate_service_action_with_provisioning_artifact<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                BatchAssociateServiceActionWithProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                BatchAssociateServiceActionWithProvisioningArtifactOutput,
+                RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_associate_service_action_with_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `batch_disassociate_service_action_from_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_batch_disassociate_service_action_from_provisioning_artifact<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                BatchDisassociateServiceActionFromProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+                RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_disassociate_service_action_from_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_product` with what `handler` returns for its input.
+    pub fn with_copy_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CopyProductInput,
+            )
+                -> ::std::result::Result<CopyProductOutput, RusotoError<CopyProductError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_constraint` with what `handler` returns for its input.
+    pub fn with_create_constraint<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreateConstraintInput,
+            )
+                -> ::std::result::Result<CreateConstraintOutput, RusotoError<CreateConstraintError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_constraint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_portfolio` with what `handler` returns for its input.
+    pub fn with_create_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreatePortfolioInput,
+            )
+                -> ::std::result::Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_portfolio_share` with what `handler` returns for its input.
+    pub fn with_create_portfolio_share<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreatePortfolioShareInput,
+            ) -> ::std::result::Result<
+                CreatePortfolioShareOutput,
+                RusotoError<CreatePortfolioShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_portfolio_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_product` with what `handler` returns for its input.
+    pub fn with_create_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreateProductInput,
+            )
+                -> ::std::result::Result<CreateProductOutput, RusotoError<CreateProductError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_provisioned_product_plan` with what `handler` returns for its input.
+    pub fn with_create_provisioned_product_plan<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreateProvisionedProductPlanInput,
+            ) -> ::std::result::Result<
+                CreateProvisionedProductPlanOutput,
+                RusotoError<CreateProvisionedProductPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_provisioned_product_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_create_provisioning_artifact<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreateProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                CreateProvisioningArtifactOutput,
+                RusotoError<CreateProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_service_action` with what `handler` returns for its input.
+    pub fn with_create_service_action<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreateServiceActionInput,
+            ) -> ::std::result::Result<
+                CreateServiceActionOutput,
+                RusotoError<CreateServiceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_service_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tag_option` with what `handler` returns for its input.
+    pub fn with_create_tag_option<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                CreateTagOptionInput,
+            )
+                -> ::std::result::Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tag_option = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_constraint` with what `handler` returns for its input.
+    pub fn with_delete_constraint<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeleteConstraintInput,
+            )
+                -> ::std::result::Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_constraint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_portfolio` with what `handler` returns for its input.
+    pub fn with_delete_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeletePortfolioInput,
+            )
+                -> ::std::result::Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_portfolio_share` with what `handler` returns for its input.
+    pub fn with_delete_portfolio_share<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeletePortfolioShareInput,
+            ) -> ::std::result::Result<
+                DeletePortfolioShareOutput,
+                RusotoError<DeletePortfolioShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_portfolio_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_product` with what `handler` returns for its input.
+    pub fn with_delete_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeleteProductInput,
+            )
+                -> ::std::result::Result<DeleteProductOutput, RusotoError<DeleteProductError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_provisioned_product_plan` with what `handler` returns for its input.
+    pub fn with_delete_provisioned_product_plan<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeleteProvisionedProductPlanInput,
+            ) -> ::std::result::Result<
+                DeleteProvisionedProductPlanOutput,
+                RusotoError<DeleteProvisionedProductPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_provisioned_product_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_delete_provisioning_artifact<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeleteProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                DeleteProvisioningArtifactOutput,
+                RusotoError<DeleteProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_service_action` with what `handler` returns for its input.
+    pub fn with_delete_service_action<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeleteServiceActionInput,
+            ) -> ::std::result::Result<
+                DeleteServiceActionOutput,
+                RusotoError<DeleteServiceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_service_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tag_option` with what `handler` returns for its input.
+    pub fn with_delete_tag_option<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DeleteTagOptionInput,
+            )
+                -> ::std::result::Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tag_option = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_constraint` with what `handler` returns for its input.
+    pub fn with_describe_constraint<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeConstraintInput,
+            ) -> ::std::result::Result<
+                DescribeConstraintOutput,
+                RusotoError<DescribeConstraintError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_constraint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_copy_product_status` with what `handler` returns for its input.
+    pub fn with_describe_copy_product_status<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeCopyProductStatusInput,
+            ) -> ::std::result::Result<
+                DescribeCopyProductStatusOutput,
+                RusotoError<DescribeCopyProductStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_copy_product_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_portfolio` with what `handler` returns for its input.
+    pub fn with_describe_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribePortfolioInput,
+            ) -> ::std::result::Result<
+                DescribePortfolioOutput,
+                RusotoError<DescribePortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_portfolio_share_status` with what `handler` returns for its input.
+    pub fn with_describe_portfolio_share_status<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribePortfolioShareStatusInput,
+            ) -> ::std::result::Result<
+                DescribePortfolioShareStatusOutput,
+                RusotoError<DescribePortfolioShareStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_portfolio_share_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_product` with what `handler` returns for its input.
+    pub fn with_describe_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProductInput,
+            )
+                -> ::std::result::Result<DescribeProductOutput, RusotoError<DescribeProductError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_product_as_admin` with what `handler` returns for its input.
+    pub fn with_describe_product_as_admin<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProductAsAdminInput,
+            ) -> ::std::result::Result<
+                DescribeProductAsAdminOutput,
+                RusotoError<DescribeProductAsAdminError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_product_as_admin = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_product_view` with what `handler` returns for its input.
+    pub fn with_describe_product_view<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProductViewInput,
+            ) -> ::std::result::Result<
+                DescribeProductViewOutput,
+                RusotoError<DescribeProductViewError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_product_view = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_provisioned_product` with what `handler` returns for its input.
+    pub fn with_describe_provisioned_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProvisionedProductInput,
+            ) -> ::std::result::Result<
+                DescribeProvisionedProductOutput,
+                RusotoError<DescribeProvisionedProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_provisioned_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_provisioned_product_plan` with what `handler` returns for its input.
+    pub fn with_describe_provisioned_product_plan<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProvisionedProductPlanInput,
+            ) -> ::std::result::Result<
+                DescribeProvisionedProductPlanOutput,
+                RusotoError<DescribeProvisionedProductPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_provisioned_product_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_describe_provisioning_artifact<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                DescribeProvisioningArtifactOutput,
+                RusotoError<DescribeProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_provisioning_parameters` with what `handler` returns for its input.
+    pub fn with_describe_provisioning_parameters<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeProvisioningParametersInput,
+            ) -> ::std::result::Result<
+                DescribeProvisioningParametersOutput,
+                RusotoError<DescribeProvisioningParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_provisioning_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_record` with what `handler` returns for its input.
+    pub fn with_describe_record<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeRecordInput,
+            )
+                -> ::std::result::Result<DescribeRecordOutput, RusotoError<DescribeRecordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_record = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_service_action` with what `handler` returns for its input.
+    pub fn with_describe_service_action<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeServiceActionInput,
+            ) -> ::std::result::Result<
+                DescribeServiceActionOutput,
+                RusotoError<DescribeServiceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_service_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_service_action_execution_parameters` with what `handler` returns for its input.
+    pub fn with_describe_service_action_execution_parameters<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeServiceActionExecutionParametersInput,
+            ) -> ::std::result::Result<
+                DescribeServiceActionExecutionParametersOutput,
+                RusotoError<DescribeServiceActionExecutionParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_service_action_execution_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tag_option` with what `handler` returns for its input.
+    pub fn with_describe_tag_option<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DescribeTagOptionInput,
+            ) -> ::std::result::Result<
+                DescribeTagOptionOutput,
+                RusotoError<DescribeTagOptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tag_option = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_aws_organizations_access` with what `handler` returns.
+    pub fn with_disable_aws_organizations_access<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn() -> ::std::result::Result<
+                DisableAWSOrganizationsAccessOutput,
+                RusotoError<DisableAWSOrganizationsAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_aws_organizations_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_budget_from_resource` with what `handler` returns for its input.
+    pub fn with_disassociate_budget_from_resource<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DisassociateBudgetFromResourceInput,
+            ) -> ::std::result::Result<
+                DisassociateBudgetFromResourceOutput,
+                RusotoError<DisassociateBudgetFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_budget_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_principal_from_portfolio` with what `handler` returns for its input.
+    pub fn with_disassociate_principal_from_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DisassociatePrincipalFromPortfolioInput,
+            ) -> ::std::result::Result<
+                DisassociatePrincipalFromPortfolioOutput,
+                RusotoError<DisassociatePrincipalFromPortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_principal_from_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_product_from_portfolio` with what `handler` returns for its input.
+    pub fn with_disassociate_product_from_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DisassociateProductFromPortfolioInput,
+            ) -> ::std::result::Result<
+                DisassociateProductFromPortfolioOutput,
+                RusotoError<DisassociateProductFromPortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_product_from_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_service_action_from_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_disassociate_service_action_from_provisioning_artifact<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                DisassociateServiceActionFromProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                DisassociateServiceActionFromProvisioningArtifactOutput,
+                RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_service_action_from_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_tag_option_from_resource` with what `handler` returns for its input.
+    pub fn with_disassociate_tag_option_from_resource<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                DisassociateTagOptionFromResourceInput,
+            ) -> ::std::result::Result<
+                DisassociateTagOptionFromResourceOutput,
+                RusotoError<DisassociateTagOptionFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_tag_option_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `enable_aws_organizations_access` with what `handler` returns.
+    pub fn with_enable_aws_organizations_access<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn() -> ::std::result::Result<
+                EnableAWSOrganizationsAccessOutput,
+                RusotoError<EnableAWSOrganizationsAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.enable_aws_organizations_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `execute_provisioned_product_plan` with what `handler` returns for its input.
+    pub fn with_execute_provisioned_product_plan<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ExecuteProvisionedProductPlanInput,
+            ) -> ::std::result::Result<
+                ExecuteProvisionedProductPlanOutput,
+                RusotoError<ExecuteProvisionedProductPlanError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.execute_provisioned_product_plan = Some(Box::new(handler));
+        self
+    }
+    /// Answers `execute_provisioned_product_service_action` with what `handler` returns for its input.
+    pub fn with_execute_provisioned_product_service_action<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                ExecuteProvisionedProductServiceActionInput,
+            ) -> ::std::result::Result<
+                ExecuteProvisionedProductServiceActionOutput,
+                RusotoError<ExecuteProvisionedProductServiceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.execute_provisioned_product_service_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_aws_organizations_access_status` with what `handler` returns.
+    pub fn with_get_aws_organizations_access_status<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn() -> ::std::result::Result<
+                GetAWSOrganizationsAccessStatusOutput,
+                RusotoError<GetAWSOrganizationsAccessStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_aws_organizations_access_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_accepted_portfolio_shares` with what `handler` returns for its input.
+    pub fn with_list_accepted_portfolio_shares<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListAcceptedPortfolioSharesInput,
+            ) -> ::std::result::Result<
+                ListAcceptedPortfolioSharesOutput,
+                RusotoError<ListAcceptedPortfolioSharesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_accepted_portfolio_shares = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_budgets_for_resource` with what `handler` returns for its input.
+    pub fn with_list_budgets_for_resource<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListBudgetsForResourceInput,
+            ) -> ::std::result::Result<
+                ListBudgetsForResourceOutput,
+                RusotoError<ListBudgetsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_budgets_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_constraints_for_portfolio` with what `handler` returns for its input.
+    pub fn with_list_constraints_for_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListConstraintsForPortfolioInput,
+            ) -> ::std::result::Result<
+                ListConstraintsForPortfolioOutput,
+                RusotoError<ListConstraintsForPortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_constraints_for_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_launch_paths` with what `handler` returns for its input.
+    pub fn with_list_launch_paths<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListLaunchPathsInput,
+            )
+                -> ::std::result::Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_launch_paths = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_organization_portfolio_access` with what `handler` returns for its input.
+    pub fn with_list_organization_portfolio_access<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListOrganizationPortfolioAccessInput,
+            ) -> ::std::result::Result<
+                ListOrganizationPortfolioAccessOutput,
+                RusotoError<ListOrganizationPortfolioAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_organization_portfolio_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_portfolio_access` with what `handler` returns for its input.
+    pub fn with_list_portfolio_access<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListPortfolioAccessInput,
+            ) -> ::std::result::Result<
+                ListPortfolioAccessOutput,
+                RusotoError<ListPortfolioAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_portfolio_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_portfolios` with what `handler` returns for its input.
+    pub fn with_list_portfolios<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListPortfoliosInput,
+            )
+                -> ::std::result::Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_portfolios = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_portfolios_for_product` with what `handler` returns for its input.
+    pub fn with_list_portfolios_for_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListPortfoliosForProductInput,
+            ) -> ::std::result::Result<
+                ListPortfoliosForProductOutput,
+                RusotoError<ListPortfoliosForProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_portfolios_for_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_principals_for_portfolio` with what `handler` returns for its input.
+    pub fn with_list_principals_for_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListPrincipalsForPortfolioInput,
+            ) -> ::std::result::Result<
+                ListPrincipalsForPortfolioOutput,
+                RusotoError<ListPrincipalsForPortfolioError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_principals_for_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_provisioned_product_plans` with what `handler` returns for its input.
+    pub fn with_list_provisioned_product_plans<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListProvisionedProductPlansInput,
+            ) -> ::std::result::Result<
+                ListProvisionedProductPlansOutput,
+                RusotoError<ListProvisionedProductPlansError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_provisioned_product_plans = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_provisioning_artifacts` with what `handler` returns for its input.
+    pub fn with_list_provisioning_artifacts<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListProvisioningArtifactsInput,
+            ) -> ::std::result::Result<
+                ListProvisioningArtifactsOutput,
+                RusotoError<ListProvisioningArtifactsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_provisioning_artifacts = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_provisioning_artifacts_for_service_action` with what `handler` returns for its input.
+    pub fn with_list_provisioning_artifacts_for_service_action<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListProvisioningArtifactsForServiceActionInput,
+            ) -> ::std::result::Result<
+                ListProvisioningArtifactsForServiceActionOutput,
+                RusotoError<ListProvisioningArtifactsForServiceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_provisioning_artifacts_for_service_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_record_history` with what `handler` returns for its input.
+    pub fn with_list_record_history<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListRecordHistoryInput,
+            ) -> ::std::result::Result<
+                ListRecordHistoryOutput,
+                RusotoError<ListRecordHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_record_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resources_for_tag_option` with what `handler` returns for its input.
+    pub fn with_list_resources_for_tag_option<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListResourcesForTagOptionInput,
+            ) -> ::std::result::Result<
+                ListResourcesForTagOptionOutput,
+                RusotoError<ListResourcesForTagOptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resources_for_tag_option = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_service_actions` with what `handler` returns for its input.
+    pub fn with_list_service_actions<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListServiceActionsInput,
+            ) -> ::std::result::Result<
+                ListServiceActionsOutput,
+                RusotoError<ListServiceActionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_service_actions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_service_actions_for_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_list_service_actions_for_provisioning_artifact<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListServiceActionsForProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                ListServiceActionsForProvisioningArtifactOutput,
+                RusotoError<ListServiceActionsForProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_service_actions_for_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_stack_instances_for_provisioned_product` with what `handler` returns for its input.
+    pub fn with_list_stack_instances_for_provisioned_product<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListStackInstancesForProvisionedProductInput,
+            ) -> ::std::result::Result<
+                ListStackInstancesForProvisionedProductOutput,
+                RusotoError<ListStackInstancesForProvisionedProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_stack_instances_for_provisioned_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tag_options` with what `handler` returns for its input.
+    pub fn with_list_tag_options<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ListTagOptionsInput,
+            )
+                -> ::std::result::Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tag_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `provision_product` with what `handler` returns for its input.
+    pub fn with_provision_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ProvisionProductInput,
+            )
+                -> ::std::result::Result<ProvisionProductOutput, RusotoError<ProvisionProductError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.provision_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reject_portfolio_share` with what `handler` returns for its input.
+    pub fn with_reject_portfolio_share<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                RejectPortfolioShareInput,
+            ) -> ::std::result::Result<
+                RejectPortfolioShareOutput,
+                RusotoError<RejectPortfolioShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reject_portfolio_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `scan_provisioned_products` with what `handler` returns for its input.
+    pub fn with_scan_provisioned_products<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                ScanProvisionedProductsInput,
+            ) -> ::std::result::Result<
+                ScanProvisionedProductsOutput,
+                RusotoError<ScanProvisionedProductsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.scan_provisioned_products = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_products` with what `handler` returns for its input.
+    pub fn with_search_products<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                SearchProductsInput,
+            )
+                -> ::std::result::Result<SearchProductsOutput, RusotoError<SearchProductsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.search_products = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_products_as_admin` with what `handler` returns for its input.
+    pub fn with_search_products_as_admin<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                SearchProductsAsAdminInput,
+            ) -> ::std::result::Result<
+                SearchProductsAsAdminOutput,
+                RusotoError<SearchProductsAsAdminError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_products_as_admin = Some(Box::new(handler));
+        self
+    }
+    /// Answers `search_provisioned_products` with what `handler` returns for its input.
+    pub fn with_search_provisioned_products<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                SearchProvisionedProductsInput,
+            ) -> ::std::result::Result<
+                SearchProvisionedProductsOutput,
+                RusotoError<SearchProvisionedProductsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.search_provisioned_products = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_provisioned_product` with what `handler` returns for its input.
+    pub fn with_terminate_provisioned_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                TerminateProvisionedProductInput,
+            ) -> ::std::result::Result<
+                TerminateProvisionedProductOutput,
+                RusotoError<TerminateProvisionedProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_provisioned_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_constraint` with what `handler` returns for its input.
+    pub fn with_update_constraint<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateConstraintInput,
+            )
+                -> ::std::result::Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_constraint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_portfolio` with what `handler` returns for its input.
+    pub fn with_update_portfolio<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdatePortfolioInput,
+            )
+                -> ::std::result::Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_portfolio = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_product` with what `handler` returns for its input.
+    pub fn with_update_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateProductInput,
+            )
+                -> ::std::result::Result<UpdateProductOutput, RusotoError<UpdateProductError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_provisioned_product` with what `handler` returns for its input.
+    pub fn with_update_provisioned_product<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateProvisionedProductInput,
+            ) -> ::std::result::Result<
+                UpdateProvisionedProductOutput,
+                RusotoError<UpdateProvisionedProductError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_provisioned_product = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_provisioned_product_properties` with what `handler` returns for its input.
+    pub fn with_update_provisioned_product_properties<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateProvisionedProductPropertiesInput,
+            ) -> ::std::result::Result<
+                UpdateProvisionedProductPropertiesOutput,
+                RusotoError<UpdateProvisionedProductPropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_provisioned_product_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_provisioning_artifact` with what `handler` returns for its input.
+    pub fn with_update_provisioning_artifact<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateProvisioningArtifactInput,
+            ) -> ::std::result::Result<
+                UpdateProvisioningArtifactOutput,
+                RusotoError<UpdateProvisioningArtifactError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_provisioning_artifact = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service_action` with what `handler` returns for its input.
+    pub fn with_update_service_action<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateServiceActionInput,
+            ) -> ::std::result::Result<
+                UpdateServiceActionOutput,
+                RusotoError<UpdateServiceActionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service_action = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_tag_option` with what `handler` returns for its input.
+    pub fn with_update_tag_option<F>(mut self, handler: F) -> MockServiceCatalog
+    where
+        F: Fn(
+                UpdateTagOptionInput,
+            )
+                -> ::std::result::Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_tag_option = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ServiceCatalog for MockServiceCatalog {
+    fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> RusotoFuture<AcceptPortfolioShareOutput, AcceptPortfolioShareError> {
+        match self.accept_portfolio_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for accept_portfolio_share"),
+        }
+    }
+    fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> RusotoFuture<AssociateBudgetWithResourceOutput, AssociateBudgetWithResourceError> {
+        match self.associate_budget_with_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for associate_budget_with_resource"),
+        }
+    }
+    fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> RusotoFuture<AssociatePrincipalWithPortfolioOutput, AssociatePrincipalWithPortfolioError>
+    {
+        match self.associate_principal_with_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for associate_principal_with_portfolio")
+            }
+        }
+    }
+    fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> RusotoFuture<AssociateProductWithPortfolioOutput, AssociateProductWithPortfolioError> {
+        match self.associate_product_with_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for associate_product_with_portfolio")
+            }
+        }
+    }
+    fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        AssociateServiceActionWithProvisioningArtifactError,
+    > {
+        match self.associate_service_action_with_provisioning_artifact {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for associate_service_action_with_provisioning_artifact"),
+}
+    }
+    fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> RusotoFuture<AssociateTagOptionWithResourceOutput, AssociateTagOptionWithResourceError>
+    {
+        match self.associate_tag_option_with_resource {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for associate_tag_option_with_resource")
+            }
+        }
+    }
+    fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        BatchAssociateServiceActionWithProvisioningArtifactError,
+    > {
+        match self.batch_associate_service_action_with_provisioning_artifact {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for batch_associate_service_action_with_provisioning_artifact"),
+}
+    }
+    fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        BatchDisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        match self.batch_disassociate_service_action_from_provisioning_artifact {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for batch_disassociate_service_action_from_provisioning_artifact"),
+}
+    }
+    fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> RusotoFuture<CopyProductOutput, CopyProductError> {
+        match self.copy_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for copy_product"),
+        }
+    }
+    fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> RusotoFuture<CreateConstraintOutput, CreateConstraintError> {
+        match self.create_constraint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_constraint"),
+        }
+    }
+    fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> RusotoFuture<CreatePortfolioOutput, CreatePortfolioError> {
+        match self.create_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_portfolio"),
+        }
+    }
+    fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> RusotoFuture<CreatePortfolioShareOutput, CreatePortfolioShareError> {
+        match self.create_portfolio_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_portfolio_share"),
+        }
+    }
+    fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> RusotoFuture<CreateProductOutput, CreateProductError> {
+        match self.create_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_product"),
+        }
+    }
+    fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> RusotoFuture<CreateProvisionedProductPlanOutput, CreateProvisionedProductPlanError> {
+        match self.create_provisioned_product_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_provisioned_product_plan"),
+        }
+    }
+    fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> RusotoFuture<CreateProvisioningArtifactOutput, CreateProvisioningArtifactError> {
+        match self.create_provisioning_artifact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_provisioning_artifact"),
+        }
+    }
+    fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> RusotoFuture<CreateServiceActionOutput, CreateServiceActionError> {
+        match self.create_service_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_service_action"),
+        }
+    }
+    fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> RusotoFuture<CreateTagOptionOutput, CreateTagOptionError> {
+        match self.create_tag_option {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for create_tag_option"),
+        }
+    }
+    fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> RusotoFuture<DeleteConstraintOutput, DeleteConstraintError> {
+        match self.delete_constraint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_constraint"),
+        }
+    }
+    fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> RusotoFuture<DeletePortfolioOutput, DeletePortfolioError> {
+        match self.delete_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_portfolio"),
+        }
+    }
+    fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> RusotoFuture<DeletePortfolioShareOutput, DeletePortfolioShareError> {
+        match self.delete_portfolio_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_portfolio_share"),
+        }
+    }
+    fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> RusotoFuture<DeleteProductOutput, DeleteProductError> {
+        match self.delete_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_product"),
+        }
+    }
+    fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> RusotoFuture<DeleteProvisionedProductPlanOutput, DeleteProvisionedProductPlanError> {
+        match self.delete_provisioned_product_plan {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_provisioned_product_plan"),
+        }
+    }
+    fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> RusotoFuture<DeleteProvisioningArtifactOutput, DeleteProvisioningArtifactError> {
+        match self.delete_provisioning_artifact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_provisioning_artifact"),
+        }
+    }
+    fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> RusotoFuture<DeleteServiceActionOutput, DeleteServiceActionError> {
+        match self.delete_service_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_service_action"),
+        }
+    }
+    fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> RusotoFuture<DeleteTagOptionOutput, DeleteTagOptionError> {
+        match self.delete_tag_option {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for delete_tag_option"),
+        }
+    }
+    fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> RusotoFuture<DescribeConstraintOutput, DescribeConstraintError> {
+        match self.describe_constraint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_constraint"),
+        }
+    }
+    fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> RusotoFuture<DescribeCopyProductStatusOutput, DescribeCopyProductStatusError> {
+        match self.describe_copy_product_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_copy_product_status"),
+        }
+    }
+    fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> RusotoFuture<DescribePortfolioOutput, DescribePortfolioError> {
+        match self.describe_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_portfolio"),
+        }
+    }
+    fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> RusotoFuture<DescribePortfolioShareStatusOutput, DescribePortfolioShareStatusError> {
+        match self.describe_portfolio_share_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_portfolio_share_status"),
+        }
+    }
+    fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> RusotoFuture<DescribeProductOutput, DescribeProductError> {
+        match self.describe_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_product"),
+        }
+    }
+    fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> RusotoFuture<DescribeProductAsAdminOutput, DescribeProductAsAdminError> {
+        match self.describe_product_as_admin {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_product_as_admin"),
+        }
+    }
+    fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> RusotoFuture<DescribeProductViewOutput, DescribeProductViewError> {
+        match self.describe_product_view {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_product_view"),
+        }
+    }
+    fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> RusotoFuture<DescribeProvisionedProductOutput, DescribeProvisionedProductError> {
+        match self.describe_provisioned_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_provisioned_product"),
+        }
+    }
+    fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> RusotoFuture<DescribeProvisionedProductPlanOutput, DescribeProvisionedProductPlanError>
+    {
+        match self.describe_provisioned_product_plan {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for describe_provisioned_product_plan")
+            }
+        }
+    }
+    fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> RusotoFuture<DescribeProvisioningArtifactOutput, DescribeProvisioningArtifactError> {
+        match self.describe_provisioning_artifact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_provisioning_artifact"),
+        }
+    }
+    fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> RusotoFuture<DescribeProvisioningParametersOutput, DescribeProvisioningParametersError>
+    {
+        match self.describe_provisioning_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for describe_provisioning_parameters")
+            }
+        }
+    }
+    fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> RusotoFuture<DescribeRecordOutput, DescribeRecordError> {
+        match self.describe_record {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_record"),
+        }
+    }
+    fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> RusotoFuture<DescribeServiceActionOutput, DescribeServiceActionError> {
+        match self.describe_service_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_service_action"),
+        }
+    }
+    fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> RusotoFuture<
+        DescribeServiceActionExecutionParametersOutput,
+        DescribeServiceActionExecutionParametersError,
+    > {
+        match self.describe_service_action_execution_parameters {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for describe_service_action_execution_parameters"),
+}
+    }
+    fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> RusotoFuture<DescribeTagOptionOutput, DescribeTagOptionError> {
+        match self.describe_tag_option {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for describe_tag_option"),
+        }
+    }
+    fn disable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<DisableAWSOrganizationsAccessOutput, DisableAWSOrganizationsAccessError> {
+        match self.disable_aws_organizations_access {
+            Some(ref handler) => handler().into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for disable_aws_organizations_access")
+            }
+        }
+    }
+    fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> RusotoFuture<DisassociateBudgetFromResourceOutput, DisassociateBudgetFromResourceError>
+    {
+        match self.disassociate_budget_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for disassociate_budget_from_resource")
+            }
+        }
+    }
+    fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> RusotoFuture<
+        DisassociatePrincipalFromPortfolioOutput,
+        DisassociatePrincipalFromPortfolioError,
+    > {
+        match self.disassociate_principal_from_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServiceCatalog has no handler for disassociate_principal_from_portfolio"
+            ),
+        }
+    }
+    fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> RusotoFuture<DisassociateProductFromPortfolioOutput, DisassociateProductFromPortfolioError>
+    {
+        match self.disassociate_product_from_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for disassociate_product_from_portfolio")
+            }
+        }
+    }
+    fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        DisassociateServiceActionFromProvisioningArtifactError,
+    > {
+        match self.disassociate_service_action_from_provisioning_artifact {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for disassociate_service_action_from_provisioning_artifact"),
+}
+    }
+    fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> RusotoFuture<DisassociateTagOptionFromResourceOutput, DisassociateTagOptionFromResourceError>
+    {
+        match self.disassociate_tag_option_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServiceCatalog has no handler for disassociate_tag_option_from_resource"
+            ),
+        }
+    }
+    fn enable_aws_organizations_access(
+        &self,
+    ) -> RusotoFuture<EnableAWSOrganizationsAccessOutput, EnableAWSOrganizationsAccessError> {
+        match self.enable_aws_organizations_access {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockServiceCatalog has no handler for enable_aws_organizations_access"),
+        }
+    }
+    fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> RusotoFuture<ExecuteProvisionedProductPlanOutput, ExecuteProvisionedProductPlanError> {
+        match self.execute_provisioned_product_plan {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for execute_provisioned_product_plan")
+            }
+        }
+    }
+    fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> RusotoFuture<
+        ExecuteProvisionedProductServiceActionOutput,
+        ExecuteProvisionedProductServiceActionError,
+    > {
+        match self.execute_provisioned_product_service_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServiceCatalog has no handler for execute_provisioned_product_service_action"
+            ),
+        }
+    }
+    fn get_aws_organizations_access_status(
+        &self,
+    ) -> RusotoFuture<GetAWSOrganizationsAccessStatusOutput, GetAWSOrganizationsAccessStatusError>
+    {
+        match self.get_aws_organizations_access_status {
+            Some(ref handler) => handler().into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for get_aws_organizations_access_status")
+            }
+        }
+    }
+    fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> RusotoFuture<ListAcceptedPortfolioSharesOutput, ListAcceptedPortfolioSharesError> {
+        match self.list_accepted_portfolio_shares {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_accepted_portfolio_shares"),
+        }
+    }
+    fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> RusotoFuture<ListBudgetsForResourceOutput, ListBudgetsForResourceError> {
+        match self.list_budgets_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_budgets_for_resource"),
+        }
+    }
+    fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> RusotoFuture<ListConstraintsForPortfolioOutput, ListConstraintsForPortfolioError> {
+        match self.list_constraints_for_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_constraints_for_portfolio"),
+        }
+    }
+    fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> RusotoFuture<ListLaunchPathsOutput, ListLaunchPathsError> {
+        match self.list_launch_paths {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_launch_paths"),
+        }
+    }
+    fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> RusotoFuture<ListOrganizationPortfolioAccessOutput, ListOrganizationPortfolioAccessError>
+    {
+        match self.list_organization_portfolio_access {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServiceCatalog has no handler for list_organization_portfolio_access")
+            }
+        }
+    }
+    fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> RusotoFuture<ListPortfolioAccessOutput, ListPortfolioAccessError> {
+        match self.list_portfolio_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_portfolio_access"),
+        }
+    }
+    fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> RusotoFuture<ListPortfoliosOutput, ListPortfoliosError> {
+        match self.list_portfolios {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_portfolios"),
+        }
+    }
+    fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> RusotoFuture<ListPortfoliosForProductOutput, ListPortfoliosForProductError> {
+        match self.list_portfolios_for_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_portfolios_for_product"),
+        }
+    }
+    fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> RusotoFuture<ListPrincipalsForPortfolioOutput, ListPrincipalsForPortfolioError> {
+        match self.list_principals_for_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_principals_for_portfolio"),
+        }
+    }
+    fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> RusotoFuture<ListProvisionedProductPlansOutput, ListProvisionedProductPlansError> {
+        match self.list_provisioned_product_plans {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_provisioned_product_plans"),
+        }
+    }
+    fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> RusotoFuture<ListProvisioningArtifactsOutput, ListProvisioningArtifactsError> {
+        match self.list_provisioning_artifacts {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_provisioning_artifacts"),
+        }
+    }
+    fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> RusotoFuture<
+        ListProvisioningArtifactsForServiceActionOutput,
+        ListProvisioningArtifactsForServiceActionError,
+    > {
+        match self.list_provisioning_artifacts_for_service_action {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for list_provisioning_artifacts_for_service_action"),
+}
+    }
+    fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> RusotoFuture<ListRecordHistoryOutput, ListRecordHistoryError> {
+        match self.list_record_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_record_history"),
+        }
+    }
+    fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> RusotoFuture<ListResourcesForTagOptionOutput, ListResourcesForTagOptionError> {
+        match self.list_resources_for_tag_option {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_resources_for_tag_option"),
+        }
+    }
+    fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> RusotoFuture<ListServiceActionsOutput, ListServiceActionsError> {
+        match self.list_service_actions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_service_actions"),
+        }
+    }
+    fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> RusotoFuture<
+        ListServiceActionsForProvisioningArtifactOutput,
+        ListServiceActionsForProvisioningArtifactError,
+    > {
+        match self.list_service_actions_for_provisioning_artifact {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for list_service_actions_for_provisioning_artifact"),
+}
+    }
+    fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> RusotoFuture<
+        ListStackInstancesForProvisionedProductOutput,
+        ListStackInstancesForProvisionedProductError,
+    > {
+        match self.list_stack_instances_for_provisioned_product {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServiceCatalog has no handler for list_stack_instances_for_provisioned_product"),
+}
+    }
+    fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> RusotoFuture<ListTagOptionsOutput, ListTagOptionsError> {
+        match self.list_tag_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for list_tag_options"),
+        }
+    }
+    fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> RusotoFuture<ProvisionProductOutput, ProvisionProductError> {
+        match self.provision_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for provision_product"),
+        }
+    }
+    fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> RusotoFuture<RejectPortfolioShareOutput, RejectPortfolioShareError> {
+        match self.reject_portfolio_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for reject_portfolio_share"),
+        }
+    }
+    fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> RusotoFuture<ScanProvisionedProductsOutput, ScanProvisionedProductsError> {
+        match self.scan_provisioned_products {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for scan_provisioned_products"),
+        }
+    }
+    fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> RusotoFuture<SearchProductsOutput, SearchProductsError> {
+        match self.search_products {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for search_products"),
+        }
+    }
+    fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> RusotoFuture<SearchProductsAsAdminOutput, SearchProductsAsAdminError> {
+        match self.search_products_as_admin {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for search_products_as_admin"),
+        }
     }
-
-    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
     fn search_provisioned_products(
         &self,
         input: SearchProvisionedProductsInput,
     ) -> RusotoFuture<SearchProvisionedProductsOutput, SearchProvisionedProductsError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.SearchProvisionedProducts",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SearchProvisionedProductsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SearchProvisionedProductsError::from_response(response))
-                }))
-            }
-        })
+        match self.search_provisioned_products {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for search_provisioned_products"),
+        }
     }
-
-    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
     fn terminate_provisioned_product(
         &self,
         input: TerminateProvisionedProductInput,
     ) -> RusotoFuture<TerminateProvisionedProductOutput, TerminateProvisionedProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.TerminateProvisionedProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TerminateProvisionedProductOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(TerminateProvisionedProductError::from_response(response))
-                }))
-            }
-        })
+        match self.terminate_provisioned_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for terminate_provisioned_product"),
+        }
     }
-
-    /// <p>Updates the specified constraint.</p>
     fn update_constraint(
         &self,
         input: UpdateConstraintInput,
     ) -> RusotoFuture<UpdateConstraintOutput, UpdateConstraintError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdateConstraint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateConstraintOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateConstraintError::from_response(response))),
-                )
-            }
-        })
+        match self.update_constraint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_constraint"),
+        }
     }
-
-    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
     fn update_portfolio(
         &self,
         input: UpdatePortfolioInput,
     ) -> RusotoFuture<UpdatePortfolioOutput, UpdatePortfolioError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdatePortfolio",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePortfolioOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdatePortfolioError::from_response(response))),
-                )
-            }
-        })
+        match self.update_portfolio {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_portfolio"),
+        }
     }
-
-    /// <p>Updates the specified product.</p>
     fn update_product(
         &self,
         input: UpdateProductInput,
     ) -> RusotoFuture<UpdateProductOutput, UpdateProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWS242ServiceCatalogService.UpdateProduct");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProductOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateProductError::from_response(response))),
-                )
-            }
-        })
+        match self.update_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_product"),
+        }
     }
-
-    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
     fn update_provisioned_product(
         &self,
         input: UpdateProvisionedProductInput,
     ) -> RusotoFuture<UpdateProvisionedProductOutput, UpdateProvisionedProductError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdateProvisionedProduct",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProvisionedProductOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateProvisionedProductError::from_response(response))
-                }))
-            }
-        })
+        match self.update_provisioned_product {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_provisioned_product"),
+        }
     }
-
-    /// <p>Requests updates to the properties of the specified provisioned product.</p>
     fn update_provisioned_product_properties(
         &self,
         input: UpdateProvisionedProductPropertiesInput,
@@ -10313,121 +19383,38 @@ impl ServiceCatalog for ServiceCatalogClient {
         UpdateProvisionedProductPropertiesOutput,
         UpdateProvisionedProductPropertiesError,
     > {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdateProvisionedProductProperties",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProvisionedProductPropertiesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateProvisionedProductPropertiesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.update_provisioned_product_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServiceCatalog has no handler for update_provisioned_product_properties"
+            ),
+        }
     }
-
-    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
     fn update_provisioning_artifact(
         &self,
         input: UpdateProvisioningArtifactInput,
     ) -> RusotoFuture<UpdateProvisioningArtifactOutput, UpdateProvisioningArtifactError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdateProvisioningArtifact",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateProvisioningArtifactOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateProvisioningArtifactError::from_response(response))
-                }))
-            }
-        })
+        match self.update_provisioning_artifact {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_provisioning_artifact"),
+        }
     }
-
-    /// <p>Updates a self-service action.</p>
     fn update_service_action(
         &self,
         input: UpdateServiceActionInput,
     ) -> RusotoFuture<UpdateServiceActionOutput, UpdateServiceActionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdateServiceAction",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServiceActionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateServiceActionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_service_action {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_service_action"),
+        }
     }
-
-    /// <p>Updates the specified TagOption.</p>
     fn update_tag_option(
         &self,
         input: UpdateTagOptionInput,
     ) -> RusotoFuture<UpdateTagOptionOutput, UpdateTagOptionError> {
-        let mut request = SignedRequest::new("POST", "servicecatalog", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWS242ServiceCatalogService.UpdateTagOption",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTagOptionOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTagOptionError::from_response(response))),
-                )
-            }
-        })
+        match self.update_tag_option {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceCatalog has no handler for update_tag_option"),
+        }
     }
 }
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateHttpNamespaceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreateHttpNamespace</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
     #[serde(rename = "CreatorRequestId")]
@@ -38,8 +40,36 @@ pub struct CreateHttpNamespaceRequest {
     pub name: String,
 }
 
+impl Validate for CreateHttpNamespaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.creator_request_id {
+            errors.check_length(
+                &field_path(path, "creator_request_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[!-~]{1,1024}$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateHttpNamespaceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. To get the status of the operation, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -48,6 +78,7 @@ pub struct CreateHttpNamespaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePrivateDnsNamespaceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreatePrivateDnsNamespace</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
     #[serde(rename = "CreatorRequestId")]
@@ -65,8 +96,42 @@ pub struct CreatePrivateDnsNamespaceRequest {
     pub vpc: String,
 }
 
+impl Validate for CreatePrivateDnsNamespaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.creator_request_id {
+            errors.check_length(
+                &field_path(path, "creator_request_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^[!-~]{1,253}$");
+        errors.check_length(
+            &field_path(path, "vpc"),
+            self.vpc.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePrivateDnsNamespaceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. To get the status of the operation, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -75,6 +140,7 @@ pub struct CreatePrivateDnsNamespaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePublicDnsNamespaceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreatePublicDnsNamespace</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
     #[serde(rename = "CreatorRequestId")]
@@ -89,8 +155,36 @@ pub struct CreatePublicDnsNamespaceRequest {
     pub name: String,
 }
 
+impl Validate for CreatePublicDnsNamespaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.creator_request_id {
+            errors.check_length(
+                &field_path(path, "creator_request_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            None,
+            Some(253),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([a-zA-Z0-9]([a-zA-Z0-9\\-]{0,61}[a-zA-Z0-9])?\\.)+[a-zA-Z0-9]([a-zA-Z0-9\\-]{0,61}[a-zA-Z0-9])?$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePublicDnsNamespaceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. To get the status of the operation, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -99,6 +193,7 @@ pub struct CreatePublicDnsNamespaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateServiceRequest {
     /// <p>A unique string that identifies the request and that allows failed <code>CreateService</code> requests to be retried without the risk of executing the operation twice. <code>CreatorRequestId</code> can be any unique string, for example, a date/time stamp.</p>
     #[serde(rename = "CreatorRequestId")]
@@ -129,8 +224,44 @@ pub struct CreateServiceRequest {
     pub namespace_id: Option<String>,
 }
 
+impl Validate for CreateServiceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.creator_request_id {
+            errors.check_length(
+                &field_path(path, "creator_request_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.dns_config {
+            value.validate_at(&field_path(path, "dns_config"), errors);
+        }
+        if let Some(ref value) = self.health_check_config {
+            value.validate_at(&field_path(path, "health_check_config"), errors);
+        }
+        errors.check_pattern(&field_path(path, "name"), &self.name, "((?=^.{1,127}$)^([a-zA-Z0-9_][a-zA-Z0-9-_]{0,61}[a-zA-Z0-9_]|[a-zA-Z0-9])(\\.([a-zA-Z0-9_][a-zA-Z0-9-_]{0,61}[a-zA-Z0-9_]|[a-zA-Z0-9]))*$)|(^\\.$)");
+        if let Some(ref value) = self.namespace_id {
+            errors.check_length(
+                &field_path(path, "namespace_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateServiceResponse {
     /// <p>A complex type that contains information about the new service.</p>
     #[serde(rename = "Service")]
@@ -139,14 +270,26 @@ pub struct CreateServiceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNamespaceRequest {
     /// <p>The ID of the namespace that you want to delete.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for DeleteNamespaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteNamespaceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. To get the status of the operation, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -155,17 +298,30 @@ pub struct DeleteNamespaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteServiceRequest {
     /// <p>The ID of the service that you want to delete.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for DeleteServiceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteServiceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterInstanceRequest {
     /// <p>The value that you specified for <code>Id</code> in the <a>RegisterInstance</a> request.</p>
     #[serde(rename = "InstanceId")]
@@ -175,8 +331,25 @@ pub struct DeregisterInstanceRequest {
     pub service_id: String,
 }
 
+impl Validate for DeregisterInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "service_id"),
+            self.service_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterInstanceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. For more information, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -185,6 +358,7 @@ pub struct DeregisterInstanceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DiscoverInstancesRequest {
     /// <p>The health status of the instances that you want to discover.</p>
     #[serde(rename = "HealthStatus")]
@@ -206,8 +380,39 @@ pub struct DiscoverInstancesRequest {
     pub service_name: String,
 }
 
+impl Validate for DiscoverInstancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "namespace_name"),
+            self.namespace_name.chars().count(),
+            None,
+            Some(1024),
+        );
+        if let Some(ref value) = self.query_parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "query_parameters"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^([a-zA-Z0-9!-~][ \\ta-zA-Z0-9!-~]*){0,1}[a-zA-Z0-9!-~]{0,1}$",
+                );
+            }
+        }
+        errors.check_pattern(&field_path(path, "service_name"), &self.service_name, "((?=^.{1,127}$)^([a-zA-Z0-9_][a-zA-Z0-9-_]{0,61}[a-zA-Z0-9_]|[a-zA-Z0-9])(\\.([a-zA-Z0-9_][a-zA-Z0-9-_]{0,61}[a-zA-Z0-9_]|[a-zA-Z0-9]))*$)|(^\\.$)");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DiscoverInstancesResponse {
     /// <p>A complex type that contains one <code>HttpInstanceSummary</code> for each registered instance.</p>
     #[serde(rename = "Instances")]
@@ -227,17 +432,36 @@ pub struct DnsConfig {
     pub routing_policy: Option<String>,
 }
 
+impl Validate for DnsConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.dns_records.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "dns_records"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>A complex type that contains information about changes to the Route 53 DNS records that AWS Cloud Map creates when you register an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DnsConfigChange {
     /// <p>An array that contains one <code>DnsRecord</code> object for each Route 53 record that you want AWS Cloud Map to create when you register an instance.</p>
     #[serde(rename = "DnsRecords")]
     pub dns_records: Vec<DnsRecord>,
 }
 
+impl Validate for DnsConfigChange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.dns_records.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "dns_records"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>A complex type that contains the ID for the Route 53 hosted zone that AWS Cloud Map creates when you create a namespace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DnsProperties {
     /// <p>The ID for the Route 53 hosted zone that AWS Cloud Map creates when you create a namespace.</p>
     #[serde(rename = "HostedZoneId")]
@@ -256,7 +480,14 @@ pub struct DnsRecord {
     pub type_: String,
 }
 
+impl Validate for DnsRecord {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(&field_path(path, "ttl"), self.ttl, None, Some(2147483647));
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstanceRequest {
     /// <p>The ID of the instance that you want to get information about.</p>
     #[serde(rename = "InstanceId")]
@@ -266,8 +497,25 @@ pub struct GetInstanceRequest {
     pub service_id: String,
 }
 
+impl Validate for GetInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "service_id"),
+            self.service_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstanceResponse {
     /// <p>A complex type that contains information about a specified instance.</p>
     #[serde(rename = "Instance")]
@@ -276,6 +524,7 @@ pub struct GetInstanceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInstancesHealthStatusRequest {
     /// <p><p>An array that contains the IDs of all the instances that you want to get the health status for.</p> <p>If you omit <code>Instances</code>, AWS Cloud Map returns the health status for all the instances that are associated with the specified service.</p> <note> <p>To get the IDs for the instances that you&#39;ve registered by using a specified service, submit a <a>ListInstances</a> request.</p> </note></p>
     #[serde(rename = "Instances")]
@@ -294,8 +543,37 @@ pub struct GetInstancesHealthStatusRequest {
     pub service_id: String,
 }
 
+impl Validate for GetInstancesHealthStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.instances {
+            errors.check_length(&field_path(path, "instances"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instances"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(64));
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_id"),
+            self.service_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInstancesHealthStatusResponse {
     /// <p>If more than <code>MaxResults</code> instances match the specified criteria, you can submit another <code>GetInstancesHealthStatus</code> request to get the next group of results. Specify the value of <code>NextToken</code> from the previous response in the next request.</p>
     #[serde(rename = "NextToken")]
@@ -308,14 +586,26 @@ pub struct GetInstancesHealthStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetNamespaceRequest {
     /// <p>The ID of the namespace that you want to get information about.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for GetNamespaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetNamespaceResponse {
     /// <p>A complex type that contains information about the specified namespace.</p>
     #[serde(rename = "Namespace")]
@@ -324,14 +614,26 @@ pub struct GetNamespaceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOperationRequest {
     /// <p>The ID of the operation that you want to get more information about.</p>
     #[serde(rename = "OperationId")]
     pub operation_id: String,
 }
 
+impl Validate for GetOperationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "operation_id"),
+            self.operation_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOperationResponse {
     /// <p>A complex type that contains information about the operation.</p>
     #[serde(rename = "Operation")]
@@ -340,14 +642,26 @@ pub struct GetOperationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetServiceRequest {
     /// <p>The ID of the service that you want to get settings for.</p>
     #[serde(rename = "Id")]
     pub id: String,
 }
 
+impl Validate for GetServiceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetServiceResponse {
     /// <p>A complex type that contains information about the service.</p>
     #[serde(rename = "Service")]
@@ -371,6 +685,27 @@ pub struct HealthCheckConfig {
     pub type_: String,
 }
 
+impl Validate for HealthCheckConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.failure_threshold {
+            errors.check_range(
+                &field_path(path, "failure_threshold"),
+                *value,
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.resource_path {
+            errors.check_length(
+                &field_path(path, "resource_path"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>A complex type that contains information about an optional custom health check. A custom health check, which requires that you use a third-party health checker to evaluate the health of your resources, is useful in the following circumstances:</p> <ul> <li> <p>You can't use a health check that is defined by <code>HealthCheckConfig</code> because the resource isn't available over the internet. For example, you can use a custom health check when the instance is in an Amazon VPC. (To check the health of resources in a VPC, the health checker must also be in the VPC.)</p> </li> <li> <p>You want to use a third-party health checker regardless of where your resources are.</p> </li> </ul> <important> <p>If you specify a health check configuration, you can specify either <code>HealthCheckCustomConfig</code> or <code>HealthCheckConfig</code> but not both.</p> </important> <p>To change the status of a custom health check, submit an <code>UpdateInstanceCustomHealthStatus</code> request. Cloud Map doesn't monitor the status of the resource, it just keeps a record of the status specified in the most recent <code>UpdateInstanceCustomHealthStatus</code> request.</p> <p>Here's how custom health checks work:</p> <ol> <li> <p>You create a service and specify a value for <code>FailureThreshold</code>. </p> <p>The failure threshold indicates the number of 30-second intervals you want AWS Cloud Map to wait between the time that your application sends an <a>UpdateInstanceCustomHealthStatus</a> request and the time that AWS Cloud Map stops routing internet traffic to the corresponding resource.</p> </li> <li> <p>You register an instance.</p> </li> <li> <p>You configure a third-party health checker to monitor the resource that is associated with the new instance. </p> <note> <p>AWS Cloud Map doesn't check the health of the resource directly. </p> </note> </li> <li> <p>The third-party health-checker determines that the resource is unhealthy and notifies your application.</p> </li> <li> <p>Your application submits an <code>UpdateInstanceCustomHealthStatus</code> request.</p> </li> <li> <p>AWS Cloud Map waits for (<code>FailureThreshold</code> x 30) seconds.</p> </li> <li> <p>If another <code>UpdateInstanceCustomHealthStatus</code> request doesn't arrive during that time to change the status back to healthy, AWS Cloud Map stops routing traffic to the resource.</p> </li> </ol> <p>Note the following about configuring custom health checks.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HealthCheckCustomConfig {
@@ -382,7 +717,7 @@ pub struct HealthCheckCustomConfig {
 
 /// <p>In a response to a <a>DiscoverInstance</a> request, <code>HttpInstanceSummary</code> contains information about one instance that matches the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HttpInstanceSummary {
     /// <p>If you included any attributes when you registered the instance, the values of those attributes.</p>
     #[serde(rename = "Attributes")]
@@ -408,7 +743,7 @@ pub struct HttpInstanceSummary {
 
 /// <p>A complex type that contains the name of an HTTP namespace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HttpProperties {
     /// <p>The name of an HTTP namespace.</p>
     #[serde(rename = "HttpName")]
@@ -418,7 +753,7 @@ pub struct HttpProperties {
 
 /// <p>A complex type that contains information about an instance that AWS Cloud Map creates when you submit a <code>RegisterInstance</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Instance {
     /// <p>A string map that contains the following information for the service that you specify in <code>ServiceId</code>:</p> <ul> <li> <p>The attributes that apply to the records that are defined in the service. </p> </li> <li> <p>For each attribute, the applicable value.</p> </li> </ul> <p>Supported attribute keys include the following:</p> <p> <b>AWS_ALIAS_DNS_NAME</b> </p> <p> <b/> </p> <p>If you want AWS Cloud Map to create a Route 53 alias record that routes traffic to an Elastic Load Balancing load balancer, specify the DNS name that is associated with the load balancer. For information about how to get the DNS name, see "DNSName" in the topic <a href="http://docs.aws.amazon.com/Route53/latest/APIReference/API_AliasTarget.html">AliasTarget</a>.</p> <p>Note the following:</p> <ul> <li> <p>The configuration for the service that is specified by <code>ServiceId</code> must include settings for an A record, an AAAA record, or both.</p> </li> <li> <p>In the service that is specified by <code>ServiceId</code>, the value of <code>RoutingPolicy</code> must be <code>WEIGHTED</code>.</p> </li> <li> <p>If the service that is specified by <code>ServiceId</code> includes <code>HealthCheckConfig</code> settings, AWS Cloud Map will create the health check, but it won't associate the health check with the alias record.</p> </li> <li> <p>Auto naming currently doesn't support creating alias records that route traffic to AWS resources other than ELB load balancers.</p> </li> <li> <p>If you specify a value for <code>AWS_ALIAS_DNS_NAME</code>, don't specify values for any of the <code>AWS_INSTANCE</code> attributes.</p> </li> </ul> <p> <b>AWS_INSTANCE_CNAME</b> </p> <p>If the service configuration includes a CNAME record, the domain name that you want Route 53 to return in response to DNS queries, for example, <code>example.com</code>.</p> <p>This value is required if the service specified by <code>ServiceId</code> includes settings for an CNAME record.</p> <p> <b>AWS_INSTANCE_IPV4</b> </p> <p>If the service configuration includes an A record, the IPv4 address that you want Route 53 to return in response to DNS queries, for example, <code>192.0.2.44</code>.</p> <p>This value is required if the service specified by <code>ServiceId</code> includes settings for an A record. If the service includes settings for an SRV record, you must specify a value for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both.</p> <p> <b>AWS_INSTANCE_IPV6</b> </p> <p>If the service configuration includes an AAAA record, the IPv6 address that you want Route 53 to return in response to DNS queries, for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>.</p> <p>This value is required if the service specified by <code>ServiceId</code> includes settings for an AAAA record. If the service includes settings for an SRV record, you must specify a value for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both.</p> <p> <b>AWS_INSTANCE_PORT</b> </p> <p>If the service includes an SRV record, the value that you want Route 53 to return for the port.</p> <p>If the service includes <code>HealthCheckConfig</code>, the port on the endpoint that you want Route 53 to send requests to. </p> <p>This value is required if you specified settings for an SRV record when you created the service.</p>
     #[serde(rename = "Attributes")]
@@ -435,7 +770,7 @@ pub struct Instance {
 
 /// <p>A complex type that contains information about the instances that you registered by using a specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceSummary {
     /// <p><p>A string map that contains the following information:</p> <ul> <li> <p>The attributes that are associate with the instance. </p> </li> <li> <p>For each attribute, the applicable value.</p> </li> </ul> <p>Supported attribute keys include the following:</p> <ul> <li> <p> <code>AWS<em>ALIAS</em>DNS<em>NAME</code>: For an alias record that routes traffic to an Elastic Load Balancing load balancer, the DNS name that is associated with the load balancer. </p> </li> <li> <p> <code>AWS</em>INSTANCE<em>CNAME</code>: For a CNAME record, the domain name that Route 53 returns in response to DNS queries, for example, <code>example.com</code>.</p> </li> <li> <p> <code>AWS</em>INSTANCE<em>IPV4</code>: For an A record, the IPv4 address that Route 53 returns in response to DNS queries, for example, <code>192.0.2.44</code>.</p> </li> <li> <p> <code>AWS</em>INSTANCE<em>IPV6</code>: For an AAAA record, the IPv6 address that Route 53 returns in response to DNS queries, for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>.</p> </li> <li> <p> <code>AWS</em>INSTANCE_PORT</code>: For an SRV record, the value that Route 53 returns for the port. In addition, if the service includes <code>HealthCheckConfig</code>, the port on the endpoint that Route 53 sends requests to.</p> </li> </ul></p>
     #[serde(rename = "Attributes")]
@@ -448,6 +783,7 @@ pub struct InstanceSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInstancesRequest {
     /// <p>The maximum number of instances that you want AWS Cloud Map to return in the response to a <code>ListInstances</code> request. If you don't specify a value for <code>MaxResults</code>, AWS Cloud Map returns up to 100 instances.</p>
     #[serde(rename = "MaxResults")]
@@ -462,8 +798,30 @@ pub struct ListInstancesRequest {
     pub service_id: String,
 }
 
+impl Validate for ListInstancesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_id"),
+            self.service_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInstancesResponse {
     /// <p>Summary information about the instances that are associated with the specified service.</p>
     #[serde(rename = "Instances")]
@@ -476,6 +834,7 @@ pub struct ListInstancesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListNamespacesRequest {
     /// <p>A complex type that contains specifications for the namespaces that you want to list.</p> <p>If you specify more than one filter, a namespace must match all filters to be returned by <code>ListNamespaces</code>.</p>
     #[serde(rename = "Filters")]
@@ -491,8 +850,30 @@ pub struct ListNamespacesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListNamespacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListNamespacesResponse {
     /// <p>An array that contains one <code>NamespaceSummary</code> object for each namespace that matches the specified filter criteria.</p>
     #[serde(rename = "Namespaces")]
@@ -505,6 +886,7 @@ pub struct ListNamespacesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOperationsRequest {
     /// <p>A complex type that contains specifications for the operations that you want to list, for example, operations that you started between a specified start date and end date.</p> <p>If you specify more than one filter, an operation must match all filters to be returned by <code>ListOperations</code>.</p>
     #[serde(rename = "Filters")]
@@ -520,8 +902,30 @@ pub struct ListOperationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListOperationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOperationsResponse {
     /// <p><p>If the response contains <code>NextToken</code>, submit another <code>ListOperations</code> request to get the next group of results. Specify the value of <code>NextToken</code> from the previous response in the next request.</p> <note> <p>AWS Cloud Map gets <code>MaxResults</code> operations and then filters them based on the specified criteria. It&#39;s possible that no operations in the first <code>MaxResults</code> operations matched the specified criteria but that subsequent groups of <code>MaxResults</code> operations do contain operations that match the criteria.</p> </note></p>
     #[serde(rename = "NextToken")]
@@ -534,6 +938,7 @@ pub struct ListOperationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListServicesRequest {
     /// <p>A complex type that contains specifications for the namespaces that you want to list services for. </p> <p>If you specify more than one filter, an operation must match all filters to be returned by <code>ListServices</code>.</p>
     #[serde(rename = "Filters")]
@@ -549,8 +954,30 @@ pub struct ListServicesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListServicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListServicesResponse {
     /// <p><p>If the response contains <code>NextToken</code>, submit another <code>ListServices</code> request to get the next group of results. Specify the value of <code>NextToken</code> from the previous response in the next request.</p> <note> <p>AWS Cloud Map gets <code>MaxResults</code> services and then filters them based on the specified criteria. It&#39;s possible that no services in the first <code>MaxResults</code> services matched the specified criteria but that subsequent groups of <code>MaxResults</code> services do contain services that match the criteria.</p> </note></p>
     #[serde(rename = "NextToken")]
@@ -564,7 +991,7 @@ pub struct ListServicesResponse {
 
 /// <p>A complex type that contains information about a specified namespace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Namespace {
     /// <p>The Amazon Resource Name (ARN) that AWS Cloud Map assigns to the namespace when you create it.</p>
     #[serde(rename = "Arn")]
@@ -606,6 +1033,7 @@ pub struct Namespace {
 
 /// <p>A complex type that identifies the namespaces that you want to list. You can choose to list public or private namespaces.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NamespaceFilter {
     /// <p><p>The operator that you want to use to determine whether <code>ListNamespaces</code> returns a namespace. Valid values for <code>condition</code> include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can choose to list only public namespaces or private namespaces, but not both. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can choose to list public namespaces, private namespaces, or both. </p> </li> <li> <p> <code>BETWEEN</code>: Not applicable</p> </li> </ul></p>
     #[serde(rename = "Condition")]
@@ -619,9 +1047,18 @@ pub struct NamespaceFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for NamespaceFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+    }
+}
+
 /// <p>A complex type that contains information that is specific to the namespace type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NamespaceProperties {
     /// <p>A complex type that contains the ID for the Route 53 hosted zone that AWS Cloud Map creates when you create a namespace.</p>
     #[serde(rename = "DnsProperties")]
@@ -635,7 +1072,7 @@ pub struct NamespaceProperties {
 
 /// <p>A complex type that contains information about a namespace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NamespaceSummary {
     /// <p>The Amazon Resource Name (ARN) that AWS Cloud Map assigns to the namespace when you create it.</p>
     #[serde(rename = "Arn")]
@@ -672,7 +1109,7 @@ pub struct NamespaceSummary {
 
 /// <p>A complex type that contains information about a specified operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Operation {
     /// <p>The date and time that the request was submitted, in Unix date/time format and Coordinated Universal Time (UTC). The value of <code>CreateDate</code> is accurate to milliseconds. For example, the value <code>1516925490.087</code> represents Friday, January 26, 2018 12:11:30.087 AM.</p>
     #[serde(rename = "CreateDate")]
@@ -710,6 +1147,7 @@ pub struct Operation {
 
 /// <p>A complex type that lets you select the operations that you want to list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OperationFilter {
     /// <p><p>The operator that you want to use to determine whether an operation matches the specified value. Valid values for condition include:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code> for the condition, you can specify only one value. <code>EQ</code> is supported for <code>NAMESPACE<em>ID</code>, <code>SERVICE</em>ID</code>, <code>STATUS</code>, and <code>TYPE</code>. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code> for the condition, you can specify a list of one or more values. <code>IN</code> is supported for <code>STATUS</code> and <code>TYPE</code>. An operation must match one of the specified values to be returned in the response.</p> </li> <li> <p> <code>BETWEEN</code>: Specify a start date and an end date in Unix date/time format and Coordinated Universal Time (UTC). The start date must be the first value. <code>BETWEEN</code> is supported for <code>UPDATE_DATE</code>. </p> </li> </ul></p>
     #[serde(rename = "Condition")]
@@ -723,9 +1161,18 @@ pub struct OperationFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for OperationFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+    }
+}
+
 /// <p>A complex type that contains information about an operation that matches the criteria that you specified in a <a>ListOperations</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OperationSummary {
     /// <p>The ID for an operation.</p>
     #[serde(rename = "Id")]
@@ -738,6 +1185,7 @@ pub struct OperationSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterInstanceRequest {
     /// <p>A string map that contains the following information for the service that you specify in <code>ServiceId</code>:</p> <ul> <li> <p>The attributes that apply to the records that are defined in the service. </p> </li> <li> <p>For each attribute, the applicable value.</p> </li> </ul> <p>Supported attribute keys include the following:</p> <p> <b>AWS_ALIAS_DNS_NAME</b> </p> <p> <b/> </p> <p>If you want AWS Cloud Map to create an Amazon Route 53 alias record that routes traffic to an Elastic Load Balancing load balancer, specify the DNS name that is associated with the load balancer. For information about how to get the DNS name, see "DNSName" in the topic <a href="http://docs.aws.amazon.com/Route53/latest/APIReference/API_AliasTarget.html">AliasTarget</a> in the <i>Route 53 API Reference</i>.</p> <p>Note the following:</p> <ul> <li> <p>The configuration for the service that is specified by <code>ServiceId</code> must include settings for an A record, an AAAA record, or both.</p> </li> <li> <p>In the service that is specified by <code>ServiceId</code>, the value of <code>RoutingPolicy</code> must be <code>WEIGHTED</code>.</p> </li> <li> <p>If the service that is specified by <code>ServiceId</code> includes <code>HealthCheckConfig</code> settings, AWS Cloud Map will create the Route 53 health check, but it won't associate the health check with the alias record.</p> </li> <li> <p>Auto naming currently doesn't support creating alias records that route traffic to AWS resources other than ELB load balancers.</p> </li> <li> <p>If you specify a value for <code>AWS_ALIAS_DNS_NAME</code>, don't specify values for any of the <code>AWS_INSTANCE</code> attributes.</p> </li> </ul> <p> <b>AWS_INIT_HEALTH_STATUS</b> </p> <p>If the service configuration includes <code>HealthCheckCustomConfig</code>, you can optionally use <code>AWS_INIT_HEALTH_STATUS</code> to specify the initial status of the custom health check, <code>HEALTHY</code> or <code>UNHEALTHY</code>. If you don't specify a value for <code>AWS_INIT_HEALTH_STATUS</code>, the initial status is <code>HEALTHY</code>.</p> <p> <b>AWS_INSTANCE_CNAME</b> </p> <p>If the service configuration includes a CNAME record, the domain name that you want Route 53 to return in response to DNS queries, for example, <code>example.com</code>.</p> <p>This value is required if the service specified by <code>ServiceId</code> includes settings for an CNAME record.</p> <p> <b>AWS_INSTANCE_IPV4</b> </p> <p>If the service configuration includes an A record, the IPv4 address that you want Route 53 to return in response to DNS queries, for example, <code>192.0.2.44</code>.</p> <p>This value is required if the service specified by <code>ServiceId</code> includes settings for an A record. If the service includes settings for an SRV record, you must specify a value for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both.</p> <p> <b>AWS_INSTANCE_IPV6</b> </p> <p>If the service configuration includes an AAAA record, the IPv6 address that you want Route 53 to return in response to DNS queries, for example, <code>2001:0db8:85a3:0000:0000:abcd:0001:2345</code>.</p> <p>This value is required if the service specified by <code>ServiceId</code> includes settings for an AAAA record. If the service includes settings for an SRV record, you must specify a value for <code>AWS_INSTANCE_IPV4</code>, <code>AWS_INSTANCE_IPV6</code>, or both.</p> <p> <b>AWS_INSTANCE_PORT</b> </p> <p>If the service includes an SRV record, the value that you want Route 53 to return for the port.</p> <p>If the service includes <code>HealthCheckConfig</code>, the port on the endpoint that you want Route 53 to send requests to. </p> <p>This value is required if you specified settings for an SRV record when you created the service.</p> <p> <b>Custom attributes</b> </p> <p>You can add up to 30 custom attributes. For each key-value pair, the maximum length of the attribute name is 255 characters, and the maximum length of the attribute value is 1,024 characters. </p>
     #[serde(rename = "Attributes")]
@@ -754,8 +1202,47 @@ pub struct RegisterInstanceRequest {
     pub service_id: String,
 }
 
+impl Validate for RegisterInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (key, item) in self.attributes.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+            errors.check_length(&item_path, item.chars().count(), None, Some(1024));
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "^([a-zA-Z0-9!-~][ \\ta-zA-Z0-9!-~]*){0,1}[a-zA-Z0-9!-~]{0,1}$",
+            );
+        }
+        if let Some(ref value) = self.creator_request_id {
+            errors.check_length(
+                &field_path(path, "creator_request_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "^[0-9a-zA-Z_/:.@-]+$",
+        );
+        errors.check_length(
+            &field_path(path, "service_id"),
+            self.service_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterInstanceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. To get the status of the operation, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -765,7 +1252,7 @@ pub struct RegisterInstanceResponse {
 
 /// <p>A complex type that contains information about the specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Service {
     /// <p>The Amazon Resource Name (ARN) that AWS Cloud Map assigns to the service when you create it.</p>
     #[serde(rename = "Arn")]
@@ -815,6 +1302,7 @@ pub struct Service {
 
 /// <p>A complex type that contains changes to an existing service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServiceChange {
     /// <p>A description for the service.</p>
     #[serde(rename = "Description")]
@@ -828,8 +1316,27 @@ pub struct ServiceChange {
     pub health_check_config: Option<HealthCheckConfig>,
 }
 
+impl Validate for ServiceChange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        self.dns_config
+            .validate_at(&field_path(path, "dns_config"), errors);
+        if let Some(ref value) = self.health_check_config {
+            value.validate_at(&field_path(path, "health_check_config"), errors);
+        }
+    }
+}
+
 /// <p>A complex type that lets you specify the namespaces that you want to list services for.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ServiceFilter {
     /// <p><p>The operator that you want to use to determine whether a service is returned by <code>ListServices</code>. Valid values for <code>Condition</code> include the following:</p> <ul> <li> <p> <code>EQ</code>: When you specify <code>EQ</code>, specify one namespace ID for <code>Values</code>. <code>EQ</code> is the default condition and can be omitted.</p> </li> <li> <p> <code>IN</code>: When you specify <code>IN</code>, specify a list of the IDs for the namespaces that you want <code>ListServices</code> to return a list of services for.</p> </li> <li> <p> <code>BETWEEN</code>: Not applicable.</p> </li> </ul></p>
     #[serde(rename = "Condition")]
@@ -843,9 +1350,18 @@ pub struct ServiceFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for ServiceFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+        }
+    }
+}
+
 /// <p>A complex type that contains information about a specified service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceSummary {
     /// <p>The Amazon Resource Name (ARN) that AWS Cloud Map assigns to the service when you create it.</p>
     #[serde(rename = "Arn")]
@@ -883,6 +1399,7 @@ pub struct ServiceSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateInstanceCustomHealthStatusRequest {
     /// <p>The ID of the instance that you want to change the health status for.</p>
     #[serde(rename = "InstanceId")]
@@ -895,7 +1412,25 @@ pub struct UpdateInstanceCustomHealthStatusRequest {
     pub status: String,
 }
 
+impl Validate for UpdateInstanceCustomHealthStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "service_id"),
+            self.service_id.chars().count(),
+            None,
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceRequest {
     /// <p>The ID of the service that you want to update.</p>
     #[serde(rename = "Id")]
@@ -905,8 +1440,21 @@ pub struct UpdateServiceRequest {
     pub service: ServiceChange,
 }
 
+impl Validate for UpdateServiceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            None,
+            Some(64),
+        );
+        self.service
+            .validate_at(&field_path(path, "service"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServiceResponse {
     /// <p>A value that you can use to determine whether the request completed successfully. To get the status of the operation, see <a>GetOperation</a>.</p>
     #[serde(rename = "OperationId")]
@@ -949,7 +1497,12 @@ impl CreateHttpNamespaceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1008,7 +1561,12 @@ impl CreatePrivateDnsNamespaceError {
                         CreatePrivateDnsNamespaceError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1067,7 +1625,12 @@ impl CreatePublicDnsNamespaceError {
                         CreatePublicDnsNamespaceError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1118,7 +1681,12 @@ impl CreateServiceError {
                 "ServiceAlreadyExists" => {
                     return RusotoError::Service(CreateServiceError::ServiceAlreadyExists(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1169,7 +1737,12 @@ impl DeleteNamespaceError {
                 "ResourceInUse" => {
                     return RusotoError::Service(DeleteNamespaceError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1215,7 +1788,12 @@ impl DeleteServiceError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1270,7 +1848,12 @@ impl DeregisterInstanceError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(DeregisterInstanceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1317,7 +1900,12 @@ impl DiscoverInstancesError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(DiscoverInstancesError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1362,7 +1950,12 @@ impl GetInstanceError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(GetInstanceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1413,7 +2006,12 @@ impl GetInstancesHealthStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1453,7 +2051,12 @@ impl GetNamespaceError {
                 "NamespaceNotFound" => {
                     return RusotoError::Service(GetNamespaceError::NamespaceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1492,7 +2095,12 @@ impl GetOperationError {
                 "OperationNotFound" => {
                     return RusotoError::Service(GetOperationError::OperationNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1531,7 +2139,12 @@ impl GetServiceError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(GetServiceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1570,7 +2183,12 @@ impl ListInstancesError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(ListInstancesError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1604,7 +2222,12 @@ impl ListNamespacesError {
                 "InvalidInput" => {
                     return RusotoError::Service(ListNamespacesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1637,7 +2260,12 @@ impl ListOperationsError {
                 "InvalidInput" => {
                     return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1670,7 +2298,12 @@ impl ListServicesError {
                 "InvalidInput" => {
                     return RusotoError::Service(ListServicesError::InvalidInput(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1725,7 +2358,12 @@ impl RegisterInstanceError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(RegisterInstanceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1787,7 +2425,12 @@ impl UpdateInstanceCustomHealthStatusError {
                         UpdateInstanceCustomHealthStatusError::ServiceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1833,7 +2476,12 @@ impl UpdateServiceError {
                 "ServiceNotFound" => {
                     return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1976,125 +2624,519 @@ pub trait ServiceDiscovery {
         input: UpdateServiceRequest,
     ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError>;
 }
-/// A client for the ServiceDiscovery API.
-#[derive(Clone)]
-pub struct ServiceDiscoveryClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ServiceDiscoveryClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ServiceDiscoveryClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ServiceDiscoveryClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
-        ServiceDiscoveryClient { client, region }
-    }
-}
-
-impl ServiceDiscovery for ServiceDiscoveryClient {
-    /// <p>Creates an HTTP namespace. Service instances that you register using an HTTP namespace can be discovered using a <code>DiscoverInstances</code> request but can't be discovered using DNS. </p> <p>For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+const _: Option<&dyn ServiceDiscovery> = None;
+impl<'a, T: ServiceDiscovery + ?Sized> ServiceDiscovery for &'a T {
     fn create_http_namespace(
         &self,
         input: CreateHttpNamespaceRequest,
     ) -> RusotoFuture<CreateHttpNamespaceResponse, CreateHttpNamespaceError> {
-        let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Route53AutoNaming_v20170314.CreateHttpNamespace",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateHttpNamespaceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateHttpNamespaceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_http_namespace(input)
     }
-
-    /// <p>Creates a private namespace based on DNS, which will be visible only inside a specified Amazon VPC. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
     fn create_private_dns_namespace(
         &self,
         input: CreatePrivateDnsNamespaceRequest,
     ) -> RusotoFuture<CreatePrivateDnsNamespaceResponse, CreatePrivateDnsNamespaceError> {
-        let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Route53AutoNaming_v20170314.CreatePrivateDnsNamespace",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePrivateDnsNamespaceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePrivateDnsNamespaceError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_private_dns_namespace(input)
     }
-
-    /// <p>Creates a public namespace based on DNS, which will be visible on the internet. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
     fn create_public_dns_namespace(
         &self,
         input: CreatePublicDnsNamespaceRequest,
     ) -> RusotoFuture<CreatePublicDnsNamespaceResponse, CreatePublicDnsNamespaceError> {
-        let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
-
+        (**self).create_public_dns_namespace(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> RusotoFuture<DeleteNamespaceResponse, DeleteNamespaceError> {
+        (**self).delete_namespace(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<DeregisterInstanceResponse, DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> RusotoFuture<DiscoverInstancesResponse, DiscoverInstancesError> {
+        (**self).discover_instances(input)
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResponse, GetInstanceError> {
+        (**self).get_instance(input)
+    }
+    fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> RusotoFuture<GetInstancesHealthStatusResponse, GetInstancesHealthStatusError> {
+        (**self).get_instances_health_status(input)
+    }
+    fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> RusotoFuture<GetNamespaceResponse, GetNamespaceError> {
+        (**self).get_namespace(input)
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResponse, GetOperationError> {
+        (**self).get_operation(input)
+    }
+    fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> RusotoFuture<GetServiceResponse, GetServiceError> {
+        (**self).get_service(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> RusotoFuture<ListNamespacesResponse, ListNamespacesError> {
+        (**self).list_namespaces(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResponse, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> RusotoFuture<(), UpdateInstanceCustomHealthStatusError> {
+        (**self).update_instance_custom_health_status(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+}
+impl<T: ServiceDiscovery + ?Sized> ServiceDiscovery for Box<T> {
+    fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> RusotoFuture<CreateHttpNamespaceResponse, CreateHttpNamespaceError> {
+        (**self).create_http_namespace(input)
+    }
+    fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePrivateDnsNamespaceResponse, CreatePrivateDnsNamespaceError> {
+        (**self).create_private_dns_namespace(input)
+    }
+    fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePublicDnsNamespaceResponse, CreatePublicDnsNamespaceError> {
+        (**self).create_public_dns_namespace(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> RusotoFuture<DeleteNamespaceResponse, DeleteNamespaceError> {
+        (**self).delete_namespace(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<DeregisterInstanceResponse, DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> RusotoFuture<DiscoverInstancesResponse, DiscoverInstancesError> {
+        (**self).discover_instances(input)
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResponse, GetInstanceError> {
+        (**self).get_instance(input)
+    }
+    fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> RusotoFuture<GetInstancesHealthStatusResponse, GetInstancesHealthStatusError> {
+        (**self).get_instances_health_status(input)
+    }
+    fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> RusotoFuture<GetNamespaceResponse, GetNamespaceError> {
+        (**self).get_namespace(input)
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResponse, GetOperationError> {
+        (**self).get_operation(input)
+    }
+    fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> RusotoFuture<GetServiceResponse, GetServiceError> {
+        (**self).get_service(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> RusotoFuture<ListNamespacesResponse, ListNamespacesError> {
+        (**self).list_namespaces(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResponse, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> RusotoFuture<(), UpdateInstanceCustomHealthStatusError> {
+        (**self).update_instance_custom_health_status(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+}
+impl<T: ServiceDiscovery + ?Sized> ServiceDiscovery for ::std::sync::Arc<T> {
+    fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> RusotoFuture<CreateHttpNamespaceResponse, CreateHttpNamespaceError> {
+        (**self).create_http_namespace(input)
+    }
+    fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePrivateDnsNamespaceResponse, CreatePrivateDnsNamespaceError> {
+        (**self).create_private_dns_namespace(input)
+    }
+    fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePublicDnsNamespaceResponse, CreatePublicDnsNamespaceError> {
+        (**self).create_public_dns_namespace(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> RusotoFuture<DeleteNamespaceResponse, DeleteNamespaceError> {
+        (**self).delete_namespace(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<DeregisterInstanceResponse, DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> RusotoFuture<DiscoverInstancesResponse, DiscoverInstancesError> {
+        (**self).discover_instances(input)
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResponse, GetInstanceError> {
+        (**self).get_instance(input)
+    }
+    fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> RusotoFuture<GetInstancesHealthStatusResponse, GetInstancesHealthStatusError> {
+        (**self).get_instances_health_status(input)
+    }
+    fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> RusotoFuture<GetNamespaceResponse, GetNamespaceError> {
+        (**self).get_namespace(input)
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResponse, GetOperationError> {
+        (**self).get_operation(input)
+    }
+    fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> RusotoFuture<GetServiceResponse, GetServiceError> {
+        (**self).get_service(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> RusotoFuture<ListNamespacesResponse, ListNamespacesError> {
+        (**self).list_namespaces(input)
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        (**self).list_operations(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResponse, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> RusotoFuture<(), UpdateInstanceCustomHealthStatusError> {
+        (**self).update_instance_custom_health_status(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+}
+/// A client for the ServiceDiscovery API.
+#[derive(Clone)]
+pub struct ServiceDiscoveryClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ServiceDiscoveryClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServiceDiscoveryClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceDiscoveryClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
+        ServiceDiscoveryClient { client, region }
+    }
+}
+
+impl ServiceDiscovery for ServiceDiscoveryClient {
+    /// <p>Creates an HTTP namespace. Service instances that you register using an HTTP namespace can be discovered using a <code>DiscoverInstances</code> request but can't be discovered using DNS. </p> <p>For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> RusotoFuture<CreateHttpNamespaceResponse, CreateHttpNamespaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreateHttpNamespace");
+
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "Route53AutoNaming_v20170314.CreatePublicDnsNamespace",
+            "Route53AutoNaming_v20170314.CreateHttpNamespace",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateHttpNamespaceResponse, _>(
+                    response,
+                    "CreateHttpNamespace",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateHttpNamespaceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a private namespace based on DNS, which will be visible only inside a specified Amazon VPC. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePrivateDnsNamespaceResponse, CreatePrivateDnsNamespaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreatePrivateDnsNamespace");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Route53AutoNaming_v20170314.CreatePrivateDnsNamespace",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePrivateDnsNamespaceResponse, _>(
+                    response,
+                    "CreatePrivateDnsNamespace",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePublicDnsNamespaceResponse, _>()
+                    Err(CreatePrivateDnsNamespaceError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Creates a public namespace based on DNS, which will be visible on the internet. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="http://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePublicDnsNamespaceResponse, CreatePublicDnsNamespaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreatePublicDnsNamespace");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "Route53AutoNaming_v20170314.CreatePublicDnsNamespace",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePublicDnsNamespaceResponse, _>(
+                    response,
+                    "CreatePublicDnsNamespace",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(CreatePublicDnsNamespaceError::from_response(response))
@@ -2108,7 +3150,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: CreateServiceRequest,
     ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("CreateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.CreateService");
@@ -2117,10 +3168,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateServiceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateServiceResponse, _>(
+                    response,
+                    "CreateService",
+                )
             } else {
                 Box::new(
                     response
@@ -2137,7 +3188,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: DeleteNamespaceRequest,
     ) -> RusotoFuture<DeleteNamespaceResponse, DeleteNamespaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeleteNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2149,10 +3209,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteNamespaceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteNamespaceResponse, _>(
+                    response,
+                    "DeleteNamespace",
+                )
             } else {
                 Box::new(
                     response
@@ -2169,7 +3229,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: DeleteServiceRequest,
     ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeleteService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.DeleteService");
@@ -2178,10 +3247,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteServiceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeleteServiceResponse, _>(
+                    response,
+                    "DeleteService",
+                )
             } else {
                 Box::new(
                     response
@@ -2198,7 +3267,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: DeregisterInstanceRequest,
     ) -> RusotoFuture<DeregisterInstanceResponse, DeregisterInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DeregisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2210,10 +3288,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterInstanceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DeregisterInstanceResponse, _>(
+                    response,
+                    "DeregisterInstance",
+                )
             } else {
                 Box::new(
                     response
@@ -2230,7 +3308,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: DiscoverInstancesRequest,
     ) -> RusotoFuture<DiscoverInstancesResponse, DiscoverInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("DiscoverInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2242,10 +3329,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DiscoverInstancesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DiscoverInstancesResponse, _>(
+                    response,
+                    "DiscoverInstances",
+                )
             } else {
                 Box::new(
                     response
@@ -2262,7 +3349,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: GetInstanceRequest,
     ) -> RusotoFuture<GetInstanceResponse, GetInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetInstance");
@@ -2271,10 +3367,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstanceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetInstanceResponse, _>(
+                    response,
+                    "GetInstance",
+                )
             } else {
                 Box::new(
                     response
@@ -2291,7 +3387,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: GetInstancesHealthStatusRequest,
     ) -> RusotoFuture<GetInstancesHealthStatusResponse, GetInstancesHealthStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetInstancesHealthStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2303,10 +3408,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInstancesHealthStatusResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetInstancesHealthStatusResponse, _>(
+                    response,
+                    "GetInstancesHealthStatus",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetInstancesHealthStatusError::from_response(response))
@@ -2320,7 +3425,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: GetNamespaceRequest,
     ) -> RusotoFuture<GetNamespaceResponse, GetNamespaceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetNamespace");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetNamespace");
@@ -2329,10 +3443,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetNamespaceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetNamespaceResponse, _>(
+                    response,
+                    "GetNamespace",
+                )
             } else {
                 Box::new(
                     response
@@ -2349,7 +3463,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: GetOperationRequest,
     ) -> RusotoFuture<GetOperationResponse, GetOperationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetOperation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetOperation");
@@ -2358,10 +3481,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOperationResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetOperationResponse, _>(
+                    response,
+                    "GetOperation",
+                )
             } else {
                 Box::new(
                     response
@@ -2378,7 +3501,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: GetServiceRequest,
     ) -> RusotoFuture<GetServiceResponse, GetServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("GetService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetService");
@@ -2387,10 +3519,7 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetServiceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetServiceResponse, _>(response, "GetService")
             } else {
                 Box::new(
                     response
@@ -2407,7 +3536,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: ListInstancesRequest,
     ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListInstances");
@@ -2416,10 +3554,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInstancesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListInstancesResponse, _>(
+                    response,
+                    "ListInstances",
+                )
             } else {
                 Box::new(
                     response
@@ -2436,7 +3574,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: ListNamespacesRequest,
     ) -> RusotoFuture<ListNamespacesResponse, ListNamespacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListNamespaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListNamespaces");
@@ -2445,10 +3592,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListNamespacesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListNamespacesResponse, _>(
+                    response,
+                    "ListNamespaces",
+                )
             } else {
                 Box::new(
                     response
@@ -2465,7 +3612,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: ListOperationsRequest,
     ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListOperations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListOperations");
@@ -2474,10 +3630,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOperationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListOperationsResponse, _>(
+                    response,
+                    "ListOperations",
+                )
             } else {
                 Box::new(
                     response
@@ -2494,7 +3650,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: ListServicesRequest,
     ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListServices");
@@ -2503,10 +3668,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListServicesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListServicesResponse, _>(
+                    response,
+                    "ListServices",
+                )
             } else {
                 Box::new(
                     response
@@ -2523,7 +3688,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: RegisterInstanceRequest,
     ) -> RusotoFuture<RegisterInstanceResponse, RegisterInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("RegisterInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2535,10 +3709,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterInstanceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RegisterInstanceResponse, _>(
+                    response,
+                    "RegisterInstance",
+                )
             } else {
                 Box::new(
                     response
@@ -2555,7 +3729,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: UpdateInstanceCustomHealthStatusRequest,
     ) -> RusotoFuture<(), UpdateInstanceCustomHealthStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("UpdateInstanceCustomHealthStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2583,7 +3766,16 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         &self,
         input: UpdateServiceRequest,
     ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "servicediscovery", &self.region, "/");
+        request.set_operation("UpdateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UpdateService");
@@ -2592,10 +3784,10 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServiceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateServiceResponse, _>(
+                    response,
+                    "UpdateService",
+                )
             } else {
                 Box::new(
                     response
@@ -2607,3 +3799,707 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         })
     }
 }
+
+/// A mock of the ServiceDiscovery API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockServiceDiscovery {
+    create_http_namespace: Option<
+        Box<
+            dyn Fn(
+                    CreateHttpNamespaceRequest,
+                ) -> ::std::result::Result<
+                    CreateHttpNamespaceResponse,
+                    RusotoError<CreateHttpNamespaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_private_dns_namespace: Option<
+        Box<
+            dyn Fn(
+                    CreatePrivateDnsNamespaceRequest,
+                ) -> ::std::result::Result<
+                    CreatePrivateDnsNamespaceResponse,
+                    RusotoError<CreatePrivateDnsNamespaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_public_dns_namespace: Option<
+        Box<
+            dyn Fn(
+                    CreatePublicDnsNamespaceRequest,
+                ) -> ::std::result::Result<
+                    CreatePublicDnsNamespaceResponse,
+                    RusotoError<CreatePublicDnsNamespaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_service: Option<
+        Box<
+            dyn Fn(
+                    CreateServiceRequest,
+                )
+                    -> ::std::result::Result<CreateServiceResponse, RusotoError<CreateServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_namespace: Option<
+        Box<
+            dyn Fn(
+                    DeleteNamespaceRequest,
+                ) -> ::std::result::Result<
+                    DeleteNamespaceResponse,
+                    RusotoError<DeleteNamespaceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_service: Option<
+        Box<
+            dyn Fn(
+                    DeleteServiceRequest,
+                )
+                    -> ::std::result::Result<DeleteServiceResponse, RusotoError<DeleteServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_instance: Option<
+        Box<
+            dyn Fn(
+                    DeregisterInstanceRequest,
+                ) -> ::std::result::Result<
+                    DeregisterInstanceResponse,
+                    RusotoError<DeregisterInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    discover_instances: Option<
+        Box<
+            dyn Fn(
+                    DiscoverInstancesRequest,
+                ) -> ::std::result::Result<
+                    DiscoverInstancesResponse,
+                    RusotoError<DiscoverInstancesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_instance: Option<
+        Box<
+            dyn Fn(
+                    GetInstanceRequest,
+                )
+                    -> ::std::result::Result<GetInstanceResponse, RusotoError<GetInstanceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_instances_health_status: Option<
+        Box<
+            dyn Fn(
+                    GetInstancesHealthStatusRequest,
+                ) -> ::std::result::Result<
+                    GetInstancesHealthStatusResponse,
+                    RusotoError<GetInstancesHealthStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_namespace: Option<
+        Box<
+            dyn Fn(
+                    GetNamespaceRequest,
+                )
+                    -> ::std::result::Result<GetNamespaceResponse, RusotoError<GetNamespaceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_operation: Option<
+        Box<
+            dyn Fn(
+                    GetOperationRequest,
+                )
+                    -> ::std::result::Result<GetOperationResponse, RusotoError<GetOperationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_service: Option<
+        Box<
+            dyn Fn(
+                    GetServiceRequest,
+                )
+                    -> ::std::result::Result<GetServiceResponse, RusotoError<GetServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_instances: Option<
+        Box<
+            dyn Fn(
+                    ListInstancesRequest,
+                )
+                    -> ::std::result::Result<ListInstancesResponse, RusotoError<ListInstancesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_namespaces: Option<
+        Box<
+            dyn Fn(
+                    ListNamespacesRequest,
+                ) -> ::std::result::Result<
+                    ListNamespacesResponse,
+                    RusotoError<ListNamespacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_operations: Option<
+        Box<
+            dyn Fn(
+                    ListOperationsRequest,
+                ) -> ::std::result::Result<
+                    ListOperationsResponse,
+                    RusotoError<ListOperationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_services: Option<
+        Box<
+            dyn Fn(
+                    ListServicesRequest,
+                )
+                    -> ::std::result::Result<ListServicesResponse, RusotoError<ListServicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_instance: Option<
+        Box<
+            dyn Fn(
+                    RegisterInstanceRequest,
+                ) -> ::std::result::Result<
+                    RegisterInstanceResponse,
+                    RusotoError<RegisterInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_instance_custom_health_status: Option<
+        Box<
+            dyn Fn(
+                    UpdateInstanceCustomHealthStatusRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_service: Option<
+        Box<
+            dyn Fn(
+                    UpdateServiceRequest,
+                )
+                    -> ::std::result::Result<UpdateServiceResponse, RusotoError<UpdateServiceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockServiceDiscovery {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockServiceDiscovery {
+        Default::default()
+    }
+
+    /// Answers `create_http_namespace` with what `handler` returns for its input.
+    pub fn with_create_http_namespace<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                CreateHttpNamespaceRequest,
+            ) -> ::std::result::Result<
+                CreateHttpNamespaceResponse,
+                RusotoError<CreateHttpNamespaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_http_namespace = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_private_dns_namespace` with what `handler` returns for its input.
+    pub fn with_create_private_dns_namespace<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                CreatePrivateDnsNamespaceRequest,
+            ) -> ::std::result::Result<
+                CreatePrivateDnsNamespaceResponse,
+                RusotoError<CreatePrivateDnsNamespaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_private_dns_namespace = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_public_dns_namespace` with what `handler` returns for its input.
+    pub fn with_create_public_dns_namespace<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                CreatePublicDnsNamespaceRequest,
+            ) -> ::std::result::Result<
+                CreatePublicDnsNamespaceResponse,
+                RusotoError<CreatePublicDnsNamespaceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_public_dns_namespace = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_service` with what `handler` returns for its input.
+    pub fn with_create_service<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                CreateServiceRequest,
+            )
+                -> ::std::result::Result<CreateServiceResponse, RusotoError<CreateServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_namespace` with what `handler` returns for its input.
+    pub fn with_delete_namespace<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                DeleteNamespaceRequest,
+            )
+                -> ::std::result::Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_namespace = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_service` with what `handler` returns for its input.
+    pub fn with_delete_service<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                DeleteServiceRequest,
+            )
+                -> ::std::result::Result<DeleteServiceResponse, RusotoError<DeleteServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_instance` with what `handler` returns for its input.
+    pub fn with_deregister_instance<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                DeregisterInstanceRequest,
+            ) -> ::std::result::Result<
+                DeregisterInstanceResponse,
+                RusotoError<DeregisterInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `discover_instances` with what `handler` returns for its input.
+    pub fn with_discover_instances<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                DiscoverInstancesRequest,
+            ) -> ::std::result::Result<
+                DiscoverInstancesResponse,
+                RusotoError<DiscoverInstancesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.discover_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instance` with what `handler` returns for its input.
+    pub fn with_get_instance<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                GetInstanceRequest,
+            )
+                -> ::std::result::Result<GetInstanceResponse, RusotoError<GetInstanceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_instances_health_status` with what `handler` returns for its input.
+    pub fn with_get_instances_health_status<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                GetInstancesHealthStatusRequest,
+            ) -> ::std::result::Result<
+                GetInstancesHealthStatusResponse,
+                RusotoError<GetInstancesHealthStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_instances_health_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_namespace` with what `handler` returns for its input.
+    pub fn with_get_namespace<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                GetNamespaceRequest,
+            )
+                -> ::std::result::Result<GetNamespaceResponse, RusotoError<GetNamespaceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_namespace = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_operation` with what `handler` returns for its input.
+    pub fn with_get_operation<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                GetOperationRequest,
+            )
+                -> ::std::result::Result<GetOperationResponse, RusotoError<GetOperationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_operation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_service` with what `handler` returns for its input.
+    pub fn with_get_service<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                GetServiceRequest,
+            )
+                -> ::std::result::Result<GetServiceResponse, RusotoError<GetServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_service = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_instances` with what `handler` returns for its input.
+    pub fn with_list_instances<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                ListInstancesRequest,
+            )
+                -> ::std::result::Result<ListInstancesResponse, RusotoError<ListInstancesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_instances = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_namespaces` with what `handler` returns for its input.
+    pub fn with_list_namespaces<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                ListNamespacesRequest,
+            )
+                -> ::std::result::Result<ListNamespacesResponse, RusotoError<ListNamespacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_namespaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_operations` with what `handler` returns for its input.
+    pub fn with_list_operations<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                ListOperationsRequest,
+            )
+                -> ::std::result::Result<ListOperationsResponse, RusotoError<ListOperationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_operations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_services` with what `handler` returns for its input.
+    pub fn with_list_services<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                ListServicesRequest,
+            )
+                -> ::std::result::Result<ListServicesResponse, RusotoError<ListServicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_services = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_instance` with what `handler` returns for its input.
+    pub fn with_register_instance<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                RegisterInstanceRequest,
+            ) -> ::std::result::Result<
+                RegisterInstanceResponse,
+                RusotoError<RegisterInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_instance_custom_health_status` with what `handler` returns for its input.
+    pub fn with_update_instance_custom_health_status<F>(
+        mut self,
+        handler: F,
+    ) -> MockServiceDiscovery
+    where
+        F: Fn(
+                UpdateInstanceCustomHealthStatusRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_instance_custom_health_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service` with what `handler` returns for its input.
+    pub fn with_update_service<F>(mut self, handler: F) -> MockServiceDiscovery
+    where
+        F: Fn(
+                UpdateServiceRequest,
+            )
+                -> ::std::result::Result<UpdateServiceResponse, RusotoError<UpdateServiceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ServiceDiscovery for MockServiceDiscovery {
+    fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> RusotoFuture<CreateHttpNamespaceResponse, CreateHttpNamespaceError> {
+        match self.create_http_namespace {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for create_http_namespace"),
+        }
+    }
+    fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePrivateDnsNamespaceResponse, CreatePrivateDnsNamespaceError> {
+        match self.create_private_dns_namespace {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for create_private_dns_namespace"),
+        }
+    }
+    fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> RusotoFuture<CreatePublicDnsNamespaceResponse, CreatePublicDnsNamespaceError> {
+        match self.create_public_dns_namespace {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for create_public_dns_namespace"),
+        }
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        match self.create_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for create_service"),
+        }
+    }
+    fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> RusotoFuture<DeleteNamespaceResponse, DeleteNamespaceError> {
+        match self.delete_namespace {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for delete_namespace"),
+        }
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        match self.delete_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for delete_service"),
+        }
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<DeregisterInstanceResponse, DeregisterInstanceError> {
+        match self.deregister_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for deregister_instance"),
+        }
+    }
+    fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> RusotoFuture<DiscoverInstancesResponse, DiscoverInstancesError> {
+        match self.discover_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for discover_instances"),
+        }
+    }
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResponse, GetInstanceError> {
+        match self.get_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for get_instance"),
+        }
+    }
+    fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> RusotoFuture<GetInstancesHealthStatusResponse, GetInstancesHealthStatusError> {
+        match self.get_instances_health_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for get_instances_health_status"),
+        }
+    }
+    fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> RusotoFuture<GetNamespaceResponse, GetNamespaceError> {
+        match self.get_namespace {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for get_namespace"),
+        }
+    }
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResponse, GetOperationError> {
+        match self.get_operation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for get_operation"),
+        }
+    }
+    fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> RusotoFuture<GetServiceResponse, GetServiceError> {
+        match self.get_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for get_service"),
+        }
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> RusotoFuture<ListInstancesResponse, ListInstancesError> {
+        match self.list_instances {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for list_instances"),
+        }
+    }
+    fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> RusotoFuture<ListNamespacesResponse, ListNamespacesError> {
+        match self.list_namespaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for list_namespaces"),
+        }
+    }
+    fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> RusotoFuture<ListOperationsResponse, ListOperationsError> {
+        match self.list_operations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for list_operations"),
+        }
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        match self.list_services {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for list_services"),
+        }
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResponse, RegisterInstanceError> {
+        match self.register_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for register_instance"),
+        }
+    }
+    fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> RusotoFuture<(), UpdateInstanceCustomHealthStatusError> {
+        match self.update_instance_custom_health_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServiceDiscovery has no handler for update_instance_custom_health_status"
+            ),
+        }
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        match self.update_service {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServiceDiscovery has no handler for update_service"),
+        }
+    }
+}
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -35,6 +36,8 @@ use xml::EventReader;
 
 /// <p>When included in a receipt rule, this action adds a header to the received email.</p> <p>For information about adding a header using a receipt rule, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-add-header.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddHeaderAction {
     /// <p>The name of the header to add. Must be between 1 and 50 characters, inclusive, and consist of alphanumeric (a-z, A-Z, 0-9) characters and dashes only.</p>
     pub header_name: String,
@@ -142,6 +145,8 @@ impl BehaviorOnMXFailureDeserializer {
 }
 /// <p>Represents the body of the message. You can specify text, HTML, or both. If you use both, then the message should display correctly in the widest variety of email clients.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Body {
     /// <p>The content of the message, in HTML format. Use this for email clients that can process HTML. You can include clickable links, formatted text, and much more in an HTML message.</p>
     pub html: Option<Content>,
@@ -169,6 +174,8 @@ impl BodySerializer {
 
 /// <p>When included in a receipt rule, this action rejects the received email by returning a bounce response to the sender and, optionally, publishes a notification to Amazon Simple Notification Service (Amazon SNS).</p> <p>For information about sending a bounce message in response to a received email, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-bounce.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BounceAction {
     /// <p>Human-readable text to include in the bounce message.</p>
     pub message: String,
@@ -278,6 +285,8 @@ impl BounceStatusCodeDeserializer {
 }
 /// <p>Recipient-related information to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BouncedRecipientInfo {
     /// <p>The reason for the bounce. You must provide either this parameter or <code>RecipientDsnFields</code>.</p>
     pub bounce_type: Option<String>,
@@ -328,6 +337,8 @@ impl BouncedRecipientInfoListSerializer {
 
 /// <p>An array that contains one or more Destinations, as well as the tags and replacement data associated with each of those Destinations.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BulkEmailDestination {
     pub destination: Destination,
     /// <p>A list of tags, in the form of name/value pairs, to apply to an email that you send using <code>SendBulkTemplatedEmail</code>. Tags correspond to characteristics of the email that you define, so that you can publish email sending events.</p>
@@ -336,6 +347,19 @@ pub struct BulkEmailDestination {
     pub replacement_template_data: Option<String>,
 }
 
+impl Validate for BulkEmailDestination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.replacement_template_data {
+            errors.check_length(
+                &field_path(path, "replacement_template_data"),
+                value.chars().count(),
+                None,
+                Some(262144),
+            );
+        }
+    }
+}
+
 /// Serialize `BulkEmailDestination` contents to a `SignedRequest`.
 struct BulkEmailDestinationSerializer;
 impl BulkEmailDestinationSerializer {
@@ -379,6 +403,8 @@ impl BulkEmailDestinationListSerializer {
 
 /// <p>An object that contains the response from the <code>SendBulkTemplatedEmail</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BulkEmailDestinationStatus {
     /// <p>A description of an error that prevented a message being sent using the <code>SendBulkTemplatedEmail</code> operation.</p>
     pub error: Option<String>,
@@ -461,6 +487,8 @@ impl CidrDeserializer {
 }
 /// <p>Represents a request to create a receipt rule set by cloning an existing one. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloneReceiptRuleSetRequest {
     /// <p>The name of the rule set to clone.</p>
     pub original_rule_set_name: String,
@@ -487,6 +515,8 @@ impl CloneReceiptRuleSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloneReceiptRuleSetResponse {}
 
 struct CloneReceiptRuleSetResponseDeserializer;
@@ -507,6 +537,8 @@ impl CloneReceiptRuleSetResponseDeserializer {
 }
 /// <p>Contains information associated with an Amazon CloudWatch event destination to which email sending events are published.</p> <p>Event destinations, such as Amazon CloudWatch, are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudWatchDestination {
     /// <p>A list of dimensions upon which to categorize your emails when you publish email sending events to Amazon CloudWatch.</p>
     pub dimension_configurations: Vec<CloudWatchDimensionConfiguration>,
@@ -555,6 +587,8 @@ impl CloudWatchDestinationSerializer {
 
 /// <p>Contains the dimension configuration to use when you publish email sending events to Amazon CloudWatch.</p> <p>For information about publishing email sending events to Amazon CloudWatch, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloudWatchDimensionConfiguration {
     /// <p><p>The default value of the dimension that is published to Amazon CloudWatch if you do not provide the value of the dimension when you send an email. The default value must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 256 characters.</p> </li> </ul></p>
     pub default_dimension_value: String,
@@ -658,6 +692,8 @@ impl CloudWatchDimensionConfigurationsSerializer {
 
 /// <p>The name of the configuration set.</p> <p>Configuration sets let you create groups of rules that you can apply to the emails you send using Amazon SES. For more information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/using-configuration-sets.html">Using Amazon SES Configuration Sets</a> in the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfigurationSet {
     /// <p><p>The name of the configuration set. The name must meet the following requirements:</p> <ul> <li> <p>Contain only letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain 64 characters or fewer.</p> </li> </ul></p>
     pub name: String,
@@ -736,6 +772,8 @@ impl ConfigurationSetsDeserializer {
 }
 /// <p>Represents textual data, plus an optional character set specification.</p> <p>By default, the text must be 7-bit ASCII, due to the constraints of the SMTP protocol. If the text must contain any other characters, then you must also specify a character set. Examples include UTF-8, ISO-8859-1, and Shift_JIS.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Content {
     /// <p>The character set of the content.</p>
     pub charset: Option<String>,
@@ -764,7 +802,7 @@ impl CounterDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -772,6 +810,8 @@ impl CounterDeserializer {
 }
 /// <p>Represents a request to create a configuration set event destination. A configuration set event destination, which can be either Amazon CloudWatch or Amazon Kinesis Firehose, describes an AWS service in which Amazon SES publishes the email sending events associated with a configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationSetEventDestinationRequest {
     /// <p>The name of the configuration set that the event destination should be associated with.</p>
     pub configuration_set_name: String,
@@ -806,6 +846,8 @@ impl CreateConfigurationSetEventDestinationRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationSetEventDestinationResponse {}
 
 struct CreateConfigurationSetEventDestinationResponseDeserializer;
@@ -826,6 +868,8 @@ impl CreateConfigurationSetEventDestinationResponseDeserializer {
 }
 /// <p>Represents a request to create a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationSetRequest {
     /// <p>A data structure that contains the name of the configuration set.</p>
     pub configuration_set: ConfigurationSet,
@@ -850,6 +894,8 @@ impl CreateConfigurationSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationSetResponse {}
 
 struct CreateConfigurationSetResponseDeserializer;
@@ -870,6 +916,8 @@ impl CreateConfigurationSetResponseDeserializer {
 }
 /// <p>Represents a request to create an open and click tracking option object in a configuration set. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationSetTrackingOptionsRequest {
     /// <p>The name of the configuration set that the tracking options should be associated with.</p>
     pub configuration_set_name: String,
@@ -903,6 +951,8 @@ impl CreateConfigurationSetTrackingOptionsRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateConfigurationSetTrackingOptionsResponse {}
 
 struct CreateConfigurationSetTrackingOptionsResponseDeserializer;
@@ -923,6 +973,8 @@ impl CreateConfigurationSetTrackingOptionsResponseDeserializer {
 }
 /// <p>Represents a request to create a custom verification email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCustomVerificationEmailTemplateRequest {
     /// <p>The URL that the recipient of the verification email is sent to if his or her address is not successfully verified.</p>
     pub failure_redirection_url: String,
@@ -977,6 +1029,8 @@ impl CreateCustomVerificationEmailTemplateRequestSerializer {
 
 /// <p>Represents a request to create a new IP address filter. You use IP address filters when you receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReceiptFilterRequest {
     /// <p>A data structure that describes the IP address filter to create, which consists of a name, an IP address range, and whether to allow or block mail from it.</p>
     pub filter: ReceiptFilter,
@@ -997,6 +1051,8 @@ impl CreateReceiptFilterRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReceiptFilterResponse {}
 
 struct CreateReceiptFilterResponseDeserializer;
@@ -1017,6 +1073,8 @@ impl CreateReceiptFilterResponseDeserializer {
 }
 /// <p>Represents a request to create a receipt rule. You use receipt rules to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReceiptRuleRequest {
     /// <p>The name of an existing rule after which the new rule will be placed. If this parameter is null, the new rule will be inserted at the beginning of the rule list.</p>
     pub after: Option<String>,
@@ -1045,6 +1103,8 @@ impl CreateReceiptRuleRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReceiptRuleResponse {}
 
 struct CreateReceiptRuleResponseDeserializer;
@@ -1065,6 +1125,8 @@ impl CreateReceiptRuleResponseDeserializer {
 }
 /// <p>Represents a request to create an empty receipt rule set. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReceiptRuleSetRequest {
     /// <p><p>The name of the rule set to create. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Start and end with a letter or number.</p> </li> <li> <p>Contain less than 64 characters.</p> </li> </ul></p>
     pub rule_set_name: String,
@@ -1085,6 +1147,8 @@ impl CreateReceiptRuleSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReceiptRuleSetResponse {}
 
 struct CreateReceiptRuleSetResponseDeserializer;
@@ -1105,6 +1169,8 @@ impl CreateReceiptRuleSetResponseDeserializer {
 }
 /// <p>Represents a request to create an email template. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTemplateRequest {
     /// <p>The content of the email, composed of a subject line, an HTML part, and a text-only part.</p>
     pub template: Template,
@@ -1124,6 +1190,8 @@ impl CreateTemplateRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTemplateResponse {}
 
 struct CreateTemplateResponseDeserializer;
@@ -1166,6 +1234,8 @@ impl CustomRedirectDomainDeserializer {
 }
 /// <p>Contains information about a custom verification email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CustomVerificationEmailTemplate {
     /// <p>The URL that the recipient of the verification email is sent to if his or her address is not successfully verified.</p>
     pub failure_redirection_url: Option<String>,
@@ -1260,6 +1330,8 @@ impl DefaultDimensionValueDeserializer {
 }
 /// <p>Represents a request to delete a configuration set event destination. Configuration set event destinations are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationSetEventDestinationRequest {
     /// <p>The name of the configuration set from which to delete the event destination.</p>
     pub configuration_set_name: String,
@@ -1293,6 +1365,8 @@ impl DeleteConfigurationSetEventDestinationRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationSetEventDestinationResponse {}
 
 struct DeleteConfigurationSetEventDestinationResponseDeserializer;
@@ -1313,6 +1387,8 @@ impl DeleteConfigurationSetEventDestinationResponseDeserializer {
 }
 /// <p>Represents a request to delete a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationSetRequest {
     /// <p>The name of the configuration set to delete.</p>
     pub configuration_set_name: String,
@@ -1336,6 +1412,8 @@ impl DeleteConfigurationSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationSetResponse {}
 
 struct DeleteConfigurationSetResponseDeserializer;
@@ -1356,6 +1434,8 @@ impl DeleteConfigurationSetResponseDeserializer {
 }
 /// <p>Represents a request to delete open and click tracking options in a configuration set. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationSetTrackingOptionsRequest {
     /// <p>The name of the configuration set from which you want to delete the tracking options.</p>
     pub configuration_set_name: String,
@@ -1383,6 +1463,8 @@ impl DeleteConfigurationSetTrackingOptionsRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteConfigurationSetTrackingOptionsResponse {}
 
 struct DeleteConfigurationSetTrackingOptionsResponseDeserializer;
@@ -1403,6 +1485,8 @@ impl DeleteConfigurationSetTrackingOptionsResponseDeserializer {
 }
 /// <p>Represents a request to delete an existing custom verification email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCustomVerificationEmailTemplateRequest {
     /// <p>The name of the custom verification email template that you want to delete.</p>
     pub template_name: String,
@@ -1427,6 +1511,8 @@ impl DeleteCustomVerificationEmailTemplateRequestSerializer {
 
 /// <p>Represents a request to delete a sending authorization policy for an identity. Sending authorization is an Amazon SES feature that enables you to authorize other senders to use your identities. For information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentityPolicyRequest {
     /// <p>The identity that is associated with the policy that you want to delete. You can specify the identity by using its name or by using its Amazon Resource Name (ARN). Examples: <code>user@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p> <p>To successfully call this API, you must own the identity.</p>
     pub identity: String,
@@ -1434,6 +1520,17 @@ pub struct DeleteIdentityPolicyRequest {
     pub policy_name: String,
 }
 
+impl Validate for DeleteIdentityPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// Serialize `DeleteIdentityPolicyRequest` contents to a `SignedRequest`.
 struct DeleteIdentityPolicyRequestSerializer;
 impl DeleteIdentityPolicyRequestSerializer {
@@ -1450,6 +1547,8 @@ impl DeleteIdentityPolicyRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentityPolicyResponse {}
 
 struct DeleteIdentityPolicyResponseDeserializer;
@@ -1470,6 +1569,8 @@ impl DeleteIdentityPolicyResponseDeserializer {
 }
 /// <p>Represents a request to delete one of your Amazon SES identities (an email address or domain).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentityRequest {
     /// <p>The identity to be removed from the list of identities for the AWS Account.</p>
     pub identity: String,
@@ -1490,6 +1591,8 @@ impl DeleteIdentityRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIdentityResponse {}
 
 struct DeleteIdentityResponseDeserializer;
@@ -1510,6 +1613,8 @@ impl DeleteIdentityResponseDeserializer {
 }
 /// <p>Represents a request to delete an IP address filter. You use IP address filters when you receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReceiptFilterRequest {
     /// <p>The name of the IP address filter to delete.</p>
     pub filter_name: String,
@@ -1530,6 +1635,8 @@ impl DeleteReceiptFilterRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReceiptFilterResponse {}
 
 struct DeleteReceiptFilterResponseDeserializer;
@@ -1550,6 +1657,8 @@ impl DeleteReceiptFilterResponseDeserializer {
 }
 /// <p>Represents a request to delete a receipt rule. You use receipt rules to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReceiptRuleRequest {
     /// <p>The name of the receipt rule to delete.</p>
     pub rule_name: String,
@@ -1573,6 +1682,8 @@ impl DeleteReceiptRuleRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReceiptRuleResponse {}
 
 struct DeleteReceiptRuleResponseDeserializer;
@@ -1593,6 +1704,8 @@ impl DeleteReceiptRuleResponseDeserializer {
 }
 /// <p>Represents a request to delete a receipt rule set and all of the receipt rules it contains. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReceiptRuleSetRequest {
     /// <p>The name of the receipt rule set to delete.</p>
     pub rule_set_name: String,
@@ -1613,6 +1726,8 @@ impl DeleteReceiptRuleSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReceiptRuleSetResponse {}
 
 struct DeleteReceiptRuleSetResponseDeserializer;
@@ -1633,6 +1748,8 @@ impl DeleteReceiptRuleSetResponseDeserializer {
 }
 /// <p>Represents a request to delete an email template. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTemplateRequest {
     /// <p>The name of the template to be deleted.</p>
     pub template_name: String,
@@ -1652,6 +1769,8 @@ impl DeleteTemplateRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTemplateResponse {}
 
 struct DeleteTemplateResponseDeserializer;
@@ -1672,6 +1791,8 @@ impl DeleteTemplateResponseDeserializer {
 }
 /// <p>Represents a request to delete an email address from the list of email addresses you have attempted to verify under your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVerifiedEmailAddressRequest {
     /// <p>An email address to be removed from the list of verified addresses.</p>
     pub email_address: String,
@@ -1692,6 +1813,8 @@ impl DeleteVerifiedEmailAddressRequestSerializer {
 
 /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeliveryOptions {
     /// <p>Specifies whether messages that use the configuration set are required to use Transport Layer Security (TLS). If the value is <code>Require</code>, messages are only delivered if a TLS connection can be established. If the value is <code>Optional</code>, messages can be delivered in plain text if a TLS connection can't be established.</p>
     pub tls_policy: Option<String>,
@@ -1733,6 +1856,8 @@ impl DeliveryOptionsSerializer {
 
 /// <p>Represents a request to return the metadata and receipt rules for the receipt rule set that is currently active. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActiveReceiptRuleSetRequest {}
 
 /// Serialize `DescribeActiveReceiptRuleSetRequest` contents to a `SignedRequest`.
@@ -1748,6 +1873,8 @@ impl DescribeActiveReceiptRuleSetRequestSerializer {
 
 /// <p>Represents the metadata and receipt rules for the receipt rule set that is currently active.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActiveReceiptRuleSetResponse {
     /// <p>The metadata for the currently active receipt rule set. The metadata consists of the rule set name and a timestamp of when the rule set was created.</p>
     pub metadata: Option<ReceiptRuleSetMetadata>,
@@ -1786,6 +1913,8 @@ impl DescribeActiveReceiptRuleSetResponseDeserializer {
 }
 /// <p>Represents a request to return the details of a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationSetRequest {
     /// <p>A list of configuration set attributes to return.</p>
     pub configuration_set_attribute_names: Option<Vec<String>>,
@@ -1818,6 +1947,8 @@ impl DescribeConfigurationSetRequestSerializer {
 
 /// <p>Represents the details of a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeConfigurationSetResponse {
     /// <p>The configuration set object associated with the specified configuration set.</p>
     pub configuration_set: Option<ConfigurationSet>,
@@ -1880,6 +2011,8 @@ impl DescribeConfigurationSetResponseDeserializer {
 }
 /// <p>Represents a request to return the details of a receipt rule. You use receipt rules to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReceiptRuleRequest {
     /// <p>The name of the receipt rule.</p>
     pub rule_name: String,
@@ -1903,6 +2036,8 @@ impl DescribeReceiptRuleRequestSerializer {
 
 /// <p>Represents the details of a receipt rule.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReceiptRuleResponse {
     /// <p>A data structure that contains the specified receipt rule's name, actions, recipients, domains, enabled status, scan status, and Transport Layer Security (TLS) policy.</p>
     pub rule: Option<ReceiptRule>,
@@ -1932,6 +2067,8 @@ impl DescribeReceiptRuleResponseDeserializer {
 }
 /// <p>Represents a request to return the details of a receipt rule set. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReceiptRuleSetRequest {
     /// <p>The name of the receipt rule set to describe.</p>
     pub rule_set_name: String,
@@ -1952,6 +2089,8 @@ impl DescribeReceiptRuleSetRequestSerializer {
 
 /// <p>Represents the details of the specified receipt rule set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeReceiptRuleSetResponse {
     /// <p>The metadata for the receipt rule set, which consists of the rule set name and the timestamp of when the rule set was created.</p>
     pub metadata: Option<ReceiptRuleSetMetadata>,
@@ -1990,6 +2129,8 @@ impl DescribeReceiptRuleSetResponseDeserializer {
 }
 /// <p><p>Represents the destination of the message, consisting of To:, CC:, and BCC: fields.</p> <note> <p>Amazon SES does not support the SMTPUTF8 extension, as described in <a href="https://tools.ietf.org/html/rfc6531">RFC6531</a>. For this reason, the <i>local part</i> of a destination email address (the part of the email address that precedes the @ sign) may only contain <a href="https://en.wikipedia.org/wiki/Email_address#Local-part">7-bit ASCII characters</a>. If the <i>domain part</i> of an address (the part after the @ sign) contains non-ASCII characters, they must be encoded using Punycode, as described in <a href="https://tools.ietf.org/html/rfc3492.html">RFC3492</a>.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Destination {
     /// <p>The recipients to place on the BCC: line of the message.</p>
     pub bcc_addresses: Option<Vec<String>>,
@@ -2082,7 +2223,7 @@ impl EnabledDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2101,6 +2242,8 @@ impl SesErrorDeserializer {
 }
 /// <p>Contains information about the event destination that the specified email sending events will be published to.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct EventDestination {
     /// <p>An object that contains the names, default values, and sources of the dimensions associated with an Amazon CloudWatch event destination.</p>
     pub cloud_watch_destination: Option<CloudWatchDestination>,
@@ -2277,6 +2420,8 @@ impl EventTypesSerializer {
 
 /// <p>Additional X-headers to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExtensionField {
     /// <p>The name of the header to add. Must be between 1 and 50 characters, inclusive, and consist of alphanumeric (a-z, A-Z, 0-9) characters and dashes only.</p>
     pub name: String,
@@ -2333,6 +2478,8 @@ impl FromAddressDeserializer {
 }
 /// <p>Represents a request to return the email sending status for your Amazon SES account in the current AWS Region.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccountSendingEnabledResponse {
     /// <p>Describes whether email sending is enabled or disabled for your Amazon SES account in the current AWS Region.</p>
     pub enabled: Option<bool>,
@@ -2362,6 +2509,8 @@ impl GetAccountSendingEnabledResponseDeserializer {
 }
 /// <p>Represents a request to retrieve an existing custom verification email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCustomVerificationEmailTemplateRequest {
     /// <p>The name of the custom verification email template that you want to retrieve.</p>
     pub template_name: String,
@@ -2382,6 +2531,8 @@ impl GetCustomVerificationEmailTemplateRequestSerializer {
 
 /// <p>The content of the custom verification email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCustomVerificationEmailTemplateResponse {
     /// <p>The URL that the recipient of the verification email is sent to if his or her address is not successfully verified.</p>
     pub failure_redirection_url: Option<String>,
@@ -2454,6 +2605,8 @@ impl GetCustomVerificationEmailTemplateResponseDeserializer {
 }
 /// <p>Represents a request for the status of Amazon SES Easy DKIM signing for an identity. For domain identities, this request also returns the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES successfully verified that these tokens were published. For more information about Easy DKIM, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityDkimAttributesRequest {
     /// <p>A list of one or more verified identities - email addresses, domains, or both.</p>
     pub identities: Vec<String>,
@@ -2478,6 +2631,8 @@ impl GetIdentityDkimAttributesRequestSerializer {
 
 /// <p>Represents the status of Amazon SES Easy DKIM signing for an identity. For domain identities, this response also contains the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES successfully verified that these tokens were published.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityDkimAttributesResponse {
     /// <p>The DKIM attributes for an email address or a domain.</p>
     pub dkim_attributes: ::std::collections::HashMap<String, IdentityDkimAttributes>,
@@ -2508,6 +2663,8 @@ impl GetIdentityDkimAttributesResponseDeserializer {
 }
 /// <p>Represents a request to return the Amazon SES custom MAIL FROM attributes for a list of identities. For information about using a custom MAIL FROM domain, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityMailFromDomainAttributesRequest {
     /// <p>A list of one or more identities.</p>
     pub identities: Vec<String>,
@@ -2536,6 +2693,8 @@ impl GetIdentityMailFromDomainAttributesRequestSerializer {
 
 /// <p>Represents the custom MAIL FROM attributes for a list of identities.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityMailFromDomainAttributesResponse {
     /// <p>A map of identities to custom MAIL FROM attributes.</p>
     pub mail_from_domain_attributes:
@@ -2570,6 +2729,8 @@ impl GetIdentityMailFromDomainAttributesResponseDeserializer {
 }
 /// <p>Represents a request to return the notification attributes for a list of identities you verified with Amazon SES. For information about Amazon SES notifications, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityNotificationAttributesRequest {
     /// <p>A list of one or more identities. You can specify an identity by using its name or by using its Amazon Resource Name (ARN). Examples: <code>user@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p>
     pub identities: Vec<String>,
@@ -2594,6 +2755,8 @@ impl GetIdentityNotificationAttributesRequestSerializer {
 
 /// <p>Represents the notification attributes for a list of identities.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityNotificationAttributesResponse {
     /// <p>A map of Identity to IdentityNotificationAttributes.</p>
     pub notification_attributes:
@@ -2628,6 +2791,8 @@ impl GetIdentityNotificationAttributesResponseDeserializer {
 }
 /// <p>Represents a request to return the requested sending authorization policies for an identity. Sending authorization is an Amazon SES feature that enables you to authorize other senders to use your identities. For information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityPoliciesRequest {
     /// <p>The identity for which the policies will be retrieved. You can specify an identity by using its name or by using its Amazon Resource Name (ARN). Examples: <code>user@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p> <p>To successfully call this API, you must own the identity.</p>
     pub identity: String,
@@ -2635,6 +2800,15 @@ pub struct GetIdentityPoliciesRequest {
     pub policy_names: Vec<String>,
 }
 
+impl Validate for GetIdentityPoliciesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.policy_names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "policy_names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+        }
+    }
+}
+
 /// Serialize `GetIdentityPoliciesRequest` contents to a `SignedRequest`.
 struct GetIdentityPoliciesRequestSerializer;
 impl GetIdentityPoliciesRequestSerializer {
@@ -2655,6 +2829,8 @@ impl GetIdentityPoliciesRequestSerializer {
 
 /// <p>Represents the requested sending authorization policies.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityPoliciesResponse {
     /// <p>A map of policy names to policies.</p>
     pub policies: ::std::collections::HashMap<String, String>,
@@ -2684,6 +2860,8 @@ impl GetIdentityPoliciesResponseDeserializer {
 }
 /// <p>Represents a request to return the Amazon SES verification status of a list of identities. For domain identities, this request also returns the verification token. For information about verifying identities with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityVerificationAttributesRequest {
     /// <p>A list of identities.</p>
     pub identities: Vec<String>,
@@ -2708,6 +2886,8 @@ impl GetIdentityVerificationAttributesRequestSerializer {
 
 /// <p>The Amazon SES verification status of a list of identities. For domain identities, this response also contains the verification token.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIdentityVerificationAttributesResponse {
     /// <p>A map of Identities to IdentityVerificationAttributes objects.</p>
     pub verification_attributes:
@@ -2742,6 +2922,8 @@ impl GetIdentityVerificationAttributesResponseDeserializer {
 }
 /// <p>Represents your Amazon SES daily sending quota, maximum send rate, and the number of emails you have sent in the last 24 hours.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSendQuotaResponse {
     /// <p>The maximum number of emails the user is allowed to send in a 24-hour interval. A value of -1 signifies an unlimited quota.</p>
     pub max_24_hour_send: Option<f64>,
@@ -2784,6 +2966,8 @@ impl GetSendQuotaResponseDeserializer {
 }
 /// <p>Represents a list of data points. This list contains aggregated data from the previous two weeks of your sending activity with Amazon SES.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSendStatisticsResponse {
     /// <p>A list of data points, each of which represents 15 minutes of activity.</p>
     pub send_data_points: Option<Vec<SendDataPoint>>,
@@ -2814,6 +2998,8 @@ impl GetSendStatisticsResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTemplateRequest {
     /// <p>The name of the template you want to retrieve.</p>
     pub template_name: String,
@@ -2833,6 +3019,8 @@ impl GetTemplateRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTemplateResponse {
     pub template: Option<Template>,
 }
@@ -2901,6 +3089,8 @@ impl IdentityDeserializer {
 }
 /// <p>Represents the DKIM attributes of a verified email address or a domain.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IdentityDkimAttributes {
     /// <p>Is true if DKIM signing is enabled for email sent from the identity. It's false otherwise. The default value is true.</p>
     pub dkim_enabled: bool,
@@ -2970,6 +3160,8 @@ impl IdentityListSerializer {
 
 /// <p>Represents the custom MAIL FROM domain attributes of a verified identity (email address or domain).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IdentityMailFromDomainAttributes {
     /// <p>The action that Amazon SES takes if it cannot successfully read the required MX record when you send an email. A value of <code>UseDefaultValue</code> indicates that if Amazon SES cannot read the required MX record, it uses amazonses.com (or a subdomain of that) as the MAIL FROM domain. A value of <code>RejectMessage</code> indicates that if Amazon SES cannot read the required MX record, Amazon SES returns a <code>MailFromDomainNotVerified</code> error and does not send the email.</p> <p>The custom MAIL FROM setup states that result in this behavior are <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code>.</p>
     pub behavior_on_mx_failure: String,
@@ -3017,6 +3209,8 @@ impl IdentityMailFromDomainAttributesDeserializer {
 }
 /// <p>Represents the notification attributes of an identity, including whether an identity has Amazon Simple Notification Service (Amazon SNS) topics set for bounce, complaint, and/or delivery notifications, and whether feedback forwarding is enabled for bounce and complaint notifications.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IdentityNotificationAttributes {
     /// <p>The Amazon Resource Name (ARN) of the Amazon SNS topic where Amazon SES will publish bounce notifications.</p>
     pub bounce_topic: String,
@@ -3092,6 +3286,8 @@ impl IdentityNotificationAttributesDeserializer {
 }
 /// <p>Represents the verification attributes of a single identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IdentityVerificationAttributes {
     /// <p>The verification status of the identity: "Pending", "Success", "Failed", or "TemporaryFailure".</p>
     pub verification_status: String,
@@ -3143,6 +3339,8 @@ impl InvocationTypeDeserializer {
 }
 /// <p>Contains the delivery stream ARN and the IAM role ARN associated with an Amazon Kinesis Firehose event destination.</p> <p>Event destinations, such as Amazon Kinesis Firehose, are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct KinesisFirehoseDestination {
     /// <p>The ARN of the Amazon Kinesis Firehose stream that email sending events should be published to.</p>
     pub delivery_stream_arn: String,
@@ -3199,6 +3397,8 @@ impl KinesisFirehoseDestinationSerializer {
 
 /// <p>When included in a receipt rule, this action calls an AWS Lambda function and, optionally, publishes a notification to Amazon Simple Notification Service (Amazon SNS).</p> <p>To enable Amazon SES to call your AWS Lambda function or to publish to an Amazon SNS topic of another account, Amazon SES must have permission to access those resources. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p> <p>For information about using AWS Lambda actions in receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-lambda.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LambdaAction {
     /// <p>The Amazon Resource Name (ARN) of the AWS Lambda function. An example of an AWS Lambda function ARN is <code>arn:aws:lambda:us-west-2:account-id:function:MyFunction</code>. For more information about AWS Lambda, see the <a href="https://docs.aws.amazon.com/lambda/latest/dg/welcome.html">AWS Lambda Developer Guide</a>.</p>
     pub function_arn: String,
@@ -3271,6 +3471,8 @@ impl LastFreshStartDeserializer {
 }
 /// <p>Represents a request to list the configuration sets associated with your AWS account. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationSetsRequest {
     /// <p>The number of configuration sets to return.</p>
     pub max_items: Option<i64>,
@@ -3298,6 +3500,8 @@ impl ListConfigurationSetsRequestSerializer {
 
 /// <p>A list of configuration sets associated with your AWS account. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListConfigurationSetsResponse {
     /// <p>A list of configuration sets.</p>
     pub configuration_sets: Option<Vec<ConfigurationSet>>,
@@ -3335,6 +3539,8 @@ impl ListConfigurationSetsResponseDeserializer {
 }
 /// <p>Represents a request to list the existing custom verification email templates for your account.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCustomVerificationEmailTemplatesRequest {
     /// <p>The maximum number of custom verification email templates to return. This value must be at least 1 and less than or equal to 50. If you do not specify a value, or if you specify a value less than 1 or greater than 50, the operation will return up to 50 results.</p>
     pub max_results: Option<i64>,
@@ -3342,6 +3548,14 @@ pub struct ListCustomVerificationEmailTemplatesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCustomVerificationEmailTemplatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 /// Serialize `ListCustomVerificationEmailTemplatesRequest` contents to a `SignedRequest`.
 struct ListCustomVerificationEmailTemplatesRequestSerializer;
 impl ListCustomVerificationEmailTemplatesRequestSerializer {
@@ -3366,6 +3580,8 @@ impl ListCustomVerificationEmailTemplatesRequestSerializer {
 
 /// <p>A paginated list of custom verification email templates.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCustomVerificationEmailTemplatesResponse {
     /// <p>A list of the custom verification email templates that exist in your account.</p>
     pub custom_verification_email_templates: Option<Vec<CustomVerificationEmailTemplate>>,
@@ -3406,6 +3622,8 @@ impl ListCustomVerificationEmailTemplatesResponseDeserializer {
 }
 /// <p>Represents a request to return a list of all identities (email addresses and domains) that you have attempted to verify under your AWS account, regardless of verification status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentitiesRequest {
     /// <p>The type of the identities to list. Possible values are "EmailAddress" and "Domain". If this parameter is omitted, then all identities will be listed.</p>
     pub identity_type: Option<String>,
@@ -3438,6 +3656,8 @@ impl ListIdentitiesRequestSerializer {
 
 /// <p>A list of all identities that you have attempted to verify under your AWS account, regardless of verification status.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentitiesResponse {
     /// <p>A list of identities.</p>
     pub identities: Vec<String>,
@@ -3469,6 +3689,8 @@ impl ListIdentitiesResponseDeserializer {
 }
 /// <p>Represents a request to return a list of sending authorization policies that are attached to an identity. Sending authorization is an Amazon SES feature that enables you to authorize other senders to use your identities. For information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentityPoliciesRequest {
     /// <p>The identity that is associated with the policy for which the policies will be listed. You can specify an identity by using its name or by using its Amazon Resource Name (ARN). Examples: <code>user@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p> <p>To successfully call this API, you must own the identity.</p>
     pub identity: String,
@@ -3489,6 +3711,8 @@ impl ListIdentityPoliciesRequestSerializer {
 
 /// <p>A list of names of sending authorization policies that apply to an identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIdentityPoliciesResponse {
     /// <p>A list of names of policies that apply to the specified identity.</p>
     pub policy_names: Vec<String>,
@@ -3522,6 +3746,8 @@ impl ListIdentityPoliciesResponseDeserializer {
 }
 /// <p>Represents a request to list the IP address filters that exist under your AWS account. You use IP address filters when you receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReceiptFiltersRequest {}
 
 /// Serialize `ListReceiptFiltersRequest` contents to a `SignedRequest`.
@@ -3537,6 +3763,8 @@ impl ListReceiptFiltersRequestSerializer {
 
 /// <p>A list of IP address filters that exist under your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReceiptFiltersResponse {
     /// <p>A list of IP address filter data structures, which each consist of a name, an IP address range, and whether to allow or block mail from it.</p>
     pub filters: Option<Vec<ReceiptFilter>>,
@@ -3568,6 +3796,8 @@ impl ListReceiptFiltersResponseDeserializer {
 }
 /// <p>Represents a request to list the receipt rule sets that exist under your AWS account. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReceiptRuleSetsRequest {
     /// <p>A token returned from a previous call to <code>ListReceiptRuleSets</code> to indicate the position in the receipt rule set list.</p>
     pub next_token: Option<String>,
@@ -3590,6 +3820,8 @@ impl ListReceiptRuleSetsRequestSerializer {
 
 /// <p>A list of receipt rule sets that exist under your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListReceiptRuleSetsResponse {
     /// <p>A token indicating that there are additional receipt rule sets available to be listed. Pass this token to successive calls of <code>ListReceiptRuleSets</code> to retrieve up to 100 receipt rule sets at a time.</p>
     pub next_token: Option<String>,
@@ -3626,6 +3858,8 @@ impl ListReceiptRuleSetsResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTemplatesRequest {
     /// <p>The maximum number of templates to return. This value must be at least 1 and less than or equal to 10. If you do not specify a value, or if you specify a value less than 1 or greater than 10, the operation will return up to 10 results.</p>
     pub max_items: Option<i64>,
@@ -3652,6 +3886,8 @@ impl ListTemplatesRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTemplatesResponse {
     /// <p>A token indicating that there are additional email templates available to be listed. Pass this token to a subsequent call to <code>ListTemplates</code> to retrieve the next 50 email templates.</p>
     pub next_token: Option<String>,
@@ -3684,6 +3920,8 @@ impl ListTemplatesResponseDeserializer {
 }
 /// <p>A list of email addresses that you have verified with Amazon SES under your AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVerifiedEmailAddressesResponse {
     /// <p>A list of email addresses that have been verified.</p>
     pub verified_email_addresses: Option<Vec<String>>,
@@ -3753,7 +3991,7 @@ impl Max24HourSendDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3764,7 +4002,7 @@ impl MaxSendRateDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3772,6 +4010,8 @@ impl MaxSendRateDeserializer {
 }
 /// <p>Represents the message to be sent, composed of a subject and a body.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Message {
     /// <p>The message body.</p>
     pub body: Body,
@@ -3795,6 +4035,8 @@ impl MessageSerializer {
 
 /// <p>Message-related information to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MessageDsn {
     /// <p>When the message was received by the reporting mail transfer agent (MTA), in <a href="https://www.ietf.org/rfc/rfc0822.txt">RFC 822</a> date-time format.</p>
     pub arrival_date: Option<String>,
@@ -3840,6 +4082,8 @@ impl MessageIdDeserializer {
 }
 /// <p>Contains the name and value of a tag that you can provide to <code>SendEmail</code> or <code>SendRawEmail</code> to apply to an email.</p> <p>Message tags, which you use with configuration sets, enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MessageTag {
     /// <p><p>The name of the tag. The name must:</p> <ul> <li> <p>This value can only contain ASCII letters (a-z, A-Z), numbers (0-9), underscores (_), or dashes (-).</p> </li> <li> <p>Contain less than 256 characters.</p> </li> </ul></p>
     pub name: String,
@@ -3994,6 +4238,8 @@ impl PolicyNameListSerializer {
 
 /// <p>A request to modify the delivery options for a configuration set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutConfigurationSetDeliveryOptionsRequest {
     /// <p>The name of the configuration set that you want to specify the delivery options for.</p>
     pub configuration_set_name: String,
@@ -4026,6 +4272,8 @@ impl PutConfigurationSetDeliveryOptionsRequestSerializer {
 
 /// <p>An HTTP 200 response if the request succeeds, or an error message if the request fails.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutConfigurationSetDeliveryOptionsResponse {}
 
 struct PutConfigurationSetDeliveryOptionsResponseDeserializer;
@@ -4046,6 +4294,8 @@ impl PutConfigurationSetDeliveryOptionsResponseDeserializer {
 }
 /// <p>Represents a request to add or update a sending authorization policy for an identity. Sending authorization is an Amazon SES feature that enables you to authorize other senders to use your identities. For information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutIdentityPolicyRequest {
     /// <p>The identity that the policy will apply to. You can specify an identity by using its name or by using its Amazon Resource Name (ARN). Examples: <code>user@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p> <p>To successfully call this API, you must own the identity.</p>
     pub identity: String,
@@ -4055,6 +4305,23 @@ pub struct PutIdentityPolicyRequest {
     pub policy_name: String,
 }
 
+impl Validate for PutIdentityPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy"),
+            self.policy.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "policy_name"),
+            self.policy_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// Serialize `PutIdentityPolicyRequest` contents to a `SignedRequest`.
 struct PutIdentityPolicyRequestSerializer;
 impl PutIdentityPolicyRequestSerializer {
@@ -4072,6 +4339,8 @@ impl PutIdentityPolicyRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutIdentityPolicyResponse {}
 
 struct PutIdentityPolicyResponseDeserializer;
@@ -4092,8 +4361,18 @@ impl PutIdentityPolicyResponseDeserializer {
 }
 /// <p>Represents the raw data of the message.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RawMessage {
     /// <p>The raw data of the message. This data needs to base64-encoded if you are accessing Amazon SES directly through the HTTPS interface. If you are accessing Amazon SES using an AWS SDK, the SDK takes care of the base 64-encoding for you. In all cases, the client must ensure that the message format complies with Internet email standards regarding email header fields, MIME types, and MIME encoding.</p> <p>The To:, CC:, and BCC: headers in the raw message can contain a group list.</p> <p>If you are using <code>SendRawEmail</code> with sending authorization, you can include X-headers in the raw message to specify the "Source," "From," and "Return-Path" addresses. For more information, see the documentation for <code>SendRawEmail</code>. </p> <important> <p>Do not include these X-headers in the DKIM signature, because they are removed by Amazon SES before sending the email.</p> </important> <p>For more information, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html">Amazon SES Developer Guide</a>.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+            default
+        )
+    )]
     pub data: bytes::Bytes,
 }
 
@@ -4115,6 +4394,8 @@ impl RawMessageSerializer {
 
 /// <p>An action that Amazon SES can take when it receives an email on behalf of one or more email addresses or domains that you own. An instance of this data type can represent only one action.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiptAction {
     /// <p>Adds a header to the received email.</p>
     pub add_header_action: Option<AddHeaderAction>,
@@ -4274,6 +4555,8 @@ impl ReceiptActionsListSerializer {
 
 /// <p>A receipt IP address filter enables you to specify whether to accept or reject mail originating from an IP address or range of IP addresses.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiptFilter {
     /// <p>A structure that provides the IP addresses to block or allow, and whether to block or allow incoming mail from them.</p>
     pub ip_filter: ReceiptIpFilter,
@@ -4362,6 +4645,8 @@ impl ReceiptFilterPolicyDeserializer {
 }
 /// <p>A receipt IP address filter enables you to specify whether to accept or reject mail originating from an IP address or range of IP addresses.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiptIpFilter {
     /// <p>A single IP address or a range of IP addresses that you want to block or allow, specified in Classless Inter-Domain Routing (CIDR) notation. An example of a single email address is 10.0.0.1. An example of a range of IP addresses is 10.0.0.1/24. For more information about CIDR notation, see <a href="https://tools.ietf.org/html/rfc2317">RFC 2317</a>.</p>
     pub cidr: String,
@@ -4407,6 +4692,8 @@ impl ReceiptIpFilterSerializer {
 
 /// <p>Receipt rules enable you to specify which actions Amazon SES should take when it receives mail on behalf of one or more email addresses or domains that you own.</p> <p>Each receipt rule defines a set of email addresses or domains that it applies to. If the email addresses or domains match at least one recipient address of the message, Amazon SES executes all of the receipt rule's actions on the message.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiptRule {
     /// <p>An ordered list of actions to perform on messages that match at least one of the recipient email addresses or domains specified in the receipt rule.</p>
     pub actions: Option<Vec<ReceiptAction>>,
@@ -4522,6 +4809,8 @@ impl ReceiptRuleNamesListSerializer {
 
 /// <p>Information about a receipt rule set.</p> <p>A receipt rule set is a collection of rules that specify what Amazon SES should do with mail it receives on behalf of your account's verified domains.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiptRuleSetMetadata {
     /// <p>The date and time the receipt rule set was created.</p>
     pub created_timestamp: Option<String>,
@@ -4613,6 +4902,8 @@ impl RecipientDeserializer {
 }
 /// <p>Recipient-related information to include in the Delivery Status Notification (DSN) when an email that Amazon SES receives on your behalf bounces.</p> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecipientDsnFields {
     /// <p>The action performed by the reporting mail transfer agent (MTA) as a result of its attempt to deliver the message to the recipient address. This is required by <a href="https://tools.ietf.org/html/rfc3464">RFC 3464</a>.</p>
     pub action: String,
@@ -4705,6 +4996,8 @@ impl RenderedTemplateDeserializer {
 }
 /// <p>Represents a request to reorder the receipt rules within a receipt rule set. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReorderReceiptRuleSetRequest {
     /// <p>A list of the specified receipt rule set's receipt rules in the order that you want to put them.</p>
     pub rule_names: Vec<String>,
@@ -4732,6 +5025,8 @@ impl ReorderReceiptRuleSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReorderReceiptRuleSetResponse {}
 
 struct ReorderReceiptRuleSetResponseDeserializer;
@@ -4752,6 +5047,8 @@ impl ReorderReceiptRuleSetResponseDeserializer {
 }
 /// <p>Contains information about the reputation settings for a configuration set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReputationOptions {
     /// <p>The date and time at which the reputation metrics for the configuration set were last reset. Resetting these metrics is known as a <i>fresh start</i>.</p> <p>When you disable email sending for a configuration set using <a>UpdateConfigurationSetSendingEnabled</a> and later re-enable it, the reputation metrics for the configuration set (but not for the entire Amazon SES account) are reset.</p> <p>If email sending for the configuration set has never been disabled and later re-enabled, the value of this attribute is <code>null</code>.</p>
     pub last_fresh_start: Option<String>,
@@ -4794,6 +5091,8 @@ impl ReputationOptionsDeserializer {
 }
 /// <p>When included in a receipt rule, this action saves the received message to an Amazon Simple Storage Service (Amazon S3) bucket and, optionally, publishes a notification to Amazon Simple Notification Service (Amazon SNS).</p> <p>To enable Amazon SES to write emails to your Amazon S3 bucket, use an AWS KMS key to encrypt your emails, or publish to an Amazon SNS topic of another account, Amazon SES must have permission to access those resources. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p> <note> <p>When you save your emails to an Amazon S3 bucket, the maximum email size (including headers) is 30 MB. Emails larger than that will bounce.</p> </note> <p>For information about specifying Amazon S3 actions in receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-s3.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Action {
     /// <p>The name of the Amazon S3 bucket that incoming email will be saved to.</p>
     pub bucket_name: String,
@@ -4887,6 +5186,8 @@ impl S3KeyPrefixDeserializer {
 }
 /// <p>When included in a receipt rule, this action publishes a notification to Amazon Simple Notification Service (Amazon SNS). This action includes a complete copy of the email content in the Amazon SNS notifications. Amazon SNS notifications for all other actions simply provide information about the email. They do not include the email content itself.</p> <p>If you own the Amazon SNS topic, you don't need to do anything to give Amazon SES permission to publish emails to it. However, if you don't own the Amazon SNS topic, you need to attach a policy to the topic to give Amazon SES permissions to access it. For information about giving permissions, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-permissions.html">Amazon SES Developer Guide</a>.</p> <important> <p>You can only publish emails that are 150 KB or less (including the header) to Amazon SNS. Larger emails will bounce. If you anticipate emails larger than 150 KB, use the S3 action instead.</p> </important> <p>For information about using a receipt rule to publish an Amazon SNS notification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-sns.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SNSAction {
     /// <p>The encoding to use for the email within the Amazon SNS notification. UTF-8 is easier to use, but may not preserve all special characters when a message was encoded with a different encoding format. Base64 preserves all special characters. The default value is UTF-8.</p>
     pub encoding: Option<String>,
@@ -4947,6 +5248,8 @@ impl SNSActionEncodingDeserializer {
 }
 /// <p>Contains the topic ARN associated with an Amazon Simple Notification Service (Amazon SNS) event destination.</p> <p>Event destinations, such as Amazon SNS, are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SNSDestination {
     /// <p>The ARN of the Amazon SNS topic that email sending events will be published to. An example of an Amazon SNS topic ARN is <code>arn:aws:sns:us-west-2:123456789012:MyTopic</code>. For more information about Amazon SNS topics, see the <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Amazon SNS Developer Guide</a>.</p>
     pub topic_arn: String,
@@ -4986,6 +5289,8 @@ impl SNSDestinationSerializer {
 
 /// <p>Represents a request to send a bounce message to the sender of an email you received through Amazon SES.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendBounceRequest {
     /// <p>The address to use in the "From" header of the bounce message. This must be an identity that you have verified with Amazon SES.</p>
     pub bounce_sender: String,
@@ -5038,6 +5343,8 @@ impl SendBounceRequestSerializer {
 
 /// <p>Represents a unique message ID.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendBounceResponse {
     /// <p>The message ID of the bounce message.</p>
     pub message_id: Option<String>,
@@ -5063,6 +5370,8 @@ impl SendBounceResponseDeserializer {
 }
 /// <p>Represents a request to send a templated email to multiple destinations using Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendBulkTemplatedEmailRequest {
     /// <p>The name of the configuration set to use when you send an email using <code>SendBulkTemplatedEmail</code>.</p>
     pub configuration_set_name: Option<String>,
@@ -5088,6 +5397,23 @@ pub struct SendBulkTemplatedEmailRequest {
     pub template_arn: Option<String>,
 }
 
+impl Validate for SendBulkTemplatedEmailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_template_data {
+            errors.check_length(
+                &field_path(path, "default_template_data"),
+                value.chars().count(),
+                None,
+                Some(262144),
+            );
+        }
+        for (i, item) in self.destinations.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "destinations"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `SendBulkTemplatedEmailRequest` contents to a `SignedRequest`.
 struct SendBulkTemplatedEmailRequestSerializer;
 impl SendBulkTemplatedEmailRequestSerializer {
@@ -5146,6 +5472,8 @@ impl SendBulkTemplatedEmailRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendBulkTemplatedEmailResponse {
     /// <p>The unique message identifier returned from the <code>SendBulkTemplatedEmail</code> action.</p>
     pub status: Vec<BulkEmailDestinationStatus>,
@@ -5178,6 +5506,8 @@ impl SendBulkTemplatedEmailResponseDeserializer {
 }
 /// <p>Represents a request to send a custom verification email to a specified recipient.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendCustomVerificationEmailRequest {
     /// <p>Name of a configuration set to use when sending the verification email.</p>
     pub configuration_set_name: Option<String>,
@@ -5209,6 +5539,8 @@ impl SendCustomVerificationEmailRequestSerializer {
 
 /// <p>The response received when attempting to send the custom verification email.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendCustomVerificationEmailResponse {
     /// <p>The unique message identifier returned from the <code>SendCustomVerificationEmail</code> operation.</p>
     pub message_id: Option<String>,
@@ -5239,6 +5571,8 @@ impl SendCustomVerificationEmailResponseDeserializer {
 }
 /// <p>Represents sending statistics data. Each <code>SendDataPoint</code> contains statistics for a 15-minute period of sending activity. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendDataPoint {
     /// <p>Number of emails that have bounced.</p>
     pub bounces: Option<i64>,
@@ -5302,6 +5636,8 @@ impl SendDataPointListDeserializer {
 }
 /// <p>Represents a request to send a single formatted email using Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-formatted.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendEmailRequest {
     /// <p>The name of the configuration set to use when you send an email using <code>SendEmail</code>.</p>
     pub configuration_set_name: Option<String>,
@@ -5373,6 +5709,8 @@ impl SendEmailRequestSerializer {
 
 /// <p>Represents a unique message ID.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendEmailResponse {
     /// <p>The unique message identifier returned from the <code>SendEmail</code> action. </p>
     pub message_id: String,
@@ -5398,6 +5736,8 @@ impl SendEmailResponseDeserializer {
 }
 /// <p>Represents a request to send a single raw email using Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendRawEmailRequest {
     /// <p>The name of the configuration set to use when you send an email using <code>SendRawEmail</code>.</p>
     pub configuration_set_name: Option<String>,
@@ -5468,6 +5808,8 @@ impl SendRawEmailRequestSerializer {
 
 /// <p>Represents a unique message ID.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendRawEmailResponse {
     /// <p>The unique message identifier returned from the <code>SendRawEmail</code> action. </p>
     pub message_id: String,
@@ -5493,6 +5835,8 @@ impl SendRawEmailResponseDeserializer {
 }
 /// <p>Represents a request to send a templated email using Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendTemplatedEmailRequest {
     /// <p>The name of the configuration set to use when you send an email using <code>SendTemplatedEmail</code>.</p>
     pub configuration_set_name: Option<String>,
@@ -5518,6 +5862,17 @@ pub struct SendTemplatedEmailRequest {
     pub template_data: String,
 }
 
+impl Validate for SendTemplatedEmailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "template_data"),
+            self.template_data.chars().count(),
+            None,
+            Some(262144),
+        );
+    }
+}
+
 /// Serialize `SendTemplatedEmailRequest` contents to a `SignedRequest`.
 struct SendTemplatedEmailRequestSerializer;
 impl SendTemplatedEmailRequestSerializer {
@@ -5571,6 +5926,8 @@ impl SendTemplatedEmailRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendTemplatedEmailResponse {
     /// <p>The unique message identifier returned from the <code>SendTemplatedEmail</code> action. </p>
     pub message_id: String,
@@ -5603,7 +5960,7 @@ impl SentLast24HoursDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<f64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5611,6 +5968,8 @@ impl SentLast24HoursDeserializer {
 }
 /// <p>Represents a request to set a receipt rule set as the active receipt rule set. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetActiveReceiptRuleSetRequest {
     /// <p>The name of the receipt rule set to make active. Setting this value to null disables all email receiving.</p>
     pub rule_set_name: Option<String>,
@@ -5633,6 +5992,8 @@ impl SetActiveReceiptRuleSetRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetActiveReceiptRuleSetResponse {}
 
 struct SetActiveReceiptRuleSetResponseDeserializer;
@@ -5653,6 +6014,8 @@ impl SetActiveReceiptRuleSetResponseDeserializer {
 }
 /// <p>Represents a request to enable or disable Amazon SES Easy DKIM signing for an identity. For more information about setting up Easy DKIM, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityDkimEnabledRequest {
     /// <p>Sets whether DKIM signing is enabled for an identity. Set to <code>true</code> to enable DKIM signing for this identity; <code>false</code> to disable it. </p>
     pub dkim_enabled: bool,
@@ -5676,6 +6039,8 @@ impl SetIdentityDkimEnabledRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityDkimEnabledResponse {}
 
 struct SetIdentityDkimEnabledResponseDeserializer;
@@ -5696,6 +6061,8 @@ impl SetIdentityDkimEnabledResponseDeserializer {
 }
 /// <p>Represents a request to enable or disable whether Amazon SES forwards you bounce and complaint notifications through email. For information about email feedback forwarding, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications-via-email.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityFeedbackForwardingEnabledRequest {
     /// <p>Sets whether Amazon SES will forward bounce and complaint notifications as email. <code>true</code> specifies that Amazon SES will forward bounce and complaint notifications as email, in addition to any Amazon SNS topic publishing otherwise specified. <code>false</code> specifies that Amazon SES will publish bounce and complaint notifications only through Amazon SNS. This value can only be set to <code>false</code> when Amazon SNS topics are set for both <code>Bounce</code> and <code>Complaint</code> notification types.</p>
     pub forwarding_enabled: bool,
@@ -5726,6 +6093,8 @@ impl SetIdentityFeedbackForwardingEnabledRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityFeedbackForwardingEnabledResponse {}
 
 struct SetIdentityFeedbackForwardingEnabledResponseDeserializer;
@@ -5746,6 +6115,8 @@ impl SetIdentityFeedbackForwardingEnabledResponseDeserializer {
 }
 /// <p>Represents a request to set whether Amazon SES includes the original email headers in the Amazon SNS notifications of a specified type. For information about notifications, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications-via-sns.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityHeadersInNotificationsEnabledRequest {
     /// <p>Sets whether Amazon SES includes the original email headers in Amazon SNS notifications of the specified notification type. A value of <code>true</code> specifies that Amazon SES will include headers in notifications, and a value of <code>false</code> specifies that Amazon SES will not include headers in notifications.</p> <p>This value can only be set when <code>NotificationType</code> is already set to use a particular Amazon SNS topic.</p>
     pub enabled: bool,
@@ -5779,6 +6150,8 @@ impl SetIdentityHeadersInNotificationsEnabledRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityHeadersInNotificationsEnabledResponse {}
 
 struct SetIdentityHeadersInNotificationsEnabledResponseDeserializer;
@@ -5799,6 +6172,8 @@ impl SetIdentityHeadersInNotificationsEnabledResponseDeserializer {
 }
 /// <p>Represents a request to enable or disable the Amazon SES custom MAIL FROM domain setup for a verified identity. For information about using a custom MAIL FROM domain, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityMailFromDomainRequest {
     /// <p>The action that you want Amazon SES to take if it cannot successfully read the required MX record when you send an email. If you choose <code>UseDefaultValue</code>, Amazon SES will use amazonses.com (or a subdomain of that) as the MAIL FROM domain. If you choose <code>RejectMessage</code>, Amazon SES will return a <code>MailFromDomainNotVerified</code> error and not send the email.</p> <p>The action specified in <code>BehaviorOnMXFailure</code> is taken when the custom MAIL FROM domain setup is in the <code>Pending</code>, <code>Failed</code>, and <code>TemporaryFailure</code> states.</p>
     pub behavior_on_mx_failure: Option<String>,
@@ -5832,6 +6207,8 @@ impl SetIdentityMailFromDomainRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityMailFromDomainResponse {}
 
 struct SetIdentityMailFromDomainResponseDeserializer;
@@ -5852,6 +6229,8 @@ impl SetIdentityMailFromDomainResponseDeserializer {
 }
 /// <p>Represents a request to specify the Amazon SNS topic to which Amazon SES will publish bounce, complaint, or delivery notifications for emails sent with that identity as the Source. For information about Amazon SES notifications, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications-via-sns.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityNotificationTopicRequest {
     /// <p>The identity (email address or domain) that you want to set the Amazon SNS topic for.</p> <important> <p>You can only specify a verified identity for this parameter.</p> </important> <p>You can specify an identity by using its name or by using its Amazon Resource Name (ARN). The following examples are all valid identities: <code>sender@example.com</code>, <code>example.com</code>, <code>arn:aws:ses:us-east-1:123456789012:identity/example.com</code>.</p>
     pub identity: String,
@@ -5883,6 +6262,8 @@ impl SetIdentityNotificationTopicRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetIdentityNotificationTopicResponse {}
 
 struct SetIdentityNotificationTopicResponseDeserializer;
@@ -5903,6 +6284,8 @@ impl SetIdentityNotificationTopicResponseDeserializer {
 }
 /// <p>Represents a request to set the position of a receipt rule in a receipt rule set. You use receipt rule sets to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetReceiptRulePositionRequest {
     /// <p>The name of the receipt rule after which to place the specified receipt rule.</p>
     pub after: Option<String>,
@@ -5931,6 +6314,8 @@ impl SetReceiptRulePositionRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetReceiptRulePositionResponse {}
 
 struct SetReceiptRulePositionResponseDeserializer;
@@ -5951,6 +6336,8 @@ impl SetReceiptRulePositionResponseDeserializer {
 }
 /// <p>When included in a receipt rule, this action terminates the evaluation of the receipt rule set and, optionally, publishes a notification to Amazon Simple Notification Service (Amazon SNS).</p> <p>For information about setting a stop action in a receipt rule, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-stop.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopAction {
     /// <p>The scope of the StopAction. The only acceptable value is <code>RuleSet</code>.</p>
     pub scope: String,
@@ -6044,6 +6431,8 @@ impl SuccessRedirectionURLDeserializer {
 }
 /// <p>The content of the email, composed of a subject line, an HTML part, and a text-only part.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Template {
     /// <p>The HTML body of the email.</p>
     pub html_part: Option<String>,
@@ -6120,6 +6509,8 @@ impl TemplateContentDeserializer {
 }
 /// <p>Contains information about an email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TemplateMetadata {
     /// <p>The time and date the template was created.</p>
     pub created_timestamp: Option<String>,
@@ -6180,6 +6571,8 @@ impl TemplateNameDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestRenderTemplateRequest {
     /// <p>A list of replacement values to apply to the template. This parameter is a JSON object, typically consisting of key-value pairs in which the keys correspond to replacement tags in the email template.</p>
     pub template_data: String,
@@ -6187,6 +6580,17 @@ pub struct TestRenderTemplateRequest {
     pub template_name: String,
 }
 
+impl Validate for TestRenderTemplateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "template_data"),
+            self.template_data.chars().count(),
+            None,
+            Some(262144),
+        );
+    }
+}
+
 /// Serialize `TestRenderTemplateRequest` contents to a `SignedRequest`.
 struct TestRenderTemplateRequestSerializer;
 impl TestRenderTemplateRequestSerializer {
@@ -6202,6 +6606,8 @@ impl TestRenderTemplateRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestRenderTemplateResponse {
     /// <p>The complete MIME message rendered by applying the data in the TemplateData parameter to the template specified in the TemplateName parameter.</p>
     pub rendered_template: Option<String>,
@@ -6267,6 +6673,8 @@ impl TlsPolicyDeserializer {
 }
 /// <p>A domain that is used to redirect email recipients to an Amazon SES-operated domain. This domain captures open and click events generated by Amazon SES emails.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Configuring Custom Domains to Handle Open and Click Tracking</a> in the <i>Amazon SES Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TrackingOptions {
     /// <p>The custom subdomain that will be used to redirect email recipients to the Amazon SES event tracking domain.</p>
     pub custom_redirect_domain: Option<String>,
@@ -6315,6 +6723,8 @@ impl TrackingOptionsSerializer {
 
 /// <p>Represents a request to enable or disable the email sending capabilities for your entire Amazon SES account.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAccountSendingEnabledRequest {
     /// <p>Describes whether email sending is enabled or disabled for your Amazon SES account in the current AWS Region.</p>
     pub enabled: Option<bool>,
@@ -6337,6 +6747,8 @@ impl UpdateAccountSendingEnabledRequestSerializer {
 
 /// <p>Represents a request to update the event destination of a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationSetEventDestinationRequest {
     /// <p>The name of the configuration set that contains the event destination that you want to update.</p>
     pub configuration_set_name: String,
@@ -6371,6 +6783,8 @@ impl UpdateConfigurationSetEventDestinationRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationSetEventDestinationResponse {}
 
 struct UpdateConfigurationSetEventDestinationResponseDeserializer;
@@ -6391,6 +6805,8 @@ impl UpdateConfigurationSetEventDestinationResponseDeserializer {
 }
 /// <p>Represents a request to modify the reputation metric publishing settings for a configuration set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationSetReputationMetricsEnabledRequest {
     /// <p>The name of the configuration set that you want to update.</p>
     pub configuration_set_name: String,
@@ -6421,6 +6837,8 @@ impl UpdateConfigurationSetReputationMetricsEnabledRequestSerializer {
 
 /// <p>Represents a request to enable or disable the email sending capabilities for a specific configuration set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationSetSendingEnabledRequest {
     /// <p>The name of the configuration set that you want to update.</p>
     pub configuration_set_name: String,
@@ -6451,6 +6869,8 @@ impl UpdateConfigurationSetSendingEnabledRequestSerializer {
 
 /// <p>Represents a request to update the tracking options for a configuration set. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationSetTrackingOptionsRequest {
     /// <p>The name of the configuration set for which you want to update the custom tracking domain.</p>
     pub configuration_set_name: String,
@@ -6484,6 +6904,8 @@ impl UpdateConfigurationSetTrackingOptionsRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateConfigurationSetTrackingOptionsResponse {}
 
 struct UpdateConfigurationSetTrackingOptionsResponseDeserializer;
@@ -6504,6 +6926,8 @@ impl UpdateConfigurationSetTrackingOptionsResponseDeserializer {
 }
 /// <p>Represents a request to update an existing custom verification email template.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCustomVerificationEmailTemplateRequest {
     /// <p>The URL that the recipient of the verification email is sent to if his or her address is not successfully verified.</p>
     pub failure_redirection_url: Option<String>,
@@ -6559,6 +6983,8 @@ impl UpdateCustomVerificationEmailTemplateRequestSerializer {
 
 /// <p>Represents a request to update a receipt rule. You use receipt rules to receive email with Amazon SES. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-concepts.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateReceiptRuleRequest {
     /// <p>A data structure that contains the updated receipt rule information.</p>
     pub rule: ReceiptRule,
@@ -6582,6 +7008,8 @@ impl UpdateReceiptRuleRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateReceiptRuleResponse {}
 
 struct UpdateReceiptRuleResponseDeserializer;
@@ -6601,6 +7029,8 @@ impl UpdateReceiptRuleResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTemplateRequest {
     pub template: Template,
 }
@@ -6619,6 +7049,8 @@ impl UpdateTemplateRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateTemplateResponse {}
 
 struct UpdateTemplateResponseDeserializer;
@@ -6702,6 +7134,8 @@ impl VerificationTokenListDeserializer {
 }
 /// <p>Represents a request to generate the CNAME records needed to set up Easy DKIM with Amazon SES. For more information about setting up Easy DKIM, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyDomainDkimRequest {
     /// <p>The name of the domain to be verified for Easy DKIM signing.</p>
     pub domain: String,
@@ -6722,6 +7156,8 @@ impl VerifyDomainDkimRequestSerializer {
 
 /// <p>Returns CNAME records that you must publish to the DNS server of your domain to set up Easy DKIM with Amazon SES.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyDomainDkimResponse {
     /// <p>A set of character strings that represent the domain's identity. If the identity is an email address, the tokens represent the domain of that address.</p> <p>Using these tokens, you need to create DNS CNAME records that point to DKIM public keys that are hosted by Amazon SES. Amazon Web Services eventually detects that you've updated your DNS records. This detection process might take up to 72 hours. After successful detection, Amazon SES is able to DKIM-sign email originating from that domain. (This only applies to domain identities, not email address identities.)</p> <p>For more information about creating DNS records using DKIM tokens, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
     pub dkim_tokens: Vec<String>,
@@ -6755,6 +7191,8 @@ impl VerifyDomainDkimResponseDeserializer {
 }
 /// <p>Represents a request to begin Amazon SES domain verification and to generate the TXT records that you must publish to the DNS server of your domain to complete the verification. For information about domain verification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-domains.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyDomainIdentityRequest {
     /// <p>The domain to be verified.</p>
     pub domain: String,
@@ -6775,6 +7213,8 @@ impl VerifyDomainIdentityRequestSerializer {
 
 /// <p>Returns a TXT record that you must publish to the DNS server of your domain to complete domain verification with Amazon SES.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyDomainIdentityResponse {
     /// <p>A TXT record that you must place in the DNS settings of the domain to complete domain verification with Amazon SES.</p> <p>As Amazon SES searches for the TXT record, the domain's verification status is "Pending". When Amazon SES detects the record, the domain's verification status changes to "Success". If Amazon SES is unable to detect the record within 72 hours, the domain's verification status changes to "Failed." In that case, if you still want to verify the domain, you must restart the verification process from the beginning.</p>
     pub verification_token: String,
@@ -6805,6 +7245,8 @@ impl VerifyDomainIdentityResponseDeserializer {
 }
 /// <p>Represents a request to begin email address verification with Amazon SES. For information about email address verification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-email-addresses.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyEmailAddressRequest {
     /// <p>The email address to be verified.</p>
     pub email_address: String,
@@ -6825,6 +7267,8 @@ impl VerifyEmailAddressRequestSerializer {
 
 /// <p>Represents a request to begin email address verification with Amazon SES. For information about email address verification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-email-addresses.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyEmailIdentityRequest {
     /// <p>The email address to be verified.</p>
     pub email_address: String,
@@ -6845,6 +7289,8 @@ impl VerifyEmailIdentityRequestSerializer {
 
 /// <p>An empty element returned on a successful request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct VerifyEmailIdentityResponse {}
 
 struct VerifyEmailIdentityResponseDeserializer;
@@ -6865,6 +7311,8 @@ impl VerifyEmailIdentityResponseDeserializer {
 }
 /// <p>When included in a receipt rule, this action calls Amazon WorkMail and, optionally, publishes a notification to Amazon Simple Notification Service (Amazon SNS). You will typically not use this action directly because Amazon WorkMail adds the rule automatically during its setup procedure.</p> <p>For information using a receipt rule to call Amazon WorkMail, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-action-workmail.html">Amazon SES Developer Guide</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct WorkmailAction {
     /// <p>The ARN of the Amazon WorkMail organization. An example of an Amazon WorkMail organization ARN is <code>arn:aws:workmail:us-west-2:123456789012:organization/m-68755160c4cb4e29a2b2f8fb58f359d7</code>. For information about Amazon WorkMail organizations, see the <a href="https://docs.aws.amazon.com/workmail/latest/adminguide/organizations_overview.html">Amazon WorkMail Administrator Guide</a>.</p>
     pub organization_arn: String,
@@ -10999,143 +11447,20 @@ pub trait Ses {
         input: VerifyEmailIdentityRequest,
     ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError>;
 }
-/// A client for the Amazon SES API.
-#[derive(Clone)]
-pub struct SesClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SesClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SesClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SesClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SesClient {
-        SesClient { client, region }
-    }
-}
-
-impl Ses for SesClient {
-    /// <p>Creates a receipt rule set by cloning an existing one. All receipt rules and configurations are copied to the new receipt rule set and are completely independent of the source rule set.</p> <p>For information about setting up rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+const _: Option<&dyn Ses> = None;
+impl<'a, T: Ses + ?Sized> Ses for &'a T {
     fn clone_receipt_rule_set(
         &self,
         input: CloneReceiptRuleSetRequest,
     ) -> RusotoFuture<CloneReceiptRuleSetResponse, CloneReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CloneReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        CloneReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CloneReceiptRuleSetError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CloneReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CloneReceiptRuleSetResponseDeserializer::deserialize(
-                        "CloneReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).clone_receipt_rule_set(input)
     }
-
-    /// <p>Creates a configuration set.</p> <p>Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_configuration_set(
         &self,
         input: CreateConfigurationSetRequest,
     ) -> RusotoFuture<CreateConfigurationSetResponse, CreateConfigurationSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateConfigurationSet");
-        params.put("Version", "2010-12-01");
-        CreateConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConfigurationSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateConfigurationSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateConfigurationSetResponseDeserializer::deserialize(
-                        "CreateConfigurationSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_configuration_set(input)
     }
-
-    /// <p>Creates a configuration set event destination.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>An event destination is the AWS service to which Amazon SES publishes the email sending events associated with a configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_configuration_set_event_destination(
         &self,
         input: CreateConfigurationSetEventDestinationRequest,
@@ -11143,53 +11468,8 @@ impl Ses for SesClient {
         CreateConfigurationSetEventDestinationResponse,
         CreateConfigurationSetEventDestinationError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateConfigurationSetEventDestination");
-        params.put("Version", "2010-12-01");
-        CreateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConfigurationSetEventDestinationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateConfigurationSetEventDestinationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        CreateConfigurationSetEventDestinationResponseDeserializer::deserialize(
-                            "CreateConfigurationSetEventDestinationResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_configuration_set_event_destination(input)
     }
-
-    /// <p>Creates an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
     fn create_configuration_set_tracking_options(
         &self,
         input: CreateConfigurationSetTrackingOptionsRequest,
@@ -11197,330 +11477,44 @@ impl Ses for SesClient {
         CreateConfigurationSetTrackingOptionsResponse,
         CreateConfigurationSetTrackingOptionsError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateConfigurationSetTrackingOptions");
-        params.put("Version", "2010-12-01");
-        CreateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateConfigurationSetTrackingOptionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateConfigurationSetTrackingOptionsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        CreateConfigurationSetTrackingOptionsResponseDeserializer::deserialize(
-                            "CreateConfigurationSetTrackingOptionsResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_configuration_set_tracking_options(input)
     }
-
-    /// <p>Creates a new custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_custom_verification_email_template(
         &self,
         input: CreateCustomVerificationEmailTemplateRequest,
     ) -> RusotoFuture<(), CreateCustomVerificationEmailTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateCustomVerificationEmailTemplate");
-        params.put("Version", "2010-12-01");
-        CreateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCustomVerificationEmailTemplateError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).create_custom_verification_email_template(input)
     }
-
-    /// <p>Creates a new IP address filter.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_receipt_filter(
         &self,
         input: CreateReceiptFilterRequest,
     ) -> RusotoFuture<CreateReceiptFilterResponse, CreateReceiptFilterError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateReceiptFilter");
-        params.put("Version", "2010-12-01");
-        CreateReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateReceiptFilterError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateReceiptFilterResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateReceiptFilterResponseDeserializer::deserialize(
-                        "CreateReceiptFilterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_receipt_filter(input)
     }
-
-    /// <p>Creates a receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_receipt_rule(
         &self,
         input: CreateReceiptRuleRequest,
     ) -> RusotoFuture<CreateReceiptRuleResponse, CreateReceiptRuleError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateReceiptRule");
-        params.put("Version", "2010-12-01");
-        CreateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateReceiptRuleError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateReceiptRuleResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateReceiptRuleResponseDeserializer::deserialize(
-                        "CreateReceiptRuleResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_receipt_rule(input)
     }
-
-    /// <p>Creates an empty receipt rule set.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_receipt_rule_set(
         &self,
         input: CreateReceiptRuleSetRequest,
     ) -> RusotoFuture<CreateReceiptRuleSetResponse, CreateReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        CreateReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateReceiptRuleSetError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateReceiptRuleSetResponseDeserializer::deserialize(
-                        "CreateReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_receipt_rule_set(input)
     }
-
-    /// <p>Creates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn create_template(
         &self,
         input: CreateTemplateRequest,
     ) -> RusotoFuture<CreateTemplateResponse, CreateTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateTemplate");
-        params.put("Version", "2010-12-01");
-        CreateTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateTemplateResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateTemplateResponseDeserializer::deserialize(
-                        "CreateTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_template(input)
     }
-
-    /// <p>Deletes a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_configuration_set(
         &self,
         input: DeleteConfigurationSetRequest,
     ) -> RusotoFuture<DeleteConfigurationSetResponse, DeleteConfigurationSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteConfigurationSet");
-        params.put("Version", "2010-12-01");
-        DeleteConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConfigurationSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteConfigurationSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteConfigurationSetResponseDeserializer::deserialize(
-                        "DeleteConfigurationSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_configuration_set(input)
     }
-
-    /// <p>Deletes a configuration set event destination. Configuration set event destinations are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_configuration_set_event_destination(
         &self,
         input: DeleteConfigurationSetEventDestinationRequest,
@@ -11528,53 +11522,8 @@ impl Ses for SesClient {
         DeleteConfigurationSetEventDestinationResponse,
         DeleteConfigurationSetEventDestinationError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteConfigurationSetEventDestination");
-        params.put("Version", "2010-12-01");
-        DeleteConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConfigurationSetEventDestinationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteConfigurationSetEventDestinationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        DeleteConfigurationSetEventDestinationResponseDeserializer::deserialize(
-                            "DeleteConfigurationSetEventDestinationResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_configuration_set_event_destination(input)
     }
-
-    /// <p><p>Deletes an association between a configuration set and a custom domain for open and click event tracking.</p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p> <note> <p>Deleting this kind of association will result in emails sent using the specified configuration set to capture open and click events using the standard, Amazon SES-operated domains.</p> </note></p>
     fn delete_configuration_set_tracking_options(
         &self,
         input: DeleteConfigurationSetTrackingOptionsRequest,
@@ -11582,649 +11531,85 @@ impl Ses for SesClient {
         DeleteConfigurationSetTrackingOptionsResponse,
         DeleteConfigurationSetTrackingOptionsError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteConfigurationSetTrackingOptions");
-        params.put("Version", "2010-12-01");
-        DeleteConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteConfigurationSetTrackingOptionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteConfigurationSetTrackingOptionsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        DeleteConfigurationSetTrackingOptionsResponseDeserializer::deserialize(
-                            "DeleteConfigurationSetTrackingOptionsResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_configuration_set_tracking_options(input)
     }
-
-    /// <p>Deletes an existing custom verification email template. </p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_custom_verification_email_template(
         &self,
         input: DeleteCustomVerificationEmailTemplateRequest,
     ) -> RusotoFuture<(), DeleteCustomVerificationEmailTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteCustomVerificationEmailTemplate");
-        params.put("Version", "2010-12-01");
-        DeleteCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteCustomVerificationEmailTemplateError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_custom_verification_email_template(input)
     }
-
-    /// <p>Deletes the specified identity (an email address or a domain) from the list of verified identities.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_identity(
         &self,
         input: DeleteIdentityRequest,
     ) -> RusotoFuture<DeleteIdentityResponse, DeleteIdentityError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteIdentity");
-        params.put("Version", "2010-12-01");
-        DeleteIdentityRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteIdentityError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteIdentityResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteIdentityResponseDeserializer::deserialize(
-                        "DeleteIdentityResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_identity(input)
     }
-
-    /// <p>Deletes the specified sending authorization policy for the given identity (an email address or a domain). This API returns successfully even if a policy with the specified name does not exist.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_identity_policy(
         &self,
         input: DeleteIdentityPolicyRequest,
     ) -> RusotoFuture<DeleteIdentityPolicyResponse, DeleteIdentityPolicyError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteIdentityPolicy");
-        params.put("Version", "2010-12-01");
-        DeleteIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteIdentityPolicyError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteIdentityPolicyResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteIdentityPolicyResponseDeserializer::deserialize(
-                        "DeleteIdentityPolicyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_identity_policy(input)
     }
-
-    /// <p>Deletes the specified IP address filter.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_receipt_filter(
         &self,
         input: DeleteReceiptFilterRequest,
     ) -> RusotoFuture<DeleteReceiptFilterResponse, DeleteReceiptFilterError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteReceiptFilter");
-        params.put("Version", "2010-12-01");
-        DeleteReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteReceiptFilterError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteReceiptFilterResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteReceiptFilterResponseDeserializer::deserialize(
-                        "DeleteReceiptFilterResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_receipt_filter(input)
     }
-
-    /// <p>Deletes the specified receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_receipt_rule(
         &self,
         input: DeleteReceiptRuleRequest,
     ) -> RusotoFuture<DeleteReceiptRuleResponse, DeleteReceiptRuleError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteReceiptRule");
-        params.put("Version", "2010-12-01");
-        DeleteReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteReceiptRuleError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteReceiptRuleResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteReceiptRuleResponseDeserializer::deserialize(
-                        "DeleteReceiptRuleResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_receipt_rule(input)
     }
-
-    /// <p>Deletes the specified receipt rule set and all of the receipt rules it contains.</p> <note> <p>The currently active rule set cannot be deleted.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_receipt_rule_set(
         &self,
         input: DeleteReceiptRuleSetRequest,
     ) -> RusotoFuture<DeleteReceiptRuleSetResponse, DeleteReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        DeleteReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteReceiptRuleSetError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteReceiptRuleSetResponseDeserializer::deserialize(
-                        "DeleteReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_receipt_rule_set(input)
     }
-
-    /// <p>Deletes an email template.</p> <p>You can execute this operation no more than once per second.</p>
     fn delete_template(
         &self,
         input: DeleteTemplateRequest,
     ) -> RusotoFuture<DeleteTemplateResponse, DeleteTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteTemplate");
-        params.put("Version", "2010-12-01");
-        DeleteTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DeleteTemplateResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DeleteTemplateResponseDeserializer::deserialize(
-                        "DeleteTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).delete_template(input)
     }
-
-    /// <p>Deprecated. Use the <code>DeleteIdentity</code> operation to delete email addresses and domains.</p>
     fn delete_verified_email_address(
         &self,
         input: DeleteVerifiedEmailAddressRequest,
     ) -> RusotoFuture<(), DeleteVerifiedEmailAddressError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteVerifiedEmailAddress");
-        params.put("Version", "2010-12-01");
-        DeleteVerifiedEmailAddressRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteVerifiedEmailAddressError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_verified_email_address(input)
     }
-
-    /// <p>Returns the metadata and receipt rules for the receipt rule set that is currently active.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn describe_active_receipt_rule_set(
         &self,
         input: DescribeActiveReceiptRuleSetRequest,
     ) -> RusotoFuture<DescribeActiveReceiptRuleSetResponse, DescribeActiveReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeActiveReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        DescribeActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeActiveReceiptRuleSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeActiveReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeActiveReceiptRuleSetResponseDeserializer::deserialize(
-                        "DescribeActiveReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_active_receipt_rule_set(input)
     }
-
-    /// <p>Returns the details of the specified configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn describe_configuration_set(
         &self,
         input: DescribeConfigurationSetRequest,
     ) -> RusotoFuture<DescribeConfigurationSetResponse, DescribeConfigurationSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeConfigurationSet");
-        params.put("Version", "2010-12-01");
-        DescribeConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeConfigurationSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeConfigurationSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeConfigurationSetResponseDeserializer::deserialize(
-                        "DescribeConfigurationSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_configuration_set(input)
     }
-
-    /// <p>Returns the details of the specified receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn describe_receipt_rule(
         &self,
         input: DescribeReceiptRuleRequest,
     ) -> RusotoFuture<DescribeReceiptRuleResponse, DescribeReceiptRuleError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReceiptRule");
-        params.put("Version", "2010-12-01");
-        DescribeReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeReceiptRuleError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeReceiptRuleResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeReceiptRuleResponseDeserializer::deserialize(
-                        "DescribeReceiptRuleResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_receipt_rule(input)
     }
-
-    /// <p>Returns the details of the specified receipt rule set.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn describe_receipt_rule_set(
         &self,
         input: DescribeReceiptRuleSetRequest,
     ) -> RusotoFuture<DescribeReceiptRuleSetResponse, DescribeReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        DescribeReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeReceiptRuleSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = DescribeReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = DescribeReceiptRuleSetResponseDeserializer::deserialize(
-                        "DescribeReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).describe_receipt_rule_set(input)
     }
-
-    /// <p>Returns the email sending status of the Amazon SES account for the current region.</p> <p>You can execute this operation no more than once per second.</p>
     fn get_account_sending_enabled(
         &self,
     ) -> RusotoFuture<GetAccountSendingEnabledResponse, GetAccountSendingEnabledError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetAccountSendingEnabled");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAccountSendingEnabledError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetAccountSendingEnabledResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetAccountSendingEnabledResponseDeserializer::deserialize(
-                        "GetAccountSendingEnabledResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_account_sending_enabled()
     }
-
-    /// <p>Returns the custom email verification template for the template name you specify.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
     fn get_custom_verification_email_template(
         &self,
         input: GetCustomVerificationEmailTemplateRequest,
@@ -12232,100 +11617,14 @@ impl Ses for SesClient {
         GetCustomVerificationEmailTemplateResponse,
         GetCustomVerificationEmailTemplateError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetCustomVerificationEmailTemplate");
-        params.put("Version", "2010-12-01");
-        GetCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetCustomVerificationEmailTemplateError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetCustomVerificationEmailTemplateResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetCustomVerificationEmailTemplateResponseDeserializer::deserialize(
-                        "GetCustomVerificationEmailTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_custom_verification_email_template(input)
     }
-
-    /// <p>Returns the current status of Easy DKIM signing for an entity. For domain name identities, this operation also returns the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES has successfully verified that these tokens have been published.</p> <p>This operation takes a list of identities as input and returns the following information for each:</p> <ul> <li> <p>Whether Easy DKIM signing is enabled or disabled.</p> </li> <li> <p>A set of DKIM tokens that represent the identity. If the identity is an email address, the tokens represent the domain of that address.</p> </li> <li> <p>Whether Amazon SES has successfully verified the DKIM tokens published in the domain's DNS. This information is only returned for domain name identities, not for email addresses.</p> </li> </ul> <p>This operation is throttled at one request per second and can only get DKIM attributes for up to 100 identities at a time.</p> <p>For more information about creating DNS records using DKIM tokens, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim-dns-records.html">Amazon SES Developer Guide</a>.</p>
     fn get_identity_dkim_attributes(
         &self,
         input: GetIdentityDkimAttributesRequest,
     ) -> RusotoFuture<GetIdentityDkimAttributesResponse, GetIdentityDkimAttributesError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetIdentityDkimAttributes");
-        params.put("Version", "2010-12-01");
-        GetIdentityDkimAttributesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetIdentityDkimAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetIdentityDkimAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetIdentityDkimAttributesResponseDeserializer::deserialize(
-                        "GetIdentityDkimAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_identity_dkim_attributes(input)
     }
-
-    /// <p>Returns the custom MAIL FROM attributes for a list of identities (email addresses : domains).</p> <p>This operation is throttled at one request per second and can only get custom MAIL FROM attributes for up to 100 identities at a time.</p>
     fn get_identity_mail_from_domain_attributes(
         &self,
         input: GetIdentityMailFromDomainAttributesRequest,
@@ -12333,52 +11632,8 @@ impl Ses for SesClient {
         GetIdentityMailFromDomainAttributesResponse,
         GetIdentityMailFromDomainAttributesError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetIdentityMailFromDomainAttributes");
-        params.put("Version", "2010-12-01");
-        GetIdentityMailFromDomainAttributesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetIdentityMailFromDomainAttributesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetIdentityMailFromDomainAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetIdentityMailFromDomainAttributesResponseDeserializer::deserialize(
-                        "GetIdentityMailFromDomainAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_identity_mail_from_domain_attributes(input)
     }
-
-    /// <p>Given a list of verified identities (email addresses and/or domains), returns a structure describing identity notification attributes.</p> <p>This operation is throttled at one request per second and can only get notification attributes for up to 100 identities at a time.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
     fn get_identity_notification_attributes(
         &self,
         input: GetIdentityNotificationAttributesRequest,
@@ -12386,102 +11641,14 @@ impl Ses for SesClient {
         GetIdentityNotificationAttributesResponse,
         GetIdentityNotificationAttributesError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetIdentityNotificationAttributes");
-        params.put("Version", "2010-12-01");
-        GetIdentityNotificationAttributesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetIdentityNotificationAttributesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetIdentityNotificationAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetIdentityNotificationAttributesResponseDeserializer::deserialize(
-                        "GetIdentityNotificationAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_identity_notification_attributes(input)
     }
-
-    /// <p>Returns the requested sending authorization policies for the given identity (an email address or a domain). The policies are returned as a map of policy names to policy contents. You can retrieve a maximum of 20 policies at a time.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn get_identity_policies(
         &self,
         input: GetIdentityPoliciesRequest,
     ) -> RusotoFuture<GetIdentityPoliciesResponse, GetIdentityPoliciesError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetIdentityPolicies");
-        params.put("Version", "2010-12-01");
-        GetIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetIdentityPoliciesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetIdentityPoliciesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetIdentityPoliciesResponseDeserializer::deserialize(
-                        "GetIdentityPoliciesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_identity_policies(input)
     }
-
-    /// <p>Given a list of identities (email addresses and/or domains), returns the verification status and (for domain identities) the verification token for each identity.</p> <p>The verification status of an email address is "Pending" until the email address owner clicks the link within the verification email that Amazon SES sent to that address. If the email address owner clicks the link within 24 hours, the verification status of the email address changes to "Success". If the link is not clicked within 24 hours, the verification status changes to "Failed." In that case, if you still want to verify the email address, you must restart the verification process from the beginning.</p> <p>For domain identities, the domain's verification status is "Pending" as Amazon SES searches for the required TXT record in the DNS settings of the domain. When Amazon SES detects the record, the domain's verification status changes to "Success". If Amazon SES is unable to detect the record within 72 hours, the domain's verification status changes to "Failed." In that case, if you still want to verify the domain, you must restart the verification process from the beginning.</p> <p>This operation is throttled at one request per second and can only get verification attributes for up to 100 identities at a time.</p>
     fn get_identity_verification_attributes(
         &self,
         input: GetIdentityVerificationAttributesRequest,
@@ -12489,249 +11656,28 @@ impl Ses for SesClient {
         GetIdentityVerificationAttributesResponse,
         GetIdentityVerificationAttributesError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetIdentityVerificationAttributes");
-        params.put("Version", "2010-12-01");
-        GetIdentityVerificationAttributesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetIdentityVerificationAttributesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetIdentityVerificationAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetIdentityVerificationAttributesResponseDeserializer::deserialize(
-                        "GetIdentityVerificationAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_identity_verification_attributes(input)
     }
-
-    /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
     fn get_send_quota(&self) -> RusotoFuture<GetSendQuotaResponse, GetSendQuotaError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetSendQuota");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSendQuotaError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetSendQuotaResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetSendQuotaResponseDeserializer::deserialize(
-                        "GetSendQuotaResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_send_quota()
     }
-
-    /// <p>Provides sending statistics for the current AWS Region. The result is a list of data points, representing the last two weeks of sending activity. Each data point in the list contains statistics for a 15-minute period of time.</p> <p>You can execute this operation no more than once per second.</p>
     fn get_send_statistics(
         &self,
     ) -> RusotoFuture<GetSendStatisticsResponse, GetSendStatisticsError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetSendStatistics");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSendStatisticsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetSendStatisticsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetSendStatisticsResponseDeserializer::deserialize(
-                        "GetSendStatisticsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_send_statistics()
     }
-
-    /// <p>Displays the template object (which includes the Subject line, HTML part and text part) for the template you specify.</p> <p>You can execute this operation no more than once per second.</p>
     fn get_template(
         &self,
         input: GetTemplateRequest,
     ) -> RusotoFuture<GetTemplateResponse, GetTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetTemplate");
-        params.put("Version", "2010-12-01");
-        GetTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetTemplateResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetTemplateResponseDeserializer::deserialize(
-                        "GetTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_template(input)
     }
-
-    /// <p>Provides a list of the configuration sets associated with your Amazon SES account in the current AWS Region. For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second. This operation will return up to 1,000 configuration sets each time it is run. If your Amazon SES account has more than 1,000 configuration sets, this operation will also return a NextToken element. You can then execute the <code>ListConfigurationSets</code> operation again, passing the <code>NextToken</code> parameter and the value of the NextToken element to retrieve additional results.</p>
     fn list_configuration_sets(
         &self,
         input: ListConfigurationSetsRequest,
     ) -> RusotoFuture<ListConfigurationSetsResponse, ListConfigurationSetsError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListConfigurationSets");
-        params.put("Version", "2010-12-01");
-        ListConfigurationSetsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListConfigurationSetsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListConfigurationSetsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListConfigurationSetsResponseDeserializer::deserialize(
-                        "ListConfigurationSetsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_configuration_sets(input)
     }
-
-    /// <p>Lists the existing custom verification email templates for your account in the current AWS Region.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
     fn list_custom_verification_email_templates(
         &self,
         input: ListCustomVerificationEmailTemplatesRequest,
@@ -12739,352 +11685,43 @@ impl Ses for SesClient {
         ListCustomVerificationEmailTemplatesResponse,
         ListCustomVerificationEmailTemplatesError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListCustomVerificationEmailTemplates");
-        params.put("Version", "2010-12-01");
-        ListCustomVerificationEmailTemplatesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListCustomVerificationEmailTemplatesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListCustomVerificationEmailTemplatesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListCustomVerificationEmailTemplatesResponseDeserializer::deserialize(
-                        "ListCustomVerificationEmailTemplatesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_custom_verification_email_templates(input)
     }
-
-    /// <p>Returns a list containing all of the identities (email addresses and domains) for your AWS account in the current AWS Region, regardless of verification status.</p> <p>You can execute this operation no more than once per second.</p>
     fn list_identities(
         &self,
         input: ListIdentitiesRequest,
     ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListIdentities");
-        params.put("Version", "2010-12-01");
-        ListIdentitiesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListIdentitiesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListIdentitiesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListIdentitiesResponseDeserializer::deserialize(
-                        "ListIdentitiesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_identities(input)
     }
-
-    /// <p>Returns a list of sending authorization policies that are attached to the given identity (an email address or a domain). This API returns only a list. If you want the actual policy content, you can use <code>GetIdentityPolicies</code>.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn list_identity_policies(
         &self,
         input: ListIdentityPoliciesRequest,
     ) -> RusotoFuture<ListIdentityPoliciesResponse, ListIdentityPoliciesError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListIdentityPolicies");
-        params.put("Version", "2010-12-01");
-        ListIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListIdentityPoliciesError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListIdentityPoliciesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListIdentityPoliciesResponseDeserializer::deserialize(
-                        "ListIdentityPoliciesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_identity_policies(input)
     }
-
-    /// <p>Lists the IP address filters associated with your AWS account in the current AWS Region.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn list_receipt_filters(
         &self,
         input: ListReceiptFiltersRequest,
     ) -> RusotoFuture<ListReceiptFiltersResponse, ListReceiptFiltersError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListReceiptFilters");
-        params.put("Version", "2010-12-01");
-        ListReceiptFiltersRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListReceiptFiltersError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListReceiptFiltersResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListReceiptFiltersResponseDeserializer::deserialize(
-                        "ListReceiptFiltersResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_receipt_filters(input)
     }
-
-    /// <p>Lists the receipt rule sets that exist under your AWS account in the current AWS Region. If there are additional receipt rule sets to be retrieved, you will receive a <code>NextToken</code> that you can provide to the next call to <code>ListReceiptRuleSets</code> to retrieve the additional entries.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn list_receipt_rule_sets(
         &self,
         input: ListReceiptRuleSetsRequest,
     ) -> RusotoFuture<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListReceiptRuleSets");
-        params.put("Version", "2010-12-01");
-        ListReceiptRuleSetsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListReceiptRuleSetsError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListReceiptRuleSetsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListReceiptRuleSetsResponseDeserializer::deserialize(
-                        "ListReceiptRuleSetsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_receipt_rule_sets(input)
     }
-
-    /// <p>Lists the email templates present in your Amazon SES account in the current AWS Region.</p> <p>You can execute this operation no more than once per second.</p>
     fn list_templates(
         &self,
         input: ListTemplatesRequest,
     ) -> RusotoFuture<ListTemplatesResponse, ListTemplatesError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListTemplates");
-        params.put("Version", "2010-12-01");
-        ListTemplatesRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTemplatesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListTemplatesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListTemplatesResponseDeserializer::deserialize(
-                        "ListTemplatesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_templates(input)
     }
-
-    /// <p>Deprecated. Use the <code>ListIdentities</code> operation to list the email addresses and domains associated with your account.</p>
     fn list_verified_email_addresses(
         &self,
     ) -> RusotoFuture<ListVerifiedEmailAddressesResponse, ListVerifiedEmailAddressesError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListVerifiedEmailAddresses");
-        params.put("Version", "2010-12-01");
-
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListVerifiedEmailAddressesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListVerifiedEmailAddressesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListVerifiedEmailAddressesResponseDeserializer::deserialize(
-                        "ListVerifiedEmailAddressesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_verified_email_addresses()
     }
-
-    /// <p>Adds or updates the delivery options for a configuration set.</p>
     fn put_configuration_set_delivery_options(
         &self,
         input: PutConfigurationSetDeliveryOptionsRequest,
@@ -13092,545 +11729,68 @@ impl Ses for SesClient {
         PutConfigurationSetDeliveryOptionsResponse,
         PutConfigurationSetDeliveryOptionsError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PutConfigurationSetDeliveryOptions");
-        params.put("Version", "2010-12-01");
-        PutConfigurationSetDeliveryOptionsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutConfigurationSetDeliveryOptionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PutConfigurationSetDeliveryOptionsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PutConfigurationSetDeliveryOptionsResponseDeserializer::deserialize(
-                        "PutConfigurationSetDeliveryOptionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).put_configuration_set_delivery_options(input)
     }
-
-    /// <p>Adds or updates a sending authorization policy for the specified identity (an email address or a domain).</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn put_identity_policy(
         &self,
         input: PutIdentityPolicyRequest,
     ) -> RusotoFuture<PutIdentityPolicyResponse, PutIdentityPolicyError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "PutIdentityPolicy");
-        params.put("Version", "2010-12-01");
-        PutIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutIdentityPolicyError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PutIdentityPolicyResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PutIdentityPolicyResponseDeserializer::deserialize(
-                        "PutIdentityPolicyResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).put_identity_policy(input)
     }
-
-    /// <p>Reorders the receipt rules within a receipt rule set.</p> <note> <p>All of the rules in the rule set must be represented in this request. That is, this API will return an error if the reorder request doesn't explicitly position all of the rules.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn reorder_receipt_rule_set(
         &self,
         input: ReorderReceiptRuleSetRequest,
     ) -> RusotoFuture<ReorderReceiptRuleSetResponse, ReorderReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ReorderReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        ReorderReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ReorderReceiptRuleSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ReorderReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ReorderReceiptRuleSetResponseDeserializer::deserialize(
-                        "ReorderReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).reorder_receipt_rule_set(input)
     }
-
-    /// <p>Generates and sends a bounce message to the sender of an email you received through Amazon SES. You can only use this API on an email up to 24 hours after you receive it.</p> <note> <p>You cannot use this API to send generic bounces for mail that was not received by Amazon SES.</p> </note> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn send_bounce(
         &self,
         input: SendBounceRequest,
     ) -> RusotoFuture<SendBounceResponse, SendBounceError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SendBounce");
-        params.put("Version", "2010-12-01");
-        SendBounceRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendBounceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SendBounceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SendBounceResponseDeserializer::deserialize(
-                        "SendBounceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).send_bounce(input)
     }
-
-    /// <p><p>Composes an email message to multiple destinations. The message body is created using an email template.</p> <p>In order to send email using the <code>SendBulkTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Each <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendBulkTemplatedEmail</code> operation several times to send the message to each group.</p> </li> <li> <p>The number of destinations you can contact in a single call to the API may be limited by your account&#39;s maximum sending rate.</p> </li> </ul></p>
     fn send_bulk_templated_email(
         &self,
         input: SendBulkTemplatedEmailRequest,
     ) -> RusotoFuture<SendBulkTemplatedEmailResponse, SendBulkTemplatedEmailError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SendBulkTemplatedEmail");
-        params.put("Version", "2010-12-01");
-        SendBulkTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SendBulkTemplatedEmailError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SendBulkTemplatedEmailResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SendBulkTemplatedEmailResponseDeserializer::deserialize(
-                        "SendBulkTemplatedEmailResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).send_bulk_templated_email(input)
     }
-
-    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. As a result of executing this operation, a customized verification email is sent to the specified address.</p> <p>To use this operation, you must first create a custom verification email template. For more information about creating and using custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
     fn send_custom_verification_email(
         &self,
         input: SendCustomVerificationEmailRequest,
     ) -> RusotoFuture<SendCustomVerificationEmailResponse, SendCustomVerificationEmailError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SendCustomVerificationEmail");
-        params.put("Version", "2010-12-01");
-        SendCustomVerificationEmailRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SendCustomVerificationEmailError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SendCustomVerificationEmailResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SendCustomVerificationEmailResponseDeserializer::deserialize(
-                        "SendCustomVerificationEmailResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).send_custom_verification_email(input)
     }
-
-    /// <p><p>Composes an email message and immediately queues it for sending. In order to send email using the <code>SendEmail</code> operation, your message must meet the following requirements:</p> <ul> <li> <p>The message must be sent from a verified email address or domain. If you attempt to send email using a non-verified address or domain, the operation will result in an &quot;Email address not verified&quot; error. </p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>The message must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendEmail</code> operation several times to send the message to each group.</p> </li> </ul> <important> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </important></p>
     fn send_email(
         &self,
         input: SendEmailRequest,
     ) -> RusotoFuture<SendEmailResponse, SendEmailError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SendEmail");
-        params.put("Version", "2010-12-01");
-        SendEmailRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendEmailError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SendEmailResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        SendEmailResponseDeserializer::deserialize("SendEmailResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).send_email(input)
     }
-
-    /// <p><p>Composes an email message and immediately queues it for sending.</p> <p>This operation is more flexible than the <code>SendEmail</code> API operation. When you use the <code>SendRawEmail</code> operation, you can specify the headers of the message as well as its content. This flexibility is useful, for example, when you want to send a multipart MIME email (such a message that contains both a text and an HTML version). You can also use this operation to send messages that include attachments.</p> <p>The <code>SendRawEmail</code> operation has the following requirements:</p> <ul> <li> <p>You can only send email from <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">verified email addresses or domains</a>. If you try to send email from an address that isn&#39;t verified, the operation results in an &quot;Email address not verified&quot; error.</p> </li> <li> <p>If your account is still in the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/request-production-access.html">Amazon SES sandbox</a>, you can only send email to other verified addresses in your account, or to addresses that are associated with the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mailbox-simulator.html">Amazon SES mailbox simulator</a>.</p> </li> <li> <p>The maximum message size, including attachments, is 10 MB.</p> </li> <li> <p>Each message has to include at least one recipient address. A recipient address includes any address on the To:, CC:, or BCC: lines.</p> </li> <li> <p>If you send a single message to more than one recipient address, and one of the recipient addresses isn&#39;t in a valid format (that is, it&#39;s not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), Amazon SES rejects the entire message, even if the other addresses are valid.</p> </li> <li> <p>Each message can include up to 50 recipient addresses across the To:, CC:, or BCC: lines. If you need to send a single message to more than 50 recipients, you have to split the list of recipient addresses into groups of less than 50 recipients, and send separate messages to each group.</p> </li> <li> <p>Amazon SES allows you to specify 8-bit Content-Transfer-Encoding for MIME message parts. However, if Amazon SES has to modify the contents of your message (for example, if you use open and click tracking), 8-bit content isn&#39;t preserved. For this reason, we highly recommend that you encode all content that isn&#39;t 7-bit ASCII. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html#send-email-mime-encoding">MIME Encoding</a> in the <i>Amazon SES Developer Guide</i>.</p> </li> </ul> <p>Additionally, keep the following considerations in mind when using the <code>SendRawEmail</code> operation:</p> <ul> <li> <p>Although you can customize the message headers when using the <code>SendRawEmail</code> operation, Amazon SES will automatically apply its own <code>Message-ID</code> and <code>Date</code> headers; if you passed these headers when creating the message, they will be overwritten by the values that Amazon SES provides.</p> </li> <li> <p>If you are using sending authorization to send on behalf of another user, <code>SendRawEmail</code> enables you to specify the cross-account identity for the email&#39;s Source, From, and Return-Path parameters in one of two ways: you can pass optional parameters <code>SourceArn</code>, <code>FromArn</code>, and/or <code>ReturnPathArn</code> to the API, or you can include the following X-headers in the header of your raw email:</p> <ul> <li> <p> <code>X-SES-SOURCE-ARN</code> </p> </li> <li> <p> <code>X-SES-FROM-ARN</code> </p> </li> <li> <p> <code>X-SES-RETURN-PATH-ARN</code> </p> </li> </ul> <important> <p>Don&#39;t include these X-headers in the DKIM signature. Amazon SES removes these before it sends the email.</p> </important> <p>If you only specify the <code>SourceIdentityArn</code> parameter, Amazon SES sets the From and Return-Path addresses to the same identity that you specified.</p> <p>For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Using Sending Authorization with Amazon SES</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> </ul></p>
     fn send_raw_email(
         &self,
         input: SendRawEmailRequest,
     ) -> RusotoFuture<SendRawEmailResponse, SendRawEmailError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SendRawEmail");
-        params.put("Version", "2010-12-01");
-        SendRawEmailRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendRawEmailError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SendRawEmailResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SendRawEmailResponseDeserializer::deserialize(
-                        "SendRawEmailResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).send_raw_email(input)
     }
-
-    /// <p><p>Composes an email message using an email template and immediately queues it for sending.</p> <p>In order to send email using the <code>SendTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Calls to the <code>SendTemplatedEmail</code> operation may only include one <code>Destination</code> parameter. A destination is a set of recipients who will receive the same version of the email. The <code>Destination</code> parameter can include up to 50 recipients, across the To:, CC: and BCC: fields.</p> </li> <li> <p>The <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> </ul> <important> <p>If your call to the <code>SendTemplatedEmail</code> operation includes all of the required parameters, Amazon SES accepts it and returns a Message ID. However, if Amazon SES can&#39;t render the email because the template contains errors, it doesn&#39;t send the email. Additionally, because it already accepted the message, Amazon SES doesn&#39;t return a message stating that it was unable to send the email.</p> <p>For these reasons, we highly recommend that you set up Amazon SES to send you notifications when Rendering Failure events occur. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Sending Personalized Email Using the Amazon SES API</a> in the <i>Amazon Simple Email Service Developer Guide</i>.</p> </important></p>
     fn send_templated_email(
         &self,
         input: SendTemplatedEmailRequest,
     ) -> RusotoFuture<SendTemplatedEmailResponse, SendTemplatedEmailError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SendTemplatedEmail");
-        params.put("Version", "2010-12-01");
-        SendTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendTemplatedEmailError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SendTemplatedEmailResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SendTemplatedEmailResponseDeserializer::deserialize(
-                        "SendTemplatedEmailResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).send_templated_email(input)
     }
-
-    /// <p>Sets the specified receipt rule set as the active receipt rule set.</p> <note> <p>To disable your email-receiving through Amazon SES completely, you can call this API with RuleSetName set to null.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn set_active_receipt_rule_set(
         &self,
         input: SetActiveReceiptRuleSetRequest,
     ) -> RusotoFuture<SetActiveReceiptRuleSetResponse, SetActiveReceiptRuleSetError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetActiveReceiptRuleSet");
-        params.put("Version", "2010-12-01");
-        SetActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetActiveReceiptRuleSetError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetActiveReceiptRuleSetResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetActiveReceiptRuleSetResponseDeserializer::deserialize(
-                        "SetActiveReceiptRuleSetResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).set_active_receipt_rule_set(input)
     }
-
-    /// <p>Enables or disables Easy DKIM signing of email sent from an identity. If Easy DKIM signing is enabled for a domain, then Amazon SES uses DKIM to sign all email that it sends from addresses on that domain. If Easy DKIM signing is enabled for an email address, then Amazon SES uses DKIM to sign all email it sends from that address.</p> <note> <p>For email addresses (for example, <code>user@example.com</code>), you can only enable DKIM signing if the corresponding domain (in this case, <code>example.com</code>) has been set up to use Easy DKIM.</p> </note> <p>You can enable DKIM signing for an identity at any time after you start the verification process for the identity, even if the verification process isn't complete. </p> <p>You can execute this operation no more than once per second.</p> <p>For more information about Easy DKIM signing, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
     fn set_identity_dkim_enabled(
         &self,
         input: SetIdentityDkimEnabledRequest,
     ) -> RusotoFuture<SetIdentityDkimEnabledResponse, SetIdentityDkimEnabledError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetIdentityDkimEnabled");
-        params.put("Version", "2010-12-01");
-        SetIdentityDkimEnabledRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetIdentityDkimEnabledError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetIdentityDkimEnabledResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetIdentityDkimEnabledResponseDeserializer::deserialize(
-                        "SetIdentityDkimEnabledResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).set_identity_dkim_enabled(input)
     }
-
-    /// <p>Given an identity (an email address or a domain), enables or disables whether Amazon SES forwards bounce and complaint notifications as email. Feedback forwarding can only be disabled when Amazon Simple Notification Service (Amazon SNS) topics are specified for both bounces and complaints.</p> <note> <p>Feedback forwarding does not apply to delivery notifications. Delivery notifications are only available through Amazon SNS.</p> </note> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
     fn set_identity_feedback_forwarding_enabled(
         &self,
         input: SetIdentityFeedbackForwardingEnabledRequest,
@@ -13638,52 +11798,8 @@ impl Ses for SesClient {
         SetIdentityFeedbackForwardingEnabledResponse,
         SetIdentityFeedbackForwardingEnabledError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetIdentityFeedbackForwardingEnabled");
-        params.put("Version", "2010-12-01");
-        SetIdentityFeedbackForwardingEnabledRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetIdentityFeedbackForwardingEnabledError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetIdentityFeedbackForwardingEnabledResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetIdentityFeedbackForwardingEnabledResponseDeserializer::deserialize(
-                        "SetIdentityFeedbackForwardingEnabledResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).set_identity_feedback_forwarding_enabled(input)
     }
-
-    /// <p>Given an identity (an email address or a domain), sets whether Amazon SES includes the original email headers in the Amazon Simple Notification Service (Amazon SNS) notifications of a specified type.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
     fn set_identity_headers_in_notifications_enabled(
         &self,
         input: SetIdentityHeadersInNotificationsEnabledRequest,
@@ -13691,275 +11807,7233 @@ impl Ses for SesClient {
         SetIdentityHeadersInNotificationsEnabledResponse,
         SetIdentityHeadersInNotificationsEnabledError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetIdentityHeadersInNotificationsEnabled");
-        params.put("Version", "2010-12-01");
-        SetIdentityHeadersInNotificationsEnabledRequestSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetIdentityHeadersInNotificationsEnabledError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetIdentityHeadersInNotificationsEnabledResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        SetIdentityHeadersInNotificationsEnabledResponseDeserializer::deserialize(
-                            "SetIdentityHeadersInNotificationsEnabledResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).set_identity_headers_in_notifications_enabled(input)
     }
-
-    /// <p>Enables or disables the custom MAIL FROM domain setup for a verified identity (an email address or a domain).</p> <important> <p>To send emails using the specified MAIL FROM domain, you must add an MX record to your MAIL FROM domain's DNS settings. If you want your emails to pass Sender Policy Framework (SPF) checks, you must also add or update an SPF record. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-set.html">Amazon SES Developer Guide</a>.</p> </important> <p>You can execute this operation no more than once per second.</p>
     fn set_identity_mail_from_domain(
         &self,
         input: SetIdentityMailFromDomainRequest,
     ) -> RusotoFuture<SetIdentityMailFromDomainResponse, SetIdentityMailFromDomainError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetIdentityMailFromDomain");
-        params.put("Version", "2010-12-01");
-        SetIdentityMailFromDomainRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetIdentityMailFromDomainError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetIdentityMailFromDomainResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetIdentityMailFromDomainResponseDeserializer::deserialize(
-                        "SetIdentityMailFromDomainResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).set_identity_mail_from_domain(input)
     }
-
-    /// <p>Sets an Amazon Simple Notification Service (Amazon SNS) topic to use when delivering notifications. When you use this operation, you specify a verified identity, such as an email address or domain. When you send an email that uses the chosen identity in the Source field, Amazon SES sends notifications to the topic you specified. You can send bounce, complaint, or delivery notifications (or any combination of the three) to the Amazon SNS topic that you specify.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about feedback notification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
     fn set_identity_notification_topic(
         &self,
         input: SetIdentityNotificationTopicRequest,
     ) -> RusotoFuture<SetIdentityNotificationTopicResponse, SetIdentityNotificationTopicError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetIdentityNotificationTopic");
-        params.put("Version", "2010-12-01");
-        SetIdentityNotificationTopicRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetIdentityNotificationTopicError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetIdentityNotificationTopicResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetIdentityNotificationTopicResponseDeserializer::deserialize(
-                        "SetIdentityNotificationTopicResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).set_identity_notification_topic(input)
     }
-
-    /// <p>Sets the position of the specified receipt rule in the receipt rule set.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn set_receipt_rule_position(
         &self,
         input: SetReceiptRulePositionRequest,
     ) -> RusotoFuture<SetReceiptRulePositionResponse, SetReceiptRulePositionError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetReceiptRulePosition");
+        (**self).set_receipt_rule_position(input)
+    }
+    fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> RusotoFuture<TestRenderTemplateResponse, TestRenderTemplateError> {
+        (**self).test_render_template(input)
+    }
+    fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateAccountSendingEnabledError> {
+        (**self).update_account_sending_enabled(input)
+    }
+    fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetEventDestinationResponse,
+        UpdateConfigurationSetEventDestinationError,
+    > {
+        (**self).update_configuration_set_event_destination(input)
+    }
+    fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetReputationMetricsEnabledError> {
+        (**self).update_configuration_set_reputation_metrics_enabled(input)
+    }
+    fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetSendingEnabledError> {
+        (**self).update_configuration_set_sending_enabled(input)
+    }
+    fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        UpdateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).update_configuration_set_tracking_options(input)
+    }
+    fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), UpdateCustomVerificationEmailTemplateError> {
+        (**self).update_custom_verification_email_template(input)
+    }
+    fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> RusotoFuture<UpdateReceiptRuleResponse, UpdateReceiptRuleError> {
+        (**self).update_receipt_rule(input)
+    }
+    fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> RusotoFuture<UpdateTemplateResponse, UpdateTemplateError> {
+        (**self).update_template(input)
+    }
+    fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> RusotoFuture<VerifyDomainDkimResponse, VerifyDomainDkimError> {
+        (**self).verify_domain_dkim(input)
+    }
+    fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> RusotoFuture<VerifyDomainIdentityResponse, VerifyDomainIdentityError> {
+        (**self).verify_domain_identity(input)
+    }
+    fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> RusotoFuture<(), VerifyEmailAddressError> {
+        (**self).verify_email_address(input)
+    }
+    fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError> {
+        (**self).verify_email_identity(input)
+    }
+}
+impl<T: Ses + ?Sized> Ses for Box<T> {
+    fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> RusotoFuture<CloneReceiptRuleSetResponse, CloneReceiptRuleSetError> {
+        (**self).clone_receipt_rule_set(input)
+    }
+    fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> RusotoFuture<CreateConfigurationSetResponse, CreateConfigurationSetError> {
+        (**self).create_configuration_set(input)
+    }
+    fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetEventDestinationResponse,
+        CreateConfigurationSetEventDestinationError,
+    > {
+        (**self).create_configuration_set_event_destination(input)
+    }
+    fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetTrackingOptionsResponse,
+        CreateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).create_configuration_set_tracking_options(input)
+    }
+    fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), CreateCustomVerificationEmailTemplateError> {
+        (**self).create_custom_verification_email_template(input)
+    }
+    fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> RusotoFuture<CreateReceiptFilterResponse, CreateReceiptFilterError> {
+        (**self).create_receipt_filter(input)
+    }
+    fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> RusotoFuture<CreateReceiptRuleResponse, CreateReceiptRuleError> {
+        (**self).create_receipt_rule(input)
+    }
+    fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> RusotoFuture<CreateReceiptRuleSetResponse, CreateReceiptRuleSetError> {
+        (**self).create_receipt_rule_set(input)
+    }
+    fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> RusotoFuture<CreateTemplateResponse, CreateTemplateError> {
+        (**self).create_template(input)
+    }
+    fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> RusotoFuture<DeleteConfigurationSetResponse, DeleteConfigurationSetError> {
+        (**self).delete_configuration_set(input)
+    }
+    fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetEventDestinationResponse,
+        DeleteConfigurationSetEventDestinationError,
+    > {
+        (**self).delete_configuration_set_event_destination(input)
+    }
+    fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        DeleteConfigurationSetTrackingOptionsError,
+    > {
+        (**self).delete_configuration_set_tracking_options(input)
+    }
+    fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), DeleteCustomVerificationEmailTemplateError> {
+        (**self).delete_custom_verification_email_template(input)
+    }
+    fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> RusotoFuture<DeleteIdentityResponse, DeleteIdentityError> {
+        (**self).delete_identity(input)
+    }
+    fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> RusotoFuture<DeleteIdentityPolicyResponse, DeleteIdentityPolicyError> {
+        (**self).delete_identity_policy(input)
+    }
+    fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> RusotoFuture<DeleteReceiptFilterResponse, DeleteReceiptFilterError> {
+        (**self).delete_receipt_filter(input)
+    }
+    fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleResponse, DeleteReceiptRuleError> {
+        (**self).delete_receipt_rule(input)
+    }
+    fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleSetResponse, DeleteReceiptRuleSetError> {
+        (**self).delete_receipt_rule_set(input)
+    }
+    fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> RusotoFuture<DeleteTemplateResponse, DeleteTemplateError> {
+        (**self).delete_template(input)
+    }
+    fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> RusotoFuture<(), DeleteVerifiedEmailAddressError> {
+        (**self).delete_verified_email_address(input)
+    }
+    fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeActiveReceiptRuleSetResponse, DescribeActiveReceiptRuleSetError> {
+        (**self).describe_active_receipt_rule_set(input)
+    }
+    fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> RusotoFuture<DescribeConfigurationSetResponse, DescribeConfigurationSetError> {
+        (**self).describe_configuration_set(input)
+    }
+    fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleResponse, DescribeReceiptRuleError> {
+        (**self).describe_receipt_rule(input)
+    }
+    fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleSetResponse, DescribeReceiptRuleSetError> {
+        (**self).describe_receipt_rule_set(input)
+    }
+    fn get_account_sending_enabled(
+        &self,
+    ) -> RusotoFuture<GetAccountSendingEnabledResponse, GetAccountSendingEnabledError> {
+        (**self).get_account_sending_enabled()
+    }
+    fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<
+        GetCustomVerificationEmailTemplateResponse,
+        GetCustomVerificationEmailTemplateError,
+    > {
+        (**self).get_custom_verification_email_template(input)
+    }
+    fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> RusotoFuture<GetIdentityDkimAttributesResponse, GetIdentityDkimAttributesError> {
+        (**self).get_identity_dkim_attributes(input)
+    }
+    fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityMailFromDomainAttributesResponse,
+        GetIdentityMailFromDomainAttributesError,
+    > {
+        (**self).get_identity_mail_from_domain_attributes(input)
+    }
+    fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityNotificationAttributesResponse,
+        GetIdentityNotificationAttributesError,
+    > {
+        (**self).get_identity_notification_attributes(input)
+    }
+    fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> RusotoFuture<GetIdentityPoliciesResponse, GetIdentityPoliciesError> {
+        (**self).get_identity_policies(input)
+    }
+    fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityVerificationAttributesResponse,
+        GetIdentityVerificationAttributesError,
+    > {
+        (**self).get_identity_verification_attributes(input)
+    }
+    fn get_send_quota(&self) -> RusotoFuture<GetSendQuotaResponse, GetSendQuotaError> {
+        (**self).get_send_quota()
+    }
+    fn get_send_statistics(
+        &self,
+    ) -> RusotoFuture<GetSendStatisticsResponse, GetSendStatisticsError> {
+        (**self).get_send_statistics()
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> RusotoFuture<GetTemplateResponse, GetTemplateError> {
+        (**self).get_template(input)
+    }
+    fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> RusotoFuture<ListConfigurationSetsResponse, ListConfigurationSetsError> {
+        (**self).list_configuration_sets(input)
+    }
+    fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> RusotoFuture<
+        ListCustomVerificationEmailTemplatesResponse,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        (**self).list_custom_verification_email_templates(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> RusotoFuture<ListIdentityPoliciesResponse, ListIdentityPoliciesError> {
+        (**self).list_identity_policies(input)
+    }
+    fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> RusotoFuture<ListReceiptFiltersResponse, ListReceiptFiltersError> {
+        (**self).list_receipt_filters(input)
+    }
+    fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> RusotoFuture<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError> {
+        (**self).list_receipt_rule_sets(input)
+    }
+    fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> RusotoFuture<ListTemplatesResponse, ListTemplatesError> {
+        (**self).list_templates(input)
+    }
+    fn list_verified_email_addresses(
+        &self,
+    ) -> RusotoFuture<ListVerifiedEmailAddressesResponse, ListVerifiedEmailAddressesError> {
+        (**self).list_verified_email_addresses()
+    }
+    fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> RusotoFuture<
+        PutConfigurationSetDeliveryOptionsResponse,
+        PutConfigurationSetDeliveryOptionsError,
+    > {
+        (**self).put_configuration_set_delivery_options(input)
+    }
+    fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> RusotoFuture<PutIdentityPolicyResponse, PutIdentityPolicyError> {
+        (**self).put_identity_policy(input)
+    }
+    fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> RusotoFuture<ReorderReceiptRuleSetResponse, ReorderReceiptRuleSetError> {
+        (**self).reorder_receipt_rule_set(input)
+    }
+    fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> RusotoFuture<SendBounceResponse, SendBounceError> {
+        (**self).send_bounce(input)
+    }
+    fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> RusotoFuture<SendBulkTemplatedEmailResponse, SendBulkTemplatedEmailError> {
+        (**self).send_bulk_templated_email(input)
+    }
+    fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> RusotoFuture<SendCustomVerificationEmailResponse, SendCustomVerificationEmailError> {
+        (**self).send_custom_verification_email(input)
+    }
+    fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> RusotoFuture<SendEmailResponse, SendEmailError> {
+        (**self).send_email(input)
+    }
+    fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> RusotoFuture<SendRawEmailResponse, SendRawEmailError> {
+        (**self).send_raw_email(input)
+    }
+    fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> RusotoFuture<SendTemplatedEmailResponse, SendTemplatedEmailError> {
+        (**self).send_templated_email(input)
+    }
+    fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<SetActiveReceiptRuleSetResponse, SetActiveReceiptRuleSetError> {
+        (**self).set_active_receipt_rule_set(input)
+    }
+    fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> RusotoFuture<SetIdentityDkimEnabledResponse, SetIdentityDkimEnabledError> {
+        (**self).set_identity_dkim_enabled(input)
+    }
+    fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        SetIdentityFeedbackForwardingEnabledError,
+    > {
+        (**self).set_identity_feedback_forwarding_enabled(input)
+    }
+    fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        SetIdentityHeadersInNotificationsEnabledError,
+    > {
+        (**self).set_identity_headers_in_notifications_enabled(input)
+    }
+    fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> RusotoFuture<SetIdentityMailFromDomainResponse, SetIdentityMailFromDomainError> {
+        (**self).set_identity_mail_from_domain(input)
+    }
+    fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> RusotoFuture<SetIdentityNotificationTopicResponse, SetIdentityNotificationTopicError> {
+        (**self).set_identity_notification_topic(input)
+    }
+    fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> RusotoFuture<SetReceiptRulePositionResponse, SetReceiptRulePositionError> {
+        (**self).set_receipt_rule_position(input)
+    }
+    fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> RusotoFuture<TestRenderTemplateResponse, TestRenderTemplateError> {
+        (**self).test_render_template(input)
+    }
+    fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateAccountSendingEnabledError> {
+        (**self).update_account_sending_enabled(input)
+    }
+    fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetEventDestinationResponse,
+        UpdateConfigurationSetEventDestinationError,
+    > {
+        (**self).update_configuration_set_event_destination(input)
+    }
+    fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetReputationMetricsEnabledError> {
+        (**self).update_configuration_set_reputation_metrics_enabled(input)
+    }
+    fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetSendingEnabledError> {
+        (**self).update_configuration_set_sending_enabled(input)
+    }
+    fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        UpdateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).update_configuration_set_tracking_options(input)
+    }
+    fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), UpdateCustomVerificationEmailTemplateError> {
+        (**self).update_custom_verification_email_template(input)
+    }
+    fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> RusotoFuture<UpdateReceiptRuleResponse, UpdateReceiptRuleError> {
+        (**self).update_receipt_rule(input)
+    }
+    fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> RusotoFuture<UpdateTemplateResponse, UpdateTemplateError> {
+        (**self).update_template(input)
+    }
+    fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> RusotoFuture<VerifyDomainDkimResponse, VerifyDomainDkimError> {
+        (**self).verify_domain_dkim(input)
+    }
+    fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> RusotoFuture<VerifyDomainIdentityResponse, VerifyDomainIdentityError> {
+        (**self).verify_domain_identity(input)
+    }
+    fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> RusotoFuture<(), VerifyEmailAddressError> {
+        (**self).verify_email_address(input)
+    }
+    fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError> {
+        (**self).verify_email_identity(input)
+    }
+}
+impl<T: Ses + ?Sized> Ses for ::std::sync::Arc<T> {
+    fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> RusotoFuture<CloneReceiptRuleSetResponse, CloneReceiptRuleSetError> {
+        (**self).clone_receipt_rule_set(input)
+    }
+    fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> RusotoFuture<CreateConfigurationSetResponse, CreateConfigurationSetError> {
+        (**self).create_configuration_set(input)
+    }
+    fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetEventDestinationResponse,
+        CreateConfigurationSetEventDestinationError,
+    > {
+        (**self).create_configuration_set_event_destination(input)
+    }
+    fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetTrackingOptionsResponse,
+        CreateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).create_configuration_set_tracking_options(input)
+    }
+    fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), CreateCustomVerificationEmailTemplateError> {
+        (**self).create_custom_verification_email_template(input)
+    }
+    fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> RusotoFuture<CreateReceiptFilterResponse, CreateReceiptFilterError> {
+        (**self).create_receipt_filter(input)
+    }
+    fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> RusotoFuture<CreateReceiptRuleResponse, CreateReceiptRuleError> {
+        (**self).create_receipt_rule(input)
+    }
+    fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> RusotoFuture<CreateReceiptRuleSetResponse, CreateReceiptRuleSetError> {
+        (**self).create_receipt_rule_set(input)
+    }
+    fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> RusotoFuture<CreateTemplateResponse, CreateTemplateError> {
+        (**self).create_template(input)
+    }
+    fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> RusotoFuture<DeleteConfigurationSetResponse, DeleteConfigurationSetError> {
+        (**self).delete_configuration_set(input)
+    }
+    fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetEventDestinationResponse,
+        DeleteConfigurationSetEventDestinationError,
+    > {
+        (**self).delete_configuration_set_event_destination(input)
+    }
+    fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        DeleteConfigurationSetTrackingOptionsError,
+    > {
+        (**self).delete_configuration_set_tracking_options(input)
+    }
+    fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), DeleteCustomVerificationEmailTemplateError> {
+        (**self).delete_custom_verification_email_template(input)
+    }
+    fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> RusotoFuture<DeleteIdentityResponse, DeleteIdentityError> {
+        (**self).delete_identity(input)
+    }
+    fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> RusotoFuture<DeleteIdentityPolicyResponse, DeleteIdentityPolicyError> {
+        (**self).delete_identity_policy(input)
+    }
+    fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> RusotoFuture<DeleteReceiptFilterResponse, DeleteReceiptFilterError> {
+        (**self).delete_receipt_filter(input)
+    }
+    fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleResponse, DeleteReceiptRuleError> {
+        (**self).delete_receipt_rule(input)
+    }
+    fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleSetResponse, DeleteReceiptRuleSetError> {
+        (**self).delete_receipt_rule_set(input)
+    }
+    fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> RusotoFuture<DeleteTemplateResponse, DeleteTemplateError> {
+        (**self).delete_template(input)
+    }
+    fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> RusotoFuture<(), DeleteVerifiedEmailAddressError> {
+        (**self).delete_verified_email_address(input)
+    }
+    fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeActiveReceiptRuleSetResponse, DescribeActiveReceiptRuleSetError> {
+        (**self).describe_active_receipt_rule_set(input)
+    }
+    fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> RusotoFuture<DescribeConfigurationSetResponse, DescribeConfigurationSetError> {
+        (**self).describe_configuration_set(input)
+    }
+    fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleResponse, DescribeReceiptRuleError> {
+        (**self).describe_receipt_rule(input)
+    }
+    fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleSetResponse, DescribeReceiptRuleSetError> {
+        (**self).describe_receipt_rule_set(input)
+    }
+    fn get_account_sending_enabled(
+        &self,
+    ) -> RusotoFuture<GetAccountSendingEnabledResponse, GetAccountSendingEnabledError> {
+        (**self).get_account_sending_enabled()
+    }
+    fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<
+        GetCustomVerificationEmailTemplateResponse,
+        GetCustomVerificationEmailTemplateError,
+    > {
+        (**self).get_custom_verification_email_template(input)
+    }
+    fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> RusotoFuture<GetIdentityDkimAttributesResponse, GetIdentityDkimAttributesError> {
+        (**self).get_identity_dkim_attributes(input)
+    }
+    fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityMailFromDomainAttributesResponse,
+        GetIdentityMailFromDomainAttributesError,
+    > {
+        (**self).get_identity_mail_from_domain_attributes(input)
+    }
+    fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityNotificationAttributesResponse,
+        GetIdentityNotificationAttributesError,
+    > {
+        (**self).get_identity_notification_attributes(input)
+    }
+    fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> RusotoFuture<GetIdentityPoliciesResponse, GetIdentityPoliciesError> {
+        (**self).get_identity_policies(input)
+    }
+    fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityVerificationAttributesResponse,
+        GetIdentityVerificationAttributesError,
+    > {
+        (**self).get_identity_verification_attributes(input)
+    }
+    fn get_send_quota(&self) -> RusotoFuture<GetSendQuotaResponse, GetSendQuotaError> {
+        (**self).get_send_quota()
+    }
+    fn get_send_statistics(
+        &self,
+    ) -> RusotoFuture<GetSendStatisticsResponse, GetSendStatisticsError> {
+        (**self).get_send_statistics()
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> RusotoFuture<GetTemplateResponse, GetTemplateError> {
+        (**self).get_template(input)
+    }
+    fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> RusotoFuture<ListConfigurationSetsResponse, ListConfigurationSetsError> {
+        (**self).list_configuration_sets(input)
+    }
+    fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> RusotoFuture<
+        ListCustomVerificationEmailTemplatesResponse,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        (**self).list_custom_verification_email_templates(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> RusotoFuture<ListIdentityPoliciesResponse, ListIdentityPoliciesError> {
+        (**self).list_identity_policies(input)
+    }
+    fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> RusotoFuture<ListReceiptFiltersResponse, ListReceiptFiltersError> {
+        (**self).list_receipt_filters(input)
+    }
+    fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> RusotoFuture<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError> {
+        (**self).list_receipt_rule_sets(input)
+    }
+    fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> RusotoFuture<ListTemplatesResponse, ListTemplatesError> {
+        (**self).list_templates(input)
+    }
+    fn list_verified_email_addresses(
+        &self,
+    ) -> RusotoFuture<ListVerifiedEmailAddressesResponse, ListVerifiedEmailAddressesError> {
+        (**self).list_verified_email_addresses()
+    }
+    fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> RusotoFuture<
+        PutConfigurationSetDeliveryOptionsResponse,
+        PutConfigurationSetDeliveryOptionsError,
+    > {
+        (**self).put_configuration_set_delivery_options(input)
+    }
+    fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> RusotoFuture<PutIdentityPolicyResponse, PutIdentityPolicyError> {
+        (**self).put_identity_policy(input)
+    }
+    fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> RusotoFuture<ReorderReceiptRuleSetResponse, ReorderReceiptRuleSetError> {
+        (**self).reorder_receipt_rule_set(input)
+    }
+    fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> RusotoFuture<SendBounceResponse, SendBounceError> {
+        (**self).send_bounce(input)
+    }
+    fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> RusotoFuture<SendBulkTemplatedEmailResponse, SendBulkTemplatedEmailError> {
+        (**self).send_bulk_templated_email(input)
+    }
+    fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> RusotoFuture<SendCustomVerificationEmailResponse, SendCustomVerificationEmailError> {
+        (**self).send_custom_verification_email(input)
+    }
+    fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> RusotoFuture<SendEmailResponse, SendEmailError> {
+        (**self).send_email(input)
+    }
+    fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> RusotoFuture<SendRawEmailResponse, SendRawEmailError> {
+        (**self).send_raw_email(input)
+    }
+    fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> RusotoFuture<SendTemplatedEmailResponse, SendTemplatedEmailError> {
+        (**self).send_templated_email(input)
+    }
+    fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<SetActiveReceiptRuleSetResponse, SetActiveReceiptRuleSetError> {
+        (**self).set_active_receipt_rule_set(input)
+    }
+    fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> RusotoFuture<SetIdentityDkimEnabledResponse, SetIdentityDkimEnabledError> {
+        (**self).set_identity_dkim_enabled(input)
+    }
+    fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        SetIdentityFeedbackForwardingEnabledError,
+    > {
+        (**self).set_identity_feedback_forwarding_enabled(input)
+    }
+    fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        SetIdentityHeadersInNotificationsEnabledError,
+    > {
+        (**self).set_identity_headers_in_notifications_enabled(input)
+    }
+    fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> RusotoFuture<SetIdentityMailFromDomainResponse, SetIdentityMailFromDomainError> {
+        (**self).set_identity_mail_from_domain(input)
+    }
+    fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> RusotoFuture<SetIdentityNotificationTopicResponse, SetIdentityNotificationTopicError> {
+        (**self).set_identity_notification_topic(input)
+    }
+    fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> RusotoFuture<SetReceiptRulePositionResponse, SetReceiptRulePositionError> {
+        (**self).set_receipt_rule_position(input)
+    }
+    fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> RusotoFuture<TestRenderTemplateResponse, TestRenderTemplateError> {
+        (**self).test_render_template(input)
+    }
+    fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateAccountSendingEnabledError> {
+        (**self).update_account_sending_enabled(input)
+    }
+    fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetEventDestinationResponse,
+        UpdateConfigurationSetEventDestinationError,
+    > {
+        (**self).update_configuration_set_event_destination(input)
+    }
+    fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetReputationMetricsEnabledError> {
+        (**self).update_configuration_set_reputation_metrics_enabled(input)
+    }
+    fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetSendingEnabledError> {
+        (**self).update_configuration_set_sending_enabled(input)
+    }
+    fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        UpdateConfigurationSetTrackingOptionsError,
+    > {
+        (**self).update_configuration_set_tracking_options(input)
+    }
+    fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), UpdateCustomVerificationEmailTemplateError> {
+        (**self).update_custom_verification_email_template(input)
+    }
+    fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> RusotoFuture<UpdateReceiptRuleResponse, UpdateReceiptRuleError> {
+        (**self).update_receipt_rule(input)
+    }
+    fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> RusotoFuture<UpdateTemplateResponse, UpdateTemplateError> {
+        (**self).update_template(input)
+    }
+    fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> RusotoFuture<VerifyDomainDkimResponse, VerifyDomainDkimError> {
+        (**self).verify_domain_dkim(input)
+    }
+    fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> RusotoFuture<VerifyDomainIdentityResponse, VerifyDomainIdentityError> {
+        (**self).verify_domain_identity(input)
+    }
+    fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> RusotoFuture<(), VerifyEmailAddressError> {
+        (**self).verify_email_address(input)
+    }
+    fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError> {
+        (**self).verify_email_identity(input)
+    }
+}
+/// A client for the Amazon SES API.
+#[derive(Clone)]
+pub struct SesClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SesClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SesClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SesClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SesClient {
+        SesClient { client, region }
+    }
+}
+
+impl Ses for SesClient {
+    /// <p>Creates a receipt rule set by cloning an existing one. All receipt rules and configurations are copied to the new receipt rule set and are completely independent of the source rule set.</p> <p>For information about setting up rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> RusotoFuture<CloneReceiptRuleSetResponse, CloneReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CloneReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "CloneReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        CloneReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CloneReceiptRuleSetError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CloneReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CloneReceiptRuleSetResponseDeserializer::deserialize(
+                        "CloneReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CloneReceiptRuleSet", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a configuration set.</p> <p>Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> RusotoFuture<CreateConfigurationSetResponse, CreateConfigurationSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSet");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateConfigurationSet");
+        params.put("Version", "2010-12-01");
+        CreateConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConfigurationSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateConfigurationSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateConfigurationSetResponseDeserializer::deserialize(
+                        "CreateConfigurationSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateConfigurationSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a configuration set event destination.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>An event destination is the AWS service to which Amazon SES publishes the email sending events associated with a configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetEventDestinationResponse,
+        CreateConfigurationSetEventDestinationError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetEventDestination");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateConfigurationSetEventDestination");
+        params.put("Version", "2010-12-01");
+        CreateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConfigurationSetEventDestinationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateConfigurationSetEventDestinationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        CreateConfigurationSetEventDestinationResponseDeserializer::deserialize(
+                            "CreateConfigurationSetEventDestinationResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "CreateConfigurationSetEventDestination",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
+    fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetTrackingOptionsResponse,
+        CreateConfigurationSetTrackingOptionsError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateConfigurationSetTrackingOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateConfigurationSetTrackingOptions");
+        params.put("Version", "2010-12-01");
+        CreateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateConfigurationSetTrackingOptionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateConfigurationSetTrackingOptionsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        CreateConfigurationSetTrackingOptionsResponseDeserializer::deserialize(
+                            "CreateConfigurationSetTrackingOptionsResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "CreateConfigurationSetTrackingOptions",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a new custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), CreateCustomVerificationEmailTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateCustomVerificationEmailTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateCustomVerificationEmailTemplate");
+        params.put("Version", "2010-12-01");
+        CreateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCustomVerificationEmailTemplateError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Creates a new IP address filter.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> RusotoFuture<CreateReceiptFilterResponse, CreateReceiptFilterError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptFilter");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateReceiptFilter");
+        params.put("Version", "2010-12-01");
+        CreateReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateReceiptFilterError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateReceiptFilterResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateReceiptFilterResponseDeserializer::deserialize(
+                        "CreateReceiptFilterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateReceiptFilter", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> RusotoFuture<CreateReceiptRuleResponse, CreateReceiptRuleError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRule");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateReceiptRule");
+        params.put("Version", "2010-12-01");
+        CreateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateReceiptRuleError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateReceiptRuleResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateReceiptRuleResponseDeserializer::deserialize(
+                        "CreateReceiptRuleResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateReceiptRule", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an empty receipt rule set.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> RusotoFuture<CreateReceiptRuleSetResponse, CreateReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        CreateReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateReceiptRuleSetError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateReceiptRuleSetResponseDeserializer::deserialize(
+                        "CreateReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreateReceiptRuleSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> RusotoFuture<CreateTemplateResponse, CreateTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("CreateTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateTemplate");
+        params.put("Version", "2010-12-01");
+        CreateTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateTemplateResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateTemplateResponseDeserializer::deserialize(
+                        "CreateTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateTemplate", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> RusotoFuture<DeleteConfigurationSetResponse, DeleteConfigurationSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteConfigurationSet");
+        params.put("Version", "2010-12-01");
+        DeleteConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConfigurationSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteConfigurationSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteConfigurationSetResponseDeserializer::deserialize(
+                        "DeleteConfigurationSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteConfigurationSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a configuration set event destination. Configuration set event destinations are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetEventDestinationResponse,
+        DeleteConfigurationSetEventDestinationError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetEventDestination");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteConfigurationSetEventDestination");
+        params.put("Version", "2010-12-01");
+        DeleteConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConfigurationSetEventDestinationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteConfigurationSetEventDestinationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        DeleteConfigurationSetEventDestinationResponseDeserializer::deserialize(
+                            "DeleteConfigurationSetEventDestinationResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "DeleteConfigurationSetEventDestination",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Deletes an association between a configuration set and a custom domain for open and click event tracking.</p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p> <note> <p>Deleting this kind of association will result in emails sent using the specified configuration set to capture open and click events using the standard, Amazon SES-operated domains.</p> </note></p>
+    fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        DeleteConfigurationSetTrackingOptionsError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteConfigurationSetTrackingOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteConfigurationSetTrackingOptions");
+        params.put("Version", "2010-12-01");
+        DeleteConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteConfigurationSetTrackingOptionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteConfigurationSetTrackingOptionsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        DeleteConfigurationSetTrackingOptionsResponseDeserializer::deserialize(
+                            "DeleteConfigurationSetTrackingOptionsResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "DeleteConfigurationSetTrackingOptions",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes an existing custom verification email template. </p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), DeleteCustomVerificationEmailTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteCustomVerificationEmailTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteCustomVerificationEmailTemplate");
+        params.put("Version", "2010-12-01");
+        DeleteCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteCustomVerificationEmailTemplateError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes the specified identity (an email address or a domain) from the list of verified identities.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> RusotoFuture<DeleteIdentityResponse, DeleteIdentityError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentity");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteIdentity");
+        params.put("Version", "2010-12-01");
+        DeleteIdentityRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIdentityError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteIdentityResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteIdentityResponseDeserializer::deserialize(
+                        "DeleteIdentityResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteIdentity", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified sending authorization policy for the given identity (an email address or a domain). This API returns successfully even if a policy with the specified name does not exist.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> RusotoFuture<DeleteIdentityPolicyResponse, DeleteIdentityPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteIdentityPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteIdentityPolicy");
+        params.put("Version", "2010-12-01");
+        DeleteIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteIdentityPolicyError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteIdentityPolicyResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteIdentityPolicyResponseDeserializer::deserialize(
+                        "DeleteIdentityPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteIdentityPolicy", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified IP address filter.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> RusotoFuture<DeleteReceiptFilterResponse, DeleteReceiptFilterError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptFilter");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteReceiptFilter");
+        params.put("Version", "2010-12-01");
+        DeleteReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteReceiptFilterError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteReceiptFilterResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteReceiptFilterResponseDeserializer::deserialize(
+                        "DeleteReceiptFilterResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteReceiptFilter", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleResponse, DeleteReceiptRuleError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRule");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteReceiptRule");
+        params.put("Version", "2010-12-01");
+        DeleteReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteReceiptRuleError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteReceiptRuleResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteReceiptRuleResponseDeserializer::deserialize(
+                        "DeleteReceiptRuleResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteReceiptRule", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the specified receipt rule set and all of the receipt rules it contains.</p> <note> <p>The currently active rule set cannot be deleted.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleSetResponse, DeleteReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        DeleteReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteReceiptRuleSetError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteReceiptRuleSetResponseDeserializer::deserialize(
+                        "DeleteReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DeleteReceiptRuleSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes an email template.</p> <p>You can execute this operation no more than once per second.</p>
+    fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> RusotoFuture<DeleteTemplateResponse, DeleteTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteTemplate");
+        params.put("Version", "2010-12-01");
+        DeleteTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DeleteTemplateResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DeleteTemplateResponseDeserializer::deserialize(
+                        "DeleteTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DeleteTemplate", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deprecated. Use the <code>DeleteIdentity</code> operation to delete email addresses and domains.</p>
+    fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> RusotoFuture<(), DeleteVerifiedEmailAddressError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DeleteVerifiedEmailAddress");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteVerifiedEmailAddress");
+        params.put("Version", "2010-12-01");
+        DeleteVerifiedEmailAddressRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteVerifiedEmailAddressError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Returns the metadata and receipt rules for the receipt rule set that is currently active.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeActiveReceiptRuleSetResponse, DescribeActiveReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeActiveReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeActiveReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        DescribeActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeActiveReceiptRuleSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeActiveReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeActiveReceiptRuleSetResponseDeserializer::deserialize(
+                        "DescribeActiveReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeActiveReceiptRuleSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the details of the specified configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> RusotoFuture<DescribeConfigurationSetResponse, DescribeConfigurationSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeConfigurationSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeConfigurationSet");
+        params.put("Version", "2010-12-01");
+        DescribeConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeConfigurationSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeConfigurationSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeConfigurationSetResponseDeserializer::deserialize(
+                        "DescribeConfigurationSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeConfigurationSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the details of the specified receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleResponse, DescribeReceiptRuleError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRule");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReceiptRule");
+        params.put("Version", "2010-12-01");
+        DescribeReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeReceiptRuleError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeReceiptRuleResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeReceiptRuleResponseDeserializer::deserialize(
+                        "DescribeReceiptRuleResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("DescribeReceiptRule", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the details of the specified receipt rule set.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleSetResponse, DescribeReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("DescribeReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        DescribeReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeReceiptRuleSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = DescribeReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = DescribeReceiptRuleSetResponseDeserializer::deserialize(
+                        "DescribeReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DescribeReceiptRuleSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the email sending status of the Amazon SES account for the current region.</p> <p>You can execute this operation no more than once per second.</p>
+    fn get_account_sending_enabled(
+        &self,
+    ) -> RusotoFuture<GetAccountSendingEnabledResponse, GetAccountSendingEnabledError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetAccountSendingEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "GetAccountSendingEnabled");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAccountSendingEnabledError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetAccountSendingEnabledResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetAccountSendingEnabledResponseDeserializer::deserialize(
+                        "GetAccountSendingEnabledResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetAccountSendingEnabled", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the custom email verification template for the template name you specify.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<
+        GetCustomVerificationEmailTemplateResponse,
+        GetCustomVerificationEmailTemplateError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetCustomVerificationEmailTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "GetCustomVerificationEmailTemplate");
+        params.put("Version", "2010-12-01");
+        GetCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetCustomVerificationEmailTemplateError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetCustomVerificationEmailTemplateResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetCustomVerificationEmailTemplateResponseDeserializer::deserialize(
+                        "GetCustomVerificationEmailTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetCustomVerificationEmailTemplate", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the current status of Easy DKIM signing for an entity. For domain name identities, this operation also returns the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES has successfully verified that these tokens have been published.</p> <p>This operation takes a list of identities as input and returns the following information for each:</p> <ul> <li> <p>Whether Easy DKIM signing is enabled or disabled.</p> </li> <li> <p>A set of DKIM tokens that represent the identity. If the identity is an email address, the tokens represent the domain of that address.</p> </li> <li> <p>Whether Amazon SES has successfully verified the DKIM tokens published in the domain's DNS. This information is only returned for domain name identities, not for email addresses.</p> </li> </ul> <p>This operation is throttled at one request per second and can only get DKIM attributes for up to 100 identities at a time.</p> <p>For more information about creating DNS records using DKIM tokens, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim-dns-records.html">Amazon SES Developer Guide</a>.</p>
+    fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> RusotoFuture<GetIdentityDkimAttributesResponse, GetIdentityDkimAttributesError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityDkimAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetIdentityDkimAttributes");
+        params.put("Version", "2010-12-01");
+        GetIdentityDkimAttributesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetIdentityDkimAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetIdentityDkimAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetIdentityDkimAttributesResponseDeserializer::deserialize(
+                        "GetIdentityDkimAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetIdentityDkimAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the custom MAIL FROM attributes for a list of identities (email addresses : domains).</p> <p>This operation is throttled at one request per second and can only get custom MAIL FROM attributes for up to 100 identities at a time.</p>
+    fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityMailFromDomainAttributesResponse,
+        GetIdentityMailFromDomainAttributesError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityMailFromDomainAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetIdentityMailFromDomainAttributes");
+        params.put("Version", "2010-12-01");
+        GetIdentityMailFromDomainAttributesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetIdentityMailFromDomainAttributesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetIdentityMailFromDomainAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetIdentityMailFromDomainAttributesResponseDeserializer::deserialize(
+                        "GetIdentityMailFromDomainAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "GetIdentityMailFromDomainAttributes",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Given a list of verified identities (email addresses and/or domains), returns a structure describing identity notification attributes.</p> <p>This operation is throttled at one request per second and can only get notification attributes for up to 100 identities at a time.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityNotificationAttributesResponse,
+        GetIdentityNotificationAttributesError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityNotificationAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetIdentityNotificationAttributes");
+        params.put("Version", "2010-12-01");
+        GetIdentityNotificationAttributesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetIdentityNotificationAttributesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetIdentityNotificationAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetIdentityNotificationAttributesResponseDeserializer::deserialize(
+                        "GetIdentityNotificationAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetIdentityNotificationAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the requested sending authorization policies for the given identity (an email address or a domain). The policies are returned as a map of policy names to policy contents. You can retrieve a maximum of 20 policies at a time.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> RusotoFuture<GetIdentityPoliciesResponse, GetIdentityPoliciesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityPolicies");
+        let mut params = Params::new();
+
+        params.put("Action", "GetIdentityPolicies");
+        params.put("Version", "2010-12-01");
+        GetIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetIdentityPoliciesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetIdentityPoliciesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetIdentityPoliciesResponseDeserializer::deserialize(
+                        "GetIdentityPoliciesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetIdentityPolicies", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Given a list of identities (email addresses and/or domains), returns the verification status and (for domain identities) the verification token for each identity.</p> <p>The verification status of an email address is "Pending" until the email address owner clicks the link within the verification email that Amazon SES sent to that address. If the email address owner clicks the link within 24 hours, the verification status of the email address changes to "Success". If the link is not clicked within 24 hours, the verification status changes to "Failed." In that case, if you still want to verify the email address, you must restart the verification process from the beginning.</p> <p>For domain identities, the domain's verification status is "Pending" as Amazon SES searches for the required TXT record in the DNS settings of the domain. When Amazon SES detects the record, the domain's verification status changes to "Success". If Amazon SES is unable to detect the record within 72 hours, the domain's verification status changes to "Failed." In that case, if you still want to verify the domain, you must restart the verification process from the beginning.</p> <p>This operation is throttled at one request per second and can only get verification attributes for up to 100 identities at a time.</p>
+    fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityVerificationAttributesResponse,
+        GetIdentityVerificationAttributesError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetIdentityVerificationAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetIdentityVerificationAttributes");
+        params.put("Version", "2010-12-01");
+        GetIdentityVerificationAttributesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetIdentityVerificationAttributesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetIdentityVerificationAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetIdentityVerificationAttributesResponseDeserializer::deserialize(
+                        "GetIdentityVerificationAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetIdentityVerificationAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
+    fn get_send_quota(&self) -> RusotoFuture<GetSendQuotaResponse, GetSendQuotaError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendQuota");
+        let mut params = Params::new();
+
+        params.put("Action", "GetSendQuota");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSendQuotaError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetSendQuotaResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetSendQuotaResponseDeserializer::deserialize(
+                        "GetSendQuotaResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetSendQuota", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Provides sending statistics for the current AWS Region. The result is a list of data points, representing the last two weeks of sending activity. Each data point in the list contains statistics for a 15-minute period of time.</p> <p>You can execute this operation no more than once per second.</p>
+    fn get_send_statistics(
+        &self,
+    ) -> RusotoFuture<GetSendStatisticsResponse, GetSendStatisticsError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetSendStatistics");
+        let mut params = Params::new();
+
+        params.put("Action", "GetSendStatistics");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSendStatisticsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetSendStatisticsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetSendStatisticsResponseDeserializer::deserialize(
+                        "GetSendStatisticsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetSendStatistics", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Displays the template object (which includes the Subject line, HTML part and text part) for the template you specify.</p> <p>You can execute this operation no more than once per second.</p>
+    fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> RusotoFuture<GetTemplateResponse, GetTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("GetTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "GetTemplate");
+        params.put("Version", "2010-12-01");
+        GetTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetTemplateResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetTemplateResponseDeserializer::deserialize(
+                        "GetTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetTemplate", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Provides a list of the configuration sets associated with your Amazon SES account in the current AWS Region. For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second. This operation will return up to 1,000 configuration sets each time it is run. If your Amazon SES account has more than 1,000 configuration sets, this operation will also return a NextToken element. You can then execute the <code>ListConfigurationSets</code> operation again, passing the <code>NextToken</code> parameter and the value of the NextToken element to retrieve additional results.</p>
+    fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> RusotoFuture<ListConfigurationSetsResponse, ListConfigurationSetsError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListConfigurationSets");
+        let mut params = Params::new();
+
+        params.put("Action", "ListConfigurationSets");
+        params.put("Version", "2010-12-01");
+        ListConfigurationSetsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListConfigurationSetsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListConfigurationSetsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListConfigurationSetsResponseDeserializer::deserialize(
+                        "ListConfigurationSetsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListConfigurationSets", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the existing custom verification email templates for your account in the current AWS Region.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> RusotoFuture<
+        ListCustomVerificationEmailTemplatesResponse,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListCustomVerificationEmailTemplates");
+        let mut params = Params::new();
+
+        params.put("Action", "ListCustomVerificationEmailTemplates");
+        params.put("Version", "2010-12-01");
+        ListCustomVerificationEmailTemplatesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListCustomVerificationEmailTemplatesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListCustomVerificationEmailTemplatesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListCustomVerificationEmailTemplatesResponseDeserializer::deserialize(
+                        "ListCustomVerificationEmailTemplatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "ListCustomVerificationEmailTemplates",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list containing all of the identities (email addresses and domains) for your AWS account in the current AWS Region, regardless of verification status.</p> <p>You can execute this operation no more than once per second.</p>
+    fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentities");
+        let mut params = Params::new();
+
+        params.put("Action", "ListIdentities");
+        params.put("Version", "2010-12-01");
+        ListIdentitiesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListIdentitiesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListIdentitiesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListIdentitiesResponseDeserializer::deserialize(
+                        "ListIdentitiesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListIdentities", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of sending authorization policies that are attached to the given identity (an email address or a domain). This API returns only a list. If you want the actual policy content, you can use <code>GetIdentityPolicies</code>.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> RusotoFuture<ListIdentityPoliciesResponse, ListIdentityPoliciesError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListIdentityPolicies");
+        let mut params = Params::new();
+
+        params.put("Action", "ListIdentityPolicies");
+        params.put("Version", "2010-12-01");
+        ListIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListIdentityPoliciesError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListIdentityPoliciesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListIdentityPoliciesResponseDeserializer::deserialize(
+                        "ListIdentityPoliciesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListIdentityPolicies", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the IP address filters associated with your AWS account in the current AWS Region.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> RusotoFuture<ListReceiptFiltersResponse, ListReceiptFiltersError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptFilters");
+        let mut params = Params::new();
+
+        params.put("Action", "ListReceiptFilters");
+        params.put("Version", "2010-12-01");
+        ListReceiptFiltersRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListReceiptFiltersError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListReceiptFiltersResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListReceiptFiltersResponseDeserializer::deserialize(
+                        "ListReceiptFiltersResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListReceiptFilters", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the receipt rule sets that exist under your AWS account in the current AWS Region. If there are additional receipt rule sets to be retrieved, you will receive a <code>NextToken</code> that you can provide to the next call to <code>ListReceiptRuleSets</code> to retrieve the additional entries.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> RusotoFuture<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListReceiptRuleSets");
+        let mut params = Params::new();
+
+        params.put("Action", "ListReceiptRuleSets");
+        params.put("Version", "2010-12-01");
+        ListReceiptRuleSetsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListReceiptRuleSetsError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListReceiptRuleSetsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListReceiptRuleSetsResponseDeserializer::deserialize(
+                        "ListReceiptRuleSetsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListReceiptRuleSets", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the email templates present in your Amazon SES account in the current AWS Region.</p> <p>You can execute this operation no more than once per second.</p>
+    fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> RusotoFuture<ListTemplatesResponse, ListTemplatesError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListTemplates");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTemplates");
+        params.put("Version", "2010-12-01");
+        ListTemplatesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTemplatesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListTemplatesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListTemplatesResponseDeserializer::deserialize(
+                        "ListTemplatesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTemplates", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deprecated. Use the <code>ListIdentities</code> operation to list the email addresses and domains associated with your account.</p>
+    fn list_verified_email_addresses(
+        &self,
+    ) -> RusotoFuture<ListVerifiedEmailAddressesResponse, ListVerifiedEmailAddressesError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ListVerifiedEmailAddresses");
+        let mut params = Params::new();
+
+        params.put("Action", "ListVerifiedEmailAddresses");
+        params.put("Version", "2010-12-01");
+
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListVerifiedEmailAddressesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListVerifiedEmailAddressesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListVerifiedEmailAddressesResponseDeserializer::deserialize(
+                        "ListVerifiedEmailAddressesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListVerifiedEmailAddresses", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds or updates the delivery options for a configuration set.</p>
+    fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> RusotoFuture<
+        PutConfigurationSetDeliveryOptionsResponse,
+        PutConfigurationSetDeliveryOptionsError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutConfigurationSetDeliveryOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "PutConfigurationSetDeliveryOptions");
+        params.put("Version", "2010-12-01");
+        PutConfigurationSetDeliveryOptionsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutConfigurationSetDeliveryOptionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PutConfigurationSetDeliveryOptionsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PutConfigurationSetDeliveryOptionsResponseDeserializer::deserialize(
+                        "PutConfigurationSetDeliveryOptionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("PutConfigurationSetDeliveryOptions", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds or updates a sending authorization policy for the specified identity (an email address or a domain).</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> RusotoFuture<PutIdentityPolicyResponse, PutIdentityPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("PutIdentityPolicy");
+        let mut params = Params::new();
+
+        params.put("Action", "PutIdentityPolicy");
+        params.put("Version", "2010-12-01");
+        PutIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutIdentityPolicyError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PutIdentityPolicyResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PutIdentityPolicyResponseDeserializer::deserialize(
+                        "PutIdentityPolicyResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("PutIdentityPolicy", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Reorders the receipt rules within a receipt rule set.</p> <note> <p>All of the rules in the rule set must be represented in this request. That is, this API will return an error if the reorder request doesn't explicitly position all of the rules.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> RusotoFuture<ReorderReceiptRuleSetResponse, ReorderReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("ReorderReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "ReorderReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        ReorderReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ReorderReceiptRuleSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ReorderReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ReorderReceiptRuleSetResponseDeserializer::deserialize(
+                        "ReorderReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ReorderReceiptRuleSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Generates and sends a bounce message to the sender of an email you received through Amazon SES. You can only use this API on an email up to 24 hours after you receive it.</p> <note> <p>You cannot use this API to send generic bounces for mail that was not received by Amazon SES.</p> </note> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> RusotoFuture<SendBounceResponse, SendBounceError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBounce");
+        let mut params = Params::new();
+
+        params.put("Action", "SendBounce");
+        params.put("Version", "2010-12-01");
+        SendBounceRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendBounceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SendBounceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        SendBounceResponseDeserializer::deserialize("SendBounceResult", &mut stack)
+                            .map_err(|err| stack.parse_error("SendBounce", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Composes an email message to multiple destinations. The message body is created using an email template.</p> <p>In order to send email using the <code>SendBulkTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Each <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendBulkTemplatedEmail</code> operation several times to send the message to each group.</p> </li> <li> <p>The number of destinations you can contact in a single call to the API may be limited by your account&#39;s maximum sending rate.</p> </li> </ul></p>
+    fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> RusotoFuture<SendBulkTemplatedEmailResponse, SendBulkTemplatedEmailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendBulkTemplatedEmail");
+        let mut params = Params::new();
+
+        params.put("Action", "SendBulkTemplatedEmail");
+        params.put("Version", "2010-12-01");
+        SendBulkTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SendBulkTemplatedEmailError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SendBulkTemplatedEmailResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SendBulkTemplatedEmailResponseDeserializer::deserialize(
+                        "SendBulkTemplatedEmailResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SendBulkTemplatedEmail", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. As a result of executing this operation, a customized verification email is sent to the specified address.</p> <p>To use this operation, you must first create a custom verification email template. For more information about creating and using custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> RusotoFuture<SendCustomVerificationEmailResponse, SendCustomVerificationEmailError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendCustomVerificationEmail");
+        let mut params = Params::new();
+
+        params.put("Action", "SendCustomVerificationEmail");
+        params.put("Version", "2010-12-01");
+        SendCustomVerificationEmailRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SendCustomVerificationEmailError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SendCustomVerificationEmailResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SendCustomVerificationEmailResponseDeserializer::deserialize(
+                        "SendCustomVerificationEmailResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SendCustomVerificationEmail", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Composes an email message and immediately queues it for sending. In order to send email using the <code>SendEmail</code> operation, your message must meet the following requirements:</p> <ul> <li> <p>The message must be sent from a verified email address or domain. If you attempt to send email using a non-verified address or domain, the operation will result in an &quot;Email address not verified&quot; error. </p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>The message must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendEmail</code> operation several times to send the message to each group.</p> </li> </ul> <important> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </important></p>
+    fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> RusotoFuture<SendEmailResponse, SendEmailError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendEmail");
+        let mut params = Params::new();
+
+        params.put("Action", "SendEmail");
+        params.put("Version", "2010-12-01");
+        SendEmailRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendEmailError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SendEmailResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        SendEmailResponseDeserializer::deserialize("SendEmailResult", &mut stack)
+                            .map_err(|err| stack.parse_error("SendEmail", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Composes an email message and immediately queues it for sending.</p> <p>This operation is more flexible than the <code>SendEmail</code> API operation. When you use the <code>SendRawEmail</code> operation, you can specify the headers of the message as well as its content. This flexibility is useful, for example, when you want to send a multipart MIME email (such a message that contains both a text and an HTML version). You can also use this operation to send messages that include attachments.</p> <p>The <code>SendRawEmail</code> operation has the following requirements:</p> <ul> <li> <p>You can only send email from <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">verified email addresses or domains</a>. If you try to send email from an address that isn&#39;t verified, the operation results in an &quot;Email address not verified&quot; error.</p> </li> <li> <p>If your account is still in the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/request-production-access.html">Amazon SES sandbox</a>, you can only send email to other verified addresses in your account, or to addresses that are associated with the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mailbox-simulator.html">Amazon SES mailbox simulator</a>.</p> </li> <li> <p>The maximum message size, including attachments, is 10 MB.</p> </li> <li> <p>Each message has to include at least one recipient address. A recipient address includes any address on the To:, CC:, or BCC: lines.</p> </li> <li> <p>If you send a single message to more than one recipient address, and one of the recipient addresses isn&#39;t in a valid format (that is, it&#39;s not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), Amazon SES rejects the entire message, even if the other addresses are valid.</p> </li> <li> <p>Each message can include up to 50 recipient addresses across the To:, CC:, or BCC: lines. If you need to send a single message to more than 50 recipients, you have to split the list of recipient addresses into groups of less than 50 recipients, and send separate messages to each group.</p> </li> <li> <p>Amazon SES allows you to specify 8-bit Content-Transfer-Encoding for MIME message parts. However, if Amazon SES has to modify the contents of your message (for example, if you use open and click tracking), 8-bit content isn&#39;t preserved. For this reason, we highly recommend that you encode all content that isn&#39;t 7-bit ASCII. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html#send-email-mime-encoding">MIME Encoding</a> in the <i>Amazon SES Developer Guide</i>.</p> </li> </ul> <p>Additionally, keep the following considerations in mind when using the <code>SendRawEmail</code> operation:</p> <ul> <li> <p>Although you can customize the message headers when using the <code>SendRawEmail</code> operation, Amazon SES will automatically apply its own <code>Message-ID</code> and <code>Date</code> headers; if you passed these headers when creating the message, they will be overwritten by the values that Amazon SES provides.</p> </li> <li> <p>If you are using sending authorization to send on behalf of another user, <code>SendRawEmail</code> enables you to specify the cross-account identity for the email&#39;s Source, From, and Return-Path parameters in one of two ways: you can pass optional parameters <code>SourceArn</code>, <code>FromArn</code>, and/or <code>ReturnPathArn</code> to the API, or you can include the following X-headers in the header of your raw email:</p> <ul> <li> <p> <code>X-SES-SOURCE-ARN</code> </p> </li> <li> <p> <code>X-SES-FROM-ARN</code> </p> </li> <li> <p> <code>X-SES-RETURN-PATH-ARN</code> </p> </li> </ul> <important> <p>Don&#39;t include these X-headers in the DKIM signature. Amazon SES removes these before it sends the email.</p> </important> <p>If you only specify the <code>SourceIdentityArn</code> parameter, Amazon SES sets the From and Return-Path addresses to the same identity that you specified.</p> <p>For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Using Sending Authorization with Amazon SES</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> </ul></p>
+    fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> RusotoFuture<SendRawEmailResponse, SendRawEmailError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendRawEmail");
+        let mut params = Params::new();
+
+        params.put("Action", "SendRawEmail");
+        params.put("Version", "2010-12-01");
+        SendRawEmailRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendRawEmailError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SendRawEmailResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SendRawEmailResponseDeserializer::deserialize(
+                        "SendRawEmailResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SendRawEmail", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p><p>Composes an email message using an email template and immediately queues it for sending.</p> <p>In order to send email using the <code>SendTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Calls to the <code>SendTemplatedEmail</code> operation may only include one <code>Destination</code> parameter. A destination is a set of recipients who will receive the same version of the email. The <code>Destination</code> parameter can include up to 50 recipients, across the To:, CC: and BCC: fields.</p> </li> <li> <p>The <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> </ul> <important> <p>If your call to the <code>SendTemplatedEmail</code> operation includes all of the required parameters, Amazon SES accepts it and returns a Message ID. However, if Amazon SES can&#39;t render the email because the template contains errors, it doesn&#39;t send the email. Additionally, because it already accepted the message, Amazon SES doesn&#39;t return a message stating that it was unable to send the email.</p> <p>For these reasons, we highly recommend that you set up Amazon SES to send you notifications when Rendering Failure events occur. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Sending Personalized Email Using the Amazon SES API</a> in the <i>Amazon Simple Email Service Developer Guide</i>.</p> </important></p>
+    fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> RusotoFuture<SendTemplatedEmailResponse, SendTemplatedEmailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SendTemplatedEmail");
+        let mut params = Params::new();
+
+        params.put("Action", "SendTemplatedEmail");
+        params.put("Version", "2010-12-01");
+        SendTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendTemplatedEmailError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SendTemplatedEmailResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SendTemplatedEmailResponseDeserializer::deserialize(
+                        "SendTemplatedEmailResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SendTemplatedEmail", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets the specified receipt rule set as the active receipt rule set.</p> <note> <p>To disable your email-receiving through Amazon SES completely, you can call this API with RuleSetName set to null.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<SetActiveReceiptRuleSetResponse, SetActiveReceiptRuleSetError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetActiveReceiptRuleSet");
+        let mut params = Params::new();
+
+        params.put("Action", "SetActiveReceiptRuleSet");
+        params.put("Version", "2010-12-01");
+        SetActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetActiveReceiptRuleSetError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetActiveReceiptRuleSetResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetActiveReceiptRuleSetResponseDeserializer::deserialize(
+                        "SetActiveReceiptRuleSetResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetActiveReceiptRuleSet", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Enables or disables Easy DKIM signing of email sent from an identity. If Easy DKIM signing is enabled for a domain, then Amazon SES uses DKIM to sign all email that it sends from addresses on that domain. If Easy DKIM signing is enabled for an email address, then Amazon SES uses DKIM to sign all email it sends from that address.</p> <note> <p>For email addresses (for example, <code>user@example.com</code>), you can only enable DKIM signing if the corresponding domain (in this case, <code>example.com</code>) has been set up to use Easy DKIM.</p> </note> <p>You can enable DKIM signing for an identity at any time after you start the verification process for the identity, even if the verification process isn't complete. </p> <p>You can execute this operation no more than once per second.</p> <p>For more information about Easy DKIM signing, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
+    fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> RusotoFuture<SetIdentityDkimEnabledResponse, SetIdentityDkimEnabledError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityDkimEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "SetIdentityDkimEnabled");
+        params.put("Version", "2010-12-01");
+        SetIdentityDkimEnabledRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetIdentityDkimEnabledError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetIdentityDkimEnabledResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetIdentityDkimEnabledResponseDeserializer::deserialize(
+                        "SetIdentityDkimEnabledResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetIdentityDkimEnabled", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Given an identity (an email address or a domain), enables or disables whether Amazon SES forwards bounce and complaint notifications as email. Feedback forwarding can only be disabled when Amazon Simple Notification Service (Amazon SNS) topics are specified for both bounces and complaints.</p> <note> <p>Feedback forwarding does not apply to delivery notifications. Delivery notifications are only available through Amazon SNS.</p> </note> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        SetIdentityFeedbackForwardingEnabledError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityFeedbackForwardingEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "SetIdentityFeedbackForwardingEnabled");
+        params.put("Version", "2010-12-01");
+        SetIdentityFeedbackForwardingEnabledRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetIdentityFeedbackForwardingEnabledError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetIdentityFeedbackForwardingEnabledResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetIdentityFeedbackForwardingEnabledResponseDeserializer::deserialize(
+                        "SetIdentityFeedbackForwardingEnabledResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error(
+                            "SetIdentityFeedbackForwardingEnabled",
+                            err,
+                            &response.body,
+                        )
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Given an identity (an email address or a domain), sets whether Amazon SES includes the original email headers in the Amazon Simple Notification Service (Amazon SNS) notifications of a specified type.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        SetIdentityHeadersInNotificationsEnabledError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityHeadersInNotificationsEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "SetIdentityHeadersInNotificationsEnabled");
+        params.put("Version", "2010-12-01");
+        SetIdentityHeadersInNotificationsEnabledRequestSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetIdentityHeadersInNotificationsEnabledError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetIdentityHeadersInNotificationsEnabledResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        SetIdentityHeadersInNotificationsEnabledResponseDeserializer::deserialize(
+                            "SetIdentityHeadersInNotificationsEnabledResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "SetIdentityHeadersInNotificationsEnabled",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Enables or disables the custom MAIL FROM domain setup for a verified identity (an email address or a domain).</p> <important> <p>To send emails using the specified MAIL FROM domain, you must add an MX record to your MAIL FROM domain's DNS settings. If you want your emails to pass Sender Policy Framework (SPF) checks, you must also add or update an SPF record. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-set.html">Amazon SES Developer Guide</a>.</p> </important> <p>You can execute this operation no more than once per second.</p>
+    fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> RusotoFuture<SetIdentityMailFromDomainResponse, SetIdentityMailFromDomainError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityMailFromDomain");
+        let mut params = Params::new();
+
+        params.put("Action", "SetIdentityMailFromDomain");
+        params.put("Version", "2010-12-01");
+        SetIdentityMailFromDomainRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetIdentityMailFromDomainError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetIdentityMailFromDomainResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetIdentityMailFromDomainResponseDeserializer::deserialize(
+                        "SetIdentityMailFromDomainResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetIdentityMailFromDomain", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets an Amazon Simple Notification Service (Amazon SNS) topic to use when delivering notifications. When you use this operation, you specify a verified identity, such as an email address or domain. When you send an email that uses the chosen identity in the Source field, Amazon SES sends notifications to the topic you specified. You can send bounce, complaint, or delivery notifications (or any combination of the three) to the Amazon SNS topic that you specify.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about feedback notification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> RusotoFuture<SetIdentityNotificationTopicResponse, SetIdentityNotificationTopicError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetIdentityNotificationTopic");
+        let mut params = Params::new();
+
+        params.put("Action", "SetIdentityNotificationTopic");
+        params.put("Version", "2010-12-01");
+        SetIdentityNotificationTopicRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetIdentityNotificationTopicError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetIdentityNotificationTopicResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetIdentityNotificationTopicResponseDeserializer::deserialize(
+                        "SetIdentityNotificationTopicResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetIdentityNotificationTopic", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sets the position of the specified receipt rule in the receipt rule set.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> RusotoFuture<SetReceiptRulePositionResponse, SetReceiptRulePositionError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("SetReceiptRulePosition");
+        let mut params = Params::new();
+
+        params.put("Action", "SetReceiptRulePosition");
+        params.put("Version", "2010-12-01");
+        SetReceiptRulePositionRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetReceiptRulePositionError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetReceiptRulePositionResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetReceiptRulePositionResponseDeserializer::deserialize(
+                        "SetReceiptRulePositionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("SetReceiptRulePosition", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a preview of the MIME content of an email when provided with a template and a set of replacement data.</p> <p>You can execute this operation no more than once per second.</p>
+    fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> RusotoFuture<TestRenderTemplateResponse, TestRenderTemplateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("TestRenderTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "TestRenderTemplate");
+        params.put("Version", "2010-12-01");
+        TestRenderTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TestRenderTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TestRenderTemplateResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TestRenderTemplateResponseDeserializer::deserialize(
+                        "TestRenderTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("TestRenderTemplate", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Enables or disables email sending across your entire Amazon SES account in the current AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending across your Amazon SES account in a given AWS Region when reputation metrics (such as your bounce or complaint rates) reach certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateAccountSendingEnabledError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateAccountSendingEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateAccountSendingEnabled");
+        params.put("Version", "2010-12-01");
+        UpdateAccountSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateAccountSendingEnabledError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Updates the event destination of a configuration set. Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>You can execute this operation no more than once per second.</p>
+    fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetEventDestinationResponse,
+        UpdateConfigurationSetEventDestinationError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetEventDestination");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateConfigurationSetEventDestination");
+        params.put("Version", "2010-12-01");
+        UpdateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConfigurationSetEventDestinationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateConfigurationSetEventDestinationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        UpdateConfigurationSetEventDestinationResponseDeserializer::deserialize(
+                            "UpdateConfigurationSetEventDestinationResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "UpdateConfigurationSetEventDestination",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Enables or disables the publishing of reputation metrics for emails sent using a specific configuration set in a given AWS Region. Reputation metrics include bounce and complaint rates. These metrics are published to Amazon CloudWatch. By using CloudWatch, you can create alarms when bounce or complaint rates exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetReputationMetricsEnabledError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetReputationMetricsEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateConfigurationSetReputationMetricsEnabled");
+        params.put("Version", "2010-12-01");
+        UpdateConfigurationSetReputationMetricsEnabledRequestSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        UpdateConfigurationSetReputationMetricsEnabledError::from_response(
+                            response,
+                        ),
+                    )
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Enables or disables email sending for messages sent using a specific configuration set in a given AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending for a configuration set when the reputation metrics for that configuration set (such as your bounce on complaint rate) exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> RusotoFuture<(), UpdateConfigurationSetSendingEnabledError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetSendingEnabled");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateConfigurationSetSendingEnabled");
+        params.put("Version", "2010-12-01");
+        UpdateConfigurationSetSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConfigurationSetSendingEnabledError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Modifies an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
+    fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        UpdateConfigurationSetTrackingOptionsError,
+    > {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateConfigurationSetTrackingOptions");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateConfigurationSetTrackingOptions");
+        params.put("Version", "2010-12-01");
+        UpdateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateConfigurationSetTrackingOptionsError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateConfigurationSetTrackingOptionsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        UpdateConfigurationSetTrackingOptionsResponseDeserializer::deserialize(
+                            "UpdateConfigurationSetTrackingOptionsResult",
+                            &mut stack,
+                        )
+                        .map_err(|err| {
+                            stack.parse_error(
+                                "UpdateConfigurationSetTrackingOptions",
+                                err,
+                                &response.body,
+                            )
+                        })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates an existing custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), UpdateCustomVerificationEmailTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateCustomVerificationEmailTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateCustomVerificationEmailTemplate");
+        params.put("Version", "2010-12-01");
+        UpdateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateCustomVerificationEmailTemplateError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Updates a receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> RusotoFuture<UpdateReceiptRuleResponse, UpdateReceiptRuleError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateReceiptRule");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateReceiptRule");
+        params.put("Version", "2010-12-01");
+        UpdateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateReceiptRuleError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateReceiptRuleResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateReceiptRuleResponseDeserializer::deserialize(
+                        "UpdateReceiptRuleResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateReceiptRule", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Updates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> RusotoFuture<UpdateTemplateResponse, UpdateTemplateError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("UpdateTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateTemplate");
+        params.put("Version", "2010-12-01");
+        UpdateTemplateRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateTemplateError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UpdateTemplateResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UpdateTemplateResponseDeserializer::deserialize(
+                        "UpdateTemplateResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UpdateTemplate", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a set of DKIM tokens for a domain identity.</p> <important> <p>When you execute the <code>VerifyDomainDkim</code> operation, the domain that you specify is added to the list of identities that are associated with your account. This is true even if you haven't already associated the domain with your account by using the <code>VerifyDomainIdentity</code> operation. However, you can't send email from the domain until you either successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-domains.html">verify it</a> or you successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">set up DKIM for it</a>.</p> </important> <p>You use the tokens that are generated by this operation to create CNAME records. When Amazon SES detects that you've added these records to the DNS configuration for a domain, you can start sending email from that domain. You can start sending email even if you haven't added the TXT record provided by the VerifyDomainIdentity operation to the DNS configuration for your domain. All email that you send from the domain is authenticated using DKIM.</p> <p>To create the CNAME records for DKIM authentication, use the following values:</p> <ul> <li> <p> <b>Name</b>: <i>token</i>._domainkey.<i>example.com</i> </p> </li> <li> <p> <b>Type</b>: CNAME</p> </li> <li> <p> <b>Value</b>: <i>token</i>.dkim.amazonses.com</p> </li> </ul> <p>In the preceding example, replace <i>token</i> with one of the tokens that are generated when you execute this operation. Replace <i>example.com</i> with your domain. Repeat this process for each token that's generated by this operation.</p> <p>You can execute this operation no more than once per second.</p>
+    fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> RusotoFuture<VerifyDomainDkimResponse, VerifyDomainDkimError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainDkim");
+        let mut params = Params::new();
+
+        params.put("Action", "VerifyDomainDkim");
+        params.put("Version", "2010-12-01");
+        VerifyDomainDkimRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(VerifyDomainDkimError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = VerifyDomainDkimResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = VerifyDomainDkimResponseDeserializer::deserialize(
+                        "VerifyDomainDkimResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("VerifyDomainDkim", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Adds a domain to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. For more information about verifying domains, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second.</p>
+    fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> RusotoFuture<VerifyDomainIdentityResponse, VerifyDomainIdentityError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyDomainIdentity");
+        let mut params = Params::new();
+
+        params.put("Action", "VerifyDomainIdentity");
         params.put("Version", "2010-12-01");
-        SetReceiptRulePositionRequestSerializer::serialize(&mut params, "", &input);
+        VerifyDomainIdentityRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(VerifyDomainIdentityError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = VerifyDomainIdentityResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = VerifyDomainIdentityResponseDeserializer::deserialize(
+                        "VerifyDomainIdentityResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("VerifyDomainIdentity", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deprecated. Use the <code>VerifyEmailIdentity</code> operation to verify a new email address.</p>
+    fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> RusotoFuture<(), VerifyEmailAddressError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailAddress");
+        let mut params = Params::new();
+
+        params.put("Action", "VerifyEmailAddress");
+        params.put("Version", "2010-12-01");
+        VerifyEmailAddressRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(VerifyEmailAddressError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS region and attempts to verify it. As a result of executing this operation, a verification email is sent to the specified address.</p> <p>You can execute this operation no more than once per second.</p>
+    fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError> {
+        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation("VerifyEmailIdentity");
+        let mut params = Params::new();
+
+        params.put("Action", "VerifyEmailIdentity");
+        params.put("Version", "2010-12-01");
+        VerifyEmailIdentityRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetReceiptRulePositionError::from_response(response))
-                }));
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(VerifyEmailIdentityError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = VerifyEmailIdentityResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = VerifyEmailIdentityResponseDeserializer::deserialize(
+                        "VerifyEmailIdentityResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("VerifyEmailIdentity", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+}
+
+/// A mock of the Amazon SES API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSes {
+    clone_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    CloneReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    CloneReceiptRuleSetResponse,
+                    RusotoError<CloneReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_configuration_set: Option<
+        Box<
+            dyn Fn(
+                    CreateConfigurationSetRequest,
+                ) -> ::std::result::Result<
+                    CreateConfigurationSetResponse,
+                    RusotoError<CreateConfigurationSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_configuration_set_event_destination: Option<
+        Box<
+            dyn Fn(
+                    CreateConfigurationSetEventDestinationRequest,
+                ) -> ::std::result::Result<
+                    CreateConfigurationSetEventDestinationResponse,
+                    RusotoError<CreateConfigurationSetEventDestinationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_configuration_set_tracking_options: Option<
+        Box<
+            dyn Fn(
+                    CreateConfigurationSetTrackingOptionsRequest,
+                ) -> ::std::result::Result<
+                    CreateConfigurationSetTrackingOptionsResponse,
+                    RusotoError<CreateConfigurationSetTrackingOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_custom_verification_email_template: Option<
+        Box<
+            dyn Fn(
+                    CreateCustomVerificationEmailTemplateRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<CreateCustomVerificationEmailTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_receipt_filter: Option<
+        Box<
+            dyn Fn(
+                    CreateReceiptFilterRequest,
+                ) -> ::std::result::Result<
+                    CreateReceiptFilterResponse,
+                    RusotoError<CreateReceiptFilterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_receipt_rule: Option<
+        Box<
+            dyn Fn(
+                    CreateReceiptRuleRequest,
+                ) -> ::std::result::Result<
+                    CreateReceiptRuleResponse,
+                    RusotoError<CreateReceiptRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    CreateReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    CreateReceiptRuleSetResponse,
+                    RusotoError<CreateReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_template: Option<
+        Box<
+            dyn Fn(
+                    CreateTemplateRequest,
+                ) -> ::std::result::Result<
+                    CreateTemplateResponse,
+                    RusotoError<CreateTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_configuration_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigurationSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteConfigurationSetResponse,
+                    RusotoError<DeleteConfigurationSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_configuration_set_event_destination: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigurationSetEventDestinationRequest,
+                ) -> ::std::result::Result<
+                    DeleteConfigurationSetEventDestinationResponse,
+                    RusotoError<DeleteConfigurationSetEventDestinationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_configuration_set_tracking_options: Option<
+        Box<
+            dyn Fn(
+                    DeleteConfigurationSetTrackingOptionsRequest,
+                ) -> ::std::result::Result<
+                    DeleteConfigurationSetTrackingOptionsResponse,
+                    RusotoError<DeleteConfigurationSetTrackingOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_custom_verification_email_template: Option<
+        Box<
+            dyn Fn(
+                    DeleteCustomVerificationEmailTemplateRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<DeleteCustomVerificationEmailTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_identity: Option<
+        Box<
+            dyn Fn(
+                    DeleteIdentityRequest,
+                ) -> ::std::result::Result<
+                    DeleteIdentityResponse,
+                    RusotoError<DeleteIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_identity_policy: Option<
+        Box<
+            dyn Fn(
+                    DeleteIdentityPolicyRequest,
+                ) -> ::std::result::Result<
+                    DeleteIdentityPolicyResponse,
+                    RusotoError<DeleteIdentityPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_receipt_filter: Option<
+        Box<
+            dyn Fn(
+                    DeleteReceiptFilterRequest,
+                ) -> ::std::result::Result<
+                    DeleteReceiptFilterResponse,
+                    RusotoError<DeleteReceiptFilterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_receipt_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteReceiptRuleRequest,
+                ) -> ::std::result::Result<
+                    DeleteReceiptRuleResponse,
+                    RusotoError<DeleteReceiptRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteReceiptRuleSetResponse,
+                    RusotoError<DeleteReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_template: Option<
+        Box<
+            dyn Fn(
+                    DeleteTemplateRequest,
+                ) -> ::std::result::Result<
+                    DeleteTemplateResponse,
+                    RusotoError<DeleteTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_verified_email_address: Option<
+        Box<
+            dyn Fn(
+                    DeleteVerifiedEmailAddressRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteVerifiedEmailAddressError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_active_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    DescribeActiveReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    DescribeActiveReceiptRuleSetResponse,
+                    RusotoError<DescribeActiveReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_configuration_set: Option<
+        Box<
+            dyn Fn(
+                    DescribeConfigurationSetRequest,
+                ) -> ::std::result::Result<
+                    DescribeConfigurationSetResponse,
+                    RusotoError<DescribeConfigurationSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_receipt_rule: Option<
+        Box<
+            dyn Fn(
+                    DescribeReceiptRuleRequest,
+                ) -> ::std::result::Result<
+                    DescribeReceiptRuleResponse,
+                    RusotoError<DescribeReceiptRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    DescribeReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    DescribeReceiptRuleSetResponse,
+                    RusotoError<DescribeReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_account_sending_enabled: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetAccountSendingEnabledResponse,
+                    RusotoError<GetAccountSendingEnabledError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_custom_verification_email_template: Option<
+        Box<
+            dyn Fn(
+                    GetCustomVerificationEmailTemplateRequest,
+                ) -> ::std::result::Result<
+                    GetCustomVerificationEmailTemplateResponse,
+                    RusotoError<GetCustomVerificationEmailTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_identity_dkim_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityDkimAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityDkimAttributesResponse,
+                    RusotoError<GetIdentityDkimAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_identity_mail_from_domain_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityMailFromDomainAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityMailFromDomainAttributesResponse,
+                    RusotoError<GetIdentityMailFromDomainAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_identity_notification_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityNotificationAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityNotificationAttributesResponse,
+                    RusotoError<GetIdentityNotificationAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_identity_policies: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityPoliciesRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityPoliciesResponse,
+                    RusotoError<GetIdentityPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_identity_verification_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetIdentityVerificationAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetIdentityVerificationAttributesResponse,
+                    RusotoError<GetIdentityVerificationAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_send_quota: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_send_statistics: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetSendStatisticsResponse,
+                    RusotoError<GetSendStatisticsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_template: Option<
+        Box<
+            dyn Fn(
+                    GetTemplateRequest,
+                )
+                    -> ::std::result::Result<GetTemplateResponse, RusotoError<GetTemplateError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_configuration_sets: Option<
+        Box<
+            dyn Fn(
+                    ListConfigurationSetsRequest,
+                ) -> ::std::result::Result<
+                    ListConfigurationSetsResponse,
+                    RusotoError<ListConfigurationSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_custom_verification_email_templates: Option<
+        Box<
+            dyn Fn(
+                    ListCustomVerificationEmailTemplatesRequest,
+                ) -> ::std::result::Result<
+                    ListCustomVerificationEmailTemplatesResponse,
+                    RusotoError<ListCustomVerificationEmailTemplatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_identities: Option<
+        Box<
+            dyn Fn(
+                    ListIdentitiesRequest,
+                ) -> ::std::result::Result<
+                    ListIdentitiesResponse,
+                    RusotoError<ListIdentitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_identity_policies: Option<
+        Box<
+            dyn Fn(
+                    ListIdentityPoliciesRequest,
+                ) -> ::std::result::Result<
+                    ListIdentityPoliciesResponse,
+                    RusotoError<ListIdentityPoliciesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_receipt_filters: Option<
+        Box<
+            dyn Fn(
+                    ListReceiptFiltersRequest,
+                ) -> ::std::result::Result<
+                    ListReceiptFiltersResponse,
+                    RusotoError<ListReceiptFiltersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_receipt_rule_sets: Option<
+        Box<
+            dyn Fn(
+                    ListReceiptRuleSetsRequest,
+                ) -> ::std::result::Result<
+                    ListReceiptRuleSetsResponse,
+                    RusotoError<ListReceiptRuleSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_templates: Option<
+        Box<
+            dyn Fn(
+                    ListTemplatesRequest,
+                )
+                    -> ::std::result::Result<ListTemplatesResponse, RusotoError<ListTemplatesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_verified_email_addresses: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ListVerifiedEmailAddressesResponse,
+                    RusotoError<ListVerifiedEmailAddressesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_configuration_set_delivery_options: Option<
+        Box<
+            dyn Fn(
+                    PutConfigurationSetDeliveryOptionsRequest,
+                ) -> ::std::result::Result<
+                    PutConfigurationSetDeliveryOptionsResponse,
+                    RusotoError<PutConfigurationSetDeliveryOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_identity_policy: Option<
+        Box<
+            dyn Fn(
+                    PutIdentityPolicyRequest,
+                ) -> ::std::result::Result<
+                    PutIdentityPolicyResponse,
+                    RusotoError<PutIdentityPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reorder_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    ReorderReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    ReorderReceiptRuleSetResponse,
+                    RusotoError<ReorderReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_bounce: Option<
+        Box<
+            dyn Fn(
+                    SendBounceRequest,
+                )
+                    -> ::std::result::Result<SendBounceResponse, RusotoError<SendBounceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_bulk_templated_email: Option<
+        Box<
+            dyn Fn(
+                    SendBulkTemplatedEmailRequest,
+                ) -> ::std::result::Result<
+                    SendBulkTemplatedEmailResponse,
+                    RusotoError<SendBulkTemplatedEmailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_custom_verification_email: Option<
+        Box<
+            dyn Fn(
+                    SendCustomVerificationEmailRequest,
+                ) -> ::std::result::Result<
+                    SendCustomVerificationEmailResponse,
+                    RusotoError<SendCustomVerificationEmailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_email: Option<
+        Box<
+            dyn Fn(
+                    SendEmailRequest,
+                )
+                    -> ::std::result::Result<SendEmailResponse, RusotoError<SendEmailError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_raw_email: Option<
+        Box<
+            dyn Fn(
+                    SendRawEmailRequest,
+                )
+                    -> ::std::result::Result<SendRawEmailResponse, RusotoError<SendRawEmailError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_templated_email: Option<
+        Box<
+            dyn Fn(
+                    SendTemplatedEmailRequest,
+                ) -> ::std::result::Result<
+                    SendTemplatedEmailResponse,
+                    RusotoError<SendTemplatedEmailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_active_receipt_rule_set: Option<
+        Box<
+            dyn Fn(
+                    SetActiveReceiptRuleSetRequest,
+                ) -> ::std::result::Result<
+                    SetActiveReceiptRuleSetResponse,
+                    RusotoError<SetActiveReceiptRuleSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_identity_dkim_enabled: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityDkimEnabledRequest,
+                ) -> ::std::result::Result<
+                    SetIdentityDkimEnabledResponse,
+                    RusotoError<SetIdentityDkimEnabledError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_identity_feedback_forwarding_enabled: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityFeedbackForwardingEnabledRequest,
+                ) -> ::std::result::Result<
+                    SetIdentityFeedbackForwardingEnabledResponse,
+                    RusotoError<SetIdentityFeedbackForwardingEnabledError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_identity_headers_in_notifications_enabled: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityHeadersInNotificationsEnabledRequest,
+                ) -> ::std::result::Result<
+                    SetIdentityHeadersInNotificationsEnabledResponse,
+                    RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_identity_mail_from_domain: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityMailFromDomainRequest,
+                ) -> ::std::result::Result<
+                    SetIdentityMailFromDomainResponse,
+                    RusotoError<SetIdentityMailFromDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_identity_notification_topic: Option<
+        Box<
+            dyn Fn(
+                    SetIdentityNotificationTopicRequest,
+                ) -> ::std::result::Result<
+                    SetIdentityNotificationTopicResponse,
+                    RusotoError<SetIdentityNotificationTopicError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_receipt_rule_position: Option<
+        Box<
+            dyn Fn(
+                    SetReceiptRulePositionRequest,
+                ) -> ::std::result::Result<
+                    SetReceiptRulePositionResponse,
+                    RusotoError<SetReceiptRulePositionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    test_render_template: Option<
+        Box<
+            dyn Fn(
+                    TestRenderTemplateRequest,
+                ) -> ::std::result::Result<
+                    TestRenderTemplateResponse,
+                    RusotoError<TestRenderTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_account_sending_enabled: Option<
+        Box<
+            dyn Fn(
+                    UpdateAccountSendingEnabledRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateAccountSendingEnabledError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_configuration_set_event_destination: Option<
+        Box<
+            dyn Fn(
+                    UpdateConfigurationSetEventDestinationRequest,
+                ) -> ::std::result::Result<
+                    UpdateConfigurationSetEventDestinationResponse,
+                    RusotoError<UpdateConfigurationSetEventDestinationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_configuration_set_reputation_metrics_enabled: Option<
+        Box<
+            dyn Fn(
+                    UpdateConfigurationSetReputationMetricsEnabledRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_configuration_set_sending_enabled: Option<
+        Box<
+            dyn Fn(
+                    UpdateConfigurationSetSendingEnabledRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<UpdateConfigurationSetSendingEnabledError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_configuration_set_tracking_options: Option<
+        Box<
+            dyn Fn(
+                    UpdateConfigurationSetTrackingOptionsRequest,
+                ) -> ::std::result::Result<
+                    UpdateConfigurationSetTrackingOptionsResponse,
+                    RusotoError<UpdateConfigurationSetTrackingOptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_custom_verification_email_template: Option<
+        Box<
+            dyn Fn(
+                    UpdateCustomVerificationEmailTemplateRequest,
+                ) -> ::std::result::Result<
+                    (),
+                    RusotoError<UpdateCustomVerificationEmailTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_receipt_rule: Option<
+        Box<
+            dyn Fn(
+                    UpdateReceiptRuleRequest,
+                ) -> ::std::result::Result<
+                    UpdateReceiptRuleResponse,
+                    RusotoError<UpdateReceiptRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_template: Option<
+        Box<
+            dyn Fn(
+                    UpdateTemplateRequest,
+                ) -> ::std::result::Result<
+                    UpdateTemplateResponse,
+                    RusotoError<UpdateTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    verify_domain_dkim: Option<
+        Box<
+            dyn Fn(
+                    VerifyDomainDkimRequest,
+                ) -> ::std::result::Result<
+                    VerifyDomainDkimResponse,
+                    RusotoError<VerifyDomainDkimError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    verify_domain_identity: Option<
+        Box<
+            dyn Fn(
+                    VerifyDomainIdentityRequest,
+                ) -> ::std::result::Result<
+                    VerifyDomainIdentityResponse,
+                    RusotoError<VerifyDomainIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    verify_email_address: Option<
+        Box<
+            dyn Fn(
+                    VerifyEmailAddressRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<VerifyEmailAddressError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    verify_email_identity: Option<
+        Box<
+            dyn Fn(
+                    VerifyEmailIdentityRequest,
+                ) -> ::std::result::Result<
+                    VerifyEmailIdentityResponse,
+                    RusotoError<VerifyEmailIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSes {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSes {
+        Default::default()
+    }
+
+    /// Answers `clone_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_clone_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CloneReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                CloneReceiptRuleSetResponse,
+                RusotoError<CloneReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.clone_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_configuration_set` with what `handler` returns for its input.
+    pub fn with_create_configuration_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateConfigurationSetRequest,
+            ) -> ::std::result::Result<
+                CreateConfigurationSetResponse,
+                RusotoError<CreateConfigurationSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_configuration_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_configuration_set_event_destination` with what `handler` returns for its input.
+    pub fn with_create_configuration_set_event_destination<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateConfigurationSetEventDestinationRequest,
+            ) -> ::std::result::Result<
+                CreateConfigurationSetEventDestinationResponse,
+                RusotoError<CreateConfigurationSetEventDestinationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_configuration_set_event_destination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_configuration_set_tracking_options` with what `handler` returns for its input.
+    pub fn with_create_configuration_set_tracking_options<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateConfigurationSetTrackingOptionsRequest,
+            ) -> ::std::result::Result<
+                CreateConfigurationSetTrackingOptionsResponse,
+                RusotoError<CreateConfigurationSetTrackingOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_configuration_set_tracking_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_custom_verification_email_template` with what `handler` returns for its input.
+    pub fn with_create_custom_verification_email_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateCustomVerificationEmailTemplateRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_custom_verification_email_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_receipt_filter` with what `handler` returns for its input.
+    pub fn with_create_receipt_filter<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateReceiptFilterRequest,
+            ) -> ::std::result::Result<
+                CreateReceiptFilterResponse,
+                RusotoError<CreateReceiptFilterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_receipt_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_receipt_rule` with what `handler` returns for its input.
+    pub fn with_create_receipt_rule<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateReceiptRuleRequest,
+            ) -> ::std::result::Result<
+                CreateReceiptRuleResponse,
+                RusotoError<CreateReceiptRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_receipt_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_create_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                CreateReceiptRuleSetResponse,
+                RusotoError<CreateReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_template` with what `handler` returns for its input.
+    pub fn with_create_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                CreateTemplateRequest,
+            )
+                -> ::std::result::Result<CreateTemplateResponse, RusotoError<CreateTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_configuration_set` with what `handler` returns for its input.
+    pub fn with_delete_configuration_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteConfigurationSetRequest,
+            ) -> ::std::result::Result<
+                DeleteConfigurationSetResponse,
+                RusotoError<DeleteConfigurationSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_configuration_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_configuration_set_event_destination` with what `handler` returns for its input.
+    pub fn with_delete_configuration_set_event_destination<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteConfigurationSetEventDestinationRequest,
+            ) -> ::std::result::Result<
+                DeleteConfigurationSetEventDestinationResponse,
+                RusotoError<DeleteConfigurationSetEventDestinationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_configuration_set_event_destination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_configuration_set_tracking_options` with what `handler` returns for its input.
+    pub fn with_delete_configuration_set_tracking_options<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteConfigurationSetTrackingOptionsRequest,
+            ) -> ::std::result::Result<
+                DeleteConfigurationSetTrackingOptionsResponse,
+                RusotoError<DeleteConfigurationSetTrackingOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_configuration_set_tracking_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_custom_verification_email_template` with what `handler` returns for its input.
+    pub fn with_delete_custom_verification_email_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteCustomVerificationEmailTemplateRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_custom_verification_email_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_identity` with what `handler` returns for its input.
+    pub fn with_delete_identity<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteIdentityRequest,
+            )
+                -> ::std::result::Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_identity_policy` with what `handler` returns for its input.
+    pub fn with_delete_identity_policy<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteIdentityPolicyRequest,
+            ) -> ::std::result::Result<
+                DeleteIdentityPolicyResponse,
+                RusotoError<DeleteIdentityPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_identity_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_receipt_filter` with what `handler` returns for its input.
+    pub fn with_delete_receipt_filter<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteReceiptFilterRequest,
+            ) -> ::std::result::Result<
+                DeleteReceiptFilterResponse,
+                RusotoError<DeleteReceiptFilterError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_receipt_filter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_receipt_rule` with what `handler` returns for its input.
+    pub fn with_delete_receipt_rule<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteReceiptRuleRequest,
+            ) -> ::std::result::Result<
+                DeleteReceiptRuleResponse,
+                RusotoError<DeleteReceiptRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_receipt_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_delete_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                DeleteReceiptRuleSetResponse,
+                RusotoError<DeleteReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_template` with what `handler` returns for its input.
+    pub fn with_delete_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteTemplateRequest,
+            )
+                -> ::std::result::Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_verified_email_address` with what `handler` returns for its input.
+    pub fn with_delete_verified_email_address<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DeleteVerifiedEmailAddressRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteVerifiedEmailAddressError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_verified_email_address = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_active_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_describe_active_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DescribeActiveReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                DescribeActiveReceiptRuleSetResponse,
+                RusotoError<DescribeActiveReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_active_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_configuration_set` with what `handler` returns for its input.
+    pub fn with_describe_configuration_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DescribeConfigurationSetRequest,
+            ) -> ::std::result::Result<
+                DescribeConfigurationSetResponse,
+                RusotoError<DescribeConfigurationSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_configuration_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_receipt_rule` with what `handler` returns for its input.
+    pub fn with_describe_receipt_rule<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DescribeReceiptRuleRequest,
+            ) -> ::std::result::Result<
+                DescribeReceiptRuleResponse,
+                RusotoError<DescribeReceiptRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_receipt_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_describe_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                DescribeReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                DescribeReceiptRuleSetResponse,
+                RusotoError<DescribeReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_account_sending_enabled` with what `handler` returns.
+    pub fn with_get_account_sending_enabled<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn() -> ::std::result::Result<
+                GetAccountSendingEnabledResponse,
+                RusotoError<GetAccountSendingEnabledError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_account_sending_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_custom_verification_email_template` with what `handler` returns for its input.
+    pub fn with_get_custom_verification_email_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetCustomVerificationEmailTemplateRequest,
+            ) -> ::std::result::Result<
+                GetCustomVerificationEmailTemplateResponse,
+                RusotoError<GetCustomVerificationEmailTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_custom_verification_email_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_dkim_attributes` with what `handler` returns for its input.
+    pub fn with_get_identity_dkim_attributes<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetIdentityDkimAttributesRequest,
+            ) -> ::std::result::Result<
+                GetIdentityDkimAttributesResponse,
+                RusotoError<GetIdentityDkimAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_dkim_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_mail_from_domain_attributes` with what `handler` returns for its input.
+    pub fn with_get_identity_mail_from_domain_attributes<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetIdentityMailFromDomainAttributesRequest,
+            ) -> ::std::result::Result<
+                GetIdentityMailFromDomainAttributesResponse,
+                RusotoError<GetIdentityMailFromDomainAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_mail_from_domain_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_notification_attributes` with what `handler` returns for its input.
+    pub fn with_get_identity_notification_attributes<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetIdentityNotificationAttributesRequest,
+            ) -> ::std::result::Result<
+                GetIdentityNotificationAttributesResponse,
+                RusotoError<GetIdentityNotificationAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_notification_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_policies` with what `handler` returns for its input.
+    pub fn with_get_identity_policies<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetIdentityPoliciesRequest,
+            ) -> ::std::result::Result<
+                GetIdentityPoliciesResponse,
+                RusotoError<GetIdentityPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_identity_verification_attributes` with what `handler` returns for its input.
+    pub fn with_get_identity_verification_attributes<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetIdentityVerificationAttributesRequest,
+            ) -> ::std::result::Result<
+                GetIdentityVerificationAttributesResponse,
+                RusotoError<GetIdentityVerificationAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_identity_verification_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_send_quota` with what `handler` returns.
+    pub fn with_get_send_quota<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn() -> ::std::result::Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_send_quota = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_send_statistics` with what `handler` returns.
+    pub fn with_get_send_statistics<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn() -> ::std::result::Result<
+                GetSendStatisticsResponse,
+                RusotoError<GetSendStatisticsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_send_statistics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_template` with what `handler` returns for its input.
+    pub fn with_get_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                GetTemplateRequest,
+            )
+                -> ::std::result::Result<GetTemplateResponse, RusotoError<GetTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_configuration_sets` with what `handler` returns for its input.
+    pub fn with_list_configuration_sets<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListConfigurationSetsRequest,
+            ) -> ::std::result::Result<
+                ListConfigurationSetsResponse,
+                RusotoError<ListConfigurationSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_configuration_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_custom_verification_email_templates` with what `handler` returns for its input.
+    pub fn with_list_custom_verification_email_templates<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListCustomVerificationEmailTemplatesRequest,
+            ) -> ::std::result::Result<
+                ListCustomVerificationEmailTemplatesResponse,
+                RusotoError<ListCustomVerificationEmailTemplatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_custom_verification_email_templates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_identities` with what `handler` returns for its input.
+    pub fn with_list_identities<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListIdentitiesRequest,
+            )
+                -> ::std::result::Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_identities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_identity_policies` with what `handler` returns for its input.
+    pub fn with_list_identity_policies<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListIdentityPoliciesRequest,
+            ) -> ::std::result::Result<
+                ListIdentityPoliciesResponse,
+                RusotoError<ListIdentityPoliciesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_identity_policies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_receipt_filters` with what `handler` returns for its input.
+    pub fn with_list_receipt_filters<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListReceiptFiltersRequest,
+            ) -> ::std::result::Result<
+                ListReceiptFiltersResponse,
+                RusotoError<ListReceiptFiltersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_receipt_filters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_receipt_rule_sets` with what `handler` returns for its input.
+    pub fn with_list_receipt_rule_sets<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListReceiptRuleSetsRequest,
+            ) -> ::std::result::Result<
+                ListReceiptRuleSetsResponse,
+                RusotoError<ListReceiptRuleSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_receipt_rule_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_templates` with what `handler` returns for its input.
+    pub fn with_list_templates<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ListTemplatesRequest,
+            )
+                -> ::std::result::Result<ListTemplatesResponse, RusotoError<ListTemplatesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_templates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_verified_email_addresses` with what `handler` returns.
+    pub fn with_list_verified_email_addresses<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn() -> ::std::result::Result<
+                ListVerifiedEmailAddressesResponse,
+                RusotoError<ListVerifiedEmailAddressesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_verified_email_addresses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_configuration_set_delivery_options` with what `handler` returns for its input.
+    pub fn with_put_configuration_set_delivery_options<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                PutConfigurationSetDeliveryOptionsRequest,
+            ) -> ::std::result::Result<
+                PutConfigurationSetDeliveryOptionsResponse,
+                RusotoError<PutConfigurationSetDeliveryOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_configuration_set_delivery_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_identity_policy` with what `handler` returns for its input.
+    pub fn with_put_identity_policy<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                PutIdentityPolicyRequest,
+            ) -> ::std::result::Result<
+                PutIdentityPolicyResponse,
+                RusotoError<PutIdentityPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_identity_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reorder_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_reorder_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                ReorderReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                ReorderReceiptRuleSetResponse,
+                RusotoError<ReorderReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reorder_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_bounce` with what `handler` returns for its input.
+    pub fn with_send_bounce<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SendBounceRequest,
+            )
+                -> ::std::result::Result<SendBounceResponse, RusotoError<SendBounceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_bounce = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_bulk_templated_email` with what `handler` returns for its input.
+    pub fn with_send_bulk_templated_email<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SendBulkTemplatedEmailRequest,
+            ) -> ::std::result::Result<
+                SendBulkTemplatedEmailResponse,
+                RusotoError<SendBulkTemplatedEmailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_bulk_templated_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_custom_verification_email` with what `handler` returns for its input.
+    pub fn with_send_custom_verification_email<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SendCustomVerificationEmailRequest,
+            ) -> ::std::result::Result<
+                SendCustomVerificationEmailResponse,
+                RusotoError<SendCustomVerificationEmailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_custom_verification_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_email` with what `handler` returns for its input.
+    pub fn with_send_email<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SendEmailRequest,
+            ) -> ::std::result::Result<SendEmailResponse, RusotoError<SendEmailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_raw_email` with what `handler` returns for its input.
+    pub fn with_send_raw_email<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SendRawEmailRequest,
+            )
+                -> ::std::result::Result<SendRawEmailResponse, RusotoError<SendRawEmailError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_raw_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_templated_email` with what `handler` returns for its input.
+    pub fn with_send_templated_email<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SendTemplatedEmailRequest,
+            ) -> ::std::result::Result<
+                SendTemplatedEmailResponse,
+                RusotoError<SendTemplatedEmailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_templated_email = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_active_receipt_rule_set` with what `handler` returns for its input.
+    pub fn with_set_active_receipt_rule_set<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetActiveReceiptRuleSetRequest,
+            ) -> ::std::result::Result<
+                SetActiveReceiptRuleSetResponse,
+                RusotoError<SetActiveReceiptRuleSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_active_receipt_rule_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_dkim_enabled` with what `handler` returns for its input.
+    pub fn with_set_identity_dkim_enabled<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetIdentityDkimEnabledRequest,
+            ) -> ::std::result::Result<
+                SetIdentityDkimEnabledResponse,
+                RusotoError<SetIdentityDkimEnabledError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_dkim_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_feedback_forwarding_enabled` with what `handler` returns for its input.
+    pub fn with_set_identity_feedback_forwarding_enabled<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetIdentityFeedbackForwardingEnabledRequest,
+            ) -> ::std::result::Result<
+                SetIdentityFeedbackForwardingEnabledResponse,
+                RusotoError<SetIdentityFeedbackForwardingEnabledError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_feedback_forwarding_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_headers_in_notifications_enabled` with what `handler` returns for its input.
+    pub fn with_set_identity_headers_in_notifications_enabled<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetIdentityHeadersInNotificationsEnabledRequest,
+            ) -> ::std::result::Result<
+                SetIdentityHeadersInNotificationsEnabledResponse,
+                RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_headers_in_notifications_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_mail_from_domain` with what `handler` returns for its input.
+    pub fn with_set_identity_mail_from_domain<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetIdentityMailFromDomainRequest,
+            ) -> ::std::result::Result<
+                SetIdentityMailFromDomainResponse,
+                RusotoError<SetIdentityMailFromDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_mail_from_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_identity_notification_topic` with what `handler` returns for its input.
+    pub fn with_set_identity_notification_topic<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetIdentityNotificationTopicRequest,
+            ) -> ::std::result::Result<
+                SetIdentityNotificationTopicResponse,
+                RusotoError<SetIdentityNotificationTopicError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_identity_notification_topic = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_receipt_rule_position` with what `handler` returns for its input.
+    pub fn with_set_receipt_rule_position<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                SetReceiptRulePositionRequest,
+            ) -> ::std::result::Result<
+                SetReceiptRulePositionResponse,
+                RusotoError<SetReceiptRulePositionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_receipt_rule_position = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_render_template` with what `handler` returns for its input.
+    pub fn with_test_render_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                TestRenderTemplateRequest,
+            ) -> ::std::result::Result<
+                TestRenderTemplateResponse,
+                RusotoError<TestRenderTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_render_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_account_sending_enabled` with what `handler` returns for its input.
+    pub fn with_update_account_sending_enabled<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateAccountSendingEnabledRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateAccountSendingEnabledError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_account_sending_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_configuration_set_event_destination` with what `handler` returns for its input.
+    pub fn with_update_configuration_set_event_destination<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateConfigurationSetEventDestinationRequest,
+            ) -> ::std::result::Result<
+                UpdateConfigurationSetEventDestinationResponse,
+                RusotoError<UpdateConfigurationSetEventDestinationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_configuration_set_event_destination = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_configuration_set_reputation_metrics_enabled` with what `handler` returns for its input.
+    pub fn with_update_configuration_set_reputation_metrics_enabled<F>(
+        mut self,
+        handler: F,
+    ) -> MockSes
+    where
+        F: Fn(
+                UpdateConfigurationSetReputationMetricsEnabledRequest,
+            ) -> ::std::result::Result<
+                (),
+                RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_configuration_set_reputation_metrics_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_configuration_set_sending_enabled` with what `handler` returns for its input.
+    pub fn with_update_configuration_set_sending_enabled<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateConfigurationSetSendingEnabledRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_configuration_set_sending_enabled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_configuration_set_tracking_options` with what `handler` returns for its input.
+    pub fn with_update_configuration_set_tracking_options<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateConfigurationSetTrackingOptionsRequest,
+            ) -> ::std::result::Result<
+                UpdateConfigurationSetTrackingOptionsResponse,
+                RusotoError<UpdateConfigurationSetTrackingOptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_configuration_set_tracking_options = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_custom_verification_email_template` with what `handler` returns for its input.
+    pub fn with_update_custom_verification_email_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateCustomVerificationEmailTemplateRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_custom_verification_email_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_receipt_rule` with what `handler` returns for its input.
+    pub fn with_update_receipt_rule<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateReceiptRuleRequest,
+            ) -> ::std::result::Result<
+                UpdateReceiptRuleResponse,
+                RusotoError<UpdateReceiptRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_receipt_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_template` with what `handler` returns for its input.
+    pub fn with_update_template<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                UpdateTemplateRequest,
+            )
+                -> ::std::result::Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_domain_dkim` with what `handler` returns for its input.
+    pub fn with_verify_domain_dkim<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                VerifyDomainDkimRequest,
+            ) -> ::std::result::Result<
+                VerifyDomainDkimResponse,
+                RusotoError<VerifyDomainDkimError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_domain_dkim = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_domain_identity` with what `handler` returns for its input.
+    pub fn with_verify_domain_identity<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                VerifyDomainIdentityRequest,
+            ) -> ::std::result::Result<
+                VerifyDomainIdentityResponse,
+                RusotoError<VerifyDomainIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_domain_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_email_address` with what `handler` returns for its input.
+    pub fn with_verify_email_address<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                VerifyEmailAddressRequest,
+            ) -> ::std::result::Result<(), RusotoError<VerifyEmailAddressError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_email_address = Some(Box::new(handler));
+        self
+    }
+    /// Answers `verify_email_identity` with what `handler` returns for its input.
+    pub fn with_verify_email_identity<F>(mut self, handler: F) -> MockSes
+    where
+        F: Fn(
+                VerifyEmailIdentityRequest,
+            ) -> ::std::result::Result<
+                VerifyEmailIdentityResponse,
+                RusotoError<VerifyEmailIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.verify_email_identity = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ses for MockSes {
+    fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> RusotoFuture<CloneReceiptRuleSetResponse, CloneReceiptRuleSetError> {
+        match self.clone_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for clone_receipt_rule_set"),
+        }
+    }
+    fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> RusotoFuture<CreateConfigurationSetResponse, CreateConfigurationSetError> {
+        match self.create_configuration_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_configuration_set"),
+        }
+    }
+    fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetEventDestinationResponse,
+        CreateConfigurationSetEventDestinationError,
+    > {
+        match self.create_configuration_set_event_destination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_configuration_set_event_destination"),
+        }
+    }
+    fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        CreateConfigurationSetTrackingOptionsResponse,
+        CreateConfigurationSetTrackingOptionsError,
+    > {
+        match self.create_configuration_set_tracking_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_configuration_set_tracking_options"),
+        }
+    }
+    fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), CreateCustomVerificationEmailTemplateError> {
+        match self.create_custom_verification_email_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_custom_verification_email_template"),
+        }
+    }
+    fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> RusotoFuture<CreateReceiptFilterResponse, CreateReceiptFilterError> {
+        match self.create_receipt_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_receipt_filter"),
+        }
+    }
+    fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> RusotoFuture<CreateReceiptRuleResponse, CreateReceiptRuleError> {
+        match self.create_receipt_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_receipt_rule"),
+        }
+    }
+    fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> RusotoFuture<CreateReceiptRuleSetResponse, CreateReceiptRuleSetError> {
+        match self.create_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_receipt_rule_set"),
+        }
+    }
+    fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> RusotoFuture<CreateTemplateResponse, CreateTemplateError> {
+        match self.create_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for create_template"),
+        }
+    }
+    fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> RusotoFuture<DeleteConfigurationSetResponse, DeleteConfigurationSetError> {
+        match self.delete_configuration_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_configuration_set"),
+        }
+    }
+    fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetEventDestinationResponse,
+        DeleteConfigurationSetEventDestinationError,
+    > {
+        match self.delete_configuration_set_event_destination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_configuration_set_event_destination"),
+        }
+    }
+    fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> RusotoFuture<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        DeleteConfigurationSetTrackingOptionsError,
+    > {
+        match self.delete_configuration_set_tracking_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_configuration_set_tracking_options"),
+        }
+    }
+    fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<(), DeleteCustomVerificationEmailTemplateError> {
+        match self.delete_custom_verification_email_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_custom_verification_email_template"),
+        }
+    }
+    fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> RusotoFuture<DeleteIdentityResponse, DeleteIdentityError> {
+        match self.delete_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_identity"),
+        }
+    }
+    fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> RusotoFuture<DeleteIdentityPolicyResponse, DeleteIdentityPolicyError> {
+        match self.delete_identity_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_identity_policy"),
+        }
+    }
+    fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> RusotoFuture<DeleteReceiptFilterResponse, DeleteReceiptFilterError> {
+        match self.delete_receipt_filter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_receipt_filter"),
+        }
+    }
+    fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleResponse, DeleteReceiptRuleError> {
+        match self.delete_receipt_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_receipt_rule"),
+        }
+    }
+    fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> RusotoFuture<DeleteReceiptRuleSetResponse, DeleteReceiptRuleSetError> {
+        match self.delete_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_receipt_rule_set"),
+        }
+    }
+    fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> RusotoFuture<DeleteTemplateResponse, DeleteTemplateError> {
+        match self.delete_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_template"),
+        }
+    }
+    fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> RusotoFuture<(), DeleteVerifiedEmailAddressError> {
+        match self.delete_verified_email_address {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for delete_verified_email_address"),
+        }
+    }
+    fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeActiveReceiptRuleSetResponse, DescribeActiveReceiptRuleSetError> {
+        match self.describe_active_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for describe_active_receipt_rule_set"),
+        }
+    }
+    fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> RusotoFuture<DescribeConfigurationSetResponse, DescribeConfigurationSetError> {
+        match self.describe_configuration_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for describe_configuration_set"),
+        }
+    }
+    fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleResponse, DescribeReceiptRuleError> {
+        match self.describe_receipt_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for describe_receipt_rule"),
+        }
+    }
+    fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> RusotoFuture<DescribeReceiptRuleSetResponse, DescribeReceiptRuleSetError> {
+        match self.describe_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for describe_receipt_rule_set"),
+        }
+    }
+    fn get_account_sending_enabled(
+        &self,
+    ) -> RusotoFuture<GetAccountSendingEnabledResponse, GetAccountSendingEnabledError> {
+        match self.get_account_sending_enabled {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSes has no handler for get_account_sending_enabled"),
+        }
+    }
+    fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> RusotoFuture<
+        GetCustomVerificationEmailTemplateResponse,
+        GetCustomVerificationEmailTemplateError,
+    > {
+        match self.get_custom_verification_email_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_custom_verification_email_template"),
+        }
+    }
+    fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> RusotoFuture<GetIdentityDkimAttributesResponse, GetIdentityDkimAttributesError> {
+        match self.get_identity_dkim_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_identity_dkim_attributes"),
+        }
+    }
+    fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityMailFromDomainAttributesResponse,
+        GetIdentityMailFromDomainAttributesError,
+    > {
+        match self.get_identity_mail_from_domain_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_identity_mail_from_domain_attributes"),
+        }
+    }
+    fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityNotificationAttributesResponse,
+        GetIdentityNotificationAttributesError,
+    > {
+        match self.get_identity_notification_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_identity_notification_attributes"),
+        }
+    }
+    fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> RusotoFuture<GetIdentityPoliciesResponse, GetIdentityPoliciesError> {
+        match self.get_identity_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_identity_policies"),
+        }
+    }
+    fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> RusotoFuture<
+        GetIdentityVerificationAttributesResponse,
+        GetIdentityVerificationAttributesError,
+    > {
+        match self.get_identity_verification_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_identity_verification_attributes"),
+        }
+    }
+    fn get_send_quota(&self) -> RusotoFuture<GetSendQuotaResponse, GetSendQuotaError> {
+        match self.get_send_quota {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSes has no handler for get_send_quota"),
+        }
+    }
+    fn get_send_statistics(
+        &self,
+    ) -> RusotoFuture<GetSendStatisticsResponse, GetSendStatisticsError> {
+        match self.get_send_statistics {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSes has no handler for get_send_statistics"),
+        }
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> RusotoFuture<GetTemplateResponse, GetTemplateError> {
+        match self.get_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for get_template"),
+        }
+    }
+    fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> RusotoFuture<ListConfigurationSetsResponse, ListConfigurationSetsError> {
+        match self.list_configuration_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_configuration_sets"),
+        }
+    }
+    fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> RusotoFuture<
+        ListCustomVerificationEmailTemplatesResponse,
+        ListCustomVerificationEmailTemplatesError,
+    > {
+        match self.list_custom_verification_email_templates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_custom_verification_email_templates"),
+        }
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        match self.list_identities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_identities"),
+        }
+    }
+    fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> RusotoFuture<ListIdentityPoliciesResponse, ListIdentityPoliciesError> {
+        match self.list_identity_policies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_identity_policies"),
+        }
+    }
+    fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> RusotoFuture<ListReceiptFiltersResponse, ListReceiptFiltersError> {
+        match self.list_receipt_filters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_receipt_filters"),
+        }
+    }
+    fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> RusotoFuture<ListReceiptRuleSetsResponse, ListReceiptRuleSetsError> {
+        match self.list_receipt_rule_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_receipt_rule_sets"),
+        }
+    }
+    fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> RusotoFuture<ListTemplatesResponse, ListTemplatesError> {
+        match self.list_templates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for list_templates"),
+        }
+    }
+    fn list_verified_email_addresses(
+        &self,
+    ) -> RusotoFuture<ListVerifiedEmailAddressesResponse, ListVerifiedEmailAddressesError> {
+        match self.list_verified_email_addresses {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSes has no handler for list_verified_email_addresses"),
+        }
+    }
+    fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> RusotoFuture<
+        PutConfigurationSetDeliveryOptionsResponse,
+        PutConfigurationSetDeliveryOptionsError,
+    > {
+        match self.put_configuration_set_delivery_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for put_configuration_set_delivery_options"),
+        }
+    }
+    fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> RusotoFuture<PutIdentityPolicyResponse, PutIdentityPolicyError> {
+        match self.put_identity_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for put_identity_policy"),
+        }
+    }
+    fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> RusotoFuture<ReorderReceiptRuleSetResponse, ReorderReceiptRuleSetError> {
+        match self.reorder_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for reorder_receipt_rule_set"),
+        }
+    }
+    fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> RusotoFuture<SendBounceResponse, SendBounceError> {
+        match self.send_bounce {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for send_bounce"),
+        }
+    }
+    fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> RusotoFuture<SendBulkTemplatedEmailResponse, SendBulkTemplatedEmailError> {
+        match self.send_bulk_templated_email {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for send_bulk_templated_email"),
+        }
+    }
+    fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> RusotoFuture<SendCustomVerificationEmailResponse, SendCustomVerificationEmailError> {
+        match self.send_custom_verification_email {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for send_custom_verification_email"),
+        }
+    }
+    fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> RusotoFuture<SendEmailResponse, SendEmailError> {
+        match self.send_email {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for send_email"),
+        }
+    }
+    fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> RusotoFuture<SendRawEmailResponse, SendRawEmailError> {
+        match self.send_raw_email {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for send_raw_email"),
+        }
+    }
+    fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> RusotoFuture<SendTemplatedEmailResponse, SendTemplatedEmailError> {
+        match self.send_templated_email {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for send_templated_email"),
+        }
+    }
+    fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> RusotoFuture<SetActiveReceiptRuleSetResponse, SetActiveReceiptRuleSetError> {
+        match self.set_active_receipt_rule_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for set_active_receipt_rule_set"),
+        }
+    }
+    fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> RusotoFuture<SetIdentityDkimEnabledResponse, SetIdentityDkimEnabledError> {
+        match self.set_identity_dkim_enabled {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for set_identity_dkim_enabled"),
+        }
+    }
+    fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        SetIdentityFeedbackForwardingEnabledError,
+    > {
+        match self.set_identity_feedback_forwarding_enabled {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for set_identity_feedback_forwarding_enabled"),
+        }
+    }
+    fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> RusotoFuture<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        SetIdentityHeadersInNotificationsEnabledError,
+    > {
+        match self.set_identity_headers_in_notifications_enabled {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSes has no handler for set_identity_headers_in_notifications_enabled")
             }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetReceiptRulePositionResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetReceiptRulePositionResponseDeserializer::deserialize(
-                        "SetReceiptRulePositionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        }
+    }
+    fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> RusotoFuture<SetIdentityMailFromDomainResponse, SetIdentityMailFromDomainError> {
+        match self.set_identity_mail_from_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for set_identity_mail_from_domain"),
+        }
+    }
+    fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> RusotoFuture<SetIdentityNotificationTopicResponse, SetIdentityNotificationTopicError> {
+        match self.set_identity_notification_topic {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for set_identity_notification_topic"),
+        }
+    }
+    fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> RusotoFuture<SetReceiptRulePositionResponse, SetReceiptRulePositionError> {
+        match self.set_receipt_rule_position {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for set_receipt_rule_position"),
+        }
     }
-
-    /// <p>Creates a preview of the MIME content of an email when provided with a template and a set of replacement data.</p> <p>You can execute this operation no more than once per second.</p>
     fn test_render_template(
         &self,
         input: TestRenderTemplateRequest,
     ) -> RusotoFuture<TestRenderTemplateResponse, TestRenderTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "TestRenderTemplate");
-        params.put("Version", "2010-12-01");
-        TestRenderTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TestRenderTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TestRenderTemplateResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TestRenderTemplateResponseDeserializer::deserialize(
-                        "TestRenderTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.test_render_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for test_render_template"),
+        }
     }
-
-    /// <p>Enables or disables email sending across your entire Amazon SES account in the current AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending across your Amazon SES account in a given AWS Region when reputation metrics (such as your bounce or complaint rates) reach certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
     fn update_account_sending_enabled(
         &self,
         input: UpdateAccountSendingEnabledRequest,
     ) -> RusotoFuture<(), UpdateAccountSendingEnabledError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateAccountSendingEnabled");
-        params.put("Version", "2010-12-01");
-        UpdateAccountSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateAccountSendingEnabledError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.update_account_sending_enabled {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_account_sending_enabled"),
+        }
     }
-
-    /// <p>Updates the event destination of a configuration set. Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>You can execute this operation no more than once per second.</p>
     fn update_configuration_set_event_destination(
         &self,
         input: UpdateConfigurationSetEventDestinationRequest,
@@ -13967,113 +19041,31 @@ impl Ses for SesClient {
         UpdateConfigurationSetEventDestinationResponse,
         UpdateConfigurationSetEventDestinationError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateConfigurationSetEventDestination");
-        params.put("Version", "2010-12-01");
-        UpdateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConfigurationSetEventDestinationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateConfigurationSetEventDestinationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        UpdateConfigurationSetEventDestinationResponseDeserializer::deserialize(
-                            "UpdateConfigurationSetEventDestinationResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_configuration_set_event_destination {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_configuration_set_event_destination"),
+        }
     }
-
-    /// <p>Enables or disables the publishing of reputation metrics for emails sent using a specific configuration set in a given AWS Region. Reputation metrics include bounce and complaint rates. These metrics are published to Amazon CloudWatch. By using CloudWatch, you can create alarms when bounce or complaint rates exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
     fn update_configuration_set_reputation_metrics_enabled(
         &self,
         input: UpdateConfigurationSetReputationMetricsEnabledRequest,
     ) -> RusotoFuture<(), UpdateConfigurationSetReputationMetricsEnabledError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateConfigurationSetReputationMetricsEnabled");
-        params.put("Version", "2010-12-01");
-        UpdateConfigurationSetReputationMetricsEnabledRequestSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(
-                        UpdateConfigurationSetReputationMetricsEnabledError::from_response(
-                            response,
-                        ),
-                    )
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.update_configuration_set_reputation_metrics_enabled {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockSes has no handler for update_configuration_set_reputation_metrics_enabled"
+            ),
+        }
     }
-
-    /// <p>Enables or disables email sending for messages sent using a specific configuration set in a given AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending for a configuration set when the reputation metrics for that configuration set (such as your bounce on complaint rate) exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
     fn update_configuration_set_sending_enabled(
         &self,
         input: UpdateConfigurationSetSendingEnabledRequest,
     ) -> RusotoFuture<(), UpdateConfigurationSetSendingEnabledError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateConfigurationSetSendingEnabled");
-        params.put("Version", "2010-12-01");
-        UpdateConfigurationSetSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConfigurationSetSendingEnabledError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.update_configuration_set_sending_enabled {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_configuration_set_sending_enabled"),
+        }
     }
-
-    /// <p>Modifies an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
     fn update_configuration_set_tracking_options(
         &self,
         input: UpdateConfigurationSetTrackingOptionsRequest,
@@ -14081,358 +19073,73 @@ impl Ses for SesClient {
         UpdateConfigurationSetTrackingOptionsResponse,
         UpdateConfigurationSetTrackingOptionsError,
     > {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateConfigurationSetTrackingOptions");
-        params.put("Version", "2010-12-01");
-        UpdateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateConfigurationSetTrackingOptionsError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateConfigurationSetTrackingOptionsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        UpdateConfigurationSetTrackingOptionsResponseDeserializer::deserialize(
-                            "UpdateConfigurationSetTrackingOptionsResult",
-                            &mut stack,
-                        )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_configuration_set_tracking_options {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_configuration_set_tracking_options"),
+        }
     }
-
-    /// <p>Updates an existing custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
     fn update_custom_verification_email_template(
         &self,
         input: UpdateCustomVerificationEmailTemplateRequest,
     ) -> RusotoFuture<(), UpdateCustomVerificationEmailTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateCustomVerificationEmailTemplate");
-        params.put("Version", "2010-12-01");
-        UpdateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateCustomVerificationEmailTemplateError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
-    }
-
-    /// <p>Updates a receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+        match self.update_custom_verification_email_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_custom_verification_email_template"),
+        }
+    }
     fn update_receipt_rule(
         &self,
         input: UpdateReceiptRuleRequest,
     ) -> RusotoFuture<UpdateReceiptRuleResponse, UpdateReceiptRuleError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateReceiptRule");
-        params.put("Version", "2010-12-01");
-        UpdateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateReceiptRuleError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateReceiptRuleResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateReceiptRuleResponseDeserializer::deserialize(
-                        "UpdateReceiptRuleResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_receipt_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_receipt_rule"),
+        }
     }
-
-    /// <p>Updates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
     fn update_template(
         &self,
         input: UpdateTemplateRequest,
     ) -> RusotoFuture<UpdateTemplateResponse, UpdateTemplateError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UpdateTemplate");
-        params.put("Version", "2010-12-01");
-        UpdateTemplateRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTemplateError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UpdateTemplateResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UpdateTemplateResponseDeserializer::deserialize(
-                        "UpdateTemplateResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.update_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for update_template"),
+        }
     }
-
-    /// <p>Returns a set of DKIM tokens for a domain identity.</p> <important> <p>When you execute the <code>VerifyDomainDkim</code> operation, the domain that you specify is added to the list of identities that are associated with your account. This is true even if you haven't already associated the domain with your account by using the <code>VerifyDomainIdentity</code> operation. However, you can't send email from the domain until you either successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-domains.html">verify it</a> or you successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">set up DKIM for it</a>.</p> </important> <p>You use the tokens that are generated by this operation to create CNAME records. When Amazon SES detects that you've added these records to the DNS configuration for a domain, you can start sending email from that domain. You can start sending email even if you haven't added the TXT record provided by the VerifyDomainIdentity operation to the DNS configuration for your domain. All email that you send from the domain is authenticated using DKIM.</p> <p>To create the CNAME records for DKIM authentication, use the following values:</p> <ul> <li> <p> <b>Name</b>: <i>token</i>._domainkey.<i>example.com</i> </p> </li> <li> <p> <b>Type</b>: CNAME</p> </li> <li> <p> <b>Value</b>: <i>token</i>.dkim.amazonses.com</p> </li> </ul> <p>In the preceding example, replace <i>token</i> with one of the tokens that are generated when you execute this operation. Replace <i>example.com</i> with your domain. Repeat this process for each token that's generated by this operation.</p> <p>You can execute this operation no more than once per second.</p>
     fn verify_domain_dkim(
         &self,
         input: VerifyDomainDkimRequest,
     ) -> RusotoFuture<VerifyDomainDkimResponse, VerifyDomainDkimError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "VerifyDomainDkim");
-        params.put("Version", "2010-12-01");
-        VerifyDomainDkimRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(VerifyDomainDkimError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = VerifyDomainDkimResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = VerifyDomainDkimResponseDeserializer::deserialize(
-                        "VerifyDomainDkimResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.verify_domain_dkim {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for verify_domain_dkim"),
+        }
     }
-
-    /// <p>Adds a domain to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. For more information about verifying domains, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second.</p>
     fn verify_domain_identity(
         &self,
         input: VerifyDomainIdentityRequest,
     ) -> RusotoFuture<VerifyDomainIdentityResponse, VerifyDomainIdentityError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "VerifyDomainIdentity");
-        params.put("Version", "2010-12-01");
-        VerifyDomainIdentityRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(VerifyDomainIdentityError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = VerifyDomainIdentityResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = VerifyDomainIdentityResponseDeserializer::deserialize(
-                        "VerifyDomainIdentityResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.verify_domain_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for verify_domain_identity"),
+        }
     }
-
-    /// <p>Deprecated. Use the <code>VerifyEmailIdentity</code> operation to verify a new email address.</p>
     fn verify_email_address(
         &self,
         input: VerifyEmailAddressRequest,
     ) -> RusotoFuture<(), VerifyEmailAddressError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "VerifyEmailAddress");
-        params.put("Version", "2010-12-01");
-        VerifyEmailAddressRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(VerifyEmailAddressError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.verify_email_address {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for verify_email_address"),
+        }
     }
-
-    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS region and attempts to verify it. As a result of executing this operation, a verification email is sent to the specified address.</p> <p>You can execute this operation no more than once per second.</p>
     fn verify_email_identity(
         &self,
         input: VerifyEmailIdentityRequest,
     ) -> RusotoFuture<VerifyEmailIdentityResponse, VerifyEmailIdentityError> {
-        let mut request = SignedRequest::new("POST", "email", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "VerifyEmailIdentity");
-        params.put("Version", "2010-12-01");
-        VerifyEmailIdentityRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(VerifyEmailIdentityError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = VerifyEmailIdentityResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = VerifyEmailIdentityResponseDeserializer::deserialize(
-                        "VerifyEmailIdentityResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.verify_email_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSes has no handler for verify_email_identity"),
+        }
     }
 }
 
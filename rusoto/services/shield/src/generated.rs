@@ -22,32 +22,63 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDRTLogBucketRequest {
     /// <p>The Amazon S3 bucket that contains your AWS WAF logs.</p>
     #[serde(rename = "LogBucket")]
     pub log_bucket: String,
 }
 
+impl Validate for AssociateDRTLogBucketRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_bucket"),
+            self.log_bucket.chars().count(),
+            Some(3),
+            Some(63),
+        );
+        errors.check_pattern(&field_path(path, "log_bucket"), &self.log_bucket, "^([a-z]|(\\d(?!\\d{0,2}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3})))([a-z\\d]|(\\.(?!(\\.|-)))|(-(?!\\.))){1,61}[a-z\\d]$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDRTLogBucketResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDRTRoleRequest {
     /// <p>The Amazon Resource Name (ARN) of the role the DRT will use to access your AWS account.</p> <p>Prior to making the <code>AssociateDRTRole</code> request, you must attach the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSShieldDRTAccessPolicy">AWSShieldDRTAccessPolicy</a> managed policy to this role. For more information see <a href=" https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_manage-attach-detach.html">Attaching and Detaching IAM Policies</a>.</p>
     #[serde(rename = "RoleArn")]
     pub role_arn: String,
 }
 
+impl Validate for AssociateDRTRoleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "^arn:aws:iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDRTRoleResponse {}
 
 /// <p>The details of a DDoS attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttackDetail {
     /// <p>List of counters that describe the attack for the specified time period.</p>
     #[serde(rename = "AttackCounters")]
@@ -85,7 +116,7 @@ pub struct AttackDetail {
 
 /// <p>Details of the described attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttackProperty {
     /// <p>The type of distributed denial of service (DDoS) event that was observed. <code>NETWORK</code> indicates layer 3 and layer 4 events and <code>APPLICATION</code> indicates layer 7 events.</p>
     #[serde(rename = "AttackLayer")]
@@ -111,7 +142,7 @@ pub struct AttackProperty {
 
 /// <p>Summarizes all DDoS attacks for a specified time period.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttackSummary {
     /// <p>The unique identifier (ID) of the attack.</p>
     #[serde(rename = "AttackId")]
@@ -137,7 +168,7 @@ pub struct AttackSummary {
 
 /// <p>Describes the attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttackVectorDescription {
     /// <p><p>The attack type. Valid values:</p> <ul> <li> <p>UDP<em>TRAFFIC</p> </li> <li> <p>UDP</em>FRAGMENT</p> </li> <li> <p>GENERIC<em>UDP</em>REFLECTION</p> </li> <li> <p>DNS<em>REFLECTION</p> </li> <li> <p>NTP</em>REFLECTION</p> </li> <li> <p>CHARGEN<em>REFLECTION</p> </li> <li> <p>SSDP</em>REFLECTION</p> </li> <li> <p>PORT<em>MAPPER</p> </li> <li> <p>RIP</em>REFLECTION</p> </li> <li> <p>SNMP<em>REFLECTION</p> </li> <li> <p>MSSQL</em>REFLECTION</p> </li> <li> <p>NET<em>BIOS</em>REFLECTION</p> </li> <li> <p>SYN<em>FLOOD</p> </li> <li> <p>ACK</em>FLOOD</p> </li> <li> <p>REQUEST<em>FLOOD</p> </li> <li> <p>HTTP</em>REFLECTION</p> </li> <li> <p>UDS<em>REFLECTION</p> </li> <li> <p>MEMCACHED</em>REFLECTION</p> </li> </ul></p>
     #[serde(rename = "VectorType")]
@@ -146,7 +177,7 @@ pub struct AttackVectorDescription {
 
 /// <p>A contributor to the attack and their contribution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Contributor {
     /// <p>The name of the contributor. This is dependent on the <code>AttackPropertyIdentifier</code>. For example, if the <code>AttackPropertyIdentifier</code> is <code>SOURCE_COUNTRY</code>, the <code>Name</code> could be <code>United States</code>.</p>
     #[serde(rename = "Name")]
@@ -159,6 +190,7 @@ pub struct Contributor {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateProtectionRequest {
     /// <p>Friendly name for the <code>Protection</code> you are creating.</p>
     #[serde(rename = "Name")]
@@ -168,8 +200,35 @@ pub struct CreateProtectionRequest {
     pub resource_arn: String,
 }
 
+impl Validate for CreateProtectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[ a-zA-Z0-9_\\\\.\\\\-]*",
+        );
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            "^arn:aws.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateProtectionResponse {
     /// <p>The unique identifier (ID) for the <a>Protection</a> object that is created.</p>
     #[serde(rename = "ProtectionId")]
@@ -178,39 +237,75 @@ pub struct CreateProtectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSubscriptionRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSubscriptionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteProtectionRequest {
     /// <p>The unique identifier (ID) for the <a>Protection</a> object to be deleted.</p>
     #[serde(rename = "ProtectionId")]
     pub protection_id: String,
 }
 
+impl Validate for DeleteProtectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "protection_id"),
+            self.protection_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "protection_id"),
+            &self.protection_id,
+            "[a-zA-Z0-9\\\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteProtectionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSubscriptionRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSubscriptionResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAttackRequest {
     /// <p>The unique identifier (ID) for the attack that to be described.</p>
     #[serde(rename = "AttackId")]
     pub attack_id: String,
 }
 
+impl Validate for DescribeAttackRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "attack_id"),
+            self.attack_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "attack_id"),
+            &self.attack_id,
+            "[a-zA-Z0-9\\\\-]*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAttackResponse {
     /// <p>The attack that is described.</p>
     #[serde(rename = "Attack")]
@@ -219,10 +314,11 @@ pub struct DescribeAttackResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDRTAccessRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDRTAccessResponse {
     /// <p>The list of Amazon S3 buckets accessed by the DRT.</p>
     #[serde(rename = "LogBucketList")]
@@ -235,10 +331,11 @@ pub struct DescribeDRTAccessResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEmergencyContactSettingsRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEmergencyContactSettingsResponse {
     /// <p>A list of email addresses that the DRT can use to contact you during a suspected attack.</p>
     #[serde(rename = "EmergencyContactList")]
@@ -247,6 +344,7 @@ pub struct DescribeEmergencyContactSettingsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeProtectionRequest {
     /// <p>The unique identifier (ID) for the <a>Protection</a> object that is described. When submitting the <code>DescribeProtection</code> request you must provide either the <code>ResourceArn</code> or the <code>ProtectionID</code>, but not both.</p>
     #[serde(rename = "ProtectionId")]
@@ -258,8 +356,35 @@ pub struct DescribeProtectionRequest {
     pub resource_arn: Option<String>,
 }
 
+impl Validate for DescribeProtectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.protection_id {
+            errors.check_length(
+                &field_path(path, "protection_id"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+            errors.check_pattern(
+                &field_path(path, "protection_id"),
+                &value,
+                "[a-zA-Z0-9\\\\-]*",
+            );
+        }
+        if let Some(ref value) = self.resource_arn {
+            errors.check_length(
+                &field_path(path, "resource_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "resource_arn"), &value, "^arn:aws.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeProtectionResponse {
     /// <p>The <a>Protection</a> object that is described.</p>
     #[serde(rename = "Protection")]
@@ -268,10 +393,11 @@ pub struct DescribeProtectionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSubscriptionRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSubscriptionResponse {
     /// <p>The AWS Shield Advanced subscription details for an account.</p>
     #[serde(rename = "Subscription")]
@@ -280,21 +406,35 @@ pub struct DescribeSubscriptionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDRTLogBucketRequest {
     /// <p>The Amazon S3 bucket that contains your AWS WAF logs.</p>
     #[serde(rename = "LogBucket")]
     pub log_bucket: String,
 }
 
+impl Validate for DisassociateDRTLogBucketRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_bucket"),
+            self.log_bucket.chars().count(),
+            Some(3),
+            Some(63),
+        );
+        errors.check_pattern(&field_path(path, "log_bucket"), &self.log_bucket, "^([a-z]|(\\d(?!\\d{0,2}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3})))([a-z\\d]|(\\.(?!(\\.|-)))|(-(?!\\.))){1,61}[a-z\\d]$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDRTLogBucketResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDRTRoleRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDRTRoleResponse {}
 
 /// <p>Contact information that the DRT can use to contact you during a suspected attack.</p>
@@ -305,11 +445,28 @@ pub struct EmergencyContact {
     pub email_address: String,
 }
 
+impl Validate for EmergencyContact {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "email_address"),
+            self.email_address.chars().count(),
+            Some(1),
+            Some(150),
+        );
+        errors.check_pattern(
+            &field_path(path, "email_address"),
+            &self.email_address,
+            "^\\S+@\\S+\\.\\S+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSubscriptionStateRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSubscriptionStateResponse {
     /// <p>The status of the subscription.</p>
     #[serde(rename = "SubscriptionState")]
@@ -318,7 +475,7 @@ pub struct GetSubscriptionStateResponse {
 
 /// <p>Specifies how many protections of a given type you can create.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Limit {
     /// <p>The maximum number of protections that can be created for the specified <code>Type</code>.</p>
     #[serde(rename = "Max")]
@@ -331,6 +488,7 @@ pub struct Limit {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAttacksRequest {
     /// <p>The end of the time period for the attacks. This is a <code>timestamp</code> type. The sample request above indicates a <code>number</code> type because the default used by WAF is Unix time in seconds. However any valid <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp format</a> is allowed. </p>
     #[serde(rename = "EndTime")]
@@ -354,8 +512,32 @@ pub struct ListAttacksRequest {
     pub start_time: Option<TimeRange>,
 }
 
+impl Validate for ListAttacksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(10000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^.*$");
+        }
+        if let Some(ref value) = self.resource_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_arns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+                errors.check_pattern(&item_path, &item, "^arn:aws.*");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAttacksResponse {
     /// <p>The attack information for the specified time range.</p>
     #[serde(rename = "AttackSummaries")]
@@ -368,6 +550,7 @@ pub struct ListAttacksResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListProtectionsRequest {
     /// <p>The maximum number of <a>Protection</a> objects to be returned. If this is left blank the first 20 results will be returned.</p> <p>This is a maximum value; it is possible that AWS WAF will return the results in smaller batches. That is, the number of <a>Protection</a> objects returned could be less than <code>MaxResults</code>, even if there are still more <a>Protection</a> objects yet to return. If there are more <a>Protection</a> objects to return, AWS WAF will always also return a <code>NextToken</code>.</p>
     #[serde(rename = "MaxResults")]
@@ -379,8 +562,25 @@ pub struct ListProtectionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListProtectionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(10000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "^.*$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListProtectionsResponse {
     /// <p>If you specify a value for <code>MaxResults</code> and you have more Protections than the value of MaxResults, AWS Shield Advanced returns a NextToken value in the response that allows you to list another group of Protections. For the second and subsequent ListProtections requests, specify the value of NextToken from the previous response to get information about another batch of Protections.</p> <p>AWS WAF might return the list of <a>Protection</a> objects in batches smaller than the number specified by MaxResults. If there are more <a>Protection</a> objects to return, AWS WAF will always also return a <code>NextToken</code>.</p>
     #[serde(rename = "NextToken")]
@@ -394,7 +594,7 @@ pub struct ListProtectionsResponse {
 
 /// <p>The mitigation applied to a DDoS attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Mitigation {
     /// <p>The name of the mitigation taken for this attack.</p>
     #[serde(rename = "MitigationName")]
@@ -404,7 +604,7 @@ pub struct Mitigation {
 
 /// <p>An object that represents a resource that is under DDoS protection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Protection {
     /// <p>The unique identifier (ID) of the protection.</p>
     #[serde(rename = "Id")]
@@ -422,7 +622,7 @@ pub struct Protection {
 
 /// <p>The attack information for the specified SubResource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubResourceSummary {
     /// <p>The list of attack types and associated counters.</p>
     #[serde(rename = "AttackVectors")]
@@ -444,7 +644,7 @@ pub struct SubResourceSummary {
 
 /// <p>Information about the AWS Shield Advanced subscription for an account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Subscription {
     /// <p>If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period.</p> <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
     #[serde(rename = "AutoRenew")]
@@ -470,7 +670,7 @@ pub struct Subscription {
 
 /// <p>A summary of information about the attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SummarizedAttackVector {
     /// <p>The list of counters that describe the details of the attack.</p>
     #[serde(rename = "VectorCounters")]
@@ -483,7 +683,7 @@ pub struct SummarizedAttackVector {
 
 /// <p>The counter that describes a DDoS attack.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SummarizedCounter {
     /// <p>The average value of the counter for a specified time period.</p>
     #[serde(rename = "Average")]
@@ -513,6 +713,7 @@ pub struct SummarizedCounter {
 
 /// <p>The time range.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TimeRange {
     /// <p>The start time, in Unix time in seconds. For more information see <a href="http://docs.aws.amazon.com/cli/latest/userguide/cli-using-param.html#parameter-types">timestamp</a>.</p>
     #[serde(rename = "FromInclusive")]
@@ -525,6 +726,7 @@ pub struct TimeRange {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateEmergencyContactSettingsRequest {
     /// <p>A list of email addresses that the DRT can use to contact you during a suspected attack.</p>
     #[serde(rename = "EmergencyContactList")]
@@ -532,11 +734,29 @@ pub struct UpdateEmergencyContactSettingsRequest {
     pub emergency_contact_list: Option<Vec<EmergencyContact>>,
 }
 
+impl Validate for UpdateEmergencyContactSettingsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.emergency_contact_list {
+            errors.check_length(
+                &field_path(path, "emergency_contact_list"),
+                value.len(),
+                None,
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "emergency_contact_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateEmergencyContactSettingsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSubscriptionRequest {
     /// <p>When you initally create a subscription, <code>AutoRenew</code> is set to <code>ENABLED</code>. If <code>ENABLED</code>, the subscription will be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. If the <code>UpdateSubscription</code> request does not included a value for <code>AutoRenew</code>, the existing value for <code>AutoRenew</code> remains unchanged.</p>
     #[serde(rename = "AutoRenew")]
@@ -545,7 +765,7 @@ pub struct UpdateSubscriptionRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSubscriptionResponse {}
 
 /// Errors returned by AssociateDRTLogBucket
@@ -611,7 +831,12 @@ impl AssociateDRTLogBucketError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -678,7 +903,12 @@ impl AssociateDRTRoleError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssociateDRTRoleError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -748,7 +978,12 @@ impl CreateProtectionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateProtectionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -794,7 +1029,12 @@ impl CreateSubscriptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -838,7 +1078,12 @@ impl DeleteProtectionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteProtectionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -885,7 +1130,12 @@ impl DeleteSubscriptionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteSubscriptionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -925,7 +1175,12 @@ impl DescribeAttackError {
                 "InternalErrorException" => {
                     return RusotoError::Service(DescribeAttackError::InternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -964,7 +1219,12 @@ impl DescribeDRTAccessError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeDRTAccessError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1009,7 +1269,12 @@ impl DescribeEmergencyContactSettingsError {
                         DescribeEmergencyContactSettingsError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1053,7 +1318,12 @@ impl DescribeProtectionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeProtectionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1095,7 +1365,12 @@ impl DescribeSubscriptionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1166,7 +1441,12 @@ impl DisassociateDRTLogBucketError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1223,7 +1503,12 @@ impl DisassociateDRTRoleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1259,7 +1544,12 @@ impl GetSubscriptionStateError {
                 "InternalErrorException" => {
                     return RusotoError::Service(GetSubscriptionStateError::InternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1302,7 +1592,12 @@ impl ListAttacksError {
                 "InvalidParameterException" => {
                     return RusotoError::Service(ListAttacksError::InvalidParameter(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1349,7 +1644,12 @@ impl ListProtectionsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(ListProtectionsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1409,7 +1709,12 @@ impl UpdateEmergencyContactSettingsError {
                         UpdateEmergencyContactSettingsError::ResourceNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1467,7 +1772,12 @@ impl UpdateSubscriptionError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(UpdateSubscriptionError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1593,233 +1903,596 @@ pub trait Shield {
         input: UpdateSubscriptionRequest,
     ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError>;
 }
-/// A client for the AWS Shield API.
-#[derive(Clone)]
-pub struct ShieldClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ShieldClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ShieldClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ShieldClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ShieldClient {
-        ShieldClient { client, region }
-    }
-}
-
-impl Shield for ShieldClient {
-    /// <p>Authorizes the DDoS Response team (DRT) to access the specified Amazon S3 bucket containing your AWS WAF logs. You can associate up to 10 Amazon S3 buckets with your subscription.</p> <p>To use the services of the DRT and make an <code>AssociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+const _: Option<&dyn Shield> = None;
+impl<'a, T: Shield + ?Sized> Shield for &'a T {
     fn associate_drt_log_bucket(
         &self,
         input: AssociateDRTLogBucketRequest,
     ) -> RusotoFuture<AssociateDRTLogBucketResponse, AssociateDRTLogBucketError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTLogBucket");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDRTLogBucketResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AssociateDRTLogBucketError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).associate_drt_log_bucket(input)
     }
-
-    /// <p>Authorizes the DDoS Response team (DRT), using the specified role, to access your AWS account to assist with DDoS attack mitigation during potential attacks. This enables the DRT to inspect your AWS WAF configuration and create or update AWS WAF rules and web ACLs.</p> <p>You can associate only one <code>RoleArn</code> with your subscription. If you submit an <code>AssociateDRTRole</code> request for an account that already has an associated role, the new <code>RoleArn</code> will replace the existing <code>RoleArn</code>. </p> <p>Prior to making the <code>AssociateDRTRole</code> request, you must attach the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSShieldDRTAccessPolicy">AWSShieldDRTAccessPolicy</a> managed policy to the role you will specify in the request. For more information see <a href=" https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_manage-attach-detach.html">Attaching and Detaching IAM Policies</a>. The role must also trust the service principal <code> drt.shield.amazonaws.com</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_principal.html">IAM JSON Policy Elements: Principal</a>.</p> <p>The DRT will have access only to your AWS WAF and Shield resources. By submitting this request, you authorize the DRT to inspect your AWS WAF and Shield configuration and create and update AWS WAF rules and web ACLs on your behalf. The DRT takes these actions only if explicitly authorized by you.</p> <p>You must have the <code>iam:PassRole</code> permission to make an <code>AssociateDRTRole</code> request. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p> <p>To use the services of the DRT and make an <code>AssociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
     fn associate_drt_role(
         &self,
         input: AssociateDRTRoleRequest,
     ) -> RusotoFuture<AssociateDRTRoleResponse, AssociateDRTRoleError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTRole");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDRTRoleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssociateDRTRoleError::from_response(response))),
-                )
-            }
-        })
+        (**self).associate_drt_role(input)
     }
-
-    /// <p>Enables AWS Shield Advanced for a specific AWS resource. The resource can be an Amazon CloudFront distribution, Elastic Load Balancing load balancer, AWS Global Accelerator accelerator, Elastic IP Address, or an Amazon Route 53 hosted zone.</p> <p>You can add protection to only a single resource with each CreateProtection request. If you want to add protection to multiple resources at once, use the <a href="https://console.aws.amazon.com/waf/">AWS WAF console</a>. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/getting-started-ddos.html">Getting Started with AWS Shield Advanced</a> and <a href="https://docs.aws.amazon.com/waf/latest/developerguide/configure-new-protection.html">Add AWS Shield Advanced Protection to more AWS Resources</a>.</p>
     fn create_protection(
         &self,
         input: CreateProtectionRequest,
     ) -> RusotoFuture<CreateProtectionResponse, CreateProtectionError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.CreateProtection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateProtectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateProtectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_protection(input)
     }
-
-    /// <p>Activates AWS Shield Advanced for an account.</p> <p>As part of this request you can specify <code>EmergencySettings</code> that automaticaly grant the DDoS response team (DRT) needed permissions to assist you during a suspected DDoS attack. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/authorize-DRT.html">Authorize the DDoS Response Team to Create Rules and Web ACLs on Your Behalf</a>.</p> <p>To use the services of the DRT, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p> <p>When you initally create a subscription, your subscription is set to be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. </p>
     fn create_subscription(
         &self,
     ) -> RusotoFuture<CreateSubscriptionResponse, CreateSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.CreateSubscription");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSubscriptionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSubscriptionError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_subscription()
     }
-
-    /// <p>Deletes an AWS Shield Advanced <a>Protection</a>.</p>
     fn delete_protection(
         &self,
         input: DeleteProtectionRequest,
     ) -> RusotoFuture<DeleteProtectionResponse, DeleteProtectionError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtection");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteProtectionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteProtectionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_protection(input)
     }
-
-    /// <p>Removes AWS Shield Advanced from an account. AWS Shield Advanced requires a 1-year subscription commitment. You cannot delete a subscription prior to the completion of that commitment. </p>
     fn delete_subscription(
         &self,
     ) -> RusotoFuture<DeleteSubscriptionResponse, DeleteSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.DeleteSubscription");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSubscriptionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSubscriptionError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_subscription()
     }
-
-    /// <p>Describes the details of a DDoS attack. </p>
     fn describe_attack(
         &self,
         input: DescribeAttackRequest,
     ) -> RusotoFuture<DescribeAttackResponse, DescribeAttackError> {
-        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSShield_20160616.DescribeAttack");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAttackResponse, _>()
-                }))
-            } else {
-                Box::new(
+        (**self).describe_attack(input)
+    }
+    fn describe_drt_access(
+        &self,
+    ) -> RusotoFuture<DescribeDRTAccessResponse, DescribeDRTAccessError> {
+        (**self).describe_drt_access()
+    }
+    fn describe_emergency_contact_settings(
+        &self,
+    ) -> RusotoFuture<DescribeEmergencyContactSettingsResponse, DescribeEmergencyContactSettingsError>
+    {
+        (**self).describe_emergency_contact_settings()
+    }
+    fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> RusotoFuture<DescribeProtectionResponse, DescribeProtectionError> {
+        (**self).describe_protection(input)
+    }
+    fn describe_subscription(
+        &self,
+    ) -> RusotoFuture<DescribeSubscriptionResponse, DescribeSubscriptionError> {
+        (**self).describe_subscription()
+    }
+    fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> RusotoFuture<DisassociateDRTLogBucketResponse, DisassociateDRTLogBucketError> {
+        (**self).disassociate_drt_log_bucket(input)
+    }
+    fn disassociate_drt_role(
+        &self,
+    ) -> RusotoFuture<DisassociateDRTRoleResponse, DisassociateDRTRoleError> {
+        (**self).disassociate_drt_role()
+    }
+    fn get_subscription_state(
+        &self,
+    ) -> RusotoFuture<GetSubscriptionStateResponse, GetSubscriptionStateError> {
+        (**self).get_subscription_state()
+    }
+    fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> RusotoFuture<ListAttacksResponse, ListAttacksError> {
+        (**self).list_attacks(input)
+    }
+    fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> RusotoFuture<ListProtectionsResponse, ListProtectionsError> {
+        (**self).list_protections(input)
+    }
+    fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> RusotoFuture<UpdateEmergencyContactSettingsResponse, UpdateEmergencyContactSettingsError>
+    {
+        (**self).update_emergency_contact_settings(input)
+    }
+    fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError> {
+        (**self).update_subscription(input)
+    }
+}
+impl<T: Shield + ?Sized> Shield for Box<T> {
+    fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> RusotoFuture<AssociateDRTLogBucketResponse, AssociateDRTLogBucketError> {
+        (**self).associate_drt_log_bucket(input)
+    }
+    fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> RusotoFuture<AssociateDRTRoleResponse, AssociateDRTRoleError> {
+        (**self).associate_drt_role(input)
+    }
+    fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> RusotoFuture<CreateProtectionResponse, CreateProtectionError> {
+        (**self).create_protection(input)
+    }
+    fn create_subscription(
+        &self,
+    ) -> RusotoFuture<CreateSubscriptionResponse, CreateSubscriptionError> {
+        (**self).create_subscription()
+    }
+    fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> RusotoFuture<DeleteProtectionResponse, DeleteProtectionError> {
+        (**self).delete_protection(input)
+    }
+    fn delete_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteSubscriptionResponse, DeleteSubscriptionError> {
+        (**self).delete_subscription()
+    }
+    fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> RusotoFuture<DescribeAttackResponse, DescribeAttackError> {
+        (**self).describe_attack(input)
+    }
+    fn describe_drt_access(
+        &self,
+    ) -> RusotoFuture<DescribeDRTAccessResponse, DescribeDRTAccessError> {
+        (**self).describe_drt_access()
+    }
+    fn describe_emergency_contact_settings(
+        &self,
+    ) -> RusotoFuture<DescribeEmergencyContactSettingsResponse, DescribeEmergencyContactSettingsError>
+    {
+        (**self).describe_emergency_contact_settings()
+    }
+    fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> RusotoFuture<DescribeProtectionResponse, DescribeProtectionError> {
+        (**self).describe_protection(input)
+    }
+    fn describe_subscription(
+        &self,
+    ) -> RusotoFuture<DescribeSubscriptionResponse, DescribeSubscriptionError> {
+        (**self).describe_subscription()
+    }
+    fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> RusotoFuture<DisassociateDRTLogBucketResponse, DisassociateDRTLogBucketError> {
+        (**self).disassociate_drt_log_bucket(input)
+    }
+    fn disassociate_drt_role(
+        &self,
+    ) -> RusotoFuture<DisassociateDRTRoleResponse, DisassociateDRTRoleError> {
+        (**self).disassociate_drt_role()
+    }
+    fn get_subscription_state(
+        &self,
+    ) -> RusotoFuture<GetSubscriptionStateResponse, GetSubscriptionStateError> {
+        (**self).get_subscription_state()
+    }
+    fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> RusotoFuture<ListAttacksResponse, ListAttacksError> {
+        (**self).list_attacks(input)
+    }
+    fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> RusotoFuture<ListProtectionsResponse, ListProtectionsError> {
+        (**self).list_protections(input)
+    }
+    fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> RusotoFuture<UpdateEmergencyContactSettingsResponse, UpdateEmergencyContactSettingsError>
+    {
+        (**self).update_emergency_contact_settings(input)
+    }
+    fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError> {
+        (**self).update_subscription(input)
+    }
+}
+impl<T: Shield + ?Sized> Shield for ::std::sync::Arc<T> {
+    fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> RusotoFuture<AssociateDRTLogBucketResponse, AssociateDRTLogBucketError> {
+        (**self).associate_drt_log_bucket(input)
+    }
+    fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> RusotoFuture<AssociateDRTRoleResponse, AssociateDRTRoleError> {
+        (**self).associate_drt_role(input)
+    }
+    fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> RusotoFuture<CreateProtectionResponse, CreateProtectionError> {
+        (**self).create_protection(input)
+    }
+    fn create_subscription(
+        &self,
+    ) -> RusotoFuture<CreateSubscriptionResponse, CreateSubscriptionError> {
+        (**self).create_subscription()
+    }
+    fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> RusotoFuture<DeleteProtectionResponse, DeleteProtectionError> {
+        (**self).delete_protection(input)
+    }
+    fn delete_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteSubscriptionResponse, DeleteSubscriptionError> {
+        (**self).delete_subscription()
+    }
+    fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> RusotoFuture<DescribeAttackResponse, DescribeAttackError> {
+        (**self).describe_attack(input)
+    }
+    fn describe_drt_access(
+        &self,
+    ) -> RusotoFuture<DescribeDRTAccessResponse, DescribeDRTAccessError> {
+        (**self).describe_drt_access()
+    }
+    fn describe_emergency_contact_settings(
+        &self,
+    ) -> RusotoFuture<DescribeEmergencyContactSettingsResponse, DescribeEmergencyContactSettingsError>
+    {
+        (**self).describe_emergency_contact_settings()
+    }
+    fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> RusotoFuture<DescribeProtectionResponse, DescribeProtectionError> {
+        (**self).describe_protection(input)
+    }
+    fn describe_subscription(
+        &self,
+    ) -> RusotoFuture<DescribeSubscriptionResponse, DescribeSubscriptionError> {
+        (**self).describe_subscription()
+    }
+    fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> RusotoFuture<DisassociateDRTLogBucketResponse, DisassociateDRTLogBucketError> {
+        (**self).disassociate_drt_log_bucket(input)
+    }
+    fn disassociate_drt_role(
+        &self,
+    ) -> RusotoFuture<DisassociateDRTRoleResponse, DisassociateDRTRoleError> {
+        (**self).disassociate_drt_role()
+    }
+    fn get_subscription_state(
+        &self,
+    ) -> RusotoFuture<GetSubscriptionStateResponse, GetSubscriptionStateError> {
+        (**self).get_subscription_state()
+    }
+    fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> RusotoFuture<ListAttacksResponse, ListAttacksError> {
+        (**self).list_attacks(input)
+    }
+    fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> RusotoFuture<ListProtectionsResponse, ListProtectionsError> {
+        (**self).list_protections(input)
+    }
+    fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> RusotoFuture<UpdateEmergencyContactSettingsResponse, UpdateEmergencyContactSettingsError>
+    {
+        (**self).update_emergency_contact_settings(input)
+    }
+    fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError> {
+        (**self).update_subscription(input)
+    }
+}
+/// A client for the AWS Shield API.
+#[derive(Clone)]
+pub struct ShieldClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ShieldClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ShieldClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ShieldClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ShieldClient {
+        ShieldClient { client, region }
+    }
+}
+
+impl Shield for ShieldClient {
+    /// <p>Authorizes the DDoS Response team (DRT) to access the specified Amazon S3 bucket containing your AWS WAF logs. You can associate up to 10 Amazon S3 buckets with your subscription.</p> <p>To use the services of the DRT and make an <code>AssociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+    fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> RusotoFuture<AssociateDRTLogBucketResponse, AssociateDRTLogBucketError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("AssociateDRTLogBucket");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTLogBucket");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateDRTLogBucketResponse, _>(
+                    response,
+                    "AssociateDRTLogBucket",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AssociateDRTLogBucketError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Authorizes the DDoS Response team (DRT), using the specified role, to access your AWS account to assist with DDoS attack mitigation during potential attacks. This enables the DRT to inspect your AWS WAF configuration and create or update AWS WAF rules and web ACLs.</p> <p>You can associate only one <code>RoleArn</code> with your subscription. If you submit an <code>AssociateDRTRole</code> request for an account that already has an associated role, the new <code>RoleArn</code> will replace the existing <code>RoleArn</code>. </p> <p>Prior to making the <code>AssociateDRTRole</code> request, you must attach the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSShieldDRTAccessPolicy">AWSShieldDRTAccessPolicy</a> managed policy to the role you will specify in the request. For more information see <a href=" https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_manage-attach-detach.html">Attaching and Detaching IAM Policies</a>. The role must also trust the service principal <code> drt.shield.amazonaws.com</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_principal.html">IAM JSON Policy Elements: Principal</a>.</p> <p>The DRT will have access only to your AWS WAF and Shield resources. By submitting this request, you authorize the DRT to inspect your AWS WAF and Shield configuration and create and update AWS WAF rules and web ACLs on your behalf. The DRT takes these actions only if explicitly authorized by you.</p> <p>You must have the <code>iam:PassRole</code> permission to make an <code>AssociateDRTRole</code> request. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p> <p>To use the services of the DRT and make an <code>AssociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+    fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> RusotoFuture<AssociateDRTRoleResponse, AssociateDRTRoleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("AssociateDRTRole");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTRole");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateDRTRoleResponse, _>(
+                    response,
+                    "AssociateDRTRole",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateDRTRoleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Enables AWS Shield Advanced for a specific AWS resource. The resource can be an Amazon CloudFront distribution, Elastic Load Balancing load balancer, AWS Global Accelerator accelerator, Elastic IP Address, or an Amazon Route 53 hosted zone.</p> <p>You can add protection to only a single resource with each CreateProtection request. If you want to add protection to multiple resources at once, use the <a href="https://console.aws.amazon.com/waf/">AWS WAF console</a>. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/getting-started-ddos.html">Getting Started with AWS Shield Advanced</a> and <a href="https://docs.aws.amazon.com/waf/latest/developerguide/configure-new-protection.html">Add AWS Shield Advanced Protection to more AWS Resources</a>.</p>
+    fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> RusotoFuture<CreateProtectionResponse, CreateProtectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("CreateProtection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.CreateProtection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateProtectionResponse, _>(
+                    response,
+                    "CreateProtection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateProtectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Activates AWS Shield Advanced for an account.</p> <p>As part of this request you can specify <code>EmergencySettings</code> that automaticaly grant the DDoS response team (DRT) needed permissions to assist you during a suspected DDoS attack. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/authorize-DRT.html">Authorize the DDoS Response Team to Create Rules and Web ACLs on Your Behalf</a>.</p> <p>To use the services of the DRT, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p> <p>When you initally create a subscription, your subscription is set to be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. </p>
+    fn create_subscription(
+        &self,
+    ) -> RusotoFuture<CreateSubscriptionResponse, CreateSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("CreateSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.CreateSubscription");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSubscriptionResponse, _>(
+                    response,
+                    "CreateSubscription",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSubscriptionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an AWS Shield Advanced <a>Protection</a>.</p>
+    fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> RusotoFuture<DeleteProtectionResponse, DeleteProtectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DeleteProtection");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtection");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteProtectionResponse, _>(
+                    response,
+                    "DeleteProtection",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteProtectionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes AWS Shield Advanced from an account. AWS Shield Advanced requires a 1-year subscription commitment. You cannot delete a subscription prior to the completion of that commitment. </p>
+    fn delete_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteSubscriptionResponse, DeleteSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DeleteSubscription");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.DeleteSubscription");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSubscriptionResponse, _>(
+                    response,
+                    "DeleteSubscription",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSubscriptionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the details of a DDoS attack. </p>
+    fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> RusotoFuture<DescribeAttackResponse, DescribeAttackError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeAttack");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSShield_20160616.DescribeAttack");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAttackResponse, _>(
+                    response,
+                    "DescribeAttack",
+                )
+            } else {
+                Box::new(
                     response
                         .buffer()
                         .from_err()
@@ -1834,6 +2507,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> RusotoFuture<DescribeDRTAccessResponse, DescribeDRTAccessError> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeDRTAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeDRTAccess");
@@ -1841,10 +2515,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDRTAccessResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeDRTAccessResponse, _>(
+                    response,
+                    "DescribeDRTAccess",
+                )
             } else {
                 Box::new(
                     response
@@ -1862,6 +2536,7 @@ impl Shield for ShieldClient {
     ) -> RusotoFuture<DescribeEmergencyContactSettingsResponse, DescribeEmergencyContactSettingsError>
     {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeEmergencyContactSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1872,10 +2547,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEmergencyContactSettingsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeEmergencyContactSettingsResponse, _>(
+                    response,
+                    "DescribeEmergencyContactSettings",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeEmergencyContactSettingsError::from_response(
@@ -1891,7 +2566,16 @@ impl Shield for ShieldClient {
         &self,
         input: DescribeProtectionRequest,
     ) -> RusotoFuture<DescribeProtectionResponse, DescribeProtectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeProtection");
@@ -1900,10 +2584,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeProtectionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeProtectionResponse, _>(
+                    response,
+                    "DescribeProtection",
+                )
             } else {
                 Box::new(
                     response
@@ -1920,6 +2604,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> RusotoFuture<DescribeSubscriptionResponse, DescribeSubscriptionError> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DescribeSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeSubscription");
@@ -1927,10 +2612,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSubscriptionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeSubscriptionResponse, _>(
+                    response,
+                    "DescribeSubscription",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1946,7 +2631,16 @@ impl Shield for ShieldClient {
         &self,
         input: DisassociateDRTLogBucketRequest,
     ) -> RusotoFuture<DisassociateDRTLogBucketResponse, DisassociateDRTLogBucketError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DisassociateDRTLogBucket");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1958,10 +2652,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateDRTLogBucketResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateDRTLogBucketResponse, _>(
+                    response,
+                    "DisassociateDRTLogBucket",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DisassociateDRTLogBucketError::from_response(response))
@@ -1975,6 +2669,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> RusotoFuture<DisassociateDRTRoleResponse, DisassociateDRTRoleError> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("DisassociateDRTRole");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateDRTRole");
@@ -1982,10 +2677,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateDRTRoleResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DisassociateDRTRoleResponse, _>(
+                    response,
+                    "DisassociateDRTRole",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2001,6 +2696,7 @@ impl Shield for ShieldClient {
         &self,
     ) -> RusotoFuture<GetSubscriptionStateResponse, GetSubscriptionStateError> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("GetSubscriptionState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.GetSubscriptionState");
@@ -2008,10 +2704,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSubscriptionStateResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetSubscriptionStateResponse, _>(
+                    response,
+                    "GetSubscriptionState",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2027,7 +2723,16 @@ impl Shield for ShieldClient {
         &self,
         input: ListAttacksRequest,
     ) -> RusotoFuture<ListAttacksResponse, ListAttacksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("ListAttacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.ListAttacks");
@@ -2036,10 +2741,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAttacksResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListAttacksResponse, _>(
+                    response,
+                    "ListAttacks",
+                )
             } else {
                 Box::new(
                     response
@@ -2056,7 +2761,16 @@ impl Shield for ShieldClient {
         &self,
         input: ListProtectionsRequest,
     ) -> RusotoFuture<ListProtectionsResponse, ListProtectionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("ListProtections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.ListProtections");
@@ -2065,10 +2779,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListProtectionsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListProtectionsResponse, _>(
+                    response,
+                    "ListProtections",
+                )
             } else {
                 Box::new(
                     response
@@ -2086,7 +2800,16 @@ impl Shield for ShieldClient {
         input: UpdateEmergencyContactSettingsRequest,
     ) -> RusotoFuture<UpdateEmergencyContactSettingsResponse, UpdateEmergencyContactSettingsError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("UpdateEmergencyContactSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2098,10 +2821,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateEmergencyContactSettingsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateEmergencyContactSettingsResponse, _>(
+                    response,
+                    "UpdateEmergencyContactSettings",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateEmergencyContactSettingsError::from_response(response))
@@ -2116,6 +2839,7 @@ impl Shield for ShieldClient {
         input: UpdateSubscriptionRequest,
     ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError> {
         let mut request = SignedRequest::new("POST", "shield", &self.region, "/");
+        request.set_operation("UpdateSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSShield_20160616.UpdateSubscription");
@@ -2124,10 +2848,10 @@ impl Shield for ShieldClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSubscriptionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateSubscriptionResponse, _>(
+                    response,
+                    "UpdateSubscription",
+                )
             } else {
                 Box::new(
                     response
@@ -2139,3 +2863,618 @@ impl Shield for ShieldClient {
         })
     }
 }
+
+/// A mock of the AWS Shield API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockShield {
+    associate_drt_log_bucket: Option<
+        Box<
+            dyn Fn(
+                    AssociateDRTLogBucketRequest,
+                ) -> ::std::result::Result<
+                    AssociateDRTLogBucketResponse,
+                    RusotoError<AssociateDRTLogBucketError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_drt_role: Option<
+        Box<
+            dyn Fn(
+                    AssociateDRTRoleRequest,
+                ) -> ::std::result::Result<
+                    AssociateDRTRoleResponse,
+                    RusotoError<AssociateDRTRoleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_protection: Option<
+        Box<
+            dyn Fn(
+                    CreateProtectionRequest,
+                ) -> ::std::result::Result<
+                    CreateProtectionResponse,
+                    RusotoError<CreateProtectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_subscription: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    CreateSubscriptionResponse,
+                    RusotoError<CreateSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_protection: Option<
+        Box<
+            dyn Fn(
+                    DeleteProtectionRequest,
+                ) -> ::std::result::Result<
+                    DeleteProtectionResponse,
+                    RusotoError<DeleteProtectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_subscription: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DeleteSubscriptionResponse,
+                    RusotoError<DeleteSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_attack: Option<
+        Box<
+            dyn Fn(
+                    DescribeAttackRequest,
+                ) -> ::std::result::Result<
+                    DescribeAttackResponse,
+                    RusotoError<DescribeAttackError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_drt_access: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeDRTAccessResponse,
+                    RusotoError<DescribeDRTAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_emergency_contact_settings: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeEmergencyContactSettingsResponse,
+                    RusotoError<DescribeEmergencyContactSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_protection: Option<
+        Box<
+            dyn Fn(
+                    DescribeProtectionRequest,
+                ) -> ::std::result::Result<
+                    DescribeProtectionResponse,
+                    RusotoError<DescribeProtectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_subscription: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeSubscriptionResponse,
+                    RusotoError<DescribeSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_drt_log_bucket: Option<
+        Box<
+            dyn Fn(
+                    DisassociateDRTLogBucketRequest,
+                ) -> ::std::result::Result<
+                    DisassociateDRTLogBucketResponse,
+                    RusotoError<DisassociateDRTLogBucketError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_drt_role: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DisassociateDRTRoleResponse,
+                    RusotoError<DisassociateDRTRoleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_subscription_state: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetSubscriptionStateResponse,
+                    RusotoError<GetSubscriptionStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_attacks: Option<
+        Box<
+            dyn Fn(
+                    ListAttacksRequest,
+                )
+                    -> ::std::result::Result<ListAttacksResponse, RusotoError<ListAttacksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_protections: Option<
+        Box<
+            dyn Fn(
+                    ListProtectionsRequest,
+                ) -> ::std::result::Result<
+                    ListProtectionsResponse,
+                    RusotoError<ListProtectionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_emergency_contact_settings: Option<
+        Box<
+            dyn Fn(
+                    UpdateEmergencyContactSettingsRequest,
+                ) -> ::std::result::Result<
+                    UpdateEmergencyContactSettingsResponse,
+                    RusotoError<UpdateEmergencyContactSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_subscription: Option<
+        Box<
+            dyn Fn(
+                    UpdateSubscriptionRequest,
+                ) -> ::std::result::Result<
+                    UpdateSubscriptionResponse,
+                    RusotoError<UpdateSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockShield {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockShield {
+        Default::default()
+    }
+
+    /// Answers `associate_drt_log_bucket` with what `handler` returns for its input.
+    pub fn with_associate_drt_log_bucket<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                AssociateDRTLogBucketRequest,
+            ) -> ::std::result::Result<
+                AssociateDRTLogBucketResponse,
+                RusotoError<AssociateDRTLogBucketError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_drt_log_bucket = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_drt_role` with what `handler` returns for its input.
+    pub fn with_associate_drt_role<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                AssociateDRTRoleRequest,
+            ) -> ::std::result::Result<
+                AssociateDRTRoleResponse,
+                RusotoError<AssociateDRTRoleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_drt_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_protection` with what `handler` returns for its input.
+    pub fn with_create_protection<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                CreateProtectionRequest,
+            ) -> ::std::result::Result<
+                CreateProtectionResponse,
+                RusotoError<CreateProtectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_protection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_subscription` with what `handler` returns.
+    pub fn with_create_subscription<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                CreateSubscriptionResponse,
+                RusotoError<CreateSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_protection` with what `handler` returns for its input.
+    pub fn with_delete_protection<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                DeleteProtectionRequest,
+            ) -> ::std::result::Result<
+                DeleteProtectionResponse,
+                RusotoError<DeleteProtectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_protection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_subscription` with what `handler` returns.
+    pub fn with_delete_subscription<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                DeleteSubscriptionResponse,
+                RusotoError<DeleteSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_attack` with what `handler` returns for its input.
+    pub fn with_describe_attack<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                DescribeAttackRequest,
+            )
+                -> ::std::result::Result<DescribeAttackResponse, RusotoError<DescribeAttackError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_attack = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_drt_access` with what `handler` returns.
+    pub fn with_describe_drt_access<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeDRTAccessResponse,
+                RusotoError<DescribeDRTAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_drt_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_emergency_contact_settings` with what `handler` returns.
+    pub fn with_describe_emergency_contact_settings<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeEmergencyContactSettingsResponse,
+                RusotoError<DescribeEmergencyContactSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_emergency_contact_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_protection` with what `handler` returns for its input.
+    pub fn with_describe_protection<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                DescribeProtectionRequest,
+            ) -> ::std::result::Result<
+                DescribeProtectionResponse,
+                RusotoError<DescribeProtectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_protection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_subscription` with what `handler` returns.
+    pub fn with_describe_subscription<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                DescribeSubscriptionResponse,
+                RusotoError<DescribeSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_drt_log_bucket` with what `handler` returns for its input.
+    pub fn with_disassociate_drt_log_bucket<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                DisassociateDRTLogBucketRequest,
+            ) -> ::std::result::Result<
+                DisassociateDRTLogBucketResponse,
+                RusotoError<DisassociateDRTLogBucketError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_drt_log_bucket = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_drt_role` with what `handler` returns.
+    pub fn with_disassociate_drt_role<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                DisassociateDRTRoleResponse,
+                RusotoError<DisassociateDRTRoleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_drt_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_subscription_state` with what `handler` returns.
+    pub fn with_get_subscription_state<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn() -> ::std::result::Result<
+                GetSubscriptionStateResponse,
+                RusotoError<GetSubscriptionStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_subscription_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_attacks` with what `handler` returns for its input.
+    pub fn with_list_attacks<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                ListAttacksRequest,
+            )
+                -> ::std::result::Result<ListAttacksResponse, RusotoError<ListAttacksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_attacks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_protections` with what `handler` returns for its input.
+    pub fn with_list_protections<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                ListProtectionsRequest,
+            )
+                -> ::std::result::Result<ListProtectionsResponse, RusotoError<ListProtectionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_protections = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_emergency_contact_settings` with what `handler` returns for its input.
+    pub fn with_update_emergency_contact_settings<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                UpdateEmergencyContactSettingsRequest,
+            ) -> ::std::result::Result<
+                UpdateEmergencyContactSettingsResponse,
+                RusotoError<UpdateEmergencyContactSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_emergency_contact_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_subscription` with what `handler` returns for its input.
+    pub fn with_update_subscription<F>(mut self, handler: F) -> MockShield
+    where
+        F: Fn(
+                UpdateSubscriptionRequest,
+            ) -> ::std::result::Result<
+                UpdateSubscriptionResponse,
+                RusotoError<UpdateSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_subscription = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Shield for MockShield {
+    fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> RusotoFuture<AssociateDRTLogBucketResponse, AssociateDRTLogBucketError> {
+        match self.associate_drt_log_bucket {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for associate_drt_log_bucket"),
+        }
+    }
+    fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> RusotoFuture<AssociateDRTRoleResponse, AssociateDRTRoleError> {
+        match self.associate_drt_role {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for associate_drt_role"),
+        }
+    }
+    fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> RusotoFuture<CreateProtectionResponse, CreateProtectionError> {
+        match self.create_protection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for create_protection"),
+        }
+    }
+    fn create_subscription(
+        &self,
+    ) -> RusotoFuture<CreateSubscriptionResponse, CreateSubscriptionError> {
+        match self.create_subscription {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for create_subscription"),
+        }
+    }
+    fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> RusotoFuture<DeleteProtectionResponse, DeleteProtectionError> {
+        match self.delete_protection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for delete_protection"),
+        }
+    }
+    fn delete_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteSubscriptionResponse, DeleteSubscriptionError> {
+        match self.delete_subscription {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for delete_subscription"),
+        }
+    }
+    fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> RusotoFuture<DescribeAttackResponse, DescribeAttackError> {
+        match self.describe_attack {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for describe_attack"),
+        }
+    }
+    fn describe_drt_access(
+        &self,
+    ) -> RusotoFuture<DescribeDRTAccessResponse, DescribeDRTAccessError> {
+        match self.describe_drt_access {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for describe_drt_access"),
+        }
+    }
+    fn describe_emergency_contact_settings(
+        &self,
+    ) -> RusotoFuture<DescribeEmergencyContactSettingsResponse, DescribeEmergencyContactSettingsError>
+    {
+        match self.describe_emergency_contact_settings {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for describe_emergency_contact_settings"),
+        }
+    }
+    fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> RusotoFuture<DescribeProtectionResponse, DescribeProtectionError> {
+        match self.describe_protection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for describe_protection"),
+        }
+    }
+    fn describe_subscription(
+        &self,
+    ) -> RusotoFuture<DescribeSubscriptionResponse, DescribeSubscriptionError> {
+        match self.describe_subscription {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for describe_subscription"),
+        }
+    }
+    fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> RusotoFuture<DisassociateDRTLogBucketResponse, DisassociateDRTLogBucketError> {
+        match self.disassociate_drt_log_bucket {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for disassociate_drt_log_bucket"),
+        }
+    }
+    fn disassociate_drt_role(
+        &self,
+    ) -> RusotoFuture<DisassociateDRTRoleResponse, DisassociateDRTRoleError> {
+        match self.disassociate_drt_role {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for disassociate_drt_role"),
+        }
+    }
+    fn get_subscription_state(
+        &self,
+    ) -> RusotoFuture<GetSubscriptionStateResponse, GetSubscriptionStateError> {
+        match self.get_subscription_state {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockShield has no handler for get_subscription_state"),
+        }
+    }
+    fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> RusotoFuture<ListAttacksResponse, ListAttacksError> {
+        match self.list_attacks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for list_attacks"),
+        }
+    }
+    fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> RusotoFuture<ListProtectionsResponse, ListProtectionsError> {
+        match self.list_protections {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for list_protections"),
+        }
+    }
+    fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> RusotoFuture<UpdateEmergencyContactSettingsResponse, UpdateEmergencyContactSettingsError>
+    {
+        match self.update_emergency_contact_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for update_emergency_contact_settings"),
+        }
+    }
+    fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionResponse, UpdateSubscriptionError> {
+        match self.update_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockShield has no handler for update_subscription"),
+        }
+    }
+}
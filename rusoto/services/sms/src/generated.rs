@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Information about the application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AppSummary {
     /// <p>Unique ID of the application.</p>
     #[serde(rename = "appId")]
@@ -95,7 +96,7 @@ pub struct AppSummary {
 
 /// <p>Represents a connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Connector {
     /// <p>The time the connector was associated.</p>
     #[serde(rename = "associatedOn")]
@@ -140,6 +141,7 @@ pub struct Connector {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAppRequest {
     /// <p>A unique, case-sensitive identifier you provide to ensure idempotency of application creation.</p>
     #[serde(rename = "clientToken")]
@@ -168,7 +170,7 @@ pub struct CreateAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAppResponse {
     /// <p>Summary description of the application.</p>
     #[serde(rename = "appSummary")]
@@ -185,6 +187,7 @@ pub struct CreateAppResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateReplicationJobRequest {
     /// <p>The description of the replication job.</p>
     #[serde(rename = "description")]
@@ -227,7 +230,7 @@ pub struct CreateReplicationJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateReplicationJobResponse {
     /// <p>The unique identifier of the replication job.</p>
     #[serde(rename = "replicationJobId")]
@@ -236,6 +239,7 @@ pub struct CreateReplicationJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAppLaunchConfigurationRequest {
     /// <p>ID of the application associated with the launch configuration.</p>
     #[serde(rename = "appId")]
@@ -244,10 +248,11 @@ pub struct DeleteAppLaunchConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAppLaunchConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAppReplicationConfigurationRequest {
     /// <p>ID of the application associated with the replication configuration.</p>
     #[serde(rename = "appId")]
@@ -256,10 +261,11 @@ pub struct DeleteAppReplicationConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAppReplicationConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAppRequest {
     /// <p>ID of the application to delete.</p>
     #[serde(rename = "appId")]
@@ -276,10 +282,11 @@ pub struct DeleteAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAppResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteReplicationJobRequest {
     /// <p>The identifier of the replication job.</p>
     #[serde(rename = "replicationJobId")]
@@ -287,17 +294,19 @@ pub struct DeleteReplicationJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteReplicationJobResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteServerCatalogRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteServerCatalogResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateConnectorRequest {
     /// <p>The identifier of the connector.</p>
     #[serde(rename = "connectorId")]
@@ -305,10 +314,11 @@ pub struct DisassociateConnectorRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateConnectorResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateChangeSetRequest {
     /// <p>ID of the application associated with the change set.</p>
     #[serde(rename = "appId")]
@@ -321,7 +331,7 @@ pub struct GenerateChangeSetRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenerateChangeSetResponse {
     /// <p>Location of the Amazon S3 object.</p>
     #[serde(rename = "s3Location")]
@@ -330,6 +340,7 @@ pub struct GenerateChangeSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GenerateTemplateRequest {
     /// <p>ID of the application associated with the Amazon CloudFormation template.</p>
     #[serde(rename = "appId")]
@@ -342,7 +353,7 @@ pub struct GenerateTemplateRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GenerateTemplateResponse {
     /// <p>Location of the Amazon S3 object.</p>
     #[serde(rename = "s3Location")]
@@ -351,6 +362,7 @@ pub struct GenerateTemplateResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAppLaunchConfigurationRequest {
     /// <p>ID of the application launch configuration.</p>
     #[serde(rename = "appId")]
@@ -359,7 +371,7 @@ pub struct GetAppLaunchConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAppLaunchConfigurationResponse {
     /// <p>ID of the application associated with the launch configuration.</p>
     #[serde(rename = "appId")]
@@ -376,6 +388,7 @@ pub struct GetAppLaunchConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAppReplicationConfigurationRequest {
     /// <p>ID of the application associated with the replication configuration.</p>
     #[serde(rename = "appId")]
@@ -384,7 +397,7 @@ pub struct GetAppReplicationConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAppReplicationConfigurationResponse {
     /// <p>Replication configurations associated with server groups in this application.</p>
     #[serde(rename = "serverGroupReplicationConfigurations")]
@@ -393,6 +406,7 @@ pub struct GetAppReplicationConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAppRequest {
     /// <p>ID of the application whose information is being retrieved.</p>
     #[serde(rename = "appId")]
@@ -401,7 +415,7 @@ pub struct GetAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAppResponse {
     /// <p>Information about the application.</p>
     #[serde(rename = "appSummary")]
@@ -418,6 +432,7 @@ pub struct GetAppResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectorsRequest {
     /// <p>The maximum number of results to return in a single call. The default value is 50. To retrieve the remaining results, make another call with the returned <code>NextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -430,7 +445,7 @@ pub struct GetConnectorsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectorsResponse {
     /// <p>Information about the registered connectors.</p>
     #[serde(rename = "connectorList")]
@@ -443,6 +458,7 @@ pub struct GetConnectorsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReplicationJobsRequest {
     /// <p>The maximum number of results to return in a single call. The default value is 50. To retrieve the remaining results, make another call with the returned <code>NextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -459,7 +475,7 @@ pub struct GetReplicationJobsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetReplicationJobsResponse {
     /// <p>The token required to retrieve the next set of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -472,6 +488,7 @@ pub struct GetReplicationJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetReplicationRunsRequest {
     /// <p>The maximum number of results to return in a single call. The default value is 50. To retrieve the remaining results, make another call with the returned <code>NextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -487,7 +504,7 @@ pub struct GetReplicationRunsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetReplicationRunsResponse {
     /// <p>The token required to retrieve the next set of results. This value is null when there are no more results to return.</p>
     #[serde(rename = "nextToken")]
@@ -504,6 +521,7 @@ pub struct GetReplicationRunsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetServersRequest {
     /// <p>The maximum number of results to return in a single call. The default value is 50. To retrieve the remaining results, make another call with the returned <code>NextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -520,7 +538,7 @@ pub struct GetServersRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetServersResponse {
     /// <p>The time when the server was last modified.</p>
     #[serde(rename = "lastModifiedOn")]
@@ -541,13 +559,15 @@ pub struct GetServersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportServerCatalogRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportServerCatalogResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LaunchAppRequest {
     /// <p>ID of the application to launch.</p>
     #[serde(rename = "appId")]
@@ -556,12 +576,12 @@ pub struct LaunchAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LaunchAppResponse {}
 
 /// <p>Details about the latest launch of an application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LaunchDetails {
     /// <p>Latest time this application was launched successfully.</p>
     #[serde(rename = "latestLaunchTime")]
@@ -578,6 +598,7 @@ pub struct LaunchDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAppsRequest {
     /// <p><p/></p>
     #[serde(rename = "appIds")]
@@ -594,7 +615,7 @@ pub struct ListAppsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAppsResponse {
     /// <p>A list of application summaries.</p>
     #[serde(rename = "apps")]
@@ -607,6 +628,7 @@ pub struct ListAppsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAppLaunchConfigurationRequest {
     /// <p>ID of the application associated with the launch configuration.</p>
     #[serde(rename = "appId")]
@@ -622,11 +644,27 @@ pub struct PutAppLaunchConfigurationRequest {
     pub server_group_launch_configurations: Option<Vec<ServerGroupLaunchConfiguration>>,
 }
 
+impl Validate for PutAppLaunchConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.server_group_launch_configurations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "server_group_launch_configurations"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAppLaunchConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutAppReplicationConfigurationRequest {
     /// <p>ID of the application tassociated with the replication configuration.</p>
     #[serde(rename = "appId")]
@@ -639,12 +677,12 @@ pub struct PutAppReplicationConfigurationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutAppReplicationConfigurationResponse {}
 
 /// <p>Represents a replication job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationJob {
     /// <p>The description of the replication job.</p>
     #[serde(rename = "description")]
@@ -722,7 +760,7 @@ pub struct ReplicationJob {
 
 /// <p>Represents a replication run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationRun {
     /// <p>The identifier of the Amazon Machine Image (AMI) from the replication run.</p>
     #[serde(rename = "amiId")]
@@ -772,7 +810,7 @@ pub struct ReplicationRun {
 
 /// <p>Details of the current stage of a replication run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ReplicationRunStageDetails {
     /// <p>String describing the current stage of a replication run.</p>
     #[serde(rename = "stage")]
@@ -797,6 +835,27 @@ pub struct S3Location {
     pub key: Option<String>,
 }
 
+impl Validate for S3Location {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket {
+            errors.check_length(
+                &field_path(path, "bucket"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 /// <p>Represents a server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Server {
@@ -856,6 +915,21 @@ pub struct ServerGroupLaunchConfiguration {
     pub server_launch_configurations: Option<Vec<ServerLaunchConfiguration>>,
 }
 
+impl Validate for ServerGroupLaunchConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.server_launch_configurations {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "server_launch_configurations"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Replication configuration for a server group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServerGroupReplicationConfiguration {
@@ -910,6 +984,14 @@ pub struct ServerLaunchConfiguration {
     pub vpc: Option<String>,
 }
 
+impl Validate for ServerLaunchConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.user_data {
+            value.validate_at(&field_path(path, "user_data"), errors);
+        }
+    }
+}
+
 /// <p>Replication configuration of a server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServerReplicationConfiguration {
@@ -957,6 +1039,7 @@ pub struct ServerReplicationParameters {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartAppReplicationRequest {
     /// <p>ID of the application to replicate.</p>
     #[serde(rename = "appId")]
@@ -965,10 +1048,11 @@ pub struct StartAppReplicationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartAppReplicationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartOnDemandReplicationRunRequest {
     /// <p>The description of the replication run.</p>
     #[serde(rename = "description")]
@@ -980,7 +1064,7 @@ pub struct StartOnDemandReplicationRunRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartOnDemandReplicationRunResponse {
     /// <p>The identifier of the replication run.</p>
     #[serde(rename = "replicationRunId")]
@@ -989,6 +1073,7 @@ pub struct StartOnDemandReplicationRunResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopAppReplicationRequest {
     /// <p>ID of the application to stop replicating.</p>
     #[serde(rename = "appId")]
@@ -997,7 +1082,7 @@ pub struct StopAppReplicationRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopAppReplicationResponse {}
 
 /// <p>A label that can be assigned to an application.</p>
@@ -1014,6 +1099,7 @@ pub struct Tag {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateAppRequest {
     /// <p>ID of the application to terminate.</p>
     #[serde(rename = "appId")]
@@ -1022,10 +1108,11 @@ pub struct TerminateAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminateAppResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAppRequest {
     /// <p>ID of the application to update.</p>
     #[serde(rename = "appId")]
@@ -1054,7 +1141,7 @@ pub struct UpdateAppRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAppResponse {
     /// <p>Summary description of the application.</p>
     #[serde(rename = "appSummary")]
@@ -1071,6 +1158,7 @@ pub struct UpdateAppResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateReplicationJobRequest {
     /// <p>The description of the replication job.</p>
     #[serde(rename = "description")]
@@ -1110,7 +1198,7 @@ pub struct UpdateReplicationJobRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateReplicationJobResponse {}
 
 /// <p>A script that runs on first launch of an Amazon EC2 instance. Used for configuring the server during launch.</p>
@@ -1122,6 +1210,14 @@ pub struct UserData {
     pub s_3_location: Option<S3Location>,
 }
 
+impl Validate for UserData {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s_3_location {
+            value.validate_at(&field_path(path, "s_3_location"), errors);
+        }
+    }
+}
+
 /// <p>Represents a VM server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VmServer {
@@ -1194,7 +1290,12 @@ impl CreateAppError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(CreateAppError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1287,7 +1388,12 @@ impl CreateReplicationJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1348,7 +1454,12 @@ impl DeleteAppError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(DeleteAppError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1417,7 +1528,12 @@ impl DeleteAppLaunchConfigurationError {
                         DeleteAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1486,7 +1602,12 @@ impl DeleteAppReplicationConfigurationError {
                         DeleteAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1553,7 +1674,12 @@ impl DeleteReplicationJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1613,7 +1739,12 @@ impl DeleteServerCatalogError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1672,7 +1803,12 @@ impl DisassociateConnectorError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1734,7 +1870,12 @@ impl GenerateChangeSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1797,7 +1938,12 @@ impl GenerateTemplateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1854,7 +2000,12 @@ impl GetAppError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(GetAppError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1921,7 +2072,12 @@ impl GetAppLaunchConfigurationError {
                         GetAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1990,7 +2146,12 @@ impl GetAppReplicationConfigurationError {
                         GetAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2027,7 +2188,12 @@ impl GetConnectorsError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(GetConnectorsError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2074,7 +2240,12 @@ impl GetReplicationJobsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2123,7 +2294,12 @@ impl GetReplicationRunsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2158,7 +2334,12 @@ impl GetServersError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(GetServersError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2221,7 +2402,12 @@ impl ImportServerCatalogError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2278,7 +2464,12 @@ impl LaunchAppError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(LaunchAppError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2335,7 +2526,12 @@ impl ListAppsError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(ListAppsError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2402,7 +2598,12 @@ impl PutAppLaunchConfigurationError {
                         PutAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2471,7 +2672,12 @@ impl PutAppReplicationConfigurationError {
                         PutAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2536,7 +2742,12 @@ impl StartAppReplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2605,7 +2816,12 @@ impl StartOnDemandReplicationRunError {
                         StartOnDemandReplicationRunError::UnauthorizedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2668,7 +2884,12 @@ impl StopAppReplicationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2727,7 +2948,12 @@ impl TerminateAppError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(TerminateAppError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2784,7 +3010,12 @@ impl UpdateAppError {
                 "UnauthorizedOperationException" => {
                     return RusotoError::Service(UpdateAppError::UnauthorizedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2870,7 +3101,12 @@ impl UpdateReplicationJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3061,169 +3297,32 @@ pub trait ServerMigrationService {
         input: UpdateReplicationJobRequest,
     ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError>;
 }
-/// A client for the SMS API.
-#[derive(Clone)]
-pub struct ServerMigrationServiceClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ServerMigrationServiceClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ServerMigrationServiceClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ServerMigrationServiceClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
-        ServerMigrationServiceClient { client, region }
-    }
-}
-
-impl ServerMigrationService for ServerMigrationServiceClient {
-    /// <p>Creates an application. An application consists of one or more server groups. Each server group contain one or more servers.</p>
+const _: Option<&dyn ServerMigrationService> = None;
+impl<'a, T: ServerMigrationService + ?Sized> ServerMigrationService for &'a T {
     fn create_app(
         &self,
         input: CreateAppRequest,
     ) -> RusotoFuture<CreateAppResponse, CreateAppError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.CreateApp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAppResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_app(input)
     }
-
-    /// <p>Creates a replication job. The replication job schedules periodic replication runs to replicate your server to AWS. Each replication run creates an Amazon Machine Image (AMI).</p>
     fn create_replication_job(
         &self,
         input: CreateReplicationJobRequest,
     ) -> RusotoFuture<CreateReplicationJobResponse, CreateReplicationJobError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.CreateReplicationJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateReplicationJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateReplicationJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_replication_job(input)
     }
-
-    /// <p>Deletes an existing application. Optionally deletes the launched stack associated with the application and all AWS SMS replication jobs for servers in the application.</p>
     fn delete_app(
         &self,
         input: DeleteAppRequest,
     ) -> RusotoFuture<DeleteAppResponse, DeleteAppError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.DeleteApp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAppResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_app(input)
     }
-
-    /// <p>Deletes existing launch configuration for an application.</p>
     fn delete_app_launch_configuration(
         &self,
         input: DeleteAppLaunchConfigurationRequest,
     ) -> RusotoFuture<DeleteAppLaunchConfigurationResponse, DeleteAppLaunchConfigurationError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.DeleteAppLaunchConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAppLaunchConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteAppLaunchConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_app_launch_configuration(input)
     }
-
-    /// <p>Deletes existing replication configuration for an application.</p>
     fn delete_app_replication_configuration(
         &self,
         input: DeleteAppReplicationConfigurationRequest,
@@ -3231,735 +3330,2379 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         DeleteAppReplicationConfigurationResponse,
         DeleteAppReplicationConfigurationError,
     > {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.DeleteAppReplicationConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAppReplicationConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteAppReplicationConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).delete_app_replication_configuration(input)
     }
-
-    /// <p>Deletes the specified replication job.</p> <p>After you delete a replication job, there are no further replication runs. AWS deletes the contents of the Amazon S3 bucket used to store AWS SMS artifacts. The AMIs created by the replication runs are not deleted.</p>
     fn delete_replication_job(
         &self,
         input: DeleteReplicationJobRequest,
     ) -> RusotoFuture<DeleteReplicationJobResponse, DeleteReplicationJobError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.DeleteReplicationJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteReplicationJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteReplicationJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_replication_job(input)
     }
-
-    /// <p>Deletes all servers from your server catalog.</p>
     fn delete_server_catalog(
         &self,
     ) -> RusotoFuture<DeleteServerCatalogResponse, DeleteServerCatalogError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.DeleteServerCatalog",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteServerCatalogResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteServerCatalogError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_server_catalog()
     }
-
-    /// <p>Disassociates the specified connector from AWS SMS.</p> <p>After you disassociate a connector, it is no longer available to support replication jobs.</p>
     fn disassociate_connector(
         &self,
         input: DisassociateConnectorRequest,
     ) -> RusotoFuture<DisassociateConnectorResponse, DisassociateConnectorError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.DisassociateConnector",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateConnectorResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisassociateConnectorError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).disassociate_connector(input)
     }
-
-    /// <p>Generates a target change set for a currently launched stack and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
     fn generate_change_set(
         &self,
         input: GenerateChangeSetRequest,
     ) -> RusotoFuture<GenerateChangeSetResponse, GenerateChangeSetError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GenerateChangeSet",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GenerateChangeSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GenerateChangeSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).generate_change_set(input)
     }
-
-    /// <p>Generates an Amazon CloudFormation template based on the current launch configuration and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
     fn generate_template(
         &self,
         input: GenerateTemplateRequest,
     ) -> RusotoFuture<GenerateTemplateResponse, GenerateTemplateError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GenerateTemplate",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GenerateTemplateResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GenerateTemplateError::from_response(response))),
-                )
-            }
-        })
+        (**self).generate_template(input)
     }
-
-    /// <p>Retrieve information about an application.</p>
     fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResponse, GetAppError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetApp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetAppResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetAppError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_app(input)
     }
-
-    /// <p>Retrieves the application launch configuration associated with an application.</p>
     fn get_app_launch_configuration(
         &self,
         input: GetAppLaunchConfigurationRequest,
     ) -> RusotoFuture<GetAppLaunchConfigurationResponse, GetAppLaunchConfigurationError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetAppLaunchConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAppLaunchConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAppLaunchConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_app_launch_configuration(input)
     }
-
-    /// <p>Retrieves an application replication configuration associatd with an application.</p>
     fn get_app_replication_configuration(
         &self,
         input: GetAppReplicationConfigurationRequest,
     ) -> RusotoFuture<GetAppReplicationConfigurationResponse, GetAppReplicationConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetAppReplicationConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAppReplicationConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetAppReplicationConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_app_replication_configuration(input)
     }
-
-    /// <p>Describes the connectors registered with the AWS SMS.</p>
     fn get_connectors(
         &self,
         input: GetConnectorsRequest,
     ) -> RusotoFuture<GetConnectorsResponse, GetConnectorsError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetConnectors",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectorsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetConnectorsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_connectors(input)
     }
-
-    /// <p>Describes the specified replication job or all of your replication jobs.</p>
     fn get_replication_jobs(
         &self,
         input: GetReplicationJobsRequest,
     ) -> RusotoFuture<GetReplicationJobsResponse, GetReplicationJobsError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetReplicationJobs",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetReplicationJobsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetReplicationJobsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_replication_jobs(input)
     }
-
-    /// <p>Describes the replication runs for the specified replication job.</p>
     fn get_replication_runs(
         &self,
         input: GetReplicationRunsRequest,
     ) -> RusotoFuture<GetReplicationRunsResponse, GetReplicationRunsError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetReplicationRuns",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetReplicationRunsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetReplicationRunsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_replication_runs(input)
     }
-
-    /// <p>Describes the servers in your server catalog.</p> <p>Before you can describe your servers, you must import them using <a>ImportServerCatalog</a>.</p>
     fn get_servers(
         &self,
         input: GetServersRequest,
     ) -> RusotoFuture<GetServersResponse, GetServersError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.GetServers",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetServersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetServersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_servers(input)
     }
-
-    /// <p>Gathers a complete list of on-premises servers. Connectors must be installed and monitoring all servers that you want to import.</p> <p>This call returns immediately, but might take additional time to retrieve all the servers.</p>
     fn import_server_catalog(
         &self,
     ) -> RusotoFuture<ImportServerCatalogResponse, ImportServerCatalogError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.ImportServerCatalog",
-        );
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportServerCatalogResponse, _>()
+        (**self).import_server_catalog()
+    }
+    fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> RusotoFuture<LaunchAppResponse, LaunchAppError> {
+        (**self).launch_app(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResponse, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<PutAppLaunchConfigurationResponse, PutAppLaunchConfigurationError> {
+        (**self).put_app_launch_configuration(input)
+    }
+    fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<PutAppReplicationConfigurationResponse, PutAppReplicationConfigurationError>
+    {
+        (**self).put_app_replication_configuration(input)
+    }
+    fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> RusotoFuture<StartAppReplicationResponse, StartAppReplicationError> {
+        (**self).start_app_replication(input)
+    }
+    fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> RusotoFuture<StartOnDemandReplicationRunResponse, StartOnDemandReplicationRunError> {
+        (**self).start_on_demand_replication_run(input)
+    }
+    fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> RusotoFuture<StopAppReplicationResponse, StopAppReplicationError> {
+        (**self).stop_app_replication(input)
+    }
+    fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> RusotoFuture<TerminateAppResponse, TerminateAppError> {
+        (**self).terminate_app(input)
+    }
+    fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> RusotoFuture<UpdateAppResponse, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError> {
+        (**self).update_replication_job(input)
+    }
+}
+impl<T: ServerMigrationService + ?Sized> ServerMigrationService for Box<T> {
+    fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> RusotoFuture<CreateAppResponse, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> RusotoFuture<CreateReplicationJobResponse, CreateReplicationJobError> {
+        (**self).create_replication_job(input)
+    }
+    fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> RusotoFuture<DeleteAppResponse, DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<DeleteAppLaunchConfigurationResponse, DeleteAppLaunchConfigurationError> {
+        (**self).delete_app_launch_configuration(input)
+    }
+    fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteAppReplicationConfigurationResponse,
+        DeleteAppReplicationConfigurationError,
+    > {
+        (**self).delete_app_replication_configuration(input)
+    }
+    fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> RusotoFuture<DeleteReplicationJobResponse, DeleteReplicationJobError> {
+        (**self).delete_replication_job(input)
+    }
+    fn delete_server_catalog(
+        &self,
+    ) -> RusotoFuture<DeleteServerCatalogResponse, DeleteServerCatalogError> {
+        (**self).delete_server_catalog()
+    }
+    fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> RusotoFuture<DisassociateConnectorResponse, DisassociateConnectorError> {
+        (**self).disassociate_connector(input)
+    }
+    fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> RusotoFuture<GenerateChangeSetResponse, GenerateChangeSetError> {
+        (**self).generate_change_set(input)
+    }
+    fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> RusotoFuture<GenerateTemplateResponse, GenerateTemplateError> {
+        (**self).generate_template(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResponse, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<GetAppLaunchConfigurationResponse, GetAppLaunchConfigurationError> {
+        (**self).get_app_launch_configuration(input)
+    }
+    fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<GetAppReplicationConfigurationResponse, GetAppReplicationConfigurationError>
+    {
+        (**self).get_app_replication_configuration(input)
+    }
+    fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> RusotoFuture<GetConnectorsResponse, GetConnectorsError> {
+        (**self).get_connectors(input)
+    }
+    fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> RusotoFuture<GetReplicationJobsResponse, GetReplicationJobsError> {
+        (**self).get_replication_jobs(input)
+    }
+    fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> RusotoFuture<GetReplicationRunsResponse, GetReplicationRunsError> {
+        (**self).get_replication_runs(input)
+    }
+    fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> RusotoFuture<GetServersResponse, GetServersError> {
+        (**self).get_servers(input)
+    }
+    fn import_server_catalog(
+        &self,
+    ) -> RusotoFuture<ImportServerCatalogResponse, ImportServerCatalogError> {
+        (**self).import_server_catalog()
+    }
+    fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> RusotoFuture<LaunchAppResponse, LaunchAppError> {
+        (**self).launch_app(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResponse, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<PutAppLaunchConfigurationResponse, PutAppLaunchConfigurationError> {
+        (**self).put_app_launch_configuration(input)
+    }
+    fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<PutAppReplicationConfigurationResponse, PutAppReplicationConfigurationError>
+    {
+        (**self).put_app_replication_configuration(input)
+    }
+    fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> RusotoFuture<StartAppReplicationResponse, StartAppReplicationError> {
+        (**self).start_app_replication(input)
+    }
+    fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> RusotoFuture<StartOnDemandReplicationRunResponse, StartOnDemandReplicationRunError> {
+        (**self).start_on_demand_replication_run(input)
+    }
+    fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> RusotoFuture<StopAppReplicationResponse, StopAppReplicationError> {
+        (**self).stop_app_replication(input)
+    }
+    fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> RusotoFuture<TerminateAppResponse, TerminateAppError> {
+        (**self).terminate_app(input)
+    }
+    fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> RusotoFuture<UpdateAppResponse, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError> {
+        (**self).update_replication_job(input)
+    }
+}
+impl<T: ServerMigrationService + ?Sized> ServerMigrationService for ::std::sync::Arc<T> {
+    fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> RusotoFuture<CreateAppResponse, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> RusotoFuture<CreateReplicationJobResponse, CreateReplicationJobError> {
+        (**self).create_replication_job(input)
+    }
+    fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> RusotoFuture<DeleteAppResponse, DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<DeleteAppLaunchConfigurationResponse, DeleteAppLaunchConfigurationError> {
+        (**self).delete_app_launch_configuration(input)
+    }
+    fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteAppReplicationConfigurationResponse,
+        DeleteAppReplicationConfigurationError,
+    > {
+        (**self).delete_app_replication_configuration(input)
+    }
+    fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> RusotoFuture<DeleteReplicationJobResponse, DeleteReplicationJobError> {
+        (**self).delete_replication_job(input)
+    }
+    fn delete_server_catalog(
+        &self,
+    ) -> RusotoFuture<DeleteServerCatalogResponse, DeleteServerCatalogError> {
+        (**self).delete_server_catalog()
+    }
+    fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> RusotoFuture<DisassociateConnectorResponse, DisassociateConnectorError> {
+        (**self).disassociate_connector(input)
+    }
+    fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> RusotoFuture<GenerateChangeSetResponse, GenerateChangeSetError> {
+        (**self).generate_change_set(input)
+    }
+    fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> RusotoFuture<GenerateTemplateResponse, GenerateTemplateError> {
+        (**self).generate_template(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResponse, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<GetAppLaunchConfigurationResponse, GetAppLaunchConfigurationError> {
+        (**self).get_app_launch_configuration(input)
+    }
+    fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<GetAppReplicationConfigurationResponse, GetAppReplicationConfigurationError>
+    {
+        (**self).get_app_replication_configuration(input)
+    }
+    fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> RusotoFuture<GetConnectorsResponse, GetConnectorsError> {
+        (**self).get_connectors(input)
+    }
+    fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> RusotoFuture<GetReplicationJobsResponse, GetReplicationJobsError> {
+        (**self).get_replication_jobs(input)
+    }
+    fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> RusotoFuture<GetReplicationRunsResponse, GetReplicationRunsError> {
+        (**self).get_replication_runs(input)
+    }
+    fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> RusotoFuture<GetServersResponse, GetServersError> {
+        (**self).get_servers(input)
+    }
+    fn import_server_catalog(
+        &self,
+    ) -> RusotoFuture<ImportServerCatalogResponse, ImportServerCatalogError> {
+        (**self).import_server_catalog()
+    }
+    fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> RusotoFuture<LaunchAppResponse, LaunchAppError> {
+        (**self).launch_app(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResponse, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<PutAppLaunchConfigurationResponse, PutAppLaunchConfigurationError> {
+        (**self).put_app_launch_configuration(input)
+    }
+    fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<PutAppReplicationConfigurationResponse, PutAppReplicationConfigurationError>
+    {
+        (**self).put_app_replication_configuration(input)
+    }
+    fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> RusotoFuture<StartAppReplicationResponse, StartAppReplicationError> {
+        (**self).start_app_replication(input)
+    }
+    fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> RusotoFuture<StartOnDemandReplicationRunResponse, StartOnDemandReplicationRunError> {
+        (**self).start_on_demand_replication_run(input)
+    }
+    fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> RusotoFuture<StopAppReplicationResponse, StopAppReplicationError> {
+        (**self).stop_app_replication(input)
+    }
+    fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> RusotoFuture<TerminateAppResponse, TerminateAppError> {
+        (**self).terminate_app(input)
+    }
+    fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> RusotoFuture<UpdateAppResponse, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError> {
+        (**self).update_replication_job(input)
+    }
+}
+/// A client for the SMS API.
+#[derive(Clone)]
+pub struct ServerMigrationServiceClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ServerMigrationServiceClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServerMigrationServiceClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServerMigrationServiceClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
+        ServerMigrationServiceClient { client, region }
+    }
+}
+
+impl ServerMigrationService for ServerMigrationServiceClient {
+    /// <p>Creates an application. An application consists of one or more server groups. Each server group contain one or more servers.</p>
+    fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> RusotoFuture<CreateAppResponse, CreateAppError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("CreateApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.CreateApp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAppResponse, _>(response, "CreateApp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a replication job. The replication job schedules periodic replication runs to replicate your server to AWS. Each replication run creates an Amazon Machine Image (AMI).</p>
+    fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> RusotoFuture<CreateReplicationJobResponse, CreateReplicationJobError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("CreateReplicationJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.CreateReplicationJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateReplicationJobResponse, _>(
+                    response,
+                    "CreateReplicationJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateReplicationJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an existing application. Optionally deletes the launched stack associated with the application and all AWS SMS replication jobs for servers in the application.</p>
+    fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> RusotoFuture<DeleteAppResponse, DeleteAppError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.DeleteApp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAppResponse, _>(response, "DeleteApp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes existing launch configuration for an application.</p>
+    fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<DeleteAppLaunchConfigurationResponse, DeleteAppLaunchConfigurationError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteAppLaunchConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.DeleteAppLaunchConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAppLaunchConfigurationResponse, _>(
+                    response,
+                    "DeleteAppLaunchConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteAppLaunchConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes existing replication configuration for an application.</p>
+    fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteAppReplicationConfigurationResponse,
+        DeleteAppReplicationConfigurationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteAppReplicationConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.DeleteAppReplicationConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAppReplicationConfigurationResponse, _>(
+                    response,
+                    "DeleteAppReplicationConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteAppReplicationConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified replication job.</p> <p>After you delete a replication job, there are no further replication runs. AWS deletes the contents of the Amazon S3 bucket used to store AWS SMS artifacts. The AMIs created by the replication runs are not deleted.</p>
+    fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> RusotoFuture<DeleteReplicationJobResponse, DeleteReplicationJobError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteReplicationJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.DeleteReplicationJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteReplicationJobResponse, _>(
+                    response,
+                    "DeleteReplicationJob",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteReplicationJobError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes all servers from your server catalog.</p>
+    fn delete_server_catalog(
+        &self,
+    ) -> RusotoFuture<DeleteServerCatalogResponse, DeleteServerCatalogError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DeleteServerCatalog");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.DeleteServerCatalog",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteServerCatalogResponse, _>(
+                    response,
+                    "DeleteServerCatalog",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteServerCatalogError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified connector from AWS SMS.</p> <p>After you disassociate a connector, it is no longer available to support replication jobs.</p>
+    fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> RusotoFuture<DisassociateConnectorResponse, DisassociateConnectorError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("DisassociateConnector");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.DisassociateConnector",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateConnectorResponse, _>(
+                    response,
+                    "DisassociateConnector",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisassociateConnectorError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates a target change set for a currently launched stack and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
+    fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> RusotoFuture<GenerateChangeSetResponse, GenerateChangeSetError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GenerateChangeSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GenerateChangeSet",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GenerateChangeSetResponse, _>(
+                    response,
+                    "GenerateChangeSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GenerateChangeSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Generates an Amazon CloudFormation template based on the current launch configuration and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
+    fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> RusotoFuture<GenerateTemplateResponse, GenerateTemplateError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GenerateTemplate");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GenerateTemplate",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GenerateTemplateResponse, _>(
+                    response,
+                    "GenerateTemplate",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GenerateTemplateError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieve information about an application.</p>
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResponse, GetAppError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetApp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAppResponse, _>(response, "GetApp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the application launch configuration associated with an application.</p>
+    fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<GetAppLaunchConfigurationResponse, GetAppLaunchConfigurationError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetAppLaunchConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetAppLaunchConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAppLaunchConfigurationResponse, _>(
+                    response,
+                    "GetAppLaunchConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAppLaunchConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves an application replication configuration associatd with an application.</p>
+    fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<GetAppReplicationConfigurationResponse, GetAppReplicationConfigurationError>
+    {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetAppReplicationConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetAppReplicationConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAppReplicationConfigurationResponse, _>(
+                    response,
+                    "GetAppReplicationConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetAppReplicationConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the connectors registered with the AWS SMS.</p>
+    fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> RusotoFuture<GetConnectorsResponse, GetConnectorsError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetConnectors");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetConnectors",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetConnectorsResponse, _>(
+                    response,
+                    "GetConnectors",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetConnectorsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the specified replication job or all of your replication jobs.</p>
+    fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> RusotoFuture<GetReplicationJobsResponse, GetReplicationJobsError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetReplicationJobs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetReplicationJobs",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetReplicationJobsResponse, _>(
+                    response,
+                    "GetReplicationJobs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetReplicationJobsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the replication runs for the specified replication job.</p>
+    fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> RusotoFuture<GetReplicationRunsResponse, GetReplicationRunsError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetReplicationRuns");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetReplicationRuns",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetReplicationRunsResponse, _>(
+                    response,
+                    "GetReplicationRuns",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetReplicationRunsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the servers in your server catalog.</p> <p>Before you can describe your servers, you must import them using <a>ImportServerCatalog</a>.</p>
+    fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> RusotoFuture<GetServersResponse, GetServersError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("GetServers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.GetServers",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetServersResponse, _>(response, "GetServers")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetServersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gathers a complete list of on-premises servers. Connectors must be installed and monitoring all servers that you want to import.</p> <p>This call returns immediately, but might take additional time to retrieve all the servers.</p>
+    fn import_server_catalog(
+        &self,
+    ) -> RusotoFuture<ImportServerCatalogResponse, ImportServerCatalogError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("ImportServerCatalog");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.ImportServerCatalog",
+        );
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ImportServerCatalogResponse, _>(
+                    response,
+                    "ImportServerCatalog",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ImportServerCatalogError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Launches an application stack.</p>
+    fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> RusotoFuture<LaunchAppResponse, LaunchAppError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("LaunchApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.LaunchApp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<LaunchAppResponse, _>(response, "LaunchApp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(LaunchAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of summaries for all applications.</p>
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResponse, ListAppsError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("ListApps");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.ListApps",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAppsResponse, _>(response, "ListApps")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAppsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a launch configuration for an application.</p>
+    fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<PutAppLaunchConfigurationResponse, PutAppLaunchConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("PutAppLaunchConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.PutAppLaunchConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAppLaunchConfigurationResponse, _>(
+                    response,
+                    "PutAppLaunchConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutAppLaunchConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates or updates a replication configuration for an application.</p>
+    fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<PutAppReplicationConfigurationResponse, PutAppReplicationConfigurationError>
+    {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("PutAppReplicationConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.PutAppReplicationConfiguration",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutAppReplicationConfigurationResponse, _>(
+                    response,
+                    "PutAppReplicationConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutAppReplicationConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts replicating an application.</p>
+    fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> RusotoFuture<StartAppReplicationResponse, StartAppReplicationError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StartAppReplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.StartAppReplication",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartAppReplicationResponse, _>(
+                    response,
+                    "StartAppReplication",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartAppReplicationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts an on-demand replication run for the specified replication job. This replication run starts immediately. This replication run is in addition to the ones already scheduled.</p> <p>There is a limit on the number of on-demand replications runs you can request in a 24-hour period.</p>
+    fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> RusotoFuture<StartOnDemandReplicationRunResponse, StartOnDemandReplicationRunError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StartOnDemandReplicationRun");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.StartOnDemandReplicationRun",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartOnDemandReplicationRunResponse, _>(
+                    response,
+                    "StartOnDemandReplicationRun",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartOnDemandReplicationRunError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Stops replicating an application.</p>
+    fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> RusotoFuture<StopAppReplicationResponse, StopAppReplicationError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("StopAppReplication");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.StopAppReplication",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopAppReplicationResponse, _>(
+                    response,
+                    "StopAppReplication",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopAppReplicationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Terminates the stack for an application.</p>
+    fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> RusotoFuture<TerminateAppResponse, TerminateAppError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("TerminateApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.TerminateApp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TerminateAppResponse, _>(
+                    response,
+                    "TerminateApp",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TerminateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates an application.</p>
+    fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> RusotoFuture<UpdateAppResponse, UpdateAppError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("UpdateApp");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.UpdateApp",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateAppResponse, _>(response, "UpdateApp")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAppError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified settings for the specified replication job.</p>
+    fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError> {
+        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
+        request.set_operation("UpdateReplicationJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSServerMigrationService_V2016_10_24.UpdateReplicationJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateReplicationJobResponse, _>(
+                    response,
+                    "UpdateReplicationJob",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
-                        Err(ImportServerCatalogError::from_response(response))
+                        Err(UpdateReplicationJobError::from_response(response))
                     }),
                 )
             }
         })
     }
-
-    /// <p>Launches an application stack.</p>
+}
+
+/// A mock of the SMS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockServerMigrationService {
+    create_app: Option<
+        Box<
+            dyn Fn(
+                    CreateAppRequest,
+                )
+                    -> ::std::result::Result<CreateAppResponse, RusotoError<CreateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_replication_job: Option<
+        Box<
+            dyn Fn(
+                    CreateReplicationJobRequest,
+                ) -> ::std::result::Result<
+                    CreateReplicationJobResponse,
+                    RusotoError<CreateReplicationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_app: Option<
+        Box<
+            dyn Fn(
+                    DeleteAppRequest,
+                )
+                    -> ::std::result::Result<DeleteAppResponse, RusotoError<DeleteAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_app_launch_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteAppLaunchConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteAppLaunchConfigurationResponse,
+                    RusotoError<DeleteAppLaunchConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_app_replication_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteAppReplicationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteAppReplicationConfigurationResponse,
+                    RusotoError<DeleteAppReplicationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_replication_job: Option<
+        Box<
+            dyn Fn(
+                    DeleteReplicationJobRequest,
+                ) -> ::std::result::Result<
+                    DeleteReplicationJobResponse,
+                    RusotoError<DeleteReplicationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_server_catalog: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DeleteServerCatalogResponse,
+                    RusotoError<DeleteServerCatalogError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_connector: Option<
+        Box<
+            dyn Fn(
+                    DisassociateConnectorRequest,
+                ) -> ::std::result::Result<
+                    DisassociateConnectorResponse,
+                    RusotoError<DisassociateConnectorError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    generate_change_set: Option<
+        Box<
+            dyn Fn(
+                    GenerateChangeSetRequest,
+                ) -> ::std::result::Result<
+                    GenerateChangeSetResponse,
+                    RusotoError<GenerateChangeSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    generate_template: Option<
+        Box<
+            dyn Fn(
+                    GenerateTemplateRequest,
+                ) -> ::std::result::Result<
+                    GenerateTemplateResponse,
+                    RusotoError<GenerateTemplateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_app: Option<
+        Box<
+            dyn Fn(GetAppRequest) -> ::std::result::Result<GetAppResponse, RusotoError<GetAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_app_launch_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetAppLaunchConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetAppLaunchConfigurationResponse,
+                    RusotoError<GetAppLaunchConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_app_replication_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetAppReplicationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetAppReplicationConfigurationResponse,
+                    RusotoError<GetAppReplicationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_connectors: Option<
+        Box<
+            dyn Fn(
+                    GetConnectorsRequest,
+                )
+                    -> ::std::result::Result<GetConnectorsResponse, RusotoError<GetConnectorsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_replication_jobs: Option<
+        Box<
+            dyn Fn(
+                    GetReplicationJobsRequest,
+                ) -> ::std::result::Result<
+                    GetReplicationJobsResponse,
+                    RusotoError<GetReplicationJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_replication_runs: Option<
+        Box<
+            dyn Fn(
+                    GetReplicationRunsRequest,
+                ) -> ::std::result::Result<
+                    GetReplicationRunsResponse,
+                    RusotoError<GetReplicationRunsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_servers: Option<
+        Box<
+            dyn Fn(
+                    GetServersRequest,
+                )
+                    -> ::std::result::Result<GetServersResponse, RusotoError<GetServersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    import_server_catalog: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    ImportServerCatalogResponse,
+                    RusotoError<ImportServerCatalogError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    launch_app: Option<
+        Box<
+            dyn Fn(
+                    LaunchAppRequest,
+                )
+                    -> ::std::result::Result<LaunchAppResponse, RusotoError<LaunchAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_apps: Option<
+        Box<
+            dyn Fn(
+                    ListAppsRequest,
+                )
+                    -> ::std::result::Result<ListAppsResponse, RusotoError<ListAppsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_app_launch_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutAppLaunchConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutAppLaunchConfigurationResponse,
+                    RusotoError<PutAppLaunchConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_app_replication_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutAppReplicationConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutAppReplicationConfigurationResponse,
+                    RusotoError<PutAppReplicationConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_app_replication: Option<
+        Box<
+            dyn Fn(
+                    StartAppReplicationRequest,
+                ) -> ::std::result::Result<
+                    StartAppReplicationResponse,
+                    RusotoError<StartAppReplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_on_demand_replication_run: Option<
+        Box<
+            dyn Fn(
+                    StartOnDemandReplicationRunRequest,
+                ) -> ::std::result::Result<
+                    StartOnDemandReplicationRunResponse,
+                    RusotoError<StartOnDemandReplicationRunError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_app_replication: Option<
+        Box<
+            dyn Fn(
+                    StopAppReplicationRequest,
+                ) -> ::std::result::Result<
+                    StopAppReplicationResponse,
+                    RusotoError<StopAppReplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    terminate_app: Option<
+        Box<
+            dyn Fn(
+                    TerminateAppRequest,
+                )
+                    -> ::std::result::Result<TerminateAppResponse, RusotoError<TerminateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_app: Option<
+        Box<
+            dyn Fn(
+                    UpdateAppRequest,
+                )
+                    -> ::std::result::Result<UpdateAppResponse, RusotoError<UpdateAppError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_replication_job: Option<
+        Box<
+            dyn Fn(
+                    UpdateReplicationJobRequest,
+                ) -> ::std::result::Result<
+                    UpdateReplicationJobResponse,
+                    RusotoError<UpdateReplicationJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockServerMigrationService {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockServerMigrationService {
+        Default::default()
+    }
+
+    /// Answers `create_app` with what `handler` returns for its input.
+    pub fn with_create_app<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                CreateAppRequest,
+            ) -> ::std::result::Result<CreateAppResponse, RusotoError<CreateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_replication_job` with what `handler` returns for its input.
+    pub fn with_create_replication_job<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                CreateReplicationJobRequest,
+            ) -> ::std::result::Result<
+                CreateReplicationJobResponse,
+                RusotoError<CreateReplicationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_replication_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_app` with what `handler` returns for its input.
+    pub fn with_delete_app<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                DeleteAppRequest,
+            ) -> ::std::result::Result<DeleteAppResponse, RusotoError<DeleteAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_app_launch_configuration` with what `handler` returns for its input.
+    pub fn with_delete_app_launch_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockServerMigrationService
+    where
+        F: Fn(
+                DeleteAppLaunchConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteAppLaunchConfigurationResponse,
+                RusotoError<DeleteAppLaunchConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_app_launch_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_app_replication_configuration` with what `handler` returns for its input.
+    pub fn with_delete_app_replication_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockServerMigrationService
+    where
+        F: Fn(
+                DeleteAppReplicationConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteAppReplicationConfigurationResponse,
+                RusotoError<DeleteAppReplicationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_app_replication_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_replication_job` with what `handler` returns for its input.
+    pub fn with_delete_replication_job<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                DeleteReplicationJobRequest,
+            ) -> ::std::result::Result<
+                DeleteReplicationJobResponse,
+                RusotoError<DeleteReplicationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_replication_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_server_catalog` with what `handler` returns.
+    pub fn with_delete_server_catalog<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn() -> ::std::result::Result<
+                DeleteServerCatalogResponse,
+                RusotoError<DeleteServerCatalogError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_server_catalog = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_connector` with what `handler` returns for its input.
+    pub fn with_disassociate_connector<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                DisassociateConnectorRequest,
+            ) -> ::std::result::Result<
+                DisassociateConnectorResponse,
+                RusotoError<DisassociateConnectorError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_connector = Some(Box::new(handler));
+        self
+    }
+    /// Answers `generate_change_set` with what `handler` returns for its input.
+    pub fn with_generate_change_set<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GenerateChangeSetRequest,
+            ) -> ::std::result::Result<
+                GenerateChangeSetResponse,
+                RusotoError<GenerateChangeSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_change_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `generate_template` with what `handler` returns for its input.
+    pub fn with_generate_template<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GenerateTemplateRequest,
+            ) -> ::std::result::Result<
+                GenerateTemplateResponse,
+                RusotoError<GenerateTemplateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.generate_template = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_app` with what `handler` returns for its input.
+    pub fn with_get_app<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(GetAppRequest) -> ::std::result::Result<GetAppResponse, RusotoError<GetAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_app_launch_configuration` with what `handler` returns for its input.
+    pub fn with_get_app_launch_configuration<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GetAppLaunchConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetAppLaunchConfigurationResponse,
+                RusotoError<GetAppLaunchConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_app_launch_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_app_replication_configuration` with what `handler` returns for its input.
+    pub fn with_get_app_replication_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockServerMigrationService
+    where
+        F: Fn(
+                GetAppReplicationConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetAppReplicationConfigurationResponse,
+                RusotoError<GetAppReplicationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_app_replication_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connectors` with what `handler` returns for its input.
+    pub fn with_get_connectors<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GetConnectorsRequest,
+            )
+                -> ::std::result::Result<GetConnectorsResponse, RusotoError<GetConnectorsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connectors = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_replication_jobs` with what `handler` returns for its input.
+    pub fn with_get_replication_jobs<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GetReplicationJobsRequest,
+            ) -> ::std::result::Result<
+                GetReplicationJobsResponse,
+                RusotoError<GetReplicationJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_replication_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_replication_runs` with what `handler` returns for its input.
+    pub fn with_get_replication_runs<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GetReplicationRunsRequest,
+            ) -> ::std::result::Result<
+                GetReplicationRunsResponse,
+                RusotoError<GetReplicationRunsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_replication_runs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_servers` with what `handler` returns for its input.
+    pub fn with_get_servers<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                GetServersRequest,
+            )
+                -> ::std::result::Result<GetServersResponse, RusotoError<GetServersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_servers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_server_catalog` with what `handler` returns.
+    pub fn with_import_server_catalog<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn() -> ::std::result::Result<
+                ImportServerCatalogResponse,
+                RusotoError<ImportServerCatalogError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_server_catalog = Some(Box::new(handler));
+        self
+    }
+    /// Answers `launch_app` with what `handler` returns for its input.
+    pub fn with_launch_app<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                LaunchAppRequest,
+            ) -> ::std::result::Result<LaunchAppResponse, RusotoError<LaunchAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.launch_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_apps` with what `handler` returns for its input.
+    pub fn with_list_apps<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                ListAppsRequest,
+            ) -> ::std::result::Result<ListAppsResponse, RusotoError<ListAppsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_apps = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_app_launch_configuration` with what `handler` returns for its input.
+    pub fn with_put_app_launch_configuration<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                PutAppLaunchConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutAppLaunchConfigurationResponse,
+                RusotoError<PutAppLaunchConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_app_launch_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_app_replication_configuration` with what `handler` returns for its input.
+    pub fn with_put_app_replication_configuration<F>(
+        mut self,
+        handler: F,
+    ) -> MockServerMigrationService
+    where
+        F: Fn(
+                PutAppReplicationConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutAppReplicationConfigurationResponse,
+                RusotoError<PutAppReplicationConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_app_replication_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_app_replication` with what `handler` returns for its input.
+    pub fn with_start_app_replication<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                StartAppReplicationRequest,
+            ) -> ::std::result::Result<
+                StartAppReplicationResponse,
+                RusotoError<StartAppReplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_app_replication = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_on_demand_replication_run` with what `handler` returns for its input.
+    pub fn with_start_on_demand_replication_run<F>(
+        mut self,
+        handler: F,
+    ) -> MockServerMigrationService
+    where
+        F: Fn(
+                StartOnDemandReplicationRunRequest,
+            ) -> ::std::result::Result<
+                StartOnDemandReplicationRunResponse,
+                RusotoError<StartOnDemandReplicationRunError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_on_demand_replication_run = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_app_replication` with what `handler` returns for its input.
+    pub fn with_stop_app_replication<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                StopAppReplicationRequest,
+            ) -> ::std::result::Result<
+                StopAppReplicationResponse,
+                RusotoError<StopAppReplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_app_replication = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_app` with what `handler` returns for its input.
+    pub fn with_terminate_app<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                TerminateAppRequest,
+            )
+                -> ::std::result::Result<TerminateAppResponse, RusotoError<TerminateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_app` with what `handler` returns for its input.
+    pub fn with_update_app<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                UpdateAppRequest,
+            ) -> ::std::result::Result<UpdateAppResponse, RusotoError<UpdateAppError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_app = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_replication_job` with what `handler` returns for its input.
+    pub fn with_update_replication_job<F>(mut self, handler: F) -> MockServerMigrationService
+    where
+        F: Fn(
+                UpdateReplicationJobRequest,
+            ) -> ::std::result::Result<
+                UpdateReplicationJobResponse,
+                RusotoError<UpdateReplicationJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_replication_job = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl ServerMigrationService for MockServerMigrationService {
+    fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> RusotoFuture<CreateAppResponse, CreateAppError> {
+        match self.create_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for create_app"),
+        }
+    }
+    fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> RusotoFuture<CreateReplicationJobResponse, CreateReplicationJobError> {
+        match self.create_replication_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for create_replication_job"),
+        }
+    }
+    fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> RusotoFuture<DeleteAppResponse, DeleteAppError> {
+        match self.delete_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for delete_app"),
+        }
+    }
+    fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<DeleteAppLaunchConfigurationResponse, DeleteAppLaunchConfigurationError> {
+        match self.delete_app_launch_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServerMigrationService has no handler for delete_app_launch_configuration"
+            ),
+        }
+    }
+    fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteAppReplicationConfigurationResponse,
+        DeleteAppReplicationConfigurationError,
+    > {
+        match self.delete_app_replication_configuration {
+Some(ref handler) => handler(input).into(),
+None => panic!("MockServerMigrationService has no handler for delete_app_replication_configuration"),
+}
+    }
+    fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> RusotoFuture<DeleteReplicationJobResponse, DeleteReplicationJobError> {
+        match self.delete_replication_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for delete_replication_job"),
+        }
+    }
+    fn delete_server_catalog(
+        &self,
+    ) -> RusotoFuture<DeleteServerCatalogResponse, DeleteServerCatalogError> {
+        match self.delete_server_catalog {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockServerMigrationService has no handler for delete_server_catalog"),
+        }
+    }
+    fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> RusotoFuture<DisassociateConnectorResponse, DisassociateConnectorError> {
+        match self.disassociate_connector {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for disassociate_connector"),
+        }
+    }
+    fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> RusotoFuture<GenerateChangeSetResponse, GenerateChangeSetError> {
+        match self.generate_change_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for generate_change_set"),
+        }
+    }
+    fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> RusotoFuture<GenerateTemplateResponse, GenerateTemplateError> {
+        match self.generate_template {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for generate_template"),
+        }
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResponse, GetAppError> {
+        match self.get_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for get_app"),
+        }
+    }
+    fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> RusotoFuture<GetAppLaunchConfigurationResponse, GetAppLaunchConfigurationError> {
+        match self.get_app_launch_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServerMigrationService has no handler for get_app_launch_configuration")
+            }
+        }
+    }
+    fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> RusotoFuture<GetAppReplicationConfigurationResponse, GetAppReplicationConfigurationError>
+    {
+        match self.get_app_replication_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServerMigrationService has no handler for get_app_replication_configuration"
+            ),
+        }
+    }
+    fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> RusotoFuture<GetConnectorsResponse, GetConnectorsError> {
+        match self.get_connectors {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for get_connectors"),
+        }
+    }
+    fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> RusotoFuture<GetReplicationJobsResponse, GetReplicationJobsError> {
+        match self.get_replication_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for get_replication_jobs"),
+        }
+    }
+    fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> RusotoFuture<GetReplicationRunsResponse, GetReplicationRunsError> {
+        match self.get_replication_runs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for get_replication_runs"),
+        }
+    }
+    fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> RusotoFuture<GetServersResponse, GetServersError> {
+        match self.get_servers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for get_servers"),
+        }
+    }
+    fn import_server_catalog(
+        &self,
+    ) -> RusotoFuture<ImportServerCatalogResponse, ImportServerCatalogError> {
+        match self.import_server_catalog {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockServerMigrationService has no handler for import_server_catalog"),
+        }
+    }
     fn launch_app(
         &self,
         input: LaunchAppRequest,
     ) -> RusotoFuture<LaunchAppResponse, LaunchAppError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.LaunchApp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<LaunchAppResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(LaunchAppError::from_response(response))),
-                )
-            }
-        })
+        match self.launch_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for launch_app"),
+        }
     }
-
-    /// <p>Returns a list of summaries for all applications.</p>
     fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResponse, ListAppsError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.ListApps",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAppsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAppsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_apps {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for list_apps"),
+        }
     }
-
-    /// <p>Creates a launch configuration for an application.</p>
     fn put_app_launch_configuration(
         &self,
         input: PutAppLaunchConfigurationRequest,
     ) -> RusotoFuture<PutAppLaunchConfigurationResponse, PutAppLaunchConfigurationError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.PutAppLaunchConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAppLaunchConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutAppLaunchConfigurationError::from_response(response))
-                }))
+        match self.put_app_launch_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockServerMigrationService has no handler for put_app_launch_configuration")
             }
-        })
+        }
     }
-
-    /// <p>Creates or updates a replication configuration for an application.</p>
     fn put_app_replication_configuration(
         &self,
         input: PutAppReplicationConfigurationRequest,
     ) -> RusotoFuture<PutAppReplicationConfigurationResponse, PutAppReplicationConfigurationError>
     {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.PutAppReplicationConfiguration",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutAppReplicationConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutAppReplicationConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.put_app_replication_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServerMigrationService has no handler for put_app_replication_configuration"
+            ),
+        }
     }
-
-    /// <p>Starts replicating an application.</p>
     fn start_app_replication(
         &self,
         input: StartAppReplicationRequest,
     ) -> RusotoFuture<StartAppReplicationResponse, StartAppReplicationError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.StartAppReplication",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartAppReplicationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartAppReplicationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.start_app_replication {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for start_app_replication"),
+        }
     }
-
-    /// <p>Starts an on-demand replication run for the specified replication job. This replication run starts immediately. This replication run is in addition to the ones already scheduled.</p> <p>There is a limit on the number of on-demand replications runs you can request in a 24-hour period.</p>
     fn start_on_demand_replication_run(
         &self,
         input: StartOnDemandReplicationRunRequest,
     ) -> RusotoFuture<StartOnDemandReplicationRunResponse, StartOnDemandReplicationRunError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.StartOnDemandReplicationRun",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartOnDemandReplicationRunResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartOnDemandReplicationRunError::from_response(response))
-                }))
-            }
-        })
+        match self.start_on_demand_replication_run {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockServerMigrationService has no handler for start_on_demand_replication_run"
+            ),
+        }
     }
-
-    /// <p>Stops replicating an application.</p>
     fn stop_app_replication(
         &self,
         input: StopAppReplicationRequest,
     ) -> RusotoFuture<StopAppReplicationResponse, StopAppReplicationError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.StopAppReplication",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopAppReplicationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopAppReplicationError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_app_replication {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for stop_app_replication"),
+        }
     }
-
-    /// <p>Terminates the stack for an application.</p>
     fn terminate_app(
         &self,
         input: TerminateAppRequest,
     ) -> RusotoFuture<TerminateAppResponse, TerminateAppError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.TerminateApp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TerminateAppResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TerminateAppError::from_response(response))),
-                )
-            }
-        })
+        match self.terminate_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for terminate_app"),
+        }
     }
-
-    /// <p>Updates an application.</p>
     fn update_app(
         &self,
         input: UpdateAppRequest,
     ) -> RusotoFuture<UpdateAppResponse, UpdateAppError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.UpdateApp",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAppResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAppError::from_response(response))),
-                )
-            }
-        })
+        match self.update_app {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for update_app"),
+        }
     }
-
-    /// <p>Updates the specified settings for the specified replication job.</p>
     fn update_replication_job(
         &self,
         input: UpdateReplicationJobRequest,
     ) -> RusotoFuture<UpdateReplicationJobResponse, UpdateReplicationJobError> {
-        let mut request = SignedRequest::new("POST", "sms", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSServerMigrationService_V2016_10_24.UpdateReplicationJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateReplicationJobResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateReplicationJobError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_replication_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockServerMigrationService has no handler for update_replication_job"),
+        }
     }
 }
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The address that you want the Snowball or Snowballs associated with a specific job to be shipped to. Addresses are validated at the time of creation. The address you provide must be located within the serviceable area of your region. Although no individual elements of the <code>Address</code> are required, if the address is invalid or unsupported, then an exception is thrown.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -84,31 +85,191 @@ pub struct Address {
     pub street_3: Option<String>,
 }
 
+impl Validate for Address {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.address_id {
+            errors.check_length(
+                &field_path(path, "address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.city {
+            errors.check_length(
+                &field_path(path, "city"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "city"), &value, ".*");
+        }
+        if let Some(ref value) = self.company {
+            errors.check_length(
+                &field_path(path, "company"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "company"), &value, ".*");
+        }
+        if let Some(ref value) = self.country {
+            errors.check_length(
+                &field_path(path, "country"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "country"), &value, ".*");
+        }
+        if let Some(ref value) = self.landmark {
+            errors.check_length(
+                &field_path(path, "landmark"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "landmark"), &value, ".*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, ".*");
+        }
+        if let Some(ref value) = self.phone_number {
+            errors.check_length(
+                &field_path(path, "phone_number"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "phone_number"), &value, ".*");
+        }
+        if let Some(ref value) = self.postal_code {
+            errors.check_length(
+                &field_path(path, "postal_code"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "postal_code"), &value, ".*");
+        }
+        if let Some(ref value) = self.prefecture_or_district {
+            errors.check_length(
+                &field_path(path, "prefecture_or_district"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "prefecture_or_district"), &value, ".*");
+        }
+        if let Some(ref value) = self.state_or_province {
+            errors.check_length(
+                &field_path(path, "state_or_province"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "state_or_province"), &value, ".*");
+        }
+        if let Some(ref value) = self.street_1 {
+            errors.check_length(
+                &field_path(path, "street_1"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "street_1"), &value, ".*");
+        }
+        if let Some(ref value) = self.street_2 {
+            errors.check_length(
+                &field_path(path, "street_2"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "street_2"), &value, ".*");
+        }
+        if let Some(ref value) = self.street_3 {
+            errors.check_length(
+                &field_path(path, "street_3"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "street_3"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelClusterRequest {
     /// <p>The 39-character ID for the cluster that you want to cancel, for example <code>CID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "ClusterId")]
     pub cluster_id: String,
 }
 
+impl Validate for CancelClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_id"),
+            self.cluster_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "CID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelClusterResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelJobRequest {
     /// <p>The 39-character job ID for the job that you want to cancel, for example <code>JID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for CancelJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "(M|J)ID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelJobResult {}
 
 /// <p>Contains a cluster's state, a cluster's ID, and other important information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterListEntry {
     /// <p>The 39-character ID for the cluster that you want to list, for example <code>CID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "ClusterId")]
@@ -130,7 +291,7 @@ pub struct ClusterListEntry {
 
 /// <p>Contains metadata about a specific cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClusterMetadata {
     /// <p>The automatically generated ID for a specific address.</p>
     #[serde(rename = "AddressId")]
@@ -188,7 +349,7 @@ pub struct ClusterMetadata {
 
 /// <p>A JSON-formatted object that describes a compatible Amazon Machine Image (AMI). For more information on compatible AMIs, see <a href="http://docs.aws.amazon.com/snowball/latest/developer-guide/using-ec2.html">Using Amazon EC2 Compute Instances</a> in the <i>AWS Snowball Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompatibleImage {
     /// <p>The unique identifier for an individual Snowball Edge AMI.</p>
     #[serde(rename = "AmiId")]
@@ -201,14 +362,22 @@ pub struct CompatibleImage {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAddressRequest {
     /// <p>The address that you want the Snowball shipped to.</p>
     #[serde(rename = "Address")]
     pub address: Address,
 }
 
+impl Validate for CreateAddressRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.address
+            .validate_at(&field_path(path, "address"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAddressResult {
     /// <p>The automatically generated ID for a specific address. You'll use this ID when you create a job to specify which address you want the Snowball for that job shipped to.</p>
     #[serde(rename = "AddressId")]
@@ -217,6 +386,7 @@ pub struct CreateAddressResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateClusterRequest {
     /// <p>The ID for the address that you want the cluster shipped to.</p>
     #[serde(rename = "AddressId")]
@@ -255,8 +425,75 @@ pub struct CreateClusterRequest {
     pub snowball_type: Option<String>,
 }
 
+impl Validate for CreateClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "address_id"),
+            self.address_id.chars().count(),
+            Some(40),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "address_id"),
+            &self.address_id,
+            "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, ".*");
+        }
+        if let Some(ref value) = self.forwarding_address_id {
+            errors.check_length(
+                &field_path(path, "forwarding_address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "forwarding_address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_length(
+                &field_path(path, "kms_key_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "kms_key_arn"),
+                &value,
+                "arn:aws.*:kms:.*:[0-9]{12}:key/.*",
+            );
+        }
+        if let Some(ref value) = self.notification {
+            value.validate_at(&field_path(path, "notification"), errors);
+        }
+        self.resources
+            .validate_at(&field_path(path, "resources"), errors);
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            None,
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:aws.*:iam::[0-9]{12}:role/.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateClusterResult {
     /// <p>The automatically generated ID for a cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -265,6 +502,7 @@ pub struct CreateClusterResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateJobRequest {
     /// <p>The ID for the address that you want the Snowball shipped to.</p>
     #[serde(rename = "AddressId")]
@@ -316,8 +554,93 @@ pub struct CreateJobRequest {
     pub snowball_type: Option<String>,
 }
 
+impl Validate for CreateJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.address_id {
+            errors.check_length(
+                &field_path(path, "address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.cluster_id {
+            errors.check_length(
+                &field_path(path, "cluster_id"),
+                value.chars().count(),
+                Some(39),
+                Some(39),
+            );
+            errors.check_pattern(
+                &field_path(path, "cluster_id"),
+                &value,
+                "CID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, ".*");
+        }
+        if let Some(ref value) = self.forwarding_address_id {
+            errors.check_length(
+                &field_path(path, "forwarding_address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "forwarding_address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.kms_key_arn {
+            errors.check_length(
+                &field_path(path, "kms_key_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "kms_key_arn"),
+                &value,
+                "arn:aws.*:kms:.*:[0-9]{12}:key/.*",
+            );
+        }
+        if let Some(ref value) = self.notification {
+            value.validate_at(&field_path(path, "notification"), errors);
+        }
+        if let Some(ref value) = self.resources {
+            value.validate_at(&field_path(path, "resources"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws.*:iam::[0-9]{12}:role/.*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateJobResult {
     /// <p>The automatically generated ID for a job, for example <code>JID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "JobId")]
@@ -327,7 +650,7 @@ pub struct CreateJobResult {
 
 /// <p>Defines the real-time status of a Snowball's data transfer while the device is at AWS. This data is only available while a job has a <code>JobState</code> value of <code>InProgress</code>, for both import and export jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DataTransfer {
     /// <p>The number of bytes transferred between a Snowball and Amazon S3.</p>
     #[serde(rename = "BytesTransferred")]
@@ -348,14 +671,31 @@ pub struct DataTransfer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAddressRequest {
     /// <p>The automatically generated ID for a specific address.</p>
     #[serde(rename = "AddressId")]
     pub address_id: String,
 }
 
+impl Validate for DescribeAddressRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "address_id"),
+            self.address_id.chars().count(),
+            Some(40),
+            Some(40),
+        );
+        errors.check_pattern(
+            &field_path(path, "address_id"),
+            &self.address_id,
+            "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAddressResult {
     /// <p>The address that you want the Snowball or Snowballs associated with a specific job to be shipped to.</p>
     #[serde(rename = "Address")]
@@ -364,6 +704,7 @@ pub struct DescribeAddressResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAddressesRequest {
     /// <p>The number of <code>ADDRESS</code> objects to return.</p>
     #[serde(rename = "MaxResults")]
@@ -375,8 +716,25 @@ pub struct DescribeAddressesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAddressesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAddressesResult {
     /// <p>The Snowball shipping addresses that were created for this account.</p>
     #[serde(rename = "Addresses")]
@@ -389,14 +747,31 @@ pub struct DescribeAddressesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClusterRequest {
     /// <p>The automatically generated ID for a cluster.</p>
     #[serde(rename = "ClusterId")]
     pub cluster_id: String,
 }
 
+impl Validate for DescribeClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_id"),
+            self.cluster_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "CID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClusterResult {
     /// <p>Information about a specific cluster, including shipping information, cluster status, and other important metadata.</p>
     #[serde(rename = "ClusterMetadata")]
@@ -405,14 +780,31 @@ pub struct DescribeClusterResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeJobRequest {
     /// <p>The automatically generated ID for a job, for example <code>JID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for DescribeJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "(M|J)ID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeJobResult {
     /// <p>Information about a specific job, including shipping information, job status, and other important metadata.</p>
     #[serde(rename = "JobMetadata")]
@@ -436,6 +828,31 @@ pub struct Ec2AmiResource {
     pub snowball_ami_id: Option<String>,
 }
 
+impl Validate for Ec2AmiResource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ami_id"),
+            self.ami_id.chars().count(),
+            Some(12),
+            Some(21),
+        );
+        errors.check_pattern(
+            &field_path(path, "ami_id"),
+            &self.ami_id,
+            "(ami-[0-9a-f]{8})|(ami-[0-9a-f]{17})",
+        );
+        if let Some(ref value) = self.snowball_ami_id {
+            errors.check_length(
+                &field_path(path, "snowball_ami_id"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "snowball_ami_id"), &value, ".*");
+        }
+    }
+}
+
 /// <p>The container for the <a>EventTriggerDefinition$EventResourceARN</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EventTriggerDefinition {
@@ -445,15 +862,50 @@ pub struct EventTriggerDefinition {
     pub event_resource_arn: Option<String>,
 }
 
+impl Validate for EventTriggerDefinition {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_resource_arn {
+            errors.check_length(
+                &field_path(path, "event_resource_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "event_resource_arn"),
+                &value,
+                "arn:aws.*:*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobManifestRequest {
     /// <p>The ID for a job that you want to get the manifest file for, for example <code>JID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for GetJobManifestRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "(M|J)ID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobManifestResult {
     /// <p>The Amazon S3 presigned URL for the manifest file associated with the specified <code>JobId</code> value.</p>
     #[serde(rename = "ManifestURI")]
@@ -462,14 +914,31 @@ pub struct GetJobManifestResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetJobUnlockCodeRequest {
     /// <p>The ID for the job that you want to get the <code>UnlockCode</code> value for, for example <code>JID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "JobId")]
     pub job_id: String,
 }
 
+impl Validate for GetJobUnlockCodeRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "(M|J)ID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetJobUnlockCodeResult {
     /// <p>The <code>UnlockCode</code> value for the specified job. The <code>UnlockCode</code> value can be accessed for up to 90 days after the job has been created.</p>
     #[serde(rename = "UnlockCode")]
@@ -478,10 +947,11 @@ pub struct GetJobUnlockCodeResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSnowballUsageRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSnowballUsageResult {
     /// <p>The service limit for number of Snowballs this account can have at once. The default service limit is 1 (one).</p>
     #[serde(rename = "SnowballLimit")]
@@ -495,7 +965,7 @@ pub struct GetSnowballUsageResult {
 
 /// <p>Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of an export job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobListEntry {
     /// <p>The creation date for this job.</p>
     #[serde(rename = "CreationDate")]
@@ -529,7 +999,7 @@ pub struct JobListEntry {
 
 /// <p>Contains job logs. Whenever Snowball is used to import data into or export data out of Amazon S3, you'll have the option of downloading a PDF job report. Job logs are returned as a part of the response syntax of the <code>DescribeJob</code> action in the <code>JobMetadata</code> data type. The job logs can be accessed for up to 60 minutes after this request has been made. To access any of the job logs after 60 minutes have passed, you'll have to make another call to the <code>DescribeJob</code> action.</p> <p>For import jobs, the PDF job report becomes available at the end of the import process. For export jobs, your job report typically becomes available while the Snowball for your job part is being delivered to you.</p> <p>The job report provides you insight into the state of your Amazon S3 data transfer. The report includes details about your job or job part for your records.</p> <p>For deeper visibility into the status of your transferred objects, you can look at the two associated logs: a success log and a failure log. The logs are saved in comma-separated value (CSV) format, and the name of each log includes the ID of the job or job part that the log describes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobLogs {
     /// <p>A link to an Amazon S3 presigned URL where the job completion report is located.</p>
     #[serde(rename = "JobCompletionReportURI")]
@@ -547,7 +1017,7 @@ pub struct JobLogs {
 
 /// <p>Contains information about a specific job including shipping information, job status, and other important metadata. This information is returned as a part of the response syntax of the <code>DescribeJob</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct JobMetadata {
     /// <p>The ID for the address that you want the Snowball shipped to.</p>
     #[serde(rename = "AddressId")]
@@ -636,6 +1106,29 @@ pub struct JobResource {
     pub s3_resources: Option<Vec<S3Resource>>,
 }
 
+impl Validate for JobResource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ec_2_ami_resources {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "ec_2_ami_resources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.lambda_resources {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "lambda_resources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.s3_resources {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "s3_resources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Contains a key range. For export jobs, a <code>S3Resource</code> object can have an optional <code>KeyRange</code> value. The length of the range is defined at job creation, and has either an inclusive <code>BeginMarker</code>, an inclusive <code>EndMarker</code>, or both. Ranges are UTF-8 binary sorted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyRange {
@@ -649,6 +1142,29 @@ pub struct KeyRange {
     pub end_marker: Option<String>,
 }
 
+impl Validate for KeyRange {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.begin_marker {
+            errors.check_length(
+                &field_path(path, "begin_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "begin_marker"), &value, ".*");
+        }
+        if let Some(ref value) = self.end_marker {
+            errors.check_length(
+                &field_path(path, "end_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "end_marker"), &value, ".*");
+        }
+    }
+}
+
 /// <p>Identifies </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LambdaResource {
@@ -662,7 +1178,28 @@ pub struct LambdaResource {
     pub lambda_arn: Option<String>,
 }
 
+impl Validate for LambdaResource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.event_triggers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "event_triggers"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.lambda_arn {
+            errors.check_length(
+                &field_path(path, "lambda_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "lambda_arn"), &value, "arn:aws.*:*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClusterJobsRequest {
     /// <p>The 39-character ID for the cluster that you want to list, for example <code>CID123e4567-e89b-12d3-a456-426655440000</code>.</p>
     #[serde(rename = "ClusterId")]
@@ -677,8 +1214,36 @@ pub struct ListClusterJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListClusterJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "cluster_id"),
+            self.cluster_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "CID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClusterJobsResult {
     /// <p>Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of export jobs. </p>
     #[serde(rename = "JobListEntries")]
@@ -691,6 +1256,7 @@ pub struct ListClusterJobsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClustersRequest {
     /// <p>The number of <code>ClusterListEntry</code> objects to return.</p>
     #[serde(rename = "MaxResults")]
@@ -702,8 +1268,25 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListClustersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListClustersResult {
     /// <p>Each <code>ClusterListEntry</code> object contains a cluster's state, a cluster's ID, and other important status information.</p>
     #[serde(rename = "ClusterListEntries")]
@@ -716,6 +1299,7 @@ pub struct ListClustersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCompatibleImagesRequest {
     /// <p>The maximum number of results for the list of compatible images. Currently, each supported device can store 10 AMIs.</p>
     #[serde(rename = "MaxResults")]
@@ -727,8 +1311,25 @@ pub struct ListCompatibleImagesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCompatibleImagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCompatibleImagesResult {
     /// <p>A JSON-formatted object that describes a compatible AMI.</p>
     #[serde(rename = "CompatibleImages")]
@@ -741,6 +1342,7 @@ pub struct ListCompatibleImagesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListJobsRequest {
     /// <p>The number of <code>JobListEntry</code> objects to return.</p>
     #[serde(rename = "MaxResults")]
@@ -752,8 +1354,25 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListJobsResult {
     /// <p>Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of export jobs. </p>
     #[serde(rename = "JobListEntries")]
@@ -782,6 +1401,24 @@ pub struct Notification {
     pub sns_topic_arn: Option<String>,
 }
 
+impl Validate for Notification {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.sns_topic_arn {
+            errors.check_length(
+                &field_path(path, "sns_topic_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "sns_topic_arn"),
+                &value,
+                "arn:aws.*:sns:.*:[0-9]{12}:.*",
+            );
+        }
+    }
+}
+
 /// <p>Each <code>S3Resource</code> object represents an Amazon S3 bucket that your transferred data will be exported from or imported into. For export jobs, this object can have an optional <code>KeyRange</code> value. The length of the range is defined at job creation, and has either an inclusive <code>BeginMarker</code>, an inclusive <code>EndMarker</code>, or both. Ranges are UTF-8 binary sorted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Resource {
@@ -795,9 +1432,26 @@ pub struct S3Resource {
     pub key_range: Option<KeyRange>,
 }
 
+impl Validate for S3Resource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket_arn {
+            errors.check_length(
+                &field_path(path, "bucket_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "bucket_arn"), &value, "arn:aws.*:*");
+        }
+        if let Some(ref value) = self.key_range {
+            value.validate_at(&field_path(path, "key_range"), errors);
+        }
+    }
+}
+
 /// <p>The <code>Status</code> and <code>TrackingNumber</code> information for an inbound or outbound shipment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Shipment {
     /// <p>Status information for a shipment.</p>
     #[serde(rename = "Status")]
@@ -811,7 +1465,7 @@ pub struct Shipment {
 
 /// <p>A job's shipping information, including inbound and outbound tracking numbers and shipping speed options.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ShippingDetails {
     /// <p>The <code>Status</code> and <code>TrackingNumber</code> values for a Snowball being returned to AWS for a particular job.</p>
     #[serde(rename = "InboundShipment")]
@@ -828,6 +1482,7 @@ pub struct ShippingDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateClusterRequest {
     /// <p>The ID of the updated <a>Address</a> object.</p>
     #[serde(rename = "AddressId")]
@@ -862,11 +1517,82 @@ pub struct UpdateClusterRequest {
     pub shipping_option: Option<String>,
 }
 
+impl Validate for UpdateClusterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.address_id {
+            errors.check_length(
+                &field_path(path, "address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "cluster_id"),
+            self.cluster_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "cluster_id"),
+            &self.cluster_id,
+            "CID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, ".*");
+        }
+        if let Some(ref value) = self.forwarding_address_id {
+            errors.check_length(
+                &field_path(path, "forwarding_address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "forwarding_address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.notification {
+            value.validate_at(&field_path(path, "notification"), errors);
+        }
+        if let Some(ref value) = self.resources {
+            value.validate_at(&field_path(path, "resources"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws.*:iam::[0-9]{12}:role/.*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateClusterResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateJobRequest {
     /// <p>The ID of the updated <a>Address</a> object.</p>
     #[serde(rename = "AddressId")]
@@ -905,8 +1631,78 @@ pub struct UpdateJobRequest {
     pub snowball_capacity_preference: Option<String>,
 }
 
+impl Validate for UpdateJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.address_id {
+            errors.check_length(
+                &field_path(path, "address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "description"), &value, ".*");
+        }
+        if let Some(ref value) = self.forwarding_address_id {
+            errors.check_length(
+                &field_path(path, "forwarding_address_id"),
+                value.chars().count(),
+                Some(40),
+                Some(40),
+            );
+            errors.check_pattern(
+                &field_path(path, "forwarding_address_id"),
+                &value,
+                "ADID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(39),
+            Some(39),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "(M|J)ID[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        );
+        if let Some(ref value) = self.notification {
+            value.validate_at(&field_path(path, "notification"), errors);
+        }
+        if let Some(ref value) = self.resources {
+            value.validate_at(&field_path(path, "resources"), errors);
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "role_arn"),
+                &value,
+                "arn:aws.*:iam::[0-9]{12}:role/.*",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateJobResult {}
 
 /// Errors returned by CancelCluster
@@ -933,7 +1729,12 @@ impl CancelClusterError {
                 "KMSRequestFailedException" => {
                     return RusotoError::Service(CancelClusterError::KMSRequestFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -978,7 +1779,12 @@ impl CancelJobError {
                 "KMSRequestFailedException" => {
                     return RusotoError::Service(CancelJobError::KMSRequestFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1018,7 +1824,12 @@ impl CreateAddressError {
                 "UnsupportedAddressException" => {
                     return RusotoError::Service(CreateAddressError::UnsupportedAddress(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1069,7 +1880,12 @@ impl CreateClusterError {
                 "KMSRequestFailedException" => {
                     return RusotoError::Service(CreateClusterError::KMSRequestFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1125,7 +1941,12 @@ impl CreateJobError {
                 "KMSRequestFailedException" => {
                     return RusotoError::Service(CreateJobError::KMSRequestFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1162,7 +1983,12 @@ impl DescribeAddressError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(DescribeAddressError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1200,7 +2026,12 @@ impl DescribeAddressesError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(DescribeAddressesError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1234,7 +2065,12 @@ impl DescribeClusterError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(DescribeClusterError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1267,7 +2103,12 @@ impl DescribeJobError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(DescribeJobError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1305,7 +2146,12 @@ impl GetJobManifestError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(GetJobManifestError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1344,7 +2190,12 @@ impl GetJobUnlockCodeError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(GetJobUnlockCodeError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1372,7 +2223,12 @@ impl GetSnowballUsageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSnowballUsageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1408,7 +2264,12 @@ impl ListClusterJobsError {
                 "InvalidResourceException" => {
                     return RusotoError::Service(ListClusterJobsError::InvalidResource(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1442,7 +2303,12 @@ impl ListClustersError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListClustersError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1484,7 +2350,12 @@ impl ListCompatibleImagesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1518,7 +2389,12 @@ impl ListJobsError {
                 "InvalidNextTokenException" => {
                     return RusotoError::Service(ListJobsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1573,7 +2449,12 @@ impl UpdateClusterError {
                 "KMSRequestFailedException" => {
                     return RusotoError::Service(UpdateClusterError::KMSRequestFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1635,7 +2516,12 @@ impl UpdateJobError {
                 "KMSRequestFailedException" => {
                     return RusotoError::Service(UpdateJobError::KMSRequestFailed(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1754,93 +2640,395 @@ pub trait Snowball {
     /// <p>While a job's <code>JobState</code> value is <code>New</code>, you can update some of the information associated with a job. Once the job changes to a different job state, usually within 60 minutes of the job being created, this action is no longer available.</p>
     fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError>;
 }
-/// A client for the Amazon Snowball API.
-#[derive(Clone)]
-pub struct SnowballClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SnowballClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SnowballClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SnowballClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SnowballClient {
-        SnowballClient { client, region }
-    }
-}
-
-impl Snowball for SnowballClient {
-    /// <p>Cancels a cluster job. You can only cancel a cluster job while it's in the <code>AwaitingQuorum</code> status. You'll have at least an hour after creating a cluster job to cancel it.</p>
+const _: Option<&dyn Snowball> = None;
+impl<'a, T: Snowball + ?Sized> Snowball for &'a T {
     fn cancel_cluster(
         &self,
         input: CancelClusterRequest,
     ) -> RusotoFuture<CancelClusterResult, CancelClusterError> {
-        let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSIESnowballJobManagementService.CancelCluster",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelClusterResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelClusterError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_cluster(input)
     }
-
-    /// <p>Cancels the specified job. You can only cancel a job before its <code>JobState</code> value changes to <code>PreparingAppliance</code>. Requesting the <code>ListJobs</code> or <code>DescribeJob</code> action returns a job's <code>JobState</code> as part of the response element data returned.</p>
     fn cancel_job(&self, input: CancelJobRequest) -> RusotoFuture<CancelJobResult, CancelJobError> {
-        let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSIESnowballJobManagementService.CancelJob",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<CancelJobResult, _>()
-                }))
+        (**self).cancel_job(input)
+    }
+    fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> RusotoFuture<CreateAddressResult, CreateAddressError> {
+        (**self).create_address(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_job(&self, input: CreateJobRequest) -> RusotoFuture<CreateJobResult, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> RusotoFuture<DescribeAddressResult, DescribeAddressError> {
+        (**self).describe_address(input)
+    }
+    fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> RusotoFuture<DescribeAddressesResult, DescribeAddressesError> {
+        (**self).describe_addresses(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResult, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> RusotoFuture<DescribeJobResult, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> RusotoFuture<GetJobManifestResult, GetJobManifestError> {
+        (**self).get_job_manifest(input)
+    }
+    fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> RusotoFuture<GetJobUnlockCodeResult, GetJobUnlockCodeError> {
+        (**self).get_job_unlock_code(input)
+    }
+    fn get_snowball_usage(&self) -> RusotoFuture<GetSnowballUsageResult, GetSnowballUsageError> {
+        (**self).get_snowball_usage()
+    }
+    fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> RusotoFuture<ListClusterJobsResult, ListClusterJobsError> {
+        (**self).list_cluster_jobs(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResult, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> RusotoFuture<ListCompatibleImagesResult, ListCompatibleImagesError> {
+        (**self).list_compatible_images(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResult, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+impl<T: Snowball + ?Sized> Snowball for Box<T> {
+    fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> RusotoFuture<CancelClusterResult, CancelClusterError> {
+        (**self).cancel_cluster(input)
+    }
+    fn cancel_job(&self, input: CancelJobRequest) -> RusotoFuture<CancelJobResult, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> RusotoFuture<CreateAddressResult, CreateAddressError> {
+        (**self).create_address(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_job(&self, input: CreateJobRequest) -> RusotoFuture<CreateJobResult, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> RusotoFuture<DescribeAddressResult, DescribeAddressError> {
+        (**self).describe_address(input)
+    }
+    fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> RusotoFuture<DescribeAddressesResult, DescribeAddressesError> {
+        (**self).describe_addresses(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResult, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> RusotoFuture<DescribeJobResult, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> RusotoFuture<GetJobManifestResult, GetJobManifestError> {
+        (**self).get_job_manifest(input)
+    }
+    fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> RusotoFuture<GetJobUnlockCodeResult, GetJobUnlockCodeError> {
+        (**self).get_job_unlock_code(input)
+    }
+    fn get_snowball_usage(&self) -> RusotoFuture<GetSnowballUsageResult, GetSnowballUsageError> {
+        (**self).get_snowball_usage()
+    }
+    fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> RusotoFuture<ListClusterJobsResult, ListClusterJobsError> {
+        (**self).list_cluster_jobs(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResult, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> RusotoFuture<ListCompatibleImagesResult, ListCompatibleImagesError> {
+        (**self).list_compatible_images(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResult, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+impl<T: Snowball + ?Sized> Snowball for ::std::sync::Arc<T> {
+    fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> RusotoFuture<CancelClusterResult, CancelClusterError> {
+        (**self).cancel_cluster(input)
+    }
+    fn cancel_job(&self, input: CancelJobRequest) -> RusotoFuture<CancelJobResult, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> RusotoFuture<CreateAddressResult, CreateAddressError> {
+        (**self).create_address(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_job(&self, input: CreateJobRequest) -> RusotoFuture<CreateJobResult, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> RusotoFuture<DescribeAddressResult, DescribeAddressError> {
+        (**self).describe_address(input)
+    }
+    fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> RusotoFuture<DescribeAddressesResult, DescribeAddressesError> {
+        (**self).describe_addresses(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResult, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> RusotoFuture<DescribeJobResult, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> RusotoFuture<GetJobManifestResult, GetJobManifestError> {
+        (**self).get_job_manifest(input)
+    }
+    fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> RusotoFuture<GetJobUnlockCodeResult, GetJobUnlockCodeError> {
+        (**self).get_job_unlock_code(input)
+    }
+    fn get_snowball_usage(&self) -> RusotoFuture<GetSnowballUsageResult, GetSnowballUsageError> {
+        (**self).get_snowball_usage()
+    }
+    fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> RusotoFuture<ListClusterJobsResult, ListClusterJobsError> {
+        (**self).list_cluster_jobs(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResult, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> RusotoFuture<ListCompatibleImagesResult, ListCompatibleImagesError> {
+        (**self).list_compatible_images(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResult, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+/// A client for the Amazon Snowball API.
+#[derive(Clone)]
+pub struct SnowballClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SnowballClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SnowballClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SnowballClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SnowballClient {
+        SnowballClient { client, region }
+    }
+}
+
+impl Snowball for SnowballClient {
+    /// <p>Cancels a cluster job. You can only cancel a cluster job while it's in the <code>AwaitingQuorum</code> status. You'll have at least an hour after creating a cluster job to cancel it.</p>
+    fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> RusotoFuture<CancelClusterResult, CancelClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CancelCluster");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSIESnowballJobManagementService.CancelCluster",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelClusterResult, _>(
+                    response,
+                    "CancelCluster",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelClusterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels the specified job. You can only cancel a job before its <code>JobState</code> value changes to <code>PreparingAppliance</code>. Requesting the <code>ListJobs</code> or <code>DescribeJob</code> action returns a job's <code>JobState</code> as part of the response element data returned.</p>
+    fn cancel_job(&self, input: CancelJobRequest) -> RusotoFuture<CancelJobResult, CancelJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CancelJob");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSIESnowballJobManagementService.CancelJob",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelJobResult, _>(response, "CancelJob")
             } else {
                 Box::new(
                     response
@@ -1857,7 +3045,16 @@ impl Snowball for SnowballClient {
         &self,
         input: CreateAddressRequest,
     ) -> RusotoFuture<CreateAddressResult, CreateAddressError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1869,10 +3066,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAddressResult, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateAddressResult, _>(
+                    response,
+                    "CreateAddress",
+                )
             } else {
                 Box::new(
                     response
@@ -1889,7 +3086,16 @@ impl Snowball for SnowballClient {
         &self,
         input: CreateClusterRequest,
     ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1901,10 +3107,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateClusterResult, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateClusterResult, _>(
+                    response,
+                    "CreateCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -1918,7 +3124,16 @@ impl Snowball for SnowballClient {
 
     /// <p>Creates a job to import or export data between Amazon S3 and your on-premises data center. Your AWS account must have the right trust policies and permissions in place to create a job for Snowball. If you're creating a job for a node in a cluster, you only need to provide the <code>clusterId</code> value; the other job attributes are inherited from the cluster. </p>
     fn create_job(&self, input: CreateJobRequest) -> RusotoFuture<CreateJobResult, CreateJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("CreateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1930,9 +3145,7 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<CreateJobResult, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateJobResult, _>(response, "CreateJob")
             } else {
                 Box::new(
                     response
@@ -1949,7 +3162,16 @@ impl Snowball for SnowballClient {
         &self,
         input: DescribeAddressRequest,
     ) -> RusotoFuture<DescribeAddressResult, DescribeAddressError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeAddress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1961,10 +3183,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAddressResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeAddressResult, _>(
+                    response,
+                    "DescribeAddress",
+                )
             } else {
                 Box::new(
                     response
@@ -1981,7 +3203,16 @@ impl Snowball for SnowballClient {
         &self,
         input: DescribeAddressesRequest,
     ) -> RusotoFuture<DescribeAddressesResult, DescribeAddressesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeAddresses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1993,10 +3224,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAddressesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeAddressesResult, _>(
+                    response,
+                    "DescribeAddresses",
+                )
             } else {
                 Box::new(
                     response
@@ -2013,7 +3244,16 @@ impl Snowball for SnowballClient {
         &self,
         input: DescribeClusterRequest,
     ) -> RusotoFuture<DescribeClusterResult, DescribeClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2025,10 +3265,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeClusterResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeClusterResult, _>(
+                    response,
+                    "DescribeCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -2045,7 +3285,16 @@ impl Snowball for SnowballClient {
         &self,
         input: DescribeJobRequest,
     ) -> RusotoFuture<DescribeJobResult, DescribeJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("DescribeJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2057,10 +3306,7 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeJobResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeJobResult, _>(response, "DescribeJob")
             } else {
                 Box::new(
                     response
@@ -2077,7 +3323,16 @@ impl Snowball for SnowballClient {
         &self,
         input: GetJobManifestRequest,
     ) -> RusotoFuture<GetJobManifestResult, GetJobManifestError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetJobManifest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2089,10 +3344,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetJobManifestResult, _>()
-                }))
+                proto::json::deserialize_streaming::<GetJobManifestResult, _>(
+                    response,
+                    "GetJobManifest",
+                )
             } else {
                 Box::new(
                     response
@@ -2109,7 +3364,16 @@ impl Snowball for SnowballClient {
         &self,
         input: GetJobUnlockCodeRequest,
     ) -> RusotoFuture<GetJobUnlockCodeResult, GetJobUnlockCodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetJobUnlockCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2121,10 +3385,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetJobUnlockCodeResult, _>()
-                }))
+                proto::json::deserialize_streaming::<GetJobUnlockCodeResult, _>(
+                    response,
+                    "GetJobUnlockCode",
+                )
             } else {
                 Box::new(
                     response
@@ -2139,6 +3403,7 @@ impl Snowball for SnowballClient {
     /// <p>Returns information about the Snowball service limit for your account, and also the number of Snowballs your account has in use.</p> <p>The default service limit for the number of Snowballs that you can have at one time is 1. If you want to increase your service limit, contact AWS Support.</p>
     fn get_snowball_usage(&self) -> RusotoFuture<GetSnowballUsageResult, GetSnowballUsageError> {
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("GetSnowballUsage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2149,10 +3414,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSnowballUsageResult, _>()
-                }))
+                proto::json::deserialize_streaming::<GetSnowballUsageResult, _>(
+                    response,
+                    "GetSnowballUsage",
+                )
             } else {
                 Box::new(
                     response
@@ -2169,7 +3434,16 @@ impl Snowball for SnowballClient {
         &self,
         input: ListClusterJobsRequest,
     ) -> RusotoFuture<ListClusterJobsResult, ListClusterJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListClusterJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2181,10 +3455,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListClusterJobsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListClusterJobsResult, _>(
+                    response,
+                    "ListClusterJobs",
+                )
             } else {
                 Box::new(
                     response
@@ -2201,7 +3475,16 @@ impl Snowball for SnowballClient {
         &self,
         input: ListClustersRequest,
     ) -> RusotoFuture<ListClustersResult, ListClustersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2213,10 +3496,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListClustersResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListClustersResult, _>(
+                    response,
+                    "ListClusters",
+                )
             } else {
                 Box::new(
                     response
@@ -2233,7 +3516,16 @@ impl Snowball for SnowballClient {
         &self,
         input: ListCompatibleImagesRequest,
     ) -> RusotoFuture<ListCompatibleImagesResult, ListCompatibleImagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListCompatibleImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2245,10 +3537,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCompatibleImagesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListCompatibleImagesResult, _>(
+                    response,
+                    "ListCompatibleImages",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2261,7 +3553,16 @@ impl Snowball for SnowballClient {
 
     /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of export jobs. Calling this API action in one of the US regions will return jobs from the list of all jobs associated with this account in all US regions.</p>
     fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSIESnowballJobManagementService.ListJobs");
@@ -2270,9 +3571,7 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ListJobsResult, _>(response, "ListJobs")
             } else {
                 Box::new(
                     response
@@ -2289,7 +3588,16 @@ impl Snowball for SnowballClient {
         &self,
         input: UpdateClusterRequest,
     ) -> RusotoFuture<UpdateClusterResult, UpdateClusterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("UpdateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2301,10 +3609,10 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateClusterResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateClusterResult, _>(
+                    response,
+                    "UpdateCluster",
+                )
             } else {
                 Box::new(
                     response
@@ -2318,7 +3626,16 @@ impl Snowball for SnowballClient {
 
     /// <p>While a job's <code>JobState</code> value is <code>New</code>, you can update some of the information associated with a job. Once the job changes to a different job state, usually within 60 minutes of the job being created, this action is no longer available.</p>
     fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "snowball", &self.region, "/");
+        request.set_operation("UpdateJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2330,9 +3647,7 @@ impl Snowball for SnowballClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateJobResult, _>(response, "UpdateJob")
             } else {
                 Box::new(
                     response
@@ -2344,3 +3659,603 @@ impl Snowball for SnowballClient {
         })
     }
 }
+
+/// A mock of the Amazon Snowball API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSnowball {
+    cancel_cluster: Option<
+        Box<
+            dyn Fn(
+                    CancelClusterRequest,
+                )
+                    -> ::std::result::Result<CancelClusterResult, RusotoError<CancelClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_job: Option<
+        Box<
+            dyn Fn(
+                    CancelJobRequest,
+                )
+                    -> ::std::result::Result<CancelJobResult, RusotoError<CancelJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_address: Option<
+        Box<
+            dyn Fn(
+                    CreateAddressRequest,
+                )
+                    -> ::std::result::Result<CreateAddressResult, RusotoError<CreateAddressError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_cluster: Option<
+        Box<
+            dyn Fn(
+                    CreateClusterRequest,
+                )
+                    -> ::std::result::Result<CreateClusterResult, RusotoError<CreateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_job: Option<
+        Box<
+            dyn Fn(
+                    CreateJobRequest,
+                )
+                    -> ::std::result::Result<CreateJobResult, RusotoError<CreateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_address: Option<
+        Box<
+            dyn Fn(
+                    DescribeAddressRequest,
+                ) -> ::std::result::Result<
+                    DescribeAddressResult,
+                    RusotoError<DescribeAddressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_addresses: Option<
+        Box<
+            dyn Fn(
+                    DescribeAddressesRequest,
+                ) -> ::std::result::Result<
+                    DescribeAddressesResult,
+                    RusotoError<DescribeAddressesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cluster: Option<
+        Box<
+            dyn Fn(
+                    DescribeClusterRequest,
+                ) -> ::std::result::Result<
+                    DescribeClusterResult,
+                    RusotoError<DescribeClusterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_job: Option<
+        Box<
+            dyn Fn(
+                    DescribeJobRequest,
+                )
+                    -> ::std::result::Result<DescribeJobResult, RusotoError<DescribeJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_job_manifest: Option<
+        Box<
+            dyn Fn(
+                    GetJobManifestRequest,
+                )
+                    -> ::std::result::Result<GetJobManifestResult, RusotoError<GetJobManifestError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_job_unlock_code: Option<
+        Box<
+            dyn Fn(
+                    GetJobUnlockCodeRequest,
+                ) -> ::std::result::Result<
+                    GetJobUnlockCodeResult,
+                    RusotoError<GetJobUnlockCodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_snowball_usage: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetSnowballUsageResult,
+                    RusotoError<GetSnowballUsageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_cluster_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListClusterJobsRequest,
+                ) -> ::std::result::Result<
+                    ListClusterJobsResult,
+                    RusotoError<ListClusterJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_clusters: Option<
+        Box<
+            dyn Fn(
+                    ListClustersRequest,
+                )
+                    -> ::std::result::Result<ListClustersResult, RusotoError<ListClustersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_compatible_images: Option<
+        Box<
+            dyn Fn(
+                    ListCompatibleImagesRequest,
+                ) -> ::std::result::Result<
+                    ListCompatibleImagesResult,
+                    RusotoError<ListCompatibleImagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListJobsRequest,
+                )
+                    -> ::std::result::Result<ListJobsResult, RusotoError<ListJobsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_cluster: Option<
+        Box<
+            dyn Fn(
+                    UpdateClusterRequest,
+                )
+                    -> ::std::result::Result<UpdateClusterResult, RusotoError<UpdateClusterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_job: Option<
+        Box<
+            dyn Fn(
+                    UpdateJobRequest,
+                )
+                    -> ::std::result::Result<UpdateJobResult, RusotoError<UpdateJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSnowball {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSnowball {
+        Default::default()
+    }
+
+    /// Answers `cancel_cluster` with what `handler` returns for its input.
+    pub fn with_cancel_cluster<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                CancelClusterRequest,
+            )
+                -> ::std::result::Result<CancelClusterResult, RusotoError<CancelClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_job` with what `handler` returns for its input.
+    pub fn with_cancel_job<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                CancelJobRequest,
+            ) -> ::std::result::Result<CancelJobResult, RusotoError<CancelJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_address` with what `handler` returns for its input.
+    pub fn with_create_address<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                CreateAddressRequest,
+            )
+                -> ::std::result::Result<CreateAddressResult, RusotoError<CreateAddressError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_address = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cluster` with what `handler` returns for its input.
+    pub fn with_create_cluster<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                CreateClusterRequest,
+            )
+                -> ::std::result::Result<CreateClusterResult, RusotoError<CreateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_job` with what `handler` returns for its input.
+    pub fn with_create_job<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                CreateJobRequest,
+            ) -> ::std::result::Result<CreateJobResult, RusotoError<CreateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_address` with what `handler` returns for its input.
+    pub fn with_describe_address<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                DescribeAddressRequest,
+            )
+                -> ::std::result::Result<DescribeAddressResult, RusotoError<DescribeAddressError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_address = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_addresses` with what `handler` returns for its input.
+    pub fn with_describe_addresses<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                DescribeAddressesRequest,
+            ) -> ::std::result::Result<
+                DescribeAddressesResult,
+                RusotoError<DescribeAddressesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_addresses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cluster` with what `handler` returns for its input.
+    pub fn with_describe_cluster<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                DescribeClusterRequest,
+            )
+                -> ::std::result::Result<DescribeClusterResult, RusotoError<DescribeClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_job` with what `handler` returns for its input.
+    pub fn with_describe_job<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                DescribeJobRequest,
+            )
+                -> ::std::result::Result<DescribeJobResult, RusotoError<DescribeJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_manifest` with what `handler` returns for its input.
+    pub fn with_get_job_manifest<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                GetJobManifestRequest,
+            )
+                -> ::std::result::Result<GetJobManifestResult, RusotoError<GetJobManifestError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_manifest = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_job_unlock_code` with what `handler` returns for its input.
+    pub fn with_get_job_unlock_code<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                GetJobUnlockCodeRequest,
+            )
+                -> ::std::result::Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_job_unlock_code = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_snowball_usage` with what `handler` returns.
+    pub fn with_get_snowball_usage<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn()
+                -> ::std::result::Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_snowball_usage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_cluster_jobs` with what `handler` returns for its input.
+    pub fn with_list_cluster_jobs<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                ListClusterJobsRequest,
+            )
+                -> ::std::result::Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_cluster_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_clusters` with what `handler` returns for its input.
+    pub fn with_list_clusters<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                ListClustersRequest,
+            )
+                -> ::std::result::Result<ListClustersResult, RusotoError<ListClustersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_clusters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_compatible_images` with what `handler` returns for its input.
+    pub fn with_list_compatible_images<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                ListCompatibleImagesRequest,
+            ) -> ::std::result::Result<
+                ListCompatibleImagesResult,
+                RusotoError<ListCompatibleImagesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_compatible_images = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_jobs` with what `handler` returns for its input.
+    pub fn with_list_jobs<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(ListJobsRequest) -> ::std::result::Result<ListJobsResult, RusotoError<ListJobsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_cluster` with what `handler` returns for its input.
+    pub fn with_update_cluster<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                UpdateClusterRequest,
+            )
+                -> ::std::result::Result<UpdateClusterResult, RusotoError<UpdateClusterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_cluster = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_job` with what `handler` returns for its input.
+    pub fn with_update_job<F>(mut self, handler: F) -> MockSnowball
+    where
+        F: Fn(
+                UpdateJobRequest,
+            ) -> ::std::result::Result<UpdateJobResult, RusotoError<UpdateJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_job = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Snowball for MockSnowball {
+    fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> RusotoFuture<CancelClusterResult, CancelClusterError> {
+        match self.cancel_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for cancel_cluster"),
+        }
+    }
+    fn cancel_job(&self, input: CancelJobRequest) -> RusotoFuture<CancelJobResult, CancelJobError> {
+        match self.cancel_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for cancel_job"),
+        }
+    }
+    fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> RusotoFuture<CreateAddressResult, CreateAddressError> {
+        match self.create_address {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for create_address"),
+        }
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        match self.create_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for create_cluster"),
+        }
+    }
+    fn create_job(&self, input: CreateJobRequest) -> RusotoFuture<CreateJobResult, CreateJobError> {
+        match self.create_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for create_job"),
+        }
+    }
+    fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> RusotoFuture<DescribeAddressResult, DescribeAddressError> {
+        match self.describe_address {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for describe_address"),
+        }
+    }
+    fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> RusotoFuture<DescribeAddressesResult, DescribeAddressesError> {
+        match self.describe_addresses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for describe_addresses"),
+        }
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResult, DescribeClusterError> {
+        match self.describe_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for describe_cluster"),
+        }
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> RusotoFuture<DescribeJobResult, DescribeJobError> {
+        match self.describe_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for describe_job"),
+        }
+    }
+    fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> RusotoFuture<GetJobManifestResult, GetJobManifestError> {
+        match self.get_job_manifest {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for get_job_manifest"),
+        }
+    }
+    fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> RusotoFuture<GetJobUnlockCodeResult, GetJobUnlockCodeError> {
+        match self.get_job_unlock_code {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for get_job_unlock_code"),
+        }
+    }
+    fn get_snowball_usage(&self) -> RusotoFuture<GetSnowballUsageResult, GetSnowballUsageError> {
+        match self.get_snowball_usage {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockSnowball has no handler for get_snowball_usage"),
+        }
+    }
+    fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> RusotoFuture<ListClusterJobsResult, ListClusterJobsError> {
+        match self.list_cluster_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for list_cluster_jobs"),
+        }
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResult, ListClustersError> {
+        match self.list_clusters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for list_clusters"),
+        }
+    }
+    fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> RusotoFuture<ListCompatibleImagesResult, ListCompatibleImagesError> {
+        match self.list_compatible_images {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for list_compatible_images"),
+        }
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        match self.list_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for list_jobs"),
+        }
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResult, UpdateClusterError> {
+        match self.update_cluster {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for update_cluster"),
+        }
+    }
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<UpdateJobResult, UpdateJobError> {
+        match self.update_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSnowball has no handler for update_job"),
+        }
+    }
+}
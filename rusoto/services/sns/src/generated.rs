@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -57,6 +58,8 @@ impl ActionsListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddPermissionInput {
     /// <p>The AWS account IDs of the users (principals) who will be given access to the specified actions. The users must have AWS accounts, but do not need to be signed up for this service.</p>
     pub aws_account_id: Vec<String>,
@@ -119,7 +122,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -127,6 +130,8 @@ impl BooleanDeserializer {
 }
 /// <p>The input for the <code>CheckIfPhoneNumberIsOptedOut</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CheckIfPhoneNumberIsOptedOutInput {
     /// <p>The phone number for which you want to check the opt out status.</p>
     pub phone_number: String,
@@ -147,6 +152,8 @@ impl CheckIfPhoneNumberIsOptedOutInputSerializer {
 
 /// <p>The response from the <code>CheckIfPhoneNumberIsOptedOut</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CheckIfPhoneNumberIsOptedOutResponse {
     /// <p><p>Indicates whether the phone number is opted out:</p> <ul> <li> <p> <code>true</code> – The phone number is opted out, meaning you cannot publish SMS messages to it.</p> </li> <li> <p> <code>false</code> – The phone number is opted in, meaning you can publish SMS messages to it.</p> </li> </ul></p>
     pub is_opted_out: Option<bool>,
@@ -177,6 +184,8 @@ impl CheckIfPhoneNumberIsOptedOutResponseDeserializer {
 }
 /// <p>Input for ConfirmSubscription action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmSubscriptionInput {
     /// <p>Disallows unauthenticated unsubscribes of the subscription. If the value of this parameter is <code>true</code> and the request has an AWS signature, then only the topic owner and the subscription owner can unsubscribe the endpoint. The unsubscribe action requires AWS authentication. </p>
     pub authenticate_on_unsubscribe: Option<String>,
@@ -208,6 +217,8 @@ impl ConfirmSubscriptionInputSerializer {
 
 /// <p>Response for ConfirmSubscriptions action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ConfirmSubscriptionResponse {
     /// <p>The ARN of the created subscription.</p>
     pub subscription_arn: Option<String>,
@@ -240,6 +251,8 @@ impl ConfirmSubscriptionResponseDeserializer {
 }
 /// <p>Response from CreateEndpoint action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateEndpointResponse {
     /// <p>EndpointArn returned from CreateEndpoint action.</p>
     pub endpoint_arn: Option<String>,
@@ -265,6 +278,8 @@ impl CreateEndpointResponseDeserializer {
 }
 /// <p>Input for CreatePlatformApplication action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlatformApplicationInput {
     /// <p>For a list of attributes, see <a href="https://docs.aws.amazon.com/sns/latest/api/API_SetPlatformApplicationAttributes.html">SetPlatformApplicationAttributes</a> </p>
     pub attributes: ::std::collections::HashMap<String, String>,
@@ -295,6 +310,8 @@ impl CreatePlatformApplicationInputSerializer {
 
 /// <p>Response from CreatePlatformApplication action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlatformApplicationResponse {
     /// <p>PlatformApplicationArn is returned.</p>
     pub platform_application_arn: Option<String>,
@@ -327,6 +344,8 @@ impl CreatePlatformApplicationResponseDeserializer {
 }
 /// <p>Input for CreatePlatformEndpoint action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePlatformEndpointInput {
     /// <p>For a list of attributes, see <a href="https://docs.aws.amazon.com/sns/latest/api/API_SetEndpointAttributes.html">SetEndpointAttributes</a>.</p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -367,6 +386,8 @@ impl CreatePlatformEndpointInputSerializer {
 
 /// <p>Input for CreateTopic action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTopicInput {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>CreateTopic</code> action uses:</p> <ul> <li> <p> <code>DeliveryPolicy</code> – The policy that defines how Amazon SNS retries failed deliveries to HTTP/S endpoints.</p> </li> <li> <p> <code>DisplayName</code> – The display name to use for a topic with SMS subscriptions.</p> </li> <li> <p> <code>Policy</code> – The policy that defines who can access your topic. By default, only the topic owner can publish or subscribe to the topic.</p> </li> </ul> <p>The following attribute applies only to <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SNS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-server-side-encryption.html#sse-key-terms">Key Terms</a>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -376,6 +397,17 @@ pub struct CreateTopicInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateTopicInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `CreateTopicInput` contents to a `SignedRequest`.
 struct CreateTopicInputSerializer;
 impl CreateTopicInputSerializer {
@@ -401,6 +433,8 @@ impl CreateTopicInputSerializer {
 
 /// <p>Response from CreateTopic action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTopicResponse {
     /// <p>The Amazon Resource Name (ARN) assigned to the created topic.</p>
     pub topic_arn: Option<String>,
@@ -438,6 +472,8 @@ impl DelegatesListSerializer {
 
 /// <p>Input for DeleteEndpoint action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteEndpointInput {
     /// <p>EndpointArn of endpoint to delete.</p>
     pub endpoint_arn: String,
@@ -458,6 +494,8 @@ impl DeleteEndpointInputSerializer {
 
 /// <p>Input for DeletePlatformApplication action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePlatformApplicationInput {
     /// <p>PlatformApplicationArn of platform application object to delete.</p>
     pub platform_application_arn: String,
@@ -480,6 +518,8 @@ impl DeletePlatformApplicationInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTopicInput {
     /// <p>The ARN of the topic you want to delete.</p>
     pub topic_arn: String,
@@ -511,6 +551,8 @@ impl EndpointDeserializer {
 }
 /// <p>Input for GetEndpointAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEndpointAttributesInput {
     /// <p>EndpointArn for GetEndpointAttributes input.</p>
     pub endpoint_arn: String,
@@ -531,6 +573,8 @@ impl GetEndpointAttributesInputSerializer {
 
 /// <p>Response from GetEndpointAttributes of the EndpointArn.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEndpointAttributesResponse {
     /// <p><p>Attributes include the following:</p> <ul> <li> <p> <code>CustomUserData</code> – arbitrary user data to associate with the endpoint. Amazon SNS does not use this data. The data must be in UTF-8 format and less than 2KB.</p> </li> <li> <p> <code>Enabled</code> – flag that enables/disables delivery to the endpoint. Amazon SNS will set this to false when a notification service indicates to Amazon SNS that the endpoint is invalid. Users can set it back to true, typically after updating Token.</p> </li> <li> <p> <code>Token</code> – device token, also referred to as a registration id, for an app and mobile device. This is returned from the notification service when an app and mobile device are registered with the notification service.</p> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -563,6 +607,8 @@ impl GetEndpointAttributesResponseDeserializer {
 }
 /// <p>Input for GetPlatformApplicationAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPlatformApplicationAttributesInput {
     /// <p>PlatformApplicationArn for GetPlatformApplicationAttributesInput.</p>
     pub platform_application_arn: String,
@@ -586,6 +632,8 @@ impl GetPlatformApplicationAttributesInputSerializer {
 
 /// <p>Response for GetPlatformApplicationAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPlatformApplicationAttributesResponse {
     /// <p><p>Attributes include the following:</p> <ul> <li> <p> <code>EventEndpointCreated</code> – Topic ARN to which EndpointCreated event notifications should be sent.</p> </li> <li> <p> <code>EventEndpointDeleted</code> – Topic ARN to which EndpointDeleted event notifications should be sent.</p> </li> <li> <p> <code>EventEndpointUpdated</code> – Topic ARN to which EndpointUpdate event notifications should be sent.</p> </li> <li> <p> <code>EventDeliveryFailure</code> – Topic ARN to which DeliveryFailure event notifications should be sent upon Direct Publish delivery failure (permanent) to one of the application&#39;s endpoints.</p> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -618,6 +666,8 @@ impl GetPlatformApplicationAttributesResponseDeserializer {
 }
 /// <p>The input for the <code>GetSMSAttributes</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSMSAttributesInput {
     /// <p>A list of the individual attribute names, such as <code>MonthlySpendLimit</code>, for which you want values.</p> <p>For all attribute names, see <a href="https://docs.aws.amazon.com/sns/latest/api/API_SetSMSAttributes.html">SetSMSAttributes</a>.</p> <p>If you don't use this parameter, Amazon SNS returns all SMS attributes.</p>
     pub attributes: Option<Vec<String>>,
@@ -644,6 +694,8 @@ impl GetSMSAttributesInputSerializer {
 
 /// <p>The response from the <code>GetSMSAttributes</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSMSAttributesResponse {
     /// <p>The SMS attribute names and their values.</p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -676,6 +728,8 @@ impl GetSMSAttributesResponseDeserializer {
 }
 /// <p>Input for GetSubscriptionAttributes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSubscriptionAttributesInput {
     /// <p>The ARN of the subscription whose properties you want to get.</p>
     pub subscription_arn: String,
@@ -699,6 +753,8 @@ impl GetSubscriptionAttributesInputSerializer {
 
 /// <p>Response for GetSubscriptionAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSubscriptionAttributesResponse {
     /// <p><p>A map of the subscription&#39;s attributes. Attributes in this map include the following:</p> <ul> <li> <p> <code>ConfirmationWasAuthenticated</code> – <code>true</code> if the subscription confirmation request was authenticated.</p> </li> <li> <p> <code>DeliveryPolicy</code> – The JSON serialization of the subscription&#39;s delivery policy.</p> </li> <li> <p> <code>EffectiveDeliveryPolicy</code> – The JSON serialization of the effective delivery policy that takes into account the topic delivery policy and account system defaults.</p> </li> <li> <p> <code>FilterPolicy</code> – The filter policy JSON that is assigned to the subscription.</p> </li> <li> <p> <code>Owner</code> – The AWS account ID of the subscription&#39;s owner.</p> </li> <li> <p> <code>PendingConfirmation</code> – <code>true</code> if the subscription hasn&#39;t been confirmed. To confirm a pending subscription, call the <code>ConfirmSubscription</code> action with a confirmation token.</p> </li> <li> <p> <code>RawMessageDelivery</code> – <code>true</code> if raw message delivery is enabled for the subscription. Raw messages are free of JSON formatting and can be sent to HTTP/S and Amazon SQS endpoints.</p> </li> <li> <p> <code>SubscriptionArn</code> – The subscription&#39;s ARN.</p> </li> <li> <p> <code>TopicArn</code> – The topic ARN that the subscription is associated with.</p> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -731,6 +787,8 @@ impl GetSubscriptionAttributesResponseDeserializer {
 }
 /// <p>Input for GetTopicAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTopicAttributesInput {
     /// <p>The ARN of the topic whose properties you want to get.</p>
     pub topic_arn: String,
@@ -751,6 +809,8 @@ impl GetTopicAttributesInputSerializer {
 
 /// <p>Response for GetTopicAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTopicAttributesResponse {
     /// <p><p>A map of the topic&#39;s attributes. Attributes in this map include the following:</p> <ul> <li> <p> <code>TopicArn</code> – the topic&#39;s ARN</p> </li> <li> <p> <code>Owner</code> – the AWS account ID of the topic&#39;s owner</p> </li> <li> <p> <code>Policy</code> – the JSON serialization of the topic&#39;s access control policy</p> </li> <li> <p> <code>DisplayName</code> – the human-readable name used in the &quot;From&quot; field for notifications to email and email-json endpoints</p> </li> <li> <p> <code>SubscriptionsPending</code> – the number of subscriptions pending confirmation on this topic</p> </li> <li> <p> <code>SubscriptionsConfirmed</code> – the number of confirmed subscriptions on this topic</p> </li> <li> <p> <code>SubscriptionsDeleted</code> – the number of deleted subscriptions on this topic</p> </li> <li> <p> <code>DeliveryPolicy</code> – the JSON serialization of the topic&#39;s delivery policy</p> </li> <li> <p> <code>EffectiveDeliveryPolicy</code> – the JSON serialization of the effective delivery policy that takes into account system defaults</p> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -783,6 +843,8 @@ impl GetTopicAttributesResponseDeserializer {
 }
 /// <p>Input for ListEndpointsByPlatformApplication action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEndpointsByPlatformApplicationInput {
     /// <p>NextToken string is used when calling ListEndpointsByPlatformApplication action to retrieve additional records that are available after the first page results.</p>
     pub next_token: Option<String>,
@@ -811,6 +873,8 @@ impl ListEndpointsByPlatformApplicationInputSerializer {
 
 /// <p>Response for ListEndpointsByPlatformApplication action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListEndpointsByPlatformApplicationResponse {
     /// <p>Endpoints returned for ListEndpointsByPlatformApplication action.</p>
     pub endpoints: Option<Vec<String>>,
@@ -883,6 +947,8 @@ impl ListOfPlatformApplicationsDeserializer {
 }
 /// <p>The input for the <code>ListPhoneNumbersOptedOut</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPhoneNumbersOptedOutInput {
     /// <p>A <code>NextToken</code> string is used when you call the <code>ListPhoneNumbersOptedOut</code> action to retrieve additional records that are available after the first page of results.</p>
     pub next_token: Option<String>,
@@ -905,6 +971,8 @@ impl ListPhoneNumbersOptedOutInputSerializer {
 
 /// <p>The response from the <code>ListPhoneNumbersOptedOut</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPhoneNumbersOptedOutResponse {
     /// <p>A <code>NextToken</code> string is returned when you call the <code>ListPhoneNumbersOptedOut</code> action if additional records are available after the first page of results.</p>
     pub next_token: Option<String>,
@@ -941,6 +1009,8 @@ impl ListPhoneNumbersOptedOutResponseDeserializer {
 }
 /// <p>Input for ListPlatformApplications action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPlatformApplicationsInput {
     /// <p>NextToken string is used when calling ListPlatformApplications action to retrieve additional records that are available after the first page results.</p>
     pub next_token: Option<String>,
@@ -963,6 +1033,8 @@ impl ListPlatformApplicationsInputSerializer {
 
 /// <p>Response for ListPlatformApplications action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListPlatformApplicationsResponse {
     /// <p>NextToken string is returned when calling ListPlatformApplications action if additional records are available after the first page results.</p>
     pub next_token: Option<String>,
@@ -1014,6 +1086,8 @@ impl ListStringSerializer {
 
 /// <p>Input for ListSubscriptionsByTopic action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscriptionsByTopicInput {
     /// <p>Token returned by the previous <code>ListSubscriptionsByTopic</code> request.</p>
     pub next_token: Option<String>,
@@ -1039,6 +1113,8 @@ impl ListSubscriptionsByTopicInputSerializer {
 
 /// <p>Response for ListSubscriptionsByTopic action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscriptionsByTopicResponse {
     /// <p>Token to pass along to the next <code>ListSubscriptionsByTopic</code> request. This element is returned if there are more subscriptions to retrieve.</p>
     pub next_token: Option<String>,
@@ -1076,6 +1152,8 @@ impl ListSubscriptionsByTopicResponseDeserializer {
 }
 /// <p>Input for ListSubscriptions action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscriptionsInput {
     /// <p>Token returned by the previous <code>ListSubscriptions</code> request.</p>
     pub next_token: Option<String>,
@@ -1098,6 +1176,8 @@ impl ListSubscriptionsInputSerializer {
 
 /// <p>Response for ListSubscriptions action</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscriptionsResponse {
     /// <p>Token to pass along to the next <code>ListSubscriptions</code> request. This element is returned if there are more subscriptions to retrieve.</p>
     pub next_token: Option<String>,
@@ -1134,11 +1214,24 @@ impl ListSubscriptionsResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The ARN of the topic for which to list tags.</p>
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+    }
+}
+
 /// Serialize `ListTagsForResourceRequest` contents to a `SignedRequest`.
 struct ListTagsForResourceRequestSerializer;
 impl ListTagsForResourceRequestSerializer {
@@ -1153,6 +1246,8 @@ impl ListTagsForResourceRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>The tags associated with the specified topic.</p>
     pub tags: Option<Vec<Tag>>,
@@ -1183,6 +1278,8 @@ impl ListTagsForResourceResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTopicsInput {
     /// <p>Token returned by the previous <code>ListTopics</code> request.</p>
     pub next_token: Option<String>,
@@ -1205,6 +1302,8 @@ impl ListTopicsInputSerializer {
 
 /// <p>Response for ListTopics action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTopicsResponse {
     /// <p>Token to pass along to the next <code>ListTopics</code> request. This element is returned if there are additional topics to retrieve.</p>
     pub next_token: Option<String>,
@@ -1297,8 +1396,18 @@ impl MessageAttributeMapSerializer {
 
 /// <p>The user-specified message attribute value. For string data types, the value attribute has the same restrictions on the content as the message body. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/api/API_Publish.html">Publish</a>.</p> <p>Name, type, and value must not be empty or null. In addition, the message body should not be empty or null. All parts of the message attribute, including name, type, and value, are included in the message size restriction, which is currently 256 KB (262,144 bytes). For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMessageAttributes.html">Using Amazon SNS Message Attributes</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MessageAttributeValue {
     /// <p>Binary type attributes can store any binary data, for example, compressed data, encrypted data, or images.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+            default
+        )
+    )]
     pub binary_value: Option<bytes::Bytes>,
     /// <p>Amazon SNS supports the following logical data types: String, String.Array, Number, and Binary. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMessageAttributes.html#SNSMessageAttributes.DataTypes">Message Attribute Data Types</a>.</p>
     pub data_type: String,
@@ -1352,6 +1461,8 @@ impl NextTokenDeserializer {
 }
 /// <p>Input for the OptInPhoneNumber action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OptInPhoneNumberInput {
     /// <p>The phone number to opt in.</p>
     pub phone_number: String,
@@ -1372,6 +1483,8 @@ impl OptInPhoneNumberInputSerializer {
 
 /// <p>The response for the OptInPhoneNumber action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OptInPhoneNumberResponse {}
 
 struct OptInPhoneNumberResponseDeserializer;
@@ -1420,6 +1533,8 @@ impl PhoneNumberListDeserializer {
 }
 /// <p>Platform application object.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PlatformApplication {
     /// <p>Attributes for platform application object.</p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -1467,6 +1582,8 @@ impl ProtocolDeserializer {
 }
 /// <p>Input for Publish action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublishInput {
     /// <p><p>The message you want to send.</p> <important> <p>The <code>Message</code> parameter is always a string. If you set <code>MessageStructure</code> to <code>json</code>, you must string-encode the <code>Message</code> parameter.</p> </important> <p>If you are publishing to a topic and you want to send the same message to all transport protocols, include the text of the message as a String value. If you want to send different messages for each transport protocol, set the value of the <code>MessageStructure</code> parameter to <code>json</code> and use a JSON object for the <code>Message</code> parameter. </p> <p/> <p>Constraints:</p> <ul> <li> <p>With the exception of SMS, messages must be UTF-8 encoded strings and at most 256 KB in size (262,144 bytes, not 262,144 characters).</p> </li> <li> <p>For SMS, each message can contain up to 140 characters. This character limit depends on the encoding schema. For example, an SMS message can contain 160 GSM characters, 140 ASCII characters, or 70 UCS-2 characters.</p> <p>If you publish a message that exceeds this size limit, Amazon SNS sends the message as multiple messages, each fitting within the size limit. Messages aren&#39;t truncated mid-word but are cut off at whole-word boundaries.</p> <p>The total size limit for a single SMS <code>Publish</code> action is 1,600 characters.</p> </li> </ul> <p>JSON-specific constraints:</p> <ul> <li> <p>Keys in the JSON object that correspond to supported transport protocols must have simple JSON string values.</p> </li> <li> <p>The values will be parsed (unescaped) before they are used in outgoing messages.</p> </li> <li> <p>Outbound notifications are JSON encoded (meaning that the characters will be reescaped for sending).</p> </li> <li> <p>Values have a minimum length of 0 (the empty string, &quot;&quot;, is allowed).</p> </li> <li> <p>Values have a maximum length bounded by the overall message size (so, including multiple protocols may limit message sizes).</p> </li> <li> <p>Non-string values will cause the key to be ignored.</p> </li> <li> <p>Keys that do not correspond to supported transport protocols are ignored.</p> </li> <li> <p>Duplicate keys are not allowed.</p> </li> <li> <p>Failure to parse or validate any key or value in the message will cause the <code>Publish</code> call to return an error (no partial delivery).</p> </li> </ul></p>
     pub message: String,
@@ -1521,6 +1638,8 @@ impl PublishInputSerializer {
 
 /// <p>Response for Publish action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PublishResponse {
     /// <p>Unique identifier assigned to the published message.</p> <p>Length Constraint: Maximum 100 characters</p>
     pub message_id: Option<String>,
@@ -1546,6 +1665,8 @@ impl PublishResponseDeserializer {
 }
 /// <p>Input for RemovePermission action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemovePermissionInput {
     /// <p>The unique label of the statement you want to remove.</p>
     pub label: String,
@@ -1569,6 +1690,8 @@ impl RemovePermissionInputSerializer {
 
 /// <p>Input for SetEndpointAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetEndpointAttributesInput {
     /// <p><p>A map of the endpoint attributes. Attributes in this map include the following:</p> <ul> <li> <p> <code>CustomUserData</code> – arbitrary user data to associate with the endpoint. Amazon SNS does not use this data. The data must be in UTF-8 format and less than 2KB.</p> </li> <li> <p> <code>Enabled</code> – flag that enables/disables delivery to the endpoint. Amazon SNS will set this to false when a notification service indicates to Amazon SNS that the endpoint is invalid. Users can set it back to true, typically after updating Token.</p> </li> <li> <p> <code>Token</code> – device token, also referred to as a registration id, for an app and mobile device. This is returned from the notification service when an app and mobile device are registered with the notification service.</p> </li> </ul></p>
     pub attributes: ::std::collections::HashMap<String, String>,
@@ -1596,6 +1719,8 @@ impl SetEndpointAttributesInputSerializer {
 
 /// <p>Input for SetPlatformApplicationAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetPlatformApplicationAttributesInput {
     /// <p><p>A map of the platform application attributes. Attributes in this map include the following:</p> <ul> <li> <p> <code>PlatformCredential</code> – The credential received from the notification service. For APNS/APNS<em>SANDBOX, PlatformCredential is private key. For GCM, PlatformCredential is &quot;API key&quot;. For ADM, PlatformCredential is &quot;client secret&quot;.</p> </li> <li> <p> <code>PlatformPrincipal</code> – The principal received from the notification service. For APNS/APNS</em>SANDBOX, PlatformPrincipal is SSL certificate. For GCM, PlatformPrincipal is not applicable. For ADM, PlatformPrincipal is &quot;client id&quot;.</p> </li> <li> <p> <code>EventEndpointCreated</code> – Topic ARN to which EndpointCreated event notifications should be sent.</p> </li> <li> <p> <code>EventEndpointDeleted</code> – Topic ARN to which EndpointDeleted event notifications should be sent.</p> </li> <li> <p> <code>EventEndpointUpdated</code> – Topic ARN to which EndpointUpdate event notifications should be sent.</p> </li> <li> <p> <code>EventDeliveryFailure</code> – Topic ARN to which DeliveryFailure event notifications should be sent upon Direct Publish delivery failure (permanent) to one of the application&#39;s endpoints.</p> </li> <li> <p> <code>SuccessFeedbackRoleArn</code> – IAM role ARN used to give Amazon SNS write access to use CloudWatch Logs on your behalf.</p> </li> <li> <p> <code>FailureFeedbackRoleArn</code> – IAM role ARN used to give Amazon SNS write access to use CloudWatch Logs on your behalf.</p> </li> <li> <p> <code>SuccessFeedbackSampleRate</code> – Sample rate percentage (0-100) of successfully delivered messages.</p> </li> </ul></p>
     pub attributes: ::std::collections::HashMap<String, String>,
@@ -1626,6 +1751,8 @@ impl SetPlatformApplicationAttributesInputSerializer {
 
 /// <p>The input for the SetSMSAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSMSAttributesInput {
     /// <p>The default settings for sending SMS messages from your account. You can set values for the following attribute names:</p> <p> <code>MonthlySpendLimit</code> – The maximum amount in USD that you are willing to spend each month to send SMS messages. When Amazon SNS determines that sending an SMS message would incur a cost that exceeds this limit, it stops sending SMS messages within minutes.</p> <important> <p>Amazon SNS stops sending SMS messages within minutes of the limit being crossed. During that interval, if you continue to send SMS messages, you will incur costs that exceed your limit.</p> </important> <p>By default, the spend limit is set to the maximum allowed by Amazon SNS. If you want to raise the limit, submit an <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-sns">SNS Limit Increase case</a>. For <b>New limit value</b>, enter your desired monthly spend limit. In the <b>Use Case Description</b> field, explain that you are requesting an SMS monthly spend limit increase.</p> <p> <code>DeliveryStatusIAMRole</code> – The ARN of the IAM role that allows Amazon SNS to write logs about SMS deliveries in CloudWatch Logs. For each SMS message that you send, Amazon SNS writes a log that includes the message price, the success or failure status, the reason for failure (if the message failed), the message dwell time, and other information.</p> <p> <code>DeliveryStatusSuccessSamplingRate</code> – The percentage of successful SMS deliveries for which Amazon SNS will write logs in CloudWatch Logs. The value can be an integer from 0 - 100. For example, to write logs only for failed deliveries, set this value to <code>0</code>. To write logs for 10% of your successful deliveries, set it to <code>10</code>.</p> <p> <code>DefaultSenderID</code> – A string, such as your business brand, that is displayed as the sender on the receiving device. Support for sender IDs varies by country. The sender ID can be 1 - 11 alphanumeric characters, and it must contain at least one letter.</p> <p> <code>DefaultSMSType</code> – The type of SMS message that you will send by default. You can assign the following values:</p> <ul> <li> <p> <code>Promotional</code> – (Default) Noncritical messages, such as marketing messages. Amazon SNS optimizes the message delivery to incur the lowest cost.</p> </li> <li> <p> <code>Transactional</code> – Critical messages that support customer transactions, such as one-time passcodes for multi-factor authentication. Amazon SNS optimizes the message delivery to achieve the highest reliability.</p> </li> </ul> <p> <code>UsageReportS3Bucket</code> – The name of the Amazon S3 bucket to receive daily SMS usage reports from Amazon SNS. Each day, Amazon SNS will deliver a usage report as a CSV file to the bucket. The report includes the following information for each SMS message that was successfully delivered by your account:</p> <ul> <li> <p>Time that the message was published (in UTC)</p> </li> <li> <p>Message ID</p> </li> <li> <p>Destination phone number</p> </li> <li> <p>Message type</p> </li> <li> <p>Delivery status</p> </li> <li> <p>Message price (in USD)</p> </li> <li> <p>Part number (a message is split into multiple parts if it is too long for a single message)</p> </li> <li> <p>Total number of parts</p> </li> </ul> <p>To receive the report, the bucket must have a policy that allows the Amazon SNS service principle to perform the <code>s3:PutObject</code> and <code>s3:GetBucketLocation</code> actions.</p> <p>For an example bucket policy and usage report, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sms_stats.html">Monitoring SMS Activity</a> in the <i>Amazon SNS Developer Guide</i>.</p>
     pub attributes: ::std::collections::HashMap<String, String>,
@@ -1650,6 +1777,8 @@ impl SetSMSAttributesInputSerializer {
 
 /// <p>The response for the SetSMSAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSMSAttributesResponse {}
 
 struct SetSMSAttributesResponseDeserializer;
@@ -1670,6 +1799,8 @@ impl SetSMSAttributesResponseDeserializer {
 }
 /// <p>Input for SetSubscriptionAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSubscriptionAttributesInput {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>SetTopicAttributes</code> action uses:</p> <ul> <li> <p> <code>DeliveryPolicy</code> – The policy that defines how Amazon SNS retries failed deliveries to HTTP/S endpoints.</p> </li> <li> <p> <code>FilterPolicy</code> – The simple JSON object that lets your subscriber receive only a subset of messages, rather than receiving every message published to the topic.</p> </li> <li> <p> <code>RawMessageDelivery</code> – When set to <code>true</code>, enables raw message delivery to Amazon SQS or HTTP/S endpoints. This eliminates the need for the endpoints to process JSON formatting, which is otherwise created for Amazon SNS metadata.</p> </li> </ul></p>
     pub attribute_name: String,
@@ -1704,6 +1835,8 @@ impl SetSubscriptionAttributesInputSerializer {
 
 /// <p>Input for SetTopicAttributes action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetTopicAttributesInput {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>SetTopicAttributes</code> action uses:</p> <ul> <li> <p> <code>DeliveryPolicy</code> – The policy that defines how Amazon SNS retries failed deliveries to HTTP/S endpoints.</p> </li> <li> <p> <code>DisplayName</code> – The display name to use for a topic with SMS subscriptions.</p> </li> <li> <p> <code>Policy</code> – The policy that defines who can access your topic. By default, only the topic owner can publish or subscribe to the topic.</p> </li> </ul> <p>The following attribute applies only to <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SNS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-server-side-encryption.html#sse-key-terms">Key Terms</a>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> </ul></p>
     pub attribute_name: String,
@@ -1746,6 +1879,8 @@ impl StringDeserializer {
 }
 /// <p>Input for Subscribe action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubscribeInput {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>SetTopicAttributes</code> action uses:</p> <ul> <li> <p> <code>DeliveryPolicy</code> – The policy that defines how Amazon SNS retries failed deliveries to HTTP/S endpoints.</p> </li> <li> <p> <code>FilterPolicy</code> – The simple JSON object that lets your subscriber receive only a subset of messages, rather than receiving every message published to the topic.</p> </li> <li> <p> <code>RawMessageDelivery</code> – When set to <code>true</code>, enables raw message delivery to Amazon SQS or HTTP/S endpoints. This eliminates the need for the endpoints to process JSON formatting, which is otherwise created for Amazon SNS metadata.</p> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -1791,6 +1926,8 @@ impl SubscribeInputSerializer {
 
 /// <p>Response for Subscribe action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SubscribeResponse {
     /// <p>The ARN of the subscription if it is confirmed, or the string "pending confirmation" if the subscription requires confirmation. However, if the API request parameter <code>ReturnSubscriptionArn</code> is true, then the value is always the subscription ARN, even if the subscription requires confirmation.</p>
     pub subscription_arn: Option<String>,
@@ -1819,6 +1956,8 @@ impl SubscribeResponseDeserializer {
 }
 /// <p>A wrapper type for the attributes of an Amazon SNS subscription.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Subscription {
     /// <p>The subscription's endpoint (format depends on the protocol).</p>
     pub endpoint: Option<String>,
@@ -1935,6 +2074,8 @@ impl SubscriptionsListDeserializer {
 }
 /// <p>The list of tags to be added to the specified topic.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Tag {
     /// <p>The required key portion of the tag.</p>
     pub key: String,
@@ -1942,6 +2083,23 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
@@ -2028,6 +2186,8 @@ impl TagListSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>The ARN of the topic to which to add tags.</p>
     pub resource_arn: String,
@@ -2035,6 +2195,21 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// Serialize `TagResourceRequest` contents to a `SignedRequest`.
 struct TagResourceRequestSerializer;
 impl TagResourceRequestSerializer {
@@ -2050,6 +2225,8 @@ impl TagResourceRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceResponse {}
 
 struct TagResourceResponseDeserializer;
@@ -2081,6 +2258,8 @@ impl TagValueDeserializer {
 }
 /// <p>A wrapper type for the topic's Amazon Resource Name (ARN). To retrieve a topic's attributes, use <code>GetTopicAttributes</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Topic {
     /// <p>The topic's ARN.</p>
     pub topic_arn: Option<String>,
@@ -2171,6 +2350,8 @@ impl TopicsListDeserializer {
 }
 /// <p>Input for Unsubscribe action.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UnsubscribeInput {
     /// <p>The ARN of the subscription to be deleted.</p>
     pub subscription_arn: String,
@@ -2193,6 +2374,8 @@ impl UnsubscribeInputSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>The ARN of the topic from which to remove tags.</p>
     pub resource_arn: String,
@@ -2200,6 +2383,21 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1011),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// Serialize `UntagResourceRequest` contents to a `SignedRequest`.
 struct UntagResourceRequestSerializer;
 impl UntagResourceRequestSerializer {
@@ -2215,6 +2413,8 @@ impl UntagResourceRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceResponse {}
 
 struct UntagResourceResponseDeserializer;
@@ -5025,639 +5225,84 @@ pub trait Sns {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
-/// A client for the Amazon SNS API.
-#[derive(Clone)]
-pub struct SnsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SnsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SnsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SnsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SnsClient {
-        SnsClient { client, region }
-    }
-}
-
-impl Sns for SnsClient {
-    /// <p>Adds a statement to a topic's access control policy, granting access for the specified AWS accounts to the specified actions.</p>
+const _: Option<&dyn Sns> = None;
+impl<'a, T: Sns + ?Sized> Sns for &'a T {
     fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddPermission");
-        params.put("Version", "2010-03-31");
-        AddPermissionInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddPermissionError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).add_permission(input)
     }
-
-    /// <p>Accepts a phone number and indicates whether the phone holder has opted out of receiving SMS messages from your account. You cannot send SMS messages to a number that is opted out.</p> <p>To resume sending messages, you can opt in the number by using the <code>OptInPhoneNumber</code> action.</p>
     fn check_if_phone_number_is_opted_out(
         &self,
         input: CheckIfPhoneNumberIsOptedOutInput,
     ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CheckIfPhoneNumberIsOptedOut");
-        params.put("Version", "2010-03-31");
-        CheckIfPhoneNumberIsOptedOutInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CheckIfPhoneNumberIsOptedOutError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CheckIfPhoneNumberIsOptedOutResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CheckIfPhoneNumberIsOptedOutResponseDeserializer::deserialize(
-                        "CheckIfPhoneNumberIsOptedOutResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).check_if_phone_number_is_opted_out(input)
     }
-
-    /// <p>Verifies an endpoint owner's intent to receive messages by validating the token sent to the endpoint by an earlier <code>Subscribe</code> action. If the token is valid, the action creates a new subscription and returns its Amazon Resource Name (ARN). This call requires an AWS signature only when the <code>AuthenticateOnUnsubscribe</code> flag is set to "true".</p>
     fn confirm_subscription(
         &self,
         input: ConfirmSubscriptionInput,
     ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ConfirmSubscription");
-        params.put("Version", "2010-03-31");
-        ConfirmSubscriptionInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ConfirmSubscriptionError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ConfirmSubscriptionResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ConfirmSubscriptionResponseDeserializer::deserialize(
-                        "ConfirmSubscriptionResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).confirm_subscription(input)
     }
-
-    /// <p>Creates a platform application object for one of the supported push notification services, such as APNS and FCM, to which devices and mobile apps may register. You must specify PlatformPrincipal and PlatformCredential attributes when using the <code>CreatePlatformApplication</code> action. The PlatformPrincipal is received from the notification service. For APNS/APNS_SANDBOX, PlatformPrincipal is "SSL certificate". For GCM, PlatformPrincipal is not applicable. For ADM, PlatformPrincipal is "client id". The PlatformCredential is also received from the notification service. For WNS, PlatformPrincipal is "Package Security Identifier". For MPNS, PlatformPrincipal is "TLS certificate". For Baidu, PlatformPrincipal is "API key".</p> <p>For APNS/APNS_SANDBOX, PlatformCredential is "private key". For GCM, PlatformCredential is "API key". For ADM, PlatformCredential is "client secret". For WNS, PlatformCredential is "secret key". For MPNS, PlatformCredential is "private key". For Baidu, PlatformCredential is "secret key". The PlatformApplicationArn that is returned when using <code>CreatePlatformApplication</code> is then used as an attribute for the <code>CreatePlatformEndpoint</code> action. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For more information about obtaining the PlatformPrincipal and PlatformCredential for each of the supported push notification services, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-apns.html">Getting Started with Apple Push Notification Service</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-adm.html">Getting Started with Amazon Device Messaging</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-baidu.html">Getting Started with Baidu Cloud Push</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-gcm.html">Getting Started with Google Cloud Messaging for Android</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-mpns.html">Getting Started with MPNS</a>, or <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-wns.html">Getting Started with WNS</a>. </p>
     fn create_platform_application(
         &self,
         input: CreatePlatformApplicationInput,
     ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreatePlatformApplication");
-        params.put("Version", "2010-03-31");
-        CreatePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePlatformApplicationError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreatePlatformApplicationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreatePlatformApplicationResponseDeserializer::deserialize(
-                        "CreatePlatformApplicationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_platform_application(input)
     }
-
-    /// <p>Creates an endpoint for a device and mobile app on one of the supported push notification services, such as GCM and APNS. <code>CreatePlatformEndpoint</code> requires the PlatformApplicationArn that is returned from <code>CreatePlatformApplication</code>. The EndpointArn that is returned when using <code>CreatePlatformEndpoint</code> can then be used by the <code>Publish</code> action to send a message to a mobile app or by the <code>Subscribe</code> action for subscription to a topic. The <code>CreatePlatformEndpoint</code> action is idempotent, so if the requester already owns an endpoint with the same device token and attributes, that endpoint's ARN is returned without creating a new endpoint. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When using <code>CreatePlatformEndpoint</code> with Baidu, two attributes must be provided: ChannelId and UserId. The token field must also contain the ChannelId. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePushBaiduEndpoint.html">Creating an Amazon SNS Endpoint for Baidu</a>. </p>
     fn create_platform_endpoint(
         &self,
         input: CreatePlatformEndpointInput,
     ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreatePlatformEndpoint");
-        params.put("Version", "2010-03-31");
-        CreatePlatformEndpointInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreatePlatformEndpointError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateEndpointResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateEndpointResponseDeserializer::deserialize(
-                        "CreatePlatformEndpointResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_platform_endpoint(input)
     }
-
-    /// <p>Creates a topic to which notifications can be published. Users can create at most 100,000 topics. For more information, see <a href="http://aws.amazon.com/sns/">https://aws.amazon.com/sns</a>. This action is idempotent, so if the requester already owns a topic with the specified name, that topic's ARN is returned without creating a new topic.</p>
     fn create_topic(
         &self,
         input: CreateTopicInput,
     ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateTopic");
-        params.put("Version", "2010-03-31");
-        CreateTopicInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTopicError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = CreateTopicResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateTopicResponseDeserializer::deserialize(
-                        "CreateTopicResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).create_topic(input)
     }
-
-    /// <p>Deletes the endpoint for a device and mobile app from Amazon SNS. This action is idempotent. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When you delete an endpoint that is also subscribed to a topic, then you must also unsubscribe the endpoint from the topic.</p>
     fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteEndpoint");
-        params.put("Version", "2010-03-31");
-        DeleteEndpointInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteEndpointError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_endpoint(input)
     }
-
-    /// <p>Deletes a platform application object for one of the supported push notification services, such as APNS and GCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
     fn delete_platform_application(
         &self,
         input: DeletePlatformApplicationInput,
     ) -> RusotoFuture<(), DeletePlatformApplicationError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeletePlatformApplication");
-        params.put("Version", "2010-03-31");
-        DeletePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeletePlatformApplicationError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_platform_application(input)
     }
-
-    /// <p>Deletes a topic and all its subscriptions. Deleting a topic might prevent some messages previously sent to the topic from being delivered to subscribers. This action is idempotent, so deleting a topic that does not exist does not result in an error.</p>
     fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteTopic");
-        params.put("Version", "2010-03-31");
-        DeleteTopicInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTopicError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).delete_topic(input)
     }
-
-    /// <p>Retrieves the endpoint attributes for a device on one of the supported push notification services, such as GCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
     fn get_endpoint_attributes(
         &self,
         input: GetEndpointAttributesInput,
     ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetEndpointAttributes");
-        params.put("Version", "2010-03-31");
-        GetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetEndpointAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetEndpointAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetEndpointAttributesResponseDeserializer::deserialize(
-                        "GetEndpointAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_endpoint_attributes(input)
     }
-
-    /// <p>Retrieves the attributes of the platform application object for the supported push notification services, such as APNS and GCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
     fn get_platform_application_attributes(
         &self,
         input: GetPlatformApplicationAttributesInput,
     ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
     {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetPlatformApplicationAttributes");
-        params.put("Version", "2010-03-31");
-        GetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetPlatformApplicationAttributesError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetPlatformApplicationAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetPlatformApplicationAttributesResponseDeserializer::deserialize(
-                        "GetPlatformApplicationAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_platform_application_attributes(input)
     }
-
-    /// <p>Returns the settings for sending SMS messages from your account.</p> <p>These settings are set with the <code>SetSMSAttributes</code> action.</p>
     fn get_sms_attributes(
         &self,
         input: GetSMSAttributesInput,
     ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetSMSAttributes");
-        params.put("Version", "2010-03-31");
-        GetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSMSAttributesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetSMSAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetSMSAttributesResponseDeserializer::deserialize(
-                        "GetSMSAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_sms_attributes(input)
     }
-
-    /// <p>Returns all of the properties of a subscription.</p>
     fn get_subscription_attributes(
         &self,
         input: GetSubscriptionAttributesInput,
     ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetSubscriptionAttributes");
-        params.put("Version", "2010-03-31");
-        GetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSubscriptionAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetSubscriptionAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetSubscriptionAttributesResponseDeserializer::deserialize(
-                        "GetSubscriptionAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_subscription_attributes(input)
     }
-
-    /// <p>Returns all of the properties of a topic. Topic properties returned might differ based on the authorization of the user.</p>
     fn get_topic_attributes(
         &self,
         input: GetTopicAttributesInput,
     ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "GetTopicAttributes");
-        params.put("Version", "2010-03-31");
-        GetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetTopicAttributesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = GetTopicAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = GetTopicAttributesResponseDeserializer::deserialize(
-                        "GetTopicAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).get_topic_attributes(input)
     }
-
-    /// <p>Lists the endpoints and endpoint attributes for devices in a supported push notification service, such as GCM and APNS. The results for <code>ListEndpointsByPlatformApplication</code> are paginated and return a limited list of endpoints, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListEndpointsByPlatformApplication</code> again using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 30 transactions per second (TPS).</p>
     fn list_endpoints_by_platform_application(
         &self,
         input: ListEndpointsByPlatformApplicationInput,
@@ -5665,798 +5310,3142 @@ impl Sns for SnsClient {
         ListEndpointsByPlatformApplicationResponse,
         ListEndpointsByPlatformApplicationError,
     > {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListEndpointsByPlatformApplication");
-        params.put("Version", "2010-03-31");
-        ListEndpointsByPlatformApplicationInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListEndpointsByPlatformApplicationError::from_response(
-                        response,
-                    ))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListEndpointsByPlatformApplicationResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListEndpointsByPlatformApplicationResponseDeserializer::deserialize(
-                        "ListEndpointsByPlatformApplicationResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_endpoints_by_platform_application(input)
     }
-
-    /// <p>Returns a list of phone numbers that are opted out, meaning you cannot send SMS messages to them.</p> <p>The results for <code>ListPhoneNumbersOptedOut</code> are paginated, and each page returns up to 100 phone numbers. If additional phone numbers are available after the first page of results, then a <code>NextToken</code> string will be returned. To receive the next page, you call <code>ListPhoneNumbersOptedOut</code> again using the <code>NextToken</code> string received from the previous call. When there are no more records to return, <code>NextToken</code> will be null.</p>
     fn list_phone_numbers_opted_out(
         &self,
         input: ListPhoneNumbersOptedOutInput,
     ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListPhoneNumbersOptedOut");
-        params.put("Version", "2010-03-31");
-        ListPhoneNumbersOptedOutInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPhoneNumbersOptedOutError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListPhoneNumbersOptedOutResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListPhoneNumbersOptedOutResponseDeserializer::deserialize(
-                        "ListPhoneNumbersOptedOutResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_phone_numbers_opted_out(input)
     }
-
-    /// <p>Lists the platform application objects for the supported push notification services, such as APNS and GCM. The results for <code>ListPlatformApplications</code> are paginated and return a limited list of applications, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListPlatformApplications</code> using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 15 transactions per second (TPS).</p>
     fn list_platform_applications(
         &self,
         input: ListPlatformApplicationsInput,
     ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListPlatformApplications");
-        params.put("Version", "2010-03-31");
-        ListPlatformApplicationsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListPlatformApplicationsError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListPlatformApplicationsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListPlatformApplicationsResponseDeserializer::deserialize(
-                        "ListPlatformApplicationsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_platform_applications(input)
     }
-
-    /// <p>Returns a list of the requester's subscriptions. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptions</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
     fn list_subscriptions(
         &self,
         input: ListSubscriptionsInput,
     ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListSubscriptions");
-        params.put("Version", "2010-03-31");
-        ListSubscriptionsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListSubscriptionsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListSubscriptionsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListSubscriptionsResponseDeserializer::deserialize(
-                        "ListSubscriptionsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_subscriptions(input)
     }
-
-    /// <p>Returns a list of the subscriptions to a specific topic. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptionsByTopic</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
     fn list_subscriptions_by_topic(
         &self,
         input: ListSubscriptionsByTopicInput,
     ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListSubscriptionsByTopic");
-        params.put("Version", "2010-03-31");
-        ListSubscriptionsByTopicInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSubscriptionsByTopicError::from_response(response))
-                }));
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListSubscriptionsByTopicResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListSubscriptionsByTopicResponseDeserializer::deserialize(
-                        "ListSubscriptionsByTopicResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_subscriptions_by_topic(input)
     }
-
-    /// <p>List all tags added to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon Simple Notification Service Developer Guide</i>.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListTagsForResource");
-        params.put("Version", "2010-03-31");
-        ListTagsForResourceRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListTagsForResourceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListTagsForResourceResponseDeserializer::deserialize(
-                        "ListTagsForResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Returns a list of the requester's topics. Each call returns a limited list of topics, up to 100. If there are more topics, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListTopics</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
     fn list_topics(
         &self,
         input: ListTopicsInput,
     ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "ListTopics");
-        params.put("Version", "2010-03-31");
-        ListTopicsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTopicsError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = ListTopicsResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = ListTopicsResponseDeserializer::deserialize(
-                        "ListTopicsResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).list_topics(input)
     }
-
-    /// <p>Use this request to opt in a phone number that is opted out, which enables you to resume sending SMS messages to the number.</p> <p>You can opt in a phone number only once every 30 days.</p>
     fn opt_in_phone_number(
         &self,
         input: OptInPhoneNumberInput,
     ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "OptInPhoneNumber");
-        params.put("Version", "2010-03-31");
-        OptInPhoneNumberInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(OptInPhoneNumberError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = OptInPhoneNumberResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = OptInPhoneNumberResponseDeserializer::deserialize(
-                        "OptInPhoneNumberResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).opt_in_phone_number(input)
     }
-
-    /// <p>Sends a message to an Amazon SNS topic or sends a text message (SMS message) directly to a phone number. </p> <p>If you send a message to a topic, Amazon SNS delivers the message to each endpoint that is subscribed to the topic. The format of the message depends on the notification protocol for each subscribed endpoint.</p> <p>When a <code>messageId</code> is returned, the message has been saved and Amazon SNS will attempt to deliver it shortly.</p> <p>To use the <code>Publish</code> action for sending a message to a mobile endpoint, such as an app on a Kindle device or mobile phone, you must specify the EndpointArn for the TargetArn parameter. The EndpointArn is returned when making a call with the <code>CreatePlatformEndpoint</code> action. </p> <p>For more information about formatting messages, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-send-custommessage.html">Send Custom Platform-Specific Payloads in Messages to Mobile Devices</a>. </p>
     fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "Publish");
-        params.put("Version", "2010-03-31");
-        PublishInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PublishError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = PublishResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = PublishResponseDeserializer::deserialize("PublishResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        (**self).publish(input)
     }
-
-    /// <p>Removes a statement from a topic's access control policy.</p>
     fn remove_permission(
         &self,
         input: RemovePermissionInput,
     ) -> RusotoFuture<(), RemovePermissionError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "RemovePermission");
-        params.put("Version", "2010-03-31");
-        RemovePermissionInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RemovePermissionError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).remove_permission(input)
     }
-
-    /// <p>Sets the attributes for an endpoint for a device on one of the supported push notification services, such as GCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
     fn set_endpoint_attributes(
         &self,
         input: SetEndpointAttributesInput,
     ) -> RusotoFuture<(), SetEndpointAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetEndpointAttributes");
-        params.put("Version", "2010-03-31");
-        SetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetEndpointAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        (**self).set_endpoint_attributes(input)
     }
-
-    /// <p>Sets the attributes of the platform application object for the supported push notification services, such as APNS and GCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For information on configuring attributes for message delivery status, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-msg-status.html">Using Amazon SNS Application Attributes for Message Delivery Status</a>. </p>
     fn set_platform_application_attributes(
         &self,
         input: SetPlatformApplicationAttributesInput,
     ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetPlatformApplicationAttributes");
-        params.put("Version", "2010-03-31");
-        SetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
+        (**self).set_platform_application_attributes(input)
+    }
+    fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
+        (**self).set_sms_attributes(input)
+    }
+    fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
+        (**self).set_subscription_attributes(input)
+    }
+    fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> RusotoFuture<(), SetTopicAttributesError> {
+        (**self).set_topic_attributes(input)
+    }
+    fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
+        (**self).subscribe(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
+        (**self).unsubscribe(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: Sns + ?Sized> Sns for Box<T> {
+    fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
+        (**self).check_if_phone_number_is_opted_out(input)
+    }
+    fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
+        (**self).confirm_subscription(input)
+    }
+    fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
+        (**self).create_platform_application(input)
+    }
+    fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
+        (**self).create_platform_endpoint(input)
+    }
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
+        (**self).create_topic(input)
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> RusotoFuture<(), DeletePlatformApplicationError> {
+        (**self).delete_platform_application(input)
+    }
+    fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
+        (**self).delete_topic(input)
+    }
+    fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
+        (**self).get_endpoint_attributes(input)
+    }
+    fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
+    {
+        (**self).get_platform_application_attributes(input)
+    }
+    fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
+        (**self).get_sms_attributes(input)
+    }
+    fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
+        (**self).get_subscription_attributes(input)
+    }
+    fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
+        (**self).get_topic_attributes(input)
+    }
+    fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> RusotoFuture<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        (**self).list_endpoints_by_platform_application(input)
+    }
+    fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
+        (**self).list_phone_numbers_opted_out(input)
+    }
+    fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
+        (**self).list_platform_applications(input)
+    }
+    fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
+        (**self).list_subscriptions(input)
+    }
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
+        (**self).list_subscriptions_by_topic(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
+        (**self).list_topics(input)
+    }
+    fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
+        (**self).opt_in_phone_number(input)
+    }
+    fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
+        (**self).publish(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> RusotoFuture<(), SetEndpointAttributesError> {
+        (**self).set_endpoint_attributes(input)
+    }
+    fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
+        (**self).set_platform_application_attributes(input)
+    }
+    fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
+        (**self).set_sms_attributes(input)
+    }
+    fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
+        (**self).set_subscription_attributes(input)
+    }
+    fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> RusotoFuture<(), SetTopicAttributesError> {
+        (**self).set_topic_attributes(input)
+    }
+    fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
+        (**self).subscribe(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
+        (**self).unsubscribe(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: Sns + ?Sized> Sns for ::std::sync::Arc<T> {
+    fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
+        (**self).check_if_phone_number_is_opted_out(input)
+    }
+    fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
+        (**self).confirm_subscription(input)
+    }
+    fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
+        (**self).create_platform_application(input)
+    }
+    fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
+        (**self).create_platform_endpoint(input)
+    }
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
+        (**self).create_topic(input)
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> RusotoFuture<(), DeletePlatformApplicationError> {
+        (**self).delete_platform_application(input)
+    }
+    fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
+        (**self).delete_topic(input)
+    }
+    fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
+        (**self).get_endpoint_attributes(input)
+    }
+    fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
+    {
+        (**self).get_platform_application_attributes(input)
+    }
+    fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
+        (**self).get_sms_attributes(input)
+    }
+    fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
+        (**self).get_subscription_attributes(input)
+    }
+    fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
+        (**self).get_topic_attributes(input)
+    }
+    fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> RusotoFuture<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        (**self).list_endpoints_by_platform_application(input)
+    }
+    fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
+        (**self).list_phone_numbers_opted_out(input)
+    }
+    fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
+        (**self).list_platform_applications(input)
+    }
+    fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
+        (**self).list_subscriptions(input)
+    }
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
+        (**self).list_subscriptions_by_topic(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
+        (**self).list_topics(input)
+    }
+    fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
+        (**self).opt_in_phone_number(input)
+    }
+    fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
+        (**self).publish(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> RusotoFuture<(), SetEndpointAttributesError> {
+        (**self).set_endpoint_attributes(input)
+    }
+    fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
+        (**self).set_platform_application_attributes(input)
+    }
+    fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
+        (**self).set_sms_attributes(input)
+    }
+    fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
+        (**self).set_subscription_attributes(input)
+    }
+    fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> RusotoFuture<(), SetTopicAttributesError> {
+        (**self).set_topic_attributes(input)
+    }
+    fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
+        (**self).subscribe(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
+        (**self).unsubscribe(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the Amazon SNS API.
+#[derive(Clone)]
+pub struct SnsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SnsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SnsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SnsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SnsClient {
+        SnsClient { client, region }
+    }
+}
+
+impl Sns for SnsClient {
+    /// <p>Adds a statement to a topic's access control policy, granting access for the specified AWS accounts to the specified actions.</p>
+    fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("AddPermission");
+        let mut params = Params::new();
+
+        params.put("Action", "AddPermission");
+        params.put("Version", "2010-03-31");
+        AddPermissionInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddPermissionError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Accepts a phone number and indicates whether the phone holder has opted out of receiving SMS messages from your account. You cannot send SMS messages to a number that is opted out.</p> <p>To resume sending messages, you can opt in the number by using the <code>OptInPhoneNumber</code> action.</p>
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CheckIfPhoneNumberIsOptedOut");
+        let mut params = Params::new();
+
+        params.put("Action", "CheckIfPhoneNumberIsOptedOut");
+        params.put("Version", "2010-03-31");
+        CheckIfPhoneNumberIsOptedOutInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CheckIfPhoneNumberIsOptedOutError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CheckIfPhoneNumberIsOptedOutResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CheckIfPhoneNumberIsOptedOutResponseDeserializer::deserialize(
+                        "CheckIfPhoneNumberIsOptedOutResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CheckIfPhoneNumberIsOptedOut", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Verifies an endpoint owner's intent to receive messages by validating the token sent to the endpoint by an earlier <code>Subscribe</code> action. If the token is valid, the action creates a new subscription and returns its Amazon Resource Name (ARN). This call requires an AWS signature only when the <code>AuthenticateOnUnsubscribe</code> flag is set to "true".</p>
+    fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ConfirmSubscription");
+        let mut params = Params::new();
+
+        params.put("Action", "ConfirmSubscription");
+        params.put("Version", "2010-03-31");
+        ConfirmSubscriptionInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ConfirmSubscriptionError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ConfirmSubscriptionResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ConfirmSubscriptionResponseDeserializer::deserialize(
+                        "ConfirmSubscriptionResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ConfirmSubscription", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a platform application object for one of the supported push notification services, such as APNS and FCM, to which devices and mobile apps may register. You must specify PlatformPrincipal and PlatformCredential attributes when using the <code>CreatePlatformApplication</code> action. The PlatformPrincipal is received from the notification service. For APNS/APNS_SANDBOX, PlatformPrincipal is "SSL certificate". For GCM, PlatformPrincipal is not applicable. For ADM, PlatformPrincipal is "client id". The PlatformCredential is also received from the notification service. For WNS, PlatformPrincipal is "Package Security Identifier". For MPNS, PlatformPrincipal is "TLS certificate". For Baidu, PlatformPrincipal is "API key".</p> <p>For APNS/APNS_SANDBOX, PlatformCredential is "private key". For GCM, PlatformCredential is "API key". For ADM, PlatformCredential is "client secret". For WNS, PlatformCredential is "secret key". For MPNS, PlatformCredential is "private key". For Baidu, PlatformCredential is "secret key". The PlatformApplicationArn that is returned when using <code>CreatePlatformApplication</code> is then used as an attribute for the <code>CreatePlatformEndpoint</code> action. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For more information about obtaining the PlatformPrincipal and PlatformCredential for each of the supported push notification services, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-apns.html">Getting Started with Apple Push Notification Service</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-adm.html">Getting Started with Amazon Device Messaging</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-baidu.html">Getting Started with Baidu Cloud Push</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-gcm.html">Getting Started with Google Cloud Messaging for Android</a>, <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-mpns.html">Getting Started with MPNS</a>, or <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-wns.html">Getting Started with WNS</a>. </p>
+    fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformApplication");
+        let mut params = Params::new();
+
+        params.put("Action", "CreatePlatformApplication");
+        params.put("Version", "2010-03-31");
+        CreatePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePlatformApplicationError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreatePlatformApplicationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreatePlatformApplicationResponseDeserializer::deserialize(
+                        "CreatePlatformApplicationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreatePlatformApplication", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates an endpoint for a device and mobile app on one of the supported push notification services, such as GCM and APNS. <code>CreatePlatformEndpoint</code> requires the PlatformApplicationArn that is returned from <code>CreatePlatformApplication</code>. The EndpointArn that is returned when using <code>CreatePlatformEndpoint</code> can then be used by the <code>Publish</code> action to send a message to a mobile app or by the <code>Subscribe</code> action for subscription to a topic. The <code>CreatePlatformEndpoint</code> action is idempotent, so if the requester already owns an endpoint with the same device token and attributes, that endpoint's ARN is returned without creating a new endpoint. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When using <code>CreatePlatformEndpoint</code> with Baidu, two attributes must be provided: ChannelId and UserId. The token field must also contain the ChannelId. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePushBaiduEndpoint.html">Creating an Amazon SNS Endpoint for Baidu</a>. </p>
+    fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreatePlatformEndpoint");
+        let mut params = Params::new();
+
+        params.put("Action", "CreatePlatformEndpoint");
+        params.put("Version", "2010-03-31");
+        CreatePlatformEndpointInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreatePlatformEndpointError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateEndpointResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateEndpointResponseDeserializer::deserialize(
+                        "CreatePlatformEndpointResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("CreatePlatformEndpoint", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Creates a topic to which notifications can be published. Users can create at most 100,000 topics. For more information, see <a href="http://aws.amazon.com/sns/">https://aws.amazon.com/sns</a>. This action is idempotent, so if the requester already owns a topic with the specified name, that topic's ARN is returned without creating a new topic.</p>
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("CreateTopic");
+        let mut params = Params::new();
+
+        params.put("Action", "CreateTopic");
+        params.put("Version", "2010-03-31");
+        CreateTopicInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTopicError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = CreateTopicResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = CreateTopicResponseDeserializer::deserialize(
+                        "CreateTopicResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("CreateTopic", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes the endpoint for a device and mobile app from Amazon SNS. This action is idempotent. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When you delete an endpoint that is also subscribed to a topic, then you must also unsubscribe the endpoint from the topic.</p>
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteEndpoint");
+        params.put("Version", "2010-03-31");
+        DeleteEndpointInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteEndpointError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes a platform application object for one of the supported push notification services, such as APNS and GCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> RusotoFuture<(), DeletePlatformApplicationError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeletePlatformApplication");
+        let mut params = Params::new();
+
+        params.put("Action", "DeletePlatformApplication");
+        params.put("Version", "2010-03-31");
+        DeletePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeletePlatformApplicationError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Deletes a topic and all its subscriptions. Deleting a topic might prevent some messages previously sent to the topic from being delivered to subscribers. This action is idempotent, so deleting a topic that does not exist does not result in an error.</p>
+    fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("DeleteTopic");
+        let mut params = Params::new();
+
+        params.put("Action", "DeleteTopic");
+        params.put("Version", "2010-03-31");
+        DeleteTopicInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTopicError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Retrieves the endpoint attributes for a device on one of the supported push notification services, such as GCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetEndpointAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetEndpointAttributes");
+        params.put("Version", "2010-03-31");
+        GetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetEndpointAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetEndpointAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetEndpointAttributesResponseDeserializer::deserialize(
+                        "GetEndpointAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetEndpointAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Retrieves the attributes of the platform application object for the supported push notification services, such as APNS and GCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
+    {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetPlatformApplicationAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetPlatformApplicationAttributes");
+        params.put("Version", "2010-03-31");
+        GetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetPlatformApplicationAttributesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetPlatformApplicationAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetPlatformApplicationAttributesResponseDeserializer::deserialize(
+                        "GetPlatformApplicationAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetPlatformApplicationAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns the settings for sending SMS messages from your account.</p> <p>These settings are set with the <code>SetSMSAttributes</code> action.</p>
+    fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSMSAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetSMSAttributes");
+        params.put("Version", "2010-03-31");
+        GetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSMSAttributesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetSMSAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetSMSAttributesResponseDeserializer::deserialize(
+                        "GetSMSAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetSMSAttributes", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns all of the properties of a subscription.</p>
+    fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetSubscriptionAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetSubscriptionAttributes");
+        params.put("Version", "2010-03-31");
+        GetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSubscriptionAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetSubscriptionAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetSubscriptionAttributesResponseDeserializer::deserialize(
+                        "GetSubscriptionAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("GetSubscriptionAttributes", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns all of the properties of a topic. Topic properties returned might differ based on the authorization of the user.</p>
+    fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("GetTopicAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "GetTopicAttributes");
+        params.put("Version", "2010-03-31");
+        GetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetTopicAttributesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = GetTopicAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = GetTopicAttributesResponseDeserializer::deserialize(
+                        "GetTopicAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("GetTopicAttributes", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the endpoints and endpoint attributes for devices in a supported push notification service, such as GCM and APNS. The results for <code>ListEndpointsByPlatformApplication</code> are paginated and return a limited list of endpoints, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListEndpointsByPlatformApplication</code> again using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> RusotoFuture<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListEndpointsByPlatformApplication");
+        let mut params = Params::new();
+
+        params.put("Action", "ListEndpointsByPlatformApplication");
+        params.put("Version", "2010-03-31");
+        ListEndpointsByPlatformApplicationInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListEndpointsByPlatformApplicationError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListEndpointsByPlatformApplicationResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListEndpointsByPlatformApplicationResponseDeserializer::deserialize(
+                        "ListEndpointsByPlatformApplicationResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListEndpointsByPlatformApplication", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of phone numbers that are opted out, meaning you cannot send SMS messages to them.</p> <p>The results for <code>ListPhoneNumbersOptedOut</code> are paginated, and each page returns up to 100 phone numbers. If additional phone numbers are available after the first page of results, then a <code>NextToken</code> string will be returned. To receive the next page, you call <code>ListPhoneNumbersOptedOut</code> again using the <code>NextToken</code> string received from the previous call. When there are no more records to return, <code>NextToken</code> will be null.</p>
+    fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPhoneNumbersOptedOut");
+        let mut params = Params::new();
+
+        params.put("Action", "ListPhoneNumbersOptedOut");
+        params.put("Version", "2010-03-31");
+        ListPhoneNumbersOptedOutInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPhoneNumbersOptedOutError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListPhoneNumbersOptedOutResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListPhoneNumbersOptedOutResponseDeserializer::deserialize(
+                        "ListPhoneNumbersOptedOutResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListPhoneNumbersOptedOut", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Lists the platform application objects for the supported push notification services, such as APNS and GCM. The results for <code>ListPlatformApplications</code> are paginated and return a limited list of applications, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListPlatformApplications</code> using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 15 transactions per second (TPS).</p>
+    fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListPlatformApplications");
+        let mut params = Params::new();
+
+        params.put("Action", "ListPlatformApplications");
+        params.put("Version", "2010-03-31");
+        ListPlatformApplicationsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListPlatformApplicationsError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListPlatformApplicationsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListPlatformApplicationsResponseDeserializer::deserialize(
+                        "ListPlatformApplicationsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListPlatformApplications", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the requester's subscriptions. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptions</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptions");
+        let mut params = Params::new();
+
+        params.put("Action", "ListSubscriptions");
+        params.put("Version", "2010-03-31");
+        ListSubscriptionsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListSubscriptionsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListSubscriptionsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListSubscriptionsResponseDeserializer::deserialize(
+                        "ListSubscriptionsResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListSubscriptions", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the subscriptions to a specific topic. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptionsByTopic</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListSubscriptionsByTopic");
+        let mut params = Params::new();
+
+        params.put("Action", "ListSubscriptionsByTopic");
+        params.put("Version", "2010-03-31");
+        ListSubscriptionsByTopicInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSubscriptionsByTopicError::from_response(response))
+                }));
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListSubscriptionsByTopicResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListSubscriptionsByTopicResponseDeserializer::deserialize(
+                        "ListSubscriptionsByTopicResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListSubscriptionsByTopic", err, &response.body)
+                    })?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>List all tags added to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon Simple Notification Service Developer Guide</i>.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTagsForResource");
+        params.put("Version", "2010-03-31");
+        ListTagsForResourceRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListTagsForResourceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = ListTagsForResourceResponseDeserializer::deserialize(
+                        "ListTagsForResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("ListTagsForResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Returns a list of the requester's topics. Each call returns a limited list of topics, up to 100. If there are more topics, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListTopics</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("ListTopics");
+        let mut params = Params::new();
+
+        params.put("Action", "ListTopics");
+        params.put("Version", "2010-03-31");
+        ListTopicsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTopicsError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = ListTopicsResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        ListTopicsResponseDeserializer::deserialize("ListTopicsResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListTopics", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Use this request to opt in a phone number that is opted out, which enables you to resume sending SMS messages to the number.</p> <p>You can opt in a phone number only once every 30 days.</p>
+    fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("OptInPhoneNumber");
+        let mut params = Params::new();
+
+        params.put("Action", "OptInPhoneNumber");
+        params.put("Version", "2010-03-31");
+        OptInPhoneNumberInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(OptInPhoneNumberError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = OptInPhoneNumberResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = OptInPhoneNumberResponseDeserializer::deserialize(
+                        "OptInPhoneNumberResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("OptInPhoneNumber", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Sends a message to an Amazon SNS topic or sends a text message (SMS message) directly to a phone number. </p> <p>If you send a message to a topic, Amazon SNS delivers the message to each endpoint that is subscribed to the topic. The format of the message depends on the notification protocol for each subscribed endpoint.</p> <p>When a <code>messageId</code> is returned, the message has been saved and Amazon SNS will attempt to deliver it shortly.</p> <p>To use the <code>Publish</code> action for sending a message to a mobile endpoint, such as an app on a Kindle device or mobile phone, you must specify the EndpointArn for the TargetArn parameter. The EndpointArn is returned when making a call with the <code>CreatePlatformEndpoint</code> action. </p> <p>For more information about formatting messages, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-send-custommessage.html">Send Custom Platform-Specific Payloads in Messages to Mobile Devices</a>. </p>
+    fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Publish");
+        let mut params = Params::new();
+
+        params.put("Action", "Publish");
+        params.put("Version", "2010-03-31");
+        PublishInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PublishError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = PublishResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = PublishResponseDeserializer::deserialize("PublishResult", &mut stack)
+                        .map_err(|err| stack.parse_error("Publish", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Removes a statement from a topic's access control policy.</p>
+    fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("RemovePermission");
+        let mut params = Params::new();
+
+        params.put("Action", "RemovePermission");
+        params.put("Version", "2010-03-31");
+        RemovePermissionInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RemovePermissionError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Sets the attributes for an endpoint for a device on one of the supported push notification services, such as GCM and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> RusotoFuture<(), SetEndpointAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetEndpointAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "SetEndpointAttributes");
+        params.put("Version", "2010-03-31");
+        SetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetEndpointAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Sets the attributes of the platform application object for the supported push notification services, such as APNS and GCM. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For information on configuring attributes for message delivery status, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-msg-status.html">Using Amazon SNS Application Attributes for Message Delivery Status</a>. </p>
+    fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetPlatformApplicationAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "SetPlatformApplicationAttributes");
+        params.put("Version", "2010-03-31");
+        SetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetPlatformApplicationAttributesError::from_response(
+                        response,
+                    ))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Use this request to set the default settings for sending SMS messages and receiving daily SMS usage reports.</p> <p>You can override some of these settings for a single message when you use the <code>Publish</code> action with the <code>MessageAttributes.entry.N</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sms_publish-to-phone.html">Sending an SMS Message</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSMSAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "SetSMSAttributes");
+        params.put("Version", "2010-03-31");
+        SetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetSMSAttributesError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SetSMSAttributesResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = SetSMSAttributesResponseDeserializer::deserialize(
+                        "SetSMSAttributesResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("SetSMSAttributes", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Allows a subscription owner to set an attribute of the subscription to a new value.</p>
+    fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetSubscriptionAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "SetSubscriptionAttributes");
+        params.put("Version", "2010-03-31");
+        SetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetSubscriptionAttributesError::from_response(response))
+                }));
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Allows a topic owner to set an attribute of the topic to a new value.</p>
+    fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> RusotoFuture<(), SetTopicAttributesError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("SetTopicAttributes");
+        let mut params = Params::new();
+
+        params.put("Action", "SetTopicAttributes");
+        params.put("Version", "2010-03-31");
+        SetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SetTopicAttributesError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Prepares to subscribe an endpoint by sending the endpoint a confirmation message. To actually create a subscription, the endpoint owner must call the <code>ConfirmSubscription</code> action with the token from the confirmation message. Confirmation tokens are valid for three days.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
+    fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Subscribe");
+        let mut params = Params::new();
+
+        params.put("Action", "Subscribe");
+        params.put("Version", "2010-03-31");
+        SubscribeInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SubscribeError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = SubscribeResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result =
+                        SubscribeResponseDeserializer::deserialize("SubscribeResult", &mut stack)
+                            .map_err(|err| stack.parse_error("Subscribe", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Add tags to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p> <p>When you use topic tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a topic isn't recommended.</p> </li> <li> <p>Tags don't have any semantic meaning. Amazon SNS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> <li> <p>Tagging actions are limited to 10 TPS per AWS account. If your application requires a higher throughput, file a <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=technical">technical support request</a>.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-limits.html#limits-topics">Limits Related to Topics</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("TagResource");
+        let mut params = Params::new();
+
+        params.put("Action", "TagResource");
+        params.put("Version", "2010-03-31");
+        TagResourceRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = TagResourceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = TagResourceResponseDeserializer::deserialize(
+                        "TagResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("TagResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+
+    /// <p>Deletes a subscription. If the subscription requires authentication for deletion, only the owner of the subscription or the topic's owner can unsubscribe, and an AWS signature is required. If the <code>Unsubscribe</code> call does not require authentication and the requester is not the subscription owner, a final cancellation message is delivered to the endpoint, so that the endpoint owner can easily resubscribe to the topic if the <code>Unsubscribe</code> request was unintended.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
+    fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("Unsubscribe");
+        let mut params = Params::new();
+
+        params.put("Action", "Unsubscribe");
+        params.put("Version", "2010-03-31");
+        UnsubscribeInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UnsubscribeError::from_response(response))),
+                );
+            }
+
+            Box::new(future::ok(::std::mem::drop(response)))
+        })
+    }
+
+    /// <p>Remove tags from the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation("UntagResource");
+        let mut params = Params::new();
+
+        params.put("Action", "UntagResource");
+        params.put("Version", "2010-03-31");
+        UntagResourceRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetPlatformApplicationAttributesError::from_response(
-                        response,
-                    ))
-                }));
-            }
+        self.client.sign_and_dispatch(request, |response| {
+            if !response.status.is_success() {
+                return Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                );
+            }
+
+            Box::new(response.buffer().from_err().and_then(move |response| {
+                let result;
+
+                if response.body.is_empty() {
+                    result = UntagResourceResponse::default();
+                } else {
+                    expect_xml(&response)?;
+                    let reader = EventReader::new_with_config(
+                        response.body.as_ref(),
+                        ParserConfig::new().trim_whitespace(true),
+                    );
+                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                    let _start_document = stack.next();
+                    let actual_tag_name = peek_at_name(&mut stack)?;
+                    start_element(&actual_tag_name, &mut stack)?;
+                    result = UntagResourceResponseDeserializer::deserialize(
+                        "UntagResourceResult",
+                        &mut stack,
+                    )
+                    .map_err(|err| stack.parse_error("UntagResource", err, &response.body))?;
+                    skip_tree(&mut stack);
+                    end_element(&actual_tag_name, &mut stack)?;
+                }
+                // parse non-payload
+                Ok(result)
+            }))
+        })
+    }
+}
+
+/// A mock of the Amazon SNS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSns {
+    add_permission: Option<
+        Box<
+            dyn Fn(AddPermissionInput) -> ::std::result::Result<(), RusotoError<AddPermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    check_if_phone_number_is_opted_out: Option<
+        Box<
+            dyn Fn(
+                    CheckIfPhoneNumberIsOptedOutInput,
+                ) -> ::std::result::Result<
+                    CheckIfPhoneNumberIsOptedOutResponse,
+                    RusotoError<CheckIfPhoneNumberIsOptedOutError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    confirm_subscription: Option<
+        Box<
+            dyn Fn(
+                    ConfirmSubscriptionInput,
+                ) -> ::std::result::Result<
+                    ConfirmSubscriptionResponse,
+                    RusotoError<ConfirmSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_platform_application: Option<
+        Box<
+            dyn Fn(
+                    CreatePlatformApplicationInput,
+                ) -> ::std::result::Result<
+                    CreatePlatformApplicationResponse,
+                    RusotoError<CreatePlatformApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_platform_endpoint: Option<
+        Box<
+            dyn Fn(
+                    CreatePlatformEndpointInput,
+                ) -> ::std::result::Result<
+                    CreateEndpointResponse,
+                    RusotoError<CreatePlatformEndpointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_topic: Option<
+        Box<
+            dyn Fn(
+                    CreateTopicInput,
+                )
+                    -> ::std::result::Result<CreateTopicResponse, RusotoError<CreateTopicError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_endpoint: Option<
+        Box<
+            dyn Fn(
+                    DeleteEndpointInput,
+                ) -> ::std::result::Result<(), RusotoError<DeleteEndpointError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_platform_application: Option<
+        Box<
+            dyn Fn(
+                    DeletePlatformApplicationInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeletePlatformApplicationError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_topic: Option<
+        Box<
+            dyn Fn(DeleteTopicInput) -> ::std::result::Result<(), RusotoError<DeleteTopicError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_endpoint_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetEndpointAttributesInput,
+                ) -> ::std::result::Result<
+                    GetEndpointAttributesResponse,
+                    RusotoError<GetEndpointAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_platform_application_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetPlatformApplicationAttributesInput,
+                ) -> ::std::result::Result<
+                    GetPlatformApplicationAttributesResponse,
+                    RusotoError<GetPlatformApplicationAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_sms_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetSMSAttributesInput,
+                ) -> ::std::result::Result<
+                    GetSMSAttributesResponse,
+                    RusotoError<GetSMSAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_subscription_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetSubscriptionAttributesInput,
+                ) -> ::std::result::Result<
+                    GetSubscriptionAttributesResponse,
+                    RusotoError<GetSubscriptionAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_topic_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetTopicAttributesInput,
+                ) -> ::std::result::Result<
+                    GetTopicAttributesResponse,
+                    RusotoError<GetTopicAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_endpoints_by_platform_application: Option<
+        Box<
+            dyn Fn(
+                    ListEndpointsByPlatformApplicationInput,
+                ) -> ::std::result::Result<
+                    ListEndpointsByPlatformApplicationResponse,
+                    RusotoError<ListEndpointsByPlatformApplicationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_phone_numbers_opted_out: Option<
+        Box<
+            dyn Fn(
+                    ListPhoneNumbersOptedOutInput,
+                ) -> ::std::result::Result<
+                    ListPhoneNumbersOptedOutResponse,
+                    RusotoError<ListPhoneNumbersOptedOutError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_platform_applications: Option<
+        Box<
+            dyn Fn(
+                    ListPlatformApplicationsInput,
+                ) -> ::std::result::Result<
+                    ListPlatformApplicationsResponse,
+                    RusotoError<ListPlatformApplicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    ListSubscriptionsInput,
+                ) -> ::std::result::Result<
+                    ListSubscriptionsResponse,
+                    RusotoError<ListSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_subscriptions_by_topic: Option<
+        Box<
+            dyn Fn(
+                    ListSubscriptionsByTopicInput,
+                ) -> ::std::result::Result<
+                    ListSubscriptionsByTopicResponse,
+                    RusotoError<ListSubscriptionsByTopicError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_topics: Option<
+        Box<
+            dyn Fn(
+                    ListTopicsInput,
+                )
+                    -> ::std::result::Result<ListTopicsResponse, RusotoError<ListTopicsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    opt_in_phone_number: Option<
+        Box<
+            dyn Fn(
+                    OptInPhoneNumberInput,
+                ) -> ::std::result::Result<
+                    OptInPhoneNumberResponse,
+                    RusotoError<OptInPhoneNumberError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    publish: Option<
+        Box<
+            dyn Fn(
+                    PublishInput,
+                )
+                    -> ::std::result::Result<PublishResponse, RusotoError<PublishError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_permission: Option<
+        Box<
+            dyn Fn(
+                    RemovePermissionInput,
+                ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_endpoint_attributes: Option<
+        Box<
+            dyn Fn(
+                    SetEndpointAttributesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetEndpointAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_platform_application_attributes: Option<
+        Box<
+            dyn Fn(
+                    SetPlatformApplicationAttributesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetPlatformApplicationAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_sms_attributes: Option<
+        Box<
+            dyn Fn(
+                    SetSMSAttributesInput,
+                ) -> ::std::result::Result<
+                    SetSMSAttributesResponse,
+                    RusotoError<SetSMSAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_subscription_attributes: Option<
+        Box<
+            dyn Fn(
+                    SetSubscriptionAttributesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetSubscriptionAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    set_topic_attributes: Option<
+        Box<
+            dyn Fn(
+                    SetTopicAttributesInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetTopicAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    subscribe: Option<
+        Box<
+            dyn Fn(
+                    SubscribeInput,
+                )
+                    -> ::std::result::Result<SubscribeResponse, RusotoError<SubscribeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    unsubscribe: Option<
+        Box<
+            dyn Fn(UnsubscribeInput) -> ::std::result::Result<(), RusotoError<UnsubscribeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSns {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSns {
+        Default::default()
+    }
+
+    /// Answers `add_permission` with what `handler` returns for its input.
+    pub fn with_add_permission<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(AddPermissionInput) -> ::std::result::Result<(), RusotoError<AddPermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `check_if_phone_number_is_opted_out` with what `handler` returns for its input.
+    pub fn with_check_if_phone_number_is_opted_out<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                CheckIfPhoneNumberIsOptedOutInput,
+            ) -> ::std::result::Result<
+                CheckIfPhoneNumberIsOptedOutResponse,
+                RusotoError<CheckIfPhoneNumberIsOptedOutError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.check_if_phone_number_is_opted_out = Some(Box::new(handler));
+        self
+    }
+    /// Answers `confirm_subscription` with what `handler` returns for its input.
+    pub fn with_confirm_subscription<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ConfirmSubscriptionInput,
+            ) -> ::std::result::Result<
+                ConfirmSubscriptionResponse,
+                RusotoError<ConfirmSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.confirm_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_platform_application` with what `handler` returns for its input.
+    pub fn with_create_platform_application<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                CreatePlatformApplicationInput,
+            ) -> ::std::result::Result<
+                CreatePlatformApplicationResponse,
+                RusotoError<CreatePlatformApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_platform_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_platform_endpoint` with what `handler` returns for its input.
+    pub fn with_create_platform_endpoint<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                CreatePlatformEndpointInput,
+            ) -> ::std::result::Result<
+                CreateEndpointResponse,
+                RusotoError<CreatePlatformEndpointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_platform_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_topic` with what `handler` returns for its input.
+    pub fn with_create_topic<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                CreateTopicInput,
+            )
+                -> ::std::result::Result<CreateTopicResponse, RusotoError<CreateTopicError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_topic = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_endpoint` with what `handler` returns for its input.
+    pub fn with_delete_endpoint<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(DeleteEndpointInput) -> ::std::result::Result<(), RusotoError<DeleteEndpointError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_endpoint = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_platform_application` with what `handler` returns for its input.
+    pub fn with_delete_platform_application<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                DeletePlatformApplicationInput,
+            )
+                -> ::std::result::Result<(), RusotoError<DeletePlatformApplicationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_platform_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_topic` with what `handler` returns for its input.
+    pub fn with_delete_topic<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(DeleteTopicInput) -> ::std::result::Result<(), RusotoError<DeleteTopicError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_topic = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_endpoint_attributes` with what `handler` returns for its input.
+    pub fn with_get_endpoint_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                GetEndpointAttributesInput,
+            ) -> ::std::result::Result<
+                GetEndpointAttributesResponse,
+                RusotoError<GetEndpointAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_endpoint_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_platform_application_attributes` with what `handler` returns for its input.
+    pub fn with_get_platform_application_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                GetPlatformApplicationAttributesInput,
+            ) -> ::std::result::Result<
+                GetPlatformApplicationAttributesResponse,
+                RusotoError<GetPlatformApplicationAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_platform_application_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sms_attributes` with what `handler` returns for its input.
+    pub fn with_get_sms_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                GetSMSAttributesInput,
+            ) -> ::std::result::Result<
+                GetSMSAttributesResponse,
+                RusotoError<GetSMSAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sms_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_subscription_attributes` with what `handler` returns for its input.
+    pub fn with_get_subscription_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                GetSubscriptionAttributesInput,
+            ) -> ::std::result::Result<
+                GetSubscriptionAttributesResponse,
+                RusotoError<GetSubscriptionAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_subscription_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_topic_attributes` with what `handler` returns for its input.
+    pub fn with_get_topic_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                GetTopicAttributesInput,
+            ) -> ::std::result::Result<
+                GetTopicAttributesResponse,
+                RusotoError<GetTopicAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_topic_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_endpoints_by_platform_application` with what `handler` returns for its input.
+    pub fn with_list_endpoints_by_platform_application<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListEndpointsByPlatformApplicationInput,
+            ) -> ::std::result::Result<
+                ListEndpointsByPlatformApplicationResponse,
+                RusotoError<ListEndpointsByPlatformApplicationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_endpoints_by_platform_application = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_phone_numbers_opted_out` with what `handler` returns for its input.
+    pub fn with_list_phone_numbers_opted_out<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListPhoneNumbersOptedOutInput,
+            ) -> ::std::result::Result<
+                ListPhoneNumbersOptedOutResponse,
+                RusotoError<ListPhoneNumbersOptedOutError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_phone_numbers_opted_out = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_platform_applications` with what `handler` returns for its input.
+    pub fn with_list_platform_applications<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListPlatformApplicationsInput,
+            ) -> ::std::result::Result<
+                ListPlatformApplicationsResponse,
+                RusotoError<ListPlatformApplicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_platform_applications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_subscriptions` with what `handler` returns for its input.
+    pub fn with_list_subscriptions<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListSubscriptionsInput,
+            ) -> ::std::result::Result<
+                ListSubscriptionsResponse,
+                RusotoError<ListSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_subscriptions_by_topic` with what `handler` returns for its input.
+    pub fn with_list_subscriptions_by_topic<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListSubscriptionsByTopicInput,
+            ) -> ::std::result::Result<
+                ListSubscriptionsByTopicResponse,
+                RusotoError<ListSubscriptionsByTopicError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_subscriptions_by_topic = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_topics` with what `handler` returns for its input.
+    pub fn with_list_topics<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                ListTopicsInput,
+            )
+                -> ::std::result::Result<ListTopicsResponse, RusotoError<ListTopicsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_topics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `opt_in_phone_number` with what `handler` returns for its input.
+    pub fn with_opt_in_phone_number<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                OptInPhoneNumberInput,
+            ) -> ::std::result::Result<
+                OptInPhoneNumberResponse,
+                RusotoError<OptInPhoneNumberError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.opt_in_phone_number = Some(Box::new(handler));
+        self
+    }
+    /// Answers `publish` with what `handler` returns for its input.
+    pub fn with_publish<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(PublishInput) -> ::std::result::Result<PublishResponse, RusotoError<PublishError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.publish = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_permission` with what `handler` returns for its input.
+    pub fn with_remove_permission<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                RemovePermissionInput,
+            ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_endpoint_attributes` with what `handler` returns for its input.
+    pub fn with_set_endpoint_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                SetEndpointAttributesInput,
+            ) -> ::std::result::Result<(), RusotoError<SetEndpointAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_endpoint_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_platform_application_attributes` with what `handler` returns for its input.
+    pub fn with_set_platform_application_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                SetPlatformApplicationAttributesInput,
+            )
+                -> ::std::result::Result<(), RusotoError<SetPlatformApplicationAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_platform_application_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_sms_attributes` with what `handler` returns for its input.
+    pub fn with_set_sms_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                SetSMSAttributesInput,
+            ) -> ::std::result::Result<
+                SetSMSAttributesResponse,
+                RusotoError<SetSMSAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_sms_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_subscription_attributes` with what `handler` returns for its input.
+    pub fn with_set_subscription_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                SetSubscriptionAttributesInput,
+            )
+                -> ::std::result::Result<(), RusotoError<SetSubscriptionAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_subscription_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_topic_attributes` with what `handler` returns for its input.
+    pub fn with_set_topic_attributes<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                SetTopicAttributesInput,
+            ) -> ::std::result::Result<(), RusotoError<SetTopicAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_topic_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `subscribe` with what `handler` returns for its input.
+    pub fn with_subscribe<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                SubscribeInput,
+            ) -> ::std::result::Result<SubscribeResponse, RusotoError<SubscribeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.subscribe = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `unsubscribe` with what `handler` returns for its input.
+    pub fn with_unsubscribe<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(UnsubscribeInput) -> ::std::result::Result<(), RusotoError<UnsubscribeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.unsubscribe = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockSns
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
 
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+#[cfg(any(test, feature = "mocks"))]
+impl Sns for MockSns {
+    fn add_permission(&self, input: AddPermissionInput) -> RusotoFuture<(), AddPermissionError> {
+        match self.add_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for add_permission"),
+        }
+    }
+    fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> RusotoFuture<CheckIfPhoneNumberIsOptedOutResponse, CheckIfPhoneNumberIsOptedOutError> {
+        match self.check_if_phone_number_is_opted_out {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for check_if_phone_number_is_opted_out"),
+        }
+    }
+    fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> RusotoFuture<ConfirmSubscriptionResponse, ConfirmSubscriptionError> {
+        match self.confirm_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for confirm_subscription"),
+        }
+    }
+    fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> RusotoFuture<CreatePlatformApplicationResponse, CreatePlatformApplicationError> {
+        match self.create_platform_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for create_platform_application"),
+        }
+    }
+    fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> RusotoFuture<CreateEndpointResponse, CreatePlatformEndpointError> {
+        match self.create_platform_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for create_platform_endpoint"),
+        }
+    }
+    fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> RusotoFuture<CreateTopicResponse, CreateTopicError> {
+        match self.create_topic {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for create_topic"),
+        }
+    }
+    fn delete_endpoint(&self, input: DeleteEndpointInput) -> RusotoFuture<(), DeleteEndpointError> {
+        match self.delete_endpoint {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for delete_endpoint"),
+        }
+    }
+    fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> RusotoFuture<(), DeletePlatformApplicationError> {
+        match self.delete_platform_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for delete_platform_application"),
+        }
+    }
+    fn delete_topic(&self, input: DeleteTopicInput) -> RusotoFuture<(), DeleteTopicError> {
+        match self.delete_topic {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for delete_topic"),
+        }
+    }
+    fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> RusotoFuture<GetEndpointAttributesResponse, GetEndpointAttributesError> {
+        match self.get_endpoint_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for get_endpoint_attributes"),
+        }
+    }
+    fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<GetPlatformApplicationAttributesResponse, GetPlatformApplicationAttributesError>
+    {
+        match self.get_platform_application_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for get_platform_application_attributes"),
+        }
+    }
+    fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> RusotoFuture<GetSMSAttributesResponse, GetSMSAttributesError> {
+        match self.get_sms_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for get_sms_attributes"),
+        }
+    }
+    fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> RusotoFuture<GetSubscriptionAttributesResponse, GetSubscriptionAttributesError> {
+        match self.get_subscription_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for get_subscription_attributes"),
+        }
+    }
+    fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> RusotoFuture<GetTopicAttributesResponse, GetTopicAttributesError> {
+        match self.get_topic_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for get_topic_attributes"),
+        }
+    }
+    fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> RusotoFuture<
+        ListEndpointsByPlatformApplicationResponse,
+        ListEndpointsByPlatformApplicationError,
+    > {
+        match self.list_endpoints_by_platform_application {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_endpoints_by_platform_application"),
+        }
+    }
+    fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> RusotoFuture<ListPhoneNumbersOptedOutResponse, ListPhoneNumbersOptedOutError> {
+        match self.list_phone_numbers_opted_out {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_phone_numbers_opted_out"),
+        }
+    }
+    fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> RusotoFuture<ListPlatformApplicationsResponse, ListPlatformApplicationsError> {
+        match self.list_platform_applications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_platform_applications"),
+        }
+    }
+    fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> RusotoFuture<ListSubscriptionsResponse, ListSubscriptionsError> {
+        match self.list_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_subscriptions"),
+        }
+    }
+    fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> RusotoFuture<ListSubscriptionsByTopicResponse, ListSubscriptionsByTopicError> {
+        match self.list_subscriptions_by_topic {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_subscriptions_by_topic"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> RusotoFuture<ListTopicsResponse, ListTopicsError> {
+        match self.list_topics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for list_topics"),
+        }
+    }
+    fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> RusotoFuture<OptInPhoneNumberResponse, OptInPhoneNumberError> {
+        match self.opt_in_phone_number {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for opt_in_phone_number"),
+        }
+    }
+    fn publish(&self, input: PublishInput) -> RusotoFuture<PublishResponse, PublishError> {
+        match self.publish {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for publish"),
+        }
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        match self.remove_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for remove_permission"),
+        }
+    }
+    fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> RusotoFuture<(), SetEndpointAttributesError> {
+        match self.set_endpoint_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for set_endpoint_attributes"),
+        }
+    }
+    fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> RusotoFuture<(), SetPlatformApplicationAttributesError> {
+        match self.set_platform_application_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for set_platform_application_attributes"),
+        }
     }
-
-    /// <p>Use this request to set the default settings for sending SMS messages and receiving daily SMS usage reports.</p> <p>You can override some of these settings for a single message when you use the <code>Publish</code> action with the <code>MessageAttributes.entry.N</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sms_publish-to-phone.html">Sending an SMS Message</a> in the <i>Amazon SNS Developer Guide</i>.</p>
     fn set_sms_attributes(
         &self,
         input: SetSMSAttributesInput,
     ) -> RusotoFuture<SetSMSAttributesResponse, SetSMSAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetSMSAttributes");
-        params.put("Version", "2010-03-31");
-        SetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetSMSAttributesError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SetSMSAttributesResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = SetSMSAttributesResponseDeserializer::deserialize(
-                        "SetSMSAttributesResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.set_sms_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for set_sms_attributes"),
+        }
     }
-
-    /// <p>Allows a subscription owner to set an attribute of the subscription to a new value.</p>
     fn set_subscription_attributes(
         &self,
         input: SetSubscriptionAttributesInput,
     ) -> RusotoFuture<(), SetSubscriptionAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetSubscriptionAttributes");
-        params.put("Version", "2010-03-31");
-        SetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetSubscriptionAttributesError::from_response(response))
-                }));
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.set_subscription_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for set_subscription_attributes"),
+        }
     }
-
-    /// <p>Allows a topic owner to set an attribute of the topic to a new value.</p>
     fn set_topic_attributes(
         &self,
         input: SetTopicAttributesInput,
     ) -> RusotoFuture<(), SetTopicAttributesError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "SetTopicAttributes");
-        params.put("Version", "2010-03-31");
-        SetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SetTopicAttributesError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.set_topic_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for set_topic_attributes"),
+        }
     }
-
-    /// <p>Prepares to subscribe an endpoint by sending the endpoint a confirmation message. To actually create a subscription, the endpoint owner must call the <code>ConfirmSubscription</code> action with the token from the confirmation message. Confirmation tokens are valid for three days.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
     fn subscribe(&self, input: SubscribeInput) -> RusotoFuture<SubscribeResponse, SubscribeError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "Subscribe");
-        params.put("Version", "2010-03-31");
-        SubscribeInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SubscribeError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = SubscribeResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result =
-                        SubscribeResponseDeserializer::deserialize("SubscribeResult", &mut stack)?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.subscribe {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for subscribe"),
+        }
     }
-
-    /// <p>Add tags to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p> <p>When you use topic tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a topic isn't recommended.</p> </li> <li> <p>Tags don't have any semantic meaning. Amazon SNS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> <li> <p>Tagging actions are limited to 10 TPS per AWS account. If your application requires a higher throughput, file a <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=technical">technical support request</a>.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-limits.html#limits-topics">Limits Related to Topics</a> in the <i>Amazon SNS Developer Guide</i>.</p>
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "TagResource");
-        params.put("Version", "2010-03-31");
-        TagResourceRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = TagResourceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = TagResourceResponseDeserializer::deserialize(
-                        "TagResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Deletes a subscription. If the subscription requires authentication for deletion, only the owner of the subscription or the topic's owner can unsubscribe, and an AWS signature is required. If the <code>Unsubscribe</code> call does not require authentication and the requester is not the subscription owner, a final cancellation message is delivered to the endpoint, so that the endpoint owner can easily resubscribe to the topic if the <code>Unsubscribe</code> request was unintended.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
     fn unsubscribe(&self, input: UnsubscribeInput) -> RusotoFuture<(), UnsubscribeError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "Unsubscribe");
-        params.put("Version", "2010-03-31");
-        UnsubscribeInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UnsubscribeError::from_response(response))),
-                );
-            }
-
-            Box::new(future::ok(::std::mem::drop(response)))
-        })
+        match self.unsubscribe {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for unsubscribe"),
+        }
     }
-
-    /// <p>Remove tags from the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p>
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "UntagResource");
-        params.put("Version", "2010-03-31");
-        UntagResourceRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if !response.status.is_success() {
-                return Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                );
-            }
-
-            Box::new(response.buffer().from_err().and_then(move |response| {
-                let result;
-
-                if response.body.is_empty() {
-                    result = UntagResourceResponse::default();
-                } else {
-                    let reader = EventReader::new_with_config(
-                        response.body.as_ref(),
-                        ParserConfig::new().trim_whitespace(true),
-                    );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
-                    let actual_tag_name = peek_at_name(&mut stack)?;
-                    start_element(&actual_tag_name, &mut stack)?;
-                    result = UntagResourceResponseDeserializer::deserialize(
-                        "UntagResourceResult",
-                        &mut stack,
-                    )?;
-                    skip_tree(&mut stack);
-                    end_element(&actual_tag_name, &mut stack)?;
-                }
-                // parse non-payload
-                Ok(result)
-            }))
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSns has no handler for untag_resource"),
+        }
     }
 }
 
@@ -1,16 +1,22 @@
 extern crate rusoto_mock;
 
+use crate::fifo::{
+    content_deduplication_id, send_fifo_messages, FifoError, FifoMessage, MAX_ID_LENGTH,
+};
 use crate::generated::{
     GetQueueUrlError, GetQueueUrlRequest, MessageAttributeValue, ReceiveMessageRequest,
     SendMessageRequest, Sqs, SqsClient,
 };
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use self::rusoto_mock::*;
+use futures::Future;
 use rusoto_core::param::Params;
 use rusoto_core::signature::SignedRequest;
 use rusoto_core::signature::SignedRequestPayload;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::{DispatchSignedRequest, Region, RusotoError};
 use serde_urlencoded;
 
 #[test]
@@ -179,3 +185,209 @@ fn test_parse_queue_does_not_exist_error() {
         err
     );
 }
+
+const FIFO_QUEUE_URL: &str = "https://sqs.us-east-1.amazonaws.com/123456789012/orders.fifo";
+
+#[test]
+fn fifo_messages_derive_their_deduplication_id_from_their_body() {
+    assert_eq!(
+        content_deduplication_id("hello"),
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+
+    let request = FifoMessage::new("customer-42", "hello")
+        .send_request(FIFO_QUEUE_URL)
+        .unwrap();
+    assert_eq!(request.queue_url, FIFO_QUEUE_URL);
+    assert_eq!(request.message_group_id, Some("customer-42".to_owned()));
+    assert_eq!(
+        request.message_deduplication_id,
+        Some(content_deduplication_id("hello"))
+    );
+
+    let request = FifoMessage::new("customer-42", "hello")
+        .with_content_based_deduplication(true)
+        .send_request(FIFO_QUEUE_URL)
+        .unwrap();
+    assert_eq!(request.message_deduplication_id, None);
+
+    let entry = FifoMessage::new("customer-42", "hello")
+        .with_content_based_deduplication(true)
+        .with_deduplication_id("order-1")
+        .batch_entry("0")
+        .unwrap();
+    assert_eq!(entry.message_deduplication_id, Some("order-1".to_owned()));
+}
+
+#[test]
+fn fifo_messages_with_invalid_ids_are_refused() {
+    let too_long = "a".repeat(MAX_ID_LENGTH + 1);
+    assert_eq!(
+        FifoMessage::new("", "hello").send_request(FIFO_QUEUE_URL),
+        Err(FifoError::InvalidGroupId {
+            id: "".to_owned(),
+            reason: "it is empty".to_owned(),
+        })
+    );
+    assert_eq!(
+        FifoMessage::new(too_long.clone(), "hello").send_request(FIFO_QUEUE_URL),
+        Err(FifoError::InvalidGroupId {
+            id: too_long.clone(),
+            reason: "it is longer than 128 characters".to_owned(),
+        })
+    );
+    assert_eq!(
+        FifoMessage::new("customer 42", "hello")
+            .send_request(FIFO_QUEUE_URL)
+            .unwrap_err()
+            .to_string(),
+        "invalid message group id \"customer 42\": ' ' is not an ASCII letter, digit or \
+         punctuation character"
+    );
+    assert_eq!(
+        FifoMessage::new("customer-42", "hello")
+            .with_deduplication_id("ordre-\u{e9}")
+            .batch_entry("0"),
+        Err(FifoError::InvalidDeduplicationId {
+            id: "ordre-\u{e9}".to_owned(),
+            reason: "'\u{e9}' is not an ASCII letter, digit or punctuation character".to_owned(),
+        })
+    );
+    assert!(FifoMessage::new("a".repeat(MAX_ID_LENGTH), "hello")
+        .with_deduplication_id("!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~")
+        .send_request(FIFO_QUEUE_URL)
+        .is_ok());
+}
+
+#[test]
+fn fifo_messages_are_refused_for_standard_queues() {
+    let queue_url = "https://sqs.us-east-1.amazonaws.com/123456789012/orders";
+    let err = FifoMessage::new("customer-42", "hello")
+        .send_request(queue_url)
+        .unwrap_err();
+    assert_eq!(err, FifoError::NotFifoQueue(queue_url.to_owned()));
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "{} is not the URL of a FIFO queue, whose names end with .fifo",
+            queue_url
+        )
+    );
+
+    let client = SqsClient::new_with(
+        MockRequestDispatcher::with_status(200).with_request_checker(|_| {
+            panic!("batch sent to a standard queue");
+        }),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    assert!(send_fifo_messages(client, queue_url, vec![FifoMessage::new("a", "1")]).is_err());
+}
+
+/// Answers `SendMessageBatch` requests, failing the entries whose body is `fail`, and
+/// records the `(id, group)` of the entries of each batch.
+struct BatchDispatcher {
+    batches: Batches,
+}
+
+type Batches = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+impl DispatchSignedRequest for BatchDispatcher {
+    type Future = <MockRequestDispatcher as DispatchSignedRequest>::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let params: Params = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                serde_urlencoded::from_bytes(buffer).unwrap()
+            }
+            _ => panic!("request without a body"),
+        };
+        let param = |name: String| params.get(&name).cloned().unwrap().unwrap();
+        let mut batch = Vec::new();
+        let mut results = String::new();
+        for i in 1..=params.len() {
+            let prefix = format!("SendMessageBatchRequestEntry.{}", i);
+            if !params.contains_key(&format!("{}.Id", prefix)) {
+                break;
+            }
+            let id = param(format!("{}.Id", prefix));
+            batch.push((id.clone(), param(format!("{}.MessageGroupId", prefix))));
+            if param(format!("{}.MessageBody", prefix)) == "fail" {
+                results.push_str(&format!(
+                    "<BatchResultErrorEntry><Id>{}</Id><Code>InternalError</Code>\
+                     <SenderFault>false</SenderFault></BatchResultErrorEntry>",
+                    id
+                ));
+            } else {
+                results.push_str(&format!(
+                    "<SendMessageBatchResultEntry><Id>{}</Id><MessageId>message-{}</MessageId>\
+                     <MD5OfMessageBody>0</MD5OfMessageBody></SendMessageBatchResultEntry>",
+                    id, id
+                ));
+            }
+        }
+        self.batches.lock().unwrap().push(batch);
+        MockRequestDispatcher::with_status(200)
+            .with_body(&format!(
+                "<SendMessageBatchResponse><SendMessageBatchResult>{}\
+                 </SendMessageBatchResult><ResponseMetadata><RequestId>1</RequestId>\
+                 </ResponseMetadata></SendMessageBatchResponse>",
+                results
+            ))
+            .dispatch(request, timeout)
+    }
+}
+
+const GROUPS: [&str; 2] = ["a", "b"];
+
+#[test]
+fn fifo_batches_keep_the_order_of_each_group() {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let client = SqsClient::new_with(
+        BatchDispatcher {
+            batches: batches.clone(),
+        },
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    // the third message of group b fails, so the following ones are held back
+    let messages = (0..24)
+        .map(|i| {
+            let group = GROUPS[i % 2];
+            let body = if i == 5 {
+                "fail".to_owned()
+            } else {
+                i.to_string()
+            };
+            FifoMessage::new(group, body)
+        })
+        .collect();
+    let output = send_fifo_messages(client, FIFO_QUEUE_URL, messages)
+        .unwrap()
+        .wait()
+        .unwrap();
+
+    let batches = batches.lock().unwrap();
+    let sent: Vec<Vec<(usize, &str)>> = batches
+        .iter()
+        .map(|batch| {
+            batch
+                .iter()
+                .map(|(id, group)| (id.parse().unwrap(), group.as_str()))
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        sent,
+        vec![
+            (0..10).map(|i| (i, GROUPS[i % 2])).collect::<Vec<_>>(),
+            (10..24).step_by(2).map(|i| (i, "a")).collect(),
+        ]
+    );
+
+    assert_eq!(output.failed.len(), 1);
+    assert_eq!(output.failed[0].id, "5");
+    assert_eq!(output.successful.len(), 16);
+    assert_eq!(output.successful[0].message_id, "message-0");
+    assert_eq!(output.skipped, (11..24).step_by(2).collect::<Vec<_>>());
+}
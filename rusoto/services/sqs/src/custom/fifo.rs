@@ -0,0 +1,334 @@
+//! Sending messages to FIFO queues.
+//!
+//! Every message sent to a FIFO queue needs a message group id, and a deduplication id
+//! unless the queue has content-based deduplication. A `FifoMessage` checks both ids before
+//! anything is sent, rather than leaving SQS to answer with an `InvalidParameterValue`, and
+//! derives the deduplication id from the SHA-256 digest of the body, as content-based
+//! deduplication would, when the queue doesn't have it. `send_fifo_messages` sends many
+//! messages in batches, keeping the order of each group.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_sqs::fifo::FifoMessage;
+//! use rusoto_sqs::{Sqs, SqsClient};
+//!
+//! let client = SqsClient::new(Region::UsEast1);
+//! let request = FifoMessage::new("customer-42", r#"{"order": 1}"#)
+//!     .send_request("https://sqs.us-east-1.amazonaws.com/123456789012/orders.fifo")
+//!     .unwrap();
+//! client.send_message(request).sync().unwrap();
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Either, Loop};
+use futures::Future;
+use rusoto_core::RusotoError;
+use sha2::{Digest, Sha256};
+
+use crate::generated::{
+    BatchResultErrorEntry, MessageAttributeValue, SendMessageBatchError, SendMessageBatchRequest,
+    SendMessageBatchRequestEntry, SendMessageBatchResultEntry, SendMessageRequest, Sqs,
+};
+
+/// The longest message group or deduplication id SQS accepts.
+pub const MAX_ID_LENGTH: usize = 128;
+
+/// The most messages SQS accepts in a single `send_message_batch` request.
+pub const MAX_BATCH_SIZE: usize = 10;
+
+/// The suffix of the names, and so of the URLs, of FIFO queues.
+const FIFO_SUFFIX: &str = ".fifo";
+
+/// An error in a message for a FIFO queue, found before sending it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FifoError {
+    /// The queue URL, which doesn't end with `.fifo`.
+    NotFifoQueue(String),
+    /// The message group id is empty, too long, or has characters SQS doesn't accept.
+    InvalidGroupId {
+        /// The rejected id.
+        id: String,
+        /// What is wrong with it.
+        reason: String,
+    },
+    /// The message deduplication id is empty, too long, or has characters SQS doesn't accept.
+    InvalidDeduplicationId {
+        /// The rejected id.
+        id: String,
+        /// What is wrong with it.
+        reason: String,
+    },
+}
+
+impl fmt::Display for FifoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FifoError::NotFifoQueue(ref queue_url) => write!(
+                f,
+                "{} is not the URL of a FIFO queue, whose names end with {}",
+                queue_url, FIFO_SUFFIX
+            ),
+            FifoError::InvalidGroupId { ref id, ref reason } => {
+                write!(f, "invalid message group id {:?}: {}", id, reason)
+            }
+            FifoError::InvalidDeduplicationId { ref id, ref reason } => {
+                write!(f, "invalid message deduplication id {:?}: {}", id, reason)
+            }
+        }
+    }
+}
+
+impl Error for FifoError {
+    fn description(&self) -> &str {
+        match *self {
+            FifoError::NotFifoQueue(_) => "not the URL of a FIFO queue",
+            FifoError::InvalidGroupId { .. } => "invalid message group id",
+            FifoError::InvalidDeduplicationId { .. } => "invalid message deduplication id",
+        }
+    }
+}
+
+/// A message for a FIFO queue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FifoMessage {
+    group_id: String,
+    body: String,
+    deduplication_id: Option<String>,
+    content_based_deduplication: bool,
+    message_attributes: Option<HashMap<String, MessageAttributeValue>>,
+}
+
+impl FifoMessage {
+    /// A message with `body`, delivered in order with the other messages of `group_id`.
+    pub fn new<G: Into<String>, B: Into<String>>(group_id: G, body: B) -> FifoMessage {
+        FifoMessage {
+            group_id: group_id.into(),
+            body: body.into(),
+            deduplication_id: None,
+            content_based_deduplication: false,
+            message_attributes: None,
+        }
+    }
+
+    /// Deduplicate the message with `deduplication_id` rather than with its body.
+    pub fn with_deduplication_id<D: Into<String>>(mut self, deduplication_id: D) -> FifoMessage {
+        self.deduplication_id = Some(deduplication_id.into());
+        self
+    }
+
+    /// Whether the queue has content-based deduplication, in which case a message without
+    /// a deduplication id is sent without one, for SQS to deduplicate it by its body.
+    /// Otherwise, which is the default, the id is derived with `content_deduplication_id`.
+    pub fn with_content_based_deduplication(mut self, enabled: bool) -> FifoMessage {
+        self.content_based_deduplication = enabled;
+        self
+    }
+
+    /// Send the message with `message_attributes`.
+    pub fn with_message_attributes(
+        mut self,
+        message_attributes: HashMap<String, MessageAttributeValue>,
+    ) -> FifoMessage {
+        self.message_attributes = Some(message_attributes);
+        self
+    }
+
+    /// The group of the message.
+    pub fn group_id(&self) -> &str {
+        &self.group_id
+    }
+
+    /// The request sending the message to the FIFO queue at `queue_url`.
+    pub fn send_request(self, queue_url: &str) -> Result<SendMessageRequest, FifoError> {
+        check_queue_url(queue_url)?;
+        let deduplication_id = self.checked_deduplication_id()?;
+        Ok(SendMessageRequest {
+            message_attributes: self.message_attributes,
+            message_body: self.body,
+            message_deduplication_id: deduplication_id,
+            message_group_id: Some(self.group_id),
+            queue_url: queue_url.to_owned(),
+            ..Default::default()
+        })
+    }
+
+    /// The entry sending the message in a batch, identified by `id` in the results.
+    pub fn batch_entry<I: Into<String>>(
+        self,
+        id: I,
+    ) -> Result<SendMessageBatchRequestEntry, FifoError> {
+        let deduplication_id = self.checked_deduplication_id()?;
+        Ok(SendMessageBatchRequestEntry {
+            id: id.into(),
+            message_attributes: self.message_attributes,
+            message_body: self.body,
+            message_deduplication_id: deduplication_id,
+            message_group_id: Some(self.group_id),
+            ..Default::default()
+        })
+    }
+
+    /// Checks both ids, returning the deduplication id to send the message with.
+    fn checked_deduplication_id(&self) -> Result<Option<String>, FifoError> {
+        if let Err(reason) = check_id(&self.group_id) {
+            return Err(FifoError::InvalidGroupId {
+                id: self.group_id.clone(),
+                reason,
+            });
+        }
+        match self.deduplication_id {
+            Some(ref id) => match check_id(id) {
+                Ok(()) => Ok(Some(id.clone())),
+                Err(reason) => Err(FifoError::InvalidDeduplicationId {
+                    id: id.clone(),
+                    reason,
+                }),
+            },
+            None if self.content_based_deduplication => Ok(None),
+            None => Ok(Some(content_deduplication_id(&self.body))),
+        }
+    }
+}
+
+/// The deduplication id of a message by its body: the hex encoded SHA-256 digest of the
+/// body, as used by queues with content-based deduplication.
+pub fn content_deduplication_id(body: &str) -> String {
+    Sha256::digest(body.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// The outcome of `send_fifo_messages`, where messages are identified by their position.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FifoBatchOutput {
+    /// The messages sent, whose position is the `id` of their entry.
+    pub successful: Vec<SendMessageBatchResultEntry>,
+    /// The messages SQS refused, whose position is the `id` of their entry.
+    pub failed: Vec<BatchResultErrorEntry>,
+    /// The positions of the messages not sent because an earlier message of their group
+    /// failed, as sending them would deliver the group out of order.
+    pub skipped: Vec<usize>,
+}
+
+/// Sends `messages` to the FIFO queue at `queue_url`, `MAX_BATCH_SIZE` at a time.
+///
+/// Batches are sent one after the other, so that the messages of each group are sent in
+/// the order given. Once a message fails, the following messages of its group are skipped
+/// rather than sent ahead of it, except for those already sent in the same batch. Every
+/// message is checked before the first batch is sent.
+pub fn send_fifo_messages<C>(
+    client: C,
+    queue_url: &str,
+    messages: Vec<FifoMessage>,
+) -> Result<
+    impl Future<Item = FifoBatchOutput, Error = RusotoError<SendMessageBatchError>> + Send,
+    FifoError,
+>
+where
+    C: Sqs + Send + 'static,
+{
+    check_queue_url(queue_url)?;
+    let mut groups = Vec::with_capacity(messages.len());
+    let mut pending = VecDeque::with_capacity(messages.len());
+    for (position, message) in messages.into_iter().enumerate() {
+        groups.push(message.group_id.clone());
+        pending.push_back((position, message.batch_entry(position.to_string())?));
+    }
+
+    let state = BatchState {
+        client,
+        queue_url: queue_url.to_owned(),
+        groups,
+        pending,
+        failed_groups: HashSet::new(),
+        output: FifoBatchOutput::default(),
+    };
+    Ok(future::loop_fn(state, |mut state| {
+        let entries = state.next_batch();
+        if entries.is_empty() {
+            return Either::A(future::ok(Loop::Break(state.output)));
+        }
+        let request = SendMessageBatchRequest {
+            entries,
+            queue_url: state.queue_url.clone(),
+        };
+        Either::B(state.client.send_message_batch(request).map(move |result| {
+            for entry in &result.failed {
+                if let Some(group) = entry
+                    .id
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|position| state.groups.get(position))
+                {
+                    state.failed_groups.insert(group.clone());
+                }
+            }
+            state.output.successful.extend(result.successful);
+            state.output.failed.extend(result.failed);
+            Loop::Continue(state)
+        }))
+    }))
+}
+
+struct BatchState<C> {
+    client: C,
+    queue_url: String,
+    /// The group of each message, by position.
+    groups: Vec<String>,
+    pending: VecDeque<(usize, SendMessageBatchRequestEntry)>,
+    failed_groups: HashSet<String>,
+    output: FifoBatchOutput,
+}
+
+impl<C> BatchState<C> {
+    /// Takes up to `MAX_BATCH_SIZE` pending entries, skipping those of failed groups.
+    fn next_batch(&mut self) -> Vec<SendMessageBatchRequestEntry> {
+        let mut entries = Vec::with_capacity(MAX_BATCH_SIZE);
+        while entries.len() < MAX_BATCH_SIZE {
+            match self.pending.pop_front() {
+                Some((position, entry)) => {
+                    if self.failed_groups.contains(&self.groups[position]) {
+                        self.output.skipped.push(position);
+                    } else {
+                        entries.push(entry);
+                    }
+                }
+                None => break,
+            }
+        }
+        entries
+    }
+}
+
+fn check_queue_url(queue_url: &str) -> Result<(), FifoError> {
+    if queue_url.ends_with(FIFO_SUFFIX) {
+        Ok(())
+    } else {
+        Err(FifoError::NotFifoQueue(queue_url.to_owned()))
+    }
+}
+
+/// Checks a group or deduplication id, returning what is wrong with it.
+fn check_id(id: &str) -> Result<(), String> {
+    if id.is_empty() {
+        Err("it is empty".to_owned())
+    } else if id.chars().count() > MAX_ID_LENGTH {
+        Err(format!("it is longer than {} characters", MAX_ID_LENGTH))
+    } else if let Some(c) = id
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !c.is_ascii_punctuation())
+    {
+        Err(format!(
+            "{:?} is not an ASCII letter, digit or punctuation character",
+            c
+        ))
+    } else {
+        Ok(())
+    }
+}
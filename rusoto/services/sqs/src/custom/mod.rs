@@ -1,2 +1,5 @@
+/// Sending messages to FIFO queues
+pub mod fifo;
+
 #[cfg(test)]
 mod custom_tests;
@@ -23,8 +23,8 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
@@ -57,6 +57,8 @@ impl ActionNameListSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddPermissionRequest {
     /// <p>The AWS account number of the <a href="https://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P">principal</a> who is given permission. The principal must have an AWS account, but does not need to be signed up for Amazon SQS. For information about locating the AWS account identification, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-making-api-requests.html#sqs-api-request-authentication">Your AWS Identifiers</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
     pub aws_account_ids: Vec<String>,
@@ -105,6 +107,8 @@ impl AttributeNameListSerializer {
 
 /// <p>Gives a detailed description of the result of an action on each entry in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchResultErrorEntry {
     /// <p>An error code representing why the action failed on this entry.</p>
     pub code: String,
@@ -220,7 +224,7 @@ impl BooleanDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<bool>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -228,6 +232,8 @@ impl BooleanDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeMessageVisibilityBatchRequest {
     /// <p>A list of receipt handles of the messages for which the visibility timeout must be changed.</p>
     pub entries: Vec<ChangeMessageVisibilityBatchRequestEntry>,
@@ -255,6 +261,8 @@ impl ChangeMessageVisibilityBatchRequestSerializer {
 
 /// <p>Encloses a receipt handle and an entry id for each message in <code> <a>ChangeMessageVisibilityBatch</a>.</code> </p> <important> <p>All of the following list parameters must be prefixed with <code>ChangeMessageVisibilityBatchRequestEntry.n</code>, where <code>n</code> is an integer value starting with <code>1</code>. For example, a parameter list for this action might look like this:</p> </important> <p> <code>&amp;ChangeMessageVisibilityBatchRequestEntry.1.Id=change_visibility_msg_2</code> </p> <p> <code>&amp;ChangeMessageVisibilityBatchRequestEntry.1.ReceiptHandle=your_receipt_handle</code> </p> <p> <code>&amp;ChangeMessageVisibilityBatchRequestEntry.1.VisibilityTimeout=45</code> </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeMessageVisibilityBatchRequestEntry {
     /// <p><p>An identifier for this particular receipt handle used to communicate the result.</p> <note> <p>The <code>Id</code>s of a batch request need to be unique within a request</p> </note></p>
     pub id: String,
@@ -301,6 +309,8 @@ impl ChangeMessageVisibilityBatchRequestEntryListSerializer {
 
 /// <p>For each message in the batch, the response contains a <code> <a>ChangeMessageVisibilityBatchResultEntry</a> </code> tag if the message succeeds or a <code> <a>BatchResultErrorEntry</a> </code> tag if the message fails.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeMessageVisibilityBatchResult {
     /// <p>A list of <code> <a>BatchResultErrorEntry</a> </code> items.</p>
     pub failed: Vec<BatchResultErrorEntry>,
@@ -344,6 +354,8 @@ impl ChangeMessageVisibilityBatchResultDeserializer {
 }
 /// <p>Encloses the <code>Id</code> of an entry in <code> <a>ChangeMessageVisibilityBatch</a>.</code> </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeMessageVisibilityBatchResultEntry {
     /// <p>Represents a message whose visibility timeout has been changed successfully.</p>
     pub id: String,
@@ -403,6 +415,8 @@ impl ChangeMessageVisibilityBatchResultEntryListDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ChangeMessageVisibilityRequest {
     /// <p>The URL of the Amazon SQS queue whose message's visibility is changed.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
@@ -435,6 +449,8 @@ impl ChangeMessageVisibilityRequestSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateQueueRequest {
     /// <p><p>A map of attributes with their corresponding values.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>CreateQueue</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 seconds (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer from 60 seconds (1 minute) to 1,209,600 seconds (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: An integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: An integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Designates a queue as FIFO. Valid values: <code>true</code>, <code>false</code>. If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue. You can provide this attribute only during queue creation. You can&#39;t change it for an existing queue. When you set this attribute, you must also provide the <code>MessageGroupId</code> for your messages explicitly.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. Valid values: <code>true</code>, <code>false</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -469,6 +485,8 @@ impl CreateQueueRequestSerializer {
 
 /// <p>Returns the <code>QueueUrl</code> attribute of the created queue.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateQueueResult {
     /// <p>The URL of the created Amazon SQS queue.</p>
     pub queue_url: Option<String>,
@@ -494,6 +512,8 @@ impl CreateQueueResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMessageBatchRequest {
     /// <p>A list of receipt handles for the messages to be deleted.</p>
     pub entries: Vec<DeleteMessageBatchRequestEntry>,
@@ -521,6 +541,8 @@ impl DeleteMessageBatchRequestSerializer {
 
 /// <p>Encloses a receipt handle and an identifier for it.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMessageBatchRequestEntry {
     /// <p><p>An identifier for this particular receipt handle. This is used to communicate the result.</p> <note> <p>The <code>Id</code>s of a batch request need to be unique within a request</p> </note></p>
     pub id: String,
@@ -558,6 +580,8 @@ impl DeleteMessageBatchRequestEntryListSerializer {
 
 /// <p>For each message in the batch, the response contains a <code> <a>DeleteMessageBatchResultEntry</a> </code> tag if the message is deleted or a <code> <a>BatchResultErrorEntry</a> </code> tag if the message can't be deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMessageBatchResult {
     /// <p>A list of <code> <a>BatchResultErrorEntry</a> </code> items.</p>
     pub failed: Vec<BatchResultErrorEntry>,
@@ -601,6 +625,8 @@ impl DeleteMessageBatchResultDeserializer {
 }
 /// <p>Encloses the <code>Id</code> of an entry in <code> <a>DeleteMessageBatch</a>.</code> </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMessageBatchResultEntry {
     /// <p>Represents a successfully deleted message.</p>
     pub id: String,
@@ -659,6 +685,8 @@ impl DeleteMessageBatchResultEntryListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMessageRequest {
     /// <p>The URL of the Amazon SQS queue from which messages are deleted.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
@@ -685,6 +713,8 @@ impl DeleteMessageRequestSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteQueueRequest {
     /// <p>The URL of the Amazon SQS queue to delete.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
@@ -705,6 +735,8 @@ impl DeleteQueueRequestSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueueAttributesRequest {
     /// <p><p>A list of attributes for which to retrieve information.</p> <note> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </note> <p>The following attributes are supported:</p> <ul> <li> <p> <code>All</code> - Returns all values. </p> </li> <li> <p> <code>ApproximateNumberOfMessages</code> - Returns the approximate number of messages available for retrieval from the queue.</p> </li> <li> <p> <code>ApproximateNumberOfMessagesDelayed</code> - Returns the approximate number of messages in the queue that are delayed and not available for reading immediately. This can happen when the queue is configured as a delay queue or when a message has been sent with a delay parameter.</p> </li> <li> <p> <code>ApproximateNumberOfMessagesNotVisible</code> - Returns the approximate number of messages that are in flight. Messages are considered to be <i>in flight</i> if they have been sent to a client but have not yet been deleted or have not yet reached the end of their visibility window. </p> </li> <li> <p> <code>CreatedTimestamp</code> - Returns the time when the queue was created in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p> </li> <li> <p> <code>DelaySeconds</code> - Returns the default delay on the queue in seconds.</p> </li> <li> <p> <code>LastModifiedTimestamp</code> - Returns the time when the queue was last changed in seconds (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a>).</p> </li> <li> <p> <code>MaximumMessageSize</code> - Returns the limit of how many bytes a message can contain before Amazon SQS rejects it.</p> </li> <li> <p> <code>MessageRetentionPeriod</code> - Returns the length of time, in seconds, for which Amazon SQS retains a message.</p> </li> <li> <p> <code>Policy</code> - Returns the policy of the queue.</p> </li> <li> <p> <code>QueueArn</code> - Returns the Amazon resource name (ARN) of the queue.</p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - Returns the length of time, in seconds, for which the <code>ReceiveMessage</code> action waits for a message to arrive. </p> </li> <li> <p> <code>RedrivePolicy</code> - Returns the string that includes the parameters for dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> </li> <li> <p> <code>VisibilityTimeout</code> - Returns the visibility timeout for the queue. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - Returns the ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - Returns the length of time, in seconds, for which Amazon SQS can reuse a data key to encrypt or decrypt messages before calling AWS KMS again. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>FifoQueue</code> - Returns whether the queue is FIFO. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-understanding-logic">FIFO Queue Logic</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>To determine whether a queue is <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO</a>, you can check whether <code>QueueName</code> ends with the <code>.fifo</code> suffix.</p> </note> </li> <li> <p> <code>ContentBasedDeduplication</code> - Returns whether content-based deduplication is enabled for the queue. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </li> </ul></p>
     pub attribute_names: Option<Vec<String>>,
@@ -734,6 +766,8 @@ impl GetQueueAttributesRequestSerializer {
 
 /// <p>A list of returned queue attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueueAttributesResult {
     /// <p>A map of attributes to their respective values.</p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -766,6 +800,8 @@ impl GetQueueAttributesResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueueUrlRequest {
     /// <p>The name of the queue whose URL must be fetched. Maximum 80 characters. Valid values: alphanumeric characters, hyphens (<code>-</code>), and underscores (<code>_</code>).</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_name: String,
@@ -794,6 +830,8 @@ impl GetQueueUrlRequestSerializer {
 
 /// <p>For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-api-responses.html">Interpreting Responses</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetQueueUrlResult {
     /// <p>The URL of the queue.</p>
     pub queue_url: Option<String>,
@@ -819,6 +857,8 @@ impl GetQueueUrlResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeadLetterSourceQueuesRequest {
     /// <p>The URL of a dead-letter queue.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
@@ -839,6 +879,8 @@ impl ListDeadLetterSourceQueuesRequestSerializer {
 
 /// <p>A list of your dead letter source queues.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDeadLetterSourceQueuesResult {
     /// <p>A list of source queue URLs that have the <code>RedrivePolicy</code> queue attribute configured with a dead-letter queue.</p>
     pub queue_urls: Vec<String>,
@@ -868,6 +910,8 @@ impl ListDeadLetterSourceQueuesResultDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueueTagsRequest {
     /// <p>The URL of the queue.</p>
     pub queue_url: String,
@@ -887,6 +931,8 @@ impl ListQueueTagsRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueueTagsResult {
     /// <p>The list of all tags added to the specified queue.</p>
     pub tags: Option<::std::collections::HashMap<String, String>>,
@@ -912,6 +958,8 @@ impl ListQueueTagsResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueuesRequest {
     /// <p>A string to use for filtering the list results. Only those queues whose name begins with the specified string are returned.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_name_prefix: Option<String>,
@@ -934,6 +982,8 @@ impl ListQueuesRequestSerializer {
 
 /// <p>A list of your queues.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListQueuesResult {
     /// <p>A list of queue URLs, up to 1,000 entries.</p>
     pub queue_urls: Option<Vec<String>>,
@@ -961,6 +1011,8 @@ impl ListQueuesResultDeserializer {
 }
 /// <p>An Amazon SQS message.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Message {
     /// <p>A map of the attributes requested in <code> <a>ReceiveMessage</a> </code> to their respective values. Supported attributes:</p> <ul> <li> <p> <code>ApproximateReceiveCount</code> </p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> </p> </li> <li> <p> <code>MessageDeduplicationId</code> </p> </li> <li> <p> <code>MessageGroupId</code> </p> </li> <li> <p> <code>SenderId</code> </p> </li> <li> <p> <code>SentTimestamp</code> </p> </li> <li> <p> <code>SequenceNumber</code> </p> </li> </ul> <p> <code>ApproximateFirstReceiveTimestamp</code> and <code>SentTimestamp</code> are each returned as an integer representing the <a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds.</p>
     pub attributes: Option<::std::collections::HashMap<String, String>>,
@@ -1039,10 +1091,28 @@ impl MessageAttributeNameListSerializer {
 
 /// <p>The user-specified message attribute value. For string data types, the <code>Value</code> attribute has the same restrictions on the content as the message body. For more information, see <code> <a>SendMessage</a>.</code> </p> <p> <code>Name</code>, <code>type</code>, <code>value</code> and the message body must not be empty or null. All parts of the message attribute, including <code>Name</code>, <code>Type</code>, and <code>Value</code>, are part of the message size restriction (256 KB or 262,144 bytes).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MessageAttributeValue {
     /// <p>Not implemented. Reserved for future use.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlobList::deserialize_blob_list",
+            serialize_with = "::rusoto_core::serialization::SerdeBlobList::serialize_blob_list",
+            default
+        )
+    )]
     pub binary_list_values: Option<Vec<bytes::Bytes>>,
     /// <p>Binary type attributes can store any binary data, such as compressed data, encrypted data, or images.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+            default
+        )
+    )]
     pub binary_value: Option<bytes::Bytes>,
     /// <p>Amazon SQS supports the following logical data types: <code>String</code>, <code>Number</code>, and <code>Binary</code>. For the <code>Number</code> data type, you must use <code>StringValue</code>.</p> <p>You can also append custom labels. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-message-attributes.html">Amazon SQS Message Attributes</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
     pub data_type: String,
@@ -1244,10 +1314,28 @@ impl MessageSystemAttributeNameDeserializer {
 }
 /// <p>The user-specified message system attribute value. For string data types, the <code>Value</code> attribute has the same restrictions on the content as the message body. For more information, see <code> <a>SendMessage</a>.</code> </p> <p> <code>Name</code>, <code>type</code>, <code>value</code> and the message body must not be empty or null.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MessageSystemAttributeValue {
     /// <p>Not implemented. Reserved for future use.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlobList::deserialize_blob_list",
+            serialize_with = "::rusoto_core::serialization::SerdeBlobList::serialize_blob_list",
+            default
+        )
+    )]
     pub binary_list_values: Option<Vec<bytes::Bytes>>,
     /// <p>Binary type attributes can store any binary data, such as compressed data, encrypted data, or images.</p>
+    #[cfg_attr(
+        any(test, feature = "serialize_structs", feature = "deserialize_structs"),
+        serde(
+            deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+            serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+            default
+        )
+    )]
     pub binary_value: Option<bytes::Bytes>,
     /// <p>Amazon SQS supports the following logical data types: <code>String</code>, <code>Number</code>, and <code>Binary</code>. For the <code>Number</code> data type, you must use <code>StringValue</code>.</p> <p>You can also append custom labels. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-message-attributes.html">Amazon SQS Message Attributes</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
     pub data_type: String,
@@ -1295,6 +1383,8 @@ impl MessageSystemAttributeValueSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PurgeQueueRequest {
     /// <p>The URL of the queue from which the <code>PurgeQueue</code> action deletes messages.</p> <p>Queue URLs and names are case-sensitive.</p>
     pub queue_url: String,
@@ -1390,6 +1480,8 @@ impl QueueUrlListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiveMessageRequest {
     /// <p><p>A list of attributes that need to be returned along with each message. These attributes include:</p> <ul> <li> <p> <code>All</code> - Returns all values.</p> </li> <li> <p> <code>ApproximateFirstReceiveTimestamp</code> - Returns the time the message was first received from the queue (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds).</p> </li> <li> <p> <code>ApproximateReceiveCount</code> - Returns the number of times a message has been received from the queue but not deleted.</p> </li> <li> <p> <code>AWSTraceHeader</code> - Returns the AWS X-Ray trace header string. </p> </li> <li> <p> <code>SenderId</code> </p> <ul> <li> <p>For an IAM user, returns the IAM user ID, for example <code>ABCDEFGHI1JKLMNOPQ23R</code>.</p> </li> <li> <p>For an IAM role, returns the IAM role ID, for example <code>ABCDE1F2GH3I4JK5LMNOP:i-a123b456</code>.</p> </li> </ul> </li> <li> <p> <code>SentTimestamp</code> - Returns the time the message was sent to the queue (<a href="http://en.wikipedia.org/wiki/Unix_time">epoch time</a> in milliseconds).</p> </li> <li> <p> <code>MessageDeduplicationId</code> - Returns the value provided by the producer that calls the <code> <a>SendMessage</a> </code> action.</p> </li> <li> <p> <code>MessageGroupId</code> - Returns the value provided by the producer that calls the <code> <a>SendMessage</a> </code> action. Messages with the same <code>MessageGroupId</code> are returned in sequence.</p> </li> <li> <p> <code>SequenceNumber</code> - Returns the value provided by Amazon SQS.</p> </li> </ul></p>
     pub attribute_names: Option<Vec<String>>,
@@ -1454,6 +1546,8 @@ impl ReceiveMessageRequestSerializer {
 
 /// <p>A list of received messages.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ReceiveMessageResult {
     /// <p>A list of messages.</p>
     pub messages: Option<Vec<Message>>,
@@ -1481,6 +1575,8 @@ impl ReceiveMessageResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemovePermissionRequest {
     /// <p>The identification of the permission to remove. This is the label added using the <code> <a>AddPermission</a> </code> action.</p>
     pub label: String,
@@ -1504,6 +1600,8 @@ impl RemovePermissionRequestSerializer {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendMessageBatchRequest {
     /// <p>A list of <code> <a>SendMessageBatchRequestEntry</a> </code> items.</p>
     pub entries: Vec<SendMessageBatchRequestEntry>,
@@ -1531,6 +1629,8 @@ impl SendMessageBatchRequestSerializer {
 
 /// <p>Contains the details of a single Amazon SQS message along with an <code>Id</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendMessageBatchRequestEntry {
     /// <p><p>The length of time, in seconds, for which a specific message is delayed. Valid values: 0 to 900. Maximum: 15 minutes. Messages with a positive <code>DelaySeconds</code> value become available for processing after the delay period is finished. If you don&#39;t specify a value, the default value for the queue is applied. </p> <note> <p>When you set <code>FifoQueue</code>, you can&#39;t set <code>DelaySeconds</code> per message. You can set this parameter only on a queue level.</p> </note></p>
     pub delay_seconds: Option<i64>,
@@ -1602,6 +1702,8 @@ impl SendMessageBatchRequestEntryListSerializer {
 
 /// <p>For each message in the batch, the response contains a <code> <a>SendMessageBatchResultEntry</a> </code> tag if the message succeeds or a <code> <a>BatchResultErrorEntry</a> </code> tag if the message fails.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendMessageBatchResult {
     /// <p>A list of <code> <a>BatchResultErrorEntry</a> </code> items with error details about each message that can't be enqueued.</p>
     pub failed: Vec<BatchResultErrorEntry>,
@@ -1641,6 +1743,8 @@ impl SendMessageBatchResultDeserializer {
 }
 /// <p>Encloses a <code>MessageId</code> for a successfully-enqueued message in a <code> <a>SendMessageBatch</a>.</code> </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendMessageBatchResultEntry {
     /// <p>An identifier for the message in this batch.</p>
     pub id: String,
@@ -1731,6 +1835,8 @@ impl SendMessageBatchResultEntryListDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendMessageRequest {
     /// <p><p> The length of time, in seconds, for which to delay a specific message. Valid values: 0 to 900. Maximum: 15 minutes. Messages with a positive <code>DelaySeconds</code> value become available for processing after the delay period is finished. If you don&#39;t specify a value, the default value for the queue applies. </p> <note> <p>When you set <code>FifoQueue</code>, you can&#39;t set <code>DelaySeconds</code> per message. You can set this parameter only on a queue level.</p> </note></p>
     pub delay_seconds: Option<i64>,
@@ -1791,6 +1897,8 @@ impl SendMessageRequestSerializer {
 
 /// <p>The <code>MD5OfMessageBody</code> and <code>MessageId</code> elements.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendMessageResult {
     /// <p>An MD5 digest of the non-URL-encoded message attribute string. You can use this attribute to verify that Amazon SQS received the message correctly. Amazon SQS URL-decodes the message before creating the MD5 digest. For information about MD5, see <a href="https://www.ietf.org/rfc/rfc1321.txt">RFC1321</a>.</p>
     pub md5_of_message_attributes: Option<String>,
@@ -1844,6 +1952,8 @@ impl SendMessageResultDeserializer {
 }
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetQueueAttributesRequest {
     /// <p><p>A map of attributes to set.</p> <p>The following lists the names, descriptions, and values of the special request parameters that the <code>SetQueueAttributes</code> action uses:</p> <ul> <li> <p> <code>DelaySeconds</code> - The length of time, in seconds, for which the delivery of all messages in the queue is delayed. Valid values: An integer from 0 to 900 (15 minutes). Default: 0. </p> </li> <li> <p> <code>MaximumMessageSize</code> - The limit of how many bytes a message can contain before Amazon SQS rejects it. Valid values: An integer from 1,024 bytes (1 KiB) up to 262,144 bytes (256 KiB). Default: 262,144 (256 KiB). </p> </li> <li> <p> <code>MessageRetentionPeriod</code> - The length of time, in seconds, for which Amazon SQS retains a message. Valid values: An integer representing seconds, from 60 (1 minute) to 1,209,600 (14 days). Default: 345,600 (4 days). </p> </li> <li> <p> <code>Policy</code> - The queue&#39;s policy. A valid AWS policy. For more information about policy structure, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PoliciesOverview.html">Overview of AWS IAM Policies</a> in the <i>Amazon IAM User Guide</i>. </p> </li> <li> <p> <code>ReceiveMessageWaitTimeSeconds</code> - The length of time, in seconds, for which a <code> <a>ReceiveMessage</a> </code> action waits for a message to arrive. Valid values: an integer from 0 to 20 (seconds). Default: 0. </p> </li> <li> <p> <code>RedrivePolicy</code> - The string that includes the parameters for the dead-letter queue functionality of the source queue. For more information about the redrive policy and dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p> <code>deadLetterTargetArn</code> - The Amazon Resource Name (ARN) of the dead-letter queue to which Amazon SQS moves messages after the value of <code>maxReceiveCount</code> is exceeded.</p> </li> <li> <p> <code>maxReceiveCount</code> - The number of times a message is delivered to the source queue before being moved to the dead-letter queue. When the <code>ReceiveCount</code> for a message exceeds the <code>maxReceiveCount</code> for a queue, Amazon SQS moves the message to the dead-letter-queue.</p> </li> </ul> <note> <p>The dead-letter queue of a FIFO queue must also be a FIFO queue. Similarly, the dead-letter queue of a standard queue must also be a standard queue.</p> </note> </li> <li> <p> <code>VisibilityTimeout</code> - The visibility timeout for the queue, in seconds. Valid values: an integer from 0 to 43,200 (12 hours). Default: 30. For more information about the visibility timeout, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> </ul> <p>The following attributes apply only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html">server-side-encryption</a>:</p> <ul> <li> <p> <code>KmsMasterKeyId</code> - The ID of an AWS-managed customer master key (CMK) for Amazon SQS or a custom CMK. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-sse-key-terms">Key Terms</a>. While the alias of the AWS-managed CMK for Amazon SQS is always <code>alias/aws/sqs</code>, the alias of a custom CMK can, for example, be <code>alias/<i>MyAlias</i> </code>. For more examples, see <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_DescribeKey.html#API_DescribeKey_RequestParameters">KeyId</a> in the <i>AWS Key Management Service API Reference</i>. </p> </li> <li> <p> <code>KmsDataKeyReusePeriodSeconds</code> - The length of time, in seconds, for which Amazon SQS can reuse a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data key</a> to encrypt or decrypt messages before calling AWS KMS again. An integer representing seconds, between 60 seconds (1 minute) and 86,400 seconds (24 hours). Default: 300 (5 minutes). A shorter time period provides better security but results in more calls to KMS which might incur charges after Free Tier. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-server-side-encryption.html#sqs-how-does-the-data-key-reuse-period-work">How Does the Data Key Reuse Period Work?</a>. </p> </li> </ul> <p>The following attribute applies only to <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO (first-in-first-out) queues</a>:</p> <ul> <li> <p> <code>ContentBasedDeduplication</code> - Enables content-based deduplication. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-exactly-once-processing">Exactly-Once Processing</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <ul> <li> <p>Every message must have a unique <code>MessageDeduplicationId</code>,</p> <ul> <li> <p>You may provide a <code>MessageDeduplicationId</code> explicitly.</p> </li> <li> <p>If you aren&#39;t able to provide a <code>MessageDeduplicationId</code> and you enable <code>ContentBasedDeduplication</code> for your queue, Amazon SQS uses a SHA-256 hash to generate the <code>MessageDeduplicationId</code> using the body of the message (but not the attributes of the message). </p> </li> <li> <p>If you don&#39;t provide a <code>MessageDeduplicationId</code> and the queue doesn&#39;t have <code>ContentBasedDeduplication</code> set, the action fails with an error.</p> </li> <li> <p>If the queue has <code>ContentBasedDeduplication</code> set, your <code>MessageDeduplicationId</code> overrides the generated one.</p> </li> </ul> </li> <li> <p>When <code>ContentBasedDeduplication</code> is in effect, messages with identical content sent within the deduplication interval are treated as duplicates and only one copy of the message is delivered.</p> </li> <li> <p>If you send one message with <code>ContentBasedDeduplication</code> enabled and then another message with a <code>MessageDeduplicationId</code> that is the same as the one generated for the first <code>MessageDeduplicationId</code>, the two messages are treated as duplicates and only one copy of the message is delivered. </p> </li> </ul> </li> </ul></p>
     pub attributes: ::std::collections::HashMap<String, String>,
@@ -1970,6 +2080,8 @@ impl TagMapSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagQueueRequest {
     /// <p>The URL of the queue.</p>
     pub queue_url: String,
@@ -2003,6 +2115,8 @@ impl TagValueDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagQueueRequest {
     /// <p>The URL of the queue.</p>
     pub queue_url: String,
@@ -3156,6 +3270,319 @@ pub trait Sqs {
     /// <p><p>Remove cost allocation tags from the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError>;
 }
+const _: Option<&dyn Sqs> = None;
+impl<'a, T: Sqs + ?Sized> Sqs for &'a T {
+    fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
+        (**self).change_message_visibility(input)
+    }
+    fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
+        (**self).change_message_visibility_batch(input)
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
+        (**self).create_queue(input)
+    }
+    fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
+        (**self).delete_message(input)
+    }
+    fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
+        (**self).delete_message_batch(input)
+    }
+    fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
+        (**self).get_queue_attributes(input)
+    }
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
+        (**self).get_queue_url(input)
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
+        (**self).list_dead_letter_source_queues(input)
+    }
+    fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
+        (**self).list_queue_tags(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
+        (**self).purge_queue(input)
+    }
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
+        (**self).receive_message(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> RusotoFuture<SendMessageResult, SendMessageError> {
+        (**self).send_message(input)
+    }
+    fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
+        (**self).send_message_batch(input)
+    }
+    fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> RusotoFuture<(), SetQueueAttributesError> {
+        (**self).set_queue_attributes(input)
+    }
+    fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
+        (**self).tag_queue(input)
+    }
+    fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
+        (**self).untag_queue(input)
+    }
+}
+impl<T: Sqs + ?Sized> Sqs for Box<T> {
+    fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
+        (**self).change_message_visibility(input)
+    }
+    fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
+        (**self).change_message_visibility_batch(input)
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
+        (**self).create_queue(input)
+    }
+    fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
+        (**self).delete_message(input)
+    }
+    fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
+        (**self).delete_message_batch(input)
+    }
+    fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
+        (**self).get_queue_attributes(input)
+    }
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
+        (**self).get_queue_url(input)
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
+        (**self).list_dead_letter_source_queues(input)
+    }
+    fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
+        (**self).list_queue_tags(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
+        (**self).purge_queue(input)
+    }
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
+        (**self).receive_message(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> RusotoFuture<SendMessageResult, SendMessageError> {
+        (**self).send_message(input)
+    }
+    fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
+        (**self).send_message_batch(input)
+    }
+    fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> RusotoFuture<(), SetQueueAttributesError> {
+        (**self).set_queue_attributes(input)
+    }
+    fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
+        (**self).tag_queue(input)
+    }
+    fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
+        (**self).untag_queue(input)
+    }
+}
+impl<T: Sqs + ?Sized> Sqs for ::std::sync::Arc<T> {
+    fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
+        (**self).change_message_visibility(input)
+    }
+    fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
+        (**self).change_message_visibility_batch(input)
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
+        (**self).create_queue(input)
+    }
+    fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
+        (**self).delete_message(input)
+    }
+    fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
+        (**self).delete_message_batch(input)
+    }
+    fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
+        (**self).delete_queue(input)
+    }
+    fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
+        (**self).get_queue_attributes(input)
+    }
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
+        (**self).get_queue_url(input)
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
+        (**self).list_dead_letter_source_queues(input)
+    }
+    fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
+        (**self).list_queue_tags(input)
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
+        (**self).list_queues(input)
+    }
+    fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
+        (**self).purge_queue(input)
+    }
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
+        (**self).receive_message(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> RusotoFuture<SendMessageResult, SendMessageError> {
+        (**self).send_message(input)
+    }
+    fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
+        (**self).send_message_batch(input)
+    }
+    fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> RusotoFuture<(), SetQueueAttributesError> {
+        (**self).set_queue_attributes(input)
+    }
+    fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
+        (**self).tag_queue(input)
+    }
+    fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
+        (**self).untag_queue(input)
+    }
+}
 /// A client for the Amazon SQS API.
 #[derive(Clone)]
 pub struct SqsClient {
@@ -3197,6 +3624,7 @@ impl Sqs for SqsClient {
     /// <p><p>Adds a permission to a queue for a specific <a href="https://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P">principal</a>. This allows sharing access to the queue.</p> <p>When you create a queue, you have full control access rights for the queue. Only you, the owner of the queue, can grant or deny permissions to the queue. For more information about these permissions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <ul> <li> <p> <code>AddPermission</code> generates a policy for you. You can use <code> <a>SetQueueAttributes</a> </code> to upload your policy. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-creating-custom-policies.html">Using Custom Policies with the Amazon SQS Access Policy Language</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>An Amazon SQS policy can have a maximum of 7 actions.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;Attribute.1=first</code> </p> <p> <code>&amp;Attribute.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("AddPermission");
         let mut params = Params::new();
 
         params.put("Action", "AddPermission");
@@ -3225,6 +3653,7 @@ impl Sqs for SqsClient {
         input: ChangeMessageVisibilityRequest,
     ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibility");
         let mut params = Params::new();
 
         params.put("Action", "ChangeMessageVisibility");
@@ -3250,6 +3679,7 @@ impl Sqs for SqsClient {
         input: ChangeMessageVisibilityBatchRequest,
     ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ChangeMessageVisibilityBatch");
         let mut params = Params::new();
 
         params.put("Action", "ChangeMessageVisibilityBatch");
@@ -3271,6 +3701,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = ChangeMessageVisibilityBatchResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3282,7 +3713,10 @@ impl Sqs for SqsClient {
                     result = ChangeMessageVisibilityBatchResultDeserializer::deserialize(
                         "ChangeMessageVisibilityBatchResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ChangeMessageVisibilityBatch", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3298,6 +3732,7 @@ impl Sqs for SqsClient {
         input: CreateQueueRequest,
     ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("CreateQueue");
         let mut params = Params::new();
 
         params.put("Action", "CreateQueue");
@@ -3322,6 +3757,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = CreateQueueResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3330,10 +3766,9 @@ impl Sqs for SqsClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = CreateQueueResultDeserializer::deserialize(
-                        "CreateQueueResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        CreateQueueResultDeserializer::deserialize("CreateQueueResult", &mut stack)
+                            .map_err(|err| stack.parse_error("CreateQueue", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3346,6 +3781,7 @@ impl Sqs for SqsClient {
     /// <p><p>Deletes the specified message from the specified queue. To select the message to delete, use the <code>ReceiptHandle</code> of the message (<i>not</i> the <code>MessageId</code> which you receive when you send the message). Amazon SQS can delete a message from a queue even if a visibility timeout setting causes the message to be locked by another consumer. Amazon SQS automatically deletes messages left in a queue longer than the retention period configured for the queue. </p> <note> <p>The <code>ReceiptHandle</code> is associated with a <i>specific instance</i> of receiving a message. If you receive a message more than once, the <code>ReceiptHandle</code> is different each time you receive a message. When you use the <code>DeleteMessage</code> action, you must provide the most recently received <code>ReceiptHandle</code> for the message (otherwise, the request succeeds, but the message might not be deleted).</p> <p>For standard queues, it is possible to receive a message even after you delete it. This might happen on rare occasions if one of the servers which stores a copy of the message is unavailable when you send the request to delete the message. The copy remains on the server and might be returned to you during a subsequent receive request. You should ensure that your application is idempotent, so that receiving a message more than once does not cause issues.</p> </note></p>
     fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessage");
         let mut params = Params::new();
 
         params.put("Action", "DeleteMessage");
@@ -3374,6 +3810,7 @@ impl Sqs for SqsClient {
         input: DeleteMessageBatchRequest,
     ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteMessageBatch");
         let mut params = Params::new();
 
         params.put("Action", "DeleteMessageBatch");
@@ -3398,6 +3835,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = DeleteMessageBatchResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3409,7 +3847,8 @@ impl Sqs for SqsClient {
                     result = DeleteMessageBatchResultDeserializer::deserialize(
                         "DeleteMessageBatchResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("DeleteMessageBatch", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3422,6 +3861,7 @@ impl Sqs for SqsClient {
     /// <p><p>Deletes the queue specified by the <code>QueueUrl</code>, regardless of the queue&#39;s contents. If the specified queue doesn&#39;t exist, Amazon SQS returns a successful response.</p> <important> <p>Be careful with the <code>DeleteQueue</code> action: When you delete a queue, any messages in the queue are no longer available. </p> </important> <p>When you delete a queue, the deletion process takes up to 60 seconds. Requests you send involving that queue during the 60 seconds might succeed. For example, a <code> <a>SendMessage</a> </code> request might succeed, but after 60 seconds the queue and the message you sent no longer exist.</p> <p>When you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("DeleteQueue");
         let mut params = Params::new();
 
         params.put("Action", "DeleteQueue");
@@ -3450,6 +3890,7 @@ impl Sqs for SqsClient {
         input: GetQueueAttributesRequest,
     ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueAttributes");
         let mut params = Params::new();
 
         params.put("Action", "GetQueueAttributes");
@@ -3474,6 +3915,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = GetQueueAttributesResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3485,7 +3927,8 @@ impl Sqs for SqsClient {
                     result = GetQueueAttributesResultDeserializer::deserialize(
                         "GetQueueAttributesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetQueueAttributes", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3501,6 +3944,7 @@ impl Sqs for SqsClient {
         input: GetQueueUrlRequest,
     ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("GetQueueUrl");
         let mut params = Params::new();
 
         params.put("Action", "GetQueueUrl");
@@ -3525,6 +3969,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = GetQueueUrlResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3533,10 +3978,9 @@ impl Sqs for SqsClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = GetQueueUrlResultDeserializer::deserialize(
-                        "GetQueueUrlResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        GetQueueUrlResultDeserializer::deserialize("GetQueueUrlResult", &mut stack)
+                            .map_err(|err| stack.parse_error("GetQueueUrl", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3552,6 +3996,7 @@ impl Sqs for SqsClient {
         input: ListDeadLetterSourceQueuesRequest,
     ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListDeadLetterSourceQueues");
         let mut params = Params::new();
 
         params.put("Action", "ListDeadLetterSourceQueues");
@@ -3573,6 +4018,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = ListDeadLetterSourceQueuesResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3584,7 +4030,10 @@ impl Sqs for SqsClient {
                     result = ListDeadLetterSourceQueuesResultDeserializer::deserialize(
                         "ListDeadLetterSourceQueuesResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("ListDeadLetterSourceQueues", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3600,6 +4049,7 @@ impl Sqs for SqsClient {
         input: ListQueueTagsRequest,
     ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueueTags");
         let mut params = Params::new();
 
         params.put("Action", "ListQueueTags");
@@ -3624,6 +4074,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = ListQueueTagsResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3635,7 +4086,8 @@ impl Sqs for SqsClient {
                     result = ListQueueTagsResultDeserializer::deserialize(
                         "ListQueueTagsResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ListQueueTags", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3651,6 +4103,7 @@ impl Sqs for SqsClient {
         input: ListQueuesRequest,
     ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ListQueues");
         let mut params = Params::new();
 
         params.put("Action", "ListQueues");
@@ -3675,6 +4128,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = ListQueuesResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3684,7 +4138,8 @@ impl Sqs for SqsClient {
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
                     result =
-                        ListQueuesResultDeserializer::deserialize("ListQueuesResult", &mut stack)?;
+                        ListQueuesResultDeserializer::deserialize("ListQueuesResult", &mut stack)
+                            .map_err(|err| stack.parse_error("ListQueues", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3697,6 +4152,7 @@ impl Sqs for SqsClient {
     /// <p>Deletes the messages in a queue specified by the <code>QueueURL</code> parameter.</p> <important> <p>When you use the <code>PurgeQueue</code> action, you can't retrieve any messages deleted from a queue.</p> <p>The message deletion process takes up to 60 seconds. We recommend waiting for 60 seconds regardless of your queue's size. </p> </important> <p>Messages sent to the queue <i>before</i> you call <code>PurgeQueue</code> might be received but are deleted within the next minute.</p> <p>Messages sent to the queue <i>after</i> you call <code>PurgeQueue</code> might be deleted while the queue is being purged.</p>
     fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("PurgeQueue");
         let mut params = Params::new();
 
         params.put("Action", "PurgeQueue");
@@ -3725,6 +4181,7 @@ impl Sqs for SqsClient {
         input: ReceiveMessageRequest,
     ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("ReceiveMessage");
         let mut params = Params::new();
 
         params.put("Action", "ReceiveMessage");
@@ -3749,6 +4206,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = ReceiveMessageResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3760,7 +4218,8 @@ impl Sqs for SqsClient {
                     result = ReceiveMessageResultDeserializer::deserialize(
                         "ReceiveMessageResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("ReceiveMessage", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3776,6 +4235,7 @@ impl Sqs for SqsClient {
         input: RemovePermissionRequest,
     ) -> RusotoFuture<(), RemovePermissionError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("RemovePermission");
         let mut params = Params::new();
 
         params.put("Action", "RemovePermission");
@@ -3804,6 +4264,7 @@ impl Sqs for SqsClient {
         input: SendMessageRequest,
     ) -> RusotoFuture<SendMessageResult, SendMessageError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessage");
         let mut params = Params::new();
 
         params.put("Action", "SendMessage");
@@ -3828,6 +4289,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = SendMessageResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3836,10 +4298,9 @@ impl Sqs for SqsClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = SendMessageResultDeserializer::deserialize(
-                        "SendMessageResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        SendMessageResultDeserializer::deserialize("SendMessageResult", &mut stack)
+                            .map_err(|err| stack.parse_error("SendMessage", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3855,6 +4316,7 @@ impl Sqs for SqsClient {
         input: SendMessageBatchRequest,
     ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SendMessageBatch");
         let mut params = Params::new();
 
         params.put("Action", "SendMessageBatch");
@@ -3879,6 +4341,7 @@ impl Sqs for SqsClient {
                 if response.body.is_empty() {
                     result = SendMessageBatchResult::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -3890,7 +4353,8 @@ impl Sqs for SqsClient {
                     result = SendMessageBatchResultDeserializer::deserialize(
                         "SendMessageBatchResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("SendMessageBatch", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -3906,6 +4370,7 @@ impl Sqs for SqsClient {
         input: SetQueueAttributesRequest,
     ) -> RusotoFuture<(), SetQueueAttributesError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("SetQueueAttributes");
         let mut params = Params::new();
 
         params.put("Action", "SetQueueAttributes");
@@ -3931,6 +4396,7 @@ impl Sqs for SqsClient {
     /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("TagQueue");
         let mut params = Params::new();
 
         params.put("Action", "TagQueue");
@@ -3956,6 +4422,7 @@ impl Sqs for SqsClient {
     /// <p><p>Remove cost allocation tags from the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation("UntagQueue");
         let mut params = Params::new();
 
         params.put("Action", "UntagQueue");
@@ -3979,6 +4446,642 @@ impl Sqs for SqsClient {
     }
 }
 
+/// A mock of the Amazon SQS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSqs {
+    add_permission: Option<
+        Box<
+            dyn Fn(
+                    AddPermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<AddPermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    change_message_visibility: Option<
+        Box<
+            dyn Fn(
+                    ChangeMessageVisibilityRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<ChangeMessageVisibilityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    change_message_visibility_batch: Option<
+        Box<
+            dyn Fn(
+                    ChangeMessageVisibilityBatchRequest,
+                ) -> ::std::result::Result<
+                    ChangeMessageVisibilityBatchResult,
+                    RusotoError<ChangeMessageVisibilityBatchError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_queue: Option<
+        Box<
+            dyn Fn(
+                    CreateQueueRequest,
+                )
+                    -> ::std::result::Result<CreateQueueResult, RusotoError<CreateQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_message: Option<
+        Box<
+            dyn Fn(
+                    DeleteMessageRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteMessageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_message_batch: Option<
+        Box<
+            dyn Fn(
+                    DeleteMessageBatchRequest,
+                ) -> ::std::result::Result<
+                    DeleteMessageBatchResult,
+                    RusotoError<DeleteMessageBatchError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_queue: Option<
+        Box<
+            dyn Fn(DeleteQueueRequest) -> ::std::result::Result<(), RusotoError<DeleteQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_queue_attributes: Option<
+        Box<
+            dyn Fn(
+                    GetQueueAttributesRequest,
+                ) -> ::std::result::Result<
+                    GetQueueAttributesResult,
+                    RusotoError<GetQueueAttributesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_queue_url: Option<
+        Box<
+            dyn Fn(
+                    GetQueueUrlRequest,
+                )
+                    -> ::std::result::Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_dead_letter_source_queues: Option<
+        Box<
+            dyn Fn(
+                    ListDeadLetterSourceQueuesRequest,
+                ) -> ::std::result::Result<
+                    ListDeadLetterSourceQueuesResult,
+                    RusotoError<ListDeadLetterSourceQueuesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_queue_tags: Option<
+        Box<
+            dyn Fn(
+                    ListQueueTagsRequest,
+                )
+                    -> ::std::result::Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_queues: Option<
+        Box<
+            dyn Fn(
+                    ListQueuesRequest,
+                )
+                    -> ::std::result::Result<ListQueuesResult, RusotoError<ListQueuesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    purge_queue: Option<
+        Box<
+            dyn Fn(PurgeQueueRequest) -> ::std::result::Result<(), RusotoError<PurgeQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    receive_message: Option<
+        Box<
+            dyn Fn(
+                    ReceiveMessageRequest,
+                )
+                    -> ::std::result::Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_permission: Option<
+        Box<
+            dyn Fn(
+                    RemovePermissionRequest,
+                ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_message: Option<
+        Box<
+            dyn Fn(
+                    SendMessageRequest,
+                )
+                    -> ::std::result::Result<SendMessageResult, RusotoError<SendMessageError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_message_batch: Option<
+        Box<
+            dyn Fn(
+                    SendMessageBatchRequest,
+                ) -> ::std::result::Result<
+                    SendMessageBatchResult,
+                    RusotoError<SendMessageBatchError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_queue_attributes: Option<
+        Box<
+            dyn Fn(
+                    SetQueueAttributesRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<SetQueueAttributesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_queue: Option<
+        Box<
+            dyn Fn(TagQueueRequest) -> ::std::result::Result<(), RusotoError<TagQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_queue: Option<
+        Box<
+            dyn Fn(UntagQueueRequest) -> ::std::result::Result<(), RusotoError<UntagQueueError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSqs {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSqs {
+        Default::default()
+    }
+
+    /// Answers `add_permission` with what `handler` returns for its input.
+    pub fn with_add_permission<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(AddPermissionRequest) -> ::std::result::Result<(), RusotoError<AddPermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `change_message_visibility` with what `handler` returns for its input.
+    pub fn with_change_message_visibility<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                ChangeMessageVisibilityRequest,
+            ) -> ::std::result::Result<(), RusotoError<ChangeMessageVisibilityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.change_message_visibility = Some(Box::new(handler));
+        self
+    }
+    /// Answers `change_message_visibility_batch` with what `handler` returns for its input.
+    pub fn with_change_message_visibility_batch<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                ChangeMessageVisibilityBatchRequest,
+            ) -> ::std::result::Result<
+                ChangeMessageVisibilityBatchResult,
+                RusotoError<ChangeMessageVisibilityBatchError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.change_message_visibility_batch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_queue` with what `handler` returns for its input.
+    pub fn with_create_queue<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                CreateQueueRequest,
+            )
+                -> ::std::result::Result<CreateQueueResult, RusotoError<CreateQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_message` with what `handler` returns for its input.
+    pub fn with_delete_message<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(DeleteMessageRequest) -> ::std::result::Result<(), RusotoError<DeleteMessageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_message = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_message_batch` with what `handler` returns for its input.
+    pub fn with_delete_message_batch<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                DeleteMessageBatchRequest,
+            ) -> ::std::result::Result<
+                DeleteMessageBatchResult,
+                RusotoError<DeleteMessageBatchError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_message_batch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_queue` with what `handler` returns for its input.
+    pub fn with_delete_queue<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(DeleteQueueRequest) -> ::std::result::Result<(), RusotoError<DeleteQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_queue_attributes` with what `handler` returns for its input.
+    pub fn with_get_queue_attributes<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                GetQueueAttributesRequest,
+            ) -> ::std::result::Result<
+                GetQueueAttributesResult,
+                RusotoError<GetQueueAttributesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_queue_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_queue_url` with what `handler` returns for its input.
+    pub fn with_get_queue_url<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                GetQueueUrlRequest,
+            )
+                -> ::std::result::Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_queue_url = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_dead_letter_source_queues` with what `handler` returns for its input.
+    pub fn with_list_dead_letter_source_queues<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                ListDeadLetterSourceQueuesRequest,
+            ) -> ::std::result::Result<
+                ListDeadLetterSourceQueuesResult,
+                RusotoError<ListDeadLetterSourceQueuesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_dead_letter_source_queues = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_queue_tags` with what `handler` returns for its input.
+    pub fn with_list_queue_tags<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                ListQueueTagsRequest,
+            )
+                -> ::std::result::Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_queue_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_queues` with what `handler` returns for its input.
+    pub fn with_list_queues<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                ListQueuesRequest,
+            ) -> ::std::result::Result<ListQueuesResult, RusotoError<ListQueuesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_queues = Some(Box::new(handler));
+        self
+    }
+    /// Answers `purge_queue` with what `handler` returns for its input.
+    pub fn with_purge_queue<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(PurgeQueueRequest) -> ::std::result::Result<(), RusotoError<PurgeQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.purge_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `receive_message` with what `handler` returns for its input.
+    pub fn with_receive_message<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                ReceiveMessageRequest,
+            )
+                -> ::std::result::Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.receive_message = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_permission` with what `handler` returns for its input.
+    pub fn with_remove_permission<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                RemovePermissionRequest,
+            ) -> ::std::result::Result<(), RusotoError<RemovePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_message` with what `handler` returns for its input.
+    pub fn with_send_message<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                SendMessageRequest,
+            )
+                -> ::std::result::Result<SendMessageResult, RusotoError<SendMessageError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_message = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_message_batch` with what `handler` returns for its input.
+    pub fn with_send_message_batch<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                SendMessageBatchRequest,
+            )
+                -> ::std::result::Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_message_batch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_queue_attributes` with what `handler` returns for its input.
+    pub fn with_set_queue_attributes<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(
+                SetQueueAttributesRequest,
+            ) -> ::std::result::Result<(), RusotoError<SetQueueAttributesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.set_queue_attributes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_queue` with what `handler` returns for its input.
+    pub fn with_tag_queue<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(TagQueueRequest) -> ::std::result::Result<(), RusotoError<TagQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_queue = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_queue` with what `handler` returns for its input.
+    pub fn with_untag_queue<F>(mut self, handler: F) -> MockSqs
+    where
+        F: Fn(UntagQueueRequest) -> ::std::result::Result<(), RusotoError<UntagQueueError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_queue = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Sqs for MockSqs {
+    fn add_permission(&self, input: AddPermissionRequest) -> RusotoFuture<(), AddPermissionError> {
+        match self.add_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for add_permission"),
+        }
+    }
+    fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> RusotoFuture<(), ChangeMessageVisibilityError> {
+        match self.change_message_visibility {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for change_message_visibility"),
+        }
+    }
+    fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> RusotoFuture<ChangeMessageVisibilityBatchResult, ChangeMessageVisibilityBatchError> {
+        match self.change_message_visibility_batch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for change_message_visibility_batch"),
+        }
+    }
+    fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> RusotoFuture<CreateQueueResult, CreateQueueError> {
+        match self.create_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for create_queue"),
+        }
+    }
+    fn delete_message(&self, input: DeleteMessageRequest) -> RusotoFuture<(), DeleteMessageError> {
+        match self.delete_message {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for delete_message"),
+        }
+    }
+    fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> RusotoFuture<DeleteMessageBatchResult, DeleteMessageBatchError> {
+        match self.delete_message_batch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for delete_message_batch"),
+        }
+    }
+    fn delete_queue(&self, input: DeleteQueueRequest) -> RusotoFuture<(), DeleteQueueError> {
+        match self.delete_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for delete_queue"),
+        }
+    }
+    fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> RusotoFuture<GetQueueAttributesResult, GetQueueAttributesError> {
+        match self.get_queue_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for get_queue_attributes"),
+        }
+    }
+    fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> RusotoFuture<GetQueueUrlResult, GetQueueUrlError> {
+        match self.get_queue_url {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for get_queue_url"),
+        }
+    }
+    fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> RusotoFuture<ListDeadLetterSourceQueuesResult, ListDeadLetterSourceQueuesError> {
+        match self.list_dead_letter_source_queues {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for list_dead_letter_source_queues"),
+        }
+    }
+    fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> RusotoFuture<ListQueueTagsResult, ListQueueTagsError> {
+        match self.list_queue_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for list_queue_tags"),
+        }
+    }
+    fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> RusotoFuture<ListQueuesResult, ListQueuesError> {
+        match self.list_queues {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for list_queues"),
+        }
+    }
+    fn purge_queue(&self, input: PurgeQueueRequest) -> RusotoFuture<(), PurgeQueueError> {
+        match self.purge_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for purge_queue"),
+        }
+    }
+    fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> RusotoFuture<ReceiveMessageResult, ReceiveMessageError> {
+        match self.receive_message {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for receive_message"),
+        }
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        match self.remove_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for remove_permission"),
+        }
+    }
+    fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> RusotoFuture<SendMessageResult, SendMessageError> {
+        match self.send_message {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for send_message"),
+        }
+    }
+    fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> RusotoFuture<SendMessageBatchResult, SendMessageBatchError> {
+        match self.send_message_batch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for send_message_batch"),
+        }
+    }
+    fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> RusotoFuture<(), SetQueueAttributesError> {
+        match self.set_queue_attributes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for set_queue_attributes"),
+        }
+    }
+    fn tag_queue(&self, input: TagQueueRequest) -> RusotoFuture<(), TagQueueError> {
+        match self.tag_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for tag_queue"),
+        }
+    }
+    fn untag_queue(&self, input: UntagQueueRequest) -> RusotoFuture<(), UntagQueueError> {
+        match self.untag_queue {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSqs has no handler for untag_queue"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
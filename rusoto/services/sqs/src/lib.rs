@@ -19,7 +19,11 @@
 extern crate bytes;
 extern crate futures;
 extern crate rusoto_core;
+extern crate serde;
+#[cfg_attr(any(test, feature = "serialize_structs", feature = "deserialize_structs"), macro_use)]
+extern crate serde_derive;
 extern crate serde_urlencoded;
+extern crate sha2;
 extern crate xml;
 
 mod generated;
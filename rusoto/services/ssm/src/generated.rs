@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An activation registers one or more on-premises servers or virtual machines (VMs) with AWS so that you can configure those servers or VMs using Run Command. A server or VM that has been registered with AWS is called a managed instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Activation {
     /// <p>The ID created by Systems Manager when you submitted the activation.</p>
     #[serde(rename = "ActivationId")]
@@ -70,6 +71,7 @@ pub struct Activation {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceRequest {
     /// <p><p>The resource ID you want to tag.</p> <p>Use the ID of the resource. Here are some examples:</p> <p>ManagedInstance: mi-012345abcde</p> <p>MaintenanceWindow: mw-012345abcde</p> <p>PatchBaseline: pb-012345abcde</p> <p>For the Document and Parameter values, use the name of the resource.</p> <note> <p>The ManagedInstance type for this API action is only for on-premises managed instances. You must specify the name of the managed instance in the following format: mi-ID_number. For example, mi-1a2b3c4d5e6f.</p> </note></p>
     #[serde(rename = "ResourceId")]
@@ -82,13 +84,23 @@ pub struct AddTagsToResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsToResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), None, Some(1000));
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsToResourceResult {}
 
 /// <p>Describes an association of a Systems Manager document and an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Association {
     /// <p>The ID created by the system when you create an association. An association is a binding between a document and a set of targets with a schedule.</p>
     #[serde(rename = "AssociationId")]
@@ -134,7 +146,7 @@ pub struct Association {
 
 /// <p>Describes the parameters for a document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociationDescription {
     /// <p>The association ID.</p>
     #[serde(rename = "AssociationId")]
@@ -220,7 +232,7 @@ pub struct AssociationDescription {
 
 /// <p>Includes information about the specified association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociationExecution {
     /// <p>The association ID.</p>
     #[serde(rename = "AssociationId")]
@@ -258,6 +270,7 @@ pub struct AssociationExecution {
 
 /// <p>Filters used in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociationExecutionFilter {
     /// <p>The key value used in the request.</p>
     #[serde(rename = "Key")]
@@ -270,9 +283,20 @@ pub struct AssociationExecutionFilter {
     pub value: String,
 }
 
+impl Validate for AssociationExecutionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Includes information about the specified association execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociationExecutionTarget {
     /// <p>The association ID.</p>
     #[serde(rename = "AssociationId")]
@@ -314,6 +338,7 @@ pub struct AssociationExecutionTarget {
 
 /// <p>Filters for the association execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociationExecutionTargetsFilter {
     /// <p>The key value used in the request.</p>
     #[serde(rename = "Key")]
@@ -323,8 +348,20 @@ pub struct AssociationExecutionTargetsFilter {
     pub value: String,
 }
 
+impl Validate for AssociationExecutionTargetsFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Describes a filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociationFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
@@ -334,9 +371,20 @@ pub struct AssociationFilter {
     pub value: String,
 }
 
+impl Validate for AssociationFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Information about the association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociationOverview {
     /// <p>Returns the number of targets for the association status. For example, if you created an association with two instances, and one of them was successful, this would return the count of instances by status.</p>
     #[serde(rename = "AssociationStatusAggregatedCount")]
@@ -370,9 +418,28 @@ pub struct AssociationStatus {
     pub name: String,
 }
 
+impl Validate for AssociationStatus {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.additional_info {
+            errors.check_length(
+                &field_path(path, "additional_info"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "message"),
+            self.message.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Information about the association version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociationVersionInfo {
     /// <p>The ID created by the system when the association was created.</p>
     #[serde(rename = "AssociationId")]
@@ -430,7 +497,7 @@ pub struct AssociationVersionInfo {
 
 /// <p>A structure that includes attributes that describe a document attachment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachmentContent {
     /// <p>The cryptographic hash value of the document content.</p>
     #[serde(rename = "Hash")]
@@ -456,7 +523,7 @@ pub struct AttachmentContent {
 
 /// <p>An attribute of an attachment, such as the attachment name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachmentInformation {
     /// <p>The name of the attachment.</p>
     #[serde(rename = "Name")]
@@ -466,6 +533,7 @@ pub struct AttachmentInformation {
 
 /// <p>A key and value pair that identifies the location of an attachment to a document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachmentsSource {
     /// <p>The key of a key and value pair that identifies the location of an attachment to a document.</p>
     #[serde(rename = "Key")]
@@ -477,9 +545,21 @@ pub struct AttachmentsSource {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for AttachmentsSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), Some(1), Some(1));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+    }
+}
+
 /// <p>Detailed information about the current state of an individual Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutomationExecution {
     /// <p>The execution ID.</p>
     #[serde(rename = "AutomationExecutionId")]
@@ -585,6 +665,7 @@ pub struct AutomationExecution {
 
 /// <p>A filter used to match specific automation executions. This is used to limit the scope of Automation execution information returned.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AutomationExecutionFilter {
     /// <p>One or more keys to limit the results. Valid filter keys include the following: DocumentNamePrefix, ExecutionStatus, ExecutionId, ParentExecutionId, CurrentAction, StartTimeBefore, StartTimeAfter.</p>
     #[serde(rename = "Key")]
@@ -594,9 +675,24 @@ pub struct AutomationExecutionFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for AutomationExecutionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(150));
+        }
+    }
+}
+
 /// <p>Details about a specific Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AutomationExecutionMetadata {
     /// <p>The execution ID.</p>
     #[serde(rename = "AutomationExecutionId")]
@@ -690,6 +786,7 @@ pub struct AutomationExecutionMetadata {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelCommandRequest {
     /// <p>The ID of the command you want to cancel.</p>
     #[serde(rename = "CommandId")]
@@ -700,20 +797,60 @@ pub struct CancelCommandRequest {
     pub instance_ids: Option<Vec<String>>,
 }
 
+impl Validate for CancelCommandRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "command_id"),
+            self.command_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        if let Some(ref value) = self.instance_ids {
+            errors.check_length(
+                &field_path(path, "instance_ids"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_pattern(&item_path, &item, "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)");
+            }
+        }
+    }
+}
+
 /// <p>Whether or not the command was successfully canceled. There is no guarantee that a request can be canceled.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelCommandResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelMaintenanceWindowExecutionRequest {
     /// <p>The ID of the maintenance window execution to stop.</p>
     #[serde(rename = "WindowExecutionId")]
     pub window_execution_id: String,
 }
 
+impl Validate for CancelMaintenanceWindowExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_execution_id"),
+            self.window_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_execution_id"),
+            &self.window_execution_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelMaintenanceWindowExecutionResult {
     /// <p>The ID of the maintenance window execution that has been stopped.</p>
     #[serde(rename = "WindowExecutionId")]
@@ -734,9 +871,22 @@ pub struct CloudWatchOutputConfig {
     pub cloud_watch_output_enabled: Option<bool>,
 }
 
+impl Validate for CloudWatchOutputConfig {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_log_group_name {
+            errors.check_length(
+                &field_path(path, "cloud_watch_log_group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+        }
+    }
+}
+
 /// <p>Describes a command request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Command {
     /// <p>CloudWatch Logs information where you want Systems Manager to send the command output.</p>
     #[serde(rename = "CloudWatchOutputConfig")]
@@ -834,6 +984,7 @@ pub struct Command {
 
 /// <p>Describes a command filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CommandFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
@@ -843,9 +994,20 @@ pub struct CommandFilter {
     pub value: String,
 }
 
+impl Validate for CommandFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(128),
+        );
+    }
+}
+
 /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. A command invocation returns status and detail information about a command you ran. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommandInvocation {
     /// <p>CloudWatch Logs information where you want Systems Manager to send the command output.</p>
     #[serde(rename = "CloudWatchOutputConfig")]
@@ -914,7 +1076,7 @@ pub struct CommandInvocation {
 
 /// <p>Describes plugin details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommandPlugin {
     /// <p>The name of the plugin. Must be one of the following: aws:updateAgent, aws:domainjoin, aws:applications, aws:runPowerShellScript, aws:psmodule, aws:cloudWatch, aws:runShellScript, or aws:updateSSMAgent. </p>
     #[serde(rename = "Name")]
@@ -982,9 +1144,30 @@ pub struct ComplianceExecutionSummary {
     pub execution_type: Option<String>,
 }
 
+impl Validate for ComplianceExecutionSummary {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.execution_id {
+            errors.check_length(
+                &field_path(path, "execution_id"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.execution_type {
+            errors.check_length(
+                &field_path(path, "execution_type"),
+                value.chars().count(),
+                None,
+                Some(50),
+            );
+        }
+    }
+}
+
 /// <p>Information about the compliance as defined by the resource type. For example, for a patch resource type, <code>Items</code> includes information about the PatchSeverity, Classification, etc.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceItem {
     /// <p>The compliance type. For example, Association (for a State Manager association), Patch, or Custom:<code>string</code> are all valid compliance types.</p>
     #[serde(rename = "ComplianceType")]
@@ -1026,6 +1209,7 @@ pub struct ComplianceItem {
 
 /// <p>Information about a compliance item.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ComplianceItemEntry {
     /// <p>A "Key": "Value" tag combination for the compliance item.</p>
     #[serde(rename = "Details")]
@@ -1047,8 +1231,28 @@ pub struct ComplianceItemEntry {
     pub title: Option<String>,
 }
 
+impl Validate for ComplianceItemEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "details"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(4096));
+            }
+        }
+        if let Some(ref value) = self.title {
+            errors.check_length(
+                &field_path(path, "title"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+    }
+}
+
 /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ComplianceStringFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
@@ -1064,9 +1268,25 @@ pub struct ComplianceStringFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for ComplianceStringFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), Some(1), Some(20));
+        }
+    }
+}
+
 /// <p>A summary of compliance information by compliance type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComplianceSummaryItem {
     /// <p>The type of compliance item. For example, the compliance type can be Association, Patch, or Custom:string.</p>
     #[serde(rename = "ComplianceType")]
@@ -1084,7 +1304,7 @@ pub struct ComplianceSummaryItem {
 
 /// <p>A summary of resources that are compliant. The summary is organized according to the resource count for each compliance type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompliantSummary {
     /// <p>The total number of resources that are compliant.</p>
     #[serde(rename = "CompliantCount")]
@@ -1097,6 +1317,7 @@ pub struct CompliantSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateActivationRequest {
     /// <p><p>The name of the registered, managed instance as it will appear in the Amazon EC2 console or when you use the AWS command line tools to list EC2 resources.</p> <important> <p>Do not enter personally identifiable information in this field.</p> </important></p>
     #[serde(rename = "DefaultInstanceName")]
@@ -1123,8 +1344,55 @@ pub struct CreateActivationRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateActivationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_instance_name {
+            errors.check_length(
+                &field_path(path, "default_instance_name"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "default_instance_name"),
+                &value,
+                "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "iam_role"),
+            self.iam_role.chars().count(),
+            None,
+            Some(64),
+        );
+        if let Some(ref value) = self.registration_limit {
+            errors.check_range(
+                &field_path(path, "registration_limit"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(1000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateActivationResult {
     /// <p>The code the system generates when it processes the activation. The activation code functions like a password to validate the activation ID. </p>
     #[serde(rename = "ActivationCode")]
@@ -1137,12 +1405,28 @@ pub struct CreateActivationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAssociationBatchRequest {
     /// <p>One or more associations.</p>
     #[serde(rename = "Entries")]
     pub entries: Vec<CreateAssociationBatchRequestEntry>,
 }
 
+impl Validate for CreateAssociationBatchRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entries"),
+            self.entries.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.entries.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "entries"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Describes the association of a Systems Manager SSM document and an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateAssociationBatchRequestEntry {
@@ -1195,8 +1479,91 @@ pub struct CreateAssociationBatchRequestEntry {
     pub targets: Option<Vec<Target>>,
 }
 
+impl Validate for CreateAssociationBatchRequestEntry {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.association_name {
+            errors.check_pattern(
+                &field_path(path, "association_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.automation_target_parameter_name {
+            errors.check_length(
+                &field_path(path, "automation_target_parameter_name"),
+                value.chars().count(),
+                Some(1),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+            );
+        }
+        if let Some(ref value) = self.max_concurrency {
+            errors.check_length(
+                &field_path(path, "max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.max_errors {
+            errors.check_length(
+                &field_path(path, "max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+        if let Some(ref value) = self.output_location {
+            value.validate_at(&field_path(path, "output_location"), errors);
+        }
+        if let Some(ref value) = self.schedule_expression {
+            errors.check_length(
+                &field_path(path, "schedule_expression"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAssociationBatchResult {
     /// <p>Information about the associations that failed.</p>
     #[serde(rename = "Failed")]
@@ -1209,6 +1576,7 @@ pub struct CreateAssociationBatchResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAssociationRequest {
     /// <p>Specify a descriptive name for the association.</p>
     #[serde(rename = "AssociationName")]
@@ -1259,8 +1627,91 @@ pub struct CreateAssociationRequest {
     pub targets: Option<Vec<Target>>,
 }
 
+impl Validate for CreateAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.association_name {
+            errors.check_pattern(
+                &field_path(path, "association_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.automation_target_parameter_name {
+            errors.check_length(
+                &field_path(path, "automation_target_parameter_name"),
+                value.chars().count(),
+                Some(1),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+            );
+        }
+        if let Some(ref value) = self.max_concurrency {
+            errors.check_length(
+                &field_path(path, "max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.max_errors {
+            errors.check_length(
+                &field_path(path, "max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+        if let Some(ref value) = self.output_location {
+            value.validate_at(&field_path(path, "output_location"), errors);
+        }
+        if let Some(ref value) = self.schedule_expression {
+            errors.check_length(
+                &field_path(path, "schedule_expression"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAssociationResult {
     /// <p>Information about the association.</p>
     #[serde(rename = "AssociationDescription")]
@@ -1269,6 +1720,7 @@ pub struct CreateAssociationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateDocumentRequest {
     /// <p>A list of key and value pairs that describe attachments to a version of a document.</p>
     #[serde(rename = "Attachments")]
@@ -1302,8 +1754,63 @@ pub struct CreateDocumentRequest {
     pub version_name: Option<String>,
 }
 
+impl Validate for CreateDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attachments {
+            errors.check_length(
+                &field_path(path, "attachments"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attachments"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "content"),
+            self.content.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(1000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.target_type {
+            errors.check_length(
+                &field_path(path, "target_type"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+            errors.check_pattern(
+                &field_path(path, "target_type"),
+                &value,
+                "^\\/[\\w\\.\\-\\:\\/]*$",
+            );
+        }
+        if let Some(ref value) = self.version_name {
+            errors.check_pattern(
+                &field_path(path, "version_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{1,128}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateDocumentResult {
     /// <p>Information about the Systems Manager document.</p>
     #[serde(rename = "DocumentDescription")]
@@ -1312,6 +1819,7 @@ pub struct CreateDocumentResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateMaintenanceWindowRequest {
     /// <p>Enables a maintenance window task to run on managed instances, even if you have not registered those instances as targets. If enabled, then you must specify the unregistered instances (by instance ID) when you register a task with the maintenance window.</p> <p>If you don't enable this option, then you must specify previously-registered targets when you register a task with the maintenance window.</p>
     #[serde(rename = "AllowUnassociatedTargets")]
@@ -1354,8 +1862,60 @@ pub struct CreateMaintenanceWindowRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        errors.check_range(&field_path(path, "cutoff"), self.cutoff, None, Some(23));
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "duration"),
+            self.duration,
+            Some(1),
+            Some(24),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(3),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "schedule"),
+            self.schedule.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(1000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateMaintenanceWindowResult {
     /// <p>The ID of the created maintenance window.</p>
     #[serde(rename = "WindowId")]
@@ -1364,6 +1924,7 @@ pub struct CreateMaintenanceWindowResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateOpsItemRequest {
     /// <p>Information about the OpsItem. </p>
     #[serde(rename = "Description")]
@@ -1396,8 +1957,54 @@ pub struct CreateOpsItemRequest {
     pub title: String,
 }
 
+impl Validate for CreateOpsItemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "description"),
+            self.description.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "description"),
+            &self.description,
+            "[\\s\\S]*\\S[\\s\\S]*",
+        );
+        if let Some(ref value) = self.operational_data {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "operational_data"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, Some(1), Some(5));
+        }
+        errors.check_length(
+            &field_path(path, "source"),
+            self.source.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "source"), &self.source, "^(?!\\s*$).+");
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(1000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "title"),
+            self.title.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "title"), &self.title, "^(?!\\s*$).+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateOpsItemResponse {
     /// <p>The ID of the OpsItem.</p>
     #[serde(rename = "OpsItemId")]
@@ -1406,6 +2013,7 @@ pub struct CreateOpsItemResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreatePatchBaselineRequest {
     /// <p>A set of rules used to include patches in the baseline.</p>
     #[serde(rename = "ApprovalRules")]
@@ -1460,8 +2068,84 @@ pub struct CreatePatchBaselineRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreatePatchBaselineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.approval_rules {
+            value.validate_at(&field_path(path, "approval_rules"), errors);
+        }
+        if let Some(ref value) = self.approved_patches {
+            errors.check_length(
+                &field_path(path, "approved_patches"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "approved_patches"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.global_filters {
+            value.validate_at(&field_path(path, "global_filters"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(3),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+        if let Some(ref value) = self.rejected_patches {
+            errors.check_length(
+                &field_path(path, "rejected_patches"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "rejected_patches"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+        if let Some(ref value) = self.sources {
+            errors.check_length(&field_path(path, "sources"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(1000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreatePatchBaselineResult {
     /// <p>The ID of the created patch baseline.</p>
     #[serde(rename = "BaselineId")]
@@ -1470,6 +2154,7 @@ pub struct CreatePatchBaselineResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceDataSyncRequest {
     /// <p>Amazon S3 configuration details for the sync.</p>
     #[serde(rename = "S3Destination")]
@@ -1479,22 +2164,47 @@ pub struct CreateResourceDataSyncRequest {
     pub sync_name: String,
 }
 
+impl Validate for CreateResourceDataSyncRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.s3_destination
+            .validate_at(&field_path(path, "s3_destination"), errors);
+        errors.check_length(
+            &field_path(path, "sync_name"),
+            self.sync_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceDataSyncResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteActivationRequest {
     /// <p>The ID of the activation that you want to delete.</p>
     #[serde(rename = "ActivationId")]
     pub activation_id: String,
 }
 
+impl Validate for DeleteActivationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "activation_id"),
+            &self.activation_id,
+            "^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteActivationResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAssociationRequest {
     /// <p>The association ID that you want to delete.</p>
     #[serde(rename = "AssociationId")]
@@ -1510,11 +2220,38 @@ pub struct DeleteAssociationRequest {
     pub name: Option<String>,
 }
 
+impl Validate for DeleteAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.association_id {
+            errors.check_pattern(
+                &field_path(path, "association_id"),
+                &value,
+                "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+            );
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAssociationResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDocumentRequest {
     /// <p>The version of the document that you want to delete. If not provided, all versions of the document are deleted.</p>
     #[serde(rename = "DocumentVersion")]
@@ -1529,11 +2266,36 @@ pub struct DeleteDocumentRequest {
     pub version_name: Option<String>,
 }
 
+impl Validate for DeleteDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+        if let Some(ref value) = self.version_name {
+            errors.check_pattern(
+                &field_path(path, "version_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{1,128}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteDocumentResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteInventoryRequest {
     /// <p>User-provided idempotency token.</p>
     #[serde(rename = "ClientToken")]
@@ -1552,8 +2314,31 @@ pub struct DeleteInventoryRequest {
     pub type_name: String,
 }
 
+impl Validate for DeleteInventoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_pattern(
+                &field_path(path, "client_token"),
+                &value,
+                "[a-f0-9]{8}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{12}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "^(AWS|Custom):.*$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteInventoryResult {
     /// <p>Every <code>DeleteInventory</code> action is assigned a unique ID. This option returns a unique ID. You can use this ID to query the status of a delete operation. This option is useful for ensuring that a delete operation has completed before you begin other actions. </p>
     #[serde(rename = "DeletionId")]
@@ -1570,14 +2355,31 @@ pub struct DeleteInventoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMaintenanceWindowRequest {
     /// <p>The ID of the maintenance window to delete.</p>
     #[serde(rename = "WindowId")]
     pub window_id: String,
 }
 
+impl Validate for DeleteMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMaintenanceWindowResult {
     /// <p>The ID of the deleted maintenance window.</p>
     #[serde(rename = "WindowId")]
@@ -1586,25 +2388,53 @@ pub struct DeleteMaintenanceWindowResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteParameterRequest {
     /// <p>The name of the parameter to delete.</p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteParameterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteParameterResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteParametersRequest {
     /// <p>The names of the parameters to delete.</p>
     #[serde(rename = "Names")]
     pub names: Vec<String>,
 }
 
+impl Validate for DeleteParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "names"),
+            self.names.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteParametersResult {
     /// <p>The names of the deleted parameters.</p>
     #[serde(rename = "DeletedParameters")]
@@ -1617,14 +2447,31 @@ pub struct DeleteParametersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePatchBaselineRequest {
     /// <p>The ID of the patch baseline to delete.</p>
     #[serde(rename = "BaselineId")]
     pub baseline_id: String,
 }
 
+impl Validate for DeletePatchBaselineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePatchBaselineResult {
     /// <p>The ID of the deleted patch baseline.</p>
     #[serde(rename = "BaselineId")]
@@ -1633,28 +2480,58 @@ pub struct DeletePatchBaselineResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourceDataSyncRequest {
     /// <p>The name of the configuration to delete.</p>
     #[serde(rename = "SyncName")]
     pub sync_name: String,
 }
 
+impl Validate for DeleteResourceDataSyncRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "sync_name"),
+            self.sync_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResourceDataSyncResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterManagedInstanceRequest {
     /// <p>The ID assigned to the managed instance when you registered it using the activation process. </p>
     #[serde(rename = "InstanceId")]
     pub instance_id: String,
 }
 
+impl Validate for DeregisterManagedInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(20),
+            Some(124),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^mi-[0-9a-f]{17}$)|(^eks_c:[0-9A-Za-z][A-Za-z0-9\\-_]{0,99}_\\w{17}$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterManagedInstanceResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterPatchBaselineForPatchGroupRequest {
     /// <p>The ID of the patch baseline to deregister the patch group from.</p>
     #[serde(rename = "BaselineId")]
@@ -1664,8 +2541,35 @@ pub struct DeregisterPatchBaselineForPatchGroupRequest {
     pub patch_group: String,
 }
 
+impl Validate for DeregisterPatchBaselineForPatchGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "patch_group"),
+            self.patch_group.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "patch_group"),
+            &self.patch_group,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterPatchBaselineForPatchGroupResult {
     /// <p>The ID of the patch baseline the patch group was deregistered from.</p>
     #[serde(rename = "BaselineId")]
@@ -1678,6 +2582,7 @@ pub struct DeregisterPatchBaselineForPatchGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterTargetFromMaintenanceWindowRequest {
     /// <p>The system checks if the target is being referenced by a task. If the target is being referenced, the system returns an error and does not deregister the target from the maintenance window.</p>
     #[serde(rename = "Safe")]
@@ -1691,8 +2596,35 @@ pub struct DeregisterTargetFromMaintenanceWindowRequest {
     pub window_target_id: String,
 }
 
+impl Validate for DeregisterTargetFromMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_target_id"),
+            self.window_target_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_target_id"),
+            &self.window_target_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterTargetFromMaintenanceWindowResult {
     /// <p>The ID of the maintenance window the target was removed from.</p>
     #[serde(rename = "WindowId")]
@@ -1705,6 +2637,7 @@ pub struct DeregisterTargetFromMaintenanceWindowResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterTaskFromMaintenanceWindowRequest {
     /// <p>The ID of the maintenance window the task should be removed from.</p>
     #[serde(rename = "WindowId")]
@@ -1714,8 +2647,35 @@ pub struct DeregisterTaskFromMaintenanceWindowRequest {
     pub window_task_id: String,
 }
 
+impl Validate for DeregisterTaskFromMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_task_id"),
+            self.window_task_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_task_id"),
+            &self.window_task_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterTaskFromMaintenanceWindowResult {
     /// <p>The ID of the maintenance window the task was removed from.</p>
     #[serde(rename = "WindowId")]
@@ -1729,6 +2689,7 @@ pub struct DeregisterTaskFromMaintenanceWindowResult {
 
 /// <p>Filter for the DescribeActivation API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActivationsFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "FilterKey")]
@@ -1741,6 +2702,7 @@ pub struct DescribeActivationsFilter {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActivationsRequest {
     /// <p>A filter to view information about your activations.</p>
     #[serde(rename = "Filters")]
@@ -1756,8 +2718,16 @@ pub struct DescribeActivationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeActivationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeActivationsResult {
     /// <p>A list of activations for your AWS account.</p>
     #[serde(rename = "ActivationList")]
@@ -1770,6 +2740,7 @@ pub struct DescribeActivationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAssociationExecutionTargetsRequest {
     /// <p>The association ID that includes the execution for which you want to view details.</p>
     #[serde(rename = "AssociationId")]
@@ -1791,8 +2762,33 @@ pub struct DescribeAssociationExecutionTargetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAssociationExecutionTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "association_id"),
+            &self.association_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+        errors.check_pattern(
+            &field_path(path, "execution_id"),
+            &self.execution_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAssociationExecutionTargetsResult {
     /// <p>Information about the execution.</p>
     #[serde(rename = "AssociationExecutionTargets")]
@@ -1805,6 +2801,7 @@ pub struct DescribeAssociationExecutionTargetsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAssociationExecutionsRequest {
     /// <p>The association ID for which you want to view execution history details.</p>
     #[serde(rename = "AssociationId")]
@@ -1823,8 +2820,28 @@ pub struct DescribeAssociationExecutionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAssociationExecutionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "association_id"),
+            &self.association_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAssociationExecutionsResult {
     /// <p>A list of the executions for the specified association ID.</p>
     #[serde(rename = "AssociationExecutions")]
@@ -1837,6 +2854,7 @@ pub struct DescribeAssociationExecutionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAssociationRequest {
     /// <p>The association ID for which you want information.</p>
     #[serde(rename = "AssociationId")]
@@ -1856,16 +2874,50 @@ pub struct DescribeAssociationRequest {
     pub name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeAssociationResult {
-    /// <p>Information about the association.</p>
+impl Validate for DescribeAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.association_id {
+            errors.check_pattern(
+                &field_path(path, "association_id"),
+                &value,
+                "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+            );
+        }
+        if let Some(ref value) = self.association_version {
+            errors.check_pattern(
+                &field_path(path, "association_version"),
+                &value,
+                "([$]LATEST)|([1-9][0-9]*)",
+            );
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+            );
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct DescribeAssociationResult {
+    /// <p>Information about the association.</p>
     #[serde(rename = "AssociationDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub association_description: Option<AssociationDescription>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAutomationExecutionsRequest {
     /// <p>Filters used to limit the scope of executions that are requested.</p>
     #[serde(rename = "Filters")]
@@ -1881,8 +2933,23 @@ pub struct DescribeAutomationExecutionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAutomationExecutionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(10));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAutomationExecutionsResult {
     /// <p>The list of details about each automation execution which has occurred which matches the filter specification, if any.</p>
     #[serde(rename = "AutomationExecutionMetadataList")]
@@ -1895,6 +2962,7 @@ pub struct DescribeAutomationExecutionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAutomationStepExecutionsRequest {
     /// <p>The Automation execution ID for which you want step execution descriptions.</p>
     #[serde(rename = "AutomationExecutionId")]
@@ -1917,8 +2985,29 @@ pub struct DescribeAutomationStepExecutionsRequest {
     pub reverse_order: Option<bool>,
 }
 
+impl Validate for DescribeAutomationStepExecutionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "automation_execution_id"),
+            self.automation_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(6));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAutomationStepExecutionsResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -1931,6 +3020,7 @@ pub struct DescribeAutomationStepExecutionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAvailablePatchesRequest {
     /// <p>Filters used to scope down the returned patches.</p>
     #[serde(rename = "Filters")]
@@ -1946,8 +3036,23 @@ pub struct DescribeAvailablePatchesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAvailablePatchesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAvailablePatchesResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -1960,6 +3065,7 @@ pub struct DescribeAvailablePatchesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDocumentPermissionRequest {
     /// <p>The name of the document for which you are the owner.</p>
     #[serde(rename = "Name")]
@@ -1969,8 +3075,18 @@ pub struct DescribeDocumentPermissionRequest {
     pub permission_type: String,
 }
 
+impl Validate for DescribeDocumentPermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDocumentPermissionResponse {
     /// <p>The account IDs that have permission to use this document. The ID can be either an AWS account or <i>All</i>.</p>
     #[serde(rename = "AccountIds")]
@@ -1979,6 +3095,7 @@ pub struct DescribeDocumentPermissionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDocumentRequest {
     /// <p>The document version for which you want information. Can be a specific version or the default version.</p>
     #[serde(rename = "DocumentVersion")]
@@ -1993,8 +3110,32 @@ pub struct DescribeDocumentRequest {
     pub version_name: Option<String>,
 }
 
+impl Validate for DescribeDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+        if let Some(ref value) = self.version_name {
+            errors.check_pattern(
+                &field_path(path, "version_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{1,128}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDocumentResult {
     /// <p>Information about the Systems Manager document.</p>
     #[serde(rename = "Document")]
@@ -2003,6 +3144,7 @@ pub struct DescribeDocumentResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEffectiveInstanceAssociationsRequest {
     /// <p>The instance ID for which you want to view all associations.</p>
     #[serde(rename = "InstanceId")]
@@ -2017,8 +3159,21 @@ pub struct DescribeEffectiveInstanceAssociationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeEffectiveInstanceAssociationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(5));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEffectiveInstanceAssociationsResult {
     /// <p>The associations for the requested instance.</p>
     #[serde(rename = "Associations")]
@@ -2031,6 +3186,7 @@ pub struct DescribeEffectiveInstanceAssociationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeEffectivePatchesForPatchBaselineRequest {
     /// <p>The ID of the patch baseline to retrieve the effective patches for.</p>
     #[serde(rename = "BaselineId")]
@@ -2045,8 +3201,27 @@ pub struct DescribeEffectivePatchesForPatchBaselineRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeEffectivePatchesForPatchBaselineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeEffectivePatchesForPatchBaselineResult {
     /// <p>An array of patches and patch status.</p>
     #[serde(rename = "EffectivePatches")]
@@ -2059,6 +3234,7 @@ pub struct DescribeEffectivePatchesForPatchBaselineResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstanceAssociationsStatusRequest {
     /// <p>The instance IDs for which you want association status information.</p>
     #[serde(rename = "InstanceId")]
@@ -2073,8 +3249,21 @@ pub struct DescribeInstanceAssociationsStatusRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInstanceAssociationsStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstanceAssociationsStatusResult {
     /// <p>Status information about the association.</p>
     #[serde(rename = "InstanceAssociationStatusInfos")]
@@ -2087,6 +3276,7 @@ pub struct DescribeInstanceAssociationsStatusResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstanceInformationRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of instances. You can filter on Amazon EC2 tag. Specify tags by using a key-value mapping.</p>
     #[serde(rename = "Filters")]
@@ -2106,8 +3296,32 @@ pub struct DescribeInstanceInformationRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInstanceInformationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.instance_information_filter_list {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!(
+                    "{}[{}]",
+                    field_path(path, "instance_information_filter_list"),
+                    i
+                );
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(5), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstanceInformationResult {
     /// <p>The instance information list.</p>
     #[serde(rename = "InstanceInformationList")]
@@ -2120,6 +3334,7 @@ pub struct DescribeInstanceInformationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancePatchStatesForPatchGroupRequest {
     /// <p>Each entry in the array is a structure containing:</p> <p>Key (string between 1 and 200 characters)</p> <p> Values (array containing a single string)</p> <p> Type (string "Equal", "NotEqual", "LessThan", "GreaterThan")</p>
     #[serde(rename = "Filters")]
@@ -2138,8 +3353,39 @@ pub struct DescribeInstancePatchStatesForPatchGroupRequest {
     pub patch_group: String,
 }
 
+impl Validate for DescribeInstancePatchStatesForPatchGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(4));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "patch_group"),
+            self.patch_group.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "patch_group"),
+            &self.patch_group,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstancePatchStatesForPatchGroupResult {
     /// <p>The high-level patch state for the requested instances. </p>
     #[serde(rename = "InstancePatchStates")]
@@ -2152,6 +3398,7 @@ pub struct DescribeInstancePatchStatesForPatchGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancePatchStatesRequest {
     /// <p>The ID of the instance whose patch state information should be retrieved.</p>
     #[serde(rename = "InstanceIds")]
@@ -2166,8 +3413,31 @@ pub struct DescribeInstancePatchStatesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInstancePatchStatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "instance_ids"),
+            self.instance_ids.len(),
+            None,
+            Some(50),
+        );
+        for (i, item) in self.instance_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+            errors.check_pattern(&item_path, &item, "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)");
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstancePatchStatesResult {
     /// <p>The high-level patch state for the requested instances.</p>
     #[serde(rename = "InstancePatchStates")]
@@ -2180,6 +3450,7 @@ pub struct DescribeInstancePatchStatesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInstancePatchesRequest {
     /// <p>An array of structures. Each entry in the array is a structure containing a Key, Value combination. Valid values for Key are <code>Classification</code> | <code>KBId</code> | <code>Severity</code> | <code>State</code>.</p>
     #[serde(rename = "Filters")]
@@ -2198,8 +3469,33 @@ pub struct DescribeInstancePatchesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInstancePatchesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInstancePatchesResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2212,6 +3508,7 @@ pub struct DescribeInstancePatchesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeInventoryDeletionsRequest {
     /// <p>Specify the delete inventory ID for which you want information. This ID was returned by the <code>DeleteInventory</code> action.</p>
     #[serde(rename = "DeletionId")]
@@ -2227,8 +3524,23 @@ pub struct DescribeInventoryDeletionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeInventoryDeletionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.deletion_id {
+            errors.check_pattern(
+                &field_path(path, "deletion_id"),
+                &value,
+                "[a-f0-9]{8}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{12}",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeInventoryDeletionsResult {
     /// <p>A list of status items for deleted inventory.</p>
     #[serde(rename = "InventoryDeletions")]
@@ -2241,6 +3553,7 @@ pub struct DescribeInventoryDeletionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowExecutionTaskInvocationsRequest {
     /// <p>Optional filters used to scope down the returned task invocations. The supported filter key is STATUS with the corresponding values PENDING, IN_PROGRESS, SUCCESS, FAILED, TIMED_OUT, CANCELLING, and CANCELLED.</p>
     #[serde(rename = "Filters")]
@@ -2262,8 +3575,50 @@ pub struct DescribeMaintenanceWindowExecutionTaskInvocationsRequest {
     pub window_execution_id: String,
 }
 
+impl Validate for DescribeMaintenanceWindowExecutionTaskInvocationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_id"),
+            self.task_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_id"),
+            &self.task_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_execution_id"),
+            self.window_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_execution_id"),
+            &self.window_execution_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowExecutionTaskInvocationsResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2277,6 +3632,7 @@ pub struct DescribeMaintenanceWindowExecutionTaskInvocationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowExecutionTasksRequest {
     /// <p>Optional filters used to scope down the returned tasks. The supported filter key is STATUS with the corresponding values PENDING, IN_PROGRESS, SUCCESS, FAILED, TIMED_OUT, CANCELLING, and CANCELLED. </p>
     #[serde(rename = "Filters")]
@@ -2295,8 +3651,39 @@ pub struct DescribeMaintenanceWindowExecutionTasksRequest {
     pub window_execution_id: String,
 }
 
+impl Validate for DescribeMaintenanceWindowExecutionTasksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "window_execution_id"),
+            self.window_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_execution_id"),
+            &self.window_execution_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowExecutionTasksResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2309,6 +3696,7 @@ pub struct DescribeMaintenanceWindowExecutionTasksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowExecutionsRequest {
     /// <p>Each entry in the array is a structure containing:</p> <p>Key (string, between 1 and 128 characters)</p> <p>Values (array of strings, each string is between 1 and 256 characters)</p> <p>The supported Keys are ExecutedBefore and ExecutedAfter with the value being a date/time string such as 2016-11-04T05:00:00Z.</p>
     #[serde(rename = "Filters")]
@@ -2327,8 +3715,39 @@ pub struct DescribeMaintenanceWindowExecutionsRequest {
     pub window_id: String,
 }
 
+impl Validate for DescribeMaintenanceWindowExecutionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowExecutionsResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2341,6 +3760,7 @@ pub struct DescribeMaintenanceWindowExecutionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowScheduleRequest {
     /// <p>Filters used to limit the range of results. For example, you can limit maintenance window executions to only those scheduled before or after a certain date and time.</p>
     #[serde(rename = "Filters")]
@@ -2368,8 +3788,39 @@ pub struct DescribeMaintenanceWindowScheduleRequest {
     pub window_id: Option<String>,
 }
 
+impl Validate for DescribeMaintenanceWindowScheduleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.window_id {
+            errors.check_length(
+                &field_path(path, "window_id"),
+                value.chars().count(),
+                Some(20),
+                Some(20),
+            );
+            errors.check_pattern(&field_path(path, "window_id"), &value, "^mw-[0-9a-f]{17}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowScheduleResult {
     /// <p>The token for the next set of items to return. (You use this token in the next call.)</p>
     #[serde(rename = "NextToken")]
@@ -2382,6 +3833,7 @@ pub struct DescribeMaintenanceWindowScheduleResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowTargetsRequest {
     /// <p>Optional filters that can be used to narrow down the scope of the returned window targets. The supported filter keys are Type, WindowTargetId and OwnerInformation.</p>
     #[serde(rename = "Filters")]
@@ -2400,8 +3852,39 @@ pub struct DescribeMaintenanceWindowTargetsRequest {
     pub window_id: String,
 }
 
+impl Validate for DescribeMaintenanceWindowTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowTargetsResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2414,6 +3897,7 @@ pub struct DescribeMaintenanceWindowTargetsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowTasksRequest {
     /// <p>Optional filters used to narrow down the scope of the returned tasks. The supported filter keys are WindowTaskId, TaskArn, Priority, and TaskType.</p>
     #[serde(rename = "Filters")]
@@ -2432,8 +3916,39 @@ pub struct DescribeMaintenanceWindowTasksRequest {
     pub window_id: String,
 }
 
+impl Validate for DescribeMaintenanceWindowTasksRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowTasksResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2446,6 +3961,7 @@ pub struct DescribeMaintenanceWindowTasksResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowsForTargetRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -2463,8 +3979,26 @@ pub struct DescribeMaintenanceWindowsForTargetRequest {
     pub targets: Vec<Target>,
 }
 
+impl Validate for DescribeMaintenanceWindowsForTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        errors.check_length(
+            &field_path(path, "targets"),
+            self.targets.len(),
+            None,
+            Some(5),
+        );
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowsForTargetResult {
     /// <p>The token for the next set of items to return. (You use this token in the next call.)</p>
     #[serde(rename = "NextToken")]
@@ -2477,6 +4011,7 @@ pub struct DescribeMaintenanceWindowsForTargetResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceWindowsRequest {
     /// <p>Optional filters used to narrow down the scope of the returned maintenance windows. Supported filter keys are <b>Name</b> and <b>Enabled</b>.</p>
     #[serde(rename = "Filters")]
@@ -2492,8 +4027,28 @@ pub struct DescribeMaintenanceWindowsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeMaintenanceWindowsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceWindowsResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2506,6 +4061,7 @@ pub struct DescribeMaintenanceWindowsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOpsItemsRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -2521,8 +4077,16 @@ pub struct DescribeOpsItemsRequest {
     pub ops_item_filters: Option<Vec<OpsItemFilter>>,
 }
 
+impl Validate for DescribeOpsItemsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOpsItemsResponse {
     /// <p>The token for the next set of items to return. Use this token to get the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -2535,6 +4099,7 @@ pub struct DescribeOpsItemsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeParametersRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "Filters")]
@@ -2554,8 +4119,28 @@ pub struct DescribeParametersRequest {
     pub parameter_filters: Option<Vec<ParameterStringFilter>>,
 }
 
+impl Validate for DescribeParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.parameter_filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeParametersResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -2568,6 +4153,7 @@ pub struct DescribeParametersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePatchBaselinesRequest {
     /// <p>Each element in the array is a structure containing: </p> <p>Key: (string, "NAME_PREFIX" or "OWNER")</p> <p>Value: (array of strings, exactly 1 entry, between 1 and 255 characters)</p>
     #[serde(rename = "Filters")]
@@ -2583,8 +4169,23 @@ pub struct DescribePatchBaselinesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribePatchBaselinesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePatchBaselinesResult {
     /// <p>An array of PatchBaselineIdentity elements.</p>
     #[serde(rename = "BaselineIdentities")]
@@ -2597,14 +4198,31 @@ pub struct DescribePatchBaselinesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePatchGroupStateRequest {
     /// <p>The name of the patch group whose patch snapshot should be retrieved.</p>
     #[serde(rename = "PatchGroup")]
     pub patch_group: String,
 }
 
+impl Validate for DescribePatchGroupStateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "patch_group"),
+            self.patch_group.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "patch_group"),
+            &self.patch_group,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePatchGroupStateResult {
     /// <p>The number of instances in the patch group.</p>
     #[serde(rename = "Instances")]
@@ -2641,6 +4259,7 @@ pub struct DescribePatchGroupStateResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePatchGroupsRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "Filters")]
@@ -2656,8 +4275,23 @@ pub struct DescribePatchGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribePatchGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePatchGroupsResult {
     /// <p>Each entry in the array contains:</p> <p>PatchGroup: string (between 1 and 256 characters, Regex: ^([\p{L}\p{Z}\p{N}_.:/=+\-@]*)$)</p> <p>PatchBaselineIdentity: A PatchBaselineIdentity element. </p>
     #[serde(rename = "Mappings")]
@@ -2670,6 +4304,7 @@ pub struct DescribePatchGroupsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribePatchPropertiesRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -2691,8 +4326,16 @@ pub struct DescribePatchPropertiesRequest {
     pub property: String,
 }
 
+impl Validate for DescribePatchPropertiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribePatchPropertiesResult {
     /// <p>The token for the next set of items to return. (You use this token in the next call.)</p>
     #[serde(rename = "NextToken")]
@@ -2705,6 +4348,7 @@ pub struct DescribePatchPropertiesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSessionsRequest {
     /// <p>One or more filters to limit the type of sessions returned by the request.</p>
     #[serde(rename = "Filters")]
@@ -2723,8 +4367,23 @@ pub struct DescribeSessionsRequest {
     pub state: String,
 }
 
+impl Validate for DescribeSessionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(6));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(200));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSessionsResponse {
     /// <p>The token for the next set of items to return. (You received this token from a previous call.)</p>
     #[serde(rename = "NextToken")]
@@ -2738,7 +4397,7 @@ pub struct DescribeSessionsResponse {
 
 /// <p>A default version of a document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentDefaultVersionDescription {
     /// <p>The default version of the document.</p>
     #[serde(rename = "DefaultVersion")]
@@ -2756,7 +4415,7 @@ pub struct DocumentDefaultVersionDescription {
 
 /// <p>Describes a Systems Manager document. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentDescription {
     /// <p>Details about the document attachments, including names, locations, sizes, etc.</p>
     #[serde(rename = "AttachmentsInformation")]
@@ -2846,6 +4505,7 @@ pub struct DocumentDescription {
 
 /// <p>Describes a filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DocumentFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
@@ -2855,9 +4515,20 @@ pub struct DocumentFilter {
     pub value: String,
 }
 
+impl Validate for DocumentFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 /// <p>Describes the name of a Systems Manager document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentIdentifier {
     /// <p>The document format, either JSON or YAML.</p>
     #[serde(rename = "DocumentFormat")]
@@ -2903,6 +4574,7 @@ pub struct DocumentIdentifier {
 
 /// <p>One or more filters. Use a filter to return a more specific list of documents.</p> <p>For keys, you can specify one or more tags that have been applied to a document. </p> <p>Other valid values include Owner, Name, PlatformTypes, and DocumentType.</p> <p>Note that only one Owner can be specified in a request. For example: <code>Key=Owner,Values=Self</code>.</p> <p>If you use Name as a key, you can use a name prefix to return a list of documents. For example, in the AWS CLI, to return a list of all documents that begin with <code>Te</code>, run the following command:</p> <p> <code>aws ssm list-documents --filters Key=Name,Values=Te</code> </p> <p>If you specify more than two keys, only documents that are identified by all the tags are returned in the results. If you specify more than two values for a key, documents that are identified by any of the values are returned in the results.</p> <p>To specify a custom key and value pair, use the format <code>Key=tag:[tagName],Values=[valueName]</code>.</p> <p>For example, if you created a Key called region and are using the AWS CLI to call the <code>list-documents</code> command: </p> <p> <code>aws ssm list-documents --filters Key=tag:region,Values=east,west Key=Owner,Values=Self</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DocumentKeyValuesFilter {
     /// <p>The name of the filter key.</p>
     #[serde(rename = "Key")]
@@ -2914,9 +4586,28 @@ pub struct DocumentKeyValuesFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for DocumentKeyValuesFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>Parameters specified in a System Manager document that run on the server when the command is run. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentParameter {
     /// <p>If specified, the default values for the parameters. Parameters without a default value are required. Parameters with a default value are optional.</p>
     #[serde(rename = "DefaultValue")]
@@ -2938,7 +4629,7 @@ pub struct DocumentParameter {
 
 /// <p>Version information about the document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentVersionInfo {
     /// <p>The date the document was created.</p>
     #[serde(rename = "CreatedDate")]
@@ -2976,7 +4667,7 @@ pub struct DocumentVersionInfo {
 
 /// <p>The EffectivePatch structure defines metadata about a patch along with the approval state of the patch in a particular patch baseline. The approval state includes information about whether the patch is currently approved, due to be approved by a rule, explicitly approved, or explicitly rejected and the date the patch was or will be approved.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EffectivePatch {
     /// <p>Provides metadata for a patch, including information such as the KB ID, severity, classification and a URL for where more information can be obtained about the patch.</p>
     #[serde(rename = "Patch")]
@@ -2990,7 +4681,7 @@ pub struct EffectivePatch {
 
 /// <p>Describes a failed association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedCreateAssociation {
     /// <p>The association.</p>
     #[serde(rename = "Entry")]
@@ -3008,7 +4699,7 @@ pub struct FailedCreateAssociation {
 
 /// <p>Information about an Automation failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailureDetails {
     /// <p>Detailed information about the Automation step failure.</p>
     #[serde(rename = "Details")]
@@ -3025,14 +4716,26 @@ pub struct FailureDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAutomationExecutionRequest {
     /// <p>The unique identifier for an existing automation execution to examine. The execution ID is returned by StartAutomationExecution when the execution of an Automation document is initiated.</p>
     #[serde(rename = "AutomationExecutionId")]
     pub automation_execution_id: String,
 }
 
+impl Validate for GetAutomationExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "automation_execution_id"),
+            self.automation_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetAutomationExecutionResult {
     /// <p>Detailed information about the current state of an automation execution.</p>
     #[serde(rename = "AutomationExecution")]
@@ -3041,6 +4744,7 @@ pub struct GetAutomationExecutionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCommandInvocationRequest {
     /// <p>(Required) The parent command ID of the invocation plugin.</p>
     #[serde(rename = "CommandId")]
@@ -3054,8 +4758,32 @@ pub struct GetCommandInvocationRequest {
     pub plugin_name: Option<String>,
 }
 
+impl Validate for GetCommandInvocationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "command_id"),
+            self.command_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        if let Some(ref value) = self.plugin_name {
+            errors.check_length(
+                &field_path(path, "plugin_name"),
+                value.chars().count(),
+                Some(4),
+                None,
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCommandInvocationResult {
     /// <p>CloudWatch Logs information where Systems Manager sent the command output.</p>
     #[serde(rename = "CloudWatchOutputConfig")]
@@ -3128,14 +4856,26 @@ pub struct GetCommandInvocationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetConnectionStatusRequest {
     /// <p>The ID of the instance.</p>
     #[serde(rename = "Target")]
     pub target: String,
 }
 
+impl Validate for GetConnectionStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "target"),
+            self.target.chars().count(),
+            Some(1),
+            Some(400),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetConnectionStatusResponse {
     /// <p>The status of the connection to the instance. For example, 'Connected' or 'Not Connected'.</p>
     #[serde(rename = "Status")]
@@ -3148,6 +4888,7 @@ pub struct GetConnectionStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDefaultPatchBaselineRequest {
     /// <p>Returns the default patch baseline for the specified operating system.</p>
     #[serde(rename = "OperatingSystem")]
@@ -3156,7 +4897,7 @@ pub struct GetDefaultPatchBaselineRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDefaultPatchBaselineResult {
     /// <p>The ID of the default patch baseline.</p>
     #[serde(rename = "BaselineId")]
@@ -3169,6 +4910,7 @@ pub struct GetDefaultPatchBaselineResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDeployablePatchSnapshotForInstanceRequest {
     /// <p>The ID of the instance for which the appropriate patch snapshot should be retrieved.</p>
     #[serde(rename = "InstanceId")]
@@ -3178,8 +4920,29 @@ pub struct GetDeployablePatchSnapshotForInstanceRequest {
     pub snapshot_id: String,
 }
 
+impl Validate for GetDeployablePatchSnapshotForInstanceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        errors.check_length(
+            &field_path(path, "snapshot_id"),
+            self.snapshot_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "snapshot_id"),
+            &self.snapshot_id,
+            "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDeployablePatchSnapshotForInstanceResult {
     /// <p>The ID of the instance.</p>
     #[serde(rename = "InstanceId")]
@@ -3200,6 +4963,7 @@ pub struct GetDeployablePatchSnapshotForInstanceResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentRequest {
     /// <p>Returns the document in the specified format. The document format can be either JSON or YAML. JSON is the default format.</p>
     #[serde(rename = "DocumentFormat")]
@@ -3218,8 +4982,32 @@ pub struct GetDocumentRequest {
     pub version_name: Option<String>,
 }
 
+impl Validate for GetDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+        if let Some(ref value) = self.version_name {
+            errors.check_pattern(
+                &field_path(path, "version_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{1,128}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDocumentResult {
     /// <p>A description of the document attachments, including names, locations, sizes, etc.</p>
     #[serde(rename = "AttachmentsContent")]
@@ -3260,6 +5048,7 @@ pub struct GetDocumentResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInventoryRequest {
     /// <p>Returns counts of inventory types based on one or more expressions. For example, if you aggregate by using an expression that uses the <code>AWS:InstanceInformation.PlatformType</code> type, you can see a count of how many Windows and Linux instances exist in your inventoried fleet.</p>
     #[serde(rename = "Aggregators")]
@@ -3283,8 +5072,47 @@ pub struct GetInventoryRequest {
     pub result_attributes: Option<Vec<ResultAttribute>>,
 }
 
+impl Validate for GetInventoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aggregators {
+            errors.check_length(
+                &field_path(path, "aggregators"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "aggregators"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.result_attributes {
+            errors.check_length(
+                &field_path(path, "result_attributes"),
+                value.len(),
+                Some(1),
+                Some(1),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "result_attributes"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInventoryResult {
     /// <p>Collection of inventory entities such as a collection of instance inventory. </p>
     #[serde(rename = "Entities")]
@@ -3297,6 +5125,7 @@ pub struct GetInventoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetInventorySchemaRequest {
     /// <p>Returns inventory schemas that support aggregation. For example, this call returns the <code>AWS:InstanceInformation</code> type, because it supports aggregation based on the <code>PlatformName</code>, <code>PlatformType</code>, and <code>PlatformVersion</code> attributes.</p>
     #[serde(rename = "Aggregator")]
@@ -3320,8 +5149,29 @@ pub struct GetInventorySchemaRequest {
     pub type_name: Option<String>,
 }
 
+impl Validate for GetInventorySchemaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(50),
+                Some(200),
+            );
+        }
+        if let Some(ref value) = self.type_name {
+            errors.check_length(
+                &field_path(path, "type_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetInventorySchemaResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -3334,14 +5184,31 @@ pub struct GetInventorySchemaResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMaintenanceWindowExecutionRequest {
     /// <p>The ID of the maintenance window execution that includes the task.</p>
     #[serde(rename = "WindowExecutionId")]
     pub window_execution_id: String,
 }
 
+impl Validate for GetMaintenanceWindowExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_execution_id"),
+            self.window_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_execution_id"),
+            &self.window_execution_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMaintenanceWindowExecutionResult {
     /// <p>The time the maintenance window finished running.</p>
     #[serde(rename = "EndTime")]
@@ -3370,6 +5237,7 @@ pub struct GetMaintenanceWindowExecutionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMaintenanceWindowExecutionTaskInvocationRequest {
     /// <p>The invocation ID to retrieve.</p>
     #[serde(rename = "InvocationId")]
@@ -3382,8 +5250,46 @@ pub struct GetMaintenanceWindowExecutionTaskInvocationRequest {
     pub window_execution_id: String,
 }
 
+impl Validate for GetMaintenanceWindowExecutionTaskInvocationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "invocation_id"),
+            self.invocation_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "invocation_id"),
+            &self.invocation_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+        errors.check_length(
+            &field_path(path, "task_id"),
+            self.task_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_id"),
+            &self.task_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_execution_id"),
+            self.window_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_execution_id"),
+            &self.window_execution_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMaintenanceWindowExecutionTaskInvocationResult {
     /// <p>The time that the task finished running on the target.</p>
     #[serde(rename = "EndTime")]
@@ -3436,6 +5342,7 @@ pub struct GetMaintenanceWindowExecutionTaskInvocationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMaintenanceWindowExecutionTaskRequest {
     /// <p>The ID of the specific task execution in the maintenance window task that should be retrieved.</p>
     #[serde(rename = "TaskId")]
@@ -3445,8 +5352,35 @@ pub struct GetMaintenanceWindowExecutionTaskRequest {
     pub window_execution_id: String,
 }
 
+impl Validate for GetMaintenanceWindowExecutionTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "task_id"),
+            self.task_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "task_id"),
+            &self.task_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_execution_id"),
+            self.window_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_execution_id"),
+            &self.window_execution_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMaintenanceWindowExecutionTaskResult {
     /// <p>The time the task execution completed.</p>
     #[serde(rename = "EndTime")]
@@ -3505,14 +5439,31 @@ pub struct GetMaintenanceWindowExecutionTaskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMaintenanceWindowRequest {
     /// <p>The ID of the maintenance window for which you want to retrieve information.</p>
     #[serde(rename = "WindowId")]
     pub window_id: String,
 }
 
+impl Validate for GetMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMaintenanceWindowResult {
     /// <p>Whether targets must be registered with the maintenance window before tasks can be defined for those targets.</p>
     #[serde(rename = "AllowUnassociatedTargets")]
@@ -3573,6 +5524,7 @@ pub struct GetMaintenanceWindowResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMaintenanceWindowTaskRequest {
     /// <p>The maintenance window ID that includes the task to retrieve.</p>
     #[serde(rename = "WindowId")]
@@ -3582,8 +5534,35 @@ pub struct GetMaintenanceWindowTaskRequest {
     pub window_task_id: String,
 }
 
+impl Validate for GetMaintenanceWindowTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_task_id"),
+            self.window_task_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_task_id"),
+            &self.window_task_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMaintenanceWindowTaskResult {
     /// <p>The retrieved task description.</p>
     #[serde(rename = "Description")]
@@ -3645,14 +5624,25 @@ pub struct GetMaintenanceWindowTaskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOpsItemRequest {
     /// <p>The ID of the OpsItem that you want to get.</p>
     #[serde(rename = "OpsItemId")]
     pub ops_item_id: String,
 }
 
+impl Validate for GetOpsItemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "ops_item_id"),
+            &self.ops_item_id,
+            "^(oi)-[0-9a-f]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOpsItemResponse {
     /// <p>The OpsItem.</p>
     #[serde(rename = "OpsItem")]
@@ -3661,6 +5651,7 @@ pub struct GetOpsItemResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetOpsSummaryRequest {
     /// <p>Optional aggregators that return counts of OpsItems based on one or more expressions.</p>
     #[serde(rename = "Aggregators")]
@@ -3679,8 +5670,33 @@ pub struct GetOpsSummaryRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetOpsSummaryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "aggregators"),
+            self.aggregators.len(),
+            Some(1),
+            Some(12),
+        );
+        for (i, item) in self.aggregators.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "aggregators"), i);
+            item.validate_at(&item_path, errors);
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetOpsSummaryResult {
     /// <p>The list of aggregated and filtered OpsItems.</p>
     #[serde(rename = "Entities")]
@@ -3693,6 +5709,7 @@ pub struct GetOpsSummaryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetParameterHistoryRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -3711,8 +5728,22 @@ pub struct GetParameterHistoryRequest {
     pub with_decryption: Option<bool>,
 }
 
+impl Validate for GetParameterHistoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetParameterHistoryResult {
     /// <p>The token to use when requesting the next set of items. If there are no additional items to return, the string is empty.</p>
     #[serde(rename = "NextToken")]
@@ -3725,6 +5756,7 @@ pub struct GetParameterHistoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetParameterRequest {
     /// <p>The name of the parameter you want to query.</p>
     #[serde(rename = "Name")]
@@ -3735,8 +5767,19 @@ pub struct GetParameterRequest {
     pub with_decryption: Option<bool>,
 }
 
+impl Validate for GetParameterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetParameterResult {
     /// <p>Information about a parameter.</p>
     #[serde(rename = "Parameter")]
@@ -3745,6 +5788,7 @@ pub struct GetParameterResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetParametersByPathRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -3771,8 +5815,28 @@ pub struct GetParametersByPathRequest {
     pub with_decryption: Option<bool>,
 }
 
+impl Validate for GetParametersByPathRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(10));
+        }
+        if let Some(ref value) = self.parameter_filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "parameter_filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "path"),
+            self.path.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetParametersByPathResult {
     /// <p>The token for the next set of items to return. Use this token to get the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -3785,6 +5849,7 @@ pub struct GetParametersByPathResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetParametersRequest {
     /// <p>Names of the parameters for which you want to query information.</p>
     #[serde(rename = "Names")]
@@ -3795,8 +5860,23 @@ pub struct GetParametersRequest {
     pub with_decryption: Option<bool>,
 }
 
+impl Validate for GetParametersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "names"),
+            self.names.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.names.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "names"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(2048));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetParametersResult {
     /// <p>A list of parameters that are not formatted correctly or do not run during an execution.</p>
     #[serde(rename = "InvalidParameters")]
@@ -3809,6 +5889,7 @@ pub struct GetParametersResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPatchBaselineForPatchGroupRequest {
     /// <p>Returns he operating system rule specified for patch groups using the patch baseline.</p>
     #[serde(rename = "OperatingSystem")]
@@ -3819,8 +5900,24 @@ pub struct GetPatchBaselineForPatchGroupRequest {
     pub patch_group: String,
 }
 
+impl Validate for GetPatchBaselineForPatchGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "patch_group"),
+            self.patch_group.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "patch_group"),
+            &self.patch_group,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPatchBaselineForPatchGroupResult {
     /// <p>The ID of the patch baseline that should be used for the patch group.</p>
     #[serde(rename = "BaselineId")]
@@ -3837,14 +5934,31 @@ pub struct GetPatchBaselineForPatchGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPatchBaselineRequest {
     /// <p>The ID of the patch baseline to retrieve.</p>
     #[serde(rename = "BaselineId")]
     pub baseline_id: String,
 }
 
+impl Validate for GetPatchBaselineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPatchBaselineResult {
     /// <p>A set of rules used to include patches in the baseline.</p>
     #[serde(rename = "ApprovalRules")]
@@ -3910,15 +6024,27 @@ pub struct GetPatchBaselineResult {
 
 /// <p>The request body of the GetServiceSetting API action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetServiceSettingRequest {
     /// <p>The ID of the service setting to get.</p>
     #[serde(rename = "SettingId")]
     pub setting_id: String,
 }
 
+impl Validate for GetServiceSettingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "setting_id"),
+            self.setting_id.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 /// <p>The query result body of the GetServiceSetting API action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetServiceSettingResult {
     /// <p>The query result of the current service setting.</p>
     #[serde(rename = "ServiceSetting")]
@@ -3928,7 +6054,7 @@ pub struct GetServiceSettingResult {
 
 /// <p>Status information about the aggregated associations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceAggregatedAssociationOverview {
     /// <p>Detailed status information about the aggregated associations.</p>
     #[serde(rename = "DetailedStatus")]
@@ -3943,7 +6069,7 @@ pub struct InstanceAggregatedAssociationOverview {
 
 /// <p>One or more association documents on the instance. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceAssociation {
     /// <p>The association ID.</p>
     #[serde(rename = "AssociationId")]
@@ -3972,9 +6098,17 @@ pub struct InstanceAssociationOutputLocation {
     pub s3_location: Option<S3OutputLocation>,
 }
 
+impl Validate for InstanceAssociationOutputLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_location {
+            value.validate_at(&field_path(path, "s3_location"), errors);
+        }
+    }
+}
+
 /// <p>The URL of Amazon S3 bucket where you want to store the results of this request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceAssociationOutputUrl {
     /// <p>The URL of Amazon S3 bucket where you want to store the results of this request.</p>
     #[serde(rename = "S3OutputUrl")]
@@ -3984,7 +6118,7 @@ pub struct InstanceAssociationOutputUrl {
 
 /// <p>Status information about the instance association.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceAssociationStatusInfo {
     /// <p>The association ID.</p>
     #[serde(rename = "AssociationId")]
@@ -4038,7 +6172,7 @@ pub struct InstanceAssociationStatusInfo {
 
 /// <p>Describes a filter for a specific list of instances. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceInformation {
     /// <p>The activation ID created by Systems Manager when the server or VM was registered.</p>
     #[serde(rename = "ActivationId")]
@@ -4120,6 +6254,7 @@ pub struct InstanceInformation {
 
 /// <p>Describes a filter for a specific list of instances. You can filter instances information by using tags. You specify tags by using a key-value mapping.</p> <p>Use this action instead of the <a>DescribeInstanceInformationRequest$InstanceInformationFilterList</a> method. The <code>InstanceInformationFilterList</code> method is a legacy method and does not support tags. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceInformationFilter {
     /// <p>The name of the filter. </p>
     #[serde(rename = "key")]
@@ -4129,8 +6264,24 @@ pub struct InstanceInformationFilter {
     pub value_set: Vec<String>,
 }
 
+impl Validate for InstanceInformationFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value_set"),
+            self.value_set.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.value_set.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "value_set"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 /// <p>The filters to describe or get information about your managed instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstanceInformationStringFilter {
     /// <p>The filter key name to describe your instances. For example:</p> <p>"InstanceIds"|"AgentVersion"|"PingStatus"|"PlatformTypes"|"ActivationIds"|"IamRole"|"ResourceType"|"AssociationStatus"|"Tag Key"</p>
     #[serde(rename = "Key")]
@@ -4140,9 +6291,30 @@ pub struct InstanceInformationStringFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for InstanceInformationStringFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(100),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 /// <p>Defines the high-level patch compliance state for a managed instance, providing information about the number of installed, missing, not applicable, and failed patches along with metadata about the operation when this information was gathered for the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstancePatchState {
     /// <p>The ID of the patch baseline used to patch the instance.</p>
     #[serde(rename = "BaselineId")]
@@ -4206,6 +6378,7 @@ pub struct InstancePatchState {
 
 /// <p>Defines a filter used in DescribeInstancePatchStatesForPatchGroup used to scope down the information returned by the API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InstancePatchStateFilter {
     /// <p>The key for the filter. Supported values are FailedCount, InstalledCount, InstalledOtherCount, MissingCount and NotApplicableCount.</p>
     #[serde(rename = "Key")]
@@ -4218,8 +6391,26 @@ pub struct InstancePatchStateFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for InstancePatchStateFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(1),
+        );
+    }
+}
+
 /// <p>Specifies the inventory type and attribute for the aggregation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryAggregator {
     /// <p>Nested aggregators to further refine aggregation for an inventory type.</p>
     #[serde(rename = "Aggregators")]
@@ -4235,9 +6426,41 @@ pub struct InventoryAggregator {
     pub groups: Option<Vec<InventoryGroup>>,
 }
 
+impl Validate for InventoryAggregator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aggregators {
+            errors.check_length(
+                &field_path(path, "aggregators"),
+                value.len(),
+                Some(1),
+                Some(10),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "aggregators"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.expression {
+            errors.check_length(
+                &field_path(path, "expression"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.groups {
+            errors.check_length(&field_path(path, "groups"), value.len(), Some(1), Some(15));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "groups"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>Status information returned by the <code>DeleteInventory</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryDeletionStatusItem {
     /// <p>The deletion ID returned by the <code>DeleteInventory</code> action.</p>
     #[serde(rename = "DeletionId")]
@@ -4271,7 +6494,7 @@ pub struct InventoryDeletionStatusItem {
 
 /// <p>Information about the delete operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryDeletionSummary {
     /// <p>Remaining number of items to delete.</p>
     #[serde(rename = "RemainingCount")]
@@ -4289,7 +6512,7 @@ pub struct InventoryDeletionSummary {
 
 /// <p>Either a count, remaining count, or a version number in a delete inventory summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryDeletionSummaryItem {
     /// <p>A count of the number of deleted items.</p>
     #[serde(rename = "Count")]
@@ -4307,6 +6530,7 @@ pub struct InventoryDeletionSummaryItem {
 
 /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryFilter {
     /// <p>The name of the filter key.</p>
     #[serde(rename = "Key")]
@@ -4320,8 +6544,26 @@ pub struct InventoryFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for InventoryFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(40),
+        );
+    }
+}
+
 /// <p>A user-defined set of one or more filters on which to aggregate inventory data. Groups return a count of resources that match and don't match the specified criteria.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryGroup {
     /// <p>Filters define the criteria for the group. The <code>matchingCount</code> field displays the number of resources that match the criteria. The <code>notMatchingCount</code> field displays the number of resources that don't match the criteria. </p>
     #[serde(rename = "Filters")]
@@ -4331,8 +6573,30 @@ pub struct InventoryGroup {
     pub name: String,
 }
 
+impl Validate for InventoryGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "filters"),
+            self.filters.len(),
+            Some(1),
+            Some(5),
+        );
+        for (i, item) in self.filters.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+    }
+}
+
 /// <p>Information collected from managed instances based on your inventory policy document</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InventoryItem {
     /// <p>The time the inventory information was collected.</p>
     #[serde(rename = "CaptureTime")]
@@ -4357,9 +6621,57 @@ pub struct InventoryItem {
     pub type_name: String,
 }
 
+impl Validate for InventoryItem {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "capture_time"), &self.capture_time, "^(20)[0-9][0-9]-(0[1-9]|1[012])-([12][0-9]|3[01]|0[1-9])(T)(2[0-3]|[0-1][0-9])(:[0-5][0-9])(:[0-5][0-9])(Z)$");
+        if let Some(ref value) = self.content {
+            errors.check_length(&field_path(path, "content"), value.len(), None, Some(10000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "content"), i);
+                errors.check_length(&item_path, item.len(), None, Some(50));
+                for (key2, item2) in item.iter() {
+                    let item_path2 = format!("{}[{}]", item_path, key2);
+                    errors.check_length(&item_path2, item2.chars().count(), None, Some(4096));
+                }
+            }
+        }
+        if let Some(ref value) = self.content_hash {
+            errors.check_length(
+                &field_path(path, "content_hash"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.context {
+            errors.check_length(&field_path(path, "context"), value.len(), None, Some(50));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "context"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(4096));
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "schema_version"),
+            &self.schema_version,
+            "^([0-9]{1,6})(\\.[0-9]{1,6})$",
+        );
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "^(AWS|Custom):.*$",
+        );
+    }
+}
+
 /// <p>Attributes are the entries within the inventory item content. It contains name and value.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryItemAttribute {
     /// <p>The data type of the inventory item attribute. </p>
     #[serde(rename = "DataType")]
@@ -4371,7 +6683,7 @@ pub struct InventoryItemAttribute {
 
 /// <p>The inventory item schema definition. Users can use this to compose inventory query filters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryItemSchema {
     /// <p>The schema attributes for inventory. This contains data type and attribute name.</p>
     #[serde(rename = "Attributes")]
@@ -4391,7 +6703,7 @@ pub struct InventoryItemSchema {
 
 /// <p>Inventory query results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryResultEntity {
     /// <p>The data section in the inventory result entity JSON.</p>
     #[serde(rename = "Data")]
@@ -4405,7 +6717,7 @@ pub struct InventoryResultEntity {
 
 /// <p>The inventory result item.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InventoryResultItem {
     /// <p>The time inventory item data was captured.</p>
     #[serde(rename = "CaptureTime")]
@@ -4427,6 +6739,7 @@ pub struct InventoryResultItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct LabelParameterVersionRequest {
     /// <p>One or more labels to attach to the specified parameter version.</p>
     #[serde(rename = "Labels")]
@@ -4440,8 +6753,29 @@ pub struct LabelParameterVersionRequest {
     pub parameter_version: Option<i64>,
 }
 
+impl Validate for LabelParameterVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "labels"),
+            self.labels.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.labels.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "labels"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LabelParameterVersionResult {
     /// <p>The label does not meet the requirements. For information about parameter label requirements, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-paramstore-labels.html">Labeling Parameters</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     #[serde(rename = "InvalidLabels")]
@@ -4450,6 +6784,7 @@ pub struct LabelParameterVersionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssociationVersionsRequest {
     /// <p>The association ID for which you want to view all versions.</p>
     #[serde(rename = "AssociationId")]
@@ -4464,8 +6799,21 @@ pub struct ListAssociationVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssociationVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "association_id"),
+            &self.association_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssociationVersionsResult {
     /// <p>Information about all versions of the association for the specified association ID.</p>
     #[serde(rename = "AssociationVersions")]
@@ -4478,6 +6826,7 @@ pub struct ListAssociationVersionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAssociationsRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "AssociationFilterList")]
@@ -4493,8 +6842,28 @@ pub struct ListAssociationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAssociationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.association_filter_list {
+            errors.check_length(
+                &field_path(path, "association_filter_list"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "association_filter_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAssociationsResult {
     /// <p>The associations.</p>
     #[serde(rename = "Associations")]
@@ -4507,6 +6876,7 @@ pub struct ListAssociationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCommandInvocationsRequest {
     /// <p>(Optional) The invocations for a specific command ID.</p>
     #[serde(rename = "CommandId")]
@@ -4534,8 +6904,38 @@ pub struct ListCommandInvocationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCommandInvocationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.command_id {
+            errors.check_length(
+                &field_path(path, "command_id"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCommandInvocationsResult {
     /// <p>(Optional) A list of all invocations. </p>
     #[serde(rename = "CommandInvocations")]
@@ -4548,6 +6948,7 @@ pub struct ListCommandInvocationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListCommandsRequest {
     /// <p>(Optional) If provided, lists only the specified command.</p>
     #[serde(rename = "CommandId")]
@@ -4571,8 +6972,38 @@ pub struct ListCommandsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListCommandsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.command_id {
+            errors.check_length(
+                &field_path(path, "command_id"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.instance_id {
+            errors.check_pattern(
+                &field_path(path, "instance_id"),
+                &value,
+                "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListCommandsResult {
     /// <p>(Optional) The list of commands requested by the user. </p>
     #[serde(rename = "Commands")]
@@ -4585,6 +7016,7 @@ pub struct ListCommandsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListComplianceItemsRequest {
     /// <p>One or more compliance filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "Filters")]
@@ -4608,8 +7040,46 @@ pub struct ListComplianceItemsRequest {
     pub resource_types: Option<Vec<String>>,
 }
 
+impl Validate for ListComplianceItemsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        if let Some(ref value) = self.resource_ids {
+            errors.check_length(
+                &field_path(path, "resource_ids"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+        if let Some(ref value) = self.resource_types {
+            errors.check_length(
+                &field_path(path, "resource_types"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "resource_types"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(50));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListComplianceItemsResult {
     /// <p>A list of compliance information for the specified resource ID. </p>
     #[serde(rename = "ComplianceItems")]
@@ -4622,6 +7092,7 @@ pub struct ListComplianceItemsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListComplianceSummariesRequest {
     /// <p>One or more compliance or inventory filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "Filters")]
@@ -4637,8 +7108,22 @@ pub struct ListComplianceSummariesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListComplianceSummariesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListComplianceSummariesResult {
     /// <p>A list of compliant and non-compliant summary counts based on compliance types. For example, this call returns State Manager associations, patches, or custom compliance types according to the filter criteria that you specified.</p>
     #[serde(rename = "ComplianceSummaryItems")]
@@ -4651,6 +7136,7 @@ pub struct ListComplianceSummariesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDocumentVersionsRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -4665,8 +7151,21 @@ pub struct ListDocumentVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDocumentVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDocumentVersionsResult {
     /// <p>The document versions.</p>
     #[serde(rename = "DocumentVersions")]
@@ -4679,6 +7178,7 @@ pub struct ListDocumentVersionsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDocumentsRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "DocumentFilterList")]
@@ -4698,8 +7198,35 @@ pub struct ListDocumentsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDocumentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document_filter_list {
+            errors.check_length(
+                &field_path(path, "document_filter_list"),
+                value.len(),
+                Some(1),
+                None,
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "document_filter_list"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), None, Some(6));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDocumentsResult {
     /// <p>The names of the Systems Manager documents.</p>
     #[serde(rename = "DocumentIdentifiers")]
@@ -4712,6 +7239,7 @@ pub struct ListDocumentsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListInventoryEntriesRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "Filters")]
@@ -4733,8 +7261,39 @@ pub struct ListInventoryEntriesRequest {
     pub type_name: String,
 }
 
+impl Validate for ListInventoryEntriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "^(AWS|Custom):.*$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListInventoryEntriesResult {
     /// <p>The time that inventory information was collected for the instance(s).</p>
     #[serde(rename = "CaptureTime")]
@@ -4763,6 +7322,7 @@ pub struct ListInventoryEntriesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceComplianceSummariesRequest {
     /// <p>One or more filters. Use a filter to return a more specific list of results.</p>
     #[serde(rename = "Filters")]
@@ -4778,8 +7338,22 @@ pub struct ListResourceComplianceSummariesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListResourceComplianceSummariesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.filters {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceComplianceSummariesResult {
     /// <p>The token for the next set of items to return. Use this token to get the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -4792,6 +7366,7 @@ pub struct ListResourceComplianceSummariesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceDataSyncRequest {
     /// <p>The maximum number of items to return for this call. The call also returns a token that you can specify in a subsequent call to get the next set of results.</p>
     #[serde(rename = "MaxResults")]
@@ -4803,8 +7378,16 @@ pub struct ListResourceDataSyncRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListResourceDataSyncRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(50));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceDataSyncResult {
     /// <p>The token for the next set of items to return. Use this token to get the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -4817,6 +7400,7 @@ pub struct ListResourceDataSyncResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>The resource ID for which you want to see a list of tags.</p>
     #[serde(rename = "ResourceId")]
@@ -4827,7 +7411,7 @@ pub struct ListTagsForResourceRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResult {
     /// <p>A list of tags.</p>
     #[serde(rename = "TagList")]
@@ -4850,6 +7434,31 @@ pub struct LoggingInfo {
     pub s3_region: String,
 }
 
+impl Validate for LoggingInfo {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "s3_bucket_name"),
+            self.s3_bucket_name.chars().count(),
+            Some(3),
+            Some(63),
+        );
+        if let Some(ref value) = self.s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "s3_region"),
+            self.s3_region.chars().count(),
+            Some(3),
+            Some(20),
+        );
+    }
+}
+
 /// <p>The parameters for an AUTOMATION task type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MaintenanceWindowAutomationParameters {
@@ -4863,9 +7472,37 @@ pub struct MaintenanceWindowAutomationParameters {
     pub parameters: Option<::std::collections::HashMap<String, Vec<String>>>,
 }
 
+impl Validate for MaintenanceWindowAutomationParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            errors.check_length(
+                &field_path(path, "parameters"),
+                value.len(),
+                Some(1),
+                Some(200),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.len(), None, Some(50));
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), Some(1), Some(512));
+                }
+            }
+        }
+    }
+}
+
 /// <p>Describes the information about an execution of a maintenance window. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowExecution {
     /// <p>The time the execution finished.</p>
     #[serde(rename = "EndTime")]
@@ -4895,7 +7532,7 @@ pub struct MaintenanceWindowExecution {
 
 /// <p>Information about a task execution performed as part of a maintenance window execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowExecutionTaskIdentity {
     /// <p>The time the task execution finished.</p>
     #[serde(rename = "EndTime")]
@@ -4933,7 +7570,7 @@ pub struct MaintenanceWindowExecutionTaskIdentity {
 
 /// <p>Describes the information about a task invocation for a particular target as part of a task execution performed as part of a maintenance window execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowExecutionTaskInvocationIdentity {
     /// <p>The time the invocation finished.</p>
     #[serde(rename = "EndTime")]
@@ -4987,6 +7624,7 @@ pub struct MaintenanceWindowExecutionTaskInvocationIdentity {
 
 /// <p>Filter used in the request. Supported filter keys are Name and Enabled.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct MaintenanceWindowFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
@@ -4998,9 +7636,28 @@ pub struct MaintenanceWindowFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for MaintenanceWindowFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>Information about the maintenance window.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowIdentity {
     /// <p>The number of hours before the end of the maintenance window that Systems Manager stops scheduling new tasks for execution.</p>
     #[serde(rename = "Cutoff")]
@@ -5050,7 +7707,7 @@ pub struct MaintenanceWindowIdentity {
 
 /// <p>The maintenance window to which the specified target belongs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowIdentityForTarget {
     /// <p>The name of the maintenance window.</p>
     #[serde(rename = "Name")]
@@ -5084,6 +7741,30 @@ pub struct MaintenanceWindowLambdaParameters {
     pub qualifier: Option<String>,
 }
 
+impl Validate for MaintenanceWindowLambdaParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_context {
+            errors.check_length(
+                &field_path(path, "client_context"),
+                value.chars().count(),
+                Some(1),
+                Some(8000),
+            );
+        }
+        if let Some(ref value) = self.payload {
+            errors.check_length(&field_path(path, "payload"), value.len(), None, Some(4096));
+        }
+        if let Some(ref value) = self.qualifier {
+            errors.check_length(
+                &field_path(path, "qualifier"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p><p>The parameters for a RUN_COMMAND task type.</p> <p>For information about specifying and updating task parameters, see <a>RegisterTaskWithMaintenanceWindow</a> and <a>UpdateMaintenanceWindowTask</a>.</p> <note> <p> <code>LoggingInfo</code> has been deprecated. To specify an S3 bucket to contain logs, instead use the <code>OutputS3BucketName</code> and <code>OutputS3KeyPrefix</code> options in the <code>TaskInvocationParameters</code> structure. For information about how Systems Manager handles these options for the supported maintenance window task types, see <a>MaintenanceWindowTaskInvocationParameters</a>.</p> <p> <code>TaskParameters</code> has been deprecated. To specify parameters to pass to a task when it runs, instead use the <code>Parameters</code> option in the <code>TaskInvocationParameters</code> structure. For information about how Systems Manager handles these options for the supported maintenance window task types, see <a>MaintenanceWindowTaskInvocationParameters</a>.</p> <p>For Run Command tasks, Systems Manager uses specified values for <code>TaskParameters</code> and <code>LoggingInfo</code> only if no values are specified for <code>TaskInvocationParameters</code>. </p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MaintenanceWindowRunCommandParameters {
@@ -5125,6 +7806,51 @@ pub struct MaintenanceWindowRunCommandParameters {
     pub timeout_seconds: Option<i64>,
 }
 
+impl Validate for MaintenanceWindowRunCommandParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.document_hash {
+            errors.check_length(
+                &field_path(path, "document_hash"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.output_s3_bucket_name {
+            errors.check_length(
+                &field_path(path, "output_s3_bucket_name"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.output_s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "output_s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.timeout_seconds {
+            errors.check_range(
+                &field_path(path, "timeout_seconds"),
+                *value,
+                Some(30),
+                Some(2592000),
+            );
+        }
+    }
+}
+
 /// <p><p>The parameters for a STEP_FUNCTIONS task.</p> <p>For information about specifying and updating task parameters, see <a>RegisterTaskWithMaintenanceWindow</a> and <a>UpdateMaintenanceWindowTask</a>.</p> <note> <p> <code>LoggingInfo</code> has been deprecated. To specify an S3 bucket to contain logs, instead use the <code>OutputS3BucketName</code> and <code>OutputS3KeyPrefix</code> options in the <code>TaskInvocationParameters</code> structure. For information about how Systems Manager handles these options for the supported maintenance window task types, see <a>MaintenanceWindowTaskInvocationParameters</a>.</p> <p> <code>TaskParameters</code> has been deprecated. To specify parameters to pass to a task when it runs, instead use the <code>Parameters</code> option in the <code>TaskInvocationParameters</code> structure. For information about how Systems Manager handles these options for the supported maintenance window task types, see <a>MaintenanceWindowTaskInvocationParameters</a>.</p> <p>For Step Functions tasks, Systems Manager ignores any values specified for <code>TaskParameters</code> and <code>LoggingInfo</code>.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MaintenanceWindowStepFunctionsParameters {
@@ -5138,9 +7864,30 @@ pub struct MaintenanceWindowStepFunctionsParameters {
     pub name: Option<String>,
 }
 
+impl Validate for MaintenanceWindowStepFunctionsParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(80),
+            );
+        }
+    }
+}
+
 /// <p>The target registered with the maintenance window.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowTarget {
     /// <p>A description for the target.</p>
     #[serde(rename = "Description")]
@@ -5174,7 +7921,7 @@ pub struct MaintenanceWindowTarget {
 
 /// <p>Information about a task defined for a maintenance window.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MaintenanceWindowTask {
     /// <p>A description of the task.</p>
     #[serde(rename = "Description")]
@@ -5252,6 +7999,23 @@ pub struct MaintenanceWindowTaskInvocationParameters {
     pub step_functions: Option<MaintenanceWindowStepFunctionsParameters>,
 }
 
+impl Validate for MaintenanceWindowTaskInvocationParameters {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.automation {
+            value.validate_at(&field_path(path, "automation"), errors);
+        }
+        if let Some(ref value) = self.lambda {
+            value.validate_at(&field_path(path, "lambda"), errors);
+        }
+        if let Some(ref value) = self.run_command {
+            value.validate_at(&field_path(path, "run_command"), errors);
+        }
+        if let Some(ref value) = self.step_functions {
+            value.validate_at(&field_path(path, "step_functions"), errors);
+        }
+    }
+}
+
 /// <p>Defines the values for a task parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MaintenanceWindowTaskParameterValueExpression {
@@ -5261,7 +8025,19 @@ pub struct MaintenanceWindowTaskParameterValueExpression {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for MaintenanceWindowTaskParameterValueExpression {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(255));
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyDocumentPermissionRequest {
     /// <p>The AWS user accounts that should have access to the document. The account IDs can either be a group of account IDs or <i>All</i>.</p>
     #[serde(rename = "AccountIdsToAdd")]
@@ -5279,13 +8055,47 @@ pub struct ModifyDocumentPermissionRequest {
     pub permission_type: String,
 }
 
+impl Validate for ModifyDocumentPermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.account_ids_to_add {
+            errors.check_length(
+                &field_path(path, "account_ids_to_add"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "account_ids_to_add"), i);
+                errors.check_pattern(&item_path, &item, "(?i)all|[0-9]{12}");
+            }
+        }
+        if let Some(ref value) = self.account_ids_to_remove {
+            errors.check_length(
+                &field_path(path, "account_ids_to_remove"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "account_ids_to_remove"), i);
+                errors.check_pattern(&item_path, &item, "(?i)all|[0-9]{12}");
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyDocumentPermissionResponse {}
 
 /// <p>A summary of resources that are not compliant. The summary is organized according to resource type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NonCompliantSummary {
     /// <p>The total number of compliance items that are not compliant.</p>
     #[serde(rename = "NonCompliantCount")]
@@ -5316,6 +8126,7 @@ pub struct NotificationConfig {
 
 /// <p>One or more aggregators for viewing counts of OpsItems using different dimensions such as <code>Source</code>, <code>CreatedTime</code>, or <code>Source and CreatedTime</code>, to name a few.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OpsAggregator {
     /// <p>Either a Range or Count aggregator for limiting an OpsItem summary.</p>
     #[serde(rename = "AggregatorType")]
@@ -5343,9 +8154,70 @@ pub struct OpsAggregator {
     pub values: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl Validate for OpsAggregator {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.aggregator_type {
+            errors.check_length(
+                &field_path(path, "aggregator_type"),
+                value.chars().count(),
+                Some(1),
+                Some(20),
+            );
+            errors.check_pattern(
+                &field_path(path, "aggregator_type"),
+                &value,
+                "^(range|count|sum)",
+            );
+        }
+        if let Some(ref value) = self.aggregators {
+            errors.check_length(
+                &field_path(path, "aggregators"),
+                value.len(),
+                Some(1),
+                Some(12),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "aggregators"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.attribute_name {
+            errors.check_length(
+                &field_path(path, "attribute_name"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.filters {
+            errors.check_length(&field_path(path, "filters"), value.len(), Some(1), Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "filters"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.type_name {
+            errors.check_length(
+                &field_path(path, "type_name"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+            errors.check_pattern(&field_path(path, "type_name"), &value, "^(AWS|Custom):.*$");
+        }
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), None, Some(5));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), key);
+                errors.check_length(&item_path, item.chars().count(), None, Some(2048));
+            }
+        }
+    }
+}
+
 /// <p>The result of the query.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OpsEntity {
     /// <p>The data returned by the query.</p>
     #[serde(rename = "Data")]
@@ -5359,7 +8231,7 @@ pub struct OpsEntity {
 
 /// <p>The OpsItem summaries result item.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OpsEntityItem {
     /// <p>The detailed data content for an OpsItem summaries result item.</p>
     #[serde(rename = "Content")]
@@ -5369,6 +8241,7 @@ pub struct OpsEntityItem {
 
 /// <p>A filter for viewing OpsItem summaries.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OpsFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
@@ -5382,9 +8255,30 @@ pub struct OpsFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for OpsFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(40),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(256));
+        }
+    }
+}
+
 /// <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OpsItem {
     /// <p>The ARN of the AWS account that created the OpsItem.</p>
     #[serde(rename = "CreatedBy")]
@@ -5457,8 +8351,17 @@ pub struct OpsItemDataValue {
     pub value: Option<String>,
 }
 
+impl Validate for OpsItemDataValue {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.value {
+            errors.check_pattern(&field_path(path, "value"), &value, "[\\s\\S]*\\S[\\s\\S]*");
+        }
+    }
+}
+
 /// <p>Describes an OpsItem filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct OpsItemFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
@@ -5482,7 +8385,7 @@ pub struct OpsItemNotification {
 
 /// <p>A count of OpsItems.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OpsItemSummary {
     /// <p>The Amazon Resource Name (ARN) of the IAM entity that created the OpsItem.</p>
     #[serde(rename = "CreatedBy")]
@@ -5528,7 +8431,7 @@ pub struct OpsItemSummary {
 
 /// <p>Information about the source where the association execution details are stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OutputSource {
     /// <p>The ID of the output source, for example the URL of an Amazon S3 bucket.</p>
     #[serde(rename = "OutputSourceId")]
@@ -5542,7 +8445,7 @@ pub struct OutputSource {
 
 /// <p>An Amazon EC2 Systems Manager parameter in Parameter Store.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Parameter {
     /// <p>The Amazon Resource Name (ARN) of the parameter.</p>
     #[serde(rename = "ARN")]
@@ -5580,7 +8483,7 @@ pub struct Parameter {
 
 /// <p>Information about parameter usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterHistory {
     /// <p>Parameter names can include the following letters and symbols.</p> <p>a-zA-Z0-9_.-</p>
     #[serde(rename = "AllowedPattern")]
@@ -5634,7 +8537,7 @@ pub struct ParameterHistory {
 
 /// <p>One or more policies assigned to a parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterInlinePolicy {
     /// <p>The status of the policy. Policies report the following statuses: Pending (the policy has not been enforced or applied yet), Finished (the policy was applied), Failed (the policy was not applied), or InProgress (the policy is being applied now). </p>
     #[serde(rename = "PolicyStatus")]
@@ -5652,7 +8555,7 @@ pub struct ParameterInlinePolicy {
 
 /// <p>Metadata includes information like the ARN of the last user and the date/time the parameter was last used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ParameterMetadata {
     /// <p>A parameter name can include only the following letters and symbols.</p> <p>a-zA-Z0-9_.-</p>
     #[serde(rename = "AllowedPattern")]
@@ -5698,6 +8601,7 @@ pub struct ParameterMetadata {
 
 /// <p><p>One or more filters. Use a filter to return a more specific list of results.</p> <note> <p>The <code>Name</code> and <code>Tier</code> filter keys can&#39;t be used with the <a>GetParametersByPath</a> API action. Also, the <code>Label</code> filter key can&#39;t be used with the <a>DescribeParameters</a> API action.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParameterStringFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
@@ -5712,8 +8616,40 @@ pub struct ParameterStringFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for ParameterStringFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(132),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "tag:.+|Name|Type|KeyId|Path|Label|Tier|DataType",
+        );
+        if let Some(ref value) = self.option {
+            errors.check_length(
+                &field_path(path, "option"),
+                value.chars().count(),
+                Some(1),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+    }
+}
+
 /// <p>This data type is deprecated. Instead, use <a>ParameterStringFilter</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ParametersFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "Key")]
@@ -5723,9 +8659,24 @@ pub struct ParametersFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for ParametersFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+        }
+    }
+}
+
 /// <p>Represents metadata about a patch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Patch {
     /// <p>The classification of the patch (for example, SecurityUpdates, Updates, CriticalUpdates).</p>
     #[serde(rename = "Classification")]
@@ -5783,7 +8734,7 @@ pub struct Patch {
 
 /// <p>Defines the basic information about a patch baseline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PatchBaselineIdentity {
     /// <p>The description of the patch baseline.</p>
     #[serde(rename = "BaselineDescription")]
@@ -5809,7 +8760,7 @@ pub struct PatchBaselineIdentity {
 
 /// <p>Information about the state of a patch on a particular instance as it relates to the patch baseline used to patch the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PatchComplianceData {
     /// <p>The classification of the patch (for example, SecurityUpdates, Updates, CriticalUpdates).</p>
     #[serde(rename = "Classification")]
@@ -5842,6 +8793,21 @@ pub struct PatchFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for PatchFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+        }
+    }
+}
+
 /// <p>A set of patch filters, typically used for approval rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PatchFilterGroup {
@@ -5850,9 +8816,24 @@ pub struct PatchFilterGroup {
     pub patch_filters: Vec<PatchFilter>,
 }
 
+impl Validate for PatchFilterGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "patch_filters"),
+            self.patch_filters.len(),
+            None,
+            Some(4),
+        );
+        for (i, item) in self.patch_filters.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "patch_filters"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>The mapping between a patch group and the patch baseline the patch group is registered with.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PatchGroupPatchBaselineMapping {
     /// <p>The patch baseline the patch group is registered with.</p>
     #[serde(rename = "BaselineIdentity")]
@@ -5866,6 +8847,7 @@ pub struct PatchGroupPatchBaselineMapping {
 
 /// <p>Defines a filter used in Patch Manager APIs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PatchOrchestratorFilter {
     /// <p>The key for the filter.</p>
     #[serde(rename = "Key")]
@@ -5877,6 +8859,25 @@ pub struct PatchOrchestratorFilter {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for PatchOrchestratorFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.values {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "values"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            }
+        }
+    }
+}
+
 /// <p>Defines an approval rule for a patch baseline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PatchRule {
@@ -5896,6 +8897,19 @@ pub struct PatchRule {
     pub patch_filter_group: PatchFilterGroup,
 }
 
+impl Validate for PatchRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "approve_after_days"),
+            self.approve_after_days,
+            None,
+            Some(360),
+        );
+        self.patch_filter_group
+            .validate_at(&field_path(path, "patch_filter_group"), errors);
+    }
+}
+
 /// <p>A set of rules defining the approval rules for a patch baseline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PatchRuleGroup {
@@ -5904,6 +8918,21 @@ pub struct PatchRuleGroup {
     pub patch_rules: Vec<PatchRule>,
 }
 
+impl Validate for PatchRuleGroup {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "patch_rules"),
+            self.patch_rules.len(),
+            None,
+            Some(10),
+        );
+        for (i, item) in self.patch_rules.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "patch_rules"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Information about the patches to use to update the instances, including target operating systems and source repository. Applies to Linux instances only.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PatchSource {
@@ -5918,9 +8947,35 @@ pub struct PatchSource {
     pub products: Vec<String>,
 }
 
+impl Validate for PatchSource {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "configuration"),
+            self.configuration.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,50}$",
+        );
+        errors.check_length(
+            &field_path(path, "products"),
+            self.products.len(),
+            Some(1),
+            Some(20),
+        );
+        for (i, item) in self.products.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "products"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// <p>Information about the approval status of a patch.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PatchStatus {
     /// <p>The date the patch was approved (or will be approved if the status is PENDING_APPROVAL).</p>
     #[serde(rename = "ApprovalDate")]
@@ -5938,7 +8993,7 @@ pub struct PatchStatus {
 
 /// <p>An aggregate of step execution statuses displayed in the AWS Console for a multi-Region and multi-account Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ProgressCounters {
     /// <p>The total number of steps that the system cancelled in all specified AWS Regions and accounts for the current Automation execution.</p>
     #[serde(rename = "CancelledSteps")]
@@ -5963,6 +9018,7 @@ pub struct ProgressCounters {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutComplianceItemsRequest {
     /// <p>Specify the compliance type. For example, specify Association (for a State Manager association), Patch, or Custom:<code>string</code>.</p>
     #[serde(rename = "ComplianceType")]
@@ -5985,11 +9041,60 @@ pub struct PutComplianceItemsRequest {
     pub resource_type: String,
 }
 
+impl Validate for PutComplianceItemsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "compliance_type"),
+            self.compliance_type.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "compliance_type"),
+            &self.compliance_type,
+            "[A-Za-z0-9_\\-]\\w+|Custom:[a-zA-Z0-9_\\-]\\w+",
+        );
+        self.execution_summary
+            .validate_at(&field_path(path, "execution_summary"), errors);
+        if let Some(ref value) = self.item_content_hash {
+            errors.check_length(
+                &field_path(path, "item_content_hash"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "items"),
+            self.items.len(),
+            None,
+            Some(10000),
+        );
+        for (i, item) in self.items.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "items"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "resource_type"),
+            self.resource_type.chars().count(),
+            Some(1),
+            Some(50),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutComplianceItemsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutInventoryRequest {
     /// <p>One or more instance IDs where you want to add or update inventory items.</p>
     #[serde(rename = "InstanceId")]
@@ -5999,8 +9104,28 @@ pub struct PutInventoryRequest {
     pub items: Vec<InventoryItem>,
 }
 
+impl Validate for PutInventoryRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        errors.check_length(
+            &field_path(path, "items"),
+            self.items.len(),
+            Some(1),
+            Some(30),
+        );
+        for (i, item) in self.items.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "items"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutInventoryResult {
     /// <p>Information about the request.</p>
     #[serde(rename = "Message")]
@@ -6009,6 +9134,7 @@ pub struct PutInventoryResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutParameterRequest {
     /// <p>A regular expression used to validate the parameter value. For example, for String types with values restricted to numbers, you can specify the following: AllowedPattern=^\d+$ </p>
     #[serde(rename = "AllowedPattern")]
@@ -6049,8 +9175,59 @@ pub struct PutParameterRequest {
     pub value: String,
 }
 
+impl Validate for PutParameterRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.allowed_pattern {
+            errors.check_length(
+                &field_path(path, "allowed_pattern"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.key_id {
+            errors.check_length(
+                &field_path(path, "key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "key_id"), &value, "^([a-zA-Z0-9:/_-]+)$");
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.policies {
+            errors.check_length(
+                &field_path(path, "policies"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(1000));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutParameterResult {
     /// <p>The new version number of a parameter. If you edit a parameter value, Parameter Store automatically creates a new version and assigns this new version a unique ID. You can reference a parameter version ID in API actions or in Systems Manager documents (SSM documents). By default, if you don't specify a specific version, the system returns the latest parameter value when a parameter is called.</p>
     #[serde(rename = "Version")]
@@ -6059,14 +9236,31 @@ pub struct PutParameterResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterDefaultPatchBaselineRequest {
     /// <p>The ID of the patch baseline that should be the default patch baseline.</p>
     #[serde(rename = "BaselineId")]
     pub baseline_id: String,
 }
 
+impl Validate for RegisterDefaultPatchBaselineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterDefaultPatchBaselineResult {
     /// <p>The ID of the default patch baseline.</p>
     #[serde(rename = "BaselineId")]
@@ -6075,6 +9269,7 @@ pub struct RegisterDefaultPatchBaselineResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterPatchBaselineForPatchGroupRequest {
     /// <p>The ID of the patch baseline to register the patch group with.</p>
     #[serde(rename = "BaselineId")]
@@ -6084,8 +9279,35 @@ pub struct RegisterPatchBaselineForPatchGroupRequest {
     pub patch_group: String,
 }
 
+impl Validate for RegisterPatchBaselineForPatchGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+        errors.check_length(
+            &field_path(path, "patch_group"),
+            self.patch_group.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "patch_group"),
+            &self.patch_group,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterPatchBaselineForPatchGroupResult {
     /// <p>The ID of the patch baseline the patch group was registered with.</p>
     #[serde(rename = "BaselineId")]
@@ -6098,6 +9320,7 @@ pub struct RegisterPatchBaselineForPatchGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterTargetWithMaintenanceWindowRequest {
     /// <p>User-provided idempotency token.</p>
     #[serde(rename = "ClientToken")]
@@ -6126,8 +9349,71 @@ pub struct RegisterTargetWithMaintenanceWindowRequest {
     pub window_id: String,
 }
 
+impl Validate for RegisterTargetWithMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.owner_information {
+            errors.check_length(
+                &field_path(path, "owner_information"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "targets"),
+            self.targets.len(),
+            None,
+            Some(5),
+        );
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterTargetWithMaintenanceWindowResult {
     /// <p>The ID of the target definition in this maintenance window.</p>
     #[serde(rename = "WindowTargetId")]
@@ -6136,6 +9422,7 @@ pub struct RegisterTargetWithMaintenanceWindowResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterTaskWithMaintenanceWindowRequest {
     /// <p>User-provided idempotency token.</p>
     #[serde(rename = "ClientToken")]
@@ -6190,8 +9477,103 @@ pub struct RegisterTaskWithMaintenanceWindowRequest {
     pub window_id: String,
 }
 
+impl Validate for RegisterTaskWithMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.logging_info {
+            value.validate_at(&field_path(path, "logging_info"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "max_concurrency"),
+            self.max_concurrency.chars().count(),
+            Some(1),
+            Some(7),
+        );
+        errors.check_pattern(
+            &field_path(path, "max_concurrency"),
+            &self.max_concurrency,
+            "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+        );
+        errors.check_length(
+            &field_path(path, "max_errors"),
+            self.max_errors.chars().count(),
+            Some(1),
+            Some(7),
+        );
+        errors.check_pattern(
+            &field_path(path, "max_errors"),
+            &self.max_errors,
+            "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "targets"),
+            self.targets.len(),
+            None,
+            Some(5),
+        );
+        for (i, item) in self.targets.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "task_arn"),
+            self.task_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        if let Some(ref value) = self.task_invocation_parameters {
+            value.validate_at(&field_path(path, "task_invocation_parameters"), errors);
+        }
+        if let Some(ref value) = self.task_parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "task_parameters"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterTaskWithMaintenanceWindowResult {
     /// <p>The ID of the task in the maintenance window.</p>
     #[serde(rename = "WindowTaskId")]
@@ -6208,6 +9590,7 @@ pub struct RelatedOpsItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceRequest {
     /// <p><p>The ID of the resource from which you want to remove tags. For example:</p> <p>ManagedInstance: mi-012345abcde</p> <p>MaintenanceWindow: mw-012345abcde</p> <p>PatchBaseline: pb-012345abcde</p> <p>For the Document and Parameter values, use the name of the resource.</p> <note> <p>The ManagedInstance type for this API action is only for on-premises managed instances. Specify the name of the managed instance in the following format: mi-ID_number. For example, mi-1a2b3c4d5e6f.</p> </note></p>
     #[serde(rename = "ResourceId")]
@@ -6220,21 +9603,43 @@ pub struct RemoveTagsFromResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for RemoveTagsFromResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceResult {}
 
 /// <p>The request body of the ResetServiceSetting API action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetServiceSettingRequest {
     /// <p>The ID of the service setting to reset.</p>
     #[serde(rename = "SettingId")]
     pub setting_id: String,
 }
 
+impl Validate for ResetServiceSettingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "setting_id"),
+            self.setting_id.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+    }
+}
+
 /// <p>The result body of the ResetServiceSetting API action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetServiceSettingResult {
     /// <p>The current, effective service setting after calling the ResetServiceSetting API action.</p>
     #[serde(rename = "ServiceSetting")]
@@ -6244,7 +9649,7 @@ pub struct ResetServiceSettingResult {
 
 /// <p>Information about targets that resolved during the Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResolvedTargets {
     /// <p>A list of parameter values sent to targets that resolved during the Automation execution.</p>
     #[serde(rename = "ParameterValues")]
@@ -6258,7 +9663,7 @@ pub struct ResolvedTargets {
 
 /// <p>Compliance summary information for a specific resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceComplianceSummaryItem {
     /// <p>The compliance type.</p>
     #[serde(rename = "ComplianceType")]
@@ -6296,7 +9701,7 @@ pub struct ResourceComplianceSummaryItem {
 
 /// <p>Information about a Resource Data Sync configuration, including its current status and last successful sync.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceDataSyncItem {
     /// <p>The status reported by the last sync.</p>
     #[serde(rename = "LastStatus")]
@@ -6350,23 +9755,86 @@ pub struct ResourceDataSyncS3Destination {
     pub sync_format: String,
 }
 
+impl Validate for ResourceDataSyncS3Destination {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.awskms_key_arn {
+            errors.check_length(
+                &field_path(path, "awskms_key_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "awskms_key_arn"), &value, "arn:.*");
+        }
+        errors.check_length(
+            &field_path(path, "bucket_name"),
+            self.bucket_name.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.prefix {
+            errors.check_length(
+                &field_path(path, "prefix"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "region"),
+            self.region.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>The inventory item result attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResultAttribute {
     /// <p>Name of the inventory item type. Valid value: AWS:InstanceInformation. Default Value: AWS:InstanceInformation.</p>
     #[serde(rename = "TypeName")]
     pub type_name: String,
 }
 
+impl Validate for ResultAttribute {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "type_name"),
+            self.type_name.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "type_name"),
+            &self.type_name,
+            "^(AWS|Custom):.*$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResumeSessionRequest {
     /// <p>The ID of the disconnected session to resume.</p>
     #[serde(rename = "SessionId")]
     pub session_id: String,
 }
 
+impl Validate for ResumeSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "session_id"),
+            self.session_id.chars().count(),
+            Some(1),
+            Some(96),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResumeSessionResponse {
     /// <p>The ID of the session.</p>
     #[serde(rename = "SessionId")]
@@ -6399,9 +9867,38 @@ pub struct S3OutputLocation {
     pub output_s3_region: Option<String>,
 }
 
+impl Validate for S3OutputLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.output_s3_bucket_name {
+            errors.check_length(
+                &field_path(path, "output_s3_bucket_name"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.output_s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "output_s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.output_s3_region {
+            errors.check_length(
+                &field_path(path, "output_s3_region"),
+                value.chars().count(),
+                Some(3),
+                Some(20),
+            );
+        }
+    }
+}
+
 /// <p>A URL for the Amazon S3 bucket where you want to store the results of this request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct S3OutputUrl {
     /// <p>A URL for an Amazon S3 bucket where you want to store the results of this request.</p>
     #[serde(rename = "OutputUrl")]
@@ -6411,7 +9908,7 @@ pub struct S3OutputUrl {
 
 /// <p>Information about a scheduled execution for a maintenance window.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduledWindowExecution {
     /// <p>The time, in ISO-8601 Extended format, that the maintenance window is scheduled to be run.</p>
     #[serde(rename = "ExecutionTime")]
@@ -6428,6 +9925,7 @@ pub struct ScheduledWindowExecution {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendAutomationSignalRequest {
     /// <p>The unique identifier for an existing Automation execution that you want to send the signal to.</p>
     #[serde(rename = "AutomationExecutionId")]
@@ -6441,11 +9939,39 @@ pub struct SendAutomationSignalRequest {
     pub signal_type: String,
 }
 
+impl Validate for SendAutomationSignalRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "automation_execution_id"),
+            self.automation_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        if let Some(ref value) = self.payload {
+            errors.check_length(
+                &field_path(path, "payload"),
+                value.len(),
+                Some(1),
+                Some(200),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "payload"), key);
+                errors.check_length(&item_path, item.len(), None, Some(50));
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), Some(1), Some(512));
+                }
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendAutomationSignalResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendCommandRequest {
     /// <p>Enables Systems Manager to send Run Command output to Amazon CloudWatch Logs. </p>
     #[serde(rename = "CloudWatchOutputConfig")]
@@ -6516,8 +10042,121 @@ pub struct SendCommandRequest {
     pub timeout_seconds: Option<i64>,
 }
 
+impl Validate for SendCommandRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_output_config {
+            value.validate_at(&field_path(path, "cloud_watch_output_config"), errors);
+        }
+        if let Some(ref value) = self.comment {
+            errors.check_length(
+                &field_path(path, "comment"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.document_hash {
+            errors.check_length(
+                &field_path(path, "document_hash"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "document_name"),
+            &self.document_name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        if let Some(ref value) = self.instance_ids {
+            errors.check_length(
+                &field_path(path, "instance_ids"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "instance_ids"), i);
+                errors.check_pattern(&item_path, &item, "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)");
+            }
+        }
+        if let Some(ref value) = self.max_concurrency {
+            errors.check_length(
+                &field_path(path, "max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.max_errors {
+            errors.check_length(
+                &field_path(path, "max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.output_s3_bucket_name {
+            errors.check_length(
+                &field_path(path, "output_s3_bucket_name"),
+                value.chars().count(),
+                Some(3),
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.output_s3_key_prefix {
+            errors.check_length(
+                &field_path(path, "output_s3_key_prefix"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.output_s3_region {
+            errors.check_length(
+                &field_path(path, "output_s3_region"),
+                value.chars().count(),
+                Some(3),
+                Some(20),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.timeout_seconds {
+            errors.check_range(
+                &field_path(path, "timeout_seconds"),
+                *value,
+                Some(30),
+                Some(2592000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendCommandResult {
     /// <p>The request as it was received by Systems Manager. Also provides the command ID which can be used future references to this request.</p>
     #[serde(rename = "Command")]
@@ -6527,7 +10166,7 @@ pub struct SendCommandResult {
 
 /// <p>The service setting data structure.</p> <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>UpdateServiceSetting</a> API action to change the default setting. Or, use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceSetting {
     /// <p>The ARN of the service setting.</p>
     #[serde(rename = "ARN")]
@@ -6557,7 +10196,7 @@ pub struct ServiceSetting {
 
 /// <p>Information about a Session Manager connection to an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Session {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "Details")]
@@ -6599,6 +10238,7 @@ pub struct Session {
 
 /// <p>Describes a filter for Session Manager information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SessionFilter {
     /// <p>The name of the filter.</p>
     #[serde(rename = "key")]
@@ -6608,9 +10248,20 @@ pub struct SessionFilter {
     pub value: String,
 }
 
+impl Validate for SessionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(400),
+        );
+    }
+}
+
 /// <p>Reserved for future use.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SessionManagerOutputUrl {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "CloudWatchOutputUrl")]
@@ -6624,7 +10275,7 @@ pub struct SessionManagerOutputUrl {
 
 /// <p>The number of managed instances found for each patch severity level defined in the request filter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SeveritySummary {
     /// <p>The total number of resources or compliance items that have a severity level of critical. Critical severity is determined by the organization that published the compliance items.</p>
     #[serde(rename = "CriticalCount")]
@@ -6653,17 +10304,38 @@ pub struct SeveritySummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartAssociationsOnceRequest {
     /// <p>The association IDs that you want to run immediately and only one time.</p>
     #[serde(rename = "AssociationIds")]
     pub association_ids: Vec<String>,
 }
 
+impl Validate for StartAssociationsOnceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "association_ids"),
+            self.association_ids.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.association_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "association_ids"), i);
+            errors.check_pattern(
+                &item_path,
+                &item,
+                "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartAssociationsOnceResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartAutomationExecutionRequest {
     /// <p>User-provided idempotency token. The token must be unique, is case insensitive, enforces the UUID format, and can't be reused.</p>
     #[serde(rename = "ClientToken")]
@@ -6710,8 +10382,127 @@ pub struct StartAutomationExecutionRequest {
     pub targets: Option<Vec<Target>>,
 }
 
+impl Validate for StartAutomationExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_token {
+            errors.check_length(
+                &field_path(path, "client_token"),
+                value.chars().count(),
+                Some(36),
+                Some(36),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_token"),
+                &value,
+                "[a-fA-F0-9]{8}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{4}-[a-fA-F0-9]{12}",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "document_name"),
+            &self.document_name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        if let Some(ref value) = self.max_concurrency {
+            errors.check_length(
+                &field_path(path, "max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.max_errors {
+            errors.check_length(
+                &field_path(path, "max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            errors.check_length(
+                &field_path(path, "parameters"),
+                value.len(),
+                Some(1),
+                Some(200),
+            );
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                errors.check_length(&item_path, item.len(), None, Some(50));
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), Some(1), Some(512));
+                }
+            }
+        }
+        if let Some(ref value) = self.target_locations {
+            errors.check_length(
+                &field_path(path, "target_locations"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "target_locations"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.target_maps {
+            errors.check_length(
+                &field_path(path, "target_maps"),
+                value.len(),
+                None,
+                Some(300),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "target_maps"), i);
+                errors.check_length(&item_path, item.len(), Some(1), Some(20));
+                for (key2, item2) in item.iter() {
+                    let item_path2 = format!("{}[{}]", item_path, key2);
+                    errors.check_length(&item_path2, item2.len(), None, Some(25));
+                    for (i3, item3) in item2.iter().enumerate() {
+                        let item_path3 = format!("{}[{}]", item_path2, i3);
+                        errors.check_length(&item_path3, item3.chars().count(), Some(1), Some(50));
+                    }
+                }
+            }
+        }
+        if let Some(ref value) = self.target_parameter_name {
+            errors.check_length(
+                &field_path(path, "target_parameter_name"),
+                value.chars().count(),
+                Some(1),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartAutomationExecutionResult {
     /// <p>The unique ID of a newly scheduled automation execution.</p>
     #[serde(rename = "AutomationExecutionId")]
@@ -6720,6 +10511,7 @@ pub struct StartAutomationExecutionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartSessionRequest {
     /// <p>The name of the SSM document to define the parameters and plugin settings for the session. For example, <code>SSM-SessionManagerRunShell</code>. If no document name is provided, a shell to the instance is launched by default.</p>
     #[serde(rename = "DocumentName")]
@@ -6734,8 +10526,35 @@ pub struct StartSessionRequest {
     pub target: String,
 }
 
+impl Validate for StartSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.document_name {
+            errors.check_pattern(
+                &field_path(path, "document_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "parameters"), key);
+                for (i2, item2) in item.iter().enumerate() {
+                    let item_path2 = format!("{}[{}]", item_path, i2);
+                    errors.check_length(&item_path2, item2.chars().count(), Some(1), Some(65535));
+                }
+            }
+        }
+        errors.check_length(
+            &field_path(path, "target"),
+            self.target.chars().count(),
+            Some(1),
+            Some(400),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartSessionResponse {
     /// <p>The ID of the session.</p>
     #[serde(rename = "SessionId")]
@@ -6753,7 +10572,7 @@ pub struct StartSessionResponse {
 
 /// <p>Detailed information about an the execution state of an Automation step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StepExecution {
     /// <p>The action this step performs. The action determines the behavior of the step.</p>
     #[serde(rename = "Action")]
@@ -6847,6 +10666,7 @@ pub struct StepExecution {
 
 /// <p>A filter to limit the amount of step execution information returned by the call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StepExecutionFilter {
     /// <p>One or more keys to limit the results. Valid filter keys include the following: StepName, Action, StepExecutionId, StepExecutionStatus, StartTimeBefore, StartTimeAfter.</p>
     #[serde(rename = "Key")]
@@ -6856,7 +10676,23 @@ pub struct StepExecutionFilter {
     pub values: Vec<String>,
 }
 
+impl Validate for StepExecutionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "values"),
+            self.values.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.values.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "values"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(150));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopAutomationExecutionRequest {
     /// <p>The execution ID of the Automation to stop.</p>
     #[serde(rename = "AutomationExecutionId")]
@@ -6867,8 +10703,19 @@ pub struct StopAutomationExecutionRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for StopAutomationExecutionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "automation_execution_id"),
+            self.automation_execution_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopAutomationExecutionResult {}
 
 /// <p>Metadata that you assign to your AWS resources. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. In Systems Manager, you can apply tags to documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines.</p>
@@ -6882,6 +10729,33 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+    }
+}
+
 /// <p>An array of search criteria that targets instances using a Key,Value combination that you specify. </p> <p>Supported formats include the following.</p> <ul> <li> <p> <code>Key=InstanceIds,Values=<i>instance-id-1</i>,<i>instance-id-2</i>,<i>instance-id-3</i> </code> </p> </li> <li> <p> <code>Key=tag:<i>my-tag-key</i>,Values=<i>my-tag-value-1</i>,<i>my-tag-value-2</i> </code> </p> </li> <li> <p> <code>Key=tag-key,Values=<i>my-tag-key-1</i>,<i>my-tag-key-2</i> </code> </p> </li> <li> <p>(Maintenance window targets only) <code>Key=resource-groups:Name,Values=<i>resource-group-name</i> </code> </p> </li> <li> <p>(Maintenance window targets only) <code>Key=resource-groups:ResourceTypeFilters,Values=<i>resource-type-1</i>,<i>resource-type-2</i> </code> </p> </li> </ul> <p>For example:</p> <ul> <li> <p> <code>Key=InstanceIds,Values=i-02573cafcfEXAMPLE,i-0471e04240EXAMPLE,i-07782c72faEXAMPLE</code> </p> </li> <li> <p> <code>Key=tag:CostCenter,Values=CostCenter1,CostCenter2,CostCenter3</code> </p> </li> <li> <p> <code>Key=tag-key,Values=Name,Instance-Type,CostCenter</code> </p> </li> <li> <p>(Maintenance window targets only) <code>Key=resource-groups:Name,Values=ProductionResourceGroup</code> </p> </li> <li> <p>(Maintenance window targets only) <code>Key=resource-groups:ResourceTypeFilters,Values=<i>AWS::EC2::INSTANCE</i>,<i>AWS::EC2::VPC</i> </code> </p> </li> </ul> <p>For information about how to send commands that target instances using <code>Key,Value</code> parameters, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/send-commands-multiple.html#send-commands-targeting">Using Targets and Rate Controls to Send Commands to a Fleet</a> in the <i>AWS Systems Manager User Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Target {
@@ -6895,6 +10769,23 @@ pub struct Target {
     pub values: Option<Vec<String>>,
 }
 
+impl Validate for Target {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(163),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, "^[\\p{L}\\p{Z}\\p{N}_.:/=\\-@]*$|resource-groups:ResourceTypeFilters|resource-groups:Name");
+        }
+        if let Some(ref value) = self.values {
+            errors.check_length(&field_path(path, "values"), value.len(), None, Some(50));
+        }
+    }
+}
+
 /// <p>The combination of AWS Regions and accounts targeted by the current Automation execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetLocation {
@@ -6920,15 +10811,82 @@ pub struct TargetLocation {
     pub target_location_max_errors: Option<String>,
 }
 
+impl Validate for TargetLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.accounts {
+            errors.check_length(
+                &field_path(path, "accounts"),
+                value.len(),
+                Some(1),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.execution_role_name {
+            errors.check_length(
+                &field_path(path, "execution_role_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "execution_role_name"),
+                &value,
+                "[\\w+=,.@/-]+",
+            );
+        }
+        if let Some(ref value) = self.regions {
+            errors.check_length(&field_path(path, "regions"), value.len(), Some(1), Some(50));
+        }
+        if let Some(ref value) = self.target_location_max_concurrency {
+            errors.check_length(
+                &field_path(path, "target_location_max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "target_location_max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.target_location_max_errors {
+            errors.check_length(
+                &field_path(path, "target_location_max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "target_location_max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateSessionRequest {
     /// <p>The ID of the session to terminate.</p>
     #[serde(rename = "SessionId")]
     pub session_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl Validate for TerminateSessionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "session_id"),
+            self.session_id.chars().count(),
+            Some(1),
+            Some(96),
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminateSessionResponse {
     /// <p>The ID of the session that has been terminated.</p>
     #[serde(rename = "SessionId")]
@@ -6937,6 +10895,7 @@ pub struct TerminateSessionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAssociationRequest {
     /// <p>The ID of the association you want to update. </p>
     #[serde(rename = "AssociationId")]
@@ -6991,8 +10950,98 @@ pub struct UpdateAssociationRequest {
     pub targets: Option<Vec<Target>>,
 }
 
+impl Validate for UpdateAssociationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "association_id"),
+            &self.association_id,
+            "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}",
+        );
+        if let Some(ref value) = self.association_name {
+            errors.check_pattern(
+                &field_path(path, "association_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.association_version {
+            errors.check_pattern(
+                &field_path(path, "association_version"),
+                &value,
+                "([$]LATEST)|([1-9][0-9]*)",
+            );
+        }
+        if let Some(ref value) = self.automation_target_parameter_name {
+            errors.check_length(
+                &field_path(path, "automation_target_parameter_name"),
+                value.chars().count(),
+                Some(1),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        if let Some(ref value) = self.max_concurrency {
+            errors.check_length(
+                &field_path(path, "max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.max_errors {
+            errors.check_length(
+                &field_path(path, "max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.output_location {
+            value.validate_at(&field_path(path, "output_location"), errors);
+        }
+        if let Some(ref value) = self.schedule_expression {
+            errors.check_length(
+                &field_path(path, "schedule_expression"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAssociationResult {
     /// <p>The description of the association that was updated.</p>
     #[serde(rename = "AssociationDescription")]
@@ -7001,6 +11050,7 @@ pub struct UpdateAssociationResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAssociationStatusRequest {
     /// <p>The association status.</p>
     #[serde(rename = "AssociationStatus")]
@@ -7013,8 +11063,25 @@ pub struct UpdateAssociationStatusRequest {
     pub name: String,
 }
 
+impl Validate for UpdateAssociationStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.association_status
+            .validate_at(&field_path(path, "association_status"), errors);
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^i-(\\w{8}|\\w{17})$)|(^mi-\\w{17}$)",
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.:/]{3,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAssociationStatusResult {
     /// <p>Information about the association.</p>
     #[serde(rename = "AssociationDescription")]
@@ -7023,6 +11090,7 @@ pub struct UpdateAssociationStatusResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDocumentDefaultVersionRequest {
     /// <p>The version of a custom document that you want to set as the default version.</p>
     #[serde(rename = "DocumentVersion")]
@@ -7032,8 +11100,23 @@ pub struct UpdateDocumentDefaultVersionRequest {
     pub name: String,
 }
 
+impl Validate for UpdateDocumentDefaultVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "document_version"),
+            &self.document_version,
+            "(^[1-9][0-9]*$)",
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDocumentDefaultVersionResult {
     /// <p>The description of a custom document that you want to set as the default version.</p>
     #[serde(rename = "Description")]
@@ -7042,6 +11125,7 @@ pub struct UpdateDocumentDefaultVersionResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDocumentRequest {
     /// <p>A list of key and value pairs that describe attachments to a version of a document.</p>
     #[serde(rename = "Attachments")]
@@ -7071,8 +11155,63 @@ pub struct UpdateDocumentRequest {
     pub version_name: Option<String>,
 }
 
+impl Validate for UpdateDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attachments {
+            errors.check_length(
+                &field_path(path, "attachments"),
+                value.len(),
+                None,
+                Some(20),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "attachments"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "content"),
+            self.content.chars().count(),
+            Some(1),
+            None,
+        );
+        if let Some(ref value) = self.document_version {
+            errors.check_pattern(
+                &field_path(path, "document_version"),
+                &value,
+                "([$]LATEST|[$]DEFAULT|^[1-9][0-9]*$)",
+            );
+        }
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_\\-.]{3,128}$",
+        );
+        if let Some(ref value) = self.target_type {
+            errors.check_length(
+                &field_path(path, "target_type"),
+                value.chars().count(),
+                None,
+                Some(200),
+            );
+            errors.check_pattern(
+                &field_path(path, "target_type"),
+                &value,
+                "^\\/[\\w\\.\\-\\:\\/]*$",
+            );
+        }
+        if let Some(ref value) = self.version_name {
+            errors.check_pattern(
+                &field_path(path, "version_name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{1,128}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDocumentResult {
     /// <p>A description of the document that was updated.</p>
     #[serde(rename = "DocumentDescription")]
@@ -7081,6 +11220,7 @@ pub struct UpdateDocumentResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMaintenanceWindowRequest {
     /// <p>Whether targets must be registered with the maintenance window before tasks can be defined for those targets.</p>
     #[serde(rename = "AllowUnassociatedTargets")]
@@ -7131,8 +11271,59 @@ pub struct UpdateMaintenanceWindowRequest {
     pub window_id: String,
 }
 
+impl Validate for UpdateMaintenanceWindowRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cutoff {
+            errors.check_range(&field_path(path, "cutoff"), *value, None, Some(23));
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.duration {
+            errors.check_range(&field_path(path, "duration"), *value, Some(1), Some(24));
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.schedule {
+            errors.check_length(
+                &field_path(path, "schedule"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMaintenanceWindowResult {
     /// <p>Whether targets must be registered with the maintenance window before tasks can be defined for those targets.</p>
     #[serde(rename = "AllowUnassociatedTargets")]
@@ -7181,6 +11372,7 @@ pub struct UpdateMaintenanceWindowResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMaintenanceWindowTargetRequest {
     /// <p>An optional description for the update.</p>
     #[serde(rename = "Description")]
@@ -7210,8 +11402,71 @@ pub struct UpdateMaintenanceWindowTargetRequest {
     pub window_target_id: String,
 }
 
+impl Validate for UpdateMaintenanceWindowTargetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.owner_information {
+            errors.check_length(
+                &field_path(path, "owner_information"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_target_id"),
+            self.window_target_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_target_id"),
+            &self.window_target_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMaintenanceWindowTargetResult {
     /// <p>The updated description.</p>
     #[serde(rename = "Description")]
@@ -7240,6 +11495,7 @@ pub struct UpdateMaintenanceWindowTargetResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMaintenanceWindowTaskRequest {
     /// <p>The new task description to specify.</p>
     #[serde(rename = "Description")]
@@ -7298,8 +11554,109 @@ pub struct UpdateMaintenanceWindowTaskRequest {
     pub window_task_id: String,
 }
 
+impl Validate for UpdateMaintenanceWindowTaskRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.logging_info {
+            value.validate_at(&field_path(path, "logging_info"), errors);
+        }
+        if let Some(ref value) = self.max_concurrency {
+            errors.check_length(
+                &field_path(path, "max_concurrency"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_concurrency"),
+                &value,
+                "^([1-9][0-9]*|[1-9][0-9]%|[1-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.max_errors {
+            errors.check_length(
+                &field_path(path, "max_errors"),
+                value.chars().count(),
+                Some(1),
+                Some(7),
+            );
+            errors.check_pattern(
+                &field_path(path, "max_errors"),
+                &value,
+                "^([1-9][0-9]*|[0]|[1-9][0-9]%|[0-9]%|100%)$",
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.targets {
+            errors.check_length(&field_path(path, "targets"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "targets"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.task_arn {
+            errors.check_length(
+                &field_path(path, "task_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.task_invocation_parameters {
+            value.validate_at(&field_path(path, "task_invocation_parameters"), errors);
+        }
+        if let Some(ref value) = self.task_parameters {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "task_parameters"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "window_id"),
+            self.window_id.chars().count(),
+            Some(20),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_id"),
+            &self.window_id,
+            "^mw-[0-9a-f]{17}$",
+        );
+        errors.check_length(
+            &field_path(path, "window_task_id"),
+            self.window_task_id.chars().count(),
+            Some(36),
+            Some(36),
+        );
+        errors.check_pattern(
+            &field_path(path, "window_task_id"),
+            &self.window_task_id,
+            "^[0-9a-fA-F]{8}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{4}\\-[0-9a-fA-F]{12}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMaintenanceWindowTaskResult {
     /// <p>The updated task description.</p>
     #[serde(rename = "Description")]
@@ -7357,6 +11714,7 @@ pub struct UpdateMaintenanceWindowTaskResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateManagedInstanceRoleRequest {
     /// <p>The IAM role you want to assign or change.</p>
     #[serde(rename = "IamRole")]
@@ -7366,11 +11724,34 @@ pub struct UpdateManagedInstanceRoleRequest {
     pub instance_id: String,
 }
 
+impl Validate for UpdateManagedInstanceRoleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "iam_role"),
+            self.iam_role.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "instance_id"),
+            self.instance_id.chars().count(),
+            Some(20),
+            Some(124),
+        );
+        errors.check_pattern(
+            &field_path(path, "instance_id"),
+            &self.instance_id,
+            "(^mi-[0-9a-f]{17}$)|(^eks_c:[0-9A-Za-z][A-Za-z0-9\\-_]{0,99}_\\w{17}$)",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateManagedInstanceRoleResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateOpsItemRequest {
     /// <p>Update the information about the OpsItem. Provide enough information so that users reading this OpsItem for the first time understand the issue. </p>
     #[serde(rename = "Description")]
@@ -7409,11 +11790,53 @@ pub struct UpdateOpsItemRequest {
     pub title: Option<String>,
 }
 
+impl Validate for UpdateOpsItemRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\s\\S]*\\S[\\s\\S]*",
+            );
+        }
+        if let Some(ref value) = self.operational_data {
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "operational_data"), key);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_pattern(
+            &field_path(path, "ops_item_id"),
+            &self.ops_item_id,
+            "^(oi)-[0-9a-f]{12}$",
+        );
+        if let Some(ref value) = self.priority {
+            errors.check_range(&field_path(path, "priority"), *value, Some(1), Some(5));
+        }
+        if let Some(ref value) = self.title {
+            errors.check_length(
+                &field_path(path, "title"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "title"), &value, "^(?!\\s*$).+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateOpsItemResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePatchBaselineRequest {
     /// <p>A set of rules used to include patches in the baseline.</p>
     #[serde(rename = "ApprovalRules")]
@@ -7464,8 +11887,82 @@ pub struct UpdatePatchBaselineRequest {
     pub sources: Option<Vec<PatchSource>>,
 }
 
+impl Validate for UpdatePatchBaselineRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.approval_rules {
+            value.validate_at(&field_path(path, "approval_rules"), errors);
+        }
+        if let Some(ref value) = self.approved_patches {
+            errors.check_length(
+                &field_path(path, "approved_patches"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "approved_patches"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+        errors.check_length(
+            &field_path(path, "baseline_id"),
+            self.baseline_id.chars().count(),
+            Some(20),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "baseline_id"),
+            &self.baseline_id,
+            "^[a-zA-Z0-9_\\-:/]{20,128}$",
+        );
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        if let Some(ref value) = self.global_filters {
+            value.validate_at(&field_path(path, "global_filters"), errors);
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(3),
+                Some(128),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "^[a-zA-Z0-9_\\-.]{3,128}$",
+            );
+        }
+        if let Some(ref value) = self.rejected_patches {
+            errors.check_length(
+                &field_path(path, "rejected_patches"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "rejected_patches"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(100));
+            }
+        }
+        if let Some(ref value) = self.sources {
+            errors.check_length(&field_path(path, "sources"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "sources"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePatchBaselineResult {
     /// <p>A set of rules used to include patches in the baseline.</p>
     #[serde(rename = "ApprovalRules")]
@@ -7527,6 +12024,7 @@ pub struct UpdatePatchBaselineResult {
 
 /// <p>The request body of the UpdateServiceSetting API action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServiceSettingRequest {
     /// <p>The ID of the service setting to update.</p>
     #[serde(rename = "SettingId")]
@@ -7536,9 +12034,26 @@ pub struct UpdateServiceSettingRequest {
     pub setting_value: String,
 }
 
+impl Validate for UpdateServiceSettingRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "setting_id"),
+            self.setting_id.chars().count(),
+            Some(1),
+            Some(1000),
+        );
+        errors.check_length(
+            &field_path(path, "setting_value"),
+            self.setting_value.chars().count(),
+            Some(1),
+            Some(4096),
+        );
+    }
+}
+
 /// <p>The result body of the UpdateServiceSetting API action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServiceSettingResult {}
 
 /// Errors returned by AddTagsToResource
@@ -7579,7 +12094,12 @@ impl AddTagsToResourceError {
                 "TooManyUpdates" => {
                     return RusotoError::Service(AddTagsToResourceError::TooManyUpdates(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7631,7 +12151,12 @@ impl CancelCommandError {
                 "InvalidInstanceId" => {
                     return RusotoError::Service(CancelCommandError::InvalidInstanceId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7678,7 +12203,12 @@ impl CancelMaintenanceWindowExecutionError {
                         CancelMaintenanceWindowExecutionError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7714,7 +12244,12 @@ impl CreateActivationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7809,7 +12344,12 @@ impl CreateAssociationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7924,7 +12464,12 @@ impl CreateAssociationBatchError {
                         CreateAssociationBatchError::UnsupportedPlatformType(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8002,7 +12547,12 @@ impl CreateDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8056,7 +12606,12 @@ impl CreateMaintenanceWindowError {
                         CreateMaintenanceWindowError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8108,7 +12663,12 @@ impl CreateOpsItemError {
                 "OpsItemLimitExceededException" => {
                     return RusotoError::Service(CreateOpsItemError::OpsItemLimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8160,7 +12720,12 @@ impl CreatePatchBaselineError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8218,7 +12783,12 @@ impl CreateResourceDataSyncError {
                         CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8273,7 +12843,12 @@ impl DeleteActivationError {
                 "TooManyUpdates" => {
                     return RusotoError::Service(DeleteActivationError::TooManyUpdates(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8333,7 +12908,12 @@ impl DeleteAssociationError {
                 "TooManyUpdates" => {
                     return RusotoError::Service(DeleteAssociationError::TooManyUpdates(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8387,7 +12967,12 @@ impl DeleteDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8447,7 +13032,12 @@ impl DeleteInventoryError {
                 "InvalidTypeNameException" => {
                     return RusotoError::Service(DeleteInventoryError::InvalidTypeName(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8486,7 +13076,12 @@ impl DeleteMaintenanceWindowError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8524,7 +13119,12 @@ impl DeleteParameterError {
                 "ParameterNotFound" => {
                     return RusotoError::Service(DeleteParameterError::ParameterNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8560,7 +13160,12 @@ impl DeleteParametersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8600,7 +13205,12 @@ impl DeletePatchBaselineError {
                 "ResourceInUseException" => {
                     return RusotoError::Service(DeletePatchBaselineError::ResourceInUse(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8643,7 +13253,12 @@ impl DeleteResourceDataSyncError {
                         DeleteResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8686,7 +13301,12 @@ impl DeregisterManagedInstanceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8731,7 +13351,12 @@ impl DeregisterPatchBaselineForPatchGroupError {
                         DeregisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8783,7 +13408,12 @@ impl DeregisterTargetFromMaintenanceWindowError {
                         DeregisterTargetFromMaintenanceWindowError::TargetInUse(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8829,7 +13459,12 @@ impl DeregisterTaskFromMaintenanceWindowError {
                         DeregisterTaskFromMaintenanceWindowError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8877,7 +13512,12 @@ impl DescribeActivationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -8940,7 +13580,12 @@ impl DescribeAssociationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9004,7 +13649,12 @@ impl DescribeAssociationExecutionTargetsError {
                         DescribeAssociationExecutionTargetsError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9060,7 +13710,12 @@ impl DescribeAssociationExecutionsError {
                         DescribeAssociationExecutionsError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9120,7 +13775,12 @@ impl DescribeAutomationExecutionsError {
                         DescribeAutomationExecutionsError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9188,7 +13848,12 @@ impl DescribeAutomationStepExecutionsError {
                         DescribeAutomationStepExecutionsError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9227,7 +13892,12 @@ impl DescribeAvailablePatchesError {
                         DescribeAvailablePatchesError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9274,7 +13944,12 @@ impl DescribeDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9327,7 +14002,12 @@ impl DescribeDocumentPermissionError {
                         DescribeDocumentPermissionError::InvalidPermissionType(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9380,7 +14060,12 @@ impl DescribeEffectiveInstanceAssociationsError {
                         DescribeEffectiveInstanceAssociationsError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9442,7 +14127,12 @@ impl DescribeEffectivePatchesForPatchBaselineError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9498,7 +14188,12 @@ impl DescribeInstanceAssociationsStatusError {
                         DescribeInstanceAssociationsStatusError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9567,7 +14262,12 @@ impl DescribeInstanceInformationError {
                         DescribeInstanceInformationError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9617,7 +14317,12 @@ impl DescribeInstancePatchStatesError {
                         DescribeInstancePatchStatesError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9669,7 +14374,12 @@ impl DescribeInstancePatchStatesForPatchGroupError {
                         DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9727,7 +14437,12 @@ impl DescribeInstancePatchesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9781,7 +14496,12 @@ impl DescribeInventoryDeletionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9831,7 +14551,12 @@ impl DescribeMaintenanceWindowExecutionTaskInvocationsError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9880,7 +14605,12 @@ impl DescribeMaintenanceWindowExecutionTasksError {
                         DescribeMaintenanceWindowExecutionTasksError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9918,7 +14648,12 @@ impl DescribeMaintenanceWindowExecutionsError {
                         DescribeMaintenanceWindowExecutionsError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -9962,7 +14697,12 @@ impl DescribeMaintenanceWindowScheduleError {
                         DescribeMaintenanceWindowScheduleError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10007,7 +14747,12 @@ impl DescribeMaintenanceWindowTargetsError {
                         DescribeMaintenanceWindowTargetsError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10052,7 +14797,12 @@ impl DescribeMaintenanceWindowTasksError {
                         DescribeMaintenanceWindowTasksError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10090,7 +14840,12 @@ impl DescribeMaintenanceWindowsError {
                         DescribeMaintenanceWindowsError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10127,7 +14882,12 @@ impl DescribeMaintenanceWindowsForTargetError {
                         DescribeMaintenanceWindowsForTargetError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10162,7 +14922,12 @@ impl DescribeOpsItemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10221,7 +14986,12 @@ impl DescribeParametersError {
                 "InvalidNextToken" => {
                     return RusotoError::Service(DescribeParametersError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10260,7 +15030,12 @@ impl DescribePatchBaselinesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10302,7 +15077,12 @@ impl DescribePatchGroupStateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10338,7 +15118,12 @@ impl DescribePatchGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10373,7 +15158,12 @@ impl DescribePatchPropertiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10418,7 +15208,12 @@ impl DescribeSessionsError {
                 "InvalidNextToken" => {
                     return RusotoError::Service(DescribeSessionsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10462,7 +15257,12 @@ impl GetAutomationExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10526,7 +15326,12 @@ impl GetCommandInvocationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10565,7 +15370,12 @@ impl GetConnectionStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10600,7 +15410,12 @@ impl GetDefaultPatchBaselineError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10655,7 +15470,12 @@ impl GetDeployablePatchSnapshotForInstanceError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10704,7 +15524,12 @@ impl GetDocumentError {
                 "InvalidDocumentVersion" => {
                     return RusotoError::Service(GetDocumentError::InvalidDocumentVersion(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10769,7 +15594,12 @@ impl GetInventoryError {
                 "InvalidTypeNameException" => {
                     return RusotoError::Service(GetInventoryError::InvalidTypeName(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10820,7 +15650,12 @@ impl GetInventorySchemaError {
                 "InvalidTypeNameException" => {
                     return RusotoError::Service(GetInventorySchemaError::InvalidTypeName(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10862,7 +15697,12 @@ impl GetMaintenanceWindowError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10907,7 +15747,12 @@ impl GetMaintenanceWindowExecutionError {
                         GetMaintenanceWindowExecutionError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10952,7 +15797,12 @@ impl GetMaintenanceWindowExecutionTaskError {
                         GetMaintenanceWindowExecutionTaskError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -10999,7 +15849,12 @@ impl GetMaintenanceWindowExecutionTaskInvocationError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11044,7 +15899,12 @@ impl GetMaintenanceWindowTaskError {
                         GetMaintenanceWindowTaskError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11083,7 +15943,12 @@ impl GetOpsItemError {
                 "OpsItemNotFoundException" => {
                     return RusotoError::Service(GetOpsItemError::OpsItemNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11137,7 +16002,12 @@ impl GetOpsSummaryError {
                 "InvalidTypeNameException" => {
                     return RusotoError::Service(GetOpsSummaryError::InvalidTypeName(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11191,7 +16061,12 @@ impl GetParameterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11248,7 +16123,12 @@ impl GetParameterHistoryError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11289,7 +16169,12 @@ impl GetParametersError {
                 "InvalidKeyId" => {
                     return RusotoError::Service(GetParametersError::InvalidKeyId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11358,7 +16243,12 @@ impl GetParametersByPathError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11408,7 +16298,12 @@ impl GetPatchBaselineError {
                 "InvalidResourceId" => {
                     return RusotoError::Service(GetPatchBaselineError::InvalidResourceId(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11447,7 +16342,12 @@ impl GetPatchBaselineForPatchGroupError {
                         GetPatchBaselineForPatchGroupError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11489,7 +16389,12 @@ impl GetServiceSettingError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11553,7 +16458,12 @@ impl LabelParameterVersionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11606,7 +16516,12 @@ impl ListAssociationVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11648,7 +16563,12 @@ impl ListAssociationsError {
                 "InvalidNextToken" => {
                     return RusotoError::Service(ListAssociationsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11712,7 +16632,12 @@ impl ListCommandInvocationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11769,7 +16694,12 @@ impl ListCommandsError {
                 "InvalidNextToken" => {
                     return RusotoError::Service(ListCommandsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11834,7 +16764,12 @@ impl ListComplianceItemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11887,7 +16822,12 @@ impl ListComplianceSummariesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11938,7 +16878,12 @@ impl ListDocumentVersionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -11983,7 +16928,12 @@ impl ListDocumentsError {
                 "InvalidNextToken" => {
                     return RusotoError::Service(ListDocumentsError::InvalidNextToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12046,7 +16996,12 @@ impl ListInventoryEntriesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12101,7 +17056,12 @@ impl ListResourceComplianceSummariesError {
                         ListResourceComplianceSummariesError::InvalidNextToken(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12145,7 +17105,12 @@ impl ListResourceDataSyncError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12195,7 +17160,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12260,7 +17230,12 @@ impl ModifyDocumentPermissionError {
                         ModifyDocumentPermissionError::InvalidPermissionType(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12341,7 +17316,12 @@ impl PutComplianceItemsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12445,7 +17425,12 @@ impl PutInventoryError {
                         PutInventoryError::UnsupportedInventorySchemaVersion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12567,7 +17552,12 @@ impl PutParameterError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12632,7 +17622,12 @@ impl RegisterDefaultPatchBaselineError {
                         RegisterDefaultPatchBaselineError::InvalidResourceId(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12699,7 +17694,12 @@ impl RegisterPatchBaselineForPatchGroupError {
                         RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12763,7 +17763,12 @@ impl RegisterTargetWithMaintenanceWindowError {
                         RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12835,7 +17840,12 @@ impl RegisterTaskWithMaintenanceWindowError {
                         RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12895,7 +17905,12 @@ impl RemoveTagsFromResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12945,7 +17960,12 @@ impl ResetServiceSettingError {
                 "TooManyUpdates" => {
                     return RusotoError::Service(ResetServiceSettingError::TooManyUpdates(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -12985,7 +18005,12 @@ impl ResumeSessionError {
                 "InternalServerError" => {
                     return RusotoError::Service(ResumeSessionError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13042,7 +18067,12 @@ impl SendAutomationSignalError {
                         SendAutomationSignalError::InvalidAutomationSignal(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13130,7 +18160,12 @@ impl SendCommandError {
                 "UnsupportedPlatformType" => {
                     return RusotoError::Service(SendCommandError::UnsupportedPlatformType(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13182,7 +18217,12 @@ impl StartAssociationsOnceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13262,7 +18302,12 @@ impl StartAutomationExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13311,7 +18356,12 @@ impl StartSessionError {
                 "TargetNotConnected" => {
                     return RusotoError::Service(StartSessionError::TargetNotConnected(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13362,7 +18412,12 @@ impl StopAutomationExecutionError {
                         StopAutomationExecutionError::InvalidAutomationStatusUpdate(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13404,7 +18459,12 @@ impl TerminateSessionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13505,7 +18565,12 @@ impl UpdateAssociationError {
                 "TooManyUpdates" => {
                     return RusotoError::Service(UpdateAssociationError::TooManyUpdates(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13586,7 +18651,12 @@ impl UpdateAssociationStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13685,7 +18755,12 @@ impl UpdateDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13752,7 +18827,12 @@ impl UpdateDocumentDefaultVersionError {
                         UpdateDocumentDefaultVersionError::InvalidDocumentVersion(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13797,7 +18877,12 @@ impl UpdateMaintenanceWindowError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13842,7 +18927,12 @@ impl UpdateMaintenanceWindowTargetError {
                         UpdateMaintenanceWindowTargetError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13887,7 +18977,12 @@ impl UpdateMaintenanceWindowTaskError {
                         UpdateMaintenanceWindowTaskError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13930,7 +19025,12 @@ impl UpdateManagedInstanceRoleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -13986,7 +19086,12 @@ impl UpdateOpsItemError {
                 "OpsItemNotFoundException" => {
                     return RusotoError::Service(UpdateOpsItemError::OpsItemNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -14030,7 +19135,12 @@ impl UpdatePatchBaselineError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -14078,7 +19188,12 @@ impl UpdateServiceSettingError {
                 "TooManyUpdates" => {
                     return RusotoError::Service(UpdateServiceSettingError::TooManyUpdates(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -14869,640 +19984,135 @@ pub trait Ssm {
         input: UpdateServiceSettingRequest,
     ) -> RusotoFuture<UpdateServiceSettingResult, UpdateServiceSettingError>;
 }
-/// A client for the Amazon SSM API.
-#[derive(Clone)]
-pub struct SsmClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SsmClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SsmClient {
-        Self::new_with_client(Client::shared(), region)
+const _: Option<&dyn Ssm> = None;
+impl<'a, T: Ssm + ?Sized> Ssm for &'a T {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SsmClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SsmClient {
-        SsmClient { client, region }
-    }
-}
-
-impl Ssm for SsmClient {
-    /// <p>Adds or overwrites one or more tags for the specified resource. Tags are metadata that you can assign to your documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. Each tag consists of a key and an optional value, both of which you define. For example, you could define a set of tags for your account's managed instances that helps you track each instance's owner and stack level. For example: Key=Owner and Value=DbAdmin, SysAdmin, or Dev. Or Key=Stack and Value=Production, Pre-Production, or Test.</p> <p>Each resource can have a maximum of 50 tags. </p> <p>We recommend that you devise a set of tag keys that meets your needs for each resource type. Using a consistent set of tag keys makes it easier for you to manage your resources. You can search and filter the resources based on the tags you add. Tags don't have any semantic meaning to Amazon EC2 and are interpreted strictly as a string of characters. </p> <p>For more information about tags, see <a href="http://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Amazon EC2 Resources</a> in the <i>Amazon EC2 User Guide</i>.</p>
-    fn add_tags_to_resource(
-        &self,
-        input: AddTagsToResourceRequest,
-    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddTagsToResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                )
-            }
-        })
-    }
-
-    /// <p>Attempts to cancel the command specified by the Command ID. There is no guarantee that the command will be terminated and the underlying process stopped.</p>
     fn cancel_command(
         &self,
         input: CancelCommandRequest,
     ) -> RusotoFuture<CancelCommandResult, CancelCommandError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelCommandResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelCommandError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_command(input)
     }
-
-    /// <p>Stops a maintenance window execution that is already in progress and cancels any tasks in the window that have not already starting running. (Tasks already in progress will continue to completion.)</p>
     fn cancel_maintenance_window_execution(
         &self,
         input: CancelMaintenanceWindowExecutionRequest,
     ) -> RusotoFuture<CancelMaintenanceWindowExecutionResult, CancelMaintenanceWindowExecutionError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelMaintenanceWindowExecutionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CancelMaintenanceWindowExecutionError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).cancel_maintenance_window_execution(input)
     }
-
-    /// <p>Registers your on-premises server or virtual machine with Amazon EC2 so that you can manage these resources using Run Command. An on-premises server or virtual machine that has been registered with EC2 is called a managed instance. For more information about activations, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-managedinstances.html">Setting Up AWS Systems Manager for Hybrid Environments</a>.</p>
     fn create_activation(
         &self,
         input: CreateActivationRequest,
     ) -> RusotoFuture<CreateActivationResult, CreateActivationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateActivationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateActivationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_activation(input)
     }
-
-    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
     fn create_association(
         &self,
         input: CreateAssociationRequest,
     ) -> RusotoFuture<CreateAssociationResult, CreateAssociationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAssociationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAssociationError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_association(input)
     }
-
-    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
     fn create_association_batch(
         &self,
         input: CreateAssociationBatchRequest,
     ) -> RusotoFuture<CreateAssociationBatchResult, CreateAssociationBatchError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAssociationBatchResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateAssociationBatchError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_association_batch(input)
     }
-
-    /// <p>Creates a Systems Manager document.</p> <p>After you create a document, you can use CreateAssociation to associate it with one or more running instances.</p>
     fn create_document(
         &self,
         input: CreateDocumentRequest,
     ) -> RusotoFuture<CreateDocumentResult, CreateDocumentError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateDocumentResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateDocumentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_document(input)
     }
-
-    /// <p>Creates a new maintenance window.</p>
     fn create_maintenance_window(
         &self,
         input: CreateMaintenanceWindowRequest,
     ) -> RusotoFuture<CreateMaintenanceWindowResult, CreateMaintenanceWindowError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateMaintenanceWindowError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_maintenance_window(input)
     }
-
-    /// <p>Creates a new OpsItem. You must have permission in AWS Identity and Access Management (IAM) to create a new OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
     fn create_ops_item(
         &self,
         input: CreateOpsItemRequest,
     ) -> RusotoFuture<CreateOpsItemResponse, CreateOpsItemError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateOpsItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateOpsItemResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateOpsItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_ops_item(input)
     }
-
-    /// <p><p>Creates a patch baseline.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
     fn create_patch_baseline(
         &self,
         input: CreatePatchBaselineRequest,
     ) -> RusotoFuture<CreatePatchBaselineResult, CreatePatchBaselineError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreatePatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreatePatchBaselineError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_patch_baseline(input)
     }
-
-    /// <p>Creates a resource data sync configuration to a single bucket in Amazon S3. This is an asynchronous operation that returns immediately. After a successful initial sync is completed, the system continuously syncs data to the Amazon S3 bucket. To check the status of the sync, use the <a>ListResourceDataSync</a>.</p> <p>By default, data is not encrypted in Amazon S3. We strongly recommend that you enable encryption in Amazon S3 to ensure secure data storage. We also recommend that you secure access to the Amazon S3 bucket by creating a restrictive bucket policy. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-inventory-datasync.html">Configuring Resource Data Sync for Inventory</a> in the <i>AWS Systems Manager User Guide</i>.</p>
     fn create_resource_data_sync(
         &self,
         input: CreateResourceDataSyncRequest,
     ) -> RusotoFuture<CreateResourceDataSyncResult, CreateResourceDataSyncError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.CreateResourceDataSync");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResourceDataSyncResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateResourceDataSyncError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_resource_data_sync(input)
     }
-
-    /// <p>Deletes an activation. You are not required to delete an activation. If you delete an activation, you can no longer use it to register additional managed instances. Deleting an activation does not de-register managed instances. You must manually de-register managed instances.</p>
     fn delete_activation(
         &self,
         input: DeleteActivationRequest,
     ) -> RusotoFuture<DeleteActivationResult, DeleteActivationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteActivation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteActivationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteActivationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_activation(input)
     }
-
-    /// <p>Disassociates the specified Systems Manager document from the specified instance.</p> <p>When you disassociate a document from an instance, it does not change the configuration of the instance. To change the configuration state of an instance after you disassociate a document, you must create a new document with the desired configuration and associate it with the instance.</p>
     fn delete_association(
         &self,
         input: DeleteAssociationRequest,
     ) -> RusotoFuture<DeleteAssociationResult, DeleteAssociationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteAssociation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAssociationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAssociationError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_association(input)
     }
-
-    /// <p>Deletes the Systems Manager document and all instance associations to the document.</p> <p>Before you delete the document, we recommend that you use <a>DeleteAssociation</a> to disassociate all instances that are associated with the document.</p>
     fn delete_document(
         &self,
         input: DeleteDocumentRequest,
     ) -> RusotoFuture<DeleteDocumentResult, DeleteDocumentError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteDocument");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteDocumentResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDocumentError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_document(input)
     }
-
-    /// <p>Delete a custom inventory type, or the data associated with a custom Inventory type. Deleting a custom inventory type is also referred to as deleting a custom inventory schema.</p>
     fn delete_inventory(
         &self,
         input: DeleteInventoryRequest,
     ) -> RusotoFuture<DeleteInventoryResult, DeleteInventoryError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteInventoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteInventoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_inventory(input)
     }
-
-    /// <p>Deletes a maintenance window.</p>
     fn delete_maintenance_window(
         &self,
         input: DeleteMaintenanceWindowRequest,
     ) -> RusotoFuture<DeleteMaintenanceWindowResult, DeleteMaintenanceWindowError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteMaintenanceWindow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteMaintenanceWindowError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_maintenance_window(input)
     }
-
-    /// <p>Delete a parameter from the system.</p>
     fn delete_parameter(
         &self,
         input: DeleteParameterRequest,
     ) -> RusotoFuture<DeleteParameterResult, DeleteParameterError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteParameter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteParameterResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteParameterError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_parameter(input)
     }
-
-    /// <p>Delete a list of parameters.</p>
     fn delete_parameters(
         &self,
         input: DeleteParametersRequest,
     ) -> RusotoFuture<DeleteParametersResult, DeleteParametersError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteParameters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteParametersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteParametersError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_parameters(input)
     }
-
-    /// <p>Deletes a patch baseline.</p>
     fn delete_patch_baseline(
         &self,
         input: DeletePatchBaselineRequest,
     ) -> RusotoFuture<DeletePatchBaselineResult, DeletePatchBaselineError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeletePatchBaseline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeletePatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeletePatchBaselineError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_patch_baseline(input)
     }
-
-    /// <p>Deletes a Resource Data Sync configuration. After the configuration is deleted, changes to inventory data on managed instances are no longer synced with the target Amazon S3 bucket. Deleting a sync configuration does not delete data in the target Amazon S3 bucket.</p>
     fn delete_resource_data_sync(
         &self,
         input: DeleteResourceDataSyncRequest,
     ) -> RusotoFuture<DeleteResourceDataSyncResult, DeleteResourceDataSyncError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeleteResourceDataSync");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteResourceDataSyncResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteResourceDataSyncError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_resource_data_sync(input)
     }
-
-    /// <p>Removes the server or virtual machine from the list of registered servers. You can reregister the instance again at any time. If you don't plan to use Run Command on the server, we suggest uninstalling SSM Agent first.</p>
     fn deregister_managed_instance(
         &self,
         input: DeregisterManagedInstanceRequest,
     ) -> RusotoFuture<DeregisterManagedInstanceResult, DeregisterManagedInstanceError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DeregisterManagedInstance");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterManagedInstanceResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterManagedInstanceError::from_response(response))
-                }))
-            }
-        })
+        (**self).deregister_managed_instance(input)
     }
-
-    /// <p>Removes a patch group from a patch baseline.</p>
     fn deregister_patch_baseline_for_patch_group(
         &self,
         input: DeregisterPatchBaselineForPatchGroupRequest,
@@ -15510,33 +20120,8 @@ impl Ssm for SsmClient {
         DeregisterPatchBaselineForPatchGroupResult,
         DeregisterPatchBaselineForPatchGroupError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DeregisterPatchBaselineForPatchGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterPatchBaselineForPatchGroupResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterPatchBaselineForPatchGroupError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).deregister_patch_baseline_for_patch_group(input)
     }
-
-    /// <p>Removes a target from a maintenance window.</p>
     fn deregister_target_from_maintenance_window(
         &self,
         input: DeregisterTargetFromMaintenanceWindowRequest,
@@ -15544,33 +20129,8 @@ impl Ssm for SsmClient {
         DeregisterTargetFromMaintenanceWindowResult,
         DeregisterTargetFromMaintenanceWindowError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DeregisterTargetFromMaintenanceWindow",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterTargetFromMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterTargetFromMaintenanceWindowError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).deregister_target_from_maintenance_window(input)
     }
-
-    /// <p>Removes a task from a maintenance window.</p>
     fn deregister_task_from_maintenance_window(
         &self,
         input: DeregisterTaskFromMaintenanceWindowRequest,
@@ -15578,89 +20138,20 @@ impl Ssm for SsmClient {
         DeregisterTaskFromMaintenanceWindowResult,
         DeregisterTaskFromMaintenanceWindowError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DeregisterTaskFromMaintenanceWindow",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterTaskFromMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeregisterTaskFromMaintenanceWindowError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).deregister_task_from_maintenance_window(input)
     }
-
-    /// <p>Describes details about the activation, such as the date and time the activation was created, its expiration date, the IAM role assigned to the instances in the activation, and the number of instances registered by using this activation.</p>
     fn describe_activations(
         &self,
         input: DescribeActivationsRequest,
     ) -> RusotoFuture<DescribeActivationsResult, DescribeActivationsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeActivations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeActivationsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeActivationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_activations(input)
     }
-
-    /// <p>Describes the association for the specified target or instance. If you created the association by using the <code>Targets</code> parameter, then you must retrieve the association by using the association ID. If you created the association by specifying an instance ID and a Systems Manager document, then you retrieve the association by specifying the document name and the instance ID. </p>
     fn describe_association(
         &self,
         input: DescribeAssociationRequest,
     ) -> RusotoFuture<DescribeAssociationResult, DescribeAssociationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeAssociation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAssociationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeAssociationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_association(input)
     }
-
-    /// <p>Use this API action to view information about a specific execution of a specific association.</p>
     fn describe_association_execution_targets(
         &self,
         input: DescribeAssociationExecutionTargetsRequest,
@@ -15668,195 +20159,45 @@ impl Ssm for SsmClient {
         DescribeAssociationExecutionTargetsResult,
         DescribeAssociationExecutionTargetsError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeAssociationExecutionTargets",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAssociationExecutionTargetsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAssociationExecutionTargetsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_association_execution_targets(input)
     }
-
-    /// <p>Use this API action to view all executions for a specific association ID. </p>
     fn describe_association_executions(
         &self,
         input: DescribeAssociationExecutionsRequest,
     ) -> RusotoFuture<DescribeAssociationExecutionsResult, DescribeAssociationExecutionsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeAssociationExecutions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAssociationExecutionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAssociationExecutionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_association_executions(input)
     }
-
-    /// <p>Provides details about all active and terminated Automation executions.</p>
     fn describe_automation_executions(
         &self,
         input: DescribeAutomationExecutionsRequest,
     ) -> RusotoFuture<DescribeAutomationExecutionsResult, DescribeAutomationExecutionsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationExecutions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAutomationExecutionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAutomationExecutionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_automation_executions(input)
     }
-
-    /// <p>Information about all active and terminated step executions in an Automation workflow.</p>
     fn describe_automation_step_executions(
         &self,
         input: DescribeAutomationStepExecutionsRequest,
     ) -> RusotoFuture<DescribeAutomationStepExecutionsResult, DescribeAutomationStepExecutionsError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationStepExecutions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAutomationStepExecutionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAutomationStepExecutionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_automation_step_executions(input)
     }
-
-    /// <p>Lists all patches eligible to be included in a patch baseline.</p>
     fn describe_available_patches(
         &self,
         input: DescribeAvailablePatchesRequest,
     ) -> RusotoFuture<DescribeAvailablePatchesResult, DescribeAvailablePatchesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeAvailablePatches");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAvailablePatchesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAvailablePatchesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_available_patches(input)
     }
-
-    /// <p>Describes the specified Systems Manager document.</p>
     fn describe_document(
         &self,
         input: DescribeDocumentRequest,
     ) -> RusotoFuture<DescribeDocumentResult, DescribeDocumentError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeDocument");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDocumentResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDocumentError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_document(input)
     }
-
-    /// <p>Describes the permissions for a Systems Manager document. If you created the document, you are the owner. If a document is shared, it can either be shared privately (by specifying a user's AWS account ID) or publicly (<i>All</i>). </p>
     fn describe_document_permission(
         &self,
         input: DescribeDocumentPermissionRequest,
     ) -> RusotoFuture<DescribeDocumentPermissionResponse, DescribeDocumentPermissionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeDocumentPermission");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDocumentPermissionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDocumentPermissionError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_document_permission(input)
     }
-
-    /// <p>All associations for the instance(s).</p>
     fn describe_effective_instance_associations(
         &self,
         input: DescribeEffectiveInstanceAssociationsRequest,
@@ -15864,33 +20205,8 @@ impl Ssm for SsmClient {
         DescribeEffectiveInstanceAssociationsResult,
         DescribeEffectiveInstanceAssociationsError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeEffectiveInstanceAssociations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEffectiveInstanceAssociationsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEffectiveInstanceAssociationsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_effective_instance_associations(input)
     }
-
-    /// <p>Retrieves the current effective patches (the patch and the approval state) for the specified patch baseline. Note that this API applies only to Windows patch baselines.</p>
     fn describe_effective_patches_for_patch_baseline(
         &self,
         input: DescribeEffectivePatchesForPatchBaselineRequest,
@@ -15898,31 +20214,8 @@ impl Ssm for SsmClient {
         DescribeEffectivePatchesForPatchBaselineResult,
         DescribeEffectivePatchesForPatchBaselineError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeEffectivePatchesForPatchBaseline",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeEffectivePatchesForPatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeEffectivePatchesForPatchBaselineError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_effective_patches_for_patch_baseline(input)
     }
-
-    /// <p>The status of the associations for the instance(s).</p>
     fn describe_instance_associations_status(
         &self,
         input: DescribeInstanceAssociationsStatusRequest,
@@ -15930,85 +20223,20 @@ impl Ssm for SsmClient {
         DescribeInstanceAssociationsStatusResult,
         DescribeInstanceAssociationsStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeInstanceAssociationsStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstanceAssociationsStatusResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstanceAssociationsStatusError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_instance_associations_status(input)
     }
-
-    /// <p><p>Describes one or more of your instances. You can use this to get information about instances like the operating system platform, the SSM Agent version (Linux), status etc. If you specify one or more instance IDs, it returns information for those instances. If you do not specify instance IDs, it returns information for all your instances. If you specify an instance ID that is not valid or an instance that you do not own, you receive an error. </p> <note> <p>The IamRole field for this API action is the Amazon Identity and Access Management (IAM) role assigned to on-premises instances. This call does not return the IAM role for Amazon EC2 instances.</p> </note></p>
     fn describe_instance_information(
         &self,
         input: DescribeInstanceInformationRequest,
     ) -> RusotoFuture<DescribeInstanceInformationResult, DescribeInstanceInformationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeInstanceInformation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstanceInformationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstanceInformationError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_instance_information(input)
     }
-
-    /// <p>Retrieves the high-level patch state of one or more instances.</p>
     fn describe_instance_patch_states(
         &self,
         input: DescribeInstancePatchStatesRequest,
     ) -> RusotoFuture<DescribeInstancePatchStatesResult, DescribeInstancePatchStatesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatchStates");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstancePatchStatesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstancePatchStatesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_instance_patch_states(input)
     }
-
-    /// <p>Retrieves the high-level patch state for the instances in the specified patch group.</p>
     fn describe_instance_patch_states_for_patch_group(
         &self,
         input: DescribeInstancePatchStatesForPatchGroupRequest,
@@ -16016,83 +20244,20 @@ impl Ssm for SsmClient {
         DescribeInstancePatchStatesForPatchGroupResult,
         DescribeInstancePatchStatesForPatchGroupError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeInstancePatchStatesForPatchGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstancePatchStatesForPatchGroupResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstancePatchStatesForPatchGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_instance_patch_states_for_patch_group(input)
     }
-
-    /// <p>Retrieves information about the patches on the specified instance and their state relative to the patch baseline being used for the instance.</p>
     fn describe_instance_patches(
         &self,
         input: DescribeInstancePatchesRequest,
     ) -> RusotoFuture<DescribeInstancePatchesResult, DescribeInstancePatchesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatches");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInstancePatchesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInstancePatchesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_instance_patches(input)
     }
-
-    /// <p>Describes a specific delete inventory operation.</p>
     fn describe_inventory_deletions(
         &self,
         input: DescribeInventoryDeletionsRequest,
     ) -> RusotoFuture<DescribeInventoryDeletionsResult, DescribeInventoryDeletionsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeInventoryDeletions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeInventoryDeletionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeInventoryDeletionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_inventory_deletions(input)
     }
-
-    /// <p>Retrieves the individual task executions (one per target) for a particular task run as part of a maintenance window execution.</p>
     fn describe_maintenance_window_execution_task_invocations(
         &self,
         input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
@@ -16100,30 +20265,8 @@ impl Ssm for SsmClient {
         DescribeMaintenanceWindowExecutionTaskInvocationsResult,
         DescribeMaintenanceWindowExecutionTaskInvocationsError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeMaintenanceWindowExecutionTaskInvocations",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-                        if response.status.is_success() {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DescribeMaintenanceWindowExecutionTaskInvocationsResult, _>()
-                }))
-                        } else {
-                            Box::new(response.buffer().from_err().and_then(|response| {
-                                Err(DescribeMaintenanceWindowExecutionTaskInvocationsError::from_response(response))
-                            }))
-                        }
-                    })
+        (**self).describe_maintenance_window_execution_task_invocations(input)
     }
-
-    /// <p>For a given maintenance window execution, lists the tasks that were run.</p>
     fn describe_maintenance_window_execution_tasks(
         &self,
         input: DescribeMaintenanceWindowExecutionTasksRequest,
@@ -16131,33 +20274,8 @@ impl Ssm for SsmClient {
         DescribeMaintenanceWindowExecutionTasksResult,
         DescribeMaintenanceWindowExecutionTasksError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeMaintenanceWindowExecutionTasks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowExecutionTasksResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowExecutionTasksError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_maintenance_window_execution_tasks(input)
     }
-
-    /// <p>Lists the executions of a maintenance window. This includes information about when the maintenance window was scheduled to be active, and information about tasks registered and run with the maintenance window.</p>
     fn describe_maintenance_window_executions(
         &self,
         input: DescribeMaintenanceWindowExecutionsRequest,
@@ -16165,147 +20283,35 @@ impl Ssm for SsmClient {
         DescribeMaintenanceWindowExecutionsResult,
         DescribeMaintenanceWindowExecutionsError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeMaintenanceWindowExecutions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowExecutionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowExecutionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_maintenance_window_executions(input)
     }
-
-    /// <p>Retrieves information about upcoming executions of a maintenance window.</p>
     fn describe_maintenance_window_schedule(
         &self,
         input: DescribeMaintenanceWindowScheduleRequest,
     ) -> RusotoFuture<DescribeMaintenanceWindowScheduleResult, DescribeMaintenanceWindowScheduleError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeMaintenanceWindowSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowScheduleResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowScheduleError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_maintenance_window_schedule(input)
     }
-
-    /// <p>Lists the targets registered with the maintenance window.</p>
     fn describe_maintenance_window_targets(
         &self,
         input: DescribeMaintenanceWindowTargetsRequest,
     ) -> RusotoFuture<DescribeMaintenanceWindowTargetsResult, DescribeMaintenanceWindowTargetsError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTargets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowTargetsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowTargetsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_maintenance_window_targets(input)
     }
-
-    /// <p>Lists the tasks in a maintenance window.</p>
     fn describe_maintenance_window_tasks(
         &self,
         input: DescribeMaintenanceWindowTasksRequest,
     ) -> RusotoFuture<DescribeMaintenanceWindowTasksResult, DescribeMaintenanceWindowTasksError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTasks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowTasksResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowTasksError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_maintenance_window_tasks(input)
     }
-
-    /// <p>Retrieves the maintenance windows in an AWS account.</p>
     fn describe_maintenance_windows(
         &self,
         input: DescribeMaintenanceWindowsRequest,
     ) -> RusotoFuture<DescribeMaintenanceWindowsResult, DescribeMaintenanceWindowsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindows");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_maintenance_windows(input)
     }
-
-    /// <p>Retrieves information about the maintenance window targets or tasks that an instance is associated with.</p>
     fn describe_maintenance_windows_for_target(
         &self,
         input: DescribeMaintenanceWindowsForTargetRequest,
@@ -16313,338 +20319,74 @@ impl Ssm for SsmClient {
         DescribeMaintenanceWindowsForTargetResult,
         DescribeMaintenanceWindowsForTargetError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.DescribeMaintenanceWindowsForTarget",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceWindowsForTargetResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceWindowsForTargetError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_maintenance_windows_for_target(input)
     }
-
-    /// <p>Query a set of OpsItems. You must have permission in AWS Identity and Access Management (IAM) to query a list of OpsItems. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
     fn describe_ops_items(
         &self,
         input: DescribeOpsItemsRequest,
     ) -> RusotoFuture<DescribeOpsItemsResponse, DescribeOpsItemsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeOpsItems");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOpsItemsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeOpsItemsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_ops_items(input)
     }
-
-    /// <p>Get information about a parameter.</p> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p>
     fn describe_parameters(
         &self,
         input: DescribeParametersRequest,
     ) -> RusotoFuture<DescribeParametersResult, DescribeParametersError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeParameters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeParametersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeParametersError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_parameters(input)
     }
-
-    /// <p>Lists the patch baselines in your AWS account.</p>
     fn describe_patch_baselines(
         &self,
         input: DescribePatchBaselinesRequest,
     ) -> RusotoFuture<DescribePatchBaselinesResult, DescribePatchBaselinesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribePatchBaselines");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePatchBaselinesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribePatchBaselinesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_patch_baselines(input)
     }
-
-    /// <p>Returns high-level aggregated patch compliance state for a patch group.</p>
     fn describe_patch_group_state(
         &self,
         input: DescribePatchGroupStateRequest,
     ) -> RusotoFuture<DescribePatchGroupStateResult, DescribePatchGroupStateError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroupState");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePatchGroupStateResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePatchGroupStateError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_patch_group_state(input)
     }
-
-    /// <p>Lists all patch groups that have been registered with patch baselines.</p>
     fn describe_patch_groups(
         &self,
         input: DescribePatchGroupsRequest,
     ) -> RusotoFuture<DescribePatchGroupsResult, DescribePatchGroupsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePatchGroupsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribePatchGroupsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_patch_groups(input)
     }
-
-    /// <p><p>Lists the properties of available patches organized by product, product family, classification, severity, and other properties of available patches. You can use the reported properties in the filters you specify in requests for actions such as <a>CreatePatchBaseline</a>, <a>UpdatePatchBaseline</a>, <a>DescribeAvailablePatches</a>, and <a>DescribePatchBaselines</a>.</p> <p>The following section lists the properties that can be used in filters for each major operating system type:</p> <dl> <dt>WINDOWS</dt> <dd> <p>Valid properties: PRODUCT, PRODUCT<em>FAMILY, CLASSIFICATION, MSRC</em>SEVERITY</p> </dd> <dt>AMAZON<em>LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>AMAZON</em>LINUX<em>2</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>UBUNTU </dt> <dd> <p>Valid properties: PRODUCT, PRIORITY</p> </dd> <dt>REDHAT</em>ENTERPRISE_LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>SUSE</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>CENTOS</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> </dl></p>
     fn describe_patch_properties(
         &self,
         input: DescribePatchPropertiesRequest,
     ) -> RusotoFuture<DescribePatchPropertiesResult, DescribePatchPropertiesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribePatchProperties");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribePatchPropertiesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribePatchPropertiesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_patch_properties(input)
     }
-
-    /// <p>Retrieves a list of all active sessions (both connected and disconnected) or terminated sessions from the past 30 days.</p>
     fn describe_sessions(
         &self,
         input: DescribeSessionsRequest,
     ) -> RusotoFuture<DescribeSessionsResponse, DescribeSessionsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.DescribeSessions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSessionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeSessionsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_sessions(input)
     }
-
-    /// <p>Get detailed information about a particular Automation execution.</p>
     fn get_automation_execution(
         &self,
         input: GetAutomationExecutionRequest,
     ) -> RusotoFuture<GetAutomationExecutionResult, GetAutomationExecutionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetAutomationExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetAutomationExecutionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetAutomationExecutionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_automation_execution(input)
     }
-
-    /// <p>Returns detailed information about command execution for an invocation or plugin. </p>
     fn get_command_invocation(
         &self,
         input: GetCommandInvocationRequest,
     ) -> RusotoFuture<GetCommandInvocationResult, GetCommandInvocationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetCommandInvocation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCommandInvocationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetCommandInvocationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_command_invocation(input)
     }
-
-    /// <p>Retrieves the Session Manager connection status for an instance to determine whether it is connected and ready to receive Session Manager connections.</p>
     fn get_connection_status(
         &self,
         input: GetConnectionStatusRequest,
     ) -> RusotoFuture<GetConnectionStatusResponse, GetConnectionStatusError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetConnectionStatus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetConnectionStatusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetConnectionStatusError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_connection_status(input)
     }
-
-    /// <p>Retrieves the default patch baseline. Note that Systems Manager supports creating multiple default patch baselines. For example, you can create a default patch baseline for each operating system.</p> <p>If you do not specify an operating system value, the default patch baseline for Windows is returned.</p>
     fn get_default_patch_baseline(
         &self,
         input: GetDefaultPatchBaselineRequest,
     ) -> RusotoFuture<GetDefaultPatchBaselineResult, GetDefaultPatchBaselineError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetDefaultPatchBaseline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDefaultPatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDefaultPatchBaselineError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_default_patch_baseline(input)
     }
-
-    /// <p>Retrieves the current snapshot for the patch baseline the instance uses. This API is primarily used by the AWS-RunPatchBaseline Systems Manager document. </p>
     fn get_deployable_patch_snapshot_for_instance(
         &self,
         input: GetDeployablePatchSnapshotForInstanceRequest,
@@ -16652,206 +20394,45 @@ impl Ssm for SsmClient {
         GetDeployablePatchSnapshotForInstanceResult,
         GetDeployablePatchSnapshotForInstanceError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.GetDeployablePatchSnapshotForInstance",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDeployablePatchSnapshotForInstanceResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetDeployablePatchSnapshotForInstanceError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_deployable_patch_snapshot_for_instance(input)
     }
-
-    /// <p>Gets the contents of the specified Systems Manager document.</p>
     fn get_document(
         &self,
         input: GetDocumentRequest,
     ) -> RusotoFuture<GetDocumentResult, GetDocumentError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetDocument");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDocumentResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDocumentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_document(input)
     }
-
-    /// <p>Query inventory information.</p>
     fn get_inventory(
         &self,
         input: GetInventoryRequest,
     ) -> RusotoFuture<GetInventoryResult, GetInventoryError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetInventory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInventoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInventoryError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_inventory(input)
     }
-
-    /// <p>Return a list of inventory type names for the account, or return a list of attribute names for a specific Inventory item type. </p>
     fn get_inventory_schema(
         &self,
         input: GetInventorySchemaRequest,
     ) -> RusotoFuture<GetInventorySchemaResult, GetInventorySchemaError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetInventorySchema");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetInventorySchemaResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetInventorySchemaError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_inventory_schema(input)
     }
-
-    /// <p>Retrieves a maintenance window.</p>
     fn get_maintenance_window(
         &self,
         input: GetMaintenanceWindowRequest,
     ) -> RusotoFuture<GetMaintenanceWindowResult, GetMaintenanceWindowError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetMaintenanceWindowError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_maintenance_window(input)
     }
-
-    /// <p>Retrieves details about a specific a maintenance window execution.</p>
     fn get_maintenance_window_execution(
         &self,
         input: GetMaintenanceWindowExecutionRequest,
     ) -> RusotoFuture<GetMaintenanceWindowExecutionResult, GetMaintenanceWindowExecutionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMaintenanceWindowExecutionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetMaintenanceWindowExecutionError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_maintenance_window_execution(input)
     }
-
-    /// <p>Retrieves the details about a specific task run as part of a maintenance window execution.</p>
     fn get_maintenance_window_execution_task(
         &self,
         input: GetMaintenanceWindowExecutionTaskRequest,
     ) -> RusotoFuture<GetMaintenanceWindowExecutionTaskResult, GetMaintenanceWindowExecutionTaskError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.GetMaintenanceWindowExecutionTask",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMaintenanceWindowExecutionTaskResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetMaintenanceWindowExecutionTaskError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).get_maintenance_window_execution_task(input)
     }
-
-    /// <p>Retrieves information about a specific task running on a specific target.</p>
     fn get_maintenance_window_execution_task_invocation(
         &self,
         input: GetMaintenanceWindowExecutionTaskInvocationRequest,
@@ -16859,850 +20440,10447 @@ impl Ssm for SsmClient {
         GetMaintenanceWindowExecutionTaskInvocationResult,
         GetMaintenanceWindowExecutionTaskInvocationError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.GetMaintenanceWindowExecutionTaskInvocation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMaintenanceWindowExecutionTaskInvocationResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetMaintenanceWindowExecutionTaskInvocationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_maintenance_window_execution_task_invocation(input)
     }
-
-    /// <p>Lists the tasks in a maintenance window.</p>
     fn get_maintenance_window_task(
         &self,
         input: GetMaintenanceWindowTaskRequest,
     ) -> RusotoFuture<GetMaintenanceWindowTaskResult, GetMaintenanceWindowTaskError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMaintenanceWindowTaskResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetMaintenanceWindowTaskError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_maintenance_window_task(input)
     }
-
-    /// <p>Get information about an OpsItem by using the ID. You must have permission in AWS Identity and Access Management (IAM) to view information about an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
     fn get_ops_item(
         &self,
         input: GetOpsItemRequest,
     ) -> RusotoFuture<GetOpsItemResponse, GetOpsItemError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetOpsItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOpsItemResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetOpsItemError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ops_item(input)
     }
-
-    /// <p>View a summary of OpsItems based on specified filters and aggregators.</p>
     fn get_ops_summary(
         &self,
         input: GetOpsSummaryRequest,
     ) -> RusotoFuture<GetOpsSummaryResult, GetOpsSummaryError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetOpsSummary");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetOpsSummaryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetOpsSummaryError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ops_summary(input)
     }
-
-    /// <p>Get information about a parameter by using the parameter name. Don't confuse this API action with the <a>GetParameters</a> API action.</p>
     fn get_parameter(
         &self,
         input: GetParameterRequest,
     ) -> RusotoFuture<GetParameterResult, GetParameterError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetParameter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetParameterResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetParameterError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_parameter(input)
     }
-
-    /// <p>Query a list of all parameters used by the AWS account.</p>
     fn get_parameter_history(
         &self,
         input: GetParameterHistoryRequest,
     ) -> RusotoFuture<GetParameterHistoryResult, GetParameterHistoryError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetParameterHistory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetParameterHistoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetParameterHistoryError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_parameter_history(input)
     }
-
-    /// <p>Get details of a parameter. Don't confuse this API action with the <a>GetParameter</a> API action.</p>
     fn get_parameters(
         &self,
         input: GetParametersRequest,
     ) -> RusotoFuture<GetParametersResult, GetParametersError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetParameters");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetParametersResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetParametersError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_parameters(input)
     }
-
-    /// <p><p>Retrieve parameters in a specific hierarchy. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-paramstore-working.html">Working with Systems Manager Parameters</a> in the <i>AWS Systems Manager User Guide</i>. </p> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> <note> <p>This API action doesn&#39;t support filtering by tags. </p> </note></p>
     fn get_parameters_by_path(
         &self,
         input: GetParametersByPathRequest,
     ) -> RusotoFuture<GetParametersByPathResult, GetParametersByPathError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetParametersByPath");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetParametersByPathResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetParametersByPathError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_parameters_by_path(input)
+    }
+    fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> RusotoFuture<GetPatchBaselineResult, GetPatchBaselineError> {
+        (**self).get_patch_baseline(input)
+    }
+    fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<GetPatchBaselineForPatchGroupResult, GetPatchBaselineForPatchGroupError> {
+        (**self).get_patch_baseline_for_patch_group(input)
+    }
+    fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> RusotoFuture<GetServiceSettingResult, GetServiceSettingError> {
+        (**self).get_service_setting(input)
+    }
+    fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> RusotoFuture<LabelParameterVersionResult, LabelParameterVersionError> {
+        (**self).label_parameter_version(input)
+    }
+    fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> RusotoFuture<ListAssociationVersionsResult, ListAssociationVersionsError> {
+        (**self).list_association_versions(input)
+    }
+    fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> RusotoFuture<ListAssociationsResult, ListAssociationsError> {
+        (**self).list_associations(input)
+    }
+    fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> RusotoFuture<ListCommandInvocationsResult, ListCommandInvocationsError> {
+        (**self).list_command_invocations(input)
+    }
+    fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> RusotoFuture<ListCommandsResult, ListCommandsError> {
+        (**self).list_commands(input)
+    }
+    fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> RusotoFuture<ListComplianceItemsResult, ListComplianceItemsError> {
+        (**self).list_compliance_items(input)
+    }
+    fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> RusotoFuture<ListComplianceSummariesResult, ListComplianceSummariesError> {
+        (**self).list_compliance_summaries(input)
+    }
+    fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> RusotoFuture<ListDocumentVersionsResult, ListDocumentVersionsError> {
+        (**self).list_document_versions(input)
+    }
+    fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> RusotoFuture<ListDocumentsResult, ListDocumentsError> {
+        (**self).list_documents(input)
+    }
+    fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> RusotoFuture<ListInventoryEntriesResult, ListInventoryEntriesError> {
+        (**self).list_inventory_entries(input)
+    }
+    fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> RusotoFuture<ListResourceComplianceSummariesResult, ListResourceComplianceSummariesError>
+    {
+        (**self).list_resource_compliance_summaries(input)
+    }
+    fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> RusotoFuture<ListResourceDataSyncResult, ListResourceDataSyncError> {
+        (**self).list_resource_data_sync(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> RusotoFuture<ModifyDocumentPermissionResponse, ModifyDocumentPermissionError> {
+        (**self).modify_document_permission(input)
+    }
+    fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> RusotoFuture<PutComplianceItemsResult, PutComplianceItemsError> {
+        (**self).put_compliance_items(input)
+    }
+    fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> RusotoFuture<PutInventoryResult, PutInventoryError> {
+        (**self).put_inventory(input)
+    }
+    fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> RusotoFuture<PutParameterResult, PutParameterError> {
+        (**self).put_parameter(input)
+    }
+    fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<RegisterDefaultPatchBaselineResult, RegisterDefaultPatchBaselineError> {
+        (**self).register_default_patch_baseline(input)
+    }
+    fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        RegisterPatchBaselineForPatchGroupResult,
+        RegisterPatchBaselineForPatchGroupError,
+    > {
+        (**self).register_patch_baseline_for_patch_group(input)
+    }
+    fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        RegisterTargetWithMaintenanceWindowResult,
+        RegisterTargetWithMaintenanceWindowError,
+    > {
+        (**self).register_target_with_maintenance_window(input)
+    }
+    fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<RegisterTaskWithMaintenanceWindowResult, RegisterTaskWithMaintenanceWindowError>
+    {
+        (**self).register_task_with_maintenance_window(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> RusotoFuture<ResetServiceSettingResult, ResetServiceSettingError> {
+        (**self).reset_service_setting(input)
+    }
+    fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> RusotoFuture<ResumeSessionResponse, ResumeSessionError> {
+        (**self).resume_session(input)
+    }
+    fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> RusotoFuture<SendAutomationSignalResult, SendAutomationSignalError> {
+        (**self).send_automation_signal(input)
+    }
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+    fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> RusotoFuture<StartAssociationsOnceResult, StartAssociationsOnceError> {
+        (**self).start_associations_once(input)
+    }
+    fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> RusotoFuture<StartAutomationExecutionResult, StartAutomationExecutionError> {
+        (**self).start_automation_execution(input)
+    }
+    fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> RusotoFuture<StartSessionResponse, StartSessionError> {
+        (**self).start_session(input)
+    }
+    fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> RusotoFuture<StopAutomationExecutionResult, StopAutomationExecutionError> {
+        (**self).stop_automation_execution(input)
+    }
+    fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> RusotoFuture<TerminateSessionResponse, TerminateSessionError> {
+        (**self).terminate_session(input)
+    }
+    fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> RusotoFuture<UpdateAssociationResult, UpdateAssociationError> {
+        (**self).update_association(input)
+    }
+    fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> RusotoFuture<UpdateAssociationStatusResult, UpdateAssociationStatusError> {
+        (**self).update_association_status(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<UpdateDocumentResult, UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> RusotoFuture<UpdateDocumentDefaultVersionResult, UpdateDocumentDefaultVersionError> {
+        (**self).update_document_default_version(input)
+    }
+    fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowResult, UpdateMaintenanceWindowError> {
+        (**self).update_maintenance_window(input)
+    }
+    fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTargetResult, UpdateMaintenanceWindowTargetError> {
+        (**self).update_maintenance_window_target(input)
+    }
+    fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTaskResult, UpdateMaintenanceWindowTaskError> {
+        (**self).update_maintenance_window_task(input)
+    }
+    fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> RusotoFuture<UpdateManagedInstanceRoleResult, UpdateManagedInstanceRoleError> {
+        (**self).update_managed_instance_role(input)
+    }
+    fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> RusotoFuture<UpdateOpsItemResponse, UpdateOpsItemError> {
+        (**self).update_ops_item(input)
+    }
+    fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> RusotoFuture<UpdatePatchBaselineResult, UpdatePatchBaselineError> {
+        (**self).update_patch_baseline(input)
+    }
+    fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> RusotoFuture<UpdateServiceSettingResult, UpdateServiceSettingError> {
+        (**self).update_service_setting(input)
+    }
+}
+impl<T: Ssm + ?Sized> Ssm for Box<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> RusotoFuture<CancelCommandResult, CancelCommandError> {
+        (**self).cancel_command(input)
+    }
+    fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<CancelMaintenanceWindowExecutionResult, CancelMaintenanceWindowExecutionError>
+    {
+        (**self).cancel_maintenance_window_execution(input)
+    }
+    fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> RusotoFuture<CreateActivationResult, CreateActivationError> {
+        (**self).create_activation(input)
+    }
+    fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> RusotoFuture<CreateAssociationResult, CreateAssociationError> {
+        (**self).create_association(input)
+    }
+    fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> RusotoFuture<CreateAssociationBatchResult, CreateAssociationBatchError> {
+        (**self).create_association_batch(input)
+    }
+    fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> RusotoFuture<CreateDocumentResult, CreateDocumentError> {
+        (**self).create_document(input)
+    }
+    fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> RusotoFuture<CreateMaintenanceWindowResult, CreateMaintenanceWindowError> {
+        (**self).create_maintenance_window(input)
+    }
+    fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> RusotoFuture<CreateOpsItemResponse, CreateOpsItemError> {
+        (**self).create_ops_item(input)
+    }
+    fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> RusotoFuture<CreatePatchBaselineResult, CreatePatchBaselineError> {
+        (**self).create_patch_baseline(input)
+    }
+    fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> RusotoFuture<CreateResourceDataSyncResult, CreateResourceDataSyncError> {
+        (**self).create_resource_data_sync(input)
+    }
+    fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> RusotoFuture<DeleteActivationResult, DeleteActivationError> {
+        (**self).delete_activation(input)
+    }
+    fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> RusotoFuture<DeleteAssociationResult, DeleteAssociationError> {
+        (**self).delete_association(input)
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<DeleteDocumentResult, DeleteDocumentError> {
+        (**self).delete_document(input)
+    }
+    fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> RusotoFuture<DeleteInventoryResult, DeleteInventoryError> {
+        (**self).delete_inventory(input)
+    }
+    fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> RusotoFuture<DeleteMaintenanceWindowResult, DeleteMaintenanceWindowError> {
+        (**self).delete_maintenance_window(input)
+    }
+    fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> RusotoFuture<DeleteParameterResult, DeleteParameterError> {
+        (**self).delete_parameter(input)
+    }
+    fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> RusotoFuture<DeleteParametersResult, DeleteParametersError> {
+        (**self).delete_parameters(input)
+    }
+    fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> RusotoFuture<DeletePatchBaselineResult, DeletePatchBaselineError> {
+        (**self).delete_patch_baseline(input)
+    }
+    fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> RusotoFuture<DeleteResourceDataSyncResult, DeleteResourceDataSyncError> {
+        (**self).delete_resource_data_sync(input)
+    }
+    fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> RusotoFuture<DeregisterManagedInstanceResult, DeregisterManagedInstanceError> {
+        (**self).deregister_managed_instance(input)
+    }
+    fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DeregisterPatchBaselineForPatchGroupResult,
+        DeregisterPatchBaselineForPatchGroupError,
+    > {
+        (**self).deregister_patch_baseline_for_patch_group(input)
+    }
+    fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTargetFromMaintenanceWindowResult,
+        DeregisterTargetFromMaintenanceWindowError,
+    > {
+        (**self).deregister_target_from_maintenance_window(input)
+    }
+    fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTaskFromMaintenanceWindowResult,
+        DeregisterTaskFromMaintenanceWindowError,
+    > {
+        (**self).deregister_task_from_maintenance_window(input)
+    }
+    fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> RusotoFuture<DescribeActivationsResult, DescribeActivationsError> {
+        (**self).describe_activations(input)
+    }
+    fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> RusotoFuture<DescribeAssociationResult, DescribeAssociationError> {
+        (**self).describe_association(input)
+    }
+    fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> RusotoFuture<
+        DescribeAssociationExecutionTargetsResult,
+        DescribeAssociationExecutionTargetsError,
+    > {
+        (**self).describe_association_execution_targets(input)
+    }
+    fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAssociationExecutionsResult, DescribeAssociationExecutionsError> {
+        (**self).describe_association_executions(input)
+    }
+    fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationExecutionsResult, DescribeAutomationExecutionsError> {
+        (**self).describe_automation_executions(input)
+    }
+    fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationStepExecutionsResult, DescribeAutomationStepExecutionsError>
+    {
+        (**self).describe_automation_step_executions(input)
+    }
+    fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> RusotoFuture<DescribeAvailablePatchesResult, DescribeAvailablePatchesError> {
+        (**self).describe_available_patches(input)
+    }
+    fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> RusotoFuture<DescribeDocumentResult, DescribeDocumentError> {
+        (**self).describe_document(input)
+    }
+    fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> RusotoFuture<DescribeDocumentPermissionResponse, DescribeDocumentPermissionError> {
+        (**self).describe_document_permission(input)
+    }
+    fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeEffectiveInstanceAssociationsResult,
+        DescribeEffectiveInstanceAssociationsError,
+    > {
+        (**self).describe_effective_instance_associations(input)
+    }
+    fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> RusotoFuture<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        DescribeEffectivePatchesForPatchBaselineError,
+    > {
+        (**self).describe_effective_patches_for_patch_baseline(input)
+    }
+    fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAssociationsStatusResult,
+        DescribeInstanceAssociationsStatusError,
+    > {
+        (**self).describe_instance_associations_status(input)
+    }
+    fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> RusotoFuture<DescribeInstanceInformationResult, DescribeInstanceInformationError> {
+        (**self).describe_instance_information(input)
+    }
+    fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchStatesResult, DescribeInstancePatchStatesError> {
+        (**self).describe_instance_patch_states(input)
+    }
+    fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        DescribeInstancePatchStatesForPatchGroupError,
+    > {
+        (**self).describe_instance_patch_states_for_patch_group(input)
+    }
+    fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchesResult, DescribeInstancePatchesError> {
+        (**self).describe_instance_patches(input)
+    }
+    fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> RusotoFuture<DescribeInventoryDeletionsResult, DescribeInventoryDeletionsError> {
+        (**self).describe_inventory_deletions(input)
+    }
+    fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        DescribeMaintenanceWindowExecutionTaskInvocationsError,
+    > {
+        (**self).describe_maintenance_window_execution_task_invocations(input)
+    }
+    fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        DescribeMaintenanceWindowExecutionTasksError,
+    > {
+        (**self).describe_maintenance_window_execution_tasks(input)
+    }
+    fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionsResult,
+        DescribeMaintenanceWindowExecutionsError,
+    > {
+        (**self).describe_maintenance_window_executions(input)
+    }
+    fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowScheduleResult, DescribeMaintenanceWindowScheduleError>
+    {
+        (**self).describe_maintenance_window_schedule(input)
+    }
+    fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTargetsResult, DescribeMaintenanceWindowTargetsError>
+    {
+        (**self).describe_maintenance_window_targets(input)
+    }
+    fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTasksResult, DescribeMaintenanceWindowTasksError>
+    {
+        (**self).describe_maintenance_window_tasks(input)
+    }
+    fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowsResult, DescribeMaintenanceWindowsError> {
+        (**self).describe_maintenance_windows(input)
+    }
+    fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowsForTargetResult,
+        DescribeMaintenanceWindowsForTargetError,
+    > {
+        (**self).describe_maintenance_windows_for_target(input)
+    }
+    fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> RusotoFuture<DescribeOpsItemsResponse, DescribeOpsItemsError> {
+        (**self).describe_ops_items(input)
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResult, DescribeParametersError> {
+        (**self).describe_parameters(input)
+    }
+    fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> RusotoFuture<DescribePatchBaselinesResult, DescribePatchBaselinesError> {
+        (**self).describe_patch_baselines(input)
+    }
+    fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> RusotoFuture<DescribePatchGroupStateResult, DescribePatchGroupStateError> {
+        (**self).describe_patch_group_state(input)
+    }
+    fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> RusotoFuture<DescribePatchGroupsResult, DescribePatchGroupsError> {
+        (**self).describe_patch_groups(input)
+    }
+    fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> RusotoFuture<DescribePatchPropertiesResult, DescribePatchPropertiesError> {
+        (**self).describe_patch_properties(input)
+    }
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResponse, DescribeSessionsError> {
+        (**self).describe_sessions(input)
+    }
+    fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> RusotoFuture<GetAutomationExecutionResult, GetAutomationExecutionError> {
+        (**self).get_automation_execution(input)
+    }
+    fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> RusotoFuture<GetCommandInvocationResult, GetCommandInvocationError> {
+        (**self).get_command_invocation(input)
+    }
+    fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> RusotoFuture<GetConnectionStatusResponse, GetConnectionStatusError> {
+        (**self).get_connection_status(input)
+    }
+    fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<GetDefaultPatchBaselineResult, GetDefaultPatchBaselineError> {
+        (**self).get_default_patch_baseline(input)
+    }
+    fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> RusotoFuture<
+        GetDeployablePatchSnapshotForInstanceResult,
+        GetDeployablePatchSnapshotForInstanceError,
+    > {
+        (**self).get_deployable_patch_snapshot_for_instance(input)
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResult, GetDocumentError> {
+        (**self).get_document(input)
+    }
+    fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> RusotoFuture<GetInventoryResult, GetInventoryError> {
+        (**self).get_inventory(input)
+    }
+    fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> RusotoFuture<GetInventorySchemaResult, GetInventorySchemaError> {
+        (**self).get_inventory_schema(input)
+    }
+    fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowResult, GetMaintenanceWindowError> {
+        (**self).get_maintenance_window(input)
+    }
+    fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionResult, GetMaintenanceWindowExecutionError> {
+        (**self).get_maintenance_window_execution(input)
+    }
+    fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionTaskResult, GetMaintenanceWindowExecutionTaskError>
+    {
+        (**self).get_maintenance_window_execution_task(input)
+    }
+    fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> RusotoFuture<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        GetMaintenanceWindowExecutionTaskInvocationError,
+    > {
+        (**self).get_maintenance_window_execution_task_invocation(input)
+    }
+    fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowTaskResult, GetMaintenanceWindowTaskError> {
+        (**self).get_maintenance_window_task(input)
+    }
+    fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> RusotoFuture<GetOpsItemResponse, GetOpsItemError> {
+        (**self).get_ops_item(input)
+    }
+    fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> RusotoFuture<GetOpsSummaryResult, GetOpsSummaryError> {
+        (**self).get_ops_summary(input)
+    }
+    fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> RusotoFuture<GetParameterResult, GetParameterError> {
+        (**self).get_parameter(input)
+    }
+    fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> RusotoFuture<GetParameterHistoryResult, GetParameterHistoryError> {
+        (**self).get_parameter_history(input)
+    }
+    fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> RusotoFuture<GetParametersResult, GetParametersError> {
+        (**self).get_parameters(input)
+    }
+    fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> RusotoFuture<GetParametersByPathResult, GetParametersByPathError> {
+        (**self).get_parameters_by_path(input)
+    }
+    fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> RusotoFuture<GetPatchBaselineResult, GetPatchBaselineError> {
+        (**self).get_patch_baseline(input)
+    }
+    fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<GetPatchBaselineForPatchGroupResult, GetPatchBaselineForPatchGroupError> {
+        (**self).get_patch_baseline_for_patch_group(input)
+    }
+    fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> RusotoFuture<GetServiceSettingResult, GetServiceSettingError> {
+        (**self).get_service_setting(input)
+    }
+    fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> RusotoFuture<LabelParameterVersionResult, LabelParameterVersionError> {
+        (**self).label_parameter_version(input)
+    }
+    fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> RusotoFuture<ListAssociationVersionsResult, ListAssociationVersionsError> {
+        (**self).list_association_versions(input)
+    }
+    fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> RusotoFuture<ListAssociationsResult, ListAssociationsError> {
+        (**self).list_associations(input)
+    }
+    fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> RusotoFuture<ListCommandInvocationsResult, ListCommandInvocationsError> {
+        (**self).list_command_invocations(input)
+    }
+    fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> RusotoFuture<ListCommandsResult, ListCommandsError> {
+        (**self).list_commands(input)
+    }
+    fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> RusotoFuture<ListComplianceItemsResult, ListComplianceItemsError> {
+        (**self).list_compliance_items(input)
+    }
+    fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> RusotoFuture<ListComplianceSummariesResult, ListComplianceSummariesError> {
+        (**self).list_compliance_summaries(input)
+    }
+    fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> RusotoFuture<ListDocumentVersionsResult, ListDocumentVersionsError> {
+        (**self).list_document_versions(input)
+    }
+    fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> RusotoFuture<ListDocumentsResult, ListDocumentsError> {
+        (**self).list_documents(input)
+    }
+    fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> RusotoFuture<ListInventoryEntriesResult, ListInventoryEntriesError> {
+        (**self).list_inventory_entries(input)
+    }
+    fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> RusotoFuture<ListResourceComplianceSummariesResult, ListResourceComplianceSummariesError>
+    {
+        (**self).list_resource_compliance_summaries(input)
+    }
+    fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> RusotoFuture<ListResourceDataSyncResult, ListResourceDataSyncError> {
+        (**self).list_resource_data_sync(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> RusotoFuture<ModifyDocumentPermissionResponse, ModifyDocumentPermissionError> {
+        (**self).modify_document_permission(input)
+    }
+    fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> RusotoFuture<PutComplianceItemsResult, PutComplianceItemsError> {
+        (**self).put_compliance_items(input)
+    }
+    fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> RusotoFuture<PutInventoryResult, PutInventoryError> {
+        (**self).put_inventory(input)
+    }
+    fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> RusotoFuture<PutParameterResult, PutParameterError> {
+        (**self).put_parameter(input)
+    }
+    fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<RegisterDefaultPatchBaselineResult, RegisterDefaultPatchBaselineError> {
+        (**self).register_default_patch_baseline(input)
+    }
+    fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        RegisterPatchBaselineForPatchGroupResult,
+        RegisterPatchBaselineForPatchGroupError,
+    > {
+        (**self).register_patch_baseline_for_patch_group(input)
+    }
+    fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        RegisterTargetWithMaintenanceWindowResult,
+        RegisterTargetWithMaintenanceWindowError,
+    > {
+        (**self).register_target_with_maintenance_window(input)
+    }
+    fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<RegisterTaskWithMaintenanceWindowResult, RegisterTaskWithMaintenanceWindowError>
+    {
+        (**self).register_task_with_maintenance_window(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> RusotoFuture<ResetServiceSettingResult, ResetServiceSettingError> {
+        (**self).reset_service_setting(input)
+    }
+    fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> RusotoFuture<ResumeSessionResponse, ResumeSessionError> {
+        (**self).resume_session(input)
+    }
+    fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> RusotoFuture<SendAutomationSignalResult, SendAutomationSignalError> {
+        (**self).send_automation_signal(input)
+    }
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+    fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> RusotoFuture<StartAssociationsOnceResult, StartAssociationsOnceError> {
+        (**self).start_associations_once(input)
+    }
+    fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> RusotoFuture<StartAutomationExecutionResult, StartAutomationExecutionError> {
+        (**self).start_automation_execution(input)
+    }
+    fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> RusotoFuture<StartSessionResponse, StartSessionError> {
+        (**self).start_session(input)
+    }
+    fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> RusotoFuture<StopAutomationExecutionResult, StopAutomationExecutionError> {
+        (**self).stop_automation_execution(input)
+    }
+    fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> RusotoFuture<TerminateSessionResponse, TerminateSessionError> {
+        (**self).terminate_session(input)
+    }
+    fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> RusotoFuture<UpdateAssociationResult, UpdateAssociationError> {
+        (**self).update_association(input)
+    }
+    fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> RusotoFuture<UpdateAssociationStatusResult, UpdateAssociationStatusError> {
+        (**self).update_association_status(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<UpdateDocumentResult, UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> RusotoFuture<UpdateDocumentDefaultVersionResult, UpdateDocumentDefaultVersionError> {
+        (**self).update_document_default_version(input)
+    }
+    fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowResult, UpdateMaintenanceWindowError> {
+        (**self).update_maintenance_window(input)
+    }
+    fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTargetResult, UpdateMaintenanceWindowTargetError> {
+        (**self).update_maintenance_window_target(input)
+    }
+    fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTaskResult, UpdateMaintenanceWindowTaskError> {
+        (**self).update_maintenance_window_task(input)
+    }
+    fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> RusotoFuture<UpdateManagedInstanceRoleResult, UpdateManagedInstanceRoleError> {
+        (**self).update_managed_instance_role(input)
+    }
+    fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> RusotoFuture<UpdateOpsItemResponse, UpdateOpsItemError> {
+        (**self).update_ops_item(input)
+    }
+    fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> RusotoFuture<UpdatePatchBaselineResult, UpdatePatchBaselineError> {
+        (**self).update_patch_baseline(input)
+    }
+    fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> RusotoFuture<UpdateServiceSettingResult, UpdateServiceSettingError> {
+        (**self).update_service_setting(input)
+    }
+}
+impl<T: Ssm + ?Sized> Ssm for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> RusotoFuture<CancelCommandResult, CancelCommandError> {
+        (**self).cancel_command(input)
+    }
+    fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<CancelMaintenanceWindowExecutionResult, CancelMaintenanceWindowExecutionError>
+    {
+        (**self).cancel_maintenance_window_execution(input)
+    }
+    fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> RusotoFuture<CreateActivationResult, CreateActivationError> {
+        (**self).create_activation(input)
+    }
+    fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> RusotoFuture<CreateAssociationResult, CreateAssociationError> {
+        (**self).create_association(input)
+    }
+    fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> RusotoFuture<CreateAssociationBatchResult, CreateAssociationBatchError> {
+        (**self).create_association_batch(input)
+    }
+    fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> RusotoFuture<CreateDocumentResult, CreateDocumentError> {
+        (**self).create_document(input)
+    }
+    fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> RusotoFuture<CreateMaintenanceWindowResult, CreateMaintenanceWindowError> {
+        (**self).create_maintenance_window(input)
+    }
+    fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> RusotoFuture<CreateOpsItemResponse, CreateOpsItemError> {
+        (**self).create_ops_item(input)
+    }
+    fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> RusotoFuture<CreatePatchBaselineResult, CreatePatchBaselineError> {
+        (**self).create_patch_baseline(input)
+    }
+    fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> RusotoFuture<CreateResourceDataSyncResult, CreateResourceDataSyncError> {
+        (**self).create_resource_data_sync(input)
+    }
+    fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> RusotoFuture<DeleteActivationResult, DeleteActivationError> {
+        (**self).delete_activation(input)
+    }
+    fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> RusotoFuture<DeleteAssociationResult, DeleteAssociationError> {
+        (**self).delete_association(input)
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<DeleteDocumentResult, DeleteDocumentError> {
+        (**self).delete_document(input)
+    }
+    fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> RusotoFuture<DeleteInventoryResult, DeleteInventoryError> {
+        (**self).delete_inventory(input)
+    }
+    fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> RusotoFuture<DeleteMaintenanceWindowResult, DeleteMaintenanceWindowError> {
+        (**self).delete_maintenance_window(input)
+    }
+    fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> RusotoFuture<DeleteParameterResult, DeleteParameterError> {
+        (**self).delete_parameter(input)
+    }
+    fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> RusotoFuture<DeleteParametersResult, DeleteParametersError> {
+        (**self).delete_parameters(input)
+    }
+    fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> RusotoFuture<DeletePatchBaselineResult, DeletePatchBaselineError> {
+        (**self).delete_patch_baseline(input)
+    }
+    fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> RusotoFuture<DeleteResourceDataSyncResult, DeleteResourceDataSyncError> {
+        (**self).delete_resource_data_sync(input)
+    }
+    fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> RusotoFuture<DeregisterManagedInstanceResult, DeregisterManagedInstanceError> {
+        (**self).deregister_managed_instance(input)
+    }
+    fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DeregisterPatchBaselineForPatchGroupResult,
+        DeregisterPatchBaselineForPatchGroupError,
+    > {
+        (**self).deregister_patch_baseline_for_patch_group(input)
+    }
+    fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTargetFromMaintenanceWindowResult,
+        DeregisterTargetFromMaintenanceWindowError,
+    > {
+        (**self).deregister_target_from_maintenance_window(input)
+    }
+    fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTaskFromMaintenanceWindowResult,
+        DeregisterTaskFromMaintenanceWindowError,
+    > {
+        (**self).deregister_task_from_maintenance_window(input)
+    }
+    fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> RusotoFuture<DescribeActivationsResult, DescribeActivationsError> {
+        (**self).describe_activations(input)
+    }
+    fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> RusotoFuture<DescribeAssociationResult, DescribeAssociationError> {
+        (**self).describe_association(input)
+    }
+    fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> RusotoFuture<
+        DescribeAssociationExecutionTargetsResult,
+        DescribeAssociationExecutionTargetsError,
+    > {
+        (**self).describe_association_execution_targets(input)
+    }
+    fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAssociationExecutionsResult, DescribeAssociationExecutionsError> {
+        (**self).describe_association_executions(input)
+    }
+    fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationExecutionsResult, DescribeAutomationExecutionsError> {
+        (**self).describe_automation_executions(input)
+    }
+    fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationStepExecutionsResult, DescribeAutomationStepExecutionsError>
+    {
+        (**self).describe_automation_step_executions(input)
+    }
+    fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> RusotoFuture<DescribeAvailablePatchesResult, DescribeAvailablePatchesError> {
+        (**self).describe_available_patches(input)
+    }
+    fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> RusotoFuture<DescribeDocumentResult, DescribeDocumentError> {
+        (**self).describe_document(input)
+    }
+    fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> RusotoFuture<DescribeDocumentPermissionResponse, DescribeDocumentPermissionError> {
+        (**self).describe_document_permission(input)
+    }
+    fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeEffectiveInstanceAssociationsResult,
+        DescribeEffectiveInstanceAssociationsError,
+    > {
+        (**self).describe_effective_instance_associations(input)
+    }
+    fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> RusotoFuture<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        DescribeEffectivePatchesForPatchBaselineError,
+    > {
+        (**self).describe_effective_patches_for_patch_baseline(input)
+    }
+    fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAssociationsStatusResult,
+        DescribeInstanceAssociationsStatusError,
+    > {
+        (**self).describe_instance_associations_status(input)
+    }
+    fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> RusotoFuture<DescribeInstanceInformationResult, DescribeInstanceInformationError> {
+        (**self).describe_instance_information(input)
+    }
+    fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchStatesResult, DescribeInstancePatchStatesError> {
+        (**self).describe_instance_patch_states(input)
+    }
+    fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        DescribeInstancePatchStatesForPatchGroupError,
+    > {
+        (**self).describe_instance_patch_states_for_patch_group(input)
+    }
+    fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchesResult, DescribeInstancePatchesError> {
+        (**self).describe_instance_patches(input)
+    }
+    fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> RusotoFuture<DescribeInventoryDeletionsResult, DescribeInventoryDeletionsError> {
+        (**self).describe_inventory_deletions(input)
+    }
+    fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        DescribeMaintenanceWindowExecutionTaskInvocationsError,
+    > {
+        (**self).describe_maintenance_window_execution_task_invocations(input)
+    }
+    fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        DescribeMaintenanceWindowExecutionTasksError,
+    > {
+        (**self).describe_maintenance_window_execution_tasks(input)
+    }
+    fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionsResult,
+        DescribeMaintenanceWindowExecutionsError,
+    > {
+        (**self).describe_maintenance_window_executions(input)
+    }
+    fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowScheduleResult, DescribeMaintenanceWindowScheduleError>
+    {
+        (**self).describe_maintenance_window_schedule(input)
+    }
+    fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTargetsResult, DescribeMaintenanceWindowTargetsError>
+    {
+        (**self).describe_maintenance_window_targets(input)
+    }
+    fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTasksResult, DescribeMaintenanceWindowTasksError>
+    {
+        (**self).describe_maintenance_window_tasks(input)
+    }
+    fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowsResult, DescribeMaintenanceWindowsError> {
+        (**self).describe_maintenance_windows(input)
+    }
+    fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowsForTargetResult,
+        DescribeMaintenanceWindowsForTargetError,
+    > {
+        (**self).describe_maintenance_windows_for_target(input)
+    }
+    fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> RusotoFuture<DescribeOpsItemsResponse, DescribeOpsItemsError> {
+        (**self).describe_ops_items(input)
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResult, DescribeParametersError> {
+        (**self).describe_parameters(input)
+    }
+    fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> RusotoFuture<DescribePatchBaselinesResult, DescribePatchBaselinesError> {
+        (**self).describe_patch_baselines(input)
+    }
+    fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> RusotoFuture<DescribePatchGroupStateResult, DescribePatchGroupStateError> {
+        (**self).describe_patch_group_state(input)
+    }
+    fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> RusotoFuture<DescribePatchGroupsResult, DescribePatchGroupsError> {
+        (**self).describe_patch_groups(input)
+    }
+    fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> RusotoFuture<DescribePatchPropertiesResult, DescribePatchPropertiesError> {
+        (**self).describe_patch_properties(input)
+    }
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResponse, DescribeSessionsError> {
+        (**self).describe_sessions(input)
+    }
+    fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> RusotoFuture<GetAutomationExecutionResult, GetAutomationExecutionError> {
+        (**self).get_automation_execution(input)
+    }
+    fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> RusotoFuture<GetCommandInvocationResult, GetCommandInvocationError> {
+        (**self).get_command_invocation(input)
+    }
+    fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> RusotoFuture<GetConnectionStatusResponse, GetConnectionStatusError> {
+        (**self).get_connection_status(input)
+    }
+    fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<GetDefaultPatchBaselineResult, GetDefaultPatchBaselineError> {
+        (**self).get_default_patch_baseline(input)
+    }
+    fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> RusotoFuture<
+        GetDeployablePatchSnapshotForInstanceResult,
+        GetDeployablePatchSnapshotForInstanceError,
+    > {
+        (**self).get_deployable_patch_snapshot_for_instance(input)
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResult, GetDocumentError> {
+        (**self).get_document(input)
+    }
+    fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> RusotoFuture<GetInventoryResult, GetInventoryError> {
+        (**self).get_inventory(input)
+    }
+    fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> RusotoFuture<GetInventorySchemaResult, GetInventorySchemaError> {
+        (**self).get_inventory_schema(input)
+    }
+    fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowResult, GetMaintenanceWindowError> {
+        (**self).get_maintenance_window(input)
+    }
+    fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionResult, GetMaintenanceWindowExecutionError> {
+        (**self).get_maintenance_window_execution(input)
+    }
+    fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionTaskResult, GetMaintenanceWindowExecutionTaskError>
+    {
+        (**self).get_maintenance_window_execution_task(input)
+    }
+    fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> RusotoFuture<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        GetMaintenanceWindowExecutionTaskInvocationError,
+    > {
+        (**self).get_maintenance_window_execution_task_invocation(input)
+    }
+    fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowTaskResult, GetMaintenanceWindowTaskError> {
+        (**self).get_maintenance_window_task(input)
+    }
+    fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> RusotoFuture<GetOpsItemResponse, GetOpsItemError> {
+        (**self).get_ops_item(input)
+    }
+    fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> RusotoFuture<GetOpsSummaryResult, GetOpsSummaryError> {
+        (**self).get_ops_summary(input)
+    }
+    fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> RusotoFuture<GetParameterResult, GetParameterError> {
+        (**self).get_parameter(input)
+    }
+    fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> RusotoFuture<GetParameterHistoryResult, GetParameterHistoryError> {
+        (**self).get_parameter_history(input)
+    }
+    fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> RusotoFuture<GetParametersResult, GetParametersError> {
+        (**self).get_parameters(input)
+    }
+    fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> RusotoFuture<GetParametersByPathResult, GetParametersByPathError> {
+        (**self).get_parameters_by_path(input)
+    }
+    fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> RusotoFuture<GetPatchBaselineResult, GetPatchBaselineError> {
+        (**self).get_patch_baseline(input)
+    }
+    fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<GetPatchBaselineForPatchGroupResult, GetPatchBaselineForPatchGroupError> {
+        (**self).get_patch_baseline_for_patch_group(input)
+    }
+    fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> RusotoFuture<GetServiceSettingResult, GetServiceSettingError> {
+        (**self).get_service_setting(input)
+    }
+    fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> RusotoFuture<LabelParameterVersionResult, LabelParameterVersionError> {
+        (**self).label_parameter_version(input)
+    }
+    fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> RusotoFuture<ListAssociationVersionsResult, ListAssociationVersionsError> {
+        (**self).list_association_versions(input)
+    }
+    fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> RusotoFuture<ListAssociationsResult, ListAssociationsError> {
+        (**self).list_associations(input)
+    }
+    fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> RusotoFuture<ListCommandInvocationsResult, ListCommandInvocationsError> {
+        (**self).list_command_invocations(input)
+    }
+    fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> RusotoFuture<ListCommandsResult, ListCommandsError> {
+        (**self).list_commands(input)
+    }
+    fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> RusotoFuture<ListComplianceItemsResult, ListComplianceItemsError> {
+        (**self).list_compliance_items(input)
+    }
+    fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> RusotoFuture<ListComplianceSummariesResult, ListComplianceSummariesError> {
+        (**self).list_compliance_summaries(input)
+    }
+    fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> RusotoFuture<ListDocumentVersionsResult, ListDocumentVersionsError> {
+        (**self).list_document_versions(input)
+    }
+    fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> RusotoFuture<ListDocumentsResult, ListDocumentsError> {
+        (**self).list_documents(input)
+    }
+    fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> RusotoFuture<ListInventoryEntriesResult, ListInventoryEntriesError> {
+        (**self).list_inventory_entries(input)
+    }
+    fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> RusotoFuture<ListResourceComplianceSummariesResult, ListResourceComplianceSummariesError>
+    {
+        (**self).list_resource_compliance_summaries(input)
+    }
+    fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> RusotoFuture<ListResourceDataSyncResult, ListResourceDataSyncError> {
+        (**self).list_resource_data_sync(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> RusotoFuture<ModifyDocumentPermissionResponse, ModifyDocumentPermissionError> {
+        (**self).modify_document_permission(input)
+    }
+    fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> RusotoFuture<PutComplianceItemsResult, PutComplianceItemsError> {
+        (**self).put_compliance_items(input)
+    }
+    fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> RusotoFuture<PutInventoryResult, PutInventoryError> {
+        (**self).put_inventory(input)
+    }
+    fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> RusotoFuture<PutParameterResult, PutParameterError> {
+        (**self).put_parameter(input)
+    }
+    fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<RegisterDefaultPatchBaselineResult, RegisterDefaultPatchBaselineError> {
+        (**self).register_default_patch_baseline(input)
+    }
+    fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        RegisterPatchBaselineForPatchGroupResult,
+        RegisterPatchBaselineForPatchGroupError,
+    > {
+        (**self).register_patch_baseline_for_patch_group(input)
+    }
+    fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        RegisterTargetWithMaintenanceWindowResult,
+        RegisterTargetWithMaintenanceWindowError,
+    > {
+        (**self).register_target_with_maintenance_window(input)
+    }
+    fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<RegisterTaskWithMaintenanceWindowResult, RegisterTaskWithMaintenanceWindowError>
+    {
+        (**self).register_task_with_maintenance_window(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> RusotoFuture<ResetServiceSettingResult, ResetServiceSettingError> {
+        (**self).reset_service_setting(input)
+    }
+    fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> RusotoFuture<ResumeSessionResponse, ResumeSessionError> {
+        (**self).resume_session(input)
+    }
+    fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> RusotoFuture<SendAutomationSignalResult, SendAutomationSignalError> {
+        (**self).send_automation_signal(input)
+    }
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+    fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> RusotoFuture<StartAssociationsOnceResult, StartAssociationsOnceError> {
+        (**self).start_associations_once(input)
+    }
+    fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> RusotoFuture<StartAutomationExecutionResult, StartAutomationExecutionError> {
+        (**self).start_automation_execution(input)
+    }
+    fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> RusotoFuture<StartSessionResponse, StartSessionError> {
+        (**self).start_session(input)
+    }
+    fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> RusotoFuture<StopAutomationExecutionResult, StopAutomationExecutionError> {
+        (**self).stop_automation_execution(input)
+    }
+    fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> RusotoFuture<TerminateSessionResponse, TerminateSessionError> {
+        (**self).terminate_session(input)
+    }
+    fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> RusotoFuture<UpdateAssociationResult, UpdateAssociationError> {
+        (**self).update_association(input)
+    }
+    fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> RusotoFuture<UpdateAssociationStatusResult, UpdateAssociationStatusError> {
+        (**self).update_association_status(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<UpdateDocumentResult, UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> RusotoFuture<UpdateDocumentDefaultVersionResult, UpdateDocumentDefaultVersionError> {
+        (**self).update_document_default_version(input)
+    }
+    fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowResult, UpdateMaintenanceWindowError> {
+        (**self).update_maintenance_window(input)
+    }
+    fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTargetResult, UpdateMaintenanceWindowTargetError> {
+        (**self).update_maintenance_window_target(input)
+    }
+    fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTaskResult, UpdateMaintenanceWindowTaskError> {
+        (**self).update_maintenance_window_task(input)
+    }
+    fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> RusotoFuture<UpdateManagedInstanceRoleResult, UpdateManagedInstanceRoleError> {
+        (**self).update_managed_instance_role(input)
+    }
+    fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> RusotoFuture<UpdateOpsItemResponse, UpdateOpsItemError> {
+        (**self).update_ops_item(input)
+    }
+    fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> RusotoFuture<UpdatePatchBaselineResult, UpdatePatchBaselineError> {
+        (**self).update_patch_baseline(input)
+    }
+    fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> RusotoFuture<UpdateServiceSettingResult, UpdateServiceSettingError> {
+        (**self).update_service_setting(input)
+    }
+}
+/// A client for the Amazon SSM API.
+#[derive(Clone)]
+pub struct SsmClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SsmClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SsmClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsmClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SsmClient {
+        SsmClient { client, region }
+    }
+}
+
+impl Ssm for SsmClient {
+    /// <p>Adds or overwrites one or more tags for the specified resource. Tags are metadata that you can assign to your documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. Each tag consists of a key and an optional value, both of which you define. For example, you could define a set of tags for your account's managed instances that helps you track each instance's owner and stack level. For example: Key=Owner and Value=DbAdmin, SysAdmin, or Dev. Or Key=Stack and Value=Production, Pre-Production, or Test.</p> <p>Each resource can have a maximum of 50 tags. </p> <p>We recommend that you devise a set of tag keys that meets your needs for each resource type. Using a consistent set of tag keys makes it easier for you to manage your resources. You can search and filter the resources based on the tags you add. Tags don't have any semantic meaning to Amazon EC2 and are interpreted strictly as a string of characters. </p> <p>For more information about tags, see <a href="http://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Amazon EC2 Resources</a> in the <i>Amazon EC2 User Guide</i>.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsToResourceResult, _>(
+                    response,
+                    "AddTagsToResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Attempts to cancel the command specified by the Command ID. There is no guarantee that the command will be terminated and the underlying process stopped.</p>
+    fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> RusotoFuture<CancelCommandResult, CancelCommandError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CancelCommand");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelCommandResult, _>(
+                    response,
+                    "CancelCommand",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelCommandError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stops a maintenance window execution that is already in progress and cancels any tasks in the window that have not already starting running. (Tasks already in progress will continue to completion.)</p>
+    fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<CancelMaintenanceWindowExecutionResult, CancelMaintenanceWindowExecutionError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CancelMaintenanceWindowExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelMaintenanceWindowExecutionResult, _>(
+                    response,
+                    "CancelMaintenanceWindowExecution",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CancelMaintenanceWindowExecutionError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Registers your on-premises server or virtual machine with Amazon EC2 so that you can manage these resources using Run Command. An on-premises server or virtual machine that has been registered with EC2 is called a managed instance. For more information about activations, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-managedinstances.html">Setting Up AWS Systems Manager for Hybrid Environments</a>.</p>
+    fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> RusotoFuture<CreateActivationResult, CreateActivationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateActivation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateActivationResult, _>(
+                    response,
+                    "CreateActivation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateActivationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
+    fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> RusotoFuture<CreateAssociationResult, CreateAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAssociationResult, _>(
+                    response,
+                    "CreateAssociation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAssociationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
+    fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> RusotoFuture<CreateAssociationBatchResult, CreateAssociationBatchError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateAssociationBatch");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAssociationBatchResult, _>(
+                    response,
+                    "CreateAssociationBatch",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateAssociationBatchError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a Systems Manager document.</p> <p>After you create a document, you can use CreateAssociation to associate it with one or more running instances.</p>
+    fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> RusotoFuture<CreateDocumentResult, CreateDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateDocument");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateDocumentResult, _>(
+                    response,
+                    "CreateDocument",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new maintenance window.</p>
+    fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> RusotoFuture<CreateMaintenanceWindowResult, CreateMaintenanceWindowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateMaintenanceWindowResult, _>(
+                    response,
+                    "CreateMaintenanceWindow",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateMaintenanceWindowError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a new OpsItem. You must have permission in AWS Identity and Access Management (IAM) to create a new OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> RusotoFuture<CreateOpsItemResponse, CreateOpsItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateOpsItem");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateOpsItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateOpsItemResponse, _>(
+                    response,
+                    "CreateOpsItem",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateOpsItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a patch baseline.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
+    fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> RusotoFuture<CreatePatchBaselineResult, CreatePatchBaselineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreatePatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreatePatchBaselineResult, _>(
+                    response,
+                    "CreatePatchBaseline",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreatePatchBaselineError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a resource data sync configuration to a single bucket in Amazon S3. This is an asynchronous operation that returns immediately. After a successful initial sync is completed, the system continuously syncs data to the Amazon S3 bucket. To check the status of the sync, use the <a>ListResourceDataSync</a>.</p> <p>By default, data is not encrypted in Amazon S3. We strongly recommend that you enable encryption in Amazon S3 to ensure secure data storage. We also recommend that you secure access to the Amazon S3 bucket by creating a restrictive bucket policy. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-inventory-datasync.html">Configuring Resource Data Sync for Inventory</a> in the <i>AWS Systems Manager User Guide</i>.</p>
+    fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> RusotoFuture<CreateResourceDataSyncResult, CreateResourceDataSyncError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("CreateResourceDataSync");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.CreateResourceDataSync");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateResourceDataSyncResult, _>(
+                    response,
+                    "CreateResourceDataSync",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateResourceDataSyncError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an activation. You are not required to delete an activation. If you delete an activation, you can no longer use it to register additional managed instances. Deleting an activation does not de-register managed instances. You must manually de-register managed instances.</p>
+    fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> RusotoFuture<DeleteActivationResult, DeleteActivationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteActivation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteActivation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteActivationResult, _>(
+                    response,
+                    "DeleteActivation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteActivationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified Systems Manager document from the specified instance.</p> <p>When you disassociate a document from an instance, it does not change the configuration of the instance. To change the configuration state of an instance after you disassociate a document, you must create a new document with the desired configuration and associate it with the instance.</p>
+    fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> RusotoFuture<DeleteAssociationResult, DeleteAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteAssociation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAssociationResult, _>(
+                    response,
+                    "DeleteAssociation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAssociationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the Systems Manager document and all instance associations to the document.</p> <p>Before you delete the document, we recommend that you use <a>DeleteAssociation</a> to disassociate all instances that are associated with the document.</p>
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<DeleteDocumentResult, DeleteDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteDocument");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteDocument");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteDocumentResult, _>(
+                    response,
+                    "DeleteDocument",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Delete a custom inventory type, or the data associated with a custom Inventory type. Deleting a custom inventory type is also referred to as deleting a custom inventory schema.</p>
+    fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> RusotoFuture<DeleteInventoryResult, DeleteInventoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteInventory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteInventoryResult, _>(
+                    response,
+                    "DeleteInventory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteInventoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a maintenance window.</p>
+    fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> RusotoFuture<DeleteMaintenanceWindowResult, DeleteMaintenanceWindowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteMaintenanceWindow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteMaintenanceWindowResult, _>(
+                    response,
+                    "DeleteMaintenanceWindow",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteMaintenanceWindowError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Delete a parameter from the system.</p>
+    fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> RusotoFuture<DeleteParameterResult, DeleteParameterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteParameter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteParameter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteParameterResult, _>(
+                    response,
+                    "DeleteParameter",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteParameterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Delete a list of parameters.</p>
+    fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> RusotoFuture<DeleteParametersResult, DeleteParametersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteParameters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteParametersResult, _>(
+                    response,
+                    "DeleteParameters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteParametersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a patch baseline.</p>
+    fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> RusotoFuture<DeletePatchBaselineResult, DeletePatchBaselineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeletePatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeletePatchBaseline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeletePatchBaselineResult, _>(
+                    response,
+                    "DeletePatchBaseline",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeletePatchBaselineError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a Resource Data Sync configuration. After the configuration is deleted, changes to inventory data on managed instances are no longer synced with the target Amazon S3 bucket. Deleting a sync configuration does not delete data in the target Amazon S3 bucket.</p>
+    fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> RusotoFuture<DeleteResourceDataSyncResult, DeleteResourceDataSyncError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeleteResourceDataSync");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeleteResourceDataSync");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteResourceDataSyncResult, _>(
+                    response,
+                    "DeleteResourceDataSync",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteResourceDataSyncError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes the server or virtual machine from the list of registered servers. You can reregister the instance again at any time. If you don't plan to use Run Command on the server, we suggest uninstalling SSM Agent first.</p>
+    fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> RusotoFuture<DeregisterManagedInstanceResult, DeregisterManagedInstanceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterManagedInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DeregisterManagedInstance");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterManagedInstanceResult, _>(
+                    response,
+                    "DeregisterManagedInstance",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterManagedInstanceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes a patch group from a patch baseline.</p>
+    fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DeregisterPatchBaselineForPatchGroupResult,
+        DeregisterPatchBaselineForPatchGroupError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterPatchBaselineForPatchGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DeregisterPatchBaselineForPatchGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterPatchBaselineForPatchGroupResult, _>(
+                    response,
+                    "DeregisterPatchBaselineForPatchGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterPatchBaselineForPatchGroupError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes a target from a maintenance window.</p>
+    fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTargetFromMaintenanceWindowResult,
+        DeregisterTargetFromMaintenanceWindowError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterTargetFromMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DeregisterTargetFromMaintenanceWindow",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterTargetFromMaintenanceWindowResult, _>(response, "DeregisterTargetFromMaintenanceWindow")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterTargetFromMaintenanceWindowError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes a task from a maintenance window.</p>
+    fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTaskFromMaintenanceWindowResult,
+        DeregisterTaskFromMaintenanceWindowError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DeregisterTaskFromMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DeregisterTaskFromMaintenanceWindow",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterTaskFromMaintenanceWindowResult, _>(
+                    response,
+                    "DeregisterTaskFromMaintenanceWindow",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeregisterTaskFromMaintenanceWindowError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes details about the activation, such as the date and time the activation was created, its expiration date, the IAM role assigned to the instances in the activation, and the number of instances registered by using this activation.</p>
+    fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> RusotoFuture<DescribeActivationsResult, DescribeActivationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeActivations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeActivations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeActivationsResult, _>(
+                    response,
+                    "DescribeActivations",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeActivationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the association for the specified target or instance. If you created the association by using the <code>Targets</code> parameter, then you must retrieve the association by using the association ID. If you created the association by specifying an instance ID and a Systems Manager document, then you retrieve the association by specifying the document name and the instance ID. </p>
+    fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> RusotoFuture<DescribeAssociationResult, DescribeAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeAssociation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAssociationResult, _>(
+                    response,
+                    "DescribeAssociation",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeAssociationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Use this API action to view information about a specific execution of a specific association.</p>
+    fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> RusotoFuture<
+        DescribeAssociationExecutionTargetsResult,
+        DescribeAssociationExecutionTargetsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociationExecutionTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeAssociationExecutionTargets",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAssociationExecutionTargetsResult, _>(
+                    response,
+                    "DescribeAssociationExecutionTargets",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAssociationExecutionTargetsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Use this API action to view all executions for a specific association ID. </p>
+    fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAssociationExecutionsResult, DescribeAssociationExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAssociationExecutions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeAssociationExecutions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAssociationExecutionsResult, _>(
+                    response,
+                    "DescribeAssociationExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAssociationExecutionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides details about all active and terminated Automation executions.</p>
+    fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationExecutionsResult, DescribeAutomationExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAutomationExecutions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationExecutions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAutomationExecutionsResult, _>(
+                    response,
+                    "DescribeAutomationExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAutomationExecutionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Information about all active and terminated step executions in an Automation workflow.</p>
+    fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationStepExecutionsResult, DescribeAutomationStepExecutionsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAutomationStepExecutions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationStepExecutions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAutomationStepExecutionsResult, _>(
+                    response,
+                    "DescribeAutomationStepExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAutomationStepExecutionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all patches eligible to be included in a patch baseline.</p>
+    fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> RusotoFuture<DescribeAvailablePatchesResult, DescribeAvailablePatchesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeAvailablePatches");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeAvailablePatches");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAvailablePatchesResult, _>(
+                    response,
+                    "DescribeAvailablePatches",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAvailablePatchesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the specified Systems Manager document.</p>
+    fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> RusotoFuture<DescribeDocumentResult, DescribeDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeDocument");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeDocument");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDocumentResult, _>(
+                    response,
+                    "DescribeDocument",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the permissions for a Systems Manager document. If you created the document, you are the owner. If a document is shared, it can either be shared privately (by specifying a user's AWS account ID) or publicly (<i>All</i>). </p>
+    fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> RusotoFuture<DescribeDocumentPermissionResponse, DescribeDocumentPermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeDocumentPermission");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeDocumentPermission");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeDocumentPermissionResponse, _>(
+                    response,
+                    "DescribeDocumentPermission",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDocumentPermissionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>All associations for the instance(s).</p>
+    fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeEffectiveInstanceAssociationsResult,
+        DescribeEffectiveInstanceAssociationsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeEffectiveInstanceAssociations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeEffectiveInstanceAssociations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeEffectiveInstanceAssociationsResult, _>(response, "DescribeEffectiveInstanceAssociations")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEffectiveInstanceAssociationsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the current effective patches (the patch and the approval state) for the specified patch baseline. Note that this API applies only to Windows patch baselines.</p>
+    fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> RusotoFuture<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        DescribeEffectivePatchesForPatchBaselineError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeEffectivePatchesForPatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeEffectivePatchesForPatchBaseline",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeEffectivePatchesForPatchBaselineResult,
+                    _,
+                >(response, "DescribeEffectivePatchesForPatchBaseline")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeEffectivePatchesForPatchBaselineError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>The status of the associations for the instance(s).</p>
+    fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAssociationsStatusResult,
+        DescribeInstanceAssociationsStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstanceAssociationsStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeInstanceAssociationsStatus",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInstanceAssociationsStatusResult, _>(
+                    response,
+                    "DescribeInstanceAssociationsStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInstanceAssociationsStatusError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Describes one or more of your instances. You can use this to get information about instances like the operating system platform, the SSM Agent version (Linux), status etc. If you specify one or more instance IDs, it returns information for those instances. If you do not specify instance IDs, it returns information for all your instances. If you specify an instance ID that is not valid or an instance that you do not own, you receive an error. </p> <note> <p>The IamRole field for this API action is the Amazon Identity and Access Management (IAM) role assigned to on-premises instances. This call does not return the IAM role for Amazon EC2 instances.</p> </note></p>
+    fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> RusotoFuture<DescribeInstanceInformationResult, DescribeInstanceInformationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstanceInformation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeInstanceInformation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInstanceInformationResult, _>(
+                    response,
+                    "DescribeInstanceInformation",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInstanceInformationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the high-level patch state of one or more instances.</p>
+    fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchStatesResult, DescribeInstancePatchStatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatchStates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatchStates");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInstancePatchStatesResult, _>(
+                    response,
+                    "DescribeInstancePatchStates",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInstancePatchStatesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the high-level patch state for the instances in the specified patch group.</p>
+    fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        DescribeInstancePatchStatesForPatchGroupError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatchStatesForPatchGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeInstancePatchStatesForPatchGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeInstancePatchStatesForPatchGroupResult,
+                    _,
+                >(response, "DescribeInstancePatchStatesForPatchGroup")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInstancePatchStatesForPatchGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about the patches on the specified instance and their state relative to the patch baseline being used for the instance.</p>
+    fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchesResult, DescribeInstancePatchesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInstancePatches");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatches");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInstancePatchesResult, _>(
+                    response,
+                    "DescribeInstancePatches",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInstancePatchesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes a specific delete inventory operation.</p>
+    fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> RusotoFuture<DescribeInventoryDeletionsResult, DescribeInventoryDeletionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeInventoryDeletions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeInventoryDeletions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeInventoryDeletionsResult, _>(
+                    response,
+                    "DescribeInventoryDeletions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeInventoryDeletionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the individual task executions (one per target) for a particular task run as part of a maintenance window execution.</p>
+    fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        DescribeMaintenanceWindowExecutionTaskInvocationsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTaskInvocations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeMaintenanceWindowExecutionTaskInvocations",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+                    _,
+                >(
+                    response,
+                    "DescribeMaintenanceWindowExecutionTaskInvocations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(
+                        DescribeMaintenanceWindowExecutionTaskInvocationsError::from_response(
+                            response,
+                        ),
+                    )
+                }))
+            }
+        })
+    }
+
+    /// <p>For a given maintenance window execution, lists the tasks that were run.</p>
+    fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        DescribeMaintenanceWindowExecutionTasksError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutionTasks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeMaintenanceWindowExecutionTasks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowExecutionTasksResult, _>(response, "DescribeMaintenanceWindowExecutionTasks")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowExecutionTasksError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the executions of a maintenance window. This includes information about when the maintenance window was scheduled to be active, and information about tasks registered and run with the maintenance window.</p>
+    fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionsResult,
+        DescribeMaintenanceWindowExecutionsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowExecutions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeMaintenanceWindowExecutions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowExecutionsResult, _>(
+                    response,
+                    "DescribeMaintenanceWindowExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowExecutionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about upcoming executions of a maintenance window.</p>
+    fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowScheduleResult, DescribeMaintenanceWindowScheduleError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeMaintenanceWindowSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowScheduleResult, _>(
+                    response,
+                    "DescribeMaintenanceWindowSchedule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowScheduleError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the targets registered with the maintenance window.</p>
+    fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTargetsResult, DescribeMaintenanceWindowTargetsError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowTargets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTargets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowTargetsResult, _>(
+                    response,
+                    "DescribeMaintenanceWindowTargets",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowTargetsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the tasks in a maintenance window.</p>
+    fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTasksResult, DescribeMaintenanceWindowTasksError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowTasks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTasks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowTasksResult, _>(
+                    response,
+                    "DescribeMaintenanceWindowTasks",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowTasksError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the maintenance windows in an AWS account.</p>
+    fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowsResult, DescribeMaintenanceWindowsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindows");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindows");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowsResult, _>(
+                    response,
+                    "DescribeMaintenanceWindows",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about the maintenance window targets or tasks that an instance is associated with.</p>
+    fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowsForTargetResult,
+        DescribeMaintenanceWindowsForTargetError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeMaintenanceWindowsForTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.DescribeMaintenanceWindowsForTarget",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceWindowsForTargetResult, _>(
+                    response,
+                    "DescribeMaintenanceWindowsForTarget",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceWindowsForTargetError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Query a set of OpsItems. You must have permission in AWS Identity and Access Management (IAM) to query a list of OpsItems. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> RusotoFuture<DescribeOpsItemsResponse, DescribeOpsItemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeOpsItems");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeOpsItems");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOpsItemsResponse, _>(
+                    response,
+                    "DescribeOpsItems",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeOpsItemsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Get information about a parameter.</p> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p>
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResult, DescribeParametersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeParameters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeParametersResult, _>(
+                    response,
+                    "DescribeParameters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeParametersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the patch baselines in your AWS account.</p>
+    fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> RusotoFuture<DescribePatchBaselinesResult, DescribePatchBaselinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchBaselines");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribePatchBaselines");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePatchBaselinesResult, _>(
+                    response,
+                    "DescribePatchBaselines",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribePatchBaselinesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns high-level aggregated patch compliance state for a patch group.</p>
+    fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> RusotoFuture<DescribePatchGroupStateResult, DescribePatchGroupStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchGroupState");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroupState");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePatchGroupStateResult, _>(
+                    response,
+                    "DescribePatchGroupState",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePatchGroupStateError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists all patch groups that have been registered with patch baselines.</p>
+    fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> RusotoFuture<DescribePatchGroupsResult, DescribePatchGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePatchGroupsResult, _>(
+                    response,
+                    "DescribePatchGroups",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribePatchGroupsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Lists the properties of available patches organized by product, product family, classification, severity, and other properties of available patches. You can use the reported properties in the filters you specify in requests for actions such as <a>CreatePatchBaseline</a>, <a>UpdatePatchBaseline</a>, <a>DescribeAvailablePatches</a>, and <a>DescribePatchBaselines</a>.</p> <p>The following section lists the properties that can be used in filters for each major operating system type:</p> <dl> <dt>WINDOWS</dt> <dd> <p>Valid properties: PRODUCT, PRODUCT<em>FAMILY, CLASSIFICATION, MSRC</em>SEVERITY</p> </dd> <dt>AMAZON<em>LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>AMAZON</em>LINUX<em>2</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>UBUNTU </dt> <dd> <p>Valid properties: PRODUCT, PRIORITY</p> </dd> <dt>REDHAT</em>ENTERPRISE_LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>SUSE</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>CENTOS</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> </dl></p>
+    fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> RusotoFuture<DescribePatchPropertiesResult, DescribePatchPropertiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribePatchProperties");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribePatchProperties");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribePatchPropertiesResult, _>(
+                    response,
+                    "DescribePatchProperties",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribePatchPropertiesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of all active sessions (both connected and disconnected) or terminated sessions from the past 30 days.</p>
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResponse, DescribeSessionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("DescribeSessions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.DescribeSessions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSessionsResponse, _>(
+                    response,
+                    "DescribeSessions",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeSessionsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Get detailed information about a particular Automation execution.</p>
+    fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> RusotoFuture<GetAutomationExecutionResult, GetAutomationExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetAutomationExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetAutomationExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetAutomationExecutionResult, _>(
+                    response,
+                    "GetAutomationExecution",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetAutomationExecutionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns detailed information about command execution for an invocation or plugin. </p>
+    fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> RusotoFuture<GetCommandInvocationResult, GetCommandInvocationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetCommandInvocation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetCommandInvocation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetCommandInvocationResult, _>(
+                    response,
+                    "GetCommandInvocation",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetCommandInvocationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the Session Manager connection status for an instance to determine whether it is connected and ready to receive Session Manager connections.</p>
+    fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> RusotoFuture<GetConnectionStatusResponse, GetConnectionStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetConnectionStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetConnectionStatus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetConnectionStatusResponse, _>(
+                    response,
+                    "GetConnectionStatus",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetConnectionStatusError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the default patch baseline. Note that Systems Manager supports creating multiple default patch baselines. For example, you can create a default patch baseline for each operating system.</p> <p>If you do not specify an operating system value, the default patch baseline for Windows is returned.</p>
+    fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<GetDefaultPatchBaselineResult, GetDefaultPatchBaselineError> {
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDefaultPatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetDefaultPatchBaseline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDefaultPatchBaselineResult, _>(
+                    response,
+                    "GetDefaultPatchBaseline",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDefaultPatchBaselineError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the current snapshot for the patch baseline the instance uses. This API is primarily used by the AWS-RunPatchBaseline Systems Manager document. </p>
+    fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> RusotoFuture<
+        GetDeployablePatchSnapshotForInstanceResult,
+        GetDeployablePatchSnapshotForInstanceError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDeployablePatchSnapshotForInstance");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.GetDeployablePatchSnapshotForInstance",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDeployablePatchSnapshotForInstanceResult, _>(response, "GetDeployablePatchSnapshotForInstance")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetDeployablePatchSnapshotForInstanceError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets the contents of the specified Systems Manager document.</p>
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResult, GetDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetDocument");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetDocument");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetDocumentResult, _>(response, "GetDocument")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Query inventory information.</p>
+    fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> RusotoFuture<GetInventoryResult, GetInventoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetInventory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetInventory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInventoryResult, _>(
+                    response,
+                    "GetInventory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInventoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Return a list of inventory type names for the account, or return a list of attribute names for a specific Inventory item type. </p>
+    fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> RusotoFuture<GetInventorySchemaResult, GetInventorySchemaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetInventorySchema");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetInventorySchema");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetInventorySchemaResult, _>(
+                    response,
+                    "GetInventorySchema",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetInventorySchemaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a maintenance window.</p>
+    fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowResult, GetMaintenanceWindowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMaintenanceWindowResult, _>(
+                    response,
+                    "GetMaintenanceWindow",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetMaintenanceWindowError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details about a specific a maintenance window execution.</p>
+    fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionResult, GetMaintenanceWindowExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMaintenanceWindowExecutionResult, _>(
+                    response,
+                    "GetMaintenanceWindowExecution",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetMaintenanceWindowExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves the details about a specific task run as part of a maintenance window execution.</p>
+    fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionTaskResult, GetMaintenanceWindowExecutionTaskError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecutionTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.GetMaintenanceWindowExecutionTask",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMaintenanceWindowExecutionTaskResult, _>(
+                    response,
+                    "GetMaintenanceWindowExecutionTask",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetMaintenanceWindowExecutionTaskError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a specific task running on a specific target.</p>
+    fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> RusotoFuture<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        GetMaintenanceWindowExecutionTaskInvocationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowExecutionTaskInvocation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.GetMaintenanceWindowExecutionTaskInvocation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<
+                    GetMaintenanceWindowExecutionTaskInvocationResult,
+                    _,
+                >(response, "GetMaintenanceWindowExecutionTaskInvocation")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetMaintenanceWindowExecutionTaskInvocationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the tasks in a maintenance window.</p>
+    fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowTaskResult, GetMaintenanceWindowTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetMaintenanceWindowTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMaintenanceWindowTaskResult, _>(
+                    response,
+                    "GetMaintenanceWindowTask",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetMaintenanceWindowTaskError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Get information about an OpsItem by using the ID. You must have permission in AWS Identity and Access Management (IAM) to view information about an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> RusotoFuture<GetOpsItemResponse, GetOpsItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetOpsItem");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetOpsItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOpsItemResponse, _>(response, "GetOpsItem")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetOpsItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>View a summary of OpsItems based on specified filters and aggregators.</p>
+    fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> RusotoFuture<GetOpsSummaryResult, GetOpsSummaryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetOpsSummary");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetOpsSummary");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetOpsSummaryResult, _>(
+                    response,
+                    "GetOpsSummary",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetOpsSummaryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Get information about a parameter by using the parameter name. Don't confuse this API action with the <a>GetParameters</a> API action.</p>
+    fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> RusotoFuture<GetParameterResult, GetParameterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetParameter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetParameterResult, _>(
+                    response,
+                    "GetParameter",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetParameterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Query a list of all parameters used by the AWS account.</p>
+    fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> RusotoFuture<GetParameterHistoryResult, GetParameterHistoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameterHistory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetParameterHistory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetParameterHistoryResult, _>(
+                    response,
+                    "GetParameterHistory",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetParameterHistoryError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Get details of a parameter. Don't confuse this API action with the <a>GetParameter</a> API action.</p>
+    fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> RusotoFuture<GetParametersResult, GetParametersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParameters");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetParameters");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetParametersResult, _>(
+                    response,
+                    "GetParameters",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetParametersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieve parameters in a specific hierarchy. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-paramstore-working.html">Working with Systems Manager Parameters</a> in the <i>AWS Systems Manager User Guide</i>. </p> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> <note> <p>This API action doesn&#39;t support filtering by tags. </p> </note></p>
+    fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> RusotoFuture<GetParametersByPathResult, GetParametersByPathError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetParametersByPath");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetParametersByPath");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetParametersByPathResult, _>(
+                    response,
+                    "GetParametersByPath",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetParametersByPathError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves information about a patch baseline.</p>
+    fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> RusotoFuture<GetPatchBaselineResult, GetPatchBaselineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetPatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetPatchBaseline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPatchBaselineResult, _>(
+                    response,
+                    "GetPatchBaseline",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetPatchBaselineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the patch baseline that should be used for the specified patch group.</p>
+    fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<GetPatchBaselineForPatchGroupResult, GetPatchBaselineForPatchGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetPatchBaselineForPatchGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetPatchBaselineForPatchGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPatchBaselineForPatchGroupResult, _>(
+                    response,
+                    "GetPatchBaselineForPatchGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetPatchBaselineForPatchGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>UpdateServiceSetting</a> API action to change the default setting. Or use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Query the current service setting for the account. </p>
+    fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> RusotoFuture<GetServiceSettingResult, GetServiceSettingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("GetServiceSetting");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.GetServiceSetting");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetServiceSettingResult, _>(
+                    response,
+                    "GetServiceSetting",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetServiceSettingError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>A parameter label is a user-defined alias to help you manage different versions of a parameter. When you modify a parameter, Systems Manager automatically saves a new version and increments the version number by one. A label can help you remember the purpose of a parameter when there are multiple versions. </p> <p>Parameter labels have the following requirements and restrictions.</p> <ul> <li> <p>A version of a parameter can have a maximum of 10 labels.</p> </li> <li> <p>You can&#39;t attach the same label to different versions of the same parameter. For example, if version 1 has the label Production, then you can&#39;t attach Production to version 2.</p> </li> <li> <p>You can move a label from one version of a parameter to another.</p> </li> <li> <p>You can&#39;t create a label when you create a new parameter. You must attach a label to a specific version of a parameter.</p> </li> <li> <p>You can&#39;t delete a parameter label. If you no longer want to use a parameter label, then you must move it to a different version of a parameter.</p> </li> <li> <p>A label can have a maximum of 100 characters.</p> </li> <li> <p>Labels can contain letters (case sensitive), numbers, periods (.), hyphens (-), or underscores (_).</p> </li> <li> <p>Labels can&#39;t begin with a number, &quot;aws,&quot; or &quot;ssm&quot; (not case sensitive). If a label fails to meet these requirements, then the label is not associated with a parameter and the system displays it in the list of InvalidLabels.</p> </li> </ul></p>
+    fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> RusotoFuture<LabelParameterVersionResult, LabelParameterVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("LabelParameterVersion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.LabelParameterVersion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<LabelParameterVersionResult, _>(
+                    response,
+                    "LabelParameterVersion",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(LabelParameterVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves all versions of an association for a specific association ID.</p>
+    fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> RusotoFuture<ListAssociationVersionsResult, ListAssociationVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListAssociationVersions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListAssociationVersions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssociationVersionsResult, _>(
+                    response,
+                    "ListAssociationVersions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListAssociationVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the associations for the specified Systems Manager document or instance.</p>
+    fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> RusotoFuture<ListAssociationsResult, ListAssociationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListAssociations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListAssociations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAssociationsResult, _>(
+                    response,
+                    "ListAssociations",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAssociationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. ListCommandInvocations provide status about command execution.</p>
+    fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> RusotoFuture<ListCommandInvocationsResult, ListCommandInvocationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListCommandInvocations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListCommandInvocations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCommandInvocationsResult, _>(
+                    response,
+                    "ListCommandInvocations",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListCommandInvocationsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the commands requested by users of the AWS account.</p>
+    fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> RusotoFuture<ListCommandsResult, ListCommandsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListCommands");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListCommands");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListCommandsResult, _>(
+                    response,
+                    "ListCommands",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListCommandsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>For a specified resource ID, this API action returns a list of compliance statuses for different resource types. Currently, you can only specify one resource ID per call. List results depend on the criteria specified in the filter. </p>
+    fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> RusotoFuture<ListComplianceItemsResult, ListComplianceItemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListComplianceItems");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListComplianceItems");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListComplianceItemsResult, _>(
+                    response,
+                    "ListComplianceItems",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListComplianceItemsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a summary count of compliant and non-compliant resources for a compliance type. For example, this call can return State Manager associations, patches, or custom compliance types according to the filter criteria that you specify. </p>
+    fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> RusotoFuture<ListComplianceSummariesResult, ListComplianceSummariesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListComplianceSummaries");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListComplianceSummaries");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListComplianceSummariesResult, _>(
+                    response,
+                    "ListComplianceSummaries",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListComplianceSummariesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>List all versions for a document.</p>
+    fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> RusotoFuture<ListDocumentVersionsResult, ListDocumentVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListDocumentVersions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListDocumentVersions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDocumentVersionsResult, _>(
+                    response,
+                    "ListDocumentVersions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListDocumentVersionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes one or more of your Systems Manager documents.</p>
+    fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> RusotoFuture<ListDocumentsResult, ListDocumentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListDocuments");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListDocuments");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListDocumentsResult, _>(
+                    response,
+                    "ListDocuments",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDocumentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>A list of inventory items returned by the request.</p>
+    fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> RusotoFuture<ListInventoryEntriesResult, ListInventoryEntriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListInventoryEntries");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListInventoryEntries");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListInventoryEntriesResult, _>(
+                    response,
+                    "ListInventoryEntries",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListInventoryEntriesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a resource-level summary count. The summary includes information about compliant and non-compliant statuses and detailed compliance-item severity counts, according to the filter criteria you specify.</p>
+    fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> RusotoFuture<ListResourceComplianceSummariesResult, ListResourceComplianceSummariesError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListResourceComplianceSummaries");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListResourceComplianceSummaries");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourceComplianceSummariesResult, _>(
+                    response,
+                    "ListResourceComplianceSummaries",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListResourceComplianceSummariesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists your resource data sync configurations. Includes information about the last time a sync attempted to start, the last sync status, and the last time a sync successfully completed.</p> <p>The number of sync configurations might be too large to return using a single call to <code>ListResourceDataSync</code>. You can limit the number of sync configurations returned by using the <code>MaxResults</code> parameter. To determine whether there are more sync configurations to list, check the value of <code>NextToken</code> in the output. If there are more sync configurations to list, you can request them by specifying the <code>NextToken</code> returned in the call to the parameter of a subsequent call. </p>
+    fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> RusotoFuture<ListResourceDataSyncResult, ListResourceDataSyncError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListResourceDataSync");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListResourceDataSync");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourceDataSyncResult, _>(
+                    response,
+                    "ListResourceDataSync",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListResourceDataSyncError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResult, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Shares a Systems Manager document publicly or privately. If you share a document privately, you must specify the AWS user account IDs for those people who can use the document. If you share a document publicly, you must specify <i>All</i> as the account ID.</p>
+    fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> RusotoFuture<ModifyDocumentPermissionResponse, ModifyDocumentPermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ModifyDocumentPermission");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ModifyDocumentPermission");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyDocumentPermissionResponse, _>(
+                    response,
+                    "ModifyDocumentPermission",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ModifyDocumentPermissionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Registers a compliance type and other compliance details on a designated resource. This action lets you register custom compliance details with a resource. This call overwrites existing compliance information on the resource, so you must provide a full list of compliance items each time that you send the request.</p> <p>ComplianceType can be one of the following:</p> <ul> <li> <p>ExecutionId: The execution ID when the patch, association, or custom compliance item was applied.</p> </li> <li> <p>ExecutionType: Specify patch, association, or Custom:<code>string</code>.</p> </li> <li> <p>ExecutionTime. The time the patch, association, or custom compliance item was applied to the instance.</p> </li> <li> <p>Id: The patch, association, or custom compliance ID.</p> </li> <li> <p>Title: A title.</p> </li> <li> <p>Status: The status of the compliance item. For example, <code>approved</code> for patches, or <code>Failed</code> for associations.</p> </li> <li> <p>Severity: A patch severity. For example, <code>critical</code>.</p> </li> <li> <p>DocumentName: A SSM document name. For example, AWS-RunPatchBaseline.</p> </li> <li> <p>DocumentVersion: An SSM document version number. For example, 4.</p> </li> <li> <p>Classification: A patch classification. For example, <code>security updates</code>.</p> </li> <li> <p>PatchBaselineId: A patch baseline ID.</p> </li> <li> <p>PatchSeverity: A patch severity. For example, <code>Critical</code>.</p> </li> <li> <p>PatchState: A patch state. For example, <code>InstancesWithFailedPatches</code>.</p> </li> <li> <p>PatchGroup: The name of a patch group.</p> </li> <li> <p>InstalledTime: The time the association, patch, or custom compliance item was applied to the resource. Specify the time by using the following format: yyyy-MM-dd&#39;T&#39;HH:mm:ss&#39;Z&#39;</p> </li> </ul></p>
+    fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> RusotoFuture<PutComplianceItemsResult, PutComplianceItemsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutComplianceItems");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.PutComplianceItems");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutComplianceItemsResult, _>(
+                    response,
+                    "PutComplianceItems",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutComplianceItemsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Bulk update custom inventory items on one more instance. The request adds an inventory item, if it doesn't already exist, or updates an inventory item, if it does exist.</p>
+    fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> RusotoFuture<PutInventoryResult, PutInventoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutInventory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.PutInventory");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutInventoryResult, _>(
+                    response,
+                    "PutInventory",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutInventoryError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Add a parameter to the system.</p>
+    fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> RusotoFuture<PutParameterResult, PutParameterError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("PutParameter");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.PutParameter");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutParameterResult, _>(
+                    response,
+                    "PutParameter",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(PutParameterError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Defines the default patch baseline for the relevant operating system.</p> <p>To reset the AWS predefined patch baseline as the default, specify the full patch baseline ARN as the baseline ID value. For example, for CentOS, specify <code>arn:aws:ssm:us-east-2:733109147000:patchbaseline/pb-0574b43a65ea646ed</code> instead of <code>pb-0574b43a65ea646ed</code>.</p>
+    fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<RegisterDefaultPatchBaselineResult, RegisterDefaultPatchBaselineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterDefaultPatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.RegisterDefaultPatchBaseline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterDefaultPatchBaselineResult, _>(
+                    response,
+                    "RegisterDefaultPatchBaseline",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterDefaultPatchBaselineError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Registers a patch baseline for a patch group.</p>
+    fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        RegisterPatchBaselineForPatchGroupResult,
+        RegisterPatchBaselineForPatchGroupError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterPatchBaselineForPatchGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.RegisterPatchBaselineForPatchGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterPatchBaselineForPatchGroupResult, _>(
+                    response,
+                    "RegisterPatchBaselineForPatchGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterPatchBaselineForPatchGroupError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Registers a target with a maintenance window.</p>
+    fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        RegisterTargetWithMaintenanceWindowResult,
+        RegisterTargetWithMaintenanceWindowError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterTargetWithMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.RegisterTargetWithMaintenanceWindow",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterTargetWithMaintenanceWindowResult, _>(
+                    response,
+                    "RegisterTargetWithMaintenanceWindow",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterTargetWithMaintenanceWindowError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds a new task to a maintenance window.</p>
+    fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> RusotoFuture<RegisterTaskWithMaintenanceWindowResult, RegisterTaskWithMaintenanceWindowError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RegisterTaskWithMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AmazonSSM.RegisterTaskWithMaintenanceWindow",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterTaskWithMaintenanceWindowResult, _>(
+                    response,
+                    "RegisterTaskWithMaintenanceWindow",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RegisterTaskWithMaintenanceWindowError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes tag keys from the specified resource.</p>
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.RemoveTagsFromResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveTagsFromResourceResult, _>(
+                    response,
+                    "RemoveTagsFromResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveTagsFromResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Use the <a>UpdateServiceSetting</a> API action to change the default setting. </p> <p>Reset the service setting for the account to the default value as provisioned by the AWS service team. </p>
+    fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> RusotoFuture<ResetServiceSettingResult, ResetServiceSettingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ResetServiceSetting");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ResetServiceSetting");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResetServiceSettingResult, _>(
+                    response,
+                    "ResetServiceSetting",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ResetServiceSettingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Reconnects a session to an instance after it has been disconnected. Connections can be resumed for disconnected sessions, but not terminated sessions.</p> <note> <p>This command is primarily for use by client machines to automatically reconnect during intermittent network issues. It is not intended for any other use.</p> </note></p>
+    fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> RusotoFuture<ResumeSessionResponse, ResumeSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("ResumeSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.ResumeSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResumeSessionResponse, _>(
+                    response,
+                    "ResumeSession",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResumeSessionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sends a signal to an Automation execution to change the current behavior or status of the execution. </p>
+    fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> RusotoFuture<SendAutomationSignalResult, SendAutomationSignalError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("SendAutomationSignal");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.SendAutomationSignal");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SendAutomationSignalResult, _>(
+                    response,
+                    "SendAutomationSignal",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SendAutomationSignalError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Runs commands on one or more managed instances.</p>
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("SendCommand");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.SendCommand");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SendCommandResult, _>(response, "SendCommand")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SendCommandError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Use this API action to run an association immediately and only one time. This action can be helpful when troubleshooting associations.</p>
+    fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> RusotoFuture<StartAssociationsOnceResult, StartAssociationsOnceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartAssociationsOnce");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.StartAssociationsOnce");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartAssociationsOnceResult, _>(
+                    response,
+                    "StartAssociationsOnce",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartAssociationsOnceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Initiates execution of an Automation document.</p>
+    fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> RusotoFuture<StartAutomationExecutionResult, StartAutomationExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartAutomationExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.StartAutomationExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartAutomationExecutionResult, _>(
+                    response,
+                    "StartAutomationExecution",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StartAutomationExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Initiates a connection to a target (for example, an instance) for a Session Manager session. Returns a URL and token that can be used to open a WebSocket connection for sending input and receiving outputs.</p> <note> <p>AWS CLI usage: <code>start-session</code> is an interactive command that requires the Session Manager plugin to be installed on the client machine making the call. For information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-working-with-install-plugin.html"> Install the Session Manager Plugin for the AWS CLI</a> in the <i>AWS Systems Manager User Guide</i>.</p> </note></p>
+    fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> RusotoFuture<StartSessionResponse, StartSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StartSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.StartSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartSessionResponse, _>(
+                    response,
+                    "StartSession",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartSessionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Stop an Automation that is currently running.</p>
+    fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> RusotoFuture<StopAutomationExecutionResult, StopAutomationExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("StopAutomationExecution");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.StopAutomationExecution");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StopAutomationExecutionResult, _>(
+                    response,
+                    "StopAutomationExecution",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(StopAutomationExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Permanently ends a session and closes the data connection between the Session Manager client and SSM Agent on the instance. A terminated session cannot be resumed.</p>
+    fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> RusotoFuture<TerminateSessionResponse, TerminateSessionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("TerminateSession");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.TerminateSession");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TerminateSessionResponse, _>(
+                    response,
+                    "TerminateSession",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TerminateSessionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates an association. You can update the association name and version, the document version, schedule, parameters, and Amazon S3 output.</p> <important> <p>When you update an association, the association immediately runs against the specified targets.</p> </important></p>
+    fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> RusotoFuture<UpdateAssociationResult, UpdateAssociationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateAssociation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateAssociation");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateAssociationResult, _>(
+                    response,
+                    "UpdateAssociation",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateAssociationError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the status of the Systems Manager document associated with the specified instance.</p>
+    fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> RusotoFuture<UpdateAssociationStatusResult, UpdateAssociationStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateAssociationStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateAssociationStatus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateAssociationStatusResult, _>(
+                    response,
+                    "UpdateAssociationStatus",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateAssociationStatusError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates one or more values for an SSM document.</p>
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<UpdateDocumentResult, UpdateDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateDocument");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateDocument");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDocumentResult, _>(
+                    response,
+                    "UpdateDocument",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Set the default version of a document. </p>
+    fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> RusotoFuture<UpdateDocumentDefaultVersionResult, UpdateDocumentDefaultVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateDocumentDefaultVersion");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentDefaultVersion");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateDocumentDefaultVersionResult, _>(
+                    response,
+                    "UpdateDocumentDefaultVersion",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateDocumentDefaultVersionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates an existing maintenance window. Only specified parameters are modified.</p>
+    fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowResult, UpdateMaintenanceWindowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindow");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMaintenanceWindowResult, _>(
+                    response,
+                    "UpdateMaintenanceWindow",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateMaintenanceWindowError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Modifies the target of an existing maintenance window. You can change the following:</p> <ul> <li> <p>Name</p> </li> <li> <p>Description</p> </li> <li> <p>Owner</p> </li> <li> <p>IDs for an ID target</p> </li> <li> <p>Tags for a Tag target</p> </li> <li> <p>From any supported tag type to another. The three supported tag types are ID target, Tag target, and resource group. For more information, see <a>Target</a>.</p> </li> </ul> <note> <p>If a parameter is null, then the corresponding field is not modified.</p> </note></p>
+    fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTargetResult, UpdateMaintenanceWindowTargetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindowTarget");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTarget");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMaintenanceWindowTargetResult, _>(
+                    response,
+                    "UpdateMaintenanceWindowTarget",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateMaintenanceWindowTargetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Modifies a task assigned to a maintenance window. You can't change the task type, but you can change the following values:</p> <ul> <li> <p>TaskARN. For example, you can change a RUN_COMMAND task from AWS-RunPowerShellScript to AWS-RunShellScript.</p> </li> <li> <p>ServiceRoleArn</p> </li> <li> <p>TaskInvocationParameters</p> </li> <li> <p>Priority</p> </li> <li> <p>MaxConcurrency</p> </li> <li> <p>MaxErrors</p> </li> </ul> <p>If a parameter is null, then the corresponding field is not modified. Also, if you set Replace to true, then all fields required by the <a>RegisterTaskWithMaintenanceWindow</a> action are required for this request. Optional fields that aren't specified are set to null.</p>
+    fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<UpdateMaintenanceWindowTaskResult, UpdateMaintenanceWindowTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateMaintenanceWindowTask");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMaintenanceWindowTaskResult, _>(
+                    response,
+                    "UpdateMaintenanceWindowTask",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateMaintenanceWindowTaskError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Assigns or changes an Amazon Identity and Access Management (IAM) role for the managed instance.</p>
+    fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> RusotoFuture<UpdateManagedInstanceRoleResult, UpdateManagedInstanceRoleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateManagedInstanceRole");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateManagedInstanceRole");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateManagedInstanceRoleResult, _>(
+                    response,
+                    "UpdateManagedInstanceRole",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateManagedInstanceRoleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Edit or change an OpsItem. You must have permission in AWS Identity and Access Management (IAM) to update an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> RusotoFuture<UpdateOpsItemResponse, UpdateOpsItemError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateOpsItem");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateOpsItem");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateOpsItemResponse, _>(
+                    response,
+                    "UpdateOpsItem",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateOpsItemError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Modifies an existing patch baseline. Fields not specified in the request are left unchanged.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
+    fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> RusotoFuture<UpdatePatchBaselineResult, UpdatePatchBaselineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdatePatchBaseline");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdatePatchBaseline");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePatchBaselineResult, _>(
+                    response,
+                    "UpdatePatchBaseline",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdatePatchBaselineError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Or, use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Update the service setting for the account. </p>
+    fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> RusotoFuture<UpdateServiceSettingResult, UpdateServiceSettingError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
+        request.set_operation("UpdateServiceSetting");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonSSM.UpdateServiceSetting");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateServiceSettingResult, _>(
+                    response,
+                    "UpdateServiceSetting",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateServiceSettingError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon SSM API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSsm {
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceRequest,
+                ) -> ::std::result::Result<
+                    AddTagsToResourceResult,
+                    RusotoError<AddTagsToResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    cancel_command: Option<
+        Box<
+            dyn Fn(
+                    CancelCommandRequest,
+                )
+                    -> ::std::result::Result<CancelCommandResult, RusotoError<CancelCommandError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_maintenance_window_execution: Option<
+        Box<
+            dyn Fn(
+                    CancelMaintenanceWindowExecutionRequest,
+                ) -> ::std::result::Result<
+                    CancelMaintenanceWindowExecutionResult,
+                    RusotoError<CancelMaintenanceWindowExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_activation: Option<
+        Box<
+            dyn Fn(
+                    CreateActivationRequest,
+                ) -> ::std::result::Result<
+                    CreateActivationResult,
+                    RusotoError<CreateActivationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_association: Option<
+        Box<
+            dyn Fn(
+                    CreateAssociationRequest,
+                ) -> ::std::result::Result<
+                    CreateAssociationResult,
+                    RusotoError<CreateAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_association_batch: Option<
+        Box<
+            dyn Fn(
+                    CreateAssociationBatchRequest,
+                ) -> ::std::result::Result<
+                    CreateAssociationBatchResult,
+                    RusotoError<CreateAssociationBatchError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_document: Option<
+        Box<
+            dyn Fn(
+                    CreateDocumentRequest,
+                )
+                    -> ::std::result::Result<CreateDocumentResult, RusotoError<CreateDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    CreateMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    CreateMaintenanceWindowResult,
+                    RusotoError<CreateMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_ops_item: Option<
+        Box<
+            dyn Fn(
+                    CreateOpsItemRequest,
+                )
+                    -> ::std::result::Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    CreatePatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    CreatePatchBaselineResult,
+                    RusotoError<CreatePatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_resource_data_sync: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceDataSyncRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceDataSyncResult,
+                    RusotoError<CreateResourceDataSyncError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_activation: Option<
+        Box<
+            dyn Fn(
+                    DeleteActivationRequest,
+                ) -> ::std::result::Result<
+                    DeleteActivationResult,
+                    RusotoError<DeleteActivationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_association: Option<
+        Box<
+            dyn Fn(
+                    DeleteAssociationRequest,
+                ) -> ::std::result::Result<
+                    DeleteAssociationResult,
+                    RusotoError<DeleteAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_document: Option<
+        Box<
+            dyn Fn(
+                    DeleteDocumentRequest,
+                )
+                    -> ::std::result::Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_inventory: Option<
+        Box<
+            dyn Fn(
+                    DeleteInventoryRequest,
+                ) -> ::std::result::Result<
+                    DeleteInventoryResult,
+                    RusotoError<DeleteInventoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    DeleteMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    DeleteMaintenanceWindowResult,
+                    RusotoError<DeleteMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_parameter: Option<
+        Box<
+            dyn Fn(
+                    DeleteParameterRequest,
+                ) -> ::std::result::Result<
+                    DeleteParameterResult,
+                    RusotoError<DeleteParameterError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_parameters: Option<
+        Box<
+            dyn Fn(
+                    DeleteParametersRequest,
+                ) -> ::std::result::Result<
+                    DeleteParametersResult,
+                    RusotoError<DeleteParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    DeletePatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    DeletePatchBaselineResult,
+                    RusotoError<DeletePatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_resource_data_sync: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourceDataSyncRequest,
+                ) -> ::std::result::Result<
+                    DeleteResourceDataSyncResult,
+                    RusotoError<DeleteResourceDataSyncError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_managed_instance: Option<
+        Box<
+            dyn Fn(
+                    DeregisterManagedInstanceRequest,
+                ) -> ::std::result::Result<
+                    DeregisterManagedInstanceResult,
+                    RusotoError<DeregisterManagedInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_patch_baseline_for_patch_group: Option<
+        Box<
+            dyn Fn(
+                    DeregisterPatchBaselineForPatchGroupRequest,
+                ) -> ::std::result::Result<
+                    DeregisterPatchBaselineForPatchGroupResult,
+                    RusotoError<DeregisterPatchBaselineForPatchGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_target_from_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    DeregisterTargetFromMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    DeregisterTargetFromMaintenanceWindowResult,
+                    RusotoError<DeregisterTargetFromMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deregister_task_from_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    DeregisterTaskFromMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    DeregisterTaskFromMaintenanceWindowResult,
+                    RusotoError<DeregisterTaskFromMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_activations: Option<
+        Box<
+            dyn Fn(
+                    DescribeActivationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeActivationsResult,
+                    RusotoError<DescribeActivationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_association: Option<
+        Box<
+            dyn Fn(
+                    DescribeAssociationRequest,
+                ) -> ::std::result::Result<
+                    DescribeAssociationResult,
+                    RusotoError<DescribeAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_association_execution_targets: Option<
+        Box<
+            dyn Fn(
+                    DescribeAssociationExecutionTargetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAssociationExecutionTargetsResult,
+                    RusotoError<DescribeAssociationExecutionTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_association_executions: Option<
+        Box<
+            dyn Fn(
+                    DescribeAssociationExecutionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAssociationExecutionsResult,
+                    RusotoError<DescribeAssociationExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_automation_executions: Option<
+        Box<
+            dyn Fn(
+                    DescribeAutomationExecutionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAutomationExecutionsResult,
+                    RusotoError<DescribeAutomationExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_automation_step_executions: Option<
+        Box<
+            dyn Fn(
+                    DescribeAutomationStepExecutionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAutomationStepExecutionsResult,
+                    RusotoError<DescribeAutomationStepExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_available_patches: Option<
+        Box<
+            dyn Fn(
+                    DescribeAvailablePatchesRequest,
+                ) -> ::std::result::Result<
+                    DescribeAvailablePatchesResult,
+                    RusotoError<DescribeAvailablePatchesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_document: Option<
+        Box<
+            dyn Fn(
+                    DescribeDocumentRequest,
+                ) -> ::std::result::Result<
+                    DescribeDocumentResult,
+                    RusotoError<DescribeDocumentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_document_permission: Option<
+        Box<
+            dyn Fn(
+                    DescribeDocumentPermissionRequest,
+                ) -> ::std::result::Result<
+                    DescribeDocumentPermissionResponse,
+                    RusotoError<DescribeDocumentPermissionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_effective_instance_associations: Option<
+        Box<
+            dyn Fn(
+                    DescribeEffectiveInstanceAssociationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeEffectiveInstanceAssociationsResult,
+                    RusotoError<DescribeEffectiveInstanceAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_effective_patches_for_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    DescribeEffectivePatchesForPatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    DescribeEffectivePatchesForPatchBaselineResult,
+                    RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instance_associations_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstanceAssociationsStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstanceAssociationsStatusResult,
+                    RusotoError<DescribeInstanceAssociationsStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instance_information: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstanceInformationRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstanceInformationResult,
+                    RusotoError<DescribeInstanceInformationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instance_patch_states: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstancePatchStatesRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstancePatchStatesResult,
+                    RusotoError<DescribeInstancePatchStatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instance_patch_states_for_patch_group: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstancePatchStatesForPatchGroupRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstancePatchStatesForPatchGroupResult,
+                    RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_instance_patches: Option<
+        Box<
+            dyn Fn(
+                    DescribeInstancePatchesRequest,
+                ) -> ::std::result::Result<
+                    DescribeInstancePatchesResult,
+                    RusotoError<DescribeInstancePatchesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_inventory_deletions: Option<
+        Box<
+            dyn Fn(
+                    DescribeInventoryDeletionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeInventoryDeletionsResult,
+                    RusotoError<DescribeInventoryDeletionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_window_execution_task_invocations: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+                    RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_window_execution_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowExecutionTasksRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowExecutionTasksResult,
+                    RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_window_executions: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowExecutionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowExecutionsResult,
+                    RusotoError<DescribeMaintenanceWindowExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_window_schedule: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowScheduleRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowScheduleResult,
+                    RusotoError<DescribeMaintenanceWindowScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_window_targets: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowTargetsRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowTargetsResult,
+                    RusotoError<DescribeMaintenanceWindowTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_window_tasks: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowTasksRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowTasksResult,
+                    RusotoError<DescribeMaintenanceWindowTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_windows: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowsRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowsResult,
+                    RusotoError<DescribeMaintenanceWindowsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_windows_for_target: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceWindowsForTargetRequest,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceWindowsForTargetResult,
+                    RusotoError<DescribeMaintenanceWindowsForTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_ops_items: Option<
+        Box<
+            dyn Fn(
+                    DescribeOpsItemsRequest,
+                ) -> ::std::result::Result<
+                    DescribeOpsItemsResponse,
+                    RusotoError<DescribeOpsItemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_parameters: Option<
+        Box<
+            dyn Fn(
+                    DescribeParametersRequest,
+                ) -> ::std::result::Result<
+                    DescribeParametersResult,
+                    RusotoError<DescribeParametersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_patch_baselines: Option<
+        Box<
+            dyn Fn(
+                    DescribePatchBaselinesRequest,
+                ) -> ::std::result::Result<
+                    DescribePatchBaselinesResult,
+                    RusotoError<DescribePatchBaselinesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_patch_group_state: Option<
+        Box<
+            dyn Fn(
+                    DescribePatchGroupStateRequest,
+                ) -> ::std::result::Result<
+                    DescribePatchGroupStateResult,
+                    RusotoError<DescribePatchGroupStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_patch_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribePatchGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribePatchGroupsResult,
+                    RusotoError<DescribePatchGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_patch_properties: Option<
+        Box<
+            dyn Fn(
+                    DescribePatchPropertiesRequest,
+                ) -> ::std::result::Result<
+                    DescribePatchPropertiesResult,
+                    RusotoError<DescribePatchPropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_sessions: Option<
+        Box<
+            dyn Fn(
+                    DescribeSessionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeSessionsResponse,
+                    RusotoError<DescribeSessionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_automation_execution: Option<
+        Box<
+            dyn Fn(
+                    GetAutomationExecutionRequest,
+                ) -> ::std::result::Result<
+                    GetAutomationExecutionResult,
+                    RusotoError<GetAutomationExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_command_invocation: Option<
+        Box<
+            dyn Fn(
+                    GetCommandInvocationRequest,
+                ) -> ::std::result::Result<
+                    GetCommandInvocationResult,
+                    RusotoError<GetCommandInvocationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_connection_status: Option<
+        Box<
+            dyn Fn(
+                    GetConnectionStatusRequest,
+                ) -> ::std::result::Result<
+                    GetConnectionStatusResponse,
+                    RusotoError<GetConnectionStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_default_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    GetDefaultPatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    GetDefaultPatchBaselineResult,
+                    RusotoError<GetDefaultPatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_deployable_patch_snapshot_for_instance: Option<
+        Box<
+            dyn Fn(
+                    GetDeployablePatchSnapshotForInstanceRequest,
+                ) -> ::std::result::Result<
+                    GetDeployablePatchSnapshotForInstanceResult,
+                    RusotoError<GetDeployablePatchSnapshotForInstanceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_document: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentRequest,
+                )
+                    -> ::std::result::Result<GetDocumentResult, RusotoError<GetDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_inventory: Option<
+        Box<
+            dyn Fn(
+                    GetInventoryRequest,
+                )
+                    -> ::std::result::Result<GetInventoryResult, RusotoError<GetInventoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_inventory_schema: Option<
+        Box<
+            dyn Fn(
+                    GetInventorySchemaRequest,
+                ) -> ::std::result::Result<
+                    GetInventorySchemaResult,
+                    RusotoError<GetInventorySchemaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    GetMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    GetMaintenanceWindowResult,
+                    RusotoError<GetMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_maintenance_window_execution: Option<
+        Box<
+            dyn Fn(
+                    GetMaintenanceWindowExecutionRequest,
+                ) -> ::std::result::Result<
+                    GetMaintenanceWindowExecutionResult,
+                    RusotoError<GetMaintenanceWindowExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_maintenance_window_execution_task: Option<
+        Box<
+            dyn Fn(
+                    GetMaintenanceWindowExecutionTaskRequest,
+                ) -> ::std::result::Result<
+                    GetMaintenanceWindowExecutionTaskResult,
+                    RusotoError<GetMaintenanceWindowExecutionTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_maintenance_window_execution_task_invocation: Option<
+        Box<
+            dyn Fn(
+                    GetMaintenanceWindowExecutionTaskInvocationRequest,
+                ) -> ::std::result::Result<
+                    GetMaintenanceWindowExecutionTaskInvocationResult,
+                    RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_maintenance_window_task: Option<
+        Box<
+            dyn Fn(
+                    GetMaintenanceWindowTaskRequest,
+                ) -> ::std::result::Result<
+                    GetMaintenanceWindowTaskResult,
+                    RusotoError<GetMaintenanceWindowTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_ops_item: Option<
+        Box<
+            dyn Fn(
+                    GetOpsItemRequest,
+                )
+                    -> ::std::result::Result<GetOpsItemResponse, RusotoError<GetOpsItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_ops_summary: Option<
+        Box<
+            dyn Fn(
+                    GetOpsSummaryRequest,
+                )
+                    -> ::std::result::Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_parameter: Option<
+        Box<
+            dyn Fn(
+                    GetParameterRequest,
+                )
+                    -> ::std::result::Result<GetParameterResult, RusotoError<GetParameterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_parameter_history: Option<
+        Box<
+            dyn Fn(
+                    GetParameterHistoryRequest,
+                ) -> ::std::result::Result<
+                    GetParameterHistoryResult,
+                    RusotoError<GetParameterHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_parameters: Option<
+        Box<
+            dyn Fn(
+                    GetParametersRequest,
+                )
+                    -> ::std::result::Result<GetParametersResult, RusotoError<GetParametersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_parameters_by_path: Option<
+        Box<
+            dyn Fn(
+                    GetParametersByPathRequest,
+                ) -> ::std::result::Result<
+                    GetParametersByPathResult,
+                    RusotoError<GetParametersByPathError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    GetPatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    GetPatchBaselineResult,
+                    RusotoError<GetPatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_patch_baseline_for_patch_group: Option<
+        Box<
+            dyn Fn(
+                    GetPatchBaselineForPatchGroupRequest,
+                ) -> ::std::result::Result<
+                    GetPatchBaselineForPatchGroupResult,
+                    RusotoError<GetPatchBaselineForPatchGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_service_setting: Option<
+        Box<
+            dyn Fn(
+                    GetServiceSettingRequest,
+                ) -> ::std::result::Result<
+                    GetServiceSettingResult,
+                    RusotoError<GetServiceSettingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    label_parameter_version: Option<
+        Box<
+            dyn Fn(
+                    LabelParameterVersionRequest,
+                ) -> ::std::result::Result<
+                    LabelParameterVersionResult,
+                    RusotoError<LabelParameterVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_association_versions: Option<
+        Box<
+            dyn Fn(
+                    ListAssociationVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListAssociationVersionsResult,
+                    RusotoError<ListAssociationVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_associations: Option<
+        Box<
+            dyn Fn(
+                    ListAssociationsRequest,
+                ) -> ::std::result::Result<
+                    ListAssociationsResult,
+                    RusotoError<ListAssociationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_command_invocations: Option<
+        Box<
+            dyn Fn(
+                    ListCommandInvocationsRequest,
+                ) -> ::std::result::Result<
+                    ListCommandInvocationsResult,
+                    RusotoError<ListCommandInvocationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_commands: Option<
+        Box<
+            dyn Fn(
+                    ListCommandsRequest,
+                )
+                    -> ::std::result::Result<ListCommandsResult, RusotoError<ListCommandsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_compliance_items: Option<
+        Box<
+            dyn Fn(
+                    ListComplianceItemsRequest,
+                ) -> ::std::result::Result<
+                    ListComplianceItemsResult,
+                    RusotoError<ListComplianceItemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_compliance_summaries: Option<
+        Box<
+            dyn Fn(
+                    ListComplianceSummariesRequest,
+                ) -> ::std::result::Result<
+                    ListComplianceSummariesResult,
+                    RusotoError<ListComplianceSummariesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_document_versions: Option<
+        Box<
+            dyn Fn(
+                    ListDocumentVersionsRequest,
+                ) -> ::std::result::Result<
+                    ListDocumentVersionsResult,
+                    RusotoError<ListDocumentVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_documents: Option<
+        Box<
+            dyn Fn(
+                    ListDocumentsRequest,
+                )
+                    -> ::std::result::Result<ListDocumentsResult, RusotoError<ListDocumentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_inventory_entries: Option<
+        Box<
+            dyn Fn(
+                    ListInventoryEntriesRequest,
+                ) -> ::std::result::Result<
+                    ListInventoryEntriesResult,
+                    RusotoError<ListInventoryEntriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_compliance_summaries: Option<
+        Box<
+            dyn Fn(
+                    ListResourceComplianceSummariesRequest,
+                ) -> ::std::result::Result<
+                    ListResourceComplianceSummariesResult,
+                    RusotoError<ListResourceComplianceSummariesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_data_sync: Option<
+        Box<
+            dyn Fn(
+                    ListResourceDataSyncRequest,
+                ) -> ::std::result::Result<
+                    ListResourceDataSyncResult,
+                    RusotoError<ListResourceDataSyncError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResult,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_document_permission: Option<
+        Box<
+            dyn Fn(
+                    ModifyDocumentPermissionRequest,
+                ) -> ::std::result::Result<
+                    ModifyDocumentPermissionResponse,
+                    RusotoError<ModifyDocumentPermissionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_compliance_items: Option<
+        Box<
+            dyn Fn(
+                    PutComplianceItemsRequest,
+                ) -> ::std::result::Result<
+                    PutComplianceItemsResult,
+                    RusotoError<PutComplianceItemsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_inventory: Option<
+        Box<
+            dyn Fn(
+                    PutInventoryRequest,
+                )
+                    -> ::std::result::Result<PutInventoryResult, RusotoError<PutInventoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_parameter: Option<
+        Box<
+            dyn Fn(
+                    PutParameterRequest,
+                )
+                    -> ::std::result::Result<PutParameterResult, RusotoError<PutParameterError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_default_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    RegisterDefaultPatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    RegisterDefaultPatchBaselineResult,
+                    RusotoError<RegisterDefaultPatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_patch_baseline_for_patch_group: Option<
+        Box<
+            dyn Fn(
+                    RegisterPatchBaselineForPatchGroupRequest,
+                ) -> ::std::result::Result<
+                    RegisterPatchBaselineForPatchGroupResult,
+                    RusotoError<RegisterPatchBaselineForPatchGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_target_with_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    RegisterTargetWithMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    RegisterTargetWithMaintenanceWindowResult,
+                    RusotoError<RegisterTargetWithMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_task_with_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    RegisterTaskWithMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    RegisterTaskWithMaintenanceWindowResult,
+                    RusotoError<RegisterTaskWithMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceRequest,
+                ) -> ::std::result::Result<
+                    RemoveTagsFromResourceResult,
+                    RusotoError<RemoveTagsFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_service_setting: Option<
+        Box<
+            dyn Fn(
+                    ResetServiceSettingRequest,
+                ) -> ::std::result::Result<
+                    ResetServiceSettingResult,
+                    RusotoError<ResetServiceSettingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resume_session: Option<
+        Box<
+            dyn Fn(
+                    ResumeSessionRequest,
+                )
+                    -> ::std::result::Result<ResumeSessionResponse, RusotoError<ResumeSessionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    send_automation_signal: Option<
+        Box<
+            dyn Fn(
+                    SendAutomationSignalRequest,
+                ) -> ::std::result::Result<
+                    SendAutomationSignalResult,
+                    RusotoError<SendAutomationSignalError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_command: Option<
+        Box<
+            dyn Fn(
+                    SendCommandRequest,
+                )
+                    -> ::std::result::Result<SendCommandResult, RusotoError<SendCommandError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_associations_once: Option<
+        Box<
+            dyn Fn(
+                    StartAssociationsOnceRequest,
+                ) -> ::std::result::Result<
+                    StartAssociationsOnceResult,
+                    RusotoError<StartAssociationsOnceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_automation_execution: Option<
+        Box<
+            dyn Fn(
+                    StartAutomationExecutionRequest,
+                ) -> ::std::result::Result<
+                    StartAutomationExecutionResult,
+                    RusotoError<StartAutomationExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_session: Option<
+        Box<
+            dyn Fn(
+                    StartSessionRequest,
+                )
+                    -> ::std::result::Result<StartSessionResponse, RusotoError<StartSessionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_automation_execution: Option<
+        Box<
+            dyn Fn(
+                    StopAutomationExecutionRequest,
+                ) -> ::std::result::Result<
+                    StopAutomationExecutionResult,
+                    RusotoError<StopAutomationExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    terminate_session: Option<
+        Box<
+            dyn Fn(
+                    TerminateSessionRequest,
+                ) -> ::std::result::Result<
+                    TerminateSessionResponse,
+                    RusotoError<TerminateSessionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_association: Option<
+        Box<
+            dyn Fn(
+                    UpdateAssociationRequest,
+                ) -> ::std::result::Result<
+                    UpdateAssociationResult,
+                    RusotoError<UpdateAssociationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_association_status: Option<
+        Box<
+            dyn Fn(
+                    UpdateAssociationStatusRequest,
+                ) -> ::std::result::Result<
+                    UpdateAssociationStatusResult,
+                    RusotoError<UpdateAssociationStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_document: Option<
+        Box<
+            dyn Fn(
+                    UpdateDocumentRequest,
+                )
+                    -> ::std::result::Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_document_default_version: Option<
+        Box<
+            dyn Fn(
+                    UpdateDocumentDefaultVersionRequest,
+                ) -> ::std::result::Result<
+                    UpdateDocumentDefaultVersionResult,
+                    RusotoError<UpdateDocumentDefaultVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_maintenance_window: Option<
+        Box<
+            dyn Fn(
+                    UpdateMaintenanceWindowRequest,
+                ) -> ::std::result::Result<
+                    UpdateMaintenanceWindowResult,
+                    RusotoError<UpdateMaintenanceWindowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_maintenance_window_target: Option<
+        Box<
+            dyn Fn(
+                    UpdateMaintenanceWindowTargetRequest,
+                ) -> ::std::result::Result<
+                    UpdateMaintenanceWindowTargetResult,
+                    RusotoError<UpdateMaintenanceWindowTargetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_maintenance_window_task: Option<
+        Box<
+            dyn Fn(
+                    UpdateMaintenanceWindowTaskRequest,
+                ) -> ::std::result::Result<
+                    UpdateMaintenanceWindowTaskResult,
+                    RusotoError<UpdateMaintenanceWindowTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_managed_instance_role: Option<
+        Box<
+            dyn Fn(
+                    UpdateManagedInstanceRoleRequest,
+                ) -> ::std::result::Result<
+                    UpdateManagedInstanceRoleResult,
+                    RusotoError<UpdateManagedInstanceRoleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_ops_item: Option<
+        Box<
+            dyn Fn(
+                    UpdateOpsItemRequest,
+                )
+                    -> ::std::result::Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_patch_baseline: Option<
+        Box<
+            dyn Fn(
+                    UpdatePatchBaselineRequest,
+                ) -> ::std::result::Result<
+                    UpdatePatchBaselineResult,
+                    RusotoError<UpdatePatchBaselineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_service_setting: Option<
+        Box<
+            dyn Fn(
+                    UpdateServiceSettingRequest,
+                ) -> ::std::result::Result<
+                    UpdateServiceSettingResult,
+                    RusotoError<UpdateServiceSettingError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSsm {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSsm {
+        Default::default()
+    }
+
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                AddTagsToResourceRequest,
+            ) -> ::std::result::Result<
+                AddTagsToResourceResult,
+                RusotoError<AddTagsToResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_command` with what `handler` returns for its input.
+    pub fn with_cancel_command<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CancelCommandRequest,
+            )
+                -> ::std::result::Result<CancelCommandResult, RusotoError<CancelCommandError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_command = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_maintenance_window_execution` with what `handler` returns for its input.
+    pub fn with_cancel_maintenance_window_execution<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CancelMaintenanceWindowExecutionRequest,
+            ) -> ::std::result::Result<
+                CancelMaintenanceWindowExecutionResult,
+                RusotoError<CancelMaintenanceWindowExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_maintenance_window_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_activation` with what `handler` returns for its input.
+    pub fn with_create_activation<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateActivationRequest,
+            )
+                -> ::std::result::Result<CreateActivationResult, RusotoError<CreateActivationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_activation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_association` with what `handler` returns for its input.
+    pub fn with_create_association<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateAssociationRequest,
+            ) -> ::std::result::Result<
+                CreateAssociationResult,
+                RusotoError<CreateAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_association_batch` with what `handler` returns for its input.
+    pub fn with_create_association_batch<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateAssociationBatchRequest,
+            ) -> ::std::result::Result<
+                CreateAssociationBatchResult,
+                RusotoError<CreateAssociationBatchError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_association_batch = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_document` with what `handler` returns for its input.
+    pub fn with_create_document<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateDocumentRequest,
+            )
+                -> ::std::result::Result<CreateDocumentResult, RusotoError<CreateDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_maintenance_window` with what `handler` returns for its input.
+    pub fn with_create_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                CreateMaintenanceWindowResult,
+                RusotoError<CreateMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_ops_item` with what `handler` returns for its input.
+    pub fn with_create_ops_item<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateOpsItemRequest,
+            )
+                -> ::std::result::Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ops_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_patch_baseline` with what `handler` returns for its input.
+    pub fn with_create_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreatePatchBaselineRequest,
+            ) -> ::std::result::Result<
+                CreatePatchBaselineResult,
+                RusotoError<CreatePatchBaselineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource_data_sync` with what `handler` returns for its input.
+    pub fn with_create_resource_data_sync<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                CreateResourceDataSyncRequest,
+            ) -> ::std::result::Result<
+                CreateResourceDataSyncResult,
+                RusotoError<CreateResourceDataSyncError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource_data_sync = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_activation` with what `handler` returns for its input.
+    pub fn with_delete_activation<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteActivationRequest,
+            )
+                -> ::std::result::Result<DeleteActivationResult, RusotoError<DeleteActivationError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_activation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_association` with what `handler` returns for its input.
+    pub fn with_delete_association<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteAssociationRequest,
+            ) -> ::std::result::Result<
+                DeleteAssociationResult,
+                RusotoError<DeleteAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_document` with what `handler` returns for its input.
+    pub fn with_delete_document<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteDocumentRequest,
+            )
+                -> ::std::result::Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_inventory` with what `handler` returns for its input.
+    pub fn with_delete_inventory<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteInventoryRequest,
+            )
+                -> ::std::result::Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_inventory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_maintenance_window` with what `handler` returns for its input.
+    pub fn with_delete_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                DeleteMaintenanceWindowResult,
+                RusotoError<DeleteMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_parameter` with what `handler` returns for its input.
+    pub fn with_delete_parameter<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteParameterRequest,
+            )
+                -> ::std::result::Result<DeleteParameterResult, RusotoError<DeleteParameterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_parameter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_parameters` with what `handler` returns for its input.
+    pub fn with_delete_parameters<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteParametersRequest,
+            )
+                -> ::std::result::Result<DeleteParametersResult, RusotoError<DeleteParametersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_patch_baseline` with what `handler` returns for its input.
+    pub fn with_delete_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeletePatchBaselineRequest,
+            ) -> ::std::result::Result<
+                DeletePatchBaselineResult,
+                RusotoError<DeletePatchBaselineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource_data_sync` with what `handler` returns for its input.
+    pub fn with_delete_resource_data_sync<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeleteResourceDataSyncRequest,
+            ) -> ::std::result::Result<
+                DeleteResourceDataSyncResult,
+                RusotoError<DeleteResourceDataSyncError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource_data_sync = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_managed_instance` with what `handler` returns for its input.
+    pub fn with_deregister_managed_instance<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeregisterManagedInstanceRequest,
+            ) -> ::std::result::Result<
+                DeregisterManagedInstanceResult,
+                RusotoError<DeregisterManagedInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_managed_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_patch_baseline_for_patch_group` with what `handler` returns for its input.
+    pub fn with_deregister_patch_baseline_for_patch_group<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeregisterPatchBaselineForPatchGroupRequest,
+            ) -> ::std::result::Result<
+                DeregisterPatchBaselineForPatchGroupResult,
+                RusotoError<DeregisterPatchBaselineForPatchGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_patch_baseline_for_patch_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_target_from_maintenance_window` with what `handler` returns for its input.
+    pub fn with_deregister_target_from_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeregisterTargetFromMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                DeregisterTargetFromMaintenanceWindowResult,
+                RusotoError<DeregisterTargetFromMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_target_from_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_task_from_maintenance_window` with what `handler` returns for its input.
+    pub fn with_deregister_task_from_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DeregisterTaskFromMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                DeregisterTaskFromMaintenanceWindowResult,
+                RusotoError<DeregisterTaskFromMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_task_from_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_activations` with what `handler` returns for its input.
+    pub fn with_describe_activations<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeActivationsRequest,
+            ) -> ::std::result::Result<
+                DescribeActivationsResult,
+                RusotoError<DescribeActivationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_activations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_association` with what `handler` returns for its input.
+    pub fn with_describe_association<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeAssociationRequest,
+            ) -> ::std::result::Result<
+                DescribeAssociationResult,
+                RusotoError<DescribeAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_association_execution_targets` with what `handler` returns for its input.
+    pub fn with_describe_association_execution_targets<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeAssociationExecutionTargetsRequest,
+            ) -> ::std::result::Result<
+                DescribeAssociationExecutionTargetsResult,
+                RusotoError<DescribeAssociationExecutionTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_association_execution_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_association_executions` with what `handler` returns for its input.
+    pub fn with_describe_association_executions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeAssociationExecutionsRequest,
+            ) -> ::std::result::Result<
+                DescribeAssociationExecutionsResult,
+                RusotoError<DescribeAssociationExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_association_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_automation_executions` with what `handler` returns for its input.
+    pub fn with_describe_automation_executions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeAutomationExecutionsRequest,
+            ) -> ::std::result::Result<
+                DescribeAutomationExecutionsResult,
+                RusotoError<DescribeAutomationExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_automation_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_automation_step_executions` with what `handler` returns for its input.
+    pub fn with_describe_automation_step_executions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeAutomationStepExecutionsRequest,
+            ) -> ::std::result::Result<
+                DescribeAutomationStepExecutionsResult,
+                RusotoError<DescribeAutomationStepExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_automation_step_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_available_patches` with what `handler` returns for its input.
+    pub fn with_describe_available_patches<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeAvailablePatchesRequest,
+            ) -> ::std::result::Result<
+                DescribeAvailablePatchesResult,
+                RusotoError<DescribeAvailablePatchesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_available_patches = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_document` with what `handler` returns for its input.
+    pub fn with_describe_document<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeDocumentRequest,
+            )
+                -> ::std::result::Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_document_permission` with what `handler` returns for its input.
+    pub fn with_describe_document_permission<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeDocumentPermissionRequest,
+            ) -> ::std::result::Result<
+                DescribeDocumentPermissionResponse,
+                RusotoError<DescribeDocumentPermissionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_document_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_effective_instance_associations` with what `handler` returns for its input.
+    pub fn with_describe_effective_instance_associations<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeEffectiveInstanceAssociationsRequest,
+            ) -> ::std::result::Result<
+                DescribeEffectiveInstanceAssociationsResult,
+                RusotoError<DescribeEffectiveInstanceAssociationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_effective_instance_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_effective_patches_for_patch_baseline` with what `handler` returns for its input.
+    pub fn with_describe_effective_patches_for_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeEffectivePatchesForPatchBaselineRequest,
+            ) -> ::std::result::Result<
+                DescribeEffectivePatchesForPatchBaselineResult,
+                RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_effective_patches_for_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instance_associations_status` with what `handler` returns for its input.
+    pub fn with_describe_instance_associations_status<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeInstanceAssociationsStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeInstanceAssociationsStatusResult,
+                RusotoError<DescribeInstanceAssociationsStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instance_associations_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instance_information` with what `handler` returns for its input.
+    pub fn with_describe_instance_information<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeInstanceInformationRequest,
+            ) -> ::std::result::Result<
+                DescribeInstanceInformationResult,
+                RusotoError<DescribeInstanceInformationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instance_information = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instance_patch_states` with what `handler` returns for its input.
+    pub fn with_describe_instance_patch_states<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeInstancePatchStatesRequest,
+            ) -> ::std::result::Result<
+                DescribeInstancePatchStatesResult,
+                RusotoError<DescribeInstancePatchStatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instance_patch_states = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instance_patch_states_for_patch_group` with what `handler` returns for its input.
+    pub fn with_describe_instance_patch_states_for_patch_group<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeInstancePatchStatesForPatchGroupRequest,
+            ) -> ::std::result::Result<
+                DescribeInstancePatchStatesForPatchGroupResult,
+                RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instance_patch_states_for_patch_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_instance_patches` with what `handler` returns for its input.
+    pub fn with_describe_instance_patches<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeInstancePatchesRequest,
+            ) -> ::std::result::Result<
+                DescribeInstancePatchesResult,
+                RusotoError<DescribeInstancePatchesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_instance_patches = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_inventory_deletions` with what `handler` returns for its input.
+    pub fn with_describe_inventory_deletions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeInventoryDeletionsRequest,
+            ) -> ::std::result::Result<
+                DescribeInventoryDeletionsResult,
+                RusotoError<DescribeInventoryDeletionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_inventory_deletions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_window_execution_task_invocations` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_window_execution_task_invocations<F>(
+        mut self,
+        handler: F,
+    ) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+                RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_window_execution_task_invocations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_window_execution_tasks` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_window_execution_tasks<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowExecutionTasksRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowExecutionTasksResult,
+                RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_window_execution_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_window_executions` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_window_executions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowExecutionsRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowExecutionsResult,
+                RusotoError<DescribeMaintenanceWindowExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_window_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_window_schedule` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_window_schedule<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowScheduleRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowScheduleResult,
+                RusotoError<DescribeMaintenanceWindowScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_window_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_window_targets` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_window_targets<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowTargetsRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowTargetsResult,
+                RusotoError<DescribeMaintenanceWindowTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_window_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_window_tasks` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_window_tasks<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowTasksRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowTasksResult,
+                RusotoError<DescribeMaintenanceWindowTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_window_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_windows` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_windows<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowsRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowsResult,
+                RusotoError<DescribeMaintenanceWindowsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_windows = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_windows_for_target` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_windows_for_target<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeMaintenanceWindowsForTargetRequest,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceWindowsForTargetResult,
+                RusotoError<DescribeMaintenanceWindowsForTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_windows_for_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ops_items` with what `handler` returns for its input.
+    pub fn with_describe_ops_items<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeOpsItemsRequest,
+            ) -> ::std::result::Result<
+                DescribeOpsItemsResponse,
+                RusotoError<DescribeOpsItemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ops_items = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_parameters` with what `handler` returns for its input.
+    pub fn with_describe_parameters<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeParametersRequest,
+            ) -> ::std::result::Result<
+                DescribeParametersResult,
+                RusotoError<DescribeParametersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_patch_baselines` with what `handler` returns for its input.
+    pub fn with_describe_patch_baselines<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribePatchBaselinesRequest,
+            ) -> ::std::result::Result<
+                DescribePatchBaselinesResult,
+                RusotoError<DescribePatchBaselinesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_patch_baselines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_patch_group_state` with what `handler` returns for its input.
+    pub fn with_describe_patch_group_state<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribePatchGroupStateRequest,
+            ) -> ::std::result::Result<
+                DescribePatchGroupStateResult,
+                RusotoError<DescribePatchGroupStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_patch_group_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_patch_groups` with what `handler` returns for its input.
+    pub fn with_describe_patch_groups<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribePatchGroupsRequest,
+            ) -> ::std::result::Result<
+                DescribePatchGroupsResult,
+                RusotoError<DescribePatchGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_patch_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_patch_properties` with what `handler` returns for its input.
+    pub fn with_describe_patch_properties<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribePatchPropertiesRequest,
+            ) -> ::std::result::Result<
+                DescribePatchPropertiesResult,
+                RusotoError<DescribePatchPropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_patch_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_sessions` with what `handler` returns for its input.
+    pub fn with_describe_sessions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                DescribeSessionsRequest,
+            ) -> ::std::result::Result<
+                DescribeSessionsResponse,
+                RusotoError<DescribeSessionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_sessions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_automation_execution` with what `handler` returns for its input.
+    pub fn with_get_automation_execution<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetAutomationExecutionRequest,
+            ) -> ::std::result::Result<
+                GetAutomationExecutionResult,
+                RusotoError<GetAutomationExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_automation_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_command_invocation` with what `handler` returns for its input.
+    pub fn with_get_command_invocation<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetCommandInvocationRequest,
+            ) -> ::std::result::Result<
+                GetCommandInvocationResult,
+                RusotoError<GetCommandInvocationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_command_invocation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_connection_status` with what `handler` returns for its input.
+    pub fn with_get_connection_status<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetConnectionStatusRequest,
+            ) -> ::std::result::Result<
+                GetConnectionStatusResponse,
+                RusotoError<GetConnectionStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_connection_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_default_patch_baseline` with what `handler` returns for its input.
+    pub fn with_get_default_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetDefaultPatchBaselineRequest,
+            ) -> ::std::result::Result<
+                GetDefaultPatchBaselineResult,
+                RusotoError<GetDefaultPatchBaselineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_default_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_deployable_patch_snapshot_for_instance` with what `handler` returns for its input.
+    pub fn with_get_deployable_patch_snapshot_for_instance<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetDeployablePatchSnapshotForInstanceRequest,
+            ) -> ::std::result::Result<
+                GetDeployablePatchSnapshotForInstanceResult,
+                RusotoError<GetDeployablePatchSnapshotForInstanceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_deployable_patch_snapshot_for_instance = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_document` with what `handler` returns for its input.
+    pub fn with_get_document<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetDocumentRequest,
+            )
+                -> ::std::result::Result<GetDocumentResult, RusotoError<GetDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_inventory` with what `handler` returns for its input.
+    pub fn with_get_inventory<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetInventoryRequest,
+            )
+                -> ::std::result::Result<GetInventoryResult, RusotoError<GetInventoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_inventory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_inventory_schema` with what `handler` returns for its input.
+    pub fn with_get_inventory_schema<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetInventorySchemaRequest,
+            ) -> ::std::result::Result<
+                GetInventorySchemaResult,
+                RusotoError<GetInventorySchemaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_inventory_schema = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_maintenance_window` with what `handler` returns for its input.
+    pub fn with_get_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                GetMaintenanceWindowResult,
+                RusotoError<GetMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_maintenance_window_execution` with what `handler` returns for its input.
+    pub fn with_get_maintenance_window_execution<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetMaintenanceWindowExecutionRequest,
+            ) -> ::std::result::Result<
+                GetMaintenanceWindowExecutionResult,
+                RusotoError<GetMaintenanceWindowExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_maintenance_window_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_maintenance_window_execution_task` with what `handler` returns for its input.
+    pub fn with_get_maintenance_window_execution_task<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetMaintenanceWindowExecutionTaskRequest,
+            ) -> ::std::result::Result<
+                GetMaintenanceWindowExecutionTaskResult,
+                RusotoError<GetMaintenanceWindowExecutionTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_maintenance_window_execution_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_maintenance_window_execution_task_invocation` with what `handler` returns for its input.
+    pub fn with_get_maintenance_window_execution_task_invocation<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetMaintenanceWindowExecutionTaskInvocationRequest,
+            ) -> ::std::result::Result<
+                GetMaintenanceWindowExecutionTaskInvocationResult,
+                RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_maintenance_window_execution_task_invocation = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_maintenance_window_task` with what `handler` returns for its input.
+    pub fn with_get_maintenance_window_task<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetMaintenanceWindowTaskRequest,
+            ) -> ::std::result::Result<
+                GetMaintenanceWindowTaskResult,
+                RusotoError<GetMaintenanceWindowTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_maintenance_window_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ops_item` with what `handler` returns for its input.
+    pub fn with_get_ops_item<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetOpsItemRequest,
+            )
+                -> ::std::result::Result<GetOpsItemResponse, RusotoError<GetOpsItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ops_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ops_summary` with what `handler` returns for its input.
+    pub fn with_get_ops_summary<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetOpsSummaryRequest,
+            )
+                -> ::std::result::Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ops_summary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_parameter` with what `handler` returns for its input.
+    pub fn with_get_parameter<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetParameterRequest,
+            )
+                -> ::std::result::Result<GetParameterResult, RusotoError<GetParameterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_parameter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_parameter_history` with what `handler` returns for its input.
+    pub fn with_get_parameter_history<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetParameterHistoryRequest,
+            ) -> ::std::result::Result<
+                GetParameterHistoryResult,
+                RusotoError<GetParameterHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_parameter_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_parameters` with what `handler` returns for its input.
+    pub fn with_get_parameters<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetParametersRequest,
+            )
+                -> ::std::result::Result<GetParametersResult, RusotoError<GetParametersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_parameters = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_parameters_by_path` with what `handler` returns for its input.
+    pub fn with_get_parameters_by_path<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetParametersByPathRequest,
+            ) -> ::std::result::Result<
+                GetParametersByPathResult,
+                RusotoError<GetParametersByPathError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_parameters_by_path = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_patch_baseline` with what `handler` returns for its input.
+    pub fn with_get_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetPatchBaselineRequest,
+            )
+                -> ::std::result::Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_patch_baseline_for_patch_group` with what `handler` returns for its input.
+    pub fn with_get_patch_baseline_for_patch_group<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetPatchBaselineForPatchGroupRequest,
+            ) -> ::std::result::Result<
+                GetPatchBaselineForPatchGroupResult,
+                RusotoError<GetPatchBaselineForPatchGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_patch_baseline_for_patch_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_service_setting` with what `handler` returns for its input.
+    pub fn with_get_service_setting<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                GetServiceSettingRequest,
+            ) -> ::std::result::Result<
+                GetServiceSettingResult,
+                RusotoError<GetServiceSettingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_service_setting = Some(Box::new(handler));
+        self
+    }
+    /// Answers `label_parameter_version` with what `handler` returns for its input.
+    pub fn with_label_parameter_version<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                LabelParameterVersionRequest,
+            ) -> ::std::result::Result<
+                LabelParameterVersionResult,
+                RusotoError<LabelParameterVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.label_parameter_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_association_versions` with what `handler` returns for its input.
+    pub fn with_list_association_versions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListAssociationVersionsRequest,
+            ) -> ::std::result::Result<
+                ListAssociationVersionsResult,
+                RusotoError<ListAssociationVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_association_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_associations` with what `handler` returns for its input.
+    pub fn with_list_associations<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListAssociationsRequest,
+            )
+                -> ::std::result::Result<ListAssociationsResult, RusotoError<ListAssociationsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_associations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_command_invocations` with what `handler` returns for its input.
+    pub fn with_list_command_invocations<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListCommandInvocationsRequest,
+            ) -> ::std::result::Result<
+                ListCommandInvocationsResult,
+                RusotoError<ListCommandInvocationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_command_invocations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_commands` with what `handler` returns for its input.
+    pub fn with_list_commands<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListCommandsRequest,
+            )
+                -> ::std::result::Result<ListCommandsResult, RusotoError<ListCommandsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_commands = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_compliance_items` with what `handler` returns for its input.
+    pub fn with_list_compliance_items<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListComplianceItemsRequest,
+            ) -> ::std::result::Result<
+                ListComplianceItemsResult,
+                RusotoError<ListComplianceItemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_compliance_items = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_compliance_summaries` with what `handler` returns for its input.
+    pub fn with_list_compliance_summaries<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListComplianceSummariesRequest,
+            ) -> ::std::result::Result<
+                ListComplianceSummariesResult,
+                RusotoError<ListComplianceSummariesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_compliance_summaries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_document_versions` with what `handler` returns for its input.
+    pub fn with_list_document_versions<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListDocumentVersionsRequest,
+            ) -> ::std::result::Result<
+                ListDocumentVersionsResult,
+                RusotoError<ListDocumentVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_document_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_documents` with what `handler` returns for its input.
+    pub fn with_list_documents<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListDocumentsRequest,
+            )
+                -> ::std::result::Result<ListDocumentsResult, RusotoError<ListDocumentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_documents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_inventory_entries` with what `handler` returns for its input.
+    pub fn with_list_inventory_entries<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListInventoryEntriesRequest,
+            ) -> ::std::result::Result<
+                ListInventoryEntriesResult,
+                RusotoError<ListInventoryEntriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_inventory_entries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_compliance_summaries` with what `handler` returns for its input.
+    pub fn with_list_resource_compliance_summaries<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListResourceComplianceSummariesRequest,
+            ) -> ::std::result::Result<
+                ListResourceComplianceSummariesResult,
+                RusotoError<ListResourceComplianceSummariesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_compliance_summaries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_data_sync` with what `handler` returns for its input.
+    pub fn with_list_resource_data_sync<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListResourceDataSyncRequest,
+            ) -> ::std::result::Result<
+                ListResourceDataSyncResult,
+                RusotoError<ListResourceDataSyncError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_data_sync = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResult,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_document_permission` with what `handler` returns for its input.
+    pub fn with_modify_document_permission<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ModifyDocumentPermissionRequest,
+            ) -> ::std::result::Result<
+                ModifyDocumentPermissionResponse,
+                RusotoError<ModifyDocumentPermissionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_document_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_compliance_items` with what `handler` returns for its input.
+    pub fn with_put_compliance_items<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                PutComplianceItemsRequest,
+            ) -> ::std::result::Result<
+                PutComplianceItemsResult,
+                RusotoError<PutComplianceItemsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_compliance_items = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_inventory` with what `handler` returns for its input.
+    pub fn with_put_inventory<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                PutInventoryRequest,
+            )
+                -> ::std::result::Result<PutInventoryResult, RusotoError<PutInventoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_inventory = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_parameter` with what `handler` returns for its input.
+    pub fn with_put_parameter<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                PutParameterRequest,
+            )
+                -> ::std::result::Result<PutParameterResult, RusotoError<PutParameterError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_parameter = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_default_patch_baseline` with what `handler` returns for its input.
+    pub fn with_register_default_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                RegisterDefaultPatchBaselineRequest,
+            ) -> ::std::result::Result<
+                RegisterDefaultPatchBaselineResult,
+                RusotoError<RegisterDefaultPatchBaselineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_default_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_patch_baseline_for_patch_group` with what `handler` returns for its input.
+    pub fn with_register_patch_baseline_for_patch_group<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                RegisterPatchBaselineForPatchGroupRequest,
+            ) -> ::std::result::Result<
+                RegisterPatchBaselineForPatchGroupResult,
+                RusotoError<RegisterPatchBaselineForPatchGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_patch_baseline_for_patch_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_target_with_maintenance_window` with what `handler` returns for its input.
+    pub fn with_register_target_with_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                RegisterTargetWithMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                RegisterTargetWithMaintenanceWindowResult,
+                RusotoError<RegisterTargetWithMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_target_with_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_task_with_maintenance_window` with what `handler` returns for its input.
+    pub fn with_register_task_with_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                RegisterTaskWithMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                RegisterTaskWithMaintenanceWindowResult,
+                RusotoError<RegisterTaskWithMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_task_with_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                RemoveTagsFromResourceRequest,
+            ) -> ::std::result::Result<
+                RemoveTagsFromResourceResult,
+                RusotoError<RemoveTagsFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_service_setting` with what `handler` returns for its input.
+    pub fn with_reset_service_setting<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ResetServiceSettingRequest,
+            ) -> ::std::result::Result<
+                ResetServiceSettingResult,
+                RusotoError<ResetServiceSettingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_service_setting = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resume_session` with what `handler` returns for its input.
+    pub fn with_resume_session<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                ResumeSessionRequest,
+            )
+                -> ::std::result::Result<ResumeSessionResponse, RusotoError<ResumeSessionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resume_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_automation_signal` with what `handler` returns for its input.
+    pub fn with_send_automation_signal<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                SendAutomationSignalRequest,
+            ) -> ::std::result::Result<
+                SendAutomationSignalResult,
+                RusotoError<SendAutomationSignalError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_automation_signal = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_command` with what `handler` returns for its input.
+    pub fn with_send_command<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                SendCommandRequest,
+            )
+                -> ::std::result::Result<SendCommandResult, RusotoError<SendCommandError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_command = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_associations_once` with what `handler` returns for its input.
+    pub fn with_start_associations_once<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                StartAssociationsOnceRequest,
+            ) -> ::std::result::Result<
+                StartAssociationsOnceResult,
+                RusotoError<StartAssociationsOnceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_associations_once = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_automation_execution` with what `handler` returns for its input.
+    pub fn with_start_automation_execution<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                StartAutomationExecutionRequest,
+            ) -> ::std::result::Result<
+                StartAutomationExecutionResult,
+                RusotoError<StartAutomationExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_automation_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_session` with what `handler` returns for its input.
+    pub fn with_start_session<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                StartSessionRequest,
+            )
+                -> ::std::result::Result<StartSessionResponse, RusotoError<StartSessionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_automation_execution` with what `handler` returns for its input.
+    pub fn with_stop_automation_execution<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                StopAutomationExecutionRequest,
+            ) -> ::std::result::Result<
+                StopAutomationExecutionResult,
+                RusotoError<StopAutomationExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_automation_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_session` with what `handler` returns for its input.
+    pub fn with_terminate_session<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                TerminateSessionRequest,
+            ) -> ::std::result::Result<
+                TerminateSessionResponse,
+                RusotoError<TerminateSessionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_session = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_association` with what `handler` returns for its input.
+    pub fn with_update_association<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateAssociationRequest,
+            ) -> ::std::result::Result<
+                UpdateAssociationResult,
+                RusotoError<UpdateAssociationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_association = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_association_status` with what `handler` returns for its input.
+    pub fn with_update_association_status<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateAssociationStatusRequest,
+            ) -> ::std::result::Result<
+                UpdateAssociationStatusResult,
+                RusotoError<UpdateAssociationStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_association_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_document` with what `handler` returns for its input.
+    pub fn with_update_document<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateDocumentRequest,
+            )
+                -> ::std::result::Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_document_default_version` with what `handler` returns for its input.
+    pub fn with_update_document_default_version<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateDocumentDefaultVersionRequest,
+            ) -> ::std::result::Result<
+                UpdateDocumentDefaultVersionResult,
+                RusotoError<UpdateDocumentDefaultVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_document_default_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_maintenance_window` with what `handler` returns for its input.
+    pub fn with_update_maintenance_window<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateMaintenanceWindowRequest,
+            ) -> ::std::result::Result<
+                UpdateMaintenanceWindowResult,
+                RusotoError<UpdateMaintenanceWindowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_maintenance_window = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_maintenance_window_target` with what `handler` returns for its input.
+    pub fn with_update_maintenance_window_target<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateMaintenanceWindowTargetRequest,
+            ) -> ::std::result::Result<
+                UpdateMaintenanceWindowTargetResult,
+                RusotoError<UpdateMaintenanceWindowTargetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_maintenance_window_target = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_maintenance_window_task` with what `handler` returns for its input.
+    pub fn with_update_maintenance_window_task<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateMaintenanceWindowTaskRequest,
+            ) -> ::std::result::Result<
+                UpdateMaintenanceWindowTaskResult,
+                RusotoError<UpdateMaintenanceWindowTaskError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_maintenance_window_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_managed_instance_role` with what `handler` returns for its input.
+    pub fn with_update_managed_instance_role<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateManagedInstanceRoleRequest,
+            ) -> ::std::result::Result<
+                UpdateManagedInstanceRoleResult,
+                RusotoError<UpdateManagedInstanceRoleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_managed_instance_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_ops_item` with what `handler` returns for its input.
+    pub fn with_update_ops_item<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateOpsItemRequest,
+            )
+                -> ::std::result::Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_ops_item = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_patch_baseline` with what `handler` returns for its input.
+    pub fn with_update_patch_baseline<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdatePatchBaselineRequest,
+            ) -> ::std::result::Result<
+                UpdatePatchBaselineResult,
+                RusotoError<UpdatePatchBaselineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_patch_baseline = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_service_setting` with what `handler` returns for its input.
+    pub fn with_update_service_setting<F>(mut self, handler: F) -> MockSsm
+    where
+        F: Fn(
+                UpdateServiceSettingRequest,
+            ) -> ::std::result::Result<
+                UpdateServiceSettingResult,
+                RusotoError<UpdateServiceSettingError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_service_setting = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Ssm for MockSsm {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for add_tags_to_resource"),
+        }
+    }
+    fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> RusotoFuture<CancelCommandResult, CancelCommandError> {
+        match self.cancel_command {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for cancel_command"),
+        }
+    }
+    fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<CancelMaintenanceWindowExecutionResult, CancelMaintenanceWindowExecutionError>
+    {
+        match self.cancel_maintenance_window_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for cancel_maintenance_window_execution"),
+        }
+    }
+    fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> RusotoFuture<CreateActivationResult, CreateActivationError> {
+        match self.create_activation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_activation"),
+        }
+    }
+    fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> RusotoFuture<CreateAssociationResult, CreateAssociationError> {
+        match self.create_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_association"),
+        }
+    }
+    fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> RusotoFuture<CreateAssociationBatchResult, CreateAssociationBatchError> {
+        match self.create_association_batch {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_association_batch"),
+        }
+    }
+    fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> RusotoFuture<CreateDocumentResult, CreateDocumentError> {
+        match self.create_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_document"),
+        }
+    }
+    fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> RusotoFuture<CreateMaintenanceWindowResult, CreateMaintenanceWindowError> {
+        match self.create_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_maintenance_window"),
+        }
+    }
+    fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> RusotoFuture<CreateOpsItemResponse, CreateOpsItemError> {
+        match self.create_ops_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_ops_item"),
+        }
+    }
+    fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> RusotoFuture<CreatePatchBaselineResult, CreatePatchBaselineError> {
+        match self.create_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_patch_baseline"),
+        }
+    }
+    fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> RusotoFuture<CreateResourceDataSyncResult, CreateResourceDataSyncError> {
+        match self.create_resource_data_sync {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for create_resource_data_sync"),
+        }
+    }
+    fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> RusotoFuture<DeleteActivationResult, DeleteActivationError> {
+        match self.delete_activation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_activation"),
+        }
+    }
+    fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> RusotoFuture<DeleteAssociationResult, DeleteAssociationError> {
+        match self.delete_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_association"),
+        }
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<DeleteDocumentResult, DeleteDocumentError> {
+        match self.delete_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_document"),
+        }
+    }
+    fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> RusotoFuture<DeleteInventoryResult, DeleteInventoryError> {
+        match self.delete_inventory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_inventory"),
+        }
+    }
+    fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> RusotoFuture<DeleteMaintenanceWindowResult, DeleteMaintenanceWindowError> {
+        match self.delete_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_maintenance_window"),
+        }
+    }
+    fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> RusotoFuture<DeleteParameterResult, DeleteParameterError> {
+        match self.delete_parameter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_parameter"),
+        }
+    }
+    fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> RusotoFuture<DeleteParametersResult, DeleteParametersError> {
+        match self.delete_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_parameters"),
+        }
+    }
+    fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> RusotoFuture<DeletePatchBaselineResult, DeletePatchBaselineError> {
+        match self.delete_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_patch_baseline"),
+        }
+    }
+    fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> RusotoFuture<DeleteResourceDataSyncResult, DeleteResourceDataSyncError> {
+        match self.delete_resource_data_sync {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for delete_resource_data_sync"),
+        }
+    }
+    fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> RusotoFuture<DeregisterManagedInstanceResult, DeregisterManagedInstanceError> {
+        match self.deregister_managed_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for deregister_managed_instance"),
+        }
+    }
+    fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DeregisterPatchBaselineForPatchGroupResult,
+        DeregisterPatchBaselineForPatchGroupError,
+    > {
+        match self.deregister_patch_baseline_for_patch_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for deregister_patch_baseline_for_patch_group"),
+        }
+    }
+    fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTargetFromMaintenanceWindowResult,
+        DeregisterTargetFromMaintenanceWindowError,
+    > {
+        match self.deregister_target_from_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for deregister_target_from_maintenance_window"),
+        }
+    }
+    fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> RusotoFuture<
+        DeregisterTaskFromMaintenanceWindowResult,
+        DeregisterTaskFromMaintenanceWindowError,
+    > {
+        match self.deregister_task_from_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for deregister_task_from_maintenance_window"),
+        }
+    }
+    fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> RusotoFuture<DescribeActivationsResult, DescribeActivationsError> {
+        match self.describe_activations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_activations"),
+        }
+    }
+    fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> RusotoFuture<DescribeAssociationResult, DescribeAssociationError> {
+        match self.describe_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_association"),
+        }
+    }
+    fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> RusotoFuture<
+        DescribeAssociationExecutionTargetsResult,
+        DescribeAssociationExecutionTargetsError,
+    > {
+        match self.describe_association_execution_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_association_execution_targets"),
+        }
+    }
+    fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAssociationExecutionsResult, DescribeAssociationExecutionsError> {
+        match self.describe_association_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_association_executions"),
+        }
+    }
+    fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationExecutionsResult, DescribeAutomationExecutionsError> {
+        match self.describe_automation_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_automation_executions"),
+        }
+    }
+    fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> RusotoFuture<DescribeAutomationStepExecutionsResult, DescribeAutomationStepExecutionsError>
+    {
+        match self.describe_automation_step_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_automation_step_executions"),
+        }
+    }
+    fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> RusotoFuture<DescribeAvailablePatchesResult, DescribeAvailablePatchesError> {
+        match self.describe_available_patches {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_available_patches"),
+        }
+    }
+    fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> RusotoFuture<DescribeDocumentResult, DescribeDocumentError> {
+        match self.describe_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_document"),
+        }
+    }
+    fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> RusotoFuture<DescribeDocumentPermissionResponse, DescribeDocumentPermissionError> {
+        match self.describe_document_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_document_permission"),
+        }
+    }
+    fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeEffectiveInstanceAssociationsResult,
+        DescribeEffectiveInstanceAssociationsError,
+    > {
+        match self.describe_effective_instance_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_effective_instance_associations"),
+        }
+    }
+    fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> RusotoFuture<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        DescribeEffectivePatchesForPatchBaselineError,
+    > {
+        match self.describe_effective_patches_for_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSsm has no handler for describe_effective_patches_for_patch_baseline")
+            }
+        }
+    }
+    fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> RusotoFuture<
+        DescribeInstanceAssociationsStatusResult,
+        DescribeInstanceAssociationsStatusError,
+    > {
+        match self.describe_instance_associations_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_instance_associations_status"),
+        }
+    }
+    fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> RusotoFuture<DescribeInstanceInformationResult, DescribeInstanceInformationError> {
+        match self.describe_instance_information {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_instance_information"),
+        }
+    }
+    fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchStatesResult, DescribeInstancePatchStatesError> {
+        match self.describe_instance_patch_states {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_instance_patch_states"),
+        }
+    }
+    fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> RusotoFuture<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        DescribeInstancePatchStatesForPatchGroupError,
+    > {
+        match self.describe_instance_patch_states_for_patch_group {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSsm has no handler for describe_instance_patch_states_for_patch_group")
+            }
+        }
+    }
+    fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> RusotoFuture<DescribeInstancePatchesResult, DescribeInstancePatchesError> {
+        match self.describe_instance_patches {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_instance_patches"),
+        }
+    }
+    fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> RusotoFuture<DescribeInventoryDeletionsResult, DescribeInventoryDeletionsError> {
+        match self.describe_inventory_deletions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_inventory_deletions"),
+        }
+    }
+    fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        DescribeMaintenanceWindowExecutionTaskInvocationsError,
+    > {
+        match self.describe_maintenance_window_execution_task_invocations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockSsm has no handler for describe_maintenance_window_execution_task_invocations"
+            ),
+        }
+    }
+    fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        DescribeMaintenanceWindowExecutionTasksError,
+    > {
+        match self.describe_maintenance_window_execution_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockSsm has no handler for describe_maintenance_window_execution_tasks")
+            }
+        }
+    }
+    fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowExecutionsResult,
+        DescribeMaintenanceWindowExecutionsError,
+    > {
+        match self.describe_maintenance_window_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_maintenance_window_executions"),
+        }
+    }
+    fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowScheduleResult, DescribeMaintenanceWindowScheduleError>
+    {
+        match self.describe_maintenance_window_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_maintenance_window_schedule"),
+        }
+    }
+    fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTargetsResult, DescribeMaintenanceWindowTargetsError>
+    {
+        match self.describe_maintenance_window_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_maintenance_window_targets"),
+        }
+    }
+    fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowTasksResult, DescribeMaintenanceWindowTasksError>
+    {
+        match self.describe_maintenance_window_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_maintenance_window_tasks"),
+        }
+    }
+    fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> RusotoFuture<DescribeMaintenanceWindowsResult, DescribeMaintenanceWindowsError> {
+        match self.describe_maintenance_windows {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_maintenance_windows"),
+        }
+    }
+    fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> RusotoFuture<
+        DescribeMaintenanceWindowsForTargetResult,
+        DescribeMaintenanceWindowsForTargetError,
+    > {
+        match self.describe_maintenance_windows_for_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_maintenance_windows_for_target"),
+        }
+    }
+    fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> RusotoFuture<DescribeOpsItemsResponse, DescribeOpsItemsError> {
+        match self.describe_ops_items {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_ops_items"),
+        }
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResult, DescribeParametersError> {
+        match self.describe_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_parameters"),
+        }
+    }
+    fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> RusotoFuture<DescribePatchBaselinesResult, DescribePatchBaselinesError> {
+        match self.describe_patch_baselines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_patch_baselines"),
+        }
+    }
+    fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> RusotoFuture<DescribePatchGroupStateResult, DescribePatchGroupStateError> {
+        match self.describe_patch_group_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_patch_group_state"),
+        }
+    }
+    fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> RusotoFuture<DescribePatchGroupsResult, DescribePatchGroupsError> {
+        match self.describe_patch_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_patch_groups"),
+        }
+    }
+    fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> RusotoFuture<DescribePatchPropertiesResult, DescribePatchPropertiesError> {
+        match self.describe_patch_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_patch_properties"),
+        }
+    }
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResponse, DescribeSessionsError> {
+        match self.describe_sessions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for describe_sessions"),
+        }
+    }
+    fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> RusotoFuture<GetAutomationExecutionResult, GetAutomationExecutionError> {
+        match self.get_automation_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_automation_execution"),
+        }
+    }
+    fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> RusotoFuture<GetCommandInvocationResult, GetCommandInvocationError> {
+        match self.get_command_invocation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_command_invocation"),
+        }
+    }
+    fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> RusotoFuture<GetConnectionStatusResponse, GetConnectionStatusError> {
+        match self.get_connection_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_connection_status"),
+        }
+    }
+    fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> RusotoFuture<GetDefaultPatchBaselineResult, GetDefaultPatchBaselineError> {
+        match self.get_default_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_default_patch_baseline"),
+        }
+    }
+    fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> RusotoFuture<
+        GetDeployablePatchSnapshotForInstanceResult,
+        GetDeployablePatchSnapshotForInstanceError,
+    > {
+        match self.get_deployable_patch_snapshot_for_instance {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_deployable_patch_snapshot_for_instance"),
+        }
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResult, GetDocumentError> {
+        match self.get_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_document"),
+        }
+    }
+    fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> RusotoFuture<GetInventoryResult, GetInventoryError> {
+        match self.get_inventory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_inventory"),
+        }
+    }
+    fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> RusotoFuture<GetInventorySchemaResult, GetInventorySchemaError> {
+        match self.get_inventory_schema {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_inventory_schema"),
+        }
+    }
+    fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowResult, GetMaintenanceWindowError> {
+        match self.get_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_maintenance_window"),
+        }
+    }
+    fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionResult, GetMaintenanceWindowExecutionError> {
+        match self.get_maintenance_window_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_maintenance_window_execution"),
+        }
+    }
+    fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowExecutionTaskResult, GetMaintenanceWindowExecutionTaskError>
+    {
+        match self.get_maintenance_window_execution_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_maintenance_window_execution_task"),
+        }
+    }
+    fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> RusotoFuture<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        GetMaintenanceWindowExecutionTaskInvocationError,
+    > {
+        match self.get_maintenance_window_execution_task_invocation {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockSsm has no handler for get_maintenance_window_execution_task_invocation"
+            ),
+        }
+    }
+    fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> RusotoFuture<GetMaintenanceWindowTaskResult, GetMaintenanceWindowTaskError> {
+        match self.get_maintenance_window_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_maintenance_window_task"),
+        }
+    }
+    fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> RusotoFuture<GetOpsItemResponse, GetOpsItemError> {
+        match self.get_ops_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_ops_item"),
+        }
+    }
+    fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> RusotoFuture<GetOpsSummaryResult, GetOpsSummaryError> {
+        match self.get_ops_summary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_ops_summary"),
+        }
+    }
+    fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> RusotoFuture<GetParameterResult, GetParameterError> {
+        match self.get_parameter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_parameter"),
+        }
+    }
+    fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> RusotoFuture<GetParameterHistoryResult, GetParameterHistoryError> {
+        match self.get_parameter_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_parameter_history"),
+        }
+    }
+    fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> RusotoFuture<GetParametersResult, GetParametersError> {
+        match self.get_parameters {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_parameters"),
+        }
+    }
+    fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> RusotoFuture<GetParametersByPathResult, GetParametersByPathError> {
+        match self.get_parameters_by_path {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_parameters_by_path"),
+        }
     }
-
-    /// <p>Retrieves information about a patch baseline.</p>
     fn get_patch_baseline(
         &self,
         input: GetPatchBaselineRequest,
     ) -> RusotoFuture<GetPatchBaselineResult, GetPatchBaselineError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetPatchBaseline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetPatchBaselineError::from_response(response))),
-                )
-            }
-        })
+        match self.get_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_patch_baseline"),
+        }
     }
-
-    /// <p>Retrieves the patch baseline that should be used for the specified patch group.</p>
     fn get_patch_baseline_for_patch_group(
         &self,
         input: GetPatchBaselineForPatchGroupRequest,
     ) -> RusotoFuture<GetPatchBaselineForPatchGroupResult, GetPatchBaselineForPatchGroupError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetPatchBaselineForPatchGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPatchBaselineForPatchGroupResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetPatchBaselineForPatchGroupError::from_response(response))
-                }))
-            }
-        })
+        match self.get_patch_baseline_for_patch_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_patch_baseline_for_patch_group"),
+        }
     }
-
-    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>UpdateServiceSetting</a> API action to change the default setting. Or use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Query the current service setting for the account. </p>
     fn get_service_setting(
         &self,
         input: GetServiceSettingRequest,
     ) -> RusotoFuture<GetServiceSettingResult, GetServiceSettingError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.GetServiceSetting");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetServiceSettingResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetServiceSettingError::from_response(response))),
-                )
-            }
-        })
+        match self.get_service_setting {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for get_service_setting"),
+        }
     }
-
-    /// <p><p>A parameter label is a user-defined alias to help you manage different versions of a parameter. When you modify a parameter, Systems Manager automatically saves a new version and increments the version number by one. A label can help you remember the purpose of a parameter when there are multiple versions. </p> <p>Parameter labels have the following requirements and restrictions.</p> <ul> <li> <p>A version of a parameter can have a maximum of 10 labels.</p> </li> <li> <p>You can&#39;t attach the same label to different versions of the same parameter. For example, if version 1 has the label Production, then you can&#39;t attach Production to version 2.</p> </li> <li> <p>You can move a label from one version of a parameter to another.</p> </li> <li> <p>You can&#39;t create a label when you create a new parameter. You must attach a label to a specific version of a parameter.</p> </li> <li> <p>You can&#39;t delete a parameter label. If you no longer want to use a parameter label, then you must move it to a different version of a parameter.</p> </li> <li> <p>A label can have a maximum of 100 characters.</p> </li> <li> <p>Labels can contain letters (case sensitive), numbers, periods (.), hyphens (-), or underscores (_).</p> </li> <li> <p>Labels can&#39;t begin with a number, &quot;aws,&quot; or &quot;ssm&quot; (not case sensitive). If a label fails to meet these requirements, then the label is not associated with a parameter and the system displays it in the list of InvalidLabels.</p> </li> </ul></p>
     fn label_parameter_version(
         &self,
         input: LabelParameterVersionRequest,
     ) -> RusotoFuture<LabelParameterVersionResult, LabelParameterVersionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.LabelParameterVersion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<LabelParameterVersionResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(LabelParameterVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.label_parameter_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for label_parameter_version"),
+        }
     }
-
-    /// <p>Retrieves all versions of an association for a specific association ID.</p>
     fn list_association_versions(
         &self,
         input: ListAssociationVersionsRequest,
     ) -> RusotoFuture<ListAssociationVersionsResult, ListAssociationVersionsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListAssociationVersions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssociationVersionsResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAssociationVersionsError::from_response(response))
-                }))
-            }
-        })
+        match self.list_association_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_association_versions"),
+        }
     }
-
-    /// <p>Lists the associations for the specified Systems Manager document or instance.</p>
     fn list_associations(
         &self,
         input: ListAssociationsRequest,
     ) -> RusotoFuture<ListAssociationsResult, ListAssociationsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListAssociations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAssociationsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAssociationsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_associations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_associations"),
+        }
     }
-
-    /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. ListCommandInvocations provide status about command execution.</p>
     fn list_command_invocations(
         &self,
         input: ListCommandInvocationsRequest,
     ) -> RusotoFuture<ListCommandInvocationsResult, ListCommandInvocationsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListCommandInvocations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCommandInvocationsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListCommandInvocationsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_command_invocations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_command_invocations"),
+        }
     }
-
-    /// <p>Lists the commands requested by users of the AWS account.</p>
     fn list_commands(
         &self,
         input: ListCommandsRequest,
     ) -> RusotoFuture<ListCommandsResult, ListCommandsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListCommands");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListCommandsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListCommandsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_commands {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_commands"),
+        }
     }
-
-    /// <p>For a specified resource ID, this API action returns a list of compliance statuses for different resource types. Currently, you can only specify one resource ID per call. List results depend on the criteria specified in the filter. </p>
     fn list_compliance_items(
         &self,
         input: ListComplianceItemsRequest,
     ) -> RusotoFuture<ListComplianceItemsResult, ListComplianceItemsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListComplianceItems");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListComplianceItemsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListComplianceItemsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_compliance_items {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_compliance_items"),
+        }
     }
-
-    /// <p>Returns a summary count of compliant and non-compliant resources for a compliance type. For example, this call can return State Manager associations, patches, or custom compliance types according to the filter criteria that you specify. </p>
     fn list_compliance_summaries(
         &self,
         input: ListComplianceSummariesRequest,
     ) -> RusotoFuture<ListComplianceSummariesResult, ListComplianceSummariesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListComplianceSummaries");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListComplianceSummariesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListComplianceSummariesError::from_response(response))
-                }))
-            }
-        })
+        match self.list_compliance_summaries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_compliance_summaries"),
+        }
     }
-
-    /// <p>List all versions for a document.</p>
     fn list_document_versions(
-        &self,
-        input: ListDocumentVersionsRequest,
-    ) -> RusotoFuture<ListDocumentVersionsResult, ListDocumentVersionsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListDocumentVersions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDocumentVersionsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListDocumentVersionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> RusotoFuture<ListDocumentVersionsResult, ListDocumentVersionsError> {
+        match self.list_document_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_document_versions"),
+        }
     }
-
-    /// <p>Describes one or more of your Systems Manager documents.</p>
     fn list_documents(
         &self,
         input: ListDocumentsRequest,
     ) -> RusotoFuture<ListDocumentsResult, ListDocumentsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListDocuments");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDocumentsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDocumentsError::from_response(response))),
-                )
-            }
-        })
+        match self.list_documents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_documents"),
+        }
     }
-
-    /// <p>A list of inventory items returned by the request.</p>
     fn list_inventory_entries(
         &self,
         input: ListInventoryEntriesRequest,
     ) -> RusotoFuture<ListInventoryEntriesResult, ListInventoryEntriesError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListInventoryEntries");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListInventoryEntriesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListInventoryEntriesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_inventory_entries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_inventory_entries"),
+        }
     }
-
-    /// <p>Returns a resource-level summary count. The summary includes information about compliant and non-compliant statuses and detailed compliance-item severity counts, according to the filter criteria you specify.</p>
     fn list_resource_compliance_summaries(
         &self,
         input: ListResourceComplianceSummariesRequest,
     ) -> RusotoFuture<ListResourceComplianceSummariesResult, ListResourceComplianceSummariesError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListResourceComplianceSummaries");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceComplianceSummariesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListResourceComplianceSummariesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.list_resource_compliance_summaries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_resource_compliance_summaries"),
+        }
     }
-
-    /// <p>Lists your resource data sync configurations. Includes information about the last time a sync attempted to start, the last sync status, and the last time a sync successfully completed.</p> <p>The number of sync configurations might be too large to return using a single call to <code>ListResourceDataSync</code>. You can limit the number of sync configurations returned by using the <code>MaxResults</code> parameter. To determine whether there are more sync configurations to list, check the value of <code>NextToken</code> in the output. If there are more sync configurations to list, you can request them by specifying the <code>NextToken</code> returned in the call to the parameter of a subsequent call. </p>
     fn list_resource_data_sync(
         &self,
         input: ListResourceDataSyncRequest,
     ) -> RusotoFuture<ListResourceDataSyncResult, ListResourceDataSyncError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListResourceDataSync");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceDataSyncResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListResourceDataSyncError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_resource_data_sync {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_resource_data_sync"),
+        }
     }
-
-    /// <p>Returns a list of the tags assigned to the specified resource.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for list_tags_for_resource"),
+        }
     }
-
-    /// <p>Shares a Systems Manager document publicly or privately. If you share a document privately, you must specify the AWS user account IDs for those people who can use the document. If you share a document publicly, you must specify <i>All</i> as the account ID.</p>
     fn modify_document_permission(
         &self,
         input: ModifyDocumentPermissionRequest,
     ) -> RusotoFuture<ModifyDocumentPermissionResponse, ModifyDocumentPermissionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ModifyDocumentPermission");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyDocumentPermissionResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyDocumentPermissionError::from_response(response))
-                }))
-            }
-        })
+        match self.modify_document_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for modify_document_permission"),
+        }
     }
-
-    /// <p><p>Registers a compliance type and other compliance details on a designated resource. This action lets you register custom compliance details with a resource. This call overwrites existing compliance information on the resource, so you must provide a full list of compliance items each time that you send the request.</p> <p>ComplianceType can be one of the following:</p> <ul> <li> <p>ExecutionId: The execution ID when the patch, association, or custom compliance item was applied.</p> </li> <li> <p>ExecutionType: Specify patch, association, or Custom:<code>string</code>.</p> </li> <li> <p>ExecutionTime. The time the patch, association, or custom compliance item was applied to the instance.</p> </li> <li> <p>Id: The patch, association, or custom compliance ID.</p> </li> <li> <p>Title: A title.</p> </li> <li> <p>Status: The status of the compliance item. For example, <code>approved</code> for patches, or <code>Failed</code> for associations.</p> </li> <li> <p>Severity: A patch severity. For example, <code>critical</code>.</p> </li> <li> <p>DocumentName: A SSM document name. For example, AWS-RunPatchBaseline.</p> </li> <li> <p>DocumentVersion: An SSM document version number. For example, 4.</p> </li> <li> <p>Classification: A patch classification. For example, <code>security updates</code>.</p> </li> <li> <p>PatchBaselineId: A patch baseline ID.</p> </li> <li> <p>PatchSeverity: A patch severity. For example, <code>Critical</code>.</p> </li> <li> <p>PatchState: A patch state. For example, <code>InstancesWithFailedPatches</code>.</p> </li> <li> <p>PatchGroup: The name of a patch group.</p> </li> <li> <p>InstalledTime: The time the association, patch, or custom compliance item was applied to the resource. Specify the time by using the following format: yyyy-MM-dd&#39;T&#39;HH:mm:ss&#39;Z&#39;</p> </li> </ul></p>
     fn put_compliance_items(
         &self,
         input: PutComplianceItemsRequest,
     ) -> RusotoFuture<PutComplianceItemsResult, PutComplianceItemsError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.PutComplianceItems");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutComplianceItemsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutComplianceItemsError::from_response(response))),
-                )
-            }
-        })
+        match self.put_compliance_items {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for put_compliance_items"),
+        }
     }
-
-    /// <p>Bulk update custom inventory items on one more instance. The request adds an inventory item, if it doesn't already exist, or updates an inventory item, if it does exist.</p>
     fn put_inventory(
         &self,
         input: PutInventoryRequest,
     ) -> RusotoFuture<PutInventoryResult, PutInventoryError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.PutInventory");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutInventoryResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutInventoryError::from_response(response))),
-                )
-            }
-        })
+        match self.put_inventory {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for put_inventory"),
+        }
     }
-
-    /// <p>Add a parameter to the system.</p>
     fn put_parameter(
         &self,
         input: PutParameterRequest,
     ) -> RusotoFuture<PutParameterResult, PutParameterError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.PutParameter");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutParameterResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutParameterError::from_response(response))),
-                )
-            }
-        })
+        match self.put_parameter {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for put_parameter"),
+        }
     }
-
-    /// <p>Defines the default patch baseline for the relevant operating system.</p> <p>To reset the AWS predefined patch baseline as the default, specify the full patch baseline ARN as the baseline ID value. For example, for CentOS, specify <code>arn:aws:ssm:us-east-2:733109147000:patchbaseline/pb-0574b43a65ea646ed</code> instead of <code>pb-0574b43a65ea646ed</code>.</p>
     fn register_default_patch_baseline(
         &self,
         input: RegisterDefaultPatchBaselineRequest,
     ) -> RusotoFuture<RegisterDefaultPatchBaselineResult, RegisterDefaultPatchBaselineError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.RegisterDefaultPatchBaseline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterDefaultPatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterDefaultPatchBaselineError::from_response(response))
-                }))
-            }
-        })
-    }
-
-    /// <p>Registers a patch baseline for a patch group.</p>
-    fn register_patch_baseline_for_patch_group(
-        &self,
-        input: RegisterPatchBaselineForPatchGroupRequest,
-    ) -> RusotoFuture<
-        RegisterPatchBaselineForPatchGroupResult,
-        RegisterPatchBaselineForPatchGroupError,
-    > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.RegisterPatchBaselineForPatchGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterPatchBaselineForPatchGroupResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterPatchBaselineForPatchGroupError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.register_default_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for register_default_patch_baseline"),
+        }
+    }
+    fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> RusotoFuture<
+        RegisterPatchBaselineForPatchGroupResult,
+        RegisterPatchBaselineForPatchGroupError,
+    > {
+        match self.register_patch_baseline_for_patch_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for register_patch_baseline_for_patch_group"),
+        }
     }
-
-    /// <p>Registers a target with a maintenance window.</p>
     fn register_target_with_maintenance_window(
         &self,
         input: RegisterTargetWithMaintenanceWindowRequest,
@@ -17710,640 +30888,208 @@ impl Ssm for SsmClient {
         RegisterTargetWithMaintenanceWindowResult,
         RegisterTargetWithMaintenanceWindowError,
     > {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.RegisterTargetWithMaintenanceWindow",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterTargetWithMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterTargetWithMaintenanceWindowError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.register_target_with_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for register_target_with_maintenance_window"),
+        }
     }
-
-    /// <p>Adds a new task to a maintenance window.</p>
     fn register_task_with_maintenance_window(
         &self,
         input: RegisterTaskWithMaintenanceWindowRequest,
     ) -> RusotoFuture<RegisterTaskWithMaintenanceWindowResult, RegisterTaskWithMaintenanceWindowError>
     {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AmazonSSM.RegisterTaskWithMaintenanceWindow",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterTaskWithMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RegisterTaskWithMaintenanceWindowError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.register_task_with_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for register_task_with_maintenance_window"),
+        }
     }
-
-    /// <p>Removes tag keys from the specified resource.</p>
     fn remove_tags_from_resource(
         &self,
         input: RemoveTagsFromResourceRequest,
     ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.RemoveTagsFromResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsFromResourceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveTagsFromResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for remove_tags_from_resource"),
+        }
     }
-
-    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Use the <a>UpdateServiceSetting</a> API action to change the default setting. </p> <p>Reset the service setting for the account to the default value as provisioned by the AWS service team. </p>
     fn reset_service_setting(
         &self,
         input: ResetServiceSettingRequest,
     ) -> RusotoFuture<ResetServiceSettingResult, ResetServiceSettingError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ResetServiceSetting");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetServiceSettingResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ResetServiceSettingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.reset_service_setting {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for reset_service_setting"),
+        }
     }
-
-    /// <p><p>Reconnects a session to an instance after it has been disconnected. Connections can be resumed for disconnected sessions, but not terminated sessions.</p> <note> <p>This command is primarily for use by client machines to automatically reconnect during intermittent network issues. It is not intended for any other use.</p> </note></p>
     fn resume_session(
         &self,
         input: ResumeSessionRequest,
     ) -> RusotoFuture<ResumeSessionResponse, ResumeSessionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.ResumeSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResumeSessionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResumeSessionError::from_response(response))),
-                )
-            }
-        })
+        match self.resume_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for resume_session"),
+        }
     }
-
-    /// <p>Sends a signal to an Automation execution to change the current behavior or status of the execution. </p>
     fn send_automation_signal(
         &self,
         input: SendAutomationSignalRequest,
     ) -> RusotoFuture<SendAutomationSignalResult, SendAutomationSignalError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.SendAutomationSignal");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendAutomationSignalResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SendAutomationSignalError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.send_automation_signal {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for send_automation_signal"),
+        }
     }
-
-    /// <p>Runs commands on one or more managed instances.</p>
     fn send_command(
         &self,
         input: SendCommandRequest,
     ) -> RusotoFuture<SendCommandResult, SendCommandError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.SendCommand");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendCommandResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SendCommandError::from_response(response))),
-                )
-            }
-        })
+        match self.send_command {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for send_command"),
+        }
     }
-
-    /// <p>Use this API action to run an association immediately and only one time. This action can be helpful when troubleshooting associations.</p>
     fn start_associations_once(
         &self,
         input: StartAssociationsOnceRequest,
     ) -> RusotoFuture<StartAssociationsOnceResult, StartAssociationsOnceError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.StartAssociationsOnce");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartAssociationsOnceResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartAssociationsOnceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.start_associations_once {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for start_associations_once"),
+        }
     }
-
-    /// <p>Initiates execution of an Automation document.</p>
     fn start_automation_execution(
         &self,
         input: StartAutomationExecutionRequest,
     ) -> RusotoFuture<StartAutomationExecutionResult, StartAutomationExecutionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.StartAutomationExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartAutomationExecutionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StartAutomationExecutionError::from_response(response))
-                }))
-            }
-        })
+        match self.start_automation_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for start_automation_execution"),
+        }
     }
-
-    /// <p><p>Initiates a connection to a target (for example, an instance) for a Session Manager session. Returns a URL and token that can be used to open a WebSocket connection for sending input and receiving outputs.</p> <note> <p>AWS CLI usage: <code>start-session</code> is an interactive command that requires the Session Manager plugin to be installed on the client machine making the call. For information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-working-with-install-plugin.html"> Install the Session Manager Plugin for the AWS CLI</a> in the <i>AWS Systems Manager User Guide</i>.</p> </note></p>
     fn start_session(
         &self,
         input: StartSessionRequest,
     ) -> RusotoFuture<StartSessionResponse, StartSessionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.StartSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartSessionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartSessionError::from_response(response))),
-                )
-            }
-        })
+        match self.start_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for start_session"),
+        }
     }
-
-    /// <p>Stop an Automation that is currently running.</p>
     fn stop_automation_execution(
         &self,
         input: StopAutomationExecutionRequest,
     ) -> RusotoFuture<StopAutomationExecutionResult, StopAutomationExecutionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.StopAutomationExecution");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopAutomationExecutionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(StopAutomationExecutionError::from_response(response))
-                }))
-            }
-        })
+        match self.stop_automation_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for stop_automation_execution"),
+        }
     }
-
-    /// <p>Permanently ends a session and closes the data connection between the Session Manager client and SSM Agent on the instance. A terminated session cannot be resumed.</p>
     fn terminate_session(
         &self,
         input: TerminateSessionRequest,
     ) -> RusotoFuture<TerminateSessionResponse, TerminateSessionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.TerminateSession");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TerminateSessionResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TerminateSessionError::from_response(response))),
-                )
-            }
-        })
+        match self.terminate_session {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for terminate_session"),
+        }
     }
-
-    /// <p><p>Updates an association. You can update the association name and version, the document version, schedule, parameters, and Amazon S3 output.</p> <important> <p>When you update an association, the association immediately runs against the specified targets.</p> </important></p>
     fn update_association(
         &self,
         input: UpdateAssociationRequest,
     ) -> RusotoFuture<UpdateAssociationResult, UpdateAssociationError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateAssociation");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAssociationResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateAssociationError::from_response(response))),
-                )
-            }
-        })
+        match self.update_association {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_association"),
+        }
     }
-
-    /// <p>Updates the status of the Systems Manager document associated with the specified instance.</p>
     fn update_association_status(
         &self,
         input: UpdateAssociationStatusRequest,
     ) -> RusotoFuture<UpdateAssociationStatusResult, UpdateAssociationStatusError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateAssociationStatus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAssociationStatusResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateAssociationStatusError::from_response(response))
-                }))
-            }
-        })
+        match self.update_association_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_association_status"),
+        }
     }
-
-    /// <p>Updates one or more values for an SSM document.</p>
     fn update_document(
         &self,
         input: UpdateDocumentRequest,
     ) -> RusotoFuture<UpdateDocumentResult, UpdateDocumentError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateDocument");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDocumentResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDocumentError::from_response(response))),
-                )
-            }
-        })
+        match self.update_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_document"),
+        }
     }
-
-    /// <p>Set the default version of a document. </p>
     fn update_document_default_version(
         &self,
         input: UpdateDocumentDefaultVersionRequest,
     ) -> RusotoFuture<UpdateDocumentDefaultVersionResult, UpdateDocumentDefaultVersionError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentDefaultVersion");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDocumentDefaultVersionResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateDocumentDefaultVersionError::from_response(response))
-                }))
-            }
-        })
+        match self.update_document_default_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_document_default_version"),
+        }
     }
-
-    /// <p>Updates an existing maintenance window. Only specified parameters are modified.</p>
     fn update_maintenance_window(
         &self,
         input: UpdateMaintenanceWindowRequest,
     ) -> RusotoFuture<UpdateMaintenanceWindowResult, UpdateMaintenanceWindowError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindow");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMaintenanceWindowResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateMaintenanceWindowError::from_response(response))
-                }))
-            }
-        })
+        match self.update_maintenance_window {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_maintenance_window"),
+        }
     }
-
-    /// <p><p>Modifies the target of an existing maintenance window. You can change the following:</p> <ul> <li> <p>Name</p> </li> <li> <p>Description</p> </li> <li> <p>Owner</p> </li> <li> <p>IDs for an ID target</p> </li> <li> <p>Tags for a Tag target</p> </li> <li> <p>From any supported tag type to another. The three supported tag types are ID target, Tag target, and resource group. For more information, see <a>Target</a>.</p> </li> </ul> <note> <p>If a parameter is null, then the corresponding field is not modified.</p> </note></p>
     fn update_maintenance_window_target(
         &self,
         input: UpdateMaintenanceWindowTargetRequest,
     ) -> RusotoFuture<UpdateMaintenanceWindowTargetResult, UpdateMaintenanceWindowTargetError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTarget");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMaintenanceWindowTargetResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateMaintenanceWindowTargetError::from_response(response))
-                }))
-            }
-        })
+        match self.update_maintenance_window_target {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_maintenance_window_target"),
+        }
     }
-
-    /// <p>Modifies a task assigned to a maintenance window. You can't change the task type, but you can change the following values:</p> <ul> <li> <p>TaskARN. For example, you can change a RUN_COMMAND task from AWS-RunPowerShellScript to AWS-RunShellScript.</p> </li> <li> <p>ServiceRoleArn</p> </li> <li> <p>TaskInvocationParameters</p> </li> <li> <p>Priority</p> </li> <li> <p>MaxConcurrency</p> </li> <li> <p>MaxErrors</p> </li> </ul> <p>If a parameter is null, then the corresponding field is not modified. Also, if you set Replace to true, then all fields required by the <a>RegisterTaskWithMaintenanceWindow</a> action are required for this request. Optional fields that aren't specified are set to null.</p>
     fn update_maintenance_window_task(
         &self,
         input: UpdateMaintenanceWindowTaskRequest,
     ) -> RusotoFuture<UpdateMaintenanceWindowTaskResult, UpdateMaintenanceWindowTaskError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMaintenanceWindowTaskResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateMaintenanceWindowTaskError::from_response(response))
-                }))
-            }
-        })
+        match self.update_maintenance_window_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_maintenance_window_task"),
+        }
     }
-
-    /// <p>Assigns or changes an Amazon Identity and Access Management (IAM) role for the managed instance.</p>
     fn update_managed_instance_role(
         &self,
         input: UpdateManagedInstanceRoleRequest,
     ) -> RusotoFuture<UpdateManagedInstanceRoleResult, UpdateManagedInstanceRoleError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateManagedInstanceRole");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateManagedInstanceRoleResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateManagedInstanceRoleError::from_response(response))
-                }))
-            }
-        })
+        match self.update_managed_instance_role {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_managed_instance_role"),
+        }
     }
-
-    /// <p>Edit or change an OpsItem. You must have permission in AWS Identity and Access Management (IAM) to update an OpsItem. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting Started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
     fn update_ops_item(
         &self,
         input: UpdateOpsItemRequest,
     ) -> RusotoFuture<UpdateOpsItemResponse, UpdateOpsItemError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateOpsItem");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateOpsItemResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateOpsItemError::from_response(response))),
-                )
-            }
-        })
+        match self.update_ops_item {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_ops_item"),
+        }
     }
-
-    /// <p><p>Modifies an existing patch baseline. Fields not specified in the request are left unchanged.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
     fn update_patch_baseline(
         &self,
         input: UpdatePatchBaselineRequest,
     ) -> RusotoFuture<UpdatePatchBaselineResult, UpdatePatchBaselineError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdatePatchBaseline");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePatchBaselineResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdatePatchBaselineError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_patch_baseline {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_patch_baseline"),
+        }
     }
-
-    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Or, use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Update the service setting for the account. </p>
     fn update_service_setting(
         &self,
         input: UpdateServiceSettingRequest,
     ) -> RusotoFuture<UpdateServiceSettingResult, UpdateServiceSettingError> {
-        let mut request = SignedRequest::new("POST", "ssm", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonSSM.UpdateServiceSetting");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServiceSettingResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateServiceSettingError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_service_setting {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSsm has no handler for update_service_setting"),
+        }
     }
 }
@@ -0,0 +1,386 @@
+//! Workers running the tasks of Step Functions activities.
+//!
+//! An `ActivityWorker` long-polls an activity for tasks, runs each task with a handler,
+//! sends heartbeats while the handler runs and reports its output or failure. Polls which
+//! end without a task are simply made again.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::Future;
+//! use rusoto_core::Region;
+//! use rusoto_stepfunctions::activity::{ActivityWorker, TaskError};
+//! use rusoto_stepfunctions::StepFunctionsClient;
+//!
+//! let client = StepFunctionsClient::new(Region::UsEast1);
+//! let worker = ActivityWorker::new(client, "arn:aws:states:us-east-1:123456789012:activity:resize")
+//!     .with_max_concurrent_tasks(4);
+//! let shutdown = worker.shutdown_handle();
+//!
+//! let work = worker.run(|input: String| {
+//!     if input.is_empty() {
+//!         Err(TaskError::new("EmptyInput", "the task has no input"))
+//!     } else {
+//!         Ok(input)
+//!     }
+//! });
+//! // `shutdown.shutdown()` stops polling, and `work` resolves once the running tasks are done
+//! tokio::run(work.map_err(|err| eprintln!("worker failed: {}", err)));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::future::{self, Either, Loop};
+use futures::{stream, Future, IntoFuture, Stream};
+use rusoto_core::cancellation::{CancellationToken, Cancelled};
+use rusoto_core::RusotoError;
+use tokio_timer::Delay;
+
+use crate::generated::{
+    GetActivityTaskError, GetActivityTaskInput, SendTaskFailureError, SendTaskFailureInput,
+    SendTaskHeartbeatInput, SendTaskSuccessError, SendTaskSuccessInput, StepFunctions,
+};
+
+/// The longest error name Step Functions accepts when a task fails.
+pub const MAX_ERROR_LENGTH: usize = 256;
+
+/// The longest failure cause Step Functions accepts when a task fails.
+pub const MAX_CAUSE_LENGTH: usize = 32_768;
+
+/// The failure of a task, reported to Step Functions with `send_task_failure`.
+///
+/// Error names and causes longer than Step Functions accepts are truncated when reported.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskError {
+    /// The name of the error, which the state machine can catch or retry on.
+    pub error: String,
+    /// A description of the failure.
+    pub cause: String,
+}
+
+impl TaskError {
+    /// A failure with the given error name and cause.
+    pub fn new<E: Into<String>, C: Into<String>>(error: E, cause: C) -> Self {
+        TaskError {
+            error: error.into(),
+            cause: cause.into(),
+        }
+    }
+}
+
+/// Errors which stop an `ActivityWorker`.
+///
+/// A failure to report a task which timed out or no longer exists doesn't stop the worker,
+/// as there is nothing left to report it to. Give the client a `RetryPolicy` to make the
+/// worker ride out transient failures of the service.
+#[derive(Debug)]
+pub enum WorkerError {
+    /// Polling the activity for a task failed.
+    GetActivityTask(RusotoError<GetActivityTaskError>),
+    /// Reporting the output of a task failed.
+    SendTaskSuccess(RusotoError<SendTaskSuccessError>),
+    /// Reporting the failure of a task failed.
+    SendTaskFailure(RusotoError<SendTaskFailureError>),
+    /// The cancellation token of the worker was cancelled.
+    Cancelled,
+}
+
+impl From<Cancelled> for WorkerError {
+    fn from(_: Cancelled) -> Self {
+        WorkerError::Cancelled
+    }
+}
+
+impl fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WorkerError::GetActivityTask(ref err) => write!(f, "failed to poll for tasks: {}", err),
+            WorkerError::SendTaskSuccess(ref err) => {
+                write!(f, "failed to report task output: {}", err)
+            }
+            WorkerError::SendTaskFailure(ref err) => {
+                write!(f, "failed to report task failure: {}", err)
+            }
+            WorkerError::Cancelled => write!(f, "worker was cancelled"),
+        }
+    }
+}
+
+impl Error for WorkerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            WorkerError::GetActivityTask(ref err) => Some(err),
+            WorkerError::SendTaskSuccess(ref err) => Some(err),
+            WorkerError::SendTaskFailure(ref err) => Some(err),
+            WorkerError::Cancelled => None,
+        }
+    }
+}
+
+/// Stops an `ActivityWorker` gracefully.
+#[derive(Clone, Debug, Default)]
+pub struct ShutdownHandle {
+    shutdown: Arc<AtomicBool>,
+}
+
+impl ShutdownHandle {
+    /// Stops the worker from polling for new tasks.
+    ///
+    /// Polls already made are allowed to finish, as the service may have handed them a task,
+    /// and tasks they get are run and reported. The future returned by `ActivityWorker::run`
+    /// resolves once every running task is done.
+    pub fn shutdown(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the worker was asked to stop.
+    pub fn is_shutdown(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+}
+
+/// Polls an activity for tasks and runs them with a handler.
+pub struct ActivityWorker<C> {
+    client: C,
+    activity_arn: String,
+    worker_name: Option<String>,
+    max_concurrent_tasks: usize,
+    heartbeat_interval: Duration,
+    shutdown: ShutdownHandle,
+    cancellation: CancellationToken,
+}
+
+impl<C> ActivityWorker<C>
+where
+    C: StepFunctions + Clone + Send + Sync + 'static,
+{
+    /// A worker for the activity with the given ARN, running one task at a time and sending
+    /// a heartbeat every 30 seconds.
+    pub fn new<A: Into<String>>(client: C, activity_arn: A) -> Self {
+        ActivityWorker {
+            client,
+            activity_arn: activity_arn.into(),
+            worker_name: None,
+            max_concurrent_tasks: 1,
+            heartbeat_interval: Duration::from_secs(30),
+            shutdown: ShutdownHandle::default(),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Sets the name the worker polls with, which shows in the execution history.
+    pub fn with_worker_name<N: Into<String>>(mut self, worker_name: N) -> Self {
+        self.worker_name = Some(worker_name.into());
+        self
+    }
+
+    /// Sets the most tasks run at once. The worker keeps this many polls open while idle.
+    pub fn with_max_concurrent_tasks(mut self, max_concurrent_tasks: usize) -> Self {
+        self.max_concurrent_tasks = max_concurrent_tasks.max(1);
+        self
+    }
+
+    /// Sets the time between heartbeats sent while a task runs. It should be well under the
+    /// `HeartbeatSeconds` of the task states using the activity.
+    pub fn with_heartbeat_interval(mut self, heartbeat_interval: Duration) -> Self {
+        self.heartbeat_interval = heartbeat_interval;
+        self
+    }
+
+    /// Stops the worker abruptly when `token` is cancelled, unlike a shutdown.
+    ///
+    /// Open polls are abandoned, and so are running tasks: their handlers are dropped,
+    /// their heartbeats stop and nothing is reported, so Step Functions times them out.
+    /// The future returned by `run` then fails with `WorkerError::Cancelled`.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// A handle to stop the worker once it runs.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// Polls for tasks and runs them with `handler` until the worker is shut down or fails.
+    ///
+    /// The handler gets the input of a task and resolves to its output. A task whose
+    /// heartbeat is refused because it timed out or no longer exists is abandoned: its
+    /// handler is dropped and nothing is reported. When polling or reporting fails, the
+    /// worker stops polling, lets the running tasks finish and resolves to the error.
+    pub fn run<H, F>(self, handler: H) -> Box<dyn Future<Item = (), Error = WorkerError> + Send>
+    where
+        H: Fn(String) -> F + Send + Sync + 'static,
+        F: IntoFuture<Item = String, Error = TaskError>,
+        F::Future: Send + 'static,
+    {
+        let ActivityWorker {
+            client,
+            activity_arn,
+            worker_name,
+            max_concurrent_tasks,
+            heartbeat_interval,
+            shutdown,
+            cancellation,
+        } = self;
+        let handler = Arc::new(handler);
+        let failure = Arc::new(Mutex::new(None));
+
+        let polling = shutdown.clone();
+        let cancelled = cancellation.clone();
+        let failed = failure.clone();
+        let work = stream::repeat::<(), ()>(())
+            .take_while(move |_| Ok(!polling.is_shutdown() && !cancelled.is_cancelled()))
+            .map(move |_| {
+                let poll = GetActivityTaskInput {
+                    activity_arn: activity_arn.clone(),
+                    worker_name: worker_name.clone(),
+                };
+                let client = client.clone();
+                let handler = handler.clone();
+                let shutdown = shutdown.clone();
+                let failed = failed.clone();
+                let task = client
+                    .get_activity_task(poll)
+                    .map_err(WorkerError::GetActivityTask)
+                    .and_then(move |task| match task.task_token {
+                        Some(ref token) if !token.is_empty() => Either::A(run_task(
+                            client,
+                            token.to_owned(),
+                            task.input.unwrap_or_default(),
+                            &*handler,
+                            heartbeat_interval,
+                        )),
+                        // the poll ended without a task
+                        _ => Either::B(future::ok(())),
+                    });
+                cancellation.guard(task).then(move |result| {
+                    if let Err(err) = result {
+                        shutdown.shutdown();
+                        failed.lock().unwrap().get_or_insert(err);
+                    }
+                    Ok(())
+                })
+            })
+            .buffer_unordered(max_concurrent_tasks)
+            .for_each(|_| Ok(()))
+            .then(move |_| match failure.lock().unwrap().take() {
+                Some(err) => Err(err),
+                None => Ok(()),
+            });
+        Box::new(work)
+    }
+}
+
+/// Runs a task, sending heartbeats until its handler is done, and reports the outcome.
+fn run_task<C, H, F>(
+    client: C,
+    token: String,
+    input: String,
+    handler: &H,
+    heartbeat_interval: Duration,
+) -> impl Future<Item = (), Error = WorkerError>
+where
+    C: StepFunctions + Clone + Send + 'static,
+    H: Fn(String) -> F,
+    F: IntoFuture<Item = String, Error = TaskError>,
+    F::Future: Send + 'static,
+{
+    let work = handler(input).into_future();
+    let heartbeats = send_heartbeats(client.clone(), token.clone(), heartbeat_interval);
+    work.select2(heartbeats).then(move |outcome| match outcome {
+        Ok(Either::A((output, _))) => Either::A(report_success(client, token, output)),
+        Err(Either::A((error, _))) => Either::B(Either::A(report_failure(&client, token, error))),
+        // heartbeats can't be sent without a timer, but the task can still complete
+        Err(Either::B((_, work))) => {
+            Either::B(Either::B(Either::A(work.then(
+                move |result| match result {
+                    Ok(output) => Either::A(report_success(client, token, output)),
+                    Err(error) => Either::B(report_failure(&client, token, error)),
+                },
+            ))))
+        }
+        // the task timed out or no longer exists
+        Ok(Either::B(_)) => Either::B(Either::B(Either::B(future::ok(())))),
+    })
+}
+
+/// Sends heartbeats for a task until one is refused, or fails if the timer is unavailable.
+fn send_heartbeats<C>(
+    client: C,
+    token: String,
+    interval: Duration,
+) -> impl Future<Item = (), Error = ()>
+where
+    C: StepFunctions + Clone + Send + 'static,
+{
+    future::loop_fn((), move |()| {
+        let client = client.clone();
+        let heartbeat = SendTaskHeartbeatInput {
+            task_token: token.clone(),
+        };
+        Delay::new(Instant::now() + interval)
+            .map_err(|_| ())
+            .and_then(move |()| {
+                client
+                    .send_task_heartbeat(heartbeat)
+                    .then(|result| match result {
+                        Ok(_) => Ok(Loop::Continue(())),
+                        Err(RusotoError::Service(_)) => Ok(Loop::Break(())),
+                        // keep going, the next heartbeat may get through in time
+                        Err(_) => Ok(Loop::Continue(())),
+                    })
+            })
+    })
+}
+
+fn report_success<C: StepFunctions>(
+    client: C,
+    token: String,
+    output: String,
+) -> impl Future<Item = (), Error = WorkerError> {
+    let success = SendTaskSuccessInput {
+        output,
+        task_token: token.clone(),
+    };
+    client
+        .send_task_success(success)
+        .then(move |result| match result {
+            Ok(_) => Either::A(future::ok(())),
+            Err(RusotoError::Service(SendTaskSuccessError::InvalidOutput(message))) => Either::B(
+                report_failure(&client, token, TaskError::new("InvalidOutput", message)),
+            ),
+            Err(RusotoError::Service(_)) => Either::A(future::ok(())),
+            Err(err) => Either::A(future::err(WorkerError::SendTaskSuccess(err))),
+        })
+}
+
+fn report_failure<C: StepFunctions>(
+    client: &C,
+    token: String,
+    error: TaskError,
+) -> impl Future<Item = (), Error = WorkerError> {
+    let failure = SendTaskFailureInput {
+        cause: Some(truncate(error.cause, MAX_CAUSE_LENGTH)),
+        error: Some(truncate(error.error, MAX_ERROR_LENGTH)),
+        task_token: token,
+    };
+    client
+        .send_task_failure(failure)
+        .then(|result| match result {
+            Ok(_) | Err(RusotoError::Service(_)) => Ok(()),
+            Err(err) => Err(WorkerError::SendTaskFailure(err)),
+        })
+}
+
+/// Truncates `value` to at most `max` characters.
+fn truncate(mut value: String, max: usize) -> String {
+    if let Some((index, _)) = value.char_indices().nth(max) {
+        value.truncate(index);
+    }
+    value
+}
@@ -0,0 +1,245 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::future;
+use rusoto_core::cancellation::CancellationToken;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{DispatchSignedRequest, Region};
+
+use self::rusoto_mock::*;
+use crate::activity::{ActivityWorker, TaskError, MAX_CAUSE_LENGTH, MAX_ERROR_LENGTH};
+use crate::generated::StepFunctionsClient;
+
+const ACTIVITY_ARN: &str = "arn:aws:states:us-east-1:123456789012:activity:test";
+
+/// The target and payload of each request sent.
+type Requests = Arc<Mutex<Vec<(String, String)>>>;
+
+/// Hands out `tasks` to the first polls, then answers polls without a task, and answers
+/// heartbeats with `heartbeat`.
+struct ActivityDispatcher {
+    tasks: Mutex<Vec<&'static str>>,
+    heartbeat: MockRequestDispatcher,
+    requests: Requests,
+}
+
+impl ActivityDispatcher {
+    fn new(tasks: &[&'static str], heartbeat: MockRequestDispatcher) -> (Self, Requests) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = ActivityDispatcher {
+            tasks: Mutex::new(tasks.iter().rev().cloned().collect()),
+            heartbeat,
+            requests: requests.clone(),
+        };
+        (dispatcher, requests)
+    }
+}
+
+impl DispatchSignedRequest for ActivityDispatcher {
+    type Future = <MockRequestDispatcher as DispatchSignedRequest>::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let target = request
+            .headers()
+            .get("x-amz-target")
+            .map(|values| String::from_utf8_lossy(&values[0]).into_owned())
+            .unwrap_or_default();
+        let payload = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                String::from_utf8_lossy(buffer).into_owned()
+            }
+            _ => String::new(),
+        };
+        self.requests
+            .lock()
+            .unwrap()
+            .push((target.clone(), payload));
+
+        match target.as_str() {
+            "AWSStepFunctions.GetActivityTask" => {
+                let task = self.tasks.lock().unwrap().pop().unwrap_or("{}");
+                MockRequestDispatcher::with_status(200)
+                    .with_body(task)
+                    .dispatch(request, timeout)
+            }
+            "AWSStepFunctions.SendTaskHeartbeat" => self.heartbeat.dispatch(request, timeout),
+            _ => MockRequestDispatcher::with_status(200)
+                .with_body("{}")
+                .dispatch(request, timeout),
+        }
+    }
+}
+
+fn targets(requests: &[(String, String)]) -> Vec<&str> {
+    requests
+        .iter()
+        .map(|(target, _)| target.trim_start_matches("AWSStepFunctions."))
+        .collect()
+}
+
+#[test]
+fn test_activity_worker_reports_task_output() {
+    let (dispatcher, requests) = ActivityDispatcher::new(
+        &[r#"{"taskToken":"token-1","input":"{\"n\":1}"}"#],
+        MockRequestDispatcher::with_status(200).with_body("{}"),
+    );
+    let client =
+        StepFunctionsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    let worker = ActivityWorker::new(client, ACTIVITY_ARN).with_worker_name("worker-1");
+    let shutdown = worker.shutdown_handle();
+
+    let work = worker.run(move |input: String| {
+        shutdown.shutdown();
+        Ok(format!("{{\"input\":{}}}", input))
+    });
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(work)
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        targets(&requests),
+        vec!["GetActivityTask", "SendTaskSuccess"]
+    );
+    assert_eq!(
+        requests[0].1,
+        format!(
+            r#"{{"activityArn":"{}","workerName":"worker-1"}}"#,
+            ACTIVITY_ARN
+        )
+    );
+    assert_eq!(
+        requests[1].1,
+        r#"{"output":"{\"input\":{\"n\":1}}","taskToken":"token-1"}"#
+    );
+}
+
+#[test]
+fn test_activity_worker_reports_truncated_task_failure() {
+    let (dispatcher, requests) = ActivityDispatcher::new(
+        &["{}", r#"{"taskToken":"token-1","input":"{}"}"#],
+        MockRequestDispatcher::with_status(200).with_body("{}"),
+    );
+    let client =
+        StepFunctionsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    let worker = ActivityWorker::new(client, ACTIVITY_ARN);
+    let shutdown = worker.shutdown_handle();
+
+    let work = worker.run(move |_input: String| {
+        shutdown.shutdown();
+        Err::<String, _>(TaskError::new("E".repeat(300), "é".repeat(40_000)))
+    });
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(work)
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    // the first poll ended without a task
+    assert_eq!(
+        targets(&requests),
+        vec!["GetActivityTask", "GetActivityTask", "SendTaskFailure"]
+    );
+    let failure: serde_json::Value = serde_json::from_str(&requests[2].1).unwrap();
+    assert_eq!(failure["taskToken"], "token-1");
+    assert_eq!(
+        failure["error"].as_str().unwrap(),
+        "E".repeat(MAX_ERROR_LENGTH)
+    );
+    assert_eq!(
+        failure["cause"].as_str().unwrap(),
+        "é".repeat(MAX_CAUSE_LENGTH)
+    );
+}
+
+#[test]
+fn test_activity_worker_abandons_timed_out_task() {
+    let (dispatcher, requests) = ActivityDispatcher::new(
+        &[r#"{"taskToken":"token-1","input":"{}"}"#],
+        MockRequestDispatcher::with_status(400)
+            .with_body(r#"{"__type":"TaskTimedOut","message":"Task Timed Out"}"#),
+    );
+    let client =
+        StepFunctionsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    let worker = ActivityWorker::new(client, ACTIVITY_ARN)
+        .with_heartbeat_interval(Duration::from_millis(10));
+    let shutdown = worker.shutdown_handle();
+
+    // the handler never completes, so the worker only finishes if it gives up on the task
+    let work = worker.run(move |_input: String| {
+        shutdown.shutdown();
+        future::empty::<String, TaskError>()
+    });
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(work)
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        targets(&requests),
+        vec!["GetActivityTask", "SendTaskHeartbeat"]
+    );
+    assert_eq!(requests[1].1, r#"{"taskToken":"token-1"}"#);
+}
+
+#[test]
+fn test_activity_worker_stops_when_polling_fails() {
+    let client = StepFunctionsClient::new_with(
+        MockRequestDispatcher::with_status(400)
+            .with_body(r#"{"__type":"ActivityDoesNotExist","message":"no such activity"}"#),
+        MockCredentialsProvider,
+        Region::UsEast1,
+    );
+    let work = ActivityWorker::new(client, ACTIVITY_ARN).run(|input: String| Ok(input));
+
+    let err = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(work)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to poll for tasks: no such activity"
+    );
+}
+
+#[test]
+fn test_activity_worker_stops_heartbeats_when_cancelled() {
+    let (dispatcher, requests) = ActivityDispatcher::new(
+        &[r#"{"taskToken":"token-1","input":"{}"}"#],
+        MockRequestDispatcher::with_status(200).with_body("{}"),
+    );
+    let client =
+        StepFunctionsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    let token = CancellationToken::new();
+    let worker = ActivityWorker::new(client, ACTIVITY_ARN)
+        .with_heartbeat_interval(Duration::from_millis(10))
+        .with_cancellation(token.clone());
+
+    // the handler never completes, so the worker only finishes once cancelled
+    let work = worker.run(move |_input: String| {
+        let token = token.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        });
+        future::empty::<String, TaskError>()
+    });
+    let err = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(work)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "worker was cancelled");
+
+    let sent = requests.lock().unwrap().len();
+    std::thread::sleep(Duration::from_millis(50));
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), sent);
+    assert!(targets(&requests).contains(&"SendTaskHeartbeat"));
+    assert!(!targets(&requests)
+        .iter()
+        .any(|target| target.starts_with("SendTask") && *target != "SendTaskHeartbeat"));
+}
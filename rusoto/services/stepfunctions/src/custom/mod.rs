@@ -1 +1,5 @@
+/// Workers running the tasks of Step Functions activities
+pub mod activity;
 
+#[cfg(test)]
+mod custom_tests;
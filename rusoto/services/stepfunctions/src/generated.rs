@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Contains details about an activity that failed during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -39,7 +40,7 @@ pub struct ActivityFailedEventDetails {
 
 /// <p>Contains details about an activity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityListItem {
     /// <p>The Amazon Resource Name (ARN) that identifies the activity.</p>
     #[serde(rename = "activityArn")]
@@ -54,7 +55,7 @@ pub struct ActivityListItem {
 
 /// <p>Contains details about an activity schedule failure that occurred during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityScheduleFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -68,7 +69,7 @@ pub struct ActivityScheduleFailedEventDetails {
 
 /// <p>Contains details about an activity scheduled during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityScheduledEventDetails {
     /// <p>The maximum allowed duration between two heartbeats for the activity task.</p>
     #[serde(rename = "heartbeatInSeconds")]
@@ -89,7 +90,7 @@ pub struct ActivityScheduledEventDetails {
 
 /// <p>Contains details about the start of an activity during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityStartedEventDetails {
     /// <p>The name of the worker that the task is assigned to. These names are provided by the workers when calling <a>GetActivityTask</a>.</p>
     #[serde(rename = "workerName")]
@@ -99,7 +100,7 @@ pub struct ActivityStartedEventDetails {
 
 /// <p>Contains details about an activity that successfully terminated during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivitySucceededEventDetails {
     /// <p>The JSON data output by the activity task.</p>
     #[serde(rename = "output")]
@@ -109,7 +110,7 @@ pub struct ActivitySucceededEventDetails {
 
 /// <p>Contains details about an activity timeout that occurred during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTimedOutEventDetails {
     /// <p>A more detailed explanation of the cause of the timeout.</p>
     #[serde(rename = "cause")]
@@ -122,6 +123,7 @@ pub struct ActivityTimedOutEventDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateActivityInput {
     /// <p><p>The name of the activity to create. This name must be unique for your AWS account and region for 90 days. For more information, see <a href="https://docs.aws.amazon.com/step-functions/latest/dg/limits.html#service-limits-state-machine-executions"> Limits Related to State Machine Executions</a> in the <i>AWS Step Functions Developer Guide</i>.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
@@ -132,8 +134,25 @@ pub struct CreateActivityInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateActivityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(80),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateActivityOutput {
     /// <p>The Amazon Resource Name (ARN) that identifies the created activity.</p>
     #[serde(rename = "activityArn")]
@@ -144,6 +163,7 @@ pub struct CreateActivityOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStateMachineInput {
     /// <p>The Amazon States Language definition of the state machine. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/concepts-amazon-states-language.html">Amazon States Language</a>.</p>
     #[serde(rename = "definition")]
@@ -160,8 +180,37 @@ pub struct CreateStateMachineInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateStateMachineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "definition"),
+            self.definition.chars().count(),
+            Some(1),
+            Some(1048576),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(80),
+        );
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStateMachineOutput {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
@@ -172,36 +221,72 @@ pub struct CreateStateMachineOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteActivityInput {
     /// <p>The Amazon Resource Name (ARN) of the activity to delete.</p>
     #[serde(rename = "activityArn")]
     pub activity_arn: String,
 }
 
+impl Validate for DeleteActivityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "activity_arn"),
+            self.activity_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteActivityOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteStateMachineInput {
     /// <p>The Amazon Resource Name (ARN) of the state machine to delete.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
 }
 
+impl Validate for DeleteStateMachineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "state_machine_arn"),
+            self.state_machine_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteStateMachineOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActivityInput {
     /// <p>The Amazon Resource Name (ARN) of the activity to describe.</p>
     #[serde(rename = "activityArn")]
     pub activity_arn: String,
 }
 
+impl Validate for DescribeActivityInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "activity_arn"),
+            self.activity_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeActivityOutput {
     /// <p>The Amazon Resource Name (ARN) that identifies the activity.</p>
     #[serde(rename = "activityArn")]
@@ -215,14 +300,26 @@ pub struct DescribeActivityOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeExecutionInput {
     /// <p>The Amazon Resource Name (ARN) of the execution to describe.</p>
     #[serde(rename = "executionArn")]
     pub execution_arn: String,
 }
 
+impl Validate for DescribeExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "execution_arn"),
+            self.execution_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeExecutionOutput {
     /// <p>The Amazon Resource Name (ARN) that identifies the execution.</p>
     #[serde(rename = "executionArn")]
@@ -254,14 +351,26 @@ pub struct DescribeExecutionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStateMachineForExecutionInput {
     /// <p>The Amazon Resource Name (ARN) of the execution you want state machine information for.</p>
     #[serde(rename = "executionArn")]
     pub execution_arn: String,
 }
 
+impl Validate for DescribeStateMachineForExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "execution_arn"),
+            self.execution_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStateMachineForExecutionOutput {
     /// <p>The Amazon States Language definition of the state machine. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/concepts-amazon-states-language.html">Amazon States Language</a>.</p>
     #[serde(rename = "definition")]
@@ -281,14 +390,26 @@ pub struct DescribeStateMachineForExecutionOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStateMachineInput {
     /// <p>The Amazon Resource Name (ARN) of the state machine to describe.</p>
     #[serde(rename = "stateMachineArn")]
     pub state_machine_arn: String,
 }
 
+impl Validate for DescribeStateMachineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "state_machine_arn"),
+            self.state_machine_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStateMachineOutput {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
@@ -313,7 +434,7 @@ pub struct DescribeStateMachineOutput {
 
 /// <p>Contains details about an abort of an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionAbortedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -327,7 +448,7 @@ pub struct ExecutionAbortedEventDetails {
 
 /// <p>Contains details about an execution failure event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -341,7 +462,7 @@ pub struct ExecutionFailedEventDetails {
 
 /// <p>Contains details about an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionListItem {
     /// <p>The Amazon Resource Name (ARN) that identifies the execution.</p>
     #[serde(rename = "executionArn")]
@@ -366,7 +487,7 @@ pub struct ExecutionListItem {
 
 /// <p>Contains details about the start of the execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionStartedEventDetails {
     /// <p>The JSON data input to the execution.</p>
     #[serde(rename = "input")]
@@ -380,7 +501,7 @@ pub struct ExecutionStartedEventDetails {
 
 /// <p>Contains details about the successful termination of the execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionSucceededEventDetails {
     /// <p>The JSON data output by the execution.</p>
     #[serde(rename = "output")]
@@ -390,7 +511,7 @@ pub struct ExecutionSucceededEventDetails {
 
 /// <p>Contains details about the execution timeout that occurred during the execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExecutionTimedOutEventDetails {
     /// <p>A more detailed explanation of the cause of the timeout.</p>
     #[serde(rename = "cause")]
@@ -403,6 +524,7 @@ pub struct ExecutionTimedOutEventDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetActivityTaskInput {
     /// <p>The Amazon Resource Name (ARN) of the activity to retrieve tasks from (assigned when you create the task using <a>CreateActivity</a>.)</p>
     #[serde(rename = "activityArn")]
@@ -413,8 +535,27 @@ pub struct GetActivityTaskInput {
     pub worker_name: Option<String>,
 }
 
+impl Validate for GetActivityTaskInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "activity_arn"),
+            self.activity_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.worker_name {
+            errors.check_length(
+                &field_path(path, "worker_name"),
+                value.chars().count(),
+                Some(1),
+                Some(80),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetActivityTaskOutput {
     /// <p>The string that contains the JSON input data for the task.</p>
     #[serde(rename = "input")]
@@ -427,6 +568,7 @@ pub struct GetActivityTaskOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetExecutionHistoryInput {
     /// <p>The Amazon Resource Name (ARN) of the execution.</p>
     #[serde(rename = "executionArn")]
@@ -445,8 +587,30 @@ pub struct GetExecutionHistoryInput {
     pub reverse_order: Option<bool>,
 }
 
+impl Validate for GetExecutionHistoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "execution_arn"),
+            self.execution_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetExecutionHistoryOutput {
     /// <p>The list of events that occurred in the execution.</p>
     #[serde(rename = "events")]
@@ -459,7 +623,7 @@ pub struct GetExecutionHistoryOutput {
 
 /// <p>Contains details about the events of an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HistoryEvent {
     #[serde(rename = "activityFailedEventDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -591,7 +755,7 @@ pub struct HistoryEvent {
 
 /// <p>Contains details about a lambda function that failed during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -605,7 +769,7 @@ pub struct LambdaFunctionFailedEventDetails {
 
 /// <p>Contains details about a failed lambda function schedule event that occurred during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionScheduleFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -619,7 +783,7 @@ pub struct LambdaFunctionScheduleFailedEventDetails {
 
 /// <p>Contains details about a lambda function scheduled during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionScheduledEventDetails {
     /// <p>The JSON data input to the lambda function.</p>
     #[serde(rename = "input")]
@@ -636,7 +800,7 @@ pub struct LambdaFunctionScheduledEventDetails {
 
 /// <p>Contains details about a lambda function that failed to start during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionStartFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -650,7 +814,7 @@ pub struct LambdaFunctionStartFailedEventDetails {
 
 /// <p>Contains details about a lambda function that successfully terminated during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionSucceededEventDetails {
     /// <p>The JSON data output by the lambda function.</p>
     #[serde(rename = "output")]
@@ -660,7 +824,7 @@ pub struct LambdaFunctionSucceededEventDetails {
 
 /// <p>Contains details about a lambda function timeout that occurred during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionTimedOutEventDetails {
     /// <p>A more detailed explanation of the cause of the timeout.</p>
     #[serde(rename = "cause")]
@@ -673,6 +837,7 @@ pub struct LambdaFunctionTimedOutEventDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListActivitiesInput {
     /// <p>The maximum number of results that are returned per call. You can use <code>nextToken</code> to obtain further pages of results. The default is 100 and the maximum allowed page size is 1000. A value of 0 uses the default.</p> <p>This is only an upper limit. The actual number of results returned per call might be fewer than the specified maximum.</p>
     #[serde(rename = "maxResults")]
@@ -684,8 +849,24 @@ pub struct ListActivitiesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListActivitiesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListActivitiesOutput {
     /// <p>The list of activities.</p>
     #[serde(rename = "activities")]
@@ -697,6 +878,7 @@ pub struct ListActivitiesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListExecutionsInput {
     /// <p>The maximum number of results that are returned per call. You can use <code>nextToken</code> to obtain further pages of results. The default is 100 and the maximum allowed page size is 1000. A value of 0 uses the default.</p> <p>This is only an upper limit. The actual number of results returned per call might be fewer than the specified maximum.</p>
     #[serde(rename = "maxResults")]
@@ -715,8 +897,30 @@ pub struct ListExecutionsInput {
     pub status_filter: Option<String>,
 }
 
+impl Validate for ListExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(3096),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "state_machine_arn"),
+            self.state_machine_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListExecutionsOutput {
     /// <p>The list of matching executions.</p>
     #[serde(rename = "executions")]
@@ -728,6 +932,7 @@ pub struct ListExecutionsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListStateMachinesInput {
     /// <p>The maximum number of results that are returned per call. You can use <code>nextToken</code> to obtain further pages of results. The default is 100 and the maximum allowed page size is 1000. A value of 0 uses the default.</p> <p>This is only an upper limit. The actual number of results returned per call might be fewer than the specified maximum.</p>
     #[serde(rename = "maxResults")]
@@ -739,8 +944,24 @@ pub struct ListStateMachinesInput {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListStateMachinesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, None, Some(1000));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListStateMachinesOutput {
     /// <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p>
     #[serde(rename = "nextToken")]
@@ -751,14 +972,26 @@ pub struct ListStateMachinesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Step Functions state machine or activity.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>An array of tags associated with the resource.</p>
     #[serde(rename = "tags")]
@@ -768,7 +1001,7 @@ pub struct ListTagsForResourceOutput {
 
 /// <p>Contains details about an iteration of a Map state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MapIterationEventDetails {
     /// <p>The index of the array belonging to the Map state iteration.</p>
     #[serde(rename = "index")]
@@ -782,7 +1015,7 @@ pub struct MapIterationEventDetails {
 
 /// <p>Details about a Map state that was started.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MapStateStartedEventDetails {
     /// <p>The size of the array for Map state iterations.</p>
     #[serde(rename = "length")]
@@ -791,6 +1024,7 @@ pub struct MapStateStartedEventDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendTaskFailureInput {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -805,22 +1039,62 @@ pub struct SendTaskFailureInput {
     pub task_token: String,
 }
 
+impl Validate for SendTaskFailureInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cause {
+            errors.check_length(
+                &field_path(path, "cause"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.error {
+            errors.check_length(
+                &field_path(path, "error"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendTaskFailureOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendTaskHeartbeatInput {
     /// <p>The token that represents this task. Task tokens are generated by Step Functions when tasks are assigned to a worker, or in the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/input-output-contextobject.html">context object</a> when a workflow enters a task state. See <a>GetActivityTaskOutput$taskToken</a>.</p>
     #[serde(rename = "taskToken")]
     pub task_token: String,
 }
 
+impl Validate for SendTaskHeartbeatInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendTaskHeartbeatOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SendTaskSuccessInput {
     /// <p>The JSON output of the task.</p>
     #[serde(rename = "output")]
@@ -830,11 +1104,29 @@ pub struct SendTaskSuccessInput {
     pub task_token: String,
 }
 
+impl Validate for SendTaskSuccessInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "output"),
+            self.output.chars().count(),
+            None,
+            Some(262144),
+        );
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SendTaskSuccessOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartExecutionInput {
     /// <p><p>The string that contains the JSON input data for the execution, for example:</p> <p> <code>&quot;input&quot;: &quot;{&quot;first_name&quot; : &quot;test&quot;}&quot;</code> </p> <note> <p>If you don&#39;t include any JSON input data, you still must include the two braces, for example: <code>&quot;input&quot;: &quot;{}&quot;</code> </p> </note></p>
     #[serde(rename = "input")]
@@ -849,8 +1141,35 @@ pub struct StartExecutionInput {
     pub state_machine_arn: String,
 }
 
+impl Validate for StartExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(262144),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(80),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "state_machine_arn"),
+            self.state_machine_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartExecutionOutput {
     /// <p>The Amazon Resource Name (ARN) that identifies the execution.</p>
     #[serde(rename = "executionArn")]
@@ -862,7 +1181,7 @@ pub struct StartExecutionOutput {
 
 /// <p>Contains details about a state entered during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StateEnteredEventDetails {
     /// <p>The string that contains the JSON input data for the state.</p>
     #[serde(rename = "input")]
@@ -875,7 +1194,7 @@ pub struct StateEnteredEventDetails {
 
 /// <p>Contains details about an exit from a state during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StateExitedEventDetails {
     /// <p><p>The name of the state.</p> <p>A name must <i>not</i> contain:</p> <ul> <li> <p>white space</p> </li> <li> <p>brackets <code>&lt; &gt; { } [ ]</code> </p> </li> <li> <p>wildcard characters <code>? *</code> </p> </li> <li> <p>special characters <code>&quot; # % \ ^ | ~ ` $ &amp; , ; : /</code> </p> </li> <li> <p>control characters (<code>U+0000-001F</code>, <code>U+007F-009F</code>)</p> </li> </ul></p>
     #[serde(rename = "name")]
@@ -888,7 +1207,7 @@ pub struct StateExitedEventDetails {
 
 /// <p>Contains details about the state machine.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StateMachineListItem {
     /// <p>The date the state machine is created.</p>
     #[serde(rename = "creationDate")]
@@ -902,6 +1221,7 @@ pub struct StateMachineListItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopExecutionInput {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -916,8 +1236,35 @@ pub struct StopExecutionInput {
     pub execution_arn: String,
 }
 
+impl Validate for StopExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cause {
+            errors.check_length(
+                &field_path(path, "cause"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.error {
+            errors.check_length(
+                &field_path(path, "error"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "execution_arn"),
+            self.execution_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopExecutionOutput {
     /// <p>The date the execution is stopped.</p>
     #[serde(rename = "stopDate")]
@@ -937,7 +1284,29 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Step Functions state machine or activity.</p>
     #[serde(rename = "resourceArn")]
@@ -947,13 +1316,28 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceOutput {}
 
 /// <p>Contains details about a task failure event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -973,7 +1357,7 @@ pub struct TaskFailedEventDetails {
 
 /// <p>Contains details about a task scheduled during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskScheduledEventDetails {
     /// <p>The JSON data passed to the resource referenced in a task state.</p>
     #[serde(rename = "parameters")]
@@ -995,7 +1379,7 @@ pub struct TaskScheduledEventDetails {
 
 /// <p>Contains details about a task that failed to start during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskStartFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -1015,7 +1399,7 @@ pub struct TaskStartFailedEventDetails {
 
 /// <p>Contains details about the start of a task during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskStartedEventDetails {
     /// <p>The service name of the resource in a task state.</p>
     #[serde(rename = "resource")]
@@ -1027,7 +1411,7 @@ pub struct TaskStartedEventDetails {
 
 /// <p>Contains details about a task that failed to submit during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskSubmitFailedEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -1047,7 +1431,7 @@ pub struct TaskSubmitFailedEventDetails {
 
 /// <p>Contains details about a task submitted to a resource .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskSubmittedEventDetails {
     /// <p>The response from a resource when a task has started.</p>
     #[serde(rename = "output")]
@@ -1063,7 +1447,7 @@ pub struct TaskSubmittedEventDetails {
 
 /// <p>Contains details about the successful completion of a task state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskSucceededEventDetails {
     /// <p>The full JSON response from a resource when a task has succeeded. This response becomes the output of the related task.</p>
     #[serde(rename = "output")]
@@ -1079,7 +1463,7 @@ pub struct TaskSucceededEventDetails {
 
 /// <p>Contains details about a resource timeout that occurred during an execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TaskTimedOutEventDetails {
     /// <p>A more detailed explanation of the cause of the failure.</p>
     #[serde(rename = "cause")]
@@ -1098,6 +1482,7 @@ pub struct TaskTimedOutEventDetails {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Step Functions state machine or activity.</p>
     #[serde(rename = "resourceArn")]
@@ -1107,11 +1492,27 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceOutput {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateStateMachineInput {
     /// <p>The Amazon States Language definition of the state machine. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/concepts-amazon-states-language.html">Amazon States Language</a>.</p>
     #[serde(rename = "definition")]
@@ -1126,8 +1527,35 @@ pub struct UpdateStateMachineInput {
     pub state_machine_arn: String,
 }
 
+impl Validate for UpdateStateMachineInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.definition {
+            errors.check_length(
+                &field_path(path, "definition"),
+                value.chars().count(),
+                Some(1),
+                Some(1048576),
+            );
+        }
+        if let Some(ref value) = self.role_arn {
+            errors.check_length(
+                &field_path(path, "role_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "state_machine_arn"),
+            self.state_machine_arn.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateStateMachineOutput {
     /// <p>The date and time the state machine was updated.</p>
     #[serde(rename = "updateDate")]
@@ -1160,7 +1588,12 @@ impl CreateActivityError {
                 "TooManyTags" => {
                     return RusotoError::Service(CreateActivityError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1233,7 +1666,12 @@ impl CreateStateMachineError {
                 "TooManyTags" => {
                     return RusotoError::Service(CreateStateMachineError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1272,7 +1710,12 @@ impl DeleteActivityError {
                 "InvalidArn" => {
                     return RusotoError::Service(DeleteActivityError::InvalidArn(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1305,7 +1748,12 @@ impl DeleteStateMachineError {
                 "InvalidArn" => {
                     return RusotoError::Service(DeleteStateMachineError::InvalidArn(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1345,7 +1793,12 @@ impl DescribeActivityError {
                 "InvalidArn" => {
                     return RusotoError::Service(DescribeActivityError::InvalidArn(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1386,7 +1839,12 @@ impl DescribeExecutionError {
                 "InvalidArn" => {
                     return RusotoError::Service(DescribeExecutionError::InvalidArn(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1427,7 +1885,12 @@ impl DescribeStateMachineError {
                         DescribeStateMachineError::StateMachineDoesNotExist(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1472,7 +1935,12 @@ impl DescribeStateMachineForExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1520,7 +1988,12 @@ impl GetActivityTaskError {
                 "InvalidArn" => {
                     return RusotoError::Service(GetActivityTaskError::InvalidArn(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1567,7 +2040,12 @@ impl GetExecutionHistoryError {
                 "InvalidToken" => {
                     return RusotoError::Service(GetExecutionHistoryError::InvalidToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1602,7 +2080,12 @@ impl ListActivitiesError {
                 "InvalidToken" => {
                     return RusotoError::Service(ListActivitiesError::InvalidToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1647,7 +2130,12 @@ impl ListExecutionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1682,7 +2170,12 @@ impl ListStateMachinesError {
                 "InvalidToken" => {
                     return RusotoError::Service(ListStateMachinesError::InvalidToken(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1722,7 +2215,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1766,7 +2264,12 @@ impl SendTaskFailureError {
                 "TaskTimedOut" => {
                     return RusotoError::Service(SendTaskFailureError::TaskTimedOut(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1811,7 +2314,12 @@ impl SendTaskHeartbeatError {
                 "TaskTimedOut" => {
                     return RusotoError::Service(SendTaskHeartbeatError::TaskTimedOut(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1861,7 +2369,12 @@ impl SendTaskSuccessError {
                 "TaskTimedOut" => {
                     return RusotoError::Service(SendTaskSuccessError::TaskTimedOut(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1935,7 +2448,12 @@ impl StartExecutionError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1979,7 +2497,12 @@ impl StopExecutionError {
                 "InvalidArn" => {
                     return RusotoError::Service(StopExecutionError::InvalidArn(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2021,7 +2544,12 @@ impl TagResourceError {
                 "TooManyTags" => {
                     return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2061,7 +2589,12 @@ impl UntagResourceError {
                 "ResourceNotFound" => {
                     return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2123,7 +2656,12 @@ impl UpdateStateMachineError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2280,150 +2818,592 @@ pub trait StepFunctions {
         input: UpdateStateMachineInput,
     ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError>;
 }
-/// A client for the AWS SFN API.
-#[derive(Clone)]
-pub struct StepFunctionsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl StepFunctionsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> StepFunctionsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> StepFunctionsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsClient {
-        StepFunctionsClient { client, region }
-    }
-}
-
-impl StepFunctions for StepFunctionsClient {
-    /// <p><p>Creates an activity. An activity is a task that you write in any programming language and host on any machine that has access to AWS Step Functions. Activities must poll Step Functions using the <code>GetActivityTask</code> API action and respond using <code>SendTask*</code> API actions. This function lets Step Functions know the existence of your activity and returns an identifier for use in a state machine and when polling from the activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateActivity</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateActivity</code>&#39;s idempotency check is based on the activity <code>name</code>. If a following request has different <code>tags</code> values, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+const _: Option<&dyn StepFunctions> = None;
+impl<'a, T: StepFunctions + ?Sized> StepFunctions for &'a T {
     fn create_activity(
         &self,
         input: CreateActivityInput,
     ) -> RusotoFuture<CreateActivityOutput, CreateActivityError> {
-        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "AWSStepFunctions.CreateActivity");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateActivityOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateActivityError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_activity(input)
     }
-
-    /// <p><p>Creates a state machine. A state machine consists of a collection of states that can do work (<code>Task</code> states), determine to which states to transition next (<code>Choice</code> states), stop an execution with an error (<code>Fail</code> states), and so on. State machines are specified using a JSON-based, structured language.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateStateMachine</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateStateMachine</code>&#39;s idempotency check is based on the state machine <code>name</code> and <code>definition</code>. If a following request has a different <code>roleArn</code> or <code>tags</code>, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>roleArn</code> and <code>tags</code> will not be updated, even if they are different.</p> </note></p>
     fn create_state_machine(
         &self,
         input: CreateStateMachineInput,
     ) -> RusotoFuture<CreateStateMachineOutput, CreateStateMachineError> {
-        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "AWSStepFunctions.CreateStateMachine");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStateMachineOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateStateMachineError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_state_machine(input)
     }
-
-    /// <p>Deletes an activity.</p>
     fn delete_activity(
         &self,
         input: DeleteActivityInput,
     ) -> RusotoFuture<DeleteActivityOutput, DeleteActivityError> {
-        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "AWSStepFunctions.DeleteActivity");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteActivityOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteActivityError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_activity(input)
     }
-
-    /// <p><p>Deletes a state machine. This is an asynchronous operation: It sets the state machine&#39;s status to <code>DELETING</code> and begins the deletion process. Each state machine execution is deleted the next time it makes a state transition.</p> <note> <p>The state machine itself is deleted after all executions are completed or deleted.</p> </note></p>
     fn delete_state_machine(
         &self,
         input: DeleteStateMachineInput,
     ) -> RusotoFuture<DeleteStateMachineOutput, DeleteStateMachineError> {
-        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "AWSStepFunctions.DeleteStateMachine");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteStateMachineOutput, _>()
-                }))
-            } else {
+        (**self).delete_state_machine(input)
+    }
+    fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> RusotoFuture<DescribeActivityOutput, DescribeActivityError> {
+        (**self).describe_activity(input)
+    }
+    fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> RusotoFuture<DescribeExecutionOutput, DescribeExecutionError> {
+        (**self).describe_execution(input)
+    }
+    fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> RusotoFuture<DescribeStateMachineOutput, DescribeStateMachineError> {
+        (**self).describe_state_machine(input)
+    }
+    fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> RusotoFuture<DescribeStateMachineForExecutionOutput, DescribeStateMachineForExecutionError>
+    {
+        (**self).describe_state_machine_for_execution(input)
+    }
+    fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> RusotoFuture<GetActivityTaskOutput, GetActivityTaskError> {
+        (**self).get_activity_task(input)
+    }
+    fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> RusotoFuture<GetExecutionHistoryOutput, GetExecutionHistoryError> {
+        (**self).get_execution_history(input)
+    }
+    fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> RusotoFuture<ListActivitiesOutput, ListActivitiesError> {
+        (**self).list_activities(input)
+    }
+    fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> RusotoFuture<ListExecutionsOutput, ListExecutionsError> {
+        (**self).list_executions(input)
+    }
+    fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> RusotoFuture<ListStateMachinesOutput, ListStateMachinesError> {
+        (**self).list_state_machines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> RusotoFuture<SendTaskFailureOutput, SendTaskFailureError> {
+        (**self).send_task_failure(input)
+    }
+    fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> RusotoFuture<SendTaskHeartbeatOutput, SendTaskHeartbeatError> {
+        (**self).send_task_heartbeat(input)
+    }
+    fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> RusotoFuture<SendTaskSuccessOutput, SendTaskSuccessError> {
+        (**self).send_task_success(input)
+    }
+    fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> RusotoFuture<StartExecutionOutput, StartExecutionError> {
+        (**self).start_execution(input)
+    }
+    fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> RusotoFuture<StopExecutionOutput, StopExecutionError> {
+        (**self).stop_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError> {
+        (**self).update_state_machine(input)
+    }
+}
+impl<T: StepFunctions + ?Sized> StepFunctions for Box<T> {
+    fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> RusotoFuture<CreateActivityOutput, CreateActivityError> {
+        (**self).create_activity(input)
+    }
+    fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> RusotoFuture<CreateStateMachineOutput, CreateStateMachineError> {
+        (**self).create_state_machine(input)
+    }
+    fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> RusotoFuture<DeleteActivityOutput, DeleteActivityError> {
+        (**self).delete_activity(input)
+    }
+    fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> RusotoFuture<DeleteStateMachineOutput, DeleteStateMachineError> {
+        (**self).delete_state_machine(input)
+    }
+    fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> RusotoFuture<DescribeActivityOutput, DescribeActivityError> {
+        (**self).describe_activity(input)
+    }
+    fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> RusotoFuture<DescribeExecutionOutput, DescribeExecutionError> {
+        (**self).describe_execution(input)
+    }
+    fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> RusotoFuture<DescribeStateMachineOutput, DescribeStateMachineError> {
+        (**self).describe_state_machine(input)
+    }
+    fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> RusotoFuture<DescribeStateMachineForExecutionOutput, DescribeStateMachineForExecutionError>
+    {
+        (**self).describe_state_machine_for_execution(input)
+    }
+    fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> RusotoFuture<GetActivityTaskOutput, GetActivityTaskError> {
+        (**self).get_activity_task(input)
+    }
+    fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> RusotoFuture<GetExecutionHistoryOutput, GetExecutionHistoryError> {
+        (**self).get_execution_history(input)
+    }
+    fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> RusotoFuture<ListActivitiesOutput, ListActivitiesError> {
+        (**self).list_activities(input)
+    }
+    fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> RusotoFuture<ListExecutionsOutput, ListExecutionsError> {
+        (**self).list_executions(input)
+    }
+    fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> RusotoFuture<ListStateMachinesOutput, ListStateMachinesError> {
+        (**self).list_state_machines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> RusotoFuture<SendTaskFailureOutput, SendTaskFailureError> {
+        (**self).send_task_failure(input)
+    }
+    fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> RusotoFuture<SendTaskHeartbeatOutput, SendTaskHeartbeatError> {
+        (**self).send_task_heartbeat(input)
+    }
+    fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> RusotoFuture<SendTaskSuccessOutput, SendTaskSuccessError> {
+        (**self).send_task_success(input)
+    }
+    fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> RusotoFuture<StartExecutionOutput, StartExecutionError> {
+        (**self).start_execution(input)
+    }
+    fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> RusotoFuture<StopExecutionOutput, StopExecutionError> {
+        (**self).stop_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError> {
+        (**self).update_state_machine(input)
+    }
+}
+impl<T: StepFunctions + ?Sized> StepFunctions for ::std::sync::Arc<T> {
+    fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> RusotoFuture<CreateActivityOutput, CreateActivityError> {
+        (**self).create_activity(input)
+    }
+    fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> RusotoFuture<CreateStateMachineOutput, CreateStateMachineError> {
+        (**self).create_state_machine(input)
+    }
+    fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> RusotoFuture<DeleteActivityOutput, DeleteActivityError> {
+        (**self).delete_activity(input)
+    }
+    fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> RusotoFuture<DeleteStateMachineOutput, DeleteStateMachineError> {
+        (**self).delete_state_machine(input)
+    }
+    fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> RusotoFuture<DescribeActivityOutput, DescribeActivityError> {
+        (**self).describe_activity(input)
+    }
+    fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> RusotoFuture<DescribeExecutionOutput, DescribeExecutionError> {
+        (**self).describe_execution(input)
+    }
+    fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> RusotoFuture<DescribeStateMachineOutput, DescribeStateMachineError> {
+        (**self).describe_state_machine(input)
+    }
+    fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> RusotoFuture<DescribeStateMachineForExecutionOutput, DescribeStateMachineForExecutionError>
+    {
+        (**self).describe_state_machine_for_execution(input)
+    }
+    fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> RusotoFuture<GetActivityTaskOutput, GetActivityTaskError> {
+        (**self).get_activity_task(input)
+    }
+    fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> RusotoFuture<GetExecutionHistoryOutput, GetExecutionHistoryError> {
+        (**self).get_execution_history(input)
+    }
+    fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> RusotoFuture<ListActivitiesOutput, ListActivitiesError> {
+        (**self).list_activities(input)
+    }
+    fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> RusotoFuture<ListExecutionsOutput, ListExecutionsError> {
+        (**self).list_executions(input)
+    }
+    fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> RusotoFuture<ListStateMachinesOutput, ListStateMachinesError> {
+        (**self).list_state_machines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> RusotoFuture<SendTaskFailureOutput, SendTaskFailureError> {
+        (**self).send_task_failure(input)
+    }
+    fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> RusotoFuture<SendTaskHeartbeatOutput, SendTaskHeartbeatError> {
+        (**self).send_task_heartbeat(input)
+    }
+    fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> RusotoFuture<SendTaskSuccessOutput, SendTaskSuccessError> {
+        (**self).send_task_success(input)
+    }
+    fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> RusotoFuture<StartExecutionOutput, StartExecutionError> {
+        (**self).start_execution(input)
+    }
+    fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> RusotoFuture<StopExecutionOutput, StopExecutionError> {
+        (**self).stop_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError> {
+        (**self).update_state_machine(input)
+    }
+}
+/// A client for the AWS SFN API.
+#[derive(Clone)]
+pub struct StepFunctionsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl StepFunctionsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> StepFunctionsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StepFunctionsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsClient {
+        StepFunctionsClient { client, region }
+    }
+}
+
+impl StepFunctions for StepFunctionsClient {
+    /// <p><p>Creates an activity. An activity is a task that you write in any programming language and host on any machine that has access to AWS Step Functions. Activities must poll Step Functions using the <code>GetActivityTask</code> API action and respond using <code>SendTask*</code> API actions. This function lets Step Functions know the existence of your activity and returns an identifier for use in a state machine and when polling from the activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateActivity</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateActivity</code>&#39;s idempotency check is based on the activity <code>name</code>. If a following request has different <code>tags</code> values, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+    fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> RusotoFuture<CreateActivityOutput, CreateActivityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("CreateActivity");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "AWSStepFunctions.CreateActivity");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateActivityOutput, _>(
+                    response,
+                    "CreateActivity",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateActivityError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a state machine. A state machine consists of a collection of states that can do work (<code>Task</code> states), determine to which states to transition next (<code>Choice</code> states), stop an execution with an error (<code>Fail</code> states), and so on. State machines are specified using a JSON-based, structured language.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateStateMachine</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateStateMachine</code>&#39;s idempotency check is based on the state machine <code>name</code> and <code>definition</code>. If a following request has a different <code>roleArn</code> or <code>tags</code>, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>roleArn</code> and <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+    fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> RusotoFuture<CreateStateMachineOutput, CreateStateMachineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("CreateStateMachine");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "AWSStepFunctions.CreateStateMachine");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateStateMachineOutput, _>(
+                    response,
+                    "CreateStateMachine",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateStateMachineError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes an activity.</p>
+    fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> RusotoFuture<DeleteActivityOutput, DeleteActivityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DeleteActivity");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "AWSStepFunctions.DeleteActivity");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteActivityOutput, _>(
+                    response,
+                    "DeleteActivity",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteActivityError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a state machine. This is an asynchronous operation: It sets the state machine&#39;s status to <code>DELETING</code> and begins the deletion process. Each state machine execution is deleted the next time it makes a state transition.</p> <note> <p>The state machine itself is deleted after all executions are completed or deleted.</p> </note></p>
+    fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> RusotoFuture<DeleteStateMachineOutput, DeleteStateMachineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DeleteStateMachine");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "AWSStepFunctions.DeleteStateMachine");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteStateMachineOutput, _>(
+                    response,
+                    "DeleteStateMachine",
+                )
+            } else {
                 Box::new(
                     response
                         .buffer()
@@ -2439,7 +3419,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: DescribeActivityInput,
     ) -> RusotoFuture<DescribeActivityOutput, DescribeActivityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeActivity");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeActivity");
@@ -2448,10 +3437,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeActivityOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeActivityOutput, _>(
+                    response,
+                    "DescribeActivity",
+                )
             } else {
                 Box::new(
                     response
@@ -2468,7 +3457,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: DescribeExecutionInput,
     ) -> RusotoFuture<DescribeExecutionOutput, DescribeExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeExecution");
@@ -2477,10 +3475,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeExecutionOutput, _>(
+                    response,
+                    "DescribeExecution",
+                )
             } else {
                 Box::new(
                     response
@@ -2497,7 +3495,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: DescribeStateMachineInput,
     ) -> RusotoFuture<DescribeStateMachineOutput, DescribeStateMachineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeStateMachine");
@@ -2506,10 +3513,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStateMachineOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeStateMachineOutput, _>(
+                    response,
+                    "DescribeStateMachine",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2526,7 +3533,16 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeStateMachineForExecutionInput,
     ) -> RusotoFuture<DescribeStateMachineForExecutionOutput, DescribeStateMachineForExecutionError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("DescribeStateMachineForExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -2538,10 +3554,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStateMachineForExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeStateMachineForExecutionOutput, _>(
+                    response,
+                    "DescribeStateMachineForExecution",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeStateMachineForExecutionError::from_response(
@@ -2557,7 +3573,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: GetActivityTaskInput,
     ) -> RusotoFuture<GetActivityTaskOutput, GetActivityTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("GetActivityTask");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.GetActivityTask");
@@ -2566,10 +3591,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetActivityTaskOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetActivityTaskOutput, _>(
+                    response,
+                    "GetActivityTask",
+                )
             } else {
                 Box::new(
                     response
@@ -2586,7 +3611,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: GetExecutionHistoryInput,
     ) -> RusotoFuture<GetExecutionHistoryOutput, GetExecutionHistoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("GetExecutionHistory");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.GetExecutionHistory");
@@ -2595,10 +3629,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetExecutionHistoryOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<GetExecutionHistoryOutput, _>(
+                    response,
+                    "GetExecutionHistory",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2614,7 +3648,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: ListActivitiesInput,
     ) -> RusotoFuture<ListActivitiesOutput, ListActivitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListActivities");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListActivities");
@@ -2623,10 +3666,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListActivitiesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListActivitiesOutput, _>(
+                    response,
+                    "ListActivities",
+                )
             } else {
                 Box::new(
                     response
@@ -2643,7 +3686,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: ListExecutionsInput,
     ) -> RusotoFuture<ListExecutionsOutput, ListExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListExecutions");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListExecutions");
@@ -2652,10 +3704,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListExecutionsOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListExecutionsOutput, _>(
+                    response,
+                    "ListExecutions",
+                )
             } else {
                 Box::new(
                     response
@@ -2672,7 +3724,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: ListStateMachinesInput,
     ) -> RusotoFuture<ListStateMachinesOutput, ListStateMachinesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListStateMachines");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListStateMachines");
@@ -2681,10 +3742,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListStateMachinesOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListStateMachinesOutput, _>(
+                    response,
+                    "ListStateMachines",
+                )
             } else {
                 Box::new(
                     response
@@ -2701,7 +3762,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.ListTagsForResource");
@@ -2710,10 +3780,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2729,7 +3799,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: SendTaskFailureInput,
     ) -> RusotoFuture<SendTaskFailureOutput, SendTaskFailureError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskFailure");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskFailure");
@@ -2738,10 +3817,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendTaskFailureOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<SendTaskFailureOutput, _>(
+                    response,
+                    "SendTaskFailure",
+                )
             } else {
                 Box::new(
                     response
@@ -2758,7 +3837,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: SendTaskHeartbeatInput,
     ) -> RusotoFuture<SendTaskHeartbeatOutput, SendTaskHeartbeatError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskHeartbeat");
@@ -2767,10 +3855,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendTaskHeartbeatOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<SendTaskHeartbeatOutput, _>(
+                    response,
+                    "SendTaskHeartbeat",
+                )
             } else {
                 Box::new(
                     response
@@ -2787,7 +3875,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: SendTaskSuccessInput,
     ) -> RusotoFuture<SendTaskSuccessOutput, SendTaskSuccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("SendTaskSuccess");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskSuccess");
@@ -2796,10 +3893,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SendTaskSuccessOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<SendTaskSuccessOutput, _>(
+                    response,
+                    "SendTaskSuccess",
+                )
             } else {
                 Box::new(
                     response
@@ -2816,7 +3913,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: StartExecutionInput,
     ) -> RusotoFuture<StartExecutionOutput, StartExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("StartExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.StartExecution");
@@ -2825,10 +3931,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StartExecutionOutput, _>(
+                    response,
+                    "StartExecution",
+                )
             } else {
                 Box::new(
                     response
@@ -2845,7 +3951,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: StopExecutionInput,
     ) -> RusotoFuture<StopExecutionOutput, StopExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("StopExecution");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.StopExecution");
@@ -2854,10 +3969,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopExecutionOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<StopExecutionOutput, _>(
+                    response,
+                    "StopExecution",
+                )
             } else {
                 Box::new(
                     response
@@ -2874,7 +3989,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: TagResourceInput,
     ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.TagResource");
@@ -2883,10 +4007,7 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<TagResourceOutput, _>(response, "TagResource")
             } else {
                 Box::new(
                     response
@@ -2903,7 +4024,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.UntagResource");
@@ -2912,10 +4042,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UntagResourceOutput, _>(
+                    response,
+                    "UntagResource",
+                )
             } else {
                 Box::new(
                     response
@@ -2932,7 +4062,16 @@ impl StepFunctions for StepFunctionsClient {
         &self,
         input: UpdateStateMachineInput,
     ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "states", &self.region, "/");
+        request.set_operation("UpdateStateMachine");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "AWSStepFunctions.UpdateStateMachine");
@@ -2941,10 +4080,10 @@ impl StepFunctions for StepFunctionsClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateStateMachineOutput, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateStateMachineOutput, _>(
+                    response,
+                    "UpdateStateMachine",
+                )
             } else {
                 Box::new(
                     response
@@ -2956,3 +4095,778 @@ impl StepFunctions for StepFunctionsClient {
         })
     }
 }
+
+/// A mock of the AWS SFN API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockStepFunctions {
+    create_activity: Option<
+        Box<
+            dyn Fn(
+                    CreateActivityInput,
+                )
+                    -> ::std::result::Result<CreateActivityOutput, RusotoError<CreateActivityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_state_machine: Option<
+        Box<
+            dyn Fn(
+                    CreateStateMachineInput,
+                ) -> ::std::result::Result<
+                    CreateStateMachineOutput,
+                    RusotoError<CreateStateMachineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_activity: Option<
+        Box<
+            dyn Fn(
+                    DeleteActivityInput,
+                )
+                    -> ::std::result::Result<DeleteActivityOutput, RusotoError<DeleteActivityError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_state_machine: Option<
+        Box<
+            dyn Fn(
+                    DeleteStateMachineInput,
+                ) -> ::std::result::Result<
+                    DeleteStateMachineOutput,
+                    RusotoError<DeleteStateMachineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_activity: Option<
+        Box<
+            dyn Fn(
+                    DescribeActivityInput,
+                ) -> ::std::result::Result<
+                    DescribeActivityOutput,
+                    RusotoError<DescribeActivityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_execution: Option<
+        Box<
+            dyn Fn(
+                    DescribeExecutionInput,
+                ) -> ::std::result::Result<
+                    DescribeExecutionOutput,
+                    RusotoError<DescribeExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_state_machine: Option<
+        Box<
+            dyn Fn(
+                    DescribeStateMachineInput,
+                ) -> ::std::result::Result<
+                    DescribeStateMachineOutput,
+                    RusotoError<DescribeStateMachineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_state_machine_for_execution: Option<
+        Box<
+            dyn Fn(
+                    DescribeStateMachineForExecutionInput,
+                ) -> ::std::result::Result<
+                    DescribeStateMachineForExecutionOutput,
+                    RusotoError<DescribeStateMachineForExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_activity_task: Option<
+        Box<
+            dyn Fn(
+                    GetActivityTaskInput,
+                ) -> ::std::result::Result<
+                    GetActivityTaskOutput,
+                    RusotoError<GetActivityTaskError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_execution_history: Option<
+        Box<
+            dyn Fn(
+                    GetExecutionHistoryInput,
+                ) -> ::std::result::Result<
+                    GetExecutionHistoryOutput,
+                    RusotoError<GetExecutionHistoryError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_activities: Option<
+        Box<
+            dyn Fn(
+                    ListActivitiesInput,
+                )
+                    -> ::std::result::Result<ListActivitiesOutput, RusotoError<ListActivitiesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_executions: Option<
+        Box<
+            dyn Fn(
+                    ListExecutionsInput,
+                )
+                    -> ::std::result::Result<ListExecutionsOutput, RusotoError<ListExecutionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_state_machines: Option<
+        Box<
+            dyn Fn(
+                    ListStateMachinesInput,
+                ) -> ::std::result::Result<
+                    ListStateMachinesOutput,
+                    RusotoError<ListStateMachinesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_task_failure: Option<
+        Box<
+            dyn Fn(
+                    SendTaskFailureInput,
+                ) -> ::std::result::Result<
+                    SendTaskFailureOutput,
+                    RusotoError<SendTaskFailureError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_task_heartbeat: Option<
+        Box<
+            dyn Fn(
+                    SendTaskHeartbeatInput,
+                ) -> ::std::result::Result<
+                    SendTaskHeartbeatOutput,
+                    RusotoError<SendTaskHeartbeatError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    send_task_success: Option<
+        Box<
+            dyn Fn(
+                    SendTaskSuccessInput,
+                ) -> ::std::result::Result<
+                    SendTaskSuccessOutput,
+                    RusotoError<SendTaskSuccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_execution: Option<
+        Box<
+            dyn Fn(
+                    StartExecutionInput,
+                )
+                    -> ::std::result::Result<StartExecutionOutput, RusotoError<StartExecutionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_execution: Option<
+        Box<
+            dyn Fn(
+                    StopExecutionInput,
+                )
+                    -> ::std::result::Result<StopExecutionOutput, RusotoError<StopExecutionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceInput,
+                )
+                    -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceInput,
+                )
+                    -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_state_machine: Option<
+        Box<
+            dyn Fn(
+                    UpdateStateMachineInput,
+                ) -> ::std::result::Result<
+                    UpdateStateMachineOutput,
+                    RusotoError<UpdateStateMachineError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockStepFunctions {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockStepFunctions {
+        Default::default()
+    }
+
+    /// Answers `create_activity` with what `handler` returns for its input.
+    pub fn with_create_activity<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                CreateActivityInput,
+            )
+                -> ::std::result::Result<CreateActivityOutput, RusotoError<CreateActivityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_activity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_state_machine` with what `handler` returns for its input.
+    pub fn with_create_state_machine<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                CreateStateMachineInput,
+            ) -> ::std::result::Result<
+                CreateStateMachineOutput,
+                RusotoError<CreateStateMachineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_state_machine = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_activity` with what `handler` returns for its input.
+    pub fn with_delete_activity<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                DeleteActivityInput,
+            )
+                -> ::std::result::Result<DeleteActivityOutput, RusotoError<DeleteActivityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_activity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_state_machine` with what `handler` returns for its input.
+    pub fn with_delete_state_machine<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                DeleteStateMachineInput,
+            ) -> ::std::result::Result<
+                DeleteStateMachineOutput,
+                RusotoError<DeleteStateMachineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_state_machine = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_activity` with what `handler` returns for its input.
+    pub fn with_describe_activity<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                DescribeActivityInput,
+            )
+                -> ::std::result::Result<DescribeActivityOutput, RusotoError<DescribeActivityError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_activity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_execution` with what `handler` returns for its input.
+    pub fn with_describe_execution<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                DescribeExecutionInput,
+            ) -> ::std::result::Result<
+                DescribeExecutionOutput,
+                RusotoError<DescribeExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_state_machine` with what `handler` returns for its input.
+    pub fn with_describe_state_machine<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                DescribeStateMachineInput,
+            ) -> ::std::result::Result<
+                DescribeStateMachineOutput,
+                RusotoError<DescribeStateMachineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_state_machine = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_state_machine_for_execution` with what `handler` returns for its input.
+    pub fn with_describe_state_machine_for_execution<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                DescribeStateMachineForExecutionInput,
+            ) -> ::std::result::Result<
+                DescribeStateMachineForExecutionOutput,
+                RusotoError<DescribeStateMachineForExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_state_machine_for_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_activity_task` with what `handler` returns for its input.
+    pub fn with_get_activity_task<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                GetActivityTaskInput,
+            )
+                -> ::std::result::Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_activity_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_execution_history` with what `handler` returns for its input.
+    pub fn with_get_execution_history<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                GetExecutionHistoryInput,
+            ) -> ::std::result::Result<
+                GetExecutionHistoryOutput,
+                RusotoError<GetExecutionHistoryError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_execution_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_activities` with what `handler` returns for its input.
+    pub fn with_list_activities<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                ListActivitiesInput,
+            )
+                -> ::std::result::Result<ListActivitiesOutput, RusotoError<ListActivitiesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_activities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_executions` with what `handler` returns for its input.
+    pub fn with_list_executions<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                ListExecutionsInput,
+            )
+                -> ::std::result::Result<ListExecutionsOutput, RusotoError<ListExecutionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_state_machines` with what `handler` returns for its input.
+    pub fn with_list_state_machines<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                ListStateMachinesInput,
+            ) -> ::std::result::Result<
+                ListStateMachinesOutput,
+                RusotoError<ListStateMachinesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_state_machines = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_task_failure` with what `handler` returns for its input.
+    pub fn with_send_task_failure<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                SendTaskFailureInput,
+            )
+                -> ::std::result::Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_task_failure = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_task_heartbeat` with what `handler` returns for its input.
+    pub fn with_send_task_heartbeat<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                SendTaskHeartbeatInput,
+            ) -> ::std::result::Result<
+                SendTaskHeartbeatOutput,
+                RusotoError<SendTaskHeartbeatError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.send_task_heartbeat = Some(Box::new(handler));
+        self
+    }
+    /// Answers `send_task_success` with what `handler` returns for its input.
+    pub fn with_send_task_success<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                SendTaskSuccessInput,
+            )
+                -> ::std::result::Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.send_task_success = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_execution` with what `handler` returns for its input.
+    pub fn with_start_execution<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                StartExecutionInput,
+            )
+                -> ::std::result::Result<StartExecutionOutput, RusotoError<StartExecutionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_execution` with what `handler` returns for its input.
+    pub fn with_stop_execution<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                StopExecutionInput,
+            )
+                -> ::std::result::Result<StopExecutionOutput, RusotoError<StopExecutionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                TagResourceInput,
+            )
+                -> ::std::result::Result<TagResourceOutput, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                UntagResourceInput,
+            )
+                -> ::std::result::Result<UntagResourceOutput, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_state_machine` with what `handler` returns for its input.
+    pub fn with_update_state_machine<F>(mut self, handler: F) -> MockStepFunctions
+    where
+        F: Fn(
+                UpdateStateMachineInput,
+            ) -> ::std::result::Result<
+                UpdateStateMachineOutput,
+                RusotoError<UpdateStateMachineError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_state_machine = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl StepFunctions for MockStepFunctions {
+    fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> RusotoFuture<CreateActivityOutput, CreateActivityError> {
+        match self.create_activity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for create_activity"),
+        }
+    }
+    fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> RusotoFuture<CreateStateMachineOutput, CreateStateMachineError> {
+        match self.create_state_machine {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for create_state_machine"),
+        }
+    }
+    fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> RusotoFuture<DeleteActivityOutput, DeleteActivityError> {
+        match self.delete_activity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for delete_activity"),
+        }
+    }
+    fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> RusotoFuture<DeleteStateMachineOutput, DeleteStateMachineError> {
+        match self.delete_state_machine {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for delete_state_machine"),
+        }
+    }
+    fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> RusotoFuture<DescribeActivityOutput, DescribeActivityError> {
+        match self.describe_activity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for describe_activity"),
+        }
+    }
+    fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> RusotoFuture<DescribeExecutionOutput, DescribeExecutionError> {
+        match self.describe_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for describe_execution"),
+        }
+    }
+    fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> RusotoFuture<DescribeStateMachineOutput, DescribeStateMachineError> {
+        match self.describe_state_machine {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for describe_state_machine"),
+        }
+    }
+    fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> RusotoFuture<DescribeStateMachineForExecutionOutput, DescribeStateMachineForExecutionError>
+    {
+        match self.describe_state_machine_for_execution {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockStepFunctions has no handler for describe_state_machine_for_execution")
+            }
+        }
+    }
+    fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> RusotoFuture<GetActivityTaskOutput, GetActivityTaskError> {
+        match self.get_activity_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for get_activity_task"),
+        }
+    }
+    fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> RusotoFuture<GetExecutionHistoryOutput, GetExecutionHistoryError> {
+        match self.get_execution_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for get_execution_history"),
+        }
+    }
+    fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> RusotoFuture<ListActivitiesOutput, ListActivitiesError> {
+        match self.list_activities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for list_activities"),
+        }
+    }
+    fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> RusotoFuture<ListExecutionsOutput, ListExecutionsError> {
+        match self.list_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for list_executions"),
+        }
+    }
+    fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> RusotoFuture<ListStateMachinesOutput, ListStateMachinesError> {
+        match self.list_state_machines {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for list_state_machines"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for list_tags_for_resource"),
+        }
+    }
+    fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> RusotoFuture<SendTaskFailureOutput, SendTaskFailureError> {
+        match self.send_task_failure {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for send_task_failure"),
+        }
+    }
+    fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> RusotoFuture<SendTaskHeartbeatOutput, SendTaskHeartbeatError> {
+        match self.send_task_heartbeat {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for send_task_heartbeat"),
+        }
+    }
+    fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> RusotoFuture<SendTaskSuccessOutput, SendTaskSuccessError> {
+        match self.send_task_success {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for send_task_success"),
+        }
+    }
+    fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> RusotoFuture<StartExecutionOutput, StartExecutionError> {
+        match self.start_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for start_execution"),
+        }
+    }
+    fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> RusotoFuture<StopExecutionOutput, StopExecutionError> {
+        match self.stop_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for stop_execution"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for untag_resource"),
+        }
+    }
+    fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> RusotoFuture<UpdateStateMachineOutput, UpdateStateMachineError> {
+        match self.update_state_machine {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStepFunctions has no handler for update_state_machine"),
+        }
+    }
+}
@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>ActivateGatewayInput$ActivationKey</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayName</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayRegion</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayTimezone</a> </p> </li> <li> <p> <a>ActivateGatewayInput$GatewayType</a> </p> </li> <li> <p> <a>ActivateGatewayInput$TapeDriveType</a> </p> </li> <li> <p> <a>ActivateGatewayInput$MediumChangerType</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActivateGatewayInput {
     /// <p>Your gateway activation key. You can obtain the activation key by sending an HTTP GET request with redirects enabled to the gateway IP address (port 80). The redirect URL returned in the response provides you the activation key for your gateway in the query string parameter <code>activationKey</code>. It may also include other activation-related parameters, however, these are merely defaults -- the arguments you pass to the <code>ActivateGateway</code> API call determine the actual configuration of your gateway. </p> <p>For more information, see https://docs.aws.amazon.com/storagegateway/latest/userguide/get-activation-key.html in the Storage Gateway User Guide.</p>
     #[serde(rename = "ActivationKey")]
@@ -56,9 +58,73 @@ pub struct ActivateGatewayInput {
     pub tape_drive_type: Option<String>,
 }
 
+impl Validate for ActivateGatewayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "activation_key"),
+            self.activation_key.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_length(
+            &field_path(path, "gateway_name"),
+            self.gateway_name.chars().count(),
+            Some(2),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "gateway_name"),
+            &self.gateway_name,
+            "^[ -\\.0-\\[\\]-~]*[!-\\.0-\\[\\]-~][ -\\.0-\\[\\]-~]*$",
+        );
+        errors.check_length(
+            &field_path(path, "gateway_region"),
+            self.gateway_region.chars().count(),
+            Some(1),
+            Some(25),
+        );
+        errors.check_length(
+            &field_path(path, "gateway_timezone"),
+            self.gateway_timezone.chars().count(),
+            Some(3),
+            Some(10),
+        );
+        if let Some(ref value) = self.gateway_type {
+            errors.check_length(
+                &field_path(path, "gateway_type"),
+                value.chars().count(),
+                Some(2),
+                Some(20),
+            );
+        }
+        if let Some(ref value) = self.medium_changer_type {
+            errors.check_length(
+                &field_path(path, "medium_changer_type"),
+                value.chars().count(),
+                Some(2),
+                Some(50),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.tape_drive_type {
+            errors.check_length(
+                &field_path(path, "tape_drive_type"),
+                value.chars().count(),
+                Some(2),
+                Some(50),
+            );
+        }
+    }
+}
+
 /// <p><p>AWS Storage Gateway returns the Amazon Resource Name (ARN) of the activated gateway. It is a string made of information such as your account, gateway name, and AWS Region. This ARN is used to reference the gateway in other API operations as well as resource-based authorization.</p> <note> <p>For gateways activated prior to September 02, 2015, the gateway ARN contains the gateway name rather than the gateway ID. Changing the name of the gateway has no effect on the gateway ARN.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivateGatewayOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,6 +132,7 @@ pub struct ActivateGatewayOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddCacheInput {
     /// <p>An array of strings that identify disks that are to be configured as working storage. Each string have a minimum length of 1 and maximum length of 300. You can get the disk IDs from the <a>ListLocalDisks</a> API.</p>
     #[serde(rename = "DiskIds")]
@@ -74,8 +141,23 @@ pub struct AddCacheInput {
     pub gateway_arn: String,
 }
 
+impl Validate for AddCacheInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.disk_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "disk_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddCacheOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -84,6 +166,7 @@ pub struct AddCacheOutput {
 
 /// <p>AddTagsToResourceInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddTagsToResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource you want to add tags to.</p>
     #[serde(rename = "ResourceARN")]
@@ -93,9 +176,24 @@ pub struct AddTagsToResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for AddTagsToResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>AddTagsToResourceOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddTagsToResourceOutput {
     /// <p>The Amazon Resource Name (ARN) of the resource you want to add tags to.</p>
     #[serde(rename = "ResourceARN")]
@@ -104,6 +202,7 @@ pub struct AddTagsToResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddUploadBufferInput {
     /// <p>An array of strings that identify disks that are to be configured as working storage. Each string have a minimum length of 1 and maximum length of 300. You can get the disk IDs from the <a>ListLocalDisks</a> API.</p>
     #[serde(rename = "DiskIds")]
@@ -112,8 +211,23 @@ pub struct AddUploadBufferInput {
     pub gateway_arn: String,
 }
 
+impl Validate for AddUploadBufferInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.disk_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "disk_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddUploadBufferOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -122,6 +236,7 @@ pub struct AddUploadBufferOutput {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>AddWorkingStorageInput$DiskIds</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddWorkingStorageInput {
     /// <p>An array of strings that identify disks that are to be configured as working storage. Each string have a minimum length of 1 and maximum length of 300. You can get the disk IDs from the <a>ListLocalDisks</a> API.</p>
     #[serde(rename = "DiskIds")]
@@ -130,9 +245,24 @@ pub struct AddWorkingStorageInput {
     pub gateway_arn: String,
 }
 
+impl Validate for AddWorkingStorageInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.disk_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "disk_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(300));
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway for which working storage was configured.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddWorkingStorageOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -140,6 +270,7 @@ pub struct AddWorkingStorageOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssignTapePoolInput {
     /// <p>The ID of the pool that you want to add your tape to for archiving. The tape in this pool is archived in the S3 storage class that is associated with the pool. When you use your backup application to eject the tape, the tape is archived directly into the storage class (Glacier or Deep Archive) that corresponds to the pool.</p> <p>Valid values: "GLACIER", "DEEP_ARCHIVE"</p>
     #[serde(rename = "PoolId")]
@@ -149,8 +280,26 @@ pub struct AssignTapePoolInput {
     pub tape_arn: String,
 }
 
+impl Validate for AssignTapePoolInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "pool_id"),
+            self.pool_id.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssignTapePoolOutput {
     /// <p>The unique Amazon Resource Names (ARN) of the virtual tape that was added to the tape pool.</p>
     #[serde(rename = "TapeARN")]
@@ -160,6 +309,7 @@ pub struct AssignTapePoolOutput {
 
 /// <p>AttachVolumeInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AttachVolumeInput {
     /// <p>The unique device ID or other distinguishing data that identifies the local disk used to create the volume. This value is only required when you are attaching a stored volume.</p>
     #[serde(rename = "DiskId")]
@@ -180,9 +330,48 @@ pub struct AttachVolumeInput {
     pub volume_arn: String,
 }
 
+impl Validate for AttachVolumeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.disk_id {
+            errors.check_length(
+                &field_path(path, "disk_id"),
+                value.chars().count(),
+                Some(1),
+                Some(300),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(
+            &field_path(path, "network_interface_id"),
+            &self.network_interface_id,
+            "\\A(25[0-5]|2[0-4]\\d|[0-1]?\\d?\\d)(\\.(25[0-5]|2[0-4]\\d|[0-1]?\\d?\\d)){3}\\z",
+        );
+        if let Some(ref value) = self.target_name {
+            errors.check_length(
+                &field_path(path, "target_name"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(&field_path(path, "target_name"), &value, "^[-\\.;a-z0-9]+$");
+        }
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>AttachVolumeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachVolumeOutput {
     /// <p>The Amazon Resource Name (ARN) of the volume target, which includes the iSCSI name for the initiator that was used to connect to the target.</p>
     #[serde(rename = "TargetARN")]
@@ -196,7 +385,7 @@ pub struct AttachVolumeOutput {
 
 /// <p>Describes an iSCSI cached volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CachediSCSIVolume {
     /// <p>The date the volume was created. Volumes created prior to March 28, 2017 don’t have this time stamp.</p>
     #[serde(rename = "CreatedDate")]
@@ -253,6 +442,7 @@ pub struct CachediSCSIVolume {
 
 /// <p>CancelArchivalInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelArchivalInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -261,9 +451,27 @@ pub struct CancelArchivalInput {
     pub tape_arn: String,
 }
 
+impl Validate for CancelArchivalInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 /// <p>CancelArchivalOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelArchivalOutput {
     /// <p>The Amazon Resource Name (ARN) of the virtual tape for which archiving was canceled.</p>
     #[serde(rename = "TapeARN")]
@@ -273,6 +481,7 @@ pub struct CancelArchivalOutput {
 
 /// <p>CancelRetrievalInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelRetrievalInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -281,9 +490,27 @@ pub struct CancelRetrievalInput {
     pub tape_arn: String,
 }
 
+impl Validate for CancelRetrievalInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 /// <p>CancelRetrievalOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelRetrievalOutput {
     /// <p>The Amazon Resource Name (ARN) of the virtual tape for which retrieval was canceled.</p>
     #[serde(rename = "TapeARN")]
@@ -293,7 +520,7 @@ pub struct CancelRetrievalOutput {
 
 /// <p>Describes Challenge-Handshake Authentication Protocol (CHAP) information that supports authentication between your gateway and iSCSI initiators.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChapInfo {
     /// <p>The iSCSI initiator that connects to the target.</p>
     #[serde(rename = "InitiatorName")]
@@ -314,6 +541,7 @@ pub struct ChapInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCachediSCSIVolumeInput {
     /// <p>A unique identifier that you use to retry a request. If you retry a request, use the same <code>ClientToken</code> you specified in the initial request.</p>
     #[serde(rename = "ClientToken")]
@@ -351,8 +579,71 @@ pub struct CreateCachediSCSIVolumeInput {
     pub volume_size_in_bytes: i64,
 }
 
+impl Validate for CreateCachediSCSIVolumeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(5),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        errors.check_pattern(
+            &field_path(path, "network_interface_id"),
+            &self.network_interface_id,
+            "\\A(25[0-5]|2[0-4]\\d|[0-1]?\\d?\\d)(\\.(25[0-5]|2[0-4]\\d|[0-1]?\\d?\\d)){3}\\z",
+        );
+        if let Some(ref value) = self.snapshot_id {
+            errors.check_pattern(
+                &field_path(path, "snapshot_id"),
+                &value,
+                "\\Asnap-([0-9A-Fa-f]{8}|[0-9A-Fa-f]{17})\\z",
+            );
+        }
+        if let Some(ref value) = self.source_volume_arn {
+            errors.check_length(
+                &field_path(path, "source_volume_arn"),
+                value.chars().count(),
+                Some(50),
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "target_name"),
+            self.target_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_name"),
+            &self.target_name,
+            "^[-\\.;a-z0-9]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCachediSCSIVolumeOutput {
     /// <p>The Amazon Resource Name (ARN) of the volume target, which includes the iSCSI name that initiators can use to connect to the target.</p>
     #[serde(rename = "TargetARN")]
@@ -366,6 +657,7 @@ pub struct CreateCachediSCSIVolumeOutput {
 
 /// <p>CreateNFSFileShareInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNFSFileShareInput {
     /// <p>The list of clients that are allowed to access the file gateway. The list must contain either valid IP addresses or valid CIDR blocks. </p>
     #[serde(rename = "ClientList")]
@@ -425,9 +717,89 @@ pub struct CreateNFSFileShareInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateNFSFileShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_list {
+            errors.check_length(
+                &field_path(path, "client_list"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "client_list"), i);
+                errors.check_pattern(&item_path, &item, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])(\\/([0-9]|[1-2][0-9]|3[0-2]))?$");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(5),
+            Some(100),
+        );
+        if let Some(ref value) = self.default_storage_class {
+            errors.check_length(
+                &field_path(path, "default_storage_class"),
+                value.chars().count(),
+                Some(5),
+                Some(50),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        errors.check_length(
+            &field_path(path, "location_arn"),
+            self.location_arn.chars().count(),
+            Some(16),
+            Some(1400),
+        );
+        if let Some(ref value) = self.nfs_file_share_defaults {
+            value.validate_at(&field_path(path, "nfs_file_share_defaults"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "role"),
+            self.role.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role"),
+            &self.role,
+            "^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):iam::([0-9]+):role/(\\S+)$",
+        );
+        if let Some(ref value) = self.squash {
+            errors.check_length(
+                &field_path(path, "squash"),
+                value.chars().count(),
+                Some(5),
+                Some(15),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// <p>CreateNFSFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNFSFileShareOutput {
     /// <p>The Amazon Resource Name (ARN) of the newly created file share. </p>
     #[serde(rename = "FileShareARN")]
@@ -437,6 +809,7 @@ pub struct CreateNFSFileShareOutput {
 
 /// <p>CreateSMBFileShareInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSMBFileShareInput {
     /// <p><p>A list of users in the Active Directory that will be granted administrator privileges on the file share. These users can do all file operations as the super-user. </p> <important> <p>Use this option very carefully, because any user in this list can do anything they like on the file share, regardless of file permissions.</p> </important></p>
     #[serde(rename = "AdminUserList")]
@@ -504,9 +877,110 @@ pub struct CreateSMBFileShareInput {
     pub valid_user_list: Option<Vec<String>>,
 }
 
+impl Validate for CreateSMBFileShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.admin_user_list {
+            errors.check_length(
+                &field_path(path, "admin_user_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "admin_user_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.authentication {
+            errors.check_length(
+                &field_path(path, "authentication"),
+                value.chars().count(),
+                Some(5),
+                Some(15),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(5),
+            Some(100),
+        );
+        if let Some(ref value) = self.default_storage_class {
+            errors.check_length(
+                &field_path(path, "default_storage_class"),
+                value.chars().count(),
+                Some(5),
+                Some(50),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.invalid_user_list {
+            errors.check_length(
+                &field_path(path, "invalid_user_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "invalid_user_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        errors.check_length(
+            &field_path(path, "location_arn"),
+            self.location_arn.chars().count(),
+            Some(16),
+            Some(1400),
+        );
+        errors.check_length(
+            &field_path(path, "role"),
+            self.role.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role"),
+            &self.role,
+            "^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):iam::([0-9]+):role/(\\S+)$",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.valid_user_list {
+            errors.check_length(
+                &field_path(path, "valid_user_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "valid_user_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+    }
+}
+
 /// <p>CreateSMBFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSMBFileShareOutput {
     /// <p>The Amazon Resource Name (ARN) of the newly created file share. </p>
     #[serde(rename = "FileShareARN")]
@@ -515,6 +989,7 @@ pub struct CreateSMBFileShareOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotFromVolumeRecoveryPointInput {
     /// <p>Textual description of the snapshot that appears in the Amazon EC2 console, Elastic Block Store snapshots panel in the <b>Description</b> field, and in the AWS Storage Gateway snapshot <b>Details</b> pane, <b>Description</b> field</p>
     #[serde(rename = "SnapshotDescription")]
@@ -528,8 +1003,31 @@ pub struct CreateSnapshotFromVolumeRecoveryPointInput {
     pub volume_arn: String,
 }
 
+impl Validate for CreateSnapshotFromVolumeRecoveryPointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "snapshot_description"),
+            self.snapshot_description.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSnapshotFromVolumeRecoveryPointOutput {
     /// <p>The ID of the snapshot.</p>
     #[serde(rename = "SnapshotId")]
@@ -547,6 +1045,7 @@ pub struct CreateSnapshotFromVolumeRecoveryPointOutput {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>CreateSnapshotInput$SnapshotDescription</a> </p> </li> <li> <p> <a>CreateSnapshotInput$VolumeARN</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSnapshotInput {
     /// <p>Textual description of the snapshot that appears in the Amazon EC2 console, Elastic Block Store snapshots panel in the <b>Description</b> field, and in the AWS Storage Gateway snapshot <b>Details</b> pane, <b>Description</b> field</p>
     #[serde(rename = "SnapshotDescription")]
@@ -560,9 +1059,32 @@ pub struct CreateSnapshotInput {
     pub volume_arn: String,
 }
 
+impl Validate for CreateSnapshotInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "snapshot_description"),
+            self.snapshot_description.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSnapshotOutput {
     /// <p>The snapshot ID that is used to refer to the snapshot in future operations such as describing snapshots (Amazon Elastic Compute Cloud API <code>DescribeSnapshots</code>) or creating a volume from a snapshot (<a>CreateStorediSCSIVolume</a>).</p>
     #[serde(rename = "SnapshotId")]
@@ -576,6 +1098,7 @@ pub struct CreateSnapshotOutput {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>CreateStorediSCSIVolumeInput$DiskId</a> </p> </li> <li> <p> <a>CreateStorediSCSIVolumeInput$NetworkInterfaceId</a> </p> </li> <li> <p> <a>CreateStorediSCSIVolumeInput$PreserveExistingData</a> </p> </li> <li> <p> <a>CreateStorediSCSIVolumeInput$SnapshotId</a> </p> </li> <li> <p> <a>CreateStorediSCSIVolumeInput$TargetName</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateStorediSCSIVolumeInput {
     /// <p>The unique identifier for the gateway local disk that is configured as a stored volume. Use <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/API_ListLocalDisks.html">ListLocalDisks</a> to list disk IDs for a gateway.</p>
     #[serde(rename = "DiskId")]
@@ -609,9 +1132,64 @@ pub struct CreateStorediSCSIVolumeInput {
     pub target_name: String,
 }
 
+impl Validate for CreateStorediSCSIVolumeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "disk_id"),
+            self.disk_id.chars().count(),
+            Some(1),
+            Some(300),
+        );
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        errors.check_pattern(
+            &field_path(path, "network_interface_id"),
+            &self.network_interface_id,
+            "\\A(25[0-5]|2[0-4]\\d|[0-1]?\\d?\\d)(\\.(25[0-5]|2[0-4]\\d|[0-1]?\\d?\\d)){3}\\z",
+        );
+        if let Some(ref value) = self.snapshot_id {
+            errors.check_pattern(
+                &field_path(path, "snapshot_id"),
+                &value,
+                "\\Asnap-([0-9A-Fa-f]{8}|[0-9A-Fa-f]{17})\\z",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "target_name"),
+            self.target_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "target_name"),
+            &self.target_name,
+            "^[-\\.;a-z0-9]+$",
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateStorediSCSIVolumeOutput {
     /// <p>The Amazon Resource Name (ARN) of the volume target, which includes the iSCSI name that initiators can use to connect to the target.</p>
     #[serde(rename = "TargetARN")]
@@ -629,6 +1207,7 @@ pub struct CreateStorediSCSIVolumeOutput {
 
 /// <p>CreateTapeWithBarcodeInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTapeWithBarcodeInput {
     /// <p>The unique Amazon Resource Name (ARN) that represents the gateway to associate the virtual tape with. Use the <a>ListGateways</a> operation to return a list of gateways for your account and AWS Region.</p>
     #[serde(rename = "GatewayARN")]
@@ -657,9 +1236,54 @@ pub struct CreateTapeWithBarcodeInput {
     pub tape_size_in_bytes: i64,
 }
 
+impl Validate for CreateTapeWithBarcodeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        if let Some(ref value) = self.pool_id {
+            errors.check_length(
+                &field_path(path, "pool_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "tape_barcode"),
+            self.tape_barcode.chars().count(),
+            Some(5),
+            Some(16),
+        );
+        errors.check_pattern(
+            &field_path(path, "tape_barcode"),
+            &self.tape_barcode,
+            "^[A-Z0-9]*$",
+        );
+    }
+}
+
 /// <p>CreateTapeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTapeWithBarcodeOutput {
     /// <p>A unique Amazon Resource Name (ARN) that represents the virtual tape that was created.</p>
     #[serde(rename = "TapeARN")]
@@ -669,6 +1293,7 @@ pub struct CreateTapeWithBarcodeOutput {
 
 /// <p>CreateTapesInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTapesInput {
     /// <p><p>A unique identifier that you use to retry a request. If you retry a request, use the same <code>ClientToken</code> you specified in the initial request.</p> <note> <p>Using the same <code>ClientToken</code> prevents creating the tape multiple times.</p> </note></p>
     #[serde(rename = "ClientToken")]
@@ -703,9 +1328,66 @@ pub struct CreateTapesInput {
     pub tape_size_in_bytes: i64,
 }
 
+impl Validate for CreateTapesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_token"),
+            self.client_token.chars().count(),
+            Some(5),
+            Some(100),
+        );
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        errors.check_range(
+            &field_path(path, "num_tapes_to_create"),
+            self.num_tapes_to_create,
+            Some(1),
+            Some(10),
+        );
+        if let Some(ref value) = self.pool_id {
+            errors.check_length(
+                &field_path(path, "pool_id"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "tape_barcode_prefix"),
+            self.tape_barcode_prefix.chars().count(),
+            Some(1),
+            Some(4),
+        );
+        errors.check_pattern(
+            &field_path(path, "tape_barcode_prefix"),
+            &self.tape_barcode_prefix,
+            "^[A-Z]*$",
+        );
+    }
+}
+
 /// <p>CreateTapeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTapesOutput {
     /// <p>A list of unique Amazon Resource Names (ARNs) that represents the virtual tapes that were created.</p>
     #[serde(rename = "TapeARNs")]
@@ -715,6 +1397,7 @@ pub struct CreateTapesOutput {
 
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>DeleteBandwidthRateLimitInput$BandwidthType</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteBandwidthRateLimitInput {
     /// <p>One of the BandwidthType values that indicates the gateway bandwidth rate limit to delete.</p> <p>Valid Values: <code>Upload</code>, <code>Download</code>, <code>All</code>.</p>
     #[serde(rename = "BandwidthType")]
@@ -723,9 +1406,26 @@ pub struct DeleteBandwidthRateLimitInput {
     pub gateway_arn: String,
 }
 
+impl Validate for DeleteBandwidthRateLimitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "bandwidth_type"),
+            self.bandwidth_type.chars().count(),
+            Some(3),
+            Some(25),
+        );
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway whose bandwidth rate information was deleted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteBandwidthRateLimitOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -734,6 +1434,7 @@ pub struct DeleteBandwidthRateLimitOutput {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>DeleteChapCredentialsInput$InitiatorName</a> </p> </li> <li> <p> <a>DeleteChapCredentialsInput$TargetARN</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteChapCredentialsInput {
     /// <p>The iSCSI initiator that connects to the target.</p>
     #[serde(rename = "InitiatorName")]
@@ -743,9 +1444,31 @@ pub struct DeleteChapCredentialsInput {
     pub target_arn: String,
 }
 
+impl Validate for DeleteChapCredentialsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "initiator_name"),
+            self.initiator_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "initiator_name"),
+            &self.initiator_name,
+            "[0-9a-z:.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "target_arn"),
+            self.target_arn.chars().count(),
+            Some(50),
+            Some(800),
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteChapCredentialsOutput {
     /// <p>The iSCSI initiator that connects to the target.</p>
     #[serde(rename = "InitiatorName")]
@@ -759,6 +1482,7 @@ pub struct DeleteChapCredentialsOutput {
 
 /// <p>DeleteFileShareInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFileShareInput {
     /// <p>The Amazon Resource Name (ARN) of the file share to be deleted. </p>
     #[serde(rename = "FileShareARN")]
@@ -769,9 +1493,20 @@ pub struct DeleteFileShareInput {
     pub force_delete: Option<bool>,
 }
 
+impl Validate for DeleteFileShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_share_arn"),
+            self.file_share_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>DeleteFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFileShareOutput {
     /// <p>The Amazon Resource Name (ARN) of the deleted file share. </p>
     #[serde(rename = "FileShareARN")]
@@ -781,14 +1516,26 @@ pub struct DeleteFileShareOutput {
 
 /// <p>A JSON object containing the ID of the gateway to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGatewayInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DeleteGatewayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the ID of the deleted gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGatewayOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -796,14 +1543,26 @@ pub struct DeleteGatewayOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSnapshotScheduleInput {
     /// <p>The volume which snapshot schedule to delete.</p>
     #[serde(rename = "VolumeARN")]
     pub volume_arn: String,
 }
 
+impl Validate for DeleteSnapshotScheduleInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSnapshotScheduleOutput {
     /// <p>The volume which snapshot schedule was deleted.</p>
     #[serde(rename = "VolumeARN")]
@@ -813,15 +1572,28 @@ pub struct DeleteSnapshotScheduleOutput {
 
 /// <p>DeleteTapeArchiveInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTapeArchiveInput {
     /// <p>The Amazon Resource Name (ARN) of the virtual tape to delete from the virtual tape shelf (VTS).</p>
     #[serde(rename = "TapeARN")]
     pub tape_arn: String,
 }
 
+impl Validate for DeleteTapeArchiveInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 /// <p>DeleteTapeArchiveOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTapeArchiveOutput {
     /// <p>The Amazon Resource Name (ARN) of the virtual tape that was deleted from the virtual tape shelf (VTS).</p>
     #[serde(rename = "TapeARN")]
@@ -831,6 +1603,7 @@ pub struct DeleteTapeArchiveOutput {
 
 /// <p>DeleteTapeInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTapeInput {
     /// <p>The unique Amazon Resource Name (ARN) of the gateway that the virtual tape to delete is associated with. Use the <a>ListGateways</a> operation to return a list of gateways for your account and AWS Region.</p>
     #[serde(rename = "GatewayARN")]
@@ -840,9 +1613,27 @@ pub struct DeleteTapeInput {
     pub tape_arn: String,
 }
 
+impl Validate for DeleteTapeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 /// <p>DeleteTapeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTapeOutput {
     /// <p>The Amazon Resource Name (ARN) of the deleted virtual tape.</p>
     #[serde(rename = "TapeARN")]
@@ -852,15 +1643,27 @@ pub struct DeleteTapeOutput {
 
 /// <p>A JSON object containing the <a>DeleteVolumeInput$VolumeARN</a> to delete.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVolumeInput {
     /// <p>The Amazon Resource Name (ARN) of the volume. Use the <a>ListVolumes</a> operation to return a list of gateway volumes.</p>
     #[serde(rename = "VolumeARN")]
     pub volume_arn: String,
 }
 
+impl Validate for DeleteVolumeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the storage volume that was deleted</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteVolumeOutput {
     /// <p>The Amazon Resource Name (ARN) of the storage volume that was deleted. It is the same ARN you provided in the request.</p>
     #[serde(rename = "VolumeARN")]
@@ -870,14 +1673,26 @@ pub struct DeleteVolumeOutput {
 
 /// <p>A JSON object containing the of the gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeBandwidthRateLimitInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeBandwidthRateLimitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeBandwidthRateLimitOutput {
     /// <p>The average download bandwidth rate limit in bits per second. This field does not appear in the response if the download rate limit is not set.</p>
     #[serde(rename = "AverageDownloadRateLimitInBitsPerSec")]
@@ -893,13 +1708,25 @@ pub struct DescribeBandwidthRateLimitOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCacheInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeCacheInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCacheOutput {
     /// <p>The amount of cache in bytes allocated to the a gateway.</p>
     #[serde(rename = "CacheAllocatedInBytes")]
@@ -931,15 +1758,25 @@ pub struct DescribeCacheOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCachediSCSIVolumesInput {
     /// <p>An array of strings where each string represents the Amazon Resource Name (ARN) of a cached volume. All of the specified cached volumes must from the same gateway. Use <a>ListVolumes</a> to get volume ARNs for a gateway.</p>
     #[serde(rename = "VolumeARNs")]
     pub volume_ar_ns: Vec<String>,
 }
 
+impl Validate for DescribeCachediSCSIVolumesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.volume_ar_ns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "volume_ar_ns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+        }
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCachediSCSIVolumesOutput {
     /// <p>An array of objects where each object contains metadata about one cached volume.</p>
     #[serde(rename = "CachediSCSIVolumes")]
@@ -949,15 +1786,27 @@ pub struct DescribeCachediSCSIVolumesOutput {
 
 /// <p>A JSON object containing the Amazon Resource Name (ARN) of the iSCSI volume target.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeChapCredentialsInput {
     /// <p>The Amazon Resource Name (ARN) of the iSCSI volume target. Use the <a>DescribeStorediSCSIVolumes</a> operation to return to retrieve the TargetARN for specified VolumeARN.</p>
     #[serde(rename = "TargetARN")]
     pub target_arn: String,
 }
 
+impl Validate for DescribeChapCredentialsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "target_arn"),
+            self.target_arn.chars().count(),
+            Some(50),
+            Some(800),
+        );
+    }
+}
+
 /// <p>A JSON object containing a .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeChapCredentialsOutput {
     /// <p><p>An array of <a>ChapInfo</a> objects that represent CHAP credentials. Each object in the array contains CHAP credential information for one target-initiator pair. If no CHAP credentials are set, an empty array is returned. CHAP credential information is provided in a JSON object with the following fields:</p> <ul> <li> <p> <b>InitiatorName</b>: The iSCSI initiator that connects to the target.</p> </li> <li> <p> <b>SecretToAuthenticateInitiator</b>: The secret key that the initiator (for example, the Windows client) must provide to participate in mutual CHAP with the target.</p> </li> <li> <p> <b>SecretToAuthenticateTarget</b>: The secret key that the target must provide to participate in mutual CHAP with the initiator (e.g. Windows client).</p> </li> <li> <p> <b>TargetARN</b>: The Amazon Resource Name (ARN) of the storage volume.</p> </li> </ul></p>
     #[serde(rename = "ChapCredentials")]
@@ -967,14 +1816,26 @@ pub struct DescribeChapCredentialsOutput {
 
 /// <p>A JSON object containing the ID of the gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGatewayInformationInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeGatewayInformationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGatewayInformationOutput {
     /// <p>The Amazon Resource Name (ARN) of the Amazon CloudWatch log group that was used to monitor and log events in the gateway.</p>
     #[serde(rename = "CloudWatchLogGroupARN")]
@@ -1035,14 +1896,26 @@ pub struct DescribeGatewayInformationOutput {
 
 /// <p>A JSON object containing the of the gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeMaintenanceStartTimeInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeMaintenanceStartTimeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>DescribeMaintenanceStartTimeOutput$DayOfMonth</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$DayOfWeek</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$HourOfDay</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$MinuteOfHour</a> </p> </li> <li> <p> <a>DescribeMaintenanceStartTimeOutput$Timezone</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeMaintenanceStartTimeOutput {
     /// <p><p>The day of the month component of the maintenance start time represented as an ordinal number from 1 to 28, where 1 represents the first day of the month and 28 represents the last day of the month.</p> <note> <p>This value is only available for tape and volume gateways.</p> </note></p>
     #[serde(rename = "DayOfMonth")]
@@ -1071,15 +1944,31 @@ pub struct DescribeMaintenanceStartTimeOutput {
 
 /// <p>DescribeNFSFileSharesInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNFSFileSharesInput {
     /// <p>An array containing the Amazon Resource Name (ARN) of each file share to be described. </p>
     #[serde(rename = "FileShareARNList")]
     pub file_share_arn_list: Vec<String>,
 }
 
+impl Validate for DescribeNFSFileSharesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_share_arn_list"),
+            self.file_share_arn_list.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.file_share_arn_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "file_share_arn_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+        }
+    }
+}
+
 /// <p>DescribeNFSFileSharesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeNFSFileSharesOutput {
     /// <p>An array containing a description for each requested file share. </p>
     #[serde(rename = "NFSFileShareInfoList")]
@@ -1089,15 +1978,31 @@ pub struct DescribeNFSFileSharesOutput {
 
 /// <p>DescribeSMBFileSharesInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSMBFileSharesInput {
     /// <p>An array containing the Amazon Resource Name (ARN) of each file share to be described. </p>
     #[serde(rename = "FileShareARNList")]
     pub file_share_arn_list: Vec<String>,
 }
 
+impl Validate for DescribeSMBFileSharesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_share_arn_list"),
+            self.file_share_arn_list.len(),
+            Some(1),
+            Some(10),
+        );
+        for (i, item) in self.file_share_arn_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "file_share_arn_list"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+        }
+    }
+}
+
 /// <p>DescribeSMBFileSharesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSMBFileSharesOutput {
     /// <p>An array containing a description for each requested file share. </p>
     #[serde(rename = "SMBFileShareInfoList")]
@@ -1106,13 +2011,25 @@ pub struct DescribeSMBFileSharesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSMBSettingsInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeSMBSettingsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSMBSettingsOutput {
     /// <p>The name of the domain that the gateway is joined to.</p>
     #[serde(rename = "DomainName")]
@@ -1133,14 +2050,26 @@ pub struct DescribeSMBSettingsOutput {
 
 /// <p>A JSON object containing the <a>DescribeSnapshotScheduleInput$VolumeARN</a> of the volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSnapshotScheduleInput {
     /// <p>The Amazon Resource Name (ARN) of the volume. Use the <a>ListVolumes</a> operation to return a list of gateway volumes.</p>
     #[serde(rename = "VolumeARN")]
     pub volume_arn: String,
 }
 
+impl Validate for DescribeSnapshotScheduleInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSnapshotScheduleOutput {
     /// <p>The snapshot description.</p>
     #[serde(rename = "Description")]
@@ -1170,14 +2099,24 @@ pub struct DescribeSnapshotScheduleOutput {
 
 /// <p>A JSON object containing a list of <a>DescribeStorediSCSIVolumesInput$VolumeARNs</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeStorediSCSIVolumesInput {
     /// <p>An array of strings where each string represents the Amazon Resource Name (ARN) of a stored volume. All of the specified stored volumes must from the same gateway. Use <a>ListVolumes</a> to get volume ARNs for a gateway.</p>
     #[serde(rename = "VolumeARNs")]
     pub volume_ar_ns: Vec<String>,
 }
 
+impl Validate for DescribeStorediSCSIVolumesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.volume_ar_ns.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "volume_ar_ns"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeStorediSCSIVolumesOutput {
     /// <p><p>Describes a single unit of output from <a>DescribeStorediSCSIVolumes</a>. The following fields are returned:</p> <ul> <li> <p> <b>ChapEnabled</b>: Indicates whether mutual CHAP is enabled for the iSCSI target.</p> </li> <li> <p> <b>LunNumber</b>: The logical disk number.</p> </li> <li> <p> <b>NetworkInterfaceId</b>: The network interface ID of the stored volume that initiator use to map the stored volume as an iSCSI target.</p> </li> <li> <p> <b>NetworkInterfacePort</b>: The port used to communicate with iSCSI targets.</p> </li> <li> <p> <b>PreservedExistingData</b>: Indicates if when the stored volume was created, existing data on the underlying local disk was preserved.</p> </li> <li> <p> <b>SourceSnapshotId</b>: If the stored volume was created from a snapshot, this field contains the snapshot ID used, e.g. snap-1122aabb. Otherwise, this field is not included.</p> </li> <li> <p> <b>StorediSCSIVolumes</b>: An array of StorediSCSIVolume objects where each object contains metadata about one stored volume.</p> </li> <li> <p> <b>TargetARN</b>: The Amazon Resource Name (ARN) of the volume target.</p> </li> <li> <p> <b>VolumeARN</b>: The Amazon Resource Name (ARN) of the stored volume.</p> </li> <li> <p> <b>VolumeDiskId</b>: The disk ID of the local disk that was specified in the <a>CreateStorediSCSIVolume</a> operation.</p> </li> <li> <p> <b>VolumeId</b>: The unique identifier of the storage volume, e.g. vol-1122AABB.</p> </li> <li> <p> <b>VolumeiSCSIAttributes</b>: An <a>VolumeiSCSIAttributes</a> object that represents a collection of iSCSI attributes for one stored volume.</p> </li> <li> <p> <b>VolumeProgress</b>: Represents the percentage complete if the volume is restoring or bootstrapping that represents the percent of data transferred. This field does not appear in the response if the stored volume is not restoring or bootstrapping.</p> </li> <li> <p> <b>VolumeSizeInBytes</b>: The size of the volume in bytes.</p> </li> <li> <p> <b>VolumeStatus</b>: One of the <code>VolumeStatus</code> values that indicates the state of the volume.</p> </li> <li> <p> <b>VolumeType</b>: One of the enumeration values describing the type of the volume. Currently, on STORED volumes are supported.</p> </li> </ul></p>
     #[serde(rename = "StorediSCSIVolumes")]
@@ -1187,6 +2126,7 @@ pub struct DescribeStorediSCSIVolumesOutput {
 
 /// <p>DescribeTapeArchivesInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTapeArchivesInput {
     /// <p>Specifies that the number of virtual tapes descried be limited to the specified number.</p>
     #[serde(rename = "Limit")]
@@ -1202,9 +2142,32 @@ pub struct DescribeTapeArchivesInput {
     pub tape_ar_ns: Option<Vec<String>>,
 }
 
+impl Validate for DescribeTapeArchivesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tape_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tape_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+                errors.check_pattern(&item_path, &item, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+            }
+        }
+    }
+}
+
 /// <p>DescribeTapeArchivesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTapeArchivesOutput {
     /// <p>An opaque string that indicates the position at which the virtual tapes that were fetched for description ended. Use this marker in your next request to fetch the next set of virtual tapes in the virtual tape shelf (VTS). If there are no more virtual tapes to describe, this field does not appear in the response.</p>
     #[serde(rename = "Marker")]
@@ -1218,6 +2181,7 @@ pub struct DescribeTapeArchivesOutput {
 
 /// <p>DescribeTapeRecoveryPointsInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTapeRecoveryPointsInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -1231,9 +2195,31 @@ pub struct DescribeTapeRecoveryPointsInput {
     pub marker: Option<String>,
 }
 
+impl Validate for DescribeTapeRecoveryPointsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>DescribeTapeRecoveryPointsOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTapeRecoveryPointsOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1250,6 +2236,7 @@ pub struct DescribeTapeRecoveryPointsOutput {
 
 /// <p>DescribeTapesInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTapesInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -1267,9 +2254,38 @@ pub struct DescribeTapesInput {
     pub tape_ar_ns: Option<Vec<String>>,
 }
 
+impl Validate for DescribeTapesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tape_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tape_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+                errors.check_pattern(&item_path, &item, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+            }
+        }
+    }
+}
+
 /// <p>DescribeTapesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTapesOutput {
     /// <p>An opaque string which can be used as part of a subsequent DescribeTapes call to retrieve the next page of results.</p> <p>If a response does not contain a marker, then there are no more results to be retrieved.</p>
     #[serde(rename = "Marker")]
@@ -1282,13 +2298,25 @@ pub struct DescribeTapesOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUploadBufferInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeUploadBufferInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUploadBufferOutput {
     /// <p>An array of the gateway's local disk IDs that are configured as working storage. Each local disk ID is specified as a string (minimum length of 1 and maximum length of 300). If no local disks are configured as working storage, then the DiskIds array is empty.</p>
     #[serde(rename = "DiskIds")]
@@ -1309,6 +2337,7 @@ pub struct DescribeUploadBufferOutput {
 
 /// <p>DescribeVTLDevicesInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeVTLDevicesInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -1326,9 +2355,37 @@ pub struct DescribeVTLDevicesInput {
     pub vtl_device_ar_ns: Option<Vec<String>>,
 }
 
+impl Validate for DescribeVTLDevicesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.vtl_device_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "vtl_device_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+            }
+        }
+    }
+}
+
 /// <p>DescribeVTLDevicesOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeVTLDevicesOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1345,14 +2402,26 @@ pub struct DescribeVTLDevicesOutput {
 
 /// <p>A JSON object containing the of the gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkingStorageInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DescribeWorkingStorageInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkingStorageOutput {
     /// <p>An array of the gateway's local disk IDs that are configured as working storage. Each local disk ID is specified as a string (minimum length of 1 and maximum length of 300). If no local disks are configured as working storage, then the DiskIds array is empty.</p>
     #[serde(rename = "DiskIds")]
@@ -1373,6 +2442,7 @@ pub struct DescribeWorkingStorageOutput {
 
 /// <p>AttachVolumeInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetachVolumeInput {
     /// <p>Set to <code>true</code> to forcibly remove the iSCSI connection of the target volume and detach the volume. The default is <code>false</code>. If this value is set to <code>false</code>, you must manually disconnect the iSCSI connection from the target volume.</p>
     #[serde(rename = "ForceDetach")]
@@ -1383,9 +2453,20 @@ pub struct DetachVolumeInput {
     pub volume_arn: String,
 }
 
+impl Validate for DetachVolumeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>AttachVolumeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetachVolumeOutput {
     /// <p>The Amazon Resource Name (ARN) of the volume that was detached.</p>
     #[serde(rename = "VolumeARN")]
@@ -1395,7 +2476,7 @@ pub struct DetachVolumeOutput {
 
 /// <p>Lists iSCSI information about a VTL device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceiSCSIAttributes {
     /// <p>Indicates whether mutual CHAP is enabled for the iSCSI target.</p>
     #[serde(rename = "ChapEnabled")]
@@ -1417,14 +2498,26 @@ pub struct DeviceiSCSIAttributes {
 
 /// <p>DisableGatewayInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisableGatewayInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for DisableGatewayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>DisableGatewayOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisableGatewayOutput {
     /// <p>The unique Amazon Resource Name (ARN) of the disabled gateway.</p>
     #[serde(rename = "GatewayARN")]
@@ -1434,7 +2527,7 @@ pub struct DisableGatewayOutput {
 
 /// <p>Represents a gateway's local disk.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Disk {
     /// <p>The iSCSI qualified name (IQN) that is defined for a disk. This field is not included in the response if the local disk is not defined as an iSCSI target. The format of this field is <i>targetIqn::LUNNumber::region-volumeId</i>. </p>
     #[serde(rename = "DiskAllocationResource")]
@@ -1470,7 +2563,7 @@ pub struct Disk {
 
 /// <p>Describes a file share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FileShareInfo {
     #[serde(rename = "FileShareARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1491,7 +2584,7 @@ pub struct FileShareInfo {
 
 /// <p>Describes a gateway object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GatewayInfo {
     /// <p>The ID of the Amazon EC2 instance that was used to launch the gateway.</p>
     #[serde(rename = "Ec2InstanceId")]
@@ -1525,6 +2618,7 @@ pub struct GatewayInfo {
 
 /// <p>JoinDomainInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct JoinDomainInput {
     /// <p>List of IPv4 addresses, NetBIOS names, or host names of your domain server. If you need to specify the port number include it after the colon (“:”). For example, <code>mydc.mydomain.com:389</code>.</p>
     #[serde(rename = "DomainControllers")]
@@ -1548,18 +2642,78 @@ pub struct JoinDomainInput {
     pub user_name: String,
 }
 
-/// <p>JoinDomainOutput</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct JoinDomainOutput {
-    /// <p>The unique Amazon Resource Name (ARN) of the gateway that joined the domain.</p>
-    #[serde(rename = "GatewayARN")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gateway_arn: Option<String>,
-}
+impl Validate for JoinDomainInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.domain_controllers {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "domain_controllers"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(6), Some(1024));
+                errors.check_pattern(
+                    &item_path,
+                    &item,
+                    "^(([a-zA-Z0-9\\-]*[a-zA-Z0-9])\\.)*([A-Za-z0-9\\-]*[A-Za-z0-9])(:(\\d+))?$",
+                );
+            }
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^([a-zA-Z0-9]+[\\\\.-])+([a-zA-Z0-9])+$",
+        );
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.organizational_unit {
+            errors.check_length(
+                &field_path(path, "organizational_unit"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "^[ -~]+$");
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "^\\w[\\w\\.\\- ]*$",
+        );
+    }
+}
+
+/// <p>JoinDomainOutput</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct JoinDomainOutput {
+    /// <p>The unique Amazon Resource Name (ARN) of the gateway that joined the domain.</p>
+    #[serde(rename = "GatewayARN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway_arn: Option<String>,
+}
 
 /// <p>ListFileShareInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFileSharesInput {
     /// <p>The Amazon resource Name (ARN) of the gateway whose file shares you want to list. If this field is not present, all file shares under your account are listed.</p>
     #[serde(rename = "GatewayARN")]
@@ -1575,9 +2729,33 @@ pub struct ListFileSharesInput {
     pub marker: Option<String>,
 }
 
+impl Validate for ListFileSharesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.gateway_arn {
+            errors.check_length(
+                &field_path(path, "gateway_arn"),
+                value.chars().count(),
+                Some(50),
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p>ListFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFileSharesOutput {
     /// <p>An array of information about the file gateway's file shares. </p>
     #[serde(rename = "FileShareInfoList")]
@@ -1595,6 +2773,7 @@ pub struct ListFileSharesOutput {
 
 /// <p><p>A JSON object containing zero or more of the following fields:</p> <ul> <li> <p> <a>ListGatewaysInput$Limit</a> </p> </li> <li> <p> <a>ListGatewaysInput$Marker</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGatewaysInput {
     /// <p>Specifies that the list of gateways returned be limited to the specified number of items.</p>
     #[serde(rename = "Limit")]
@@ -1606,8 +2785,24 @@ pub struct ListGatewaysInput {
     pub marker: Option<String>,
 }
 
+impl Validate for ListGatewaysInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGatewaysOutput {
     /// <p>An array of <a>GatewayInfo</a> objects.</p>
     #[serde(rename = "Gateways")]
@@ -1621,13 +2816,25 @@ pub struct ListGatewaysOutput {
 
 /// <p>A JSON object containing the of the gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLocalDisksInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for ListLocalDisksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLocalDisksOutput {
     /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>ListLocalDisksOutput$Disks</a> </p> </li> </ul></p>
     #[serde(rename = "Disks")]
@@ -1640,6 +2847,7 @@ pub struct ListLocalDisksOutput {
 
 /// <p>ListTagsForResourceInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>Specifies that the list of tags returned be limited to the specified number of items.</p>
     #[serde(rename = "Limit")]
@@ -1654,9 +2862,31 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>ListTagsForResourceOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>An opaque string that indicates the position at which to stop returning the list of tags.</p>
     #[serde(rename = "Marker")]
@@ -1674,6 +2904,7 @@ pub struct ListTagsForResourceOutput {
 
 /// <p><p>A JSON object that contains one or more of the following fields:</p> <ul> <li> <p> <a>ListTapesInput$Limit</a> </p> </li> <li> <p> <a>ListTapesInput$Marker</a> </p> </li> <li> <p> <a>ListTapesInput$TapeARNs</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTapesInput {
     /// <p>An optional number limit for the tapes in the list returned by this call.</p>
     #[serde(rename = "Limit")]
@@ -1688,9 +2919,32 @@ pub struct ListTapesInput {
     pub tape_ar_ns: Option<Vec<String>>,
 }
 
+impl Validate for ListTapesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.tape_ar_ns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tape_ar_ns"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(50), Some(500));
+                errors.check_pattern(&item_path, &item, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+            }
+        }
+    }
+}
+
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>ListTapesOutput$Marker</a> </p> </li> <li> <p> <a>ListTapesOutput$VolumeInfos</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTapesOutput {
     /// <p>A string that indicates the position at which to begin returning the next list of tapes. Use the marker in your next request to continue pagination of tapes. If there are no more tapes to list, this element does not appear in the response body.</p>
     #[serde(rename = "Marker")]
@@ -1703,15 +2957,27 @@ pub struct ListTapesOutput {
 
 /// <p>ListVolumeInitiatorsInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVolumeInitiatorsInput {
     /// <p>The Amazon Resource Name (ARN) of the volume. Use the <a>ListVolumes</a> operation to return a list of gateway volumes for the gateway.</p>
     #[serde(rename = "VolumeARN")]
     pub volume_arn: String,
 }
 
+impl Validate for ListVolumeInitiatorsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>ListVolumeInitiatorsOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVolumeInitiatorsOutput {
     /// <p>The host names and port numbers of all iSCSI initiators that are connected to the gateway.</p>
     #[serde(rename = "Initiators")]
@@ -1720,13 +2986,25 @@ pub struct ListVolumeInitiatorsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVolumeRecoveryPointsInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for ListVolumeRecoveryPointsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVolumeRecoveryPointsOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1739,6 +3017,7 @@ pub struct ListVolumeRecoveryPointsOutput {
 
 /// <p><p>A JSON object that contains one or more of the following fields:</p> <ul> <li> <p> <a>ListVolumesInput$Limit</a> </p> </li> <li> <p> <a>ListVolumesInput$Marker</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVolumesInput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1753,9 +3032,33 @@ pub struct ListVolumesInput {
     pub marker: Option<String>,
 }
 
+impl Validate for ListVolumesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.gateway_arn {
+            errors.check_length(
+                &field_path(path, "gateway_arn"),
+                value.chars().count(),
+                Some(50),
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1000),
+            );
+        }
+    }
+}
+
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>ListVolumesOutput$Marker</a> </p> </li> <li> <p> <a>ListVolumesOutput$VolumeInfos</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVolumesOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1791,9 +3094,48 @@ pub struct NFSFileShareDefaults {
     pub owner_id: Option<i64>,
 }
 
+impl Validate for NFSFileShareDefaults {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_mode {
+            errors.check_length(
+                &field_path(path, "directory_mode"),
+                value.chars().count(),
+                Some(1),
+                Some(4),
+            );
+            errors.check_pattern(&field_path(path, "directory_mode"), &value, "^[0-7]{4}$");
+        }
+        if let Some(ref value) = self.file_mode {
+            errors.check_length(
+                &field_path(path, "file_mode"),
+                value.chars().count(),
+                Some(1),
+                Some(4),
+            );
+            errors.check_pattern(&field_path(path, "file_mode"), &value, "^[0-7]{4}$");
+        }
+        if let Some(ref value) = self.group_id {
+            errors.check_range(
+                &field_path(path, "group_id"),
+                *value,
+                None,
+                Some(4294967294),
+            );
+        }
+        if let Some(ref value) = self.owner_id {
+            errors.check_range(
+                &field_path(path, "owner_id"),
+                *value,
+                None,
+                Some(4294967294),
+            );
+        }
+    }
+}
+
 /// <p>The Unix file permissions and ownership information assigned, by default, to native S3 objects when file gateway discovers them in S3 buckets. This operation is only supported in file gateways.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NFSFileShareInfo {
     #[serde(rename = "ClientList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1859,7 +3201,7 @@ pub struct NFSFileShareInfo {
 
 /// <p>Describes a gateway's network interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NetworkInterface {
     /// <p>The Internet Protocol version 4 (IPv4) address of the interface.</p>
     #[serde(rename = "Ipv4Address")]
@@ -1876,13 +3218,25 @@ pub struct NetworkInterface {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotifyWhenUploadedInput {
     #[serde(rename = "FileShareARN")]
     pub file_share_arn: String,
 }
 
+impl Validate for NotifyWhenUploadedInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_share_arn"),
+            self.file_share_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct NotifyWhenUploadedOutput {
     #[serde(rename = "FileShareARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1894,6 +3248,7 @@ pub struct NotifyWhenUploadedOutput {
 
 /// <p>RefreshCacheInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RefreshCacheInput {
     /// <p>The Amazon Resource Name (ARN) of the file share you want to refresh.</p>
     #[serde(rename = "FileShareARN")]
@@ -1908,9 +3263,32 @@ pub struct RefreshCacheInput {
     pub recursive: Option<bool>,
 }
 
+impl Validate for RefreshCacheInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file_share_arn"),
+            self.file_share_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.folder_list {
+            errors.check_length(
+                &field_path(path, "folder_list"),
+                value.len(),
+                Some(1),
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "folder_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(1024));
+            }
+        }
+    }
+}
+
 /// <p>RefreshCacheOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RefreshCacheOutput {
     #[serde(rename = "FileShareARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1922,6 +3300,7 @@ pub struct RefreshCacheOutput {
 
 /// <p>RemoveTagsFromResourceInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveTagsFromResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the resource you want to remove the tags from.</p>
     #[serde(rename = "ResourceARN")]
@@ -1931,9 +3310,25 @@ pub struct RemoveTagsFromResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for RemoveTagsFromResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$");
+        }
+    }
+}
+
 /// <p>RemoveTagsFromResourceOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RemoveTagsFromResourceOutput {
     /// <p>The Amazon Resource Name (ARN) of the resource that the tags were removed from.</p>
     #[serde(rename = "ResourceARN")]
@@ -1942,13 +3337,25 @@ pub struct RemoveTagsFromResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetCacheInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for ResetCacheInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetCacheOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1957,6 +3364,7 @@ pub struct ResetCacheOutput {
 
 /// <p>RetrieveTapeArchiveInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetrieveTapeArchiveInput {
     /// <p>The Amazon Resource Name (ARN) of the gateway you want to retrieve the virtual tape to. Use the <a>ListGateways</a> operation to return a list of gateways for your account and AWS Region.</p> <p>You retrieve archived virtual tapes to only one gateway and the gateway must be a tape gateway.</p>
     #[serde(rename = "GatewayARN")]
@@ -1966,9 +3374,27 @@ pub struct RetrieveTapeArchiveInput {
     pub tape_arn: String,
 }
 
+impl Validate for RetrieveTapeArchiveInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 /// <p>RetrieveTapeArchiveOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RetrieveTapeArchiveOutput {
     /// <p>The Amazon Resource Name (ARN) of the retrieved virtual tape.</p>
     #[serde(rename = "TapeARN")]
@@ -1978,6 +3404,7 @@ pub struct RetrieveTapeArchiveOutput {
 
 /// <p>RetrieveTapeRecoveryPointInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RetrieveTapeRecoveryPointInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -1986,9 +3413,27 @@ pub struct RetrieveTapeRecoveryPointInput {
     pub tape_arn: String,
 }
 
+impl Validate for RetrieveTapeRecoveryPointInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "tape_arn"),
+            self.tape_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_pattern(&field_path(path, "tape_arn"), &self.tape_arn, "arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):storagegateway:[a-z\\-0-9]+:[0-9]+:tape\\/[0-9A-Z]{5,16}$");
+    }
+}
+
 /// <p>RetrieveTapeRecoveryPointOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RetrieveTapeRecoveryPointOutput {
     /// <p>The Amazon Resource Name (ARN) of the virtual tape for which the recovery point was retrieved.</p>
     #[serde(rename = "TapeARN")]
@@ -1998,7 +3443,7 @@ pub struct RetrieveTapeRecoveryPointOutput {
 
 /// <p>The Windows file permissions and ownership information assigned, by default, to native S3 objects when file gateway discovers them in S3 buckets. This operation is only supported for file gateways.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SMBFileShareInfo {
     /// <p>A list of users or groups in the Active Directory that have administrator rights to the file share. A group must be prefixed with the @ character. For example <code>@group1</code>. Can only be set if Authentication is set to <code>ActiveDirectory</code>.</p>
     #[serde(rename = "AdminUserList")]
@@ -2075,6 +3520,7 @@ pub struct SMBFileShareInfo {
 
 /// <p>SetLocalConsolePasswordInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetLocalConsolePasswordInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -2083,8 +3529,30 @@ pub struct SetLocalConsolePasswordInput {
     pub local_console_password: String,
 }
 
+impl Validate for SetLocalConsolePasswordInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "local_console_password"),
+            self.local_console_password.chars().count(),
+            Some(6),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "local_console_password"),
+            &self.local_console_password,
+            "^[ -~]+$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetLocalConsolePasswordOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2093,6 +3561,7 @@ pub struct SetLocalConsolePasswordOutput {
 
 /// <p>SetSMBGuestPasswordInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SetSMBGuestPasswordInput {
     /// <p>The Amazon Resource Name (ARN) of the file gateway the SMB file share is associated with.</p>
     #[serde(rename = "GatewayARN")]
@@ -2102,8 +3571,26 @@ pub struct SetSMBGuestPasswordInput {
     pub password: String,
 }
 
+impl Validate for SetSMBGuestPasswordInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(6),
+            Some(512),
+        );
+        errors.check_pattern(&field_path(path, "password"), &self.password, "^[ -~]+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SetSMBGuestPasswordOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2112,14 +3599,26 @@ pub struct SetSMBGuestPasswordOutput {
 
 /// <p>A JSON object containing the of the gateway to shut down.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ShutdownGatewayInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for ShutdownGatewayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway that was shut down.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ShutdownGatewayOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2128,14 +3627,26 @@ pub struct ShutdownGatewayOutput {
 
 /// <p>A JSON object containing the of the gateway to start.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartGatewayInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for StartGatewayInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway that was restarted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartGatewayOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2153,7 +3664,7 @@ pub struct StorageGatewayError {
 
 /// <p>Describes an iSCSI stored volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StorediSCSIVolume {
     /// <p>The date the volume was created. Volumes created prior to March 28, 2017 don’t have this time stamp.</p>
     #[serde(rename = "CreatedDate")]
@@ -2227,9 +3738,31 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "^([\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*)$",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 /// <p>Describes a virtual tape object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Tape {
     #[serde(rename = "KMSKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2274,7 +3807,7 @@ pub struct Tape {
 
 /// <p>Represents a virtual tape that is archived in the virtual tape shelf (VTS).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TapeArchive {
     /// <p>The time that the archiving of the virtual tape was completed.</p> <p>The default time stamp format is in the ISO8601 extended YYYY-MM-DD'T'HH:MM:SS'Z' format.</p>
     #[serde(rename = "CompletionTime")]
@@ -2319,7 +3852,7 @@ pub struct TapeArchive {
 
 /// <p>Describes a virtual tape.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TapeInfo {
     /// <p>The Amazon Resource Name (ARN) of the gateway. Use the <a>ListGateways</a> operation to return a list of gateways for your account and AWS Region.</p>
     #[serde(rename = "GatewayARN")]
@@ -2349,7 +3882,7 @@ pub struct TapeInfo {
 
 /// <p>Describes a recovery point.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TapeRecoveryPointInfo {
     /// <p>The Amazon Resource Name (ARN) of the virtual tape.</p>
     #[serde(rename = "TapeARN")]
@@ -2371,6 +3904,7 @@ pub struct TapeRecoveryPointInfo {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>UpdateBandwidthRateLimitInput$AverageDownloadRateLimitInBitsPerSec</a> </p> </li> <li> <p> <a>UpdateBandwidthRateLimitInput$AverageUploadRateLimitInBitsPerSec</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateBandwidthRateLimitInput {
     /// <p>The average download bandwidth rate limit in bits per second.</p>
     #[serde(rename = "AverageDownloadRateLimitInBitsPerSec")]
@@ -2384,9 +3918,36 @@ pub struct UpdateBandwidthRateLimitInput {
     pub gateway_arn: String,
 }
 
+impl Validate for UpdateBandwidthRateLimitInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.average_download_rate_limit_in_bits_per_sec {
+            errors.check_range(
+                &field_path(path, "average_download_rate_limit_in_bits_per_sec"),
+                *value,
+                Some(102400),
+                None,
+            );
+        }
+        if let Some(ref value) = self.average_upload_rate_limit_in_bits_per_sec {
+            errors.check_range(
+                &field_path(path, "average_upload_rate_limit_in_bits_per_sec"),
+                *value,
+                Some(51200),
+                None,
+            );
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway whose throttle information was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateBandwidthRateLimitOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2395,6 +3956,7 @@ pub struct UpdateBandwidthRateLimitOutput {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>UpdateChapCredentialsInput$InitiatorName</a> </p> </li> <li> <p> <a>UpdateChapCredentialsInput$SecretToAuthenticateInitiator</a> </p> </li> <li> <p> <a>UpdateChapCredentialsInput$SecretToAuthenticateTarget</a> </p> </li> <li> <p> <a>UpdateChapCredentialsInput$TargetARN</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateChapCredentialsInput {
     /// <p>The iSCSI initiator that connects to the target.</p>
     #[serde(rename = "InitiatorName")]
@@ -2411,9 +3973,45 @@ pub struct UpdateChapCredentialsInput {
     pub target_arn: String,
 }
 
+impl Validate for UpdateChapCredentialsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "initiator_name"),
+            self.initiator_name.chars().count(),
+            Some(1),
+            Some(255),
+        );
+        errors.check_pattern(
+            &field_path(path, "initiator_name"),
+            &self.initiator_name,
+            "[0-9a-z:.-]+",
+        );
+        errors.check_length(
+            &field_path(path, "secret_to_authenticate_initiator"),
+            self.secret_to_authenticate_initiator.chars().count(),
+            Some(1),
+            Some(100),
+        );
+        if let Some(ref value) = self.secret_to_authenticate_target {
+            errors.check_length(
+                &field_path(path, "secret_to_authenticate_target"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "target_arn"),
+            self.target_arn.chars().count(),
+            Some(50),
+            Some(800),
+        );
+    }
+}
+
 /// <p>A JSON object containing the following fields:</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateChapCredentialsOutput {
     /// <p>The iSCSI initiator that connects to the target. This is the same initiator name specified in the request.</p>
     #[serde(rename = "InitiatorName")]
@@ -2426,6 +4024,7 @@ pub struct UpdateChapCredentialsOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGatewayInformationInput {
     /// <p>The Amazon Resource Name (ARN) of the Amazon CloudWatch log group that you want to use to monitor and log events in the gateway. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/WhatIsCloudWatchLogs.html">What Is Amazon CloudWatch Logs?</a>.</p>
     #[serde(rename = "CloudWatchLogGroupARN")]
@@ -2442,9 +4041,49 @@ pub struct UpdateGatewayInformationInput {
     pub gateway_timezone: Option<String>,
 }
 
+impl Validate for UpdateGatewayInformationInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cloud_watch_log_group_arn {
+            errors.check_length(
+                &field_path(path, "cloud_watch_log_group_arn"),
+                value.chars().count(),
+                None,
+                Some(562),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.gateway_name {
+            errors.check_length(
+                &field_path(path, "gateway_name"),
+                value.chars().count(),
+                Some(2),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "gateway_name"),
+                &value,
+                "^[ -\\.0-\\[\\]-~]*[!-\\.0-\\[\\]-~][ -\\.0-\\[\\]-~]*$",
+            );
+        }
+        if let Some(ref value) = self.gateway_timezone {
+            errors.check_length(
+                &field_path(path, "gateway_timezone"),
+                value.chars().count(),
+                Some(3),
+                Some(10),
+            );
+        }
+    }
+}
+
 /// <p>A JSON object containing the ARN of the gateway that was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGatewayInformationOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2457,14 +4096,26 @@ pub struct UpdateGatewayInformationOutput {
 
 /// <p>A JSON object containing the of the gateway to update.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGatewaySoftwareNowInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
 }
 
+impl Validate for UpdateGatewaySoftwareNowInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway that was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGatewaySoftwareNowOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2473,6 +4124,7 @@ pub struct UpdateGatewaySoftwareNowOutput {
 
 /// <p><p>A JSON object containing the following fields:</p> <ul> <li> <p> <a>UpdateMaintenanceStartTimeInput$DayOfMonth</a> </p> </li> <li> <p> <a>UpdateMaintenanceStartTimeInput$DayOfWeek</a> </p> </li> <li> <p> <a>UpdateMaintenanceStartTimeInput$HourOfDay</a> </p> </li> <li> <p> <a>UpdateMaintenanceStartTimeInput$MinuteOfHour</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMaintenanceStartTimeInput {
     /// <p><p>The day of the month component of the maintenance start time represented as an ordinal number from 1 to 28, where 1 represents the first day of the month and 28 represents the last day of the month.</p> <note> <p>This value is only available for tape and volume gateways.</p> </note></p>
     #[serde(rename = "DayOfMonth")]
@@ -2492,9 +4144,38 @@ pub struct UpdateMaintenanceStartTimeInput {
     pub minute_of_hour: i64,
 }
 
+impl Validate for UpdateMaintenanceStartTimeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.day_of_month {
+            errors.check_range(&field_path(path, "day_of_month"), *value, Some(1), Some(28));
+        }
+        if let Some(ref value) = self.day_of_week {
+            errors.check_range(&field_path(path, "day_of_week"), *value, None, Some(6));
+        }
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        errors.check_range(
+            &field_path(path, "hour_of_day"),
+            self.hour_of_day,
+            None,
+            Some(23),
+        );
+        errors.check_range(
+            &field_path(path, "minute_of_hour"),
+            self.minute_of_hour,
+            None,
+            Some(59),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the gateway whose maintenance start time is updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMaintenanceStartTimeOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2503,6 +4184,7 @@ pub struct UpdateMaintenanceStartTimeOutput {
 
 /// <p>UpdateNFSFileShareInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateNFSFileShareInput {
     /// <p>The list of clients that are allowed to access the file gateway. The list must contain either valid IP addresses or valid CIDR blocks.</p>
     #[serde(rename = "ClientList")]
@@ -2549,9 +4231,60 @@ pub struct UpdateNFSFileShareInput {
     pub squash: Option<String>,
 }
 
+impl Validate for UpdateNFSFileShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_list {
+            errors.check_length(
+                &field_path(path, "client_list"),
+                value.len(),
+                Some(1),
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "client_list"), i);
+                errors.check_pattern(&item_path, &item, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])(\\/([0-9]|[1-2][0-9]|3[0-2]))?$");
+            }
+        }
+        if let Some(ref value) = self.default_storage_class {
+            errors.check_length(
+                &field_path(path, "default_storage_class"),
+                value.chars().count(),
+                Some(5),
+                Some(50),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "file_share_arn"),
+            self.file_share_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        if let Some(ref value) = self.nfs_file_share_defaults {
+            value.validate_at(&field_path(path, "nfs_file_share_defaults"), errors);
+        }
+        if let Some(ref value) = self.squash {
+            errors.check_length(
+                &field_path(path, "squash"),
+                value.chars().count(),
+                Some(5),
+                Some(15),
+            );
+        }
+    }
+}
+
 /// <p>UpdateNFSFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateNFSFileShareOutput {
     /// <p>The Amazon Resource Name (ARN) of the updated file share. </p>
     #[serde(rename = "FileShareARN")]
@@ -2561,6 +4294,7 @@ pub struct UpdateNFSFileShareOutput {
 
 /// <p>UpdateSMBFileShareInput</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSMBFileShareInput {
     /// <p>A list of users in the Active Directory that have administrator rights to the file share. A group must be prefixed with the @ character. For example <code>@group1</code>. Can only be set if Authentication is set to <code>ActiveDirectory</code>.</p>
     #[serde(rename = "AdminUserList")]
@@ -2611,9 +4345,73 @@ pub struct UpdateSMBFileShareInput {
     pub valid_user_list: Option<Vec<String>>,
 }
 
+impl Validate for UpdateSMBFileShareInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.admin_user_list {
+            errors.check_length(
+                &field_path(path, "admin_user_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "admin_user_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.default_storage_class {
+            errors.check_length(
+                &field_path(path, "default_storage_class"),
+                value.chars().count(),
+                Some(5),
+                Some(50),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "file_share_arn"),
+            self.file_share_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+        if let Some(ref value) = self.invalid_user_list {
+            errors.check_length(
+                &field_path(path, "invalid_user_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "invalid_user_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+        if let Some(ref value) = self.kms_key {
+            errors.check_length(
+                &field_path(path, "kms_key"),
+                value.chars().count(),
+                Some(7),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "kms_key"), &value, "(^arn:(aws(|-cn|-us-gov|-iso[A-Za-z0-9_-]*)):kms:([a-zA-Z0-9-]+):([0-9]+):(key|alias)/(\\S+)$)|(^alias/(\\S+)$)");
+        }
+        if let Some(ref value) = self.valid_user_list {
+            errors.check_length(
+                &field_path(path, "valid_user_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "valid_user_list"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(64));
+            }
+        }
+    }
+}
+
 /// <p>UpdateSMBFileShareOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSMBFileShareOutput {
     /// <p>The Amazon Resource Name (ARN) of the updated SMB file share. </p>
     #[serde(rename = "FileShareARN")]
@@ -2622,6 +4420,7 @@ pub struct UpdateSMBFileShareOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSMBSecurityStrategyInput {
     #[serde(rename = "GatewayARN")]
     pub gateway_arn: String,
@@ -2630,8 +4429,19 @@ pub struct UpdateSMBSecurityStrategyInput {
     pub smb_security_strategy: String,
 }
 
+impl Validate for UpdateSMBSecurityStrategyInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "gateway_arn"),
+            self.gateway_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSMBSecurityStrategyOutput {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2640,6 +4450,7 @@ pub struct UpdateSMBSecurityStrategyOutput {
 
 /// <p><p>A JSON object containing one or more of the following fields:</p> <ul> <li> <p> <a>UpdateSnapshotScheduleInput$Description</a> </p> </li> <li> <p> <a>UpdateSnapshotScheduleInput$RecurrenceInHours</a> </p> </li> <li> <p> <a>UpdateSnapshotScheduleInput$StartAt</a> </p> </li> <li> <p> <a>UpdateSnapshotScheduleInput$VolumeARN</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSnapshotScheduleInput {
     /// <p>Optional description of the snapshot that overwrites the existing description.</p>
     #[serde(rename = "Description")]
@@ -2660,9 +4471,41 @@ pub struct UpdateSnapshotScheduleInput {
     pub volume_arn: String,
 }
 
+impl Validate for UpdateSnapshotScheduleInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+        }
+        errors.check_range(
+            &field_path(path, "recurrence_in_hours"),
+            self.recurrence_in_hours,
+            Some(1),
+            Some(24),
+        );
+        errors.check_range(&field_path(path, "start_at"), self.start_at, None, Some(23));
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "volume_arn"),
+            self.volume_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>A JSON object containing the of the updated storage volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSnapshotScheduleOutput {
     /// <p>The Amazon Resource Name (ARN) of the volume. Use the <a>ListVolumes</a> operation to return a list of gateway volumes.</p>
     #[serde(rename = "VolumeARN")]
@@ -2671,6 +4514,7 @@ pub struct UpdateSnapshotScheduleOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVTLDeviceTypeInput {
     /// <p>The type of medium changer you want to select.</p> <p> Valid Values: "STK-L700", "AWS-Gateway-VTL"</p>
     #[serde(rename = "DeviceType")]
@@ -2680,9 +4524,26 @@ pub struct UpdateVTLDeviceTypeInput {
     pub vtl_device_arn: String,
 }
 
+impl Validate for UpdateVTLDeviceTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_type"),
+            self.device_type.chars().count(),
+            Some(2),
+            Some(50),
+        );
+        errors.check_length(
+            &field_path(path, "vtl_device_arn"),
+            self.vtl_device_arn.chars().count(),
+            Some(50),
+            Some(500),
+        );
+    }
+}
+
 /// <p>UpdateVTLDeviceTypeOutput</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVTLDeviceTypeOutput {
     /// <p>The Amazon Resource Name (ARN) of the medium changer you have selected.</p>
     #[serde(rename = "VTLDeviceARN")]
@@ -2692,7 +4553,7 @@ pub struct UpdateVTLDeviceTypeOutput {
 
 /// <p>Represents a device object associated with a tape gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VTLDevice {
     /// <p>A list of iSCSI information about a VTL device.</p>
     #[serde(rename = "DeviceiSCSIAttributes")]
@@ -2718,7 +4579,7 @@ pub struct VTLDevice {
 
 /// <p>Describes a storage volume object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VolumeInfo {
     #[serde(rename = "GatewayARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2751,7 +4612,7 @@ pub struct VolumeInfo {
 
 /// <p>Describes a storage volume recovery point object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VolumeRecoveryPointInfo {
     /// <p>The Amazon Resource Name (ARN) of the volume target.</p>
     #[serde(rename = "VolumeARN")]
@@ -2773,7 +4634,7 @@ pub struct VolumeRecoveryPointInfo {
 
 /// <p>Lists iSCSI information about a volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VolumeiSCSIAttributes {
     /// <p>Indicates whether mutual CHAP is enabled for the iSCSI target.</p>
     #[serde(rename = "ChapEnabled")]
@@ -2818,7 +4679,12 @@ impl ActivateGatewayError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2857,7 +4723,12 @@ impl AddCacheError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(AddCacheError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2900,7 +4771,12 @@ impl AddTagsToResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2941,7 +4817,12 @@ impl AddUploadBufferError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2984,7 +4865,12 @@ impl AddWorkingStorageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3025,7 +4911,12 @@ impl AssignTapePoolError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3064,7 +4955,12 @@ impl AttachVolumeError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(AttachVolumeError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3105,7 +5001,12 @@ impl CancelArchivalError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3146,7 +5047,12 @@ impl CancelRetrievalError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3189,7 +5095,12 @@ impl CreateCachediSCSIVolumeError {
                         CreateCachediSCSIVolumeError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3232,7 +5143,12 @@ impl CreateNFSFileShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3275,7 +5191,12 @@ impl CreateSMBFileShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3323,7 +5244,12 @@ impl CreateSnapshotError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3378,7 +5304,12 @@ impl CreateSnapshotFromVolumeRecoveryPointError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3422,7 +5353,12 @@ impl CreateStorediSCSIVolumeError {
                         CreateStorediSCSIVolumeError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3465,7 +5401,12 @@ impl CreateTapeWithBarcodeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3504,7 +5445,12 @@ impl CreateTapesError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(CreateTapesError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3547,7 +5493,12 @@ impl DeleteBandwidthRateLimitError {
                         DeleteBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3590,7 +5541,12 @@ impl DeleteChapCredentialsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3631,7 +5587,12 @@ impl DeleteFileShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3670,7 +5631,12 @@ impl DeleteGatewayError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(DeleteGatewayError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3713,7 +5679,12 @@ impl DeleteSnapshotScheduleError {
                         DeleteSnapshotScheduleError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3752,7 +5723,12 @@ impl DeleteTapeError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(DeleteTapeError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3795,7 +5771,12 @@ impl DeleteTapeArchiveError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3834,7 +5815,12 @@ impl DeleteVolumeError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(DeleteVolumeError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3879,7 +5865,12 @@ impl DescribeBandwidthRateLimitError {
                         DescribeBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3918,7 +5909,12 @@ impl DescribeCacheError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(DescribeCacheError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3963,7 +5959,12 @@ impl DescribeCachediSCSIVolumesError {
                         DescribeCachediSCSIVolumesError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4006,7 +6007,12 @@ impl DescribeChapCredentialsError {
                         DescribeChapCredentialsError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4051,7 +6057,12 @@ impl DescribeGatewayInformationError {
                         DescribeGatewayInformationError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4096,7 +6107,12 @@ impl DescribeMaintenanceStartTimeError {
                         DescribeMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4139,7 +6155,12 @@ impl DescribeNFSFileSharesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4182,7 +6203,12 @@ impl DescribeSMBFileSharesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4225,7 +6251,12 @@ impl DescribeSMBSettingsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4268,7 +6299,12 @@ impl DescribeSnapshotScheduleError {
                         DescribeSnapshotScheduleError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4313,7 +6349,12 @@ impl DescribeStorediSCSIVolumesError {
                         DescribeStorediSCSIVolumesError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4356,7 +6397,12 @@ impl DescribeTapeArchivesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4401,7 +6447,12 @@ impl DescribeTapeRecoveryPointsError {
                         DescribeTapeRecoveryPointsError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4440,7 +6491,12 @@ impl DescribeTapesError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(DescribeTapesError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4483,7 +6539,12 @@ impl DescribeUploadBufferError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4526,7 +6587,12 @@ impl DescribeVTLDevicesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4569,7 +6635,12 @@ impl DescribeWorkingStorageError {
                         DescribeWorkingStorageError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4608,7 +6679,12 @@ impl DetachVolumeError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(DetachVolumeError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4649,7 +6725,12 @@ impl DisableGatewayError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4688,7 +6769,12 @@ impl JoinDomainError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(JoinDomainError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4729,7 +6815,12 @@ impl ListFileSharesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4768,7 +6859,12 @@ impl ListGatewaysError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(ListGatewaysError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4809,7 +6905,12 @@ impl ListLocalDisksError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4852,7 +6953,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4891,7 +6997,12 @@ impl ListTapesError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(ListTapesError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4934,7 +7045,12 @@ impl ListVolumeInitiatorsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4977,7 +7093,12 @@ impl ListVolumeRecoveryPointsError {
                         ListVolumeRecoveryPointsError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5016,7 +7137,12 @@ impl ListVolumesError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(ListVolumesError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5059,7 +7185,12 @@ impl NotifyWhenUploadedError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5098,7 +7229,12 @@ impl RefreshCacheError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(RefreshCacheError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5141,7 +7277,12 @@ impl RemoveTagsFromResourceError {
                         RemoveTagsFromResourceError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5180,7 +7321,12 @@ impl ResetCacheError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(ResetCacheError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5223,7 +7369,12 @@ impl RetrieveTapeArchiveError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5266,7 +7417,12 @@ impl RetrieveTapeRecoveryPointError {
                         RetrieveTapeRecoveryPointError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5309,7 +7465,12 @@ impl SetLocalConsolePasswordError {
                         SetLocalConsolePasswordError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5352,7 +7513,12 @@ impl SetSMBGuestPasswordError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5393,7 +7559,12 @@ impl ShutdownGatewayError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5432,7 +7603,12 @@ impl StartGatewayError {
                 "InvalidGatewayRequestException" => {
                     return RusotoError::Service(StartGatewayError::InvalidGatewayRequest(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5475,7 +7651,12 @@ impl UpdateBandwidthRateLimitError {
                         UpdateBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5518,7 +7699,12 @@ impl UpdateChapCredentialsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5561,7 +7747,12 @@ impl UpdateGatewayInformationError {
                         UpdateGatewayInformationError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5604,7 +7795,12 @@ impl UpdateGatewaySoftwareNowError {
                         UpdateGatewaySoftwareNowError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5649,7 +7845,12 @@ impl UpdateMaintenanceStartTimeError {
                         UpdateMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5692,7 +7893,12 @@ impl UpdateNFSFileShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5735,7 +7941,12 @@ impl UpdateSMBFileShareError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5778,7 +7989,12 @@ impl UpdateSMBSecurityStrategyError {
                         UpdateSMBSecurityStrategyError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5821,7 +8037,12 @@ impl UpdateSnapshotScheduleError {
                         UpdateSnapshotScheduleError::InvalidGatewayRequest(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5864,7 +8085,12 @@ impl UpdateVTLDeviceTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6321,418 +8547,83 @@ pub trait StorageGateway {
         input: UpdateVTLDeviceTypeInput,
     ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError>;
 }
-/// A client for the AWS Storage Gateway API.
-#[derive(Clone)]
-pub struct StorageGatewayClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl StorageGatewayClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> StorageGatewayClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> StorageGatewayClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayClient {
-        StorageGatewayClient { client, region }
-    }
-}
-
-impl StorageGateway for StorageGatewayClient {
-    /// <p><p>Activates the gateway you previously deployed on your host. In the activation process, you specify information such as the AWS Region that you want to use for storing snapshots or tapes, the time zone for scheduled snapshots the gateway snapshot schedule window, an activation key, and a name for your gateway. The activation process also associates your gateway with your account; for more information, see <a>UpdateGatewayInformation</a>.</p> <note> <p>You must turn on the gateway VM before you can activate your gateway.</p> </note></p>
+const _: Option<&dyn StorageGateway> = None;
+impl<'a, T: StorageGateway + ?Sized> StorageGateway for &'a T {
     fn activate_gateway(
         &self,
         input: ActivateGatewayInput,
     ) -> RusotoFuture<ActivateGatewayOutput, ActivateGatewayError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ActivateGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ActivateGatewayOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ActivateGatewayError::from_response(response))),
-                )
-            }
-        })
+        (**self).activate_gateway(input)
     }
-
-    /// <p>Configures one or more gateway local disks as cache for a gateway. This operation is only supported in the cached volume, tape and file gateway type (see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/StorageGatewayConcepts.html">Storage Gateway Concepts</a>).</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add cache, and one or more disk IDs that you want to configure as cache.</p>
     fn add_cache(&self, input: AddCacheInput) -> RusotoFuture<AddCacheOutput, AddCacheError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.AddCache");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<AddCacheOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddCacheError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_cache(input)
     }
-
-    /// <p>Adds one or more tags to the specified resource. You use tags to add metadata to resources, which you can use to categorize these resources. For example, you can categorize resources by purpose, owner, environment, or team. Each tag consists of a key and a value, which you define. You can add tags to the following AWS Storage Gateway resources:</p> <ul> <li> <p>Storage gateways of all types</p> </li> <li> <p>Storage volumes</p> </li> <li> <p>Virtual tapes</p> </li> <li> <p>NFS and SMB file shares</p> </li> </ul> <p>You can create a maximum of 50 tags for each resource. Virtual tapes and storage volumes that are recovered to a new gateway maintain their tags.</p>
     fn add_tags_to_resource(
         &self,
         input: AddTagsToResourceInput,
     ) -> RusotoFuture<AddTagsToResourceOutput, AddTagsToResourceError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.AddTagsToResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddTagsToResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_tags_to_resource(input)
     }
-
-    /// <p>Configures one or more gateway local disks as upload buffer for a specified gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add upload buffer, and one or more disk IDs that you want to configure as upload buffer.</p>
     fn add_upload_buffer(
         &self,
         input: AddUploadBufferInput,
     ) -> RusotoFuture<AddUploadBufferOutput, AddUploadBufferError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.AddUploadBuffer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddUploadBufferOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddUploadBufferError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_upload_buffer(input)
     }
-
-    /// <p>Configures one or more gateway local disks as working storage for a gateway. This operation is only supported in the stored volume gateway type. This operation is deprecated in cached volume API version 20120630. Use <a>AddUploadBuffer</a> instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the <a>AddUploadBuffer</a> operation to add upload buffer to a stored volume gateway.</p> </note> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add working storage, and one or more disk IDs that you want to configure as working storage.</p>
     fn add_working_storage(
         &self,
         input: AddWorkingStorageInput,
     ) -> RusotoFuture<AddWorkingStorageOutput, AddWorkingStorageError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.AddWorkingStorage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddWorkingStorageOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AddWorkingStorageError::from_response(response))),
-                )
-            }
-        })
+        (**self).add_working_storage(input)
     }
-
-    /// <p>Assigns a tape to a tape pool for archiving. The tape assigned to a pool is archived in the S3 storage class that is associated with the pool. When you use your backup application to eject the tape, the tape is archived directly into the S3 storage class (Glacier or Deep Archive) that corresponds to the pool.</p> <p>Valid values: "GLACIER", "DEEP_ARCHIVE"</p>
     fn assign_tape_pool(
         &self,
         input: AssignTapePoolInput,
     ) -> RusotoFuture<AssignTapePoolOutput, AssignTapePoolError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.AssignTapePool");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssignTapePoolOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssignTapePoolError::from_response(response))),
-                )
-            }
-        })
+        (**self).assign_tape_pool(input)
     }
-
-    /// <p>Connects a volume to an iSCSI connection and then attaches the volume to the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
     fn attach_volume(
         &self,
         input: AttachVolumeInput,
     ) -> RusotoFuture<AttachVolumeOutput, AttachVolumeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.AttachVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AttachVolumeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AttachVolumeError::from_response(response))),
-                )
-            }
-        })
+        (**self).attach_volume(input)
     }
-
-    /// <p>Cancels archiving of a virtual tape to the virtual tape shelf (VTS) after the archiving process is initiated. This operation is only supported in the tape gateway type.</p>
     fn cancel_archival(
         &self,
         input: CancelArchivalInput,
     ) -> RusotoFuture<CancelArchivalOutput, CancelArchivalError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.CancelArchival");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelArchivalOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelArchivalError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_archival(input)
     }
-
-    /// <p>Cancels retrieval of a virtual tape from the virtual tape shelf (VTS) to a gateway after the retrieval process is initiated. The virtual tape is returned to the VTS. This operation is only supported in the tape gateway type.</p>
     fn cancel_retrieval(
         &self,
         input: CancelRetrievalInput,
     ) -> RusotoFuture<CancelRetrievalOutput, CancelRetrievalError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.CancelRetrieval");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CancelRetrievalOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CancelRetrievalError::from_response(response))),
-                )
-            }
-        })
+        (**self).cancel_retrieval(input)
     }
-
-    /// <p>Creates a cached volume on a specified cached volume gateway. This operation is only supported in the cached volume gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a cached volume. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note> <p>In the request, you must specify the gateway, size of the volume in bytes, the iSCSI target name, an IP address on which to expose the target, and a unique client token. In response, the gateway creates the volume and returns information about it. This information includes the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p> <p>Optionally, you can provide the ARN for an existing volume as the <code>SourceVolumeARN</code> for this cached volume, which creates an exact copy of the existing volume’s latest recovery point. The <code>VolumeSizeInBytes</code> value must be equal to or larger than the size of the copied volume, in bytes.</p>
     fn create_cachedi_scsi_volume(
         &self,
         input: CreateCachediSCSIVolumeInput,
     ) -> RusotoFuture<CreateCachediSCSIVolumeOutput, CreateCachediSCSIVolumeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.CreateCachediSCSIVolume",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCachediSCSIVolumeOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateCachediSCSIVolumeError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_cachedi_scsi_volume(input)
     }
-
-    /// <p><p>Creates a Network File System (NFS) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway exposes file shares using a NFS interface. This operation is only supported for file gateways.</p> <important> <p>File gateway requires AWS Security Token Service (AWS STS) to be activated to enable you create a file share. Make sure AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in the AWS Region, activate it. For information about how to activate AWS STS, see Activating and Deactivating AWS STS in an AWS Region in the AWS Identity and Access Management User Guide. </p> <p>File gateway does not support creating hard or symbolic links on a file share.</p> </important></p>
     fn create_nfs_file_share(
         &self,
         input: CreateNFSFileShareInput,
     ) -> RusotoFuture<CreateNFSFileShareOutput, CreateNFSFileShareError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.CreateNFSFileShare");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNFSFileShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateNFSFileShareError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_nfs_file_share(input)
     }
-
-    /// <p><p>Creates a Server Message Block (SMB) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway expose file shares using a SMB interface. This operation is only supported for file gateways.</p> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
     fn create_smb_file_share(
         &self,
         input: CreateSMBFileShareInput,
     ) -> RusotoFuture<CreateSMBFileShareOutput, CreateSMBFileShareError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.CreateSMBFileShare");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSMBFileShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSMBFileShareError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_smb_file_share(input)
     }
-
-    /// <p><p>Initiates a snapshot of a volume.</p> <p>AWS Storage Gateway provides the ability to back up point-in-time snapshots of your data to Amazon Simple Storage (S3) for durable off-site recovery, as well as import the data to an Amazon Elastic Block Store (EBS) volume in Amazon Elastic Compute Cloud (EC2). You can take snapshots of your gateway volume on a scheduled or ad hoc basis. This API enables you to take ad-hoc snapshot. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/managing-volumes.html#SchedulingSnapshot">Editing a Snapshot Schedule</a>.</p> <p>In the CreateSnapshot request you identify the volume by providing its Amazon Resource Name (ARN). You must also provide description for the snapshot. When AWS Storage Gateway takes the snapshot of specified volume, the snapshot and description appears in the AWS Storage Gateway Console. In response, AWS Storage Gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot. This operation is only supported in stored and cached volume gateway type.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, see DescribeSnapshots or DeleteSnapshot in the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_Operations.html">EC2 API reference</a>.</p> </note> <important> <p>Volume and snapshot IDs are changing to a longer length ID format. For more information, see the important note on the <a href="https://docs.aws.amazon.com/storagegateway/latest/APIReference/Welcome.html">Welcome</a> page.</p> </important></p>
     fn create_snapshot(
         &self,
         input: CreateSnapshotInput,
     ) -> RusotoFuture<CreateSnapshotOutput, CreateSnapshotError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.CreateSnapshot");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSnapshotOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSnapshotError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_snapshot(input)
     }
-
-    /// <p><p>Initiates a snapshot of a gateway from a volume recovery point. This operation is only supported in the cached volume gateway type.</p> <p>A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot. To get a list of volume recovery point for cached volume gateway, use <a>ListVolumeRecoveryPoints</a>.</p> <p>In the <code>CreateSnapshotFromVolumeRecoveryPoint</code> request, you identify the volume by providing its Amazon Resource Name (ARN). You must also provide a description for the snapshot. When the gateway takes a snapshot of the specified volume, the snapshot and its description appear in the AWS Storage Gateway console. In response, the gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
     fn create_snapshot_from_volume_recovery_point(
         &self,
         input: CreateSnapshotFromVolumeRecoveryPointInput,
@@ -6740,1616 +8631,3853 @@ impl StorageGateway for StorageGatewayClient {
         CreateSnapshotFromVolumeRecoveryPointOutput,
         CreateSnapshotFromVolumeRecoveryPointError,
     > {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.CreateSnapshotFromVolumeRecoveryPoint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSnapshotFromVolumeRecoveryPointOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSnapshotFromVolumeRecoveryPointError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).create_snapshot_from_volume_recovery_point(input)
     }
-
-    /// <p>Creates a volume on a specified gateway. This operation is only supported in the stored volume gateway type.</p> <p>The size of the volume to create is inferred from the disk size. You can choose to preserve existing data on the disk, create volume from an existing snapshot, or create an empty volume. If you choose to create an empty gateway volume, then any existing data on the disk is erased.</p> <p>In the request you must specify the gateway and the disk information on which you are creating the volume. In response, the gateway creates the volume and returns volume information such as the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p>
     fn create_storedi_scsi_volume(
         &self,
         input: CreateStorediSCSIVolumeInput,
     ) -> RusotoFuture<CreateStorediSCSIVolumeOutput, CreateStorediSCSIVolumeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.CreateStorediSCSIVolume",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateStorediSCSIVolumeOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateStorediSCSIVolumeError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_storedi_scsi_volume(input)
     }
-
-    /// <p><p>Creates a virtual tape by using your own barcode. You write data to the virtual tape and then archive the tape. A barcode is unique and can not be reused if it has already been used on a tape . This applies to barcodes used on deleted tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a virtual tape. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note></p>
     fn create_tape_with_barcode(
         &self,
         input: CreateTapeWithBarcodeInput,
     ) -> RusotoFuture<CreateTapeWithBarcodeOutput, CreateTapeWithBarcodeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.CreateTapeWithBarcode",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTapeWithBarcodeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateTapeWithBarcodeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_tape_with_barcode(input)
     }
-
-    /// <p><p>Creates one or more virtual tapes. You write data to the virtual tapes and then archive the tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create virtual tapes. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note></p>
     fn create_tapes(
         &self,
         input: CreateTapesInput,
     ) -> RusotoFuture<CreateTapesOutput, CreateTapesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTapesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTapesError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_tapes(input)
     }
-
-    /// <p>Deletes the bandwidth rate limits of a gateway. You can delete either the upload and download bandwidth rate limit, or you can delete both. If you delete only one of the limits, the other limit remains unchanged. To specify which gateway to work with, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
     fn delete_bandwidth_rate_limit(
         &self,
         input: DeleteBandwidthRateLimitInput,
     ) -> RusotoFuture<DeleteBandwidthRateLimitOutput, DeleteBandwidthRateLimitError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DeleteBandwidthRateLimit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBandwidthRateLimitOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteBandwidthRateLimitError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_bandwidth_rate_limit(input)
     }
-
-    /// <p>Deletes Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target and initiator pair.</p>
     fn delete_chap_credentials(
         &self,
         input: DeleteChapCredentialsInput,
     ) -> RusotoFuture<DeleteChapCredentialsOutput, DeleteChapCredentialsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DeleteChapCredentials",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteChapCredentialsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteChapCredentialsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_chap_credentials(input)
     }
-
-    /// <p>Deletes a file share from a file gateway. This operation is only supported for file gateways.</p>
     fn delete_file_share(
         &self,
         input: DeleteFileShareInput,
     ) -> RusotoFuture<DeleteFileShareOutput, DeleteFileShareError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteFileShare");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFileShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFileShareError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_file_share(input)
     }
-
-    /// <p><p>Deletes a gateway. To specify which gateway to delete, use the Amazon Resource Name (ARN) of the gateway in your request. The operation deletes the gateway; however, it does not delete the gateway virtual machine (VM) from your host computer.</p> <p>After you delete a gateway, you cannot reactivate it. Completed snapshots of the gateway volumes are not deleted upon deleting the gateway, however, pending snapshots will not complete. After you delete a gateway, your next step is to remove it from your environment.</p> <important> <p>You no longer pay software charges after the gateway is deleted; however, your existing Amazon EBS snapshots persist and you will continue to be billed for these snapshots. You can choose to remove all remaining Amazon EBS snapshots by canceling your Amazon EC2 subscription.  If you prefer not to cancel your Amazon EC2 subscription, you can delete your snapshots using the Amazon EC2 console. For more information, see the <a href="http://aws.amazon.com/storagegateway"> AWS Storage Gateway Detail Page</a>. </p> </important></p>
     fn delete_gateway(
         &self,
         input: DeleteGatewayInput,
     ) -> RusotoFuture<DeleteGatewayOutput, DeleteGatewayError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGatewayOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGatewayError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_gateway(input)
     }
-
-    /// <p><p>Deletes a snapshot of a volume.</p> <p>You can take snapshots of your gateway volumes on a scheduled or ad hoc basis. This API action enables you to delete a snapshot schedule for a volume. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/WorkingWithSnapshots.html">Working with Snapshots</a>. In the <code>DeleteSnapshotSchedule</code> request, you identify the volume by providing its Amazon Resource Name (ARN). This operation is only supported in stored and cached volume gateway types.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
     fn delete_snapshot_schedule(
         &self,
         input: DeleteSnapshotScheduleInput,
     ) -> RusotoFuture<DeleteSnapshotScheduleOutput, DeleteSnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DeleteSnapshotSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSnapshotScheduleOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteSnapshotScheduleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_snapshot_schedule(input)
     }
-
-    /// <p>Deletes the specified virtual tape. This operation is only supported in the tape gateway type.</p>
     fn delete_tape(
         &self,
         input: DeleteTapeInput,
     ) -> RusotoFuture<DeleteTapeOutput, DeleteTapeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTape");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTapeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTapeError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tape(input)
     }
-
-    /// <p>Deletes the specified virtual tape from the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p>
     fn delete_tape_archive(
         &self,
         input: DeleteTapeArchiveInput,
     ) -> RusotoFuture<DeleteTapeArchiveOutput, DeleteTapeArchiveError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapeArchive");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTapeArchiveOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTapeArchiveError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_tape_archive(input)
     }
-
-    /// <p>Deletes the specified storage volume that you previously created using the <a>CreateCachediSCSIVolume</a> or <a>CreateStorediSCSIVolume</a> API. This operation is only supported in the cached volume and stored volume types. For stored volume gateways, the local disk that was configured as the storage volume is not deleted. You can reuse the local disk to create another storage volume. </p> <p>Before you delete a volume, make sure there are no iSCSI connections to the volume you are deleting. You should also make sure there is no snapshot in progress. You can use the Amazon Elastic Compute Cloud (Amazon EC2) API to query snapshots on the volume you are deleting and check the snapshot status. For more information, go to <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/ApiReference-query-DescribeSnapshots.html">DescribeSnapshots</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> <p>In the request, you must provide the Amazon Resource Name (ARN) of the storage volume you want to delete.</p>
     fn delete_volume(
         &self,
         input: DeleteVolumeInput,
     ) -> RusotoFuture<DeleteVolumeOutput, DeleteVolumeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteVolumeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteVolumeError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_volume(input)
     }
-
-    /// <p>Returns the bandwidth rate limits of a gateway. By default, these limits are not set, which means no bandwidth rate limiting is in effect.</p> <p>This operation only returns a value for a bandwidth rate limit only if the limit is set. If no limits are set for the gateway, then this operation returns only the gateway ARN in the response body. To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
     fn describe_bandwidth_rate_limit(
         &self,
         input: DescribeBandwidthRateLimitInput,
     ) -> RusotoFuture<DescribeBandwidthRateLimitOutput, DescribeBandwidthRateLimitError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeBandwidthRateLimit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBandwidthRateLimitOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeBandwidthRateLimitError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_bandwidth_rate_limit(input)
     }
-
-    /// <p>Returns information about the cache of a gateway. This operation is only supported in the cached volume, tape and file gateway types.</p> <p>The response includes disk IDs that are configured as cache, and it includes the amount of cache allocated and used.</p>
     fn describe_cache(
         &self,
         input: DescribeCacheInput,
     ) -> RusotoFuture<DescribeCacheOutput, DescribeCacheError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DescribeCache");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCacheOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeCacheError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_cache(input)
     }
-
-    /// <p>Returns a description of the gateway volumes specified in the request. This operation is only supported in the cached volume gateway types.</p> <p>The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume Amazon Resource Name (ARN).</p>
     fn describe_cachedi_scsi_volumes(
         &self,
         input: DescribeCachediSCSIVolumesInput,
     ) -> RusotoFuture<DescribeCachediSCSIVolumesOutput, DescribeCachediSCSIVolumesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeCachediSCSIVolumes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCachediSCSIVolumesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCachediSCSIVolumesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_cachedi_scsi_volumes(input)
     }
-
-    /// <p>Returns an array of Challenge-Handshake Authentication Protocol (CHAP) credentials information for a specified iSCSI target, one for each target-initiator pair.</p>
     fn describe_chap_credentials(
         &self,
         input: DescribeChapCredentialsInput,
     ) -> RusotoFuture<DescribeChapCredentialsOutput, DescribeChapCredentialsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeChapCredentials",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeChapCredentialsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeChapCredentialsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_chap_credentials(input)
     }
-
-    /// <p>Returns metadata about a gateway such as its name, network interfaces, configured time zone, and the state (whether the gateway is running or not). To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
     fn describe_gateway_information(
         &self,
         input: DescribeGatewayInformationInput,
     ) -> RusotoFuture<DescribeGatewayInformationOutput, DescribeGatewayInformationError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeGatewayInformation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGatewayInformationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeGatewayInformationError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_gateway_information(input)
     }
-
-    /// <p>Returns your gateway's weekly maintenance start time including the day and time of the week. Note that values are in terms of the gateway's time zone.</p>
     fn describe_maintenance_start_time(
         &self,
         input: DescribeMaintenanceStartTimeInput,
     ) -> RusotoFuture<DescribeMaintenanceStartTimeOutput, DescribeMaintenanceStartTimeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeMaintenanceStartTime",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeMaintenanceStartTimeOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeMaintenanceStartTimeError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_maintenance_start_time(input)
     }
-
-    /// <p>Gets a description for one or more Network File System (NFS) file shares from a file gateway. This operation is only supported for file gateways.</p>
     fn describe_nfs_file_shares(
         &self,
         input: DescribeNFSFileSharesInput,
     ) -> RusotoFuture<DescribeNFSFileSharesOutput, DescribeNFSFileSharesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeNFSFileShares",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeNFSFileSharesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeNFSFileSharesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_nfs_file_shares(input)
     }
-
-    /// <p>Gets a description for one or more Server Message Block (SMB) file shares from a file gateway. This operation is only supported for file gateways.</p>
     fn describe_smb_file_shares(
         &self,
         input: DescribeSMBFileSharesInput,
     ) -> RusotoFuture<DescribeSMBFileSharesOutput, DescribeSMBFileSharesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeSMBFileShares",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSMBFileSharesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeSMBFileSharesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_smb_file_shares(input)
     }
-
-    /// <p>Gets a description of a Server Message Block (SMB) file share settings from a file gateway. This operation is only supported for file gateways.</p>
     fn describe_smb_settings(
         &self,
         input: DescribeSMBSettingsInput,
     ) -> RusotoFuture<DescribeSMBSettingsOutput, DescribeSMBSettingsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeSMBSettings",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSMBSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeSMBSettingsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_smb_settings(input)
     }
-
-    /// <p>Describes the snapshot schedule for the specified gateway volume. The snapshot schedule information includes intervals at which snapshots are automatically initiated on the volume. This operation is only supported in the cached volume and stored volume types.</p>
     fn describe_snapshot_schedule(
         &self,
         input: DescribeSnapshotScheduleInput,
     ) -> RusotoFuture<DescribeSnapshotScheduleOutput, DescribeSnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeSnapshotSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSnapshotScheduleOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeSnapshotScheduleError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_snapshot_schedule(input)
     }
-
-    /// <p>Returns the description of the gateway volumes specified in the request. The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume ARNs. This operation is only supported in stored volume gateway type.</p>
     fn describe_storedi_scsi_volumes(
         &self,
         input: DescribeStorediSCSIVolumesInput,
     ) -> RusotoFuture<DescribeStorediSCSIVolumesOutput, DescribeStorediSCSIVolumesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeStorediSCSIVolumes",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeStorediSCSIVolumesOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeStorediSCSIVolumesError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_storedi_scsi_volumes(input)
     }
-
-    /// <p>Returns a description of specified virtual tapes in the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p> <p>If a specific <code>TapeARN</code> is not specified, AWS Storage Gateway returns a description of all virtual tapes found in the VTS associated with your account.</p>
     fn describe_tape_archives(
         &self,
         input: DescribeTapeArchivesInput,
     ) -> RusotoFuture<DescribeTapeArchivesOutput, DescribeTapeArchivesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeTapeArchives",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTapeArchivesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeTapeArchivesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_tape_archives(input)
     }
-
-    /// <p>Returns a list of virtual tape recovery points that are available for the specified tape gateway.</p> <p>A recovery point is a point-in-time view of a virtual tape at which all the data on the virtual tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway. This operation is only supported in the tape gateway type.</p>
     fn describe_tape_recovery_points(
         &self,
         input: DescribeTapeRecoveryPointsInput,
     ) -> RusotoFuture<DescribeTapeRecoveryPointsOutput, DescribeTapeRecoveryPointsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeTapeRecoveryPoints",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTapeRecoveryPointsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeTapeRecoveryPointsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_tape_recovery_points(input)
     }
-
-    /// <p>Returns a description of the specified Amazon Resource Name (ARN) of virtual tapes. If a <code>TapeARN</code> is not specified, returns a description of all virtual tapes associated with the specified gateway. This operation is only supported in the tape gateway type.</p>
     fn describe_tapes(
         &self,
         input: DescribeTapesInput,
     ) -> RusotoFuture<DescribeTapesOutput, DescribeTapesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DescribeTapes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTapesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTapesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_tapes(input)
     }
-
-    /// <p>Returns information about the upload buffer of a gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>The response includes disk IDs that are configured as upload buffer space, and it includes the amount of upload buffer space allocated and used.</p>
     fn describe_upload_buffer(
         &self,
         input: DescribeUploadBufferInput,
     ) -> RusotoFuture<DescribeUploadBufferOutput, DescribeUploadBufferError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeUploadBuffer",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUploadBufferOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeUploadBufferError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_upload_buffer(input)
     }
-
-    /// <p>Returns a description of virtual tape library (VTL) devices for the specified tape gateway. In the response, AWS Storage Gateway returns VTL device information.</p> <p>This operation is only supported in the tape gateway type.</p>
     fn describe_vtl_devices(
         &self,
         input: DescribeVTLDevicesInput,
     ) -> RusotoFuture<DescribeVTLDevicesOutput, DescribeVTLDevicesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DescribeVTLDevices");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeVTLDevicesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeVTLDevicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_vtl_devices(input)
     }
-
-    /// <p>Returns information about the working storage of a gateway. This operation is only supported in the stored volumes gateway type. This operation is deprecated in cached volumes API version (20120630). Use DescribeUploadBuffer instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the DescribeUploadBuffer operation to add upload buffer to a stored volume gateway.</p> </note> <p>The response includes disk IDs that are configured as working storage, and it includes the amount of working storage allocated and used.</p>
     fn describe_working_storage(
         &self,
         input: DescribeWorkingStorageInput,
     ) -> RusotoFuture<DescribeWorkingStorageOutput, DescribeWorkingStorageError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.DescribeWorkingStorage",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkingStorageOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeWorkingStorageError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_working_storage(input)
     }
-
-    /// <p>Disconnects a volume from an iSCSI connection and then detaches the volume from the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
     fn detach_volume(
         &self,
         input: DetachVolumeInput,
     ) -> RusotoFuture<DetachVolumeOutput, DetachVolumeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DetachVolume");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetachVolumeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DetachVolumeError::from_response(response))),
-                )
-            }
-        })
+        (**self).detach_volume(input)
     }
-
-    /// <p><p>Disables a tape gateway when the gateway is no longer functioning. For example, if your gateway VM is damaged, you can disable the gateway so you can recover virtual tapes.</p> <p>Use this operation for a tape gateway that is not reachable or not functioning. This operation is only supported in the tape gateway type.</p> <important> <p>Once a gateway is disabled it cannot be enabled.</p> </important></p>
     fn disable_gateway(
         &self,
         input: DisableGatewayInput,
     ) -> RusotoFuture<DisableGatewayOutput, DisableGatewayError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.DisableGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisableGatewayOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisableGatewayError::from_response(response))),
-                )
-            }
-        })
+        (**self).disable_gateway(input)
     }
-
-    /// <p>Adds a file gateway to an Active Directory domain. This operation is only supported for file gateways that support the SMB file protocol.</p>
     fn join_domain(
         &self,
         input: JoinDomainInput,
     ) -> RusotoFuture<JoinDomainOutput, JoinDomainError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.JoinDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<JoinDomainOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(JoinDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).join_domain(input)
     }
-
-    /// <p>Gets a list of the file shares for a specific file gateway, or the list of file shares that belong to the calling user account. This operation is only supported for file gateways.</p>
     fn list_file_shares(
         &self,
         input: ListFileSharesInput,
     ) -> RusotoFuture<ListFileSharesOutput, ListFileSharesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ListFileShares");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFileSharesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFileSharesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_file_shares(input)
     }
-
-    /// <p>Lists gateways owned by an AWS account in an AWS Region specified in the request. The returned list is ordered by gateway Amazon Resource Name (ARN).</p> <p>By default, the operation returns a maximum of 100 gateways. This operation supports pagination that allows you to optionally reduce the number of gateways returned in a response.</p> <p>If you have more gateways than are returned in a response (that is, the response returns only a truncated list of your gateways), the response contains a marker that you can specify in your next request to fetch the next page of gateways.</p>
     fn list_gateways(
         &self,
         input: ListGatewaysInput,
     ) -> RusotoFuture<ListGatewaysOutput, ListGatewaysError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ListGateways");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGatewaysOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGatewaysError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_gateways(input)
     }
-
-    /// <p>Returns a list of the gateway's local disks. To specify which gateway to describe, you use the Amazon Resource Name (ARN) of the gateway in the body of the request.</p> <p>The request returns a list of all disks, specifying which are configured as working storage, cache storage, or stored volume or not configured at all. The response includes a <code>DiskStatus</code> field. This field can have a value of present (the disk is available to use), missing (the disk is no longer connected to the gateway), or mismatch (the disk node is occupied by a disk that has incorrect metadata or the disk content is corrupted).</p>
     fn list_local_disks(
         &self,
         input: ListLocalDisksInput,
     ) -> RusotoFuture<ListLocalDisksOutput, ListLocalDisksError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ListLocalDisks");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLocalDisksOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListLocalDisksError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_local_disks(input)
     }
-
-    /// <p>Lists the tags that have been added to the specified resource. This operation is only supported in the cached volume, stored volume and tape gateway type.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.ListTagsForResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Lists virtual tapes in your virtual tape library (VTL) and your virtual tape shelf (VTS). You specify the tapes to list by specifying one or more tape Amazon Resource Names (ARNs). If you don't specify a tape ARN, the operation lists all virtual tapes in both your VTL and VTS.</p> <p>This operation supports pagination. By default, the operation returns a maximum of up to 100 tapes. You can optionally specify the <code>Limit</code> parameter in the body to limit the number of tapes in the response. If the number of tapes returned in the response is truncated, the response includes a <code>Marker</code> element that you can use in your subsequent request to retrieve the next set of tapes. This operation is only supported in the tape gateway type.</p>
     fn list_tapes(&self, input: ListTapesInput) -> RusotoFuture<ListTapesOutput, ListTapesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ListTapes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ListTapesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTapesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_tapes(input)
     }
-
-    /// <p>Lists iSCSI initiators that are connected to a volume. You can use this operation to determine whether a volume is being used or not. This operation is only supported in the cached volume and stored volume gateway types.</p>
     fn list_volume_initiators(
         &self,
         input: ListVolumeInitiatorsInput,
     ) -> RusotoFuture<ListVolumeInitiatorsOutput, ListVolumeInitiatorsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.ListVolumeInitiators",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVolumeInitiatorsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListVolumeInitiatorsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_volume_initiators(input)
     }
-
-    /// <p>Lists the recovery points for a specified gateway. This operation is only supported in the cached volume gateway type.</p> <p>Each cache volume has one recovery point. A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot or clone a new cached volume from a source volume. To create a snapshot from a volume recovery point use the <a>CreateSnapshotFromVolumeRecoveryPoint</a> operation.</p>
     fn list_volume_recovery_points(
         &self,
         input: ListVolumeRecoveryPointsInput,
     ) -> RusotoFuture<ListVolumeRecoveryPointsOutput, ListVolumeRecoveryPointsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.ListVolumeRecoveryPoints",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVolumeRecoveryPointsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListVolumeRecoveryPointsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_volume_recovery_points(input)
     }
-
-    /// <p>Lists the iSCSI stored volumes of a gateway. Results are sorted by volume ARN. The response includes only the volume ARNs. If you want additional volume information, use the <a>DescribeStorediSCSIVolumes</a> or the <a>DescribeCachediSCSIVolumes</a> API.</p> <p>The operation supports pagination. By default, the operation returns a maximum of up to 100 volumes. You can optionally specify the <code>Limit</code> field in the body to limit the number of volumes in the response. If the number of volumes returned in the response is truncated, the response includes a Marker field. You can use this Marker value in your subsequent request to retrieve the next set of volumes. This operation is only supported in the cached volume and stored volume gateway types.</p>
     fn list_volumes(
         &self,
         input: ListVolumesInput,
     ) -> RusotoFuture<ListVolumesOutput, ListVolumesError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ListVolumes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVolumesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListVolumesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_volumes(input)
     }
-
-    /// <p>Sends you notification through CloudWatch Events when all files written to your file share have been uploaded to Amazon S3.</p> <p>AWS Storage Gateway can send a notification through Amazon CloudWatch Events when all files written to your file share up to that point in time have been uploaded to Amazon S3. These files include files written to the file share up to the time that you make a request for notification. When the upload is done, Storage Gateway sends you notification through an Amazon CloudWatch Event. You can configure CloudWatch Events to send the notification through event targets such as Amazon SNS or AWS Lambda function. This operation is only supported for file gateways.</p> <p>For more information, see Getting File Upload Notification in the Storage Gateway User Guide (https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-upload-notification). </p>
     fn notify_when_uploaded(
         &self,
         input: NotifyWhenUploadedInput,
     ) -> RusotoFuture<NotifyWhenUploadedOutput, NotifyWhenUploadedError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.NotifyWhenUploaded");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<NotifyWhenUploadedOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(NotifyWhenUploadedError::from_response(response))),
-                )
-            }
-        })
+        (**self).notify_when_uploaded(input)
     }
-
-    /// <p>Refreshes the cache for the specified file share. This operation finds objects in the Amazon S3 bucket that were added, removed or replaced since the gateway last listed the bucket's contents and cached the results. This operation is only supported in the file gateway type. You can subscribe to be notified through an Amazon CloudWatch event when your RefreshCache operation completes. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting Notified About File Operations</a>.</p> <p>When this API is called, it only initiates the refresh operation. When the API call completes and returns a success code, it doesn't necessarily mean that the file refresh has completed. You should use the refresh-complete notification to determine that the operation has completed before you check for new files on the gateway file share. You can subscribe to be notified through an CloudWatch event when your <code>RefreshCache</code> operation completes. </p>
     fn refresh_cache(
         &self,
         input: RefreshCacheInput,
     ) -> RusotoFuture<RefreshCacheOutput, RefreshCacheError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.RefreshCache");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RefreshCacheOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RefreshCacheError::from_response(response))),
-                )
-            }
-        })
+        (**self).refresh_cache(input)
     }
-
-    /// <p>Removes one or more tags from the specified resource. This operation is only supported in the cached volume, stored volume and tape gateway types.</p>
     fn remove_tags_from_resource(
         &self,
         input: RemoveTagsFromResourceInput,
     ) -> RusotoFuture<RemoveTagsFromResourceOutput, RemoveTagsFromResourceError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.RemoveTagsFromResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RemoveTagsFromResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RemoveTagsFromResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).remove_tags_from_resource(input)
     }
-
-    /// <p><p>Resets all cache disks that have encountered a error and makes the disks available for reconfiguration as cache storage. If your cache disk encounters a error, the gateway prevents read and write operations on virtual tapes in the gateway. For example, an error can occur when a disk is corrupted or removed from the gateway. When a cache is reset, the gateway loses its cache storage. At this point you can reconfigure the disks as cache disks. This operation is only supported in the cached volume and tape types.</p> <important> <p>If the cache disk you are resetting contains data that has not been uploaded to Amazon S3 yet, that data can be lost. After you reset cache disks, there will be no configured cache disks left in the gateway, so you must configure at least one new cache disk for your gateway to function properly.</p> </important></p>
     fn reset_cache(
         &self,
         input: ResetCacheInput,
     ) -> RusotoFuture<ResetCacheOutput, ResetCacheError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ResetCache");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetCacheOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResetCacheError::from_response(response))),
-                )
-            }
-        })
+        (**self).reset_cache(input)
     }
-
-    /// <p>Retrieves an archived virtual tape from the virtual tape shelf (VTS) to a tape gateway. Virtual tapes archived in the VTS are not associated with any gateway. However after a tape is retrieved, it is associated with a gateway, even though it is also listed in the VTS, that is, archive. This operation is only supported in the tape gateway type.</p> <p>Once a tape is successfully retrieved to a gateway, it cannot be retrieved again to another gateway. You must archive the tape again before you can retrieve it to another gateway. This operation is only supported in the tape gateway type.</p>
     fn retrieve_tape_archive(
         &self,
         input: RetrieveTapeArchiveInput,
     ) -> RusotoFuture<RetrieveTapeArchiveOutput, RetrieveTapeArchiveError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.RetrieveTapeArchive",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RetrieveTapeArchiveOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RetrieveTapeArchiveError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).retrieve_tape_archive(input)
     }
-
-    /// <p><p>Retrieves the recovery point for the specified virtual tape. This operation is only supported in the tape gateway type.</p> <p>A recovery point is a point in time view of a virtual tape at which all the data on the tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway.</p> <note> <p>The virtual tape can be retrieved to only one gateway. The retrieved tape is read-only. The virtual tape can be retrieved to only a tape gateway. There is no charge for retrieving recovery points.</p> </note></p>
     fn retrieve_tape_recovery_point(
         &self,
         input: RetrieveTapeRecoveryPointInput,
     ) -> RusotoFuture<RetrieveTapeRecoveryPointOutput, RetrieveTapeRecoveryPointError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.RetrieveTapeRecoveryPoint",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RetrieveTapeRecoveryPointOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RetrieveTapeRecoveryPointError::from_response(response))
-                }))
-            }
-        })
+        (**self).retrieve_tape_recovery_point(input)
     }
-
-    /// <p>Sets the password for your VM local console. When you log in to the local console for the first time, you log in to the VM with the default credentials. We recommend that you set a new password. You don't need to know the default password to set a new password.</p>
     fn set_local_console_password(
         &self,
         input: SetLocalConsolePasswordInput,
     ) -> RusotoFuture<SetLocalConsolePasswordOutput, SetLocalConsolePasswordError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.SetLocalConsolePassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetLocalConsolePasswordOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SetLocalConsolePasswordError::from_response(response))
-                }))
-            }
-        })
+        (**self).set_local_console_password(input)
     }
-
-    /// <p>Sets the password for the guest user <code>smbguest</code>. The <code>smbguest</code> user is the user when the authentication method for the file share is set to <code>GuestAccess</code>.</p>
     fn set_smb_guest_password(
         &self,
         input: SetSMBGuestPasswordInput,
     ) -> RusotoFuture<SetSMBGuestPasswordOutput, SetSMBGuestPasswordError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.SetSMBGuestPassword",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SetSMBGuestPasswordOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(SetSMBGuestPasswordError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).set_smb_guest_password(input)
     }
-
-    /// <p>Shuts down a gateway. To specify which gateway to shut down, use the Amazon Resource Name (ARN) of the gateway in the body of your request.</p> <p>The operation shuts down the gateway service component running in the gateway's virtual machine (VM) and not the host VM.</p> <note> <p>If you want to shut down the VM, it is recommended that you first shut down the gateway component in the VM to avoid unpredictable conditions.</p> </note> <p>After the gateway is shutdown, you cannot call any other API except <a>StartGateway</a>, <a>DescribeGatewayInformation</a>, and <a>ListGateways</a>. For more information, see <a>ActivateGateway</a>. Your applications cannot read from or write to the gateway's storage volumes, and there are no snapshots taken.</p> <note> <p>When you make a shutdown request, you will get a <code>200 OK</code> success response immediately. However, it might take some time for the gateway to shut down. You can call the <a>DescribeGatewayInformation</a> API to check the status. For more information, see <a>ActivateGateway</a>.</p> </note> <p>If do not intend to use the gateway again, you must delete the gateway (using <a>DeleteGateway</a>) to no longer pay software charges associated with the gateway.</p>
     fn shutdown_gateway(
         &self,
         input: ShutdownGatewayInput,
     ) -> RusotoFuture<ShutdownGatewayOutput, ShutdownGatewayError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.ShutdownGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ShutdownGatewayOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ShutdownGatewayError::from_response(response))),
-                )
-            }
-        })
+        (**self).shutdown_gateway(input)
     }
-
-    /// <p>Starts a gateway that you previously shut down (see <a>ShutdownGateway</a>). After the gateway starts, you can then make other API calls, your applications can read from or write to the gateway's storage volumes and you will be able to take snapshot backups.</p> <note> <p>When you make a request, you will get a 200 OK success response immediately. However, it might take some time for the gateway to be ready. You should call <a>DescribeGatewayInformation</a> and check the status before making any additional API calls. For more information, see <a>ActivateGateway</a>.</p> </note> <p>To specify which gateway to start, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
     fn start_gateway(
         &self,
         input: StartGatewayInput,
     ) -> RusotoFuture<StartGatewayOutput, StartGatewayError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.StartGateway");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartGatewayOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartGatewayError::from_response(response))),
-                )
-            }
-        })
+        (**self).start_gateway(input)
     }
-
-    /// <p>Updates the bandwidth rate limits of a gateway. You can update both the upload and download bandwidth rate limit or specify only one of the two. If you don't set a bandwidth rate limit, the existing rate limit remains.</p> <p>By default, a gateway's bandwidth rate limits are not set. If you don't set any limit, the gateway does not have any limitations on its bandwidth usage and could potentially use the maximum available bandwidth.</p> <p>To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
     fn update_bandwidth_rate_limit(
         &self,
         input: UpdateBandwidthRateLimitInput,
     ) -> RusotoFuture<UpdateBandwidthRateLimitOutput, UpdateBandwidthRateLimitError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateBandwidthRateLimit",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateBandwidthRateLimitOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateBandwidthRateLimitError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_bandwidth_rate_limit(input)
     }
-
-    /// <p><p>Updates the Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target. By default, a gateway does not have CHAP enabled; however, for added security, you might use it.</p> <important> <p>When you update CHAP credentials, all existing connections on the target are closed and initiators must reconnect with the new credentials.</p> </important></p>
     fn update_chap_credentials(
         &self,
         input: UpdateChapCredentialsInput,
     ) -> RusotoFuture<UpdateChapCredentialsOutput, UpdateChapCredentialsError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateChapCredentials",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateChapCredentialsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateChapCredentialsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).update_chap_credentials(input)
     }
-
-    /// <p><p>Updates a gateway&#39;s metadata, which includes the gateway&#39;s name and time zone. To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p> <note> <p>For Gateways activated after September 2, 2015, the gateway&#39;s ARN contains the gateway ID rather than the gateway name. However, changing the name of the gateway has no effect on the gateway&#39;s ARN.</p> </note></p>
     fn update_gateway_information(
         &self,
         input: UpdateGatewayInformationInput,
     ) -> RusotoFuture<UpdateGatewayInformationOutput, UpdateGatewayInformationError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateGatewayInformation",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGatewayInformationOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateGatewayInformationError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_gateway_information(input)
     }
-
-    /// <p><p>Updates the gateway virtual machine (VM) software. The request immediately triggers the software update.</p> <note> <p>When you make this request, you get a <code>200 OK</code> success response immediately. However, it might take some time for the update to complete. You can call <a>DescribeGatewayInformation</a> to verify the gateway is in the <code>STATE_RUNNING</code> state.</p> </note> <important> <p>A software update forces a system restart of your gateway. You can minimize the chance of any disruption to your applications by increasing your iSCSI Initiators&#39; timeouts. For more information about increasing iSCSI Initiator timeouts for Windows and Linux, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorWindowsClient.html#CustomizeWindowsiSCSISettings">Customizing Your Windows iSCSI Settings</a> and <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorRedHatClient.html#CustomizeLinuxiSCSISettings">Customizing Your Linux iSCSI Settings</a>, respectively.</p> </important></p>
     fn update_gateway_software_now(
         &self,
         input: UpdateGatewaySoftwareNowInput,
     ) -> RusotoFuture<UpdateGatewaySoftwareNowOutput, UpdateGatewaySoftwareNowError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateGatewaySoftwareNow",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGatewaySoftwareNowOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateGatewaySoftwareNowError::from_response(response))
-                }))
-            }
-        })
+        (**self).update_gateway_software_now(input)
     }
-
-    /// <p>Updates a gateway's weekly maintenance start time information, including day and time of the week. The maintenance time is the time in your gateway's time zone.</p>
     fn update_maintenance_start_time(
         &self,
         input: UpdateMaintenanceStartTimeInput,
     ) -> RusotoFuture<UpdateMaintenanceStartTimeOutput, UpdateMaintenanceStartTimeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
+        (**self).update_maintenance_start_time(input)
+    }
+    fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> RusotoFuture<UpdateNFSFileShareOutput, UpdateNFSFileShareError> {
+        (**self).update_nfs_file_share(input)
+    }
+    fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> RusotoFuture<UpdateSMBFileShareOutput, UpdateSMBFileShareError> {
+        (**self).update_smb_file_share(input)
+    }
+    fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> RusotoFuture<UpdateSMBSecurityStrategyOutput, UpdateSMBSecurityStrategyError> {
+        (**self).update_smb_security_strategy(input)
+    }
+    fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> RusotoFuture<UpdateSnapshotScheduleOutput, UpdateSnapshotScheduleError> {
+        (**self).update_snapshot_schedule(input)
+    }
+    fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError> {
+        (**self).update_vtl_device_type(input)
+    }
+}
+impl<T: StorageGateway + ?Sized> StorageGateway for Box<T> {
+    fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> RusotoFuture<ActivateGatewayOutput, ActivateGatewayError> {
+        (**self).activate_gateway(input)
+    }
+    fn add_cache(&self, input: AddCacheInput) -> RusotoFuture<AddCacheOutput, AddCacheError> {
+        (**self).add_cache(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> RusotoFuture<AddTagsToResourceOutput, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> RusotoFuture<AddUploadBufferOutput, AddUploadBufferError> {
+        (**self).add_upload_buffer(input)
+    }
+    fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> RusotoFuture<AddWorkingStorageOutput, AddWorkingStorageError> {
+        (**self).add_working_storage(input)
+    }
+    fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> RusotoFuture<AssignTapePoolOutput, AssignTapePoolError> {
+        (**self).assign_tape_pool(input)
+    }
+    fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> RusotoFuture<AttachVolumeOutput, AttachVolumeError> {
+        (**self).attach_volume(input)
+    }
+    fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> RusotoFuture<CancelArchivalOutput, CancelArchivalError> {
+        (**self).cancel_archival(input)
+    }
+    fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> RusotoFuture<CancelRetrievalOutput, CancelRetrievalError> {
+        (**self).cancel_retrieval(input)
+    }
+    fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateCachediSCSIVolumeOutput, CreateCachediSCSIVolumeError> {
+        (**self).create_cachedi_scsi_volume(input)
+    }
+    fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> RusotoFuture<CreateNFSFileShareOutput, CreateNFSFileShareError> {
+        (**self).create_nfs_file_share(input)
+    }
+    fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> RusotoFuture<CreateSMBFileShareOutput, CreateSMBFileShareError> {
+        (**self).create_smb_file_share(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> RusotoFuture<CreateSnapshotOutput, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> RusotoFuture<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        CreateSnapshotFromVolumeRecoveryPointError,
+    > {
+        (**self).create_snapshot_from_volume_recovery_point(input)
+    }
+    fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateStorediSCSIVolumeOutput, CreateStorediSCSIVolumeError> {
+        (**self).create_storedi_scsi_volume(input)
+    }
+    fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> RusotoFuture<CreateTapeWithBarcodeOutput, CreateTapeWithBarcodeError> {
+        (**self).create_tape_with_barcode(input)
+    }
+    fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> RusotoFuture<CreateTapesOutput, CreateTapesError> {
+        (**self).create_tapes(input)
+    }
+    fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> RusotoFuture<DeleteBandwidthRateLimitOutput, DeleteBandwidthRateLimitError> {
+        (**self).delete_bandwidth_rate_limit(input)
+    }
+    fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> RusotoFuture<DeleteChapCredentialsOutput, DeleteChapCredentialsError> {
+        (**self).delete_chap_credentials(input)
+    }
+    fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> RusotoFuture<DeleteFileShareOutput, DeleteFileShareError> {
+        (**self).delete_file_share(input)
+    }
+    fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> RusotoFuture<DeleteGatewayOutput, DeleteGatewayError> {
+        (**self).delete_gateway(input)
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> RusotoFuture<DeleteSnapshotScheduleOutput, DeleteSnapshotScheduleError> {
+        (**self).delete_snapshot_schedule(input)
+    }
+    fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> RusotoFuture<DeleteTapeOutput, DeleteTapeError> {
+        (**self).delete_tape(input)
+    }
+    fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> RusotoFuture<DeleteTapeArchiveOutput, DeleteTapeArchiveError> {
+        (**self).delete_tape_archive(input)
+    }
+    fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> RusotoFuture<DeleteVolumeOutput, DeleteVolumeError> {
+        (**self).delete_volume(input)
+    }
+    fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> RusotoFuture<DescribeBandwidthRateLimitOutput, DescribeBandwidthRateLimitError> {
+        (**self).describe_bandwidth_rate_limit(input)
+    }
+    fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> RusotoFuture<DescribeCacheOutput, DescribeCacheError> {
+        (**self).describe_cache(input)
+    }
+    fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeCachediSCSIVolumesOutput, DescribeCachediSCSIVolumesError> {
+        (**self).describe_cachedi_scsi_volumes(input)
+    }
+    fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> RusotoFuture<DescribeChapCredentialsOutput, DescribeChapCredentialsError> {
+        (**self).describe_chap_credentials(input)
+    }
+    fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> RusotoFuture<DescribeGatewayInformationOutput, DescribeGatewayInformationError> {
+        (**self).describe_gateway_information(input)
+    }
+    fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> RusotoFuture<DescribeMaintenanceStartTimeOutput, DescribeMaintenanceStartTimeError> {
+        (**self).describe_maintenance_start_time(input)
+    }
+    fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> RusotoFuture<DescribeNFSFileSharesOutput, DescribeNFSFileSharesError> {
+        (**self).describe_nfs_file_shares(input)
+    }
+    fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> RusotoFuture<DescribeSMBFileSharesOutput, DescribeSMBFileSharesError> {
+        (**self).describe_smb_file_shares(input)
+    }
+    fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> RusotoFuture<DescribeSMBSettingsOutput, DescribeSMBSettingsError> {
+        (**self).describe_smb_settings(input)
+    }
+    fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> RusotoFuture<DescribeSnapshotScheduleOutput, DescribeSnapshotScheduleError> {
+        (**self).describe_snapshot_schedule(input)
+    }
+    fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeStorediSCSIVolumesOutput, DescribeStorediSCSIVolumesError> {
+        (**self).describe_storedi_scsi_volumes(input)
+    }
+    fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> RusotoFuture<DescribeTapeArchivesOutput, DescribeTapeArchivesError> {
+        (**self).describe_tape_archives(input)
+    }
+    fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> RusotoFuture<DescribeTapeRecoveryPointsOutput, DescribeTapeRecoveryPointsError> {
+        (**self).describe_tape_recovery_points(input)
+    }
+    fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> RusotoFuture<DescribeTapesOutput, DescribeTapesError> {
+        (**self).describe_tapes(input)
+    }
+    fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> RusotoFuture<DescribeUploadBufferOutput, DescribeUploadBufferError> {
+        (**self).describe_upload_buffer(input)
+    }
+    fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> RusotoFuture<DescribeVTLDevicesOutput, DescribeVTLDevicesError> {
+        (**self).describe_vtl_devices(input)
+    }
+    fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> RusotoFuture<DescribeWorkingStorageOutput, DescribeWorkingStorageError> {
+        (**self).describe_working_storage(input)
+    }
+    fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> RusotoFuture<DetachVolumeOutput, DetachVolumeError> {
+        (**self).detach_volume(input)
+    }
+    fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> RusotoFuture<DisableGatewayOutput, DisableGatewayError> {
+        (**self).disable_gateway(input)
+    }
+    fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> RusotoFuture<JoinDomainOutput, JoinDomainError> {
+        (**self).join_domain(input)
+    }
+    fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> RusotoFuture<ListFileSharesOutput, ListFileSharesError> {
+        (**self).list_file_shares(input)
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> RusotoFuture<ListGatewaysOutput, ListGatewaysError> {
+        (**self).list_gateways(input)
+    }
+    fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> RusotoFuture<ListLocalDisksOutput, ListLocalDisksError> {
+        (**self).list_local_disks(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tapes(&self, input: ListTapesInput) -> RusotoFuture<ListTapesOutput, ListTapesError> {
+        (**self).list_tapes(input)
+    }
+    fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> RusotoFuture<ListVolumeInitiatorsOutput, ListVolumeInitiatorsError> {
+        (**self).list_volume_initiators(input)
+    }
+    fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> RusotoFuture<ListVolumeRecoveryPointsOutput, ListVolumeRecoveryPointsError> {
+        (**self).list_volume_recovery_points(input)
+    }
+    fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> RusotoFuture<ListVolumesOutput, ListVolumesError> {
+        (**self).list_volumes(input)
+    }
+    fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> RusotoFuture<NotifyWhenUploadedOutput, NotifyWhenUploadedError> {
+        (**self).notify_when_uploaded(input)
+    }
+    fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> RusotoFuture<RefreshCacheOutput, RefreshCacheError> {
+        (**self).refresh_cache(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> RusotoFuture<RemoveTagsFromResourceOutput, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> RusotoFuture<ResetCacheOutput, ResetCacheError> {
+        (**self).reset_cache(input)
+    }
+    fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> RusotoFuture<RetrieveTapeArchiveOutput, RetrieveTapeArchiveError> {
+        (**self).retrieve_tape_archive(input)
+    }
+    fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> RusotoFuture<RetrieveTapeRecoveryPointOutput, RetrieveTapeRecoveryPointError> {
+        (**self).retrieve_tape_recovery_point(input)
+    }
+    fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> RusotoFuture<SetLocalConsolePasswordOutput, SetLocalConsolePasswordError> {
+        (**self).set_local_console_password(input)
+    }
+    fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> RusotoFuture<SetSMBGuestPasswordOutput, SetSMBGuestPasswordError> {
+        (**self).set_smb_guest_password(input)
+    }
+    fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> RusotoFuture<ShutdownGatewayOutput, ShutdownGatewayError> {
+        (**self).shutdown_gateway(input)
+    }
+    fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> RusotoFuture<StartGatewayOutput, StartGatewayError> {
+        (**self).start_gateway(input)
+    }
+    fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> RusotoFuture<UpdateBandwidthRateLimitOutput, UpdateBandwidthRateLimitError> {
+        (**self).update_bandwidth_rate_limit(input)
+    }
+    fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> RusotoFuture<UpdateChapCredentialsOutput, UpdateChapCredentialsError> {
+        (**self).update_chap_credentials(input)
+    }
+    fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> RusotoFuture<UpdateGatewayInformationOutput, UpdateGatewayInformationError> {
+        (**self).update_gateway_information(input)
+    }
+    fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> RusotoFuture<UpdateGatewaySoftwareNowOutput, UpdateGatewaySoftwareNowError> {
+        (**self).update_gateway_software_now(input)
+    }
+    fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> RusotoFuture<UpdateMaintenanceStartTimeOutput, UpdateMaintenanceStartTimeError> {
+        (**self).update_maintenance_start_time(input)
+    }
+    fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> RusotoFuture<UpdateNFSFileShareOutput, UpdateNFSFileShareError> {
+        (**self).update_nfs_file_share(input)
+    }
+    fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> RusotoFuture<UpdateSMBFileShareOutput, UpdateSMBFileShareError> {
+        (**self).update_smb_file_share(input)
+    }
+    fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> RusotoFuture<UpdateSMBSecurityStrategyOutput, UpdateSMBSecurityStrategyError> {
+        (**self).update_smb_security_strategy(input)
+    }
+    fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> RusotoFuture<UpdateSnapshotScheduleOutput, UpdateSnapshotScheduleError> {
+        (**self).update_snapshot_schedule(input)
+    }
+    fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError> {
+        (**self).update_vtl_device_type(input)
+    }
+}
+impl<T: StorageGateway + ?Sized> StorageGateway for ::std::sync::Arc<T> {
+    fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> RusotoFuture<ActivateGatewayOutput, ActivateGatewayError> {
+        (**self).activate_gateway(input)
+    }
+    fn add_cache(&self, input: AddCacheInput) -> RusotoFuture<AddCacheOutput, AddCacheError> {
+        (**self).add_cache(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> RusotoFuture<AddTagsToResourceOutput, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> RusotoFuture<AddUploadBufferOutput, AddUploadBufferError> {
+        (**self).add_upload_buffer(input)
+    }
+    fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> RusotoFuture<AddWorkingStorageOutput, AddWorkingStorageError> {
+        (**self).add_working_storage(input)
+    }
+    fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> RusotoFuture<AssignTapePoolOutput, AssignTapePoolError> {
+        (**self).assign_tape_pool(input)
+    }
+    fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> RusotoFuture<AttachVolumeOutput, AttachVolumeError> {
+        (**self).attach_volume(input)
+    }
+    fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> RusotoFuture<CancelArchivalOutput, CancelArchivalError> {
+        (**self).cancel_archival(input)
+    }
+    fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> RusotoFuture<CancelRetrievalOutput, CancelRetrievalError> {
+        (**self).cancel_retrieval(input)
+    }
+    fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateCachediSCSIVolumeOutput, CreateCachediSCSIVolumeError> {
+        (**self).create_cachedi_scsi_volume(input)
+    }
+    fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> RusotoFuture<CreateNFSFileShareOutput, CreateNFSFileShareError> {
+        (**self).create_nfs_file_share(input)
+    }
+    fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> RusotoFuture<CreateSMBFileShareOutput, CreateSMBFileShareError> {
+        (**self).create_smb_file_share(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> RusotoFuture<CreateSnapshotOutput, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> RusotoFuture<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        CreateSnapshotFromVolumeRecoveryPointError,
+    > {
+        (**self).create_snapshot_from_volume_recovery_point(input)
+    }
+    fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateStorediSCSIVolumeOutput, CreateStorediSCSIVolumeError> {
+        (**self).create_storedi_scsi_volume(input)
+    }
+    fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> RusotoFuture<CreateTapeWithBarcodeOutput, CreateTapeWithBarcodeError> {
+        (**self).create_tape_with_barcode(input)
+    }
+    fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> RusotoFuture<CreateTapesOutput, CreateTapesError> {
+        (**self).create_tapes(input)
+    }
+    fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> RusotoFuture<DeleteBandwidthRateLimitOutput, DeleteBandwidthRateLimitError> {
+        (**self).delete_bandwidth_rate_limit(input)
+    }
+    fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> RusotoFuture<DeleteChapCredentialsOutput, DeleteChapCredentialsError> {
+        (**self).delete_chap_credentials(input)
+    }
+    fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> RusotoFuture<DeleteFileShareOutput, DeleteFileShareError> {
+        (**self).delete_file_share(input)
+    }
+    fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> RusotoFuture<DeleteGatewayOutput, DeleteGatewayError> {
+        (**self).delete_gateway(input)
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> RusotoFuture<DeleteSnapshotScheduleOutput, DeleteSnapshotScheduleError> {
+        (**self).delete_snapshot_schedule(input)
+    }
+    fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> RusotoFuture<DeleteTapeOutput, DeleteTapeError> {
+        (**self).delete_tape(input)
+    }
+    fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> RusotoFuture<DeleteTapeArchiveOutput, DeleteTapeArchiveError> {
+        (**self).delete_tape_archive(input)
+    }
+    fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> RusotoFuture<DeleteVolumeOutput, DeleteVolumeError> {
+        (**self).delete_volume(input)
+    }
+    fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> RusotoFuture<DescribeBandwidthRateLimitOutput, DescribeBandwidthRateLimitError> {
+        (**self).describe_bandwidth_rate_limit(input)
+    }
+    fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> RusotoFuture<DescribeCacheOutput, DescribeCacheError> {
+        (**self).describe_cache(input)
+    }
+    fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeCachediSCSIVolumesOutput, DescribeCachediSCSIVolumesError> {
+        (**self).describe_cachedi_scsi_volumes(input)
+    }
+    fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> RusotoFuture<DescribeChapCredentialsOutput, DescribeChapCredentialsError> {
+        (**self).describe_chap_credentials(input)
+    }
+    fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> RusotoFuture<DescribeGatewayInformationOutput, DescribeGatewayInformationError> {
+        (**self).describe_gateway_information(input)
+    }
+    fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> RusotoFuture<DescribeMaintenanceStartTimeOutput, DescribeMaintenanceStartTimeError> {
+        (**self).describe_maintenance_start_time(input)
+    }
+    fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> RusotoFuture<DescribeNFSFileSharesOutput, DescribeNFSFileSharesError> {
+        (**self).describe_nfs_file_shares(input)
+    }
+    fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> RusotoFuture<DescribeSMBFileSharesOutput, DescribeSMBFileSharesError> {
+        (**self).describe_smb_file_shares(input)
+    }
+    fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> RusotoFuture<DescribeSMBSettingsOutput, DescribeSMBSettingsError> {
+        (**self).describe_smb_settings(input)
+    }
+    fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> RusotoFuture<DescribeSnapshotScheduleOutput, DescribeSnapshotScheduleError> {
+        (**self).describe_snapshot_schedule(input)
+    }
+    fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeStorediSCSIVolumesOutput, DescribeStorediSCSIVolumesError> {
+        (**self).describe_storedi_scsi_volumes(input)
+    }
+    fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> RusotoFuture<DescribeTapeArchivesOutput, DescribeTapeArchivesError> {
+        (**self).describe_tape_archives(input)
+    }
+    fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> RusotoFuture<DescribeTapeRecoveryPointsOutput, DescribeTapeRecoveryPointsError> {
+        (**self).describe_tape_recovery_points(input)
+    }
+    fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> RusotoFuture<DescribeTapesOutput, DescribeTapesError> {
+        (**self).describe_tapes(input)
+    }
+    fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> RusotoFuture<DescribeUploadBufferOutput, DescribeUploadBufferError> {
+        (**self).describe_upload_buffer(input)
+    }
+    fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> RusotoFuture<DescribeVTLDevicesOutput, DescribeVTLDevicesError> {
+        (**self).describe_vtl_devices(input)
+    }
+    fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> RusotoFuture<DescribeWorkingStorageOutput, DescribeWorkingStorageError> {
+        (**self).describe_working_storage(input)
+    }
+    fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> RusotoFuture<DetachVolumeOutput, DetachVolumeError> {
+        (**self).detach_volume(input)
+    }
+    fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> RusotoFuture<DisableGatewayOutput, DisableGatewayError> {
+        (**self).disable_gateway(input)
+    }
+    fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> RusotoFuture<JoinDomainOutput, JoinDomainError> {
+        (**self).join_domain(input)
+    }
+    fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> RusotoFuture<ListFileSharesOutput, ListFileSharesError> {
+        (**self).list_file_shares(input)
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> RusotoFuture<ListGatewaysOutput, ListGatewaysError> {
+        (**self).list_gateways(input)
+    }
+    fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> RusotoFuture<ListLocalDisksOutput, ListLocalDisksError> {
+        (**self).list_local_disks(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tapes(&self, input: ListTapesInput) -> RusotoFuture<ListTapesOutput, ListTapesError> {
+        (**self).list_tapes(input)
+    }
+    fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> RusotoFuture<ListVolumeInitiatorsOutput, ListVolumeInitiatorsError> {
+        (**self).list_volume_initiators(input)
+    }
+    fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> RusotoFuture<ListVolumeRecoveryPointsOutput, ListVolumeRecoveryPointsError> {
+        (**self).list_volume_recovery_points(input)
+    }
+    fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> RusotoFuture<ListVolumesOutput, ListVolumesError> {
+        (**self).list_volumes(input)
+    }
+    fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> RusotoFuture<NotifyWhenUploadedOutput, NotifyWhenUploadedError> {
+        (**self).notify_when_uploaded(input)
+    }
+    fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> RusotoFuture<RefreshCacheOutput, RefreshCacheError> {
+        (**self).refresh_cache(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> RusotoFuture<RemoveTagsFromResourceOutput, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> RusotoFuture<ResetCacheOutput, ResetCacheError> {
+        (**self).reset_cache(input)
+    }
+    fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> RusotoFuture<RetrieveTapeArchiveOutput, RetrieveTapeArchiveError> {
+        (**self).retrieve_tape_archive(input)
+    }
+    fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> RusotoFuture<RetrieveTapeRecoveryPointOutput, RetrieveTapeRecoveryPointError> {
+        (**self).retrieve_tape_recovery_point(input)
+    }
+    fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> RusotoFuture<SetLocalConsolePasswordOutput, SetLocalConsolePasswordError> {
+        (**self).set_local_console_password(input)
+    }
+    fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> RusotoFuture<SetSMBGuestPasswordOutput, SetSMBGuestPasswordError> {
+        (**self).set_smb_guest_password(input)
+    }
+    fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> RusotoFuture<ShutdownGatewayOutput, ShutdownGatewayError> {
+        (**self).shutdown_gateway(input)
+    }
+    fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> RusotoFuture<StartGatewayOutput, StartGatewayError> {
+        (**self).start_gateway(input)
+    }
+    fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> RusotoFuture<UpdateBandwidthRateLimitOutput, UpdateBandwidthRateLimitError> {
+        (**self).update_bandwidth_rate_limit(input)
+    }
+    fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> RusotoFuture<UpdateChapCredentialsOutput, UpdateChapCredentialsError> {
+        (**self).update_chap_credentials(input)
+    }
+    fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> RusotoFuture<UpdateGatewayInformationOutput, UpdateGatewayInformationError> {
+        (**self).update_gateway_information(input)
+    }
+    fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> RusotoFuture<UpdateGatewaySoftwareNowOutput, UpdateGatewaySoftwareNowError> {
+        (**self).update_gateway_software_now(input)
+    }
+    fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> RusotoFuture<UpdateMaintenanceStartTimeOutput, UpdateMaintenanceStartTimeError> {
+        (**self).update_maintenance_start_time(input)
+    }
+    fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> RusotoFuture<UpdateNFSFileShareOutput, UpdateNFSFileShareError> {
+        (**self).update_nfs_file_share(input)
+    }
+    fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> RusotoFuture<UpdateSMBFileShareOutput, UpdateSMBFileShareError> {
+        (**self).update_smb_file_share(input)
+    }
+    fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> RusotoFuture<UpdateSMBSecurityStrategyOutput, UpdateSMBSecurityStrategyError> {
+        (**self).update_smb_security_strategy(input)
+    }
+    fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> RusotoFuture<UpdateSnapshotScheduleOutput, UpdateSnapshotScheduleError> {
+        (**self).update_snapshot_schedule(input)
+    }
+    fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError> {
+        (**self).update_vtl_device_type(input)
+    }
+}
+/// A client for the AWS Storage Gateway API.
+#[derive(Clone)]
+pub struct StorageGatewayClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl StorageGatewayClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> StorageGatewayClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StorageGatewayClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayClient {
+        StorageGatewayClient { client, region }
+    }
+}
+
+impl StorageGateway for StorageGatewayClient {
+    /// <p><p>Activates the gateway you previously deployed on your host. In the activation process, you specify information such as the AWS Region that you want to use for storing snapshots or tapes, the time zone for scheduled snapshots the gateway snapshot schedule window, an activation key, and a name for your gateway. The activation process also associates your gateway with your account; for more information, see <a>UpdateGatewayInformation</a>.</p> <note> <p>You must turn on the gateway VM before you can activate your gateway.</p> </note></p>
+    fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> RusotoFuture<ActivateGatewayOutput, ActivateGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ActivateGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ActivateGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ActivateGatewayOutput, _>(
+                    response,
+                    "ActivateGateway",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ActivateGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Configures one or more gateway local disks as cache for a gateway. This operation is only supported in the cached volume, tape and file gateway type (see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/StorageGatewayConcepts.html">Storage Gateway Concepts</a>).</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add cache, and one or more disk IDs that you want to configure as cache.</p>
+    fn add_cache(&self, input: AddCacheInput) -> RusotoFuture<AddCacheOutput, AddCacheError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddCache");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.AddCache");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddCacheOutput, _>(response, "AddCache")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddCacheError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds one or more tags to the specified resource. You use tags to add metadata to resources, which you can use to categorize these resources. For example, you can categorize resources by purpose, owner, environment, or team. Each tag consists of a key and a value, which you define. You can add tags to the following AWS Storage Gateway resources:</p> <ul> <li> <p>Storage gateways of all types</p> </li> <li> <p>Storage volumes</p> </li> <li> <p>Virtual tapes</p> </li> <li> <p>NFS and SMB file shares</p> </li> </ul> <p>You can create a maximum of 50 tags for each resource. Virtual tapes and storage volumes that are recovered to a new gateway maintain their tags.</p>
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> RusotoFuture<AddTagsToResourceOutput, AddTagsToResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddTagsToResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.AddTagsToResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddTagsToResourceOutput, _>(
+                    response,
+                    "AddTagsToResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddTagsToResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Configures one or more gateway local disks as upload buffer for a specified gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add upload buffer, and one or more disk IDs that you want to configure as upload buffer.</p>
+    fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> RusotoFuture<AddUploadBufferOutput, AddUploadBufferError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddUploadBuffer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.AddUploadBuffer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddUploadBufferOutput, _>(
+                    response,
+                    "AddUploadBuffer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddUploadBufferError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Configures one or more gateway local disks as working storage for a gateway. This operation is only supported in the stored volume gateway type. This operation is deprecated in cached volume API version 20120630. Use <a>AddUploadBuffer</a> instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the <a>AddUploadBuffer</a> operation to add upload buffer to a stored volume gateway.</p> </note> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add working storage, and one or more disk IDs that you want to configure as working storage.</p>
+    fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> RusotoFuture<AddWorkingStorageOutput, AddWorkingStorageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AddWorkingStorage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.AddWorkingStorage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AddWorkingStorageOutput, _>(
+                    response,
+                    "AddWorkingStorage",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AddWorkingStorageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Assigns a tape to a tape pool for archiving. The tape assigned to a pool is archived in the S3 storage class that is associated with the pool. When you use your backup application to eject the tape, the tape is archived directly into the S3 storage class (Glacier or Deep Archive) that corresponds to the pool.</p> <p>Valid values: "GLACIER", "DEEP_ARCHIVE"</p>
+    fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> RusotoFuture<AssignTapePoolOutput, AssignTapePoolError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AssignTapePool");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.AssignTapePool");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssignTapePoolOutput, _>(
+                    response,
+                    "AssignTapePool",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssignTapePoolError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Connects a volume to an iSCSI connection and then attaches the volume to the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
+    fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> RusotoFuture<AttachVolumeOutput, AttachVolumeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("AttachVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.AttachVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AttachVolumeOutput, _>(
+                    response,
+                    "AttachVolume",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AttachVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels archiving of a virtual tape to the virtual tape shelf (VTS) after the archiving process is initiated. This operation is only supported in the tape gateway type.</p>
+    fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> RusotoFuture<CancelArchivalOutput, CancelArchivalError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CancelArchival");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.CancelArchival");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelArchivalOutput, _>(
+                    response,
+                    "CancelArchival",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelArchivalError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Cancels retrieval of a virtual tape from the virtual tape shelf (VTS) to a gateway after the retrieval process is initiated. The virtual tape is returned to the VTS. This operation is only supported in the tape gateway type.</p>
+    fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> RusotoFuture<CancelRetrievalOutput, CancelRetrievalError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CancelRetrieval");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.CancelRetrieval");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CancelRetrievalOutput, _>(
+                    response,
+                    "CancelRetrieval",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CancelRetrievalError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a cached volume on a specified cached volume gateway. This operation is only supported in the cached volume gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a cached volume. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note> <p>In the request, you must specify the gateway, size of the volume in bytes, the iSCSI target name, an IP address on which to expose the target, and a unique client token. In response, the gateway creates the volume and returns information about it. This information includes the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p> <p>Optionally, you can provide the ARN for an existing volume as the <code>SourceVolumeARN</code> for this cached volume, which creates an exact copy of the existing volume’s latest recovery point. The <code>VolumeSizeInBytes</code> value must be equal to or larger than the size of the copied volume, in bytes.</p>
+    fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateCachediSCSIVolumeOutput, CreateCachediSCSIVolumeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateCachediSCSIVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.CreateCachediSCSIVolume",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateCachediSCSIVolumeOutput, _>(
+                    response,
+                    "CreateCachediSCSIVolume",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateCachediSCSIVolumeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a Network File System (NFS) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway exposes file shares using a NFS interface. This operation is only supported for file gateways.</p> <important> <p>File gateway requires AWS Security Token Service (AWS STS) to be activated to enable you create a file share. Make sure AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in the AWS Region, activate it. For information about how to activate AWS STS, see Activating and Deactivating AWS STS in an AWS Region in the AWS Identity and Access Management User Guide. </p> <p>File gateway does not support creating hard or symbolic links on a file share.</p> </important></p>
+    fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> RusotoFuture<CreateNFSFileShareOutput, CreateNFSFileShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateNFSFileShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.CreateNFSFileShare");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateNFSFileShareOutput, _>(
+                    response,
+                    "CreateNFSFileShare",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateNFSFileShareError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates a Server Message Block (SMB) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway expose file shares using a SMB interface. This operation is only supported for file gateways.</p> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
+    fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> RusotoFuture<CreateSMBFileShareOutput, CreateSMBFileShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSMBFileShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.CreateSMBFileShare");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSMBFileShareOutput, _>(
+                    response,
+                    "CreateSMBFileShare",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSMBFileShareError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Initiates a snapshot of a volume.</p> <p>AWS Storage Gateway provides the ability to back up point-in-time snapshots of your data to Amazon Simple Storage (S3) for durable off-site recovery, as well as import the data to an Amazon Elastic Block Store (EBS) volume in Amazon Elastic Compute Cloud (EC2). You can take snapshots of your gateway volume on a scheduled or ad hoc basis. This API enables you to take ad-hoc snapshot. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/managing-volumes.html#SchedulingSnapshot">Editing a Snapshot Schedule</a>.</p> <p>In the CreateSnapshot request you identify the volume by providing its Amazon Resource Name (ARN). You must also provide description for the snapshot. When AWS Storage Gateway takes the snapshot of specified volume, the snapshot and description appears in the AWS Storage Gateway Console. In response, AWS Storage Gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot. This operation is only supported in stored and cached volume gateway type.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, see DescribeSnapshots or DeleteSnapshot in the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_Operations.html">EC2 API reference</a>.</p> </note> <important> <p>Volume and snapshot IDs are changing to a longer length ID format. For more information, see the important note on the <a href="https://docs.aws.amazon.com/storagegateway/latest/APIReference/Welcome.html">Welcome</a> page.</p> </important></p>
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> RusotoFuture<CreateSnapshotOutput, CreateSnapshotError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSnapshot");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.CreateSnapshot");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSnapshotOutput, _>(
+                    response,
+                    "CreateSnapshot",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSnapshotError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Initiates a snapshot of a gateway from a volume recovery point. This operation is only supported in the cached volume gateway type.</p> <p>A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot. To get a list of volume recovery point for cached volume gateway, use <a>ListVolumeRecoveryPoints</a>.</p> <p>In the <code>CreateSnapshotFromVolumeRecoveryPoint</code> request, you identify the volume by providing its Amazon Resource Name (ARN). You must also provide a description for the snapshot. When the gateway takes a snapshot of the specified volume, the snapshot and its description appear in the AWS Storage Gateway console. In response, the gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
+    fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> RusotoFuture<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        CreateSnapshotFromVolumeRecoveryPointError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateSnapshotFromVolumeRecoveryPoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.CreateSnapshotFromVolumeRecoveryPoint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSnapshotFromVolumeRecoveryPointOutput, _>(response, "CreateSnapshotFromVolumeRecoveryPoint")
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSnapshotFromVolumeRecoveryPointError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a volume on a specified gateway. This operation is only supported in the stored volume gateway type.</p> <p>The size of the volume to create is inferred from the disk size. You can choose to preserve existing data on the disk, create volume from an existing snapshot, or create an empty volume. If you choose to create an empty gateway volume, then any existing data on the disk is erased.</p> <p>In the request you must specify the gateway and the disk information on which you are creating the volume. In response, the gateway creates the volume and returns volume information such as the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p>
+    fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateStorediSCSIVolumeOutput, CreateStorediSCSIVolumeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateStorediSCSIVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.CreateStorediSCSIVolume",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateStorediSCSIVolumeOutput, _>(
+                    response,
+                    "CreateStorediSCSIVolume",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateStorediSCSIVolumeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Creates a virtual tape by using your own barcode. You write data to the virtual tape and then archive the tape. A barcode is unique and can not be reused if it has already been used on a tape . This applies to barcodes used on deleted tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a virtual tape. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note></p>
+    fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> RusotoFuture<CreateTapeWithBarcodeOutput, CreateTapeWithBarcodeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateTapeWithBarcode");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.CreateTapeWithBarcode",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTapeWithBarcodeOutput, _>(
+                    response,
+                    "CreateTapeWithBarcode",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateTapeWithBarcodeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Creates one or more virtual tapes. You write data to the virtual tapes and then archive the tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create virtual tapes. Use the <a>AddCache</a> operation to add cache storage to a gateway. </p> </note></p>
+    fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> RusotoFuture<CreateTapesOutput, CreateTapesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("CreateTapes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTapesOutput, _>(response, "CreateTapes")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTapesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the bandwidth rate limits of a gateway. You can delete either the upload and download bandwidth rate limit, or you can delete both. If you delete only one of the limits, the other limit remains unchanged. To specify which gateway to work with, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> RusotoFuture<DeleteBandwidthRateLimitOutput, DeleteBandwidthRateLimitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteBandwidthRateLimit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DeleteBandwidthRateLimit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteBandwidthRateLimitOutput, _>(
+                    response,
+                    "DeleteBandwidthRateLimit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteBandwidthRateLimitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target and initiator pair.</p>
+    fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> RusotoFuture<DeleteChapCredentialsOutput, DeleteChapCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteChapCredentials");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DeleteChapCredentials",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteChapCredentialsOutput, _>(
+                    response,
+                    "DeleteChapCredentials",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteChapCredentialsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a file share from a file gateway. This operation is only supported for file gateways.</p>
+    fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> RusotoFuture<DeleteFileShareOutput, DeleteFileShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteFileShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteFileShare");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteFileShareOutput, _>(
+                    response,
+                    "DeleteFileShare",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFileShareError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a gateway. To specify which gateway to delete, use the Amazon Resource Name (ARN) of the gateway in your request. The operation deletes the gateway; however, it does not delete the gateway virtual machine (VM) from your host computer.</p> <p>After you delete a gateway, you cannot reactivate it. Completed snapshots of the gateway volumes are not deleted upon deleting the gateway, however, pending snapshots will not complete. After you delete a gateway, your next step is to remove it from your environment.</p> <important> <p>You no longer pay software charges after the gateway is deleted; however, your existing Amazon EBS snapshots persist and you will continue to be billed for these snapshots. You can choose to remove all remaining Amazon EBS snapshots by canceling your Amazon EC2 subscription.  If you prefer not to cancel your Amazon EC2 subscription, you can delete your snapshots using the Amazon EC2 console. For more information, see the <a href="http://aws.amazon.com/storagegateway"> AWS Storage Gateway Detail Page</a>. </p> </important></p>
+    fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> RusotoFuture<DeleteGatewayOutput, DeleteGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteGatewayOutput, _>(
+                    response,
+                    "DeleteGateway",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes a snapshot of a volume.</p> <p>You can take snapshots of your gateway volumes on a scheduled or ad hoc basis. This API action enables you to delete a snapshot schedule for a volume. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/WorkingWithSnapshots.html">Working with Snapshots</a>. In the <code>DeleteSnapshotSchedule</code> request, you identify the volume by providing its Amazon Resource Name (ARN). This operation is only supported in stored and cached volume gateway types.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. in <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> RusotoFuture<DeleteSnapshotScheduleOutput, DeleteSnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DeleteSnapshotSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSnapshotScheduleOutput, _>(
+                    response,
+                    "DeleteSnapshotSchedule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteSnapshotScheduleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified virtual tape. This operation is only supported in the tape gateway type.</p>
+    fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> RusotoFuture<DeleteTapeOutput, DeleteTapeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteTape");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTape");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTapeOutput, _>(response, "DeleteTape")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTapeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified virtual tape from the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p>
+    fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> RusotoFuture<DeleteTapeArchiveOutput, DeleteTapeArchiveError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteTapeArchive");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapeArchive");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTapeArchiveOutput, _>(
+                    response,
+                    "DeleteTapeArchive",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTapeArchiveError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified storage volume that you previously created using the <a>CreateCachediSCSIVolume</a> or <a>CreateStorediSCSIVolume</a> API. This operation is only supported in the cached volume and stored volume types. For stored volume gateways, the local disk that was configured as the storage volume is not deleted. You can reuse the local disk to create another storage volume. </p> <p>Before you delete a volume, make sure there are no iSCSI connections to the volume you are deleting. You should also make sure there is no snapshot in progress. You can use the Amazon Elastic Compute Cloud (Amazon EC2) API to query snapshots on the volume you are deleting and check the snapshot status. For more information, go to <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/ApiReference-query-DescribeSnapshots.html">DescribeSnapshots</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> <p>In the request, you must provide the Amazon Resource Name (ARN) of the storage volume you want to delete.</p>
+    fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> RusotoFuture<DeleteVolumeOutput, DeleteVolumeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DeleteVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DeleteVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteVolumeOutput, _>(
+                    response,
+                    "DeleteVolume",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the bandwidth rate limits of a gateway. By default, these limits are not set, which means no bandwidth rate limiting is in effect.</p> <p>This operation only returns a value for a bandwidth rate limit only if the limit is set. If no limits are set for the gateway, then this operation returns only the gateway ARN in the response body. To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> RusotoFuture<DescribeBandwidthRateLimitOutput, DescribeBandwidthRateLimitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeBandwidthRateLimit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeBandwidthRateLimit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeBandwidthRateLimitOutput, _>(
+                    response,
+                    "DescribeBandwidthRateLimit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeBandwidthRateLimitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the cache of a gateway. This operation is only supported in the cached volume, tape and file gateway types.</p> <p>The response includes disk IDs that are configured as cache, and it includes the amount of cache allocated and used.</p>
+    fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> RusotoFuture<DescribeCacheOutput, DescribeCacheError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeCache");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DescribeCache");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCacheOutput, _>(
+                    response,
+                    "DescribeCache",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeCacheError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a description of the gateway volumes specified in the request. This operation is only supported in the cached volume gateway types.</p> <p>The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume Amazon Resource Name (ARN).</p>
+    fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeCachediSCSIVolumesOutput, DescribeCachediSCSIVolumesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeCachediSCSIVolumes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeCachediSCSIVolumes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeCachediSCSIVolumesOutput, _>(
+                    response,
+                    "DescribeCachediSCSIVolumes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCachediSCSIVolumesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns an array of Challenge-Handshake Authentication Protocol (CHAP) credentials information for a specified iSCSI target, one for each target-initiator pair.</p>
+    fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> RusotoFuture<DescribeChapCredentialsOutput, DescribeChapCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeChapCredentials");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeChapCredentials",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeChapCredentialsOutput, _>(
+                    response,
+                    "DescribeChapCredentials",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeChapCredentialsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns metadata about a gateway such as its name, network interfaces, configured time zone, and the state (whether the gateway is running or not). To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> RusotoFuture<DescribeGatewayInformationOutput, DescribeGatewayInformationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeGatewayInformation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeGatewayInformation",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGatewayInformationOutput, _>(
+                    response,
+                    "DescribeGatewayInformation",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeGatewayInformationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns your gateway's weekly maintenance start time including the day and time of the week. Note that values are in terms of the gateway's time zone.</p>
+    fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> RusotoFuture<DescribeMaintenanceStartTimeOutput, DescribeMaintenanceStartTimeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeMaintenanceStartTime");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeMaintenanceStartTime",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeMaintenanceStartTimeOutput, _>(
+                    response,
+                    "DescribeMaintenanceStartTime",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeMaintenanceStartTimeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Gets a description for one or more Network File System (NFS) file shares from a file gateway. This operation is only supported for file gateways.</p>
+    fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> RusotoFuture<DescribeNFSFileSharesOutput, DescribeNFSFileSharesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeNFSFileShares");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeNFSFileShares",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeNFSFileSharesOutput, _>(
+                    response,
+                    "DescribeNFSFileShares",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeNFSFileSharesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a description for one or more Server Message Block (SMB) file shares from a file gateway. This operation is only supported for file gateways.</p>
+    fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> RusotoFuture<DescribeSMBFileSharesOutput, DescribeSMBFileSharesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSMBFileShares");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeSMBFileShares",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSMBFileSharesOutput, _>(
+                    response,
+                    "DescribeSMBFileShares",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeSMBFileSharesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a description of a Server Message Block (SMB) file share settings from a file gateway. This operation is only supported for file gateways.</p>
+    fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> RusotoFuture<DescribeSMBSettingsOutput, DescribeSMBSettingsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSMBSettings");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeSMBSettings",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSMBSettingsOutput, _>(
+                    response,
+                    "DescribeSMBSettings",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeSMBSettingsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the snapshot schedule for the specified gateway volume. The snapshot schedule information includes intervals at which snapshots are automatically initiated on the volume. This operation is only supported in the cached volume and stored volume types.</p>
+    fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> RusotoFuture<DescribeSnapshotScheduleOutput, DescribeSnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeSnapshotSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeSnapshotScheduleOutput, _>(
+                    response,
+                    "DescribeSnapshotSchedule",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeSnapshotScheduleError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the description of the gateway volumes specified in the request. The list of gateway volumes in the request must be from one gateway. In the response Amazon Storage Gateway returns volume information sorted by volume ARNs. This operation is only supported in stored volume gateway type.</p>
+    fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeStorediSCSIVolumesOutput, DescribeStorediSCSIVolumesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeStorediSCSIVolumes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeStorediSCSIVolumes",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeStorediSCSIVolumesOutput, _>(
+                    response,
+                    "DescribeStorediSCSIVolumes",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeStorediSCSIVolumesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a description of specified virtual tapes in the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p> <p>If a specific <code>TapeARN</code> is not specified, AWS Storage Gateway returns a description of all virtual tapes found in the VTS associated with your account.</p>
+    fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> RusotoFuture<DescribeTapeArchivesOutput, DescribeTapeArchivesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapeArchives");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeTapeArchives",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTapeArchivesOutput, _>(
+                    response,
+                    "DescribeTapeArchives",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeTapeArchivesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of virtual tape recovery points that are available for the specified tape gateway.</p> <p>A recovery point is a point-in-time view of a virtual tape at which all the data on the virtual tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway. This operation is only supported in the tape gateway type.</p>
+    fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> RusotoFuture<DescribeTapeRecoveryPointsOutput, DescribeTapeRecoveryPointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapeRecoveryPoints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeTapeRecoveryPoints",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTapeRecoveryPointsOutput, _>(
+                    response,
+                    "DescribeTapeRecoveryPoints",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeTapeRecoveryPointsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns a description of the specified Amazon Resource Name (ARN) of virtual tapes. If a <code>TapeARN</code> is not specified, returns a description of all virtual tapes associated with the specified gateway. This operation is only supported in the tape gateway type.</p>
+    fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> RusotoFuture<DescribeTapesOutput, DescribeTapesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeTapes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DescribeTapes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTapesOutput, _>(
+                    response,
+                    "DescribeTapes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTapesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the upload buffer of a gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>The response includes disk IDs that are configured as upload buffer space, and it includes the amount of upload buffer space allocated and used.</p>
+    fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> RusotoFuture<DescribeUploadBufferOutput, DescribeUploadBufferError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeUploadBuffer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeUploadBuffer",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUploadBufferOutput, _>(
+                    response,
+                    "DescribeUploadBuffer",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeUploadBufferError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a description of virtual tape library (VTL) devices for the specified tape gateway. In the response, AWS Storage Gateway returns VTL device information.</p> <p>This operation is only supported in the tape gateway type.</p>
+    fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> RusotoFuture<DescribeVTLDevicesOutput, DescribeVTLDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeVTLDevices");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DescribeVTLDevices");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeVTLDevicesOutput, _>(
+                    response,
+                    "DescribeVTLDevices",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeVTLDevicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the working storage of a gateway. This operation is only supported in the stored volumes gateway type. This operation is deprecated in cached volumes API version (20120630). Use DescribeUploadBuffer instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the DescribeUploadBuffer operation to add upload buffer to a stored volume gateway.</p> </note> <p>The response includes disk IDs that are configured as working storage, and it includes the amount of working storage allocated and used.</p>
+    fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> RusotoFuture<DescribeWorkingStorageOutput, DescribeWorkingStorageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DescribeWorkingStorage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.DescribeWorkingStorage",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeWorkingStorageOutput, _>(
+                    response,
+                    "DescribeWorkingStorage",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeWorkingStorageError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Disconnects a volume from an iSCSI connection and then detaches the volume from the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
+    fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> RusotoFuture<DetachVolumeOutput, DetachVolumeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DetachVolume");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DetachVolume");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DetachVolumeOutput, _>(
+                    response,
+                    "DetachVolume",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DetachVolumeError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Disables a tape gateway when the gateway is no longer functioning. For example, if your gateway VM is damaged, you can disable the gateway so you can recover virtual tapes.</p> <p>Use this operation for a tape gateway that is not reachable or not functioning. This operation is only supported in the tape gateway type.</p> <important> <p>Once a gateway is disabled it cannot be enabled.</p> </important></p>
+    fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> RusotoFuture<DisableGatewayOutput, DisableGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("DisableGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.DisableGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisableGatewayOutput, _>(
+                    response,
+                    "DisableGateway",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisableGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds a file gateway to an Active Directory domain. This operation is only supported for file gateways that support the SMB file protocol.</p>
+    fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> RusotoFuture<JoinDomainOutput, JoinDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("JoinDomain");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.JoinDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<JoinDomainOutput, _>(response, "JoinDomain")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(JoinDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets a list of the file shares for a specific file gateway, or the list of file shares that belong to the calling user account. This operation is only supported for file gateways.</p>
+    fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> RusotoFuture<ListFileSharesOutput, ListFileSharesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListFileShares");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ListFileShares");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListFileSharesOutput, _>(
+                    response,
+                    "ListFileShares",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFileSharesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists gateways owned by an AWS account in an AWS Region specified in the request. The returned list is ordered by gateway Amazon Resource Name (ARN).</p> <p>By default, the operation returns a maximum of 100 gateways. This operation supports pagination that allows you to optionally reduce the number of gateways returned in a response.</p> <p>If you have more gateways than are returned in a response (that is, the response returns only a truncated list of your gateways), the response contains a marker that you can specify in your next request to fetch the next page of gateways.</p>
+    fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> RusotoFuture<ListGatewaysOutput, ListGatewaysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListGateways");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ListGateways");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGatewaysOutput, _>(
+                    response,
+                    "ListGateways",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGatewaysError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of the gateway's local disks. To specify which gateway to describe, you use the Amazon Resource Name (ARN) of the gateway in the body of the request.</p> <p>The request returns a list of all disks, specifying which are configured as working storage, cache storage, or stored volume or not configured at all. The response includes a <code>DiskStatus</code> field. This field can have a value of present (the disk is available to use), missing (the disk is no longer connected to the gateway), or mismatch (the disk node is occupied by a disk that has incorrect metadata or the disk content is corrupted).</p>
+    fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> RusotoFuture<ListLocalDisksOutput, ListLocalDisksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListLocalDisks");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ListLocalDisks");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListLocalDisksOutput, _>(
+                    response,
+                    "ListLocalDisks",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListLocalDisksError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the tags that have been added to the specified resource. This operation is only supported in the cached volume, stored volume and tape gateway type.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.ListTagsForResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists virtual tapes in your virtual tape library (VTL) and your virtual tape shelf (VTS). You specify the tapes to list by specifying one or more tape Amazon Resource Names (ARNs). If you don't specify a tape ARN, the operation lists all virtual tapes in both your VTL and VTS.</p> <p>This operation supports pagination. By default, the operation returns a maximum of up to 100 tapes. You can optionally specify the <code>Limit</code> parameter in the body to limit the number of tapes in the response. If the number of tapes returned in the response is truncated, the response includes a <code>Marker</code> element that you can use in your subsequent request to retrieve the next set of tapes. This operation is only supported in the tape gateway type.</p>
+    fn list_tapes(&self, input: ListTapesInput) -> RusotoFuture<ListTapesOutput, ListTapesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListTapes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ListTapes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTapesOutput, _>(response, "ListTapes")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListTapesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists iSCSI initiators that are connected to a volume. You can use this operation to determine whether a volume is being used or not. This operation is only supported in the cached volume and stored volume gateway types.</p>
+    fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> RusotoFuture<ListVolumeInitiatorsOutput, ListVolumeInitiatorsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumeInitiators");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.ListVolumeInitiators",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListVolumeInitiatorsOutput, _>(
+                    response,
+                    "ListVolumeInitiators",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListVolumeInitiatorsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the recovery points for a specified gateway. This operation is only supported in the cached volume gateway type.</p> <p>Each cache volume has one recovery point. A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot or clone a new cached volume from a source volume. To create a snapshot from a volume recovery point use the <a>CreateSnapshotFromVolumeRecoveryPoint</a> operation.</p>
+    fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> RusotoFuture<ListVolumeRecoveryPointsOutput, ListVolumeRecoveryPointsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumeRecoveryPoints");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.ListVolumeRecoveryPoints",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListVolumeRecoveryPointsOutput, _>(
+                    response,
+                    "ListVolumeRecoveryPoints",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListVolumeRecoveryPointsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Lists the iSCSI stored volumes of a gateway. Results are sorted by volume ARN. The response includes only the volume ARNs. If you want additional volume information, use the <a>DescribeStorediSCSIVolumes</a> or the <a>DescribeCachediSCSIVolumes</a> API.</p> <p>The operation supports pagination. By default, the operation returns a maximum of up to 100 volumes. You can optionally specify the <code>Limit</code> field in the body to limit the number of volumes in the response. If the number of volumes returned in the response is truncated, the response includes a Marker field. You can use this Marker value in your subsequent request to retrieve the next set of volumes. This operation is only supported in the cached volume and stored volume gateway types.</p>
+    fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> RusotoFuture<ListVolumesOutput, ListVolumesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ListVolumes");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ListVolumes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListVolumesOutput, _>(response, "ListVolumes")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListVolumesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sends you notification through CloudWatch Events when all files written to your file share have been uploaded to Amazon S3.</p> <p>AWS Storage Gateway can send a notification through Amazon CloudWatch Events when all files written to your file share up to that point in time have been uploaded to Amazon S3. These files include files written to the file share up to the time that you make a request for notification. When the upload is done, Storage Gateway sends you notification through an Amazon CloudWatch Event. You can configure CloudWatch Events to send the notification through event targets such as Amazon SNS or AWS Lambda function. This operation is only supported for file gateways.</p> <p>For more information, see Getting File Upload Notification in the Storage Gateway User Guide (https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-upload-notification). </p>
+    fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> RusotoFuture<NotifyWhenUploadedOutput, NotifyWhenUploadedError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("NotifyWhenUploaded");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.NotifyWhenUploaded");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<NotifyWhenUploadedOutput, _>(
+                    response,
+                    "NotifyWhenUploaded",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(NotifyWhenUploadedError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Refreshes the cache for the specified file share. This operation finds objects in the Amazon S3 bucket that were added, removed or replaced since the gateway last listed the bucket's contents and cached the results. This operation is only supported in the file gateway type. You can subscribe to be notified through an Amazon CloudWatch event when your RefreshCache operation completes. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting Notified About File Operations</a>.</p> <p>When this API is called, it only initiates the refresh operation. When the API call completes and returns a success code, it doesn't necessarily mean that the file refresh has completed. You should use the refresh-complete notification to determine that the operation has completed before you check for new files on the gateway file share. You can subscribe to be notified through an CloudWatch event when your <code>RefreshCache</code> operation completes. </p>
+    fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> RusotoFuture<RefreshCacheOutput, RefreshCacheError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RefreshCache");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.RefreshCache");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RefreshCacheOutput, _>(
+                    response,
+                    "RefreshCache",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RefreshCacheError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes one or more tags from the specified resource. This operation is only supported in the cached volume, stored volume and tape gateway types.</p>
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> RusotoFuture<RemoveTagsFromResourceOutput, RemoveTagsFromResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.RemoveTagsFromResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RemoveTagsFromResourceOutput, _>(
+                    response,
+                    "RemoveTagsFromResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RemoveTagsFromResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Resets all cache disks that have encountered a error and makes the disks available for reconfiguration as cache storage. If your cache disk encounters a error, the gateway prevents read and write operations on virtual tapes in the gateway. For example, an error can occur when a disk is corrupted or removed from the gateway. When a cache is reset, the gateway loses its cache storage. At this point you can reconfigure the disks as cache disks. This operation is only supported in the cached volume and tape types.</p> <important> <p>If the cache disk you are resetting contains data that has not been uploaded to Amazon S3 yet, that data can be lost. After you reset cache disks, there will be no configured cache disks left in the gateway, so you must configure at least one new cache disk for your gateway to function properly.</p> </important></p>
+    fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> RusotoFuture<ResetCacheOutput, ResetCacheError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ResetCache");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ResetCache");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResetCacheOutput, _>(response, "ResetCache")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResetCacheError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves an archived virtual tape from the virtual tape shelf (VTS) to a tape gateway. Virtual tapes archived in the VTS are not associated with any gateway. However after a tape is retrieved, it is associated with a gateway, even though it is also listed in the VTS, that is, archive. This operation is only supported in the tape gateway type.</p> <p>Once a tape is successfully retrieved to a gateway, it cannot be retrieved again to another gateway. You must archive the tape again before you can retrieve it to another gateway. This operation is only supported in the tape gateway type.</p>
+    fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> RusotoFuture<RetrieveTapeArchiveOutput, RetrieveTapeArchiveError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RetrieveTapeArchive");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.RetrieveTapeArchive",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RetrieveTapeArchiveOutput, _>(
+                    response,
+                    "RetrieveTapeArchive",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RetrieveTapeArchiveError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Retrieves the recovery point for the specified virtual tape. This operation is only supported in the tape gateway type.</p> <p>A recovery point is a point in time view of a virtual tape at which all the data on the tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway.</p> <note> <p>The virtual tape can be retrieved to only one gateway. The retrieved tape is read-only. The virtual tape can be retrieved to only a tape gateway. There is no charge for retrieving recovery points.</p> </note></p>
+    fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> RusotoFuture<RetrieveTapeRecoveryPointOutput, RetrieveTapeRecoveryPointError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("RetrieveTapeRecoveryPoint");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.RetrieveTapeRecoveryPoint",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RetrieveTapeRecoveryPointOutput, _>(
+                    response,
+                    "RetrieveTapeRecoveryPoint",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RetrieveTapeRecoveryPointError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sets the password for your VM local console. When you log in to the local console for the first time, you log in to the VM with the default credentials. We recommend that you set a new password. You don't need to know the default password to set a new password.</p>
+    fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> RusotoFuture<SetLocalConsolePasswordOutput, SetLocalConsolePasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("SetLocalConsolePassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.SetLocalConsolePassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetLocalConsolePasswordOutput, _>(
+                    response,
+                    "SetLocalConsolePassword",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SetLocalConsolePasswordError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Sets the password for the guest user <code>smbguest</code>. The <code>smbguest</code> user is the user when the authentication method for the file share is set to <code>GuestAccess</code>.</p>
+    fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> RusotoFuture<SetSMBGuestPasswordOutput, SetSMBGuestPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("SetSMBGuestPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.SetSMBGuestPassword",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<SetSMBGuestPasswordOutput, _>(
+                    response,
+                    "SetSMBGuestPassword",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(SetSMBGuestPasswordError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Shuts down a gateway. To specify which gateway to shut down, use the Amazon Resource Name (ARN) of the gateway in the body of your request.</p> <p>The operation shuts down the gateway service component running in the gateway's virtual machine (VM) and not the host VM.</p> <note> <p>If you want to shut down the VM, it is recommended that you first shut down the gateway component in the VM to avoid unpredictable conditions.</p> </note> <p>After the gateway is shutdown, you cannot call any other API except <a>StartGateway</a>, <a>DescribeGatewayInformation</a>, and <a>ListGateways</a>. For more information, see <a>ActivateGateway</a>. Your applications cannot read from or write to the gateway's storage volumes, and there are no snapshots taken.</p> <note> <p>When you make a shutdown request, you will get a <code>200 OK</code> success response immediately. However, it might take some time for the gateway to shut down. You can call the <a>DescribeGatewayInformation</a> API to check the status. For more information, see <a>ActivateGateway</a>.</p> </note> <p>If do not intend to use the gateway again, you must delete the gateway (using <a>DeleteGateway</a>) to no longer pay software charges associated with the gateway.</p>
+    fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> RusotoFuture<ShutdownGatewayOutput, ShutdownGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("ShutdownGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.ShutdownGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ShutdownGatewayOutput, _>(
+                    response,
+                    "ShutdownGateway",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ShutdownGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Starts a gateway that you previously shut down (see <a>ShutdownGateway</a>). After the gateway starts, you can then make other API calls, your applications can read from or write to the gateway's storage volumes and you will be able to take snapshot backups.</p> <note> <p>When you make a request, you will get a 200 OK success response immediately. However, it might take some time for the gateway to be ready. You should call <a>DescribeGatewayInformation</a> and check the status before making any additional API calls. For more information, see <a>ActivateGateway</a>.</p> </note> <p>To specify which gateway to start, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> RusotoFuture<StartGatewayOutput, StartGatewayError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("StartGateway");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.StartGateway");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<StartGatewayOutput, _>(
+                    response,
+                    "StartGateway",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StartGatewayError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the bandwidth rate limits of a gateway. You can update both the upload and download bandwidth rate limit or specify only one of the two. If you don't set a bandwidth rate limit, the existing rate limit remains.</p> <p>By default, a gateway's bandwidth rate limits are not set. If you don't set any limit, the gateway does not have any limitations on its bandwidth usage and could potentially use the maximum available bandwidth.</p> <p>To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> RusotoFuture<UpdateBandwidthRateLimitOutput, UpdateBandwidthRateLimitError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateBandwidthRateLimit");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
             "x-amz-target",
-            "StorageGateway_20130630.UpdateMaintenanceStartTime",
+            "StorageGateway_20130630.UpdateBandwidthRateLimit",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateBandwidthRateLimitOutput, _>(
+                    response,
+                    "UpdateBandwidthRateLimit",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateBandwidthRateLimitError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Updates the Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target. By default, a gateway does not have CHAP enabled; however, for added security, you might use it.</p> <important> <p>When you update CHAP credentials, all existing connections on the target are closed and initiators must reconnect with the new credentials.</p> </important></p>
+    fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> RusotoFuture<UpdateChapCredentialsOutput, UpdateChapCredentialsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateChapCredentials");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateChapCredentials",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateChapCredentialsOutput, _>(
+                    response,
+                    "UpdateChapCredentials",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateChapCredentialsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates a gateway&#39;s metadata, which includes the gateway&#39;s name and time zone. To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p> <note> <p>For Gateways activated after September 2, 2015, the gateway&#39;s ARN contains the gateway ID rather than the gateway name. However, changing the name of the gateway has no effect on the gateway&#39;s ARN.</p> </note></p>
+    fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> RusotoFuture<UpdateGatewayInformationOutput, UpdateGatewayInformationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateGatewayInformation");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateGatewayInformation",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGatewayInformationOutput, _>(
+                    response,
+                    "UpdateGatewayInformation",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateGatewayInformationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Updates the gateway virtual machine (VM) software. The request immediately triggers the software update.</p> <note> <p>When you make this request, you get a <code>200 OK</code> success response immediately. However, it might take some time for the update to complete. You can call <a>DescribeGatewayInformation</a> to verify the gateway is in the <code>STATE_RUNNING</code> state.</p> </note> <important> <p>A software update forces a system restart of your gateway. You can minimize the chance of any disruption to your applications by increasing your iSCSI Initiators&#39; timeouts. For more information about increasing iSCSI Initiator timeouts for Windows and Linux, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorWindowsClient.html#CustomizeWindowsiSCSISettings">Customizing Your Windows iSCSI Settings</a> and <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorRedHatClient.html#CustomizeLinuxiSCSISettings">Customizing Your Linux iSCSI Settings</a>, respectively.</p> </important></p>
+    fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> RusotoFuture<UpdateGatewaySoftwareNowOutput, UpdateGatewaySoftwareNowError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateGatewaySoftwareNow");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateGatewaySoftwareNow",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGatewaySoftwareNowOutput, _>(
+                    response,
+                    "UpdateGatewaySoftwareNow",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMaintenanceStartTimeOutput, _>()
+                    Err(UpdateGatewaySoftwareNowError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Updates a gateway's weekly maintenance start time information, including day and time of the week. The maintenance time is the time in your gateway's time zone.</p>
+    fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> RusotoFuture<UpdateMaintenanceStartTimeOutput, UpdateMaintenanceStartTimeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateMaintenanceStartTime");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateMaintenanceStartTime",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMaintenanceStartTimeOutput, _>(
+                    response,
+                    "UpdateMaintenanceStartTime",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdateMaintenanceStartTimeError::from_response(response))
@@ -8357,153 +12485,2721 @@ impl StorageGateway for StorageGatewayClient {
             }
         })
     }
-
-    /// <p><p>Updates a Network File System (NFS) file share. This operation is only supported in the file gateway type.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null.</p> </note> <p>Updates the following file share setting:</p> <ul> <li> <p>Default storage class for your S3 bucket</p> </li> <li> <p>Metadata defaults for your S3 bucket</p> </li> <li> <p>Allowed NFS clients for your file share</p> </li> <li> <p>Squash settings</p> </li> <li> <p>Write status of your file share</p> </li> </ul> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported in file gateways.</p> </note></p>
+
+    /// <p><p>Updates a Network File System (NFS) file share. This operation is only supported in the file gateway type.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null.</p> </note> <p>Updates the following file share setting:</p> <ul> <li> <p>Default storage class for your S3 bucket</p> </li> <li> <p>Metadata defaults for your S3 bucket</p> </li> <li> <p>Allowed NFS clients for your file share</p> </li> <li> <p>Squash settings</p> </li> <li> <p>Write status of your file share</p> </li> </ul> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported in file gateways.</p> </note></p>
+    fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> RusotoFuture<UpdateNFSFileShareOutput, UpdateNFSFileShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateNFSFileShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.UpdateNFSFileShare");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateNFSFileShareOutput, _>(
+                    response,
+                    "UpdateNFSFileShare",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateNFSFileShareError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates a Server Message Block (SMB) file share.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported for file gateways.</p> </note> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
+    fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> RusotoFuture<UpdateSMBFileShareOutput, UpdateSMBFileShareError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSMBFileShare");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StorageGateway_20130630.UpdateSMBFileShare");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateSMBFileShareOutput, _>(
+                    response,
+                    "UpdateSMBFileShare",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateSMBFileShareError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Updates the SMB security strategy on a file gateway. This action is only supported in file gateways.</p> <note> <p>This API is called Security level in the User Guide.</p> <p>A higher security level can affect performance of the gateway.</p> </note></p>
+    fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> RusotoFuture<UpdateSMBSecurityStrategyOutput, UpdateSMBSecurityStrategyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSMBSecurityStrategy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateSMBSecurityStrategy",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateSMBSecurityStrategyOutput, _>(
+                    response,
+                    "UpdateSMBSecurityStrategy",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateSMBSecurityStrategyError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates a snapshot schedule configured for a gateway volume. This operation is only supported in the cached volume and stored volume gateway types.</p> <p>The default snapshot schedule for volume is once every 24 hours, starting at the creation time of the volume. You can use this API to change the snapshot schedule configured for the volume.</p> <p>In the request you must identify the gateway volume whose snapshot schedule you want to update, and the schedule information, including when you want the snapshot to begin on a day and the frequency (in hours) of snapshots.</p>
+    fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> RusotoFuture<UpdateSnapshotScheduleOutput, UpdateSnapshotScheduleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateSnapshotSchedule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateSnapshotSchedule",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateSnapshotScheduleOutput, _>(
+                    response,
+                    "UpdateSnapshotSchedule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateSnapshotScheduleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the type of medium changer in a tape gateway. When you activate a tape gateway, you select a medium changer type for the tape gateway. This operation enables you to select a different type of medium changer after a tape gateway is activated. This operation is only supported in the tape gateway type.</p>
+    fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
+        request.set_operation("UpdateVTLDeviceType");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StorageGateway_20130630.UpdateVTLDeviceType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateVTLDeviceTypeOutput, _>(
+                    response,
+                    "UpdateVTLDeviceType",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateVTLDeviceTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the AWS Storage Gateway API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockStorageGateway {
+    activate_gateway: Option<
+        Box<
+            dyn Fn(
+                    ActivateGatewayInput,
+                ) -> ::std::result::Result<
+                    ActivateGatewayOutput,
+                    RusotoError<ActivateGatewayError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_cache: Option<
+        Box<
+            dyn Fn(
+                    AddCacheInput,
+                )
+                    -> ::std::result::Result<AddCacheOutput, RusotoError<AddCacheError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    add_tags_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AddTagsToResourceInput,
+                ) -> ::std::result::Result<
+                    AddTagsToResourceOutput,
+                    RusotoError<AddTagsToResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_upload_buffer: Option<
+        Box<
+            dyn Fn(
+                    AddUploadBufferInput,
+                ) -> ::std::result::Result<
+                    AddUploadBufferOutput,
+                    RusotoError<AddUploadBufferError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_working_storage: Option<
+        Box<
+            dyn Fn(
+                    AddWorkingStorageInput,
+                ) -> ::std::result::Result<
+                    AddWorkingStorageOutput,
+                    RusotoError<AddWorkingStorageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    assign_tape_pool: Option<
+        Box<
+            dyn Fn(
+                    AssignTapePoolInput,
+                )
+                    -> ::std::result::Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    attach_volume: Option<
+        Box<
+            dyn Fn(
+                    AttachVolumeInput,
+                )
+                    -> ::std::result::Result<AttachVolumeOutput, RusotoError<AttachVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_archival: Option<
+        Box<
+            dyn Fn(
+                    CancelArchivalInput,
+                )
+                    -> ::std::result::Result<CancelArchivalOutput, RusotoError<CancelArchivalError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    cancel_retrieval: Option<
+        Box<
+            dyn Fn(
+                    CancelRetrievalInput,
+                ) -> ::std::result::Result<
+                    CancelRetrievalOutput,
+                    RusotoError<CancelRetrievalError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_cachedi_scsi_volume: Option<
+        Box<
+            dyn Fn(
+                    CreateCachediSCSIVolumeInput,
+                ) -> ::std::result::Result<
+                    CreateCachediSCSIVolumeOutput,
+                    RusotoError<CreateCachediSCSIVolumeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_nfs_file_share: Option<
+        Box<
+            dyn Fn(
+                    CreateNFSFileShareInput,
+                ) -> ::std::result::Result<
+                    CreateNFSFileShareOutput,
+                    RusotoError<CreateNFSFileShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_smb_file_share: Option<
+        Box<
+            dyn Fn(
+                    CreateSMBFileShareInput,
+                ) -> ::std::result::Result<
+                    CreateSMBFileShareOutput,
+                    RusotoError<CreateSMBFileShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_snapshot: Option<
+        Box<
+            dyn Fn(
+                    CreateSnapshotInput,
+                )
+                    -> ::std::result::Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_snapshot_from_volume_recovery_point: Option<
+        Box<
+            dyn Fn(
+                    CreateSnapshotFromVolumeRecoveryPointInput,
+                ) -> ::std::result::Result<
+                    CreateSnapshotFromVolumeRecoveryPointOutput,
+                    RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_storedi_scsi_volume: Option<
+        Box<
+            dyn Fn(
+                    CreateStorediSCSIVolumeInput,
+                ) -> ::std::result::Result<
+                    CreateStorediSCSIVolumeOutput,
+                    RusotoError<CreateStorediSCSIVolumeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tape_with_barcode: Option<
+        Box<
+            dyn Fn(
+                    CreateTapeWithBarcodeInput,
+                ) -> ::std::result::Result<
+                    CreateTapeWithBarcodeOutput,
+                    RusotoError<CreateTapeWithBarcodeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_tapes: Option<
+        Box<
+            dyn Fn(
+                    CreateTapesInput,
+                )
+                    -> ::std::result::Result<CreateTapesOutput, RusotoError<CreateTapesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_bandwidth_rate_limit: Option<
+        Box<
+            dyn Fn(
+                    DeleteBandwidthRateLimitInput,
+                ) -> ::std::result::Result<
+                    DeleteBandwidthRateLimitOutput,
+                    RusotoError<DeleteBandwidthRateLimitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_chap_credentials: Option<
+        Box<
+            dyn Fn(
+                    DeleteChapCredentialsInput,
+                ) -> ::std::result::Result<
+                    DeleteChapCredentialsOutput,
+                    RusotoError<DeleteChapCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_file_share: Option<
+        Box<
+            dyn Fn(
+                    DeleteFileShareInput,
+                ) -> ::std::result::Result<
+                    DeleteFileShareOutput,
+                    RusotoError<DeleteFileShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_gateway: Option<
+        Box<
+            dyn Fn(
+                    DeleteGatewayInput,
+                )
+                    -> ::std::result::Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    DeleteSnapshotScheduleInput,
+                ) -> ::std::result::Result<
+                    DeleteSnapshotScheduleOutput,
+                    RusotoError<DeleteSnapshotScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_tape: Option<
+        Box<
+            dyn Fn(
+                    DeleteTapeInput,
+                )
+                    -> ::std::result::Result<DeleteTapeOutput, RusotoError<DeleteTapeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_tape_archive: Option<
+        Box<
+            dyn Fn(
+                    DeleteTapeArchiveInput,
+                ) -> ::std::result::Result<
+                    DeleteTapeArchiveOutput,
+                    RusotoError<DeleteTapeArchiveError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_volume: Option<
+        Box<
+            dyn Fn(
+                    DeleteVolumeInput,
+                )
+                    -> ::std::result::Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_bandwidth_rate_limit: Option<
+        Box<
+            dyn Fn(
+                    DescribeBandwidthRateLimitInput,
+                ) -> ::std::result::Result<
+                    DescribeBandwidthRateLimitOutput,
+                    RusotoError<DescribeBandwidthRateLimitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cache: Option<
+        Box<
+            dyn Fn(
+                    DescribeCacheInput,
+                )
+                    -> ::std::result::Result<DescribeCacheOutput, RusotoError<DescribeCacheError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_cachedi_scsi_volumes: Option<
+        Box<
+            dyn Fn(
+                    DescribeCachediSCSIVolumesInput,
+                ) -> ::std::result::Result<
+                    DescribeCachediSCSIVolumesOutput,
+                    RusotoError<DescribeCachediSCSIVolumesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_chap_credentials: Option<
+        Box<
+            dyn Fn(
+                    DescribeChapCredentialsInput,
+                ) -> ::std::result::Result<
+                    DescribeChapCredentialsOutput,
+                    RusotoError<DescribeChapCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_gateway_information: Option<
+        Box<
+            dyn Fn(
+                    DescribeGatewayInformationInput,
+                ) -> ::std::result::Result<
+                    DescribeGatewayInformationOutput,
+                    RusotoError<DescribeGatewayInformationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_maintenance_start_time: Option<
+        Box<
+            dyn Fn(
+                    DescribeMaintenanceStartTimeInput,
+                ) -> ::std::result::Result<
+                    DescribeMaintenanceStartTimeOutput,
+                    RusotoError<DescribeMaintenanceStartTimeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_nfs_file_shares: Option<
+        Box<
+            dyn Fn(
+                    DescribeNFSFileSharesInput,
+                ) -> ::std::result::Result<
+                    DescribeNFSFileSharesOutput,
+                    RusotoError<DescribeNFSFileSharesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_smb_file_shares: Option<
+        Box<
+            dyn Fn(
+                    DescribeSMBFileSharesInput,
+                ) -> ::std::result::Result<
+                    DescribeSMBFileSharesOutput,
+                    RusotoError<DescribeSMBFileSharesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_smb_settings: Option<
+        Box<
+            dyn Fn(
+                    DescribeSMBSettingsInput,
+                ) -> ::std::result::Result<
+                    DescribeSMBSettingsOutput,
+                    RusotoError<DescribeSMBSettingsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    DescribeSnapshotScheduleInput,
+                ) -> ::std::result::Result<
+                    DescribeSnapshotScheduleOutput,
+                    RusotoError<DescribeSnapshotScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_storedi_scsi_volumes: Option<
+        Box<
+            dyn Fn(
+                    DescribeStorediSCSIVolumesInput,
+                ) -> ::std::result::Result<
+                    DescribeStorediSCSIVolumesOutput,
+                    RusotoError<DescribeStorediSCSIVolumesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tape_archives: Option<
+        Box<
+            dyn Fn(
+                    DescribeTapeArchivesInput,
+                ) -> ::std::result::Result<
+                    DescribeTapeArchivesOutput,
+                    RusotoError<DescribeTapeArchivesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tape_recovery_points: Option<
+        Box<
+            dyn Fn(
+                    DescribeTapeRecoveryPointsInput,
+                ) -> ::std::result::Result<
+                    DescribeTapeRecoveryPointsOutput,
+                    RusotoError<DescribeTapeRecoveryPointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tapes: Option<
+        Box<
+            dyn Fn(
+                    DescribeTapesInput,
+                )
+                    -> ::std::result::Result<DescribeTapesOutput, RusotoError<DescribeTapesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_upload_buffer: Option<
+        Box<
+            dyn Fn(
+                    DescribeUploadBufferInput,
+                ) -> ::std::result::Result<
+                    DescribeUploadBufferOutput,
+                    RusotoError<DescribeUploadBufferError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_vtl_devices: Option<
+        Box<
+            dyn Fn(
+                    DescribeVTLDevicesInput,
+                ) -> ::std::result::Result<
+                    DescribeVTLDevicesOutput,
+                    RusotoError<DescribeVTLDevicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_working_storage: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkingStorageInput,
+                ) -> ::std::result::Result<
+                    DescribeWorkingStorageOutput,
+                    RusotoError<DescribeWorkingStorageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detach_volume: Option<
+        Box<
+            dyn Fn(
+                    DetachVolumeInput,
+                )
+                    -> ::std::result::Result<DetachVolumeOutput, RusotoError<DetachVolumeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disable_gateway: Option<
+        Box<
+            dyn Fn(
+                    DisableGatewayInput,
+                )
+                    -> ::std::result::Result<DisableGatewayOutput, RusotoError<DisableGatewayError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    join_domain: Option<
+        Box<
+            dyn Fn(
+                    JoinDomainInput,
+                )
+                    -> ::std::result::Result<JoinDomainOutput, RusotoError<JoinDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_file_shares: Option<
+        Box<
+            dyn Fn(
+                    ListFileSharesInput,
+                )
+                    -> ::std::result::Result<ListFileSharesOutput, RusotoError<ListFileSharesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_gateways: Option<
+        Box<
+            dyn Fn(
+                    ListGatewaysInput,
+                )
+                    -> ::std::result::Result<ListGatewaysOutput, RusotoError<ListGatewaysError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_local_disks: Option<
+        Box<
+            dyn Fn(
+                    ListLocalDisksInput,
+                )
+                    -> ::std::result::Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tapes: Option<
+        Box<
+            dyn Fn(
+                    ListTapesInput,
+                )
+                    -> ::std::result::Result<ListTapesOutput, RusotoError<ListTapesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_volume_initiators: Option<
+        Box<
+            dyn Fn(
+                    ListVolumeInitiatorsInput,
+                ) -> ::std::result::Result<
+                    ListVolumeInitiatorsOutput,
+                    RusotoError<ListVolumeInitiatorsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_volume_recovery_points: Option<
+        Box<
+            dyn Fn(
+                    ListVolumeRecoveryPointsInput,
+                ) -> ::std::result::Result<
+                    ListVolumeRecoveryPointsOutput,
+                    RusotoError<ListVolumeRecoveryPointsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_volumes: Option<
+        Box<
+            dyn Fn(
+                    ListVolumesInput,
+                )
+                    -> ::std::result::Result<ListVolumesOutput, RusotoError<ListVolumesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    notify_when_uploaded: Option<
+        Box<
+            dyn Fn(
+                    NotifyWhenUploadedInput,
+                ) -> ::std::result::Result<
+                    NotifyWhenUploadedOutput,
+                    RusotoError<NotifyWhenUploadedError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    refresh_cache: Option<
+        Box<
+            dyn Fn(
+                    RefreshCacheInput,
+                )
+                    -> ::std::result::Result<RefreshCacheOutput, RusotoError<RefreshCacheError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_tags_from_resource: Option<
+        Box<
+            dyn Fn(
+                    RemoveTagsFromResourceInput,
+                ) -> ::std::result::Result<
+                    RemoveTagsFromResourceOutput,
+                    RusotoError<RemoveTagsFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_cache: Option<
+        Box<
+            dyn Fn(
+                    ResetCacheInput,
+                )
+                    -> ::std::result::Result<ResetCacheOutput, RusotoError<ResetCacheError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    retrieve_tape_archive: Option<
+        Box<
+            dyn Fn(
+                    RetrieveTapeArchiveInput,
+                ) -> ::std::result::Result<
+                    RetrieveTapeArchiveOutput,
+                    RusotoError<RetrieveTapeArchiveError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    retrieve_tape_recovery_point: Option<
+        Box<
+            dyn Fn(
+                    RetrieveTapeRecoveryPointInput,
+                ) -> ::std::result::Result<
+                    RetrieveTapeRecoveryPointOutput,
+                    RusotoError<RetrieveTapeRecoveryPointError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_local_console_password: Option<
+        Box<
+            dyn Fn(
+                    SetLocalConsolePasswordInput,
+                ) -> ::std::result::Result<
+                    SetLocalConsolePasswordOutput,
+                    RusotoError<SetLocalConsolePasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    set_smb_guest_password: Option<
+        Box<
+            dyn Fn(
+                    SetSMBGuestPasswordInput,
+                ) -> ::std::result::Result<
+                    SetSMBGuestPasswordOutput,
+                    RusotoError<SetSMBGuestPasswordError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    shutdown_gateway: Option<
+        Box<
+            dyn Fn(
+                    ShutdownGatewayInput,
+                ) -> ::std::result::Result<
+                    ShutdownGatewayOutput,
+                    RusotoError<ShutdownGatewayError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_gateway: Option<
+        Box<
+            dyn Fn(
+                    StartGatewayInput,
+                )
+                    -> ::std::result::Result<StartGatewayOutput, RusotoError<StartGatewayError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_bandwidth_rate_limit: Option<
+        Box<
+            dyn Fn(
+                    UpdateBandwidthRateLimitInput,
+                ) -> ::std::result::Result<
+                    UpdateBandwidthRateLimitOutput,
+                    RusotoError<UpdateBandwidthRateLimitError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_chap_credentials: Option<
+        Box<
+            dyn Fn(
+                    UpdateChapCredentialsInput,
+                ) -> ::std::result::Result<
+                    UpdateChapCredentialsOutput,
+                    RusotoError<UpdateChapCredentialsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_gateway_information: Option<
+        Box<
+            dyn Fn(
+                    UpdateGatewayInformationInput,
+                ) -> ::std::result::Result<
+                    UpdateGatewayInformationOutput,
+                    RusotoError<UpdateGatewayInformationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_gateway_software_now: Option<
+        Box<
+            dyn Fn(
+                    UpdateGatewaySoftwareNowInput,
+                ) -> ::std::result::Result<
+                    UpdateGatewaySoftwareNowOutput,
+                    RusotoError<UpdateGatewaySoftwareNowError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_maintenance_start_time: Option<
+        Box<
+            dyn Fn(
+                    UpdateMaintenanceStartTimeInput,
+                ) -> ::std::result::Result<
+                    UpdateMaintenanceStartTimeOutput,
+                    RusotoError<UpdateMaintenanceStartTimeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_nfs_file_share: Option<
+        Box<
+            dyn Fn(
+                    UpdateNFSFileShareInput,
+                ) -> ::std::result::Result<
+                    UpdateNFSFileShareOutput,
+                    RusotoError<UpdateNFSFileShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_smb_file_share: Option<
+        Box<
+            dyn Fn(
+                    UpdateSMBFileShareInput,
+                ) -> ::std::result::Result<
+                    UpdateSMBFileShareOutput,
+                    RusotoError<UpdateSMBFileShareError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_smb_security_strategy: Option<
+        Box<
+            dyn Fn(
+                    UpdateSMBSecurityStrategyInput,
+                ) -> ::std::result::Result<
+                    UpdateSMBSecurityStrategyOutput,
+                    RusotoError<UpdateSMBSecurityStrategyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_snapshot_schedule: Option<
+        Box<
+            dyn Fn(
+                    UpdateSnapshotScheduleInput,
+                ) -> ::std::result::Result<
+                    UpdateSnapshotScheduleOutput,
+                    RusotoError<UpdateSnapshotScheduleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_vtl_device_type: Option<
+        Box<
+            dyn Fn(
+                    UpdateVTLDeviceTypeInput,
+                ) -> ::std::result::Result<
+                    UpdateVTLDeviceTypeOutput,
+                    RusotoError<UpdateVTLDeviceTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockStorageGateway {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockStorageGateway {
+        Default::default()
+    }
+
+    /// Answers `activate_gateway` with what `handler` returns for its input.
+    pub fn with_activate_gateway<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ActivateGatewayInput,
+            )
+                -> ::std::result::Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.activate_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_cache` with what `handler` returns for its input.
+    pub fn with_add_cache<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(AddCacheInput) -> ::std::result::Result<AddCacheOutput, RusotoError<AddCacheError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_tags_to_resource` with what `handler` returns for its input.
+    pub fn with_add_tags_to_resource<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                AddTagsToResourceInput,
+            ) -> ::std::result::Result<
+                AddTagsToResourceOutput,
+                RusotoError<AddTagsToResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_tags_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_upload_buffer` with what `handler` returns for its input.
+    pub fn with_add_upload_buffer<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                AddUploadBufferInput,
+            )
+                -> ::std::result::Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.add_upload_buffer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_working_storage` with what `handler` returns for its input.
+    pub fn with_add_working_storage<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                AddWorkingStorageInput,
+            ) -> ::std::result::Result<
+                AddWorkingStorageOutput,
+                RusotoError<AddWorkingStorageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_working_storage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `assign_tape_pool` with what `handler` returns for its input.
+    pub fn with_assign_tape_pool<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                AssignTapePoolInput,
+            )
+                -> ::std::result::Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.assign_tape_pool = Some(Box::new(handler));
+        self
+    }
+    /// Answers `attach_volume` with what `handler` returns for its input.
+    pub fn with_attach_volume<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                AttachVolumeInput,
+            )
+                -> ::std::result::Result<AttachVolumeOutput, RusotoError<AttachVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attach_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_archival` with what `handler` returns for its input.
+    pub fn with_cancel_archival<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CancelArchivalInput,
+            )
+                -> ::std::result::Result<CancelArchivalOutput, RusotoError<CancelArchivalError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_archival = Some(Box::new(handler));
+        self
+    }
+    /// Answers `cancel_retrieval` with what `handler` returns for its input.
+    pub fn with_cancel_retrieval<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CancelRetrievalInput,
+            )
+                -> ::std::result::Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.cancel_retrieval = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_cachedi_scsi_volume` with what `handler` returns for its input.
+    pub fn with_create_cachedi_scsi_volume<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateCachediSCSIVolumeInput,
+            ) -> ::std::result::Result<
+                CreateCachediSCSIVolumeOutput,
+                RusotoError<CreateCachediSCSIVolumeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_cachedi_scsi_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_nfs_file_share` with what `handler` returns for its input.
+    pub fn with_create_nfs_file_share<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateNFSFileShareInput,
+            ) -> ::std::result::Result<
+                CreateNFSFileShareOutput,
+                RusotoError<CreateNFSFileShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_nfs_file_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_smb_file_share` with what `handler` returns for its input.
+    pub fn with_create_smb_file_share<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateSMBFileShareInput,
+            ) -> ::std::result::Result<
+                CreateSMBFileShareOutput,
+                RusotoError<CreateSMBFileShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_smb_file_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_snapshot` with what `handler` returns for its input.
+    pub fn with_create_snapshot<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateSnapshotInput,
+            )
+                -> ::std::result::Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_snapshot = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_snapshot_from_volume_recovery_point` with what `handler` returns for its input.
+    pub fn with_create_snapshot_from_volume_recovery_point<F>(
+        mut self,
+        handler: F,
+    ) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateSnapshotFromVolumeRecoveryPointInput,
+            ) -> ::std::result::Result<
+                CreateSnapshotFromVolumeRecoveryPointOutput,
+                RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_snapshot_from_volume_recovery_point = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_storedi_scsi_volume` with what `handler` returns for its input.
+    pub fn with_create_storedi_scsi_volume<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateStorediSCSIVolumeInput,
+            ) -> ::std::result::Result<
+                CreateStorediSCSIVolumeOutput,
+                RusotoError<CreateStorediSCSIVolumeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_storedi_scsi_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tape_with_barcode` with what `handler` returns for its input.
+    pub fn with_create_tape_with_barcode<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateTapeWithBarcodeInput,
+            ) -> ::std::result::Result<
+                CreateTapeWithBarcodeOutput,
+                RusotoError<CreateTapeWithBarcodeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tape_with_barcode = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tapes` with what `handler` returns for its input.
+    pub fn with_create_tapes<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                CreateTapesInput,
+            )
+                -> ::std::result::Result<CreateTapesOutput, RusotoError<CreateTapesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tapes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_bandwidth_rate_limit` with what `handler` returns for its input.
+    pub fn with_delete_bandwidth_rate_limit<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteBandwidthRateLimitInput,
+            ) -> ::std::result::Result<
+                DeleteBandwidthRateLimitOutput,
+                RusotoError<DeleteBandwidthRateLimitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_bandwidth_rate_limit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_chap_credentials` with what `handler` returns for its input.
+    pub fn with_delete_chap_credentials<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteChapCredentialsInput,
+            ) -> ::std::result::Result<
+                DeleteChapCredentialsOutput,
+                RusotoError<DeleteChapCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_chap_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_file_share` with what `handler` returns for its input.
+    pub fn with_delete_file_share<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteFileShareInput,
+            )
+                -> ::std::result::Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_file_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_gateway` with what `handler` returns for its input.
+    pub fn with_delete_gateway<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteGatewayInput,
+            )
+                -> ::std::result::Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_delete_snapshot_schedule<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteSnapshotScheduleInput,
+            ) -> ::std::result::Result<
+                DeleteSnapshotScheduleOutput,
+                RusotoError<DeleteSnapshotScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tape` with what `handler` returns for its input.
+    pub fn with_delete_tape<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteTapeInput,
+            ) -> ::std::result::Result<DeleteTapeOutput, RusotoError<DeleteTapeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tape = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tape_archive` with what `handler` returns for its input.
+    pub fn with_delete_tape_archive<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteTapeArchiveInput,
+            ) -> ::std::result::Result<
+                DeleteTapeArchiveOutput,
+                RusotoError<DeleteTapeArchiveError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tape_archive = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_volume` with what `handler` returns for its input.
+    pub fn with_delete_volume<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DeleteVolumeInput,
+            )
+                -> ::std::result::Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_bandwidth_rate_limit` with what `handler` returns for its input.
+    pub fn with_describe_bandwidth_rate_limit<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeBandwidthRateLimitInput,
+            ) -> ::std::result::Result<
+                DescribeBandwidthRateLimitOutput,
+                RusotoError<DescribeBandwidthRateLimitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_bandwidth_rate_limit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cache` with what `handler` returns for its input.
+    pub fn with_describe_cache<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeCacheInput,
+            )
+                -> ::std::result::Result<DescribeCacheOutput, RusotoError<DescribeCacheError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cachedi_scsi_volumes` with what `handler` returns for its input.
+    pub fn with_describe_cachedi_scsi_volumes<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeCachediSCSIVolumesInput,
+            ) -> ::std::result::Result<
+                DescribeCachediSCSIVolumesOutput,
+                RusotoError<DescribeCachediSCSIVolumesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cachedi_scsi_volumes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_chap_credentials` with what `handler` returns for its input.
+    pub fn with_describe_chap_credentials<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeChapCredentialsInput,
+            ) -> ::std::result::Result<
+                DescribeChapCredentialsOutput,
+                RusotoError<DescribeChapCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_chap_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_gateway_information` with what `handler` returns for its input.
+    pub fn with_describe_gateway_information<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeGatewayInformationInput,
+            ) -> ::std::result::Result<
+                DescribeGatewayInformationOutput,
+                RusotoError<DescribeGatewayInformationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_gateway_information = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_maintenance_start_time` with what `handler` returns for its input.
+    pub fn with_describe_maintenance_start_time<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeMaintenanceStartTimeInput,
+            ) -> ::std::result::Result<
+                DescribeMaintenanceStartTimeOutput,
+                RusotoError<DescribeMaintenanceStartTimeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_maintenance_start_time = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_nfs_file_shares` with what `handler` returns for its input.
+    pub fn with_describe_nfs_file_shares<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeNFSFileSharesInput,
+            ) -> ::std::result::Result<
+                DescribeNFSFileSharesOutput,
+                RusotoError<DescribeNFSFileSharesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_nfs_file_shares = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_smb_file_shares` with what `handler` returns for its input.
+    pub fn with_describe_smb_file_shares<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeSMBFileSharesInput,
+            ) -> ::std::result::Result<
+                DescribeSMBFileSharesOutput,
+                RusotoError<DescribeSMBFileSharesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_smb_file_shares = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_smb_settings` with what `handler` returns for its input.
+    pub fn with_describe_smb_settings<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeSMBSettingsInput,
+            ) -> ::std::result::Result<
+                DescribeSMBSettingsOutput,
+                RusotoError<DescribeSMBSettingsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_smb_settings = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_describe_snapshot_schedule<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeSnapshotScheduleInput,
+            ) -> ::std::result::Result<
+                DescribeSnapshotScheduleOutput,
+                RusotoError<DescribeSnapshotScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_storedi_scsi_volumes` with what `handler` returns for its input.
+    pub fn with_describe_storedi_scsi_volumes<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeStorediSCSIVolumesInput,
+            ) -> ::std::result::Result<
+                DescribeStorediSCSIVolumesOutput,
+                RusotoError<DescribeStorediSCSIVolumesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_storedi_scsi_volumes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tape_archives` with what `handler` returns for its input.
+    pub fn with_describe_tape_archives<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeTapeArchivesInput,
+            ) -> ::std::result::Result<
+                DescribeTapeArchivesOutput,
+                RusotoError<DescribeTapeArchivesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tape_archives = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tape_recovery_points` with what `handler` returns for its input.
+    pub fn with_describe_tape_recovery_points<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeTapeRecoveryPointsInput,
+            ) -> ::std::result::Result<
+                DescribeTapeRecoveryPointsOutput,
+                RusotoError<DescribeTapeRecoveryPointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tape_recovery_points = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tapes` with what `handler` returns for its input.
+    pub fn with_describe_tapes<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeTapesInput,
+            )
+                -> ::std::result::Result<DescribeTapesOutput, RusotoError<DescribeTapesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tapes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_upload_buffer` with what `handler` returns for its input.
+    pub fn with_describe_upload_buffer<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeUploadBufferInput,
+            ) -> ::std::result::Result<
+                DescribeUploadBufferOutput,
+                RusotoError<DescribeUploadBufferError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_upload_buffer = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_vtl_devices` with what `handler` returns for its input.
+    pub fn with_describe_vtl_devices<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeVTLDevicesInput,
+            ) -> ::std::result::Result<
+                DescribeVTLDevicesOutput,
+                RusotoError<DescribeVTLDevicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_vtl_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_working_storage` with what `handler` returns for its input.
+    pub fn with_describe_working_storage<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DescribeWorkingStorageInput,
+            ) -> ::std::result::Result<
+                DescribeWorkingStorageOutput,
+                RusotoError<DescribeWorkingStorageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_working_storage = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detach_volume` with what `handler` returns for its input.
+    pub fn with_detach_volume<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DetachVolumeInput,
+            )
+                -> ::std::result::Result<DetachVolumeOutput, RusotoError<DetachVolumeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.detach_volume = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disable_gateway` with what `handler` returns for its input.
+    pub fn with_disable_gateway<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                DisableGatewayInput,
+            )
+                -> ::std::result::Result<DisableGatewayOutput, RusotoError<DisableGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.disable_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `join_domain` with what `handler` returns for its input.
+    pub fn with_join_domain<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                JoinDomainInput,
+            ) -> ::std::result::Result<JoinDomainOutput, RusotoError<JoinDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.join_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_file_shares` with what `handler` returns for its input.
+    pub fn with_list_file_shares<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListFileSharesInput,
+            )
+                -> ::std::result::Result<ListFileSharesOutput, RusotoError<ListFileSharesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_file_shares = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_gateways` with what `handler` returns for its input.
+    pub fn with_list_gateways<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListGatewaysInput,
+            )
+                -> ::std::result::Result<ListGatewaysOutput, RusotoError<ListGatewaysError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_gateways = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_local_disks` with what `handler` returns for its input.
+    pub fn with_list_local_disks<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListLocalDisksInput,
+            )
+                -> ::std::result::Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_local_disks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tapes` with what `handler` returns for its input.
+    pub fn with_list_tapes<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListTapesInput,
+            ) -> ::std::result::Result<ListTapesOutput, RusotoError<ListTapesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tapes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_volume_initiators` with what `handler` returns for its input.
+    pub fn with_list_volume_initiators<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListVolumeInitiatorsInput,
+            ) -> ::std::result::Result<
+                ListVolumeInitiatorsOutput,
+                RusotoError<ListVolumeInitiatorsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_volume_initiators = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_volume_recovery_points` with what `handler` returns for its input.
+    pub fn with_list_volume_recovery_points<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListVolumeRecoveryPointsInput,
+            ) -> ::std::result::Result<
+                ListVolumeRecoveryPointsOutput,
+                RusotoError<ListVolumeRecoveryPointsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_volume_recovery_points = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_volumes` with what `handler` returns for its input.
+    pub fn with_list_volumes<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ListVolumesInput,
+            )
+                -> ::std::result::Result<ListVolumesOutput, RusotoError<ListVolumesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_volumes = Some(Box::new(handler));
+        self
+    }
+    /// Answers `notify_when_uploaded` with what `handler` returns for its input.
+    pub fn with_notify_when_uploaded<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                NotifyWhenUploadedInput,
+            ) -> ::std::result::Result<
+                NotifyWhenUploadedOutput,
+                RusotoError<NotifyWhenUploadedError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.notify_when_uploaded = Some(Box::new(handler));
+        self
+    }
+    /// Answers `refresh_cache` with what `handler` returns for its input.
+    pub fn with_refresh_cache<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                RefreshCacheInput,
+            )
+                -> ::std::result::Result<RefreshCacheOutput, RusotoError<RefreshCacheError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.refresh_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_tags_from_resource` with what `handler` returns for its input.
+    pub fn with_remove_tags_from_resource<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                RemoveTagsFromResourceInput,
+            ) -> ::std::result::Result<
+                RemoveTagsFromResourceOutput,
+                RusotoError<RemoveTagsFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_tags_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_cache` with what `handler` returns for its input.
+    pub fn with_reset_cache<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ResetCacheInput,
+            ) -> ::std::result::Result<ResetCacheOutput, RusotoError<ResetCacheError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_cache = Some(Box::new(handler));
+        self
+    }
+    /// Answers `retrieve_tape_archive` with what `handler` returns for its input.
+    pub fn with_retrieve_tape_archive<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                RetrieveTapeArchiveInput,
+            ) -> ::std::result::Result<
+                RetrieveTapeArchiveOutput,
+                RusotoError<RetrieveTapeArchiveError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.retrieve_tape_archive = Some(Box::new(handler));
+        self
+    }
+    /// Answers `retrieve_tape_recovery_point` with what `handler` returns for its input.
+    pub fn with_retrieve_tape_recovery_point<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                RetrieveTapeRecoveryPointInput,
+            ) -> ::std::result::Result<
+                RetrieveTapeRecoveryPointOutput,
+                RusotoError<RetrieveTapeRecoveryPointError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.retrieve_tape_recovery_point = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_local_console_password` with what `handler` returns for its input.
+    pub fn with_set_local_console_password<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                SetLocalConsolePasswordInput,
+            ) -> ::std::result::Result<
+                SetLocalConsolePasswordOutput,
+                RusotoError<SetLocalConsolePasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_local_console_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `set_smb_guest_password` with what `handler` returns for its input.
+    pub fn with_set_smb_guest_password<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                SetSMBGuestPasswordInput,
+            ) -> ::std::result::Result<
+                SetSMBGuestPasswordOutput,
+                RusotoError<SetSMBGuestPasswordError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.set_smb_guest_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `shutdown_gateway` with what `handler` returns for its input.
+    pub fn with_shutdown_gateway<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                ShutdownGatewayInput,
+            )
+                -> ::std::result::Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.shutdown_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_gateway` with what `handler` returns for its input.
+    pub fn with_start_gateway<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                StartGatewayInput,
+            )
+                -> ::std::result::Result<StartGatewayOutput, RusotoError<StartGatewayError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_gateway = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_bandwidth_rate_limit` with what `handler` returns for its input.
+    pub fn with_update_bandwidth_rate_limit<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateBandwidthRateLimitInput,
+            ) -> ::std::result::Result<
+                UpdateBandwidthRateLimitOutput,
+                RusotoError<UpdateBandwidthRateLimitError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_bandwidth_rate_limit = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_chap_credentials` with what `handler` returns for its input.
+    pub fn with_update_chap_credentials<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateChapCredentialsInput,
+            ) -> ::std::result::Result<
+                UpdateChapCredentialsOutput,
+                RusotoError<UpdateChapCredentialsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_chap_credentials = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_gateway_information` with what `handler` returns for its input.
+    pub fn with_update_gateway_information<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateGatewayInformationInput,
+            ) -> ::std::result::Result<
+                UpdateGatewayInformationOutput,
+                RusotoError<UpdateGatewayInformationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_gateway_information = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_gateway_software_now` with what `handler` returns for its input.
+    pub fn with_update_gateway_software_now<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateGatewaySoftwareNowInput,
+            ) -> ::std::result::Result<
+                UpdateGatewaySoftwareNowOutput,
+                RusotoError<UpdateGatewaySoftwareNowError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_gateway_software_now = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_maintenance_start_time` with what `handler` returns for its input.
+    pub fn with_update_maintenance_start_time<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateMaintenanceStartTimeInput,
+            ) -> ::std::result::Result<
+                UpdateMaintenanceStartTimeOutput,
+                RusotoError<UpdateMaintenanceStartTimeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_maintenance_start_time = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_nfs_file_share` with what `handler` returns for its input.
+    pub fn with_update_nfs_file_share<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateNFSFileShareInput,
+            ) -> ::std::result::Result<
+                UpdateNFSFileShareOutput,
+                RusotoError<UpdateNFSFileShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_nfs_file_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_smb_file_share` with what `handler` returns for its input.
+    pub fn with_update_smb_file_share<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateSMBFileShareInput,
+            ) -> ::std::result::Result<
+                UpdateSMBFileShareOutput,
+                RusotoError<UpdateSMBFileShareError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_smb_file_share = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_smb_security_strategy` with what `handler` returns for its input.
+    pub fn with_update_smb_security_strategy<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateSMBSecurityStrategyInput,
+            ) -> ::std::result::Result<
+                UpdateSMBSecurityStrategyOutput,
+                RusotoError<UpdateSMBSecurityStrategyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_smb_security_strategy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_snapshot_schedule` with what `handler` returns for its input.
+    pub fn with_update_snapshot_schedule<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateSnapshotScheduleInput,
+            ) -> ::std::result::Result<
+                UpdateSnapshotScheduleOutput,
+                RusotoError<UpdateSnapshotScheduleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_snapshot_schedule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_vtl_device_type` with what `handler` returns for its input.
+    pub fn with_update_vtl_device_type<F>(mut self, handler: F) -> MockStorageGateway
+    where
+        F: Fn(
+                UpdateVTLDeviceTypeInput,
+            ) -> ::std::result::Result<
+                UpdateVTLDeviceTypeOutput,
+                RusotoError<UpdateVTLDeviceTypeError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_vtl_device_type = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl StorageGateway for MockStorageGateway {
+    fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> RusotoFuture<ActivateGatewayOutput, ActivateGatewayError> {
+        match self.activate_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for activate_gateway"),
+        }
+    }
+    fn add_cache(&self, input: AddCacheInput) -> RusotoFuture<AddCacheOutput, AddCacheError> {
+        match self.add_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for add_cache"),
+        }
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> RusotoFuture<AddTagsToResourceOutput, AddTagsToResourceError> {
+        match self.add_tags_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for add_tags_to_resource"),
+        }
+    }
+    fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> RusotoFuture<AddUploadBufferOutput, AddUploadBufferError> {
+        match self.add_upload_buffer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for add_upload_buffer"),
+        }
+    }
+    fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> RusotoFuture<AddWorkingStorageOutput, AddWorkingStorageError> {
+        match self.add_working_storage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for add_working_storage"),
+        }
+    }
+    fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> RusotoFuture<AssignTapePoolOutput, AssignTapePoolError> {
+        match self.assign_tape_pool {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for assign_tape_pool"),
+        }
+    }
+    fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> RusotoFuture<AttachVolumeOutput, AttachVolumeError> {
+        match self.attach_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for attach_volume"),
+        }
+    }
+    fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> RusotoFuture<CancelArchivalOutput, CancelArchivalError> {
+        match self.cancel_archival {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for cancel_archival"),
+        }
+    }
+    fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> RusotoFuture<CancelRetrievalOutput, CancelRetrievalError> {
+        match self.cancel_retrieval {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for cancel_retrieval"),
+        }
+    }
+    fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateCachediSCSIVolumeOutput, CreateCachediSCSIVolumeError> {
+        match self.create_cachedi_scsi_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_cachedi_scsi_volume"),
+        }
+    }
+    fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> RusotoFuture<CreateNFSFileShareOutput, CreateNFSFileShareError> {
+        match self.create_nfs_file_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_nfs_file_share"),
+        }
+    }
+    fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> RusotoFuture<CreateSMBFileShareOutput, CreateSMBFileShareError> {
+        match self.create_smb_file_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_smb_file_share"),
+        }
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> RusotoFuture<CreateSnapshotOutput, CreateSnapshotError> {
+        match self.create_snapshot {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_snapshot"),
+        }
+    }
+    fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> RusotoFuture<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        CreateSnapshotFromVolumeRecoveryPointError,
+    > {
+        match self.create_snapshot_from_volume_recovery_point {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockStorageGateway has no handler for create_snapshot_from_volume_recovery_point"
+            ),
+        }
+    }
+    fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> RusotoFuture<CreateStorediSCSIVolumeOutput, CreateStorediSCSIVolumeError> {
+        match self.create_storedi_scsi_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_storedi_scsi_volume"),
+        }
+    }
+    fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> RusotoFuture<CreateTapeWithBarcodeOutput, CreateTapeWithBarcodeError> {
+        match self.create_tape_with_barcode {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_tape_with_barcode"),
+        }
+    }
+    fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> RusotoFuture<CreateTapesOutput, CreateTapesError> {
+        match self.create_tapes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for create_tapes"),
+        }
+    }
+    fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> RusotoFuture<DeleteBandwidthRateLimitOutput, DeleteBandwidthRateLimitError> {
+        match self.delete_bandwidth_rate_limit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_bandwidth_rate_limit"),
+        }
+    }
+    fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> RusotoFuture<DeleteChapCredentialsOutput, DeleteChapCredentialsError> {
+        match self.delete_chap_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_chap_credentials"),
+        }
+    }
+    fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> RusotoFuture<DeleteFileShareOutput, DeleteFileShareError> {
+        match self.delete_file_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_file_share"),
+        }
+    }
+    fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> RusotoFuture<DeleteGatewayOutput, DeleteGatewayError> {
+        match self.delete_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_gateway"),
+        }
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> RusotoFuture<DeleteSnapshotScheduleOutput, DeleteSnapshotScheduleError> {
+        match self.delete_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_snapshot_schedule"),
+        }
+    }
+    fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> RusotoFuture<DeleteTapeOutput, DeleteTapeError> {
+        match self.delete_tape {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_tape"),
+        }
+    }
+    fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> RusotoFuture<DeleteTapeArchiveOutput, DeleteTapeArchiveError> {
+        match self.delete_tape_archive {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_tape_archive"),
+        }
+    }
+    fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> RusotoFuture<DeleteVolumeOutput, DeleteVolumeError> {
+        match self.delete_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for delete_volume"),
+        }
+    }
+    fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> RusotoFuture<DescribeBandwidthRateLimitOutput, DescribeBandwidthRateLimitError> {
+        match self.describe_bandwidth_rate_limit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_bandwidth_rate_limit"),
+        }
+    }
+    fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> RusotoFuture<DescribeCacheOutput, DescribeCacheError> {
+        match self.describe_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_cache"),
+        }
+    }
+    fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeCachediSCSIVolumesOutput, DescribeCachediSCSIVolumesError> {
+        match self.describe_cachedi_scsi_volumes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_cachedi_scsi_volumes"),
+        }
+    }
+    fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> RusotoFuture<DescribeChapCredentialsOutput, DescribeChapCredentialsError> {
+        match self.describe_chap_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_chap_credentials"),
+        }
+    }
+    fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> RusotoFuture<DescribeGatewayInformationOutput, DescribeGatewayInformationError> {
+        match self.describe_gateway_information {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_gateway_information"),
+        }
+    }
+    fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> RusotoFuture<DescribeMaintenanceStartTimeOutput, DescribeMaintenanceStartTimeError> {
+        match self.describe_maintenance_start_time {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_maintenance_start_time"),
+        }
+    }
+    fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> RusotoFuture<DescribeNFSFileSharesOutput, DescribeNFSFileSharesError> {
+        match self.describe_nfs_file_shares {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_nfs_file_shares"),
+        }
+    }
+    fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> RusotoFuture<DescribeSMBFileSharesOutput, DescribeSMBFileSharesError> {
+        match self.describe_smb_file_shares {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_smb_file_shares"),
+        }
+    }
+    fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> RusotoFuture<DescribeSMBSettingsOutput, DescribeSMBSettingsError> {
+        match self.describe_smb_settings {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_smb_settings"),
+        }
+    }
+    fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> RusotoFuture<DescribeSnapshotScheduleOutput, DescribeSnapshotScheduleError> {
+        match self.describe_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_snapshot_schedule"),
+        }
+    }
+    fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> RusotoFuture<DescribeStorediSCSIVolumesOutput, DescribeStorediSCSIVolumesError> {
+        match self.describe_storedi_scsi_volumes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_storedi_scsi_volumes"),
+        }
+    }
+    fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> RusotoFuture<DescribeTapeArchivesOutput, DescribeTapeArchivesError> {
+        match self.describe_tape_archives {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_tape_archives"),
+        }
+    }
+    fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> RusotoFuture<DescribeTapeRecoveryPointsOutput, DescribeTapeRecoveryPointsError> {
+        match self.describe_tape_recovery_points {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_tape_recovery_points"),
+        }
+    }
+    fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> RusotoFuture<DescribeTapesOutput, DescribeTapesError> {
+        match self.describe_tapes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_tapes"),
+        }
+    }
+    fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> RusotoFuture<DescribeUploadBufferOutput, DescribeUploadBufferError> {
+        match self.describe_upload_buffer {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_upload_buffer"),
+        }
+    }
+    fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> RusotoFuture<DescribeVTLDevicesOutput, DescribeVTLDevicesError> {
+        match self.describe_vtl_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_vtl_devices"),
+        }
+    }
+    fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> RusotoFuture<DescribeWorkingStorageOutput, DescribeWorkingStorageError> {
+        match self.describe_working_storage {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for describe_working_storage"),
+        }
+    }
+    fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> RusotoFuture<DetachVolumeOutput, DetachVolumeError> {
+        match self.detach_volume {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for detach_volume"),
+        }
+    }
+    fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> RusotoFuture<DisableGatewayOutput, DisableGatewayError> {
+        match self.disable_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for disable_gateway"),
+        }
+    }
+    fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> RusotoFuture<JoinDomainOutput, JoinDomainError> {
+        match self.join_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for join_domain"),
+        }
+    }
+    fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> RusotoFuture<ListFileSharesOutput, ListFileSharesError> {
+        match self.list_file_shares {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_file_shares"),
+        }
+    }
+    fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> RusotoFuture<ListGatewaysOutput, ListGatewaysError> {
+        match self.list_gateways {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_gateways"),
+        }
+    }
+    fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> RusotoFuture<ListLocalDisksOutput, ListLocalDisksError> {
+        match self.list_local_disks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_local_disks"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_tapes(&self, input: ListTapesInput) -> RusotoFuture<ListTapesOutput, ListTapesError> {
+        match self.list_tapes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_tapes"),
+        }
+    }
+    fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> RusotoFuture<ListVolumeInitiatorsOutput, ListVolumeInitiatorsError> {
+        match self.list_volume_initiators {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_volume_initiators"),
+        }
+    }
+    fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> RusotoFuture<ListVolumeRecoveryPointsOutput, ListVolumeRecoveryPointsError> {
+        match self.list_volume_recovery_points {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_volume_recovery_points"),
+        }
+    }
+    fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> RusotoFuture<ListVolumesOutput, ListVolumesError> {
+        match self.list_volumes {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for list_volumes"),
+        }
+    }
+    fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> RusotoFuture<NotifyWhenUploadedOutput, NotifyWhenUploadedError> {
+        match self.notify_when_uploaded {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for notify_when_uploaded"),
+        }
+    }
+    fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> RusotoFuture<RefreshCacheOutput, RefreshCacheError> {
+        match self.refresh_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for refresh_cache"),
+        }
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> RusotoFuture<RemoveTagsFromResourceOutput, RemoveTagsFromResourceError> {
+        match self.remove_tags_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for remove_tags_from_resource"),
+        }
+    }
+    fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> RusotoFuture<ResetCacheOutput, ResetCacheError> {
+        match self.reset_cache {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for reset_cache"),
+        }
+    }
+    fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> RusotoFuture<RetrieveTapeArchiveOutput, RetrieveTapeArchiveError> {
+        match self.retrieve_tape_archive {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for retrieve_tape_archive"),
+        }
+    }
+    fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> RusotoFuture<RetrieveTapeRecoveryPointOutput, RetrieveTapeRecoveryPointError> {
+        match self.retrieve_tape_recovery_point {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for retrieve_tape_recovery_point"),
+        }
+    }
+    fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> RusotoFuture<SetLocalConsolePasswordOutput, SetLocalConsolePasswordError> {
+        match self.set_local_console_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for set_local_console_password"),
+        }
+    }
+    fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> RusotoFuture<SetSMBGuestPasswordOutput, SetSMBGuestPasswordError> {
+        match self.set_smb_guest_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for set_smb_guest_password"),
+        }
+    }
+    fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> RusotoFuture<ShutdownGatewayOutput, ShutdownGatewayError> {
+        match self.shutdown_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for shutdown_gateway"),
+        }
+    }
+    fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> RusotoFuture<StartGatewayOutput, StartGatewayError> {
+        match self.start_gateway {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for start_gateway"),
+        }
+    }
+    fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> RusotoFuture<UpdateBandwidthRateLimitOutput, UpdateBandwidthRateLimitError> {
+        match self.update_bandwidth_rate_limit {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_bandwidth_rate_limit"),
+        }
+    }
+    fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> RusotoFuture<UpdateChapCredentialsOutput, UpdateChapCredentialsError> {
+        match self.update_chap_credentials {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_chap_credentials"),
+        }
+    }
+    fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> RusotoFuture<UpdateGatewayInformationOutput, UpdateGatewayInformationError> {
+        match self.update_gateway_information {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_gateway_information"),
+        }
+    }
+    fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> RusotoFuture<UpdateGatewaySoftwareNowOutput, UpdateGatewaySoftwareNowError> {
+        match self.update_gateway_software_now {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_gateway_software_now"),
+        }
+    }
+    fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> RusotoFuture<UpdateMaintenanceStartTimeOutput, UpdateMaintenanceStartTimeError> {
+        match self.update_maintenance_start_time {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_maintenance_start_time"),
+        }
+    }
     fn update_nfs_file_share(
         &self,
         input: UpdateNFSFileShareInput,
     ) -> RusotoFuture<UpdateNFSFileShareOutput, UpdateNFSFileShareError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.UpdateNFSFileShare");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateNFSFileShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateNFSFileShareError::from_response(response))),
-                )
-            }
-        })
+        match self.update_nfs_file_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_nfs_file_share"),
+        }
     }
-
-    /// <p><p>Updates a Server Message Block (SMB) file share.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported for file gateways.</p> </note> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide.</i> </p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
     fn update_smb_file_share(
         &self,
         input: UpdateSMBFileShareInput,
     ) -> RusotoFuture<UpdateSMBFileShareOutput, UpdateSMBFileShareError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StorageGateway_20130630.UpdateSMBFileShare");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSMBFileShareOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateSMBFileShareError::from_response(response))),
-                )
-            }
-        })
+        match self.update_smb_file_share {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_smb_file_share"),
+        }
     }
-
-    /// <p><p>Updates the SMB security strategy on a file gateway. This action is only supported in file gateways.</p> <note> <p>This API is called Security level in the User Guide.</p> <p>A higher security level can affect performance of the gateway.</p> </note></p>
     fn update_smb_security_strategy(
         &self,
         input: UpdateSMBSecurityStrategyInput,
     ) -> RusotoFuture<UpdateSMBSecurityStrategyOutput, UpdateSMBSecurityStrategyError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateSMBSecurityStrategy",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSMBSecurityStrategyOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateSMBSecurityStrategyError::from_response(response))
-                }))
-            }
-        })
+        match self.update_smb_security_strategy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_smb_security_strategy"),
+        }
     }
-
-    /// <p>Updates a snapshot schedule configured for a gateway volume. This operation is only supported in the cached volume and stored volume gateway types.</p> <p>The default snapshot schedule for volume is once every 24 hours, starting at the creation time of the volume. You can use this API to change the snapshot schedule configured for the volume.</p> <p>In the request you must identify the gateway volume whose snapshot schedule you want to update, and the schedule information, including when you want the snapshot to begin on a day and the frequency (in hours) of snapshots.</p>
     fn update_snapshot_schedule(
         &self,
         input: UpdateSnapshotScheduleInput,
     ) -> RusotoFuture<UpdateSnapshotScheduleOutput, UpdateSnapshotScheduleError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateSnapshotSchedule",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSnapshotScheduleOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateSnapshotScheduleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_snapshot_schedule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_snapshot_schedule"),
+        }
     }
-
-    /// <p>Updates the type of medium changer in a tape gateway. When you activate a tape gateway, you select a medium changer type for the tape gateway. This operation enables you to select a different type of medium changer after a tape gateway is activated. This operation is only supported in the tape gateway type.</p>
     fn update_vtl_device_type(
         &self,
         input: UpdateVTLDeviceTypeInput,
     ) -> RusotoFuture<UpdateVTLDeviceTypeOutput, UpdateVTLDeviceTypeError> {
-        let mut request = SignedRequest::new("POST", "storagegateway", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StorageGateway_20130630.UpdateVTLDeviceType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVTLDeviceTypeOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateVTLDeviceTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_vtl_device_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockStorageGateway has no handler for update_vtl_device_type"),
+        }
     }
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use chrono::prelude::*;
 use chrono::Duration;
 use futures::{Async, Future, Poll};
@@ -12,10 +14,10 @@ use crate::{
     DecodeAuthorizationMessageResponse, GetCallerIdentityError, GetCallerIdentityRequest,
     GetCallerIdentityResponse, GetFederationTokenError, GetFederationTokenRequest,
     GetFederationTokenResponse, GetSessionTokenError, GetSessionTokenRequest,
-    GetSessionTokenResponse, Sts, StsClient,
+    GetSessionTokenResponse, Sts, StsClient, Tag,
 };
 use rusoto_core::credential::AwsCredentials;
-use rusoto_core::{CredentialsError, ProvideAwsCredentials, RusotoFuture};
+use rusoto_core::{CredentialsError, ProvideAwsCredentials, RusotoError, RusotoFuture};
 
 pub const DEFAULT_DURATION_SECONDS: i64 = 3600;
 pub const DEFAULT_ROLE_DURATION_SECONDS: i64 = 900;
@@ -250,6 +252,8 @@ pub struct StsAssumeRoleSessionCredentialsProvider {
     scope_down_policy: Option<String>,
     mfa_serial: Option<String>,
     mfa_code: Option<String>,
+    tags: Vec<Tag>,
+    transitive_tag_keys: Vec<String>,
 }
 
 impl StsAssumeRoleSessionCredentialsProvider {
@@ -282,6 +286,8 @@ impl StsAssumeRoleSessionCredentialsProvider {
             scope_down_policy,
             mfa_serial,
             mfa_code: None,
+            tags: Vec::new(),
+            transitive_tag_keys: Vec::new(),
         }
     }
 
@@ -298,6 +304,17 @@ impl StsAssumeRoleSessionCredentialsProvider {
         self.mfa_code = None;
     }
 
+    /// Set the session tags passed when assuming the role, up to 50.
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = tags;
+    }
+
+    /// Set the keys of the session tags which are passed on to the sessions of roles
+    /// assumed with the credentials of this one.
+    pub fn set_transitive_tag_keys(&mut self, keys: Vec<String>) {
+        self.transitive_tag_keys = keys;
+    }
+
     /// Calls `AssumeRole` to get a session token from the STS Api.
     /// Optionally uses MFA if the MFA serial number and code are set.
     pub fn assume_role(&self) -> StsAssumeRoleSessionCredentialsProviderFuture {
@@ -309,6 +326,8 @@ impl StsAssumeRoleSessionCredentialsProvider {
             policy: self.scope_down_policy.clone(),
             serial_number: self.mfa_serial.clone(),
             token_code: self.mfa_code.clone(),
+            tags: non_empty(&self.tags),
+            transitive_tag_keys: non_empty(&self.transitive_tag_keys),
             ..Default::default()
         };
         StsAssumeRoleSessionCredentialsProviderFuture {
@@ -351,6 +370,190 @@ impl ProvideAwsCredentials for StsAssumeRoleSessionCredentialsProvider {
     }
 }
 
+/// A list option of a request, left out when empty.
+fn non_empty<T: Clone>(items: &[T]) -> Option<Vec<T>> {
+    if items.is_empty() {
+        None
+    } else {
+        Some(items.to_vec())
+    }
+}
+
+/// Callback producing the SAML assertion of an `AssumeRoleWithSAML` call.
+type SamlAssertionCallback = dyn Fn() -> Result<String, CredentialsError> + Send + Sync;
+
+/// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that calls
+/// `AssumeRoleWithSAML` using the provided [StsClient](struct.StsClient.html), with a
+/// SAML assertion from an identity provider such as ADFS or Okta.
+///
+/// SAML assertions are usually only valid for a few minutes, much less than the
+/// credentials they are exchanged for, so a fresh assertion is asked from the callback
+/// every time credentials are fetched, and once more if STS rejects the assertion as
+/// expired. Wrap the provider in an `AutoRefreshingProvider` to only fetch credentials
+/// again when they expire.
+///
+/// `AssumeRoleWithSAML` needs no AWS credentials: the [StsClient](struct.StsClient.html)
+/// can be created from a `Client::new_not_signing` client.
+pub struct StsSamlFederationSessionCredentialsProvider {
+    sts_client: Arc<dyn StsSessionCredentialsClient + Send + Sync>,
+    saml_assertion: Arc<SamlAssertionCallback>,
+    principal_arn: String,
+    role_arn: String,
+    session_duration: Duration,
+    scope_down_policy: Option<String>,
+}
+
+impl StsSamlFederationSessionCredentialsProvider {
+    /// Creates a new `StsSamlFederationSessionCredentialsProvider` with the given
+    /// [StsClient](struct.StsClient.html) and session parameters.
+    ///
+    /// * `sts_client` - The [StsClient](struct.StsClient.html) to use to acquire session tokens.
+    /// * `saml_assertion` - Produces the base-64 encoded SAML authentication response of the identity provider, called for every call to `AssumeRoleWithSAML`.
+    /// * `principal_arn` - The ARN of the SAML provider in IAM that describes the identity provider.
+    /// * `role_arn` - The ARN of the role to assume.
+    /// * `session_duration` - Duration of session tokens. Default 1 hour.
+    /// * `scope_down_policy` - Optional inline IAM policy in JSON format to further restrict the access granted to the negotiated session.
+    pub fn new<F>(
+        sts_client: StsClient,
+        saml_assertion: F,
+        principal_arn: String,
+        role_arn: String,
+        session_duration: Option<Duration>,
+        scope_down_policy: Option<String>,
+    ) -> StsSamlFederationSessionCredentialsProvider
+    where
+        F: Fn() -> Result<String, CredentialsError> + Send + Sync + 'static,
+    {
+        StsSamlFederationSessionCredentialsProvider {
+            sts_client: Arc::new(sts_client),
+            saml_assertion: Arc::new(saml_assertion),
+            principal_arn,
+            role_arn,
+            session_duration: session_duration
+                .unwrap_or_else(|| Duration::seconds(DEFAULT_DURATION_SECONDS)),
+            scope_down_policy,
+        }
+    }
+
+    /// Calls `AssumeRoleWithSAML` with a fresh SAML assertion to get a session token
+    /// from the STS Api.
+    pub fn assume_role_with_saml(&self) -> StsSamlFederationSessionCredentialsProviderFuture {
+        let call = SamlCall {
+            sts_client: self.sts_client.clone(),
+            saml_assertion: self.saml_assertion.clone(),
+            request: AssumeRoleWithSAMLRequest {
+                principal_arn: self.principal_arn.clone(),
+                role_arn: self.role_arn.clone(),
+                duration_seconds: Some(self.session_duration.num_seconds() as i64),
+                policy: self.scope_down_policy.clone(),
+                ..Default::default()
+            },
+        };
+        StsSamlFederationSessionCredentialsProviderFuture {
+            inner: call.start(),
+            call,
+            retried: false,
+        }
+    }
+}
+
+/// An `AssumeRoleWithSAML` call, which can be made again with a fresh assertion.
+struct SamlCall {
+    sts_client: Arc<dyn StsSessionCredentialsClient + Send + Sync>,
+    saml_assertion: Arc<SamlAssertionCallback>,
+    request: AssumeRoleWithSAMLRequest,
+}
+
+impl SamlCall {
+    fn start(
+        &self,
+    ) -> Result<RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError>, CredentialsError>
+    {
+        let saml_assertion = (self.saml_assertion)().map_err(|err| {
+            CredentialsError::new(format!("Couldn't get a SAML assertion: {}", err))
+        })?;
+        Ok(self
+            .sts_client
+            .assume_role_with_saml(AssumeRoleWithSAMLRequest {
+                saml_assertion,
+                ..self.request.clone()
+            }))
+    }
+}
+
+pub struct StsSamlFederationSessionCredentialsProviderFuture {
+    call: SamlCall,
+    inner:
+        Result<RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError>, CredentialsError>,
+    retried: bool,
+}
+
+impl Future for StsSamlFederationSessionCredentialsProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            let result = match self.inner {
+                Ok(ref mut future) => future.poll(),
+                Err(ref err) => return Err(CredentialsError::new(err.message.clone())),
+            };
+            match result {
+                Ok(Async::Ready(resp)) => {
+                    let creds = resp
+                        .credentials
+                        .ok_or_else(|| CredentialsError::new("no credentials in response"))?;
+
+                    let mut aws_creds = AwsCredentials::new_for_credentials(creds)?;
+
+                    if let Some(subject) = resp.subject {
+                        aws_creds
+                            .claims_mut()
+                            .insert(rusoto_core::credential::claims::SUBJECT.to_owned(), subject);
+                    }
+
+                    if let Some(audience) = resp.audience {
+                        aws_creds.claims_mut().insert(
+                            rusoto_core::credential::claims::AUDIENCE.to_owned(),
+                            audience,
+                        );
+                    }
+
+                    if let Some(issuer) = resp.issuer {
+                        aws_creds
+                            .claims_mut()
+                            .insert(rusoto_core::credential::claims::ISSUER.to_owned(), issuer);
+                    }
+
+                    return Ok(Async::Ready(aws_creds));
+                }
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                // the assertion expired before reaching STS, try once more with a fresh one
+                Err(RusotoError::Service(AssumeRoleWithSAMLError::ExpiredToken(_)))
+                    if !self.retried =>
+                {
+                    self.retried = true;
+                    self.inner = self.call.start();
+                }
+                Err(err) => {
+                    return Err(CredentialsError::new(format!(
+                        "Sts AssumeRoleWithSAMLError: {:?}",
+                        err
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl ProvideAwsCredentials for StsSamlFederationSessionCredentialsProvider {
+    type Future = StsSamlFederationSessionCredentialsProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        self.assume_role_with_saml()
+    }
+}
+
 /// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that calls
 /// `AssumeRoleWithWebIdentity` using the provided [StsClient](struct.StsClient.html).
 pub struct StsWebIdentityFederationSessionCredentialsProvider {
@@ -477,4 +680,292 @@ fn sts_futures_are_send() {
     is_send::<StsSessionCredentialsProvider>();
     is_send::<StsAssumeRoleSessionCredentialsProvider>();
     is_send::<StsWebIdentityFederationSessionCredentialsProvider>();
+    is_send::<StsSamlFederationSessionCredentialsProvider>();
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate rusoto_mock;
+
+    use std::sync::Mutex;
+    use std::time::Duration as StdDuration;
+
+    use self::rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use super::*;
+    use futures::future::{ok, FutureResult};
+    use rusoto_core::request::{DispatchSignedRequest, HttpDispatchError, HttpResponse};
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::{ByteStream, Client, Region};
+
+    const CREDENTIALS: &str = "<Credentials>
+          <AccessKeyId>ASIAJEXAMPLEXEG2JICEA</AccessKeyId>
+          <SecretAccessKey>9drTJvcXLB89EXAMPLELB8923FB892xMFI</SecretAccessKey>
+          <SessionToken>AQoXdG9rZW4EXAMPLE</SessionToken>
+          <Expiration>2019-11-09T13:34:41Z</Expiration>
+        </Credentials>";
+
+    fn assume_role_response() -> String {
+        format!(
+            "<AssumeRoleResponse xmlns=\"https://sts.amazonaws.com/doc/2011-06-15/\">
+              <AssumeRoleResult>{}</AssumeRoleResult>
+              <ResponseMetadata>
+                <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+              </ResponseMetadata>
+            </AssumeRoleResponse>",
+            CREDENTIALS
+        )
+    }
+
+    fn assume_role_with_saml_response() -> String {
+        format!(
+            "<AssumeRoleWithSAMLResponse xmlns=\"https://sts.amazonaws.com/doc/2011-06-15/\">
+              <AssumeRoleWithSAMLResult>
+                {}
+                <Audience>https://signin.aws.amazon.com/saml</Audience>
+                <Issuer>https://adfs.example.com/adfs/services/trust</Issuer>
+                <Subject>jane.doe</Subject>
+              </AssumeRoleWithSAMLResult>
+              <ResponseMetadata>
+                <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+              </ResponseMetadata>
+            </AssumeRoleWithSAMLResponse>",
+            CREDENTIALS
+        )
+    }
+
+    const EXPIRED_TOKEN_RESPONSE: &str = "<ErrorResponse>
+          <Error>
+            <Type>Sender</Type>
+            <Code>ExpiredTokenException</Code>
+            <Message>Token expired</Message>
+          </Error>
+        </ErrorResponse>";
+
+    /// The form parameters of a request.
+    fn params(request: &SignedRequest) -> Vec<(String, String)> {
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => {
+                serde_urlencoded::from_bytes(body).unwrap()
+            }
+            _ => panic!("expected a buffered payload"),
+        }
+    }
+
+    fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+        params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn assume_role_provider(
+        checker: impl Fn(&SignedRequest) + Send + Sync + 'static,
+    ) -> StsAssumeRoleSessionCredentialsProvider {
+        let dispatcher = MockRequestDispatcher::with_status(200)
+            .with_body(&assume_role_response())
+            .with_request_checker(checker);
+        StsAssumeRoleSessionCredentialsProvider::new(
+            StsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1),
+            "arn:aws:iam::123456789012:role/demo".to_owned(),
+            "session".to_owned(),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn assume_role_passes_session_tags() {
+        let mut provider = assume_role_provider(|request| {
+            let params = params(request);
+            assert_eq!(param(&params, "Tags.member.1.Key"), Some("Project"));
+            assert_eq!(param(&params, "Tags.member.1.Value"), Some("Unicorn"));
+            assert_eq!(param(&params, "Tags.member.2.Key"), Some("Team"));
+            assert_eq!(param(&params, "Tags.member.2.Value"), Some("Automation"));
+            assert_eq!(param(&params, "Tags.member.3.Key"), None);
+            assert_eq!(
+                param(&params, "TransitiveTagKeys.member.1"),
+                Some("Project")
+            );
+            assert_eq!(param(&params, "TransitiveTagKeys.member.2"), None);
+        });
+        provider.set_tags(vec![
+            Tag {
+                key: "Project".to_owned(),
+                value: "Unicorn".to_owned(),
+            },
+            Tag {
+                key: "Team".to_owned(),
+                value: "Automation".to_owned(),
+            },
+        ]);
+        provider.set_transitive_tag_keys(vec!["Project".to_owned()]);
+
+        let credentials = provider.credentials().wait().unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "ASIAJEXAMPLEXEG2JICEA");
+    }
+
+    #[test]
+    fn assume_role_without_session_tags_passes_none() {
+        let provider = assume_role_provider(|request| {
+            for (key, _) in params(request) {
+                assert!(
+                    !key.starts_with("Tags") && !key.starts_with("TransitiveTagKeys"),
+                    "{}",
+                    key
+                );
+            }
+        });
+        assert!(provider.credentials().wait().is_ok());
+    }
+
+    /// Answers requests with the next of the given responses, keeping the requests.
+    #[derive(Clone, Default)]
+    struct RecordingDispatcher {
+        responses: Arc<Mutex<Vec<(u16, String)>>>,
+        requests: Arc<Mutex<Vec<SignedRequest>>>,
+    }
+
+    impl RecordingDispatcher {
+        fn new(responses: Vec<(u16, String)>) -> RecordingDispatcher {
+            RecordingDispatcher {
+                responses: Arc::new(Mutex::new(responses.into_iter().rev().collect())),
+                requests: Arc::default(),
+            }
+        }
+
+        fn saml_assertions(&self) -> Vec<String> {
+            self.requests
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|request| param(&params(request), "SAMLAssertion").unwrap().to_owned())
+                .collect()
+        }
+    }
+
+    impl DispatchSignedRequest for RecordingDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<StdDuration>) -> Self::Future {
+            self.requests.lock().unwrap().push(request);
+            let (status, body) = self.responses.lock().unwrap().pop().unwrap();
+            ok(HttpResponse {
+                status: status.to_string().parse().unwrap(),
+                body: ByteStream::from(body.into_bytes()),
+                headers: Default::default(),
+                url: None,
+            })
+        }
+    }
+
+    fn saml_provider(
+        dispatcher: &RecordingDispatcher,
+        assertion_fails: bool,
+    ) -> (
+        StsSamlFederationSessionCredentialsProvider,
+        Arc<Mutex<usize>>,
+    ) {
+        let assertions = Arc::new(Mutex::new(0));
+        let counter = assertions.clone();
+        let provider = StsSamlFederationSessionCredentialsProvider::new(
+            StsClient::new_with_client(
+                Client::new_not_signing(dispatcher.clone()),
+                Region::UsEast1,
+            ),
+            move || {
+                if assertion_fails {
+                    return Err(CredentialsError::new("identity provider unavailable"));
+                }
+                let mut count = counter.lock().unwrap();
+                *count += 1;
+                Ok(format!("assertion-{}", count))
+            },
+            "arn:aws:iam::123456789012:saml-provider/ADFS".to_owned(),
+            "arn:aws:iam::123456789012:role/demo".to_owned(),
+            Some(Duration::seconds(1800)),
+            None,
+        );
+        (provider, assertions)
+    }
+
+    #[test]
+    fn saml_provider_sends_a_fresh_assertion_for_every_fetch() {
+        let dispatcher = RecordingDispatcher::new(vec![
+            (200, assume_role_with_saml_response()),
+            (200, assume_role_with_saml_response()),
+        ]);
+        let (provider, _) = saml_provider(&dispatcher, false);
+
+        let credentials = provider.credentials().wait().unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "ASIAJEXAMPLEXEG2JICEA");
+        assert_eq!(credentials.token(), &Some("AQoXdG9rZW4EXAMPLE".to_owned()));
+        assert_eq!(
+            credentials
+                .claims()
+                .get(rusoto_core::credential::claims::SUBJECT),
+            Some(&"jane.doe".to_owned())
+        );
+        provider.credentials().wait().unwrap();
+
+        assert_eq!(
+            dispatcher.saml_assertions(),
+            vec!["assertion-1".to_owned(), "assertion-2".to_owned()]
+        );
+        let requests = dispatcher.requests.lock().unwrap();
+        let params = params(&requests[0]);
+        assert_eq!(param(&params, "Action"), Some("AssumeRoleWithSAML"));
+        assert_eq!(
+            param(&params, "PrincipalArn"),
+            Some("arn:aws:iam::123456789012:saml-provider/ADFS")
+        );
+        assert_eq!(
+            param(&params, "RoleArn"),
+            Some("arn:aws:iam::123456789012:role/demo")
+        );
+        assert_eq!(param(&params, "DurationSeconds"), Some("1800"));
+        assert!(!requests[0].headers().contains_key("authorization"));
+    }
+
+    #[test]
+    fn saml_provider_retries_an_expired_assertion_once() {
+        let dispatcher = RecordingDispatcher::new(vec![
+            (400, EXPIRED_TOKEN_RESPONSE.to_owned()),
+            (200, assume_role_with_saml_response()),
+        ]);
+        let (provider, _) = saml_provider(&dispatcher, false);
+
+        assert!(provider.credentials().wait().is_ok());
+        assert_eq!(
+            dispatcher.saml_assertions(),
+            vec!["assertion-1".to_owned(), "assertion-2".to_owned()]
+        );
+    }
+
+    #[test]
+    fn saml_provider_fails_when_fresh_assertions_keep_expiring() {
+        let dispatcher = RecordingDispatcher::new(vec![
+            (400, EXPIRED_TOKEN_RESPONSE.to_owned()),
+            (400, EXPIRED_TOKEN_RESPONSE.to_owned()),
+        ]);
+        let (provider, assertions) = saml_provider(&dispatcher, false);
+
+        let err = provider.credentials().wait().unwrap_err();
+        assert!(err.message.contains("ExpiredToken"), "{}", err.message);
+        assert_eq!(*assertions.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn saml_provider_fails_without_an_assertion() {
+        let dispatcher = RecordingDispatcher::new(Vec::new());
+        let (provider, _) = saml_provider(&dispatcher, true);
+
+        let err = provider.credentials().wait().unwrap_err();
+        assert_eq!(
+            err.message,
+            "Couldn't get a SAML assertion: identity provider unavailable"
+        );
+        assert!(dispatcher.requests.lock().unwrap().is_empty());
+    }
 }
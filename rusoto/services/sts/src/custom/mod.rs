@@ -1,6 +1,7 @@
 mod credential;
 
 pub use self::credential::{
-    NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
+    NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider,
+    StsSamlFederationSessionCredentialsProvider, StsSessionCredentialsProvider,
     StsWebIdentityFederationSessionCredentialsProvider,
 };
@@ -23,11 +23,12 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
 use rusoto_core::proto::xml::util::{
-    characters, deserialize_elements, end_element, find_start_element, peek_at_name, skip_tree,
-    start_element,
+    characters, deserialize_elements, end_element, expect_xml, find_start_element, parse_primitive,
+    peek_at_name, skip_tree, start_element,
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -78,6 +79,8 @@ impl ArnTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumeRoleRequest {
     /// <p><p>The duration, in seconds, of the role session. The value can range from 900 seconds (15 minutes) up to the maximum session duration setting for the role. This setting can have a value from 1 hour to 12 hours. If you specify a value higher than this setting, the operation fails. For example, if you specify a session duration of 12 hours, but your administrator set the maximum session duration to 6 hours, your operation fails. To learn how to view the maximum value for your role, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use.html#id_roles_use_view-role-max-session">View the Maximum Session Duration Setting for a Role</a> in the <i>IAM User Guide</i>.</p> <p>By default, the value is set to <code>3600</code> seconds. </p> <note> <p>The <code>DurationSeconds</code> parameter is separate from the duration of a console session that you might request using the returned credentials. The request to the federation endpoint for a console sign-in token takes a <code>SessionDuration</code> parameter that specifies the maximum length of the console session. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_providers_enable-console-custom-url.html">Creating a URL that Enables Federated Users to Access the AWS Management Console</a> in the <i>IAM User Guide</i>.</p> </note></p>
     pub duration_seconds: Option<i64>,
@@ -93,8 +96,109 @@ pub struct AssumeRoleRequest {
     pub role_session_name: String,
     /// <p>The identification number of the MFA device that is associated with the user who is making the <code>AssumeRole</code> call. Specify this value if the trust policy of the role being assumed includes a condition that requires MFA authentication. The value is either the serial number for a hardware device (such as <code>GAHT12345678</code>) or an Amazon Resource Name (ARN) for a virtual device (such as <code>arn:aws:iam::123456789012:mfa/user</code>).</p> <p>The regex used to validate this parameter is a string of characters consisting of upper- and lower-case alphanumeric characters with no spaces. You can also include underscores or any of the following characters: =,.@-</p>
     pub serial_number: Option<String>,
+    /// <p>A list of session tags that you want to pass. Each session tag consists of a key name and an associated value. For more information about session tags, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Tagging AWS STS Sessions</a> in the <i>IAM User Guide</i>.</p> <p>This parameter is optional. You can pass up to 50 session tags. The plain text session tag keys can’t exceed 128 characters, and the values can’t exceed 256 characters.</p> <note> <p>An AWS conversion compresses the passed session policies and session tags into a packed binary format that has a separate limit. Your request can fail for this limit even if your plain text meets the other requirements. The <code>PackedPolicySize</code> response element indicates by percentage how close the policies and tags for your request are to the upper size limit.</p> </note> <p>You can pass a session tag with the same key as a tag that is already attached to the role. When you do, session tags override a role tag with the same key.</p> <p>Tag key–value pairs are not case sensitive, but case is preserved. This means that you cannot have separate <code>Department</code> and <code>department</code> tag keys. Assume that the role has the <code>Department</code>=<code>Marketing</code> tag and you pass the <code>department</code>=<code>engineering</code> session tag. <code>Department</code> and <code>department</code> are not saved as separate tags, and the session tag passed in the request takes precedence over the role tag.</p>
+    pub tags: Option<Vec<Tag>>,
     /// <p>The value provided by the MFA device, if the trust policy of the role being assumed requires MFA (that is, if the policy includes a condition that tests for MFA). If the role being assumed requires MFA and if the <code>TokenCode</code> value is missing or expired, the <code>AssumeRole</code> call returns an "access denied" error.</p> <p>The format for this parameter, as described by its regex pattern, is a sequence of six numeric digits.</p>
     pub token_code: Option<String>,
+    /// <p>A list of keys for session tags that you want to set as transitive. If you set a tag key as transitive, the corresponding key and value passes to subsequent sessions in a role chain. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html#id_session-tags_role-chaining">Chaining Roles with Session Tags</a> in the <i>IAM User Guide</i>.</p> <p>This parameter is optional. When you set session tags as transitive, the session policy and session tags packed binary limit is not affected.</p> <p>If you choose not to specify a transitive tag key, then no tags are passed from this session to any subsequent sessions.</p>
+    pub transitive_tag_keys: Option<Vec<String>>,
+}
+
+impl Validate for AssumeRoleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_seconds {
+            errors.check_range(
+                &field_path(path, "duration_seconds"),
+                *value,
+                Some(900),
+                Some(43200),
+            );
+        }
+        if let Some(ref value) = self.external_id {
+            errors.check_length(
+                &field_path(path, "external_id"),
+                value.chars().count(),
+                Some(2),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "external_id"), &value, "[\\w+=,.@:\\/-]*");
+        }
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+            errors.check_pattern(
+                &field_path(path, "policy"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+            );
+        }
+        if let Some(ref value) = self.policy_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_arns"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]+");
+        errors.check_length(
+            &field_path(path, "role_session_name"),
+            self.role_session_name.chars().count(),
+            Some(2),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_session_name"),
+            &self.role_session_name,
+            "[\\w+=,.@-]*",
+        );
+        if let Some(ref value) = self.serial_number {
+            errors.check_length(
+                &field_path(path, "serial_number"),
+                value.chars().count(),
+                Some(9),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "serial_number"), &value, "[\\w+=/:,.@-]*");
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), None, Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.token_code {
+            errors.check_length(
+                &field_path(path, "token_code"),
+                value.chars().count(),
+                Some(6),
+                Some(6),
+            );
+            errors.check_pattern(&field_path(path, "token_code"), &value, "[\\d]*");
+        }
+        if let Some(ref value) = self.transitive_tag_keys {
+            errors.check_length(
+                &field_path(path, "transitive_tag_keys"),
+                value.len(),
+                None,
+                Some(50),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "transitive_tag_keys"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+                errors.check_pattern(&item_path, &item, "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]+");
+            }
+        }
+    }
 }
 
 /// Serialize `AssumeRoleRequest` contents to a `SignedRequest`.
@@ -130,14 +234,26 @@ impl AssumeRoleRequestSerializer {
         if let Some(ref field_value) = obj.serial_number {
             params.put(&format!("{}{}", prefix, "SerialNumber"), &field_value);
         }
+        if let Some(ref field_value) = obj.tags {
+            TagListTypeSerializer::serialize(params, &format!("{}{}", prefix, "Tags"), field_value);
+        }
         if let Some(ref field_value) = obj.token_code {
             params.put(&format!("{}{}", prefix, "TokenCode"), &field_value);
         }
+        if let Some(ref field_value) = obj.transitive_tag_keys {
+            TagKeyListTypeSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "TransitiveTagKeys"),
+                field_value,
+            );
+        }
     }
 }
 
 /// <p>Contains the response to a successful <a>AssumeRole</a> request, including temporary AWS credentials that can be used to make AWS requests. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumeRoleResponse {
     /// <p>The Amazon Resource Name (ARN) and the assumed role ID, which are identifiers that you can use to refer to the resulting temporary security credentials. For example, you can reference these credentials as a principal in a resource-based policy by using the ARN or assumed role ID. The ARN and ID include the <code>RoleSessionName</code> that you specified when you called <code>AssumeRole</code>. </p>
     pub assumed_role_user: Option<AssumedRoleUser>,
@@ -179,6 +295,8 @@ impl AssumeRoleResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumeRoleWithSAMLRequest {
     /// <p><p>The duration, in seconds, of the role session. Your role session lasts for the duration that you specify for the <code>DurationSeconds</code> parameter, or until the time specified in the SAML authentication response&#39;s <code>SessionNotOnOrAfter</code> value, whichever is shorter. You can provide a <code>DurationSeconds</code> value from 900 seconds (15 minutes) up to the maximum session duration setting for the role. This setting can have a value from 1 hour to 12 hours. If you specify a value higher than this setting, the operation fails. For example, if you specify a session duration of 12 hours, but your administrator set the maximum session duration to 6 hours, your operation fails. To learn how to view the maximum value for your role, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use.html#id_roles_use_view-role-max-session">View the Maximum Session Duration Setting for a Role</a> in the <i>IAM User Guide</i>.</p> <p>By default, the value is set to <code>3600</code> seconds. </p> <note> <p>The <code>DurationSeconds</code> parameter is separate from the duration of a console session that you might request using the returned credentials. The request to the federation endpoint for a console sign-in token takes a <code>SessionDuration</code> parameter that specifies the maximum length of the console session. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_providers_enable-console-custom-url.html">Creating a URL that Enables Federated Users to Access the AWS Management Console</a> in the <i>IAM User Guide</i>.</p> </note></p>
     pub duration_seconds: Option<i64>,
@@ -194,6 +312,58 @@ pub struct AssumeRoleWithSAMLRequest {
     pub saml_assertion: String,
 }
 
+impl Validate for AssumeRoleWithSAMLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_seconds {
+            errors.check_range(
+                &field_path(path, "duration_seconds"),
+                *value,
+                Some(900),
+                Some(43200),
+            );
+        }
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+            );
+        }
+        if let Some(ref value) = self.policy_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_arns"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "principal_arn"),
+            self.principal_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "principal_arn"), &self.principal_arn, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]+");
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]+");
+        errors.check_length(
+            &field_path(path, "saml_assertion"),
+            self.saml_assertion.chars().count(),
+            Some(4),
+            Some(100000),
+        );
+    }
+}
+
 /// Serialize `AssumeRoleWithSAMLRequest` contents to a `SignedRequest`.
 struct AssumeRoleWithSAMLRequestSerializer;
 impl AssumeRoleWithSAMLRequestSerializer {
@@ -227,6 +397,8 @@ impl AssumeRoleWithSAMLRequestSerializer {
 
 /// <p>Contains the response to a successful <a>AssumeRoleWithSAML</a> request, including temporary AWS credentials that can be used to make AWS requests. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumeRoleWithSAMLResponse {
     /// <p>The identifiers for the temporary security credentials that the operation returns.</p>
     pub assumed_role_user: Option<AssumedRoleUser>,
@@ -302,6 +474,8 @@ impl AssumeRoleWithSAMLResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumeRoleWithWebIdentityRequest {
     /// <p><p>The duration, in seconds, of the role session. The value can range from 900 seconds (15 minutes) up to the maximum session duration setting for the role. This setting can have a value from 1 hour to 12 hours. If you specify a value higher than this setting, the operation fails. For example, if you specify a session duration of 12 hours, but your administrator set the maximum session duration to 6 hours, your operation fails. To learn how to view the maximum value for your role, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use.html#id_roles_use_view-role-max-session">View the Maximum Session Duration Setting for a Role</a> in the <i>IAM User Guide</i>.</p> <p>By default, the value is set to <code>3600</code> seconds. </p> <note> <p>The <code>DurationSeconds</code> parameter is separate from the duration of a console session that you might request using the returned credentials. The request to the federation endpoint for a console sign-in token takes a <code>SessionDuration</code> parameter that specifies the maximum length of the console session. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_providers_enable-console-custom-url.html">Creating a URL that Enables Federated Users to Access the AWS Management Console</a> in the <i>IAM User Guide</i>.</p> </note></p>
     pub duration_seconds: Option<i64>,
@@ -319,6 +493,70 @@ pub struct AssumeRoleWithWebIdentityRequest {
     pub web_identity_token: String,
 }
 
+impl Validate for AssumeRoleWithWebIdentityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_seconds {
+            errors.check_range(
+                &field_path(path, "duration_seconds"),
+                *value,
+                Some(900),
+                Some(43200),
+            );
+        }
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+            );
+        }
+        if let Some(ref value) = self.policy_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_arns"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.provider_id {
+            errors.check_length(
+                &field_path(path, "provider_id"),
+                value.chars().count(),
+                Some(4),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "role_arn"), &self.role_arn, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]+");
+        errors.check_length(
+            &field_path(path, "role_session_name"),
+            self.role_session_name.chars().count(),
+            Some(2),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_session_name"),
+            &self.role_session_name,
+            "[\\w+=,.@-]*",
+        );
+        errors.check_length(
+            &field_path(path, "web_identity_token"),
+            self.web_identity_token.chars().count(),
+            Some(4),
+            Some(20000),
+        );
+    }
+}
+
 /// Serialize `AssumeRoleWithWebIdentityRequest` contents to a `SignedRequest`.
 struct AssumeRoleWithWebIdentityRequestSerializer;
 impl AssumeRoleWithWebIdentityRequestSerializer {
@@ -358,6 +596,8 @@ impl AssumeRoleWithWebIdentityRequestSerializer {
 
 /// <p>Contains the response to a successful <a>AssumeRoleWithWebIdentity</a> request, including temporary AWS credentials that can be used to make AWS requests. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumeRoleWithWebIdentityResponse {
     /// <p>The Amazon Resource Name (ARN) and the assumed role ID, which are identifiers that you can use to refer to the resulting temporary security credentials. For example, you can reference these credentials as a principal in a resource-based policy by using the ARN or assumed role ID. The ARN and ID include the <code>RoleSessionName</code> that you specified when you called <code>AssumeRole</code>. </p>
     pub assumed_role_user: Option<AssumedRoleUser>,
@@ -435,6 +675,8 @@ impl AssumedRoleIdTypeDeserializer {
 }
 /// <p>The identifiers for the temporary security credentials that the operation returns.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssumedRoleUser {
     /// <p>The ARN of the temporary security credentials that are returned from the <a>AssumeRole</a> action. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html">IAM Identifiers</a> in <i>Using IAM</i>. </p>
     pub arn: String,
@@ -477,6 +719,8 @@ impl AudienceDeserializer {
 }
 /// <p>AWS credentials for API authentication.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Credentials {
     /// <p>The access key ID that identifies the temporary security credentials.</p>
     pub access_key_id: String,
@@ -529,11 +773,24 @@ impl DateTypeDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecodeAuthorizationMessageRequest {
     /// <p>The encoded message that was returned with the response.</p>
     pub encoded_message: String,
 }
 
+impl Validate for DecodeAuthorizationMessageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "encoded_message"),
+            self.encoded_message.chars().count(),
+            Some(1),
+            Some(10240),
+        );
+    }
+}
+
 /// Serialize `DecodeAuthorizationMessageRequest` contents to a `SignedRequest`.
 struct DecodeAuthorizationMessageRequestSerializer;
 impl DecodeAuthorizationMessageRequestSerializer {
@@ -552,6 +809,8 @@ impl DecodeAuthorizationMessageRequestSerializer {
 
 /// <p>A document that contains additional information about the authorization status of a request from an encoded message that is returned in response to an AWS request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DecodeAuthorizationMessageResponse {
     /// <p>An XML document that contains the decoded message.</p>
     pub decoded_message: Option<String>,
@@ -606,6 +865,8 @@ impl FederatedIdTypeDeserializer {
 }
 /// <p>Identifiers for the federated user that is associated with the credentials.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FederatedUser {
     /// <p>The ARN that specifies the federated user that is associated with the credentials. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html">IAM Identifiers</a> in <i>Using IAM</i>. </p>
     pub arn: String,
@@ -636,11 +897,29 @@ impl FederatedUserDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccessKeyInfoRequest {
     /// <p>The identifier of an access key.</p> <p>This parameter allows (through its regex pattern) a string of characters that can consist of any upper- or lowercased letter or digit.</p>
     pub access_key_id: String,
 }
 
+impl Validate for GetAccessKeyInfoRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "access_key_id"),
+            self.access_key_id.chars().count(),
+            Some(16),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "access_key_id"),
+            &self.access_key_id,
+            "[\\w]*",
+        );
+    }
+}
+
 /// Serialize `GetAccessKeyInfoRequest` contents to a `SignedRequest`.
 struct GetAccessKeyInfoRequestSerializer;
 impl GetAccessKeyInfoRequestSerializer {
@@ -655,6 +934,8 @@ impl GetAccessKeyInfoRequestSerializer {
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetAccessKeyInfoResponse {
     /// <p>The number used to identify the AWS account.</p>
     pub account: Option<String>,
@@ -683,6 +964,8 @@ impl GetAccessKeyInfoResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCallerIdentityRequest {}
 
 /// Serialize `GetCallerIdentityRequest` contents to a `SignedRequest`.
@@ -698,6 +981,8 @@ impl GetCallerIdentityRequestSerializer {
 
 /// <p>Contains the response to a successful <a>GetCallerIdentity</a> request, including information about the entity making the request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCallerIdentityResponse {
     /// <p>The AWS account ID number of the account that owns or contains the calling entity.</p>
     pub account: Option<String>,
@@ -736,6 +1021,8 @@ impl GetCallerIdentityResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFederationTokenRequest {
     /// <p>The duration, in seconds, that the session should last. Acceptable durations for federation sessions range from 900 seconds (15 minutes) to 129,600 seconds (36 hours), with 43,200 seconds (12 hours) as the default. Sessions obtained using AWS account root user credentials are restricted to a maximum of 3,600 seconds (one hour). If the specified duration is longer than one hour, the session obtained by using root user credentials defaults to one hour.</p>
     pub duration_seconds: Option<i64>,
@@ -747,6 +1034,45 @@ pub struct GetFederationTokenRequest {
     pub policy_arns: Option<Vec<PolicyDescriptorType>>,
 }
 
+impl Validate for GetFederationTokenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_seconds {
+            errors.check_range(
+                &field_path(path, "duration_seconds"),
+                *value,
+                Some(900),
+                Some(129600),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(2),
+            Some(32),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\w+=,.@-]*");
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "policy"),
+                &value,
+                "[\\u0009\\u000A\\u000D\\u0020-\\u00FF]+",
+            );
+        }
+        if let Some(ref value) = self.policy_arns {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "policy_arns"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 /// Serialize `GetFederationTokenRequest` contents to a `SignedRequest`.
 struct GetFederationTokenRequestSerializer;
 impl GetFederationTokenRequestSerializer {
@@ -775,6 +1101,8 @@ impl GetFederationTokenRequestSerializer {
 
 /// <p>Contains the response to a successful <a>GetFederationToken</a> request, including temporary AWS credentials that can be used to make AWS requests. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFederationTokenResponse {
     /// <p><p>The temporary security credentials, which include an access key ID, a secret access key, and a security (or session) token.</p> <note> <p>The size of the security token that STS API operations return is not fixed. We strongly recommend that you make no assumptions about the maximum size.</p> </note></p>
     pub credentials: Option<Credentials>,
@@ -821,6 +1149,8 @@ impl GetFederationTokenResponseDeserializer {
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSessionTokenRequest {
     /// <p>The duration, in seconds, that the credentials should remain valid. Acceptable durations for IAM user sessions range from 900 seconds (15 minutes) to 129,600 seconds (36 hours), with 43,200 seconds (12 hours) as the default. Sessions for AWS account owners are restricted to a maximum of 3,600 seconds (one hour). If the duration is longer than one hour, the session for AWS account owners defaults to one hour.</p>
     pub duration_seconds: Option<i64>,
@@ -830,6 +1160,37 @@ pub struct GetSessionTokenRequest {
     pub token_code: Option<String>,
 }
 
+impl Validate for GetSessionTokenRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.duration_seconds {
+            errors.check_range(
+                &field_path(path, "duration_seconds"),
+                *value,
+                Some(900),
+                Some(129600),
+            );
+        }
+        if let Some(ref value) = self.serial_number {
+            errors.check_length(
+                &field_path(path, "serial_number"),
+                value.chars().count(),
+                Some(9),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "serial_number"), &value, "[\\w+=/:,.@-]*");
+        }
+        if let Some(ref value) = self.token_code {
+            errors.check_length(
+                &field_path(path, "token_code"),
+                value.chars().count(),
+                Some(6),
+                Some(6),
+            );
+            errors.check_pattern(&field_path(path, "token_code"), &value, "[\\d]*");
+        }
+    }
+}
+
 /// Serialize `GetSessionTokenRequest` contents to a `SignedRequest`.
 struct GetSessionTokenRequestSerializer;
 impl GetSessionTokenRequestSerializer {
@@ -853,6 +1214,8 @@ impl GetSessionTokenRequestSerializer {
 
 /// <p>Contains the response to a successful <a>GetSessionToken</a> request, including temporary AWS credentials that can be used to make AWS requests. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSessionTokenResponse {
     /// <p><p>The temporary security credentials, which include an access key ID, a secret access key, and a security (or session) token.</p> <note> <p>The size of the security token that STS API operations return is not fixed. We strongly recommend that you make no assumptions about the maximum size.</p> </note></p>
     pub credentials: Option<Credentials>,
@@ -908,7 +1271,7 @@ impl NonNegativeIntegerTypeDeserializer {
     #[allow(unused_variables)]
     fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        let obj = parse_primitive::<i64>(&characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -928,11 +1291,27 @@ impl PolicyDescriptorListTypeSerializer {
 
 /// <p>A reference to the IAM managed policy that is passed as a session policy for a role session or a federated user session.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PolicyDescriptorType {
     /// <p>The Amazon Resource Name (ARN) of the IAM managed policy to use as a session policy for the role. For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a> in the <i>AWS General Reference</i>.</p>
     pub arn: Option<String>,
 }
 
+impl Validate for PolicyDescriptorType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.arn {
+            errors.check_length(
+                &field_path(path, "arn"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "arn"), &value, "[\\u0009\\u000A\\u000D\\u0020-\\u007E\\u0085\\u00A0-\\uD7FF\\uE000-\\uFFFD\\u10000-\\u10FFFF]+");
+        }
+    }
+}
+
 /// Serialize `PolicyDescriptorType` contents to a `SignedRequest`.
 struct PolicyDescriptorTypeSerializer;
 impl PolicyDescriptorTypeSerializer {
@@ -970,6 +1349,80 @@ impl SubjectTypeDeserializer {
         Ok(obj)
     }
 }
+/// <p>You can pass custom key-value pair attributes when you assume a role or federate a user. These are called session tags. You can then use the session tags to control access to resources. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_session-tags.html">Tagging AWS STS Sessions</a> in the <i>IAM User Guide</i>.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
+pub struct Tag {
+    /// <p>The key for a session tag.</p> <p>You can pass up to 50 session tags. The plain text session tag keys can’t exceed 128 characters. For these and additional limits, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-limits.html#reference_iam-limits-entity-length">IAM and STS Character Limits</a> in the <i>IAM User Guide</i>.</p>
+    pub key: String,
+    /// <p>The value for a session tag.</p> <p>You can pass up to 50 session tags. The plain text session tag values can’t exceed 256 characters. For these and additional limits, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-limits.html#reference_iam-limits-entity-length">IAM and STS Character Limits</a> in the <i>IAM User Guide</i>.</p>
+    pub value: String,
+}
+
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "key"),
+            &self.key,
+            "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]+",
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "value"),
+            &self.value,
+            "[\\p{L}\\p{Z}\\p{N}_.:/=+\\-@]*",
+        );
+    }
+}
+
+/// Serialize `Tag` contents to a `SignedRequest`.
+struct TagSerializer;
+impl TagSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &Tag) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        params.put(&format!("{}{}", prefix, "Key"), &obj.key);
+        params.put(&format!("{}{}", prefix, "Value"), &obj.value);
+    }
+}
+
+/// Serialize `TagKeyListType` contents to a `SignedRequest`.
+struct TagKeyListTypeSerializer;
+impl TagKeyListTypeSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+        for (index, obj) in obj.iter().enumerate() {
+            let key = format!("{}.member.{}", name, index + 1);
+            params.put(&key, &obj);
+        }
+    }
+}
+
+/// Serialize `TagListType` contents to a `SignedRequest`.
+struct TagListTypeSerializer;
+impl TagListTypeSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<Tag>) {
+        for (index, obj) in obj.iter().enumerate() {
+            let key = format!("{}.member.{}", name, index + 1);
+            TagSerializer::serialize(params, &key, obj);
+        }
+    }
+}
+
 struct TokenTypeDeserializer;
 impl TokenTypeDeserializer {
     #[allow(unused_variables)]
@@ -1541,6 +1994,157 @@ pub trait Sts {
         input: GetSessionTokenRequest,
     ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError>;
 }
+const _: Option<&dyn Sts> = None;
+impl<'a, T: Sts + ?Sized> Sts for &'a T {
+    fn assume_role(
+        &self,
+        input: AssumeRoleRequest,
+    ) -> RusotoFuture<AssumeRoleResponse, AssumeRoleError> {
+        (**self).assume_role(input)
+    }
+    fn assume_role_with_saml(
+        &self,
+        input: AssumeRoleWithSAMLRequest,
+    ) -> RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError> {
+        (**self).assume_role_with_saml(input)
+    }
+    fn assume_role_with_web_identity(
+        &self,
+        input: AssumeRoleWithWebIdentityRequest,
+    ) -> RusotoFuture<AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError> {
+        (**self).assume_role_with_web_identity(input)
+    }
+    fn decode_authorization_message(
+        &self,
+        input: DecodeAuthorizationMessageRequest,
+    ) -> RusotoFuture<DecodeAuthorizationMessageResponse, DecodeAuthorizationMessageError> {
+        (**self).decode_authorization_message(input)
+    }
+    fn get_access_key_info(
+        &self,
+        input: GetAccessKeyInfoRequest,
+    ) -> RusotoFuture<GetAccessKeyInfoResponse, GetAccessKeyInfoError> {
+        (**self).get_access_key_info(input)
+    }
+    fn get_caller_identity(
+        &self,
+        input: GetCallerIdentityRequest,
+    ) -> RusotoFuture<GetCallerIdentityResponse, GetCallerIdentityError> {
+        (**self).get_caller_identity(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_session_token(
+        &self,
+        input: GetSessionTokenRequest,
+    ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError> {
+        (**self).get_session_token(input)
+    }
+}
+impl<T: Sts + ?Sized> Sts for Box<T> {
+    fn assume_role(
+        &self,
+        input: AssumeRoleRequest,
+    ) -> RusotoFuture<AssumeRoleResponse, AssumeRoleError> {
+        (**self).assume_role(input)
+    }
+    fn assume_role_with_saml(
+        &self,
+        input: AssumeRoleWithSAMLRequest,
+    ) -> RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError> {
+        (**self).assume_role_with_saml(input)
+    }
+    fn assume_role_with_web_identity(
+        &self,
+        input: AssumeRoleWithWebIdentityRequest,
+    ) -> RusotoFuture<AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError> {
+        (**self).assume_role_with_web_identity(input)
+    }
+    fn decode_authorization_message(
+        &self,
+        input: DecodeAuthorizationMessageRequest,
+    ) -> RusotoFuture<DecodeAuthorizationMessageResponse, DecodeAuthorizationMessageError> {
+        (**self).decode_authorization_message(input)
+    }
+    fn get_access_key_info(
+        &self,
+        input: GetAccessKeyInfoRequest,
+    ) -> RusotoFuture<GetAccessKeyInfoResponse, GetAccessKeyInfoError> {
+        (**self).get_access_key_info(input)
+    }
+    fn get_caller_identity(
+        &self,
+        input: GetCallerIdentityRequest,
+    ) -> RusotoFuture<GetCallerIdentityResponse, GetCallerIdentityError> {
+        (**self).get_caller_identity(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_session_token(
+        &self,
+        input: GetSessionTokenRequest,
+    ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError> {
+        (**self).get_session_token(input)
+    }
+}
+impl<T: Sts + ?Sized> Sts for ::std::sync::Arc<T> {
+    fn assume_role(
+        &self,
+        input: AssumeRoleRequest,
+    ) -> RusotoFuture<AssumeRoleResponse, AssumeRoleError> {
+        (**self).assume_role(input)
+    }
+    fn assume_role_with_saml(
+        &self,
+        input: AssumeRoleWithSAMLRequest,
+    ) -> RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError> {
+        (**self).assume_role_with_saml(input)
+    }
+    fn assume_role_with_web_identity(
+        &self,
+        input: AssumeRoleWithWebIdentityRequest,
+    ) -> RusotoFuture<AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError> {
+        (**self).assume_role_with_web_identity(input)
+    }
+    fn decode_authorization_message(
+        &self,
+        input: DecodeAuthorizationMessageRequest,
+    ) -> RusotoFuture<DecodeAuthorizationMessageResponse, DecodeAuthorizationMessageError> {
+        (**self).decode_authorization_message(input)
+    }
+    fn get_access_key_info(
+        &self,
+        input: GetAccessKeyInfoRequest,
+    ) -> RusotoFuture<GetAccessKeyInfoResponse, GetAccessKeyInfoError> {
+        (**self).get_access_key_info(input)
+    }
+    fn get_caller_identity(
+        &self,
+        input: GetCallerIdentityRequest,
+    ) -> RusotoFuture<GetCallerIdentityResponse, GetCallerIdentityError> {
+        (**self).get_caller_identity(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_session_token(
+        &self,
+        input: GetSessionTokenRequest,
+    ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError> {
+        (**self).get_session_token(input)
+    }
+}
 /// A client for the AWS STS API.
 #[derive(Clone)]
 pub struct StsClient {
@@ -1584,7 +2188,16 @@ impl Sts for StsClient {
         &self,
         input: AssumeRoleRequest,
     ) -> RusotoFuture<AssumeRoleResponse, AssumeRoleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRole");
         let mut params = Params::new();
 
         params.put("Action", "AssumeRole");
@@ -1609,6 +2222,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = AssumeRoleResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1617,10 +2231,9 @@ impl Sts for StsClient {
                     let _start_document = stack.next();
                     let actual_tag_name = peek_at_name(&mut stack)?;
                     start_element(&actual_tag_name, &mut stack)?;
-                    result = AssumeRoleResponseDeserializer::deserialize(
-                        "AssumeRoleResult",
-                        &mut stack,
-                    )?;
+                    result =
+                        AssumeRoleResponseDeserializer::deserialize("AssumeRoleResult", &mut stack)
+                            .map_err(|err| stack.parse_error("AssumeRole", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1635,7 +2248,16 @@ impl Sts for StsClient {
         &self,
         input: AssumeRoleWithSAMLRequest,
     ) -> RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRoleWithSAML");
         let mut params = Params::new();
 
         params.put("Action", "AssumeRoleWithSAML");
@@ -1660,6 +2282,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = AssumeRoleWithSAMLResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1671,7 +2294,8 @@ impl Sts for StsClient {
                     result = AssumeRoleWithSAMLResponseDeserializer::deserialize(
                         "AssumeRoleWithSAMLResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("AssumeRoleWithSAML", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1686,7 +2310,16 @@ impl Sts for StsClient {
         &self,
         input: AssumeRoleWithWebIdentityRequest,
     ) -> RusotoFuture<AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("AssumeRoleWithWebIdentity");
         let mut params = Params::new();
 
         params.put("Action", "AssumeRoleWithWebIdentity");
@@ -1708,6 +2341,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = AssumeRoleWithWebIdentityResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1719,7 +2353,10 @@ impl Sts for StsClient {
                     result = AssumeRoleWithWebIdentityResponseDeserializer::deserialize(
                         "AssumeRoleWithWebIdentityResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("AssumeRoleWithWebIdentity", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1734,7 +2371,16 @@ impl Sts for StsClient {
         &self,
         input: DecodeAuthorizationMessageRequest,
     ) -> RusotoFuture<DecodeAuthorizationMessageResponse, DecodeAuthorizationMessageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("DecodeAuthorizationMessage");
         let mut params = Params::new();
 
         params.put("Action", "DecodeAuthorizationMessage");
@@ -1756,6 +2402,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = DecodeAuthorizationMessageResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1767,7 +2414,10 @@ impl Sts for StsClient {
                     result = DecodeAuthorizationMessageResponseDeserializer::deserialize(
                         "DecodeAuthorizationMessageResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| {
+                        stack.parse_error("DecodeAuthorizationMessage", err, &response.body)
+                    })?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1782,7 +2432,16 @@ impl Sts for StsClient {
         &self,
         input: GetAccessKeyInfoRequest,
     ) -> RusotoFuture<GetAccessKeyInfoResponse, GetAccessKeyInfoError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetAccessKeyInfo");
         let mut params = Params::new();
 
         params.put("Action", "GetAccessKeyInfo");
@@ -1807,6 +2466,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = GetAccessKeyInfoResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1818,7 +2478,8 @@ impl Sts for StsClient {
                     result = GetAccessKeyInfoResponseDeserializer::deserialize(
                         "GetAccessKeyInfoResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetAccessKeyInfo", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1834,6 +2495,7 @@ impl Sts for StsClient {
         input: GetCallerIdentityRequest,
     ) -> RusotoFuture<GetCallerIdentityResponse, GetCallerIdentityError> {
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetCallerIdentity");
         let mut params = Params::new();
 
         params.put("Action", "GetCallerIdentity");
@@ -1858,6 +2520,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = GetCallerIdentityResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1869,7 +2532,8 @@ impl Sts for StsClient {
                     result = GetCallerIdentityResponseDeserializer::deserialize(
                         "GetCallerIdentityResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetCallerIdentity", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1884,7 +2548,16 @@ impl Sts for StsClient {
         &self,
         input: GetFederationTokenRequest,
     ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetFederationToken");
         let mut params = Params::new();
 
         params.put("Action", "GetFederationToken");
@@ -1909,6 +2582,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = GetFederationTokenResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1920,7 +2594,8 @@ impl Sts for StsClient {
                     result = GetFederationTokenResponseDeserializer::deserialize(
                         "GetFederationTokenResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetFederationToken", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1935,7 +2610,16 @@ impl Sts for StsClient {
         &self,
         input: GetSessionTokenRequest,
     ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "sts", &self.region, "/");
+        request.set_operation("GetSessionToken");
         let mut params = Params::new();
 
         params.put("Action", "GetSessionToken");
@@ -1960,6 +2644,7 @@ impl Sts for StsClient {
                 if response.body.is_empty() {
                     result = GetSessionTokenResponse::default();
                 } else {
+                    expect_xml(&response)?;
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
                         ParserConfig::new().trim_whitespace(true),
@@ -1971,7 +2656,8 @@ impl Sts for StsClient {
                     result = GetSessionTokenResponseDeserializer::deserialize(
                         "GetSessionTokenResult",
                         &mut stack,
-                    )?;
+                    )
+                    .map_err(|err| stack.parse_error("GetSessionToken", err, &response.body))?;
                     skip_tree(&mut stack);
                     end_element(&actual_tag_name, &mut stack)?;
                 }
@@ -1982,6 +2668,305 @@ impl Sts for StsClient {
     }
 }
 
+/// A mock of the AWS STS API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSts {
+    assume_role: Option<
+        Box<
+            dyn Fn(
+                    AssumeRoleRequest,
+                )
+                    -> ::std::result::Result<AssumeRoleResponse, RusotoError<AssumeRoleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    assume_role_with_saml: Option<
+        Box<
+            dyn Fn(
+                    AssumeRoleWithSAMLRequest,
+                ) -> ::std::result::Result<
+                    AssumeRoleWithSAMLResponse,
+                    RusotoError<AssumeRoleWithSAMLError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    assume_role_with_web_identity: Option<
+        Box<
+            dyn Fn(
+                    AssumeRoleWithWebIdentityRequest,
+                ) -> ::std::result::Result<
+                    AssumeRoleWithWebIdentityResponse,
+                    RusotoError<AssumeRoleWithWebIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    decode_authorization_message: Option<
+        Box<
+            dyn Fn(
+                    DecodeAuthorizationMessageRequest,
+                ) -> ::std::result::Result<
+                    DecodeAuthorizationMessageResponse,
+                    RusotoError<DecodeAuthorizationMessageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_access_key_info: Option<
+        Box<
+            dyn Fn(
+                    GetAccessKeyInfoRequest,
+                ) -> ::std::result::Result<
+                    GetAccessKeyInfoResponse,
+                    RusotoError<GetAccessKeyInfoError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_caller_identity: Option<
+        Box<
+            dyn Fn(
+                    GetCallerIdentityRequest,
+                ) -> ::std::result::Result<
+                    GetCallerIdentityResponse,
+                    RusotoError<GetCallerIdentityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_federation_token: Option<
+        Box<
+            dyn Fn(
+                    GetFederationTokenRequest,
+                ) -> ::std::result::Result<
+                    GetFederationTokenResponse,
+                    RusotoError<GetFederationTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_session_token: Option<
+        Box<
+            dyn Fn(
+                    GetSessionTokenRequest,
+                ) -> ::std::result::Result<
+                    GetSessionTokenResponse,
+                    RusotoError<GetSessionTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSts {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSts {
+        Default::default()
+    }
+
+    /// Answers `assume_role` with what `handler` returns for its input.
+    pub fn with_assume_role<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                AssumeRoleRequest,
+            )
+                -> ::std::result::Result<AssumeRoleResponse, RusotoError<AssumeRoleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.assume_role = Some(Box::new(handler));
+        self
+    }
+    /// Answers `assume_role_with_saml` with what `handler` returns for its input.
+    pub fn with_assume_role_with_saml<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                AssumeRoleWithSAMLRequest,
+            ) -> ::std::result::Result<
+                AssumeRoleWithSAMLResponse,
+                RusotoError<AssumeRoleWithSAMLError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.assume_role_with_saml = Some(Box::new(handler));
+        self
+    }
+    /// Answers `assume_role_with_web_identity` with what `handler` returns for its input.
+    pub fn with_assume_role_with_web_identity<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                AssumeRoleWithWebIdentityRequest,
+            ) -> ::std::result::Result<
+                AssumeRoleWithWebIdentityResponse,
+                RusotoError<AssumeRoleWithWebIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.assume_role_with_web_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `decode_authorization_message` with what `handler` returns for its input.
+    pub fn with_decode_authorization_message<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                DecodeAuthorizationMessageRequest,
+            ) -> ::std::result::Result<
+                DecodeAuthorizationMessageResponse,
+                RusotoError<DecodeAuthorizationMessageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.decode_authorization_message = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_access_key_info` with what `handler` returns for its input.
+    pub fn with_get_access_key_info<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                GetAccessKeyInfoRequest,
+            ) -> ::std::result::Result<
+                GetAccessKeyInfoResponse,
+                RusotoError<GetAccessKeyInfoError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_access_key_info = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_caller_identity` with what `handler` returns for its input.
+    pub fn with_get_caller_identity<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                GetCallerIdentityRequest,
+            ) -> ::std::result::Result<
+                GetCallerIdentityResponse,
+                RusotoError<GetCallerIdentityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_caller_identity = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_federation_token` with what `handler` returns for its input.
+    pub fn with_get_federation_token<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                GetFederationTokenRequest,
+            ) -> ::std::result::Result<
+                GetFederationTokenResponse,
+                RusotoError<GetFederationTokenError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_federation_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_session_token` with what `handler` returns for its input.
+    pub fn with_get_session_token<F>(mut self, handler: F) -> MockSts
+    where
+        F: Fn(
+                GetSessionTokenRequest,
+            )
+                -> ::std::result::Result<GetSessionTokenResponse, RusotoError<GetSessionTokenError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_session_token = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Sts for MockSts {
+    fn assume_role(
+        &self,
+        input: AssumeRoleRequest,
+    ) -> RusotoFuture<AssumeRoleResponse, AssumeRoleError> {
+        match self.assume_role {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for assume_role"),
+        }
+    }
+    fn assume_role_with_saml(
+        &self,
+        input: AssumeRoleWithSAMLRequest,
+    ) -> RusotoFuture<AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError> {
+        match self.assume_role_with_saml {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for assume_role_with_saml"),
+        }
+    }
+    fn assume_role_with_web_identity(
+        &self,
+        input: AssumeRoleWithWebIdentityRequest,
+    ) -> RusotoFuture<AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError> {
+        match self.assume_role_with_web_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for assume_role_with_web_identity"),
+        }
+    }
+    fn decode_authorization_message(
+        &self,
+        input: DecodeAuthorizationMessageRequest,
+    ) -> RusotoFuture<DecodeAuthorizationMessageResponse, DecodeAuthorizationMessageError> {
+        match self.decode_authorization_message {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for decode_authorization_message"),
+        }
+    }
+    fn get_access_key_info(
+        &self,
+        input: GetAccessKeyInfoRequest,
+    ) -> RusotoFuture<GetAccessKeyInfoResponse, GetAccessKeyInfoError> {
+        match self.get_access_key_info {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for get_access_key_info"),
+        }
+    }
+    fn get_caller_identity(
+        &self,
+        input: GetCallerIdentityRequest,
+    ) -> RusotoFuture<GetCallerIdentityResponse, GetCallerIdentityError> {
+        match self.get_caller_identity {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for get_caller_identity"),
+        }
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        match self.get_federation_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for get_federation_token"),
+        }
+    }
+    fn get_session_token(
+        &self,
+        input: GetSessionTokenRequest,
+    ) -> RusotoFuture<GetSessionTokenResponse, GetSessionTokenError> {
+        match self.get_session_token {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSts has no handler for get_session_token"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -20,6 +20,9 @@ extern crate bytes;
 extern crate chrono;
 extern crate futures;
 extern crate rusoto_core;
+extern crate serde;
+#[cfg_attr(any(test, feature = "serialize_structs", feature = "deserialize_structs"), macro_use)]
+extern crate serde_derive;
 extern crate serde_urlencoded;
 extern crate xml;
 
@@ -22,9 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddAttachmentsToSetRequest {
     /// <p>The ID of the attachment set. If an <code>attachmentSetId</code> is not specified, a new attachment set is created, and the ID of the set is returned in the response. If an <code>attachmentSetId</code> is specified, the attachments are added to the specified set, if it exists.</p>
     #[serde(rename = "attachmentSetId")]
@@ -37,7 +39,7 @@ pub struct AddAttachmentsToSetRequest {
 
 /// <p>The ID and expiry time of the attachment set returned by the <a>AddAttachmentsToSet</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddAttachmentsToSetResponse {
     /// <p>The ID of the attachment set. If an <code>attachmentSetId</code> was not specified, a new attachment set is created, and the ID of the set is returned in the response. If an <code>attachmentSetId</code> was specified, the attachments are added to the specified set, if it exists.</p>
     #[serde(rename = "attachmentSetId")]
@@ -51,6 +53,7 @@ pub struct AddAttachmentsToSetResponse {
 
 /// <p>To be written.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddCommunicationToCaseRequest {
     /// <p>The ID of a set of one or more attachments for the communication to add to the case. Create the set by calling <a>AddAttachmentsToSet</a> </p>
     #[serde(rename = "attachmentSetId")]
@@ -69,9 +72,28 @@ pub struct AddCommunicationToCaseRequest {
     pub communication_body: String,
 }
 
+impl Validate for AddCommunicationToCaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cc_email_addresses {
+            errors.check_length(
+                &field_path(path, "cc_email_addresses"),
+                value.len(),
+                None,
+                Some(10),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "communication_body"),
+            self.communication_body.chars().count(),
+            Some(1),
+            Some(8000),
+        );
+    }
+}
+
 /// <p>The result of the <a>AddCommunicationToCase</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddCommunicationToCaseResponse {
     /// <p>True if <a>AddCommunicationToCase</a> succeeds. Otherwise, returns an error.</p>
     #[serde(rename = "result")]
@@ -99,7 +121,7 @@ pub struct Attachment {
 
 /// <p>The file name and ID of an attachment to a case communication. You can use the ID to retrieve the attachment with the <a>DescribeAttachment</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AttachmentDetails {
     /// <p>The ID of the attachment.</p>
     #[serde(rename = "attachmentId")]
@@ -113,7 +135,7 @@ pub struct AttachmentDetails {
 
 /// <p><p>A JSON-formatted object that contains the metadata for a support case. It is contained the response from a <a>DescribeCases</a> request. <b>CaseDetails</b> contains the following fields:</p> <ul> <li> <p> <b>caseId.</b> The AWS Support case ID requested or returned in the call. The case ID is an alphanumeric string formatted as shown in this example: case-<i>12345678910-2013-c4c1d2bf33c5cf47</i>.</p> </li> <li> <p> <b>categoryCode.</b> The category of problem for the AWS Support case. Corresponds to the CategoryCode values returned by a call to <a>DescribeServices</a>.</p> </li> <li> <p> <b>displayId.</b> The identifier for the case on pages in the AWS Support Center.</p> </li> <li> <p> <b>language.</b> The ISO 639-1 code for the language in which AWS provides support. AWS Support currently supports English (&quot;en&quot;) and Japanese (&quot;ja&quot;). Language parameters must be passed explicitly for operations that take them.</p> </li> <li> <p> <b>recentCommunications.</b> One or more <a>Communication</a> objects. Fields of these objects are <code>attachments</code>, <code>body</code>, <code>caseId</code>, <code>submittedBy</code>, and <code>timeCreated</code>.</p> </li> <li> <p> <b>nextToken.</b> A resumption point for pagination.</p> </li> <li> <p> <b>serviceCode.</b> The identifier for the AWS service that corresponds to the service code defined in the call to <a>DescribeServices</a>.</p> </li> <li> <p> <b>severityCode. </b>The severity code assigned to the case. Contains one of the values returned by the call to <a>DescribeSeverityLevels</a>.</p> </li> <li> <p> <b>status.</b> The status of the case in the AWS Support Center.</p> </li> <li> <p> <b>subject.</b> The subject line of the case.</p> </li> <li> <p> <b>submittedBy.</b> The email address of the account that submitted the case.</p> </li> <li> <p> <b>timeCreated.</b> The time the case was created, in ISO-8601 format.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CaseDetails {
     /// <p>The AWS Support case ID requested or returned in the call. The case ID is an alphanumeric string formatted as shown in this example: case-<i>12345678910-2013-c4c1d2bf33c5cf47</i> </p>
     #[serde(rename = "caseId")]
@@ -167,7 +189,7 @@ pub struct CaseDetails {
 
 /// <p>A JSON-formatted name/value pair that represents the category name and category code of the problem, selected from the <a>DescribeServices</a> response for each AWS service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Category {
     /// <p>The category code for the support case.</p>
     #[serde(rename = "code")]
@@ -181,7 +203,7 @@ pub struct Category {
 
 /// <p>A communication associated with an AWS Support case. The communication consists of the case ID, the message body, attachment information, the account email address, and the date and time of the communication.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Communication {
     /// <p>Information about the attachments to the case communication.</p>
     #[serde(rename = "attachmentSet")]
@@ -207,6 +229,7 @@ pub struct Communication {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCaseRequest {
     /// <p>The ID of a set of one or more attachments for the case. Create the set by using <a>AddAttachmentsToSet</a>.</p>
     #[serde(rename = "attachmentSetId")]
@@ -244,9 +267,28 @@ pub struct CreateCaseRequest {
     pub subject: String,
 }
 
+impl Validate for CreateCaseRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cc_email_addresses {
+            errors.check_length(
+                &field_path(path, "cc_email_addresses"),
+                value.len(),
+                None,
+                Some(10),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "communication_body"),
+            self.communication_body.chars().count(),
+            Some(1),
+            Some(8000),
+        );
+    }
+}
+
 /// <p>The AWS Support case ID returned by a successful completion of the <a>CreateCase</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCaseResponse {
     /// <p>The AWS Support case ID requested or returned in the call. The case ID is an alphanumeric string formatted as shown in this example: case-<i>12345678910-2013-c4c1d2bf33c5cf47</i> </p>
     #[serde(rename = "caseId")]
@@ -255,6 +297,7 @@ pub struct CreateCaseResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAttachmentRequest {
     /// <p>The ID of the attachment to return. Attachment IDs are returned by the <a>DescribeCommunications</a> operation.</p>
     #[serde(rename = "attachmentId")]
@@ -263,7 +306,7 @@ pub struct DescribeAttachmentRequest {
 
 /// <p>The content and file name of the attachment returned by the <a>DescribeAttachment</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAttachmentResponse {
     /// <p>The attachment content and file name.</p>
     #[serde(rename = "attachment")]
@@ -273,6 +316,7 @@ pub struct DescribeAttachmentResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCasesRequest {
     /// <p>The start date for a filtered date search on support case communications. Case communications are available for 12 months after creation.</p>
     #[serde(rename = "afterTime")]
@@ -312,9 +356,30 @@ pub struct DescribeCasesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeCasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.case_id_list {
+            errors.check_length(
+                &field_path(path, "case_id_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>Returns an array of <a>CaseDetails</a> objects and a <code>nextToken</code> that defines a point for pagination in the result set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCasesResponse {
     /// <p>The details for the cases that match the request.</p>
     #[serde(rename = "cases")]
@@ -328,6 +393,7 @@ pub struct DescribeCasesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCommunicationsRequest {
     /// <p>The start date for a filtered date search on support case communications. Case communications are available for 12 months after creation.</p>
     #[serde(rename = "afterTime")]
@@ -350,9 +416,22 @@ pub struct DescribeCommunicationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeCommunicationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(10),
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>The communications returned by the <a>DescribeCommunications</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCommunicationsResponse {
     /// <p>The communications for the case.</p>
     #[serde(rename = "communications")]
@@ -366,6 +445,7 @@ pub struct DescribeCommunicationsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServicesRequest {
     /// <p>The ISO 639-1 code for the language in which AWS provides support. AWS Support currently supports English ("en") and Japanese ("ja"). Language parameters must be passed explicitly for operations that take them.</p>
     #[serde(rename = "language")]
@@ -377,9 +457,22 @@ pub struct DescribeServicesRequest {
     pub service_code_list: Option<Vec<String>>,
 }
 
+impl Validate for DescribeServicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.service_code_list {
+            errors.check_length(
+                &field_path(path, "service_code_list"),
+                value.len(),
+                None,
+                Some(100),
+            );
+        }
+    }
+}
+
 /// <p>The list of AWS services returned by the <a>DescribeServices</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServicesResponse {
     /// <p>A JSON-formatted list of AWS services.</p>
     #[serde(rename = "services")]
@@ -389,6 +482,7 @@ pub struct DescribeServicesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeSeverityLevelsRequest {
     /// <p>The ISO 639-1 code for the language in which AWS provides support. AWS Support currently supports English ("en") and Japanese ("ja"). Language parameters must be passed explicitly for operations that take them.</p>
     #[serde(rename = "language")]
@@ -398,7 +492,7 @@ pub struct DescribeSeverityLevelsRequest {
 
 /// <p>The list of severity levels returned by the <a>DescribeSeverityLevels</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeSeverityLevelsResponse {
     /// <p>The available severity levels for the support case. Available severity levels are defined by your service level agreement with AWS.</p>
     #[serde(rename = "severityLevels")]
@@ -408,6 +502,7 @@ pub struct DescribeSeverityLevelsResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrustedAdvisorCheckRefreshStatusesRequest {
     /// <p>The IDs of the Trusted Advisor checks to get the status of. <b>Note:</b> Specifying the check ID of a check that is automatically refreshed causes an <code>InvalidParameterValue</code> error.</p>
     #[serde(rename = "checkIds")]
@@ -416,7 +511,7 @@ pub struct DescribeTrustedAdvisorCheckRefreshStatusesRequest {
 
 /// <p>The statuses of the Trusted Advisor checks returned by the <a>DescribeTrustedAdvisorCheckRefreshStatuses</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrustedAdvisorCheckRefreshStatusesResponse {
     /// <p>The refresh status of the specified Trusted Advisor checks.</p>
     #[serde(rename = "statuses")]
@@ -425,6 +520,7 @@ pub struct DescribeTrustedAdvisorCheckRefreshStatusesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrustedAdvisorCheckResultRequest {
     /// <p>The unique identifier for the Trusted Advisor check.</p>
     #[serde(rename = "checkId")]
@@ -437,7 +533,7 @@ pub struct DescribeTrustedAdvisorCheckResultRequest {
 
 /// <p>The result of the Trusted Advisor check returned by the <a>DescribeTrustedAdvisorCheckResult</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrustedAdvisorCheckResultResponse {
     /// <p>The detailed results of the Trusted Advisor check.</p>
     #[serde(rename = "result")]
@@ -447,6 +543,7 @@ pub struct DescribeTrustedAdvisorCheckResultResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrustedAdvisorCheckSummariesRequest {
     /// <p>The IDs of the Trusted Advisor checks.</p>
     #[serde(rename = "checkIds")]
@@ -455,7 +552,7 @@ pub struct DescribeTrustedAdvisorCheckSummariesRequest {
 
 /// <p>The summaries of the Trusted Advisor checks returned by the <a>DescribeTrustedAdvisorCheckSummaries</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrustedAdvisorCheckSummariesResponse {
     /// <p>The summary information for the requested Trusted Advisor checks.</p>
     #[serde(rename = "summaries")]
@@ -464,6 +561,7 @@ pub struct DescribeTrustedAdvisorCheckSummariesResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTrustedAdvisorChecksRequest {
     /// <p>The ISO 639-1 code for the language in which AWS provides support. AWS Support currently supports English ("en") and Japanese ("ja"). Language parameters must be passed explicitly for operations that take them.</p>
     #[serde(rename = "language")]
@@ -472,7 +570,7 @@ pub struct DescribeTrustedAdvisorChecksRequest {
 
 /// <p>Information about the Trusted Advisor checks returned by the <a>DescribeTrustedAdvisorChecks</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTrustedAdvisorChecksResponse {
     /// <p>Information about all available Trusted Advisor checks.</p>
     #[serde(rename = "checks")]
@@ -481,7 +579,7 @@ pub struct DescribeTrustedAdvisorChecksResponse {
 
 /// <p>The five most recent communications associated with the case.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecentCaseCommunications {
     /// <p>The five most recent communications associated with the case.</p>
     #[serde(rename = "communications")]
@@ -495,6 +593,7 @@ pub struct RecentCaseCommunications {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RefreshTrustedAdvisorCheckRequest {
     /// <p>The unique identifier for the Trusted Advisor check to refresh. <b>Note:</b> Specifying the check ID of a check that is automatically refreshed causes an <code>InvalidParameterValue</code> error.</p>
     #[serde(rename = "checkId")]
@@ -503,7 +602,7 @@ pub struct RefreshTrustedAdvisorCheckRequest {
 
 /// <p>The current refresh status of a Trusted Advisor check.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RefreshTrustedAdvisorCheckResponse {
     /// <p>The current refresh status for a check, including the amount of time until the check is eligible for refresh.</p>
     #[serde(rename = "status")]
@@ -512,6 +611,7 @@ pub struct RefreshTrustedAdvisorCheckResponse {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResolveCaseRequest {
     /// <p>The AWS Support case ID requested or returned in the call. The case ID is an alphanumeric string formatted as shown in this example: case-<i>12345678910-2013-c4c1d2bf33c5cf47</i> </p>
     #[serde(rename = "caseId")]
@@ -521,7 +621,7 @@ pub struct ResolveCaseRequest {
 
 /// <p>The status of the case returned by the <a>ResolveCase</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResolveCaseResponse {
     /// <p>The status of the case after the <a>ResolveCase</a> request was processed.</p>
     #[serde(rename = "finalCaseStatus")]
@@ -535,7 +635,7 @@ pub struct ResolveCaseResponse {
 
 /// <p>Information about an AWS service returned by the <a>DescribeServices</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Service {
     /// <p>A list of categories that describe the type of support issue a case describes. Categories consist of a category name and a category code. Category names and codes are passed to AWS Support when you call <a>CreateCase</a>.</p>
     #[serde(rename = "categories")]
@@ -553,7 +653,7 @@ pub struct Service {
 
 /// <p>A code and name pair that represent a severity level that can be applied to a support case.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SeverityLevel {
     /// <p>One of four values: "low," "medium," "high," and "urgent". These values correspond to response times returned to the caller in <code>severityLevel.name</code>. </p>
     #[serde(rename = "code")]
@@ -567,7 +667,7 @@ pub struct SeverityLevel {
 
 /// <p>The container for summary information that relates to the category of the Trusted Advisor check.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorCategorySpecificSummary {
     /// <p>The summary information about cost savings for a Trusted Advisor check that is in the Cost Optimizing category.</p>
     #[serde(rename = "costOptimizing")]
@@ -577,7 +677,7 @@ pub struct TrustedAdvisorCategorySpecificSummary {
 
 /// <p>The description and metadata for a Trusted Advisor check.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorCheckDescription {
     /// <p>The category of the Trusted Advisor check.</p>
     #[serde(rename = "category")]
@@ -598,7 +698,7 @@ pub struct TrustedAdvisorCheckDescription {
 
 /// <p>The refresh status of a Trusted Advisor check.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorCheckRefreshStatus {
     /// <p>The unique identifier for the Trusted Advisor check.</p>
     #[serde(rename = "checkId")]
@@ -613,7 +713,7 @@ pub struct TrustedAdvisorCheckRefreshStatus {
 
 /// <p>The results of a Trusted Advisor check returned by <a>DescribeTrustedAdvisorCheckResult</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorCheckResult {
     /// <p>Summary information that relates to the category of the check. Cost Optimizing is the only category that is currently supported.</p>
     #[serde(rename = "categorySpecificSummary")]
@@ -636,7 +736,7 @@ pub struct TrustedAdvisorCheckResult {
 
 /// <p>A summary of a Trusted Advisor check result, including the alert status, last refresh, and number of resources examined.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorCheckSummary {
     /// <p>Summary information that relates to the category of the check. Cost Optimizing is the only category that is currently supported.</p>
     #[serde(rename = "categorySpecificSummary")]
@@ -660,7 +760,7 @@ pub struct TrustedAdvisorCheckSummary {
 
 /// <p>The estimated cost savings that might be realized if the recommended actions are taken.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorCostOptimizingSummary {
     /// <p>The estimated monthly savings that might be realized if the recommended actions are taken.</p>
     #[serde(rename = "estimatedMonthlySavings")]
@@ -672,7 +772,7 @@ pub struct TrustedAdvisorCostOptimizingSummary {
 
 /// <p>Contains information about a resource identified by a Trusted Advisor check.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorResourceDetail {
     /// <p>Specifies whether the AWS resource was ignored by Trusted Advisor because it was marked as suppressed by the user.</p>
     #[serde(rename = "isSuppressed")]
@@ -695,7 +795,7 @@ pub struct TrustedAdvisorResourceDetail {
 
 /// <p>Details about AWS resources that were analyzed in a call to Trusted Advisor <a>DescribeTrustedAdvisorCheckSummaries</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TrustedAdvisorResourcesSummary {
     /// <p>The number of AWS resources that were flagged (listed) by the Trusted Advisor check.</p>
     #[serde(rename = "resourcesFlagged")]
@@ -755,7 +855,12 @@ impl AddAttachmentsToSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -815,7 +920,12 @@ impl AddCommunicationToCaseError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -868,7 +978,12 @@ impl CreateCaseError {
                 "InternalServerError" => {
                     return RusotoError::Service(CreateCaseError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -920,7 +1035,12 @@ impl DescribeAttachmentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -960,7 +1080,12 @@ impl DescribeCasesError {
                 "InternalServerError" => {
                     return RusotoError::Service(DescribeCasesError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1003,7 +1128,12 @@ impl DescribeCommunicationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1039,7 +1169,12 @@ impl DescribeServicesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1074,7 +1209,12 @@ impl DescribeSeverityLevelsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1113,7 +1253,12 @@ impl DescribeTrustedAdvisorCheckRefreshStatusesError {
                         ),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1152,7 +1297,12 @@ impl DescribeTrustedAdvisorCheckResultError {
                         DescribeTrustedAdvisorCheckResultError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1189,7 +1339,12 @@ impl DescribeTrustedAdvisorCheckSummariesError {
                         DescribeTrustedAdvisorCheckSummariesError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1226,7 +1381,12 @@ impl DescribeTrustedAdvisorChecksError {
                         DescribeTrustedAdvisorChecksError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1263,7 +1423,12 @@ impl RefreshTrustedAdvisorCheckError {
                         RefreshTrustedAdvisorCheckError::InternalServerError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1301,7 +1466,12 @@ impl ResolveCaseError {
                 "InternalServerError" => {
                     return RusotoError::Service(ResolveCaseError::InternalServerError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1416,6 +1586,292 @@ pub trait AWSSupport {
         input: ResolveCaseRequest,
     ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError>;
 }
+const _: Option<&dyn AWSSupport> = None;
+impl<'a, T: AWSSupport + ?Sized> AWSSupport for &'a T {
+    fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> RusotoFuture<AddAttachmentsToSetResponse, AddAttachmentsToSetError> {
+        (**self).add_attachments_to_set(input)
+    }
+    fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> RusotoFuture<AddCommunicationToCaseResponse, AddCommunicationToCaseError> {
+        (**self).add_communication_to_case(input)
+    }
+    fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> RusotoFuture<CreateCaseResponse, CreateCaseError> {
+        (**self).create_case(input)
+    }
+    fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> RusotoFuture<DescribeAttachmentResponse, DescribeAttachmentError> {
+        (**self).describe_attachment(input)
+    }
+    fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> RusotoFuture<DescribeCasesResponse, DescribeCasesError> {
+        (**self).describe_cases(input)
+    }
+    fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> RusotoFuture<DescribeCommunicationsResponse, DescribeCommunicationsError> {
+        (**self).describe_communications(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> RusotoFuture<DescribeSeverityLevelsResponse, DescribeSeverityLevelsError> {
+        (**self).describe_severity_levels(input)
+    }
+    fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        DescribeTrustedAdvisorCheckRefreshStatusesError,
+    > {
+        (**self).describe_trusted_advisor_check_refresh_statuses(input)
+    }
+    fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckResultResponse,
+        DescribeTrustedAdvisorCheckResultError,
+    > {
+        (**self).describe_trusted_advisor_check_result(input)
+    }
+    fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        DescribeTrustedAdvisorCheckSummariesError,
+    > {
+        (**self).describe_trusted_advisor_check_summaries(input)
+    }
+    fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> RusotoFuture<DescribeTrustedAdvisorChecksResponse, DescribeTrustedAdvisorChecksError> {
+        (**self).describe_trusted_advisor_checks(input)
+    }
+    fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> RusotoFuture<RefreshTrustedAdvisorCheckResponse, RefreshTrustedAdvisorCheckError> {
+        (**self).refresh_trusted_advisor_check(input)
+    }
+    fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError> {
+        (**self).resolve_case(input)
+    }
+}
+impl<T: AWSSupport + ?Sized> AWSSupport for Box<T> {
+    fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> RusotoFuture<AddAttachmentsToSetResponse, AddAttachmentsToSetError> {
+        (**self).add_attachments_to_set(input)
+    }
+    fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> RusotoFuture<AddCommunicationToCaseResponse, AddCommunicationToCaseError> {
+        (**self).add_communication_to_case(input)
+    }
+    fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> RusotoFuture<CreateCaseResponse, CreateCaseError> {
+        (**self).create_case(input)
+    }
+    fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> RusotoFuture<DescribeAttachmentResponse, DescribeAttachmentError> {
+        (**self).describe_attachment(input)
+    }
+    fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> RusotoFuture<DescribeCasesResponse, DescribeCasesError> {
+        (**self).describe_cases(input)
+    }
+    fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> RusotoFuture<DescribeCommunicationsResponse, DescribeCommunicationsError> {
+        (**self).describe_communications(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> RusotoFuture<DescribeSeverityLevelsResponse, DescribeSeverityLevelsError> {
+        (**self).describe_severity_levels(input)
+    }
+    fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        DescribeTrustedAdvisorCheckRefreshStatusesError,
+    > {
+        (**self).describe_trusted_advisor_check_refresh_statuses(input)
+    }
+    fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckResultResponse,
+        DescribeTrustedAdvisorCheckResultError,
+    > {
+        (**self).describe_trusted_advisor_check_result(input)
+    }
+    fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        DescribeTrustedAdvisorCheckSummariesError,
+    > {
+        (**self).describe_trusted_advisor_check_summaries(input)
+    }
+    fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> RusotoFuture<DescribeTrustedAdvisorChecksResponse, DescribeTrustedAdvisorChecksError> {
+        (**self).describe_trusted_advisor_checks(input)
+    }
+    fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> RusotoFuture<RefreshTrustedAdvisorCheckResponse, RefreshTrustedAdvisorCheckError> {
+        (**self).refresh_trusted_advisor_check(input)
+    }
+    fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError> {
+        (**self).resolve_case(input)
+    }
+}
+impl<T: AWSSupport + ?Sized> AWSSupport for ::std::sync::Arc<T> {
+    fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> RusotoFuture<AddAttachmentsToSetResponse, AddAttachmentsToSetError> {
+        (**self).add_attachments_to_set(input)
+    }
+    fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> RusotoFuture<AddCommunicationToCaseResponse, AddCommunicationToCaseError> {
+        (**self).add_communication_to_case(input)
+    }
+    fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> RusotoFuture<CreateCaseResponse, CreateCaseError> {
+        (**self).create_case(input)
+    }
+    fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> RusotoFuture<DescribeAttachmentResponse, DescribeAttachmentError> {
+        (**self).describe_attachment(input)
+    }
+    fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> RusotoFuture<DescribeCasesResponse, DescribeCasesError> {
+        (**self).describe_cases(input)
+    }
+    fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> RusotoFuture<DescribeCommunicationsResponse, DescribeCommunicationsError> {
+        (**self).describe_communications(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> RusotoFuture<DescribeSeverityLevelsResponse, DescribeSeverityLevelsError> {
+        (**self).describe_severity_levels(input)
+    }
+    fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        DescribeTrustedAdvisorCheckRefreshStatusesError,
+    > {
+        (**self).describe_trusted_advisor_check_refresh_statuses(input)
+    }
+    fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckResultResponse,
+        DescribeTrustedAdvisorCheckResultError,
+    > {
+        (**self).describe_trusted_advisor_check_result(input)
+    }
+    fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        DescribeTrustedAdvisorCheckSummariesError,
+    > {
+        (**self).describe_trusted_advisor_check_summaries(input)
+    }
+    fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> RusotoFuture<DescribeTrustedAdvisorChecksResponse, DescribeTrustedAdvisorChecksError> {
+        (**self).describe_trusted_advisor_checks(input)
+    }
+    fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> RusotoFuture<RefreshTrustedAdvisorCheckResponse, RefreshTrustedAdvisorCheckError> {
+        (**self).refresh_trusted_advisor_check(input)
+    }
+    fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError> {
+        (**self).resolve_case(input)
+    }
+}
 /// A client for the AWS Support API.
 #[derive(Clone)]
 pub struct AWSSupportClient {
@@ -1460,6 +1916,7 @@ impl AWSSupport for AWSSupportClient {
         input: AddAttachmentsToSetRequest,
     ) -> RusotoFuture<AddAttachmentsToSetResponse, AddAttachmentsToSetError> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("AddAttachmentsToSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.AddAttachmentsToSet");
@@ -1468,10 +1925,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddAttachmentsToSetResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<AddAttachmentsToSetResponse, _>(
+                    response,
+                    "AddAttachmentsToSet",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1487,7 +1944,16 @@ impl AWSSupport for AWSSupportClient {
         &self,
         input: AddCommunicationToCaseRequest,
     ) -> RusotoFuture<AddCommunicationToCaseResponse, AddCommunicationToCaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("AddCommunicationToCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.AddCommunicationToCase");
@@ -1496,10 +1962,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddCommunicationToCaseResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<AddCommunicationToCaseResponse, _>(
+                    response,
+                    "AddCommunicationToCase",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1515,7 +1981,16 @@ impl AWSSupport for AWSSupportClient {
         &self,
         input: CreateCaseRequest,
     ) -> RusotoFuture<CreateCaseResponse, CreateCaseError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("CreateCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.CreateCase");
@@ -1524,10 +1999,7 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCaseResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateCaseResponse, _>(response, "CreateCase")
             } else {
                 Box::new(
                     response
@@ -1545,6 +2017,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeAttachmentRequest,
     ) -> RusotoFuture<DescribeAttachmentResponse, DescribeAttachmentError> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeAttachment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeAttachment");
@@ -1553,10 +2026,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAttachmentResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeAttachmentResponse, _>(
+                    response,
+                    "DescribeAttachment",
+                )
             } else {
                 Box::new(
                     response
@@ -1573,7 +2046,16 @@ impl AWSSupport for AWSSupportClient {
         &self,
         input: DescribeCasesRequest,
     ) -> RusotoFuture<DescribeCasesResponse, DescribeCasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeCases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCases");
@@ -1582,10 +2064,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCasesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeCasesResponse, _>(
+                    response,
+                    "DescribeCases",
+                )
             } else {
                 Box::new(
                     response
@@ -1602,7 +2084,16 @@ impl AWSSupport for AWSSupportClient {
         &self,
         input: DescribeCommunicationsRequest,
     ) -> RusotoFuture<DescribeCommunicationsResponse, DescribeCommunicationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeCommunications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCommunications");
@@ -1611,10 +2102,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCommunicationsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeCommunicationsResponse, _>(
+                    response,
+                    "DescribeCommunications",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1630,7 +2121,16 @@ impl AWSSupport for AWSSupportClient {
         &self,
         input: DescribeServicesRequest,
     ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeServices");
@@ -1639,10 +2139,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServicesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeServicesResponse, _>(
+                    response,
+                    "DescribeServices",
+                )
             } else {
                 Box::new(
                     response
@@ -1660,6 +2160,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeSeverityLevelsRequest,
     ) -> RusotoFuture<DescribeSeverityLevelsResponse, DescribeSeverityLevelsError> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeSeverityLevels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeSeverityLevels");
@@ -1668,10 +2169,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeSeverityLevelsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeSeverityLevelsResponse, _>(
+                    response,
+                    "DescribeSeverityLevels",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1691,6 +2192,7 @@ impl AWSSupport for AWSSupportClient {
         DescribeTrustedAdvisorCheckRefreshStatusesError,
     > {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckRefreshStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1702,10 +2204,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrustedAdvisorCheckRefreshStatusesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<
+                    DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+                    _,
+                >(response, "DescribeTrustedAdvisorCheckRefreshStatuses")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeTrustedAdvisorCheckRefreshStatusesError::from_response(response))
@@ -1723,6 +2225,7 @@ impl AWSSupport for AWSSupportClient {
         DescribeTrustedAdvisorCheckResultError,
     > {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1734,10 +2237,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrustedAdvisorCheckResultResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeTrustedAdvisorCheckResultResponse, _>(
+                    response,
+                    "DescribeTrustedAdvisorCheckResult",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeTrustedAdvisorCheckResultError::from_response(
@@ -1757,6 +2260,7 @@ impl AWSSupport for AWSSupportClient {
         DescribeTrustedAdvisorCheckSummariesError,
     > {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorCheckSummaries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1768,10 +2272,7 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrustedAdvisorCheckSummariesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeTrustedAdvisorCheckSummariesResponse, _>(response, "DescribeTrustedAdvisorCheckSummaries")
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeTrustedAdvisorCheckSummariesError::from_response(
@@ -1788,6 +2289,7 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeTrustedAdvisorChecksRequest,
     ) -> RusotoFuture<DescribeTrustedAdvisorChecksResponse, DescribeTrustedAdvisorChecksError> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("DescribeTrustedAdvisorChecks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1799,10 +2301,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTrustedAdvisorChecksResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeTrustedAdvisorChecksResponse, _>(
+                    response,
+                    "DescribeTrustedAdvisorChecks",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(DescribeTrustedAdvisorChecksError::from_response(response))
@@ -1817,6 +2319,7 @@ impl AWSSupport for AWSSupportClient {
         input: RefreshTrustedAdvisorCheckRequest,
     ) -> RusotoFuture<RefreshTrustedAdvisorCheckResponse, RefreshTrustedAdvisorCheckError> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("RefreshTrustedAdvisorCheck");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1828,10 +2331,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RefreshTrustedAdvisorCheckResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<RefreshTrustedAdvisorCheckResponse, _>(
+                    response,
+                    "RefreshTrustedAdvisorCheck",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(RefreshTrustedAdvisorCheckError::from_response(response))
@@ -1846,6 +2349,7 @@ impl AWSSupport for AWSSupportClient {
         input: ResolveCaseRequest,
     ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError> {
         let mut request = SignedRequest::new("POST", "support", &self.region, "/");
+        request.set_operation("ResolveCase");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSupport_20130415.ResolveCase");
@@ -1854,10 +2358,10 @@ impl AWSSupport for AWSSupportClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResolveCaseResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ResolveCaseResponse, _>(
+                    response,
+                    "ResolveCase",
+                )
             } else {
                 Box::new(
                     response
@@ -1869,3 +2373,527 @@ impl AWSSupport for AWSSupportClient {
         })
     }
 }
+
+/// A mock of the AWS Support API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockAWSSupport {
+    add_attachments_to_set: Option<
+        Box<
+            dyn Fn(
+                    AddAttachmentsToSetRequest,
+                ) -> ::std::result::Result<
+                    AddAttachmentsToSetResponse,
+                    RusotoError<AddAttachmentsToSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    add_communication_to_case: Option<
+        Box<
+            dyn Fn(
+                    AddCommunicationToCaseRequest,
+                ) -> ::std::result::Result<
+                    AddCommunicationToCaseResponse,
+                    RusotoError<AddCommunicationToCaseError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_case: Option<
+        Box<
+            dyn Fn(
+                    CreateCaseRequest,
+                )
+                    -> ::std::result::Result<CreateCaseResponse, RusotoError<CreateCaseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_attachment: Option<
+        Box<
+            dyn Fn(
+                    DescribeAttachmentRequest,
+                ) -> ::std::result::Result<
+                    DescribeAttachmentResponse,
+                    RusotoError<DescribeAttachmentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_cases: Option<
+        Box<
+            dyn Fn(
+                    DescribeCasesRequest,
+                )
+                    -> ::std::result::Result<DescribeCasesResponse, RusotoError<DescribeCasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_communications: Option<
+        Box<
+            dyn Fn(
+                    DescribeCommunicationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeCommunicationsResponse,
+                    RusotoError<DescribeCommunicationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_services: Option<
+        Box<
+            dyn Fn(
+                    DescribeServicesRequest,
+                ) -> ::std::result::Result<
+                    DescribeServicesResponse,
+                    RusotoError<DescribeServicesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_severity_levels: Option<
+        Box<
+            dyn Fn(
+                    DescribeSeverityLevelsRequest,
+                ) -> ::std::result::Result<
+                    DescribeSeverityLevelsResponse,
+                    RusotoError<DescribeSeverityLevelsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_trusted_advisor_check_refresh_statuses: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+                ) -> ::std::result::Result<
+                    DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+                    RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_trusted_advisor_check_result: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrustedAdvisorCheckResultRequest,
+                ) -> ::std::result::Result<
+                    DescribeTrustedAdvisorCheckResultResponse,
+                    RusotoError<DescribeTrustedAdvisorCheckResultError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_trusted_advisor_check_summaries: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrustedAdvisorCheckSummariesRequest,
+                ) -> ::std::result::Result<
+                    DescribeTrustedAdvisorCheckSummariesResponse,
+                    RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_trusted_advisor_checks: Option<
+        Box<
+            dyn Fn(
+                    DescribeTrustedAdvisorChecksRequest,
+                ) -> ::std::result::Result<
+                    DescribeTrustedAdvisorChecksResponse,
+                    RusotoError<DescribeTrustedAdvisorChecksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    refresh_trusted_advisor_check: Option<
+        Box<
+            dyn Fn(
+                    RefreshTrustedAdvisorCheckRequest,
+                ) -> ::std::result::Result<
+                    RefreshTrustedAdvisorCheckResponse,
+                    RusotoError<RefreshTrustedAdvisorCheckError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    resolve_case: Option<
+        Box<
+            dyn Fn(
+                    ResolveCaseRequest,
+                )
+                    -> ::std::result::Result<ResolveCaseResponse, RusotoError<ResolveCaseError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockAWSSupport {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockAWSSupport {
+        Default::default()
+    }
+
+    /// Answers `add_attachments_to_set` with what `handler` returns for its input.
+    pub fn with_add_attachments_to_set<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                AddAttachmentsToSetRequest,
+            ) -> ::std::result::Result<
+                AddAttachmentsToSetResponse,
+                RusotoError<AddAttachmentsToSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_attachments_to_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_communication_to_case` with what `handler` returns for its input.
+    pub fn with_add_communication_to_case<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                AddCommunicationToCaseRequest,
+            ) -> ::std::result::Result<
+                AddCommunicationToCaseResponse,
+                RusotoError<AddCommunicationToCaseError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_communication_to_case = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_case` with what `handler` returns for its input.
+    pub fn with_create_case<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                CreateCaseRequest,
+            )
+                -> ::std::result::Result<CreateCaseResponse, RusotoError<CreateCaseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_case = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_attachment` with what `handler` returns for its input.
+    pub fn with_describe_attachment<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeAttachmentRequest,
+            ) -> ::std::result::Result<
+                DescribeAttachmentResponse,
+                RusotoError<DescribeAttachmentError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_attachment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_cases` with what `handler` returns for its input.
+    pub fn with_describe_cases<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeCasesRequest,
+            )
+                -> ::std::result::Result<DescribeCasesResponse, RusotoError<DescribeCasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_cases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_communications` with what `handler` returns for its input.
+    pub fn with_describe_communications<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeCommunicationsRequest,
+            ) -> ::std::result::Result<
+                DescribeCommunicationsResponse,
+                RusotoError<DescribeCommunicationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_communications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_services` with what `handler` returns for its input.
+    pub fn with_describe_services<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeServicesRequest,
+            ) -> ::std::result::Result<
+                DescribeServicesResponse,
+                RusotoError<DescribeServicesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_services = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_severity_levels` with what `handler` returns for its input.
+    pub fn with_describe_severity_levels<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeSeverityLevelsRequest,
+            ) -> ::std::result::Result<
+                DescribeSeverityLevelsResponse,
+                RusotoError<DescribeSeverityLevelsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_severity_levels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_trusted_advisor_check_refresh_statuses` with what `handler` returns for its input.
+    pub fn with_describe_trusted_advisor_check_refresh_statuses<F>(
+        mut self,
+        handler: F,
+    ) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+            ) -> ::std::result::Result<
+                DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+                RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_trusted_advisor_check_refresh_statuses = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_trusted_advisor_check_result` with what `handler` returns for its input.
+    pub fn with_describe_trusted_advisor_check_result<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeTrustedAdvisorCheckResultRequest,
+            ) -> ::std::result::Result<
+                DescribeTrustedAdvisorCheckResultResponse,
+                RusotoError<DescribeTrustedAdvisorCheckResultError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_trusted_advisor_check_result = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_trusted_advisor_check_summaries` with what `handler` returns for its input.
+    pub fn with_describe_trusted_advisor_check_summaries<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeTrustedAdvisorCheckSummariesRequest,
+            ) -> ::std::result::Result<
+                DescribeTrustedAdvisorCheckSummariesResponse,
+                RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_trusted_advisor_check_summaries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_trusted_advisor_checks` with what `handler` returns for its input.
+    pub fn with_describe_trusted_advisor_checks<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                DescribeTrustedAdvisorChecksRequest,
+            ) -> ::std::result::Result<
+                DescribeTrustedAdvisorChecksResponse,
+                RusotoError<DescribeTrustedAdvisorChecksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_trusted_advisor_checks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `refresh_trusted_advisor_check` with what `handler` returns for its input.
+    pub fn with_refresh_trusted_advisor_check<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                RefreshTrustedAdvisorCheckRequest,
+            ) -> ::std::result::Result<
+                RefreshTrustedAdvisorCheckResponse,
+                RusotoError<RefreshTrustedAdvisorCheckError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.refresh_trusted_advisor_check = Some(Box::new(handler));
+        self
+    }
+    /// Answers `resolve_case` with what `handler` returns for its input.
+    pub fn with_resolve_case<F>(mut self, handler: F) -> MockAWSSupport
+    where
+        F: Fn(
+                ResolveCaseRequest,
+            )
+                -> ::std::result::Result<ResolveCaseResponse, RusotoError<ResolveCaseError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.resolve_case = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl AWSSupport for MockAWSSupport {
+    fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> RusotoFuture<AddAttachmentsToSetResponse, AddAttachmentsToSetError> {
+        match self.add_attachments_to_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for add_attachments_to_set"),
+        }
+    }
+    fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> RusotoFuture<AddCommunicationToCaseResponse, AddCommunicationToCaseError> {
+        match self.add_communication_to_case {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for add_communication_to_case"),
+        }
+    }
+    fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> RusotoFuture<CreateCaseResponse, CreateCaseError> {
+        match self.create_case {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for create_case"),
+        }
+    }
+    fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> RusotoFuture<DescribeAttachmentResponse, DescribeAttachmentError> {
+        match self.describe_attachment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for describe_attachment"),
+        }
+    }
+    fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> RusotoFuture<DescribeCasesResponse, DescribeCasesError> {
+        match self.describe_cases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for describe_cases"),
+        }
+    }
+    fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> RusotoFuture<DescribeCommunicationsResponse, DescribeCommunicationsError> {
+        match self.describe_communications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for describe_communications"),
+        }
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        match self.describe_services {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for describe_services"),
+        }
+    }
+    fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> RusotoFuture<DescribeSeverityLevelsResponse, DescribeSeverityLevelsError> {
+        match self.describe_severity_levels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for describe_severity_levels"),
+        }
+    }
+    fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        DescribeTrustedAdvisorCheckRefreshStatusesError,
+    > {
+        match self.describe_trusted_advisor_check_refresh_statuses {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockAWSSupport has no handler for describe_trusted_advisor_check_refresh_statuses"
+            ),
+        }
+    }
+    fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckResultResponse,
+        DescribeTrustedAdvisorCheckResultError,
+    > {
+        match self.describe_trusted_advisor_check_result {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAWSSupport has no handler for describe_trusted_advisor_check_result")
+            }
+        }
+    }
+    fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> RusotoFuture<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        DescribeTrustedAdvisorCheckSummariesError,
+    > {
+        match self.describe_trusted_advisor_check_summaries {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockAWSSupport has no handler for describe_trusted_advisor_check_summaries")
+            }
+        }
+    }
+    fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> RusotoFuture<DescribeTrustedAdvisorChecksResponse, DescribeTrustedAdvisorChecksError> {
+        match self.describe_trusted_advisor_checks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for describe_trusted_advisor_checks"),
+        }
+    }
+    fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> RusotoFuture<RefreshTrustedAdvisorCheckResponse, RefreshTrustedAdvisorCheckError> {
+        match self.refresh_trusted_advisor_check {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for refresh_trusted_advisor_check"),
+        }
+    }
+    fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> RusotoFuture<ResolveCaseResponse, ResolveCaseError> {
+        match self.resolve_case {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockAWSSupport has no handler for resolve_case"),
+        }
+    }
+}
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Unit of work sent to an activity worker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTask {
     /// <p>The unique ID of the task.</p>
     #[serde(rename = "activityId")]
@@ -50,7 +51,7 @@ pub struct ActivityTask {
 
 /// <p>Provides the details of the <code>ActivityTaskCancelRequested</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskCancelRequestedEventAttributes {
     /// <p>The unique ID of the task.</p>
     #[serde(rename = "activityId")]
@@ -62,7 +63,7 @@ pub struct ActivityTaskCancelRequestedEventAttributes {
 
 /// <p>Provides the details of the <code>ActivityTaskCanceled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskCanceledEventAttributes {
     /// <p>Details of the cancellation.</p>
     #[serde(rename = "details")]
@@ -82,7 +83,7 @@ pub struct ActivityTaskCanceledEventAttributes {
 
 /// <p>Provides the details of the <code>ActivityTaskCompleted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskCompletedEventAttributes {
     /// <p>The results of the activity task.</p>
     #[serde(rename = "result")]
@@ -98,7 +99,7 @@ pub struct ActivityTaskCompletedEventAttributes {
 
 /// <p>Provides the details of the <code>ActivityTaskFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskFailedEventAttributes {
     /// <p>The details of the failure.</p>
     #[serde(rename = "details")]
@@ -118,7 +119,7 @@ pub struct ActivityTaskFailedEventAttributes {
 
 /// <p>Provides the details of the <code>ActivityTaskScheduled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskScheduledEventAttributes {
     /// <p>The unique ID of the activity task.</p>
     #[serde(rename = "activityId")]
@@ -164,7 +165,7 @@ pub struct ActivityTaskScheduledEventAttributes {
 
 /// <p>Provides the details of the <code>ActivityTaskStarted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskStartedEventAttributes {
     /// <p>Identity of the worker that was assigned this task. This aids diagnostics when problems arise. The form of this identity is user defined.</p>
     #[serde(rename = "identity")]
@@ -177,7 +178,7 @@ pub struct ActivityTaskStartedEventAttributes {
 
 /// <p>Status information about an activity task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskStatus {
     /// <p>Set to <code>true</code> if cancellation of the task is requested.</p>
     #[serde(rename = "cancelRequested")]
@@ -186,7 +187,7 @@ pub struct ActivityTaskStatus {
 
 /// <p>Provides the details of the <code>ActivityTaskTimedOut</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTaskTimedOutEventAttributes {
     /// <p>Contains the content of the <code>details</code> parameter for the last call made by the activity to <code>RecordActivityTaskHeartbeat</code>.</p>
     #[serde(rename = "details")]
@@ -214,9 +215,26 @@ pub struct ActivityType {
     pub version: String,
 }
 
+impl Validate for ActivityType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>Configuration settings registered with the activity type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTypeConfiguration {
     /// <p> The default maximum time, in seconds, before which a worker processing a task must report progress by calling <a>RecordActivityTaskHeartbeat</a>.</p> <p>You can specify this value only when <i>registering</i> an activity type. The registered default value can be overridden when you schedule a task through the <code>ScheduleActivityTask</code> <a>Decision</a>. If the activity worker subsequently attempts to record a heartbeat or returns a result, the activity worker receives an <code>UnknownResource</code> fault. In this case, Amazon SWF no longer considers the activity task to be valid; the activity worker should clean up the activity task.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "defaultTaskHeartbeatTimeout")]
@@ -246,7 +264,7 @@ pub struct ActivityTypeConfiguration {
 
 /// <p>Detailed information about an activity type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTypeDetail {
     /// <p>The configuration settings registered with the activity type.</p>
     #[serde(rename = "configuration")]
@@ -258,7 +276,7 @@ pub struct ActivityTypeDetail {
 
 /// <p>Detailed information about an activity type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTypeInfo {
     /// <p>The <a>ActivityType</a> type structure representing the activity type.</p>
     #[serde(rename = "activityType")]
@@ -281,7 +299,7 @@ pub struct ActivityTypeInfo {
 
 /// <p>Contains a paginated list of activity type information structures.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivityTypeInfos {
     /// <p>If a <code>NextPageToken</code> was returned by a previous call, there are more results available. To retrieve the next page of results, make the call again using the returned token in <code>nextPageToken</code>. Keep all other arguments unchanged.</p> <p>The configured <code>maximumPageSize</code> determines how many results can be returned in a single call.</p>
     #[serde(rename = "nextPageToken")]
@@ -294,15 +312,27 @@ pub struct ActivityTypeInfos {
 
 /// <p>Provides the details of the <code>CancelTimer</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelTimerDecisionAttributes {
     /// <p> The unique ID of the timer to cancel.</p>
     #[serde(rename = "timerId")]
     pub timer_id: String,
 }
 
+impl Validate for CancelTimerDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "timer_id"),
+            self.timer_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>CancelTimerFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelTimerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -317,6 +347,7 @@ pub struct CancelTimerFailedEventAttributes {
 
 /// <p>Provides the details of the <code>CancelWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CancelWorkflowExecutionDecisionAttributes {
     /// <p> Details of the cancellation.</p>
     #[serde(rename = "details")]
@@ -324,9 +355,22 @@ pub struct CancelWorkflowExecutionDecisionAttributes {
     pub details: Option<String>,
 }
 
+impl Validate for CancelWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+    }
+}
+
 /// <p>Provides the details of the <code>CancelWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CancelWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -338,7 +382,7 @@ pub struct CancelWorkflowExecutionFailedEventAttributes {
 
 /// <p>Provide details of the <code>ChildWorkflowExecutionCanceled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChildWorkflowExecutionCanceledEventAttributes {
     /// <p>Details of the cancellation (if provided).</p>
     #[serde(rename = "details")]
@@ -360,7 +404,7 @@ pub struct ChildWorkflowExecutionCanceledEventAttributes {
 
 /// <p>Provides the details of the <code>ChildWorkflowExecutionCompleted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChildWorkflowExecutionCompletedEventAttributes {
     /// <p>The ID of the <code>StartChildWorkflowExecutionInitiated</code> event corresponding to the <code>StartChildWorkflowExecution</code> <a>Decision</a> to start this child workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "initiatedEventId")]
@@ -382,7 +426,7 @@ pub struct ChildWorkflowExecutionCompletedEventAttributes {
 
 /// <p>Provides the details of the <code>ChildWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChildWorkflowExecutionFailedEventAttributes {
     /// <p>The details of the failure (if provided).</p>
     #[serde(rename = "details")]
@@ -408,7 +452,7 @@ pub struct ChildWorkflowExecutionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>ChildWorkflowExecutionStarted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChildWorkflowExecutionStartedEventAttributes {
     /// <p>The ID of the <code>StartChildWorkflowExecutionInitiated</code> event corresponding to the <code>StartChildWorkflowExecution</code> <a>Decision</a> to start this child workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "initiatedEventId")]
@@ -423,7 +467,7 @@ pub struct ChildWorkflowExecutionStartedEventAttributes {
 
 /// <p>Provides the details of the <code>ChildWorkflowExecutionTerminated</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChildWorkflowExecutionTerminatedEventAttributes {
     /// <p>The ID of the <code>StartChildWorkflowExecutionInitiated</code> event corresponding to the <code>StartChildWorkflowExecution</code> <a>Decision</a> to start this child workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "initiatedEventId")]
@@ -441,7 +485,7 @@ pub struct ChildWorkflowExecutionTerminatedEventAttributes {
 
 /// <p>Provides the details of the <code>ChildWorkflowExecutionTimedOut</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ChildWorkflowExecutionTimedOutEventAttributes {
     /// <p>The ID of the <code>StartChildWorkflowExecutionInitiated</code> event corresponding to the <code>StartChildWorkflowExecution</code> <a>Decision</a> to start this child workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "initiatedEventId")]
@@ -462,6 +506,7 @@ pub struct ChildWorkflowExecutionTimedOutEventAttributes {
 
 /// <p>Used to filter the closed workflow executions in visibility APIs by their close status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CloseStatusFilter {
     /// <p> The close status that must match the close status of an execution for it to meet the criteria of this filter.</p>
     #[serde(rename = "status")]
@@ -470,6 +515,7 @@ pub struct CloseStatusFilter {
 
 /// <p>Provides the details of the <code>CompleteWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CompleteWorkflowExecutionDecisionAttributes {
     /// <p>The result of the workflow execution. The form of the result is implementation defined.</p>
     #[serde(rename = "result")]
@@ -477,9 +523,22 @@ pub struct CompleteWorkflowExecutionDecisionAttributes {
     pub result: Option<String>,
 }
 
+impl Validate for CompleteWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.result {
+            errors.check_length(
+                &field_path(path, "result"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+    }
+}
+
 /// <p>Provides the details of the <code>CompleteWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CompleteWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -491,6 +550,7 @@ pub struct CompleteWorkflowExecutionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>ContinueAsNewWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tag</code> – A tag used to identify the workflow execution</p> </li> <li> <p> <code>taskList</code> – String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.version</code> – String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ContinueAsNewWorkflowExecutionDecisionAttributes {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
@@ -530,9 +590,64 @@ pub struct ContinueAsNewWorkflowExecutionDecisionAttributes {
     pub workflow_type_version: Option<String>,
 }
 
+impl Validate for ContinueAsNewWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.execution_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "execution_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.lambda_role {
+            errors.check_length(
+                &field_path(path, "lambda_role"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.tag_list {
+            errors.check_length(&field_path(path, "tag_list"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_list"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.task_list {
+            value.validate_at(&field_path(path, "task_list"), errors);
+        }
+        if let Some(ref value) = self.task_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "task_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.workflow_type_version {
+            errors.check_length(
+                &field_path(path, "workflow_type_version"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>Provides the details of the <code>ContinueAsNewWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ContinueAsNewWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -543,6 +658,7 @@ pub struct ContinueAsNewWorkflowExecutionFailedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CountClosedWorkflowExecutionsInput {
     /// <p><p>If specified, only workflow executions that match this close status are counted. This filter has an affect only if <code>executionStatus</code> is specified as <code>CLOSED</code>.</p> <note> <p> <code>closeStatusFilter</code>, <code>executionFilter</code>, <code>typeFilter</code> and <code>tagFilter</code> are mutually exclusive. You can specify at most one of these in a request.</p> </note></p>
     #[serde(rename = "closeStatusFilter")]
@@ -573,7 +689,28 @@ pub struct CountClosedWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
+impl Validate for CountClosedWorkflowExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.execution_filter {
+            value.validate_at(&field_path(path, "execution_filter"), errors);
+        }
+        if let Some(ref value) = self.tag_filter {
+            value.validate_at(&field_path(path, "tag_filter"), errors);
+        }
+        if let Some(ref value) = self.type_filter {
+            value.validate_at(&field_path(path, "type_filter"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CountOpenWorkflowExecutionsInput {
     /// <p>The name of the domain containing the workflow executions to count.</p>
     #[serde(rename = "domain")]
@@ -595,7 +732,28 @@ pub struct CountOpenWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
+impl Validate for CountOpenWorkflowExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.execution_filter {
+            value.validate_at(&field_path(path, "execution_filter"), errors);
+        }
+        if let Some(ref value) = self.tag_filter {
+            value.validate_at(&field_path(path, "tag_filter"), errors);
+        }
+        if let Some(ref value) = self.type_filter {
+            value.validate_at(&field_path(path, "type_filter"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CountPendingActivityTasksInput {
     /// <p>The name of the domain that contains the task list.</p>
     #[serde(rename = "domain")]
@@ -605,7 +763,21 @@ pub struct CountPendingActivityTasksInput {
     pub task_list: TaskList,
 }
 
+impl Validate for CountPendingActivityTasksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.task_list
+            .validate_at(&field_path(path, "task_list"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CountPendingDecisionTasksInput {
     /// <p>The name of the domain that contains the task list.</p>
     #[serde(rename = "domain")]
@@ -615,8 +787,22 @@ pub struct CountPendingDecisionTasksInput {
     pub task_list: TaskList,
 }
 
+impl Validate for CountPendingDecisionTasksInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.task_list
+            .validate_at(&field_path(path, "task_list"), errors);
+    }
+}
+
 /// <p><p>Specifies a decision made by the decider. A decision can be one of these types:</p> <ul> <li> <p> <code>CancelTimer</code> – Cancels a previously started timer and records a <code>TimerCanceled</code> event in the history.</p> </li> <li> <p> <code>CancelWorkflowExecution</code> – Closes the workflow execution and records a <code>WorkflowExecutionCanceled</code> event in the history.</p> </li> <li> <p> <code>CompleteWorkflowExecution</code> – Closes the workflow execution and records a <code>WorkflowExecutionCompleted</code> event in the history .</p> </li> <li> <p> <code>ContinueAsNewWorkflowExecution</code> – Closes the workflow execution and starts a new workflow execution of the same type using the same workflow ID and a unique run Id. A <code>WorkflowExecutionContinuedAsNew</code> event is recorded in the history.</p> </li> <li> <p> <code>FailWorkflowExecution</code> – Closes the workflow execution and records a <code>WorkflowExecutionFailed</code> event in the history.</p> </li> <li> <p> <code>RecordMarker</code> – Records a <code>MarkerRecorded</code> event in the history. Markers can be used for adding custom information in the history for instance to let deciders know that they don&#39;t need to look at the history beyond the marker event.</p> </li> <li> <p> <code>RequestCancelActivityTask</code> – Attempts to cancel a previously scheduled activity task. If the activity task was scheduled but has not been assigned to a worker, then it is canceled. If the activity task was already assigned to a worker, then the worker is informed that cancellation has been requested in the response to <a>RecordActivityTaskHeartbeat</a>.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecution</code> – Requests that a request be made to cancel the specified external workflow execution and records a <code>RequestCancelExternalWorkflowExecutionInitiated</code> event in the history.</p> </li> <li> <p> <code>ScheduleActivityTask</code> – Schedules an activity task.</p> </li> <li> <p> <code>SignalExternalWorkflowExecution</code> – Requests a signal to be delivered to the specified external workflow execution and records a <code>SignalExternalWorkflowExecutionInitiated</code> event in the history.</p> </li> <li> <p> <code>StartChildWorkflowExecution</code> – Requests that a child workflow execution be started and records a <code>StartChildWorkflowExecutionInitiated</code> event in the history. The child workflow execution is a separate workflow execution with its own history.</p> </li> <li> <p> <code>StartTimer</code> – Starts a timer for this workflow execution and records a <code>TimerStarted</code> event in the history. This timer fires after the specified delay and record a <code>TimerFired</code> event.</p> </li> </ul> <p> <b>Access Control</b> </p> <p>If you grant permission to use <code>RespondDecisionTaskCompleted</code>, you can use IAM policies to express permissions for the list of decisions returned by this action as if they were members of the API. Treating decisions as a pseudo API maintains a uniform conceptual model and helps keep policies readable. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> <p> <b>Decision Failure</b> </p> <p>Decisions can fail for several reasons</p> <ul> <li> <p>The ordering of decisions should follow a logical flow. Some decisions might not make sense in the current context of the workflow execution and therefore fails.</p> </li> <li> <p>A limit on your account was reached.</p> </li> <li> <p>The decision lacks sufficient permissions.</p> </li> </ul> <p>One of the following events might be added to the history to indicate an error. The event attribute&#39;s <code>cause</code> parameter indicates the cause. If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> <ul> <li> <p> <code>ScheduleActivityTaskFailed</code> – A <code>ScheduleActivityTask</code> decision failed. This could happen if the activity type specified in the decision isn&#39;t registered, is in a deprecated state, or the decision isn&#39;t properly configured.</p> </li> <li> <p> <code>RequestCancelActivityTaskFailed</code> – A <code>RequestCancelActivityTask</code> decision failed. This could happen if there is no open activity task with the specified activityId.</p> </li> <li> <p> <code>StartTimerFailed</code> – A <code>StartTimer</code> decision failed. This could happen if there is another open timer with the same timerId.</p> </li> <li> <p> <code>CancelTimerFailed</code> – A <code>CancelTimer</code> decision failed. This could happen if there is no open timer with the specified timerId.</p> </li> <li> <p> <code>StartChildWorkflowExecutionFailed</code> – A <code>StartChildWorkflowExecution</code> decision failed. This could happen if the workflow type specified isn&#39;t registered, is deprecated, or the decision isn&#39;t properly configured.</p> </li> <li> <p> <code>SignalExternalWorkflowExecutionFailed</code> – A <code>SignalExternalWorkflowExecution</code> decision failed. This could happen if the <code>workflowID</code> specified in the decision was incorrect.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecutionFailed</code> – A <code>RequestCancelExternalWorkflowExecution</code> decision failed. This could happen if the <code>workflowID</code> specified in the decision was incorrect.</p> </li> <li> <p> <code>CancelWorkflowExecutionFailed</code> – A <code>CancelWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution.</p> </li> <li> <p> <code>CompleteWorkflowExecutionFailed</code> – A <code>CompleteWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution.</p> </li> <li> <p> <code>ContinueAsNewWorkflowExecutionFailed</code> – A <code>ContinueAsNewWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution or the ContinueAsNewWorkflowExecution decision was not configured correctly.</p> </li> <li> <p> <code>FailWorkflowExecutionFailed</code> – A <code>FailWorkflowExecution</code> decision failed. This could happen if there is an unhandled decision task pending in the workflow execution.</p> </li> </ul> <p>The preceding error events might occur due to an error in the decider logic, which might put the workflow execution in an unstable state The cause field in the event structure for the error event indicates the cause of the error.</p> <note> <p>A workflow execution may be closed by the decider by returning one of the following decisions when completing a decision task: <code>CompleteWorkflowExecution</code>, <code>FailWorkflowExecution</code>, <code>CancelWorkflowExecution</code> and <code>ContinueAsNewWorkflowExecution</code>. An <code>UnhandledDecision</code> fault is returned if a workflow closing decision is specified and a signal or activity event had been added to the history while the decision task was being performed by the decider. Unlike the above situations which are logic issues, this fault is always possible because of race conditions in a distributed system. The right action here is to call <a>RespondDecisionTaskCompleted</a> without any decisions. This would result in another decision task with these new events included in the history. The decider should handle the new events and may decide to close the workflow execution.</p> </note> <p> <b>How to Code a Decision</b> </p> <p>You code a decision by first setting the decision type field to one of the above decision values, and then set the corresponding attributes field shown below:</p> <ul> <li> <p> <code> <a>ScheduleActivityTaskDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>RequestCancelActivityTaskDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>CompleteWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>FailWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>CancelWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>ContinueAsNewWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>RecordMarkerDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>StartTimerDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>CancelTimerDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>SignalExternalWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>RequestCancelExternalWorkflowExecutionDecisionAttributes</a> </code> </p> </li> <li> <p> <code> <a>StartChildWorkflowExecutionDecisionAttributes</a> </code> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Decision {
     /// <p>Provides the details of the <code>CancelTimer</code> decision. It isn't set for other decision types.</p>
     #[serde(rename = "cancelTimerDecisionAttributes")]
@@ -684,9 +870,99 @@ pub struct Decision {
     pub start_timer_decision_attributes: Option<StartTimerDecisionAttributes>,
 }
 
+impl Validate for Decision {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.cancel_timer_decision_attributes {
+            value.validate_at(
+                &field_path(path, "cancel_timer_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.cancel_workflow_execution_decision_attributes {
+            value.validate_at(
+                &field_path(path, "cancel_workflow_execution_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.complete_workflow_execution_decision_attributes {
+            value.validate_at(
+                &field_path(path, "complete_workflow_execution_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.continue_as_new_workflow_execution_decision_attributes {
+            value.validate_at(
+                &field_path(
+                    path,
+                    "continue_as_new_workflow_execution_decision_attributes",
+                ),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.fail_workflow_execution_decision_attributes {
+            value.validate_at(
+                &field_path(path, "fail_workflow_execution_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.record_marker_decision_attributes {
+            value.validate_at(
+                &field_path(path, "record_marker_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.request_cancel_activity_task_decision_attributes {
+            value.validate_at(
+                &field_path(path, "request_cancel_activity_task_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.request_cancel_external_workflow_execution_decision_attributes
+        {
+            value.validate_at(
+                &field_path(
+                    path,
+                    "request_cancel_external_workflow_execution_decision_attributes",
+                ),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.schedule_activity_task_decision_attributes {
+            value.validate_at(
+                &field_path(path, "schedule_activity_task_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.schedule_lambda_function_decision_attributes {
+            value.validate_at(
+                &field_path(path, "schedule_lambda_function_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.signal_external_workflow_execution_decision_attributes {
+            value.validate_at(
+                &field_path(
+                    path,
+                    "signal_external_workflow_execution_decision_attributes",
+                ),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.start_child_workflow_execution_decision_attributes {
+            value.validate_at(
+                &field_path(path, "start_child_workflow_execution_decision_attributes"),
+                errors,
+            );
+        }
+        if let Some(ref value) = self.start_timer_decision_attributes {
+            value.validate_at(&field_path(path, "start_timer_decision_attributes"), errors);
+        }
+    }
+}
+
 /// <p>A structure that represents a decision task. Decision tasks are sent to deciders in order for them to make decisions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecisionTask {
     /// <p>A paginated list of history events of the workflow execution. The decider uses this during the processing of the decision task.</p>
     #[serde(rename = "events")]
@@ -715,7 +991,7 @@ pub struct DecisionTask {
 
 /// <p>Provides the details of the <code>DecisionTaskCompleted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecisionTaskCompletedEventAttributes {
     /// <p>User defined context for the workflow execution.</p>
     #[serde(rename = "executionContext")]
@@ -731,7 +1007,7 @@ pub struct DecisionTaskCompletedEventAttributes {
 
 /// <p>Provides details about the <code>DecisionTaskScheduled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecisionTaskScheduledEventAttributes {
     /// <p>The maximum duration for this decision task. The task is considered timed out if it doesn't completed within this duration.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "startToCloseTimeout")]
@@ -748,7 +1024,7 @@ pub struct DecisionTaskScheduledEventAttributes {
 
 /// <p>Provides the details of the <code>DecisionTaskStarted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecisionTaskStartedEventAttributes {
     /// <p>Identity of the decider making the request. This enables diagnostic tracing when problems arise. The form of this identity is user defined.</p>
     #[serde(rename = "identity")]
@@ -761,7 +1037,7 @@ pub struct DecisionTaskStartedEventAttributes {
 
 /// <p>Provides the details of the <code>DecisionTaskTimedOut</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DecisionTaskTimedOutEventAttributes {
     /// <p>The ID of the <code>DecisionTaskScheduled</code> event that was recorded when this decision task was scheduled. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "scheduledEventId")]
@@ -775,6 +1051,7 @@ pub struct DecisionTaskTimedOutEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeprecateActivityTypeInput {
     /// <p>The activity type to deprecate.</p>
     #[serde(rename = "activityType")]
@@ -784,14 +1061,40 @@ pub struct DeprecateActivityTypeInput {
     pub domain: String,
 }
 
+impl Validate for DeprecateActivityTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.activity_type
+            .validate_at(&field_path(path, "activity_type"), errors);
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeprecateDomainInput {
     /// <p>The name of the domain to deprecate.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DeprecateDomainInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeprecateWorkflowTypeInput {
     /// <p>The name of the domain in which the workflow type is registered.</p>
     #[serde(rename = "domain")]
@@ -801,7 +1104,21 @@ pub struct DeprecateWorkflowTypeInput {
     pub workflow_type: WorkflowType,
 }
 
+impl Validate for DeprecateWorkflowTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.workflow_type
+            .validate_at(&field_path(path, "workflow_type"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActivityTypeInput {
     /// <p>The activity type to get information about. Activity types are identified by the <code>name</code> and <code>version</code> that were supplied when the activity was registered.</p>
     #[serde(rename = "activityType")]
@@ -811,14 +1128,40 @@ pub struct DescribeActivityTypeInput {
     pub domain: String,
 }
 
+impl Validate for DescribeActivityTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.activity_type
+            .validate_at(&field_path(path, "activity_type"), errors);
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDomainInput {
     /// <p>The name of the domain to describe.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for DescribeDomainInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkflowExecutionInput {
     /// <p>The name of the domain containing the workflow execution.</p>
     #[serde(rename = "domain")]
@@ -828,7 +1171,21 @@ pub struct DescribeWorkflowExecutionInput {
     pub execution: WorkflowExecution,
 }
 
+impl Validate for DescribeWorkflowExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.execution
+            .validate_at(&field_path(path, "execution"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkflowTypeInput {
     /// <p>The name of the domain in which this workflow type is registered.</p>
     #[serde(rename = "domain")]
@@ -838,9 +1195,22 @@ pub struct DescribeWorkflowTypeInput {
     pub workflow_type: WorkflowType,
 }
 
+impl Validate for DescribeWorkflowTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.workflow_type
+            .validate_at(&field_path(path, "workflow_type"), errors);
+    }
+}
+
 /// <p>Contains the configuration settings of a domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainConfiguration {
     /// <p>The retention period for workflow executions in this domain.</p>
     #[serde(rename = "workflowExecutionRetentionPeriodInDays")]
@@ -849,7 +1219,7 @@ pub struct DomainConfiguration {
 
 /// <p>Contains details of a domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainDetail {
     /// <p>The domain configuration. Currently, this includes only the domain's retention period.</p>
     #[serde(rename = "configuration")]
@@ -861,7 +1231,7 @@ pub struct DomainDetail {
 
 /// <p>Contains general information about a domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainInfo {
     /// <p>The ARN of the domain.</p>
     #[serde(rename = "arn")]
@@ -881,7 +1251,7 @@ pub struct DomainInfo {
 
 /// <p>Contains a paginated collection of DomainInfo structures.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainInfos {
     /// <p>A list of DomainInfo structures.</p>
     #[serde(rename = "domainInfos")]
@@ -894,6 +1264,7 @@ pub struct DomainInfos {
 
 /// <p>Used to filter the workflow executions in visibility APIs by various time-based rules. Each parameter, if specified, defines a rule that must be satisfied by each returned query result. The parameter values are in the <a href="https://en.wikipedia.org/wiki/Unix_time">Unix Time format</a>. For example: <code>"oldestDate": 1325376070.</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ExecutionTimeFilter {
     /// <p>Specifies the latest start or close date and time to return.</p>
     #[serde(rename = "latestDate")]
@@ -906,7 +1277,7 @@ pub struct ExecutionTimeFilter {
 
 /// <p>Provides the details of the <code>ExternalWorkflowExecutionCancelRequested</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExternalWorkflowExecutionCancelRequestedEventAttributes {
     /// <p>The ID of the <code>RequestCancelExternalWorkflowExecutionInitiated</code> event corresponding to the <code>RequestCancelExternalWorkflowExecution</code> decision to cancel this external workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "initiatedEventId")]
@@ -918,7 +1289,7 @@ pub struct ExternalWorkflowExecutionCancelRequestedEventAttributes {
 
 /// <p>Provides the details of the <code>ExternalWorkflowExecutionSignaled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ExternalWorkflowExecutionSignaledEventAttributes {
     /// <p>The ID of the <code>SignalExternalWorkflowExecutionInitiated</code> event corresponding to the <code>SignalExternalWorkflowExecution</code> decision to request this signal. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "initiatedEventId")]
@@ -930,6 +1301,7 @@ pub struct ExternalWorkflowExecutionSignaledEventAttributes {
 
 /// <p>Provides the details of the <code>FailWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct FailWorkflowExecutionDecisionAttributes {
     /// <p> Details of the failure.</p>
     #[serde(rename = "details")]
@@ -941,9 +1313,30 @@ pub struct FailWorkflowExecutionDecisionAttributes {
     pub reason: Option<String>,
 }
 
+impl Validate for FailWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.reason {
+            errors.check_length(
+                &field_path(path, "reason"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 /// <p>Provides the details of the <code>FailWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -954,6 +1347,7 @@ pub struct FailWorkflowExecutionFailedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWorkflowExecutionHistoryInput {
     /// <p>The name of the domain containing the workflow execution.</p>
     #[serde(rename = "domain")]
@@ -975,9 +1369,38 @@ pub struct GetWorkflowExecutionHistoryInput {
     pub reverse_order: Option<bool>,
 }
 
+impl Validate for GetWorkflowExecutionHistoryInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.execution
+            .validate_at(&field_path(path, "execution"), errors);
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Paginated representation of a workflow history for a workflow execution. This is the up to date, complete and authoritative record of the events related to all tasks and events in the life of the workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct History {
     /// <p>The list of history events.</p>
     #[serde(rename = "events")]
@@ -990,7 +1413,7 @@ pub struct History {
 
 /// <p><p>Event within a workflow execution. A history event can be one of these types:</p> <ul> <li> <p> <code>ActivityTaskCancelRequested</code> – A <code>RequestCancelActivityTask</code> decision was received by the system.</p> </li> <li> <p> <code>ActivityTaskCanceled</code> – The activity task was successfully canceled.</p> </li> <li> <p> <code>ActivityTaskCompleted</code> – An activity worker successfully completed an activity task by calling <a>RespondActivityTaskCompleted</a>.</p> </li> <li> <p> <code>ActivityTaskFailed</code> – An activity worker failed an activity task by calling <a>RespondActivityTaskFailed</a>.</p> </li> <li> <p> <code>ActivityTaskScheduled</code> – An activity task was scheduled for execution.</p> </li> <li> <p> <code>ActivityTaskStarted</code> – The scheduled activity task was dispatched to a worker.</p> </li> <li> <p> <code>ActivityTaskTimedOut</code> – The activity task timed out.</p> </li> <li> <p> <code>CancelTimerFailed</code> – Failed to process CancelTimer decision. This happens when the decision isn&#39;t configured properly, for example no timer exists with the specified timer Id.</p> </li> <li> <p> <code>CancelWorkflowExecutionFailed</code> – A request to cancel a workflow execution failed.</p> </li> <li> <p> <code>ChildWorkflowExecutionCanceled</code> – A child workflow execution, started by this workflow execution, was canceled and closed.</p> </li> <li> <p> <code>ChildWorkflowExecutionCompleted</code> – A child workflow execution, started by this workflow execution, completed successfully and was closed.</p> </li> <li> <p> <code>ChildWorkflowExecutionFailed</code> – A child workflow execution, started by this workflow execution, failed to complete successfully and was closed.</p> </li> <li> <p> <code>ChildWorkflowExecutionStarted</code> – A child workflow execution was successfully started.</p> </li> <li> <p> <code>ChildWorkflowExecutionTerminated</code> – A child workflow execution, started by this workflow execution, was terminated.</p> </li> <li> <p> <code>ChildWorkflowExecutionTimedOut</code> – A child workflow execution, started by this workflow execution, timed out and was closed.</p> </li> <li> <p> <code>CompleteWorkflowExecutionFailed</code> – The workflow execution failed to complete.</p> </li> <li> <p> <code>ContinueAsNewWorkflowExecutionFailed</code> – The workflow execution failed to complete after being continued as a new workflow execution.</p> </li> <li> <p> <code>DecisionTaskCompleted</code> – The decider successfully completed a decision task by calling <a>RespondDecisionTaskCompleted</a>.</p> </li> <li> <p> <code>DecisionTaskScheduled</code> – A decision task was scheduled for the workflow execution.</p> </li> <li> <p> <code>DecisionTaskStarted</code> – The decision task was dispatched to a decider.</p> </li> <li> <p> <code>DecisionTaskTimedOut</code> – The decision task timed out.</p> </li> <li> <p> <code>ExternalWorkflowExecutionCancelRequested</code> – Request to cancel an external workflow execution was successfully delivered to the target execution.</p> </li> <li> <p> <code>ExternalWorkflowExecutionSignaled</code> – A signal, requested by this workflow execution, was successfully delivered to the target external workflow execution.</p> </li> <li> <p> <code>FailWorkflowExecutionFailed</code> – A request to mark a workflow execution as failed, itself failed.</p> </li> <li> <p> <code>MarkerRecorded</code> – A marker was recorded in the workflow history as the result of a <code>RecordMarker</code> decision.</p> </li> <li> <p> <code>RecordMarkerFailed</code> – A <code>RecordMarker</code> decision was returned as failed.</p> </li> <li> <p> <code>RequestCancelActivityTaskFailed</code> – Failed to process RequestCancelActivityTask decision. This happens when the decision isn&#39;t configured properly.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecutionFailed</code> – Request to cancel an external workflow execution failed.</p> </li> <li> <p> <code>RequestCancelExternalWorkflowExecutionInitiated</code> – A request was made to request the cancellation of an external workflow execution.</p> </li> <li> <p> <code>ScheduleActivityTaskFailed</code> – Failed to process ScheduleActivityTask decision. This happens when the decision isn&#39;t configured properly, for example the activity type specified isn&#39;t registered.</p> </li> <li> <p> <code>SignalExternalWorkflowExecutionFailed</code> – The request to signal an external workflow execution failed.</p> </li> <li> <p> <code>SignalExternalWorkflowExecutionInitiated</code> – A request to signal an external workflow was made.</p> </li> <li> <p> <code>StartActivityTaskFailed</code> – A scheduled activity task failed to start.</p> </li> <li> <p> <code>StartChildWorkflowExecutionFailed</code> – Failed to process StartChildWorkflowExecution decision. This happens when the decision isn&#39;t configured properly, for example the workflow type specified isn&#39;t registered.</p> </li> <li> <p> <code>StartChildWorkflowExecutionInitiated</code> – A request was made to start a child workflow execution.</p> </li> <li> <p> <code>StartTimerFailed</code> – Failed to process StartTimer decision. This happens when the decision isn&#39;t configured properly, for example a timer already exists with the specified timer Id.</p> </li> <li> <p> <code>TimerCanceled</code> – A timer, previously started for this workflow execution, was successfully canceled.</p> </li> <li> <p> <code>TimerFired</code> – A timer, previously started for this workflow execution, fired.</p> </li> <li> <p> <code>TimerStarted</code> – A timer was started for the workflow execution due to a <code>StartTimer</code> decision.</p> </li> <li> <p> <code>WorkflowExecutionCancelRequested</code> – A request to cancel this workflow execution was made.</p> </li> <li> <p> <code>WorkflowExecutionCanceled</code> – The workflow execution was successfully canceled and closed.</p> </li> <li> <p> <code>WorkflowExecutionCompleted</code> – The workflow execution was closed due to successful completion.</p> </li> <li> <p> <code>WorkflowExecutionContinuedAsNew</code> – The workflow execution was closed and a new execution of the same type was created with the same workflowId.</p> </li> <li> <p> <code>WorkflowExecutionFailed</code> – The workflow execution closed due to a failure.</p> </li> <li> <p> <code>WorkflowExecutionSignaled</code> – An external signal was received for the workflow execution.</p> </li> <li> <p> <code>WorkflowExecutionStarted</code> – The workflow execution was started.</p> </li> <li> <p> <code>WorkflowExecutionTerminated</code> – The workflow execution was terminated.</p> </li> <li> <p> <code>WorkflowExecutionTimedOut</code> – The workflow execution was closed because a time out was exceeded.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HistoryEvent {
     /// <p>If the event is of type <code>ActivityTaskcancelRequested</code> then this member is set and provides detailed information about the event. It isn't set for other event types.</p>
     #[serde(rename = "activityTaskCancelRequestedEventAttributes")]
@@ -1252,7 +1675,7 @@ pub struct HistoryEvent {
 
 /// <p>Provides the details of the <code>LambdaFunctionCompleted</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionCompletedEventAttributes {
     /// <p>The results of the Lambda task.</p>
     #[serde(rename = "result")]
@@ -1268,7 +1691,7 @@ pub struct LambdaFunctionCompletedEventAttributes {
 
 /// <p>Provides the details of the <code>LambdaFunctionFailed</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionFailedEventAttributes {
     /// <p>The details of the failure.</p>
     #[serde(rename = "details")]
@@ -1288,7 +1711,7 @@ pub struct LambdaFunctionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>LambdaFunctionScheduled</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionScheduledEventAttributes {
     /// <p>Data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the Lambda task.</p>
     #[serde(rename = "control")]
@@ -1315,7 +1738,7 @@ pub struct LambdaFunctionScheduledEventAttributes {
 
 /// <p>Provides the details of the <code>LambdaFunctionStarted</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionStartedEventAttributes {
     /// <p>The ID of the <code>LambdaFunctionScheduled</code> event that was recorded when this activity task was scheduled. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p>
     #[serde(rename = "scheduledEventId")]
@@ -1324,7 +1747,7 @@ pub struct LambdaFunctionStartedEventAttributes {
 
 /// <p>Provides details of the <code>LambdaFunctionTimedOut</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct LambdaFunctionTimedOutEventAttributes {
     /// <p>The ID of the <code>LambdaFunctionScheduled</code> event that was recorded when this activity task was scheduled. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p>
     #[serde(rename = "scheduledEventId")]
@@ -1339,6 +1762,7 @@ pub struct LambdaFunctionTimedOutEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListActivityTypesInput {
     /// <p>The name of the domain in which the activity types have been registered.</p>
     #[serde(rename = "domain")]
@@ -1364,7 +1788,43 @@ pub struct ListActivityTypesInput {
     pub reverse_order: Option<bool>,
 }
 
+impl Validate for ListActivityTypesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListClosedWorkflowExecutionsInput {
     /// <p><p>If specified, only workflow executions that match this <i>close status</i> are listed. For example, if TERMINATED is specified, then only TERMINATED workflow executions are listed.</p> <note> <p> <code>closeStatusFilter</code>, <code>executionFilter</code>, <code>typeFilter</code> and <code>tagFilter</code> are mutually exclusive. You can specify at most one of these in a request.</p> </note></p>
     #[serde(rename = "closeStatusFilter")]
@@ -1407,7 +1867,44 @@ pub struct ListClosedWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
+impl Validate for ListClosedWorkflowExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.execution_filter {
+            value.validate_at(&field_path(path, "execution_filter"), errors);
+        }
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.tag_filter {
+            value.validate_at(&field_path(path, "tag_filter"), errors);
+        }
+        if let Some(ref value) = self.type_filter {
+            value.validate_at(&field_path(path, "type_filter"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainsInput {
     /// <p>The maximum number of results that are returned per call. Use <code>nextPageToken</code> to obtain further pages of results. </p>
     #[serde(rename = "maximumPageSize")]
@@ -1426,7 +1923,29 @@ pub struct ListDomainsInput {
     pub reverse_order: Option<bool>,
 }
 
+impl Validate for ListDomainsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOpenWorkflowExecutionsInput {
     /// <p>The name of the domain that contains the workflow executions to list.</p>
     #[serde(rename = "domain")]
@@ -1460,15 +1979,63 @@ pub struct ListOpenWorkflowExecutionsInput {
     pub type_filter: Option<WorkflowTypeFilter>,
 }
 
+impl Validate for ListOpenWorkflowExecutionsInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.execution_filter {
+            value.validate_at(&field_path(path, "execution_filter"), errors);
+        }
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.tag_filter {
+            value.validate_at(&field_path(path, "tag_filter"), errors);
+        }
+        if let Some(ref value) = self.type_filter {
+            value.validate_at(&field_path(path, "type_filter"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Amazon SWF domain.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceOutput {
     /// <p>An array of tags associated with the domain.</p>
     #[serde(rename = "tags")]
@@ -1477,6 +2044,7 @@ pub struct ListTagsForResourceOutput {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWorkflowTypesInput {
     /// <p>The name of the domain in which the workflow types have been registered.</p>
     #[serde(rename = "domain")]
@@ -1502,9 +2070,44 @@ pub struct ListWorkflowTypesInput {
     pub reverse_order: Option<bool>,
 }
 
+impl Validate for ListWorkflowTypesInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Provides the details of the <code>MarkerRecorded</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct MarkerRecordedEventAttributes {
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>RecordMarker</code> decision that requested this marker. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
@@ -1520,7 +2123,7 @@ pub struct MarkerRecordedEventAttributes {
 
 /// <p>Contains the count of tasks in a task list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PendingTaskCount {
     /// <p>The number of tasks in the task list.</p>
     #[serde(rename = "count")]
@@ -1532,6 +2135,7 @@ pub struct PendingTaskCount {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PollForActivityTaskInput {
     /// <p>The name of the domain that contains the task lists being polled.</p>
     #[serde(rename = "domain")]
@@ -1545,7 +2149,29 @@ pub struct PollForActivityTaskInput {
     pub task_list: TaskList,
 }
 
+impl Validate for PollForActivityTaskInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.identity {
+            errors.check_length(
+                &field_path(path, "identity"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        self.task_list
+            .validate_at(&field_path(path, "task_list"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PollForDecisionTaskInput {
     /// <p>The name of the domain containing the task lists to poll.</p>
     #[serde(rename = "domain")]
@@ -1571,7 +2197,45 @@ pub struct PollForDecisionTaskInput {
     pub task_list: TaskList,
 }
 
+impl Validate for PollForDecisionTaskInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.identity {
+            errors.check_length(
+                &field_path(path, "identity"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.maximum_page_size {
+            errors.check_range(
+                &field_path(path, "maximum_page_size"),
+                *value,
+                None,
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_page_token {
+            errors.check_length(
+                &field_path(path, "next_page_token"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        self.task_list
+            .validate_at(&field_path(path, "task_list"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecordActivityTaskHeartbeatInput {
     /// <p>If specified, contains details about the progress of the task.</p>
     #[serde(rename = "details")]
@@ -1582,8 +2246,28 @@ pub struct RecordActivityTaskHeartbeatInput {
     pub task_token: String,
 }
 
+impl Validate for RecordActivityTaskHeartbeatInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>RecordMarker</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RecordMarkerDecisionAttributes {
     /// <p> The details of the marker.</p>
     #[serde(rename = "details")]
@@ -1594,9 +2278,28 @@ pub struct RecordMarkerDecisionAttributes {
     pub marker_name: String,
 }
 
+impl Validate for RecordMarkerDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "marker_name"),
+            self.marker_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>RecordMarkerFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RecordMarkerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -1610,6 +2313,7 @@ pub struct RecordMarkerFailedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterActivityTypeInput {
     /// <p>If set, specifies the default maximum time before which a worker processing a task of this type must report progress by calling <a>RecordActivityTaskHeartbeat</a>. If the timeout is exceeded, the activity task is automatically timed out. This default can be overridden when scheduling an activity task using the <code>ScheduleActivityTask</code> <a>Decision</a>. If the activity worker subsequently attempts to record a heartbeat or returns a result, the activity worker receives an <code>UnknownResource</code> fault. In this case, Amazon SWF no longer considers the activity task to be valid; the activity worker should clean up the activity task.</p> <p>The duration is specified in seconds, an integer greater than or equal to <code>0</code>. You can use <code>NONE</code> to specify unlimited duration.</p>
     #[serde(rename = "defaultTaskHeartbeatTimeout")]
@@ -1650,7 +2354,74 @@ pub struct RegisterActivityTypeInput {
     pub version: String,
 }
 
+impl Validate for RegisterActivityTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_task_heartbeat_timeout {
+            errors.check_length(
+                &field_path(path, "default_task_heartbeat_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.default_task_list {
+            value.validate_at(&field_path(path, "default_task_list"), errors);
+        }
+        if let Some(ref value) = self.default_task_schedule_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "default_task_schedule_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.default_task_schedule_to_start_timeout {
+            errors.check_length(
+                &field_path(path, "default_task_schedule_to_start_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.default_task_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "default_task_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterDomainInput {
     /// <p>A text description of the domain.</p>
     #[serde(rename = "description")]
@@ -1668,7 +2439,41 @@ pub struct RegisterDomainInput {
     pub workflow_execution_retention_period_in_days: String,
 }
 
+impl Validate for RegisterDomainInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "workflow_execution_retention_period_in_days"),
+            self.workflow_execution_retention_period_in_days
+                .chars()
+                .count(),
+            Some(1),
+            Some(8),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterWorkflowTypeInput {
     /// <p><p>If set, specifies the default policy to use for the child workflow executions when a workflow execution of this type is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "defaultChildPolicy")]
@@ -1709,17 +2514,87 @@ pub struct RegisterWorkflowTypeInput {
     pub version: String,
 }
 
+impl Validate for RegisterWorkflowTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.default_execution_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "default_execution_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.default_lambda_role {
+            errors.check_length(
+                &field_path(path, "default_lambda_role"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.default_task_list {
+            value.validate_at(&field_path(path, "default_task_list"), errors);
+        }
+        if let Some(ref value) = self.default_task_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "default_task_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>RequestCancelActivityTask</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestCancelActivityTaskDecisionAttributes {
     /// <p>The <code>activityId</code> of the activity task to be canceled.</p>
     #[serde(rename = "activityId")]
     pub activity_id: String,
 }
 
+impl Validate for RequestCancelActivityTaskDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "activity_id"),
+            self.activity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>RequestCancelActivityTaskFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestCancelActivityTaskFailedEventAttributes {
     /// <p>The activityId provided in the <code>RequestCancelActivityTask</code> decision that failed.</p>
     #[serde(rename = "activityId")]
@@ -1734,6 +2609,7 @@ pub struct RequestCancelActivityTaskFailedEventAttributes {
 
 /// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestCancelExternalWorkflowExecutionDecisionAttributes {
     /// <p>The data attached to the event that can be used by the decider in subsequent workflow tasks.</p>
     #[serde(rename = "control")]
@@ -1748,9 +2624,36 @@ pub struct RequestCancelExternalWorkflowExecutionDecisionAttributes {
     pub workflow_id: String,
 }
 
+impl Validate for RequestCancelExternalWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.control {
+            errors.check_length(
+                &field_path(path, "control"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.run_id {
+            errors.check_length(
+                &field_path(path, "run_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestCancelExternalWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -1776,7 +2679,7 @@ pub struct RequestCancelExternalWorkflowExecutionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>RequestCancelExternalWorkflowExecutionInitiated</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RequestCancelExternalWorkflowExecutionInitiatedEventAttributes {
     /// <p>Data attached to the event that can be used by the decider in subsequent workflow tasks.</p>
     #[serde(rename = "control")]
@@ -1795,6 +2698,7 @@ pub struct RequestCancelExternalWorkflowExecutionInitiatedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RequestCancelWorkflowExecutionInput {
     /// <p>The name of the domain containing the workflow execution to cancel.</p>
     #[serde(rename = "domain")]
@@ -1808,6 +2712,31 @@ pub struct RequestCancelWorkflowExecutionInput {
     pub workflow_id: String,
 }
 
+impl Validate for RequestCancelWorkflowExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.run_id {
+            errors.check_length(
+                &field_path(path, "run_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Tags are key-value pairs that can be associated with Amazon SWF state machines and activities.</p> <p>Tags may only contain unicode letters, digits, whitespace, or these symbols: <code>_ . : / = + - @</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceTag {
@@ -1820,7 +2749,27 @@ pub struct ResourceTag {
     pub value: Option<String>,
 }
 
+impl Validate for ResourceTag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RespondActivityTaskCanceledInput {
     /// <p> Information about the cancellation.</p>
     #[serde(rename = "details")]
@@ -1831,7 +2780,27 @@ pub struct RespondActivityTaskCanceledInput {
     pub task_token: String,
 }
 
+impl Validate for RespondActivityTaskCanceledInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RespondActivityTaskCompletedInput {
     /// <p>The result of the activity task. It is a free form string that is implementation specific.</p>
     #[serde(rename = "result")]
@@ -1842,7 +2811,27 @@ pub struct RespondActivityTaskCompletedInput {
     pub task_token: String,
 }
 
+impl Validate for RespondActivityTaskCompletedInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.result {
+            errors.check_length(
+                &field_path(path, "result"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RespondActivityTaskFailedInput {
     /// <p> Detailed information about the failure.</p>
     #[serde(rename = "details")]
@@ -1857,8 +2846,36 @@ pub struct RespondActivityTaskFailedInput {
     pub task_token: String,
 }
 
+impl Validate for RespondActivityTaskFailedInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.reason {
+            errors.check_length(
+                &field_path(path, "reason"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Input data for a TaskCompleted response to a decision task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RespondDecisionTaskCompletedInput {
     /// <p>The list of decisions (possibly empty) made by the decider while processing this decision task. See the docs for the <a>Decision</a> structure for details.</p>
     #[serde(rename = "decisions")]
@@ -1873,9 +2890,34 @@ pub struct RespondDecisionTaskCompletedInput {
     pub task_token: String,
 }
 
+impl Validate for RespondDecisionTaskCompletedInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.decisions {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "decisions"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        if let Some(ref value) = self.execution_context {
+            errors.check_length(
+                &field_path(path, "execution_context"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "task_token"),
+            self.task_token.chars().count(),
+            Some(1),
+            Some(1024),
+        );
+    }
+}
+
 /// <p>Specifies the <code>runId</code> of a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Run {
     /// <p>The <code>runId</code> of a workflow execution. This ID is generated by the service and can be used to uniquely identify the workflow execution within a domain.</p>
     #[serde(rename = "runId")]
@@ -1885,6 +2927,7 @@ pub struct Run {
 
 /// <p>Provides the details of the <code>ScheduleActivityTask</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code> – String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code> – String constraint. The key is <code>swf:activityType.version</code>.</p> </li> <li> <p> <code>taskList</code> – String constraint. The key is <code>swf:taskList.name</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduleActivityTaskDecisionAttributes {
     /// <p> The <code>activityId</code> of the activity task.</p> <p>The specified string must not start or end with whitespace. It must not contain a <code>:</code> (colon), <code>/</code> (slash), <code>|</code> (vertical bar), or any control characters (<code>\u0000-\u001f</code> | <code>\u007f-\u009f</code>). Also, it must not contain the literal string <code>arn</code>.</p>
     #[serde(rename = "activityId")]
@@ -1926,9 +2969,73 @@ pub struct ScheduleActivityTaskDecisionAttributes {
     pub task_priority: Option<String>,
 }
 
+impl Validate for ScheduleActivityTaskDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "activity_id"),
+            self.activity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.activity_type
+            .validate_at(&field_path(path, "activity_type"), errors);
+        if let Some(ref value) = self.control {
+            errors.check_length(
+                &field_path(path, "control"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.heartbeat_timeout {
+            errors.check_length(
+                &field_path(path, "heartbeat_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.schedule_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "schedule_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.schedule_to_start_timeout {
+            errors.check_length(
+                &field_path(path, "schedule_to_start_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.task_list {
+            value.validate_at(&field_path(path, "task_list"), errors);
+        }
+    }
+}
+
 /// <p>Provides the details of the <code>ScheduleActivityTaskFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduleActivityTaskFailedEventAttributes {
     /// <p>The activityId provided in the <code>ScheduleActivityTask</code> decision that failed.</p>
     #[serde(rename = "activityId")]
@@ -1946,6 +3053,7 @@ pub struct ScheduleActivityTaskFailedEventAttributes {
 
 /// <p>Decision attributes specified in <code>scheduleLambdaFunctionDecisionAttributes</code> within the list of decisions <code>decisions</code> passed to <a>RespondDecisionTaskCompleted</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ScheduleLambdaFunctionDecisionAttributes {
     /// <p>The data attached to the event that the decider can use in subsequent workflow tasks. This data isn't sent to the Lambda task.</p>
     #[serde(rename = "control")]
@@ -1967,9 +3075,50 @@ pub struct ScheduleLambdaFunctionDecisionAttributes {
     pub start_to_close_timeout: Option<String>,
 }
 
-/// <p>Provides the details of the <code>ScheduleLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl Validate for ScheduleLambdaFunctionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.control {
+            errors.check_length(
+                &field_path(path, "control"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        if let Some(ref value) = self.start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+    }
+}
+
+/// <p>Provides the details of the <code>ScheduleLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ScheduleLambdaFunctionFailedEventAttributes {
     /// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -1987,6 +3136,7 @@ pub struct ScheduleLambdaFunctionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>SignalExternalWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SignalExternalWorkflowExecutionDecisionAttributes {
     /// <p>The data attached to the event that can be used by the decider in subsequent decision tasks.</p>
     #[serde(rename = "control")]
@@ -2008,9 +3158,50 @@ pub struct SignalExternalWorkflowExecutionDecisionAttributes {
     pub workflow_id: String,
 }
 
+impl Validate for SignalExternalWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.control {
+            errors.check_length(
+                &field_path(path, "control"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.run_id {
+            errors.check_length(
+                &field_path(path, "run_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "signal_name"),
+            self.signal_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>SignalExternalWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SignalExternalWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -2036,7 +3227,7 @@ pub struct SignalExternalWorkflowExecutionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>SignalExternalWorkflowExecutionInitiated</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SignalExternalWorkflowExecutionInitiatedEventAttributes {
     /// <p>Data attached to the event that can be used by the decider in subsequent decision tasks.</p>
     #[serde(rename = "control")]
@@ -2062,6 +3253,7 @@ pub struct SignalExternalWorkflowExecutionInitiatedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SignalWorkflowExecutionInput {
     /// <p>The name of the domain containing the workflow execution to signal.</p>
     #[serde(rename = "domain")]
@@ -2082,8 +3274,48 @@ pub struct SignalWorkflowExecutionInput {
     pub workflow_id: String,
 }
 
+impl Validate for SignalWorkflowExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.run_id {
+            errors.check_length(
+                &field_path(path, "run_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "signal_name"),
+            self.signal_name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>StartChildWorkflowExecution</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.N</code> – The key is "swf:tagList.N" where N is the tag number from 0 to 4, inclusive.</p> </li> <li> <p> <code>taskList</code> – String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code> – String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code> – String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartChildWorkflowExecutionDecisionAttributes {
     /// <p><p> If set, specifies the policy to use for the child workflow executions if the workflow execution being started is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
@@ -2129,9 +3361,72 @@ pub struct StartChildWorkflowExecutionDecisionAttributes {
     pub workflow_type: WorkflowType,
 }
 
+impl Validate for StartChildWorkflowExecutionDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.control {
+            errors.check_length(
+                &field_path(path, "control"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.execution_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "execution_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.lambda_role {
+            errors.check_length(
+                &field_path(path, "lambda_role"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.tag_list {
+            errors.check_length(&field_path(path, "tag_list"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_list"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.task_list {
+            value.validate_at(&field_path(path, "task_list"), errors);
+        }
+        if let Some(ref value) = self.task_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "task_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.workflow_type
+            .validate_at(&field_path(path, "workflow_type"), errors);
+    }
+}
+
 /// <p>Provides the details of the <code>StartChildWorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartChildWorkflowExecutionFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>When <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision fails because it lacks sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html"> Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -2156,7 +3451,7 @@ pub struct StartChildWorkflowExecutionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>StartChildWorkflowExecutionInitiated</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
     /// <p><p>The policy to use for the child workflow executions if this execution gets terminated by explicitly calling the <a>TerminateWorkflowExecution</a> action or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
@@ -2205,7 +3500,7 @@ pub struct StartChildWorkflowExecutionInitiatedEventAttributes {
 
 /// <p>Provides the details of the <code>StartLambdaFunctionFailed</code> event. It isn't set for other event types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartLambdaFunctionFailedEventAttributes {
     /// <p><p>The cause of the failure. To help diagnose issues, use this information to trace back the chain of events leading up to this event.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because the IAM role attached to the execution lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/lambda-task.html">Lambda Tasks</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -2223,6 +3518,7 @@ pub struct StartLambdaFunctionFailedEventAttributes {
 
 /// <p>Provides the details of the <code>StartTimer</code> decision.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this decision's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartTimerDecisionAttributes {
     /// <p>The data attached to the event that can be used by the decider in subsequent workflow tasks.</p>
     #[serde(rename = "control")]
@@ -2236,9 +3532,34 @@ pub struct StartTimerDecisionAttributes {
     pub timer_id: String,
 }
 
+impl Validate for StartTimerDecisionAttributes {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.control {
+            errors.check_length(
+                &field_path(path, "control"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "start_to_fire_timeout"),
+            self.start_to_fire_timeout.chars().count(),
+            Some(1),
+            Some(8),
+        );
+        errors.check_length(
+            &field_path(path, "timer_id"),
+            self.timer_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>StartTimerFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTimerFailedEventAttributes {
     /// <p><p>The cause of the failure. This information is generated by the system and can be useful for diagnostic purposes.</p> <note> <p>If <code>cause</code> is set to <code>OPERATION<em>NOT</em>PERMITTED</code>, the decision failed because it lacked sufficient permissions. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p> </note></p>
     #[serde(rename = "cause")]
@@ -2252,6 +3573,7 @@ pub struct StartTimerFailedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartWorkflowExecutionInput {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of this workflow execution if it is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This policy overrides the default child policy specified when registering the workflow type using <a>RegisterWorkflowType</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
@@ -2296,15 +3618,89 @@ pub struct StartWorkflowExecutionInput {
     pub workflow_type: WorkflowType,
 }
 
+impl Validate for StartWorkflowExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.execution_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "execution_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        if let Some(ref value) = self.input {
+            errors.check_length(
+                &field_path(path, "input"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        if let Some(ref value) = self.lambda_role {
+            errors.check_length(
+                &field_path(path, "lambda_role"),
+                value.chars().count(),
+                Some(1),
+                Some(1600),
+            );
+        }
+        if let Some(ref value) = self.tag_list {
+            errors.check_length(&field_path(path, "tag_list"), value.len(), None, Some(5));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tag_list"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+            }
+        }
+        if let Some(ref value) = self.task_list {
+            value.validate_at(&field_path(path, "task_list"), errors);
+        }
+        if let Some(ref value) = self.task_start_to_close_timeout {
+            errors.check_length(
+                &field_path(path, "task_start_to_close_timeout"),
+                value.chars().count(),
+                None,
+                Some(8),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.workflow_type
+            .validate_at(&field_path(path, "workflow_type"), errors);
+    }
+}
+
 /// <p>Used to filter the workflow executions in visibility APIs based on a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagFilter {
     /// <p> Specifies the tag that must be associated with the execution for it to meet the filter criteria.</p> <p>Tags may only contain unicode letters, digits, whitespace, or these symbols: <code>_ . : / = + - @</code>.</p>
     #[serde(rename = "tag")]
     pub tag: String,
 }
 
+impl Validate for TagFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "tag"),
+            self.tag.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Amazon SWF domain.</p>
     #[serde(rename = "resourceArn")]
@@ -2314,6 +3710,21 @@ pub struct TagResourceInput {
     pub tags: Vec<ResourceTag>,
 }
 
+impl Validate for TagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>Represents a task list.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskList {
@@ -2322,7 +3733,19 @@ pub struct TaskList {
     pub name: String,
 }
 
+impl Validate for TaskList {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateWorkflowExecutionInput {
     /// <p><p>If set, specifies the policy to use for the child workflow executions of the workflow execution being terminated. This policy overrides the child policy specified for the workflow execution at registration time or when starting the execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul> <note> <p>A child policy for this workflow execution must be specified either as a default for the workflow type or through this parameter. If neither this parameter is set nor a default child policy was specified at registration time then a fault is returned.</p> </note></p>
     #[serde(rename = "childPolicy")]
@@ -2348,9 +3771,50 @@ pub struct TerminateWorkflowExecutionInput {
     pub workflow_id: String,
 }
 
+impl Validate for TerminateWorkflowExecutionInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.details {
+            errors.check_length(
+                &field_path(path, "details"),
+                value.chars().count(),
+                None,
+                Some(32768),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.reason {
+            errors.check_length(
+                &field_path(path, "reason"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+        }
+        if let Some(ref value) = self.run_id {
+            errors.check_length(
+                &field_path(path, "run_id"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p> Provides the details of the <code>TimerCanceled</code> event. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TimerCanceledEventAttributes {
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CancelTimer</code> decision to cancel this timer. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
@@ -2365,7 +3829,7 @@ pub struct TimerCanceledEventAttributes {
 
 /// <p>Provides the details of the <code>TimerFired</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TimerFiredEventAttributes {
     /// <p>The ID of the <code>TimerStarted</code> event that was recorded when this timer was started. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "startedEventId")]
@@ -2377,7 +3841,7 @@ pub struct TimerFiredEventAttributes {
 
 /// <p>Provides the details of the <code>TimerStarted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TimerStartedEventAttributes {
     /// <p>Data attached to the event that can be used by the decider in subsequent workflow tasks.</p>
     #[serde(rename = "control")]
@@ -2395,6 +3859,7 @@ pub struct TimerStartedEventAttributes {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UndeprecateActivityTypeInput {
     /// <p>The activity type to undeprecate.</p>
     #[serde(rename = "activityType")]
@@ -2404,14 +3869,40 @@ pub struct UndeprecateActivityTypeInput {
     pub domain: String,
 }
 
+impl Validate for UndeprecateActivityTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.activity_type
+            .validate_at(&field_path(path, "activity_type"), errors);
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UndeprecateDomainInput {
     /// <p>The name of the domain of the deprecated workflow type.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl Validate for UndeprecateDomainInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UndeprecateWorkflowTypeInput {
     /// <p>The name of the domain of the deprecated workflow type.</p>
     #[serde(rename = "domain")]
@@ -2421,7 +3912,21 @@ pub struct UndeprecateWorkflowTypeInput {
     pub workflow_type: WorkflowType,
 }
 
+impl Validate for UndeprecateWorkflowTypeInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain"),
+            self.domain.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        self.workflow_type
+            .validate_at(&field_path(path, "workflow_type"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceInput {
     /// <p>The Amazon Resource Name (ARN) for the Amazon SWF domain.</p>
     #[serde(rename = "resourceArn")]
@@ -2431,6 +3936,21 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceInput {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1600),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+        }
+    }
+}
+
 /// <p>Represents a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorkflowExecution {
@@ -2442,9 +3962,26 @@ pub struct WorkflowExecution {
     pub workflow_id: String,
 }
 
+impl Validate for WorkflowExecution {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "run_id"),
+            self.run_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Provides the details of the <code>WorkflowExecutionCancelRequested</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionCancelRequestedEventAttributes {
     /// <p>If set, indicates that the request to cancel the workflow execution was automatically generated, and specifies the cause. This happens if the parent workflow execution times out or is terminated, and the child policy is set to cancel child executions.</p>
     #[serde(rename = "cause")]
@@ -2462,7 +3999,7 @@ pub struct WorkflowExecutionCancelRequestedEventAttributes {
 
 /// <p>Provides the details of the <code>WorkflowExecutionCanceled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionCanceledEventAttributes {
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CancelWorkflowExecution</code> decision for this cancellation request. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
@@ -2475,7 +4012,7 @@ pub struct WorkflowExecutionCanceledEventAttributes {
 
 /// <p>Provides the details of the <code>WorkflowExecutionCompleted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionCompletedEventAttributes {
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>CompleteWorkflowExecution</code> decision to complete this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
@@ -2488,7 +4025,7 @@ pub struct WorkflowExecutionCompletedEventAttributes {
 
 /// <p>The configuration settings for a workflow execution including timeout values, tasklist etc. These configuration settings are determined from the defaults specified when registering the workflow type and those specified when starting the workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionConfiguration {
     /// <p><p>The policy to use for the child workflow executions if this workflow execution is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
@@ -2514,7 +4051,7 @@ pub struct WorkflowExecutionConfiguration {
 
 /// <p>Provides the details of the <code>WorkflowExecutionContinuedAsNew</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionContinuedAsNewEventAttributes {
     /// <p><p>The policy to use for the child workflow executions of the new execution if it is terminated by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
@@ -2559,7 +4096,7 @@ pub struct WorkflowExecutionContinuedAsNewEventAttributes {
 
 /// <p>Contains the count of workflow executions returned from <a>CountOpenWorkflowExecutions</a> or <a>CountClosedWorkflowExecutions</a> </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionCount {
     /// <p>The number of workflow executions.</p>
     #[serde(rename = "count")]
@@ -2572,7 +4109,7 @@ pub struct WorkflowExecutionCount {
 
 /// <p>Contains details about a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionDetail {
     /// <p>The configuration settings for this workflow execution including timeout values, tasklist etc.</p>
     #[serde(rename = "executionConfiguration")]
@@ -2595,7 +4132,7 @@ pub struct WorkflowExecutionDetail {
 
 /// <p>Provides the details of the <code>WorkflowExecutionFailed</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionFailedEventAttributes {
     /// <p>The ID of the <code>DecisionTaskCompleted</code> event corresponding to the decision task that resulted in the <code>FailWorkflowExecution</code> decision to fail this execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event.</p>
     #[serde(rename = "decisionTaskCompletedEventId")]
@@ -2612,15 +4149,27 @@ pub struct WorkflowExecutionFailedEventAttributes {
 
 /// <p>Used to filter the workflow executions in visibility APIs by their <code>workflowId</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct WorkflowExecutionFilter {
     /// <p>The workflowId to pass of match the criteria of this filter.</p>
     #[serde(rename = "workflowId")]
     pub workflow_id: String,
 }
 
+impl Validate for WorkflowExecutionFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "workflow_id"),
+            self.workflow_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 /// <p>Contains information about a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionInfo {
     /// <p>Set to true if a cancellation is requested for this workflow execution.</p>
     #[serde(rename = "cancelRequested")]
@@ -2658,7 +4207,7 @@ pub struct WorkflowExecutionInfo {
 
 /// <p>Contains a paginated list of information about workflow executions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionInfos {
     /// <p>The list of workflow information structures.</p>
     #[serde(rename = "executionInfos")]
@@ -2671,7 +4220,7 @@ pub struct WorkflowExecutionInfos {
 
 /// <p>Contains the counts of open tasks, child workflow executions and timers for a workflow execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionOpenCounts {
     /// <p>The count of activity tasks whose status is <code>OPEN</code>.</p>
     #[serde(rename = "openActivityTasks")]
@@ -2693,7 +4242,7 @@ pub struct WorkflowExecutionOpenCounts {
 
 /// <p>Provides the details of the <code>WorkflowExecutionSignaled</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionSignaledEventAttributes {
     /// <p>The ID of the <code>SignalExternalWorkflowExecutionInitiated</code> event corresponding to the <code>SignalExternalWorkflow</code> decision to signal this workflow execution.The source event with this ID can be found in the history of the source workflow execution. This information can be useful for diagnosing problems by tracing back the chain of events leading up to this event. This field is set only if the signal was initiated by another workflow execution.</p>
     #[serde(rename = "externalInitiatedEventId")]
@@ -2714,7 +4263,7 @@ pub struct WorkflowExecutionSignaledEventAttributes {
 
 /// <p>Provides details of <code>WorkflowExecutionStarted</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionStartedEventAttributes {
     /// <p><p>The policy to use for the child workflow executions if this workflow execution is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
@@ -2765,7 +4314,7 @@ pub struct WorkflowExecutionStartedEventAttributes {
 
 /// <p>Provides the details of the <code>WorkflowExecutionTerminated</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionTerminatedEventAttributes {
     /// <p>If set, indicates that the workflow execution was automatically terminated, and specifies the cause. This happens if the parent workflow execution times out or is terminated and the child policy is set to terminate child executions.</p>
     #[serde(rename = "cause")]
@@ -2786,7 +4335,7 @@ pub struct WorkflowExecutionTerminatedEventAttributes {
 
 /// <p>Provides the details of the <code>WorkflowExecutionTimedOut</code> event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowExecutionTimedOutEventAttributes {
     /// <p><p>The policy used for the child workflow executions of this workflow execution.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "childPolicy")]
@@ -2807,9 +4356,26 @@ pub struct WorkflowType {
     pub version: String,
 }
 
+impl Validate for WorkflowType {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "version"),
+            self.version.chars().count(),
+            Some(1),
+            Some(64),
+        );
+    }
+}
+
 /// <p>The configuration settings of a workflow type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowTypeConfiguration {
     /// <p><p> The default policy to use for the child workflow executions when a workflow execution of this type is terminated, by calling the <a>TerminateWorkflowExecution</a> action explicitly or due to an expired timeout. This default can be overridden when starting a workflow execution using the <a>StartWorkflowExecution</a> action or the <code>StartChildWorkflowExecution</code> <a>Decision</a>.</p> <p>The supported child policies are:</p> <ul> <li> <p> <code>TERMINATE</code> – The child executions are terminated.</p> </li> <li> <p> <code>REQUEST_CANCEL</code> – A request to cancel is attempted for each child execution by recording a <code>WorkflowExecutionCancelRequested</code> event in its history. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> </li> <li> <p> <code>ABANDON</code> – No action is taken. The child executions continue to run.</p> </li> </ul></p>
     #[serde(rename = "defaultChildPolicy")]
@@ -2839,7 +4405,7 @@ pub struct WorkflowTypeConfiguration {
 
 /// <p>Contains details about a workflow type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowTypeDetail {
     /// <p>Configuration settings of the workflow type registered through <a>RegisterWorkflowType</a> </p>
     #[serde(rename = "configuration")]
@@ -2851,6 +4417,7 @@ pub struct WorkflowTypeDetail {
 
 /// <p>Used to filter workflow execution query results by type. Each parameter, if specified, defines a rule that must be satisfied by each returned result.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct WorkflowTypeFilter {
     /// <p> Name of the workflow type.</p>
     #[serde(rename = "name")]
@@ -2861,9 +4428,28 @@ pub struct WorkflowTypeFilter {
     pub version: Option<String>,
 }
 
+impl Validate for WorkflowTypeFilter {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+    }
+}
+
 /// <p>Contains information about a workflow type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowTypeInfo {
     /// <p>The date when this type was registered.</p>
     #[serde(rename = "creationDate")]
@@ -2886,7 +4472,7 @@ pub struct WorkflowTypeInfo {
 
 /// <p>Contains a paginated list of information structures about workflow types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkflowTypeInfos {
     /// <p>If a <code>NextPageToken</code> was returned by a previous call, there are more results available. To retrieve the next page of results, make the call again using the returned token in <code>nextPageToken</code>. Keep all other arguments unchanged.</p> <p>The configured <code>maximumPageSize</code> determines how many results can be returned in a single call.</p>
     #[serde(rename = "nextPageToken")]
@@ -2922,7 +4508,12 @@ impl CountClosedWorkflowExecutionsError {
                         CountClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2967,7 +4558,12 @@ impl CountOpenWorkflowExecutionsError {
                         CountOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3010,7 +4606,12 @@ impl CountPendingActivityTasksError {
                         CountPendingActivityTasksError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3053,7 +4654,12 @@ impl CountPendingDecisionTasksError {
                         CountPendingDecisionTasksError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3103,7 +4709,12 @@ impl DeprecateActivityTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3154,7 +4765,12 @@ impl DeprecateDomainError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3205,7 +4821,12 @@ impl DeprecateWorkflowTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3249,7 +4870,12 @@ impl DescribeActivityTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3290,7 +4916,12 @@ impl DescribeDomainError {
                 "UnknownResourceFault" => {
                     return RusotoError::Service(DescribeDomainError::UnknownResourceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3333,7 +4964,12 @@ impl DescribeWorkflowExecutionError {
                         DescribeWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3376,7 +5012,12 @@ impl DescribeWorkflowTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3421,7 +5062,12 @@ impl GetWorkflowExecutionHistoryError {
                         GetWorkflowExecutionHistoryError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3464,7 +5110,12 @@ impl ListActivityTypesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3509,7 +5160,12 @@ impl ListClosedWorkflowExecutionsError {
                         ListClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3545,7 +5201,12 @@ impl ListDomainsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3589,7 +5250,12 @@ impl ListOpenWorkflowExecutionsError {
                         ListOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3639,7 +5305,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3683,7 +5354,12 @@ impl ListWorkflowTypesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3733,7 +5409,12 @@ impl PollForActivityTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3784,7 +5465,12 @@ impl PollForDecisionTaskError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3830,7 +5516,12 @@ impl RecordActivityTaskHeartbeatError {
                         RecordActivityTaskHeartbeatError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3887,7 +5578,12 @@ impl RegisterActivityTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3942,7 +5638,12 @@ impl RegisterDomainError {
                 "TooManyTagsFault" => {
                     return RusotoError::Service(RegisterDomainError::TooManyTagsFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4001,7 +5702,12 @@ impl RegisterWorkflowTypeError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4048,7 +5754,12 @@ impl RequestCancelWorkflowExecutionError {
                         RequestCancelWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4093,7 +5804,12 @@ impl RespondActivityTaskCanceledError {
                         RespondActivityTaskCanceledError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4138,7 +5854,12 @@ impl RespondActivityTaskCompletedError {
                         RespondActivityTaskCompletedError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4181,7 +5902,12 @@ impl RespondActivityTaskFailedError {
                         RespondActivityTaskFailedError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4226,7 +5952,12 @@ impl RespondDecisionTaskCompletedError {
                         RespondDecisionTaskCompletedError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4269,7 +6000,12 @@ impl SignalWorkflowExecutionError {
                         SignalWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4340,7 +6076,12 @@ impl StartWorkflowExecutionError {
                         StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4395,7 +6136,12 @@ impl TagResourceError {
                 "UnknownResourceFault" => {
                     return RusotoError::Service(TagResourceError::UnknownResourceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4442,7 +6188,12 @@ impl TerminateWorkflowExecutionError {
                         TerminateWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4492,7 +6243,12 @@ impl UndeprecateActivityTypeError {
                         UndeprecateActivityTypeError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4543,7 +6299,12 @@ impl UndeprecateDomainError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4594,7 +6355,12 @@ impl UndeprecateWorkflowTypeError {
                         UndeprecateWorkflowTypeError::UnknownResourceFault(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4641,7 +6407,12 @@ impl UntagResourceError {
                 "UnknownResourceFault" => {
                     return RusotoError::Service(UntagResourceError::UnknownResourceFault(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4874,1041 +6645,3228 @@ pub trait Swf {
     /// <p>Remove a tag from a Amazon SWF domain.</p>
     fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError>;
 }
-/// A client for the Amazon SWF API.
-#[derive(Clone)]
-pub struct SwfClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl SwfClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> SwfClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> SwfClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> SwfClient {
-        SwfClient { client, region }
-    }
-}
-
-impl Swf for SwfClient {
-    /// <p>Returns the number of closed workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+const _: Option<&dyn Swf> = None;
+impl<'a, T: Swf + ?Sized> Swf for &'a T {
     fn count_closed_workflow_executions(
         &self,
         input: CountClosedWorkflowExecutionsInput,
     ) -> RusotoFuture<WorkflowExecutionCount, CountClosedWorkflowExecutionsError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.CountClosedWorkflowExecutions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowExecutionCount, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CountClosedWorkflowExecutionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).count_closed_workflow_executions(input)
     }
-
-    /// <p>Returns the number of open workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn count_open_workflow_executions(
         &self,
         input: CountOpenWorkflowExecutionsInput,
     ) -> RusotoFuture<WorkflowExecutionCount, CountOpenWorkflowExecutionsError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.CountOpenWorkflowExecutions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowExecutionCount, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CountOpenWorkflowExecutionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).count_open_workflow_executions(input)
     }
-
-    /// <p>Returns the estimated number of activity tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no activity task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn count_pending_activity_tasks(
         &self,
         input: CountPendingActivityTasksInput,
     ) -> RusotoFuture<PendingTaskCount, CountPendingActivityTasksError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.CountPendingActivityTasks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PendingTaskCount, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CountPendingActivityTasksError::from_response(response))
-                }))
-            }
-        })
+        (**self).count_pending_activity_tasks(input)
     }
-
-    /// <p>Returns the estimated number of decision tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no decision task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn count_pending_decision_tasks(
         &self,
         input: CountPendingDecisionTasksInput,
     ) -> RusotoFuture<PendingTaskCount, CountPendingDecisionTasksError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.CountPendingDecisionTasks",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PendingTaskCount, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CountPendingDecisionTasksError::from_response(response))
-                }))
-            }
-        })
+        (**self).count_pending_decision_tasks(input)
     }
-
-    /// <p>Deprecates the specified <i>activity type</i>. After an activity type has been deprecated, you cannot create new tasks of that activity type. Tasks of this type that were scheduled before the type was deprecated continue to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn deprecate_activity_type(
         &self,
         input: DeprecateActivityTypeInput,
     ) -> RusotoFuture<(), DeprecateActivityTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.DeprecateActivityType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeprecateActivityTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deprecate_activity_type(input)
     }
-
-    /// <p>Deprecates the specified domain. After a domain has been deprecated it cannot be used to create new workflow executions or register new types. However, you can still use visibility actions on this domain. Deprecating a domain also deprecates all activity and workflow types registered in the domain. Executions that were started before the domain was deprecated continues to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn deprecate_domain(
         &self,
         input: DeprecateDomainInput,
     ) -> RusotoFuture<(), DeprecateDomainError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.DeprecateDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeprecateDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).deprecate_domain(input)
     }
-
-    /// <p>Deprecates the specified <i>workflow type</i>. After a workflow type has been deprecated, you cannot create new executions of that type. Executions that were started before the type was deprecated continues to run. A deprecated workflow type may still be used when calling visibility actions.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn deprecate_workflow_type(
         &self,
         input: DeprecateWorkflowTypeInput,
     ) -> RusotoFuture<(), DeprecateWorkflowTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.DeprecateWorkflowType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeprecateWorkflowTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deprecate_workflow_type(input)
     }
-
-    /// <p>Returns information about the specified activity type. This includes configuration settings provided when the type was registered and other general information about the type.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn describe_activity_type(
         &self,
         input: DescribeActivityTypeInput,
     ) -> RusotoFuture<ActivityTypeDetail, DescribeActivityTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.DescribeActivityType");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ActivityTypeDetail, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeActivityTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_activity_type(input)
     }
-
-    /// <p>Returns information about the specified domain, including description and status.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn describe_domain(
         &self,
         input: DescribeDomainInput,
     ) -> RusotoFuture<DomainDetail, DescribeDomainError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.DescribeDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DomainDetail, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_domain(input)
     }
-
-    /// <p>Returns information about the specified workflow execution including its type and some statistics.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn describe_workflow_execution(
         &self,
         input: DescribeWorkflowExecutionInput,
     ) -> RusotoFuture<WorkflowExecutionDetail, DescribeWorkflowExecutionError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.DescribeWorkflowExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowExecutionDetail, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeWorkflowExecutionError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_workflow_execution(input)
     }
-
-    /// <p>Returns information about the specified <i>workflow type</i>. This includes configuration settings specified when the type was registered and other information such as creation date, current status, etc.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn describe_workflow_type(
         &self,
         input: DescribeWorkflowTypeInput,
     ) -> RusotoFuture<WorkflowTypeDetail, DescribeWorkflowTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.DescribeWorkflowType");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowTypeDetail, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeWorkflowTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_workflow_type(input)
     }
-
-    /// <p>Returns the history of the specified workflow execution. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn get_workflow_execution_history(
         &self,
         input: GetWorkflowExecutionHistoryInput,
     ) -> RusotoFuture<History, GetWorkflowExecutionHistoryError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.GetWorkflowExecutionHistory",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<History, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetWorkflowExecutionHistoryError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_workflow_execution_history(input)
     }
-
-    /// <p>Returns information about all activities registered in the specified domain that match the specified name and registration status. The result includes information like creation date, current status of the activity, etc. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn list_activity_types(
         &self,
         input: ListActivityTypesInput,
     ) -> RusotoFuture<ActivityTypeInfos, ListActivityTypesError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.ListActivityTypes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ActivityTypeInfos, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListActivityTypesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_activity_types(input)
     }
-
-    /// <p>Returns a list of closed workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn list_closed_workflow_executions(
         &self,
         input: ListClosedWorkflowExecutionsInput,
     ) -> RusotoFuture<WorkflowExecutionInfos, ListClosedWorkflowExecutionsError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.ListClosedWorkflowExecutions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowExecutionInfos, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListClosedWorkflowExecutionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_closed_workflow_executions(input)
     }
-
-    /// <p>Returns the list of domains registered in the account. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains. The element must be set to <code>arn:aws:swf::AccountID:domain/*</code>, where <i>AccountID</i> is the account ID, with no dashes.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn list_domains(&self, input: ListDomainsInput) -> RusotoFuture<DomainInfos, ListDomainsError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.ListDomains");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DomainInfos, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDomainsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_domains(input)
     }
-
-    /// <p>Returns a list of open workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn list_open_workflow_executions(
         &self,
         input: ListOpenWorkflowExecutionsInput,
     ) -> RusotoFuture<WorkflowExecutionInfos, ListOpenWorkflowExecutionsError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.ListOpenWorkflowExecutions",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowExecutionInfos, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListOpenWorkflowExecutionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_open_workflow_executions(input)
     }
-
-    /// <p>List tags for a given domain.</p>
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceInput,
     ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Returns information about workflow types in the specified domain. The results may be split into multiple pages that can be retrieved by making the call repeatedly.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn list_workflow_types(
         &self,
         input: ListWorkflowTypesInput,
     ) -> RusotoFuture<WorkflowTypeInfos, ListWorkflowTypesError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.ListWorkflowTypes");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<WorkflowTypeInfos, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListWorkflowTypesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_workflow_types(input)
     }
-
-    /// <p>Used by workers to get an <a>ActivityTask</a> from the specified activity <code>taskList</code>. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available. The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns an empty result. An empty result, in this context, means that an ActivityTask is returned, but that the value of taskToken is an empty string. If a task is returned, the worker should use its type to identify and process it correctly.</p> <important> <p>Workers should set their client side socket timeout to at least 70 seconds (10 seconds higher than the maximum time service may hold the poll request).</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn poll_for_activity_task(
         &self,
         input: PollForActivityTaskInput,
     ) -> RusotoFuture<ActivityTask, PollForActivityTaskError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.PollForActivityTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ActivityTask, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PollForActivityTaskError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).poll_for_activity_task(input)
     }
-
-    /// <p>Used by deciders to get a <a>DecisionTask</a> from the specified decision <code>taskList</code>. A decision task may be returned for any open workflow execution that is using the specified task list. The task includes a paginated view of the history of the workflow execution. The decider should use the workflow type and the history to determine how to properly handle the task.</p> <p>This action initiates a long poll, where the service holds the HTTP connection open and responds as soon a task becomes available. If no decision task is available in the specified task list before the timeout of 60 seconds expires, an empty result is returned. An empty result, in this context, means that a DecisionTask is returned, but that the value of taskToken is an empty string.</p> <important> <p>Deciders should set their client side socket timeout to at least 70 seconds (10 seconds higher than the timeout).</p> </important> <important> <p>Because the number of workflow history events for a single workflow execution might be very large, the result returned might be split up across a number of pages. To retrieve subsequent pages, make additional calls to <code>PollForDecisionTask</code> using the <code>nextPageToken</code> returned by the initial call. Note that you do <i>not</i> call <code>GetWorkflowExecutionHistory</code> with this <code>nextPageToken</code>. Instead, call <code>PollForDecisionTask</code> again.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn poll_for_decision_task(
         &self,
         input: PollForDecisionTaskInput,
     ) -> RusotoFuture<DecisionTask, PollForDecisionTaskError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.PollForDecisionTask");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<DecisionTask, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PollForDecisionTaskError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).poll_for_decision_task(input)
     }
-
-    /// <p>Used by activity workers to report to the service that the <a>ActivityTask</a> represented by the specified <code>taskToken</code> is still making progress. The worker can also specify details of the progress, for example percent complete, using the <code>details</code> parameter. This action can also be used by the worker as a mechanism to check if cancellation is being requested for the activity task. If a cancellation is being attempted for the specified task, then the boolean <code>cancelRequested</code> flag returned by the service is set to <code>true</code>.</p> <p>This action resets the <code>taskHeartbeatTimeout</code> clock. The <code>taskHeartbeatTimeout</code> is specified in <a>RegisterActivityType</a>.</p> <p>This action doesn't in itself create an event in the workflow execution history. However, if the task times out, the workflow execution history contains a <code>ActivityTaskTimedOut</code> event that contains the information from the last heartbeat generated by the activity worker.</p> <note> <p>The <code>taskStartToCloseTimeout</code> of an activity type is the maximum duration of an activity task, regardless of the number of <a>RecordActivityTaskHeartbeat</a> requests received. The <code>taskStartToCloseTimeout</code> is also specified in <a>RegisterActivityType</a>.</p> </note> <note> <p>This operation is only useful for long-lived activities to report liveliness of the task and to determine if a cancellation is being attempted.</p> </note> <important> <p>If the <code>cancelRequested</code> flag returns <code>true</code>, a cancellation is being attempted. If the worker can cancel the activity, it should respond with <a>RespondActivityTaskCanceled</a>. Otherwise, it should ignore the cancellation request.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn record_activity_task_heartbeat(
         &self,
         input: RecordActivityTaskHeartbeatInput,
     ) -> RusotoFuture<ActivityTaskStatus, RecordActivityTaskHeartbeatError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.RecordActivityTaskHeartbeat",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ActivityTaskStatus, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RecordActivityTaskHeartbeatError::from_response(response))
-                }))
-            }
-        })
+        (**self).record_activity_task_heartbeat(input)
     }
-
-    /// <p>Registers a new <i>activity type</i> along with its configuration settings in the specified domain.</p> <important> <p>A <code>TypeAlreadyExists</code> fault is returned if the type already exists in the domain. You cannot change any configuration settings of the type after its registration, and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn register_activity_type(
         &self,
         input: RegisterActivityTypeInput,
     ) -> RusotoFuture<(), RegisterActivityTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.RegisterActivityType");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RegisterActivityTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).register_activity_type(input)
     }
-
-    /// <p>Registers a new domain.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>You cannot use an IAM policy to control domain access for this action. The name of the domain being registered is available as the resource of this action.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn register_domain(&self, input: RegisterDomainInput) -> RusotoFuture<(), RegisterDomainError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.RegisterDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_domain(input)
     }
-
-    /// <p>Registers a new <i>workflow type</i> and its configuration settings in the specified domain.</p> <p>The retention period for the workflow history is set by the <a>RegisterDomain</a> action.</p> <important> <p>If the type already exists, then a <code>TypeAlreadyExists</code> fault is returned. You cannot change the configuration settings of a workflow type once it is registered and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn register_workflow_type(
         &self,
         input: RegisterWorkflowTypeInput,
     ) -> RusotoFuture<(), RegisterWorkflowTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.RegisterWorkflowType");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RegisterWorkflowTypeError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).register_workflow_type(input)
     }
-
-    /// <p>Records a <code>WorkflowExecutionCancelRequested</code> event in the currently running workflow execution identified by the given domain, workflowId, and runId. This logically requests the cancellation of the workflow execution as a whole. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> <note> <p>If the runId isn't specified, the <code>WorkflowExecutionCancelRequested</code> event is recorded in the history of the current open workflow execution with the specified workflowId in the domain.</p> </note> <note> <p>Because this action allows the workflow to properly clean up and gracefully close, it should be used instead of <a>TerminateWorkflowExecution</a> when possible.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn request_cancel_workflow_execution(
         &self,
         input: RequestCancelWorkflowExecutionInput,
     ) -> RusotoFuture<(), RequestCancelWorkflowExecutionError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.RequestCancelWorkflowExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RequestCancelWorkflowExecutionError::from_response(response))
-                }))
-            }
-        })
+        (**self).request_cancel_workflow_execution(input)
     }
-
-    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> was successfully canceled. Additional <code>details</code> can be provided using the <code>details</code> argument.</p> <p>These <code>details</code> (if provided) appear in the <code>ActivityTaskCanceled</code> event added to the workflow history.</p> <important> <p>Only use this operation if the <code>canceled</code> flag of a <a>RecordActivityTaskHeartbeat</a> request returns <code>true</code> and if the activity can be safely undone or abandoned.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, RespondActivityTaskCanceled, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn respond_activity_task_canceled(
         &self,
         input: RespondActivityTaskCanceledInput,
     ) -> RusotoFuture<(), RespondActivityTaskCanceledError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.RespondActivityTaskCanceled",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RespondActivityTaskCanceledError::from_response(response))
-                }))
-            }
-        })
+        (**self).respond_activity_task_canceled(input)
     }
-
-    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> completed successfully with a <code>result</code> (if provided). The <code>result</code> appears in the <code>ActivityTaskCompleted</code> event in the workflow history.</p> <important> <p>If the requested task doesn't complete successfully, use <a>RespondActivityTaskFailed</a> instead. If the worker finds that the task is canceled through the <code>canceled</code> flag returned by <a>RecordActivityTaskHeartbeat</a>, it should cancel the task, clean up and then call <a>RespondActivityTaskCanceled</a>.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to RespondActivityTaskCompleted, <a>RespondActivityTaskCanceled</a>, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn respond_activity_task_completed(
         &self,
         input: RespondActivityTaskCompletedInput,
     ) -> RusotoFuture<(), RespondActivityTaskCompletedError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.RespondActivityTaskCompleted",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RespondActivityTaskCompletedError::from_response(response))
-                }))
-            }
+        (**self).respond_activity_task_completed(input)
+    }
+    fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskFailedError> {
+        (**self).respond_activity_task_failed(input)
+    }
+    fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondDecisionTaskCompletedError> {
+        (**self).respond_decision_task_completed(input)
+    }
+    fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> RusotoFuture<(), SignalWorkflowExecutionError> {
+        (**self).signal_workflow_execution(input)
+    }
+    fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> RusotoFuture<Run, StartWorkflowExecutionError> {
+        (**self).start_workflow_execution(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> RusotoFuture<(), TerminateWorkflowExecutionError> {
+        (**self).terminate_workflow_execution(input)
+    }
+    fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), UndeprecateActivityTypeError> {
+        (**self).undeprecate_activity_type(input)
+    }
+    fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> RusotoFuture<(), UndeprecateDomainError> {
+        (**self).undeprecate_domain(input)
+    }
+    fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), UndeprecateWorkflowTypeError> {
+        (**self).undeprecate_workflow_type(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: Swf + ?Sized> Swf for Box<T> {
+    fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountClosedWorkflowExecutionsError> {
+        (**self).count_closed_workflow_executions(input)
+    }
+    fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountOpenWorkflowExecutionsError> {
+        (**self).count_open_workflow_executions(input)
+    }
+    fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingActivityTasksError> {
+        (**self).count_pending_activity_tasks(input)
+    }
+    fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingDecisionTasksError> {
+        (**self).count_pending_decision_tasks(input)
+    }
+    fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), DeprecateActivityTypeError> {
+        (**self).deprecate_activity_type(input)
+    }
+    fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> RusotoFuture<(), DeprecateDomainError> {
+        (**self).deprecate_domain(input)
+    }
+    fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), DeprecateWorkflowTypeError> {
+        (**self).deprecate_workflow_type(input)
+    }
+    fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> RusotoFuture<ActivityTypeDetail, DescribeActivityTypeError> {
+        (**self).describe_activity_type(input)
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> RusotoFuture<DomainDetail, DescribeDomainError> {
+        (**self).describe_domain(input)
+    }
+    fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> RusotoFuture<WorkflowExecutionDetail, DescribeWorkflowExecutionError> {
+        (**self).describe_workflow_execution(input)
+    }
+    fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> RusotoFuture<WorkflowTypeDetail, DescribeWorkflowTypeError> {
+        (**self).describe_workflow_type(input)
+    }
+    fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> RusotoFuture<History, GetWorkflowExecutionHistoryError> {
+        (**self).get_workflow_execution_history(input)
+    }
+    fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> RusotoFuture<ActivityTypeInfos, ListActivityTypesError> {
+        (**self).list_activity_types(input)
+    }
+    fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListClosedWorkflowExecutionsError> {
+        (**self).list_closed_workflow_executions(input)
+    }
+    fn list_domains(&self, input: ListDomainsInput) -> RusotoFuture<DomainInfos, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListOpenWorkflowExecutionsError> {
+        (**self).list_open_workflow_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> RusotoFuture<WorkflowTypeInfos, ListWorkflowTypesError> {
+        (**self).list_workflow_types(input)
+    }
+    fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> RusotoFuture<ActivityTask, PollForActivityTaskError> {
+        (**self).poll_for_activity_task(input)
+    }
+    fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> RusotoFuture<DecisionTask, PollForDecisionTaskError> {
+        (**self).poll_for_decision_task(input)
+    }
+    fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> RusotoFuture<ActivityTaskStatus, RecordActivityTaskHeartbeatError> {
+        (**self).record_activity_task_heartbeat(input)
+    }
+    fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> RusotoFuture<(), RegisterActivityTypeError> {
+        (**self).register_activity_type(input)
+    }
+    fn register_domain(&self, input: RegisterDomainInput) -> RusotoFuture<(), RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> RusotoFuture<(), RegisterWorkflowTypeError> {
+        (**self).register_workflow_type(input)
+    }
+    fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> RusotoFuture<(), RequestCancelWorkflowExecutionError> {
+        (**self).request_cancel_workflow_execution(input)
+    }
+    fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCanceledError> {
+        (**self).respond_activity_task_canceled(input)
+    }
+    fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCompletedError> {
+        (**self).respond_activity_task_completed(input)
+    }
+    fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskFailedError> {
+        (**self).respond_activity_task_failed(input)
+    }
+    fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondDecisionTaskCompletedError> {
+        (**self).respond_decision_task_completed(input)
+    }
+    fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> RusotoFuture<(), SignalWorkflowExecutionError> {
+        (**self).signal_workflow_execution(input)
+    }
+    fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> RusotoFuture<Run, StartWorkflowExecutionError> {
+        (**self).start_workflow_execution(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> RusotoFuture<(), TerminateWorkflowExecutionError> {
+        (**self).terminate_workflow_execution(input)
+    }
+    fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), UndeprecateActivityTypeError> {
+        (**self).undeprecate_activity_type(input)
+    }
+    fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> RusotoFuture<(), UndeprecateDomainError> {
+        (**self).undeprecate_domain(input)
+    }
+    fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), UndeprecateWorkflowTypeError> {
+        (**self).undeprecate_workflow_type(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+impl<T: Swf + ?Sized> Swf for ::std::sync::Arc<T> {
+    fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountClosedWorkflowExecutionsError> {
+        (**self).count_closed_workflow_executions(input)
+    }
+    fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountOpenWorkflowExecutionsError> {
+        (**self).count_open_workflow_executions(input)
+    }
+    fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingActivityTasksError> {
+        (**self).count_pending_activity_tasks(input)
+    }
+    fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingDecisionTasksError> {
+        (**self).count_pending_decision_tasks(input)
+    }
+    fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), DeprecateActivityTypeError> {
+        (**self).deprecate_activity_type(input)
+    }
+    fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> RusotoFuture<(), DeprecateDomainError> {
+        (**self).deprecate_domain(input)
+    }
+    fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), DeprecateWorkflowTypeError> {
+        (**self).deprecate_workflow_type(input)
+    }
+    fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> RusotoFuture<ActivityTypeDetail, DescribeActivityTypeError> {
+        (**self).describe_activity_type(input)
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> RusotoFuture<DomainDetail, DescribeDomainError> {
+        (**self).describe_domain(input)
+    }
+    fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> RusotoFuture<WorkflowExecutionDetail, DescribeWorkflowExecutionError> {
+        (**self).describe_workflow_execution(input)
+    }
+    fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> RusotoFuture<WorkflowTypeDetail, DescribeWorkflowTypeError> {
+        (**self).describe_workflow_type(input)
+    }
+    fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> RusotoFuture<History, GetWorkflowExecutionHistoryError> {
+        (**self).get_workflow_execution_history(input)
+    }
+    fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> RusotoFuture<ActivityTypeInfos, ListActivityTypesError> {
+        (**self).list_activity_types(input)
+    }
+    fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListClosedWorkflowExecutionsError> {
+        (**self).list_closed_workflow_executions(input)
+    }
+    fn list_domains(&self, input: ListDomainsInput) -> RusotoFuture<DomainInfos, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListOpenWorkflowExecutionsError> {
+        (**self).list_open_workflow_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> RusotoFuture<WorkflowTypeInfos, ListWorkflowTypesError> {
+        (**self).list_workflow_types(input)
+    }
+    fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> RusotoFuture<ActivityTask, PollForActivityTaskError> {
+        (**self).poll_for_activity_task(input)
+    }
+    fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> RusotoFuture<DecisionTask, PollForDecisionTaskError> {
+        (**self).poll_for_decision_task(input)
+    }
+    fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> RusotoFuture<ActivityTaskStatus, RecordActivityTaskHeartbeatError> {
+        (**self).record_activity_task_heartbeat(input)
+    }
+    fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> RusotoFuture<(), RegisterActivityTypeError> {
+        (**self).register_activity_type(input)
+    }
+    fn register_domain(&self, input: RegisterDomainInput) -> RusotoFuture<(), RegisterDomainError> {
+        (**self).register_domain(input)
+    }
+    fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> RusotoFuture<(), RegisterWorkflowTypeError> {
+        (**self).register_workflow_type(input)
+    }
+    fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> RusotoFuture<(), RequestCancelWorkflowExecutionError> {
+        (**self).request_cancel_workflow_execution(input)
+    }
+    fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCanceledError> {
+        (**self).respond_activity_task_canceled(input)
+    }
+    fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCompletedError> {
+        (**self).respond_activity_task_completed(input)
+    }
+    fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskFailedError> {
+        (**self).respond_activity_task_failed(input)
+    }
+    fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondDecisionTaskCompletedError> {
+        (**self).respond_decision_task_completed(input)
+    }
+    fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> RusotoFuture<(), SignalWorkflowExecutionError> {
+        (**self).signal_workflow_execution(input)
+    }
+    fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> RusotoFuture<Run, StartWorkflowExecutionError> {
+        (**self).start_workflow_execution(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> RusotoFuture<(), TerminateWorkflowExecutionError> {
+        (**self).terminate_workflow_execution(input)
+    }
+    fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), UndeprecateActivityTypeError> {
+        (**self).undeprecate_activity_type(input)
+    }
+    fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> RusotoFuture<(), UndeprecateDomainError> {
+        (**self).undeprecate_domain(input)
+    }
+    fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), UndeprecateWorkflowTypeError> {
+        (**self).undeprecate_workflow_type(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// A client for the Amazon SWF API.
+#[derive(Clone)]
+pub struct SwfClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl SwfClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SwfClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SwfClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> SwfClient {
+        SwfClient { client, region }
+    }
+}
+
+impl Swf for SwfClient {
+    /// <p>Returns the number of closed workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountClosedWorkflowExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountClosedWorkflowExecutions");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.CountClosedWorkflowExecutions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowExecutionCount, _>(
+                    response,
+                    "CountClosedWorkflowExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CountClosedWorkflowExecutionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the number of open workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountOpenWorkflowExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountOpenWorkflowExecutions");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.CountOpenWorkflowExecutions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowExecutionCount, _>(
+                    response,
+                    "CountOpenWorkflowExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CountOpenWorkflowExecutionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the estimated number of activity tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no activity task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingActivityTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountPendingActivityTasks");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.CountPendingActivityTasks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PendingTaskCount, _>(
+                    response,
+                    "CountPendingActivityTasks",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CountPendingActivityTasksError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the estimated number of decision tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no decision task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingDecisionTasksError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("CountPendingDecisionTasks");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.CountPendingDecisionTasks",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PendingTaskCount, _>(
+                    response,
+                    "CountPendingDecisionTasks",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CountPendingDecisionTasksError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deprecates the specified <i>activity type</i>. After an activity type has been deprecated, you cannot create new tasks of that activity type. Tasks of this type that were scheduled before the type was deprecated continue to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), DeprecateActivityTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateActivityType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.DeprecateActivityType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeprecateActivityTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deprecates the specified domain. After a domain has been deprecated it cannot be used to create new workflow executions or register new types. However, you can still use visibility actions on this domain. Deprecating a domain also deprecates all activity and workflow types registered in the domain. Executions that were started before the domain was deprecated continues to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> RusotoFuture<(), DeprecateDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateDomain");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.DeprecateDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeprecateDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deprecates the specified <i>workflow type</i>. After a workflow type has been deprecated, you cannot create new executions of that type. Executions that were started before the type was deprecated continues to run. A deprecated workflow type may still be used when calling visibility actions.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), DeprecateWorkflowTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DeprecateWorkflowType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.DeprecateWorkflowType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeprecateWorkflowTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified activity type. This includes configuration settings provided when the type was registered and other general information about the type.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> RusotoFuture<ActivityTypeDetail, DescribeActivityTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeActivityType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.DescribeActivityType");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ActivityTypeDetail, _>(
+                    response,
+                    "DescribeActivityType",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeActivityTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified domain, including description and status.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> RusotoFuture<DomainDetail, DescribeDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeDomain");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.DescribeDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DomainDetail, _>(response, "DescribeDomain")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified workflow execution including its type and some statistics.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> RusotoFuture<WorkflowExecutionDetail, DescribeWorkflowExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeWorkflowExecution");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.DescribeWorkflowExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowExecutionDetail, _>(
+                    response,
+                    "DescribeWorkflowExecution",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeWorkflowExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about the specified <i>workflow type</i>. This includes configuration settings specified when the type was registered and other information such as creation date, current status, etc.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> RusotoFuture<WorkflowTypeDetail, DescribeWorkflowTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("DescribeWorkflowType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.DescribeWorkflowType");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowTypeDetail, _>(
+                    response,
+                    "DescribeWorkflowType",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeWorkflowTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the history of the specified workflow execution. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> RusotoFuture<History, GetWorkflowExecutionHistoryError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("GetWorkflowExecutionHistory");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.GetWorkflowExecutionHistory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<History, _>(
+                    response,
+                    "GetWorkflowExecutionHistory",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetWorkflowExecutionHistoryError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns information about all activities registered in the specified domain that match the specified name and registration status. The result includes information like creation date, current status of the activity, etc. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> RusotoFuture<ActivityTypeInfos, ListActivityTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListActivityTypes");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.ListActivityTypes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ActivityTypeInfos, _>(
+                    response,
+                    "ListActivityTypes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListActivityTypesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of closed workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListClosedWorkflowExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListClosedWorkflowExecutions");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.ListClosedWorkflowExecutions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowExecutionInfos, _>(
+                    response,
+                    "ListClosedWorkflowExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListClosedWorkflowExecutionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the list of domains registered in the account. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains. The element must be set to <code>arn:aws:swf::AccountID:domain/*</code>, where <i>AccountID</i> is the account ID, with no dashes.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn list_domains(&self, input: ListDomainsInput) -> RusotoFuture<DomainInfos, ListDomainsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListDomains");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.ListDomains");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DomainInfos, _>(response, "ListDomains")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDomainsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns a list of open workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListOpenWorkflowExecutionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListOpenWorkflowExecutions");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.ListOpenWorkflowExecutions",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowExecutionInfos, _>(
+                    response,
+                    "ListOpenWorkflowExecutions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListOpenWorkflowExecutionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>List tags for a given domain.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceOutput, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns information about workflow types in the specified domain. The results may be split into multiple pages that can be retrieved by making the call repeatedly.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> RusotoFuture<WorkflowTypeInfos, ListWorkflowTypesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("ListWorkflowTypes");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.ListWorkflowTypes");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<WorkflowTypeInfos, _>(
+                    response,
+                    "ListWorkflowTypes",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWorkflowTypesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Used by workers to get an <a>ActivityTask</a> from the specified activity <code>taskList</code>. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available. The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns an empty result. An empty result, in this context, means that an ActivityTask is returned, but that the value of taskToken is an empty string. If a task is returned, the worker should use its type to identify and process it correctly.</p> <important> <p>Workers should set their client side socket timeout to at least 70 seconds (10 seconds higher than the maximum time service may hold the poll request).</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> RusotoFuture<ActivityTask, PollForActivityTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("PollForActivityTask");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.PollForActivityTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ActivityTask, _>(
+                    response,
+                    "PollForActivityTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PollForActivityTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Used by deciders to get a <a>DecisionTask</a> from the specified decision <code>taskList</code>. A decision task may be returned for any open workflow execution that is using the specified task list. The task includes a paginated view of the history of the workflow execution. The decider should use the workflow type and the history to determine how to properly handle the task.</p> <p>This action initiates a long poll, where the service holds the HTTP connection open and responds as soon a task becomes available. If no decision task is available in the specified task list before the timeout of 60 seconds expires, an empty result is returned. An empty result, in this context, means that a DecisionTask is returned, but that the value of taskToken is an empty string.</p> <important> <p>Deciders should set their client side socket timeout to at least 70 seconds (10 seconds higher than the timeout).</p> </important> <important> <p>Because the number of workflow history events for a single workflow execution might be very large, the result returned might be split up across a number of pages. To retrieve subsequent pages, make additional calls to <code>PollForDecisionTask</code> using the <code>nextPageToken</code> returned by the initial call. Note that you do <i>not</i> call <code>GetWorkflowExecutionHistory</code> with this <code>nextPageToken</code>. Instead, call <code>PollForDecisionTask</code> again.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> RusotoFuture<DecisionTask, PollForDecisionTaskError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("PollForDecisionTask");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.PollForDecisionTask");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DecisionTask, _>(
+                    response,
+                    "PollForDecisionTask",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PollForDecisionTaskError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Used by activity workers to report to the service that the <a>ActivityTask</a> represented by the specified <code>taskToken</code> is still making progress. The worker can also specify details of the progress, for example percent complete, using the <code>details</code> parameter. This action can also be used by the worker as a mechanism to check if cancellation is being requested for the activity task. If a cancellation is being attempted for the specified task, then the boolean <code>cancelRequested</code> flag returned by the service is set to <code>true</code>.</p> <p>This action resets the <code>taskHeartbeatTimeout</code> clock. The <code>taskHeartbeatTimeout</code> is specified in <a>RegisterActivityType</a>.</p> <p>This action doesn't in itself create an event in the workflow execution history. However, if the task times out, the workflow execution history contains a <code>ActivityTaskTimedOut</code> event that contains the information from the last heartbeat generated by the activity worker.</p> <note> <p>The <code>taskStartToCloseTimeout</code> of an activity type is the maximum duration of an activity task, regardless of the number of <a>RecordActivityTaskHeartbeat</a> requests received. The <code>taskStartToCloseTimeout</code> is also specified in <a>RegisterActivityType</a>.</p> </note> <note> <p>This operation is only useful for long-lived activities to report liveliness of the task and to determine if a cancellation is being attempted.</p> </note> <important> <p>If the <code>cancelRequested</code> flag returns <code>true</code>, a cancellation is being attempted. If the worker can cancel the activity, it should respond with <a>RespondActivityTaskCanceled</a>. Otherwise, it should ignore the cancellation request.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> RusotoFuture<ActivityTaskStatus, RecordActivityTaskHeartbeatError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RecordActivityTaskHeartbeat");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.RecordActivityTaskHeartbeat",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ActivityTaskStatus, _>(
+                    response,
+                    "RecordActivityTaskHeartbeat",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RecordActivityTaskHeartbeatError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Registers a new <i>activity type</i> along with its configuration settings in the specified domain.</p> <important> <p>A <code>TypeAlreadyExists</code> fault is returned if the type already exists in the domain. You cannot change any configuration settings of the type after its registration, and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> RusotoFuture<(), RegisterActivityTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterActivityType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.RegisterActivityType");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RegisterActivityTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers a new domain.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>You cannot use an IAM policy to control domain access for this action. The name of the domain being registered is available as the resource of this action.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn register_domain(&self, input: RegisterDomainInput) -> RusotoFuture<(), RegisterDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterDomain");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.RegisterDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers a new <i>workflow type</i> and its configuration settings in the specified domain.</p> <p>The retention period for the workflow history is set by the <a>RegisterDomain</a> action.</p> <important> <p>If the type already exists, then a <code>TypeAlreadyExists</code> fault is returned. You cannot change the configuration settings of a workflow type once it is registered and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> RusotoFuture<(), RegisterWorkflowTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RegisterWorkflowType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.RegisterWorkflowType");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RegisterWorkflowTypeError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Records a <code>WorkflowExecutionCancelRequested</code> event in the currently running workflow execution identified by the given domain, workflowId, and runId. This logically requests the cancellation of the workflow execution as a whole. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> <note> <p>If the runId isn't specified, the <code>WorkflowExecutionCancelRequested</code> event is recorded in the history of the current open workflow execution with the specified workflowId in the domain.</p> </note> <note> <p>Because this action allows the workflow to properly clean up and gracefully close, it should be used instead of <a>TerminateWorkflowExecution</a> when possible.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> RusotoFuture<(), RequestCancelWorkflowExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RequestCancelWorkflowExecution");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.RequestCancelWorkflowExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RequestCancelWorkflowExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> was successfully canceled. Additional <code>details</code> can be provided using the <code>details</code> argument.</p> <p>These <code>details</code> (if provided) appear in the <code>ActivityTaskCanceled</code> event added to the workflow history.</p> <important> <p>Only use this operation if the <code>canceled</code> flag of a <a>RecordActivityTaskHeartbeat</a> request returns <code>true</code> and if the activity can be safely undone or abandoned.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, RespondActivityTaskCanceled, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCanceledError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskCanceled");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.RespondActivityTaskCanceled",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RespondActivityTaskCanceledError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> completed successfully with a <code>result</code> (if provided). The <code>result</code> appears in the <code>ActivityTaskCompleted</code> event in the workflow history.</p> <important> <p>If the requested task doesn't complete successfully, use <a>RespondActivityTaskFailed</a> instead. If the worker finds that the task is canceled through the <code>canceled</code> flag returned by <a>RecordActivityTaskHeartbeat</a>, it should cancel the task, clean up and then call <a>RespondActivityTaskCanceled</a>.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to RespondActivityTaskCompleted, <a>RespondActivityTaskCanceled</a>, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCompletedError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskCompleted");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.RespondActivityTaskCompleted",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RespondActivityTaskCompletedError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> has failed with <code>reason</code> (if specified). The <code>reason</code> and <code>details</code> appear in the <code>ActivityTaskFailed</code> event added to the workflow history.</p> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, <a>RespondActivityTaskCanceled</a>, RespondActivityTaskFailed, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskFailedError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondActivityTaskFailed");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.RespondActivityTaskFailed",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RespondActivityTaskFailedError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Used by deciders to tell the service that the <a>DecisionTask</a> identified by the <code>taskToken</code> has successfully completed. The <code>decisions</code> argument specifies the list of decisions made while processing the task.</p> <p>A <code>DecisionTaskCompleted</code> event is added to the workflow history. The <code>executionContext</code> specified is attached to the event in the workflow execution history.</p> <p> <b>Access Control</b> </p> <p>If an IAM policy grants permission to use <code>RespondDecisionTaskCompleted</code>, it can express permissions for the list of decisions in the <code>decisions</code> parameter. Each of the decisions has one or more parameters, much like a regular API call. To allow for policies to be as readable as possible, you can express permissions on decisions as if they were actual API calls, including applying conditions to some parameters. For more information, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondDecisionTaskCompletedError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("RespondDecisionTaskCompleted");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.RespondDecisionTaskCompleted",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RespondDecisionTaskCompletedError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Records a <code>WorkflowExecutionSignaled</code> event in the workflow execution history and creates a decision task for the workflow execution identified by the given domain, workflowId and runId. The event is recorded with the specified user defined signalName and input (if provided).</p> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionSignaled</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>If the specified workflow execution isn't open, this method fails with <code>UnknownResource</code>.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> RusotoFuture<(), SignalWorkflowExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("SignalWorkflowExecution");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.SignalWorkflowExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(SignalWorkflowExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Starts an execution of the workflow type in the specified domain using the provided <code>workflowId</code> and input data.</p> <p>This action returns the newly started workflow execution.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.0</code>: The key is <code>swf:tagList.member.0</code>.</p> </li> <li> <p> <code>tagList.member.1</code>: The key is <code>swf:tagList.member.1</code>.</p> </li> <li> <p> <code>tagList.member.2</code>: The key is <code>swf:tagList.member.2</code>.</p> </li> <li> <p> <code>tagList.member.3</code>: The key is <code>swf:tagList.member.3</code>.</p> </li> <li> <p> <code>tagList.member.4</code>: The key is <code>swf:tagList.member.4</code>.</p> </li> <li> <p> <code>taskList</code>: String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> RusotoFuture<Run, StartWorkflowExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("StartWorkflowExecution");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.StartWorkflowExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<Run, _>(response, "StartWorkflowExecution")
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(StartWorkflowExecutionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Add a tag to a Amazon SWF domain.</p> <note> <p>Amazon SWF supports a maximum of 50 tags per resource.</p> </note></p>
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Records a <code>WorkflowExecutionTerminated</code> event and forces closure of the workflow execution identified by the given domain, runId, and workflowId. The child policy, registered with the workflow type or specified when starting this execution, is applied to any open child workflow executions of this workflow execution.</p> <important> <p>If the identified workflow execution was in progress, it is terminated immediately.</p> </important> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionTerminated</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>You should consider using <a>RequestCancelWorkflowExecution</a> action instead because it allows the workflow to gracefully close while <a>TerminateWorkflowExecution</a> doesn't.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> RusotoFuture<(), TerminateWorkflowExecutionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("TerminateWorkflowExecution");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.TerminateWorkflowExecution",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(TerminateWorkflowExecutionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Undeprecates a previously deprecated <i>activity type</i>. After an activity type has been undeprecated, you can create new tasks of that activity type.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), UndeprecateActivityTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateActivityType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.UndeprecateActivityType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UndeprecateActivityTypeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Undeprecates a previously deprecated domain. After a domain has been undeprecated it can be used to create new workflow executions or register new types.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> RusotoFuture<(), UndeprecateDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateDomain");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.UndeprecateDomain");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UndeprecateDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Undeprecates a previously deprecated <i>workflow type</i>. After a workflow type has been undeprecated, you can create new executions of that type. </p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), UndeprecateWorkflowTypeError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UndeprecateWorkflowType");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "SimpleWorkflowService.UndeprecateWorkflowType",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UndeprecateWorkflowTypeError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Remove a tag from a Amazon SWF domain.</p>
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "SimpleWorkflowService.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
         })
     }
-
-    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> has failed with <code>reason</code> (if specified). The <code>reason</code> and <code>details</code> appear in the <code>ActivityTaskFailed</code> event added to the workflow history.</p> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, <a>RespondActivityTaskCanceled</a>, RespondActivityTaskFailed, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+}
+
+/// A mock of the Amazon SWF API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockSwf {
+    count_closed_workflow_executions: Option<
+        Box<
+            dyn Fn(
+                    CountClosedWorkflowExecutionsInput,
+                ) -> ::std::result::Result<
+                    WorkflowExecutionCount,
+                    RusotoError<CountClosedWorkflowExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    count_open_workflow_executions: Option<
+        Box<
+            dyn Fn(
+                    CountOpenWorkflowExecutionsInput,
+                ) -> ::std::result::Result<
+                    WorkflowExecutionCount,
+                    RusotoError<CountOpenWorkflowExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    count_pending_activity_tasks: Option<
+        Box<
+            dyn Fn(
+                    CountPendingActivityTasksInput,
+                ) -> ::std::result::Result<
+                    PendingTaskCount,
+                    RusotoError<CountPendingActivityTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    count_pending_decision_tasks: Option<
+        Box<
+            dyn Fn(
+                    CountPendingDecisionTasksInput,
+                ) -> ::std::result::Result<
+                    PendingTaskCount,
+                    RusotoError<CountPendingDecisionTasksError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    deprecate_activity_type: Option<
+        Box<
+            dyn Fn(
+                    DeprecateActivityTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeprecateActivityTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deprecate_domain: Option<
+        Box<
+            dyn Fn(
+                    DeprecateDomainInput,
+                ) -> ::std::result::Result<(), RusotoError<DeprecateDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deprecate_workflow_type: Option<
+        Box<
+            dyn Fn(
+                    DeprecateWorkflowTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeprecateWorkflowTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_activity_type: Option<
+        Box<
+            dyn Fn(
+                    DescribeActivityTypeInput,
+                ) -> ::std::result::Result<
+                    ActivityTypeDetail,
+                    RusotoError<DescribeActivityTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_domain: Option<
+        Box<
+            dyn Fn(
+                    DescribeDomainInput,
+                )
+                    -> ::std::result::Result<DomainDetail, RusotoError<DescribeDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_workflow_execution: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkflowExecutionInput,
+                ) -> ::std::result::Result<
+                    WorkflowExecutionDetail,
+                    RusotoError<DescribeWorkflowExecutionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_workflow_type: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkflowTypeInput,
+                ) -> ::std::result::Result<
+                    WorkflowTypeDetail,
+                    RusotoError<DescribeWorkflowTypeError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_workflow_execution_history: Option<
+        Box<
+            dyn Fn(
+                    GetWorkflowExecutionHistoryInput,
+                )
+                    -> ::std::result::Result<History, RusotoError<GetWorkflowExecutionHistoryError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_activity_types: Option<
+        Box<
+            dyn Fn(
+                    ListActivityTypesInput,
+                )
+                    -> ::std::result::Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_closed_workflow_executions: Option<
+        Box<
+            dyn Fn(
+                    ListClosedWorkflowExecutionsInput,
+                ) -> ::std::result::Result<
+                    WorkflowExecutionInfos,
+                    RusotoError<ListClosedWorkflowExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_domains: Option<
+        Box<
+            dyn Fn(
+                    ListDomainsInput,
+                )
+                    -> ::std::result::Result<DomainInfos, RusotoError<ListDomainsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_open_workflow_executions: Option<
+        Box<
+            dyn Fn(
+                    ListOpenWorkflowExecutionsInput,
+                ) -> ::std::result::Result<
+                    WorkflowExecutionInfos,
+                    RusotoError<ListOpenWorkflowExecutionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceInput,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceOutput,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_workflow_types: Option<
+        Box<
+            dyn Fn(
+                    ListWorkflowTypesInput,
+                )
+                    -> ::std::result::Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    poll_for_activity_task: Option<
+        Box<
+            dyn Fn(
+                    PollForActivityTaskInput,
+                )
+                    -> ::std::result::Result<ActivityTask, RusotoError<PollForActivityTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    poll_for_decision_task: Option<
+        Box<
+            dyn Fn(
+                    PollForDecisionTaskInput,
+                )
+                    -> ::std::result::Result<DecisionTask, RusotoError<PollForDecisionTaskError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    record_activity_task_heartbeat: Option<
+        Box<
+            dyn Fn(
+                    RecordActivityTaskHeartbeatInput,
+                ) -> ::std::result::Result<
+                    ActivityTaskStatus,
+                    RusotoError<RecordActivityTaskHeartbeatError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_activity_type: Option<
+        Box<
+            dyn Fn(
+                    RegisterActivityTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RegisterActivityTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_domain: Option<
+        Box<
+            dyn Fn(
+                    RegisterDomainInput,
+                ) -> ::std::result::Result<(), RusotoError<RegisterDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    register_workflow_type: Option<
+        Box<
+            dyn Fn(
+                    RegisterWorkflowTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RegisterWorkflowTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    request_cancel_workflow_execution: Option<
+        Box<
+            dyn Fn(
+                    RequestCancelWorkflowExecutionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RequestCancelWorkflowExecutionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    respond_activity_task_canceled: Option<
+        Box<
+            dyn Fn(
+                    RespondActivityTaskCanceledInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RespondActivityTaskCanceledError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    respond_activity_task_completed: Option<
+        Box<
+            dyn Fn(
+                    RespondActivityTaskCompletedInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RespondActivityTaskCompletedError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    respond_activity_task_failed: Option<
+        Box<
+            dyn Fn(
+                    RespondActivityTaskFailedInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RespondActivityTaskFailedError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    respond_decision_task_completed: Option<
+        Box<
+            dyn Fn(
+                    RespondDecisionTaskCompletedInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<RespondDecisionTaskCompletedError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    signal_workflow_execution: Option<
+        Box<
+            dyn Fn(
+                    SignalWorkflowExecutionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<SignalWorkflowExecutionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_workflow_execution: Option<
+        Box<
+            dyn Fn(
+                    StartWorkflowExecutionInput,
+                )
+                    -> ::std::result::Result<Run, RusotoError<StartWorkflowExecutionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceInput) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    terminate_workflow_execution: Option<
+        Box<
+            dyn Fn(
+                    TerminateWorkflowExecutionInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<TerminateWorkflowExecutionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    undeprecate_activity_type: Option<
+        Box<
+            dyn Fn(
+                    UndeprecateActivityTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UndeprecateActivityTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    undeprecate_domain: Option<
+        Box<
+            dyn Fn(
+                    UndeprecateDomainInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UndeprecateDomainError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    undeprecate_workflow_type: Option<
+        Box<
+            dyn Fn(
+                    UndeprecateWorkflowTypeInput,
+                )
+                    -> ::std::result::Result<(), RusotoError<UndeprecateWorkflowTypeError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(UntagResourceInput) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockSwf {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockSwf {
+        Default::default()
+    }
+
+    /// Answers `count_closed_workflow_executions` with what `handler` returns for its input.
+    pub fn with_count_closed_workflow_executions<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                CountClosedWorkflowExecutionsInput,
+            ) -> ::std::result::Result<
+                WorkflowExecutionCount,
+                RusotoError<CountClosedWorkflowExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.count_closed_workflow_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `count_open_workflow_executions` with what `handler` returns for its input.
+    pub fn with_count_open_workflow_executions<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                CountOpenWorkflowExecutionsInput,
+            ) -> ::std::result::Result<
+                WorkflowExecutionCount,
+                RusotoError<CountOpenWorkflowExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.count_open_workflow_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `count_pending_activity_tasks` with what `handler` returns for its input.
+    pub fn with_count_pending_activity_tasks<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                CountPendingActivityTasksInput,
+            ) -> ::std::result::Result<
+                PendingTaskCount,
+                RusotoError<CountPendingActivityTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.count_pending_activity_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `count_pending_decision_tasks` with what `handler` returns for its input.
+    pub fn with_count_pending_decision_tasks<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                CountPendingDecisionTasksInput,
+            ) -> ::std::result::Result<
+                PendingTaskCount,
+                RusotoError<CountPendingDecisionTasksError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.count_pending_decision_tasks = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deprecate_activity_type` with what `handler` returns for its input.
+    pub fn with_deprecate_activity_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                DeprecateActivityTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<DeprecateActivityTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deprecate_activity_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deprecate_domain` with what `handler` returns for its input.
+    pub fn with_deprecate_domain<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(DeprecateDomainInput) -> ::std::result::Result<(), RusotoError<DeprecateDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deprecate_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deprecate_workflow_type` with what `handler` returns for its input.
+    pub fn with_deprecate_workflow_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                DeprecateWorkflowTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<DeprecateWorkflowTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deprecate_workflow_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_activity_type` with what `handler` returns for its input.
+    pub fn with_describe_activity_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                DescribeActivityTypeInput,
+            )
+                -> ::std::result::Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_activity_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_domain` with what `handler` returns for its input.
+    pub fn with_describe_domain<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                DescribeDomainInput,
+            ) -> ::std::result::Result<DomainDetail, RusotoError<DescribeDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workflow_execution` with what `handler` returns for its input.
+    pub fn with_describe_workflow_execution<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                DescribeWorkflowExecutionInput,
+            ) -> ::std::result::Result<
+                WorkflowExecutionDetail,
+                RusotoError<DescribeWorkflowExecutionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workflow_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workflow_type` with what `handler` returns for its input.
+    pub fn with_describe_workflow_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                DescribeWorkflowTypeInput,
+            )
+                -> ::std::result::Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workflow_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_workflow_execution_history` with what `handler` returns for its input.
+    pub fn with_get_workflow_execution_history<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                GetWorkflowExecutionHistoryInput,
+            )
+                -> ::std::result::Result<History, RusotoError<GetWorkflowExecutionHistoryError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_workflow_execution_history = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_activity_types` with what `handler` returns for its input.
+    pub fn with_list_activity_types<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                ListActivityTypesInput,
+            )
+                -> ::std::result::Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_activity_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_closed_workflow_executions` with what `handler` returns for its input.
+    pub fn with_list_closed_workflow_executions<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                ListClosedWorkflowExecutionsInput,
+            ) -> ::std::result::Result<
+                WorkflowExecutionInfos,
+                RusotoError<ListClosedWorkflowExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_closed_workflow_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_domains` with what `handler` returns for its input.
+    pub fn with_list_domains<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                ListDomainsInput,
+            ) -> ::std::result::Result<DomainInfos, RusotoError<ListDomainsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_domains = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_open_workflow_executions` with what `handler` returns for its input.
+    pub fn with_list_open_workflow_executions<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                ListOpenWorkflowExecutionsInput,
+            ) -> ::std::result::Result<
+                WorkflowExecutionInfos,
+                RusotoError<ListOpenWorkflowExecutionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_open_workflow_executions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                ListTagsForResourceInput,
+            ) -> ::std::result::Result<
+                ListTagsForResourceOutput,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_workflow_types` with what `handler` returns for its input.
+    pub fn with_list_workflow_types<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                ListWorkflowTypesInput,
+            )
+                -> ::std::result::Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_workflow_types = Some(Box::new(handler));
+        self
+    }
+    /// Answers `poll_for_activity_task` with what `handler` returns for its input.
+    pub fn with_poll_for_activity_task<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                PollForActivityTaskInput,
+            )
+                -> ::std::result::Result<ActivityTask, RusotoError<PollForActivityTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.poll_for_activity_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `poll_for_decision_task` with what `handler` returns for its input.
+    pub fn with_poll_for_decision_task<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                PollForDecisionTaskInput,
+            )
+                -> ::std::result::Result<DecisionTask, RusotoError<PollForDecisionTaskError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.poll_for_decision_task = Some(Box::new(handler));
+        self
+    }
+    /// Answers `record_activity_task_heartbeat` with what `handler` returns for its input.
+    pub fn with_record_activity_task_heartbeat<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RecordActivityTaskHeartbeatInput,
+            ) -> ::std::result::Result<
+                ActivityTaskStatus,
+                RusotoError<RecordActivityTaskHeartbeatError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.record_activity_task_heartbeat = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_activity_type` with what `handler` returns for its input.
+    pub fn with_register_activity_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RegisterActivityTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<RegisterActivityTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_activity_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_domain` with what `handler` returns for its input.
+    pub fn with_register_domain<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(RegisterDomainInput) -> ::std::result::Result<(), RusotoError<RegisterDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_workflow_type` with what `handler` returns for its input.
+    pub fn with_register_workflow_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RegisterWorkflowTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<RegisterWorkflowTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.register_workflow_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `request_cancel_workflow_execution` with what `handler` returns for its input.
+    pub fn with_request_cancel_workflow_execution<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RequestCancelWorkflowExecutionInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RequestCancelWorkflowExecutionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.request_cancel_workflow_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `respond_activity_task_canceled` with what `handler` returns for its input.
+    pub fn with_respond_activity_task_canceled<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RespondActivityTaskCanceledInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RespondActivityTaskCanceledError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.respond_activity_task_canceled = Some(Box::new(handler));
+        self
+    }
+    /// Answers `respond_activity_task_completed` with what `handler` returns for its input.
+    pub fn with_respond_activity_task_completed<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RespondActivityTaskCompletedInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RespondActivityTaskCompletedError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.respond_activity_task_completed = Some(Box::new(handler));
+        self
+    }
+    /// Answers `respond_activity_task_failed` with what `handler` returns for its input.
+    pub fn with_respond_activity_task_failed<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RespondActivityTaskFailedInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RespondActivityTaskFailedError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.respond_activity_task_failed = Some(Box::new(handler));
+        self
+    }
+    /// Answers `respond_decision_task_completed` with what `handler` returns for its input.
+    pub fn with_respond_decision_task_completed<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                RespondDecisionTaskCompletedInput,
+            )
+                -> ::std::result::Result<(), RusotoError<RespondDecisionTaskCompletedError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.respond_decision_task_completed = Some(Box::new(handler));
+        self
+    }
+    /// Answers `signal_workflow_execution` with what `handler` returns for its input.
+    pub fn with_signal_workflow_execution<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                SignalWorkflowExecutionInput,
+            ) -> ::std::result::Result<(), RusotoError<SignalWorkflowExecutionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.signal_workflow_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_workflow_execution` with what `handler` returns for its input.
+    pub fn with_start_workflow_execution<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                StartWorkflowExecutionInput,
+            ) -> ::std::result::Result<Run, RusotoError<StartWorkflowExecutionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_workflow_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(TagResourceInput) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_workflow_execution` with what `handler` returns for its input.
+    pub fn with_terminate_workflow_execution<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                TerminateWorkflowExecutionInput,
+            )
+                -> ::std::result::Result<(), RusotoError<TerminateWorkflowExecutionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_workflow_execution = Some(Box::new(handler));
+        self
+    }
+    /// Answers `undeprecate_activity_type` with what `handler` returns for its input.
+    pub fn with_undeprecate_activity_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                UndeprecateActivityTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<UndeprecateActivityTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.undeprecate_activity_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `undeprecate_domain` with what `handler` returns for its input.
+    pub fn with_undeprecate_domain<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                UndeprecateDomainInput,
+            ) -> ::std::result::Result<(), RusotoError<UndeprecateDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.undeprecate_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `undeprecate_workflow_type` with what `handler` returns for its input.
+    pub fn with_undeprecate_workflow_type<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(
+                UndeprecateWorkflowTypeInput,
+            ) -> ::std::result::Result<(), RusotoError<UndeprecateWorkflowTypeError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.undeprecate_workflow_type = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockSwf
+    where
+        F: Fn(UntagResourceInput) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Swf for MockSwf {
+    fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountClosedWorkflowExecutionsError> {
+        match self.count_closed_workflow_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for count_closed_workflow_executions"),
+        }
+    }
+    fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionCount, CountOpenWorkflowExecutionsError> {
+        match self.count_open_workflow_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for count_open_workflow_executions"),
+        }
+    }
+    fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingActivityTasksError> {
+        match self.count_pending_activity_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for count_pending_activity_tasks"),
+        }
+    }
+    fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> RusotoFuture<PendingTaskCount, CountPendingDecisionTasksError> {
+        match self.count_pending_decision_tasks {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for count_pending_decision_tasks"),
+        }
+    }
+    fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> RusotoFuture<(), DeprecateActivityTypeError> {
+        match self.deprecate_activity_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for deprecate_activity_type"),
+        }
+    }
+    fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> RusotoFuture<(), DeprecateDomainError> {
+        match self.deprecate_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for deprecate_domain"),
+        }
+    }
+    fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> RusotoFuture<(), DeprecateWorkflowTypeError> {
+        match self.deprecate_workflow_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for deprecate_workflow_type"),
+        }
+    }
+    fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> RusotoFuture<ActivityTypeDetail, DescribeActivityTypeError> {
+        match self.describe_activity_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for describe_activity_type"),
+        }
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> RusotoFuture<DomainDetail, DescribeDomainError> {
+        match self.describe_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for describe_domain"),
+        }
+    }
+    fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> RusotoFuture<WorkflowExecutionDetail, DescribeWorkflowExecutionError> {
+        match self.describe_workflow_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for describe_workflow_execution"),
+        }
+    }
+    fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> RusotoFuture<WorkflowTypeDetail, DescribeWorkflowTypeError> {
+        match self.describe_workflow_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for describe_workflow_type"),
+        }
+    }
+    fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> RusotoFuture<History, GetWorkflowExecutionHistoryError> {
+        match self.get_workflow_execution_history {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for get_workflow_execution_history"),
+        }
+    }
+    fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> RusotoFuture<ActivityTypeInfos, ListActivityTypesError> {
+        match self.list_activity_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for list_activity_types"),
+        }
+    }
+    fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListClosedWorkflowExecutionsError> {
+        match self.list_closed_workflow_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for list_closed_workflow_executions"),
+        }
+    }
+    fn list_domains(&self, input: ListDomainsInput) -> RusotoFuture<DomainInfos, ListDomainsError> {
+        match self.list_domains {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for list_domains"),
+        }
+    }
+    fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> RusotoFuture<WorkflowExecutionInfos, ListOpenWorkflowExecutionsError> {
+        match self.list_open_workflow_executions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for list_open_workflow_executions"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> RusotoFuture<WorkflowTypeInfos, ListWorkflowTypesError> {
+        match self.list_workflow_types {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for list_workflow_types"),
+        }
+    }
+    fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> RusotoFuture<ActivityTask, PollForActivityTaskError> {
+        match self.poll_for_activity_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for poll_for_activity_task"),
+        }
+    }
+    fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> RusotoFuture<DecisionTask, PollForDecisionTaskError> {
+        match self.poll_for_decision_task {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for poll_for_decision_task"),
+        }
+    }
+    fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> RusotoFuture<ActivityTaskStatus, RecordActivityTaskHeartbeatError> {
+        match self.record_activity_task_heartbeat {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for record_activity_task_heartbeat"),
+        }
+    }
+    fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> RusotoFuture<(), RegisterActivityTypeError> {
+        match self.register_activity_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for register_activity_type"),
+        }
+    }
+    fn register_domain(&self, input: RegisterDomainInput) -> RusotoFuture<(), RegisterDomainError> {
+        match self.register_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for register_domain"),
+        }
+    }
+    fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> RusotoFuture<(), RegisterWorkflowTypeError> {
+        match self.register_workflow_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for register_workflow_type"),
+        }
+    }
+    fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> RusotoFuture<(), RequestCancelWorkflowExecutionError> {
+        match self.request_cancel_workflow_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for request_cancel_workflow_execution"),
+        }
+    }
+    fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCanceledError> {
+        match self.respond_activity_task_canceled {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for respond_activity_task_canceled"),
+        }
+    }
+    fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> RusotoFuture<(), RespondActivityTaskCompletedError> {
+        match self.respond_activity_task_completed {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for respond_activity_task_completed"),
+        }
+    }
     fn respond_activity_task_failed(
         &self,
         input: RespondActivityTaskFailedInput,
     ) -> RusotoFuture<(), RespondActivityTaskFailedError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.RespondActivityTaskFailed",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RespondActivityTaskFailedError::from_response(response))
-                }))
-            }
-        })
+        match self.respond_activity_task_failed {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for respond_activity_task_failed"),
+        }
     }
-
-    /// <p>Used by deciders to tell the service that the <a>DecisionTask</a> identified by the <code>taskToken</code> has successfully completed. The <code>decisions</code> argument specifies the list of decisions made while processing the task.</p> <p>A <code>DecisionTaskCompleted</code> event is added to the workflow history. The <code>executionContext</code> specified is attached to the event in the workflow execution history.</p> <p> <b>Access Control</b> </p> <p>If an IAM policy grants permission to use <code>RespondDecisionTaskCompleted</code>, it can express permissions for the list of decisions in the <code>decisions</code> parameter. Each of the decisions has one or more parameters, much like a regular API call. To allow for policies to be as readable as possible, you can express permissions on decisions as if they were actual API calls, including applying conditions to some parameters. For more information, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn respond_decision_task_completed(
         &self,
         input: RespondDecisionTaskCompletedInput,
     ) -> RusotoFuture<(), RespondDecisionTaskCompletedError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.RespondDecisionTaskCompleted",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RespondDecisionTaskCompletedError::from_response(response))
-                }))
-            }
-        })
+        match self.respond_decision_task_completed {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for respond_decision_task_completed"),
+        }
     }
-
-    /// <p>Records a <code>WorkflowExecutionSignaled</code> event in the workflow execution history and creates a decision task for the workflow execution identified by the given domain, workflowId and runId. The event is recorded with the specified user defined signalName and input (if provided).</p> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionSignaled</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>If the specified workflow execution isn't open, this method fails with <code>UnknownResource</code>.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn signal_workflow_execution(
         &self,
         input: SignalWorkflowExecutionInput,
     ) -> RusotoFuture<(), SignalWorkflowExecutionError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.SignalWorkflowExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(SignalWorkflowExecutionError::from_response(response))
-                }))
-            }
-        })
+        match self.signal_workflow_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for signal_workflow_execution"),
+        }
     }
-
-    /// <p>Starts an execution of the workflow type in the specified domain using the provided <code>workflowId</code> and input data.</p> <p>This action returns the newly started workflow execution.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.0</code>: The key is <code>swf:tagList.member.0</code>.</p> </li> <li> <p> <code>tagList.member.1</code>: The key is <code>swf:tagList.member.1</code>.</p> </li> <li> <p> <code>tagList.member.2</code>: The key is <code>swf:tagList.member.2</code>.</p> </li> <li> <p> <code>tagList.member.3</code>: The key is <code>swf:tagList.member.3</code>.</p> </li> <li> <p> <code>tagList.member.4</code>: The key is <code>swf:tagList.member.4</code>.</p> </li> <li> <p> <code>taskList</code>: String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn start_workflow_execution(
         &self,
         input: StartWorkflowExecutionInput,
     ) -> RusotoFuture<Run, StartWorkflowExecutionError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.StartWorkflowExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<Run, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(StartWorkflowExecutionError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.start_workflow_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for start_workflow_execution"),
+        }
     }
-
-    /// <p><p>Add a tag to a Amazon SWF domain.</p> <note> <p>Amazon SWF supports a maximum of 50 tags per resource.</p> </note></p>
     fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for tag_resource"),
+        }
     }
-
-    /// <p>Records a <code>WorkflowExecutionTerminated</code> event and forces closure of the workflow execution identified by the given domain, runId, and workflowId. The child policy, registered with the workflow type or specified when starting this execution, is applied to any open child workflow executions of this workflow execution.</p> <important> <p>If the identified workflow execution was in progress, it is terminated immediately.</p> </important> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionTerminated</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>You should consider using <a>RequestCancelWorkflowExecution</a> action instead because it allows the workflow to gracefully close while <a>TerminateWorkflowExecution</a> doesn't.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn terminate_workflow_execution(
         &self,
         input: TerminateWorkflowExecutionInput,
     ) -> RusotoFuture<(), TerminateWorkflowExecutionError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.TerminateWorkflowExecution",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(TerminateWorkflowExecutionError::from_response(response))
-                }))
-            }
-        })
+        match self.terminate_workflow_execution {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for terminate_workflow_execution"),
+        }
     }
-
-    /// <p>Undeprecates a previously deprecated <i>activity type</i>. After an activity type has been undeprecated, you can create new tasks of that activity type.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn undeprecate_activity_type(
         &self,
         input: UndeprecateActivityTypeInput,
     ) -> RusotoFuture<(), UndeprecateActivityTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.UndeprecateActivityType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UndeprecateActivityTypeError::from_response(response))
-                }))
-            }
-        })
+        match self.undeprecate_activity_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for undeprecate_activity_type"),
+        }
     }
-
-    /// <p>Undeprecates a previously deprecated domain. After a domain has been undeprecated it can be used to create new workflow executions or register new types.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn undeprecate_domain(
         &self,
         input: UndeprecateDomainInput,
     ) -> RusotoFuture<(), UndeprecateDomainError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.UndeprecateDomain");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UndeprecateDomainError::from_response(response))),
-                )
-            }
-        })
+        match self.undeprecate_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for undeprecate_domain"),
+        }
     }
-
-    /// <p>Undeprecates a previously deprecated <i>workflow type</i>. After a workflow type has been undeprecated, you can create new executions of that type. </p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
     fn undeprecate_workflow_type(
         &self,
         input: UndeprecateWorkflowTypeInput,
     ) -> RusotoFuture<(), UndeprecateWorkflowTypeError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "SimpleWorkflowService.UndeprecateWorkflowType",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UndeprecateWorkflowTypeError::from_response(response))
-                }))
-            }
-        })
+        match self.undeprecate_workflow_type {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for undeprecate_workflow_type"),
+        }
     }
-
-    /// <p>Remove a tag from a Amazon SWF domain.</p>
     fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "swf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "SimpleWorkflowService.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockSwf has no handler for untag_resource"),
+        }
     }
 }
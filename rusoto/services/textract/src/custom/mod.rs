@@ -0,0 +1,2 @@
+/// Waiting for text detection jobs and fetching the detected text
+pub mod text_detection;
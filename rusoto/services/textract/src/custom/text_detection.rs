@@ -0,0 +1,174 @@
+//! Starting text detection jobs and waiting for the detected text.
+//!
+//! Unlike the synchronous `DetectDocumentText`, text detection jobs handle multi-page
+//! documents. Their results are returned by the service in pages, which are all fetched
+//! once the job has completed.
+//!
+//! ```rust,no_run
+//! use futures::Future;
+//! use rusoto_core::async_job::WaitOptions;
+//! use rusoto_core::Region;
+//! use rusoto_textract::text_detection::TextDetectionJobs;
+//! use rusoto_textract::{
+//!     DocumentLocation, S3Object, StartDocumentTextDetectionRequest, TextractClient,
+//! };
+//!
+//! let jobs = TextDetectionJobs::new(TextractClient::new(Region::UsEast1));
+//! let input = StartDocumentTextDetectionRequest {
+//!     document_location: DocumentLocation {
+//!         s3_object: Some(S3Object {
+//!             bucket: Some("documents".to_owned()),
+//!             name: Some("contract.pdf".to_owned()),
+//!             version: None,
+//!         }),
+//!     },
+//!     ..Default::default()
+//! };
+//! let detection = jobs
+//!     .start(input)
+//!     .map_err(|error| error.to_string())
+//!     .and_then(|job| job.wait(WaitOptions::new()).map_err(|error| error.to_string()))
+//!     .wait()
+//!     .unwrap();
+//! if detection.is_partial() {
+//!     println!("some pages could not be processed");
+//! }
+//! println!("{} blocks", detection.into_output().blocks.len());
+//! ```
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::async_job::{AsyncJob, JobHandle, JobStatus};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    Block, DocumentMetadata, GetDocumentTextDetectionError, GetDocumentTextDetectionRequest,
+    GetDocumentTextDetectionResponse, StartDocumentTextDetectionError,
+    StartDocumentTextDetectionRequest, Textract, TextractClient, Warning,
+};
+
+/// The text detected by a completed job, gathered from all pages of its results.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextDetection {
+    /// The detected pages, lines and words.
+    pub blocks: Vec<Block>,
+    /// Information about the document.
+    pub document_metadata: Option<DocumentMetadata>,
+    /// The problems met while processing the document.
+    pub warnings: Vec<Warning>,
+}
+
+impl TextDetection {
+    fn add_page(&mut self, page: GetDocumentTextDetectionResponse) {
+        self.blocks.extend(page.blocks.unwrap_or_default());
+        self.warnings.extend(page.warnings.unwrap_or_default());
+        if self.document_metadata.is_none() {
+            self.document_metadata = page.document_metadata;
+        }
+    }
+}
+
+/// Text detection jobs.
+#[derive(Clone)]
+pub struct TextDetectionJobs<C = TextractClient> {
+    textract: C,
+}
+
+impl<C> TextDetectionJobs<C>
+where
+    C: Textract + Clone + Send + Sync + 'static,
+{
+    /// Runs jobs with `textract`.
+    pub fn new(textract: C) -> TextDetectionJobs<C> {
+        TextDetectionJobs { textract }
+    }
+
+    /// Starts a text detection job.
+    pub fn start(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> Box<
+        dyn Future<Item = JobHandle<Self>, Error = RusotoError<StartDocumentTextDetectionError>>
+            + Send,
+    > {
+        let jobs = self.clone();
+        Box::new(
+            self.textract
+                .start_document_text_detection(input)
+                .map(move |response| JobHandle::new(jobs, response.job_id.unwrap_or_default())),
+        )
+    }
+
+    /// The job with the id `job_id`, to wait for a job started elsewhere.
+    pub fn job(&self, job_id: &str) -> JobHandle<Self> {
+        JobHandle::new(self.clone(), job_id)
+    }
+}
+
+impl<C> AsyncJob for TextDetectionJobs<C>
+where
+    C: Textract + Clone + Send + Sync + 'static,
+{
+    type Output = TextDetection;
+    type Error = RusotoError<GetDocumentTextDetectionError>;
+
+    fn status(
+        &self,
+        job_id: &str,
+    ) -> Box<dyn Future<Item = JobStatus<TextDetection>, Error = Self::Error> + Send> {
+        let textract = self.textract.clone();
+        let job_id = job_id.to_owned();
+        let request = GetDocumentTextDetectionRequest {
+            job_id: job_id.clone(),
+            ..Default::default()
+        };
+        Box::new(self.textract.get_document_text_detection(request).and_then(
+            move |first| -> Box<dyn Future<Item = _, Error = _> + Send> {
+                let status = first.job_status.clone().unwrap_or_default();
+                let partial = match status.as_str() {
+                    "SUCCEEDED" => false,
+                    "PARTIAL_SUCCESS" => true,
+                    "FAILED" => {
+                        return Box::new(future::ok(JobStatus::Failed(
+                            first.status_message.unwrap_or_default(),
+                        )))
+                    }
+                    _ => return Box::new(future::ok(JobStatus::InProgress)),
+                };
+                let reason = first.status_message.clone().unwrap_or_default();
+
+                let mut detection = TextDetection::default();
+                let next_token = first.next_token.clone();
+                detection.add_page(first);
+                let pages = future::loop_fn(
+                    (detection, next_token),
+                    move |(mut detection, next_token)| -> Box<dyn Future<Item = _, Error = _> + Send> {
+                        let next_token = match next_token {
+                            Some(next_token) => next_token,
+                            None => return Box::new(future::ok(Loop::Break(detection))),
+                        };
+                        let request = GetDocumentTextDetectionRequest {
+                            job_id: job_id.clone(),
+                            next_token: Some(next_token),
+                            ..Default::default()
+                        };
+                        Box::new(textract.get_document_text_detection(request).map(
+                            move |page| {
+                                let next_token = page.next_token.clone();
+                                detection.add_page(page);
+                                Loop::Continue((detection, next_token))
+                            },
+                        ))
+                    },
+                );
+                Box::new(pages.map(move |output| {
+                    if partial {
+                        JobStatus::PartiallySucceeded { output, reason }
+                    } else {
+                        JobStatus::Succeeded(output)
+                    }
+                }))
+            },
+        ))
+    }
+}
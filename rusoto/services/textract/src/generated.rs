@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AnalyzeDocumentRequest {
     /// <p>The input document as base64-encoded bytes or an Amazon S3 object. If you use the AWS CLI to call Amazon Textract operations, you can't pass image bytes. The document must be an image in JPG or PNG format.</p> <p>If you are using an AWS SDK to call Amazon Textract, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. </p>
     #[serde(rename = "Document")]
@@ -33,8 +35,15 @@ pub struct AnalyzeDocumentRequest {
     pub feature_types: Vec<String>,
 }
 
+impl Validate for AnalyzeDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.document
+            .validate_at(&field_path(path, "document"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AnalyzeDocumentResponse {
     /// <p>The text that's detected and analyzed by <code>AnalyzeDocument</code>.</p>
     #[serde(rename = "Blocks")]
@@ -48,7 +57,7 @@ pub struct AnalyzeDocumentResponse {
 
 /// <p>A <code>Block</code> represents items that are recognized in a document within a group of pixels close to each other. The information returned in a <code>Block</code> depends on the type of operation. In document-text detection (for example <a>DetectDocumentText</a>), you get information about the detected words and lines of text. In text analysis (for example <a>AnalyzeDocument</a>), you can also get information about the fields, tables and selection elements that are detected in the document.</p> <p>An array of <code>Block</code> objects is returned by both synchronous and asynchronous operations. In synchronous operations, such as <a>DetectDocumentText</a>, the array of <code>Block</code> objects is the entire set of results. In asynchronous operations, such as <a>GetDocumentAnalysis</a>, the array is returned over one or more responses.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works.html">How Amazon Textract Works</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Block {
     /// <p><p>The type of text that&#39;s recognized in a block. In text-detection operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of the LINE Block objects that are detected on a document page.</p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that&#39;s detected on a document page.</p> </li> </ul> <p>In text analysis operations, the following types are returned:</p> <ul> <li> <p> <i>PAGE</i> - Contains a list of child Block objects that are detected on a document page.</p> </li> <li> <p> <i>KEY<em>VALUE</em>SET</i> - Stores the KEY and VALUE Block objects for a field that&#39;s detected on a document page. Use the <code>EntityType</code> field to determine if a KEY<em>VALUE</em>SET object is a KEY Block object or a VALUE Block object. </p> </li> <li> <p> <i>WORD</i> - A word detected on a document page. A word is one or more ISO basic Latin script characters that aren&#39;t separated by spaces that&#39;s detected on a document page.</p> </li> <li> <p> <i>LINE</i> - A string of tab-delimited, contiguous words that&#39;s detected on a document page.</p> </li> <li> <p> <i>TABLE</i> - A table that&#39;s detected on a document page. A table is any grid-based information with 2 or more rows or columns with a cell span of 1 row and 1 column each. </p> </li> <li> <p> <i>CELL</i> - A cell within a detected table. The cell is the parent of the block that contains the text in the cell.</p> </li> <li> <p> <i>SELECTION_ELEMENT</i> - A selectable element such as a radio button or checkbox that&#39;s detected on a document page. Use the value of <code>SelectionStatus</code> to determine the status of the selection element.</p> </li> </ul></p>
     #[serde(rename = "BlockType")]
@@ -106,7 +115,7 @@ pub struct Block {
 
 /// <p>The bounding box around the recognized text, key, value, table or table cell on a document page. The <code>left</code> (x-coordinate) and <code>top</code> (y-coordinate) are coordinates that represent the top and left sides of the bounding box. Note that the upper-left corner of the image is the origin (0,0). </p> <p>The <code>top</code> and <code>left</code> values returned are ratios of the overall document page size. For example, if the input image is 700 x 200 pixels, and the top-left coordinate of the bounding box is 350 x 50 pixels, the API returns a <code>left</code> value of 0.5 (350/700) and a <code>top</code> value of 0.25 (50/200).</p> <p>The <code>width</code> and <code>height</code> values represent the dimensions of the bounding box as a ratio of the overall document page dimension. For example, if the document page size is 700 x 200 pixels, and the bounding box width is 70 pixels, the width returned is 0.1. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BoundingBox {
     /// <p>The height of the bounding box as a ratio of the overall document page height.</p>
     #[serde(rename = "Height")]
@@ -127,14 +136,22 @@ pub struct BoundingBox {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DetectDocumentTextRequest {
     /// <p>The input document as base64-encoded bytes or an Amazon S3 object. If you use the AWS CLI to call Amazon Textract operations, you can't pass image bytes. The document must be an image in JPG or PNG format.</p> <p>If you are using an AWS SDK to call Amazon Textract, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. </p>
     #[serde(rename = "Document")]
     pub document: Document,
 }
 
+impl Validate for DetectDocumentTextRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.document
+            .validate_at(&field_path(path, "document"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DetectDocumentTextResponse {
     /// <p>An array of Block objects containing the text detected in the document.</p>
     #[serde(rename = "Blocks")]
@@ -148,6 +165,7 @@ pub struct DetectDocumentTextResponse {
 
 /// <p>The input document, either as bytes or as an S3 object.</p> <p>You pass image bytes to an Amazon Textract API operation by using the <code>Bytes</code> property. For example, you would use the <code>Bytes</code> property to pass a document loaded from a local file system. Image bytes passed by using the <code>Bytes</code> property must be base64 encoded. Your code might not need to encode document file bytes if you're using an AWS SDK to call Amazon Textract API operations. </p> <p>You pass images stored in an S3 bucket to an Amazon Textract API operation by using the <code>S3Object</code> property. Documents stored in an S3 bucket don't need to be base64 encoded.</p> <p>The AWS Region for the S3 bucket that contains the S3 object must match the AWS Region that you use for Amazon Textract operations.</p> <p>If you use the AWS CLI to call Amazon Textract operations, passing image bytes using the Bytes property isn't supported. You must first upload the document to an Amazon S3 bucket, and then call the operation using the S3Object property.</p> <p>For Amazon Textract to process an S3 object, the user must have permission to access the S3 object. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct Document {
     /// <p>A blob of base-64 encoded documents bytes. The maximum size of a document that's provided in a blob of bytes is 5 MB. The document bytes must be in PNG or JPG format.</p> <p>If you are using an AWS SDK to call Amazon Textract, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. </p>
     #[serde(rename = "Bytes")]
@@ -164,8 +182,25 @@ pub struct Document {
     pub s3_object: Option<S3Object>,
 }
 
+impl Validate for Document {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bytes {
+            errors.check_length(
+                &field_path(path, "bytes"),
+                value.len(),
+                Some(1),
+                Some(10485760),
+            );
+        }
+        if let Some(ref value) = self.s3_object {
+            value.validate_at(&field_path(path, "s3_object"), errors);
+        }
+    }
+}
+
 /// <p>The Amazon S3 bucket that contains the document to be processed. It's used by asynchronous operations such as <a>StartDocumentTextDetection</a>.</p> <p>The input document can be an image file in JPG or PNG format. It can also be a file in PDF format.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DocumentLocation {
     /// <p>The Amazon S3 bucket that contains the input document.</p>
     #[serde(rename = "S3Object")]
@@ -173,9 +208,17 @@ pub struct DocumentLocation {
     pub s3_object: Option<S3Object>,
 }
 
+impl Validate for DocumentLocation {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.s3_object {
+            value.validate_at(&field_path(path, "s3_object"), errors);
+        }
+    }
+}
+
 /// <p>Information about the input document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentMetadata {
     /// <p>The number of pages detected in the document.</p>
     #[serde(rename = "Pages")]
@@ -185,7 +228,7 @@ pub struct DocumentMetadata {
 
 /// <p>Information about where a recognized text, key, value, table, or table cell is located on a document page.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Geometry {
     /// <p>An axis-aligned coarse representation of the location of the recognized text on the document page.</p>
     #[serde(rename = "BoundingBox")]
@@ -198,6 +241,7 @@ pub struct Geometry {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentAnalysisRequest {
     /// <p>A unique identifier for the text-detection job. The <code>JobId</code> is returned from <code>StartDocumentAnalysis</code>.</p>
     #[serde(rename = "JobId")]
@@ -212,8 +256,36 @@ pub struct GetDocumentAnalysisRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetDocumentAnalysisRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDocumentAnalysisResponse {
     /// <p>The results of the text analysis operation.</p>
     #[serde(rename = "Blocks")]
@@ -242,6 +314,7 @@ pub struct GetDocumentAnalysisResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentTextDetectionRequest {
     /// <p>A unique identifier for the text detection job. The <code>JobId</code> is returned from <code>StartDocumentTextDetection</code>.</p>
     #[serde(rename = "JobId")]
@@ -256,8 +329,36 @@ pub struct GetDocumentTextDetectionRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetDocumentTextDetectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "job_id"),
+            self.job_id.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "job_id"),
+            &self.job_id,
+            "^[a-zA-Z0-9-_]+$",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDocumentTextDetectionResponse {
     /// <p>The results of the text-detection operation.</p>
     #[serde(rename = "Blocks")]
@@ -287,6 +388,7 @@ pub struct GetDocumentTextDetectionResponse {
 
 /// <p>The Amazon Simple Notification Service (Amazon SNS) topic to which Amazon Textract publishes the completion status of an asynchronous document operation, such as <a>StartDocumentTextDetection</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationChannel {
     /// <p>The Amazon Resource Name (ARN) of an IAM role that gives Amazon Textract publishing permissions to the Amazon SNS topic. </p>
     #[serde(rename = "RoleArn")]
@@ -296,9 +398,36 @@ pub struct NotificationChannel {
     pub sns_topic_arn: String,
 }
 
+impl Validate for NotificationChannel {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "role_arn"),
+            self.role_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(
+            &field_path(path, "role_arn"),
+            &self.role_arn,
+            "arn:([a-z\\d-]+):iam::\\d{12}:role/?[a-zA-Z_0-9+=,.@\\-_/]+",
+        );
+        errors.check_length(
+            &field_path(path, "sns_topic_arn"),
+            self.sns_topic_arn.chars().count(),
+            Some(20),
+            Some(1024),
+        );
+        errors.check_pattern(
+            &field_path(path, "sns_topic_arn"),
+            &self.sns_topic_arn,
+            "(^arn:([a-z\\d-]+):sns:[a-zA-Z\\d-]{1,20}:\\w{12}:.+$)",
+        );
+    }
+}
+
 /// <p>The X and Y coordinates of a point on a document page. The X and Y values returned are ratios of the overall document page size. For example, if the input document is 700 x 200 and the operation returns X=0.5 and Y=0.25, then the point is at the (350,50) pixel coordinate on the document page.</p> <p>An array of <code>Point</code> objects, <code>Polygon</code>, is returned by <a>DetectDocumentText</a>. <code>Polygon</code> represents a fine-grained polygon around detected text. For more information, see Geometry in the Amazon Textract Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Point {
     /// <p>The value of the X coordinate for a point on a <code>Polygon</code>.</p>
     #[serde(rename = "X")]
@@ -312,7 +441,7 @@ pub struct Point {
 
 /// <p>Information about how blocks are related to each other. A <code>Block</code> object contains 0 or more <code>Relation</code> objects in a list, <code>Relationships</code>. For more information, see <a>Block</a>.</p> <p>The <code>Type</code> element provides the type of the relationship for all blocks in the <code>IDs</code> array. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Relationship {
     /// <p>An array of IDs for related blocks. You can get the type of the relationship from the <code>Type</code> element.</p>
     #[serde(rename = "Ids")]
@@ -326,6 +455,7 @@ pub struct Relationship {
 
 /// <p>The S3 bucket name and file name that identifies the document.</p> <p>The AWS Region for the S3 bucket that contains the document must match the Region that you use for Amazon Textract operations.</p> <p>For Amazon Textract to process a file in an S3 bucket, the user must have permission to access the S3 bucket and file. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct S3Object {
     /// <p>The name of the S3 bucket.</p>
     #[serde(rename = "Bucket")]
@@ -341,7 +471,40 @@ pub struct S3Object {
     pub version: Option<String>,
 }
 
+impl Validate for S3Object {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bucket {
+            errors.check_length(
+                &field_path(path, "bucket"),
+                value.chars().count(),
+                Some(3),
+                Some(255),
+            );
+            errors.check_pattern(&field_path(path, "bucket"), &value, "[0-9A-Za-z\\.\\-_]*");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "name"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.version {
+            errors.check_length(
+                &field_path(path, "version"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "version"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDocumentAnalysisRequest {
     /// <p>The idempotent token that you use to identify the start request. If you use the same token with multiple <code>StartDocumentAnalysis</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidentally started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -363,8 +526,40 @@ pub struct StartDocumentAnalysisRequest {
     pub notification_channel: Option<NotificationChannel>,
 }
 
+impl Validate for StartDocumentAnalysisRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        self.document_location
+            .validate_at(&field_path(path, "document_location"), errors);
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "job_tag"), &value, "[a-zA-Z0-9_.\\-:]+");
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDocumentAnalysisResponse {
     /// <p>The identifier for the document text detection job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetDocumentAnalysis</code>.</p>
     #[serde(rename = "JobId")]
@@ -373,6 +568,7 @@ pub struct StartDocumentAnalysisResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartDocumentTextDetectionRequest {
     /// <p>The idempotent token that's used to identify the start request. If you use the same token with multiple <code>StartDocumentTextDetection</code> requests, the same <code>JobId</code> is returned. Use <code>ClientRequestToken</code> to prevent the same job from being accidentally started more than once. </p>
     #[serde(rename = "ClientRequestToken")]
@@ -391,8 +587,40 @@ pub struct StartDocumentTextDetectionRequest {
     pub notification_channel: Option<NotificationChannel>,
 }
 
+impl Validate for StartDocumentTextDetectionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.client_request_token {
+            errors.check_length(
+                &field_path(path, "client_request_token"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "client_request_token"),
+                &value,
+                "^[a-zA-Z0-9-_]+$",
+            );
+        }
+        self.document_location
+            .validate_at(&field_path(path, "document_location"), errors);
+        if let Some(ref value) = self.job_tag {
+            errors.check_length(
+                &field_path(path, "job_tag"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+            errors.check_pattern(&field_path(path, "job_tag"), &value, "[a-zA-Z0-9_.\\-:]+");
+        }
+        if let Some(ref value) = self.notification_channel {
+            value.validate_at(&field_path(path, "notification_channel"), errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartDocumentTextDetectionResponse {
     /// <p>The identifier for the document text-detection job. Use <code>JobId</code> to identify the job in a subsequent call to <code>GetDocumentTextDetection</code>.</p>
     #[serde(rename = "JobId")]
@@ -402,7 +630,7 @@ pub struct StartDocumentTextDetectionResponse {
 
 /// <p>A warning about an issue that occurred during asynchronous text analysis (<a>StartDocumentAnalysis</a>) or asynchronous document-text detection (<a>StartDocumentTextDetection</a>). </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Warning {
     /// <p>The error code for the warning.</p>
     #[serde(rename = "ErrorCode")]
@@ -470,7 +698,12 @@ impl AnalyzeDocumentError {
                 "UnsupportedDocumentException" => {
                     return RusotoError::Service(AnalyzeDocumentError::UnsupportedDocument(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -557,7 +790,12 @@ impl DetectDocumentTextError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -629,7 +867,12 @@ impl GetDocumentAnalysisError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetDocumentAnalysisError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -702,7 +945,12 @@ impl GetDocumentTextDetectionError {
                 "ThrottlingException" => {
                     return RusotoError::Service(GetDocumentTextDetectionError::Throttling(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -804,7 +1052,12 @@ impl StartDocumentAnalysisError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -921,7 +1174,12 @@ impl StartDocumentTextDetectionError {
                         StartDocumentTextDetectionError::UnsupportedDocument(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -988,6 +1246,121 @@ pub trait Textract {
         input: StartDocumentTextDetectionRequest,
     ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError>;
 }
+const _: Option<&dyn Textract> = None;
+impl<'a, T: Textract + ?Sized> Textract for &'a T {
+    fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> RusotoFuture<AnalyzeDocumentResponse, AnalyzeDocumentError> {
+        (**self).analyze_document(input)
+    }
+    fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> RusotoFuture<DetectDocumentTextResponse, DetectDocumentTextError> {
+        (**self).detect_document_text(input)
+    }
+    fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> RusotoFuture<GetDocumentAnalysisResponse, GetDocumentAnalysisError> {
+        (**self).get_document_analysis(input)
+    }
+    fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> RusotoFuture<GetDocumentTextDetectionResponse, GetDocumentTextDetectionError> {
+        (**self).get_document_text_detection(input)
+    }
+    fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> RusotoFuture<StartDocumentAnalysisResponse, StartDocumentAnalysisError> {
+        (**self).start_document_analysis(input)
+    }
+    fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError> {
+        (**self).start_document_text_detection(input)
+    }
+}
+impl<T: Textract + ?Sized> Textract for Box<T> {
+    fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> RusotoFuture<AnalyzeDocumentResponse, AnalyzeDocumentError> {
+        (**self).analyze_document(input)
+    }
+    fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> RusotoFuture<DetectDocumentTextResponse, DetectDocumentTextError> {
+        (**self).detect_document_text(input)
+    }
+    fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> RusotoFuture<GetDocumentAnalysisResponse, GetDocumentAnalysisError> {
+        (**self).get_document_analysis(input)
+    }
+    fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> RusotoFuture<GetDocumentTextDetectionResponse, GetDocumentTextDetectionError> {
+        (**self).get_document_text_detection(input)
+    }
+    fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> RusotoFuture<StartDocumentAnalysisResponse, StartDocumentAnalysisError> {
+        (**self).start_document_analysis(input)
+    }
+    fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError> {
+        (**self).start_document_text_detection(input)
+    }
+}
+impl<T: Textract + ?Sized> Textract for ::std::sync::Arc<T> {
+    fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> RusotoFuture<AnalyzeDocumentResponse, AnalyzeDocumentError> {
+        (**self).analyze_document(input)
+    }
+    fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> RusotoFuture<DetectDocumentTextResponse, DetectDocumentTextError> {
+        (**self).detect_document_text(input)
+    }
+    fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> RusotoFuture<GetDocumentAnalysisResponse, GetDocumentAnalysisError> {
+        (**self).get_document_analysis(input)
+    }
+    fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> RusotoFuture<GetDocumentTextDetectionResponse, GetDocumentTextDetectionError> {
+        (**self).get_document_text_detection(input)
+    }
+    fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> RusotoFuture<StartDocumentAnalysisResponse, StartDocumentAnalysisError> {
+        (**self).start_document_analysis(input)
+    }
+    fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError> {
+        (**self).start_document_text_detection(input)
+    }
+}
 /// A client for the Amazon Textract API.
 #[derive(Clone)]
 pub struct TextractClient {
@@ -1031,7 +1404,16 @@ impl Textract for TextractClient {
         &self,
         input: AnalyzeDocumentRequest,
     ) -> RusotoFuture<AnalyzeDocumentResponse, AnalyzeDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("AnalyzeDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.AnalyzeDocument");
@@ -1040,10 +1422,10 @@ impl Textract for TextractClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AnalyzeDocumentResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<AnalyzeDocumentResponse, _>(
+                    response,
+                    "AnalyzeDocument",
+                )
             } else {
                 Box::new(
                     response
@@ -1060,7 +1442,16 @@ impl Textract for TextractClient {
         &self,
         input: DetectDocumentTextRequest,
     ) -> RusotoFuture<DetectDocumentTextResponse, DetectDocumentTextError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("DetectDocumentText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.DetectDocumentText");
@@ -1069,10 +1460,10 @@ impl Textract for TextractClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DetectDocumentTextResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DetectDocumentTextResponse, _>(
+                    response,
+                    "DetectDocumentText",
+                )
             } else {
                 Box::new(
                     response
@@ -1089,7 +1480,16 @@ impl Textract for TextractClient {
         &self,
         input: GetDocumentAnalysisRequest,
     ) -> RusotoFuture<GetDocumentAnalysisResponse, GetDocumentAnalysisError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("GetDocumentAnalysis");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.GetDocumentAnalysis");
@@ -1098,10 +1498,10 @@ impl Textract for TextractClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDocumentAnalysisResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDocumentAnalysisResponse, _>(
+                    response,
+                    "GetDocumentAnalysis",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1117,7 +1517,16 @@ impl Textract for TextractClient {
         &self,
         input: GetDocumentTextDetectionRequest,
     ) -> RusotoFuture<GetDocumentTextDetectionResponse, GetDocumentTextDetectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("GetDocumentTextDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.GetDocumentTextDetection");
@@ -1126,10 +1535,10 @@ impl Textract for TextractClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDocumentTextDetectionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetDocumentTextDetectionResponse, _>(
+                    response,
+                    "GetDocumentTextDetection",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(GetDocumentTextDetectionError::from_response(response))
@@ -1143,7 +1552,16 @@ impl Textract for TextractClient {
         &self,
         input: StartDocumentAnalysisRequest,
     ) -> RusotoFuture<StartDocumentAnalysisResponse, StartDocumentAnalysisError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("StartDocumentAnalysis");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.StartDocumentAnalysis");
@@ -1152,10 +1570,10 @@ impl Textract for TextractClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDocumentAnalysisResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartDocumentAnalysisResponse, _>(
+                    response,
+                    "StartDocumentAnalysis",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1171,7 +1589,16 @@ impl Textract for TextractClient {
         &self,
         input: StartDocumentTextDetectionRequest,
     ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "textract", &self.region, "/");
+        request.set_operation("StartDocumentTextDetection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Textract.StartDocumentTextDetection");
@@ -1180,10 +1607,10 @@ impl Textract for TextractClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartDocumentTextDetectionResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartDocumentTextDetectionResponse, _>(
+                    response,
+                    "StartDocumentTextDetection",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(StartDocumentTextDetectionError::from_response(response))
@@ -1192,3 +1619,234 @@ impl Textract for TextractClient {
         })
     }
 }
+
+/// A mock of the Amazon Textract API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockTextract {
+    analyze_document: Option<
+        Box<
+            dyn Fn(
+                    AnalyzeDocumentRequest,
+                ) -> ::std::result::Result<
+                    AnalyzeDocumentResponse,
+                    RusotoError<AnalyzeDocumentError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    detect_document_text: Option<
+        Box<
+            dyn Fn(
+                    DetectDocumentTextRequest,
+                ) -> ::std::result::Result<
+                    DetectDocumentTextResponse,
+                    RusotoError<DetectDocumentTextError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_document_analysis: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentAnalysisRequest,
+                ) -> ::std::result::Result<
+                    GetDocumentAnalysisResponse,
+                    RusotoError<GetDocumentAnalysisError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_document_text_detection: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentTextDetectionRequest,
+                ) -> ::std::result::Result<
+                    GetDocumentTextDetectionResponse,
+                    RusotoError<GetDocumentTextDetectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_document_analysis: Option<
+        Box<
+            dyn Fn(
+                    StartDocumentAnalysisRequest,
+                ) -> ::std::result::Result<
+                    StartDocumentAnalysisResponse,
+                    RusotoError<StartDocumentAnalysisError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_document_text_detection: Option<
+        Box<
+            dyn Fn(
+                    StartDocumentTextDetectionRequest,
+                ) -> ::std::result::Result<
+                    StartDocumentTextDetectionResponse,
+                    RusotoError<StartDocumentTextDetectionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockTextract {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockTextract {
+        Default::default()
+    }
+
+    /// Answers `analyze_document` with what `handler` returns for its input.
+    pub fn with_analyze_document<F>(mut self, handler: F) -> MockTextract
+    where
+        F: Fn(
+                AnalyzeDocumentRequest,
+            )
+                -> ::std::result::Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.analyze_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `detect_document_text` with what `handler` returns for its input.
+    pub fn with_detect_document_text<F>(mut self, handler: F) -> MockTextract
+    where
+        F: Fn(
+                DetectDocumentTextRequest,
+            ) -> ::std::result::Result<
+                DetectDocumentTextResponse,
+                RusotoError<DetectDocumentTextError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.detect_document_text = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_document_analysis` with what `handler` returns for its input.
+    pub fn with_get_document_analysis<F>(mut self, handler: F) -> MockTextract
+    where
+        F: Fn(
+                GetDocumentAnalysisRequest,
+            ) -> ::std::result::Result<
+                GetDocumentAnalysisResponse,
+                RusotoError<GetDocumentAnalysisError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_document_analysis = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_document_text_detection` with what `handler` returns for its input.
+    pub fn with_get_document_text_detection<F>(mut self, handler: F) -> MockTextract
+    where
+        F: Fn(
+                GetDocumentTextDetectionRequest,
+            ) -> ::std::result::Result<
+                GetDocumentTextDetectionResponse,
+                RusotoError<GetDocumentTextDetectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_document_text_detection = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_document_analysis` with what `handler` returns for its input.
+    pub fn with_start_document_analysis<F>(mut self, handler: F) -> MockTextract
+    where
+        F: Fn(
+                StartDocumentAnalysisRequest,
+            ) -> ::std::result::Result<
+                StartDocumentAnalysisResponse,
+                RusotoError<StartDocumentAnalysisError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_document_analysis = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_document_text_detection` with what `handler` returns for its input.
+    pub fn with_start_document_text_detection<F>(mut self, handler: F) -> MockTextract
+    where
+        F: Fn(
+                StartDocumentTextDetectionRequest,
+            ) -> ::std::result::Result<
+                StartDocumentTextDetectionResponse,
+                RusotoError<StartDocumentTextDetectionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_document_text_detection = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Textract for MockTextract {
+    fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> RusotoFuture<AnalyzeDocumentResponse, AnalyzeDocumentError> {
+        match self.analyze_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTextract has no handler for analyze_document"),
+        }
+    }
+    fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> RusotoFuture<DetectDocumentTextResponse, DetectDocumentTextError> {
+        match self.detect_document_text {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTextract has no handler for detect_document_text"),
+        }
+    }
+    fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> RusotoFuture<GetDocumentAnalysisResponse, GetDocumentAnalysisError> {
+        match self.get_document_analysis {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTextract has no handler for get_document_analysis"),
+        }
+    }
+    fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> RusotoFuture<GetDocumentTextDetectionResponse, GetDocumentTextDetectionError> {
+        match self.get_document_text_detection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTextract has no handler for get_document_text_detection"),
+        }
+    }
+    fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> RusotoFuture<StartDocumentAnalysisResponse, StartDocumentAnalysisError> {
+        match self.start_document_analysis {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTextract has no handler for start_document_analysis"),
+        }
+    }
+    fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> RusotoFuture<StartDocumentTextDetectionResponse, StartDocumentTextDetectionError> {
+        match self.start_document_text_detection {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTextract has no handler for start_document_text_detection"),
+        }
+    }
+}
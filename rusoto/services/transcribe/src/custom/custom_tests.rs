@@ -0,0 +1,451 @@
+extern crate rusoto_mock;
+
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures::{stream, Future, Stream};
+use hmac::{Hmac, Mac};
+use rusoto_core::async_job::{JobError, JobOutput, WaitOptions};
+use rusoto_core::credential::{ManualClock, StaticProvider};
+use rusoto_core::event_stream::{HeaderValue, Message, MessageStream};
+use rusoto_core::request::{HttpDispatchError, HttpResponse};
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{ByteStream, DispatchSignedRequest, Region, RusotoError};
+use rusoto_s3::S3Client;
+use sha2::{Digest, Sha256};
+
+use self::rusoto_mock::*;
+use crate::generated::{Media, StartTranscriptionJobRequest, TranscribeClient};
+use crate::streaming::{
+    StartStreamTranscriptionConfig, StartStreamTranscriptionError, TranscribeStreamingClient,
+    TranscriptEvent,
+};
+use crate::transcription::{s3_location, TranscriptionError, TranscriptionJobs};
+
+const TRANSCRIPT: &str = r#"{
+    "jobName": "interview",
+    "accountId": "123456789012",
+    "results": {
+        "transcripts": [{"transcript": "Hello world."}],
+        "items": [
+            {"start_time": "0.0", "end_time": "0.4", "alternatives": [{"confidence": "0.98", "content": "Hello"}], "type": "pronunciation"},
+            {"start_time": "0.4", "end_time": "0.9", "alternatives": [{"confidence": "0.95", "content": "world"}], "type": "pronunciation"},
+            {"alternatives": [{"confidence": "0.0", "content": "."}], "type": "punctuation"}
+        ]
+    },
+    "status": "COMPLETED"
+}"#;
+
+/// Answers transcription job descriptions with `statuses` in order, and transcript
+/// downloads with `TRANSCRIPT`.
+struct JobDispatcher {
+    statuses: Mutex<Vec<&'static str>>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl JobDispatcher {
+    fn new(statuses: &[&'static str]) -> (JobDispatcher, Arc<Mutex<Vec<String>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = JobDispatcher {
+            statuses: Mutex::new(statuses.to_vec()),
+            requests: requests.clone(),
+        };
+        (dispatcher, requests)
+    }
+}
+
+impl DispatchSignedRequest for JobDispatcher {
+    type Future = <MockRequestDispatcher as DispatchSignedRequest>::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let target = request
+            .headers()
+            .get("x-amz-target")
+            .map(|values| String::from_utf8_lossy(&values[0]).into_owned());
+        let description = match target {
+            Some(ref target) => target.trim_start_matches("Transcribe.").to_owned(),
+            None => format!("{} {}", request.method(), request.path()),
+        };
+        self.requests.lock().unwrap().push(description);
+
+        let dispatcher = match target.unwrap_or_default().as_str() {
+            "Transcribe.StartTranscriptionJob" => MockRequestDispatcher::with_status(200)
+                .with_body(r#"{"TranscriptionJob":{"TranscriptionJobName":"interview","TranscriptionJobStatus":"IN_PROGRESS"}}"#),
+            "Transcribe.GetTranscriptionJob" => {
+                let status = self.statuses.lock().unwrap().remove(0);
+                MockRequestDispatcher::with_status(200).with_body(status)
+            }
+            _ => MockRequestDispatcher::with_status(200).with_body(TRANSCRIPT),
+        };
+        dispatcher.dispatch(request, timeout)
+    }
+}
+
+fn jobs(dispatcher: JobDispatcher) -> TranscriptionJobs {
+    let dispatcher = Arc::new(dispatcher);
+    TranscriptionJobs::new(
+        TranscribeClient::new_with(dispatcher.clone(), MockCredentialsProvider, Region::UsEast1),
+        S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1),
+    )
+}
+
+fn start_request() -> StartTranscriptionJobRequest {
+    StartTranscriptionJobRequest {
+        transcription_job_name: "interview".to_owned(),
+        language_code: "en-US".to_owned(),
+        media: Media {
+            media_file_uri: Some(
+                "https://s3.us-east-1.amazonaws.com/recordings/interview.mp3".to_owned(),
+            ),
+        },
+        output_bucket_name: Some("transcripts".to_owned()),
+        ..Default::default()
+    }
+}
+
+fn wait_options() -> WaitOptions {
+    WaitOptions::new()
+        .with_initial_delay(Duration::from_millis(1))
+        .with_max_delay(Duration::from_millis(1))
+}
+
+#[test]
+fn test_transcription_job_waits_for_transcript() {
+    let (dispatcher, requests) = JobDispatcher::new(&[
+        r#"{"TranscriptionJob":{"TranscriptionJobName":"interview","TranscriptionJobStatus":"IN_PROGRESS"}}"#,
+        r#"{"TranscriptionJob":{"TranscriptionJobName":"interview","TranscriptionJobStatus":"COMPLETED",
+            "Transcript":{"TranscriptFileUri":"https://s3.us-east-1.amazonaws.com/transcripts/interview.json"}}}"#,
+    ]);
+    let jobs = jobs(dispatcher);
+
+    let work = jobs
+        .start(start_request())
+        .map_err(|error| panic!("failed to start the job: {}", error))
+        .and_then(|job| job.wait(wait_options()));
+    let output = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(work)
+        .unwrap();
+
+    let transcript = match output {
+        JobOutput::Complete(transcript) => transcript,
+        other => panic!("expected a complete transcript, got {:?}", other),
+    };
+    assert_eq!(transcript.job_name, "interview");
+    assert_eq!(transcript.transcript(), "Hello world.");
+    assert_eq!(transcript.results.items.len(), 3);
+    assert_eq!(transcript.results.items[2].item_type, "punctuation");
+    assert_eq!(transcript.results.items[2].start_time, None);
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![
+            "StartTranscriptionJob",
+            "GetTranscriptionJob",
+            "GetTranscriptionJob",
+            "GET /transcripts/interview.json",
+        ]
+    );
+}
+
+#[test]
+fn test_transcription_job_reports_failure_reason() {
+    let (dispatcher, requests) = JobDispatcher::new(&[
+        r#"{"TranscriptionJob":{"TranscriptionJobName":"interview","TranscriptionJobStatus":"FAILED",
+            "FailureReason":"The media format is not supported."}}"#,
+    ]);
+    let job = jobs(dispatcher).job("interview");
+
+    let err = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(job.wait(wait_options()))
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        JobError::Failed {
+            job_id: "interview".to_owned(),
+            reason: "The media format is not supported.".to_owned(),
+        }
+    );
+    assert_eq!(*requests.lock().unwrap(), vec!["GetTranscriptionJob"]);
+}
+
+#[test]
+fn test_transcription_job_requires_transcript_in_s3() {
+    let (dispatcher, _) = JobDispatcher::new(&[
+        r#"{"TranscriptionJob":{"TranscriptionJobName":"interview","TranscriptionJobStatus":"COMPLETED",
+            "Transcript":{"TranscriptFileUri":"https://example.com/interview.json"}}}"#,
+    ]);
+    let job = jobs(dispatcher).job("interview");
+
+    let err = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(job.wait(wait_options()))
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        JobError::Status(TranscriptionError::TranscriptLocation(
+            "https://example.com/interview.json".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn test_s3_location_of_transcript_urls() {
+    let expected = Some(("transcripts".to_owned(), "jobs/interview.json".to_owned()));
+    assert_eq!(
+        s3_location("https://s3.us-east-1.amazonaws.com/transcripts/jobs/interview.json"),
+        expected
+    );
+    assert_eq!(
+        s3_location("https://s3-eu-west-1.amazonaws.com/transcripts/jobs/interview.json"),
+        expected
+    );
+    assert_eq!(
+        s3_location("https://transcripts.s3.amazonaws.com/jobs/interview.json?X-Amz-Expires=900"),
+        expected
+    );
+    assert_eq!(
+        s3_location("https://example.com/transcripts/interview.json"),
+        None
+    );
+    assert_eq!(s3_location("https://s3.amazonaws.com/transcripts"), None);
+}
+
+/// Answers streaming transcriptions with `status` and `body`, after checking the signature chain of
+/// the frames of the request and recording the audio they carry.
+struct StreamDispatcher {
+    status: u16,
+    body: Vec<u8>,
+    audio: Arc<Mutex<Vec<Bytes>>>,
+}
+
+impl StreamDispatcher {
+    fn new(status: u16, body: Vec<u8>) -> (StreamDispatcher, Arc<Mutex<Vec<Bytes>>>) {
+        let audio = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = StreamDispatcher {
+            status,
+            body,
+            audio: audio.clone(),
+        };
+        (dispatcher, audio)
+    }
+}
+
+impl DispatchSignedRequest for StreamDispatcher {
+    type Future = Box<dyn Future<Item = HttpResponse, Error = HttpDispatchError> + Send>;
+
+    fn dispatch(&self, mut request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+        assert_eq!(request.method(), "POST");
+        assert_eq!(request.path(), "/stream-transcription");
+        assert_eq!(
+            request.hostname(),
+            "transcribestreaming.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            request.headers()["x-amz-content-sha256"],
+            vec![b"STREAMING-AWS4-HMAC-SHA256-EVENTS".to_vec()]
+        );
+        assert_eq!(
+            request.headers()["x-amzn-transcribe-sample-rate"],
+            vec![b"16000".to_vec()]
+        );
+        let authorization =
+            String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+        let seed = authorization
+            .rsplit("Signature=")
+            .next()
+            .unwrap()
+            .to_owned();
+
+        let body = match request.payload.take() {
+            Some(SignedRequestPayload::Stream(body)) => body,
+            _ => panic!("the audio isn't streamed"),
+        };
+        let audio = self.audio.clone();
+        let response_body = self.body.clone();
+        let status = self.status;
+        Box::new(
+            MessageStream::new(body)
+                .collect()
+                .map_err(|error| HttpDispatchError::new(error.to_string()))
+                .and_then(move |frames| {
+                    let mut prior_signature = seed;
+                    for frame in &frames {
+                        prior_signature = check_chunk_signature(frame, &prior_signature);
+                    }
+                    assert_eq!(frames.last().map(|frame| frame.payload.len()), Some(0));
+                    for frame in &frames[..frames.len() - 1] {
+                        let event = Message::decode(&mut BytesMut::from(frame.payload.clone()))
+                            .unwrap()
+                            .unwrap();
+                        assert_eq!(event.header_str(":event-type"), Some("AudioEvent"));
+                        audio.lock().unwrap().push(event.payload);
+                    }
+                    MockRequestDispatcher::with_status(status)
+                        .dispatch(request, None)
+                        .map(|mut response| {
+                            response.body = ByteStream::from(response_body);
+                            response
+                        })
+                }),
+        )
+    }
+}
+
+/// Checks the signature of `frame`, chained to `prior_signature`, and returns it.
+fn check_chunk_signature(frame: &Message, prior_signature: &str) -> String {
+    fn hmac(key: &[u8], message: &[u8]) -> Vec<u8> {
+        let mut hmac = Hmac::<Sha256>::new_varkey(key).unwrap();
+        hmac.input(message);
+        hmac.result().code().to_vec()
+    }
+
+    assert_eq!(frame.headers[0].name, ":date");
+    assert_eq!(
+        frame.header(":date"),
+        Some(&HeaderValue::Timestamp(1_546_344_000_000))
+    );
+    let mut date_header = b"\x05:date\x08".to_vec();
+    date_header.extend_from_slice(&1_546_344_000_000i64.to_be_bytes());
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256-PAYLOAD\n20190101T120000Z\n20190101/us-east-1/transcribe/aws4_request\n{}\n{}\n{}",
+        prior_signature,
+        hex::encode(Sha256::digest(&date_header)),
+        hex::encode(Sha256::digest(&frame.payload))
+    );
+    let key = [
+        &b"20190101"[..],
+        b"us-east-1",
+        b"transcribe",
+        b"aws4_request",
+    ]
+    .iter()
+    .fold(b"AWS4secret".to_vec(), |key, part| hmac(&key, part));
+    let signature = hmac(&key, string_to_sign.as_bytes());
+    assert_eq!(
+        frame.header(":chunk-signature"),
+        Some(&HeaderValue::ByteArray(Bytes::from(signature.clone())))
+    );
+    hex::encode(signature)
+}
+
+fn transcript_event(transcript: &str, is_partial: bool) -> Message {
+    let payload = format!(
+        r#"{{"Transcript":{{"Results":[{{"ResultId":"1","StartTime":0.0,"EndTime":1.5,"IsPartial":{},"Alternatives":[{{"Transcript":"{}","Items":[]}}]}}]}}}}"#,
+        is_partial, transcript
+    );
+    Message::new(payload)
+        .with_header(":message-type", HeaderValue::String("event".to_owned()))
+        .with_header(
+            ":event-type",
+            HeaderValue::String("TranscriptEvent".to_owned()),
+        )
+}
+
+fn frames(messages: Vec<Message>) -> Vec<u8> {
+    messages
+        .iter()
+        .flat_map(|message| message.encode().to_vec())
+        .collect()
+}
+
+fn transcribe(
+    dispatcher: StreamDispatcher,
+    chunks: Vec<&'static [u8]>,
+) -> Result<Vec<TranscriptEvent>, RusotoError<StartStreamTranscriptionError>> {
+    let clock = ManualClock::new("2019-01-01T12:00:00Z".parse().unwrap());
+    let client = TranscribeStreamingClient::new_with(
+        dispatcher,
+        StaticProvider::new_minimal("key".to_owned(), "secret".to_owned()),
+        Region::UsEast1,
+    )
+    .with_clock(clock);
+    let audio = stream::iter_ok::<_, io::Error>(chunks.into_iter().map(Bytes::from_static));
+    client
+        .start_stream_transcription(
+            audio,
+            StartStreamTranscriptionConfig::new("en-US", 16_000, "pcm"),
+        )
+        .collect()
+        .wait()
+}
+
+#[test]
+fn test_stream_transcription_sends_signed_audio_events() {
+    let (dispatcher, audio) = StreamDispatcher::new(
+        200,
+        frames(vec![
+            transcript_event("Hello", true),
+            Message::new(&b"{}"[..])
+                .with_header(":message-type", HeaderValue::String("event".to_owned()))
+                .with_header(
+                    ":event-type",
+                    HeaderValue::String("SomeNewEvent".to_owned()),
+                ),
+            transcript_event("Hello world.", false),
+        ]),
+    );
+
+    let events = transcribe(dispatcher, vec![b"first chunk", b"second chunk"]).unwrap();
+
+    assert_eq!(
+        *audio.lock().unwrap(),
+        vec![
+            Bytes::from_static(b"first chunk"),
+            Bytes::from_static(b"second chunk")
+        ]
+    );
+    let transcripts: Vec<(&str, bool)> = events
+        .iter()
+        .map(|event| {
+            let result = &event.transcript.results[0];
+            (
+                result.alternatives[0].transcript.as_str(),
+                result.is_partial,
+            )
+        })
+        .collect();
+    assert_eq!(transcripts, vec![("Hello", true), ("Hello world.", false)]);
+}
+
+#[test]
+fn test_stream_transcription_fails_on_exception_events() {
+    let (dispatcher, _) = StreamDispatcher::new(
+        200,
+        frames(vec![
+            transcript_event("Hello", true),
+            Message::new(&br#"{"Message":"Your request timed out because no new audio was received for 15 seconds."}"#[..])
+                .with_header(":message-type", HeaderValue::String("exception".to_owned()))
+                .with_header(":exception-type", HeaderValue::String("BadRequestException".to_owned())),
+        ]),
+    );
+
+    assert_eq!(
+        transcribe(dispatcher, vec![b"audio"]),
+        Err(RusotoError::Service(
+            StartStreamTranscriptionError::BadRequest(
+                "Your request timed out because no new audio was received for 15 seconds."
+                    .to_owned()
+            )
+        ))
+    );
+}
+
+#[test]
+fn test_stream_transcription_fails_on_error_responses() {
+    let (dispatcher, _) = StreamDispatcher::new(
+        429,
+        br#"{"Code":"LimitExceededException","Message":"Too many streams."}"#.to_vec(),
+    );
+
+    assert_eq!(
+        transcribe(dispatcher, vec![b"audio"]),
+        Err(RusotoError::Service(
+            StartStreamTranscriptionError::LimitExceeded("Too many streams.".to_owned())
+        ))
+    );
+}
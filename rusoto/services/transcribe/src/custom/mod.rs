@@ -1 +1,8 @@
+/// Waiting for transcription jobs and fetching their transcripts
+pub mod transcription;
 
+/// Transcribing audio as it is streamed
+pub mod streaming;
+
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,401 @@
+//! Transcribing audio as it is streamed, with Amazon Transcribe Streaming.
+//!
+//! The audio is sent in the body of a single HTTP/2 request, as an event stream whose
+//! frames are signed one by one, and the transcripts come back in the response as soon
+//! as the service has them:
+//!
+//! ```rust,no_run
+//! use std::io;
+//!
+//! use bytes::Bytes;
+//! use futures::{stream, Future, Stream};
+//! use rusoto_core::Region;
+//! use rusoto_transcribe::streaming::{StartStreamTranscriptionConfig, TranscribeStreamingClient};
+//!
+//! let client = TranscribeStreamingClient::new(Region::UsEast1);
+//! let audio: Vec<Result<Bytes, io::Error>> = vec![Ok(Bytes::from(vec![0; 3200]))];
+//! let config = StartStreamTranscriptionConfig::new("en-US", 16_000, "pcm");
+//! let transcripts = client
+//!     .start_stream_transcription(stream::iter_result(audio), config)
+//!     .filter_map(|event| event.transcript.results.into_iter().find(|result| !result.is_partial))
+//!     .for_each(|result| {
+//!         println!("{}", result.alternatives[0].transcript);
+//!         Ok(())
+//!     });
+//! tokio::run(transcripts.map_err(|error| eprintln!("{}", error)));
+//! ```
+//!
+//! The audio stream is only polled when the connection is ready to send more of it, so a
+//! live source of audio should buffer or drop the chunks it can't hand over.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::{future, try_ready, Async, Future, Poll, Stream};
+use rusoto_core::credential::{Clock, SystemClock};
+use rusoto_core::event_stream::{HeaderValue, Message, MessageStream};
+use rusoto_core::proto;
+use rusoto_core::request::{BufferedHttpResponse, HttpResponse};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{
+    ByteStream, DefaultCredentialsProvider, DispatchSignedRequest, HttpClient, HttpConfig,
+    ProvideAwsCredentials, Region, RusotoError,
+};
+
+/// The settings of a streaming transcription.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StartStreamTranscriptionConfig {
+    /// The language of the audio, like `en-US`.
+    pub language_code: String,
+    /// The sample rate of the audio, in Hertz.
+    pub media_sample_rate_hertz: i64,
+    /// The encoding of the audio, like `pcm`.
+    pub media_encoding: String,
+    /// The name of a custom vocabulary to transcribe the audio with.
+    pub vocabulary_name: Option<String>,
+    /// The identifier of the session, generated by the service when not given.
+    pub session_id: Option<String>,
+}
+
+impl StartStreamTranscriptionConfig {
+    /// Settings for transcribing audio in `language_code`, sampled at `media_sample_rate_hertz`
+    /// and encoded with `media_encoding`.
+    pub fn new<L, E>(
+        language_code: L,
+        media_sample_rate_hertz: i64,
+        media_encoding: E,
+    ) -> StartStreamTranscriptionConfig
+    where
+        L: Into<String>,
+        E: Into<String>,
+    {
+        StartStreamTranscriptionConfig {
+            language_code: language_code.into(),
+            media_sample_rate_hertz,
+            media_encoding: media_encoding.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A transcript of the audio sent so far.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptEvent {
+    /// The transcript.
+    #[serde(rename = "Transcript", default)]
+    pub transcript: Transcript,
+}
+
+/// The results of the transcription.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct Transcript {
+    /// The transcribed segments of the audio.
+    #[serde(rename = "Results", default)]
+    pub results: Vec<TranscriptResult>,
+}
+
+/// The transcription of a segment of the audio.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptResult {
+    /// The identifier of the segment, shared by the successive transcriptions of the segment.
+    #[serde(rename = "ResultId", default)]
+    pub result_id: String,
+    /// The start of the segment, in seconds from the start of the audio.
+    #[serde(rename = "StartTime", default)]
+    pub start_time: f64,
+    /// The end of the segment, in seconds from the start of the audio.
+    #[serde(rename = "EndTime", default)]
+    pub end_time: f64,
+    /// Whether the segment is still being transcribed, and will be transcribed again.
+    #[serde(rename = "IsPartial", default)]
+    pub is_partial: bool,
+    /// The possible transcriptions of the segment.
+    #[serde(rename = "Alternatives", default)]
+    pub alternatives: Vec<Alternative>,
+}
+
+/// A possible transcription of a segment of the audio.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct Alternative {
+    /// The text of the transcription.
+    #[serde(rename = "Transcript", default)]
+    pub transcript: String,
+    /// The words and punctuation of the transcription.
+    #[serde(rename = "Items", default)]
+    pub items: Vec<Item>,
+}
+
+/// A word or punctuation mark of a transcription.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct Item {
+    /// The word or punctuation mark.
+    #[serde(rename = "Content", default)]
+    pub content: String,
+    /// `pronunciation` for words and `punctuation` for punctuation marks.
+    #[serde(rename = "Type", default)]
+    pub item_type: String,
+    /// The start of the word, in seconds from the start of the audio.
+    #[serde(rename = "StartTime", default)]
+    pub start_time: f64,
+    /// The end of the word, in seconds from the start of the audio.
+    #[serde(rename = "EndTime", default)]
+    pub end_time: f64,
+}
+
+/// Errors returned by StartStreamTranscription
+#[derive(Debug, PartialEq)]
+pub enum StartStreamTranscriptionError {
+    /// The request or the audio is not valid, like audio in an unsupported encoding.
+    BadRequest(String),
+    /// A stream with the same session identifier is already being transcribed.
+    Conflict(String),
+    /// The service failed to transcribe the audio.
+    InternalFailure(String),
+    /// Too many streams are being transcribed, or the audio is too long.
+    LimitExceeded(String),
+    /// The service can't transcribe the audio right now.
+    ServiceUnavailable(String),
+}
+
+impl StartStreamTranscriptionError {
+    /// The error named `exception_type`, or `None` for an unknown exception.
+    fn from_exception(
+        exception_type: &str,
+        message: String,
+    ) -> Option<StartStreamTranscriptionError> {
+        match exception_type {
+            "BadRequestException" => Some(StartStreamTranscriptionError::BadRequest(message)),
+            "ConflictException" => Some(StartStreamTranscriptionError::Conflict(message)),
+            "InternalFailureException" => {
+                Some(StartStreamTranscriptionError::InternalFailure(message))
+            }
+            "LimitExceededException" => Some(StartStreamTranscriptionError::LimitExceeded(message)),
+            "ServiceUnavailableException" => {
+                Some(StartStreamTranscriptionError::ServiceUnavailable(message))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartStreamTranscriptionError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            if let Some(error) = StartStreamTranscriptionError::from_exception(&err.typ, err.msg) {
+                return RusotoError::Service(error);
+            }
+        }
+        RusotoError::Unknown(res)
+    }
+
+    /// The error carried by an exception message of the response stream.
+    fn from_message(message: &Message) -> RusotoError<StartStreamTranscriptionError> {
+        #[derive(Deserialize)]
+        struct Exception {
+            #[serde(rename = "Message", default)]
+            message: String,
+        }
+
+        let exception_type = message.header_str(":exception-type").unwrap_or_default();
+        let description = serde_json::from_slice::<Exception>(&message.payload)
+            .map(|exception| exception.message)
+            .unwrap_or_else(|_| String::from_utf8_lossy(&message.payload).into_owned());
+        match StartStreamTranscriptionError::from_exception(exception_type, description) {
+            Some(error) => RusotoError::Service(error),
+            None => RusotoError::ParseError(format!(
+                "unknown exception {} in the transcript stream",
+                exception_type
+            )),
+        }
+    }
+}
+
+impl fmt::Display for StartStreamTranscriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for StartStreamTranscriptionError {
+    fn description(&self) -> &str {
+        match *self {
+            StartStreamTranscriptionError::BadRequest(ref cause) => cause,
+            StartStreamTranscriptionError::Conflict(ref cause) => cause,
+            StartStreamTranscriptionError::InternalFailure(ref cause) => cause,
+            StartStreamTranscriptionError::LimitExceeded(ref cause) => cause,
+            StartStreamTranscriptionError::ServiceUnavailable(ref cause) => cause,
+        }
+    }
+}
+
+/// A client for Amazon Transcribe Streaming.
+#[derive(Clone)]
+pub struct TranscribeStreamingClient<P = DefaultCredentialsProvider, D = HttpClient> {
+    credentials_provider: Arc<P>,
+    dispatcher: Arc<D>,
+    region: Region,
+    clock: Arc<dyn Clock>,
+}
+
+impl TranscribeStreamingClient {
+    /// Creates a client backed by the default credentials provider and an HTTP/2 client.
+    ///
+    /// The default credentials provider is
+    /// [`DefaultCredentialsProvider`](../../rusoto_credential/struct.DefaultCredentialsProvider.html).
+    pub fn new(region: Region) -> TranscribeStreamingClient {
+        let credentials_provider =
+            DefaultCredentialsProvider::new().expect("failed to create credentials provider");
+        // the audio and the transcripts are exchanged over a single HTTP/2 stream
+        let mut config = HttpConfig::new();
+        config.http2_only(true);
+        let dispatcher =
+            HttpClient::new_with_config(config).expect("failed to create request dispatcher");
+        TranscribeStreamingClient::new_with(dispatcher, credentials_provider, region)
+    }
+}
+
+impl<P, D> TranscribeStreamingClient<P, D>
+where
+    P: ProvideAwsCredentials + 'static,
+    P::Future: Send,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+    D::Future: Send,
+{
+    /// Creates a client from a request dispatcher and a credentials provider.
+    pub fn new_with(dispatcher: D, credentials_provider: P, region: Region) -> Self {
+        TranscribeStreamingClient {
+            credentials_provider: Arc::new(credentials_provider),
+            dispatcher: Arc::new(dispatcher),
+            region,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Date the request and the frames of the audio with the time of `clock` instead of the
+    /// system clock.
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Transcribes the chunks of `audio`, returning the transcripts as the service sends them.
+    ///
+    /// Each chunk is sent in an `AudioEvent` of its own; the service expects chunks of 50
+    /// to 200 milliseconds of audio. The stream of transcripts ends once the service has
+    /// transcribed the end of the audio.
+    pub fn start_stream_transcription<S>(
+        &self,
+        audio: S,
+        config: StartStreamTranscriptionConfig,
+    ) -> TranscriptEventStream
+    where
+        S: Stream<Item = Bytes, Error = io::Error> + Send + 'static,
+    {
+        let mut request =
+            SignedRequest::new("POST", "transcribe", &self.region, "/stream-transcription");
+        request.set_endpoint_prefix("transcribestreaming".to_owned());
+        request.set_content_type("application/vnd.amazon.eventstream".to_owned());
+        request.add_header("x-amzn-transcribe-language-code", &config.language_code);
+        request.add_header(
+            "x-amzn-transcribe-sample-rate",
+            &config.media_sample_rate_hertz.to_string(),
+        );
+        request.add_header("x-amzn-transcribe-media-encoding", &config.media_encoding);
+        if let Some(ref vocabulary_name) = config.vocabulary_name {
+            request.add_header("x-amzn-transcribe-vocabulary-name", vocabulary_name);
+        }
+        if let Some(ref session_id) = config.session_id {
+            request.add_header("x-amzn-transcribe-session-id", session_id);
+        }
+
+        let events = audio.map(audio_event);
+        let dispatcher = self.dispatcher.clone();
+        let clock = self.clock.clone();
+        let response = self
+            .credentials_provider
+            .credentials()
+            .map_err(RusotoError::from)
+            .and_then(move |credentials| {
+                request.sign_event_stream(&credentials, clock, events);
+                dispatcher
+                    .dispatch(request, None)
+                    .map_err(RusotoError::from)
+            })
+            .and_then(|response: HttpResponse| {
+                if response.status.is_success() {
+                    future::Either::A(future::ok(response.body))
+                } else {
+                    future::Either::B(response.buffer().from_err().and_then(|response| {
+                        Err(StartStreamTranscriptionError::from_response(response))
+                    }))
+                }
+            });
+        TranscriptEventStream {
+            state: StreamState::Connecting(Box::new(response)),
+        }
+    }
+}
+
+/// Wraps a chunk of audio in an `AudioEvent` message.
+fn audio_event(chunk: Bytes) -> Message {
+    Message::new(chunk)
+        .with_header(":message-type", HeaderValue::String("event".to_owned()))
+        .with_header(":event-type", HeaderValue::String("AudioEvent".to_owned()))
+        .with_header(
+            ":content-type",
+            HeaderValue::String("application/octet-stream".to_owned()),
+        )
+}
+
+type ResponseFuture =
+    Box<dyn Future<Item = ByteStream, Error = RusotoError<StartStreamTranscriptionError>> + Send>;
+
+enum StreamState {
+    Connecting(ResponseFuture),
+    Streaming(MessageStream<ByteStream>),
+}
+
+/// The transcripts of a stream of audio, returned by
+/// [`start_stream_transcription`](struct.TranscribeStreamingClient.html#method.start_stream_transcription).
+pub struct TranscriptEventStream {
+    state: StreamState,
+}
+
+impl Stream for TranscriptEventStream {
+    type Item = TranscriptEvent;
+    type Error = RusotoError<StartStreamTranscriptionError>;
+
+    fn poll(&mut self) -> Poll<Option<TranscriptEvent>, Self::Error> {
+        loop {
+            let message = match self.state {
+                StreamState::Connecting(ref mut response) => {
+                    let body = try_ready!(response.poll());
+                    self.state = StreamState::Streaming(MessageStream::new(body));
+                    continue;
+                }
+                StreamState::Streaming(ref mut messages) => match try_ready!(messages.poll()) {
+                    Some(message) => message,
+                    None => return Ok(Async::Ready(None)),
+                },
+            };
+            match message.header_str(":message-type") {
+                Some("event") => {}
+                Some("exception") => {
+                    return Err(StartStreamTranscriptionError::from_message(&message))
+                }
+                other => {
+                    return Err(RusotoError::ParseError(format!(
+                        "unexpected message type {:?} in the transcript stream",
+                        other
+                    )))
+                }
+            }
+            // events of other types may be added to the stream, and are skipped
+            if message.header_str(":event-type") == Some("TranscriptEvent") {
+                let event = serde_json::from_slice(&message.payload)?;
+                return Ok(Async::Ready(Some(event)));
+            }
+        }
+    }
+}
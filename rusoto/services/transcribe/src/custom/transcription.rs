@@ -0,0 +1,316 @@
+//! Starting transcription jobs and waiting for their transcripts.
+//!
+//! ```rust,no_run
+//! use futures::Future;
+//! use rusoto_core::async_job::WaitOptions;
+//! use rusoto_core::Region;
+//! use rusoto_s3::S3Client;
+//! use rusoto_transcribe::transcription::TranscriptionJobs;
+//! use rusoto_transcribe::{Media, StartTranscriptionJobRequest, TranscribeClient};
+//!
+//! let jobs = TranscriptionJobs::new(
+//!     TranscribeClient::new(Region::UsEast1),
+//!     S3Client::new(Region::UsEast1),
+//! );
+//! let input = StartTranscriptionJobRequest {
+//!     transcription_job_name: "interview".to_owned(),
+//!     language_code: "en-US".to_owned(),
+//!     media: Media {
+//!         media_file_uri: Some("https://s3.us-east-1.amazonaws.com/recordings/interview.mp3".to_owned()),
+//!     },
+//!     output_bucket_name: Some("transcripts".to_owned()),
+//!     ..Default::default()
+//! };
+//! let transcript = jobs
+//!     .start(input)
+//!     .map_err(|error| error.to_string())
+//!     .and_then(|job| job.wait(WaitOptions::new()).map_err(|error| error.to_string()))
+//!     .wait()
+//!     .unwrap()
+//!     .into_output();
+//! println!("{}", transcript.transcript());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use futures::{Future, Stream};
+use rusoto_core::async_job::{AsyncJob, JobHandle, JobStatus};
+use rusoto_core::signature::decode_uri;
+use rusoto_core::RusotoError;
+use rusoto_s3::{GetObjectError, GetObjectRequest, S3Client, S3};
+
+use crate::generated::{
+    GetTranscriptionJobError, GetTranscriptionJobRequest, StartTranscriptionJobError,
+    StartTranscriptionJobRequest, Transcribe, TranscribeClient, TranscriptionJob,
+};
+
+/// The transcript written by a completed transcription job.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptDocument {
+    /// The name of the job.
+    #[serde(rename = "jobName", default)]
+    pub job_name: String,
+    /// The account the job ran in.
+    #[serde(rename = "accountId", default)]
+    pub account_id: String,
+    /// The transcription.
+    #[serde(default)]
+    pub results: TranscriptResults,
+}
+
+impl TranscriptDocument {
+    /// The text of the transcript.
+    pub fn transcript(&self) -> String {
+        self.results
+            .transcripts
+            .iter()
+            .map(|transcript| transcript.transcript.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The transcription of the media of a job.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptResults {
+    /// The text of the transcript.
+    #[serde(default)]
+    pub transcripts: Vec<TranscriptText>,
+    /// The words and punctuation of the transcript.
+    #[serde(default)]
+    pub items: Vec<TranscriptItem>,
+}
+
+/// A text of a transcript.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptText {
+    /// The text.
+    #[serde(default)]
+    pub transcript: String,
+}
+
+/// A word or punctuation mark of a transcript.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptItem {
+    /// When the word starts in the media, in seconds. Missing for punctuation.
+    pub start_time: Option<String>,
+    /// When the word ends in the media, in seconds. Missing for punctuation.
+    pub end_time: Option<String>,
+    /// `pronunciation` for words, `punctuation` for punctuation marks.
+    #[serde(rename = "type", default)]
+    pub item_type: String,
+    /// The candidate transcriptions of the item.
+    #[serde(default)]
+    pub alternatives: Vec<TranscriptAlternative>,
+}
+
+/// A candidate transcription of a word or punctuation mark.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TranscriptAlternative {
+    /// The confidence of the transcription, between 0 and 1.
+    #[serde(default)]
+    pub confidence: String,
+    /// The transcribed word or punctuation mark.
+    #[serde(default)]
+    pub content: String,
+}
+
+/// An error describing a transcription job or fetching its transcript.
+#[derive(Debug, PartialEq)]
+pub enum TranscriptionError {
+    /// Describing the job failed.
+    GetTranscriptionJob(RusotoError<GetTranscriptionJobError>),
+    /// The transcript of the completed job is not in an S3 bucket.
+    ///
+    /// Transcripts are only fetched from the output bucket of the job, so jobs must be
+    /// started with an `output_bucket_name`.
+    TranscriptLocation(String),
+    /// Fetching the transcript from S3 failed.
+    GetObject(RusotoError<GetObjectError>),
+    /// Reading the transcript from S3 failed.
+    Read(String),
+    /// The transcript is not valid.
+    Parse(String),
+}
+
+impl fmt::Display for TranscriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TranscriptionError::GetTranscriptionJob(ref error) => {
+                write!(f, "failed to describe transcription job: {}", error)
+            }
+            TranscriptionError::TranscriptLocation(ref uri) => {
+                write!(f, "transcript {} is not in an S3 bucket", uri)
+            }
+            TranscriptionError::GetObject(ref error) => {
+                write!(f, "failed to fetch transcript: {}", error)
+            }
+            TranscriptionError::Read(ref error) => {
+                write!(f, "failed to read transcript: {}", error)
+            }
+            TranscriptionError::Parse(ref error) => write!(f, "invalid transcript: {}", error),
+        }
+    }
+}
+
+impl Error for TranscriptionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TranscriptionError::GetTranscriptionJob(ref error) => Some(error),
+            TranscriptionError::GetObject(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Transcription jobs, fetching their transcripts from S3 with the given client.
+#[derive(Clone)]
+pub struct TranscriptionJobs<C = TranscribeClient, S = S3Client> {
+    transcribe: C,
+    s3: S,
+}
+
+impl<C, S> TranscriptionJobs<C, S>
+where
+    C: Transcribe + Clone + Send + Sync + 'static,
+    S: S3 + Clone + Send + Sync + 'static,
+{
+    /// Runs jobs with `transcribe`, fetching transcripts with `s3`.
+    pub fn new(transcribe: C, s3: S) -> TranscriptionJobs<C, S> {
+        TranscriptionJobs { transcribe, s3 }
+    }
+
+    /// Starts a transcription job.
+    ///
+    /// The job should have an `output_bucket_name` readable with the S3 client, as the
+    /// transcripts stored by the service itself can only be fetched through a pre-signed URL.
+    pub fn start(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> Box<
+        dyn Future<Item = JobHandle<Self>, Error = RusotoError<StartTranscriptionJobError>> + Send,
+    > {
+        let jobs = self.clone();
+        let job_name = input.transcription_job_name.clone();
+        Box::new(
+            self.transcribe
+                .start_transcription_job(input)
+                .map(move |_| JobHandle::new(jobs, job_name)),
+        )
+    }
+
+    /// The job named `job_name`, to wait for a job started elsewhere.
+    pub fn job(&self, job_name: &str) -> JobHandle<Self> {
+        JobHandle::new(self.clone(), job_name)
+    }
+
+    fn fetch_transcript(
+        &self,
+        job: TranscriptionJob,
+    ) -> Box<dyn Future<Item = TranscriptDocument, Error = TranscriptionError> + Send> {
+        let uri = job
+            .transcript
+            .and_then(|transcript| transcript.transcript_file_uri)
+            .unwrap_or_default();
+        let (bucket, key) = match s3_location(&uri) {
+            Some(location) => location,
+            None => {
+                return Box::new(futures::future::err(
+                    TranscriptionError::TranscriptLocation(uri),
+                ))
+            }
+        };
+
+        let request = GetObjectRequest {
+            bucket,
+            key,
+            ..Default::default()
+        };
+        Box::new(
+            self.s3
+                .get_object(request)
+                .map_err(TranscriptionError::GetObject)
+                .and_then(|output| {
+                    let body = output.body.ok_or_else(|| {
+                        TranscriptionError::Read("the object has no body".to_owned())
+                    });
+                    futures::future::result(body).and_then(|body| {
+                        body.concat2()
+                            .map_err(|error: io::Error| TranscriptionError::Read(error.to_string()))
+                    })
+                })
+                .and_then(|body| {
+                    serde_json::from_slice(&body)
+                        .map_err(|error| TranscriptionError::Parse(error.to_string()))
+                }),
+        )
+    }
+}
+
+impl<C, S> AsyncJob for TranscriptionJobs<C, S>
+where
+    C: Transcribe + Clone + Send + Sync + 'static,
+    S: S3 + Clone + Send + Sync + 'static,
+{
+    type Output = TranscriptDocument;
+    type Error = TranscriptionError;
+
+    fn status(
+        &self,
+        job_id: &str,
+    ) -> Box<dyn Future<Item = JobStatus<TranscriptDocument>, Error = TranscriptionError> + Send>
+    {
+        let jobs = self.clone();
+        let request = GetTranscriptionJobRequest {
+            transcription_job_name: job_id.to_owned(),
+        };
+        Box::new(
+            self.transcribe
+                .get_transcription_job(request)
+                .map_err(TranscriptionError::GetTranscriptionJob)
+                .and_then(
+                    move |response| -> Box<dyn Future<Item = _, Error = _> + Send> {
+                        let job = response.transcription_job.unwrap_or_default();
+                        let status = job.transcription_job_status.clone().unwrap_or_default();
+                        match status.as_str() {
+                            "COMPLETED" => {
+                                Box::new(jobs.fetch_transcript(job).map(JobStatus::Succeeded))
+                            }
+                            "FAILED" => Box::new(futures::future::ok(JobStatus::Failed(
+                                job.failure_reason.unwrap_or_default(),
+                            ))),
+                            _ => Box::new(futures::future::ok(JobStatus::InProgress)),
+                        }
+                    },
+                ),
+        )
+    }
+}
+
+/// The bucket and key of an object from its path-style or virtual-hosted-style URL.
+pub(crate) fn s3_location(uri: &str) -> Option<(String, String)> {
+    let uri = uri.split('?').next().unwrap_or_default();
+    let rest = match uri.find("://") {
+        Some(index) => &uri[index + 3..],
+        None => return None,
+    };
+    let slash = rest.find('/')?;
+    let (host, path) = (&rest[..slash], &rest[slash + 1..]);
+
+    if host.starts_with("s3.") || host.starts_with("s3-") {
+        let slash = path.find('/')?;
+        let (bucket, key) = (&path[..slash], &path[slash + 1..]);
+        if bucket.is_empty() || key.is_empty() {
+            return None;
+        }
+        Some((bucket.to_owned(), decode_uri(key)))
+    } else {
+        let bucket_end = host.find(".s3.").or_else(|| host.find(".s3-"))?;
+        if path.is_empty() {
+            return None;
+        }
+        Some((host[..bucket_end].to_owned(), decode_uri(path)))
+    }
+}
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateVocabularyRequest {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
@@ -41,8 +43,44 @@ pub struct CreateVocabularyRequest {
     pub vocabulary_name: String,
 }
 
+impl Validate for CreateVocabularyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.phrases {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "phrases"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, ".+");
+            }
+        }
+        if let Some(ref value) = self.vocabulary_file_uri {
+            errors.check_length(
+                &field_path(path, "vocabulary_file_uri"),
+                value.chars().count(),
+                Some(1),
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "vocabulary_file_uri"),
+                &value,
+                "(s3://|http(s*)://).+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "vocabulary_name"),
+            self.vocabulary_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "vocabulary_name"),
+            &self.vocabulary_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateVocabularyResponse {
     /// <p>If the <code>VocabularyState</code> field is <code>FAILED</code>, this field contains information about why the job failed.</p>
     #[serde(rename = "FailureReason")]
@@ -67,28 +105,79 @@ pub struct CreateVocabularyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTranscriptionJobRequest {
     /// <p>The name of the transcription job to be deleted.</p>
     #[serde(rename = "TranscriptionJobName")]
     pub transcription_job_name: String,
 }
 
+impl Validate for DeleteTranscriptionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transcription_job_name"),
+            self.transcription_job_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "transcription_job_name"),
+            &self.transcription_job_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteVocabularyRequest {
     /// <p>The name of the vocabulary to delete. </p>
     #[serde(rename = "VocabularyName")]
     pub vocabulary_name: String,
 }
 
+impl Validate for DeleteVocabularyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "vocabulary_name"),
+            self.vocabulary_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "vocabulary_name"),
+            &self.vocabulary_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTranscriptionJobRequest {
     /// <p>The name of the job.</p>
     #[serde(rename = "TranscriptionJobName")]
     pub transcription_job_name: String,
 }
 
+impl Validate for GetTranscriptionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "transcription_job_name"),
+            self.transcription_job_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "transcription_job_name"),
+            &self.transcription_job_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTranscriptionJobResponse {
     /// <p>An object that contains the results of the transcription job.</p>
     #[serde(rename = "TranscriptionJob")]
@@ -97,14 +186,31 @@ pub struct GetTranscriptionJobResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetVocabularyRequest {
     /// <p>The name of the vocabulary to return information about. The name is case-sensitive.</p>
     #[serde(rename = "VocabularyName")]
     pub vocabulary_name: String,
 }
 
+impl Validate for GetVocabularyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "vocabulary_name"),
+            self.vocabulary_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "vocabulary_name"),
+            &self.vocabulary_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetVocabularyResponse {
     /// <p>The S3 location where the vocabulary is stored. Use this URI to get the contents of the vocabulary. The URI is available for a limited time.</p>
     #[serde(rename = "DownloadUri")]
@@ -133,6 +239,7 @@ pub struct GetVocabularyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTranscriptionJobsRequest {
     /// <p>When specified, the jobs returned in the list are limited to jobs whose name contains the specified string.</p>
     #[serde(rename = "JobNameContains")]
@@ -152,8 +259,38 @@ pub struct ListTranscriptionJobsRequest {
     pub status: Option<String>,
 }
 
+impl Validate for ListTranscriptionJobsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.job_name_contains {
+            errors.check_length(
+                &field_path(path, "job_name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(
+                &field_path(path, "job_name_contains"),
+                &value,
+                "^[0-9a-zA-Z._-]+",
+            );
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTranscriptionJobsResponse {
     /// <p>The <code>ListTranscriptionJobs</code> operation returns a page of jobs at a time. The maximum size of the page is set by the <code>MaxResults</code> parameter. If there are more jobs in the list than the page size, Amazon Transcribe returns the <code>NextPage</code> token. Include the token in the next request to the <code>ListTranscriptionJobs</code> operation to return in the next page of jobs.</p>
     #[serde(rename = "NextToken")]
@@ -170,6 +307,7 @@ pub struct ListTranscriptionJobsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListVocabulariesRequest {
     /// <p>The maximum number of vocabularies to return in the response. If there are fewer results in the list, this response contains only the actual results.</p>
     #[serde(rename = "MaxResults")]
@@ -189,8 +327,38 @@ pub struct ListVocabulariesRequest {
     pub state_equals: Option<String>,
 }
 
+impl Validate for ListVocabulariesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.name_contains {
+            errors.check_length(
+                &field_path(path, "name_contains"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(
+                &field_path(path, "name_contains"),
+                &value,
+                "^[0-9a-zA-Z._-]+",
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, ".+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListVocabulariesResponse {
     /// <p>The <code>ListVocabularies</code> operation returns a page of vocabularies at a time. The maximum size of the page is set by the <code>MaxResults</code> parameter. If there are more jobs in the list than the page size, Amazon Transcribe returns the <code>NextPage</code> token. Include the token in the next request to the <code>ListVocabularies</code> operation to return in the next page of jobs.</p>
     #[serde(rename = "NextToken")]
@@ -215,6 +383,24 @@ pub struct Media {
     pub media_file_uri: Option<String>,
 }
 
+impl Validate for Media {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.media_file_uri {
+            errors.check_length(
+                &field_path(path, "media_file_uri"),
+                value.chars().count(),
+                Some(1),
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "media_file_uri"),
+                &value,
+                "(s3://|http(s*)://).+",
+            );
+        }
+    }
+}
+
 /// <p>Provides optional settings for the <code>StartTranscriptionJob</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Settings {
@@ -236,7 +422,34 @@ pub struct Settings {
     pub vocabulary_name: Option<String>,
 }
 
+impl Validate for Settings {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_speaker_labels {
+            errors.check_range(
+                &field_path(path, "max_speaker_labels"),
+                *value,
+                Some(2),
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.vocabulary_name {
+            errors.check_length(
+                &field_path(path, "vocabulary_name"),
+                value.chars().count(),
+                Some(1),
+                Some(200),
+            );
+            errors.check_pattern(
+                &field_path(path, "vocabulary_name"),
+                &value,
+                "^[0-9a-zA-Z._-]+",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartTranscriptionJobRequest {
     /// <p>The language code for the language used in the input media file.</p>
     #[serde(rename = "LanguageCode")]
@@ -265,8 +478,49 @@ pub struct StartTranscriptionJobRequest {
     pub transcription_job_name: String,
 }
 
+impl Validate for StartTranscriptionJobRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.media.validate_at(&field_path(path, "media"), errors);
+        if let Some(ref value) = self.media_sample_rate_hertz {
+            errors.check_range(
+                &field_path(path, "media_sample_rate_hertz"),
+                *value,
+                Some(8000),
+                Some(48000),
+            );
+        }
+        if let Some(ref value) = self.output_bucket_name {
+            errors.check_length(
+                &field_path(path, "output_bucket_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+            errors.check_pattern(
+                &field_path(path, "output_bucket_name"),
+                &value,
+                "[a-z0-9][\\.\\-a-z0-9]{1,61}[a-z0-9]",
+            );
+        }
+        if let Some(ref value) = self.settings {
+            value.validate_at(&field_path(path, "settings"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "transcription_job_name"),
+            self.transcription_job_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "transcription_job_name"),
+            &self.transcription_job_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartTranscriptionJobResponse {
     /// <p>An object containing details of the asynchronous transcription job.</p>
     #[serde(rename = "TranscriptionJob")]
@@ -276,7 +530,7 @@ pub struct StartTranscriptionJobResponse {
 
 /// <p>Identifies the location of a transcription.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Transcript {
     /// <p>The location where the transcription is stored.</p> <p>Use this URI to access the transcription. If you specified an S3 bucket in the <code>OutputBucketName</code> field when you created the job, this is the URI of that bucket. If you chose to store the transcription in Amazon Transcribe, this is a shareable URL that provides secure access to that location.</p>
     #[serde(rename = "TranscriptFileUri")]
@@ -286,7 +540,7 @@ pub struct Transcript {
 
 /// <p>Describes an asynchronous transcription job that was created with the <code>StartTranscriptionJob</code> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TranscriptionJob {
     /// <p>A timestamp that shows when the job was completed.</p>
     #[serde(rename = "CompletionTime")]
@@ -336,7 +590,7 @@ pub struct TranscriptionJob {
 
 /// <p>Provides a summary of information about a transcription job. .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TranscriptionJobSummary {
     /// <p>A timestamp that shows when the job was completed.</p>
     #[serde(rename = "CompletionTime")]
@@ -369,6 +623,7 @@ pub struct TranscriptionJobSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateVocabularyRequest {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
@@ -386,8 +641,44 @@ pub struct UpdateVocabularyRequest {
     pub vocabulary_name: String,
 }
 
+impl Validate for UpdateVocabularyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.phrases {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "phrases"), i);
+                errors.check_length(&item_path, item.chars().count(), None, Some(256));
+                errors.check_pattern(&item_path, &item, ".+");
+            }
+        }
+        if let Some(ref value) = self.vocabulary_file_uri {
+            errors.check_length(
+                &field_path(path, "vocabulary_file_uri"),
+                value.chars().count(),
+                Some(1),
+                Some(2000),
+            );
+            errors.check_pattern(
+                &field_path(path, "vocabulary_file_uri"),
+                &value,
+                "(s3://|http(s*)://).+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "vocabulary_name"),
+            self.vocabulary_name.chars().count(),
+            Some(1),
+            Some(200),
+        );
+        errors.check_pattern(
+            &field_path(path, "vocabulary_name"),
+            &self.vocabulary_name,
+            "^[0-9a-zA-Z._-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateVocabularyResponse {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
@@ -409,7 +700,7 @@ pub struct UpdateVocabularyResponse {
 
 /// <p>Provides information about a custom vocabulary. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct VocabularyInfo {
     /// <p>The language code of the vocabulary entries.</p>
     #[serde(rename = "LanguageCode")]
@@ -458,7 +749,12 @@ impl CreateVocabularyError {
                 "LimitExceededException" => {
                     return RusotoError::Service(CreateVocabularyError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -508,7 +804,12 @@ impl DeleteTranscriptionJobError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -558,7 +859,12 @@ impl DeleteVocabularyError {
                 "NotFoundException" => {
                     return RusotoError::Service(DeleteVocabularyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -609,7 +915,12 @@ impl GetTranscriptionJobError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetTranscriptionJobError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -660,7 +971,12 @@ impl GetVocabularyError {
                 "NotFoundException" => {
                     return RusotoError::Service(GetVocabularyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -708,7 +1024,12 @@ impl ListTranscriptionJobsError {
                 "LimitExceededException" => {
                     return RusotoError::Service(ListTranscriptionJobsError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -753,7 +1074,12 @@ impl ListVocabulariesError {
                 "LimitExceededException" => {
                     return RusotoError::Service(ListVocabulariesError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -805,7 +1131,12 @@ impl StartTranscriptionJobError {
                 "LimitExceededException" => {
                     return RusotoError::Service(StartTranscriptionJobError::LimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -861,7 +1192,12 @@ impl UpdateVocabularyError {
                 "NotFoundException" => {
                     return RusotoError::Service(UpdateVocabularyError::NotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -940,6 +1276,175 @@ pub trait Transcribe {
         input: UpdateVocabularyRequest,
     ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError>;
 }
+const _: Option<&dyn Transcribe> = None;
+impl<'a, T: Transcribe + ?Sized> Transcribe for &'a T {
+    fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> RusotoFuture<CreateVocabularyResponse, CreateVocabularyError> {
+        (**self).create_vocabulary(input)
+    }
+    fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> RusotoFuture<(), DeleteTranscriptionJobError> {
+        (**self).delete_transcription_job(input)
+    }
+    fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> RusotoFuture<(), DeleteVocabularyError> {
+        (**self).delete_vocabulary(input)
+    }
+    fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> RusotoFuture<GetTranscriptionJobResponse, GetTranscriptionJobError> {
+        (**self).get_transcription_job(input)
+    }
+    fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> RusotoFuture<GetVocabularyResponse, GetVocabularyError> {
+        (**self).get_vocabulary(input)
+    }
+    fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> RusotoFuture<ListTranscriptionJobsResponse, ListTranscriptionJobsError> {
+        (**self).list_transcription_jobs(input)
+    }
+    fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> RusotoFuture<ListVocabulariesResponse, ListVocabulariesError> {
+        (**self).list_vocabularies(input)
+    }
+    fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> RusotoFuture<StartTranscriptionJobResponse, StartTranscriptionJobError> {
+        (**self).start_transcription_job(input)
+    }
+    fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError> {
+        (**self).update_vocabulary(input)
+    }
+}
+impl<T: Transcribe + ?Sized> Transcribe for Box<T> {
+    fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> RusotoFuture<CreateVocabularyResponse, CreateVocabularyError> {
+        (**self).create_vocabulary(input)
+    }
+    fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> RusotoFuture<(), DeleteTranscriptionJobError> {
+        (**self).delete_transcription_job(input)
+    }
+    fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> RusotoFuture<(), DeleteVocabularyError> {
+        (**self).delete_vocabulary(input)
+    }
+    fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> RusotoFuture<GetTranscriptionJobResponse, GetTranscriptionJobError> {
+        (**self).get_transcription_job(input)
+    }
+    fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> RusotoFuture<GetVocabularyResponse, GetVocabularyError> {
+        (**self).get_vocabulary(input)
+    }
+    fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> RusotoFuture<ListTranscriptionJobsResponse, ListTranscriptionJobsError> {
+        (**self).list_transcription_jobs(input)
+    }
+    fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> RusotoFuture<ListVocabulariesResponse, ListVocabulariesError> {
+        (**self).list_vocabularies(input)
+    }
+    fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> RusotoFuture<StartTranscriptionJobResponse, StartTranscriptionJobError> {
+        (**self).start_transcription_job(input)
+    }
+    fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError> {
+        (**self).update_vocabulary(input)
+    }
+}
+impl<T: Transcribe + ?Sized> Transcribe for ::std::sync::Arc<T> {
+    fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> RusotoFuture<CreateVocabularyResponse, CreateVocabularyError> {
+        (**self).create_vocabulary(input)
+    }
+    fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> RusotoFuture<(), DeleteTranscriptionJobError> {
+        (**self).delete_transcription_job(input)
+    }
+    fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> RusotoFuture<(), DeleteVocabularyError> {
+        (**self).delete_vocabulary(input)
+    }
+    fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> RusotoFuture<GetTranscriptionJobResponse, GetTranscriptionJobError> {
+        (**self).get_transcription_job(input)
+    }
+    fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> RusotoFuture<GetVocabularyResponse, GetVocabularyError> {
+        (**self).get_vocabulary(input)
+    }
+    fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> RusotoFuture<ListTranscriptionJobsResponse, ListTranscriptionJobsError> {
+        (**self).list_transcription_jobs(input)
+    }
+    fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> RusotoFuture<ListVocabulariesResponse, ListVocabulariesError> {
+        (**self).list_vocabularies(input)
+    }
+    fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> RusotoFuture<StartTranscriptionJobResponse, StartTranscriptionJobError> {
+        (**self).start_transcription_job(input)
+    }
+    fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError> {
+        (**self).update_vocabulary(input)
+    }
+}
 /// A client for the Amazon Transcribe Service API.
 #[derive(Clone)]
 pub struct TranscribeClient {
@@ -983,7 +1488,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: CreateVocabularyRequest,
     ) -> RusotoFuture<CreateVocabularyResponse, CreateVocabularyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("CreateVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.CreateVocabulary");
@@ -992,10 +1506,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateVocabularyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<CreateVocabularyResponse, _>(
+                    response,
+                    "CreateVocabulary",
+                )
             } else {
                 Box::new(
                     response
@@ -1012,7 +1526,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: DeleteTranscriptionJobRequest,
     ) -> RusotoFuture<(), DeleteTranscriptionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteTranscriptionJob");
@@ -1037,7 +1560,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: DeleteVocabularyRequest,
     ) -> RusotoFuture<(), DeleteVocabularyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("DeleteVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.DeleteVocabulary");
@@ -1063,7 +1595,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: GetTranscriptionJobRequest,
     ) -> RusotoFuture<GetTranscriptionJobResponse, GetTranscriptionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetTranscriptionJob");
@@ -1072,10 +1613,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTranscriptionJobResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetTranscriptionJobResponse, _>(
+                    response,
+                    "GetTranscriptionJob",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1091,7 +1632,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: GetVocabularyRequest,
     ) -> RusotoFuture<GetVocabularyResponse, GetVocabularyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("GetVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.GetVocabulary");
@@ -1100,10 +1650,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetVocabularyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetVocabularyResponse, _>(
+                    response,
+                    "GetVocabulary",
+                )
             } else {
                 Box::new(
                     response
@@ -1120,7 +1670,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: ListTranscriptionJobsRequest,
     ) -> RusotoFuture<ListTranscriptionJobsResponse, ListTranscriptionJobsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListTranscriptionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListTranscriptionJobs");
@@ -1129,10 +1688,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTranscriptionJobsResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTranscriptionJobsResponse, _>(
+                    response,
+                    "ListTranscriptionJobs",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1148,7 +1707,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: ListVocabulariesRequest,
     ) -> RusotoFuture<ListVocabulariesResponse, ListVocabulariesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("ListVocabularies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.ListVocabularies");
@@ -1157,10 +1725,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListVocabulariesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListVocabulariesResponse, _>(
+                    response,
+                    "ListVocabularies",
+                )
             } else {
                 Box::new(
                     response
@@ -1177,7 +1745,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: StartTranscriptionJobRequest,
     ) -> RusotoFuture<StartTranscriptionJobResponse, StartTranscriptionJobError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("StartTranscriptionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.StartTranscriptionJob");
@@ -1186,10 +1763,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartTranscriptionJobResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<StartTranscriptionJobResponse, _>(
+                    response,
+                    "StartTranscriptionJob",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1205,7 +1782,16 @@ impl Transcribe for TranscribeClient {
         &self,
         input: UpdateVocabularyRequest,
     ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transcribe", &self.region, "/");
+        request.set_operation("UpdateVocabulary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Transcribe.UpdateVocabulary");
@@ -1214,10 +1800,10 @@ impl Transcribe for TranscribeClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateVocabularyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateVocabularyResponse, _>(
+                    response,
+                    "UpdateVocabulary",
+                )
             } else {
                 Box::new(
                     response
@@ -1229,3 +1815,331 @@ impl Transcribe for TranscribeClient {
         })
     }
 }
+
+/// A mock of the Amazon Transcribe Service API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockTranscribe {
+    create_vocabulary: Option<
+        Box<
+            dyn Fn(
+                    CreateVocabularyRequest,
+                ) -> ::std::result::Result<
+                    CreateVocabularyResponse,
+                    RusotoError<CreateVocabularyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_transcription_job: Option<
+        Box<
+            dyn Fn(
+                    DeleteTranscriptionJobRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteTranscriptionJobError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_vocabulary: Option<
+        Box<
+            dyn Fn(
+                    DeleteVocabularyRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteVocabularyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_transcription_job: Option<
+        Box<
+            dyn Fn(
+                    GetTranscriptionJobRequest,
+                ) -> ::std::result::Result<
+                    GetTranscriptionJobResponse,
+                    RusotoError<GetTranscriptionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_vocabulary: Option<
+        Box<
+            dyn Fn(
+                    GetVocabularyRequest,
+                )
+                    -> ::std::result::Result<GetVocabularyResponse, RusotoError<GetVocabularyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_transcription_jobs: Option<
+        Box<
+            dyn Fn(
+                    ListTranscriptionJobsRequest,
+                ) -> ::std::result::Result<
+                    ListTranscriptionJobsResponse,
+                    RusotoError<ListTranscriptionJobsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_vocabularies: Option<
+        Box<
+            dyn Fn(
+                    ListVocabulariesRequest,
+                ) -> ::std::result::Result<
+                    ListVocabulariesResponse,
+                    RusotoError<ListVocabulariesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    start_transcription_job: Option<
+        Box<
+            dyn Fn(
+                    StartTranscriptionJobRequest,
+                ) -> ::std::result::Result<
+                    StartTranscriptionJobResponse,
+                    RusotoError<StartTranscriptionJobError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_vocabulary: Option<
+        Box<
+            dyn Fn(
+                    UpdateVocabularyRequest,
+                ) -> ::std::result::Result<
+                    UpdateVocabularyResponse,
+                    RusotoError<UpdateVocabularyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockTranscribe {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockTranscribe {
+        Default::default()
+    }
+
+    /// Answers `create_vocabulary` with what `handler` returns for its input.
+    pub fn with_create_vocabulary<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                CreateVocabularyRequest,
+            ) -> ::std::result::Result<
+                CreateVocabularyResponse,
+                RusotoError<CreateVocabularyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_vocabulary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_transcription_job` with what `handler` returns for its input.
+    pub fn with_delete_transcription_job<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                DeleteTranscriptionJobRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteTranscriptionJobError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_transcription_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_vocabulary` with what `handler` returns for its input.
+    pub fn with_delete_vocabulary<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                DeleteVocabularyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteVocabularyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_vocabulary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_transcription_job` with what `handler` returns for its input.
+    pub fn with_get_transcription_job<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                GetTranscriptionJobRequest,
+            ) -> ::std::result::Result<
+                GetTranscriptionJobResponse,
+                RusotoError<GetTranscriptionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_transcription_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_vocabulary` with what `handler` returns for its input.
+    pub fn with_get_vocabulary<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                GetVocabularyRequest,
+            )
+                -> ::std::result::Result<GetVocabularyResponse, RusotoError<GetVocabularyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_vocabulary = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_transcription_jobs` with what `handler` returns for its input.
+    pub fn with_list_transcription_jobs<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                ListTranscriptionJobsRequest,
+            ) -> ::std::result::Result<
+                ListTranscriptionJobsResponse,
+                RusotoError<ListTranscriptionJobsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_transcription_jobs = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_vocabularies` with what `handler` returns for its input.
+    pub fn with_list_vocabularies<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                ListVocabulariesRequest,
+            ) -> ::std::result::Result<
+                ListVocabulariesResponse,
+                RusotoError<ListVocabulariesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_vocabularies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_transcription_job` with what `handler` returns for its input.
+    pub fn with_start_transcription_job<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                StartTranscriptionJobRequest,
+            ) -> ::std::result::Result<
+                StartTranscriptionJobResponse,
+                RusotoError<StartTranscriptionJobError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.start_transcription_job = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_vocabulary` with what `handler` returns for its input.
+    pub fn with_update_vocabulary<F>(mut self, handler: F) -> MockTranscribe
+    where
+        F: Fn(
+                UpdateVocabularyRequest,
+            ) -> ::std::result::Result<
+                UpdateVocabularyResponse,
+                RusotoError<UpdateVocabularyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_vocabulary = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Transcribe for MockTranscribe {
+    fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> RusotoFuture<CreateVocabularyResponse, CreateVocabularyError> {
+        match self.create_vocabulary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for create_vocabulary"),
+        }
+    }
+    fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> RusotoFuture<(), DeleteTranscriptionJobError> {
+        match self.delete_transcription_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for delete_transcription_job"),
+        }
+    }
+    fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> RusotoFuture<(), DeleteVocabularyError> {
+        match self.delete_vocabulary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for delete_vocabulary"),
+        }
+    }
+    fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> RusotoFuture<GetTranscriptionJobResponse, GetTranscriptionJobError> {
+        match self.get_transcription_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for get_transcription_job"),
+        }
+    }
+    fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> RusotoFuture<GetVocabularyResponse, GetVocabularyError> {
+        match self.get_vocabulary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for get_vocabulary"),
+        }
+    }
+    fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> RusotoFuture<ListTranscriptionJobsResponse, ListTranscriptionJobsError> {
+        match self.list_transcription_jobs {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for list_transcription_jobs"),
+        }
+    }
+    fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> RusotoFuture<ListVocabulariesResponse, ListVocabulariesError> {
+        match self.list_vocabularies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for list_vocabularies"),
+        }
+    }
+    fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> RusotoFuture<StartTranscriptionJobResponse, StartTranscriptionJobError> {
+        match self.start_transcription_job {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for start_transcription_job"),
+        }
+    }
+    fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> RusotoFuture<UpdateVocabularyResponse, UpdateVocabularyError> {
+        match self.update_vocabulary {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranscribe has no handler for update_vocabulary"),
+        }
+    }
+}
@@ -19,6 +19,7 @@
 extern crate bytes;
 extern crate futures;
 extern crate rusoto_core;
+extern crate rusoto_s3;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateServerRequest {
     /// <p>The virtual private cloud (VPC) endpoint settings that you want to configure for your SFTP server. This parameter is required when you specify a value for the <code>EndpointType</code> parameter.</p>
     #[serde(rename = "EndpointDetails")]
@@ -55,8 +57,47 @@ pub struct CreateServerRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.endpoint_details {
+            value.validate_at(&field_path(path, "endpoint_details"), errors);
+        }
+        if let Some(ref value) = self.host_key {
+            errors.check_length(
+                &field_path(path, "host_key"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.identity_provider_details {
+            value.validate_at(&field_path(path, "identity_provider_details"), errors);
+        }
+        if let Some(ref value) = self.logging_role {
+            errors.check_length(
+                &field_path(path, "logging_role"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "logging_role"),
+                &value,
+                "(|arn:.*role/\\S+)",
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateServerResponse {
     /// <p>The service-assigned ID of the SFTP server that is created.</p>
     #[serde(rename = "ServerId")]
@@ -64,6 +105,7 @@ pub struct CreateServerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>The landing directory (folder) for a user when they log in to the server using their SFTP client. An example is <code>/home/<i>username</i> </code>.</p>
     #[serde(rename = "HomeDirectory")]
@@ -92,8 +134,74 @@ pub struct CreateUserRequest {
     pub user_name: String,
 }
 
+impl Validate for CreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.home_directory {
+            errors.check_length(
+                &field_path(path, "home_directory"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "home_directory"), &value, "(|/.*)");
+        }
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "role"),
+            self.role.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_pattern(&field_path(path, "role"), &self.role, "arn:.*role/\\S+");
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        if let Some(ref value) = self.ssh_public_key_body {
+            errors.check_length(
+                &field_path(path, "ssh_public_key_body"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), Some(50));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {
     /// <p>The ID of the SFTP server that the user is attached to.</p>
     #[serde(rename = "ServerId")]
@@ -104,13 +212,31 @@ pub struct CreateUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteServerRequest {
     /// <p>A unique system-assigned identifier for an SFTP server instance.</p>
     #[serde(rename = "ServerId")]
     pub server_id: String,
 }
 
+impl Validate for DeleteServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSshPublicKeyRequest {
     /// <p>A system-assigned unique identifier for a Secure File Transfer Protocol (SFTP) server instance that has the user assigned to it.</p>
     #[serde(rename = "ServerId")]
@@ -123,7 +249,46 @@ pub struct DeleteSshPublicKeyRequest {
     pub user_name: String,
 }
 
+impl Validate for DeleteSshPublicKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        errors.check_length(
+            &field_path(path, "ssh_public_key_id"),
+            self.ssh_public_key_id.chars().count(),
+            Some(21),
+            Some(21),
+        );
+        errors.check_pattern(
+            &field_path(path, "ssh_public_key_id"),
+            &self.ssh_public_key_id,
+            "key-[0-9a-f]{17}",
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>A system-assigned unique identifier for an SFTP server instance that has the user assigned to it.</p>
     #[serde(rename = "ServerId")]
@@ -133,15 +298,59 @@ pub struct DeleteUserRequest {
     pub user_name: String,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeServerRequest {
     /// <p>A system-assigned unique identifier for an SFTP server.</p>
     #[serde(rename = "ServerId")]
     pub server_id: String,
 }
 
+impl Validate for DescribeServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeServerResponse {
     /// <p>An array containing the properties of the server with the <code>ServerID</code> you specified.</p>
     #[serde(rename = "Server")]
@@ -149,6 +358,7 @@ pub struct DescribeServerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserRequest {
     /// <p>A system-assigned unique identifier for an SFTP server that has this user assigned.</p>
     #[serde(rename = "ServerId")]
@@ -158,8 +368,35 @@ pub struct DescribeUserRequest {
     pub user_name: String,
 }
 
+impl Validate for DescribeUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserResponse {
     /// <p>A system-assigned unique identifier for an SFTP server that has this user assigned.</p>
     #[serde(rename = "ServerId")]
@@ -171,7 +408,7 @@ pub struct DescribeUserResponse {
 
 /// <p>Describes the properties of the server that was specified. Information returned includes the following: the server Amazon Resource Name (ARN), the authentication configuration and type, the logging role, the server ID and state, and assigned tags or metadata. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribedServer {
     /// <p>Specifies the unique Amazon Resource Name (ARN) for the server to be described.</p>
     #[serde(rename = "Arn")]
@@ -220,7 +457,7 @@ pub struct DescribedServer {
 
 /// <p>Returns properties of the user that you want to describe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribedUser {
     /// <p>This property contains the unique Amazon Resource Name (ARN) for the user that was requested to be described.</p>
     #[serde(rename = "Arn")]
@@ -260,6 +497,24 @@ pub struct EndpointDetails {
     pub vpc_endpoint_id: Option<String>,
 }
 
+impl Validate for EndpointDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.vpc_endpoint_id {
+            errors.check_length(
+                &field_path(path, "vpc_endpoint_id"),
+                value.chars().count(),
+                Some(22),
+                Some(22),
+            );
+            errors.check_pattern(
+                &field_path(path, "vpc_endpoint_id"),
+                &value,
+                "vpce-[0-9a-f]{17}",
+            );
+        }
+    }
+}
+
 /// <p>Returns information related to the type of user authentication that is in use for a server's users. A server can have only one method of authentication.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IdentityProviderDetails {
@@ -273,7 +528,34 @@ pub struct IdentityProviderDetails {
     pub url: Option<String>,
 }
 
+impl Validate for IdentityProviderDetails {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.invocation_role {
+            errors.check_length(
+                &field_path(path, "invocation_role"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "invocation_role"),
+                &value,
+                "arn:.*role/\\S+",
+            );
+        }
+        if let Some(ref value) = self.url {
+            errors.check_length(
+                &field_path(path, "url"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportSshPublicKeyRequest {
     /// <p>A system-assigned unique identifier for an SFTP server.</p>
     #[serde(rename = "ServerId")]
@@ -286,9 +568,42 @@ pub struct ImportSshPublicKeyRequest {
     pub user_name: String,
 }
 
+impl Validate for ImportSshPublicKeyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        errors.check_length(
+            &field_path(path, "ssh_public_key_body"),
+            self.ssh_public_key_body.chars().count(),
+            None,
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+    }
+}
+
 /// <p>This response identifies the user, the server they belong to, and the identifier of the SSH public key associated with that user. A user can have more than one key on each server that they are associated with.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportSshPublicKeyResponse {
     /// <p>A system-assigned unique identifier for an SFTP server.</p>
     #[serde(rename = "ServerId")]
@@ -302,6 +617,7 @@ pub struct ImportSshPublicKeyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListServersRequest {
     /// <p>Specifies the number of servers to return as a response to the <code>ListServers</code> query.</p>
     #[serde(rename = "MaxResults")]
@@ -313,8 +629,29 @@ pub struct ListServersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListServersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(6144),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListServersResponse {
     /// <p>When you can get additional results from the <code>ListServers</code> operation, a <code>NextToken</code> parameter is returned in the output. In a following command, you can pass in the <code>NextToken</code> parameter to continue listing additional servers.</p>
     #[serde(rename = "NextToken")]
@@ -326,6 +663,7 @@ pub struct ListServersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     /// <p>Requests the tags associated with a particular Amazon Resource Name (ARN). An ARN is an identifier for a specific AWS resource, such as a server, user, or role.</p>
     #[serde(rename = "Arn")]
@@ -340,8 +678,36 @@ pub struct ListTagsForResourceRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:\\S+");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(6144),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     /// <p>This value is the ARN you specified to list the tags of.</p>
     #[serde(rename = "Arn")]
@@ -358,6 +724,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersRequest {
     /// <p>Specifies the number of users to return as a response to the <code>ListUsers</code> request.</p>
     #[serde(rename = "MaxResults")]
@@ -372,8 +739,40 @@ pub struct ListUsersRequest {
     pub server_id: String,
 }
 
+impl Validate for ListUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(
+                &field_path(path, "max_results"),
+                *value,
+                Some(1),
+                Some(1000),
+            );
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(6144),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersResponse {
     /// <p>When you can get additional results from the <code>ListUsers</code> call, a <code>NextToken</code> parameter is returned in the output. You can then pass in a subsequent command to the <code>NextToken</code> parameter to continue listing additional users.</p>
     #[serde(rename = "NextToken")]
@@ -389,7 +788,7 @@ pub struct ListUsersResponse {
 
 /// <p>Returns properties of the server that was specified.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListedServer {
     /// <p>The unique Amazon Resource Name (ARN) for the server to be listed.</p>
     #[serde(rename = "Arn")]
@@ -422,7 +821,7 @@ pub struct ListedServer {
 
 /// <p>Returns properties of the user that you specify.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListedUser {
     /// <p>This property is the unique Amazon Resource Name (ARN) for the user that you want to learn about.</p>
     #[serde(rename = "Arn")]
@@ -447,7 +846,7 @@ pub struct ListedUser {
 
 /// <p>Provides information about the public Secure Shell (SSH) key that is associated with a user account for a specific server (as identified by <code>ServerId</code>). The information returned includes the date the key was imported, the public key contents, and the public key ID. A user can store more than one SSH public key associated with their user name on a specific SFTP server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SshPublicKey {
     /// <p>The date that the public key was added to the user account.</p>
     #[serde(rename = "DateImported")]
@@ -461,19 +860,53 @@ pub struct SshPublicKey {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartServerRequest {
     /// <p>A system-assigned unique identifier for an SFTP server that you start.</p>
     #[serde(rename = "ServerId")]
     pub server_id: String,
 }
 
+impl Validate for StartServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopServerRequest {
     /// <p>A system-assigned unique identifier for an SFTP server that you stopped.</p>
     #[serde(rename = "ServerId")]
     pub server_id: String,
 }
 
+impl Validate for StopServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+    }
+}
+
 /// <p>Creates a key-value pair for a specific resource. Tags are metadata that you can use to search for and group a resource for various purposes. You can apply tags to servers, users, and roles. A tag key can take more than one value. For example, to group servers for accounting purposes, you might create a tag called <code>Group</code> and assign the values <code>Research</code> and <code>Accounting</code> to that group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -485,7 +918,25 @@ pub struct Tag {
     pub value: String,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            None,
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     /// <p>An Amazon Resource Name (ARN) for a specific AWS resource, such as a server, user, or role.</p>
     #[serde(rename = "Arn")]
@@ -495,7 +946,30 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:\\S+");
+        errors.check_length(
+            &field_path(path, "tags"),
+            self.tags.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TestIdentityProviderRequest {
     /// <p>A system-assigned identifier for a specific server. That server's user authentication method is tested with a user name and password.</p>
     #[serde(rename = "ServerId")]
@@ -509,8 +983,43 @@ pub struct TestIdentityProviderRequest {
     pub user_password: Option<String>,
 }
 
+impl Validate for TestIdentityProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+        if let Some(ref value) = self.user_password {
+            errors.check_length(
+                &field_path(path, "user_password"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TestIdentityProviderResponse {
     /// <p>A message that indicates whether the test was successful or not. </p>
     #[serde(rename = "Message")]
@@ -529,6 +1038,7 @@ pub struct TestIdentityProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     /// <p>This is the value of the resource that will have the tag removed. An Amazon Resource Name (ARN) is an identifier for a specific AWS resource, such as a server, user, or role.</p>
     #[serde(rename = "Arn")]
@@ -538,7 +1048,30 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "arn"),
+            self.arn.chars().count(),
+            Some(20),
+            Some(1600),
+        );
+        errors.check_pattern(&field_path(path, "arn"), &self.arn, "arn:\\S+");
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            Some(50),
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), None, Some(128));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateServerRequest {
     /// <p>The virtual private cloud (VPC) endpoint settings that are configured for your SFTP server. With a VPC endpoint, your SFTP server isn't accessible over the public internet.</p>
     #[serde(rename = "EndpointDetails")]
@@ -565,8 +1098,51 @@ pub struct UpdateServerRequest {
     pub server_id: String,
 }
 
+impl Validate for UpdateServerRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.endpoint_details {
+            value.validate_at(&field_path(path, "endpoint_details"), errors);
+        }
+        if let Some(ref value) = self.host_key {
+            errors.check_length(
+                &field_path(path, "host_key"),
+                value.chars().count(),
+                None,
+                Some(4096),
+            );
+        }
+        if let Some(ref value) = self.identity_provider_details {
+            value.validate_at(&field_path(path, "identity_provider_details"), errors);
+        }
+        if let Some(ref value) = self.logging_role {
+            errors.check_length(
+                &field_path(path, "logging_role"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+            errors.check_pattern(
+                &field_path(path, "logging_role"),
+                &value,
+                "(|arn:.*role/\\S+)",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateServerResponse {
     /// <p>A system-assigned unique identifier for an SFTP server that the user account is assigned to.</p>
     #[serde(rename = "ServerId")]
@@ -574,6 +1150,7 @@ pub struct UpdateServerResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserRequest {
     /// <p>A parameter that specifies the landing directory (folder) for a user when they log in to the server using their client. An example is <code>/home/<i>username</i> </code>.</p>
     #[serde(rename = "HomeDirectory")]
@@ -595,9 +1172,62 @@ pub struct UpdateUserRequest {
     pub user_name: String,
 }
 
+impl Validate for UpdateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.home_directory {
+            errors.check_length(
+                &field_path(path, "home_directory"),
+                value.chars().count(),
+                None,
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "home_directory"), &value, "(|/.*)");
+        }
+        if let Some(ref value) = self.policy {
+            errors.check_length(
+                &field_path(path, "policy"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.role {
+            errors.check_length(
+                &field_path(path, "role"),
+                value.chars().count(),
+                Some(20),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "role"), &value, "arn:.*role/\\S+");
+        }
+        errors.check_length(
+            &field_path(path, "server_id"),
+            self.server_id.chars().count(),
+            Some(19),
+            Some(19),
+        );
+        errors.check_pattern(
+            &field_path(path, "server_id"),
+            &self.server_id,
+            "s-([0-9a-f]{17})",
+        );
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(3),
+            Some(100),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_name"),
+            &self.user_name,
+            "[\\w][\\w@.-]{2,99}",
+        );
+    }
+}
+
 /// <p> <code>UpdateUserResponse</code> returns the user name and server identifier for the request to update a user's properties.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserResponse {
     /// <p>A system-assigned unique identifier for an SFTP server instance that the user account is assigned to.</p>
     #[serde(rename = "ServerId")]
@@ -636,7 +1266,12 @@ impl CreateServerError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CreateServerError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -692,7 +1327,12 @@ impl CreateUserError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -744,7 +1384,12 @@ impl DeleteServerError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteServerError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -799,7 +1444,12 @@ impl DeleteSshPublicKeyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -850,7 +1500,12 @@ impl DeleteUserError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DeleteUserError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -901,7 +1556,12 @@ impl DescribeServerError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DescribeServerError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -952,7 +1612,12 @@ impl DescribeUserError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(DescribeUserError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1012,7 +1677,12 @@ impl ImportSshPublicKeyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1064,7 +1734,12 @@ impl ListServersError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListServersError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1121,7 +1796,12 @@ impl ListTagsForResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1177,7 +1857,12 @@ impl ListUsersError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1229,7 +1914,12 @@ impl StartServerError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(StartServerError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1280,7 +1970,12 @@ impl StopServerError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(StopServerError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1326,7 +2021,12 @@ impl TagResourceError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1382,7 +2082,12 @@ impl TestIdentityProviderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1428,7 +2133,12 @@ impl UntagResourceError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1478,7 +2188,12 @@ impl UpdateServerError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(UpdateServerError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1529,7 +2244,12 @@ impl UpdateUserError {
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1643,192 +2363,520 @@ pub trait Transfer {
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
 }
-/// A client for the AWS Transfer API.
-#[derive(Clone)]
-pub struct TransferClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl TransferClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> TransferClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> TransferClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> TransferClient {
-        TransferClient { client, region }
-    }
-}
-
-impl Transfer for TransferClient {
-    /// <p>Instantiates an autoscaling virtual server based on Secure File Transfer Protocol (SFTP) in AWS. When you make updates to your server or when you work with users, use the service-generated <code>ServerId</code> property that is assigned to the newly created server.</p>
+const _: Option<&dyn Transfer> = None;
+impl<'a, T: Transfer + ?Sized> Transfer for &'a T {
     fn create_server(
         &self,
         input: CreateServerRequest,
     ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
-        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TransferService.CreateServer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateServerResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateServerError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_server(input)
     }
-
-    /// <p>Creates a user and associates them with an existing Secure File Transfer Protocol (SFTP) server. You can only create and associate users with SFTP servers that have the <code>IdentityProviderType</code> set to <code>SERVICE_MANAGED</code>. Using parameters for <code>CreateUser</code>, you can specify the user name, set the home directory, store the user's public key, and assign the user's AWS Identity and Access Management (IAM) role. You can also optionally add a scope-down policy, and assign metadata with tags that can be used to group and search for users.</p>
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
-        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TransferService.CreateUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Deletes the Secure File Transfer Protocol (SFTP) server that you specify.</p> <p>No response returns from this operation.</p>
     fn delete_server(&self, input: DeleteServerRequest) -> RusotoFuture<(), DeleteServerError> {
-        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TransferService.DeleteServer");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteServerError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_server(input)
     }
-
-    /// <p>Deletes a user's Secure Shell (SSH) public key.</p> <p>No response is returned from this operation.</p>
     fn delete_ssh_public_key(
         &self,
         input: DeleteSshPublicKeyRequest,
     ) -> RusotoFuture<(), DeleteSshPublicKeyError> {
-        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TransferService.DeleteSshPublicKey");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteSshPublicKeyError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_ssh_public_key(input)
     }
-
-    /// <p><p>Deletes the user belonging to the server you specify.</p> <p>No response returns from this operation.</p> <note> <p>When you delete a user from a server, the user&#39;s information is lost.</p> </note></p>
     fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
-        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "TransferService.DeleteUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Describes the server that you specify by passing the <code>ServerId</code> parameter.</p> <p>The response contains a description of the server's properties.</p>
     fn describe_server(
         &self,
         input: DescribeServerRequest,
     ) -> RusotoFuture<DescribeServerResponse, DescribeServerError> {
-        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        (**self).describe_server(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> RusotoFuture<ImportSshPublicKeyResponse, ImportSshPublicKeyError> {
+        (**self).import_ssh_public_key(input)
+    }
+    fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> RusotoFuture<ListServersResponse, ListServersError> {
+        (**self).list_servers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_server(&self, input: StartServerRequest) -> RusotoFuture<(), StartServerError> {
+        (**self).start_server(input)
+    }
+    fn stop_server(&self, input: StopServerRequest) -> RusotoFuture<(), StopServerError> {
+        (**self).stop_server(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> RusotoFuture<TestIdentityProviderResponse, TestIdentityProviderError> {
+        (**self).test_identity_provider(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+impl<T: Transfer + ?Sized> Transfer for Box<T> {
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        (**self).create_server(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_server(&self, input: DeleteServerRequest) -> RusotoFuture<(), DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> RusotoFuture<(), DeleteSshPublicKeyError> {
+        (**self).delete_ssh_public_key(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> RusotoFuture<DescribeServerResponse, DescribeServerError> {
+        (**self).describe_server(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> RusotoFuture<ImportSshPublicKeyResponse, ImportSshPublicKeyError> {
+        (**self).import_ssh_public_key(input)
+    }
+    fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> RusotoFuture<ListServersResponse, ListServersError> {
+        (**self).list_servers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_server(&self, input: StartServerRequest) -> RusotoFuture<(), StartServerError> {
+        (**self).start_server(input)
+    }
+    fn stop_server(&self, input: StopServerRequest) -> RusotoFuture<(), StopServerError> {
+        (**self).stop_server(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> RusotoFuture<TestIdentityProviderResponse, TestIdentityProviderError> {
+        (**self).test_identity_provider(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+impl<T: Transfer + ?Sized> Transfer for ::std::sync::Arc<T> {
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        (**self).create_server(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_server(&self, input: DeleteServerRequest) -> RusotoFuture<(), DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> RusotoFuture<(), DeleteSshPublicKeyError> {
+        (**self).delete_ssh_public_key(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> RusotoFuture<DescribeServerResponse, DescribeServerError> {
+        (**self).describe_server(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> RusotoFuture<ImportSshPublicKeyResponse, ImportSshPublicKeyError> {
+        (**self).import_ssh_public_key(input)
+    }
+    fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> RusotoFuture<ListServersResponse, ListServersError> {
+        (**self).list_servers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_server(&self, input: StartServerRequest) -> RusotoFuture<(), StartServerError> {
+        (**self).start_server(input)
+    }
+    fn stop_server(&self, input: StopServerRequest) -> RusotoFuture<(), StopServerError> {
+        (**self).stop_server(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> RusotoFuture<TestIdentityProviderResponse, TestIdentityProviderError> {
+        (**self).test_identity_provider(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+/// A client for the AWS Transfer API.
+#[derive(Clone)]
+pub struct TransferClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl TransferClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> TransferClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TransferClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> TransferClient {
+        TransferClient { client, region }
+    }
+}
+
+impl Transfer for TransferClient {
+    /// <p>Instantiates an autoscaling virtual server based on Secure File Transfer Protocol (SFTP) in AWS. When you make updates to your server or when you work with users, use the service-generated <code>ServerId</code> property that is assigned to the newly created server.</p>
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("CreateServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TransferService.CreateServer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateServerResponse, _>(
+                    response,
+                    "CreateServer",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateServerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a user and associates them with an existing Secure File Transfer Protocol (SFTP) server. You can only create and associate users with SFTP servers that have the <code>IdentityProviderType</code> set to <code>SERVICE_MANAGED</code>. Using parameters for <code>CreateUser</code>, you can specify the user name, set the home directory, store the user's public key, and assign the user's AWS Identity and Access Management (IAM) role. You can also optionally add a scope-down policy, and assign metadata with tags that can be used to group and search for users.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("CreateUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TransferService.CreateUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserResponse, _>(response, "CreateUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the Secure File Transfer Protocol (SFTP) server that you specify.</p> <p>No response returns from this operation.</p>
+    fn delete_server(&self, input: DeleteServerRequest) -> RusotoFuture<(), DeleteServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TransferService.DeleteServer");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteServerError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a user's Secure Shell (SSH) public key.</p> <p>No response is returned from this operation.</p>
+    fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> RusotoFuture<(), DeleteSshPublicKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteSshPublicKey");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TransferService.DeleteSshPublicKey");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteSshPublicKeyError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Deletes the user belonging to the server you specify.</p> <p>No response returns from this operation.</p> <note> <p>When you delete a user from a server, the user&#39;s information is lost.</p> </note></p>
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DeleteUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "TransferService.DeleteUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(future::ok(::std::mem::drop(response)))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the server that you specify by passing the <code>ServerId</code> parameter.</p> <p>The response contains a description of the server's properties.</p>
+    fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> RusotoFuture<DescribeServerResponse, DescribeServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DescribeServer");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DescribeServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeServerResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeServerResponse, _>(
+                    response,
+                    "DescribeServer",
+                )
             } else {
                 Box::new(
                     response
@@ -1845,7 +2893,16 @@ impl Transfer for TransferClient {
         &self,
         input: DescribeUserRequest,
     ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("DescribeUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.DescribeUser");
@@ -1854,10 +2911,10 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeUserResponse, _>(
+                    response,
+                    "DescribeUser",
+                )
             } else {
                 Box::new(
                     response
@@ -1874,7 +2931,16 @@ impl Transfer for TransferClient {
         &self,
         input: ImportSshPublicKeyRequest,
     ) -> RusotoFuture<ImportSshPublicKeyResponse, ImportSshPublicKeyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ImportSshPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ImportSshPublicKey");
@@ -1883,10 +2949,10 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportSshPublicKeyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ImportSshPublicKeyResponse, _>(
+                    response,
+                    "ImportSshPublicKey",
+                )
             } else {
                 Box::new(
                     response
@@ -1903,7 +2969,16 @@ impl Transfer for TransferClient {
         &self,
         input: ListServersRequest,
     ) -> RusotoFuture<ListServersResponse, ListServersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListServers");
@@ -1912,10 +2987,10 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListServersResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListServersResponse, _>(
+                    response,
+                    "ListServers",
+                )
             } else {
                 Box::new(
                     response
@@ -1932,7 +3007,16 @@ impl Transfer for TransferClient {
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListTagsForResource");
@@ -1941,10 +3025,10 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -1960,7 +3044,16 @@ impl Transfer for TransferClient {
         &self,
         input: ListUsersRequest,
     ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("ListUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.ListUsers");
@@ -1969,10 +3062,7 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUsersResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListUsersResponse, _>(response, "ListUsers")
             } else {
                 Box::new(
                     response
@@ -1986,7 +3076,16 @@ impl Transfer for TransferClient {
 
     /// <p>Changes the state of a Secure File Transfer Protocol (SFTP) server from <code>OFFLINE</code> to <code>ONLINE</code>. It has no impact on an SFTP server that is already <code>ONLINE</code>. An <code>ONLINE</code> server can accept and process file transfer jobs.</p> <p>The state of <code>STARTING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully online. The values of <code>START_FAILED</code> can indicate an error condition. </p> <p>No response is returned from this call.</p>
     fn start_server(&self, input: StartServerRequest) -> RusotoFuture<(), StartServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("StartServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.StartServer");
@@ -2009,7 +3108,16 @@ impl Transfer for TransferClient {
 
     /// <p>Changes the state of an SFTP server from <code>ONLINE</code> to <code>OFFLINE</code>. An <code>OFFLINE</code> server cannot accept and process file transfer jobs. Information tied to your server such as server and user properties are not affected by stopping your server. Stopping a server will not reduce or impact your Secure File Transfer Protocol (SFTP) endpoint billing.</p> <p>The state of <code>STOPPING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>STOP_FAILED</code> can indicate an error condition.</p> <p>No response is returned from this call.</p>
     fn stop_server(&self, input: StopServerRequest) -> RusotoFuture<(), StopServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("StopServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.StopServer");
@@ -2032,7 +3140,16 @@ impl Transfer for TransferClient {
 
     /// <p>Attaches a key-value pair to a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>There is no response returned from this call.</p>
     fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.TagResource");
@@ -2058,7 +3175,16 @@ impl Transfer for TransferClient {
         &self,
         input: TestIdentityProviderRequest,
     ) -> RusotoFuture<TestIdentityProviderResponse, TestIdentityProviderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("TestIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.TestIdentityProvider");
@@ -2067,10 +3193,10 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TestIdentityProviderResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TestIdentityProviderResponse, _>(
+                    response,
+                    "TestIdentityProvider",
+                )
             } else {
                 Box::new(
                     response.buffer().from_err().and_then(|response| {
@@ -2083,7 +3209,16 @@ impl Transfer for TransferClient {
 
     /// <p>Detaches a key-value pair from a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>No response is returned from this call.</p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UntagResource");
@@ -2109,7 +3244,16 @@ impl Transfer for TransferClient {
         &self,
         input: UpdateServerRequest,
     ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UpdateServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UpdateServer");
@@ -2118,10 +3262,10 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateServerResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateServerResponse, _>(
+                    response,
+                    "UpdateServer",
+                )
             } else {
                 Box::new(
                     response
@@ -2138,7 +3282,16 @@ impl Transfer for TransferClient {
         &self,
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "transfer", &self.region, "/");
+        request.set_operation("UpdateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "TransferService.UpdateUser");
@@ -2147,10 +3300,7 @@ impl Transfer for TransferClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateUserResponse, _>(response, "UpdateUser")
             } else {
                 Box::new(
                     response
@@ -2162,3 +3312,572 @@ impl Transfer for TransferClient {
         })
     }
 }
+
+/// A mock of the AWS Transfer API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockTransfer {
+    create_server: Option<
+        Box<
+            dyn Fn(
+                    CreateServerRequest,
+                )
+                    -> ::std::result::Result<CreateServerResponse, RusotoError<CreateServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_server: Option<
+        Box<
+            dyn Fn(DeleteServerRequest) -> ::std::result::Result<(), RusotoError<DeleteServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_ssh_public_key: Option<
+        Box<
+            dyn Fn(
+                    DeleteSshPublicKeyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteSshPublicKeyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_server: Option<
+        Box<
+            dyn Fn(
+                    DescribeServerRequest,
+                ) -> ::std::result::Result<
+                    DescribeServerResponse,
+                    RusotoError<DescribeServerError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserRequest,
+                )
+                    -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    import_ssh_public_key: Option<
+        Box<
+            dyn Fn(
+                    ImportSshPublicKeyRequest,
+                ) -> ::std::result::Result<
+                    ImportSshPublicKeyResponse,
+                    RusotoError<ImportSshPublicKeyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_servers: Option<
+        Box<
+            dyn Fn(
+                    ListServersRequest,
+                )
+                    -> ::std::result::Result<ListServersResponse, RusotoError<ListServersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_users: Option<
+        Box<
+            dyn Fn(
+                    ListUsersRequest,
+                )
+                    -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_server: Option<
+        Box<
+            dyn Fn(StartServerRequest) -> ::std::result::Result<(), RusotoError<StartServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    stop_server: Option<
+        Box<
+            dyn Fn(StopServerRequest) -> ::std::result::Result<(), RusotoError<StopServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    test_identity_provider: Option<
+        Box<
+            dyn Fn(
+                    TestIdentityProviderRequest,
+                ) -> ::std::result::Result<
+                    TestIdentityProviderResponse,
+                    RusotoError<TestIdentityProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                ) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_server: Option<
+        Box<
+            dyn Fn(
+                    UpdateServerRequest,
+                )
+                    -> ::std::result::Result<UpdateServerResponse, RusotoError<UpdateServerError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserRequest,
+                )
+                    -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockTransfer {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockTransfer {
+        Default::default()
+    }
+
+    /// Answers `create_server` with what `handler` returns for its input.
+    pub fn with_create_server<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                CreateServerRequest,
+            )
+                -> ::std::result::Result<CreateServerResponse, RusotoError<CreateServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                CreateUserRequest,
+            )
+                -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_server` with what `handler` returns for its input.
+    pub fn with_delete_server<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(DeleteServerRequest) -> ::std::result::Result<(), RusotoError<DeleteServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ssh_public_key` with what `handler` returns for its input.
+    pub fn with_delete_ssh_public_key<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                DeleteSshPublicKeyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteSshPublicKeyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ssh_public_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_server` with what `handler` returns for its input.
+    pub fn with_describe_server<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                DescribeServerRequest,
+            )
+                -> ::std::result::Result<DescribeServerResponse, RusotoError<DescribeServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user` with what `handler` returns for its input.
+    pub fn with_describe_user<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                DescribeUserRequest,
+            )
+                -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_ssh_public_key` with what `handler` returns for its input.
+    pub fn with_import_ssh_public_key<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                ImportSshPublicKeyRequest,
+            ) -> ::std::result::Result<
+                ImportSshPublicKeyResponse,
+                RusotoError<ImportSshPublicKeyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_ssh_public_key = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_servers` with what `handler` returns for its input.
+    pub fn with_list_servers<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                ListServersRequest,
+            )
+                -> ::std::result::Result<ListServersResponse, RusotoError<ListServersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_servers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users` with what `handler` returns for its input.
+    pub fn with_list_users<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                ListUsersRequest,
+            ) -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_server` with what `handler` returns for its input.
+    pub fn with_start_server<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(StartServerRequest) -> ::std::result::Result<(), RusotoError<StartServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_server` with what `handler` returns for its input.
+    pub fn with_stop_server<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(StopServerRequest) -> ::std::result::Result<(), RusotoError<StopServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(TagResourceRequest) -> ::std::result::Result<(), RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `test_identity_provider` with what `handler` returns for its input.
+    pub fn with_test_identity_provider<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                TestIdentityProviderRequest,
+            ) -> ::std::result::Result<
+                TestIdentityProviderResponse,
+                RusotoError<TestIdentityProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.test_identity_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(UntagResourceRequest) -> ::std::result::Result<(), RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_server` with what `handler` returns for its input.
+    pub fn with_update_server<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                UpdateServerRequest,
+            )
+                -> ::std::result::Result<UpdateServerResponse, RusotoError<UpdateServerError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_server = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user` with what `handler` returns for its input.
+    pub fn with_update_user<F>(mut self, handler: F) -> MockTransfer
+    where
+        F: Fn(
+                UpdateUserRequest,
+            )
+                -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Transfer for MockTransfer {
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        match self.create_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for create_server"),
+        }
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for create_user"),
+        }
+    }
+    fn delete_server(&self, input: DeleteServerRequest) -> RusotoFuture<(), DeleteServerError> {
+        match self.delete_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for delete_server"),
+        }
+    }
+    fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> RusotoFuture<(), DeleteSshPublicKeyError> {
+        match self.delete_ssh_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for delete_ssh_public_key"),
+        }
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for delete_user"),
+        }
+    }
+    fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> RusotoFuture<DescribeServerResponse, DescribeServerError> {
+        match self.describe_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for describe_server"),
+        }
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        match self.describe_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for describe_user"),
+        }
+    }
+    fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> RusotoFuture<ImportSshPublicKeyResponse, ImportSshPublicKeyError> {
+        match self.import_ssh_public_key {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for import_ssh_public_key"),
+        }
+    }
+    fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> RusotoFuture<ListServersResponse, ListServersError> {
+        match self.list_servers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for list_servers"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        match self.list_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for list_users"),
+        }
+    }
+    fn start_server(&self, input: StartServerRequest) -> RusotoFuture<(), StartServerError> {
+        match self.start_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for start_server"),
+        }
+    }
+    fn stop_server(&self, input: StopServerRequest) -> RusotoFuture<(), StopServerError> {
+        match self.stop_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for stop_server"),
+        }
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for tag_resource"),
+        }
+    }
+    fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> RusotoFuture<TestIdentityProviderResponse, TestIdentityProviderError> {
+        match self.test_identity_provider {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for test_identity_provider"),
+        }
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for untag_resource"),
+        }
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        match self.update_server {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for update_server"),
+        }
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        match self.update_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTransfer has no handler for update_user"),
+        }
+    }
+}
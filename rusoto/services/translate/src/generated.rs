@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The custom terminology applied to the input text by Amazon Translate for the translated text response. This is optional in the response and will only be present if you specified terminology input in the request. Currently, only one terminology can be applied per TranslateText request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AppliedTerminology {
     /// <p>The name of the custom terminology applied to the input text by Amazon Translate for the translated text response.</p>
     #[serde(rename = "Name")]
@@ -38,12 +39,25 @@ pub struct AppliedTerminology {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTerminologyRequest {
     /// <p>The name of the custom terminology being deleted. </p>
     #[serde(rename = "Name")]
     pub name: String,
 }
 
+impl Validate for DeleteTerminologyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z0-9-]_?)+$");
+    }
+}
+
 /// <p>The encryption key used to encrypt the custom terminologies used by Amazon Translate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionKey {
@@ -55,7 +69,20 @@ pub struct EncryptionKey {
     pub type_: String,
 }
 
+impl Validate for EncryptionKey {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(400),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "(arn:aws((-us-gov)|(-iso)|(-iso-b)|(-cn))?:kms:)?([a-z]{2}-[a-z]+(-[a-z]+)?-\\d:)?(\\d{12}:)?(((key/)?[a-zA-Z0-9-_]+)|(alias/[a-zA-Z0-9:/_-]+))");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTerminologyRequest {
     /// <p>The name of the custom terminology being retrieved.</p>
     #[serde(rename = "Name")]
@@ -65,8 +92,20 @@ pub struct GetTerminologyRequest {
     pub terminology_data_format: String,
 }
 
+impl Validate for GetTerminologyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z0-9-]_?)+$");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTerminologyResponse {
     /// <p>The data location of the custom terminology being retrieved. The custom terminology file is returned in a presigned url that has a 30 minute expiration.</p>
     #[serde(rename = "TerminologyDataLocation")]
@@ -79,6 +118,7 @@ pub struct GetTerminologyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportTerminologyRequest {
     /// <p>The description of the custom terminology being imported.</p>
     #[serde(rename = "Description")]
@@ -99,8 +139,38 @@ pub struct ImportTerminologyRequest {
     pub terminology_data: TerminologyData,
 }
 
+impl Validate for ImportTerminologyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "[\\P{M}\\p{M}]{0,256}",
+            );
+        }
+        if let Some(ref value) = self.encryption_key {
+            value.validate_at(&field_path(path, "encryption_key"), errors);
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "^([A-Za-z0-9-]_?)+$");
+        self.terminology_data
+            .validate_at(&field_path(path, "terminology_data"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportTerminologyResponse {
     /// <p>The properties of the custom terminology being imported.</p>
     #[serde(rename = "TerminologyProperties")]
@@ -109,6 +179,7 @@ pub struct ImportTerminologyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTerminologiesRequest {
     /// <p>The maximum number of custom terminologies returned per list request.</p>
     #[serde(rename = "MaxResults")]
@@ -120,8 +191,29 @@ pub struct ListTerminologiesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListTerminologiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(500));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                None,
+                Some(8192),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "\\p{ASCII}{0,8192}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTerminologiesResponse {
     /// <p> If the response to the ListTerminologies was truncated, the NextToken fetches the next group of custom terminologies. </p>
     #[serde(rename = "NextToken")]
@@ -135,7 +227,7 @@ pub struct ListTerminologiesResponse {
 
 /// <p>The term being translated by the custom terminology.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Term {
     /// <p>The source text of the term being translated by the custom terminology.</p>
     #[serde(rename = "SourceText")]
@@ -149,6 +241,7 @@ pub struct Term {
 
 /// <p>The data associated with the custom terminology.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminologyData {
     /// <p>The file containing the custom terminology data.</p>
     #[serde(rename = "File")]
@@ -163,9 +256,20 @@ pub struct TerminologyData {
     pub format: String,
 }
 
+impl Validate for TerminologyData {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "file"),
+            self.file.len(),
+            None,
+            Some(10485760),
+        );
+    }
+}
+
 /// <p>The location of the custom terminology data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminologyDataLocation {
     /// <p>The location of the custom terminology data.</p>
     #[serde(rename = "Location")]
@@ -177,7 +281,7 @@ pub struct TerminologyDataLocation {
 
 /// <p>The properties of the custom terminology.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminologyProperties {
     /// <p> The Amazon Resource Name (ARN) of the custom terminology. </p>
     #[serde(rename = "Arn")]
@@ -222,6 +326,7 @@ pub struct TerminologyProperties {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TranslateTextRequest {
     /// <p>The language code for the language of the source text. The language must be a language supported by Amazon Translate. </p> <p>To have Amazon Translate determine the source language of your text, you can specify <code>auto</code> in the <code>SourceLanguageCode</code> field. If you specify <code>auto</code>, Amazon Translate will call Amazon Comprehend to determine the source language.</p>
     #[serde(rename = "SourceLanguageCode")]
@@ -238,8 +343,43 @@ pub struct TranslateTextRequest {
     pub text: String,
 }
 
+impl Validate for TranslateTextRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "source_language_code"),
+            self.source_language_code.chars().count(),
+            Some(2),
+            Some(5),
+        );
+        errors.check_length(
+            &field_path(path, "target_language_code"),
+            self.target_language_code.chars().count(),
+            Some(2),
+            Some(5),
+        );
+        if let Some(ref value) = self.terminology_names {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "terminology_names"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+                errors.check_pattern(&item_path, &item, "^([A-Za-z0-9-]_?)+$");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            Some(10000),
+        );
+        errors.check_pattern(
+            &field_path(path, "text"),
+            &self.text,
+            "[\\P{M}\\p{M}]{1,10000}",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TranslateTextResponse {
     /// <p>The names of the custom terminologies applied to the input text by Amazon Translate for the translated text response.</p>
     #[serde(rename = "AppliedTerminologies")]
@@ -280,7 +420,12 @@ impl DeleteTerminologyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(DeleteTerminologyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -332,7 +477,12 @@ impl GetTerminologyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(GetTerminologyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -385,7 +535,12 @@ impl ImportTerminologyError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ImportTerminologyError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -433,7 +588,12 @@ impl ListTerminologiesError {
                 "TooManyRequestsException" => {
                     return RusotoError::Service(ListTerminologiesError::TooManyRequests(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -507,7 +667,12 @@ impl TranslateTextError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -565,6 +730,103 @@ pub trait Translate {
         input: TranslateTextRequest,
     ) -> RusotoFuture<TranslateTextResponse, TranslateTextError>;
 }
+const _: Option<&dyn Translate> = None;
+impl<'a, T: Translate + ?Sized> Translate for &'a T {
+    fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> RusotoFuture<(), DeleteTerminologyError> {
+        (**self).delete_terminology(input)
+    }
+    fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> RusotoFuture<GetTerminologyResponse, GetTerminologyError> {
+        (**self).get_terminology(input)
+    }
+    fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> RusotoFuture<ImportTerminologyResponse, ImportTerminologyError> {
+        (**self).import_terminology(input)
+    }
+    fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> RusotoFuture<ListTerminologiesResponse, ListTerminologiesError> {
+        (**self).list_terminologies(input)
+    }
+    fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> RusotoFuture<TranslateTextResponse, TranslateTextError> {
+        (**self).translate_text(input)
+    }
+}
+impl<T: Translate + ?Sized> Translate for Box<T> {
+    fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> RusotoFuture<(), DeleteTerminologyError> {
+        (**self).delete_terminology(input)
+    }
+    fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> RusotoFuture<GetTerminologyResponse, GetTerminologyError> {
+        (**self).get_terminology(input)
+    }
+    fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> RusotoFuture<ImportTerminologyResponse, ImportTerminologyError> {
+        (**self).import_terminology(input)
+    }
+    fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> RusotoFuture<ListTerminologiesResponse, ListTerminologiesError> {
+        (**self).list_terminologies(input)
+    }
+    fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> RusotoFuture<TranslateTextResponse, TranslateTextError> {
+        (**self).translate_text(input)
+    }
+}
+impl<T: Translate + ?Sized> Translate for ::std::sync::Arc<T> {
+    fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> RusotoFuture<(), DeleteTerminologyError> {
+        (**self).delete_terminology(input)
+    }
+    fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> RusotoFuture<GetTerminologyResponse, GetTerminologyError> {
+        (**self).get_terminology(input)
+    }
+    fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> RusotoFuture<ImportTerminologyResponse, ImportTerminologyError> {
+        (**self).import_terminology(input)
+    }
+    fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> RusotoFuture<ListTerminologiesResponse, ListTerminologiesError> {
+        (**self).list_terminologies(input)
+    }
+    fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> RusotoFuture<TranslateTextResponse, TranslateTextError> {
+        (**self).translate_text(input)
+    }
+}
 /// A client for the Amazon Translate API.
 #[derive(Clone)]
 pub struct TranslateClient {
@@ -608,7 +870,16 @@ impl Translate for TranslateClient {
         &self,
         input: DeleteTerminologyRequest,
     ) -> RusotoFuture<(), DeleteTerminologyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("DeleteTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -637,7 +908,16 @@ impl Translate for TranslateClient {
         &self,
         input: GetTerminologyRequest,
     ) -> RusotoFuture<GetTerminologyResponse, GetTerminologyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("GetTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -649,10 +929,10 @@ impl Translate for TranslateClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetTerminologyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<GetTerminologyResponse, _>(
+                    response,
+                    "GetTerminology",
+                )
             } else {
                 Box::new(
                     response
@@ -669,7 +949,16 @@ impl Translate for TranslateClient {
         &self,
         input: ImportTerminologyRequest,
     ) -> RusotoFuture<ImportTerminologyResponse, ImportTerminologyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ImportTerminology");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -681,10 +970,10 @@ impl Translate for TranslateClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportTerminologyResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ImportTerminologyResponse, _>(
+                    response,
+                    "ImportTerminology",
+                )
             } else {
                 Box::new(
                     response
@@ -701,7 +990,16 @@ impl Translate for TranslateClient {
         &self,
         input: ListTerminologiesRequest,
     ) -> RusotoFuture<ListTerminologiesResponse, ListTerminologiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("ListTerminologies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -713,10 +1011,10 @@ impl Translate for TranslateClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTerminologiesResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListTerminologiesResponse, _>(
+                    response,
+                    "ListTerminologies",
+                )
             } else {
                 Box::new(
                     response
@@ -733,7 +1031,16 @@ impl Translate for TranslateClient {
         &self,
         input: TranslateTextRequest,
     ) -> RusotoFuture<TranslateTextResponse, TranslateTextError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "translate", &self.region, "/");
+        request.set_operation("TranslateText");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -745,10 +1052,10 @@ impl Translate for TranslateClient {
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TranslateTextResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<TranslateTextResponse, _>(
+                    response,
+                    "TranslateText",
+                )
             } else {
                 Box::new(
                     response
@@ -760,3 +1067,194 @@ impl Translate for TranslateClient {
         })
     }
 }
+
+/// A mock of the Amazon Translate API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockTranslate {
+    delete_terminology: Option<
+        Box<
+            dyn Fn(
+                    DeleteTerminologyRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteTerminologyError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_terminology: Option<
+        Box<
+            dyn Fn(
+                    GetTerminologyRequest,
+                ) -> ::std::result::Result<
+                    GetTerminologyResponse,
+                    RusotoError<GetTerminologyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_terminology: Option<
+        Box<
+            dyn Fn(
+                    ImportTerminologyRequest,
+                ) -> ::std::result::Result<
+                    ImportTerminologyResponse,
+                    RusotoError<ImportTerminologyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_terminologies: Option<
+        Box<
+            dyn Fn(
+                    ListTerminologiesRequest,
+                ) -> ::std::result::Result<
+                    ListTerminologiesResponse,
+                    RusotoError<ListTerminologiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    translate_text: Option<
+        Box<
+            dyn Fn(
+                    TranslateTextRequest,
+                )
+                    -> ::std::result::Result<TranslateTextResponse, RusotoError<TranslateTextError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockTranslate {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockTranslate {
+        Default::default()
+    }
+
+    /// Answers `delete_terminology` with what `handler` returns for its input.
+    pub fn with_delete_terminology<F>(mut self, handler: F) -> MockTranslate
+    where
+        F: Fn(
+                DeleteTerminologyRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteTerminologyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_terminology = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_terminology` with what `handler` returns for its input.
+    pub fn with_get_terminology<F>(mut self, handler: F) -> MockTranslate
+    where
+        F: Fn(
+                GetTerminologyRequest,
+            )
+                -> ::std::result::Result<GetTerminologyResponse, RusotoError<GetTerminologyError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_terminology = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_terminology` with what `handler` returns for its input.
+    pub fn with_import_terminology<F>(mut self, handler: F) -> MockTranslate
+    where
+        F: Fn(
+                ImportTerminologyRequest,
+            ) -> ::std::result::Result<
+                ImportTerminologyResponse,
+                RusotoError<ImportTerminologyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_terminology = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_terminologies` with what `handler` returns for its input.
+    pub fn with_list_terminologies<F>(mut self, handler: F) -> MockTranslate
+    where
+        F: Fn(
+                ListTerminologiesRequest,
+            ) -> ::std::result::Result<
+                ListTerminologiesResponse,
+                RusotoError<ListTerminologiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_terminologies = Some(Box::new(handler));
+        self
+    }
+    /// Answers `translate_text` with what `handler` returns for its input.
+    pub fn with_translate_text<F>(mut self, handler: F) -> MockTranslate
+    where
+        F: Fn(
+                TranslateTextRequest,
+            )
+                -> ::std::result::Result<TranslateTextResponse, RusotoError<TranslateTextError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.translate_text = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Translate for MockTranslate {
+    fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> RusotoFuture<(), DeleteTerminologyError> {
+        match self.delete_terminology {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranslate has no handler for delete_terminology"),
+        }
+    }
+    fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> RusotoFuture<GetTerminologyResponse, GetTerminologyError> {
+        match self.get_terminology {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranslate has no handler for get_terminology"),
+        }
+    }
+    fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> RusotoFuture<ImportTerminologyResponse, ImportTerminologyError> {
+        match self.import_terminology {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranslate has no handler for import_terminology"),
+        }
+    }
+    fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> RusotoFuture<ListTerminologiesResponse, ListTerminologiesError> {
+        match self.list_terminologies {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranslate has no handler for list_terminologies"),
+        }
+    }
+    fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> RusotoFuture<TranslateTextResponse, TranslateTextError> {
+        match self.translate_text {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockTranslate has no handler for translate_text"),
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>The <code>ActivatedRule</code> object in an <a>UpdateWebACL</a> request specifies a <code>Rule</code> that you want to insert or delete, the priority of the <code>Rule</code> in the <code>WebACL</code>, and the action that you want AWS WAF to take when a web request matches the <code>Rule</code> (<code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>).</p> <p>To specify whether to insert or delete a <code>Rule</code>, use the <code>Action</code> parameter in the <a>WebACLUpdate</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -50,9 +51,27 @@ pub struct ActivatedRule {
     pub type_: Option<String>,
 }
 
+impl Validate for ActivatedRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.excluded_rules {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "excluded_rules"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 /// <p>In a <a>GetByteMatchSet</a> request, <code>ByteMatchSet</code> is a complex type that contains the <code>ByteMatchSetId</code> and <code>Name</code> of a <code>ByteMatchSet</code>, and the values that you specified when you updated the <code>ByteMatchSet</code>. </p> <p>A complex type that contains <code>ByteMatchTuple</code> objects, which specify the parts of web requests that you want AWS WAF to inspect and the values that you want AWS WAF to search for. If a <code>ByteMatchSet</code> contains more than one <code>ByteMatchTuple</code> object, a request needs to match the settings in only one <code>ByteMatchTuple</code> to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ByteMatchSet {
     /// <p>The <code>ByteMatchSetId</code> for a <code>ByteMatchSet</code>. You use <code>ByteMatchSetId</code> to get information about a <code>ByteMatchSet</code> (see <a>GetByteMatchSet</a>), update a <code>ByteMatchSet</code> (see <a>UpdateByteMatchSet</a>), insert a <code>ByteMatchSet</code> into a <code>Rule</code> or delete one from a <code>Rule</code> (see <a>UpdateRule</a>), and delete a <code>ByteMatchSet</code> from AWS WAF (see <a>DeleteByteMatchSet</a>).</p> <p> <code>ByteMatchSetId</code> is returned by <a>CreateByteMatchSet</a> and by <a>ListByteMatchSets</a>.</p>
     #[serde(rename = "ByteMatchSetId")]
@@ -68,7 +87,7 @@ pub struct ByteMatchSet {
 
 /// <p>Returned by <a>ListByteMatchSets</a>. Each <code>ByteMatchSetSummary</code> object includes the <code>Name</code> and <code>ByteMatchSetId</code> for one <a>ByteMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ByteMatchSetSummary {
     /// <p>The <code>ByteMatchSetId</code> for a <code>ByteMatchSet</code>. You use <code>ByteMatchSetId</code> to get information about a <code>ByteMatchSet</code>, update a <code>ByteMatchSet</code>, remove a <code>ByteMatchSet</code> from a <code>Rule</code>, and delete a <code>ByteMatchSet</code> from AWS WAF.</p> <p> <code>ByteMatchSetId</code> is returned by <a>CreateByteMatchSet</a> and by <a>ListByteMatchSets</a>.</p>
     #[serde(rename = "ByteMatchSetId")]
@@ -80,6 +99,7 @@ pub struct ByteMatchSetSummary {
 
 /// <p>In an <a>UpdateByteMatchSet</a> request, <code>ByteMatchSetUpdate</code> specifies whether to insert or delete a <a>ByteMatchTuple</a> and includes the settings for the <code>ByteMatchTuple</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ByteMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a <a>ByteMatchTuple</a>.</p>
     #[serde(rename = "Action")]
@@ -89,6 +109,13 @@ pub struct ByteMatchSetUpdate {
     pub byte_match_tuple: ByteMatchTuple,
 }
 
+impl Validate for ByteMatchSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.byte_match_tuple
+            .validate_at(&field_path(path, "byte_match_tuple"), errors);
+    }
+}
+
 /// <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to search for in web requests, the location in requests that you want AWS WAF to search, and other settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ByteMatchTuple {
@@ -111,7 +138,15 @@ pub struct ByteMatchTuple {
     pub text_transformation: String,
 }
 
+impl Validate for ByteMatchTuple {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.field_to_match
+            .validate_at(&field_path(path, "field_to_match"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateByteMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -121,8 +156,31 @@ pub struct CreateByteMatchSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateByteMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateByteMatchSetResponse {
     /// <p>A <a>ByteMatchSet</a> that contains no <code>ByteMatchTuple</code> objects.</p>
     #[serde(rename = "ByteMatchSet")]
@@ -135,6 +193,7 @@ pub struct CreateByteMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGeoMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -144,8 +203,31 @@ pub struct CreateGeoMatchSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateGeoMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGeoMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateGeoMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -158,6 +240,7 @@ pub struct CreateGeoMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIPSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -167,8 +250,31 @@ pub struct CreateIPSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIPSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateIPSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -181,6 +287,7 @@ pub struct CreateIPSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRateBasedRuleRequest {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRateBasedRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -202,8 +309,55 @@ pub struct CreateRateBasedRuleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRateBasedRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "metric_name"),
+            &self.metric_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+        errors.check_range(
+            &field_path(path, "rate_limit"),
+            self.rate_limit,
+            Some(100),
+            Some(2000000000),
+        );
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRateBasedRuleResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRateBasedRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -216,6 +370,7 @@ pub struct CreateRateBasedRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRegexMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -225,8 +380,31 @@ pub struct CreateRegexMatchSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateRegexMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRegexMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRegexMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -239,6 +417,7 @@ pub struct CreateRegexMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRegexPatternSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -248,8 +427,31 @@ pub struct CreateRegexPatternSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateRegexPatternSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRegexPatternSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRegexPatternSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -262,6 +464,7 @@ pub struct CreateRegexPatternSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRuleGroupRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -277,8 +480,49 @@ pub struct CreateRuleGroupRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRuleGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "metric_name"),
+            &self.metric_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRuleGroupResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRuleGroup</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -291,6 +535,7 @@ pub struct CreateRuleGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateRuleRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -306,8 +551,49 @@ pub struct CreateRuleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "metric_name"),
+            &self.metric_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateRuleResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -320,6 +606,7 @@ pub struct CreateRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSizeConstraintSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -329,8 +616,31 @@ pub struct CreateSizeConstraintSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateSizeConstraintSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSizeConstraintSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateSizeConstraintSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -344,6 +654,7 @@ pub struct CreateSizeConstraintSetResponse {
 
 /// <p>A request to create a <a>SqlInjectionMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSqlInjectionMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -353,9 +664,32 @@ pub struct CreateSqlInjectionMatchSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateSqlInjectionMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 /// <p>The response to a <code>CreateSqlInjectionMatchSet</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSqlInjectionMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateSqlInjectionMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -368,6 +702,7 @@ pub struct CreateSqlInjectionMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWebACLRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -386,8 +721,49 @@ pub struct CreateWebACLRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CreateWebACLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "metric_name"),
+            self.metric_name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "metric_name"),
+            &self.metric_name,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+        if let Some(ref value) = self.tags {
+            errors.check_length(&field_path(path, "tags"), value.len(), Some(1), None);
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWebACLResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateWebACL</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -401,6 +777,7 @@ pub struct CreateWebACLResponse {
 
 /// <p>A request to create an <a>XssMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateXssMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -410,9 +787,32 @@ pub struct CreateXssMatchSetRequest {
     pub name: String,
 }
 
+impl Validate for CreateXssMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, ".*\\S.*");
+    }
+}
+
 /// <p>The response to a <code>CreateXssMatchSet</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateXssMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>CreateXssMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -425,6 +825,7 @@ pub struct CreateXssMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteByteMatchSetRequest {
     /// <p>The <code>ByteMatchSetId</code> of the <a>ByteMatchSet</a> that you want to delete. <code>ByteMatchSetId</code> is returned by <a>CreateByteMatchSet</a> and by <a>ListByteMatchSets</a>.</p>
     #[serde(rename = "ByteMatchSetId")]
@@ -434,8 +835,35 @@ pub struct DeleteByteMatchSetRequest {
     pub change_token: String,
 }
 
+impl Validate for DeleteByteMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "byte_match_set_id"),
+            self.byte_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "byte_match_set_id"),
+            &self.byte_match_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteByteMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteByteMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -444,6 +872,7 @@ pub struct DeleteByteMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGeoMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -453,8 +882,35 @@ pub struct DeleteGeoMatchSetRequest {
     pub geo_match_set_id: String,
 }
 
+impl Validate for DeleteGeoMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "geo_match_set_id"),
+            self.geo_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "geo_match_set_id"),
+            &self.geo_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGeoMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteGeoMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -463,6 +919,7 @@ pub struct DeleteGeoMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIPSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -472,8 +929,31 @@ pub struct DeleteIPSetRequest {
     pub ip_set_id: String,
 }
 
+impl Validate for DeleteIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "ip_set_id"),
+            self.ip_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "ip_set_id"), &self.ip_set_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteIPSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteIPSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -482,28 +962,63 @@ pub struct DeleteIPSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLoggingConfigurationRequest {
     /// <p>The Amazon Resource Name (ARN) of the web ACL from which you want to delete the <a>LoggingConfiguration</a>.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for DeleteLoggingConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLoggingConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeletePermissionPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the RuleGroup from which you want to delete the policy.</p> <p>The user making the request must be the owner of the RuleGroup.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for DeletePermissionPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeletePermissionPolicyResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRateBasedRuleRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -513,8 +1028,31 @@ pub struct DeleteRateBasedRuleRequest {
     pub rule_id: String,
 }
 
+impl Validate for DeleteRateBasedRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRateBasedRuleResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteRateBasedRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -523,6 +1061,7 @@ pub struct DeleteRateBasedRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRegexMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -532,8 +1071,35 @@ pub struct DeleteRegexMatchSetRequest {
     pub regex_match_set_id: String,
 }
 
+impl Validate for DeleteRegexMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "regex_match_set_id"),
+            self.regex_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_match_set_id"),
+            &self.regex_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRegexMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteRegexMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -542,6 +1108,7 @@ pub struct DeleteRegexMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRegexPatternSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -551,8 +1118,35 @@ pub struct DeleteRegexPatternSetRequest {
     pub regex_pattern_set_id: String,
 }
 
+impl Validate for DeleteRegexPatternSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "regex_pattern_set_id"),
+            self.regex_pattern_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_pattern_set_id"),
+            &self.regex_pattern_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRegexPatternSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteRegexPatternSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -561,6 +1155,7 @@ pub struct DeleteRegexPatternSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRuleGroupRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -570,8 +1165,35 @@ pub struct DeleteRuleGroupRequest {
     pub rule_group_id: String,
 }
 
+impl Validate for DeleteRuleGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "rule_group_id"),
+            self.rule_group_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "rule_group_id"),
+            &self.rule_group_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRuleGroupResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteRuleGroup</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -580,6 +1202,7 @@ pub struct DeleteRuleGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteRuleRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -589,8 +1212,31 @@ pub struct DeleteRuleRequest {
     pub rule_id: String,
 }
 
+impl Validate for DeleteRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteRuleResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -599,6 +1245,7 @@ pub struct DeleteRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSizeConstraintSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -608,8 +1255,35 @@ pub struct DeleteSizeConstraintSetRequest {
     pub size_constraint_set_id: String,
 }
 
+impl Validate for DeleteSizeConstraintSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "size_constraint_set_id"),
+            self.size_constraint_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "size_constraint_set_id"),
+            &self.size_constraint_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSizeConstraintSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteSizeConstraintSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -619,6 +1293,7 @@ pub struct DeleteSizeConstraintSetResponse {
 
 /// <p>A request to delete a <a>SqlInjectionMatchSet</a> from AWS WAF.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSqlInjectionMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -628,9 +1303,36 @@ pub struct DeleteSqlInjectionMatchSetRequest {
     pub sql_injection_match_set_id: String,
 }
 
+impl Validate for DeleteSqlInjectionMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "sql_injection_match_set_id"),
+            self.sql_injection_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "sql_injection_match_set_id"),
+            &self.sql_injection_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>The response to a request to delete a <a>SqlInjectionMatchSet</a> from AWS WAF.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSqlInjectionMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteSqlInjectionMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -639,6 +1341,7 @@ pub struct DeleteSqlInjectionMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWebACLRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -648,8 +1351,31 @@ pub struct DeleteWebACLRequest {
     pub web_acl_id: String,
 }
 
+impl Validate for DeleteWebACLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "web_acl_id"),
+            self.web_acl_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "web_acl_id"), &self.web_acl_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWebACLResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteWebACL</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -659,6 +1385,7 @@ pub struct DeleteWebACLResponse {
 
 /// <p>A request to delete an <a>XssMatchSet</a> from AWS WAF.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteXssMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -668,9 +1395,36 @@ pub struct DeleteXssMatchSetRequest {
     pub xss_match_set_id: String,
 }
 
+impl Validate for DeleteXssMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "xss_match_set_id"),
+            self.xss_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "xss_match_set_id"),
+            &self.xss_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>The response to a request to delete an <a>XssMatchSet</a> from AWS WAF.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteXssMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>DeleteXssMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -686,6 +1440,18 @@ pub struct ExcludedRule {
     pub rule_id: String,
 }
 
+impl Validate for ExcludedRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 /// <p>Specifies where in a web request to look for <code>TargetString</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FieldToMatch {
@@ -698,6 +1464,20 @@ pub struct FieldToMatch {
     pub type_: String,
 }
 
+impl Validate for FieldToMatch {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.data {
+            errors.check_length(
+                &field_path(path, "data"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "data"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>The country from which web requests originate that you want AWS WAF to search for.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GeoMatchConstraint {
@@ -711,7 +1491,7 @@ pub struct GeoMatchConstraint {
 
 /// <p>Contains one or more countries that AWS WAF will search for.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GeoMatchSet {
     /// <p>An array of <a>GeoMatchConstraint</a> objects, which contain the country that you want AWS WAF to search for.</p>
     #[serde(rename = "GeoMatchConstraints")]
@@ -727,7 +1507,7 @@ pub struct GeoMatchSet {
 
 /// <p>Contains the identifier and the name of the <code>GeoMatchSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GeoMatchSetSummary {
     /// <p>The <code>GeoMatchSetId</code> for an <a>GeoMatchSet</a>. You can use <code>GeoMatchSetId</code> in a <a>GetGeoMatchSet</a> request to get detailed information about an <a>GeoMatchSet</a>.</p>
     #[serde(rename = "GeoMatchSetId")]
@@ -739,6 +1519,7 @@ pub struct GeoMatchSetSummary {
 
 /// <p>Specifies the type of update to perform to an <a>GeoMatchSet</a> with <a>UpdateGeoMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GeoMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a country with <a>UpdateGeoMatchSet</a>.</p>
     #[serde(rename = "Action")]
@@ -749,14 +1530,31 @@ pub struct GeoMatchSetUpdate {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetByteMatchSetRequest {
     /// <p>The <code>ByteMatchSetId</code> of the <a>ByteMatchSet</a> that you want to get. <code>ByteMatchSetId</code> is returned by <a>CreateByteMatchSet</a> and by <a>ListByteMatchSets</a>.</p>
     #[serde(rename = "ByteMatchSetId")]
     pub byte_match_set_id: String,
 }
 
+impl Validate for GetByteMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "byte_match_set_id"),
+            self.byte_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "byte_match_set_id"),
+            &self.byte_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetByteMatchSetResponse {
     /// <p><p>Information about the <a>ByteMatchSet</a> that you specified in the <code>GetByteMatchSet</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>ByteMatchSet</a>: Contains <code>ByteMatchSetId</code>, <code>ByteMatchTuples</code>, and <code>Name</code> </p> </li> <li> <p> <code>ByteMatchTuples</code>: Contains an array of <a>ByteMatchTuple</a> objects. Each <code>ByteMatchTuple</code> object contains <a>FieldToMatch</a>, <code>PositionalConstraint</code>, <code>TargetString</code>, and <code>TextTransformation</code> </p> </li> <li> <p> <a>FieldToMatch</a>: Contains <code>Data</code> and <code>Type</code> </p> </li> </ul></p>
     #[serde(rename = "ByteMatchSet")]
@@ -765,10 +1563,11 @@ pub struct GetByteMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetChangeTokenRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetChangeTokenResponse {
     /// <p>The <code>ChangeToken</code> that you used in the request. Use this value in a <code>GetChangeTokenStatus</code> request to get the current status of the request. </p>
     #[serde(rename = "ChangeToken")]
@@ -777,14 +1576,31 @@ pub struct GetChangeTokenResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetChangeTokenStatusRequest {
     /// <p>The change token for which you want to get the status. This change token was previously returned in the <code>GetChangeToken</code> response.</p>
     #[serde(rename = "ChangeToken")]
     pub change_token: String,
 }
 
+impl Validate for GetChangeTokenStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetChangeTokenStatusResponse {
     /// <p>The status of the change token.</p>
     #[serde(rename = "ChangeTokenStatus")]
@@ -793,14 +1609,31 @@ pub struct GetChangeTokenStatusResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGeoMatchSetRequest {
     /// <p>The <code>GeoMatchSetId</code> of the <a>GeoMatchSet</a> that you want to get. <code>GeoMatchSetId</code> is returned by <a>CreateGeoMatchSet</a> and by <a>ListGeoMatchSets</a>.</p>
     #[serde(rename = "GeoMatchSetId")]
     pub geo_match_set_id: String,
 }
 
+impl Validate for GetGeoMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "geo_match_set_id"),
+            self.geo_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "geo_match_set_id"),
+            &self.geo_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGeoMatchSetResponse {
     /// <p>Information about the <a>GeoMatchSet</a> that you specified in the <code>GetGeoMatchSet</code> request. This includes the <code>Type</code>, which for a <code>GeoMatchContraint</code> is always <code>Country</code>, as well as the <code>Value</code>, which is the identifier for a specific country.</p>
     #[serde(rename = "GeoMatchSet")]
@@ -809,14 +1642,27 @@ pub struct GetGeoMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetIPSetRequest {
     /// <p>The <code>IPSetId</code> of the <a>IPSet</a> that you want to get. <code>IPSetId</code> is returned by <a>CreateIPSet</a> and by <a>ListIPSets</a>.</p>
     #[serde(rename = "IPSetId")]
     pub ip_set_id: String,
 }
 
+impl Validate for GetIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "ip_set_id"),
+            self.ip_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "ip_set_id"), &self.ip_set_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetIPSetResponse {
     /// <p><p>Information about the <a>IPSet</a> that you specified in the <code>GetIPSet</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>IPSet</a>: Contains <code>IPSetDescriptors</code>, <code>IPSetId</code>, and <code>Name</code> </p> </li> <li> <p> <code>IPSetDescriptors</code>: Contains an array of <a>IPSetDescriptor</a> objects. Each <code>IPSetDescriptor</code> object contains <code>Type</code> and <code>Value</code> </p> </li> </ul></p>
     #[serde(rename = "IPSet")]
@@ -825,14 +1671,31 @@ pub struct GetIPSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetLoggingConfigurationRequest {
     /// <p>The Amazon Resource Name (ARN) of the web ACL for which you want to get the <a>LoggingConfiguration</a>.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for GetLoggingConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetLoggingConfigurationResponse {
     /// <p>The <a>LoggingConfiguration</a> for the specified web ACL.</p>
     #[serde(rename = "LoggingConfiguration")]
@@ -841,14 +1704,31 @@ pub struct GetLoggingConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetPermissionPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the RuleGroup for which you want to get the policy.</p>
     #[serde(rename = "ResourceArn")]
     pub resource_arn: String,
 }
 
+impl Validate for GetPermissionPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetPermissionPolicyResponse {
     /// <p>The IAM policy attached to the specified RuleGroup.</p>
     #[serde(rename = "Policy")]
@@ -857,6 +1737,7 @@ pub struct GetPermissionPolicyResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRateBasedRuleManagedKeysRequest {
     /// <p>A null value and not currently used. Do not include this in your request.</p>
     #[serde(rename = "NextMarker")]
@@ -867,8 +1748,29 @@ pub struct GetRateBasedRuleManagedKeysRequest {
     pub rule_id: String,
 }
 
+impl Validate for GetRateBasedRuleManagedKeysRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRateBasedRuleManagedKeysResponse {
     /// <p>An array of IP addresses that currently are blocked by the specified <a>RateBasedRule</a>. </p>
     #[serde(rename = "ManagedKeys")]
@@ -881,14 +1783,27 @@ pub struct GetRateBasedRuleManagedKeysResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRateBasedRuleRequest {
     /// <p>The <code>RuleId</code> of the <a>RateBasedRule</a> that you want to get. <code>RuleId</code> is returned by <a>CreateRateBasedRule</a> and by <a>ListRateBasedRules</a>.</p>
     #[serde(rename = "RuleId")]
     pub rule_id: String,
 }
 
+impl Validate for GetRateBasedRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRateBasedRuleResponse {
     /// <p>Information about the <a>RateBasedRule</a> that you specified in the <code>GetRateBasedRule</code> request.</p>
     #[serde(rename = "Rule")]
@@ -897,14 +1812,31 @@ pub struct GetRateBasedRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRegexMatchSetRequest {
     /// <p>The <code>RegexMatchSetId</code> of the <a>RegexMatchSet</a> that you want to get. <code>RegexMatchSetId</code> is returned by <a>CreateRegexMatchSet</a> and by <a>ListRegexMatchSets</a>.</p>
     #[serde(rename = "RegexMatchSetId")]
     pub regex_match_set_id: String,
 }
 
+impl Validate for GetRegexMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "regex_match_set_id"),
+            self.regex_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_match_set_id"),
+            &self.regex_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRegexMatchSetResponse {
     /// <p>Information about the <a>RegexMatchSet</a> that you specified in the <code>GetRegexMatchSet</code> request. For more information, see <a>RegexMatchTuple</a>.</p>
     #[serde(rename = "RegexMatchSet")]
@@ -913,14 +1845,31 @@ pub struct GetRegexMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRegexPatternSetRequest {
     /// <p>The <code>RegexPatternSetId</code> of the <a>RegexPatternSet</a> that you want to get. <code>RegexPatternSetId</code> is returned by <a>CreateRegexPatternSet</a> and by <a>ListRegexPatternSets</a>.</p>
     #[serde(rename = "RegexPatternSetId")]
     pub regex_pattern_set_id: String,
 }
 
+impl Validate for GetRegexPatternSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "regex_pattern_set_id"),
+            self.regex_pattern_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_pattern_set_id"),
+            &self.regex_pattern_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRegexPatternSetResponse {
     /// <p>Information about the <a>RegexPatternSet</a> that you specified in the <code>GetRegexPatternSet</code> request, including the identifier of the pattern set and the regular expression patterns you want AWS WAF to search for. </p>
     #[serde(rename = "RegexPatternSet")]
@@ -929,14 +1878,31 @@ pub struct GetRegexPatternSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRuleGroupRequest {
     /// <p>The <code>RuleGroupId</code> of the <a>RuleGroup</a> that you want to get. <code>RuleGroupId</code> is returned by <a>CreateRuleGroup</a> and by <a>ListRuleGroups</a>.</p>
     #[serde(rename = "RuleGroupId")]
     pub rule_group_id: String,
 }
 
+impl Validate for GetRuleGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rule_group_id"),
+            self.rule_group_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "rule_group_id"),
+            &self.rule_group_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRuleGroupResponse {
     /// <p>Information about the <a>RuleGroup</a> that you specified in the <code>GetRuleGroup</code> request. </p>
     #[serde(rename = "RuleGroup")]
@@ -945,14 +1911,27 @@ pub struct GetRuleGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetRuleRequest {
     /// <p>The <code>RuleId</code> of the <a>Rule</a> that you want to get. <code>RuleId</code> is returned by <a>CreateRule</a> and by <a>ListRules</a>.</p>
     #[serde(rename = "RuleId")]
     pub rule_id: String,
 }
 
+impl Validate for GetRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetRuleResponse {
     /// <p><p>Information about the <a>Rule</a> that you specified in the <code>GetRule</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>Rule</a>: Contains <code>MetricName</code>, <code>Name</code>, an array of <code>Predicate</code> objects, and <code>RuleId</code> </p> </li> <li> <p> <a>Predicate</a>: Each <code>Predicate</code> object contains <code>DataId</code>, <code>Negated</code>, and <code>Type</code> </p> </li> </ul></p>
     #[serde(rename = "Rule")]
@@ -961,6 +1940,7 @@ pub struct GetRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSampledRequestsRequest {
     /// <p>The number of requests that you want AWS WAF to return from among the first 5,000 requests that your AWS resource received during the time range. If your resource received fewer requests than the value of <code>MaxItems</code>, <code>GetSampledRequests</code> returns information about all of them. </p>
     #[serde(rename = "MaxItems")]
@@ -976,8 +1956,33 @@ pub struct GetSampledRequestsRequest {
     pub web_acl_id: String,
 }
 
+impl Validate for GetSampledRequestsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "max_items"),
+            self.max_items,
+            Some(1),
+            Some(500),
+        );
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "web_acl_id"),
+            self.web_acl_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "web_acl_id"), &self.web_acl_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSampledRequestsResponse {
     /// <p>The total number of requests from which <code>GetSampledRequests</code> got a sample of <code>MaxItems</code> requests. If <code>PopulationSize</code> is less than <code>MaxItems</code>, the sample includes every request that your AWS resource received during the specified time range.</p>
     #[serde(rename = "PopulationSize")]
@@ -994,14 +1999,31 @@ pub struct GetSampledRequestsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSizeConstraintSetRequest {
     /// <p>The <code>SizeConstraintSetId</code> of the <a>SizeConstraintSet</a> that you want to get. <code>SizeConstraintSetId</code> is returned by <a>CreateSizeConstraintSet</a> and by <a>ListSizeConstraintSets</a>.</p>
     #[serde(rename = "SizeConstraintSetId")]
     pub size_constraint_set_id: String,
 }
 
+impl Validate for GetSizeConstraintSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "size_constraint_set_id"),
+            self.size_constraint_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "size_constraint_set_id"),
+            &self.size_constraint_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSizeConstraintSetResponse {
     /// <p><p>Information about the <a>SizeConstraintSet</a> that you specified in the <code>GetSizeConstraintSet</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>SizeConstraintSet</a>: Contains <code>SizeConstraintSetId</code>, <code>SizeConstraints</code>, and <code>Name</code> </p> </li> <li> <p> <code>SizeConstraints</code>: Contains an array of <a>SizeConstraint</a> objects. Each <code>SizeConstraint</code> object contains <a>FieldToMatch</a>, <code>TextTransformation</code>, <code>ComparisonOperator</code>, and <code>Size</code> </p> </li> <li> <p> <a>FieldToMatch</a>: Contains <code>Data</code> and <code>Type</code> </p> </li> </ul></p>
     #[serde(rename = "SizeConstraintSet")]
@@ -1011,15 +2033,32 @@ pub struct GetSizeConstraintSetResponse {
 
 /// <p>A request to get a <a>SqlInjectionMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSqlInjectionMatchSetRequest {
     /// <p>The <code>SqlInjectionMatchSetId</code> of the <a>SqlInjectionMatchSet</a> that you want to get. <code>SqlInjectionMatchSetId</code> is returned by <a>CreateSqlInjectionMatchSet</a> and by <a>ListSqlInjectionMatchSets</a>.</p>
     #[serde(rename = "SqlInjectionMatchSetId")]
     pub sql_injection_match_set_id: String,
 }
 
+impl Validate for GetSqlInjectionMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "sql_injection_match_set_id"),
+            self.sql_injection_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "sql_injection_match_set_id"),
+            &self.sql_injection_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>The response to a <a>GetSqlInjectionMatchSet</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSqlInjectionMatchSetResponse {
     /// <p><p>Information about the <a>SqlInjectionMatchSet</a> that you specified in the <code>GetSqlInjectionMatchSet</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>SqlInjectionMatchSet</a>: Contains <code>Name</code>, <code>SqlInjectionMatchSetId</code>, and an array of <code>SqlInjectionMatchTuple</code> objects</p> </li> <li> <p> <a>SqlInjectionMatchTuple</a>: Each <code>SqlInjectionMatchTuple</code> object contains <code>FieldToMatch</code> and <code>TextTransformation</code> </p> </li> <li> <p> <a>FieldToMatch</a>: Contains <code>Data</code> and <code>Type</code> </p> </li> </ul></p>
     #[serde(rename = "SqlInjectionMatchSet")]
@@ -1028,14 +2067,27 @@ pub struct GetSqlInjectionMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetWebACLRequest {
     /// <p>The <code>WebACLId</code> of the <a>WebACL</a> that you want to get. <code>WebACLId</code> is returned by <a>CreateWebACL</a> and by <a>ListWebACLs</a>.</p>
     #[serde(rename = "WebACLId")]
     pub web_acl_id: String,
 }
 
+impl Validate for GetWebACLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "web_acl_id"),
+            self.web_acl_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "web_acl_id"), &self.web_acl_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetWebACLResponse {
     /// <p><p>Information about the <a>WebACL</a> that you specified in the <code>GetWebACL</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>WebACL</a>: Contains <code>DefaultAction</code>, <code>MetricName</code>, <code>Name</code>, an array of <code>Rule</code> objects, and <code>WebACLId</code> </p> </li> <li> <p> <code>DefaultAction</code> (Data type is <a>WafAction</a>): Contains <code>Type</code> </p> </li> <li> <p> <code>Rules</code>: Contains an array of <code>ActivatedRule</code> objects, which contain <code>Action</code>, <code>Priority</code>, and <code>RuleId</code> </p> </li> <li> <p> <code>Action</code>: Contains <code>Type</code> </p> </li> </ul></p>
     #[serde(rename = "WebACL")]
@@ -1045,15 +2097,32 @@ pub struct GetWebACLResponse {
 
 /// <p>A request to get an <a>XssMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetXssMatchSetRequest {
     /// <p>The <code>XssMatchSetId</code> of the <a>XssMatchSet</a> that you want to get. <code>XssMatchSetId</code> is returned by <a>CreateXssMatchSet</a> and by <a>ListXssMatchSets</a>.</p>
     #[serde(rename = "XssMatchSetId")]
     pub xss_match_set_id: String,
 }
 
+impl Validate for GetXssMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "xss_match_set_id"),
+            self.xss_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "xss_match_set_id"),
+            &self.xss_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>The response to a <a>GetXssMatchSet</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetXssMatchSetResponse {
     /// <p><p>Information about the <a>XssMatchSet</a> that you specified in the <code>GetXssMatchSet</code> request. For more information, see the following topics:</p> <ul> <li> <p> <a>XssMatchSet</a>: Contains <code>Name</code>, <code>XssMatchSetId</code>, and an array of <code>XssMatchTuple</code> objects</p> </li> <li> <p> <a>XssMatchTuple</a>: Each <code>XssMatchTuple</code> object contains <code>FieldToMatch</code> and <code>TextTransformation</code> </p> </li> <li> <p> <a>FieldToMatch</a>: Contains <code>Data</code> and <code>Type</code> </p> </li> </ul></p>
     #[serde(rename = "XssMatchSet")]
@@ -1063,7 +2132,7 @@ pub struct GetXssMatchSetResponse {
 
 /// <p>The response from a <a>GetSampledRequests</a> request includes an <code>HTTPHeader</code> complex type that appears as <code>Headers</code> in the response syntax. <code>HTTPHeader</code> contains the names and values of all of the headers that appear in one of the web requests that were returned by <code>GetSampledRequests</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HTTPHeader {
     /// <p>The name of one of the headers in the sampled web request.</p>
     #[serde(rename = "Name")]
@@ -1077,7 +2146,7 @@ pub struct HTTPHeader {
 
 /// <p>The response from a <a>GetSampledRequests</a> request includes an <code>HTTPRequest</code> complex type that appears as <code>Request</code> in the response syntax. <code>HTTPRequest</code> contains information about one of the web requests that were returned by <code>GetSampledRequests</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HTTPRequest {
     /// <p><p>The IP address that the request originated from. If the <code>WebACL</code> is associated with a CloudFront distribution, this is the value of one of the following fields in CloudFront access logs:</p> <ul> <li> <p> <code>c-ip</code>, if the viewer did not use an HTTP proxy or a load balancer to send the request</p> </li> <li> <p> <code>x-forwarded-for</code>, if the viewer did use an HTTP proxy or a load balancer to send the request</p> </li> </ul></p>
     #[serde(rename = "ClientIP")]
@@ -1107,7 +2176,7 @@ pub struct HTTPRequest {
 
 /// <p>Contains one or more IP addresses or blocks of IP addresses specified in Classless Inter-Domain Routing (CIDR) notation. AWS WAF supports IPv4 address ranges: /8 and any range between /16 through /32. AWS WAF supports IPv6 address ranges: /24, /32, /48, /56, /64, and /128.</p> <p>To specify an individual IP address, you specify the four-part IP address followed by a <code>/32</code>, for example, 192.0.2.0/31. To block a range of IP addresses, you can specify /8 or any range between /16 through /32 (for IPv4) or /24, /32, /48, /56, /64, or /128 (for IPv6). For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IPSet {
     /// <p>The IP address type (<code>IPV4</code> or <code>IPV6</code>) and the IP address range (in CIDR notation) that web requests originate from. If the <code>WebACL</code> is associated with a CloudFront distribution and the viewer did not use an HTTP proxy or a load balancer to send the request, this is the value of the c-ip field in the CloudFront access logs.</p>
     #[serde(rename = "IPSetDescriptors")]
@@ -1132,9 +2201,21 @@ pub struct IPSetDescriptor {
     pub value: String,
 }
 
+impl Validate for IPSetDescriptor {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "value"),
+            self.value.chars().count(),
+            Some(1),
+            Some(50),
+        );
+        errors.check_pattern(&field_path(path, "value"), &self.value, ".*\\S.*");
+    }
+}
+
 /// <p>Contains the identifier and the name of the <code>IPSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct IPSetSummary {
     /// <p>The <code>IPSetId</code> for an <a>IPSet</a>. You can use <code>IPSetId</code> in a <a>GetIPSet</a> request to get detailed information about an <a>IPSet</a>.</p>
     #[serde(rename = "IPSetId")]
@@ -1146,6 +2227,7 @@ pub struct IPSetSummary {
 
 /// <p>Specifies the type of update to perform to an <a>IPSet</a> with <a>UpdateIPSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct IPSetUpdate {
     /// <p>Specifies whether to insert or delete an IP address with <a>UpdateIPSet</a>.</p>
     #[serde(rename = "Action")]
@@ -1155,7 +2237,15 @@ pub struct IPSetUpdate {
     pub ip_set_descriptor: IPSetDescriptor,
 }
 
+impl Validate for IPSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.ip_set_descriptor
+            .validate_at(&field_path(path, "ip_set_descriptor"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListActivatedRulesInRuleGroupRequest {
     /// <p>Specifies the number of <code>ActivatedRules</code> that you want AWS WAF to return for this request. If you have more <code>ActivatedRules</code> than the number that you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>ActivatedRules</code>.</p>
     #[serde(rename = "Limit")]
@@ -1171,8 +2261,34 @@ pub struct ListActivatedRulesInRuleGroupRequest {
     pub rule_group_id: Option<String>,
 }
 
+impl Validate for ListActivatedRulesInRuleGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.rule_group_id {
+            errors.check_length(
+                &field_path(path, "rule_group_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "rule_group_id"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListActivatedRulesInRuleGroupResponse {
     /// <p>An array of <code>ActivatedRules</code> objects.</p>
     #[serde(rename = "ActivatedRules")]
@@ -1185,6 +2301,7 @@ pub struct ListActivatedRulesInRuleGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListByteMatchSetsRequest {
     /// <p>Specifies the number of <code>ByteMatchSet</code> objects that you want AWS WAF to return for this request. If you have more <code>ByteMatchSets</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>ByteMatchSet</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1196,8 +2313,25 @@ pub struct ListByteMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListByteMatchSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListByteMatchSetsResponse {
     /// <p>An array of <a>ByteMatchSetSummary</a> objects.</p>
     #[serde(rename = "ByteMatchSets")]
@@ -1210,6 +2344,7 @@ pub struct ListByteMatchSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGeoMatchSetsRequest {
     /// <p>Specifies the number of <code>GeoMatchSet</code> objects that you want AWS WAF to return for this request. If you have more <code>GeoMatchSet</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>GeoMatchSet</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1221,8 +2356,25 @@ pub struct ListGeoMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListGeoMatchSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGeoMatchSetsResponse {
     /// <p>An array of <a>GeoMatchSetSummary</a> objects.</p>
     #[serde(rename = "GeoMatchSets")]
@@ -1235,6 +2387,7 @@ pub struct ListGeoMatchSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListIPSetsRequest {
     /// <p>Specifies the number of <code>IPSet</code> objects that you want AWS WAF to return for this request. If you have more <code>IPSet</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>IPSet</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1246,8 +2399,25 @@ pub struct ListIPSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListIPSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListIPSetsResponse {
     /// <p>An array of <a>IPSetSummary</a> objects.</p>
     #[serde(rename = "IPSets")]
@@ -1260,6 +2430,7 @@ pub struct ListIPSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListLoggingConfigurationsRequest {
     /// <p>Specifies the number of <code>LoggingConfigurations</code> that you want AWS WAF to return for this request. If you have more <code>LoggingConfigurations</code> than the number that you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>LoggingConfigurations</code>.</p>
     #[serde(rename = "Limit")]
@@ -1271,8 +2442,25 @@ pub struct ListLoggingConfigurationsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListLoggingConfigurationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListLoggingConfigurationsResponse {
     /// <p>An array of <a>LoggingConfiguration</a> objects.</p>
     #[serde(rename = "LoggingConfigurations")]
@@ -1285,6 +2473,7 @@ pub struct ListLoggingConfigurationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRateBasedRulesRequest {
     /// <p>Specifies the number of <code>Rules</code> that you want AWS WAF to return for this request. If you have more <code>Rules</code> than the number that you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>Rules</code>.</p>
     #[serde(rename = "Limit")]
@@ -1296,8 +2485,25 @@ pub struct ListRateBasedRulesRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListRateBasedRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRateBasedRulesResponse {
     /// <p>If you have more <code>Rules</code> than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>Rules</code>, submit another <code>ListRateBasedRules</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1310,6 +2516,7 @@ pub struct ListRateBasedRulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRegexMatchSetsRequest {
     /// <p>Specifies the number of <code>RegexMatchSet</code> objects that you want AWS WAF to return for this request. If you have more <code>RegexMatchSet</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>RegexMatchSet</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1321,8 +2528,25 @@ pub struct ListRegexMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListRegexMatchSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRegexMatchSetsResponse {
     /// <p>If you have more <code>RegexMatchSet</code> objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>RegexMatchSet</code> objects, submit another <code>ListRegexMatchSets</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1335,6 +2559,7 @@ pub struct ListRegexMatchSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRegexPatternSetsRequest {
     /// <p>Specifies the number of <code>RegexPatternSet</code> objects that you want AWS WAF to return for this request. If you have more <code>RegexPatternSet</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>RegexPatternSet</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1346,8 +2571,25 @@ pub struct ListRegexPatternSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListRegexPatternSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRegexPatternSetsResponse {
     /// <p>If you have more <code>RegexPatternSet</code> objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>RegexPatternSet</code> objects, submit another <code>ListRegexPatternSets</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1360,6 +2602,7 @@ pub struct ListRegexPatternSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRuleGroupsRequest {
     /// <p>Specifies the number of <code>RuleGroups</code> that you want AWS WAF to return for this request. If you have more <code>RuleGroups</code> than the number that you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>RuleGroups</code>.</p>
     #[serde(rename = "Limit")]
@@ -1371,8 +2614,25 @@ pub struct ListRuleGroupsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListRuleGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRuleGroupsResponse {
     /// <p>If you have more <code>RuleGroups</code> than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>RuleGroups</code>, submit another <code>ListRuleGroups</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1385,6 +2645,7 @@ pub struct ListRuleGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListRulesRequest {
     /// <p>Specifies the number of <code>Rules</code> that you want AWS WAF to return for this request. If you have more <code>Rules</code> than the number that you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>Rules</code>.</p>
     #[serde(rename = "Limit")]
@@ -1396,8 +2657,25 @@ pub struct ListRulesRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListRulesResponse {
     /// <p>If you have more <code>Rules</code> than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>Rules</code>, submit another <code>ListRules</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1410,6 +2688,7 @@ pub struct ListRulesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSizeConstraintSetsRequest {
     /// <p>Specifies the number of <code>SizeConstraintSet</code> objects that you want AWS WAF to return for this request. If you have more <code>SizeConstraintSets</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>SizeConstraintSet</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1421,8 +2700,25 @@ pub struct ListSizeConstraintSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListSizeConstraintSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSizeConstraintSetsResponse {
     /// <p>If you have more <code>SizeConstraintSet</code> objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>SizeConstraintSet</code> objects, submit another <code>ListSizeConstraintSets</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1436,6 +2732,7 @@ pub struct ListSizeConstraintSetsResponse {
 
 /// <p>A request to list the <a>SqlInjectionMatchSet</a> objects created by the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSqlInjectionMatchSetsRequest {
     /// <p>Specifies the number of <a>SqlInjectionMatchSet</a> objects that you want AWS WAF to return for this request. If you have more <code>SqlInjectionMatchSet</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>Rules</code>.</p>
     #[serde(rename = "Limit")]
@@ -1447,9 +2744,26 @@ pub struct ListSqlInjectionMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListSqlInjectionMatchSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>The response to a <a>ListSqlInjectionMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSqlInjectionMatchSetsResponse {
     /// <p>If you have more <a>SqlInjectionMatchSet</a> objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>SqlInjectionMatchSet</code> objects, submit another <code>ListSqlInjectionMatchSets</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1462,6 +2776,7 @@ pub struct ListSqlInjectionMatchSetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListSubscribedRuleGroupsRequest {
     /// <p>Specifies the number of subscribed rule groups that you want AWS WAF to return for this request. If you have more objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of objects.</p>
     #[serde(rename = "Limit")]
@@ -1473,8 +2788,25 @@ pub struct ListSubscribedRuleGroupsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListSubscribedRuleGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListSubscribedRuleGroupsResponse {
     /// <p>If you have more objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more objects, submit another <code>ListSubscribedRuleGroups</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1487,6 +2819,7 @@ pub struct ListSubscribedRuleGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListTagsForResourceRequest {
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1498,8 +2831,36 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl Validate for ListTagsForResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListTagsForResourceResponse {
     #[serde(rename = "NextMarker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1510,6 +2871,7 @@ pub struct ListTagsForResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWebACLsRequest {
     /// <p>Specifies the number of <code>WebACL</code> objects that you want AWS WAF to return for this request. If you have more <code>WebACL</code> objects than the number that you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>WebACL</code> objects.</p>
     #[serde(rename = "Limit")]
@@ -1521,8 +2883,25 @@ pub struct ListWebACLsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListWebACLsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWebACLsResponse {
     /// <p>If you have more <code>WebACL</code> objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>WebACL</code> objects, submit another <code>ListWebACLs</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1536,6 +2915,7 @@ pub struct ListWebACLsResponse {
 
 /// <p>A request to list the <a>XssMatchSet</a> objects created by the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListXssMatchSetsRequest {
     /// <p>Specifies the number of <a>XssMatchSet</a> objects that you want AWS WAF to return for this request. If you have more <code>XssMatchSet</code> objects than the number you specify for <code>Limit</code>, the response includes a <code>NextMarker</code> value that you can use to get another batch of <code>Rules</code>.</p>
     #[serde(rename = "Limit")]
@@ -1547,9 +2927,26 @@ pub struct ListXssMatchSetsRequest {
     pub next_marker: Option<String>,
 }
 
+impl Validate for ListXssMatchSetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, None, Some(100));
+        }
+        if let Some(ref value) = self.next_marker {
+            errors.check_length(
+                &field_path(path, "next_marker"),
+                value.chars().count(),
+                Some(1),
+                Some(1224),
+            );
+            errors.check_pattern(&field_path(path, "next_marker"), &value, ".*\\S.*");
+        }
+    }
+}
+
 /// <p>The response to a <a>ListXssMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListXssMatchSetsResponse {
     /// <p>If you have more <a>XssMatchSet</a> objects than the number that you specified for <code>Limit</code> in the request, the response includes a <code>NextMarker</code> value. To list more <code>XssMatchSet</code> objects, submit another <code>ListXssMatchSets</code> request, and specify the <code>NextMarker</code> value from the response in the <code>NextMarker</code> value in the next request.</p>
     #[serde(rename = "NextMarker")]
@@ -1576,6 +2973,39 @@ pub struct LoggingConfiguration {
     pub resource_arn: String,
 }
 
+impl Validate for LoggingConfiguration {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "log_destination_configs"),
+            self.log_destination_configs.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.log_destination_configs.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "log_destination_configs"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(1224));
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+        if let Some(ref value) = self.redacted_fields {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "redacted_fields"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>Specifies the <a>ByteMatchSet</a>, <a>IPSet</a>, <a>SqlInjectionMatchSet</a>, <a>XssMatchSet</a>, <a>RegexMatchSet</a>, <a>GeoMatchSet</a>, and <a>SizeConstraintSet</a> objects that you want to add to a <code>Rule</code> and, for each object, indicates whether you want to negate the settings, for example, requests that do NOT originate from the IP address 192.0.2.44. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Predicate {
@@ -1590,15 +3020,35 @@ pub struct Predicate {
     pub type_: String,
 }
 
+impl Validate for Predicate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "data_id"),
+            self.data_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "data_id"), &self.data_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutLoggingConfigurationRequest {
     /// <p>The Amazon Kinesis Data Firehose that contains the inspected traffic information, the redacted fields details, and the Amazon Resource Name (ARN) of the web ACL to monitor.</p>
     #[serde(rename = "LoggingConfiguration")]
     pub logging_configuration: LoggingConfiguration,
 }
 
+impl Validate for PutLoggingConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.logging_configuration
+            .validate_at(&field_path(path, "logging_configuration"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutLoggingConfigurationResponse {
     /// <p>The <a>LoggingConfiguration</a> that you submitted in the request.</p>
     #[serde(rename = "LoggingConfiguration")]
@@ -1607,6 +3057,7 @@ pub struct PutLoggingConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutPermissionPolicyRequest {
     /// <p>The policy to attach to the specified RuleGroup.</p>
     #[serde(rename = "Policy")]
@@ -1616,13 +3067,36 @@ pub struct PutPermissionPolicyRequest {
     pub resource_arn: String,
 }
 
+impl Validate for PutPermissionPolicyRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "policy"),
+            self.policy.chars().count(),
+            Some(1),
+            Some(395000),
+        );
+        errors.check_pattern(&field_path(path, "policy"), &self.policy, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutPermissionPolicyResponse {}
 
 /// <p>A <code>RateBasedRule</code> is identical to a regular <a>Rule</a>, with one addition: a <code>RateBasedRule</code> counts the number of requests that arrive from a specified IP address every five minutes. For example, based on recent requests that you've seen from an attacker, you might create a <code>RateBasedRule</code> that includes the following conditions: </p> <ul> <li> <p>The requests come from 192.0.2.44.</p> </li> <li> <p>They contain the value <code>BadBot</code> in the <code>User-Agent</code> header.</p> </li> </ul> <p>In the rule, you also define the rate limit as 15,000.</p> <p>Requests that meet both of these conditions and exceed 15,000 requests every five minutes trigger the rule's action (block or count), which is defined in the web ACL.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RateBasedRule {
     /// <p>The <code>Predicates</code> object contains one <code>Predicate</code> element for each <a>ByteMatchSet</a>, <a>IPSet</a>, or <a>SqlInjectionMatchSet</a> object that you want to include in a <code>RateBasedRule</code>.</p>
     #[serde(rename = "MatchPredicates")]
@@ -1648,7 +3122,7 @@ pub struct RateBasedRule {
 
 /// <p>In a <a>GetRegexMatchSet</a> request, <code>RegexMatchSet</code> is a complex type that contains the <code>RegexMatchSetId</code> and <code>Name</code> of a <code>RegexMatchSet</code>, and the values that you specified when you updated the <code>RegexMatchSet</code>.</p> <p> The values are contained in a <code>RegexMatchTuple</code> object, which specify the parts of web requests that you want AWS WAF to inspect and the values that you want AWS WAF to search for. If a <code>RegexMatchSet</code> contains more than one <code>RegexMatchTuple</code> object, a request needs to match the settings in only one <code>ByteMatchTuple</code> to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegexMatchSet {
     /// <p>A friendly name or description of the <a>RegexMatchSet</a>. You can't change <code>Name</code> after you create a <code>RegexMatchSet</code>.</p>
     #[serde(rename = "Name")]
@@ -1666,7 +3140,7 @@ pub struct RegexMatchSet {
 
 /// <p>Returned by <a>ListRegexMatchSets</a>. Each <code>RegexMatchSetSummary</code> object includes the <code>Name</code> and <code>RegexMatchSetId</code> for one <a>RegexMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegexMatchSetSummary {
     /// <p>A friendly name or description of the <a>RegexMatchSet</a>. You can't change <code>Name</code> after you create a <code>RegexMatchSet</code>.</p>
     #[serde(rename = "Name")]
@@ -1678,6 +3152,7 @@ pub struct RegexMatchSetSummary {
 
 /// <p>In an <a>UpdateRegexMatchSet</a> request, <code>RegexMatchSetUpdate</code> specifies whether to insert or delete a <a>RegexMatchTuple</a> and includes the settings for the <code>RegexMatchTuple</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegexMatchSetUpdate {
     /// <p>Specifies whether to insert or delete a <a>RegexMatchTuple</a>.</p>
     #[serde(rename = "Action")]
@@ -1687,6 +3162,13 @@ pub struct RegexMatchSetUpdate {
     pub regex_match_tuple: RegexMatchTuple,
 }
 
+impl Validate for RegexMatchSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.regex_match_tuple
+            .validate_at(&field_path(path, "regex_match_tuple"), errors);
+    }
+}
+
 /// <p><p>The regular expression pattern that you want AWS WAF to search for in web requests, the location in requests that you want AWS WAF to search, and other settings. Each <code>RegexMatchTuple</code> object contains: </p> <ul> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RegexMatchTuple {
@@ -1701,9 +3183,27 @@ pub struct RegexMatchTuple {
     pub text_transformation: String,
 }
 
+impl Validate for RegexMatchTuple {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.field_to_match
+            .validate_at(&field_path(path, "field_to_match"), errors);
+        errors.check_length(
+            &field_path(path, "regex_pattern_set_id"),
+            self.regex_pattern_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_pattern_set_id"),
+            &self.regex_pattern_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>The <code>RegexPatternSet</code> specifies the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegexPatternSet {
     /// <p>A friendly name or description of the <a>RegexPatternSet</a>. You can't change <code>Name</code> after you create a <code>RegexPatternSet</code>.</p>
     #[serde(rename = "Name")]
@@ -1719,7 +3219,7 @@ pub struct RegexPatternSet {
 
 /// <p>Returned by <a>ListRegexPatternSets</a>. Each <code>RegexPatternSetSummary</code> object includes the <code>Name</code> and <code>RegexPatternSetId</code> for one <a>RegexPatternSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegexPatternSetSummary {
     /// <p>A friendly name or description of the <a>RegexPatternSet</a>. You can't change <code>Name</code> after you create a <code>RegexPatternSet</code>.</p>
     #[serde(rename = "Name")]
@@ -1731,6 +3231,7 @@ pub struct RegexPatternSetSummary {
 
 /// <p>In an <a>UpdateRegexPatternSet</a> request, <code>RegexPatternSetUpdate</code> specifies whether to insert or delete a <code>RegexPatternString</code> and includes the settings for the <code>RegexPatternString</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegexPatternSetUpdate {
     /// <p>Specifies whether to insert or delete a <code>RegexPatternString</code>.</p>
     #[serde(rename = "Action")]
@@ -1740,9 +3241,25 @@ pub struct RegexPatternSetUpdate {
     pub regex_pattern_string: String,
 }
 
+impl Validate for RegexPatternSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "regex_pattern_string"),
+            self.regex_pattern_string.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_pattern_string"),
+            &self.regex_pattern_string,
+            ".*",
+        );
+    }
+}
+
 /// <p>A combination of <a>ByteMatchSet</a>, <a>IPSet</a>, and/or <a>SqlInjectionMatchSet</a> objects that identify the web requests that you want to allow, block, or count. For example, you might create a <code>Rule</code> that includes the following predicates:</p> <ul> <li> <p>An <code>IPSet</code> that causes AWS WAF to search for web requests that originate from the IP address <code>192.0.2.44</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that causes AWS WAF to search for web requests for which the value of the <code>User-Agent</code> header is <code>BadBot</code>.</p> </li> </ul> <p>To match the settings in this <code>Rule</code>, a request must originate from <code>192.0.2.44</code> AND include a <code>User-Agent</code> header for which the value is <code>BadBot</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Rule {
     /// <p>A friendly name or description for the metrics for this <code>Rule</code>. The name can contain only alphanumeric characters (A-Z, a-z, 0-9), with maximum length 128 and minimum length one. It can't contain whitespace or metric names reserved for AWS WAF, including "All" and "Default_Action." You can't change <code>MetricName</code> after you create the <code>Rule</code>.</p>
     #[serde(rename = "MetricName")]
@@ -1762,7 +3279,7 @@ pub struct Rule {
 
 /// <p><p>A collection of predefined rules that you can add to a web ACL.</p> <p>Rule groups are subject to the following limits:</p> <ul> <li> <p>Three rule groups per account. You can request an increase to this limit by contacting customer support.</p> </li> <li> <p>One rule group per web ACL.</p> </li> <li> <p>Ten rules per rule group.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RuleGroup {
     /// <p>A friendly name or description for the metrics for this <code>RuleGroup</code>. The name can contain only alphanumeric characters (A-Z, a-z, 0-9), with maximum length 128 and minimum length one. It can't contain whitespace or metric names reserved for AWS WAF, including "All" and "Default_Action." You can't change the name of the metric after you create the <code>RuleGroup</code>.</p>
     #[serde(rename = "MetricName")]
@@ -1779,7 +3296,7 @@ pub struct RuleGroup {
 
 /// <p>Contains the identifier and the friendly name or description of the <code>RuleGroup</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RuleGroupSummary {
     /// <p>A friendly name or description of the <a>RuleGroup</a>. You can't change the name of a <code>RuleGroup</code> after you create it.</p>
     #[serde(rename = "Name")]
@@ -1791,6 +3308,7 @@ pub struct RuleGroupSummary {
 
 /// <p>Specifies an <code>ActivatedRule</code> and indicates whether you want to add it to a <code>RuleGroup</code> or delete it from a <code>RuleGroup</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RuleGroupUpdate {
     /// <p>Specify <code>INSERT</code> to add an <code>ActivatedRule</code> to a <code>RuleGroup</code>. Use <code>DELETE</code> to remove an <code>ActivatedRule</code> from a <code>RuleGroup</code>.</p>
     #[serde(rename = "Action")]
@@ -1800,9 +3318,16 @@ pub struct RuleGroupUpdate {
     pub activated_rule: ActivatedRule,
 }
 
+impl Validate for RuleGroupUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.activated_rule
+            .validate_at(&field_path(path, "activated_rule"), errors);
+    }
+}
+
 /// <p>Contains the identifier and the friendly name or description of the <code>Rule</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RuleSummary {
     /// <p>A friendly name or description of the <a>Rule</a>. You can't change the name of a <code>Rule</code> after you create it.</p>
     #[serde(rename = "Name")]
@@ -1814,6 +3339,7 @@ pub struct RuleSummary {
 
 /// <p>Specifies a <code>Predicate</code> (such as an <code>IPSet</code>) and indicates whether you want to add it to a <code>Rule</code> or delete it from a <code>Rule</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RuleUpdate {
     /// <p>Specify <code>INSERT</code> to add a <code>Predicate</code> to a <code>Rule</code>. Use <code>DELETE</code> to remove a <code>Predicate</code> from a <code>Rule</code>.</p>
     #[serde(rename = "Action")]
@@ -1823,9 +3349,16 @@ pub struct RuleUpdate {
     pub predicate: Predicate,
 }
 
+impl Validate for RuleUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.predicate
+            .validate_at(&field_path(path, "predicate"), errors);
+    }
+}
+
 /// <p>The response from a <a>GetSampledRequests</a> request includes a <code>SampledHTTPRequests</code> complex type that appears as <code>SampledRequests</code> in the response syntax. <code>SampledHTTPRequests</code> contains one <code>SampledHTTPRequest</code> object for each web request that is returned by <code>GetSampledRequests</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SampledHTTPRequest {
     /// <p>The action for the <code>Rule</code> that the request matched: <code>ALLOW</code>, <code>BLOCK</code>, or <code>COUNT</code>.</p>
     #[serde(rename = "Action")]
@@ -1864,9 +3397,22 @@ pub struct SizeConstraint {
     pub text_transformation: String,
 }
 
+impl Validate for SizeConstraint {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.field_to_match
+            .validate_at(&field_path(path, "field_to_match"), errors);
+        errors.check_range(
+            &field_path(path, "size"),
+            self.size,
+            None,
+            Some(21474836480),
+        );
+    }
+}
+
 /// <p>A complex type that contains <code>SizeConstraint</code> objects, which specify the parts of web requests that you want AWS WAF to inspect the size of. If a <code>SizeConstraintSet</code> contains more than one <code>SizeConstraint</code> object, a request only needs to match one constraint to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SizeConstraintSet {
     /// <p>The name, if any, of the <code>SizeConstraintSet</code>.</p>
     #[serde(rename = "Name")]
@@ -1882,7 +3428,7 @@ pub struct SizeConstraintSet {
 
 /// <p>The <code>Id</code> and <code>Name</code> of a <code>SizeConstraintSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SizeConstraintSetSummary {
     /// <p>The name of the <code>SizeConstraintSet</code>, if any.</p>
     #[serde(rename = "Name")]
@@ -1894,6 +3440,7 @@ pub struct SizeConstraintSetSummary {
 
 /// <p>Specifies the part of a web request that you want to inspect the size of and indicates whether you want to add the specification to a <a>SizeConstraintSet</a> or delete it from a <code>SizeConstraintSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SizeConstraintSetUpdate {
     /// <p>Specify <code>INSERT</code> to add a <a>SizeConstraintSetUpdate</a> to a <a>SizeConstraintSet</a>. Use <code>DELETE</code> to remove a <code>SizeConstraintSetUpdate</code> from a <code>SizeConstraintSet</code>.</p>
     #[serde(rename = "Action")]
@@ -1903,9 +3450,16 @@ pub struct SizeConstraintSetUpdate {
     pub size_constraint: SizeConstraint,
 }
 
+impl Validate for SizeConstraintSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.size_constraint
+            .validate_at(&field_path(path, "size_constraint"), errors);
+    }
+}
+
 /// <p>A complex type that contains <code>SqlInjectionMatchTuple</code> objects, which specify the parts of web requests that you want AWS WAF to inspect for snippets of malicious SQL code and, if you want AWS WAF to inspect a header, the name of the header. If a <code>SqlInjectionMatchSet</code> contains more than one <code>SqlInjectionMatchTuple</code> object, a request needs to include snippets of SQL code in only one of the specified parts of the request to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SqlInjectionMatchSet {
     /// <p>The name, if any, of the <code>SqlInjectionMatchSet</code>.</p>
     #[serde(rename = "Name")]
@@ -1921,7 +3475,7 @@ pub struct SqlInjectionMatchSet {
 
 /// <p>The <code>Id</code> and <code>Name</code> of a <code>SqlInjectionMatchSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SqlInjectionMatchSetSummary {
     /// <p>The name of the <code>SqlInjectionMatchSet</code>, if any, specified by <code>Id</code>.</p>
     #[serde(rename = "Name")]
@@ -1933,6 +3487,7 @@ pub struct SqlInjectionMatchSetSummary {
 
 /// <p>Specifies the part of a web request that you want to inspect for snippets of malicious SQL code and indicates whether you want to add the specification to a <a>SqlInjectionMatchSet</a> or delete it from a <code>SqlInjectionMatchSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SqlInjectionMatchSetUpdate {
     /// <p>Specify <code>INSERT</code> to add a <a>SqlInjectionMatchSetUpdate</a> to a <a>SqlInjectionMatchSet</a>. Use <code>DELETE</code> to remove a <code>SqlInjectionMatchSetUpdate</code> from a <code>SqlInjectionMatchSet</code>.</p>
     #[serde(rename = "Action")]
@@ -1942,6 +3497,13 @@ pub struct SqlInjectionMatchSetUpdate {
     pub sql_injection_match_tuple: SqlInjectionMatchTuple,
 }
 
+impl Validate for SqlInjectionMatchSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.sql_injection_match_tuple
+            .validate_at(&field_path(path, "sql_injection_match_tuple"), errors);
+    }
+}
+
 /// <p>Specifies the part of a web request that you want AWS WAF to inspect for snippets of malicious SQL code and, if you want AWS WAF to inspect a header, the name of the header.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SqlInjectionMatchTuple {
@@ -1953,9 +3515,16 @@ pub struct SqlInjectionMatchTuple {
     pub text_transformation: String,
 }
 
+impl Validate for SqlInjectionMatchTuple {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.field_to_match
+            .validate_at(&field_path(path, "field_to_match"), errors);
+    }
+}
+
 /// <p>A summary of the rule groups you are subscribed to.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SubscribedRuleGroupSummary {
     /// <p>A friendly name or description for the metrics for this <code>RuleGroup</code>. The name can contain only alphanumeric characters (A-Z, a-z, 0-9), with maximum length 128 and minimum length one. It can't contain whitespace or metric names reserved for AWS WAF, including "All" and "Default_Action." You can't change the name of the metric after you create the <code>RuleGroup</code>.</p>
     #[serde(rename = "MetricName")]
@@ -1978,8 +3547,31 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key {
+            errors.check_length(
+                &field_path(path, "key"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "key"), &value, ".*\\S.*");
+        }
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "value"), &value, ".*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagInfoForResource {
     #[serde(rename = "ResourceARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1990,6 +3582,7 @@ pub struct TagInfoForResource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TagResourceRequest {
     #[serde(rename = "ResourceARN")]
     pub resource_arn: String,
@@ -1997,8 +3590,29 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for TagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+        errors.check_length(&field_path(path, "tags"), self.tags.len(), Some(1), None);
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TagResourceResponse {}
 
 /// <p>In a <a>GetSampledRequests</a> request, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which you want AWS WAF to return a sample of web requests.</p> <p>In a <a>GetSampledRequests</a> response, the <code>StartTime</code> and <code>EndTime</code> objects specify the time range for which AWS WAF actually returned a sample of web requests. AWS WAF gets the specified number of requests from among the first 5,000 requests that your AWS resource receives during the specified time period. If your resource receives more than 5,000 requests during that period, AWS WAF stops sampling after the 5,000th request. In that case, <code>EndTime</code> is the time that AWS WAF received the 5,000th request. </p>
@@ -2013,6 +3627,7 @@ pub struct TimeWindow {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UntagResourceRequest {
     #[serde(rename = "ResourceARN")]
     pub resource_arn: String,
@@ -2020,11 +3635,39 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for UntagResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            Some(1),
+            Some(1224),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_arn"),
+            &self.resource_arn,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "tag_keys"),
+            self.tag_keys.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(128));
+            errors.check_pattern(&item_path, &item, ".*\\S.*");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UntagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateByteMatchSetRequest {
     /// <p>The <code>ByteMatchSetId</code> of the <a>ByteMatchSet</a> that you want to update. <code>ByteMatchSetId</code> is returned by <a>CreateByteMatchSet</a> and by <a>ListByteMatchSets</a>.</p>
     #[serde(rename = "ByteMatchSetId")]
@@ -2037,8 +3680,45 @@ pub struct UpdateByteMatchSetRequest {
     pub updates: Vec<ByteMatchSetUpdate>,
 }
 
+impl Validate for UpdateByteMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "byte_match_set_id"),
+            self.byte_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "byte_match_set_id"),
+            &self.byte_match_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateByteMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateByteMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2047,6 +3727,7 @@ pub struct UpdateByteMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGeoMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2059,8 +3740,41 @@ pub struct UpdateGeoMatchSetRequest {
     pub updates: Vec<GeoMatchSetUpdate>,
 }
 
+impl Validate for UpdateGeoMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "geo_match_set_id"),
+            self.geo_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "geo_match_set_id"),
+            &self.geo_match_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGeoMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateGeoMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2069,6 +3783,7 @@ pub struct UpdateGeoMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIPSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2081,8 +3796,41 @@ pub struct UpdateIPSetRequest {
     pub updates: Vec<IPSetUpdate>,
 }
 
+impl Validate for UpdateIPSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "ip_set_id"),
+            self.ip_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "ip_set_id"), &self.ip_set_id, ".*\\S.*");
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateIPSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateIPSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2091,6 +3839,7 @@ pub struct UpdateIPSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRateBasedRuleRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2106,8 +3855,41 @@ pub struct UpdateRateBasedRuleRequest {
     pub updates: Vec<RuleUpdate>,
 }
 
+impl Validate for UpdateRateBasedRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_range(
+            &field_path(path, "rate_limit"),
+            self.rate_limit,
+            Some(100),
+            Some(2000000000),
+        );
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRateBasedRuleResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateRateBasedRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2116,6 +3898,7 @@ pub struct UpdateRateBasedRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRegexMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2128,8 +3911,45 @@ pub struct UpdateRegexMatchSetRequest {
     pub updates: Vec<RegexMatchSetUpdate>,
 }
 
+impl Validate for UpdateRegexMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "regex_match_set_id"),
+            self.regex_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_match_set_id"),
+            &self.regex_match_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRegexMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateRegexMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2138,6 +3958,7 @@ pub struct UpdateRegexMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRegexPatternSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2150,8 +3971,45 @@ pub struct UpdateRegexPatternSetRequest {
     pub updates: Vec<RegexPatternSetUpdate>,
 }
 
+impl Validate for UpdateRegexPatternSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "regex_pattern_set_id"),
+            self.regex_pattern_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "regex_pattern_set_id"),
+            &self.regex_pattern_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRegexPatternSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateRegexPatternSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2160,6 +4018,7 @@ pub struct UpdateRegexPatternSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRuleGroupRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2172,8 +4031,45 @@ pub struct UpdateRuleGroupRequest {
     pub updates: Vec<RuleGroupUpdate>,
 }
 
+impl Validate for UpdateRuleGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "rule_group_id"),
+            self.rule_group_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "rule_group_id"),
+            &self.rule_group_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRuleGroupResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateRuleGroup</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2182,6 +4078,7 @@ pub struct UpdateRuleGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRuleRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2194,8 +4091,35 @@ pub struct UpdateRuleRequest {
     pub updates: Vec<RuleUpdate>,
 }
 
+impl Validate for UpdateRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "rule_id"),
+            self.rule_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "rule_id"), &self.rule_id, ".*\\S.*");
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRuleResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateRule</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2204,6 +4128,7 @@ pub struct UpdateRuleResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSizeConstraintSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2216,9 +4141,46 @@ pub struct UpdateSizeConstraintSetRequest {
     pub updates: Vec<SizeConstraintSetUpdate>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateSizeConstraintSetResponse {
+impl Validate for UpdateSizeConstraintSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "size_constraint_set_id"),
+            self.size_constraint_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "size_constraint_set_id"),
+            &self.size_constraint_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct UpdateSizeConstraintSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateSizeConstraintSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2227,6 +4189,7 @@ pub struct UpdateSizeConstraintSetResponse {
 
 /// <p>A request to update a <a>SqlInjectionMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSqlInjectionMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2239,9 +4202,46 @@ pub struct UpdateSqlInjectionMatchSetRequest {
     pub updates: Vec<SqlInjectionMatchSetUpdate>,
 }
 
+impl Validate for UpdateSqlInjectionMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "sql_injection_match_set_id"),
+            self.sql_injection_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "sql_injection_match_set_id"),
+            &self.sql_injection_match_set_id,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 /// <p>The response to an <a>UpdateSqlInjectionMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSqlInjectionMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateSqlInjectionMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2250,6 +4250,7 @@ pub struct UpdateSqlInjectionMatchSetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateWebACLRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2267,8 +4268,37 @@ pub struct UpdateWebACLRequest {
     pub web_acl_id: String,
 }
 
+impl Validate for UpdateWebACLRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        if let Some(ref value) = self.updates {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "web_acl_id"),
+            self.web_acl_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(&field_path(path, "web_acl_id"), &self.web_acl_id, ".*\\S.*");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateWebACLResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateWebACL</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2278,6 +4308,7 @@ pub struct UpdateWebACLResponse {
 
 /// <p>A request to update an <a>XssMatchSet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateXssMatchSetRequest {
     /// <p>The value returned by the most recent call to <a>GetChangeToken</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2290,9 +4321,46 @@ pub struct UpdateXssMatchSetRequest {
     pub xss_match_set_id: String,
 }
 
+impl Validate for UpdateXssMatchSetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "change_token"),
+            self.change_token.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "change_token"),
+            &self.change_token,
+            ".*\\S.*",
+        );
+        errors.check_length(
+            &field_path(path, "updates"),
+            self.updates.len(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.updates.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "updates"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "xss_match_set_id"),
+            self.xss_match_set_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "xss_match_set_id"),
+            &self.xss_match_set_id,
+            ".*\\S.*",
+        );
+    }
+}
+
 /// <p>The response to an <a>UpdateXssMatchSets</a> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateXssMatchSetResponse {
     /// <p>The <code>ChangeToken</code> that you used to submit the <code>UpdateXssMatchSet</code> request. You can also use this value to query the status of the request. For more information, see <a>GetChangeTokenStatus</a>.</p>
     #[serde(rename = "ChangeToken")]
@@ -2318,7 +4386,7 @@ pub struct WafOverrideAction {
 
 /// <p>Contains the <code>Rules</code> that identify the requests that you want to allow, block, or count. In a <code>WebACL</code>, you also specify a default action (<code>ALLOW</code> or <code>BLOCK</code>), and the action for each <code>Rule</code> that you add to a <code>WebACL</code>, for example, block requests from specified IP addresses or block requests from specified referrers. You also associate the <code>WebACL</code> with a CloudFront distribution to identify the requests that you want AWS WAF to filter. If you add more than one <code>Rule</code> to a <code>WebACL</code>, a request needs to match only one of the specifications to be allowed, blocked, or counted. For more information, see <a>UpdateWebACL</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WebACL {
     /// <p>The action to perform if none of the <code>Rules</code> contained in the <code>WebACL</code> match. The action is specified by the <a>WafAction</a> object.</p>
     #[serde(rename = "DefaultAction")]
@@ -2345,7 +4413,7 @@ pub struct WebACL {
 
 /// <p>Contains the identifier and the name or description of the <a>WebACL</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WebACLSummary {
     /// <p>A friendly name or description of the <a>WebACL</a>. You can't change the name of a <code>WebACL</code> after you create it.</p>
     #[serde(rename = "Name")]
@@ -2357,6 +4425,7 @@ pub struct WebACLSummary {
 
 /// <p>Specifies whether to insert a <code>Rule</code> into or delete a <code>Rule</code> from a <code>WebACL</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct WebACLUpdate {
     /// <p>Specifies whether to insert a <code>Rule</code> into or delete a <code>Rule</code> from a <code>WebACL</code>.</p>
     #[serde(rename = "Action")]
@@ -2366,9 +4435,16 @@ pub struct WebACLUpdate {
     pub activated_rule: ActivatedRule,
 }
 
+impl Validate for WebACLUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.activated_rule
+            .validate_at(&field_path(path, "activated_rule"), errors);
+    }
+}
+
 /// <p>A complex type that contains <code>XssMatchTuple</code> objects, which specify the parts of web requests that you want AWS WAF to inspect for cross-site scripting attacks and, if you want AWS WAF to inspect a header, the name of the header. If a <code>XssMatchSet</code> contains more than one <code>XssMatchTuple</code> object, a request needs to include cross-site scripting attacks in only one of the specified parts of the request to be considered a match.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct XssMatchSet {
     /// <p>The name, if any, of the <code>XssMatchSet</code>.</p>
     #[serde(rename = "Name")]
@@ -2384,7 +4460,7 @@ pub struct XssMatchSet {
 
 /// <p>The <code>Id</code> and <code>Name</code> of an <code>XssMatchSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct XssMatchSetSummary {
     /// <p>The name of the <code>XssMatchSet</code>, if any, specified by <code>Id</code>.</p>
     #[serde(rename = "Name")]
@@ -2396,6 +4472,7 @@ pub struct XssMatchSetSummary {
 
 /// <p>Specifies the part of a web request that you want to inspect for cross-site scripting attacks and indicates whether you want to add the specification to an <a>XssMatchSet</a> or delete it from an <code>XssMatchSet</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct XssMatchSetUpdate {
     /// <p>Specify <code>INSERT</code> to add an <a>XssMatchSetUpdate</a> to an <a>XssMatchSet</a>. Use <code>DELETE</code> to remove an <code>XssMatchSetUpdate</code> from an <code>XssMatchSet</code>.</p>
     #[serde(rename = "Action")]
@@ -2405,6 +4482,13 @@ pub struct XssMatchSetUpdate {
     pub xss_match_tuple: XssMatchTuple,
 }
 
+impl Validate for XssMatchSetUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.xss_match_tuple
+            .validate_at(&field_path(path, "xss_match_tuple"), errors);
+    }
+}
+
 /// <p>Specifies the part of a web request that you want AWS WAF to inspect for cross-site scripting attacks and, if you want AWS WAF to inspect a header, the name of the header.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct XssMatchTuple {
@@ -2416,6 +4500,13 @@ pub struct XssMatchTuple {
     pub text_transformation: String,
 }
 
+impl Validate for XssMatchTuple {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.field_to_match
+            .validate_at(&field_path(path, "field_to_match"), errors);
+    }
+}
+
 /// Errors returned by CreateByteMatchSet
 #[derive(Debug, PartialEq)]
 pub enum CreateByteMatchSetError {
@@ -2463,7 +4554,12 @@ impl CreateByteMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(CreateByteMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2528,7 +4624,12 @@ impl CreateGeoMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(CreateGeoMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2591,7 +4692,12 @@ impl CreateIPSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(CreateIPSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2673,7 +4779,12 @@ impl CreateRateBasedRuleError {
                         CreateRateBasedRuleError::WAFTagOperationInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2734,7 +4845,12 @@ impl CreateRegexMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(CreateRegexMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2791,7 +4907,12 @@ impl CreateRegexPatternSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(CreateRegexPatternSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2863,7 +4984,12 @@ impl CreateRuleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2934,7 +5060,12 @@ impl CreateRuleGroupError {
                         CreateRuleGroupError::WAFTagOperationInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3010,7 +5141,12 @@ impl CreateSizeConstraintSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3087,7 +5223,12 @@ impl CreateSqlInjectionMatchSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3166,7 +5307,12 @@ impl CreateWebACLError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3234,7 +5380,12 @@ impl CreateXssMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(CreateXssMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3305,7 +5456,12 @@ impl DeleteByteMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeleteByteMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3370,7 +5526,12 @@ impl DeleteGeoMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeleteGeoMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3433,7 +5594,12 @@ impl DeleteIPSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeleteIPSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3489,7 +5655,12 @@ impl DeleteLoggingConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3538,7 +5709,12 @@ impl DeletePermissionPolicyError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeletePermissionPolicyError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3620,7 +5796,12 @@ impl DeleteRateBasedRuleError {
                         DeleteRateBasedRuleError::WAFTagOperationInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3695,7 +5876,12 @@ impl DeleteRegexMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeleteRegexMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3768,7 +5954,12 @@ impl DeleteRegexPatternSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeleteRegexPatternSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3843,7 +6034,12 @@ impl DeleteRuleError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3920,7 +6116,12 @@ impl DeleteRuleGroupError {
                         DeleteRuleGroupError::WAFTagOperationInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3997,7 +6198,12 @@ impl DeleteSizeConstraintSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4074,7 +6280,12 @@ impl DeleteSqlInjectionMatchSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4149,7 +6360,12 @@ impl DeleteWebACLError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4216,7 +6432,12 @@ impl DeleteXssMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(DeleteXssMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4264,7 +6485,12 @@ impl GetByteMatchSetError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetByteMatchSetError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4299,7 +6525,12 @@ impl GetChangeTokenError {
                 "WAFInternalErrorException" => {
                     return RusotoError::Service(GetChangeTokenError::WAFInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4341,7 +6572,12 @@ impl GetChangeTokenStatusError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4385,7 +6621,12 @@ impl GetGeoMatchSetError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetGeoMatchSetError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4430,7 +6671,12 @@ impl GetIPSetError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetIPSetError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4474,7 +6720,12 @@ impl GetLoggingConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4517,7 +6768,12 @@ impl GetPermissionPolicyError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4561,7 +6817,12 @@ impl GetRateBasedRuleError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetRateBasedRuleError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4621,7 +6882,12 @@ impl GetRateBasedRuleManagedKeysError {
                         GetRateBasedRuleManagedKeysError::WAFNonexistentItem(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4667,7 +6933,12 @@ impl GetRegexMatchSetError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetRegexMatchSetError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4716,7 +6987,12 @@ impl GetRegexPatternSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4761,7 +7037,12 @@ impl GetRuleError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetRuleError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4801,7 +7082,12 @@ impl GetRuleGroupError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetRuleGroupError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4842,7 +7128,12 @@ impl GetSampledRequestsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4892,7 +7183,12 @@ impl GetSizeConstraintSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4943,7 +7239,12 @@ impl GetSqlInjectionMatchSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4988,7 +7289,12 @@ impl GetWebACLError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetWebACLError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5033,7 +7339,12 @@ impl GetXssMatchSetError {
                 "WAFNonexistentItemException" => {
                     return RusotoError::Service(GetXssMatchSetError::WAFNonexistentItem(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5086,7 +7397,12 @@ impl ListActivatedRulesInRuleGroupError {
                         ListActivatedRulesInRuleGroupError::WAFNonexistentItem(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5126,7 +7442,12 @@ impl ListByteMatchSetsError {
                 "WAFInvalidAccountException" => {
                     return RusotoError::Service(ListByteMatchSetsError::WAFInvalidAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5165,7 +7486,12 @@ impl ListGeoMatchSetsError {
                 "WAFInvalidAccountException" => {
                     return RusotoError::Service(ListGeoMatchSetsError::WAFInvalidAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5204,7 +7530,12 @@ impl ListIPSetsError {
                 "WAFInvalidAccountException" => {
                     return RusotoError::Service(ListIPSetsError::WAFInvalidAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5254,7 +7585,12 @@ impl ListLoggingConfigurationsError {
                         ListLoggingConfigurationsError::WAFNonexistentItem(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5296,7 +7632,12 @@ impl ListRateBasedRulesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5337,7 +7678,12 @@ impl ListRegexMatchSetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5380,7 +7726,12 @@ impl ListRegexPatternSetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5414,7 +7765,12 @@ impl ListRuleGroupsError {
                 "WAFInternalErrorException" => {
                     return RusotoError::Service(ListRuleGroupsError::WAFInternalError(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5452,7 +7808,12 @@ impl ListRulesError {
                 "WAFInvalidAccountException" => {
                     return RusotoError::Service(ListRulesError::WAFInvalidAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5495,7 +7856,12 @@ impl ListSizeConstraintSetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5538,7 +7904,12 @@ impl ListSqlInjectionMatchSetsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5581,7 +7952,12 @@ impl ListSubscribedRuleGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5647,7 +8023,12 @@ impl ListTagsForResourceError {
                         ListTagsForResourceError::WAFTagOperationInternalError(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5690,7 +8071,12 @@ impl ListWebACLsError {
                 "WAFInvalidAccountException" => {
                     return RusotoError::Service(ListWebACLsError::WAFInvalidAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5729,7 +8115,12 @@ impl ListXssMatchSetsError {
                 "WAFInvalidAccountException" => {
                     return RusotoError::Service(ListXssMatchSetsError::WAFInvalidAccount(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5786,7 +8177,12 @@ impl PutLoggingConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5843,7 +8239,12 @@ impl PutPermissionPolicyError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(PutPermissionPolicyError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5910,7 +8311,12 @@ impl TagResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -5975,7 +8381,12 @@ impl UntagResourceError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6060,7 +8471,12 @@ impl UpdateByteMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateByteMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6148,7 +8564,12 @@ impl UpdateGeoMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateGeoMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6229,7 +8650,12 @@ impl UpdateIPSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateIPSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6326,7 +8752,12 @@ impl UpdateRateBasedRuleError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateRateBasedRuleError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6416,7 +8847,12 @@ impl UpdateRegexMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateRegexMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6505,7 +8941,12 @@ impl UpdateRegexPatternSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateRegexPatternSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6585,7 +9026,12 @@ impl UpdateRuleError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateRuleError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6658,7 +9104,12 @@ impl UpdateRuleGroupError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateRuleGroupError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6755,7 +9206,12 @@ impl UpdateSizeConstraintSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6849,7 +9305,12 @@ impl UpdateSqlInjectionMatchSetError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -6938,7 +9399,12 @@ impl UpdateWebACLError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7023,7 +9489,12 @@ impl UpdateXssMatchSetError {
                 "WAFStaleDataException" => {
                     return RusotoError::Service(UpdateXssMatchSetError::WAFStaleData(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -7495,2176 +9966,6799 @@ pub trait Waf {
         input: UpdateXssMatchSetRequest,
     ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError>;
 }
-/// A client for the WAF API.
-#[derive(Clone)]
-pub struct WafClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl WafClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> WafClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> WafClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> WafClient {
-        WafClient { client, region }
-    }
-}
-
-impl Waf for WafClient {
-    /// <p>Creates a <code>ByteMatchSet</code>. You then use <a>UpdateByteMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>ByteMatchSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>BadBot</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateByteMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+const _: Option<&dyn Waf> = None;
+impl<'a, T: Waf + ?Sized> Waf for &'a T {
     fn create_byte_match_set(
         &self,
         input: CreateByteMatchSetRequest,
     ) -> RusotoFuture<CreateByteMatchSetResponse, CreateByteMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateByteMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateByteMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateByteMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_byte_match_set(input)
     }
-
-    /// <p>Creates an <a>GeoMatchSet</a>, which you use to specify which web requests you want to allow or block based on the country that the requests originate from. For example, if you're receiving a lot of requests from one or more countries and you want to block the requests, you can create an <code>GeoMatchSet</code> that contains those countries and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSetSet</code> request to specify the countries that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_geo_match_set(
         &self,
         input: CreateGeoMatchSetRequest,
     ) -> RusotoFuture<CreateGeoMatchSetResponse, CreateGeoMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateGeoMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGeoMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGeoMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_geo_match_set(input)
     }
-
-    /// <p>Creates an <a>IPSet</a>, which you use to specify which web requests that you want to allow or block based on the IP addresses that the requests originate from. For example, if you're receiving a lot of requests from one or more individual IP addresses or one or more ranges of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that contains those IP addresses and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateIPSet</code> request.</p> </li> <li> <p>Submit a <code>CreateIPSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_ip_set(
         &self,
         input: CreateIPSetRequest,
     ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateIPSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIPSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateIPSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_ip_set(input)
     }
-
-    /// <p>Creates a <a>RateBasedRule</a>. The <code>RateBasedRule</code> contains a <code>RateLimit</code>, which specifies the maximum number of requests that AWS WAF allows from a specified IP address in a five-minute period. The <code>RateBasedRule</code> also contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to count or block if these requests exceed the <code>RateLimit</code>.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request not only must exceed the <code>RateLimit</code>, but it also must match all the specifications to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that meet the conditions in the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions must be received at a rate of more than 15,000 requests every five minutes. If both conditions are met and the rate is exceeded, AWS WAF blocks the requests. If the rate drops below 15,000 for a five-minute period, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p> <p>To create and configure a <code>RateBasedRule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the rule. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRateBasedRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRateBasedRule</code> request to specify the predicates that you want to include in the rule.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RateBasedRule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_rate_based_rule(
         &self,
         input: CreateRateBasedRuleRequest,
     ) -> RusotoFuture<CreateRateBasedRuleResponse, CreateRateBasedRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRateBasedRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRateBasedRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateRateBasedRuleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_rate_based_rule(input)
     }
-
-    /// <p>Creates a <a>RegexMatchSet</a>. You then use <a>UpdateRegexMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>RegexMatchSet</code> that contains a <code>RegexMatchTuple</code> that looks for any requests with <code>User-Agent</code> headers that match a <code>RegexPatternSet</code> with pattern <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value, using a <code>RegexPatternSet</code>, that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_regex_match_set(
         &self,
         input: CreateRegexMatchSetRequest,
     ) -> RusotoFuture<CreateRegexMatchSetResponse, CreateRegexMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRegexMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateRegexMatchSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_regex_match_set(input)
     }
-
-    /// <p>Creates a <code>RegexPatternSet</code>. You then use <a>UpdateRegexPatternSet</a> to specify the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexPatternSet</a> request to specify the string that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_regex_pattern_set(
         &self,
         input: CreateRegexPatternSetRequest,
     ) -> RusotoFuture<CreateRegexPatternSetResponse, CreateRegexPatternSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexPatternSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRegexPatternSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateRegexPatternSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_regex_pattern_set(input)
     }
-
-    /// <p>Creates a <code>Rule</code>, which contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to block. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed or blocked. For example, suppose that you add the following to a <code>Rule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to blocks requests that satisfy the <code>Rule</code>. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to specify the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_rule(
         &self,
         input: CreateRuleRequest,
     ) -> RusotoFuture<CreateRuleResponse, CreateRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_rule(input)
     }
-
-    /// <p>Creates a <code>RuleGroup</code>. A rule group is a collection of predefined rules that you add to a web ACL. You use <a>UpdateRuleGroup</a> to add rules to the rule group.</p> <p>Rule groups are subject to the following limits:</p> <ul> <li> <p>Three rule groups per account. You can request an increase to this limit by contacting customer support.</p> </li> <li> <p>One rule group per web ACL.</p> </li> <li> <p>Ten rules per rule group.</p> </li> </ul> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_rule_group(
         &self,
         input: CreateRuleGroupRequest,
     ) -> RusotoFuture<CreateRuleGroupResponse, CreateRuleGroupError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRuleGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateRuleGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateRuleGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_rule_group(input)
     }
-
-    /// <p>Creates a <code>SizeConstraintSet</code>. You then use <a>UpdateSizeConstraintSet</a> to identify the part of a web request that you want AWS WAF to check for length, such as the length of the <code>User-Agent</code> header or the length of the query string. For example, you can create a <code>SizeConstraintSet</code> that matches any requests that have a query string that is longer than 100 bytes. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateSizeConstraintSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_size_constraint_set(
         &self,
         input: CreateSizeConstraintSetRequest,
     ) -> RusotoFuture<CreateSizeConstraintSetResponse, CreateSizeConstraintSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateSizeConstraintSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSizeConstraintSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSizeConstraintSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_size_constraint_set(input)
     }
-
-    /// <p>Creates a <a>SqlInjectionMatchSet</a>, which you use to allow, block, or count requests that contain snippets of SQL code in a specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateSqlInjectionMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count malicious SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_sql_injection_match_set(
         &self,
         input: CreateSqlInjectionMatchSetRequest,
     ) -> RusotoFuture<CreateSqlInjectionMatchSetResponse, CreateSqlInjectionMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateSqlInjectionMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSqlInjectionMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateSqlInjectionMatchSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_sql_injection_match_set(input)
     }
-
-    /// <p>Creates a <code>WebACL</code>, which contains the <code>Rules</code> that identify the CloudFront web requests that you want to allow, block, or count. AWS WAF evaluates <code>Rules</code> in order based on the value of <code>Priority</code> for each <code>Rule</code>.</p> <p>You also specify a default action, either <code>ALLOW</code> or <code>BLOCK</code>. If a web request doesn't match any of the <code>Rules</code> in a <code>WebACL</code>, AWS WAF responds to the request with the default action. </p> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>ByteMatchSet</code> objects and other predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateWebACL</code> request.</p> </li> <li> <p>Submit a <code>CreateWebACL</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <a>UpdateWebACL</a> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution.</p> </li> </ol> <p>For more information about how to use the AWS WAF API, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_web_acl(
         &self,
         input: CreateWebACLRequest,
     ) -> RusotoFuture<CreateWebACLResponse, CreateWebACLError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACL");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWebACLResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateWebACLError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_web_acl(input)
     }
-
-    /// <p>Creates an <a>XssMatchSet</a>, which you use to allow, block, or count requests that contain cross-site scripting attacks in the specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateXssMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateXssMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn create_xss_match_set(
         &self,
         input: CreateXssMatchSetRequest,
     ) -> RusotoFuture<CreateXssMatchSetResponse, CreateXssMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.CreateXssMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateXssMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateXssMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_xss_match_set(input)
     }
-
-    /// <p><p>Permanently deletes a <a>ByteMatchSet</a>. You can&#39;t delete a <code>ByteMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>ByteMatchTuple</a> objects (any filters).</p> <p>If you just want to remove a <code>ByteMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>ByteMatchSet</code> to remove filters, if any. For more information, see <a>UpdateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteByteMatchSet</code> request.</p> </li> </ol></p>
     fn delete_byte_match_set(
         &self,
         input: DeleteByteMatchSetRequest,
     ) -> RusotoFuture<DeleteByteMatchSetResponse, DeleteByteMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteByteMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteByteMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteByteMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_byte_match_set(input)
     }
-
-    /// <p><p>Permanently deletes a <a>GeoMatchSet</a>. You can&#39;t delete a <code>GeoMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any countries.</p> <p>If you just want to remove a <code>GeoMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>GeoMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>GeoMatchSet</code> to remove any countries. For more information, see <a>UpdateGeoMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteGeoMatchSet</code> request.</p> </li> </ol></p>
     fn delete_geo_match_set(
         &self,
         input: DeleteGeoMatchSetRequest,
     ) -> RusotoFuture<DeleteGeoMatchSetResponse, DeleteGeoMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteGeoMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGeoMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGeoMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_geo_match_set(input)
     }
-
-    /// <p><p>Permanently deletes an <a>IPSet</a>. You can&#39;t delete an <code>IPSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any IP addresses.</p> <p>If you just want to remove an <code>IPSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>IPSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>IPSet</code> to remove IP address ranges, if any. For more information, see <a>UpdateIPSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteIPSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteIPSet</code> request.</p> </li> </ol></p>
     fn delete_ip_set(
         &self,
         input: DeleteIPSetRequest,
     ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteIPSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteIPSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteIPSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_ip_set(input)
     }
-
-    /// <p>Permanently deletes the <a>LoggingConfiguration</a> from the specified web ACL.</p>
     fn delete_logging_configuration(
         &self,
         input: DeleteLoggingConfigurationRequest,
     ) -> RusotoFuture<DeleteLoggingConfigurationResponse, DeleteLoggingConfigurationError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteLoggingConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLoggingConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteLoggingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_logging_configuration(input)
     }
-
-    /// <p>Permanently deletes an IAM policy from the specified RuleGroup.</p> <p>The user making the request must be the owner of the RuleGroup.</p>
     fn delete_permission_policy(
         &self,
         input: DeletePermissionPolicyRequest,
     ) -> RusotoFuture<DeletePermissionPolicyResponse, DeletePermissionPolicyError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeletePermissionPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeletePermissionPolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeletePermissionPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_permission_policy(input)
     }
-
-    /// <p><p>Permanently deletes a <a>RateBasedRule</a>. You can&#39;t delete a rule if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a rule from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RateBasedRule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RateBasedRule</code> to remove predicates, if any. For more information, see <a>UpdateRateBasedRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRateBasedRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRateBasedRule</code> request.</p> </li> </ol></p>
     fn delete_rate_based_rule(
         &self,
         input: DeleteRateBasedRuleRequest,
     ) -> RusotoFuture<DeleteRateBasedRuleResponse, DeleteRateBasedRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRateBasedRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRateBasedRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRateBasedRuleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_rate_based_rule(input)
     }
-
-    /// <p><p>Permanently deletes a <a>RegexMatchSet</a>. You can&#39;t delete a <code>RegexMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <code>RegexMatchTuples</code> objects (any filters).</p> <p>If you just want to remove a <code>RegexMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>RegexMatchSet</code> to remove filters, if any. For more information, see <a>UpdateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteRegexMatchSet</code> request.</p> </li> </ol></p>
     fn delete_regex_match_set(
         &self,
         input: DeleteRegexMatchSetRequest,
     ) -> RusotoFuture<DeleteRegexMatchSetResponse, DeleteRegexMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRegexMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRegexMatchSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_regex_match_set(input)
     }
-
-    /// <p>Permanently deletes a <a>RegexPatternSet</a>. You can't delete a <code>RegexPatternSet</code> if it's still used in any <code>RegexMatchSet</code> or if the <code>RegexPatternSet</code> is not empty. </p>
     fn delete_regex_pattern_set(
         &self,
         input: DeleteRegexPatternSetRequest,
     ) -> RusotoFuture<DeleteRegexPatternSetResponse, DeleteRegexPatternSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexPatternSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRegexPatternSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteRegexPatternSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_regex_pattern_set(input)
     }
-
-    /// <p><p>Permanently deletes a <a>Rule</a>. You can&#39;t delete a <code>Rule</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a <code>Rule</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>Rule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>Rule</code> to remove predicates, if any. For more information, see <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRule</code> request.</p> </li> </ol></p>
     fn delete_rule(
         &self,
         input: DeleteRuleRequest,
     ) -> RusotoFuture<DeleteRuleResponse, DeleteRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_rule(input)
     }
-
-    /// <p><p>Permanently deletes a <a>RuleGroup</a>. You can&#39;t delete a <code>RuleGroup</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any rules.</p> <p>If you just want to remove a <code>RuleGroup</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RuleGroup</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RuleGroup</code> to remove rules, if any. For more information, see <a>UpdateRuleGroup</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRuleGroup</code> request.</p> </li> <li> <p>Submit a <code>DeleteRuleGroup</code> request.</p> </li> </ol></p>
     fn delete_rule_group(
         &self,
         input: DeleteRuleGroupRequest,
     ) -> RusotoFuture<DeleteRuleGroupResponse, DeleteRuleGroupError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRuleGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteRuleGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteRuleGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_rule_group(input)
     }
-
-    /// <p><p>Permanently deletes a <a>SizeConstraintSet</a>. You can&#39;t delete a <code>SizeConstraintSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>SizeConstraint</a> objects (any filters).</p> <p>If you just want to remove a <code>SizeConstraintSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>SizeConstraintSet</code> to remove filters, if any. For more information, see <a>UpdateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSizeConstraintSet</code> request.</p> </li> </ol></p>
     fn delete_size_constraint_set(
         &self,
         input: DeleteSizeConstraintSetRequest,
     ) -> RusotoFuture<DeleteSizeConstraintSetResponse, DeleteSizeConstraintSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSizeConstraintSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSizeConstraintSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSizeConstraintSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_size_constraint_set(input)
     }
-
-    /// <p><p>Permanently deletes a <a>SqlInjectionMatchSet</a>. You can&#39;t delete a <code>SqlInjectionMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>SqlInjectionMatchTuple</a> objects.</p> <p>If you just want to remove a <code>SqlInjectionMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SqlInjectionMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>SqlInjectionMatchSet</code> to remove filters, if any. For more information, see <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> </ol></p>
     fn delete_sql_injection_match_set(
         &self,
         input: DeleteSqlInjectionMatchSetRequest,
     ) -> RusotoFuture<DeleteSqlInjectionMatchSetResponse, DeleteSqlInjectionMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSqlInjectionMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteSqlInjectionMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteSqlInjectionMatchSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_sql_injection_match_set(input)
     }
-
-    /// <p><p>Permanently deletes a <a>WebACL</a>. You can&#39;t delete a <code>WebACL</code> if it still contains any <code>Rules</code>.</p> <p>To delete a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>WebACL</code> to remove <code>Rules</code>, if any. For more information, see <a>UpdateWebACL</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteWebACL</code> request.</p> </li> <li> <p>Submit a <code>DeleteWebACL</code> request.</p> </li> </ol></p>
     fn delete_web_acl(
         &self,
         input: DeleteWebACLRequest,
     ) -> RusotoFuture<DeleteWebACLResponse, DeleteWebACLError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteWebACL");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWebACLResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteWebACLError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_web_acl(input)
     }
-
-    /// <p><p>Permanently deletes an <a>XssMatchSet</a>. You can&#39;t delete an <code>XssMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>XssMatchTuple</a> objects.</p> <p>If you just want to remove an <code>XssMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>XssMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>XssMatchSet</code> to remove filters, if any. For more information, see <a>UpdateXssMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteXssMatchSet</code> request.</p> </li> </ol></p>
     fn delete_xss_match_set(
         &self,
         input: DeleteXssMatchSetRequest,
     ) -> RusotoFuture<DeleteXssMatchSetResponse, DeleteXssMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteXssMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteXssMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteXssMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_xss_match_set(input)
     }
-
-    /// <p>Returns the <a>ByteMatchSet</a> specified by <code>ByteMatchSetId</code>.</p>
     fn get_byte_match_set(
         &self,
         input: GetByteMatchSetRequest,
     ) -> RusotoFuture<GetByteMatchSetResponse, GetByteMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetByteMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetByteMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetByteMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_byte_match_set(input)
     }
-
-    /// <p>When you want to create, update, or delete AWS WAF objects, get a change token and include the change token in the create, update, or delete request. Change tokens ensure that your application doesn't submit conflicting requests to AWS WAF.</p> <p>Each create, update, or delete request must use a unique change token. If your application submits a <code>GetChangeToken</code> request and then submits a second <code>GetChangeToken</code> request before submitting a create, update, or delete request, the second <code>GetChangeToken</code> request returns the same value as the first <code>GetChangeToken</code> request.</p> <p>When you use a change token in a create, update, or delete request, the status of the change token changes to <code>PENDING</code>, which indicates that AWS WAF is propagating the change to all AWS WAF servers. Use <code>GetChangeTokenStatus</code> to determine the status of your change token.</p>
     fn get_change_token(&self) -> RusotoFuture<GetChangeTokenResponse, GetChangeTokenError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeToken");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetChangeTokenResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetChangeTokenError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_change_token()
     }
-
-    /// <p><p>Returns the status of a <code>ChangeToken</code> that you got by calling <a>GetChangeToken</a>. <code>ChangeTokenStatus</code> is one of the following values:</p> <ul> <li> <p> <code>PROVISIONED</code>: You requested the change token by calling <code>GetChangeToken</code>, but you haven&#39;t used it yet in a call to create, update, or delete an AWS WAF object.</p> </li> <li> <p> <code>PENDING</code>: AWS WAF is propagating the create, update, or delete request to all AWS WAF servers.</p> </li> <li> <p> <code>INSYNC</code>: Propagation is complete.</p> </li> </ul></p>
     fn get_change_token_status(
         &self,
         input: GetChangeTokenStatusRequest,
     ) -> RusotoFuture<GetChangeTokenStatusResponse, GetChangeTokenStatusError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeTokenStatus");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetChangeTokenStatusResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetChangeTokenStatusError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_change_token_status(input)
     }
-
-    /// <p>Returns the <a>GeoMatchSet</a> that is specified by <code>GeoMatchSetId</code>.</p>
     fn get_geo_match_set(
         &self,
         input: GetGeoMatchSetRequest,
     ) -> RusotoFuture<GetGeoMatchSetResponse, GetGeoMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetGeoMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetGeoMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetGeoMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_geo_match_set(input)
     }
-
-    /// <p>Returns the <a>IPSet</a> that is specified by <code>IPSetId</code>.</p>
     fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetIPSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetIPSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetIPSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_ip_set(input)
     }
-
-    /// <p>Returns the <a>LoggingConfiguration</a> for the specified web ACL.</p>
     fn get_logging_configuration(
         &self,
         input: GetLoggingConfigurationRequest,
     ) -> RusotoFuture<GetLoggingConfigurationResponse, GetLoggingConfigurationError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetLoggingConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetLoggingConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetLoggingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_logging_configuration(input)
     }
-
-    /// <p>Returns the IAM policy attached to the RuleGroup.</p>
     fn get_permission_policy(
         &self,
         input: GetPermissionPolicyRequest,
     ) -> RusotoFuture<GetPermissionPolicyResponse, GetPermissionPolicyError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetPermissionPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetPermissionPolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetPermissionPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_permission_policy(input)
     }
-
-    /// <p>Returns the <a>RateBasedRule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRateBasedRule</code> request.</p>
     fn get_rate_based_rule(
         &self,
         input: GetRateBasedRuleRequest,
     ) -> RusotoFuture<GetRateBasedRuleResponse, GetRateBasedRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetRateBasedRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRateBasedRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRateBasedRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_rate_based_rule(input)
     }
-
-    /// <p>Returns an array of IP addresses currently being blocked by the <a>RateBasedRule</a> that is specified by the <code>RuleId</code>. The maximum number of managed keys that will be blocked is 10,000. If more than 10,000 addresses exceed the rate limit, the 10,000 addresses with the highest rates will be blocked.</p>
     fn get_rate_based_rule_managed_keys(
         &self,
         input: GetRateBasedRuleManagedKeysRequest,
     ) -> RusotoFuture<GetRateBasedRuleManagedKeysResponse, GetRateBasedRuleManagedKeysError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSWAF_20150824.GetRateBasedRuleManagedKeys",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRateBasedRuleManagedKeysResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetRateBasedRuleManagedKeysError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_rate_based_rule_managed_keys(input)
     }
-
-    /// <p>Returns the <a>RegexMatchSet</a> specified by <code>RegexMatchSetId</code>.</p>
     fn get_regex_match_set(
         &self,
         input: GetRegexMatchSetRequest,
     ) -> RusotoFuture<GetRegexMatchSetResponse, GetRegexMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRegexMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRegexMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_regex_match_set(input)
     }
-
-    /// <p>Returns the <a>RegexPatternSet</a> specified by <code>RegexPatternSetId</code>.</p>
     fn get_regex_pattern_set(
         &self,
         input: GetRegexPatternSetRequest,
     ) -> RusotoFuture<GetRegexPatternSetResponse, GetRegexPatternSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexPatternSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRegexPatternSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRegexPatternSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_regex_pattern_set(input)
     }
-
-    /// <p>Returns the <a>Rule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRule</code> request.</p>
     fn get_rule(&self, input: GetRuleRequest) -> RusotoFuture<GetRuleResponse, GetRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_rule(input)
     }
-
-    /// <p>Returns the <a>RuleGroup</a> that is specified by the <code>RuleGroupId</code> that you included in the <code>GetRuleGroup</code> request.</p> <p>To view the rules in a rule group, use <a>ListActivatedRulesInRuleGroup</a>.</p>
     fn get_rule_group(
         &self,
         input: GetRuleGroupRequest,
     ) -> RusotoFuture<GetRuleGroupResponse, GetRuleGroupError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetRuleGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetRuleGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetRuleGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_rule_group(input)
     }
-
-    /// <p>Gets detailed information about a specified number of requests--a sample--that AWS WAF randomly selects from among the first 5,000 requests that your AWS resource received during a time range that you choose. You can specify a sample size of up to 500 requests, and you can specify any time range in the previous three hours.</p> <p> <code>GetSampledRequests</code> returns a time range, which is usually the time range that you specified. However, if your resource (such as a CloudFront distribution) received 5,000 requests before the specified time range elapsed, <code>GetSampledRequests</code> returns an updated time range. This new time range indicates the actual period during which AWS WAF selected the requests in the sample.</p>
     fn get_sampled_requests(
         &self,
         input: GetSampledRequestsRequest,
     ) -> RusotoFuture<GetSampledRequestsResponse, GetSampledRequestsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetSampledRequests");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSampledRequestsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetSampledRequestsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_sampled_requests(input)
     }
-
-    /// <p>Returns the <a>SizeConstraintSet</a> specified by <code>SizeConstraintSetId</code>.</p>
     fn get_size_constraint_set(
         &self,
         input: GetSizeConstraintSetRequest,
     ) -> RusotoFuture<GetSizeConstraintSetResponse, GetSizeConstraintSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetSizeConstraintSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSizeConstraintSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(GetSizeConstraintSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).get_size_constraint_set(input)
     }
-
-    /// <p>Returns the <a>SqlInjectionMatchSet</a> that is specified by <code>SqlInjectionMatchSetId</code>.</p>
     fn get_sql_injection_match_set(
         &self,
         input: GetSqlInjectionMatchSetRequest,
     ) -> RusotoFuture<GetSqlInjectionMatchSetResponse, GetSqlInjectionMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetSqlInjectionMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetSqlInjectionMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(GetSqlInjectionMatchSetError::from_response(response))
-                }))
-            }
-        })
+        (**self).get_sql_injection_match_set(input)
     }
-
-    /// <p>Returns the <a>WebACL</a> that is specified by <code>WebACLId</code>.</p>
     fn get_web_acl(
         &self,
         input: GetWebACLRequest,
     ) -> RusotoFuture<GetWebACLResponse, GetWebACLError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetWebACL");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetWebACLResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetWebACLError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_web_acl(input)
     }
-
-    /// <p>Returns the <a>XssMatchSet</a> that is specified by <code>XssMatchSetId</code>.</p>
     fn get_xss_match_set(
         &self,
         input: GetXssMatchSetRequest,
     ) -> RusotoFuture<GetXssMatchSetResponse, GetXssMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.GetXssMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetXssMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetXssMatchSetError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_xss_match_set(input)
     }
-
-    /// <p>Returns an array of <a>ActivatedRule</a> objects.</p>
     fn list_activated_rules_in_rule_group(
         &self,
         input: ListActivatedRulesInRuleGroupRequest,
     ) -> RusotoFuture<ListActivatedRulesInRuleGroupResponse, ListActivatedRulesInRuleGroupError>
     {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSWAF_20150824.ListActivatedRulesInRuleGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListActivatedRulesInRuleGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListActivatedRulesInRuleGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_activated_rules_in_rule_group(input)
     }
-
-    /// <p>Returns an array of <a>ByteMatchSetSummary</a> objects.</p>
     fn list_byte_match_sets(
         &self,
         input: ListByteMatchSetsRequest,
     ) -> RusotoFuture<ListByteMatchSetsResponse, ListByteMatchSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListByteMatchSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListByteMatchSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListByteMatchSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_byte_match_sets(input)
     }
-
-    /// <p>Returns an array of <a>GeoMatchSetSummary</a> objects in the response.</p>
     fn list_geo_match_sets(
         &self,
         input: ListGeoMatchSetsRequest,
     ) -> RusotoFuture<ListGeoMatchSetsResponse, ListGeoMatchSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListGeoMatchSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGeoMatchSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGeoMatchSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_geo_match_sets(input)
     }
-
-    /// <p>Returns an array of <a>IPSetSummary</a> objects in the response.</p>
     fn list_ip_sets(
         &self,
         input: ListIPSetsRequest,
     ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListIPSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListIPSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListIPSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_ip_sets(input)
     }
-
-    /// <p>Returns an array of <a>LoggingConfiguration</a> objects.</p>
     fn list_logging_configurations(
         &self,
         input: ListLoggingConfigurationsRequest,
     ) -> RusotoFuture<ListLoggingConfigurationsResponse, ListLoggingConfigurationsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListLoggingConfigurations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListLoggingConfigurationsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListLoggingConfigurationsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_logging_configurations(input)
     }
-
-    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
     fn list_rate_based_rules(
         &self,
         input: ListRateBasedRulesRequest,
     ) -> RusotoFuture<ListRateBasedRulesResponse, ListRateBasedRulesError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListRateBasedRules");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRateBasedRulesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRateBasedRulesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_rate_based_rules(input)
     }
-
-    /// <p>Returns an array of <a>RegexMatchSetSummary</a> objects.</p>
     fn list_regex_match_sets(
         &self,
         input: ListRegexMatchSetsRequest,
     ) -> RusotoFuture<ListRegexMatchSetsResponse, ListRegexMatchSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexMatchSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRegexMatchSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRegexMatchSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_regex_match_sets(input)
     }
-
-    /// <p>Returns an array of <a>RegexPatternSetSummary</a> objects.</p>
     fn list_regex_pattern_sets(
         &self,
         input: ListRegexPatternSetsRequest,
     ) -> RusotoFuture<ListRegexPatternSetsResponse, ListRegexPatternSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexPatternSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRegexPatternSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListRegexPatternSetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_regex_pattern_sets(input)
     }
-
-    /// <p>Returns an array of <a>RuleGroup</a> objects.</p>
     fn list_rule_groups(
         &self,
         input: ListRuleGroupsRequest,
     ) -> RusotoFuture<ListRuleGroupsResponse, ListRuleGroupsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListRuleGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRuleGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRuleGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_rule_groups(input)
     }
-
-    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
     fn list_rules(
         &self,
         input: ListRulesRequest,
     ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListRules");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListRulesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListRulesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_rules(input)
     }
-
-    /// <p>Returns an array of <a>SizeConstraintSetSummary</a> objects.</p>
     fn list_size_constraint_sets(
         &self,
         input: ListSizeConstraintSetsRequest,
     ) -> RusotoFuture<ListSizeConstraintSetsResponse, ListSizeConstraintSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListSizeConstraintSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSizeConstraintSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListSizeConstraintSetsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_size_constraint_sets(input)
     }
-
-    /// <p>Returns an array of <a>SqlInjectionMatchSet</a> objects.</p>
     fn list_sql_injection_match_sets(
         &self,
         input: ListSqlInjectionMatchSetsRequest,
     ) -> RusotoFuture<ListSqlInjectionMatchSetsResponse, ListSqlInjectionMatchSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListSqlInjectionMatchSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSqlInjectionMatchSetsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSqlInjectionMatchSetsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_sql_injection_match_sets(input)
     }
-
-    /// <p>Returns an array of <a>RuleGroup</a> objects that you are subscribed to.</p>
     fn list_subscribed_rule_groups(
         &self,
         input: ListSubscribedRuleGroupsRequest,
     ) -> RusotoFuture<ListSubscribedRuleGroupsResponse, ListSubscribedRuleGroupsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListSubscribedRuleGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListSubscribedRuleGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListSubscribedRuleGroupsError::from_response(response))
-                }))
-            }
-        })
+        (**self).list_subscribed_rule_groups(input)
     }
-
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
     ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListTagsForResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsForResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListTagsForResourceError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_tags_for_resource(input)
     }
-
-    /// <p>Returns an array of <a>WebACLSummary</a> objects in the response.</p>
     fn list_web_ac_ls(
         &self,
         input: ListWebACLsRequest,
     ) -> RusotoFuture<ListWebACLsResponse, ListWebACLsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListWebACLs");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWebACLsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListWebACLsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_web_ac_ls(input)
     }
-
-    /// <p>Returns an array of <a>XssMatchSet</a> objects.</p>
     fn list_xss_match_sets(
         &self,
         input: ListXssMatchSetsRequest,
     ) -> RusotoFuture<ListXssMatchSetsResponse, ListXssMatchSetsError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.ListXssMatchSets");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListXssMatchSetsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListXssMatchSetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_xss_match_sets(input)
     }
-
-    /// <p>Associates a <a>LoggingConfiguration</a> with a specified web ACL.</p> <p>You can access information about all traffic that AWS WAF inspects using the following steps:</p> <ol> <li> <p>Create an Amazon Kinesis Data Firehose . </p> <p>Create the data firehose with a PUT source and in the region that you are operating. However, if you are capturing logs for Amazon CloudFront, always create the firehose in US East (N. Virginia). </p> <note> <p>Do not create the data firehose using a <code>Kinesis stream</code> as your source.</p> </note> </li> <li> <p>Associate that firehose to your web ACL using a <code>PutLoggingConfiguration</code> request.</p> </li> </ol> <p>When you successfully enable logging using a <code>PutLoggingConfiguration</code> request, AWS WAF will create a service linked role with the necessary permissions to write logs to the Amazon Kinesis Data Firehose. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/logging.html">Logging Web ACL Traffic Information</a> in the <i>AWS WAF Developer Guide</i>.</p>
     fn put_logging_configuration(
         &self,
         input: PutLoggingConfigurationRequest,
     ) -> RusotoFuture<PutLoggingConfigurationResponse, PutLoggingConfigurationError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.PutLoggingConfiguration");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutLoggingConfigurationResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(PutLoggingConfigurationError::from_response(response))
-                }))
-            }
-        })
+        (**self).put_logging_configuration(input)
     }
-
-    /// <p>Attaches a IAM policy to the specified resource. The only supported use for this action is to share a RuleGroup across accounts.</p> <p>The <code>PutPermissionPolicy</code> is subject to the following restrictions:</p> <ul> <li> <p>You can attach only one policy with each <code>PutPermissionPolicy</code> request.</p> </li> <li> <p>The policy must include an <code>Effect</code>, <code>Action</code> and <code>Principal</code>. </p> </li> <li> <p> <code>Effect</code> must specify <code>Allow</code>.</p> </li> <li> <p>The <code>Action</code> in the policy must be <code>waf:UpdateWebACL</code>, <code>waf-regional:UpdateWebACL</code>, <code>waf:GetRuleGroup</code> and <code>waf-regional:GetRuleGroup</code> . Any extra or wildcard actions in the policy will be rejected.</p> </li> <li> <p>The policy cannot include a <code>Resource</code> parameter.</p> </li> <li> <p>The ARN in the request must be a valid WAF RuleGroup ARN and the RuleGroup must exist in the same region.</p> </li> <li> <p>The user making the request must be the owner of the RuleGroup.</p> </li> <li> <p>Your policy must be composed using IAM Policy version 2012-10-17.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies.html">IAM Policies</a>. </p> <p>An example of a valid policy parameter is shown in the Examples section below.</p>
     fn put_permission_policy(
         &self,
         input: PutPermissionPolicyRequest,
     ) -> RusotoFuture<PutPermissionPolicyResponse, PutPermissionPolicyError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.PutPermissionPolicy");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutPermissionPolicyResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutPermissionPolicyError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_permission_policy(input)
     }
-
     fn tag_resource(
         &self,
         input: TagResourceRequest,
     ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.TagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).tag_resource(input)
     }
-
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UntagResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UntagResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).untag_resource(input)
     }
-
-    /// <p>Inserts or deletes <a>ByteMatchTuple</a> objects (filters) in a <a>ByteMatchSet</a>. For each <code>ByteMatchTuple</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>ByteMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to look for. For more information, including how you specify the values for the AWS WAF API and the AWS CLI or SDKs, see <code>TargetString</code> in the <a>ByteMatchTuple</a> data type. </p> </li> <li> <p>Where to look, such as at the beginning or the end of a query string.</p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p>For example, you can add a <code>ByteMatchSetUpdate</code> object that matches web requests in which <code>User-Agent</code> headers contain the string <code>BadBot</code>. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>ByteMatchSet.</code> For more information, see <a>CreateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateByteMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_byte_match_set(
         &self,
         input: UpdateByteMatchSetRequest,
     ) -> RusotoFuture<UpdateByteMatchSetResponse, UpdateByteMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateByteMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateByteMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateByteMatchSetError::from_response(response))),
-                )
+        (**self).update_byte_match_set(input)
+    }
+    fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> RusotoFuture<UpdateGeoMatchSetResponse, UpdateGeoMatchSetError> {
+        (**self).update_geo_match_set(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> RusotoFuture<UpdateRateBasedRuleResponse, UpdateRateBasedRuleError> {
+        (**self).update_rate_based_rule(input)
+    }
+    fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> RusotoFuture<UpdateRegexMatchSetResponse, UpdateRegexMatchSetError> {
+        (**self).update_regex_match_set(input)
+    }
+    fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> RusotoFuture<UpdateRegexPatternSetResponse, UpdateRegexPatternSetError> {
+        (**self).update_regex_pattern_set(input)
+    }
+    fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> RusotoFuture<UpdateRuleResponse, UpdateRuleError> {
+        (**self).update_rule(input)
+    }
+    fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> RusotoFuture<UpdateRuleGroupResponse, UpdateRuleGroupError> {
+        (**self).update_rule_group(input)
+    }
+    fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> RusotoFuture<UpdateSizeConstraintSetResponse, UpdateSizeConstraintSetError> {
+        (**self).update_size_constraint_set(input)
+    }
+    fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<UpdateSqlInjectionMatchSetResponse, UpdateSqlInjectionMatchSetError> {
+        (**self).update_sql_injection_match_set(input)
+    }
+    fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> RusotoFuture<UpdateWebACLResponse, UpdateWebACLError> {
+        (**self).update_web_acl(input)
+    }
+    fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError> {
+        (**self).update_xss_match_set(input)
+    }
+}
+impl<T: Waf + ?Sized> Waf for Box<T> {
+    fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> RusotoFuture<CreateByteMatchSetResponse, CreateByteMatchSetError> {
+        (**self).create_byte_match_set(input)
+    }
+    fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> RusotoFuture<CreateGeoMatchSetResponse, CreateGeoMatchSetError> {
+        (**self).create_geo_match_set(input)
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        (**self).create_ip_set(input)
+    }
+    fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> RusotoFuture<CreateRateBasedRuleResponse, CreateRateBasedRuleError> {
+        (**self).create_rate_based_rule(input)
+    }
+    fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> RusotoFuture<CreateRegexMatchSetResponse, CreateRegexMatchSetError> {
+        (**self).create_regex_match_set(input)
+    }
+    fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> RusotoFuture<CreateRegexPatternSetResponse, CreateRegexPatternSetError> {
+        (**self).create_regex_pattern_set(input)
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> RusotoFuture<CreateRuleResponse, CreateRuleError> {
+        (**self).create_rule(input)
+    }
+    fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> RusotoFuture<CreateRuleGroupResponse, CreateRuleGroupError> {
+        (**self).create_rule_group(input)
+    }
+    fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> RusotoFuture<CreateSizeConstraintSetResponse, CreateSizeConstraintSetError> {
+        (**self).create_size_constraint_set(input)
+    }
+    fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<CreateSqlInjectionMatchSetResponse, CreateSqlInjectionMatchSetError> {
+        (**self).create_sql_injection_match_set(input)
+    }
+    fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> RusotoFuture<CreateWebACLResponse, CreateWebACLError> {
+        (**self).create_web_acl(input)
+    }
+    fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> RusotoFuture<CreateXssMatchSetResponse, CreateXssMatchSetError> {
+        (**self).create_xss_match_set(input)
+    }
+    fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> RusotoFuture<DeleteByteMatchSetResponse, DeleteByteMatchSetError> {
+        (**self).delete_byte_match_set(input)
+    }
+    fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> RusotoFuture<DeleteGeoMatchSetResponse, DeleteGeoMatchSetError> {
+        (**self).delete_geo_match_set(input)
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        (**self).delete_ip_set(input)
+    }
+    fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> RusotoFuture<DeleteLoggingConfigurationResponse, DeleteLoggingConfigurationError> {
+        (**self).delete_logging_configuration(input)
+    }
+    fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> RusotoFuture<DeletePermissionPolicyResponse, DeletePermissionPolicyError> {
+        (**self).delete_permission_policy(input)
+    }
+    fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> RusotoFuture<DeleteRateBasedRuleResponse, DeleteRateBasedRuleError> {
+        (**self).delete_rate_based_rule(input)
+    }
+    fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> RusotoFuture<DeleteRegexMatchSetResponse, DeleteRegexMatchSetError> {
+        (**self).delete_regex_match_set(input)
+    }
+    fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> RusotoFuture<DeleteRegexPatternSetResponse, DeleteRegexPatternSetError> {
+        (**self).delete_regex_pattern_set(input)
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> RusotoFuture<DeleteRuleResponse, DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> RusotoFuture<DeleteRuleGroupResponse, DeleteRuleGroupError> {
+        (**self).delete_rule_group(input)
+    }
+    fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> RusotoFuture<DeleteSizeConstraintSetResponse, DeleteSizeConstraintSetError> {
+        (**self).delete_size_constraint_set(input)
+    }
+    fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<DeleteSqlInjectionMatchSetResponse, DeleteSqlInjectionMatchSetError> {
+        (**self).delete_sql_injection_match_set(input)
+    }
+    fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> RusotoFuture<DeleteWebACLResponse, DeleteWebACLError> {
+        (**self).delete_web_acl(input)
+    }
+    fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> RusotoFuture<DeleteXssMatchSetResponse, DeleteXssMatchSetError> {
+        (**self).delete_xss_match_set(input)
+    }
+    fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> RusotoFuture<GetByteMatchSetResponse, GetByteMatchSetError> {
+        (**self).get_byte_match_set(input)
+    }
+    fn get_change_token(&self) -> RusotoFuture<GetChangeTokenResponse, GetChangeTokenError> {
+        (**self).get_change_token()
+    }
+    fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> RusotoFuture<GetChangeTokenStatusResponse, GetChangeTokenStatusError> {
+        (**self).get_change_token_status(input)
+    }
+    fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> RusotoFuture<GetGeoMatchSetResponse, GetGeoMatchSetError> {
+        (**self).get_geo_match_set(input)
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        (**self).get_ip_set(input)
+    }
+    fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> RusotoFuture<GetLoggingConfigurationResponse, GetLoggingConfigurationError> {
+        (**self).get_logging_configuration(input)
+    }
+    fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> RusotoFuture<GetPermissionPolicyResponse, GetPermissionPolicyError> {
+        (**self).get_permission_policy(input)
+    }
+    fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> RusotoFuture<GetRateBasedRuleResponse, GetRateBasedRuleError> {
+        (**self).get_rate_based_rule(input)
+    }
+    fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> RusotoFuture<GetRateBasedRuleManagedKeysResponse, GetRateBasedRuleManagedKeysError> {
+        (**self).get_rate_based_rule_managed_keys(input)
+    }
+    fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> RusotoFuture<GetRegexMatchSetResponse, GetRegexMatchSetError> {
+        (**self).get_regex_match_set(input)
+    }
+    fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> RusotoFuture<GetRegexPatternSetResponse, GetRegexPatternSetError> {
+        (**self).get_regex_pattern_set(input)
+    }
+    fn get_rule(&self, input: GetRuleRequest) -> RusotoFuture<GetRuleResponse, GetRuleError> {
+        (**self).get_rule(input)
+    }
+    fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> RusotoFuture<GetRuleGroupResponse, GetRuleGroupError> {
+        (**self).get_rule_group(input)
+    }
+    fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> RusotoFuture<GetSampledRequestsResponse, GetSampledRequestsError> {
+        (**self).get_sampled_requests(input)
+    }
+    fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> RusotoFuture<GetSizeConstraintSetResponse, GetSizeConstraintSetError> {
+        (**self).get_size_constraint_set(input)
+    }
+    fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<GetSqlInjectionMatchSetResponse, GetSqlInjectionMatchSetError> {
+        (**self).get_sql_injection_match_set(input)
+    }
+    fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> RusotoFuture<GetWebACLResponse, GetWebACLError> {
+        (**self).get_web_acl(input)
+    }
+    fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> RusotoFuture<GetXssMatchSetResponse, GetXssMatchSetError> {
+        (**self).get_xss_match_set(input)
+    }
+    fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> RusotoFuture<ListActivatedRulesInRuleGroupResponse, ListActivatedRulesInRuleGroupError>
+    {
+        (**self).list_activated_rules_in_rule_group(input)
+    }
+    fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> RusotoFuture<ListByteMatchSetsResponse, ListByteMatchSetsError> {
+        (**self).list_byte_match_sets(input)
+    }
+    fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> RusotoFuture<ListGeoMatchSetsResponse, ListGeoMatchSetsError> {
+        (**self).list_geo_match_sets(input)
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        (**self).list_ip_sets(input)
+    }
+    fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> RusotoFuture<ListLoggingConfigurationsResponse, ListLoggingConfigurationsError> {
+        (**self).list_logging_configurations(input)
+    }
+    fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> RusotoFuture<ListRateBasedRulesResponse, ListRateBasedRulesError> {
+        (**self).list_rate_based_rules(input)
+    }
+    fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> RusotoFuture<ListRegexMatchSetsResponse, ListRegexMatchSetsError> {
+        (**self).list_regex_match_sets(input)
+    }
+    fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> RusotoFuture<ListRegexPatternSetsResponse, ListRegexPatternSetsError> {
+        (**self).list_regex_pattern_sets(input)
+    }
+    fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> RusotoFuture<ListRuleGroupsResponse, ListRuleGroupsError> {
+        (**self).list_rule_groups(input)
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        (**self).list_rules(input)
+    }
+    fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> RusotoFuture<ListSizeConstraintSetsResponse, ListSizeConstraintSetsError> {
+        (**self).list_size_constraint_sets(input)
+    }
+    fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> RusotoFuture<ListSqlInjectionMatchSetsResponse, ListSqlInjectionMatchSetsError> {
+        (**self).list_sql_injection_match_sets(input)
+    }
+    fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> RusotoFuture<ListSubscribedRuleGroupsResponse, ListSubscribedRuleGroupsError> {
+        (**self).list_subscribed_rule_groups(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> RusotoFuture<ListWebACLsResponse, ListWebACLsError> {
+        (**self).list_web_ac_ls(input)
+    }
+    fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> RusotoFuture<ListXssMatchSetsResponse, ListXssMatchSetsError> {
+        (**self).list_xss_match_sets(input)
+    }
+    fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> RusotoFuture<PutLoggingConfigurationResponse, PutLoggingConfigurationError> {
+        (**self).put_logging_configuration(input)
+    }
+    fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> RusotoFuture<PutPermissionPolicyResponse, PutPermissionPolicyError> {
+        (**self).put_permission_policy(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> RusotoFuture<UpdateByteMatchSetResponse, UpdateByteMatchSetError> {
+        (**self).update_byte_match_set(input)
+    }
+    fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> RusotoFuture<UpdateGeoMatchSetResponse, UpdateGeoMatchSetError> {
+        (**self).update_geo_match_set(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> RusotoFuture<UpdateRateBasedRuleResponse, UpdateRateBasedRuleError> {
+        (**self).update_rate_based_rule(input)
+    }
+    fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> RusotoFuture<UpdateRegexMatchSetResponse, UpdateRegexMatchSetError> {
+        (**self).update_regex_match_set(input)
+    }
+    fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> RusotoFuture<UpdateRegexPatternSetResponse, UpdateRegexPatternSetError> {
+        (**self).update_regex_pattern_set(input)
+    }
+    fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> RusotoFuture<UpdateRuleResponse, UpdateRuleError> {
+        (**self).update_rule(input)
+    }
+    fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> RusotoFuture<UpdateRuleGroupResponse, UpdateRuleGroupError> {
+        (**self).update_rule_group(input)
+    }
+    fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> RusotoFuture<UpdateSizeConstraintSetResponse, UpdateSizeConstraintSetError> {
+        (**self).update_size_constraint_set(input)
+    }
+    fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<UpdateSqlInjectionMatchSetResponse, UpdateSqlInjectionMatchSetError> {
+        (**self).update_sql_injection_match_set(input)
+    }
+    fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> RusotoFuture<UpdateWebACLResponse, UpdateWebACLError> {
+        (**self).update_web_acl(input)
+    }
+    fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError> {
+        (**self).update_xss_match_set(input)
+    }
+}
+impl<T: Waf + ?Sized> Waf for ::std::sync::Arc<T> {
+    fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> RusotoFuture<CreateByteMatchSetResponse, CreateByteMatchSetError> {
+        (**self).create_byte_match_set(input)
+    }
+    fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> RusotoFuture<CreateGeoMatchSetResponse, CreateGeoMatchSetError> {
+        (**self).create_geo_match_set(input)
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        (**self).create_ip_set(input)
+    }
+    fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> RusotoFuture<CreateRateBasedRuleResponse, CreateRateBasedRuleError> {
+        (**self).create_rate_based_rule(input)
+    }
+    fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> RusotoFuture<CreateRegexMatchSetResponse, CreateRegexMatchSetError> {
+        (**self).create_regex_match_set(input)
+    }
+    fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> RusotoFuture<CreateRegexPatternSetResponse, CreateRegexPatternSetError> {
+        (**self).create_regex_pattern_set(input)
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> RusotoFuture<CreateRuleResponse, CreateRuleError> {
+        (**self).create_rule(input)
+    }
+    fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> RusotoFuture<CreateRuleGroupResponse, CreateRuleGroupError> {
+        (**self).create_rule_group(input)
+    }
+    fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> RusotoFuture<CreateSizeConstraintSetResponse, CreateSizeConstraintSetError> {
+        (**self).create_size_constraint_set(input)
+    }
+    fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<CreateSqlInjectionMatchSetResponse, CreateSqlInjectionMatchSetError> {
+        (**self).create_sql_injection_match_set(input)
+    }
+    fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> RusotoFuture<CreateWebACLResponse, CreateWebACLError> {
+        (**self).create_web_acl(input)
+    }
+    fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> RusotoFuture<CreateXssMatchSetResponse, CreateXssMatchSetError> {
+        (**self).create_xss_match_set(input)
+    }
+    fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> RusotoFuture<DeleteByteMatchSetResponse, DeleteByteMatchSetError> {
+        (**self).delete_byte_match_set(input)
+    }
+    fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> RusotoFuture<DeleteGeoMatchSetResponse, DeleteGeoMatchSetError> {
+        (**self).delete_geo_match_set(input)
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        (**self).delete_ip_set(input)
+    }
+    fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> RusotoFuture<DeleteLoggingConfigurationResponse, DeleteLoggingConfigurationError> {
+        (**self).delete_logging_configuration(input)
+    }
+    fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> RusotoFuture<DeletePermissionPolicyResponse, DeletePermissionPolicyError> {
+        (**self).delete_permission_policy(input)
+    }
+    fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> RusotoFuture<DeleteRateBasedRuleResponse, DeleteRateBasedRuleError> {
+        (**self).delete_rate_based_rule(input)
+    }
+    fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> RusotoFuture<DeleteRegexMatchSetResponse, DeleteRegexMatchSetError> {
+        (**self).delete_regex_match_set(input)
+    }
+    fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> RusotoFuture<DeleteRegexPatternSetResponse, DeleteRegexPatternSetError> {
+        (**self).delete_regex_pattern_set(input)
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> RusotoFuture<DeleteRuleResponse, DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> RusotoFuture<DeleteRuleGroupResponse, DeleteRuleGroupError> {
+        (**self).delete_rule_group(input)
+    }
+    fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> RusotoFuture<DeleteSizeConstraintSetResponse, DeleteSizeConstraintSetError> {
+        (**self).delete_size_constraint_set(input)
+    }
+    fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<DeleteSqlInjectionMatchSetResponse, DeleteSqlInjectionMatchSetError> {
+        (**self).delete_sql_injection_match_set(input)
+    }
+    fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> RusotoFuture<DeleteWebACLResponse, DeleteWebACLError> {
+        (**self).delete_web_acl(input)
+    }
+    fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> RusotoFuture<DeleteXssMatchSetResponse, DeleteXssMatchSetError> {
+        (**self).delete_xss_match_set(input)
+    }
+    fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> RusotoFuture<GetByteMatchSetResponse, GetByteMatchSetError> {
+        (**self).get_byte_match_set(input)
+    }
+    fn get_change_token(&self) -> RusotoFuture<GetChangeTokenResponse, GetChangeTokenError> {
+        (**self).get_change_token()
+    }
+    fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> RusotoFuture<GetChangeTokenStatusResponse, GetChangeTokenStatusError> {
+        (**self).get_change_token_status(input)
+    }
+    fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> RusotoFuture<GetGeoMatchSetResponse, GetGeoMatchSetError> {
+        (**self).get_geo_match_set(input)
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        (**self).get_ip_set(input)
+    }
+    fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> RusotoFuture<GetLoggingConfigurationResponse, GetLoggingConfigurationError> {
+        (**self).get_logging_configuration(input)
+    }
+    fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> RusotoFuture<GetPermissionPolicyResponse, GetPermissionPolicyError> {
+        (**self).get_permission_policy(input)
+    }
+    fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> RusotoFuture<GetRateBasedRuleResponse, GetRateBasedRuleError> {
+        (**self).get_rate_based_rule(input)
+    }
+    fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> RusotoFuture<GetRateBasedRuleManagedKeysResponse, GetRateBasedRuleManagedKeysError> {
+        (**self).get_rate_based_rule_managed_keys(input)
+    }
+    fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> RusotoFuture<GetRegexMatchSetResponse, GetRegexMatchSetError> {
+        (**self).get_regex_match_set(input)
+    }
+    fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> RusotoFuture<GetRegexPatternSetResponse, GetRegexPatternSetError> {
+        (**self).get_regex_pattern_set(input)
+    }
+    fn get_rule(&self, input: GetRuleRequest) -> RusotoFuture<GetRuleResponse, GetRuleError> {
+        (**self).get_rule(input)
+    }
+    fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> RusotoFuture<GetRuleGroupResponse, GetRuleGroupError> {
+        (**self).get_rule_group(input)
+    }
+    fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> RusotoFuture<GetSampledRequestsResponse, GetSampledRequestsError> {
+        (**self).get_sampled_requests(input)
+    }
+    fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> RusotoFuture<GetSizeConstraintSetResponse, GetSizeConstraintSetError> {
+        (**self).get_size_constraint_set(input)
+    }
+    fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<GetSqlInjectionMatchSetResponse, GetSqlInjectionMatchSetError> {
+        (**self).get_sql_injection_match_set(input)
+    }
+    fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> RusotoFuture<GetWebACLResponse, GetWebACLError> {
+        (**self).get_web_acl(input)
+    }
+    fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> RusotoFuture<GetXssMatchSetResponse, GetXssMatchSetError> {
+        (**self).get_xss_match_set(input)
+    }
+    fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> RusotoFuture<ListActivatedRulesInRuleGroupResponse, ListActivatedRulesInRuleGroupError>
+    {
+        (**self).list_activated_rules_in_rule_group(input)
+    }
+    fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> RusotoFuture<ListByteMatchSetsResponse, ListByteMatchSetsError> {
+        (**self).list_byte_match_sets(input)
+    }
+    fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> RusotoFuture<ListGeoMatchSetsResponse, ListGeoMatchSetsError> {
+        (**self).list_geo_match_sets(input)
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        (**self).list_ip_sets(input)
+    }
+    fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> RusotoFuture<ListLoggingConfigurationsResponse, ListLoggingConfigurationsError> {
+        (**self).list_logging_configurations(input)
+    }
+    fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> RusotoFuture<ListRateBasedRulesResponse, ListRateBasedRulesError> {
+        (**self).list_rate_based_rules(input)
+    }
+    fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> RusotoFuture<ListRegexMatchSetsResponse, ListRegexMatchSetsError> {
+        (**self).list_regex_match_sets(input)
+    }
+    fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> RusotoFuture<ListRegexPatternSetsResponse, ListRegexPatternSetsError> {
+        (**self).list_regex_pattern_sets(input)
+    }
+    fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> RusotoFuture<ListRuleGroupsResponse, ListRuleGroupsError> {
+        (**self).list_rule_groups(input)
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        (**self).list_rules(input)
+    }
+    fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> RusotoFuture<ListSizeConstraintSetsResponse, ListSizeConstraintSetsError> {
+        (**self).list_size_constraint_sets(input)
+    }
+    fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> RusotoFuture<ListSqlInjectionMatchSetsResponse, ListSqlInjectionMatchSetsError> {
+        (**self).list_sql_injection_match_sets(input)
+    }
+    fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> RusotoFuture<ListSubscribedRuleGroupsResponse, ListSubscribedRuleGroupsError> {
+        (**self).list_subscribed_rule_groups(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> RusotoFuture<ListWebACLsResponse, ListWebACLsError> {
+        (**self).list_web_ac_ls(input)
+    }
+    fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> RusotoFuture<ListXssMatchSetsResponse, ListXssMatchSetsError> {
+        (**self).list_xss_match_sets(input)
+    }
+    fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> RusotoFuture<PutLoggingConfigurationResponse, PutLoggingConfigurationError> {
+        (**self).put_logging_configuration(input)
+    }
+    fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> RusotoFuture<PutPermissionPolicyResponse, PutPermissionPolicyError> {
+        (**self).put_permission_policy(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> RusotoFuture<UpdateByteMatchSetResponse, UpdateByteMatchSetError> {
+        (**self).update_byte_match_set(input)
+    }
+    fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> RusotoFuture<UpdateGeoMatchSetResponse, UpdateGeoMatchSetError> {
+        (**self).update_geo_match_set(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> RusotoFuture<UpdateRateBasedRuleResponse, UpdateRateBasedRuleError> {
+        (**self).update_rate_based_rule(input)
+    }
+    fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> RusotoFuture<UpdateRegexMatchSetResponse, UpdateRegexMatchSetError> {
+        (**self).update_regex_match_set(input)
+    }
+    fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> RusotoFuture<UpdateRegexPatternSetResponse, UpdateRegexPatternSetError> {
+        (**self).update_regex_pattern_set(input)
+    }
+    fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> RusotoFuture<UpdateRuleResponse, UpdateRuleError> {
+        (**self).update_rule(input)
+    }
+    fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> RusotoFuture<UpdateRuleGroupResponse, UpdateRuleGroupError> {
+        (**self).update_rule_group(input)
+    }
+    fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> RusotoFuture<UpdateSizeConstraintSetResponse, UpdateSizeConstraintSetError> {
+        (**self).update_size_constraint_set(input)
+    }
+    fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<UpdateSqlInjectionMatchSetResponse, UpdateSqlInjectionMatchSetError> {
+        (**self).update_sql_injection_match_set(input)
+    }
+    fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> RusotoFuture<UpdateWebACLResponse, UpdateWebACLError> {
+        (**self).update_web_acl(input)
+    }
+    fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError> {
+        (**self).update_xss_match_set(input)
+    }
+}
+/// A client for the WAF API.
+#[derive(Clone)]
+pub struct WafClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl WafClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WafClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WafClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> WafClient {
+        WafClient { client, region }
+    }
+}
+
+impl Waf for WafClient {
+    /// <p>Creates a <code>ByteMatchSet</code>. You then use <a>UpdateByteMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>ByteMatchSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>BadBot</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateByteMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> RusotoFuture<CreateByteMatchSetResponse, CreateByteMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateByteMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateByteMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateByteMatchSetResponse, _>(
+                    response,
+                    "CreateByteMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateByteMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an <a>GeoMatchSet</a>, which you use to specify which web requests you want to allow or block based on the country that the requests originate from. For example, if you're receiving a lot of requests from one or more countries and you want to block the requests, you can create an <code>GeoMatchSet</code> that contains those countries and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSetSet</code> request to specify the countries that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> RusotoFuture<CreateGeoMatchSetResponse, CreateGeoMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateGeoMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateGeoMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGeoMatchSetResponse, _>(
+                    response,
+                    "CreateGeoMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGeoMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an <a>IPSet</a>, which you use to specify which web requests that you want to allow or block based on the IP addresses that the requests originate from. For example, if you're receiving a lot of requests from one or more individual IP addresses or one or more ranges of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that contains those IP addresses and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateIPSet</code> request.</p> </li> <li> <p>Submit a <code>CreateIPSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateIPSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateIPSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateIPSetResponse, _>(
+                    response,
+                    "CreateIPSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>RateBasedRule</a>. The <code>RateBasedRule</code> contains a <code>RateLimit</code>, which specifies the maximum number of requests that AWS WAF allows from a specified IP address in a five-minute period. The <code>RateBasedRule</code> also contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to count or block if these requests exceed the <code>RateLimit</code>.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request not only must exceed the <code>RateLimit</code>, but it also must match all the specifications to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that meet the conditions in the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions must be received at a rate of more than 15,000 requests every five minutes. If both conditions are met and the rate is exceeded, AWS WAF blocks the requests. If the rate drops below 15,000 for a five-minute period, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p> <p>To create and configure a <code>RateBasedRule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the rule. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRateBasedRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRateBasedRule</code> request to specify the predicates that you want to include in the rule.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RateBasedRule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> RusotoFuture<CreateRateBasedRuleResponse, CreateRateBasedRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRateBasedRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRateBasedRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRateBasedRuleResponse, _>(
+                    response,
+                    "CreateRateBasedRule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateRateBasedRuleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <a>RegexMatchSet</a>. You then use <a>UpdateRegexMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>RegexMatchSet</code> that contains a <code>RegexMatchTuple</code> that looks for any requests with <code>User-Agent</code> headers that match a <code>RegexPatternSet</code> with pattern <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value, using a <code>RegexPatternSet</code>, that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> RusotoFuture<CreateRegexMatchSetResponse, CreateRegexMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRegexMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRegexMatchSetResponse, _>(
+                    response,
+                    "CreateRegexMatchSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateRegexMatchSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>RegexPatternSet</code>. You then use <a>UpdateRegexPatternSet</a> to specify the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexPatternSet</a> request to specify the string that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> RusotoFuture<CreateRegexPatternSetResponse, CreateRegexPatternSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRegexPatternSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexPatternSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRegexPatternSetResponse, _>(
+                    response,
+                    "CreateRegexPatternSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateRegexPatternSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>Rule</code>, which contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to block. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed or blocked. For example, suppose that you add the following to a <code>Rule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to blocks requests that satisfy the <code>Rule</code>. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to specify the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> RusotoFuture<CreateRuleResponse, CreateRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRuleResponse, _>(response, "CreateRule")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>RuleGroup</code>. A rule group is a collection of predefined rules that you add to a web ACL. You use <a>UpdateRuleGroup</a> to add rules to the rule group.</p> <p>Rule groups are subject to the following limits:</p> <ul> <li> <p>Three rule groups per account. You can request an increase to this limit by contacting customer support.</p> </li> <li> <p>One rule group per web ACL.</p> </li> <li> <p>Ten rules per rule group.</p> </li> </ul> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> RusotoFuture<CreateRuleGroupResponse, CreateRuleGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateRuleGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateRuleGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateRuleGroupResponse, _>(
+                    response,
+                    "CreateRuleGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateRuleGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a <code>SizeConstraintSet</code>. You then use <a>UpdateSizeConstraintSet</a> to identify the part of a web request that you want AWS WAF to check for length, such as the length of the <code>User-Agent</code> header or the length of the query string. For example, you can create a <code>SizeConstraintSet</code> that matches any requests that have a query string that is longer than 100 bytes. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateSizeConstraintSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> RusotoFuture<CreateSizeConstraintSetResponse, CreateSizeConstraintSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateSizeConstraintSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateSizeConstraintSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSizeConstraintSetResponse, _>(
+                    response,
+                    "CreateSizeConstraintSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSizeConstraintSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a <a>SqlInjectionMatchSet</a>, which you use to allow, block, or count requests that contain snippets of SQL code in a specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateSqlInjectionMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count malicious SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<CreateSqlInjectionMatchSetResponse, CreateSqlInjectionMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateSqlInjectionMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateSqlInjectionMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateSqlInjectionMatchSetResponse, _>(
+                    response,
+                    "CreateSqlInjectionMatchSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateSqlInjectionMatchSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a <code>WebACL</code>, which contains the <code>Rules</code> that identify the CloudFront web requests that you want to allow, block, or count. AWS WAF evaluates <code>Rules</code> in order based on the value of <code>Priority</code> for each <code>Rule</code>.</p> <p>You also specify a default action, either <code>ALLOW</code> or <code>BLOCK</code>. If a web request doesn't match any of the <code>Rules</code> in a <code>WebACL</code>, AWS WAF responds to the request with the default action. </p> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>ByteMatchSet</code> objects and other predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateWebACL</code> request.</p> </li> <li> <p>Submit a <code>CreateWebACL</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <a>UpdateWebACL</a> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution.</p> </li> </ol> <p>For more information about how to use the AWS WAF API, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> RusotoFuture<CreateWebACLResponse, CreateWebACLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateWebACL");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACL");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateWebACLResponse, _>(
+                    response,
+                    "CreateWebACL",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWebACLError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an <a>XssMatchSet</a>, which you use to allow, block, or count requests that contain cross-site scripting attacks in the specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateXssMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateXssMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> RusotoFuture<CreateXssMatchSetResponse, CreateXssMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("CreateXssMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.CreateXssMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateXssMatchSetResponse, _>(
+                    response,
+                    "CreateXssMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateXssMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>ByteMatchSet</a>. You can&#39;t delete a <code>ByteMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>ByteMatchTuple</a> objects (any filters).</p> <p>If you just want to remove a <code>ByteMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>ByteMatchSet</code> to remove filters, if any. For more information, see <a>UpdateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteByteMatchSet</code> request.</p> </li> </ol></p>
+    fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> RusotoFuture<DeleteByteMatchSetResponse, DeleteByteMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteByteMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteByteMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteByteMatchSetResponse, _>(
+                    response,
+                    "DeleteByteMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteByteMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>GeoMatchSet</a>. You can&#39;t delete a <code>GeoMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any countries.</p> <p>If you just want to remove a <code>GeoMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>GeoMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>GeoMatchSet</code> to remove any countries. For more information, see <a>UpdateGeoMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteGeoMatchSet</code> request.</p> </li> </ol></p>
+    fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> RusotoFuture<DeleteGeoMatchSetResponse, DeleteGeoMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteGeoMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteGeoMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteGeoMatchSetResponse, _>(
+                    response,
+                    "DeleteGeoMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGeoMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes an <a>IPSet</a>. You can&#39;t delete an <code>IPSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any IP addresses.</p> <p>If you just want to remove an <code>IPSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>IPSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>IPSet</code> to remove IP address ranges, if any. For more information, see <a>UpdateIPSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteIPSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteIPSet</code> request.</p> </li> </ol></p>
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteIPSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteIPSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteIPSetResponse, _>(
+                    response,
+                    "DeleteIPSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Permanently deletes the <a>LoggingConfiguration</a> from the specified web ACL.</p>
+    fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> RusotoFuture<DeleteLoggingConfigurationResponse, DeleteLoggingConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteLoggingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteLoggingConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteLoggingConfigurationResponse, _>(
+                    response,
+                    "DeleteLoggingConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteLoggingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Permanently deletes an IAM policy from the specified RuleGroup.</p> <p>The user making the request must be the owner of the RuleGroup.</p>
+    fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> RusotoFuture<DeletePermissionPolicyResponse, DeletePermissionPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeletePermissionPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeletePermissionPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeletePermissionPolicyResponse, _>(
+                    response,
+                    "DeletePermissionPolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeletePermissionPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>RateBasedRule</a>. You can&#39;t delete a rule if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a rule from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RateBasedRule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RateBasedRule</code> to remove predicates, if any. For more information, see <a>UpdateRateBasedRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRateBasedRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRateBasedRule</code> request.</p> </li> </ol></p>
+    fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> RusotoFuture<DeleteRateBasedRuleResponse, DeleteRateBasedRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRateBasedRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRateBasedRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRateBasedRuleResponse, _>(
+                    response,
+                    "DeleteRateBasedRule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRateBasedRuleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>RegexMatchSet</a>. You can&#39;t delete a <code>RegexMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <code>RegexMatchTuples</code> objects (any filters).</p> <p>If you just want to remove a <code>RegexMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>RegexMatchSet</code> to remove filters, if any. For more information, see <a>UpdateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteRegexMatchSet</code> request.</p> </li> </ol></p>
+    fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> RusotoFuture<DeleteRegexMatchSetResponse, DeleteRegexMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRegexMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRegexMatchSetResponse, _>(
+                    response,
+                    "DeleteRegexMatchSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRegexMatchSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Permanently deletes a <a>RegexPatternSet</a>. You can't delete a <code>RegexPatternSet</code> if it's still used in any <code>RegexMatchSet</code> or if the <code>RegexPatternSet</code> is not empty. </p>
+    fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> RusotoFuture<DeleteRegexPatternSetResponse, DeleteRegexPatternSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRegexPatternSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexPatternSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRegexPatternSetResponse, _>(
+                    response,
+                    "DeleteRegexPatternSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteRegexPatternSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>Rule</a>. You can&#39;t delete a <code>Rule</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a <code>Rule</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>Rule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>Rule</code> to remove predicates, if any. For more information, see <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRule</code> request.</p> </li> </ol></p>
+    fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> RusotoFuture<DeleteRuleResponse, DeleteRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRuleResponse, _>(response, "DeleteRule")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>RuleGroup</a>. You can&#39;t delete a <code>RuleGroup</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any rules.</p> <p>If you just want to remove a <code>RuleGroup</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RuleGroup</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RuleGroup</code> to remove rules, if any. For more information, see <a>UpdateRuleGroup</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRuleGroup</code> request.</p> </li> <li> <p>Submit a <code>DeleteRuleGroup</code> request.</p> </li> </ol></p>
+    fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> RusotoFuture<DeleteRuleGroupResponse, DeleteRuleGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteRuleGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRuleGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteRuleGroupResponse, _>(
+                    response,
+                    "DeleteRuleGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteRuleGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>SizeConstraintSet</a>. You can&#39;t delete a <code>SizeConstraintSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>SizeConstraint</a> objects (any filters).</p> <p>If you just want to remove a <code>SizeConstraintSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>SizeConstraintSet</code> to remove filters, if any. For more information, see <a>UpdateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSizeConstraintSet</code> request.</p> </li> </ol></p>
+    fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> RusotoFuture<DeleteSizeConstraintSetResponse, DeleteSizeConstraintSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteSizeConstraintSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSizeConstraintSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSizeConstraintSetResponse, _>(
+                    response,
+                    "DeleteSizeConstraintSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSizeConstraintSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>SqlInjectionMatchSet</a>. You can&#39;t delete a <code>SqlInjectionMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>SqlInjectionMatchTuple</a> objects.</p> <p>If you just want to remove a <code>SqlInjectionMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SqlInjectionMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>SqlInjectionMatchSet</code> to remove filters, if any. For more information, see <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> </ol></p>
+    fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<DeleteSqlInjectionMatchSetResponse, DeleteSqlInjectionMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteSqlInjectionMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSqlInjectionMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteSqlInjectionMatchSetResponse, _>(
+                    response,
+                    "DeleteSqlInjectionMatchSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteSqlInjectionMatchSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes a <a>WebACL</a>. You can&#39;t delete a <code>WebACL</code> if it still contains any <code>Rules</code>.</p> <p>To delete a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>WebACL</code> to remove <code>Rules</code>, if any. For more information, see <a>UpdateWebACL</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteWebACL</code> request.</p> </li> <li> <p>Submit a <code>DeleteWebACL</code> request.</p> </li> </ol></p>
+    fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> RusotoFuture<DeleteWebACLResponse, DeleteWebACLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteWebACL");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteWebACL");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteWebACLResponse, _>(
+                    response,
+                    "DeleteWebACL",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteWebACLError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Permanently deletes an <a>XssMatchSet</a>. You can&#39;t delete an <code>XssMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>XssMatchTuple</a> objects.</p> <p>If you just want to remove an <code>XssMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>XssMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>XssMatchSet</code> to remove filters, if any. For more information, see <a>UpdateXssMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteXssMatchSet</code> request.</p> </li> </ol></p>
+    fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> RusotoFuture<DeleteXssMatchSetResponse, DeleteXssMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("DeleteXssMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.DeleteXssMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteXssMatchSetResponse, _>(
+                    response,
+                    "DeleteXssMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteXssMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>ByteMatchSet</a> specified by <code>ByteMatchSetId</code>.</p>
+    fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> RusotoFuture<GetByteMatchSetResponse, GetByteMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetByteMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetByteMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetByteMatchSetResponse, _>(
+                    response,
+                    "GetByteMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetByteMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>When you want to create, update, or delete AWS WAF objects, get a change token and include the change token in the create, update, or delete request. Change tokens ensure that your application doesn't submit conflicting requests to AWS WAF.</p> <p>Each create, update, or delete request must use a unique change token. If your application submits a <code>GetChangeToken</code> request and then submits a second <code>GetChangeToken</code> request before submitting a create, update, or delete request, the second <code>GetChangeToken</code> request returns the same value as the first <code>GetChangeToken</code> request.</p> <p>When you use a change token in a create, update, or delete request, the status of the change token changes to <code>PENDING</code>, which indicates that AWS WAF is propagating the change to all AWS WAF servers. Use <code>GetChangeTokenStatus</code> to determine the status of your change token.</p>
+    fn get_change_token(&self) -> RusotoFuture<GetChangeTokenResponse, GetChangeTokenError> {
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetChangeToken");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeToken");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetChangeTokenResponse, _>(
+                    response,
+                    "GetChangeToken",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetChangeTokenError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p><p>Returns the status of a <code>ChangeToken</code> that you got by calling <a>GetChangeToken</a>. <code>ChangeTokenStatus</code> is one of the following values:</p> <ul> <li> <p> <code>PROVISIONED</code>: You requested the change token by calling <code>GetChangeToken</code>, but you haven&#39;t used it yet in a call to create, update, or delete an AWS WAF object.</p> </li> <li> <p> <code>PENDING</code>: AWS WAF is propagating the create, update, or delete request to all AWS WAF servers.</p> </li> <li> <p> <code>INSYNC</code>: Propagation is complete.</p> </li> </ul></p>
+    fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> RusotoFuture<GetChangeTokenStatusResponse, GetChangeTokenStatusError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetChangeTokenStatus");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeTokenStatus");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetChangeTokenStatusResponse, _>(
+                    response,
+                    "GetChangeTokenStatus",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetChangeTokenStatusError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>GeoMatchSet</a> that is specified by <code>GeoMatchSetId</code>.</p>
+    fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> RusotoFuture<GetGeoMatchSetResponse, GetGeoMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetGeoMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetGeoMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetGeoMatchSetResponse, _>(
+                    response,
+                    "GetGeoMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetGeoMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>IPSet</a> that is specified by <code>IPSetId</code>.</p>
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetIPSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetIPSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetIPSetResponse, _>(response, "GetIPSet")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>LoggingConfiguration</a> for the specified web ACL.</p>
+    fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> RusotoFuture<GetLoggingConfigurationResponse, GetLoggingConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetLoggingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetLoggingConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetLoggingConfigurationResponse, _>(
+                    response,
+                    "GetLoggingConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetLoggingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the IAM policy attached to the RuleGroup.</p>
+    fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> RusotoFuture<GetPermissionPolicyResponse, GetPermissionPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetPermissionPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetPermissionPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetPermissionPolicyResponse, _>(
+                    response,
+                    "GetPermissionPolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetPermissionPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>RateBasedRule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRateBasedRule</code> request.</p>
+    fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> RusotoFuture<GetRateBasedRuleResponse, GetRateBasedRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRateBasedRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetRateBasedRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRateBasedRuleResponse, _>(
+                    response,
+                    "GetRateBasedRule",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRateBasedRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of IP addresses currently being blocked by the <a>RateBasedRule</a> that is specified by the <code>RuleId</code>. The maximum number of managed keys that will be blocked is 10,000. If more than 10,000 addresses exceed the rate limit, the 10,000 addresses with the highest rates will be blocked.</p>
+    fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> RusotoFuture<GetRateBasedRuleManagedKeysResponse, GetRateBasedRuleManagedKeysError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRateBasedRuleManagedKeys");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSWAF_20150824.GetRateBasedRuleManagedKeys",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRateBasedRuleManagedKeysResponse, _>(
+                    response,
+                    "GetRateBasedRuleManagedKeys",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetRateBasedRuleManagedKeysError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the <a>RegexMatchSet</a> specified by <code>RegexMatchSetId</code>.</p>
+    fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> RusotoFuture<GetRegexMatchSetResponse, GetRegexMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRegexMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRegexMatchSetResponse, _>(
+                    response,
+                    "GetRegexMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRegexMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>RegexPatternSet</a> specified by <code>RegexPatternSetId</code>.</p>
+    fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> RusotoFuture<GetRegexPatternSetResponse, GetRegexPatternSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRegexPatternSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexPatternSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRegexPatternSetResponse, _>(
+                    response,
+                    "GetRegexPatternSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRegexPatternSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>Rule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRule</code> request.</p>
+    fn get_rule(&self, input: GetRuleRequest) -> RusotoFuture<GetRuleResponse, GetRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRuleResponse, _>(response, "GetRule")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>RuleGroup</a> that is specified by the <code>RuleGroupId</code> that you included in the <code>GetRuleGroup</code> request.</p> <p>To view the rules in a rule group, use <a>ListActivatedRulesInRuleGroup</a>.</p>
+    fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> RusotoFuture<GetRuleGroupResponse, GetRuleGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetRuleGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetRuleGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetRuleGroupResponse, _>(
+                    response,
+                    "GetRuleGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetRuleGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Gets detailed information about a specified number of requests--a sample--that AWS WAF randomly selects from among the first 5,000 requests that your AWS resource received during a time range that you choose. You can specify a sample size of up to 500 requests, and you can specify any time range in the previous three hours.</p> <p> <code>GetSampledRequests</code> returns a time range, which is usually the time range that you specified. However, if your resource (such as a CloudFront distribution) received 5,000 requests before the specified time range elapsed, <code>GetSampledRequests</code> returns an updated time range. This new time range indicates the actual period during which AWS WAF selected the requests in the sample.</p>
+    fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> RusotoFuture<GetSampledRequestsResponse, GetSampledRequestsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetSampledRequests");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetSampledRequests");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSampledRequestsResponse, _>(
+                    response,
+                    "GetSampledRequests",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetSampledRequestsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>SizeConstraintSet</a> specified by <code>SizeConstraintSetId</code>.</p>
+    fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> RusotoFuture<GetSizeConstraintSetResponse, GetSizeConstraintSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetSizeConstraintSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetSizeConstraintSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSizeConstraintSetResponse, _>(
+                    response,
+                    "GetSizeConstraintSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(GetSizeConstraintSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>SqlInjectionMatchSet</a> that is specified by <code>SqlInjectionMatchSetId</code>.</p>
+    fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<GetSqlInjectionMatchSetResponse, GetSqlInjectionMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetSqlInjectionMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetSqlInjectionMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetSqlInjectionMatchSetResponse, _>(
+                    response,
+                    "GetSqlInjectionMatchSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(GetSqlInjectionMatchSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns the <a>WebACL</a> that is specified by <code>WebACLId</code>.</p>
+    fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> RusotoFuture<GetWebACLResponse, GetWebACLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetWebACL");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetWebACL");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetWebACLResponse, _>(response, "GetWebACL")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetWebACLError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the <a>XssMatchSet</a> that is specified by <code>XssMatchSetId</code>.</p>
+    fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> RusotoFuture<GetXssMatchSetResponse, GetXssMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("GetXssMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.GetXssMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetXssMatchSetResponse, _>(
+                    response,
+                    "GetXssMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetXssMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>ActivatedRule</a> objects.</p>
+    fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> RusotoFuture<ListActivatedRulesInRuleGroupResponse, ListActivatedRulesInRuleGroupError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListActivatedRulesInRuleGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSWAF_20150824.ListActivatedRulesInRuleGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListActivatedRulesInRuleGroupResponse, _>(
+                    response,
+                    "ListActivatedRulesInRuleGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListActivatedRulesInRuleGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>ByteMatchSetSummary</a> objects.</p>
+    fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> RusotoFuture<ListByteMatchSetsResponse, ListByteMatchSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListByteMatchSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListByteMatchSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListByteMatchSetsResponse, _>(
+                    response,
+                    "ListByteMatchSets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListByteMatchSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>GeoMatchSetSummary</a> objects in the response.</p>
+    fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> RusotoFuture<ListGeoMatchSetsResponse, ListGeoMatchSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListGeoMatchSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListGeoMatchSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGeoMatchSetsResponse, _>(
+                    response,
+                    "ListGeoMatchSets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGeoMatchSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>IPSetSummary</a> objects in the response.</p>
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListIPSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListIPSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListIPSetsResponse, _>(response, "ListIPSets")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListIPSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>LoggingConfiguration</a> objects.</p>
+    fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> RusotoFuture<ListLoggingConfigurationsResponse, ListLoggingConfigurationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListLoggingConfigurations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListLoggingConfigurations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListLoggingConfigurationsResponse, _>(
+                    response,
+                    "ListLoggingConfigurations",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListLoggingConfigurationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
+    fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> RusotoFuture<ListRateBasedRulesResponse, ListRateBasedRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRateBasedRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListRateBasedRules");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRateBasedRulesResponse, _>(
+                    response,
+                    "ListRateBasedRules",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRateBasedRulesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>RegexMatchSetSummary</a> objects.</p>
+    fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> RusotoFuture<ListRegexMatchSetsResponse, ListRegexMatchSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRegexMatchSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexMatchSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRegexMatchSetsResponse, _>(
+                    response,
+                    "ListRegexMatchSets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRegexMatchSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>RegexPatternSetSummary</a> objects.</p>
+    fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> RusotoFuture<ListRegexPatternSetsResponse, ListRegexPatternSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRegexPatternSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexPatternSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRegexPatternSetsResponse, _>(
+                    response,
+                    "ListRegexPatternSets",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListRegexPatternSetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>RuleGroup</a> objects.</p>
+    fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> RusotoFuture<ListRuleGroupsResponse, ListRuleGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRuleGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListRuleGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRuleGroupsResponse, _>(
+                    response,
+                    "ListRuleGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRuleGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>RuleSummary</a> objects.</p>
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListRules");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListRulesResponse, _>(response, "ListRules")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListRulesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>SizeConstraintSetSummary</a> objects.</p>
+    fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> RusotoFuture<ListSizeConstraintSetsResponse, ListSizeConstraintSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListSizeConstraintSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListSizeConstraintSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSizeConstraintSetsResponse, _>(
+                    response,
+                    "ListSizeConstraintSets",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListSizeConstraintSetsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>SqlInjectionMatchSet</a> objects.</p>
+    fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> RusotoFuture<ListSqlInjectionMatchSetsResponse, ListSqlInjectionMatchSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListSqlInjectionMatchSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListSqlInjectionMatchSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSqlInjectionMatchSetsResponse, _>(
+                    response,
+                    "ListSqlInjectionMatchSets",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSqlInjectionMatchSetsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>RuleGroup</a> objects that you are subscribed to.</p>
+    fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> RusotoFuture<ListSubscribedRuleGroupsResponse, ListSubscribedRuleGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListSubscribedRuleGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListSubscribedRuleGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListSubscribedRuleGroupsResponse, _>(
+                    response,
+                    "ListSubscribedRuleGroups",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListSubscribedRuleGroupsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListTagsForResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListTagsForResourceResponse, _>(
+                    response,
+                    "ListTagsForResource",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListTagsForResourceError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>WebACLSummary</a> objects in the response.</p>
+    fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> RusotoFuture<ListWebACLsResponse, ListWebACLsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListWebACLs");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListWebACLs");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListWebACLsResponse, _>(
+                    response,
+                    "ListWebACLs",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListWebACLsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an array of <a>XssMatchSet</a> objects.</p>
+    fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> RusotoFuture<ListXssMatchSetsResponse, ListXssMatchSetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("ListXssMatchSets");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.ListXssMatchSets");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListXssMatchSetsResponse, _>(
+                    response,
+                    "ListXssMatchSets",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListXssMatchSetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates a <a>LoggingConfiguration</a> with a specified web ACL.</p> <p>You can access information about all traffic that AWS WAF inspects using the following steps:</p> <ol> <li> <p>Create an Amazon Kinesis Data Firehose . </p> <p>Create the data firehose with a PUT source and in the region that you are operating. However, if you are capturing logs for Amazon CloudFront, always create the firehose in US East (N. Virginia). </p> <note> <p>Do not create the data firehose using a <code>Kinesis stream</code> as your source.</p> </note> </li> <li> <p>Associate that firehose to your web ACL using a <code>PutLoggingConfiguration</code> request.</p> </li> </ol> <p>When you successfully enable logging using a <code>PutLoggingConfiguration</code> request, AWS WAF will create a service linked role with the necessary permissions to write logs to the Amazon Kinesis Data Firehose. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/logging.html">Logging Web ACL Traffic Information</a> in the <i>AWS WAF Developer Guide</i>.</p>
+    fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> RusotoFuture<PutLoggingConfigurationResponse, PutLoggingConfigurationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("PutLoggingConfiguration");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.PutLoggingConfiguration");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutLoggingConfigurationResponse, _>(
+                    response,
+                    "PutLoggingConfiguration",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(PutLoggingConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Attaches a IAM policy to the specified resource. The only supported use for this action is to share a RuleGroup across accounts.</p> <p>The <code>PutPermissionPolicy</code> is subject to the following restrictions:</p> <ul> <li> <p>You can attach only one policy with each <code>PutPermissionPolicy</code> request.</p> </li> <li> <p>The policy must include an <code>Effect</code>, <code>Action</code> and <code>Principal</code>. </p> </li> <li> <p> <code>Effect</code> must specify <code>Allow</code>.</p> </li> <li> <p>The <code>Action</code> in the policy must be <code>waf:UpdateWebACL</code>, <code>waf-regional:UpdateWebACL</code>, <code>waf:GetRuleGroup</code> and <code>waf-regional:GetRuleGroup</code> . Any extra or wildcard actions in the policy will be rejected.</p> </li> <li> <p>The policy cannot include a <code>Resource</code> parameter.</p> </li> <li> <p>The ARN in the request must be a valid WAF RuleGroup ARN and the RuleGroup must exist in the same region.</p> </li> <li> <p>The user making the request must be the owner of the RuleGroup.</p> </li> <li> <p>Your policy must be composed using IAM Policy version 2012-10-17.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies.html">IAM Policies</a>. </p> <p>An example of a valid policy parameter is shown in the Examples section below.</p>
+    fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> RusotoFuture<PutPermissionPolicyResponse, PutPermissionPolicyError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("PutPermissionPolicy");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.PutPermissionPolicy");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutPermissionPolicyResponse, _>(
+                    response,
+                    "PutPermissionPolicy",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutPermissionPolicyError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("TagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.TagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<TagResourceResponse, _>(
+                    response,
+                    "TagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(TagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UntagResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UntagResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UntagResourceResponse, _>(
+                    response,
+                    "UntagResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UntagResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>ByteMatchTuple</a> objects (filters) in a <a>ByteMatchSet</a>. For each <code>ByteMatchTuple</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>ByteMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to look for. For more information, including how you specify the values for the AWS WAF API and the AWS CLI or SDKs, see <code>TargetString</code> in the <a>ByteMatchTuple</a> data type. </p> </li> <li> <p>Where to look, such as at the beginning or the end of a query string.</p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p>For example, you can add a <code>ByteMatchSetUpdate</code> object that matches web requests in which <code>User-Agent</code> headers contain the string <code>BadBot</code>. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>ByteMatchSet.</code> For more information, see <a>CreateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateByteMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> RusotoFuture<UpdateByteMatchSetResponse, UpdateByteMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateByteMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateByteMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateByteMatchSetResponse, _>(
+                    response,
+                    "UpdateByteMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateByteMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>GeoMatchConstraint</a> objects in an <code>GeoMatchSet</code>. For each <code>GeoMatchConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>GeoMatchConstraint</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The <code>Type</code>. The only valid value for <code>Type</code> is <code>Country</code>.</p> </li> <li> <p>The <code>Value</code>, which is a two character code for the country to add to the <code>GeoMatchConstraint</code> object. Valid codes are listed in <a>GeoMatchConstraint$Value</a>.</p> </li> </ul> <p>To create and configure an <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateGeoMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSet</code> request to specify the country that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>GeoMatchSet</code>, you specify the country that you want to add and/or the country that you want to delete. If you want to change a country, you delete the existing country and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> RusotoFuture<UpdateGeoMatchSetResponse, UpdateGeoMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateGeoMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateGeoMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateGeoMatchSetResponse, _>(
+                    response,
+                    "UpdateGeoMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateGeoMatchSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>IPSetDescriptor</a> objects in an <code>IPSet</code>. For each <code>IPSetDescriptor</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>IPSetDescriptor</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The IP address version, <code>IPv4</code> or <code>IPv6</code>. </p> </li> <li> <p>The IP address in CIDR notation, for example, <code>192.0.2.0/24</code> (for the range of IP addresses from <code>192.0.2.0</code> to <code>192.0.2.255</code>) or <code>192.0.2.44/32</code> (for the individual IP address <code>192.0.2.44</code>). </p> </li> </ul> <p>AWS WAF supports IPv4 address ranges: /8 and any range between /16 through /32. AWS WAF supports IPv6 address ranges: /24, /32, /48, /56, /64, and /128. For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>IPv6 addresses can be represented using any of the following formats:</p> <ul> <li> <p>1111:0000:0000:0000:0000:0000:0000:0111/128</p> </li> <li> <p>1111:0:0:0:0:0:0:0111/128</p> </li> <li> <p>1111::0111/128</p> </li> <li> <p>1111::111/128</p> </li> </ul> <p>You use an <code>IPSet</code> to specify which web requests you want to allow or block based on the IP addresses that the requests originated from. For example, if you're receiving a lot of requests from one or a small number of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that specifies those IP addresses, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateIPSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>IPSet</code>, you specify the IP addresses that you want to add and/or the IP addresses that you want to delete. If you want to change an IP address, you delete the existing IP address and add the new one.</p> <p>You can insert a maximum of 1000 addresses in a single request.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateIPSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateIPSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateIPSetResponse, _>(
+                    response,
+                    "UpdateIPSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateIPSetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>Predicate</a> objects in a rule and updates the <code>RateLimit</code> in the rule. </p> <p>Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to block or count. The <code>RateLimit</code> specifies the number of requests every five minutes that triggers the rule.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request must match all the predicates and exceed the <code>RateLimit</code> to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions much be received at a rate of more than 15,000 every five minutes. If the rate drops below this limit, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p>
+    fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> RusotoFuture<UpdateRateBasedRuleResponse, UpdateRateBasedRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRateBasedRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRateBasedRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRateBasedRuleResponse, _>(
+                    response,
+                    "UpdateRateBasedRule",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRateBasedRuleError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>RegexMatchTuple</a> objects (filters) in a <a>RegexMatchSet</a>. For each <code>RegexMatchSetUpdate</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>RegexMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspectupdate, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p> For example, you can create a <code>RegexPatternSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexMatchSet.</code> For more information, see <a>CreateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the identifier of the <code>RegexPatternSet</code> that contain the regular expression patters you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> RusotoFuture<UpdateRegexMatchSetResponse, UpdateRegexMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRegexMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRegexMatchSetResponse, _>(
+                    response,
+                    "UpdateRegexMatchSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRegexMatchSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <code>RegexPatternString</code> objects in a <a>RegexPatternSet</a>. For each <code>RegexPatternString</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the <code>RegexPatternString</code>.</p> </li> <li> <p>The regular expression pattern that you want to insert or delete. For more information, see <a>RegexPatternSet</a>. </p> </li> </ul> <p> For example, you can create a <code>RegexPatternString</code> such as <code>B[a@]dB[o0]t</code>. AWS WAF will match this <code>RegexPatternString</code> to:</p> <ul> <li> <p>BadBot</p> </li> <li> <p>BadB0t</p> </li> <li> <p>B@dBot</p> </li> <li> <p>B@dB0t</p> </li> </ul> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexPatternSet.</code> For more information, see <a>CreateRegexPatternSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexPatternSet</code> request to specify the regular expression pattern that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> RusotoFuture<UpdateRegexPatternSetResponse, UpdateRegexPatternSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRegexPatternSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexPatternSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRegexPatternSetResponse, _>(
+                    response,
+                    "UpdateRegexPatternSet",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRegexPatternSetError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>Predicate</a> objects in a <code>Rule</code>. Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to allow, block, or count. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed, blocked, or counted. For example, suppose that you add the following to a <code>Rule</code>: </p> <ul> <li> <p>A <code>ByteMatchSet</code> that matches the value <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44</code> </p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the <code>Rule</code>. For a request to be blocked, the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code> <i>and</i> the request must originate from the IP address 192.0.2.44.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create the <code>Rule</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to add predicates to the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>ByteMatchSet</code> or <code>IPSet</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> RusotoFuture<UpdateRuleResponse, UpdateRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRule");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRule");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRuleResponse, _>(response, "UpdateRule")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>RuleGroup</code>.</p> <p>You can only insert <code>REGULAR</code> rules into a rule group.</p> <p>You can have a maximum of ten rules per rule group.</p> <p>To create and configure a <code>RuleGroup</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>RuleGroup</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRuleGroup</a> request.</p> </li> <li> <p>Submit an <code>UpdateRuleGroup</code> request to add <code>Rules</code> to the <code>RuleGroup</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RuleGroup</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>Rule</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> RusotoFuture<UpdateRuleGroupResponse, UpdateRuleGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateRuleGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRuleGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateRuleGroupResponse, _>(
+                    response,
+                    "UpdateRuleGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateRuleGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>SizeConstraint</a> objects (filters) in a <a>SizeConstraintSet</a>. For each <code>SizeConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>SizeConstraintSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to evaluate, such as the length of a query string or the length of the <code>User-Agent</code> header.</p> </li> <li> <p>Whether to perform any transformations on the request, such as converting it to lowercase, before checking its length. Note that transformations of the request body are not supported because the AWS resource forwards only the first <code>8192</code> bytes of your request to AWS WAF.</p> <p>You can only specify a single type of TextTransformation.</p> </li> <li> <p>A <code>ComparisonOperator</code> used for evaluating the selected part of the request against the specified <code>Size</code>, such as equals, greater than, less than, and so on.</p> </li> <li> <p>The length, in bytes, that you want AWS WAF to watch for in selected part of the request. The length is computed after applying the transformation.</p> </li> </ul> <p>For example, you can add a <code>SizeConstraintSetUpdate</code> object that matches web requests in which the length of the <code>User-Agent</code> header is greater than 100 bytes. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>SizeConstraintSet.</code> For more information, see <a>CreateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateSizeConstraintSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> RusotoFuture<UpdateSizeConstraintSetResponse, UpdateSizeConstraintSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateSizeConstraintSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSizeConstraintSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateSizeConstraintSetResponse, _>(
+                    response,
+                    "UpdateSizeConstraintSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateSizeConstraintSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>SqlInjectionMatchTuple</a> objects (filters) in a <a>SqlInjectionMatchSet</a>. For each <code>SqlInjectionMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change a <code>SqlInjectionMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for snippets of malicious SQL code.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>SqlInjectionMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain snippets of SQL code in the query string and you want to block the requests, you can create a <code>SqlInjectionMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateSqlInjectionMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for snippets of SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<UpdateSqlInjectionMatchSetResponse, UpdateSqlInjectionMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateSqlInjectionMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSqlInjectionMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateSqlInjectionMatchSetResponse, _>(
+                    response,
+                    "UpdateSqlInjectionMatchSet",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateSqlInjectionMatchSetError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>WebACL</code>. Each <code>Rule</code> identifies web requests that you want to allow, block, or count. When you update a <code>WebACL</code>, you specify the following values:</p> <ul> <li> <p>A default action for the <code>WebACL</code>, either <code>ALLOW</code> or <code>BLOCK</code>. AWS WAF performs the default action if a request doesn't match the criteria in any of the <code>Rules</code> in a <code>WebACL</code>.</p> </li> <li> <p>The <code>Rules</code> that you want to add or delete. If you want to replace one <code>Rule</code> with another, you delete the existing <code>Rule</code> and add the new one.</p> </li> <li> <p>For each <code>Rule</code>, whether you want AWS WAF to allow requests, block requests, or count requests that match the conditions in the <code>Rule</code>.</p> </li> <li> <p>The order in which you want AWS WAF to evaluate the <code>Rules</code> in a <code>WebACL</code>. If you add more than one <code>Rule</code> to a <code>WebACL</code>, AWS WAF evaluates each request against the <code>Rules</code> in order based on the value of <code>Priority</code>. (The <code>Rule</code> that has the lowest value for <code>Priority</code> is evaluated first.) When a web request matches all the predicates (such as <code>ByteMatchSets</code> and <code>IPSets</code>) in a <code>Rule</code>, AWS WAF immediately takes the corresponding action, allow or block, and doesn't evaluate the request against the remaining <code>Rules</code> in the <code>WebACL</code>, if any. </p> </li> </ul> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Create a <code>WebACL</code>. See <a>CreateWebACL</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <code>UpdateWebACL</code> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution. </p> <p>The <code>ActivatedRule</code> can be a rule group. If you specify a rule group as your <code>ActivatedRule</code>, you can exclude specific rules from that rule group.</p> <p>If you already have a rule group associated with a web ACL and want to submit an <code>UpdateWebACL</code> request to exclude certain rules from that rule group, you must first remove the rule group from the web ACL, the re-insert it again, specifying the excluded rules. For details, see <a>ActivatedRule$ExcludedRules</a>. </p> </li> </ol> <p>Be aware that if you try to add a RATE_BASED rule to a web ACL without setting the rule type when first creating the rule, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule (the default rule type) with the specified ID, which does not exist. </p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> RusotoFuture<UpdateWebACLResponse, UpdateWebACLError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateWebACL");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateWebACL");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateWebACLResponse, _>(
+                    response,
+                    "UpdateWebACL",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateWebACLError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Inserts or deletes <a>XssMatchTuple</a> objects (filters) in an <a>XssMatchSet</a>. For each <code>XssMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change an <code>XssMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for cross-site scripting attacks.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>XssMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain cross-site scripting attacks in the request body and you want to block the requests, you can create an <code>XssMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateXssMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateXssMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+    fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
+        request.set_operation("UpdateXssMatchSet");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateXssMatchSet");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateXssMatchSetResponse, _>(
+                    response,
+                    "UpdateXssMatchSet",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateXssMatchSetError::from_response(response))),
+                )
             }
         })
     }
-
-    /// <p>Inserts or deletes <a>GeoMatchConstraint</a> objects in an <code>GeoMatchSet</code>. For each <code>GeoMatchConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>GeoMatchConstraint</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The <code>Type</code>. The only valid value for <code>Type</code> is <code>Country</code>.</p> </li> <li> <p>The <code>Value</code>, which is a two character code for the country to add to the <code>GeoMatchConstraint</code> object. Valid codes are listed in <a>GeoMatchConstraint$Value</a>.</p> </li> </ul> <p>To create and configure an <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateGeoMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSet</code> request to specify the country that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>GeoMatchSet</code>, you specify the country that you want to add and/or the country that you want to delete. If you want to change a country, you delete the existing country and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
+}
+
+/// A mock of the WAF API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockWaf {
+    create_byte_match_set: Option<
+        Box<
+            dyn Fn(
+                    CreateByteMatchSetRequest,
+                ) -> ::std::result::Result<
+                    CreateByteMatchSetResponse,
+                    RusotoError<CreateByteMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_geo_match_set: Option<
+        Box<
+            dyn Fn(
+                    CreateGeoMatchSetRequest,
+                ) -> ::std::result::Result<
+                    CreateGeoMatchSetResponse,
+                    RusotoError<CreateGeoMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_ip_set: Option<
+        Box<
+            dyn Fn(
+                    CreateIPSetRequest,
+                )
+                    -> ::std::result::Result<CreateIPSetResponse, RusotoError<CreateIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_rate_based_rule: Option<
+        Box<
+            dyn Fn(
+                    CreateRateBasedRuleRequest,
+                ) -> ::std::result::Result<
+                    CreateRateBasedRuleResponse,
+                    RusotoError<CreateRateBasedRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_regex_match_set: Option<
+        Box<
+            dyn Fn(
+                    CreateRegexMatchSetRequest,
+                ) -> ::std::result::Result<
+                    CreateRegexMatchSetResponse,
+                    RusotoError<CreateRegexMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_regex_pattern_set: Option<
+        Box<
+            dyn Fn(
+                    CreateRegexPatternSetRequest,
+                ) -> ::std::result::Result<
+                    CreateRegexPatternSetResponse,
+                    RusotoError<CreateRegexPatternSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_rule: Option<
+        Box<
+            dyn Fn(
+                    CreateRuleRequest,
+                )
+                    -> ::std::result::Result<CreateRuleResponse, RusotoError<CreateRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_rule_group: Option<
+        Box<
+            dyn Fn(
+                    CreateRuleGroupRequest,
+                ) -> ::std::result::Result<
+                    CreateRuleGroupResponse,
+                    RusotoError<CreateRuleGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_size_constraint_set: Option<
+        Box<
+            dyn Fn(
+                    CreateSizeConstraintSetRequest,
+                ) -> ::std::result::Result<
+                    CreateSizeConstraintSetResponse,
+                    RusotoError<CreateSizeConstraintSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_sql_injection_match_set: Option<
+        Box<
+            dyn Fn(
+                    CreateSqlInjectionMatchSetRequest,
+                ) -> ::std::result::Result<
+                    CreateSqlInjectionMatchSetResponse,
+                    RusotoError<CreateSqlInjectionMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_web_acl: Option<
+        Box<
+            dyn Fn(
+                    CreateWebACLRequest,
+                )
+                    -> ::std::result::Result<CreateWebACLResponse, RusotoError<CreateWebACLError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_xss_match_set: Option<
+        Box<
+            dyn Fn(
+                    CreateXssMatchSetRequest,
+                ) -> ::std::result::Result<
+                    CreateXssMatchSetResponse,
+                    RusotoError<CreateXssMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_byte_match_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteByteMatchSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteByteMatchSetResponse,
+                    RusotoError<DeleteByteMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_geo_match_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteGeoMatchSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteGeoMatchSetResponse,
+                    RusotoError<DeleteGeoMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_ip_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteIPSetRequest,
+                )
+                    -> ::std::result::Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_logging_configuration: Option<
+        Box<
+            dyn Fn(
+                    DeleteLoggingConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DeleteLoggingConfigurationResponse,
+                    RusotoError<DeleteLoggingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_permission_policy: Option<
+        Box<
+            dyn Fn(
+                    DeletePermissionPolicyRequest,
+                ) -> ::std::result::Result<
+                    DeletePermissionPolicyResponse,
+                    RusotoError<DeletePermissionPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_rate_based_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteRateBasedRuleRequest,
+                ) -> ::std::result::Result<
+                    DeleteRateBasedRuleResponse,
+                    RusotoError<DeleteRateBasedRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_regex_match_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteRegexMatchSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteRegexMatchSetResponse,
+                    RusotoError<DeleteRegexMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_regex_pattern_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteRegexPatternSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteRegexPatternSetResponse,
+                    RusotoError<DeleteRegexPatternSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteRuleRequest,
+                )
+                    -> ::std::result::Result<DeleteRuleResponse, RusotoError<DeleteRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_rule_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteRuleGroupRequest,
+                ) -> ::std::result::Result<
+                    DeleteRuleGroupResponse,
+                    RusotoError<DeleteRuleGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_size_constraint_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteSizeConstraintSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteSizeConstraintSetResponse,
+                    RusotoError<DeleteSizeConstraintSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_sql_injection_match_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteSqlInjectionMatchSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteSqlInjectionMatchSetResponse,
+                    RusotoError<DeleteSqlInjectionMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_web_acl: Option<
+        Box<
+            dyn Fn(
+                    DeleteWebACLRequest,
+                )
+                    -> ::std::result::Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_xss_match_set: Option<
+        Box<
+            dyn Fn(
+                    DeleteXssMatchSetRequest,
+                ) -> ::std::result::Result<
+                    DeleteXssMatchSetResponse,
+                    RusotoError<DeleteXssMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_byte_match_set: Option<
+        Box<
+            dyn Fn(
+                    GetByteMatchSetRequest,
+                ) -> ::std::result::Result<
+                    GetByteMatchSetResponse,
+                    RusotoError<GetByteMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_change_token: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetChangeTokenResponse,
+                    RusotoError<GetChangeTokenError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_change_token_status: Option<
+        Box<
+            dyn Fn(
+                    GetChangeTokenStatusRequest,
+                ) -> ::std::result::Result<
+                    GetChangeTokenStatusResponse,
+                    RusotoError<GetChangeTokenStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_geo_match_set: Option<
+        Box<
+            dyn Fn(
+                    GetGeoMatchSetRequest,
+                ) -> ::std::result::Result<
+                    GetGeoMatchSetResponse,
+                    RusotoError<GetGeoMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_ip_set: Option<
+        Box<
+            dyn Fn(
+                    GetIPSetRequest,
+                )
+                    -> ::std::result::Result<GetIPSetResponse, RusotoError<GetIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_logging_configuration: Option<
+        Box<
+            dyn Fn(
+                    GetLoggingConfigurationRequest,
+                ) -> ::std::result::Result<
+                    GetLoggingConfigurationResponse,
+                    RusotoError<GetLoggingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_permission_policy: Option<
+        Box<
+            dyn Fn(
+                    GetPermissionPolicyRequest,
+                ) -> ::std::result::Result<
+                    GetPermissionPolicyResponse,
+                    RusotoError<GetPermissionPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_rate_based_rule: Option<
+        Box<
+            dyn Fn(
+                    GetRateBasedRuleRequest,
+                ) -> ::std::result::Result<
+                    GetRateBasedRuleResponse,
+                    RusotoError<GetRateBasedRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_rate_based_rule_managed_keys: Option<
+        Box<
+            dyn Fn(
+                    GetRateBasedRuleManagedKeysRequest,
+                ) -> ::std::result::Result<
+                    GetRateBasedRuleManagedKeysResponse,
+                    RusotoError<GetRateBasedRuleManagedKeysError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_regex_match_set: Option<
+        Box<
+            dyn Fn(
+                    GetRegexMatchSetRequest,
+                ) -> ::std::result::Result<
+                    GetRegexMatchSetResponse,
+                    RusotoError<GetRegexMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_regex_pattern_set: Option<
+        Box<
+            dyn Fn(
+                    GetRegexPatternSetRequest,
+                ) -> ::std::result::Result<
+                    GetRegexPatternSetResponse,
+                    RusotoError<GetRegexPatternSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_rule: Option<
+        Box<
+            dyn Fn(
+                    GetRuleRequest,
+                )
+                    -> ::std::result::Result<GetRuleResponse, RusotoError<GetRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_rule_group: Option<
+        Box<
+            dyn Fn(
+                    GetRuleGroupRequest,
+                )
+                    -> ::std::result::Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_sampled_requests: Option<
+        Box<
+            dyn Fn(
+                    GetSampledRequestsRequest,
+                ) -> ::std::result::Result<
+                    GetSampledRequestsResponse,
+                    RusotoError<GetSampledRequestsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_size_constraint_set: Option<
+        Box<
+            dyn Fn(
+                    GetSizeConstraintSetRequest,
+                ) -> ::std::result::Result<
+                    GetSizeConstraintSetResponse,
+                    RusotoError<GetSizeConstraintSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_sql_injection_match_set: Option<
+        Box<
+            dyn Fn(
+                    GetSqlInjectionMatchSetRequest,
+                ) -> ::std::result::Result<
+                    GetSqlInjectionMatchSetResponse,
+                    RusotoError<GetSqlInjectionMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_web_acl: Option<
+        Box<
+            dyn Fn(
+                    GetWebACLRequest,
+                )
+                    -> ::std::result::Result<GetWebACLResponse, RusotoError<GetWebACLError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_xss_match_set: Option<
+        Box<
+            dyn Fn(
+                    GetXssMatchSetRequest,
+                ) -> ::std::result::Result<
+                    GetXssMatchSetResponse,
+                    RusotoError<GetXssMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_activated_rules_in_rule_group: Option<
+        Box<
+            dyn Fn(
+                    ListActivatedRulesInRuleGroupRequest,
+                ) -> ::std::result::Result<
+                    ListActivatedRulesInRuleGroupResponse,
+                    RusotoError<ListActivatedRulesInRuleGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_byte_match_sets: Option<
+        Box<
+            dyn Fn(
+                    ListByteMatchSetsRequest,
+                ) -> ::std::result::Result<
+                    ListByteMatchSetsResponse,
+                    RusotoError<ListByteMatchSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_geo_match_sets: Option<
+        Box<
+            dyn Fn(
+                    ListGeoMatchSetsRequest,
+                ) -> ::std::result::Result<
+                    ListGeoMatchSetsResponse,
+                    RusotoError<ListGeoMatchSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_ip_sets: Option<
+        Box<
+            dyn Fn(
+                    ListIPSetsRequest,
+                )
+                    -> ::std::result::Result<ListIPSetsResponse, RusotoError<ListIPSetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_logging_configurations: Option<
+        Box<
+            dyn Fn(
+                    ListLoggingConfigurationsRequest,
+                ) -> ::std::result::Result<
+                    ListLoggingConfigurationsResponse,
+                    RusotoError<ListLoggingConfigurationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_rate_based_rules: Option<
+        Box<
+            dyn Fn(
+                    ListRateBasedRulesRequest,
+                ) -> ::std::result::Result<
+                    ListRateBasedRulesResponse,
+                    RusotoError<ListRateBasedRulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_regex_match_sets: Option<
+        Box<
+            dyn Fn(
+                    ListRegexMatchSetsRequest,
+                ) -> ::std::result::Result<
+                    ListRegexMatchSetsResponse,
+                    RusotoError<ListRegexMatchSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_regex_pattern_sets: Option<
+        Box<
+            dyn Fn(
+                    ListRegexPatternSetsRequest,
+                ) -> ::std::result::Result<
+                    ListRegexPatternSetsResponse,
+                    RusotoError<ListRegexPatternSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_rule_groups: Option<
+        Box<
+            dyn Fn(
+                    ListRuleGroupsRequest,
+                ) -> ::std::result::Result<
+                    ListRuleGroupsResponse,
+                    RusotoError<ListRuleGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_rules: Option<
+        Box<
+            dyn Fn(
+                    ListRulesRequest,
+                )
+                    -> ::std::result::Result<ListRulesResponse, RusotoError<ListRulesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_size_constraint_sets: Option<
+        Box<
+            dyn Fn(
+                    ListSizeConstraintSetsRequest,
+                ) -> ::std::result::Result<
+                    ListSizeConstraintSetsResponse,
+                    RusotoError<ListSizeConstraintSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_sql_injection_match_sets: Option<
+        Box<
+            dyn Fn(
+                    ListSqlInjectionMatchSetsRequest,
+                ) -> ::std::result::Result<
+                    ListSqlInjectionMatchSetsResponse,
+                    RusotoError<ListSqlInjectionMatchSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_subscribed_rule_groups: Option<
+        Box<
+            dyn Fn(
+                    ListSubscribedRuleGroupsRequest,
+                ) -> ::std::result::Result<
+                    ListSubscribedRuleGroupsResponse,
+                    RusotoError<ListSubscribedRuleGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_tags_for_resource: Option<
+        Box<
+            dyn Fn(
+                    ListTagsForResourceRequest,
+                ) -> ::std::result::Result<
+                    ListTagsForResourceResponse,
+                    RusotoError<ListTagsForResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_web_ac_ls: Option<
+        Box<
+            dyn Fn(
+                    ListWebACLsRequest,
+                )
+                    -> ::std::result::Result<ListWebACLsResponse, RusotoError<ListWebACLsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_xss_match_sets: Option<
+        Box<
+            dyn Fn(
+                    ListXssMatchSetsRequest,
+                ) -> ::std::result::Result<
+                    ListXssMatchSetsResponse,
+                    RusotoError<ListXssMatchSetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_logging_configuration: Option<
+        Box<
+            dyn Fn(
+                    PutLoggingConfigurationRequest,
+                ) -> ::std::result::Result<
+                    PutLoggingConfigurationResponse,
+                    RusotoError<PutLoggingConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_permission_policy: Option<
+        Box<
+            dyn Fn(
+                    PutPermissionPolicyRequest,
+                ) -> ::std::result::Result<
+                    PutPermissionPolicyResponse,
+                    RusotoError<PutPermissionPolicyError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    tag_resource: Option<
+        Box<
+            dyn Fn(
+                    TagResourceRequest,
+                )
+                    -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    untag_resource: Option<
+        Box<
+            dyn Fn(
+                    UntagResourceRequest,
+                )
+                    -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_byte_match_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateByteMatchSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateByteMatchSetResponse,
+                    RusotoError<UpdateByteMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_geo_match_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateGeoMatchSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateGeoMatchSetResponse,
+                    RusotoError<UpdateGeoMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_ip_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateIPSetRequest,
+                )
+                    -> ::std::result::Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_rate_based_rule: Option<
+        Box<
+            dyn Fn(
+                    UpdateRateBasedRuleRequest,
+                ) -> ::std::result::Result<
+                    UpdateRateBasedRuleResponse,
+                    RusotoError<UpdateRateBasedRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_regex_match_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateRegexMatchSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateRegexMatchSetResponse,
+                    RusotoError<UpdateRegexMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_regex_pattern_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateRegexPatternSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateRegexPatternSetResponse,
+                    RusotoError<UpdateRegexPatternSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_rule: Option<
+        Box<
+            dyn Fn(
+                    UpdateRuleRequest,
+                )
+                    -> ::std::result::Result<UpdateRuleResponse, RusotoError<UpdateRuleError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_rule_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateRuleGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateRuleGroupResponse,
+                    RusotoError<UpdateRuleGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_size_constraint_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateSizeConstraintSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateSizeConstraintSetResponse,
+                    RusotoError<UpdateSizeConstraintSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_sql_injection_match_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateSqlInjectionMatchSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateSqlInjectionMatchSetResponse,
+                    RusotoError<UpdateSqlInjectionMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_web_acl: Option<
+        Box<
+            dyn Fn(
+                    UpdateWebACLRequest,
+                )
+                    -> ::std::result::Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_xss_match_set: Option<
+        Box<
+            dyn Fn(
+                    UpdateXssMatchSetRequest,
+                ) -> ::std::result::Result<
+                    UpdateXssMatchSetResponse,
+                    RusotoError<UpdateXssMatchSetError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockWaf {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockWaf {
+        Default::default()
+    }
+
+    /// Answers `create_byte_match_set` with what `handler` returns for its input.
+    pub fn with_create_byte_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateByteMatchSetRequest,
+            ) -> ::std::result::Result<
+                CreateByteMatchSetResponse,
+                RusotoError<CreateByteMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_byte_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_geo_match_set` with what `handler` returns for its input.
+    pub fn with_create_geo_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateGeoMatchSetRequest,
+            ) -> ::std::result::Result<
+                CreateGeoMatchSetResponse,
+                RusotoError<CreateGeoMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_geo_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_ip_set` with what `handler` returns for its input.
+    pub fn with_create_ip_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateIPSetRequest,
+            )
+                -> ::std::result::Result<CreateIPSetResponse, RusotoError<CreateIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_rate_based_rule` with what `handler` returns for its input.
+    pub fn with_create_rate_based_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateRateBasedRuleRequest,
+            ) -> ::std::result::Result<
+                CreateRateBasedRuleResponse,
+                RusotoError<CreateRateBasedRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_rate_based_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_regex_match_set` with what `handler` returns for its input.
+    pub fn with_create_regex_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateRegexMatchSetRequest,
+            ) -> ::std::result::Result<
+                CreateRegexMatchSetResponse,
+                RusotoError<CreateRegexMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_regex_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_regex_pattern_set` with what `handler` returns for its input.
+    pub fn with_create_regex_pattern_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateRegexPatternSetRequest,
+            ) -> ::std::result::Result<
+                CreateRegexPatternSetResponse,
+                RusotoError<CreateRegexPatternSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_regex_pattern_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_rule` with what `handler` returns for its input.
+    pub fn with_create_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateRuleRequest,
+            )
+                -> ::std::result::Result<CreateRuleResponse, RusotoError<CreateRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_rule_group` with what `handler` returns for its input.
+    pub fn with_create_rule_group<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateRuleGroupRequest,
+            )
+                -> ::std::result::Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_rule_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_size_constraint_set` with what `handler` returns for its input.
+    pub fn with_create_size_constraint_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateSizeConstraintSetRequest,
+            ) -> ::std::result::Result<
+                CreateSizeConstraintSetResponse,
+                RusotoError<CreateSizeConstraintSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_size_constraint_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_sql_injection_match_set` with what `handler` returns for its input.
+    pub fn with_create_sql_injection_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateSqlInjectionMatchSetRequest,
+            ) -> ::std::result::Result<
+                CreateSqlInjectionMatchSetResponse,
+                RusotoError<CreateSqlInjectionMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_sql_injection_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_web_acl` with what `handler` returns for its input.
+    pub fn with_create_web_acl<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateWebACLRequest,
+            )
+                -> ::std::result::Result<CreateWebACLResponse, RusotoError<CreateWebACLError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_web_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_xss_match_set` with what `handler` returns for its input.
+    pub fn with_create_xss_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                CreateXssMatchSetRequest,
+            ) -> ::std::result::Result<
+                CreateXssMatchSetResponse,
+                RusotoError<CreateXssMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_xss_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_byte_match_set` with what `handler` returns for its input.
+    pub fn with_delete_byte_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteByteMatchSetRequest,
+            ) -> ::std::result::Result<
+                DeleteByteMatchSetResponse,
+                RusotoError<DeleteByteMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_byte_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_geo_match_set` with what `handler` returns for its input.
+    pub fn with_delete_geo_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteGeoMatchSetRequest,
+            ) -> ::std::result::Result<
+                DeleteGeoMatchSetResponse,
+                RusotoError<DeleteGeoMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_geo_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ip_set` with what `handler` returns for its input.
+    pub fn with_delete_ip_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteIPSetRequest,
+            )
+                -> ::std::result::Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_logging_configuration` with what `handler` returns for its input.
+    pub fn with_delete_logging_configuration<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteLoggingConfigurationRequest,
+            ) -> ::std::result::Result<
+                DeleteLoggingConfigurationResponse,
+                RusotoError<DeleteLoggingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_logging_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_permission_policy` with what `handler` returns for its input.
+    pub fn with_delete_permission_policy<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeletePermissionPolicyRequest,
+            ) -> ::std::result::Result<
+                DeletePermissionPolicyResponse,
+                RusotoError<DeletePermissionPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_permission_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_rate_based_rule` with what `handler` returns for its input.
+    pub fn with_delete_rate_based_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteRateBasedRuleRequest,
+            ) -> ::std::result::Result<
+                DeleteRateBasedRuleResponse,
+                RusotoError<DeleteRateBasedRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_rate_based_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_regex_match_set` with what `handler` returns for its input.
+    pub fn with_delete_regex_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteRegexMatchSetRequest,
+            ) -> ::std::result::Result<
+                DeleteRegexMatchSetResponse,
+                RusotoError<DeleteRegexMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_regex_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_regex_pattern_set` with what `handler` returns for its input.
+    pub fn with_delete_regex_pattern_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteRegexPatternSetRequest,
+            ) -> ::std::result::Result<
+                DeleteRegexPatternSetResponse,
+                RusotoError<DeleteRegexPatternSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_regex_pattern_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_rule` with what `handler` returns for its input.
+    pub fn with_delete_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteRuleRequest,
+            )
+                -> ::std::result::Result<DeleteRuleResponse, RusotoError<DeleteRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_rule_group` with what `handler` returns for its input.
+    pub fn with_delete_rule_group<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteRuleGroupRequest,
+            )
+                -> ::std::result::Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_rule_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_size_constraint_set` with what `handler` returns for its input.
+    pub fn with_delete_size_constraint_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteSizeConstraintSetRequest,
+            ) -> ::std::result::Result<
+                DeleteSizeConstraintSetResponse,
+                RusotoError<DeleteSizeConstraintSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_size_constraint_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_sql_injection_match_set` with what `handler` returns for its input.
+    pub fn with_delete_sql_injection_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteSqlInjectionMatchSetRequest,
+            ) -> ::std::result::Result<
+                DeleteSqlInjectionMatchSetResponse,
+                RusotoError<DeleteSqlInjectionMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_sql_injection_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_web_acl` with what `handler` returns for its input.
+    pub fn with_delete_web_acl<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteWebACLRequest,
+            )
+                -> ::std::result::Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_web_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_xss_match_set` with what `handler` returns for its input.
+    pub fn with_delete_xss_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                DeleteXssMatchSetRequest,
+            ) -> ::std::result::Result<
+                DeleteXssMatchSetResponse,
+                RusotoError<DeleteXssMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_xss_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_byte_match_set` with what `handler` returns for its input.
+    pub fn with_get_byte_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetByteMatchSetRequest,
+            )
+                -> ::std::result::Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_byte_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_change_token` with what `handler` returns.
+    pub fn with_get_change_token<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn() -> ::std::result::Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_change_token = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_change_token_status` with what `handler` returns for its input.
+    pub fn with_get_change_token_status<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetChangeTokenStatusRequest,
+            ) -> ::std::result::Result<
+                GetChangeTokenStatusResponse,
+                RusotoError<GetChangeTokenStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_change_token_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_geo_match_set` with what `handler` returns for its input.
+    pub fn with_get_geo_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetGeoMatchSetRequest,
+            )
+                -> ::std::result::Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_geo_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_ip_set` with what `handler` returns for its input.
+    pub fn with_get_ip_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetIPSetRequest,
+            ) -> ::std::result::Result<GetIPSetResponse, RusotoError<GetIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_logging_configuration` with what `handler` returns for its input.
+    pub fn with_get_logging_configuration<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetLoggingConfigurationRequest,
+            ) -> ::std::result::Result<
+                GetLoggingConfigurationResponse,
+                RusotoError<GetLoggingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_logging_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_permission_policy` with what `handler` returns for its input.
+    pub fn with_get_permission_policy<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetPermissionPolicyRequest,
+            ) -> ::std::result::Result<
+                GetPermissionPolicyResponse,
+                RusotoError<GetPermissionPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_permission_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rate_based_rule` with what `handler` returns for its input.
+    pub fn with_get_rate_based_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetRateBasedRuleRequest,
+            ) -> ::std::result::Result<
+                GetRateBasedRuleResponse,
+                RusotoError<GetRateBasedRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rate_based_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rate_based_rule_managed_keys` with what `handler` returns for its input.
+    pub fn with_get_rate_based_rule_managed_keys<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetRateBasedRuleManagedKeysRequest,
+            ) -> ::std::result::Result<
+                GetRateBasedRuleManagedKeysResponse,
+                RusotoError<GetRateBasedRuleManagedKeysError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rate_based_rule_managed_keys = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_regex_match_set` with what `handler` returns for its input.
+    pub fn with_get_regex_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetRegexMatchSetRequest,
+            ) -> ::std::result::Result<
+                GetRegexMatchSetResponse,
+                RusotoError<GetRegexMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_regex_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_regex_pattern_set` with what `handler` returns for its input.
+    pub fn with_get_regex_pattern_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetRegexPatternSetRequest,
+            ) -> ::std::result::Result<
+                GetRegexPatternSetResponse,
+                RusotoError<GetRegexPatternSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_regex_pattern_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rule` with what `handler` returns for its input.
+    pub fn with_get_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(GetRuleRequest) -> ::std::result::Result<GetRuleResponse, RusotoError<GetRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_rule_group` with what `handler` returns for its input.
+    pub fn with_get_rule_group<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetRuleGroupRequest,
+            )
+                -> ::std::result::Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_rule_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sampled_requests` with what `handler` returns for its input.
+    pub fn with_get_sampled_requests<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetSampledRequestsRequest,
+            ) -> ::std::result::Result<
+                GetSampledRequestsResponse,
+                RusotoError<GetSampledRequestsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sampled_requests = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_size_constraint_set` with what `handler` returns for its input.
+    pub fn with_get_size_constraint_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetSizeConstraintSetRequest,
+            ) -> ::std::result::Result<
+                GetSizeConstraintSetResponse,
+                RusotoError<GetSizeConstraintSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_size_constraint_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sql_injection_match_set` with what `handler` returns for its input.
+    pub fn with_get_sql_injection_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetSqlInjectionMatchSetRequest,
+            ) -> ::std::result::Result<
+                GetSqlInjectionMatchSetResponse,
+                RusotoError<GetSqlInjectionMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sql_injection_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_web_acl` with what `handler` returns for its input.
+    pub fn with_get_web_acl<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetWebACLRequest,
+            ) -> ::std::result::Result<GetWebACLResponse, RusotoError<GetWebACLError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_web_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_xss_match_set` with what `handler` returns for its input.
+    pub fn with_get_xss_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                GetXssMatchSetRequest,
+            )
+                -> ::std::result::Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_xss_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_activated_rules_in_rule_group` with what `handler` returns for its input.
+    pub fn with_list_activated_rules_in_rule_group<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListActivatedRulesInRuleGroupRequest,
+            ) -> ::std::result::Result<
+                ListActivatedRulesInRuleGroupResponse,
+                RusotoError<ListActivatedRulesInRuleGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_activated_rules_in_rule_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_byte_match_sets` with what `handler` returns for its input.
+    pub fn with_list_byte_match_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListByteMatchSetsRequest,
+            ) -> ::std::result::Result<
+                ListByteMatchSetsResponse,
+                RusotoError<ListByteMatchSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_byte_match_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_geo_match_sets` with what `handler` returns for its input.
+    pub fn with_list_geo_match_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListGeoMatchSetsRequest,
+            ) -> ::std::result::Result<
+                ListGeoMatchSetsResponse,
+                RusotoError<ListGeoMatchSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_geo_match_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_ip_sets` with what `handler` returns for its input.
+    pub fn with_list_ip_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListIPSetsRequest,
+            )
+                -> ::std::result::Result<ListIPSetsResponse, RusotoError<ListIPSetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_ip_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_logging_configurations` with what `handler` returns for its input.
+    pub fn with_list_logging_configurations<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListLoggingConfigurationsRequest,
+            ) -> ::std::result::Result<
+                ListLoggingConfigurationsResponse,
+                RusotoError<ListLoggingConfigurationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_logging_configurations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_rate_based_rules` with what `handler` returns for its input.
+    pub fn with_list_rate_based_rules<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListRateBasedRulesRequest,
+            ) -> ::std::result::Result<
+                ListRateBasedRulesResponse,
+                RusotoError<ListRateBasedRulesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_rate_based_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_regex_match_sets` with what `handler` returns for its input.
+    pub fn with_list_regex_match_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListRegexMatchSetsRequest,
+            ) -> ::std::result::Result<
+                ListRegexMatchSetsResponse,
+                RusotoError<ListRegexMatchSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_regex_match_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_regex_pattern_sets` with what `handler` returns for its input.
+    pub fn with_list_regex_pattern_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListRegexPatternSetsRequest,
+            ) -> ::std::result::Result<
+                ListRegexPatternSetsResponse,
+                RusotoError<ListRegexPatternSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_regex_pattern_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_rule_groups` with what `handler` returns for its input.
+    pub fn with_list_rule_groups<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListRuleGroupsRequest,
+            )
+                -> ::std::result::Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_rule_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_rules` with what `handler` returns for its input.
+    pub fn with_list_rules<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListRulesRequest,
+            ) -> ::std::result::Result<ListRulesResponse, RusotoError<ListRulesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_size_constraint_sets` with what `handler` returns for its input.
+    pub fn with_list_size_constraint_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListSizeConstraintSetsRequest,
+            ) -> ::std::result::Result<
+                ListSizeConstraintSetsResponse,
+                RusotoError<ListSizeConstraintSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_size_constraint_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_sql_injection_match_sets` with what `handler` returns for its input.
+    pub fn with_list_sql_injection_match_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListSqlInjectionMatchSetsRequest,
+            ) -> ::std::result::Result<
+                ListSqlInjectionMatchSetsResponse,
+                RusotoError<ListSqlInjectionMatchSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_sql_injection_match_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_subscribed_rule_groups` with what `handler` returns for its input.
+    pub fn with_list_subscribed_rule_groups<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListSubscribedRuleGroupsRequest,
+            ) -> ::std::result::Result<
+                ListSubscribedRuleGroupsResponse,
+                RusotoError<ListSubscribedRuleGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_subscribed_rule_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_tags_for_resource` with what `handler` returns for its input.
+    pub fn with_list_tags_for_resource<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListTagsForResourceRequest,
+            ) -> ::std::result::Result<
+                ListTagsForResourceResponse,
+                RusotoError<ListTagsForResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_tags_for_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_web_ac_ls` with what `handler` returns for its input.
+    pub fn with_list_web_ac_ls<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListWebACLsRequest,
+            )
+                -> ::std::result::Result<ListWebACLsResponse, RusotoError<ListWebACLsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_web_ac_ls = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_xss_match_sets` with what `handler` returns for its input.
+    pub fn with_list_xss_match_sets<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                ListXssMatchSetsRequest,
+            ) -> ::std::result::Result<
+                ListXssMatchSetsResponse,
+                RusotoError<ListXssMatchSetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_xss_match_sets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_logging_configuration` with what `handler` returns for its input.
+    pub fn with_put_logging_configuration<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                PutLoggingConfigurationRequest,
+            ) -> ::std::result::Result<
+                PutLoggingConfigurationResponse,
+                RusotoError<PutLoggingConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_logging_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_permission_policy` with what `handler` returns for its input.
+    pub fn with_put_permission_policy<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                PutPermissionPolicyRequest,
+            ) -> ::std::result::Result<
+                PutPermissionPolicyResponse,
+                RusotoError<PutPermissionPolicyError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_permission_policy = Some(Box::new(handler));
+        self
+    }
+    /// Answers `tag_resource` with what `handler` returns for its input.
+    pub fn with_tag_resource<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                TagResourceRequest,
+            )
+                -> ::std::result::Result<TagResourceResponse, RusotoError<TagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.tag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `untag_resource` with what `handler` returns for its input.
+    pub fn with_untag_resource<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UntagResourceRequest,
+            )
+                -> ::std::result::Result<UntagResourceResponse, RusotoError<UntagResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.untag_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_byte_match_set` with what `handler` returns for its input.
+    pub fn with_update_byte_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateByteMatchSetRequest,
+            ) -> ::std::result::Result<
+                UpdateByteMatchSetResponse,
+                RusotoError<UpdateByteMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_byte_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_geo_match_set` with what `handler` returns for its input.
+    pub fn with_update_geo_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateGeoMatchSetRequest,
+            ) -> ::std::result::Result<
+                UpdateGeoMatchSetResponse,
+                RusotoError<UpdateGeoMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_geo_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_ip_set` with what `handler` returns for its input.
+    pub fn with_update_ip_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateIPSetRequest,
+            )
+                -> ::std::result::Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_ip_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_rate_based_rule` with what `handler` returns for its input.
+    pub fn with_update_rate_based_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateRateBasedRuleRequest,
+            ) -> ::std::result::Result<
+                UpdateRateBasedRuleResponse,
+                RusotoError<UpdateRateBasedRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_rate_based_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_regex_match_set` with what `handler` returns for its input.
+    pub fn with_update_regex_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateRegexMatchSetRequest,
+            ) -> ::std::result::Result<
+                UpdateRegexMatchSetResponse,
+                RusotoError<UpdateRegexMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_regex_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_regex_pattern_set` with what `handler` returns for its input.
+    pub fn with_update_regex_pattern_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateRegexPatternSetRequest,
+            ) -> ::std::result::Result<
+                UpdateRegexPatternSetResponse,
+                RusotoError<UpdateRegexPatternSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_regex_pattern_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_rule` with what `handler` returns for its input.
+    pub fn with_update_rule<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateRuleRequest,
+            )
+                -> ::std::result::Result<UpdateRuleResponse, RusotoError<UpdateRuleError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_rule_group` with what `handler` returns for its input.
+    pub fn with_update_rule_group<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateRuleGroupRequest,
+            )
+                -> ::std::result::Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_rule_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_size_constraint_set` with what `handler` returns for its input.
+    pub fn with_update_size_constraint_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateSizeConstraintSetRequest,
+            ) -> ::std::result::Result<
+                UpdateSizeConstraintSetResponse,
+                RusotoError<UpdateSizeConstraintSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_size_constraint_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_sql_injection_match_set` with what `handler` returns for its input.
+    pub fn with_update_sql_injection_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateSqlInjectionMatchSetRequest,
+            ) -> ::std::result::Result<
+                UpdateSqlInjectionMatchSetResponse,
+                RusotoError<UpdateSqlInjectionMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_sql_injection_match_set = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_web_acl` with what `handler` returns for its input.
+    pub fn with_update_web_acl<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateWebACLRequest,
+            )
+                -> ::std::result::Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_web_acl = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_xss_match_set` with what `handler` returns for its input.
+    pub fn with_update_xss_match_set<F>(mut self, handler: F) -> MockWaf
+    where
+        F: Fn(
+                UpdateXssMatchSetRequest,
+            ) -> ::std::result::Result<
+                UpdateXssMatchSetResponse,
+                RusotoError<UpdateXssMatchSetError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_xss_match_set = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Waf for MockWaf {
+    fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> RusotoFuture<CreateByteMatchSetResponse, CreateByteMatchSetError> {
+        match self.create_byte_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_byte_match_set"),
+        }
+    }
+    fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> RusotoFuture<CreateGeoMatchSetResponse, CreateGeoMatchSetError> {
+        match self.create_geo_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_geo_match_set"),
+        }
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        match self.create_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_ip_set"),
+        }
+    }
+    fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> RusotoFuture<CreateRateBasedRuleResponse, CreateRateBasedRuleError> {
+        match self.create_rate_based_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_rate_based_rule"),
+        }
+    }
+    fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> RusotoFuture<CreateRegexMatchSetResponse, CreateRegexMatchSetError> {
+        match self.create_regex_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_regex_match_set"),
+        }
+    }
+    fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> RusotoFuture<CreateRegexPatternSetResponse, CreateRegexPatternSetError> {
+        match self.create_regex_pattern_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_regex_pattern_set"),
+        }
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> RusotoFuture<CreateRuleResponse, CreateRuleError> {
+        match self.create_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_rule"),
+        }
+    }
+    fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> RusotoFuture<CreateRuleGroupResponse, CreateRuleGroupError> {
+        match self.create_rule_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_rule_group"),
+        }
+    }
+    fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> RusotoFuture<CreateSizeConstraintSetResponse, CreateSizeConstraintSetError> {
+        match self.create_size_constraint_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_size_constraint_set"),
+        }
+    }
+    fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<CreateSqlInjectionMatchSetResponse, CreateSqlInjectionMatchSetError> {
+        match self.create_sql_injection_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_sql_injection_match_set"),
+        }
+    }
+    fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> RusotoFuture<CreateWebACLResponse, CreateWebACLError> {
+        match self.create_web_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_web_acl"),
+        }
+    }
+    fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> RusotoFuture<CreateXssMatchSetResponse, CreateXssMatchSetError> {
+        match self.create_xss_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for create_xss_match_set"),
+        }
+    }
+    fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> RusotoFuture<DeleteByteMatchSetResponse, DeleteByteMatchSetError> {
+        match self.delete_byte_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_byte_match_set"),
+        }
+    }
+    fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> RusotoFuture<DeleteGeoMatchSetResponse, DeleteGeoMatchSetError> {
+        match self.delete_geo_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_geo_match_set"),
+        }
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        match self.delete_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_ip_set"),
+        }
+    }
+    fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> RusotoFuture<DeleteLoggingConfigurationResponse, DeleteLoggingConfigurationError> {
+        match self.delete_logging_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_logging_configuration"),
+        }
+    }
+    fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> RusotoFuture<DeletePermissionPolicyResponse, DeletePermissionPolicyError> {
+        match self.delete_permission_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_permission_policy"),
+        }
+    }
+    fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> RusotoFuture<DeleteRateBasedRuleResponse, DeleteRateBasedRuleError> {
+        match self.delete_rate_based_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_rate_based_rule"),
+        }
+    }
+    fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> RusotoFuture<DeleteRegexMatchSetResponse, DeleteRegexMatchSetError> {
+        match self.delete_regex_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_regex_match_set"),
+        }
+    }
+    fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> RusotoFuture<DeleteRegexPatternSetResponse, DeleteRegexPatternSetError> {
+        match self.delete_regex_pattern_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_regex_pattern_set"),
+        }
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> RusotoFuture<DeleteRuleResponse, DeleteRuleError> {
+        match self.delete_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_rule"),
+        }
+    }
+    fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> RusotoFuture<DeleteRuleGroupResponse, DeleteRuleGroupError> {
+        match self.delete_rule_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_rule_group"),
+        }
+    }
+    fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> RusotoFuture<DeleteSizeConstraintSetResponse, DeleteSizeConstraintSetError> {
+        match self.delete_size_constraint_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_size_constraint_set"),
+        }
+    }
+    fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<DeleteSqlInjectionMatchSetResponse, DeleteSqlInjectionMatchSetError> {
+        match self.delete_sql_injection_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_sql_injection_match_set"),
+        }
+    }
+    fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> RusotoFuture<DeleteWebACLResponse, DeleteWebACLError> {
+        match self.delete_web_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_web_acl"),
+        }
+    }
+    fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> RusotoFuture<DeleteXssMatchSetResponse, DeleteXssMatchSetError> {
+        match self.delete_xss_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for delete_xss_match_set"),
+        }
+    }
+    fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> RusotoFuture<GetByteMatchSetResponse, GetByteMatchSetError> {
+        match self.get_byte_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_byte_match_set"),
+        }
+    }
+    fn get_change_token(&self) -> RusotoFuture<GetChangeTokenResponse, GetChangeTokenError> {
+        match self.get_change_token {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockWaf has no handler for get_change_token"),
+        }
+    }
+    fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> RusotoFuture<GetChangeTokenStatusResponse, GetChangeTokenStatusError> {
+        match self.get_change_token_status {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_change_token_status"),
+        }
+    }
+    fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> RusotoFuture<GetGeoMatchSetResponse, GetGeoMatchSetError> {
+        match self.get_geo_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_geo_match_set"),
+        }
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        match self.get_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_ip_set"),
+        }
+    }
+    fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> RusotoFuture<GetLoggingConfigurationResponse, GetLoggingConfigurationError> {
+        match self.get_logging_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_logging_configuration"),
+        }
+    }
+    fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> RusotoFuture<GetPermissionPolicyResponse, GetPermissionPolicyError> {
+        match self.get_permission_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_permission_policy"),
+        }
+    }
+    fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> RusotoFuture<GetRateBasedRuleResponse, GetRateBasedRuleError> {
+        match self.get_rate_based_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_rate_based_rule"),
+        }
+    }
+    fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> RusotoFuture<GetRateBasedRuleManagedKeysResponse, GetRateBasedRuleManagedKeysError> {
+        match self.get_rate_based_rule_managed_keys {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_rate_based_rule_managed_keys"),
+        }
+    }
+    fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> RusotoFuture<GetRegexMatchSetResponse, GetRegexMatchSetError> {
+        match self.get_regex_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_regex_match_set"),
+        }
+    }
+    fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> RusotoFuture<GetRegexPatternSetResponse, GetRegexPatternSetError> {
+        match self.get_regex_pattern_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_regex_pattern_set"),
+        }
+    }
+    fn get_rule(&self, input: GetRuleRequest) -> RusotoFuture<GetRuleResponse, GetRuleError> {
+        match self.get_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_rule"),
+        }
+    }
+    fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> RusotoFuture<GetRuleGroupResponse, GetRuleGroupError> {
+        match self.get_rule_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_rule_group"),
+        }
+    }
+    fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> RusotoFuture<GetSampledRequestsResponse, GetSampledRequestsError> {
+        match self.get_sampled_requests {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_sampled_requests"),
+        }
+    }
+    fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> RusotoFuture<GetSizeConstraintSetResponse, GetSizeConstraintSetError> {
+        match self.get_size_constraint_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_size_constraint_set"),
+        }
+    }
+    fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> RusotoFuture<GetSqlInjectionMatchSetResponse, GetSqlInjectionMatchSetError> {
+        match self.get_sql_injection_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_sql_injection_match_set"),
+        }
+    }
+    fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> RusotoFuture<GetWebACLResponse, GetWebACLError> {
+        match self.get_web_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_web_acl"),
+        }
+    }
+    fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> RusotoFuture<GetXssMatchSetResponse, GetXssMatchSetError> {
+        match self.get_xss_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for get_xss_match_set"),
+        }
+    }
+    fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> RusotoFuture<ListActivatedRulesInRuleGroupResponse, ListActivatedRulesInRuleGroupError>
+    {
+        match self.list_activated_rules_in_rule_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_activated_rules_in_rule_group"),
+        }
+    }
+    fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> RusotoFuture<ListByteMatchSetsResponse, ListByteMatchSetsError> {
+        match self.list_byte_match_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_byte_match_sets"),
+        }
+    }
+    fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> RusotoFuture<ListGeoMatchSetsResponse, ListGeoMatchSetsError> {
+        match self.list_geo_match_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_geo_match_sets"),
+        }
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        match self.list_ip_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_ip_sets"),
+        }
+    }
+    fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> RusotoFuture<ListLoggingConfigurationsResponse, ListLoggingConfigurationsError> {
+        match self.list_logging_configurations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_logging_configurations"),
+        }
+    }
+    fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> RusotoFuture<ListRateBasedRulesResponse, ListRateBasedRulesError> {
+        match self.list_rate_based_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_rate_based_rules"),
+        }
+    }
+    fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> RusotoFuture<ListRegexMatchSetsResponse, ListRegexMatchSetsError> {
+        match self.list_regex_match_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_regex_match_sets"),
+        }
+    }
+    fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> RusotoFuture<ListRegexPatternSetsResponse, ListRegexPatternSetsError> {
+        match self.list_regex_pattern_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_regex_pattern_sets"),
+        }
+    }
+    fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> RusotoFuture<ListRuleGroupsResponse, ListRuleGroupsError> {
+        match self.list_rule_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_rule_groups"),
+        }
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        match self.list_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_rules"),
+        }
+    }
+    fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> RusotoFuture<ListSizeConstraintSetsResponse, ListSizeConstraintSetsError> {
+        match self.list_size_constraint_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_size_constraint_sets"),
+        }
+    }
+    fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> RusotoFuture<ListSqlInjectionMatchSetsResponse, ListSqlInjectionMatchSetsError> {
+        match self.list_sql_injection_match_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_sql_injection_match_sets"),
+        }
+    }
+    fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> RusotoFuture<ListSubscribedRuleGroupsResponse, ListSubscribedRuleGroupsError> {
+        match self.list_subscribed_rule_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_subscribed_rule_groups"),
+        }
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        match self.list_tags_for_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_tags_for_resource"),
+        }
+    }
+    fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> RusotoFuture<ListWebACLsResponse, ListWebACLsError> {
+        match self.list_web_ac_ls {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_web_ac_ls"),
+        }
+    }
+    fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> RusotoFuture<ListXssMatchSetsResponse, ListXssMatchSetsError> {
+        match self.list_xss_match_sets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for list_xss_match_sets"),
+        }
+    }
+    fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> RusotoFuture<PutLoggingConfigurationResponse, PutLoggingConfigurationError> {
+        match self.put_logging_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for put_logging_configuration"),
+        }
+    }
+    fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> RusotoFuture<PutPermissionPolicyResponse, PutPermissionPolicyError> {
+        match self.put_permission_policy {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for put_permission_policy"),
+        }
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        match self.tag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for tag_resource"),
+        }
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        match self.untag_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for untag_resource"),
+        }
+    }
+    fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> RusotoFuture<UpdateByteMatchSetResponse, UpdateByteMatchSetError> {
+        match self.update_byte_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_byte_match_set"),
+        }
+    }
     fn update_geo_match_set(
         &self,
         input: UpdateGeoMatchSetRequest,
     ) -> RusotoFuture<UpdateGeoMatchSetResponse, UpdateGeoMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateGeoMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGeoMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGeoMatchSetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_geo_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_geo_match_set"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>IPSetDescriptor</a> objects in an <code>IPSet</code>. For each <code>IPSetDescriptor</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>IPSetDescriptor</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The IP address version, <code>IPv4</code> or <code>IPv6</code>. </p> </li> <li> <p>The IP address in CIDR notation, for example, <code>192.0.2.0/24</code> (for the range of IP addresses from <code>192.0.2.0</code> to <code>192.0.2.255</code>) or <code>192.0.2.44/32</code> (for the individual IP address <code>192.0.2.44</code>). </p> </li> </ul> <p>AWS WAF supports IPv4 address ranges: /8 and any range between /16 through /32. AWS WAF supports IPv6 address ranges: /24, /32, /48, /56, /64, and /128. For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>IPv6 addresses can be represented using any of the following formats:</p> <ul> <li> <p>1111:0000:0000:0000:0000:0000:0000:0111/128</p> </li> <li> <p>1111:0:0:0:0:0:0:0111/128</p> </li> <li> <p>1111::0111/128</p> </li> <li> <p>1111::111/128</p> </li> </ul> <p>You use an <code>IPSet</code> to specify which web requests you want to allow or block based on the IP addresses that the requests originated from. For example, if you're receiving a lot of requests from one or a small number of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that specifies those IP addresses, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateIPSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>IPSet</code>, you specify the IP addresses that you want to add and/or the IP addresses that you want to delete. If you want to change an IP address, you delete the existing IP address and add the new one.</p> <p>You can insert a maximum of 1000 addresses in a single request.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_ip_set(
         &self,
         input: UpdateIPSetRequest,
     ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateIPSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateIPSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateIPSetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_ip_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_ip_set"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>Predicate</a> objects in a rule and updates the <code>RateLimit</code> in the rule. </p> <p>Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to block or count. The <code>RateLimit</code> specifies the number of requests every five minutes that triggers the rule.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request must match all the predicates and exceed the <code>RateLimit</code> to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions much be received at a rate of more than 15,000 every five minutes. If the rate drops below this limit, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 15,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p>
     fn update_rate_based_rule(
         &self,
         input: UpdateRateBasedRuleRequest,
     ) -> RusotoFuture<UpdateRateBasedRuleResponse, UpdateRateBasedRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRateBasedRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRateBasedRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRateBasedRuleError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_rate_based_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_rate_based_rule"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>RegexMatchTuple</a> objects (filters) in a <a>RegexMatchSet</a>. For each <code>RegexMatchSetUpdate</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>RegexMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspectupdate, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p> For example, you can create a <code>RegexPatternSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexMatchSet.</code> For more information, see <a>CreateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the identifier of the <code>RegexPatternSet</code> that contain the regular expression patters you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_regex_match_set(
         &self,
         input: UpdateRegexMatchSetRequest,
     ) -> RusotoFuture<UpdateRegexMatchSetResponse, UpdateRegexMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRegexMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRegexMatchSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_regex_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_regex_match_set"),
+        }
     }
-
-    /// <p>Inserts or deletes <code>RegexPatternString</code> objects in a <a>RegexPatternSet</a>. For each <code>RegexPatternString</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the <code>RegexPatternString</code>.</p> </li> <li> <p>The regular expression pattern that you want to insert or delete. For more information, see <a>RegexPatternSet</a>. </p> </li> </ul> <p> For example, you can create a <code>RegexPatternString</code> such as <code>B[a@]dB[o0]t</code>. AWS WAF will match this <code>RegexPatternString</code> to:</p> <ul> <li> <p>BadBot</p> </li> <li> <p>BadB0t</p> </li> <li> <p>B@dBot</p> </li> <li> <p>B@dB0t</p> </li> </ul> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexPatternSet.</code> For more information, see <a>CreateRegexPatternSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexPatternSet</code> request to specify the regular expression pattern that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_regex_pattern_set(
         &self,
         input: UpdateRegexPatternSetRequest,
     ) -> RusotoFuture<UpdateRegexPatternSetResponse, UpdateRegexPatternSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexPatternSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRegexPatternSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRegexPatternSetError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_regex_pattern_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_regex_pattern_set"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>Predicate</a> objects in a <code>Rule</code>. Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to allow, block, or count. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed, blocked, or counted. For example, suppose that you add the following to a <code>Rule</code>: </p> <ul> <li> <p>A <code>ByteMatchSet</code> that matches the value <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44</code> </p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the <code>Rule</code>. For a request to be blocked, the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code> <i>and</i> the request must originate from the IP address 192.0.2.44.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create the <code>Rule</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to add predicates to the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>ByteMatchSet</code> or <code>IPSet</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_rule(
         &self,
         input: UpdateRuleRequest,
     ) -> RusotoFuture<UpdateRuleResponse, UpdateRuleError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRule");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRuleResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRuleError::from_response(response))),
-                )
-            }
-        })
+        match self.update_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_rule"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>RuleGroup</code>.</p> <p>You can only insert <code>REGULAR</code> rules into a rule group.</p> <p>You can have a maximum of ten rules per rule group.</p> <p>To create and configure a <code>RuleGroup</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>RuleGroup</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRuleGroup</a> request.</p> </li> <li> <p>Submit an <code>UpdateRuleGroup</code> request to add <code>Rules</code> to the <code>RuleGroup</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RuleGroup</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>Rule</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_rule_group(
         &self,
         input: UpdateRuleGroupRequest,
     ) -> RusotoFuture<UpdateRuleGroupResponse, UpdateRuleGroupError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRuleGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRuleGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateRuleGroupError::from_response(response))),
-                )
-            }
-        })
+        match self.update_rule_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_rule_group"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>SizeConstraint</a> objects (filters) in a <a>SizeConstraintSet</a>. For each <code>SizeConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>SizeConstraintSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to evaluate, such as the length of a query string or the length of the <code>User-Agent</code> header.</p> </li> <li> <p>Whether to perform any transformations on the request, such as converting it to lowercase, before checking its length. Note that transformations of the request body are not supported because the AWS resource forwards only the first <code>8192</code> bytes of your request to AWS WAF.</p> <p>You can only specify a single type of TextTransformation.</p> </li> <li> <p>A <code>ComparisonOperator</code> used for evaluating the selected part of the request against the specified <code>Size</code>, such as equals, greater than, less than, and so on.</p> </li> <li> <p>The length, in bytes, that you want AWS WAF to watch for in selected part of the request. The length is computed after applying the transformation.</p> </li> </ul> <p>For example, you can add a <code>SizeConstraintSetUpdate</code> object that matches web requests in which the length of the <code>User-Agent</code> header is greater than 100 bytes. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>SizeConstraintSet.</code> For more information, see <a>CreateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateSizeConstraintSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_size_constraint_set(
         &self,
         input: UpdateSizeConstraintSetRequest,
     ) -> RusotoFuture<UpdateSizeConstraintSetResponse, UpdateSizeConstraintSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSizeConstraintSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSizeConstraintSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateSizeConstraintSetError::from_response(response))
-                }))
-            }
-        })
+        match self.update_size_constraint_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_size_constraint_set"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>SqlInjectionMatchTuple</a> objects (filters) in a <a>SqlInjectionMatchSet</a>. For each <code>SqlInjectionMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change a <code>SqlInjectionMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for snippets of malicious SQL code.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>SqlInjectionMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain snippets of SQL code in the query string and you want to block the requests, you can create a <code>SqlInjectionMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateSqlInjectionMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for snippets of SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_sql_injection_match_set(
         &self,
         input: UpdateSqlInjectionMatchSetRequest,
     ) -> RusotoFuture<UpdateSqlInjectionMatchSetResponse, UpdateSqlInjectionMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSqlInjectionMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateSqlInjectionMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateSqlInjectionMatchSetError::from_response(response))
-                }))
-            }
-        })
+        match self.update_sql_injection_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_sql_injection_match_set"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>WebACL</code>. Each <code>Rule</code> identifies web requests that you want to allow, block, or count. When you update a <code>WebACL</code>, you specify the following values:</p> <ul> <li> <p>A default action for the <code>WebACL</code>, either <code>ALLOW</code> or <code>BLOCK</code>. AWS WAF performs the default action if a request doesn't match the criteria in any of the <code>Rules</code> in a <code>WebACL</code>.</p> </li> <li> <p>The <code>Rules</code> that you want to add or delete. If you want to replace one <code>Rule</code> with another, you delete the existing <code>Rule</code> and add the new one.</p> </li> <li> <p>For each <code>Rule</code>, whether you want AWS WAF to allow requests, block requests, or count requests that match the conditions in the <code>Rule</code>.</p> </li> <li> <p>The order in which you want AWS WAF to evaluate the <code>Rules</code> in a <code>WebACL</code>. If you add more than one <code>Rule</code> to a <code>WebACL</code>, AWS WAF evaluates each request against the <code>Rules</code> in order based on the value of <code>Priority</code>. (The <code>Rule</code> that has the lowest value for <code>Priority</code> is evaluated first.) When a web request matches all the predicates (such as <code>ByteMatchSets</code> and <code>IPSets</code>) in a <code>Rule</code>, AWS WAF immediately takes the corresponding action, allow or block, and doesn't evaluate the request against the remaining <code>Rules</code> in the <code>WebACL</code>, if any. </p> </li> </ul> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Create a <code>WebACL</code>. See <a>CreateWebACL</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <code>UpdateWebACL</code> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution. </p> <p>The <code>ActivatedRule</code> can be a rule group. If you specify a rule group as your <code>ActivatedRule</code>, you can exclude specific rules from that rule group.</p> <p>If you already have a rule group associated with a web ACL and want to submit an <code>UpdateWebACL</code> request to exclude certain rules from that rule group, you must first remove the rule group from the web ACL, the re-insert it again, specifying the excluded rules. For details, see <a>ActivatedRule$ExcludedRules</a>. </p> </li> </ol> <p>Be aware that if you try to add a RATE_BASED rule to a web ACL without setting the rule type when first creating the rule, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule (the default rule type) with the specified ID, which does not exist. </p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_web_acl(
         &self,
         input: UpdateWebACLRequest,
     ) -> RusotoFuture<UpdateWebACLResponse, UpdateWebACLError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateWebACL");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateWebACLResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateWebACLError::from_response(response))),
-                )
-            }
-        })
+        match self.update_web_acl {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_web_acl"),
+        }
     }
-
-    /// <p>Inserts or deletes <a>XssMatchTuple</a> objects (filters) in an <a>XssMatchSet</a>. For each <code>XssMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change an <code>XssMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for cross-site scripting attacks.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>XssMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you're receiving requests that contain cross-site scripting attacks in the request body and you want to block the requests, you can create an <code>XssMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateXssMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateXssMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p>
     fn update_xss_match_set(
         &self,
         input: UpdateXssMatchSetRequest,
     ) -> RusotoFuture<UpdateXssMatchSetResponse, UpdateXssMatchSetError> {
-        let mut request = SignedRequest::new("POST", "waf", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AWSWAF_20150824.UpdateXssMatchSet");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateXssMatchSetResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateXssMatchSetError::from_response(response))),
-                )
-            }
-        })
+        match self.update_xss_match_set {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWaf has no handler for update_xss_match_set"),
+        }
     }
 }
@@ -23,8 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AbortDocumentVersionUploadRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -38,7 +40,43 @@ pub struct AbortDocumentVersionUploadRequest {
     pub version_id: String,
 }
 
+impl Validate for AbortDocumentVersionUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ActivateUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -49,8 +87,28 @@ pub struct ActivateUserRequest {
     pub user_id: String,
 }
 
+impl Validate for ActivateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, "[&\\w+-.@]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ActivateUserResponse {
     /// <p>The user information.</p>
     #[serde(rename = "User")]
@@ -60,7 +118,7 @@ pub struct ActivateUserResponse {
 
 /// <p>Describes the activity information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Activity {
     /// <p>Metadata of the commenting activity. This is an optional field and is filled for commenting activities.</p>
     #[serde(rename = "CommentMetadata")]
@@ -101,6 +159,7 @@ pub struct Activity {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AddResourcePermissionsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -118,8 +177,39 @@ pub struct AddResourcePermissionsRequest {
     pub resource_id: String,
 }
 
+impl Validate for AddResourcePermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.notification_options {
+            value.validate_at(&field_path(path, "notification_options"), errors);
+        }
+        for (i, item) in self.principals.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "principals"), i);
+            item.validate_at(&item_path, errors);
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AddResourcePermissionsResponse {
     /// <p>The share results.</p>
     #[serde(rename = "ShareResults")]
@@ -129,7 +219,7 @@ pub struct AddResourcePermissionsResponse {
 
 /// <p>Describes a comment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Comment {
     /// <p>The ID of the comment.</p>
     #[serde(rename = "CommentId")]
@@ -170,7 +260,7 @@ pub struct Comment {
 
 /// <p>Describes the metadata of a comment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CommentMetadata {
     /// <p>The ID of the comment.</p>
     #[serde(rename = "CommentId")]
@@ -195,6 +285,7 @@ pub struct CommentMetadata {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCommentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -227,8 +318,67 @@ pub struct CreateCommentRequest {
     pub visibility: Option<String>,
 }
 
+impl Validate for CreateCommentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.parent_id {
+            errors.check_length(
+                &field_path(path, "parent_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "parent_id"), &value, "[\\w+-.@]+");
+        }
+        errors.check_length(
+            &field_path(path, "text"),
+            self.text.chars().count(),
+            Some(1),
+            Some(2048),
+        );
+        if let Some(ref value) = self.thread_id {
+            errors.check_length(
+                &field_path(path, "thread_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "thread_id"), &value, "[\\w+-.@]+");
+        }
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCommentResponse {
     /// <p>The comment that has been created.</p>
     #[serde(rename = "Comment")]
@@ -237,6 +387,7 @@ pub struct CreateCommentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateCustomMetadataRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -254,11 +405,56 @@ pub struct CreateCustomMetadataRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for CreateCustomMetadataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "custom_metadata"),
+            self.custom_metadata.len(),
+            Some(1),
+            Some(8),
+        );
+        for (key, item) in self.custom_metadata.iter() {
+            let item_path = format!("{}[{}]", field_path(path, "custom_metadata"), key);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(256));
+            errors.check_pattern(&item_path, &item, "[a-zA-Z0-9._+-/=][a-zA-Z0-9 ._+-/=]*");
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "version_id"), &value, "[\\w+-.@]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateCustomMetadataResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFolderRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -273,8 +469,45 @@ pub struct CreateFolderRequest {
     pub parent_folder_id: String,
 }
 
+impl Validate for CreateFolderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\u202D\\u202F-\\uFFFF]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "parent_folder_id"),
+            self.parent_folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "parent_folder_id"),
+            &self.parent_folder_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFolderResponse {
     /// <p>The metadata of the folder.</p>
     #[serde(rename = "Metadata")]
@@ -283,6 +516,7 @@ pub struct CreateFolderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateLabelsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -296,11 +530,47 @@ pub struct CreateLabelsRequest {
     pub resource_id: String,
 }
 
+impl Validate for CreateLabelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "labels"),
+            self.labels.len(),
+            None,
+            Some(20),
+        );
+        for (i, item) in self.labels.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "labels"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            errors.check_pattern(&item_path, &item, "[a-zA-Z0-9._+-/=][a-zA-Z0-9 ._+-/=]*");
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateLabelsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateNotificationSubscriptionRequest {
     /// <p>The endpoint to receive the notifications. If the protocol is HTTPS, the endpoint is a URL that begins with <code>https</code>.</p>
     #[serde(rename = "Endpoint")]
@@ -316,8 +586,30 @@ pub struct CreateNotificationSubscriptionRequest {
     pub subscription_type: String,
 }
 
+impl Validate for CreateNotificationSubscriptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "endpoint"),
+            self.endpoint.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "[&\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateNotificationSubscriptionResponse {
     /// <p>The subscription.</p>
     #[serde(rename = "Subscription")]
@@ -326,6 +618,7 @@ pub struct CreateNotificationSubscriptionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -361,8 +654,85 @@ pub struct CreateUserRequest {
     pub username: String,
 }
 
+impl Validate for CreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.email_address {
+            errors.check_length(
+                &field_path(path, "email_address"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "email_address"),
+                &value,
+                "[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\\.[a-zA-Z]{2,}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "given_name"),
+            self.given_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        if let Some(ref value) = self.organization_id {
+            errors.check_length(
+                &field_path(path, "organization_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "organization_id"), &value, "[&\\w+-.@]+");
+        }
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            Some(4),
+            Some(32),
+        );
+        errors.check_pattern(
+            &field_path(path, "password"),
+            &self.password,
+            "[\\u0020-\\u00FF]+",
+        );
+        errors.check_length(
+            &field_path(path, "surname"),
+            self.surname.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        if let Some(ref value) = self.time_zone_id {
+            errors.check_length(
+                &field_path(path, "time_zone_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "username"),
+            &self.username,
+            "[\\w\\-+.]+(@[a-zA-Z0-9.\\-]+\\.[a-zA-Z]+)?",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {
     /// <p>The user information.</p>
     #[serde(rename = "User")]
@@ -371,6 +741,7 @@ pub struct CreateUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeactivateUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -381,7 +752,28 @@ pub struct DeactivateUserRequest {
     pub user_id: String,
 }
 
+impl Validate for DeactivateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, "[&\\w+-.@]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCommentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -398,7 +790,54 @@ pub struct DeleteCommentRequest {
     pub version_id: String,
 }
 
+impl Validate for DeleteCommentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "comment_id"),
+            self.comment_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "comment_id"),
+            &self.comment_id,
+            "[\\w+-.@]+",
+        );
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteCustomMetadataRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -421,11 +860,53 @@ pub struct DeleteCustomMetadataRequest {
     pub version_id: Option<String>,
 }
 
+impl Validate for DeleteCustomMetadataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.keys {
+            errors.check_length(&field_path(path, "keys"), value.len(), None, Some(8));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "keys"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(56));
+                errors.check_pattern(&item_path, &item, "[a-zA-Z0-9._+-/=][a-zA-Z0-9 ._+-/=]*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.version_id {
+            errors.check_length(
+                &field_path(path, "version_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "version_id"), &value, "[\\w+-.@]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteCustomMetadataResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteDocumentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -436,7 +917,32 @@ pub struct DeleteDocumentRequest {
     pub document_id: String,
 }
 
+impl Validate for DeleteDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFolderContentsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -447,7 +953,32 @@ pub struct DeleteFolderContentsRequest {
     pub folder_id: String,
 }
 
+impl Validate for DeleteFolderContentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "folder_id"),
+            self.folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "folder_id"),
+            &self.folder_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFolderRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -458,7 +989,32 @@ pub struct DeleteFolderRequest {
     pub folder_id: String,
 }
 
+impl Validate for DeleteFolderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "folder_id"),
+            self.folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "folder_id"),
+            &self.folder_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteLabelsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -477,11 +1033,44 @@ pub struct DeleteLabelsRequest {
     pub resource_id: String,
 }
 
+impl Validate for DeleteLabelsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.labels {
+            errors.check_length(&field_path(path, "labels"), value.len(), None, Some(20));
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "labels"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+                errors.check_pattern(&item_path, &item, "[a-zA-Z0-9._+-/=][a-zA-Z0-9 ._+-/=]*");
+            }
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteLabelsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteNotificationSubscriptionRequest {
     /// <p>The ID of the organization.</p>
     #[serde(rename = "OrganizationId")]
@@ -491,7 +1080,35 @@ pub struct DeleteNotificationSubscriptionRequest {
     pub subscription_id: String,
 }
 
+impl Validate for DeleteNotificationSubscriptionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "[&\\w+-.@]+",
+        );
+        errors.check_length(
+            &field_path(path, "subscription_id"),
+            self.subscription_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "subscription_id"),
+            &self.subscription_id,
+            "[&\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -502,7 +1119,28 @@ pub struct DeleteUserRequest {
     pub user_id: String,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, "[&\\w+-.@]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeActivitiesRequest {
     /// <p>Specifies which activity types to include in the response. If this field is left empty, all activity types are returned.</p>
     #[serde(rename = "ActivityTypes")]
@@ -546,8 +1184,69 @@ pub struct DescribeActivitiesRequest {
     pub user_id: Option<String>,
 }
 
+impl Validate for DescribeActivitiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.activity_types {
+            errors.check_length(
+                &field_path(path, "activity_types"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(&field_path(path, "activity_types"), &value, "[\\w,]+");
+        }
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(12288),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0000-\\u00FF]+");
+        }
+        if let Some(ref value) = self.organization_id {
+            errors.check_length(
+                &field_path(path, "organization_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "organization_id"), &value, "[&\\w+-.@]+");
+        }
+        if let Some(ref value) = self.resource_id {
+            errors.check_length(
+                &field_path(path, "resource_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "resource_id"), &value, "[&\\w+-.@]+");
+        }
+        if let Some(ref value) = self.user_id {
+            errors.check_length(
+                &field_path(path, "user_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "user_id"), &value, "[&\\w+-.@]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeActivitiesResponse {
     /// <p>The marker for the next set of results.</p>
     #[serde(rename = "Marker")]
@@ -560,6 +1259,7 @@ pub struct DescribeActivitiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCommentsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -581,8 +1281,55 @@ pub struct DescribeCommentsRequest {
     pub version_id: String,
 }
 
+impl Validate for DescribeCommentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0000-\\u00FF]+");
+        }
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCommentsResponse {
     /// <p>The list of comments for the specified document version.</p>
     #[serde(rename = "Comments")]
@@ -595,6 +1342,7 @@ pub struct DescribeCommentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDocumentVersionsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -621,8 +1369,61 @@ pub struct DescribeDocumentVersionsRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for DescribeDocumentVersionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.fields {
+            errors.check_length(
+                &field_path(path, "fields"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "fields"), &value, "[\\w,]+");
+        }
+        if let Some(ref value) = self.include {
+            errors.check_length(
+                &field_path(path, "include"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "include"), &value, "[\\w,]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDocumentVersionsResponse {
     /// <p>The document versions.</p>
     #[serde(rename = "DocumentVersions")]
@@ -635,6 +1436,7 @@ pub struct DescribeDocumentVersionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFolderContentsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -669,8 +1471,52 @@ pub struct DescribeFolderContentsRequest {
     pub type_: Option<String>,
 }
 
+impl Validate for DescribeFolderContentsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "folder_id"),
+            self.folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "folder_id"),
+            &self.folder_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.include {
+            errors.check_length(
+                &field_path(path, "include"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "include"), &value, "[\\w,]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFolderContentsResponse {
     /// <p>The documents in the specified folder.</p>
     #[serde(rename = "Documents")]
@@ -687,6 +1533,7 @@ pub struct DescribeFolderContentsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGroupsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -709,8 +1556,53 @@ pub struct DescribeGroupsRequest {
     pub search_query: String,
 }
 
+impl Validate for DescribeGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+            errors.check_pattern(&field_path(path, "marker"), &value, "[\\u0000-\\u00FF]+");
+        }
+        if let Some(ref value) = self.organization_id {
+            errors.check_length(
+                &field_path(path, "organization_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "organization_id"), &value, "[&\\w+-.@]+");
+        }
+        errors.check_length(
+            &field_path(path, "search_query"),
+            self.search_query.chars().count(),
+            Some(1),
+            Some(512),
+        );
+        errors.check_pattern(
+            &field_path(path, "search_query"),
+            &self.search_query,
+            "[\\u0020-\\uFFFF]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGroupsResponse {
     /// <p>The list of groups.</p>
     #[serde(rename = "Groups")]
@@ -723,6 +1615,7 @@ pub struct DescribeGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeNotificationSubscriptionsRequest {
     /// <p>The maximum number of items to return with this call.</p>
     #[serde(rename = "Limit")]
@@ -737,8 +1630,35 @@ pub struct DescribeNotificationSubscriptionsRequest {
     pub organization_id: String,
 }
 
+impl Validate for DescribeNotificationSubscriptionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "[&\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeNotificationSubscriptionsResponse {
     /// <p>The marker to use when requesting the next set of results. If there are no additional results, the string is empty.</p>
     #[serde(rename = "Marker")]
@@ -751,6 +1671,7 @@ pub struct DescribeNotificationSubscriptionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeResourcePermissionsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -773,8 +1694,52 @@ pub struct DescribeResourcePermissionsRequest {
     pub resource_id: String,
 }
 
+impl Validate for DescribeResourcePermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.principal_id {
+            errors.check_length(
+                &field_path(path, "principal_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "principal_id"), &value, "[&\\w+-.@]+");
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeResourcePermissionsResponse {
     /// <p>The marker to use when requesting the next set of results. If there are no additional results, the string is empty.</p>
     #[serde(rename = "Marker")]
@@ -787,6 +1752,7 @@ pub struct DescribeResourcePermissionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeRootFoldersRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -801,8 +1767,30 @@ pub struct DescribeRootFoldersRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for DescribeRootFoldersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "authentication_token"),
+            self.authentication_token.chars().count(),
+            Some(1),
+            Some(8199),
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeRootFoldersResponse {
     /// <p>The user's special folders.</p>
     #[serde(rename = "Folders")]
@@ -815,6 +1803,7 @@ pub struct DescribeRootFoldersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUsersRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -858,8 +1847,68 @@ pub struct DescribeUsersRequest {
     pub user_ids: Option<String>,
 }
 
+impl Validate for DescribeUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.fields {
+            errors.check_length(
+                &field_path(path, "fields"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "fields"), &value, "[\\w,]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.organization_id {
+            errors.check_length(
+                &field_path(path, "organization_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "organization_id"), &value, "[&\\w+-.@]+");
+        }
+        if let Some(ref value) = self.query {
+            errors.check_length(
+                &field_path(path, "query"),
+                value.chars().count(),
+                Some(1),
+                Some(512),
+            );
+            errors.check_pattern(&field_path(path, "query"), &value, "[\\u0020-\\uFFFF]+");
+        }
+        if let Some(ref value) = self.user_ids {
+            errors.check_length(
+                &field_path(path, "user_ids"),
+                value.chars().count(),
+                Some(1),
+                Some(2000),
+            );
+            errors.check_pattern(&field_path(path, "user_ids"), &value, "[&\\w+-.@, ]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUsersResponse {
     /// <p>The marker to use when requesting the next set of results. If there are no additional results, the string is empty.</p>
     #[serde(rename = "Marker")]
@@ -873,7 +1922,7 @@ pub struct DescribeUsersResponse {
 
 /// <p>Describes the document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentMetadata {
     /// <p>The time when the document was created.</p>
     #[serde(rename = "CreatedTimestamp")]
@@ -911,7 +1960,7 @@ pub struct DocumentMetadata {
 
 /// <p>Describes a version of a document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DocumentVersionMetadata {
     /// <p>The timestamp when the content of the document was originally created.</p>
     #[serde(rename = "ContentCreatedTimestamp")]
@@ -969,7 +2018,7 @@ pub struct DocumentVersionMetadata {
 
 /// <p>Describes a folder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FolderMetadata {
     /// <p>The time when the folder was created.</p>
     #[serde(rename = "CreatedTimestamp")]
@@ -1018,14 +2067,26 @@ pub struct FolderMetadata {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetCurrentUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
     pub authentication_token: String,
 }
 
+impl Validate for GetCurrentUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "authentication_token"),
+            self.authentication_token.chars().count(),
+            Some(1),
+            Some(8199),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetCurrentUserResponse {
     /// <p>Metadata of the user.</p>
     #[serde(rename = "User")]
@@ -1034,6 +2095,7 @@ pub struct GetCurrentUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentPathRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1056,8 +2118,52 @@ pub struct GetDocumentPathRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for GetDocumentPathRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[&\\w+-.@]+",
+        );
+        if let Some(ref value) = self.fields {
+            errors.check_length(
+                &field_path(path, "fields"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "fields"), &value, "[\\w,]+");
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDocumentPathResponse {
     /// <p>The path information.</p>
     #[serde(rename = "Path")]
@@ -1066,6 +2172,7 @@ pub struct GetDocumentPathResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1080,8 +2187,32 @@ pub struct GetDocumentRequest {
     pub include_custom_metadata: Option<bool>,
 }
 
+impl Validate for GetDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDocumentResponse {
     /// <p>The custom metadata on the document.</p>
     #[serde(rename = "CustomMetadata")]
@@ -1094,6 +2225,7 @@ pub struct GetDocumentResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetDocumentVersionRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1115,8 +2247,52 @@ pub struct GetDocumentVersionRequest {
     pub version_id: String,
 }
 
+impl Validate for GetDocumentVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.fields {
+            errors.check_length(
+                &field_path(path, "fields"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "fields"), &value, "[\\w,]+");
+        }
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetDocumentVersionResponse {
     /// <p>The custom metadata on the document version.</p>
     #[serde(rename = "CustomMetadata")]
@@ -1129,6 +2305,7 @@ pub struct GetDocumentVersionResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFolderPathRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1151,8 +2328,52 @@ pub struct GetFolderPathRequest {
     pub marker: Option<String>,
 }
 
+impl Validate for GetFolderPathRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.fields {
+            errors.check_length(
+                &field_path(path, "fields"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "fields"), &value, "[\\w,]+");
+        }
+        errors.check_length(
+            &field_path(path, "folder_id"),
+            self.folder_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "folder_id"),
+            &self.folder_id,
+            "[&\\w+-.@]+",
+        );
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFolderPathResponse {
     /// <p>The path information.</p>
     #[serde(rename = "Path")]
@@ -1161,6 +2382,7 @@ pub struct GetFolderPathResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetFolderRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1175,8 +2397,32 @@ pub struct GetFolderRequest {
     pub include_custom_metadata: Option<bool>,
 }
 
+impl Validate for GetFolderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "folder_id"),
+            self.folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "folder_id"),
+            &self.folder_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetFolderResponse {
     /// <p>The custom metadata on the folder.</p>
     #[serde(rename = "CustomMetadata")]
@@ -1189,6 +2435,7 @@ pub struct GetFolderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetResourcesRequest {
     /// <p>The Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API operation using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1212,8 +2459,41 @@ pub struct GetResourcesRequest {
     pub user_id: Option<String>,
 }
 
+impl Validate for GetResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(999));
+        }
+        if let Some(ref value) = self.marker {
+            errors.check_length(
+                &field_path(path, "marker"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.user_id {
+            errors.check_length(
+                &field_path(path, "user_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(&field_path(path, "user_id"), &value, "[&\\w+-.@]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetResourcesResponse {
     /// <p>The documents in the specified collection.</p>
     #[serde(rename = "Documents")]
@@ -1231,7 +2511,7 @@ pub struct GetResourcesResponse {
 
 /// <p>Describes the metadata of a user group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupMetadata {
     /// <p>The ID of the user group.</p>
     #[serde(rename = "Id")]
@@ -1244,6 +2524,7 @@ pub struct GroupMetadata {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct InitiateDocumentVersionUploadRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1278,8 +2559,62 @@ pub struct InitiateDocumentVersionUploadRequest {
     pub parent_folder_id: String,
 }
 
+impl Validate for InitiateDocumentVersionUploadRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.content_type {
+            errors.check_length(
+                &field_path(path, "content_type"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+        }
+        if let Some(ref value) = self.id {
+            errors.check_length(
+                &field_path(path, "id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "id"), &value, "[\\w+-.@]+");
+        }
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\u202D\\u202F-\\uFFFF]+",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "parent_folder_id"),
+            self.parent_folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "parent_folder_id"),
+            &self.parent_folder_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InitiateDocumentVersionUploadResponse {
     /// <p>The document metadata.</p>
     #[serde(rename = "Metadata")]
@@ -1293,6 +2628,7 @@ pub struct InitiateDocumentVersionUploadResponse {
 
 /// <p>Set of options which defines notification preferences of given action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct NotificationOptions {
     /// <p>Text value to be included in the email body.</p>
     #[serde(rename = "EmailMessage")]
@@ -1304,9 +2640,22 @@ pub struct NotificationOptions {
     pub send_email: Option<bool>,
 }
 
+impl Validate for NotificationOptions {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.email_message {
+            errors.check_length(
+                &field_path(path, "email_message"),
+                value.chars().count(),
+                None,
+                Some(2048),
+            );
+        }
+    }
+}
+
 /// <p>Describes the users or user groups.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Participants {
     /// <p>The list of user groups.</p>
     #[serde(rename = "Groups")]
@@ -1320,7 +2669,7 @@ pub struct Participants {
 
 /// <p>Describes the permissions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PermissionInfo {
     /// <p>The role of the user.</p>
     #[serde(rename = "Role")]
@@ -1334,7 +2683,7 @@ pub struct PermissionInfo {
 
 /// <p>Describes a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Principal {
     /// <p>The ID of the resource.</p>
     #[serde(rename = "Id")]
@@ -1351,6 +2700,7 @@ pub struct Principal {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveAllResourcePermissionsRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1361,7 +2711,32 @@ pub struct RemoveAllResourcePermissionsRequest {
     pub resource_id: String,
 }
 
+impl Validate for RemoveAllResourcePermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RemoveResourcePermissionRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1379,9 +2754,44 @@ pub struct RemoveResourcePermissionRequest {
     pub resource_id: String,
 }
 
+impl Validate for RemoveResourcePermissionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "principal_id"),
+            self.principal_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "principal_id"),
+            &self.principal_id,
+            "[&\\w+-.@]+",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 /// <p>Describes the metadata of a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceMetadata {
     /// <p>The ID of the resource.</p>
     #[serde(rename = "Id")]
@@ -1415,7 +2825,7 @@ pub struct ResourceMetadata {
 
 /// <p>Describes the path information of a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourcePath {
     /// <p>The components of the resource path.</p>
     #[serde(rename = "Components")]
@@ -1425,7 +2835,7 @@ pub struct ResourcePath {
 
 /// <p>Describes the resource path.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourcePathComponent {
     /// <p>The ID of the resource path.</p>
     #[serde(rename = "Id")]
@@ -1439,6 +2849,7 @@ pub struct ResourcePathComponent {
 
 /// <p>Describes the recipient type and ID, if available.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SharePrincipal {
     /// <p>The ID of the recipient.</p>
     #[serde(rename = "Id")]
@@ -1451,9 +2862,21 @@ pub struct SharePrincipal {
     pub type_: String,
 }
 
+impl Validate for SharePrincipal {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "id"),
+            self.id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "id"), &self.id, "[&\\w+-.@]+");
+    }
+}
+
 /// <p>Describes the share results of a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ShareResult {
     /// <p>The ID of the invited user.</p>
     #[serde(rename = "InviteePrincipalId")]
@@ -1496,7 +2919,7 @@ pub struct StorageRuleType {
 
 /// <p>Describes a subscription.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Subscription {
     /// <p>The endpoint of the subscription.</p>
     #[serde(rename = "EndPoint")]
@@ -1513,6 +2936,7 @@ pub struct Subscription {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDocumentRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1535,7 +2959,54 @@ pub struct UpdateDocumentRequest {
     pub resource_state: Option<String>,
 }
 
+impl Validate for UpdateDocumentRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\u202D\\u202F-\\uFFFF]+",
+            );
+        }
+        if let Some(ref value) = self.parent_folder_id {
+            errors.check_length(
+                &field_path(path, "parent_folder_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "parent_folder_id"), &value, "[\\w+-.@]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDocumentVersionRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1553,7 +3024,43 @@ pub struct UpdateDocumentVersionRequest {
     pub version_status: Option<String>,
 }
 
+impl Validate for UpdateDocumentVersionRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "document_id"),
+            self.document_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "document_id"),
+            &self.document_id,
+            "[\\w+-.@]+",
+        );
+        errors.check_length(
+            &field_path(path, "version_id"),
+            self.version_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "version_id"),
+            &self.version_id,
+            "[\\w+-.@]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFolderRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1576,7 +3083,54 @@ pub struct UpdateFolderRequest {
     pub resource_state: Option<String>,
 }
 
+impl Validate for UpdateFolderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "folder_id"),
+            self.folder_id.chars().count(),
+            Some(1),
+            Some(128),
+        );
+        errors.check_pattern(
+            &field_path(path, "folder_id"),
+            &self.folder_id,
+            "[\\w+-.@]+",
+        );
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(255),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\u0020-\\u202D\\u202F-\\uFFFF]+",
+            );
+        }
+        if let Some(ref value) = self.parent_folder_id {
+            errors.check_length(
+                &field_path(path, "parent_folder_id"),
+                value.chars().count(),
+                Some(1),
+                Some(128),
+            );
+            errors.check_pattern(&field_path(path, "parent_folder_id"), &value, "[\\w+-.@]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateUserRequest {
     /// <p>Amazon WorkDocs authentication token. Do not set this field when using administrative API actions, as in accessing the API using AWS credentials.</p>
     #[serde(rename = "AuthenticationToken")]
@@ -1615,8 +3169,52 @@ pub struct UpdateUserRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.authentication_token {
+            errors.check_length(
+                &field_path(path, "authentication_token"),
+                value.chars().count(),
+                Some(1),
+                Some(8199),
+            );
+        }
+        if let Some(ref value) = self.given_name {
+            errors.check_length(
+                &field_path(path, "given_name"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.surname {
+            errors.check_length(
+                &field_path(path, "surname"),
+                value.chars().count(),
+                Some(1),
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.time_zone_id {
+            errors.check_length(
+                &field_path(path, "time_zone_id"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "user_id"), &self.user_id, "[&\\w+-.@]+");
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateUserResponse {
     /// <p>The user information.</p>
     #[serde(rename = "User")]
@@ -1626,7 +3224,7 @@ pub struct UpdateUserResponse {
 
 /// <p>Describes the upload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UploadMetadata {
     /// <p>The signed headers.</p>
     #[serde(rename = "SignedHeaders")]
@@ -1640,7 +3238,7 @@ pub struct UploadMetadata {
 
 /// <p>Describes a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct User {
     /// <p>The time when the user was created.</p>
     #[serde(rename = "CreatedTimestamp")]
@@ -1706,7 +3304,7 @@ pub struct User {
 
 /// <p>Describes the metadata of the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserMetadata {
     /// <p>The email address of the user.</p>
     #[serde(rename = "EmailAddress")]
@@ -1732,7 +3330,7 @@ pub struct UserMetadata {
 
 /// <p>Describes the storage for a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserStorageMetadata {
     /// <p>The storage for a user.</p>
     #[serde(rename = "StorageRule")]
@@ -1797,7 +3395,12 @@ impl AbortDocumentVersionUploadError {
                         AbortDocumentVersionUploadError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1857,7 +3460,12 @@ impl ActivateUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1917,7 +3525,12 @@ impl AddResourcePermissionsError {
                         AddResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1994,7 +3607,12 @@ impl CreateCommentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2078,7 +3696,12 @@ impl CreateCustomMetadataError {
                         CreateCustomMetadataError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2159,7 +3782,12 @@ impl CreateFolderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2227,7 +3855,12 @@ impl CreateLabelsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2283,7 +3916,12 @@ impl CreateNotificationSubscriptionError {
                         CreateNotificationSubscriptionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2340,7 +3978,12 @@ impl CreateUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2401,7 +4044,12 @@ impl DeactivateUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2472,7 +4120,12 @@ impl DeleteCommentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2548,7 +4201,12 @@ impl DeleteCustomMetadataError {
                         DeleteCustomMetadataError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2627,7 +4285,12 @@ impl DeleteDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2704,7 +4367,12 @@ impl DeleteFolderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2788,7 +4456,12 @@ impl DeleteFolderContentsError {
                         DeleteFolderContentsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2849,7 +4522,12 @@ impl DeleteLabelsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2911,7 +4589,12 @@ impl DeleteNotificationSubscriptionError {
                         DeleteNotificationSubscriptionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2969,7 +4652,12 @@ impl DeleteUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3032,7 +4720,12 @@ impl DescribeActivitiesError {
                         DescribeActivitiesError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3098,7 +4791,12 @@ impl DescribeCommentsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3180,7 +4878,12 @@ impl DescribeDocumentVersionsError {
                         DescribeDocumentVersionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3256,7 +4959,12 @@ impl DescribeFolderContentsError {
                         DescribeFolderContentsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3313,7 +5021,12 @@ impl DescribeGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3367,7 +5080,12 @@ impl DescribeNotificationSubscriptionsError {
                         DescribeNotificationSubscriptionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3427,7 +5145,12 @@ impl DescribeResourcePermissionsError {
                         DescribeResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3491,7 +5214,12 @@ impl DescribeRootFoldersError {
                         DescribeRootFoldersError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3562,7 +5290,12 @@ impl DescribeUsersError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3625,7 +5358,12 @@ impl GetCurrentUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3694,7 +5432,12 @@ impl GetDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3757,7 +5500,12 @@ impl GetDocumentPathError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3830,7 +5578,12 @@ impl GetDocumentVersionError {
                         GetDocumentVersionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3901,7 +5654,12 @@ impl GetFolderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3962,7 +5720,12 @@ impl GetFolderPathError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4021,7 +5784,12 @@ impl GetResourcesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4132,7 +5900,12 @@ impl InitiateDocumentVersionUploadError {
                         InitiateDocumentVersionUploadError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4200,7 +5973,12 @@ impl RemoveAllResourcePermissionsError {
                         RemoveAllResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4259,7 +6037,12 @@ impl RemoveResourcePermissionError {
                         RemoveResourcePermissionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4346,7 +6129,12 @@ impl UpdateDocumentError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4439,7 +6227,12 @@ impl UpdateDocumentVersionError {
                         UpdateDocumentVersionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4526,7 +6319,12 @@ impl UpdateFolderError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4607,7 +6405,12 @@ impl UpdateUserError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -4872,952 +6675,138 @@ pub trait Workdocs {
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
 }
-/// A client for the Amazon WorkDocs API.
-#[derive(Clone)]
-pub struct WorkdocsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl WorkdocsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> WorkdocsClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> WorkdocsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsClient {
-        WorkdocsClient { client, region }
-    }
-}
-
-impl Workdocs for WorkdocsClient {
-    /// <p>Aborts the upload of the specified document version that was previously initiated by <a>InitiateDocumentVersionUpload</a>. The client should make this call only when it no longer intends to upload the document version, or fails to do so.</p>
+const _: Option<&dyn Workdocs> = None;
+impl<'a, T: Workdocs + ?Sized> Workdocs for &'a T {
     fn abort_document_version_upload(
         &self,
         input: AbortDocumentVersionUploadRequest,
     ) -> RusotoFuture<(), AbortDocumentVersionUploadError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions/{version_id}",
-            document_id = input.document_id,
-            version_id = input.version_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AbortDocumentVersionUploadError::from_response(response))
-                }))
-            }
-        })
+        (**self).abort_document_version_upload(input)
     }
-
-    /// <p>Activates the specified user. Only active users can access Amazon WorkDocs.</p>
     fn activate_user(
         &self,
         input: ActivateUserRequest,
     ) -> RusotoFuture<ActivateUserResponse, ActivateUserError> {
-        let request_uri = format!(
-            "/api/v1/users/{user_id}/activation",
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ActivateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ActivateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).activate_user(input)
     }
-
-    /// <p>Creates a set of permissions for the specified folder or document. The resource permissions are overwritten if the principals already have different permissions.</p>
     fn add_resource_permissions(
         &self,
         input: AddResourcePermissionsRequest,
     ) -> RusotoFuture<AddResourcePermissionsResponse, AddResourcePermissionsError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/permissions",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AddResourcePermissionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AddResourcePermissionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).add_resource_permissions(input)
     }
-
-    /// <p>Adds a new comment to the specified document version.</p>
     fn create_comment(
         &self,
         input: CreateCommentRequest,
     ) -> RusotoFuture<CreateCommentResponse, CreateCommentError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions/{version_id}/comment",
-            document_id = input.document_id,
-            version_id = input.version_id
-        );
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCommentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateCommentError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_comment(input)
     }
-
-    /// <p>Adds one or more custom properties to the specified resource (a folder, document, or version).</p>
     fn create_custom_metadata(
         &self,
         input: CreateCustomMetadataRequest,
     ) -> RusotoFuture<CreateCustomMetadataResponse, CreateCustomMetadataError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/customMetadata",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.version_id {
-            params.put("versionid", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateCustomMetadataResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(CreateCustomMetadataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).create_custom_metadata(input)
     }
-
-    /// <p>Creates a folder with the specified name and parent folder.</p>
     fn create_folder(
         &self,
         input: CreateFolderRequest,
     ) -> RusotoFuture<CreateFolderResponse, CreateFolderError> {
-        let request_uri = "/api/v1/folders";
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFolderResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFolderError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_folder(input)
     }
-
-    /// <p>Adds the specified list of labels to the given resource (a document or folder)</p>
     fn create_labels(
         &self,
         input: CreateLabelsRequest,
     ) -> RusotoFuture<CreateLabelsResponse, CreateLabelsError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/labels",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateLabelsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateLabelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_labels(input)
     }
-
-    /// <p>Configure Amazon WorkDocs to use Amazon SNS notifications. The endpoint receives a confirmation message, and must confirm the subscription.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/subscribe-notifications.html">Subscribe to Notifications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
     fn create_notification_subscription(
         &self,
         input: CreateNotificationSubscriptionRequest,
     ) -> RusotoFuture<CreateNotificationSubscriptionResponse, CreateNotificationSubscriptionError>
     {
-        let request_uri = format!(
-            "/api/v1/organizations/{organization_id}/subscriptions",
-            organization_id = input.organization_id
-        );
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateNotificationSubscriptionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(CreateNotificationSubscriptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).create_notification_subscription(input)
     }
-
-    /// <p>Creates a user in a Simple AD or Microsoft AD directory. The status of a newly created user is "ACTIVE". New users can access Amazon WorkDocs.</p>
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
-        let request_uri = "/api/v1/users";
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Deactivates the specified user, which revokes the user's access to Amazon WorkDocs.</p>
     fn deactivate_user(
         &self,
         input: DeactivateUserRequest,
     ) -> RusotoFuture<(), DeactivateUserError> {
-        let request_uri = format!(
-            "/api/v1/users/{user_id}/activation",
-            user_id = input.user_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeactivateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).deactivate_user(input)
     }
-
-    /// <p>Deletes the specified comment from the document version.</p>
     fn delete_comment(&self, input: DeleteCommentRequest) -> RusotoFuture<(), DeleteCommentError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions/{version_id}/comment/{comment_id}",
-            comment_id = input.comment_id,
-            document_id = input.document_id,
-            version_id = input.version_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteCommentError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_comment(input)
     }
-
-    /// <p>Deletes custom metadata from the specified resource.</p>
     fn delete_custom_metadata(
         &self,
         input: DeleteCustomMetadataRequest,
     ) -> RusotoFuture<DeleteCustomMetadataResponse, DeleteCustomMetadataError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/customMetadata",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.delete_all {
-            params.put("deleteAll", x);
-        }
-        if let Some(ref x) = input.keys {
-            for item in x.iter() {
-                params.put("keys", item);
-            }
-        }
-        if let Some(ref x) = input.version_id {
-            params.put("versionId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteCustomMetadataResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteCustomMetadataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_custom_metadata(input)
     }
-
-    /// <p>Permanently deletes the specified document and its associated metadata.</p>
     fn delete_document(
         &self,
         input: DeleteDocumentRequest,
     ) -> RusotoFuture<(), DeleteDocumentError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}",
-            document_id = input.document_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteDocumentError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_document(input)
     }
-
-    /// <p>Permanently deletes the specified folder and its contents.</p>
     fn delete_folder(&self, input: DeleteFolderRequest) -> RusotoFuture<(), DeleteFolderError> {
-        let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFolderError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_folder(input)
     }
-
-    /// <p>Deletes the contents of the specified folder.</p>
     fn delete_folder_contents(
         &self,
         input: DeleteFolderContentsRequest,
     ) -> RusotoFuture<(), DeleteFolderContentsError> {
-        let request_uri = format!(
-            "/api/v1/folders/{folder_id}/contents",
-            folder_id = input.folder_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteFolderContentsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).delete_folder_contents(input)
     }
-
-    /// <p>Deletes the specified list of labels from a resource.</p>
     fn delete_labels(
         &self,
         input: DeleteLabelsRequest,
     ) -> RusotoFuture<DeleteLabelsResponse, DeleteLabelsError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/labels",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.delete_all {
-            params.put("deleteAll", x);
-        }
-        if let Some(ref x) = input.labels {
-            for item in x.iter() {
-                params.put("labels", item);
-            }
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteLabelsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteLabelsError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_labels(input)
     }
-
-    /// <p>Deletes the specified subscription from the specified organization.</p>
     fn delete_notification_subscription(
         &self,
         input: DeleteNotificationSubscriptionRequest,
     ) -> RusotoFuture<(), DeleteNotificationSubscriptionError> {
-        let request_uri = format!(
-            "/api/v1/organizations/{organization_id}/subscriptions/{subscription_id}",
-            organization_id = input.organization_id,
-            subscription_id = input.subscription_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteNotificationSubscriptionError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_notification_subscription(input)
     }
-
-    /// <p>Deletes the specified user from a Simple AD or Microsoft AD directory.</p>
     fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
-        let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Describes the user activities in a specified time period.</p>
     fn describe_activities(
         &self,
         input: DescribeActivitiesRequest,
     ) -> RusotoFuture<DescribeActivitiesResponse, DescribeActivitiesError> {
-        let request_uri = "/api/v1/activities";
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.activity_types {
-            params.put("activityTypes", x);
-        }
-        if let Some(ref x) = input.end_time {
-            params.put("endTime", x);
-        }
-        if let Some(ref x) = input.include_indirect_activities {
-            params.put("includeIndirectActivities", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.organization_id {
-            params.put("organizationId", x);
-        }
-        if let Some(ref x) = input.resource_id {
-            params.put("resourceId", x);
-        }
-        if let Some(ref x) = input.start_time {
-            params.put("startTime", x);
-        }
-        if let Some(ref x) = input.user_id {
-            params.put("userId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeActivitiesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeActivitiesError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_activities(input)
     }
-
-    /// <p>List all the comments for the specified document version.</p>
     fn describe_comments(
         &self,
         input: DescribeCommentsRequest,
     ) -> RusotoFuture<DescribeCommentsResponse, DescribeCommentsError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions/{version_id}/comments",
-            document_id = input.document_id,
-            version_id = input.version_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCommentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeCommentsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_comments(input)
     }
-
-    /// <p>Retrieves the document versions for the specified document.</p> <p>By default, only active versions are returned.</p>
     fn describe_document_versions(
         &self,
         input: DescribeDocumentVersionsRequest,
     ) -> RusotoFuture<DescribeDocumentVersionsResponse, DescribeDocumentVersionsError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions",
-            document_id = input.document_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.fields {
-            params.put("fields", x);
-        }
-        if let Some(ref x) = input.include {
-            params.put("include", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDocumentVersionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDocumentVersionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_document_versions(input)
     }
-
-    /// <p>Describes the contents of the specified folder, including its documents and subfolders.</p> <p>By default, Amazon WorkDocs returns the first 100 active document and folder metadata items. If there are more results, the response includes a marker that you can use to request the next set of results. You can also request initialized documents.</p>
     fn describe_folder_contents(
         &self,
         input: DescribeFolderContentsRequest,
     ) -> RusotoFuture<DescribeFolderContentsResponse, DescribeFolderContentsError> {
-        let request_uri = format!(
-            "/api/v1/folders/{folder_id}/contents",
-            folder_id = input.folder_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.include {
-            params.put("include", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.order {
-            params.put("order", x);
-        }
-        if let Some(ref x) = input.sort {
-            params.put("sort", x);
-        }
-        if let Some(ref x) = input.type_ {
-            params.put("type", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFolderContentsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeFolderContentsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_folder_contents(input)
     }
-
-    /// <p>Describes the groups specified by the query. Groups are defined by the underlying Active Directory.</p>
     fn describe_groups(
         &self,
         input: DescribeGroupsRequest,
     ) -> RusotoFuture<DescribeGroupsResponse, DescribeGroupsError> {
-        let request_uri = "/api/v1/groups";
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.organization_id {
-            params.put("organizationId", x);
-        }
-        params.put("searchQuery", &input.search_query);
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGroupsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_groups(input)
     }
-
-    /// <p>Lists the specified notification subscriptions.</p>
     fn describe_notification_subscriptions(
         &self,
         input: DescribeNotificationSubscriptionsRequest,
@@ -5825,726 +6814,4085 @@ impl Workdocs for WorkdocsClient {
         DescribeNotificationSubscriptionsResponse,
         DescribeNotificationSubscriptionsError,
     > {
-        let request_uri = format!(
-            "/api/v1/organizations/{organization_id}/subscriptions",
-            organization_id = input.organization_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeNotificationSubscriptionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeNotificationSubscriptionsError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_notification_subscriptions(input)
     }
-
-    /// <p>Describes the permissions of a specified resource.</p>
     fn describe_resource_permissions(
         &self,
         input: DescribeResourcePermissionsRequest,
     ) -> RusotoFuture<DescribeResourcePermissionsResponse, DescribeResourcePermissionsError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/permissions",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.principal_id {
-            params.put("principalId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeResourcePermissionsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeResourcePermissionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).describe_resource_permissions(input)
     }
-
-    /// <p>Describes the current user's special folders; the <code>RootFolder</code> and the <code>RecycleBin</code>. <code>RootFolder</code> is the root of user's files and folders and <code>RecycleBin</code> is the root of recycled items. This is not a valid action for SigV4 (administrative API) clients.</p> <p>This action requires an authentication token. To get an authentication token, register an application with Amazon WorkDocs. For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/wd-auth-user.html">Authentication and Access Control for User Applications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
     fn describe_root_folders(
         &self,
         input: DescribeRootFoldersRequest,
     ) -> RusotoFuture<DescribeRootFoldersResponse, DescribeRootFoldersError> {
-        let request_uri = "/api/v1/me/root";
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("Authentication", &input.authentication_token);
-        let mut params = Params::new();
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeRootFoldersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeRootFoldersError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_root_folders(input)
     }
-
-    /// <p>Describes the specified users. You can describe all users or filter the results (for example, by status or organization).</p> <p>By default, Amazon WorkDocs returns the first 24 active or pending users. If there are more results, the response includes a marker that you can use to request the next set of results.</p>
     fn describe_users(
         &self,
         input: DescribeUsersRequest,
     ) -> RusotoFuture<DescribeUsersResponse, DescribeUsersError> {
-        let request_uri = "/api/v1/users";
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.fields {
-            params.put("fields", x);
-        }
-        if let Some(ref x) = input.include {
-            params.put("include", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.order {
-            params.put("order", x);
-        }
-        if let Some(ref x) = input.organization_id {
-            params.put("organizationId", x);
-        }
-        if let Some(ref x) = input.query {
-            params.put("query", x);
-        }
-        if let Some(ref x) = input.sort {
-            params.put("sort", x);
-        }
-        if let Some(ref x) = input.user_ids {
-            params.put("userIds", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUsersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_users(input)
     }
-
-    /// <p>Retrieves details of the current user for whom the authentication token was generated. This is not a valid action for SigV4 (administrative API) clients.</p>
     fn get_current_user(
         &self,
         input: GetCurrentUserRequest,
     ) -> RusotoFuture<GetCurrentUserResponse, GetCurrentUserError> {
-        let request_uri = "/api/v1/me";
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.add_header("Authentication", &input.authentication_token);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetCurrentUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetCurrentUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_current_user(input)
     }
-
-    /// <p>Retrieves details of a document.</p>
     fn get_document(
         &self,
         input: GetDocumentRequest,
     ) -> RusotoFuture<GetDocumentResponse, GetDocumentError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}",
-            document_id = input.document_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.include_custom_metadata {
-            params.put("includeCustomMetadata", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDocumentResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDocumentError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_document(input)
     }
-
-    /// <p>Retrieves the path information (the hierarchy from the root folder) for the requested document.</p> <p>By default, Amazon WorkDocs returns a maximum of 100 levels upwards from the requested document and only includes the IDs of the parent folders in the path. You can limit the maximum number of levels. You can also request the names of the parent folders.</p>
     fn get_document_path(
         &self,
         input: GetDocumentPathRequest,
     ) -> RusotoFuture<GetDocumentPathResponse, GetDocumentPathError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/path",
-            document_id = input.document_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.fields {
-            params.put("fields", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDocumentPathResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDocumentPathError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_document_path(input)
     }
-
-    /// <p>Retrieves version metadata for the specified document.</p>
     fn get_document_version(
         &self,
         input: GetDocumentVersionRequest,
     ) -> RusotoFuture<GetDocumentVersionResponse, GetDocumentVersionError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions/{version_id}",
-            document_id = input.document_id,
-            version_id = input.version_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.fields {
-            params.put("fields", x);
-        }
-        if let Some(ref x) = input.include_custom_metadata {
-            params.put("includeCustomMetadata", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetDocumentVersionResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetDocumentVersionError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_document_version(input)
     }
-
-    /// <p>Retrieves the metadata of the specified folder.</p>
     fn get_folder(
         &self,
         input: GetFolderRequest,
     ) -> RusotoFuture<GetFolderResponse, GetFolderError> {
-        let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.include_custom_metadata {
-            params.put("includeCustomMetadata", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFolderResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFolderError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_folder(input)
     }
-
-    /// <p>Retrieves the path information (the hierarchy from the root folder) for the specified folder.</p> <p>By default, Amazon WorkDocs returns a maximum of 100 levels upwards from the requested folder and only includes the IDs of the parent folders in the path. You can limit the maximum number of levels. You can also request the parent folder names.</p>
     fn get_folder_path(
         &self,
         input: GetFolderPathRequest,
     ) -> RusotoFuture<GetFolderPathResponse, GetFolderPathError> {
-        let request_uri = format!(
-            "/api/v1/folders/{folder_id}/path",
-            folder_id = input.folder_id
-        );
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.fields {
-            params.put("fields", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetFolderPathResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetFolderPathError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_folder_path(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<GetResourcesResponse, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> RusotoFuture<InitiateDocumentVersionUploadResponse, InitiateDocumentVersionUploadError>
+    {
+        (**self).initiate_document_version_upload(input)
+    }
+    fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> RusotoFuture<(), RemoveAllResourcePermissionsError> {
+        (**self).remove_all_resource_permissions(input)
+    }
+    fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> RusotoFuture<(), RemoveResourcePermissionError> {
+        (**self).remove_resource_permission(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<(), UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> RusotoFuture<(), UpdateDocumentVersionError> {
+        (**self).update_document_version(input)
+    }
+    fn update_folder(&self, input: UpdateFolderRequest) -> RusotoFuture<(), UpdateFolderError> {
+        (**self).update_folder(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+impl<T: Workdocs + ?Sized> Workdocs for Box<T> {
+    fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> RusotoFuture<(), AbortDocumentVersionUploadError> {
+        (**self).abort_document_version_upload(input)
+    }
+    fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> RusotoFuture<ActivateUserResponse, ActivateUserError> {
+        (**self).activate_user(input)
+    }
+    fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> RusotoFuture<AddResourcePermissionsResponse, AddResourcePermissionsError> {
+        (**self).add_resource_permissions(input)
+    }
+    fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> RusotoFuture<CreateCommentResponse, CreateCommentError> {
+        (**self).create_comment(input)
+    }
+    fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> RusotoFuture<CreateCustomMetadataResponse, CreateCustomMetadataError> {
+        (**self).create_custom_metadata(input)
+    }
+    fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> RusotoFuture<CreateFolderResponse, CreateFolderError> {
+        (**self).create_folder(input)
+    }
+    fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> RusotoFuture<CreateLabelsResponse, CreateLabelsError> {
+        (**self).create_labels(input)
+    }
+    fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> RusotoFuture<CreateNotificationSubscriptionResponse, CreateNotificationSubscriptionError>
+    {
+        (**self).create_notification_subscription(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> RusotoFuture<(), DeactivateUserError> {
+        (**self).deactivate_user(input)
+    }
+    fn delete_comment(&self, input: DeleteCommentRequest) -> RusotoFuture<(), DeleteCommentError> {
+        (**self).delete_comment(input)
+    }
+    fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> RusotoFuture<DeleteCustomMetadataResponse, DeleteCustomMetadataError> {
+        (**self).delete_custom_metadata(input)
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<(), DeleteDocumentError> {
+        (**self).delete_document(input)
+    }
+    fn delete_folder(&self, input: DeleteFolderRequest) -> RusotoFuture<(), DeleteFolderError> {
+        (**self).delete_folder(input)
+    }
+    fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> RusotoFuture<(), DeleteFolderContentsError> {
+        (**self).delete_folder_contents(input)
+    }
+    fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> RusotoFuture<DeleteLabelsResponse, DeleteLabelsError> {
+        (**self).delete_labels(input)
+    }
+    fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> RusotoFuture<(), DeleteNotificationSubscriptionError> {
+        (**self).delete_notification_subscription(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> RusotoFuture<DescribeActivitiesResponse, DescribeActivitiesError> {
+        (**self).describe_activities(input)
+    }
+    fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> RusotoFuture<DescribeCommentsResponse, DescribeCommentsError> {
+        (**self).describe_comments(input)
+    }
+    fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> RusotoFuture<DescribeDocumentVersionsResponse, DescribeDocumentVersionsError> {
+        (**self).describe_document_versions(input)
+    }
+    fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> RusotoFuture<DescribeFolderContentsResponse, DescribeFolderContentsError> {
+        (**self).describe_folder_contents(input)
+    }
+    fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> RusotoFuture<DescribeGroupsResponse, DescribeGroupsError> {
+        (**self).describe_groups(input)
+    }
+    fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> RusotoFuture<
+        DescribeNotificationSubscriptionsResponse,
+        DescribeNotificationSubscriptionsError,
+    > {
+        (**self).describe_notification_subscriptions(input)
+    }
+    fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> RusotoFuture<DescribeResourcePermissionsResponse, DescribeResourcePermissionsError> {
+        (**self).describe_resource_permissions(input)
+    }
+    fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> RusotoFuture<DescribeRootFoldersResponse, DescribeRootFoldersError> {
+        (**self).describe_root_folders(input)
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResponse, DescribeUsersError> {
+        (**self).describe_users(input)
+    }
+    fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> RusotoFuture<GetCurrentUserResponse, GetCurrentUserError> {
+        (**self).get_current_user(input)
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResponse, GetDocumentError> {
+        (**self).get_document(input)
+    }
+    fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> RusotoFuture<GetDocumentPathResponse, GetDocumentPathError> {
+        (**self).get_document_path(input)
+    }
+    fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> RusotoFuture<GetDocumentVersionResponse, GetDocumentVersionError> {
+        (**self).get_document_version(input)
+    }
+    fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> RusotoFuture<GetFolderResponse, GetFolderError> {
+        (**self).get_folder(input)
+    }
+    fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> RusotoFuture<GetFolderPathResponse, GetFolderPathError> {
+        (**self).get_folder_path(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<GetResourcesResponse, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> RusotoFuture<InitiateDocumentVersionUploadResponse, InitiateDocumentVersionUploadError>
+    {
+        (**self).initiate_document_version_upload(input)
+    }
+    fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> RusotoFuture<(), RemoveAllResourcePermissionsError> {
+        (**self).remove_all_resource_permissions(input)
+    }
+    fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> RusotoFuture<(), RemoveResourcePermissionError> {
+        (**self).remove_resource_permission(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<(), UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> RusotoFuture<(), UpdateDocumentVersionError> {
+        (**self).update_document_version(input)
+    }
+    fn update_folder(&self, input: UpdateFolderRequest) -> RusotoFuture<(), UpdateFolderError> {
+        (**self).update_folder(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+impl<T: Workdocs + ?Sized> Workdocs for ::std::sync::Arc<T> {
+    fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> RusotoFuture<(), AbortDocumentVersionUploadError> {
+        (**self).abort_document_version_upload(input)
+    }
+    fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> RusotoFuture<ActivateUserResponse, ActivateUserError> {
+        (**self).activate_user(input)
+    }
+    fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> RusotoFuture<AddResourcePermissionsResponse, AddResourcePermissionsError> {
+        (**self).add_resource_permissions(input)
+    }
+    fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> RusotoFuture<CreateCommentResponse, CreateCommentError> {
+        (**self).create_comment(input)
+    }
+    fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> RusotoFuture<CreateCustomMetadataResponse, CreateCustomMetadataError> {
+        (**self).create_custom_metadata(input)
+    }
+    fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> RusotoFuture<CreateFolderResponse, CreateFolderError> {
+        (**self).create_folder(input)
+    }
+    fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> RusotoFuture<CreateLabelsResponse, CreateLabelsError> {
+        (**self).create_labels(input)
+    }
+    fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> RusotoFuture<CreateNotificationSubscriptionResponse, CreateNotificationSubscriptionError>
+    {
+        (**self).create_notification_subscription(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> RusotoFuture<(), DeactivateUserError> {
+        (**self).deactivate_user(input)
+    }
+    fn delete_comment(&self, input: DeleteCommentRequest) -> RusotoFuture<(), DeleteCommentError> {
+        (**self).delete_comment(input)
+    }
+    fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> RusotoFuture<DeleteCustomMetadataResponse, DeleteCustomMetadataError> {
+        (**self).delete_custom_metadata(input)
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<(), DeleteDocumentError> {
+        (**self).delete_document(input)
+    }
+    fn delete_folder(&self, input: DeleteFolderRequest) -> RusotoFuture<(), DeleteFolderError> {
+        (**self).delete_folder(input)
+    }
+    fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> RusotoFuture<(), DeleteFolderContentsError> {
+        (**self).delete_folder_contents(input)
+    }
+    fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> RusotoFuture<DeleteLabelsResponse, DeleteLabelsError> {
+        (**self).delete_labels(input)
+    }
+    fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> RusotoFuture<(), DeleteNotificationSubscriptionError> {
+        (**self).delete_notification_subscription(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> RusotoFuture<DescribeActivitiesResponse, DescribeActivitiesError> {
+        (**self).describe_activities(input)
+    }
+    fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> RusotoFuture<DescribeCommentsResponse, DescribeCommentsError> {
+        (**self).describe_comments(input)
+    }
+    fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> RusotoFuture<DescribeDocumentVersionsResponse, DescribeDocumentVersionsError> {
+        (**self).describe_document_versions(input)
+    }
+    fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> RusotoFuture<DescribeFolderContentsResponse, DescribeFolderContentsError> {
+        (**self).describe_folder_contents(input)
+    }
+    fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> RusotoFuture<DescribeGroupsResponse, DescribeGroupsError> {
+        (**self).describe_groups(input)
+    }
+    fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> RusotoFuture<
+        DescribeNotificationSubscriptionsResponse,
+        DescribeNotificationSubscriptionsError,
+    > {
+        (**self).describe_notification_subscriptions(input)
+    }
+    fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> RusotoFuture<DescribeResourcePermissionsResponse, DescribeResourcePermissionsError> {
+        (**self).describe_resource_permissions(input)
+    }
+    fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> RusotoFuture<DescribeRootFoldersResponse, DescribeRootFoldersError> {
+        (**self).describe_root_folders(input)
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResponse, DescribeUsersError> {
+        (**self).describe_users(input)
+    }
+    fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> RusotoFuture<GetCurrentUserResponse, GetCurrentUserError> {
+        (**self).get_current_user(input)
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResponse, GetDocumentError> {
+        (**self).get_document(input)
+    }
+    fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> RusotoFuture<GetDocumentPathResponse, GetDocumentPathError> {
+        (**self).get_document_path(input)
+    }
+    fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> RusotoFuture<GetDocumentVersionResponse, GetDocumentVersionError> {
+        (**self).get_document_version(input)
+    }
+    fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> RusotoFuture<GetFolderResponse, GetFolderError> {
+        (**self).get_folder(input)
+    }
+    fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> RusotoFuture<GetFolderPathResponse, GetFolderPathError> {
+        (**self).get_folder_path(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<GetResourcesResponse, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> RusotoFuture<InitiateDocumentVersionUploadResponse, InitiateDocumentVersionUploadError>
+    {
+        (**self).initiate_document_version_upload(input)
+    }
+    fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> RusotoFuture<(), RemoveAllResourcePermissionsError> {
+        (**self).remove_all_resource_permissions(input)
+    }
+    fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> RusotoFuture<(), RemoveResourcePermissionError> {
+        (**self).remove_resource_permission(input)
+    }
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<(), UpdateDocumentError> {
+        (**self).update_document(input)
+    }
+    fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> RusotoFuture<(), UpdateDocumentVersionError> {
+        (**self).update_document_version(input)
+    }
+    fn update_folder(&self, input: UpdateFolderRequest) -> RusotoFuture<(), UpdateFolderError> {
+        (**self).update_folder(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+/// A client for the Amazon WorkDocs API.
+#[derive(Clone)]
+pub struct WorkdocsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl WorkdocsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorkdocsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkdocsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsClient {
+        WorkdocsClient { client, region }
+    }
+}
+
+impl Workdocs for WorkdocsClient {
+    /// <p>Aborts the upload of the specified document version that was previously initiated by <a>InitiateDocumentVersionUpload</a>. The client should make this call only when it no longer intends to upload the document version, or fails to do so.</p>
+    fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> RusotoFuture<(), AbortDocumentVersionUploadError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions/{version_id}",
+            document_id = input.document_id,
+            version_id = input.version_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("AbortDocumentVersionUpload");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.add_resource_id("VersionId", &input.version_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AbortDocumentVersionUploadError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Activates the specified user. Only active users can access Amazon WorkDocs.</p>
+    fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> RusotoFuture<ActivateUserResponse, ActivateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/users/{user_id}/activation",
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("ActivateUser");
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ActivateUser")
+                        .deserialize::<ActivateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ActivateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a set of permissions for the specified folder or document. The resource permissions are overwritten if the principals already have different permissions.</p>
+    fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> RusotoFuture<AddResourcePermissionsResponse, AddResourcePermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/permissions",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("AddResourcePermissions");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AddResourcePermissions")
+                        .deserialize::<AddResourcePermissionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AddResourcePermissionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds a new comment to the specified document version.</p>
+    fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> RusotoFuture<CreateCommentResponse, CreateCommentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions/{version_id}/comment",
+            document_id = input.document_id,
+            version_id = input.version_id
+        );
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateComment");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.add_resource_id("VersionId", &input.version_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateComment")
+                        .deserialize::<CreateCommentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateCommentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds one or more custom properties to the specified resource (a folder, document, or version).</p>
+    fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> RusotoFuture<CreateCustomMetadataResponse, CreateCustomMetadataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/customMetadata",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateCustomMetadata");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.version_id {
+            params.put("versionid", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateCustomMetadata")
+                        .deserialize::<CreateCustomMetadataResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(CreateCustomMetadataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a folder with the specified name and parent folder.</p>
+    fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> RusotoFuture<CreateFolderResponse, CreateFolderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/folders";
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateFolder");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFolder")
+                        .deserialize::<CreateFolderResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFolderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds the specified list of labels to the given resource (a document or folder)</p>
+    fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> RusotoFuture<CreateLabelsResponse, CreateLabelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/labels",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateLabels");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateLabels")
+                        .deserialize::<CreateLabelsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateLabelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Configure Amazon WorkDocs to use Amazon SNS notifications. The endpoint receives a confirmation message, and must confirm the subscription.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/subscribe-notifications.html">Subscribe to Notifications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
+    fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> RusotoFuture<CreateNotificationSubscriptionResponse, CreateNotificationSubscriptionError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/organizations/{organization_id}/subscriptions",
+            organization_id = input.organization_id
+        );
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateNotificationSubscription");
+        request.add_resource_id("OrganizationId", &input.organization_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateNotificationSubscription")
+                        .deserialize::<CreateNotificationSubscriptionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(CreateNotificationSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a user in a Simple AD or Microsoft AD directory. The status of a newly created user is "ACTIVE". New users can access Amazon WorkDocs.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/users";
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("CreateUser");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateUser")
+                        .deserialize::<CreateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deactivates the specified user, which revokes the user's access to Amazon WorkDocs.</p>
+    fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> RusotoFuture<(), DeactivateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/users/{user_id}/activation",
+            user_id = input.user_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeactivateUser");
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeactivateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified comment from the document version.</p>
+    fn delete_comment(&self, input: DeleteCommentRequest) -> RusotoFuture<(), DeleteCommentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions/{version_id}/comment/{comment_id}",
+            comment_id = input.comment_id,
+            document_id = input.document_id,
+            version_id = input.version_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteComment");
+        request.add_resource_id("CommentId", &input.comment_id.to_string());
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.add_resource_id("VersionId", &input.version_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteCommentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes custom metadata from the specified resource.</p>
+    fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> RusotoFuture<DeleteCustomMetadataResponse, DeleteCustomMetadataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/customMetadata",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteCustomMetadata");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.delete_all {
+            params.put("deleteAll", x);
+        }
+        if let Some(ref x) = input.keys {
+            for item in x.iter() {
+                params.put("keys", item);
+            }
+        }
+        if let Some(ref x) = input.version_id {
+            params.put("versionId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteCustomMetadata")
+                        .deserialize::<DeleteCustomMetadataResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteCustomMetadataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Permanently deletes the specified document and its associated metadata.</p>
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<(), DeleteDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}",
+            document_id = input.document_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteDocument");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Permanently deletes the specified folder and its contents.</p>
+    fn delete_folder(&self, input: DeleteFolderRequest) -> RusotoFuture<(), DeleteFolderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolder");
+        request.add_resource_id("FolderId", &input.folder_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFolderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the contents of the specified folder.</p>
+    fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> RusotoFuture<(), DeleteFolderContentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/folders/{folder_id}/contents",
+            folder_id = input.folder_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteFolderContents");
+        request.add_resource_id("FolderId", &input.folder_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteFolderContentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified list of labels from a resource.</p>
+    fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> RusotoFuture<DeleteLabelsResponse, DeleteLabelsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/labels",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteLabels");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.delete_all {
+            params.put("deleteAll", x);
+        }
+        if let Some(ref x) = input.labels {
+            for item in x.iter() {
+                params.put("labels", item);
+            }
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteLabels")
+                        .deserialize::<DeleteLabelsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteLabelsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified subscription from the specified organization.</p>
+    fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> RusotoFuture<(), DeleteNotificationSubscriptionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/organizations/{organization_id}/subscriptions/{subscription_id}",
+            organization_id = input.organization_id,
+            subscription_id = input.subscription_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationSubscription");
+        request.add_resource_id("OrganizationId", &input.organization_id.to_string());
+        request.add_resource_id("SubscriptionId", &input.subscription_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteNotificationSubscriptionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified user from a Simple AD or Microsoft AD directory.</p>
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the user activities in a specified time period.</p>
+    fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> RusotoFuture<DescribeActivitiesResponse, DescribeActivitiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/activities";
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeActivities");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.activity_types {
+            params.put("activityTypes", x);
+        }
+        if let Some(ref x) = input.end_time {
+            params.put("endTime", x);
+        }
+        if let Some(ref x) = input.include_indirect_activities {
+            params.put("includeIndirectActivities", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.organization_id {
+            params.put("organizationId", x);
+        }
+        if let Some(ref x) = input.resource_id {
+            params.put("resourceId", x);
+        }
+        if let Some(ref x) = input.start_time {
+            params.put("startTime", x);
+        }
+        if let Some(ref x) = input.user_id {
+            params.put("userId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeActivities")
+                        .deserialize::<DescribeActivitiesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeActivitiesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>List all the comments for the specified document version.</p>
+    fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> RusotoFuture<DescribeCommentsResponse, DescribeCommentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions/{version_id}/comments",
+            document_id = input.document_id,
+            version_id = input.version_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeComments");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.add_resource_id("VersionId", &input.version_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeComments")
+                        .deserialize::<DescribeCommentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeCommentsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the document versions for the specified document.</p> <p>By default, only active versions are returned.</p>
+    fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> RusotoFuture<DescribeDocumentVersionsResponse, DescribeDocumentVersionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions",
+            document_id = input.document_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeDocumentVersions");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.fields {
+            params.put("fields", x);
+        }
+        if let Some(ref x) = input.include {
+            params.put("include", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDocumentVersions")
+                        .deserialize::<DescribeDocumentVersionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDocumentVersionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the contents of the specified folder, including its documents and subfolders.</p> <p>By default, Amazon WorkDocs returns the first 100 active document and folder metadata items. If there are more results, the response includes a marker that you can use to request the next set of results. You can also request initialized documents.</p>
+    fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> RusotoFuture<DescribeFolderContentsResponse, DescribeFolderContentsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/folders/{folder_id}/contents",
+            folder_id = input.folder_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeFolderContents");
+        request.add_resource_id("FolderId", &input.folder_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.include {
+            params.put("include", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.order {
+            params.put("order", x);
+        }
+        if let Some(ref x) = input.sort {
+            params.put("sort", x);
+        }
+        if let Some(ref x) = input.type_ {
+            params.put("type", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeFolderContents")
+                        .deserialize::<DescribeFolderContentsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeFolderContentsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the groups specified by the query. Groups are defined by the underlying Active Directory.</p>
+    fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> RusotoFuture<DescribeGroupsResponse, DescribeGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/groups";
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeGroups");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.organization_id {
+            params.put("organizationId", x);
+        }
+        params.put("searchQuery", &input.search_query);
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeGroups")
+                        .deserialize::<DescribeGroupsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the specified notification subscriptions.</p>
+    fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> RusotoFuture<
+        DescribeNotificationSubscriptionsResponse,
+        DescribeNotificationSubscriptionsError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/organizations/{organization_id}/subscriptions",
+            organization_id = input.organization_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationSubscriptions");
+        request.add_resource_id("OrganizationId", &input.organization_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeNotificationSubscriptions")
+                        .deserialize::<DescribeNotificationSubscriptionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeNotificationSubscriptionsError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the permissions of a specified resource.</p>
+    fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> RusotoFuture<DescribeResourcePermissionsResponse, DescribeResourcePermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/permissions",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeResourcePermissions");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.principal_id {
+            params.put("principalId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeResourcePermissions")
+                        .deserialize::<DescribeResourcePermissionsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeResourcePermissionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the current user's special folders; the <code>RootFolder</code> and the <code>RecycleBin</code>. <code>RootFolder</code> is the root of user's files and folders and <code>RecycleBin</code> is the root of recycled items. This is not a valid action for SigV4 (administrative API) clients.</p> <p>This action requires an authentication token. To get an authentication token, register an application with Amazon WorkDocs. For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/wd-auth-user.html">Authentication and Access Control for User Applications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
+    fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> RusotoFuture<DescribeRootFoldersResponse, DescribeRootFoldersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/me/root";
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeRootFolders");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("Authentication", &input.authentication_token);
+        let mut params = Params::new();
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeRootFolders")
+                        .deserialize::<DescribeRootFoldersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeRootFoldersError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the specified users. You can describe all users or filter the results (for example, by status or organization).</p> <p>By default, Amazon WorkDocs returns the first 24 active or pending users. If there are more results, the response includes a marker that you can use to request the next set of results.</p>
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResponse, DescribeUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/users";
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("DescribeUsers");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.fields {
+            params.put("fields", x);
+        }
+        if let Some(ref x) = input.include {
+            params.put("include", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.order {
+            params.put("order", x);
+        }
+        if let Some(ref x) = input.organization_id {
+            params.put("organizationId", x);
+        }
+        if let Some(ref x) = input.query {
+            params.put("query", x);
+        }
+        if let Some(ref x) = input.sort {
+            params.put("sort", x);
+        }
+        if let Some(ref x) = input.user_ids {
+            params.put("userIds", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeUsers")
+                        .deserialize::<DescribeUsersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details of the current user for whom the authentication token was generated. This is not a valid action for SigV4 (administrative API) clients.</p>
+    fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> RusotoFuture<GetCurrentUserResponse, GetCurrentUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/me";
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetCurrentUser");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.add_header("Authentication", &input.authentication_token);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetCurrentUser")
+                        .deserialize::<GetCurrentUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetCurrentUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves details of a document.</p>
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResponse, GetDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}",
+            document_id = input.document_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocument");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.include_custom_metadata {
+            params.put("includeCustomMetadata", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocument")
+                        .deserialize::<GetDocumentResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the path information (the hierarchy from the root folder) for the requested document.</p> <p>By default, Amazon WorkDocs returns a maximum of 100 levels upwards from the requested document and only includes the IDs of the parent folders in the path. You can limit the maximum number of levels. You can also request the names of the parent folders.</p>
+    fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> RusotoFuture<GetDocumentPathResponse, GetDocumentPathError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/path",
+            document_id = input.document_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentPath");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.fields {
+            params.put("fields", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocumentPath")
+                        .deserialize::<GetDocumentPathResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDocumentPathError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves version metadata for the specified document.</p>
+    fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> RusotoFuture<GetDocumentVersionResponse, GetDocumentVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions/{version_id}",
+            document_id = input.document_id,
+            version_id = input.version_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetDocumentVersion");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.add_resource_id("VersionId", &input.version_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.fields {
+            params.put("fields", x);
+        }
+        if let Some(ref x) = input.include_custom_metadata {
+            params.put("includeCustomMetadata", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetDocumentVersion")
+                        .deserialize::<GetDocumentVersionResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetDocumentVersionError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the metadata of the specified folder.</p>
+    fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> RusotoFuture<GetFolderResponse, GetFolderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolder");
+        request.add_resource_id("FolderId", &input.folder_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.include_custom_metadata {
+            params.put("includeCustomMetadata", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFolder")
+                        .deserialize::<GetFolderResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFolderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves the path information (the hierarchy from the root folder) for the specified folder.</p> <p>By default, Amazon WorkDocs returns a maximum of 100 levels upwards from the requested folder and only includes the IDs of the parent folders in the path. You can limit the maximum number of levels. You can also request the parent folder names.</p>
+    fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> RusotoFuture<GetFolderPathResponse, GetFolderPathError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/folders/{folder_id}/path",
+            folder_id = input.folder_id
+        );
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetFolderPath");
+        request.add_resource_id("FolderId", &input.folder_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.fields {
+            params.put("fields", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetFolderPath")
+                        .deserialize::<GetFolderPathResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetFolderPathError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a collection of resources, including folders and documents. The only <code>CollectionType</code> supported is <code>SHARED_WITH_ME</code>.</p>
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<GetResourcesResponse, GetResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/resources";
+
+        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation("GetResources");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.collection_type {
+            params.put("collectionType", x);
+        }
+        if let Some(ref x) = input.limit {
+            params.put("limit", x);
+        }
+        if let Some(ref x) = input.marker {
+            params.put("marker", x);
+        }
+        if let Some(ref x) = input.user_id {
+            params.put("userId", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetResources")
+                        .deserialize::<GetResourcesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetResourcesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new document object and version object.</p> <p>The client specifies the parent folder ID and name of the document to upload. The ID is optionally specified when creating a new version of an existing document. This is the first step to upload a document. Next, upload the document to the URL returned from the call, and then call <a>UpdateDocumentVersion</a>.</p> <p>To cancel the document upload, call <a>AbortDocumentVersionUpload</a>.</p>
+    fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> RusotoFuture<InitiateDocumentVersionUploadResponse, InitiateDocumentVersionUploadError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/api/v1/documents";
+
+        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation("InitiateDocumentVersionUpload");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 201 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("InitiateDocumentVersionUpload")
+                        .deserialize::<InitiateDocumentVersionUploadResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(InitiateDocumentVersionUploadError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes all the permissions from the specified resource.</p>
+    fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> RusotoFuture<(), RemoveAllResourcePermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/permissions",
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveAllResourcePermissions");
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveAllResourcePermissionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes the permission for the specified principal from the specified resource.</p>
+    fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> RusotoFuture<(), RemoveResourcePermissionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/resources/{resource_id}/permissions/{principal_id}",
+            principal_id = input.principal_id,
+            resource_id = input.resource_id
+        );
+
+        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation("RemoveResourcePermission");
+        request.add_resource_id("PrincipalId", &input.principal_id.to_string());
+        request.add_resource_id("ResourceId", &input.resource_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+        let mut params = Params::new();
+        if let Some(ref x) = input.principal_type {
+            params.put("type", x);
+        }
+        request.set_params(params);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 204 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(RemoveResourcePermissionError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the specified attributes of a document. The user must have access to both the document and its parent folder, if applicable.</p>
+    fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> RusotoFuture<(), UpdateDocumentError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}",
+            document_id = input.document_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocument");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateDocumentError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Changes the status of the document version to ACTIVE. </p> <p>Amazon WorkDocs also sets its document container to ACTIVE. This is the last step in a document upload, after the client uploads the document to an S3-presigned URL returned by <a>InitiateDocumentVersionUpload</a>. </p>
+    fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> RusotoFuture<(), UpdateDocumentVersionError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!(
+            "/api/v1/documents/{document_id}/versions/{version_id}",
+            document_id = input.document_id,
+            version_id = input.version_id
+        );
+
+        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentVersion");
+        request.add_resource_id("DocumentId", &input.document_id.to_string());
+        request.add_resource_id("VersionId", &input.version_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDocumentVersionError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified attributes of the specified folder. The user must have access to both the folder and its parent folder, if applicable.</p>
+    fn update_folder(&self, input: UpdateFolderRequest) -> RusotoFuture<(), UpdateFolderError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
+
+        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateFolder");
+        request.add_resource_id("FolderId", &input.folder_id.to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = ::std::mem::drop(response);
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateFolderError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the specified attributes of the specified user, and grants or revokes administrative privileges to the Amazon WorkDocs site.</p>
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
+
+        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
+        request.add_resource_id("UserId", &input.user_id.to_string());
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        if let Some(ref authentication_token) = input.authentication_token {
+            request.add_header("Authentication", &authentication_token.to_string());
+        }
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.as_u16() == 200 {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateUser")
+                        .deserialize::<UpdateUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon WorkDocs API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockWorkdocs {
+    abort_document_version_upload: Option<
+        Box<
+            dyn Fn(
+                    AbortDocumentVersionUploadRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<AbortDocumentVersionUploadError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    activate_user: Option<
+        Box<
+            dyn Fn(
+                    ActivateUserRequest,
+                )
+                    -> ::std::result::Result<ActivateUserResponse, RusotoError<ActivateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    add_resource_permissions: Option<
+        Box<
+            dyn Fn(
+                    AddResourcePermissionsRequest,
+                ) -> ::std::result::Result<
+                    AddResourcePermissionsResponse,
+                    RusotoError<AddResourcePermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_comment: Option<
+        Box<
+            dyn Fn(
+                    CreateCommentRequest,
+                )
+                    -> ::std::result::Result<CreateCommentResponse, RusotoError<CreateCommentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_custom_metadata: Option<
+        Box<
+            dyn Fn(
+                    CreateCustomMetadataRequest,
+                ) -> ::std::result::Result<
+                    CreateCustomMetadataResponse,
+                    RusotoError<CreateCustomMetadataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_folder: Option<
+        Box<
+            dyn Fn(
+                    CreateFolderRequest,
+                )
+                    -> ::std::result::Result<CreateFolderResponse, RusotoError<CreateFolderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_labels: Option<
+        Box<
+            dyn Fn(
+                    CreateLabelsRequest,
+                )
+                    -> ::std::result::Result<CreateLabelsResponse, RusotoError<CreateLabelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_notification_subscription: Option<
+        Box<
+            dyn Fn(
+                    CreateNotificationSubscriptionRequest,
+                ) -> ::std::result::Result<
+                    CreateNotificationSubscriptionResponse,
+                    RusotoError<CreateNotificationSubscriptionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deactivate_user: Option<
+        Box<
+            dyn Fn(
+                    DeactivateUserRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeactivateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_comment: Option<
+        Box<
+            dyn Fn(
+                    DeleteCommentRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteCommentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_custom_metadata: Option<
+        Box<
+            dyn Fn(
+                    DeleteCustomMetadataRequest,
+                ) -> ::std::result::Result<
+                    DeleteCustomMetadataResponse,
+                    RusotoError<DeleteCustomMetadataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_document: Option<
+        Box<
+            dyn Fn(
+                    DeleteDocumentRequest,
+                ) -> ::std::result::Result<(), RusotoError<DeleteDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_folder: Option<
+        Box<
+            dyn Fn(DeleteFolderRequest) -> ::std::result::Result<(), RusotoError<DeleteFolderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_folder_contents: Option<
+        Box<
+            dyn Fn(
+                    DeleteFolderContentsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteFolderContentsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_labels: Option<
+        Box<
+            dyn Fn(
+                    DeleteLabelsRequest,
+                )
+                    -> ::std::result::Result<DeleteLabelsResponse, RusotoError<DeleteLabelsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_notification_subscription: Option<
+        Box<
+            dyn Fn(
+                    DeleteNotificationSubscriptionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<DeleteNotificationSubscriptionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_activities: Option<
+        Box<
+            dyn Fn(
+                    DescribeActivitiesRequest,
+                ) -> ::std::result::Result<
+                    DescribeActivitiesResponse,
+                    RusotoError<DescribeActivitiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_comments: Option<
+        Box<
+            dyn Fn(
+                    DescribeCommentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeCommentsResponse,
+                    RusotoError<DescribeCommentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_document_versions: Option<
+        Box<
+            dyn Fn(
+                    DescribeDocumentVersionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeDocumentVersionsResponse,
+                    RusotoError<DescribeDocumentVersionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_folder_contents: Option<
+        Box<
+            dyn Fn(
+                    DescribeFolderContentsRequest,
+                ) -> ::std::result::Result<
+                    DescribeFolderContentsResponse,
+                    RusotoError<DescribeFolderContentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeGroupsResponse,
+                    RusotoError<DescribeGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_notification_subscriptions: Option<
+        Box<
+            dyn Fn(
+                    DescribeNotificationSubscriptionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeNotificationSubscriptionsResponse,
+                    RusotoError<DescribeNotificationSubscriptionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_resource_permissions: Option<
+        Box<
+            dyn Fn(
+                    DescribeResourcePermissionsRequest,
+                ) -> ::std::result::Result<
+                    DescribeResourcePermissionsResponse,
+                    RusotoError<DescribeResourcePermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_root_folders: Option<
+        Box<
+            dyn Fn(
+                    DescribeRootFoldersRequest,
+                ) -> ::std::result::Result<
+                    DescribeRootFoldersResponse,
+                    RusotoError<DescribeRootFoldersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_users: Option<
+        Box<
+            dyn Fn(
+                    DescribeUsersRequest,
+                )
+                    -> ::std::result::Result<DescribeUsersResponse, RusotoError<DescribeUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_current_user: Option<
+        Box<
+            dyn Fn(
+                    GetCurrentUserRequest,
+                ) -> ::std::result::Result<
+                    GetCurrentUserResponse,
+                    RusotoError<GetCurrentUserError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_document: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentRequest,
+                )
+                    -> ::std::result::Result<GetDocumentResponse, RusotoError<GetDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_document_path: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentPathRequest,
+                ) -> ::std::result::Result<
+                    GetDocumentPathResponse,
+                    RusotoError<GetDocumentPathError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_document_version: Option<
+        Box<
+            dyn Fn(
+                    GetDocumentVersionRequest,
+                ) -> ::std::result::Result<
+                    GetDocumentVersionResponse,
+                    RusotoError<GetDocumentVersionError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_folder: Option<
+        Box<
+            dyn Fn(
+                    GetFolderRequest,
+                )
+                    -> ::std::result::Result<GetFolderResponse, RusotoError<GetFolderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_folder_path: Option<
+        Box<
+            dyn Fn(
+                    GetFolderPathRequest,
+                )
+                    -> ::std::result::Result<GetFolderPathResponse, RusotoError<GetFolderPathError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_resources: Option<
+        Box<
+            dyn Fn(
+                    GetResourcesRequest,
+                )
+                    -> ::std::result::Result<GetResourcesResponse, RusotoError<GetResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    initiate_document_version_upload: Option<
+        Box<
+            dyn Fn(
+                    InitiateDocumentVersionUploadRequest,
+                ) -> ::std::result::Result<
+                    InitiateDocumentVersionUploadResponse,
+                    RusotoError<InitiateDocumentVersionUploadError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    remove_all_resource_permissions: Option<
+        Box<
+            dyn Fn(
+                    RemoveAllResourcePermissionsRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveAllResourcePermissionsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    remove_resource_permission: Option<
+        Box<
+            dyn Fn(
+                    RemoveResourcePermissionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<RemoveResourcePermissionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_document: Option<
+        Box<
+            dyn Fn(
+                    UpdateDocumentRequest,
+                ) -> ::std::result::Result<(), RusotoError<UpdateDocumentError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_document_version: Option<
+        Box<
+            dyn Fn(
+                    UpdateDocumentVersionRequest,
+                )
+                    -> ::std::result::Result<(), RusotoError<UpdateDocumentVersionError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_folder: Option<
+        Box<
+            dyn Fn(UpdateFolderRequest) -> ::std::result::Result<(), RusotoError<UpdateFolderError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_user: Option<
+        Box<
+            dyn Fn(
+                    UpdateUserRequest,
+                )
+                    -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockWorkdocs {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockWorkdocs {
+        Default::default()
+    }
+
+    /// Answers `abort_document_version_upload` with what `handler` returns for its input.
+    pub fn with_abort_document_version_upload<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                AbortDocumentVersionUploadRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<AbortDocumentVersionUploadError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.abort_document_version_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `activate_user` with what `handler` returns for its input.
+    pub fn with_activate_user<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                ActivateUserRequest,
+            )
+                -> ::std::result::Result<ActivateUserResponse, RusotoError<ActivateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.activate_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `add_resource_permissions` with what `handler` returns for its input.
+    pub fn with_add_resource_permissions<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                AddResourcePermissionsRequest,
+            ) -> ::std::result::Result<
+                AddResourcePermissionsResponse,
+                RusotoError<AddResourcePermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.add_resource_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_comment` with what `handler` returns for its input.
+    pub fn with_create_comment<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                CreateCommentRequest,
+            )
+                -> ::std::result::Result<CreateCommentResponse, RusotoError<CreateCommentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_comment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_custom_metadata` with what `handler` returns for its input.
+    pub fn with_create_custom_metadata<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                CreateCustomMetadataRequest,
+            ) -> ::std::result::Result<
+                CreateCustomMetadataResponse,
+                RusotoError<CreateCustomMetadataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_custom_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_folder` with what `handler` returns for its input.
+    pub fn with_create_folder<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                CreateFolderRequest,
+            )
+                -> ::std::result::Result<CreateFolderResponse, RusotoError<CreateFolderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_folder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_labels` with what `handler` returns for its input.
+    pub fn with_create_labels<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                CreateLabelsRequest,
+            )
+                -> ::std::result::Result<CreateLabelsResponse, RusotoError<CreateLabelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_labels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_notification_subscription` with what `handler` returns for its input.
+    pub fn with_create_notification_subscription<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                CreateNotificationSubscriptionRequest,
+            ) -> ::std::result::Result<
+                CreateNotificationSubscriptionResponse,
+                RusotoError<CreateNotificationSubscriptionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_notification_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                CreateUserRequest,
+            )
+                -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deactivate_user` with what `handler` returns for its input.
+    pub fn with_deactivate_user<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(DeactivateUserRequest) -> ::std::result::Result<(), RusotoError<DeactivateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deactivate_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_comment` with what `handler` returns for its input.
+    pub fn with_delete_comment<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(DeleteCommentRequest) -> ::std::result::Result<(), RusotoError<DeleteCommentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_comment = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_custom_metadata` with what `handler` returns for its input.
+    pub fn with_delete_custom_metadata<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DeleteCustomMetadataRequest,
+            ) -> ::std::result::Result<
+                DeleteCustomMetadataResponse,
+                RusotoError<DeleteCustomMetadataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_custom_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_document` with what `handler` returns for its input.
+    pub fn with_delete_document<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(DeleteDocumentRequest) -> ::std::result::Result<(), RusotoError<DeleteDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_folder` with what `handler` returns for its input.
+    pub fn with_delete_folder<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(DeleteFolderRequest) -> ::std::result::Result<(), RusotoError<DeleteFolderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_folder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_folder_contents` with what `handler` returns for its input.
+    pub fn with_delete_folder_contents<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DeleteFolderContentsRequest,
+            ) -> ::std::result::Result<(), RusotoError<DeleteFolderContentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_folder_contents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_labels` with what `handler` returns for its input.
+    pub fn with_delete_labels<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DeleteLabelsRequest,
+            )
+                -> ::std::result::Result<DeleteLabelsResponse, RusotoError<DeleteLabelsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_labels = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_notification_subscription` with what `handler` returns for its input.
+    pub fn with_delete_notification_subscription<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DeleteNotificationSubscriptionRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<DeleteNotificationSubscriptionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_notification_subscription = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(DeleteUserRequest) -> ::std::result::Result<(), RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_activities` with what `handler` returns for its input.
+    pub fn with_describe_activities<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeActivitiesRequest,
+            ) -> ::std::result::Result<
+                DescribeActivitiesResponse,
+                RusotoError<DescribeActivitiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_activities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_comments` with what `handler` returns for its input.
+    pub fn with_describe_comments<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeCommentsRequest,
+            ) -> ::std::result::Result<
+                DescribeCommentsResponse,
+                RusotoError<DescribeCommentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_comments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_document_versions` with what `handler` returns for its input.
+    pub fn with_describe_document_versions<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeDocumentVersionsRequest,
+            ) -> ::std::result::Result<
+                DescribeDocumentVersionsResponse,
+                RusotoError<DescribeDocumentVersionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_document_versions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_folder_contents` with what `handler` returns for its input.
+    pub fn with_describe_folder_contents<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeFolderContentsRequest,
+            ) -> ::std::result::Result<
+                DescribeFolderContentsResponse,
+                RusotoError<DescribeFolderContentsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_folder_contents = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_groups` with what `handler` returns for its input.
+    pub fn with_describe_groups<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeGroupsRequest,
+            )
+                -> ::std::result::Result<DescribeGroupsResponse, RusotoError<DescribeGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_notification_subscriptions` with what `handler` returns for its input.
+    pub fn with_describe_notification_subscriptions<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeNotificationSubscriptionsRequest,
+            ) -> ::std::result::Result<
+                DescribeNotificationSubscriptionsResponse,
+                RusotoError<DescribeNotificationSubscriptionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_notification_subscriptions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_resource_permissions` with what `handler` returns for its input.
+    pub fn with_describe_resource_permissions<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeResourcePermissionsRequest,
+            ) -> ::std::result::Result<
+                DescribeResourcePermissionsResponse,
+                RusotoError<DescribeResourcePermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_resource_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_root_folders` with what `handler` returns for its input.
+    pub fn with_describe_root_folders<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeRootFoldersRequest,
+            ) -> ::std::result::Result<
+                DescribeRootFoldersResponse,
+                RusotoError<DescribeRootFoldersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_root_folders = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_users` with what `handler` returns for its input.
+    pub fn with_describe_users<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                DescribeUsersRequest,
+            )
+                -> ::std::result::Result<DescribeUsersResponse, RusotoError<DescribeUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_current_user` with what `handler` returns for its input.
+    pub fn with_get_current_user<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetCurrentUserRequest,
+            )
+                -> ::std::result::Result<GetCurrentUserResponse, RusotoError<GetCurrentUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_current_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_document` with what `handler` returns for its input.
+    pub fn with_get_document<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetDocumentRequest,
+            )
+                -> ::std::result::Result<GetDocumentResponse, RusotoError<GetDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_document_path` with what `handler` returns for its input.
+    pub fn with_get_document_path<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetDocumentPathRequest,
+            )
+                -> ::std::result::Result<GetDocumentPathResponse, RusotoError<GetDocumentPathError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_document_path = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_document_version` with what `handler` returns for its input.
+    pub fn with_get_document_version<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetDocumentVersionRequest,
+            ) -> ::std::result::Result<
+                GetDocumentVersionResponse,
+                RusotoError<GetDocumentVersionError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_document_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_folder` with what `handler` returns for its input.
+    pub fn with_get_folder<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetFolderRequest,
+            ) -> ::std::result::Result<GetFolderResponse, RusotoError<GetFolderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_folder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_folder_path` with what `handler` returns for its input.
+    pub fn with_get_folder_path<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetFolderPathRequest,
+            )
+                -> ::std::result::Result<GetFolderPathResponse, RusotoError<GetFolderPathError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_folder_path = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_resources` with what `handler` returns for its input.
+    pub fn with_get_resources<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                GetResourcesRequest,
+            )
+                -> ::std::result::Result<GetResourcesResponse, RusotoError<GetResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `initiate_document_version_upload` with what `handler` returns for its input.
+    pub fn with_initiate_document_version_upload<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                InitiateDocumentVersionUploadRequest,
+            ) -> ::std::result::Result<
+                InitiateDocumentVersionUploadResponse,
+                RusotoError<InitiateDocumentVersionUploadError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.initiate_document_version_upload = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_all_resource_permissions` with what `handler` returns for its input.
+    pub fn with_remove_all_resource_permissions<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                RemoveAllResourcePermissionsRequest,
+            )
+                -> ::std::result::Result<(), RusotoError<RemoveAllResourcePermissionsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_all_resource_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `remove_resource_permission` with what `handler` returns for its input.
+    pub fn with_remove_resource_permission<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                RemoveResourcePermissionRequest,
+            ) -> ::std::result::Result<(), RusotoError<RemoveResourcePermissionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.remove_resource_permission = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_document` with what `handler` returns for its input.
+    pub fn with_update_document<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(UpdateDocumentRequest) -> ::std::result::Result<(), RusotoError<UpdateDocumentError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_document = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_document_version` with what `handler` returns for its input.
+    pub fn with_update_document_version<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                UpdateDocumentVersionRequest,
+            ) -> ::std::result::Result<(), RusotoError<UpdateDocumentVersionError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_document_version = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_folder` with what `handler` returns for its input.
+    pub fn with_update_folder<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(UpdateFolderRequest) -> ::std::result::Result<(), RusotoError<UpdateFolderError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_folder = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_user` with what `handler` returns for its input.
+    pub fn with_update_user<F>(mut self, handler: F) -> MockWorkdocs
+    where
+        F: Fn(
+                UpdateUserRequest,
+            )
+                -> ::std::result::Result<UpdateUserResponse, RusotoError<UpdateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_user = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Workdocs for MockWorkdocs {
+    fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> RusotoFuture<(), AbortDocumentVersionUploadError> {
+        match self.abort_document_version_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for abort_document_version_upload"),
+        }
+    }
+    fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> RusotoFuture<ActivateUserResponse, ActivateUserError> {
+        match self.activate_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for activate_user"),
+        }
+    }
+    fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> RusotoFuture<AddResourcePermissionsResponse, AddResourcePermissionsError> {
+        match self.add_resource_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for add_resource_permissions"),
+        }
+    }
+    fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> RusotoFuture<CreateCommentResponse, CreateCommentError> {
+        match self.create_comment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for create_comment"),
+        }
+    }
+    fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> RusotoFuture<CreateCustomMetadataResponse, CreateCustomMetadataError> {
+        match self.create_custom_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for create_custom_metadata"),
+        }
+    }
+    fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> RusotoFuture<CreateFolderResponse, CreateFolderError> {
+        match self.create_folder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for create_folder"),
+        }
+    }
+    fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> RusotoFuture<CreateLabelsResponse, CreateLabelsError> {
+        match self.create_labels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for create_labels"),
+        }
+    }
+    fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> RusotoFuture<CreateNotificationSubscriptionResponse, CreateNotificationSubscriptionError>
+    {
+        match self.create_notification_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for create_notification_subscription"),
+        }
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for create_user"),
+        }
+    }
+    fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> RusotoFuture<(), DeactivateUserError> {
+        match self.deactivate_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for deactivate_user"),
+        }
+    }
+    fn delete_comment(&self, input: DeleteCommentRequest) -> RusotoFuture<(), DeleteCommentError> {
+        match self.delete_comment {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_comment"),
+        }
+    }
+    fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> RusotoFuture<DeleteCustomMetadataResponse, DeleteCustomMetadataError> {
+        match self.delete_custom_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_custom_metadata"),
+        }
+    }
+    fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> RusotoFuture<(), DeleteDocumentError> {
+        match self.delete_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_document"),
+        }
+    }
+    fn delete_folder(&self, input: DeleteFolderRequest) -> RusotoFuture<(), DeleteFolderError> {
+        match self.delete_folder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_folder"),
+        }
+    }
+    fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> RusotoFuture<(), DeleteFolderContentsError> {
+        match self.delete_folder_contents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_folder_contents"),
+        }
+    }
+    fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> RusotoFuture<DeleteLabelsResponse, DeleteLabelsError> {
+        match self.delete_labels {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_labels"),
+        }
+    }
+    fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> RusotoFuture<(), DeleteNotificationSubscriptionError> {
+        match self.delete_notification_subscription {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_notification_subscription"),
+        }
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for delete_user"),
+        }
+    }
+    fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> RusotoFuture<DescribeActivitiesResponse, DescribeActivitiesError> {
+        match self.describe_activities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_activities"),
+        }
+    }
+    fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> RusotoFuture<DescribeCommentsResponse, DescribeCommentsError> {
+        match self.describe_comments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_comments"),
+        }
+    }
+    fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> RusotoFuture<DescribeDocumentVersionsResponse, DescribeDocumentVersionsError> {
+        match self.describe_document_versions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_document_versions"),
+        }
+    }
+    fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> RusotoFuture<DescribeFolderContentsResponse, DescribeFolderContentsError> {
+        match self.describe_folder_contents {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_folder_contents"),
+        }
+    }
+    fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> RusotoFuture<DescribeGroupsResponse, DescribeGroupsError> {
+        match self.describe_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_groups"),
+        }
+    }
+    fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> RusotoFuture<
+        DescribeNotificationSubscriptionsResponse,
+        DescribeNotificationSubscriptionsError,
+    > {
+        match self.describe_notification_subscriptions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_notification_subscriptions"),
+        }
+    }
+    fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> RusotoFuture<DescribeResourcePermissionsResponse, DescribeResourcePermissionsError> {
+        match self.describe_resource_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_resource_permissions"),
+        }
+    }
+    fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> RusotoFuture<DescribeRootFoldersResponse, DescribeRootFoldersError> {
+        match self.describe_root_folders {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_root_folders"),
+        }
+    }
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResponse, DescribeUsersError> {
+        match self.describe_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for describe_users"),
+        }
+    }
+    fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> RusotoFuture<GetCurrentUserResponse, GetCurrentUserError> {
+        match self.get_current_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_current_user"),
+        }
+    }
+    fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> RusotoFuture<GetDocumentResponse, GetDocumentError> {
+        match self.get_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_document"),
+        }
+    }
+    fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> RusotoFuture<GetDocumentPathResponse, GetDocumentPathError> {
+        match self.get_document_path {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_document_path"),
+        }
+    }
+    fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> RusotoFuture<GetDocumentVersionResponse, GetDocumentVersionError> {
+        match self.get_document_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_document_version"),
+        }
+    }
+    fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> RusotoFuture<GetFolderResponse, GetFolderError> {
+        match self.get_folder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_folder"),
+        }
+    }
+    fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> RusotoFuture<GetFolderPathResponse, GetFolderPathError> {
+        match self.get_folder_path {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_folder_path"),
+        }
     }
-
-    /// <p>Retrieves a collection of resources, including folders and documents. The only <code>CollectionType</code> supported is <code>SHARED_WITH_ME</code>.</p>
     fn get_resources(
         &self,
         input: GetResourcesRequest,
     ) -> RusotoFuture<GetResourcesResponse, GetResourcesError> {
-        let request_uri = "/api/v1/resources";
-
-        let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.get_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for get_resources"),
         }
-        let mut params = Params::new();
-        if let Some(ref x) = input.collection_type {
-            params.put("collectionType", x);
-        }
-        if let Some(ref x) = input.limit {
-            params.put("limit", x);
-        }
-        if let Some(ref x) = input.marker {
-            params.put("marker", x);
-        }
-        if let Some(ref x) = input.user_id {
-            params.put("userId", x);
-        }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetResourcesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetResourcesError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Creates a new document object and version object.</p> <p>The client specifies the parent folder ID and name of the document to upload. The ID is optionally specified when creating a new version of an existing document. This is the first step to upload a document. Next, upload the document to the URL returned from the call, and then call <a>UpdateDocumentVersion</a>.</p> <p>To cancel the document upload, call <a>AbortDocumentVersionUpload</a>.</p>
     fn initiate_document_version_upload(
         &self,
         input: InitiateDocumentVersionUploadRequest,
     ) -> RusotoFuture<InitiateDocumentVersionUploadResponse, InitiateDocumentVersionUploadError>
     {
-        let request_uri = "/api/v1/documents";
-
-        let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.initiate_document_version_upload {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for initiate_document_version_upload"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 201 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<InitiateDocumentVersionUploadResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(InitiateDocumentVersionUploadError::from_response(response))
-                }))
-            }
-        })
     }
-
-    /// <p>Removes all the permissions from the specified resource.</p>
     fn remove_all_resource_permissions(
         &self,
         input: RemoveAllResourcePermissionsRequest,
     ) -> RusotoFuture<(), RemoveAllResourcePermissionsError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/permissions",
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.remove_all_resource_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for remove_all_resource_permissions"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveAllResourcePermissionsError::from_response(response))
-                }))
-            }
-        })
     }
-
-    /// <p>Removes the permission for the specified principal from the specified resource.</p>
     fn remove_resource_permission(
         &self,
         input: RemoveResourcePermissionRequest,
     ) -> RusotoFuture<(), RemoveResourcePermissionError> {
-        let request_uri = format!(
-            "/api/v1/resources/{resource_id}/permissions/{principal_id}",
-            principal_id = input.principal_id,
-            resource_id = input.resource_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
-        }
-        let mut params = Params::new();
-        if let Some(ref x) = input.principal_type {
-            params.put("type", x);
+        match self.remove_resource_permission {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for remove_resource_permission"),
         }
-        request.set_params(params);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 204 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RemoveResourcePermissionError::from_response(response))
-                }))
-            }
-        })
     }
-
-    /// <p>Updates the specified attributes of a document. The user must have access to both the document and its parent folder, if applicable.</p>
     fn update_document(
         &self,
         input: UpdateDocumentRequest,
     ) -> RusotoFuture<(), UpdateDocumentError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}",
-            document_id = input.document_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.update_document {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for update_document"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateDocumentError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Changes the status of the document version to ACTIVE. </p> <p>Amazon WorkDocs also sets its document container to ACTIVE. This is the last step in a document upload, after the client uploads the document to an S3-presigned URL returned by <a>InitiateDocumentVersionUpload</a>. </p>
     fn update_document_version(
         &self,
         input: UpdateDocumentVersionRequest,
     ) -> RusotoFuture<(), UpdateDocumentVersionError> {
-        let request_uri = format!(
-            "/api/v1/documents/{document_id}/versions/{version_id}",
-            document_id = input.document_id,
-            version_id = input.version_id
-        );
-
-        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.update_document_version {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for update_document_version"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDocumentVersionError::from_response(response))
-                    }),
-                )
-            }
-        })
     }
-
-    /// <p>Updates the specified attributes of the specified folder. The user must have access to both the folder and its parent folder, if applicable.</p>
     fn update_folder(&self, input: UpdateFolderRequest) -> RusotoFuture<(), UpdateFolderError> {
-        let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
-
-        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.update_folder {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for update_folder"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = ::std::mem::drop(response);
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateFolderError::from_response(response))),
-                )
-            }
-        })
     }
-
-    /// <p>Updates the specified attributes of the specified user, and grants or revokes administrative privileges to the Amazon WorkDocs site.</p>
     fn update_user(
         &self,
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
-        let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
-
-        let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        if let Some(ref authentication_token) = input.authentication_token {
-            request.add_header("Authentication", &authentication_token.to_string());
+        match self.update_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkdocs has no handler for update_user"),
         }
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.as_u16() == 200 {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateUserError::from_response(response))),
-                )
-            }
-        })
     }
 }
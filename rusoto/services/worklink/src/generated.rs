@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDomainRequest {
     /// <p>The ARN of an issued ACM certificate that is valid for the domain being associated.</p>
     #[serde(rename = "AcmCertificateArn")]
@@ -40,11 +42,47 @@ pub struct AssociateDomainRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for AssociateDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "acm_certificate_arn"),
+            &self.acm_certificate_arn,
+            "arn:[\\w+=/,.@-]+:[\\w+=/,.@-]+:[\\w+=/,.@-]*:[0-9]+:[\\w+=,.@-]+(/[\\w+=/,.@-]+)*",
+        );
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDomainResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateWebsiteAuthorizationProviderRequest {
     /// <p>The authorization provider type.</p>
     #[serde(rename = "AuthorizationProviderType")]
@@ -58,8 +96,32 @@ pub struct AssociateWebsiteAuthorizationProviderRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for AssociateWebsiteAuthorizationProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.domain_name {
+            errors.check_length(
+                &field_path(path, "domain_name"),
+                value.chars().count(),
+                Some(1),
+                Some(253),
+            );
+            errors.check_pattern(
+                &field_path(path, "domain_name"),
+                &value,
+                "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateWebsiteAuthorizationProviderResponse {
     /// <p>A unique identifier for the authorization provider.</p>
     #[serde(rename = "AuthorizationProviderId")]
@@ -68,6 +130,7 @@ pub struct AssociateWebsiteAuthorizationProviderResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateWebsiteCertificateAuthorityRequest {
     /// <p>The root certificate of the CA.</p>
     #[serde(rename = "Certificate")]
@@ -81,8 +144,34 @@ pub struct AssociateWebsiteCertificateAuthorityRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for AssociateWebsiteCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "certificate"),
+            self.certificate.chars().count(),
+            Some(1),
+            Some(8192),
+        );
+        errors.check_pattern(&field_path(path, "certificate"), &self.certificate, "-{5}BEGIN CERTIFICATE-{5}\\u000D?\\u000A([A-Za-z0-9/+]{64}\\u000D?\\u000A)*[A-Za-z0-9/+]{1,64}={0,2}\\u000D?\\u000A-{5}END CERTIFICATE-{5}(\\u000D?\\u000A)?");
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateWebsiteCertificateAuthorityResponse {
     /// <p>A unique identifier for the CA.</p>
     #[serde(rename = "WebsiteCaId")]
@@ -91,6 +180,7 @@ pub struct AssociateWebsiteCertificateAuthorityResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateFleetRequest {
     /// <p>The fleet name to display.</p>
     #[serde(rename = "DisplayName")]
@@ -105,8 +195,32 @@ pub struct CreateFleetRequest {
     pub optimize_for_end_user_location: Option<bool>,
 }
 
+impl Validate for CreateFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "fleet_name"),
+            self.fleet_name.chars().count(),
+            Some(1),
+            Some(48),
+        );
+        errors.check_pattern(
+            &field_path(path, "fleet_name"),
+            &self.fleet_name,
+            "^[a-z0-9](?:[a-z0-9\\-]{0,46}[a-z0-9])?$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateFleetResponse {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -115,25 +229,49 @@ pub struct CreateFleetResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteFleetRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl Validate for DeleteFleetRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteFleetResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAuditStreamConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeAuditStreamConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAuditStreamConfigurationResponse {
     /// <p>The ARN of the Amazon Kinesis data stream that will receive the audit events.</p>
     #[serde(rename = "AuditStreamArn")]
@@ -142,14 +280,26 @@ pub struct DescribeAuditStreamConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeCompanyNetworkConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeCompanyNetworkConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeCompanyNetworkConfigurationResponse {
     /// <p>The security groups associated with access to the provided subnets.</p>
     #[serde(rename = "SecurityGroupIds")]
@@ -166,14 +316,26 @@ pub struct DescribeCompanyNetworkConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDevicePolicyConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeDevicePolicyConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDevicePolicyConfigurationResponse {
     /// <p>The certificate chain, including intermediate certificates and the root certificate authority certificate used to issue device certificates.</p>
     #[serde(rename = "DeviceCaCertificate")]
@@ -182,6 +344,7 @@ pub struct DescribeDevicePolicyConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDeviceRequest {
     /// <p>A unique identifier for a registered user's device.</p>
     #[serde(rename = "DeviceId")]
@@ -191,8 +354,25 @@ pub struct DescribeDeviceRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeDeviceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "device_id"),
+            self.device_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDeviceResponse {
     /// <p>The date that the device first signed in to Amazon WorkLink.</p>
     #[serde(rename = "FirstAccessedTime")]
@@ -233,6 +413,7 @@ pub struct DescribeDeviceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeDomainRequest {
     /// <p>The name of the domain.</p>
     #[serde(rename = "DomainName")]
@@ -242,8 +423,30 @@ pub struct DescribeDomainRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeDomainResponse {
     /// <p>The ARN of an issued ACM certificate that is valid for the domain being associated.</p>
     #[serde(rename = "AcmCertificateArn")]
@@ -268,14 +471,26 @@ pub struct DescribeDomainResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeFleetMetadataRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeFleetMetadataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeFleetMetadataResponse {
     /// <p>The identifier used by users to sign in to the Amazon WorkLink app.</p>
     #[serde(rename = "CompanyCode")]
@@ -308,14 +523,26 @@ pub struct DescribeFleetMetadataResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIdentityProviderConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
     pub fleet_arn: String,
 }
 
+impl Validate for DescribeIdentityProviderConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeIdentityProviderConfigurationResponse {
     /// <p>The SAML metadata document provided by the user’s identity provider.</p>
     #[serde(rename = "IdentityProviderSamlMetadata")]
@@ -332,6 +559,7 @@ pub struct DescribeIdentityProviderConfigurationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWebsiteCertificateAuthorityRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -341,8 +569,25 @@ pub struct DescribeWebsiteCertificateAuthorityRequest {
     pub website_ca_id: String,
 }
 
+impl Validate for DescribeWebsiteCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "website_ca_id"),
+            self.website_ca_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWebsiteCertificateAuthorityResponse {
     /// <p>The root certificate of the certificate authority.</p>
     #[serde(rename = "Certificate")]
@@ -360,7 +605,7 @@ pub struct DescribeWebsiteCertificateAuthorityResponse {
 
 /// <p>The summary of devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeviceSummary {
     /// <p>The ID of the device.</p>
     #[serde(rename = "DeviceId")]
@@ -373,6 +618,7 @@ pub struct DeviceSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDomainRequest {
     /// <p>The name of the domain.</p>
     #[serde(rename = "DomainName")]
@@ -382,11 +628,34 @@ pub struct DisassociateDomainRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for DisassociateDomainRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDomainResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateWebsiteAuthorizationProviderRequest {
     /// <p>A unique identifier for the authorization provider.</p>
     #[serde(rename = "AuthorizationProviderId")]
@@ -396,11 +665,29 @@ pub struct DisassociateWebsiteAuthorizationProviderRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for DisassociateWebsiteAuthorizationProviderRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "authorization_provider_id"),
+            self.authorization_provider_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateWebsiteAuthorizationProviderResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateWebsiteCertificateAuthorityRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -410,13 +697,30 @@ pub struct DisassociateWebsiteCertificateAuthorityRequest {
     pub website_ca_id: String,
 }
 
+impl Validate for DisassociateWebsiteCertificateAuthorityRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "website_ca_id"),
+            self.website_ca_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateWebsiteCertificateAuthorityResponse {}
 
 /// <p>The summary of the domain.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DomainSummary {
     /// <p>The time that the domain was created.</p>
     #[serde(rename = "CreatedTime")]
@@ -435,7 +739,7 @@ pub struct DomainSummary {
 
 /// <p>The summary of the fleet.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FleetSummary {
     /// <p>The identifier used by users to sign into the Amazon WorkLink app.</p>
     #[serde(rename = "CompanyCode")]
@@ -468,6 +772,7 @@ pub struct FleetSummary {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDevicesRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -482,8 +787,31 @@ pub struct ListDevicesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDevicesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\w\\-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDevicesResponse {
     /// <p>Information about the devices.</p>
     #[serde(rename = "Devices")]
@@ -496,6 +824,7 @@ pub struct ListDevicesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListDomainsRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -510,8 +839,31 @@ pub struct ListDomainsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListDomainsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\w\\-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListDomainsResponse {
     /// <p>Information about the domains.</p>
     #[serde(rename = "Domains")]
@@ -524,6 +876,7 @@ pub struct ListDomainsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListFleetsRequest {
     /// <p>The maximum number of results to be included in the next page.</p>
     #[serde(rename = "MaxResults")]
@@ -535,8 +888,25 @@ pub struct ListFleetsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListFleetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\w\\-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListFleetsResponse {
     /// <p>The summary list of the fleets.</p>
     #[serde(rename = "FleetSummaryList")]
@@ -549,6 +919,7 @@ pub struct ListFleetsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWebsiteAuthorizationProvidersRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -563,8 +934,31 @@ pub struct ListWebsiteAuthorizationProvidersRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWebsiteAuthorizationProvidersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\w\\-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWebsiteAuthorizationProvidersResponse {
     /// <p>The pagination token to use to retrieve the next page of results for this operation. If this value is null, it retrieves the first page.</p>
     #[serde(rename = "NextToken")]
@@ -577,6 +971,7 @@ pub struct ListWebsiteAuthorizationProvidersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListWebsiteCertificateAuthoritiesRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -591,8 +986,31 @@ pub struct ListWebsiteCertificateAuthoritiesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListWebsiteCertificateAuthoritiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), None);
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(4096),
+            );
+            errors.check_pattern(&field_path(path, "next_token"), &value, "[\\w\\-]+");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListWebsiteCertificateAuthoritiesResponse {
     /// <p>The pagination token used to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
     #[serde(rename = "NextToken")]
@@ -605,6 +1023,7 @@ pub struct ListWebsiteCertificateAuthoritiesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RestoreDomainAccessRequest {
     /// <p>The name of the domain.</p>
     #[serde(rename = "DomainName")]
@@ -614,11 +1033,34 @@ pub struct RestoreDomainAccessRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for RestoreDomainAccessRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RestoreDomainAccessResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeDomainAccessRequest {
     /// <p>The name of the domain.</p>
     #[serde(rename = "DomainName")]
@@ -628,11 +1070,34 @@ pub struct RevokeDomainAccessRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for RevokeDomainAccessRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RevokeDomainAccessResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SignOutUserRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -642,11 +1107,29 @@ pub struct SignOutUserRequest {
     pub username: String,
 }
 
+impl Validate for SignOutUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "username"),
+            self.username.chars().count(),
+            Some(1),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SignOutUserResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateAuditStreamConfigurationRequest {
     /// <p>The ARN of the Amazon Kinesis data stream that receives the audit events.</p>
     #[serde(rename = "AuditStreamArn")]
@@ -657,11 +1140,30 @@ pub struct UpdateAuditStreamConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for UpdateAuditStreamConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.audit_stream_arn {
+            errors.check_pattern(
+                &field_path(path, "audit_stream_arn"),
+                &value,
+                "^arn:aws:kinesis:.+:[0-9]{12}:stream/AmazonWorkLink-.*$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateAuditStreamConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateCompanyNetworkConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -677,11 +1179,42 @@ pub struct UpdateCompanyNetworkConfigurationRequest {
     pub vpc_id: String,
 }
 
+impl Validate for UpdateCompanyNetworkConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        errors.check_length(
+            &field_path(path, "security_group_ids"),
+            self.security_group_ids.len(),
+            None,
+            Some(5),
+        );
+        for (i, item) in self.security_group_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "security_group_ids"), i);
+            errors.check_pattern(&item_path, &item, "^sg-([0-9a-f]{8}|[0-9a-f]{17})$");
+        }
+        for (i, item) in self.subnet_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "subnet_ids"), i);
+            errors.check_pattern(&item_path, &item, "^subnet-([0-9a-f]{8}|[0-9a-f]{17})$");
+        }
+        errors.check_pattern(
+            &field_path(path, "vpc_id"),
+            &self.vpc_id,
+            "^vpc-([0-9a-f]{8}|[0-9a-f]{17})$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateCompanyNetworkConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDevicePolicyConfigurationRequest {
     /// <p>The certificate chain, including intermediate certificates and the root certificate authority certificate used to issue device certificates.</p>
     #[serde(rename = "DeviceCaCertificate")]
@@ -692,11 +1225,32 @@ pub struct UpdateDevicePolicyConfigurationRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for UpdateDevicePolicyConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.device_ca_certificate {
+            errors.check_length(
+                &field_path(path, "device_ca_certificate"),
+                value.chars().count(),
+                Some(1),
+                Some(32768),
+            );
+            errors.check_pattern(&field_path(path, "device_ca_certificate"), &value, "(-{5}BEGIN CERTIFICATE-{5}\\u000D?\\u000A([A-Za-z0-9/+]{64}\\u000D?\\u000A)*[A-Za-z0-9/+]{1,64}={0,2}\\u000D?\\u000A-{5}END CERTIFICATE-{5}\\u000D?\\u000A)*-{5}BEGIN CERTIFICATE-{5}\\u000D?\\u000A([A-Za-z0-9/+]{64}\\u000D?\\u000A)*[A-Za-z0-9/+]{1,64}={0,2}\\u000D?\\u000A-{5}END CERTIFICATE-{5}(\\u000D?\\u000A)?");
+        }
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDevicePolicyConfigurationResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateDomainMetadataRequest {
     /// <p>The name to display.</p>
     #[serde(rename = "DisplayName")]
@@ -710,11 +1264,42 @@ pub struct UpdateDomainMetadataRequest {
     pub fleet_arn: String,
 }
 
+impl Validate for UpdateDomainMetadataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "domain_name"),
+            self.domain_name.chars().count(),
+            Some(1),
+            Some(253),
+        );
+        errors.check_pattern(
+            &field_path(path, "domain_name"),
+            &self.domain_name,
+            "^[a-zA-Z0-9]?((?!-)([A-Za-z0-9-]*[A-Za-z0-9])\\.)+[a-zA-Z0-9]+$",
+        );
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateDomainMetadataResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateFleetMetadataRequest {
     /// <p>The fleet name to display. The existing DisplayName is unset if null is passed.</p>
     #[serde(rename = "DisplayName")]
@@ -729,11 +1314,31 @@ pub struct UpdateFleetMetadataRequest {
     pub optimize_for_end_user_location: Option<bool>,
 }
 
+impl Validate for UpdateFleetMetadataRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.display_name {
+            errors.check_length(
+                &field_path(path, "display_name"),
+                value.chars().count(),
+                None,
+                Some(100),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateFleetMetadataResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateIdentityProviderConfigurationRequest {
     /// <p>The ARN of the fleet.</p>
     #[serde(rename = "FleetArn")]
@@ -747,13 +1352,32 @@ pub struct UpdateIdentityProviderConfigurationRequest {
     pub identity_provider_type: String,
 }
 
+impl Validate for UpdateIdentityProviderConfigurationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "fleet_arn"),
+            self.fleet_arn.chars().count(),
+            Some(20),
+            Some(2048),
+        );
+        if let Some(ref value) = self.identity_provider_saml_metadata {
+            errors.check_length(
+                &field_path(path, "identity_provider_saml_metadata"),
+                value.chars().count(),
+                Some(1),
+                Some(204800),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateIdentityProviderConfigurationResponse {}
 
 /// <p>The summary of the website authorization provider.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WebsiteAuthorizationProviderSummary {
     /// <p>A unique identifier for the authorization provider.</p>
     #[serde(rename = "AuthorizationProviderId")]
@@ -774,7 +1398,7 @@ pub struct WebsiteAuthorizationProviderSummary {
 
 /// <p>The summary of the certificate authority (CA).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WebsiteCaSummary {
     /// <p>The time when the CA was added.</p>
     #[serde(rename = "CreatedTime")]
@@ -831,7 +1455,12 @@ impl AssociateDomainError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(AssociateDomainError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -908,7 +1537,12 @@ impl AssociateWebsiteAuthorizationProviderError {
                         AssociateWebsiteAuthorizationProviderError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -985,7 +1619,12 @@ impl AssociateWebsiteCertificateAuthorityError {
                         AssociateWebsiteCertificateAuthorityError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1048,7 +1687,12 @@ impl CreateFleetError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1106,7 +1750,12 @@ impl DeleteFleetError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1175,7 +1824,12 @@ impl DescribeAuditStreamConfigurationError {
                         DescribeAuditStreamConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1244,7 +1898,12 @@ impl DescribeCompanyNetworkConfigurationError {
                         DescribeCompanyNetworkConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1301,7 +1960,12 @@ impl DescribeDeviceError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeDeviceError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1370,7 +2034,12 @@ impl DescribeDevicePolicyConfigurationError {
                         DescribeDevicePolicyConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1427,7 +2096,12 @@ impl DescribeDomainError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeDomainError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1492,7 +2166,12 @@ impl DescribeFleetMetadataError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DescribeFleetMetadataError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1561,7 +2240,12 @@ impl DescribeIdentityProviderConfigurationError {
                         DescribeIdentityProviderConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1630,7 +2314,12 @@ impl DescribeWebsiteCertificateAuthorityError {
                         DescribeWebsiteCertificateAuthorityError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1689,7 +2378,12 @@ impl DisassociateDomainError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(DisassociateDomainError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1767,7 +2461,12 @@ impl DisassociateWebsiteAuthorizationProviderError {
                         DisassociateWebsiteAuthorizationProviderError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1839,7 +2538,12 @@ impl DisassociateWebsiteCertificateAuthorityError {
                         DisassociateWebsiteCertificateAuthorityError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1896,7 +2600,12 @@ impl ListDevicesError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListDevicesError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1948,7 +2657,12 @@ impl ListDomainsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListDomainsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1999,7 +2713,12 @@ impl ListFleetsError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2067,7 +2786,12 @@ impl ListWebsiteAuthorizationProvidersError {
                         ListWebsiteAuthorizationProvidersError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2129,7 +2853,12 @@ impl ListWebsiteCertificateAuthoritiesError {
                         ListWebsiteCertificateAuthoritiesError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2189,7 +2918,12 @@ impl RestoreDomainAccessError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(RestoreDomainAccessError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2248,7 +2982,12 @@ impl RevokeDomainAccessError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(RevokeDomainAccessError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2305,7 +3044,12 @@ impl SignOutUserError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(SignOutUserError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2374,7 +3118,12 @@ impl UpdateAuditStreamConfigurationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2443,7 +3192,12 @@ impl UpdateCompanyNetworkConfigurationError {
                         UpdateCompanyNetworkConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2512,7 +3266,12 @@ impl UpdateDevicePolicyConfigurationError {
                         UpdateDevicePolicyConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2575,7 +3334,12 @@ impl UpdateDomainMetadataError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateDomainMetadataError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2636,7 +3400,12 @@ impl UpdateFleetMetadataError {
                 "UnauthorizedException" => {
                     return RusotoError::Service(UpdateFleetMetadataError::Unauthorized(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2705,7 +3474,12 @@ impl UpdateIdentityProviderConfigurationError {
                         UpdateIdentityProviderConfigurationError::Unauthorized(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2946,77 +3720,14 @@ pub trait Worklink {
         UpdateIdentityProviderConfigurationError,
     >;
 }
-/// A client for the WorkLink API.
-#[derive(Clone)]
-pub struct WorklinkClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl WorklinkClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> WorklinkClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> WorklinkClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> WorklinkClient {
-        WorklinkClient { client, region }
-    }
-}
-
-impl Worklink for WorklinkClient {
-    /// <p>Specifies a domain to be associated to Amazon WorkLink.</p>
+const _: Option<&dyn Worklink> = None;
+impl<'a, T: Worklink + ?Sized> Worklink for &'a T {
     fn associate_domain(
         &self,
         input: AssociateDomainRequest,
     ) -> RusotoFuture<AssociateDomainResponse, AssociateDomainError> {
-        let request_uri = "/associateDomain";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDomainResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AssociateDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).associate_domain(input)
     }
-
-    /// <p>Associates a website authorization provider with a specified fleet. This is used to authorize users against associated websites in the company network.</p>
     fn associate_website_authorization_provider(
         &self,
         input: AssociateWebsiteAuthorizationProviderRequest,
@@ -3024,34 +3735,8 @@ impl Worklink for WorklinkClient {
         AssociateWebsiteAuthorizationProviderResponse,
         AssociateWebsiteAuthorizationProviderError,
     > {
-        let request_uri = "/associateWebsiteAuthorizationProvider";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateWebsiteAuthorizationProviderResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateWebsiteAuthorizationProviderError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_website_authorization_provider(input)
     }
-
-    /// <p>Imports the root certificate of a certificate authority (CA) used to obtain TLS certificates used by associated websites within the company network.</p>
     fn associate_website_certificate_authority(
         &self,
         input: AssociateWebsiteCertificateAuthorityRequest,
@@ -3059,130 +3744,27 @@ impl Worklink for WorklinkClient {
         AssociateWebsiteCertificateAuthorityResponse,
         AssociateWebsiteCertificateAuthorityError,
     > {
-        let request_uri = "/associateWebsiteCertificateAuthority";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateWebsiteCertificateAuthorityResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateWebsiteCertificateAuthorityError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).associate_website_certificate_authority(input)
     }
-
-    /// <p>Creates a fleet. A fleet consists of resources and the configuration that delivers associated websites to authorized users who download and set up the Amazon WorkLink app.</p>
     fn create_fleet(
         &self,
         input: CreateFleetRequest,
     ) -> RusotoFuture<CreateFleetResponse, CreateFleetError> {
-        let request_uri = "/createFleet";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateFleetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_fleet(input)
     }
-
-    /// <p>Deletes a fleet. Prevents users from accessing previously associated websites. </p>
     fn delete_fleet(
         &self,
         input: DeleteFleetRequest,
     ) -> RusotoFuture<DeleteFleetResponse, DeleteFleetError> {
-        let request_uri = "/deleteFleet";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteFleetResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteFleetError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_fleet(input)
     }
-
-    /// <p>Describes the configuration for delivering audit streams to the customer account.</p>
     fn describe_audit_stream_configuration(
         &self,
         input: DescribeAuditStreamConfigurationRequest,
     ) -> RusotoFuture<DescribeAuditStreamConfigurationResponse, DescribeAuditStreamConfigurationError>
     {
-        let request_uri = "/describeAuditStreamConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAuditStreamConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAuditStreamConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_audit_stream_configuration(input)
     }
-
-    /// <p>Describes the networking configuration to access the internal websites associated with the specified fleet.</p>
     fn describe_company_network_configuration(
         &self,
         input: DescribeCompanyNetworkConfigurationRequest,
@@ -3190,65 +3772,14 @@ impl Worklink for WorklinkClient {
         DescribeCompanyNetworkConfigurationResponse,
         DescribeCompanyNetworkConfigurationError,
     > {
-        let request_uri = "/describeCompanyNetworkConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeCompanyNetworkConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeCompanyNetworkConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_company_network_configuration(input)
     }
-
-    /// <p>Provides information about a user's device.</p>
     fn describe_device(
         &self,
         input: DescribeDeviceRequest,
     ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
-        let request_uri = "/describeDevice";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDeviceResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDeviceError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_device(input)
     }
-
-    /// <p>Describes the device policy configuration for the specified fleet.</p>
     fn describe_device_policy_configuration(
         &self,
         input: DescribeDevicePolicyConfigurationRequest,
@@ -3256,96 +3787,20 @@ impl Worklink for WorklinkClient {
         DescribeDevicePolicyConfigurationResponse,
         DescribeDevicePolicyConfigurationError,
     > {
-        let request_uri = "/describeDevicePolicyConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDevicePolicyConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeDevicePolicyConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_device_policy_configuration(input)
     }
-
-    /// <p>Provides information about the domain.</p>
     fn describe_domain(
         &self,
         input: DescribeDomainRequest,
     ) -> RusotoFuture<DescribeDomainResponse, DescribeDomainError> {
-        let request_uri = "/describeDomain";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeDomainResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_domain(input)
     }
-
-    /// <p>Provides basic information for the specified fleet, excluding identity provider, networking, and device configuration details.</p>
     fn describe_fleet_metadata(
         &self,
         input: DescribeFleetMetadataRequest,
     ) -> RusotoFuture<DescribeFleetMetadataResponse, DescribeFleetMetadataError> {
-        let request_uri = "/describeFleetMetadata";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeFleetMetadataResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeFleetMetadataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_fleet_metadata(input)
     }
-
-    /// <p>Describes the identity provider configuration of the specified fleet.</p>
     fn describe_identity_provider_configuration(
         &self,
         input: DescribeIdentityProviderConfigurationRequest,
@@ -3353,34 +3808,8 @@ impl Worklink for WorklinkClient {
         DescribeIdentityProviderConfigurationResponse,
         DescribeIdentityProviderConfigurationError,
     > {
-        let request_uri = "/describeIdentityProviderConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeIdentityProviderConfigurationResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeIdentityProviderConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_identity_provider_configuration(input)
     }
-
-    /// <p>Provides information about the certificate authority.</p>
     fn describe_website_certificate_authority(
         &self,
         input: DescribeWebsiteCertificateAuthorityRequest,
@@ -3388,65 +3817,14 @@ impl Worklink for WorklinkClient {
         DescribeWebsiteCertificateAuthorityResponse,
         DescribeWebsiteCertificateAuthorityError,
     > {
-        let request_uri = "/describeWebsiteCertificateAuthority";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWebsiteCertificateAuthorityResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeWebsiteCertificateAuthorityError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).describe_website_certificate_authority(input)
     }
-
-    /// <p>Disassociates a domain from Amazon WorkLink. End users lose the ability to access the domain with Amazon WorkLink. </p>
     fn disassociate_domain(
         &self,
         input: DisassociateDomainRequest,
     ) -> RusotoFuture<DisassociateDomainResponse, DisassociateDomainError> {
-        let request_uri = "/disassociateDomain";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateDomainResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DisassociateDomainError::from_response(response))),
-                )
-            }
-        })
+        (**self).disassociate_domain(input)
     }
-
-    /// <p>Disassociates a website authorization provider from a specified fleet. After the disassociation, users can't load any associated websites that require this authorization provider.</p>
     fn disassociate_website_authorization_provider(
         &self,
         input: DisassociateWebsiteAuthorizationProviderRequest,
@@ -3454,32 +3832,8 @@ impl Worklink for WorklinkClient {
         DisassociateWebsiteAuthorizationProviderResponse,
         DisassociateWebsiteAuthorizationProviderError,
     > {
-        let request_uri = "/disassociateWebsiteAuthorizationProvider";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateWebsiteAuthorizationProviderResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateWebsiteAuthorizationProviderError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_website_authorization_provider(input)
     }
-
-    /// <p>Removes a certificate authority (CA).</p>
     fn disassociate_website_certificate_authority(
         &self,
         input: DisassociateWebsiteCertificateAuthorityRequest,
@@ -3487,130 +3841,26 @@ impl Worklink for WorklinkClient {
         DisassociateWebsiteCertificateAuthorityResponse,
         DisassociateWebsiteCertificateAuthorityError,
     > {
-        let request_uri = "/disassociateWebsiteCertificateAuthority";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateWebsiteCertificateAuthorityResponse, _>(
-                    )?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateWebsiteCertificateAuthorityError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_website_certificate_authority(input)
     }
-
-    /// <p>Retrieves a list of devices registered with the specified fleet.</p>
     fn list_devices(
         &self,
         input: ListDevicesRequest,
     ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
-        let request_uri = "/listDevices";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDevicesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDevicesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_devices(input)
     }
-
-    /// <p>Retrieves a list of domains associated to a specified fleet.</p>
     fn list_domains(
         &self,
         input: ListDomainsRequest,
     ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
-        let request_uri = "/listDomains";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListDomainsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListDomainsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_domains(input)
     }
-
-    /// <p>Retrieves a list of fleets for the current account and Region.</p>
     fn list_fleets(
         &self,
         input: ListFleetsRequest,
     ) -> RusotoFuture<ListFleetsResponse, ListFleetsError> {
-        let request_uri = "/listFleets";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListFleetsResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListFleetsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_fleets(input)
     }
-
-    /// <p>Retrieves a list of website authorization providers associated with a specified fleet.</p>
     fn list_website_authorization_providers(
         &self,
         input: ListWebsiteAuthorizationProvidersRequest,
@@ -3618,33 +3868,8 @@ impl Worklink for WorklinkClient {
         ListWebsiteAuthorizationProvidersResponse,
         ListWebsiteAuthorizationProvidersError,
     > {
-        let request_uri = "/listWebsiteAuthorizationProviders";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWebsiteAuthorizationProvidersResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListWebsiteAuthorizationProvidersError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_website_authorization_providers(input)
     }
-
-    /// <p>Retrieves a list of certificate authorities added for the current account and Region.</p>
     fn list_website_certificate_authorities(
         &self,
         input: ListWebsiteCertificateAuthoritiesRequest,
@@ -3652,158 +3877,2900 @@ impl Worklink for WorklinkClient {
         ListWebsiteCertificateAuthoritiesResponse,
         ListWebsiteCertificateAuthoritiesError,
     > {
-        let request_uri = "/listWebsiteCertificateAuthorities";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListWebsiteCertificateAuthoritiesResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListWebsiteCertificateAuthoritiesError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).list_website_certificate_authorities(input)
     }
-
-    /// <p>Moves a domain to ACTIVE status if it was in the INACTIVE status.</p>
     fn restore_domain_access(
         &self,
         input: RestoreDomainAccessRequest,
     ) -> RusotoFuture<RestoreDomainAccessResponse, RestoreDomainAccessError> {
-        let request_uri = "/restoreDomainAccess";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreDomainAccessResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(RestoreDomainAccessError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).restore_domain_access(input)
     }
-
-    /// <p>Moves a domain to INACTIVE status if it was in the ACTIVE status.</p>
     fn revoke_domain_access(
         &self,
         input: RevokeDomainAccessRequest,
     ) -> RusotoFuture<RevokeDomainAccessResponse, RevokeDomainAccessError> {
-        let request_uri = "/revokeDomainAccess";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RevokeDomainAccessResponse, _>()?;
-
+        (**self).revoke_domain_access(input)
+    }
+    fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> RusotoFuture<SignOutUserResponse, SignOutUserError> {
+        (**self).sign_out_user(input)
+    }
+    fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<UpdateAuditStreamConfigurationResponse, UpdateAuditStreamConfigurationError>
+    {
+        (**self).update_audit_stream_configuration(input)
+    }
+    fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateCompanyNetworkConfigurationResponse,
+        UpdateCompanyNetworkConfigurationError,
+    > {
+        (**self).update_company_network_configuration(input)
+    }
+    fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<UpdateDevicePolicyConfigurationResponse, UpdateDevicePolicyConfigurationError>
+    {
+        (**self).update_device_policy_configuration(input)
+    }
+    fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> RusotoFuture<UpdateDomainMetadataResponse, UpdateDomainMetadataError> {
+        (**self).update_domain_metadata(input)
+    }
+    fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> RusotoFuture<UpdateFleetMetadataResponse, UpdateFleetMetadataError> {
+        (**self).update_fleet_metadata(input)
+    }
+    fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateIdentityProviderConfigurationResponse,
+        UpdateIdentityProviderConfigurationError,
+    > {
+        (**self).update_identity_provider_configuration(input)
+    }
+}
+impl<T: Worklink + ?Sized> Worklink for Box<T> {
+    fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> RusotoFuture<AssociateDomainResponse, AssociateDomainError> {
+        (**self).associate_domain(input)
+    }
+    fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteAuthorizationProviderResponse,
+        AssociateWebsiteAuthorizationProviderError,
+    > {
+        (**self).associate_website_authorization_provider(input)
+    }
+    fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteCertificateAuthorityResponse,
+        AssociateWebsiteCertificateAuthorityError,
+    > {
+        (**self).associate_website_certificate_authority(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResponse, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResponse, DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<DescribeAuditStreamConfigurationResponse, DescribeAuditStreamConfigurationError>
+    {
+        (**self).describe_audit_stream_configuration(input)
+    }
+    fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeCompanyNetworkConfigurationResponse,
+        DescribeCompanyNetworkConfigurationError,
+    > {
+        (**self).describe_company_network_configuration(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeDevicePolicyConfigurationResponse,
+        DescribeDevicePolicyConfigurationError,
+    > {
+        (**self).describe_device_policy_configuration(input)
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> RusotoFuture<DescribeDomainResponse, DescribeDomainError> {
+        (**self).describe_domain(input)
+    }
+    fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> RusotoFuture<DescribeFleetMetadataResponse, DescribeFleetMetadataError> {
+        (**self).describe_fleet_metadata(input)
+    }
+    fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeIdentityProviderConfigurationResponse,
+        DescribeIdentityProviderConfigurationError,
+    > {
+        (**self).describe_identity_provider_configuration(input)
+    }
+    fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DescribeWebsiteCertificateAuthorityResponse,
+        DescribeWebsiteCertificateAuthorityError,
+    > {
+        (**self).describe_website_certificate_authority(input)
+    }
+    fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> RusotoFuture<DisassociateDomainResponse, DisassociateDomainError> {
+        (**self).disassociate_domain(input)
+    }
+    fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        DisassociateWebsiteAuthorizationProviderError,
+    > {
+        (**self).disassociate_website_authorization_provider(input)
+    }
+    fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        DisassociateWebsiteCertificateAuthorityError,
+    > {
+        (**self).disassociate_website_certificate_authority(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> RusotoFuture<ListFleetsResponse, ListFleetsError> {
+        (**self).list_fleets(input)
+    }
+    fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> RusotoFuture<
+        ListWebsiteAuthorizationProvidersResponse,
+        ListWebsiteAuthorizationProvidersError,
+    > {
+        (**self).list_website_authorization_providers(input)
+    }
+    fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<
+        ListWebsiteCertificateAuthoritiesResponse,
+        ListWebsiteCertificateAuthoritiesError,
+    > {
+        (**self).list_website_certificate_authorities(input)
+    }
+    fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> RusotoFuture<RestoreDomainAccessResponse, RestoreDomainAccessError> {
+        (**self).restore_domain_access(input)
+    }
+    fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> RusotoFuture<RevokeDomainAccessResponse, RevokeDomainAccessError> {
+        (**self).revoke_domain_access(input)
+    }
+    fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> RusotoFuture<SignOutUserResponse, SignOutUserError> {
+        (**self).sign_out_user(input)
+    }
+    fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<UpdateAuditStreamConfigurationResponse, UpdateAuditStreamConfigurationError>
+    {
+        (**self).update_audit_stream_configuration(input)
+    }
+    fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateCompanyNetworkConfigurationResponse,
+        UpdateCompanyNetworkConfigurationError,
+    > {
+        (**self).update_company_network_configuration(input)
+    }
+    fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<UpdateDevicePolicyConfigurationResponse, UpdateDevicePolicyConfigurationError>
+    {
+        (**self).update_device_policy_configuration(input)
+    }
+    fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> RusotoFuture<UpdateDomainMetadataResponse, UpdateDomainMetadataError> {
+        (**self).update_domain_metadata(input)
+    }
+    fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> RusotoFuture<UpdateFleetMetadataResponse, UpdateFleetMetadataError> {
+        (**self).update_fleet_metadata(input)
+    }
+    fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateIdentityProviderConfigurationResponse,
+        UpdateIdentityProviderConfigurationError,
+    > {
+        (**self).update_identity_provider_configuration(input)
+    }
+}
+impl<T: Worklink + ?Sized> Worklink for ::std::sync::Arc<T> {
+    fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> RusotoFuture<AssociateDomainResponse, AssociateDomainError> {
+        (**self).associate_domain(input)
+    }
+    fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteAuthorizationProviderResponse,
+        AssociateWebsiteAuthorizationProviderError,
+    > {
+        (**self).associate_website_authorization_provider(input)
+    }
+    fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteCertificateAuthorityResponse,
+        AssociateWebsiteCertificateAuthorityError,
+    > {
+        (**self).associate_website_certificate_authority(input)
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResponse, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResponse, DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
+    fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<DescribeAuditStreamConfigurationResponse, DescribeAuditStreamConfigurationError>
+    {
+        (**self).describe_audit_stream_configuration(input)
+    }
+    fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeCompanyNetworkConfigurationResponse,
+        DescribeCompanyNetworkConfigurationError,
+    > {
+        (**self).describe_company_network_configuration(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeDevicePolicyConfigurationResponse,
+        DescribeDevicePolicyConfigurationError,
+    > {
+        (**self).describe_device_policy_configuration(input)
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> RusotoFuture<DescribeDomainResponse, DescribeDomainError> {
+        (**self).describe_domain(input)
+    }
+    fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> RusotoFuture<DescribeFleetMetadataResponse, DescribeFleetMetadataError> {
+        (**self).describe_fleet_metadata(input)
+    }
+    fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeIdentityProviderConfigurationResponse,
+        DescribeIdentityProviderConfigurationError,
+    > {
+        (**self).describe_identity_provider_configuration(input)
+    }
+    fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DescribeWebsiteCertificateAuthorityResponse,
+        DescribeWebsiteCertificateAuthorityError,
+    > {
+        (**self).describe_website_certificate_authority(input)
+    }
+    fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> RusotoFuture<DisassociateDomainResponse, DisassociateDomainError> {
+        (**self).disassociate_domain(input)
+    }
+    fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        DisassociateWebsiteAuthorizationProviderError,
+    > {
+        (**self).disassociate_website_authorization_provider(input)
+    }
+    fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        DisassociateWebsiteCertificateAuthorityError,
+    > {
+        (**self).disassociate_website_certificate_authority(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        (**self).list_domains(input)
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> RusotoFuture<ListFleetsResponse, ListFleetsError> {
+        (**self).list_fleets(input)
+    }
+    fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> RusotoFuture<
+        ListWebsiteAuthorizationProvidersResponse,
+        ListWebsiteAuthorizationProvidersError,
+    > {
+        (**self).list_website_authorization_providers(input)
+    }
+    fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<
+        ListWebsiteCertificateAuthoritiesResponse,
+        ListWebsiteCertificateAuthoritiesError,
+    > {
+        (**self).list_website_certificate_authorities(input)
+    }
+    fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> RusotoFuture<RestoreDomainAccessResponse, RestoreDomainAccessError> {
+        (**self).restore_domain_access(input)
+    }
+    fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> RusotoFuture<RevokeDomainAccessResponse, RevokeDomainAccessError> {
+        (**self).revoke_domain_access(input)
+    }
+    fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> RusotoFuture<SignOutUserResponse, SignOutUserError> {
+        (**self).sign_out_user(input)
+    }
+    fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<UpdateAuditStreamConfigurationResponse, UpdateAuditStreamConfigurationError>
+    {
+        (**self).update_audit_stream_configuration(input)
+    }
+    fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateCompanyNetworkConfigurationResponse,
+        UpdateCompanyNetworkConfigurationError,
+    > {
+        (**self).update_company_network_configuration(input)
+    }
+    fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<UpdateDevicePolicyConfigurationResponse, UpdateDevicePolicyConfigurationError>
+    {
+        (**self).update_device_policy_configuration(input)
+    }
+    fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> RusotoFuture<UpdateDomainMetadataResponse, UpdateDomainMetadataError> {
+        (**self).update_domain_metadata(input)
+    }
+    fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> RusotoFuture<UpdateFleetMetadataResponse, UpdateFleetMetadataError> {
+        (**self).update_fleet_metadata(input)
+    }
+    fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateIdentityProviderConfigurationResponse,
+        UpdateIdentityProviderConfigurationError,
+    > {
+        (**self).update_identity_provider_configuration(input)
+    }
+}
+/// A client for the WorkLink API.
+#[derive(Clone)]
+pub struct WorklinkClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl WorklinkClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorklinkClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorklinkClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> WorklinkClient {
+        WorklinkClient { client, region }
+    }
+}
+
+impl Worklink for WorklinkClient {
+    /// <p>Specifies a domain to be associated to Amazon WorkLink.</p>
+    fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> RusotoFuture<AssociateDomainResponse, AssociateDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/associateDomain";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateDomain");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateDomain")
+                        .deserialize::<AssociateDomainResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Associates a website authorization provider with a specified fleet. This is used to authorize users against associated websites in the company network.</p>
+    fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteAuthorizationProviderResponse,
+        AssociateWebsiteAuthorizationProviderError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/associateWebsiteAuthorizationProvider";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteAuthorizationProvider");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateWebsiteAuthorizationProvider")
+                        .deserialize::<AssociateWebsiteAuthorizationProviderResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateWebsiteAuthorizationProviderError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Imports the root certificate of a certificate authority (CA) used to obtain TLS certificates used by associated websites within the company network.</p>
+    fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteCertificateAuthorityResponse,
+        AssociateWebsiteCertificateAuthorityError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/associateWebsiteCertificateAuthority";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("AssociateWebsiteCertificateAuthority");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("AssociateWebsiteCertificateAuthority")
+                        .deserialize::<AssociateWebsiteCertificateAuthorityResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateWebsiteCertificateAuthorityError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Creates a fleet. A fleet consists of resources and the configuration that delivers associated websites to authorized users who download and set up the Amazon WorkLink app.</p>
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResponse, CreateFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/createFleet";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateFleet")
+                        .deserialize::<CreateFleetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a fleet. Prevents users from accessing previously associated websites. </p>
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResponse, DeleteFleetError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/deleteFleet";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteFleet")
+                        .deserialize::<DeleteFleetResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteFleetError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the configuration for delivering audit streams to the customer account.</p>
+    fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<DescribeAuditStreamConfigurationResponse, DescribeAuditStreamConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeAuditStreamConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeAuditStreamConfiguration");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeAuditStreamConfiguration")
+                        .deserialize::<DescribeAuditStreamConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAuditStreamConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the networking configuration to access the internal websites associated with the specified fleet.</p>
+    fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeCompanyNetworkConfigurationResponse,
+        DescribeCompanyNetworkConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeCompanyNetworkConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeCompanyNetworkConfiguration");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeCompanyNetworkConfiguration")
+                        .deserialize::<DescribeCompanyNetworkConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeCompanyNetworkConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides information about a user's device.</p>
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeDevice";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDevice")
+                        .deserialize::<DescribeDeviceResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDeviceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the device policy configuration for the specified fleet.</p>
+    fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeDevicePolicyConfigurationResponse,
+        DescribeDevicePolicyConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeDevicePolicyConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDevicePolicyConfiguration");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDevicePolicyConfiguration")
+                        .deserialize::<DescribeDevicePolicyConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeDevicePolicyConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides information about the domain.</p>
+    fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> RusotoFuture<DescribeDomainResponse, DescribeDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeDomain";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeDomain");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeDomain")
+                        .deserialize::<DescribeDomainResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides basic information for the specified fleet, excluding identity provider, networking, and device configuration details.</p>
+    fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> RusotoFuture<DescribeFleetMetadataResponse, DescribeFleetMetadataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeFleetMetadata";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeFleetMetadata");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeFleetMetadata")
+                        .deserialize::<DescribeFleetMetadataResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeFleetMetadataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the identity provider configuration of the specified fleet.</p>
+    fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeIdentityProviderConfigurationResponse,
+        DescribeIdentityProviderConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeIdentityProviderConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityProviderConfiguration");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeIdentityProviderConfiguration")
+                        .deserialize::<DescribeIdentityProviderConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeIdentityProviderConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Provides information about the certificate authority.</p>
+    fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DescribeWebsiteCertificateAuthorityResponse,
+        DescribeWebsiteCertificateAuthorityError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/describeWebsiteCertificateAuthority";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DescribeWebsiteCertificateAuthority");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DescribeWebsiteCertificateAuthority")
+                        .deserialize::<DescribeWebsiteCertificateAuthorityResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeWebsiteCertificateAuthorityError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Disassociates a domain from Amazon WorkLink. End users lose the ability to access the domain with Amazon WorkLink. </p>
+    fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> RusotoFuture<DisassociateDomainResponse, DisassociateDomainError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/disassociateDomain";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateDomain");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateDomain")
+                        .deserialize::<DisassociateDomainResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DisassociateDomainError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Disassociates a website authorization provider from a specified fleet. After the disassociation, users can't load any associated websites that require this authorization provider.</p>
+    fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        DisassociateWebsiteAuthorizationProviderError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/disassociateWebsiteAuthorizationProvider";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteAuthorizationProvider");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateWebsiteAuthorizationProvider")
+                        .deserialize::<DisassociateWebsiteAuthorizationProviderResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateWebsiteAuthorizationProviderError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes a certificate authority (CA).</p>
+    fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        DisassociateWebsiteCertificateAuthorityError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/disassociateWebsiteCertificateAuthority";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("DisassociateWebsiteCertificateAuthority");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DisassociateWebsiteCertificateAuthority")
+                        .deserialize::<DisassociateWebsiteCertificateAuthorityResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateWebsiteCertificateAuthorityError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of devices registered with the specified fleet.</p>
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/listDevices";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDevices");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDevices")
+                        .deserialize::<ListDevicesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDevicesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of domains associated to a specified fleet.</p>
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/listDomains";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListDomains");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListDomains")
+                        .deserialize::<ListDomainsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListDomainsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of fleets for the current account and Region.</p>
+    fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> RusotoFuture<ListFleetsResponse, ListFleetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/listFleets";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListFleets");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListFleets")
+                        .deserialize::<ListFleetsResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListFleetsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of website authorization providers associated with a specified fleet.</p>
+    fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> RusotoFuture<
+        ListWebsiteAuthorizationProvidersResponse,
+        ListWebsiteAuthorizationProvidersError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/listWebsiteAuthorizationProviders";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteAuthorizationProviders");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListWebsiteAuthorizationProviders")
+                        .deserialize::<ListWebsiteAuthorizationProvidersResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListWebsiteAuthorizationProvidersError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list of certificate authorities added for the current account and Region.</p>
+    fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<
+        ListWebsiteCertificateAuthoritiesResponse,
+        ListWebsiteCertificateAuthoritiesError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/listWebsiteCertificateAuthorities";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("ListWebsiteCertificateAuthorities");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("ListWebsiteCertificateAuthorities")
+                        .deserialize::<ListWebsiteCertificateAuthoritiesResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(ListWebsiteCertificateAuthoritiesError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Moves a domain to ACTIVE status if it was in the INACTIVE status.</p>
+    fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> RusotoFuture<RestoreDomainAccessResponse, RestoreDomainAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/restoreDomainAccess";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RestoreDomainAccess");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RestoreDomainAccess")
+                        .deserialize::<RestoreDomainAccessResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(RestoreDomainAccessError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Moves a domain to INACTIVE status if it was in the ACTIVE status.</p>
+    fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> RusotoFuture<RevokeDomainAccessResponse, RevokeDomainAccessError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/revokeDomainAccess";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("RevokeDomainAccess");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("RevokeDomainAccess")
+                        .deserialize::<RevokeDomainAccessResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RevokeDomainAccessError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Signs the user out from all of their devices. The user can sign in again if they have valid credentials.</p>
+    fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> RusotoFuture<SignOutUserResponse, SignOutUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/signOutUser";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("SignOutUser");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("SignOutUser")
+                        .deserialize::<SignOutUserResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(SignOutUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the audit stream configuration for the fleet.</p>
+    fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<UpdateAuditStreamConfigurationResponse, UpdateAuditStreamConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/updateAuditStreamConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateAuditStreamConfiguration");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateAuditStreamConfiguration")
+                        .deserialize::<UpdateAuditStreamConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateAuditStreamConfigurationError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the company network configuration for the fleet.</p>
+    fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateCompanyNetworkConfigurationResponse,
+        UpdateCompanyNetworkConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/updateCompanyNetworkConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateCompanyNetworkConfiguration");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateCompanyNetworkConfiguration")
+                        .deserialize::<UpdateCompanyNetworkConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateCompanyNetworkConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates the device policy configuration for the fleet.</p>
+    fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<UpdateDevicePolicyConfigurationResponse, UpdateDevicePolicyConfigurationError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/updateDevicePolicyConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDevicePolicyConfiguration");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDevicePolicyConfiguration")
+                        .deserialize::<UpdateDevicePolicyConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateDevicePolicyConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Updates domain metadata, such as DisplayName.</p>
+    fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> RusotoFuture<UpdateDomainMetadataResponse, UpdateDomainMetadataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/updateDomainMetadata";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateDomainMetadata");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateDomainMetadata")
+                        .deserialize::<UpdateDomainMetadataResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateDomainMetadataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates fleet metadata, such as DisplayName.</p>
+    fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> RusotoFuture<UpdateFleetMetadataResponse, UpdateFleetMetadataError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/UpdateFleetMetadata";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateFleetMetadata");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateFleetMetadata")
+                        .deserialize::<UpdateFleetMetadataResponse, _>()?;
+
                     Ok(result)
                 }))
             } else {
                 Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RevokeDomainAccessError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateFleetMetadataError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the identity provider configuration for the fleet.</p>
+    fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateIdentityProviderConfigurationResponse,
+        UpdateIdentityProviderConfigurationError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/updateIdentityProviderConfiguration";
+
+        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation("UpdateIdentityProviderConfiguration");
+        request.set_content_type("application/json".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateIdentityProviderConfiguration")
+                        .deserialize::<UpdateIdentityProviderConfigurationResponse, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(UpdateIdentityProviderConfigurationError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+}
+
+/// A mock of the WorkLink API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockWorklink {
+    associate_domain: Option<
+        Box<
+            dyn Fn(
+                    AssociateDomainRequest,
+                ) -> ::std::result::Result<
+                    AssociateDomainResponse,
+                    RusotoError<AssociateDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_website_authorization_provider: Option<
+        Box<
+            dyn Fn(
+                    AssociateWebsiteAuthorizationProviderRequest,
+                ) -> ::std::result::Result<
+                    AssociateWebsiteAuthorizationProviderResponse,
+                    RusotoError<AssociateWebsiteAuthorizationProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_website_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    AssociateWebsiteCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    AssociateWebsiteCertificateAuthorityResponse,
+                    RusotoError<AssociateWebsiteCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_fleet: Option<
+        Box<
+            dyn Fn(
+                    CreateFleetRequest,
+                )
+                    -> ::std::result::Result<CreateFleetResponse, RusotoError<CreateFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_fleet: Option<
+        Box<
+            dyn Fn(
+                    DeleteFleetRequest,
+                )
+                    -> ::std::result::Result<DeleteFleetResponse, RusotoError<DeleteFleetError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_audit_stream_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeAuditStreamConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeAuditStreamConfigurationResponse,
+                    RusotoError<DescribeAuditStreamConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_company_network_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeCompanyNetworkConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeCompanyNetworkConfigurationResponse,
+                    RusotoError<DescribeCompanyNetworkConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_device: Option<
+        Box<
+            dyn Fn(
+                    DescribeDeviceRequest,
+                ) -> ::std::result::Result<
+                    DescribeDeviceResponse,
+                    RusotoError<DescribeDeviceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_device_policy_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeDevicePolicyConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeDevicePolicyConfigurationResponse,
+                    RusotoError<DescribeDevicePolicyConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_domain: Option<
+        Box<
+            dyn Fn(
+                    DescribeDomainRequest,
+                ) -> ::std::result::Result<
+                    DescribeDomainResponse,
+                    RusotoError<DescribeDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_fleet_metadata: Option<
+        Box<
+            dyn Fn(
+                    DescribeFleetMetadataRequest,
+                ) -> ::std::result::Result<
+                    DescribeFleetMetadataResponse,
+                    RusotoError<DescribeFleetMetadataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_identity_provider_configuration: Option<
+        Box<
+            dyn Fn(
+                    DescribeIdentityProviderConfigurationRequest,
+                ) -> ::std::result::Result<
+                    DescribeIdentityProviderConfigurationResponse,
+                    RusotoError<DescribeIdentityProviderConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_website_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    DescribeWebsiteCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    DescribeWebsiteCertificateAuthorityResponse,
+                    RusotoError<DescribeWebsiteCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_domain: Option<
+        Box<
+            dyn Fn(
+                    DisassociateDomainRequest,
+                ) -> ::std::result::Result<
+                    DisassociateDomainResponse,
+                    RusotoError<DisassociateDomainError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_website_authorization_provider: Option<
+        Box<
+            dyn Fn(
+                    DisassociateWebsiteAuthorizationProviderRequest,
+                ) -> ::std::result::Result<
+                    DisassociateWebsiteAuthorizationProviderResponse,
+                    RusotoError<DisassociateWebsiteAuthorizationProviderError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_website_certificate_authority: Option<
+        Box<
+            dyn Fn(
+                    DisassociateWebsiteCertificateAuthorityRequest,
+                ) -> ::std::result::Result<
+                    DisassociateWebsiteCertificateAuthorityResponse,
+                    RusotoError<DisassociateWebsiteCertificateAuthorityError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_devices: Option<
+        Box<
+            dyn Fn(
+                    ListDevicesRequest,
+                )
+                    -> ::std::result::Result<ListDevicesResponse, RusotoError<ListDevicesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_domains: Option<
+        Box<
+            dyn Fn(
+                    ListDomainsRequest,
+                )
+                    -> ::std::result::Result<ListDomainsResponse, RusotoError<ListDomainsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_fleets: Option<
+        Box<
+            dyn Fn(
+                    ListFleetsRequest,
                 )
+                    -> ::std::result::Result<ListFleetsResponse, RusotoError<ListFleetsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_website_authorization_providers: Option<
+        Box<
+            dyn Fn(
+                    ListWebsiteAuthorizationProvidersRequest,
+                ) -> ::std::result::Result<
+                    ListWebsiteAuthorizationProvidersResponse,
+                    RusotoError<ListWebsiteAuthorizationProvidersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_website_certificate_authorities: Option<
+        Box<
+            dyn Fn(
+                    ListWebsiteCertificateAuthoritiesRequest,
+                ) -> ::std::result::Result<
+                    ListWebsiteCertificateAuthoritiesResponse,
+                    RusotoError<ListWebsiteCertificateAuthoritiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    restore_domain_access: Option<
+        Box<
+            dyn Fn(
+                    RestoreDomainAccessRequest,
+                ) -> ::std::result::Result<
+                    RestoreDomainAccessResponse,
+                    RusotoError<RestoreDomainAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    revoke_domain_access: Option<
+        Box<
+            dyn Fn(
+                    RevokeDomainAccessRequest,
+                ) -> ::std::result::Result<
+                    RevokeDomainAccessResponse,
+                    RusotoError<RevokeDomainAccessError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    sign_out_user: Option<
+        Box<
+            dyn Fn(
+                    SignOutUserRequest,
+                )
+                    -> ::std::result::Result<SignOutUserResponse, RusotoError<SignOutUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_audit_stream_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateAuditStreamConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateAuditStreamConfigurationResponse,
+                    RusotoError<UpdateAuditStreamConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_company_network_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateCompanyNetworkConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateCompanyNetworkConfigurationResponse,
+                    RusotoError<UpdateCompanyNetworkConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_device_policy_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateDevicePolicyConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateDevicePolicyConfigurationResponse,
+                    RusotoError<UpdateDevicePolicyConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_domain_metadata: Option<
+        Box<
+            dyn Fn(
+                    UpdateDomainMetadataRequest,
+                ) -> ::std::result::Result<
+                    UpdateDomainMetadataResponse,
+                    RusotoError<UpdateDomainMetadataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_fleet_metadata: Option<
+        Box<
+            dyn Fn(
+                    UpdateFleetMetadataRequest,
+                ) -> ::std::result::Result<
+                    UpdateFleetMetadataResponse,
+                    RusotoError<UpdateFleetMetadataError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_identity_provider_configuration: Option<
+        Box<
+            dyn Fn(
+                    UpdateIdentityProviderConfigurationRequest,
+                ) -> ::std::result::Result<
+                    UpdateIdentityProviderConfigurationResponse,
+                    RusotoError<UpdateIdentityProviderConfigurationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockWorklink {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockWorklink {
+        Default::default()
+    }
+
+    /// Answers `associate_domain` with what `handler` returns for its input.
+    pub fn with_associate_domain<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                AssociateDomainRequest,
+            )
+                -> ::std::result::Result<AssociateDomainResponse, RusotoError<AssociateDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_website_authorization_provider` with what `handler` returns for its input.
+    pub fn with_associate_website_authorization_provider<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                AssociateWebsiteAuthorizationProviderRequest,
+            ) -> ::std::result::Result<
+                AssociateWebsiteAuthorizationProviderResponse,
+                RusotoError<AssociateWebsiteAuthorizationProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_website_authorization_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_website_certificate_authority` with what `handler` returns for its input.
+    pub fn with_associate_website_certificate_authority<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                AssociateWebsiteCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                AssociateWebsiteCertificateAuthorityResponse,
+                RusotoError<AssociateWebsiteCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_website_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_fleet` with what `handler` returns for its input.
+    pub fn with_create_fleet<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                CreateFleetRequest,
+            )
+                -> ::std::result::Result<CreateFleetResponse, RusotoError<CreateFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_fleet` with what `handler` returns for its input.
+    pub fn with_delete_fleet<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DeleteFleetRequest,
+            )
+                -> ::std::result::Result<DeleteFleetResponse, RusotoError<DeleteFleetError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_fleet = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_audit_stream_configuration` with what `handler` returns for its input.
+    pub fn with_describe_audit_stream_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeAuditStreamConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeAuditStreamConfigurationResponse,
+                RusotoError<DescribeAuditStreamConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_audit_stream_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_company_network_configuration` with what `handler` returns for its input.
+    pub fn with_describe_company_network_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeCompanyNetworkConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeCompanyNetworkConfigurationResponse,
+                RusotoError<DescribeCompanyNetworkConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_company_network_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_device` with what `handler` returns for its input.
+    pub fn with_describe_device<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeDeviceRequest,
+            )
+                -> ::std::result::Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_device = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_device_policy_configuration` with what `handler` returns for its input.
+    pub fn with_describe_device_policy_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeDevicePolicyConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeDevicePolicyConfigurationResponse,
+                RusotoError<DescribeDevicePolicyConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_device_policy_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_domain` with what `handler` returns for its input.
+    pub fn with_describe_domain<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeDomainRequest,
+            )
+                -> ::std::result::Result<DescribeDomainResponse, RusotoError<DescribeDomainError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_fleet_metadata` with what `handler` returns for its input.
+    pub fn with_describe_fleet_metadata<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeFleetMetadataRequest,
+            ) -> ::std::result::Result<
+                DescribeFleetMetadataResponse,
+                RusotoError<DescribeFleetMetadataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_fleet_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_identity_provider_configuration` with what `handler` returns for its input.
+    pub fn with_describe_identity_provider_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeIdentityProviderConfigurationRequest,
+            ) -> ::std::result::Result<
+                DescribeIdentityProviderConfigurationResponse,
+                RusotoError<DescribeIdentityProviderConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_identity_provider_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_website_certificate_authority` with what `handler` returns for its input.
+    pub fn with_describe_website_certificate_authority<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DescribeWebsiteCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                DescribeWebsiteCertificateAuthorityResponse,
+                RusotoError<DescribeWebsiteCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_website_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_domain` with what `handler` returns for its input.
+    pub fn with_disassociate_domain<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DisassociateDomainRequest,
+            ) -> ::std::result::Result<
+                DisassociateDomainResponse,
+                RusotoError<DisassociateDomainError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_domain = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_website_authorization_provider` with what `handler` returns for its input.
+    pub fn with_disassociate_website_authorization_provider<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DisassociateWebsiteAuthorizationProviderRequest,
+            ) -> ::std::result::Result<
+                DisassociateWebsiteAuthorizationProviderResponse,
+                RusotoError<DisassociateWebsiteAuthorizationProviderError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_website_authorization_provider = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_website_certificate_authority` with what `handler` returns for its input.
+    pub fn with_disassociate_website_certificate_authority<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                DisassociateWebsiteCertificateAuthorityRequest,
+            ) -> ::std::result::Result<
+                DisassociateWebsiteCertificateAuthorityResponse,
+                RusotoError<DisassociateWebsiteCertificateAuthorityError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_website_certificate_authority = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_devices` with what `handler` returns for its input.
+    pub fn with_list_devices<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                ListDevicesRequest,
+            )
+                -> ::std::result::Result<ListDevicesResponse, RusotoError<ListDevicesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_devices = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_domains` with what `handler` returns for its input.
+    pub fn with_list_domains<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                ListDomainsRequest,
+            )
+                -> ::std::result::Result<ListDomainsResponse, RusotoError<ListDomainsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_domains = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_fleets` with what `handler` returns for its input.
+    pub fn with_list_fleets<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                ListFleetsRequest,
+            )
+                -> ::std::result::Result<ListFleetsResponse, RusotoError<ListFleetsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_fleets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_website_authorization_providers` with what `handler` returns for its input.
+    pub fn with_list_website_authorization_providers<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                ListWebsiteAuthorizationProvidersRequest,
+            ) -> ::std::result::Result<
+                ListWebsiteAuthorizationProvidersResponse,
+                RusotoError<ListWebsiteAuthorizationProvidersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_website_authorization_providers = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_website_certificate_authorities` with what `handler` returns for its input.
+    pub fn with_list_website_certificate_authorities<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                ListWebsiteCertificateAuthoritiesRequest,
+            ) -> ::std::result::Result<
+                ListWebsiteCertificateAuthoritiesResponse,
+                RusotoError<ListWebsiteCertificateAuthoritiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_website_certificate_authorities = Some(Box::new(handler));
+        self
+    }
+    /// Answers `restore_domain_access` with what `handler` returns for its input.
+    pub fn with_restore_domain_access<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                RestoreDomainAccessRequest,
+            ) -> ::std::result::Result<
+                RestoreDomainAccessResponse,
+                RusotoError<RestoreDomainAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.restore_domain_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_domain_access` with what `handler` returns for its input.
+    pub fn with_revoke_domain_access<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                RevokeDomainAccessRequest,
+            ) -> ::std::result::Result<
+                RevokeDomainAccessResponse,
+                RusotoError<RevokeDomainAccessError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_domain_access = Some(Box::new(handler));
+        self
+    }
+    /// Answers `sign_out_user` with what `handler` returns for its input.
+    pub fn with_sign_out_user<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                SignOutUserRequest,
+            )
+                -> ::std::result::Result<SignOutUserResponse, RusotoError<SignOutUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.sign_out_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_audit_stream_configuration` with what `handler` returns for its input.
+    pub fn with_update_audit_stream_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                UpdateAuditStreamConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateAuditStreamConfigurationResponse,
+                RusotoError<UpdateAuditStreamConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_audit_stream_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_company_network_configuration` with what `handler` returns for its input.
+    pub fn with_update_company_network_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                UpdateCompanyNetworkConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateCompanyNetworkConfigurationResponse,
+                RusotoError<UpdateCompanyNetworkConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_company_network_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_device_policy_configuration` with what `handler` returns for its input.
+    pub fn with_update_device_policy_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                UpdateDevicePolicyConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateDevicePolicyConfigurationResponse,
+                RusotoError<UpdateDevicePolicyConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_device_policy_configuration = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_domain_metadata` with what `handler` returns for its input.
+    pub fn with_update_domain_metadata<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                UpdateDomainMetadataRequest,
+            ) -> ::std::result::Result<
+                UpdateDomainMetadataResponse,
+                RusotoError<UpdateDomainMetadataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_domain_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_fleet_metadata` with what `handler` returns for its input.
+    pub fn with_update_fleet_metadata<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                UpdateFleetMetadataRequest,
+            ) -> ::std::result::Result<
+                UpdateFleetMetadataResponse,
+                RusotoError<UpdateFleetMetadataError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_fleet_metadata = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_identity_provider_configuration` with what `handler` returns for its input.
+    pub fn with_update_identity_provider_configuration<F>(mut self, handler: F) -> MockWorklink
+    where
+        F: Fn(
+                UpdateIdentityProviderConfigurationRequest,
+            ) -> ::std::result::Result<
+                UpdateIdentityProviderConfigurationResponse,
+                RusotoError<UpdateIdentityProviderConfigurationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_identity_provider_configuration = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Worklink for MockWorklink {
+    fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> RusotoFuture<AssociateDomainResponse, AssociateDomainError> {
+        match self.associate_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for associate_domain"),
+        }
+    }
+    fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteAuthorizationProviderResponse,
+        AssociateWebsiteAuthorizationProviderError,
+    > {
+        match self.associate_website_authorization_provider {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for associate_website_authorization_provider")
+            }
+        }
+    }
+    fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        AssociateWebsiteCertificateAuthorityResponse,
+        AssociateWebsiteCertificateAuthorityError,
+    > {
+        match self.associate_website_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for associate_website_certificate_authority")
+            }
+        }
+    }
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResponse, CreateFleetError> {
+        match self.create_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for create_fleet"),
+        }
+    }
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResponse, DeleteFleetError> {
+        match self.delete_fleet {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for delete_fleet"),
+        }
+    }
+    fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> RusotoFuture<DescribeAuditStreamConfigurationResponse, DescribeAuditStreamConfigurationError>
+    {
+        match self.describe_audit_stream_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for describe_audit_stream_configuration"),
+        }
+    }
+    fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeCompanyNetworkConfigurationResponse,
+        DescribeCompanyNetworkConfigurationError,
+    > {
+        match self.describe_company_network_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for describe_company_network_configuration")
+            }
+        }
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        match self.describe_device {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for describe_device"),
+        }
+    }
+    fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeDevicePolicyConfigurationResponse,
+        DescribeDevicePolicyConfigurationError,
+    > {
+        match self.describe_device_policy_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for describe_device_policy_configuration"),
+        }
+    }
+    fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> RusotoFuture<DescribeDomainResponse, DescribeDomainError> {
+        match self.describe_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for describe_domain"),
+        }
+    }
+    fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> RusotoFuture<DescribeFleetMetadataResponse, DescribeFleetMetadataError> {
+        match self.describe_fleet_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for describe_fleet_metadata"),
+        }
+    }
+    fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> RusotoFuture<
+        DescribeIdentityProviderConfigurationResponse,
+        DescribeIdentityProviderConfigurationError,
+    > {
+        match self.describe_identity_provider_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for describe_identity_provider_configuration")
+            }
+        }
+    }
+    fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DescribeWebsiteCertificateAuthorityResponse,
+        DescribeWebsiteCertificateAuthorityError,
+    > {
+        match self.describe_website_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for describe_website_certificate_authority")
+            }
+        }
+    }
+    fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> RusotoFuture<DisassociateDomainResponse, DisassociateDomainError> {
+        match self.disassociate_domain {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for disassociate_domain"),
+        }
+    }
+    fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        DisassociateWebsiteAuthorizationProviderError,
+    > {
+        match self.disassociate_website_authorization_provider {
+            Some(ref handler) => handler(input).into(),
+            None => panic!(
+                "MockWorklink has no handler for disassociate_website_authorization_provider"
+            ),
+        }
+    }
+    fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> RusotoFuture<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        DisassociateWebsiteCertificateAuthorityError,
+    > {
+        match self.disassociate_website_certificate_authority {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for disassociate_website_certificate_authority")
             }
-        })
+        }
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        match self.list_devices {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for list_devices"),
+        }
+    }
+    fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> RusotoFuture<ListDomainsResponse, ListDomainsError> {
+        match self.list_domains {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for list_domains"),
+        }
+    }
+    fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> RusotoFuture<ListFleetsResponse, ListFleetsError> {
+        match self.list_fleets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for list_fleets"),
+        }
+    }
+    fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> RusotoFuture<
+        ListWebsiteAuthorizationProvidersResponse,
+        ListWebsiteAuthorizationProvidersError,
+    > {
+        match self.list_website_authorization_providers {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for list_website_authorization_providers"),
+        }
+    }
+    fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<
+        ListWebsiteCertificateAuthoritiesResponse,
+        ListWebsiteCertificateAuthoritiesError,
+    > {
+        match self.list_website_certificate_authorities {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for list_website_certificate_authorities"),
+        }
+    }
+    fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> RusotoFuture<RestoreDomainAccessResponse, RestoreDomainAccessError> {
+        match self.restore_domain_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for restore_domain_access"),
+        }
+    }
+    fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> RusotoFuture<RevokeDomainAccessResponse, RevokeDomainAccessError> {
+        match self.revoke_domain_access {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for revoke_domain_access"),
+        }
     }
-
-    /// <p>Signs the user out from all of their devices. The user can sign in again if they have valid credentials.</p>
     fn sign_out_user(
         &self,
         input: SignOutUserRequest,
     ) -> RusotoFuture<SignOutUserResponse, SignOutUserError> {
-        let request_uri = "/signOutUser";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<SignOutUserResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(SignOutUserError::from_response(response))),
-                )
-            }
-        })
+        match self.sign_out_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for sign_out_user"),
+        }
     }
-
-    /// <p>Updates the audit stream configuration for the fleet.</p>
     fn update_audit_stream_configuration(
         &self,
         input: UpdateAuditStreamConfigurationRequest,
     ) -> RusotoFuture<UpdateAuditStreamConfigurationResponse, UpdateAuditStreamConfigurationError>
     {
-        let request_uri = "/updateAuditStreamConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateAuditStreamConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateAuditStreamConfigurationError::from_response(response))
-                }))
-            }
-        })
+        match self.update_audit_stream_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for update_audit_stream_configuration"),
+        }
     }
-
-    /// <p>Updates the company network configuration for the fleet.</p>
     fn update_company_network_configuration(
         &self,
         input: UpdateCompanyNetworkConfigurationRequest,
@@ -3811,127 +6778,39 @@ impl Worklink for WorklinkClient {
         UpdateCompanyNetworkConfigurationResponse,
         UpdateCompanyNetworkConfigurationError,
     > {
-        let request_uri = "/updateCompanyNetworkConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateCompanyNetworkConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateCompanyNetworkConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.update_company_network_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for update_company_network_configuration"),
+        }
     }
-
-    /// <p>Updates the device policy configuration for the fleet.</p>
     fn update_device_policy_configuration(
         &self,
         input: UpdateDevicePolicyConfigurationRequest,
     ) -> RusotoFuture<UpdateDevicePolicyConfigurationResponse, UpdateDevicePolicyConfigurationError>
     {
-        let request_uri = "/updateDevicePolicyConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDevicePolicyConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateDevicePolicyConfigurationError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        match self.update_device_policy_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for update_device_policy_configuration"),
+        }
     }
-
-    /// <p>Updates domain metadata, such as DisplayName.</p>
     fn update_domain_metadata(
         &self,
         input: UpdateDomainMetadataRequest,
     ) -> RusotoFuture<UpdateDomainMetadataResponse, UpdateDomainMetadataError> {
-        let request_uri = "/updateDomainMetadata";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateDomainMetadataResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateDomainMetadataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_domain_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for update_domain_metadata"),
+        }
     }
-
-    /// <p>Updates fleet metadata, such as DisplayName.</p>
     fn update_fleet_metadata(
         &self,
         input: UpdateFleetMetadataRequest,
     ) -> RusotoFuture<UpdateFleetMetadataResponse, UpdateFleetMetadataError> {
-        let request_uri = "/UpdateFleetMetadata";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateFleetMetadataResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateFleetMetadataError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_fleet_metadata {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorklink has no handler for update_fleet_metadata"),
+        }
     }
-
-    /// <p>Updates the identity provider configuration for the fleet.</p>
     fn update_identity_provider_configuration(
         &self,
         input: UpdateIdentityProviderConfigurationRequest,
@@ -3939,29 +6818,11 @@ impl Worklink for WorklinkClient {
         UpdateIdentityProviderConfigurationResponse,
         UpdateIdentityProviderConfigurationError,
     > {
-        let request_uri = "/updateIdentityProviderConfiguration";
-
-        let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
-        request.set_content_type("application/json".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateIdentityProviderConfigurationResponse, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateIdentityProviderConfigurationError::from_response(
-                        response,
-                    ))
-                }))
+        match self.update_identity_provider_configuration {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorklink has no handler for update_identity_provider_configuration")
             }
-        })
+        }
     }
 }
@@ -22,8 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateDelegateToResourceRequest {
     /// <p>The member (user or group) to associate to the resource.</p>
     #[serde(rename = "EntityId")]
@@ -36,11 +38,50 @@ pub struct AssociateDelegateToResourceRequest {
     pub resource_id: String,
 }
 
+impl Validate for AssociateDelegateToResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateDelegateToResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateMemberToGroupRequest {
     /// <p>The group to which the member (user or group) is associated.</p>
     #[serde(rename = "GroupId")]
@@ -53,8 +94,46 @@ pub struct AssociateMemberToGroupRequest {
     pub organization_id: String,
 }
 
+impl Validate for AssociateMemberToGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_id"),
+            self.group_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "member_id"),
+            self.member_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "member_id"),
+            &self.member_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateMemberToGroupResponse {}
 
 /// <p>At least one delegate must be associated to the resource to disable automatic replies from the resource.</p>
@@ -75,6 +154,7 @@ pub struct BookingOptions {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateAliasRequest {
     /// <p>The alias to add to the member set.</p>
     #[serde(rename = "Alias")]
@@ -87,11 +167,45 @@ pub struct CreateAliasRequest {
     pub organization_id: String,
 }
 
+impl Validate for CreateAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alias"),
+            self.alias.chars().count(),
+            Some(1),
+            Some(254),
+        );
+        errors.check_pattern(
+            &field_path(path, "alias"),
+            &self.alias,
+            "[a-zA-Z0-9._%+-]{1,64}@[a-zA-Z0-9.-]+\\.[a-zA-Z-]{2,}",
+        );
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(12),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateAliasResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupRequest {
     /// <p>The name of the group.</p>
     #[serde(rename = "Name")]
@@ -101,8 +215,31 @@ pub struct CreateGroupRequest {
     pub organization_id: String,
 }
 
+impl Validate for CreateGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(&field_path(path, "name"), &self.name, "[\\u0020-\\u00FF]+");
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupResponse {
     /// <p>The identifier of the group.</p>
     #[serde(rename = "GroupId")]
@@ -111,6 +248,7 @@ pub struct CreateGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateResourceRequest {
     /// <p>The name of the new resource.</p>
     #[serde(rename = "Name")]
@@ -123,8 +261,35 @@ pub struct CreateResourceRequest {
     pub type_: String,
 }
 
+impl Validate for CreateResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(20),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\w\\-.]+(@[a-zA-Z0-9.\\-]+\\.[a-zA-Z0-9-]{2,})?",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateResourceResponse {
     /// <p>The identifier of the new resource.</p>
     #[serde(rename = "ResourceId")]
@@ -133,6 +298,7 @@ pub struct CreateResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateUserRequest {
     /// <p>The display name for the new user.</p>
     #[serde(rename = "DisplayName")]
@@ -148,8 +314,52 @@ pub struct CreateUserRequest {
     pub password: String,
 }
 
+impl Validate for CreateUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "display_name"),
+            self.display_name.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "[\\w\\-.]+(@[a-zA-Z0-9.\\-]+\\.[a-zA-Z0-9-]{2,})?",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "password"),
+            &self.password,
+            "[\\u0020-\\u00FF]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateUserResponse {
     /// <p>The identifier for the new user.</p>
     #[serde(rename = "UserId")]
@@ -159,7 +369,7 @@ pub struct CreateUserResponse {
 
 /// <p>The name of the attribute, which is one of the values defined in the UserAttribute enumeration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Delegate {
     /// <p>The identifier for the user or group associated as the resource's delegate.</p>
     #[serde(rename = "Id")]
@@ -170,6 +380,7 @@ pub struct Delegate {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteAliasRequest {
     /// <p>The aliases to be removed from the user's set of aliases. Duplicate entries in the list are collapsed into single entries (the list is transformed into a set).</p>
     #[serde(rename = "Alias")]
@@ -182,11 +393,45 @@ pub struct DeleteAliasRequest {
     pub organization_id: String,
 }
 
+impl Validate for DeleteAliasRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "alias"),
+            self.alias.chars().count(),
+            Some(1),
+            Some(254),
+        );
+        errors.check_pattern(
+            &field_path(path, "alias"),
+            &self.alias,
+            "[a-zA-Z0-9._%+-]{1,64}@[a-zA-Z0-9.-]+\\.[a-zA-Z-]{2,}",
+        );
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(12),
+            Some(256),
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteAliasResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGroupRequest {
     /// <p>The identifier of the group to be deleted.</p>
     #[serde(rename = "GroupId")]
@@ -196,11 +441,39 @@ pub struct DeleteGroupRequest {
     pub organization_id: String,
 }
 
+impl Validate for DeleteGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_id"),
+            self.group_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteMailboxPermissionsRequest {
     /// <p>The identifier of the member (user or group)that owns the mailbox.</p>
     #[serde(rename = "EntityId")]
@@ -213,11 +486,50 @@ pub struct DeleteMailboxPermissionsRequest {
     pub organization_id: String,
 }
 
+impl Validate for DeleteMailboxPermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "grantee_id"),
+            self.grantee_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "grantee_id"),
+            &self.grantee_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteMailboxPermissionsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteResourceRequest {
     /// <p>The identifier associated with the organization from which the resource is deleted.</p>
     #[serde(rename = "OrganizationId")]
@@ -227,11 +539,39 @@ pub struct DeleteResourceRequest {
     pub resource_id: String,
 }
 
+impl Validate for DeleteResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteUserRequest {
     /// <p>The organization that contains the user to be deleted.</p>
     #[serde(rename = "OrganizationId")]
@@ -241,11 +581,39 @@ pub struct DeleteUserRequest {
     pub user_id: String,
 }
 
+impl Validate for DeleteUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteUserResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeregisterFromWorkMailRequest {
     /// <p>The identifier for the member (user or group) to be updated.</p>
     #[serde(rename = "EntityId")]
@@ -255,11 +623,39 @@ pub struct DeregisterFromWorkMailRequest {
     pub organization_id: String,
 }
 
+impl Validate for DeregisterFromWorkMailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeregisterFromWorkMailResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeGroupRequest {
     /// <p>The identifier for the group to be described.</p>
     #[serde(rename = "GroupId")]
@@ -269,8 +665,35 @@ pub struct DescribeGroupRequest {
     pub organization_id: String,
 }
 
+impl Validate for DescribeGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_id"),
+            self.group_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeGroupResponse {
     /// <p>The date and time when a user was deregistered from WorkMail, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
@@ -299,14 +722,31 @@ pub struct DescribeGroupResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeOrganizationRequest {
     /// <p>The identifier for the organization to be described.</p>
     #[serde(rename = "OrganizationId")]
     pub organization_id: String,
 }
 
+impl Validate for DescribeOrganizationRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeOrganizationResponse {
     /// <p>The alias for an organization.</p>
     #[serde(rename = "Alias")]
@@ -343,6 +783,7 @@ pub struct DescribeOrganizationResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeResourceRequest {
     /// <p>The identifier associated with the organization for which the resource is described.</p>
     #[serde(rename = "OrganizationId")]
@@ -352,8 +793,35 @@ pub struct DescribeResourceRequest {
     pub resource_id: String,
 }
 
+impl Validate for DescribeResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeResourceResponse {
     /// <p>The booking options for the described resource.</p>
     #[serde(rename = "BookingOptions")]
@@ -390,6 +858,7 @@ pub struct DescribeResourceResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeUserRequest {
     /// <p>The identifier for the organization under which the user exists.</p>
     #[serde(rename = "OrganizationId")]
@@ -399,8 +868,35 @@ pub struct DescribeUserRequest {
     pub user_id: String,
 }
 
+impl Validate for DescribeUserRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeUserResponse {
     /// <p>The date and time at which the user was disabled for Amazon WorkMail usage, in UNIX epoch time format.</p>
     #[serde(rename = "DisabledDate")]
@@ -437,6 +933,7 @@ pub struct DescribeUserResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateDelegateFromResourceRequest {
     /// <p>The identifier for the member (user, group) to be removed from the resource's delegates.</p>
     #[serde(rename = "EntityId")]
@@ -449,11 +946,50 @@ pub struct DisassociateDelegateFromResourceRequest {
     pub resource_id: String,
 }
 
+impl Validate for DisassociateDelegateFromResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateDelegateFromResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateMemberFromGroupRequest {
     /// <p>The identifier for the group from which members are removed.</p>
     #[serde(rename = "GroupId")]
@@ -466,11 +1002,50 @@ pub struct DisassociateMemberFromGroupRequest {
     pub organization_id: String,
 }
 
+impl Validate for DisassociateMemberFromGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_id"),
+            self.group_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "member_id"),
+            self.member_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "member_id"),
+            &self.member_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateMemberFromGroupResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetMailboxDetailsRequest {
     /// <p>The identifier for the organization that contains the user whose mailbox details are being requested.</p>
     #[serde(rename = "OrganizationId")]
@@ -480,8 +1055,35 @@ pub struct GetMailboxDetailsRequest {
     pub user_id: String,
 }
 
+impl Validate for GetMailboxDetailsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetMailboxDetailsResponse {
     /// <p>The maximum allowed mailbox size, in MB, for the specified user.</p>
     #[serde(rename = "MailboxQuota")]
@@ -495,7 +1097,7 @@ pub struct GetMailboxDetailsResponse {
 
 /// <p>The representation of an Amazon WorkMail group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Group {
     /// <p>The date indicating when the group was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
@@ -524,6 +1126,7 @@ pub struct Group {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAliasesRequest {
     /// <p>The identifier for the entity for which to list the aliases.</p>
     #[serde(rename = "EntityId")]
@@ -541,8 +1144,46 @@ pub struct ListAliasesRequest {
     pub organization_id: String,
 }
 
+impl Validate for ListAliasesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(12),
+            Some(256),
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAliasesResponse {
     /// <p>The entity's paginated aliases.</p>
     #[serde(rename = "Aliases")]
@@ -555,6 +1196,7 @@ pub struct ListAliasesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupMembersRequest {
     /// <p>The identifier for the group to which the members (users or groups) are associated.</p>
     #[serde(rename = "GroupId")]
@@ -572,8 +1214,51 @@ pub struct ListGroupMembersRequest {
     pub organization_id: String,
 }
 
+impl Validate for ListGroupMembersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_id"),
+            self.group_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupMembersResponse {
     /// <p>The members associated to the group.</p>
     #[serde(rename = "Members")]
@@ -586,6 +1271,7 @@ pub struct ListGroupMembersResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListGroupsRequest {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "MaxResults")]
@@ -600,8 +1286,40 @@ pub struct ListGroupsRequest {
     pub organization_id: String,
 }
 
+impl Validate for ListGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListGroupsResponse {
     /// <p>The overview of groups for an organization.</p>
     #[serde(rename = "Groups")]
@@ -614,6 +1332,7 @@ pub struct ListGroupsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListMailboxPermissionsRequest {
     /// <p>The identifier of the user, group, or resource for which to list mailbox permissions.</p>
     #[serde(rename = "EntityId")]
@@ -631,8 +1350,51 @@ pub struct ListMailboxPermissionsRequest {
     pub organization_id: String,
 }
 
+impl Validate for ListMailboxPermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListMailboxPermissionsResponse {
     /// <p>The token to use to retrieve the next page of results. The value is "null" when there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -645,6 +1407,7 @@ pub struct ListMailboxPermissionsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListOrganizationsRequest {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "MaxResults")]
@@ -656,8 +1419,29 @@ pub struct ListOrganizationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListOrganizationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListOrganizationsResponse {
     /// <p>The token to use to retrieve the next page of results. The value is "null" when there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -670,6 +1454,7 @@ pub struct ListOrganizationsResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourceDelegatesRequest {
     /// <p>The number of maximum results in a page.</p>
     #[serde(rename = "MaxResults")]
@@ -687,8 +1472,51 @@ pub struct ListResourceDelegatesRequest {
     pub resource_id: String,
 }
 
+impl Validate for ListResourceDelegatesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourceDelegatesResponse {
     /// <p>One page of the resource's delegates.</p>
     #[serde(rename = "Delegates")]
@@ -701,6 +1529,7 @@ pub struct ListResourceDelegatesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListResourcesRequest {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "MaxResults")]
@@ -715,8 +1544,40 @@ pub struct ListResourcesRequest {
     pub organization_id: String,
 }
 
+impl Validate for ListResourcesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListResourcesResponse {
     /// <p> The token used to paginate through all the organization's resources. While results are still available, it has an associated value. When the last page is reached, the token is empty.</p>
     #[serde(rename = "NextToken")]
@@ -729,6 +1590,7 @@ pub struct ListResourcesResponse {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListUsersRequest {
     /// <p>The maximum number of results to return in a single call.</p>
     #[serde(rename = "MaxResults")]
@@ -743,8 +1605,40 @@ pub struct ListUsersRequest {
     pub organization_id: String,
 }
 
+impl Validate for ListUsersRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(100));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(1024),
+            );
+            errors.check_pattern(
+                &field_path(path, "next_token"),
+                &value,
+                "[\\S\\s]*|[a-zA-Z0-9/+=]{1,1024}",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListUsersResponse {
     /// <p> The token to use to retrieve the next page of results. This value is `null` when there are no more results to return.</p>
     #[serde(rename = "NextToken")]
@@ -758,7 +1652,7 @@ pub struct ListUsersResponse {
 
 /// <p>The representation of a user or group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Member {
     /// <p>The date indicating when the member was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
@@ -788,7 +1682,7 @@ pub struct Member {
 
 /// <p>The representation of an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OrganizationSummary {
     /// <p>The alias associated with the organization.</p>
     #[serde(rename = "Alias")]
@@ -810,7 +1704,7 @@ pub struct OrganizationSummary {
 
 /// <p>Permission granted to a user, group, or resource to access a certain aspect of another user, group, or resource mailbox.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Permission {
     /// <p>The identifier of the user, group, or resource to which the permissions are granted.</p>
     #[serde(rename = "GranteeId")]
@@ -824,6 +1718,7 @@ pub struct Permission {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutMailboxPermissionsRequest {
     /// <p>The identifier of the user, group, or resource for which to update mailbox permissions.</p>
     #[serde(rename = "EntityId")]
@@ -839,11 +1734,50 @@ pub struct PutMailboxPermissionsRequest {
     pub permission_values: Vec<String>,
 }
 
+impl Validate for PutMailboxPermissionsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "grantee_id"),
+            self.grantee_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "grantee_id"),
+            &self.grantee_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutMailboxPermissionsResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RegisterToWorkMailRequest {
     /// <p>The email for the user, group, or resource to be updated.</p>
     #[serde(rename = "Email")]
@@ -856,11 +1790,50 @@ pub struct RegisterToWorkMailRequest {
     pub organization_id: String,
 }
 
+impl Validate for RegisterToWorkMailRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "email"),
+            self.email.chars().count(),
+            Some(1),
+            Some(254),
+        );
+        errors.check_pattern(
+            &field_path(path, "email"),
+            &self.email,
+            "[a-zA-Z0-9._%+-]{1,64}@[a-zA-Z0-9.-]+\\.[a-zA-Z-]{2,}",
+        );
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RegisterToWorkMailResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ResetPasswordRequest {
     /// <p>The identifier of the organization that contains the user for which the password is reset.</p>
     #[serde(rename = "OrganizationId")]
@@ -873,13 +1846,46 @@ pub struct ResetPasswordRequest {
     pub user_id: String,
 }
 
+impl Validate for ResetPasswordRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "password"),
+            self.password.chars().count(),
+            None,
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "password"),
+            &self.password,
+            "[\\u0020-\\u00FF]+",
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(12),
+            Some(256),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResetPasswordResponse {}
 
 /// <p>The representation of a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Resource {
     /// <p>The date indicating when the resource was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
@@ -912,6 +1918,7 @@ pub struct Resource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateMailboxQuotaRequest {
     /// <p>The updated mailbox quota, in MB, for the specified user.</p>
     #[serde(rename = "MailboxQuota")]
@@ -924,11 +1931,45 @@ pub struct UpdateMailboxQuotaRequest {
     pub user_id: String,
 }
 
+impl Validate for UpdateMailboxQuotaRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_range(
+            &field_path(path, "mailbox_quota"),
+            self.mailbox_quota,
+            Some(1),
+            None,
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "user_id"),
+            self.user_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "user_id"),
+            &self.user_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateMailboxQuotaResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdatePrimaryEmailAddressRequest {
     /// <p>The value of the email to be updated as primary.</p>
     #[serde(rename = "Email")]
@@ -941,11 +1982,50 @@ pub struct UpdatePrimaryEmailAddressRequest {
     pub organization_id: String,
 }
 
+impl Validate for UpdatePrimaryEmailAddressRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "email"),
+            self.email.chars().count(),
+            Some(1),
+            Some(254),
+        );
+        errors.check_pattern(
+            &field_path(path, "email"),
+            &self.email,
+            "[a-zA-Z0-9._%+-]{1,64}@[a-zA-Z0-9.-]+\\.[a-zA-Z-]{2,}",
+        );
+        errors.check_length(
+            &field_path(path, "entity_id"),
+            self.entity_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "entity_id"),
+            &self.entity_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdatePrimaryEmailAddressResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateResourceRequest {
     /// <p>The resource's booking options to be updated.</p>
     #[serde(rename = "BookingOptions")]
@@ -963,15 +2043,55 @@ pub struct UpdateResourceRequest {
     pub resource_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateResourceResponse {}
-
-/// <p>The representation of an Amazon WorkMail user.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct User {
-    /// <p>The date indicating when the user was disabled from Amazon WorkMail use.</p>
+impl Validate for UpdateResourceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.name {
+            errors.check_length(
+                &field_path(path, "name"),
+                value.chars().count(),
+                Some(1),
+                Some(20),
+            );
+            errors.check_pattern(
+                &field_path(path, "name"),
+                &value,
+                "[\\w\\-.]+(@[a-zA-Z0-9.\\-]+\\.[a-zA-Z0-9-]{2,})?",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "organization_id"),
+            self.organization_id.chars().count(),
+            Some(34),
+            Some(34),
+        );
+        errors.check_pattern(
+            &field_path(path, "organization_id"),
+            &self.organization_id,
+            "^m-[0-9a-f]{32}$",
+        );
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "resource_id"),
+            &self.resource_id,
+            "[a-zA-Z0-9._%+@-]+",
+        );
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct UpdateResourceResponse {}
+
+/// <p>The representation of an Amazon WorkMail user.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
+pub struct User {
+    /// <p>The date indicating when the user was disabled from Amazon WorkMail use.</p>
     #[serde(rename = "DisabledDate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disabled_date: Option<f64>,
@@ -1051,7 +2171,12 @@ impl AssociateDelegateToResourceError {
                         AssociateDelegateToResourceError::OrganizationState(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1137,7 +2262,12 @@ impl AssociateMemberToGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1212,7 +2342,12 @@ impl CreateAliasError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(CreateAliasError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1289,7 +2424,12 @@ impl CreateGroupError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(CreateGroupError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1361,7 +2501,12 @@ impl CreateResourceError {
                 "ReservedNameException" => {
                     return RusotoError::Service(CreateResourceError::ReservedName(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1442,7 +2587,12 @@ impl CreateUserError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(CreateUserError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1503,7 +2653,12 @@ impl DeleteAliasError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(DeleteAliasError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1572,7 +2727,12 @@ impl DeleteGroupError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(DeleteGroupError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1641,7 +2801,12 @@ impl DeleteMailboxPermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1693,7 +2858,12 @@ impl DeleteResourceError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(DeleteResourceError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1761,7 +2931,12 @@ impl DeleteUserError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(DeleteUserError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1828,7 +3003,12 @@ impl DeregisterFromWorkMailError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1880,7 +3060,12 @@ impl DescribeGroupError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(DescribeGroupError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1925,7 +3110,12 @@ impl DescribeOrganizationError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1976,7 +3166,12 @@ impl DescribeResourceError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(DescribeResourceError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2027,7 +3222,12 @@ impl DescribeUserError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(DescribeUserError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2095,7 +3295,12 @@ impl DisassociateDelegateFromResourceError {
                         DisassociateDelegateFromResourceError::OrganizationState(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2187,7 +3392,12 @@ impl DisassociateMemberFromGroupError {
                         DisassociateMemberFromGroupError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2241,7 +3451,12 @@ impl GetMailboxDetailsError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(GetMailboxDetailsError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2296,7 +3511,12 @@ impl ListAliasesError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(ListAliasesError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2355,7 +3575,12 @@ impl ListGroupMembersError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(ListGroupMembersError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2407,7 +3632,12 @@ impl ListGroupsError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(ListGroupsError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2466,7 +3696,12 @@ impl ListMailboxPermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2502,7 +3737,12 @@ impl ListOrganizationsError {
                 "InvalidParameterException" => {
                     return RusotoError::Service(ListOrganizationsError::InvalidParameter(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2563,7 +3803,12 @@ impl ListResourceDelegatesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2610,7 +3855,12 @@ impl ListResourcesError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(ListResourcesError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2655,7 +3905,12 @@ impl ListUsersError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(ListUsersError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2718,7 +3973,12 @@ impl PutMailboxPermissionsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2819,7 +4079,12 @@ impl RegisterToWorkMailError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2904,7 +4169,12 @@ impl ResetPasswordError {
                 "UnsupportedOperationException" => {
                     return RusotoError::Service(ResetPasswordError::UnsupportedOperation(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2969,7 +4239,12 @@ impl UpdateMailboxQuotaError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3080,7 +4355,12 @@ impl UpdatePrimaryEmailAddressError {
                         UpdatePrimaryEmailAddressError::UnsupportedOperation(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3170,7 +4450,12 @@ impl UpdateResourceError {
                 "OrganizationStateException" => {
                     return RusotoError::Service(UpdateResourceError::OrganizationState(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -3398,955 +4683,1835 @@ pub trait Workmail {
         input: UpdateResourceRequest,
     ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError>;
 }
-/// A client for the Amazon WorkMail API.
-#[derive(Clone)]
-pub struct WorkmailClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl WorkmailClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> WorkmailClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> WorkmailClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> WorkmailClient {
-        WorkmailClient { client, region }
-    }
-}
-
-impl Workmail for WorkmailClient {
-    /// <p>Adds a member (user or group) to the resource's set of delegates.</p>
+const _: Option<&dyn Workmail> = None;
+impl<'a, T: Workmail + ?Sized> Workmail for &'a T {
     fn associate_delegate_to_resource(
         &self,
         input: AssociateDelegateToResourceRequest,
     ) -> RusotoFuture<AssociateDelegateToResourceResponse, AssociateDelegateToResourceError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkMailService.AssociateDelegateToResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateDelegateToResourceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(AssociateDelegateToResourceError::from_response(response))
-                }))
-            }
-        })
+        (**self).associate_delegate_to_resource(input)
     }
-
-    /// <p>Adds a member (user or group) to the group's set.</p>
     fn associate_member_to_group(
         &self,
         input: AssociateMemberToGroupRequest,
     ) -> RusotoFuture<AssociateMemberToGroupResponse, AssociateMemberToGroupError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.AssociateMemberToGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateMemberToGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(AssociateMemberToGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).associate_member_to_group(input)
     }
-
-    /// <p>Adds an alias to the set of a given member (user or group) of Amazon WorkMail.</p>
     fn create_alias(
         &self,
         input: CreateAliasRequest,
     ) -> RusotoFuture<CreateAliasResponse, CreateAliasError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.CreateAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateAliasResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_alias(input)
     }
-
-    /// <p>Creates a group that can be used in Amazon WorkMail by calling the <a>RegisterToWorkMail</a> operation.</p>
     fn create_group(
         &self,
         input: CreateGroupRequest,
     ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.CreateGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_group(input)
     }
-
-    /// <p>Creates a new Amazon WorkMail resource. </p>
     fn create_resource(
         &self,
         input: CreateResourceRequest,
     ) -> RusotoFuture<CreateResourceResponse, CreateResourceError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.CreateResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_resource(input)
     }
-
-    /// <p>Creates a user who can be used in Amazon WorkMail by calling the <a>RegisterToWorkMail</a> operation.</p>
     fn create_user(
         &self,
         input: CreateUserRequest,
     ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.CreateUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_user(input)
     }
-
-    /// <p>Remove one or more specified aliases from a set of aliases for a given user.</p>
     fn delete_alias(
         &self,
         input: DeleteAliasRequest,
     ) -> RusotoFuture<DeleteAliasResponse, DeleteAliasError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DeleteAlias");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteAliasResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_alias(input)
     }
-
-    /// <p>Deletes a group from Amazon WorkMail.</p>
     fn delete_group(
         &self,
         input: DeleteGroupRequest,
     ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DeleteGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_group(input)
     }
-
-    /// <p>Deletes permissions granted to a member (user or group).</p>
     fn delete_mailbox_permissions(
         &self,
         input: DeleteMailboxPermissionsRequest,
     ) -> RusotoFuture<DeleteMailboxPermissionsResponse, DeleteMailboxPermissionsError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DeleteMailboxPermissions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteMailboxPermissionsResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DeleteMailboxPermissionsError::from_response(response))
-                }))
-            }
-        })
+        (**self).delete_mailbox_permissions(input)
     }
-
-    /// <p>Deletes the specified resource. </p>
     fn delete_resource(
         &self,
         input: DeleteResourceRequest,
     ) -> RusotoFuture<DeleteResourceResponse, DeleteResourceError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DeleteResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_resource(input)
     }
-
-    /// <p>Deletes a user from Amazon WorkMail and all subsequent systems. Before you can delete a user, the user state must be <code>DISABLED</code>. Use the <a>DescribeUser</a> action to confirm the user state.</p> <p>Deleting a user is permanent and cannot be undone. WorkMail archives user mailboxes for 30 days before they are permanently removed.</p>
     fn delete_user(
         &self,
         input: DeleteUserRequest,
     ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DeleteUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_user(input)
     }
-
-    /// <p>Mark a user, group, or resource as no longer used in Amazon WorkMail. This action disassociates the mailbox and schedules it for clean-up. WorkMail keeps mailboxes for 30 days before they are permanently removed. The functionality in the console is <i>Disable</i>.</p>
     fn deregister_from_work_mail(
         &self,
         input: DeregisterFromWorkMailRequest,
     ) -> RusotoFuture<DeregisterFromWorkMailResponse, DeregisterFromWorkMailError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DeregisterFromWorkMail");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeregisterFromWorkMailResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeregisterFromWorkMailError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).deregister_from_work_mail(input)
     }
-
-    /// <p>Returns the data available for the group.</p>
     fn describe_group(
         &self,
         input: DescribeGroupRequest,
     ) -> RusotoFuture<DescribeGroupResponse, DescribeGroupError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DescribeGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGroupResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_group(input)
     }
-
-    /// <p>Provides more information regarding a given organization based on its identifier.</p>
     fn describe_organization(
         &self,
         input: DescribeOrganizationRequest,
     ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DescribeOrganization");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeOrganizationResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DescribeOrganizationError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).describe_organization(input)
     }
-
-    /// <p>Returns the data available for the resource.</p>
     fn describe_resource(
         &self,
         input: DescribeResourceRequest,
     ) -> RusotoFuture<DescribeResourceResponse, DescribeResourceError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DescribeResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeResourceError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_resource(input)
     }
-
-    /// <p>Provides information regarding the user.</p>
     fn describe_user(
         &self,
         input: DescribeUserRequest,
     ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.DescribeUser");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeUserResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeUserError::from_response(response))),
-                )
-            }
-        })
+        (**self).describe_user(input)
     }
-
-    /// <p>Removes a member from the resource's set of delegates.</p>
     fn disassociate_delegate_from_resource(
         &self,
         input: DisassociateDelegateFromResourceRequest,
     ) -> RusotoFuture<DisassociateDelegateFromResourceResponse, DisassociateDelegateFromResourceError>
     {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkMailService.DisassociateDelegateFromResource",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateDelegateFromResourceResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateDelegateFromResourceError::from_response(
-                        response,
-                    ))
-                }))
-            }
-        })
+        (**self).disassociate_delegate_from_resource(input)
     }
-
-    /// <p>Removes a member from a group.</p>
     fn disassociate_member_from_group(
         &self,
         input: DisassociateMemberFromGroupRequest,
     ) -> RusotoFuture<DisassociateMemberFromGroupResponse, DisassociateMemberFromGroupError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkMailService.DisassociateMemberFromGroup",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateMemberFromGroupResponse, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DisassociateMemberFromGroupError::from_response(response))
-                }))
-            }
-        })
+        (**self).disassociate_member_from_group(input)
     }
-
-    /// <p>Requests a user's mailbox details for a specified organization and user.</p>
     fn get_mailbox_details(
         &self,
         input: GetMailboxDetailsRequest,
     ) -> RusotoFuture<GetMailboxDetailsResponse, GetMailboxDetailsError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.GetMailboxDetails");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<GetMailboxDetailsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetMailboxDetailsError::from_response(response))),
-                )
-            }
-        })
+        (**self).get_mailbox_details(input)
     }
-
-    /// <p>Creates a paginated call to list the aliases associated with a given entity.</p>
     fn list_aliases(
         &self,
         input: ListAliasesRequest,
     ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListAliases");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAliasesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListAliasesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_aliases(input)
     }
-
-    /// <p>Returns an overview of the members of a group. Users and groups can be members of a group.</p>
     fn list_group_members(
         &self,
         input: ListGroupMembersRequest,
     ) -> RusotoFuture<ListGroupMembersResponse, ListGroupMembersError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListGroupMembers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGroupMembersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGroupMembersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_group_members(input)
     }
-
-    /// <p>Returns summaries of the organization's groups.</p>
     fn list_groups(
         &self,
         input: ListGroupsRequest,
     ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGroupsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGroupsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_groups(input)
     }
-
-    /// <p>Lists the mailbox permissions associated with a user, group, or resource mailbox.</p>
     fn list_mailbox_permissions(
         &self,
         input: ListMailboxPermissionsRequest,
     ) -> RusotoFuture<ListMailboxPermissionsResponse, ListMailboxPermissionsError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListMailboxPermissions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListMailboxPermissionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListMailboxPermissionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_mailbox_permissions(input)
     }
-
-    /// <p>Returns summaries of the customer's non-deleted organizations.</p>
     fn list_organizations(
         &self,
         input: ListOrganizationsRequest,
     ) -> RusotoFuture<ListOrganizationsResponse, ListOrganizationsError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListOrganizations");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListOrganizationsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListOrganizationsError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_organizations(input)
     }
-
-    /// <p>Lists the delegates associated with a resource. Users and groups can be resource delegates and answer requests on behalf of the resource.</p>
     fn list_resource_delegates(
         &self,
         input: ListResourceDelegatesRequest,
     ) -> RusotoFuture<ListResourceDelegatesResponse, ListResourceDelegatesError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListResourceDelegates");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourceDelegatesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ListResourceDelegatesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).list_resource_delegates(input)
     }
-
-    /// <p>Returns summaries of the organization's resources.</p>
     fn list_resources(
         &self,
         input: ListResourcesRequest,
     ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListResources");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListResourcesResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListResourcesError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_resources(input)
     }
-
-    /// <p>Returns summaries of the organization's users.</p>
     fn list_users(
         &self,
         input: ListUsersRequest,
     ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ListUsers");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListUsersResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListUsersError::from_response(response))),
-                )
-            }
-        })
+        (**self).list_users(input)
     }
-
-    /// <p>Sets permissions for a user, group, or resource. This replaces any pre-existing permissions.</p>
     fn put_mailbox_permissions(
         &self,
         input: PutMailboxPermissionsRequest,
     ) -> RusotoFuture<PutMailboxPermissionsResponse, PutMailboxPermissionsError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.PutMailboxPermissions");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<PutMailboxPermissionsResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(PutMailboxPermissionsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        (**self).put_mailbox_permissions(input)
     }
-
-    /// <p>Registers an existing and disabled user, group, or resource for Amazon WorkMail use by associating a mailbox and calendaring capabilities. It performs no change if the user, group, or resource is enabled and fails if the user, group, or resource is deleted. This operation results in the accumulation of costs. For more information, see <a href="https://aws.amazon.com//workmail/pricing">Pricing</a>. The equivalent console functionality for this operation is <i>Enable</i>. </p> <p>Users can either be created by calling the <a>CreateUser</a> API operation or they can be synchronized from your directory. For more information, see <a>DeregisterFromWorkMail</a>.</p>
     fn register_to_work_mail(
         &self,
         input: RegisterToWorkMailRequest,
     ) -> RusotoFuture<RegisterToWorkMailResponse, RegisterToWorkMailError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.RegisterToWorkMail");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RegisterToWorkMailResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RegisterToWorkMailError::from_response(response))),
-                )
-            }
-        })
+        (**self).register_to_work_mail(input)
     }
-
-    /// <p>Allows the administrator to reset the password for a user.</p>
     fn reset_password(
         &self,
         input: ResetPasswordRequest,
     ) -> RusotoFuture<ResetPasswordResponse, ResetPasswordError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.ResetPassword");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ResetPasswordResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ResetPasswordError::from_response(response))),
-                )
-            }
-        })
+        (**self).reset_password(input)
     }
-
-    /// <p>Updates a user's current mailbox quota for a specified organization and user.</p>
     fn update_mailbox_quota(
         &self,
         input: UpdateMailboxQuotaRequest,
     ) -> RusotoFuture<UpdateMailboxQuotaResponse, UpdateMailboxQuotaError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.UpdateMailboxQuota");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateMailboxQuotaResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateMailboxQuotaError::from_response(response))),
-                )
-            }
-        })
+        (**self).update_mailbox_quota(input)
     }
-
-    /// <p>Updates the primary email for a user, group, or resource. The current email is moved into the list of aliases (or swapped between an existing alias and the current primary email), and the email provided in the input is promoted as the primary.</p>
     fn update_primary_email_address(
         &self,
         input: UpdatePrimaryEmailAddressRequest,
     ) -> RusotoFuture<UpdatePrimaryEmailAddressResponse, UpdatePrimaryEmailAddressError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.UpdatePrimaryEmailAddress");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        (**self).update_primary_email_address(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+}
+impl<T: Workmail + ?Sized> Workmail for Box<T> {
+    fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> RusotoFuture<AssociateDelegateToResourceResponse, AssociateDelegateToResourceError> {
+        (**self).associate_delegate_to_resource(input)
+    }
+    fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> RusotoFuture<AssociateMemberToGroupResponse, AssociateMemberToGroupError> {
+        (**self).associate_member_to_group(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResponse, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<CreateResourceResponse, CreateResourceError> {
+        (**self).create_resource(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> RusotoFuture<DeleteAliasResponse, DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> RusotoFuture<DeleteMailboxPermissionsResponse, DeleteMailboxPermissionsError> {
+        (**self).delete_mailbox_permissions(input)
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<DeleteResourceResponse, DeleteResourceError> {
+        (**self).delete_resource(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> RusotoFuture<DeregisterFromWorkMailResponse, DeregisterFromWorkMailError> {
+        (**self).deregister_from_work_mail(input)
+    }
+    fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> RusotoFuture<DescribeGroupResponse, DescribeGroupError> {
+        (**self).describe_group(input)
+    }
+    fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        (**self).describe_organization(input)
+    }
+    fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> RusotoFuture<DescribeResourceResponse, DescribeResourceError> {
+        (**self).describe_resource(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> RusotoFuture<DisassociateDelegateFromResourceResponse, DisassociateDelegateFromResourceError>
+    {
+        (**self).disassociate_delegate_from_resource(input)
+    }
+    fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> RusotoFuture<DisassociateMemberFromGroupResponse, DisassociateMemberFromGroupError> {
+        (**self).disassociate_member_from_group(input)
+    }
+    fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> RusotoFuture<GetMailboxDetailsResponse, GetMailboxDetailsError> {
+        (**self).get_mailbox_details(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> RusotoFuture<ListGroupMembersResponse, ListGroupMembersError> {
+        (**self).list_group_members(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> RusotoFuture<ListMailboxPermissionsResponse, ListMailboxPermissionsError> {
+        (**self).list_mailbox_permissions(input)
+    }
+    fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> RusotoFuture<ListOrganizationsResponse, ListOrganizationsError> {
+        (**self).list_organizations(input)
+    }
+    fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> RusotoFuture<ListResourceDelegatesResponse, ListResourceDelegatesError> {
+        (**self).list_resource_delegates(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> RusotoFuture<PutMailboxPermissionsResponse, PutMailboxPermissionsError> {
+        (**self).put_mailbox_permissions(input)
+    }
+    fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> RusotoFuture<RegisterToWorkMailResponse, RegisterToWorkMailError> {
+        (**self).register_to_work_mail(input)
+    }
+    fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> RusotoFuture<ResetPasswordResponse, ResetPasswordError> {
+        (**self).reset_password(input)
+    }
+    fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> RusotoFuture<UpdateMailboxQuotaResponse, UpdateMailboxQuotaError> {
+        (**self).update_mailbox_quota(input)
+    }
+    fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> RusotoFuture<UpdatePrimaryEmailAddressResponse, UpdatePrimaryEmailAddressError> {
+        (**self).update_primary_email_address(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+}
+impl<T: Workmail + ?Sized> Workmail for ::std::sync::Arc<T> {
+    fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> RusotoFuture<AssociateDelegateToResourceResponse, AssociateDelegateToResourceError> {
+        (**self).associate_delegate_to_resource(input)
+    }
+    fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> RusotoFuture<AssociateMemberToGroupResponse, AssociateMemberToGroupError> {
+        (**self).associate_member_to_group(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResponse, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<CreateResourceResponse, CreateResourceError> {
+        (**self).create_resource(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> RusotoFuture<DeleteAliasResponse, DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> RusotoFuture<DeleteMailboxPermissionsResponse, DeleteMailboxPermissionsError> {
+        (**self).delete_mailbox_permissions(input)
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<DeleteResourceResponse, DeleteResourceError> {
+        (**self).delete_resource(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> RusotoFuture<DeregisterFromWorkMailResponse, DeregisterFromWorkMailError> {
+        (**self).deregister_from_work_mail(input)
+    }
+    fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> RusotoFuture<DescribeGroupResponse, DescribeGroupError> {
+        (**self).describe_group(input)
+    }
+    fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        (**self).describe_organization(input)
+    }
+    fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> RusotoFuture<DescribeResourceResponse, DescribeResourceError> {
+        (**self).describe_resource(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> RusotoFuture<DisassociateDelegateFromResourceResponse, DisassociateDelegateFromResourceError>
+    {
+        (**self).disassociate_delegate_from_resource(input)
+    }
+    fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> RusotoFuture<DisassociateMemberFromGroupResponse, DisassociateMemberFromGroupError> {
+        (**self).disassociate_member_from_group(input)
+    }
+    fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> RusotoFuture<GetMailboxDetailsResponse, GetMailboxDetailsError> {
+        (**self).get_mailbox_details(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> RusotoFuture<ListGroupMembersResponse, ListGroupMembersError> {
+        (**self).list_group_members(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> RusotoFuture<ListMailboxPermissionsResponse, ListMailboxPermissionsError> {
+        (**self).list_mailbox_permissions(input)
+    }
+    fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> RusotoFuture<ListOrganizationsResponse, ListOrganizationsError> {
+        (**self).list_organizations(input)
+    }
+    fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> RusotoFuture<ListResourceDelegatesResponse, ListResourceDelegatesError> {
+        (**self).list_resource_delegates(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> RusotoFuture<PutMailboxPermissionsResponse, PutMailboxPermissionsError> {
+        (**self).put_mailbox_permissions(input)
+    }
+    fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> RusotoFuture<RegisterToWorkMailResponse, RegisterToWorkMailError> {
+        (**self).register_to_work_mail(input)
+    }
+    fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> RusotoFuture<ResetPasswordResponse, ResetPasswordError> {
+        (**self).reset_password(input)
+    }
+    fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> RusotoFuture<UpdateMailboxQuotaResponse, UpdateMailboxQuotaError> {
+        (**self).update_mailbox_quota(input)
+    }
+    fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> RusotoFuture<UpdatePrimaryEmailAddressResponse, UpdatePrimaryEmailAddressError> {
+        (**self).update_primary_email_address(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+}
+/// A client for the Amazon WorkMail API.
+#[derive(Clone)]
+pub struct WorkmailClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl WorkmailClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorkmailClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkmailClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkmailClient {
+        WorkmailClient { client, region }
+    }
+}
+
+impl Workmail for WorkmailClient {
+    /// <p>Adds a member (user or group) to the resource's set of delegates.</p>
+    fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> RusotoFuture<AssociateDelegateToResourceResponse, AssociateDelegateToResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("AssociateDelegateToResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "WorkMailService.AssociateDelegateToResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateDelegateToResourceResponse, _>(
+                    response,
+                    "AssociateDelegateToResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(AssociateDelegateToResourceError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Adds a member (user or group) to the group's set.</p>
+    fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> RusotoFuture<AssociateMemberToGroupResponse, AssociateMemberToGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("AssociateMemberToGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.AssociateMemberToGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateMemberToGroupResponse, _>(
+                    response,
+                    "AssociateMemberToGroup",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(AssociateMemberToGroupError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds an alias to the set of a given member (user or group) of Amazon WorkMail.</p>
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResponse, CreateAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.CreateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateAliasResponse, _>(
+                    response,
+                    "CreateAlias",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a group that can be used in Amazon WorkMail by calling the <a>RegisterToWorkMail</a> operation.</p>
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.CreateGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateGroupResponse, _>(
+                    response,
+                    "CreateGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a new Amazon WorkMail resource. </p>
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<CreateResourceResponse, CreateResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.CreateResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateResourceResponse, _>(
+                    response,
+                    "CreateResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a user who can be used in Amazon WorkMail by calling the <a>RegisterToWorkMail</a> operation.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("CreateUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.CreateUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateUserResponse, _>(response, "CreateUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Remove one or more specified aliases from a set of aliases for a given user.</p>
+    fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> RusotoFuture<DeleteAliasResponse, DeleteAliasError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DeleteAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteAliasResponse, _>(
+                    response,
+                    "DeleteAlias",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteAliasError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a group from Amazon WorkMail.</p>
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DeleteGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteGroupResponse, _>(
+                    response,
+                    "DeleteGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes permissions granted to a member (user or group).</p>
+    fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> RusotoFuture<DeleteMailboxPermissionsResponse, DeleteMailboxPermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteMailboxPermissions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DeleteMailboxPermissions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteMailboxPermissionsResponse, _>(
+                    response,
+                    "DeleteMailboxPermissions",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DeleteMailboxPermissionsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Deletes the specified resource. </p>
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<DeleteResourceResponse, DeleteResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DeleteResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteResourceResponse, _>(
+                    response,
+                    "DeleteResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a user from Amazon WorkMail and all subsequent systems. Before you can delete a user, the user state must be <code>DISABLED</code>. Use the <a>DescribeUser</a> action to confirm the user state.</p> <p>Deleting a user is permanent and cannot be undone. WorkMail archives user mailboxes for 30 days before they are permanently removed.</p>
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeleteUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DeleteUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteUserResponse, _>(response, "DeleteUser")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Mark a user, group, or resource as no longer used in Amazon WorkMail. This action disassociates the mailbox and schedules it for clean-up. WorkMail keeps mailboxes for 30 days before they are permanently removed. The functionality in the console is <i>Disable</i>.</p>
+    fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> RusotoFuture<DeregisterFromWorkMailResponse, DeregisterFromWorkMailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DeregisterFromWorkMail");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DeregisterFromWorkMail");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeregisterFromWorkMailResponse, _>(
+                    response,
+                    "DeregisterFromWorkMail",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeregisterFromWorkMailError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the data available for the group.</p>
+    fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> RusotoFuture<DescribeGroupResponse, DescribeGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DescribeGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeGroupResponse, _>(
+                    response,
+                    "DescribeGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides more information regarding a given organization based on its identifier.</p>
+    fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeOrganization");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DescribeOrganization");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeOrganizationResponse, _>(
+                    response,
+                    "DescribeOrganization",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DescribeOrganizationError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns the data available for the resource.</p>
+    fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> RusotoFuture<DescribeResourceResponse, DescribeResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DescribeResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeResourceResponse, _>(
+                    response,
+                    "DescribeResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Provides information regarding the user.</p>
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DescribeUser");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.DescribeUser");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeUserResponse, _>(
+                    response,
+                    "DescribeUser",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeUserError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Removes a member from the resource's set of delegates.</p>
+    fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> RusotoFuture<DisassociateDelegateFromResourceResponse, DisassociateDelegateFromResourceError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DisassociateDelegateFromResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "WorkMailService.DisassociateDelegateFromResource",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateDelegateFromResourceResponse, _>(
+                    response,
+                    "DisassociateDelegateFromResource",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateDelegateFromResourceError::from_response(
+                        response,
+                    ))
+                }))
+            }
+        })
+    }
+
+    /// <p>Removes a member from a group.</p>
+    fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> RusotoFuture<DisassociateMemberFromGroupResponse, DisassociateMemberFromGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("DisassociateMemberFromGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "WorkMailService.DisassociateMemberFromGroup",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateMemberFromGroupResponse, _>(
+                    response,
+                    "DisassociateMemberFromGroup",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DisassociateMemberFromGroupError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Requests a user's mailbox details for a specified organization and user.</p>
+    fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> RusotoFuture<GetMailboxDetailsResponse, GetMailboxDetailsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("GetMailboxDetails");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.GetMailboxDetails");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<GetMailboxDetailsResponse, _>(
+                    response,
+                    "GetMailboxDetails",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(GetMailboxDetailsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a paginated call to list the aliases associated with a given entity.</p>
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListAliases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListAliases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAliasesResponse, _>(
+                    response,
+                    "ListAliases",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListAliasesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns an overview of the members of a group. Users and groups can be members of a group.</p>
+    fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> RusotoFuture<ListGroupMembersResponse, ListGroupMembersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListGroupMembers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListGroupMembers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGroupMembersResponse, _>(
+                    response,
+                    "ListGroupMembers",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGroupMembersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns summaries of the organization's groups.</p>
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListGroupsResponse, _>(response, "ListGroups")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the mailbox permissions associated with a user, group, or resource mailbox.</p>
+    fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> RusotoFuture<ListMailboxPermissionsResponse, ListMailboxPermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListMailboxPermissions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListMailboxPermissions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListMailboxPermissionsResponse, _>(
+                    response,
+                    "ListMailboxPermissions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListMailboxPermissionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns summaries of the customer's non-deleted organizations.</p>
+    fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> RusotoFuture<ListOrganizationsResponse, ListOrganizationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListOrganizations");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListOrganizations");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListOrganizationsResponse, _>(
+                    response,
+                    "ListOrganizations",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListOrganizationsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Lists the delegates associated with a resource. Users and groups can be resource delegates and answer requests on behalf of the resource.</p>
+    fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> RusotoFuture<ListResourceDelegatesResponse, ListResourceDelegatesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListResourceDelegates");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListResourceDelegates");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourceDelegatesResponse, _>(
+                    response,
+                    "ListResourceDelegates",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ListResourceDelegatesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns summaries of the organization's resources.</p>
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListResources");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListResources");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListResourcesResponse, _>(
+                    response,
+                    "ListResources",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListResourcesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Returns summaries of the organization's users.</p>
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ListUsers");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ListUsers");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdatePrimaryEmailAddressResponse, _>()
-                }))
+                proto::json::deserialize_streaming::<ListUsersResponse, _>(response, "ListUsers")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ListUsersError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Sets permissions for a user, group, or resource. This replaces any pre-existing permissions.</p>
+    fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> RusotoFuture<PutMailboxPermissionsResponse, PutMailboxPermissionsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("PutMailboxPermissions");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.PutMailboxPermissions");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<PutMailboxPermissionsResponse, _>(
+                    response,
+                    "PutMailboxPermissions",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(PutMailboxPermissionsError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Registers an existing and disabled user, group, or resource for Amazon WorkMail use by associating a mailbox and calendaring capabilities. It performs no change if the user, group, or resource is enabled and fails if the user, group, or resource is deleted. This operation results in the accumulation of costs. For more information, see <a href="https://aws.amazon.com//workmail/pricing">Pricing</a>. The equivalent console functionality for this operation is <i>Enable</i>. </p> <p>Users can either be created by calling the <a>CreateUser</a> API operation or they can be synchronized from your directory. For more information, see <a>DeregisterFromWorkMail</a>.</p>
+    fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> RusotoFuture<RegisterToWorkMailResponse, RegisterToWorkMailError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("RegisterToWorkMail");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.RegisterToWorkMail");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RegisterToWorkMailResponse, _>(
+                    response,
+                    "RegisterToWorkMail",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(RegisterToWorkMailError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Allows the administrator to reset the password for a user.</p>
+    fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> RusotoFuture<ResetPasswordResponse, ResetPasswordError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("ResetPassword");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.ResetPassword");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ResetPasswordResponse, _>(
+                    response,
+                    "ResetPassword",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(ResetPasswordError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates a user's current mailbox quota for a specified organization and user.</p>
+    fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> RusotoFuture<UpdateMailboxQuotaResponse, UpdateMailboxQuotaError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("UpdateMailboxQuota");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.UpdateMailboxQuota");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateMailboxQuotaResponse, _>(
+                    response,
+                    "UpdateMailboxQuota",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateMailboxQuotaError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Updates the primary email for a user, group, or resource. The current email is moved into the list of aliases (or swapped between an existing alias and the current primary email), and the email provided in the input is promoted as the primary.</p>
+    fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> RusotoFuture<UpdatePrimaryEmailAddressResponse, UpdatePrimaryEmailAddressError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("UpdatePrimaryEmailAddress");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.UpdatePrimaryEmailAddress");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdatePrimaryEmailAddressResponse, _>(
+                    response,
+                    "UpdatePrimaryEmailAddress",
+                )
             } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     Err(UpdatePrimaryEmailAddressError::from_response(response))
@@ -4355,32 +6520,1189 @@ impl Workmail for WorkmailClient {
         })
     }
 
-    /// <p>Updates data for the resource. To have the latest information, it must be preceded by a <a>DescribeResource</a> call. The dataset in the request should be the one expected when performing another <code>DescribeResource</code> call.</p>
+    /// <p>Updates data for the resource. To have the latest information, it must be preceded by a <a>DescribeResource</a> call. The dataset in the request should be the one expected when performing another <code>DescribeResource</code> call.</p>
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
+        request.set_operation("UpdateResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkMailService.UpdateResource");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<UpdateResourceResponse, _>(
+                    response,
+                    "UpdateResource",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(UpdateResourceError::from_response(response))),
+                )
+            }
+        })
+    }
+}
+
+/// A mock of the Amazon WorkMail API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockWorkmail {
+    associate_delegate_to_resource: Option<
+        Box<
+            dyn Fn(
+                    AssociateDelegateToResourceRequest,
+                ) -> ::std::result::Result<
+                    AssociateDelegateToResourceResponse,
+                    RusotoError<AssociateDelegateToResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    associate_member_to_group: Option<
+        Box<
+            dyn Fn(
+                    AssociateMemberToGroupRequest,
+                ) -> ::std::result::Result<
+                    AssociateMemberToGroupResponse,
+                    RusotoError<AssociateMemberToGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_alias: Option<
+        Box<
+            dyn Fn(
+                    CreateAliasRequest,
+                )
+                    -> ::std::result::Result<CreateAliasResponse, RusotoError<CreateAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_group: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupRequest,
+                )
+                    -> ::std::result::Result<CreateGroupResponse, RusotoError<CreateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_resource: Option<
+        Box<
+            dyn Fn(
+                    CreateResourceRequest,
+                ) -> ::std::result::Result<
+                    CreateResourceResponse,
+                    RusotoError<CreateResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_user: Option<
+        Box<
+            dyn Fn(
+                    CreateUserRequest,
+                )
+                    -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_alias: Option<
+        Box<
+            dyn Fn(
+                    DeleteAliasRequest,
+                )
+                    -> ::std::result::Result<DeleteAliasResponse, RusotoError<DeleteAliasError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteGroupRequest,
+                )
+                    -> ::std::result::Result<DeleteGroupResponse, RusotoError<DeleteGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_mailbox_permissions: Option<
+        Box<
+            dyn Fn(
+                    DeleteMailboxPermissionsRequest,
+                ) -> ::std::result::Result<
+                    DeleteMailboxPermissionsResponse,
+                    RusotoError<DeleteMailboxPermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_resource: Option<
+        Box<
+            dyn Fn(
+                    DeleteResourceRequest,
+                ) -> ::std::result::Result<
+                    DeleteResourceResponse,
+                    RusotoError<DeleteResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_user: Option<
+        Box<
+            dyn Fn(
+                    DeleteUserRequest,
+                )
+                    -> ::std::result::Result<DeleteUserResponse, RusotoError<DeleteUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    deregister_from_work_mail: Option<
+        Box<
+            dyn Fn(
+                    DeregisterFromWorkMailRequest,
+                ) -> ::std::result::Result<
+                    DeregisterFromWorkMailResponse,
+                    RusotoError<DeregisterFromWorkMailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_group: Option<
+        Box<
+            dyn Fn(
+                    DescribeGroupRequest,
+                )
+                    -> ::std::result::Result<DescribeGroupResponse, RusotoError<DescribeGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_organization: Option<
+        Box<
+            dyn Fn(
+                    DescribeOrganizationRequest,
+                ) -> ::std::result::Result<
+                    DescribeOrganizationResponse,
+                    RusotoError<DescribeOrganizationError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_resource: Option<
+        Box<
+            dyn Fn(
+                    DescribeResourceRequest,
+                ) -> ::std::result::Result<
+                    DescribeResourceResponse,
+                    RusotoError<DescribeResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_user: Option<
+        Box<
+            dyn Fn(
+                    DescribeUserRequest,
+                )
+                    -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    disassociate_delegate_from_resource: Option<
+        Box<
+            dyn Fn(
+                    DisassociateDelegateFromResourceRequest,
+                ) -> ::std::result::Result<
+                    DisassociateDelegateFromResourceResponse,
+                    RusotoError<DisassociateDelegateFromResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_member_from_group: Option<
+        Box<
+            dyn Fn(
+                    DisassociateMemberFromGroupRequest,
+                ) -> ::std::result::Result<
+                    DisassociateMemberFromGroupResponse,
+                    RusotoError<DisassociateMemberFromGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_mailbox_details: Option<
+        Box<
+            dyn Fn(
+                    GetMailboxDetailsRequest,
+                ) -> ::std::result::Result<
+                    GetMailboxDetailsResponse,
+                    RusotoError<GetMailboxDetailsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_aliases: Option<
+        Box<
+            dyn Fn(
+                    ListAliasesRequest,
+                )
+                    -> ::std::result::Result<ListAliasesResponse, RusotoError<ListAliasesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_group_members: Option<
+        Box<
+            dyn Fn(
+                    ListGroupMembersRequest,
+                ) -> ::std::result::Result<
+                    ListGroupMembersResponse,
+                    RusotoError<ListGroupMembersError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_groups: Option<
+        Box<
+            dyn Fn(
+                    ListGroupsRequest,
+                )
+                    -> ::std::result::Result<ListGroupsResponse, RusotoError<ListGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_mailbox_permissions: Option<
+        Box<
+            dyn Fn(
+                    ListMailboxPermissionsRequest,
+                ) -> ::std::result::Result<
+                    ListMailboxPermissionsResponse,
+                    RusotoError<ListMailboxPermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_organizations: Option<
+        Box<
+            dyn Fn(
+                    ListOrganizationsRequest,
+                ) -> ::std::result::Result<
+                    ListOrganizationsResponse,
+                    RusotoError<ListOrganizationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resource_delegates: Option<
+        Box<
+            dyn Fn(
+                    ListResourceDelegatesRequest,
+                ) -> ::std::result::Result<
+                    ListResourceDelegatesResponse,
+                    RusotoError<ListResourceDelegatesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_resources: Option<
+        Box<
+            dyn Fn(
+                    ListResourcesRequest,
+                )
+                    -> ::std::result::Result<ListResourcesResponse, RusotoError<ListResourcesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    list_users: Option<
+        Box<
+            dyn Fn(
+                    ListUsersRequest,
+                )
+                    -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    put_mailbox_permissions: Option<
+        Box<
+            dyn Fn(
+                    PutMailboxPermissionsRequest,
+                ) -> ::std::result::Result<
+                    PutMailboxPermissionsResponse,
+                    RusotoError<PutMailboxPermissionsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    register_to_work_mail: Option<
+        Box<
+            dyn Fn(
+                    RegisterToWorkMailRequest,
+                ) -> ::std::result::Result<
+                    RegisterToWorkMailResponse,
+                    RusotoError<RegisterToWorkMailError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reset_password: Option<
+        Box<
+            dyn Fn(
+                    ResetPasswordRequest,
+                )
+                    -> ::std::result::Result<ResetPasswordResponse, RusotoError<ResetPasswordError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_mailbox_quota: Option<
+        Box<
+            dyn Fn(
+                    UpdateMailboxQuotaRequest,
+                ) -> ::std::result::Result<
+                    UpdateMailboxQuotaResponse,
+                    RusotoError<UpdateMailboxQuotaError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_primary_email_address: Option<
+        Box<
+            dyn Fn(
+                    UpdatePrimaryEmailAddressRequest,
+                ) -> ::std::result::Result<
+                    UpdatePrimaryEmailAddressResponse,
+                    RusotoError<UpdatePrimaryEmailAddressError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_resource: Option<
+        Box<
+            dyn Fn(
+                    UpdateResourceRequest,
+                ) -> ::std::result::Result<
+                    UpdateResourceResponse,
+                    RusotoError<UpdateResourceError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockWorkmail {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockWorkmail {
+        Default::default()
+    }
+
+    /// Answers `associate_delegate_to_resource` with what `handler` returns for its input.
+    pub fn with_associate_delegate_to_resource<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                AssociateDelegateToResourceRequest,
+            ) -> ::std::result::Result<
+                AssociateDelegateToResourceResponse,
+                RusotoError<AssociateDelegateToResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_delegate_to_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `associate_member_to_group` with what `handler` returns for its input.
+    pub fn with_associate_member_to_group<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                AssociateMemberToGroupRequest,
+            ) -> ::std::result::Result<
+                AssociateMemberToGroupResponse,
+                RusotoError<AssociateMemberToGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_member_to_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_alias` with what `handler` returns for its input.
+    pub fn with_create_alias<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                CreateAliasRequest,
+            )
+                -> ::std::result::Result<CreateAliasResponse, RusotoError<CreateAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_group` with what `handler` returns for its input.
+    pub fn with_create_group<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                CreateGroupRequest,
+            )
+                -> ::std::result::Result<CreateGroupResponse, RusotoError<CreateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_resource` with what `handler` returns for its input.
+    pub fn with_create_resource<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                CreateResourceRequest,
+            )
+                -> ::std::result::Result<CreateResourceResponse, RusotoError<CreateResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_user` with what `handler` returns for its input.
+    pub fn with_create_user<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                CreateUserRequest,
+            )
+                -> ::std::result::Result<CreateUserResponse, RusotoError<CreateUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_alias` with what `handler` returns for its input.
+    pub fn with_delete_alias<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DeleteAliasRequest,
+            )
+                -> ::std::result::Result<DeleteAliasResponse, RusotoError<DeleteAliasError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_alias = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_group` with what `handler` returns for its input.
+    pub fn with_delete_group<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DeleteGroupRequest,
+            )
+                -> ::std::result::Result<DeleteGroupResponse, RusotoError<DeleteGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_mailbox_permissions` with what `handler` returns for its input.
+    pub fn with_delete_mailbox_permissions<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DeleteMailboxPermissionsRequest,
+            ) -> ::std::result::Result<
+                DeleteMailboxPermissionsResponse,
+                RusotoError<DeleteMailboxPermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_mailbox_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_resource` with what `handler` returns for its input.
+    pub fn with_delete_resource<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DeleteResourceRequest,
+            )
+                -> ::std::result::Result<DeleteResourceResponse, RusotoError<DeleteResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_user` with what `handler` returns for its input.
+    pub fn with_delete_user<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DeleteUserRequest,
+            )
+                -> ::std::result::Result<DeleteUserResponse, RusotoError<DeleteUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `deregister_from_work_mail` with what `handler` returns for its input.
+    pub fn with_deregister_from_work_mail<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DeregisterFromWorkMailRequest,
+            ) -> ::std::result::Result<
+                DeregisterFromWorkMailResponse,
+                RusotoError<DeregisterFromWorkMailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.deregister_from_work_mail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_group` with what `handler` returns for its input.
+    pub fn with_describe_group<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DescribeGroupRequest,
+            )
+                -> ::std::result::Result<DescribeGroupResponse, RusotoError<DescribeGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_organization` with what `handler` returns for its input.
+    pub fn with_describe_organization<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DescribeOrganizationRequest,
+            ) -> ::std::result::Result<
+                DescribeOrganizationResponse,
+                RusotoError<DescribeOrganizationError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_organization = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_resource` with what `handler` returns for its input.
+    pub fn with_describe_resource<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DescribeResourceRequest,
+            ) -> ::std::result::Result<
+                DescribeResourceResponse,
+                RusotoError<DescribeResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_user` with what `handler` returns for its input.
+    pub fn with_describe_user<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DescribeUserRequest,
+            )
+                -> ::std::result::Result<DescribeUserResponse, RusotoError<DescribeUserError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_user = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_delegate_from_resource` with what `handler` returns for its input.
+    pub fn with_disassociate_delegate_from_resource<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DisassociateDelegateFromResourceRequest,
+            ) -> ::std::result::Result<
+                DisassociateDelegateFromResourceResponse,
+                RusotoError<DisassociateDelegateFromResourceError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_delegate_from_resource = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_member_from_group` with what `handler` returns for its input.
+    pub fn with_disassociate_member_from_group<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                DisassociateMemberFromGroupRequest,
+            ) -> ::std::result::Result<
+                DisassociateMemberFromGroupResponse,
+                RusotoError<DisassociateMemberFromGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_member_from_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_mailbox_details` with what `handler` returns for its input.
+    pub fn with_get_mailbox_details<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                GetMailboxDetailsRequest,
+            ) -> ::std::result::Result<
+                GetMailboxDetailsResponse,
+                RusotoError<GetMailboxDetailsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_mailbox_details = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_aliases` with what `handler` returns for its input.
+    pub fn with_list_aliases<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListAliasesRequest,
+            )
+                -> ::std::result::Result<ListAliasesResponse, RusotoError<ListAliasesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_aliases = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_group_members` with what `handler` returns for its input.
+    pub fn with_list_group_members<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListGroupMembersRequest,
+            ) -> ::std::result::Result<
+                ListGroupMembersResponse,
+                RusotoError<ListGroupMembersError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_group_members = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_groups` with what `handler` returns for its input.
+    pub fn with_list_groups<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListGroupsRequest,
+            )
+                -> ::std::result::Result<ListGroupsResponse, RusotoError<ListGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_mailbox_permissions` with what `handler` returns for its input.
+    pub fn with_list_mailbox_permissions<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListMailboxPermissionsRequest,
+            ) -> ::std::result::Result<
+                ListMailboxPermissionsResponse,
+                RusotoError<ListMailboxPermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_mailbox_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_organizations` with what `handler` returns for its input.
+    pub fn with_list_organizations<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListOrganizationsRequest,
+            ) -> ::std::result::Result<
+                ListOrganizationsResponse,
+                RusotoError<ListOrganizationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_organizations = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resource_delegates` with what `handler` returns for its input.
+    pub fn with_list_resource_delegates<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListResourceDelegatesRequest,
+            ) -> ::std::result::Result<
+                ListResourceDelegatesResponse,
+                RusotoError<ListResourceDelegatesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resource_delegates = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_resources` with what `handler` returns for its input.
+    pub fn with_list_resources<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListResourcesRequest,
+            )
+                -> ::std::result::Result<ListResourcesResponse, RusotoError<ListResourcesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_resources = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_users` with what `handler` returns for its input.
+    pub fn with_list_users<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ListUsersRequest,
+            ) -> ::std::result::Result<ListUsersResponse, RusotoError<ListUsersError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.list_users = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_mailbox_permissions` with what `handler` returns for its input.
+    pub fn with_put_mailbox_permissions<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                PutMailboxPermissionsRequest,
+            ) -> ::std::result::Result<
+                PutMailboxPermissionsResponse,
+                RusotoError<PutMailboxPermissionsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_mailbox_permissions = Some(Box::new(handler));
+        self
+    }
+    /// Answers `register_to_work_mail` with what `handler` returns for its input.
+    pub fn with_register_to_work_mail<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                RegisterToWorkMailRequest,
+            ) -> ::std::result::Result<
+                RegisterToWorkMailResponse,
+                RusotoError<RegisterToWorkMailError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.register_to_work_mail = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reset_password` with what `handler` returns for its input.
+    pub fn with_reset_password<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                ResetPasswordRequest,
+            )
+                -> ::std::result::Result<ResetPasswordResponse, RusotoError<ResetPasswordError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reset_password = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_mailbox_quota` with what `handler` returns for its input.
+    pub fn with_update_mailbox_quota<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                UpdateMailboxQuotaRequest,
+            ) -> ::std::result::Result<
+                UpdateMailboxQuotaResponse,
+                RusotoError<UpdateMailboxQuotaError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_mailbox_quota = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_primary_email_address` with what `handler` returns for its input.
+    pub fn with_update_primary_email_address<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                UpdatePrimaryEmailAddressRequest,
+            ) -> ::std::result::Result<
+                UpdatePrimaryEmailAddressResponse,
+                RusotoError<UpdatePrimaryEmailAddressError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_primary_email_address = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_resource` with what `handler` returns for its input.
+    pub fn with_update_resource<F>(mut self, handler: F) -> MockWorkmail
+    where
+        F: Fn(
+                UpdateResourceRequest,
+            )
+                -> ::std::result::Result<UpdateResourceResponse, RusotoError<UpdateResourceError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_resource = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Workmail for MockWorkmail {
+    fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> RusotoFuture<AssociateDelegateToResourceResponse, AssociateDelegateToResourceError> {
+        match self.associate_delegate_to_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for associate_delegate_to_resource"),
+        }
+    }
+    fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> RusotoFuture<AssociateMemberToGroupResponse, AssociateMemberToGroupError> {
+        match self.associate_member_to_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for associate_member_to_group"),
+        }
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResponse, CreateAliasError> {
+        match self.create_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for create_alias"),
+        }
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        match self.create_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for create_group"),
+        }
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<CreateResourceResponse, CreateResourceError> {
+        match self.create_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for create_resource"),
+        }
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        match self.create_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for create_user"),
+        }
+    }
+    fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> RusotoFuture<DeleteAliasResponse, DeleteAliasError> {
+        match self.delete_alias {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for delete_alias"),
+        }
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        match self.delete_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for delete_group"),
+        }
+    }
+    fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> RusotoFuture<DeleteMailboxPermissionsResponse, DeleteMailboxPermissionsError> {
+        match self.delete_mailbox_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for delete_mailbox_permissions"),
+        }
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<DeleteResourceResponse, DeleteResourceError> {
+        match self.delete_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for delete_resource"),
+        }
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        match self.delete_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for delete_user"),
+        }
+    }
+    fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> RusotoFuture<DeregisterFromWorkMailResponse, DeregisterFromWorkMailError> {
+        match self.deregister_from_work_mail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for deregister_from_work_mail"),
+        }
+    }
+    fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> RusotoFuture<DescribeGroupResponse, DescribeGroupError> {
+        match self.describe_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for describe_group"),
+        }
+    }
+    fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        match self.describe_organization {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for describe_organization"),
+        }
+    }
+    fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> RusotoFuture<DescribeResourceResponse, DescribeResourceError> {
+        match self.describe_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for describe_resource"),
+        }
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        match self.describe_user {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for describe_user"),
+        }
+    }
+    fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> RusotoFuture<DisassociateDelegateFromResourceResponse, DisassociateDelegateFromResourceError>
+    {
+        match self.disassociate_delegate_from_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for disassociate_delegate_from_resource"),
+        }
+    }
+    fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> RusotoFuture<DisassociateMemberFromGroupResponse, DisassociateMemberFromGroupError> {
+        match self.disassociate_member_from_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for disassociate_member_from_group"),
+        }
+    }
+    fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> RusotoFuture<GetMailboxDetailsResponse, GetMailboxDetailsError> {
+        match self.get_mailbox_details {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for get_mailbox_details"),
+        }
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        match self.list_aliases {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_aliases"),
+        }
+    }
+    fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> RusotoFuture<ListGroupMembersResponse, ListGroupMembersError> {
+        match self.list_group_members {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_group_members"),
+        }
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        match self.list_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_groups"),
+        }
+    }
+    fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> RusotoFuture<ListMailboxPermissionsResponse, ListMailboxPermissionsError> {
+        match self.list_mailbox_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_mailbox_permissions"),
+        }
+    }
+    fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> RusotoFuture<ListOrganizationsResponse, ListOrganizationsError> {
+        match self.list_organizations {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_organizations"),
+        }
+    }
+    fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> RusotoFuture<ListResourceDelegatesResponse, ListResourceDelegatesError> {
+        match self.list_resource_delegates {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_resource_delegates"),
+        }
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        match self.list_resources {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_resources"),
+        }
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        match self.list_users {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for list_users"),
+        }
+    }
+    fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> RusotoFuture<PutMailboxPermissionsResponse, PutMailboxPermissionsError> {
+        match self.put_mailbox_permissions {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for put_mailbox_permissions"),
+        }
+    }
+    fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> RusotoFuture<RegisterToWorkMailResponse, RegisterToWorkMailError> {
+        match self.register_to_work_mail {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for register_to_work_mail"),
+        }
+    }
+    fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> RusotoFuture<ResetPasswordResponse, ResetPasswordError> {
+        match self.reset_password {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for reset_password"),
+        }
+    }
+    fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> RusotoFuture<UpdateMailboxQuotaResponse, UpdateMailboxQuotaError> {
+        match self.update_mailbox_quota {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for update_mailbox_quota"),
+        }
+    }
+    fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> RusotoFuture<UpdatePrimaryEmailAddressResponse, UpdatePrimaryEmailAddressError> {
+        match self.update_primary_email_address {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for update_primary_email_address"),
+        }
+    }
     fn update_resource(
         &self,
         input: UpdateResourceRequest,
     ) -> RusotoFuture<UpdateResourceResponse, UpdateResourceError> {
-        let mut request = SignedRequest::new("POST", "workmail", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkMailService.UpdateResource");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateResourceResponse, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateResourceError::from_response(response))),
-                )
-            }
-        })
+        match self.update_resource {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkmail has no handler for update_resource"),
+        }
     }
 }
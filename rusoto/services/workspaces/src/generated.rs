@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>Describes a modification to the configuration of bring your own license (BYOL) for the specified account. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AccountModification {
     /// <p>The IP address range, specified as an IPv4 CIDR block, for the management network interface used for the account.</p>
     #[serde(rename = "DedicatedTenancyManagementCidrRange")]
@@ -54,6 +55,7 @@ pub struct AccountModification {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AssociateIpGroupsRequest {
     /// <p>The identifier of the directory.</p>
     #[serde(rename = "DirectoryId")]
@@ -63,11 +65,32 @@ pub struct AssociateIpGroupsRequest {
     pub group_ids: Vec<String>,
 }
 
+impl Validate for AssociateIpGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "directory_id"),
+            self.directory_id.chars().count(),
+            Some(10),
+            Some(65),
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{8,63}$",
+        );
+        for (i, item) in self.group_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "group_ids"), i);
+            errors.check_pattern(&item_path, &item, "wsipg-[0-9a-z]{8,63}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AssociateIpGroupsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct AuthorizeIpRulesRequest {
     /// <p>The identifier of the group.</p>
     #[serde(rename = "GroupId")]
@@ -77,8 +100,18 @@ pub struct AuthorizeIpRulesRequest {
     pub user_rules: Vec<IpRuleItem>,
 }
 
+impl Validate for AuthorizeIpRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "wsipg-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AuthorizeIpRulesResult {}
 
 /// <p>Describes an Amazon WorkSpaces client.</p>
@@ -92,7 +125,7 @@ pub struct ClientProperties {
 
 /// <p>Information about the Amazon WorkSpaces client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ClientPropertiesResult {
     /// <p>Information about the Amazon WorkSpaces client.</p>
     #[serde(rename = "ClientProperties")]
@@ -106,7 +139,7 @@ pub struct ClientPropertiesResult {
 
 /// <p>Describes the compute type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ComputeType {
     /// <p>The compute type.</p>
     #[serde(rename = "Name")]
@@ -115,6 +148,7 @@ pub struct ComputeType {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CopyWorkspaceImageRequest {
     /// <p>A description of the image.</p>
     #[serde(rename = "Description")]
@@ -135,8 +169,59 @@ pub struct CopyWorkspaceImageRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for CopyWorkspaceImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.description {
+            errors.check_length(
+                &field_path(path, "description"),
+                value.chars().count(),
+                Some(1),
+                Some(256),
+            );
+            errors.check_pattern(
+                &field_path(path, "description"),
+                &value,
+                "^[a-zA-Z0-9_./() -]+$",
+            );
+        }
+        errors.check_length(
+            &field_path(path, "name"),
+            self.name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "name"),
+            &self.name,
+            "^[a-zA-Z0-9_./()\\\\-]+$",
+        );
+        errors.check_pattern(
+            &field_path(path, "source_image_id"),
+            &self.source_image_id,
+            "wsi-[0-9a-z]{9,63}$",
+        );
+        errors.check_length(
+            &field_path(path, "source_region"),
+            self.source_region.chars().count(),
+            Some(1),
+            Some(31),
+        );
+        errors.check_pattern(
+            &field_path(path, "source_region"),
+            &self.source_region,
+            "^[-0-9a-z]{1,31}$",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CopyWorkspaceImageResult {
     /// <p>The identifier of the image.</p>
     #[serde(rename = "ImageId")]
@@ -145,6 +230,7 @@ pub struct CopyWorkspaceImageResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateIpGroupRequest {
     /// <p>The description of the group.</p>
     #[serde(rename = "GroupDesc")]
@@ -163,8 +249,19 @@ pub struct CreateIpGroupRequest {
     pub user_rules: Option<Vec<IpRuleItem>>,
 }
 
+impl Validate for CreateIpGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateIpGroupResult {
     /// <p>The identifier of the group.</p>
     #[serde(rename = "GroupId")]
@@ -173,6 +270,7 @@ pub struct CreateIpGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateTagsRequest {
     /// <p>The identifier of the WorkSpaces resource. The supported resource types are WorkSpaces, registered directories, images, custom bundles, and IP access control groups.</p>
     #[serde(rename = "ResourceId")]
@@ -182,19 +280,50 @@ pub struct CreateTagsRequest {
     pub tags: Vec<Tag>,
 }
 
+impl Validate for CreateTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.tags.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateTagsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateWorkspacesRequest {
     /// <p>The WorkSpaces to create. You can specify up to 25 WorkSpaces.</p>
     #[serde(rename = "Workspaces")]
     pub workspaces: Vec<WorkspaceRequest>,
 }
 
+impl Validate for CreateWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "workspaces"),
+            self.workspaces.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.workspaces.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "workspaces"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateWorkspacesResult {
     /// <p>Information about the WorkSpaces that could not be created.</p>
     #[serde(rename = "FailedRequests")]
@@ -208,7 +337,7 @@ pub struct CreateWorkspacesResult {
 
 /// <p>Describes the default values used to create a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DefaultWorkspaceCreationProperties {
     /// <p>The identifier of any security groups to apply to WorkSpaces when they are created.</p>
     #[serde(rename = "CustomSecurityGroupId")]
@@ -233,17 +362,29 @@ pub struct DefaultWorkspaceCreationProperties {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteIpGroupRequest {
     /// <p>The identifier of the IP access control group.</p>
     #[serde(rename = "GroupId")]
     pub group_id: String,
 }
 
+impl Validate for DeleteIpGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "wsipg-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteIpGroupResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteTagsRequest {
     /// <p>The identifier of the WorkSpaces resource. The supported resource types are WorkSpaces, registered directories, images, custom bundles, and IP access control groups.</p>
     #[serde(rename = "ResourceId")]
@@ -253,22 +394,49 @@ pub struct DeleteTagsRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl Validate for DeleteTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            None,
+        );
+        for (i, item) in self.tag_keys.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "tag_keys"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteTagsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteWorkspaceImageRequest {
     /// <p>The identifier of the image.</p>
     #[serde(rename = "ImageId")]
     pub image_id: String,
 }
 
+impl Validate for DeleteWorkspaceImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "image_id"),
+            &self.image_id,
+            "wsi-[0-9a-z]{9,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteWorkspaceImageResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountModificationsRequest {
     /// <p>If you received a <code>NextToken</code> from a previous call that was paginated, provide this token to receive the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -276,8 +444,21 @@ pub struct DescribeAccountModificationsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeAccountModificationsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAccountModificationsResult {
     /// <p>The list of modifications to the configuration of BYOL.</p>
     #[serde(rename = "AccountModifications")]
@@ -290,10 +471,11 @@ pub struct DescribeAccountModificationsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeAccountRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeAccountResult {
     /// <p>The IP address range, specified as an IPv4 CIDR block, used for the management network interface.</p> <p>The management network interface is connected to a secure Amazon WorkSpaces management network. It is used for interactive streaming of the WorkSpace desktop to Amazon WorkSpaces clients, and to allow Amazon WorkSpaces to manage the WorkSpace.</p>
     #[serde(rename = "DedicatedTenancyManagementCidrRange")]
@@ -306,14 +488,30 @@ pub struct DescribeAccountResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeClientPropertiesRequest {
     /// <p>The resource identifier, in the form of directory IDs.</p>
     #[serde(rename = "ResourceIds")]
     pub resource_ids: Vec<String>,
 }
 
+impl Validate for DescribeClientPropertiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_ids"),
+            self.resource_ids.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.resource_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "resource_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), None);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeClientPropertiesResult {
     /// <p>Information about the specified Amazon WorkSpaces clients.</p>
     #[serde(rename = "ClientPropertiesList")]
@@ -322,6 +520,7 @@ pub struct DescribeClientPropertiesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeIpGroupsRequest {
     /// <p>The identifiers of one or more IP access control groups.</p>
     #[serde(rename = "GroupIds")]
@@ -337,8 +536,30 @@ pub struct DescribeIpGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeIpGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.group_ids {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "group_ids"), i);
+                errors.check_pattern(&item_path, &item, "wsipg-[0-9a-z]{8,63}$");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeIpGroupsResult {
     /// <p>The token to use to retrieve the next set of results, or null if no more results are available.</p>
     #[serde(rename = "NextToken")]
@@ -351,14 +572,26 @@ pub struct DescribeIpGroupsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeTagsRequest {
     /// <p>The identifier of the WorkSpaces resource. The supported resource types are WorkSpaces, registered directories, images, custom bundles, and IP access control groups.</p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
 }
 
+impl Validate for DescribeTagsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeTagsResult {
     /// <p>The tags.</p>
     #[serde(rename = "TagList")]
@@ -367,6 +600,7 @@ pub struct DescribeTagsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkspaceBundlesRequest {
     /// <p>The identifiers of the bundles. You cannot combine this parameter with any other filter.</p>
     #[serde(rename = "BundleIds")]
@@ -382,8 +616,33 @@ pub struct DescribeWorkspaceBundlesRequest {
     pub owner: Option<String>,
 }
 
+impl Validate for DescribeWorkspaceBundlesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bundle_ids {
+            errors.check_length(
+                &field_path(path, "bundle_ids"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "bundle_ids"), i);
+                errors.check_pattern(&item_path, &item, "^wsb-[0-9a-z]{8,63}$");
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkspaceBundlesResult {
     /// <p>Information about the bundles.</p>
     #[serde(rename = "Bundles")]
@@ -396,6 +655,7 @@ pub struct DescribeWorkspaceBundlesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkspaceDirectoriesRequest {
     /// <p>The identifiers of the directories. If the value is null, all directories are retrieved.</p>
     #[serde(rename = "DirectoryIds")]
@@ -407,8 +667,34 @@ pub struct DescribeWorkspaceDirectoriesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeWorkspaceDirectoriesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.directory_ids {
+            errors.check_length(
+                &field_path(path, "directory_ids"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "directory_ids"), i);
+                errors.check_length(&item_path, item.chars().count(), Some(10), Some(65));
+                errors.check_pattern(&item_path, &item, "^d-[0-9a-f]{8,63}$");
+            }
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkspaceDirectoriesResult {
     /// <p>Information about the directories.</p>
     #[serde(rename = "Directories")]
@@ -421,6 +707,7 @@ pub struct DescribeWorkspaceDirectoriesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkspaceImagesRequest {
     /// <p>The identifier of the image.</p>
     #[serde(rename = "ImageIds")]
@@ -436,8 +723,36 @@ pub struct DescribeWorkspaceImagesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for DescribeWorkspaceImagesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.image_ids {
+            errors.check_length(
+                &field_path(path, "image_ids"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "image_ids"), i);
+                errors.check_pattern(&item_path, &item, "wsi-[0-9a-z]{9,63}$");
+            }
+        }
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkspaceImagesResult {
     /// <p>Information about the images.</p>
     #[serde(rename = "Images")]
@@ -450,6 +765,7 @@ pub struct DescribeWorkspaceImagesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkspacesConnectionStatusRequest {
     /// <p>If you received a <code>NextToken</code> from a previous call that was paginated, provide this token to receive the next set of results.</p>
     #[serde(rename = "NextToken")]
@@ -461,8 +777,33 @@ pub struct DescribeWorkspacesConnectionStatusRequest {
     pub workspace_ids: Option<Vec<String>>,
 }
 
+impl Validate for DescribeWorkspacesConnectionStatusRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.workspace_ids {
+            errors.check_length(
+                &field_path(path, "workspace_ids"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "workspace_ids"), i);
+                errors.check_pattern(&item_path, &item, "^ws-[0-9a-z]{8,63}$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkspacesConnectionStatusResult {
     /// <p>The token to use to retrieve the next set of results, or null if no more results are available.</p>
     #[serde(rename = "NextToken")]
@@ -475,6 +816,7 @@ pub struct DescribeWorkspacesConnectionStatusResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DescribeWorkspacesRequest {
     /// <p>The identifier of the bundle. All WorkSpaces that are created from this bundle are retrieved. You cannot combine this parameter with any other filter.</p>
     #[serde(rename = "BundleId")]
@@ -502,8 +844,64 @@ pub struct DescribeWorkspacesRequest {
     pub workspace_ids: Option<Vec<String>>,
 }
 
+impl Validate for DescribeWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.bundle_id {
+            errors.check_pattern(
+                &field_path(path, "bundle_id"),
+                &value,
+                "^wsb-[0-9a-z]{8,63}$",
+            );
+        }
+        if let Some(ref value) = self.directory_id {
+            errors.check_length(
+                &field_path(path, "directory_id"),
+                value.chars().count(),
+                Some(10),
+                Some(65),
+            );
+            errors.check_pattern(
+                &field_path(path, "directory_id"),
+                &value,
+                "^d-[0-9a-f]{8,63}$",
+            );
+        }
+        if let Some(ref value) = self.limit {
+            errors.check_range(&field_path(path, "limit"), *value, Some(1), Some(25));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+        if let Some(ref value) = self.user_name {
+            errors.check_length(
+                &field_path(path, "user_name"),
+                value.chars().count(),
+                Some(1),
+                Some(63),
+            );
+        }
+        if let Some(ref value) = self.workspace_ids {
+            errors.check_length(
+                &field_path(path, "workspace_ids"),
+                value.len(),
+                Some(1),
+                Some(25),
+            );
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "workspace_ids"), i);
+                errors.check_pattern(&item_path, &item, "^ws-[0-9a-z]{8,63}$");
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DescribeWorkspacesResult {
     /// <p>The token to use to retrieve the next set of results, or null if no more results are available.</p>
     #[serde(rename = "NextToken")]
@@ -516,6 +914,7 @@ pub struct DescribeWorkspacesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DisassociateIpGroupsRequest {
     /// <p>The identifier of the directory.</p>
     #[serde(rename = "DirectoryId")]
@@ -525,13 +924,33 @@ pub struct DisassociateIpGroupsRequest {
     pub group_ids: Vec<String>,
 }
 
+impl Validate for DisassociateIpGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "directory_id"),
+            self.directory_id.chars().count(),
+            Some(10),
+            Some(65),
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{8,63}$",
+        );
+        for (i, item) in self.group_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "group_ids"), i);
+            errors.check_pattern(&item_path, &item, "wsipg-[0-9a-z]{8,63}$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DisassociateIpGroupsResult {}
 
 /// <p>Describes a WorkSpace that cannot be created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedCreateWorkspaceRequest {
     /// <p>The error code that is returned if the WorkSpace cannot be created.</p>
     #[serde(rename = "ErrorCode")]
@@ -549,7 +968,7 @@ pub struct FailedCreateWorkspaceRequest {
 
 /// <p>Describes a WorkSpace that could not be rebooted. (<a>RebootWorkspaces</a>), rebuilt (<a>RebuildWorkspaces</a>), terminated (<a>TerminateWorkspaces</a>), started (<a>StartWorkspaces</a>), or stopped (<a>StopWorkspaces</a>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FailedWorkspaceChangeRequest {
     /// <p>The error code that is returned if the WorkSpace cannot be rebooted.</p>
     #[serde(rename = "ErrorCode")]
@@ -566,6 +985,7 @@ pub struct FailedWorkspaceChangeRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ImportWorkspaceImageRequest {
     /// <p>The identifier of the EC2 image.</p>
     #[serde(rename = "Ec2ImageId")]
@@ -585,8 +1005,46 @@ pub struct ImportWorkspaceImageRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl Validate for ImportWorkspaceImageRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "ec_2_image_id"),
+            &self.ec_2_image_id,
+            "^ami\\-([a-f0-9]{8}|[a-f0-9]{17})$",
+        );
+        errors.check_length(
+            &field_path(path, "image_description"),
+            self.image_description.chars().count(),
+            Some(1),
+            Some(256),
+        );
+        errors.check_pattern(
+            &field_path(path, "image_description"),
+            &self.image_description,
+            "^[a-zA-Z0-9_./() -]+$",
+        );
+        errors.check_length(
+            &field_path(path, "image_name"),
+            self.image_name.chars().count(),
+            Some(1),
+            Some(64),
+        );
+        errors.check_pattern(
+            &field_path(path, "image_name"),
+            &self.image_name,
+            "^[a-zA-Z0-9_./()\\\\-]+$",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ImportWorkspaceImageResult {
     /// <p>The identifier of the WorkSpace image.</p>
     #[serde(rename = "ImageId")]
@@ -608,6 +1066,7 @@ pub struct IpRuleItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ListAvailableManagementCidrRangesRequest {
     /// <p>The IP address range to search. Specify an IP address range that is compatible with your network and in CIDR notation (that is, specify the range as an IPv4 CIDR block).</p>
     #[serde(rename = "ManagementCidrRangeConstraint")]
@@ -622,8 +1081,25 @@ pub struct ListAvailableManagementCidrRangesRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for ListAvailableManagementCidrRangesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(&field_path(path, "management_cidr_range_constraint"), &self.management_cidr_range_constraint, "^(([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])\\.){3}([0-9]|[1-9][0-9]|1[0-9]{2}|2[0-4][0-9]|25[0-5])(\\/(3[0-2]|[1-2][0-9]|[0-9]))$");
+        if let Some(ref value) = self.max_results {
+            errors.check_range(&field_path(path, "max_results"), *value, Some(1), Some(5));
+        }
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(2048),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ListAvailableManagementCidrRangesResult {
     /// <p>The list of available IP address ranges, specified as IPv4 CIDR blocks.</p>
     #[serde(rename = "ManagementCidrRanges")]
@@ -637,7 +1113,7 @@ pub struct ListAvailableManagementCidrRangesResult {
 
 /// <p>Describes a WorkSpace modification.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModificationState {
     /// <p>The resource.</p>
     #[serde(rename = "Resource")]
@@ -650,6 +1126,7 @@ pub struct ModificationState {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyAccountRequest {
     /// <p>The IP address range, specified as an IPv4 CIDR block, for the management network interface. Specify an IP address range that is compatible with your network and in CIDR notation (that is, specify the range as an IPv4 CIDR block). The CIDR block size must be /16 (for example, 203.0.113.25/16). It must also be specified as available by the <code>ListAvailableManagementCidrRanges</code> operation.</p>
     #[serde(rename = "DedicatedTenancyManagementCidrRange")]
@@ -661,11 +1138,20 @@ pub struct ModifyAccountRequest {
     pub dedicated_tenancy_support: Option<String>,
 }
 
+impl Validate for ModifyAccountRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.dedicated_tenancy_management_cidr_range {
+            errors.check_pattern(&field_path(path, "dedicated_tenancy_management_cidr_range"), &value, "(^([0-9]|[1-9][0-9]|1[0-9][0-9]|2[0-4][0-9]|25[0-5])\\.([0-9]|[1-9][0-9]|1[0-9][0-9]|2[0-4][0-9]|25[0-5])\\.0\\.0)(\\/(16$))$");
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyAccountResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyClientPropertiesRequest {
     /// <p>Information about the Amazon WorkSpaces client.</p>
     #[serde(rename = "ClientProperties")]
@@ -675,11 +1161,23 @@ pub struct ModifyClientPropertiesRequest {
     pub resource_id: String,
 }
 
+impl Validate for ModifyClientPropertiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "resource_id"),
+            self.resource_id.chars().count(),
+            Some(1),
+            None,
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyClientPropertiesResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyWorkspacePropertiesRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
@@ -689,11 +1187,22 @@ pub struct ModifyWorkspacePropertiesRequest {
     pub workspace_properties: WorkspaceProperties,
 }
 
+impl Validate for ModifyWorkspacePropertiesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "workspace_id"),
+            &self.workspace_id,
+            "^ws-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyWorkspacePropertiesResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct ModifyWorkspaceStateRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
@@ -703,13 +1212,23 @@ pub struct ModifyWorkspaceStateRequest {
     pub workspace_state: String,
 }
 
+impl Validate for ModifyWorkspaceStateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "workspace_id"),
+            &self.workspace_id,
+            "^ws-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ModifyWorkspaceStateResult {}
 
 /// <p>The operating system that the image is running.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct OperatingSystem {
     /// <p>The operating system.</p>
     #[serde(rename = "Type")]
@@ -719,21 +1238,48 @@ pub struct OperatingSystem {
 
 /// <p>Describes the information used to reboot a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
     pub workspace_id: String,
 }
 
+impl Validate for RebootRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "workspace_id"),
+            &self.workspace_id,
+            "^ws-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebootWorkspacesRequest {
     /// <p>The WorkSpaces to reboot. You can specify up to 25 WorkSpaces.</p>
     #[serde(rename = "RebootWorkspaceRequests")]
     pub reboot_workspace_requests: Vec<RebootRequest>,
 }
 
+impl Validate for RebootWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "reboot_workspace_requests"),
+            self.reboot_workspace_requests.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.reboot_workspace_requests.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "reboot_workspace_requests"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebootWorkspacesResult {
     /// <p>Information about the WorkSpaces that could not be rebooted.</p>
     #[serde(rename = "FailedRequests")]
@@ -743,21 +1289,48 @@ pub struct RebootWorkspacesResult {
 
 /// <p>Describes the information used to rebuild a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebuildRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
     pub workspace_id: String,
 }
 
+impl Validate for RebuildRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "workspace_id"),
+            &self.workspace_id,
+            "^ws-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RebuildWorkspacesRequest {
     /// <p>The WorkSpace to rebuild. You can specify a single WorkSpace.</p>
     #[serde(rename = "RebuildWorkspaceRequests")]
     pub rebuild_workspace_requests: Vec<RebuildRequest>,
 }
 
+impl Validate for RebuildWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "rebuild_workspace_requests"),
+            self.rebuild_workspace_requests.len(),
+            Some(1),
+            Some(1),
+        );
+        for (i, item) in self.rebuild_workspace_requests.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "rebuild_workspace_requests"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RebuildWorkspacesResult {
     /// <p>Information about the WorkSpace that could not be rebuilt.</p>
     #[serde(rename = "FailedRequests")]
@@ -766,6 +1339,7 @@ pub struct RebuildWorkspacesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct RevokeIpRulesRequest {
     /// <p>The identifier of the group.</p>
     #[serde(rename = "GroupId")]
@@ -775,13 +1349,23 @@ pub struct RevokeIpRulesRequest {
     pub user_rules: Vec<String>,
 }
 
+impl Validate for RevokeIpRulesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "wsipg-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RevokeIpRulesResult {}
 
 /// <p>Describes the root volume for a WorkSpace bundle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RootStorage {
     /// <p>The size of the root volume.</p>
     #[serde(rename = "Capacity")]
@@ -789,8 +1373,22 @@ pub struct RootStorage {
     pub capacity: Option<String>,
 }
 
+impl Validate for RootStorage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.capacity {
+            errors.check_length(
+                &field_path(path, "capacity"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Information used to start a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
@@ -798,15 +1396,43 @@ pub struct StartRequest {
     pub workspace_id: Option<String>,
 }
 
+impl Validate for StartRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.workspace_id {
+            errors.check_pattern(
+                &field_path(path, "workspace_id"),
+                &value,
+                "^ws-[0-9a-z]{8,63}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StartWorkspacesRequest {
     /// <p>The WorkSpaces to start. You can specify up to 25 WorkSpaces.</p>
     #[serde(rename = "StartWorkspaceRequests")]
     pub start_workspace_requests: Vec<StartRequest>,
 }
 
+impl Validate for StartWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "start_workspace_requests"),
+            self.start_workspace_requests.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.start_workspace_requests.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "start_workspace_requests"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StartWorkspacesResult {
     /// <p>Information about the WorkSpaces that could not be started.</p>
     #[serde(rename = "FailedRequests")]
@@ -816,6 +1442,7 @@ pub struct StartWorkspacesResult {
 
 /// <p>Describes the information used to stop a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
@@ -823,15 +1450,43 @@ pub struct StopRequest {
     pub workspace_id: Option<String>,
 }
 
+impl Validate for StopRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.workspace_id {
+            errors.check_pattern(
+                &field_path(path, "workspace_id"),
+                &value,
+                "^ws-[0-9a-z]{8,63}$",
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct StopWorkspacesRequest {
     /// <p>The WorkSpaces to stop. You can specify up to 25 WorkSpaces.</p>
     #[serde(rename = "StopWorkspaceRequests")]
     pub stop_workspace_requests: Vec<StopRequest>,
 }
 
+impl Validate for StopWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "stop_workspace_requests"),
+            self.stop_workspace_requests.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.stop_workspace_requests.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "stop_workspace_requests"), i);
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct StopWorkspacesResult {
     /// <p>Information about the WorkSpaces that could not be stopped.</p>
     #[serde(rename = "FailedRequests")]
@@ -851,23 +1506,73 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+impl Validate for Tag {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "key"),
+            self.key.chars().count(),
+            Some(1),
+            Some(127),
+        );
+        if let Some(ref value) = self.value {
+            errors.check_length(
+                &field_path(path, "value"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+    }
+}
+
 /// <p>Describes the information used to terminate a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateRequest {
     /// <p>The identifier of the WorkSpace.</p>
     #[serde(rename = "WorkspaceId")]
     pub workspace_id: String,
 }
 
+impl Validate for TerminateRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "workspace_id"),
+            &self.workspace_id,
+            "^ws-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TerminateWorkspacesRequest {
     /// <p>The WorkSpaces to terminate. You can specify up to 25 WorkSpaces.</p>
     #[serde(rename = "TerminateWorkspaceRequests")]
     pub terminate_workspace_requests: Vec<TerminateRequest>,
 }
 
+impl Validate for TerminateWorkspacesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "terminate_workspace_requests"),
+            self.terminate_workspace_requests.len(),
+            Some(1),
+            Some(25),
+        );
+        for (i, item) in self.terminate_workspace_requests.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "terminate_workspace_requests"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TerminateWorkspacesResult {
     /// <p>Information about the WorkSpaces that could not be terminated.</p>
     #[serde(rename = "FailedRequests")]
@@ -876,6 +1581,7 @@ pub struct TerminateWorkspacesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateRulesOfIpGroupRequest {
     /// <p>The identifier of the group.</p>
     #[serde(rename = "GroupId")]
@@ -885,13 +1591,23 @@ pub struct UpdateRulesOfIpGroupRequest {
     pub user_rules: Vec<IpRuleItem>,
 }
 
+impl Validate for UpdateRulesOfIpGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "group_id"),
+            &self.group_id,
+            "wsipg-[0-9a-z]{8,63}$",
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateRulesOfIpGroupResult {}
 
 /// <p>Describes the user storage for a WorkSpace bundle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UserStorage {
     /// <p>The size of the user storage.</p>
     #[serde(rename = "Capacity")]
@@ -899,9 +1615,22 @@ pub struct UserStorage {
     pub capacity: Option<String>,
 }
 
+impl Validate for UserStorage {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.capacity {
+            errors.check_length(
+                &field_path(path, "capacity"),
+                value.chars().count(),
+                Some(1),
+                None,
+            );
+        }
+    }
+}
+
 /// <p>Describes a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Workspace {
     /// <p>The identifier of the bundle used to create the WorkSpace.</p>
     #[serde(rename = "BundleId")]
@@ -967,7 +1696,7 @@ pub struct Workspace {
 
 /// <p>Describes a WorkSpace bundle.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkspaceBundle {
     /// <p>The bundle identifier.</p>
     #[serde(rename = "BundleId")]
@@ -1001,7 +1730,7 @@ pub struct WorkspaceBundle {
 
 /// <p>Describes the connection status of a WorkSpace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkspaceConnectionStatus {
     /// <p>The connection state of the WorkSpace. The connection state is unknown if the WorkSpace is stopped.</p>
     #[serde(rename = "ConnectionState")]
@@ -1023,7 +1752,7 @@ pub struct WorkspaceConnectionStatus {
 
 /// <p>Describes an AWS Directory Service directory that is used with Amazon WorkSpaces.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkspaceDirectory {
     /// <p>The directory alias.</p>
     #[serde(rename = "Alias")]
@@ -1081,7 +1810,7 @@ pub struct WorkspaceDirectory {
 
 /// <p>Describes a WorkSpace image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkspaceImage {
     /// <p>The description of the image.</p>
     #[serde(rename = "Description")]
@@ -1176,9 +1905,42 @@ pub struct WorkspaceRequest {
     pub workspace_properties: Option<WorkspaceProperties>,
 }
 
+impl Validate for WorkspaceRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_pattern(
+            &field_path(path, "bundle_id"),
+            &self.bundle_id,
+            "^wsb-[0-9a-z]{8,63}$",
+        );
+        errors.check_length(
+            &field_path(path, "directory_id"),
+            self.directory_id.chars().count(),
+            Some(10),
+            Some(65),
+        );
+        errors.check_pattern(
+            &field_path(path, "directory_id"),
+            &self.directory_id,
+            "^d-[0-9a-f]{8,63}$",
+        );
+        if let Some(ref value) = self.tags {
+            for (i, item) in value.iter().enumerate() {
+                let item_path = format!("{}[{}]", field_path(path, "tags"), i);
+                item.validate_at(&item_path, errors);
+            }
+        }
+        errors.check_length(
+            &field_path(path, "user_name"),
+            self.user_name.chars().count(),
+            Some(1),
+            Some(63),
+        );
+    }
+}
+
 /// <p>Describes an IP access control group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct WorkspacesIpGroup {
     /// <p>The description of the group.</p>
     #[serde(rename = "groupDesc")]
@@ -1245,7 +2007,12 @@ impl AssociateIpGroupsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AssociateIpGroupsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1309,7 +2076,12 @@ impl AuthorizeIpRulesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(AuthorizeIpRulesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1386,7 +2158,12 @@ impl CopyWorkspaceImageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1449,7 +2226,12 @@ impl CreateIpGroupError {
                 "ResourceLimitExceededException" => {
                     return RusotoError::Service(CreateIpGroupError::ResourceLimitExceeded(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1496,7 +2278,12 @@ impl CreateTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(CreateTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1540,7 +2327,12 @@ impl CreateWorkspacesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1591,7 +2383,12 @@ impl DeleteIpGroupError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteIpGroupError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1632,7 +2429,12 @@ impl DeleteTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1680,7 +2482,12 @@ impl DeleteWorkspaceImageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1715,7 +2522,12 @@ impl DescribeAccountError {
                 "AccessDeniedException" => {
                     return RusotoError::Service(DescribeAccountError::AccessDenied(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1752,7 +2564,12 @@ impl DescribeAccountModificationsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1801,7 +2618,12 @@ impl DescribeClientPropertiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1843,7 +2665,12 @@ impl DescribeIpGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1877,7 +2704,12 @@ impl DescribeTagsError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1912,7 +2744,12 @@ impl DescribeWorkspaceBundlesError {
                         DescribeWorkspaceBundlesError::InvalidParameterValues(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1949,7 +2786,12 @@ impl DescribeWorkspaceDirectoriesError {
                         DescribeWorkspaceDirectoriesError::InvalidParameterValues(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1984,7 +2826,12 @@ impl DescribeWorkspaceImagesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2026,7 +2873,12 @@ impl DescribeWorkspacesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2064,7 +2916,12 @@ impl DescribeWorkspacesConnectionStatusError {
                         DescribeWorkspacesConnectionStatusError::InvalidParameterValues(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2118,7 +2975,12 @@ impl DisassociateIpGroupsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2189,7 +3051,12 @@ impl ImportWorkspaceImageError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2238,7 +3105,12 @@ impl ListAvailableManagementCidrRangesError {
                         ListAvailableManagementCidrRangesError::InvalidParameterValues(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2294,7 +3166,12 @@ impl ModifyAccountError {
                 "ResourceUnavailableException" => {
                     return RusotoError::Service(ModifyAccountError::ResourceUnavailable(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2345,7 +3222,12 @@ impl ModifyClientPropertiesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2424,7 +3306,12 @@ impl ModifyWorkspacePropertiesError {
                         ModifyWorkspacePropertiesError::UnsupportedWorkspaceConfiguration(err.msg),
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2479,7 +3366,12 @@ impl ModifyWorkspaceStateError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2508,7 +3400,12 @@ impl RebootWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RebootWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2533,7 +3430,12 @@ impl RebuildWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RebuildWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2581,7 +3483,12 @@ impl RevokeIpRulesError {
                 "ResourceNotFoundException" => {
                     return RusotoError::Service(RevokeIpRulesError::ResourceNotFound(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2611,7 +3518,12 @@ impl StartWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2636,7 +3548,12 @@ impl StopWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2661,7 +3578,12 @@ impl TerminateWorkspacesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TerminateWorkspacesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2720,7 +3642,12 @@ impl UpdateRulesOfIpGroupError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2943,13 +3870,617 @@ pub trait Workspaces {
         input: UpdateRulesOfIpGroupRequest,
     ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError>;
 }
-/// A client for the Amazon WorkSpaces API.
-#[derive(Clone)]
-pub struct WorkspacesClient {
-    client: Client,
-    region: region::Region,
-}
-
+const _: Option<&dyn Workspaces> = None;
+impl<'a, T: Workspaces + ?Sized> Workspaces for &'a T {
+    fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        (**self).associate_ip_groups(input)
+    }
+    fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        (**self).authorize_ip_rules(input)
+    }
+    fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        (**self).copy_workspace_image(input)
+    }
+    fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        (**self).create_ip_group(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        (**self).create_workspaces(input)
+    }
+    fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        (**self).delete_ip_group(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        (**self).delete_workspace_image(input)
+    }
+    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+        (**self).describe_account()
+    }
+    fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        (**self).describe_account_modifications(input)
+    }
+    fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> RusotoFuture<DescribeClientPropertiesResult, DescribeClientPropertiesError> {
+        (**self).describe_client_properties(input)
+    }
+    fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> RusotoFuture<DescribeIpGroupsResult, DescribeIpGroupsError> {
+        (**self).describe_ip_groups(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResult, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceBundlesResult, DescribeWorkspaceBundlesError> {
+        (**self).describe_workspace_bundles(input)
+    }
+    fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceDirectoriesResult, DescribeWorkspaceDirectoriesError> {
+        (**self).describe_workspace_directories(input)
+    }
+    fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceImagesResult, DescribeWorkspaceImagesError> {
+        (**self).describe_workspace_images(input)
+    }
+    fn describe_workspaces(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> RusotoFuture<DescribeWorkspacesResult, DescribeWorkspacesError> {
+        (**self).describe_workspaces(input)
+    }
+    fn describe_workspaces_connection_status(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeWorkspacesConnectionStatusResult,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        (**self).describe_workspaces_connection_status(input)
+    }
+    fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> RusotoFuture<DisassociateIpGroupsResult, DisassociateIpGroupsError> {
+        (**self).disassociate_ip_groups(input)
+    }
+    fn import_workspace_image(
+        &self,
+        input: ImportWorkspaceImageRequest,
+    ) -> RusotoFuture<ImportWorkspaceImageResult, ImportWorkspaceImageError> {
+        (**self).import_workspace_image(input)
+    }
+    fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
+    {
+        (**self).list_available_management_cidr_ranges(input)
+    }
+    fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> RusotoFuture<ModifyAccountResult, ModifyAccountError> {
+        (**self).modify_account(input)
+    }
+    fn modify_client_properties(
+        &self,
+        input: ModifyClientPropertiesRequest,
+    ) -> RusotoFuture<ModifyClientPropertiesResult, ModifyClientPropertiesError> {
+        (**self).modify_client_properties(input)
+    }
+    fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> RusotoFuture<ModifyWorkspacePropertiesResult, ModifyWorkspacePropertiesError> {
+        (**self).modify_workspace_properties(input)
+    }
+    fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> RusotoFuture<ModifyWorkspaceStateResult, ModifyWorkspaceStateError> {
+        (**self).modify_workspace_state(input)
+    }
+    fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> RusotoFuture<RebootWorkspacesResult, RebootWorkspacesError> {
+        (**self).reboot_workspaces(input)
+    }
+    fn rebuild_workspaces(
+        &self,
+        input: RebuildWorkspacesRequest,
+    ) -> RusotoFuture<RebuildWorkspacesResult, RebuildWorkspacesError> {
+        (**self).rebuild_workspaces(input)
+    }
+    fn revoke_ip_rules(
+        &self,
+        input: RevokeIpRulesRequest,
+    ) -> RusotoFuture<RevokeIpRulesResult, RevokeIpRulesError> {
+        (**self).revoke_ip_rules(input)
+    }
+    fn start_workspaces(
+        &self,
+        input: StartWorkspacesRequest,
+    ) -> RusotoFuture<StartWorkspacesResult, StartWorkspacesError> {
+        (**self).start_workspaces(input)
+    }
+    fn stop_workspaces(
+        &self,
+        input: StopWorkspacesRequest,
+    ) -> RusotoFuture<StopWorkspacesResult, StopWorkspacesError> {
+        (**self).stop_workspaces(input)
+    }
+    fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> RusotoFuture<TerminateWorkspacesResult, TerminateWorkspacesError> {
+        (**self).terminate_workspaces(input)
+    }
+    fn update_rules_of_ip_group(
+        &self,
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError> {
+        (**self).update_rules_of_ip_group(input)
+    }
+}
+impl<T: Workspaces + ?Sized> Workspaces for Box<T> {
+    fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        (**self).associate_ip_groups(input)
+    }
+    fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        (**self).authorize_ip_rules(input)
+    }
+    fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        (**self).copy_workspace_image(input)
+    }
+    fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        (**self).create_ip_group(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        (**self).create_workspaces(input)
+    }
+    fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        (**self).delete_ip_group(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        (**self).delete_workspace_image(input)
+    }
+    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+        (**self).describe_account()
+    }
+    fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        (**self).describe_account_modifications(input)
+    }
+    fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> RusotoFuture<DescribeClientPropertiesResult, DescribeClientPropertiesError> {
+        (**self).describe_client_properties(input)
+    }
+    fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> RusotoFuture<DescribeIpGroupsResult, DescribeIpGroupsError> {
+        (**self).describe_ip_groups(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResult, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceBundlesResult, DescribeWorkspaceBundlesError> {
+        (**self).describe_workspace_bundles(input)
+    }
+    fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceDirectoriesResult, DescribeWorkspaceDirectoriesError> {
+        (**self).describe_workspace_directories(input)
+    }
+    fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceImagesResult, DescribeWorkspaceImagesError> {
+        (**self).describe_workspace_images(input)
+    }
+    fn describe_workspaces(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> RusotoFuture<DescribeWorkspacesResult, DescribeWorkspacesError> {
+        (**self).describe_workspaces(input)
+    }
+    fn describe_workspaces_connection_status(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeWorkspacesConnectionStatusResult,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        (**self).describe_workspaces_connection_status(input)
+    }
+    fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> RusotoFuture<DisassociateIpGroupsResult, DisassociateIpGroupsError> {
+        (**self).disassociate_ip_groups(input)
+    }
+    fn import_workspace_image(
+        &self,
+        input: ImportWorkspaceImageRequest,
+    ) -> RusotoFuture<ImportWorkspaceImageResult, ImportWorkspaceImageError> {
+        (**self).import_workspace_image(input)
+    }
+    fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
+    {
+        (**self).list_available_management_cidr_ranges(input)
+    }
+    fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> RusotoFuture<ModifyAccountResult, ModifyAccountError> {
+        (**self).modify_account(input)
+    }
+    fn modify_client_properties(
+        &self,
+        input: ModifyClientPropertiesRequest,
+    ) -> RusotoFuture<ModifyClientPropertiesResult, ModifyClientPropertiesError> {
+        (**self).modify_client_properties(input)
+    }
+    fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> RusotoFuture<ModifyWorkspacePropertiesResult, ModifyWorkspacePropertiesError> {
+        (**self).modify_workspace_properties(input)
+    }
+    fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> RusotoFuture<ModifyWorkspaceStateResult, ModifyWorkspaceStateError> {
+        (**self).modify_workspace_state(input)
+    }
+    fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> RusotoFuture<RebootWorkspacesResult, RebootWorkspacesError> {
+        (**self).reboot_workspaces(input)
+    }
+    fn rebuild_workspaces(
+        &self,
+        input: RebuildWorkspacesRequest,
+    ) -> RusotoFuture<RebuildWorkspacesResult, RebuildWorkspacesError> {
+        (**self).rebuild_workspaces(input)
+    }
+    fn revoke_ip_rules(
+        &self,
+        input: RevokeIpRulesRequest,
+    ) -> RusotoFuture<RevokeIpRulesResult, RevokeIpRulesError> {
+        (**self).revoke_ip_rules(input)
+    }
+    fn start_workspaces(
+        &self,
+        input: StartWorkspacesRequest,
+    ) -> RusotoFuture<StartWorkspacesResult, StartWorkspacesError> {
+        (**self).start_workspaces(input)
+    }
+    fn stop_workspaces(
+        &self,
+        input: StopWorkspacesRequest,
+    ) -> RusotoFuture<StopWorkspacesResult, StopWorkspacesError> {
+        (**self).stop_workspaces(input)
+    }
+    fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> RusotoFuture<TerminateWorkspacesResult, TerminateWorkspacesError> {
+        (**self).terminate_workspaces(input)
+    }
+    fn update_rules_of_ip_group(
+        &self,
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError> {
+        (**self).update_rules_of_ip_group(input)
+    }
+}
+impl<T: Workspaces + ?Sized> Workspaces for ::std::sync::Arc<T> {
+    fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        (**self).associate_ip_groups(input)
+    }
+    fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        (**self).authorize_ip_rules(input)
+    }
+    fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        (**self).copy_workspace_image(input)
+    }
+    fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        (**self).create_ip_group(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        (**self).create_workspaces(input)
+    }
+    fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        (**self).delete_ip_group(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        (**self).delete_workspace_image(input)
+    }
+    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+        (**self).describe_account()
+    }
+    fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        (**self).describe_account_modifications(input)
+    }
+    fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> RusotoFuture<DescribeClientPropertiesResult, DescribeClientPropertiesError> {
+        (**self).describe_client_properties(input)
+    }
+    fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> RusotoFuture<DescribeIpGroupsResult, DescribeIpGroupsError> {
+        (**self).describe_ip_groups(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResult, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceBundlesResult, DescribeWorkspaceBundlesError> {
+        (**self).describe_workspace_bundles(input)
+    }
+    fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceDirectoriesResult, DescribeWorkspaceDirectoriesError> {
+        (**self).describe_workspace_directories(input)
+    }
+    fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceImagesResult, DescribeWorkspaceImagesError> {
+        (**self).describe_workspace_images(input)
+    }
+    fn describe_workspaces(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> RusotoFuture<DescribeWorkspacesResult, DescribeWorkspacesError> {
+        (**self).describe_workspaces(input)
+    }
+    fn describe_workspaces_connection_status(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeWorkspacesConnectionStatusResult,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        (**self).describe_workspaces_connection_status(input)
+    }
+    fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> RusotoFuture<DisassociateIpGroupsResult, DisassociateIpGroupsError> {
+        (**self).disassociate_ip_groups(input)
+    }
+    fn import_workspace_image(
+        &self,
+        input: ImportWorkspaceImageRequest,
+    ) -> RusotoFuture<ImportWorkspaceImageResult, ImportWorkspaceImageError> {
+        (**self).import_workspace_image(input)
+    }
+    fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
+    {
+        (**self).list_available_management_cidr_ranges(input)
+    }
+    fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> RusotoFuture<ModifyAccountResult, ModifyAccountError> {
+        (**self).modify_account(input)
+    }
+    fn modify_client_properties(
+        &self,
+        input: ModifyClientPropertiesRequest,
+    ) -> RusotoFuture<ModifyClientPropertiesResult, ModifyClientPropertiesError> {
+        (**self).modify_client_properties(input)
+    }
+    fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> RusotoFuture<ModifyWorkspacePropertiesResult, ModifyWorkspacePropertiesError> {
+        (**self).modify_workspace_properties(input)
+    }
+    fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> RusotoFuture<ModifyWorkspaceStateResult, ModifyWorkspaceStateError> {
+        (**self).modify_workspace_state(input)
+    }
+    fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> RusotoFuture<RebootWorkspacesResult, RebootWorkspacesError> {
+        (**self).reboot_workspaces(input)
+    }
+    fn rebuild_workspaces(
+        &self,
+        input: RebuildWorkspacesRequest,
+    ) -> RusotoFuture<RebuildWorkspacesResult, RebuildWorkspacesError> {
+        (**self).rebuild_workspaces(input)
+    }
+    fn revoke_ip_rules(
+        &self,
+        input: RevokeIpRulesRequest,
+    ) -> RusotoFuture<RevokeIpRulesResult, RevokeIpRulesError> {
+        (**self).revoke_ip_rules(input)
+    }
+    fn start_workspaces(
+        &self,
+        input: StartWorkspacesRequest,
+    ) -> RusotoFuture<StartWorkspacesResult, StartWorkspacesError> {
+        (**self).start_workspaces(input)
+    }
+    fn stop_workspaces(
+        &self,
+        input: StopWorkspacesRequest,
+    ) -> RusotoFuture<StopWorkspacesResult, StopWorkspacesError> {
+        (**self).stop_workspaces(input)
+    }
+    fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> RusotoFuture<TerminateWorkspacesResult, TerminateWorkspacesError> {
+        (**self).terminate_workspaces(input)
+    }
+    fn update_rules_of_ip_group(
+        &self,
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError> {
+        (**self).update_rules_of_ip_group(input)
+    }
+}
+/// A client for the Amazon WorkSpaces API.
+#[derive(Clone)]
+pub struct WorkspacesClient {
+    client: Client,
+    region: region::Region,
+}
+
 impl WorkspacesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
@@ -2958,538 +4489,2266 @@ impl WorkspacesClient {
         Self::new_with_client(Client::shared(), region)
     }
 
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> WorkspacesClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkspacesClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesClient {
+        WorkspacesClient { client, region }
+    }
+}
+
+impl Workspaces for WorkspacesClient {
+    /// <p>Associates the specified IP access control group with the specified directory.</p>
+    fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("AssociateIpGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.AssociateIpGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AssociateIpGroupsResult, _>(
+                    response,
+                    "AssociateIpGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AssociateIpGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Adds one or more rules to the specified IP access control group.</p> <p>This action gives users permission to access their WorkSpaces from the CIDR address ranges specified in the rules.</p>
+    fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("AuthorizeIpRules");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.AuthorizeIpRules");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<AuthorizeIpRulesResult, _>(
+                    response,
+                    "AuthorizeIpRules",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(AuthorizeIpRulesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Copies the specified image from the specified Region to the current Region.</p>
+    fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CopyWorkspaceImage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.CopyWorkspaceImage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CopyWorkspaceImageResult, _>(
+                    response,
+                    "CopyWorkspaceImage",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CopyWorkspaceImageError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates an IP access control group.</p> <p>An IP access control group provides you with the ability to control the IP addresses from which users are allowed to access their WorkSpaces. To specify the CIDR address ranges, add rules to your IP access control group and then associate the group with your directory. You can add rules when you create the group or at any time using <a>AuthorizeIpRules</a>.</p> <p>There is a default IP access control group associated with your directory. If you don't associate an IP access control group with your directory, the default group is used. The default group includes a default rule that allows users to access their WorkSpaces from anywhere. You cannot modify the default IP access control group for your directory.</p>
+    fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CreateIpGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.CreateIpGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateIpGroupResult, _>(
+                    response,
+                    "CreateIpGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateIpGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates the specified tags for the specified WorkSpaces resource.</p>
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CreateTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.CreateTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateTagsResult, _>(response, "CreateTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates one or more WorkSpaces.</p> <p>This operation is asynchronous and returns before the WorkSpaces are created.</p>
+    fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("CreateWorkspaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.CreateWorkspaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<CreateWorkspacesResult, _>(
+                    response,
+                    "CreateWorkspaces",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateWorkspacesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified IP access control group.</p> <p>You cannot delete an IP access control group that is associated with a directory.</p>
+    fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeleteIpGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DeleteIpGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteIpGroupResult, _>(
+                    response,
+                    "DeleteIpGroup",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteIpGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified tags from the specified WorkSpaces resource.</p>
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeleteTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DeleteTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteTagsResult, _>(response, "DeleteTags")
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes the specified image from your account. To delete an image, you must first delete any bundles that are associated with the image and un-share the image if it is shared with other accounts. </p>
+    fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DeleteWorkspaceImage");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DeleteWorkspaceImage");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DeleteWorkspaceImageResult, _>(
+                    response,
+                    "DeleteWorkspaceImage",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DeleteWorkspaceImageError::from_response(response))
+                    }),
+                )
+            }
+        })
     }
 
-    pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesClient {
-        WorkspacesClient { client, region }
+    /// <p>Retrieves a list that describes the configuration of bring your own license (BYOL) for the specified account.</p>
+    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DescribeAccount");
+        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAccountResult, _>(
+                    response,
+                    "DescribeAccount",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeAccountError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes modifications to the configuration of bring your own license (BYOL) for the specified account.</p>
+    fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeAccountModifications");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "WorkspacesService.DescribeAccountModifications",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeAccountModificationsResult, _>(
+                    response,
+                    "DescribeAccountModifications",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeAccountModificationsError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified Amazon WorkSpaces clients.</p>
+    fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> RusotoFuture<DescribeClientPropertiesResult, DescribeClientPropertiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeClientProperties");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DescribeClientProperties");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeClientPropertiesResult, _>(
+                    response,
+                    "DescribeClientProperties",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeClientPropertiesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes one or more of your IP access control groups.</p>
+    fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> RusotoFuture<DescribeIpGroupsResult, DescribeIpGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeIpGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DescribeIpGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeIpGroupsResult, _>(
+                    response,
+                    "DescribeIpGroups",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeIpGroupsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Describes the specified tags for the specified WorkSpaces resource.</p>
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResult, DescribeTagsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeTags");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DescribeTags");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeTagsResult, _>(
+                    response,
+                    "DescribeTags",
+                )
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes the available WorkSpace bundles.</p> <p>You can filter the results using either bundle ID or owner, but not both.</p>
+    fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceBundlesResult, DescribeWorkspaceBundlesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceBundles");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceBundles");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeWorkspaceBundlesResult, _>(
+                    response,
+                    "DescribeWorkspaceBundles",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeWorkspaceBundlesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Describes the available AWS Directory Service directories that are registered with Amazon WorkSpaces.</p>
+    fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceDirectoriesResult, DescribeWorkspaceDirectoriesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceDirectories");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "WorkspacesService.DescribeWorkspaceDirectories",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeWorkspaceDirectoriesResult, _>(
+                    response,
+                    "DescribeWorkspaceDirectories",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeWorkspaceDirectoriesError::from_response(response))
+                }))
+            }
+        })
+    }
+
+    /// <p>Retrieves a list that describes one or more specified images, if the image identifiers are provided. Otherwise, all images in the account are described. </p>
+    fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> RusotoFuture<DescribeWorkspaceImagesResult, DescribeWorkspaceImagesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaceImages");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceImages");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeWorkspaceImagesResult, _>(
+                    response,
+                    "DescribeWorkspaceImages",
+                )
+            } else {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    Err(DescribeWorkspaceImagesError::from_response(response))
+                }))
+            }
+        })
     }
-}
 
-impl Workspaces for WorkspacesClient {
-    /// <p>Associates the specified IP access control group with the specified directory.</p>
-    fn associate_ip_groups(
+    /// <p>Describes the specified WorkSpaces.</p> <p>You can filter the results by using the bundle identifier, directory identifier, or owner, but you can specify only one filter at a time.</p>
+    fn describe_workspaces(
         &self,
-        input: AssociateIpGroupsRequest,
-    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        input: DescribeWorkspacesRequest,
+    ) -> RusotoFuture<DescribeWorkspacesResult, DescribeWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.AssociateIpGroups");
+        request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AssociateIpGroupsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<DescribeWorkspacesResult, _>(
+                    response,
+                    "DescribeWorkspaces",
+                )
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(AssociateIpGroupsError::from_response(response))),
+                        .and_then(|response| Err(DescribeWorkspacesError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Adds one or more rules to the specified IP access control group.</p> <p>This action gives users permission to access their WorkSpaces from the CIDR address ranges specified in the rules.</p>
-    fn authorize_ip_rules(
+    /// <p>Describes the connection status of the specified WorkSpaces.</p>
+    fn describe_workspaces_connection_status(
         &self,
-        input: AuthorizeIpRulesRequest,
-    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeWorkspacesConnectionStatusResult,
+        DescribeWorkspacesConnectionStatusError,
+    > {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DescribeWorkspacesConnectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.AuthorizeIpRules");
+        request.add_header(
+            "x-amz-target",
+            "WorkspacesService.DescribeWorkspacesConnectionStatus",
+        );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<DescribeWorkspacesConnectionStatusResult, _>(
+                    response,
+                    "DescribeWorkspacesConnectionStatus",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<AuthorizeIpRulesResult, _>()
+                    Err(DescribeWorkspacesConnectionStatusError::from_response(
+                        response,
+                    ))
                 }))
+            }
+        })
+    }
+
+    /// <p>Disassociates the specified IP access control group from the specified directory.</p>
+    fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> RusotoFuture<DisassociateIpGroupsResult, DisassociateIpGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("DisassociateIpGroups");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.DisassociateIpGroups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<DisassociateIpGroupsResult, _>(
+                    response,
+                    "DisassociateIpGroups",
+                )
             } else {
                 Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(AuthorizeIpRulesError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(DisassociateIpGroupsError::from_response(response))
+                    }),
                 )
             }
         })
     }
 
-    /// <p>Copies the specified image from the specified Region to the current Region.</p>
-    fn copy_workspace_image(
+    /// <p>Imports the specified Windows 7 or Windows 10 bring your own license (BYOL) image into Amazon WorkSpaces. The image must be an already licensed EC2 image that is in your AWS account, and you must own the image. </p>
+    fn import_workspace_image(
         &self,
-        input: CopyWorkspaceImageRequest,
-    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        input: ImportWorkspaceImageRequest,
+    ) -> RusotoFuture<ImportWorkspaceImageResult, ImportWorkspaceImageError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ImportWorkspaceImage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.CopyWorkspaceImage");
+        request.add_header("x-amz-target", "WorkspacesService.ImportWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CopyWorkspaceImageResult, _>()
-                }))
+                proto::json::deserialize_streaming::<ImportWorkspaceImageResult, _>(
+                    response,
+                    "ImportWorkspaceImage",
+                )
             } else {
                 Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CopyWorkspaceImageError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ImportWorkspaceImageError::from_response(response))
+                    }),
                 )
             }
         })
     }
 
-    /// <p>Creates an IP access control group.</p> <p>An IP access control group provides you with the ability to control the IP addresses from which users are allowed to access their WorkSpaces. To specify the CIDR address ranges, add rules to your IP access control group and then associate the group with your directory. You can add rules when you create the group or at any time using <a>AuthorizeIpRules</a>.</p> <p>There is a default IP access control group associated with your directory. If you don't associate an IP access control group with your directory, the default group is used. The default group includes a default rule that allows users to access their WorkSpaces from anywhere. You cannot modify the default IP access control group for your directory.</p>
-    fn create_ip_group(
+    /// <p>Retrieves a list of IP address ranges, specified as IPv4 CIDR blocks, that you can use for the network management interface when you enable bring your own license (BYOL). </p> <p>The management network interface is connected to a secure Amazon WorkSpaces management network. It is used for interactive streaming of the WorkSpace desktop to Amazon WorkSpaces clients, and to allow Amazon WorkSpaces to manage the WorkSpace.</p>
+    fn list_available_management_cidr_ranges(
         &self,
-        input: CreateIpGroupRequest,
-    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
+    {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ListAvailableManagementCidrRanges");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.CreateIpGroup");
+        request.add_header(
+            "x-amz-target",
+            "WorkspacesService.ListAvailableManagementCidrRanges",
+        );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<ListAvailableManagementCidrRangesResult, _>(
+                    response,
+                    "ListAvailableManagementCidrRanges",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateIpGroupResult, _>()
+                    Err(ListAvailableManagementCidrRangesError::from_response(
+                        response,
+                    ))
                 }))
+            }
+        })
+    }
+
+    /// <p>Modifies the configuration of bring your own license (BYOL) for the specified account.</p>
+    fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> RusotoFuture<ModifyAccountResult, ModifyAccountError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyAccount");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.ModifyAccount");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyAccountResult, _>(
+                    response,
+                    "ModifyAccount",
+                )
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(CreateIpGroupError::from_response(response))),
+                        .and_then(|response| Err(ModifyAccountError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Creates the specified tags for the specified WorkSpaces resource.</p>
-    fn create_tags(
+    /// <p>Modifies the properties of the specified Amazon WorkSpaces clients.</p>
+    fn modify_client_properties(
         &self,
-        input: CreateTagsRequest,
-    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        input: ModifyClientPropertiesRequest,
+    ) -> RusotoFuture<ModifyClientPropertiesResult, ModifyClientPropertiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyClientProperties");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.CreateTags");
+        request.add_header("x-amz-target", "WorkspacesService.ModifyClientProperties");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyClientPropertiesResult, _>(
+                    response,
+                    "ModifyClientProperties",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ModifyClientPropertiesError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Modifies the specified WorkSpace properties.</p>
+    fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> RusotoFuture<ModifyWorkspacePropertiesResult, ModifyWorkspacePropertiesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyWorkspaceProperties");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "WorkspacesService.ModifyWorkspaceProperties",
+        );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyWorkspacePropertiesResult, _>(
+                    response,
+                    "ModifyWorkspaceProperties",
+                )
+            } else {
                 Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTagsResult, _>()
+                    Err(ModifyWorkspacePropertiesError::from_response(response))
                 }))
+            }
+        })
+    }
+
+    /// <p>Sets the state of the specified WorkSpace.</p> <p>To maintain a WorkSpace without being interrupted, set the WorkSpace state to <code>ADMIN_MAINTENANCE</code>. WorkSpaces in this state do not respond to requests to reboot, stop, start, rebuild, or restore. An AutoStop WorkSpace in this state is not stopped. Users cannot log into a WorkSpace in the <code>ADMIN_MAINTENANCE</code> state.</p>
+    fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> RusotoFuture<ModifyWorkspaceStateResult, ModifyWorkspaceStateError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("ModifyWorkspaceState");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.ModifyWorkspaceState");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<ModifyWorkspaceStateResult, _>(
+                    response,
+                    "ModifyWorkspaceState",
+                )
+            } else {
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(ModifyWorkspaceStateError::from_response(response))
+                    }),
+                )
+            }
+        })
+    }
+
+    /// <p>Reboots the specified WorkSpaces.</p> <p>You cannot reboot a WorkSpace unless its state is <code>AVAILABLE</code> or <code>UNHEALTHY</code>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have rebooted.</p>
+    fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> RusotoFuture<RebootWorkspacesResult, RebootWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RebootWorkspaces");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "WorkspacesService.RebootWorkspaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                proto::json::deserialize_streaming::<RebootWorkspacesResult, _>(
+                    response,
+                    "RebootWorkspaces",
+                )
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(CreateTagsError::from_response(response))),
+                        .and_then(|response| Err(RebootWorkspacesError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Creates one or more WorkSpaces.</p> <p>This operation is asynchronous and returns before the WorkSpaces are created.</p>
-    fn create_workspaces(
+    /// <p>Rebuilds the specified WorkSpace.</p> <p>You cannot rebuild a WorkSpace unless its state is <code>AVAILABLE</code>, <code>ERROR</code>, or <code>UNHEALTHY</code>.</p> <p>Rebuilding a WorkSpace is a potentially destructive action that can result in the loss of data. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/reset-workspace.html">Rebuild a WorkSpace</a>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have been completely rebuilt.</p>
+    fn rebuild_workspaces(
         &self,
-        input: CreateWorkspacesRequest,
-    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        input: RebuildWorkspacesRequest,
+    ) -> RusotoFuture<RebuildWorkspacesResult, RebuildWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RebuildWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.CreateWorkspaces");
+        request.add_header("x-amz-target", "WorkspacesService.RebuildWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateWorkspacesResult, _>()
-                }))
+                proto::json::deserialize_streaming::<RebuildWorkspacesResult, _>(
+                    response,
+                    "RebuildWorkspaces",
+                )
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(CreateWorkspacesError::from_response(response))),
+                        .and_then(|response| Err(RebuildWorkspacesError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Deletes the specified IP access control group.</p> <p>You cannot delete an IP access control group that is associated with a directory.</p>
-    fn delete_ip_group(
+    /// <p>Removes one or more rules from the specified IP access control group.</p>
+    fn revoke_ip_rules(
         &self,
-        input: DeleteIpGroupRequest,
-    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        input: RevokeIpRulesRequest,
+    ) -> RusotoFuture<RevokeIpRulesResult, RevokeIpRulesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("RevokeIpRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DeleteIpGroup");
+        request.add_header("x-amz-target", "WorkspacesService.RevokeIpRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteIpGroupResult, _>()
-                }))
+                proto::json::deserialize_streaming::<RevokeIpRulesResult, _>(
+                    response,
+                    "RevokeIpRules",
+                )
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DeleteIpGroupError::from_response(response))),
+                        .and_then(|response| Err(RevokeIpRulesError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Deletes the specified tags from the specified WorkSpaces resource.</p>
-    fn delete_tags(
+    /// <p>Starts the specified WorkSpaces.</p> <p>You cannot start a WorkSpace unless it has a running mode of <code>AutoStop</code> and a state of <code>STOPPED</code>.</p>
+    fn start_workspaces(
         &self,
-        input: DeleteTagsRequest,
-    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        input: StartWorkspacesRequest,
+    ) -> RusotoFuture<StartWorkspacesResult, StartWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("StartWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DeleteTags");
+        request.add_header("x-amz-target", "WorkspacesService.StartWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTagsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<StartWorkspacesResult, _>(
+                    response,
+                    "StartWorkspaces",
+                )
             } else {
                 Box::new(
                     response
                         .buffer()
                         .from_err()
-                        .and_then(|response| Err(DeleteTagsError::from_response(response))),
+                        .and_then(|response| Err(StartWorkspacesError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Deletes the specified image from your account. To delete an image, you must first delete any bundles that are associated with the image and un-share the image if it is shared with other accounts. </p>
-    fn delete_workspace_image(
+    /// <p> Stops the specified WorkSpaces.</p> <p>You cannot stop a WorkSpace unless it has a running mode of <code>AutoStop</code> and a state of <code>AVAILABLE</code>, <code>IMPAIRED</code>, <code>UNHEALTHY</code>, or <code>ERROR</code>.</p>
+    fn stop_workspaces(
         &self,
-        input: DeleteWorkspaceImageRequest,
-    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        input: StopWorkspacesRequest,
+    ) -> RusotoFuture<StopWorkspacesResult, StopWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("StopWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DeleteWorkspaceImage");
+        request.add_header("x-amz-target", "WorkspacesService.StopWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteWorkspaceImageResult, _>()
-                }))
+                proto::json::deserialize_streaming::<StopWorkspacesResult, _>(
+                    response,
+                    "StopWorkspaces",
+                )
             } else {
                 Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DeleteWorkspaceImageError::from_response(response))
-                    }),
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(StopWorkspacesError::from_response(response))),
                 )
             }
         })
     }
 
-    /// <p>Retrieves a list that describes the configuration of bring your own license (BYOL) for the specified account.</p>
-    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+    /// <p>Terminates the specified WorkSpaces.</p> <p>Terminating a WorkSpace is a permanent action and cannot be undone. The user's data is destroyed. If you need to archive any user data, contact Amazon Web Services before terminating the WorkSpace.</p> <p>You can terminate a WorkSpace that is in any state except <code>SUSPENDED</code>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have been completely terminated.</p>
+    fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> RusotoFuture<TerminateWorkspacesResult, TerminateWorkspacesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("TerminateWorkspaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeAccount");
-        request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
+        request.add_header("x-amz-target", "WorkspacesService.TerminateWorkspaces");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAccountResult, _>()
-                }))
+                proto::json::deserialize_streaming::<TerminateWorkspacesResult, _>(
+                    response,
+                    "TerminateWorkspaces",
+                )
             } else {
                 Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeAccountError::from_response(response))),
+                    response.buffer().from_err().and_then(|response| {
+                        Err(TerminateWorkspacesError::from_response(response))
+                    }),
                 )
             }
         })
     }
 
-    /// <p>Retrieves a list that describes modifications to the configuration of bring your own license (BYOL) for the specified account.</p>
-    fn describe_account_modifications(
+    /// <p>Replaces the current rules of the specified IP access control group with the specified rules.</p>
+    fn update_rules_of_ip_group(
         &self,
-        input: DescribeAccountModificationsRequest,
-    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
+        request.set_operation("UpdateRulesOfIpGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkspacesService.DescribeAccountModifications",
-        );
+        request.add_header("x-amz-target", "WorkspacesService.UpdateRulesOfIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeAccountModificationsResult, _>()
-                }))
+                proto::json::deserialize_streaming::<UpdateRulesOfIpGroupResult, _>(
+                    response,
+                    "UpdateRulesOfIpGroup",
+                )
             } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeAccountModificationsError::from_response(response))
-                }))
+                Box::new(
+                    response.buffer().from_err().and_then(|response| {
+                        Err(UpdateRulesOfIpGroupError::from_response(response))
+                    }),
+                )
             }
         })
     }
-
-    /// <p>Retrieves a list that describes one or more specified Amazon WorkSpaces clients.</p>
+}
+
+/// A mock of the Amazon WorkSpaces API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockWorkspaces {
+    associate_ip_groups: Option<
+        Box<
+            dyn Fn(
+                    AssociateIpGroupsRequest,
+                ) -> ::std::result::Result<
+                    AssociateIpGroupsResult,
+                    RusotoError<AssociateIpGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    authorize_ip_rules: Option<
+        Box<
+            dyn Fn(
+                    AuthorizeIpRulesRequest,
+                ) -> ::std::result::Result<
+                    AuthorizeIpRulesResult,
+                    RusotoError<AuthorizeIpRulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    copy_workspace_image: Option<
+        Box<
+            dyn Fn(
+                    CopyWorkspaceImageRequest,
+                ) -> ::std::result::Result<
+                    CopyWorkspaceImageResult,
+                    RusotoError<CopyWorkspaceImageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    create_ip_group: Option<
+        Box<
+            dyn Fn(
+                    CreateIpGroupRequest,
+                )
+                    -> ::std::result::Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_tags: Option<
+        Box<
+            dyn Fn(
+                    CreateTagsRequest,
+                )
+                    -> ::std::result::Result<CreateTagsResult, RusotoError<CreateTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_workspaces: Option<
+        Box<
+            dyn Fn(
+                    CreateWorkspacesRequest,
+                ) -> ::std::result::Result<
+                    CreateWorkspacesResult,
+                    RusotoError<CreateWorkspacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_ip_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteIpGroupRequest,
+                )
+                    -> ::std::result::Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_tags: Option<
+        Box<
+            dyn Fn(
+                    DeleteTagsRequest,
+                )
+                    -> ::std::result::Result<DeleteTagsResult, RusotoError<DeleteTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_workspace_image: Option<
+        Box<
+            dyn Fn(
+                    DeleteWorkspaceImageRequest,
+                ) -> ::std::result::Result<
+                    DeleteWorkspaceImageResult,
+                    RusotoError<DeleteWorkspaceImageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_account: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    DescribeAccountResult,
+                    RusotoError<DescribeAccountError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_account_modifications: Option<
+        Box<
+            dyn Fn(
+                    DescribeAccountModificationsRequest,
+                ) -> ::std::result::Result<
+                    DescribeAccountModificationsResult,
+                    RusotoError<DescribeAccountModificationsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_client_properties: Option<
+        Box<
+            dyn Fn(
+                    DescribeClientPropertiesRequest,
+                ) -> ::std::result::Result<
+                    DescribeClientPropertiesResult,
+                    RusotoError<DescribeClientPropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_ip_groups: Option<
+        Box<
+            dyn Fn(
+                    DescribeIpGroupsRequest,
+                ) -> ::std::result::Result<
+                    DescribeIpGroupsResult,
+                    RusotoError<DescribeIpGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_tags: Option<
+        Box<
+            dyn Fn(
+                    DescribeTagsRequest,
+                )
+                    -> ::std::result::Result<DescribeTagsResult, RusotoError<DescribeTagsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    describe_workspace_bundles: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkspaceBundlesRequest,
+                ) -> ::std::result::Result<
+                    DescribeWorkspaceBundlesResult,
+                    RusotoError<DescribeWorkspaceBundlesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_workspace_directories: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkspaceDirectoriesRequest,
+                ) -> ::std::result::Result<
+                    DescribeWorkspaceDirectoriesResult,
+                    RusotoError<DescribeWorkspaceDirectoriesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_workspace_images: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkspaceImagesRequest,
+                ) -> ::std::result::Result<
+                    DescribeWorkspaceImagesResult,
+                    RusotoError<DescribeWorkspaceImagesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_workspaces: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkspacesRequest,
+                ) -> ::std::result::Result<
+                    DescribeWorkspacesResult,
+                    RusotoError<DescribeWorkspacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    describe_workspaces_connection_status: Option<
+        Box<
+            dyn Fn(
+                    DescribeWorkspacesConnectionStatusRequest,
+                ) -> ::std::result::Result<
+                    DescribeWorkspacesConnectionStatusResult,
+                    RusotoError<DescribeWorkspacesConnectionStatusError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    disassociate_ip_groups: Option<
+        Box<
+            dyn Fn(
+                    DisassociateIpGroupsRequest,
+                ) -> ::std::result::Result<
+                    DisassociateIpGroupsResult,
+                    RusotoError<DisassociateIpGroupsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    import_workspace_image: Option<
+        Box<
+            dyn Fn(
+                    ImportWorkspaceImageRequest,
+                ) -> ::std::result::Result<
+                    ImportWorkspaceImageResult,
+                    RusotoError<ImportWorkspaceImageError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    list_available_management_cidr_ranges: Option<
+        Box<
+            dyn Fn(
+                    ListAvailableManagementCidrRangesRequest,
+                ) -> ::std::result::Result<
+                    ListAvailableManagementCidrRangesResult,
+                    RusotoError<ListAvailableManagementCidrRangesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_account: Option<
+        Box<
+            dyn Fn(
+                    ModifyAccountRequest,
+                )
+                    -> ::std::result::Result<ModifyAccountResult, RusotoError<ModifyAccountError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    modify_client_properties: Option<
+        Box<
+            dyn Fn(
+                    ModifyClientPropertiesRequest,
+                ) -> ::std::result::Result<
+                    ModifyClientPropertiesResult,
+                    RusotoError<ModifyClientPropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_workspace_properties: Option<
+        Box<
+            dyn Fn(
+                    ModifyWorkspacePropertiesRequest,
+                ) -> ::std::result::Result<
+                    ModifyWorkspacePropertiesResult,
+                    RusotoError<ModifyWorkspacePropertiesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    modify_workspace_state: Option<
+        Box<
+            dyn Fn(
+                    ModifyWorkspaceStateRequest,
+                ) -> ::std::result::Result<
+                    ModifyWorkspaceStateResult,
+                    RusotoError<ModifyWorkspaceStateError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    reboot_workspaces: Option<
+        Box<
+            dyn Fn(
+                    RebootWorkspacesRequest,
+                ) -> ::std::result::Result<
+                    RebootWorkspacesResult,
+                    RusotoError<RebootWorkspacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    rebuild_workspaces: Option<
+        Box<
+            dyn Fn(
+                    RebuildWorkspacesRequest,
+                ) -> ::std::result::Result<
+                    RebuildWorkspacesResult,
+                    RusotoError<RebuildWorkspacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    revoke_ip_rules: Option<
+        Box<
+            dyn Fn(
+                    RevokeIpRulesRequest,
+                )
+                    -> ::std::result::Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    start_workspaces: Option<
+        Box<
+            dyn Fn(
+                    StartWorkspacesRequest,
+                ) -> ::std::result::Result<
+                    StartWorkspacesResult,
+                    RusotoError<StartWorkspacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    stop_workspaces: Option<
+        Box<
+            dyn Fn(
+                    StopWorkspacesRequest,
+                )
+                    -> ::std::result::Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    terminate_workspaces: Option<
+        Box<
+            dyn Fn(
+                    TerminateWorkspacesRequest,
+                ) -> ::std::result::Result<
+                    TerminateWorkspacesResult,
+                    RusotoError<TerminateWorkspacesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_rules_of_ip_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateRulesOfIpGroupRequest,
+                ) -> ::std::result::Result<
+                    UpdateRulesOfIpGroupResult,
+                    RusotoError<UpdateRulesOfIpGroupError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockWorkspaces {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockWorkspaces {
+        Default::default()
+    }
+
+    /// Answers `associate_ip_groups` with what `handler` returns for its input.
+    pub fn with_associate_ip_groups<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                AssociateIpGroupsRequest,
+            ) -> ::std::result::Result<
+                AssociateIpGroupsResult,
+                RusotoError<AssociateIpGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.associate_ip_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `authorize_ip_rules` with what `handler` returns for its input.
+    pub fn with_authorize_ip_rules<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                AuthorizeIpRulesRequest,
+            )
+                -> ::std::result::Result<AuthorizeIpRulesResult, RusotoError<AuthorizeIpRulesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.authorize_ip_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `copy_workspace_image` with what `handler` returns for its input.
+    pub fn with_copy_workspace_image<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                CopyWorkspaceImageRequest,
+            ) -> ::std::result::Result<
+                CopyWorkspaceImageResult,
+                RusotoError<CopyWorkspaceImageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.copy_workspace_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_ip_group` with what `handler` returns for its input.
+    pub fn with_create_ip_group<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                CreateIpGroupRequest,
+            )
+                -> ::std::result::Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_ip_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_tags` with what `handler` returns for its input.
+    pub fn with_create_tags<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                CreateTagsRequest,
+            ) -> ::std::result::Result<CreateTagsResult, RusotoError<CreateTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_workspaces` with what `handler` returns for its input.
+    pub fn with_create_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                CreateWorkspacesRequest,
+            )
+                -> ::std::result::Result<CreateWorkspacesResult, RusotoError<CreateWorkspacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_ip_group` with what `handler` returns for its input.
+    pub fn with_delete_ip_group<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DeleteIpGroupRequest,
+            )
+                -> ::std::result::Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_ip_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_tags` with what `handler` returns for its input.
+    pub fn with_delete_tags<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DeleteTagsRequest,
+            ) -> ::std::result::Result<DeleteTagsResult, RusotoError<DeleteTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_workspace_image` with what `handler` returns for its input.
+    pub fn with_delete_workspace_image<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DeleteWorkspaceImageRequest,
+            ) -> ::std::result::Result<
+                DeleteWorkspaceImageResult,
+                RusotoError<DeleteWorkspaceImageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_workspace_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account` with what `handler` returns.
+    pub fn with_describe_account<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn() -> ::std::result::Result<DescribeAccountResult, RusotoError<DescribeAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_account_modifications` with what `handler` returns for its input.
+    pub fn with_describe_account_modifications<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeAccountModificationsRequest,
+            ) -> ::std::result::Result<
+                DescribeAccountModificationsResult,
+                RusotoError<DescribeAccountModificationsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_account_modifications = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_client_properties` with what `handler` returns for its input.
+    pub fn with_describe_client_properties<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeClientPropertiesRequest,
+            ) -> ::std::result::Result<
+                DescribeClientPropertiesResult,
+                RusotoError<DescribeClientPropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_client_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_ip_groups` with what `handler` returns for its input.
+    pub fn with_describe_ip_groups<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeIpGroupsRequest,
+            )
+                -> ::std::result::Result<DescribeIpGroupsResult, RusotoError<DescribeIpGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_ip_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_tags` with what `handler` returns for its input.
+    pub fn with_describe_tags<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeTagsRequest,
+            )
+                -> ::std::result::Result<DescribeTagsResult, RusotoError<DescribeTagsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_tags = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workspace_bundles` with what `handler` returns for its input.
+    pub fn with_describe_workspace_bundles<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeWorkspaceBundlesRequest,
+            ) -> ::std::result::Result<
+                DescribeWorkspaceBundlesResult,
+                RusotoError<DescribeWorkspaceBundlesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workspace_bundles = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workspace_directories` with what `handler` returns for its input.
+    pub fn with_describe_workspace_directories<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeWorkspaceDirectoriesRequest,
+            ) -> ::std::result::Result<
+                DescribeWorkspaceDirectoriesResult,
+                RusotoError<DescribeWorkspaceDirectoriesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workspace_directories = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workspace_images` with what `handler` returns for its input.
+    pub fn with_describe_workspace_images<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeWorkspaceImagesRequest,
+            ) -> ::std::result::Result<
+                DescribeWorkspaceImagesResult,
+                RusotoError<DescribeWorkspaceImagesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workspace_images = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workspaces` with what `handler` returns for its input.
+    pub fn with_describe_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeWorkspacesRequest,
+            ) -> ::std::result::Result<
+                DescribeWorkspacesResult,
+                RusotoError<DescribeWorkspacesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `describe_workspaces_connection_status` with what `handler` returns for its input.
+    pub fn with_describe_workspaces_connection_status<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DescribeWorkspacesConnectionStatusRequest,
+            ) -> ::std::result::Result<
+                DescribeWorkspacesConnectionStatusResult,
+                RusotoError<DescribeWorkspacesConnectionStatusError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.describe_workspaces_connection_status = Some(Box::new(handler));
+        self
+    }
+    /// Answers `disassociate_ip_groups` with what `handler` returns for its input.
+    pub fn with_disassociate_ip_groups<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                DisassociateIpGroupsRequest,
+            ) -> ::std::result::Result<
+                DisassociateIpGroupsResult,
+                RusotoError<DisassociateIpGroupsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.disassociate_ip_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `import_workspace_image` with what `handler` returns for its input.
+    pub fn with_import_workspace_image<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                ImportWorkspaceImageRequest,
+            ) -> ::std::result::Result<
+                ImportWorkspaceImageResult,
+                RusotoError<ImportWorkspaceImageError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.import_workspace_image = Some(Box::new(handler));
+        self
+    }
+    /// Answers `list_available_management_cidr_ranges` with what `handler` returns for its input.
+    pub fn with_list_available_management_cidr_ranges<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                ListAvailableManagementCidrRangesRequest,
+            ) -> ::std::result::Result<
+                ListAvailableManagementCidrRangesResult,
+                RusotoError<ListAvailableManagementCidrRangesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.list_available_management_cidr_ranges = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_account` with what `handler` returns for its input.
+    pub fn with_modify_account<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                ModifyAccountRequest,
+            )
+                -> ::std::result::Result<ModifyAccountResult, RusotoError<ModifyAccountError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_account = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_client_properties` with what `handler` returns for its input.
+    pub fn with_modify_client_properties<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                ModifyClientPropertiesRequest,
+            ) -> ::std::result::Result<
+                ModifyClientPropertiesResult,
+                RusotoError<ModifyClientPropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_client_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_workspace_properties` with what `handler` returns for its input.
+    pub fn with_modify_workspace_properties<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                ModifyWorkspacePropertiesRequest,
+            ) -> ::std::result::Result<
+                ModifyWorkspacePropertiesResult,
+                RusotoError<ModifyWorkspacePropertiesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_workspace_properties = Some(Box::new(handler));
+        self
+    }
+    /// Answers `modify_workspace_state` with what `handler` returns for its input.
+    pub fn with_modify_workspace_state<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                ModifyWorkspaceStateRequest,
+            ) -> ::std::result::Result<
+                ModifyWorkspaceStateResult,
+                RusotoError<ModifyWorkspaceStateError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.modify_workspace_state = Some(Box::new(handler));
+        self
+    }
+    /// Answers `reboot_workspaces` with what `handler` returns for its input.
+    pub fn with_reboot_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                RebootWorkspacesRequest,
+            )
+                -> ::std::result::Result<RebootWorkspacesResult, RusotoError<RebootWorkspacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.reboot_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `rebuild_workspaces` with what `handler` returns for its input.
+    pub fn with_rebuild_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                RebuildWorkspacesRequest,
+            ) -> ::std::result::Result<
+                RebuildWorkspacesResult,
+                RusotoError<RebuildWorkspacesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.rebuild_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `revoke_ip_rules` with what `handler` returns for its input.
+    pub fn with_revoke_ip_rules<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                RevokeIpRulesRequest,
+            )
+                -> ::std::result::Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.revoke_ip_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `start_workspaces` with what `handler` returns for its input.
+    pub fn with_start_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                StartWorkspacesRequest,
+            )
+                -> ::std::result::Result<StartWorkspacesResult, RusotoError<StartWorkspacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.start_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `stop_workspaces` with what `handler` returns for its input.
+    pub fn with_stop_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                StopWorkspacesRequest,
+            )
+                -> ::std::result::Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stop_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `terminate_workspaces` with what `handler` returns for its input.
+    pub fn with_terminate_workspaces<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                TerminateWorkspacesRequest,
+            ) -> ::std::result::Result<
+                TerminateWorkspacesResult,
+                RusotoError<TerminateWorkspacesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.terminate_workspaces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_rules_of_ip_group` with what `handler` returns for its input.
+    pub fn with_update_rules_of_ip_group<F>(mut self, handler: F) -> MockWorkspaces
+    where
+        F: Fn(
+                UpdateRulesOfIpGroupRequest,
+            ) -> ::std::result::Result<
+                UpdateRulesOfIpGroupResult,
+                RusotoError<UpdateRulesOfIpGroupError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_rules_of_ip_group = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl Workspaces for MockWorkspaces {
+    fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> RusotoFuture<AssociateIpGroupsResult, AssociateIpGroupsError> {
+        match self.associate_ip_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for associate_ip_groups"),
+        }
+    }
+    fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> RusotoFuture<AuthorizeIpRulesResult, AuthorizeIpRulesError> {
+        match self.authorize_ip_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for authorize_ip_rules"),
+        }
+    }
+    fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> RusotoFuture<CopyWorkspaceImageResult, CopyWorkspaceImageError> {
+        match self.copy_workspace_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for copy_workspace_image"),
+        }
+    }
+    fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> RusotoFuture<CreateIpGroupResult, CreateIpGroupError> {
+        match self.create_ip_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for create_ip_group"),
+        }
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResult, CreateTagsError> {
+        match self.create_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for create_tags"),
+        }
+    }
+    fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> RusotoFuture<CreateWorkspacesResult, CreateWorkspacesError> {
+        match self.create_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for create_workspaces"),
+        }
+    }
+    fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> RusotoFuture<DeleteIpGroupResult, DeleteIpGroupError> {
+        match self.delete_ip_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for delete_ip_group"),
+        }
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResult, DeleteTagsError> {
+        match self.delete_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for delete_tags"),
+        }
+    }
+    fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> RusotoFuture<DeleteWorkspaceImageResult, DeleteWorkspaceImageError> {
+        match self.delete_workspace_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for delete_workspace_image"),
+        }
+    }
+    fn describe_account(&self) -> RusotoFuture<DescribeAccountResult, DescribeAccountError> {
+        match self.describe_account {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockWorkspaces has no handler for describe_account"),
+        }
+    }
+    fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> RusotoFuture<DescribeAccountModificationsResult, DescribeAccountModificationsError> {
+        match self.describe_account_modifications {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_account_modifications"),
+        }
+    }
     fn describe_client_properties(
         &self,
         input: DescribeClientPropertiesRequest,
     ) -> RusotoFuture<DescribeClientPropertiesResult, DescribeClientPropertiesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeClientProperties");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeClientPropertiesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeClientPropertiesError::from_response(response))
-                }))
-            }
-        })
+        match self.describe_client_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_client_properties"),
+        }
     }
-
-    /// <p>Describes one or more of your IP access control groups.</p>
     fn describe_ip_groups(
         &self,
         input: DescribeIpGroupsRequest,
     ) -> RusotoFuture<DescribeIpGroupsResult, DescribeIpGroupsError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeIpGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeIpGroupsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeIpGroupsError::from_response(response))),
-                )
-            }
-        })
+        match self.describe_ip_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_ip_groups"),
+        }
     }
-
-    /// <p>Describes the specified tags for the specified WorkSpaces resource.</p>
     fn describe_tags(
         &self,
         input: DescribeTagsRequest,
     ) -> RusotoFuture<DescribeTagsResult, DescribeTagsError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeTags");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTagsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTagsError::from_response(response))),
-                )
-            }
-        })
+        match self.describe_tags {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_tags"),
+        }
     }
-
-    /// <p>Retrieves a list that describes the available WorkSpace bundles.</p> <p>You can filter the results using either bundle ID or owner, but not both.</p>
     fn describe_workspace_bundles(
         &self,
         input: DescribeWorkspaceBundlesRequest,
     ) -> RusotoFuture<DescribeWorkspaceBundlesResult, DescribeWorkspaceBundlesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceBundles");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkspaceBundlesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeWorkspaceBundlesError::from_response(response))
-                }))
-            }
-        })
+        match self.describe_workspace_bundles {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_workspace_bundles"),
+        }
     }
-
-    /// <p>Describes the available AWS Directory Service directories that are registered with Amazon WorkSpaces.</p>
     fn describe_workspace_directories(
         &self,
         input: DescribeWorkspaceDirectoriesRequest,
     ) -> RusotoFuture<DescribeWorkspaceDirectoriesResult, DescribeWorkspaceDirectoriesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkspacesService.DescribeWorkspaceDirectories",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkspaceDirectoriesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeWorkspaceDirectoriesError::from_response(response))
-                }))
-            }
-        })
+        match self.describe_workspace_directories {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_workspace_directories"),
+        }
     }
-
-    /// <p>Retrieves a list that describes one or more specified images, if the image identifiers are provided. Otherwise, all images in the account are described. </p>
     fn describe_workspace_images(
         &self,
         input: DescribeWorkspaceImagesRequest,
     ) -> RusotoFuture<DescribeWorkspaceImagesResult, DescribeWorkspaceImagesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceImages");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkspaceImagesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeWorkspaceImagesError::from_response(response))
-                }))
-            }
-        })
+        match self.describe_workspace_images {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_workspace_images"),
+        }
     }
-
-    /// <p>Describes the specified WorkSpaces.</p> <p>You can filter the results by using the bundle identifier, directory identifier, or owner, but you can specify only one filter at a time.</p>
     fn describe_workspaces(
         &self,
         input: DescribeWorkspacesRequest,
     ) -> RusotoFuture<DescribeWorkspacesResult, DescribeWorkspacesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkspacesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeWorkspacesError::from_response(response))),
-                )
-            }
-        })
+        match self.describe_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for describe_workspaces"),
+        }
     }
-
-    /// <p>Describes the connection status of the specified WorkSpaces.</p>
     fn describe_workspaces_connection_status(
         &self,
         input: DescribeWorkspacesConnectionStatusRequest,
@@ -3497,432 +6756,140 @@ impl Workspaces for WorkspacesClient {
         DescribeWorkspacesConnectionStatusResult,
         DescribeWorkspacesConnectionStatusError,
     > {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkspacesService.DescribeWorkspacesConnectionStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeWorkspacesConnectionStatusResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeWorkspacesConnectionStatusError::from_response(
-                        response,
-                    ))
-                }))
+        match self.describe_workspaces_connection_status {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorkspaces has no handler for describe_workspaces_connection_status")
             }
-        })
+        }
     }
-
-    /// <p>Disassociates the specified IP access control group from the specified directory.</p>
     fn disassociate_ip_groups(
         &self,
         input: DisassociateIpGroupsRequest,
     ) -> RusotoFuture<DisassociateIpGroupsResult, DisassociateIpGroupsError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.DisassociateIpGroups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DisassociateIpGroupsResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(DisassociateIpGroupsError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.disassociate_ip_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for disassociate_ip_groups"),
+        }
     }
-
-    /// <p>Imports the specified Windows 7 or Windows 10 bring your own license (BYOL) image into Amazon WorkSpaces. The image must be an already licensed EC2 image that is in your AWS account, and you must own the image. </p>
     fn import_workspace_image(
         &self,
         input: ImportWorkspaceImageRequest,
     ) -> RusotoFuture<ImportWorkspaceImageResult, ImportWorkspaceImageError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.ImportWorkspaceImage");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ImportWorkspaceImageResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ImportWorkspaceImageError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.import_workspace_image {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for import_workspace_image"),
+        }
     }
-
-    /// <p>Retrieves a list of IP address ranges, specified as IPv4 CIDR blocks, that you can use for the network management interface when you enable bring your own license (BYOL). </p> <p>The management network interface is connected to a secure Amazon WorkSpaces management network. It is used for interactive streaming of the WorkSpace desktop to Amazon WorkSpaces clients, and to allow Amazon WorkSpaces to manage the WorkSpace.</p>
-    fn list_available_management_cidr_ranges(
-        &self,
-        input: ListAvailableManagementCidrRangesRequest,
-    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
-    {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkspacesService.ListAvailableManagementCidrRanges",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListAvailableManagementCidrRangesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ListAvailableManagementCidrRangesError::from_response(
-                        response,
-                    ))
-                }))
+    fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> RusotoFuture<ListAvailableManagementCidrRangesResult, ListAvailableManagementCidrRangesError>
+    {
+        match self.list_available_management_cidr_ranges {
+            Some(ref handler) => handler(input).into(),
+            None => {
+                panic!("MockWorkspaces has no handler for list_available_management_cidr_ranges")
             }
-        })
+        }
     }
-
-    /// <p>Modifies the configuration of bring your own license (BYOL) for the specified account.</p>
     fn modify_account(
         &self,
         input: ModifyAccountRequest,
     ) -> RusotoFuture<ModifyAccountResult, ModifyAccountError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.ModifyAccount");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyAccountResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ModifyAccountError::from_response(response))),
-                )
-            }
-        })
+        match self.modify_account {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for modify_account"),
+        }
     }
-
-    /// <p>Modifies the properties of the specified Amazon WorkSpaces clients.</p>
     fn modify_client_properties(
         &self,
         input: ModifyClientPropertiesRequest,
     ) -> RusotoFuture<ModifyClientPropertiesResult, ModifyClientPropertiesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.ModifyClientProperties");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyClientPropertiesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ModifyClientPropertiesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.modify_client_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for modify_client_properties"),
+        }
     }
-
-    /// <p>Modifies the specified WorkSpace properties.</p>
     fn modify_workspace_properties(
         &self,
         input: ModifyWorkspacePropertiesRequest,
     ) -> RusotoFuture<ModifyWorkspacePropertiesResult, ModifyWorkspacePropertiesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "WorkspacesService.ModifyWorkspaceProperties",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyWorkspacePropertiesResult, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(ModifyWorkspacePropertiesError::from_response(response))
-                }))
-            }
-        })
+        match self.modify_workspace_properties {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for modify_workspace_properties"),
+        }
     }
-
-    /// <p>Sets the state of the specified WorkSpace.</p> <p>To maintain a WorkSpace without being interrupted, set the WorkSpace state to <code>ADMIN_MAINTENANCE</code>. WorkSpaces in this state do not respond to requests to reboot, stop, start, rebuild, or restore. An AutoStop WorkSpace in this state is not stopped. Users cannot log into a WorkSpace in the <code>ADMIN_MAINTENANCE</code> state.</p>
     fn modify_workspace_state(
         &self,
         input: ModifyWorkspaceStateRequest,
     ) -> RusotoFuture<ModifyWorkspaceStateResult, ModifyWorkspaceStateError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.ModifyWorkspaceState");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ModifyWorkspaceStateResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(ModifyWorkspaceStateError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.modify_workspace_state {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for modify_workspace_state"),
+        }
     }
-
-    /// <p>Reboots the specified WorkSpaces.</p> <p>You cannot reboot a WorkSpace unless its state is <code>AVAILABLE</code> or <code>UNHEALTHY</code>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have rebooted.</p>
     fn reboot_workspaces(
         &self,
         input: RebootWorkspacesRequest,
     ) -> RusotoFuture<RebootWorkspacesResult, RebootWorkspacesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.RebootWorkspaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RebootWorkspacesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebootWorkspacesError::from_response(response))),
-                )
-            }
-        })
+        match self.reboot_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for reboot_workspaces"),
+        }
     }
-
-    /// <p>Rebuilds the specified WorkSpace.</p> <p>You cannot rebuild a WorkSpace unless its state is <code>AVAILABLE</code>, <code>ERROR</code>, or <code>UNHEALTHY</code>.</p> <p>Rebuilding a WorkSpace is a potentially destructive action that can result in the loss of data. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/reset-workspace.html">Rebuild a WorkSpace</a>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have been completely rebuilt.</p>
     fn rebuild_workspaces(
         &self,
         input: RebuildWorkspacesRequest,
     ) -> RusotoFuture<RebuildWorkspacesResult, RebuildWorkspacesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.RebuildWorkspaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RebuildWorkspacesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RebuildWorkspacesError::from_response(response))),
-                )
-            }
-        })
+        match self.rebuild_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for rebuild_workspaces"),
+        }
     }
-
-    /// <p>Removes one or more rules from the specified IP access control group.</p>
     fn revoke_ip_rules(
         &self,
         input: RevokeIpRulesRequest,
     ) -> RusotoFuture<RevokeIpRulesResult, RevokeIpRulesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.RevokeIpRules");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RevokeIpRulesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(RevokeIpRulesError::from_response(response))),
-                )
-            }
-        })
+        match self.revoke_ip_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for revoke_ip_rules"),
+        }
     }
-
-    /// <p>Starts the specified WorkSpaces.</p> <p>You cannot start a WorkSpace unless it has a running mode of <code>AutoStop</code> and a state of <code>STOPPED</code>.</p>
     fn start_workspaces(
         &self,
         input: StartWorkspacesRequest,
     ) -> RusotoFuture<StartWorkspacesResult, StartWorkspacesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.StartWorkspaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StartWorkspacesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StartWorkspacesError::from_response(response))),
-                )
-            }
-        })
+        match self.start_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for start_workspaces"),
+        }
     }
-
-    /// <p> Stops the specified WorkSpaces.</p> <p>You cannot stop a WorkSpace unless it has a running mode of <code>AutoStop</code> and a state of <code>AVAILABLE</code>, <code>IMPAIRED</code>, <code>UNHEALTHY</code>, or <code>ERROR</code>.</p>
     fn stop_workspaces(
         &self,
         input: StopWorkspacesRequest,
     ) -> RusotoFuture<StopWorkspacesResult, StopWorkspacesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.StopWorkspaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<StopWorkspacesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(StopWorkspacesError::from_response(response))),
-                )
-            }
-        })
+        match self.stop_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for stop_workspaces"),
+        }
     }
-
-    /// <p>Terminates the specified WorkSpaces.</p> <p>Terminating a WorkSpace is a permanent action and cannot be undone. The user's data is destroyed. If you need to archive any user data, contact Amazon Web Services before terminating the WorkSpace.</p> <p>You can terminate a WorkSpace that is in any state except <code>SUSPENDED</code>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have been completely terminated.</p>
     fn terminate_workspaces(
         &self,
         input: TerminateWorkspacesRequest,
     ) -> RusotoFuture<TerminateWorkspacesResult, TerminateWorkspacesError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.TerminateWorkspaces");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TerminateWorkspacesResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(TerminateWorkspacesError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.terminate_workspaces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for terminate_workspaces"),
+        }
     }
-
-    /// <p>Replaces the current rules of the specified IP access control group with the specified rules.</p>
     fn update_rules_of_ip_group(
         &self,
         input: UpdateRulesOfIpGroupRequest,
     ) -> RusotoFuture<UpdateRulesOfIpGroupResult, UpdateRulesOfIpGroupError> {
-        let mut request = SignedRequest::new("POST", "workspaces", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "WorkspacesService.UpdateRulesOfIpGroup");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateRulesOfIpGroupResult, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
-                        Err(UpdateRulesOfIpGroupError::from_response(response))
-                    }),
-                )
-            }
-        })
+        match self.update_rules_of_ip_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockWorkspaces has no handler for update_rules_of_ip_group"),
+        }
     }
 }
@@ -22,10 +22,11 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::validation::{field_path, Validate, ValidationErrors};
 use serde_json;
 /// <p>An alias for an edge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Alias {
     /// <p>The canonical name of the alias.</p>
     #[serde(rename = "Name")]
@@ -43,7 +44,7 @@ pub struct Alias {
 
 /// <p>Value of a segment annotation. Has one of three value types: Number, Boolean or String.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AnnotationValue {
     /// <p>Value for a Boolean annotation.</p>
     #[serde(rename = "BooleanValue")]
@@ -61,7 +62,7 @@ pub struct AnnotationValue {
 
 /// <p>A list of availability zones corresponding to the segments in a trace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct AvailabilityZoneDetail {
     /// <p>The name of a corresponding availability zone.</p>
     #[serde(rename = "Name")]
@@ -71,6 +72,7 @@ pub struct AvailabilityZoneDetail {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BackendConnectionErrors {
     /// <p><p/></p>
     #[serde(rename = "ConnectionRefusedCount")]
@@ -99,6 +101,7 @@ pub struct BackendConnectionErrors {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct BatchGetTracesRequest {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -109,8 +112,17 @@ pub struct BatchGetTracesRequest {
     pub trace_ids: Vec<String>,
 }
 
+impl Validate for BatchGetTracesRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.trace_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "trace_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(35));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct BatchGetTracesResult {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -127,6 +139,7 @@ pub struct BatchGetTracesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateGroupRequest {
     /// <p>The filter expression defining criteria by which to group traces.</p>
     #[serde(rename = "FilterExpression")]
@@ -137,8 +150,19 @@ pub struct CreateGroupRequest {
     pub group_name: String,
 }
 
+impl Validate for CreateGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "group_name"),
+            self.group_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateGroupResult {
     /// <p>The group that was created. Contains the name of the group that was created, the ARN of the group that was generated based on the group name, and the filter expression that was assigned to the group.</p>
     #[serde(rename = "Group")]
@@ -147,14 +171,22 @@ pub struct CreateGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct CreateSamplingRuleRequest {
     /// <p>The rule definition.</p>
     #[serde(rename = "SamplingRule")]
     pub sampling_rule: SamplingRule,
 }
 
+impl Validate for CreateSamplingRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.sampling_rule
+            .validate_at(&field_path(path, "sampling_rule"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct CreateSamplingRuleResult {
     /// <p>The saved rule definition and metadata.</p>
     #[serde(rename = "SamplingRuleRecord")]
@@ -163,6 +195,7 @@ pub struct CreateSamplingRuleResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteGroupRequest {
     /// <p>The ARN of the group that was generated on creation.</p>
     #[serde(rename = "GroupARN")]
@@ -174,11 +207,33 @@ pub struct DeleteGroupRequest {
     pub group_name: Option<String>,
 }
 
+impl Validate for DeleteGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.group_arn {
+            errors.check_length(
+                &field_path(path, "group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(400),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteGroupResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct DeleteSamplingRuleRequest {
     /// <p>The ARN of the sampling rule. Specify a rule by either name or ARN, but not both.</p>
     #[serde(rename = "RuleARN")]
@@ -191,7 +246,7 @@ pub struct DeleteSamplingRuleRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct DeleteSamplingRuleResult {
     /// <p>The deleted rule definition and metadata.</p>
     #[serde(rename = "SamplingRuleRecord")]
@@ -201,7 +256,7 @@ pub struct DeleteSamplingRuleResult {
 
 /// <p>Information about a connection between two services.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Edge {
     /// <p>Aliases for the edge.</p>
     #[serde(rename = "Aliases")]
@@ -231,7 +286,7 @@ pub struct Edge {
 
 /// <p>Response statistics for an edge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EdgeStatistics {
     /// <p>Information about requests that failed with a 4xx Client Error status code.</p>
     #[serde(rename = "ErrorStatistics")]
@@ -257,7 +312,7 @@ pub struct EdgeStatistics {
 
 /// <p>A configuration document that specifies encryption configuration settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct EncryptionConfig {
     /// <p>The ID of the customer master key (CMK) used for encryption, if applicable.</p>
     #[serde(rename = "KeyId")]
@@ -275,7 +330,7 @@ pub struct EncryptionConfig {
 
 /// <p>The root cause of a trace summary error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorRootCause {
     /// <p>A list of services corresponding to an error. A service identifies a segment and it contains a name, account ID, type, and inferred flag.</p>
     #[serde(rename = "Services")]
@@ -285,7 +340,7 @@ pub struct ErrorRootCause {
 
 /// <p>A collection of segments and corresponding subsegments associated to a trace summary error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorRootCauseEntity {
     /// <p>The types and messages of the exceptions.</p>
     #[serde(rename = "Exceptions")]
@@ -303,7 +358,7 @@ pub struct ErrorRootCauseEntity {
 
 /// <p>A collection of fields identifying the services in a trace summary error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorRootCauseService {
     /// <p>The account ID associated to the service.</p>
     #[serde(rename = "AccountId")]
@@ -333,7 +388,7 @@ pub struct ErrorRootCauseService {
 
 /// <p>Information about requests that failed with a 4xx Client Error status code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ErrorStatistics {
     /// <p>The number of requests that failed with untracked 4xx Client Error status codes.</p>
     #[serde(rename = "OtherCount")]
@@ -351,7 +406,7 @@ pub struct ErrorStatistics {
 
 /// <p>The root cause information for a trace summary fault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaultRootCause {
     /// <p>A list of corresponding services. A service identifies a segment and it contains a name, account ID, type, and inferred flag.</p>
     #[serde(rename = "Services")]
@@ -361,7 +416,7 @@ pub struct FaultRootCause {
 
 /// <p>A collection of segments and corresponding subsegments associated to a trace summary fault error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaultRootCauseEntity {
     /// <p>The types and messages of the exceptions.</p>
     #[serde(rename = "Exceptions")]
@@ -379,7 +434,7 @@ pub struct FaultRootCauseEntity {
 
 /// <p>A collection of fields identifying the services in a trace summary fault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaultRootCauseService {
     /// <p>The account ID associated to the service.</p>
     #[serde(rename = "AccountId")]
@@ -409,7 +464,7 @@ pub struct FaultRootCauseService {
 
 /// <p>Information about requests that failed with a 5xx Server Error status code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct FaultStatistics {
     /// <p>The number of requests that failed with untracked 5xx Server Error status codes.</p>
     #[serde(rename = "OtherCount")]
@@ -422,10 +477,11 @@ pub struct FaultStatistics {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetEncryptionConfigRequest {}
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetEncryptionConfigResult {
     /// <p>The encryption configuration document.</p>
     #[serde(rename = "EncryptionConfig")]
@@ -434,6 +490,7 @@ pub struct GetEncryptionConfigResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupRequest {
     /// <p>The ARN of the group that was generated on creation.</p>
     #[serde(rename = "GroupARN")]
@@ -445,8 +502,29 @@ pub struct GetGroupRequest {
     pub group_name: Option<String>,
 }
 
+impl Validate for GetGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.group_arn {
+            errors.check_length(
+                &field_path(path, "group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(400),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupResult {
     /// <p>The group that was requested. Contains the name of the group, the ARN of the group, and the filter expression that assigned to the group.</p>
     #[serde(rename = "Group")]
@@ -455,6 +533,7 @@ pub struct GetGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetGroupsRequest {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -462,8 +541,21 @@ pub struct GetGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl Validate for GetGroupsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.next_token {
+            errors.check_length(
+                &field_path(path, "next_token"),
+                value.chars().count(),
+                Some(1),
+                Some(100),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetGroupsResult {
     /// <p>The collection of all active groups.</p>
     #[serde(rename = "Groups")]
@@ -476,6 +568,7 @@ pub struct GetGroupsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSamplingRulesRequest {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -484,7 +577,7 @@ pub struct GetSamplingRulesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSamplingRulesResult {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -497,6 +590,7 @@ pub struct GetSamplingRulesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSamplingStatisticSummariesRequest {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -505,7 +599,7 @@ pub struct GetSamplingStatisticSummariesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSamplingStatisticSummariesResult {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -518,14 +612,34 @@ pub struct GetSamplingStatisticSummariesResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetSamplingTargetsRequest {
     /// <p>Information about rules that the service is using to sample requests.</p>
     #[serde(rename = "SamplingStatisticsDocuments")]
     pub sampling_statistics_documents: Vec<SamplingStatisticsDocument>,
 }
 
+impl Validate for GetSamplingTargetsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "sampling_statistics_documents"),
+            self.sampling_statistics_documents.len(),
+            None,
+            Some(25),
+        );
+        for (i, item) in self.sampling_statistics_documents.iter().enumerate() {
+            let item_path = format!(
+                "{}[{}]",
+                field_path(path, "sampling_statistics_documents"),
+                i
+            );
+            item.validate_at(&item_path, errors);
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetSamplingTargetsResult {
     /// <p>The last time a user changed the sampling rule configuration. If the sampling rule configuration changed since the service last retrieved it, the service should call <a>GetSamplingRules</a> to get the latest version.</p>
     #[serde(rename = "LastRuleModification")]
@@ -542,6 +656,7 @@ pub struct GetSamplingTargetsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetServiceGraphRequest {
     /// <p>The end of the timeframe for which to generate a graph.</p>
     #[serde(rename = "EndTime")]
@@ -563,8 +678,29 @@ pub struct GetServiceGraphRequest {
     pub start_time: f64,
 }
 
+impl Validate for GetServiceGraphRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.group_arn {
+            errors.check_length(
+                &field_path(path, "group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(400),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetServiceGraphResult {
     /// <p>A flag indicating whether the group's filter expression has been consistent, or if the returned service graph may show traces from an older version of the group's filter expression.</p>
     #[serde(rename = "ContainsOldGroupVersions")]
@@ -589,6 +725,7 @@ pub struct GetServiceGraphResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTimeSeriesServiceStatisticsRequest {
     /// <p>The end of the time frame for which to aggregate statistics.</p>
     #[serde(rename = "EndTime")]
@@ -618,8 +755,37 @@ pub struct GetTimeSeriesServiceStatisticsRequest {
     pub start_time: f64,
 }
 
+impl Validate for GetTimeSeriesServiceStatisticsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.entity_selector_expression {
+            errors.check_length(
+                &field_path(path, "entity_selector_expression"),
+                value.chars().count(),
+                Some(1),
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.group_arn {
+            errors.check_length(
+                &field_path(path, "group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(400),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTimeSeriesServiceStatisticsResult {
     /// <p>A flag indicating whether or not a group's filter expression has been consistent, or if a returned aggregation may show statistics from an older version of the group's filter expression.</p>
     #[serde(rename = "ContainsOldGroupVersions")]
@@ -636,6 +802,7 @@ pub struct GetTimeSeriesServiceStatisticsResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTraceGraphRequest {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -646,8 +813,17 @@ pub struct GetTraceGraphRequest {
     pub trace_ids: Vec<String>,
 }
 
+impl Validate for GetTraceGraphRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        for (i, item) in self.trace_ids.iter().enumerate() {
+            let item_path = format!("{}[{}]", field_path(path, "trace_ids"), i);
+            errors.check_length(&item_path, item.chars().count(), Some(1), Some(35));
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTraceGraphResult {
     /// <p>Pagination token. Not used.</p>
     #[serde(rename = "NextToken")]
@@ -660,6 +836,7 @@ pub struct GetTraceGraphResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct GetTraceSummariesRequest {
     /// <p>The end of the time frame for which to retrieve traces.</p>
     #[serde(rename = "EndTime")]
@@ -690,7 +867,7 @@ pub struct GetTraceSummariesRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GetTraceSummariesResult {
     /// <p>The start time of this page of results.</p>
     #[serde(rename = "ApproximateTime")]
@@ -712,7 +889,7 @@ pub struct GetTraceSummariesResult {
 
 /// <p>Details and metadata for a group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Group {
     /// <p>The filter expression defining the parameters to include traces.</p>
     #[serde(rename = "FilterExpression")]
@@ -730,7 +907,7 @@ pub struct Group {
 
 /// <p>Details for a group without metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct GroupSummary {
     /// <p>The filter expression defining the parameters to include traces.</p>
     #[serde(rename = "FilterExpression")]
@@ -748,7 +925,7 @@ pub struct GroupSummary {
 
 /// <p>An entry in a histogram for a statistic. A histogram maps the range of observed values on the X axis, and the prevalence of each value on the Y axis.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct HistogramEntry {
     /// <p>The prevalence of the entry.</p>
     #[serde(rename = "Count")]
@@ -762,7 +939,7 @@ pub struct HistogramEntry {
 
 /// <p>Information about an HTTP request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Http {
     /// <p>The IP address of the requestor.</p>
     #[serde(rename = "ClientIp")]
@@ -788,7 +965,7 @@ pub struct Http {
 
 /// <p>A list of EC2 instance IDs corresponding to the segments in a trace. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct InstanceIdDetail {
     /// <p>The ID of a corresponding EC2 instance.</p>
     #[serde(rename = "Id")]
@@ -797,6 +974,7 @@ pub struct InstanceIdDetail {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutEncryptionConfigRequest {
     /// <p>An AWS KMS customer master key (CMK) in one of the following formats:</p> <ul> <li> <p> <b>Alias</b> - The name of the key. For example, <code>alias/MyKey</code>.</p> </li> <li> <p> <b>Key ID</b> - The KMS key ID of the key. For example, <code>ae4aa6d49-a4d8-9df9-a475-4ff6d7898456</code>.</p> </li> <li> <p> <b>ARN</b> - The full Amazon Resource Name of the key ID or alias. For example, <code>arn:aws:kms:us-east-2:123456789012:key/ae4aa6d49-a4d8-9df9-a475-4ff6d7898456</code>. Use this format to specify a key in a different account.</p> </li> </ul> <p>Omit this key if you set <code>Type</code> to <code>NONE</code>.</p>
     #[serde(rename = "KeyId")]
@@ -807,8 +985,21 @@ pub struct PutEncryptionConfigRequest {
     pub type_: String,
 }
 
+impl Validate for PutEncryptionConfigRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.key_id {
+            errors.check_length(
+                &field_path(path, "key_id"),
+                value.chars().count(),
+                Some(1),
+                Some(3000),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutEncryptionConfigResult {
     /// <p>The new encryption configuration.</p>
     #[serde(rename = "EncryptionConfig")]
@@ -817,6 +1008,7 @@ pub struct PutEncryptionConfigResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutTelemetryRecordsRequest {
     /// <p><p/></p>
     #[serde(rename = "EC2InstanceId")]
@@ -835,11 +1027,41 @@ pub struct PutTelemetryRecordsRequest {
     pub telemetry_records: Vec<TelemetryRecord>,
 }
 
+impl Validate for PutTelemetryRecordsRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.ec2_instance_id {
+            errors.check_length(
+                &field_path(path, "ec2_instance_id"),
+                value.chars().count(),
+                None,
+                Some(20),
+            );
+        }
+        if let Some(ref value) = self.hostname {
+            errors.check_length(
+                &field_path(path, "hostname"),
+                value.chars().count(),
+                None,
+                Some(255),
+            );
+        }
+        if let Some(ref value) = self.resource_arn {
+            errors.check_length(
+                &field_path(path, "resource_arn"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutTelemetryRecordsResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct PutTraceSegmentsRequest {
     /// <p>A string containing a JSON document defining one or more segments or subsegments.</p>
     #[serde(rename = "TraceSegmentDocuments")]
@@ -847,7 +1069,7 @@ pub struct PutTraceSegmentsRequest {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct PutTraceSegmentsResult {
     /// <p>Segments that failed processing.</p>
     #[serde(rename = "UnprocessedTraceSegments")]
@@ -857,7 +1079,7 @@ pub struct PutTraceSegmentsResult {
 
 /// <p>A list of resources ARNs corresponding to the segments in a trace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResourceARNDetail {
     /// <p>The ARN of a corresponding resource.</p>
     #[serde(rename = "ARN")]
@@ -867,7 +1089,7 @@ pub struct ResourceARNDetail {
 
 /// <p>The root cause information for a response time warning.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResponseTimeRootCause {
     /// <p>A list of corresponding services. A service identifies a segment and contains a name, account ID, type, and inferred flag.</p>
     #[serde(rename = "Services")]
@@ -877,7 +1099,7 @@ pub struct ResponseTimeRootCause {
 
 /// <p>A collection of segments and corresponding subsegments associated to a response time warning.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResponseTimeRootCauseEntity {
     /// <p>The types and messages of the exceptions.</p>
     #[serde(rename = "Coverage")]
@@ -895,7 +1117,7 @@ pub struct ResponseTimeRootCauseEntity {
 
 /// <p>A collection of fields identifying the service in a response time warning.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ResponseTimeRootCauseService {
     /// <p>The account ID associated to the service.</p>
     #[serde(rename = "AccountId")]
@@ -925,7 +1147,7 @@ pub struct ResponseTimeRootCauseService {
 
 /// <p>The exception associated with a root cause.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct RootCauseException {
     /// <p>The message of the exception.</p>
     #[serde(rename = "Message")]
@@ -984,9 +1206,78 @@ pub struct SamplingRule {
     pub version: i64,
 }
 
+impl Validate for SamplingRule {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            errors.check_length(&field_path(path, "attributes"), value.len(), None, Some(5));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            }
+        }
+        errors.check_range(
+            &field_path(path, "fixed_rate"),
+            self.fixed_rate,
+            None,
+            Some(1.0),
+        );
+        errors.check_length(
+            &field_path(path, "http_method"),
+            self.http_method.chars().count(),
+            None,
+            Some(10),
+        );
+        errors.check_length(
+            &field_path(path, "host"),
+            self.host.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_range(
+            &field_path(path, "priority"),
+            self.priority,
+            Some(1),
+            Some(9999),
+        );
+        errors.check_length(
+            &field_path(path, "resource_arn"),
+            self.resource_arn.chars().count(),
+            None,
+            Some(500),
+        );
+        if let Some(ref value) = self.rule_name {
+            errors.check_length(
+                &field_path(path, "rule_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+        errors.check_length(
+            &field_path(path, "service_name"),
+            self.service_name.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "service_type"),
+            self.service_type.chars().count(),
+            None,
+            Some(64),
+        );
+        errors.check_length(
+            &field_path(path, "url_path"),
+            self.url_path.chars().count(),
+            None,
+            Some(128),
+        );
+        errors.check_range(&field_path(path, "version"), self.version, Some(1), None);
+    }
+}
+
 /// <p>A <a>SamplingRule</a> and its metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SamplingRuleRecord {
     /// <p>When the rule was created.</p>
     #[serde(rename = "CreatedAt")]
@@ -1004,6 +1295,7 @@ pub struct SamplingRuleRecord {
 
 /// <p>A document specifying changes to a sampling rule's configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SamplingRuleUpdate {
     /// <p>Matches attributes derived from the request.</p>
     #[serde(rename = "Attributes")]
@@ -1055,9 +1347,77 @@ pub struct SamplingRuleUpdate {
     pub url_path: Option<String>,
 }
 
+impl Validate for SamplingRuleUpdate {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.attributes {
+            errors.check_length(&field_path(path, "attributes"), value.len(), None, Some(5));
+            for (key, item) in value.iter() {
+                let item_path = format!("{}[{}]", field_path(path, "attributes"), key);
+                errors.check_length(&item_path, item.chars().count(), Some(1), Some(32));
+            }
+        }
+        if let Some(ref value) = self.http_method {
+            errors.check_length(
+                &field_path(path, "http_method"),
+                value.chars().count(),
+                None,
+                Some(10),
+            );
+        }
+        if let Some(ref value) = self.host {
+            errors.check_length(
+                &field_path(path, "host"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.resource_arn {
+            errors.check_length(
+                &field_path(path, "resource_arn"),
+                value.chars().count(),
+                None,
+                Some(500),
+            );
+        }
+        if let Some(ref value) = self.rule_name {
+            errors.check_length(
+                &field_path(path, "rule_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+        if let Some(ref value) = self.service_name {
+            errors.check_length(
+                &field_path(path, "service_name"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.service_type {
+            errors.check_length(
+                &field_path(path, "service_type"),
+                value.chars().count(),
+                None,
+                Some(64),
+            );
+        }
+        if let Some(ref value) = self.url_path {
+            errors.check_length(
+                &field_path(path, "url_path"),
+                value.chars().count(),
+                None,
+                Some(128),
+            );
+        }
+    }
+}
+
 /// <p>Aggregated request sampling data for a sampling rule across all services for a 10 second window.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SamplingStatisticSummary {
     /// <p>The number of requests recorded with borrowed reservoir quota.</p>
     #[serde(rename = "BorrowCount")]
@@ -1083,6 +1443,7 @@ pub struct SamplingStatisticSummary {
 
 /// <p>Request sampling results for a single rule from a service. Results are for the last 10 seconds unless the service has been assigned a longer reporting interval after a previous call to <a>GetSamplingTargets</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SamplingStatisticsDocument {
     /// <p>The number of requests recorded with borrowed reservoir quota.</p>
     #[serde(rename = "BorrowCount")]
@@ -1105,8 +1466,26 @@ pub struct SamplingStatisticsDocument {
     pub timestamp: f64,
 }
 
+impl Validate for SamplingStatisticsDocument {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        errors.check_length(
+            &field_path(path, "client_id"),
+            self.client_id.chars().count(),
+            Some(24),
+            Some(24),
+        );
+        errors.check_length(
+            &field_path(path, "rule_name"),
+            self.rule_name.chars().count(),
+            Some(1),
+            Some(32),
+        );
+    }
+}
+
 /// <p>The name and value of a sampling rule to apply to a trace summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct SamplingStrategy {
     /// <p>The name of a sampling rule.</p>
     #[serde(rename = "Name")]
@@ -1120,7 +1499,7 @@ pub struct SamplingStrategy {
 
 /// <p>Temporary changes to a sampling rule configuration. To meet the global sampling target for a rule, X-Ray calculates a new reservoir for each service based on the recent sampling results of all services that called <a>GetSamplingTargets</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct SamplingTargetDocument {
     /// <p>The percentage of matching requests to instrument, after the reservoir is exhausted.</p>
     #[serde(rename = "FixedRate")]
@@ -1146,7 +1525,7 @@ pub struct SamplingTargetDocument {
 
 /// <p>A segment from a trace that has been ingested by the X-Ray service. The segment can be compiled from documents uploaded with <a>PutTraceSegments</a>, or an <code>inferred</code> segment for a downstream service, generated from a subsegment sent by the service that called it.</p> <p>For the full segment document schema, see <a href="https://docs.aws.amazon.com/xray/latest/devguide/xray-api-segmentdocuments.html">AWS X-Ray Segment Documents</a> in the <i>AWS X-Ray Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Segment {
     /// <p>The segment document.</p>
     #[serde(rename = "Document")]
@@ -1160,7 +1539,7 @@ pub struct Segment {
 
 /// <p>Information about an application that processed requests, users that made requests, or downstream services, resources and applications that an application used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Service {
     /// <p>Identifier of the AWS account in which the service runs.</p>
     #[serde(rename = "AccountId")]
@@ -1218,7 +1597,7 @@ pub struct Service {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceId {
     /// <p><p/></p>
     #[serde(rename = "AccountId")]
@@ -1240,7 +1619,7 @@ pub struct ServiceId {
 
 /// <p>Response statistics for a service.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ServiceStatistics {
     /// <p>Information about requests that failed with a 4xx Client Error status code.</p>
     #[serde(rename = "ErrorStatistics")]
@@ -1266,6 +1645,7 @@ pub struct ServiceStatistics {
 
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct TelemetryRecord {
     /// <p><p/></p>
     #[serde(rename = "BackendConnectionErrors")]
@@ -1294,7 +1674,7 @@ pub struct TelemetryRecord {
 
 /// <p>A list of TimeSeriesStatistic structures.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TimeSeriesServiceStatistics {
     #[serde(rename = "EdgeSummaryStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1314,7 +1694,7 @@ pub struct TimeSeriesServiceStatistics {
 
 /// <p>A collection of segment documents with matching trace IDs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct Trace {
     /// <p>The length of time in seconds between the start time of the root segment and the end time of the last segment that completed.</p>
     #[serde(rename = "Duration")]
@@ -1332,7 +1712,7 @@ pub struct Trace {
 
 /// <p>Metadata generated from the segment documents in a trace.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TraceSummary {
     /// <p>Annotations from the trace's segment documents.</p>
     #[serde(rename = "Annotations")]
@@ -1418,7 +1798,7 @@ pub struct TraceSummary {
 
 /// <p>Information about a user recorded in segment documents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct TraceUser {
     /// <p>Services that the user's request hit.</p>
     #[serde(rename = "ServiceIds")]
@@ -1432,7 +1812,7 @@ pub struct TraceUser {
 
 /// <p>Sampling statistics from a call to <a>GetSamplingTargets</a> that X-Ray could not process.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnprocessedStatistics {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
@@ -1450,7 +1830,7 @@ pub struct UnprocessedStatistics {
 
 /// <p>Information about a segment that failed processing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UnprocessedTraceSegment {
     /// <p>The error that caused processing to fail.</p>
     #[serde(rename = "ErrorCode")]
@@ -1467,6 +1847,7 @@ pub struct UnprocessedTraceSegment {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateGroupRequest {
     /// <p>The updated filter expression defining criteria by which to group traces.</p>
     #[serde(rename = "FilterExpression")]
@@ -1482,8 +1863,29 @@ pub struct UpdateGroupRequest {
     pub group_name: Option<String>,
 }
 
+impl Validate for UpdateGroupRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        if let Some(ref value) = self.group_arn {
+            errors.check_length(
+                &field_path(path, "group_arn"),
+                value.chars().count(),
+                Some(1),
+                Some(400),
+            );
+        }
+        if let Some(ref value) = self.group_name {
+            errors.check_length(
+                &field_path(path, "group_name"),
+                value.chars().count(),
+                Some(1),
+                Some(32),
+            );
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateGroupResult {
     /// <p>The group that was updated. Contains the name of the group that was updated, the ARN of the group that was updated, and the updated filter expression assigned to the group.</p>
     #[serde(rename = "Group")]
@@ -1492,14 +1894,22 @@ pub struct UpdateGroupResult {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "deserialize_structs"), derive(Deserialize))]
 pub struct UpdateSamplingRuleRequest {
     /// <p>The rule and fields to change.</p>
     #[serde(rename = "SamplingRuleUpdate")]
     pub sampling_rule_update: SamplingRuleUpdate,
 }
 
+impl Validate for UpdateSamplingRuleRequest {
+    fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {
+        self.sampling_rule_update
+            .validate_at(&field_path(path, "sampling_rule_update"), errors);
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct UpdateSamplingRuleResult {
     /// <p>The updated rule definition and metadata.</p>
     #[serde(rename = "SamplingRuleRecord")]
@@ -1509,7 +1919,7 @@ pub struct UpdateSamplingRuleResult {
 
 /// <p>Information about a segment annotation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(any(test, feature = "serialize_structs"), derive(Serialize))]
 pub struct ValueWithServiceIds {
     /// <p>Values of the annotation.</p>
     #[serde(rename = "AnnotationValue")]
@@ -1540,7 +1950,12 @@ impl BatchGetTracesError {
                 "ThrottledException" => {
                     return RusotoError::Service(BatchGetTracesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1579,7 +1994,12 @@ impl CreateGroupError {
                 "ThrottledException" => {
                     return RusotoError::Service(CreateGroupError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1625,7 +2045,12 @@ impl CreateSamplingRuleError {
                 "ThrottledException" => {
                     return RusotoError::Service(CreateSamplingRuleError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1665,7 +2090,12 @@ impl DeleteGroupError {
                 "ThrottledException" => {
                     return RusotoError::Service(DeleteGroupError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1704,7 +2134,12 @@ impl DeleteSamplingRuleError {
                 "ThrottledException" => {
                     return RusotoError::Service(DeleteSamplingRuleError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1743,7 +2178,12 @@ impl GetEncryptionConfigError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetEncryptionConfigError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1782,7 +2222,12 @@ impl GetGroupError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetGroupError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1821,7 +2266,12 @@ impl GetGroupsError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetGroupsError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1860,7 +2310,12 @@ impl GetSamplingRulesError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetSamplingRulesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1905,7 +2360,12 @@ impl GetSamplingStatisticSummariesError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1944,7 +2404,12 @@ impl GetSamplingTargetsError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetSamplingTargetsError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -1983,7 +2448,12 @@ impl GetServiceGraphError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetServiceGraphError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2028,7 +2498,12 @@ impl GetTimeSeriesServiceStatisticsError {
                         err.msg,
                     ))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2067,7 +2542,12 @@ impl GetTraceGraphError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetTraceGraphError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2106,7 +2586,12 @@ impl GetTraceSummariesError {
                 "ThrottledException" => {
                     return RusotoError::Service(GetTraceSummariesError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2145,7 +2630,12 @@ impl PutEncryptionConfigError {
                 "ThrottledException" => {
                     return RusotoError::Service(PutEncryptionConfigError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2184,7 +2674,12 @@ impl PutTelemetryRecordsError {
                 "ThrottledException" => {
                     return RusotoError::Service(PutTelemetryRecordsError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2223,7 +2718,12 @@ impl PutTraceSegmentsError {
                 "ThrottledException" => {
                     return RusotoError::Service(PutTraceSegmentsError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2262,7 +2762,12 @@ impl UpdateGroupError {
                 "ThrottledException" => {
                     return RusotoError::Service(UpdateGroupError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2301,7 +2806,12 @@ impl UpdateSamplingRuleError {
                 "ThrottledException" => {
                     return RusotoError::Service(UpdateSamplingRuleError::Throttled(err.msg))
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => {
+                    return RusotoError::Validation {
+                        message: err.msg,
+                        fields: err.fields,
+                    }
+                }
                 _ => {}
             }
         }
@@ -2436,189 +2946,584 @@ pub trait XRay {
         input: UpdateSamplingRuleRequest,
     ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError>;
 }
-/// A client for the AWS X-Ray API.
-#[derive(Clone)]
-pub struct XRayClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl XRayClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> XRayClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> XRayClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
-    }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> XRayClient {
-        XRayClient { client, region }
-    }
-}
-
-impl XRay for XRayClient {
-    /// <p>Retrieves a list of traces specified by ID. Each trace is a collection of segment documents that originates from a single request. Use <code>GetTraceSummaries</code> to get a list of trace IDs.</p>
+const _: Option<&dyn XRay> = None;
+impl<'a, T: XRay + ?Sized> XRay for &'a T {
     fn batch_get_traces(
         &self,
         input: BatchGetTracesRequest,
     ) -> RusotoFuture<BatchGetTracesResult, BatchGetTracesError> {
-        let request_uri = "/Traces";
-
-        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetTracesResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetTracesError::from_response(response))),
-                )
-            }
-        })
+        (**self).batch_get_traces(input)
     }
-
-    /// <p>Creates a group resource with a name and a filter expression. </p>
     fn create_group(
         &self,
         input: CreateGroupRequest,
     ) -> RusotoFuture<CreateGroupResult, CreateGroupError> {
-        let request_uri = "/CreateGroup";
-
-        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGroupResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_group(input)
     }
-
-    /// <p>Creates a rule to control sampling behavior for instrumented applications. Services retrieve rules with <a>GetSamplingRules</a>, and evaluate each rule in ascending order of <i>priority</i> for each request. If a rule matches, the service records a trace, borrowing it from the reservoir size. After 10 seconds, the service reports back to X-Ray with <a>GetSamplingTargets</a> to get updated versions of each in-use rule. The updated rule contains a trace quota that the service can use instead of borrowing from the reservoir.</p>
     fn create_sampling_rule(
         &self,
         input: CreateSamplingRuleRequest,
     ) -> RusotoFuture<CreateSamplingRuleResult, CreateSamplingRuleError> {
-        let request_uri = "/CreateSamplingRule";
-
-        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateSamplingRuleResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateSamplingRuleError::from_response(response))),
-                )
-            }
-        })
+        (**self).create_sampling_rule(input)
     }
-
-    /// <p>Deletes a group resource.</p>
     fn delete_group(
         &self,
         input: DeleteGroupRequest,
     ) -> RusotoFuture<DeleteGroupResult, DeleteGroupError> {
-        let request_uri = "/DeleteGroup";
-
-        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    let result = proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteGroupResult, _>()?;
-
-                    Ok(result)
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
-                )
-            }
-        })
+        (**self).delete_group(input)
     }
-
-    /// <p>Deletes a sampling rule.</p>
     fn delete_sampling_rule(
         &self,
         input: DeleteSamplingRuleRequest,
     ) -> RusotoFuture<DeleteSamplingRuleResult, DeleteSamplingRuleError> {
-        let request_uri = "/DeleteSamplingRule";
-
-        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        (**self).delete_sampling_rule(input)
+    }
+    fn get_encryption_config(
+        &self,
+    ) -> RusotoFuture<GetEncryptionConfigResult, GetEncryptionConfigError> {
+        (**self).get_encryption_config()
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResult, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_groups(&self, input: GetGroupsRequest) -> RusotoFuture<GetGroupsResult, GetGroupsError> {
+        (**self).get_groups(input)
+    }
+    fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> RusotoFuture<GetSamplingRulesResult, GetSamplingRulesError> {
+        (**self).get_sampling_rules(input)
+    }
+    fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> RusotoFuture<GetSamplingStatisticSummariesResult, GetSamplingStatisticSummariesError> {
+        (**self).get_sampling_statistic_summaries(input)
+    }
+    fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> RusotoFuture<GetSamplingTargetsResult, GetSamplingTargetsError> {
+        (**self).get_sampling_targets(input)
+    }
+    fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> RusotoFuture<GetServiceGraphResult, GetServiceGraphError> {
+        (**self).get_service_graph(input)
+    }
+    fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> RusotoFuture<GetTimeSeriesServiceStatisticsResult, GetTimeSeriesServiceStatisticsError>
+    {
+        (**self).get_time_series_service_statistics(input)
+    }
+    fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> RusotoFuture<GetTraceGraphResult, GetTraceGraphError> {
+        (**self).get_trace_graph(input)
+    }
+    fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> RusotoFuture<GetTraceSummariesResult, GetTraceSummariesError> {
+        (**self).get_trace_summaries(input)
+    }
+    fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> RusotoFuture<PutEncryptionConfigResult, PutEncryptionConfigError> {
+        (**self).put_encryption_config(input)
+    }
+    fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> RusotoFuture<PutTelemetryRecordsResult, PutTelemetryRecordsError> {
+        (**self).put_telemetry_records(input)
+    }
+    fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> RusotoFuture<PutTraceSegmentsResult, PutTraceSegmentsError> {
+        (**self).put_trace_segments(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResult, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError> {
+        (**self).update_sampling_rule(input)
+    }
+}
+impl<T: XRay + ?Sized> XRay for Box<T> {
+    fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> RusotoFuture<BatchGetTracesResult, BatchGetTracesError> {
+        (**self).batch_get_traces(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResult, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> RusotoFuture<CreateSamplingRuleResult, CreateSamplingRuleError> {
+        (**self).create_sampling_rule(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResult, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> RusotoFuture<DeleteSamplingRuleResult, DeleteSamplingRuleError> {
+        (**self).delete_sampling_rule(input)
+    }
+    fn get_encryption_config(
+        &self,
+    ) -> RusotoFuture<GetEncryptionConfigResult, GetEncryptionConfigError> {
+        (**self).get_encryption_config()
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResult, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_groups(&self, input: GetGroupsRequest) -> RusotoFuture<GetGroupsResult, GetGroupsError> {
+        (**self).get_groups(input)
+    }
+    fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> RusotoFuture<GetSamplingRulesResult, GetSamplingRulesError> {
+        (**self).get_sampling_rules(input)
+    }
+    fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> RusotoFuture<GetSamplingStatisticSummariesResult, GetSamplingStatisticSummariesError> {
+        (**self).get_sampling_statistic_summaries(input)
+    }
+    fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> RusotoFuture<GetSamplingTargetsResult, GetSamplingTargetsError> {
+        (**self).get_sampling_targets(input)
+    }
+    fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> RusotoFuture<GetServiceGraphResult, GetServiceGraphError> {
+        (**self).get_service_graph(input)
+    }
+    fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> RusotoFuture<GetTimeSeriesServiceStatisticsResult, GetTimeSeriesServiceStatisticsError>
+    {
+        (**self).get_time_series_service_statistics(input)
+    }
+    fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> RusotoFuture<GetTraceGraphResult, GetTraceGraphError> {
+        (**self).get_trace_graph(input)
+    }
+    fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> RusotoFuture<GetTraceSummariesResult, GetTraceSummariesError> {
+        (**self).get_trace_summaries(input)
+    }
+    fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> RusotoFuture<PutEncryptionConfigResult, PutEncryptionConfigError> {
+        (**self).put_encryption_config(input)
+    }
+    fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> RusotoFuture<PutTelemetryRecordsResult, PutTelemetryRecordsError> {
+        (**self).put_telemetry_records(input)
+    }
+    fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> RusotoFuture<PutTraceSegmentsResult, PutTraceSegmentsError> {
+        (**self).put_trace_segments(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResult, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError> {
+        (**self).update_sampling_rule(input)
+    }
+}
+impl<T: XRay + ?Sized> XRay for ::std::sync::Arc<T> {
+    fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> RusotoFuture<BatchGetTracesResult, BatchGetTracesError> {
+        (**self).batch_get_traces(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResult, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> RusotoFuture<CreateSamplingRuleResult, CreateSamplingRuleError> {
+        (**self).create_sampling_rule(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResult, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> RusotoFuture<DeleteSamplingRuleResult, DeleteSamplingRuleError> {
+        (**self).delete_sampling_rule(input)
+    }
+    fn get_encryption_config(
+        &self,
+    ) -> RusotoFuture<GetEncryptionConfigResult, GetEncryptionConfigError> {
+        (**self).get_encryption_config()
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResult, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_groups(&self, input: GetGroupsRequest) -> RusotoFuture<GetGroupsResult, GetGroupsError> {
+        (**self).get_groups(input)
+    }
+    fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> RusotoFuture<GetSamplingRulesResult, GetSamplingRulesError> {
+        (**self).get_sampling_rules(input)
+    }
+    fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> RusotoFuture<GetSamplingStatisticSummariesResult, GetSamplingStatisticSummariesError> {
+        (**self).get_sampling_statistic_summaries(input)
+    }
+    fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> RusotoFuture<GetSamplingTargetsResult, GetSamplingTargetsError> {
+        (**self).get_sampling_targets(input)
+    }
+    fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> RusotoFuture<GetServiceGraphResult, GetServiceGraphError> {
+        (**self).get_service_graph(input)
+    }
+    fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> RusotoFuture<GetTimeSeriesServiceStatisticsResult, GetTimeSeriesServiceStatisticsError>
+    {
+        (**self).get_time_series_service_statistics(input)
+    }
+    fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> RusotoFuture<GetTraceGraphResult, GetTraceGraphError> {
+        (**self).get_trace_graph(input)
+    }
+    fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> RusotoFuture<GetTraceSummariesResult, GetTraceSummariesError> {
+        (**self).get_trace_summaries(input)
+    }
+    fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> RusotoFuture<PutEncryptionConfigResult, PutEncryptionConfigError> {
+        (**self).put_encryption_config(input)
+    }
+    fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> RusotoFuture<PutTelemetryRecordsResult, PutTelemetryRecordsError> {
+        (**self).put_telemetry_records(input)
+    }
+    fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> RusotoFuture<PutTraceSegmentsResult, PutTraceSegmentsError> {
+        (**self).put_trace_segments(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResult, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError> {
+        (**self).update_sampling_rule(input)
+    }
+}
+/// A client for the AWS X-Ray API.
+#[derive(Clone)]
+pub struct XRayClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl XRayClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> XRayClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> XRayClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> XRayClient {
+        XRayClient { client, region }
+    }
+}
+
+impl XRay for XRayClient {
+    /// <p>Retrieves a list of traces specified by ID. Each trace is a collection of segment documents that originates from a single request. Use <code>GetTraceSummaries</code> to get a list of trace IDs.</p>
+    fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> RusotoFuture<BatchGetTracesResult, BatchGetTracesError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/Traces";
+
+        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("BatchGetTraces");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("BatchGetTraces")
+                        .deserialize::<BatchGetTracesResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(BatchGetTracesError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a group resource with a name and a filter expression. </p>
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResult, CreateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/CreateGroup";
+
+        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateGroup")
+                        .deserialize::<CreateGroupResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Creates a rule to control sampling behavior for instrumented applications. Services retrieve rules with <a>GetSamplingRules</a>, and evaluate each rule in ascending order of <i>priority</i> for each request. If a rule matches, the service records a trace, borrowing it from the reservoir size. After 10 seconds, the service reports back to X-Ray with <a>GetSamplingTargets</a> to get updated versions of each in-use rule. The updated rule contains a trace quota that the service can use instead of borrowing from the reservoir.</p>
+    fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> RusotoFuture<CreateSamplingRuleResult, CreateSamplingRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/CreateSamplingRule";
+
+        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("CreateSamplingRule");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("CreateSamplingRule")
+                        .deserialize::<CreateSamplingRuleResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(CreateSamplingRuleError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a group resource.</p>
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResult, DeleteGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
+        let request_uri = "/DeleteGroup";
+
+        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        self.client.sign_and_dispatch(request, |response| {
+            if response.status.is_success() {
+                Box::new(response.buffer().from_err().and_then(|response| {
+                    let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteGroup")
+                        .deserialize::<DeleteGroupResult, _>()?;
+
+                    Ok(result)
+                }))
+            } else {
+                Box::new(
+                    response
+                        .buffer()
+                        .from_err()
+                        .and_then(|response| Err(DeleteGroupError::from_response(response))),
+                )
+            }
+        })
+    }
+
+    /// <p>Deletes a sampling rule.</p>
+    fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> RusotoFuture<DeleteSamplingRuleResult, DeleteSamplingRuleError> {
+        let request_uri = "/DeleteSamplingRule";
+
+        let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("DeleteSamplingRule");
+        request.set_accept("application/json".to_owned());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("DeleteSamplingRule")
                         .deserialize::<DeleteSamplingRuleResult, _>()?;
 
                     Ok(result)
@@ -2641,12 +3546,15 @@ impl XRay for XRayClient {
         let request_uri = "/EncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetEncryptionConfig");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         self.client.sign_and_dispatch(request, |response| {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetEncryptionConfig")
                         .deserialize::<GetEncryptionConfigResult, _>()?;
 
                     Ok(result)
@@ -2663,9 +3571,19 @@ impl XRay for XRayClient {
 
     /// <p>Retrieves group resource details.</p>
     fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResult, GetGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/GetGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetGroup");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2675,6 +3593,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroup")
                         .deserialize::<GetGroupResult, _>()?;
 
                     Ok(result)
@@ -2692,9 +3611,19 @@ impl XRay for XRayClient {
 
     /// <p>Retrieves all active group details.</p>
     fn get_groups(&self, input: GetGroupsRequest) -> RusotoFuture<GetGroupsResult, GetGroupsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/Groups";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetGroups");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2704,6 +3633,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetGroups")
                         .deserialize::<GetGroupsResult, _>()?;
 
                     Ok(result)
@@ -2727,6 +3657,8 @@ impl XRay for XRayClient {
         let request_uri = "/GetSamplingRules";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingRules");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2736,6 +3668,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSamplingRules")
                         .deserialize::<GetSamplingRulesResult, _>()?;
 
                     Ok(result)
@@ -2759,6 +3692,8 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingStatisticSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingStatisticSummaries");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2768,6 +3703,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSamplingStatisticSummaries")
                         .deserialize::<GetSamplingStatisticSummariesResult, _>()?;
 
                     Ok(result)
@@ -2785,9 +3721,19 @@ impl XRay for XRayClient {
         &self,
         input: GetSamplingTargetsRequest,
     ) -> RusotoFuture<GetSamplingTargetsResult, GetSamplingTargetsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/SamplingTargets";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetSamplingTargets");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2797,6 +3743,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetSamplingTargets")
                         .deserialize::<GetSamplingTargetsResult, _>()?;
 
                     Ok(result)
@@ -2817,9 +3764,19 @@ impl XRay for XRayClient {
         &self,
         input: GetServiceGraphRequest,
     ) -> RusotoFuture<GetServiceGraphResult, GetServiceGraphError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/ServiceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetServiceGraph");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2829,6 +3786,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetServiceGraph")
                         .deserialize::<GetServiceGraphResult, _>()?;
 
                     Ok(result)
@@ -2850,9 +3808,19 @@ impl XRay for XRayClient {
         input: GetTimeSeriesServiceStatisticsRequest,
     ) -> RusotoFuture<GetTimeSeriesServiceStatisticsResult, GetTimeSeriesServiceStatisticsError>
     {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/TimeSeriesServiceStatistics";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTimeSeriesServiceStatistics");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2862,6 +3830,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTimeSeriesServiceStatistics")
                         .deserialize::<GetTimeSeriesServiceStatisticsResult, _>()?;
 
                     Ok(result)
@@ -2879,9 +3848,19 @@ impl XRay for XRayClient {
         &self,
         input: GetTraceGraphRequest,
     ) -> RusotoFuture<GetTraceGraphResult, GetTraceGraphError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/TraceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTraceGraph");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2891,6 +3870,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTraceGraph")
                         .deserialize::<GetTraceGraphResult, _>()?;
 
                     Ok(result)
@@ -2914,6 +3894,8 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("GetTraceSummaries");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2923,6 +3905,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("GetTraceSummaries")
                         .deserialize::<GetTraceSummariesResult, _>()?;
 
                     Ok(result)
@@ -2943,9 +3926,19 @@ impl XRay for XRayClient {
         &self,
         input: PutEncryptionConfigRequest,
     ) -> RusotoFuture<PutEncryptionConfigResult, PutEncryptionConfigError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/PutEncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutEncryptionConfig");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2955,6 +3948,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutEncryptionConfig")
                         .deserialize::<PutEncryptionConfigResult, _>()?;
 
                     Ok(result)
@@ -2974,9 +3968,18 @@ impl XRay for XRayClient {
         &self,
         input: PutTelemetryRecordsRequest,
     ) -> RusotoFuture<PutTelemetryRecordsResult, PutTelemetryRecordsError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/TelemetryRecords";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutTelemetryRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2986,6 +3989,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutTelemetryRecords")
                         .deserialize::<PutTelemetryRecordsResult, _>()?;
 
                     Ok(result)
@@ -3008,6 +4012,8 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSegments";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("PutTraceSegments");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3017,6 +4023,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("PutTraceSegments")
                         .deserialize::<PutTraceSegmentsResult, _>()?;
 
                     Ok(result)
@@ -3037,9 +4044,19 @@ impl XRay for XRayClient {
         &self,
         input: UpdateGroupRequest,
     ) -> RusotoFuture<UpdateGroupResult, UpdateGroupError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/UpdateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3049,6 +4066,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateGroup")
                         .deserialize::<UpdateGroupResult, _>()?;
 
                     Ok(result)
@@ -3069,9 +4087,19 @@ impl XRay for XRayClient {
         &self,
         input: UpdateSamplingRuleRequest,
     ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError> {
+        let validation = if self.client.validates_requests() {
+            input.validate()
+        } else {
+            input.validate_lengths()
+        };
+        if let Err(errors) = validation {
+            return RusotoFuture::from(Err(RusotoError::from(errors)));
+        }
         let request_uri = "/UpdateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation("UpdateSamplingRule");
+        request.set_accept("application/json".to_owned());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3081,6 +4109,7 @@ impl XRay for XRayClient {
             if response.status.is_success() {
                 Box::new(response.buffer().from_err().and_then(|response| {
                     let result = proto::json::ResponsePayload::new(&response)
+                        .with_operation("UpdateSamplingRule")
                         .deserialize::<UpdateSamplingRuleResult, _>()?;
 
                     Ok(result)
@@ -3096,3 +4125,694 @@ impl XRay for XRayClient {
         })
     }
 }
+
+/// A mock of the AWS X-Ray API for tests, with the `mocks` feature, whose methods
+/// answer with what the handler given for their operation returns.
+///
+/// Methods of operations without a handler panic.
+#[cfg(any(test, feature = "mocks"))]
+#[derive(Default)]
+pub struct MockXRay {
+    batch_get_traces: Option<
+        Box<
+            dyn Fn(
+                    BatchGetTracesRequest,
+                )
+                    -> ::std::result::Result<BatchGetTracesResult, RusotoError<BatchGetTracesError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_group: Option<
+        Box<
+            dyn Fn(
+                    CreateGroupRequest,
+                )
+                    -> ::std::result::Result<CreateGroupResult, RusotoError<CreateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    create_sampling_rule: Option<
+        Box<
+            dyn Fn(
+                    CreateSamplingRuleRequest,
+                ) -> ::std::result::Result<
+                    CreateSamplingRuleResult,
+                    RusotoError<CreateSamplingRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    delete_group: Option<
+        Box<
+            dyn Fn(
+                    DeleteGroupRequest,
+                )
+                    -> ::std::result::Result<DeleteGroupResult, RusotoError<DeleteGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    delete_sampling_rule: Option<
+        Box<
+            dyn Fn(
+                    DeleteSamplingRuleRequest,
+                ) -> ::std::result::Result<
+                    DeleteSamplingRuleResult,
+                    RusotoError<DeleteSamplingRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_encryption_config: Option<
+        Box<
+            dyn Fn() -> ::std::result::Result<
+                    GetEncryptionConfigResult,
+                    RusotoError<GetEncryptionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_group: Option<
+        Box<
+            dyn Fn(
+                    GetGroupRequest,
+                )
+                    -> ::std::result::Result<GetGroupResult, RusotoError<GetGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_groups: Option<
+        Box<
+            dyn Fn(
+                    GetGroupsRequest,
+                )
+                    -> ::std::result::Result<GetGroupsResult, RusotoError<GetGroupsError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_sampling_rules: Option<
+        Box<
+            dyn Fn(
+                    GetSamplingRulesRequest,
+                ) -> ::std::result::Result<
+                    GetSamplingRulesResult,
+                    RusotoError<GetSamplingRulesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_sampling_statistic_summaries: Option<
+        Box<
+            dyn Fn(
+                    GetSamplingStatisticSummariesRequest,
+                ) -> ::std::result::Result<
+                    GetSamplingStatisticSummariesResult,
+                    RusotoError<GetSamplingStatisticSummariesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_sampling_targets: Option<
+        Box<
+            dyn Fn(
+                    GetSamplingTargetsRequest,
+                ) -> ::std::result::Result<
+                    GetSamplingTargetsResult,
+                    RusotoError<GetSamplingTargetsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_service_graph: Option<
+        Box<
+            dyn Fn(
+                    GetServiceGraphRequest,
+                ) -> ::std::result::Result<
+                    GetServiceGraphResult,
+                    RusotoError<GetServiceGraphError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_time_series_service_statistics: Option<
+        Box<
+            dyn Fn(
+                    GetTimeSeriesServiceStatisticsRequest,
+                ) -> ::std::result::Result<
+                    GetTimeSeriesServiceStatisticsResult,
+                    RusotoError<GetTimeSeriesServiceStatisticsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    get_trace_graph: Option<
+        Box<
+            dyn Fn(
+                    GetTraceGraphRequest,
+                )
+                    -> ::std::result::Result<GetTraceGraphResult, RusotoError<GetTraceGraphError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    get_trace_summaries: Option<
+        Box<
+            dyn Fn(
+                    GetTraceSummariesRequest,
+                ) -> ::std::result::Result<
+                    GetTraceSummariesResult,
+                    RusotoError<GetTraceSummariesError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_encryption_config: Option<
+        Box<
+            dyn Fn(
+                    PutEncryptionConfigRequest,
+                ) -> ::std::result::Result<
+                    PutEncryptionConfigResult,
+                    RusotoError<PutEncryptionConfigError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_telemetry_records: Option<
+        Box<
+            dyn Fn(
+                    PutTelemetryRecordsRequest,
+                ) -> ::std::result::Result<
+                    PutTelemetryRecordsResult,
+                    RusotoError<PutTelemetryRecordsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    put_trace_segments: Option<
+        Box<
+            dyn Fn(
+                    PutTraceSegmentsRequest,
+                ) -> ::std::result::Result<
+                    PutTraceSegmentsResult,
+                    RusotoError<PutTraceSegmentsError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+    update_group: Option<
+        Box<
+            dyn Fn(
+                    UpdateGroupRequest,
+                )
+                    -> ::std::result::Result<UpdateGroupResult, RusotoError<UpdateGroupError>>
+                + Send
+                + Sync,
+        >,
+    >,
+    update_sampling_rule: Option<
+        Box<
+            dyn Fn(
+                    UpdateSamplingRuleRequest,
+                ) -> ::std::result::Result<
+                    UpdateSamplingRuleResult,
+                    RusotoError<UpdateSamplingRuleError>,
+                > + Send
+                + Sync,
+        >,
+    >,
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl MockXRay {
+    /// Creates a mock without handlers.
+    pub fn new() -> MockXRay {
+        Default::default()
+    }
+
+    /// Answers `batch_get_traces` with what `handler` returns for its input.
+    pub fn with_batch_get_traces<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                BatchGetTracesRequest,
+            )
+                -> ::std::result::Result<BatchGetTracesResult, RusotoError<BatchGetTracesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.batch_get_traces = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_group` with what `handler` returns for its input.
+    pub fn with_create_group<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                CreateGroupRequest,
+            )
+                -> ::std::result::Result<CreateGroupResult, RusotoError<CreateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.create_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `create_sampling_rule` with what `handler` returns for its input.
+    pub fn with_create_sampling_rule<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                CreateSamplingRuleRequest,
+            ) -> ::std::result::Result<
+                CreateSamplingRuleResult,
+                RusotoError<CreateSamplingRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.create_sampling_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_group` with what `handler` returns for its input.
+    pub fn with_delete_group<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                DeleteGroupRequest,
+            )
+                -> ::std::result::Result<DeleteGroupResult, RusotoError<DeleteGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `delete_sampling_rule` with what `handler` returns for its input.
+    pub fn with_delete_sampling_rule<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                DeleteSamplingRuleRequest,
+            ) -> ::std::result::Result<
+                DeleteSamplingRuleResult,
+                RusotoError<DeleteSamplingRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.delete_sampling_rule = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_encryption_config` with what `handler` returns.
+    pub fn with_get_encryption_config<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn() -> ::std::result::Result<
+                GetEncryptionConfigResult,
+                RusotoError<GetEncryptionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_encryption_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_group` with what `handler` returns for its input.
+    pub fn with_get_group<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(GetGroupRequest) -> ::std::result::Result<GetGroupResult, RusotoError<GetGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_groups` with what `handler` returns for its input.
+    pub fn with_get_groups<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetGroupsRequest,
+            ) -> ::std::result::Result<GetGroupsResult, RusotoError<GetGroupsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_groups = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sampling_rules` with what `handler` returns for its input.
+    pub fn with_get_sampling_rules<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetSamplingRulesRequest,
+            )
+                -> ::std::result::Result<GetSamplingRulesResult, RusotoError<GetSamplingRulesError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sampling_rules = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sampling_statistic_summaries` with what `handler` returns for its input.
+    pub fn with_get_sampling_statistic_summaries<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetSamplingStatisticSummariesRequest,
+            ) -> ::std::result::Result<
+                GetSamplingStatisticSummariesResult,
+                RusotoError<GetSamplingStatisticSummariesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sampling_statistic_summaries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_sampling_targets` with what `handler` returns for its input.
+    pub fn with_get_sampling_targets<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetSamplingTargetsRequest,
+            ) -> ::std::result::Result<
+                GetSamplingTargetsResult,
+                RusotoError<GetSamplingTargetsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_sampling_targets = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_service_graph` with what `handler` returns for its input.
+    pub fn with_get_service_graph<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetServiceGraphRequest,
+            )
+                -> ::std::result::Result<GetServiceGraphResult, RusotoError<GetServiceGraphError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_service_graph = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_time_series_service_statistics` with what `handler` returns for its input.
+    pub fn with_get_time_series_service_statistics<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetTimeSeriesServiceStatisticsRequest,
+            ) -> ::std::result::Result<
+                GetTimeSeriesServiceStatisticsResult,
+                RusotoError<GetTimeSeriesServiceStatisticsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_time_series_service_statistics = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_trace_graph` with what `handler` returns for its input.
+    pub fn with_get_trace_graph<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetTraceGraphRequest,
+            )
+                -> ::std::result::Result<GetTraceGraphResult, RusotoError<GetTraceGraphError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.get_trace_graph = Some(Box::new(handler));
+        self
+    }
+    /// Answers `get_trace_summaries` with what `handler` returns for its input.
+    pub fn with_get_trace_summaries<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                GetTraceSummariesRequest,
+            ) -> ::std::result::Result<
+                GetTraceSummariesResult,
+                RusotoError<GetTraceSummariesError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.get_trace_summaries = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_encryption_config` with what `handler` returns for its input.
+    pub fn with_put_encryption_config<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                PutEncryptionConfigRequest,
+            ) -> ::std::result::Result<
+                PutEncryptionConfigResult,
+                RusotoError<PutEncryptionConfigError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_encryption_config = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_telemetry_records` with what `handler` returns for its input.
+    pub fn with_put_telemetry_records<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                PutTelemetryRecordsRequest,
+            ) -> ::std::result::Result<
+                PutTelemetryRecordsResult,
+                RusotoError<PutTelemetryRecordsError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.put_telemetry_records = Some(Box::new(handler));
+        self
+    }
+    /// Answers `put_trace_segments` with what `handler` returns for its input.
+    pub fn with_put_trace_segments<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                PutTraceSegmentsRequest,
+            )
+                -> ::std::result::Result<PutTraceSegmentsResult, RusotoError<PutTraceSegmentsError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.put_trace_segments = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_group` with what `handler` returns for its input.
+    pub fn with_update_group<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                UpdateGroupRequest,
+            )
+                -> ::std::result::Result<UpdateGroupResult, RusotoError<UpdateGroupError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.update_group = Some(Box::new(handler));
+        self
+    }
+    /// Answers `update_sampling_rule` with what `handler` returns for its input.
+    pub fn with_update_sampling_rule<F>(mut self, handler: F) -> MockXRay
+    where
+        F: Fn(
+                UpdateSamplingRuleRequest,
+            ) -> ::std::result::Result<
+                UpdateSamplingRuleResult,
+                RusotoError<UpdateSamplingRuleError>,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        self.update_sampling_rule = Some(Box::new(handler));
+        self
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl XRay for MockXRay {
+    fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> RusotoFuture<BatchGetTracesResult, BatchGetTracesError> {
+        match self.batch_get_traces {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for batch_get_traces"),
+        }
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResult, CreateGroupError> {
+        match self.create_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for create_group"),
+        }
+    }
+    fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> RusotoFuture<CreateSamplingRuleResult, CreateSamplingRuleError> {
+        match self.create_sampling_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for create_sampling_rule"),
+        }
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResult, DeleteGroupError> {
+        match self.delete_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for delete_group"),
+        }
+    }
+    fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> RusotoFuture<DeleteSamplingRuleResult, DeleteSamplingRuleError> {
+        match self.delete_sampling_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for delete_sampling_rule"),
+        }
+    }
+    fn get_encryption_config(
+        &self,
+    ) -> RusotoFuture<GetEncryptionConfigResult, GetEncryptionConfigError> {
+        match self.get_encryption_config {
+            Some(ref handler) => handler().into(),
+            None => panic!("MockXRay has no handler for get_encryption_config"),
+        }
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResult, GetGroupError> {
+        match self.get_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_group"),
+        }
+    }
+    fn get_groups(&self, input: GetGroupsRequest) -> RusotoFuture<GetGroupsResult, GetGroupsError> {
+        match self.get_groups {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_groups"),
+        }
+    }
+    fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> RusotoFuture<GetSamplingRulesResult, GetSamplingRulesError> {
+        match self.get_sampling_rules {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_sampling_rules"),
+        }
+    }
+    fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> RusotoFuture<GetSamplingStatisticSummariesResult, GetSamplingStatisticSummariesError> {
+        match self.get_sampling_statistic_summaries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_sampling_statistic_summaries"),
+        }
+    }
+    fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> RusotoFuture<GetSamplingTargetsResult, GetSamplingTargetsError> {
+        match self.get_sampling_targets {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_sampling_targets"),
+        }
+    }
+    fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> RusotoFuture<GetServiceGraphResult, GetServiceGraphError> {
+        match self.get_service_graph {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_service_graph"),
+        }
+    }
+    fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> RusotoFuture<GetTimeSeriesServiceStatisticsResult, GetTimeSeriesServiceStatisticsError>
+    {
+        match self.get_time_series_service_statistics {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_time_series_service_statistics"),
+        }
+    }
+    fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> RusotoFuture<GetTraceGraphResult, GetTraceGraphError> {
+        match self.get_trace_graph {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_trace_graph"),
+        }
+    }
+    fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> RusotoFuture<GetTraceSummariesResult, GetTraceSummariesError> {
+        match self.get_trace_summaries {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for get_trace_summaries"),
+        }
+    }
+    fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> RusotoFuture<PutEncryptionConfigResult, PutEncryptionConfigError> {
+        match self.put_encryption_config {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for put_encryption_config"),
+        }
+    }
+    fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> RusotoFuture<PutTelemetryRecordsResult, PutTelemetryRecordsError> {
+        match self.put_telemetry_records {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for put_telemetry_records"),
+        }
+    }
+    fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> RusotoFuture<PutTraceSegmentsResult, PutTraceSegmentsError> {
+        match self.put_trace_segments {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for put_trace_segments"),
+        }
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResult, UpdateGroupError> {
+        match self.update_group {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for update_group"),
+        }
+    }
+    fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> RusotoFuture<UpdateSamplingRuleResult, UpdateSamplingRuleError> {
+        match self.update_sampling_rule {
+            Some(ref handler) => handler(input).into(),
+            None => panic!("MockXRay has no handler for update_sampling_rule"),
+        }
+    }
+}
@@ -28,8 +28,15 @@ pub struct ServiceDefinition {
 
 impl ServiceDefinition {
     pub fn load(name: &str, protocol_version: &str) -> Result<Self, Box<dyn error::Error>> {
-        let input_path =
-            Path::new(BOTOCORE_DIR).join(format!("{}/{}/service-2.json", name, protocol_version));
+        Self::load_from(Path::new(BOTOCORE_DIR), name, protocol_version)
+    }
+
+    pub fn load_from(
+        botocore_dir: &Path,
+        name: &str,
+        protocol_version: &str,
+    ) -> Result<Self, Box<dyn error::Error>> {
+        let input_path = botocore_dir.join(format!("{}/{}/service-2.json", name, protocol_version));
 
         let input_file = BufReader::new(File::open(&input_path)?);
 
@@ -39,7 +46,13 @@ impl ServiceDefinition {
     }
 
     pub fn load_all() -> Result<BTreeMap<String, Self>, Box<dyn error::Error>> {
-        fs::read_dir(BOTOCORE_DIR)?
+        Self::load_all_from(Path::new(BOTOCORE_DIR))
+    }
+
+    pub fn load_all_from(
+        botocore_dir: &Path,
+    ) -> Result<BTreeMap<String, Self>, Box<dyn error::Error>> {
+        fs::read_dir(botocore_dir)?
             .filter_map(std::result::Result::ok)
             .map(|e| e.path())
             .filter(|p| p.is_dir())
@@ -203,6 +216,7 @@ pub struct Shape {
     pub aws_box: Option<bool>,
     pub documentation: Option<String>,
     pub error: Option<HttpError>,
+    pub eventstream: Option<bool>,
     pub exception: Option<bool>,
     pub fault: Option<bool>,
     pub flattened: Option<bool>,
@@ -272,6 +286,10 @@ impl<'a> Shape {
     pub fn exception(&self) -> bool {
         self.exception.unwrap_or(false)
     }
+
+    pub fn eventstream(&self) -> bool {
+        self.eventstream.unwrap_or(false)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
@@ -310,6 +328,10 @@ pub struct Operation {
     pub documentation_url: Option<String>,
     pub errors: Option<BTreeSet<Error>>,
     pub http: HttpRequest,
+    #[serde(rename = "httpChecksum")]
+    pub http_checksum: Option<serde_json::Value>,
+    #[serde(rename = "httpChecksumRequired")]
+    pub http_checksum_required: Option<bool>,
     pub input: Option<Input>,
     pub name: String,
     pub output: Option<Output>,
@@ -343,6 +365,10 @@ impl<'a> Operation {
     pub fn errors(&'a self) -> &BTreeSet<Error> {
         self.errors.as_ref().unwrap()
     }
+
+    pub fn http_checksum(&self) -> bool {
+        self.http_checksum.is_some() || self.http_checksum_required.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize)]
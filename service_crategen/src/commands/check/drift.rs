@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_derive::Serialize;
+
+use crate::botocore::{Operation, ServiceDefinition, Shape};
+
+/// What a newer definition of a service has that the crate generated from an older one lacks,
+/// and the operations of the newer definition rusoto can't generate yet.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Drift {
+    pub new_operations: Vec<String>,
+    pub removed_operations: Vec<String>,
+    pub new_shapes: Vec<String>,
+    /// `Shape.Member` for each member added to a shape of both definitions.
+    pub new_members: Vec<String>,
+    /// `Shape.Member` for each member removed from a shape of both definitions.
+    pub removed_members: Vec<String>,
+    /// `Operation: Error` for each error an operation of the newer definition may return
+    /// that it couldn't before.
+    pub new_error_codes: Vec<String>,
+    /// `Operation: feature` for each protocol feature rusoto can't generate.
+    pub unsupported: Vec<String>,
+}
+
+impl Drift {
+    pub fn between(generated: &ServiceDefinition, current: &ServiceDefinition) -> Drift {
+        let mut drift = Drift::default();
+
+        for name in current.operations.keys() {
+            if !generated.operations.contains_key(name) {
+                drift.new_operations.push(name.clone());
+            }
+        }
+        for name in generated.operations.keys() {
+            if !current.operations.contains_key(name) {
+                drift.removed_operations.push(name.clone());
+            }
+        }
+
+        for (name, shape) in &current.shapes {
+            match generated.shapes.get(name) {
+                None => drift.new_shapes.push(name.clone()),
+                Some(generated_shape) => {
+                    let members = member_names(shape);
+                    let generated_members = member_names(generated_shape);
+                    for member in members.difference(&generated_members) {
+                        drift.new_members.push(format!("{}.{}", name, member));
+                    }
+                    for member in generated_members.difference(&members) {
+                        drift.removed_members.push(format!("{}.{}", name, member));
+                    }
+                }
+            }
+        }
+
+        for (name, operation) in &current.operations {
+            let generated_errors = generated
+                .operations
+                .get(name)
+                .map(error_names)
+                .unwrap_or_default();
+            for error in error_names(operation).difference(&generated_errors) {
+                drift.new_error_codes.push(format!("{}: {}", name, error));
+            }
+            for feature in unsupported_features(operation, &current.shapes) {
+                drift.unsupported.push(format!("{}: {}", name, feature));
+            }
+        }
+
+        drift
+    }
+
+    /// Whether the crate is missing anything of the newer definition. Unsupported features
+    /// alone don't count, since regenerating the crate wouldn't pick them up.
+    pub fn is_drifted(&self) -> bool {
+        !(self.new_operations.is_empty()
+            && self.removed_operations.is_empty()
+            && self.new_shapes.is_empty()
+            && self.new_members.is_empty()
+            && self.removed_members.is_empty()
+            && self.new_error_codes.is_empty())
+    }
+
+    /// One line per kind of change, for people to read.
+    pub fn summary(&self) -> Vec<String> {
+        let mut lines = vec![];
+        let mut describe = |label: &str, items: &[String]| {
+            if !items.is_empty() {
+                lines.push(format!("{} {}: {}", items.len(), label, items.join(", ")));
+            }
+        };
+        describe("new operations", &self.new_operations);
+        describe("removed operations", &self.removed_operations);
+        describe("new shapes", &self.new_shapes);
+        describe("new members", &self.new_members);
+        describe("removed members", &self.removed_members);
+        describe("new error codes", &self.new_error_codes);
+        describe("unsupported features", &self.unsupported);
+        lines
+    }
+}
+
+fn member_names(shape: &Shape) -> BTreeSet<String> {
+    shape
+        .members
+        .as_ref()
+        .map(|members| members.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn error_names(operation: &Operation) -> BTreeSet<String> {
+    operation
+        .errors
+        .as_ref()
+        .map(|errors| errors.iter().map(|error| error.shape.clone()).collect())
+        .unwrap_or_default()
+}
+
+fn unsupported_features(
+    operation: &Operation,
+    shapes: &BTreeMap<String, Shape>,
+) -> Vec<&'static str> {
+    let mut features = vec![];
+
+    // event streams are members of the input or output shape
+    let streams_events = operation
+        .input
+        .iter()
+        .map(|input| &input.shape)
+        .chain(operation.output.iter().map(|output| &output.shape))
+        .filter_map(|name| shapes.get(name))
+        .filter_map(|shape| shape.members.as_ref())
+        .flat_map(|members| members.values())
+        .any(|member| shapes.get(&member.shape).map_or(false, Shape::eventstream));
+    if streams_events {
+        features.push("eventstream");
+    }
+    if operation.http_checksum() {
+        features.push("httpChecksum");
+    }
+
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::Drift;
+    use crate::botocore::ServiceDefinition;
+
+    fn fixture(json: &str) -> ServiceDefinition {
+        serde_json::from_str(json).expect("fixture should be a valid service definition")
+    }
+
+    #[test]
+    fn reports_what_the_current_definition_adds_and_removes() {
+        let generated = fixture(include_str!("fixtures/generated-service-2.json"));
+        let current = fixture(include_str!("fixtures/current-service-2.json"));
+
+        let drift = Drift::between(&generated, &current);
+
+        assert_eq!(
+            drift,
+            Drift {
+                new_operations: vec!["StreamWidgets".to_owned(), "UploadWidget".to_owned()],
+                removed_operations: vec!["DescribeWidget".to_owned()],
+                new_shapes: vec![
+                    "StreamWidgetsRequest".to_owned(),
+                    "StreamWidgetsResponse".to_owned(),
+                    "UploadWidgetRequest".to_owned(),
+                    "WidgetEvent".to_owned(),
+                    "WidgetEventStream".to_owned(),
+                    "WidgetLimitExceededException".to_owned(),
+                ],
+                new_members: vec!["CreateWidgetRequest.Tags".to_owned()],
+                removed_members: vec!["CreateWidgetRequest.Color".to_owned()],
+                new_error_codes: vec![
+                    "CreateWidget: WidgetLimitExceededException".to_owned(),
+                    "StreamWidgets: WidgetNotFoundException".to_owned(),
+                    "UploadWidget: WidgetLimitExceededException".to_owned(),
+                ],
+                unsupported: vec![
+                    "StreamWidgets: eventstream".to_owned(),
+                    "UploadWidget: httpChecksum".to_owned(),
+                ],
+            }
+        );
+        assert!(drift.is_drifted());
+        assert_eq!(
+            drift.summary()[0],
+            "2 new operations: StreamWidgets, UploadWidget"
+        );
+    }
+
+    #[test]
+    fn reports_no_drift_against_the_same_definition() {
+        let generated = fixture(include_str!("fixtures/generated-service-2.json"));
+        let current = fixture(include_str!("fixtures/generated-service-2.json"));
+
+        let drift = Drift::between(&generated, &current);
+
+        assert_eq!(drift, Drift::default());
+        assert!(!drift.is_drifted());
+        assert!(drift.summary().is_empty());
+    }
+}
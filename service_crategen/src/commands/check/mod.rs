@@ -1,46 +1,117 @@
 use std::collections::BTreeMap;
+use std::path::Path;
+use std::process;
+
+use serde_derive::Serialize;
+use serde_json;
 
 use crate::{ServiceConfig, ServiceDefinition};
 
-pub fn check(services: BTreeMap<String, ServiceConfig>) {
-    let definitions = ServiceDefinition::load_all().unwrap();
+mod drift;
+
+use self::drift::Drift;
+
+#[derive(Debug, Default, Serialize)]
+struct Report {
+    /// Services botocore defines which have no crate, with their protocol and latest API
+    /// version.
+    missing: BTreeMap<String, (String, String)>,
+    /// Services whose crate was generated from an older API version, with that version and
+    /// the latest one.
+    outdated: BTreeMap<String, (String, String)>,
+    /// What the latest definition of each service changes compared to its crate.
+    drift: BTreeMap<String, Drift>,
+}
+
+/// Compares the crates generated from the botocore submodule with the latest definitions
+/// in `botocore_dir`, or in the submodule itself, and exits with status 1 when any crate
+/// is missing something.
+pub fn check(services: BTreeMap<String, ServiceConfig>, botocore_dir: Option<&Path>, json: bool) {
+    let definitions = match botocore_dir {
+        Some(dir) => ServiceDefinition::load_all_from(dir),
+        None => ServiceDefinition::load_all(),
+    }
+    .unwrap();
 
-    let mut missing = vec![];
-    let mut outdated = vec![];
+    let mut report = Report::default();
 
     for (name, definition) in definitions {
-        if let Some(config) = services.get(&name) {
-            if config.protocol_version != definition.metadata.api_version {
-                outdated.push((name, config, definition));
+        let config = match services.get(&name) {
+            Some(config) => config,
+            None => {
+                let metadata = definition.metadata;
+                report
+                    .missing
+                    .insert(name, (metadata.protocol, metadata.api_version));
+                continue;
             }
-        } else {
-            missing.push((name, definition));
+        };
+
+        if config.protocol_version != definition.metadata.api_version {
+            report.outdated.insert(
+                name.clone(),
+                (
+                    config.protocol_version.clone(),
+                    definition.metadata.api_version.clone(),
+                ),
+            );
+        }
+
+        let generated = match ServiceDefinition::load(&name, &config.protocol_version) {
+            Ok(generated) => generated,
+            Err(e) => panic!("Failed to load service {}: {}", name, e),
+        };
+        let drift = Drift::between(&generated, &definition);
+        if drift != Drift::default() {
+            report.drift.insert(name, drift);
         }
     }
 
-    if !missing.is_empty() {
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        print_summary(&report);
+    }
+
+    let drifted = !report.missing.is_empty()
+        || !report.outdated.is_empty()
+        || report.drift.values().any(Drift::is_drifted);
+    if drifted {
+        process::exit(1);
+    }
+}
+
+fn print_summary(report: &Report) {
+    if !report.missing.is_empty() {
         println!();
         println!("Missing Services");
         println!("================");
 
-        for (name, definition) in missing {
-            println!(
-                "{} ({}, {})",
-                &name, &definition.metadata.protocol, &definition.metadata.api_version
-            );
+        for (name, (protocol, api_version)) in &report.missing {
+            println!("{} ({}, {})", name, protocol, api_version);
         }
     }
 
-    if !outdated.is_empty() {
+    if !report.outdated.is_empty() {
         println!();
         println!("Outdated Services");
         println!("=================");
 
-        for (name, config, definition) in outdated {
-            println!(
-                "{} ({} => {})",
-                &name, &config.protocol_version, &definition.metadata.api_version
-            );
+        for (name, (generated, current)) in &report.outdated {
+            println!("{} ({} => {})", name, generated, current);
+        }
+    }
+
+    if !report.drift.is_empty() {
+        println!();
+        println!("Service Drift");
+        println!("=============");
+
+        for (name, drift) in &report.drift {
+            println!("{}", name);
+            for line in drift.summary() {
+                println!("    {}", line);
+            }
         }
     }
 }
@@ -0,0 +1,133 @@
+use std::io::Write;
+
+use super::json::{
+    generate_documentation, generate_endpoint_modification, generate_method_signature,
+};
+use super::{error_type_name, validation, FileWriter, GenerateProtocol, IoResult};
+use crate::botocore::Operation;
+use crate::Service;
+
+/// Generates clients for the RPC v2 CBOR protocol, whose requests and responses have
+/// CBOR bodies and are sent to `/service/{targetPrefix}/operation/{name}`.
+pub struct CborGenerator;
+
+impl GenerateProtocol for CborGenerator {
+    fn generate_method_signatures(
+        &self,
+        writer: &mut FileWriter,
+        service: &Service<'_>,
+    ) -> IoResult {
+        for (operation_name, operation) in service.operations().iter() {
+            let output_type = operation.output_shape_or("()");
+
+            writeln!(
+                writer,
+                "
+                {documentation}
+                {method_signature} -> RusotoFuture<{output_type}, {error_type}>;
+                ",
+                documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
+                method_signature = generate_method_signature(service, operation),
+                error_type = error_type_name(service, operation_name),
+                output_type = output_type
+            )?
+        }
+        Ok(())
+    }
+
+    fn omits_empty_inputs(&self) -> bool {
+        true
+    }
+
+    fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+        let validated_shapes = validation::validated_shapes(service);
+        for (operation_name, operation) in service.operations().iter() {
+            let output_type = operation.output_shape_or("()");
+
+            writeln!(writer,
+                     "
+                {documentation}
+                {method_signature} -> RusotoFuture<{output_type}, {error_type}> {{
+                    {validate_input}
+                    let mut request = SignedRequest::new(\"POST\", \"{signing_name}\", &self.region, &proto::cbor::request_path(\"{target_prefix}\", \"{name}\"));
+                    request.set_operation(\"{name}\");
+                    {modify_endpoint_prefix}
+                    {payload}
+
+                    self.client.sign_and_dispatch(request, |response| {{
+                        if response.status.is_success() {{
+                            {ok_response}
+                        }} else {{
+                            Box::new(response.buffer().from_err().and_then(|response| {{
+                                Err({error_type}::from_response(response))
+                            }}))
+                        }}
+                    }})
+                }}
+                ",
+                     validate_input = validation::generate_input_validation(&validated_shapes, operation),
+                     documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
+                     method_signature = generate_method_signature(service, operation),
+                     payload = generate_payload(service, operation),
+                     signing_name = service.signing_name(),
+                     modify_endpoint_prefix = generate_endpoint_modification(service)
+                         .unwrap_or_else(|| "".to_owned()),
+                     name = operation.name,
+                     ok_response = generate_ok_response(operation, output_type),
+                     target_prefix = service.target_prefix().unwrap(),
+                     error_type = error_type_name(service, operation_name),
+                     output_type = output_type)?;
+        }
+        Ok(())
+    }
+
+    fn generate_prelude(&self, writer: &mut FileWriter, _service: &Service<'_>) -> IoResult {
+        writeln!(
+            writer,
+            "
+        use rusoto_core::proto;
+        use rusoto_core::signature::SignedRequest;"
+        )
+    }
+
+    fn serialize_trait(&self) -> Option<&'static str> {
+        Some("Serialize")
+    }
+
+    fn deserialize_trait(&self) -> Option<&'static str> {
+        Some("Deserialize")
+    }
+
+    fn timestamp_type(&self) -> &'static str {
+        "f64"
+    }
+}
+
+fn generate_payload(service: &Service<'_>, operation: &Operation) -> String {
+    if operation.input.is_some()
+        && service
+            .get_shape(operation.input_shape())
+            .as_ref()
+            .and_then(|s| s.members.as_ref())
+            .map(|m| !m.is_empty())
+            .unwrap_or(false)
+    {
+        "proto::cbor::set_payload(&mut request, &input);".to_owned()
+    } else {
+        // operations without input send no body
+        "proto::cbor::set_empty_payload(&mut request);".to_owned()
+    }
+}
+
+fn generate_ok_response(operation: &Operation, output_type: &str) -> String {
+    if operation.output.is_some() {
+        format!(
+            "Box::new(response.buffer().from_err().and_then(|response| {{
+                proto::cbor::ResponsePayload::new(&response).with_operation(\"{}\").deserialize::<{}, _>()
+            }}))",
+            operation.name, output_type
+        )
+    } else {
+        "Box::new(future::ok(::std::mem::drop(response)))".to_owned()
+    }
+}
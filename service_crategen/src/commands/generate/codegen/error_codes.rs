@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use serde_json;
+
+use super::{FileWriter, IoResult};
+use crate::Service;
+
+/// The error codes of S3 and their descriptions, as listed in
+/// https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html
+const S3_ERROR_CODES: &str = include_str!("../../../../s3_error_codes.json");
+
+/// Generates an enum of every documented error code for S3, whose API model only lists the
+/// errors of a few operations.
+pub fn generate_error_codes(writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    if service.service_type_name() != "S3" {
+        return Ok(());
+    }
+    let codes: BTreeMap<String, String> =
+        serde_json::from_str(S3_ERROR_CODES).expect("Unable to parse S3 error codes");
+
+    let variants = codes
+        .iter()
+        .map(|(code, description)| format!("/// {}\n{},", description, code))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let from_code = codes
+        .keys()
+        .map(|code| format!("\"{code}\" => S3ErrorCode::{code},", code = code))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let as_str = codes
+        .keys()
+        .map(|code| format!("S3ErrorCode::{code} => \"{code}\",", code = code))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    writeln!(
+        writer,
+        "/// The code of an error returned by S3, whichever operation returned it.
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum S3ErrorCode {{
+            {variants}
+            /// A code which wasn't documented when this crate was generated.
+            Unknown(String),
+        }}
+
+        impl S3ErrorCode {{
+            /// The error code given in the `Code` of an error response.
+            pub fn from_code(code: &str) -> S3ErrorCode {{
+                match code {{
+                    {from_code}
+                    _ => S3ErrorCode::Unknown(code.to_owned()),
+                }}
+            }}
+
+            /// The code as it appears in error responses.
+            pub fn as_str(&self) -> &str {{
+                match *self {{
+                    {as_str}
+                    S3ErrorCode::Unknown(ref code) => code,
+                }}
+            }}
+        }}
+
+        impl fmt::Display for S3ErrorCode {{
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+                write!(f, \"{{}}\", self.as_str())
+            }}
+        }}
+        ",
+        variants = variants,
+        from_code = from_code,
+        as_str = as_str,
+    )
+}
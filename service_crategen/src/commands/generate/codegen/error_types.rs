@@ -144,6 +144,7 @@ pub trait GenerateErrorTypes {
 
 pub struct RestJsonErrorTypes;
 pub struct JsonErrorTypes;
+pub struct CborErrorTypes;
 pub struct XmlErrorTypes;
 
 impl GenerateErrorTypes for XmlErrorTypes {
@@ -280,7 +281,7 @@ impl JsonErrorTypes {
             }
         }
         type_matchers.push(format!(
-            "\"ValidationException\" => return RusotoError::Validation(err.msg)",
+            "\"ValidationException\" => return RusotoError::Validation {{ message: err.msg, fields: err.fields }}",
         ));
         type_matchers.push(format!("_ => {{}}"));
         type_matchers.join(",\n")
@@ -312,3 +313,29 @@ impl GenerateErrorTypes for RestJsonErrorTypes {
         )
     }
 }
+
+impl GenerateErrorTypes for CborErrorTypes {
+    fn generate_error_from_body_impl(
+        &self,
+        operation_name: &str,
+        operation: &Operation,
+        service: &Service<'_>,
+    ) -> String {
+        format!(
+            "
+                impl {type_name} {{
+                    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<{type_name}> {{
+                        if let Some(err) = proto::cbor::Error::parse(&res) {{
+                            match err.typ.as_str() {{
+                                {type_matchers}
+                            }}
+                        }}
+                        return RusotoError::Unknown(res);
+                    }}
+                }}",
+            type_name = error_type_name(service, operation_name),
+            type_matchers =
+                JsonErrorTypes.generate_error_type_matchers(operation_name, operation, service)
+        )
+    }
+}
@@ -1,7 +1,7 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, FileWriter, GenerateProtocol, IoResult};
+use super::{error_type_name, validation, FileWriter, GenerateProtocol, IoResult};
 use crate::botocore::Operation;
 use crate::Service;
 
@@ -27,7 +27,12 @@ impl GenerateProtocol for JsonGenerator {
         Ok(())
     }
 
+    fn omits_empty_inputs(&self) -> bool {
+        true
+    }
+
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+        let validated_shapes = validation::validated_shapes(service);
         for (operation_name, operation) in service.operations().iter() {
             let output_type = operation.output_shape_or("()");
 
@@ -35,9 +40,11 @@ impl GenerateProtocol for JsonGenerator {
                      "
                 {documentation}
                 {method_signature} -> RusotoFuture<{output_type}, {error_type}> {{
+                    {validate_input}
                     let mut request = SignedRequest::new(\"{http_method}\", \"{signing_name}\", &self.region, \"{request_uri}\");
+                    request.set_operation(\"{name}\");
                     {modify_endpoint_prefix}
-                    request.set_content_type(\"application/x-amz-json-{json_version}\".to_owned());
+                    request.set_content_type(\"{content_type}\".to_owned());
                     request.add_header(\"x-amz-target\", \"{target_prefix}.{name}\");
                     {payload}
 
@@ -52,6 +59,7 @@ impl GenerateProtocol for JsonGenerator {
                     }})
                 }}
                 ",
+                     validate_input = validation::generate_input_validation(&validated_shapes, operation),
                      documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                      method_signature = generate_method_signature(service, operation),
                      payload = generate_payload(service, operation),
@@ -63,7 +71,8 @@ impl GenerateProtocol for JsonGenerator {
                      ok_response = generate_ok_response(operation, output_type),
                      request_uri = operation.http.request_uri,
                      target_prefix = service.target_prefix().unwrap(),
-                     json_version = service.json_version().unwrap(),
+                     // botocore takes services without a JSON version to speak 1.0
+                     content_type = service.json_content_type("1.0"),
                      error_type = error_type_name(service, operation_name),
                      output_type = output_type)?;
         }
@@ -96,7 +105,7 @@ impl GenerateProtocol for JsonGenerator {
     }
 }
 
-fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
+pub(super) fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
     if service.signing_name() == service.endpoint_prefix() {
         None
     } else {
@@ -107,7 +116,7 @@ fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
     }
 }
 
-fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> String {
+pub(super) fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> String {
     if operation.input.is_some()
         && service
             .get_shape(operation.input_shape())
@@ -149,7 +158,7 @@ fn generate_payload(service: &Service<'_>, operation: &Operation) -> String {
     }
 }
 
-fn generate_documentation(operation: &Operation) -> Option<String> {
+pub(super) fn generate_documentation(operation: &Operation) -> Option<String> {
     operation
         .documentation
         .as_ref()
@@ -157,13 +166,20 @@ fn generate_documentation(operation: &Operation) -> Option<String> {
 }
 
 fn generate_ok_response(operation: &Operation, output_type: &str) -> String {
-    if operation.output.is_some() {
-        format!(
-            "Box::new(response.buffer().from_err().and_then(|response| {{
-                    proto::json::ResponsePayload::new(&response).deserialize::<{}, _>()
+    if let Some(ref output) = operation.output {
+        match output.result_wrapper {
+            Some(ref result_wrapper) => format!(
+                "Box::new(response.buffer().from_err().and_then(|response| {{
+                    proto::json::ResponsePayload::new(&response).with_operation(\"{}\").deserialize_wrapped::<{}, _>(\"{}\")
                 }}))",
-            output_type
-        )
+                operation.name, output_type, result_wrapper
+            ),
+            // large bodies, such as those of list operations, are parsed as they are read
+            None => format!(
+                "proto::json::deserialize_streaming::<{}, _>(response, \"{}\")",
+                output_type, operation.name
+            ),
+        }
     } else {
         "Box::new(future::ok(::std::mem::drop(response)))".to_owned()
     }
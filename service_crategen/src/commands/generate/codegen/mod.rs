@@ -4,17 +4,24 @@ use std::io::{BufWriter, Write};
 
 use inflector::Inflector;
 
-use self::error_types::{GenerateErrorTypes, JsonErrorTypes, RestJsonErrorTypes, XmlErrorTypes};
+use self::error_codes::generate_error_codes;
+use self::cbor::CborGenerator;
+use self::error_types::{
+    CborErrorTypes, GenerateErrorTypes, JsonErrorTypes, RestJsonErrorTypes, XmlErrorTypes,
+};
 use self::json::JsonGenerator;
 use self::query::QueryGenerator;
 use self::rest_json::RestJsonGenerator;
 use self::rest_xml::RestXmlGenerator;
 use self::tests::generate_tests;
 use self::type_filter::filter_types;
+use self::website_endpoints::generate_website_endpoints;
 use crate::botocore::{Member, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
+mod cbor;
+mod error_codes;
 mod error_types;
 mod json;
 mod query;
@@ -24,6 +31,8 @@ mod rest_response_parser;
 mod rest_xml;
 pub mod tests;
 mod type_filter;
+mod validation;
+mod website_endpoints;
 mod xml_payload_parser;
 
 type FileWriter = BufWriter<File>;
@@ -45,6 +54,11 @@ pub trait GenerateProtocol {
     /// The method generated by this method are inserted into an enclosing `impl FooClient {}` block
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult;
 
+    /// Whether the methods of operations whose input shape has no members take no input
+    fn omits_empty_inputs(&self) -> bool {
+        false
+    }
+
     /// If possible, return the trait that can be derived to serialize types
     fn serialize_trait(&self) -> Option<&'static str> {
         None
@@ -90,6 +104,7 @@ pub fn generate_source(service: &Service<'_>, writer: &mut FileWriter) -> IoResu
         "query" | "ec2" => generate(writer, service, QueryGenerator, XmlErrorTypes),
         "rest-json" => generate(writer, service, RestJsonGenerator, RestJsonErrorTypes),
         "rest-xml" => generate(writer, service, RestXmlGenerator, XmlErrorTypes),
+        "smithy-rpc-v2-cbor" => generate(writer, service, CborGenerator, CborErrorTypes),
         protocol => panic!("Unknown protocol {}", protocol),
     }
 }
@@ -145,9 +160,17 @@ where
     )?;
 
     protocol_generator.generate_prelude(writer, service)?;
+    if !validation::validated_shapes(service).is_empty() {
+        writeln!(
+            writer,
+            "use rusoto_core::validation::{{field_path, Validate, ValidationErrors}};"
+        )?;
+    }
     generate_types(writer, service, &protocol_generator)?;
     error_type_generator.generate_error_types(writer, service)?;
+    generate_error_codes(writer, service)?;
     generate_client(writer, service, &protocol_generator)?;
+    generate_website_endpoints(writer, service)?;
     generate_tests(writer, service)?;
 
     Ok(())
@@ -174,6 +197,8 @@ where
 
     writeln!(writer, "}}")?;
 
+    generate_client_delegations(writer, service, protocol_generator)?;
+
     writeln!(writer,
         "/// A client for the {service_name} API.
         #[derive(Clone)]
@@ -215,6 +240,195 @@ where
         trait_name = service.service_type_name(),
     )?;
     protocol_generator.generate_method_impls(writer, service)?;
+    writeln!(writer, "}}")?;
+
+    generate_mock(writer, service, protocol_generator)
+}
+
+/// The input type, unless they take none, output type and error type of the method of
+/// each operation, by the name of its method
+fn method_types<'b, P>(
+    service: &'b Service<'_>,
+    protocol_generator: &P,
+) -> Vec<(String, Option<&'b str>, &'b str, String)>
+where
+    P: GenerateProtocol,
+{
+    service
+        .operations()
+        .iter()
+        .map(|(operation_name, operation)| {
+            let input_type = operation.input.as_ref().and_then(|input| {
+                let empty = service
+                    .get_shape(&input.shape)
+                    .and_then(|shape| shape.members.as_ref())
+                    .map_or(true, |members| members.is_empty());
+                if empty && protocol_generator.omits_empty_inputs() {
+                    None
+                } else {
+                    Some(input.shape.as_str())
+                }
+            });
+            (
+                operation.name.to_snake_case(),
+                input_type,
+                operation.output_shape_or("()"),
+                error_type_name(service, operation_name),
+            )
+        })
+        .collect()
+}
+
+/// Implements the service trait for references, boxes and `Arc`s of its implementations,
+/// trait objects included, so that clients can be shared as `Arc<dyn Trait>`
+fn generate_client_delegations<P>(
+    writer: &mut FileWriter,
+    service: &Service<'_>,
+    protocol_generator: &P,
+) -> IoResult
+where
+    P: GenerateProtocol,
+{
+    let trait_name = service.service_type_name();
+    // object safety is required of the trait, as clients are shared as trait objects
+    writeln!(
+        writer,
+        "const _: Option<&dyn {trait_name}> = None;",
+        trait_name = trait_name
+    )?;
+
+    let methods = method_types(service, protocol_generator);
+    for &(generics, handle) in &[
+        ("'a, T", "&'a T"),
+        ("T", "Box<T>"),
+        ("T", "::std::sync::Arc<T>"),
+    ] {
+        writeln!(
+            writer,
+            "impl<{generics}: {trait_name} + ?Sized> {trait_name} for {handle} {{",
+            generics = generics,
+            trait_name = trait_name,
+            handle = handle
+        )?;
+        for (method_name, input_type, output_type, error_type) in &methods {
+            let (parameter, argument) = match input_type {
+                Some(input_type) => (format!(", input: {}", input_type), "input"),
+                None => (String::new(), ""),
+            };
+            writeln!(
+                writer,
+                "fn {method_name}(&self{parameter}) -> RusotoFuture<{output_type}, {error_type}> {{
+                    (**self).{method_name}({argument})
+                }}",
+                method_name = method_name,
+                parameter = parameter,
+                argument = argument,
+                output_type = output_type,
+                error_type = error_type
+            )?;
+        }
+        writeln!(writer, "}}")?;
+    }
+    Ok(())
+}
+
+/// Generates a mock of the service, behind the `mocks` feature, which answers each
+/// operation with what the handler given for it returns
+fn generate_mock<P>(
+    writer: &mut FileWriter,
+    service: &Service<'_>,
+    protocol_generator: &P,
+) -> IoResult
+where
+    P: GenerateProtocol,
+{
+    let trait_name = service.service_type_name();
+    let mock_name = format!("Mock{}", trait_name);
+    let methods = method_types(service, protocol_generator);
+
+    writeln!(
+        writer,
+        "/// A mock of the {service_name} API for tests, with the `mocks` feature, whose methods
+        /// answer with what the handler given for their operation returns.
+        ///
+        /// Methods of operations without a handler panic.
+        #[cfg(any(test, feature = \"mocks\"))]
+        #[derive(Default)]
+        pub struct {mock_name} {{",
+        service_name = service.name(),
+        mock_name = mock_name
+    )?;
+    for (method_name, input_type, output_type, error_type) in &methods {
+        writeln!(
+            writer,
+            "{method_name}: Option<Box<dyn Fn({input_type}) -> ::std::result::Result<{output_type}, RusotoError<{error_type}>> + Send + Sync>>,",
+            method_name = method_name,
+            input_type = input_type.unwrap_or(""),
+            output_type = output_type,
+            error_type = error_type
+        )?;
+    }
+    writeln!(
+        writer,
+        "}}
+
+        #[cfg(any(test, feature = \"mocks\"))]
+        impl {mock_name} {{
+            /// Creates a mock without handlers.
+            pub fn new() -> {mock_name} {{
+                Default::default()
+            }}
+        ",
+        mock_name = mock_name
+    )?;
+    for (method_name, input_type, output_type, error_type) in &methods {
+        writeln!(
+            writer,
+            "/// Answers `{method_name}` with what `handler` returns{for_input}.
+            pub fn with_{method_name}<F>(mut self, handler: F) -> {mock_name}
+                where F: Fn({input_type}) -> ::std::result::Result<{output_type}, RusotoError<{error_type}>> + Send + Sync + 'static
+            {{
+                self.{method_name} = Some(Box::new(handler));
+                self
+            }}",
+            method_name = method_name,
+            for_input = if input_type.is_some() { " for its input" } else { "" },
+            mock_name = mock_name,
+            input_type = input_type.unwrap_or(""),
+            output_type = output_type,
+            error_type = error_type
+        )?;
+    }
+    writeln!(
+        writer,
+        "}}
+
+        #[cfg(any(test, feature = \"mocks\"))]
+        impl {trait_name} for {mock_name} {{",
+        trait_name = trait_name,
+        mock_name = mock_name
+    )?;
+    for (method_name, input_type, output_type, error_type) in &methods {
+        let (parameter, argument) = match input_type {
+            Some(input_type) => (format!(", input: {}", input_type), "input"),
+            None => (String::new(), ""),
+        };
+        writeln!(
+            writer,
+            "fn {method_name}(&self{parameter}) -> RusotoFuture<{output_type}, {error_type}> {{
+                match self.{method_name} {{
+                    Some(ref handler) => handler({argument}).into(),
+                    None => panic!(\"{mock_name} has no handler for {method_name}\"),
+                }}
+            }}",
+            method_name = method_name,
+            parameter = parameter,
+            argument = argument,
+            output_type = output_type,
+            error_type = error_type,
+            mock_name = mock_name
+        )?;
+    }
     writeln!(writer, "}}")
 }
 
@@ -359,6 +573,7 @@ where
     P: GenerateProtocol,
 {
     let (serialized_types, deserialized_types) = filter_types(service);
+    let validated_shapes = validation::validated_shapes(service);
 
     for name in find_shapes_to_generate(service).iter() {
         let shape = service.get_shape(name).unwrap();
@@ -395,6 +610,14 @@ where
                 );
                 writeln!(writer, "{}", generated)?;
             }
+
+            if validated_shapes.contains(name) {
+                writeln!(
+                    writer,
+                    "{}",
+                    validation::generate_validate_impl(service, &validated_shapes, name, shape)
+                )?;
+            }
         }
 
         if streaming {
@@ -461,10 +684,21 @@ where
     }
 
     let attributes = format!("#[derive({})]", derived.join(","));
-    let test_attributes = if derived.iter().any(|&x| x == "Deserialize")
-        && !derived.iter().any(|&x| x == "Serialize")
-    {
-        "\n#[cfg_attr(test, derive(Serialize))]"
+    // Shapes which are only sent or only received derive the other serde trait in tests and
+    // behind a feature, so applications can store them. Both traits share the serde attributes
+    // of the fields, so the wire format is the same either way.
+    let serializes = derived.contains(&"Serialize");
+    let deserializes = derived.contains(&"Deserialize");
+    // Protocols writing their own (de)serializers derive neither trait, so they derive both.
+    let derives_serde = protocol_generator.serialize_trait().is_some()
+        || protocol_generator.deserialize_trait().is_some();
+    let test_attributes = if !derives_serde {
+        "\n#[cfg_attr(any(test, feature = \"serialize_structs\"), derive(Serialize))]
+        #[cfg_attr(any(test, feature = \"deserialize_structs\"), derive(Deserialize))]"
+    } else if deserializes && !serializes {
+        "\n#[cfg_attr(any(test, feature = \"serialize_structs\"), derive(Serialize))]"
+    } else if serializes && !deserializes {
+        "\n#[cfg_attr(any(test, feature = \"deserialize_structs\"), derive(Deserialize))]"
     } else {
         ""
     };
@@ -493,7 +727,7 @@ where
             test_attributes = test_attributes,
             name = name,
             struct_fields =
-                generate_struct_fields(service, shape, name, need_serde_attrs, protocol_generator),
+                generate_struct_fields(service, shape, name, need_serde_attrs, !derives_serde, protocol_generator),
         )
     }
 }
@@ -503,6 +737,7 @@ fn generate_struct_fields<P: GenerateProtocol>(
     shape: &Shape,
     shape_name: &str,
     serde_attrs: bool,
+    feature_serde_attrs: bool,
     protocol_generator: &P,
 ) -> String {
     shape.members.as_ref().unwrap().iter().filter_map(|(member_name, member)| {
@@ -528,6 +763,15 @@ fn generate_struct_fields<P: GenerateProtocol>(
                             default,
                         )]".to_owned()
                     );
+                } else if member_shape.shape_type == ShapeType::Timestamp
+                    && service.protocol() == "smithy-rpc-v2-cbor"
+                {
+                    // CBOR tags timestamps as epoch-based date/times
+                    lines.push(
+                        "#[serde(
+                            serialize_with=\"::rusoto_core::serialization::SerdeTimestamp::serialize_timestamp\",
+                        )]".to_owned()
+                    );
                 } else if member_shape.shape_type == ShapeType::List {
                     if let Some(ref list_element_member) = member_shape.member {
                         if let Some(list_element_shape_type) = service.shape_type_for_member(list_element_member) {
@@ -550,6 +794,15 @@ fn generate_struct_fields<P: GenerateProtocol>(
             }
         }
 
+        if feature_serde_attrs {
+            if let Some(attribute) = generate_feature_serde_attr(service, member) {
+                lines.push(format!(
+                    "#[cfg_attr(any(test, feature=\"serialize_structs\", feature=\"deserialize_structs\"), {})]",
+                    attribute
+                ));
+            }
+        }
+
         let member_shape = service.shape_for_member(member).unwrap();
         let rs_type = get_rust_type(service,
                                     &member.shape,
@@ -590,6 +843,37 @@ fn generate_struct_fields<P: GenerateProtocol>(
     }).collect::<Vec<String>>().join("\n")
 }
 
+/// The serde attribute of a member of a shape deriving serde traits only behind the
+/// features, if its type needs one: blobs keep the base64 form of the JSON protocols, and
+/// streaming bodies, which can't be stored, are skipped.
+fn generate_feature_serde_attr(service: &Service<'_>, member: &Member) -> Option<String> {
+    if member.streaming() {
+        return Some("serde(skip)".to_owned());
+    }
+    let member_shape = service.shape_for_member(member)?;
+    let helper = match member_shape.shape_type {
+        ShapeType::Blob => "SerdeBlob::deserialize_blob",
+        ShapeType::List => match member_shape
+            .member
+            .as_ref()
+            .and_then(|element| service.shape_type_for_member(element))
+        {
+            Some(ShapeType::Blob) => "SerdeBlobList::deserialize_blob_list",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(format!(
+        "serde(
+            deserialize_with=\"::rusoto_core::serialization::{}\",
+            serialize_with=\"::rusoto_core::serialization::{}\",
+            default,
+        )",
+        helper,
+        helper.replace("::deserialize_", "::serialize_")
+    ))
+}
+
 fn error_type_name(service: &Service<'_>, name: &str) -> String {
     let type_name = mutate_type_name(service, name);
     format!("{}Error", type_name)
@@ -5,7 +5,7 @@ use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
-use super::xml_payload_parser;
+use super::{validation, xml_payload_parser};
 use super::{
     error_type_name, generate_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
 };
@@ -29,12 +29,15 @@ impl GenerateProtocol for QueryGenerator {
     }
 
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+        let validated_shapes = validation::validated_shapes(service);
         for (operation_name, operation) in service.operations().iter() {
             writeln!(writer,
                      "
                 {documentation}
                 {method_signature} {{
+                    {validate_input}
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, \"{request_uri}\");
+                    request.set_operation(\"{operation_name}\");
                     let mut params = Params::new();
 
                     params.put(\"Action\", \"{operation_name}\");
@@ -54,6 +57,7 @@ impl GenerateProtocol for QueryGenerator {
                 }}
                 ",
                      api_version = service.api_version(),
+                     validate_input = validation::generate_input_validation(&validated_shapes, operation),
                      documentation = generate_documentation(operation),
                      error_type = error_type_name(service, operation_name),
                      http_method = &operation.http.method,
@@ -77,7 +81,7 @@ impl GenerateProtocol for QueryGenerator {
             use rusoto_core::param::{{Params, ServiceParams}};
             use rusoto_core::signature::SignedRequest;
             use rusoto_core::proto::xml::util::{{Next, Peek, XmlParseError, XmlResponse}};
-            use rusoto_core::proto::xml::util::{{characters, end_element, find_start_element, start_element, skip_tree, peek_at_name, deserialize_elements}};
+            use rusoto_core::proto::xml::util::{{characters, end_element, find_start_element, start_element, skip_tree, peek_at_name, deserialize_elements, expect_xml, parse_primitive}};
             use rusoto_core::proto::xml::error::*;
             use serde_urlencoded;
             ")
@@ -3,8 +3,8 @@ use std::io::Write;
 use inflector::Inflector;
 
 use super::{
-    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, FileWriter,
-    GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, validation,
+    FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape, ShapeType};
 use crate::Service;
@@ -39,7 +39,12 @@ impl GenerateProtocol for RestJsonGenerator {
         Ok(())
     }
 
+    fn omits_empty_inputs(&self) -> bool {
+        true
+    }
+
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+        let validated_shapes = validation::validated_shapes(service);
         for (operation_name, operation) in service.operations().iter() {
             let input_type = operation.input_shape_or("()");
             let output_type = operation.output_shape_or("()");
@@ -53,9 +58,13 @@ impl GenerateProtocol for RestJsonGenerator {
             writeln!(writer,"
                 {documentation}
                 {method_signature} -> RusotoFuture<{output_type}, {error_type}> {{
+                    {validate_input}
                     {request_uri_formatter}
 
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                    request.set_operation(\"{operation_name}\");
+                    {set_resource_ids}
+                    {set_accept}
                     {default_headers}
                     {set_headers}
                     {modify_endpoint_prefix}
@@ -79,8 +88,12 @@ impl GenerateProtocol for RestJsonGenerator {
                     }})
                 }}
                 ",
+                validate_input = validation::generate_input_validation(&validated_shapes, operation),
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                 method_signature = generate_method_signature(operation, *input_shape),
+                operation_name = operation.name,
+                set_resource_ids = rest_request_generator::generate_resource_ids(service, operation),
+                set_accept = rest_response_parser::generate_accept_header(service, operation, "application/json"),
                 endpoint_prefix = service.signing_name(),
                 modify_endpoint_prefix = generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
                 http_method = operation.http.method,
@@ -156,16 +169,22 @@ fn generate_headers(service: &Service<'_>) -> Option<String> {
 
 // SageMaker Runtime allows to overwrite content-type
 fn generate_default_headers(service: &Service<'_>) -> String {
+    let content_type = service.json_content_type("1.1");
     if service.full_name() == "Amazon SageMaker Runtime" {
-        return "if input.content_type.is_none() {
-                    request.set_content_type(\"application/x-amz-json-1.1\".to_owned());
-                }"
-        .to_string();
+        return format!(
+            "if input.content_type.is_none() {{
+                    request.set_content_type(\"{}\".to_owned());
+                }}",
+            content_type
+        );
     }
     if service.full_name() == "Amazon WorkLink" {
         return "request.set_content_type(\"application/json\".to_owned());".to_string()
     }
-    "request.set_content_type(\"application/x-amz-json-1.1\".to_owned());".to_string()
+    format!(
+        "request.set_content_type(\"{}\".to_owned());",
+        content_type
+    )
 }
 
 // IoT has an endpoint_prefix and a signing_name that differ
@@ -345,7 +364,7 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
         || output_shape.payload.is_some();
 
     match output_shape.payload {
-        None => json_body_parser(shape_name, mutable_result),
+        None => json_body_parser(&operation.name, shape_name, mutable_result),
         Some(ref payload_member_name) => {
             let payload_member_shape =
                 &output_shape.members.as_ref().unwrap()[payload_member_name].shape;
@@ -373,7 +392,7 @@ fn generate_body_parser(operation: &Operation, service: &Service<'_>) -> String
                         payload_shape_required,
                     )
                 }
-                _ => json_body_parser(shape_name, false),
+                _ => json_body_parser(&operation.name, shape_name, false),
             }
         }
     }
@@ -413,9 +432,10 @@ fn payload_body_parser(
 
 /// Parse the http response body as a JSON object with serde, and use that
 /// as the result object
-fn json_body_parser(output_shape: &str, mutable_result: bool) -> String {
+fn json_body_parser(operation_name: &str, output_shape: &str, mutable_result: bool) -> String {
     format!(
-        "let {mutable} result = proto::json::ResponsePayload::new(&response).deserialize::<{output_shape}, _>()?;",
+        "let {mutable} result = proto::json::ResponsePayload::new(&response).with_operation(\"{operation_name}\").deserialize::<{output_shape}, _>()?;",
+        operation_name = operation_name,
         output_shape = output_shape,
         mutable = if mutable_result { "mut" } else { "" }
     )
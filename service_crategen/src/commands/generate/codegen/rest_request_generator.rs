@@ -127,6 +127,30 @@ pub fn generate_uri_formatter(
     ))
 }
 
+/// Records the members bound to the request URI, which identify the resources the
+/// operation acts on, for audit records.
+pub fn generate_resource_ids(service: &Service<'_>, operation: &Operation) -> String {
+    let input_shape = match operation.input {
+        Some(_) => service.get_shape(operation.input_shape()).unwrap(),
+        None => return "".to_owned(),
+    };
+    input_shape
+        .members
+        .as_ref()
+        .unwrap()
+        .iter()
+        .filter(|&(_, member)| member.location.as_ref().map(String::as_str) == Some("uri"))
+        .map(|(member_name, _)| {
+            format!(
+                "request.add_resource_id(\"{member_name}\", &input.{field_name}.to_string());",
+                member_name = member_name,
+                field_name = generate_field_name(member_name),
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
 fn generate_shape_member_uri_strings(shape: &Shape) -> Vec<String> {
     shape
         .members
@@ -7,6 +7,44 @@ use crate::Service;
 //
 // Used by rest-json and rest-xml protocol codegen to generate
 // code to parse headers from the http response.
+/// Sets the `Accept` header of the request of `operation` to `media_type` when its
+/// response has a body parsed as a structure, rather than no body, only headers, or a
+/// body kept whole as a blob or string. Some endpoints answer in another format unless
+/// asked for this one.
+pub fn generate_accept_header(
+    service: &Service<'_>,
+    operation: &Operation,
+    media_type: &str,
+) -> String {
+    let output = match operation.output {
+        Some(ref output) => output,
+        None => return "".to_owned(),
+    };
+    let shape = service
+        .get_shape(&output.shape)
+        .expect("failed to get output shape");
+    let members = match shape.members {
+        Some(ref members) => members,
+        None => return "".to_owned(),
+    };
+    let parses_body = match shape.payload {
+        Some(ref payload) => {
+            let payload_member = members.get(payload).expect("failed to get payload member");
+            service
+                .get_shape(&payload_member.shape)
+                .expect("failed to get payload shape")
+                .shape_type
+                == ShapeType::Structure
+        }
+        None => members.values().any(|member| member.location.is_none()),
+    };
+    if parses_body {
+        format!("request.set_accept(\"{}\".to_owned());", media_type)
+    } else {
+        "".to_owned()
+    }
+}
+
 pub fn generate_response_headers_parser(
     service: &Service<'_>,
     operation: &Operation,
@@ -58,43 +96,28 @@ fn parse_multiple_headers(
 }
 
 fn parse_headers_list(member_name: &str, member: &Member, required: bool) -> String {
-    let set_statement = if required {
-        format!("result.{} = values;", member_name.to_snake_case())
-    } else {
-        format!("result.{} = Some(values);", member_name.to_snake_case())
-    };
-
-    format!(
-        "let mut values = Vec::new();
-            for (key, value) in response.headers.iter() {{
-              if key == \"{location_name}\" {{
-                values.push(value);
-              }}
-            }}
-            {set_statement}",
-        location_name = member.location_name.as_ref().unwrap(),
-        set_statement = set_statement
-    )
+    let values = format!(
+        "rusoto_core::proto::headers::values(&response.headers, \"{location_name}\")",
+        location_name = member.location_name.as_ref().unwrap()
+    );
+    set_multiple_headers(member_name, required, &values)
 }
 
 fn parse_headers_map(member_name: &str, member: &Member, required: bool) -> String {
-    let set_statement = if required {
-        format!("result.{} = values;", member_name.to_snake_case())
-    } else {
-        format!("result.{} = Some(values);", member_name.to_snake_case())
-    };
+    let values = format!(
+        "rusoto_core::proto::headers::prefixed(&response.headers, \"{location_name}\")",
+        location_name = member.location_name.as_ref().unwrap()
+    );
+    set_multiple_headers(member_name, required, &values)
+}
 
-    format!(
-        "let mut values = ::std::collections::HashMap::new();
-    for (key, value) in response.headers.iter() {{
-        if key.as_str().starts_with(\"{location_name}\") {{
-            values.insert(key.as_str()[\"{location_name}\".len()..].to_owned(), value.to_owned());
-        }}
-    }}
-    {set_statement}",
-        location_name = member.location_name.as_ref().unwrap(),
-        set_statement = set_statement
-    )
+/// Sets the member bound to several headers, which keeps every value of a repeated header.
+fn set_multiple_headers(member_name: &str, required: bool, values: &str) -> String {
+    if required {
+        format!("result.{} = {};", member_name.to_snake_case(), values)
+    } else {
+        format!("result.{} = Some({});", member_name.to_snake_case(), values)
+    }
 }
 
 fn parse_single_header(
@@ -3,7 +3,7 @@ use std::io::Write;
 
 use super::{error_type_name, generate_field_name, GenerateProtocol};
 use super::{
-    get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser,
+    get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser, validation,
     xml_payload_parser,
 };
 use super::{FileWriter, IoResult};
@@ -29,6 +29,7 @@ impl GenerateProtocol for RestXmlGenerator {
     }
 
     fn generate_method_impls(&self, writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+        let validated_shapes = validation::validated_shapes(service);
         for (operation_name, operation) in service.operations().iter() {
             let (request_uri, _) =
                 rest_request_generator::parse_query_string(&operation.http.request_uri);
@@ -39,9 +40,14 @@ impl GenerateProtocol for RestXmlGenerator {
                      "{documentation}
                     #[allow(unused_variables, warnings)]
                     {method_signature} {{
+                        {validate_input}
                         {modify_uri}
 
                         let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                        request.set_operation(\"{operation_name}\");
+                        {set_resource_ids}
+                        {route_to_access_point}
+                        {set_accept}
 
                         {set_headers}
                         {set_parameters}
@@ -58,8 +64,14 @@ impl GenerateProtocol for RestXmlGenerator {
                         }})
                     }}
                     ",
+                     validate_input = validation::generate_input_validation(&validated_shapes, operation),
                      documentation = generate_documentation(operation, service),
                      http_method = &operation.http.method,
+                     operation_name = operation.name,
+                     set_resource_ids = rest_request_generator::generate_resource_ids(service, operation),
+                     route_to_access_point = generate_access_point_routing(service, operation)
+                         .unwrap_or_else(|| "".to_owned()),
+                     set_accept = rest_response_parser::generate_accept_header(service, operation, "application/xml"),
                      endpoint_prefix = service.endpoint_prefix(),
                      method_signature = generate_method_signature(operation_name, operation, service),
                      error_type = error_type_name(service, operation_name),
@@ -93,7 +105,7 @@ impl GenerateProtocol for RestXmlGenerator {
             use xml::EventWriter;
             use rusoto_core::proto::xml::error::*;
             use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
-            use rusoto_core::proto::xml::util::{peek_at_name, characters, end_element, find_start_element, start_element, skip_tree, deserialize_elements};
+            use rusoto_core::proto::xml::util::{peek_at_name, characters, end_element, find_start_element, start_element, skip_tree, deserialize_elements, expect_xml, parse_primitive};
             "
             .to_owned();
 
@@ -219,6 +231,20 @@ fn generate_service_specific_code(service: &Service<'_>, operation: &Operation)
     }
 }
 
+// S3 buckets can be named by the ARN of an access point, which requests are sent to
+// instead of the bucket's path. See `custom/access_points.rs` in rusoto_s3.
+fn generate_access_point_routing(service: &Service<'_>, operation: &Operation) -> Option<String> {
+    if service.service_type_name() != "S3" || !operation.http.request_uri.contains("{Bucket}") {
+        return None;
+    }
+    Some(
+        "if let Err(err) = crate::custom::access_points::route(&mut request, &input.bucket) {
+            return RusotoFuture::from(Err(RusotoError::from(err)));
+        }"
+        .to_owned(),
+    )
+}
+
 fn generate_payload_member_serialization(service: &Service,shape: &Shape) -> String {
     let payload_field = shape.payload.as_ref().unwrap();
     let payload_member = shape.members.as_ref().unwrap().get(payload_field).unwrap();
@@ -4,7 +4,8 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use self::util::case_insensitive_btreemap_get;
-use super::{FileWriter, IoResult};
+use super::{generate_field_name, FileWriter, IoResult};
+use crate::botocore::ShapeType;
 use crate::util;
 use crate::Service;
 
@@ -105,10 +106,7 @@ fn generate_response_parse_test(
     let request_params;
     let request_constructor;
     if operation.input.is_some() {
-        request_constructor = format!(
-            "let request = {request_type}::default();",
-            request_type = operation.input_shape()
-        );
+        request_constructor = generate_request_constructor(service, operation.input_shape());
         request_params = "request".to_string();
     } else {
         request_constructor = "".to_string();
@@ -136,6 +134,44 @@ fn generate_response_parse_test(
         is_ok = if is_ok { "" } else { "!" }))
 }
 
+/// Builds the request of a response test. Length constraints are checked before any
+/// request is sent, so required strings get the shortest value they allow.
+fn generate_request_constructor(service: &Service<'_>, shape_name: &str) -> String {
+    let shape = service.get_shape(shape_name).unwrap();
+    let fields = shape
+        .members
+        .iter()
+        .flat_map(|members| members.iter())
+        .filter(|&(member_name, member)| !member.deprecated() && shape.required(member_name))
+        .filter_map(|(member_name, member)| {
+            let member_shape = service.get_shape(&member.shape).unwrap();
+            match member_shape.min {
+                Some(min) if member_shape.shape_type == ShapeType::String && min > 0.0 => {
+                    Some(format!(
+                        "{}: {:?}.to_owned(),",
+                        generate_field_name(member_name),
+                        "x".repeat(min as usize)
+                    ))
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if fields.is_empty() {
+        format!("let request = {}::default();", shape_name)
+    } else {
+        format!(
+            "let request = {} {{
+                {}
+                ..Default::default()
+            }};",
+            shape_name,
+            fields.join("\n")
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Response {
     pub service: String,
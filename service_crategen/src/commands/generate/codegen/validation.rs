@@ -0,0 +1,306 @@
+use std::collections::BTreeSet;
+
+use super::{generate_field_name, mutate_type_name};
+use crate::botocore::{Operation, Shape, ShapeType};
+use crate::Service;
+
+/// Names of the structure shapes sent in requests which the service model constrains,
+/// either directly through their members or through the structures they contain.
+pub fn validated_shapes(service: &Service<'_>) -> BTreeSet<String> {
+    let mut input_shapes = BTreeSet::new();
+    for operation in service.operations().values() {
+        if let Some(ref input) = operation.input {
+            collect_shapes(service, &mut input_shapes, &input.shape);
+        }
+    }
+
+    // structures can contain each other, so keep going until no more are found
+    let mut validated = BTreeSet::new();
+    loop {
+        let found: Vec<String> = input_shapes
+            .iter()
+            .filter(|name| !validated.contains(*name))
+            .filter(|name| {
+                let shape = service.get_shape(name).unwrap();
+                shape.shape_type == ShapeType::Structure
+                    && shape
+                        .members
+                        .iter()
+                        .flat_map(|members| members.values())
+                        .filter(|member| !member.deprecated() && !member.streaming())
+                        .any(|member| is_constrained(service, &validated, &member.shape))
+            })
+            .cloned()
+            .collect();
+        if found.is_empty() {
+            return validated;
+        }
+        validated.extend(found);
+    }
+}
+
+fn collect_shapes(service: &Service<'_>, shapes: &mut BTreeSet<String>, shape_name: &str) {
+    if !shapes.insert(shape_name.to_owned()) {
+        return;
+    }
+    let shape = service.get_shape(shape_name).unwrap();
+    match shape.shape_type {
+        ShapeType::Structure => {
+            for member in shape.members.iter().flat_map(|members| members.values()) {
+                collect_shapes(service, shapes, &member.shape);
+            }
+        }
+        ShapeType::List => collect_shapes(service, shapes, shape.member_type()),
+        ShapeType::Map => collect_shapes(service, shapes, shape.value_type()),
+        _ => {}
+    }
+}
+
+fn is_constrained(service: &Service<'_>, validated: &BTreeSet<String>, shape_name: &str) -> bool {
+    let shape = service.get_shape(shape_name).unwrap();
+    match shape.shape_type {
+        ShapeType::Structure => validated.contains(shape_name),
+        ShapeType::List => {
+            has_bounds(shape) || is_constrained(service, validated, shape.member_type())
+        }
+        ShapeType::Map => {
+            has_bounds(shape) || is_constrained(service, validated, shape.value_type())
+        }
+        ShapeType::String => has_bounds(shape) || shape.pattern.is_some(),
+        ShapeType::Blob
+        | ShapeType::Integer
+        | ShapeType::Long
+        | ShapeType::Float
+        | ShapeType::Double => has_bounds(shape),
+        ShapeType::Boolean | ShapeType::Timestamp => false,
+    }
+}
+
+// mirrors the fields `generate_struct_fields` makes optional
+fn is_optional(
+    service: &Service<'_>,
+    shape_name: &str,
+    shape: &Shape,
+    member_name: &str,
+    field_name: &str,
+) -> bool {
+    !shape.required(member_name)
+        || field_name == "created"
+        || (service.name() == "CodePipeline"
+            && shape_name == "ActionRevision"
+            && field_name == "revision_change_id")
+}
+
+fn has_bounds(shape: &Shape) -> bool {
+    min_bound(shape).is_some() || shape.max.is_some()
+}
+
+// a minimum of zero never rejects anything
+fn min_bound(shape: &Shape) -> Option<f32> {
+    shape.min.filter(|min| *min > 0.0)
+}
+
+/// Generates the implementation of `Validate` for a structure in `validated_shapes`
+pub fn generate_validate_impl(
+    service: &Service<'_>,
+    validated: &BTreeSet<String>,
+    shape_name: &str,
+    shape: &Shape,
+) -> String {
+    let type_name = mutate_type_name(service, shape_name);
+    let checks = shape
+        .members
+        .iter()
+        .flat_map(|members| members.iter())
+        .filter(|&(_, member)| !member.deprecated() && !member.streaming())
+        .filter(|&(_, member)| is_constrained(service, validated, &member.shape))
+        .map(|(member_name, member)| {
+            let field_name = generate_field_name(member_name);
+            let path = format!("field_path(path, \"{}\")", field_name);
+            let member_shape = service.get_shape(&member.shape).unwrap();
+            let boxed = member.shape == shape_name;
+
+            if !is_optional(service, shape_name, shape, member_name, &field_name) {
+                generate_checks(
+                    service,
+                    validated,
+                    member_shape,
+                    &format!("self.{}", field_name),
+                    false,
+                    &path,
+                    1,
+                )
+            } else {
+                format!(
+                    "if let Some(ref value) = {deref}self.{field_name} {{
+                        {checks}
+                    }}",
+                    deref = if boxed { "*" } else { "" },
+                    field_name = field_name,
+                    checks =
+                        generate_checks(service, validated, member_shape, "value", true, &path, 1),
+                )
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!(
+        "impl Validate for {type_name} {{
+            fn validate_at(&self, path: &str, errors: &mut ValidationErrors) {{
+                {checks}
+            }}
+        }}
+        ",
+        type_name = type_name,
+        checks = checks,
+    )
+}
+
+/// Generates the checks of a value of the given shape. `value` is either an expression
+/// of the value's type or, when `by_ref` is set, of a reference to it, and `path` an
+/// expression of the path of the value.
+fn generate_checks(
+    service: &Service<'_>,
+    validated: &BTreeSet<String>,
+    shape: &Shape,
+    value: &str,
+    by_ref: bool,
+    path: &str,
+    depth: usize,
+) -> String {
+    let mut checks = Vec::new();
+    match shape.shape_type {
+        ShapeType::Structure => {
+            checks.push(format!("{}.validate_at(&{}, errors);", value, path));
+        }
+        ShapeType::String => {
+            if has_bounds(shape) {
+                checks.push(generate_length_check(
+                    shape,
+                    &format!("{}.chars().count()", value),
+                    path,
+                ));
+            }
+            if let Some(ref pattern) = shape.pattern {
+                checks.push(format!(
+                    "errors.check_pattern(&{}, &{}, {:?});",
+                    path, value, pattern
+                ));
+            }
+        }
+        ShapeType::Blob => {
+            checks.push(generate_length_check(
+                shape,
+                &format!("{}.len()", value),
+                path,
+            ));
+        }
+        ShapeType::Integer | ShapeType::Long => {
+            checks.push(format!(
+                "errors.check_range(&{path}, {deref}{value}, {min}, {max});",
+                path = path,
+                deref = if by_ref { "*" } else { "" },
+                value = value,
+                min = bound(min_bound(shape).map(|min| (min as i64).to_string())),
+                max = bound(shape.max.map(|max| (max as i64).to_string())),
+            ));
+        }
+        ShapeType::Float | ShapeType::Double => {
+            checks.push(format!(
+                "errors.check_range(&{path}, {deref}{value}, {min}, {max});",
+                path = path,
+                deref = if by_ref { "*" } else { "" },
+                value = value,
+                min = bound(min_bound(shape).map(|min| format!("{:?}", min))),
+                max = bound(shape.max.map(|max| format!("{:?}", max))),
+            ));
+        }
+        ShapeType::List | ShapeType::Map => {
+            if has_bounds(shape) {
+                checks.push(generate_length_check(
+                    shape,
+                    &format!("{}.len()", value),
+                    path,
+                ));
+            }
+            let (element_type, iteration) = if shape.shape_type == ShapeType::List {
+                (shape.member_type(), "iter().enumerate()")
+            } else {
+                (shape.value_type(), "iter()")
+            };
+            if is_constrained(service, validated, element_type) {
+                let suffix = if depth > 1 {
+                    depth.to_string()
+                } else {
+                    String::new()
+                };
+                let item = format!("item{}", suffix);
+                let item_path = format!("item_path{}", suffix);
+                checks.push(format!(
+                    "for ({index}, {item}) in {value}.{iteration} {{
+                        let {item_path} = format!(\"{{}}[{{}}]\", {path}, {index});
+                        {checks}
+                    }}",
+                    index = if shape.shape_type == ShapeType::List {
+                        format!("i{}", suffix)
+                    } else {
+                        format!("key{}", suffix)
+                    },
+                    item = item,
+                    value = value,
+                    iteration = iteration,
+                    item_path = item_path,
+                    path = path,
+                    checks = generate_checks(
+                        service,
+                        validated,
+                        service.get_shape(element_type).unwrap(),
+                        &item,
+                        true,
+                        &item_path,
+                        depth + 1
+                    ),
+                ));
+            }
+        }
+        ShapeType::Boolean | ShapeType::Timestamp => {}
+    }
+    checks.join("\n")
+}
+
+fn generate_length_check(shape: &Shape, len: &str, path: &str) -> String {
+    format!(
+        "errors.check_length(&{}, {}, {}, {});",
+        path,
+        len,
+        bound(min_bound(shape).map(|min| (min as usize).to_string())),
+        bound(shape.max.map(|max| (max as usize).to_string())),
+    )
+}
+
+fn bound(value: Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some({})", value),
+        None => "None".to_owned(),
+    }
+}
+
+/// Generates the check of an operation's input made before signing the request: length
+/// constraints are always checked, the others only when the client validates requests
+pub fn generate_input_validation(validated: &BTreeSet<String>, operation: &Operation) -> String {
+    match operation.input {
+        Some(ref input) if validated.contains(&input.shape) => {
+            "let validation = if self.client.validates_requests() {
+                input.validate()
+            } else {
+                input.validate_lengths()
+            };
+            if let Err(errors) = validation {
+                return RusotoFuture::from(Err(RusotoError::from(errors)));
+            }"
+            .to_owned()
+        }
+        _ => "".to_owned(),
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use inflector::Inflector;
+use serde_derive::Deserialize;
+use serde_json;
+
+use super::{FileWriter, IoResult};
+use crate::Service;
+
+/// The S3 website endpoints, and the hosted zones of the Route 53 alias records pointing at
+/// them, as listed in https://docs.aws.amazon.com/general/latest/gr/s3.html
+const S3_WEBSITE_ENDPOINTS: &str = include_str!("../../../../s3_website_endpoints.json");
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WebsiteEndpoint {
+    endpoint: String,
+    hosted_zone_id: String,
+}
+
+/// Generates the lookups of the website endpoint of each region for S3.
+pub fn generate_website_endpoints(writer: &mut FileWriter, service: &Service<'_>) -> IoResult {
+    if service.service_type_name() != "S3" {
+        return Ok(());
+    }
+    let endpoints: BTreeMap<String, WebsiteEndpoint> =
+        serde_json::from_str(S3_WEBSITE_ENDPOINTS).expect("Unable to parse S3 website endpoints");
+
+    let arms = |value: &dyn Fn(&WebsiteEndpoint) -> &str| {
+        endpoints
+            .iter()
+            .map(|(region, endpoint)| {
+                format!(
+                    "region::Region::{} => Some(\"{}\"),",
+                    region.to_pascal_case(),
+                    value(endpoint)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    writeln!(
+        writer,
+        "/// The host name of the website endpoint of buckets in `region`, or `None` for custom regions.
+        pub fn website_endpoint(region: &region::Region) -> Option<&'static str> {{
+            match *region {{
+                {endpoints}
+                region::Region::Custom {{ .. }} => None,
+            }}
+        }}
+
+        /// The Route 53 hosted zone of the website endpoint of buckets in `region`, to point alias
+        /// records at, or `None` for custom regions.
+        pub fn website_hosted_zone_id(region: &region::Region) -> Option<&'static str> {{
+            match *region {{
+                {hosted_zone_ids}
+                region::Region::Custom {{ .. }} => None,
+            }}
+        }}
+        ",
+        endpoints = arms(&|endpoint| &endpoint.endpoint),
+        hosted_zone_ids = arms(&|endpoint| &endpoint.hosted_zone_id),
+    )
+}
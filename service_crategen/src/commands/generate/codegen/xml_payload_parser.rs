@@ -65,6 +65,7 @@ pub fn generate_response_parser(
     // the entire payload is set as one of the struct members, and not parsed
     match output_shape.payload {
         None => xml_body_parser(
+            &operation.name,
             &mutated_shape_name,
             result_wrapper,
             mutable_result,
@@ -93,6 +94,7 @@ pub fn generate_response_parser(
                     )
                 }
                 _ => xml_body_parser(
+                    &operation.name,
                     &mutated_shape_name,
                     result_wrapper,
                     mutable_result,
@@ -152,6 +154,7 @@ fn payload_body_parser(
 }
 
 fn xml_body_parser(
+    operation_name: &str,
     output_shape: &str,
     result_wrapper: &Option<String>,
     mutable_result: bool,
@@ -166,14 +169,18 @@ fn xml_body_parser(
     let deserialize = match *result_wrapper {
         Some(ref tag_name) => format!(
             "start_element(&actual_tag_name, &mut stack)?;
-                     result = {output_shape}Deserializer::deserialize(\"{tag_name}\", &mut stack)?;
+                     result = {output_shape}Deserializer::deserialize(\"{tag_name}\", &mut stack)
+                         .map_err(|err| stack.parse_error(\"{operation_name}\", err, &response.body))?;
                      skip_tree(&mut stack);
                      end_element(&actual_tag_name, &mut stack)?;",
+            operation_name = operation_name,
             output_shape = output_shape,
             tag_name = tag_name
         ),
         None => format!(
-            "result = {output_shape}Deserializer::deserialize(&actual_tag_name, &mut stack)?;",
+            "result = {output_shape}Deserializer::deserialize(&actual_tag_name, &mut stack)
+                 .map_err(|err| stack.parse_error(\"{operation_name}\", err, &response.body))?;",
+            operation_name = operation_name,
             output_shape = output_shape
         ),
     };
@@ -185,6 +192,7 @@ fn xml_body_parser(
             if response.body.is_empty() {{
                 result = {output_shape}::default();
             }} else {{
+                expect_xml(&response)?;
                 let reader = EventReader::new_with_config(
                     response.body.as_ref(),
                     ParserConfig::new().trim_whitespace(true)
@@ -362,13 +370,11 @@ fn generate_primitive_deserializer(shape: &Shape, percent_decode: bool) -> Strin
             "rusoto_core::signature::decode_uri(&characters(stack)?)"
         }
         ShapeType::String | ShapeType::Timestamp => "characters(stack)?",
-        ShapeType::Integer | ShapeType::Long => {
-            "i64::from_str(characters(stack)?.as_ref()).unwrap()"
-        }
-        ShapeType::Double => "f64::from_str(characters(stack)?.as_ref()).unwrap()",
-        ShapeType::Float => "f32::from_str(characters(stack)?.as_ref()).unwrap()",
+        ShapeType::Integer | ShapeType::Long => "parse_primitive::<i64>(&characters(stack)?)?",
+        ShapeType::Double => "parse_primitive::<f64>(&characters(stack)?)?",
+        ShapeType::Float => "parse_primitive::<f32>(&characters(stack)?)?",
         ShapeType::Blob => "characters(stack)?.into()",
-        ShapeType::Boolean => "bool::from_str(characters(stack)?.as_ref()).unwrap()",
+        ShapeType::Boolean => "parse_primitive::<bool>(&characters(stack)?)?",
         _ => panic!("Unknown primitive shape type"),
     };
 
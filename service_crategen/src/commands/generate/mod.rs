@@ -86,14 +86,30 @@ pub fn generate_services(
         features.insert("default".into(), vec!["native-tls".into()]);
         features.insert("native-tls".into(), vec!["rusoto_core/native-tls".into()]);
         features.insert("rustls".into(), vec!["rusoto_core/rustls".into()]);
+        features.insert("mocks".into(), vec![]);
+        features.insert("serialize_structs".into(), vec![]);
+        features.insert("deserialize_structs".into(), vec![]);
 
         let service_dependencies = service.get_dependencies();
         let service_dev_dependencies = service.get_dev_dependencies();
 
+        // shapes of protocols with their own (de)serializers only derive serde traits in
+        // tests and behind the features, unless custom code derives them too
+        let derives_behind_features = match service.protocol() {
+            "query" | "ec2" | "rest-xml" => !service_config
+                .custom_dependencies
+                .as_ref()
+                .map_or(false, |dependencies| dependencies.contains_key("serde_derive")),
+            _ => false,
+        };
+
         let mut extern_crates = service_dependencies.iter().map(|(k, _)| {
             if k == "xml-rs" {
                 return "extern crate xml;".into();
             }
+            if k == "serde_derive" && derives_behind_features {
+                return "#[cfg_attr(any(test, feature = \"serialize_structs\", feature = \"deserialize_structs\"), macro_use)]\nextern crate serde_derive;".into();
+            }
             let safe_name = k.replace("-", "_");
             let use_macro = k == "serde_derive" || k == "lazy_static";
             if use_macro {
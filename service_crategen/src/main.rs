@@ -22,12 +22,21 @@ fn main() {
         .author(crate_authors!())
         .about(crate_description!())
         .subcommand(
-            SubCommand::with_name("check").arg(
-                Arg::with_name("services_config")
-                    .long("config")
-                    .short("c")
-                    .takes_value(true),
-            ),
+            SubCommand::with_name("check")
+                .arg(
+                    Arg::with_name("services_config")
+                        .long("config")
+                        .short("c")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("botocore_dir")
+                        .long("botocore")
+                        .short("b")
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(Arg::with_name("json").long("json").required(false)),
         )
         .subcommand(
             SubCommand::with_name("generate")
@@ -60,7 +69,9 @@ fn main() {
         let service_configs = ServiceConfig::load_all(services_config_path)
             .expect("Unable to read services configuration file.");
 
-        commands::check::check(service_configs);
+        let botocore_dir = matches.value_of("botocore_dir").map(Path::new);
+
+        commands::check::check(service_configs, botocore_dir, matches.is_present("json"));
     }
 
     if let Some(matches) = matches.subcommand_matches("generate") {
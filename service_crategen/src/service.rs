@@ -66,6 +66,17 @@ impl<'b> Service<'b> {
         self.definition.metadata.json_version.as_ref()
     }
 
+    /// The `application/x-amz-json-*` content type of requests to the service, naming the
+    /// JSON version of its definition, or `default_version` if it doesn't give one.
+    pub fn json_content_type(&self, default_version: &str) -> String {
+        format!(
+            "application/x-amz-json-{}",
+            self.json_version()
+                .map(String::as_str)
+                .unwrap_or(default_version)
+        )
+    }
+
     pub fn shapes(&self) -> &BTreeMap<String, Shape> {
         &self.definition.shapes
     }
@@ -153,11 +164,39 @@ impl<'b> Service<'b> {
                     "serde_urlencoded".to_owned(),
                     cargo::Dependency::Simple("0.5".into()),
                 );
+                // shapes derive the serde traits behind the `*_structs` features
+                dependencies.insert(
+                    "serde".to_owned(),
+                    cargo::Dependency::Simple("1.0.2".into()),
+                );
+                dependencies.insert(
+                    "serde_derive".to_owned(),
+                    cargo::Dependency::Simple("1.0.2".into()),
+                );
                 dependencies.insert("xml-rs".to_owned(), cargo::Dependency::Simple("0.8".into()));
             }
             "rest-xml" => {
+                // shapes derive the serde traits behind the `*_structs` features
+                dependencies.insert(
+                    "serde".to_owned(),
+                    cargo::Dependency::Simple("1.0.2".into()),
+                );
+                dependencies.insert(
+                    "serde_derive".to_owned(),
+                    cargo::Dependency::Simple("1.0.2".into()),
+                );
                 dependencies.insert("xml-rs".to_owned(), cargo::Dependency::Simple("0.8".into()));
             }
+            "smithy-rpc-v2-cbor" => {
+                dependencies.insert(
+                    "serde".to_owned(),
+                    cargo::Dependency::Simple("1.0.2".into()),
+                );
+                dependencies.insert(
+                    "serde_derive".to_owned(),
+                    cargo::Dependency::Simple("1.0.2".into()),
+                );
+            }
             "rest-json" => {
                 dependencies.insert(
                     "serde".to_owned(),